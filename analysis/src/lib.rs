@@ -1,9 +1,323 @@
-use std::path::Path;
+pub mod xml;
 
-pub struct Analysis {}
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+pub use xml::Location;
+
+/// The API variant bindings are generated for; `vk.xml` also describes Vulkan SC.
+pub const API: &str = "vulkan";
+
+/// A `<feature>` or `<extension>` that requires an item.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Origin {
+    Feature(usize),
+    Extension(usize),
+}
+
+/// The parsed registry, with lookup tables restricted to the items relevant to [`API`].
+#[derive(Debug)]
+pub struct Analysis {
+    path: PathBuf,
+    pub registry: xml::Registry,
+    types: HashMap<String, usize>,
+    enums: HashMap<String, usize>,
+    commands: HashMap<String, usize>,
+    command_aliases: HashMap<String, usize>,
+    origins: HashMap<String, Vec<Origin>>,
+    /// Values added to `<enums>` blocks by `<require>` blocks, keyed by the extended block.
+    extended_values: HashMap<String, Vec<(Origin, usize, usize)>>,
+}
 
 impl Analysis {
-    pub fn new(_vulkan_headers_path: impl AsRef<Path>) -> Analysis {
-        Analysis {}
+    pub fn new(vulkan_headers_path: impl AsRef<Path>) -> Analysis {
+        let path = vulkan_headers_path.as_ref().join("registry/vk.xml");
+        let input = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+        Self::parse(&input, path.clone()).unwrap_or_else(|e| panic!("{}:{e}", path.display()))
+    }
+
+    /// Parses registry XML from memory. `path` is only used to report locations.
+    pub fn parse(input: &str, path: impl Into<PathBuf>) -> xml::Result<Analysis> {
+        Ok(Self::from_registry(xml::Registry::parse(input)?, path))
+    }
+
+    pub fn from_registry(registry: xml::Registry, path: impl Into<PathBuf>) -> Analysis {
+        let mut analysis = Analysis {
+            path: path.into(),
+            types: index(&registry.types, |t| t.api(), xml::Type::name),
+            enums: index(&registry.enums, |_| None, |e| &e.name),
+            commands: index(&registry.commands, |c| c.api.as_deref(), |c| &c.name),
+            command_aliases: index(&registry.command_aliases, |_| None, |a| &a.name),
+            origins: HashMap::new(),
+            extended_values: HashMap::new(),
+            registry,
+        };
+
+        let registry = &analysis.registry;
+        let features = registry
+            .features
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.api.iter().any(|api| api == API))
+            .map(|(i, f)| (Origin::Feature(i), &f.requires));
+        let extensions = registry
+            .extensions
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.is_supported(API))
+            .map(|(i, e)| (Origin::Extension(i), &e.requires));
+        for (origin, requires) in features.chain(extensions) {
+            for (require_index, require) in requires.iter().enumerate() {
+                if !is_api(require.api.as_deref()) {
+                    continue;
+                }
+                for (item_index, item) in require.items.iter().enumerate() {
+                    let name = match item {
+                        xml::RequireItem::Type { name, .. }
+                        | xml::RequireItem::Command { name, .. } => name.as_str(),
+                        xml::RequireItem::Enum(e) => {
+                            if let xml::RequireEnum::Define(value) = e {
+                                if let Some(extends) = &value.extends {
+                                    if is_api(value.api.as_deref()) {
+                                        analysis
+                                            .extended_values
+                                            .entry(extends.clone())
+                                            .or_default()
+                                            .push((origin, require_index, item_index));
+                                    }
+                                }
+                            }
+                            e.name()
+                        }
+                    };
+                    let origins = analysis.origins.entry(name.to_owned()).or_default();
+                    if !origins.contains(&origin) {
+                        origins.push(origin);
+                    }
+                }
+            }
+        }
+
+        analysis
+    }
+
+    /// Formats `location` as `path/to/vk.xml:line:column`, for diagnostics.
+    pub fn source_location(&self, location: Location) -> String {
+        format!("{}:{location}", self.path.display())
+    }
+
+    pub fn ty(&self, name: &str) -> Option<&xml::Type> {
+        self.types.get(name).map(|&i| &self.registry.types[i])
+    }
+
+    /// Like [`Self::ty()`], but follows `alias` chains to the defining type.
+    pub fn resolve_type(&self, name: &str) -> Option<&xml::Type> {
+        match self.ty(name)? {
+            xml::Type::Alias(alias) => self.resolve_type(&alias.alias),
+            ty => Some(ty),
+        }
+    }
+
+    pub fn enums(&self, name: &str) -> Option<&xml::Enums> {
+        self.enums.get(name).map(|&i| &self.registry.enums[i])
+    }
+
+    /// Values that `<require>` blocks add to the `<enums>` block `name`, in registry order.
+    pub fn extended_values(&self, name: &str) -> impl Iterator<Item = (Origin, &xml::EnumValue)> {
+        self.extended_values
+            .get(name)
+            .into_iter()
+            .flatten()
+            .map(move |&(origin, require, item)| {
+                let require = &self.requires(origin)[require];
+                match &require.items[item] {
+                    xml::RequireItem::Enum(xml::RequireEnum::Define(value)) => (origin, value),
+                    _ => unreachable!(),
+                }
+            })
+    }
+
+    /// Looks up a command by name, following aliases to the defining command.
+    pub fn command(&self, name: &str) -> Option<&xml::Command> {
+        if let Some(&i) = self.commands.get(name) {
+            return Some(&self.registry.commands[i]);
+        }
+        let alias = &self.registry.command_aliases[*self.command_aliases.get(name)?];
+        self.command(&alias.alias)
+    }
+
+    /// The features and extensions that require `name`, which may be a type, command or enum.
+    pub fn origins(&self, name: &str) -> &[Origin] {
+        self.origins.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether `name` is required by any feature or extension supporting [`API`].
+    pub fn is_required(&self, name: &str) -> bool {
+        !self.origins(name).is_empty()
+    }
+
+    pub fn origin_name(&self, origin: Origin) -> &str {
+        match origin {
+            Origin::Feature(i) => &self.registry.features[i].name,
+            Origin::Extension(i) => &self.registry.extensions[i].name,
+        }
+    }
+
+    fn requires(&self, origin: Origin) -> &[xml::Require] {
+        match origin {
+            Origin::Feature(i) => &self.registry.features[i].requires,
+            Origin::Extension(i) => &self.registry.extensions[i].requires,
+        }
+    }
+
+    /// Features targeting [`API`], in version order.
+    pub fn features(&self) -> impl Iterator<Item = &xml::Feature> {
+        self.registry
+            .features
+            .iter()
+            .filter(|f| f.api.iter().any(|api| api == API))
+    }
+
+    /// Extensions supported by [`API`], in registry order.
+    pub fn extensions(&self) -> impl Iterator<Item = &xml::Extension> {
+        self.registry
+            .extensions
+            .iter()
+            .filter(|e| e.is_supported(API))
+    }
+
+    pub fn extension(&self, name: &str) -> Option<&xml::Extension> {
+        self.extensions().find(|e| e.name == name)
+    }
+
+    /// The platform guarding `name`, when every extension requiring it is specific to the same
+    /// platform and no core version requires it.
+    pub fn platform(&self, name: &str) -> Option<&xml::Platform> {
+        let mut platforms = self.origins(name).iter().map(|origin| match *origin {
+            Origin::Feature(_) => None,
+            Origin::Extension(i) => self.registry.extensions[i].platform.as_deref(),
+        });
+        let first = platforms.next()??;
+        if !platforms.all(|p| p == Some(first)) {
+            return None;
+        }
+        self.registry.platforms.iter().find(|p| p.name == first)
+    }
+
+    /// Computes the integer value of an enum defined with `offset`, `bitpos` or a numeric `value`.
+    /// `origin` is the feature or extension defining it, which provides the default `extnumber`.
+    pub fn enum_value(&self, value: &xml::EnumValue, origin: Option<Origin>) -> Option<i64> {
+        match &value.value {
+            xml::EnumValueKind::Value(v) => {
+                let v = v.trim_start_matches('(').trim_end_matches(')');
+                match v.strip_prefix("0x") {
+                    Some(hex) => i64::from_str_radix(hex, 16).ok(),
+                    None => v.parse().ok(),
+                }
+            }
+            xml::EnumValueKind::Bitpos(pos) => Some(1 << pos),
+            xml::EnumValueKind::Alias(_) => None,
+            &xml::EnumValueKind::Offset {
+                extnumber,
+                offset,
+                negative,
+            } => {
+                let extnumber = extnumber.or_else(|| match origin? {
+                    Origin::Feature(_) => None,
+                    Origin::Extension(i) => Some(self.registry.extensions[i].number),
+                })?;
+                let value = 1_000_000_000 + (i64::from(extnumber) - 1) * 1000 + i64::from(offset);
+                Some(if negative { -value } else { value })
+            }
+        }
+    }
+}
+
+fn is_api(api: Option<&[String]>) -> bool {
+    match api {
+        Some(api) => api.iter().any(|a| a == API),
+        None => true,
+    }
+}
+
+/// Builds a name lookup table, skipping items that are specific to another API.
+fn index<T>(
+    items: &[T],
+    api: impl Fn(&T) -> Option<&[String]>,
+    name: impl Fn(&T) -> &str,
+) -> HashMap<String, usize> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| is_api(api(item)))
+        .map(|(i, item)| (name(item).to_owned(), i))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = r#"<registry>
+    <platforms>
+        <platform name="win32" protect="VK_USE_PLATFORM_WIN32_KHR" comment="Microsoft Win32 API"/>
+    </platforms>
+    <types>
+        <type category="struct" name="VkFoo" api="vulkansc"/>
+        <type category="struct" name="VkFoo"/>
+        <type category="struct" name="VkFooKHR" alias="VkFoo"/>
+        <type category="struct" name="VkWin32SurfaceCreateInfoKHR"/>
+    </types>
+    <enums name="VkStructureType" type="enum">
+        <enum value="0" name="VK_STRUCTURE_TYPE_APPLICATION_INFO"/>
+    </enums>
+    <feature api="vulkan,vulkansc" name="VK_VERSION_1_0" number="1.0">
+        <require>
+            <type name="VkFoo"/>
+        </require>
+    </feature>
+    <extensions>
+        <extension name="VK_KHR_win32_surface" number="10" type="instance" platform="win32" supported="vulkan">
+            <require>
+                <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_WIN32_SURFACE_CREATE_INFO_KHR"/>
+                <enum offset="1" extends="VkStructureType" extnumber="2" dir="-" name="VK_STRUCTURE_TYPE_NEGATIVE"/>
+                <type name="VkWin32SurfaceCreateInfoKHR"/>
+            </require>
+        </extension>
+        <extension name="VK_KHR_disabled" number="11" supported="disabled">
+            <require>
+                <type name="VkFoo"/>
+            </require>
+        </extension>
+    </extensions>
+</registry>"#;
+
+    #[test]
+    fn lookups() {
+        let analysis = Analysis::parse(REGISTRY, "vk.xml").unwrap();
+
+        let xml::Type::Struct(foo) = analysis.resolve_type("VkFooKHR").unwrap() else {
+            panic!()
+        };
+        assert_eq!(foo.api, None);
+        assert_eq!(analysis.source_location(foo.location), "vk.xml:7:9");
+
+        assert_eq!(analysis.origins("VkFoo"), [Origin::Feature(0)]);
+        assert!(analysis.platform("VkFoo").is_none());
+        assert_eq!(
+            analysis
+                .platform("VkWin32SurfaceCreateInfoKHR")
+                .map(|p| p.protect.as_str()),
+            Some("VK_USE_PLATFORM_WIN32_KHR")
+        );
+
+        let values = analysis
+            .extended_values("VkStructureType")
+            .map(|(origin, value)| analysis.enum_value(value, Some(origin)))
+            .collect::<Vec<_>>();
+        assert_eq!(values, [Some(1_000_009_000), Some(-1_000_001_001)]);
     }
 }
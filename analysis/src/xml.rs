@@ -0,0 +1,1475 @@
+//! Typed representation of the raw registry XML (`vk.xml`).
+//!
+//! This is a faithful, lossless-enough mirror of the XML elements that the generators care
+//! about. No cross-referencing happens here; that is the job of [`crate::Analysis`].
+
+use roxmltree::{Document, Node};
+use std::{cell::RefCell, fmt};
+
+thread_local! {
+    /// Byte offsets of the start of each line of the document being parsed by
+    /// [`Registry::parse()`]. [`Document::text_pos_at()`] rescans the document from the start for
+    /// every node, which is quadratic over all of `vk.xml`.
+    static LINE_STARTS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Position of an element inside the parsed XML document, 1-based.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Location {
+    fn of(node: Node) -> Self {
+        let text = node.document().input_text();
+        let offset = node.range().start;
+        LINE_STARTS.with(|line_starts| {
+            let line_starts = line_starts.borrow();
+            let line = line_starts.partition_point(|&start| start <= offset);
+            let line_start = line.checked_sub(1).map_or(0, |i| line_starts[i]);
+            Self {
+                line: line as u32,
+                column: text[line_start..offset].chars().count() as u32 + 1,
+            }
+        })
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    pub location: Location,
+    pub message: String,
+}
+
+impl Error {
+    fn new(node: Node, message: impl Into<String>) -> Self {
+        Self {
+            location: Location::of(node),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    pub platforms: Vec<Platform>,
+    pub tags: Vec<Tag>,
+    pub types: Vec<Type>,
+    pub enums: Vec<Enums>,
+    pub commands: Vec<Command>,
+    pub command_aliases: Vec<Alias>,
+    pub features: Vec<Feature>,
+    pub extensions: Vec<Extension>,
+}
+
+/// `<platform>`, naming the preprocessor guard (`protect`) of platform-specific extensions.
+#[derive(Clone, Debug)]
+pub struct Platform {
+    pub name: String,
+    pub protect: String,
+    pub comment: Option<String>,
+    pub location: Location,
+}
+
+/// `<tag>`, a vendor suffix such as `KHR` or `NV`.
+#[derive(Clone, Debug)]
+pub struct Tag {
+    pub name: String,
+    pub author: String,
+    pub contact: String,
+    pub location: Location,
+}
+
+#[derive(Clone, Debug)]
+pub enum Type {
+    Include(Include),
+    Define(Define),
+    BaseType(BaseType),
+    Bitmask(Bitmask),
+    Handle(Handle),
+    Enum(EnumDecl),
+    FuncPointer(FuncPointer),
+    Struct(Struct),
+    Union(Struct),
+    /// A type provided by a platform or system header, such as `Display` or `HANDLE`.
+    External(External),
+    Alias(TypeAlias),
+}
+
+impl Type {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Include(x) => &x.name,
+            Self::Define(x) => &x.name,
+            Self::BaseType(x) => &x.name,
+            Self::Bitmask(x) => &x.name,
+            Self::Handle(x) => &x.name,
+            Self::Enum(x) => &x.name,
+            Self::FuncPointer(x) => &x.name,
+            Self::Struct(x) | Self::Union(x) => &x.name,
+            Self::External(x) => &x.name,
+            Self::Alias(x) => &x.name,
+        }
+    }
+
+    pub fn api(&self) -> Option<&[String]> {
+        let api = match self {
+            Self::Include(_) | Self::External(_) | Self::Alias(_) => &None,
+            Self::Define(x) => &x.api,
+            Self::BaseType(x) => &x.api,
+            Self::Bitmask(x) => &x.api,
+            Self::Handle(x) => &x.api,
+            Self::Enum(_) => &None,
+            Self::FuncPointer(x) => &x.api,
+            Self::Struct(x) | Self::Union(x) => &x.api,
+        };
+        api.as_deref()
+    }
+
+    pub fn location(&self) -> Location {
+        match self {
+            Self::Include(x) => x.location,
+            Self::Define(x) => x.location,
+            Self::BaseType(x) => x.location,
+            Self::Bitmask(x) => x.location,
+            Self::Handle(x) => x.location,
+            Self::Enum(x) => x.location,
+            Self::FuncPointer(x) => x.location,
+            Self::Struct(x) | Self::Union(x) => x.location,
+            Self::External(x) => x.location,
+            Self::Alias(x) => x.location,
+        }
+    }
+}
+
+/// `<type category="include">`
+#[derive(Clone, Debug)]
+pub struct Include {
+    pub name: String,
+    /// The header path of the `#include` directive, if any.
+    pub path: Option<String>,
+    pub location: Location,
+}
+
+/// `<type category="define">`, kept as raw C code.
+#[derive(Clone, Debug)]
+pub struct Define {
+    pub name: String,
+    pub api: Option<Vec<String>>,
+    pub requires: Option<String>,
+    pub deprecated: Option<String>,
+    pub code: String,
+    pub location: Location,
+}
+
+/// `<type category="basetype">`, e.g. `typedef uint64_t VkDeviceSize;`. Opaque platform
+/// structs such as `struct ANativeWindow;` have no underlying type.
+#[derive(Clone, Debug)]
+pub struct BaseType {
+    pub name: String,
+    pub api: Option<Vec<String>>,
+    pub ty: Option<CType>,
+    pub location: Location,
+}
+
+/// `<type category="bitmask">`, the `VkFlags`/`VkFlags64` typedef used in structs and commands.
+#[derive(Clone, Debug)]
+pub struct Bitmask {
+    pub name: String,
+    pub api: Option<Vec<String>>,
+    /// `VkFlags` or `VkFlags64`.
+    pub ty: String,
+    /// The `<enums type="bitmask">` block holding the bit values, if there are any.
+    pub bits: Option<String>,
+    pub location: Location,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HandleKind {
+    Dispatchable,
+    NonDispatchable,
+}
+
+/// `<type category="handle">`
+#[derive(Clone, Debug)]
+pub struct Handle {
+    pub name: String,
+    pub api: Option<Vec<String>>,
+    pub kind: HandleKind,
+    pub parent: Vec<String>,
+    /// The matching `VK_OBJECT_TYPE_*` variant.
+    pub object_type: String,
+    pub location: Location,
+}
+
+/// `<type category="enum">`, the declaration whose values live in an [`Enums`] block.
+#[derive(Clone, Debug)]
+pub struct EnumDecl {
+    pub name: String,
+    pub location: Location,
+}
+
+/// `<type category="funcpointer">`
+#[derive(Clone, Debug)]
+pub struct FuncPointer {
+    pub name: String,
+    pub api: Option<Vec<String>>,
+    pub requires: Option<String>,
+    /// `None` for `void`.
+    pub return_type: Option<CType>,
+    pub params: Vec<CDecl>,
+    pub location: Location,
+}
+
+/// `<type category="struct">` or `<type category="union">`
+#[derive(Clone, Debug)]
+pub struct Struct {
+    pub name: String,
+    pub api: Option<Vec<String>>,
+    pub returned_only: bool,
+    pub struct_extends: Vec<String>,
+    pub allow_duplicate: bool,
+    pub comment: Option<String>,
+    pub members: Vec<Member>,
+    pub location: Location,
+}
+
+impl Struct {
+    /// The `VK_STRUCTURE_TYPE_*` value fixed by the `sType` member, if any.
+    pub fn structure_type(&self) -> Option<&str> {
+        self.members
+            .iter()
+            .find(|m| m.decl.name == "sType")
+            .and_then(|m| m.values.as_deref())
+    }
+}
+
+/// `<type requires="...">` without a category.
+#[derive(Clone, Debug)]
+pub struct External {
+    pub name: String,
+    /// The header (or [`Include`]) providing this type.
+    pub requires: Option<String>,
+    pub location: Location,
+}
+
+/// `<type name="..." alias="...">`
+#[derive(Clone, Debug)]
+pub struct TypeAlias {
+    pub name: String,
+    pub alias: String,
+    pub category: Option<String>,
+    pub deprecated: Option<String>,
+    pub location: Location,
+}
+
+/// A parsed C type, such as `const char* const*` or `float[3][4]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CType {
+    /// The base type name, e.g. `char`, `uint32_t` or `VkBuffer`.
+    pub name: String,
+    /// Whether the base type itself is `const`.
+    pub is_const: bool,
+    /// Pointer levels, from the innermost to the outermost.
+    pub pointers: Vec<Pointer>,
+    /// Static array dimensions, from the outermost to the innermost.
+    pub arrays: Vec<ArrayLen>,
+}
+
+impl CType {
+    pub fn is_pointer(&self) -> bool {
+        !self.pointers.is_empty()
+    }
+
+    pub fn is_array(&self) -> bool {
+        !self.arrays.is_empty()
+    }
+
+    /// Whether this is plain `void` (not a pointer to `void`).
+    pub fn is_void(&self) -> bool {
+        self.name == "void" && self.pointers.is_empty()
+    }
+}
+
+impl fmt::Display for CType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_const {
+            f.write_str("const ")?;
+        }
+        f.write_str(&self.name)?;
+        for ptr in &self.pointers {
+            f.write_str(if ptr.is_const { "* const" } else { "*" })?;
+        }
+        for len in &self.arrays {
+            write!(f, "[{len}]")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Pointer {
+    /// Whether the pointer itself (not the pointee) is `const`.
+    pub is_const: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArrayLen {
+    Literal(u32),
+    /// An API constant such as `VK_UUID_SIZE`.
+    Constant(String),
+}
+
+impl fmt::Display for ArrayLen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(n) => write!(f, "{n}"),
+            Self::Constant(name) => f.write_str(name),
+        }
+    }
+}
+
+/// A named C declaration: a struct member, command parameter or function pointer parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CDecl {
+    pub name: String,
+    pub ty: CType,
+    /// Width of a bitfield member, such as `instanceCustomIndex:24`.
+    pub bitfield_width: Option<u8>,
+}
+
+/// One entry of a `len` attribute; there is one entry per level of indirection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Length {
+    NullTerminated,
+    Literal(u32),
+    /// A path to a sibling member or parameter, e.g. `["pAllocateInfo", "descriptorSetCount"]`
+    /// for `pAllocateInfo->descriptorSetCount`.
+    Member(Vec<String>),
+    /// A `latexmath:` expression. The matching C expression lives in `altlen`.
+    Latexmath(String),
+}
+
+impl Length {
+    fn parse_list(s: &str) -> Vec<Self> {
+        // Latexmath expressions can contain commas, and always span the whole attribute.
+        if let Some(expr) = s.strip_prefix("latexmath:") {
+            return vec![Self::Latexmath(expr.to_owned())];
+        }
+        s.split(',').map(Self::parse).collect()
+    }
+
+    fn parse(s: &str) -> Self {
+        if s == "null-terminated" {
+            Self::NullTerminated
+        } else if let Ok(n) = s.parse() {
+            Self::Literal(n)
+        } else {
+            Self::Member(s.split("->").map(str::to_owned).collect())
+        }
+    }
+}
+
+/// Attributes shared by struct members and command parameters.
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub decl: CDecl,
+    pub api: Option<Vec<String>>,
+    /// Fixed values, such as the `VK_STRUCTURE_TYPE_*` of an `sType` member.
+    pub values: Option<String>,
+    pub len: Vec<Length>,
+    pub alt_len: Option<String>,
+    /// One entry per level of indirection, the first applying to the value itself.
+    pub optional: Vec<bool>,
+    pub no_auto_validity: bool,
+    pub extern_sync: Option<String>,
+    /// For unions, the member of the enclosing struct selecting the active field.
+    pub selector: Option<String>,
+    /// For union members, the selector values that activate this field.
+    pub selection: Vec<String>,
+    pub deprecated: Option<String>,
+    pub object_type: Option<String>,
+    /// Structures that may be passed through this `void*` parameter.
+    pub valid_structs: Vec<String>,
+    pub comment: Option<String>,
+    pub location: Location,
+}
+
+impl Member {
+    /// Whether this member may be `0`/`NULL`.
+    pub fn is_optional(&self) -> bool {
+        self.optional.first().copied().unwrap_or(false)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EnumsKind {
+    /// The `API Constants` block.
+    Constants,
+    Enum,
+    Bitmask,
+}
+
+/// `<enums>`
+#[derive(Clone, Debug)]
+pub struct Enums {
+    pub name: String,
+    pub kind: EnumsKind,
+    pub bitwidth: Option<u32>,
+    pub comment: Option<String>,
+    pub values: Vec<EnumValue>,
+    pub location: Location,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EnumValueKind {
+    /// A literal, kept verbatim: `1000001000`, `(~0U)`, `1000.0F` or `"VK_KHR_surface"`.
+    Value(String),
+    Bitpos(u32),
+    Alias(String),
+    /// Extension enum values: `1000000000 + (extnumber - 1) * 1000 + offset`.
+    Offset {
+        extnumber: Option<u32>,
+        offset: u32,
+        negative: bool,
+    },
+}
+
+/// `<enum>`, either inside an `<enums>` block or defined by a `<require>` block.
+#[derive(Clone, Debug)]
+pub struct EnumValue {
+    pub name: String,
+    pub api: Option<Vec<String>>,
+    /// The `<enums>` block this value is added to, if defined inside a `<require>` block.
+    pub extends: Option<String>,
+    pub value: EnumValueKind,
+    /// C type of API constants, e.g. `uint32_t`.
+    pub ty: Option<String>,
+    pub deprecated: Option<String>,
+    pub protect: Option<String>,
+    pub comment: Option<String>,
+    pub location: Location,
+}
+
+/// `<command>` with a full definition.
+#[derive(Clone, Debug)]
+pub struct Command {
+    pub name: String,
+    pub api: Option<Vec<String>>,
+    /// `None` for `void`.
+    pub return_type: Option<CType>,
+    pub params: Vec<Member>,
+    pub success_codes: Vec<String>,
+    pub error_codes: Vec<String>,
+    pub queues: Vec<String>,
+    pub render_pass: Option<String>,
+    pub video_coding: Option<String>,
+    pub cmd_buffer_level: Vec<String>,
+    pub tasks: Vec<String>,
+    pub comment: Option<String>,
+    pub location: Location,
+}
+
+/// `<command name="..." alias="..."/>`
+#[derive(Clone, Debug)]
+pub struct Alias {
+    pub name: String,
+    pub alias: String,
+    pub location: Location,
+}
+
+/// A boolean expression over API version and extension names, as used by `depends`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Depends {
+    Name(String),
+    /// `a+b`
+    All(Vec<Depends>),
+    /// `a,b`
+    Any(Vec<Depends>),
+}
+
+impl Depends {
+    /// Parses `depends` syntax, where `+` is AND, `,` is OR and there is no precedence between
+    /// the two; parentheses must be used to group mixed expressions.
+    pub fn parse(s: &str) -> std::result::Result<Self, String> {
+        let mut rest = s;
+        let expr = Self::parse_expr(&mut rest)?;
+        if rest.is_empty() {
+            Ok(expr)
+        } else {
+            Err(format!(
+                "unexpected `{rest}` in dependency expression `{s}`"
+            ))
+        }
+    }
+
+    fn parse_expr(s: &mut &str) -> std::result::Result<Self, String> {
+        let mut terms = vec![Self::parse_term(s)?];
+        let mut op = None;
+        while let Some(c) = s.chars().next().filter(|c| matches!(c, '+' | ',')) {
+            if matches!(op, Some(op) if op != c) {
+                return Err(format!(
+                    "mixed `+` and `,` without parentheses before `{s}`"
+                ));
+            }
+            op = Some(c);
+            *s = &s[1..];
+            terms.push(Self::parse_term(s)?);
+        }
+        Ok(match op {
+            None => terms.pop().unwrap(),
+            Some('+') => Self::All(terms),
+            Some(_) => Self::Any(terms),
+        })
+    }
+
+    fn parse_term(s: &mut &str) -> std::result::Result<Self, String> {
+        if let Some(inner) = s.strip_prefix('(') {
+            *s = inner;
+            let expr = Self::parse_expr(s)?;
+            *s = s
+                .strip_prefix(')')
+                .ok_or_else(|| format!("expected `)` before `{s}`"))?;
+            Ok(expr)
+        } else {
+            let end = s
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == ':'))
+                .unwrap_or(s.len());
+            if end == 0 {
+                return Err(format!("expected a name before `{s}`"));
+            }
+            let (name, rest) = s.split_at(end);
+            *s = rest;
+            Ok(Self::Name(name.to_owned()))
+        }
+    }
+
+    /// Evaluates the expression, given whether a single name is satisfied.
+    pub fn eval(&self, f: &mut impl FnMut(&str) -> bool) -> bool {
+        match self {
+            Self::Name(name) => f(name),
+            Self::All(terms) => terms.iter().all(|t| t.eval(f)),
+            Self::Any(terms) => terms.iter().any(|t| t.eval(f)),
+        }
+    }
+
+    /// All names referenced by this expression.
+    pub fn names(&self) -> Vec<&str> {
+        match self {
+            Self::Name(name) => vec![name.as_str()],
+            Self::All(terms) | Self::Any(terms) => terms.iter().flat_map(Self::names).collect(),
+        }
+    }
+}
+
+impl fmt::Display for Depends {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (terms, sep) = match self {
+            Self::Name(name) => return f.write_str(name),
+            Self::All(terms) => (terms, '+'),
+            Self::Any(terms) => (terms, ','),
+        };
+        for (i, term) in terms.iter().enumerate() {
+            if i != 0 {
+                write!(f, "{sep}")?;
+            }
+            if matches!(term, Self::Name(_)) {
+                write!(f, "{term}")?;
+            } else {
+                write!(f, "({term})")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum RequireItem {
+    Type { name: String, location: Location },
+    Command { name: String, location: Location },
+    Enum(RequireEnum),
+}
+
+#[derive(Clone, Debug)]
+pub enum RequireEnum {
+    /// `<enum name="..."/>`, referencing a value defined elsewhere.
+    Reference {
+        name: String,
+        location: Location,
+    },
+    Define(EnumValue),
+}
+
+impl RequireEnum {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Reference { name, .. } => name,
+            Self::Define(value) => &value.name,
+        }
+    }
+}
+
+/// `<require>` or `<remove>`
+#[derive(Clone, Debug)]
+pub struct Require {
+    pub api: Option<Vec<String>>,
+    pub depends: Option<Depends>,
+    pub comment: Option<String>,
+    pub items: Vec<RequireItem>,
+    pub location: Location,
+}
+
+/// `<feature>`, a core API version such as `VK_VERSION_1_1`.
+#[derive(Clone, Debug)]
+pub struct Feature {
+    pub name: String,
+    pub api: Vec<String>,
+    pub major: u32,
+    pub minor: u32,
+    pub depends: Option<Depends>,
+    pub comment: Option<String>,
+    pub requires: Vec<Require>,
+    pub removes: Vec<Require>,
+    pub location: Location,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExtensionKind {
+    Instance,
+    Device,
+}
+
+/// `<extension>`
+#[derive(Clone, Debug)]
+pub struct Extension {
+    pub name: String,
+    pub number: u32,
+    pub kind: Option<ExtensionKind>,
+    /// APIs this extension is supported for, or `["disabled"]`.
+    pub supported: Vec<String>,
+    pub depends: Option<Depends>,
+    /// Name of the [`Platform`] guarding this extension.
+    pub platform: Option<String>,
+    pub author: Option<String>,
+    pub contact: Option<String>,
+    pub promoted_to: Option<String>,
+    pub deprecated_by: Option<String>,
+    pub obsoleted_by: Option<String>,
+    pub provisional: bool,
+    pub special_use: Vec<String>,
+    pub comment: Option<String>,
+    pub requires: Vec<Require>,
+    pub removes: Vec<Require>,
+    pub location: Location,
+}
+
+impl Extension {
+    pub fn is_supported(&self, api: &str) -> bool {
+        self.supported.iter().any(|s| s == api)
+    }
+}
+
+impl Registry {
+    /// Parses the contents of `vk.xml`.
+    pub fn parse(input: &str) -> Result<Self> {
+        let doc = Document::parse(input).map_err(|e| {
+            let pos = e.pos();
+            Error {
+                location: Location {
+                    line: pos.row,
+                    column: pos.col,
+                },
+                message: e.to_string(),
+            }
+        })?;
+        LINE_STARTS.with(|line_starts| {
+            *line_starts.borrow_mut() = std::iter::once(0)
+                .chain(input.match_indices('\n').map(|(i, _)| i + 1))
+                .collect();
+        });
+
+        let root = doc.root_element();
+        if root.tag_name().name() != "registry" {
+            return Err(Error::new(root, "expected a <registry> root element"));
+        }
+
+        let mut registry = Self::default();
+        for node in elements(root) {
+            match node.tag_name().name() {
+                "platforms" => {
+                    for node in children_named(node, "platform") {
+                        registry.platforms.push(Platform {
+                            name: required(node, "name")?,
+                            protect: required(node, "protect")?,
+                            comment: optional(node, "comment"),
+                            location: Location::of(node),
+                        });
+                    }
+                }
+                "tags" => {
+                    for node in children_named(node, "tag") {
+                        registry.tags.push(Tag {
+                            name: required(node, "name")?,
+                            author: required(node, "author")?,
+                            contact: required(node, "contact")?,
+                            location: Location::of(node),
+                        });
+                    }
+                }
+                "types" => {
+                    for node in children_named(node, "type") {
+                        registry.types.push(parse_type(node)?);
+                    }
+                }
+                "enums" => registry.enums.push(parse_enums(node)?),
+                "commands" => {
+                    for node in children_named(node, "command") {
+                        if let Some(alias) = node.attribute("alias") {
+                            registry.command_aliases.push(Alias {
+                                name: required(node, "name")?,
+                                alias: alias.to_owned(),
+                                location: Location::of(node),
+                            });
+                        } else {
+                            registry.commands.push(parse_command(node)?);
+                        }
+                    }
+                }
+                "feature" => registry.features.push(parse_feature(node)?),
+                "extensions" => {
+                    for node in children_named(node, "extension") {
+                        registry.extensions.push(parse_extension(node)?);
+                    }
+                }
+                // `<comment>`, `<formats>`, `<spirvextensions>`, `<spirvcapabilities>`, `<sync>`
+                // and friends are not needed by the generators (yet).
+                _ => {}
+            }
+        }
+        Ok(registry)
+    }
+}
+
+fn elements<'a, 'input>(node: Node<'a, 'input>) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(Node::is_element)
+}
+
+fn children_named<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    elements(node).filter(move |n| n.tag_name().name() == name)
+}
+
+fn optional(node: Node, attribute: &str) -> Option<String> {
+    node.attribute(attribute).map(str::to_owned)
+}
+
+fn required(node: Node, attribute: &str) -> Result<String> {
+    optional(node, attribute).ok_or_else(|| {
+        Error::new(
+            node,
+            format!(
+                "<{}> is missing the `{attribute}` attribute",
+                node.tag_name().name()
+            ),
+        )
+    })
+}
+
+fn list(node: Node, attribute: &str) -> Vec<String> {
+    node.attribute(attribute)
+        .map(|s| s.split(',').map(str::to_owned).collect())
+        .unwrap_or_default()
+}
+
+fn api(node: Node) -> Option<Vec<String>> {
+    node.attribute("api")
+        .map(|s| s.split(',').map(str::to_owned).collect())
+}
+
+fn flag(node: Node, attribute: &str) -> bool {
+    node.attribute(attribute) == Some("true")
+}
+
+fn number<T: std::str::FromStr>(node: Node, attribute: &str) -> Result<Option<T>> {
+    node.attribute(attribute)
+        .map(|s| {
+            s.parse().map_err(|_| {
+                Error::new(node, format!("`{attribute}` is not a valid number: `{s}`"))
+            })
+        })
+        .transpose()
+}
+
+fn depends(node: Node) -> Result<Option<Depends>> {
+    node.attribute("depends")
+        .map(|s| Depends::parse(s).map_err(|e| Error::new(node, e)))
+        .transpose()
+}
+
+/// The text of a child element, such as `<name>` or `<type>`.
+fn child_text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    elements(node)
+        .find(|n| n.tag_name().name() == name)
+        .and_then(|n| n.text())
+}
+
+/// All text below `node`, ignoring `<comment>` children.
+fn flat_text(node: Node) -> String {
+    let mut text = String::new();
+    for child in node.children() {
+        if child.is_text() {
+            text.push_str(child.text().unwrap_or_default());
+        } else if child.is_element() && child.tag_name().name() != "comment" {
+            text.push_str(&flat_text(child));
+        }
+    }
+    text
+}
+
+/// The name of a `<type>`, which is either an attribute or a `<name>` child.
+fn type_name(node: Node) -> Result<String> {
+    node.attribute("name")
+        .or_else(|| child_text(node, "name"))
+        .map(str::to_owned)
+        .ok_or_else(|| Error::new(node, "<type> has no name"))
+}
+
+fn parse_type(node: Node) -> Result<Type> {
+    let name = type_name(node)?;
+    let location = Location::of(node);
+    let category = node.attribute("category");
+
+    if let Some(alias) = node.attribute("alias") {
+        return Ok(Type::Alias(TypeAlias {
+            name,
+            alias: alias.to_owned(),
+            category: category.map(str::to_owned),
+            deprecated: optional(node, "deprecated"),
+            location,
+        }));
+    }
+
+    Ok(match category {
+        None => Type::External(External {
+            name,
+            requires: optional(node, "requires"),
+            location,
+        }),
+        Some("include") => {
+            let code = flat_text(node);
+            let path = code
+                .trim()
+                .strip_prefix("#include")
+                .map(|p| p.trim().trim_matches(|c| matches!(c, '"' | '<' | '>')))
+                .map(str::to_owned);
+            Type::Include(Include {
+                name,
+                path,
+                location,
+            })
+        }
+        Some("define") => Type::Define(Define {
+            name,
+            api: api(node),
+            requires: optional(node, "requires"),
+            deprecated: optional(node, "deprecated"),
+            code: flat_text(node),
+            location,
+        }),
+        Some("basetype") => {
+            let ty = child_text(node, "type")
+                .map(|_| {
+                    let text = flat_text(node);
+                    let decl = text
+                        .trim()
+                        .strip_prefix("typedef")
+                        .unwrap_or(&text)
+                        .trim_end_matches(';');
+                    parse_c_decl(decl).map(|decl| decl.ty)
+                })
+                .transpose()
+                .map_err(|e| Error::new(node, e))?;
+            Type::BaseType(BaseType {
+                name,
+                api: api(node),
+                ty,
+                location,
+            })
+        }
+        Some("bitmask") => Type::Bitmask(Bitmask {
+            name,
+            api: api(node),
+            ty: child_text(node, "type")
+                .ok_or_else(|| Error::new(node, "bitmask has no underlying <type>"))?
+                .to_owned(),
+            // 64-bit flags use `bitvalues`, 32-bit ones use `requires`.
+            bits: optional(node, "bitvalues").or_else(|| optional(node, "requires")),
+            location,
+        }),
+        Some("handle") => {
+            let kind = match child_text(node, "type") {
+                Some("VK_DEFINE_HANDLE") => HandleKind::Dispatchable,
+                Some("VK_DEFINE_NON_DISPATCHABLE_HANDLE") => HandleKind::NonDispatchable,
+                other => {
+                    return Err(Error::new(
+                        node,
+                        format!("unknown handle definition macro {other:?}"),
+                    ))
+                }
+            };
+            Type::Handle(Handle {
+                name,
+                api: api(node),
+                kind,
+                parent: list(node, "parent"),
+                object_type: required(node, "objtypeenum")?,
+                location,
+            })
+        }
+        Some("enum") => Type::Enum(EnumDecl { name, location }),
+        Some("funcpointer") => parse_func_pointer(node, name)?,
+        Some(category @ ("struct" | "union")) => {
+            let s = Struct {
+                name,
+                api: api(node),
+                returned_only: flag(node, "returnedonly"),
+                struct_extends: list(node, "structextends"),
+                allow_duplicate: flag(node, "allowduplicate"),
+                comment: optional(node, "comment"),
+                members: children_named(node, "member")
+                    .map(parse_member)
+                    .collect::<Result<_>>()?,
+                location,
+            };
+            if category == "struct" {
+                Type::Struct(s)
+            } else {
+                Type::Union(s)
+            }
+        }
+        Some(other) => return Err(Error::new(node, format!("unknown type category `{other}`"))),
+    })
+}
+
+fn parse_func_pointer(node: Node, name: String) -> Result<Type> {
+    // `typedef void* (VKAPI_PTR *PFN_vkFoo)(const void* pUserData, size_t size);`
+    let text = flat_text(node);
+    let err = |msg: &str| Error::new(node, format!("{msg} in function pointer `{text}`"));
+
+    let (ret, rest) = text
+        .split_once("(VKAPI_PTR *")
+        .ok_or_else(|| err("missing `(VKAPI_PTR *`"))?;
+    let ret = ret
+        .trim()
+        .strip_prefix("typedef")
+        .ok_or_else(|| err("missing `typedef`"))?;
+    let return_type = parse_c_type(ret).map_err(|e| err(&e))?;
+    let return_type = (!return_type.is_void()).then_some(return_type);
+
+    let params = rest
+        .trim()
+        .strip_prefix(name.as_str())
+        .and_then(|s| s.trim_start().strip_prefix(")("))
+        .and_then(|s| s.trim_end().strip_suffix(");"))
+        .ok_or_else(|| err("malformed parameter list"))?;
+    let params = if params.trim() == "void" {
+        vec![]
+    } else {
+        params
+            .split(',')
+            .map(parse_c_decl)
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|e| err(&e))?
+    };
+
+    Ok(Type::FuncPointer(FuncPointer {
+        name,
+        api: api(node),
+        requires: optional(node, "requires"),
+        return_type,
+        params,
+        location: Location::of(node),
+    }))
+}
+
+/// Parses `<member>` and `<param>` elements.
+fn parse_member(node: Node) -> Result<Member> {
+    let decl = parse_c_decl(&flat_text(node)).map_err(|e| Error::new(node, e))?;
+    if child_text(node, "name") != Some(decl.name.as_str()) {
+        return Err(Error::new(
+            node,
+            format!("parsed name `{}` does not match <name>", decl.name),
+        ));
+    }
+    Ok(Member {
+        decl,
+        api: api(node),
+        values: optional(node, "values"),
+        len: node
+            .attribute("len")
+            .map(Length::parse_list)
+            .unwrap_or_default(),
+        alt_len: optional(node, "altlen"),
+        optional: node
+            .attribute("optional")
+            .map(|s| s.split(',').map(|o| o == "true").collect())
+            .unwrap_or_default(),
+        no_auto_validity: flag(node, "noautovalidity"),
+        extern_sync: optional(node, "externsync"),
+        selector: optional(node, "selector"),
+        selection: list(node, "selection"),
+        deprecated: optional(node, "deprecated"),
+        object_type: optional(node, "objecttype"),
+        valid_structs: list(node, "validstructs"),
+        comment: child_text(node, "comment").map(str::to_owned),
+        location: Location::of(node),
+    })
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Ident(&'a str),
+    Number(u32),
+    Punct(char),
+}
+
+fn tokenize(s: &str) -> std::result::Result<Vec<Token<'_>>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Ident(&rest[..end]));
+            rest = &rest[end..];
+        } else if c.is_ascii_digit() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let n = rest[..end]
+                .parse()
+                .map_err(|_| format!("number out of range in `{s}`"))?;
+            tokens.push(Token::Number(n));
+            rest = &rest[end..];
+        } else if matches!(c, '*' | '[' | ']' | ':') {
+            tokens.push(Token::Punct(c));
+            rest = &rest[1..];
+        } else {
+            return Err(format!("unexpected character `{c}` in `{s}`"));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Parses a C type without a declarator name, e.g. `const void*`.
+fn parse_c_type(s: &str) -> std::result::Result<CType, String> {
+    let tokens = tokenize(s)?;
+    let mut tokens = tokens.iter().peekable();
+    let ty = parse_type_tokens(&mut tokens, s)?;
+    match tokens.next() {
+        None => Ok(ty),
+        Some(t) => Err(format!("unexpected {t:?} in `{s}`")),
+    }
+}
+
+fn parse_type_tokens<'a>(
+    tokens: &mut std::iter::Peekable<impl Iterator<Item = &'a Token<'a>>>,
+    s: &str,
+) -> std::result::Result<CType, String> {
+    let mut is_const = false;
+    let name = loop {
+        match tokens.next() {
+            Some(Token::Ident("const")) => is_const = true,
+            Some(Token::Ident("struct")) => {}
+            Some(Token::Ident(name)) => break *name,
+            t => return Err(format!("expected a type name, found {t:?} in `{s}`")),
+        }
+    };
+    let mut pointers: Vec<Pointer> = Vec::new();
+    loop {
+        match tokens.peek() {
+            Some(Token::Punct('*')) => pointers.push(Pointer { is_const: false }),
+            Some(Token::Ident("const")) => match pointers.last_mut() {
+                Some(ptr) => ptr.is_const = true,
+                None => is_const = true,
+            },
+            _ => break,
+        }
+        tokens.next();
+    }
+    Ok(CType {
+        name: name.to_owned(),
+        is_const,
+        pointers,
+        arrays: Vec::new(),
+    })
+}
+
+/// Parses a named C declaration such as `const char* const* ppEnabledLayerNames`,
+/// `float matrix[3][4]` or `uint32_t mask:8`.
+fn parse_c_decl(s: &str) -> std::result::Result<CDecl, String> {
+    let tokens = tokenize(s)?;
+    let mut tokens = tokens.iter().peekable();
+    let mut ty = parse_type_tokens(&mut tokens, s)?;
+    let name = match tokens.next() {
+        Some(Token::Ident(name)) => (*name).to_owned(),
+        t => return Err(format!("expected a declarator name, found {t:?} in `{s}`")),
+    };
+    let mut bitfield_width = None;
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Punct('[') => {
+                let len = match tokens.next() {
+                    Some(Token::Number(n)) => ArrayLen::Literal(*n),
+                    Some(Token::Ident(c)) => ArrayLen::Constant((*c).to_owned()),
+                    t => return Err(format!("expected an array length, found {t:?} in `{s}`")),
+                };
+                if tokens.next() != Some(&Token::Punct(']')) {
+                    return Err(format!("expected `]` in `{s}`"));
+                }
+                ty.arrays.push(len);
+            }
+            Token::Punct(':') => match tokens.next() {
+                Some(Token::Number(n)) => {
+                    bitfield_width =
+                        Some(u8::try_from(*n).map_err(|_| format!("bitfield too wide in `{s}`"))?)
+                }
+                t => return Err(format!("expected a bitfield width, found {t:?} in `{s}`")),
+            },
+            t => return Err(format!("unexpected {t:?} in `{s}`")),
+        }
+    }
+    Ok(CDecl {
+        name,
+        ty,
+        bitfield_width,
+    })
+}
+
+fn parse_enums(node: Node) -> Result<Enums> {
+    let name = required(node, "name")?;
+    let kind = match node.attribute("type") {
+        // Older registries did not mark the `API Constants` block explicitly.
+        Some("constants") | None => EnumsKind::Constants,
+        Some("enum") => EnumsKind::Enum,
+        Some("bitmask") => EnumsKind::Bitmask,
+        Some(other) => return Err(Error::new(node, format!("unknown enums type `{other}`"))),
+    };
+    Ok(Enums {
+        name,
+        kind,
+        bitwidth: number(node, "bitwidth")?,
+        comment: optional(node, "comment"),
+        values: children_named(node, "enum")
+            .map(|node| {
+                parse_enum_value(node)?.ok_or_else(|| Error::new(node, "<enum> has no value"))
+            })
+            .collect::<Result<_>>()?,
+        location: Location::of(node),
+    })
+}
+
+/// Returns `None` for `<enum>` elements that only reference a value defined elsewhere.
+fn parse_enum_value(node: Node) -> Result<Option<EnumValue>> {
+    let value = if let Some(value) = node.attribute("value") {
+        EnumValueKind::Value(value.to_owned())
+    } else if let Some(bitpos) = number(node, "bitpos")? {
+        EnumValueKind::Bitpos(bitpos)
+    } else if let Some(alias) = node.attribute("alias") {
+        EnumValueKind::Alias(alias.to_owned())
+    } else if let Some(offset) = number(node, "offset")? {
+        EnumValueKind::Offset {
+            extnumber: number(node, "extnumber")?,
+            offset,
+            negative: node.attribute("dir") == Some("-"),
+        }
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(EnumValue {
+        name: required(node, "name")?,
+        api: api(node),
+        extends: optional(node, "extends"),
+        value,
+        ty: optional(node, "type"),
+        deprecated: optional(node, "deprecated"),
+        protect: optional(node, "protect"),
+        comment: optional(node, "comment"),
+        location: Location::of(node),
+    }))
+}
+
+fn parse_command(node: Node) -> Result<Command> {
+    let proto = children_named(node, "proto")
+        .next()
+        .ok_or_else(|| Error::new(node, "<command> has no <proto>"))?;
+    let decl = parse_c_decl(&flat_text(proto)).map_err(|e| Error::new(proto, e))?;
+    Ok(Command {
+        name: decl.name,
+        api: api(node),
+        return_type: (!decl.ty.is_void()).then_some(decl.ty),
+        params: children_named(node, "param")
+            .map(parse_member)
+            .collect::<Result<_>>()?,
+        success_codes: list(node, "successcodes"),
+        error_codes: list(node, "errorcodes"),
+        queues: list(node, "queues"),
+        render_pass: optional(node, "renderpass"),
+        video_coding: optional(node, "videocoding"),
+        cmd_buffer_level: list(node, "cmdbufferlevel"),
+        tasks: list(node, "tasks"),
+        comment: optional(node, "comment"),
+        location: Location::of(node),
+    })
+}
+
+fn parse_requires(node: Node, name: &'static str) -> Result<Vec<Require>> {
+    children_named(node, name)
+        .map(|node| {
+            let mut items = Vec::new();
+            for item in elements(node) {
+                let location = Location::of(item);
+                match item.tag_name().name() {
+                    "type" => items.push(RequireItem::Type {
+                        name: required(item, "name")?,
+                        location,
+                    }),
+                    "command" => items.push(RequireItem::Command {
+                        name: required(item, "name")?,
+                        location,
+                    }),
+                    "enum" => items.push(RequireItem::Enum(match parse_enum_value(item)? {
+                        Some(value) => RequireEnum::Define(value),
+                        None => RequireEnum::Reference {
+                            name: required(item, "name")?,
+                            location,
+                        },
+                    })),
+                    _ => {}
+                }
+            }
+            Ok(Require {
+                api: api(node),
+                depends: depends(node)?,
+                comment: optional(node, "comment"),
+                items,
+                location: Location::of(node),
+            })
+        })
+        .collect()
+}
+
+fn parse_feature(node: Node) -> Result<Feature> {
+    let version = required(node, "number")?;
+    let (major, minor) = version
+        .split_once('.')
+        .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)))
+        .ok_or_else(|| Error::new(node, format!("invalid feature version `{version}`")))?;
+    Ok(Feature {
+        name: required(node, "name")?,
+        api: list(node, "api"),
+        major,
+        minor,
+        depends: depends(node)?,
+        comment: optional(node, "comment"),
+        requires: parse_requires(node, "require")?,
+        removes: parse_requires(node, "remove")?,
+        location: Location::of(node),
+    })
+}
+
+fn parse_extension(node: Node) -> Result<Extension> {
+    let kind = match node.attribute("type") {
+        None => None,
+        Some("instance") => Some(ExtensionKind::Instance),
+        Some("device") => Some(ExtensionKind::Device),
+        Some(other) => {
+            return Err(Error::new(
+                node,
+                format!("unknown extension type `{other}`"),
+            ))
+        }
+    };
+    Ok(Extension {
+        name: required(node, "name")?,
+        number: number(node, "number")?
+            .ok_or_else(|| Error::new(node, "<extension> is missing the `number` attribute"))?,
+        kind,
+        supported: list(node, "supported"),
+        depends: depends(node)?,
+        platform: optional(node, "platform"),
+        author: optional(node, "author"),
+        contact: optional(node, "contact"),
+        promoted_to: optional(node, "promotedto"),
+        deprecated_by: optional(node, "deprecatedby"),
+        obsoleted_by: optional(node, "obsoletedby"),
+        provisional: flag(node, "provisional"),
+        special_use: list(node, "specialuse"),
+        comment: optional(node, "comment"),
+        requires: parse_requires(node, "require")?,
+        removes: parse_requires(node, "remove")?,
+        location: Location::of(node),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn c_declarations() {
+        let decl = parse_c_decl("const char* const* ppEnabledLayerNames").unwrap();
+        assert_eq!(decl.name, "ppEnabledLayerNames");
+        assert_eq!(decl.ty.to_string(), "const char* const*");
+        assert!(decl.ty.is_const);
+        assert_eq!(
+            decl.ty.pointers,
+            [Pointer { is_const: true }, Pointer { is_const: false }]
+        );
+
+        let decl = parse_c_decl("float matrix[3][VK_UUID_SIZE]").unwrap();
+        assert_eq!(
+            decl.ty.arrays,
+            [
+                ArrayLen::Literal(3),
+                ArrayLen::Constant("VK_UUID_SIZE".into())
+            ]
+        );
+
+        let decl = parse_c_decl("uint32_t instanceCustomIndex:24").unwrap();
+        assert_eq!(decl.bitfield_width, Some(24));
+
+        let decl = parse_c_decl("struct VkBaseOutStructure* pNext").unwrap();
+        assert_eq!(decl.ty.name, "VkBaseOutStructure");
+        assert!(!decl.ty.is_const);
+    }
+
+    #[test]
+    fn depends_expressions() {
+        let depends = Depends::parse("(VK_KHR_a,VK_VERSION_1_1)+VK_KHR_b").unwrap();
+        assert_eq!(
+            depends,
+            Depends::All(vec![
+                Depends::Any(vec![
+                    Depends::Name("VK_KHR_a".into()),
+                    Depends::Name("VK_VERSION_1_1".into()),
+                ]),
+                Depends::Name("VK_KHR_b".into()),
+            ])
+        );
+        assert_eq!(depends.to_string(), "(VK_KHR_a,VK_VERSION_1_1)+VK_KHR_b");
+        assert!(depends.eval(&mut |name| name != "VK_KHR_a"));
+        assert!(!depends.eval(&mut |name| name == "VK_KHR_b"));
+        assert!(Depends::parse("VK_KHR_a,VK_KHR_b+VK_KHR_c").is_err());
+    }
+
+    #[test]
+    fn registry() {
+        let registry = Registry::parse(
+            r#"<registry>
+    <types>
+        <type category="handle" parent="VkInstance" objtypeenum="VK_OBJECT_TYPE_DEVICE"><type>VK_DEFINE_HANDLE</type>(<name>VkDevice</name>)</type>
+        <type category="funcpointer">typedef void (VKAPI_PTR *<name>PFN_vkVoidFunction</name>)(void);</type>
+        <type category="funcpointer" requires="VkInternalAllocationType">typedef void (VKAPI_PTR *<name>PFN_vkInternalFreeNotification</name>)(
+    <type>void</type>*                                       pUserData,
+    <type>size_t</type>                                      size);</type>
+        <type category="struct" name="VkDeviceCreateInfo">
+            <member values="VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO"><type>VkStructureType</type> <name>sType</name></member>
+            <member optional="true">const <type>void</type>*     <name>pNext</name></member>
+            <member optional="true"><type>uint32_t</type>               <name>enabledExtensionCount</name></member>
+            <member len="enabledExtensionCount,null-terminated">const <type>char</type>* const*      <name>ppEnabledExtensionNames</name></member>
+        </type>
+        <type category="struct" name="VkDeviceCreateInfoKHR" alias="VkDeviceCreateInfo"/>
+    </types>
+    <commands>
+        <command successcodes="VK_SUCCESS,VK_TIMEOUT" errorcodes="VK_ERROR_DEVICE_LOST">
+            <proto><type>VkResult</type> <name>vkWaitForFences</name></proto>
+            <param><type>VkDevice</type> <name>device</name></param>
+            <param><type>uint32_t</type> <name>fenceCount</name></param>
+            <param len="fenceCount">const <type>VkFence</type>* <name>pFences</name></param>
+        </command>
+        <command name="vkWaitForFencesKHR" alias="vkWaitForFences"/>
+    </commands>
+    <extensions>
+        <extension name="VK_KHR_win32_surface" number="10" type="instance" depends="VK_KHR_surface" platform="win32" supported="vulkan">
+            <require>
+                <enum value="6" name="VK_KHR_WIN32_SURFACE_SPEC_VERSION"/>
+                <enum offset="0" extends="VkStructureType" name="VK_STRUCTURE_TYPE_WIN32_SURFACE_CREATE_INFO_KHR"/>
+                <command name="vkWaitForFences"/>
+            </require>
+        </extension>
+    </extensions>
+</registry>"#,
+        )
+        .unwrap();
+
+        let Type::Struct(s) = &registry.types[3] else {
+            panic!()
+        };
+        assert_eq!(
+            s.structure_type(),
+            Some("VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO")
+        );
+        assert_eq!(
+            s.members[3].len,
+            [
+                Length::Member(vec!["enabledExtensionCount".into()]),
+                Length::NullTerminated
+            ]
+        );
+        assert!(s.members[2].is_optional());
+        assert_eq!(s.location, Location { line: 8, column: 9 });
+
+        let Type::FuncPointer(f) = &registry.types[2] else {
+            panic!()
+        };
+        assert_eq!(f.params.len(), 2);
+        assert_eq!(f.params[0].ty.to_string(), "void*");
+
+        let command = &registry.commands[0];
+        assert_eq!(command.name, "vkWaitForFences");
+        assert_eq!(command.success_codes, ["VK_SUCCESS", "VK_TIMEOUT"]);
+        assert_eq!(registry.command_aliases[0].alias, "vkWaitForFences");
+
+        let extension = &registry.extensions[0];
+        assert_eq!(extension.platform.as_deref(), Some("win32"));
+        assert_eq!(extension.kind, Some(ExtensionKind::Instance));
+        assert!(matches!(
+            &extension.requires[0].items[1],
+            RequireItem::Enum(RequireEnum::Define(EnumValue {
+                value: EnumValueKind::Offset { offset: 0, .. },
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn error_location() {
+        let err = Registry::parse("<registry>\n  <types>\n    <type category=\"bogus\" name=\"x\"/>\n  </types>\n</registry>")
+            .unwrap_err();
+        assert_eq!(err.location, Location { line: 3, column: 5 });
+        assert_eq!(err.to_string(), "3:5: unknown type category `bogus`");
+    }
+}
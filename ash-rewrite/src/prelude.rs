@@ -0,0 +1,104 @@
+use crate::vk;
+use std::fmt;
+
+/// Returns `Err(())` if `api_version` is below `required`, ignoring patch versions.
+pub(crate) fn require_version(api_version: u32, required: u32) -> Result<(), ()> {
+    let minor = |version| {
+        vk::make_api_version(
+            0,
+            vk::api_version_major(version),
+            vk::api_version_minor(version),
+            0,
+        )
+    };
+    if minor(api_version) >= minor(required) {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+/// The known values of an enum or flags type, as their raw value, the name of their constant and
+/// their name in the Vulkan specification.
+pub(crate) type Known<Value> = &'static [(Value, &'static str, &'static str)];
+
+pub(crate) fn debug_enum(f: &mut fmt::Formatter, known: Known<i32>, value: i32) -> fmt::Result {
+    match known.iter().find(|&&(known, _, _)| known == value) {
+        Some(&(_, name, _)) => f.write_str(name),
+        None => fmt::Debug::fmt(&value, f),
+    }
+}
+
+#[cfg(feature = "debug")]
+pub(crate) fn debug_flags<Value: Into<u64> + Copy>(
+    f: &mut fmt::Formatter,
+    known: Known<Value>,
+    value: Value,
+) -> fmt::Result {
+    let mut first = true;
+    let mut accum = value.into();
+    for &(bit, name, _) in known {
+        let bit = bit.into();
+        if bit != 0 && accum & bit == bit {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            f.write_str(name)?;
+            first = false;
+            accum &= !bit;
+        }
+    }
+    if accum != 0 {
+        if !first {
+            f.write_str(" | ")?;
+        }
+        write!(f, "{accum:b}")?;
+    }
+    Ok(())
+}
+
+/// Parses the value of an enum from the name of its constant, its name in the Vulkan
+/// specification or its raw value.
+pub(crate) fn parse_enum(
+    type_name: &'static str,
+    known: Known<i32>,
+    s: &str,
+) -> Result<i32, vk::ParseValueError> {
+    let s = s.trim();
+    known
+        .iter()
+        .find(|&&(_, name, spec_name)| s == name || s == spec_name)
+        .map(|&(value, _, _)| value)
+        .or_else(|| s.parse().ok())
+        .ok_or_else(|| vk::ParseValueError::new(type_name, s))
+}
+
+/// Parses flags from the names or raw values of their bits, separated by `|`.
+pub(crate) fn parse_flags<Value: Into<u64> + TryFrom<u64> + Copy>(
+    type_name: &'static str,
+    known: Known<Value>,
+    s: &str,
+) -> Result<Value, vk::ParseValueError> {
+    let mut bits = 0;
+    for s in s.split('|').map(str::trim).filter(|s| !s.is_empty()) {
+        let known = known
+            .iter()
+            .find(|&&(_, name, spec_name)| s == name || s == spec_name);
+        bits |= match known {
+            Some(&(bit, _, _)) => bit.into(),
+            None => parse_bits(s).ok_or_else(|| vk::ParseValueError::new(type_name, s))?,
+        };
+    }
+    Value::try_from(bits).map_err(|_| vk::ParseValueError::new(type_name, s.trim()))
+}
+
+/// Parses a raw flags value, in hexadecimal or binary with a `0x` or `0b` prefix, or in decimal.
+fn parse_bits(s: &str) -> Option<u64> {
+    if let Some(hex) = s.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16).ok()
+    } else if let Some(binary) = s.strip_prefix("0b") {
+        u64::from_str_radix(binary, 2).ok()
+    } else {
+        s.parse().ok()
+    }
+}
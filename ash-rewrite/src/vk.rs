@@ -0,0 +1,73 @@
+#![allow(
+    clippy::too_many_arguments,
+    clippy::cognitive_complexity,
+    clippy::wrong_self_convention
+)]
+#[macro_use]
+mod macros;
+pub use macros::*;
+mod aliases;
+pub use aliases::*;
+mod bitflags;
+pub use bitflags::*;
+#[cfg(feature = "debug")]
+mod const_debugs;
+mod constants;
+pub use constants::*;
+mod definitions;
+pub use definitions::*;
+mod enums;
+pub use enums::*;
+mod extensions;
+pub use extensions::*;
+mod feature_extensions;
+pub use feature_extensions::*;
+mod features;
+pub use features::*;
+mod prelude;
+pub use prelude::*;
+/// Native bindings from Vulkan headers, generated by bindgen
+#[allow(clippy::useless_transmute, nonstandard_style)]
+pub mod native;
+mod platform_types;
+pub use platform_types::*;
+/// Iterates through the pointer chain. Includes the item that is passed into the function.
+/// Stops at the last [`BaseOutStructure`] that has a null [`BaseOutStructure::p_next`] field.
+pub(crate) unsafe fn ptr_chain_iter<T>(ptr: &mut T) -> impl Iterator<Item = *mut BaseOutStructure> {
+    let ptr = <*mut T>::cast::<BaseOutStructure>(ptr);
+    (0..).scan(ptr, |p_ptr, _| {
+        if p_ptr.is_null() {
+            return None;
+        }
+        let n_ptr = (**p_ptr).p_next;
+        let old = *p_ptr;
+        *p_ptr = n_ptr;
+        Some(old)
+    })
+}
+/// Reads a fixed-size string member up to its first nul character, or returns `None` if it is not
+/// nul-terminated.
+pub(crate) fn wrap_c_str_slice_until_nul(str: &[std::os::raw::c_char]) -> Option<&std::ffi::CStr> {
+    // SAFETY: `c_char` is either `i8` or `u8`, with the layout of `u8`
+    let bytes = unsafe { std::slice::from_raw_parts(str.as_ptr().cast::<u8>(), str.len()) };
+    let nul = bytes.iter().position(|&c| c == 0)?;
+    std::ffi::CStr::from_bytes_with_nul(&bytes[..=nul]).ok()
+}
+pub trait Handle: Sized {
+    const TYPE: ObjectType;
+    fn as_raw(self) -> u64;
+    fn from_raw(_: u64) -> Self;
+
+    /// Returns whether the handle is a `NULL` value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ash_rewrite::vk::{Handle, Instance};
+    /// let instance = Instance::null();
+    /// assert!(instance.is_null());
+    /// ```
+    fn is_null(self) -> bool {
+        self.as_raw() == 0
+    }
+}
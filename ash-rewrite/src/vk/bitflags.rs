@@ -0,0 +1,10679 @@
+use crate::prelude::{parse_flags, Known};
+use crate::vk::definitions::*;
+use crate::vk::ParseValueError;
+use std::str::FromStr;
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineCacheCreateFlagBits.html>"]
+pub struct PipelineCacheCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(PipelineCacheCreateFlags, Flags);
+impl PipelineCacheCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueueFlagBits.html>"]
+pub struct QueueFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(QueueFlags, Flags);
+impl QueueFlags {
+    #[doc = "Queue supports graphics operations"]
+    pub const GRAPHICS: Self = Self(0b1);
+    #[doc = "Queue supports compute operations"]
+    pub const COMPUTE: Self = Self(0b10);
+    #[doc = "Queue supports transfer operations"]
+    pub const TRANSFER: Self = Self(0b100);
+    #[doc = "Queue supports sparse resource memory management operations"]
+    pub const SPARSE_BINDING: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCullModeFlagBits.html>"]
+pub struct CullModeFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(CullModeFlags, Flags);
+impl CullModeFlags {
+    pub const NONE: Self = Self(0);
+    pub const FRONT: Self = Self(0b1);
+    pub const BACK: Self = Self(0b10);
+    pub const FRONT_AND_BACK: Self = Self(0x0000_0003);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkRenderPassCreateFlagBits.html>"]
+pub struct RenderPassCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(RenderPassCreateFlags, Flags);
+impl RenderPassCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceQueueCreateFlagBits.html>"]
+pub struct DeviceQueueCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(DeviceQueueCreateFlags, Flags);
+impl DeviceQueueCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryPropertyFlagBits.html>"]
+pub struct MemoryPropertyFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(MemoryPropertyFlags, Flags);
+impl MemoryPropertyFlags {
+    #[doc = "If otherwise stated, then allocate memory on device"]
+    pub const DEVICE_LOCAL: Self = Self(0b1);
+    #[doc = "Memory is mappable by host"]
+    pub const HOST_VISIBLE: Self = Self(0b10);
+    #[doc = "Memory will have i/o coherency. If not set, application may need to use vkFlushMappedMemoryRanges and vkInvalidateMappedMemoryRanges to flush/invalidate host cache"]
+    pub const HOST_COHERENT: Self = Self(0b100);
+    #[doc = "Memory will be cached by the host"]
+    pub const HOST_CACHED: Self = Self(0b1000);
+    #[doc = "Memory may be allocated by the driver when it is required"]
+    pub const LAZILY_ALLOCATED: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryHeapFlagBits.html>"]
+pub struct MemoryHeapFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(MemoryHeapFlags, Flags);
+impl MemoryHeapFlags {
+    #[doc = "If set, heap represents device memory"]
+    pub const DEVICE_LOCAL: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccessFlagBits.html>"]
+pub struct AccessFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(AccessFlags, Flags);
+impl AccessFlags {
+    #[doc = "Controls coherency of indirect command reads"]
+    pub const INDIRECT_COMMAND_READ: Self = Self(0b1);
+    #[doc = "Controls coherency of index reads"]
+    pub const INDEX_READ: Self = Self(0b10);
+    #[doc = "Controls coherency of vertex attribute reads"]
+    pub const VERTEX_ATTRIBUTE_READ: Self = Self(0b100);
+    #[doc = "Controls coherency of uniform buffer reads"]
+    pub const UNIFORM_READ: Self = Self(0b1000);
+    #[doc = "Controls coherency of input attachment reads"]
+    pub const INPUT_ATTACHMENT_READ: Self = Self(0b1_0000);
+    #[doc = "Controls coherency of shader reads"]
+    pub const SHADER_READ: Self = Self(0b10_0000);
+    #[doc = "Controls coherency of shader writes"]
+    pub const SHADER_WRITE: Self = Self(0b100_0000);
+    #[doc = "Controls coherency of color attachment reads"]
+    pub const COLOR_ATTACHMENT_READ: Self = Self(0b1000_0000);
+    #[doc = "Controls coherency of color attachment writes"]
+    pub const COLOR_ATTACHMENT_WRITE: Self = Self(0b1_0000_0000);
+    #[doc = "Controls coherency of depth/stencil attachment reads"]
+    pub const DEPTH_STENCIL_ATTACHMENT_READ: Self = Self(0b10_0000_0000);
+    #[doc = "Controls coherency of depth/stencil attachment writes"]
+    pub const DEPTH_STENCIL_ATTACHMENT_WRITE: Self = Self(0b100_0000_0000);
+    #[doc = "Controls coherency of transfer reads"]
+    pub const TRANSFER_READ: Self = Self(0b1000_0000_0000);
+    #[doc = "Controls coherency of transfer writes"]
+    pub const TRANSFER_WRITE: Self = Self(0b1_0000_0000_0000);
+    #[doc = "Controls coherency of host reads"]
+    pub const HOST_READ: Self = Self(0b10_0000_0000_0000);
+    #[doc = "Controls coherency of host writes"]
+    pub const HOST_WRITE: Self = Self(0b100_0000_0000_0000);
+    #[doc = "Controls coherency of memory reads"]
+    pub const MEMORY_READ: Self = Self(0b1000_0000_0000_0000);
+    #[doc = "Controls coherency of memory writes"]
+    pub const MEMORY_WRITE: Self = Self(0b1_0000_0000_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBufferUsageFlagBits.html>"]
+pub struct BufferUsageFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(BufferUsageFlags, Flags);
+impl BufferUsageFlags {
+    #[doc = "Can be used as a source of transfer operations"]
+    pub const TRANSFER_SRC: Self = Self(0b1);
+    #[doc = "Can be used as a destination of transfer operations"]
+    pub const TRANSFER_DST: Self = Self(0b10);
+    #[doc = "Can be used as TBO"]
+    pub const UNIFORM_TEXEL_BUFFER: Self = Self(0b100);
+    #[doc = "Can be used as IBO"]
+    pub const STORAGE_TEXEL_BUFFER: Self = Self(0b1000);
+    #[doc = "Can be used as UBO"]
+    pub const UNIFORM_BUFFER: Self = Self(0b1_0000);
+    #[doc = "Can be used as SSBO"]
+    pub const STORAGE_BUFFER: Self = Self(0b10_0000);
+    #[doc = "Can be used as source of fixed-function index fetch (index buffer)"]
+    pub const INDEX_BUFFER: Self = Self(0b100_0000);
+    #[doc = "Can be used as source of fixed-function vertex fetch (VBO)"]
+    pub const VERTEX_BUFFER: Self = Self(0b1000_0000);
+    #[doc = "Can be the source of indirect parameters (e.g. indirect buffer, parameter buffer)"]
+    pub const INDIRECT_BUFFER: Self = Self(0b1_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBufferCreateFlagBits.html>"]
+pub struct BufferCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(BufferCreateFlags, Flags);
+impl BufferCreateFlags {
+    #[doc = "Buffer should support sparse backing"]
+    pub const SPARSE_BINDING: Self = Self(0b1);
+    #[doc = "Buffer should support sparse backing with partial residency"]
+    pub const SPARSE_RESIDENCY: Self = Self(0b10);
+    #[doc = "Buffer should support constant data access to physical memory ranges mapped into multiple locations of sparse buffers"]
+    pub const SPARSE_ALIASED: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderStageFlagBits.html>"]
+pub struct ShaderStageFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ShaderStageFlags, Flags);
+impl ShaderStageFlags {
+    pub const VERTEX: Self = Self(0b1);
+    pub const TESSELLATION_CONTROL: Self = Self(0b10);
+    pub const TESSELLATION_EVALUATION: Self = Self(0b100);
+    pub const GEOMETRY: Self = Self(0b1000);
+    pub const FRAGMENT: Self = Self(0b1_0000);
+    pub const COMPUTE: Self = Self(0b10_0000);
+    pub const ALL_GRAPHICS: Self = Self(0x0000_001F);
+    pub const ALL: Self = Self(0x7FFF_FFFF);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageUsageFlagBits.html>"]
+pub struct ImageUsageFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ImageUsageFlags, Flags);
+impl ImageUsageFlags {
+    #[doc = "Can be used as a source of transfer operations"]
+    pub const TRANSFER_SRC: Self = Self(0b1);
+    #[doc = "Can be used as a destination of transfer operations"]
+    pub const TRANSFER_DST: Self = Self(0b10);
+    #[doc = "Can be sampled from (SAMPLED_IMAGE and COMBINED_IMAGE_SAMPLER descriptor types)"]
+    pub const SAMPLED: Self = Self(0b100);
+    #[doc = "Can be used as storage image (STORAGE_IMAGE descriptor type)"]
+    pub const STORAGE: Self = Self(0b1000);
+    #[doc = "Can be used as framebuffer color attachment"]
+    pub const COLOR_ATTACHMENT: Self = Self(0b1_0000);
+    #[doc = "Can be used as framebuffer depth/stencil attachment"]
+    pub const DEPTH_STENCIL_ATTACHMENT: Self = Self(0b10_0000);
+    #[doc = "Image data not needed outside of rendering"]
+    pub const TRANSIENT_ATTACHMENT: Self = Self(0b100_0000);
+    #[doc = "Can be used as framebuffer input attachment"]
+    pub const INPUT_ATTACHMENT: Self = Self(0b1000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageCreateFlagBits.html>"]
+pub struct ImageCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ImageCreateFlags, Flags);
+impl ImageCreateFlags {
+    #[doc = "Image should support sparse backing"]
+    pub const SPARSE_BINDING: Self = Self(0b1);
+    #[doc = "Image should support sparse backing with partial residency"]
+    pub const SPARSE_RESIDENCY: Self = Self(0b10);
+    #[doc = "Image should support constant data access to physical memory ranges mapped into multiple locations of sparse images"]
+    pub const SPARSE_ALIASED: Self = Self(0b100);
+    #[doc = "Allows image views to have different format than the base image"]
+    pub const MUTABLE_FORMAT: Self = Self(0b1000);
+    #[doc = "Allows creating image views with cube type from the created image"]
+    pub const CUBE_COMPATIBLE: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageViewCreateFlagBits.html>"]
+pub struct ImageViewCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ImageViewCreateFlags, Flags);
+impl ImageViewCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSamplerCreateFlagBits.html>"]
+pub struct SamplerCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(SamplerCreateFlags, Flags);
+impl SamplerCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineCreateFlagBits.html>"]
+pub struct PipelineCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(PipelineCreateFlags, Flags);
+impl PipelineCreateFlags {
+    pub const DISABLE_OPTIMIZATION: Self = Self(0b1);
+    pub const ALLOW_DERIVATIVES: Self = Self(0b10);
+    pub const DERIVATIVE: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineShaderStageCreateFlagBits.html>"]
+pub struct PipelineShaderStageCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(PipelineShaderStageCreateFlags, Flags);
+impl PipelineShaderStageCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkColorComponentFlagBits.html>"]
+pub struct ColorComponentFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ColorComponentFlags, Flags);
+impl ColorComponentFlags {
+    pub const R: Self = Self(0b1);
+    pub const G: Self = Self(0b10);
+    pub const B: Self = Self(0b100);
+    pub const A: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFenceCreateFlagBits.html>"]
+pub struct FenceCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(FenceCreateFlags, Flags);
+impl FenceCreateFlags {
+    pub const SIGNALED: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSemaphoreCreateFlagBits.html>"]
+pub struct SemaphoreCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(SemaphoreCreateFlags, Flags);
+impl SemaphoreCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFormatFeatureFlagBits.html>"]
+pub struct FormatFeatureFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(FormatFeatureFlags, Flags);
+impl FormatFeatureFlags {
+    #[doc = "Format can be used for sampled images (SAMPLED_IMAGE and COMBINED_IMAGE_SAMPLER descriptor types)"]
+    pub const SAMPLED_IMAGE: Self = Self(0b1);
+    #[doc = "Format can be used for storage images (STORAGE_IMAGE descriptor type)"]
+    pub const STORAGE_IMAGE: Self = Self(0b10);
+    #[doc = "Format supports atomic operations in case it is used for storage images"]
+    pub const STORAGE_IMAGE_ATOMIC: Self = Self(0b100);
+    #[doc = "Format can be used for uniform texel buffers (TBOs)"]
+    pub const UNIFORM_TEXEL_BUFFER: Self = Self(0b1000);
+    #[doc = "Format can be used for storage texel buffers (IBOs)"]
+    pub const STORAGE_TEXEL_BUFFER: Self = Self(0b1_0000);
+    #[doc = "Format supports atomic operations in case it is used for storage texel buffers"]
+    pub const STORAGE_TEXEL_BUFFER_ATOMIC: Self = Self(0b10_0000);
+    #[doc = "Format can be used for vertex buffers (VBOs)"]
+    pub const VERTEX_BUFFER: Self = Self(0b100_0000);
+    #[doc = "Format can be used for color attachment images"]
+    pub const COLOR_ATTACHMENT: Self = Self(0b1000_0000);
+    #[doc = "Format supports blending in case it is used for color attachment images"]
+    pub const COLOR_ATTACHMENT_BLEND: Self = Self(0b1_0000_0000);
+    #[doc = "Format can be used for depth/stencil attachment images"]
+    pub const DEPTH_STENCIL_ATTACHMENT: Self = Self(0b10_0000_0000);
+    #[doc = "Format can be used as the source image of blits with vkCmdBlitImage"]
+    pub const BLIT_SRC: Self = Self(0b100_0000_0000);
+    #[doc = "Format can be used as the destination image of blits with vkCmdBlitImage"]
+    pub const BLIT_DST: Self = Self(0b1000_0000_0000);
+    #[doc = "Format can be filtered with VK_FILTER_LINEAR when being sampled"]
+    pub const SAMPLED_IMAGE_FILTER_LINEAR: Self = Self(0b1_0000_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueryControlFlagBits.html>"]
+pub struct QueryControlFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(QueryControlFlags, Flags);
+impl QueryControlFlags {
+    #[doc = "Require precise results to be collected by the query"]
+    pub const PRECISE: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueryResultFlagBits.html>"]
+pub struct QueryResultFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(QueryResultFlags, Flags);
+impl QueryResultFlags {
+    #[doc = "Results of the queries are written to the destination buffer as 64-bit values"]
+    pub const TYPE_64: Self = Self(0b1);
+    #[doc = "Results of the queries are waited on before proceeding with the result copy"]
+    pub const WAIT: Self = Self(0b10);
+    #[doc = "Besides the results of the query, the availability of the results is also written"]
+    pub const WITH_AVAILABILITY: Self = Self(0b100);
+    #[doc = "Copy the partial results of the query even if the final results are not available"]
+    pub const PARTIAL: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCommandBufferUsageFlagBits.html>"]
+pub struct CommandBufferUsageFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(CommandBufferUsageFlags, Flags);
+impl CommandBufferUsageFlags {
+    pub const ONE_TIME_SUBMIT: Self = Self(0b1);
+    pub const RENDER_PASS_CONTINUE: Self = Self(0b10);
+    #[doc = "Command buffer may be submitted/executed more than once simultaneously"]
+    pub const SIMULTANEOUS_USE: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkQueryPipelineStatisticFlagBits.html>"]
+pub struct QueryPipelineStatisticFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(QueryPipelineStatisticFlags, Flags);
+impl QueryPipelineStatisticFlags {
+    #[doc = "Optional"]
+    pub const INPUT_ASSEMBLY_VERTICES: Self = Self(0b1);
+    #[doc = "Optional"]
+    pub const INPUT_ASSEMBLY_PRIMITIVES: Self = Self(0b10);
+    #[doc = "Optional"]
+    pub const VERTEX_SHADER_INVOCATIONS: Self = Self(0b100);
+    #[doc = "Optional"]
+    pub const GEOMETRY_SHADER_INVOCATIONS: Self = Self(0b1000);
+    #[doc = "Optional"]
+    pub const GEOMETRY_SHADER_PRIMITIVES: Self = Self(0b1_0000);
+    #[doc = "Optional"]
+    pub const CLIPPING_INVOCATIONS: Self = Self(0b10_0000);
+    #[doc = "Optional"]
+    pub const CLIPPING_PRIMITIVES: Self = Self(0b100_0000);
+    #[doc = "Optional"]
+    pub const FRAGMENT_SHADER_INVOCATIONS: Self = Self(0b1000_0000);
+    #[doc = "Optional"]
+    pub const TESSELLATION_CONTROL_SHADER_PATCHES: Self = Self(0b1_0000_0000);
+    #[doc = "Optional"]
+    pub const TESSELLATION_EVALUATION_SHADER_INVOCATIONS: Self = Self(0b10_0000_0000);
+    #[doc = "Optional"]
+    pub const COMPUTE_SHADER_INVOCATIONS: Self = Self(0b100_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageAspectFlagBits.html>"]
+pub struct ImageAspectFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ImageAspectFlags, Flags);
+impl ImageAspectFlags {
+    pub const COLOR: Self = Self(0b1);
+    pub const DEPTH: Self = Self(0b10);
+    pub const STENCIL: Self = Self(0b100);
+    pub const METADATA: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSparseImageFormatFlagBits.html>"]
+pub struct SparseImageFormatFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(SparseImageFormatFlags, Flags);
+impl SparseImageFormatFlags {
+    #[doc = "Image uses a single mip tail region for all array layers"]
+    pub const SINGLE_MIPTAIL: Self = Self(0b1);
+    #[doc = "Image requires mip level dimensions to be an integer multiple of the sparse image block dimensions for non-tail mip levels."]
+    pub const ALIGNED_MIP_SIZE: Self = Self(0b10);
+    #[doc = "Image uses a non-standard sparse image block dimensions"]
+    pub const NONSTANDARD_BLOCK_SIZE: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSparseMemoryBindFlagBits.html>"]
+pub struct SparseMemoryBindFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(SparseMemoryBindFlags, Flags);
+impl SparseMemoryBindFlags {
+    #[doc = "Operation binds resource metadata to memory"]
+    pub const METADATA: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineStageFlagBits.html>"]
+pub struct PipelineStageFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(PipelineStageFlags, Flags);
+impl PipelineStageFlags {
+    #[doc = "Before subsequent commands are processed"]
+    pub const TOP_OF_PIPE: Self = Self(0b1);
+    #[doc = "Draw/DispatchIndirect command fetch"]
+    pub const DRAW_INDIRECT: Self = Self(0b10);
+    #[doc = "Vertex/index fetch"]
+    pub const VERTEX_INPUT: Self = Self(0b100);
+    #[doc = "Vertex shading"]
+    pub const VERTEX_SHADER: Self = Self(0b1000);
+    #[doc = "Tessellation control shading"]
+    pub const TESSELLATION_CONTROL_SHADER: Self = Self(0b1_0000);
+    #[doc = "Tessellation evaluation shading"]
+    pub const TESSELLATION_EVALUATION_SHADER: Self = Self(0b10_0000);
+    #[doc = "Geometry shading"]
+    pub const GEOMETRY_SHADER: Self = Self(0b100_0000);
+    #[doc = "Fragment shading"]
+    pub const FRAGMENT_SHADER: Self = Self(0b1000_0000);
+    #[doc = "Early fragment (depth and stencil) tests"]
+    pub const EARLY_FRAGMENT_TESTS: Self = Self(0b1_0000_0000);
+    #[doc = "Late fragment (depth and stencil) tests"]
+    pub const LATE_FRAGMENT_TESTS: Self = Self(0b10_0000_0000);
+    #[doc = "Color attachment writes"]
+    pub const COLOR_ATTACHMENT_OUTPUT: Self = Self(0b100_0000_0000);
+    #[doc = "Compute shading"]
+    pub const COMPUTE_SHADER: Self = Self(0b1000_0000_0000);
+    #[doc = "Transfer/copy operations"]
+    pub const TRANSFER: Self = Self(0b1_0000_0000_0000);
+    #[doc = "After previous commands have completed"]
+    pub const BOTTOM_OF_PIPE: Self = Self(0b10_0000_0000_0000);
+    #[doc = "Indicates host (CPU) is a source/sink of the dependency"]
+    pub const HOST: Self = Self(0b100_0000_0000_0000);
+    #[doc = "All stages of the graphics pipeline"]
+    pub const ALL_GRAPHICS: Self = Self(0b1000_0000_0000_0000);
+    #[doc = "All stages supported on the queue"]
+    pub const ALL_COMMANDS: Self = Self(0b1_0000_0000_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCommandPoolCreateFlagBits.html>"]
+pub struct CommandPoolCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(CommandPoolCreateFlags, Flags);
+impl CommandPoolCreateFlags {
+    #[doc = "Command buffers have a short lifetime"]
+    pub const TRANSIENT: Self = Self(0b1);
+    #[doc = "Command buffers may release their memory individually"]
+    pub const RESET_COMMAND_BUFFER: Self = Self(0b10);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCommandPoolResetFlagBits.html>"]
+pub struct CommandPoolResetFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(CommandPoolResetFlags, Flags);
+impl CommandPoolResetFlags {
+    #[doc = "Release resources owned by the pool"]
+    pub const RELEASE_RESOURCES: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCommandBufferResetFlagBits.html>"]
+pub struct CommandBufferResetFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(CommandBufferResetFlags, Flags);
+impl CommandBufferResetFlags {
+    #[doc = "Release resources owned by the buffer"]
+    pub const RELEASE_RESOURCES: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSampleCountFlagBits.html>"]
+pub struct SampleCountFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(SampleCountFlags, Flags);
+impl SampleCountFlags {
+    #[doc = "Sample count 1 supported"]
+    pub const TYPE_1: Self = Self(0b1);
+    #[doc = "Sample count 2 supported"]
+    pub const TYPE_2: Self = Self(0b10);
+    #[doc = "Sample count 4 supported"]
+    pub const TYPE_4: Self = Self(0b100);
+    #[doc = "Sample count 8 supported"]
+    pub const TYPE_8: Self = Self(0b1000);
+    #[doc = "Sample count 16 supported"]
+    pub const TYPE_16: Self = Self(0b1_0000);
+    #[doc = "Sample count 32 supported"]
+    pub const TYPE_32: Self = Self(0b10_0000);
+    #[doc = "Sample count 64 supported"]
+    pub const TYPE_64: Self = Self(0b100_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAttachmentDescriptionFlagBits.html>"]
+pub struct AttachmentDescriptionFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(AttachmentDescriptionFlags, Flags);
+impl AttachmentDescriptionFlags {
+    #[doc = "The attachment may alias physical memory of another attachment in the same render pass"]
+    pub const MAY_ALIAS: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkStencilFaceFlagBits.html>"]
+pub struct StencilFaceFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(StencilFaceFlags, Flags);
+impl StencilFaceFlags {
+    #[doc = "Front face"]
+    pub const FRONT: Self = Self(0b1);
+    #[doc = "Back face"]
+    pub const BACK: Self = Self(0b10);
+    #[doc = "Front and back faces"]
+    pub const FRONT_AND_BACK: Self = Self(0x0000_0003);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDescriptorPoolCreateFlagBits.html>"]
+pub struct DescriptorPoolCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(DescriptorPoolCreateFlags, Flags);
+impl DescriptorPoolCreateFlags {
+    #[doc = "Descriptor sets may be freed individually"]
+    pub const FREE_DESCRIPTOR_SET: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDependencyFlagBits.html>"]
+pub struct DependencyFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(DependencyFlags, Flags);
+impl DependencyFlags {
+    #[doc = "Dependency is per pixel region "]
+    pub const BY_REGION: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSemaphoreWaitFlagBits.html>"]
+pub struct SemaphoreWaitFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(SemaphoreWaitFlags, Flags);
+impl SemaphoreWaitFlags {
+    pub const ANY: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDisplayPlaneAlphaFlagBitsKHR.html>"]
+pub struct DisplayPlaneAlphaFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(DisplayPlaneAlphaFlagsKHR, Flags);
+impl DisplayPlaneAlphaFlagsKHR {
+    pub const OPAQUE: Self = Self(0b1);
+    pub const GLOBAL: Self = Self(0b10);
+    pub const PER_PIXEL: Self = Self(0b100);
+    pub const PER_PIXEL_PREMULTIPLIED: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkCompositeAlphaFlagBitsKHR.html>"]
+pub struct CompositeAlphaFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(CompositeAlphaFlagsKHR, Flags);
+impl CompositeAlphaFlagsKHR {
+    pub const OPAQUE: Self = Self(0b1);
+    pub const PRE_MULTIPLIED: Self = Self(0b10);
+    pub const POST_MULTIPLIED: Self = Self(0b100);
+    pub const INHERIT: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSurfaceTransformFlagBitsKHR.html>"]
+pub struct SurfaceTransformFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(SurfaceTransformFlagsKHR, Flags);
+impl SurfaceTransformFlagsKHR {
+    pub const IDENTITY: Self = Self(0b1);
+    pub const ROTATE_90: Self = Self(0b10);
+    pub const ROTATE_180: Self = Self(0b100);
+    pub const ROTATE_270: Self = Self(0b1000);
+    pub const HORIZONTAL_MIRROR: Self = Self(0b1_0000);
+    pub const HORIZONTAL_MIRROR_ROTATE_90: Self = Self(0b10_0000);
+    pub const HORIZONTAL_MIRROR_ROTATE_180: Self = Self(0b100_0000);
+    pub const HORIZONTAL_MIRROR_ROTATE_270: Self = Self(0b1000_0000);
+    pub const INHERIT: Self = Self(0b1_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSwapchainImageUsageFlagBitsANDROID.html>"]
+pub struct SwapchainImageUsageFlagsANDROID(pub(crate) Flags);
+vk_bitflags_wrapped!(SwapchainImageUsageFlagsANDROID, Flags);
+impl SwapchainImageUsageFlagsANDROID {
+    pub const SHARED: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDebugReportFlagBitsEXT.html>"]
+pub struct DebugReportFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(DebugReportFlagsEXT, Flags);
+impl DebugReportFlagsEXT {
+    pub const INFORMATION: Self = Self(0b1);
+    pub const WARNING: Self = Self(0b10);
+    pub const PERFORMANCE_WARNING: Self = Self(0b100);
+    pub const ERROR: Self = Self(0b1000);
+    pub const DEBUG: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalMemoryHandleTypeFlagBitsNV.html>"]
+pub struct ExternalMemoryHandleTypeFlagsNV(pub(crate) Flags);
+vk_bitflags_wrapped!(ExternalMemoryHandleTypeFlagsNV, Flags);
+impl ExternalMemoryHandleTypeFlagsNV {
+    pub const OPAQUE_WIN32: Self = Self(0b1);
+    pub const OPAQUE_WIN32_KMT: Self = Self(0b10);
+    pub const D3D11_IMAGE: Self = Self(0b100);
+    pub const D3D11_IMAGE_KMT: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalMemoryFeatureFlagBitsNV.html>"]
+pub struct ExternalMemoryFeatureFlagsNV(pub(crate) Flags);
+vk_bitflags_wrapped!(ExternalMemoryFeatureFlagsNV, Flags);
+impl ExternalMemoryFeatureFlagsNV {
+    pub const DEDICATED_ONLY: Self = Self(0b1);
+    pub const EXPORTABLE: Self = Self(0b10);
+    pub const IMPORTABLE: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSubgroupFeatureFlagBits.html>"]
+pub struct SubgroupFeatureFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(SubgroupFeatureFlags, Flags);
+impl SubgroupFeatureFlags {
+    #[doc = "Basic subgroup operations"]
+    pub const BASIC: Self = Self(0b1);
+    #[doc = "Vote subgroup operations"]
+    pub const VOTE: Self = Self(0b10);
+    #[doc = "Arithmetic subgroup operations"]
+    pub const ARITHMETIC: Self = Self(0b100);
+    #[doc = "Ballot subgroup operations"]
+    pub const BALLOT: Self = Self(0b1000);
+    #[doc = "Shuffle subgroup operations"]
+    pub const SHUFFLE: Self = Self(0b1_0000);
+    #[doc = "Shuffle relative subgroup operations"]
+    pub const SHUFFLE_RELATIVE: Self = Self(0b10_0000);
+    #[doc = "Clustered subgroup operations"]
+    pub const CLUSTERED: Self = Self(0b100_0000);
+    #[doc = "Quad subgroup operations"]
+    pub const QUAD: Self = Self(0b1000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectCommandsLayoutUsageFlagBitsNV.html>"]
+pub struct IndirectCommandsLayoutUsageFlagsNV(pub(crate) Flags);
+vk_bitflags_wrapped!(IndirectCommandsLayoutUsageFlagsNV, Flags);
+impl IndirectCommandsLayoutUsageFlagsNV {
+    pub const EXPLICIT_PREPROCESS: Self = Self(0b1);
+    pub const INDEXED_SEQUENCES: Self = Self(0b10);
+    pub const UNORDERED_SEQUENCES: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkIndirectStateFlagBitsNV.html>"]
+pub struct IndirectStateFlagsNV(pub(crate) Flags);
+vk_bitflags_wrapped!(IndirectStateFlagsNV, Flags);
+impl IndirectStateFlagsNV {
+    pub const FLAG_FRONTFACE: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPrivateDataSlotCreateFlagBits.html>"]
+pub struct PrivateDataSlotCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(PrivateDataSlotCreateFlags, Flags);
+impl PrivateDataSlotCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDescriptorSetLayoutCreateFlagBits.html>"]
+pub struct DescriptorSetLayoutCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(DescriptorSetLayoutCreateFlags, Flags);
+impl DescriptorSetLayoutCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalMemoryHandleTypeFlagBits.html>"]
+pub struct ExternalMemoryHandleTypeFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ExternalMemoryHandleTypeFlags, Flags);
+impl ExternalMemoryHandleTypeFlags {
+    pub const OPAQUE_FD: Self = Self(0b1);
+    pub const OPAQUE_WIN32: Self = Self(0b10);
+    pub const OPAQUE_WIN32_KMT: Self = Self(0b100);
+    pub const D3D11_TEXTURE: Self = Self(0b1000);
+    pub const D3D11_TEXTURE_KMT: Self = Self(0b1_0000);
+    pub const D3D12_HEAP: Self = Self(0b10_0000);
+    pub const D3D12_RESOURCE: Self = Self(0b100_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalMemoryFeatureFlagBits.html>"]
+pub struct ExternalMemoryFeatureFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ExternalMemoryFeatureFlags, Flags);
+impl ExternalMemoryFeatureFlags {
+    pub const DEDICATED_ONLY: Self = Self(0b1);
+    pub const EXPORTABLE: Self = Self(0b10);
+    pub const IMPORTABLE: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalSemaphoreHandleTypeFlagBits.html>"]
+pub struct ExternalSemaphoreHandleTypeFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ExternalSemaphoreHandleTypeFlags, Flags);
+impl ExternalSemaphoreHandleTypeFlags {
+    pub const OPAQUE_FD: Self = Self(0b1);
+    pub const OPAQUE_WIN32: Self = Self(0b10);
+    pub const OPAQUE_WIN32_KMT: Self = Self(0b100);
+    pub const D3D12_FENCE: Self = Self(0b1000);
+    pub const D3D11_FENCE: Self = Self::D3D12_FENCE;
+    pub const SYNC_FD: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalSemaphoreFeatureFlagBits.html>"]
+pub struct ExternalSemaphoreFeatureFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ExternalSemaphoreFeatureFlags, Flags);
+impl ExternalSemaphoreFeatureFlags {
+    pub const EXPORTABLE: Self = Self(0b1);
+    pub const IMPORTABLE: Self = Self(0b10);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSemaphoreImportFlagBits.html>"]
+pub struct SemaphoreImportFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(SemaphoreImportFlags, Flags);
+impl SemaphoreImportFlags {
+    pub const TEMPORARY: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalFenceHandleTypeFlagBits.html>"]
+pub struct ExternalFenceHandleTypeFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ExternalFenceHandleTypeFlags, Flags);
+impl ExternalFenceHandleTypeFlags {
+    pub const OPAQUE_FD: Self = Self(0b1);
+    pub const OPAQUE_WIN32: Self = Self(0b10);
+    pub const OPAQUE_WIN32_KMT: Self = Self(0b100);
+    pub const SYNC_FD: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExternalFenceFeatureFlagBits.html>"]
+pub struct ExternalFenceFeatureFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ExternalFenceFeatureFlags, Flags);
+impl ExternalFenceFeatureFlags {
+    pub const EXPORTABLE: Self = Self(0b1);
+    pub const IMPORTABLE: Self = Self(0b10);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFenceImportFlagBits.html>"]
+pub struct FenceImportFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(FenceImportFlags, Flags);
+impl FenceImportFlags {
+    pub const TEMPORARY: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSurfaceCounterFlagBitsEXT.html>"]
+pub struct SurfaceCounterFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(SurfaceCounterFlagsEXT, Flags);
+impl SurfaceCounterFlagsEXT {
+    pub const VBLANK: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPeerMemoryFeatureFlagBits.html>"]
+pub struct PeerMemoryFeatureFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(PeerMemoryFeatureFlags, Flags);
+impl PeerMemoryFeatureFlags {
+    #[doc = "Can read with vkCmdCopy commands"]
+    pub const COPY_SRC: Self = Self(0b1);
+    #[doc = "Can write with vkCmdCopy commands"]
+    pub const COPY_DST: Self = Self(0b10);
+    #[doc = "Can read with any access type/command"]
+    pub const GENERIC_SRC: Self = Self(0b100);
+    #[doc = "Can write with and access type/command"]
+    pub const GENERIC_DST: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryAllocateFlagBits.html>"]
+pub struct MemoryAllocateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(MemoryAllocateFlags, Flags);
+impl MemoryAllocateFlags {
+    #[doc = "Force allocation on specific devices"]
+    pub const DEVICE_MASK: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceGroupPresentModeFlagBitsKHR.html>"]
+pub struct DeviceGroupPresentModeFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(DeviceGroupPresentModeFlagsKHR, Flags);
+impl DeviceGroupPresentModeFlagsKHR {
+    #[doc = "Present from local memory"]
+    pub const LOCAL: Self = Self(0b1);
+    #[doc = "Present from remote memory"]
+    pub const REMOTE: Self = Self(0b10);
+    #[doc = "Present sum of local and/or remote memory"]
+    pub const SUM: Self = Self(0b100);
+    #[doc = "Each physical device presents from local memory"]
+    pub const LOCAL_MULTI_DEVICE: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSwapchainCreateFlagBitsKHR.html>"]
+pub struct SwapchainCreateFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(SwapchainCreateFlagsKHR, Flags);
+impl SwapchainCreateFlagsKHR {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSubpassDescriptionFlagBits.html>"]
+pub struct SubpassDescriptionFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(SubpassDescriptionFlags, Flags);
+impl SubpassDescriptionFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDebugUtilsMessageSeverityFlagBitsEXT.html>"]
+pub struct DebugUtilsMessageSeverityFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(DebugUtilsMessageSeverityFlagsEXT, Flags);
+impl DebugUtilsMessageSeverityFlagsEXT {
+    pub const VERBOSE: Self = Self(0b1);
+    pub const INFO: Self = Self(0b1_0000);
+    pub const WARNING: Self = Self(0b1_0000_0000);
+    pub const ERROR: Self = Self(0b1_0000_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDebugUtilsMessageTypeFlagBitsEXT.html>"]
+pub struct DebugUtilsMessageTypeFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(DebugUtilsMessageTypeFlagsEXT, Flags);
+impl DebugUtilsMessageTypeFlagsEXT {
+    pub const GENERAL: Self = Self(0b1);
+    pub const VALIDATION: Self = Self(0b10);
+    pub const PERFORMANCE: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDescriptorBindingFlagBits.html>"]
+pub struct DescriptorBindingFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(DescriptorBindingFlags, Flags);
+impl DescriptorBindingFlags {
+    pub const UPDATE_AFTER_BIND: Self = Self(0b1);
+    pub const UPDATE_UNUSED_WHILE_PENDING: Self = Self(0b10);
+    pub const PARTIALLY_BOUND: Self = Self(0b100);
+    pub const VARIABLE_DESCRIPTOR_COUNT: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkConditionalRenderingFlagBitsEXT.html>"]
+pub struct ConditionalRenderingFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(ConditionalRenderingFlagsEXT, Flags);
+impl ConditionalRenderingFlagsEXT {
+    pub const INVERTED: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkResolveModeFlagBits.html>"]
+pub struct ResolveModeFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ResolveModeFlags, Flags);
+impl ResolveModeFlags {
+    pub const NONE: Self = Self(0);
+    pub const SAMPLE_ZERO: Self = Self(0b1);
+    pub const AVERAGE: Self = Self(0b10);
+    pub const MIN: Self = Self(0b100);
+    pub const MAX: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkGeometryInstanceFlagBitsKHR.html>"]
+pub struct GeometryInstanceFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(GeometryInstanceFlagsKHR, Flags);
+impl GeometryInstanceFlagsKHR {
+    pub const TRIANGLE_FACING_CULL_DISABLE: Self = Self(0b1);
+    pub const TRIANGLE_FLIP_FACING: Self = Self(0b10);
+    pub const FORCE_OPAQUE: Self = Self(0b100);
+    pub const FORCE_NO_OPAQUE: Self = Self(0b1000);
+    pub const TRIANGLE_FRONT_COUNTERCLOCKWISE: Self = Self::TRIANGLE_FLIP_FACING;
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkGeometryFlagBitsKHR.html>"]
+pub struct GeometryFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(GeometryFlagsKHR, Flags);
+impl GeometryFlagsKHR {
+    pub const OPAQUE: Self = Self(0b1);
+    pub const NO_DUPLICATE_ANY_HIT_INVOCATION: Self = Self(0b10);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBuildAccelerationStructureFlagBitsKHR.html>"]
+pub struct BuildAccelerationStructureFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(BuildAccelerationStructureFlagsKHR, Flags);
+impl BuildAccelerationStructureFlagsKHR {
+    pub const ALLOW_UPDATE: Self = Self(0b1);
+    pub const ALLOW_COMPACTION: Self = Self(0b10);
+    pub const PREFER_FAST_TRACE: Self = Self(0b100);
+    pub const PREFER_FAST_BUILD: Self = Self(0b1000);
+    pub const LOW_MEMORY: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureCreateFlagBitsKHR.html>"]
+pub struct AccelerationStructureCreateFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(AccelerationStructureCreateFlagsKHR, Flags);
+impl AccelerationStructureCreateFlagsKHR {
+    pub const DEVICE_ADDRESS_CAPTURE_REPLAY: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFramebufferCreateFlagBits.html>"]
+pub struct FramebufferCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(FramebufferCreateFlags, Flags);
+impl FramebufferCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceDiagnosticsConfigFlagBitsNV.html>"]
+pub struct DeviceDiagnosticsConfigFlagsNV(pub(crate) Flags);
+vk_bitflags_wrapped!(DeviceDiagnosticsConfigFlagsNV, Flags);
+impl DeviceDiagnosticsConfigFlagsNV {
+    pub const ENABLE_SHADER_DEBUG_INFO: Self = Self(0b1);
+    pub const ENABLE_RESOURCE_TRACKING: Self = Self(0b10);
+    pub const ENABLE_AUTOMATIC_CHECKPOINTS: Self = Self(0b100);
+    pub const ENABLE_SHADER_ERROR_REPORTING: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineCreationFeedbackFlagBits.html>"]
+pub struct PipelineCreationFeedbackFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(PipelineCreationFeedbackFlags, Flags);
+impl PipelineCreationFeedbackFlags {
+    pub const VALID: Self = Self(0b1);
+    pub const VALID_EXT: Self = Self::VALID;
+    pub const APPLICATION_PIPELINE_CACHE_HIT: Self = Self(0b10);
+    pub const APPLICATION_PIPELINE_CACHE_HIT_EXT: Self = Self::APPLICATION_PIPELINE_CACHE_HIT;
+    pub const BASE_PIPELINE_ACCELERATION: Self = Self(0b100);
+    pub const BASE_PIPELINE_ACCELERATION_EXT: Self = Self::BASE_PIPELINE_ACCELERATION;
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMemoryDecompressionMethodFlagBitsNV.html>"]
+pub struct MemoryDecompressionMethodFlagsNV(pub(crate) Flags64);
+vk_bitflags_wrapped!(MemoryDecompressionMethodFlagsNV, Flags64);
+impl MemoryDecompressionMethodFlagsNV {
+    pub const GDEFLATE_1_0: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPerformanceCounterDescriptionFlagBitsKHR.html>"]
+pub struct PerformanceCounterDescriptionFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(PerformanceCounterDescriptionFlagsKHR, Flags);
+impl PerformanceCounterDescriptionFlagsKHR {
+    pub const PERFORMANCE_IMPACTING: Self = Self(0b1);
+    pub const CONCURRENTLY_IMPACTED: Self = Self(0b10);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAcquireProfilingLockFlagBitsKHR.html>"]
+pub struct AcquireProfilingLockFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(AcquireProfilingLockFlagsKHR, Flags);
+impl AcquireProfilingLockFlagsKHR {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderCorePropertiesFlagBitsAMD.html>"]
+pub struct ShaderCorePropertiesFlagsAMD(pub(crate) Flags);
+vk_bitflags_wrapped!(ShaderCorePropertiesFlagsAMD, Flags);
+impl ShaderCorePropertiesFlagsAMD {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderModuleCreateFlagBits.html>"]
+pub struct ShaderModuleCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ShaderModuleCreateFlags, Flags);
+impl ShaderModuleCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineCompilerControlFlagBitsAMD.html>"]
+pub struct PipelineCompilerControlFlagsAMD(pub(crate) Flags);
+vk_bitflags_wrapped!(PipelineCompilerControlFlagsAMD, Flags);
+impl PipelineCompilerControlFlagsAMD {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkToolPurposeFlagBits.html>"]
+pub struct ToolPurposeFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(ToolPurposeFlags, Flags);
+impl ToolPurposeFlags {
+    pub const VALIDATION: Self = Self(0b1);
+    pub const VALIDATION_EXT: Self = Self::VALIDATION;
+    pub const PROFILING: Self = Self(0b10);
+    pub const PROFILING_EXT: Self = Self::PROFILING;
+    pub const TRACING: Self = Self(0b100);
+    pub const TRACING_EXT: Self = Self::TRACING;
+    pub const ADDITIONAL_FEATURES: Self = Self(0b1000);
+    pub const ADDITIONAL_FEATURES_EXT: Self = Self::ADDITIONAL_FEATURES;
+    pub const MODIFYING_FEATURES: Self = Self(0b1_0000);
+    pub const MODIFYING_FEATURES_EXT: Self = Self::MODIFYING_FEATURES;
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccessFlagBits2.html>"]
+pub struct AccessFlags2(pub(crate) Flags64);
+vk_bitflags_wrapped!(AccessFlags2, Flags64);
+impl AccessFlags2 {
+    pub const NONE: Self = Self(0);
+    pub const NONE_KHR: Self = Self::NONE;
+    pub const INDIRECT_COMMAND_READ: Self = Self(0b1);
+    pub const INDIRECT_COMMAND_READ_KHR: Self = Self::INDIRECT_COMMAND_READ;
+    pub const INDEX_READ: Self = Self(0b10);
+    pub const INDEX_READ_KHR: Self = Self::INDEX_READ;
+    pub const VERTEX_ATTRIBUTE_READ: Self = Self(0b100);
+    pub const VERTEX_ATTRIBUTE_READ_KHR: Self = Self::VERTEX_ATTRIBUTE_READ;
+    pub const UNIFORM_READ: Self = Self(0b1000);
+    pub const UNIFORM_READ_KHR: Self = Self::UNIFORM_READ;
+    pub const INPUT_ATTACHMENT_READ: Self = Self(0b1_0000);
+    pub const INPUT_ATTACHMENT_READ_KHR: Self = Self::INPUT_ATTACHMENT_READ;
+    pub const SHADER_READ: Self = Self(0b10_0000);
+    pub const SHADER_READ_KHR: Self = Self::SHADER_READ;
+    pub const SHADER_WRITE: Self = Self(0b100_0000);
+    pub const SHADER_WRITE_KHR: Self = Self::SHADER_WRITE;
+    pub const COLOR_ATTACHMENT_READ: Self = Self(0b1000_0000);
+    pub const COLOR_ATTACHMENT_READ_KHR: Self = Self::COLOR_ATTACHMENT_READ;
+    pub const COLOR_ATTACHMENT_WRITE: Self = Self(0b1_0000_0000);
+    pub const COLOR_ATTACHMENT_WRITE_KHR: Self = Self::COLOR_ATTACHMENT_WRITE;
+    pub const DEPTH_STENCIL_ATTACHMENT_READ: Self = Self(0b10_0000_0000);
+    pub const DEPTH_STENCIL_ATTACHMENT_READ_KHR: Self = Self::DEPTH_STENCIL_ATTACHMENT_READ;
+    pub const DEPTH_STENCIL_ATTACHMENT_WRITE: Self = Self(0b100_0000_0000);
+    pub const DEPTH_STENCIL_ATTACHMENT_WRITE_KHR: Self = Self::DEPTH_STENCIL_ATTACHMENT_WRITE;
+    pub const TRANSFER_READ: Self = Self(0b1000_0000_0000);
+    pub const TRANSFER_READ_KHR: Self = Self::TRANSFER_READ;
+    pub const TRANSFER_WRITE: Self = Self(0b1_0000_0000_0000);
+    pub const TRANSFER_WRITE_KHR: Self = Self::TRANSFER_WRITE;
+    pub const HOST_READ: Self = Self(0b10_0000_0000_0000);
+    pub const HOST_READ_KHR: Self = Self::HOST_READ;
+    pub const HOST_WRITE: Self = Self(0b100_0000_0000_0000);
+    pub const HOST_WRITE_KHR: Self = Self::HOST_WRITE;
+    pub const MEMORY_READ: Self = Self(0b1000_0000_0000_0000);
+    pub const MEMORY_READ_KHR: Self = Self::MEMORY_READ;
+    pub const MEMORY_WRITE: Self = Self(0b1_0000_0000_0000_0000);
+    pub const MEMORY_WRITE_KHR: Self = Self::MEMORY_WRITE;
+    pub const SHADER_SAMPLED_READ: Self = Self(0b1_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const SHADER_SAMPLED_READ_KHR: Self = Self::SHADER_SAMPLED_READ;
+    pub const SHADER_STORAGE_READ: Self = Self(0b10_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const SHADER_STORAGE_READ_KHR: Self = Self::SHADER_STORAGE_READ;
+    pub const SHADER_STORAGE_WRITE: Self = Self(0b100_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const SHADER_STORAGE_WRITE_KHR: Self = Self::SHADER_STORAGE_WRITE;
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineStageFlagBits2.html>"]
+pub struct PipelineStageFlags2(pub(crate) Flags64);
+vk_bitflags_wrapped!(PipelineStageFlags2, Flags64);
+impl PipelineStageFlags2 {
+    pub const NONE: Self = Self(0);
+    pub const NONE_KHR: Self = Self::NONE;
+    pub const TOP_OF_PIPE: Self = Self(0b1);
+    pub const TOP_OF_PIPE_KHR: Self = Self::TOP_OF_PIPE;
+    pub const DRAW_INDIRECT: Self = Self(0b10);
+    pub const DRAW_INDIRECT_KHR: Self = Self::DRAW_INDIRECT;
+    pub const VERTEX_INPUT: Self = Self(0b100);
+    pub const VERTEX_INPUT_KHR: Self = Self::VERTEX_INPUT;
+    pub const VERTEX_SHADER: Self = Self(0b1000);
+    pub const VERTEX_SHADER_KHR: Self = Self::VERTEX_SHADER;
+    pub const TESSELLATION_CONTROL_SHADER: Self = Self(0b1_0000);
+    pub const TESSELLATION_CONTROL_SHADER_KHR: Self = Self::TESSELLATION_CONTROL_SHADER;
+    pub const TESSELLATION_EVALUATION_SHADER: Self = Self(0b10_0000);
+    pub const TESSELLATION_EVALUATION_SHADER_KHR: Self = Self::TESSELLATION_EVALUATION_SHADER;
+    pub const GEOMETRY_SHADER: Self = Self(0b100_0000);
+    pub const GEOMETRY_SHADER_KHR: Self = Self::GEOMETRY_SHADER;
+    pub const FRAGMENT_SHADER: Self = Self(0b1000_0000);
+    pub const FRAGMENT_SHADER_KHR: Self = Self::FRAGMENT_SHADER;
+    pub const EARLY_FRAGMENT_TESTS: Self = Self(0b1_0000_0000);
+    pub const EARLY_FRAGMENT_TESTS_KHR: Self = Self::EARLY_FRAGMENT_TESTS;
+    pub const LATE_FRAGMENT_TESTS: Self = Self(0b10_0000_0000);
+    pub const LATE_FRAGMENT_TESTS_KHR: Self = Self::LATE_FRAGMENT_TESTS;
+    pub const COLOR_ATTACHMENT_OUTPUT: Self = Self(0b100_0000_0000);
+    pub const COLOR_ATTACHMENT_OUTPUT_KHR: Self = Self::COLOR_ATTACHMENT_OUTPUT;
+    pub const COMPUTE_SHADER: Self = Self(0b1000_0000_0000);
+    pub const COMPUTE_SHADER_KHR: Self = Self::COMPUTE_SHADER;
+    pub const ALL_TRANSFER: Self = Self(0b1_0000_0000_0000);
+    pub const ALL_TRANSFER_KHR: Self = Self::ALL_TRANSFER;
+    pub const TRANSFER: Self = Self::ALL_TRANSFER_KHR;
+    pub const TRANSFER_KHR: Self = Self::ALL_TRANSFER;
+    pub const BOTTOM_OF_PIPE: Self = Self(0b10_0000_0000_0000);
+    pub const BOTTOM_OF_PIPE_KHR: Self = Self::BOTTOM_OF_PIPE;
+    pub const HOST: Self = Self(0b100_0000_0000_0000);
+    pub const HOST_KHR: Self = Self::HOST;
+    pub const ALL_GRAPHICS: Self = Self(0b1000_0000_0000_0000);
+    pub const ALL_GRAPHICS_KHR: Self = Self::ALL_GRAPHICS;
+    pub const ALL_COMMANDS: Self = Self(0b1_0000_0000_0000_0000);
+    pub const ALL_COMMANDS_KHR: Self = Self::ALL_COMMANDS;
+    pub const COPY: Self = Self(0b1_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const COPY_KHR: Self = Self::COPY;
+    pub const RESOLVE: Self = Self(0b10_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const RESOLVE_KHR: Self = Self::RESOLVE;
+    pub const BLIT: Self = Self(0b100_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const BLIT_KHR: Self = Self::BLIT;
+    pub const CLEAR: Self = Self(0b1000_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const CLEAR_KHR: Self = Self::CLEAR;
+    pub const INDEX_INPUT: Self = Self(0b1_0000_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const INDEX_INPUT_KHR: Self = Self::INDEX_INPUT;
+    pub const VERTEX_ATTRIBUTE_INPUT: Self =
+        Self(0b10_0000_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const VERTEX_ATTRIBUTE_INPUT_KHR: Self = Self::VERTEX_ATTRIBUTE_INPUT;
+    pub const PRE_RASTERIZATION_SHADERS: Self =
+        Self(0b100_0000_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const PRE_RASTERIZATION_SHADERS_KHR: Self = Self::PRE_RASTERIZATION_SHADERS;
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkSubmitFlagBits.html>"]
+pub struct SubmitFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(SubmitFlags, Flags);
+impl SubmitFlags {
+    pub const PROTECTED: Self = Self(0b1);
+    pub const PROTECTED_KHR: Self = Self::PROTECTED;
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkEventCreateFlagBits.html>"]
+pub struct EventCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(EventCreateFlags, Flags);
+impl EventCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineLayoutCreateFlagBits.html>"]
+pub struct PipelineLayoutCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(PipelineLayoutCreateFlags, Flags);
+impl PipelineLayoutCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineColorBlendStateCreateFlagBits.html>"]
+pub struct PipelineColorBlendStateCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(PipelineColorBlendStateCreateFlags, Flags);
+impl PipelineColorBlendStateCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineDepthStencilStateCreateFlagBits.html>"]
+pub struct PipelineDepthStencilStateCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(PipelineDepthStencilStateCreateFlags, Flags);
+impl PipelineDepthStencilStateCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkGraphicsPipelineLibraryFlagBitsEXT.html>"]
+pub struct GraphicsPipelineLibraryFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(GraphicsPipelineLibraryFlagsEXT, Flags);
+impl GraphicsPipelineLibraryFlagsEXT {
+    pub const VERTEX_INPUT_INTERFACE: Self = Self(0b1);
+    pub const PRE_RASTERIZATION_SHADERS: Self = Self(0b10);
+    pub const FRAGMENT_SHADER: Self = Self(0b100);
+    pub const FRAGMENT_OUTPUT_INTERFACE: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkDeviceAddressBindingFlagBitsEXT.html>"]
+pub struct DeviceAddressBindingFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(DeviceAddressBindingFlagsEXT, Flags);
+impl DeviceAddressBindingFlagsEXT {
+    pub const INTERNAL_OBJECT: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPresentScalingFlagBitsEXT.html>"]
+pub struct PresentScalingFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(PresentScalingFlagsEXT, Flags);
+impl PresentScalingFlagsEXT {
+    pub const ONE_TO_ONE: Self = Self(0b1);
+    pub const ASPECT_RATIO_STRETCH: Self = Self(0b10);
+    pub const STRETCH: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPresentGravityFlagBitsEXT.html>"]
+pub struct PresentGravityFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(PresentGravityFlagsEXT, Flags);
+impl PresentGravityFlagsEXT {
+    pub const MIN: Self = Self(0b1);
+    pub const MAX: Self = Self(0b10);
+    pub const CENTERED: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoCodecOperationFlagBitsKHR.html>"]
+pub struct VideoCodecOperationFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoCodecOperationFlagsKHR, Flags);
+impl VideoCodecOperationFlagsKHR {
+    pub const NONE: Self = Self(0);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoChromaSubsamplingFlagBitsKHR.html>"]
+pub struct VideoChromaSubsamplingFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoChromaSubsamplingFlagsKHR, Flags);
+impl VideoChromaSubsamplingFlagsKHR {
+    pub const INVALID: Self = Self(0);
+    pub const MONOCHROME: Self = Self(0b1);
+    pub const TYPE_420: Self = Self(0b10);
+    pub const TYPE_422: Self = Self(0b100);
+    pub const TYPE_444: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoComponentBitDepthFlagBitsKHR.html>"]
+pub struct VideoComponentBitDepthFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoComponentBitDepthFlagsKHR, Flags);
+impl VideoComponentBitDepthFlagsKHR {
+    pub const INVALID: Self = Self(0);
+    pub const TYPE_8: Self = Self(0b1);
+    pub const TYPE_10: Self = Self(0b100);
+    pub const TYPE_12: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoCapabilityFlagBitsKHR.html>"]
+pub struct VideoCapabilityFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoCapabilityFlagsKHR, Flags);
+impl VideoCapabilityFlagsKHR {
+    pub const PROTECTED_CONTENT: Self = Self(0b1);
+    pub const SEPARATE_REFERENCE_IMAGES: Self = Self(0b10);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoSessionCreateFlagBitsKHR.html>"]
+pub struct VideoSessionCreateFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoSessionCreateFlagsKHR, Flags);
+impl VideoSessionCreateFlagsKHR {
+    pub const PROTECTED_CONTENT: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoDecodeH264PictureLayoutFlagBitsKHR.html>"]
+pub struct VideoDecodeH264PictureLayoutFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoDecodeH264PictureLayoutFlagsKHR, Flags);
+impl VideoDecodeH264PictureLayoutFlagsKHR {
+    pub const PROGRESSIVE: Self = Self(0);
+    pub const INTERLACED_INTERLEAVED_LINES: Self = Self(0b1);
+    pub const INTERLACED_SEPARATE_PLANES: Self = Self(0b10);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoCodingControlFlagBitsKHR.html>"]
+pub struct VideoCodingControlFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoCodingControlFlagsKHR, Flags);
+impl VideoCodingControlFlagsKHR {
+    pub const RESET: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoDecodeUsageFlagBitsKHR.html>"]
+pub struct VideoDecodeUsageFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoDecodeUsageFlagsKHR, Flags);
+impl VideoDecodeUsageFlagsKHR {
+    pub const DEFAULT: Self = Self(0);
+    pub const TRANSCODING: Self = Self(0b1);
+    pub const OFFLINE: Self = Self(0b10);
+    pub const STREAMING: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoDecodeCapabilityFlagBitsKHR.html>"]
+pub struct VideoDecodeCapabilityFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoDecodeCapabilityFlagsKHR, Flags);
+impl VideoDecodeCapabilityFlagsKHR {
+    pub const DPB_AND_OUTPUT_COINCIDE: Self = Self(0b1);
+    pub const DPB_AND_OUTPUT_DISTINCT: Self = Self(0b10);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeUsageFlagBitsKHR.html>"]
+pub struct VideoEncodeUsageFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeUsageFlagsKHR, Flags);
+impl VideoEncodeUsageFlagsKHR {
+    pub const DEFAULT: Self = Self(0);
+    pub const TRANSCODING: Self = Self(0b1);
+    pub const STREAMING: Self = Self(0b10);
+    pub const RECORDING: Self = Self(0b100);
+    pub const CONFERENCING: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeContentFlagBitsKHR.html>"]
+pub struct VideoEncodeContentFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeContentFlagsKHR, Flags);
+impl VideoEncodeContentFlagsKHR {
+    pub const DEFAULT: Self = Self(0);
+    pub const CAMERA: Self = Self(0b1);
+    pub const DESKTOP: Self = Self(0b10);
+    pub const RENDERED: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeCapabilityFlagBitsKHR.html>"]
+pub struct VideoEncodeCapabilityFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeCapabilityFlagsKHR, Flags);
+impl VideoEncodeCapabilityFlagsKHR {
+    pub const PRECEDING_EXTERNALLY_ENCODED_BYTES: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeFeedbackFlagBitsKHR.html>"]
+pub struct VideoEncodeFeedbackFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeFeedbackFlagsKHR, Flags);
+impl VideoEncodeFeedbackFlagsKHR {
+    pub const BITSTREAM_BUFFER_OFFSET: Self = Self(0b1);
+    pub const BITSTREAM_BYTES_WRITTEN: Self = Self(0b10);
+    pub const BITSTREAM_HAS_OVERRIDES: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeRateControlModeFlagBitsKHR.html>"]
+pub struct VideoEncodeRateControlModeFlagsKHR(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeRateControlModeFlagsKHR, Flags);
+impl VideoEncodeRateControlModeFlagsKHR {
+    pub const DEFAULT: Self = Self(0);
+    pub const DISABLED: Self = Self(0b1);
+    pub const CBR: Self = Self(0b10);
+    pub const VBR: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH264CapabilityFlagBitsEXT.html>"]
+pub struct VideoEncodeH264CapabilityFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeH264CapabilityFlagsEXT, Flags);
+impl VideoEncodeH264CapabilityFlagsEXT {
+    pub const HRD_COMPLIANCE: Self = Self(0b1);
+    pub const PREDICTION_WEIGHT_TABLE_GENERATED: Self = Self(0b10);
+    pub const ROW_UNALIGNED_SLICE: Self = Self(0b100);
+    pub const DIFFERENT_SLICE_TYPE: Self = Self(0b1000);
+    pub const B_FRAME_IN_L0_LIST: Self = Self(0b1_0000);
+    pub const B_FRAME_IN_L1_LIST: Self = Self(0b10_0000);
+    pub const PER_PICTURE_TYPE_MIN_MAX_QP: Self = Self(0b100_0000);
+    pub const PER_SLICE_CONSTANT_QP: Self = Self(0b1000_0000);
+    pub const GENERATE_PREFIX_NALU: Self = Self(0b1_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH264StdFlagBitsEXT.html>"]
+pub struct VideoEncodeH264StdFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeH264StdFlagsEXT, Flags);
+impl VideoEncodeH264StdFlagsEXT {
+    pub const SEPARATE_COLOR_PLANE_FLAG_SET: Self = Self(0b1);
+    pub const QPPRIME_Y_ZERO_TRANSFORM_BYPASS_FLAG_SET: Self = Self(0b10);
+    pub const SCALING_MATRIX_PRESENT_FLAG_SET: Self = Self(0b100);
+    pub const CHROMA_QP_INDEX_OFFSET: Self = Self(0b1000);
+    pub const SECOND_CHROMA_QP_INDEX_OFFSET: Self = Self(0b1_0000);
+    pub const PIC_INIT_QP_MINUS26: Self = Self(0b10_0000);
+    pub const WEIGHTED_PRED_FLAG_SET: Self = Self(0b100_0000);
+    pub const WEIGHTED_BIPRED_IDC_EXPLICIT: Self = Self(0b1000_0000);
+    pub const WEIGHTED_BIPRED_IDC_IMPLICIT: Self = Self(0b1_0000_0000);
+    pub const TRANSFORM_8X8_MODE_FLAG_SET: Self = Self(0b10_0000_0000);
+    pub const DIRECT_SPATIAL_MV_PRED_FLAG_UNSET: Self = Self(0b100_0000_0000);
+    pub const ENTROPY_CODING_MODE_FLAG_UNSET: Self = Self(0b1000_0000_0000);
+    pub const ENTROPY_CODING_MODE_FLAG_SET: Self = Self(0b1_0000_0000_0000);
+    pub const DIRECT_8X8_INFERENCE_FLAG_UNSET: Self = Self(0b10_0000_0000_0000);
+    pub const CONSTRAINED_INTRA_PRED_FLAG_SET: Self = Self(0b100_0000_0000_0000);
+    pub const DEBLOCKING_FILTER_DISABLED: Self = Self(0b1000_0000_0000_0000);
+    pub const DEBLOCKING_FILTER_ENABLED: Self = Self(0b1_0000_0000_0000_0000);
+    pub const DEBLOCKING_FILTER_PARTIAL: Self = Self(0b10_0000_0000_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH264RateControlFlagBitsEXT.html>"]
+pub struct VideoEncodeH264RateControlFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeH264RateControlFlagsEXT, Flags);
+impl VideoEncodeH264RateControlFlagsEXT {
+    pub const ATTEMPT_HRD_COMPLIANCE: Self = Self(0b1);
+    pub const REGULAR_GOP: Self = Self(0b10);
+    pub const REFERENCE_PATTERN_FLAT: Self = Self(0b100);
+    pub const REFERENCE_PATTERN_DYADIC: Self = Self(0b1000);
+    pub const TEMPORAL_LAYER_PATTERN_DYADIC: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageFormatConstraintsFlagBitsFUCHSIA.html>"]
+pub struct ImageFormatConstraintsFlagsFUCHSIA(pub(crate) Flags);
+vk_bitflags_wrapped!(ImageFormatConstraintsFlagsFUCHSIA, Flags);
+impl ImageFormatConstraintsFlagsFUCHSIA {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageConstraintsInfoFlagBitsFUCHSIA.html>"]
+pub struct ImageConstraintsInfoFlagsFUCHSIA(pub(crate) Flags);
+vk_bitflags_wrapped!(ImageConstraintsInfoFlagsFUCHSIA, Flags);
+impl ImageConstraintsInfoFlagsFUCHSIA {
+    pub const CPU_READ_RARELY: Self = Self(0b1);
+    pub const CPU_READ_OFTEN: Self = Self(0b10);
+    pub const CPU_WRITE_RARELY: Self = Self(0b100);
+    pub const CPU_WRITE_OFTEN: Self = Self(0b1000);
+    pub const PROTECTED_OPTIONAL: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkFormatFeatureFlagBits2.html>"]
+pub struct FormatFeatureFlags2(pub(crate) Flags64);
+vk_bitflags_wrapped!(FormatFeatureFlags2, Flags64);
+impl FormatFeatureFlags2 {
+    pub const SAMPLED_IMAGE: Self = Self(0b1);
+    pub const SAMPLED_IMAGE_KHR: Self = Self::SAMPLED_IMAGE;
+    pub const STORAGE_IMAGE: Self = Self(0b10);
+    pub const STORAGE_IMAGE_KHR: Self = Self::STORAGE_IMAGE;
+    pub const STORAGE_IMAGE_ATOMIC: Self = Self(0b100);
+    pub const STORAGE_IMAGE_ATOMIC_KHR: Self = Self::STORAGE_IMAGE_ATOMIC;
+    pub const UNIFORM_TEXEL_BUFFER: Self = Self(0b1000);
+    pub const UNIFORM_TEXEL_BUFFER_KHR: Self = Self::UNIFORM_TEXEL_BUFFER;
+    pub const STORAGE_TEXEL_BUFFER: Self = Self(0b1_0000);
+    pub const STORAGE_TEXEL_BUFFER_KHR: Self = Self::STORAGE_TEXEL_BUFFER;
+    pub const STORAGE_TEXEL_BUFFER_ATOMIC: Self = Self(0b10_0000);
+    pub const STORAGE_TEXEL_BUFFER_ATOMIC_KHR: Self = Self::STORAGE_TEXEL_BUFFER_ATOMIC;
+    pub const VERTEX_BUFFER: Self = Self(0b100_0000);
+    pub const VERTEX_BUFFER_KHR: Self = Self::VERTEX_BUFFER;
+    pub const COLOR_ATTACHMENT: Self = Self(0b1000_0000);
+    pub const COLOR_ATTACHMENT_KHR: Self = Self::COLOR_ATTACHMENT;
+    pub const COLOR_ATTACHMENT_BLEND: Self = Self(0b1_0000_0000);
+    pub const COLOR_ATTACHMENT_BLEND_KHR: Self = Self::COLOR_ATTACHMENT_BLEND;
+    pub const DEPTH_STENCIL_ATTACHMENT: Self = Self(0b10_0000_0000);
+    pub const DEPTH_STENCIL_ATTACHMENT_KHR: Self = Self::DEPTH_STENCIL_ATTACHMENT;
+    pub const BLIT_SRC: Self = Self(0b100_0000_0000);
+    pub const BLIT_SRC_KHR: Self = Self::BLIT_SRC;
+    pub const BLIT_DST: Self = Self(0b1000_0000_0000);
+    pub const BLIT_DST_KHR: Self = Self::BLIT_DST;
+    pub const SAMPLED_IMAGE_FILTER_LINEAR: Self = Self(0b1_0000_0000_0000);
+    pub const SAMPLED_IMAGE_FILTER_LINEAR_KHR: Self = Self::SAMPLED_IMAGE_FILTER_LINEAR;
+    pub const SAMPLED_IMAGE_FILTER_CUBIC: Self = Self(0b10_0000_0000_0000);
+    pub const SAMPLED_IMAGE_FILTER_CUBIC_EXT: Self = Self::SAMPLED_IMAGE_FILTER_CUBIC;
+    pub const TRANSFER_SRC: Self = Self(0b100_0000_0000_0000);
+    pub const TRANSFER_SRC_KHR: Self = Self::TRANSFER_SRC;
+    pub const TRANSFER_DST: Self = Self(0b1000_0000_0000_0000);
+    pub const TRANSFER_DST_KHR: Self = Self::TRANSFER_DST;
+    pub const SAMPLED_IMAGE_FILTER_MINMAX: Self = Self(0b1_0000_0000_0000_0000);
+    pub const SAMPLED_IMAGE_FILTER_MINMAX_KHR: Self = Self::SAMPLED_IMAGE_FILTER_MINMAX;
+    pub const MIDPOINT_CHROMA_SAMPLES: Self = Self(0b10_0000_0000_0000_0000);
+    pub const MIDPOINT_CHROMA_SAMPLES_KHR: Self = Self::MIDPOINT_CHROMA_SAMPLES;
+    pub const SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER: Self = Self(0b100_0000_0000_0000_0000);
+    pub const SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_KHR: Self =
+        Self::SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER;
+    pub const SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER: Self =
+        Self(0b1000_0000_0000_0000_0000);
+    pub const SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_KHR: Self =
+        Self::SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER;
+    pub const SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT: Self =
+        Self(0b1_0000_0000_0000_0000_0000);
+    pub const SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_KHR: Self =
+        Self::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT;
+    pub const SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE: Self =
+        Self(0b10_0000_0000_0000_0000_0000);
+    pub const SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_KHR: Self =
+        Self::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE;
+    pub const DISJOINT: Self = Self(0b100_0000_0000_0000_0000_0000);
+    pub const DISJOINT_KHR: Self = Self::DISJOINT;
+    pub const COSITED_CHROMA_SAMPLES: Self = Self(0b1000_0000_0000_0000_0000_0000);
+    pub const COSITED_CHROMA_SAMPLES_KHR: Self = Self::COSITED_CHROMA_SAMPLES;
+    pub const STORAGE_READ_WITHOUT_FORMAT: Self = Self(0b1000_0000_0000_0000_0000_0000_0000_0000);
+    pub const STORAGE_READ_WITHOUT_FORMAT_KHR: Self = Self::STORAGE_READ_WITHOUT_FORMAT;
+    pub const STORAGE_WRITE_WITHOUT_FORMAT: Self =
+        Self(0b1_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const STORAGE_WRITE_WITHOUT_FORMAT_KHR: Self = Self::STORAGE_WRITE_WITHOUT_FORMAT;
+    pub const SAMPLED_IMAGE_DEPTH_COMPARISON: Self =
+        Self(0b10_0000_0000_0000_0000_0000_0000_0000_0000);
+    pub const SAMPLED_IMAGE_DEPTH_COMPARISON_KHR: Self = Self::SAMPLED_IMAGE_DEPTH_COMPARISON;
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkRenderingFlagBits.html>"]
+pub struct RenderingFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(RenderingFlags, Flags);
+impl RenderingFlags {
+    pub const CONTENTS_SECONDARY_COMMAND_BUFFERS: Self = Self(0b1);
+    pub const CONTENTS_SECONDARY_COMMAND_BUFFERS_KHR: Self =
+        Self::CONTENTS_SECONDARY_COMMAND_BUFFERS;
+    pub const SUSPENDING: Self = Self(0b10);
+    pub const SUSPENDING_KHR: Self = Self::SUSPENDING;
+    pub const RESUMING: Self = Self(0b100);
+    pub const RESUMING_KHR: Self = Self::RESUMING;
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH265CapabilityFlagBitsEXT.html>"]
+pub struct VideoEncodeH265CapabilityFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeH265CapabilityFlagsEXT, Flags);
+impl VideoEncodeH265CapabilityFlagsEXT {
+    pub const HRD_COMPLIANCE: Self = Self(0b1);
+    pub const PREDICTION_WEIGHT_TABLE_GENERATED: Self = Self(0b10);
+    pub const ROW_UNALIGNED_SLICE_SEGMENT: Self = Self(0b100);
+    pub const DIFFERENT_SLICE_SEGMENT_TYPE: Self = Self(0b1000);
+    pub const B_FRAME_IN_L0_LIST: Self = Self(0b1_0000);
+    pub const B_FRAME_IN_L1_LIST: Self = Self(0b10_0000);
+    pub const PER_PICTURE_TYPE_MIN_MAX_QP: Self = Self(0b100_0000);
+    pub const PER_SLICE_SEGMENT_CONSTANT_QP: Self = Self(0b1000_0000);
+    pub const MULTIPLE_TILES_PER_SLICE_SEGMENT: Self = Self(0b1_0000_0000);
+    pub const MULTIPLE_SLICE_SEGMENTS_PER_TILE: Self = Self(0b10_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH265StdFlagBitsEXT.html>"]
+pub struct VideoEncodeH265StdFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeH265StdFlagsEXT, Flags);
+impl VideoEncodeH265StdFlagsEXT {
+    pub const SEPARATE_COLOR_PLANE_FLAG_SET: Self = Self(0b1);
+    pub const SAMPLE_ADAPTIVE_OFFSET_ENABLED_FLAG_SET: Self = Self(0b10);
+    pub const SCALING_LIST_DATA_PRESENT_FLAG_SET: Self = Self(0b100);
+    pub const PCM_ENABLED_FLAG_SET: Self = Self(0b1000);
+    pub const SPS_TEMPORAL_MVP_ENABLED_FLAG_SET: Self = Self(0b1_0000);
+    pub const INIT_QP_MINUS26: Self = Self(0b10_0000);
+    pub const WEIGHTED_PRED_FLAG_SET: Self = Self(0b100_0000);
+    pub const WEIGHTED_BIPRED_FLAG_SET: Self = Self(0b1000_0000);
+    pub const LOG2_PARALLEL_MERGE_LEVEL_MINUS2: Self = Self(0b1_0000_0000);
+    pub const SIGN_DATA_HIDING_ENABLED_FLAG_SET: Self = Self(0b10_0000_0000);
+    pub const TRANSFORM_SKIP_ENABLED_FLAG_SET: Self = Self(0b100_0000_0000);
+    pub const TRANSFORM_SKIP_ENABLED_FLAG_UNSET: Self = Self(0b1000_0000_0000);
+    pub const PPS_SLICE_CHROMA_QP_OFFSETS_PRESENT_FLAG_SET: Self = Self(0b1_0000_0000_0000);
+    pub const TRANSQUANT_BYPASS_ENABLED_FLAG_SET: Self = Self(0b10_0000_0000_0000);
+    pub const CONSTRAINED_INTRA_PRED_FLAG_SET: Self = Self(0b100_0000_0000_0000);
+    pub const ENTROPY_CODING_SYNC_ENABLED_FLAG_SET: Self = Self(0b1000_0000_0000_0000);
+    pub const DEBLOCKING_FILTER_OVERRIDE_ENABLED_FLAG_SET: Self = Self(0b1_0000_0000_0000_0000);
+    pub const DEPENDENT_SLICE_SEGMENTS_ENABLED_FLAG_SET: Self = Self(0b10_0000_0000_0000_0000);
+    pub const DEPENDENT_SLICE_SEGMENT_FLAG_SET: Self = Self(0b100_0000_0000_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH265RateControlFlagBitsEXT.html>"]
+pub struct VideoEncodeH265RateControlFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeH265RateControlFlagsEXT, Flags);
+impl VideoEncodeH265RateControlFlagsEXT {
+    pub const ATTEMPT_HRD_COMPLIANCE: Self = Self(0b1);
+    pub const REGULAR_GOP: Self = Self(0b10);
+    pub const REFERENCE_PATTERN_FLAT: Self = Self(0b100);
+    pub const REFERENCE_PATTERN_DYADIC: Self = Self(0b1000);
+    pub const TEMPORAL_SUB_LAYER_PATTERN_DYADIC: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH265CtbSizeFlagBitsEXT.html>"]
+pub struct VideoEncodeH265CtbSizeFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeH265CtbSizeFlagsEXT, Flags);
+impl VideoEncodeH265CtbSizeFlagsEXT {
+    pub const TYPE_16: Self = Self(0b1);
+    pub const TYPE_32: Self = Self(0b10);
+    pub const TYPE_64: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkVideoEncodeH265TransformBlockSizeFlagBitsEXT.html>"]
+pub struct VideoEncodeH265TransformBlockSizeFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(VideoEncodeH265TransformBlockSizeFlagsEXT, Flags);
+impl VideoEncodeH265TransformBlockSizeFlagsEXT {
+    pub const TYPE_4: Self = Self(0b1);
+    pub const TYPE_8: Self = Self(0b10);
+    pub const TYPE_16: Self = Self(0b100);
+    pub const TYPE_32: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkExportMetalObjectTypeFlagBitsEXT.html>"]
+pub struct ExportMetalObjectTypeFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(ExportMetalObjectTypeFlagsEXT, Flags);
+impl ExportMetalObjectTypeFlagsEXT {
+    pub const METAL_DEVICE: Self = Self(0b1);
+    pub const METAL_COMMAND_QUEUE: Self = Self(0b10);
+    pub const METAL_BUFFER: Self = Self(0b100);
+    pub const METAL_TEXTURE: Self = Self(0b1000);
+    pub const METAL_IOSURFACE: Self = Self(0b1_0000);
+    pub const METAL_SHARED_EVENT: Self = Self(0b10_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkInstanceCreateFlagBits.html>"]
+pub struct InstanceCreateFlags(pub(crate) Flags);
+vk_bitflags_wrapped!(InstanceCreateFlags, Flags);
+impl InstanceCreateFlags {}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageCompressionFlagBitsEXT.html>"]
+pub struct ImageCompressionFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(ImageCompressionFlagsEXT, Flags);
+impl ImageCompressionFlagsEXT {
+    pub const DEFAULT: Self = Self(0);
+    pub const FIXED_RATE_DEFAULT: Self = Self(0b1);
+    pub const FIXED_RATE_EXPLICIT: Self = Self(0b10);
+    pub const DISABLED: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageCompressionFixedRateFlagBitsEXT.html>"]
+pub struct ImageCompressionFixedRateFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(ImageCompressionFixedRateFlagsEXT, Flags);
+impl ImageCompressionFixedRateFlagsEXT {
+    pub const NONE: Self = Self(0);
+    pub const TYPE_1BPC: Self = Self(0b1);
+    pub const TYPE_2BPC: Self = Self(0b10);
+    pub const TYPE_3BPC: Self = Self(0b100);
+    pub const TYPE_4BPC: Self = Self(0b1000);
+    pub const TYPE_5BPC: Self = Self(0b1_0000);
+    pub const TYPE_6BPC: Self = Self(0b10_0000);
+    pub const TYPE_7BPC: Self = Self(0b100_0000);
+    pub const TYPE_8BPC: Self = Self(0b1000_0000);
+    pub const TYPE_9BPC: Self = Self(0b1_0000_0000);
+    pub const TYPE_10BPC: Self = Self(0b10_0000_0000);
+    pub const TYPE_11BPC: Self = Self(0b100_0000_0000);
+    pub const TYPE_12BPC: Self = Self(0b1000_0000_0000);
+    pub const TYPE_13BPC: Self = Self(0b1_0000_0000_0000);
+    pub const TYPE_14BPC: Self = Self(0b10_0000_0000_0000);
+    pub const TYPE_15BPC: Self = Self(0b100_0000_0000_0000);
+    pub const TYPE_16BPC: Self = Self(0b1000_0000_0000_0000);
+    pub const TYPE_17BPC: Self = Self(0b1_0000_0000_0000_0000);
+    pub const TYPE_18BPC: Self = Self(0b10_0000_0000_0000_0000);
+    pub const TYPE_19BPC: Self = Self(0b100_0000_0000_0000_0000);
+    pub const TYPE_20BPC: Self = Self(0b1000_0000_0000_0000_0000);
+    pub const TYPE_21BPC: Self = Self(0b1_0000_0000_0000_0000_0000);
+    pub const TYPE_22BPC: Self = Self(0b10_0000_0000_0000_0000_0000);
+    pub const TYPE_23BPC: Self = Self(0b100_0000_0000_0000_0000_0000);
+    pub const TYPE_24BPC: Self = Self(0b1000_0000_0000_0000_0000_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowGridSizeFlagBitsNV.html>"]
+pub struct OpticalFlowGridSizeFlagsNV(pub(crate) Flags);
+vk_bitflags_wrapped!(OpticalFlowGridSizeFlagsNV, Flags);
+impl OpticalFlowGridSizeFlagsNV {
+    pub const UNKNOWN: Self = Self(0);
+    pub const TYPE_1X1: Self = Self(0b1);
+    pub const TYPE_2X2: Self = Self(0b10);
+    pub const TYPE_4X4: Self = Self(0b100);
+    pub const TYPE_8X8: Self = Self(0b1000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowUsageFlagBitsNV.html>"]
+pub struct OpticalFlowUsageFlagsNV(pub(crate) Flags);
+vk_bitflags_wrapped!(OpticalFlowUsageFlagsNV, Flags);
+impl OpticalFlowUsageFlagsNV {
+    pub const UNKNOWN: Self = Self(0);
+    pub const INPUT: Self = Self(0b1);
+    pub const OUTPUT: Self = Self(0b10);
+    pub const HINT: Self = Self(0b100);
+    pub const COST: Self = Self(0b1000);
+    pub const GLOBAL_FLOW: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowSessionCreateFlagBitsNV.html>"]
+pub struct OpticalFlowSessionCreateFlagsNV(pub(crate) Flags);
+vk_bitflags_wrapped!(OpticalFlowSessionCreateFlagsNV, Flags);
+impl OpticalFlowSessionCreateFlagsNV {
+    pub const ENABLE_HINT: Self = Self(0b1);
+    pub const ENABLE_COST: Self = Self(0b10);
+    pub const ENABLE_GLOBAL_FLOW: Self = Self(0b100);
+    pub const ALLOW_REGIONS: Self = Self(0b1000);
+    pub const BOTH_DIRECTIONS: Self = Self(0b1_0000);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkOpticalFlowExecuteFlagBitsNV.html>"]
+pub struct OpticalFlowExecuteFlagsNV(pub(crate) Flags);
+vk_bitflags_wrapped!(OpticalFlowExecuteFlagsNV, Flags);
+impl OpticalFlowExecuteFlagsNV {
+    pub const DISABLE_TEMPORAL_HINTS: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkBuildMicromapFlagBitsEXT.html>"]
+pub struct BuildMicromapFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(BuildMicromapFlagsEXT, Flags);
+impl BuildMicromapFlagsEXT {
+    pub const PREFER_FAST_TRACE: Self = Self(0b1);
+    pub const PREFER_FAST_BUILD: Self = Self(0b10);
+    pub const ALLOW_COMPACTION: Self = Self(0b100);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkMicromapCreateFlagBitsEXT.html>"]
+pub struct MicromapCreateFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(MicromapCreateFlagsEXT, Flags);
+impl MicromapCreateFlagsEXT {
+    pub const DEVICE_ADDRESS_CAPTURE_REPLAY: Self = Self(0b1);
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkShaderCreateFlagBitsEXT.html>"]
+pub struct ShaderCreateFlagsEXT(pub(crate) Flags);
+vk_bitflags_wrapped!(ShaderCreateFlagsEXT, Flags);
+impl ShaderCreateFlagsEXT {
+    pub const LINK_STAGE: Self = Self(0b1);
+}
+impl AccelerationStructureCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::DEVICE_ADDRESS_CAPTURE_REPLAY.0
+                | Self::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.0
+                | Self::MOTION_NV.0,
+        )
+    }
+}
+impl AccelerationStructureMotionInfoFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl AccelerationStructureMotionInstanceFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl AccessFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::INDIRECT_COMMAND_READ.0
+                | Self::INDEX_READ.0
+                | Self::VERTEX_ATTRIBUTE_READ.0
+                | Self::UNIFORM_READ.0
+                | Self::INPUT_ATTACHMENT_READ.0
+                | Self::SHADER_READ.0
+                | Self::SHADER_WRITE.0
+                | Self::COLOR_ATTACHMENT_READ.0
+                | Self::COLOR_ATTACHMENT_WRITE.0
+                | Self::DEPTH_STENCIL_ATTACHMENT_READ.0
+                | Self::DEPTH_STENCIL_ATTACHMENT_WRITE.0
+                | Self::TRANSFER_READ.0
+                | Self::TRANSFER_WRITE.0
+                | Self::HOST_READ.0
+                | Self::HOST_WRITE.0
+                | Self::MEMORY_READ.0
+                | Self::MEMORY_WRITE.0
+                | Self::TRANSFORM_FEEDBACK_WRITE_EXT.0
+                | Self::TRANSFORM_FEEDBACK_COUNTER_READ_EXT.0
+                | Self::TRANSFORM_FEEDBACK_COUNTER_WRITE_EXT.0
+                | Self::CONDITIONAL_RENDERING_READ_EXT.0
+                | Self::COLOR_ATTACHMENT_READ_NONCOHERENT_EXT.0
+                | Self::ACCELERATION_STRUCTURE_READ_KHR.0
+                | Self::ACCELERATION_STRUCTURE_WRITE_KHR.0
+                | Self::FRAGMENT_DENSITY_MAP_READ_EXT.0
+                | Self::FRAGMENT_SHADING_RATE_ATTACHMENT_READ_KHR.0
+                | Self::COMMAND_PREPROCESS_READ_NV.0
+                | Self::COMMAND_PREPROCESS_WRITE_NV.0,
+        )
+    }
+}
+impl AccessFlags2 {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::INDIRECT_COMMAND_READ.0
+                | Self::INDEX_READ.0
+                | Self::VERTEX_ATTRIBUTE_READ.0
+                | Self::UNIFORM_READ.0
+                | Self::INPUT_ATTACHMENT_READ.0
+                | Self::SHADER_READ.0
+                | Self::SHADER_WRITE.0
+                | Self::COLOR_ATTACHMENT_READ.0
+                | Self::COLOR_ATTACHMENT_WRITE.0
+                | Self::DEPTH_STENCIL_ATTACHMENT_READ.0
+                | Self::DEPTH_STENCIL_ATTACHMENT_WRITE.0
+                | Self::TRANSFER_READ.0
+                | Self::TRANSFER_WRITE.0
+                | Self::HOST_READ.0
+                | Self::HOST_WRITE.0
+                | Self::MEMORY_READ.0
+                | Self::MEMORY_WRITE.0
+                | Self::SHADER_SAMPLED_READ.0
+                | Self::SHADER_STORAGE_READ.0
+                | Self::SHADER_STORAGE_WRITE.0
+                | Self::VIDEO_DECODE_READ_KHR.0
+                | Self::VIDEO_DECODE_WRITE_KHR.0
+                | Self::VIDEO_ENCODE_READ_KHR.0
+                | Self::VIDEO_ENCODE_WRITE_KHR.0
+                | Self::TRANSFORM_FEEDBACK_WRITE_EXT.0
+                | Self::TRANSFORM_FEEDBACK_COUNTER_READ_EXT.0
+                | Self::TRANSFORM_FEEDBACK_COUNTER_WRITE_EXT.0
+                | Self::CONDITIONAL_RENDERING_READ_EXT.0
+                | Self::COMMAND_PREPROCESS_READ_NV.0
+                | Self::COMMAND_PREPROCESS_WRITE_NV.0
+                | Self::FRAGMENT_SHADING_RATE_ATTACHMENT_READ_KHR.0
+                | Self::ACCELERATION_STRUCTURE_READ_KHR.0
+                | Self::ACCELERATION_STRUCTURE_WRITE_KHR.0
+                | Self::FRAGMENT_DENSITY_MAP_READ_EXT.0
+                | Self::COLOR_ATTACHMENT_READ_NONCOHERENT_EXT.0
+                | Self::DESCRIPTOR_BUFFER_READ_EXT.0
+                | Self::INVOCATION_MASK_READ_HUAWEI.0
+                | Self::SHADER_BINDING_TABLE_READ_KHR.0
+                | Self::MICROMAP_READ_EXT.0
+                | Self::MICROMAP_WRITE_EXT.0
+                | Self::OPTICAL_FLOW_READ_NV.0
+                | Self::OPTICAL_FLOW_WRITE_NV.0,
+        )
+    }
+}
+impl AcquireProfilingLockFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl AndroidSurfaceCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl AttachmentDescriptionFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::MAY_ALIAS.0)
+    }
+}
+impl BufferCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::SPARSE_BINDING.0
+                | Self::SPARSE_RESIDENCY.0
+                | Self::SPARSE_ALIASED.0
+                | Self::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.0
+                | Self::PROTECTED.0
+                | Self::DEVICE_ADDRESS_CAPTURE_REPLAY.0,
+        )
+    }
+}
+impl BufferUsageFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::TRANSFER_SRC.0
+                | Self::TRANSFER_DST.0
+                | Self::UNIFORM_TEXEL_BUFFER.0
+                | Self::STORAGE_TEXEL_BUFFER.0
+                | Self::UNIFORM_BUFFER.0
+                | Self::STORAGE_BUFFER.0
+                | Self::INDEX_BUFFER.0
+                | Self::VERTEX_BUFFER.0
+                | Self::INDIRECT_BUFFER.0
+                | Self::VIDEO_DECODE_SRC_KHR.0
+                | Self::VIDEO_DECODE_DST_KHR.0
+                | Self::TRANSFORM_FEEDBACK_BUFFER_EXT.0
+                | Self::TRANSFORM_FEEDBACK_COUNTER_BUFFER_EXT.0
+                | Self::CONDITIONAL_RENDERING_EXT.0
+                | Self::ACCELERATION_STRUCTURE_BUILD_INPUT_READ_ONLY_KHR.0
+                | Self::ACCELERATION_STRUCTURE_STORAGE_KHR.0
+                | Self::SHADER_BINDING_TABLE_KHR.0
+                | Self::VIDEO_ENCODE_DST_KHR.0
+                | Self::VIDEO_ENCODE_SRC_KHR.0
+                | Self::SAMPLER_DESCRIPTOR_BUFFER_EXT.0
+                | Self::RESOURCE_DESCRIPTOR_BUFFER_EXT.0
+                | Self::PUSH_DESCRIPTORS_DESCRIPTOR_BUFFER_EXT.0
+                | Self::MICROMAP_BUILD_INPUT_READ_ONLY_EXT.0
+                | Self::MICROMAP_STORAGE_EXT.0
+                | Self::SHADER_DEVICE_ADDRESS.0,
+        )
+    }
+}
+impl BufferViewCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl BuildAccelerationStructureFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::ALLOW_UPDATE.0
+                | Self::ALLOW_COMPACTION.0
+                | Self::PREFER_FAST_TRACE.0
+                | Self::PREFER_FAST_BUILD.0
+                | Self::LOW_MEMORY.0
+                | Self::MOTION_NV.0
+                | Self::ALLOW_OPACITY_MICROMAP_UPDATE_EXT.0
+                | Self::ALLOW_DISABLE_OPACITY_MICROMAPS_EXT.0
+                | Self::ALLOW_OPACITY_MICROMAP_DATA_UPDATE_EXT.0
+                | Self::ALLOW_DISPLACEMENT_MICROMAP_UPDATE_NV.0
+                | Self::ALLOW_DATA_ACCESS.0,
+        )
+    }
+}
+impl BuildMicromapFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::PREFER_FAST_TRACE.0 | Self::PREFER_FAST_BUILD.0 | Self::ALLOW_COMPACTION.0)
+    }
+}
+impl ColorComponentFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::R.0 | Self::G.0 | Self::B.0 | Self::A.0)
+    }
+}
+impl CommandBufferResetFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::RELEASE_RESOURCES.0)
+    }
+}
+impl CommandBufferUsageFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::ONE_TIME_SUBMIT.0 | Self::RENDER_PASS_CONTINUE.0 | Self::SIMULTANEOUS_USE.0)
+    }
+}
+impl CommandPoolCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::TRANSIENT.0 | Self::RESET_COMMAND_BUFFER.0 | Self::PROTECTED.0)
+    }
+}
+impl CommandPoolResetFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::RELEASE_RESOURCES.0)
+    }
+}
+impl CommandPoolTrimFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl CompositeAlphaFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::OPAQUE.0 | Self::PRE_MULTIPLIED.0 | Self::POST_MULTIPLIED.0 | Self::INHERIT.0)
+    }
+}
+impl ConditionalRenderingFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::INVERTED.0)
+    }
+}
+impl CullModeFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::FRONT.0 | Self::BACK.0)
+    }
+}
+impl DebugReportFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::INFORMATION.0
+                | Self::WARNING.0
+                | Self::PERFORMANCE_WARNING.0
+                | Self::ERROR.0
+                | Self::DEBUG.0,
+        )
+    }
+}
+impl DebugUtilsMessageSeverityFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::VERBOSE.0 | Self::INFO.0 | Self::WARNING.0 | Self::ERROR.0)
+    }
+}
+impl DebugUtilsMessageTypeFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::GENERAL.0
+                | Self::VALIDATION.0
+                | Self::PERFORMANCE.0
+                | Self::DEVICE_ADDRESS_BINDING.0,
+        )
+    }
+}
+impl DebugUtilsMessengerCallbackDataFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl DebugUtilsMessengerCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl DependencyFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::BY_REGION.0
+                | Self::FEEDBACK_LOOP_EXT.0
+                | Self::DEVICE_GROUP.0
+                | Self::VIEW_LOCAL.0,
+        )
+    }
+}
+impl DescriptorBindingFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::UPDATE_AFTER_BIND.0
+                | Self::UPDATE_UNUSED_WHILE_PENDING.0
+                | Self::PARTIALLY_BOUND.0
+                | Self::VARIABLE_DESCRIPTOR_COUNT.0,
+        )
+    }
+}
+impl DescriptorPoolCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::FREE_DESCRIPTOR_SET.0 | Self::HOST_ONLY_EXT.0 | Self::UPDATE_AFTER_BIND.0)
+    }
+}
+impl DescriptorPoolResetFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl DescriptorSetLayoutCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::PUSH_DESCRIPTOR_KHR.0
+                | Self::DESCRIPTOR_BUFFER_EXT.0
+                | Self::EMBEDDED_IMMUTABLE_SAMPLERS_EXT.0
+                | Self::HOST_ONLY_POOL_EXT.0
+                | Self::UPDATE_AFTER_BIND_POOL.0,
+        )
+    }
+}
+impl DescriptorUpdateTemplateCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl DeviceAddressBindingFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::INTERNAL_OBJECT.0)
+    }
+}
+impl DeviceCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl DeviceDiagnosticsConfigFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::ENABLE_SHADER_DEBUG_INFO.0
+                | Self::ENABLE_RESOURCE_TRACKING.0
+                | Self::ENABLE_AUTOMATIC_CHECKPOINTS.0
+                | Self::ENABLE_SHADER_ERROR_REPORTING.0,
+        )
+    }
+}
+impl DeviceGroupPresentModeFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::LOCAL.0 | Self::REMOTE.0 | Self::SUM.0 | Self::LOCAL_MULTI_DEVICE.0)
+    }
+}
+impl DeviceMemoryReportFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl DeviceQueueCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::PROTECTED.0)
+    }
+}
+impl DirectDriverLoadingFlagsLUNARG {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl DirectFBSurfaceCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl DisplayModeCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl DisplayPlaneAlphaFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::OPAQUE.0 | Self::GLOBAL.0 | Self::PER_PIXEL.0 | Self::PER_PIXEL_PREMULTIPLIED.0)
+    }
+}
+impl DisplaySurfaceCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl EventCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::DEVICE_ONLY.0)
+    }
+}
+impl ExportMetalObjectTypeFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::METAL_DEVICE.0
+                | Self::METAL_COMMAND_QUEUE.0
+                | Self::METAL_BUFFER.0
+                | Self::METAL_TEXTURE.0
+                | Self::METAL_IOSURFACE.0
+                | Self::METAL_SHARED_EVENT.0,
+        )
+    }
+}
+impl ExternalFenceFeatureFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::EXPORTABLE.0 | Self::IMPORTABLE.0)
+    }
+}
+impl ExternalFenceHandleTypeFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::OPAQUE_FD.0 | Self::OPAQUE_WIN32.0 | Self::OPAQUE_WIN32_KMT.0 | Self::SYNC_FD.0)
+    }
+}
+impl ExternalMemoryFeatureFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::DEDICATED_ONLY.0 | Self::EXPORTABLE.0 | Self::IMPORTABLE.0)
+    }
+}
+impl ExternalMemoryFeatureFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::DEDICATED_ONLY.0 | Self::EXPORTABLE.0 | Self::IMPORTABLE.0)
+    }
+}
+impl ExternalMemoryHandleTypeFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::OPAQUE_FD.0
+                | Self::OPAQUE_WIN32.0
+                | Self::OPAQUE_WIN32_KMT.0
+                | Self::D3D11_TEXTURE.0
+                | Self::D3D11_TEXTURE_KMT.0
+                | Self::D3D12_HEAP.0
+                | Self::D3D12_RESOURCE.0
+                | Self::DMA_BUF_EXT.0
+                | Self::ANDROID_HARDWARE_BUFFER_ANDROID.0
+                | Self::HOST_ALLOCATION_EXT.0
+                | Self::HOST_MAPPED_FOREIGN_MEMORY_EXT.0
+                | Self::ZIRCON_VMO_FUCHSIA.0
+                | Self::RDMA_ADDRESS_NV.0
+                | Self::SCREEN_BUFFER_QNX.0,
+        )
+    }
+}
+impl ExternalMemoryHandleTypeFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::OPAQUE_WIN32.0
+                | Self::OPAQUE_WIN32_KMT.0
+                | Self::D3D11_IMAGE.0
+                | Self::D3D11_IMAGE_KMT.0,
+        )
+    }
+}
+impl ExternalSemaphoreFeatureFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::EXPORTABLE.0 | Self::IMPORTABLE.0)
+    }
+}
+impl ExternalSemaphoreHandleTypeFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::OPAQUE_FD.0
+                | Self::OPAQUE_WIN32.0
+                | Self::OPAQUE_WIN32_KMT.0
+                | Self::D3D12_FENCE.0
+                | Self::SYNC_FD.0
+                | Self::ZIRCON_EVENT_FUCHSIA.0,
+        )
+    }
+}
+impl FenceCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::SIGNALED.0)
+    }
+}
+impl FenceImportFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::TEMPORARY.0)
+    }
+}
+impl FormatFeatureFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::SAMPLED_IMAGE.0
+                | Self::STORAGE_IMAGE.0
+                | Self::STORAGE_IMAGE_ATOMIC.0
+                | Self::UNIFORM_TEXEL_BUFFER.0
+                | Self::STORAGE_TEXEL_BUFFER.0
+                | Self::STORAGE_TEXEL_BUFFER_ATOMIC.0
+                | Self::VERTEX_BUFFER.0
+                | Self::COLOR_ATTACHMENT.0
+                | Self::COLOR_ATTACHMENT_BLEND.0
+                | Self::DEPTH_STENCIL_ATTACHMENT.0
+                | Self::BLIT_SRC.0
+                | Self::BLIT_DST.0
+                | Self::SAMPLED_IMAGE_FILTER_LINEAR.0
+                | Self::VIDEO_DECODE_OUTPUT_KHR.0
+                | Self::VIDEO_DECODE_DPB_KHR.0
+                | Self::ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR.0
+                | Self::SAMPLED_IMAGE_FILTER_CUBIC_EXT.0
+                | Self::FRAGMENT_DENSITY_MAP_EXT.0
+                | Self::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.0
+                | Self::VIDEO_ENCODE_INPUT_KHR.0
+                | Self::VIDEO_ENCODE_DPB_KHR.0
+                | Self::TRANSFER_SRC.0
+                | Self::TRANSFER_DST.0
+                | Self::MIDPOINT_CHROMA_SAMPLES.0
+                | Self::SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER.0
+                | Self::SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER.0
+                | Self::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT.0
+                | Self::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE.0
+                | Self::DISJOINT.0
+                | Self::COSITED_CHROMA_SAMPLES.0
+                | Self::SAMPLED_IMAGE_FILTER_MINMAX.0,
+        )
+    }
+}
+impl FormatFeatureFlags2 {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::SAMPLED_IMAGE.0
+                | Self::STORAGE_IMAGE.0
+                | Self::STORAGE_IMAGE_ATOMIC.0
+                | Self::UNIFORM_TEXEL_BUFFER.0
+                | Self::STORAGE_TEXEL_BUFFER.0
+                | Self::STORAGE_TEXEL_BUFFER_ATOMIC.0
+                | Self::VERTEX_BUFFER.0
+                | Self::COLOR_ATTACHMENT.0
+                | Self::COLOR_ATTACHMENT_BLEND.0
+                | Self::DEPTH_STENCIL_ATTACHMENT.0
+                | Self::BLIT_SRC.0
+                | Self::BLIT_DST.0
+                | Self::SAMPLED_IMAGE_FILTER_LINEAR.0
+                | Self::SAMPLED_IMAGE_FILTER_CUBIC.0
+                | Self::TRANSFER_SRC.0
+                | Self::TRANSFER_DST.0
+                | Self::SAMPLED_IMAGE_FILTER_MINMAX.0
+                | Self::MIDPOINT_CHROMA_SAMPLES.0
+                | Self::SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER.0
+                | Self::SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER.0
+                | Self::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT.0
+                | Self::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE.0
+                | Self::DISJOINT.0
+                | Self::COSITED_CHROMA_SAMPLES.0
+                | Self::STORAGE_READ_WITHOUT_FORMAT.0
+                | Self::STORAGE_WRITE_WITHOUT_FORMAT.0
+                | Self::SAMPLED_IMAGE_DEPTH_COMPARISON.0
+                | Self::VIDEO_DECODE_OUTPUT_KHR.0
+                | Self::VIDEO_DECODE_DPB_KHR.0
+                | Self::ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR.0
+                | Self::FRAGMENT_DENSITY_MAP_EXT.0
+                | Self::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.0
+                | Self::VIDEO_ENCODE_INPUT_KHR.0
+                | Self::VIDEO_ENCODE_DPB_KHR.0
+                | Self::LINEAR_COLOR_ATTACHMENT_NV.0
+                | Self::WEIGHT_IMAGE_QCOM.0
+                | Self::WEIGHT_SAMPLED_IMAGE_QCOM.0
+                | Self::BLOCK_MATCHING_QCOM.0
+                | Self::BOX_FILTER_SAMPLED_QCOM.0
+                | Self::OPTICAL_FLOW_IMAGE_NV.0
+                | Self::OPTICAL_FLOW_VECTOR_NV.0
+                | Self::OPTICAL_FLOW_COST_NV.0,
+        )
+    }
+}
+impl FramebufferCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::IMAGELESS.0)
+    }
+}
+impl GeometryFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::OPAQUE.0 | Self::NO_DUPLICATE_ANY_HIT_INVOCATION.0)
+    }
+}
+impl GeometryInstanceFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::TRIANGLE_FACING_CULL_DISABLE.0
+                | Self::TRIANGLE_FLIP_FACING.0
+                | Self::FORCE_OPAQUE.0
+                | Self::FORCE_NO_OPAQUE.0
+                | Self::FORCE_OPACITY_MICROMAP_2_STATE_EXT.0
+                | Self::DISABLE_OPACITY_MICROMAPS_EXT.0,
+        )
+    }
+}
+impl GraphicsPipelineLibraryFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::VERTEX_INPUT_INTERFACE.0
+                | Self::PRE_RASTERIZATION_SHADERS.0
+                | Self::FRAGMENT_SHADER.0
+                | Self::FRAGMENT_OUTPUT_INTERFACE.0,
+        )
+    }
+}
+impl HeadlessSurfaceCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl IOSSurfaceCreateFlagsMVK {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl ImageAspectFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::COLOR.0
+                | Self::DEPTH.0
+                | Self::STENCIL.0
+                | Self::METADATA.0
+                | Self::MEMORY_PLANE_0_EXT.0
+                | Self::MEMORY_PLANE_1_EXT.0
+                | Self::MEMORY_PLANE_2_EXT.0
+                | Self::MEMORY_PLANE_3_EXT.0
+                | Self::PLANE_0.0
+                | Self::PLANE_1.0
+                | Self::PLANE_2.0,
+        )
+    }
+}
+impl ImageCompressionFixedRateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::TYPE_1BPC.0
+                | Self::TYPE_2BPC.0
+                | Self::TYPE_3BPC.0
+                | Self::TYPE_4BPC.0
+                | Self::TYPE_5BPC.0
+                | Self::TYPE_6BPC.0
+                | Self::TYPE_7BPC.0
+                | Self::TYPE_8BPC.0
+                | Self::TYPE_9BPC.0
+                | Self::TYPE_10BPC.0
+                | Self::TYPE_11BPC.0
+                | Self::TYPE_12BPC.0
+                | Self::TYPE_13BPC.0
+                | Self::TYPE_14BPC.0
+                | Self::TYPE_15BPC.0
+                | Self::TYPE_16BPC.0
+                | Self::TYPE_17BPC.0
+                | Self::TYPE_18BPC.0
+                | Self::TYPE_19BPC.0
+                | Self::TYPE_20BPC.0
+                | Self::TYPE_21BPC.0
+                | Self::TYPE_22BPC.0
+                | Self::TYPE_23BPC.0
+                | Self::TYPE_24BPC.0,
+        )
+    }
+}
+impl ImageCompressionFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::FIXED_RATE_DEFAULT.0 | Self::FIXED_RATE_EXPLICIT.0 | Self::DISABLED.0)
+    }
+}
+impl ImageConstraintsInfoFlagsFUCHSIA {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::CPU_READ_RARELY.0
+                | Self::CPU_READ_OFTEN.0
+                | Self::CPU_WRITE_RARELY.0
+                | Self::CPU_WRITE_OFTEN.0
+                | Self::PROTECTED_OPTIONAL.0,
+        )
+    }
+}
+impl ImageCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::SPARSE_BINDING.0
+                | Self::SPARSE_RESIDENCY.0
+                | Self::SPARSE_ALIASED.0
+                | Self::MUTABLE_FORMAT.0
+                | Self::CUBE_COMPATIBLE.0
+                | Self::CORNER_SAMPLED_NV.0
+                | Self::SAMPLE_LOCATIONS_COMPATIBLE_DEPTH_EXT.0
+                | Self::SUBSAMPLED_EXT.0
+                | Self::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.0
+                | Self::MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_EXT.0
+                | Self::TYPE_2D_VIEW_COMPATIBLE_EXT.0
+                | Self::FRAGMENT_DENSITY_MAP_OFFSET_QCOM.0
+                | Self::ALIAS.0
+                | Self::SPLIT_INSTANCE_BIND_REGIONS.0
+                | Self::TYPE_2D_ARRAY_COMPATIBLE.0
+                | Self::BLOCK_TEXEL_VIEW_COMPATIBLE.0
+                | Self::EXTENDED_USAGE.0
+                | Self::PROTECTED.0
+                | Self::DISJOINT.0,
+        )
+    }
+}
+impl ImageFormatConstraintsFlagsFUCHSIA {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl ImagePipeSurfaceCreateFlagsFUCHSIA {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl ImageUsageFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::TRANSFER_SRC.0
+                | Self::TRANSFER_DST.0
+                | Self::SAMPLED.0
+                | Self::STORAGE.0
+                | Self::COLOR_ATTACHMENT.0
+                | Self::DEPTH_STENCIL_ATTACHMENT.0
+                | Self::TRANSIENT_ATTACHMENT.0
+                | Self::INPUT_ATTACHMENT.0
+                | Self::VIDEO_DECODE_DST_KHR.0
+                | Self::VIDEO_DECODE_SRC_KHR.0
+                | Self::VIDEO_DECODE_DPB_KHR.0
+                | Self::FRAGMENT_DENSITY_MAP_EXT.0
+                | Self::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.0
+                | Self::VIDEO_ENCODE_DST_KHR.0
+                | Self::VIDEO_ENCODE_SRC_KHR.0
+                | Self::VIDEO_ENCODE_DPB_KHR.0
+                | Self::ATTACHMENT_FEEDBACK_LOOP_EXT.0
+                | Self::INVOCATION_MASK_HUAWEI.0
+                | Self::SAMPLE_WEIGHT_QCOM.0
+                | Self::SAMPLE_BLOCK_MATCH_QCOM.0,
+        )
+    }
+}
+impl ImageViewCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::FRAGMENT_DENSITY_MAP_DYNAMIC_EXT.0
+                | Self::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.0
+                | Self::FRAGMENT_DENSITY_MAP_DEFERRED_EXT.0,
+        )
+    }
+}
+impl IndirectCommandsLayoutUsageFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::EXPLICIT_PREPROCESS.0 | Self::INDEXED_SEQUENCES.0 | Self::UNORDERED_SEQUENCES.0)
+    }
+}
+impl IndirectStateFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::FLAG_FRONTFACE.0)
+    }
+}
+impl InstanceCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::ENUMERATE_PORTABILITY_KHR.0)
+    }
+}
+impl MacOSSurfaceCreateFlagsMVK {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl MemoryAllocateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::DEVICE_MASK.0 | Self::DEVICE_ADDRESS.0 | Self::DEVICE_ADDRESS_CAPTURE_REPLAY.0)
+    }
+}
+impl MemoryDecompressionMethodFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::GDEFLATE_1_0.0)
+    }
+}
+impl MemoryHeapFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::DEVICE_LOCAL.0 | Self::MULTI_INSTANCE.0)
+    }
+}
+impl MemoryMapFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl MemoryPropertyFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::DEVICE_LOCAL.0
+                | Self::HOST_VISIBLE.0
+                | Self::HOST_COHERENT.0
+                | Self::HOST_CACHED.0
+                | Self::LAZILY_ALLOCATED.0
+                | Self::DEVICE_COHERENT_AMD.0
+                | Self::DEVICE_UNCACHED_AMD.0
+                | Self::RDMA_CAPABLE_NV.0
+                | Self::PROTECTED.0,
+        )
+    }
+}
+impl MemoryUnmapFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl MetalSurfaceCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl MicromapCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::DEVICE_ADDRESS_CAPTURE_REPLAY.0)
+    }
+}
+impl OpticalFlowExecuteFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::DISABLE_TEMPORAL_HINTS.0)
+    }
+}
+impl OpticalFlowGridSizeFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::TYPE_1X1.0 | Self::TYPE_2X2.0 | Self::TYPE_4X4.0 | Self::TYPE_8X8.0)
+    }
+}
+impl OpticalFlowSessionCreateFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::ENABLE_HINT.0
+                | Self::ENABLE_COST.0
+                | Self::ENABLE_GLOBAL_FLOW.0
+                | Self::ALLOW_REGIONS.0
+                | Self::BOTH_DIRECTIONS.0,
+        )
+    }
+}
+impl OpticalFlowUsageFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::INPUT.0 | Self::OUTPUT.0 | Self::HINT.0 | Self::COST.0 | Self::GLOBAL_FLOW.0)
+    }
+}
+impl PeerMemoryFeatureFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::COPY_SRC.0 | Self::COPY_DST.0 | Self::GENERIC_SRC.0 | Self::GENERIC_DST.0)
+    }
+}
+impl PerformanceCounterDescriptionFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::PERFORMANCE_IMPACTING.0 | Self::CONCURRENTLY_IMPACTED.0)
+    }
+}
+impl PipelineCacheCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::EXTERNALLY_SYNCHRONIZED.0)
+    }
+}
+impl PipelineColorBlendStateCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::RASTERIZATION_ORDER_ATTACHMENT_ACCESS_EXT.0)
+    }
+}
+impl PipelineCompilerControlFlagsAMD {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineCoverageModulationStateCreateFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineCoverageReductionStateCreateFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineCoverageToColorStateCreateFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::DISABLE_OPTIMIZATION.0
+                | Self::ALLOW_DERIVATIVES.0
+                | Self::DERIVATIVE.0
+                | Self::RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.0
+                | Self::RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_EXT.0
+                | Self::RAY_TRACING_NO_NULL_ANY_HIT_SHADERS_KHR.0
+                | Self::RAY_TRACING_NO_NULL_CLOSEST_HIT_SHADERS_KHR.0
+                | Self::RAY_TRACING_NO_NULL_MISS_SHADERS_KHR.0
+                | Self::RAY_TRACING_NO_NULL_INTERSECTION_SHADERS_KHR.0
+                | Self::RAY_TRACING_SKIP_TRIANGLES_KHR.0
+                | Self::RAY_TRACING_SKIP_AABBS_KHR.0
+                | Self::RAY_TRACING_SHADER_GROUP_HANDLE_CAPTURE_REPLAY_KHR.0
+                | Self::DEFER_COMPILE_NV.0
+                | Self::CAPTURE_STATISTICS_KHR.0
+                | Self::CAPTURE_INTERNAL_REPRESENTATIONS_KHR.0
+                | Self::INDIRECT_BINDABLE_NV.0
+                | Self::LIBRARY_KHR.0
+                | Self::DESCRIPTOR_BUFFER_EXT.0
+                | Self::RETAIN_LINK_TIME_OPTIMIZATION_INFO_EXT.0
+                | Self::LINK_TIME_OPTIMIZATION_EXT.0
+                | Self::RAY_TRACING_ALLOW_MOTION_NV.0
+                | Self::COLOR_ATTACHMENT_FEEDBACK_LOOP_EXT.0
+                | Self::DEPTH_STENCIL_ATTACHMENT_FEEDBACK_LOOP_EXT.0
+                | Self::RAY_TRACING_OPACITY_MICROMAP_EXT.0
+                | Self::RAY_TRACING_DISPLACEMENT_MICROMAP_NV.0
+                | Self::NO_PROTECTED_ACCESS_EXT.0
+                | Self::PROTECTED_ACCESS_ONLY_EXT.0
+                | Self::VIEW_INDEX_FROM_DEVICE_INDEX.0
+                | Self::DISPATCH_BASE.0
+                | Self::FAIL_ON_PIPELINE_COMPILE_REQUIRED.0
+                | Self::EARLY_RETURN_ON_FAILURE.0,
+        )
+    }
+}
+impl PipelineCreationFeedbackFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::VALID.0
+                | Self::APPLICATION_PIPELINE_CACHE_HIT.0
+                | Self::BASE_PIPELINE_ACCELERATION.0,
+        )
+    }
+}
+impl PipelineDepthStencilStateCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_EXT.0
+                | Self::RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_EXT.0,
+        )
+    }
+}
+impl PipelineDiscardRectangleStateCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineDynamicStateCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineInputAssemblyStateCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineLayoutCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::INDEPENDENT_SETS_EXT.0)
+    }
+}
+impl PipelineMultisampleStateCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineRasterizationConservativeStateCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineRasterizationDepthClipStateCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineRasterizationStateCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineRasterizationStateStreamCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineShaderStageCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::ALLOW_VARYING_SUBGROUP_SIZE.0 | Self::REQUIRE_FULL_SUBGROUPS.0)
+    }
+}
+impl PipelineStageFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::TOP_OF_PIPE.0
+                | Self::DRAW_INDIRECT.0
+                | Self::VERTEX_INPUT.0
+                | Self::VERTEX_SHADER.0
+                | Self::TESSELLATION_CONTROL_SHADER.0
+                | Self::TESSELLATION_EVALUATION_SHADER.0
+                | Self::GEOMETRY_SHADER.0
+                | Self::FRAGMENT_SHADER.0
+                | Self::EARLY_FRAGMENT_TESTS.0
+                | Self::LATE_FRAGMENT_TESTS.0
+                | Self::COLOR_ATTACHMENT_OUTPUT.0
+                | Self::COMPUTE_SHADER.0
+                | Self::TRANSFER.0
+                | Self::BOTTOM_OF_PIPE.0
+                | Self::HOST.0
+                | Self::ALL_GRAPHICS.0
+                | Self::ALL_COMMANDS.0
+                | Self::TRANSFORM_FEEDBACK_EXT.0
+                | Self::CONDITIONAL_RENDERING_EXT.0
+                | Self::ACCELERATION_STRUCTURE_BUILD_KHR.0
+                | Self::RAY_TRACING_SHADER_KHR.0
+                | Self::FRAGMENT_DENSITY_PROCESS_EXT.0
+                | Self::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.0
+                | Self::COMMAND_PREPROCESS_NV.0
+                | Self::TASK_SHADER_EXT.0
+                | Self::MESH_SHADER_EXT.0,
+        )
+    }
+}
+impl PipelineStageFlags2 {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::TOP_OF_PIPE.0
+                | Self::DRAW_INDIRECT.0
+                | Self::VERTEX_INPUT.0
+                | Self::VERTEX_SHADER.0
+                | Self::TESSELLATION_CONTROL_SHADER.0
+                | Self::TESSELLATION_EVALUATION_SHADER.0
+                | Self::GEOMETRY_SHADER.0
+                | Self::FRAGMENT_SHADER.0
+                | Self::EARLY_FRAGMENT_TESTS.0
+                | Self::LATE_FRAGMENT_TESTS.0
+                | Self::COLOR_ATTACHMENT_OUTPUT.0
+                | Self::COMPUTE_SHADER.0
+                | Self::ALL_TRANSFER.0
+                | Self::BOTTOM_OF_PIPE.0
+                | Self::HOST.0
+                | Self::ALL_GRAPHICS.0
+                | Self::ALL_COMMANDS.0
+                | Self::COPY.0
+                | Self::RESOLVE.0
+                | Self::BLIT.0
+                | Self::CLEAR.0
+                | Self::INDEX_INPUT.0
+                | Self::VERTEX_ATTRIBUTE_INPUT.0
+                | Self::PRE_RASTERIZATION_SHADERS.0
+                | Self::VIDEO_DECODE_KHR.0
+                | Self::VIDEO_ENCODE_KHR.0
+                | Self::TRANSFORM_FEEDBACK_EXT.0
+                | Self::CONDITIONAL_RENDERING_EXT.0
+                | Self::COMMAND_PREPROCESS_NV.0
+                | Self::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.0
+                | Self::ACCELERATION_STRUCTURE_BUILD_KHR.0
+                | Self::RAY_TRACING_SHADER_KHR.0
+                | Self::FRAGMENT_DENSITY_PROCESS_EXT.0
+                | Self::TASK_SHADER_EXT.0
+                | Self::MESH_SHADER_EXT.0
+                | Self::SUBPASS_SHADING_HUAWEI.0
+                | Self::INVOCATION_MASK_HUAWEI.0
+                | Self::ACCELERATION_STRUCTURE_COPY_KHR.0
+                | Self::MICROMAP_BUILD_EXT.0
+                | Self::CLUSTER_CULLING_SHADER_HUAWEI.0
+                | Self::OPTICAL_FLOW_NV.0,
+        )
+    }
+}
+impl PipelineTessellationStateCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineVertexInputStateCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineViewportStateCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PipelineViewportSwizzleStateCreateFlagsNV {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl PresentGravityFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::MIN.0 | Self::MAX.0 | Self::CENTERED.0)
+    }
+}
+impl PresentScalingFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::ONE_TO_ONE.0 | Self::ASPECT_RATIO_STRETCH.0 | Self::STRETCH.0)
+    }
+}
+impl PrivateDataSlotCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl QueryControlFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::PRECISE.0)
+    }
+}
+impl QueryPipelineStatisticFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::INPUT_ASSEMBLY_VERTICES.0
+                | Self::INPUT_ASSEMBLY_PRIMITIVES.0
+                | Self::VERTEX_SHADER_INVOCATIONS.0
+                | Self::GEOMETRY_SHADER_INVOCATIONS.0
+                | Self::GEOMETRY_SHADER_PRIMITIVES.0
+                | Self::CLIPPING_INVOCATIONS.0
+                | Self::CLIPPING_PRIMITIVES.0
+                | Self::FRAGMENT_SHADER_INVOCATIONS.0
+                | Self::TESSELLATION_CONTROL_SHADER_PATCHES.0
+                | Self::TESSELLATION_EVALUATION_SHADER_INVOCATIONS.0
+                | Self::COMPUTE_SHADER_INVOCATIONS.0
+                | Self::TASK_SHADER_INVOCATIONS_EXT.0
+                | Self::MESH_SHADER_INVOCATIONS_EXT.0
+                | Self::CLUSTER_CULLING_SHADER_INVOCATIONS_HUAWEI.0,
+        )
+    }
+}
+impl QueryPoolCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl QueryResultFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::TYPE_64.0
+                | Self::WAIT.0
+                | Self::WITH_AVAILABILITY.0
+                | Self::PARTIAL.0
+                | Self::WITH_STATUS_KHR.0,
+        )
+    }
+}
+impl QueueFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::GRAPHICS.0
+                | Self::COMPUTE.0
+                | Self::TRANSFER.0
+                | Self::SPARSE_BINDING.0
+                | Self::VIDEO_DECODE_KHR.0
+                | Self::VIDEO_ENCODE_KHR.0
+                | Self::OPTICAL_FLOW_NV.0
+                | Self::PROTECTED.0,
+        )
+    }
+}
+impl RenderPassCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::TRANSFORM_QCOM.0)
+    }
+}
+impl RenderingFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::CONTENTS_SECONDARY_COMMAND_BUFFERS.0
+                | Self::SUSPENDING.0
+                | Self::RESUMING.0
+                | Self::ENABLE_LEGACY_DITHERING_EXT.0,
+        )
+    }
+}
+impl ResolveModeFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::SAMPLE_ZERO.0 | Self::AVERAGE.0 | Self::MIN.0 | Self::MAX.0)
+    }
+}
+impl SampleCountFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::TYPE_1.0
+                | Self::TYPE_2.0
+                | Self::TYPE_4.0
+                | Self::TYPE_8.0
+                | Self::TYPE_16.0
+                | Self::TYPE_32.0
+                | Self::TYPE_64.0,
+        )
+    }
+}
+impl SamplerCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::SUBSAMPLED_EXT.0
+                | Self::SUBSAMPLED_COARSE_RECONSTRUCTION_EXT.0
+                | Self::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.0
+                | Self::NON_SEAMLESS_CUBE_MAP_EXT.0
+                | Self::IMAGE_PROCESSING_QCOM.0,
+        )
+    }
+}
+impl ScreenSurfaceCreateFlagsQNX {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl SemaphoreCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl SemaphoreImportFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::TEMPORARY.0)
+    }
+}
+impl SemaphoreWaitFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::ANY.0)
+    }
+}
+impl ShaderCorePropertiesFlagsAMD {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl ShaderCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::LINK_STAGE.0
+                | Self::ALLOW_VARYING_SUBGROUP_SIZE.0
+                | Self::REQUIRE_FULL_SUBGROUPS.0
+                | Self::NO_TASK_SHADER.0
+                | Self::DISPATCH_BASE.0
+                | Self::FRAGMENT_SHADING_RATE_ATTACHMENT.0
+                | Self::FRAGMENT_DENSITY_MAP_ATTACHMENT.0,
+        )
+    }
+}
+impl ShaderModuleCreateFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl ShaderStageFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::VERTEX.0
+                | Self::TESSELLATION_CONTROL.0
+                | Self::TESSELLATION_EVALUATION.0
+                | Self::GEOMETRY.0
+                | Self::FRAGMENT.0
+                | Self::COMPUTE.0
+                | Self::RAYGEN_KHR.0
+                | Self::ANY_HIT_KHR.0
+                | Self::CLOSEST_HIT_KHR.0
+                | Self::MISS_KHR.0
+                | Self::INTERSECTION_KHR.0
+                | Self::CALLABLE_KHR.0
+                | Self::TASK_EXT.0
+                | Self::MESH_EXT.0
+                | Self::SUBPASS_SHADING_HUAWEI.0
+                | Self::CLUSTER_CULLING_HUAWEI.0,
+        )
+    }
+}
+impl SparseImageFormatFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::SINGLE_MIPTAIL.0 | Self::ALIGNED_MIP_SIZE.0 | Self::NONSTANDARD_BLOCK_SIZE.0)
+    }
+}
+impl SparseMemoryBindFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::METADATA.0)
+    }
+}
+impl StencilFaceFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::FRONT.0 | Self::BACK.0)
+    }
+}
+impl StreamDescriptorSurfaceCreateFlagsGGP {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl SubgroupFeatureFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::BASIC.0
+                | Self::VOTE.0
+                | Self::ARITHMETIC.0
+                | Self::BALLOT.0
+                | Self::SHUFFLE.0
+                | Self::SHUFFLE_RELATIVE.0
+                | Self::CLUSTERED.0
+                | Self::QUAD.0
+                | Self::PARTITIONED_NV.0,
+        )
+    }
+}
+impl SubmitFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::PROTECTED.0)
+    }
+}
+impl SubpassDescriptionFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::PER_VIEW_ATTRIBUTES_NVX.0
+                | Self::PER_VIEW_POSITION_X_ONLY_NVX.0
+                | Self::FRAGMENT_REGION_QCOM.0
+                | Self::SHADER_RESOLVE_QCOM.0
+                | Self::RASTERIZATION_ORDER_ATTACHMENT_COLOR_ACCESS_EXT.0
+                | Self::RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_EXT.0
+                | Self::RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_EXT.0
+                | Self::ENABLE_LEGACY_DITHERING_EXT.0,
+        )
+    }
+}
+impl SurfaceCounterFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::VBLANK.0)
+    }
+}
+impl SurfaceTransformFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::IDENTITY.0
+                | Self::ROTATE_90.0
+                | Self::ROTATE_180.0
+                | Self::ROTATE_270.0
+                | Self::HORIZONTAL_MIRROR.0
+                | Self::HORIZONTAL_MIRROR_ROTATE_90.0
+                | Self::HORIZONTAL_MIRROR_ROTATE_180.0
+                | Self::HORIZONTAL_MIRROR_ROTATE_270.0
+                | Self::INHERIT.0,
+        )
+    }
+}
+impl SwapchainCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::SPLIT_INSTANCE_BIND_REGIONS.0
+                | Self::PROTECTED.0
+                | Self::MUTABLE_FORMAT.0
+                | Self::DEFERRED_MEMORY_ALLOCATION_EXT.0,
+        )
+    }
+}
+impl SwapchainImageUsageFlagsANDROID {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::SHARED.0)
+    }
+}
+impl ToolPurposeFlags {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::VALIDATION.0
+                | Self::PROFILING.0
+                | Self::TRACING.0
+                | Self::ADDITIONAL_FEATURES.0
+                | Self::MODIFYING_FEATURES.0
+                | Self::DEBUG_REPORTING_EXT.0
+                | Self::DEBUG_MARKERS_EXT.0,
+        )
+    }
+}
+impl ValidationCacheCreateFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl ViSurfaceCreateFlagsNN {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl VideoBeginCodingFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl VideoCapabilityFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::PROTECTED_CONTENT.0 | Self::SEPARATE_REFERENCE_IMAGES.0)
+    }
+}
+impl VideoChromaSubsamplingFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::MONOCHROME.0 | Self::TYPE_420.0 | Self::TYPE_422.0 | Self::TYPE_444.0)
+    }
+}
+impl VideoCodecOperationFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::ENCODE_H264_EXT.0
+                | Self::ENCODE_H265_EXT.0
+                | Self::DECODE_H264.0
+                | Self::DECODE_H265.0,
+        )
+    }
+}
+impl VideoCodingControlFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::RESET.0 | Self::ENCODE_RATE_CONTROL.0 | Self::ENCODE_QUALITY_LEVEL.0)
+    }
+}
+impl VideoComponentBitDepthFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::TYPE_8.0 | Self::TYPE_10.0 | Self::TYPE_12.0)
+    }
+}
+impl VideoDecodeCapabilityFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::DPB_AND_OUTPUT_COINCIDE.0 | Self::DPB_AND_OUTPUT_DISTINCT.0)
+    }
+}
+impl VideoDecodeFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl VideoDecodeH264PictureLayoutFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::INTERLACED_INTERLEAVED_LINES.0 | Self::INTERLACED_SEPARATE_PLANES.0)
+    }
+}
+impl VideoDecodeUsageFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::TRANSCODING.0 | Self::OFFLINE.0 | Self::STREAMING.0)
+    }
+}
+impl VideoEncodeCapabilityFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::PRECEDING_EXTERNALLY_ENCODED_BYTES.0)
+    }
+}
+impl VideoEncodeContentFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::CAMERA.0 | Self::DESKTOP.0 | Self::RENDERED.0)
+    }
+}
+impl VideoEncodeFeedbackFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::BITSTREAM_BUFFER_OFFSET.0
+                | Self::BITSTREAM_BYTES_WRITTEN.0
+                | Self::BITSTREAM_HAS_OVERRIDES.0,
+        )
+    }
+}
+impl VideoEncodeFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl VideoEncodeH264CapabilityFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::HRD_COMPLIANCE.0
+                | Self::PREDICTION_WEIGHT_TABLE_GENERATED.0
+                | Self::ROW_UNALIGNED_SLICE.0
+                | Self::DIFFERENT_SLICE_TYPE.0
+                | Self::B_FRAME_IN_L0_LIST.0
+                | Self::B_FRAME_IN_L1_LIST.0
+                | Self::PER_PICTURE_TYPE_MIN_MAX_QP.0
+                | Self::PER_SLICE_CONSTANT_QP.0
+                | Self::GENERATE_PREFIX_NALU.0,
+        )
+    }
+}
+impl VideoEncodeH264RateControlFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::ATTEMPT_HRD_COMPLIANCE.0
+                | Self::REGULAR_GOP.0
+                | Self::REFERENCE_PATTERN_FLAT.0
+                | Self::REFERENCE_PATTERN_DYADIC.0
+                | Self::TEMPORAL_LAYER_PATTERN_DYADIC.0,
+        )
+    }
+}
+impl VideoEncodeH264StdFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::SEPARATE_COLOR_PLANE_FLAG_SET.0
+                | Self::QPPRIME_Y_ZERO_TRANSFORM_BYPASS_FLAG_SET.0
+                | Self::SCALING_MATRIX_PRESENT_FLAG_SET.0
+                | Self::CHROMA_QP_INDEX_OFFSET.0
+                | Self::SECOND_CHROMA_QP_INDEX_OFFSET.0
+                | Self::PIC_INIT_QP_MINUS26.0
+                | Self::WEIGHTED_PRED_FLAG_SET.0
+                | Self::WEIGHTED_BIPRED_IDC_EXPLICIT.0
+                | Self::WEIGHTED_BIPRED_IDC_IMPLICIT.0
+                | Self::TRANSFORM_8X8_MODE_FLAG_SET.0
+                | Self::DIRECT_SPATIAL_MV_PRED_FLAG_UNSET.0
+                | Self::ENTROPY_CODING_MODE_FLAG_UNSET.0
+                | Self::ENTROPY_CODING_MODE_FLAG_SET.0
+                | Self::DIRECT_8X8_INFERENCE_FLAG_UNSET.0
+                | Self::CONSTRAINED_INTRA_PRED_FLAG_SET.0
+                | Self::DEBLOCKING_FILTER_DISABLED.0
+                | Self::DEBLOCKING_FILTER_ENABLED.0
+                | Self::DEBLOCKING_FILTER_PARTIAL.0,
+        )
+    }
+}
+impl VideoEncodeH265CapabilityFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::HRD_COMPLIANCE.0
+                | Self::PREDICTION_WEIGHT_TABLE_GENERATED.0
+                | Self::ROW_UNALIGNED_SLICE_SEGMENT.0
+                | Self::DIFFERENT_SLICE_SEGMENT_TYPE.0
+                | Self::B_FRAME_IN_L0_LIST.0
+                | Self::B_FRAME_IN_L1_LIST.0
+                | Self::PER_PICTURE_TYPE_MIN_MAX_QP.0
+                | Self::PER_SLICE_SEGMENT_CONSTANT_QP.0
+                | Self::MULTIPLE_TILES_PER_SLICE_SEGMENT.0
+                | Self::MULTIPLE_SLICE_SEGMENTS_PER_TILE.0,
+        )
+    }
+}
+impl VideoEncodeH265CtbSizeFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::TYPE_16.0 | Self::TYPE_32.0 | Self::TYPE_64.0)
+    }
+}
+impl VideoEncodeH265RateControlFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::ATTEMPT_HRD_COMPLIANCE.0
+                | Self::REGULAR_GOP.0
+                | Self::REFERENCE_PATTERN_FLAT.0
+                | Self::REFERENCE_PATTERN_DYADIC.0
+                | Self::TEMPORAL_SUB_LAYER_PATTERN_DYADIC.0,
+        )
+    }
+}
+impl VideoEncodeH265StdFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(
+            Self::SEPARATE_COLOR_PLANE_FLAG_SET.0
+                | Self::SAMPLE_ADAPTIVE_OFFSET_ENABLED_FLAG_SET.0
+                | Self::SCALING_LIST_DATA_PRESENT_FLAG_SET.0
+                | Self::PCM_ENABLED_FLAG_SET.0
+                | Self::SPS_TEMPORAL_MVP_ENABLED_FLAG_SET.0
+                | Self::INIT_QP_MINUS26.0
+                | Self::WEIGHTED_PRED_FLAG_SET.0
+                | Self::WEIGHTED_BIPRED_FLAG_SET.0
+                | Self::LOG2_PARALLEL_MERGE_LEVEL_MINUS2.0
+                | Self::SIGN_DATA_HIDING_ENABLED_FLAG_SET.0
+                | Self::TRANSFORM_SKIP_ENABLED_FLAG_SET.0
+                | Self::TRANSFORM_SKIP_ENABLED_FLAG_UNSET.0
+                | Self::PPS_SLICE_CHROMA_QP_OFFSETS_PRESENT_FLAG_SET.0
+                | Self::TRANSQUANT_BYPASS_ENABLED_FLAG_SET.0
+                | Self::CONSTRAINED_INTRA_PRED_FLAG_SET.0
+                | Self::ENTROPY_CODING_SYNC_ENABLED_FLAG_SET.0
+                | Self::DEBLOCKING_FILTER_OVERRIDE_ENABLED_FLAG_SET.0
+                | Self::DEPENDENT_SLICE_SEGMENTS_ENABLED_FLAG_SET.0
+                | Self::DEPENDENT_SLICE_SEGMENT_FLAG_SET.0,
+        )
+    }
+}
+impl VideoEncodeH265TransformBlockSizeFlagsEXT {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::TYPE_4.0 | Self::TYPE_8.0 | Self::TYPE_16.0 | Self::TYPE_32.0)
+    }
+}
+impl VideoEncodeRateControlFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl VideoEncodeRateControlModeFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::DISABLED.0 | Self::CBR.0 | Self::VBR.0)
+    }
+}
+impl VideoEncodeUsageFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::TRANSCODING.0 | Self::STREAMING.0 | Self::RECORDING.0 | Self::CONFERENCING.0)
+    }
+}
+impl VideoEndCodingFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl VideoSessionCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(Self::PROTECTED_CONTENT.0 | Self::ALLOW_ENCODE_PARAMETER_OPTIMIZATIONS.0)
+    }
+}
+impl VideoSessionParametersCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl WaylandSurfaceCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl Win32SurfaceCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl XcbSurfaceCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl XlibSurfaceCreateFlagsKHR {
+    #[doc = r" Every bit with a name in the Vulkan specification"]
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0)
+    }
+}
+impl AccelerationStructureCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEVICE_ADDRESS_CAPTURE_REPLAY.0,
+            "DEVICE_ADDRESS_CAPTURE_REPLAY",
+            "VK_ACCELERATION_STRUCTURE_CREATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT_KHR",
+        ),
+        (
+            Self::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.0,
+            "DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT",
+            "VK_ACCELERATION_STRUCTURE_CREATE_DESCRIPTOR_BUFFER_CAPTURE_REPLAY_BIT_EXT",
+        ),
+        (
+            Self::MOTION_NV.0,
+            "MOTION_NV",
+            "VK_ACCELERATION_STRUCTURE_CREATE_MOTION_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for AccelerationStructureCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("AccelerationStructureCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AccelerationStructureCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AccelerationStructureMotionInfoFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for AccelerationStructureMotionInfoFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("AccelerationStructureMotionInfoFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AccelerationStructureMotionInfoFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AccelerationStructureMotionInstanceFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for AccelerationStructureMotionInstanceFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("AccelerationStructureMotionInstanceFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AccelerationStructureMotionInstanceFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AccessFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::INDIRECT_COMMAND_READ.0,
+            "INDIRECT_COMMAND_READ",
+            "VK_ACCESS_INDIRECT_COMMAND_READ_BIT",
+        ),
+        (Self::INDEX_READ.0, "INDEX_READ", "VK_ACCESS_INDEX_READ_BIT"),
+        (
+            Self::VERTEX_ATTRIBUTE_READ.0,
+            "VERTEX_ATTRIBUTE_READ",
+            "VK_ACCESS_VERTEX_ATTRIBUTE_READ_BIT",
+        ),
+        (
+            Self::UNIFORM_READ.0,
+            "UNIFORM_READ",
+            "VK_ACCESS_UNIFORM_READ_BIT",
+        ),
+        (
+            Self::INPUT_ATTACHMENT_READ.0,
+            "INPUT_ATTACHMENT_READ",
+            "VK_ACCESS_INPUT_ATTACHMENT_READ_BIT",
+        ),
+        (
+            Self::SHADER_READ.0,
+            "SHADER_READ",
+            "VK_ACCESS_SHADER_READ_BIT",
+        ),
+        (
+            Self::SHADER_WRITE.0,
+            "SHADER_WRITE",
+            "VK_ACCESS_SHADER_WRITE_BIT",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_READ.0,
+            "COLOR_ATTACHMENT_READ",
+            "VK_ACCESS_COLOR_ATTACHMENT_READ_BIT",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_WRITE.0,
+            "COLOR_ATTACHMENT_WRITE",
+            "VK_ACCESS_COLOR_ATTACHMENT_WRITE_BIT",
+        ),
+        (
+            Self::DEPTH_STENCIL_ATTACHMENT_READ.0,
+            "DEPTH_STENCIL_ATTACHMENT_READ",
+            "VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_READ_BIT",
+        ),
+        (
+            Self::DEPTH_STENCIL_ATTACHMENT_WRITE.0,
+            "DEPTH_STENCIL_ATTACHMENT_WRITE",
+            "VK_ACCESS_DEPTH_STENCIL_ATTACHMENT_WRITE_BIT",
+        ),
+        (
+            Self::TRANSFER_READ.0,
+            "TRANSFER_READ",
+            "VK_ACCESS_TRANSFER_READ_BIT",
+        ),
+        (
+            Self::TRANSFER_WRITE.0,
+            "TRANSFER_WRITE",
+            "VK_ACCESS_TRANSFER_WRITE_BIT",
+        ),
+        (Self::HOST_READ.0, "HOST_READ", "VK_ACCESS_HOST_READ_BIT"),
+        (Self::HOST_WRITE.0, "HOST_WRITE", "VK_ACCESS_HOST_WRITE_BIT"),
+        (
+            Self::MEMORY_READ.0,
+            "MEMORY_READ",
+            "VK_ACCESS_MEMORY_READ_BIT",
+        ),
+        (
+            Self::MEMORY_WRITE.0,
+            "MEMORY_WRITE",
+            "VK_ACCESS_MEMORY_WRITE_BIT",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_WRITE_EXT.0,
+            "TRANSFORM_FEEDBACK_WRITE_EXT",
+            "VK_ACCESS_TRANSFORM_FEEDBACK_WRITE_BIT_EXT",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_COUNTER_READ_EXT.0,
+            "TRANSFORM_FEEDBACK_COUNTER_READ_EXT",
+            "VK_ACCESS_TRANSFORM_FEEDBACK_COUNTER_READ_BIT_EXT",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_COUNTER_WRITE_EXT.0,
+            "TRANSFORM_FEEDBACK_COUNTER_WRITE_EXT",
+            "VK_ACCESS_TRANSFORM_FEEDBACK_COUNTER_WRITE_BIT_EXT",
+        ),
+        (
+            Self::CONDITIONAL_RENDERING_READ_EXT.0,
+            "CONDITIONAL_RENDERING_READ_EXT",
+            "VK_ACCESS_CONDITIONAL_RENDERING_READ_BIT_EXT",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_READ_NONCOHERENT_EXT.0,
+            "COLOR_ATTACHMENT_READ_NONCOHERENT_EXT",
+            "VK_ACCESS_COLOR_ATTACHMENT_READ_NONCOHERENT_BIT_EXT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_READ_KHR.0,
+            "ACCELERATION_STRUCTURE_READ_KHR",
+            "VK_ACCESS_ACCELERATION_STRUCTURE_READ_BIT_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_WRITE_KHR.0,
+            "ACCELERATION_STRUCTURE_WRITE_KHR",
+            "VK_ACCESS_ACCELERATION_STRUCTURE_WRITE_BIT_KHR",
+        ),
+        (
+            Self::FRAGMENT_DENSITY_MAP_READ_EXT.0,
+            "FRAGMENT_DENSITY_MAP_READ_EXT",
+            "VK_ACCESS_FRAGMENT_DENSITY_MAP_READ_BIT_EXT",
+        ),
+        (
+            Self::FRAGMENT_SHADING_RATE_ATTACHMENT_READ_KHR.0,
+            "FRAGMENT_SHADING_RATE_ATTACHMENT_READ_KHR",
+            "VK_ACCESS_FRAGMENT_SHADING_RATE_ATTACHMENT_READ_BIT_KHR",
+        ),
+        (
+            Self::COMMAND_PREPROCESS_READ_NV.0,
+            "COMMAND_PREPROCESS_READ_NV",
+            "VK_ACCESS_COMMAND_PREPROCESS_READ_BIT_NV",
+        ),
+        (
+            Self::COMMAND_PREPROCESS_WRITE_NV.0,
+            "COMMAND_PREPROCESS_WRITE_NV",
+            "VK_ACCESS_COMMAND_PREPROCESS_WRITE_BIT_NV",
+        ),
+        (Self::NONE.0, "NONE", "VK_ACCESS_NONE"),
+        (
+            Self::SHADING_RATE_IMAGE_READ_NV.0,
+            "SHADING_RATE_IMAGE_READ_NV",
+            "VK_ACCESS_SHADING_RATE_IMAGE_READ_BIT_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_READ_NV.0,
+            "ACCELERATION_STRUCTURE_READ_NV",
+            "VK_ACCESS_ACCELERATION_STRUCTURE_READ_BIT_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_WRITE_NV.0,
+            "ACCELERATION_STRUCTURE_WRITE_NV",
+            "VK_ACCESS_ACCELERATION_STRUCTURE_WRITE_BIT_NV",
+        ),
+        (Self::NONE_KHR.0, "NONE_KHR", "VK_ACCESS_NONE_KHR"),
+    ];
+}
+impl FromStr for AccessFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("AccessFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AccessFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AccessFlags2 {
+    pub(crate) const KNOWN: Known<Flags64> = &[
+        (Self::NONE.0, "NONE", "VK_ACCESS_2_NONE"),
+        (
+            Self::INDIRECT_COMMAND_READ.0,
+            "INDIRECT_COMMAND_READ",
+            "VK_ACCESS_2_INDIRECT_COMMAND_READ_BIT",
+        ),
+        (
+            Self::INDEX_READ.0,
+            "INDEX_READ",
+            "VK_ACCESS_2_INDEX_READ_BIT",
+        ),
+        (
+            Self::VERTEX_ATTRIBUTE_READ.0,
+            "VERTEX_ATTRIBUTE_READ",
+            "VK_ACCESS_2_VERTEX_ATTRIBUTE_READ_BIT",
+        ),
+        (
+            Self::UNIFORM_READ.0,
+            "UNIFORM_READ",
+            "VK_ACCESS_2_UNIFORM_READ_BIT",
+        ),
+        (
+            Self::INPUT_ATTACHMENT_READ.0,
+            "INPUT_ATTACHMENT_READ",
+            "VK_ACCESS_2_INPUT_ATTACHMENT_READ_BIT",
+        ),
+        (
+            Self::SHADER_READ.0,
+            "SHADER_READ",
+            "VK_ACCESS_2_SHADER_READ_BIT",
+        ),
+        (
+            Self::SHADER_WRITE.0,
+            "SHADER_WRITE",
+            "VK_ACCESS_2_SHADER_WRITE_BIT",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_READ.0,
+            "COLOR_ATTACHMENT_READ",
+            "VK_ACCESS_2_COLOR_ATTACHMENT_READ_BIT",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_WRITE.0,
+            "COLOR_ATTACHMENT_WRITE",
+            "VK_ACCESS_2_COLOR_ATTACHMENT_WRITE_BIT",
+        ),
+        (
+            Self::DEPTH_STENCIL_ATTACHMENT_READ.0,
+            "DEPTH_STENCIL_ATTACHMENT_READ",
+            "VK_ACCESS_2_DEPTH_STENCIL_ATTACHMENT_READ_BIT",
+        ),
+        (
+            Self::DEPTH_STENCIL_ATTACHMENT_WRITE.0,
+            "DEPTH_STENCIL_ATTACHMENT_WRITE",
+            "VK_ACCESS_2_DEPTH_STENCIL_ATTACHMENT_WRITE_BIT",
+        ),
+        (
+            Self::TRANSFER_READ.0,
+            "TRANSFER_READ",
+            "VK_ACCESS_2_TRANSFER_READ_BIT",
+        ),
+        (
+            Self::TRANSFER_WRITE.0,
+            "TRANSFER_WRITE",
+            "VK_ACCESS_2_TRANSFER_WRITE_BIT",
+        ),
+        (Self::HOST_READ.0, "HOST_READ", "VK_ACCESS_2_HOST_READ_BIT"),
+        (
+            Self::HOST_WRITE.0,
+            "HOST_WRITE",
+            "VK_ACCESS_2_HOST_WRITE_BIT",
+        ),
+        (
+            Self::MEMORY_READ.0,
+            "MEMORY_READ",
+            "VK_ACCESS_2_MEMORY_READ_BIT",
+        ),
+        (
+            Self::MEMORY_WRITE.0,
+            "MEMORY_WRITE",
+            "VK_ACCESS_2_MEMORY_WRITE_BIT",
+        ),
+        (
+            Self::SHADER_SAMPLED_READ.0,
+            "SHADER_SAMPLED_READ",
+            "VK_ACCESS_2_SHADER_SAMPLED_READ_BIT",
+        ),
+        (
+            Self::SHADER_STORAGE_READ.0,
+            "SHADER_STORAGE_READ",
+            "VK_ACCESS_2_SHADER_STORAGE_READ_BIT",
+        ),
+        (
+            Self::SHADER_STORAGE_WRITE.0,
+            "SHADER_STORAGE_WRITE",
+            "VK_ACCESS_2_SHADER_STORAGE_WRITE_BIT",
+        ),
+        (
+            Self::VIDEO_DECODE_READ_KHR.0,
+            "VIDEO_DECODE_READ_KHR",
+            "VK_ACCESS_2_VIDEO_DECODE_READ_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_WRITE_KHR.0,
+            "VIDEO_DECODE_WRITE_KHR",
+            "VK_ACCESS_2_VIDEO_DECODE_WRITE_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_READ_KHR.0,
+            "VIDEO_ENCODE_READ_KHR",
+            "VK_ACCESS_2_VIDEO_ENCODE_READ_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_WRITE_KHR.0,
+            "VIDEO_ENCODE_WRITE_KHR",
+            "VK_ACCESS_2_VIDEO_ENCODE_WRITE_BIT_KHR",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_WRITE_EXT.0,
+            "TRANSFORM_FEEDBACK_WRITE_EXT",
+            "VK_ACCESS_2_TRANSFORM_FEEDBACK_WRITE_BIT_EXT",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_COUNTER_READ_EXT.0,
+            "TRANSFORM_FEEDBACK_COUNTER_READ_EXT",
+            "VK_ACCESS_2_TRANSFORM_FEEDBACK_COUNTER_READ_BIT_EXT",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_COUNTER_WRITE_EXT.0,
+            "TRANSFORM_FEEDBACK_COUNTER_WRITE_EXT",
+            "VK_ACCESS_2_TRANSFORM_FEEDBACK_COUNTER_WRITE_BIT_EXT",
+        ),
+        (
+            Self::CONDITIONAL_RENDERING_READ_EXT.0,
+            "CONDITIONAL_RENDERING_READ_EXT",
+            "VK_ACCESS_2_CONDITIONAL_RENDERING_READ_BIT_EXT",
+        ),
+        (
+            Self::COMMAND_PREPROCESS_READ_NV.0,
+            "COMMAND_PREPROCESS_READ_NV",
+            "VK_ACCESS_2_COMMAND_PREPROCESS_READ_BIT_NV",
+        ),
+        (
+            Self::COMMAND_PREPROCESS_WRITE_NV.0,
+            "COMMAND_PREPROCESS_WRITE_NV",
+            "VK_ACCESS_2_COMMAND_PREPROCESS_WRITE_BIT_NV",
+        ),
+        (
+            Self::FRAGMENT_SHADING_RATE_ATTACHMENT_READ_KHR.0,
+            "FRAGMENT_SHADING_RATE_ATTACHMENT_READ_KHR",
+            "VK_ACCESS_2_FRAGMENT_SHADING_RATE_ATTACHMENT_READ_BIT_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_READ_KHR.0,
+            "ACCELERATION_STRUCTURE_READ_KHR",
+            "VK_ACCESS_2_ACCELERATION_STRUCTURE_READ_BIT_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_WRITE_KHR.0,
+            "ACCELERATION_STRUCTURE_WRITE_KHR",
+            "VK_ACCESS_2_ACCELERATION_STRUCTURE_WRITE_BIT_KHR",
+        ),
+        (
+            Self::FRAGMENT_DENSITY_MAP_READ_EXT.0,
+            "FRAGMENT_DENSITY_MAP_READ_EXT",
+            "VK_ACCESS_2_FRAGMENT_DENSITY_MAP_READ_BIT_EXT",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_READ_NONCOHERENT_EXT.0,
+            "COLOR_ATTACHMENT_READ_NONCOHERENT_EXT",
+            "VK_ACCESS_2_COLOR_ATTACHMENT_READ_NONCOHERENT_BIT_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_BUFFER_READ_EXT.0,
+            "DESCRIPTOR_BUFFER_READ_EXT",
+            "VK_ACCESS_2_DESCRIPTOR_BUFFER_READ_BIT_EXT",
+        ),
+        (
+            Self::INVOCATION_MASK_READ_HUAWEI.0,
+            "INVOCATION_MASK_READ_HUAWEI",
+            "VK_ACCESS_2_INVOCATION_MASK_READ_BIT_HUAWEI",
+        ),
+        (
+            Self::SHADER_BINDING_TABLE_READ_KHR.0,
+            "SHADER_BINDING_TABLE_READ_KHR",
+            "VK_ACCESS_2_SHADER_BINDING_TABLE_READ_BIT_KHR",
+        ),
+        (
+            Self::MICROMAP_READ_EXT.0,
+            "MICROMAP_READ_EXT",
+            "VK_ACCESS_2_MICROMAP_READ_BIT_EXT",
+        ),
+        (
+            Self::MICROMAP_WRITE_EXT.0,
+            "MICROMAP_WRITE_EXT",
+            "VK_ACCESS_2_MICROMAP_WRITE_BIT_EXT",
+        ),
+        (
+            Self::OPTICAL_FLOW_READ_NV.0,
+            "OPTICAL_FLOW_READ_NV",
+            "VK_ACCESS_2_OPTICAL_FLOW_READ_BIT_NV",
+        ),
+        (
+            Self::OPTICAL_FLOW_WRITE_NV.0,
+            "OPTICAL_FLOW_WRITE_NV",
+            "VK_ACCESS_2_OPTICAL_FLOW_WRITE_BIT_NV",
+        ),
+        (Self::NONE_KHR.0, "NONE_KHR", "VK_ACCESS_2_NONE_KHR"),
+        (
+            Self::INDIRECT_COMMAND_READ_KHR.0,
+            "INDIRECT_COMMAND_READ_KHR",
+            "VK_ACCESS_2_INDIRECT_COMMAND_READ_BIT_KHR",
+        ),
+        (
+            Self::INDEX_READ_KHR.0,
+            "INDEX_READ_KHR",
+            "VK_ACCESS_2_INDEX_READ_BIT_KHR",
+        ),
+        (
+            Self::VERTEX_ATTRIBUTE_READ_KHR.0,
+            "VERTEX_ATTRIBUTE_READ_KHR",
+            "VK_ACCESS_2_VERTEX_ATTRIBUTE_READ_BIT_KHR",
+        ),
+        (
+            Self::UNIFORM_READ_KHR.0,
+            "UNIFORM_READ_KHR",
+            "VK_ACCESS_2_UNIFORM_READ_BIT_KHR",
+        ),
+        (
+            Self::INPUT_ATTACHMENT_READ_KHR.0,
+            "INPUT_ATTACHMENT_READ_KHR",
+            "VK_ACCESS_2_INPUT_ATTACHMENT_READ_BIT_KHR",
+        ),
+        (
+            Self::SHADER_READ_KHR.0,
+            "SHADER_READ_KHR",
+            "VK_ACCESS_2_SHADER_READ_BIT_KHR",
+        ),
+        (
+            Self::SHADER_WRITE_KHR.0,
+            "SHADER_WRITE_KHR",
+            "VK_ACCESS_2_SHADER_WRITE_BIT_KHR",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_READ_KHR.0,
+            "COLOR_ATTACHMENT_READ_KHR",
+            "VK_ACCESS_2_COLOR_ATTACHMENT_READ_BIT_KHR",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_WRITE_KHR.0,
+            "COLOR_ATTACHMENT_WRITE_KHR",
+            "VK_ACCESS_2_COLOR_ATTACHMENT_WRITE_BIT_KHR",
+        ),
+        (
+            Self::DEPTH_STENCIL_ATTACHMENT_READ_KHR.0,
+            "DEPTH_STENCIL_ATTACHMENT_READ_KHR",
+            "VK_ACCESS_2_DEPTH_STENCIL_ATTACHMENT_READ_BIT_KHR",
+        ),
+        (
+            Self::DEPTH_STENCIL_ATTACHMENT_WRITE_KHR.0,
+            "DEPTH_STENCIL_ATTACHMENT_WRITE_KHR",
+            "VK_ACCESS_2_DEPTH_STENCIL_ATTACHMENT_WRITE_BIT_KHR",
+        ),
+        (
+            Self::TRANSFER_READ_KHR.0,
+            "TRANSFER_READ_KHR",
+            "VK_ACCESS_2_TRANSFER_READ_BIT_KHR",
+        ),
+        (
+            Self::TRANSFER_WRITE_KHR.0,
+            "TRANSFER_WRITE_KHR",
+            "VK_ACCESS_2_TRANSFER_WRITE_BIT_KHR",
+        ),
+        (
+            Self::HOST_READ_KHR.0,
+            "HOST_READ_KHR",
+            "VK_ACCESS_2_HOST_READ_BIT_KHR",
+        ),
+        (
+            Self::HOST_WRITE_KHR.0,
+            "HOST_WRITE_KHR",
+            "VK_ACCESS_2_HOST_WRITE_BIT_KHR",
+        ),
+        (
+            Self::MEMORY_READ_KHR.0,
+            "MEMORY_READ_KHR",
+            "VK_ACCESS_2_MEMORY_READ_BIT_KHR",
+        ),
+        (
+            Self::MEMORY_WRITE_KHR.0,
+            "MEMORY_WRITE_KHR",
+            "VK_ACCESS_2_MEMORY_WRITE_BIT_KHR",
+        ),
+        (
+            Self::SHADER_SAMPLED_READ_KHR.0,
+            "SHADER_SAMPLED_READ_KHR",
+            "VK_ACCESS_2_SHADER_SAMPLED_READ_BIT_KHR",
+        ),
+        (
+            Self::SHADER_STORAGE_READ_KHR.0,
+            "SHADER_STORAGE_READ_KHR",
+            "VK_ACCESS_2_SHADER_STORAGE_READ_BIT_KHR",
+        ),
+        (
+            Self::SHADER_STORAGE_WRITE_KHR.0,
+            "SHADER_STORAGE_WRITE_KHR",
+            "VK_ACCESS_2_SHADER_STORAGE_WRITE_BIT_KHR",
+        ),
+        (
+            Self::SHADING_RATE_IMAGE_READ_NV.0,
+            "SHADING_RATE_IMAGE_READ_NV",
+            "VK_ACCESS_2_SHADING_RATE_IMAGE_READ_BIT_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_READ_NV.0,
+            "ACCELERATION_STRUCTURE_READ_NV",
+            "VK_ACCESS_2_ACCELERATION_STRUCTURE_READ_BIT_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_WRITE_NV.0,
+            "ACCELERATION_STRUCTURE_WRITE_NV",
+            "VK_ACCESS_2_ACCELERATION_STRUCTURE_WRITE_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for AccessFlags2 {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("AccessFlags2", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AccessFlags2 {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AcquireProfilingLockFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for AcquireProfilingLockFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("AcquireProfilingLockFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AcquireProfilingLockFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AndroidSurfaceCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for AndroidSurfaceCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("AndroidSurfaceCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AndroidSurfaceCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AttachmentDescriptionFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::MAY_ALIAS.0,
+        "MAY_ALIAS",
+        "VK_ATTACHMENT_DESCRIPTION_MAY_ALIAS_BIT",
+    )];
+}
+impl FromStr for AttachmentDescriptionFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("AttachmentDescriptionFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AttachmentDescriptionFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BufferCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::SPARSE_BINDING.0,
+            "SPARSE_BINDING",
+            "VK_BUFFER_CREATE_SPARSE_BINDING_BIT",
+        ),
+        (
+            Self::SPARSE_RESIDENCY.0,
+            "SPARSE_RESIDENCY",
+            "VK_BUFFER_CREATE_SPARSE_RESIDENCY_BIT",
+        ),
+        (
+            Self::SPARSE_ALIASED.0,
+            "SPARSE_ALIASED",
+            "VK_BUFFER_CREATE_SPARSE_ALIASED_BIT",
+        ),
+        (
+            Self::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.0,
+            "DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT",
+            "VK_BUFFER_CREATE_DESCRIPTOR_BUFFER_CAPTURE_REPLAY_BIT_EXT",
+        ),
+        (
+            Self::PROTECTED.0,
+            "PROTECTED",
+            "VK_BUFFER_CREATE_PROTECTED_BIT",
+        ),
+        (
+            Self::DEVICE_ADDRESS_CAPTURE_REPLAY.0,
+            "DEVICE_ADDRESS_CAPTURE_REPLAY",
+            "VK_BUFFER_CREATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT",
+        ),
+        (
+            Self::DEVICE_ADDRESS_CAPTURE_REPLAY_EXT.0,
+            "DEVICE_ADDRESS_CAPTURE_REPLAY_EXT",
+            "VK_BUFFER_CREATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT_EXT",
+        ),
+        (
+            Self::DEVICE_ADDRESS_CAPTURE_REPLAY_KHR.0,
+            "DEVICE_ADDRESS_CAPTURE_REPLAY_KHR",
+            "VK_BUFFER_CREATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for BufferCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("BufferCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BufferCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BufferUsageFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::TRANSFER_SRC.0,
+            "TRANSFER_SRC",
+            "VK_BUFFER_USAGE_TRANSFER_SRC_BIT",
+        ),
+        (
+            Self::TRANSFER_DST.0,
+            "TRANSFER_DST",
+            "VK_BUFFER_USAGE_TRANSFER_DST_BIT",
+        ),
+        (
+            Self::UNIFORM_TEXEL_BUFFER.0,
+            "UNIFORM_TEXEL_BUFFER",
+            "VK_BUFFER_USAGE_UNIFORM_TEXEL_BUFFER_BIT",
+        ),
+        (
+            Self::STORAGE_TEXEL_BUFFER.0,
+            "STORAGE_TEXEL_BUFFER",
+            "VK_BUFFER_USAGE_STORAGE_TEXEL_BUFFER_BIT",
+        ),
+        (
+            Self::UNIFORM_BUFFER.0,
+            "UNIFORM_BUFFER",
+            "VK_BUFFER_USAGE_UNIFORM_BUFFER_BIT",
+        ),
+        (
+            Self::STORAGE_BUFFER.0,
+            "STORAGE_BUFFER",
+            "VK_BUFFER_USAGE_STORAGE_BUFFER_BIT",
+        ),
+        (
+            Self::INDEX_BUFFER.0,
+            "INDEX_BUFFER",
+            "VK_BUFFER_USAGE_INDEX_BUFFER_BIT",
+        ),
+        (
+            Self::VERTEX_BUFFER.0,
+            "VERTEX_BUFFER",
+            "VK_BUFFER_USAGE_VERTEX_BUFFER_BIT",
+        ),
+        (
+            Self::INDIRECT_BUFFER.0,
+            "INDIRECT_BUFFER",
+            "VK_BUFFER_USAGE_INDIRECT_BUFFER_BIT",
+        ),
+        (
+            Self::VIDEO_DECODE_SRC_KHR.0,
+            "VIDEO_DECODE_SRC_KHR",
+            "VK_BUFFER_USAGE_VIDEO_DECODE_SRC_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_DST_KHR.0,
+            "VIDEO_DECODE_DST_KHR",
+            "VK_BUFFER_USAGE_VIDEO_DECODE_DST_BIT_KHR",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_BUFFER_EXT.0,
+            "TRANSFORM_FEEDBACK_BUFFER_EXT",
+            "VK_BUFFER_USAGE_TRANSFORM_FEEDBACK_BUFFER_BIT_EXT",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_COUNTER_BUFFER_EXT.0,
+            "TRANSFORM_FEEDBACK_COUNTER_BUFFER_EXT",
+            "VK_BUFFER_USAGE_TRANSFORM_FEEDBACK_COUNTER_BUFFER_BIT_EXT",
+        ),
+        (
+            Self::CONDITIONAL_RENDERING_EXT.0,
+            "CONDITIONAL_RENDERING_EXT",
+            "VK_BUFFER_USAGE_CONDITIONAL_RENDERING_BIT_EXT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_BUILD_INPUT_READ_ONLY_KHR.0,
+            "ACCELERATION_STRUCTURE_BUILD_INPUT_READ_ONLY_KHR",
+            "VK_BUFFER_USAGE_ACCELERATION_STRUCTURE_BUILD_INPUT_READ_ONLY_BIT_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_STORAGE_KHR.0,
+            "ACCELERATION_STRUCTURE_STORAGE_KHR",
+            "VK_BUFFER_USAGE_ACCELERATION_STRUCTURE_STORAGE_BIT_KHR",
+        ),
+        (
+            Self::SHADER_BINDING_TABLE_KHR.0,
+            "SHADER_BINDING_TABLE_KHR",
+            "VK_BUFFER_USAGE_SHADER_BINDING_TABLE_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_DST_KHR.0,
+            "VIDEO_ENCODE_DST_KHR",
+            "VK_BUFFER_USAGE_VIDEO_ENCODE_DST_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_SRC_KHR.0,
+            "VIDEO_ENCODE_SRC_KHR",
+            "VK_BUFFER_USAGE_VIDEO_ENCODE_SRC_BIT_KHR",
+        ),
+        (
+            Self::SAMPLER_DESCRIPTOR_BUFFER_EXT.0,
+            "SAMPLER_DESCRIPTOR_BUFFER_EXT",
+            "VK_BUFFER_USAGE_SAMPLER_DESCRIPTOR_BUFFER_BIT_EXT",
+        ),
+        (
+            Self::RESOURCE_DESCRIPTOR_BUFFER_EXT.0,
+            "RESOURCE_DESCRIPTOR_BUFFER_EXT",
+            "VK_BUFFER_USAGE_RESOURCE_DESCRIPTOR_BUFFER_BIT_EXT",
+        ),
+        (
+            Self::PUSH_DESCRIPTORS_DESCRIPTOR_BUFFER_EXT.0,
+            "PUSH_DESCRIPTORS_DESCRIPTOR_BUFFER_EXT",
+            "VK_BUFFER_USAGE_PUSH_DESCRIPTORS_DESCRIPTOR_BUFFER_BIT_EXT",
+        ),
+        (
+            Self::MICROMAP_BUILD_INPUT_READ_ONLY_EXT.0,
+            "MICROMAP_BUILD_INPUT_READ_ONLY_EXT",
+            "VK_BUFFER_USAGE_MICROMAP_BUILD_INPUT_READ_ONLY_BIT_EXT",
+        ),
+        (
+            Self::MICROMAP_STORAGE_EXT.0,
+            "MICROMAP_STORAGE_EXT",
+            "VK_BUFFER_USAGE_MICROMAP_STORAGE_BIT_EXT",
+        ),
+        (
+            Self::SHADER_DEVICE_ADDRESS.0,
+            "SHADER_DEVICE_ADDRESS",
+            "VK_BUFFER_USAGE_SHADER_DEVICE_ADDRESS_BIT",
+        ),
+        (
+            Self::RAY_TRACING_NV.0,
+            "RAY_TRACING_NV",
+            "VK_BUFFER_USAGE_RAY_TRACING_BIT_NV",
+        ),
+        (
+            Self::SHADER_DEVICE_ADDRESS_EXT.0,
+            "SHADER_DEVICE_ADDRESS_EXT",
+            "VK_BUFFER_USAGE_SHADER_DEVICE_ADDRESS_BIT_EXT",
+        ),
+        (
+            Self::SHADER_DEVICE_ADDRESS_KHR.0,
+            "SHADER_DEVICE_ADDRESS_KHR",
+            "VK_BUFFER_USAGE_SHADER_DEVICE_ADDRESS_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for BufferUsageFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("BufferUsageFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BufferUsageFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BufferViewCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for BufferViewCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("BufferViewCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BufferViewCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BuildAccelerationStructureFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::ALLOW_UPDATE.0,
+            "ALLOW_UPDATE",
+            "VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_UPDATE_BIT_KHR",
+        ),
+        (
+            Self::ALLOW_COMPACTION.0,
+            "ALLOW_COMPACTION",
+            "VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_COMPACTION_BIT_KHR",
+        ),
+        (
+            Self::PREFER_FAST_TRACE.0,
+            "PREFER_FAST_TRACE",
+            "VK_BUILD_ACCELERATION_STRUCTURE_PREFER_FAST_TRACE_BIT_KHR",
+        ),
+        (
+            Self::PREFER_FAST_BUILD.0,
+            "PREFER_FAST_BUILD",
+            "VK_BUILD_ACCELERATION_STRUCTURE_PREFER_FAST_BUILD_BIT_KHR",
+        ),
+        (
+            Self::LOW_MEMORY.0,
+            "LOW_MEMORY",
+            "VK_BUILD_ACCELERATION_STRUCTURE_LOW_MEMORY_BIT_KHR",
+        ),
+        (
+            Self::MOTION_NV.0,
+            "MOTION_NV",
+            "VK_BUILD_ACCELERATION_STRUCTURE_MOTION_BIT_NV",
+        ),
+        (
+            Self::ALLOW_OPACITY_MICROMAP_UPDATE_EXT.0,
+            "ALLOW_OPACITY_MICROMAP_UPDATE_EXT",
+            "VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_OPACITY_MICROMAP_UPDATE_EXT",
+        ),
+        (
+            Self::ALLOW_DISABLE_OPACITY_MICROMAPS_EXT.0,
+            "ALLOW_DISABLE_OPACITY_MICROMAPS_EXT",
+            "VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_DISABLE_OPACITY_MICROMAPS_EXT",
+        ),
+        (
+            Self::ALLOW_OPACITY_MICROMAP_DATA_UPDATE_EXT.0,
+            "ALLOW_OPACITY_MICROMAP_DATA_UPDATE_EXT",
+            "VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_OPACITY_MICROMAP_DATA_UPDATE_EXT",
+        ),
+        (
+            Self::ALLOW_DISPLACEMENT_MICROMAP_UPDATE_NV.0,
+            "ALLOW_DISPLACEMENT_MICROMAP_UPDATE_NV",
+            "VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_DISPLACEMENT_MICROMAP_UPDATE_NV",
+        ),
+        (
+            Self::ALLOW_DATA_ACCESS.0,
+            "ALLOW_DATA_ACCESS",
+            "VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_DATA_ACCESS_KHR",
+        ),
+        (
+            Self::ALLOW_UPDATE_NV.0,
+            "ALLOW_UPDATE_NV",
+            "VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_UPDATE_BIT_NV",
+        ),
+        (
+            Self::ALLOW_COMPACTION_NV.0,
+            "ALLOW_COMPACTION_NV",
+            "VK_BUILD_ACCELERATION_STRUCTURE_ALLOW_COMPACTION_BIT_NV",
+        ),
+        (
+            Self::PREFER_FAST_TRACE_NV.0,
+            "PREFER_FAST_TRACE_NV",
+            "VK_BUILD_ACCELERATION_STRUCTURE_PREFER_FAST_TRACE_BIT_NV",
+        ),
+        (
+            Self::PREFER_FAST_BUILD_NV.0,
+            "PREFER_FAST_BUILD_NV",
+            "VK_BUILD_ACCELERATION_STRUCTURE_PREFER_FAST_BUILD_BIT_NV",
+        ),
+        (
+            Self::LOW_MEMORY_NV.0,
+            "LOW_MEMORY_NV",
+            "VK_BUILD_ACCELERATION_STRUCTURE_LOW_MEMORY_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for BuildAccelerationStructureFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("BuildAccelerationStructureFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BuildAccelerationStructureFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BuildMicromapFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::PREFER_FAST_TRACE.0,
+            "PREFER_FAST_TRACE",
+            "VK_BUILD_MICROMAP_PREFER_FAST_TRACE_BIT_EXT",
+        ),
+        (
+            Self::PREFER_FAST_BUILD.0,
+            "PREFER_FAST_BUILD",
+            "VK_BUILD_MICROMAP_PREFER_FAST_BUILD_BIT_EXT",
+        ),
+        (
+            Self::ALLOW_COMPACTION.0,
+            "ALLOW_COMPACTION",
+            "VK_BUILD_MICROMAP_ALLOW_COMPACTION_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for BuildMicromapFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("BuildMicromapFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BuildMicromapFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ColorComponentFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::R.0, "R", "VK_COLOR_COMPONENT_R_BIT"),
+        (Self::G.0, "G", "VK_COLOR_COMPONENT_G_BIT"),
+        (Self::B.0, "B", "VK_COLOR_COMPONENT_B_BIT"),
+        (Self::A.0, "A", "VK_COLOR_COMPONENT_A_BIT"),
+    ];
+}
+impl FromStr for ColorComponentFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ColorComponentFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ColorComponentFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CommandBufferResetFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::RELEASE_RESOURCES.0,
+        "RELEASE_RESOURCES",
+        "VK_COMMAND_BUFFER_RESET_RELEASE_RESOURCES_BIT",
+    )];
+}
+impl FromStr for CommandBufferResetFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("CommandBufferResetFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CommandBufferResetFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CommandBufferUsageFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::ONE_TIME_SUBMIT.0,
+            "ONE_TIME_SUBMIT",
+            "VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT",
+        ),
+        (
+            Self::RENDER_PASS_CONTINUE.0,
+            "RENDER_PASS_CONTINUE",
+            "VK_COMMAND_BUFFER_USAGE_RENDER_PASS_CONTINUE_BIT",
+        ),
+        (
+            Self::SIMULTANEOUS_USE.0,
+            "SIMULTANEOUS_USE",
+            "VK_COMMAND_BUFFER_USAGE_SIMULTANEOUS_USE_BIT",
+        ),
+    ];
+}
+impl FromStr for CommandBufferUsageFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("CommandBufferUsageFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CommandBufferUsageFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CommandPoolCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::TRANSIENT.0,
+            "TRANSIENT",
+            "VK_COMMAND_POOL_CREATE_TRANSIENT_BIT",
+        ),
+        (
+            Self::RESET_COMMAND_BUFFER.0,
+            "RESET_COMMAND_BUFFER",
+            "VK_COMMAND_POOL_CREATE_RESET_COMMAND_BUFFER_BIT",
+        ),
+        (
+            Self::PROTECTED.0,
+            "PROTECTED",
+            "VK_COMMAND_POOL_CREATE_PROTECTED_BIT",
+        ),
+    ];
+}
+impl FromStr for CommandPoolCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("CommandPoolCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CommandPoolCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CommandPoolResetFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::RELEASE_RESOURCES.0,
+        "RELEASE_RESOURCES",
+        "VK_COMMAND_POOL_RESET_RELEASE_RESOURCES_BIT",
+    )];
+}
+impl FromStr for CommandPoolResetFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("CommandPoolResetFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CommandPoolResetFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CommandPoolTrimFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for CommandPoolTrimFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("CommandPoolTrimFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CommandPoolTrimFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CompositeAlphaFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::OPAQUE.0,
+            "OPAQUE",
+            "VK_COMPOSITE_ALPHA_OPAQUE_BIT_KHR",
+        ),
+        (
+            Self::PRE_MULTIPLIED.0,
+            "PRE_MULTIPLIED",
+            "VK_COMPOSITE_ALPHA_PRE_MULTIPLIED_BIT_KHR",
+        ),
+        (
+            Self::POST_MULTIPLIED.0,
+            "POST_MULTIPLIED",
+            "VK_COMPOSITE_ALPHA_POST_MULTIPLIED_BIT_KHR",
+        ),
+        (
+            Self::INHERIT.0,
+            "INHERIT",
+            "VK_COMPOSITE_ALPHA_INHERIT_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for CompositeAlphaFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("CompositeAlphaFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CompositeAlphaFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ConditionalRenderingFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::INVERTED.0,
+        "INVERTED",
+        "VK_CONDITIONAL_RENDERING_INVERTED_BIT_EXT",
+    )];
+}
+impl FromStr for ConditionalRenderingFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ConditionalRenderingFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ConditionalRenderingFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CullModeFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::NONE.0, "NONE", "VK_CULL_MODE_NONE"),
+        (Self::FRONT.0, "FRONT", "VK_CULL_MODE_FRONT_BIT"),
+        (Self::BACK.0, "BACK", "VK_CULL_MODE_BACK_BIT"),
+        (
+            Self::FRONT_AND_BACK.0,
+            "FRONT_AND_BACK",
+            "VK_CULL_MODE_FRONT_AND_BACK",
+        ),
+    ];
+}
+impl FromStr for CullModeFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("CullModeFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CullModeFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DebugReportFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::INFORMATION.0,
+            "INFORMATION",
+            "VK_DEBUG_REPORT_INFORMATION_BIT_EXT",
+        ),
+        (
+            Self::WARNING.0,
+            "WARNING",
+            "VK_DEBUG_REPORT_WARNING_BIT_EXT",
+        ),
+        (
+            Self::PERFORMANCE_WARNING.0,
+            "PERFORMANCE_WARNING",
+            "VK_DEBUG_REPORT_PERFORMANCE_WARNING_BIT_EXT",
+        ),
+        (Self::ERROR.0, "ERROR", "VK_DEBUG_REPORT_ERROR_BIT_EXT"),
+        (Self::DEBUG.0, "DEBUG", "VK_DEBUG_REPORT_DEBUG_BIT_EXT"),
+    ];
+}
+impl FromStr for DebugReportFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DebugReportFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DebugReportFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DebugUtilsMessageSeverityFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::VERBOSE.0,
+            "VERBOSE",
+            "VK_DEBUG_UTILS_MESSAGE_SEVERITY_VERBOSE_BIT_EXT",
+        ),
+        (
+            Self::INFO.0,
+            "INFO",
+            "VK_DEBUG_UTILS_MESSAGE_SEVERITY_INFO_BIT_EXT",
+        ),
+        (
+            Self::WARNING.0,
+            "WARNING",
+            "VK_DEBUG_UTILS_MESSAGE_SEVERITY_WARNING_BIT_EXT",
+        ),
+        (
+            Self::ERROR.0,
+            "ERROR",
+            "VK_DEBUG_UTILS_MESSAGE_SEVERITY_ERROR_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for DebugUtilsMessageSeverityFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DebugUtilsMessageSeverityFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DebugUtilsMessageSeverityFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DebugUtilsMessageTypeFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::GENERAL.0,
+            "GENERAL",
+            "VK_DEBUG_UTILS_MESSAGE_TYPE_GENERAL_BIT_EXT",
+        ),
+        (
+            Self::VALIDATION.0,
+            "VALIDATION",
+            "VK_DEBUG_UTILS_MESSAGE_TYPE_VALIDATION_BIT_EXT",
+        ),
+        (
+            Self::PERFORMANCE.0,
+            "PERFORMANCE",
+            "VK_DEBUG_UTILS_MESSAGE_TYPE_PERFORMANCE_BIT_EXT",
+        ),
+        (
+            Self::DEVICE_ADDRESS_BINDING.0,
+            "DEVICE_ADDRESS_BINDING",
+            "VK_DEBUG_UTILS_MESSAGE_TYPE_DEVICE_ADDRESS_BINDING_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for DebugUtilsMessageTypeFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DebugUtilsMessageTypeFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DebugUtilsMessageTypeFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DebugUtilsMessengerCallbackDataFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for DebugUtilsMessengerCallbackDataFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DebugUtilsMessengerCallbackDataFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DebugUtilsMessengerCallbackDataFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DebugUtilsMessengerCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for DebugUtilsMessengerCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DebugUtilsMessengerCreateFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DebugUtilsMessengerCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DependencyFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::BY_REGION.0,
+            "BY_REGION",
+            "VK_DEPENDENCY_BY_REGION_BIT",
+        ),
+        (
+            Self::FEEDBACK_LOOP_EXT.0,
+            "FEEDBACK_LOOP_EXT",
+            "VK_DEPENDENCY_FEEDBACK_LOOP_BIT_EXT",
+        ),
+        (
+            Self::DEVICE_GROUP.0,
+            "DEVICE_GROUP",
+            "VK_DEPENDENCY_DEVICE_GROUP_BIT",
+        ),
+        (
+            Self::VIEW_LOCAL.0,
+            "VIEW_LOCAL",
+            "VK_DEPENDENCY_VIEW_LOCAL_BIT",
+        ),
+        (
+            Self::VIEW_LOCAL_KHR.0,
+            "VIEW_LOCAL_KHR",
+            "VK_DEPENDENCY_VIEW_LOCAL_BIT_KHR",
+        ),
+        (
+            Self::DEVICE_GROUP_KHR.0,
+            "DEVICE_GROUP_KHR",
+            "VK_DEPENDENCY_DEVICE_GROUP_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for DependencyFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DependencyFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DependencyFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DescriptorBindingFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::UPDATE_AFTER_BIND.0,
+            "UPDATE_AFTER_BIND",
+            "VK_DESCRIPTOR_BINDING_UPDATE_AFTER_BIND_BIT",
+        ),
+        (
+            Self::UPDATE_UNUSED_WHILE_PENDING.0,
+            "UPDATE_UNUSED_WHILE_PENDING",
+            "VK_DESCRIPTOR_BINDING_UPDATE_UNUSED_WHILE_PENDING_BIT",
+        ),
+        (
+            Self::PARTIALLY_BOUND.0,
+            "PARTIALLY_BOUND",
+            "VK_DESCRIPTOR_BINDING_PARTIALLY_BOUND_BIT",
+        ),
+        (
+            Self::VARIABLE_DESCRIPTOR_COUNT.0,
+            "VARIABLE_DESCRIPTOR_COUNT",
+            "VK_DESCRIPTOR_BINDING_VARIABLE_DESCRIPTOR_COUNT_BIT",
+        ),
+        (
+            Self::UPDATE_AFTER_BIND_EXT.0,
+            "UPDATE_AFTER_BIND_EXT",
+            "VK_DESCRIPTOR_BINDING_UPDATE_AFTER_BIND_BIT_EXT",
+        ),
+        (
+            Self::UPDATE_UNUSED_WHILE_PENDING_EXT.0,
+            "UPDATE_UNUSED_WHILE_PENDING_EXT",
+            "VK_DESCRIPTOR_BINDING_UPDATE_UNUSED_WHILE_PENDING_BIT_EXT",
+        ),
+        (
+            Self::PARTIALLY_BOUND_EXT.0,
+            "PARTIALLY_BOUND_EXT",
+            "VK_DESCRIPTOR_BINDING_PARTIALLY_BOUND_BIT_EXT",
+        ),
+        (
+            Self::VARIABLE_DESCRIPTOR_COUNT_EXT.0,
+            "VARIABLE_DESCRIPTOR_COUNT_EXT",
+            "VK_DESCRIPTOR_BINDING_VARIABLE_DESCRIPTOR_COUNT_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for DescriptorBindingFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DescriptorBindingFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DescriptorBindingFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DescriptorPoolCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::FREE_DESCRIPTOR_SET.0,
+            "FREE_DESCRIPTOR_SET",
+            "VK_DESCRIPTOR_POOL_CREATE_FREE_DESCRIPTOR_SET_BIT",
+        ),
+        (
+            Self::HOST_ONLY_EXT.0,
+            "HOST_ONLY_EXT",
+            "VK_DESCRIPTOR_POOL_CREATE_HOST_ONLY_BIT_EXT",
+        ),
+        (
+            Self::UPDATE_AFTER_BIND.0,
+            "UPDATE_AFTER_BIND",
+            "VK_DESCRIPTOR_POOL_CREATE_UPDATE_AFTER_BIND_BIT",
+        ),
+        (
+            Self::UPDATE_AFTER_BIND_EXT.0,
+            "UPDATE_AFTER_BIND_EXT",
+            "VK_DESCRIPTOR_POOL_CREATE_UPDATE_AFTER_BIND_BIT_EXT",
+        ),
+        (
+            Self::HOST_ONLY_VALVE.0,
+            "HOST_ONLY_VALVE",
+            "VK_DESCRIPTOR_POOL_CREATE_HOST_ONLY_BIT_VALVE",
+        ),
+    ];
+}
+impl FromStr for DescriptorPoolCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DescriptorPoolCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DescriptorPoolCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DescriptorPoolResetFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for DescriptorPoolResetFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DescriptorPoolResetFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DescriptorPoolResetFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DescriptorSetLayoutCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::PUSH_DESCRIPTOR_KHR.0,
+            "PUSH_DESCRIPTOR_KHR",
+            "VK_DESCRIPTOR_SET_LAYOUT_CREATE_PUSH_DESCRIPTOR_BIT_KHR",
+        ),
+        (
+            Self::DESCRIPTOR_BUFFER_EXT.0,
+            "DESCRIPTOR_BUFFER_EXT",
+            "VK_DESCRIPTOR_SET_LAYOUT_CREATE_DESCRIPTOR_BUFFER_BIT_EXT",
+        ),
+        (
+            Self::EMBEDDED_IMMUTABLE_SAMPLERS_EXT.0,
+            "EMBEDDED_IMMUTABLE_SAMPLERS_EXT",
+            "VK_DESCRIPTOR_SET_LAYOUT_CREATE_EMBEDDED_IMMUTABLE_SAMPLERS_BIT_EXT",
+        ),
+        (
+            Self::HOST_ONLY_POOL_EXT.0,
+            "HOST_ONLY_POOL_EXT",
+            "VK_DESCRIPTOR_SET_LAYOUT_CREATE_HOST_ONLY_POOL_BIT_EXT",
+        ),
+        (
+            Self::UPDATE_AFTER_BIND_POOL.0,
+            "UPDATE_AFTER_BIND_POOL",
+            "VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT",
+        ),
+        (
+            Self::UPDATE_AFTER_BIND_POOL_EXT.0,
+            "UPDATE_AFTER_BIND_POOL_EXT",
+            "VK_DESCRIPTOR_SET_LAYOUT_CREATE_UPDATE_AFTER_BIND_POOL_BIT_EXT",
+        ),
+        (
+            Self::HOST_ONLY_POOL_VALVE.0,
+            "HOST_ONLY_POOL_VALVE",
+            "VK_DESCRIPTOR_SET_LAYOUT_CREATE_HOST_ONLY_POOL_BIT_VALVE",
+        ),
+    ];
+}
+impl FromStr for DescriptorSetLayoutCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DescriptorSetLayoutCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DescriptorSetLayoutCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DescriptorUpdateTemplateCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for DescriptorUpdateTemplateCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DescriptorUpdateTemplateCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DescriptorUpdateTemplateCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceAddressBindingFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::INTERNAL_OBJECT.0,
+        "INTERNAL_OBJECT",
+        "VK_DEVICE_ADDRESS_BINDING_INTERNAL_OBJECT_BIT_EXT",
+    )];
+}
+impl FromStr for DeviceAddressBindingFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DeviceAddressBindingFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceAddressBindingFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for DeviceCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DeviceCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceDiagnosticsConfigFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::ENABLE_SHADER_DEBUG_INFO.0,
+            "ENABLE_SHADER_DEBUG_INFO",
+            "VK_DEVICE_DIAGNOSTICS_CONFIG_ENABLE_SHADER_DEBUG_INFO_BIT_NV",
+        ),
+        (
+            Self::ENABLE_RESOURCE_TRACKING.0,
+            "ENABLE_RESOURCE_TRACKING",
+            "VK_DEVICE_DIAGNOSTICS_CONFIG_ENABLE_RESOURCE_TRACKING_BIT_NV",
+        ),
+        (
+            Self::ENABLE_AUTOMATIC_CHECKPOINTS.0,
+            "ENABLE_AUTOMATIC_CHECKPOINTS",
+            "VK_DEVICE_DIAGNOSTICS_CONFIG_ENABLE_AUTOMATIC_CHECKPOINTS_BIT_NV",
+        ),
+        (
+            Self::ENABLE_SHADER_ERROR_REPORTING.0,
+            "ENABLE_SHADER_ERROR_REPORTING",
+            "VK_DEVICE_DIAGNOSTICS_CONFIG_ENABLE_SHADER_ERROR_REPORTING_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for DeviceDiagnosticsConfigFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DeviceDiagnosticsConfigFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceDiagnosticsConfigFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceGroupPresentModeFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::LOCAL.0,
+            "LOCAL",
+            "VK_DEVICE_GROUP_PRESENT_MODE_LOCAL_BIT_KHR",
+        ),
+        (
+            Self::REMOTE.0,
+            "REMOTE",
+            "VK_DEVICE_GROUP_PRESENT_MODE_REMOTE_BIT_KHR",
+        ),
+        (
+            Self::SUM.0,
+            "SUM",
+            "VK_DEVICE_GROUP_PRESENT_MODE_SUM_BIT_KHR",
+        ),
+        (
+            Self::LOCAL_MULTI_DEVICE.0,
+            "LOCAL_MULTI_DEVICE",
+            "VK_DEVICE_GROUP_PRESENT_MODE_LOCAL_MULTI_DEVICE_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for DeviceGroupPresentModeFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DeviceGroupPresentModeFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceGroupPresentModeFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceMemoryReportFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for DeviceMemoryReportFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DeviceMemoryReportFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceMemoryReportFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceQueueCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::PROTECTED.0,
+        "PROTECTED",
+        "VK_DEVICE_QUEUE_CREATE_PROTECTED_BIT",
+    )];
+}
+impl FromStr for DeviceQueueCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DeviceQueueCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceQueueCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DirectDriverLoadingFlagsLUNARG {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for DirectDriverLoadingFlagsLUNARG {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DirectDriverLoadingFlagsLUNARG", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DirectDriverLoadingFlagsLUNARG {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DirectFBSurfaceCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for DirectFBSurfaceCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DirectFBSurfaceCreateFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DirectFBSurfaceCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DisplayModeCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for DisplayModeCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DisplayModeCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DisplayModeCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DisplayPlaneAlphaFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::OPAQUE.0,
+            "OPAQUE",
+            "VK_DISPLAY_PLANE_ALPHA_OPAQUE_BIT_KHR",
+        ),
+        (
+            Self::GLOBAL.0,
+            "GLOBAL",
+            "VK_DISPLAY_PLANE_ALPHA_GLOBAL_BIT_KHR",
+        ),
+        (
+            Self::PER_PIXEL.0,
+            "PER_PIXEL",
+            "VK_DISPLAY_PLANE_ALPHA_PER_PIXEL_BIT_KHR",
+        ),
+        (
+            Self::PER_PIXEL_PREMULTIPLIED.0,
+            "PER_PIXEL_PREMULTIPLIED",
+            "VK_DISPLAY_PLANE_ALPHA_PER_PIXEL_PREMULTIPLIED_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for DisplayPlaneAlphaFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DisplayPlaneAlphaFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DisplayPlaneAlphaFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DisplaySurfaceCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for DisplaySurfaceCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("DisplaySurfaceCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DisplaySurfaceCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl EventCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEVICE_ONLY.0,
+            "DEVICE_ONLY",
+            "VK_EVENT_CREATE_DEVICE_ONLY_BIT",
+        ),
+        (
+            Self::DEVICE_ONLY_KHR.0,
+            "DEVICE_ONLY_KHR",
+            "VK_EVENT_CREATE_DEVICE_ONLY_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for EventCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("EventCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for EventCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ExportMetalObjectTypeFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::METAL_DEVICE.0,
+            "METAL_DEVICE",
+            "VK_EXPORT_METAL_OBJECT_TYPE_METAL_DEVICE_BIT_EXT",
+        ),
+        (
+            Self::METAL_COMMAND_QUEUE.0,
+            "METAL_COMMAND_QUEUE",
+            "VK_EXPORT_METAL_OBJECT_TYPE_METAL_COMMAND_QUEUE_BIT_EXT",
+        ),
+        (
+            Self::METAL_BUFFER.0,
+            "METAL_BUFFER",
+            "VK_EXPORT_METAL_OBJECT_TYPE_METAL_BUFFER_BIT_EXT",
+        ),
+        (
+            Self::METAL_TEXTURE.0,
+            "METAL_TEXTURE",
+            "VK_EXPORT_METAL_OBJECT_TYPE_METAL_TEXTURE_BIT_EXT",
+        ),
+        (
+            Self::METAL_IOSURFACE.0,
+            "METAL_IOSURFACE",
+            "VK_EXPORT_METAL_OBJECT_TYPE_METAL_IOSURFACE_BIT_EXT",
+        ),
+        (
+            Self::METAL_SHARED_EVENT.0,
+            "METAL_SHARED_EVENT",
+            "VK_EXPORT_METAL_OBJECT_TYPE_METAL_SHARED_EVENT_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for ExportMetalObjectTypeFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ExportMetalObjectTypeFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ExportMetalObjectTypeFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ExternalFenceFeatureFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::EXPORTABLE.0,
+            "EXPORTABLE",
+            "VK_EXTERNAL_FENCE_FEATURE_EXPORTABLE_BIT",
+        ),
+        (
+            Self::IMPORTABLE.0,
+            "IMPORTABLE",
+            "VK_EXTERNAL_FENCE_FEATURE_IMPORTABLE_BIT",
+        ),
+        (
+            Self::EXPORTABLE_KHR.0,
+            "EXPORTABLE_KHR",
+            "VK_EXTERNAL_FENCE_FEATURE_EXPORTABLE_BIT_KHR",
+        ),
+        (
+            Self::IMPORTABLE_KHR.0,
+            "IMPORTABLE_KHR",
+            "VK_EXTERNAL_FENCE_FEATURE_IMPORTABLE_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for ExternalFenceFeatureFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ExternalFenceFeatureFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ExternalFenceFeatureFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ExternalFenceHandleTypeFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::OPAQUE_FD.0,
+            "OPAQUE_FD",
+            "VK_EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_FD_BIT",
+        ),
+        (
+            Self::OPAQUE_WIN32.0,
+            "OPAQUE_WIN32",
+            "VK_EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_BIT",
+        ),
+        (
+            Self::OPAQUE_WIN32_KMT.0,
+            "OPAQUE_WIN32_KMT",
+            "VK_EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT",
+        ),
+        (
+            Self::SYNC_FD.0,
+            "SYNC_FD",
+            "VK_EXTERNAL_FENCE_HANDLE_TYPE_SYNC_FD_BIT",
+        ),
+        (
+            Self::OPAQUE_FD_KHR.0,
+            "OPAQUE_FD_KHR",
+            "VK_EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_FD_BIT_KHR",
+        ),
+        (
+            Self::OPAQUE_WIN32_KHR.0,
+            "OPAQUE_WIN32_KHR",
+            "VK_EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_BIT_KHR",
+        ),
+        (
+            Self::OPAQUE_WIN32_KMT_KHR.0,
+            "OPAQUE_WIN32_KMT_KHR",
+            "VK_EXTERNAL_FENCE_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT_KHR",
+        ),
+        (
+            Self::SYNC_FD_KHR.0,
+            "SYNC_FD_KHR",
+            "VK_EXTERNAL_FENCE_HANDLE_TYPE_SYNC_FD_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for ExternalFenceHandleTypeFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ExternalFenceHandleTypeFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ExternalFenceHandleTypeFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ExternalMemoryFeatureFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEDICATED_ONLY.0,
+            "DEDICATED_ONLY",
+            "VK_EXTERNAL_MEMORY_FEATURE_DEDICATED_ONLY_BIT",
+        ),
+        (
+            Self::EXPORTABLE.0,
+            "EXPORTABLE",
+            "VK_EXTERNAL_MEMORY_FEATURE_EXPORTABLE_BIT",
+        ),
+        (
+            Self::IMPORTABLE.0,
+            "IMPORTABLE",
+            "VK_EXTERNAL_MEMORY_FEATURE_IMPORTABLE_BIT",
+        ),
+        (
+            Self::DEDICATED_ONLY_KHR.0,
+            "DEDICATED_ONLY_KHR",
+            "VK_EXTERNAL_MEMORY_FEATURE_DEDICATED_ONLY_BIT_KHR",
+        ),
+        (
+            Self::EXPORTABLE_KHR.0,
+            "EXPORTABLE_KHR",
+            "VK_EXTERNAL_MEMORY_FEATURE_EXPORTABLE_BIT_KHR",
+        ),
+        (
+            Self::IMPORTABLE_KHR.0,
+            "IMPORTABLE_KHR",
+            "VK_EXTERNAL_MEMORY_FEATURE_IMPORTABLE_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for ExternalMemoryFeatureFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ExternalMemoryFeatureFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ExternalMemoryFeatureFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ExternalMemoryFeatureFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEDICATED_ONLY.0,
+            "DEDICATED_ONLY",
+            "VK_EXTERNAL_MEMORY_FEATURE_DEDICATED_ONLY_BIT_NV",
+        ),
+        (
+            Self::EXPORTABLE.0,
+            "EXPORTABLE",
+            "VK_EXTERNAL_MEMORY_FEATURE_EXPORTABLE_BIT_NV",
+        ),
+        (
+            Self::IMPORTABLE.0,
+            "IMPORTABLE",
+            "VK_EXTERNAL_MEMORY_FEATURE_IMPORTABLE_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for ExternalMemoryFeatureFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ExternalMemoryFeatureFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ExternalMemoryFeatureFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ExternalMemoryHandleTypeFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::OPAQUE_FD.0,
+            "OPAQUE_FD",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_FD_BIT",
+        ),
+        (
+            Self::OPAQUE_WIN32.0,
+            "OPAQUE_WIN32",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_BIT",
+        ),
+        (
+            Self::OPAQUE_WIN32_KMT.0,
+            "OPAQUE_WIN32_KMT",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT",
+        ),
+        (
+            Self::D3D11_TEXTURE.0,
+            "D3D11_TEXTURE",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_BIT",
+        ),
+        (
+            Self::D3D11_TEXTURE_KMT.0,
+            "D3D11_TEXTURE_KMT",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_KMT_BIT",
+        ),
+        (
+            Self::D3D12_HEAP.0,
+            "D3D12_HEAP",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_HEAP_BIT",
+        ),
+        (
+            Self::D3D12_RESOURCE.0,
+            "D3D12_RESOURCE",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_RESOURCE_BIT",
+        ),
+        (
+            Self::DMA_BUF_EXT.0,
+            "DMA_BUF_EXT",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_DMA_BUF_BIT_EXT",
+        ),
+        (
+            Self::ANDROID_HARDWARE_BUFFER_ANDROID.0,
+            "ANDROID_HARDWARE_BUFFER_ANDROID",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_ANDROID_HARDWARE_BUFFER_BIT_ANDROID",
+        ),
+        (
+            Self::HOST_ALLOCATION_EXT.0,
+            "HOST_ALLOCATION_EXT",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_HOST_ALLOCATION_BIT_EXT",
+        ),
+        (
+            Self::HOST_MAPPED_FOREIGN_MEMORY_EXT.0,
+            "HOST_MAPPED_FOREIGN_MEMORY_EXT",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_HOST_MAPPED_FOREIGN_MEMORY_BIT_EXT",
+        ),
+        (
+            Self::ZIRCON_VMO_FUCHSIA.0,
+            "ZIRCON_VMO_FUCHSIA",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_ZIRCON_VMO_BIT_FUCHSIA",
+        ),
+        (
+            Self::RDMA_ADDRESS_NV.0,
+            "RDMA_ADDRESS_NV",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_RDMA_ADDRESS_BIT_NV",
+        ),
+        (
+            Self::SCREEN_BUFFER_QNX.0,
+            "SCREEN_BUFFER_QNX",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_SCREEN_BUFFER_BIT_QNX",
+        ),
+        (
+            Self::OPAQUE_FD_KHR.0,
+            "OPAQUE_FD_KHR",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_FD_BIT_KHR",
+        ),
+        (
+            Self::OPAQUE_WIN32_KHR.0,
+            "OPAQUE_WIN32_KHR",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_BIT_KHR",
+        ),
+        (
+            Self::OPAQUE_WIN32_KMT_KHR.0,
+            "OPAQUE_WIN32_KMT_KHR",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT_KHR",
+        ),
+        (
+            Self::D3D11_TEXTURE_KHR.0,
+            "D3D11_TEXTURE_KHR",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_BIT_KHR",
+        ),
+        (
+            Self::D3D11_TEXTURE_KMT_KHR.0,
+            "D3D11_TEXTURE_KMT_KHR",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_TEXTURE_KMT_BIT_KHR",
+        ),
+        (
+            Self::D3D12_HEAP_KHR.0,
+            "D3D12_HEAP_KHR",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_HEAP_BIT_KHR",
+        ),
+        (
+            Self::D3D12_RESOURCE_KHR.0,
+            "D3D12_RESOURCE_KHR",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D12_RESOURCE_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for ExternalMemoryHandleTypeFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ExternalMemoryHandleTypeFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ExternalMemoryHandleTypeFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ExternalMemoryHandleTypeFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::OPAQUE_WIN32.0,
+            "OPAQUE_WIN32",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_BIT_NV",
+        ),
+        (
+            Self::OPAQUE_WIN32_KMT.0,
+            "OPAQUE_WIN32_KMT",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT_NV",
+        ),
+        (
+            Self::D3D11_IMAGE.0,
+            "D3D11_IMAGE",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_IMAGE_BIT_NV",
+        ),
+        (
+            Self::D3D11_IMAGE_KMT.0,
+            "D3D11_IMAGE_KMT",
+            "VK_EXTERNAL_MEMORY_HANDLE_TYPE_D3D11_IMAGE_KMT_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for ExternalMemoryHandleTypeFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ExternalMemoryHandleTypeFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ExternalMemoryHandleTypeFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ExternalSemaphoreFeatureFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::EXPORTABLE.0,
+            "EXPORTABLE",
+            "VK_EXTERNAL_SEMAPHORE_FEATURE_EXPORTABLE_BIT",
+        ),
+        (
+            Self::IMPORTABLE.0,
+            "IMPORTABLE",
+            "VK_EXTERNAL_SEMAPHORE_FEATURE_IMPORTABLE_BIT",
+        ),
+        (
+            Self::EXPORTABLE_KHR.0,
+            "EXPORTABLE_KHR",
+            "VK_EXTERNAL_SEMAPHORE_FEATURE_EXPORTABLE_BIT_KHR",
+        ),
+        (
+            Self::IMPORTABLE_KHR.0,
+            "IMPORTABLE_KHR",
+            "VK_EXTERNAL_SEMAPHORE_FEATURE_IMPORTABLE_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for ExternalSemaphoreFeatureFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ExternalSemaphoreFeatureFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ExternalSemaphoreFeatureFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ExternalSemaphoreHandleTypeFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::OPAQUE_FD.0,
+            "OPAQUE_FD",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD_BIT",
+        ),
+        (
+            Self::OPAQUE_WIN32.0,
+            "OPAQUE_WIN32",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_BIT",
+        ),
+        (
+            Self::OPAQUE_WIN32_KMT.0,
+            "OPAQUE_WIN32_KMT",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT",
+        ),
+        (
+            Self::D3D12_FENCE.0,
+            "D3D12_FENCE",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D12_FENCE_BIT",
+        ),
+        (
+            Self::SYNC_FD.0,
+            "SYNC_FD",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_SYNC_FD_BIT",
+        ),
+        (
+            Self::ZIRCON_EVENT_FUCHSIA.0,
+            "ZIRCON_EVENT_FUCHSIA",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_ZIRCON_EVENT_BIT_FUCHSIA",
+        ),
+        (
+            Self::D3D11_FENCE.0,
+            "D3D11_FENCE",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D11_FENCE_BIT",
+        ),
+        (
+            Self::OPAQUE_FD_KHR.0,
+            "OPAQUE_FD_KHR",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_FD_BIT_KHR",
+        ),
+        (
+            Self::OPAQUE_WIN32_KHR.0,
+            "OPAQUE_WIN32_KHR",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_BIT_KHR",
+        ),
+        (
+            Self::OPAQUE_WIN32_KMT_KHR.0,
+            "OPAQUE_WIN32_KMT_KHR",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_OPAQUE_WIN32_KMT_BIT_KHR",
+        ),
+        (
+            Self::D3D12_FENCE_KHR.0,
+            "D3D12_FENCE_KHR",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_D3D12_FENCE_BIT_KHR",
+        ),
+        (
+            Self::SYNC_FD_KHR.0,
+            "SYNC_FD_KHR",
+            "VK_EXTERNAL_SEMAPHORE_HANDLE_TYPE_SYNC_FD_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for ExternalSemaphoreHandleTypeFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ExternalSemaphoreHandleTypeFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ExternalSemaphoreHandleTypeFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl FenceCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> =
+        &[(Self::SIGNALED.0, "SIGNALED", "VK_FENCE_CREATE_SIGNALED_BIT")];
+}
+impl FromStr for FenceCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("FenceCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for FenceCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl FenceImportFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::TEMPORARY.0,
+            "TEMPORARY",
+            "VK_FENCE_IMPORT_TEMPORARY_BIT",
+        ),
+        (
+            Self::TEMPORARY_KHR.0,
+            "TEMPORARY_KHR",
+            "VK_FENCE_IMPORT_TEMPORARY_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for FenceImportFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("FenceImportFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for FenceImportFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl FormatFeatureFlags {
+    pub (crate) const KNOWN : Known < Flags > = & [(Self :: SAMPLED_IMAGE . 0 , "SAMPLED_IMAGE" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_BIT") , (Self :: STORAGE_IMAGE . 0 , "STORAGE_IMAGE" , "VK_FORMAT_FEATURE_STORAGE_IMAGE_BIT") , (Self :: STORAGE_IMAGE_ATOMIC . 0 , "STORAGE_IMAGE_ATOMIC" , "VK_FORMAT_FEATURE_STORAGE_IMAGE_ATOMIC_BIT") , (Self :: UNIFORM_TEXEL_BUFFER . 0 , "UNIFORM_TEXEL_BUFFER" , "VK_FORMAT_FEATURE_UNIFORM_TEXEL_BUFFER_BIT") , (Self :: STORAGE_TEXEL_BUFFER . 0 , "STORAGE_TEXEL_BUFFER" , "VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_BIT") , (Self :: STORAGE_TEXEL_BUFFER_ATOMIC . 0 , "STORAGE_TEXEL_BUFFER_ATOMIC" , "VK_FORMAT_FEATURE_STORAGE_TEXEL_BUFFER_ATOMIC_BIT") , (Self :: VERTEX_BUFFER . 0 , "VERTEX_BUFFER" , "VK_FORMAT_FEATURE_VERTEX_BUFFER_BIT") , (Self :: COLOR_ATTACHMENT . 0 , "COLOR_ATTACHMENT" , "VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BIT") , (Self :: COLOR_ATTACHMENT_BLEND . 0 , "COLOR_ATTACHMENT_BLEND" , "VK_FORMAT_FEATURE_COLOR_ATTACHMENT_BLEND_BIT") , (Self :: DEPTH_STENCIL_ATTACHMENT . 0 , "DEPTH_STENCIL_ATTACHMENT" , "VK_FORMAT_FEATURE_DEPTH_STENCIL_ATTACHMENT_BIT") , (Self :: BLIT_SRC . 0 , "BLIT_SRC" , "VK_FORMAT_FEATURE_BLIT_SRC_BIT") , (Self :: BLIT_DST . 0 , "BLIT_DST" , "VK_FORMAT_FEATURE_BLIT_DST_BIT") , (Self :: SAMPLED_IMAGE_FILTER_LINEAR . 0 , "SAMPLED_IMAGE_FILTER_LINEAR" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_LINEAR_BIT") , (Self :: VIDEO_DECODE_OUTPUT_KHR . 0 , "VIDEO_DECODE_OUTPUT_KHR" , "VK_FORMAT_FEATURE_VIDEO_DECODE_OUTPUT_BIT_KHR") , (Self :: VIDEO_DECODE_DPB_KHR . 0 , "VIDEO_DECODE_DPB_KHR" , "VK_FORMAT_FEATURE_VIDEO_DECODE_DPB_BIT_KHR") , (Self :: ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR . 0 , "ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR" , "VK_FORMAT_FEATURE_ACCELERATION_STRUCTURE_VERTEX_BUFFER_BIT_KHR") , (Self :: SAMPLED_IMAGE_FILTER_CUBIC_EXT . 0 , "SAMPLED_IMAGE_FILTER_CUBIC_EXT" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_CUBIC_BIT_EXT") , (Self :: FRAGMENT_DENSITY_MAP_EXT . 0 , "FRAGMENT_DENSITY_MAP_EXT" , "VK_FORMAT_FEATURE_FRAGMENT_DENSITY_MAP_BIT_EXT") , (Self :: FRAGMENT_SHADING_RATE_ATTACHMENT_KHR . 0 , "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR" , "VK_FORMAT_FEATURE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR") , (Self :: VIDEO_ENCODE_INPUT_KHR . 0 , "VIDEO_ENCODE_INPUT_KHR" , "VK_FORMAT_FEATURE_VIDEO_ENCODE_INPUT_BIT_KHR") , (Self :: VIDEO_ENCODE_DPB_KHR . 0 , "VIDEO_ENCODE_DPB_KHR" , "VK_FORMAT_FEATURE_VIDEO_ENCODE_DPB_BIT_KHR") , (Self :: TRANSFER_SRC . 0 , "TRANSFER_SRC" , "VK_FORMAT_FEATURE_TRANSFER_SRC_BIT") , (Self :: TRANSFER_DST . 0 , "TRANSFER_DST" , "VK_FORMAT_FEATURE_TRANSFER_DST_BIT") , (Self :: MIDPOINT_CHROMA_SAMPLES . 0 , "MIDPOINT_CHROMA_SAMPLES" , "VK_FORMAT_FEATURE_MIDPOINT_CHROMA_SAMPLES_BIT") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT") , (Self :: DISJOINT . 0 , "DISJOINT" , "VK_FORMAT_FEATURE_DISJOINT_BIT") , (Self :: COSITED_CHROMA_SAMPLES . 0 , "COSITED_CHROMA_SAMPLES" , "VK_FORMAT_FEATURE_COSITED_CHROMA_SAMPLES_BIT") , (Self :: SAMPLED_IMAGE_FILTER_MINMAX . 0 , "SAMPLED_IMAGE_FILTER_MINMAX" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_MINMAX_BIT") , (Self :: SAMPLED_IMAGE_FILTER_CUBIC_IMG . 0 , "SAMPLED_IMAGE_FILTER_CUBIC_IMG" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_CUBIC_BIT_IMG") , (Self :: TRANSFER_SRC_KHR . 0 , "TRANSFER_SRC_KHR" , "VK_FORMAT_FEATURE_TRANSFER_SRC_BIT_KHR") , (Self :: TRANSFER_DST_KHR . 0 , "TRANSFER_DST_KHR" , "VK_FORMAT_FEATURE_TRANSFER_DST_BIT_KHR") , (Self :: SAMPLED_IMAGE_FILTER_MINMAX_EXT . 0 , "SAMPLED_IMAGE_FILTER_MINMAX_EXT" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_FILTER_MINMAX_BIT_EXT") , (Self :: MIDPOINT_CHROMA_SAMPLES_KHR . 0 , "MIDPOINT_CHROMA_SAMPLES_KHR" , "VK_FORMAT_FEATURE_MIDPOINT_CHROMA_SAMPLES_BIT_KHR") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_KHR . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_KHR" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT_KHR") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_KHR . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_KHR" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT_KHR") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_KHR . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_KHR" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT_KHR") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_KHR . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_KHR" , "VK_FORMAT_FEATURE_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT_KHR") , (Self :: DISJOINT_KHR . 0 , "DISJOINT_KHR" , "VK_FORMAT_FEATURE_DISJOINT_BIT_KHR") , (Self :: COSITED_CHROMA_SAMPLES_KHR . 0 , "COSITED_CHROMA_SAMPLES_KHR" , "VK_FORMAT_FEATURE_COSITED_CHROMA_SAMPLES_BIT_KHR")] ;
+}
+impl FromStr for FormatFeatureFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("FormatFeatureFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for FormatFeatureFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl FormatFeatureFlags2 {
+    pub (crate) const KNOWN : Known < Flags64 > = & [(Self :: SAMPLED_IMAGE . 0 , "SAMPLED_IMAGE" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_BIT") , (Self :: STORAGE_IMAGE . 0 , "STORAGE_IMAGE" , "VK_FORMAT_FEATURE_2_STORAGE_IMAGE_BIT") , (Self :: STORAGE_IMAGE_ATOMIC . 0 , "STORAGE_IMAGE_ATOMIC" , "VK_FORMAT_FEATURE_2_STORAGE_IMAGE_ATOMIC_BIT") , (Self :: UNIFORM_TEXEL_BUFFER . 0 , "UNIFORM_TEXEL_BUFFER" , "VK_FORMAT_FEATURE_2_UNIFORM_TEXEL_BUFFER_BIT") , (Self :: STORAGE_TEXEL_BUFFER . 0 , "STORAGE_TEXEL_BUFFER" , "VK_FORMAT_FEATURE_2_STORAGE_TEXEL_BUFFER_BIT") , (Self :: STORAGE_TEXEL_BUFFER_ATOMIC . 0 , "STORAGE_TEXEL_BUFFER_ATOMIC" , "VK_FORMAT_FEATURE_2_STORAGE_TEXEL_BUFFER_ATOMIC_BIT") , (Self :: VERTEX_BUFFER . 0 , "VERTEX_BUFFER" , "VK_FORMAT_FEATURE_2_VERTEX_BUFFER_BIT") , (Self :: COLOR_ATTACHMENT . 0 , "COLOR_ATTACHMENT" , "VK_FORMAT_FEATURE_2_COLOR_ATTACHMENT_BIT") , (Self :: COLOR_ATTACHMENT_BLEND . 0 , "COLOR_ATTACHMENT_BLEND" , "VK_FORMAT_FEATURE_2_COLOR_ATTACHMENT_BLEND_BIT") , (Self :: DEPTH_STENCIL_ATTACHMENT . 0 , "DEPTH_STENCIL_ATTACHMENT" , "VK_FORMAT_FEATURE_2_DEPTH_STENCIL_ATTACHMENT_BIT") , (Self :: BLIT_SRC . 0 , "BLIT_SRC" , "VK_FORMAT_FEATURE_2_BLIT_SRC_BIT") , (Self :: BLIT_DST . 0 , "BLIT_DST" , "VK_FORMAT_FEATURE_2_BLIT_DST_BIT") , (Self :: SAMPLED_IMAGE_FILTER_LINEAR . 0 , "SAMPLED_IMAGE_FILTER_LINEAR" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_LINEAR_BIT") , (Self :: SAMPLED_IMAGE_FILTER_CUBIC . 0 , "SAMPLED_IMAGE_FILTER_CUBIC" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_CUBIC_BIT") , (Self :: TRANSFER_SRC . 0 , "TRANSFER_SRC" , "VK_FORMAT_FEATURE_2_TRANSFER_SRC_BIT") , (Self :: TRANSFER_DST . 0 , "TRANSFER_DST" , "VK_FORMAT_FEATURE_2_TRANSFER_DST_BIT") , (Self :: SAMPLED_IMAGE_FILTER_MINMAX . 0 , "SAMPLED_IMAGE_FILTER_MINMAX" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_MINMAX_BIT") , (Self :: MIDPOINT_CHROMA_SAMPLES . 0 , "MIDPOINT_CHROMA_SAMPLES" , "VK_FORMAT_FEATURE_2_MIDPOINT_CHROMA_SAMPLES_BIT") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT") , (Self :: DISJOINT . 0 , "DISJOINT" , "VK_FORMAT_FEATURE_2_DISJOINT_BIT") , (Self :: COSITED_CHROMA_SAMPLES . 0 , "COSITED_CHROMA_SAMPLES" , "VK_FORMAT_FEATURE_2_COSITED_CHROMA_SAMPLES_BIT") , (Self :: STORAGE_READ_WITHOUT_FORMAT . 0 , "STORAGE_READ_WITHOUT_FORMAT" , "VK_FORMAT_FEATURE_2_STORAGE_READ_WITHOUT_FORMAT_BIT") , (Self :: STORAGE_WRITE_WITHOUT_FORMAT . 0 , "STORAGE_WRITE_WITHOUT_FORMAT" , "VK_FORMAT_FEATURE_2_STORAGE_WRITE_WITHOUT_FORMAT_BIT") , (Self :: SAMPLED_IMAGE_DEPTH_COMPARISON . 0 , "SAMPLED_IMAGE_DEPTH_COMPARISON" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_DEPTH_COMPARISON_BIT") , (Self :: VIDEO_DECODE_OUTPUT_KHR . 0 , "VIDEO_DECODE_OUTPUT_KHR" , "VK_FORMAT_FEATURE_2_VIDEO_DECODE_OUTPUT_BIT_KHR") , (Self :: VIDEO_DECODE_DPB_KHR . 0 , "VIDEO_DECODE_DPB_KHR" , "VK_FORMAT_FEATURE_2_VIDEO_DECODE_DPB_BIT_KHR") , (Self :: ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR . 0 , "ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR" , "VK_FORMAT_FEATURE_2_ACCELERATION_STRUCTURE_VERTEX_BUFFER_BIT_KHR") , (Self :: FRAGMENT_DENSITY_MAP_EXT . 0 , "FRAGMENT_DENSITY_MAP_EXT" , "VK_FORMAT_FEATURE_2_FRAGMENT_DENSITY_MAP_BIT_EXT") , (Self :: FRAGMENT_SHADING_RATE_ATTACHMENT_KHR . 0 , "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR" , "VK_FORMAT_FEATURE_2_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR") , (Self :: VIDEO_ENCODE_INPUT_KHR . 0 , "VIDEO_ENCODE_INPUT_KHR" , "VK_FORMAT_FEATURE_2_VIDEO_ENCODE_INPUT_BIT_KHR") , (Self :: VIDEO_ENCODE_DPB_KHR . 0 , "VIDEO_ENCODE_DPB_KHR" , "VK_FORMAT_FEATURE_2_VIDEO_ENCODE_DPB_BIT_KHR") , (Self :: LINEAR_COLOR_ATTACHMENT_NV . 0 , "LINEAR_COLOR_ATTACHMENT_NV" , "VK_FORMAT_FEATURE_2_LINEAR_COLOR_ATTACHMENT_BIT_NV") , (Self :: WEIGHT_IMAGE_QCOM . 0 , "WEIGHT_IMAGE_QCOM" , "VK_FORMAT_FEATURE_2_WEIGHT_IMAGE_BIT_QCOM") , (Self :: WEIGHT_SAMPLED_IMAGE_QCOM . 0 , "WEIGHT_SAMPLED_IMAGE_QCOM" , "VK_FORMAT_FEATURE_2_WEIGHT_SAMPLED_IMAGE_BIT_QCOM") , (Self :: BLOCK_MATCHING_QCOM . 0 , "BLOCK_MATCHING_QCOM" , "VK_FORMAT_FEATURE_2_BLOCK_MATCHING_BIT_QCOM") , (Self :: BOX_FILTER_SAMPLED_QCOM . 0 , "BOX_FILTER_SAMPLED_QCOM" , "VK_FORMAT_FEATURE_2_BOX_FILTER_SAMPLED_BIT_QCOM") , (Self :: OPTICAL_FLOW_IMAGE_NV . 0 , "OPTICAL_FLOW_IMAGE_NV" , "VK_FORMAT_FEATURE_2_OPTICAL_FLOW_IMAGE_BIT_NV") , (Self :: OPTICAL_FLOW_VECTOR_NV . 0 , "OPTICAL_FLOW_VECTOR_NV" , "VK_FORMAT_FEATURE_2_OPTICAL_FLOW_VECTOR_BIT_NV") , (Self :: OPTICAL_FLOW_COST_NV . 0 , "OPTICAL_FLOW_COST_NV" , "VK_FORMAT_FEATURE_2_OPTICAL_FLOW_COST_BIT_NV") , (Self :: SAMPLED_IMAGE_KHR . 0 , "SAMPLED_IMAGE_KHR" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_BIT_KHR") , (Self :: STORAGE_IMAGE_KHR . 0 , "STORAGE_IMAGE_KHR" , "VK_FORMAT_FEATURE_2_STORAGE_IMAGE_BIT_KHR") , (Self :: STORAGE_IMAGE_ATOMIC_KHR . 0 , "STORAGE_IMAGE_ATOMIC_KHR" , "VK_FORMAT_FEATURE_2_STORAGE_IMAGE_ATOMIC_BIT_KHR") , (Self :: UNIFORM_TEXEL_BUFFER_KHR . 0 , "UNIFORM_TEXEL_BUFFER_KHR" , "VK_FORMAT_FEATURE_2_UNIFORM_TEXEL_BUFFER_BIT_KHR") , (Self :: STORAGE_TEXEL_BUFFER_KHR . 0 , "STORAGE_TEXEL_BUFFER_KHR" , "VK_FORMAT_FEATURE_2_STORAGE_TEXEL_BUFFER_BIT_KHR") , (Self :: STORAGE_TEXEL_BUFFER_ATOMIC_KHR . 0 , "STORAGE_TEXEL_BUFFER_ATOMIC_KHR" , "VK_FORMAT_FEATURE_2_STORAGE_TEXEL_BUFFER_ATOMIC_BIT_KHR") , (Self :: VERTEX_BUFFER_KHR . 0 , "VERTEX_BUFFER_KHR" , "VK_FORMAT_FEATURE_2_VERTEX_BUFFER_BIT_KHR") , (Self :: COLOR_ATTACHMENT_KHR . 0 , "COLOR_ATTACHMENT_KHR" , "VK_FORMAT_FEATURE_2_COLOR_ATTACHMENT_BIT_KHR") , (Self :: COLOR_ATTACHMENT_BLEND_KHR . 0 , "COLOR_ATTACHMENT_BLEND_KHR" , "VK_FORMAT_FEATURE_2_COLOR_ATTACHMENT_BLEND_BIT_KHR") , (Self :: DEPTH_STENCIL_ATTACHMENT_KHR . 0 , "DEPTH_STENCIL_ATTACHMENT_KHR" , "VK_FORMAT_FEATURE_2_DEPTH_STENCIL_ATTACHMENT_BIT_KHR") , (Self :: BLIT_SRC_KHR . 0 , "BLIT_SRC_KHR" , "VK_FORMAT_FEATURE_2_BLIT_SRC_BIT_KHR") , (Self :: BLIT_DST_KHR . 0 , "BLIT_DST_KHR" , "VK_FORMAT_FEATURE_2_BLIT_DST_BIT_KHR") , (Self :: SAMPLED_IMAGE_FILTER_LINEAR_KHR . 0 , "SAMPLED_IMAGE_FILTER_LINEAR_KHR" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_LINEAR_BIT_KHR") , (Self :: SAMPLED_IMAGE_FILTER_CUBIC_EXT . 0 , "SAMPLED_IMAGE_FILTER_CUBIC_EXT" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_CUBIC_BIT_EXT") , (Self :: TRANSFER_SRC_KHR . 0 , "TRANSFER_SRC_KHR" , "VK_FORMAT_FEATURE_2_TRANSFER_SRC_BIT_KHR") , (Self :: TRANSFER_DST_KHR . 0 , "TRANSFER_DST_KHR" , "VK_FORMAT_FEATURE_2_TRANSFER_DST_BIT_KHR") , (Self :: SAMPLED_IMAGE_FILTER_MINMAX_KHR . 0 , "SAMPLED_IMAGE_FILTER_MINMAX_KHR" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_FILTER_MINMAX_BIT_KHR") , (Self :: MIDPOINT_CHROMA_SAMPLES_KHR . 0 , "MIDPOINT_CHROMA_SAMPLES_KHR" , "VK_FORMAT_FEATURE_2_MIDPOINT_CHROMA_SAMPLES_BIT_KHR") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_KHR . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_KHR" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER_BIT_KHR") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_KHR . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_KHR" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER_BIT_KHR") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_KHR . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_KHR" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_BIT_KHR") , (Self :: SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_KHR . 0 , "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_KHR" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE_BIT_KHR") , (Self :: DISJOINT_KHR . 0 , "DISJOINT_KHR" , "VK_FORMAT_FEATURE_2_DISJOINT_BIT_KHR") , (Self :: COSITED_CHROMA_SAMPLES_KHR . 0 , "COSITED_CHROMA_SAMPLES_KHR" , "VK_FORMAT_FEATURE_2_COSITED_CHROMA_SAMPLES_BIT_KHR") , (Self :: STORAGE_READ_WITHOUT_FORMAT_KHR . 0 , "STORAGE_READ_WITHOUT_FORMAT_KHR" , "VK_FORMAT_FEATURE_2_STORAGE_READ_WITHOUT_FORMAT_BIT_KHR") , (Self :: STORAGE_WRITE_WITHOUT_FORMAT_KHR . 0 , "STORAGE_WRITE_WITHOUT_FORMAT_KHR" , "VK_FORMAT_FEATURE_2_STORAGE_WRITE_WITHOUT_FORMAT_BIT_KHR") , (Self :: SAMPLED_IMAGE_DEPTH_COMPARISON_KHR . 0 , "SAMPLED_IMAGE_DEPTH_COMPARISON_KHR" , "VK_FORMAT_FEATURE_2_SAMPLED_IMAGE_DEPTH_COMPARISON_BIT_KHR")] ;
+}
+impl FromStr for FormatFeatureFlags2 {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("FormatFeatureFlags2", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for FormatFeatureFlags2 {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl FramebufferCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::IMAGELESS.0,
+            "IMAGELESS",
+            "VK_FRAMEBUFFER_CREATE_IMAGELESS_BIT",
+        ),
+        (
+            Self::IMAGELESS_KHR.0,
+            "IMAGELESS_KHR",
+            "VK_FRAMEBUFFER_CREATE_IMAGELESS_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for FramebufferCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("FramebufferCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for FramebufferCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl GeometryFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::OPAQUE.0, "OPAQUE", "VK_GEOMETRY_OPAQUE_BIT_KHR"),
+        (
+            Self::NO_DUPLICATE_ANY_HIT_INVOCATION.0,
+            "NO_DUPLICATE_ANY_HIT_INVOCATION",
+            "VK_GEOMETRY_NO_DUPLICATE_ANY_HIT_INVOCATION_BIT_KHR",
+        ),
+        (Self::OPAQUE_NV.0, "OPAQUE_NV", "VK_GEOMETRY_OPAQUE_BIT_NV"),
+        (
+            Self::NO_DUPLICATE_ANY_HIT_INVOCATION_NV.0,
+            "NO_DUPLICATE_ANY_HIT_INVOCATION_NV",
+            "VK_GEOMETRY_NO_DUPLICATE_ANY_HIT_INVOCATION_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for GeometryFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("GeometryFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for GeometryFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl GeometryInstanceFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::TRIANGLE_FACING_CULL_DISABLE.0,
+            "TRIANGLE_FACING_CULL_DISABLE",
+            "VK_GEOMETRY_INSTANCE_TRIANGLE_FACING_CULL_DISABLE_BIT_KHR",
+        ),
+        (
+            Self::TRIANGLE_FLIP_FACING.0,
+            "TRIANGLE_FLIP_FACING",
+            "VK_GEOMETRY_INSTANCE_TRIANGLE_FLIP_FACING_BIT_KHR",
+        ),
+        (
+            Self::FORCE_OPAQUE.0,
+            "FORCE_OPAQUE",
+            "VK_GEOMETRY_INSTANCE_FORCE_OPAQUE_BIT_KHR",
+        ),
+        (
+            Self::FORCE_NO_OPAQUE.0,
+            "FORCE_NO_OPAQUE",
+            "VK_GEOMETRY_INSTANCE_FORCE_NO_OPAQUE_BIT_KHR",
+        ),
+        (
+            Self::FORCE_OPACITY_MICROMAP_2_STATE_EXT.0,
+            "FORCE_OPACITY_MICROMAP_2_STATE_EXT",
+            "VK_GEOMETRY_INSTANCE_FORCE_OPACITY_MICROMAP_2_STATE_EXT",
+        ),
+        (
+            Self::DISABLE_OPACITY_MICROMAPS_EXT.0,
+            "DISABLE_OPACITY_MICROMAPS_EXT",
+            "VK_GEOMETRY_INSTANCE_DISABLE_OPACITY_MICROMAPS_EXT",
+        ),
+        (
+            Self::TRIANGLE_FRONT_COUNTERCLOCKWISE.0,
+            "TRIANGLE_FRONT_COUNTERCLOCKWISE",
+            "VK_GEOMETRY_INSTANCE_TRIANGLE_FRONT_COUNTERCLOCKWISE_BIT_KHR",
+        ),
+        (
+            Self::TRIANGLE_CULL_DISABLE_NV.0,
+            "TRIANGLE_CULL_DISABLE_NV",
+            "VK_GEOMETRY_INSTANCE_TRIANGLE_CULL_DISABLE_BIT_NV",
+        ),
+        (
+            Self::TRIANGLE_FRONT_COUNTERCLOCKWISE_NV.0,
+            "TRIANGLE_FRONT_COUNTERCLOCKWISE_NV",
+            "VK_GEOMETRY_INSTANCE_TRIANGLE_FRONT_COUNTERCLOCKWISE_BIT_NV",
+        ),
+        (
+            Self::FORCE_OPAQUE_NV.0,
+            "FORCE_OPAQUE_NV",
+            "VK_GEOMETRY_INSTANCE_FORCE_OPAQUE_BIT_NV",
+        ),
+        (
+            Self::FORCE_NO_OPAQUE_NV.0,
+            "FORCE_NO_OPAQUE_NV",
+            "VK_GEOMETRY_INSTANCE_FORCE_NO_OPAQUE_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for GeometryInstanceFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("GeometryInstanceFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for GeometryInstanceFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl GraphicsPipelineLibraryFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::VERTEX_INPUT_INTERFACE.0,
+            "VERTEX_INPUT_INTERFACE",
+            "VK_GRAPHICS_PIPELINE_LIBRARY_VERTEX_INPUT_INTERFACE_BIT_EXT",
+        ),
+        (
+            Self::PRE_RASTERIZATION_SHADERS.0,
+            "PRE_RASTERIZATION_SHADERS",
+            "VK_GRAPHICS_PIPELINE_LIBRARY_PRE_RASTERIZATION_SHADERS_BIT_EXT",
+        ),
+        (
+            Self::FRAGMENT_SHADER.0,
+            "FRAGMENT_SHADER",
+            "VK_GRAPHICS_PIPELINE_LIBRARY_FRAGMENT_SHADER_BIT_EXT",
+        ),
+        (
+            Self::FRAGMENT_OUTPUT_INTERFACE.0,
+            "FRAGMENT_OUTPUT_INTERFACE",
+            "VK_GRAPHICS_PIPELINE_LIBRARY_FRAGMENT_OUTPUT_INTERFACE_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for GraphicsPipelineLibraryFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("GraphicsPipelineLibraryFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for GraphicsPipelineLibraryFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl HeadlessSurfaceCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for HeadlessSurfaceCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("HeadlessSurfaceCreateFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for HeadlessSurfaceCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl IOSSurfaceCreateFlagsMVK {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for IOSSurfaceCreateFlagsMVK {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("IOSSurfaceCreateFlagsMVK", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for IOSSurfaceCreateFlagsMVK {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageAspectFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::COLOR.0, "COLOR", "VK_IMAGE_ASPECT_COLOR_BIT"),
+        (Self::DEPTH.0, "DEPTH", "VK_IMAGE_ASPECT_DEPTH_BIT"),
+        (Self::STENCIL.0, "STENCIL", "VK_IMAGE_ASPECT_STENCIL_BIT"),
+        (Self::METADATA.0, "METADATA", "VK_IMAGE_ASPECT_METADATA_BIT"),
+        (
+            Self::MEMORY_PLANE_0_EXT.0,
+            "MEMORY_PLANE_0_EXT",
+            "VK_IMAGE_ASPECT_MEMORY_PLANE_0_BIT_EXT",
+        ),
+        (
+            Self::MEMORY_PLANE_1_EXT.0,
+            "MEMORY_PLANE_1_EXT",
+            "VK_IMAGE_ASPECT_MEMORY_PLANE_1_BIT_EXT",
+        ),
+        (
+            Self::MEMORY_PLANE_2_EXT.0,
+            "MEMORY_PLANE_2_EXT",
+            "VK_IMAGE_ASPECT_MEMORY_PLANE_2_BIT_EXT",
+        ),
+        (
+            Self::MEMORY_PLANE_3_EXT.0,
+            "MEMORY_PLANE_3_EXT",
+            "VK_IMAGE_ASPECT_MEMORY_PLANE_3_BIT_EXT",
+        ),
+        (Self::PLANE_0.0, "PLANE_0", "VK_IMAGE_ASPECT_PLANE_0_BIT"),
+        (Self::PLANE_1.0, "PLANE_1", "VK_IMAGE_ASPECT_PLANE_1_BIT"),
+        (Self::PLANE_2.0, "PLANE_2", "VK_IMAGE_ASPECT_PLANE_2_BIT"),
+        (Self::NONE.0, "NONE", "VK_IMAGE_ASPECT_NONE"),
+        (
+            Self::PLANE_0_KHR.0,
+            "PLANE_0_KHR",
+            "VK_IMAGE_ASPECT_PLANE_0_BIT_KHR",
+        ),
+        (
+            Self::PLANE_1_KHR.0,
+            "PLANE_1_KHR",
+            "VK_IMAGE_ASPECT_PLANE_1_BIT_KHR",
+        ),
+        (
+            Self::PLANE_2_KHR.0,
+            "PLANE_2_KHR",
+            "VK_IMAGE_ASPECT_PLANE_2_BIT_KHR",
+        ),
+        (Self::NONE_KHR.0, "NONE_KHR", "VK_IMAGE_ASPECT_NONE_KHR"),
+    ];
+}
+impl FromStr for ImageAspectFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ImageAspectFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageAspectFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageCompressionFixedRateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::NONE.0,
+            "NONE",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_NONE_EXT",
+        ),
+        (
+            Self::TYPE_1BPC.0,
+            "TYPE_1BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_1BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_2BPC.0,
+            "TYPE_2BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_2BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_3BPC.0,
+            "TYPE_3BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_3BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_4BPC.0,
+            "TYPE_4BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_4BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_5BPC.0,
+            "TYPE_5BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_5BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_6BPC.0,
+            "TYPE_6BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_6BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_7BPC.0,
+            "TYPE_7BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_7BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_8BPC.0,
+            "TYPE_8BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_8BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_9BPC.0,
+            "TYPE_9BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_9BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_10BPC.0,
+            "TYPE_10BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_10BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_11BPC.0,
+            "TYPE_11BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_11BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_12BPC.0,
+            "TYPE_12BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_12BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_13BPC.0,
+            "TYPE_13BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_13BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_14BPC.0,
+            "TYPE_14BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_14BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_15BPC.0,
+            "TYPE_15BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_15BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_16BPC.0,
+            "TYPE_16BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_16BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_17BPC.0,
+            "TYPE_17BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_17BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_18BPC.0,
+            "TYPE_18BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_18BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_19BPC.0,
+            "TYPE_19BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_19BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_20BPC.0,
+            "TYPE_20BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_20BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_21BPC.0,
+            "TYPE_21BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_21BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_22BPC.0,
+            "TYPE_22BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_22BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_23BPC.0,
+            "TYPE_23BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_23BPC_BIT_EXT",
+        ),
+        (
+            Self::TYPE_24BPC.0,
+            "TYPE_24BPC",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_24BPC_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for ImageCompressionFixedRateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ImageCompressionFixedRateFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageCompressionFixedRateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageCompressionFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEFAULT.0,
+            "DEFAULT",
+            "VK_IMAGE_COMPRESSION_DEFAULT_EXT",
+        ),
+        (
+            Self::FIXED_RATE_DEFAULT.0,
+            "FIXED_RATE_DEFAULT",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_DEFAULT_EXT",
+        ),
+        (
+            Self::FIXED_RATE_EXPLICIT.0,
+            "FIXED_RATE_EXPLICIT",
+            "VK_IMAGE_COMPRESSION_FIXED_RATE_EXPLICIT_EXT",
+        ),
+        (
+            Self::DISABLED.0,
+            "DISABLED",
+            "VK_IMAGE_COMPRESSION_DISABLED_EXT",
+        ),
+    ];
+}
+impl FromStr for ImageCompressionFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ImageCompressionFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageCompressionFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageConstraintsInfoFlagsFUCHSIA {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::CPU_READ_RARELY.0,
+            "CPU_READ_RARELY",
+            "VK_IMAGE_CONSTRAINTS_INFO_CPU_READ_RARELY_FUCHSIA",
+        ),
+        (
+            Self::CPU_READ_OFTEN.0,
+            "CPU_READ_OFTEN",
+            "VK_IMAGE_CONSTRAINTS_INFO_CPU_READ_OFTEN_FUCHSIA",
+        ),
+        (
+            Self::CPU_WRITE_RARELY.0,
+            "CPU_WRITE_RARELY",
+            "VK_IMAGE_CONSTRAINTS_INFO_CPU_WRITE_RARELY_FUCHSIA",
+        ),
+        (
+            Self::CPU_WRITE_OFTEN.0,
+            "CPU_WRITE_OFTEN",
+            "VK_IMAGE_CONSTRAINTS_INFO_CPU_WRITE_OFTEN_FUCHSIA",
+        ),
+        (
+            Self::PROTECTED_OPTIONAL.0,
+            "PROTECTED_OPTIONAL",
+            "VK_IMAGE_CONSTRAINTS_INFO_PROTECTED_OPTIONAL_FUCHSIA",
+        ),
+    ];
+}
+impl FromStr for ImageConstraintsInfoFlagsFUCHSIA {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ImageConstraintsInfoFlagsFUCHSIA", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageConstraintsInfoFlagsFUCHSIA {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::SPARSE_BINDING.0,
+            "SPARSE_BINDING",
+            "VK_IMAGE_CREATE_SPARSE_BINDING_BIT",
+        ),
+        (
+            Self::SPARSE_RESIDENCY.0,
+            "SPARSE_RESIDENCY",
+            "VK_IMAGE_CREATE_SPARSE_RESIDENCY_BIT",
+        ),
+        (
+            Self::SPARSE_ALIASED.0,
+            "SPARSE_ALIASED",
+            "VK_IMAGE_CREATE_SPARSE_ALIASED_BIT",
+        ),
+        (
+            Self::MUTABLE_FORMAT.0,
+            "MUTABLE_FORMAT",
+            "VK_IMAGE_CREATE_MUTABLE_FORMAT_BIT",
+        ),
+        (
+            Self::CUBE_COMPATIBLE.0,
+            "CUBE_COMPATIBLE",
+            "VK_IMAGE_CREATE_CUBE_COMPATIBLE_BIT",
+        ),
+        (
+            Self::CORNER_SAMPLED_NV.0,
+            "CORNER_SAMPLED_NV",
+            "VK_IMAGE_CREATE_CORNER_SAMPLED_BIT_NV",
+        ),
+        (
+            Self::SAMPLE_LOCATIONS_COMPATIBLE_DEPTH_EXT.0,
+            "SAMPLE_LOCATIONS_COMPATIBLE_DEPTH_EXT",
+            "VK_IMAGE_CREATE_SAMPLE_LOCATIONS_COMPATIBLE_DEPTH_BIT_EXT",
+        ),
+        (
+            Self::SUBSAMPLED_EXT.0,
+            "SUBSAMPLED_EXT",
+            "VK_IMAGE_CREATE_SUBSAMPLED_BIT_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.0,
+            "DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT",
+            "VK_IMAGE_CREATE_DESCRIPTOR_BUFFER_CAPTURE_REPLAY_BIT_EXT",
+        ),
+        (
+            Self::MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_EXT.0,
+            "MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_EXT",
+            "VK_IMAGE_CREATE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_BIT_EXT",
+        ),
+        (
+            Self::TYPE_2D_VIEW_COMPATIBLE_EXT.0,
+            "TYPE_2D_VIEW_COMPATIBLE_EXT",
+            "VK_IMAGE_CREATE_2D_VIEW_COMPATIBLE_BIT_EXT",
+        ),
+        (
+            Self::FRAGMENT_DENSITY_MAP_OFFSET_QCOM.0,
+            "FRAGMENT_DENSITY_MAP_OFFSET_QCOM",
+            "VK_IMAGE_CREATE_FRAGMENT_DENSITY_MAP_OFFSET_BIT_QCOM",
+        ),
+        (Self::ALIAS.0, "ALIAS", "VK_IMAGE_CREATE_ALIAS_BIT"),
+        (
+            Self::SPLIT_INSTANCE_BIND_REGIONS.0,
+            "SPLIT_INSTANCE_BIND_REGIONS",
+            "VK_IMAGE_CREATE_SPLIT_INSTANCE_BIND_REGIONS_BIT",
+        ),
+        (
+            Self::TYPE_2D_ARRAY_COMPATIBLE.0,
+            "TYPE_2D_ARRAY_COMPATIBLE",
+            "VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT",
+        ),
+        (
+            Self::BLOCK_TEXEL_VIEW_COMPATIBLE.0,
+            "BLOCK_TEXEL_VIEW_COMPATIBLE",
+            "VK_IMAGE_CREATE_BLOCK_TEXEL_VIEW_COMPATIBLE_BIT",
+        ),
+        (
+            Self::EXTENDED_USAGE.0,
+            "EXTENDED_USAGE",
+            "VK_IMAGE_CREATE_EXTENDED_USAGE_BIT",
+        ),
+        (
+            Self::PROTECTED.0,
+            "PROTECTED",
+            "VK_IMAGE_CREATE_PROTECTED_BIT",
+        ),
+        (Self::DISJOINT.0, "DISJOINT", "VK_IMAGE_CREATE_DISJOINT_BIT"),
+        (
+            Self::SPLIT_INSTANCE_BIND_REGIONS_KHR.0,
+            "SPLIT_INSTANCE_BIND_REGIONS_KHR",
+            "VK_IMAGE_CREATE_SPLIT_INSTANCE_BIND_REGIONS_BIT_KHR",
+        ),
+        (
+            Self::TYPE_2D_ARRAY_COMPATIBLE_KHR.0,
+            "TYPE_2D_ARRAY_COMPATIBLE_KHR",
+            "VK_IMAGE_CREATE_2D_ARRAY_COMPATIBLE_BIT_KHR",
+        ),
+        (
+            Self::BLOCK_TEXEL_VIEW_COMPATIBLE_KHR.0,
+            "BLOCK_TEXEL_VIEW_COMPATIBLE_KHR",
+            "VK_IMAGE_CREATE_BLOCK_TEXEL_VIEW_COMPATIBLE_BIT_KHR",
+        ),
+        (
+            Self::EXTENDED_USAGE_KHR.0,
+            "EXTENDED_USAGE_KHR",
+            "VK_IMAGE_CREATE_EXTENDED_USAGE_BIT_KHR",
+        ),
+        (
+            Self::DISJOINT_KHR.0,
+            "DISJOINT_KHR",
+            "VK_IMAGE_CREATE_DISJOINT_BIT_KHR",
+        ),
+        (
+            Self::ALIAS_KHR.0,
+            "ALIAS_KHR",
+            "VK_IMAGE_CREATE_ALIAS_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for ImageCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ImageCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageFormatConstraintsFlagsFUCHSIA {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for ImageFormatConstraintsFlagsFUCHSIA {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ImageFormatConstraintsFlagsFUCHSIA", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageFormatConstraintsFlagsFUCHSIA {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImagePipeSurfaceCreateFlagsFUCHSIA {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for ImagePipeSurfaceCreateFlagsFUCHSIA {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ImagePipeSurfaceCreateFlagsFUCHSIA", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImagePipeSurfaceCreateFlagsFUCHSIA {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageUsageFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::TRANSFER_SRC.0,
+            "TRANSFER_SRC",
+            "VK_IMAGE_USAGE_TRANSFER_SRC_BIT",
+        ),
+        (
+            Self::TRANSFER_DST.0,
+            "TRANSFER_DST",
+            "VK_IMAGE_USAGE_TRANSFER_DST_BIT",
+        ),
+        (Self::SAMPLED.0, "SAMPLED", "VK_IMAGE_USAGE_SAMPLED_BIT"),
+        (Self::STORAGE.0, "STORAGE", "VK_IMAGE_USAGE_STORAGE_BIT"),
+        (
+            Self::COLOR_ATTACHMENT.0,
+            "COLOR_ATTACHMENT",
+            "VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT",
+        ),
+        (
+            Self::DEPTH_STENCIL_ATTACHMENT.0,
+            "DEPTH_STENCIL_ATTACHMENT",
+            "VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT",
+        ),
+        (
+            Self::TRANSIENT_ATTACHMENT.0,
+            "TRANSIENT_ATTACHMENT",
+            "VK_IMAGE_USAGE_TRANSIENT_ATTACHMENT_BIT",
+        ),
+        (
+            Self::INPUT_ATTACHMENT.0,
+            "INPUT_ATTACHMENT",
+            "VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT",
+        ),
+        (
+            Self::VIDEO_DECODE_DST_KHR.0,
+            "VIDEO_DECODE_DST_KHR",
+            "VK_IMAGE_USAGE_VIDEO_DECODE_DST_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_SRC_KHR.0,
+            "VIDEO_DECODE_SRC_KHR",
+            "VK_IMAGE_USAGE_VIDEO_DECODE_SRC_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_DPB_KHR.0,
+            "VIDEO_DECODE_DPB_KHR",
+            "VK_IMAGE_USAGE_VIDEO_DECODE_DPB_BIT_KHR",
+        ),
+        (
+            Self::FRAGMENT_DENSITY_MAP_EXT.0,
+            "FRAGMENT_DENSITY_MAP_EXT",
+            "VK_IMAGE_USAGE_FRAGMENT_DENSITY_MAP_BIT_EXT",
+        ),
+        (
+            Self::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.0,
+            "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR",
+            "VK_IMAGE_USAGE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_DST_KHR.0,
+            "VIDEO_ENCODE_DST_KHR",
+            "VK_IMAGE_USAGE_VIDEO_ENCODE_DST_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_SRC_KHR.0,
+            "VIDEO_ENCODE_SRC_KHR",
+            "VK_IMAGE_USAGE_VIDEO_ENCODE_SRC_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_DPB_KHR.0,
+            "VIDEO_ENCODE_DPB_KHR",
+            "VK_IMAGE_USAGE_VIDEO_ENCODE_DPB_BIT_KHR",
+        ),
+        (
+            Self::ATTACHMENT_FEEDBACK_LOOP_EXT.0,
+            "ATTACHMENT_FEEDBACK_LOOP_EXT",
+            "VK_IMAGE_USAGE_ATTACHMENT_FEEDBACK_LOOP_BIT_EXT",
+        ),
+        (
+            Self::INVOCATION_MASK_HUAWEI.0,
+            "INVOCATION_MASK_HUAWEI",
+            "VK_IMAGE_USAGE_INVOCATION_MASK_BIT_HUAWEI",
+        ),
+        (
+            Self::SAMPLE_WEIGHT_QCOM.0,
+            "SAMPLE_WEIGHT_QCOM",
+            "VK_IMAGE_USAGE_SAMPLE_WEIGHT_BIT_QCOM",
+        ),
+        (
+            Self::SAMPLE_BLOCK_MATCH_QCOM.0,
+            "SAMPLE_BLOCK_MATCH_QCOM",
+            "VK_IMAGE_USAGE_SAMPLE_BLOCK_MATCH_BIT_QCOM",
+        ),
+        (
+            Self::SHADING_RATE_IMAGE_NV.0,
+            "SHADING_RATE_IMAGE_NV",
+            "VK_IMAGE_USAGE_SHADING_RATE_IMAGE_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for ImageUsageFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ImageUsageFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageUsageFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageViewCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::FRAGMENT_DENSITY_MAP_DYNAMIC_EXT.0,
+            "FRAGMENT_DENSITY_MAP_DYNAMIC_EXT",
+            "VK_IMAGE_VIEW_CREATE_FRAGMENT_DENSITY_MAP_DYNAMIC_BIT_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.0,
+            "DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT",
+            "VK_IMAGE_VIEW_CREATE_DESCRIPTOR_BUFFER_CAPTURE_REPLAY_BIT_EXT",
+        ),
+        (
+            Self::FRAGMENT_DENSITY_MAP_DEFERRED_EXT.0,
+            "FRAGMENT_DENSITY_MAP_DEFERRED_EXT",
+            "VK_IMAGE_VIEW_CREATE_FRAGMENT_DENSITY_MAP_DEFERRED_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for ImageViewCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ImageViewCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageViewCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl IndirectCommandsLayoutUsageFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::EXPLICIT_PREPROCESS.0,
+            "EXPLICIT_PREPROCESS",
+            "VK_INDIRECT_COMMANDS_LAYOUT_USAGE_EXPLICIT_PREPROCESS_BIT_NV",
+        ),
+        (
+            Self::INDEXED_SEQUENCES.0,
+            "INDEXED_SEQUENCES",
+            "VK_INDIRECT_COMMANDS_LAYOUT_USAGE_INDEXED_SEQUENCES_BIT_NV",
+        ),
+        (
+            Self::UNORDERED_SEQUENCES.0,
+            "UNORDERED_SEQUENCES",
+            "VK_INDIRECT_COMMANDS_LAYOUT_USAGE_UNORDERED_SEQUENCES_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for IndirectCommandsLayoutUsageFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("IndirectCommandsLayoutUsageFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for IndirectCommandsLayoutUsageFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl IndirectStateFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::FLAG_FRONTFACE.0,
+        "FLAG_FRONTFACE",
+        "VK_INDIRECT_STATE_FLAG_FRONTFACE_BIT_NV",
+    )];
+}
+impl FromStr for IndirectStateFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("IndirectStateFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for IndirectStateFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl InstanceCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::ENUMERATE_PORTABILITY_KHR.0,
+        "ENUMERATE_PORTABILITY_KHR",
+        "VK_INSTANCE_CREATE_ENUMERATE_PORTABILITY_BIT_KHR",
+    )];
+}
+impl FromStr for InstanceCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("InstanceCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for InstanceCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MacOSSurfaceCreateFlagsMVK {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for MacOSSurfaceCreateFlagsMVK {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("MacOSSurfaceCreateFlagsMVK", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MacOSSurfaceCreateFlagsMVK {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MemoryAllocateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEVICE_MASK.0,
+            "DEVICE_MASK",
+            "VK_MEMORY_ALLOCATE_DEVICE_MASK_BIT",
+        ),
+        (
+            Self::DEVICE_ADDRESS.0,
+            "DEVICE_ADDRESS",
+            "VK_MEMORY_ALLOCATE_DEVICE_ADDRESS_BIT",
+        ),
+        (
+            Self::DEVICE_ADDRESS_CAPTURE_REPLAY.0,
+            "DEVICE_ADDRESS_CAPTURE_REPLAY",
+            "VK_MEMORY_ALLOCATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT",
+        ),
+        (
+            Self::DEVICE_MASK_KHR.0,
+            "DEVICE_MASK_KHR",
+            "VK_MEMORY_ALLOCATE_DEVICE_MASK_BIT_KHR",
+        ),
+        (
+            Self::DEVICE_ADDRESS_KHR.0,
+            "DEVICE_ADDRESS_KHR",
+            "VK_MEMORY_ALLOCATE_DEVICE_ADDRESS_BIT_KHR",
+        ),
+        (
+            Self::DEVICE_ADDRESS_CAPTURE_REPLAY_KHR.0,
+            "DEVICE_ADDRESS_CAPTURE_REPLAY_KHR",
+            "VK_MEMORY_ALLOCATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for MemoryAllocateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("MemoryAllocateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MemoryAllocateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MemoryDecompressionMethodFlagsNV {
+    pub(crate) const KNOWN: Known<Flags64> = &[(
+        Self::GDEFLATE_1_0.0,
+        "GDEFLATE_1_0",
+        "VK_MEMORY_DECOMPRESSION_METHOD_GDEFLATE_1_0_BIT_NV",
+    )];
+}
+impl FromStr for MemoryDecompressionMethodFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("MemoryDecompressionMethodFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MemoryDecompressionMethodFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MemoryHeapFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEVICE_LOCAL.0,
+            "DEVICE_LOCAL",
+            "VK_MEMORY_HEAP_DEVICE_LOCAL_BIT",
+        ),
+        (
+            Self::MULTI_INSTANCE.0,
+            "MULTI_INSTANCE",
+            "VK_MEMORY_HEAP_MULTI_INSTANCE_BIT",
+        ),
+        (
+            Self::MULTI_INSTANCE_KHR.0,
+            "MULTI_INSTANCE_KHR",
+            "VK_MEMORY_HEAP_MULTI_INSTANCE_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for MemoryHeapFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("MemoryHeapFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MemoryHeapFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MemoryMapFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for MemoryMapFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("MemoryMapFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MemoryMapFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MemoryPropertyFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEVICE_LOCAL.0,
+            "DEVICE_LOCAL",
+            "VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT",
+        ),
+        (
+            Self::HOST_VISIBLE.0,
+            "HOST_VISIBLE",
+            "VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT",
+        ),
+        (
+            Self::HOST_COHERENT.0,
+            "HOST_COHERENT",
+            "VK_MEMORY_PROPERTY_HOST_COHERENT_BIT",
+        ),
+        (
+            Self::HOST_CACHED.0,
+            "HOST_CACHED",
+            "VK_MEMORY_PROPERTY_HOST_CACHED_BIT",
+        ),
+        (
+            Self::LAZILY_ALLOCATED.0,
+            "LAZILY_ALLOCATED",
+            "VK_MEMORY_PROPERTY_LAZILY_ALLOCATED_BIT",
+        ),
+        (
+            Self::DEVICE_COHERENT_AMD.0,
+            "DEVICE_COHERENT_AMD",
+            "VK_MEMORY_PROPERTY_DEVICE_COHERENT_BIT_AMD",
+        ),
+        (
+            Self::DEVICE_UNCACHED_AMD.0,
+            "DEVICE_UNCACHED_AMD",
+            "VK_MEMORY_PROPERTY_DEVICE_UNCACHED_BIT_AMD",
+        ),
+        (
+            Self::RDMA_CAPABLE_NV.0,
+            "RDMA_CAPABLE_NV",
+            "VK_MEMORY_PROPERTY_RDMA_CAPABLE_BIT_NV",
+        ),
+        (
+            Self::PROTECTED.0,
+            "PROTECTED",
+            "VK_MEMORY_PROPERTY_PROTECTED_BIT",
+        ),
+    ];
+}
+impl FromStr for MemoryPropertyFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("MemoryPropertyFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MemoryPropertyFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MemoryUnmapFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for MemoryUnmapFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("MemoryUnmapFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MemoryUnmapFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MetalSurfaceCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for MetalSurfaceCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("MetalSurfaceCreateFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MetalSurfaceCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MicromapCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::DEVICE_ADDRESS_CAPTURE_REPLAY.0,
+        "DEVICE_ADDRESS_CAPTURE_REPLAY",
+        "VK_MICROMAP_CREATE_DEVICE_ADDRESS_CAPTURE_REPLAY_BIT_EXT",
+    )];
+}
+impl FromStr for MicromapCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("MicromapCreateFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MicromapCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl OpticalFlowExecuteFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::DISABLE_TEMPORAL_HINTS.0,
+        "DISABLE_TEMPORAL_HINTS",
+        "VK_OPTICAL_FLOW_EXECUTE_DISABLE_TEMPORAL_HINTS_BIT_NV",
+    )];
+}
+impl FromStr for OpticalFlowExecuteFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("OpticalFlowExecuteFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for OpticalFlowExecuteFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl OpticalFlowGridSizeFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::UNKNOWN.0,
+            "UNKNOWN",
+            "VK_OPTICAL_FLOW_GRID_SIZE_UNKNOWN_NV",
+        ),
+        (
+            Self::TYPE_1X1.0,
+            "TYPE_1X1",
+            "VK_OPTICAL_FLOW_GRID_SIZE_1X1_BIT_NV",
+        ),
+        (
+            Self::TYPE_2X2.0,
+            "TYPE_2X2",
+            "VK_OPTICAL_FLOW_GRID_SIZE_2X2_BIT_NV",
+        ),
+        (
+            Self::TYPE_4X4.0,
+            "TYPE_4X4",
+            "VK_OPTICAL_FLOW_GRID_SIZE_4X4_BIT_NV",
+        ),
+        (
+            Self::TYPE_8X8.0,
+            "TYPE_8X8",
+            "VK_OPTICAL_FLOW_GRID_SIZE_8X8_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for OpticalFlowGridSizeFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("OpticalFlowGridSizeFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for OpticalFlowGridSizeFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl OpticalFlowSessionCreateFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::ENABLE_HINT.0,
+            "ENABLE_HINT",
+            "VK_OPTICAL_FLOW_SESSION_CREATE_ENABLE_HINT_BIT_NV",
+        ),
+        (
+            Self::ENABLE_COST.0,
+            "ENABLE_COST",
+            "VK_OPTICAL_FLOW_SESSION_CREATE_ENABLE_COST_BIT_NV",
+        ),
+        (
+            Self::ENABLE_GLOBAL_FLOW.0,
+            "ENABLE_GLOBAL_FLOW",
+            "VK_OPTICAL_FLOW_SESSION_CREATE_ENABLE_GLOBAL_FLOW_BIT_NV",
+        ),
+        (
+            Self::ALLOW_REGIONS.0,
+            "ALLOW_REGIONS",
+            "VK_OPTICAL_FLOW_SESSION_CREATE_ALLOW_REGIONS_BIT_NV",
+        ),
+        (
+            Self::BOTH_DIRECTIONS.0,
+            "BOTH_DIRECTIONS",
+            "VK_OPTICAL_FLOW_SESSION_CREATE_BOTH_DIRECTIONS_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for OpticalFlowSessionCreateFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("OpticalFlowSessionCreateFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for OpticalFlowSessionCreateFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl OpticalFlowUsageFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::UNKNOWN.0,
+            "UNKNOWN",
+            "VK_OPTICAL_FLOW_USAGE_UNKNOWN_NV",
+        ),
+        (Self::INPUT.0, "INPUT", "VK_OPTICAL_FLOW_USAGE_INPUT_BIT_NV"),
+        (
+            Self::OUTPUT.0,
+            "OUTPUT",
+            "VK_OPTICAL_FLOW_USAGE_OUTPUT_BIT_NV",
+        ),
+        (Self::HINT.0, "HINT", "VK_OPTICAL_FLOW_USAGE_HINT_BIT_NV"),
+        (Self::COST.0, "COST", "VK_OPTICAL_FLOW_USAGE_COST_BIT_NV"),
+        (
+            Self::GLOBAL_FLOW.0,
+            "GLOBAL_FLOW",
+            "VK_OPTICAL_FLOW_USAGE_GLOBAL_FLOW_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for OpticalFlowUsageFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("OpticalFlowUsageFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for OpticalFlowUsageFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PeerMemoryFeatureFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::COPY_SRC.0,
+            "COPY_SRC",
+            "VK_PEER_MEMORY_FEATURE_COPY_SRC_BIT",
+        ),
+        (
+            Self::COPY_DST.0,
+            "COPY_DST",
+            "VK_PEER_MEMORY_FEATURE_COPY_DST_BIT",
+        ),
+        (
+            Self::GENERIC_SRC.0,
+            "GENERIC_SRC",
+            "VK_PEER_MEMORY_FEATURE_GENERIC_SRC_BIT",
+        ),
+        (
+            Self::GENERIC_DST.0,
+            "GENERIC_DST",
+            "VK_PEER_MEMORY_FEATURE_GENERIC_DST_BIT",
+        ),
+        (
+            Self::COPY_SRC_KHR.0,
+            "COPY_SRC_KHR",
+            "VK_PEER_MEMORY_FEATURE_COPY_SRC_BIT_KHR",
+        ),
+        (
+            Self::COPY_DST_KHR.0,
+            "COPY_DST_KHR",
+            "VK_PEER_MEMORY_FEATURE_COPY_DST_BIT_KHR",
+        ),
+        (
+            Self::GENERIC_SRC_KHR.0,
+            "GENERIC_SRC_KHR",
+            "VK_PEER_MEMORY_FEATURE_GENERIC_SRC_BIT_KHR",
+        ),
+        (
+            Self::GENERIC_DST_KHR.0,
+            "GENERIC_DST_KHR",
+            "VK_PEER_MEMORY_FEATURE_GENERIC_DST_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for PeerMemoryFeatureFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PeerMemoryFeatureFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PeerMemoryFeatureFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PerformanceCounterDescriptionFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::PERFORMANCE_IMPACTING.0,
+            "PERFORMANCE_IMPACTING",
+            "VK_PERFORMANCE_COUNTER_DESCRIPTION_PERFORMANCE_IMPACTING_BIT_KHR",
+        ),
+        (
+            Self::CONCURRENTLY_IMPACTED.0,
+            "CONCURRENTLY_IMPACTED",
+            "VK_PERFORMANCE_COUNTER_DESCRIPTION_CONCURRENTLY_IMPACTED_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for PerformanceCounterDescriptionFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PerformanceCounterDescriptionFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PerformanceCounterDescriptionFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineCacheCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::EXTERNALLY_SYNCHRONIZED.0,
+            "EXTERNALLY_SYNCHRONIZED",
+            "VK_PIPELINE_CACHE_CREATE_EXTERNALLY_SYNCHRONIZED_BIT",
+        ),
+        (
+            Self::EXTERNALLY_SYNCHRONIZED_EXT.0,
+            "EXTERNALLY_SYNCHRONIZED_EXT",
+            "VK_PIPELINE_CACHE_CREATE_EXTERNALLY_SYNCHRONIZED_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for PipelineCacheCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineCacheCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineCacheCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineColorBlendStateCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::RASTERIZATION_ORDER_ATTACHMENT_ACCESS_EXT.0,
+            "RASTERIZATION_ORDER_ATTACHMENT_ACCESS_EXT",
+            "VK_PIPELINE_COLOR_BLEND_STATE_CREATE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_BIT_EXT",
+        ),
+        (
+            Self::RASTERIZATION_ORDER_ATTACHMENT_ACCESS_ARM.0,
+            "RASTERIZATION_ORDER_ATTACHMENT_ACCESS_ARM",
+            "VK_PIPELINE_COLOR_BLEND_STATE_CREATE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_BIT_ARM",
+        ),
+    ];
+}
+impl FromStr for PipelineColorBlendStateCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineColorBlendStateCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineColorBlendStateCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineCompilerControlFlagsAMD {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineCompilerControlFlagsAMD {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineCompilerControlFlagsAMD", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineCompilerControlFlagsAMD {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineCoverageModulationStateCreateFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineCoverageModulationStateCreateFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags(
+            "PipelineCoverageModulationStateCreateFlagsNV",
+            Self::KNOWN,
+            s,
+        )
+        .map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineCoverageModulationStateCreateFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineCoverageReductionStateCreateFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineCoverageReductionStateCreateFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags(
+            "PipelineCoverageReductionStateCreateFlagsNV",
+            Self::KNOWN,
+            s,
+        )
+        .map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineCoverageReductionStateCreateFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineCoverageToColorStateCreateFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineCoverageToColorStateCreateFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineCoverageToColorStateCreateFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineCoverageToColorStateCreateFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DISABLE_OPTIMIZATION.0,
+            "DISABLE_OPTIMIZATION",
+            "VK_PIPELINE_CREATE_DISABLE_OPTIMIZATION_BIT",
+        ),
+        (
+            Self::ALLOW_DERIVATIVES.0,
+            "ALLOW_DERIVATIVES",
+            "VK_PIPELINE_CREATE_ALLOW_DERIVATIVES_BIT",
+        ),
+        (
+            Self::DERIVATIVE.0,
+            "DERIVATIVE",
+            "VK_PIPELINE_CREATE_DERIVATIVE_BIT",
+        ),
+        (
+            Self::RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.0,
+            "RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_KHR",
+            "VK_PIPELINE_CREATE_RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR",
+        ),
+        (
+            Self::RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_EXT.0,
+            "RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_EXT",
+            "VK_PIPELINE_CREATE_RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_BIT_EXT",
+        ),
+        (
+            Self::RAY_TRACING_NO_NULL_ANY_HIT_SHADERS_KHR.0,
+            "RAY_TRACING_NO_NULL_ANY_HIT_SHADERS_KHR",
+            "VK_PIPELINE_CREATE_RAY_TRACING_NO_NULL_ANY_HIT_SHADERS_BIT_KHR",
+        ),
+        (
+            Self::RAY_TRACING_NO_NULL_CLOSEST_HIT_SHADERS_KHR.0,
+            "RAY_TRACING_NO_NULL_CLOSEST_HIT_SHADERS_KHR",
+            "VK_PIPELINE_CREATE_RAY_TRACING_NO_NULL_CLOSEST_HIT_SHADERS_BIT_KHR",
+        ),
+        (
+            Self::RAY_TRACING_NO_NULL_MISS_SHADERS_KHR.0,
+            "RAY_TRACING_NO_NULL_MISS_SHADERS_KHR",
+            "VK_PIPELINE_CREATE_RAY_TRACING_NO_NULL_MISS_SHADERS_BIT_KHR",
+        ),
+        (
+            Self::RAY_TRACING_NO_NULL_INTERSECTION_SHADERS_KHR.0,
+            "RAY_TRACING_NO_NULL_INTERSECTION_SHADERS_KHR",
+            "VK_PIPELINE_CREATE_RAY_TRACING_NO_NULL_INTERSECTION_SHADERS_BIT_KHR",
+        ),
+        (
+            Self::RAY_TRACING_SKIP_TRIANGLES_KHR.0,
+            "RAY_TRACING_SKIP_TRIANGLES_KHR",
+            "VK_PIPELINE_CREATE_RAY_TRACING_SKIP_TRIANGLES_BIT_KHR",
+        ),
+        (
+            Self::RAY_TRACING_SKIP_AABBS_KHR.0,
+            "RAY_TRACING_SKIP_AABBS_KHR",
+            "VK_PIPELINE_CREATE_RAY_TRACING_SKIP_AABBS_BIT_KHR",
+        ),
+        (
+            Self::RAY_TRACING_SHADER_GROUP_HANDLE_CAPTURE_REPLAY_KHR.0,
+            "RAY_TRACING_SHADER_GROUP_HANDLE_CAPTURE_REPLAY_KHR",
+            "VK_PIPELINE_CREATE_RAY_TRACING_SHADER_GROUP_HANDLE_CAPTURE_REPLAY_BIT_KHR",
+        ),
+        (
+            Self::DEFER_COMPILE_NV.0,
+            "DEFER_COMPILE_NV",
+            "VK_PIPELINE_CREATE_DEFER_COMPILE_BIT_NV",
+        ),
+        (
+            Self::CAPTURE_STATISTICS_KHR.0,
+            "CAPTURE_STATISTICS_KHR",
+            "VK_PIPELINE_CREATE_CAPTURE_STATISTICS_BIT_KHR",
+        ),
+        (
+            Self::CAPTURE_INTERNAL_REPRESENTATIONS_KHR.0,
+            "CAPTURE_INTERNAL_REPRESENTATIONS_KHR",
+            "VK_PIPELINE_CREATE_CAPTURE_INTERNAL_REPRESENTATIONS_BIT_KHR",
+        ),
+        (
+            Self::INDIRECT_BINDABLE_NV.0,
+            "INDIRECT_BINDABLE_NV",
+            "VK_PIPELINE_CREATE_INDIRECT_BINDABLE_BIT_NV",
+        ),
+        (
+            Self::LIBRARY_KHR.0,
+            "LIBRARY_KHR",
+            "VK_PIPELINE_CREATE_LIBRARY_BIT_KHR",
+        ),
+        (
+            Self::DESCRIPTOR_BUFFER_EXT.0,
+            "DESCRIPTOR_BUFFER_EXT",
+            "VK_PIPELINE_CREATE_DESCRIPTOR_BUFFER_BIT_EXT",
+        ),
+        (
+            Self::RETAIN_LINK_TIME_OPTIMIZATION_INFO_EXT.0,
+            "RETAIN_LINK_TIME_OPTIMIZATION_INFO_EXT",
+            "VK_PIPELINE_CREATE_RETAIN_LINK_TIME_OPTIMIZATION_INFO_BIT_EXT",
+        ),
+        (
+            Self::LINK_TIME_OPTIMIZATION_EXT.0,
+            "LINK_TIME_OPTIMIZATION_EXT",
+            "VK_PIPELINE_CREATE_LINK_TIME_OPTIMIZATION_BIT_EXT",
+        ),
+        (
+            Self::RAY_TRACING_ALLOW_MOTION_NV.0,
+            "RAY_TRACING_ALLOW_MOTION_NV",
+            "VK_PIPELINE_CREATE_RAY_TRACING_ALLOW_MOTION_BIT_NV",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_FEEDBACK_LOOP_EXT.0,
+            "COLOR_ATTACHMENT_FEEDBACK_LOOP_EXT",
+            "VK_PIPELINE_CREATE_COLOR_ATTACHMENT_FEEDBACK_LOOP_BIT_EXT",
+        ),
+        (
+            Self::DEPTH_STENCIL_ATTACHMENT_FEEDBACK_LOOP_EXT.0,
+            "DEPTH_STENCIL_ATTACHMENT_FEEDBACK_LOOP_EXT",
+            "VK_PIPELINE_CREATE_DEPTH_STENCIL_ATTACHMENT_FEEDBACK_LOOP_BIT_EXT",
+        ),
+        (
+            Self::RAY_TRACING_OPACITY_MICROMAP_EXT.0,
+            "RAY_TRACING_OPACITY_MICROMAP_EXT",
+            "VK_PIPELINE_CREATE_RAY_TRACING_OPACITY_MICROMAP_BIT_EXT",
+        ),
+        (
+            Self::RAY_TRACING_DISPLACEMENT_MICROMAP_NV.0,
+            "RAY_TRACING_DISPLACEMENT_MICROMAP_NV",
+            "VK_PIPELINE_CREATE_RAY_TRACING_DISPLACEMENT_MICROMAP_BIT_NV",
+        ),
+        (
+            Self::NO_PROTECTED_ACCESS_EXT.0,
+            "NO_PROTECTED_ACCESS_EXT",
+            "VK_PIPELINE_CREATE_NO_PROTECTED_ACCESS_BIT_EXT",
+        ),
+        (
+            Self::PROTECTED_ACCESS_ONLY_EXT.0,
+            "PROTECTED_ACCESS_ONLY_EXT",
+            "VK_PIPELINE_CREATE_PROTECTED_ACCESS_ONLY_BIT_EXT",
+        ),
+        (
+            Self::VIEW_INDEX_FROM_DEVICE_INDEX.0,
+            "VIEW_INDEX_FROM_DEVICE_INDEX",
+            "VK_PIPELINE_CREATE_VIEW_INDEX_FROM_DEVICE_INDEX_BIT",
+        ),
+        (
+            Self::DISPATCH_BASE.0,
+            "DISPATCH_BASE",
+            "VK_PIPELINE_CREATE_DISPATCH_BASE_BIT",
+        ),
+        (
+            Self::FAIL_ON_PIPELINE_COMPILE_REQUIRED.0,
+            "FAIL_ON_PIPELINE_COMPILE_REQUIRED",
+            "VK_PIPELINE_CREATE_FAIL_ON_PIPELINE_COMPILE_REQUIRED_BIT",
+        ),
+        (
+            Self::EARLY_RETURN_ON_FAILURE.0,
+            "EARLY_RETURN_ON_FAILURE",
+            "VK_PIPELINE_CREATE_EARLY_RETURN_ON_FAILURE_BIT",
+        ),
+        (
+            Self::VIEW_INDEX_FROM_DEVICE_INDEX_KHR.0,
+            "VIEW_INDEX_FROM_DEVICE_INDEX_KHR",
+            "VK_PIPELINE_CREATE_VIEW_INDEX_FROM_DEVICE_INDEX_BIT_KHR",
+        ),
+        (
+            Self::FAIL_ON_PIPELINE_COMPILE_REQUIRED_EXT.0,
+            "FAIL_ON_PIPELINE_COMPILE_REQUIRED_EXT",
+            "VK_PIPELINE_CREATE_FAIL_ON_PIPELINE_COMPILE_REQUIRED_BIT_EXT",
+        ),
+        (
+            Self::EARLY_RETURN_ON_FAILURE_EXT.0,
+            "EARLY_RETURN_ON_FAILURE_EXT",
+            "VK_PIPELINE_CREATE_EARLY_RETURN_ON_FAILURE_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for PipelineCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineCreationFeedbackFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::VALID.0,
+            "VALID",
+            "VK_PIPELINE_CREATION_FEEDBACK_VALID_BIT",
+        ),
+        (
+            Self::APPLICATION_PIPELINE_CACHE_HIT.0,
+            "APPLICATION_PIPELINE_CACHE_HIT",
+            "VK_PIPELINE_CREATION_FEEDBACK_APPLICATION_PIPELINE_CACHE_HIT_BIT",
+        ),
+        (
+            Self::BASE_PIPELINE_ACCELERATION.0,
+            "BASE_PIPELINE_ACCELERATION",
+            "VK_PIPELINE_CREATION_FEEDBACK_BASE_PIPELINE_ACCELERATION_BIT",
+        ),
+        (
+            Self::VALID_EXT.0,
+            "VALID_EXT",
+            "VK_PIPELINE_CREATION_FEEDBACK_VALID_BIT_EXT",
+        ),
+        (
+            Self::APPLICATION_PIPELINE_CACHE_HIT_EXT.0,
+            "APPLICATION_PIPELINE_CACHE_HIT_EXT",
+            "VK_PIPELINE_CREATION_FEEDBACK_APPLICATION_PIPELINE_CACHE_HIT_BIT_EXT",
+        ),
+        (
+            Self::BASE_PIPELINE_ACCELERATION_EXT.0,
+            "BASE_PIPELINE_ACCELERATION_EXT",
+            "VK_PIPELINE_CREATION_FEEDBACK_BASE_PIPELINE_ACCELERATION_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for PipelineCreationFeedbackFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineCreationFeedbackFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineCreationFeedbackFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineDepthStencilStateCreateFlags {
+    pub (crate) const KNOWN : Known < Flags > = & [(Self :: RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_EXT . 0 , "RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_EXT" , "VK_PIPELINE_DEPTH_STENCIL_STATE_CREATE_RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_BIT_EXT") , (Self :: RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_EXT . 0 , "RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_EXT" , "VK_PIPELINE_DEPTH_STENCIL_STATE_CREATE_RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_BIT_EXT") , (Self :: RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_ARM . 0 , "RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_ARM" , "VK_PIPELINE_DEPTH_STENCIL_STATE_CREATE_RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_BIT_ARM") , (Self :: RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_ARM . 0 , "RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_ARM" , "VK_PIPELINE_DEPTH_STENCIL_STATE_CREATE_RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_BIT_ARM")] ;
+}
+impl FromStr for PipelineDepthStencilStateCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineDepthStencilStateCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineDepthStencilStateCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineDiscardRectangleStateCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineDiscardRectangleStateCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags(
+            "PipelineDiscardRectangleStateCreateFlagsEXT",
+            Self::KNOWN,
+            s,
+        )
+        .map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineDiscardRectangleStateCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineDynamicStateCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineDynamicStateCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineDynamicStateCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineDynamicStateCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineInputAssemblyStateCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineInputAssemblyStateCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineInputAssemblyStateCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineInputAssemblyStateCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineLayoutCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::INDEPENDENT_SETS_EXT.0,
+        "INDEPENDENT_SETS_EXT",
+        "VK_PIPELINE_LAYOUT_CREATE_INDEPENDENT_SETS_BIT_EXT",
+    )];
+}
+impl FromStr for PipelineLayoutCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineLayoutCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineLayoutCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineMultisampleStateCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineMultisampleStateCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineMultisampleStateCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineMultisampleStateCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineRasterizationConservativeStateCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineRasterizationConservativeStateCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags(
+            "PipelineRasterizationConservativeStateCreateFlagsEXT",
+            Self::KNOWN,
+            s,
+        )
+        .map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineRasterizationConservativeStateCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineRasterizationDepthClipStateCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineRasterizationDepthClipStateCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags(
+            "PipelineRasterizationDepthClipStateCreateFlagsEXT",
+            Self::KNOWN,
+            s,
+        )
+        .map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineRasterizationDepthClipStateCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineRasterizationStateCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineRasterizationStateCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineRasterizationStateCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineRasterizationStateCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineRasterizationStateStreamCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineRasterizationStateStreamCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags(
+            "PipelineRasterizationStateStreamCreateFlagsEXT",
+            Self::KNOWN,
+            s,
+        )
+        .map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineRasterizationStateStreamCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineShaderStageCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::ALLOW_VARYING_SUBGROUP_SIZE.0,
+            "ALLOW_VARYING_SUBGROUP_SIZE",
+            "VK_PIPELINE_SHADER_STAGE_CREATE_ALLOW_VARYING_SUBGROUP_SIZE_BIT",
+        ),
+        (
+            Self::REQUIRE_FULL_SUBGROUPS.0,
+            "REQUIRE_FULL_SUBGROUPS",
+            "VK_PIPELINE_SHADER_STAGE_CREATE_REQUIRE_FULL_SUBGROUPS_BIT",
+        ),
+        (
+            Self::ALLOW_VARYING_SUBGROUP_SIZE_EXT.0,
+            "ALLOW_VARYING_SUBGROUP_SIZE_EXT",
+            "VK_PIPELINE_SHADER_STAGE_CREATE_ALLOW_VARYING_SUBGROUP_SIZE_BIT_EXT",
+        ),
+        (
+            Self::REQUIRE_FULL_SUBGROUPS_EXT.0,
+            "REQUIRE_FULL_SUBGROUPS_EXT",
+            "VK_PIPELINE_SHADER_STAGE_CREATE_REQUIRE_FULL_SUBGROUPS_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for PipelineShaderStageCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineShaderStageCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineShaderStageCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineStageFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::TOP_OF_PIPE.0,
+            "TOP_OF_PIPE",
+            "VK_PIPELINE_STAGE_TOP_OF_PIPE_BIT",
+        ),
+        (
+            Self::DRAW_INDIRECT.0,
+            "DRAW_INDIRECT",
+            "VK_PIPELINE_STAGE_DRAW_INDIRECT_BIT",
+        ),
+        (
+            Self::VERTEX_INPUT.0,
+            "VERTEX_INPUT",
+            "VK_PIPELINE_STAGE_VERTEX_INPUT_BIT",
+        ),
+        (
+            Self::VERTEX_SHADER.0,
+            "VERTEX_SHADER",
+            "VK_PIPELINE_STAGE_VERTEX_SHADER_BIT",
+        ),
+        (
+            Self::TESSELLATION_CONTROL_SHADER.0,
+            "TESSELLATION_CONTROL_SHADER",
+            "VK_PIPELINE_STAGE_TESSELLATION_CONTROL_SHADER_BIT",
+        ),
+        (
+            Self::TESSELLATION_EVALUATION_SHADER.0,
+            "TESSELLATION_EVALUATION_SHADER",
+            "VK_PIPELINE_STAGE_TESSELLATION_EVALUATION_SHADER_BIT",
+        ),
+        (
+            Self::GEOMETRY_SHADER.0,
+            "GEOMETRY_SHADER",
+            "VK_PIPELINE_STAGE_GEOMETRY_SHADER_BIT",
+        ),
+        (
+            Self::FRAGMENT_SHADER.0,
+            "FRAGMENT_SHADER",
+            "VK_PIPELINE_STAGE_FRAGMENT_SHADER_BIT",
+        ),
+        (
+            Self::EARLY_FRAGMENT_TESTS.0,
+            "EARLY_FRAGMENT_TESTS",
+            "VK_PIPELINE_STAGE_EARLY_FRAGMENT_TESTS_BIT",
+        ),
+        (
+            Self::LATE_FRAGMENT_TESTS.0,
+            "LATE_FRAGMENT_TESTS",
+            "VK_PIPELINE_STAGE_LATE_FRAGMENT_TESTS_BIT",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_OUTPUT.0,
+            "COLOR_ATTACHMENT_OUTPUT",
+            "VK_PIPELINE_STAGE_COLOR_ATTACHMENT_OUTPUT_BIT",
+        ),
+        (
+            Self::COMPUTE_SHADER.0,
+            "COMPUTE_SHADER",
+            "VK_PIPELINE_STAGE_COMPUTE_SHADER_BIT",
+        ),
+        (
+            Self::TRANSFER.0,
+            "TRANSFER",
+            "VK_PIPELINE_STAGE_TRANSFER_BIT",
+        ),
+        (
+            Self::BOTTOM_OF_PIPE.0,
+            "BOTTOM_OF_PIPE",
+            "VK_PIPELINE_STAGE_BOTTOM_OF_PIPE_BIT",
+        ),
+        (Self::HOST.0, "HOST", "VK_PIPELINE_STAGE_HOST_BIT"),
+        (
+            Self::ALL_GRAPHICS.0,
+            "ALL_GRAPHICS",
+            "VK_PIPELINE_STAGE_ALL_GRAPHICS_BIT",
+        ),
+        (
+            Self::ALL_COMMANDS.0,
+            "ALL_COMMANDS",
+            "VK_PIPELINE_STAGE_ALL_COMMANDS_BIT",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_EXT.0,
+            "TRANSFORM_FEEDBACK_EXT",
+            "VK_PIPELINE_STAGE_TRANSFORM_FEEDBACK_BIT_EXT",
+        ),
+        (
+            Self::CONDITIONAL_RENDERING_EXT.0,
+            "CONDITIONAL_RENDERING_EXT",
+            "VK_PIPELINE_STAGE_CONDITIONAL_RENDERING_BIT_EXT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_BUILD_KHR.0,
+            "ACCELERATION_STRUCTURE_BUILD_KHR",
+            "VK_PIPELINE_STAGE_ACCELERATION_STRUCTURE_BUILD_BIT_KHR",
+        ),
+        (
+            Self::RAY_TRACING_SHADER_KHR.0,
+            "RAY_TRACING_SHADER_KHR",
+            "VK_PIPELINE_STAGE_RAY_TRACING_SHADER_BIT_KHR",
+        ),
+        (
+            Self::FRAGMENT_DENSITY_PROCESS_EXT.0,
+            "FRAGMENT_DENSITY_PROCESS_EXT",
+            "VK_PIPELINE_STAGE_FRAGMENT_DENSITY_PROCESS_BIT_EXT",
+        ),
+        (
+            Self::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.0,
+            "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR",
+            "VK_PIPELINE_STAGE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR",
+        ),
+        (
+            Self::COMMAND_PREPROCESS_NV.0,
+            "COMMAND_PREPROCESS_NV",
+            "VK_PIPELINE_STAGE_COMMAND_PREPROCESS_BIT_NV",
+        ),
+        (
+            Self::TASK_SHADER_EXT.0,
+            "TASK_SHADER_EXT",
+            "VK_PIPELINE_STAGE_TASK_SHADER_BIT_EXT",
+        ),
+        (
+            Self::MESH_SHADER_EXT.0,
+            "MESH_SHADER_EXT",
+            "VK_PIPELINE_STAGE_MESH_SHADER_BIT_EXT",
+        ),
+        (Self::NONE.0, "NONE", "VK_PIPELINE_STAGE_NONE"),
+        (
+            Self::SHADING_RATE_IMAGE_NV.0,
+            "SHADING_RATE_IMAGE_NV",
+            "VK_PIPELINE_STAGE_SHADING_RATE_IMAGE_BIT_NV",
+        ),
+        (
+            Self::RAY_TRACING_SHADER_NV.0,
+            "RAY_TRACING_SHADER_NV",
+            "VK_PIPELINE_STAGE_RAY_TRACING_SHADER_BIT_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_BUILD_NV.0,
+            "ACCELERATION_STRUCTURE_BUILD_NV",
+            "VK_PIPELINE_STAGE_ACCELERATION_STRUCTURE_BUILD_BIT_NV",
+        ),
+        (
+            Self::TASK_SHADER_NV.0,
+            "TASK_SHADER_NV",
+            "VK_PIPELINE_STAGE_TASK_SHADER_BIT_NV",
+        ),
+        (
+            Self::MESH_SHADER_NV.0,
+            "MESH_SHADER_NV",
+            "VK_PIPELINE_STAGE_MESH_SHADER_BIT_NV",
+        ),
+        (Self::NONE_KHR.0, "NONE_KHR", "VK_PIPELINE_STAGE_NONE_KHR"),
+    ];
+}
+impl FromStr for PipelineStageFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineStageFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineStageFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineStageFlags2 {
+    pub(crate) const KNOWN: Known<Flags64> = &[
+        (Self::NONE.0, "NONE", "VK_PIPELINE_STAGE_2_NONE"),
+        (
+            Self::TOP_OF_PIPE.0,
+            "TOP_OF_PIPE",
+            "VK_PIPELINE_STAGE_2_TOP_OF_PIPE_BIT",
+        ),
+        (
+            Self::DRAW_INDIRECT.0,
+            "DRAW_INDIRECT",
+            "VK_PIPELINE_STAGE_2_DRAW_INDIRECT_BIT",
+        ),
+        (
+            Self::VERTEX_INPUT.0,
+            "VERTEX_INPUT",
+            "VK_PIPELINE_STAGE_2_VERTEX_INPUT_BIT",
+        ),
+        (
+            Self::VERTEX_SHADER.0,
+            "VERTEX_SHADER",
+            "VK_PIPELINE_STAGE_2_VERTEX_SHADER_BIT",
+        ),
+        (
+            Self::TESSELLATION_CONTROL_SHADER.0,
+            "TESSELLATION_CONTROL_SHADER",
+            "VK_PIPELINE_STAGE_2_TESSELLATION_CONTROL_SHADER_BIT",
+        ),
+        (
+            Self::TESSELLATION_EVALUATION_SHADER.0,
+            "TESSELLATION_EVALUATION_SHADER",
+            "VK_PIPELINE_STAGE_2_TESSELLATION_EVALUATION_SHADER_BIT",
+        ),
+        (
+            Self::GEOMETRY_SHADER.0,
+            "GEOMETRY_SHADER",
+            "VK_PIPELINE_STAGE_2_GEOMETRY_SHADER_BIT",
+        ),
+        (
+            Self::FRAGMENT_SHADER.0,
+            "FRAGMENT_SHADER",
+            "VK_PIPELINE_STAGE_2_FRAGMENT_SHADER_BIT",
+        ),
+        (
+            Self::EARLY_FRAGMENT_TESTS.0,
+            "EARLY_FRAGMENT_TESTS",
+            "VK_PIPELINE_STAGE_2_EARLY_FRAGMENT_TESTS_BIT",
+        ),
+        (
+            Self::LATE_FRAGMENT_TESTS.0,
+            "LATE_FRAGMENT_TESTS",
+            "VK_PIPELINE_STAGE_2_LATE_FRAGMENT_TESTS_BIT",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_OUTPUT.0,
+            "COLOR_ATTACHMENT_OUTPUT",
+            "VK_PIPELINE_STAGE_2_COLOR_ATTACHMENT_OUTPUT_BIT",
+        ),
+        (
+            Self::COMPUTE_SHADER.0,
+            "COMPUTE_SHADER",
+            "VK_PIPELINE_STAGE_2_COMPUTE_SHADER_BIT",
+        ),
+        (
+            Self::ALL_TRANSFER.0,
+            "ALL_TRANSFER",
+            "VK_PIPELINE_STAGE_2_ALL_TRANSFER_BIT",
+        ),
+        (
+            Self::BOTTOM_OF_PIPE.0,
+            "BOTTOM_OF_PIPE",
+            "VK_PIPELINE_STAGE_2_BOTTOM_OF_PIPE_BIT",
+        ),
+        (Self::HOST.0, "HOST", "VK_PIPELINE_STAGE_2_HOST_BIT"),
+        (
+            Self::ALL_GRAPHICS.0,
+            "ALL_GRAPHICS",
+            "VK_PIPELINE_STAGE_2_ALL_GRAPHICS_BIT",
+        ),
+        (
+            Self::ALL_COMMANDS.0,
+            "ALL_COMMANDS",
+            "VK_PIPELINE_STAGE_2_ALL_COMMANDS_BIT",
+        ),
+        (Self::COPY.0, "COPY", "VK_PIPELINE_STAGE_2_COPY_BIT"),
+        (
+            Self::RESOLVE.0,
+            "RESOLVE",
+            "VK_PIPELINE_STAGE_2_RESOLVE_BIT",
+        ),
+        (Self::BLIT.0, "BLIT", "VK_PIPELINE_STAGE_2_BLIT_BIT"),
+        (Self::CLEAR.0, "CLEAR", "VK_PIPELINE_STAGE_2_CLEAR_BIT"),
+        (
+            Self::INDEX_INPUT.0,
+            "INDEX_INPUT",
+            "VK_PIPELINE_STAGE_2_INDEX_INPUT_BIT",
+        ),
+        (
+            Self::VERTEX_ATTRIBUTE_INPUT.0,
+            "VERTEX_ATTRIBUTE_INPUT",
+            "VK_PIPELINE_STAGE_2_VERTEX_ATTRIBUTE_INPUT_BIT",
+        ),
+        (
+            Self::PRE_RASTERIZATION_SHADERS.0,
+            "PRE_RASTERIZATION_SHADERS",
+            "VK_PIPELINE_STAGE_2_PRE_RASTERIZATION_SHADERS_BIT",
+        ),
+        (
+            Self::VIDEO_DECODE_KHR.0,
+            "VIDEO_DECODE_KHR",
+            "VK_PIPELINE_STAGE_2_VIDEO_DECODE_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_KHR.0,
+            "VIDEO_ENCODE_KHR",
+            "VK_PIPELINE_STAGE_2_VIDEO_ENCODE_BIT_KHR",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_EXT.0,
+            "TRANSFORM_FEEDBACK_EXT",
+            "VK_PIPELINE_STAGE_2_TRANSFORM_FEEDBACK_BIT_EXT",
+        ),
+        (
+            Self::CONDITIONAL_RENDERING_EXT.0,
+            "CONDITIONAL_RENDERING_EXT",
+            "VK_PIPELINE_STAGE_2_CONDITIONAL_RENDERING_BIT_EXT",
+        ),
+        (
+            Self::COMMAND_PREPROCESS_NV.0,
+            "COMMAND_PREPROCESS_NV",
+            "VK_PIPELINE_STAGE_2_COMMAND_PREPROCESS_BIT_NV",
+        ),
+        (
+            Self::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.0,
+            "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR",
+            "VK_PIPELINE_STAGE_2_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_BUILD_KHR.0,
+            "ACCELERATION_STRUCTURE_BUILD_KHR",
+            "VK_PIPELINE_STAGE_2_ACCELERATION_STRUCTURE_BUILD_BIT_KHR",
+        ),
+        (
+            Self::RAY_TRACING_SHADER_KHR.0,
+            "RAY_TRACING_SHADER_KHR",
+            "VK_PIPELINE_STAGE_2_RAY_TRACING_SHADER_BIT_KHR",
+        ),
+        (
+            Self::FRAGMENT_DENSITY_PROCESS_EXT.0,
+            "FRAGMENT_DENSITY_PROCESS_EXT",
+            "VK_PIPELINE_STAGE_2_FRAGMENT_DENSITY_PROCESS_BIT_EXT",
+        ),
+        (
+            Self::TASK_SHADER_EXT.0,
+            "TASK_SHADER_EXT",
+            "VK_PIPELINE_STAGE_2_TASK_SHADER_BIT_EXT",
+        ),
+        (
+            Self::MESH_SHADER_EXT.0,
+            "MESH_SHADER_EXT",
+            "VK_PIPELINE_STAGE_2_MESH_SHADER_BIT_EXT",
+        ),
+        (
+            Self::SUBPASS_SHADING_HUAWEI.0,
+            "SUBPASS_SHADING_HUAWEI",
+            "VK_PIPELINE_STAGE_2_SUBPASS_SHADING_BIT_HUAWEI",
+        ),
+        (
+            Self::INVOCATION_MASK_HUAWEI.0,
+            "INVOCATION_MASK_HUAWEI",
+            "VK_PIPELINE_STAGE_2_INVOCATION_MASK_BIT_HUAWEI",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_COPY_KHR.0,
+            "ACCELERATION_STRUCTURE_COPY_KHR",
+            "VK_PIPELINE_STAGE_2_ACCELERATION_STRUCTURE_COPY_BIT_KHR",
+        ),
+        (
+            Self::MICROMAP_BUILD_EXT.0,
+            "MICROMAP_BUILD_EXT",
+            "VK_PIPELINE_STAGE_2_MICROMAP_BUILD_BIT_EXT",
+        ),
+        (
+            Self::CLUSTER_CULLING_SHADER_HUAWEI.0,
+            "CLUSTER_CULLING_SHADER_HUAWEI",
+            "VK_PIPELINE_STAGE_2_CLUSTER_CULLING_SHADER_BIT_HUAWEI",
+        ),
+        (
+            Self::OPTICAL_FLOW_NV.0,
+            "OPTICAL_FLOW_NV",
+            "VK_PIPELINE_STAGE_2_OPTICAL_FLOW_BIT_NV",
+        ),
+        (Self::NONE_KHR.0, "NONE_KHR", "VK_PIPELINE_STAGE_2_NONE_KHR"),
+        (
+            Self::TOP_OF_PIPE_KHR.0,
+            "TOP_OF_PIPE_KHR",
+            "VK_PIPELINE_STAGE_2_TOP_OF_PIPE_BIT_KHR",
+        ),
+        (
+            Self::DRAW_INDIRECT_KHR.0,
+            "DRAW_INDIRECT_KHR",
+            "VK_PIPELINE_STAGE_2_DRAW_INDIRECT_BIT_KHR",
+        ),
+        (
+            Self::VERTEX_INPUT_KHR.0,
+            "VERTEX_INPUT_KHR",
+            "VK_PIPELINE_STAGE_2_VERTEX_INPUT_BIT_KHR",
+        ),
+        (
+            Self::VERTEX_SHADER_KHR.0,
+            "VERTEX_SHADER_KHR",
+            "VK_PIPELINE_STAGE_2_VERTEX_SHADER_BIT_KHR",
+        ),
+        (
+            Self::TESSELLATION_CONTROL_SHADER_KHR.0,
+            "TESSELLATION_CONTROL_SHADER_KHR",
+            "VK_PIPELINE_STAGE_2_TESSELLATION_CONTROL_SHADER_BIT_KHR",
+        ),
+        (
+            Self::TESSELLATION_EVALUATION_SHADER_KHR.0,
+            "TESSELLATION_EVALUATION_SHADER_KHR",
+            "VK_PIPELINE_STAGE_2_TESSELLATION_EVALUATION_SHADER_BIT_KHR",
+        ),
+        (
+            Self::GEOMETRY_SHADER_KHR.0,
+            "GEOMETRY_SHADER_KHR",
+            "VK_PIPELINE_STAGE_2_GEOMETRY_SHADER_BIT_KHR",
+        ),
+        (
+            Self::FRAGMENT_SHADER_KHR.0,
+            "FRAGMENT_SHADER_KHR",
+            "VK_PIPELINE_STAGE_2_FRAGMENT_SHADER_BIT_KHR",
+        ),
+        (
+            Self::EARLY_FRAGMENT_TESTS_KHR.0,
+            "EARLY_FRAGMENT_TESTS_KHR",
+            "VK_PIPELINE_STAGE_2_EARLY_FRAGMENT_TESTS_BIT_KHR",
+        ),
+        (
+            Self::LATE_FRAGMENT_TESTS_KHR.0,
+            "LATE_FRAGMENT_TESTS_KHR",
+            "VK_PIPELINE_STAGE_2_LATE_FRAGMENT_TESTS_BIT_KHR",
+        ),
+        (
+            Self::COLOR_ATTACHMENT_OUTPUT_KHR.0,
+            "COLOR_ATTACHMENT_OUTPUT_KHR",
+            "VK_PIPELINE_STAGE_2_COLOR_ATTACHMENT_OUTPUT_BIT_KHR",
+        ),
+        (
+            Self::COMPUTE_SHADER_KHR.0,
+            "COMPUTE_SHADER_KHR",
+            "VK_PIPELINE_STAGE_2_COMPUTE_SHADER_BIT_KHR",
+        ),
+        (
+            Self::ALL_TRANSFER_KHR.0,
+            "ALL_TRANSFER_KHR",
+            "VK_PIPELINE_STAGE_2_ALL_TRANSFER_BIT_KHR",
+        ),
+        (
+            Self::TRANSFER.0,
+            "TRANSFER",
+            "VK_PIPELINE_STAGE_2_TRANSFER_BIT",
+        ),
+        (
+            Self::TRANSFER_KHR.0,
+            "TRANSFER_KHR",
+            "VK_PIPELINE_STAGE_2_TRANSFER_BIT_KHR",
+        ),
+        (
+            Self::BOTTOM_OF_PIPE_KHR.0,
+            "BOTTOM_OF_PIPE_KHR",
+            "VK_PIPELINE_STAGE_2_BOTTOM_OF_PIPE_BIT_KHR",
+        ),
+        (
+            Self::HOST_KHR.0,
+            "HOST_KHR",
+            "VK_PIPELINE_STAGE_2_HOST_BIT_KHR",
+        ),
+        (
+            Self::ALL_GRAPHICS_KHR.0,
+            "ALL_GRAPHICS_KHR",
+            "VK_PIPELINE_STAGE_2_ALL_GRAPHICS_BIT_KHR",
+        ),
+        (
+            Self::ALL_COMMANDS_KHR.0,
+            "ALL_COMMANDS_KHR",
+            "VK_PIPELINE_STAGE_2_ALL_COMMANDS_BIT_KHR",
+        ),
+        (
+            Self::COPY_KHR.0,
+            "COPY_KHR",
+            "VK_PIPELINE_STAGE_2_COPY_BIT_KHR",
+        ),
+        (
+            Self::RESOLVE_KHR.0,
+            "RESOLVE_KHR",
+            "VK_PIPELINE_STAGE_2_RESOLVE_BIT_KHR",
+        ),
+        (
+            Self::BLIT_KHR.0,
+            "BLIT_KHR",
+            "VK_PIPELINE_STAGE_2_BLIT_BIT_KHR",
+        ),
+        (
+            Self::CLEAR_KHR.0,
+            "CLEAR_KHR",
+            "VK_PIPELINE_STAGE_2_CLEAR_BIT_KHR",
+        ),
+        (
+            Self::INDEX_INPUT_KHR.0,
+            "INDEX_INPUT_KHR",
+            "VK_PIPELINE_STAGE_2_INDEX_INPUT_BIT_KHR",
+        ),
+        (
+            Self::VERTEX_ATTRIBUTE_INPUT_KHR.0,
+            "VERTEX_ATTRIBUTE_INPUT_KHR",
+            "VK_PIPELINE_STAGE_2_VERTEX_ATTRIBUTE_INPUT_BIT_KHR",
+        ),
+        (
+            Self::PRE_RASTERIZATION_SHADERS_KHR.0,
+            "PRE_RASTERIZATION_SHADERS_KHR",
+            "VK_PIPELINE_STAGE_2_PRE_RASTERIZATION_SHADERS_BIT_KHR",
+        ),
+        (
+            Self::SHADING_RATE_IMAGE_NV.0,
+            "SHADING_RATE_IMAGE_NV",
+            "VK_PIPELINE_STAGE_2_SHADING_RATE_IMAGE_BIT_NV",
+        ),
+        (
+            Self::RAY_TRACING_SHADER_NV.0,
+            "RAY_TRACING_SHADER_NV",
+            "VK_PIPELINE_STAGE_2_RAY_TRACING_SHADER_BIT_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_BUILD_NV.0,
+            "ACCELERATION_STRUCTURE_BUILD_NV",
+            "VK_PIPELINE_STAGE_2_ACCELERATION_STRUCTURE_BUILD_BIT_NV",
+        ),
+        (
+            Self::TASK_SHADER_NV.0,
+            "TASK_SHADER_NV",
+            "VK_PIPELINE_STAGE_2_TASK_SHADER_BIT_NV",
+        ),
+        (
+            Self::MESH_SHADER_NV.0,
+            "MESH_SHADER_NV",
+            "VK_PIPELINE_STAGE_2_MESH_SHADER_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for PipelineStageFlags2 {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineStageFlags2", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineStageFlags2 {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineTessellationStateCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineTessellationStateCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineTessellationStateCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineTessellationStateCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineVertexInputStateCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineVertexInputStateCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineVertexInputStateCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineVertexInputStateCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineViewportStateCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineViewportStateCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineViewportStateCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineViewportStateCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineViewportSwizzleStateCreateFlagsNV {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PipelineViewportSwizzleStateCreateFlagsNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PipelineViewportSwizzleStateCreateFlagsNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineViewportSwizzleStateCreateFlagsNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PresentGravityFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::MIN.0, "MIN", "VK_PRESENT_GRAVITY_MIN_BIT_EXT"),
+        (Self::MAX.0, "MAX", "VK_PRESENT_GRAVITY_MAX_BIT_EXT"),
+        (
+            Self::CENTERED.0,
+            "CENTERED",
+            "VK_PRESENT_GRAVITY_CENTERED_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for PresentGravityFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PresentGravityFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PresentGravityFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PresentScalingFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::ONE_TO_ONE.0,
+            "ONE_TO_ONE",
+            "VK_PRESENT_SCALING_ONE_TO_ONE_BIT_EXT",
+        ),
+        (
+            Self::ASPECT_RATIO_STRETCH.0,
+            "ASPECT_RATIO_STRETCH",
+            "VK_PRESENT_SCALING_ASPECT_RATIO_STRETCH_BIT_EXT",
+        ),
+        (
+            Self::STRETCH.0,
+            "STRETCH",
+            "VK_PRESENT_SCALING_STRETCH_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for PresentScalingFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PresentScalingFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PresentScalingFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PrivateDataSlotCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for PrivateDataSlotCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("PrivateDataSlotCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PrivateDataSlotCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl QueryControlFlags {
+    pub(crate) const KNOWN: Known<Flags> =
+        &[(Self::PRECISE.0, "PRECISE", "VK_QUERY_CONTROL_PRECISE_BIT")];
+}
+impl FromStr for QueryControlFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("QueryControlFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for QueryControlFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl QueryPipelineStatisticFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::INPUT_ASSEMBLY_VERTICES.0,
+            "INPUT_ASSEMBLY_VERTICES",
+            "VK_QUERY_PIPELINE_STATISTIC_INPUT_ASSEMBLY_VERTICES_BIT",
+        ),
+        (
+            Self::INPUT_ASSEMBLY_PRIMITIVES.0,
+            "INPUT_ASSEMBLY_PRIMITIVES",
+            "VK_QUERY_PIPELINE_STATISTIC_INPUT_ASSEMBLY_PRIMITIVES_BIT",
+        ),
+        (
+            Self::VERTEX_SHADER_INVOCATIONS.0,
+            "VERTEX_SHADER_INVOCATIONS",
+            "VK_QUERY_PIPELINE_STATISTIC_VERTEX_SHADER_INVOCATIONS_BIT",
+        ),
+        (
+            Self::GEOMETRY_SHADER_INVOCATIONS.0,
+            "GEOMETRY_SHADER_INVOCATIONS",
+            "VK_QUERY_PIPELINE_STATISTIC_GEOMETRY_SHADER_INVOCATIONS_BIT",
+        ),
+        (
+            Self::GEOMETRY_SHADER_PRIMITIVES.0,
+            "GEOMETRY_SHADER_PRIMITIVES",
+            "VK_QUERY_PIPELINE_STATISTIC_GEOMETRY_SHADER_PRIMITIVES_BIT",
+        ),
+        (
+            Self::CLIPPING_INVOCATIONS.0,
+            "CLIPPING_INVOCATIONS",
+            "VK_QUERY_PIPELINE_STATISTIC_CLIPPING_INVOCATIONS_BIT",
+        ),
+        (
+            Self::CLIPPING_PRIMITIVES.0,
+            "CLIPPING_PRIMITIVES",
+            "VK_QUERY_PIPELINE_STATISTIC_CLIPPING_PRIMITIVES_BIT",
+        ),
+        (
+            Self::FRAGMENT_SHADER_INVOCATIONS.0,
+            "FRAGMENT_SHADER_INVOCATIONS",
+            "VK_QUERY_PIPELINE_STATISTIC_FRAGMENT_SHADER_INVOCATIONS_BIT",
+        ),
+        (
+            Self::TESSELLATION_CONTROL_SHADER_PATCHES.0,
+            "TESSELLATION_CONTROL_SHADER_PATCHES",
+            "VK_QUERY_PIPELINE_STATISTIC_TESSELLATION_CONTROL_SHADER_PATCHES_BIT",
+        ),
+        (
+            Self::TESSELLATION_EVALUATION_SHADER_INVOCATIONS.0,
+            "TESSELLATION_EVALUATION_SHADER_INVOCATIONS",
+            "VK_QUERY_PIPELINE_STATISTIC_TESSELLATION_EVALUATION_SHADER_INVOCATIONS_BIT",
+        ),
+        (
+            Self::COMPUTE_SHADER_INVOCATIONS.0,
+            "COMPUTE_SHADER_INVOCATIONS",
+            "VK_QUERY_PIPELINE_STATISTIC_COMPUTE_SHADER_INVOCATIONS_BIT",
+        ),
+        (
+            Self::TASK_SHADER_INVOCATIONS_EXT.0,
+            "TASK_SHADER_INVOCATIONS_EXT",
+            "VK_QUERY_PIPELINE_STATISTIC_TASK_SHADER_INVOCATIONS_BIT_EXT",
+        ),
+        (
+            Self::MESH_SHADER_INVOCATIONS_EXT.0,
+            "MESH_SHADER_INVOCATIONS_EXT",
+            "VK_QUERY_PIPELINE_STATISTIC_MESH_SHADER_INVOCATIONS_BIT_EXT",
+        ),
+        (
+            Self::CLUSTER_CULLING_SHADER_INVOCATIONS_HUAWEI.0,
+            "CLUSTER_CULLING_SHADER_INVOCATIONS_HUAWEI",
+            "VK_QUERY_PIPELINE_STATISTIC_CLUSTER_CULLING_SHADER_INVOCATIONS_BIT_HUAWEI",
+        ),
+    ];
+}
+impl FromStr for QueryPipelineStatisticFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("QueryPipelineStatisticFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for QueryPipelineStatisticFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl QueryPoolCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for QueryPoolCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("QueryPoolCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for QueryPoolCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl QueryResultFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::TYPE_64.0, "TYPE_64", "VK_QUERY_RESULT_64_BIT"),
+        (Self::WAIT.0, "WAIT", "VK_QUERY_RESULT_WAIT_BIT"),
+        (
+            Self::WITH_AVAILABILITY.0,
+            "WITH_AVAILABILITY",
+            "VK_QUERY_RESULT_WITH_AVAILABILITY_BIT",
+        ),
+        (Self::PARTIAL.0, "PARTIAL", "VK_QUERY_RESULT_PARTIAL_BIT"),
+        (
+            Self::WITH_STATUS_KHR.0,
+            "WITH_STATUS_KHR",
+            "VK_QUERY_RESULT_WITH_STATUS_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for QueryResultFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("QueryResultFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for QueryResultFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl QueueFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::GRAPHICS.0, "GRAPHICS", "VK_QUEUE_GRAPHICS_BIT"),
+        (Self::COMPUTE.0, "COMPUTE", "VK_QUEUE_COMPUTE_BIT"),
+        (Self::TRANSFER.0, "TRANSFER", "VK_QUEUE_TRANSFER_BIT"),
+        (
+            Self::SPARSE_BINDING.0,
+            "SPARSE_BINDING",
+            "VK_QUEUE_SPARSE_BINDING_BIT",
+        ),
+        (
+            Self::VIDEO_DECODE_KHR.0,
+            "VIDEO_DECODE_KHR",
+            "VK_QUEUE_VIDEO_DECODE_BIT_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_KHR.0,
+            "VIDEO_ENCODE_KHR",
+            "VK_QUEUE_VIDEO_ENCODE_BIT_KHR",
+        ),
+        (
+            Self::OPTICAL_FLOW_NV.0,
+            "OPTICAL_FLOW_NV",
+            "VK_QUEUE_OPTICAL_FLOW_BIT_NV",
+        ),
+        (Self::PROTECTED.0, "PROTECTED", "VK_QUEUE_PROTECTED_BIT"),
+    ];
+}
+impl FromStr for QueueFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("QueueFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for QueueFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl RenderPassCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::TRANSFORM_QCOM.0,
+        "TRANSFORM_QCOM",
+        "VK_RENDER_PASS_CREATE_TRANSFORM_BIT_QCOM",
+    )];
+}
+impl FromStr for RenderPassCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("RenderPassCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for RenderPassCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl RenderingFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::CONTENTS_SECONDARY_COMMAND_BUFFERS.0,
+            "CONTENTS_SECONDARY_COMMAND_BUFFERS",
+            "VK_RENDERING_CONTENTS_SECONDARY_COMMAND_BUFFERS_BIT",
+        ),
+        (
+            Self::SUSPENDING.0,
+            "SUSPENDING",
+            "VK_RENDERING_SUSPENDING_BIT",
+        ),
+        (Self::RESUMING.0, "RESUMING", "VK_RENDERING_RESUMING_BIT"),
+        (
+            Self::ENABLE_LEGACY_DITHERING_EXT.0,
+            "ENABLE_LEGACY_DITHERING_EXT",
+            "VK_RENDERING_ENABLE_LEGACY_DITHERING_BIT_EXT",
+        ),
+        (
+            Self::CONTENTS_SECONDARY_COMMAND_BUFFERS_KHR.0,
+            "CONTENTS_SECONDARY_COMMAND_BUFFERS_KHR",
+            "VK_RENDERING_CONTENTS_SECONDARY_COMMAND_BUFFERS_BIT_KHR",
+        ),
+        (
+            Self::SUSPENDING_KHR.0,
+            "SUSPENDING_KHR",
+            "VK_RENDERING_SUSPENDING_BIT_KHR",
+        ),
+        (
+            Self::RESUMING_KHR.0,
+            "RESUMING_KHR",
+            "VK_RENDERING_RESUMING_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for RenderingFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("RenderingFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for RenderingFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ResolveModeFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::NONE.0, "NONE", "VK_RESOLVE_MODE_NONE"),
+        (
+            Self::SAMPLE_ZERO.0,
+            "SAMPLE_ZERO",
+            "VK_RESOLVE_MODE_SAMPLE_ZERO_BIT",
+        ),
+        (Self::AVERAGE.0, "AVERAGE", "VK_RESOLVE_MODE_AVERAGE_BIT"),
+        (Self::MIN.0, "MIN", "VK_RESOLVE_MODE_MIN_BIT"),
+        (Self::MAX.0, "MAX", "VK_RESOLVE_MODE_MAX_BIT"),
+        (Self::NONE_KHR.0, "NONE_KHR", "VK_RESOLVE_MODE_NONE_KHR"),
+        (
+            Self::SAMPLE_ZERO_KHR.0,
+            "SAMPLE_ZERO_KHR",
+            "VK_RESOLVE_MODE_SAMPLE_ZERO_BIT_KHR",
+        ),
+        (
+            Self::AVERAGE_KHR.0,
+            "AVERAGE_KHR",
+            "VK_RESOLVE_MODE_AVERAGE_BIT_KHR",
+        ),
+        (Self::MIN_KHR.0, "MIN_KHR", "VK_RESOLVE_MODE_MIN_BIT_KHR"),
+        (Self::MAX_KHR.0, "MAX_KHR", "VK_RESOLVE_MODE_MAX_BIT_KHR"),
+    ];
+}
+impl FromStr for ResolveModeFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ResolveModeFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ResolveModeFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SampleCountFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::TYPE_1.0, "TYPE_1", "VK_SAMPLE_COUNT_1_BIT"),
+        (Self::TYPE_2.0, "TYPE_2", "VK_SAMPLE_COUNT_2_BIT"),
+        (Self::TYPE_4.0, "TYPE_4", "VK_SAMPLE_COUNT_4_BIT"),
+        (Self::TYPE_8.0, "TYPE_8", "VK_SAMPLE_COUNT_8_BIT"),
+        (Self::TYPE_16.0, "TYPE_16", "VK_SAMPLE_COUNT_16_BIT"),
+        (Self::TYPE_32.0, "TYPE_32", "VK_SAMPLE_COUNT_32_BIT"),
+        (Self::TYPE_64.0, "TYPE_64", "VK_SAMPLE_COUNT_64_BIT"),
+    ];
+}
+impl FromStr for SampleCountFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SampleCountFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SampleCountFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SamplerCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::SUBSAMPLED_EXT.0,
+            "SUBSAMPLED_EXT",
+            "VK_SAMPLER_CREATE_SUBSAMPLED_BIT_EXT",
+        ),
+        (
+            Self::SUBSAMPLED_COARSE_RECONSTRUCTION_EXT.0,
+            "SUBSAMPLED_COARSE_RECONSTRUCTION_EXT",
+            "VK_SAMPLER_CREATE_SUBSAMPLED_COARSE_RECONSTRUCTION_BIT_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.0,
+            "DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT",
+            "VK_SAMPLER_CREATE_DESCRIPTOR_BUFFER_CAPTURE_REPLAY_BIT_EXT",
+        ),
+        (
+            Self::NON_SEAMLESS_CUBE_MAP_EXT.0,
+            "NON_SEAMLESS_CUBE_MAP_EXT",
+            "VK_SAMPLER_CREATE_NON_SEAMLESS_CUBE_MAP_BIT_EXT",
+        ),
+        (
+            Self::IMAGE_PROCESSING_QCOM.0,
+            "IMAGE_PROCESSING_QCOM",
+            "VK_SAMPLER_CREATE_IMAGE_PROCESSING_BIT_QCOM",
+        ),
+    ];
+}
+impl FromStr for SamplerCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SamplerCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SamplerCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ScreenSurfaceCreateFlagsQNX {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for ScreenSurfaceCreateFlagsQNX {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ScreenSurfaceCreateFlagsQNX", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ScreenSurfaceCreateFlagsQNX {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SemaphoreCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for SemaphoreCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SemaphoreCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SemaphoreCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SemaphoreImportFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::TEMPORARY.0,
+            "TEMPORARY",
+            "VK_SEMAPHORE_IMPORT_TEMPORARY_BIT",
+        ),
+        (
+            Self::TEMPORARY_KHR.0,
+            "TEMPORARY_KHR",
+            "VK_SEMAPHORE_IMPORT_TEMPORARY_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for SemaphoreImportFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SemaphoreImportFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SemaphoreImportFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SemaphoreWaitFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::ANY.0, "ANY", "VK_SEMAPHORE_WAIT_ANY_BIT"),
+        (Self::ANY_KHR.0, "ANY_KHR", "VK_SEMAPHORE_WAIT_ANY_BIT_KHR"),
+    ];
+}
+impl FromStr for SemaphoreWaitFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SemaphoreWaitFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SemaphoreWaitFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ShaderCorePropertiesFlagsAMD {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for ShaderCorePropertiesFlagsAMD {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ShaderCorePropertiesFlagsAMD", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ShaderCorePropertiesFlagsAMD {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ShaderCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::LINK_STAGE.0,
+            "LINK_STAGE",
+            "VK_SHADER_CREATE_LINK_STAGE_BIT_EXT",
+        ),
+        (
+            Self::ALLOW_VARYING_SUBGROUP_SIZE.0,
+            "ALLOW_VARYING_SUBGROUP_SIZE",
+            "VK_SHADER_CREATE_ALLOW_VARYING_SUBGROUP_SIZE_BIT_EXT",
+        ),
+        (
+            Self::REQUIRE_FULL_SUBGROUPS.0,
+            "REQUIRE_FULL_SUBGROUPS",
+            "VK_SHADER_CREATE_REQUIRE_FULL_SUBGROUPS_BIT_EXT",
+        ),
+        (
+            Self::NO_TASK_SHADER.0,
+            "NO_TASK_SHADER",
+            "VK_SHADER_CREATE_NO_TASK_SHADER_BIT_EXT",
+        ),
+        (
+            Self::DISPATCH_BASE.0,
+            "DISPATCH_BASE",
+            "VK_SHADER_CREATE_DISPATCH_BASE_BIT_EXT",
+        ),
+        (
+            Self::FRAGMENT_SHADING_RATE_ATTACHMENT.0,
+            "FRAGMENT_SHADING_RATE_ATTACHMENT",
+            "VK_SHADER_CREATE_FRAGMENT_SHADING_RATE_ATTACHMENT_BIT_EXT",
+        ),
+        (
+            Self::FRAGMENT_DENSITY_MAP_ATTACHMENT.0,
+            "FRAGMENT_DENSITY_MAP_ATTACHMENT",
+            "VK_SHADER_CREATE_FRAGMENT_DENSITY_MAP_ATTACHMENT_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for ShaderCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ShaderCreateFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ShaderCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ShaderModuleCreateFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for ShaderModuleCreateFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ShaderModuleCreateFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ShaderModuleCreateFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ShaderStageFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::VERTEX.0, "VERTEX", "VK_SHADER_STAGE_VERTEX_BIT"),
+        (
+            Self::TESSELLATION_CONTROL.0,
+            "TESSELLATION_CONTROL",
+            "VK_SHADER_STAGE_TESSELLATION_CONTROL_BIT",
+        ),
+        (
+            Self::TESSELLATION_EVALUATION.0,
+            "TESSELLATION_EVALUATION",
+            "VK_SHADER_STAGE_TESSELLATION_EVALUATION_BIT",
+        ),
+        (Self::GEOMETRY.0, "GEOMETRY", "VK_SHADER_STAGE_GEOMETRY_BIT"),
+        (Self::FRAGMENT.0, "FRAGMENT", "VK_SHADER_STAGE_FRAGMENT_BIT"),
+        (Self::COMPUTE.0, "COMPUTE", "VK_SHADER_STAGE_COMPUTE_BIT"),
+        (
+            Self::ALL_GRAPHICS.0,
+            "ALL_GRAPHICS",
+            "VK_SHADER_STAGE_ALL_GRAPHICS",
+        ),
+        (Self::ALL.0, "ALL", "VK_SHADER_STAGE_ALL"),
+        (
+            Self::RAYGEN_KHR.0,
+            "RAYGEN_KHR",
+            "VK_SHADER_STAGE_RAYGEN_BIT_KHR",
+        ),
+        (
+            Self::ANY_HIT_KHR.0,
+            "ANY_HIT_KHR",
+            "VK_SHADER_STAGE_ANY_HIT_BIT_KHR",
+        ),
+        (
+            Self::CLOSEST_HIT_KHR.0,
+            "CLOSEST_HIT_KHR",
+            "VK_SHADER_STAGE_CLOSEST_HIT_BIT_KHR",
+        ),
+        (Self::MISS_KHR.0, "MISS_KHR", "VK_SHADER_STAGE_MISS_BIT_KHR"),
+        (
+            Self::INTERSECTION_KHR.0,
+            "INTERSECTION_KHR",
+            "VK_SHADER_STAGE_INTERSECTION_BIT_KHR",
+        ),
+        (
+            Self::CALLABLE_KHR.0,
+            "CALLABLE_KHR",
+            "VK_SHADER_STAGE_CALLABLE_BIT_KHR",
+        ),
+        (Self::TASK_EXT.0, "TASK_EXT", "VK_SHADER_STAGE_TASK_BIT_EXT"),
+        (Self::MESH_EXT.0, "MESH_EXT", "VK_SHADER_STAGE_MESH_BIT_EXT"),
+        (
+            Self::SUBPASS_SHADING_HUAWEI.0,
+            "SUBPASS_SHADING_HUAWEI",
+            "VK_SHADER_STAGE_SUBPASS_SHADING_BIT_HUAWEI",
+        ),
+        (
+            Self::CLUSTER_CULLING_HUAWEI.0,
+            "CLUSTER_CULLING_HUAWEI",
+            "VK_SHADER_STAGE_CLUSTER_CULLING_BIT_HUAWEI",
+        ),
+        (
+            Self::RAYGEN_NV.0,
+            "RAYGEN_NV",
+            "VK_SHADER_STAGE_RAYGEN_BIT_NV",
+        ),
+        (
+            Self::ANY_HIT_NV.0,
+            "ANY_HIT_NV",
+            "VK_SHADER_STAGE_ANY_HIT_BIT_NV",
+        ),
+        (
+            Self::CLOSEST_HIT_NV.0,
+            "CLOSEST_HIT_NV",
+            "VK_SHADER_STAGE_CLOSEST_HIT_BIT_NV",
+        ),
+        (Self::MISS_NV.0, "MISS_NV", "VK_SHADER_STAGE_MISS_BIT_NV"),
+        (
+            Self::INTERSECTION_NV.0,
+            "INTERSECTION_NV",
+            "VK_SHADER_STAGE_INTERSECTION_BIT_NV",
+        ),
+        (
+            Self::CALLABLE_NV.0,
+            "CALLABLE_NV",
+            "VK_SHADER_STAGE_CALLABLE_BIT_NV",
+        ),
+        (Self::TASK_NV.0, "TASK_NV", "VK_SHADER_STAGE_TASK_BIT_NV"),
+        (Self::MESH_NV.0, "MESH_NV", "VK_SHADER_STAGE_MESH_BIT_NV"),
+    ];
+}
+impl FromStr for ShaderStageFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ShaderStageFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ShaderStageFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SparseImageFormatFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::SINGLE_MIPTAIL.0,
+            "SINGLE_MIPTAIL",
+            "VK_SPARSE_IMAGE_FORMAT_SINGLE_MIPTAIL_BIT",
+        ),
+        (
+            Self::ALIGNED_MIP_SIZE.0,
+            "ALIGNED_MIP_SIZE",
+            "VK_SPARSE_IMAGE_FORMAT_ALIGNED_MIP_SIZE_BIT",
+        ),
+        (
+            Self::NONSTANDARD_BLOCK_SIZE.0,
+            "NONSTANDARD_BLOCK_SIZE",
+            "VK_SPARSE_IMAGE_FORMAT_NONSTANDARD_BLOCK_SIZE_BIT",
+        ),
+    ];
+}
+impl FromStr for SparseImageFormatFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SparseImageFormatFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SparseImageFormatFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SparseMemoryBindFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::METADATA.0,
+        "METADATA",
+        "VK_SPARSE_MEMORY_BIND_METADATA_BIT",
+    )];
+}
+impl FromStr for SparseMemoryBindFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SparseMemoryBindFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SparseMemoryBindFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl StencilFaceFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::FRONT.0, "FRONT", "VK_STENCIL_FACE_FRONT_BIT"),
+        (Self::BACK.0, "BACK", "VK_STENCIL_FACE_BACK_BIT"),
+        (
+            Self::FRONT_AND_BACK.0,
+            "FRONT_AND_BACK",
+            "VK_STENCIL_FACE_FRONT_AND_BACK",
+        ),
+    ];
+}
+impl FromStr for StencilFaceFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("StencilFaceFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for StencilFaceFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl StreamDescriptorSurfaceCreateFlagsGGP {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for StreamDescriptorSurfaceCreateFlagsGGP {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("StreamDescriptorSurfaceCreateFlagsGGP", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for StreamDescriptorSurfaceCreateFlagsGGP {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SubgroupFeatureFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::BASIC.0, "BASIC", "VK_SUBGROUP_FEATURE_BASIC_BIT"),
+        (Self::VOTE.0, "VOTE", "VK_SUBGROUP_FEATURE_VOTE_BIT"),
+        (
+            Self::ARITHMETIC.0,
+            "ARITHMETIC",
+            "VK_SUBGROUP_FEATURE_ARITHMETIC_BIT",
+        ),
+        (Self::BALLOT.0, "BALLOT", "VK_SUBGROUP_FEATURE_BALLOT_BIT"),
+        (
+            Self::SHUFFLE.0,
+            "SHUFFLE",
+            "VK_SUBGROUP_FEATURE_SHUFFLE_BIT",
+        ),
+        (
+            Self::SHUFFLE_RELATIVE.0,
+            "SHUFFLE_RELATIVE",
+            "VK_SUBGROUP_FEATURE_SHUFFLE_RELATIVE_BIT",
+        ),
+        (
+            Self::CLUSTERED.0,
+            "CLUSTERED",
+            "VK_SUBGROUP_FEATURE_CLUSTERED_BIT",
+        ),
+        (Self::QUAD.0, "QUAD", "VK_SUBGROUP_FEATURE_QUAD_BIT"),
+        (
+            Self::PARTITIONED_NV.0,
+            "PARTITIONED_NV",
+            "VK_SUBGROUP_FEATURE_PARTITIONED_BIT_NV",
+        ),
+    ];
+}
+impl FromStr for SubgroupFeatureFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SubgroupFeatureFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SubgroupFeatureFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SubmitFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::PROTECTED.0, "PROTECTED", "VK_SUBMIT_PROTECTED_BIT"),
+        (
+            Self::PROTECTED_KHR.0,
+            "PROTECTED_KHR",
+            "VK_SUBMIT_PROTECTED_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for SubmitFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SubmitFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SubmitFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SubpassDescriptionFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::PER_VIEW_ATTRIBUTES_NVX.0,
+            "PER_VIEW_ATTRIBUTES_NVX",
+            "VK_SUBPASS_DESCRIPTION_PER_VIEW_ATTRIBUTES_BIT_NVX",
+        ),
+        (
+            Self::PER_VIEW_POSITION_X_ONLY_NVX.0,
+            "PER_VIEW_POSITION_X_ONLY_NVX",
+            "VK_SUBPASS_DESCRIPTION_PER_VIEW_POSITION_X_ONLY_BIT_NVX",
+        ),
+        (
+            Self::FRAGMENT_REGION_QCOM.0,
+            "FRAGMENT_REGION_QCOM",
+            "VK_SUBPASS_DESCRIPTION_FRAGMENT_REGION_BIT_QCOM",
+        ),
+        (
+            Self::SHADER_RESOLVE_QCOM.0,
+            "SHADER_RESOLVE_QCOM",
+            "VK_SUBPASS_DESCRIPTION_SHADER_RESOLVE_BIT_QCOM",
+        ),
+        (
+            Self::RASTERIZATION_ORDER_ATTACHMENT_COLOR_ACCESS_EXT.0,
+            "RASTERIZATION_ORDER_ATTACHMENT_COLOR_ACCESS_EXT",
+            "VK_SUBPASS_DESCRIPTION_RASTERIZATION_ORDER_ATTACHMENT_COLOR_ACCESS_BIT_EXT",
+        ),
+        (
+            Self::RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_EXT.0,
+            "RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_EXT",
+            "VK_SUBPASS_DESCRIPTION_RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_BIT_EXT",
+        ),
+        (
+            Self::RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_EXT.0,
+            "RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_EXT",
+            "VK_SUBPASS_DESCRIPTION_RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_BIT_EXT",
+        ),
+        (
+            Self::ENABLE_LEGACY_DITHERING_EXT.0,
+            "ENABLE_LEGACY_DITHERING_EXT",
+            "VK_SUBPASS_DESCRIPTION_ENABLE_LEGACY_DITHERING_BIT_EXT",
+        ),
+        (
+            Self::RASTERIZATION_ORDER_ATTACHMENT_COLOR_ACCESS_ARM.0,
+            "RASTERIZATION_ORDER_ATTACHMENT_COLOR_ACCESS_ARM",
+            "VK_SUBPASS_DESCRIPTION_RASTERIZATION_ORDER_ATTACHMENT_COLOR_ACCESS_BIT_ARM",
+        ),
+        (
+            Self::RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_ARM.0,
+            "RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_ARM",
+            "VK_SUBPASS_DESCRIPTION_RASTERIZATION_ORDER_ATTACHMENT_DEPTH_ACCESS_BIT_ARM",
+        ),
+        (
+            Self::RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_ARM.0,
+            "RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_ARM",
+            "VK_SUBPASS_DESCRIPTION_RASTERIZATION_ORDER_ATTACHMENT_STENCIL_ACCESS_BIT_ARM",
+        ),
+    ];
+}
+impl FromStr for SubpassDescriptionFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SubpassDescriptionFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SubpassDescriptionFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SurfaceCounterFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::VBLANK.0,
+        "VBLANK",
+        "VK_SURFACE_COUNTER_VBLANK_BIT_EXT",
+    )];
+}
+impl FromStr for SurfaceCounterFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SurfaceCounterFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SurfaceCounterFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SurfaceTransformFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::IDENTITY.0,
+            "IDENTITY",
+            "VK_SURFACE_TRANSFORM_IDENTITY_BIT_KHR",
+        ),
+        (
+            Self::ROTATE_90.0,
+            "ROTATE_90",
+            "VK_SURFACE_TRANSFORM_ROTATE_90_BIT_KHR",
+        ),
+        (
+            Self::ROTATE_180.0,
+            "ROTATE_180",
+            "VK_SURFACE_TRANSFORM_ROTATE_180_BIT_KHR",
+        ),
+        (
+            Self::ROTATE_270.0,
+            "ROTATE_270",
+            "VK_SURFACE_TRANSFORM_ROTATE_270_BIT_KHR",
+        ),
+        (
+            Self::HORIZONTAL_MIRROR.0,
+            "HORIZONTAL_MIRROR",
+            "VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_BIT_KHR",
+        ),
+        (
+            Self::HORIZONTAL_MIRROR_ROTATE_90.0,
+            "HORIZONTAL_MIRROR_ROTATE_90",
+            "VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_ROTATE_90_BIT_KHR",
+        ),
+        (
+            Self::HORIZONTAL_MIRROR_ROTATE_180.0,
+            "HORIZONTAL_MIRROR_ROTATE_180",
+            "VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_ROTATE_180_BIT_KHR",
+        ),
+        (
+            Self::HORIZONTAL_MIRROR_ROTATE_270.0,
+            "HORIZONTAL_MIRROR_ROTATE_270",
+            "VK_SURFACE_TRANSFORM_HORIZONTAL_MIRROR_ROTATE_270_BIT_KHR",
+        ),
+        (
+            Self::INHERIT.0,
+            "INHERIT",
+            "VK_SURFACE_TRANSFORM_INHERIT_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for SurfaceTransformFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SurfaceTransformFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SurfaceTransformFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SwapchainCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::SPLIT_INSTANCE_BIND_REGIONS.0,
+            "SPLIT_INSTANCE_BIND_REGIONS",
+            "VK_SWAPCHAIN_CREATE_SPLIT_INSTANCE_BIND_REGIONS_BIT_KHR",
+        ),
+        (
+            Self::PROTECTED.0,
+            "PROTECTED",
+            "VK_SWAPCHAIN_CREATE_PROTECTED_BIT_KHR",
+        ),
+        (
+            Self::MUTABLE_FORMAT.0,
+            "MUTABLE_FORMAT",
+            "VK_SWAPCHAIN_CREATE_MUTABLE_FORMAT_BIT_KHR",
+        ),
+        (
+            Self::DEFERRED_MEMORY_ALLOCATION_EXT.0,
+            "DEFERRED_MEMORY_ALLOCATION_EXT",
+            "VK_SWAPCHAIN_CREATE_DEFERRED_MEMORY_ALLOCATION_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for SwapchainCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SwapchainCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SwapchainCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SwapchainImageUsageFlagsANDROID {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::SHARED.0,
+        "SHARED",
+        "VK_SWAPCHAIN_IMAGE_USAGE_SHARED_BIT_ANDROID",
+    )];
+}
+impl FromStr for SwapchainImageUsageFlagsANDROID {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("SwapchainImageUsageFlagsANDROID", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SwapchainImageUsageFlagsANDROID {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ToolPurposeFlags {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::VALIDATION.0,
+            "VALIDATION",
+            "VK_TOOL_PURPOSE_VALIDATION_BIT",
+        ),
+        (
+            Self::PROFILING.0,
+            "PROFILING",
+            "VK_TOOL_PURPOSE_PROFILING_BIT",
+        ),
+        (Self::TRACING.0, "TRACING", "VK_TOOL_PURPOSE_TRACING_BIT"),
+        (
+            Self::ADDITIONAL_FEATURES.0,
+            "ADDITIONAL_FEATURES",
+            "VK_TOOL_PURPOSE_ADDITIONAL_FEATURES_BIT",
+        ),
+        (
+            Self::MODIFYING_FEATURES.0,
+            "MODIFYING_FEATURES",
+            "VK_TOOL_PURPOSE_MODIFYING_FEATURES_BIT",
+        ),
+        (
+            Self::DEBUG_REPORTING_EXT.0,
+            "DEBUG_REPORTING_EXT",
+            "VK_TOOL_PURPOSE_DEBUG_REPORTING_BIT_EXT",
+        ),
+        (
+            Self::DEBUG_MARKERS_EXT.0,
+            "DEBUG_MARKERS_EXT",
+            "VK_TOOL_PURPOSE_DEBUG_MARKERS_BIT_EXT",
+        ),
+        (
+            Self::VALIDATION_EXT.0,
+            "VALIDATION_EXT",
+            "VK_TOOL_PURPOSE_VALIDATION_BIT_EXT",
+        ),
+        (
+            Self::PROFILING_EXT.0,
+            "PROFILING_EXT",
+            "VK_TOOL_PURPOSE_PROFILING_BIT_EXT",
+        ),
+        (
+            Self::TRACING_EXT.0,
+            "TRACING_EXT",
+            "VK_TOOL_PURPOSE_TRACING_BIT_EXT",
+        ),
+        (
+            Self::ADDITIONAL_FEATURES_EXT.0,
+            "ADDITIONAL_FEATURES_EXT",
+            "VK_TOOL_PURPOSE_ADDITIONAL_FEATURES_BIT_EXT",
+        ),
+        (
+            Self::MODIFYING_FEATURES_EXT.0,
+            "MODIFYING_FEATURES_EXT",
+            "VK_TOOL_PURPOSE_MODIFYING_FEATURES_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for ToolPurposeFlags {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ToolPurposeFlags", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ToolPurposeFlags {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ValidationCacheCreateFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for ValidationCacheCreateFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ValidationCacheCreateFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ValidationCacheCreateFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ViSurfaceCreateFlagsNN {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for ViSurfaceCreateFlagsNN {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("ViSurfaceCreateFlagsNN", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ViSurfaceCreateFlagsNN {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoBeginCodingFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for VideoBeginCodingFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoBeginCodingFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoBeginCodingFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoCapabilityFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::PROTECTED_CONTENT.0,
+            "PROTECTED_CONTENT",
+            "VK_VIDEO_CAPABILITY_PROTECTED_CONTENT_BIT_KHR",
+        ),
+        (
+            Self::SEPARATE_REFERENCE_IMAGES.0,
+            "SEPARATE_REFERENCE_IMAGES",
+            "VK_VIDEO_CAPABILITY_SEPARATE_REFERENCE_IMAGES_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoCapabilityFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoCapabilityFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoCapabilityFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoChromaSubsamplingFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::INVALID.0,
+            "INVALID",
+            "VK_VIDEO_CHROMA_SUBSAMPLING_INVALID_KHR",
+        ),
+        (
+            Self::MONOCHROME.0,
+            "MONOCHROME",
+            "VK_VIDEO_CHROMA_SUBSAMPLING_MONOCHROME_BIT_KHR",
+        ),
+        (
+            Self::TYPE_420.0,
+            "TYPE_420",
+            "VK_VIDEO_CHROMA_SUBSAMPLING_420_BIT_KHR",
+        ),
+        (
+            Self::TYPE_422.0,
+            "TYPE_422",
+            "VK_VIDEO_CHROMA_SUBSAMPLING_422_BIT_KHR",
+        ),
+        (
+            Self::TYPE_444.0,
+            "TYPE_444",
+            "VK_VIDEO_CHROMA_SUBSAMPLING_444_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoChromaSubsamplingFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoChromaSubsamplingFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoChromaSubsamplingFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoCodecOperationFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (Self::NONE.0, "NONE", "VK_VIDEO_CODEC_OPERATION_NONE_KHR"),
+        (
+            Self::ENCODE_H264_EXT.0,
+            "ENCODE_H264_EXT",
+            "VK_VIDEO_CODEC_OPERATION_ENCODE_H264_BIT_EXT",
+        ),
+        (
+            Self::ENCODE_H265_EXT.0,
+            "ENCODE_H265_EXT",
+            "VK_VIDEO_CODEC_OPERATION_ENCODE_H265_BIT_EXT",
+        ),
+        (
+            Self::DECODE_H264.0,
+            "DECODE_H264",
+            "VK_VIDEO_CODEC_OPERATION_DECODE_H264_BIT_KHR",
+        ),
+        (
+            Self::DECODE_H265.0,
+            "DECODE_H265",
+            "VK_VIDEO_CODEC_OPERATION_DECODE_H265_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoCodecOperationFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoCodecOperationFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoCodecOperationFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoCodingControlFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::RESET.0,
+            "RESET",
+            "VK_VIDEO_CODING_CONTROL_RESET_BIT_KHR",
+        ),
+        (
+            Self::ENCODE_RATE_CONTROL.0,
+            "ENCODE_RATE_CONTROL",
+            "VK_VIDEO_CODING_CONTROL_ENCODE_RATE_CONTROL_BIT_KHR",
+        ),
+        (
+            Self::ENCODE_QUALITY_LEVEL.0,
+            "ENCODE_QUALITY_LEVEL",
+            "VK_VIDEO_CODING_CONTROL_ENCODE_QUALITY_LEVEL_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoCodingControlFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoCodingControlFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoCodingControlFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoComponentBitDepthFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::INVALID.0,
+            "INVALID",
+            "VK_VIDEO_COMPONENT_BIT_DEPTH_INVALID_KHR",
+        ),
+        (
+            Self::TYPE_8.0,
+            "TYPE_8",
+            "VK_VIDEO_COMPONENT_BIT_DEPTH_8_BIT_KHR",
+        ),
+        (
+            Self::TYPE_10.0,
+            "TYPE_10",
+            "VK_VIDEO_COMPONENT_BIT_DEPTH_10_BIT_KHR",
+        ),
+        (
+            Self::TYPE_12.0,
+            "TYPE_12",
+            "VK_VIDEO_COMPONENT_BIT_DEPTH_12_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoComponentBitDepthFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoComponentBitDepthFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoComponentBitDepthFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoDecodeCapabilityFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DPB_AND_OUTPUT_COINCIDE.0,
+            "DPB_AND_OUTPUT_COINCIDE",
+            "VK_VIDEO_DECODE_CAPABILITY_DPB_AND_OUTPUT_COINCIDE_BIT_KHR",
+        ),
+        (
+            Self::DPB_AND_OUTPUT_DISTINCT.0,
+            "DPB_AND_OUTPUT_DISTINCT",
+            "VK_VIDEO_DECODE_CAPABILITY_DPB_AND_OUTPUT_DISTINCT_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoDecodeCapabilityFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoDecodeCapabilityFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoDecodeCapabilityFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoDecodeFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for VideoDecodeFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoDecodeFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoDecodeFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoDecodeH264PictureLayoutFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::PROGRESSIVE.0,
+            "PROGRESSIVE",
+            "VK_VIDEO_DECODE_H264_PICTURE_LAYOUT_PROGRESSIVE_KHR",
+        ),
+        (
+            Self::INTERLACED_INTERLEAVED_LINES.0,
+            "INTERLACED_INTERLEAVED_LINES",
+            "VK_VIDEO_DECODE_H264_PICTURE_LAYOUT_INTERLACED_INTERLEAVED_LINES_BIT_KHR",
+        ),
+        (
+            Self::INTERLACED_SEPARATE_PLANES.0,
+            "INTERLACED_SEPARATE_PLANES",
+            "VK_VIDEO_DECODE_H264_PICTURE_LAYOUT_INTERLACED_SEPARATE_PLANES_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoDecodeH264PictureLayoutFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoDecodeH264PictureLayoutFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoDecodeH264PictureLayoutFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoDecodeUsageFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEFAULT.0,
+            "DEFAULT",
+            "VK_VIDEO_DECODE_USAGE_DEFAULT_KHR",
+        ),
+        (
+            Self::TRANSCODING.0,
+            "TRANSCODING",
+            "VK_VIDEO_DECODE_USAGE_TRANSCODING_BIT_KHR",
+        ),
+        (
+            Self::OFFLINE.0,
+            "OFFLINE",
+            "VK_VIDEO_DECODE_USAGE_OFFLINE_BIT_KHR",
+        ),
+        (
+            Self::STREAMING.0,
+            "STREAMING",
+            "VK_VIDEO_DECODE_USAGE_STREAMING_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoDecodeUsageFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoDecodeUsageFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoDecodeUsageFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeCapabilityFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[(
+        Self::PRECEDING_EXTERNALLY_ENCODED_BYTES.0,
+        "PRECEDING_EXTERNALLY_ENCODED_BYTES",
+        "VK_VIDEO_ENCODE_CAPABILITY_PRECEDING_EXTERNALLY_ENCODED_BYTES_BIT_KHR",
+    )];
+}
+impl FromStr for VideoEncodeCapabilityFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeCapabilityFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeCapabilityFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeContentFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEFAULT.0,
+            "DEFAULT",
+            "VK_VIDEO_ENCODE_CONTENT_DEFAULT_KHR",
+        ),
+        (
+            Self::CAMERA.0,
+            "CAMERA",
+            "VK_VIDEO_ENCODE_CONTENT_CAMERA_BIT_KHR",
+        ),
+        (
+            Self::DESKTOP.0,
+            "DESKTOP",
+            "VK_VIDEO_ENCODE_CONTENT_DESKTOP_BIT_KHR",
+        ),
+        (
+            Self::RENDERED.0,
+            "RENDERED",
+            "VK_VIDEO_ENCODE_CONTENT_RENDERED_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeContentFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeContentFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeContentFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeFeedbackFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::BITSTREAM_BUFFER_OFFSET.0,
+            "BITSTREAM_BUFFER_OFFSET",
+            "VK_VIDEO_ENCODE_FEEDBACK_BITSTREAM_BUFFER_OFFSET_BIT_KHR",
+        ),
+        (
+            Self::BITSTREAM_BYTES_WRITTEN.0,
+            "BITSTREAM_BYTES_WRITTEN",
+            "VK_VIDEO_ENCODE_FEEDBACK_BITSTREAM_BYTES_WRITTEN_BIT_KHR",
+        ),
+        (
+            Self::BITSTREAM_HAS_OVERRIDES.0,
+            "BITSTREAM_HAS_OVERRIDES",
+            "VK_VIDEO_ENCODE_FEEDBACK_BITSTREAM_HAS_OVERRIDES_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeFeedbackFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeFeedbackFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeFeedbackFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for VideoEncodeFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeH264CapabilityFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::HRD_COMPLIANCE.0,
+            "HRD_COMPLIANCE",
+            "VK_VIDEO_ENCODE_H264_CAPABILITY_HRD_COMPLIANCE_BIT_EXT",
+        ),
+        (
+            Self::PREDICTION_WEIGHT_TABLE_GENERATED.0,
+            "PREDICTION_WEIGHT_TABLE_GENERATED",
+            "VK_VIDEO_ENCODE_H264_CAPABILITY_PREDICTION_WEIGHT_TABLE_GENERATED_BIT_EXT",
+        ),
+        (
+            Self::ROW_UNALIGNED_SLICE.0,
+            "ROW_UNALIGNED_SLICE",
+            "VK_VIDEO_ENCODE_H264_CAPABILITY_ROW_UNALIGNED_SLICE_BIT_EXT",
+        ),
+        (
+            Self::DIFFERENT_SLICE_TYPE.0,
+            "DIFFERENT_SLICE_TYPE",
+            "VK_VIDEO_ENCODE_H264_CAPABILITY_DIFFERENT_SLICE_TYPE_BIT_EXT",
+        ),
+        (
+            Self::B_FRAME_IN_L0_LIST.0,
+            "B_FRAME_IN_L0_LIST",
+            "VK_VIDEO_ENCODE_H264_CAPABILITY_B_FRAME_IN_L0_LIST_BIT_EXT",
+        ),
+        (
+            Self::B_FRAME_IN_L1_LIST.0,
+            "B_FRAME_IN_L1_LIST",
+            "VK_VIDEO_ENCODE_H264_CAPABILITY_B_FRAME_IN_L1_LIST_BIT_EXT",
+        ),
+        (
+            Self::PER_PICTURE_TYPE_MIN_MAX_QP.0,
+            "PER_PICTURE_TYPE_MIN_MAX_QP",
+            "VK_VIDEO_ENCODE_H264_CAPABILITY_PER_PICTURE_TYPE_MIN_MAX_QP_BIT_EXT",
+        ),
+        (
+            Self::PER_SLICE_CONSTANT_QP.0,
+            "PER_SLICE_CONSTANT_QP",
+            "VK_VIDEO_ENCODE_H264_CAPABILITY_PER_SLICE_CONSTANT_QP_BIT_EXT",
+        ),
+        (
+            Self::GENERATE_PREFIX_NALU.0,
+            "GENERATE_PREFIX_NALU",
+            "VK_VIDEO_ENCODE_H264_CAPABILITY_GENERATE_PREFIX_NALU_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeH264CapabilityFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeH264CapabilityFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeH264CapabilityFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeH264RateControlFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::ATTEMPT_HRD_COMPLIANCE.0,
+            "ATTEMPT_HRD_COMPLIANCE",
+            "VK_VIDEO_ENCODE_H264_RATE_CONTROL_ATTEMPT_HRD_COMPLIANCE_BIT_EXT",
+        ),
+        (
+            Self::REGULAR_GOP.0,
+            "REGULAR_GOP",
+            "VK_VIDEO_ENCODE_H264_RATE_CONTROL_REGULAR_GOP_BIT_EXT",
+        ),
+        (
+            Self::REFERENCE_PATTERN_FLAT.0,
+            "REFERENCE_PATTERN_FLAT",
+            "VK_VIDEO_ENCODE_H264_RATE_CONTROL_REFERENCE_PATTERN_FLAT_BIT_EXT",
+        ),
+        (
+            Self::REFERENCE_PATTERN_DYADIC.0,
+            "REFERENCE_PATTERN_DYADIC",
+            "VK_VIDEO_ENCODE_H264_RATE_CONTROL_REFERENCE_PATTERN_DYADIC_BIT_EXT",
+        ),
+        (
+            Self::TEMPORAL_LAYER_PATTERN_DYADIC.0,
+            "TEMPORAL_LAYER_PATTERN_DYADIC",
+            "VK_VIDEO_ENCODE_H264_RATE_CONTROL_TEMPORAL_LAYER_PATTERN_DYADIC_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeH264RateControlFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeH264RateControlFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeH264RateControlFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeH264StdFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::SEPARATE_COLOR_PLANE_FLAG_SET.0,
+            "SEPARATE_COLOR_PLANE_FLAG_SET",
+            "VK_VIDEO_ENCODE_H264_STD_SEPARATE_COLOR_PLANE_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::QPPRIME_Y_ZERO_TRANSFORM_BYPASS_FLAG_SET.0,
+            "QPPRIME_Y_ZERO_TRANSFORM_BYPASS_FLAG_SET",
+            "VK_VIDEO_ENCODE_H264_STD_QPPRIME_Y_ZERO_TRANSFORM_BYPASS_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::SCALING_MATRIX_PRESENT_FLAG_SET.0,
+            "SCALING_MATRIX_PRESENT_FLAG_SET",
+            "VK_VIDEO_ENCODE_H264_STD_SCALING_MATRIX_PRESENT_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::CHROMA_QP_INDEX_OFFSET.0,
+            "CHROMA_QP_INDEX_OFFSET",
+            "VK_VIDEO_ENCODE_H264_STD_CHROMA_QP_INDEX_OFFSET_BIT_EXT",
+        ),
+        (
+            Self::SECOND_CHROMA_QP_INDEX_OFFSET.0,
+            "SECOND_CHROMA_QP_INDEX_OFFSET",
+            "VK_VIDEO_ENCODE_H264_STD_SECOND_CHROMA_QP_INDEX_OFFSET_BIT_EXT",
+        ),
+        (
+            Self::PIC_INIT_QP_MINUS26.0,
+            "PIC_INIT_QP_MINUS26",
+            "VK_VIDEO_ENCODE_H264_STD_PIC_INIT_QP_MINUS26_BIT_EXT",
+        ),
+        (
+            Self::WEIGHTED_PRED_FLAG_SET.0,
+            "WEIGHTED_PRED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H264_STD_WEIGHTED_PRED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::WEIGHTED_BIPRED_IDC_EXPLICIT.0,
+            "WEIGHTED_BIPRED_IDC_EXPLICIT",
+            "VK_VIDEO_ENCODE_H264_STD_WEIGHTED_BIPRED_IDC_EXPLICIT_BIT_EXT",
+        ),
+        (
+            Self::WEIGHTED_BIPRED_IDC_IMPLICIT.0,
+            "WEIGHTED_BIPRED_IDC_IMPLICIT",
+            "VK_VIDEO_ENCODE_H264_STD_WEIGHTED_BIPRED_IDC_IMPLICIT_BIT_EXT",
+        ),
+        (
+            Self::TRANSFORM_8X8_MODE_FLAG_SET.0,
+            "TRANSFORM_8X8_MODE_FLAG_SET",
+            "VK_VIDEO_ENCODE_H264_STD_TRANSFORM_8X8_MODE_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::DIRECT_SPATIAL_MV_PRED_FLAG_UNSET.0,
+            "DIRECT_SPATIAL_MV_PRED_FLAG_UNSET",
+            "VK_VIDEO_ENCODE_H264_STD_DIRECT_SPATIAL_MV_PRED_FLAG_UNSET_BIT_EXT",
+        ),
+        (
+            Self::ENTROPY_CODING_MODE_FLAG_UNSET.0,
+            "ENTROPY_CODING_MODE_FLAG_UNSET",
+            "VK_VIDEO_ENCODE_H264_STD_ENTROPY_CODING_MODE_FLAG_UNSET_BIT_EXT",
+        ),
+        (
+            Self::ENTROPY_CODING_MODE_FLAG_SET.0,
+            "ENTROPY_CODING_MODE_FLAG_SET",
+            "VK_VIDEO_ENCODE_H264_STD_ENTROPY_CODING_MODE_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::DIRECT_8X8_INFERENCE_FLAG_UNSET.0,
+            "DIRECT_8X8_INFERENCE_FLAG_UNSET",
+            "VK_VIDEO_ENCODE_H264_STD_DIRECT_8X8_INFERENCE_FLAG_UNSET_BIT_EXT",
+        ),
+        (
+            Self::CONSTRAINED_INTRA_PRED_FLAG_SET.0,
+            "CONSTRAINED_INTRA_PRED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H264_STD_CONSTRAINED_INTRA_PRED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::DEBLOCKING_FILTER_DISABLED.0,
+            "DEBLOCKING_FILTER_DISABLED",
+            "VK_VIDEO_ENCODE_H264_STD_DEBLOCKING_FILTER_DISABLED_BIT_EXT",
+        ),
+        (
+            Self::DEBLOCKING_FILTER_ENABLED.0,
+            "DEBLOCKING_FILTER_ENABLED",
+            "VK_VIDEO_ENCODE_H264_STD_DEBLOCKING_FILTER_ENABLED_BIT_EXT",
+        ),
+        (
+            Self::DEBLOCKING_FILTER_PARTIAL.0,
+            "DEBLOCKING_FILTER_PARTIAL",
+            "VK_VIDEO_ENCODE_H264_STD_DEBLOCKING_FILTER_PARTIAL_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeH264StdFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeH264StdFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeH264StdFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeH265CapabilityFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::HRD_COMPLIANCE.0,
+            "HRD_COMPLIANCE",
+            "VK_VIDEO_ENCODE_H265_CAPABILITY_HRD_COMPLIANCE_BIT_EXT",
+        ),
+        (
+            Self::PREDICTION_WEIGHT_TABLE_GENERATED.0,
+            "PREDICTION_WEIGHT_TABLE_GENERATED",
+            "VK_VIDEO_ENCODE_H265_CAPABILITY_PREDICTION_WEIGHT_TABLE_GENERATED_BIT_EXT",
+        ),
+        (
+            Self::ROW_UNALIGNED_SLICE_SEGMENT.0,
+            "ROW_UNALIGNED_SLICE_SEGMENT",
+            "VK_VIDEO_ENCODE_H265_CAPABILITY_ROW_UNALIGNED_SLICE_SEGMENT_BIT_EXT",
+        ),
+        (
+            Self::DIFFERENT_SLICE_SEGMENT_TYPE.0,
+            "DIFFERENT_SLICE_SEGMENT_TYPE",
+            "VK_VIDEO_ENCODE_H265_CAPABILITY_DIFFERENT_SLICE_SEGMENT_TYPE_BIT_EXT",
+        ),
+        (
+            Self::B_FRAME_IN_L0_LIST.0,
+            "B_FRAME_IN_L0_LIST",
+            "VK_VIDEO_ENCODE_H265_CAPABILITY_B_FRAME_IN_L0_LIST_BIT_EXT",
+        ),
+        (
+            Self::B_FRAME_IN_L1_LIST.0,
+            "B_FRAME_IN_L1_LIST",
+            "VK_VIDEO_ENCODE_H265_CAPABILITY_B_FRAME_IN_L1_LIST_BIT_EXT",
+        ),
+        (
+            Self::PER_PICTURE_TYPE_MIN_MAX_QP.0,
+            "PER_PICTURE_TYPE_MIN_MAX_QP",
+            "VK_VIDEO_ENCODE_H265_CAPABILITY_PER_PICTURE_TYPE_MIN_MAX_QP_BIT_EXT",
+        ),
+        (
+            Self::PER_SLICE_SEGMENT_CONSTANT_QP.0,
+            "PER_SLICE_SEGMENT_CONSTANT_QP",
+            "VK_VIDEO_ENCODE_H265_CAPABILITY_PER_SLICE_SEGMENT_CONSTANT_QP_BIT_EXT",
+        ),
+        (
+            Self::MULTIPLE_TILES_PER_SLICE_SEGMENT.0,
+            "MULTIPLE_TILES_PER_SLICE_SEGMENT",
+            "VK_VIDEO_ENCODE_H265_CAPABILITY_MULTIPLE_TILES_PER_SLICE_SEGMENT_BIT_EXT",
+        ),
+        (
+            Self::MULTIPLE_SLICE_SEGMENTS_PER_TILE.0,
+            "MULTIPLE_SLICE_SEGMENTS_PER_TILE",
+            "VK_VIDEO_ENCODE_H265_CAPABILITY_MULTIPLE_SLICE_SEGMENTS_PER_TILE_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeH265CapabilityFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeH265CapabilityFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeH265CapabilityFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeH265CtbSizeFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::TYPE_16.0,
+            "TYPE_16",
+            "VK_VIDEO_ENCODE_H265_CTB_SIZE_16_BIT_EXT",
+        ),
+        (
+            Self::TYPE_32.0,
+            "TYPE_32",
+            "VK_VIDEO_ENCODE_H265_CTB_SIZE_32_BIT_EXT",
+        ),
+        (
+            Self::TYPE_64.0,
+            "TYPE_64",
+            "VK_VIDEO_ENCODE_H265_CTB_SIZE_64_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeH265CtbSizeFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeH265CtbSizeFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeH265CtbSizeFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeH265RateControlFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::ATTEMPT_HRD_COMPLIANCE.0,
+            "ATTEMPT_HRD_COMPLIANCE",
+            "VK_VIDEO_ENCODE_H265_RATE_CONTROL_ATTEMPT_HRD_COMPLIANCE_BIT_EXT",
+        ),
+        (
+            Self::REGULAR_GOP.0,
+            "REGULAR_GOP",
+            "VK_VIDEO_ENCODE_H265_RATE_CONTROL_REGULAR_GOP_BIT_EXT",
+        ),
+        (
+            Self::REFERENCE_PATTERN_FLAT.0,
+            "REFERENCE_PATTERN_FLAT",
+            "VK_VIDEO_ENCODE_H265_RATE_CONTROL_REFERENCE_PATTERN_FLAT_BIT_EXT",
+        ),
+        (
+            Self::REFERENCE_PATTERN_DYADIC.0,
+            "REFERENCE_PATTERN_DYADIC",
+            "VK_VIDEO_ENCODE_H265_RATE_CONTROL_REFERENCE_PATTERN_DYADIC_BIT_EXT",
+        ),
+        (
+            Self::TEMPORAL_SUB_LAYER_PATTERN_DYADIC.0,
+            "TEMPORAL_SUB_LAYER_PATTERN_DYADIC",
+            "VK_VIDEO_ENCODE_H265_RATE_CONTROL_TEMPORAL_SUB_LAYER_PATTERN_DYADIC_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeH265RateControlFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeH265RateControlFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeH265RateControlFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeH265StdFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::SEPARATE_COLOR_PLANE_FLAG_SET.0,
+            "SEPARATE_COLOR_PLANE_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_SEPARATE_COLOR_PLANE_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::SAMPLE_ADAPTIVE_OFFSET_ENABLED_FLAG_SET.0,
+            "SAMPLE_ADAPTIVE_OFFSET_ENABLED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_SAMPLE_ADAPTIVE_OFFSET_ENABLED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::SCALING_LIST_DATA_PRESENT_FLAG_SET.0,
+            "SCALING_LIST_DATA_PRESENT_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_SCALING_LIST_DATA_PRESENT_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::PCM_ENABLED_FLAG_SET.0,
+            "PCM_ENABLED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_PCM_ENABLED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::SPS_TEMPORAL_MVP_ENABLED_FLAG_SET.0,
+            "SPS_TEMPORAL_MVP_ENABLED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_SPS_TEMPORAL_MVP_ENABLED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::INIT_QP_MINUS26.0,
+            "INIT_QP_MINUS26",
+            "VK_VIDEO_ENCODE_H265_STD_INIT_QP_MINUS26_BIT_EXT",
+        ),
+        (
+            Self::WEIGHTED_PRED_FLAG_SET.0,
+            "WEIGHTED_PRED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_WEIGHTED_PRED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::WEIGHTED_BIPRED_FLAG_SET.0,
+            "WEIGHTED_BIPRED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_WEIGHTED_BIPRED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::LOG2_PARALLEL_MERGE_LEVEL_MINUS2.0,
+            "LOG2_PARALLEL_MERGE_LEVEL_MINUS2",
+            "VK_VIDEO_ENCODE_H265_STD_LOG2_PARALLEL_MERGE_LEVEL_MINUS2_BIT_EXT",
+        ),
+        (
+            Self::SIGN_DATA_HIDING_ENABLED_FLAG_SET.0,
+            "SIGN_DATA_HIDING_ENABLED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_SIGN_DATA_HIDING_ENABLED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::TRANSFORM_SKIP_ENABLED_FLAG_SET.0,
+            "TRANSFORM_SKIP_ENABLED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_TRANSFORM_SKIP_ENABLED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::TRANSFORM_SKIP_ENABLED_FLAG_UNSET.0,
+            "TRANSFORM_SKIP_ENABLED_FLAG_UNSET",
+            "VK_VIDEO_ENCODE_H265_STD_TRANSFORM_SKIP_ENABLED_FLAG_UNSET_BIT_EXT",
+        ),
+        (
+            Self::PPS_SLICE_CHROMA_QP_OFFSETS_PRESENT_FLAG_SET.0,
+            "PPS_SLICE_CHROMA_QP_OFFSETS_PRESENT_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_PPS_SLICE_CHROMA_QP_OFFSETS_PRESENT_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::TRANSQUANT_BYPASS_ENABLED_FLAG_SET.0,
+            "TRANSQUANT_BYPASS_ENABLED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_TRANSQUANT_BYPASS_ENABLED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::CONSTRAINED_INTRA_PRED_FLAG_SET.0,
+            "CONSTRAINED_INTRA_PRED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_CONSTRAINED_INTRA_PRED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::ENTROPY_CODING_SYNC_ENABLED_FLAG_SET.0,
+            "ENTROPY_CODING_SYNC_ENABLED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_ENTROPY_CODING_SYNC_ENABLED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::DEBLOCKING_FILTER_OVERRIDE_ENABLED_FLAG_SET.0,
+            "DEBLOCKING_FILTER_OVERRIDE_ENABLED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_DEBLOCKING_FILTER_OVERRIDE_ENABLED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::DEPENDENT_SLICE_SEGMENTS_ENABLED_FLAG_SET.0,
+            "DEPENDENT_SLICE_SEGMENTS_ENABLED_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_DEPENDENT_SLICE_SEGMENTS_ENABLED_FLAG_SET_BIT_EXT",
+        ),
+        (
+            Self::DEPENDENT_SLICE_SEGMENT_FLAG_SET.0,
+            "DEPENDENT_SLICE_SEGMENT_FLAG_SET",
+            "VK_VIDEO_ENCODE_H265_STD_DEPENDENT_SLICE_SEGMENT_FLAG_SET_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeH265StdFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeH265StdFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeH265StdFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeH265TransformBlockSizeFlagsEXT {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::TYPE_4.0,
+            "TYPE_4",
+            "VK_VIDEO_ENCODE_H265_TRANSFORM_BLOCK_SIZE_4_BIT_EXT",
+        ),
+        (
+            Self::TYPE_8.0,
+            "TYPE_8",
+            "VK_VIDEO_ENCODE_H265_TRANSFORM_BLOCK_SIZE_8_BIT_EXT",
+        ),
+        (
+            Self::TYPE_16.0,
+            "TYPE_16",
+            "VK_VIDEO_ENCODE_H265_TRANSFORM_BLOCK_SIZE_16_BIT_EXT",
+        ),
+        (
+            Self::TYPE_32.0,
+            "TYPE_32",
+            "VK_VIDEO_ENCODE_H265_TRANSFORM_BLOCK_SIZE_32_BIT_EXT",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeH265TransformBlockSizeFlagsEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeH265TransformBlockSizeFlagsEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeH265TransformBlockSizeFlagsEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeRateControlFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for VideoEncodeRateControlFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeRateControlFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeRateControlFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeRateControlModeFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEFAULT.0,
+            "DEFAULT",
+            "VK_VIDEO_ENCODE_RATE_CONTROL_MODE_DEFAULT_KHR",
+        ),
+        (
+            Self::DISABLED.0,
+            "DISABLED",
+            "VK_VIDEO_ENCODE_RATE_CONTROL_MODE_DISABLED_BIT_KHR",
+        ),
+        (
+            Self::CBR.0,
+            "CBR",
+            "VK_VIDEO_ENCODE_RATE_CONTROL_MODE_CBR_BIT_KHR",
+        ),
+        (
+            Self::VBR.0,
+            "VBR",
+            "VK_VIDEO_ENCODE_RATE_CONTROL_MODE_VBR_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeRateControlModeFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeRateControlModeFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeRateControlModeFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeUsageFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::DEFAULT.0,
+            "DEFAULT",
+            "VK_VIDEO_ENCODE_USAGE_DEFAULT_KHR",
+        ),
+        (
+            Self::TRANSCODING.0,
+            "TRANSCODING",
+            "VK_VIDEO_ENCODE_USAGE_TRANSCODING_BIT_KHR",
+        ),
+        (
+            Self::STREAMING.0,
+            "STREAMING",
+            "VK_VIDEO_ENCODE_USAGE_STREAMING_BIT_KHR",
+        ),
+        (
+            Self::RECORDING.0,
+            "RECORDING",
+            "VK_VIDEO_ENCODE_USAGE_RECORDING_BIT_KHR",
+        ),
+        (
+            Self::CONFERENCING.0,
+            "CONFERENCING",
+            "VK_VIDEO_ENCODE_USAGE_CONFERENCING_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeUsageFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEncodeUsageFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeUsageFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEndCodingFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for VideoEndCodingFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoEndCodingFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEndCodingFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoSessionCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[
+        (
+            Self::PROTECTED_CONTENT.0,
+            "PROTECTED_CONTENT",
+            "VK_VIDEO_SESSION_CREATE_PROTECTED_CONTENT_BIT_KHR",
+        ),
+        (
+            Self::ALLOW_ENCODE_PARAMETER_OPTIMIZATIONS.0,
+            "ALLOW_ENCODE_PARAMETER_OPTIMIZATIONS",
+            "VK_VIDEO_SESSION_CREATE_ALLOW_ENCODE_PARAMETER_OPTIMIZATIONS_BIT_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoSessionCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoSessionCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoSessionCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoSessionParametersCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for VideoSessionParametersCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("VideoSessionParametersCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoSessionParametersCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl WaylandSurfaceCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for WaylandSurfaceCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("WaylandSurfaceCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for WaylandSurfaceCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl Win32SurfaceCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for Win32SurfaceCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("Win32SurfaceCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for Win32SurfaceCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl XcbSurfaceCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for XcbSurfaceCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("XcbSurfaceCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for XcbSurfaceCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl XlibSurfaceCreateFlagsKHR {
+    pub(crate) const KNOWN: Known<Flags> = &[];
+}
+impl FromStr for XlibSurfaceCreateFlagsKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flags("XlibSurfaceCreateFlagsKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for XlibSurfaceCreateFlagsKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
@@ -0,0 +1,1505 @@
+use crate::prelude::{debug_enum, debug_flags};
+use crate::vk::bitflags::*;
+use crate::vk::definitions::*;
+use crate::vk::enums::*;
+use std::fmt;
+impl fmt::Debug for AccelerationStructureBuildTypeKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AccelerationStructureCompatibilityKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AccelerationStructureCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AccelerationStructureMemoryRequirementsTypeNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AccelerationStructureMotionInfoFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AccelerationStructureMotionInstanceFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AccelerationStructureMotionInstanceTypeNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AccelerationStructureTypeKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AccessFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AccessFlags2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AcquireProfilingLockFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AndroidSurfaceCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AttachmentDescriptionFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AttachmentLoadOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for AttachmentStoreOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BlendFactor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BlendOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BlendOverlapEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BorderColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BufferCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BufferUsageFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BufferViewCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BuildAccelerationStructureFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BuildAccelerationStructureModeKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BuildMicromapFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for BuildMicromapModeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ChromaLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CoarseSampleOrderTypeNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ColorComponentFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ColorSpaceKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CommandBufferLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CommandBufferResetFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CommandBufferUsageFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CommandPoolCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CommandPoolResetFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CommandPoolTrimFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CompareOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ComponentSwizzle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ComponentTypeNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CompositeAlphaFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ConditionalRenderingFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ConservativeRasterizationModeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CopyAccelerationStructureModeKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CopyMicromapModeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CoverageModulationModeNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CoverageReductionModeNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for CullModeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DebugReportFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DebugReportObjectTypeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DebugUtilsMessageSeverityFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DebugUtilsMessageTypeFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DebugUtilsMessengerCallbackDataFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DebugUtilsMessengerCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DependencyFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DepthBiasRepresentationEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DescriptorBindingFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DescriptorPoolCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DescriptorPoolResetFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DescriptorSetLayoutCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DescriptorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DescriptorUpdateTemplateCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DescriptorUpdateTemplateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceAddressBindingFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceAddressBindingTypeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceDiagnosticsConfigFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceEventTypeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceFaultAddressTypeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceFaultVendorBinaryHeaderVersionEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceGroupPresentModeFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceMemoryReportEventTypeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceMemoryReportFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DeviceQueueCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DirectDriverLoadingFlagsLUNARG {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DirectDriverLoadingModeLUNARG {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DirectFBSurfaceCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DiscardRectangleModeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DisplacementMicromapFormatNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DisplayEventTypeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DisplayModeCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DisplayPlaneAlphaFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DisplayPowerStateEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DisplaySurfaceCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DriverId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for DynamicState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for EventCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ExportMetalObjectTypeFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ExternalFenceFeatureFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ExternalFenceHandleTypeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ExternalMemoryFeatureFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ExternalMemoryFeatureFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ExternalMemoryHandleTypeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ExternalMemoryHandleTypeFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ExternalSemaphoreFeatureFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ExternalSemaphoreHandleTypeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for FenceCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for FenceImportFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for FormatFeatureFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for FormatFeatureFlags2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for FragmentShadingRateCombinerOpKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for FragmentShadingRateNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for FragmentShadingRateTypeNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for FramebufferCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for FrontFace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for FullScreenExclusiveEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for GeometryFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for GeometryInstanceFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for GeometryTypeKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for GraphicsPipelineLibraryFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for HeadlessSurfaceCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for IOSSurfaceCreateFlagsMVK {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageAspectFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageCompressionFixedRateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageCompressionFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageConstraintsInfoFlagsFUCHSIA {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageFormatConstraintsFlagsFUCHSIA {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageLayout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImagePipeSurfaceCreateFlagsFUCHSIA {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageTiling {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageUsageFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageViewCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ImageViewType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for IndexType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for IndirectCommandsLayoutUsageFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for IndirectCommandsTokenTypeNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for IndirectStateFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for InstanceCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for InternalAllocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for LineRasterizationModeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for LogicOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MacOSSurfaceCreateFlagsMVK {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MemoryAllocateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MemoryDecompressionMethodFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MemoryHeapFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MemoryMapFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MemoryOverallocationBehaviorAMD {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MemoryPropertyFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MemoryUnmapFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MetalSurfaceCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MicromapCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for MicromapTypeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for OpacityMicromapFormatEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for OpacityMicromapSpecialIndexEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for OpticalFlowExecuteFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for OpticalFlowGridSizeFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for OpticalFlowPerformanceLevelNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for OpticalFlowSessionBindingPointNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for OpticalFlowSessionCreateFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for OpticalFlowUsageFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PeerMemoryFeatureFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PerformanceConfigurationTypeINTEL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PerformanceCounterDescriptionFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PerformanceCounterScopeKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PerformanceCounterStorageKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PerformanceCounterUnitKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PerformanceOverrideTypeINTEL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PerformanceParameterTypeINTEL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PerformanceValueTypeINTEL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PhysicalDeviceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineBindPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineCacheCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineCacheHeaderVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineColorBlendStateCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineCompilerControlFlagsAMD {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineCoverageModulationStateCreateFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineCoverageReductionStateCreateFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineCoverageToColorStateCreateFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineCreationFeedbackFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineDepthStencilStateCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineDiscardRectangleStateCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineDynamicStateCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineExecutableStatisticFormatKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineInputAssemblyStateCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineLayoutCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineMultisampleStateCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineRasterizationConservativeStateCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineRasterizationDepthClipStateCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineRasterizationStateCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineRasterizationStateStreamCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineRobustnessBufferBehaviorEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineRobustnessImageBehaviorEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineShaderStageCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineStageFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineStageFlags2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineTessellationStateCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineVertexInputStateCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineViewportStateCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PipelineViewportSwizzleStateCreateFlagsNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PointClippingBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PolygonMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PresentGravityFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PresentModeKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PresentScalingFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PrimitiveTopology {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for PrivateDataSlotCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ProvokingVertexModeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for QueryControlFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for QueryPipelineStatisticFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for QueryPoolCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for QueryPoolSamplingModeINTEL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for QueryResultFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for QueryResultStatusKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for QueryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for QueueFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for QueueGlobalPriorityKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for RasterizationOrderAMD {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for RayTracingInvocationReorderModeNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for RayTracingShaderGroupTypeKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for RenderPassCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for RenderingFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ResolveModeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SampleCountFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SamplerAddressMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SamplerCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SamplerMipmapMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SamplerReductionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SamplerYcbcrModelConversion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SamplerYcbcrRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ScopeNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ScreenSurfaceCreateFlagsQNX {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SemaphoreCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SemaphoreImportFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SemaphoreType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SemaphoreWaitFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ShaderCodeTypeEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ShaderCorePropertiesFlagsAMD {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ShaderCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ShaderFloatControlsIndependence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ShaderGroupShaderKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ShaderInfoTypeAMD {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ShaderModuleCreateFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ShaderStageFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ShadingRatePaletteEntryNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SharingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SparseImageFormatFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SparseMemoryBindFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for StencilFaceFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for StencilOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for StreamDescriptorSurfaceCreateFlagsGGP {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for StructureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SubgroupFeatureFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SubmitFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SubpassContents {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SubpassDescriptionFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SubpassMergeStatusEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SurfaceCounterFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SurfaceTransformFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SwapchainCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SwapchainImageUsageFlagsANDROID {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for SystemAllocationScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for TessellationDomainOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for TimeDomainEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ToolPurposeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ValidationCacheCreateFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ValidationCacheHeaderVersionEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ValidationCheckEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ValidationFeatureDisableEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ValidationFeatureEnableEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VendorId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VertexInputRate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ViSurfaceCreateFlagsNN {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoBeginCodingFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoCapabilityFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoChromaSubsamplingFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoCodecOperationFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoCodingControlFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoComponentBitDepthFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoDecodeCapabilityFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoDecodeFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoDecodeH264PictureLayoutFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoDecodeUsageFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeCapabilityFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeContentFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeFeedbackFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeH264CapabilityFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeH264RateControlFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeH264StdFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeH265CapabilityFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeH265CtbSizeFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeH265RateControlFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeH265StdFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeH265TransformBlockSizeFlagsEXT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeRateControlFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeRateControlModeFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeTuningModeKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEncodeUsageFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoEndCodingFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoSessionCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for VideoSessionParametersCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for ViewportCoordinateSwizzleNV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_enum(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for WaylandSurfaceCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for Win32SurfaceCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for XcbSurfaceCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
+impl fmt::Debug for XlibSurfaceCreateFlagsKHR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_flags(f, Self::KNOWN, self.0)
+    }
+}
@@ -0,0 +1,648 @@
+//! Deep copies of the structs of [`vk`], which own the arrays, strings and extension structs they
+//! point to.
+//!
+//! Structs such as [`vk::GraphicsPipelineCreateInfo`] borrow the data they point to, so they can't
+//! outlive it, be stored in a cache key or be sent to another thread. A [`DeepCopy`] copies that
+//! data into a [`Value`], including every extension struct in `p_next` chains that is known
+//! through [`vk::TaggedStructure`], and rebuilds a `'static` view of the struct that points into
+//! storage owned by the copy.
+//!
+//! ```
+//! # use ash::{deep_copy::DeepCopy, vk};
+//! let bindings = [vk::VertexInputBindingDescription::default().stride(16)];
+//! let vertex_input = vk::PipelineVertexInputStateCreateInfo::default()
+//!     .vertex_binding_descriptions(&bindings);
+//! let copy = unsafe { DeepCopy::new(&vertex_input) };
+//!
+//! std::thread::spawn(move || {
+//!     let vertex_input = copy.view();
+//!     let bindings = unsafe { &*vertex_input.p_vertex_binding_descriptions };
+//!     assert_eq!(bindings.stride, 16);
+//! })
+//! .join()
+//! .unwrap();
+//! ```
+//!
+//! Some data is not copied:
+//!
+//! * Extension structs that are not known through [`vk::TaggedStructure`], which are left out of
+//!   the chain.
+//! * `void` pointers without a known size and function pointers, which are copied by address, and
+//!   unions and video `StdVideo*` structs, which are copied without following their pointers.
+//!   Copies of structs that contain such data are not [`Send`] and [`Sync`], as only those of
+//!   [`SelfContained`] structs are.
+
+use crate::vk::{self, Handle};
+use std::alloc::{self, Layout};
+use std::collections::HashMap;
+use std::error::Error;
+use std::ffi::{c_void, CStr, CString};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::os::raw::c_char;
+use std::ptr::{self, NonNull};
+use std::{mem, slice};
+
+#[allow(deprecated)]
+mod generated;
+
+/// A deep copy of a value, independent of the memory it was copied from.
+///
+/// Floating-point values compare and hash by their bits.
+#[derive(Clone, Debug)]
+pub enum Value {
+    /// A null pointer, or an argument that is not captured.
+    Null,
+    /// An integer, enum or flags value, or an address that is not followed.
+    Int(u64),
+    /// A floating-point value.
+    Float(f64),
+    /// A handle with its [`vk::ObjectType`].
+    Handle(vk::ObjectType, u64),
+    /// Data without a known structure, such as `void` pointers of a given size and unions.
+    Bytes(Vec<u8>),
+    /// A null-terminated string.
+    String(CString),
+    /// The elements behind a pointer, a pointer to a single element, or a fixed-size array.
+    Array(Vec<Self>),
+    /// The members of a struct in declaration order, with the `p_next` chain as an
+    /// [`Array`][Value::Array] of extension structs.
+    Struct(Vec<Self>),
+}
+
+impl Value {
+    fn int(&self) -> Result<u64, ValueError> {
+        match *self {
+            Self::Int(value) => Ok(value),
+            Self::Null => Ok(0),
+            ref value => Err(ValueError::new("an integer", value)),
+        }
+    }
+
+    fn float(&self) -> Result<f64, ValueError> {
+        match *self {
+            Self::Float(value) => Ok(value),
+            ref value => Err(ValueError::new("a float", value)),
+        }
+    }
+
+    fn bytes(&self) -> Result<&[u8], ValueError> {
+        match self {
+            Self::Bytes(bytes) => Ok(bytes),
+            value => Err(ValueError::new("bytes", value)),
+        }
+    }
+
+    fn elements(&self) -> Result<&[Self], ValueError> {
+        match self {
+            Self::Array(elements) => Ok(elements),
+            value => Err(ValueError::new("an array", value)),
+        }
+    }
+
+    /// The members of a struct with `len` members.
+    fn members(&self, len: usize) -> Result<&[Self], ValueError> {
+        match self {
+            Self::Struct(members) if members.len() == len => Ok(members),
+            value => Err(ValueError::new(
+                "a struct with a value for every member",
+                value,
+            )),
+        }
+    }
+}
+
+/// A [`Value`] that does not match the type it is replayed as, such as one from a truncated or
+/// edited trace.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueError {
+    /// What was expected, such as `"an integer"`.
+    pub expected: &'static str,
+    /// The value found instead.
+    pub found: Value,
+}
+
+impl ValueError {
+    pub(crate) fn new(expected: &'static str, found: &Value) -> Self {
+        Self {
+            expected,
+            found: found.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Expected {}, found {:?}", self.expected, self.found)
+    }
+}
+
+impl Error for ValueError {}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Null, Self::Null) => true,
+            (Self::Int(a), Self::Int(b)) => a == b,
+            (Self::Float(a), Self::Float(b)) => a.to_bits() == b.to_bits(),
+            (Self::Handle(a_ty, a), Self::Handle(b_ty, b)) => a_ty == b_ty && a == b,
+            (Self::Bytes(a), Self::Bytes(b)) => a == b,
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::Array(a), Self::Array(b)) | (Self::Struct(a), Self::Struct(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Self::Null => {}
+            Self::Int(value) => value.hash(state),
+            Self::Float(value) => value.to_bits().hash(state),
+            Self::Handle(ty, raw) => {
+                ty.hash(state);
+                raw.hash(state);
+            }
+            Self::Bytes(bytes) => bytes.hash(state),
+            Self::String(string) => string.hash(state),
+            Self::Array(values) | Self::Struct(values) => values.hash(state),
+        }
+    }
+}
+
+/// Types that can be deep-copied into a [`Value`] and rebuilt from it.
+///
+/// # Safety
+///
+/// [`DeepCopyable::record()`] must only follow pointers as described by `vk.xml`, and
+/// [`DeepCopyable::replay()`] must only point into allocations of the given [`Arena`].
+pub unsafe trait DeepCopyable: Sized {
+    /// `Self` with its lifetime, if any, set to `'static`, such as
+    /// `vk::GraphicsPipelineCreateInfo<'static>`.
+    type Static: DeepCopyable;
+
+    /// Deep-copies `self`, following pointers to arrays, strings and extension structs.
+    ///
+    /// # Safety
+    ///
+    /// All pointers in `self` must be valid as required by the Vulkan specification.
+    unsafe fn record(&self) -> Value;
+
+    /// Rebuilds a value from a [`Value`] returned by [`DeepCopyable::record()`], allocating pointed-to
+    /// data in `arena` and mapping recorded handles to their replacements in `arena`. Fails if
+    /// `value` does not match `Self`.
+    ///
+    /// # Safety
+    ///
+    /// The pointers in the returned value are only valid as long as `arena` is alive.
+    unsafe fn replay(value: &Value, arena: &mut Arena) -> Result<Self, ValueError>;
+}
+
+/// Structs whose copies only point into their [`Arena`], which makes a [`DeepCopy`] of them
+/// [`Send`] and [`Sync`].
+///
+/// Structs with members that are copied by address, such as `void` pointers without a known size
+/// and platform handles, or without following their pointers, such as unions and video
+/// `StdVideo*` structs, are left out, as are the structs that contain or point to them or that
+/// they may be extended with.
+///
+/// ```compile_fail
+/// # use ash::{deep_copy::DeepCopy, vk};
+/// // `p_user_data` is copied by address
+/// let create_info = vk::DebugUtilsMessengerCreateInfoEXT::default();
+/// let copy = unsafe { DeepCopy::new(&create_info) };
+/// std::thread::spawn(move || drop(copy));
+/// ```
+///
+/// # Safety
+///
+/// [`DeepCopyable::replay()`] must not return pointers outside of its [`Arena`].
+pub unsafe trait SelfContained: DeepCopyable {}
+
+macro_rules! traced_int {
+    ($($ty:ty),*) => {
+        $(#[allow(trivial_numeric_casts)]
+        unsafe impl DeepCopyable for $ty {
+            type Static = Self;
+
+            unsafe fn record(&self) -> Value {
+                Value::Int(*self as u64)
+            }
+
+            unsafe fn replay(value: &Value, _arena: &mut Arena) -> Result<Self, ValueError> {
+                Ok(value.int()? as Self)
+            }
+        })*
+    };
+}
+
+traced_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+macro_rules! traced_float {
+    ($($ty:ty),*) => {
+        $(#[allow(trivial_numeric_casts)]
+        unsafe impl DeepCopyable for $ty {
+            type Static = Self;
+
+            unsafe fn record(&self) -> Value {
+                Value::Float(f64::from(*self))
+            }
+
+            unsafe fn replay(value: &Value, _arena: &mut Arena) -> Result<Self, ValueError> {
+                Ok(value.float()? as Self)
+            }
+        })*
+    };
+}
+
+traced_float!(f32, f64);
+
+/// Pointers that are not followed, such as platform handles, are recorded by address.
+unsafe impl<T> DeepCopyable for *const T {
+    type Static = Self;
+
+    unsafe fn record(&self) -> Value {
+        Value::Int(*self as usize as u64)
+    }
+
+    unsafe fn replay(value: &Value, _arena: &mut Arena) -> Result<Self, ValueError> {
+        Ok(value.int()? as usize as Self)
+    }
+}
+
+unsafe impl<T> DeepCopyable for *mut T {
+    type Static = Self;
+
+    unsafe fn record(&self) -> Value {
+        Value::Int(*self as usize as u64)
+    }
+
+    unsafe fn replay(value: &Value, _arena: &mut Arena) -> Result<Self, ValueError> {
+        Ok(value.int()? as usize as Self)
+    }
+}
+
+unsafe impl<H: Handle + Copy> DeepCopyable for H {
+    type Static = Self;
+
+    unsafe fn record(&self) -> Value {
+        Value::Handle(H::TYPE, self.as_raw())
+    }
+
+    unsafe fn replay(value: &Value, arena: &mut Arena) -> Result<Self, ValueError> {
+        match *value {
+            Value::Handle(_, raw) => Ok(H::from_raw(arena.handle(raw))),
+            Value::Null => Ok(H::from_raw(0)),
+            ref value => Err(ValueError::new("a handle", value)),
+        }
+    }
+}
+
+unsafe impl<T: DeepCopyable, const N: usize> DeepCopyable for [T; N] {
+    type Static = [T::Static; N];
+
+    unsafe fn record(&self) -> Value {
+        Value::Array(self.iter().map(|element| element.record()).collect())
+    }
+
+    unsafe fn replay(value: &Value, arena: &mut Arena) -> Result<Self, ValueError> {
+        let elements = value.elements()?;
+        if elements.len() != N {
+            return Err(ValueError::new("an array of the declared length", value));
+        }
+        let mut array = mem::MaybeUninit::<Self>::uninit();
+        let first = array.as_mut_ptr().cast::<T>();
+        for (i, element) in elements.iter().enumerate() {
+            first.add(i).write(T::replay(element, arena)?);
+        }
+        Ok(array.assume_init())
+    }
+}
+
+/// Storage for the data pointed to by replayed values, and the handles to substitute for recorded
+/// ones.
+#[derive(Default)]
+pub struct Arena {
+    allocations: Vec<(NonNull<u8>, Layout)>,
+    handles: HashMap<u64, u64>,
+}
+
+impl Arena {
+    /// An empty arena.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replays recorded uses of the handle `recorded` with `replayed`.
+    pub fn map_handle(&mut self, recorded: u64, replayed: u64) {
+        self.handles.insert(recorded, replayed);
+    }
+
+    /// The replacement of a recorded handle, or the handle itself if it was not mapped.
+    pub fn handle(&self, recorded: u64) -> u64 {
+        self.handles.get(&recorded).copied().unwrap_or(recorded)
+    }
+
+    #[cfg(feature = "trace")]
+    pub(crate) fn is_mapped(&self, recorded: u64) -> bool {
+        self.handles.contains_key(&recorded)
+    }
+
+    /// Whether `ptr` points to an allocation of at least `len` elements.
+    #[cfg(feature = "serde")]
+    pub(crate) fn holds<T>(&self, ptr: *const T, len: usize) -> bool {
+        let size = mem::size_of::<T>() * len;
+        size == 0
+            || self.allocations.iter().any(|&(start, layout)| {
+                ptr::eq(start.as_ptr(), ptr.cast()) && layout.size() >= size
+            })
+    }
+
+    pub(crate) fn alloc_value<T>(&mut self, value: T) -> *mut T {
+        let ptr = self.alloc::<T>(1);
+        unsafe { ptr.write(value) };
+        ptr
+    }
+
+    pub(crate) fn alloc<T>(&mut self, len: usize) -> *mut T {
+        let layout = Layout::array::<T>(len).unwrap();
+        if layout.size() == 0 {
+            return NonNull::dangling().as_ptr();
+        }
+        let ptr = NonNull::new(unsafe { alloc::alloc_zeroed(layout) })
+            .unwrap_or_else(|| alloc::handle_alloc_error(layout));
+        self.allocations.push((ptr, layout));
+        ptr.as_ptr().cast()
+    }
+}
+
+impl Drop for Arena {
+    fn drop(&mut self) {
+        for &(ptr, layout) in &self.allocations {
+            unsafe { alloc::dealloc(ptr.as_ptr(), layout) };
+        }
+    }
+}
+
+/// Records `len` elements at `ptr` with `f`.
+pub(crate) unsafe fn record_array<T>(ptr: *const T, len: usize, f: impl Fn(&T) -> Value) -> Value {
+    if ptr.is_null() {
+        Value::Null
+    } else {
+        Value::Array(slice::from_raw_parts(ptr, len).iter().map(f).collect())
+    }
+}
+
+/// Replays an array recorded by [`record_array()`], or the storage of an output recorded by
+/// [`record_output()`], whose elements are zeroed.
+pub(crate) unsafe fn replay_array<T>(
+    value: &Value,
+    arena: &mut Arena,
+    mut f: impl FnMut(&Value, &mut Arena) -> Result<T, ValueError>,
+) -> Result<*mut T, ValueError> {
+    if let Value::Null = value {
+        return Ok(ptr::null_mut());
+    }
+    let elements = value.elements()?;
+    let ptr = arena.alloc::<T>(elements.len());
+    for (i, element) in elements.iter().enumerate() {
+        if let Value::Null = element {
+            continue;
+        }
+        ptr.add(i).write(f(element, arena)?);
+    }
+    Ok(ptr)
+}
+
+/// Records the storage of `len` elements that the driver writes to, without reading them.
+#[cfg(feature = "trace")]
+pub(crate) fn record_output<T>(ptr: *const T, len: usize) -> Value {
+    if ptr.is_null() {
+        Value::Null
+    } else {
+        Value::Array(vec![Value::Null; len])
+    }
+}
+
+/// Records the bytes of `len` plain values at `ptr`.
+pub(crate) unsafe fn record_data<T>(ptr: *const T, len: usize) -> Value {
+    if ptr.is_null() {
+        Value::Null
+    } else {
+        Value::Bytes(slice::from_raw_parts(ptr.cast(), len * mem::size_of::<T>()).to_vec())
+    }
+}
+
+/// Records the storage of `len` plain values that the driver writes to, without reading them.
+#[cfg(feature = "trace")]
+pub(crate) fn record_data_output<T>(ptr: *const T, len: usize) -> Value {
+    if ptr.is_null() {
+        Value::Null
+    } else {
+        Value::Bytes(vec![0; len * mem::size_of::<T>()])
+    }
+}
+
+pub(crate) unsafe fn replay_data<T>(
+    value: &Value,
+    arena: &mut Arena,
+) -> Result<*mut T, ValueError> {
+    if let Value::Null = value {
+        return Ok(ptr::null_mut());
+    }
+    let bytes = value.bytes()?;
+    let size = mem::size_of::<T>().max(1);
+    if bytes.len() % size != 0 {
+        return Err(ValueError::new("a whole number of elements", value));
+    }
+    let ptr = arena.alloc::<T>(bytes.len() / size);
+    ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.cast(), bytes.len());
+    Ok(ptr)
+}
+
+/// Records a value by its bytes, without following its pointers.
+unsafe fn record_bytes<T>(value: &T) -> Value {
+    record_data(value, 1)
+}
+
+unsafe fn replay_bytes<T>(value: &Value) -> Result<T, ValueError> {
+    let bytes = value.bytes()?;
+    if bytes.len() != mem::size_of::<T>() {
+        return Err(ValueError::new("the bytes of a single value", value));
+    }
+    Ok(ptr::read_unaligned(bytes.as_ptr().cast()))
+}
+
+/// Records a function pointer by address.
+unsafe fn record_pfn<F>(f: &F) -> Value {
+    Value::Int(mem::transmute_copy::<F, usize>(f) as u64)
+}
+
+unsafe fn replay_pfn<F>(value: &Value) -> Result<F, ValueError> {
+    assert_eq!(mem::size_of::<F>(), mem::size_of::<usize>());
+    Ok(mem::transmute_copy(&(value.int()? as usize)))
+}
+
+fn as_const<T>(ptr: *mut T) -> *const T {
+    ptr
+}
+
+unsafe fn record_str(ptr: *const c_char) -> Value {
+    if ptr.is_null() {
+        Value::Null
+    } else {
+        Value::String(CStr::from_ptr(ptr).to_owned())
+    }
+}
+
+unsafe fn replay_str(value: &Value, arena: &mut Arena) -> Result<*const c_char, ValueError> {
+    match value {
+        Value::Null => Ok(ptr::null()),
+        Value::String(string) => {
+            let bytes = string.as_bytes_with_nul();
+            let ptr = arena.alloc::<c_char>(bytes.len());
+            ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.cast(), bytes.len());
+            Ok(ptr)
+        }
+        value => Err(ValueError::new("a string", value)),
+    }
+}
+
+/// Records the extension structs of a `p_next` chain that are known through
+/// [`vk::TaggedStructure`], skipping unknown ones.
+unsafe fn record_chain(p_next: *const c_void) -> Value {
+    if p_next.is_null() {
+        return Value::Null;
+    }
+    let mut extensions = Vec::new();
+    let mut ptr = p_next.cast::<vk::BaseInStructure<'_>>();
+    while !ptr.is_null() {
+        if let Some(extension) = generated::record_extension(ptr) {
+            extensions.push(extension);
+        }
+        ptr = (*ptr).p_next;
+    }
+    Value::Array(extensions)
+}
+
+/// Replays a chain recorded by [`record_chain()`], skipping extension structs of unknown types,
+/// such as those of a value that was recorded by a later version of `ash`.
+unsafe fn replay_chain(value: &Value, arena: &mut Arena) -> Result<*mut c_void, ValueError> {
+    if let Value::Null = value {
+        return Ok(ptr::null_mut());
+    }
+    let mut p_next = ptr::null_mut::<vk::BaseOutStructure<'_>>();
+    for extension in value.elements()?.iter().rev() {
+        let s_type = match extension {
+            Value::Struct(members) if !members.is_empty() => {
+                vk::StructureType::replay(&members[0], arena)?
+            }
+            extension => return Err(ValueError::new("an extension struct", extension)),
+        };
+        if let Some(ptr) = generated::replay_extension(s_type, extension, arena)? {
+            (*ptr).p_next = p_next;
+            p_next = ptr;
+        }
+    }
+    Ok(p_next.cast())
+}
+
+/// A deep copy of a struct, and a view of it that points into the copy.
+///
+/// `T` is the `'static` [`DeepCopyable::Static`] type of the copied struct. Copies compare and
+/// hash by their [`Value`], and can be used as cache keys.
+pub struct DeepCopy<T> {
+    view: T,
+    value: Value,
+    /// Owns the data that `view` points to.
+    _arena: Arena,
+}
+
+impl<T: DeepCopyable> DeepCopy<T> {
+    /// Copies `value` and everything it points to.
+    ///
+    /// # Safety
+    ///
+    /// All pointers in `value` must be valid as required by the Vulkan specification.
+    pub unsafe fn new<U: DeepCopyable<Static = T>>(value: &U) -> Self {
+        Self::from_value(value.record()).expect("Recorded values match their type")
+    }
+
+    /// Rebuilds a copy from the [`Value`] of another copy, or one returned by
+    /// [`DeepCopyable::record()`], failing if `value` does not match `T`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must have been recorded from a `T`, and the addresses it holds must still be valid.
+    pub unsafe fn from_value(value: Value) -> Result<Self, ValueError> {
+        let mut arena = Arena::new();
+        let view = T::replay(&value, &mut arena)?;
+        Ok(Self {
+            view,
+            value,
+            _arena: arena,
+        })
+    }
+
+    /// A copy of `view`, which only points into `arena`.
+    #[cfg(feature = "serde")]
+    pub(crate) unsafe fn from_parts(view: T, arena: Arena) -> Self {
+        Self {
+            value: view.record(),
+            view,
+            _arena: arena,
+        }
+    }
+
+    /// The copied struct.
+    ///
+    /// Its pointers are only valid as long as `self` is alive, even though its lifetime is
+    /// `'static`.
+    pub fn view(&self) -> &T {
+        &self.view
+    }
+
+    /// The copied data.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// The copied data, dropping the view.
+    pub fn into_value(self) -> Value {
+        self.value
+    }
+}
+
+impl<T: DeepCopyable> Clone for DeepCopy<T> {
+    fn clone(&self) -> Self {
+        unsafe { Self::from_value(self.value.clone()) }.expect("Copies replay their own value")
+    }
+}
+
+impl<T> fmt::Debug for DeepCopy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DeepCopy").field(&self.value).finish()
+    }
+}
+
+impl<T> PartialEq for DeepCopy<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> Eq for DeepCopy<T> {}
+
+impl<T> Hash for DeepCopy<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+// The view only points into `arena`
+unsafe impl<T: SelfContained> Send for DeepCopy<T> {}
+unsafe impl<T: SelfContained> Sync for DeepCopy<T> {}
@@ -1,4 +1,6 @@
 use crate::instance::Instance;
+#[cfg(feature = "loaded")]
+use crate::manifest::IcdManifest;
 use crate::prelude::*;
 use crate::vk;
 use crate::RawPtr;
@@ -8,6 +10,8 @@ use std::ffi::OsStr;
 use std::mem;
 use std::os::raw::c_char;
 use std::os::raw::c_void;
+#[cfg(feature = "loaded")]
+use std::path::{Path, PathBuf};
 use std::ptr;
 #[cfg(feature = "loaded")]
 use std::sync::Arc;
@@ -29,6 +33,9 @@ pub struct Entry {
 impl Entry {
     /// Load default Vulkan library for the current platform
     ///
+    /// The [`loader_candidates()`][Self::loader_candidates()] are tried in order, and
+    /// [`LoadingError::NotFound`] lists why each of them failed to load.
+    ///
     /// Prefer this over [`linked()`][Self::linked()] when your application can gracefully handle
     /// environments that lack Vulkan support, and when the build environment might not have Vulkan
     /// development packages installed (e.g. the Vulkan SDK, or Ubuntu's `libvulkan-dev`).
@@ -61,22 +68,101 @@ impl Entry {
     #[cfg(feature = "loaded")]
     #[cfg_attr(docsrs, doc(cfg(feature = "loaded")))]
     pub unsafe fn load() -> Result<Self, LoadingError> {
+        Self::load_from_candidates(Self::loader_candidates())
+    }
+
+    /// Loads the first of `candidates` that loads, like [`load()`][Self::load()] does with the
+    /// [`loader_candidates()`][Self::loader_candidates()].
+    ///
+    /// # Safety
+    ///
+    /// See [`load()`][Self::load()].
+    #[cfg(feature = "loaded")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "loaded")))]
+    pub unsafe fn load_from_candidates(
+        candidates: impl IntoIterator<Item = PathBuf>,
+    ) -> Result<Self, LoadingError> {
+        let mut attempts = Vec::new();
+        for path in candidates {
+            match Self::load_from(&path) {
+                Ok(entry) => return Ok(entry),
+                Err(error) => attempts.push(LoadAttempt { path, error }),
+            }
+        }
+        Err(LoadingError::NotFound(attempts))
+    }
+
+    /// The environment variable listing the Vulkan loaders that [`load()`][Self::load()] tries
+    /// first, see [`loader_candidates()`][Self::loader_candidates()].
+    #[cfg(feature = "loaded")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "loaded")))]
+    pub const LOADER_PATH_VAR: &str = "VK_LOADER_LIBRARY_PATH";
+
+    /// The paths that [`load()`][Self::load()] tries in order, until one of them loads:
+    ///
+    /// 1. Every entry of the [`LOADER_PATH_VAR`][Self::LOADER_PATH_VAR] environment variable, a
+    ///    list of paths separated like `PATH`, which name either a Vulkan loader library or a
+    ///    directory containing one.
+    /// 2. The names of the system loader on the current platform, which are searched for by the
+    ///    dynamic linker.
+    /// 3. A loader bundled with the application, next to its executable (or in the `Frameworks`
+    ///    directory of its bundle on macOS), if there is one. It comes last so that a library
+    ///    dropped next to the executable cannot take the place of the system loader.
+    #[cfg(feature = "loaded")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "loaded")))]
+    pub fn loader_candidates() -> Vec<PathBuf> {
+        Self::loader_candidates_in(std::env::var_os(Self::LOADER_PATH_VAR).as_deref())
+    }
+
+    /// The [`loader_candidates()`][Self::loader_candidates()] for `loader_paths` instead of the
+    /// value of the [`LOADER_PATH_VAR`][Self::LOADER_PATH_VAR] environment variable.
+    #[cfg(feature = "loaded")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "loaded")))]
+    pub fn loader_candidates_in(loader_paths: Option<&OsStr>) -> Vec<PathBuf> {
         #[cfg(windows)]
-        const LIB_PATH: &str = "vulkan-1.dll";
+        const LIB_NAMES: &[&str] = &["vulkan-1.dll"];
 
         #[cfg(all(
             unix,
             not(any(target_os = "macos", target_os = "ios", target_os = "android"))
         ))]
-        const LIB_PATH: &str = "libvulkan.so.1";
+        const LIB_NAMES: &[&str] = &["libvulkan.so.1", "libvulkan.so"];
 
         #[cfg(target_os = "android")]
-        const LIB_PATH: &str = "libvulkan.so";
+        const LIB_NAMES: &[&str] = &["libvulkan.so"];
 
         #[cfg(any(target_os = "macos", target_os = "ios"))]
-        const LIB_PATH: &str = "libvulkan.dylib";
+        const LIB_NAMES: &[&str] = &["libvulkan.dylib", "libvulkan.1.dylib"];
+
+        fn in_dir(dir: &Path) -> impl Iterator<Item = PathBuf> + '_ {
+            LIB_NAMES.iter().map(move |name| dir.join(name))
+        }
 
-        Self::load_from(LIB_PATH)
+        let mut candidates = Vec::new();
+        if let Some(paths) = loader_paths {
+            for path in std::env::split_paths(paths) {
+                if path.is_dir() {
+                    candidates.extend(in_dir(&path));
+                } else if !path.as_os_str().is_empty() {
+                    candidates.push(path);
+                }
+            }
+        }
+
+        candidates.extend(LIB_NAMES.iter().map(PathBuf::from));
+
+        if let Some(exe_dir) = std::env::current_exe()
+            .ok()
+            .as_deref()
+            .and_then(Path::parent)
+        {
+            let mut bundled = in_dir(exe_dir).collect::<Vec<_>>();
+            if cfg!(target_os = "macos") {
+                bundled.extend(in_dir(&exe_dir.join("../Frameworks")));
+            }
+            candidates.extend(bundled.into_iter().filter(|path| path.is_file()));
+        }
+        candidates
     }
 
     /// Load entry points from a Vulkan loader linked at compile time
@@ -149,6 +235,76 @@ impl Entry {
         })
     }
 
+    /// Load a Vulkan driver directly instead of the Vulkan loader, from its ICD manifest or, if
+    /// `path` does not have a `.json` extension, from its library
+    ///
+    /// The returned [`Entry`] only exposes that driver: no layers are enabled, and the instance
+    /// extensions implemented by the loader such as `VK_EXT_debug_utils` are not available.
+    ///
+    /// # Safety
+    ///
+    /// `dlopen`ing native libraries is inherently unsafe. The safety guidelines
+    /// for [`Library::new()`] and [`Library::get()`] apply here.
+    ///
+    /// No Vulkan functions loaded directly or indirectly from this [`Entry`]
+    /// may be called after it is [dropped][drop()].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ash::Entry;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // Pin lavapipe, the software rasterizer of Mesa
+    /// let entry = unsafe { Entry::load_icd("/usr/share/vulkan/icd.d/lvp_icd.x86_64.json")? };
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "loaded")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "loaded")))]
+    pub unsafe fn load_icd(path: impl AsRef<Path>) -> Result<Self, LoadingError> {
+        /// The version of the loader and ICD interface to negotiate. Version 5 allows passing any
+        /// `api_version` to `vkCreateInstance`, and later versions only concern the loader.
+        const ICD_INTERFACE_VERSION: u32 = 5;
+
+        let path = path.as_ref();
+        let is_manifest = path
+            .extension()
+            .map_or(false, |extension| extension.eq_ignore_ascii_case("json"));
+        let library_path = if is_manifest {
+            IcdManifest::from_file(path)
+                .map_err(LoadingError::InvalidManifest)?
+                .library_path
+        } else {
+            path.to_owned()
+        };
+        let lib = Library::new(library_path)
+            .map_err(LoadingError::LibraryLoadFailure)
+            .map(Arc::new)?;
+
+        // Drivers that do not export it only support versions 0 and 1
+        if let Ok(negotiate) = lib.get::<PFN_vk_icdNegotiateLoaderICDInterfaceVersion>(
+            b"vk_icdNegotiateLoaderICDInterfaceVersion\0",
+        ) {
+            let mut version = ICD_INTERFACE_VERSION;
+            let result = negotiate(&mut version);
+            if result != vk::Result::SUCCESS {
+                return Err(LoadingError::IncompatibleDriver(result));
+            }
+        }
+
+        let static_fn = vk::StaticFn::load_checked(|_| {
+            // Version 0 drivers only export vkGetInstanceProcAddr
+            lib.get(b"vk_icdGetInstanceProcAddr\0")
+                .or_else(|_| lib.get(b"vkGetInstanceProcAddr\0"))
+                .map(|symbol| *symbol)
+                .unwrap_or(ptr::null_mut())
+        })?;
+
+        Ok(Self {
+            _lib_guard: Some(lib),
+            ..Self::from_static_fn(static_fn)
+        })
+    }
+
     /// Load entry points based on an already-loaded [`vk::StaticFn`]
     ///
     /// # Safety
@@ -157,10 +313,12 @@ impl Entry {
     /// by Vulkan 1.0, which must remain valid for at least the lifetime of the returned [`Entry`].
     pub unsafe fn from_static_fn(static_fn: vk::StaticFn) -> Self {
         let load_fn = move |name: &std::ffi::CStr| {
-            mem::transmute((static_fn.get_instance_proc_addr)(
-                vk::Instance::null(),
-                name.as_ptr(),
-            ))
+            resolve_command(name, vk::Instance::null(), || {
+                mem::transmute((static_fn.get_instance_proc_addr)(
+                    vk::Instance::null(),
+                    name.as_ptr(),
+                ))
+            })
         };
 
         Self::from_parts_1_1(
@@ -256,7 +414,19 @@ impl Entry {
             &mut instance,
         )
         .result()?;
-        Ok(Instance::load(&self.static_fn, instance))
+        let api_version = create_info
+            .p_application_info
+            .as_ref()
+            .map_or(0, |application_info| application_info.api_version);
+        Ok(Instance::load_with_version(
+            &self.static_fn,
+            instance,
+            api_version.max(vk::API_VERSION_1_0),
+        )
+        .with_enabled_extensions(&c_str_array(
+            create_info.enabled_extension_count,
+            create_info.pp_enabled_extension_names,
+        )))
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceLayerProperties.html>
@@ -289,7 +459,9 @@ impl Entry {
         instance: vk::Instance,
         p_name: *const c_char,
     ) -> vk::PFN_vkVoidFunction {
-        (self.static_fn.get_instance_proc_addr)(instance, p_name)
+        mem::transmute(resolve_command(CStr::from_ptr(p_name), instance, || {
+            mem::transmute((self.static_fn.get_instance_proc_addr)(instance, p_name))
+        }))
     }
 }
 
@@ -349,6 +521,11 @@ impl std::fmt::Display for MissingEntryPoint {
 }
 impl std::error::Error for MissingEntryPoint {}
 
+#[cfg(feature = "loaded")]
+#[allow(non_camel_case_types)]
+type PFN_vk_icdNegotiateLoaderICDInterfaceVersion =
+    unsafe extern "system" fn(p_supported_version: *mut u32) -> vk::Result;
+
 #[cfg(feature = "linked")]
 extern "system" {
     fn vkGetInstanceProcAddr(instance: vk::Instance, name: *const c_char)
@@ -360,6 +537,8 @@ mod loaded {
     use std::error::Error;
     use std::fmt;
 
+    use crate::manifest::ManifestError;
+
     use super::*;
 
     #[derive(Debug)]
@@ -367,6 +546,22 @@ mod loaded {
     pub enum LoadingError {
         LibraryLoadFailure(libloading::Error),
         MissingEntryPoint(MissingEntryPoint),
+        /// None of the [`Entry::loader_candidates()`], or of the candidates passed to
+        /// [`Entry::load_from_candidates()`], could be loaded.
+        NotFound(Vec<LoadAttempt>),
+        /// The manifest passed to [`Entry::load_icd()`] could not be read.
+        InvalidManifest(ManifestError),
+        /// The driver passed to [`Entry::load_icd()`] does not support the interface version of
+        /// `ash`.
+        IncompatibleDriver(vk::Result),
+    }
+
+    /// A path that [`Entry::load()`] failed to load the Vulkan loader from.
+    #[derive(Debug)]
+    #[cfg_attr(docsrs, doc(cfg(feature = "loaded")))]
+    pub struct LoadAttempt {
+        pub path: PathBuf,
+        pub error: LoadingError,
     }
 
     impl fmt::Display for LoadingError {
@@ -374,16 +569,37 @@ mod loaded {
             match self {
                 Self::LibraryLoadFailure(err) => fmt::Display::fmt(err, f),
                 Self::MissingEntryPoint(err) => fmt::Display::fmt(err, f),
+                Self::NotFound(attempts) => {
+                    write!(f, "Vulkan loader not found")?;
+                    if attempts.is_empty() {
+                        return write!(f, ", no paths to try");
+                    }
+                    write!(f, ", tried:")?;
+                    for attempt in attempts {
+                        write!(f, "\n  {}: {}", attempt.path.display(), attempt.error)?;
+                    }
+                    Ok(())
+                }
+                Self::InvalidManifest(err) => write!(f, "Invalid ICD manifest: {}", err),
+                Self::IncompatibleDriver(result) => write!(
+                    f,
+                    "Driver interface version negotiation failed with {}",
+                    result
+                ),
             }
         }
     }
 
     impl Error for LoadingError {
         fn source(&self) -> Option<&(dyn Error + 'static)> {
-            Some(match self {
-                Self::LibraryLoadFailure(err) => err,
-                Self::MissingEntryPoint(err) => err,
-            })
+            match self {
+                Self::LibraryLoadFailure(err) => Some(err),
+                Self::MissingEntryPoint(err) => Some(err),
+                // Every attempt is part of the message
+                Self::NotFound(_) => None,
+                Self::InvalidManifest(err) => Some(err),
+                Self::IncompatibleDriver(result) => Some(result),
+            }
         }
     }
 
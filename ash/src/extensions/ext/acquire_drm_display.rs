@@ -19,6 +19,8 @@ impl AcquireDrmDisplay {
         Self { fp }
     }
 
+    try_new!(instance, vk::ExtAcquireDrmDisplayFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireDrmDisplayEXT.html>
     #[inline]
     pub unsafe fn acquire_drm_display(
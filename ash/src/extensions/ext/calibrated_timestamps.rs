@@ -19,6 +19,8 @@ impl CalibratedTimestamps {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::ExtCalibratedTimestampsFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceCalibrateableTimeDomainsEXT.html>
     #[inline]
     pub unsafe fn get_physical_device_calibrateable_time_domains(
@@ -19,6 +19,8 @@ impl DebugMarker {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::ExtDebugMarkerFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDebugMarkerSetObjectNameEXT.html>
     #[inline]
     pub unsafe fn debug_marker_set_object_name(
@@ -20,6 +20,8 @@ impl DebugReport {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::ExtDebugReportFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDebugReportCallbackEXT.html>
     #[inline]
     pub unsafe fn destroy_debug_report_callback(
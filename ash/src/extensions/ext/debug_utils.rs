@@ -1,13 +1,17 @@
 use crate::prelude::*;
-use crate::{vk, RawPtr};
+use crate::vk::{self, Handle};
+use crate::RawPtr;
 use crate::{Entry, Instance};
-use std::ffi::CStr;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
 use std::mem;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 #[derive(Clone)]
 pub struct DebugUtils {
     handle: vk::Instance,
     fp: vk::ExtDebugUtilsFn,
+    names: ObjectNames,
 }
 
 impl DebugUtils {
@@ -16,9 +20,15 @@ impl DebugUtils {
         let fp = vk::ExtDebugUtilsFn::load(|name| unsafe {
             mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
         });
-        Self { handle, fp }
+        Self {
+            handle,
+            fp,
+            names: ObjectNames::default(),
+        }
     }
 
+    try_new!(instance, vk::ExtDebugUtilsFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetDebugUtilsObjectNameEXT.html>
     #[inline]
     pub unsafe fn set_debug_utils_object_name(
@@ -29,6 +39,28 @@ impl DebugUtils {
         (self.fp.set_debug_utils_object_name_ext)(device, name_info).result()
     }
 
+    /// Names `handle` with [`DebugUtils::set_debug_utils_object_name()`], and records the name in
+    /// [`DebugUtils::names()`]. The name is truncated at its first null byte.
+    pub unsafe fn set_object_name<H: Handle>(
+        &self,
+        device: vk::Device,
+        handle: H,
+        name: &str,
+    ) -> VkResult<()> {
+        let (ty, raw) = (H::TYPE, handle.as_raw());
+        let c_name = c_string(name);
+        let name_info = vk::DebugUtilsObjectNameInfoEXT {
+            object_type: ty,
+            object_handle: raw,
+            ..Default::default()
+        }
+        .object_name(&c_name);
+        self.set_debug_utils_object_name(device, &name_info)?;
+        let name = c_name.into_string().unwrap();
+        self.names.lock().insert((ty, raw), name);
+        Ok(())
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetDebugUtilsObjectTagEXT.html>
     #[inline]
     pub unsafe fn set_debug_utils_object_tag(
@@ -91,6 +123,36 @@ impl DebugUtils {
         (self.fp.queue_insert_debug_utils_label_ext)(queue, label);
     }
 
+    /// Opens a label region named `name` in `command_buffer`, which is closed with
+    /// [`DebugUtils::cmd_end_debug_utils_label()`] when the returned scope is dropped. The name is
+    /// truncated at its first null byte.
+    pub unsafe fn cmd_label_scope(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        name: &str,
+    ) -> CommandBufferLabelScope<'_> {
+        let name = c_string(name);
+        let label = vk::DebugUtilsLabelEXT::default().label_name(&name);
+        self.cmd_begin_debug_utils_label(command_buffer, &label);
+        CommandBufferLabelScope {
+            debug_utils: self,
+            command_buffer,
+        }
+    }
+
+    /// Opens a label region named `name` in `queue`, which is closed with
+    /// [`DebugUtils::queue_end_debug_utils_label()`] when the returned scope is dropped. The
+    /// name is truncated at its first null byte.
+    pub unsafe fn queue_label_scope(&self, queue: vk::Queue, name: &str) -> QueueLabelScope<'_> {
+        let name = c_string(name);
+        let label = vk::DebugUtilsLabelEXT::default().label_name(&name);
+        self.queue_begin_debug_utils_label(queue, &label);
+        QueueLabelScope {
+            debug_utils: self,
+            queue,
+        }
+    }
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDebugUtilsMessengerEXT.html>
     #[inline]
     pub unsafe fn create_debug_utils_messenger(
@@ -136,6 +198,19 @@ impl DebugUtils {
 
     pub const NAME: &'static CStr = vk::ExtDebugUtilsFn::NAME;
 
+    /// The names given with [`DebugUtils::set_object_name()`], shared with the clones of `self`.
+    #[inline]
+    pub fn names(&self) -> &ObjectNames {
+        &self.names
+    }
+
+    /// Records names in `names` instead, to share them with other instances.
+    #[inline]
+    pub fn with_names(mut self, names: ObjectNames) -> Self {
+        self.names = names;
+        self
+    }
+
     #[inline]
     pub fn fp(&self) -> &vk::ExtDebugUtilsFn {
         &self.fp
@@ -146,3 +221,121 @@ impl DebugUtils {
         self.handle
     }
 }
+
+/// Converts `name` to a C string, truncated at its first null byte.
+fn c_string(name: &str) -> CString {
+    let name = name.split('\0').next().unwrap_or_default();
+    CString::new(name).unwrap()
+}
+
+/// A label region of a command buffer, closed when dropped, see [`DebugUtils::cmd_label_scope()`].
+#[must_use = "The label region is closed when the scope is dropped"]
+pub struct CommandBufferLabelScope<'a> {
+    debug_utils: &'a DebugUtils,
+    command_buffer: vk::CommandBuffer,
+}
+
+impl Drop for CommandBufferLabelScope<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            self.debug_utils
+                .cmd_end_debug_utils_label(self.command_buffer)
+        };
+    }
+}
+
+/// A label region of a queue, closed when dropped, see [`DebugUtils::queue_label_scope()`].
+#[must_use = "The label region is closed when the scope is dropped"]
+pub struct QueueLabelScope<'a> {
+    debug_utils: &'a DebugUtils,
+    queue: vk::Queue,
+}
+
+impl Drop for QueueLabelScope<'_> {
+    fn drop(&mut self) {
+        unsafe { self.debug_utils.queue_end_debug_utils_label(self.queue) };
+    }
+}
+
+/// The names given to handles with [`DebugUtils::set_object_name()`], to resolve the handles
+/// reported by a debug messenger back to their names.
+///
+/// Clones share the same names.
+#[derive(Clone, Debug, Default)]
+pub struct ObjectNames(Arc<Mutex<HashMap<(vk::ObjectType, u64), String>>>);
+
+impl ObjectNames {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<(vk::ObjectType, u64), String>> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// The name of `handle`.
+    pub fn get<H: Handle>(&self, handle: H) -> Option<String> {
+        self.get_raw(H::TYPE, handle.as_raw())
+    }
+
+    /// The name of the handle of type `ty` whose raw value is `raw`.
+    pub fn get_raw(&self, ty: vk::ObjectType, raw: u64) -> Option<String> {
+        self.lock().get(&(ty, raw)).cloned()
+    }
+
+    /// Records the name of `handle` without naming it in Vulkan, returning its previous name.
+    pub fn insert<H: Handle>(&self, handle: H, name: &str) -> Option<String> {
+        self.lock()
+            .insert((H::TYPE, handle.as_raw()), name.to_owned())
+    }
+
+    /// Forgets the name of `handle`, for example when it is destroyed, returning it.
+    pub fn remove<H: Handle>(&self, handle: H) -> Option<String> {
+        self.lock().remove(&(H::TYPE, handle.as_raw()))
+    }
+
+    /// The objects reported in `callback_data`, named by the message, or by `self` when the
+    /// message does not name them.
+    ///
+    /// # Safety
+    ///
+    /// The pointers in `callback_data` must be valid, as in the callback of a debug messenger.
+    pub unsafe fn objects(
+        &self,
+        callback_data: &vk::DebugUtilsMessengerCallbackDataEXT<'_>,
+    ) -> Vec<NamedObject> {
+        if callback_data.p_objects.is_null() {
+            return Vec::new();
+        }
+        let objects = std::slice::from_raw_parts(
+            callback_data.p_objects,
+            callback_data.object_count as usize,
+        );
+        objects
+            .iter()
+            .map(|object| {
+                let name = if object.p_object_name.is_null() {
+                    self.get_raw(object.object_type, object.object_handle)
+                } else {
+                    let name = CStr::from_ptr(object.p_object_name);
+                    Some(name.to_string_lossy().into_owned())
+                };
+                NamedObject {
+                    ty: object.object_type,
+                    raw: object.object_handle,
+                    name,
+                }
+            })
+            .collect()
+    }
+}
+
+/// An object reported by a debug messenger, see [`ObjectNames::objects()`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NamedObject {
+    pub ty: vk::ObjectType,
+    pub raw: u64,
+    pub name: Option<String>,
+}
@@ -20,6 +20,8 @@ impl DescriptorBuffer {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::ExtDescriptorBufferFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSizeEXT.html>
     #[inline]
     pub unsafe fn get_descriptor_set_layout_size(
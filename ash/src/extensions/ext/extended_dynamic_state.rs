@@ -17,6 +17,8 @@ impl ExtendedDynamicState {
         Self { fp }
     }
 
+    try_new!(device, vk::ExtExtendedDynamicStateFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCullModeEXT.html>
     #[inline]
     pub unsafe fn cmd_set_cull_mode(
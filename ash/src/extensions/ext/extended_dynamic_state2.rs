@@ -17,6 +17,8 @@ impl ExtendedDynamicState2 {
         Self { fp }
     }
 
+    try_new!(device, vk::ExtExtendedDynamicState2Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPatchControlPointsEXT.html>
     #[inline]
     pub unsafe fn cmd_set_patch_control_points(
@@ -17,6 +17,8 @@ impl ExtendedDynamicState3 {
         Self { fp }
     }
 
+    try_new!(device, vk::ExtExtendedDynamicState3Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetTessellationDomainOriginEXT.html>
     #[inline]
     pub unsafe fn cmd_set_tessellation_domain_origin(
@@ -19,6 +19,8 @@ impl FullScreenExclusive {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::ExtFullScreenExclusiveFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireFullScreenExclusiveModeEXT.html>
     #[inline]
     pub unsafe fn acquire_full_screen_exclusive_mode(
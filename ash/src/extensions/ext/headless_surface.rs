@@ -21,6 +21,8 @@ impl HeadlessSurface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::ExtHeadlessSurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateHeadlessSurfaceEXT.html>
     #[inline]
     pub unsafe fn create_headless_surface(
@@ -19,6 +19,8 @@ impl ImageCompressionControl {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::ExtImageCompressionControlFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout2EXT.html>
     #[inline]
     pub unsafe fn get_image_subresource_layout2(
@@ -20,6 +20,8 @@ impl ImageDrmFormatModifier {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::ExtImageDrmFormatModifierFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageDrmFormatModifierPropertiesEXT.html>
     #[inline]
     pub unsafe fn get_image_drm_format_modifier_properties(
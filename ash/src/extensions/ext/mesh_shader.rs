@@ -17,6 +17,8 @@ impl MeshShader {
         Self { fp }
     }
 
+    try_new!(device, vk::ExtMeshShaderFn);
+
     /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksEXT.html>
     #[inline]
     pub unsafe fn cmd_draw_mesh_tasks(
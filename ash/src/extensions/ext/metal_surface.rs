@@ -20,6 +20,8 @@ impl MetalSurface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::ExtMetalSurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateMetalSurfaceEXT.html>
     #[inline]
     pub unsafe fn create_metal_surface(
@@ -5,7 +5,9 @@ pub use self::calibrated_timestamps::CalibratedTimestamps;
 pub use self::debug_marker::DebugMarker;
 #[allow(deprecated)]
 pub use self::debug_report::DebugReport;
-pub use self::debug_utils::DebugUtils;
+pub use self::debug_utils::{
+    CommandBufferLabelScope, DebugUtils, NamedObject, ObjectNames, QueueLabelScope,
+};
 pub use self::descriptor_buffer::DescriptorBuffer;
 pub use self::extended_dynamic_state::ExtendedDynamicState;
 pub use self::extended_dynamic_state2::ExtendedDynamicState2;
@@ -21,6 +23,7 @@ pub use self::private_data::PrivateData;
 pub use self::sample_locations::SampleLocations;
 pub use self::shader_object::ShaderObject;
 pub use self::tooling_info::ToolingInfo;
+pub use super::generated::ext::*;
 
 mod acquire_drm_display;
 mod buffer_device_address;
@@ -20,6 +20,8 @@ impl PipelineProperties {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::ExtPipelinePropertiesFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelinePropertiesEXT.html>
     #[inline]
     pub unsafe fn get_pipeline_properties(
@@ -21,6 +21,8 @@ impl PrivateData {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::ExtPrivateDataFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreatePrivateDataSlotEXT.html>
     #[inline]
     pub unsafe fn create_private_data_slot(
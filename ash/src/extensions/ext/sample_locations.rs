@@ -17,6 +17,8 @@ impl SampleLocations {
         Self { fp }
     }
 
+    try_new!(instance, vk::ExtSampleLocationsFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMultisamplePropertiesEXT.html>
     #[inline]
     pub unsafe fn get_physical_device_multisample_properties(
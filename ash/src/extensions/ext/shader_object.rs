@@ -22,6 +22,8 @@ impl ShaderObject {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::ExtShaderObjectFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateShadersEXT.html>
     #[inline]
     pub unsafe fn create_shaders(
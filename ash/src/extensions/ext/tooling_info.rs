@@ -17,6 +17,8 @@ impl ToolingInfo {
         Self { fp }
     }
 
+    try_new!(instance, vk::ExtToolingInfoFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceToolPropertiesEXT.html>
     #[inline]
     pub unsafe fn get_physical_device_tool_properties(
@@ -0,0 +1,4027 @@
+pub mod amd {
+    use crate::prelude::*;
+    use crate::vk;
+    use crate::{Device, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_AMD_draw_indirect_count.html>"]
+    #[derive(Clone)]
+    pub struct DrawIndirectCount {
+        handle: vk::Device,
+        fp: vk::AmdDrawIndirectCountFn,
+    }
+    impl DrawIndirectCount {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::AmdDrawIndirectCountFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::AmdDrawIndirectCountFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectCountAMD.html>"]
+        #[inline]
+        pub unsafe fn cmd_draw_indirect_count(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            buffer: vk::Buffer,
+            offset: vk::DeviceSize,
+            count_buffer: vk::Buffer,
+            count_buffer_offset: vk::DeviceSize,
+            max_draw_count: u32,
+            stride: u32,
+        ) {
+            (self.fp.cmd_draw_indirect_count_amd)(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexedIndirectCountAMD.html>"]
+        #[inline]
+        pub unsafe fn cmd_draw_indexed_indirect_count(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            buffer: vk::Buffer,
+            offset: vk::DeviceSize,
+            count_buffer: vk::Buffer,
+            count_buffer_offset: vk::DeviceSize,
+            max_draw_count: u32,
+            stride: u32,
+        ) {
+            (self.fp.cmd_draw_indexed_indirect_count_amd)(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            );
+        }
+        pub const NAME: &'static CStr = vk::AmdDrawIndirectCountFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::AmdDrawIndirectCountFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_AMD_shader_info.html>"]
+    #[derive(Clone)]
+    pub struct ShaderInfo {
+        handle: vk::Device,
+        fp: vk::AmdShaderInfoFn,
+    }
+    impl ShaderInfo {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::AmdShaderInfoFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::AmdShaderInfoFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderInfoAMD.html>"]
+        #[inline]
+        pub unsafe fn get_shader_info(
+            &self,
+            pipeline: vk::Pipeline,
+            shader_stage: vk::ShaderStageFlags,
+            info_type: vk::ShaderInfoTypeAMD,
+        ) -> VkResult<Vec<u8>> {
+            read_into_uninitialized_vector(|count, data: *mut u8| {
+                (self.fp.get_shader_info_amd)(
+                    self.handle,
+                    pipeline,
+                    shader_stage,
+                    info_type,
+                    count,
+                    data.cast(),
+                )
+            })
+        }
+        pub const NAME: &'static CStr = vk::AmdShaderInfoFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::AmdShaderInfoFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_AMD_buffer_marker.html>"]
+    #[derive(Clone)]
+    pub struct BufferMarker {
+        handle: vk::Device,
+        fp: vk::AmdBufferMarkerFn,
+    }
+    impl BufferMarker {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::AmdBufferMarkerFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::AmdBufferMarkerFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteBufferMarkerAMD.html>"]
+        #[inline]
+        pub unsafe fn cmd_write_buffer_marker(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            pipeline_stage: vk::PipelineStageFlags,
+            dst_buffer: vk::Buffer,
+            dst_offset: vk::DeviceSize,
+            marker: u32,
+        ) {
+            (self.fp.cmd_write_buffer_marker_amd)(
+                command_buffer,
+                pipeline_stage,
+                dst_buffer,
+                dst_offset,
+                marker,
+            );
+        }
+        pub const NAME: &'static CStr = vk::AmdBufferMarkerFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::AmdBufferMarkerFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_AMD_display_native_hdr.html>"]
+    #[derive(Clone)]
+    pub struct DisplayNativeHdr {
+        handle: vk::Device,
+        fp: vk::AmdDisplayNativeHdrFn,
+    }
+    impl DisplayNativeHdr {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::AmdDisplayNativeHdrFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::AmdDisplayNativeHdrFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetLocalDimmingAMD.html>"]
+        #[inline]
+        pub unsafe fn set_local_dimming(
+            &self,
+            swap_chain: vk::SwapchainKHR,
+            local_dimming_enable: bool,
+        ) {
+            (self.fp.set_local_dimming_amd)(self.handle, swap_chain, local_dimming_enable.into());
+        }
+        pub const NAME: &'static CStr = vk::AmdDisplayNativeHdrFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::AmdDisplayNativeHdrFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod android {
+    use crate::prelude::*;
+    use crate::vk;
+    use crate::{Device, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_ANDROID_external_memory_android_hardware_buffer.html>"]
+    #[derive(Clone)]
+    pub struct ExternalMemoryAndroidHardwareBuffer {
+        handle: vk::Device,
+        fp: vk::AndroidExternalMemoryAndroidHardwareBufferFn,
+    }
+    impl ExternalMemoryAndroidHardwareBuffer {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::AndroidExternalMemoryAndroidHardwareBufferFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::AndroidExternalMemoryAndroidHardwareBufferFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAndroidHardwareBufferPropertiesANDROID.html>"]
+        #[inline]
+        pub unsafe fn get_android_hardware_buffer_properties(
+            &self,
+            buffer: &vk::AHardwareBuffer,
+            properties: &mut vk::AndroidHardwareBufferPropertiesANDROID<'_>,
+        ) -> VkResult<()> {
+            (self.fp.get_android_hardware_buffer_properties_android)(
+                self.handle,
+                buffer,
+                properties,
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryAndroidHardwareBufferANDROID.html>"]
+        #[inline]
+        pub unsafe fn get_memory_android_hardware_buffer(
+            &self,
+            info: *const vk::MemoryGetAndroidHardwareBufferInfoANDROID<'_>,
+            buffer: *mut *mut vk::AHardwareBuffer,
+        ) -> vk::Result {
+            (self.fp.get_memory_android_hardware_buffer_android)(self.handle, info, buffer)
+        }
+        pub const NAME: &'static CStr = vk::AndroidExternalMemoryAndroidHardwareBufferFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::AndroidExternalMemoryAndroidHardwareBufferFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod ext {
+    use crate::prelude::*;
+    use crate::success_codes;
+    use crate::vk;
+    use crate::RawPtr;
+    use crate::{Device, Entry, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    use std::os::raw::*;
+    use std::ptr;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_transform_feedback.html>"]
+    #[derive(Clone)]
+    pub struct TransformFeedback {
+        handle: vk::Device,
+        fp: vk::ExtTransformFeedbackFn,
+    }
+    impl TransformFeedback {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtTransformFeedbackFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtTransformFeedbackFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindTransformFeedbackBuffersEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_bind_transform_feedback_buffers(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            first_binding: u32,
+            buffers: &[vk::Buffer],
+            offsets: &[vk::DeviceSize],
+            sizes: Option<&[vk::DeviceSize]>,
+        ) {
+            assert_eq!(buffers.len(), offsets.len());
+            if let Some(sizes) = sizes {
+                assert_eq!(buffers.len(), sizes.len());
+            }
+            (self.fp.cmd_bind_transform_feedback_buffers_ext)(
+                command_buffer,
+                first_binding,
+                buffers.len() as u32,
+                buffers.as_ptr(),
+                offsets.as_ptr(),
+                sizes.map_or(ptr::null(), <[_]>::as_ptr),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginTransformFeedbackEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_begin_transform_feedback(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            first_counter_buffer: u32,
+            counter_buffers: &[vk::Buffer],
+            counter_buffer_offsets: Option<&[vk::DeviceSize]>,
+        ) {
+            if let Some(counter_buffer_offsets) = counter_buffer_offsets {
+                assert_eq!(counter_buffers.len(), counter_buffer_offsets.len());
+            }
+            (self.fp.cmd_begin_transform_feedback_ext)(
+                command_buffer,
+                first_counter_buffer,
+                counter_buffers.len() as u32,
+                counter_buffers.as_ptr(),
+                counter_buffer_offsets.map_or(ptr::null(), <[_]>::as_ptr),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndTransformFeedbackEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_end_transform_feedback(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            first_counter_buffer: u32,
+            counter_buffers: &[vk::Buffer],
+            counter_buffer_offsets: Option<&[vk::DeviceSize]>,
+        ) {
+            if let Some(counter_buffer_offsets) = counter_buffer_offsets {
+                assert_eq!(counter_buffers.len(), counter_buffer_offsets.len());
+            }
+            (self.fp.cmd_end_transform_feedback_ext)(
+                command_buffer,
+                first_counter_buffer,
+                counter_buffers.len() as u32,
+                counter_buffers.as_ptr(),
+                counter_buffer_offsets.map_or(ptr::null(), <[_]>::as_ptr),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginQueryIndexedEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_begin_query_indexed(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            query_pool: vk::QueryPool,
+            query: u32,
+            flags: vk::QueryControlFlags,
+            index: u32,
+        ) {
+            (self.fp.cmd_begin_query_indexed_ext)(command_buffer, query_pool, query, flags, index);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndQueryIndexedEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_end_query_indexed(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            query_pool: vk::QueryPool,
+            query: u32,
+            index: u32,
+        ) {
+            (self.fp.cmd_end_query_indexed_ext)(command_buffer, query_pool, query, index);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectByteCountEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_draw_indirect_byte_count(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            instance_count: u32,
+            first_instance: u32,
+            counter_buffer: vk::Buffer,
+            counter_buffer_offset: vk::DeviceSize,
+            counter_offset: u32,
+            vertex_stride: u32,
+        ) {
+            (self.fp.cmd_draw_indirect_byte_count_ext)(
+                command_buffer,
+                instance_count,
+                first_instance,
+                counter_buffer,
+                counter_buffer_offset,
+                counter_offset,
+                vertex_stride,
+            );
+        }
+        pub const NAME: &'static CStr = vk::ExtTransformFeedbackFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtTransformFeedbackFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_conditional_rendering.html>"]
+    #[derive(Clone)]
+    pub struct ConditionalRendering {
+        handle: vk::Device,
+        fp: vk::ExtConditionalRenderingFn,
+    }
+    impl ConditionalRendering {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtConditionalRenderingFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtConditionalRenderingFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginConditionalRenderingEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_begin_conditional_rendering(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            conditional_rendering_begin: &vk::ConditionalRenderingBeginInfoEXT<'_>,
+        ) {
+            (self.fp.cmd_begin_conditional_rendering_ext)(
+                command_buffer,
+                conditional_rendering_begin,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndConditionalRenderingEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_end_conditional_rendering(&self, command_buffer: vk::CommandBuffer) {
+            (self.fp.cmd_end_conditional_rendering_ext)(command_buffer);
+        }
+        pub const NAME: &'static CStr = vk::ExtConditionalRenderingFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtConditionalRenderingFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_direct_mode_display.html>"]
+    #[derive(Clone)]
+    pub struct DirectModeDisplay {
+        handle: vk::Instance,
+        fp: vk::ExtDirectModeDisplayFn,
+    }
+    impl DirectModeDisplay {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::ExtDirectModeDisplayFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::ExtDirectModeDisplayFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleaseDisplayEXT.html>"]
+        #[inline]
+        pub unsafe fn release_display(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            display: vk::DisplayKHR,
+        ) -> VkResult<()> {
+            (self.fp.release_display_ext)(physical_device, display).result()
+        }
+        pub const NAME: &'static CStr = vk::ExtDirectModeDisplayFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtDirectModeDisplayFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_acquire_xlib_display.html>"]
+    #[derive(Clone)]
+    pub struct AcquireXlibDisplay {
+        handle: vk::Instance,
+        fp: vk::ExtAcquireXlibDisplayFn,
+    }
+    impl AcquireXlibDisplay {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::ExtAcquireXlibDisplayFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::ExtAcquireXlibDisplayFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireXlibDisplayEXT.html>"]
+        #[inline]
+        pub unsafe fn acquire_xlib_display(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            display: vk::DisplayKHR,
+        ) -> VkResult<vk::Display> {
+            let mut dpy: vk::Display = mem::zeroed();
+            (self.fp.acquire_xlib_display_ext)(physical_device, &mut dpy, display)
+                .result_with_success(dpy)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRandROutputDisplayEXT.html>"]
+        #[inline]
+        pub unsafe fn get_rand_r_output_display(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            rr_output: vk::RROutput,
+        ) -> VkResult<(vk::Display, vk::DisplayKHR)> {
+            let mut dpy: vk::Display = mem::zeroed();
+            let mut display: vk::DisplayKHR = mem::zeroed();
+            (self.fp.get_rand_r_output_display_ext)(
+                physical_device,
+                &mut dpy,
+                rr_output,
+                &mut display,
+            )
+            .result_with_success((dpy, display))
+        }
+        pub const NAME: &'static CStr = vk::ExtAcquireXlibDisplayFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtAcquireXlibDisplayFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_display_surface_counter.html>"]
+    #[derive(Clone)]
+    pub struct DisplaySurfaceCounter {
+        handle: vk::Instance,
+        fp: vk::ExtDisplaySurfaceCounterFn,
+    }
+    impl DisplaySurfaceCounter {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::ExtDisplaySurfaceCounterFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::ExtDisplaySurfaceCounterFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceCapabilities2EXT.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_surface_capabilities2(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            surface: vk::SurfaceKHR,
+            surface_capabilities: &mut vk::SurfaceCapabilities2EXT<'_>,
+        ) -> VkResult<()> {
+            (self.fp.get_physical_device_surface_capabilities2_ext)(
+                physical_device,
+                surface,
+                surface_capabilities,
+            )
+            .result()
+        }
+        pub const NAME: &'static CStr = vk::ExtDisplaySurfaceCounterFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtDisplaySurfaceCounterFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_display_control.html>"]
+    #[derive(Clone)]
+    pub struct DisplayControl {
+        handle: vk::Device,
+        fp: vk::ExtDisplayControlFn,
+    }
+    impl DisplayControl {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtDisplayControlFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtDisplayControlFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDisplayPowerControlEXT.html>"]
+        #[inline]
+        pub unsafe fn display_power_control(
+            &self,
+            display: vk::DisplayKHR,
+            display_power_info: &vk::DisplayPowerInfoEXT<'_>,
+        ) -> VkResult<()> {
+            (self.fp.display_power_control_ext)(self.handle, display, display_power_info).result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkRegisterDeviceEventEXT.html>"]
+        #[inline]
+        pub unsafe fn register_device_event(
+            &self,
+            device_event_info: &vk::DeviceEventInfoEXT<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::Fence> {
+            let mut fence: vk::Fence = mem::zeroed();
+            (self.fp.register_device_event_ext)(
+                self.handle,
+                device_event_info,
+                allocator.as_raw_ptr(),
+                &mut fence,
+            )
+            .result_with_success(fence)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkRegisterDisplayEventEXT.html>"]
+        #[inline]
+        pub unsafe fn register_display_event(
+            &self,
+            display: vk::DisplayKHR,
+            display_event_info: &vk::DisplayEventInfoEXT<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::Fence> {
+            let mut fence: vk::Fence = mem::zeroed();
+            (self.fp.register_display_event_ext)(
+                self.handle,
+                display,
+                display_event_info,
+                allocator.as_raw_ptr(),
+                &mut fence,
+            )
+            .result_with_success(fence)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainCounterEXT.html>"]
+        #[inline]
+        pub unsafe fn get_swapchain_counter(
+            &self,
+            swapchain: vk::SwapchainKHR,
+            counter: vk::SurfaceCounterFlagsEXT,
+        ) -> VkResult<u64> {
+            let mut counter_value: u64 = mem::zeroed();
+            (self.fp.get_swapchain_counter_ext)(self.handle, swapchain, counter, &mut counter_value)
+                .result_with_success(counter_value)
+        }
+        pub const NAME: &'static CStr = vk::ExtDisplayControlFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtDisplayControlFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_discard_rectangles.html>"]
+    #[derive(Clone)]
+    pub struct DiscardRectangles {
+        handle: vk::Device,
+        fp: vk::ExtDiscardRectanglesFn,
+    }
+    impl DiscardRectangles {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtDiscardRectanglesFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtDiscardRectanglesFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_discard_rectangle(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            first_discard_rectangle: u32,
+            discard_rectangles: &[vk::Rect2D],
+        ) {
+            (self.fp.cmd_set_discard_rectangle_ext)(
+                command_buffer,
+                first_discard_rectangle,
+                discard_rectangles.len() as u32,
+                discard_rectangles.as_ptr(),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleEnableEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_discard_rectangle_enable(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            discard_rectangle_enable: bool,
+        ) {
+            (self.fp.cmd_set_discard_rectangle_enable_ext)(
+                command_buffer,
+                discard_rectangle_enable.into(),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleModeEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_discard_rectangle_mode(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            discard_rectangle_mode: vk::DiscardRectangleModeEXT,
+        ) {
+            (self.fp.cmd_set_discard_rectangle_mode_ext)(command_buffer, discard_rectangle_mode);
+        }
+        pub const NAME: &'static CStr = vk::ExtDiscardRectanglesFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtDiscardRectanglesFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_hdr_metadata.html>"]
+    #[derive(Clone)]
+    pub struct HdrMetadata {
+        handle: vk::Device,
+        fp: vk::ExtHdrMetadataFn,
+    }
+    impl HdrMetadata {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtHdrMetadataFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtHdrMetadataFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetHdrMetadataEXT.html>"]
+        #[inline]
+        pub unsafe fn set_hdr_metadata(
+            &self,
+            swapchains: &[vk::SwapchainKHR],
+            metadata: &[vk::HdrMetadataEXT<'_>],
+        ) {
+            assert_eq!(swapchains.len(), metadata.len());
+            (self.fp.set_hdr_metadata_ext)(
+                self.handle,
+                swapchains.len() as u32,
+                swapchains.as_ptr(),
+                metadata.as_ptr(),
+            );
+        }
+        pub const NAME: &'static CStr = vk::ExtHdrMetadataFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtHdrMetadataFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_validation_cache.html>"]
+    #[derive(Clone)]
+    pub struct ValidationCache {
+        handle: vk::Device,
+        fp: vk::ExtValidationCacheFn,
+    }
+    impl ValidationCache {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtValidationCacheFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtValidationCacheFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateValidationCacheEXT.html>"]
+        #[inline]
+        pub unsafe fn create_validation_cache(
+            &self,
+            create_info: &vk::ValidationCacheCreateInfoEXT<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::ValidationCacheEXT> {
+            let mut validation_cache: vk::ValidationCacheEXT = mem::zeroed();
+            (self.fp.create_validation_cache_ext)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut validation_cache,
+            )
+            .result_with_success(validation_cache)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyValidationCacheEXT.html>"]
+        #[inline]
+        pub unsafe fn destroy_validation_cache(
+            &self,
+            validation_cache: vk::ValidationCacheEXT,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_validation_cache_ext)(
+                self.handle,
+                validation_cache,
+                allocator.as_raw_ptr(),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkMergeValidationCachesEXT.html>"]
+        #[inline]
+        pub unsafe fn merge_validation_caches(
+            &self,
+            dst_cache: vk::ValidationCacheEXT,
+            src_caches: &[vk::ValidationCacheEXT],
+        ) -> VkResult<()> {
+            (self.fp.merge_validation_caches_ext)(
+                self.handle,
+                dst_cache,
+                src_caches.len() as u32,
+                src_caches.as_ptr(),
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetValidationCacheDataEXT.html>"]
+        #[inline]
+        pub unsafe fn get_validation_cache_data(
+            &self,
+            validation_cache: vk::ValidationCacheEXT,
+        ) -> VkResult<Vec<u8>> {
+            read_into_uninitialized_vector(|count, data: *mut u8| {
+                (self.fp.get_validation_cache_data_ext)(
+                    self.handle,
+                    validation_cache,
+                    count,
+                    data.cast(),
+                )
+            })
+        }
+        pub const NAME: &'static CStr = vk::ExtValidationCacheFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtValidationCacheFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_external_memory_host.html>"]
+    #[derive(Clone)]
+    pub struct ExternalMemoryHost {
+        handle: vk::Device,
+        fp: vk::ExtExternalMemoryHostFn,
+    }
+    impl ExternalMemoryHost {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtExternalMemoryHostFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtExternalMemoryHostFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryHostPointerPropertiesEXT.html>"]
+        #[inline]
+        pub unsafe fn get_memory_host_pointer_properties(
+            &self,
+            handle_type: vk::ExternalMemoryHandleTypeFlags,
+            host_pointer: *const c_void,
+            memory_host_pointer_properties: &mut vk::MemoryHostPointerPropertiesEXT<'_>,
+        ) -> VkResult<()> {
+            (self.fp.get_memory_host_pointer_properties_ext)(
+                self.handle,
+                handle_type,
+                host_pointer,
+                memory_host_pointer_properties,
+            )
+            .result()
+        }
+        pub const NAME: &'static CStr = vk::ExtExternalMemoryHostFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtExternalMemoryHostFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_line_rasterization.html>"]
+    #[derive(Clone)]
+    pub struct LineRasterization {
+        handle: vk::Device,
+        fp: vk::ExtLineRasterizationFn,
+    }
+    impl LineRasterization {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtLineRasterizationFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtLineRasterizationFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineStippleEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_line_stipple(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            line_stipple_factor: u32,
+            line_stipple_pattern: u16,
+        ) {
+            (self.fp.cmd_set_line_stipple_ext)(
+                command_buffer,
+                line_stipple_factor,
+                line_stipple_pattern,
+            );
+        }
+        pub const NAME: &'static CStr = vk::ExtLineRasterizationFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtLineRasterizationFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_host_query_reset.html>"]
+    #[derive(Clone)]
+    pub struct HostQueryReset {
+        handle: vk::Device,
+        fp: vk::ExtHostQueryResetFn,
+    }
+    impl HostQueryReset {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtHostQueryResetFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtHostQueryResetFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetQueryPoolEXT.html>"]
+        #[inline]
+        pub unsafe fn reset_query_pool(
+            &self,
+            query_pool: vk::QueryPool,
+            first_query: u32,
+            query_count: u32,
+        ) {
+            (self.fp.reset_query_pool_ext)(self.handle, query_pool, first_query, query_count);
+        }
+        pub const NAME: &'static CStr = vk::ExtHostQueryResetFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtHostQueryResetFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_swapchain_maintenance1.html>"]
+    #[derive(Clone)]
+    pub struct SwapchainMaintenance1 {
+        handle: vk::Device,
+        fp: vk::ExtSwapchainMaintenance1Fn,
+    }
+    impl SwapchainMaintenance1 {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtSwapchainMaintenance1Fn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtSwapchainMaintenance1Fn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleaseSwapchainImagesEXT.html>"]
+        #[inline]
+        pub unsafe fn release_swapchain_images(
+            &self,
+            release_info: &vk::ReleaseSwapchainImagesInfoEXT<'_>,
+        ) -> VkResult<()> {
+            (self.fp.release_swapchain_images_ext)(self.handle, release_info).result()
+        }
+        pub const NAME: &'static CStr = vk::ExtSwapchainMaintenance1Fn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtSwapchainMaintenance1Fn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_depth_bias_control.html>"]
+    #[derive(Clone)]
+    pub struct DepthBiasControl {
+        handle: vk::Device,
+        fp: vk::ExtDepthBiasControlFn,
+    }
+    impl DepthBiasControl {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtDepthBiasControlFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtDepthBiasControlFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBias2EXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_depth_bias2(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            depth_bias_info: &vk::DepthBiasInfoEXT<'_>,
+        ) {
+            (self.fp.cmd_set_depth_bias2_ext)(command_buffer, depth_bias_info);
+        }
+        pub const NAME: &'static CStr = vk::ExtDepthBiasControlFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtDepthBiasControlFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_metal_objects.html>"]
+    #[derive(Clone)]
+    pub struct MetalObjects {
+        handle: vk::Device,
+        fp: vk::ExtMetalObjectsFn,
+    }
+    impl MetalObjects {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtMetalObjectsFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtMetalObjectsFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkExportMetalObjectsEXT.html>"]
+        #[inline]
+        pub unsafe fn export_metal_objects(
+            &self,
+            metal_objects_info: &mut vk::ExportMetalObjectsInfoEXT<'_>,
+        ) {
+            (self.fp.export_metal_objects_ext)(self.handle, metal_objects_info);
+        }
+        pub const NAME: &'static CStr = vk::ExtMetalObjectsFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtMetalObjectsFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_device_fault.html>"]
+    #[derive(Clone)]
+    pub struct DeviceFault {
+        handle: vk::Device,
+        fp: vk::ExtDeviceFaultFn,
+    }
+    impl DeviceFault {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtDeviceFaultFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtDeviceFaultFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceFaultInfoEXT.html>"]
+        #[inline]
+        pub unsafe fn get_device_fault_info(
+            &self,
+            fault_counts: &mut vk::DeviceFaultCountsEXT<'_>,
+            fault_info: &mut vk::DeviceFaultInfoEXT<'_>,
+        ) -> VkResult<success_codes::GetDeviceFaultInfoEXT> {
+            (self.fp.get_device_fault_info_ext)(self.handle, fault_counts, fault_info)
+                .success_code()
+        }
+        pub const NAME: &'static CStr = vk::ExtDeviceFaultFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtDeviceFaultFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_directfb_surface.html>"]
+    #[derive(Clone)]
+    pub struct DirectfbSurface {
+        handle: vk::Instance,
+        fp: vk::ExtDirectfbSurfaceFn,
+    }
+    impl DirectfbSurface {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::ExtDirectfbSurfaceFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::ExtDirectfbSurfaceFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDirectFBSurfaceEXT.html>"]
+        #[inline]
+        pub unsafe fn create_direct_fb_surface(
+            &self,
+            create_info: &vk::DirectFBSurfaceCreateInfoEXT<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::SurfaceKHR> {
+            let mut surface: vk::SurfaceKHR = mem::zeroed();
+            (self.fp.create_direct_fb_surface_ext)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut surface,
+            )
+            .result_with_success(surface)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDirectFBPresentationSupportEXT.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_direct_fb_presentation_support(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            queue_family_index: u32,
+        ) -> (bool, vk::IDirectFB) {
+            let mut dfb: vk::IDirectFB = mem::zeroed();
+            let ret = (self
+                .fp
+                .get_physical_device_direct_fb_presentation_support_ext)(
+                physical_device,
+                queue_family_index,
+                &mut dfb,
+            );
+            (ret != vk::FALSE, dfb)
+        }
+        pub const NAME: &'static CStr = vk::ExtDirectfbSurfaceFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtDirectfbSurfaceFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_vertex_input_dynamic_state.html>"]
+    #[derive(Clone)]
+    pub struct VertexInputDynamicState {
+        handle: vk::Device,
+        fp: vk::ExtVertexInputDynamicStateFn,
+    }
+    impl VertexInputDynamicState {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtVertexInputDynamicStateFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtVertexInputDynamicStateFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetVertexInputEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_vertex_input(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            vertex_binding_descriptions: &[vk::VertexInputBindingDescription2EXT<'_>],
+            vertex_attribute_descriptions: &[vk::VertexInputAttributeDescription2EXT<'_>],
+        ) {
+            (self.fp.cmd_set_vertex_input_ext)(
+                command_buffer,
+                vertex_binding_descriptions.len() as u32,
+                vertex_binding_descriptions.as_ptr(),
+                vertex_attribute_descriptions.len() as u32,
+                vertex_attribute_descriptions.as_ptr(),
+            );
+        }
+        pub const NAME: &'static CStr = vk::ExtVertexInputDynamicStateFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtVertexInputDynamicStateFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_color_write_enable.html>"]
+    #[derive(Clone)]
+    pub struct ColorWriteEnable {
+        handle: vk::Device,
+        fp: vk::ExtColorWriteEnableFn,
+    }
+    impl ColorWriteEnable {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtColorWriteEnableFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtColorWriteEnableFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorWriteEnableEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_color_write_enable(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            color_write_enables: &[vk::Bool32],
+        ) {
+            (self.fp.cmd_set_color_write_enable_ext)(
+                command_buffer,
+                color_write_enables.len() as u32,
+                color_write_enables.as_ptr(),
+            );
+        }
+        pub const NAME: &'static CStr = vk::ExtColorWriteEnableFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtColorWriteEnableFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_multi_draw.html>"]
+    #[derive(Clone)]
+    pub struct MultiDraw {
+        handle: vk::Device,
+        fp: vk::ExtMultiDrawFn,
+    }
+    impl MultiDraw {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtMultiDrawFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtMultiDrawFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMultiEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_draw_multi(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            vertex_info: &[vk::MultiDrawInfoEXT],
+            instance_count: u32,
+            first_instance: u32,
+            stride: u32,
+        ) {
+            (self.fp.cmd_draw_multi_ext)(
+                command_buffer,
+                vertex_info.len() as u32,
+                vertex_info.as_ptr(),
+                instance_count,
+                first_instance,
+                stride,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMultiIndexedEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_draw_multi_indexed(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            index_info: &[vk::MultiDrawIndexedInfoEXT],
+            instance_count: u32,
+            first_instance: u32,
+            stride: u32,
+            vertex_offset: Option<&i32>,
+        ) {
+            (self.fp.cmd_draw_multi_indexed_ext)(
+                command_buffer,
+                index_info.len() as u32,
+                index_info.as_ptr(),
+                instance_count,
+                first_instance,
+                stride,
+                vertex_offset.as_raw_ptr(),
+            );
+        }
+        pub const NAME: &'static CStr = vk::ExtMultiDrawFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtMultiDrawFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_opacity_micromap.html>"]
+    #[derive(Clone)]
+    pub struct OpacityMicromap {
+        handle: vk::Device,
+        fp: vk::ExtOpacityMicromapFn,
+    }
+    impl OpacityMicromap {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtOpacityMicromapFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtOpacityMicromapFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateMicromapEXT.html>"]
+        #[inline]
+        pub unsafe fn create_micromap(
+            &self,
+            create_info: &vk::MicromapCreateInfoEXT<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::MicromapEXT> {
+            let mut micromap: vk::MicromapEXT = mem::zeroed();
+            (self.fp.create_micromap_ext)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut micromap,
+            )
+            .result_with_success(micromap)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyMicromapEXT.html>"]
+        #[inline]
+        pub unsafe fn destroy_micromap(
+            &self,
+            micromap: vk::MicromapEXT,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_micromap_ext)(self.handle, micromap, allocator.as_raw_ptr());
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildMicromapsEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_build_micromaps(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            infos: &[vk::MicromapBuildInfoEXT<'_>],
+        ) {
+            (self.fp.cmd_build_micromaps_ext)(command_buffer, infos.len() as u32, infos.as_ptr());
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBuildMicromapsEXT.html>"]
+        #[inline]
+        pub unsafe fn build_micromaps(
+            &self,
+            deferred_operation: vk::DeferredOperationKHR,
+            infos: &[vk::MicromapBuildInfoEXT<'_>],
+        ) -> VkResult<success_codes::BuildMicromapsEXT> {
+            (self.fp.build_micromaps_ext)(
+                self.handle,
+                deferred_operation,
+                infos.len() as u32,
+                infos.as_ptr(),
+            )
+            .success_code()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMicromapEXT.html>"]
+        #[inline]
+        pub unsafe fn copy_micromap(
+            &self,
+            deferred_operation: vk::DeferredOperationKHR,
+            info: &vk::CopyMicromapInfoEXT<'_>,
+        ) -> VkResult<success_codes::CopyMicromapEXT> {
+            (self.fp.copy_micromap_ext)(self.handle, deferred_operation, info).success_code()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMicromapToMemoryEXT.html>"]
+        #[inline]
+        pub unsafe fn copy_micromap_to_memory(
+            &self,
+            deferred_operation: vk::DeferredOperationKHR,
+            info: &vk::CopyMicromapToMemoryInfoEXT<'_>,
+        ) -> VkResult<success_codes::CopyMicromapToMemoryEXT> {
+            (self.fp.copy_micromap_to_memory_ext)(self.handle, deferred_operation, info)
+                .success_code()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMemoryToMicromapEXT.html>"]
+        #[inline]
+        pub unsafe fn copy_memory_to_micromap(
+            &self,
+            deferred_operation: vk::DeferredOperationKHR,
+            info: &vk::CopyMemoryToMicromapInfoEXT<'_>,
+        ) -> VkResult<success_codes::CopyMemoryToMicromapEXT> {
+            (self.fp.copy_memory_to_micromap_ext)(self.handle, deferred_operation, info)
+                .success_code()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWriteMicromapsPropertiesEXT.html>"]
+        #[inline]
+        pub unsafe fn write_micromaps_properties(
+            &self,
+            micromaps: &[vk::MicromapEXT],
+            query_type: vk::QueryType,
+            data: &mut [u8],
+            stride: usize,
+        ) -> VkResult<()> {
+            (self.fp.write_micromaps_properties_ext)(
+                self.handle,
+                micromaps.len() as u32,
+                micromaps.as_ptr(),
+                query_type,
+                data.len(),
+                data.as_mut_ptr().cast(),
+                stride,
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMicromapEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_copy_micromap(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            info: &vk::CopyMicromapInfoEXT<'_>,
+        ) {
+            (self.fp.cmd_copy_micromap_ext)(command_buffer, info);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMicromapToMemoryEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_copy_micromap_to_memory(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            info: &vk::CopyMicromapToMemoryInfoEXT<'_>,
+        ) {
+            (self.fp.cmd_copy_micromap_to_memory_ext)(command_buffer, info);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToMicromapEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_copy_memory_to_micromap(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            info: &vk::CopyMemoryToMicromapInfoEXT<'_>,
+        ) {
+            (self.fp.cmd_copy_memory_to_micromap_ext)(command_buffer, info);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteMicromapsPropertiesEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_write_micromaps_properties(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            micromaps: &[vk::MicromapEXT],
+            query_type: vk::QueryType,
+            query_pool: vk::QueryPool,
+            first_query: u32,
+        ) {
+            (self.fp.cmd_write_micromaps_properties_ext)(
+                command_buffer,
+                micromaps.len() as u32,
+                micromaps.as_ptr(),
+                query_type,
+                query_pool,
+                first_query,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceMicromapCompatibilityEXT.html>"]
+        #[inline]
+        pub unsafe fn get_device_micromap_compatibility(
+            &self,
+            version_info: &vk::MicromapVersionInfoEXT<'_>,
+        ) -> vk::AccelerationStructureCompatibilityKHR {
+            let mut compatibility: vk::AccelerationStructureCompatibilityKHR = mem::zeroed();
+            (self.fp.get_device_micromap_compatibility_ext)(
+                self.handle,
+                version_info,
+                &mut compatibility,
+            );
+            compatibility
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMicromapBuildSizesEXT.html>"]
+        #[inline]
+        pub unsafe fn get_micromap_build_sizes(
+            &self,
+            build_type: vk::AccelerationStructureBuildTypeKHR,
+            build_info: &vk::MicromapBuildInfoEXT<'_>,
+            size_info: &mut vk::MicromapBuildSizesInfoEXT<'_>,
+        ) {
+            (self.fp.get_micromap_build_sizes_ext)(self.handle, build_type, build_info, size_info);
+        }
+        pub const NAME: &'static CStr = vk::ExtOpacityMicromapFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtOpacityMicromapFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_pageable_device_local_memory.html>"]
+    #[derive(Clone)]
+    pub struct PageableDeviceLocalMemory {
+        handle: vk::Device,
+        fp: vk::ExtPageableDeviceLocalMemoryFn,
+    }
+    impl PageableDeviceLocalMemory {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtPageableDeviceLocalMemoryFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtPageableDeviceLocalMemoryFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetDeviceMemoryPriorityEXT.html>"]
+        #[inline]
+        pub unsafe fn set_device_memory_priority(&self, memory: vk::DeviceMemory, priority: f32) {
+            (self.fp.set_device_memory_priority_ext)(self.handle, memory, priority);
+        }
+        pub const NAME: &'static CStr = vk::ExtPageableDeviceLocalMemoryFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtPageableDeviceLocalMemoryFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_shader_module_identifier.html>"]
+    #[derive(Clone)]
+    pub struct ShaderModuleIdentifier {
+        handle: vk::Device,
+        fp: vk::ExtShaderModuleIdentifierFn,
+    }
+    impl ShaderModuleIdentifier {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtShaderModuleIdentifierFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtShaderModuleIdentifierFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderModuleIdentifierEXT.html>"]
+        #[inline]
+        pub unsafe fn get_shader_module_identifier(
+            &self,
+            shader_module: vk::ShaderModule,
+            identifier: &mut vk::ShaderModuleIdentifierEXT<'_>,
+        ) {
+            (self.fp.get_shader_module_identifier_ext)(self.handle, shader_module, identifier);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderModuleCreateInfoIdentifierEXT.html>"]
+        #[inline]
+        pub unsafe fn get_shader_module_create_info_identifier(
+            &self,
+            create_info: &vk::ShaderModuleCreateInfo<'_>,
+            identifier: &mut vk::ShaderModuleIdentifierEXT<'_>,
+        ) {
+            (self.fp.get_shader_module_create_info_identifier_ext)(
+                self.handle,
+                create_info,
+                identifier,
+            );
+        }
+        pub const NAME: &'static CStr = vk::ExtShaderModuleIdentifierFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtShaderModuleIdentifierFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_attachment_feedback_loop_dynamic_state.html>"]
+    #[derive(Clone)]
+    pub struct AttachmentFeedbackLoopDynamicState {
+        handle: vk::Device,
+        fp: vk::ExtAttachmentFeedbackLoopDynamicStateFn,
+    }
+    impl AttachmentFeedbackLoopDynamicState {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ExtAttachmentFeedbackLoopDynamicStateFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ExtAttachmentFeedbackLoopDynamicStateFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetAttachmentFeedbackLoopEnableEXT.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_attachment_feedback_loop_enable(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            aspect_mask: vk::ImageAspectFlags,
+        ) {
+            (self.fp.cmd_set_attachment_feedback_loop_enable_ext)(command_buffer, aspect_mask);
+        }
+        pub const NAME: &'static CStr = vk::ExtAttachmentFeedbackLoopDynamicStateFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ExtAttachmentFeedbackLoopDynamicStateFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod fuchsia {
+    use crate::prelude::*;
+    use crate::vk;
+    use crate::RawPtr;
+    use crate::{Device, Entry, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_FUCHSIA_imagepipe_surface.html>"]
+    #[derive(Clone)]
+    pub struct ImagepipeSurface {
+        handle: vk::Instance,
+        fp: vk::FuchsiaImagepipeSurfaceFn,
+    }
+    impl ImagepipeSurface {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::FuchsiaImagepipeSurfaceFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::FuchsiaImagepipeSurfaceFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateImagePipeSurfaceFUCHSIA.html>"]
+        #[inline]
+        pub unsafe fn create_image_pipe_surface(
+            &self,
+            create_info: &vk::ImagePipeSurfaceCreateInfoFUCHSIA<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::SurfaceKHR> {
+            let mut surface: vk::SurfaceKHR = mem::zeroed();
+            (self.fp.create_image_pipe_surface_fuchsia)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut surface,
+            )
+            .result_with_success(surface)
+        }
+        pub const NAME: &'static CStr = vk::FuchsiaImagepipeSurfaceFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::FuchsiaImagepipeSurfaceFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_FUCHSIA_external_memory.html>"]
+    #[derive(Clone)]
+    pub struct ExternalMemory {
+        handle: vk::Device,
+        fp: vk::FuchsiaExternalMemoryFn,
+    }
+    impl ExternalMemory {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::FuchsiaExternalMemoryFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::FuchsiaExternalMemoryFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryZirconHandleFUCHSIA.html>"]
+        #[inline]
+        pub unsafe fn get_memory_zircon_handle(
+            &self,
+            get_zircon_handle_info: &vk::MemoryGetZirconHandleInfoFUCHSIA<'_>,
+        ) -> VkResult<vk::zx_handle_t> {
+            let mut zircon_handle: vk::zx_handle_t = mem::zeroed();
+            (self.fp.get_memory_zircon_handle_fuchsia)(
+                self.handle,
+                get_zircon_handle_info,
+                &mut zircon_handle,
+            )
+            .result_with_success(zircon_handle)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryZirconHandlePropertiesFUCHSIA.html>"]
+        #[inline]
+        pub unsafe fn get_memory_zircon_handle_properties(
+            &self,
+            handle_type: vk::ExternalMemoryHandleTypeFlags,
+            zircon_handle: vk::zx_handle_t,
+            memory_zircon_handle_properties: &mut vk::MemoryZirconHandlePropertiesFUCHSIA<'_>,
+        ) -> VkResult<()> {
+            (self.fp.get_memory_zircon_handle_properties_fuchsia)(
+                self.handle,
+                handle_type,
+                zircon_handle,
+                memory_zircon_handle_properties,
+            )
+            .result()
+        }
+        pub const NAME: &'static CStr = vk::FuchsiaExternalMemoryFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::FuchsiaExternalMemoryFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_FUCHSIA_external_semaphore.html>"]
+    #[derive(Clone)]
+    pub struct ExternalSemaphore {
+        handle: vk::Device,
+        fp: vk::FuchsiaExternalSemaphoreFn,
+    }
+    impl ExternalSemaphore {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::FuchsiaExternalSemaphoreFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::FuchsiaExternalSemaphoreFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportSemaphoreZirconHandleFUCHSIA.html>"]
+        #[inline]
+        pub unsafe fn import_semaphore_zircon_handle(
+            &self,
+            import_semaphore_zircon_handle_info: &vk::ImportSemaphoreZirconHandleInfoFUCHSIA<'_>,
+        ) -> VkResult<()> {
+            (self.fp.import_semaphore_zircon_handle_fuchsia)(
+                self.handle,
+                import_semaphore_zircon_handle_info,
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreZirconHandleFUCHSIA.html>"]
+        #[inline]
+        pub unsafe fn get_semaphore_zircon_handle(
+            &self,
+            get_zircon_handle_info: &vk::SemaphoreGetZirconHandleInfoFUCHSIA<'_>,
+        ) -> VkResult<vk::zx_handle_t> {
+            let mut zircon_handle: vk::zx_handle_t = mem::zeroed();
+            (self.fp.get_semaphore_zircon_handle_fuchsia)(
+                self.handle,
+                get_zircon_handle_info,
+                &mut zircon_handle,
+            )
+            .result_with_success(zircon_handle)
+        }
+        pub const NAME: &'static CStr = vk::FuchsiaExternalSemaphoreFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::FuchsiaExternalSemaphoreFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_FUCHSIA_buffer_collection.html>"]
+    #[derive(Clone)]
+    pub struct BufferCollection {
+        handle: vk::Device,
+        fp: vk::FuchsiaBufferCollectionFn,
+    }
+    impl BufferCollection {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::FuchsiaBufferCollectionFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::FuchsiaBufferCollectionFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateBufferCollectionFUCHSIA.html>"]
+        #[inline]
+        pub unsafe fn create_buffer_collection(
+            &self,
+            create_info: &vk::BufferCollectionCreateInfoFUCHSIA<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::BufferCollectionFUCHSIA> {
+            let mut collection: vk::BufferCollectionFUCHSIA = mem::zeroed();
+            (self.fp.create_buffer_collection_fuchsia)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut collection,
+            )
+            .result_with_success(collection)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetBufferCollectionImageConstraintsFUCHSIA.html>"]
+        #[inline]
+        pub unsafe fn set_buffer_collection_image_constraints(
+            &self,
+            collection: vk::BufferCollectionFUCHSIA,
+            image_constraints_info: &vk::ImageConstraintsInfoFUCHSIA<'_>,
+        ) -> VkResult<()> {
+            (self.fp.set_buffer_collection_image_constraints_fuchsia)(
+                self.handle,
+                collection,
+                image_constraints_info,
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetBufferCollectionBufferConstraintsFUCHSIA.html>"]
+        #[inline]
+        pub unsafe fn set_buffer_collection_buffer_constraints(
+            &self,
+            collection: vk::BufferCollectionFUCHSIA,
+            buffer_constraints_info: &vk::BufferConstraintsInfoFUCHSIA<'_>,
+        ) -> VkResult<()> {
+            (self.fp.set_buffer_collection_buffer_constraints_fuchsia)(
+                self.handle,
+                collection,
+                buffer_constraints_info,
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyBufferCollectionFUCHSIA.html>"]
+        #[inline]
+        pub unsafe fn destroy_buffer_collection(
+            &self,
+            collection: vk::BufferCollectionFUCHSIA,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_buffer_collection_fuchsia)(
+                self.handle,
+                collection,
+                allocator.as_raw_ptr(),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferCollectionPropertiesFUCHSIA.html>"]
+        #[inline]
+        pub unsafe fn get_buffer_collection_properties(
+            &self,
+            collection: vk::BufferCollectionFUCHSIA,
+            properties: &mut vk::BufferCollectionPropertiesFUCHSIA<'_>,
+        ) -> VkResult<()> {
+            (self.fp.get_buffer_collection_properties_fuchsia)(self.handle, collection, properties)
+                .result()
+        }
+        pub const NAME: &'static CStr = vk::FuchsiaBufferCollectionFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::FuchsiaBufferCollectionFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod ggp {
+    use crate::prelude::*;
+    use crate::vk;
+    use crate::RawPtr;
+    use crate::{Entry, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_GGP_stream_descriptor_surface.html>"]
+    #[derive(Clone)]
+    pub struct StreamDescriptorSurface {
+        handle: vk::Instance,
+        fp: vk::GgpStreamDescriptorSurfaceFn,
+    }
+    impl StreamDescriptorSurface {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::GgpStreamDescriptorSurfaceFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::GgpStreamDescriptorSurfaceFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateStreamDescriptorSurfaceGGP.html>"]
+        #[inline]
+        pub unsafe fn create_stream_descriptor_surface(
+            &self,
+            create_info: &vk::StreamDescriptorSurfaceCreateInfoGGP<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::SurfaceKHR> {
+            let mut surface: vk::SurfaceKHR = mem::zeroed();
+            (self.fp.create_stream_descriptor_surface_ggp)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut surface,
+            )
+            .result_with_success(surface)
+        }
+        pub const NAME: &'static CStr = vk::GgpStreamDescriptorSurfaceFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::GgpStreamDescriptorSurfaceFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+}
+pub mod google {
+    use crate::prelude::*;
+    use crate::vk;
+    use crate::{Device, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_GOOGLE_display_timing.html>"]
+    #[derive(Clone)]
+    pub struct DisplayTiming {
+        handle: vk::Device,
+        fp: vk::GoogleDisplayTimingFn,
+    }
+    impl DisplayTiming {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::GoogleDisplayTimingFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::GoogleDisplayTimingFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRefreshCycleDurationGOOGLE.html>"]
+        #[inline]
+        pub unsafe fn get_refresh_cycle_duration(
+            &self,
+            swapchain: vk::SwapchainKHR,
+        ) -> VkResult<vk::RefreshCycleDurationGOOGLE> {
+            let mut display_timing_properties: vk::RefreshCycleDurationGOOGLE = mem::zeroed();
+            (self.fp.get_refresh_cycle_duration_google)(
+                self.handle,
+                swapchain,
+                &mut display_timing_properties,
+            )
+            .result_with_success(display_timing_properties)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPastPresentationTimingGOOGLE.html>"]
+        #[inline]
+        pub unsafe fn get_past_presentation_timing(
+            &self,
+            swapchain: vk::SwapchainKHR,
+        ) -> VkResult<Vec<vk::PastPresentationTimingGOOGLE>> {
+            read_into_uninitialized_vector(|count, data| {
+                (self.fp.get_past_presentation_timing_google)(self.handle, swapchain, count, data)
+            })
+        }
+        pub const NAME: &'static CStr = vk::GoogleDisplayTimingFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::GoogleDisplayTimingFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod huawei {
+    use crate::prelude::*;
+    use crate::success_codes;
+    use crate::vk;
+    use crate::{Device, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_HUAWEI_subpass_shading.html>"]
+    #[derive(Clone)]
+    pub struct SubpassShading {
+        handle: vk::Device,
+        fp: vk::HuaweiSubpassShadingFn,
+    }
+    impl SubpassShading {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::HuaweiSubpassShadingFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::HuaweiSubpassShadingFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI.html>"]
+        #[inline]
+        pub unsafe fn get_device_subpass_shading_max_workgroup_size(
+            &self,
+            renderpass: vk::RenderPass,
+        ) -> VkResult<(
+            vk::Extent2D,
+            success_codes::GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI,
+        )> {
+            let mut max_workgroup_size: vk::Extent2D = mem::zeroed();
+            let code = (self.fp.get_device_subpass_shading_max_workgroup_size_huawei)(
+                self.handle,
+                renderpass,
+                &mut max_workgroup_size,
+            )
+            .success_code()?;
+            Ok((max_workgroup_size, code))
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSubpassShadingHUAWEI.html>"]
+        #[inline]
+        pub unsafe fn cmd_subpass_shading(&self, command_buffer: vk::CommandBuffer) {
+            (self.fp.cmd_subpass_shading_huawei)(command_buffer);
+        }
+        pub const NAME: &'static CStr = vk::HuaweiSubpassShadingFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::HuaweiSubpassShadingFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_HUAWEI_invocation_mask.html>"]
+    #[derive(Clone)]
+    pub struct InvocationMask {
+        handle: vk::Device,
+        fp: vk::HuaweiInvocationMaskFn,
+    }
+    impl InvocationMask {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::HuaweiInvocationMaskFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::HuaweiInvocationMaskFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindInvocationMaskHUAWEI.html>"]
+        #[inline]
+        pub unsafe fn cmd_bind_invocation_mask(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            image_view: vk::ImageView,
+            image_layout: vk::ImageLayout,
+        ) {
+            (self.fp.cmd_bind_invocation_mask_huawei)(command_buffer, image_view, image_layout);
+        }
+        pub const NAME: &'static CStr = vk::HuaweiInvocationMaskFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::HuaweiInvocationMaskFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_HUAWEI_cluster_culling_shader.html>"]
+    #[derive(Clone)]
+    pub struct ClusterCullingShader {
+        handle: vk::Device,
+        fp: vk::HuaweiClusterCullingShaderFn,
+    }
+    impl ClusterCullingShader {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::HuaweiClusterCullingShaderFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::HuaweiClusterCullingShaderFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawClusterHUAWEI.html>"]
+        #[inline]
+        pub unsafe fn cmd_draw_cluster(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            group_count_x: u32,
+            group_count_y: u32,
+            group_count_z: u32,
+        ) {
+            (self.fp.cmd_draw_cluster_huawei)(
+                command_buffer,
+                group_count_x,
+                group_count_y,
+                group_count_z,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawClusterIndirectHUAWEI.html>"]
+        #[inline]
+        pub unsafe fn cmd_draw_cluster_indirect(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            buffer: vk::Buffer,
+            offset: vk::DeviceSize,
+        ) {
+            (self.fp.cmd_draw_cluster_indirect_huawei)(command_buffer, buffer, offset);
+        }
+        pub const NAME: &'static CStr = vk::HuaweiClusterCullingShaderFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::HuaweiClusterCullingShaderFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod intel {
+    use crate::prelude::*;
+    use crate::vk;
+    use crate::{Device, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_INTEL_performance_query.html>"]
+    #[derive(Clone)]
+    pub struct PerformanceQuery {
+        handle: vk::Device,
+        fp: vk::IntelPerformanceQueryFn,
+    }
+    impl PerformanceQuery {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::IntelPerformanceQueryFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::IntelPerformanceQueryFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkInitializePerformanceApiINTEL.html>"]
+        #[inline]
+        pub unsafe fn initialize_performance_api(
+            &self,
+            initialize_info: &vk::InitializePerformanceApiInfoINTEL<'_>,
+        ) -> VkResult<()> {
+            (self.fp.initialize_performance_api_intel)(self.handle, initialize_info).result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUninitializePerformanceApiINTEL.html>"]
+        #[inline]
+        pub unsafe fn uninitialize_performance_api(&self) {
+            (self.fp.uninitialize_performance_api_intel)(self.handle);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceMarkerINTEL.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_performance_marker(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            marker_info: &vk::PerformanceMarkerInfoINTEL<'_>,
+        ) -> VkResult<()> {
+            (self.fp.cmd_set_performance_marker_intel)(command_buffer, marker_info).result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceStreamMarkerINTEL.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_performance_stream_marker(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            marker_info: &vk::PerformanceStreamMarkerInfoINTEL<'_>,
+        ) -> VkResult<()> {
+            (self.fp.cmd_set_performance_stream_marker_intel)(command_buffer, marker_info).result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceOverrideINTEL.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_performance_override(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            override_info: &vk::PerformanceOverrideInfoINTEL<'_>,
+        ) -> VkResult<()> {
+            (self.fp.cmd_set_performance_override_intel)(command_buffer, override_info).result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquirePerformanceConfigurationINTEL.html>"]
+        #[inline]
+        pub unsafe fn acquire_performance_configuration(
+            &self,
+            acquire_info: &vk::PerformanceConfigurationAcquireInfoINTEL<'_>,
+        ) -> VkResult<vk::PerformanceConfigurationINTEL> {
+            let mut configuration: vk::PerformanceConfigurationINTEL = mem::zeroed();
+            (self.fp.acquire_performance_configuration_intel)(
+                self.handle,
+                acquire_info,
+                &mut configuration,
+            )
+            .result_with_success(configuration)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleasePerformanceConfigurationINTEL.html>"]
+        #[inline]
+        pub unsafe fn release_performance_configuration(
+            &self,
+            configuration: vk::PerformanceConfigurationINTEL,
+        ) -> VkResult<()> {
+            (self.fp.release_performance_configuration_intel)(self.handle, configuration).result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueSetPerformanceConfigurationINTEL.html>"]
+        #[inline]
+        pub unsafe fn queue_set_performance_configuration(
+            &self,
+            queue: vk::Queue,
+            configuration: vk::PerformanceConfigurationINTEL,
+        ) -> VkResult<()> {
+            (self.fp.queue_set_performance_configuration_intel)(queue, configuration).result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPerformanceParameterINTEL.html>"]
+        #[inline]
+        pub unsafe fn get_performance_parameter(
+            &self,
+            parameter: vk::PerformanceParameterTypeINTEL,
+        ) -> VkResult<vk::PerformanceValueINTEL> {
+            let mut value: vk::PerformanceValueINTEL = mem::zeroed();
+            (self.fp.get_performance_parameter_intel)(self.handle, parameter, &mut value)
+                .result_with_success(value)
+        }
+        pub const NAME: &'static CStr = vk::IntelPerformanceQueryFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::IntelPerformanceQueryFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod khr {
+    use crate::prelude::*;
+    use crate::success_codes;
+    use crate::vk;
+    use crate::RawPtr;
+    use crate::{Device, Entry, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    use std::os::raw::*;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_video_queue.html>"]
+    #[derive(Clone)]
+    pub struct VideoQueue {
+        handle: vk::Device,
+        fp: vk::KhrVideoQueueFn,
+    }
+    impl VideoQueue {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::KhrVideoQueueFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::KhrVideoQueueFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoCapabilitiesKHR.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_video_capabilities(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            video_profile: &vk::VideoProfileInfoKHR<'_>,
+            capabilities: &mut vk::VideoCapabilitiesKHR<'_>,
+        ) -> VkResult<()> {
+            (self.fp.get_physical_device_video_capabilities_khr)(
+                physical_device,
+                video_profile,
+                capabilities,
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoFormatPropertiesKHR.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_video_format_properties(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            video_format_info: &vk::PhysicalDeviceVideoFormatInfoKHR<'_>,
+        ) -> VkResult<Vec<vk::VideoFormatPropertiesKHR<'_>>> {
+            read_into_defaulted_vector(|count, data| {
+                (self.fp.get_physical_device_video_format_properties_khr)(
+                    physical_device,
+                    video_format_info,
+                    count,
+                    data,
+                )
+            })
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateVideoSessionKHR.html>"]
+        #[inline]
+        pub unsafe fn create_video_session(
+            &self,
+            create_info: &vk::VideoSessionCreateInfoKHR<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::VideoSessionKHR> {
+            let mut video_session: vk::VideoSessionKHR = mem::zeroed();
+            (self.fp.create_video_session_khr)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut video_session,
+            )
+            .result_with_success(video_session)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyVideoSessionKHR.html>"]
+        #[inline]
+        pub unsafe fn destroy_video_session(
+            &self,
+            video_session: vk::VideoSessionKHR,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_video_session_khr)(self.handle, video_session, allocator.as_raw_ptr());
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetVideoSessionMemoryRequirementsKHR.html>"]
+        #[inline]
+        pub unsafe fn get_video_session_memory_requirements(
+            &self,
+            video_session: vk::VideoSessionKHR,
+        ) -> VkResult<Vec<vk::VideoSessionMemoryRequirementsKHR<'_>>> {
+            read_into_defaulted_vector(|count, data| {
+                (self.fp.get_video_session_memory_requirements_khr)(
+                    self.handle,
+                    video_session,
+                    count,
+                    data,
+                )
+            })
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindVideoSessionMemoryKHR.html>"]
+        #[inline]
+        pub unsafe fn bind_video_session_memory(
+            &self,
+            video_session: vk::VideoSessionKHR,
+            bind_session_memory_infos: &[vk::BindVideoSessionMemoryInfoKHR<'_>],
+        ) -> VkResult<()> {
+            (self.fp.bind_video_session_memory_khr)(
+                self.handle,
+                video_session,
+                bind_session_memory_infos.len() as u32,
+                bind_session_memory_infos.as_ptr(),
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateVideoSessionParametersKHR.html>"]
+        #[inline]
+        pub unsafe fn create_video_session_parameters(
+            &self,
+            create_info: &vk::VideoSessionParametersCreateInfoKHR<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::VideoSessionParametersKHR> {
+            let mut video_session_parameters: vk::VideoSessionParametersKHR = mem::zeroed();
+            (self.fp.create_video_session_parameters_khr)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut video_session_parameters,
+            )
+            .result_with_success(video_session_parameters)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUpdateVideoSessionParametersKHR.html>"]
+        #[inline]
+        pub unsafe fn update_video_session_parameters(
+            &self,
+            video_session_parameters: vk::VideoSessionParametersKHR,
+            update_info: &vk::VideoSessionParametersUpdateInfoKHR<'_>,
+        ) -> VkResult<()> {
+            (self.fp.update_video_session_parameters_khr)(
+                self.handle,
+                video_session_parameters,
+                update_info,
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyVideoSessionParametersKHR.html>"]
+        #[inline]
+        pub unsafe fn destroy_video_session_parameters(
+            &self,
+            video_session_parameters: vk::VideoSessionParametersKHR,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_video_session_parameters_khr)(
+                self.handle,
+                video_session_parameters,
+                allocator.as_raw_ptr(),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginVideoCodingKHR.html>"]
+        #[inline]
+        pub unsafe fn cmd_begin_video_coding(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            begin_info: &vk::VideoBeginCodingInfoKHR<'_>,
+        ) {
+            (self.fp.cmd_begin_video_coding_khr)(command_buffer, begin_info);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndVideoCodingKHR.html>"]
+        #[inline]
+        pub unsafe fn cmd_end_video_coding(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            end_coding_info: &vk::VideoEndCodingInfoKHR<'_>,
+        ) {
+            (self.fp.cmd_end_video_coding_khr)(command_buffer, end_coding_info);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdControlVideoCodingKHR.html>"]
+        #[inline]
+        pub unsafe fn cmd_control_video_coding(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            coding_control_info: &vk::VideoCodingControlInfoKHR<'_>,
+        ) {
+            (self.fp.cmd_control_video_coding_khr)(command_buffer, coding_control_info);
+        }
+        pub const NAME: &'static CStr = vk::KhrVideoQueueFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrVideoQueueFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_video_decode_queue.html>"]
+    #[derive(Clone)]
+    pub struct VideoDecodeQueue {
+        handle: vk::Device,
+        fp: vk::KhrVideoDecodeQueueFn,
+    }
+    impl VideoDecodeQueue {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::KhrVideoDecodeQueueFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::KhrVideoDecodeQueueFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecodeVideoKHR.html>"]
+        #[inline]
+        pub unsafe fn cmd_decode_video(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            decode_info: &vk::VideoDecodeInfoKHR<'_>,
+        ) {
+            (self.fp.cmd_decode_video_khr)(command_buffer, decode_info);
+        }
+        pub const NAME: &'static CStr = vk::KhrVideoDecodeQueueFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrVideoDecodeQueueFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_external_memory_capabilities.html>"]
+    #[derive(Clone)]
+    pub struct ExternalMemoryCapabilities {
+        handle: vk::Instance,
+        fp: vk::KhrExternalMemoryCapabilitiesFn,
+    }
+    impl ExternalMemoryCapabilities {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::KhrExternalMemoryCapabilitiesFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::KhrExternalMemoryCapabilitiesFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalBufferPropertiesKHR.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_external_buffer_properties(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            external_buffer_info: &vk::PhysicalDeviceExternalBufferInfo<'_>,
+            external_buffer_properties: &mut vk::ExternalBufferProperties<'_>,
+        ) {
+            (self.fp.get_physical_device_external_buffer_properties_khr)(
+                physical_device,
+                external_buffer_info,
+                external_buffer_properties,
+            );
+        }
+        pub const NAME: &'static CStr = vk::KhrExternalMemoryCapabilitiesFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrExternalMemoryCapabilitiesFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_external_semaphore_capabilities.html>"]
+    #[derive(Clone)]
+    pub struct ExternalSemaphoreCapabilities {
+        handle: vk::Instance,
+        fp: vk::KhrExternalSemaphoreCapabilitiesFn,
+    }
+    impl ExternalSemaphoreCapabilities {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::KhrExternalSemaphoreCapabilitiesFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::KhrExternalSemaphoreCapabilitiesFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphorePropertiesKHR.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_external_semaphore_properties(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            external_semaphore_info: &vk::PhysicalDeviceExternalSemaphoreInfo<'_>,
+            external_semaphore_properties: &mut vk::ExternalSemaphoreProperties<'_>,
+        ) {
+            (self
+                .fp
+                .get_physical_device_external_semaphore_properties_khr)(
+                physical_device,
+                external_semaphore_info,
+                external_semaphore_properties,
+            );
+        }
+        pub const NAME: &'static CStr = vk::KhrExternalSemaphoreCapabilitiesFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrExternalSemaphoreCapabilitiesFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_descriptor_update_template.html>"]
+    #[derive(Clone)]
+    pub struct DescriptorUpdateTemplate {
+        handle: vk::Device,
+        fp: vk::KhrDescriptorUpdateTemplateFn,
+    }
+    impl DescriptorUpdateTemplate {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::KhrDescriptorUpdateTemplateFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::KhrDescriptorUpdateTemplateFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDescriptorUpdateTemplateKHR.html>"]
+        #[inline]
+        pub unsafe fn create_descriptor_update_template(
+            &self,
+            create_info: &vk::DescriptorUpdateTemplateCreateInfo<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::DescriptorUpdateTemplate> {
+            let mut descriptor_update_template: vk::DescriptorUpdateTemplate = mem::zeroed();
+            (self.fp.create_descriptor_update_template_khr)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut descriptor_update_template,
+            )
+            .result_with_success(descriptor_update_template)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDescriptorUpdateTemplateKHR.html>"]
+        #[inline]
+        pub unsafe fn destroy_descriptor_update_template(
+            &self,
+            descriptor_update_template: vk::DescriptorUpdateTemplate,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_descriptor_update_template_khr)(
+                self.handle,
+                descriptor_update_template,
+                allocator.as_raw_ptr(),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUpdateDescriptorSetWithTemplateKHR.html>"]
+        #[inline]
+        pub unsafe fn update_descriptor_set_with_template(
+            &self,
+            descriptor_set: vk::DescriptorSet,
+            descriptor_update_template: vk::DescriptorUpdateTemplate,
+            data: *const c_void,
+        ) {
+            (self.fp.update_descriptor_set_with_template_khr)(
+                self.handle,
+                descriptor_set,
+                descriptor_update_template,
+                data,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushDescriptorSetWithTemplateKHR.html>"]
+        #[inline]
+        pub unsafe fn cmd_push_descriptor_set_with_template(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            descriptor_update_template: vk::DescriptorUpdateTemplate,
+            layout: vk::PipelineLayout,
+            set: u32,
+            data: *const c_void,
+        ) {
+            (self.fp.cmd_push_descriptor_set_with_template_khr)(
+                command_buffer,
+                descriptor_update_template,
+                layout,
+                set,
+                data,
+            );
+        }
+        pub const NAME: &'static CStr = vk::KhrDescriptorUpdateTemplateFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrDescriptorUpdateTemplateFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_shared_presentable_image.html>"]
+    #[derive(Clone)]
+    pub struct SharedPresentableImage {
+        handle: vk::Device,
+        fp: vk::KhrSharedPresentableImageFn,
+    }
+    impl SharedPresentableImage {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::KhrSharedPresentableImageFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::KhrSharedPresentableImageFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainStatusKHR.html>"]
+        #[inline]
+        pub unsafe fn get_swapchain_status(
+            &self,
+            swapchain: vk::SwapchainKHR,
+        ) -> VkResult<success_codes::GetSwapchainStatusKHR> {
+            (self.fp.get_swapchain_status_khr)(self.handle, swapchain).success_code()
+        }
+        pub const NAME: &'static CStr = vk::KhrSharedPresentableImageFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrSharedPresentableImageFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_external_fence_capabilities.html>"]
+    #[derive(Clone)]
+    pub struct ExternalFenceCapabilities {
+        handle: vk::Instance,
+        fp: vk::KhrExternalFenceCapabilitiesFn,
+    }
+    impl ExternalFenceCapabilities {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::KhrExternalFenceCapabilitiesFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::KhrExternalFenceCapabilitiesFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFencePropertiesKHR.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_external_fence_properties(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            external_fence_info: &vk::PhysicalDeviceExternalFenceInfo<'_>,
+            external_fence_properties: &mut vk::ExternalFenceProperties<'_>,
+        ) {
+            (self.fp.get_physical_device_external_fence_properties_khr)(
+                physical_device,
+                external_fence_info,
+                external_fence_properties,
+            );
+        }
+        pub const NAME: &'static CStr = vk::KhrExternalFenceCapabilitiesFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrExternalFenceCapabilitiesFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_get_display_properties2.html>"]
+    #[derive(Clone)]
+    pub struct GetDisplayProperties2 {
+        handle: vk::Instance,
+        fp: vk::KhrGetDisplayProperties2Fn,
+    }
+    impl GetDisplayProperties2 {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::KhrGetDisplayProperties2Fn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::KhrGetDisplayProperties2Fn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayProperties2KHR.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_display_properties2(
+            &self,
+            physical_device: vk::PhysicalDevice,
+        ) -> VkResult<Vec<vk::DisplayProperties2KHR<'_>>> {
+            read_into_defaulted_vector(|count, data| {
+                (self.fp.get_physical_device_display_properties2_khr)(physical_device, count, data)
+            })
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayPlaneProperties2KHR.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_display_plane_properties2(
+            &self,
+            physical_device: vk::PhysicalDevice,
+        ) -> VkResult<Vec<vk::DisplayPlaneProperties2KHR<'_>>> {
+            read_into_defaulted_vector(|count, data| {
+                (self.fp.get_physical_device_display_plane_properties2_khr)(
+                    physical_device,
+                    count,
+                    data,
+                )
+            })
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayModeProperties2KHR.html>"]
+        #[inline]
+        pub unsafe fn get_display_mode_properties2(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            display: vk::DisplayKHR,
+        ) -> VkResult<Vec<vk::DisplayModeProperties2KHR<'_>>> {
+            read_into_defaulted_vector(|count, data| {
+                (self.fp.get_display_mode_properties2_khr)(physical_device, display, count, data)
+            })
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayPlaneCapabilities2KHR.html>"]
+        #[inline]
+        pub unsafe fn get_display_plane_capabilities2(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            display_plane_info: &vk::DisplayPlaneInfo2KHR<'_>,
+            capabilities: &mut vk::DisplayPlaneCapabilities2KHR<'_>,
+        ) -> VkResult<()> {
+            (self.fp.get_display_plane_capabilities2_khr)(
+                physical_device,
+                display_plane_info,
+                capabilities,
+            )
+            .result()
+        }
+        pub const NAME: &'static CStr = vk::KhrGetDisplayProperties2Fn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrGetDisplayProperties2Fn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_sampler_ycbcr_conversion.html>"]
+    #[derive(Clone)]
+    pub struct SamplerYcbcrConversion {
+        handle: vk::Device,
+        fp: vk::KhrSamplerYcbcrConversionFn,
+    }
+    impl SamplerYcbcrConversion {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::KhrSamplerYcbcrConversionFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::KhrSamplerYcbcrConversionFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSamplerYcbcrConversionKHR.html>"]
+        #[inline]
+        pub unsafe fn create_sampler_ycbcr_conversion(
+            &self,
+            create_info: &vk::SamplerYcbcrConversionCreateInfo<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::SamplerYcbcrConversion> {
+            let mut ycbcr_conversion: vk::SamplerYcbcrConversion = mem::zeroed();
+            (self.fp.create_sampler_ycbcr_conversion_khr)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut ycbcr_conversion,
+            )
+            .result_with_success(ycbcr_conversion)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySamplerYcbcrConversionKHR.html>"]
+        #[inline]
+        pub unsafe fn destroy_sampler_ycbcr_conversion(
+            &self,
+            ycbcr_conversion: vk::SamplerYcbcrConversion,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_sampler_ycbcr_conversion_khr)(
+                self.handle,
+                ycbcr_conversion,
+                allocator.as_raw_ptr(),
+            );
+        }
+        pub const NAME: &'static CStr = vk::KhrSamplerYcbcrConversionFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrSamplerYcbcrConversionFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_bind_memory2.html>"]
+    #[derive(Clone)]
+    pub struct BindMemory2 {
+        handle: vk::Device,
+        fp: vk::KhrBindMemory2Fn,
+    }
+    impl BindMemory2 {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::KhrBindMemory2Fn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::KhrBindMemory2Fn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindBufferMemory2KHR.html>"]
+        #[inline]
+        pub unsafe fn bind_buffer_memory2(
+            &self,
+            bind_infos: &[vk::BindBufferMemoryInfo<'_>],
+        ) -> VkResult<()> {
+            (self.fp.bind_buffer_memory2_khr)(
+                self.handle,
+                bind_infos.len() as u32,
+                bind_infos.as_ptr(),
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindImageMemory2KHR.html>"]
+        #[inline]
+        pub unsafe fn bind_image_memory2(
+            &self,
+            bind_infos: &[vk::BindImageMemoryInfo<'_>],
+        ) -> VkResult<()> {
+            (self.fp.bind_image_memory2_khr)(
+                self.handle,
+                bind_infos.len() as u32,
+                bind_infos.as_ptr(),
+            )
+            .result()
+        }
+        pub const NAME: &'static CStr = vk::KhrBindMemory2Fn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrBindMemory2Fn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_fragment_shading_rate.html>"]
+    #[derive(Clone)]
+    pub struct FragmentShadingRate {
+        handle: vk::Device,
+        fp: vk::KhrFragmentShadingRateFn,
+    }
+    impl FragmentShadingRate {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::KhrFragmentShadingRateFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::KhrFragmentShadingRateFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFragmentShadingRatesKHR.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_fragment_shading_rates(
+            &self,
+            physical_device: vk::PhysicalDevice,
+        ) -> VkResult<Vec<vk::PhysicalDeviceFragmentShadingRateKHR<'_>>> {
+            read_into_defaulted_vector(|count, data| {
+                (self.fp.get_physical_device_fragment_shading_rates_khr)(
+                    physical_device,
+                    count,
+                    data,
+                )
+            })
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFragmentShadingRateKHR.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_fragment_shading_rate(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            fragment_size: &vk::Extent2D,
+            combiner_ops: &[vk::FragmentShadingRateCombinerOpKHR; 2usize],
+        ) {
+            (self.fp.cmd_set_fragment_shading_rate_khr)(
+                command_buffer,
+                fragment_size,
+                combiner_ops,
+            );
+        }
+        pub const NAME: &'static CStr = vk::KhrFragmentShadingRateFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrFragmentShadingRateFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_map_memory2.html>"]
+    #[derive(Clone)]
+    pub struct MapMemory2 {
+        handle: vk::Device,
+        fp: vk::KhrMapMemory2Fn,
+    }
+    impl MapMemory2 {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::KhrMapMemory2Fn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::KhrMapMemory2Fn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkMapMemory2KHR.html>"]
+        #[inline]
+        pub unsafe fn map_memory2(
+            &self,
+            memory_map_info: &vk::MemoryMapInfoKHR<'_>,
+        ) -> VkResult<*mut c_void> {
+            let mut data: *mut c_void = mem::zeroed();
+            (self.fp.map_memory2_khr)(self.handle, memory_map_info, &mut data)
+                .result_with_success(data)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUnmapMemory2KHR.html>"]
+        #[inline]
+        pub unsafe fn unmap_memory2(
+            &self,
+            memory_unmap_info: &vk::MemoryUnmapInfoKHR<'_>,
+        ) -> VkResult<()> {
+            (self.fp.unmap_memory2_khr)(self.handle, memory_unmap_info).result()
+        }
+        pub const NAME: &'static CStr = vk::KhrMapMemory2Fn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrMapMemory2Fn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_video_encode_queue.html>"]
+    #[derive(Clone)]
+    pub struct VideoEncodeQueue {
+        handle: vk::Device,
+        fp: vk::KhrVideoEncodeQueueFn,
+    }
+    impl VideoEncodeQueue {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::KhrVideoEncodeQueueFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::KhrVideoEncodeQueueFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_video_encode_quality_level_properties(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            quality_level_info: &vk::PhysicalDeviceVideoEncodeQualityLevelInfoKHR<'_>,
+            quality_level_properties: &mut vk::VideoEncodeQualityLevelPropertiesKHR<'_>,
+        ) -> VkResult<()> {
+            (self
+                .fp
+                .get_physical_device_video_encode_quality_level_properties_khr)(
+                physical_device,
+                quality_level_info,
+                quality_level_properties,
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetEncodedVideoSessionParametersKHR.html>"]
+        #[inline]
+        pub unsafe fn get_encoded_video_session_parameters(
+            &self,
+            video_session_parameters_info: &vk::VideoEncodeSessionParametersGetInfoKHR<'_>,
+            feedback_info: &mut vk::VideoEncodeSessionParametersFeedbackInfoKHR<'_>,
+        ) -> VkResult<Vec<u8>> {
+            let feedback_info: *mut _ = feedback_info;
+            read_into_uninitialized_vector(|count, data: *mut u8| {
+                (self.fp.get_encoded_video_session_parameters_khr)(
+                    self.handle,
+                    video_session_parameters_info,
+                    feedback_info,
+                    count,
+                    data.cast(),
+                )
+            })
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEncodeVideoKHR.html>"]
+        #[inline]
+        pub unsafe fn cmd_encode_video(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            encode_info: &vk::VideoEncodeInfoKHR<'_>,
+        ) {
+            (self.fp.cmd_encode_video_khr)(command_buffer, encode_info);
+        }
+        pub const NAME: &'static CStr = vk::KhrVideoEncodeQueueFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::KhrVideoEncodeQueueFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod nv {
+    use crate::prelude::*;
+    use crate::vk;
+    use crate::RawPtr;
+    use crate::{Device, Entry, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_external_memory_capabilities.html>"]
+    #[derive(Clone)]
+    pub struct ExternalMemoryCapabilities {
+        handle: vk::Instance,
+        fp: vk::NvExternalMemoryCapabilitiesFn,
+    }
+    impl ExternalMemoryCapabilities {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::NvExternalMemoryCapabilitiesFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::NvExternalMemoryCapabilitiesFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalImageFormatPropertiesNV.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_external_image_format_properties(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            format: vk::Format,
+            ty: vk::ImageType,
+            tiling: vk::ImageTiling,
+            usage: vk::ImageUsageFlags,
+            flags: vk::ImageCreateFlags,
+            external_handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+        ) -> VkResult<vk::ExternalImageFormatPropertiesNV> {
+            let mut external_image_format_properties: vk::ExternalImageFormatPropertiesNV =
+                mem::zeroed();
+            (self
+                .fp
+                .get_physical_device_external_image_format_properties_nv)(
+                physical_device,
+                format,
+                ty,
+                tiling,
+                usage,
+                flags,
+                external_handle_type,
+                &mut external_image_format_properties,
+            )
+            .result_with_success(external_image_format_properties)
+        }
+        pub const NAME: &'static CStr = vk::NvExternalMemoryCapabilitiesFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvExternalMemoryCapabilitiesFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_external_memory_win32.html>"]
+    #[derive(Clone)]
+    pub struct ExternalMemoryWin32 {
+        handle: vk::Device,
+        fp: vk::NvExternalMemoryWin32Fn,
+    }
+    impl ExternalMemoryWin32 {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvExternalMemoryWin32Fn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvExternalMemoryWin32Fn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandleNV.html>"]
+        #[inline]
+        pub unsafe fn get_memory_win32_handle(
+            &self,
+            memory: vk::DeviceMemory,
+            handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+        ) -> VkResult<vk::HANDLE> {
+            let mut handle: vk::HANDLE = mem::zeroed();
+            (self.fp.get_memory_win32_handle_nv)(self.handle, memory, handle_type, &mut handle)
+                .result_with_success(handle)
+        }
+        pub const NAME: &'static CStr = vk::NvExternalMemoryWin32Fn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvExternalMemoryWin32Fn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_clip_space_w_scaling.html>"]
+    #[derive(Clone)]
+    pub struct ClipSpaceWScaling {
+        handle: vk::Device,
+        fp: vk::NvClipSpaceWScalingFn,
+    }
+    impl ClipSpaceWScaling {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvClipSpaceWScalingFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvClipSpaceWScalingFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWScalingNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_viewport_w_scaling(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            first_viewport: u32,
+            viewport_w_scalings: &[vk::ViewportWScalingNV],
+        ) {
+            (self.fp.cmd_set_viewport_w_scaling_nv)(
+                command_buffer,
+                first_viewport,
+                viewport_w_scalings.len() as u32,
+                viewport_w_scalings.as_ptr(),
+            );
+        }
+        pub const NAME: &'static CStr = vk::NvClipSpaceWScalingFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvClipSpaceWScalingFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_shading_rate_image.html>"]
+    #[derive(Clone)]
+    pub struct ShadingRateImage {
+        handle: vk::Device,
+        fp: vk::NvShadingRateImageFn,
+    }
+    impl ShadingRateImage {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvShadingRateImageFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvShadingRateImageFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindShadingRateImageNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_bind_shading_rate_image(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            image_view: vk::ImageView,
+            image_layout: vk::ImageLayout,
+        ) {
+            (self.fp.cmd_bind_shading_rate_image_nv)(command_buffer, image_view, image_layout);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportShadingRatePaletteNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_viewport_shading_rate_palette(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            first_viewport: u32,
+            shading_rate_palettes: &[vk::ShadingRatePaletteNV<'_>],
+        ) {
+            (self.fp.cmd_set_viewport_shading_rate_palette_nv)(
+                command_buffer,
+                first_viewport,
+                shading_rate_palettes.len() as u32,
+                shading_rate_palettes.as_ptr(),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoarseSampleOrderNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_coarse_sample_order(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            sample_order_type: vk::CoarseSampleOrderTypeNV,
+            custom_sample_orders: &[vk::CoarseSampleOrderCustomNV<'_>],
+        ) {
+            (self.fp.cmd_set_coarse_sample_order_nv)(
+                command_buffer,
+                sample_order_type,
+                custom_sample_orders.len() as u32,
+                custom_sample_orders.as_ptr(),
+            );
+        }
+        pub const NAME: &'static CStr = vk::NvShadingRateImageFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvShadingRateImageFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_scissor_exclusive.html>"]
+    #[derive(Clone)]
+    pub struct ScissorExclusive {
+        handle: vk::Device,
+        fp: vk::NvScissorExclusiveFn,
+    }
+    impl ScissorExclusive {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvScissorExclusiveFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvScissorExclusiveFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExclusiveScissorEnableNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_exclusive_scissor_enable(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            first_exclusive_scissor: u32,
+            exclusive_scissor_enables: &[vk::Bool32],
+        ) {
+            (self.fp.cmd_set_exclusive_scissor_enable_nv)(
+                command_buffer,
+                first_exclusive_scissor,
+                exclusive_scissor_enables.len() as u32,
+                exclusive_scissor_enables.as_ptr(),
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExclusiveScissorNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_exclusive_scissor(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            first_exclusive_scissor: u32,
+            exclusive_scissors: &[vk::Rect2D],
+        ) {
+            (self.fp.cmd_set_exclusive_scissor_nv)(
+                command_buffer,
+                first_exclusive_scissor,
+                exclusive_scissors.len() as u32,
+                exclusive_scissors.as_ptr(),
+            );
+        }
+        pub const NAME: &'static CStr = vk::NvScissorExclusiveFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvScissorExclusiveFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_cooperative_matrix.html>"]
+    #[derive(Clone)]
+    pub struct CooperativeMatrix {
+        handle: vk::Device,
+        fp: vk::NvCooperativeMatrixFn,
+    }
+    impl CooperativeMatrix {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvCooperativeMatrixFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvCooperativeMatrixFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceCooperativeMatrixPropertiesNV.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_cooperative_matrix_properties(
+            &self,
+            physical_device: vk::PhysicalDevice,
+        ) -> VkResult<Vec<vk::CooperativeMatrixPropertiesNV<'_>>> {
+            read_into_defaulted_vector(|count, data| {
+                (self.fp.get_physical_device_cooperative_matrix_properties_nv)(
+                    physical_device,
+                    count,
+                    data,
+                )
+            })
+        }
+        pub const NAME: &'static CStr = vk::NvCooperativeMatrixFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvCooperativeMatrixFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_device_generated_commands.html>"]
+    #[derive(Clone)]
+    pub struct DeviceGeneratedCommands {
+        handle: vk::Device,
+        fp: vk::NvDeviceGeneratedCommandsFn,
+    }
+    impl DeviceGeneratedCommands {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvDeviceGeneratedCommandsFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvDeviceGeneratedCommandsFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetGeneratedCommandsMemoryRequirementsNV.html>"]
+        #[inline]
+        pub unsafe fn get_generated_commands_memory_requirements(
+            &self,
+            info: &vk::GeneratedCommandsMemoryRequirementsInfoNV<'_>,
+            memory_requirements: &mut vk::MemoryRequirements2<'_>,
+        ) {
+            (self.fp.get_generated_commands_memory_requirements_nv)(
+                self.handle,
+                info,
+                memory_requirements,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPreprocessGeneratedCommandsNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_preprocess_generated_commands(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            generated_commands_info: &vk::GeneratedCommandsInfoNV<'_>,
+        ) {
+            (self.fp.cmd_preprocess_generated_commands_nv)(command_buffer, generated_commands_info);
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdExecuteGeneratedCommandsNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_execute_generated_commands(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            is_preprocessed: bool,
+            generated_commands_info: &vk::GeneratedCommandsInfoNV<'_>,
+        ) {
+            (self.fp.cmd_execute_generated_commands_nv)(
+                command_buffer,
+                is_preprocessed.into(),
+                generated_commands_info,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindPipelineShaderGroupNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_bind_pipeline_shader_group(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            pipeline_bind_point: vk::PipelineBindPoint,
+            pipeline: vk::Pipeline,
+            group_index: u32,
+        ) {
+            (self.fp.cmd_bind_pipeline_shader_group_nv)(
+                command_buffer,
+                pipeline_bind_point,
+                pipeline,
+                group_index,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateIndirectCommandsLayoutNV.html>"]
+        #[inline]
+        pub unsafe fn create_indirect_commands_layout(
+            &self,
+            create_info: &vk::IndirectCommandsLayoutCreateInfoNV<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::IndirectCommandsLayoutNV> {
+            let mut indirect_commands_layout: vk::IndirectCommandsLayoutNV = mem::zeroed();
+            (self.fp.create_indirect_commands_layout_nv)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut indirect_commands_layout,
+            )
+            .result_with_success(indirect_commands_layout)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyIndirectCommandsLayoutNV.html>"]
+        #[inline]
+        pub unsafe fn destroy_indirect_commands_layout(
+            &self,
+            indirect_commands_layout: vk::IndirectCommandsLayoutNV,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_indirect_commands_layout_nv)(
+                self.handle,
+                indirect_commands_layout,
+                allocator.as_raw_ptr(),
+            );
+        }
+        pub const NAME: &'static CStr = vk::NvDeviceGeneratedCommandsFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvDeviceGeneratedCommandsFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_fragment_shading_rate_enums.html>"]
+    #[derive(Clone)]
+    pub struct FragmentShadingRateEnums {
+        handle: vk::Device,
+        fp: vk::NvFragmentShadingRateEnumsFn,
+    }
+    impl FragmentShadingRateEnums {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvFragmentShadingRateEnumsFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvFragmentShadingRateEnumsFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFragmentShadingRateEnumNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_set_fragment_shading_rate_enum(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            shading_rate: vk::FragmentShadingRateNV,
+            combiner_ops: &[vk::FragmentShadingRateCombinerOpKHR; 2usize],
+        ) {
+            (self.fp.cmd_set_fragment_shading_rate_enum_nv)(
+                command_buffer,
+                shading_rate,
+                combiner_ops,
+            );
+        }
+        pub const NAME: &'static CStr = vk::NvFragmentShadingRateEnumsFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvFragmentShadingRateEnumsFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_acquire_winrt_display.html>"]
+    #[derive(Clone)]
+    pub struct AcquireWinrtDisplay {
+        handle: vk::Device,
+        fp: vk::NvAcquireWinrtDisplayFn,
+    }
+    impl AcquireWinrtDisplay {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvAcquireWinrtDisplayFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvAcquireWinrtDisplayFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireWinrtDisplayNV.html>"]
+        #[inline]
+        pub unsafe fn acquire_winrt_display(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            display: vk::DisplayKHR,
+        ) -> VkResult<()> {
+            (self.fp.acquire_winrt_display_nv)(physical_device, display).result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetWinrtDisplayNV.html>"]
+        #[inline]
+        pub unsafe fn get_winrt_display(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            device_relative_id: u32,
+        ) -> VkResult<vk::DisplayKHR> {
+            let mut display: vk::DisplayKHR = mem::zeroed();
+            (self.fp.get_winrt_display_nv)(physical_device, device_relative_id, &mut display)
+                .result_with_success(display)
+        }
+        pub const NAME: &'static CStr = vk::NvAcquireWinrtDisplayFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvAcquireWinrtDisplayFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_external_memory_rdma.html>"]
+    #[derive(Clone)]
+    pub struct ExternalMemoryRdma {
+        handle: vk::Device,
+        fp: vk::NvExternalMemoryRdmaFn,
+    }
+    impl ExternalMemoryRdma {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvExternalMemoryRdmaFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvExternalMemoryRdmaFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryRemoteAddressNV.html>"]
+        #[inline]
+        pub unsafe fn get_memory_remote_address(
+            &self,
+            memory_get_remote_address_info: &vk::MemoryGetRemoteAddressInfoNV<'_>,
+        ) -> VkResult<vk::RemoteAddressNV> {
+            let mut address: vk::RemoteAddressNV = mem::zeroed();
+            (self.fp.get_memory_remote_address_nv)(
+                self.handle,
+                memory_get_remote_address_info,
+                &mut address,
+            )
+            .result_with_success(address)
+        }
+        pub const NAME: &'static CStr = vk::NvExternalMemoryRdmaFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvExternalMemoryRdmaFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_copy_memory_indirect.html>"]
+    #[derive(Clone)]
+    pub struct CopyMemoryIndirect {
+        handle: vk::Device,
+        fp: vk::NvCopyMemoryIndirectFn,
+    }
+    impl CopyMemoryIndirect {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvCopyMemoryIndirectFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvCopyMemoryIndirectFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryIndirectNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_copy_memory_indirect(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            copy_buffer_address: vk::DeviceAddress,
+            copy_count: u32,
+            stride: u32,
+        ) {
+            (self.fp.cmd_copy_memory_indirect_nv)(
+                command_buffer,
+                copy_buffer_address,
+                copy_count,
+                stride,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToImageIndirectNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_copy_memory_to_image_indirect(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            copy_buffer_address: vk::DeviceAddress,
+            stride: u32,
+            dst_image: vk::Image,
+            dst_image_layout: vk::ImageLayout,
+            image_subresources: &[vk::ImageSubresourceLayers],
+        ) {
+            (self.fp.cmd_copy_memory_to_image_indirect_nv)(
+                command_buffer,
+                copy_buffer_address,
+                image_subresources.len() as u32,
+                stride,
+                dst_image,
+                dst_image_layout,
+                image_subresources.as_ptr(),
+            );
+        }
+        pub const NAME: &'static CStr = vk::NvCopyMemoryIndirectFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvCopyMemoryIndirectFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NV_optical_flow.html>"]
+    #[derive(Clone)]
+    pub struct OpticalFlow {
+        handle: vk::Device,
+        fp: vk::NvOpticalFlowFn,
+    }
+    impl OpticalFlow {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvOpticalFlowFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvOpticalFlowFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceOpticalFlowImageFormatsNV.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_optical_flow_image_formats(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            optical_flow_image_format_info: &vk::OpticalFlowImageFormatInfoNV<'_>,
+        ) -> VkResult<Vec<vk::OpticalFlowImageFormatPropertiesNV<'_>>> {
+            read_into_defaulted_vector(|count, data| {
+                (self.fp.get_physical_device_optical_flow_image_formats_nv)(
+                    physical_device,
+                    optical_flow_image_format_info,
+                    count,
+                    data,
+                )
+            })
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateOpticalFlowSessionNV.html>"]
+        #[inline]
+        pub unsafe fn create_optical_flow_session(
+            &self,
+            create_info: &vk::OpticalFlowSessionCreateInfoNV<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::OpticalFlowSessionNV> {
+            let mut session: vk::OpticalFlowSessionNV = mem::zeroed();
+            (self.fp.create_optical_flow_session_nv)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut session,
+            )
+            .result_with_success(session)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyOpticalFlowSessionNV.html>"]
+        #[inline]
+        pub unsafe fn destroy_optical_flow_session(
+            &self,
+            session: vk::OpticalFlowSessionNV,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_optical_flow_session_nv)(self.handle, session, allocator.as_raw_ptr());
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindOpticalFlowSessionImageNV.html>"]
+        #[inline]
+        pub unsafe fn bind_optical_flow_session_image(
+            &self,
+            session: vk::OpticalFlowSessionNV,
+            binding_point: vk::OpticalFlowSessionBindingPointNV,
+            view: vk::ImageView,
+            layout: vk::ImageLayout,
+        ) -> VkResult<()> {
+            (self.fp.bind_optical_flow_session_image_nv)(
+                self.handle,
+                session,
+                binding_point,
+                view,
+                layout,
+            )
+            .result()
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdOpticalFlowExecuteNV.html>"]
+        #[inline]
+        pub unsafe fn cmd_optical_flow_execute(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            session: vk::OpticalFlowSessionNV,
+            execute_info: &vk::OpticalFlowExecuteInfoNV<'_>,
+        ) {
+            (self.fp.cmd_optical_flow_execute_nv)(command_buffer, session, execute_info);
+        }
+        pub const NAME: &'static CStr = vk::NvOpticalFlowFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvOpticalFlowFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod nvx {
+    use crate::prelude::*;
+    use crate::vk;
+    use crate::RawPtr;
+    use crate::{Device, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NVX_binary_import.html>"]
+    #[derive(Clone)]
+    pub struct BinaryImport {
+        handle: vk::Device,
+        fp: vk::NvxBinaryImportFn,
+    }
+    impl BinaryImport {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvxBinaryImportFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvxBinaryImportFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCuModuleNVX.html>"]
+        #[inline]
+        pub unsafe fn create_cu_module(
+            &self,
+            create_info: &vk::CuModuleCreateInfoNVX<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::CuModuleNVX> {
+            let mut module: vk::CuModuleNVX = mem::zeroed();
+            (self.fp.create_cu_module_nvx)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut module,
+            )
+            .result_with_success(module)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCuFunctionNVX.html>"]
+        #[inline]
+        pub unsafe fn create_cu_function(
+            &self,
+            create_info: &vk::CuFunctionCreateInfoNVX<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::CuFunctionNVX> {
+            let mut function: vk::CuFunctionNVX = mem::zeroed();
+            (self.fp.create_cu_function_nvx)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut function,
+            )
+            .result_with_success(function)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCuModuleNVX.html>"]
+        #[inline]
+        pub unsafe fn destroy_cu_module(
+            &self,
+            module: vk::CuModuleNVX,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_cu_module_nvx)(self.handle, module, allocator.as_raw_ptr());
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCuFunctionNVX.html>"]
+        #[inline]
+        pub unsafe fn destroy_cu_function(
+            &self,
+            function: vk::CuFunctionNVX,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) {
+            (self.fp.destroy_cu_function_nvx)(self.handle, function, allocator.as_raw_ptr());
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCuLaunchKernelNVX.html>"]
+        #[inline]
+        pub unsafe fn cmd_cu_launch_kernel(
+            &self,
+            command_buffer: vk::CommandBuffer,
+            launch_info: &vk::CuLaunchInfoNVX<'_>,
+        ) {
+            (self.fp.cmd_cu_launch_kernel_nvx)(command_buffer, launch_info);
+        }
+        pub const NAME: &'static CStr = vk::NvxBinaryImportFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvxBinaryImportFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_NVX_image_view_handle.html>"]
+    #[derive(Clone)]
+    pub struct ImageViewHandle {
+        handle: vk::Device,
+        fp: vk::NvxImageViewHandleFn,
+    }
+    impl ImageViewHandle {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::NvxImageViewHandleFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::NvxImageViewHandleFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewHandleNVX.html>"]
+        #[inline]
+        pub unsafe fn get_image_view_handle(&self, info: &vk::ImageViewHandleInfoNVX<'_>) -> u32 {
+            (self.fp.get_image_view_handle_nvx)(self.handle, info)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewAddressNVX.html>"]
+        #[inline]
+        pub unsafe fn get_image_view_address(
+            &self,
+            image_view: vk::ImageView,
+            properties: &mut vk::ImageViewAddressPropertiesNVX<'_>,
+        ) -> VkResult<()> {
+            (self.fp.get_image_view_address_nvx)(self.handle, image_view, properties).result()
+        }
+        pub const NAME: &'static CStr = vk::NvxImageViewHandleFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::NvxImageViewHandleFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod qcom {
+    use crate::prelude::*;
+    use crate::vk;
+    use crate::{Device, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_QCOM_tile_properties.html>"]
+    #[derive(Clone)]
+    pub struct TileProperties {
+        handle: vk::Device,
+        fp: vk::QcomTilePropertiesFn,
+    }
+    impl TileProperties {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::QcomTilePropertiesFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::QcomTilePropertiesFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFramebufferTilePropertiesQCOM.html>"]
+        #[inline]
+        pub unsafe fn get_framebuffer_tile_properties(
+            &self,
+            framebuffer: vk::Framebuffer,
+        ) -> VkResult<Vec<vk::TilePropertiesQCOM<'_>>> {
+            read_into_defaulted_vector(|count, data| {
+                (self.fp.get_framebuffer_tile_properties_qcom)(
+                    self.handle,
+                    framebuffer,
+                    count,
+                    data,
+                )
+            })
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDynamicRenderingTilePropertiesQCOM.html>"]
+        #[inline]
+        pub unsafe fn get_dynamic_rendering_tile_properties(
+            &self,
+            rendering_info: &vk::RenderingInfo<'_>,
+            properties: &mut vk::TilePropertiesQCOM<'_>,
+        ) -> VkResult<()> {
+            (self.fp.get_dynamic_rendering_tile_properties_qcom)(
+                self.handle,
+                rendering_info,
+                properties,
+            )
+            .result()
+        }
+        pub const NAME: &'static CStr = vk::QcomTilePropertiesFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::QcomTilePropertiesFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod qnx {
+    use crate::prelude::*;
+    use crate::vk;
+    use crate::RawPtr;
+    use crate::{Device, Entry, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_QNX_screen_surface.html>"]
+    #[derive(Clone)]
+    pub struct ScreenSurface {
+        handle: vk::Instance,
+        fp: vk::QnxScreenSurfaceFn,
+    }
+    impl ScreenSurface {
+        pub fn new(entry: &Entry, instance: &Instance) -> Self {
+            let handle = instance.handle();
+            let fp = vk::QnxScreenSurfaceFn::load(|name| unsafe {
+                mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(instance, vk::QnxScreenSurfaceFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateScreenSurfaceQNX.html>"]
+        #[inline]
+        pub unsafe fn create_screen_surface(
+            &self,
+            create_info: &vk::ScreenSurfaceCreateInfoQNX<'_>,
+            allocator: Option<&vk::AllocationCallbacks>,
+        ) -> VkResult<vk::SurfaceKHR> {
+            let mut surface: vk::SurfaceKHR = mem::zeroed();
+            (self.fp.create_screen_surface_qnx)(
+                self.handle,
+                create_info,
+                allocator.as_raw_ptr(),
+                &mut surface,
+            )
+            .result_with_success(surface)
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceScreenPresentationSupportQNX.html>"]
+        #[inline]
+        pub unsafe fn get_physical_device_screen_presentation_support(
+            &self,
+            physical_device: vk::PhysicalDevice,
+            queue_family_index: u32,
+        ) -> (bool, vk::_screen_window) {
+            let mut window: vk::_screen_window = mem::zeroed();
+            let ret = (self.fp.get_physical_device_screen_presentation_support_qnx)(
+                physical_device,
+                queue_family_index,
+                &mut window,
+            );
+            (ret != vk::FALSE, window)
+        }
+        pub const NAME: &'static CStr = vk::QnxScreenSurfaceFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::QnxScreenSurfaceFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn instance(&self) -> vk::Instance {
+            self.handle
+        }
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_QNX_external_memory_screen_buffer.html>"]
+    #[derive(Clone)]
+    pub struct ExternalMemoryScreenBuffer {
+        handle: vk::Device,
+        fp: vk::QnxExternalMemoryScreenBufferFn,
+    }
+    impl ExternalMemoryScreenBuffer {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::QnxExternalMemoryScreenBufferFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::QnxExternalMemoryScreenBufferFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetScreenBufferPropertiesQNX.html>"]
+        #[inline]
+        pub unsafe fn get_screen_buffer_properties(
+            &self,
+            buffer: &vk::_screen_buffer,
+            properties: &mut vk::ScreenBufferPropertiesQNX<'_>,
+        ) -> VkResult<()> {
+            (self.fp.get_screen_buffer_properties_qnx)(self.handle, buffer, properties).result()
+        }
+        pub const NAME: &'static CStr = vk::QnxExternalMemoryScreenBufferFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::QnxExternalMemoryScreenBufferFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
+pub mod valve {
+    use crate::vk;
+    use crate::{Device, Instance};
+    use std::ffi::CStr;
+    use std::mem;
+    use std::os::raw::*;
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VALVE_descriptor_set_host_mapping.html>"]
+    #[derive(Clone)]
+    pub struct DescriptorSetHostMapping {
+        handle: vk::Device,
+        fp: vk::ValveDescriptorSetHostMappingFn,
+    }
+    impl DescriptorSetHostMapping {
+        pub fn new(instance: &Instance, device: &Device) -> Self {
+            let handle = device.handle();
+            let fp = vk::ValveDescriptorSetHostMappingFn::load(|name| unsafe {
+                mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+            });
+            Self { handle, fp }
+        }
+        try_new!(device, vk::ValveDescriptorSetHostMappingFn);
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutHostMappingInfoVALVE.html>"]
+        #[inline]
+        pub unsafe fn get_descriptor_set_layout_host_mapping_info(
+            &self,
+            binding_reference: &vk::DescriptorSetBindingReferenceVALVE<'_>,
+            host_mapping: &mut vk::DescriptorSetLayoutHostMappingInfoVALVE<'_>,
+        ) {
+            (self.fp.get_descriptor_set_layout_host_mapping_info_valve)(
+                self.handle,
+                binding_reference,
+                host_mapping,
+            );
+        }
+        #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetHostMappingVALVE.html>"]
+        #[inline]
+        pub unsafe fn get_descriptor_set_host_mapping(
+            &self,
+            descriptor_set: vk::DescriptorSet,
+        ) -> *mut c_void {
+            let mut data: *mut c_void = mem::zeroed();
+            (self.fp.get_descriptor_set_host_mapping_valve)(self.handle, descriptor_set, &mut data);
+            data
+        }
+        pub const NAME: &'static CStr = vk::ValveDescriptorSetHostMappingFn::NAME;
+        #[inline]
+        pub fn fp(&self) -> &vk::ValveDescriptorSetHostMappingFn {
+            &self.fp
+        }
+        #[inline]
+        pub fn device(&self) -> vk::Device {
+            self.handle
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::success_codes;
 use crate::vk;
 use crate::RawPtr;
 use crate::{Device, Instance};
@@ -21,6 +22,8 @@ impl AccelerationStructure {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrAccelerationStructureFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAccelerationStructureKHR.html>
     #[inline]
     pub unsafe fn create_acceleration_structure(
@@ -119,7 +122,7 @@ impl AccelerationStructure {
         deferred_operation: vk::DeferredOperationKHR,
         infos: &[vk::AccelerationStructureBuildGeometryInfoKHR],
         build_range_infos: &[&[vk::AccelerationStructureBuildRangeInfoKHR]],
-    ) -> VkResult<()> {
+    ) -> VkResult<success_codes::BuildAccelerationStructuresKHR> {
         assert_eq!(infos.len(), build_range_infos.len());
 
         let build_range_infos = build_range_infos
@@ -138,7 +141,7 @@ impl AccelerationStructure {
             infos.as_ptr(),
             build_range_infos.as_ptr(),
         )
-        .result()
+        .success_code()
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyAccelerationStructureKHR.html>
@@ -147,8 +150,9 @@ impl AccelerationStructure {
         &self,
         deferred_operation: vk::DeferredOperationKHR,
         info: &vk::CopyAccelerationStructureInfoKHR,
-    ) -> VkResult<()> {
-        (self.fp.copy_acceleration_structure_khr)(self.handle, deferred_operation, info).result()
+    ) -> VkResult<success_codes::CopyAccelerationStructureKHR> {
+        (self.fp.copy_acceleration_structure_khr)(self.handle, deferred_operation, info)
+            .success_code()
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyAccelerationStructureToMemoryKHR.html>
@@ -157,9 +161,9 @@ impl AccelerationStructure {
         &self,
         deferred_operation: vk::DeferredOperationKHR,
         info: &vk::CopyAccelerationStructureToMemoryInfoKHR,
-    ) -> VkResult<()> {
+    ) -> VkResult<success_codes::CopyAccelerationStructureToMemoryKHR> {
         (self.fp.copy_acceleration_structure_to_memory_khr)(self.handle, deferred_operation, info)
-            .result()
+            .success_code()
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMemoryToAccelerationStructureKHR.html>
@@ -168,9 +172,9 @@ impl AccelerationStructure {
         &self,
         deferred_operation: vk::DeferredOperationKHR,
         info: &vk::CopyMemoryToAccelerationStructureInfoKHR,
-    ) -> VkResult<()> {
+    ) -> VkResult<success_codes::CopyMemoryToAccelerationStructureKHR> {
         (self.fp.copy_memory_to_acceleration_structure_khr)(self.handle, deferred_operation, info)
-            .result()
+            .success_code()
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWriteAccelerationStructuresPropertiesKHR.html>
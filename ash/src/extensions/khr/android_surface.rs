@@ -20,6 +20,8 @@ impl AndroidSurface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::KhrAndroidSurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAndroidSurfaceKHR.html>
     #[inline]
     pub unsafe fn create_android_surface(
@@ -18,6 +18,8 @@ impl BufferDeviceAddress {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrBufferDeviceAddressFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferDeviceAddressKHR.html>
     #[inline]
     pub unsafe fn get_buffer_device_address(
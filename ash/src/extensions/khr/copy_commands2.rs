@@ -17,6 +17,8 @@ impl CopyCommands2 {
         Self { fp }
     }
 
+    try_new!(device, vk::KhrCopyCommands2Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBuffer2KHR.html>
     #[inline]
     pub unsafe fn cmd_copy_buffer2(
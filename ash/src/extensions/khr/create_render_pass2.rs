@@ -20,6 +20,8 @@ impl CreateRenderPass2 {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrCreateRenderpass2Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRenderPass2.html>
     #[inline]
     pub unsafe fn create_render_pass2(
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::success_codes;
 use crate::vk;
 use crate::RawPtr;
 use crate::{Device, Instance};
@@ -20,6 +21,8 @@ impl DeferredHostOperations {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrDeferredHostOperationsFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDeferredOperationKHR.html>
     #[inline]
     pub unsafe fn create_deferred_operation(
@@ -40,8 +43,8 @@ impl DeferredHostOperations {
     pub unsafe fn deferred_operation_join(
         &self,
         operation: vk::DeferredOperationKHR,
-    ) -> VkResult<()> {
-        (self.fp.deferred_operation_join_khr)(self.handle, operation).result()
+    ) -> VkResult<success_codes::DeferredOperationJoinKHR> {
+        (self.fp.deferred_operation_join_khr)(self.handle, operation).success_code()
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDeferredOperationKHR.html>
@@ -72,8 +75,8 @@ impl DeferredHostOperations {
     pub unsafe fn get_deferred_operation_result(
         &self,
         operation: vk::DeferredOperationKHR,
-    ) -> VkResult<()> {
-        (self.fp.get_deferred_operation_result_khr)(self.handle, operation).result()
+    ) -> VkResult<success_codes::GetDeferredOperationResultKHR> {
+        (self.fp.get_deferred_operation_result_khr)(self.handle, operation).success_code()
     }
 
     pub const NAME: &'static CStr = vk::KhrDeferredHostOperationsFn::NAME;
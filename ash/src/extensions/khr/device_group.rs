@@ -1,6 +1,7 @@
 #[cfg(doc)]
 use super::Swapchain;
 use crate::prelude::*;
+use crate::success_codes;
 use crate::vk;
 use crate::{Device, Instance};
 use std::ffi::CStr;
@@ -22,6 +23,8 @@ impl DeviceGroup {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrDeviceGroupFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupPeerMemoryFeaturesKHR.html>
     #[inline]
     pub unsafe fn get_device_group_peer_memory_features(
@@ -128,7 +131,9 @@ impl DeviceGroup {
         })
     }
 
-    /// On success, returns the next image's index and whether the swapchain is suboptimal for the surface.
+    /// On success, returns the next image's index and the success code, which tells whether the
+    /// swapchain is suboptimal for the surface or whether no image was available, in which case
+    /// the index is not valid.
     ///
     /// Requires [`VK_KHR_swapchain`] to be enabled.
     ///
@@ -142,14 +147,11 @@ impl DeviceGroup {
     pub unsafe fn acquire_next_image2(
         &self,
         acquire_info: &vk::AcquireNextImageInfoKHR,
-    ) -> VkResult<(u32, bool)> {
+    ) -> VkResult<(u32, success_codes::AcquireNextImage2KHR)> {
         let mut index = 0;
-        let err_code = (self.fp.acquire_next_image2_khr)(self.handle, acquire_info, &mut index);
-        match err_code {
-            vk::Result::SUCCESS => Ok((index, false)),
-            vk::Result::SUBOPTIMAL_KHR => Ok((index, true)),
-            _ => Err(err_code),
-        }
+        let code = (self.fp.acquire_next_image2_khr)(self.handle, acquire_info, &mut index)
+            .success_code()?;
+        Ok((index, code))
     }
 
     pub const NAME: &'static CStr = vk::KhrDeviceGroupFn::NAME;
@@ -21,6 +21,8 @@ impl DeviceGroupCreation {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::KhrDeviceGroupCreationFn);
+
     /// Retrieve the number of elements to pass to [`enumerate_physical_device_groups()`][Self::enumerate_physical_device_groups()]
     #[inline]
     pub unsafe fn enumerate_physical_device_groups_len(&self) -> VkResult<usize> {
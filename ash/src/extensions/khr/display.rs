@@ -20,6 +20,8 @@ impl Display {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::KhrDisplayFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayPropertiesKHR.html>
     #[inline]
     pub unsafe fn get_physical_device_display_properties(
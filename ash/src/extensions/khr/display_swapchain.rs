@@ -20,6 +20,8 @@ impl DisplaySwapchain {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrDisplaySwapchainFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSharedSwapchainsKHR.html>
     #[inline]
     pub unsafe fn create_shared_swapchains(
@@ -16,6 +16,8 @@ impl DrawIndirectCount {
         Self { fp }
     }
 
+    try_new!(device, vk::KhrDrawIndirectCountFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexedIndirectCountKHR.html>
     #[inline]
     pub unsafe fn cmd_draw_indexed_indirect_count(
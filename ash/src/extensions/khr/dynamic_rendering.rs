@@ -16,6 +16,8 @@ impl DynamicRendering {
         Self { fp }
     }
 
+    try_new!(device, vk::KhrDynamicRenderingFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRenderingKHR.html>
     #[inline]
     pub unsafe fn cmd_begin_rendering(
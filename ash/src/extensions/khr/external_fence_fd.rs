@@ -19,6 +19,8 @@ impl ExternalFenceFd {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrExternalFenceFdFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportFenceFdKHR.html>
     #[inline]
     pub unsafe fn import_fence_fd(&self, import_info: &vk::ImportFenceFdInfoKHR) -> VkResult<()> {
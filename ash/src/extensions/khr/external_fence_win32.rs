@@ -21,6 +21,8 @@ impl ExternalFenceWin32 {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrExternalFenceWin32Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportFenceWin32HandleKHR.html>
     #[inline]
     pub unsafe fn import_fence_win32_handle(
@@ -20,6 +20,8 @@ impl ExternalMemoryFd {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrExternalMemoryFdFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryFdKHR.html>
     #[inline]
     pub unsafe fn get_memory_fd(&self, get_fd_info: &vk::MemoryGetFdInfoKHR) -> VkResult<i32> {
@@ -21,6 +21,8 @@ impl ExternalMemoryWin32 {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrExternalMemoryWin32Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandleKHR.html>
     #[inline]
     pub unsafe fn get_memory_win32_handle(
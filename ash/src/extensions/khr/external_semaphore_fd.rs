@@ -19,6 +19,8 @@ impl ExternalSemaphoreFd {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrExternalSemaphoreFdFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportSemaphoreFdKHR.html>
     #[inline]
     pub unsafe fn import_semaphore_fd(
@@ -21,6 +21,8 @@ impl ExternalSemaphoreWin32 {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrExternalSemaphoreWin32Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportSemaphoreWin32HandleKHR.html>
     #[inline]
     pub unsafe fn import_semaphore_win32_handle(
@@ -19,6 +19,8 @@ impl GetMemoryRequirements2 {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrGetMemoryRequirements2Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements2KHR.html>
     #[inline]
     pub unsafe fn get_buffer_memory_requirements2(
@@ -18,6 +18,8 @@ impl GetPhysicalDeviceProperties2 {
         Self { fp }
     }
 
+    try_new!(instance, vk::KhrGetPhysicalDeviceProperties2Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2KHR.html>
     #[inline]
     pub unsafe fn get_physical_device_features2(
@@ -18,6 +18,8 @@ impl GetSurfaceCapabilities2 {
         Self { fp }
     }
 
+    try_new!(instance, vk::KhrGetSurfaceCapabilities2Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceCapabilities2KHR.html>
     #[inline]
     pub unsafe fn get_physical_device_surface_capabilities2(
@@ -18,6 +18,8 @@ impl Maintenance1 {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrMaintenance1Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkTrimCommandPoolKHR.html>
     #[inline]
     pub unsafe fn trim_command_pool(
@@ -18,6 +18,8 @@ impl Maintenance3 {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrMaintenance3Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSupportKHR.html>
     #[inline]
     pub unsafe fn get_descriptor_set_layout_support(
@@ -18,6 +18,8 @@ impl Maintenance4 {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrMaintenance4Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceBufferMemoryRequirementsKHR.html>
     #[inline]
     pub unsafe fn get_device_buffer_memory_requirements(
@@ -36,6 +36,7 @@ pub use self::wayland_surface::WaylandSurface;
 pub use self::win32_surface::Win32Surface;
 pub use self::xcb_surface::XcbSurface;
 pub use self::xlib_surface::XlibSurface;
+pub use super::generated::khr::*;
 
 mod acceleration_structure;
 mod android_surface;
@@ -21,6 +21,8 @@ impl PerformanceQuery {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::KhrPerformanceQueryFn);
+
     /// Retrieve the number of elements to pass to [`enumerate_physical_device_queue_family_performance_query_counters()`][Self::enumerate_physical_device_queue_family_performance_query_counters()]
     #[inline]
     pub unsafe fn enumerate_physical_device_queue_family_performance_query_counters_len(
@@ -19,6 +19,8 @@ impl PipelineExecutableProperties {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrPipelineExecutablePropertiesFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineExecutableInternalRepresentationsKHR.html>
     #[inline]
     pub unsafe fn get_pipeline_executable_internal_representations(
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::success_codes;
 use crate::vk;
 use crate::{Device, Instance};
 use std::ffi::CStr;
@@ -19,6 +20,8 @@ impl PresentWait {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrPresentWaitFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitForPresentKHR.html>
     #[inline]
     pub unsafe fn wait_for_present(
@@ -26,8 +29,8 @@ impl PresentWait {
         swapchain: vk::SwapchainKHR,
         present_id: u64,
         timeout: u64,
-    ) -> VkResult<()> {
-        (self.fp.wait_for_present_khr)(self.handle, swapchain, present_id, timeout).result()
+    ) -> VkResult<success_codes::WaitForPresentKHR> {
+        (self.fp.wait_for_present_khr)(self.handle, swapchain, present_id, timeout).success_code()
     }
 
     pub const NAME: &'static CStr = vk::KhrPresentWaitFn::NAME;
@@ -17,6 +17,8 @@ impl PushDescriptor {
         Self { fp }
     }
 
+    try_new!(device, vk::KhrPushDescriptorFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushDescriptorSetKHR.html>
     #[inline]
     pub unsafe fn cmd_push_descriptor_set(
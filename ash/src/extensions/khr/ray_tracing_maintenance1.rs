@@ -18,6 +18,8 @@ impl RayTracingMaintenance1 {
         Self { fp }
     }
 
+    try_new!(device, vk::KhrRayTracingMaintenance1Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysIndirect2KHR.html>
     ///
     /// `indirect_device_address` is a buffer device address which is a pointer to a [`vk::TraceRaysIndirectCommand2KHR`] structure containing the trace ray parameters.
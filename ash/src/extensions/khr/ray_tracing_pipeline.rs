@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::success_codes;
 use crate::vk;
 use crate::RawPtr;
 use crate::{Device, Instance};
@@ -20,6 +21,8 @@ impl RayTracingPipeline {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrRayTracingPipelineFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysKHR.html>
     #[inline]
     pub unsafe fn cmd_trace_rays(
@@ -53,9 +56,12 @@ impl RayTracingPipeline {
         pipeline_cache: vk::PipelineCache,
         create_info: &[vk::RayTracingPipelineCreateInfoKHR],
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
-    ) -> VkResult<Vec<vk::Pipeline>> {
+    ) -> VkResult<(
+        Vec<vk::Pipeline>,
+        success_codes::CreateRayTracingPipelinesKHR,
+    )> {
         let mut pipelines = vec![mem::zeroed(); create_info.len()];
-        (self.fp.create_ray_tracing_pipelines_khr)(
+        let code = (self.fp.create_ray_tracing_pipelines_khr)(
             self.handle,
             deferred_operation,
             pipeline_cache,
@@ -64,7 +70,8 @@ impl RayTracingPipeline {
             allocation_callbacks.as_raw_ptr(),
             pipelines.as_mut_ptr(),
         )
-        .result_with_success(pipelines)
+        .success_code()?;
+        Ok((pipelines, code))
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRayTracingShaderGroupHandlesKHR.html>
@@ -20,6 +20,8 @@ impl Surface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::KhrSurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceSupportKHR.html>
     #[inline]
     pub unsafe fn get_physical_device_surface_support(
@@ -1,6 +1,7 @@
 #[cfg(doc)]
 use super::DeviceGroup;
 use crate::prelude::*;
+use crate::success_codes;
 use crate::vk;
 use crate::RawPtr;
 use crate::{Device, Instance};
@@ -22,6 +23,8 @@ impl Swapchain {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrSwapchainFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSwapchainKHR.html>
     #[inline]
     pub unsafe fn create_swapchain(
@@ -60,7 +63,9 @@ impl Swapchain {
         })
     }
 
-    /// On success, returns the next image's index and whether the swapchain is suboptimal for the surface.
+    /// On success, returns the next image's index and the success code, which tells whether the
+    /// swapchain is suboptimal for the surface or whether no image was available, in which case
+    /// the index is not valid.
     ///
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireNextImageKHR.html>
     #[inline]
@@ -70,24 +75,22 @@ impl Swapchain {
         timeout: u64,
         semaphore: vk::Semaphore,
         fence: vk::Fence,
-    ) -> VkResult<(u32, bool)> {
+    ) -> VkResult<(u32, success_codes::AcquireNextImageKHR)> {
         let mut index = 0;
-        let err_code = (self.fp.acquire_next_image_khr)(
+        let code = (self.fp.acquire_next_image_khr)(
             self.handle,
             swapchain,
             timeout,
             semaphore,
             fence,
             &mut index,
-        );
-        match err_code {
-            vk::Result::SUCCESS => Ok((index, false)),
-            vk::Result::SUBOPTIMAL_KHR => Ok((index, true)),
-            _ => Err(err_code),
-        }
+        )
+        .success_code()?;
+        Ok((index, code))
     }
 
-    /// On success, returns whether the swapchain is suboptimal for the surface.
+    /// On success, returns the success code, which tells whether the swapchain is suboptimal for
+    /// the surface.
     ///
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueuePresentKHR.html>
     #[inline]
@@ -95,13 +98,8 @@ impl Swapchain {
         &self,
         queue: vk::Queue,
         present_info: &vk::PresentInfoKHR,
-    ) -> VkResult<bool> {
-        let err_code = (self.fp.queue_present_khr)(queue, present_info);
-        match err_code {
-            vk::Result::SUCCESS => Ok(false),
-            vk::Result::SUBOPTIMAL_KHR => Ok(true),
-            _ => Err(err_code),
-        }
+    ) -> VkResult<success_codes::QueuePresentKHR> {
+        (self.fp.queue_present_khr)(queue, present_info).success_code()
     }
 
     /// Only available since [Vulkan 1.1].
@@ -169,7 +167,9 @@ impl Swapchain {
         })
     }
 
-    /// On success, returns the next image's index and whether the swapchain is suboptimal for the surface.
+    /// On success, returns the next image's index and the success code, which tells whether the
+    /// swapchain is suboptimal for the surface or whether no image was available, in which case
+    /// the index is not valid.
     ///
     /// Only available since [Vulkan 1.1].
     ///
@@ -184,14 +184,11 @@ impl Swapchain {
     pub unsafe fn acquire_next_image2(
         &self,
         acquire_info: &vk::AcquireNextImageInfoKHR,
-    ) -> VkResult<(u32, bool)> {
+    ) -> VkResult<(u32, success_codes::AcquireNextImage2KHR)> {
         let mut index = 0;
-        let err_code = (self.fp.acquire_next_image2_khr)(self.handle, acquire_info, &mut index);
-        match err_code {
-            vk::Result::SUCCESS => Ok((index, false)),
-            vk::Result::SUBOPTIMAL_KHR => Ok((index, true)),
-            _ => Err(err_code),
-        }
+        let code = (self.fp.acquire_next_image2_khr)(self.handle, acquire_info, &mut index)
+            .success_code()?;
+        Ok((index, code))
     }
 
     pub const NAME: &'static CStr = vk::KhrSwapchainFn::NAME;
@@ -17,6 +17,8 @@ impl Synchronization2 {
         Self { fp }
     }
 
+    try_new!(device, vk::KhrSynchronization2Fn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPipelineBarrier2KHR.html>
     #[inline]
     pub unsafe fn cmd_pipeline_barrier2(
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::success_codes;
 use crate::vk;
 use crate::{Device, Instance};
 use std::ffi::CStr;
@@ -19,6 +20,8 @@ impl TimelineSemaphore {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::KhrTimelineSemaphoreFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreCounterValue.html>
     #[inline]
     pub unsafe fn get_semaphore_counter_value(&self, semaphore: vk::Semaphore) -> VkResult<u64> {
@@ -33,8 +36,8 @@ impl TimelineSemaphore {
         &self,
         wait_info: &vk::SemaphoreWaitInfo,
         timeout: u64,
-    ) -> VkResult<()> {
-        (self.fp.wait_semaphores_khr)(self.handle, wait_info, timeout).result()
+    ) -> VkResult<success_codes::WaitSemaphores> {
+        (self.fp.wait_semaphores_khr)(self.handle, wait_info, timeout).success_code()
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSignalSemaphore.html>
@@ -20,6 +20,8 @@ impl WaylandSurface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::KhrWaylandSurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateWaylandSurfaceKHR.html>
     #[inline]
     pub unsafe fn create_wayland_surface(
@@ -20,6 +20,8 @@ impl Win32Surface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::KhrWin32SurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateWin32SurfaceKHR.html>
     #[inline]
     pub unsafe fn create_win32_surface(
@@ -20,6 +20,8 @@ impl XcbSurface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::KhrXcbSurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateXcbSurfaceKHR.html>
     #[inline]
     pub unsafe fn create_xcb_surface(
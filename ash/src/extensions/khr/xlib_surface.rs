@@ -20,6 +20,8 @@ impl XlibSurface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::KhrXlibSurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateXlibSurfaceKHR.html>
     #[inline]
     pub unsafe fn create_xlib_surface(
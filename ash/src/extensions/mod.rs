@@ -1,5 +1,38 @@
+/// Defines the `try_new()` constructor of a device or instance extension wrapper, which checks
+/// that the extension of `$fn` and its dependencies were enabled before calling `new()`.
+macro_rules! try_new {
+    (device, $fn:ty) => {
+        /// Fails if the extension or one of its dependencies was not enabled.
+        pub fn try_new(
+            instance: &$crate::Instance,
+            device: &$crate::Device,
+        ) -> Result<Self, $crate::prelude::MissingExtensionError> {
+            device.require_extension(instance, <$fn>::NAME, &<$fn>::DEPENDS)?;
+            Ok(Self::new(instance, device))
+        }
+    };
+    (instance, $fn:ty) => {
+        /// Fails if the extension or one of its dependencies was not enabled.
+        pub fn try_new(
+            entry: &$crate::Entry,
+            instance: &$crate::Instance,
+        ) -> Result<Self, $crate::prelude::MissingExtensionError> {
+            instance.require_extension(<$fn>::NAME, &<$fn>::DEPENDS)?;
+            Ok(Self::new(entry, instance))
+        }
+    };
+}
+
 pub mod ext;
 pub mod khr;
 pub mod mvk;
 pub mod nn;
 pub mod nv;
+
+// Wrappers generated from `vk.xml` for every extension with commands that has no hand-written
+// wrapper. Those of the vendors above are re-exported from their modules.
+mod generated;
+
+pub use self::generated::{
+    amd, android, fuchsia, ggp, google, huawei, intel, nvx, qcom, qnx, valve,
+};
@@ -20,6 +20,8 @@ impl IOSSurface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::MvkIosSurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateIOSSurfaceMVK.html>
     #[inline]
     pub unsafe fn create_ios_surface(
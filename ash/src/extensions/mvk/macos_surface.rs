@@ -20,6 +20,8 @@ impl MacOSSurface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::MvkMacosSurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateMacOSSurfaceMVK.html>
     #[inline]
     pub unsafe fn create_mac_os_surface(
@@ -20,6 +20,8 @@ impl ViSurface {
         Self { handle, fp }
     }
 
+    try_new!(instance, vk::NnViSurfaceFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateViSurfaceNN.html>
     #[inline]
     pub unsafe fn create_vi_surface(
@@ -18,6 +18,8 @@ impl CoverageReductionMode {
         Self { fp }
     }
 
+    try_new!(instance, vk::NvCoverageReductionModeFn);
+
     /// Retrieve the number of elements to pass to [`get_physical_device_supported_framebuffer_mixed_samples_combinations()`][Self::get_physical_device_supported_framebuffer_mixed_samples_combinations()]
     #[inline]
     pub unsafe fn get_physical_device_supported_framebuffer_mixed_samples_combinations_len(
@@ -18,6 +18,8 @@ impl DeviceDiagnosticCheckpoints {
         Self { fp }
     }
 
+    try_new!(device, vk::NvDeviceDiagnosticCheckpointsFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCheckpointNV.html>
     #[inline]
     pub unsafe fn cmd_set_checkpoint(
@@ -16,6 +16,8 @@ impl MemoryDecompression {
         Self { fp }
     }
 
+    try_new!(device, vk::NvMemoryDecompressionFn);
+
     /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkCmdDecompressMemoryNV.html>
     pub unsafe fn cmd_decompress_memory(
         &self,
@@ -16,6 +16,8 @@ impl MeshShader {
         Self { fp }
     }
 
+    try_new!(device, vk::NvMeshShaderFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksNV.html>
     #[inline]
     pub unsafe fn cmd_draw_mesh_tasks(
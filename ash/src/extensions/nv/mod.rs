@@ -3,6 +3,7 @@ pub use self::device_diagnostic_checkpoints::DeviceDiagnosticCheckpoints;
 pub use self::memory_decompression::MemoryDecompression;
 pub use self::mesh_shader::MeshShader;
 pub use self::ray_tracing::RayTracing;
+pub use super::generated::nv::*;
 
 mod coverage_reduction_mode;
 mod device_diagnostic_checkpoints;
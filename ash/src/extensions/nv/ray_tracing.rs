@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::success_codes;
 use crate::vk;
 use crate::RawPtr;
 use crate::{Device, Instance};
@@ -20,6 +21,8 @@ impl RayTracing {
         Self { handle, fp }
     }
 
+    try_new!(device, vk::NvRayTracingFn);
+
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAccelerationStructureNV.html>
     #[inline]
     pub unsafe fn create_acceleration_structure(
@@ -165,9 +168,12 @@ impl RayTracing {
         pipeline_cache: vk::PipelineCache,
         create_info: &[vk::RayTracingPipelineCreateInfoNV],
         allocation_callbacks: Option<&vk::AllocationCallbacks>,
-    ) -> VkResult<Vec<vk::Pipeline>> {
+    ) -> VkResult<(
+        Vec<vk::Pipeline>,
+        success_codes::CreateRayTracingPipelinesNV,
+    )> {
         let mut pipelines = vec![mem::zeroed(); create_info.len()];
-        (self.fp.create_ray_tracing_pipelines_nv)(
+        let code = (self.fp.create_ray_tracing_pipelines_nv)(
             self.handle,
             pipeline_cache,
             create_info.len() as u32,
@@ -175,7 +181,8 @@ impl RayTracing {
             allocation_callbacks.as_raw_ptr(),
             pipelines.as_mut_ptr(),
         )
-        .result_with_success(pipelines)
+        .success_code()?;
+        Ok((pipelines, code))
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRayTracingShaderGroupHandlesNV.html>
@@ -0,0 +1,224 @@
+//! Every struct of device features known to `vk.xml`, queried, compared and enabled together.
+//!
+//! [`Features`] owns a [`vk::PhysicalDeviceFeatures2`] and every struct that extends it, such as
+//! [`vk::PhysicalDeviceVulkan12Features`], and builds their `p_next` chain when it is passed to
+//! Vulkan:
+//!
+//! ```no_run
+//! # use ash::{features::Features, vk};
+//! # unsafe fn f(instance: &ash::Instance, physical_device: vk::PhysicalDevice) -> Result<(), Box<dyn std::error::Error>> {
+//! let supported = Features::query(instance, physical_device)?;
+//!
+//! let mut required = Features::default()
+//!     .with(vk::PhysicalDeviceVulkan12Features::default().buffer_device_address(true))
+//!     .with(vk::PhysicalDeviceVulkan13Features::default().dynamic_rendering(true));
+//! let missing = required.missing(&supported);
+//! if !missing.is_empty() {
+//!     return Err(format!("unsupported features: {}", missing.join(", ")).into());
+//! }
+//!
+//! let create_info = vk::DeviceCreateInfo::default().push_next(required.chain());
+//! let device = instance.create_device(physical_device, &create_info, None)?;
+//! # Ok(()) }
+//! ```
+//!
+//! Features are named by the struct and member in the specification, such as
+//! `"VkPhysicalDeviceVulkan12Features::bufferDeviceAddress"`.
+
+use crate::prelude::VkResult;
+use crate::{vk, Instance};
+use std::ffi::CStr;
+use std::fmt;
+use std::ptr;
+
+mod generated;
+
+use generated::{Structs, COUNT, DEPENDS, PROMOTED_TO};
+
+/// A struct of device features owned by [`Features`]: [`vk::PhysicalDeviceFeatures`] or a struct
+/// that extends [`vk::PhysicalDeviceFeatures2`].
+pub trait Feature {
+    fn get(features: &Features) -> &Self;
+    fn get_mut(features: &mut Features) -> &mut Self;
+}
+
+/// Every struct of device features, see the [module documentation][self].
+#[derive(Default)]
+pub struct Features {
+    structs: Structs,
+}
+
+// SAFETY: The structs are plain data apart from their `p_next` pointers, which are what makes them
+// neither `Send` nor `Sync`. Those pointers are null or point into the same `Features`, which
+// never reads through them: `chain()` and `query()` rebuild them through `&mut self` before
+// handing them to Vulkan, and `Clone` resets them. Moving a `Features` to another thread or sharing
+// a reference to it thus gives no thread access to data owned by another.
+unsafe impl Send for Features {}
+unsafe impl Sync for Features {}
+
+impl Clone for Features {
+    fn clone(&self) -> Self {
+        let mut features = Self {
+            structs: self.structs.clone(),
+        };
+        features.link(|_| false);
+        features
+    }
+}
+
+impl fmt::Debug for Features {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.enabled()).finish()
+    }
+}
+
+impl PartialEq for Features {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_superset_of(other) && other.is_superset_of(self)
+    }
+}
+
+impl Eq for Features {}
+
+impl Features {
+    /// Returns the features of a physical device.
+    ///
+    /// Only the structs that the physical device supports are queried, according to the version
+    /// of `instance` and of the physical device, and the device extensions it supports. Instances
+    /// created for Vulkan 1.0 only query [`vk::PhysicalDeviceFeatures`].
+    ///
+    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2.html>
+    pub unsafe fn query(
+        instance: &Instance,
+        physical_device: vk::PhysicalDevice,
+    ) -> VkResult<Self> {
+        let mut features = Self::default();
+        let instance_1_1 = match instance.v1_1() {
+            Ok(instance_1_1) => instance_1_1,
+            Err(_) => {
+                features.structs.physical_device_features2.features =
+                    instance.get_physical_device_features(physical_device);
+                return Ok(features);
+            }
+        };
+
+        let properties = instance.get_physical_device_properties(physical_device);
+        let api_version = instance.api_version().min(properties.api_version);
+        let extensions = instance.enumerate_device_extension_properties(physical_device)?;
+        let is_supported = |name: &CStr| {
+            extensions
+                .iter()
+                .any(|extension| extension.extension_name_as_c_str() == Some(name))
+        };
+        features.link(|index| DEPENDS[index].missing(api_version, &is_supported).is_none());
+        instance_1_1.get_physical_device_features2(
+            physical_device,
+            &mut features.structs.physical_device_features2,
+        );
+        features.link(|_| false);
+        Ok(features)
+    }
+
+    /// Returns the struct of features of type `T`.
+    pub fn get<T: Feature>(&self) -> &T {
+        T::get(self)
+    }
+
+    /// Returns the struct of features of type `T` for modification.
+    pub fn get_mut<T: Feature>(&mut self) -> &mut T {
+        T::get_mut(self)
+    }
+
+    /// Replaces the struct of features of type `T`.
+    pub fn with<T: Feature>(mut self, features: T) -> Self {
+        *T::get_mut(&mut self) = features;
+        self
+    }
+
+    /// Returns the names of the enabled features.
+    pub fn enabled(&self) -> Vec<&'static str> {
+        let mut enabled = Vec::new();
+        self.structs.zip(&self.structs, |_, name, value, _| {
+            if value != vk::FALSE {
+                enabled.push(name);
+            }
+        });
+        enabled
+    }
+
+    /// Returns the names of the features that are enabled in `self` but not in `supported`.
+    pub fn missing(&self, supported: &Self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        self.structs
+            .zip(&supported.structs, |_, name, value, supported| {
+                if value != vk::FALSE && supported == vk::FALSE {
+                    missing.push(name);
+                }
+            });
+        missing
+    }
+
+    /// Returns whether every feature enabled in `other` is enabled in `self`.
+    pub fn is_superset_of(&self, other: &Self) -> bool {
+        other.missing(self).is_empty()
+    }
+
+    /// Returns the features that are enabled in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = self.clone();
+        intersection
+            .structs
+            .zip_mut(&other.structs, |_, _, value, other| {
+                if other == vk::FALSE {
+                    *value = vk::FALSE;
+                }
+            });
+        intersection
+    }
+
+    /// Links the structs with enabled features into a chain to enable them with
+    /// [`vk::DeviceCreateInfo::push_next()`], in which case
+    /// [`vk::DeviceCreateInfo::p_enabled_features`] must be null.
+    ///
+    /// The features of structs that were promoted to a core version, such as
+    /// [`vk::PhysicalDeviceVariablePointersFeatures`], are moved to the struct of that version,
+    /// such as [`vk::PhysicalDeviceVulkan11Features`], when features of the latter are enabled as
+    /// well, since the specification does not allow both in the same chain.
+    pub fn chain(&mut self) -> &mut vk::PhysicalDeviceFeatures2<'static> {
+        let mut enabled = [false; COUNT];
+        enabled[0] = true;
+        self.structs.zip(&self.structs, |index, _, value, _| {
+            enabled[index] |= value != vk::FALSE;
+        });
+        for index in 0..COUNT {
+            match PROMOTED_TO[index] {
+                Some(core) if enabled[index] && enabled[core] => {
+                    self.structs.promote(index);
+                    enabled[index] = false;
+                }
+                _ => {}
+            }
+        }
+        self.link(|index| enabled[index])
+    }
+
+    /// Links the structs at the indices for which `include` returns `true` behind
+    /// [`vk::PhysicalDeviceFeatures2`], and unlinks the others.
+    fn link(
+        &mut self,
+        include: impl Fn(usize) -> bool,
+    ) -> &mut vk::PhysicalDeviceFeatures2<'static> {
+        let bases = self.structs.bases();
+        let mut last = bases[0];
+        for (index, &base) in bases.iter().enumerate() {
+            unsafe {
+                (*base).p_next = ptr::null_mut();
+                if index > 0 && include(index) {
+                    (*last).p_next = base;
+                    last = base;
+                }
+            }
+        }
+        &mut self.structs.physical_device_features2
+    }
+}
@@ -0,0 +1,10490 @@
+use super::*;
+pub(super) const COUNT: usize = 157usize;
+#[derive(Clone, Default)]
+pub(super) struct Structs {
+    pub(super) physical_device_features2: vk::PhysicalDeviceFeatures2<'static>,
+    pub(super) physical_device_device_generated_commands_features_nv:
+        vk::PhysicalDeviceDeviceGeneratedCommandsFeaturesNV<'static>,
+    pub(super) physical_device_private_data_features:
+        vk::PhysicalDevicePrivateDataFeatures<'static>,
+    pub(super) physical_device_variable_pointers_features:
+        vk::PhysicalDeviceVariablePointersFeatures<'static>,
+    pub(super) physical_device_multiview_features: vk::PhysicalDeviceMultiviewFeatures<'static>,
+    pub(super) physical_device_present_id_features_khr:
+        vk::PhysicalDevicePresentIdFeaturesKHR<'static>,
+    pub(super) physical_device_present_wait_features_khr:
+        vk::PhysicalDevicePresentWaitFeaturesKHR<'static>,
+    pub(super) physical_device16_bit_storage_features:
+        vk::PhysicalDevice16BitStorageFeatures<'static>,
+    pub(super) physical_device_shader_subgroup_extended_types_features:
+        vk::PhysicalDeviceShaderSubgroupExtendedTypesFeatures<'static>,
+    pub(super) physical_device_sampler_ycbcr_conversion_features:
+        vk::PhysicalDeviceSamplerYcbcrConversionFeatures<'static>,
+    pub(super) physical_device_protected_memory_features:
+        vk::PhysicalDeviceProtectedMemoryFeatures<'static>,
+    pub(super) physical_device_blend_operation_advanced_features_ext:
+        vk::PhysicalDeviceBlendOperationAdvancedFeaturesEXT<'static>,
+    pub(super) physical_device_multi_draw_features_ext:
+        vk::PhysicalDeviceMultiDrawFeaturesEXT<'static>,
+    pub(super) physical_device_inline_uniform_block_features:
+        vk::PhysicalDeviceInlineUniformBlockFeatures<'static>,
+    pub(super) physical_device_maintenance4_features:
+        vk::PhysicalDeviceMaintenance4Features<'static>,
+    pub(super) physical_device_shader_draw_parameters_features:
+        vk::PhysicalDeviceShaderDrawParametersFeatures<'static>,
+    pub(super) physical_device_shader_float16_int8_features:
+        vk::PhysicalDeviceShaderFloat16Int8Features<'static>,
+    pub(super) physical_device_host_query_reset_features:
+        vk::PhysicalDeviceHostQueryResetFeatures<'static>,
+    pub(super) physical_device_global_priority_query_features_khr:
+        vk::PhysicalDeviceGlobalPriorityQueryFeaturesKHR<'static>,
+    pub(super) physical_device_device_memory_report_features_ext:
+        vk::PhysicalDeviceDeviceMemoryReportFeaturesEXT<'static>,
+    pub(super) physical_device_descriptor_indexing_features:
+        vk::PhysicalDeviceDescriptorIndexingFeatures<'static>,
+    pub(super) physical_device_timeline_semaphore_features:
+        vk::PhysicalDeviceTimelineSemaphoreFeatures<'static>,
+    pub(super) physical_device8_bit_storage_features:
+        vk::PhysicalDevice8BitStorageFeatures<'static>,
+    pub(super) physical_device_conditional_rendering_features_ext:
+        vk::PhysicalDeviceConditionalRenderingFeaturesEXT<'static>,
+    pub(super) physical_device_vulkan_memory_model_features:
+        vk::PhysicalDeviceVulkanMemoryModelFeatures<'static>,
+    pub(super) physical_device_shader_atomic_int64_features:
+        vk::PhysicalDeviceShaderAtomicInt64Features<'static>,
+    pub(super) physical_device_shader_atomic_float_features_ext:
+        vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>,
+    pub(super) physical_device_shader_atomic_float2_features_ext:
+        vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>,
+    pub(super) physical_device_vertex_attribute_divisor_features_ext:
+        vk::PhysicalDeviceVertexAttributeDivisorFeaturesEXT<'static>,
+    pub(super) physical_device_astc_decode_features_ext:
+        vk::PhysicalDeviceASTCDecodeFeaturesEXT<'static>,
+    pub(super) physical_device_transform_feedback_features_ext:
+        vk::PhysicalDeviceTransformFeedbackFeaturesEXT<'static>,
+    pub(super) physical_device_representative_fragment_test_features_nv:
+        vk::PhysicalDeviceRepresentativeFragmentTestFeaturesNV<'static>,
+    pub(super) physical_device_exclusive_scissor_features_nv:
+        vk::PhysicalDeviceExclusiveScissorFeaturesNV<'static>,
+    pub(super) physical_device_corner_sampled_image_features_nv:
+        vk::PhysicalDeviceCornerSampledImageFeaturesNV<'static>,
+    pub(super) physical_device_compute_shader_derivatives_features_nv:
+        vk::PhysicalDeviceComputeShaderDerivativesFeaturesNV<'static>,
+    pub(super) physical_device_shader_image_footprint_features_nv:
+        vk::PhysicalDeviceShaderImageFootprintFeaturesNV<'static>,
+    pub(super) physical_device_dedicated_allocation_image_aliasing_features_nv:
+        vk::PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV<'static>,
+    pub(super) physical_device_copy_memory_indirect_features_nv:
+        vk::PhysicalDeviceCopyMemoryIndirectFeaturesNV<'static>,
+    pub(super) physical_device_memory_decompression_features_nv:
+        vk::PhysicalDeviceMemoryDecompressionFeaturesNV<'static>,
+    pub(super) physical_device_shading_rate_image_features_nv:
+        vk::PhysicalDeviceShadingRateImageFeaturesNV<'static>,
+    pub(super) physical_device_invocation_mask_features_huawei:
+        vk::PhysicalDeviceInvocationMaskFeaturesHUAWEI<'static>,
+    pub(super) physical_device_mesh_shader_features_nv:
+        vk::PhysicalDeviceMeshShaderFeaturesNV<'static>,
+    pub(super) physical_device_mesh_shader_features_ext:
+        vk::PhysicalDeviceMeshShaderFeaturesEXT<'static>,
+    pub(super) physical_device_acceleration_structure_features_khr:
+        vk::PhysicalDeviceAccelerationStructureFeaturesKHR<'static>,
+    pub(super) physical_device_ray_tracing_pipeline_features_khr:
+        vk::PhysicalDeviceRayTracingPipelineFeaturesKHR<'static>,
+    pub(super) physical_device_ray_query_features_khr:
+        vk::PhysicalDeviceRayQueryFeaturesKHR<'static>,
+    pub(super) physical_device_ray_tracing_maintenance1_features_khr:
+        vk::PhysicalDeviceRayTracingMaintenance1FeaturesKHR<'static>,
+    pub(super) physical_device_fragment_density_map_features_ext:
+        vk::PhysicalDeviceFragmentDensityMapFeaturesEXT<'static>,
+    pub(super) physical_device_fragment_density_map2_features_ext:
+        vk::PhysicalDeviceFragmentDensityMap2FeaturesEXT<'static>,
+    pub(super) physical_device_fragment_density_map_offset_features_qcom:
+        vk::PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM<'static>,
+    pub(super) physical_device_scalar_block_layout_features:
+        vk::PhysicalDeviceScalarBlockLayoutFeatures<'static>,
+    pub(super) physical_device_uniform_buffer_standard_layout_features:
+        vk::PhysicalDeviceUniformBufferStandardLayoutFeatures<'static>,
+    pub(super) physical_device_depth_clip_enable_features_ext:
+        vk::PhysicalDeviceDepthClipEnableFeaturesEXT<'static>,
+    pub(super) physical_device_memory_priority_features_ext:
+        vk::PhysicalDeviceMemoryPriorityFeaturesEXT<'static>,
+    pub(super) physical_device_pageable_device_local_memory_features_ext:
+        vk::PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT<'static>,
+    pub(super) physical_device_buffer_device_address_features:
+        vk::PhysicalDeviceBufferDeviceAddressFeatures<'static>,
+    pub(super) physical_device_buffer_device_address_features_ext:
+        vk::PhysicalDeviceBufferDeviceAddressFeaturesEXT<'static>,
+    pub(super) physical_device_imageless_framebuffer_features:
+        vk::PhysicalDeviceImagelessFramebufferFeatures<'static>,
+    pub(super) physical_device_texture_compression_astchdr_features:
+        vk::PhysicalDeviceTextureCompressionASTCHDRFeatures<'static>,
+    pub(super) physical_device_cooperative_matrix_features_nv:
+        vk::PhysicalDeviceCooperativeMatrixFeaturesNV<'static>,
+    pub(super) physical_device_ycbcr_image_arrays_features_ext:
+        vk::PhysicalDeviceYcbcrImageArraysFeaturesEXT<'static>,
+    pub(super) physical_device_present_barrier_features_nv:
+        vk::PhysicalDevicePresentBarrierFeaturesNV<'static>,
+    pub(super) physical_device_performance_query_features_khr:
+        vk::PhysicalDevicePerformanceQueryFeaturesKHR<'static>,
+    pub(super) physical_device_coverage_reduction_mode_features_nv:
+        vk::PhysicalDeviceCoverageReductionModeFeaturesNV<'static>,
+    pub(super) physical_device_shader_integer_functions2_features_intel:
+        vk::PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL<'static>,
+    pub(super) physical_device_shader_clock_features_khr:
+        vk::PhysicalDeviceShaderClockFeaturesKHR<'static>,
+    pub(super) physical_device_index_type_uint8_features_ext:
+        vk::PhysicalDeviceIndexTypeUint8FeaturesEXT<'static>,
+    pub(super) physical_device_shader_sm_builtins_features_nv:
+        vk::PhysicalDeviceShaderSMBuiltinsFeaturesNV<'static>,
+    pub(super) physical_device_fragment_shader_interlock_features_ext:
+        vk::PhysicalDeviceFragmentShaderInterlockFeaturesEXT<'static>,
+    pub(super) physical_device_separate_depth_stencil_layouts_features:
+        vk::PhysicalDeviceSeparateDepthStencilLayoutsFeatures<'static>,
+    pub(super) physical_device_primitive_topology_list_restart_features_ext:
+        vk::PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT<'static>,
+    pub(super) physical_device_pipeline_executable_properties_features_khr:
+        vk::PhysicalDevicePipelineExecutablePropertiesFeaturesKHR<'static>,
+    pub(super) physical_device_shader_demote_to_helper_invocation_features:
+        vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures<'static>,
+    pub(super) physical_device_texel_buffer_alignment_features_ext:
+        vk::PhysicalDeviceTexelBufferAlignmentFeaturesEXT<'static>,
+    pub(super) physical_device_subgroup_size_control_features:
+        vk::PhysicalDeviceSubgroupSizeControlFeatures<'static>,
+    pub(super) physical_device_line_rasterization_features_ext:
+        vk::PhysicalDeviceLineRasterizationFeaturesEXT<'static>,
+    pub(super) physical_device_pipeline_creation_cache_control_features:
+        vk::PhysicalDevicePipelineCreationCacheControlFeatures<'static>,
+    pub(super) physical_device_vulkan11_features: vk::PhysicalDeviceVulkan11Features<'static>,
+    pub(super) physical_device_vulkan12_features: vk::PhysicalDeviceVulkan12Features<'static>,
+    pub(super) physical_device_vulkan13_features: vk::PhysicalDeviceVulkan13Features<'static>,
+    pub(super) physical_device_coherent_memory_features_amd:
+        vk::PhysicalDeviceCoherentMemoryFeaturesAMD<'static>,
+    pub(super) physical_device_custom_border_color_features_ext:
+        vk::PhysicalDeviceCustomBorderColorFeaturesEXT<'static>,
+    pub(super) physical_device_border_color_swizzle_features_ext:
+        vk::PhysicalDeviceBorderColorSwizzleFeaturesEXT<'static>,
+    pub(super) physical_device_extended_dynamic_state_features_ext:
+        vk::PhysicalDeviceExtendedDynamicStateFeaturesEXT<'static>,
+    pub(super) physical_device_extended_dynamic_state2_features_ext:
+        vk::PhysicalDeviceExtendedDynamicState2FeaturesEXT<'static>,
+    pub(super) physical_device_extended_dynamic_state3_features_ext:
+        vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>,
+    pub(super) physical_device_diagnostics_config_features_nv:
+        vk::PhysicalDeviceDiagnosticsConfigFeaturesNV<'static>,
+    pub(super) physical_device_zero_initialize_workgroup_memory_features:
+        vk::PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures<'static>,
+    pub(super) physical_device_shader_subgroup_uniform_control_flow_features_khr:
+        vk::PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR<'static>,
+    pub(super) physical_device_robustness2_features_ext:
+        vk::PhysicalDeviceRobustness2FeaturesEXT<'static>,
+    pub(super) physical_device_image_robustness_features:
+        vk::PhysicalDeviceImageRobustnessFeatures<'static>,
+    pub(super) physical_device_workgroup_memory_explicit_layout_features_khr:
+        vk::PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR<'static>,
+    pub(super) physical_device_portability_subset_features_khr:
+        vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>,
+    pub(super) physical_device4444_formats_features_ext:
+        vk::PhysicalDevice4444FormatsFeaturesEXT<'static>,
+    pub(super) physical_device_subpass_shading_features_huawei:
+        vk::PhysicalDeviceSubpassShadingFeaturesHUAWEI<'static>,
+    pub(super) physical_device_cluster_culling_shader_features_huawei:
+        vk::PhysicalDeviceClusterCullingShaderFeaturesHUAWEI<'static>,
+    pub(super) physical_device_shader_image_atomic_int64_features_ext:
+        vk::PhysicalDeviceShaderImageAtomicInt64FeaturesEXT<'static>,
+    pub(super) physical_device_fragment_shading_rate_features_khr:
+        vk::PhysicalDeviceFragmentShadingRateFeaturesKHR<'static>,
+    pub(super) physical_device_shader_terminate_invocation_features:
+        vk::PhysicalDeviceShaderTerminateInvocationFeatures<'static>,
+    pub(super) physical_device_fragment_shading_rate_enums_features_nv:
+        vk::PhysicalDeviceFragmentShadingRateEnumsFeaturesNV<'static>,
+    pub(super) physical_device_image2_d_view_of3_d_features_ext:
+        vk::PhysicalDeviceImage2DViewOf3DFeaturesEXT<'static>,
+    pub(super) physical_device_image_sliced_view_of3_d_features_ext:
+        vk::PhysicalDeviceImageSlicedViewOf3DFeaturesEXT<'static>,
+    pub(super) physical_device_attachment_feedback_loop_dynamic_state_features_ext:
+        vk::PhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT<'static>,
+    pub(super) physical_device_mutable_descriptor_type_features_ext:
+        vk::PhysicalDeviceMutableDescriptorTypeFeaturesEXT<'static>,
+    pub(super) physical_device_depth_clip_control_features_ext:
+        vk::PhysicalDeviceDepthClipControlFeaturesEXT<'static>,
+    pub(super) physical_device_vertex_input_dynamic_state_features_ext:
+        vk::PhysicalDeviceVertexInputDynamicStateFeaturesEXT<'static>,
+    pub(super) physical_device_external_memory_rdma_features_nv:
+        vk::PhysicalDeviceExternalMemoryRDMAFeaturesNV<'static>,
+    pub(super) physical_device_color_write_enable_features_ext:
+        vk::PhysicalDeviceColorWriteEnableFeaturesEXT<'static>,
+    pub(super) physical_device_synchronization2_features:
+        vk::PhysicalDeviceSynchronization2Features<'static>,
+    pub(super) physical_device_primitives_generated_query_features_ext:
+        vk::PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT<'static>,
+    pub(super) physical_device_legacy_dithering_features_ext:
+        vk::PhysicalDeviceLegacyDitheringFeaturesEXT<'static>,
+    pub(super) physical_device_multisampled_render_to_single_sampled_features_ext:
+        vk::PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT<'static>,
+    pub(super) physical_device_pipeline_protected_access_features_ext:
+        vk::PhysicalDevicePipelineProtectedAccessFeaturesEXT<'static>,
+    pub(super) physical_device_inherited_viewport_scissor_features_nv:
+        vk::PhysicalDeviceInheritedViewportScissorFeaturesNV<'static>,
+    pub(super) physical_device_ycbcr2_plane444_formats_features_ext:
+        vk::PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT<'static>,
+    pub(super) physical_device_provoking_vertex_features_ext:
+        vk::PhysicalDeviceProvokingVertexFeaturesEXT<'static>,
+    pub(super) physical_device_descriptor_buffer_features_ext:
+        vk::PhysicalDeviceDescriptorBufferFeaturesEXT<'static>,
+    pub(super) physical_device_shader_integer_dot_product_features:
+        vk::PhysicalDeviceShaderIntegerDotProductFeatures<'static>,
+    pub(super) physical_device_fragment_shader_barycentric_features_khr:
+        vk::PhysicalDeviceFragmentShaderBarycentricFeaturesKHR<'static>,
+    pub(super) physical_device_ray_tracing_motion_blur_features_nv:
+        vk::PhysicalDeviceRayTracingMotionBlurFeaturesNV<'static>,
+    pub(super) physical_device_rgba10x6_formats_features_ext:
+        vk::PhysicalDeviceRGBA10X6FormatsFeaturesEXT<'static>,
+    pub(super) physical_device_dynamic_rendering_features:
+        vk::PhysicalDeviceDynamicRenderingFeatures<'static>,
+    pub(super) physical_device_image_view_min_lod_features_ext:
+        vk::PhysicalDeviceImageViewMinLodFeaturesEXT<'static>,
+    pub(super) physical_device_rasterization_order_attachment_access_features_ext:
+        vk::PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT<'static>,
+    pub(super) physical_device_linear_color_attachment_features_nv:
+        vk::PhysicalDeviceLinearColorAttachmentFeaturesNV<'static>,
+    pub(super) physical_device_graphics_pipeline_library_features_ext:
+        vk::PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT<'static>,
+    pub(super) physical_device_descriptor_set_host_mapping_features_valve:
+        vk::PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE<'static>,
+    pub(super) physical_device_shader_module_identifier_features_ext:
+        vk::PhysicalDeviceShaderModuleIdentifierFeaturesEXT<'static>,
+    pub(super) physical_device_image_compression_control_features_ext:
+        vk::PhysicalDeviceImageCompressionControlFeaturesEXT<'static>,
+    pub(super) physical_device_image_compression_control_swapchain_features_ext:
+        vk::PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT<'static>,
+    pub(super) physical_device_subpass_merge_feedback_features_ext:
+        vk::PhysicalDeviceSubpassMergeFeedbackFeaturesEXT<'static>,
+    pub(super) physical_device_opacity_micromap_features_ext:
+        vk::PhysicalDeviceOpacityMicromapFeaturesEXT<'static>,
+    pub(super) physical_device_displacement_micromap_features_nv:
+        vk::PhysicalDeviceDisplacementMicromapFeaturesNV<'static>,
+    pub(super) physical_device_pipeline_properties_features_ext:
+        vk::PhysicalDevicePipelinePropertiesFeaturesEXT<'static>,
+    pub(super) physical_device_shader_early_and_late_fragment_tests_features_amd:
+        vk::PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD<'static>,
+    pub(super) physical_device_non_seamless_cube_map_features_ext:
+        vk::PhysicalDeviceNonSeamlessCubeMapFeaturesEXT<'static>,
+    pub(super) physical_device_pipeline_robustness_features_ext:
+        vk::PhysicalDevicePipelineRobustnessFeaturesEXT<'static>,
+    pub(super) physical_device_image_processing_features_qcom:
+        vk::PhysicalDeviceImageProcessingFeaturesQCOM<'static>,
+    pub(super) physical_device_tile_properties_features_qcom:
+        vk::PhysicalDeviceTilePropertiesFeaturesQCOM<'static>,
+    pub(super) physical_device_amigo_profiling_features_sec:
+        vk::PhysicalDeviceAmigoProfilingFeaturesSEC<'static>,
+    pub(super) physical_device_attachment_feedback_loop_layout_features_ext:
+        vk::PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT<'static>,
+    pub(super) physical_device_depth_clamp_zero_one_features_ext:
+        vk::PhysicalDeviceDepthClampZeroOneFeaturesEXT<'static>,
+    pub(super) physical_device_address_binding_report_features_ext:
+        vk::PhysicalDeviceAddressBindingReportFeaturesEXT<'static>,
+    pub(super) physical_device_optical_flow_features_nv:
+        vk::PhysicalDeviceOpticalFlowFeaturesNV<'static>,
+    pub(super) physical_device_fault_features_ext: vk::PhysicalDeviceFaultFeaturesEXT<'static>,
+    pub(super) physical_device_pipeline_library_group_handles_features_ext:
+        vk::PhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT<'static>,
+    pub(super) physical_device_shader_core_builtins_features_arm:
+        vk::PhysicalDeviceShaderCoreBuiltinsFeaturesARM<'static>,
+    pub(super) physical_device_dynamic_rendering_unused_attachments_features_ext:
+        vk::PhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT<'static>,
+    pub(super) physical_device_swapchain_maintenance1_features_ext:
+        vk::PhysicalDeviceSwapchainMaintenance1FeaturesEXT<'static>,
+    pub(super) physical_device_depth_bias_control_features_ext:
+        vk::PhysicalDeviceDepthBiasControlFeaturesEXT<'static>,
+    pub(super) physical_device_ray_tracing_invocation_reorder_features_nv:
+        vk::PhysicalDeviceRayTracingInvocationReorderFeaturesNV<'static>,
+    pub(super) physical_device_multiview_per_view_viewports_features_qcom:
+        vk::PhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM<'static>,
+    pub(super) physical_device_ray_tracing_position_fetch_features_khr:
+        vk::PhysicalDeviceRayTracingPositionFetchFeaturesKHR<'static>,
+    pub(super) physical_device_multiview_per_view_render_areas_features_qcom:
+        vk::PhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM<'static>,
+    pub(super) physical_device_shader_object_features_ext:
+        vk::PhysicalDeviceShaderObjectFeaturesEXT<'static>,
+    pub(super) physical_device_shader_tile_image_features_ext:
+        vk::PhysicalDeviceShaderTileImageFeaturesEXT<'static>,
+    pub(super) physical_device_external_memory_screen_buffer_features_qnx:
+        vk::PhysicalDeviceExternalMemoryScreenBufferFeaturesQNX<'static>,
+}
+#[doc = r" The versions and extensions that provide each struct."]
+pub(super) const DEPENDS: [vk::Depends; COUNT] = [
+    vk::Depends::NONE,
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_device_generated_commands\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_private_data\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_variable_pointers\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_multiview\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_present_id\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_present_wait\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_16bit_storage\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_shader_subgroup_extended_types\0",
+            )
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_sampler_ycbcr_conversion\0")
+        }),
+    ]),
+    crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_blend_operation_advanced\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_multi_draw\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_inline_uniform_block\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance4\0")
+        }),
+    ]),
+    crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_float16_int8\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_host_query_reset\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_global_priority\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_global_priority_query\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_device_memory_report\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_descriptor_indexing\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_timeline_semaphore\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_8bit_storage\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_conditional_rendering\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_vulkan_memory_model\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_atomic_int64\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_atomic_float\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_atomic_float2\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_vertex_attribute_divisor\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_astc_decode_mode\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_transform_feedback\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_representative_fragment_test\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_scissor_exclusive\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_corner_sampled_image\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_compute_shader_derivatives\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_shader_image_footprint\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+            b"VK_NV_dedicated_allocation_image_aliasing\0",
+        )
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_copy_memory_indirect\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_memory_decompression\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_shading_rate_image\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_HUAWEI_invocation_mask\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_mesh_shader\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_mesh_shader\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_acceleration_structure\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_pipeline\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_query\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_maintenance1\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_fragment_density_map\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_fragment_density_map2\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_fragment_density_map_offset\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_scalar_block_layout\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_uniform_buffer_standard_layout\0",
+            )
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_depth_clip_enable\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_memory_priority\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pageable_device_local_memory\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_buffer_device_address\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_buffer_device_address\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_imageless_framebuffer\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_EXT_texture_compression_astc_hdr\0",
+            )
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_cooperative_matrix\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_ycbcr_image_arrays\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_present_barrier\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_performance_query\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_coverage_reduction_mode\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_INTEL_shader_integer_functions2\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_clock\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_index_type_uint8\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_shader_sm_builtins\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_fragment_shader_interlock\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_separate_depth_stencil_layouts\0",
+            )
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_primitive_topology_list_restart\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_pipeline_executable_properties\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_EXT_shader_demote_to_helper_invocation\0",
+            )
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_texel_buffer_alignment\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_subgroup_size_control\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_line_rasterization\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_EXT_pipeline_creation_cache_control\0",
+            )
+        }),
+    ]),
+    crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+    crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+    crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_device_coherent_memory\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_custom_border_color\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_border_color_swizzle\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_extended_dynamic_state\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_extended_dynamic_state2\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_extended_dynamic_state3\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_device_diagnostics_config\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_zero_initialize_workgroup_memory\0",
+            )
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+            b"VK_KHR_shader_subgroup_uniform_control_flow\0",
+        )
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_robustness2\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_robustness\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+            b"VK_KHR_workgroup_memory_explicit_layout\0",
+        )
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_portability_subset\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_4444_formats\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_HUAWEI_subpass_shading\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_HUAWEI_cluster_culling_shader\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_image_atomic_int64\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_fragment_shading_rate\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_terminate_invocation\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_fragment_shading_rate_enums\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_2d_view_of_3d\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_sliced_view_of_3d\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+            b"VK_EXT_attachment_feedback_loop_dynamic_state\0",
+        )
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_VALVE_mutable_descriptor_type\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_mutable_descriptor_type\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_depth_clip_control\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_vertex_input_dynamic_state\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_external_memory_rdma\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_color_write_enable\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_synchronization2\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_primitives_generated_query\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_legacy_dithering\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+            b"VK_EXT_multisampled_render_to_single_sampled\0",
+        )
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_protected_access\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_inherited_viewport_scissor\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_ycbcr_2plane_444_formats\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_provoking_vertex\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_descriptor_buffer\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_integer_dot_product\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_fragment_shader_barycentric\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_fragment_shader_barycentric\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_ray_tracing_motion_blur\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_rgba10x6_formats\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_dynamic_rendering\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_view_min_lod\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_ARM_rasterization_order_attachment_access\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_EXT_rasterization_order_attachment_access\0",
+            )
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_linear_color_attachment\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_graphics_pipeline_library\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_VALVE_descriptor_set_host_mapping\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_module_identifier\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_compression_control\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+            b"VK_EXT_image_compression_control_swapchain\0",
+        )
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_subpass_merge_feedback\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_opacity_micromap\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_displacement_micromap\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_properties\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+            b"VK_AMD_shader_early_and_late_fragment_tests\0",
+        )
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_non_seamless_cube_map\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_robustness\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_image_processing\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_tile_properties\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_SEC_amigo_profiling\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_attachment_feedback_loop_layout\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_depth_clamp_zero_one\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_device_address_binding_report\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_optical_flow\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_device_fault\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_library_group_handles\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_ARM_shader_core_builtins\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+            b"VK_EXT_dynamic_rendering_unused_attachments\0",
+        )
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_swapchain_maintenance1\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_depth_bias_control\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_ray_tracing_invocation_reorder\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_multiview_per_view_viewports\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_position_fetch\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+            b"VK_QCOM_multiview_per_view_render_areas\0",
+        )
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_object\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_tile_image\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QNX_external_memory_screen_buffer\0")
+    }),
+];
+#[doc = r" The struct of the core version that each struct was promoted to."]
+pub(super) const PROMOTED_TO: [Option<usize>; COUNT] = [
+    None,
+    None,
+    Some(79usize),
+    Some(77usize),
+    Some(77usize),
+    None,
+    None,
+    Some(77usize),
+    Some(78usize),
+    Some(77usize),
+    Some(77usize),
+    None,
+    None,
+    Some(79usize),
+    Some(79usize),
+    Some(77usize),
+    Some(78usize),
+    Some(78usize),
+    None,
+    None,
+    Some(78usize),
+    Some(78usize),
+    Some(78usize),
+    None,
+    Some(78usize),
+    Some(78usize),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(78usize),
+    Some(78usize),
+    None,
+    None,
+    None,
+    Some(78usize),
+    None,
+    Some(78usize),
+    Some(79usize),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(78usize),
+    None,
+    None,
+    Some(79usize),
+    None,
+    Some(79usize),
+    None,
+    Some(79usize),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(79usize),
+    None,
+    None,
+    Some(79usize),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(79usize),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(79usize),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(79usize),
+    None,
+    None,
+    None,
+    Some(79usize),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+];
+impl Structs {
+    pub(super) fn bases(&mut self) -> [*mut vk::BaseOutStructure<'static>; COUNT] {
+        [
+            <*mut _>::cast(&mut self.physical_device_features2),
+            <*mut _>::cast(&mut self.physical_device_device_generated_commands_features_nv),
+            <*mut _>::cast(&mut self.physical_device_private_data_features),
+            <*mut _>::cast(&mut self.physical_device_variable_pointers_features),
+            <*mut _>::cast(&mut self.physical_device_multiview_features),
+            <*mut _>::cast(&mut self.physical_device_present_id_features_khr),
+            <*mut _>::cast(&mut self.physical_device_present_wait_features_khr),
+            <*mut _>::cast(&mut self.physical_device16_bit_storage_features),
+            <*mut _>::cast(&mut self.physical_device_shader_subgroup_extended_types_features),
+            <*mut _>::cast(&mut self.physical_device_sampler_ycbcr_conversion_features),
+            <*mut _>::cast(&mut self.physical_device_protected_memory_features),
+            <*mut _>::cast(&mut self.physical_device_blend_operation_advanced_features_ext),
+            <*mut _>::cast(&mut self.physical_device_multi_draw_features_ext),
+            <*mut _>::cast(&mut self.physical_device_inline_uniform_block_features),
+            <*mut _>::cast(&mut self.physical_device_maintenance4_features),
+            <*mut _>::cast(&mut self.physical_device_shader_draw_parameters_features),
+            <*mut _>::cast(&mut self.physical_device_shader_float16_int8_features),
+            <*mut _>::cast(&mut self.physical_device_host_query_reset_features),
+            <*mut _>::cast(&mut self.physical_device_global_priority_query_features_khr),
+            <*mut _>::cast(&mut self.physical_device_device_memory_report_features_ext),
+            <*mut _>::cast(&mut self.physical_device_descriptor_indexing_features),
+            <*mut _>::cast(&mut self.physical_device_timeline_semaphore_features),
+            <*mut _>::cast(&mut self.physical_device8_bit_storage_features),
+            <*mut _>::cast(&mut self.physical_device_conditional_rendering_features_ext),
+            <*mut _>::cast(&mut self.physical_device_vulkan_memory_model_features),
+            <*mut _>::cast(&mut self.physical_device_shader_atomic_int64_features),
+            <*mut _>::cast(&mut self.physical_device_shader_atomic_float_features_ext),
+            <*mut _>::cast(&mut self.physical_device_shader_atomic_float2_features_ext),
+            <*mut _>::cast(&mut self.physical_device_vertex_attribute_divisor_features_ext),
+            <*mut _>::cast(&mut self.physical_device_astc_decode_features_ext),
+            <*mut _>::cast(&mut self.physical_device_transform_feedback_features_ext),
+            <*mut _>::cast(&mut self.physical_device_representative_fragment_test_features_nv),
+            <*mut _>::cast(&mut self.physical_device_exclusive_scissor_features_nv),
+            <*mut _>::cast(&mut self.physical_device_corner_sampled_image_features_nv),
+            <*mut _>::cast(&mut self.physical_device_compute_shader_derivatives_features_nv),
+            <*mut _>::cast(&mut self.physical_device_shader_image_footprint_features_nv),
+            <*mut _>::cast(
+                &mut self.physical_device_dedicated_allocation_image_aliasing_features_nv,
+            ),
+            <*mut _>::cast(&mut self.physical_device_copy_memory_indirect_features_nv),
+            <*mut _>::cast(&mut self.physical_device_memory_decompression_features_nv),
+            <*mut _>::cast(&mut self.physical_device_shading_rate_image_features_nv),
+            <*mut _>::cast(&mut self.physical_device_invocation_mask_features_huawei),
+            <*mut _>::cast(&mut self.physical_device_mesh_shader_features_nv),
+            <*mut _>::cast(&mut self.physical_device_mesh_shader_features_ext),
+            <*mut _>::cast(&mut self.physical_device_acceleration_structure_features_khr),
+            <*mut _>::cast(&mut self.physical_device_ray_tracing_pipeline_features_khr),
+            <*mut _>::cast(&mut self.physical_device_ray_query_features_khr),
+            <*mut _>::cast(&mut self.physical_device_ray_tracing_maintenance1_features_khr),
+            <*mut _>::cast(&mut self.physical_device_fragment_density_map_features_ext),
+            <*mut _>::cast(&mut self.physical_device_fragment_density_map2_features_ext),
+            <*mut _>::cast(&mut self.physical_device_fragment_density_map_offset_features_qcom),
+            <*mut _>::cast(&mut self.physical_device_scalar_block_layout_features),
+            <*mut _>::cast(&mut self.physical_device_uniform_buffer_standard_layout_features),
+            <*mut _>::cast(&mut self.physical_device_depth_clip_enable_features_ext),
+            <*mut _>::cast(&mut self.physical_device_memory_priority_features_ext),
+            <*mut _>::cast(&mut self.physical_device_pageable_device_local_memory_features_ext),
+            <*mut _>::cast(&mut self.physical_device_buffer_device_address_features),
+            <*mut _>::cast(&mut self.physical_device_buffer_device_address_features_ext),
+            <*mut _>::cast(&mut self.physical_device_imageless_framebuffer_features),
+            <*mut _>::cast(&mut self.physical_device_texture_compression_astchdr_features),
+            <*mut _>::cast(&mut self.physical_device_cooperative_matrix_features_nv),
+            <*mut _>::cast(&mut self.physical_device_ycbcr_image_arrays_features_ext),
+            <*mut _>::cast(&mut self.physical_device_present_barrier_features_nv),
+            <*mut _>::cast(&mut self.physical_device_performance_query_features_khr),
+            <*mut _>::cast(&mut self.physical_device_coverage_reduction_mode_features_nv),
+            <*mut _>::cast(&mut self.physical_device_shader_integer_functions2_features_intel),
+            <*mut _>::cast(&mut self.physical_device_shader_clock_features_khr),
+            <*mut _>::cast(&mut self.physical_device_index_type_uint8_features_ext),
+            <*mut _>::cast(&mut self.physical_device_shader_sm_builtins_features_nv),
+            <*mut _>::cast(&mut self.physical_device_fragment_shader_interlock_features_ext),
+            <*mut _>::cast(&mut self.physical_device_separate_depth_stencil_layouts_features),
+            <*mut _>::cast(&mut self.physical_device_primitive_topology_list_restart_features_ext),
+            <*mut _>::cast(&mut self.physical_device_pipeline_executable_properties_features_khr),
+            <*mut _>::cast(&mut self.physical_device_shader_demote_to_helper_invocation_features),
+            <*mut _>::cast(&mut self.physical_device_texel_buffer_alignment_features_ext),
+            <*mut _>::cast(&mut self.physical_device_subgroup_size_control_features),
+            <*mut _>::cast(&mut self.physical_device_line_rasterization_features_ext),
+            <*mut _>::cast(&mut self.physical_device_pipeline_creation_cache_control_features),
+            <*mut _>::cast(&mut self.physical_device_vulkan11_features),
+            <*mut _>::cast(&mut self.physical_device_vulkan12_features),
+            <*mut _>::cast(&mut self.physical_device_vulkan13_features),
+            <*mut _>::cast(&mut self.physical_device_coherent_memory_features_amd),
+            <*mut _>::cast(&mut self.physical_device_custom_border_color_features_ext),
+            <*mut _>::cast(&mut self.physical_device_border_color_swizzle_features_ext),
+            <*mut _>::cast(&mut self.physical_device_extended_dynamic_state_features_ext),
+            <*mut _>::cast(&mut self.physical_device_extended_dynamic_state2_features_ext),
+            <*mut _>::cast(&mut self.physical_device_extended_dynamic_state3_features_ext),
+            <*mut _>::cast(&mut self.physical_device_diagnostics_config_features_nv),
+            <*mut _>::cast(&mut self.physical_device_zero_initialize_workgroup_memory_features),
+            <*mut _>::cast(
+                &mut self.physical_device_shader_subgroup_uniform_control_flow_features_khr,
+            ),
+            <*mut _>::cast(&mut self.physical_device_robustness2_features_ext),
+            <*mut _>::cast(&mut self.physical_device_image_robustness_features),
+            <*mut _>::cast(&mut self.physical_device_workgroup_memory_explicit_layout_features_khr),
+            <*mut _>::cast(&mut self.physical_device_portability_subset_features_khr),
+            <*mut _>::cast(&mut self.physical_device4444_formats_features_ext),
+            <*mut _>::cast(&mut self.physical_device_subpass_shading_features_huawei),
+            <*mut _>::cast(&mut self.physical_device_cluster_culling_shader_features_huawei),
+            <*mut _>::cast(&mut self.physical_device_shader_image_atomic_int64_features_ext),
+            <*mut _>::cast(&mut self.physical_device_fragment_shading_rate_features_khr),
+            <*mut _>::cast(&mut self.physical_device_shader_terminate_invocation_features),
+            <*mut _>::cast(&mut self.physical_device_fragment_shading_rate_enums_features_nv),
+            <*mut _>::cast(&mut self.physical_device_image2_d_view_of3_d_features_ext),
+            <*mut _>::cast(&mut self.physical_device_image_sliced_view_of3_d_features_ext),
+            <*mut _>::cast(
+                &mut self.physical_device_attachment_feedback_loop_dynamic_state_features_ext,
+            ),
+            <*mut _>::cast(&mut self.physical_device_mutable_descriptor_type_features_ext),
+            <*mut _>::cast(&mut self.physical_device_depth_clip_control_features_ext),
+            <*mut _>::cast(&mut self.physical_device_vertex_input_dynamic_state_features_ext),
+            <*mut _>::cast(&mut self.physical_device_external_memory_rdma_features_nv),
+            <*mut _>::cast(&mut self.physical_device_color_write_enable_features_ext),
+            <*mut _>::cast(&mut self.physical_device_synchronization2_features),
+            <*mut _>::cast(&mut self.physical_device_primitives_generated_query_features_ext),
+            <*mut _>::cast(&mut self.physical_device_legacy_dithering_features_ext),
+            <*mut _>::cast(
+                &mut self.physical_device_multisampled_render_to_single_sampled_features_ext,
+            ),
+            <*mut _>::cast(&mut self.physical_device_pipeline_protected_access_features_ext),
+            <*mut _>::cast(&mut self.physical_device_inherited_viewport_scissor_features_nv),
+            <*mut _>::cast(&mut self.physical_device_ycbcr2_plane444_formats_features_ext),
+            <*mut _>::cast(&mut self.physical_device_provoking_vertex_features_ext),
+            <*mut _>::cast(&mut self.physical_device_descriptor_buffer_features_ext),
+            <*mut _>::cast(&mut self.physical_device_shader_integer_dot_product_features),
+            <*mut _>::cast(&mut self.physical_device_fragment_shader_barycentric_features_khr),
+            <*mut _>::cast(&mut self.physical_device_ray_tracing_motion_blur_features_nv),
+            <*mut _>::cast(&mut self.physical_device_rgba10x6_formats_features_ext),
+            <*mut _>::cast(&mut self.physical_device_dynamic_rendering_features),
+            <*mut _>::cast(&mut self.physical_device_image_view_min_lod_features_ext),
+            <*mut _>::cast(
+                &mut self.physical_device_rasterization_order_attachment_access_features_ext,
+            ),
+            <*mut _>::cast(&mut self.physical_device_linear_color_attachment_features_nv),
+            <*mut _>::cast(&mut self.physical_device_graphics_pipeline_library_features_ext),
+            <*mut _>::cast(&mut self.physical_device_descriptor_set_host_mapping_features_valve),
+            <*mut _>::cast(&mut self.physical_device_shader_module_identifier_features_ext),
+            <*mut _>::cast(&mut self.physical_device_image_compression_control_features_ext),
+            <*mut _>::cast(
+                &mut self.physical_device_image_compression_control_swapchain_features_ext,
+            ),
+            <*mut _>::cast(&mut self.physical_device_subpass_merge_feedback_features_ext),
+            <*mut _>::cast(&mut self.physical_device_opacity_micromap_features_ext),
+            <*mut _>::cast(&mut self.physical_device_displacement_micromap_features_nv),
+            <*mut _>::cast(&mut self.physical_device_pipeline_properties_features_ext),
+            <*mut _>::cast(
+                &mut self.physical_device_shader_early_and_late_fragment_tests_features_amd,
+            ),
+            <*mut _>::cast(&mut self.physical_device_non_seamless_cube_map_features_ext),
+            <*mut _>::cast(&mut self.physical_device_pipeline_robustness_features_ext),
+            <*mut _>::cast(&mut self.physical_device_image_processing_features_qcom),
+            <*mut _>::cast(&mut self.physical_device_tile_properties_features_qcom),
+            <*mut _>::cast(&mut self.physical_device_amigo_profiling_features_sec),
+            <*mut _>::cast(&mut self.physical_device_attachment_feedback_loop_layout_features_ext),
+            <*mut _>::cast(&mut self.physical_device_depth_clamp_zero_one_features_ext),
+            <*mut _>::cast(&mut self.physical_device_address_binding_report_features_ext),
+            <*mut _>::cast(&mut self.physical_device_optical_flow_features_nv),
+            <*mut _>::cast(&mut self.physical_device_fault_features_ext),
+            <*mut _>::cast(&mut self.physical_device_pipeline_library_group_handles_features_ext),
+            <*mut _>::cast(&mut self.physical_device_shader_core_builtins_features_arm),
+            <*mut _>::cast(
+                &mut self.physical_device_dynamic_rendering_unused_attachments_features_ext,
+            ),
+            <*mut _>::cast(&mut self.physical_device_swapchain_maintenance1_features_ext),
+            <*mut _>::cast(&mut self.physical_device_depth_bias_control_features_ext),
+            <*mut _>::cast(&mut self.physical_device_ray_tracing_invocation_reorder_features_nv),
+            <*mut _>::cast(&mut self.physical_device_multiview_per_view_viewports_features_qcom),
+            <*mut _>::cast(&mut self.physical_device_ray_tracing_position_fetch_features_khr),
+            <*mut _>::cast(&mut self.physical_device_multiview_per_view_render_areas_features_qcom),
+            <*mut _>::cast(&mut self.physical_device_shader_object_features_ext),
+            <*mut _>::cast(&mut self.physical_device_shader_tile_image_features_ext),
+            <*mut _>::cast(&mut self.physical_device_external_memory_screen_buffer_features_qnx),
+        ]
+    }
+    #[doc = r" Calls `f` with the index of the struct, the name and the values in `self` and"]
+    #[doc = r" `other` of every feature."]
+    pub(super) fn zip(
+        &self,
+        other: &Self,
+        mut f: impl FnMut(usize, &'static str, vk::Bool32, vk::Bool32),
+    ) {
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::robustBufferAccess",
+            self.physical_device_features2.features.robust_buffer_access,
+            other
+                .physical_device_features2
+                .features
+                .robust_buffer_access,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::fullDrawIndexUint32",
+            self.physical_device_features2
+                .features
+                .full_draw_index_uint32,
+            other
+                .physical_device_features2
+                .features
+                .full_draw_index_uint32,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::imageCubeArray",
+            self.physical_device_features2.features.image_cube_array,
+            other.physical_device_features2.features.image_cube_array,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::independentBlend",
+            self.physical_device_features2.features.independent_blend,
+            other.physical_device_features2.features.independent_blend,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::geometryShader",
+            self.physical_device_features2.features.geometry_shader,
+            other.physical_device_features2.features.geometry_shader,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::tessellationShader",
+            self.physical_device_features2.features.tessellation_shader,
+            other.physical_device_features2.features.tessellation_shader,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sampleRateShading",
+            self.physical_device_features2.features.sample_rate_shading,
+            other.physical_device_features2.features.sample_rate_shading,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::dualSrcBlend",
+            self.physical_device_features2.features.dual_src_blend,
+            other.physical_device_features2.features.dual_src_blend,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::logicOp",
+            self.physical_device_features2.features.logic_op,
+            other.physical_device_features2.features.logic_op,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::multiDrawIndirect",
+            self.physical_device_features2.features.multi_draw_indirect,
+            other.physical_device_features2.features.multi_draw_indirect,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::drawIndirectFirstInstance",
+            self.physical_device_features2
+                .features
+                .draw_indirect_first_instance,
+            other
+                .physical_device_features2
+                .features
+                .draw_indirect_first_instance,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::depthClamp",
+            self.physical_device_features2.features.depth_clamp,
+            other.physical_device_features2.features.depth_clamp,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::depthBiasClamp",
+            self.physical_device_features2.features.depth_bias_clamp,
+            other.physical_device_features2.features.depth_bias_clamp,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::fillModeNonSolid",
+            self.physical_device_features2.features.fill_mode_non_solid,
+            other.physical_device_features2.features.fill_mode_non_solid,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::depthBounds",
+            self.physical_device_features2.features.depth_bounds,
+            other.physical_device_features2.features.depth_bounds,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::wideLines",
+            self.physical_device_features2.features.wide_lines,
+            other.physical_device_features2.features.wide_lines,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::largePoints",
+            self.physical_device_features2.features.large_points,
+            other.physical_device_features2.features.large_points,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::alphaToOne",
+            self.physical_device_features2.features.alpha_to_one,
+            other.physical_device_features2.features.alpha_to_one,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::multiViewport",
+            self.physical_device_features2.features.multi_viewport,
+            other.physical_device_features2.features.multi_viewport,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::samplerAnisotropy",
+            self.physical_device_features2.features.sampler_anisotropy,
+            other.physical_device_features2.features.sampler_anisotropy,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::textureCompressionETC2",
+            self.physical_device_features2
+                .features
+                .texture_compression_etc2,
+            other
+                .physical_device_features2
+                .features
+                .texture_compression_etc2,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::textureCompressionASTC_LDR",
+            self.physical_device_features2
+                .features
+                .texture_compression_astc_ldr,
+            other
+                .physical_device_features2
+                .features
+                .texture_compression_astc_ldr,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::textureCompressionBC",
+            self.physical_device_features2
+                .features
+                .texture_compression_bc,
+            other
+                .physical_device_features2
+                .features
+                .texture_compression_bc,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::occlusionQueryPrecise",
+            self.physical_device_features2
+                .features
+                .occlusion_query_precise,
+            other
+                .physical_device_features2
+                .features
+                .occlusion_query_precise,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::pipelineStatisticsQuery",
+            self.physical_device_features2
+                .features
+                .pipeline_statistics_query,
+            other
+                .physical_device_features2
+                .features
+                .pipeline_statistics_query,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::vertexPipelineStoresAndAtomics",
+            self.physical_device_features2
+                .features
+                .vertex_pipeline_stores_and_atomics,
+            other
+                .physical_device_features2
+                .features
+                .vertex_pipeline_stores_and_atomics,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::fragmentStoresAndAtomics",
+            self.physical_device_features2
+                .features
+                .fragment_stores_and_atomics,
+            other
+                .physical_device_features2
+                .features
+                .fragment_stores_and_atomics,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderTessellationAndGeometryPointSize",
+            self.physical_device_features2
+                .features
+                .shader_tessellation_and_geometry_point_size,
+            other
+                .physical_device_features2
+                .features
+                .shader_tessellation_and_geometry_point_size,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderImageGatherExtended",
+            self.physical_device_features2
+                .features
+                .shader_image_gather_extended,
+            other
+                .physical_device_features2
+                .features
+                .shader_image_gather_extended,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageImageExtendedFormats",
+            self.physical_device_features2
+                .features
+                .shader_storage_image_extended_formats,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_image_extended_formats,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageImageMultisample",
+            self.physical_device_features2
+                .features
+                .shader_storage_image_multisample,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_image_multisample,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageImageReadWithoutFormat",
+            self.physical_device_features2
+                .features
+                .shader_storage_image_read_without_format,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_image_read_without_format,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageImageWriteWithoutFormat",
+            self.physical_device_features2
+                .features
+                .shader_storage_image_write_without_format,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_image_write_without_format,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderUniformBufferArrayDynamicIndexing",
+            self.physical_device_features2
+                .features
+                .shader_uniform_buffer_array_dynamic_indexing,
+            other
+                .physical_device_features2
+                .features
+                .shader_uniform_buffer_array_dynamic_indexing,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderSampledImageArrayDynamicIndexing",
+            self.physical_device_features2
+                .features
+                .shader_sampled_image_array_dynamic_indexing,
+            other
+                .physical_device_features2
+                .features
+                .shader_sampled_image_array_dynamic_indexing,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageBufferArrayDynamicIndexing",
+            self.physical_device_features2
+                .features
+                .shader_storage_buffer_array_dynamic_indexing,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_buffer_array_dynamic_indexing,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageImageArrayDynamicIndexing",
+            self.physical_device_features2
+                .features
+                .shader_storage_image_array_dynamic_indexing,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_image_array_dynamic_indexing,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderClipDistance",
+            self.physical_device_features2.features.shader_clip_distance,
+            other
+                .physical_device_features2
+                .features
+                .shader_clip_distance,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderCullDistance",
+            self.physical_device_features2.features.shader_cull_distance,
+            other
+                .physical_device_features2
+                .features
+                .shader_cull_distance,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderFloat64",
+            self.physical_device_features2.features.shader_float64,
+            other.physical_device_features2.features.shader_float64,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderInt64",
+            self.physical_device_features2.features.shader_int64,
+            other.physical_device_features2.features.shader_int64,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderInt16",
+            self.physical_device_features2.features.shader_int16,
+            other.physical_device_features2.features.shader_int16,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderResourceResidency",
+            self.physical_device_features2
+                .features
+                .shader_resource_residency,
+            other
+                .physical_device_features2
+                .features
+                .shader_resource_residency,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderResourceMinLod",
+            self.physical_device_features2
+                .features
+                .shader_resource_min_lod,
+            other
+                .physical_device_features2
+                .features
+                .shader_resource_min_lod,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseBinding",
+            self.physical_device_features2.features.sparse_binding,
+            other.physical_device_features2.features.sparse_binding,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidencyBuffer",
+            self.physical_device_features2
+                .features
+                .sparse_residency_buffer,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency_buffer,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidencyImage2D",
+            self.physical_device_features2
+                .features
+                .sparse_residency_image2_d,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency_image2_d,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidencyImage3D",
+            self.physical_device_features2
+                .features
+                .sparse_residency_image3_d,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency_image3_d,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidency2Samples",
+            self.physical_device_features2
+                .features
+                .sparse_residency2_samples,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency2_samples,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidency4Samples",
+            self.physical_device_features2
+                .features
+                .sparse_residency4_samples,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency4_samples,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidency8Samples",
+            self.physical_device_features2
+                .features
+                .sparse_residency8_samples,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency8_samples,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidency16Samples",
+            self.physical_device_features2
+                .features
+                .sparse_residency16_samples,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency16_samples,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidencyAliased",
+            self.physical_device_features2
+                .features
+                .sparse_residency_aliased,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency_aliased,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::variableMultisampleRate",
+            self.physical_device_features2
+                .features
+                .variable_multisample_rate,
+            other
+                .physical_device_features2
+                .features
+                .variable_multisample_rate,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::inheritedQueries",
+            self.physical_device_features2.features.inherited_queries,
+            other.physical_device_features2.features.inherited_queries,
+        );
+        f(
+            1usize,
+            "VkPhysicalDeviceDeviceGeneratedCommandsFeaturesNV::deviceGeneratedCommands",
+            self.physical_device_device_generated_commands_features_nv
+                .device_generated_commands,
+            other
+                .physical_device_device_generated_commands_features_nv
+                .device_generated_commands,
+        );
+        f(
+            2usize,
+            "VkPhysicalDevicePrivateDataFeatures::privateData",
+            self.physical_device_private_data_features.private_data,
+            other.physical_device_private_data_features.private_data,
+        );
+        f(
+            3usize,
+            "VkPhysicalDeviceVariablePointersFeatures::variablePointersStorageBuffer",
+            self.physical_device_variable_pointers_features
+                .variable_pointers_storage_buffer,
+            other
+                .physical_device_variable_pointers_features
+                .variable_pointers_storage_buffer,
+        );
+        f(
+            3usize,
+            "VkPhysicalDeviceVariablePointersFeatures::variablePointers",
+            self.physical_device_variable_pointers_features
+                .variable_pointers,
+            other
+                .physical_device_variable_pointers_features
+                .variable_pointers,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceMultiviewFeatures::multiview",
+            self.physical_device_multiview_features.multiview,
+            other.physical_device_multiview_features.multiview,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceMultiviewFeatures::multiviewGeometryShader",
+            self.physical_device_multiview_features
+                .multiview_geometry_shader,
+            other
+                .physical_device_multiview_features
+                .multiview_geometry_shader,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceMultiviewFeatures::multiviewTessellationShader",
+            self.physical_device_multiview_features
+                .multiview_tessellation_shader,
+            other
+                .physical_device_multiview_features
+                .multiview_tessellation_shader,
+        );
+        f(
+            5usize,
+            "VkPhysicalDevicePresentIdFeaturesKHR::presentId",
+            self.physical_device_present_id_features_khr.present_id,
+            other.physical_device_present_id_features_khr.present_id,
+        );
+        f(
+            6usize,
+            "VkPhysicalDevicePresentWaitFeaturesKHR::presentWait",
+            self.physical_device_present_wait_features_khr.present_wait,
+            other.physical_device_present_wait_features_khr.present_wait,
+        );
+        f(
+            7usize,
+            "VkPhysicalDevice16BitStorageFeatures::storageBuffer16BitAccess",
+            self.physical_device16_bit_storage_features
+                .storage_buffer16_bit_access,
+            other
+                .physical_device16_bit_storage_features
+                .storage_buffer16_bit_access,
+        );
+        f(
+            7usize,
+            "VkPhysicalDevice16BitStorageFeatures::uniformAndStorageBuffer16BitAccess",
+            self.physical_device16_bit_storage_features
+                .uniform_and_storage_buffer16_bit_access,
+            other
+                .physical_device16_bit_storage_features
+                .uniform_and_storage_buffer16_bit_access,
+        );
+        f(
+            7usize,
+            "VkPhysicalDevice16BitStorageFeatures::storagePushConstant16",
+            self.physical_device16_bit_storage_features
+                .storage_push_constant16,
+            other
+                .physical_device16_bit_storage_features
+                .storage_push_constant16,
+        );
+        f(
+            7usize,
+            "VkPhysicalDevice16BitStorageFeatures::storageInputOutput16",
+            self.physical_device16_bit_storage_features
+                .storage_input_output16,
+            other
+                .physical_device16_bit_storage_features
+                .storage_input_output16,
+        );
+        f(
+            8usize,
+            "VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures::shaderSubgroupExtendedTypes",
+            self.physical_device_shader_subgroup_extended_types_features
+                .shader_subgroup_extended_types,
+            other
+                .physical_device_shader_subgroup_extended_types_features
+                .shader_subgroup_extended_types,
+        );
+        f(
+            9usize,
+            "VkPhysicalDeviceSamplerYcbcrConversionFeatures::samplerYcbcrConversion",
+            self.physical_device_sampler_ycbcr_conversion_features
+                .sampler_ycbcr_conversion,
+            other
+                .physical_device_sampler_ycbcr_conversion_features
+                .sampler_ycbcr_conversion,
+        );
+        f(
+            10usize,
+            "VkPhysicalDeviceProtectedMemoryFeatures::protectedMemory",
+            self.physical_device_protected_memory_features
+                .protected_memory,
+            other
+                .physical_device_protected_memory_features
+                .protected_memory,
+        );
+        f(
+            11usize,
+            "VkPhysicalDeviceBlendOperationAdvancedFeaturesEXT::advancedBlendCoherentOperations",
+            self.physical_device_blend_operation_advanced_features_ext
+                .advanced_blend_coherent_operations,
+            other
+                .physical_device_blend_operation_advanced_features_ext
+                .advanced_blend_coherent_operations,
+        );
+        f(
+            12usize,
+            "VkPhysicalDeviceMultiDrawFeaturesEXT::multiDraw",
+            self.physical_device_multi_draw_features_ext.multi_draw,
+            other.physical_device_multi_draw_features_ext.multi_draw,
+        );
+        f(
+            13usize,
+            "VkPhysicalDeviceInlineUniformBlockFeatures::inlineUniformBlock",
+            self.physical_device_inline_uniform_block_features
+                .inline_uniform_block,
+            other
+                .physical_device_inline_uniform_block_features
+                .inline_uniform_block,
+        );
+        f (13usize , "VkPhysicalDeviceInlineUniformBlockFeatures::descriptorBindingInlineUniformBlockUpdateAfterBind" , self . physical_device_inline_uniform_block_features . descriptor_binding_inline_uniform_block_update_after_bind , other . physical_device_inline_uniform_block_features . descriptor_binding_inline_uniform_block_update_after_bind) ;
+        f(
+            14usize,
+            "VkPhysicalDeviceMaintenance4Features::maintenance4",
+            self.physical_device_maintenance4_features.maintenance4,
+            other.physical_device_maintenance4_features.maintenance4,
+        );
+        f(
+            15usize,
+            "VkPhysicalDeviceShaderDrawParametersFeatures::shaderDrawParameters",
+            self.physical_device_shader_draw_parameters_features
+                .shader_draw_parameters,
+            other
+                .physical_device_shader_draw_parameters_features
+                .shader_draw_parameters,
+        );
+        f(
+            16usize,
+            "VkPhysicalDeviceShaderFloat16Int8Features::shaderFloat16",
+            self.physical_device_shader_float16_int8_features
+                .shader_float16,
+            other
+                .physical_device_shader_float16_int8_features
+                .shader_float16,
+        );
+        f(
+            16usize,
+            "VkPhysicalDeviceShaderFloat16Int8Features::shaderInt8",
+            self.physical_device_shader_float16_int8_features
+                .shader_int8,
+            other
+                .physical_device_shader_float16_int8_features
+                .shader_int8,
+        );
+        f(
+            17usize,
+            "VkPhysicalDeviceHostQueryResetFeatures::hostQueryReset",
+            self.physical_device_host_query_reset_features
+                .host_query_reset,
+            other
+                .physical_device_host_query_reset_features
+                .host_query_reset,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR::globalPriorityQuery",
+            self.physical_device_global_priority_query_features_khr
+                .global_priority_query,
+            other
+                .physical_device_global_priority_query_features_khr
+                .global_priority_query,
+        );
+        f(
+            19usize,
+            "VkPhysicalDeviceDeviceMemoryReportFeaturesEXT::deviceMemoryReport",
+            self.physical_device_device_memory_report_features_ext
+                .device_memory_report,
+            other
+                .physical_device_device_memory_report_features_ext
+                .device_memory_report,
+        );
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::shaderInputAttachmentArrayDynamicIndexing",
+            self.physical_device_descriptor_indexing_features
+                .shader_input_attachment_array_dynamic_indexing,
+            other
+                .physical_device_descriptor_indexing_features
+                .shader_input_attachment_array_dynamic_indexing,
+        );
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderUniformTexelBufferArrayDynamicIndexing" , self . physical_device_descriptor_indexing_features . shader_uniform_texel_buffer_array_dynamic_indexing , other . physical_device_descriptor_indexing_features . shader_uniform_texel_buffer_array_dynamic_indexing) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderStorageTexelBufferArrayDynamicIndexing" , self . physical_device_descriptor_indexing_features . shader_storage_texel_buffer_array_dynamic_indexing , other . physical_device_descriptor_indexing_features . shader_storage_texel_buffer_array_dynamic_indexing) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderUniformBufferArrayNonUniformIndexing" , self . physical_device_descriptor_indexing_features . shader_uniform_buffer_array_non_uniform_indexing , other . physical_device_descriptor_indexing_features . shader_uniform_buffer_array_non_uniform_indexing) ;
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::shaderSampledImageArrayNonUniformIndexing",
+            self.physical_device_descriptor_indexing_features
+                .shader_sampled_image_array_non_uniform_indexing,
+            other
+                .physical_device_descriptor_indexing_features
+                .shader_sampled_image_array_non_uniform_indexing,
+        );
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderStorageBufferArrayNonUniformIndexing" , self . physical_device_descriptor_indexing_features . shader_storage_buffer_array_non_uniform_indexing , other . physical_device_descriptor_indexing_features . shader_storage_buffer_array_non_uniform_indexing) ;
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::shaderStorageImageArrayNonUniformIndexing",
+            self.physical_device_descriptor_indexing_features
+                .shader_storage_image_array_non_uniform_indexing,
+            other
+                .physical_device_descriptor_indexing_features
+                .shader_storage_image_array_non_uniform_indexing,
+        );
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderInputAttachmentArrayNonUniformIndexing" , self . physical_device_descriptor_indexing_features . shader_input_attachment_array_non_uniform_indexing , other . physical_device_descriptor_indexing_features . shader_input_attachment_array_non_uniform_indexing) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderUniformTexelBufferArrayNonUniformIndexing" , self . physical_device_descriptor_indexing_features . shader_uniform_texel_buffer_array_non_uniform_indexing , other . physical_device_descriptor_indexing_features . shader_uniform_texel_buffer_array_non_uniform_indexing) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderStorageTexelBufferArrayNonUniformIndexing" , self . physical_device_descriptor_indexing_features . shader_storage_texel_buffer_array_non_uniform_indexing , other . physical_device_descriptor_indexing_features . shader_storage_texel_buffer_array_non_uniform_indexing) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingUniformBufferUpdateAfterBind" , self . physical_device_descriptor_indexing_features . descriptor_binding_uniform_buffer_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_uniform_buffer_update_after_bind) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingSampledImageUpdateAfterBind" , self . physical_device_descriptor_indexing_features . descriptor_binding_sampled_image_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_sampled_image_update_after_bind) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingStorageImageUpdateAfterBind" , self . physical_device_descriptor_indexing_features . descriptor_binding_storage_image_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_storage_image_update_after_bind) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingStorageBufferUpdateAfterBind" , self . physical_device_descriptor_indexing_features . descriptor_binding_storage_buffer_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_storage_buffer_update_after_bind) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingUniformTexelBufferUpdateAfterBind" , self . physical_device_descriptor_indexing_features . descriptor_binding_uniform_texel_buffer_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_uniform_texel_buffer_update_after_bind) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingStorageTexelBufferUpdateAfterBind" , self . physical_device_descriptor_indexing_features . descriptor_binding_storage_texel_buffer_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_storage_texel_buffer_update_after_bind) ;
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingUpdateUnusedWhilePending",
+            self.physical_device_descriptor_indexing_features
+                .descriptor_binding_update_unused_while_pending,
+            other
+                .physical_device_descriptor_indexing_features
+                .descriptor_binding_update_unused_while_pending,
+        );
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingPartiallyBound",
+            self.physical_device_descriptor_indexing_features
+                .descriptor_binding_partially_bound,
+            other
+                .physical_device_descriptor_indexing_features
+                .descriptor_binding_partially_bound,
+        );
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingVariableDescriptorCount",
+            self.physical_device_descriptor_indexing_features
+                .descriptor_binding_variable_descriptor_count,
+            other
+                .physical_device_descriptor_indexing_features
+                .descriptor_binding_variable_descriptor_count,
+        );
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::runtimeDescriptorArray",
+            self.physical_device_descriptor_indexing_features
+                .runtime_descriptor_array,
+            other
+                .physical_device_descriptor_indexing_features
+                .runtime_descriptor_array,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceTimelineSemaphoreFeatures::timelineSemaphore",
+            self.physical_device_timeline_semaphore_features
+                .timeline_semaphore,
+            other
+                .physical_device_timeline_semaphore_features
+                .timeline_semaphore,
+        );
+        f(
+            22usize,
+            "VkPhysicalDevice8BitStorageFeatures::storageBuffer8BitAccess",
+            self.physical_device8_bit_storage_features
+                .storage_buffer8_bit_access,
+            other
+                .physical_device8_bit_storage_features
+                .storage_buffer8_bit_access,
+        );
+        f(
+            22usize,
+            "VkPhysicalDevice8BitStorageFeatures::uniformAndStorageBuffer8BitAccess",
+            self.physical_device8_bit_storage_features
+                .uniform_and_storage_buffer8_bit_access,
+            other
+                .physical_device8_bit_storage_features
+                .uniform_and_storage_buffer8_bit_access,
+        );
+        f(
+            22usize,
+            "VkPhysicalDevice8BitStorageFeatures::storagePushConstant8",
+            self.physical_device8_bit_storage_features
+                .storage_push_constant8,
+            other
+                .physical_device8_bit_storage_features
+                .storage_push_constant8,
+        );
+        f(
+            23usize,
+            "VkPhysicalDeviceConditionalRenderingFeaturesEXT::conditionalRendering",
+            self.physical_device_conditional_rendering_features_ext
+                .conditional_rendering,
+            other
+                .physical_device_conditional_rendering_features_ext
+                .conditional_rendering,
+        );
+        f(
+            23usize,
+            "VkPhysicalDeviceConditionalRenderingFeaturesEXT::inheritedConditionalRendering",
+            self.physical_device_conditional_rendering_features_ext
+                .inherited_conditional_rendering,
+            other
+                .physical_device_conditional_rendering_features_ext
+                .inherited_conditional_rendering,
+        );
+        f(
+            24usize,
+            "VkPhysicalDeviceVulkanMemoryModelFeatures::vulkanMemoryModel",
+            self.physical_device_vulkan_memory_model_features
+                .vulkan_memory_model,
+            other
+                .physical_device_vulkan_memory_model_features
+                .vulkan_memory_model,
+        );
+        f(
+            24usize,
+            "VkPhysicalDeviceVulkanMemoryModelFeatures::vulkanMemoryModelDeviceScope",
+            self.physical_device_vulkan_memory_model_features
+                .vulkan_memory_model_device_scope,
+            other
+                .physical_device_vulkan_memory_model_features
+                .vulkan_memory_model_device_scope,
+        );
+        f (24usize , "VkPhysicalDeviceVulkanMemoryModelFeatures::vulkanMemoryModelAvailabilityVisibilityChains" , self . physical_device_vulkan_memory_model_features . vulkan_memory_model_availability_visibility_chains , other . physical_device_vulkan_memory_model_features . vulkan_memory_model_availability_visibility_chains) ;
+        f(
+            25usize,
+            "VkPhysicalDeviceShaderAtomicInt64Features::shaderBufferInt64Atomics",
+            self.physical_device_shader_atomic_int64_features
+                .shader_buffer_int64_atomics,
+            other
+                .physical_device_shader_atomic_int64_features
+                .shader_buffer_int64_atomics,
+        );
+        f(
+            25usize,
+            "VkPhysicalDeviceShaderAtomicInt64Features::shaderSharedInt64Atomics",
+            self.physical_device_shader_atomic_int64_features
+                .shader_shared_int64_atomics,
+            other
+                .physical_device_shader_atomic_int64_features
+                .shader_shared_int64_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderBufferFloat32Atomics",
+            self.physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float32_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float32_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderBufferFloat32AtomicAdd",
+            self.physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float32_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float32_atomic_add,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderBufferFloat64Atomics",
+            self.physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float64_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float64_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderBufferFloat64AtomicAdd",
+            self.physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float64_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float64_atomic_add,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderSharedFloat32Atomics",
+            self.physical_device_shader_atomic_float_features_ext
+                .shader_shared_float32_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float32_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderSharedFloat32AtomicAdd",
+            self.physical_device_shader_atomic_float_features_ext
+                .shader_shared_float32_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float32_atomic_add,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderSharedFloat64Atomics",
+            self.physical_device_shader_atomic_float_features_ext
+                .shader_shared_float64_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float64_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderSharedFloat64AtomicAdd",
+            self.physical_device_shader_atomic_float_features_ext
+                .shader_shared_float64_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float64_atomic_add,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderImageFloat32Atomics",
+            self.physical_device_shader_atomic_float_features_ext
+                .shader_image_float32_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_image_float32_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderImageFloat32AtomicAdd",
+            self.physical_device_shader_atomic_float_features_ext
+                .shader_image_float32_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_image_float32_atomic_add,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::sparseImageFloat32Atomics",
+            self.physical_device_shader_atomic_float_features_ext
+                .sparse_image_float32_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .sparse_image_float32_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::sparseImageFloat32AtomicAdd",
+            self.physical_device_shader_atomic_float_features_ext
+                .sparse_image_float32_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .sparse_image_float32_atomic_add,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderBufferFloat16Atomics",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomics,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomics,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderBufferFloat16AtomicAdd",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomic_add,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomic_add,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderBufferFloat16AtomicMinMax",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderBufferFloat32AtomicMinMax",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float32_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float32_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderBufferFloat64AtomicMinMax",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float64_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float64_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderSharedFloat16Atomics",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomics,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomics,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderSharedFloat16AtomicAdd",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomic_add,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomic_add,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderSharedFloat16AtomicMinMax",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderSharedFloat32AtomicMinMax",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float32_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float32_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderSharedFloat64AtomicMinMax",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float64_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float64_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderImageFloat32AtomicMinMax",
+            self.physical_device_shader_atomic_float2_features_ext
+                .shader_image_float32_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_image_float32_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::sparseImageFloat32AtomicMinMax",
+            self.physical_device_shader_atomic_float2_features_ext
+                .sparse_image_float32_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .sparse_image_float32_atomic_min_max,
+        );
+        f(
+            28usize,
+            "VkPhysicalDeviceVertexAttributeDivisorFeaturesEXT::vertexAttributeInstanceRateDivisor",
+            self.physical_device_vertex_attribute_divisor_features_ext
+                .vertex_attribute_instance_rate_divisor,
+            other
+                .physical_device_vertex_attribute_divisor_features_ext
+                .vertex_attribute_instance_rate_divisor,
+        );
+        f (28usize , "VkPhysicalDeviceVertexAttributeDivisorFeaturesEXT::vertexAttributeInstanceRateZeroDivisor" , self . physical_device_vertex_attribute_divisor_features_ext . vertex_attribute_instance_rate_zero_divisor , other . physical_device_vertex_attribute_divisor_features_ext . vertex_attribute_instance_rate_zero_divisor) ;
+        f(
+            29usize,
+            "VkPhysicalDeviceASTCDecodeFeaturesEXT::decodeModeSharedExponent",
+            self.physical_device_astc_decode_features_ext
+                .decode_mode_shared_exponent,
+            other
+                .physical_device_astc_decode_features_ext
+                .decode_mode_shared_exponent,
+        );
+        f(
+            30usize,
+            "VkPhysicalDeviceTransformFeedbackFeaturesEXT::transformFeedback",
+            self.physical_device_transform_feedback_features_ext
+                .transform_feedback,
+            other
+                .physical_device_transform_feedback_features_ext
+                .transform_feedback,
+        );
+        f(
+            30usize,
+            "VkPhysicalDeviceTransformFeedbackFeaturesEXT::geometryStreams",
+            self.physical_device_transform_feedback_features_ext
+                .geometry_streams,
+            other
+                .physical_device_transform_feedback_features_ext
+                .geometry_streams,
+        );
+        f(
+            31usize,
+            "VkPhysicalDeviceRepresentativeFragmentTestFeaturesNV::representativeFragmentTest",
+            self.physical_device_representative_fragment_test_features_nv
+                .representative_fragment_test,
+            other
+                .physical_device_representative_fragment_test_features_nv
+                .representative_fragment_test,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceExclusiveScissorFeaturesNV::exclusiveScissor",
+            self.physical_device_exclusive_scissor_features_nv
+                .exclusive_scissor,
+            other
+                .physical_device_exclusive_scissor_features_nv
+                .exclusive_scissor,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceCornerSampledImageFeaturesNV::cornerSampledImage",
+            self.physical_device_corner_sampled_image_features_nv
+                .corner_sampled_image,
+            other
+                .physical_device_corner_sampled_image_features_nv
+                .corner_sampled_image,
+        );
+        f(
+            34usize,
+            "VkPhysicalDeviceComputeShaderDerivativesFeaturesNV::computeDerivativeGroupQuads",
+            self.physical_device_compute_shader_derivatives_features_nv
+                .compute_derivative_group_quads,
+            other
+                .physical_device_compute_shader_derivatives_features_nv
+                .compute_derivative_group_quads,
+        );
+        f(
+            34usize,
+            "VkPhysicalDeviceComputeShaderDerivativesFeaturesNV::computeDerivativeGroupLinear",
+            self.physical_device_compute_shader_derivatives_features_nv
+                .compute_derivative_group_linear,
+            other
+                .physical_device_compute_shader_derivatives_features_nv
+                .compute_derivative_group_linear,
+        );
+        f(
+            35usize,
+            "VkPhysicalDeviceShaderImageFootprintFeaturesNV::imageFootprint",
+            self.physical_device_shader_image_footprint_features_nv
+                .image_footprint,
+            other
+                .physical_device_shader_image_footprint_features_nv
+                .image_footprint,
+        );
+        f (36usize , "VkPhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV::dedicatedAllocationImageAliasing" , self . physical_device_dedicated_allocation_image_aliasing_features_nv . dedicated_allocation_image_aliasing , other . physical_device_dedicated_allocation_image_aliasing_features_nv . dedicated_allocation_image_aliasing) ;
+        f(
+            37usize,
+            "VkPhysicalDeviceCopyMemoryIndirectFeaturesNV::indirectCopy",
+            self.physical_device_copy_memory_indirect_features_nv
+                .indirect_copy,
+            other
+                .physical_device_copy_memory_indirect_features_nv
+                .indirect_copy,
+        );
+        f(
+            38usize,
+            "VkPhysicalDeviceMemoryDecompressionFeaturesNV::memoryDecompression",
+            self.physical_device_memory_decompression_features_nv
+                .memory_decompression,
+            other
+                .physical_device_memory_decompression_features_nv
+                .memory_decompression,
+        );
+        f(
+            39usize,
+            "VkPhysicalDeviceShadingRateImageFeaturesNV::shadingRateImage",
+            self.physical_device_shading_rate_image_features_nv
+                .shading_rate_image,
+            other
+                .physical_device_shading_rate_image_features_nv
+                .shading_rate_image,
+        );
+        f(
+            39usize,
+            "VkPhysicalDeviceShadingRateImageFeaturesNV::shadingRateCoarseSampleOrder",
+            self.physical_device_shading_rate_image_features_nv
+                .shading_rate_coarse_sample_order,
+            other
+                .physical_device_shading_rate_image_features_nv
+                .shading_rate_coarse_sample_order,
+        );
+        f(
+            40usize,
+            "VkPhysicalDeviceInvocationMaskFeaturesHUAWEI::invocationMask",
+            self.physical_device_invocation_mask_features_huawei
+                .invocation_mask,
+            other
+                .physical_device_invocation_mask_features_huawei
+                .invocation_mask,
+        );
+        f(
+            41usize,
+            "VkPhysicalDeviceMeshShaderFeaturesNV::taskShader",
+            self.physical_device_mesh_shader_features_nv.task_shader,
+            other.physical_device_mesh_shader_features_nv.task_shader,
+        );
+        f(
+            41usize,
+            "VkPhysicalDeviceMeshShaderFeaturesNV::meshShader",
+            self.physical_device_mesh_shader_features_nv.mesh_shader,
+            other.physical_device_mesh_shader_features_nv.mesh_shader,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceMeshShaderFeaturesEXT::taskShader",
+            self.physical_device_mesh_shader_features_ext.task_shader,
+            other.physical_device_mesh_shader_features_ext.task_shader,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceMeshShaderFeaturesEXT::meshShader",
+            self.physical_device_mesh_shader_features_ext.mesh_shader,
+            other.physical_device_mesh_shader_features_ext.mesh_shader,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceMeshShaderFeaturesEXT::multiviewMeshShader",
+            self.physical_device_mesh_shader_features_ext
+                .multiview_mesh_shader,
+            other
+                .physical_device_mesh_shader_features_ext
+                .multiview_mesh_shader,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceMeshShaderFeaturesEXT::primitiveFragmentShadingRateMeshShader",
+            self.physical_device_mesh_shader_features_ext
+                .primitive_fragment_shading_rate_mesh_shader,
+            other
+                .physical_device_mesh_shader_features_ext
+                .primitive_fragment_shading_rate_mesh_shader,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceMeshShaderFeaturesEXT::meshShaderQueries",
+            self.physical_device_mesh_shader_features_ext
+                .mesh_shader_queries,
+            other
+                .physical_device_mesh_shader_features_ext
+                .mesh_shader_queries,
+        );
+        f(
+            43usize,
+            "VkPhysicalDeviceAccelerationStructureFeaturesKHR::accelerationStructure",
+            self.physical_device_acceleration_structure_features_khr
+                .acceleration_structure,
+            other
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure,
+        );
+        f(
+            43usize,
+            "VkPhysicalDeviceAccelerationStructureFeaturesKHR::accelerationStructureCaptureReplay",
+            self.physical_device_acceleration_structure_features_khr
+                .acceleration_structure_capture_replay,
+            other
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure_capture_replay,
+        );
+        f(
+            43usize,
+            "VkPhysicalDeviceAccelerationStructureFeaturesKHR::accelerationStructureIndirectBuild",
+            self.physical_device_acceleration_structure_features_khr
+                .acceleration_structure_indirect_build,
+            other
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure_indirect_build,
+        );
+        f(
+            43usize,
+            "VkPhysicalDeviceAccelerationStructureFeaturesKHR::accelerationStructureHostCommands",
+            self.physical_device_acceleration_structure_features_khr
+                .acceleration_structure_host_commands,
+            other
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure_host_commands,
+        );
+        f (43usize , "VkPhysicalDeviceAccelerationStructureFeaturesKHR::descriptorBindingAccelerationStructureUpdateAfterBind" , self . physical_device_acceleration_structure_features_khr . descriptor_binding_acceleration_structure_update_after_bind , other . physical_device_acceleration_structure_features_khr . descriptor_binding_acceleration_structure_update_after_bind) ;
+        f(
+            44usize,
+            "VkPhysicalDeviceRayTracingPipelineFeaturesKHR::rayTracingPipeline",
+            self.physical_device_ray_tracing_pipeline_features_khr
+                .ray_tracing_pipeline,
+            other
+                .physical_device_ray_tracing_pipeline_features_khr
+                .ray_tracing_pipeline,
+        );
+        f (44usize , "VkPhysicalDeviceRayTracingPipelineFeaturesKHR::rayTracingPipelineShaderGroupHandleCaptureReplay" , self . physical_device_ray_tracing_pipeline_features_khr . ray_tracing_pipeline_shader_group_handle_capture_replay , other . physical_device_ray_tracing_pipeline_features_khr . ray_tracing_pipeline_shader_group_handle_capture_replay) ;
+        f (44usize , "VkPhysicalDeviceRayTracingPipelineFeaturesKHR::rayTracingPipelineShaderGroupHandleCaptureReplayMixed" , self . physical_device_ray_tracing_pipeline_features_khr . ray_tracing_pipeline_shader_group_handle_capture_replay_mixed , other . physical_device_ray_tracing_pipeline_features_khr . ray_tracing_pipeline_shader_group_handle_capture_replay_mixed) ;
+        f(
+            44usize,
+            "VkPhysicalDeviceRayTracingPipelineFeaturesKHR::rayTracingPipelineTraceRaysIndirect",
+            self.physical_device_ray_tracing_pipeline_features_khr
+                .ray_tracing_pipeline_trace_rays_indirect,
+            other
+                .physical_device_ray_tracing_pipeline_features_khr
+                .ray_tracing_pipeline_trace_rays_indirect,
+        );
+        f(
+            44usize,
+            "VkPhysicalDeviceRayTracingPipelineFeaturesKHR::rayTraversalPrimitiveCulling",
+            self.physical_device_ray_tracing_pipeline_features_khr
+                .ray_traversal_primitive_culling,
+            other
+                .physical_device_ray_tracing_pipeline_features_khr
+                .ray_traversal_primitive_culling,
+        );
+        f(
+            45usize,
+            "VkPhysicalDeviceRayQueryFeaturesKHR::rayQuery",
+            self.physical_device_ray_query_features_khr.ray_query,
+            other.physical_device_ray_query_features_khr.ray_query,
+        );
+        f(
+            46usize,
+            "VkPhysicalDeviceRayTracingMaintenance1FeaturesKHR::rayTracingMaintenance1",
+            self.physical_device_ray_tracing_maintenance1_features_khr
+                .ray_tracing_maintenance1,
+            other
+                .physical_device_ray_tracing_maintenance1_features_khr
+                .ray_tracing_maintenance1,
+        );
+        f (46usize , "VkPhysicalDeviceRayTracingMaintenance1FeaturesKHR::rayTracingPipelineTraceRaysIndirect2" , self . physical_device_ray_tracing_maintenance1_features_khr . ray_tracing_pipeline_trace_rays_indirect2 , other . physical_device_ray_tracing_maintenance1_features_khr . ray_tracing_pipeline_trace_rays_indirect2) ;
+        f(
+            47usize,
+            "VkPhysicalDeviceFragmentDensityMapFeaturesEXT::fragmentDensityMap",
+            self.physical_device_fragment_density_map_features_ext
+                .fragment_density_map,
+            other
+                .physical_device_fragment_density_map_features_ext
+                .fragment_density_map,
+        );
+        f(
+            47usize,
+            "VkPhysicalDeviceFragmentDensityMapFeaturesEXT::fragmentDensityMapDynamic",
+            self.physical_device_fragment_density_map_features_ext
+                .fragment_density_map_dynamic,
+            other
+                .physical_device_fragment_density_map_features_ext
+                .fragment_density_map_dynamic,
+        );
+        f(
+            47usize,
+            "VkPhysicalDeviceFragmentDensityMapFeaturesEXT::fragmentDensityMapNonSubsampledImages",
+            self.physical_device_fragment_density_map_features_ext
+                .fragment_density_map_non_subsampled_images,
+            other
+                .physical_device_fragment_density_map_features_ext
+                .fragment_density_map_non_subsampled_images,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceFragmentDensityMap2FeaturesEXT::fragmentDensityMapDeferred",
+            self.physical_device_fragment_density_map2_features_ext
+                .fragment_density_map_deferred,
+            other
+                .physical_device_fragment_density_map2_features_ext
+                .fragment_density_map_deferred,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM::fragmentDensityMapOffset",
+            self.physical_device_fragment_density_map_offset_features_qcom
+                .fragment_density_map_offset,
+            other
+                .physical_device_fragment_density_map_offset_features_qcom
+                .fragment_density_map_offset,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceScalarBlockLayoutFeatures::scalarBlockLayout",
+            self.physical_device_scalar_block_layout_features
+                .scalar_block_layout,
+            other
+                .physical_device_scalar_block_layout_features
+                .scalar_block_layout,
+        );
+        f(
+            51usize,
+            "VkPhysicalDeviceUniformBufferStandardLayoutFeatures::uniformBufferStandardLayout",
+            self.physical_device_uniform_buffer_standard_layout_features
+                .uniform_buffer_standard_layout,
+            other
+                .physical_device_uniform_buffer_standard_layout_features
+                .uniform_buffer_standard_layout,
+        );
+        f(
+            52usize,
+            "VkPhysicalDeviceDepthClipEnableFeaturesEXT::depthClipEnable",
+            self.physical_device_depth_clip_enable_features_ext
+                .depth_clip_enable,
+            other
+                .physical_device_depth_clip_enable_features_ext
+                .depth_clip_enable,
+        );
+        f(
+            53usize,
+            "VkPhysicalDeviceMemoryPriorityFeaturesEXT::memoryPriority",
+            self.physical_device_memory_priority_features_ext
+                .memory_priority,
+            other
+                .physical_device_memory_priority_features_ext
+                .memory_priority,
+        );
+        f(
+            54usize,
+            "VkPhysicalDevicePageableDeviceLocalMemoryFeaturesEXT::pageableDeviceLocalMemory",
+            self.physical_device_pageable_device_local_memory_features_ext
+                .pageable_device_local_memory,
+            other
+                .physical_device_pageable_device_local_memory_features_ext
+                .pageable_device_local_memory,
+        );
+        f(
+            55usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeatures::bufferDeviceAddress",
+            self.physical_device_buffer_device_address_features
+                .buffer_device_address,
+            other
+                .physical_device_buffer_device_address_features
+                .buffer_device_address,
+        );
+        f(
+            55usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeatures::bufferDeviceAddressCaptureReplay",
+            self.physical_device_buffer_device_address_features
+                .buffer_device_address_capture_replay,
+            other
+                .physical_device_buffer_device_address_features
+                .buffer_device_address_capture_replay,
+        );
+        f(
+            55usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeatures::bufferDeviceAddressMultiDevice",
+            self.physical_device_buffer_device_address_features
+                .buffer_device_address_multi_device,
+            other
+                .physical_device_buffer_device_address_features
+                .buffer_device_address_multi_device,
+        );
+        f(
+            56usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT::bufferDeviceAddress",
+            self.physical_device_buffer_device_address_features_ext
+                .buffer_device_address,
+            other
+                .physical_device_buffer_device_address_features_ext
+                .buffer_device_address,
+        );
+        f(
+            56usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT::bufferDeviceAddressCaptureReplay",
+            self.physical_device_buffer_device_address_features_ext
+                .buffer_device_address_capture_replay,
+            other
+                .physical_device_buffer_device_address_features_ext
+                .buffer_device_address_capture_replay,
+        );
+        f(
+            56usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT::bufferDeviceAddressMultiDevice",
+            self.physical_device_buffer_device_address_features_ext
+                .buffer_device_address_multi_device,
+            other
+                .physical_device_buffer_device_address_features_ext
+                .buffer_device_address_multi_device,
+        );
+        f(
+            57usize,
+            "VkPhysicalDeviceImagelessFramebufferFeatures::imagelessFramebuffer",
+            self.physical_device_imageless_framebuffer_features
+                .imageless_framebuffer,
+            other
+                .physical_device_imageless_framebuffer_features
+                .imageless_framebuffer,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceTextureCompressionASTCHDRFeatures::textureCompressionASTC_HDR",
+            self.physical_device_texture_compression_astchdr_features
+                .texture_compression_astc_hdr,
+            other
+                .physical_device_texture_compression_astchdr_features
+                .texture_compression_astc_hdr,
+        );
+        f(
+            59usize,
+            "VkPhysicalDeviceCooperativeMatrixFeaturesNV::cooperativeMatrix",
+            self.physical_device_cooperative_matrix_features_nv
+                .cooperative_matrix,
+            other
+                .physical_device_cooperative_matrix_features_nv
+                .cooperative_matrix,
+        );
+        f(
+            59usize,
+            "VkPhysicalDeviceCooperativeMatrixFeaturesNV::cooperativeMatrixRobustBufferAccess",
+            self.physical_device_cooperative_matrix_features_nv
+                .cooperative_matrix_robust_buffer_access,
+            other
+                .physical_device_cooperative_matrix_features_nv
+                .cooperative_matrix_robust_buffer_access,
+        );
+        f(
+            60usize,
+            "VkPhysicalDeviceYcbcrImageArraysFeaturesEXT::ycbcrImageArrays",
+            self.physical_device_ycbcr_image_arrays_features_ext
+                .ycbcr_image_arrays,
+            other
+                .physical_device_ycbcr_image_arrays_features_ext
+                .ycbcr_image_arrays,
+        );
+        f(
+            61usize,
+            "VkPhysicalDevicePresentBarrierFeaturesNV::presentBarrier",
+            self.physical_device_present_barrier_features_nv
+                .present_barrier,
+            other
+                .physical_device_present_barrier_features_nv
+                .present_barrier,
+        );
+        f(
+            62usize,
+            "VkPhysicalDevicePerformanceQueryFeaturesKHR::performanceCounterQueryPools",
+            self.physical_device_performance_query_features_khr
+                .performance_counter_query_pools,
+            other
+                .physical_device_performance_query_features_khr
+                .performance_counter_query_pools,
+        );
+        f(
+            62usize,
+            "VkPhysicalDevicePerformanceQueryFeaturesKHR::performanceCounterMultipleQueryPools",
+            self.physical_device_performance_query_features_khr
+                .performance_counter_multiple_query_pools,
+            other
+                .physical_device_performance_query_features_khr
+                .performance_counter_multiple_query_pools,
+        );
+        f(
+            63usize,
+            "VkPhysicalDeviceCoverageReductionModeFeaturesNV::coverageReductionMode",
+            self.physical_device_coverage_reduction_mode_features_nv
+                .coverage_reduction_mode,
+            other
+                .physical_device_coverage_reduction_mode_features_nv
+                .coverage_reduction_mode,
+        );
+        f(
+            64usize,
+            "VkPhysicalDeviceShaderIntegerFunctions2FeaturesINTEL::shaderIntegerFunctions2",
+            self.physical_device_shader_integer_functions2_features_intel
+                .shader_integer_functions2,
+            other
+                .physical_device_shader_integer_functions2_features_intel
+                .shader_integer_functions2,
+        );
+        f(
+            65usize,
+            "VkPhysicalDeviceShaderClockFeaturesKHR::shaderSubgroupClock",
+            self.physical_device_shader_clock_features_khr
+                .shader_subgroup_clock,
+            other
+                .physical_device_shader_clock_features_khr
+                .shader_subgroup_clock,
+        );
+        f(
+            65usize,
+            "VkPhysicalDeviceShaderClockFeaturesKHR::shaderDeviceClock",
+            self.physical_device_shader_clock_features_khr
+                .shader_device_clock,
+            other
+                .physical_device_shader_clock_features_khr
+                .shader_device_clock,
+        );
+        f(
+            66usize,
+            "VkPhysicalDeviceIndexTypeUint8FeaturesEXT::indexTypeUint8",
+            self.physical_device_index_type_uint8_features_ext
+                .index_type_uint8,
+            other
+                .physical_device_index_type_uint8_features_ext
+                .index_type_uint8,
+        );
+        f(
+            67usize,
+            "VkPhysicalDeviceShaderSMBuiltinsFeaturesNV::shaderSMBuiltins",
+            self.physical_device_shader_sm_builtins_features_nv
+                .shader_sm_builtins,
+            other
+                .physical_device_shader_sm_builtins_features_nv
+                .shader_sm_builtins,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT::fragmentShaderSampleInterlock",
+            self.physical_device_fragment_shader_interlock_features_ext
+                .fragment_shader_sample_interlock,
+            other
+                .physical_device_fragment_shader_interlock_features_ext
+                .fragment_shader_sample_interlock,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT::fragmentShaderPixelInterlock",
+            self.physical_device_fragment_shader_interlock_features_ext
+                .fragment_shader_pixel_interlock,
+            other
+                .physical_device_fragment_shader_interlock_features_ext
+                .fragment_shader_pixel_interlock,
+        );
+        f (68usize , "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT::fragmentShaderShadingRateInterlock" , self . physical_device_fragment_shader_interlock_features_ext . fragment_shader_shading_rate_interlock , other . physical_device_fragment_shader_interlock_features_ext . fragment_shader_shading_rate_interlock) ;
+        f(
+            69usize,
+            "VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures::separateDepthStencilLayouts",
+            self.physical_device_separate_depth_stencil_layouts_features
+                .separate_depth_stencil_layouts,
+            other
+                .physical_device_separate_depth_stencil_layouts_features
+                .separate_depth_stencil_layouts,
+        );
+        f(
+            70usize,
+            "VkPhysicalDevicePrimitiveTopologyListRestartFeaturesEXT::primitiveTopologyListRestart",
+            self.physical_device_primitive_topology_list_restart_features_ext
+                .primitive_topology_list_restart,
+            other
+                .physical_device_primitive_topology_list_restart_features_ext
+                .primitive_topology_list_restart,
+        );
+        f (70usize , "VkPhysicalDevicePrimitiveTopologyListRestartFeaturesEXT::primitiveTopologyPatchListRestart" , self . physical_device_primitive_topology_list_restart_features_ext . primitive_topology_patch_list_restart , other . physical_device_primitive_topology_list_restart_features_ext . primitive_topology_patch_list_restart) ;
+        f(
+            71usize,
+            "VkPhysicalDevicePipelineExecutablePropertiesFeaturesKHR::pipelineExecutableInfo",
+            self.physical_device_pipeline_executable_properties_features_khr
+                .pipeline_executable_info,
+            other
+                .physical_device_pipeline_executable_properties_features_khr
+                .pipeline_executable_info,
+        );
+        f (72usize , "VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures::shaderDemoteToHelperInvocation" , self . physical_device_shader_demote_to_helper_invocation_features . shader_demote_to_helper_invocation , other . physical_device_shader_demote_to_helper_invocation_features . shader_demote_to_helper_invocation) ;
+        f(
+            73usize,
+            "VkPhysicalDeviceTexelBufferAlignmentFeaturesEXT::texelBufferAlignment",
+            self.physical_device_texel_buffer_alignment_features_ext
+                .texel_buffer_alignment,
+            other
+                .physical_device_texel_buffer_alignment_features_ext
+                .texel_buffer_alignment,
+        );
+        f(
+            74usize,
+            "VkPhysicalDeviceSubgroupSizeControlFeatures::subgroupSizeControl",
+            self.physical_device_subgroup_size_control_features
+                .subgroup_size_control,
+            other
+                .physical_device_subgroup_size_control_features
+                .subgroup_size_control,
+        );
+        f(
+            74usize,
+            "VkPhysicalDeviceSubgroupSizeControlFeatures::computeFullSubgroups",
+            self.physical_device_subgroup_size_control_features
+                .compute_full_subgroups,
+            other
+                .physical_device_subgroup_size_control_features
+                .compute_full_subgroups,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::rectangularLines",
+            self.physical_device_line_rasterization_features_ext
+                .rectangular_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .rectangular_lines,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::bresenhamLines",
+            self.physical_device_line_rasterization_features_ext
+                .bresenham_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .bresenham_lines,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::smoothLines",
+            self.physical_device_line_rasterization_features_ext
+                .smooth_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .smooth_lines,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::stippledRectangularLines",
+            self.physical_device_line_rasterization_features_ext
+                .stippled_rectangular_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .stippled_rectangular_lines,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::stippledBresenhamLines",
+            self.physical_device_line_rasterization_features_ext
+                .stippled_bresenham_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .stippled_bresenham_lines,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::stippledSmoothLines",
+            self.physical_device_line_rasterization_features_ext
+                .stippled_smooth_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .stippled_smooth_lines,
+        );
+        f(
+            76usize,
+            "VkPhysicalDevicePipelineCreationCacheControlFeatures::pipelineCreationCacheControl",
+            self.physical_device_pipeline_creation_cache_control_features
+                .pipeline_creation_cache_control,
+            other
+                .physical_device_pipeline_creation_cache_control_features
+                .pipeline_creation_cache_control,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::storageBuffer16BitAccess",
+            self.physical_device_vulkan11_features
+                .storage_buffer16_bit_access,
+            other
+                .physical_device_vulkan11_features
+                .storage_buffer16_bit_access,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::uniformAndStorageBuffer16BitAccess",
+            self.physical_device_vulkan11_features
+                .uniform_and_storage_buffer16_bit_access,
+            other
+                .physical_device_vulkan11_features
+                .uniform_and_storage_buffer16_bit_access,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::storagePushConstant16",
+            self.physical_device_vulkan11_features
+                .storage_push_constant16,
+            other
+                .physical_device_vulkan11_features
+                .storage_push_constant16,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::storageInputOutput16",
+            self.physical_device_vulkan11_features
+                .storage_input_output16,
+            other
+                .physical_device_vulkan11_features
+                .storage_input_output16,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::multiview",
+            self.physical_device_vulkan11_features.multiview,
+            other.physical_device_vulkan11_features.multiview,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::multiviewGeometryShader",
+            self.physical_device_vulkan11_features
+                .multiview_geometry_shader,
+            other
+                .physical_device_vulkan11_features
+                .multiview_geometry_shader,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::multiviewTessellationShader",
+            self.physical_device_vulkan11_features
+                .multiview_tessellation_shader,
+            other
+                .physical_device_vulkan11_features
+                .multiview_tessellation_shader,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::variablePointersStorageBuffer",
+            self.physical_device_vulkan11_features
+                .variable_pointers_storage_buffer,
+            other
+                .physical_device_vulkan11_features
+                .variable_pointers_storage_buffer,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::variablePointers",
+            self.physical_device_vulkan11_features.variable_pointers,
+            other.physical_device_vulkan11_features.variable_pointers,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::protectedMemory",
+            self.physical_device_vulkan11_features.protected_memory,
+            other.physical_device_vulkan11_features.protected_memory,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::samplerYcbcrConversion",
+            self.physical_device_vulkan11_features
+                .sampler_ycbcr_conversion,
+            other
+                .physical_device_vulkan11_features
+                .sampler_ycbcr_conversion,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::shaderDrawParameters",
+            self.physical_device_vulkan11_features
+                .shader_draw_parameters,
+            other
+                .physical_device_vulkan11_features
+                .shader_draw_parameters,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::samplerMirrorClampToEdge",
+            self.physical_device_vulkan12_features
+                .sampler_mirror_clamp_to_edge,
+            other
+                .physical_device_vulkan12_features
+                .sampler_mirror_clamp_to_edge,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::drawIndirectCount",
+            self.physical_device_vulkan12_features.draw_indirect_count,
+            other.physical_device_vulkan12_features.draw_indirect_count,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::storageBuffer8BitAccess",
+            self.physical_device_vulkan12_features
+                .storage_buffer8_bit_access,
+            other
+                .physical_device_vulkan12_features
+                .storage_buffer8_bit_access,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::uniformAndStorageBuffer8BitAccess",
+            self.physical_device_vulkan12_features
+                .uniform_and_storage_buffer8_bit_access,
+            other
+                .physical_device_vulkan12_features
+                .uniform_and_storage_buffer8_bit_access,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::storagePushConstant8",
+            self.physical_device_vulkan12_features
+                .storage_push_constant8,
+            other
+                .physical_device_vulkan12_features
+                .storage_push_constant8,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderBufferInt64Atomics",
+            self.physical_device_vulkan12_features
+                .shader_buffer_int64_atomics,
+            other
+                .physical_device_vulkan12_features
+                .shader_buffer_int64_atomics,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderSharedInt64Atomics",
+            self.physical_device_vulkan12_features
+                .shader_shared_int64_atomics,
+            other
+                .physical_device_vulkan12_features
+                .shader_shared_int64_atomics,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderFloat16",
+            self.physical_device_vulkan12_features.shader_float16,
+            other.physical_device_vulkan12_features.shader_float16,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderInt8",
+            self.physical_device_vulkan12_features.shader_int8,
+            other.physical_device_vulkan12_features.shader_int8,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorIndexing",
+            self.physical_device_vulkan12_features.descriptor_indexing,
+            other.physical_device_vulkan12_features.descriptor_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderInputAttachmentArrayDynamicIndexing",
+            self.physical_device_vulkan12_features
+                .shader_input_attachment_array_dynamic_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_input_attachment_array_dynamic_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderUniformTexelBufferArrayDynamicIndexing",
+            self.physical_device_vulkan12_features
+                .shader_uniform_texel_buffer_array_dynamic_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_uniform_texel_buffer_array_dynamic_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderStorageTexelBufferArrayDynamicIndexing",
+            self.physical_device_vulkan12_features
+                .shader_storage_texel_buffer_array_dynamic_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_storage_texel_buffer_array_dynamic_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderUniformBufferArrayNonUniformIndexing",
+            self.physical_device_vulkan12_features
+                .shader_uniform_buffer_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_uniform_buffer_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderSampledImageArrayNonUniformIndexing",
+            self.physical_device_vulkan12_features
+                .shader_sampled_image_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_sampled_image_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderStorageBufferArrayNonUniformIndexing",
+            self.physical_device_vulkan12_features
+                .shader_storage_buffer_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_storage_buffer_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderStorageImageArrayNonUniformIndexing",
+            self.physical_device_vulkan12_features
+                .shader_storage_image_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_storage_image_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderInputAttachmentArrayNonUniformIndexing",
+            self.physical_device_vulkan12_features
+                .shader_input_attachment_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_input_attachment_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderUniformTexelBufferArrayNonUniformIndexing",
+            self.physical_device_vulkan12_features
+                .shader_uniform_texel_buffer_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_uniform_texel_buffer_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderStorageTexelBufferArrayNonUniformIndexing",
+            self.physical_device_vulkan12_features
+                .shader_storage_texel_buffer_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_storage_texel_buffer_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingUniformBufferUpdateAfterBind",
+            self.physical_device_vulkan12_features
+                .descriptor_binding_uniform_buffer_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_uniform_buffer_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingSampledImageUpdateAfterBind",
+            self.physical_device_vulkan12_features
+                .descriptor_binding_sampled_image_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_sampled_image_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingStorageImageUpdateAfterBind",
+            self.physical_device_vulkan12_features
+                .descriptor_binding_storage_image_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_storage_image_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingStorageBufferUpdateAfterBind",
+            self.physical_device_vulkan12_features
+                .descriptor_binding_storage_buffer_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_storage_buffer_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingUniformTexelBufferUpdateAfterBind",
+            self.physical_device_vulkan12_features
+                .descriptor_binding_uniform_texel_buffer_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_uniform_texel_buffer_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingStorageTexelBufferUpdateAfterBind",
+            self.physical_device_vulkan12_features
+                .descriptor_binding_storage_texel_buffer_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_storage_texel_buffer_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingUpdateUnusedWhilePending",
+            self.physical_device_vulkan12_features
+                .descriptor_binding_update_unused_while_pending,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_update_unused_while_pending,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingPartiallyBound",
+            self.physical_device_vulkan12_features
+                .descriptor_binding_partially_bound,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_partially_bound,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingVariableDescriptorCount",
+            self.physical_device_vulkan12_features
+                .descriptor_binding_variable_descriptor_count,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_variable_descriptor_count,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::runtimeDescriptorArray",
+            self.physical_device_vulkan12_features
+                .runtime_descriptor_array,
+            other
+                .physical_device_vulkan12_features
+                .runtime_descriptor_array,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::samplerFilterMinmax",
+            self.physical_device_vulkan12_features.sampler_filter_minmax,
+            other
+                .physical_device_vulkan12_features
+                .sampler_filter_minmax,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::scalarBlockLayout",
+            self.physical_device_vulkan12_features.scalar_block_layout,
+            other.physical_device_vulkan12_features.scalar_block_layout,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::imagelessFramebuffer",
+            self.physical_device_vulkan12_features.imageless_framebuffer,
+            other
+                .physical_device_vulkan12_features
+                .imageless_framebuffer,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::uniformBufferStandardLayout",
+            self.physical_device_vulkan12_features
+                .uniform_buffer_standard_layout,
+            other
+                .physical_device_vulkan12_features
+                .uniform_buffer_standard_layout,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderSubgroupExtendedTypes",
+            self.physical_device_vulkan12_features
+                .shader_subgroup_extended_types,
+            other
+                .physical_device_vulkan12_features
+                .shader_subgroup_extended_types,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::separateDepthStencilLayouts",
+            self.physical_device_vulkan12_features
+                .separate_depth_stencil_layouts,
+            other
+                .physical_device_vulkan12_features
+                .separate_depth_stencil_layouts,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::hostQueryReset",
+            self.physical_device_vulkan12_features.host_query_reset,
+            other.physical_device_vulkan12_features.host_query_reset,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::timelineSemaphore",
+            self.physical_device_vulkan12_features.timeline_semaphore,
+            other.physical_device_vulkan12_features.timeline_semaphore,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::bufferDeviceAddress",
+            self.physical_device_vulkan12_features.buffer_device_address,
+            other
+                .physical_device_vulkan12_features
+                .buffer_device_address,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::bufferDeviceAddressCaptureReplay",
+            self.physical_device_vulkan12_features
+                .buffer_device_address_capture_replay,
+            other
+                .physical_device_vulkan12_features
+                .buffer_device_address_capture_replay,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::bufferDeviceAddressMultiDevice",
+            self.physical_device_vulkan12_features
+                .buffer_device_address_multi_device,
+            other
+                .physical_device_vulkan12_features
+                .buffer_device_address_multi_device,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::vulkanMemoryModel",
+            self.physical_device_vulkan12_features.vulkan_memory_model,
+            other.physical_device_vulkan12_features.vulkan_memory_model,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::vulkanMemoryModelDeviceScope",
+            self.physical_device_vulkan12_features
+                .vulkan_memory_model_device_scope,
+            other
+                .physical_device_vulkan12_features
+                .vulkan_memory_model_device_scope,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::vulkanMemoryModelAvailabilityVisibilityChains",
+            self.physical_device_vulkan12_features
+                .vulkan_memory_model_availability_visibility_chains,
+            other
+                .physical_device_vulkan12_features
+                .vulkan_memory_model_availability_visibility_chains,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderOutputViewportIndex",
+            self.physical_device_vulkan12_features
+                .shader_output_viewport_index,
+            other
+                .physical_device_vulkan12_features
+                .shader_output_viewport_index,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderOutputLayer",
+            self.physical_device_vulkan12_features.shader_output_layer,
+            other.physical_device_vulkan12_features.shader_output_layer,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::subgroupBroadcastDynamicId",
+            self.physical_device_vulkan12_features
+                .subgroup_broadcast_dynamic_id,
+            other
+                .physical_device_vulkan12_features
+                .subgroup_broadcast_dynamic_id,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::robustImageAccess",
+            self.physical_device_vulkan13_features.robust_image_access,
+            other.physical_device_vulkan13_features.robust_image_access,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::inlineUniformBlock",
+            self.physical_device_vulkan13_features.inline_uniform_block,
+            other.physical_device_vulkan13_features.inline_uniform_block,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::descriptorBindingInlineUniformBlockUpdateAfterBind",
+            self.physical_device_vulkan13_features
+                .descriptor_binding_inline_uniform_block_update_after_bind,
+            other
+                .physical_device_vulkan13_features
+                .descriptor_binding_inline_uniform_block_update_after_bind,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::pipelineCreationCacheControl",
+            self.physical_device_vulkan13_features
+                .pipeline_creation_cache_control,
+            other
+                .physical_device_vulkan13_features
+                .pipeline_creation_cache_control,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::privateData",
+            self.physical_device_vulkan13_features.private_data,
+            other.physical_device_vulkan13_features.private_data,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::shaderDemoteToHelperInvocation",
+            self.physical_device_vulkan13_features
+                .shader_demote_to_helper_invocation,
+            other
+                .physical_device_vulkan13_features
+                .shader_demote_to_helper_invocation,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::shaderTerminateInvocation",
+            self.physical_device_vulkan13_features
+                .shader_terminate_invocation,
+            other
+                .physical_device_vulkan13_features
+                .shader_terminate_invocation,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::subgroupSizeControl",
+            self.physical_device_vulkan13_features.subgroup_size_control,
+            other
+                .physical_device_vulkan13_features
+                .subgroup_size_control,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::computeFullSubgroups",
+            self.physical_device_vulkan13_features
+                .compute_full_subgroups,
+            other
+                .physical_device_vulkan13_features
+                .compute_full_subgroups,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::synchronization2",
+            self.physical_device_vulkan13_features.synchronization2,
+            other.physical_device_vulkan13_features.synchronization2,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::textureCompressionASTC_HDR",
+            self.physical_device_vulkan13_features
+                .texture_compression_astc_hdr,
+            other
+                .physical_device_vulkan13_features
+                .texture_compression_astc_hdr,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::shaderZeroInitializeWorkgroupMemory",
+            self.physical_device_vulkan13_features
+                .shader_zero_initialize_workgroup_memory,
+            other
+                .physical_device_vulkan13_features
+                .shader_zero_initialize_workgroup_memory,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::dynamicRendering",
+            self.physical_device_vulkan13_features.dynamic_rendering,
+            other.physical_device_vulkan13_features.dynamic_rendering,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::shaderIntegerDotProduct",
+            self.physical_device_vulkan13_features
+                .shader_integer_dot_product,
+            other
+                .physical_device_vulkan13_features
+                .shader_integer_dot_product,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::maintenance4",
+            self.physical_device_vulkan13_features.maintenance4,
+            other.physical_device_vulkan13_features.maintenance4,
+        );
+        f(
+            80usize,
+            "VkPhysicalDeviceCoherentMemoryFeaturesAMD::deviceCoherentMemory",
+            self.physical_device_coherent_memory_features_amd
+                .device_coherent_memory,
+            other
+                .physical_device_coherent_memory_features_amd
+                .device_coherent_memory,
+        );
+        f(
+            81usize,
+            "VkPhysicalDeviceCustomBorderColorFeaturesEXT::customBorderColors",
+            self.physical_device_custom_border_color_features_ext
+                .custom_border_colors,
+            other
+                .physical_device_custom_border_color_features_ext
+                .custom_border_colors,
+        );
+        f(
+            81usize,
+            "VkPhysicalDeviceCustomBorderColorFeaturesEXT::customBorderColorWithoutFormat",
+            self.physical_device_custom_border_color_features_ext
+                .custom_border_color_without_format,
+            other
+                .physical_device_custom_border_color_features_ext
+                .custom_border_color_without_format,
+        );
+        f(
+            82usize,
+            "VkPhysicalDeviceBorderColorSwizzleFeaturesEXT::borderColorSwizzle",
+            self.physical_device_border_color_swizzle_features_ext
+                .border_color_swizzle,
+            other
+                .physical_device_border_color_swizzle_features_ext
+                .border_color_swizzle,
+        );
+        f(
+            82usize,
+            "VkPhysicalDeviceBorderColorSwizzleFeaturesEXT::borderColorSwizzleFromImage",
+            self.physical_device_border_color_swizzle_features_ext
+                .border_color_swizzle_from_image,
+            other
+                .physical_device_border_color_swizzle_features_ext
+                .border_color_swizzle_from_image,
+        );
+        f(
+            83usize,
+            "VkPhysicalDeviceExtendedDynamicStateFeaturesEXT::extendedDynamicState",
+            self.physical_device_extended_dynamic_state_features_ext
+                .extended_dynamic_state,
+            other
+                .physical_device_extended_dynamic_state_features_ext
+                .extended_dynamic_state,
+        );
+        f(
+            84usize,
+            "VkPhysicalDeviceExtendedDynamicState2FeaturesEXT::extendedDynamicState2",
+            self.physical_device_extended_dynamic_state2_features_ext
+                .extended_dynamic_state2,
+            other
+                .physical_device_extended_dynamic_state2_features_ext
+                .extended_dynamic_state2,
+        );
+        f(
+            84usize,
+            "VkPhysicalDeviceExtendedDynamicState2FeaturesEXT::extendedDynamicState2LogicOp",
+            self.physical_device_extended_dynamic_state2_features_ext
+                .extended_dynamic_state2_logic_op,
+            other
+                .physical_device_extended_dynamic_state2_features_ext
+                .extended_dynamic_state2_logic_op,
+        );
+        f (84usize , "VkPhysicalDeviceExtendedDynamicState2FeaturesEXT::extendedDynamicState2PatchControlPoints" , self . physical_device_extended_dynamic_state2_features_ext . extended_dynamic_state2_patch_control_points , other . physical_device_extended_dynamic_state2_features_ext . extended_dynamic_state2_patch_control_points) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3TessellationDomainOrigin" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_tessellation_domain_origin , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_tessellation_domain_origin) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3DepthClampEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clamp_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clamp_enable) ;
+        f(
+            85usize,
+            "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3PolygonMode",
+            self.physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_polygon_mode,
+            other
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_polygon_mode,
+        );
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3RasterizationSamples" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_rasterization_samples , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_rasterization_samples) ;
+        f(
+            85usize,
+            "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3SampleMask",
+            self.physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_sample_mask,
+            other
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_sample_mask,
+        );
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3AlphaToCoverageEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_alpha_to_coverage_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_alpha_to_coverage_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3AlphaToOneEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_alpha_to_one_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_alpha_to_one_enable) ;
+        f(
+            85usize,
+            "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3LogicOpEnable",
+            self.physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_logic_op_enable,
+            other
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_logic_op_enable,
+        );
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ColorBlendEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ColorBlendEquation" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_equation , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_equation) ;
+        f(
+            85usize,
+            "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ColorWriteMask",
+            self.physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_color_write_mask,
+            other
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_color_write_mask,
+        );
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3RasterizationStream" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_rasterization_stream , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_rasterization_stream) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ConservativeRasterizationMode" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_conservative_rasterization_mode , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_conservative_rasterization_mode) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ExtraPrimitiveOverestimationSize" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_extra_primitive_overestimation_size , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_extra_primitive_overestimation_size) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3DepthClipEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clip_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clip_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3SampleLocationsEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_sample_locations_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_sample_locations_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ColorBlendAdvanced" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_advanced , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_advanced) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ProvokingVertexMode" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_provoking_vertex_mode , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_provoking_vertex_mode) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3LineRasterizationMode" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_line_rasterization_mode , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_line_rasterization_mode) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3LineStippleEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_line_stipple_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_line_stipple_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3DepthClipNegativeOneToOne" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clip_negative_one_to_one , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clip_negative_one_to_one) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ViewportWScalingEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_viewport_w_scaling_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_viewport_w_scaling_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ViewportSwizzle" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_viewport_swizzle , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_viewport_swizzle) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageToColorEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_to_color_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_to_color_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageToColorLocation" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_to_color_location , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_to_color_location) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageModulationMode" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_mode , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_mode) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageModulationTableEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_table_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_table_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageModulationTable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_table , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_table) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageReductionMode" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_reduction_mode , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_reduction_mode) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3RepresentativeFragmentTestEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_representative_fragment_test_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_representative_fragment_test_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ShadingRateImageEnable" , self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_shading_rate_image_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_shading_rate_image_enable) ;
+        f(
+            86usize,
+            "VkPhysicalDeviceDiagnosticsConfigFeaturesNV::diagnosticsConfig",
+            self.physical_device_diagnostics_config_features_nv
+                .diagnostics_config,
+            other
+                .physical_device_diagnostics_config_features_nv
+                .diagnostics_config,
+        );
+        f (87usize , "VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures::shaderZeroInitializeWorkgroupMemory" , self . physical_device_zero_initialize_workgroup_memory_features . shader_zero_initialize_workgroup_memory , other . physical_device_zero_initialize_workgroup_memory_features . shader_zero_initialize_workgroup_memory) ;
+        f (88usize , "VkPhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR::shaderSubgroupUniformControlFlow" , self . physical_device_shader_subgroup_uniform_control_flow_features_khr . shader_subgroup_uniform_control_flow , other . physical_device_shader_subgroup_uniform_control_flow_features_khr . shader_subgroup_uniform_control_flow) ;
+        f(
+            89usize,
+            "VkPhysicalDeviceRobustness2FeaturesEXT::robustBufferAccess2",
+            self.physical_device_robustness2_features_ext
+                .robust_buffer_access2,
+            other
+                .physical_device_robustness2_features_ext
+                .robust_buffer_access2,
+        );
+        f(
+            89usize,
+            "VkPhysicalDeviceRobustness2FeaturesEXT::robustImageAccess2",
+            self.physical_device_robustness2_features_ext
+                .robust_image_access2,
+            other
+                .physical_device_robustness2_features_ext
+                .robust_image_access2,
+        );
+        f(
+            89usize,
+            "VkPhysicalDeviceRobustness2FeaturesEXT::nullDescriptor",
+            self.physical_device_robustness2_features_ext
+                .null_descriptor,
+            other
+                .physical_device_robustness2_features_ext
+                .null_descriptor,
+        );
+        f(
+            90usize,
+            "VkPhysicalDeviceImageRobustnessFeatures::robustImageAccess",
+            self.physical_device_image_robustness_features
+                .robust_image_access,
+            other
+                .physical_device_image_robustness_features
+                .robust_image_access,
+        );
+        f (91usize , "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::workgroupMemoryExplicitLayout" , self . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout , other . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout) ;
+        f (91usize , "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::workgroupMemoryExplicitLayoutScalarBlockLayout" , self . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout_scalar_block_layout , other . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout_scalar_block_layout) ;
+        f (91usize , "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::workgroupMemoryExplicitLayout8BitAccess" , self . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout8_bit_access , other . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout8_bit_access) ;
+        f (91usize , "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::workgroupMemoryExplicitLayout16BitAccess" , self . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout16_bit_access , other . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout16_bit_access) ;
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::constantAlphaColorBlendFactors",
+            self.physical_device_portability_subset_features_khr
+                .constant_alpha_color_blend_factors,
+            other
+                .physical_device_portability_subset_features_khr
+                .constant_alpha_color_blend_factors,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::events",
+            self.physical_device_portability_subset_features_khr.events,
+            other.physical_device_portability_subset_features_khr.events,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::imageViewFormatReinterpretation",
+            self.physical_device_portability_subset_features_khr
+                .image_view_format_reinterpretation,
+            other
+                .physical_device_portability_subset_features_khr
+                .image_view_format_reinterpretation,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::imageViewFormatSwizzle",
+            self.physical_device_portability_subset_features_khr
+                .image_view_format_swizzle,
+            other
+                .physical_device_portability_subset_features_khr
+                .image_view_format_swizzle,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::imageView2DOn3DImage",
+            self.physical_device_portability_subset_features_khr
+                .image_view2_d_on3_d_image,
+            other
+                .physical_device_portability_subset_features_khr
+                .image_view2_d_on3_d_image,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::multisampleArrayImage",
+            self.physical_device_portability_subset_features_khr
+                .multisample_array_image,
+            other
+                .physical_device_portability_subset_features_khr
+                .multisample_array_image,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::mutableComparisonSamplers",
+            self.physical_device_portability_subset_features_khr
+                .mutable_comparison_samplers,
+            other
+                .physical_device_portability_subset_features_khr
+                .mutable_comparison_samplers,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::pointPolygons",
+            self.physical_device_portability_subset_features_khr
+                .point_polygons,
+            other
+                .physical_device_portability_subset_features_khr
+                .point_polygons,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::samplerMipLodBias",
+            self.physical_device_portability_subset_features_khr
+                .sampler_mip_lod_bias,
+            other
+                .physical_device_portability_subset_features_khr
+                .sampler_mip_lod_bias,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::separateStencilMaskRef",
+            self.physical_device_portability_subset_features_khr
+                .separate_stencil_mask_ref,
+            other
+                .physical_device_portability_subset_features_khr
+                .separate_stencil_mask_ref,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::shaderSampleRateInterpolationFunctions",
+            self.physical_device_portability_subset_features_khr
+                .shader_sample_rate_interpolation_functions,
+            other
+                .physical_device_portability_subset_features_khr
+                .shader_sample_rate_interpolation_functions,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::tessellationIsolines",
+            self.physical_device_portability_subset_features_khr
+                .tessellation_isolines,
+            other
+                .physical_device_portability_subset_features_khr
+                .tessellation_isolines,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::tessellationPointMode",
+            self.physical_device_portability_subset_features_khr
+                .tessellation_point_mode,
+            other
+                .physical_device_portability_subset_features_khr
+                .tessellation_point_mode,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::triangleFans",
+            self.physical_device_portability_subset_features_khr
+                .triangle_fans,
+            other
+                .physical_device_portability_subset_features_khr
+                .triangle_fans,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::vertexAttributeAccessBeyondStride",
+            self.physical_device_portability_subset_features_khr
+                .vertex_attribute_access_beyond_stride,
+            other
+                .physical_device_portability_subset_features_khr
+                .vertex_attribute_access_beyond_stride,
+        );
+        f(
+            93usize,
+            "VkPhysicalDevice4444FormatsFeaturesEXT::formatA4R4G4B4",
+            self.physical_device4444_formats_features_ext
+                .format_a4r4g4b4,
+            other
+                .physical_device4444_formats_features_ext
+                .format_a4r4g4b4,
+        );
+        f(
+            93usize,
+            "VkPhysicalDevice4444FormatsFeaturesEXT::formatA4B4G4R4",
+            self.physical_device4444_formats_features_ext
+                .format_a4b4g4r4,
+            other
+                .physical_device4444_formats_features_ext
+                .format_a4b4g4r4,
+        );
+        f(
+            94usize,
+            "VkPhysicalDeviceSubpassShadingFeaturesHUAWEI::subpassShading",
+            self.physical_device_subpass_shading_features_huawei
+                .subpass_shading,
+            other
+                .physical_device_subpass_shading_features_huawei
+                .subpass_shading,
+        );
+        f(
+            95usize,
+            "VkPhysicalDeviceClusterCullingShaderFeaturesHUAWEI::clustercullingShader",
+            self.physical_device_cluster_culling_shader_features_huawei
+                .clusterculling_shader,
+            other
+                .physical_device_cluster_culling_shader_features_huawei
+                .clusterculling_shader,
+        );
+        f(
+            95usize,
+            "VkPhysicalDeviceClusterCullingShaderFeaturesHUAWEI::multiviewClusterCullingShader",
+            self.physical_device_cluster_culling_shader_features_huawei
+                .multiview_cluster_culling_shader,
+            other
+                .physical_device_cluster_culling_shader_features_huawei
+                .multiview_cluster_culling_shader,
+        );
+        f(
+            96usize,
+            "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT::shaderImageInt64Atomics",
+            self.physical_device_shader_image_atomic_int64_features_ext
+                .shader_image_int64_atomics,
+            other
+                .physical_device_shader_image_atomic_int64_features_ext
+                .shader_image_int64_atomics,
+        );
+        f(
+            96usize,
+            "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT::sparseImageInt64Atomics",
+            self.physical_device_shader_image_atomic_int64_features_ext
+                .sparse_image_int64_atomics,
+            other
+                .physical_device_shader_image_atomic_int64_features_ext
+                .sparse_image_int64_atomics,
+        );
+        f(
+            97usize,
+            "VkPhysicalDeviceFragmentShadingRateFeaturesKHR::pipelineFragmentShadingRate",
+            self.physical_device_fragment_shading_rate_features_khr
+                .pipeline_fragment_shading_rate,
+            other
+                .physical_device_fragment_shading_rate_features_khr
+                .pipeline_fragment_shading_rate,
+        );
+        f(
+            97usize,
+            "VkPhysicalDeviceFragmentShadingRateFeaturesKHR::primitiveFragmentShadingRate",
+            self.physical_device_fragment_shading_rate_features_khr
+                .primitive_fragment_shading_rate,
+            other
+                .physical_device_fragment_shading_rate_features_khr
+                .primitive_fragment_shading_rate,
+        );
+        f(
+            97usize,
+            "VkPhysicalDeviceFragmentShadingRateFeaturesKHR::attachmentFragmentShadingRate",
+            self.physical_device_fragment_shading_rate_features_khr
+                .attachment_fragment_shading_rate,
+            other
+                .physical_device_fragment_shading_rate_features_khr
+                .attachment_fragment_shading_rate,
+        );
+        f(
+            98usize,
+            "VkPhysicalDeviceShaderTerminateInvocationFeatures::shaderTerminateInvocation",
+            self.physical_device_shader_terminate_invocation_features
+                .shader_terminate_invocation,
+            other
+                .physical_device_shader_terminate_invocation_features
+                .shader_terminate_invocation,
+        );
+        f(
+            99usize,
+            "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV::fragmentShadingRateEnums",
+            self.physical_device_fragment_shading_rate_enums_features_nv
+                .fragment_shading_rate_enums,
+            other
+                .physical_device_fragment_shading_rate_enums_features_nv
+                .fragment_shading_rate_enums,
+        );
+        f(
+            99usize,
+            "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV::supersampleFragmentShadingRates",
+            self.physical_device_fragment_shading_rate_enums_features_nv
+                .supersample_fragment_shading_rates,
+            other
+                .physical_device_fragment_shading_rate_enums_features_nv
+                .supersample_fragment_shading_rates,
+        );
+        f(
+            99usize,
+            "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV::noInvocationFragmentShadingRates",
+            self.physical_device_fragment_shading_rate_enums_features_nv
+                .no_invocation_fragment_shading_rates,
+            other
+                .physical_device_fragment_shading_rate_enums_features_nv
+                .no_invocation_fragment_shading_rates,
+        );
+        f(
+            100usize,
+            "VkPhysicalDeviceImage2DViewOf3DFeaturesEXT::image2DViewOf3D",
+            self.physical_device_image2_d_view_of3_d_features_ext
+                .image2_d_view_of3_d,
+            other
+                .physical_device_image2_d_view_of3_d_features_ext
+                .image2_d_view_of3_d,
+        );
+        f(
+            100usize,
+            "VkPhysicalDeviceImage2DViewOf3DFeaturesEXT::sampler2DViewOf3D",
+            self.physical_device_image2_d_view_of3_d_features_ext
+                .sampler2_d_view_of3_d,
+            other
+                .physical_device_image2_d_view_of3_d_features_ext
+                .sampler2_d_view_of3_d,
+        );
+        f(
+            101usize,
+            "VkPhysicalDeviceImageSlicedViewOf3DFeaturesEXT::imageSlicedViewOf3D",
+            self.physical_device_image_sliced_view_of3_d_features_ext
+                .image_sliced_view_of3_d,
+            other
+                .physical_device_image_sliced_view_of3_d_features_ext
+                .image_sliced_view_of3_d,
+        );
+        f (102usize , "VkPhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT::attachmentFeedbackLoopDynamicState" , self . physical_device_attachment_feedback_loop_dynamic_state_features_ext . attachment_feedback_loop_dynamic_state , other . physical_device_attachment_feedback_loop_dynamic_state_features_ext . attachment_feedback_loop_dynamic_state) ;
+        f(
+            103usize,
+            "VkPhysicalDeviceMutableDescriptorTypeFeaturesEXT::mutableDescriptorType",
+            self.physical_device_mutable_descriptor_type_features_ext
+                .mutable_descriptor_type,
+            other
+                .physical_device_mutable_descriptor_type_features_ext
+                .mutable_descriptor_type,
+        );
+        f(
+            104usize,
+            "VkPhysicalDeviceDepthClipControlFeaturesEXT::depthClipControl",
+            self.physical_device_depth_clip_control_features_ext
+                .depth_clip_control,
+            other
+                .physical_device_depth_clip_control_features_ext
+                .depth_clip_control,
+        );
+        f(
+            105usize,
+            "VkPhysicalDeviceVertexInputDynamicStateFeaturesEXT::vertexInputDynamicState",
+            self.physical_device_vertex_input_dynamic_state_features_ext
+                .vertex_input_dynamic_state,
+            other
+                .physical_device_vertex_input_dynamic_state_features_ext
+                .vertex_input_dynamic_state,
+        );
+        f(
+            106usize,
+            "VkPhysicalDeviceExternalMemoryRDMAFeaturesNV::externalMemoryRDMA",
+            self.physical_device_external_memory_rdma_features_nv
+                .external_memory_rdma,
+            other
+                .physical_device_external_memory_rdma_features_nv
+                .external_memory_rdma,
+        );
+        f(
+            107usize,
+            "VkPhysicalDeviceColorWriteEnableFeaturesEXT::colorWriteEnable",
+            self.physical_device_color_write_enable_features_ext
+                .color_write_enable,
+            other
+                .physical_device_color_write_enable_features_ext
+                .color_write_enable,
+        );
+        f(
+            108usize,
+            "VkPhysicalDeviceSynchronization2Features::synchronization2",
+            self.physical_device_synchronization2_features
+                .synchronization2,
+            other
+                .physical_device_synchronization2_features
+                .synchronization2,
+        );
+        f(
+            109usize,
+            "VkPhysicalDevicePrimitivesGeneratedQueryFeaturesEXT::primitivesGeneratedQuery",
+            self.physical_device_primitives_generated_query_features_ext
+                .primitives_generated_query,
+            other
+                .physical_device_primitives_generated_query_features_ext
+                .primitives_generated_query,
+        );
+        f (109usize , "VkPhysicalDevicePrimitivesGeneratedQueryFeaturesEXT::primitivesGeneratedQueryWithRasterizerDiscard" , self . physical_device_primitives_generated_query_features_ext . primitives_generated_query_with_rasterizer_discard , other . physical_device_primitives_generated_query_features_ext . primitives_generated_query_with_rasterizer_discard) ;
+        f (109usize , "VkPhysicalDevicePrimitivesGeneratedQueryFeaturesEXT::primitivesGeneratedQueryWithNonZeroStreams" , self . physical_device_primitives_generated_query_features_ext . primitives_generated_query_with_non_zero_streams , other . physical_device_primitives_generated_query_features_ext . primitives_generated_query_with_non_zero_streams) ;
+        f(
+            110usize,
+            "VkPhysicalDeviceLegacyDitheringFeaturesEXT::legacyDithering",
+            self.physical_device_legacy_dithering_features_ext
+                .legacy_dithering,
+            other
+                .physical_device_legacy_dithering_features_ext
+                .legacy_dithering,
+        );
+        f (111usize , "VkPhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT::multisampledRenderToSingleSampled" , self . physical_device_multisampled_render_to_single_sampled_features_ext . multisampled_render_to_single_sampled , other . physical_device_multisampled_render_to_single_sampled_features_ext . multisampled_render_to_single_sampled) ;
+        f(
+            112usize,
+            "VkPhysicalDevicePipelineProtectedAccessFeaturesEXT::pipelineProtectedAccess",
+            self.physical_device_pipeline_protected_access_features_ext
+                .pipeline_protected_access,
+            other
+                .physical_device_pipeline_protected_access_features_ext
+                .pipeline_protected_access,
+        );
+        f(
+            113usize,
+            "VkPhysicalDeviceInheritedViewportScissorFeaturesNV::inheritedViewportScissor2D",
+            self.physical_device_inherited_viewport_scissor_features_nv
+                .inherited_viewport_scissor2_d,
+            other
+                .physical_device_inherited_viewport_scissor_features_nv
+                .inherited_viewport_scissor2_d,
+        );
+        f(
+            114usize,
+            "VkPhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT::ycbcr2plane444Formats",
+            self.physical_device_ycbcr2_plane444_formats_features_ext
+                .ycbcr2plane444_formats,
+            other
+                .physical_device_ycbcr2_plane444_formats_features_ext
+                .ycbcr2plane444_formats,
+        );
+        f(
+            115usize,
+            "VkPhysicalDeviceProvokingVertexFeaturesEXT::provokingVertexLast",
+            self.physical_device_provoking_vertex_features_ext
+                .provoking_vertex_last,
+            other
+                .physical_device_provoking_vertex_features_ext
+                .provoking_vertex_last,
+        );
+        f(
+            115usize,
+            "VkPhysicalDeviceProvokingVertexFeaturesEXT::transformFeedbackPreservesProvokingVertex",
+            self.physical_device_provoking_vertex_features_ext
+                .transform_feedback_preserves_provoking_vertex,
+            other
+                .physical_device_provoking_vertex_features_ext
+                .transform_feedback_preserves_provoking_vertex,
+        );
+        f(
+            116usize,
+            "VkPhysicalDeviceDescriptorBufferFeaturesEXT::descriptorBuffer",
+            self.physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer,
+            other
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer,
+        );
+        f(
+            116usize,
+            "VkPhysicalDeviceDescriptorBufferFeaturesEXT::descriptorBufferCaptureReplay",
+            self.physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_capture_replay,
+            other
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_capture_replay,
+        );
+        f(
+            116usize,
+            "VkPhysicalDeviceDescriptorBufferFeaturesEXT::descriptorBufferImageLayoutIgnored",
+            self.physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_image_layout_ignored,
+            other
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_image_layout_ignored,
+        );
+        f(
+            116usize,
+            "VkPhysicalDeviceDescriptorBufferFeaturesEXT::descriptorBufferPushDescriptors",
+            self.physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_push_descriptors,
+            other
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_push_descriptors,
+        );
+        f(
+            117usize,
+            "VkPhysicalDeviceShaderIntegerDotProductFeatures::shaderIntegerDotProduct",
+            self.physical_device_shader_integer_dot_product_features
+                .shader_integer_dot_product,
+            other
+                .physical_device_shader_integer_dot_product_features
+                .shader_integer_dot_product,
+        );
+        f(
+            118usize,
+            "VkPhysicalDeviceFragmentShaderBarycentricFeaturesKHR::fragmentShaderBarycentric",
+            self.physical_device_fragment_shader_barycentric_features_khr
+                .fragment_shader_barycentric,
+            other
+                .physical_device_fragment_shader_barycentric_features_khr
+                .fragment_shader_barycentric,
+        );
+        f(
+            119usize,
+            "VkPhysicalDeviceRayTracingMotionBlurFeaturesNV::rayTracingMotionBlur",
+            self.physical_device_ray_tracing_motion_blur_features_nv
+                .ray_tracing_motion_blur,
+            other
+                .physical_device_ray_tracing_motion_blur_features_nv
+                .ray_tracing_motion_blur,
+        );
+        f (119usize , "VkPhysicalDeviceRayTracingMotionBlurFeaturesNV::rayTracingMotionBlurPipelineTraceRaysIndirect" , self . physical_device_ray_tracing_motion_blur_features_nv . ray_tracing_motion_blur_pipeline_trace_rays_indirect , other . physical_device_ray_tracing_motion_blur_features_nv . ray_tracing_motion_blur_pipeline_trace_rays_indirect) ;
+        f(
+            120usize,
+            "VkPhysicalDeviceRGBA10X6FormatsFeaturesEXT::formatRgba10x6WithoutYCbCrSampler",
+            self.physical_device_rgba10x6_formats_features_ext
+                .format_rgba10x6_without_y_cb_cr_sampler,
+            other
+                .physical_device_rgba10x6_formats_features_ext
+                .format_rgba10x6_without_y_cb_cr_sampler,
+        );
+        f(
+            121usize,
+            "VkPhysicalDeviceDynamicRenderingFeatures::dynamicRendering",
+            self.physical_device_dynamic_rendering_features
+                .dynamic_rendering,
+            other
+                .physical_device_dynamic_rendering_features
+                .dynamic_rendering,
+        );
+        f(
+            122usize,
+            "VkPhysicalDeviceImageViewMinLodFeaturesEXT::minLod",
+            self.physical_device_image_view_min_lod_features_ext.min_lod,
+            other
+                .physical_device_image_view_min_lod_features_ext
+                .min_lod,
+        );
+        f (123usize , "VkPhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT::rasterizationOrderColorAttachmentAccess" , self . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_color_attachment_access , other . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_color_attachment_access) ;
+        f (123usize , "VkPhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT::rasterizationOrderDepthAttachmentAccess" , self . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_depth_attachment_access , other . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_depth_attachment_access) ;
+        f (123usize , "VkPhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT::rasterizationOrderStencilAttachmentAccess" , self . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_stencil_attachment_access , other . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_stencil_attachment_access) ;
+        f(
+            124usize,
+            "VkPhysicalDeviceLinearColorAttachmentFeaturesNV::linearColorAttachment",
+            self.physical_device_linear_color_attachment_features_nv
+                .linear_color_attachment,
+            other
+                .physical_device_linear_color_attachment_features_nv
+                .linear_color_attachment,
+        );
+        f(
+            125usize,
+            "VkPhysicalDeviceGraphicsPipelineLibraryFeaturesEXT::graphicsPipelineLibrary",
+            self.physical_device_graphics_pipeline_library_features_ext
+                .graphics_pipeline_library,
+            other
+                .physical_device_graphics_pipeline_library_features_ext
+                .graphics_pipeline_library,
+        );
+        f(
+            126usize,
+            "VkPhysicalDeviceDescriptorSetHostMappingFeaturesVALVE::descriptorSetHostMapping",
+            self.physical_device_descriptor_set_host_mapping_features_valve
+                .descriptor_set_host_mapping,
+            other
+                .physical_device_descriptor_set_host_mapping_features_valve
+                .descriptor_set_host_mapping,
+        );
+        f(
+            127usize,
+            "VkPhysicalDeviceShaderModuleIdentifierFeaturesEXT::shaderModuleIdentifier",
+            self.physical_device_shader_module_identifier_features_ext
+                .shader_module_identifier,
+            other
+                .physical_device_shader_module_identifier_features_ext
+                .shader_module_identifier,
+        );
+        f(
+            128usize,
+            "VkPhysicalDeviceImageCompressionControlFeaturesEXT::imageCompressionControl",
+            self.physical_device_image_compression_control_features_ext
+                .image_compression_control,
+            other
+                .physical_device_image_compression_control_features_ext
+                .image_compression_control,
+        );
+        f (129usize , "VkPhysicalDeviceImageCompressionControlSwapchainFeaturesEXT::imageCompressionControlSwapchain" , self . physical_device_image_compression_control_swapchain_features_ext . image_compression_control_swapchain , other . physical_device_image_compression_control_swapchain_features_ext . image_compression_control_swapchain) ;
+        f(
+            130usize,
+            "VkPhysicalDeviceSubpassMergeFeedbackFeaturesEXT::subpassMergeFeedback",
+            self.physical_device_subpass_merge_feedback_features_ext
+                .subpass_merge_feedback,
+            other
+                .physical_device_subpass_merge_feedback_features_ext
+                .subpass_merge_feedback,
+        );
+        f(
+            131usize,
+            "VkPhysicalDeviceOpacityMicromapFeaturesEXT::micromap",
+            self.physical_device_opacity_micromap_features_ext.micromap,
+            other.physical_device_opacity_micromap_features_ext.micromap,
+        );
+        f(
+            131usize,
+            "VkPhysicalDeviceOpacityMicromapFeaturesEXT::micromapCaptureReplay",
+            self.physical_device_opacity_micromap_features_ext
+                .micromap_capture_replay,
+            other
+                .physical_device_opacity_micromap_features_ext
+                .micromap_capture_replay,
+        );
+        f(
+            131usize,
+            "VkPhysicalDeviceOpacityMicromapFeaturesEXT::micromapHostCommands",
+            self.physical_device_opacity_micromap_features_ext
+                .micromap_host_commands,
+            other
+                .physical_device_opacity_micromap_features_ext
+                .micromap_host_commands,
+        );
+        f(
+            132usize,
+            "VkPhysicalDeviceDisplacementMicromapFeaturesNV::displacementMicromap",
+            self.physical_device_displacement_micromap_features_nv
+                .displacement_micromap,
+            other
+                .physical_device_displacement_micromap_features_nv
+                .displacement_micromap,
+        );
+        f(
+            133usize,
+            "VkPhysicalDevicePipelinePropertiesFeaturesEXT::pipelinePropertiesIdentifier",
+            self.physical_device_pipeline_properties_features_ext
+                .pipeline_properties_identifier,
+            other
+                .physical_device_pipeline_properties_features_ext
+                .pipeline_properties_identifier,
+        );
+        f (134usize , "VkPhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD::shaderEarlyAndLateFragmentTests" , self . physical_device_shader_early_and_late_fragment_tests_features_amd . shader_early_and_late_fragment_tests , other . physical_device_shader_early_and_late_fragment_tests_features_amd . shader_early_and_late_fragment_tests) ;
+        f(
+            135usize,
+            "VkPhysicalDeviceNonSeamlessCubeMapFeaturesEXT::nonSeamlessCubeMap",
+            self.physical_device_non_seamless_cube_map_features_ext
+                .non_seamless_cube_map,
+            other
+                .physical_device_non_seamless_cube_map_features_ext
+                .non_seamless_cube_map,
+        );
+        f(
+            136usize,
+            "VkPhysicalDevicePipelineRobustnessFeaturesEXT::pipelineRobustness",
+            self.physical_device_pipeline_robustness_features_ext
+                .pipeline_robustness,
+            other
+                .physical_device_pipeline_robustness_features_ext
+                .pipeline_robustness,
+        );
+        f(
+            137usize,
+            "VkPhysicalDeviceImageProcessingFeaturesQCOM::textureSampleWeighted",
+            self.physical_device_image_processing_features_qcom
+                .texture_sample_weighted,
+            other
+                .physical_device_image_processing_features_qcom
+                .texture_sample_weighted,
+        );
+        f(
+            137usize,
+            "VkPhysicalDeviceImageProcessingFeaturesQCOM::textureBoxFilter",
+            self.physical_device_image_processing_features_qcom
+                .texture_box_filter,
+            other
+                .physical_device_image_processing_features_qcom
+                .texture_box_filter,
+        );
+        f(
+            137usize,
+            "VkPhysicalDeviceImageProcessingFeaturesQCOM::textureBlockMatch",
+            self.physical_device_image_processing_features_qcom
+                .texture_block_match,
+            other
+                .physical_device_image_processing_features_qcom
+                .texture_block_match,
+        );
+        f(
+            138usize,
+            "VkPhysicalDeviceTilePropertiesFeaturesQCOM::tileProperties",
+            self.physical_device_tile_properties_features_qcom
+                .tile_properties,
+            other
+                .physical_device_tile_properties_features_qcom
+                .tile_properties,
+        );
+        f(
+            139usize,
+            "VkPhysicalDeviceAmigoProfilingFeaturesSEC::amigoProfiling",
+            self.physical_device_amigo_profiling_features_sec
+                .amigo_profiling,
+            other
+                .physical_device_amigo_profiling_features_sec
+                .amigo_profiling,
+        );
+        f(
+            140usize,
+            "VkPhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT::attachmentFeedbackLoopLayout",
+            self.physical_device_attachment_feedback_loop_layout_features_ext
+                .attachment_feedback_loop_layout,
+            other
+                .physical_device_attachment_feedback_loop_layout_features_ext
+                .attachment_feedback_loop_layout,
+        );
+        f(
+            141usize,
+            "VkPhysicalDeviceDepthClampZeroOneFeaturesEXT::depthClampZeroOne",
+            self.physical_device_depth_clamp_zero_one_features_ext
+                .depth_clamp_zero_one,
+            other
+                .physical_device_depth_clamp_zero_one_features_ext
+                .depth_clamp_zero_one,
+        );
+        f(
+            142usize,
+            "VkPhysicalDeviceAddressBindingReportFeaturesEXT::reportAddressBinding",
+            self.physical_device_address_binding_report_features_ext
+                .report_address_binding,
+            other
+                .physical_device_address_binding_report_features_ext
+                .report_address_binding,
+        );
+        f(
+            143usize,
+            "VkPhysicalDeviceOpticalFlowFeaturesNV::opticalFlow",
+            self.physical_device_optical_flow_features_nv.optical_flow,
+            other.physical_device_optical_flow_features_nv.optical_flow,
+        );
+        f(
+            144usize,
+            "VkPhysicalDeviceFaultFeaturesEXT::deviceFault",
+            self.physical_device_fault_features_ext.device_fault,
+            other.physical_device_fault_features_ext.device_fault,
+        );
+        f(
+            144usize,
+            "VkPhysicalDeviceFaultFeaturesEXT::deviceFaultVendorBinary",
+            self.physical_device_fault_features_ext
+                .device_fault_vendor_binary,
+            other
+                .physical_device_fault_features_ext
+                .device_fault_vendor_binary,
+        );
+        f(
+            145usize,
+            "VkPhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT::pipelineLibraryGroupHandles",
+            self.physical_device_pipeline_library_group_handles_features_ext
+                .pipeline_library_group_handles,
+            other
+                .physical_device_pipeline_library_group_handles_features_ext
+                .pipeline_library_group_handles,
+        );
+        f(
+            146usize,
+            "VkPhysicalDeviceShaderCoreBuiltinsFeaturesARM::shaderCoreBuiltins",
+            self.physical_device_shader_core_builtins_features_arm
+                .shader_core_builtins,
+            other
+                .physical_device_shader_core_builtins_features_arm
+                .shader_core_builtins,
+        );
+        f (147usize , "VkPhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT::dynamicRenderingUnusedAttachments" , self . physical_device_dynamic_rendering_unused_attachments_features_ext . dynamic_rendering_unused_attachments , other . physical_device_dynamic_rendering_unused_attachments_features_ext . dynamic_rendering_unused_attachments) ;
+        f(
+            148usize,
+            "VkPhysicalDeviceSwapchainMaintenance1FeaturesEXT::swapchainMaintenance1",
+            self.physical_device_swapchain_maintenance1_features_ext
+                .swapchain_maintenance1,
+            other
+                .physical_device_swapchain_maintenance1_features_ext
+                .swapchain_maintenance1,
+        );
+        f(
+            149usize,
+            "VkPhysicalDeviceDepthBiasControlFeaturesEXT::depthBiasControl",
+            self.physical_device_depth_bias_control_features_ext
+                .depth_bias_control,
+            other
+                .physical_device_depth_bias_control_features_ext
+                .depth_bias_control,
+        );
+        f (149usize , "VkPhysicalDeviceDepthBiasControlFeaturesEXT::leastRepresentableValueForceUnormRepresentation" , self . physical_device_depth_bias_control_features_ext . least_representable_value_force_unorm_representation , other . physical_device_depth_bias_control_features_ext . least_representable_value_force_unorm_representation) ;
+        f(
+            149usize,
+            "VkPhysicalDeviceDepthBiasControlFeaturesEXT::floatRepresentation",
+            self.physical_device_depth_bias_control_features_ext
+                .float_representation,
+            other
+                .physical_device_depth_bias_control_features_ext
+                .float_representation,
+        );
+        f(
+            149usize,
+            "VkPhysicalDeviceDepthBiasControlFeaturesEXT::depthBiasExact",
+            self.physical_device_depth_bias_control_features_ext
+                .depth_bias_exact,
+            other
+                .physical_device_depth_bias_control_features_ext
+                .depth_bias_exact,
+        );
+        f(
+            150usize,
+            "VkPhysicalDeviceRayTracingInvocationReorderFeaturesNV::rayTracingInvocationReorder",
+            self.physical_device_ray_tracing_invocation_reorder_features_nv
+                .ray_tracing_invocation_reorder,
+            other
+                .physical_device_ray_tracing_invocation_reorder_features_nv
+                .ray_tracing_invocation_reorder,
+        );
+        f(
+            151usize,
+            "VkPhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM::multiviewPerViewViewports",
+            self.physical_device_multiview_per_view_viewports_features_qcom
+                .multiview_per_view_viewports,
+            other
+                .physical_device_multiview_per_view_viewports_features_qcom
+                .multiview_per_view_viewports,
+        );
+        f(
+            152usize,
+            "VkPhysicalDeviceRayTracingPositionFetchFeaturesKHR::rayTracingPositionFetch",
+            self.physical_device_ray_tracing_position_fetch_features_khr
+                .ray_tracing_position_fetch,
+            other
+                .physical_device_ray_tracing_position_fetch_features_khr
+                .ray_tracing_position_fetch,
+        );
+        f(
+            153usize,
+            "VkPhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM::multiviewPerViewRenderAreas",
+            self.physical_device_multiview_per_view_render_areas_features_qcom
+                .multiview_per_view_render_areas,
+            other
+                .physical_device_multiview_per_view_render_areas_features_qcom
+                .multiview_per_view_render_areas,
+        );
+        f(
+            154usize,
+            "VkPhysicalDeviceShaderObjectFeaturesEXT::shaderObject",
+            self.physical_device_shader_object_features_ext
+                .shader_object,
+            other
+                .physical_device_shader_object_features_ext
+                .shader_object,
+        );
+        f(
+            155usize,
+            "VkPhysicalDeviceShaderTileImageFeaturesEXT::shaderTileImageColorReadAccess",
+            self.physical_device_shader_tile_image_features_ext
+                .shader_tile_image_color_read_access,
+            other
+                .physical_device_shader_tile_image_features_ext
+                .shader_tile_image_color_read_access,
+        );
+        f(
+            155usize,
+            "VkPhysicalDeviceShaderTileImageFeaturesEXT::shaderTileImageDepthReadAccess",
+            self.physical_device_shader_tile_image_features_ext
+                .shader_tile_image_depth_read_access,
+            other
+                .physical_device_shader_tile_image_features_ext
+                .shader_tile_image_depth_read_access,
+        );
+        f(
+            155usize,
+            "VkPhysicalDeviceShaderTileImageFeaturesEXT::shaderTileImageStencilReadAccess",
+            self.physical_device_shader_tile_image_features_ext
+                .shader_tile_image_stencil_read_access,
+            other
+                .physical_device_shader_tile_image_features_ext
+                .shader_tile_image_stencil_read_access,
+        );
+        f(
+            156usize,
+            "VkPhysicalDeviceExternalMemoryScreenBufferFeaturesQNX::screenBufferImport",
+            self.physical_device_external_memory_screen_buffer_features_qnx
+                .screen_buffer_import,
+            other
+                .physical_device_external_memory_screen_buffer_features_qnx
+                .screen_buffer_import,
+        );
+    }
+    #[doc = r" Like [`Structs::zip()`], with mutable values in `self`."]
+    pub(super) fn zip_mut(
+        &mut self,
+        other: &Self,
+        mut f: impl FnMut(usize, &'static str, &mut vk::Bool32, vk::Bool32),
+    ) {
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::robustBufferAccess",
+            &mut self.physical_device_features2.features.robust_buffer_access,
+            other
+                .physical_device_features2
+                .features
+                .robust_buffer_access,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::fullDrawIndexUint32",
+            &mut self
+                .physical_device_features2
+                .features
+                .full_draw_index_uint32,
+            other
+                .physical_device_features2
+                .features
+                .full_draw_index_uint32,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::imageCubeArray",
+            &mut self.physical_device_features2.features.image_cube_array,
+            other.physical_device_features2.features.image_cube_array,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::independentBlend",
+            &mut self.physical_device_features2.features.independent_blend,
+            other.physical_device_features2.features.independent_blend,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::geometryShader",
+            &mut self.physical_device_features2.features.geometry_shader,
+            other.physical_device_features2.features.geometry_shader,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::tessellationShader",
+            &mut self.physical_device_features2.features.tessellation_shader,
+            other.physical_device_features2.features.tessellation_shader,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sampleRateShading",
+            &mut self.physical_device_features2.features.sample_rate_shading,
+            other.physical_device_features2.features.sample_rate_shading,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::dualSrcBlend",
+            &mut self.physical_device_features2.features.dual_src_blend,
+            other.physical_device_features2.features.dual_src_blend,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::logicOp",
+            &mut self.physical_device_features2.features.logic_op,
+            other.physical_device_features2.features.logic_op,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::multiDrawIndirect",
+            &mut self.physical_device_features2.features.multi_draw_indirect,
+            other.physical_device_features2.features.multi_draw_indirect,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::drawIndirectFirstInstance",
+            &mut self
+                .physical_device_features2
+                .features
+                .draw_indirect_first_instance,
+            other
+                .physical_device_features2
+                .features
+                .draw_indirect_first_instance,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::depthClamp",
+            &mut self.physical_device_features2.features.depth_clamp,
+            other.physical_device_features2.features.depth_clamp,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::depthBiasClamp",
+            &mut self.physical_device_features2.features.depth_bias_clamp,
+            other.physical_device_features2.features.depth_bias_clamp,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::fillModeNonSolid",
+            &mut self.physical_device_features2.features.fill_mode_non_solid,
+            other.physical_device_features2.features.fill_mode_non_solid,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::depthBounds",
+            &mut self.physical_device_features2.features.depth_bounds,
+            other.physical_device_features2.features.depth_bounds,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::wideLines",
+            &mut self.physical_device_features2.features.wide_lines,
+            other.physical_device_features2.features.wide_lines,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::largePoints",
+            &mut self.physical_device_features2.features.large_points,
+            other.physical_device_features2.features.large_points,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::alphaToOne",
+            &mut self.physical_device_features2.features.alpha_to_one,
+            other.physical_device_features2.features.alpha_to_one,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::multiViewport",
+            &mut self.physical_device_features2.features.multi_viewport,
+            other.physical_device_features2.features.multi_viewport,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::samplerAnisotropy",
+            &mut self.physical_device_features2.features.sampler_anisotropy,
+            other.physical_device_features2.features.sampler_anisotropy,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::textureCompressionETC2",
+            &mut self
+                .physical_device_features2
+                .features
+                .texture_compression_etc2,
+            other
+                .physical_device_features2
+                .features
+                .texture_compression_etc2,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::textureCompressionASTC_LDR",
+            &mut self
+                .physical_device_features2
+                .features
+                .texture_compression_astc_ldr,
+            other
+                .physical_device_features2
+                .features
+                .texture_compression_astc_ldr,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::textureCompressionBC",
+            &mut self
+                .physical_device_features2
+                .features
+                .texture_compression_bc,
+            other
+                .physical_device_features2
+                .features
+                .texture_compression_bc,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::occlusionQueryPrecise",
+            &mut self
+                .physical_device_features2
+                .features
+                .occlusion_query_precise,
+            other
+                .physical_device_features2
+                .features
+                .occlusion_query_precise,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::pipelineStatisticsQuery",
+            &mut self
+                .physical_device_features2
+                .features
+                .pipeline_statistics_query,
+            other
+                .physical_device_features2
+                .features
+                .pipeline_statistics_query,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::vertexPipelineStoresAndAtomics",
+            &mut self
+                .physical_device_features2
+                .features
+                .vertex_pipeline_stores_and_atomics,
+            other
+                .physical_device_features2
+                .features
+                .vertex_pipeline_stores_and_atomics,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::fragmentStoresAndAtomics",
+            &mut self
+                .physical_device_features2
+                .features
+                .fragment_stores_and_atomics,
+            other
+                .physical_device_features2
+                .features
+                .fragment_stores_and_atomics,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderTessellationAndGeometryPointSize",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_tessellation_and_geometry_point_size,
+            other
+                .physical_device_features2
+                .features
+                .shader_tessellation_and_geometry_point_size,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderImageGatherExtended",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_image_gather_extended,
+            other
+                .physical_device_features2
+                .features
+                .shader_image_gather_extended,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageImageExtendedFormats",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_storage_image_extended_formats,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_image_extended_formats,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageImageMultisample",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_storage_image_multisample,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_image_multisample,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageImageReadWithoutFormat",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_storage_image_read_without_format,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_image_read_without_format,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageImageWriteWithoutFormat",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_storage_image_write_without_format,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_image_write_without_format,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderUniformBufferArrayDynamicIndexing",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_uniform_buffer_array_dynamic_indexing,
+            other
+                .physical_device_features2
+                .features
+                .shader_uniform_buffer_array_dynamic_indexing,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderSampledImageArrayDynamicIndexing",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_sampled_image_array_dynamic_indexing,
+            other
+                .physical_device_features2
+                .features
+                .shader_sampled_image_array_dynamic_indexing,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageBufferArrayDynamicIndexing",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_storage_buffer_array_dynamic_indexing,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_buffer_array_dynamic_indexing,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderStorageImageArrayDynamicIndexing",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_storage_image_array_dynamic_indexing,
+            other
+                .physical_device_features2
+                .features
+                .shader_storage_image_array_dynamic_indexing,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderClipDistance",
+            &mut self.physical_device_features2.features.shader_clip_distance,
+            other
+                .physical_device_features2
+                .features
+                .shader_clip_distance,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderCullDistance",
+            &mut self.physical_device_features2.features.shader_cull_distance,
+            other
+                .physical_device_features2
+                .features
+                .shader_cull_distance,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderFloat64",
+            &mut self.physical_device_features2.features.shader_float64,
+            other.physical_device_features2.features.shader_float64,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderInt64",
+            &mut self.physical_device_features2.features.shader_int64,
+            other.physical_device_features2.features.shader_int64,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderInt16",
+            &mut self.physical_device_features2.features.shader_int16,
+            other.physical_device_features2.features.shader_int16,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderResourceResidency",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_resource_residency,
+            other
+                .physical_device_features2
+                .features
+                .shader_resource_residency,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::shaderResourceMinLod",
+            &mut self
+                .physical_device_features2
+                .features
+                .shader_resource_min_lod,
+            other
+                .physical_device_features2
+                .features
+                .shader_resource_min_lod,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseBinding",
+            &mut self.physical_device_features2.features.sparse_binding,
+            other.physical_device_features2.features.sparse_binding,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidencyBuffer",
+            &mut self
+                .physical_device_features2
+                .features
+                .sparse_residency_buffer,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency_buffer,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidencyImage2D",
+            &mut self
+                .physical_device_features2
+                .features
+                .sparse_residency_image2_d,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency_image2_d,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidencyImage3D",
+            &mut self
+                .physical_device_features2
+                .features
+                .sparse_residency_image3_d,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency_image3_d,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidency2Samples",
+            &mut self
+                .physical_device_features2
+                .features
+                .sparse_residency2_samples,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency2_samples,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidency4Samples",
+            &mut self
+                .physical_device_features2
+                .features
+                .sparse_residency4_samples,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency4_samples,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidency8Samples",
+            &mut self
+                .physical_device_features2
+                .features
+                .sparse_residency8_samples,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency8_samples,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidency16Samples",
+            &mut self
+                .physical_device_features2
+                .features
+                .sparse_residency16_samples,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency16_samples,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::sparseResidencyAliased",
+            &mut self
+                .physical_device_features2
+                .features
+                .sparse_residency_aliased,
+            other
+                .physical_device_features2
+                .features
+                .sparse_residency_aliased,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::variableMultisampleRate",
+            &mut self
+                .physical_device_features2
+                .features
+                .variable_multisample_rate,
+            other
+                .physical_device_features2
+                .features
+                .variable_multisample_rate,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceFeatures::inheritedQueries",
+            &mut self.physical_device_features2.features.inherited_queries,
+            other.physical_device_features2.features.inherited_queries,
+        );
+        f(
+            1usize,
+            "VkPhysicalDeviceDeviceGeneratedCommandsFeaturesNV::deviceGeneratedCommands",
+            &mut self
+                .physical_device_device_generated_commands_features_nv
+                .device_generated_commands,
+            other
+                .physical_device_device_generated_commands_features_nv
+                .device_generated_commands,
+        );
+        f(
+            2usize,
+            "VkPhysicalDevicePrivateDataFeatures::privateData",
+            &mut self.physical_device_private_data_features.private_data,
+            other.physical_device_private_data_features.private_data,
+        );
+        f(
+            3usize,
+            "VkPhysicalDeviceVariablePointersFeatures::variablePointersStorageBuffer",
+            &mut self
+                .physical_device_variable_pointers_features
+                .variable_pointers_storage_buffer,
+            other
+                .physical_device_variable_pointers_features
+                .variable_pointers_storage_buffer,
+        );
+        f(
+            3usize,
+            "VkPhysicalDeviceVariablePointersFeatures::variablePointers",
+            &mut self
+                .physical_device_variable_pointers_features
+                .variable_pointers,
+            other
+                .physical_device_variable_pointers_features
+                .variable_pointers,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceMultiviewFeatures::multiview",
+            &mut self.physical_device_multiview_features.multiview,
+            other.physical_device_multiview_features.multiview,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceMultiviewFeatures::multiviewGeometryShader",
+            &mut self
+                .physical_device_multiview_features
+                .multiview_geometry_shader,
+            other
+                .physical_device_multiview_features
+                .multiview_geometry_shader,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceMultiviewFeatures::multiviewTessellationShader",
+            &mut self
+                .physical_device_multiview_features
+                .multiview_tessellation_shader,
+            other
+                .physical_device_multiview_features
+                .multiview_tessellation_shader,
+        );
+        f(
+            5usize,
+            "VkPhysicalDevicePresentIdFeaturesKHR::presentId",
+            &mut self.physical_device_present_id_features_khr.present_id,
+            other.physical_device_present_id_features_khr.present_id,
+        );
+        f(
+            6usize,
+            "VkPhysicalDevicePresentWaitFeaturesKHR::presentWait",
+            &mut self.physical_device_present_wait_features_khr.present_wait,
+            other.physical_device_present_wait_features_khr.present_wait,
+        );
+        f(
+            7usize,
+            "VkPhysicalDevice16BitStorageFeatures::storageBuffer16BitAccess",
+            &mut self
+                .physical_device16_bit_storage_features
+                .storage_buffer16_bit_access,
+            other
+                .physical_device16_bit_storage_features
+                .storage_buffer16_bit_access,
+        );
+        f(
+            7usize,
+            "VkPhysicalDevice16BitStorageFeatures::uniformAndStorageBuffer16BitAccess",
+            &mut self
+                .physical_device16_bit_storage_features
+                .uniform_and_storage_buffer16_bit_access,
+            other
+                .physical_device16_bit_storage_features
+                .uniform_and_storage_buffer16_bit_access,
+        );
+        f(
+            7usize,
+            "VkPhysicalDevice16BitStorageFeatures::storagePushConstant16",
+            &mut self
+                .physical_device16_bit_storage_features
+                .storage_push_constant16,
+            other
+                .physical_device16_bit_storage_features
+                .storage_push_constant16,
+        );
+        f(
+            7usize,
+            "VkPhysicalDevice16BitStorageFeatures::storageInputOutput16",
+            &mut self
+                .physical_device16_bit_storage_features
+                .storage_input_output16,
+            other
+                .physical_device16_bit_storage_features
+                .storage_input_output16,
+        );
+        f(
+            8usize,
+            "VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures::shaderSubgroupExtendedTypes",
+            &mut self
+                .physical_device_shader_subgroup_extended_types_features
+                .shader_subgroup_extended_types,
+            other
+                .physical_device_shader_subgroup_extended_types_features
+                .shader_subgroup_extended_types,
+        );
+        f(
+            9usize,
+            "VkPhysicalDeviceSamplerYcbcrConversionFeatures::samplerYcbcrConversion",
+            &mut self
+                .physical_device_sampler_ycbcr_conversion_features
+                .sampler_ycbcr_conversion,
+            other
+                .physical_device_sampler_ycbcr_conversion_features
+                .sampler_ycbcr_conversion,
+        );
+        f(
+            10usize,
+            "VkPhysicalDeviceProtectedMemoryFeatures::protectedMemory",
+            &mut self
+                .physical_device_protected_memory_features
+                .protected_memory,
+            other
+                .physical_device_protected_memory_features
+                .protected_memory,
+        );
+        f(
+            11usize,
+            "VkPhysicalDeviceBlendOperationAdvancedFeaturesEXT::advancedBlendCoherentOperations",
+            &mut self
+                .physical_device_blend_operation_advanced_features_ext
+                .advanced_blend_coherent_operations,
+            other
+                .physical_device_blend_operation_advanced_features_ext
+                .advanced_blend_coherent_operations,
+        );
+        f(
+            12usize,
+            "VkPhysicalDeviceMultiDrawFeaturesEXT::multiDraw",
+            &mut self.physical_device_multi_draw_features_ext.multi_draw,
+            other.physical_device_multi_draw_features_ext.multi_draw,
+        );
+        f(
+            13usize,
+            "VkPhysicalDeviceInlineUniformBlockFeatures::inlineUniformBlock",
+            &mut self
+                .physical_device_inline_uniform_block_features
+                .inline_uniform_block,
+            other
+                .physical_device_inline_uniform_block_features
+                .inline_uniform_block,
+        );
+        f (13usize , "VkPhysicalDeviceInlineUniformBlockFeatures::descriptorBindingInlineUniformBlockUpdateAfterBind" , & mut self . physical_device_inline_uniform_block_features . descriptor_binding_inline_uniform_block_update_after_bind , other . physical_device_inline_uniform_block_features . descriptor_binding_inline_uniform_block_update_after_bind) ;
+        f(
+            14usize,
+            "VkPhysicalDeviceMaintenance4Features::maintenance4",
+            &mut self.physical_device_maintenance4_features.maintenance4,
+            other.physical_device_maintenance4_features.maintenance4,
+        );
+        f(
+            15usize,
+            "VkPhysicalDeviceShaderDrawParametersFeatures::shaderDrawParameters",
+            &mut self
+                .physical_device_shader_draw_parameters_features
+                .shader_draw_parameters,
+            other
+                .physical_device_shader_draw_parameters_features
+                .shader_draw_parameters,
+        );
+        f(
+            16usize,
+            "VkPhysicalDeviceShaderFloat16Int8Features::shaderFloat16",
+            &mut self
+                .physical_device_shader_float16_int8_features
+                .shader_float16,
+            other
+                .physical_device_shader_float16_int8_features
+                .shader_float16,
+        );
+        f(
+            16usize,
+            "VkPhysicalDeviceShaderFloat16Int8Features::shaderInt8",
+            &mut self
+                .physical_device_shader_float16_int8_features
+                .shader_int8,
+            other
+                .physical_device_shader_float16_int8_features
+                .shader_int8,
+        );
+        f(
+            17usize,
+            "VkPhysicalDeviceHostQueryResetFeatures::hostQueryReset",
+            &mut self
+                .physical_device_host_query_reset_features
+                .host_query_reset,
+            other
+                .physical_device_host_query_reset_features
+                .host_query_reset,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR::globalPriorityQuery",
+            &mut self
+                .physical_device_global_priority_query_features_khr
+                .global_priority_query,
+            other
+                .physical_device_global_priority_query_features_khr
+                .global_priority_query,
+        );
+        f(
+            19usize,
+            "VkPhysicalDeviceDeviceMemoryReportFeaturesEXT::deviceMemoryReport",
+            &mut self
+                .physical_device_device_memory_report_features_ext
+                .device_memory_report,
+            other
+                .physical_device_device_memory_report_features_ext
+                .device_memory_report,
+        );
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::shaderInputAttachmentArrayDynamicIndexing",
+            &mut self
+                .physical_device_descriptor_indexing_features
+                .shader_input_attachment_array_dynamic_indexing,
+            other
+                .physical_device_descriptor_indexing_features
+                .shader_input_attachment_array_dynamic_indexing,
+        );
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderUniformTexelBufferArrayDynamicIndexing" , & mut self . physical_device_descriptor_indexing_features . shader_uniform_texel_buffer_array_dynamic_indexing , other . physical_device_descriptor_indexing_features . shader_uniform_texel_buffer_array_dynamic_indexing) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderStorageTexelBufferArrayDynamicIndexing" , & mut self . physical_device_descriptor_indexing_features . shader_storage_texel_buffer_array_dynamic_indexing , other . physical_device_descriptor_indexing_features . shader_storage_texel_buffer_array_dynamic_indexing) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderUniformBufferArrayNonUniformIndexing" , & mut self . physical_device_descriptor_indexing_features . shader_uniform_buffer_array_non_uniform_indexing , other . physical_device_descriptor_indexing_features . shader_uniform_buffer_array_non_uniform_indexing) ;
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::shaderSampledImageArrayNonUniformIndexing",
+            &mut self
+                .physical_device_descriptor_indexing_features
+                .shader_sampled_image_array_non_uniform_indexing,
+            other
+                .physical_device_descriptor_indexing_features
+                .shader_sampled_image_array_non_uniform_indexing,
+        );
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderStorageBufferArrayNonUniformIndexing" , & mut self . physical_device_descriptor_indexing_features . shader_storage_buffer_array_non_uniform_indexing , other . physical_device_descriptor_indexing_features . shader_storage_buffer_array_non_uniform_indexing) ;
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::shaderStorageImageArrayNonUniformIndexing",
+            &mut self
+                .physical_device_descriptor_indexing_features
+                .shader_storage_image_array_non_uniform_indexing,
+            other
+                .physical_device_descriptor_indexing_features
+                .shader_storage_image_array_non_uniform_indexing,
+        );
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderInputAttachmentArrayNonUniformIndexing" , & mut self . physical_device_descriptor_indexing_features . shader_input_attachment_array_non_uniform_indexing , other . physical_device_descriptor_indexing_features . shader_input_attachment_array_non_uniform_indexing) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderUniformTexelBufferArrayNonUniformIndexing" , & mut self . physical_device_descriptor_indexing_features . shader_uniform_texel_buffer_array_non_uniform_indexing , other . physical_device_descriptor_indexing_features . shader_uniform_texel_buffer_array_non_uniform_indexing) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::shaderStorageTexelBufferArrayNonUniformIndexing" , & mut self . physical_device_descriptor_indexing_features . shader_storage_texel_buffer_array_non_uniform_indexing , other . physical_device_descriptor_indexing_features . shader_storage_texel_buffer_array_non_uniform_indexing) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingUniformBufferUpdateAfterBind" , & mut self . physical_device_descriptor_indexing_features . descriptor_binding_uniform_buffer_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_uniform_buffer_update_after_bind) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingSampledImageUpdateAfterBind" , & mut self . physical_device_descriptor_indexing_features . descriptor_binding_sampled_image_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_sampled_image_update_after_bind) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingStorageImageUpdateAfterBind" , & mut self . physical_device_descriptor_indexing_features . descriptor_binding_storage_image_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_storage_image_update_after_bind) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingStorageBufferUpdateAfterBind" , & mut self . physical_device_descriptor_indexing_features . descriptor_binding_storage_buffer_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_storage_buffer_update_after_bind) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingUniformTexelBufferUpdateAfterBind" , & mut self . physical_device_descriptor_indexing_features . descriptor_binding_uniform_texel_buffer_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_uniform_texel_buffer_update_after_bind) ;
+        f (20usize , "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingStorageTexelBufferUpdateAfterBind" , & mut self . physical_device_descriptor_indexing_features . descriptor_binding_storage_texel_buffer_update_after_bind , other . physical_device_descriptor_indexing_features . descriptor_binding_storage_texel_buffer_update_after_bind) ;
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingUpdateUnusedWhilePending",
+            &mut self
+                .physical_device_descriptor_indexing_features
+                .descriptor_binding_update_unused_while_pending,
+            other
+                .physical_device_descriptor_indexing_features
+                .descriptor_binding_update_unused_while_pending,
+        );
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingPartiallyBound",
+            &mut self
+                .physical_device_descriptor_indexing_features
+                .descriptor_binding_partially_bound,
+            other
+                .physical_device_descriptor_indexing_features
+                .descriptor_binding_partially_bound,
+        );
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::descriptorBindingVariableDescriptorCount",
+            &mut self
+                .physical_device_descriptor_indexing_features
+                .descriptor_binding_variable_descriptor_count,
+            other
+                .physical_device_descriptor_indexing_features
+                .descriptor_binding_variable_descriptor_count,
+        );
+        f(
+            20usize,
+            "VkPhysicalDeviceDescriptorIndexingFeatures::runtimeDescriptorArray",
+            &mut self
+                .physical_device_descriptor_indexing_features
+                .runtime_descriptor_array,
+            other
+                .physical_device_descriptor_indexing_features
+                .runtime_descriptor_array,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceTimelineSemaphoreFeatures::timelineSemaphore",
+            &mut self
+                .physical_device_timeline_semaphore_features
+                .timeline_semaphore,
+            other
+                .physical_device_timeline_semaphore_features
+                .timeline_semaphore,
+        );
+        f(
+            22usize,
+            "VkPhysicalDevice8BitStorageFeatures::storageBuffer8BitAccess",
+            &mut self
+                .physical_device8_bit_storage_features
+                .storage_buffer8_bit_access,
+            other
+                .physical_device8_bit_storage_features
+                .storage_buffer8_bit_access,
+        );
+        f(
+            22usize,
+            "VkPhysicalDevice8BitStorageFeatures::uniformAndStorageBuffer8BitAccess",
+            &mut self
+                .physical_device8_bit_storage_features
+                .uniform_and_storage_buffer8_bit_access,
+            other
+                .physical_device8_bit_storage_features
+                .uniform_and_storage_buffer8_bit_access,
+        );
+        f(
+            22usize,
+            "VkPhysicalDevice8BitStorageFeatures::storagePushConstant8",
+            &mut self
+                .physical_device8_bit_storage_features
+                .storage_push_constant8,
+            other
+                .physical_device8_bit_storage_features
+                .storage_push_constant8,
+        );
+        f(
+            23usize,
+            "VkPhysicalDeviceConditionalRenderingFeaturesEXT::conditionalRendering",
+            &mut self
+                .physical_device_conditional_rendering_features_ext
+                .conditional_rendering,
+            other
+                .physical_device_conditional_rendering_features_ext
+                .conditional_rendering,
+        );
+        f(
+            23usize,
+            "VkPhysicalDeviceConditionalRenderingFeaturesEXT::inheritedConditionalRendering",
+            &mut self
+                .physical_device_conditional_rendering_features_ext
+                .inherited_conditional_rendering,
+            other
+                .physical_device_conditional_rendering_features_ext
+                .inherited_conditional_rendering,
+        );
+        f(
+            24usize,
+            "VkPhysicalDeviceVulkanMemoryModelFeatures::vulkanMemoryModel",
+            &mut self
+                .physical_device_vulkan_memory_model_features
+                .vulkan_memory_model,
+            other
+                .physical_device_vulkan_memory_model_features
+                .vulkan_memory_model,
+        );
+        f(
+            24usize,
+            "VkPhysicalDeviceVulkanMemoryModelFeatures::vulkanMemoryModelDeviceScope",
+            &mut self
+                .physical_device_vulkan_memory_model_features
+                .vulkan_memory_model_device_scope,
+            other
+                .physical_device_vulkan_memory_model_features
+                .vulkan_memory_model_device_scope,
+        );
+        f (24usize , "VkPhysicalDeviceVulkanMemoryModelFeatures::vulkanMemoryModelAvailabilityVisibilityChains" , & mut self . physical_device_vulkan_memory_model_features . vulkan_memory_model_availability_visibility_chains , other . physical_device_vulkan_memory_model_features . vulkan_memory_model_availability_visibility_chains) ;
+        f(
+            25usize,
+            "VkPhysicalDeviceShaderAtomicInt64Features::shaderBufferInt64Atomics",
+            &mut self
+                .physical_device_shader_atomic_int64_features
+                .shader_buffer_int64_atomics,
+            other
+                .physical_device_shader_atomic_int64_features
+                .shader_buffer_int64_atomics,
+        );
+        f(
+            25usize,
+            "VkPhysicalDeviceShaderAtomicInt64Features::shaderSharedInt64Atomics",
+            &mut self
+                .physical_device_shader_atomic_int64_features
+                .shader_shared_int64_atomics,
+            other
+                .physical_device_shader_atomic_int64_features
+                .shader_shared_int64_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderBufferFloat32Atomics",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float32_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float32_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderBufferFloat32AtomicAdd",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float32_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float32_atomic_add,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderBufferFloat64Atomics",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float64_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float64_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderBufferFloat64AtomicAdd",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float64_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_buffer_float64_atomic_add,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderSharedFloat32Atomics",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float32_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float32_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderSharedFloat32AtomicAdd",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float32_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float32_atomic_add,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderSharedFloat64Atomics",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float64_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float64_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderSharedFloat64AtomicAdd",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float64_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_shared_float64_atomic_add,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderImageFloat32Atomics",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .shader_image_float32_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_image_float32_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::shaderImageFloat32AtomicAdd",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .shader_image_float32_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .shader_image_float32_atomic_add,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::sparseImageFloat32Atomics",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .sparse_image_float32_atomics,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .sparse_image_float32_atomics,
+        );
+        f(
+            26usize,
+            "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT::sparseImageFloat32AtomicAdd",
+            &mut self
+                .physical_device_shader_atomic_float_features_ext
+                .sparse_image_float32_atomic_add,
+            other
+                .physical_device_shader_atomic_float_features_ext
+                .sparse_image_float32_atomic_add,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderBufferFloat16Atomics",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomics,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomics,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderBufferFloat16AtomicAdd",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomic_add,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomic_add,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderBufferFloat16AtomicMinMax",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float16_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderBufferFloat32AtomicMinMax",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float32_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float32_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderBufferFloat64AtomicMinMax",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float64_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_buffer_float64_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderSharedFloat16Atomics",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomics,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomics,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderSharedFloat16AtomicAdd",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomic_add,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomic_add,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderSharedFloat16AtomicMinMax",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float16_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderSharedFloat32AtomicMinMax",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float32_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float32_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderSharedFloat64AtomicMinMax",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float64_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_shared_float64_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::shaderImageFloat32AtomicMinMax",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_image_float32_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .shader_image_float32_atomic_min_max,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT::sparseImageFloat32AtomicMinMax",
+            &mut self
+                .physical_device_shader_atomic_float2_features_ext
+                .sparse_image_float32_atomic_min_max,
+            other
+                .physical_device_shader_atomic_float2_features_ext
+                .sparse_image_float32_atomic_min_max,
+        );
+        f(
+            28usize,
+            "VkPhysicalDeviceVertexAttributeDivisorFeaturesEXT::vertexAttributeInstanceRateDivisor",
+            &mut self
+                .physical_device_vertex_attribute_divisor_features_ext
+                .vertex_attribute_instance_rate_divisor,
+            other
+                .physical_device_vertex_attribute_divisor_features_ext
+                .vertex_attribute_instance_rate_divisor,
+        );
+        f (28usize , "VkPhysicalDeviceVertexAttributeDivisorFeaturesEXT::vertexAttributeInstanceRateZeroDivisor" , & mut self . physical_device_vertex_attribute_divisor_features_ext . vertex_attribute_instance_rate_zero_divisor , other . physical_device_vertex_attribute_divisor_features_ext . vertex_attribute_instance_rate_zero_divisor) ;
+        f(
+            29usize,
+            "VkPhysicalDeviceASTCDecodeFeaturesEXT::decodeModeSharedExponent",
+            &mut self
+                .physical_device_astc_decode_features_ext
+                .decode_mode_shared_exponent,
+            other
+                .physical_device_astc_decode_features_ext
+                .decode_mode_shared_exponent,
+        );
+        f(
+            30usize,
+            "VkPhysicalDeviceTransformFeedbackFeaturesEXT::transformFeedback",
+            &mut self
+                .physical_device_transform_feedback_features_ext
+                .transform_feedback,
+            other
+                .physical_device_transform_feedback_features_ext
+                .transform_feedback,
+        );
+        f(
+            30usize,
+            "VkPhysicalDeviceTransformFeedbackFeaturesEXT::geometryStreams",
+            &mut self
+                .physical_device_transform_feedback_features_ext
+                .geometry_streams,
+            other
+                .physical_device_transform_feedback_features_ext
+                .geometry_streams,
+        );
+        f(
+            31usize,
+            "VkPhysicalDeviceRepresentativeFragmentTestFeaturesNV::representativeFragmentTest",
+            &mut self
+                .physical_device_representative_fragment_test_features_nv
+                .representative_fragment_test,
+            other
+                .physical_device_representative_fragment_test_features_nv
+                .representative_fragment_test,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceExclusiveScissorFeaturesNV::exclusiveScissor",
+            &mut self
+                .physical_device_exclusive_scissor_features_nv
+                .exclusive_scissor,
+            other
+                .physical_device_exclusive_scissor_features_nv
+                .exclusive_scissor,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceCornerSampledImageFeaturesNV::cornerSampledImage",
+            &mut self
+                .physical_device_corner_sampled_image_features_nv
+                .corner_sampled_image,
+            other
+                .physical_device_corner_sampled_image_features_nv
+                .corner_sampled_image,
+        );
+        f(
+            34usize,
+            "VkPhysicalDeviceComputeShaderDerivativesFeaturesNV::computeDerivativeGroupQuads",
+            &mut self
+                .physical_device_compute_shader_derivatives_features_nv
+                .compute_derivative_group_quads,
+            other
+                .physical_device_compute_shader_derivatives_features_nv
+                .compute_derivative_group_quads,
+        );
+        f(
+            34usize,
+            "VkPhysicalDeviceComputeShaderDerivativesFeaturesNV::computeDerivativeGroupLinear",
+            &mut self
+                .physical_device_compute_shader_derivatives_features_nv
+                .compute_derivative_group_linear,
+            other
+                .physical_device_compute_shader_derivatives_features_nv
+                .compute_derivative_group_linear,
+        );
+        f(
+            35usize,
+            "VkPhysicalDeviceShaderImageFootprintFeaturesNV::imageFootprint",
+            &mut self
+                .physical_device_shader_image_footprint_features_nv
+                .image_footprint,
+            other
+                .physical_device_shader_image_footprint_features_nv
+                .image_footprint,
+        );
+        f (36usize , "VkPhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV::dedicatedAllocationImageAliasing" , & mut self . physical_device_dedicated_allocation_image_aliasing_features_nv . dedicated_allocation_image_aliasing , other . physical_device_dedicated_allocation_image_aliasing_features_nv . dedicated_allocation_image_aliasing) ;
+        f(
+            37usize,
+            "VkPhysicalDeviceCopyMemoryIndirectFeaturesNV::indirectCopy",
+            &mut self
+                .physical_device_copy_memory_indirect_features_nv
+                .indirect_copy,
+            other
+                .physical_device_copy_memory_indirect_features_nv
+                .indirect_copy,
+        );
+        f(
+            38usize,
+            "VkPhysicalDeviceMemoryDecompressionFeaturesNV::memoryDecompression",
+            &mut self
+                .physical_device_memory_decompression_features_nv
+                .memory_decompression,
+            other
+                .physical_device_memory_decompression_features_nv
+                .memory_decompression,
+        );
+        f(
+            39usize,
+            "VkPhysicalDeviceShadingRateImageFeaturesNV::shadingRateImage",
+            &mut self
+                .physical_device_shading_rate_image_features_nv
+                .shading_rate_image,
+            other
+                .physical_device_shading_rate_image_features_nv
+                .shading_rate_image,
+        );
+        f(
+            39usize,
+            "VkPhysicalDeviceShadingRateImageFeaturesNV::shadingRateCoarseSampleOrder",
+            &mut self
+                .physical_device_shading_rate_image_features_nv
+                .shading_rate_coarse_sample_order,
+            other
+                .physical_device_shading_rate_image_features_nv
+                .shading_rate_coarse_sample_order,
+        );
+        f(
+            40usize,
+            "VkPhysicalDeviceInvocationMaskFeaturesHUAWEI::invocationMask",
+            &mut self
+                .physical_device_invocation_mask_features_huawei
+                .invocation_mask,
+            other
+                .physical_device_invocation_mask_features_huawei
+                .invocation_mask,
+        );
+        f(
+            41usize,
+            "VkPhysicalDeviceMeshShaderFeaturesNV::taskShader",
+            &mut self.physical_device_mesh_shader_features_nv.task_shader,
+            other.physical_device_mesh_shader_features_nv.task_shader,
+        );
+        f(
+            41usize,
+            "VkPhysicalDeviceMeshShaderFeaturesNV::meshShader",
+            &mut self.physical_device_mesh_shader_features_nv.mesh_shader,
+            other.physical_device_mesh_shader_features_nv.mesh_shader,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceMeshShaderFeaturesEXT::taskShader",
+            &mut self.physical_device_mesh_shader_features_ext.task_shader,
+            other.physical_device_mesh_shader_features_ext.task_shader,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceMeshShaderFeaturesEXT::meshShader",
+            &mut self.physical_device_mesh_shader_features_ext.mesh_shader,
+            other.physical_device_mesh_shader_features_ext.mesh_shader,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceMeshShaderFeaturesEXT::multiviewMeshShader",
+            &mut self
+                .physical_device_mesh_shader_features_ext
+                .multiview_mesh_shader,
+            other
+                .physical_device_mesh_shader_features_ext
+                .multiview_mesh_shader,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceMeshShaderFeaturesEXT::primitiveFragmentShadingRateMeshShader",
+            &mut self
+                .physical_device_mesh_shader_features_ext
+                .primitive_fragment_shading_rate_mesh_shader,
+            other
+                .physical_device_mesh_shader_features_ext
+                .primitive_fragment_shading_rate_mesh_shader,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceMeshShaderFeaturesEXT::meshShaderQueries",
+            &mut self
+                .physical_device_mesh_shader_features_ext
+                .mesh_shader_queries,
+            other
+                .physical_device_mesh_shader_features_ext
+                .mesh_shader_queries,
+        );
+        f(
+            43usize,
+            "VkPhysicalDeviceAccelerationStructureFeaturesKHR::accelerationStructure",
+            &mut self
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure,
+            other
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure,
+        );
+        f(
+            43usize,
+            "VkPhysicalDeviceAccelerationStructureFeaturesKHR::accelerationStructureCaptureReplay",
+            &mut self
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure_capture_replay,
+            other
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure_capture_replay,
+        );
+        f(
+            43usize,
+            "VkPhysicalDeviceAccelerationStructureFeaturesKHR::accelerationStructureIndirectBuild",
+            &mut self
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure_indirect_build,
+            other
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure_indirect_build,
+        );
+        f(
+            43usize,
+            "VkPhysicalDeviceAccelerationStructureFeaturesKHR::accelerationStructureHostCommands",
+            &mut self
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure_host_commands,
+            other
+                .physical_device_acceleration_structure_features_khr
+                .acceleration_structure_host_commands,
+        );
+        f (43usize , "VkPhysicalDeviceAccelerationStructureFeaturesKHR::descriptorBindingAccelerationStructureUpdateAfterBind" , & mut self . physical_device_acceleration_structure_features_khr . descriptor_binding_acceleration_structure_update_after_bind , other . physical_device_acceleration_structure_features_khr . descriptor_binding_acceleration_structure_update_after_bind) ;
+        f(
+            44usize,
+            "VkPhysicalDeviceRayTracingPipelineFeaturesKHR::rayTracingPipeline",
+            &mut self
+                .physical_device_ray_tracing_pipeline_features_khr
+                .ray_tracing_pipeline,
+            other
+                .physical_device_ray_tracing_pipeline_features_khr
+                .ray_tracing_pipeline,
+        );
+        f (44usize , "VkPhysicalDeviceRayTracingPipelineFeaturesKHR::rayTracingPipelineShaderGroupHandleCaptureReplay" , & mut self . physical_device_ray_tracing_pipeline_features_khr . ray_tracing_pipeline_shader_group_handle_capture_replay , other . physical_device_ray_tracing_pipeline_features_khr . ray_tracing_pipeline_shader_group_handle_capture_replay) ;
+        f (44usize , "VkPhysicalDeviceRayTracingPipelineFeaturesKHR::rayTracingPipelineShaderGroupHandleCaptureReplayMixed" , & mut self . physical_device_ray_tracing_pipeline_features_khr . ray_tracing_pipeline_shader_group_handle_capture_replay_mixed , other . physical_device_ray_tracing_pipeline_features_khr . ray_tracing_pipeline_shader_group_handle_capture_replay_mixed) ;
+        f(
+            44usize,
+            "VkPhysicalDeviceRayTracingPipelineFeaturesKHR::rayTracingPipelineTraceRaysIndirect",
+            &mut self
+                .physical_device_ray_tracing_pipeline_features_khr
+                .ray_tracing_pipeline_trace_rays_indirect,
+            other
+                .physical_device_ray_tracing_pipeline_features_khr
+                .ray_tracing_pipeline_trace_rays_indirect,
+        );
+        f(
+            44usize,
+            "VkPhysicalDeviceRayTracingPipelineFeaturesKHR::rayTraversalPrimitiveCulling",
+            &mut self
+                .physical_device_ray_tracing_pipeline_features_khr
+                .ray_traversal_primitive_culling,
+            other
+                .physical_device_ray_tracing_pipeline_features_khr
+                .ray_traversal_primitive_culling,
+        );
+        f(
+            45usize,
+            "VkPhysicalDeviceRayQueryFeaturesKHR::rayQuery",
+            &mut self.physical_device_ray_query_features_khr.ray_query,
+            other.physical_device_ray_query_features_khr.ray_query,
+        );
+        f(
+            46usize,
+            "VkPhysicalDeviceRayTracingMaintenance1FeaturesKHR::rayTracingMaintenance1",
+            &mut self
+                .physical_device_ray_tracing_maintenance1_features_khr
+                .ray_tracing_maintenance1,
+            other
+                .physical_device_ray_tracing_maintenance1_features_khr
+                .ray_tracing_maintenance1,
+        );
+        f (46usize , "VkPhysicalDeviceRayTracingMaintenance1FeaturesKHR::rayTracingPipelineTraceRaysIndirect2" , & mut self . physical_device_ray_tracing_maintenance1_features_khr . ray_tracing_pipeline_trace_rays_indirect2 , other . physical_device_ray_tracing_maintenance1_features_khr . ray_tracing_pipeline_trace_rays_indirect2) ;
+        f(
+            47usize,
+            "VkPhysicalDeviceFragmentDensityMapFeaturesEXT::fragmentDensityMap",
+            &mut self
+                .physical_device_fragment_density_map_features_ext
+                .fragment_density_map,
+            other
+                .physical_device_fragment_density_map_features_ext
+                .fragment_density_map,
+        );
+        f(
+            47usize,
+            "VkPhysicalDeviceFragmentDensityMapFeaturesEXT::fragmentDensityMapDynamic",
+            &mut self
+                .physical_device_fragment_density_map_features_ext
+                .fragment_density_map_dynamic,
+            other
+                .physical_device_fragment_density_map_features_ext
+                .fragment_density_map_dynamic,
+        );
+        f(
+            47usize,
+            "VkPhysicalDeviceFragmentDensityMapFeaturesEXT::fragmentDensityMapNonSubsampledImages",
+            &mut self
+                .physical_device_fragment_density_map_features_ext
+                .fragment_density_map_non_subsampled_images,
+            other
+                .physical_device_fragment_density_map_features_ext
+                .fragment_density_map_non_subsampled_images,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceFragmentDensityMap2FeaturesEXT::fragmentDensityMapDeferred",
+            &mut self
+                .physical_device_fragment_density_map2_features_ext
+                .fragment_density_map_deferred,
+            other
+                .physical_device_fragment_density_map2_features_ext
+                .fragment_density_map_deferred,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM::fragmentDensityMapOffset",
+            &mut self
+                .physical_device_fragment_density_map_offset_features_qcom
+                .fragment_density_map_offset,
+            other
+                .physical_device_fragment_density_map_offset_features_qcom
+                .fragment_density_map_offset,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceScalarBlockLayoutFeatures::scalarBlockLayout",
+            &mut self
+                .physical_device_scalar_block_layout_features
+                .scalar_block_layout,
+            other
+                .physical_device_scalar_block_layout_features
+                .scalar_block_layout,
+        );
+        f(
+            51usize,
+            "VkPhysicalDeviceUniformBufferStandardLayoutFeatures::uniformBufferStandardLayout",
+            &mut self
+                .physical_device_uniform_buffer_standard_layout_features
+                .uniform_buffer_standard_layout,
+            other
+                .physical_device_uniform_buffer_standard_layout_features
+                .uniform_buffer_standard_layout,
+        );
+        f(
+            52usize,
+            "VkPhysicalDeviceDepthClipEnableFeaturesEXT::depthClipEnable",
+            &mut self
+                .physical_device_depth_clip_enable_features_ext
+                .depth_clip_enable,
+            other
+                .physical_device_depth_clip_enable_features_ext
+                .depth_clip_enable,
+        );
+        f(
+            53usize,
+            "VkPhysicalDeviceMemoryPriorityFeaturesEXT::memoryPriority",
+            &mut self
+                .physical_device_memory_priority_features_ext
+                .memory_priority,
+            other
+                .physical_device_memory_priority_features_ext
+                .memory_priority,
+        );
+        f(
+            54usize,
+            "VkPhysicalDevicePageableDeviceLocalMemoryFeaturesEXT::pageableDeviceLocalMemory",
+            &mut self
+                .physical_device_pageable_device_local_memory_features_ext
+                .pageable_device_local_memory,
+            other
+                .physical_device_pageable_device_local_memory_features_ext
+                .pageable_device_local_memory,
+        );
+        f(
+            55usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeatures::bufferDeviceAddress",
+            &mut self
+                .physical_device_buffer_device_address_features
+                .buffer_device_address,
+            other
+                .physical_device_buffer_device_address_features
+                .buffer_device_address,
+        );
+        f(
+            55usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeatures::bufferDeviceAddressCaptureReplay",
+            &mut self
+                .physical_device_buffer_device_address_features
+                .buffer_device_address_capture_replay,
+            other
+                .physical_device_buffer_device_address_features
+                .buffer_device_address_capture_replay,
+        );
+        f(
+            55usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeatures::bufferDeviceAddressMultiDevice",
+            &mut self
+                .physical_device_buffer_device_address_features
+                .buffer_device_address_multi_device,
+            other
+                .physical_device_buffer_device_address_features
+                .buffer_device_address_multi_device,
+        );
+        f(
+            56usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT::bufferDeviceAddress",
+            &mut self
+                .physical_device_buffer_device_address_features_ext
+                .buffer_device_address,
+            other
+                .physical_device_buffer_device_address_features_ext
+                .buffer_device_address,
+        );
+        f(
+            56usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT::bufferDeviceAddressCaptureReplay",
+            &mut self
+                .physical_device_buffer_device_address_features_ext
+                .buffer_device_address_capture_replay,
+            other
+                .physical_device_buffer_device_address_features_ext
+                .buffer_device_address_capture_replay,
+        );
+        f(
+            56usize,
+            "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT::bufferDeviceAddressMultiDevice",
+            &mut self
+                .physical_device_buffer_device_address_features_ext
+                .buffer_device_address_multi_device,
+            other
+                .physical_device_buffer_device_address_features_ext
+                .buffer_device_address_multi_device,
+        );
+        f(
+            57usize,
+            "VkPhysicalDeviceImagelessFramebufferFeatures::imagelessFramebuffer",
+            &mut self
+                .physical_device_imageless_framebuffer_features
+                .imageless_framebuffer,
+            other
+                .physical_device_imageless_framebuffer_features
+                .imageless_framebuffer,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceTextureCompressionASTCHDRFeatures::textureCompressionASTC_HDR",
+            &mut self
+                .physical_device_texture_compression_astchdr_features
+                .texture_compression_astc_hdr,
+            other
+                .physical_device_texture_compression_astchdr_features
+                .texture_compression_astc_hdr,
+        );
+        f(
+            59usize,
+            "VkPhysicalDeviceCooperativeMatrixFeaturesNV::cooperativeMatrix",
+            &mut self
+                .physical_device_cooperative_matrix_features_nv
+                .cooperative_matrix,
+            other
+                .physical_device_cooperative_matrix_features_nv
+                .cooperative_matrix,
+        );
+        f(
+            59usize,
+            "VkPhysicalDeviceCooperativeMatrixFeaturesNV::cooperativeMatrixRobustBufferAccess",
+            &mut self
+                .physical_device_cooperative_matrix_features_nv
+                .cooperative_matrix_robust_buffer_access,
+            other
+                .physical_device_cooperative_matrix_features_nv
+                .cooperative_matrix_robust_buffer_access,
+        );
+        f(
+            60usize,
+            "VkPhysicalDeviceYcbcrImageArraysFeaturesEXT::ycbcrImageArrays",
+            &mut self
+                .physical_device_ycbcr_image_arrays_features_ext
+                .ycbcr_image_arrays,
+            other
+                .physical_device_ycbcr_image_arrays_features_ext
+                .ycbcr_image_arrays,
+        );
+        f(
+            61usize,
+            "VkPhysicalDevicePresentBarrierFeaturesNV::presentBarrier",
+            &mut self
+                .physical_device_present_barrier_features_nv
+                .present_barrier,
+            other
+                .physical_device_present_barrier_features_nv
+                .present_barrier,
+        );
+        f(
+            62usize,
+            "VkPhysicalDevicePerformanceQueryFeaturesKHR::performanceCounterQueryPools",
+            &mut self
+                .physical_device_performance_query_features_khr
+                .performance_counter_query_pools,
+            other
+                .physical_device_performance_query_features_khr
+                .performance_counter_query_pools,
+        );
+        f(
+            62usize,
+            "VkPhysicalDevicePerformanceQueryFeaturesKHR::performanceCounterMultipleQueryPools",
+            &mut self
+                .physical_device_performance_query_features_khr
+                .performance_counter_multiple_query_pools,
+            other
+                .physical_device_performance_query_features_khr
+                .performance_counter_multiple_query_pools,
+        );
+        f(
+            63usize,
+            "VkPhysicalDeviceCoverageReductionModeFeaturesNV::coverageReductionMode",
+            &mut self
+                .physical_device_coverage_reduction_mode_features_nv
+                .coverage_reduction_mode,
+            other
+                .physical_device_coverage_reduction_mode_features_nv
+                .coverage_reduction_mode,
+        );
+        f(
+            64usize,
+            "VkPhysicalDeviceShaderIntegerFunctions2FeaturesINTEL::shaderIntegerFunctions2",
+            &mut self
+                .physical_device_shader_integer_functions2_features_intel
+                .shader_integer_functions2,
+            other
+                .physical_device_shader_integer_functions2_features_intel
+                .shader_integer_functions2,
+        );
+        f(
+            65usize,
+            "VkPhysicalDeviceShaderClockFeaturesKHR::shaderSubgroupClock",
+            &mut self
+                .physical_device_shader_clock_features_khr
+                .shader_subgroup_clock,
+            other
+                .physical_device_shader_clock_features_khr
+                .shader_subgroup_clock,
+        );
+        f(
+            65usize,
+            "VkPhysicalDeviceShaderClockFeaturesKHR::shaderDeviceClock",
+            &mut self
+                .physical_device_shader_clock_features_khr
+                .shader_device_clock,
+            other
+                .physical_device_shader_clock_features_khr
+                .shader_device_clock,
+        );
+        f(
+            66usize,
+            "VkPhysicalDeviceIndexTypeUint8FeaturesEXT::indexTypeUint8",
+            &mut self
+                .physical_device_index_type_uint8_features_ext
+                .index_type_uint8,
+            other
+                .physical_device_index_type_uint8_features_ext
+                .index_type_uint8,
+        );
+        f(
+            67usize,
+            "VkPhysicalDeviceShaderSMBuiltinsFeaturesNV::shaderSMBuiltins",
+            &mut self
+                .physical_device_shader_sm_builtins_features_nv
+                .shader_sm_builtins,
+            other
+                .physical_device_shader_sm_builtins_features_nv
+                .shader_sm_builtins,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT::fragmentShaderSampleInterlock",
+            &mut self
+                .physical_device_fragment_shader_interlock_features_ext
+                .fragment_shader_sample_interlock,
+            other
+                .physical_device_fragment_shader_interlock_features_ext
+                .fragment_shader_sample_interlock,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT::fragmentShaderPixelInterlock",
+            &mut self
+                .physical_device_fragment_shader_interlock_features_ext
+                .fragment_shader_pixel_interlock,
+            other
+                .physical_device_fragment_shader_interlock_features_ext
+                .fragment_shader_pixel_interlock,
+        );
+        f (68usize , "VkPhysicalDeviceFragmentShaderInterlockFeaturesEXT::fragmentShaderShadingRateInterlock" , & mut self . physical_device_fragment_shader_interlock_features_ext . fragment_shader_shading_rate_interlock , other . physical_device_fragment_shader_interlock_features_ext . fragment_shader_shading_rate_interlock) ;
+        f(
+            69usize,
+            "VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures::separateDepthStencilLayouts",
+            &mut self
+                .physical_device_separate_depth_stencil_layouts_features
+                .separate_depth_stencil_layouts,
+            other
+                .physical_device_separate_depth_stencil_layouts_features
+                .separate_depth_stencil_layouts,
+        );
+        f(
+            70usize,
+            "VkPhysicalDevicePrimitiveTopologyListRestartFeaturesEXT::primitiveTopologyListRestart",
+            &mut self
+                .physical_device_primitive_topology_list_restart_features_ext
+                .primitive_topology_list_restart,
+            other
+                .physical_device_primitive_topology_list_restart_features_ext
+                .primitive_topology_list_restart,
+        );
+        f (70usize , "VkPhysicalDevicePrimitiveTopologyListRestartFeaturesEXT::primitiveTopologyPatchListRestart" , & mut self . physical_device_primitive_topology_list_restart_features_ext . primitive_topology_patch_list_restart , other . physical_device_primitive_topology_list_restart_features_ext . primitive_topology_patch_list_restart) ;
+        f(
+            71usize,
+            "VkPhysicalDevicePipelineExecutablePropertiesFeaturesKHR::pipelineExecutableInfo",
+            &mut self
+                .physical_device_pipeline_executable_properties_features_khr
+                .pipeline_executable_info,
+            other
+                .physical_device_pipeline_executable_properties_features_khr
+                .pipeline_executable_info,
+        );
+        f (72usize , "VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures::shaderDemoteToHelperInvocation" , & mut self . physical_device_shader_demote_to_helper_invocation_features . shader_demote_to_helper_invocation , other . physical_device_shader_demote_to_helper_invocation_features . shader_demote_to_helper_invocation) ;
+        f(
+            73usize,
+            "VkPhysicalDeviceTexelBufferAlignmentFeaturesEXT::texelBufferAlignment",
+            &mut self
+                .physical_device_texel_buffer_alignment_features_ext
+                .texel_buffer_alignment,
+            other
+                .physical_device_texel_buffer_alignment_features_ext
+                .texel_buffer_alignment,
+        );
+        f(
+            74usize,
+            "VkPhysicalDeviceSubgroupSizeControlFeatures::subgroupSizeControl",
+            &mut self
+                .physical_device_subgroup_size_control_features
+                .subgroup_size_control,
+            other
+                .physical_device_subgroup_size_control_features
+                .subgroup_size_control,
+        );
+        f(
+            74usize,
+            "VkPhysicalDeviceSubgroupSizeControlFeatures::computeFullSubgroups",
+            &mut self
+                .physical_device_subgroup_size_control_features
+                .compute_full_subgroups,
+            other
+                .physical_device_subgroup_size_control_features
+                .compute_full_subgroups,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::rectangularLines",
+            &mut self
+                .physical_device_line_rasterization_features_ext
+                .rectangular_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .rectangular_lines,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::bresenhamLines",
+            &mut self
+                .physical_device_line_rasterization_features_ext
+                .bresenham_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .bresenham_lines,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::smoothLines",
+            &mut self
+                .physical_device_line_rasterization_features_ext
+                .smooth_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .smooth_lines,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::stippledRectangularLines",
+            &mut self
+                .physical_device_line_rasterization_features_ext
+                .stippled_rectangular_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .stippled_rectangular_lines,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::stippledBresenhamLines",
+            &mut self
+                .physical_device_line_rasterization_features_ext
+                .stippled_bresenham_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .stippled_bresenham_lines,
+        );
+        f(
+            75usize,
+            "VkPhysicalDeviceLineRasterizationFeaturesEXT::stippledSmoothLines",
+            &mut self
+                .physical_device_line_rasterization_features_ext
+                .stippled_smooth_lines,
+            other
+                .physical_device_line_rasterization_features_ext
+                .stippled_smooth_lines,
+        );
+        f(
+            76usize,
+            "VkPhysicalDevicePipelineCreationCacheControlFeatures::pipelineCreationCacheControl",
+            &mut self
+                .physical_device_pipeline_creation_cache_control_features
+                .pipeline_creation_cache_control,
+            other
+                .physical_device_pipeline_creation_cache_control_features
+                .pipeline_creation_cache_control,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::storageBuffer16BitAccess",
+            &mut self
+                .physical_device_vulkan11_features
+                .storage_buffer16_bit_access,
+            other
+                .physical_device_vulkan11_features
+                .storage_buffer16_bit_access,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::uniformAndStorageBuffer16BitAccess",
+            &mut self
+                .physical_device_vulkan11_features
+                .uniform_and_storage_buffer16_bit_access,
+            other
+                .physical_device_vulkan11_features
+                .uniform_and_storage_buffer16_bit_access,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::storagePushConstant16",
+            &mut self
+                .physical_device_vulkan11_features
+                .storage_push_constant16,
+            other
+                .physical_device_vulkan11_features
+                .storage_push_constant16,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::storageInputOutput16",
+            &mut self
+                .physical_device_vulkan11_features
+                .storage_input_output16,
+            other
+                .physical_device_vulkan11_features
+                .storage_input_output16,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::multiview",
+            &mut self.physical_device_vulkan11_features.multiview,
+            other.physical_device_vulkan11_features.multiview,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::multiviewGeometryShader",
+            &mut self
+                .physical_device_vulkan11_features
+                .multiview_geometry_shader,
+            other
+                .physical_device_vulkan11_features
+                .multiview_geometry_shader,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::multiviewTessellationShader",
+            &mut self
+                .physical_device_vulkan11_features
+                .multiview_tessellation_shader,
+            other
+                .physical_device_vulkan11_features
+                .multiview_tessellation_shader,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::variablePointersStorageBuffer",
+            &mut self
+                .physical_device_vulkan11_features
+                .variable_pointers_storage_buffer,
+            other
+                .physical_device_vulkan11_features
+                .variable_pointers_storage_buffer,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::variablePointers",
+            &mut self.physical_device_vulkan11_features.variable_pointers,
+            other.physical_device_vulkan11_features.variable_pointers,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::protectedMemory",
+            &mut self.physical_device_vulkan11_features.protected_memory,
+            other.physical_device_vulkan11_features.protected_memory,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::samplerYcbcrConversion",
+            &mut self
+                .physical_device_vulkan11_features
+                .sampler_ycbcr_conversion,
+            other
+                .physical_device_vulkan11_features
+                .sampler_ycbcr_conversion,
+        );
+        f(
+            77usize,
+            "VkPhysicalDeviceVulkan11Features::shaderDrawParameters",
+            &mut self
+                .physical_device_vulkan11_features
+                .shader_draw_parameters,
+            other
+                .physical_device_vulkan11_features
+                .shader_draw_parameters,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::samplerMirrorClampToEdge",
+            &mut self
+                .physical_device_vulkan12_features
+                .sampler_mirror_clamp_to_edge,
+            other
+                .physical_device_vulkan12_features
+                .sampler_mirror_clamp_to_edge,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::drawIndirectCount",
+            &mut self.physical_device_vulkan12_features.draw_indirect_count,
+            other.physical_device_vulkan12_features.draw_indirect_count,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::storageBuffer8BitAccess",
+            &mut self
+                .physical_device_vulkan12_features
+                .storage_buffer8_bit_access,
+            other
+                .physical_device_vulkan12_features
+                .storage_buffer8_bit_access,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::uniformAndStorageBuffer8BitAccess",
+            &mut self
+                .physical_device_vulkan12_features
+                .uniform_and_storage_buffer8_bit_access,
+            other
+                .physical_device_vulkan12_features
+                .uniform_and_storage_buffer8_bit_access,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::storagePushConstant8",
+            &mut self
+                .physical_device_vulkan12_features
+                .storage_push_constant8,
+            other
+                .physical_device_vulkan12_features
+                .storage_push_constant8,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderBufferInt64Atomics",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_buffer_int64_atomics,
+            other
+                .physical_device_vulkan12_features
+                .shader_buffer_int64_atomics,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderSharedInt64Atomics",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_shared_int64_atomics,
+            other
+                .physical_device_vulkan12_features
+                .shader_shared_int64_atomics,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderFloat16",
+            &mut self.physical_device_vulkan12_features.shader_float16,
+            other.physical_device_vulkan12_features.shader_float16,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderInt8",
+            &mut self.physical_device_vulkan12_features.shader_int8,
+            other.physical_device_vulkan12_features.shader_int8,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorIndexing",
+            &mut self.physical_device_vulkan12_features.descriptor_indexing,
+            other.physical_device_vulkan12_features.descriptor_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderInputAttachmentArrayDynamicIndexing",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_input_attachment_array_dynamic_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_input_attachment_array_dynamic_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderUniformTexelBufferArrayDynamicIndexing",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_uniform_texel_buffer_array_dynamic_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_uniform_texel_buffer_array_dynamic_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderStorageTexelBufferArrayDynamicIndexing",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_storage_texel_buffer_array_dynamic_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_storage_texel_buffer_array_dynamic_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderUniformBufferArrayNonUniformIndexing",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_uniform_buffer_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_uniform_buffer_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderSampledImageArrayNonUniformIndexing",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_sampled_image_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_sampled_image_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderStorageBufferArrayNonUniformIndexing",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_storage_buffer_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_storage_buffer_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderStorageImageArrayNonUniformIndexing",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_storage_image_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_storage_image_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderInputAttachmentArrayNonUniformIndexing",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_input_attachment_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_input_attachment_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderUniformTexelBufferArrayNonUniformIndexing",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_uniform_texel_buffer_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_uniform_texel_buffer_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderStorageTexelBufferArrayNonUniformIndexing",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_storage_texel_buffer_array_non_uniform_indexing,
+            other
+                .physical_device_vulkan12_features
+                .shader_storage_texel_buffer_array_non_uniform_indexing,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingUniformBufferUpdateAfterBind",
+            &mut self
+                .physical_device_vulkan12_features
+                .descriptor_binding_uniform_buffer_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_uniform_buffer_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingSampledImageUpdateAfterBind",
+            &mut self
+                .physical_device_vulkan12_features
+                .descriptor_binding_sampled_image_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_sampled_image_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingStorageImageUpdateAfterBind",
+            &mut self
+                .physical_device_vulkan12_features
+                .descriptor_binding_storage_image_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_storage_image_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingStorageBufferUpdateAfterBind",
+            &mut self
+                .physical_device_vulkan12_features
+                .descriptor_binding_storage_buffer_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_storage_buffer_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingUniformTexelBufferUpdateAfterBind",
+            &mut self
+                .physical_device_vulkan12_features
+                .descriptor_binding_uniform_texel_buffer_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_uniform_texel_buffer_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingStorageTexelBufferUpdateAfterBind",
+            &mut self
+                .physical_device_vulkan12_features
+                .descriptor_binding_storage_texel_buffer_update_after_bind,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_storage_texel_buffer_update_after_bind,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingUpdateUnusedWhilePending",
+            &mut self
+                .physical_device_vulkan12_features
+                .descriptor_binding_update_unused_while_pending,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_update_unused_while_pending,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingPartiallyBound",
+            &mut self
+                .physical_device_vulkan12_features
+                .descriptor_binding_partially_bound,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_partially_bound,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::descriptorBindingVariableDescriptorCount",
+            &mut self
+                .physical_device_vulkan12_features
+                .descriptor_binding_variable_descriptor_count,
+            other
+                .physical_device_vulkan12_features
+                .descriptor_binding_variable_descriptor_count,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::runtimeDescriptorArray",
+            &mut self
+                .physical_device_vulkan12_features
+                .runtime_descriptor_array,
+            other
+                .physical_device_vulkan12_features
+                .runtime_descriptor_array,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::samplerFilterMinmax",
+            &mut self.physical_device_vulkan12_features.sampler_filter_minmax,
+            other
+                .physical_device_vulkan12_features
+                .sampler_filter_minmax,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::scalarBlockLayout",
+            &mut self.physical_device_vulkan12_features.scalar_block_layout,
+            other.physical_device_vulkan12_features.scalar_block_layout,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::imagelessFramebuffer",
+            &mut self.physical_device_vulkan12_features.imageless_framebuffer,
+            other
+                .physical_device_vulkan12_features
+                .imageless_framebuffer,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::uniformBufferStandardLayout",
+            &mut self
+                .physical_device_vulkan12_features
+                .uniform_buffer_standard_layout,
+            other
+                .physical_device_vulkan12_features
+                .uniform_buffer_standard_layout,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderSubgroupExtendedTypes",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_subgroup_extended_types,
+            other
+                .physical_device_vulkan12_features
+                .shader_subgroup_extended_types,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::separateDepthStencilLayouts",
+            &mut self
+                .physical_device_vulkan12_features
+                .separate_depth_stencil_layouts,
+            other
+                .physical_device_vulkan12_features
+                .separate_depth_stencil_layouts,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::hostQueryReset",
+            &mut self.physical_device_vulkan12_features.host_query_reset,
+            other.physical_device_vulkan12_features.host_query_reset,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::timelineSemaphore",
+            &mut self.physical_device_vulkan12_features.timeline_semaphore,
+            other.physical_device_vulkan12_features.timeline_semaphore,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::bufferDeviceAddress",
+            &mut self.physical_device_vulkan12_features.buffer_device_address,
+            other
+                .physical_device_vulkan12_features
+                .buffer_device_address,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::bufferDeviceAddressCaptureReplay",
+            &mut self
+                .physical_device_vulkan12_features
+                .buffer_device_address_capture_replay,
+            other
+                .physical_device_vulkan12_features
+                .buffer_device_address_capture_replay,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::bufferDeviceAddressMultiDevice",
+            &mut self
+                .physical_device_vulkan12_features
+                .buffer_device_address_multi_device,
+            other
+                .physical_device_vulkan12_features
+                .buffer_device_address_multi_device,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::vulkanMemoryModel",
+            &mut self.physical_device_vulkan12_features.vulkan_memory_model,
+            other.physical_device_vulkan12_features.vulkan_memory_model,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::vulkanMemoryModelDeviceScope",
+            &mut self
+                .physical_device_vulkan12_features
+                .vulkan_memory_model_device_scope,
+            other
+                .physical_device_vulkan12_features
+                .vulkan_memory_model_device_scope,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::vulkanMemoryModelAvailabilityVisibilityChains",
+            &mut self
+                .physical_device_vulkan12_features
+                .vulkan_memory_model_availability_visibility_chains,
+            other
+                .physical_device_vulkan12_features
+                .vulkan_memory_model_availability_visibility_chains,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderOutputViewportIndex",
+            &mut self
+                .physical_device_vulkan12_features
+                .shader_output_viewport_index,
+            other
+                .physical_device_vulkan12_features
+                .shader_output_viewport_index,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::shaderOutputLayer",
+            &mut self.physical_device_vulkan12_features.shader_output_layer,
+            other.physical_device_vulkan12_features.shader_output_layer,
+        );
+        f(
+            78usize,
+            "VkPhysicalDeviceVulkan12Features::subgroupBroadcastDynamicId",
+            &mut self
+                .physical_device_vulkan12_features
+                .subgroup_broadcast_dynamic_id,
+            other
+                .physical_device_vulkan12_features
+                .subgroup_broadcast_dynamic_id,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::robustImageAccess",
+            &mut self.physical_device_vulkan13_features.robust_image_access,
+            other.physical_device_vulkan13_features.robust_image_access,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::inlineUniformBlock",
+            &mut self.physical_device_vulkan13_features.inline_uniform_block,
+            other.physical_device_vulkan13_features.inline_uniform_block,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::descriptorBindingInlineUniformBlockUpdateAfterBind",
+            &mut self
+                .physical_device_vulkan13_features
+                .descriptor_binding_inline_uniform_block_update_after_bind,
+            other
+                .physical_device_vulkan13_features
+                .descriptor_binding_inline_uniform_block_update_after_bind,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::pipelineCreationCacheControl",
+            &mut self
+                .physical_device_vulkan13_features
+                .pipeline_creation_cache_control,
+            other
+                .physical_device_vulkan13_features
+                .pipeline_creation_cache_control,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::privateData",
+            &mut self.physical_device_vulkan13_features.private_data,
+            other.physical_device_vulkan13_features.private_data,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::shaderDemoteToHelperInvocation",
+            &mut self
+                .physical_device_vulkan13_features
+                .shader_demote_to_helper_invocation,
+            other
+                .physical_device_vulkan13_features
+                .shader_demote_to_helper_invocation,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::shaderTerminateInvocation",
+            &mut self
+                .physical_device_vulkan13_features
+                .shader_terminate_invocation,
+            other
+                .physical_device_vulkan13_features
+                .shader_terminate_invocation,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::subgroupSizeControl",
+            &mut self.physical_device_vulkan13_features.subgroup_size_control,
+            other
+                .physical_device_vulkan13_features
+                .subgroup_size_control,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::computeFullSubgroups",
+            &mut self
+                .physical_device_vulkan13_features
+                .compute_full_subgroups,
+            other
+                .physical_device_vulkan13_features
+                .compute_full_subgroups,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::synchronization2",
+            &mut self.physical_device_vulkan13_features.synchronization2,
+            other.physical_device_vulkan13_features.synchronization2,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::textureCompressionASTC_HDR",
+            &mut self
+                .physical_device_vulkan13_features
+                .texture_compression_astc_hdr,
+            other
+                .physical_device_vulkan13_features
+                .texture_compression_astc_hdr,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::shaderZeroInitializeWorkgroupMemory",
+            &mut self
+                .physical_device_vulkan13_features
+                .shader_zero_initialize_workgroup_memory,
+            other
+                .physical_device_vulkan13_features
+                .shader_zero_initialize_workgroup_memory,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::dynamicRendering",
+            &mut self.physical_device_vulkan13_features.dynamic_rendering,
+            other.physical_device_vulkan13_features.dynamic_rendering,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::shaderIntegerDotProduct",
+            &mut self
+                .physical_device_vulkan13_features
+                .shader_integer_dot_product,
+            other
+                .physical_device_vulkan13_features
+                .shader_integer_dot_product,
+        );
+        f(
+            79usize,
+            "VkPhysicalDeviceVulkan13Features::maintenance4",
+            &mut self.physical_device_vulkan13_features.maintenance4,
+            other.physical_device_vulkan13_features.maintenance4,
+        );
+        f(
+            80usize,
+            "VkPhysicalDeviceCoherentMemoryFeaturesAMD::deviceCoherentMemory",
+            &mut self
+                .physical_device_coherent_memory_features_amd
+                .device_coherent_memory,
+            other
+                .physical_device_coherent_memory_features_amd
+                .device_coherent_memory,
+        );
+        f(
+            81usize,
+            "VkPhysicalDeviceCustomBorderColorFeaturesEXT::customBorderColors",
+            &mut self
+                .physical_device_custom_border_color_features_ext
+                .custom_border_colors,
+            other
+                .physical_device_custom_border_color_features_ext
+                .custom_border_colors,
+        );
+        f(
+            81usize,
+            "VkPhysicalDeviceCustomBorderColorFeaturesEXT::customBorderColorWithoutFormat",
+            &mut self
+                .physical_device_custom_border_color_features_ext
+                .custom_border_color_without_format,
+            other
+                .physical_device_custom_border_color_features_ext
+                .custom_border_color_without_format,
+        );
+        f(
+            82usize,
+            "VkPhysicalDeviceBorderColorSwizzleFeaturesEXT::borderColorSwizzle",
+            &mut self
+                .physical_device_border_color_swizzle_features_ext
+                .border_color_swizzle,
+            other
+                .physical_device_border_color_swizzle_features_ext
+                .border_color_swizzle,
+        );
+        f(
+            82usize,
+            "VkPhysicalDeviceBorderColorSwizzleFeaturesEXT::borderColorSwizzleFromImage",
+            &mut self
+                .physical_device_border_color_swizzle_features_ext
+                .border_color_swizzle_from_image,
+            other
+                .physical_device_border_color_swizzle_features_ext
+                .border_color_swizzle_from_image,
+        );
+        f(
+            83usize,
+            "VkPhysicalDeviceExtendedDynamicStateFeaturesEXT::extendedDynamicState",
+            &mut self
+                .physical_device_extended_dynamic_state_features_ext
+                .extended_dynamic_state,
+            other
+                .physical_device_extended_dynamic_state_features_ext
+                .extended_dynamic_state,
+        );
+        f(
+            84usize,
+            "VkPhysicalDeviceExtendedDynamicState2FeaturesEXT::extendedDynamicState2",
+            &mut self
+                .physical_device_extended_dynamic_state2_features_ext
+                .extended_dynamic_state2,
+            other
+                .physical_device_extended_dynamic_state2_features_ext
+                .extended_dynamic_state2,
+        );
+        f(
+            84usize,
+            "VkPhysicalDeviceExtendedDynamicState2FeaturesEXT::extendedDynamicState2LogicOp",
+            &mut self
+                .physical_device_extended_dynamic_state2_features_ext
+                .extended_dynamic_state2_logic_op,
+            other
+                .physical_device_extended_dynamic_state2_features_ext
+                .extended_dynamic_state2_logic_op,
+        );
+        f (84usize , "VkPhysicalDeviceExtendedDynamicState2FeaturesEXT::extendedDynamicState2PatchControlPoints" , & mut self . physical_device_extended_dynamic_state2_features_ext . extended_dynamic_state2_patch_control_points , other . physical_device_extended_dynamic_state2_features_ext . extended_dynamic_state2_patch_control_points) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3TessellationDomainOrigin" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_tessellation_domain_origin , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_tessellation_domain_origin) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3DepthClampEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clamp_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clamp_enable) ;
+        f(
+            85usize,
+            "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3PolygonMode",
+            &mut self
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_polygon_mode,
+            other
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_polygon_mode,
+        );
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3RasterizationSamples" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_rasterization_samples , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_rasterization_samples) ;
+        f(
+            85usize,
+            "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3SampleMask",
+            &mut self
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_sample_mask,
+            other
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_sample_mask,
+        );
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3AlphaToCoverageEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_alpha_to_coverage_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_alpha_to_coverage_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3AlphaToOneEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_alpha_to_one_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_alpha_to_one_enable) ;
+        f(
+            85usize,
+            "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3LogicOpEnable",
+            &mut self
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_logic_op_enable,
+            other
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_logic_op_enable,
+        );
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ColorBlendEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ColorBlendEquation" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_equation , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_equation) ;
+        f(
+            85usize,
+            "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ColorWriteMask",
+            &mut self
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_color_write_mask,
+            other
+                .physical_device_extended_dynamic_state3_features_ext
+                .extended_dynamic_state3_color_write_mask,
+        );
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3RasterizationStream" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_rasterization_stream , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_rasterization_stream) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ConservativeRasterizationMode" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_conservative_rasterization_mode , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_conservative_rasterization_mode) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ExtraPrimitiveOverestimationSize" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_extra_primitive_overestimation_size , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_extra_primitive_overestimation_size) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3DepthClipEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clip_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clip_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3SampleLocationsEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_sample_locations_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_sample_locations_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ColorBlendAdvanced" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_advanced , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_color_blend_advanced) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ProvokingVertexMode" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_provoking_vertex_mode , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_provoking_vertex_mode) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3LineRasterizationMode" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_line_rasterization_mode , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_line_rasterization_mode) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3LineStippleEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_line_stipple_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_line_stipple_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3DepthClipNegativeOneToOne" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clip_negative_one_to_one , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_depth_clip_negative_one_to_one) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ViewportWScalingEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_viewport_w_scaling_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_viewport_w_scaling_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ViewportSwizzle" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_viewport_swizzle , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_viewport_swizzle) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageToColorEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_to_color_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_to_color_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageToColorLocation" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_to_color_location , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_to_color_location) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageModulationMode" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_mode , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_mode) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageModulationTableEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_table_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_table_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageModulationTable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_table , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_modulation_table) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3CoverageReductionMode" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_reduction_mode , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_coverage_reduction_mode) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3RepresentativeFragmentTestEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_representative_fragment_test_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_representative_fragment_test_enable) ;
+        f (85usize , "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT::extendedDynamicState3ShadingRateImageEnable" , & mut self . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_shading_rate_image_enable , other . physical_device_extended_dynamic_state3_features_ext . extended_dynamic_state3_shading_rate_image_enable) ;
+        f(
+            86usize,
+            "VkPhysicalDeviceDiagnosticsConfigFeaturesNV::diagnosticsConfig",
+            &mut self
+                .physical_device_diagnostics_config_features_nv
+                .diagnostics_config,
+            other
+                .physical_device_diagnostics_config_features_nv
+                .diagnostics_config,
+        );
+        f (87usize , "VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures::shaderZeroInitializeWorkgroupMemory" , & mut self . physical_device_zero_initialize_workgroup_memory_features . shader_zero_initialize_workgroup_memory , other . physical_device_zero_initialize_workgroup_memory_features . shader_zero_initialize_workgroup_memory) ;
+        f (88usize , "VkPhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR::shaderSubgroupUniformControlFlow" , & mut self . physical_device_shader_subgroup_uniform_control_flow_features_khr . shader_subgroup_uniform_control_flow , other . physical_device_shader_subgroup_uniform_control_flow_features_khr . shader_subgroup_uniform_control_flow) ;
+        f(
+            89usize,
+            "VkPhysicalDeviceRobustness2FeaturesEXT::robustBufferAccess2",
+            &mut self
+                .physical_device_robustness2_features_ext
+                .robust_buffer_access2,
+            other
+                .physical_device_robustness2_features_ext
+                .robust_buffer_access2,
+        );
+        f(
+            89usize,
+            "VkPhysicalDeviceRobustness2FeaturesEXT::robustImageAccess2",
+            &mut self
+                .physical_device_robustness2_features_ext
+                .robust_image_access2,
+            other
+                .physical_device_robustness2_features_ext
+                .robust_image_access2,
+        );
+        f(
+            89usize,
+            "VkPhysicalDeviceRobustness2FeaturesEXT::nullDescriptor",
+            &mut self
+                .physical_device_robustness2_features_ext
+                .null_descriptor,
+            other
+                .physical_device_robustness2_features_ext
+                .null_descriptor,
+        );
+        f(
+            90usize,
+            "VkPhysicalDeviceImageRobustnessFeatures::robustImageAccess",
+            &mut self
+                .physical_device_image_robustness_features
+                .robust_image_access,
+            other
+                .physical_device_image_robustness_features
+                .robust_image_access,
+        );
+        f (91usize , "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::workgroupMemoryExplicitLayout" , & mut self . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout , other . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout) ;
+        f (91usize , "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::workgroupMemoryExplicitLayoutScalarBlockLayout" , & mut self . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout_scalar_block_layout , other . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout_scalar_block_layout) ;
+        f (91usize , "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::workgroupMemoryExplicitLayout8BitAccess" , & mut self . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout8_bit_access , other . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout8_bit_access) ;
+        f (91usize , "VkPhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR::workgroupMemoryExplicitLayout16BitAccess" , & mut self . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout16_bit_access , other . physical_device_workgroup_memory_explicit_layout_features_khr . workgroup_memory_explicit_layout16_bit_access) ;
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::constantAlphaColorBlendFactors",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .constant_alpha_color_blend_factors,
+            other
+                .physical_device_portability_subset_features_khr
+                .constant_alpha_color_blend_factors,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::events",
+            &mut self.physical_device_portability_subset_features_khr.events,
+            other.physical_device_portability_subset_features_khr.events,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::imageViewFormatReinterpretation",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .image_view_format_reinterpretation,
+            other
+                .physical_device_portability_subset_features_khr
+                .image_view_format_reinterpretation,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::imageViewFormatSwizzle",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .image_view_format_swizzle,
+            other
+                .physical_device_portability_subset_features_khr
+                .image_view_format_swizzle,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::imageView2DOn3DImage",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .image_view2_d_on3_d_image,
+            other
+                .physical_device_portability_subset_features_khr
+                .image_view2_d_on3_d_image,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::multisampleArrayImage",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .multisample_array_image,
+            other
+                .physical_device_portability_subset_features_khr
+                .multisample_array_image,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::mutableComparisonSamplers",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .mutable_comparison_samplers,
+            other
+                .physical_device_portability_subset_features_khr
+                .mutable_comparison_samplers,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::pointPolygons",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .point_polygons,
+            other
+                .physical_device_portability_subset_features_khr
+                .point_polygons,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::samplerMipLodBias",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .sampler_mip_lod_bias,
+            other
+                .physical_device_portability_subset_features_khr
+                .sampler_mip_lod_bias,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::separateStencilMaskRef",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .separate_stencil_mask_ref,
+            other
+                .physical_device_portability_subset_features_khr
+                .separate_stencil_mask_ref,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::shaderSampleRateInterpolationFunctions",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .shader_sample_rate_interpolation_functions,
+            other
+                .physical_device_portability_subset_features_khr
+                .shader_sample_rate_interpolation_functions,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::tessellationIsolines",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .tessellation_isolines,
+            other
+                .physical_device_portability_subset_features_khr
+                .tessellation_isolines,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::tessellationPointMode",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .tessellation_point_mode,
+            other
+                .physical_device_portability_subset_features_khr
+                .tessellation_point_mode,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::triangleFans",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .triangle_fans,
+            other
+                .physical_device_portability_subset_features_khr
+                .triangle_fans,
+        );
+        f(
+            92usize,
+            "VkPhysicalDevicePortabilitySubsetFeaturesKHR::vertexAttributeAccessBeyondStride",
+            &mut self
+                .physical_device_portability_subset_features_khr
+                .vertex_attribute_access_beyond_stride,
+            other
+                .physical_device_portability_subset_features_khr
+                .vertex_attribute_access_beyond_stride,
+        );
+        f(
+            93usize,
+            "VkPhysicalDevice4444FormatsFeaturesEXT::formatA4R4G4B4",
+            &mut self
+                .physical_device4444_formats_features_ext
+                .format_a4r4g4b4,
+            other
+                .physical_device4444_formats_features_ext
+                .format_a4r4g4b4,
+        );
+        f(
+            93usize,
+            "VkPhysicalDevice4444FormatsFeaturesEXT::formatA4B4G4R4",
+            &mut self
+                .physical_device4444_formats_features_ext
+                .format_a4b4g4r4,
+            other
+                .physical_device4444_formats_features_ext
+                .format_a4b4g4r4,
+        );
+        f(
+            94usize,
+            "VkPhysicalDeviceSubpassShadingFeaturesHUAWEI::subpassShading",
+            &mut self
+                .physical_device_subpass_shading_features_huawei
+                .subpass_shading,
+            other
+                .physical_device_subpass_shading_features_huawei
+                .subpass_shading,
+        );
+        f(
+            95usize,
+            "VkPhysicalDeviceClusterCullingShaderFeaturesHUAWEI::clustercullingShader",
+            &mut self
+                .physical_device_cluster_culling_shader_features_huawei
+                .clusterculling_shader,
+            other
+                .physical_device_cluster_culling_shader_features_huawei
+                .clusterculling_shader,
+        );
+        f(
+            95usize,
+            "VkPhysicalDeviceClusterCullingShaderFeaturesHUAWEI::multiviewClusterCullingShader",
+            &mut self
+                .physical_device_cluster_culling_shader_features_huawei
+                .multiview_cluster_culling_shader,
+            other
+                .physical_device_cluster_culling_shader_features_huawei
+                .multiview_cluster_culling_shader,
+        );
+        f(
+            96usize,
+            "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT::shaderImageInt64Atomics",
+            &mut self
+                .physical_device_shader_image_atomic_int64_features_ext
+                .shader_image_int64_atomics,
+            other
+                .physical_device_shader_image_atomic_int64_features_ext
+                .shader_image_int64_atomics,
+        );
+        f(
+            96usize,
+            "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT::sparseImageInt64Atomics",
+            &mut self
+                .physical_device_shader_image_atomic_int64_features_ext
+                .sparse_image_int64_atomics,
+            other
+                .physical_device_shader_image_atomic_int64_features_ext
+                .sparse_image_int64_atomics,
+        );
+        f(
+            97usize,
+            "VkPhysicalDeviceFragmentShadingRateFeaturesKHR::pipelineFragmentShadingRate",
+            &mut self
+                .physical_device_fragment_shading_rate_features_khr
+                .pipeline_fragment_shading_rate,
+            other
+                .physical_device_fragment_shading_rate_features_khr
+                .pipeline_fragment_shading_rate,
+        );
+        f(
+            97usize,
+            "VkPhysicalDeviceFragmentShadingRateFeaturesKHR::primitiveFragmentShadingRate",
+            &mut self
+                .physical_device_fragment_shading_rate_features_khr
+                .primitive_fragment_shading_rate,
+            other
+                .physical_device_fragment_shading_rate_features_khr
+                .primitive_fragment_shading_rate,
+        );
+        f(
+            97usize,
+            "VkPhysicalDeviceFragmentShadingRateFeaturesKHR::attachmentFragmentShadingRate",
+            &mut self
+                .physical_device_fragment_shading_rate_features_khr
+                .attachment_fragment_shading_rate,
+            other
+                .physical_device_fragment_shading_rate_features_khr
+                .attachment_fragment_shading_rate,
+        );
+        f(
+            98usize,
+            "VkPhysicalDeviceShaderTerminateInvocationFeatures::shaderTerminateInvocation",
+            &mut self
+                .physical_device_shader_terminate_invocation_features
+                .shader_terminate_invocation,
+            other
+                .physical_device_shader_terminate_invocation_features
+                .shader_terminate_invocation,
+        );
+        f(
+            99usize,
+            "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV::fragmentShadingRateEnums",
+            &mut self
+                .physical_device_fragment_shading_rate_enums_features_nv
+                .fragment_shading_rate_enums,
+            other
+                .physical_device_fragment_shading_rate_enums_features_nv
+                .fragment_shading_rate_enums,
+        );
+        f(
+            99usize,
+            "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV::supersampleFragmentShadingRates",
+            &mut self
+                .physical_device_fragment_shading_rate_enums_features_nv
+                .supersample_fragment_shading_rates,
+            other
+                .physical_device_fragment_shading_rate_enums_features_nv
+                .supersample_fragment_shading_rates,
+        );
+        f(
+            99usize,
+            "VkPhysicalDeviceFragmentShadingRateEnumsFeaturesNV::noInvocationFragmentShadingRates",
+            &mut self
+                .physical_device_fragment_shading_rate_enums_features_nv
+                .no_invocation_fragment_shading_rates,
+            other
+                .physical_device_fragment_shading_rate_enums_features_nv
+                .no_invocation_fragment_shading_rates,
+        );
+        f(
+            100usize,
+            "VkPhysicalDeviceImage2DViewOf3DFeaturesEXT::image2DViewOf3D",
+            &mut self
+                .physical_device_image2_d_view_of3_d_features_ext
+                .image2_d_view_of3_d,
+            other
+                .physical_device_image2_d_view_of3_d_features_ext
+                .image2_d_view_of3_d,
+        );
+        f(
+            100usize,
+            "VkPhysicalDeviceImage2DViewOf3DFeaturesEXT::sampler2DViewOf3D",
+            &mut self
+                .physical_device_image2_d_view_of3_d_features_ext
+                .sampler2_d_view_of3_d,
+            other
+                .physical_device_image2_d_view_of3_d_features_ext
+                .sampler2_d_view_of3_d,
+        );
+        f(
+            101usize,
+            "VkPhysicalDeviceImageSlicedViewOf3DFeaturesEXT::imageSlicedViewOf3D",
+            &mut self
+                .physical_device_image_sliced_view_of3_d_features_ext
+                .image_sliced_view_of3_d,
+            other
+                .physical_device_image_sliced_view_of3_d_features_ext
+                .image_sliced_view_of3_d,
+        );
+        f (102usize , "VkPhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT::attachmentFeedbackLoopDynamicState" , & mut self . physical_device_attachment_feedback_loop_dynamic_state_features_ext . attachment_feedback_loop_dynamic_state , other . physical_device_attachment_feedback_loop_dynamic_state_features_ext . attachment_feedback_loop_dynamic_state) ;
+        f(
+            103usize,
+            "VkPhysicalDeviceMutableDescriptorTypeFeaturesEXT::mutableDescriptorType",
+            &mut self
+                .physical_device_mutable_descriptor_type_features_ext
+                .mutable_descriptor_type,
+            other
+                .physical_device_mutable_descriptor_type_features_ext
+                .mutable_descriptor_type,
+        );
+        f(
+            104usize,
+            "VkPhysicalDeviceDepthClipControlFeaturesEXT::depthClipControl",
+            &mut self
+                .physical_device_depth_clip_control_features_ext
+                .depth_clip_control,
+            other
+                .physical_device_depth_clip_control_features_ext
+                .depth_clip_control,
+        );
+        f(
+            105usize,
+            "VkPhysicalDeviceVertexInputDynamicStateFeaturesEXT::vertexInputDynamicState",
+            &mut self
+                .physical_device_vertex_input_dynamic_state_features_ext
+                .vertex_input_dynamic_state,
+            other
+                .physical_device_vertex_input_dynamic_state_features_ext
+                .vertex_input_dynamic_state,
+        );
+        f(
+            106usize,
+            "VkPhysicalDeviceExternalMemoryRDMAFeaturesNV::externalMemoryRDMA",
+            &mut self
+                .physical_device_external_memory_rdma_features_nv
+                .external_memory_rdma,
+            other
+                .physical_device_external_memory_rdma_features_nv
+                .external_memory_rdma,
+        );
+        f(
+            107usize,
+            "VkPhysicalDeviceColorWriteEnableFeaturesEXT::colorWriteEnable",
+            &mut self
+                .physical_device_color_write_enable_features_ext
+                .color_write_enable,
+            other
+                .physical_device_color_write_enable_features_ext
+                .color_write_enable,
+        );
+        f(
+            108usize,
+            "VkPhysicalDeviceSynchronization2Features::synchronization2",
+            &mut self
+                .physical_device_synchronization2_features
+                .synchronization2,
+            other
+                .physical_device_synchronization2_features
+                .synchronization2,
+        );
+        f(
+            109usize,
+            "VkPhysicalDevicePrimitivesGeneratedQueryFeaturesEXT::primitivesGeneratedQuery",
+            &mut self
+                .physical_device_primitives_generated_query_features_ext
+                .primitives_generated_query,
+            other
+                .physical_device_primitives_generated_query_features_ext
+                .primitives_generated_query,
+        );
+        f (109usize , "VkPhysicalDevicePrimitivesGeneratedQueryFeaturesEXT::primitivesGeneratedQueryWithRasterizerDiscard" , & mut self . physical_device_primitives_generated_query_features_ext . primitives_generated_query_with_rasterizer_discard , other . physical_device_primitives_generated_query_features_ext . primitives_generated_query_with_rasterizer_discard) ;
+        f (109usize , "VkPhysicalDevicePrimitivesGeneratedQueryFeaturesEXT::primitivesGeneratedQueryWithNonZeroStreams" , & mut self . physical_device_primitives_generated_query_features_ext . primitives_generated_query_with_non_zero_streams , other . physical_device_primitives_generated_query_features_ext . primitives_generated_query_with_non_zero_streams) ;
+        f(
+            110usize,
+            "VkPhysicalDeviceLegacyDitheringFeaturesEXT::legacyDithering",
+            &mut self
+                .physical_device_legacy_dithering_features_ext
+                .legacy_dithering,
+            other
+                .physical_device_legacy_dithering_features_ext
+                .legacy_dithering,
+        );
+        f (111usize , "VkPhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT::multisampledRenderToSingleSampled" , & mut self . physical_device_multisampled_render_to_single_sampled_features_ext . multisampled_render_to_single_sampled , other . physical_device_multisampled_render_to_single_sampled_features_ext . multisampled_render_to_single_sampled) ;
+        f(
+            112usize,
+            "VkPhysicalDevicePipelineProtectedAccessFeaturesEXT::pipelineProtectedAccess",
+            &mut self
+                .physical_device_pipeline_protected_access_features_ext
+                .pipeline_protected_access,
+            other
+                .physical_device_pipeline_protected_access_features_ext
+                .pipeline_protected_access,
+        );
+        f(
+            113usize,
+            "VkPhysicalDeviceInheritedViewportScissorFeaturesNV::inheritedViewportScissor2D",
+            &mut self
+                .physical_device_inherited_viewport_scissor_features_nv
+                .inherited_viewport_scissor2_d,
+            other
+                .physical_device_inherited_viewport_scissor_features_nv
+                .inherited_viewport_scissor2_d,
+        );
+        f(
+            114usize,
+            "VkPhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT::ycbcr2plane444Formats",
+            &mut self
+                .physical_device_ycbcr2_plane444_formats_features_ext
+                .ycbcr2plane444_formats,
+            other
+                .physical_device_ycbcr2_plane444_formats_features_ext
+                .ycbcr2plane444_formats,
+        );
+        f(
+            115usize,
+            "VkPhysicalDeviceProvokingVertexFeaturesEXT::provokingVertexLast",
+            &mut self
+                .physical_device_provoking_vertex_features_ext
+                .provoking_vertex_last,
+            other
+                .physical_device_provoking_vertex_features_ext
+                .provoking_vertex_last,
+        );
+        f(
+            115usize,
+            "VkPhysicalDeviceProvokingVertexFeaturesEXT::transformFeedbackPreservesProvokingVertex",
+            &mut self
+                .physical_device_provoking_vertex_features_ext
+                .transform_feedback_preserves_provoking_vertex,
+            other
+                .physical_device_provoking_vertex_features_ext
+                .transform_feedback_preserves_provoking_vertex,
+        );
+        f(
+            116usize,
+            "VkPhysicalDeviceDescriptorBufferFeaturesEXT::descriptorBuffer",
+            &mut self
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer,
+            other
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer,
+        );
+        f(
+            116usize,
+            "VkPhysicalDeviceDescriptorBufferFeaturesEXT::descriptorBufferCaptureReplay",
+            &mut self
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_capture_replay,
+            other
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_capture_replay,
+        );
+        f(
+            116usize,
+            "VkPhysicalDeviceDescriptorBufferFeaturesEXT::descriptorBufferImageLayoutIgnored",
+            &mut self
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_image_layout_ignored,
+            other
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_image_layout_ignored,
+        );
+        f(
+            116usize,
+            "VkPhysicalDeviceDescriptorBufferFeaturesEXT::descriptorBufferPushDescriptors",
+            &mut self
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_push_descriptors,
+            other
+                .physical_device_descriptor_buffer_features_ext
+                .descriptor_buffer_push_descriptors,
+        );
+        f(
+            117usize,
+            "VkPhysicalDeviceShaderIntegerDotProductFeatures::shaderIntegerDotProduct",
+            &mut self
+                .physical_device_shader_integer_dot_product_features
+                .shader_integer_dot_product,
+            other
+                .physical_device_shader_integer_dot_product_features
+                .shader_integer_dot_product,
+        );
+        f(
+            118usize,
+            "VkPhysicalDeviceFragmentShaderBarycentricFeaturesKHR::fragmentShaderBarycentric",
+            &mut self
+                .physical_device_fragment_shader_barycentric_features_khr
+                .fragment_shader_barycentric,
+            other
+                .physical_device_fragment_shader_barycentric_features_khr
+                .fragment_shader_barycentric,
+        );
+        f(
+            119usize,
+            "VkPhysicalDeviceRayTracingMotionBlurFeaturesNV::rayTracingMotionBlur",
+            &mut self
+                .physical_device_ray_tracing_motion_blur_features_nv
+                .ray_tracing_motion_blur,
+            other
+                .physical_device_ray_tracing_motion_blur_features_nv
+                .ray_tracing_motion_blur,
+        );
+        f (119usize , "VkPhysicalDeviceRayTracingMotionBlurFeaturesNV::rayTracingMotionBlurPipelineTraceRaysIndirect" , & mut self . physical_device_ray_tracing_motion_blur_features_nv . ray_tracing_motion_blur_pipeline_trace_rays_indirect , other . physical_device_ray_tracing_motion_blur_features_nv . ray_tracing_motion_blur_pipeline_trace_rays_indirect) ;
+        f(
+            120usize,
+            "VkPhysicalDeviceRGBA10X6FormatsFeaturesEXT::formatRgba10x6WithoutYCbCrSampler",
+            &mut self
+                .physical_device_rgba10x6_formats_features_ext
+                .format_rgba10x6_without_y_cb_cr_sampler,
+            other
+                .physical_device_rgba10x6_formats_features_ext
+                .format_rgba10x6_without_y_cb_cr_sampler,
+        );
+        f(
+            121usize,
+            "VkPhysicalDeviceDynamicRenderingFeatures::dynamicRendering",
+            &mut self
+                .physical_device_dynamic_rendering_features
+                .dynamic_rendering,
+            other
+                .physical_device_dynamic_rendering_features
+                .dynamic_rendering,
+        );
+        f(
+            122usize,
+            "VkPhysicalDeviceImageViewMinLodFeaturesEXT::minLod",
+            &mut self.physical_device_image_view_min_lod_features_ext.min_lod,
+            other
+                .physical_device_image_view_min_lod_features_ext
+                .min_lod,
+        );
+        f (123usize , "VkPhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT::rasterizationOrderColorAttachmentAccess" , & mut self . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_color_attachment_access , other . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_color_attachment_access) ;
+        f (123usize , "VkPhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT::rasterizationOrderDepthAttachmentAccess" , & mut self . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_depth_attachment_access , other . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_depth_attachment_access) ;
+        f (123usize , "VkPhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT::rasterizationOrderStencilAttachmentAccess" , & mut self . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_stencil_attachment_access , other . physical_device_rasterization_order_attachment_access_features_ext . rasterization_order_stencil_attachment_access) ;
+        f(
+            124usize,
+            "VkPhysicalDeviceLinearColorAttachmentFeaturesNV::linearColorAttachment",
+            &mut self
+                .physical_device_linear_color_attachment_features_nv
+                .linear_color_attachment,
+            other
+                .physical_device_linear_color_attachment_features_nv
+                .linear_color_attachment,
+        );
+        f(
+            125usize,
+            "VkPhysicalDeviceGraphicsPipelineLibraryFeaturesEXT::graphicsPipelineLibrary",
+            &mut self
+                .physical_device_graphics_pipeline_library_features_ext
+                .graphics_pipeline_library,
+            other
+                .physical_device_graphics_pipeline_library_features_ext
+                .graphics_pipeline_library,
+        );
+        f(
+            126usize,
+            "VkPhysicalDeviceDescriptorSetHostMappingFeaturesVALVE::descriptorSetHostMapping",
+            &mut self
+                .physical_device_descriptor_set_host_mapping_features_valve
+                .descriptor_set_host_mapping,
+            other
+                .physical_device_descriptor_set_host_mapping_features_valve
+                .descriptor_set_host_mapping,
+        );
+        f(
+            127usize,
+            "VkPhysicalDeviceShaderModuleIdentifierFeaturesEXT::shaderModuleIdentifier",
+            &mut self
+                .physical_device_shader_module_identifier_features_ext
+                .shader_module_identifier,
+            other
+                .physical_device_shader_module_identifier_features_ext
+                .shader_module_identifier,
+        );
+        f(
+            128usize,
+            "VkPhysicalDeviceImageCompressionControlFeaturesEXT::imageCompressionControl",
+            &mut self
+                .physical_device_image_compression_control_features_ext
+                .image_compression_control,
+            other
+                .physical_device_image_compression_control_features_ext
+                .image_compression_control,
+        );
+        f (129usize , "VkPhysicalDeviceImageCompressionControlSwapchainFeaturesEXT::imageCompressionControlSwapchain" , & mut self . physical_device_image_compression_control_swapchain_features_ext . image_compression_control_swapchain , other . physical_device_image_compression_control_swapchain_features_ext . image_compression_control_swapchain) ;
+        f(
+            130usize,
+            "VkPhysicalDeviceSubpassMergeFeedbackFeaturesEXT::subpassMergeFeedback",
+            &mut self
+                .physical_device_subpass_merge_feedback_features_ext
+                .subpass_merge_feedback,
+            other
+                .physical_device_subpass_merge_feedback_features_ext
+                .subpass_merge_feedback,
+        );
+        f(
+            131usize,
+            "VkPhysicalDeviceOpacityMicromapFeaturesEXT::micromap",
+            &mut self.physical_device_opacity_micromap_features_ext.micromap,
+            other.physical_device_opacity_micromap_features_ext.micromap,
+        );
+        f(
+            131usize,
+            "VkPhysicalDeviceOpacityMicromapFeaturesEXT::micromapCaptureReplay",
+            &mut self
+                .physical_device_opacity_micromap_features_ext
+                .micromap_capture_replay,
+            other
+                .physical_device_opacity_micromap_features_ext
+                .micromap_capture_replay,
+        );
+        f(
+            131usize,
+            "VkPhysicalDeviceOpacityMicromapFeaturesEXT::micromapHostCommands",
+            &mut self
+                .physical_device_opacity_micromap_features_ext
+                .micromap_host_commands,
+            other
+                .physical_device_opacity_micromap_features_ext
+                .micromap_host_commands,
+        );
+        f(
+            132usize,
+            "VkPhysicalDeviceDisplacementMicromapFeaturesNV::displacementMicromap",
+            &mut self
+                .physical_device_displacement_micromap_features_nv
+                .displacement_micromap,
+            other
+                .physical_device_displacement_micromap_features_nv
+                .displacement_micromap,
+        );
+        f(
+            133usize,
+            "VkPhysicalDevicePipelinePropertiesFeaturesEXT::pipelinePropertiesIdentifier",
+            &mut self
+                .physical_device_pipeline_properties_features_ext
+                .pipeline_properties_identifier,
+            other
+                .physical_device_pipeline_properties_features_ext
+                .pipeline_properties_identifier,
+        );
+        f (134usize , "VkPhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD::shaderEarlyAndLateFragmentTests" , & mut self . physical_device_shader_early_and_late_fragment_tests_features_amd . shader_early_and_late_fragment_tests , other . physical_device_shader_early_and_late_fragment_tests_features_amd . shader_early_and_late_fragment_tests) ;
+        f(
+            135usize,
+            "VkPhysicalDeviceNonSeamlessCubeMapFeaturesEXT::nonSeamlessCubeMap",
+            &mut self
+                .physical_device_non_seamless_cube_map_features_ext
+                .non_seamless_cube_map,
+            other
+                .physical_device_non_seamless_cube_map_features_ext
+                .non_seamless_cube_map,
+        );
+        f(
+            136usize,
+            "VkPhysicalDevicePipelineRobustnessFeaturesEXT::pipelineRobustness",
+            &mut self
+                .physical_device_pipeline_robustness_features_ext
+                .pipeline_robustness,
+            other
+                .physical_device_pipeline_robustness_features_ext
+                .pipeline_robustness,
+        );
+        f(
+            137usize,
+            "VkPhysicalDeviceImageProcessingFeaturesQCOM::textureSampleWeighted",
+            &mut self
+                .physical_device_image_processing_features_qcom
+                .texture_sample_weighted,
+            other
+                .physical_device_image_processing_features_qcom
+                .texture_sample_weighted,
+        );
+        f(
+            137usize,
+            "VkPhysicalDeviceImageProcessingFeaturesQCOM::textureBoxFilter",
+            &mut self
+                .physical_device_image_processing_features_qcom
+                .texture_box_filter,
+            other
+                .physical_device_image_processing_features_qcom
+                .texture_box_filter,
+        );
+        f(
+            137usize,
+            "VkPhysicalDeviceImageProcessingFeaturesQCOM::textureBlockMatch",
+            &mut self
+                .physical_device_image_processing_features_qcom
+                .texture_block_match,
+            other
+                .physical_device_image_processing_features_qcom
+                .texture_block_match,
+        );
+        f(
+            138usize,
+            "VkPhysicalDeviceTilePropertiesFeaturesQCOM::tileProperties",
+            &mut self
+                .physical_device_tile_properties_features_qcom
+                .tile_properties,
+            other
+                .physical_device_tile_properties_features_qcom
+                .tile_properties,
+        );
+        f(
+            139usize,
+            "VkPhysicalDeviceAmigoProfilingFeaturesSEC::amigoProfiling",
+            &mut self
+                .physical_device_amigo_profiling_features_sec
+                .amigo_profiling,
+            other
+                .physical_device_amigo_profiling_features_sec
+                .amigo_profiling,
+        );
+        f(
+            140usize,
+            "VkPhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT::attachmentFeedbackLoopLayout",
+            &mut self
+                .physical_device_attachment_feedback_loop_layout_features_ext
+                .attachment_feedback_loop_layout,
+            other
+                .physical_device_attachment_feedback_loop_layout_features_ext
+                .attachment_feedback_loop_layout,
+        );
+        f(
+            141usize,
+            "VkPhysicalDeviceDepthClampZeroOneFeaturesEXT::depthClampZeroOne",
+            &mut self
+                .physical_device_depth_clamp_zero_one_features_ext
+                .depth_clamp_zero_one,
+            other
+                .physical_device_depth_clamp_zero_one_features_ext
+                .depth_clamp_zero_one,
+        );
+        f(
+            142usize,
+            "VkPhysicalDeviceAddressBindingReportFeaturesEXT::reportAddressBinding",
+            &mut self
+                .physical_device_address_binding_report_features_ext
+                .report_address_binding,
+            other
+                .physical_device_address_binding_report_features_ext
+                .report_address_binding,
+        );
+        f(
+            143usize,
+            "VkPhysicalDeviceOpticalFlowFeaturesNV::opticalFlow",
+            &mut self.physical_device_optical_flow_features_nv.optical_flow,
+            other.physical_device_optical_flow_features_nv.optical_flow,
+        );
+        f(
+            144usize,
+            "VkPhysicalDeviceFaultFeaturesEXT::deviceFault",
+            &mut self.physical_device_fault_features_ext.device_fault,
+            other.physical_device_fault_features_ext.device_fault,
+        );
+        f(
+            144usize,
+            "VkPhysicalDeviceFaultFeaturesEXT::deviceFaultVendorBinary",
+            &mut self
+                .physical_device_fault_features_ext
+                .device_fault_vendor_binary,
+            other
+                .physical_device_fault_features_ext
+                .device_fault_vendor_binary,
+        );
+        f(
+            145usize,
+            "VkPhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT::pipelineLibraryGroupHandles",
+            &mut self
+                .physical_device_pipeline_library_group_handles_features_ext
+                .pipeline_library_group_handles,
+            other
+                .physical_device_pipeline_library_group_handles_features_ext
+                .pipeline_library_group_handles,
+        );
+        f(
+            146usize,
+            "VkPhysicalDeviceShaderCoreBuiltinsFeaturesARM::shaderCoreBuiltins",
+            &mut self
+                .physical_device_shader_core_builtins_features_arm
+                .shader_core_builtins,
+            other
+                .physical_device_shader_core_builtins_features_arm
+                .shader_core_builtins,
+        );
+        f (147usize , "VkPhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT::dynamicRenderingUnusedAttachments" , & mut self . physical_device_dynamic_rendering_unused_attachments_features_ext . dynamic_rendering_unused_attachments , other . physical_device_dynamic_rendering_unused_attachments_features_ext . dynamic_rendering_unused_attachments) ;
+        f(
+            148usize,
+            "VkPhysicalDeviceSwapchainMaintenance1FeaturesEXT::swapchainMaintenance1",
+            &mut self
+                .physical_device_swapchain_maintenance1_features_ext
+                .swapchain_maintenance1,
+            other
+                .physical_device_swapchain_maintenance1_features_ext
+                .swapchain_maintenance1,
+        );
+        f(
+            149usize,
+            "VkPhysicalDeviceDepthBiasControlFeaturesEXT::depthBiasControl",
+            &mut self
+                .physical_device_depth_bias_control_features_ext
+                .depth_bias_control,
+            other
+                .physical_device_depth_bias_control_features_ext
+                .depth_bias_control,
+        );
+        f (149usize , "VkPhysicalDeviceDepthBiasControlFeaturesEXT::leastRepresentableValueForceUnormRepresentation" , & mut self . physical_device_depth_bias_control_features_ext . least_representable_value_force_unorm_representation , other . physical_device_depth_bias_control_features_ext . least_representable_value_force_unorm_representation) ;
+        f(
+            149usize,
+            "VkPhysicalDeviceDepthBiasControlFeaturesEXT::floatRepresentation",
+            &mut self
+                .physical_device_depth_bias_control_features_ext
+                .float_representation,
+            other
+                .physical_device_depth_bias_control_features_ext
+                .float_representation,
+        );
+        f(
+            149usize,
+            "VkPhysicalDeviceDepthBiasControlFeaturesEXT::depthBiasExact",
+            &mut self
+                .physical_device_depth_bias_control_features_ext
+                .depth_bias_exact,
+            other
+                .physical_device_depth_bias_control_features_ext
+                .depth_bias_exact,
+        );
+        f(
+            150usize,
+            "VkPhysicalDeviceRayTracingInvocationReorderFeaturesNV::rayTracingInvocationReorder",
+            &mut self
+                .physical_device_ray_tracing_invocation_reorder_features_nv
+                .ray_tracing_invocation_reorder,
+            other
+                .physical_device_ray_tracing_invocation_reorder_features_nv
+                .ray_tracing_invocation_reorder,
+        );
+        f(
+            151usize,
+            "VkPhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM::multiviewPerViewViewports",
+            &mut self
+                .physical_device_multiview_per_view_viewports_features_qcom
+                .multiview_per_view_viewports,
+            other
+                .physical_device_multiview_per_view_viewports_features_qcom
+                .multiview_per_view_viewports,
+        );
+        f(
+            152usize,
+            "VkPhysicalDeviceRayTracingPositionFetchFeaturesKHR::rayTracingPositionFetch",
+            &mut self
+                .physical_device_ray_tracing_position_fetch_features_khr
+                .ray_tracing_position_fetch,
+            other
+                .physical_device_ray_tracing_position_fetch_features_khr
+                .ray_tracing_position_fetch,
+        );
+        f(
+            153usize,
+            "VkPhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM::multiviewPerViewRenderAreas",
+            &mut self
+                .physical_device_multiview_per_view_render_areas_features_qcom
+                .multiview_per_view_render_areas,
+            other
+                .physical_device_multiview_per_view_render_areas_features_qcom
+                .multiview_per_view_render_areas,
+        );
+        f(
+            154usize,
+            "VkPhysicalDeviceShaderObjectFeaturesEXT::shaderObject",
+            &mut self
+                .physical_device_shader_object_features_ext
+                .shader_object,
+            other
+                .physical_device_shader_object_features_ext
+                .shader_object,
+        );
+        f(
+            155usize,
+            "VkPhysicalDeviceShaderTileImageFeaturesEXT::shaderTileImageColorReadAccess",
+            &mut self
+                .physical_device_shader_tile_image_features_ext
+                .shader_tile_image_color_read_access,
+            other
+                .physical_device_shader_tile_image_features_ext
+                .shader_tile_image_color_read_access,
+        );
+        f(
+            155usize,
+            "VkPhysicalDeviceShaderTileImageFeaturesEXT::shaderTileImageDepthReadAccess",
+            &mut self
+                .physical_device_shader_tile_image_features_ext
+                .shader_tile_image_depth_read_access,
+            other
+                .physical_device_shader_tile_image_features_ext
+                .shader_tile_image_depth_read_access,
+        );
+        f(
+            155usize,
+            "VkPhysicalDeviceShaderTileImageFeaturesEXT::shaderTileImageStencilReadAccess",
+            &mut self
+                .physical_device_shader_tile_image_features_ext
+                .shader_tile_image_stencil_read_access,
+            other
+                .physical_device_shader_tile_image_features_ext
+                .shader_tile_image_stencil_read_access,
+        );
+        f(
+            156usize,
+            "VkPhysicalDeviceExternalMemoryScreenBufferFeaturesQNX::screenBufferImport",
+            &mut self
+                .physical_device_external_memory_screen_buffer_features_qnx
+                .screen_buffer_import,
+            other
+                .physical_device_external_memory_screen_buffer_features_qnx
+                .screen_buffer_import,
+        );
+    }
+    #[doc = r" Enables the features of the struct at `index` in the struct it was promoted to."]
+    pub(super) fn promote(&mut self, index: usize) {
+        match index {
+            2usize => {
+                self.physical_device_vulkan13_features.private_data |=
+                    self.physical_device_private_data_features.private_data;
+            }
+            3usize => {
+                self.physical_device_vulkan11_features
+                    .variable_pointers_storage_buffer |= self
+                    .physical_device_variable_pointers_features
+                    .variable_pointers_storage_buffer;
+                self.physical_device_vulkan11_features.variable_pointers |= self
+                    .physical_device_variable_pointers_features
+                    .variable_pointers;
+            }
+            4usize => {
+                self.physical_device_vulkan11_features.multiview |=
+                    self.physical_device_multiview_features.multiview;
+                self.physical_device_vulkan11_features
+                    .multiview_geometry_shader |= self
+                    .physical_device_multiview_features
+                    .multiview_geometry_shader;
+                self.physical_device_vulkan11_features
+                    .multiview_tessellation_shader |= self
+                    .physical_device_multiview_features
+                    .multiview_tessellation_shader;
+            }
+            7usize => {
+                self.physical_device_vulkan11_features
+                    .storage_buffer16_bit_access |= self
+                    .physical_device16_bit_storage_features
+                    .storage_buffer16_bit_access;
+                self.physical_device_vulkan11_features
+                    .uniform_and_storage_buffer16_bit_access |= self
+                    .physical_device16_bit_storage_features
+                    .uniform_and_storage_buffer16_bit_access;
+                self.physical_device_vulkan11_features
+                    .storage_push_constant16 |= self
+                    .physical_device16_bit_storage_features
+                    .storage_push_constant16;
+                self.physical_device_vulkan11_features
+                    .storage_input_output16 |= self
+                    .physical_device16_bit_storage_features
+                    .storage_input_output16;
+            }
+            8usize => {
+                self.physical_device_vulkan12_features
+                    .shader_subgroup_extended_types |= self
+                    .physical_device_shader_subgroup_extended_types_features
+                    .shader_subgroup_extended_types;
+            }
+            9usize => {
+                self.physical_device_vulkan11_features
+                    .sampler_ycbcr_conversion |= self
+                    .physical_device_sampler_ycbcr_conversion_features
+                    .sampler_ycbcr_conversion;
+            }
+            10usize => {
+                self.physical_device_vulkan11_features.protected_memory |= self
+                    .physical_device_protected_memory_features
+                    .protected_memory;
+            }
+            13usize => {
+                self.physical_device_vulkan13_features.inline_uniform_block |= self
+                    .physical_device_inline_uniform_block_features
+                    .inline_uniform_block;
+                self.physical_device_vulkan13_features
+                    .descriptor_binding_inline_uniform_block_update_after_bind |= self
+                    .physical_device_inline_uniform_block_features
+                    .descriptor_binding_inline_uniform_block_update_after_bind;
+            }
+            14usize => {
+                self.physical_device_vulkan13_features.maintenance4 |=
+                    self.physical_device_maintenance4_features.maintenance4;
+            }
+            15usize => {
+                self.physical_device_vulkan11_features
+                    .shader_draw_parameters |= self
+                    .physical_device_shader_draw_parameters_features
+                    .shader_draw_parameters;
+            }
+            16usize => {
+                self.physical_device_vulkan12_features.shader_float16 |= self
+                    .physical_device_shader_float16_int8_features
+                    .shader_float16;
+                self.physical_device_vulkan12_features.shader_int8 |= self
+                    .physical_device_shader_float16_int8_features
+                    .shader_int8;
+            }
+            17usize => {
+                self.physical_device_vulkan12_features.host_query_reset |= self
+                    .physical_device_host_query_reset_features
+                    .host_query_reset;
+            }
+            20usize => {
+                self.physical_device_vulkan12_features
+                    .shader_input_attachment_array_dynamic_indexing |= self
+                    .physical_device_descriptor_indexing_features
+                    .shader_input_attachment_array_dynamic_indexing;
+                self.physical_device_vulkan12_features
+                    .shader_uniform_texel_buffer_array_dynamic_indexing |= self
+                    .physical_device_descriptor_indexing_features
+                    .shader_uniform_texel_buffer_array_dynamic_indexing;
+                self.physical_device_vulkan12_features
+                    .shader_storage_texel_buffer_array_dynamic_indexing |= self
+                    .physical_device_descriptor_indexing_features
+                    .shader_storage_texel_buffer_array_dynamic_indexing;
+                self.physical_device_vulkan12_features
+                    .shader_uniform_buffer_array_non_uniform_indexing |= self
+                    .physical_device_descriptor_indexing_features
+                    .shader_uniform_buffer_array_non_uniform_indexing;
+                self.physical_device_vulkan12_features
+                    .shader_sampled_image_array_non_uniform_indexing |= self
+                    .physical_device_descriptor_indexing_features
+                    .shader_sampled_image_array_non_uniform_indexing;
+                self.physical_device_vulkan12_features
+                    .shader_storage_buffer_array_non_uniform_indexing |= self
+                    .physical_device_descriptor_indexing_features
+                    .shader_storage_buffer_array_non_uniform_indexing;
+                self.physical_device_vulkan12_features
+                    .shader_storage_image_array_non_uniform_indexing |= self
+                    .physical_device_descriptor_indexing_features
+                    .shader_storage_image_array_non_uniform_indexing;
+                self.physical_device_vulkan12_features
+                    .shader_input_attachment_array_non_uniform_indexing |= self
+                    .physical_device_descriptor_indexing_features
+                    .shader_input_attachment_array_non_uniform_indexing;
+                self.physical_device_vulkan12_features
+                    .shader_uniform_texel_buffer_array_non_uniform_indexing |= self
+                    .physical_device_descriptor_indexing_features
+                    .shader_uniform_texel_buffer_array_non_uniform_indexing;
+                self.physical_device_vulkan12_features
+                    .shader_storage_texel_buffer_array_non_uniform_indexing |= self
+                    .physical_device_descriptor_indexing_features
+                    .shader_storage_texel_buffer_array_non_uniform_indexing;
+                self.physical_device_vulkan12_features
+                    .descriptor_binding_uniform_buffer_update_after_bind |= self
+                    .physical_device_descriptor_indexing_features
+                    .descriptor_binding_uniform_buffer_update_after_bind;
+                self.physical_device_vulkan12_features
+                    .descriptor_binding_sampled_image_update_after_bind |= self
+                    .physical_device_descriptor_indexing_features
+                    .descriptor_binding_sampled_image_update_after_bind;
+                self.physical_device_vulkan12_features
+                    .descriptor_binding_storage_image_update_after_bind |= self
+                    .physical_device_descriptor_indexing_features
+                    .descriptor_binding_storage_image_update_after_bind;
+                self.physical_device_vulkan12_features
+                    .descriptor_binding_storage_buffer_update_after_bind |= self
+                    .physical_device_descriptor_indexing_features
+                    .descriptor_binding_storage_buffer_update_after_bind;
+                self.physical_device_vulkan12_features
+                    .descriptor_binding_uniform_texel_buffer_update_after_bind |= self
+                    .physical_device_descriptor_indexing_features
+                    .descriptor_binding_uniform_texel_buffer_update_after_bind;
+                self.physical_device_vulkan12_features
+                    .descriptor_binding_storage_texel_buffer_update_after_bind |= self
+                    .physical_device_descriptor_indexing_features
+                    .descriptor_binding_storage_texel_buffer_update_after_bind;
+                self.physical_device_vulkan12_features
+                    .descriptor_binding_update_unused_while_pending |= self
+                    .physical_device_descriptor_indexing_features
+                    .descriptor_binding_update_unused_while_pending;
+                self.physical_device_vulkan12_features
+                    .descriptor_binding_partially_bound |= self
+                    .physical_device_descriptor_indexing_features
+                    .descriptor_binding_partially_bound;
+                self.physical_device_vulkan12_features
+                    .descriptor_binding_variable_descriptor_count |= self
+                    .physical_device_descriptor_indexing_features
+                    .descriptor_binding_variable_descriptor_count;
+                self.physical_device_vulkan12_features
+                    .runtime_descriptor_array |= self
+                    .physical_device_descriptor_indexing_features
+                    .runtime_descriptor_array;
+            }
+            21usize => {
+                self.physical_device_vulkan12_features.timeline_semaphore |= self
+                    .physical_device_timeline_semaphore_features
+                    .timeline_semaphore;
+            }
+            22usize => {
+                self.physical_device_vulkan12_features
+                    .storage_buffer8_bit_access |= self
+                    .physical_device8_bit_storage_features
+                    .storage_buffer8_bit_access;
+                self.physical_device_vulkan12_features
+                    .uniform_and_storage_buffer8_bit_access |= self
+                    .physical_device8_bit_storage_features
+                    .uniform_and_storage_buffer8_bit_access;
+                self.physical_device_vulkan12_features
+                    .storage_push_constant8 |= self
+                    .physical_device8_bit_storage_features
+                    .storage_push_constant8;
+            }
+            24usize => {
+                self.physical_device_vulkan12_features.vulkan_memory_model |= self
+                    .physical_device_vulkan_memory_model_features
+                    .vulkan_memory_model;
+                self.physical_device_vulkan12_features
+                    .vulkan_memory_model_device_scope |= self
+                    .physical_device_vulkan_memory_model_features
+                    .vulkan_memory_model_device_scope;
+                self.physical_device_vulkan12_features
+                    .vulkan_memory_model_availability_visibility_chains |= self
+                    .physical_device_vulkan_memory_model_features
+                    .vulkan_memory_model_availability_visibility_chains;
+            }
+            25usize => {
+                self.physical_device_vulkan12_features
+                    .shader_buffer_int64_atomics |= self
+                    .physical_device_shader_atomic_int64_features
+                    .shader_buffer_int64_atomics;
+                self.physical_device_vulkan12_features
+                    .shader_shared_int64_atomics |= self
+                    .physical_device_shader_atomic_int64_features
+                    .shader_shared_int64_atomics;
+            }
+            50usize => {
+                self.physical_device_vulkan12_features.scalar_block_layout |= self
+                    .physical_device_scalar_block_layout_features
+                    .scalar_block_layout;
+            }
+            51usize => {
+                self.physical_device_vulkan12_features
+                    .uniform_buffer_standard_layout |= self
+                    .physical_device_uniform_buffer_standard_layout_features
+                    .uniform_buffer_standard_layout;
+            }
+            55usize => {
+                self.physical_device_vulkan12_features.buffer_device_address |= self
+                    .physical_device_buffer_device_address_features
+                    .buffer_device_address;
+                self.physical_device_vulkan12_features
+                    .buffer_device_address_capture_replay |= self
+                    .physical_device_buffer_device_address_features
+                    .buffer_device_address_capture_replay;
+                self.physical_device_vulkan12_features
+                    .buffer_device_address_multi_device |= self
+                    .physical_device_buffer_device_address_features
+                    .buffer_device_address_multi_device;
+            }
+            57usize => {
+                self.physical_device_vulkan12_features.imageless_framebuffer |= self
+                    .physical_device_imageless_framebuffer_features
+                    .imageless_framebuffer;
+            }
+            58usize => {
+                self.physical_device_vulkan13_features
+                    .texture_compression_astc_hdr |= self
+                    .physical_device_texture_compression_astchdr_features
+                    .texture_compression_astc_hdr;
+            }
+            69usize => {
+                self.physical_device_vulkan12_features
+                    .separate_depth_stencil_layouts |= self
+                    .physical_device_separate_depth_stencil_layouts_features
+                    .separate_depth_stencil_layouts;
+            }
+            72usize => {
+                self.physical_device_vulkan13_features
+                    .shader_demote_to_helper_invocation |= self
+                    .physical_device_shader_demote_to_helper_invocation_features
+                    .shader_demote_to_helper_invocation;
+            }
+            74usize => {
+                self.physical_device_vulkan13_features.subgroup_size_control |= self
+                    .physical_device_subgroup_size_control_features
+                    .subgroup_size_control;
+                self.physical_device_vulkan13_features
+                    .compute_full_subgroups |= self
+                    .physical_device_subgroup_size_control_features
+                    .compute_full_subgroups;
+            }
+            76usize => {
+                self.physical_device_vulkan13_features
+                    .pipeline_creation_cache_control |= self
+                    .physical_device_pipeline_creation_cache_control_features
+                    .pipeline_creation_cache_control;
+            }
+            87usize => {
+                self.physical_device_vulkan13_features
+                    .shader_zero_initialize_workgroup_memory |= self
+                    .physical_device_zero_initialize_workgroup_memory_features
+                    .shader_zero_initialize_workgroup_memory;
+            }
+            90usize => {
+                self.physical_device_vulkan13_features.robust_image_access |= self
+                    .physical_device_image_robustness_features
+                    .robust_image_access;
+            }
+            98usize => {
+                self.physical_device_vulkan13_features
+                    .shader_terminate_invocation |= self
+                    .physical_device_shader_terminate_invocation_features
+                    .shader_terminate_invocation;
+            }
+            108usize => {
+                self.physical_device_vulkan13_features.synchronization2 |= self
+                    .physical_device_synchronization2_features
+                    .synchronization2;
+            }
+            117usize => {
+                self.physical_device_vulkan13_features
+                    .shader_integer_dot_product |= self
+                    .physical_device_shader_integer_dot_product_features
+                    .shader_integer_dot_product;
+            }
+            121usize => {
+                self.physical_device_vulkan13_features.dynamic_rendering |= self
+                    .physical_device_dynamic_rendering_features
+                    .dynamic_rendering;
+            }
+            _ => {}
+        }
+    }
+}
+impl Feature for vk::PhysicalDeviceFeatures {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_features2.features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_features2.features
+    }
+}
+impl Feature for vk::PhysicalDeviceDeviceGeneratedCommandsFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_device_generated_commands_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_device_generated_commands_features_nv
+    }
+}
+impl Feature for vk::PhysicalDevicePrivateDataFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_private_data_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_private_data_features
+    }
+}
+impl Feature for vk::PhysicalDeviceVariablePointersFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_variable_pointers_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_variable_pointers_features
+    }
+}
+impl Feature for vk::PhysicalDeviceMultiviewFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_multiview_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_multiview_features
+    }
+}
+impl Feature for vk::PhysicalDevicePresentIdFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_present_id_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_present_id_features_khr
+    }
+}
+impl Feature for vk::PhysicalDevicePresentWaitFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_present_wait_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_present_wait_features_khr
+    }
+}
+impl Feature for vk::PhysicalDevice16BitStorageFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device16_bit_storage_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device16_bit_storage_features
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderSubgroupExtendedTypesFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_subgroup_extended_types_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_subgroup_extended_types_features
+    }
+}
+impl Feature for vk::PhysicalDeviceSamplerYcbcrConversionFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_sampler_ycbcr_conversion_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_sampler_ycbcr_conversion_features
+    }
+}
+impl Feature for vk::PhysicalDeviceProtectedMemoryFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_protected_memory_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_protected_memory_features
+    }
+}
+impl Feature for vk::PhysicalDeviceBlendOperationAdvancedFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_blend_operation_advanced_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_blend_operation_advanced_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceMultiDrawFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_multi_draw_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_multi_draw_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceInlineUniformBlockFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_inline_uniform_block_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_inline_uniform_block_features
+    }
+}
+impl Feature for vk::PhysicalDeviceMaintenance4Features<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_maintenance4_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_maintenance4_features
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderDrawParametersFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_draw_parameters_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_draw_parameters_features
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderFloat16Int8Features<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_float16_int8_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_float16_int8_features
+    }
+}
+impl Feature for vk::PhysicalDeviceHostQueryResetFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_host_query_reset_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_host_query_reset_features
+    }
+}
+impl Feature for vk::PhysicalDeviceGlobalPriorityQueryFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_global_priority_query_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_global_priority_query_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceDeviceMemoryReportFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_device_memory_report_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_device_memory_report_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceDescriptorIndexingFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_descriptor_indexing_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_descriptor_indexing_features
+    }
+}
+impl Feature for vk::PhysicalDeviceTimelineSemaphoreFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_timeline_semaphore_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_timeline_semaphore_features
+    }
+}
+impl Feature for vk::PhysicalDevice8BitStorageFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device8_bit_storage_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device8_bit_storage_features
+    }
+}
+impl Feature for vk::PhysicalDeviceConditionalRenderingFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_conditional_rendering_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_conditional_rendering_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceVulkanMemoryModelFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_vulkan_memory_model_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_vulkan_memory_model_features
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderAtomicInt64Features<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_atomic_int64_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_atomic_int64_features
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_atomic_float_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_atomic_float_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_atomic_float2_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_atomic_float2_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceVertexAttributeDivisorFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_vertex_attribute_divisor_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_vertex_attribute_divisor_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceASTCDecodeFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_astc_decode_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_astc_decode_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceTransformFeedbackFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_transform_feedback_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_transform_feedback_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceRepresentativeFragmentTestFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_representative_fragment_test_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_representative_fragment_test_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceExclusiveScissorFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_exclusive_scissor_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_exclusive_scissor_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceCornerSampledImageFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_corner_sampled_image_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_corner_sampled_image_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceComputeShaderDerivativesFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_compute_shader_derivatives_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_compute_shader_derivatives_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderImageFootprintFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_image_footprint_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_image_footprint_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_dedicated_allocation_image_aliasing_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_dedicated_allocation_image_aliasing_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceCopyMemoryIndirectFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_copy_memory_indirect_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_copy_memory_indirect_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceMemoryDecompressionFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_memory_decompression_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_memory_decompression_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceShadingRateImageFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shading_rate_image_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shading_rate_image_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceInvocationMaskFeaturesHUAWEI<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_invocation_mask_features_huawei
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_invocation_mask_features_huawei
+    }
+}
+impl Feature for vk::PhysicalDeviceMeshShaderFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_mesh_shader_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_mesh_shader_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceMeshShaderFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_mesh_shader_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_mesh_shader_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceAccelerationStructureFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_acceleration_structure_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_acceleration_structure_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceRayTracingPipelineFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_ray_tracing_pipeline_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_ray_tracing_pipeline_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceRayQueryFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_ray_query_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_ray_query_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceRayTracingMaintenance1FeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_ray_tracing_maintenance1_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_ray_tracing_maintenance1_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceFragmentDensityMapFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_fragment_density_map_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_fragment_density_map_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceFragmentDensityMap2FeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_fragment_density_map2_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_fragment_density_map2_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_fragment_density_map_offset_features_qcom
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_fragment_density_map_offset_features_qcom
+    }
+}
+impl Feature for vk::PhysicalDeviceScalarBlockLayoutFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_scalar_block_layout_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_scalar_block_layout_features
+    }
+}
+impl Feature for vk::PhysicalDeviceUniformBufferStandardLayoutFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_uniform_buffer_standard_layout_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_uniform_buffer_standard_layout_features
+    }
+}
+impl Feature for vk::PhysicalDeviceDepthClipEnableFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_depth_clip_enable_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_depth_clip_enable_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceMemoryPriorityFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_memory_priority_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_memory_priority_features_ext
+    }
+}
+impl Feature for vk::PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_pageable_device_local_memory_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_pageable_device_local_memory_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceBufferDeviceAddressFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_buffer_device_address_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_buffer_device_address_features
+    }
+}
+impl Feature for vk::PhysicalDeviceBufferDeviceAddressFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_buffer_device_address_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_buffer_device_address_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceImagelessFramebufferFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_imageless_framebuffer_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_imageless_framebuffer_features
+    }
+}
+impl Feature for vk::PhysicalDeviceTextureCompressionASTCHDRFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_texture_compression_astchdr_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_texture_compression_astchdr_features
+    }
+}
+impl Feature for vk::PhysicalDeviceCooperativeMatrixFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_cooperative_matrix_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_cooperative_matrix_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceYcbcrImageArraysFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_ycbcr_image_arrays_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_ycbcr_image_arrays_features_ext
+    }
+}
+impl Feature for vk::PhysicalDevicePresentBarrierFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_present_barrier_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_present_barrier_features_nv
+    }
+}
+impl Feature for vk::PhysicalDevicePerformanceQueryFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_performance_query_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_performance_query_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceCoverageReductionModeFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_coverage_reduction_mode_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_coverage_reduction_mode_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_integer_functions2_features_intel
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_integer_functions2_features_intel
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderClockFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_shader_clock_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_shader_clock_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceIndexTypeUint8FeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_index_type_uint8_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_index_type_uint8_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderSMBuiltinsFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_sm_builtins_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_sm_builtins_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceFragmentShaderInterlockFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_fragment_shader_interlock_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_fragment_shader_interlock_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceSeparateDepthStencilLayoutsFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_separate_depth_stencil_layouts_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_separate_depth_stencil_layouts_features
+    }
+}
+impl Feature for vk::PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_primitive_topology_list_restart_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_primitive_topology_list_restart_features_ext
+    }
+}
+impl Feature for vk::PhysicalDevicePipelineExecutablePropertiesFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_pipeline_executable_properties_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_pipeline_executable_properties_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_demote_to_helper_invocation_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_demote_to_helper_invocation_features
+    }
+}
+impl Feature for vk::PhysicalDeviceTexelBufferAlignmentFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_texel_buffer_alignment_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_texel_buffer_alignment_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceSubgroupSizeControlFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_subgroup_size_control_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_subgroup_size_control_features
+    }
+}
+impl Feature for vk::PhysicalDeviceLineRasterizationFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_line_rasterization_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_line_rasterization_features_ext
+    }
+}
+impl Feature for vk::PhysicalDevicePipelineCreationCacheControlFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_pipeline_creation_cache_control_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_pipeline_creation_cache_control_features
+    }
+}
+impl Feature for vk::PhysicalDeviceVulkan11Features<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_vulkan11_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_vulkan11_features
+    }
+}
+impl Feature for vk::PhysicalDeviceVulkan12Features<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_vulkan12_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_vulkan12_features
+    }
+}
+impl Feature for vk::PhysicalDeviceVulkan13Features<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_vulkan13_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_vulkan13_features
+    }
+}
+impl Feature for vk::PhysicalDeviceCoherentMemoryFeaturesAMD<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_coherent_memory_features_amd
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_coherent_memory_features_amd
+    }
+}
+impl Feature for vk::PhysicalDeviceCustomBorderColorFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_custom_border_color_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_custom_border_color_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceBorderColorSwizzleFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_border_color_swizzle_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_border_color_swizzle_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceExtendedDynamicStateFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_extended_dynamic_state_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_extended_dynamic_state_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceExtendedDynamicState2FeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_extended_dynamic_state2_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_extended_dynamic_state2_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_extended_dynamic_state3_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_extended_dynamic_state3_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceDiagnosticsConfigFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_diagnostics_config_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_diagnostics_config_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_zero_initialize_workgroup_memory_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_zero_initialize_workgroup_memory_features
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_subgroup_uniform_control_flow_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_subgroup_uniform_control_flow_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceRobustness2FeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_robustness2_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_robustness2_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceImageRobustnessFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_image_robustness_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_image_robustness_features
+    }
+}
+impl Feature for vk::PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_workgroup_memory_explicit_layout_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_workgroup_memory_explicit_layout_features_khr
+    }
+}
+impl Feature for vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_portability_subset_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_portability_subset_features_khr
+    }
+}
+impl Feature for vk::PhysicalDevice4444FormatsFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device4444_formats_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device4444_formats_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceSubpassShadingFeaturesHUAWEI<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_subpass_shading_features_huawei
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_subpass_shading_features_huawei
+    }
+}
+impl Feature for vk::PhysicalDeviceClusterCullingShaderFeaturesHUAWEI<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_cluster_culling_shader_features_huawei
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_cluster_culling_shader_features_huawei
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderImageAtomicInt64FeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_image_atomic_int64_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_image_atomic_int64_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceFragmentShadingRateFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_fragment_shading_rate_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_fragment_shading_rate_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderTerminateInvocationFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_terminate_invocation_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_terminate_invocation_features
+    }
+}
+impl Feature for vk::PhysicalDeviceFragmentShadingRateEnumsFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_fragment_shading_rate_enums_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_fragment_shading_rate_enums_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceImage2DViewOf3DFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_image2_d_view_of3_d_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_image2_d_view_of3_d_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceImageSlicedViewOf3DFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_image_sliced_view_of3_d_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_image_sliced_view_of3_d_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceAttachmentFeedbackLoopDynamicStateFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_attachment_feedback_loop_dynamic_state_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_attachment_feedback_loop_dynamic_state_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceMutableDescriptorTypeFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_mutable_descriptor_type_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_mutable_descriptor_type_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceDepthClipControlFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_depth_clip_control_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_depth_clip_control_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceVertexInputDynamicStateFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_vertex_input_dynamic_state_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_vertex_input_dynamic_state_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceExternalMemoryRDMAFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_external_memory_rdma_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_external_memory_rdma_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceColorWriteEnableFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_color_write_enable_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_color_write_enable_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceSynchronization2Features<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_synchronization2_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_synchronization2_features
+    }
+}
+impl Feature for vk::PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_primitives_generated_query_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_primitives_generated_query_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceLegacyDitheringFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_legacy_dithering_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_legacy_dithering_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_multisampled_render_to_single_sampled_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_multisampled_render_to_single_sampled_features_ext
+    }
+}
+impl Feature for vk::PhysicalDevicePipelineProtectedAccessFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_pipeline_protected_access_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_pipeline_protected_access_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceInheritedViewportScissorFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_inherited_viewport_scissor_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_inherited_viewport_scissor_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_ycbcr2_plane444_formats_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_ycbcr2_plane444_formats_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceProvokingVertexFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_provoking_vertex_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_provoking_vertex_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceDescriptorBufferFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_descriptor_buffer_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_descriptor_buffer_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderIntegerDotProductFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_integer_dot_product_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_integer_dot_product_features
+    }
+}
+impl Feature for vk::PhysicalDeviceFragmentShaderBarycentricFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_fragment_shader_barycentric_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_fragment_shader_barycentric_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceRayTracingMotionBlurFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_ray_tracing_motion_blur_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_ray_tracing_motion_blur_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceRGBA10X6FormatsFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_rgba10x6_formats_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_rgba10x6_formats_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceDynamicRenderingFeatures<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_dynamic_rendering_features
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_dynamic_rendering_features
+    }
+}
+impl Feature for vk::PhysicalDeviceImageViewMinLodFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_image_view_min_lod_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_image_view_min_lod_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_rasterization_order_attachment_access_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_rasterization_order_attachment_access_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceLinearColorAttachmentFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_linear_color_attachment_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_linear_color_attachment_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_graphics_pipeline_library_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_graphics_pipeline_library_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_descriptor_set_host_mapping_features_valve
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_descriptor_set_host_mapping_features_valve
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderModuleIdentifierFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_module_identifier_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_module_identifier_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceImageCompressionControlFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_image_compression_control_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_image_compression_control_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_image_compression_control_swapchain_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_image_compression_control_swapchain_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceSubpassMergeFeedbackFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_subpass_merge_feedback_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_subpass_merge_feedback_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceOpacityMicromapFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_opacity_micromap_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_opacity_micromap_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceDisplacementMicromapFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_displacement_micromap_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_displacement_micromap_features_nv
+    }
+}
+impl Feature for vk::PhysicalDevicePipelinePropertiesFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_pipeline_properties_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_pipeline_properties_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_early_and_late_fragment_tests_features_amd
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_early_and_late_fragment_tests_features_amd
+    }
+}
+impl Feature for vk::PhysicalDeviceNonSeamlessCubeMapFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_non_seamless_cube_map_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_non_seamless_cube_map_features_ext
+    }
+}
+impl Feature for vk::PhysicalDevicePipelineRobustnessFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_pipeline_robustness_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_pipeline_robustness_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceImageProcessingFeaturesQCOM<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_image_processing_features_qcom
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_image_processing_features_qcom
+    }
+}
+impl Feature for vk::PhysicalDeviceTilePropertiesFeaturesQCOM<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_tile_properties_features_qcom
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_tile_properties_features_qcom
+    }
+}
+impl Feature for vk::PhysicalDeviceAmigoProfilingFeaturesSEC<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_amigo_profiling_features_sec
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_amigo_profiling_features_sec
+    }
+}
+impl Feature for vk::PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_attachment_feedback_loop_layout_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_attachment_feedback_loop_layout_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceDepthClampZeroOneFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_depth_clamp_zero_one_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_depth_clamp_zero_one_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceAddressBindingReportFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_address_binding_report_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_address_binding_report_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceOpticalFlowFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_optical_flow_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_optical_flow_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceFaultFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_fault_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_fault_features_ext
+    }
+}
+impl Feature for vk::PhysicalDevicePipelineLibraryGroupHandlesFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_pipeline_library_group_handles_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_pipeline_library_group_handles_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderCoreBuiltinsFeaturesARM<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_core_builtins_features_arm
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_core_builtins_features_arm
+    }
+}
+impl Feature for vk::PhysicalDeviceDynamicRenderingUnusedAttachmentsFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_dynamic_rendering_unused_attachments_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_dynamic_rendering_unused_attachments_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceSwapchainMaintenance1FeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_swapchain_maintenance1_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_swapchain_maintenance1_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceDepthBiasControlFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_depth_bias_control_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_depth_bias_control_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceRayTracingInvocationReorderFeaturesNV<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_ray_tracing_invocation_reorder_features_nv
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_ray_tracing_invocation_reorder_features_nv
+    }
+}
+impl Feature for vk::PhysicalDeviceMultiviewPerViewViewportsFeaturesQCOM<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_multiview_per_view_viewports_features_qcom
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_multiview_per_view_viewports_features_qcom
+    }
+}
+impl Feature for vk::PhysicalDeviceRayTracingPositionFetchFeaturesKHR<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_ray_tracing_position_fetch_features_khr
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_ray_tracing_position_fetch_features_khr
+    }
+}
+impl Feature for vk::PhysicalDeviceMultiviewPerViewRenderAreasFeaturesQCOM<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_multiview_per_view_render_areas_features_qcom
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_multiview_per_view_render_areas_features_qcom
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderObjectFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features.structs.physical_device_shader_object_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features.structs.physical_device_shader_object_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceShaderTileImageFeaturesEXT<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_shader_tile_image_features_ext
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_shader_tile_image_features_ext
+    }
+}
+impl Feature for vk::PhysicalDeviceExternalMemoryScreenBufferFeaturesQNX<'static> {
+    fn get(features: &Features) -> &Self {
+        &features
+            .structs
+            .physical_device_external_memory_screen_buffer_features_qnx
+    }
+    fn get_mut(features: &mut Features) -> &mut Self {
+        &mut features
+            .structs
+            .physical_device_external_memory_screen_buffer_features_qnx
+    }
+}
@@ -0,0 +1,315 @@
+//! Interception of the commands loaded by [`Entry`], [`Instance`], [`Device`] and the extension
+//! wrappers, to profile, log or inject faults into an application without writing a Vulkan layer.
+//!
+//! The [`Hooks`] passed to [`install()`] apply to every function pointer resolved afterwards by
+//! [`Entry::from_static_fn()`], [`Instance::load()`], [`Device::load()`],
+//! [`Entry::get_instance_proc_addr()`] and [`Instance::get_device_proc_addr()`], and thus by
+//! [`Entry::create_instance()`], [`Instance::create_device()`] and the `new()` constructors of
+//! extension wrappers:
+//!
+//! * [`Hooks::resolve_with()`] overrides the resolution of any command, for example to replace it
+//!   with a function returning an error.
+//! * [`Hooks::before()`] and [`Hooks::after()`] run a closure around every call of a command,
+//!   which then calls into a stub forwarding to the resolved function pointer. The closure
+//!   receives the arguments of the call, and its result in [`Hooks::after()`], through a
+//!   [`Call`].
+//!
+//! ```no_run
+//! # use ash::{hooks::{self, Hooks}, vk, Entry};
+//! # unsafe fn profile() -> Result<(), Box<dyn std::error::Error>> {
+//! use std::time::Instant;
+//!
+//! let start = std::sync::Arc::new(std::sync::Mutex::new(Instant::now()));
+//! let end = start.clone();
+//! hooks::install(
+//!     Hooks::new()
+//!         .before("vkQueueSubmit", move |_| *start.lock().unwrap() = Instant::now())
+//!         .after("vkQueueSubmit", move |call| {
+//!             let submit_count = call.arg::<u32>(1).unwrap();
+//!             let result = call.result::<vk::Result>().unwrap();
+//!             println!(
+//!                 "vkQueueSubmit of {submit_count} batches returned {result} after {:?}",
+//!                 end.lock().unwrap().elapsed()
+//!             )
+//!         }),
+//! );
+//! let entry = Entry::load()?;
+//! # Ok(()) }
+//! ```
+//!
+//! The stubs forward to the function pointers that were loaded for the instance or device that
+//! the dispatchable handle passed to them belongs to, which they find through the dispatch table
+//! pointer that the Vulkan loader stores in every dispatchable object. Calls with a null handle,
+//! and calls of the commands that take no dispatchable handle such as `vkCreateInstance`, forward
+//! to the function pointer that was loaded last. `vkGetInstanceProcAddr` and `vkGetDeviceProcAddr`
+//! cannot be hooked.
+
+use crate::prelude::dispatch_key;
+use crate::vk;
+#[cfg(doc)]
+use crate::{Device, Entry, Instance};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr, CString};
+use std::mem;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+#[allow(deprecated)]
+mod generated;
+
+type Resolver = Box<dyn FnMut(&CStr) -> Option<vk::PFN_vkVoidFunction> + Send>;
+type Hook = Box<dyn FnMut(&Call<'_>) + Send>;
+
+/// Overrides and closures applied to the commands loaded after [`install()`].
+#[derive(Default)]
+pub struct Hooks {
+    resolver: Option<Resolver>,
+    /// Hooks are taken out while they run, so that they can call hooked commands themselves.
+    before: HashMap<CString, Option<Hook>>,
+    after: HashMap<CString, Option<Hook>>,
+}
+
+impl Hooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves commands with `resolver` first, which returns `None` to fall back to the
+    /// driver. Returning `Some(None)` makes the command unavailable.
+    pub fn resolve_with(
+        mut self,
+        resolver: impl FnMut(&CStr) -> Option<vk::PFN_vkVoidFunction> + Send + 'static,
+    ) -> Self {
+        self.resolver = Some(Box::new(resolver));
+        self
+    }
+
+    /// Runs `hook` before every call of `command`, the name of a command such as
+    /// `"vkQueueSubmit"`. Commands promoted to core are hooked under their core name, also when
+    /// loaded under the name of their extension. A hook that panics is removed.
+    ///
+    /// # Panics
+    ///
+    /// If `command` contains a null byte.
+    pub fn before(mut self, command: &str, hook: impl FnMut(&Call<'_>) + Send + 'static) -> Self {
+        self.before
+            .insert(command_name(command), Some(Box::new(hook)));
+        self
+    }
+
+    /// Runs `hook` after every call of `command`, see [`Hooks::before()`].
+    ///
+    /// # Panics
+    ///
+    /// If `command` contains a null byte.
+    pub fn after(mut self, command: &str, hook: impl FnMut(&Call<'_>) + Send + 'static) -> Self {
+        self.after
+            .insert(command_name(command), Some(Box::new(hook)));
+        self
+    }
+}
+
+fn command_name(command: &str) -> CString {
+    CString::new(command).expect("Command names cannot contain null bytes")
+}
+
+/// A pointer to an argument or result of a hooked call, and the type it points to.
+#[derive(Clone, Copy)]
+struct Arg {
+    ty: TypeId,
+    ptr: *const c_void,
+}
+
+impl Arg {
+    /// `T` is the type that `ptr` points to, with its lifetimes made `'static`.
+    fn new<T: Any>(ptr: *const c_void) -> Self {
+        Self {
+            ty: TypeId::of::<T>(),
+            ptr,
+        }
+    }
+
+    fn get<T: Any + Copy>(&self) -> Option<T> {
+        // Sound because the type of the value matches, apart from lifetimes that `T` cannot
+        // borrow through as it is `'static`
+        (self.ty == TypeId::of::<T>()).then(|| unsafe { *self.ptr.cast::<T>() })
+    }
+}
+
+/// A call of a hooked command, passed to the closures of [`Hooks::before()`] and
+/// [`Hooks::after()`].
+pub struct Call<'a> {
+    command: &'a CStr,
+    args: &'a [Arg],
+    result: Option<Arg>,
+}
+
+impl<'a> Call<'a> {
+    fn new(command: &'a CStr, args: &'a [Arg], result: Option<Arg>) -> Self {
+        Self {
+            command,
+            args,
+            result,
+        }
+    }
+
+    /// The name of the called command, such as `"vkQueueSubmit"`.
+    pub fn command(&self) -> &CStr {
+        self.command
+    }
+
+    /// The number of parameters of the command.
+    pub fn arg_count(&self) -> usize {
+        self.args.len()
+    }
+
+    /// The argument of parameter `index`, or `None` if the command has no such parameter or if
+    /// it is not of type `T`. Pointers are passed as they are: hooks must only dereference
+    /// them if the command allows it.
+    ///
+    /// ```no_run
+    /// # use ash::{hooks::Hooks, vk};
+    /// Hooks::new().before("vkCreateFence", |call| {
+    ///     let create_info = call.arg::<*const vk::FenceCreateInfo>(1).unwrap();
+    ///     println!("{:?}", unsafe { (*create_info).flags });
+    /// });
+    /// ```
+    pub fn arg<T: Any + Copy>(&self, index: usize) -> Option<T> {
+        self.args.get(index)?.get()
+    }
+
+    /// The value returned by the command, or `None` before the call, for `void` commands or if
+    /// it is not of type `T`.
+    pub fn result<T: Any + Copy>(&self) -> Option<T> {
+        self.result?.get()
+    }
+}
+
+/// Allocates a global on first use, which is never freed, and locks it. It remains usable after
+/// a hook panicked.
+fn lock<T: Default>(global: &'static AtomicPtr<Mutex<T>>) -> MutexGuard<'static, T> {
+    let mut value = global.load(Ordering::Acquire);
+    if value.is_null() {
+        let new = Box::into_raw(Box::default());
+        value = match global.compare_exchange(
+            ptr::null_mut(),
+            new,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => new,
+            Err(existing) => {
+                drop(unsafe { Box::from_raw(new) });
+                existing
+            }
+        };
+    }
+    let value = unsafe { &*value };
+    value
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The installed hooks.
+static HOOKS: AtomicPtr<Mutex<Option<Hooks>>> = AtomicPtr::new(ptr::null_mut());
+
+fn hooks() -> MutexGuard<'static, Option<Hooks>> {
+    lock(&HOOKS)
+}
+
+/// The function pointers that the stubs forward to.
+#[derive(Default)]
+struct Forwarding {
+    /// By dispatch key and command index.
+    next: HashMap<(usize, usize), usize>,
+    /// The pointer loaded last, by command index.
+    last: HashMap<usize, usize>,
+}
+
+static FORWARDING: AtomicPtr<Mutex<Forwarding>> = AtomicPtr::new(ptr::null_mut());
+
+/// The function pointer that the stub of command `index` forwards to for `key`. Stubs are only
+/// returned after storing a pointer for them, so there is always one.
+fn next(index: usize, key: usize) -> *const c_void {
+    let forwarding = lock(&FORWARDING);
+    let next = forwarding
+        .next
+        .get(&(key, index))
+        .or_else(|| forwarding.last.get(&index));
+    *next.unwrap() as *const c_void
+}
+
+/// Applies `hooks` to the commands loaded from now on, returning the previously installed hooks.
+/// The stubs of commands that were loaded before run the [`Hooks::before()`] and
+/// [`Hooks::after()`] hooks of the same name in `hooks` instead.
+pub fn install(hooks: Hooks) -> Option<Hooks> {
+    self::hooks().replace(hooks)
+}
+
+/// Stops applying the installed hooks, returning them.
+pub fn uninstall() -> Option<Hooks> {
+    hooks().take()
+}
+
+/// Resolves `name` for `handle`, the raw value of the dispatchable handle that it is loaded
+/// with, with the installed hooks, falling back to `load`.
+///
+/// # Safety
+///
+/// `handle` must be null or a valid dispatchable handle.
+pub(crate) unsafe fn resolve(
+    name: &CStr,
+    handle: u64,
+    load: impl FnOnce() -> *const c_void,
+) -> *const c_void {
+    let mut resolver = match &mut *hooks() {
+        Some(hooks) => hooks.resolver.take(),
+        None => return load(),
+    };
+    let f = match resolver.as_mut().and_then(|resolver| resolver(name)) {
+        Some(f) => f.map_or(ptr::null(), |f| f as *const c_void),
+        None => load(),
+    };
+    let mut hooks = hooks();
+    let hooks = match &mut *hooks {
+        Some(hooks) => hooks,
+        None => return f,
+    };
+    if hooks.resolver.is_none() {
+        hooks.resolver = resolver;
+    }
+    let (index, command, stub) = match generated::stub(name.to_bytes()) {
+        Some(stub) if !f.is_null() => stub,
+        _ => return f,
+    };
+    if !(hooks.before.contains_key(command) || hooks.after.contains_key(command)) {
+        return f;
+    }
+    let key = dispatch_key(handle);
+    let mut forwarding = lock(&FORWARDING);
+    forwarding.next.insert((key, index), f as usize);
+    forwarding.last.insert(index, f as usize);
+    stub
+}
+
+/// Runs the hook of the called command in one of the maps selected by `stage`. Never panics, as
+/// it runs inside `extern "system"` functions.
+fn run_hook(call: &Call<'_>, stage: fn(&mut Hooks) -> &mut HashMap<CString, Option<Hook>>) {
+    let name = call.command;
+    let hook = hooks()
+        .as_mut()
+        .and_then(|hooks| stage(hooks).get_mut(name)?.take());
+    if let Some(mut hook) = hook {
+        if std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| hook(call))).is_err() {
+            return;
+        }
+        if let Some(slot) = hooks()
+            .as_mut()
+            .and_then(|hooks| stage(hooks).get_mut(name))
+        {
+            slot.get_or_insert(hook);
+        }
+    }
+}
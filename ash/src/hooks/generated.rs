@@ -0,0 +1,17831 @@
+use super::*;
+use crate::vk::Handle;
+use std::os::raw::{c_char, c_int};
+#[doc = r" Returns the index of a command in the forwarding tables, the name it is hooked under"]
+#[doc = r" and its stub, which runs the hooks around calls to the function pointer it was loaded"]
+#[doc = r" as."]
+pub(super) fn stub(name: &[u8]) -> Option<(usize, &'static CStr, *const c_void)> {
+    Some(match name {
+        b"vkAcquireDrmDisplayEXT" => (
+            0usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkAcquireDrmDisplayEXT\0") },
+            acquire_drm_display_ext as *const c_void,
+        ),
+        b"vkAcquireFullScreenExclusiveModeEXT" => (
+            1usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkAcquireFullScreenExclusiveModeEXT\0")
+            },
+            acquire_full_screen_exclusive_mode_ext as *const c_void,
+        ),
+        b"vkAcquireImageANDROID" => (
+            2usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkAcquireImageANDROID\0") },
+            acquire_image_android as *const c_void,
+        ),
+        b"vkAcquireNextImage2KHR" => (
+            3usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkAcquireNextImage2KHR\0") },
+            acquire_next_image2_khr as *const c_void,
+        ),
+        b"vkAcquireNextImageKHR" => (
+            4usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkAcquireNextImageKHR\0") },
+            acquire_next_image_khr as *const c_void,
+        ),
+        b"vkAcquirePerformanceConfigurationINTEL" => (
+            5usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkAcquirePerformanceConfigurationINTEL\0")
+            },
+            acquire_performance_configuration_intel as *const c_void,
+        ),
+        b"vkAcquireProfilingLockKHR" => (
+            6usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkAcquireProfilingLockKHR\0") },
+            acquire_profiling_lock_khr as *const c_void,
+        ),
+        b"vkAcquireWinrtDisplayNV" => (
+            7usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkAcquireWinrtDisplayNV\0") },
+            acquire_winrt_display_nv as *const c_void,
+        ),
+        b"vkAcquireXlibDisplayEXT" => (
+            8usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkAcquireXlibDisplayEXT\0") },
+            acquire_xlib_display_ext as *const c_void,
+        ),
+        b"vkAllocateCommandBuffers" => (
+            9usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkAllocateCommandBuffers\0") },
+            allocate_command_buffers as *const c_void,
+        ),
+        b"vkAllocateDescriptorSets" => (
+            10usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkAllocateDescriptorSets\0") },
+            allocate_descriptor_sets as *const c_void,
+        ),
+        b"vkAllocateMemory" => (
+            11usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkAllocateMemory\0") },
+            allocate_memory as *const c_void,
+        ),
+        b"vkBeginCommandBuffer" => (
+            12usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBeginCommandBuffer\0") },
+            begin_command_buffer as *const c_void,
+        ),
+        b"vkBindAccelerationStructureMemoryNV" => (
+            13usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkBindAccelerationStructureMemoryNV\0")
+            },
+            bind_acceleration_structure_memory_nv as *const c_void,
+        ),
+        b"vkBindBufferMemory" => (
+            14usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBindBufferMemory\0") },
+            bind_buffer_memory as *const c_void,
+        ),
+        b"vkBindBufferMemory2" => (
+            15usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBindBufferMemory2\0") },
+            bind_buffer_memory2 as *const c_void,
+        ),
+        b"vkBindBufferMemory2KHR" => (
+            15usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBindBufferMemory2\0") },
+            bind_buffer_memory2 as *const c_void,
+        ),
+        b"vkBindImageMemory" => (
+            16usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBindImageMemory\0") },
+            bind_image_memory as *const c_void,
+        ),
+        b"vkBindImageMemory2" => (
+            17usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBindImageMemory2\0") },
+            bind_image_memory2 as *const c_void,
+        ),
+        b"vkBindImageMemory2KHR" => (
+            17usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBindImageMemory2\0") },
+            bind_image_memory2 as *const c_void,
+        ),
+        b"vkBindOpticalFlowSessionImageNV" => (
+            18usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBindOpticalFlowSessionImageNV\0") },
+            bind_optical_flow_session_image_nv as *const c_void,
+        ),
+        b"vkBindVideoSessionMemoryKHR" => (
+            19usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBindVideoSessionMemoryKHR\0") },
+            bind_video_session_memory_khr as *const c_void,
+        ),
+        b"vkBuildAccelerationStructuresKHR" => (
+            20usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBuildAccelerationStructuresKHR\0") },
+            build_acceleration_structures_khr as *const c_void,
+        ),
+        b"vkBuildMicromapsEXT" => (
+            21usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBuildMicromapsEXT\0") },
+            build_micromaps_ext as *const c_void,
+        ),
+        b"vkCmdBeginConditionalRenderingEXT" => (
+            22usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginConditionalRenderingEXT\0") },
+            cmd_begin_conditional_rendering_ext as *const c_void,
+        ),
+        b"vkCmdBeginDebugUtilsLabelEXT" => (
+            23usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginDebugUtilsLabelEXT\0") },
+            cmd_begin_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkCmdBeginQuery" => (
+            24usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginQuery\0") },
+            cmd_begin_query as *const c_void,
+        ),
+        b"vkCmdBeginQueryIndexedEXT" => (
+            25usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginQueryIndexedEXT\0") },
+            cmd_begin_query_indexed_ext as *const c_void,
+        ),
+        b"vkCmdBeginRenderPass" => (
+            26usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRenderPass\0") },
+            cmd_begin_render_pass as *const c_void,
+        ),
+        b"vkCmdBeginRenderPass2" => (
+            27usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRenderPass2\0") },
+            cmd_begin_render_pass2 as *const c_void,
+        ),
+        b"vkCmdBeginRenderPass2KHR" => (
+            27usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRenderPass2\0") },
+            cmd_begin_render_pass2 as *const c_void,
+        ),
+        b"vkCmdBeginRendering" => (
+            28usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRendering\0") },
+            cmd_begin_rendering as *const c_void,
+        ),
+        b"vkCmdBeginRenderingKHR" => (
+            28usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRendering\0") },
+            cmd_begin_rendering as *const c_void,
+        ),
+        b"vkCmdBeginTransformFeedbackEXT" => (
+            29usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginTransformFeedbackEXT\0") },
+            cmd_begin_transform_feedback_ext as *const c_void,
+        ),
+        b"vkCmdBeginVideoCodingKHR" => (
+            30usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginVideoCodingKHR\0") },
+            cmd_begin_video_coding_khr as *const c_void,
+        ),
+        b"vkCmdBindDescriptorBufferEmbeddedSamplersEXT" => (
+            31usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkCmdBindDescriptorBufferEmbeddedSamplersEXT\0",
+                )
+            },
+            cmd_bind_descriptor_buffer_embedded_samplers_ext as *const c_void,
+        ),
+        b"vkCmdBindDescriptorBuffersEXT" => (
+            32usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindDescriptorBuffersEXT\0") },
+            cmd_bind_descriptor_buffers_ext as *const c_void,
+        ),
+        b"vkCmdBindDescriptorSets" => (
+            33usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindDescriptorSets\0") },
+            cmd_bind_descriptor_sets as *const c_void,
+        ),
+        b"vkCmdBindIndexBuffer" => (
+            34usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindIndexBuffer\0") },
+            cmd_bind_index_buffer as *const c_void,
+        ),
+        b"vkCmdBindInvocationMaskHUAWEI" => (
+            35usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindInvocationMaskHUAWEI\0") },
+            cmd_bind_invocation_mask_huawei as *const c_void,
+        ),
+        b"vkCmdBindPipeline" => (
+            36usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindPipeline\0") },
+            cmd_bind_pipeline as *const c_void,
+        ),
+        b"vkCmdBindPipelineShaderGroupNV" => (
+            37usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindPipelineShaderGroupNV\0") },
+            cmd_bind_pipeline_shader_group_nv as *const c_void,
+        ),
+        b"vkCmdBindShadersEXT" => (
+            38usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindShadersEXT\0") },
+            cmd_bind_shaders_ext as *const c_void,
+        ),
+        b"vkCmdBindShadingRateImageNV" => (
+            39usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindShadingRateImageNV\0") },
+            cmd_bind_shading_rate_image_nv as *const c_void,
+        ),
+        b"vkCmdBindTransformFeedbackBuffersEXT" => (
+            40usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdBindTransformFeedbackBuffersEXT\0")
+            },
+            cmd_bind_transform_feedback_buffers_ext as *const c_void,
+        ),
+        b"vkCmdBindVertexBuffers" => (
+            41usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindVertexBuffers\0") },
+            cmd_bind_vertex_buffers as *const c_void,
+        ),
+        b"vkCmdBindVertexBuffers2" => (
+            42usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindVertexBuffers2\0") },
+            cmd_bind_vertex_buffers2 as *const c_void,
+        ),
+        b"vkCmdBindVertexBuffers2EXT" => (
+            42usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBindVertexBuffers2\0") },
+            cmd_bind_vertex_buffers2 as *const c_void,
+        ),
+        b"vkCmdBlitImage" => (
+            43usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBlitImage\0") },
+            cmd_blit_image as *const c_void,
+        ),
+        b"vkCmdBlitImage2" => (
+            44usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBlitImage2\0") },
+            cmd_blit_image2 as *const c_void,
+        ),
+        b"vkCmdBlitImage2KHR" => (
+            44usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBlitImage2\0") },
+            cmd_blit_image2 as *const c_void,
+        ),
+        b"vkCmdBuildAccelerationStructureNV" => (
+            45usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBuildAccelerationStructureNV\0") },
+            cmd_build_acceleration_structure_nv as *const c_void,
+        ),
+        b"vkCmdBuildAccelerationStructuresIndirectKHR" => (
+            46usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkCmdBuildAccelerationStructuresIndirectKHR\0",
+                )
+            },
+            cmd_build_acceleration_structures_indirect_khr as *const c_void,
+        ),
+        b"vkCmdBuildAccelerationStructuresKHR" => (
+            47usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdBuildAccelerationStructuresKHR\0")
+            },
+            cmd_build_acceleration_structures_khr as *const c_void,
+        ),
+        b"vkCmdBuildMicromapsEXT" => (
+            48usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdBuildMicromapsEXT\0") },
+            cmd_build_micromaps_ext as *const c_void,
+        ),
+        b"vkCmdClearAttachments" => (
+            49usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdClearAttachments\0") },
+            cmd_clear_attachments as *const c_void,
+        ),
+        b"vkCmdClearColorImage" => (
+            50usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdClearColorImage\0") },
+            cmd_clear_color_image as *const c_void,
+        ),
+        b"vkCmdClearDepthStencilImage" => (
+            51usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdClearDepthStencilImage\0") },
+            cmd_clear_depth_stencil_image as *const c_void,
+        ),
+        b"vkCmdControlVideoCodingKHR" => (
+            52usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdControlVideoCodingKHR\0") },
+            cmd_control_video_coding_khr as *const c_void,
+        ),
+        b"vkCmdCopyAccelerationStructureKHR" => (
+            53usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyAccelerationStructureKHR\0") },
+            cmd_copy_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCmdCopyAccelerationStructureNV" => (
+            54usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyAccelerationStructureNV\0") },
+            cmd_copy_acceleration_structure_nv as *const c_void,
+        ),
+        b"vkCmdCopyAccelerationStructureToMemoryKHR" => (
+            55usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyAccelerationStructureToMemoryKHR\0")
+            },
+            cmd_copy_acceleration_structure_to_memory_khr as *const c_void,
+        ),
+        b"vkCmdCopyBuffer" => (
+            56usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBuffer\0") },
+            cmd_copy_buffer as *const c_void,
+        ),
+        b"vkCmdCopyBuffer2" => (
+            57usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBuffer2\0") },
+            cmd_copy_buffer2 as *const c_void,
+        ),
+        b"vkCmdCopyBuffer2KHR" => (
+            57usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBuffer2\0") },
+            cmd_copy_buffer2 as *const c_void,
+        ),
+        b"vkCmdCopyBufferToImage" => (
+            58usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBufferToImage\0") },
+            cmd_copy_buffer_to_image as *const c_void,
+        ),
+        b"vkCmdCopyBufferToImage2" => (
+            59usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBufferToImage2\0") },
+            cmd_copy_buffer_to_image2 as *const c_void,
+        ),
+        b"vkCmdCopyBufferToImage2KHR" => (
+            59usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBufferToImage2\0") },
+            cmd_copy_buffer_to_image2 as *const c_void,
+        ),
+        b"vkCmdCopyImage" => (
+            60usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImage\0") },
+            cmd_copy_image as *const c_void,
+        ),
+        b"vkCmdCopyImage2" => (
+            61usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImage2\0") },
+            cmd_copy_image2 as *const c_void,
+        ),
+        b"vkCmdCopyImage2KHR" => (
+            61usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImage2\0") },
+            cmd_copy_image2 as *const c_void,
+        ),
+        b"vkCmdCopyImageToBuffer" => (
+            62usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImageToBuffer\0") },
+            cmd_copy_image_to_buffer as *const c_void,
+        ),
+        b"vkCmdCopyImageToBuffer2" => (
+            63usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImageToBuffer2\0") },
+            cmd_copy_image_to_buffer2 as *const c_void,
+        ),
+        b"vkCmdCopyImageToBuffer2KHR" => (
+            63usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImageToBuffer2\0") },
+            cmd_copy_image_to_buffer2 as *const c_void,
+        ),
+        b"vkCmdCopyMemoryIndirectNV" => (
+            64usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMemoryIndirectNV\0") },
+            cmd_copy_memory_indirect_nv as *const c_void,
+        ),
+        b"vkCmdCopyMemoryToAccelerationStructureKHR" => (
+            65usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMemoryToAccelerationStructureKHR\0")
+            },
+            cmd_copy_memory_to_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCmdCopyMemoryToImageIndirectNV" => (
+            66usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMemoryToImageIndirectNV\0") },
+            cmd_copy_memory_to_image_indirect_nv as *const c_void,
+        ),
+        b"vkCmdCopyMemoryToMicromapEXT" => (
+            67usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMemoryToMicromapEXT\0") },
+            cmd_copy_memory_to_micromap_ext as *const c_void,
+        ),
+        b"vkCmdCopyMicromapEXT" => (
+            68usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMicromapEXT\0") },
+            cmd_copy_micromap_ext as *const c_void,
+        ),
+        b"vkCmdCopyMicromapToMemoryEXT" => (
+            69usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMicromapToMemoryEXT\0") },
+            cmd_copy_micromap_to_memory_ext as *const c_void,
+        ),
+        b"vkCmdCopyQueryPoolResults" => (
+            70usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyQueryPoolResults\0") },
+            cmd_copy_query_pool_results as *const c_void,
+        ),
+        b"vkCmdCuLaunchKernelNVX" => (
+            71usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdCuLaunchKernelNVX\0") },
+            cmd_cu_launch_kernel_nvx as *const c_void,
+        ),
+        b"vkCmdDebugMarkerBeginEXT" => (
+            72usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDebugMarkerBeginEXT\0") },
+            cmd_debug_marker_begin_ext as *const c_void,
+        ),
+        b"vkCmdDebugMarkerEndEXT" => (
+            73usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDebugMarkerEndEXT\0") },
+            cmd_debug_marker_end_ext as *const c_void,
+        ),
+        b"vkCmdDebugMarkerInsertEXT" => (
+            74usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDebugMarkerInsertEXT\0") },
+            cmd_debug_marker_insert_ext as *const c_void,
+        ),
+        b"vkCmdDecodeVideoKHR" => (
+            75usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDecodeVideoKHR\0") },
+            cmd_decode_video_khr as *const c_void,
+        ),
+        b"vkCmdDecompressMemoryIndirectCountNV" => (
+            76usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdDecompressMemoryIndirectCountNV\0")
+            },
+            cmd_decompress_memory_indirect_count_nv as *const c_void,
+        ),
+        b"vkCmdDecompressMemoryNV" => (
+            77usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDecompressMemoryNV\0") },
+            cmd_decompress_memory_nv as *const c_void,
+        ),
+        b"vkCmdDispatch" => (
+            78usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatch\0") },
+            cmd_dispatch as *const c_void,
+        ),
+        b"vkCmdDispatchBase" => (
+            79usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatchBase\0") },
+            cmd_dispatch_base as *const c_void,
+        ),
+        b"vkCmdDispatchBaseKHR" => (
+            79usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatchBase\0") },
+            cmd_dispatch_base as *const c_void,
+        ),
+        b"vkCmdDispatchIndirect" => (
+            80usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatchIndirect\0") },
+            cmd_dispatch_indirect as *const c_void,
+        ),
+        b"vkCmdDraw" => (
+            81usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDraw\0") },
+            cmd_draw as *const c_void,
+        ),
+        b"vkCmdDrawClusterHUAWEI" => (
+            82usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawClusterHUAWEI\0") },
+            cmd_draw_cluster_huawei as *const c_void,
+        ),
+        b"vkCmdDrawClusterIndirectHUAWEI" => (
+            83usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawClusterIndirectHUAWEI\0") },
+            cmd_draw_cluster_indirect_huawei as *const c_void,
+        ),
+        b"vkCmdDrawIndexed" => (
+            84usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexed\0") },
+            cmd_draw_indexed as *const c_void,
+        ),
+        b"vkCmdDrawIndexedIndirect" => (
+            85usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexedIndirect\0") },
+            cmd_draw_indexed_indirect as *const c_void,
+        ),
+        b"vkCmdDrawIndexedIndirectCount" => (
+            86usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexedIndirectCount\0") },
+            cmd_draw_indexed_indirect_count as *const c_void,
+        ),
+        b"vkCmdDrawIndexedIndirectCountAMD" => (
+            86usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexedIndirectCount\0") },
+            cmd_draw_indexed_indirect_count as *const c_void,
+        ),
+        b"vkCmdDrawIndexedIndirectCountKHR" => (
+            86usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexedIndirectCount\0") },
+            cmd_draw_indexed_indirect_count as *const c_void,
+        ),
+        b"vkCmdDrawIndirect" => (
+            87usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirect\0") },
+            cmd_draw_indirect as *const c_void,
+        ),
+        b"vkCmdDrawIndirectByteCountEXT" => (
+            88usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirectByteCountEXT\0") },
+            cmd_draw_indirect_byte_count_ext as *const c_void,
+        ),
+        b"vkCmdDrawIndirectCount" => (
+            89usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirectCount\0") },
+            cmd_draw_indirect_count as *const c_void,
+        ),
+        b"vkCmdDrawIndirectCountAMD" => (
+            89usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirectCount\0") },
+            cmd_draw_indirect_count as *const c_void,
+        ),
+        b"vkCmdDrawIndirectCountKHR" => (
+            89usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirectCount\0") },
+            cmd_draw_indirect_count as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksEXT" => (
+            90usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksEXT\0") },
+            cmd_draw_mesh_tasks_ext as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksIndirectCountEXT" => (
+            91usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksIndirectCountEXT\0") },
+            cmd_draw_mesh_tasks_indirect_count_ext as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksIndirectCountNV" => (
+            92usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksIndirectCountNV\0") },
+            cmd_draw_mesh_tasks_indirect_count_nv as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksIndirectEXT" => (
+            93usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksIndirectEXT\0") },
+            cmd_draw_mesh_tasks_indirect_ext as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksIndirectNV" => (
+            94usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksIndirectNV\0") },
+            cmd_draw_mesh_tasks_indirect_nv as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksNV" => (
+            95usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksNV\0") },
+            cmd_draw_mesh_tasks_nv as *const c_void,
+        ),
+        b"vkCmdDrawMultiEXT" => (
+            96usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMultiEXT\0") },
+            cmd_draw_multi_ext as *const c_void,
+        ),
+        b"vkCmdDrawMultiIndexedEXT" => (
+            97usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMultiIndexedEXT\0") },
+            cmd_draw_multi_indexed_ext as *const c_void,
+        ),
+        b"vkCmdEncodeVideoKHR" => (
+            98usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEncodeVideoKHR\0") },
+            cmd_encode_video_khr as *const c_void,
+        ),
+        b"vkCmdEndConditionalRenderingEXT" => (
+            99usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndConditionalRenderingEXT\0") },
+            cmd_end_conditional_rendering_ext as *const c_void,
+        ),
+        b"vkCmdEndDebugUtilsLabelEXT" => (
+            100usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndDebugUtilsLabelEXT\0") },
+            cmd_end_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkCmdEndQuery" => (
+            101usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndQuery\0") },
+            cmd_end_query as *const c_void,
+        ),
+        b"vkCmdEndQueryIndexedEXT" => (
+            102usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndQueryIndexedEXT\0") },
+            cmd_end_query_indexed_ext as *const c_void,
+        ),
+        b"vkCmdEndRenderPass" => (
+            103usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRenderPass\0") },
+            cmd_end_render_pass as *const c_void,
+        ),
+        b"vkCmdEndRenderPass2" => (
+            104usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRenderPass2\0") },
+            cmd_end_render_pass2 as *const c_void,
+        ),
+        b"vkCmdEndRenderPass2KHR" => (
+            104usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRenderPass2\0") },
+            cmd_end_render_pass2 as *const c_void,
+        ),
+        b"vkCmdEndRendering" => (
+            105usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRendering\0") },
+            cmd_end_rendering as *const c_void,
+        ),
+        b"vkCmdEndRenderingKHR" => (
+            105usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRendering\0") },
+            cmd_end_rendering as *const c_void,
+        ),
+        b"vkCmdEndTransformFeedbackEXT" => (
+            106usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndTransformFeedbackEXT\0") },
+            cmd_end_transform_feedback_ext as *const c_void,
+        ),
+        b"vkCmdEndVideoCodingKHR" => (
+            107usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdEndVideoCodingKHR\0") },
+            cmd_end_video_coding_khr as *const c_void,
+        ),
+        b"vkCmdExecuteCommands" => (
+            108usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdExecuteCommands\0") },
+            cmd_execute_commands as *const c_void,
+        ),
+        b"vkCmdExecuteGeneratedCommandsNV" => (
+            109usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdExecuteGeneratedCommandsNV\0") },
+            cmd_execute_generated_commands_nv as *const c_void,
+        ),
+        b"vkCmdFillBuffer" => (
+            110usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdFillBuffer\0") },
+            cmd_fill_buffer as *const c_void,
+        ),
+        b"vkCmdInsertDebugUtilsLabelEXT" => (
+            111usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdInsertDebugUtilsLabelEXT\0") },
+            cmd_insert_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkCmdNextSubpass" => (
+            112usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdNextSubpass\0") },
+            cmd_next_subpass as *const c_void,
+        ),
+        b"vkCmdNextSubpass2" => (
+            113usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdNextSubpass2\0") },
+            cmd_next_subpass2 as *const c_void,
+        ),
+        b"vkCmdNextSubpass2KHR" => (
+            113usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdNextSubpass2\0") },
+            cmd_next_subpass2 as *const c_void,
+        ),
+        b"vkCmdOpticalFlowExecuteNV" => (
+            114usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdOpticalFlowExecuteNV\0") },
+            cmd_optical_flow_execute_nv as *const c_void,
+        ),
+        b"vkCmdPipelineBarrier" => (
+            115usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdPipelineBarrier\0") },
+            cmd_pipeline_barrier as *const c_void,
+        ),
+        b"vkCmdPipelineBarrier2" => (
+            116usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdPipelineBarrier2\0") },
+            cmd_pipeline_barrier2 as *const c_void,
+        ),
+        b"vkCmdPipelineBarrier2KHR" => (
+            116usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdPipelineBarrier2\0") },
+            cmd_pipeline_barrier2 as *const c_void,
+        ),
+        b"vkCmdPreprocessGeneratedCommandsNV" => (
+            117usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdPreprocessGeneratedCommandsNV\0") },
+            cmd_preprocess_generated_commands_nv as *const c_void,
+        ),
+        b"vkCmdPushConstants" => (
+            118usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdPushConstants\0") },
+            cmd_push_constants as *const c_void,
+        ),
+        b"vkCmdPushDescriptorSetKHR" => (
+            119usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdPushDescriptorSetKHR\0") },
+            cmd_push_descriptor_set_khr as *const c_void,
+        ),
+        b"vkCmdPushDescriptorSetWithTemplateKHR" => (
+            120usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdPushDescriptorSetWithTemplateKHR\0")
+            },
+            cmd_push_descriptor_set_with_template_khr as *const c_void,
+        ),
+        b"vkCmdResetEvent" => (
+            121usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdResetEvent\0") },
+            cmd_reset_event as *const c_void,
+        ),
+        b"vkCmdResetEvent2" => (
+            122usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdResetEvent2\0") },
+            cmd_reset_event2 as *const c_void,
+        ),
+        b"vkCmdResetEvent2KHR" => (
+            122usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdResetEvent2\0") },
+            cmd_reset_event2 as *const c_void,
+        ),
+        b"vkCmdResetQueryPool" => (
+            123usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdResetQueryPool\0") },
+            cmd_reset_query_pool as *const c_void,
+        ),
+        b"vkCmdResolveImage" => (
+            124usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdResolveImage\0") },
+            cmd_resolve_image as *const c_void,
+        ),
+        b"vkCmdResolveImage2" => (
+            125usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdResolveImage2\0") },
+            cmd_resolve_image2 as *const c_void,
+        ),
+        b"vkCmdResolveImage2KHR" => (
+            125usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdResolveImage2\0") },
+            cmd_resolve_image2 as *const c_void,
+        ),
+        b"vkCmdSetAlphaToCoverageEnableEXT" => (
+            126usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetAlphaToCoverageEnableEXT\0") },
+            cmd_set_alpha_to_coverage_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetAlphaToOneEnableEXT" => (
+            127usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetAlphaToOneEnableEXT\0") },
+            cmd_set_alpha_to_one_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetAttachmentFeedbackLoopEnableEXT" => (
+            128usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdSetAttachmentFeedbackLoopEnableEXT\0")
+            },
+            cmd_set_attachment_feedback_loop_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetBlendConstants" => (
+            129usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetBlendConstants\0") },
+            cmd_set_blend_constants as *const c_void,
+        ),
+        b"vkCmdSetCheckpointNV" => (
+            130usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCheckpointNV\0") },
+            cmd_set_checkpoint_nv as *const c_void,
+        ),
+        b"vkCmdSetCoarseSampleOrderNV" => (
+            131usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoarseSampleOrderNV\0") },
+            cmd_set_coarse_sample_order_nv as *const c_void,
+        ),
+        b"vkCmdSetColorBlendAdvancedEXT" => (
+            132usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorBlendAdvancedEXT\0") },
+            cmd_set_color_blend_advanced_ext as *const c_void,
+        ),
+        b"vkCmdSetColorBlendEnableEXT" => (
+            133usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorBlendEnableEXT\0") },
+            cmd_set_color_blend_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetColorBlendEquationEXT" => (
+            134usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorBlendEquationEXT\0") },
+            cmd_set_color_blend_equation_ext as *const c_void,
+        ),
+        b"vkCmdSetColorWriteEnableEXT" => (
+            135usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorWriteEnableEXT\0") },
+            cmd_set_color_write_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetColorWriteMaskEXT" => (
+            136usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorWriteMaskEXT\0") },
+            cmd_set_color_write_mask_ext as *const c_void,
+        ),
+        b"vkCmdSetConservativeRasterizationModeEXT" => (
+            137usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdSetConservativeRasterizationModeEXT\0")
+            },
+            cmd_set_conservative_rasterization_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetCoverageModulationModeNV" => (
+            138usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageModulationModeNV\0") },
+            cmd_set_coverage_modulation_mode_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageModulationTableEnableNV" => (
+            139usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageModulationTableEnableNV\0")
+            },
+            cmd_set_coverage_modulation_table_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageModulationTableNV" => (
+            140usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageModulationTableNV\0") },
+            cmd_set_coverage_modulation_table_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageReductionModeNV" => (
+            141usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageReductionModeNV\0") },
+            cmd_set_coverage_reduction_mode_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageToColorEnableNV" => (
+            142usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageToColorEnableNV\0") },
+            cmd_set_coverage_to_color_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageToColorLocationNV" => (
+            143usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageToColorLocationNV\0") },
+            cmd_set_coverage_to_color_location_nv as *const c_void,
+        ),
+        b"vkCmdSetCullMode" => (
+            144usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCullMode\0") },
+            cmd_set_cull_mode as *const c_void,
+        ),
+        b"vkCmdSetCullModeEXT" => (
+            144usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCullMode\0") },
+            cmd_set_cull_mode as *const c_void,
+        ),
+        b"vkCmdSetDepthBias" => (
+            145usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBias\0") },
+            cmd_set_depth_bias as *const c_void,
+        ),
+        b"vkCmdSetDepthBias2EXT" => (
+            146usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBias2EXT\0") },
+            cmd_set_depth_bias2_ext as *const c_void,
+        ),
+        b"vkCmdSetDepthBiasEnable" => (
+            147usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBiasEnable\0") },
+            cmd_set_depth_bias_enable as *const c_void,
+        ),
+        b"vkCmdSetDepthBiasEnableEXT" => (
+            147usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBiasEnable\0") },
+            cmd_set_depth_bias_enable as *const c_void,
+        ),
+        b"vkCmdSetDepthBounds" => (
+            148usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBounds\0") },
+            cmd_set_depth_bounds as *const c_void,
+        ),
+        b"vkCmdSetDepthBoundsTestEnable" => (
+            149usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBoundsTestEnable\0") },
+            cmd_set_depth_bounds_test_enable as *const c_void,
+        ),
+        b"vkCmdSetDepthBoundsTestEnableEXT" => (
+            149usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBoundsTestEnable\0") },
+            cmd_set_depth_bounds_test_enable as *const c_void,
+        ),
+        b"vkCmdSetDepthClampEnableEXT" => (
+            150usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthClampEnableEXT\0") },
+            cmd_set_depth_clamp_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetDepthClipEnableEXT" => (
+            151usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthClipEnableEXT\0") },
+            cmd_set_depth_clip_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetDepthClipNegativeOneToOneEXT" => (
+            152usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthClipNegativeOneToOneEXT\0")
+            },
+            cmd_set_depth_clip_negative_one_to_one_ext as *const c_void,
+        ),
+        b"vkCmdSetDepthCompareOp" => (
+            153usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthCompareOp\0") },
+            cmd_set_depth_compare_op as *const c_void,
+        ),
+        b"vkCmdSetDepthCompareOpEXT" => (
+            153usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthCompareOp\0") },
+            cmd_set_depth_compare_op as *const c_void,
+        ),
+        b"vkCmdSetDepthTestEnable" => (
+            154usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthTestEnable\0") },
+            cmd_set_depth_test_enable as *const c_void,
+        ),
+        b"vkCmdSetDepthTestEnableEXT" => (
+            154usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthTestEnable\0") },
+            cmd_set_depth_test_enable as *const c_void,
+        ),
+        b"vkCmdSetDepthWriteEnable" => (
+            155usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthWriteEnable\0") },
+            cmd_set_depth_write_enable as *const c_void,
+        ),
+        b"vkCmdSetDepthWriteEnableEXT" => (
+            155usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthWriteEnable\0") },
+            cmd_set_depth_write_enable as *const c_void,
+        ),
+        b"vkCmdSetDescriptorBufferOffsetsEXT" => (
+            156usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDescriptorBufferOffsetsEXT\0") },
+            cmd_set_descriptor_buffer_offsets_ext as *const c_void,
+        ),
+        b"vkCmdSetDeviceMask" => (
+            157usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDeviceMask\0") },
+            cmd_set_device_mask as *const c_void,
+        ),
+        b"vkCmdSetDeviceMaskKHR" => (
+            157usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDeviceMask\0") },
+            cmd_set_device_mask as *const c_void,
+        ),
+        b"vkCmdSetDiscardRectangleEXT" => (
+            158usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDiscardRectangleEXT\0") },
+            cmd_set_discard_rectangle_ext as *const c_void,
+        ),
+        b"vkCmdSetDiscardRectangleEnableEXT" => (
+            159usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDiscardRectangleEnableEXT\0") },
+            cmd_set_discard_rectangle_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetDiscardRectangleModeEXT" => (
+            160usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDiscardRectangleModeEXT\0") },
+            cmd_set_discard_rectangle_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetEvent" => (
+            161usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetEvent\0") },
+            cmd_set_event as *const c_void,
+        ),
+        b"vkCmdSetEvent2" => (
+            162usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetEvent2\0") },
+            cmd_set_event2 as *const c_void,
+        ),
+        b"vkCmdSetEvent2KHR" => (
+            162usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetEvent2\0") },
+            cmd_set_event2 as *const c_void,
+        ),
+        b"vkCmdSetExclusiveScissorEnableNV" => (
+            163usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetExclusiveScissorEnableNV\0") },
+            cmd_set_exclusive_scissor_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetExclusiveScissorNV" => (
+            164usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetExclusiveScissorNV\0") },
+            cmd_set_exclusive_scissor_nv as *const c_void,
+        ),
+        b"vkCmdSetExtraPrimitiveOverestimationSizeEXT" => (
+            165usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkCmdSetExtraPrimitiveOverestimationSizeEXT\0",
+                )
+            },
+            cmd_set_extra_primitive_overestimation_size_ext as *const c_void,
+        ),
+        b"vkCmdSetFragmentShadingRateEnumNV" => (
+            166usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetFragmentShadingRateEnumNV\0") },
+            cmd_set_fragment_shading_rate_enum_nv as *const c_void,
+        ),
+        b"vkCmdSetFragmentShadingRateKHR" => (
+            167usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetFragmentShadingRateKHR\0") },
+            cmd_set_fragment_shading_rate_khr as *const c_void,
+        ),
+        b"vkCmdSetFrontFace" => (
+            168usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetFrontFace\0") },
+            cmd_set_front_face as *const c_void,
+        ),
+        b"vkCmdSetFrontFaceEXT" => (
+            168usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetFrontFace\0") },
+            cmd_set_front_face as *const c_void,
+        ),
+        b"vkCmdSetLineRasterizationModeEXT" => (
+            169usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLineRasterizationModeEXT\0") },
+            cmd_set_line_rasterization_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetLineStippleEXT" => (
+            170usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLineStippleEXT\0") },
+            cmd_set_line_stipple_ext as *const c_void,
+        ),
+        b"vkCmdSetLineStippleEnableEXT" => (
+            171usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLineStippleEnableEXT\0") },
+            cmd_set_line_stipple_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetLineWidth" => (
+            172usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLineWidth\0") },
+            cmd_set_line_width as *const c_void,
+        ),
+        b"vkCmdSetLogicOpEXT" => (
+            173usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLogicOpEXT\0") },
+            cmd_set_logic_op_ext as *const c_void,
+        ),
+        b"vkCmdSetLogicOpEnableEXT" => (
+            174usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLogicOpEnableEXT\0") },
+            cmd_set_logic_op_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetPatchControlPointsEXT" => (
+            175usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPatchControlPointsEXT\0") },
+            cmd_set_patch_control_points_ext as *const c_void,
+        ),
+        b"vkCmdSetPerformanceMarkerINTEL" => (
+            176usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPerformanceMarkerINTEL\0") },
+            cmd_set_performance_marker_intel as *const c_void,
+        ),
+        b"vkCmdSetPerformanceOverrideINTEL" => (
+            177usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPerformanceOverrideINTEL\0") },
+            cmd_set_performance_override_intel as *const c_void,
+        ),
+        b"vkCmdSetPerformanceStreamMarkerINTEL" => (
+            178usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPerformanceStreamMarkerINTEL\0")
+            },
+            cmd_set_performance_stream_marker_intel as *const c_void,
+        ),
+        b"vkCmdSetPolygonModeEXT" => (
+            179usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPolygonModeEXT\0") },
+            cmd_set_polygon_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetPrimitiveRestartEnable" => (
+            180usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPrimitiveRestartEnable\0") },
+            cmd_set_primitive_restart_enable as *const c_void,
+        ),
+        b"vkCmdSetPrimitiveRestartEnableEXT" => (
+            180usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPrimitiveRestartEnable\0") },
+            cmd_set_primitive_restart_enable as *const c_void,
+        ),
+        b"vkCmdSetPrimitiveTopology" => (
+            181usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPrimitiveTopology\0") },
+            cmd_set_primitive_topology as *const c_void,
+        ),
+        b"vkCmdSetPrimitiveTopologyEXT" => (
+            181usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPrimitiveTopology\0") },
+            cmd_set_primitive_topology as *const c_void,
+        ),
+        b"vkCmdSetProvokingVertexModeEXT" => (
+            182usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetProvokingVertexModeEXT\0") },
+            cmd_set_provoking_vertex_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetRasterizationSamplesEXT" => (
+            183usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRasterizationSamplesEXT\0") },
+            cmd_set_rasterization_samples_ext as *const c_void,
+        ),
+        b"vkCmdSetRasterizationStreamEXT" => (
+            184usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRasterizationStreamEXT\0") },
+            cmd_set_rasterization_stream_ext as *const c_void,
+        ),
+        b"vkCmdSetRasterizerDiscardEnable" => (
+            185usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRasterizerDiscardEnable\0") },
+            cmd_set_rasterizer_discard_enable as *const c_void,
+        ),
+        b"vkCmdSetRasterizerDiscardEnableEXT" => (
+            185usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRasterizerDiscardEnable\0") },
+            cmd_set_rasterizer_discard_enable as *const c_void,
+        ),
+        b"vkCmdSetRayTracingPipelineStackSizeKHR" => (
+            186usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRayTracingPipelineStackSizeKHR\0")
+            },
+            cmd_set_ray_tracing_pipeline_stack_size_khr as *const c_void,
+        ),
+        b"vkCmdSetRepresentativeFragmentTestEnableNV" => (
+            187usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRepresentativeFragmentTestEnableNV\0")
+            },
+            cmd_set_representative_fragment_test_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetSampleLocationsEXT" => (
+            188usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetSampleLocationsEXT\0") },
+            cmd_set_sample_locations_ext as *const c_void,
+        ),
+        b"vkCmdSetSampleLocationsEnableEXT" => (
+            189usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetSampleLocationsEnableEXT\0") },
+            cmd_set_sample_locations_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetSampleMaskEXT" => (
+            190usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetSampleMaskEXT\0") },
+            cmd_set_sample_mask_ext as *const c_void,
+        ),
+        b"vkCmdSetScissor" => (
+            191usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetScissor\0") },
+            cmd_set_scissor as *const c_void,
+        ),
+        b"vkCmdSetScissorWithCount" => (
+            192usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetScissorWithCount\0") },
+            cmd_set_scissor_with_count as *const c_void,
+        ),
+        b"vkCmdSetScissorWithCountEXT" => (
+            192usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetScissorWithCount\0") },
+            cmd_set_scissor_with_count as *const c_void,
+        ),
+        b"vkCmdSetShadingRateImageEnableNV" => (
+            193usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetShadingRateImageEnableNV\0") },
+            cmd_set_shading_rate_image_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetStencilCompareMask" => (
+            194usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilCompareMask\0") },
+            cmd_set_stencil_compare_mask as *const c_void,
+        ),
+        b"vkCmdSetStencilOp" => (
+            195usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilOp\0") },
+            cmd_set_stencil_op as *const c_void,
+        ),
+        b"vkCmdSetStencilOpEXT" => (
+            195usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilOp\0") },
+            cmd_set_stencil_op as *const c_void,
+        ),
+        b"vkCmdSetStencilReference" => (
+            196usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilReference\0") },
+            cmd_set_stencil_reference as *const c_void,
+        ),
+        b"vkCmdSetStencilTestEnable" => (
+            197usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilTestEnable\0") },
+            cmd_set_stencil_test_enable as *const c_void,
+        ),
+        b"vkCmdSetStencilTestEnableEXT" => (
+            197usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilTestEnable\0") },
+            cmd_set_stencil_test_enable as *const c_void,
+        ),
+        b"vkCmdSetStencilWriteMask" => (
+            198usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilWriteMask\0") },
+            cmd_set_stencil_write_mask as *const c_void,
+        ),
+        b"vkCmdSetTessellationDomainOriginEXT" => (
+            199usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdSetTessellationDomainOriginEXT\0")
+            },
+            cmd_set_tessellation_domain_origin_ext as *const c_void,
+        ),
+        b"vkCmdSetVertexInputEXT" => (
+            200usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetVertexInputEXT\0") },
+            cmd_set_vertex_input_ext as *const c_void,
+        ),
+        b"vkCmdSetViewport" => (
+            201usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewport\0") },
+            cmd_set_viewport as *const c_void,
+        ),
+        b"vkCmdSetViewportShadingRatePaletteNV" => (
+            202usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportShadingRatePaletteNV\0")
+            },
+            cmd_set_viewport_shading_rate_palette_nv as *const c_void,
+        ),
+        b"vkCmdSetViewportSwizzleNV" => (
+            203usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportSwizzleNV\0") },
+            cmd_set_viewport_swizzle_nv as *const c_void,
+        ),
+        b"vkCmdSetViewportWScalingEnableNV" => (
+            204usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportWScalingEnableNV\0") },
+            cmd_set_viewport_w_scaling_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetViewportWScalingNV" => (
+            205usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportWScalingNV\0") },
+            cmd_set_viewport_w_scaling_nv as *const c_void,
+        ),
+        b"vkCmdSetViewportWithCount" => (
+            206usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportWithCount\0") },
+            cmd_set_viewport_with_count as *const c_void,
+        ),
+        b"vkCmdSetViewportWithCountEXT" => (
+            206usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportWithCount\0") },
+            cmd_set_viewport_with_count as *const c_void,
+        ),
+        b"vkCmdSubpassShadingHUAWEI" => (
+            207usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdSubpassShadingHUAWEI\0") },
+            cmd_subpass_shading_huawei as *const c_void,
+        ),
+        b"vkCmdTraceRaysIndirect2KHR" => (
+            208usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysIndirect2KHR\0") },
+            cmd_trace_rays_indirect2_khr as *const c_void,
+        ),
+        b"vkCmdTraceRaysIndirectKHR" => (
+            209usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysIndirectKHR\0") },
+            cmd_trace_rays_indirect_khr as *const c_void,
+        ),
+        b"vkCmdTraceRaysKHR" => (
+            210usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysKHR\0") },
+            cmd_trace_rays_khr as *const c_void,
+        ),
+        b"vkCmdTraceRaysNV" => (
+            211usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysNV\0") },
+            cmd_trace_rays_nv as *const c_void,
+        ),
+        b"vkCmdUpdateBuffer" => (
+            212usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdUpdateBuffer\0") },
+            cmd_update_buffer as *const c_void,
+        ),
+        b"vkCmdWaitEvents" => (
+            213usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdWaitEvents\0") },
+            cmd_wait_events as *const c_void,
+        ),
+        b"vkCmdWaitEvents2" => (
+            214usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdWaitEvents2\0") },
+            cmd_wait_events2 as *const c_void,
+        ),
+        b"vkCmdWaitEvents2KHR" => (
+            214usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdWaitEvents2\0") },
+            cmd_wait_events2 as *const c_void,
+        ),
+        b"vkCmdWriteAccelerationStructuresPropertiesKHR" => (
+            215usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkCmdWriteAccelerationStructuresPropertiesKHR\0",
+                )
+            },
+            cmd_write_acceleration_structures_properties_khr as *const c_void,
+        ),
+        b"vkCmdWriteAccelerationStructuresPropertiesNV" => (
+            216usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkCmdWriteAccelerationStructuresPropertiesNV\0",
+                )
+            },
+            cmd_write_acceleration_structures_properties_nv as *const c_void,
+        ),
+        b"vkCmdWriteBufferMarker2AMD" => (
+            217usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteBufferMarker2AMD\0") },
+            cmd_write_buffer_marker2_amd as *const c_void,
+        ),
+        b"vkCmdWriteBufferMarkerAMD" => (
+            218usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteBufferMarkerAMD\0") },
+            cmd_write_buffer_marker_amd as *const c_void,
+        ),
+        b"vkCmdWriteMicromapsPropertiesEXT" => (
+            219usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteMicromapsPropertiesEXT\0") },
+            cmd_write_micromaps_properties_ext as *const c_void,
+        ),
+        b"vkCmdWriteTimestamp" => (
+            220usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteTimestamp\0") },
+            cmd_write_timestamp as *const c_void,
+        ),
+        b"vkCmdWriteTimestamp2" => (
+            221usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteTimestamp2\0") },
+            cmd_write_timestamp2 as *const c_void,
+        ),
+        b"vkCmdWriteTimestamp2KHR" => (
+            221usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteTimestamp2\0") },
+            cmd_write_timestamp2 as *const c_void,
+        ),
+        b"vkCompileDeferredNV" => (
+            222usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCompileDeferredNV\0") },
+            compile_deferred_nv as *const c_void,
+        ),
+        b"vkCopyAccelerationStructureKHR" => (
+            223usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCopyAccelerationStructureKHR\0") },
+            copy_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCopyAccelerationStructureToMemoryKHR" => (
+            224usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCopyAccelerationStructureToMemoryKHR\0")
+            },
+            copy_acceleration_structure_to_memory_khr as *const c_void,
+        ),
+        b"vkCopyMemoryToAccelerationStructureKHR" => (
+            225usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkCopyMemoryToAccelerationStructureKHR\0")
+            },
+            copy_memory_to_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCopyMemoryToMicromapEXT" => (
+            226usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCopyMemoryToMicromapEXT\0") },
+            copy_memory_to_micromap_ext as *const c_void,
+        ),
+        b"vkCopyMicromapEXT" => (
+            227usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCopyMicromapEXT\0") },
+            copy_micromap_ext as *const c_void,
+        ),
+        b"vkCopyMicromapToMemoryEXT" => (
+            228usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCopyMicromapToMemoryEXT\0") },
+            copy_micromap_to_memory_ext as *const c_void,
+        ),
+        b"vkCreateAccelerationStructureKHR" => (
+            229usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateAccelerationStructureKHR\0") },
+            create_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCreateAccelerationStructureNV" => (
+            230usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateAccelerationStructureNV\0") },
+            create_acceleration_structure_nv as *const c_void,
+        ),
+        b"vkCreateAndroidSurfaceKHR" => (
+            231usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateAndroidSurfaceKHR\0") },
+            create_android_surface_khr as *const c_void,
+        ),
+        b"vkCreateBuffer" => (
+            232usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateBuffer\0") },
+            create_buffer as *const c_void,
+        ),
+        b"vkCreateBufferCollectionFUCHSIA" => (
+            233usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateBufferCollectionFUCHSIA\0") },
+            create_buffer_collection_fuchsia as *const c_void,
+        ),
+        b"vkCreateBufferView" => (
+            234usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateBufferView\0") },
+            create_buffer_view as *const c_void,
+        ),
+        b"vkCreateCommandPool" => (
+            235usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateCommandPool\0") },
+            create_command_pool as *const c_void,
+        ),
+        b"vkCreateComputePipelines" => (
+            236usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateComputePipelines\0") },
+            create_compute_pipelines as *const c_void,
+        ),
+        b"vkCreateCuFunctionNVX" => (
+            237usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateCuFunctionNVX\0") },
+            create_cu_function_nvx as *const c_void,
+        ),
+        b"vkCreateCuModuleNVX" => (
+            238usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateCuModuleNVX\0") },
+            create_cu_module_nvx as *const c_void,
+        ),
+        b"vkCreateDebugReportCallbackEXT" => (
+            239usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDebugReportCallbackEXT\0") },
+            create_debug_report_callback_ext as *const c_void,
+        ),
+        b"vkCreateDebugUtilsMessengerEXT" => (
+            240usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDebugUtilsMessengerEXT\0") },
+            create_debug_utils_messenger_ext as *const c_void,
+        ),
+        b"vkCreateDeferredOperationKHR" => (
+            241usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDeferredOperationKHR\0") },
+            create_deferred_operation_khr as *const c_void,
+        ),
+        b"vkCreateDescriptorPool" => (
+            242usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDescriptorPool\0") },
+            create_descriptor_pool as *const c_void,
+        ),
+        b"vkCreateDescriptorSetLayout" => (
+            243usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDescriptorSetLayout\0") },
+            create_descriptor_set_layout as *const c_void,
+        ),
+        b"vkCreateDescriptorUpdateTemplate" => (
+            244usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDescriptorUpdateTemplate\0") },
+            create_descriptor_update_template as *const c_void,
+        ),
+        b"vkCreateDescriptorUpdateTemplateKHR" => (
+            244usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDescriptorUpdateTemplate\0") },
+            create_descriptor_update_template as *const c_void,
+        ),
+        b"vkCreateDevice" => (
+            245usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDevice\0") },
+            create_device as *const c_void,
+        ),
+        b"vkCreateDirectFBSurfaceEXT" => (
+            246usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDirectFBSurfaceEXT\0") },
+            create_direct_fb_surface_ext as *const c_void,
+        ),
+        b"vkCreateDisplayModeKHR" => (
+            247usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDisplayModeKHR\0") },
+            create_display_mode_khr as *const c_void,
+        ),
+        b"vkCreateDisplayPlaneSurfaceKHR" => (
+            248usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateDisplayPlaneSurfaceKHR\0") },
+            create_display_plane_surface_khr as *const c_void,
+        ),
+        b"vkCreateEvent" => (
+            249usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateEvent\0") },
+            create_event as *const c_void,
+        ),
+        b"vkCreateFence" => (
+            250usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateFence\0") },
+            create_fence as *const c_void,
+        ),
+        b"vkCreateFramebuffer" => (
+            251usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateFramebuffer\0") },
+            create_framebuffer as *const c_void,
+        ),
+        b"vkCreateGraphicsPipelines" => (
+            252usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateGraphicsPipelines\0") },
+            create_graphics_pipelines as *const c_void,
+        ),
+        b"vkCreateHeadlessSurfaceEXT" => (
+            253usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateHeadlessSurfaceEXT\0") },
+            create_headless_surface_ext as *const c_void,
+        ),
+        b"vkCreateIOSSurfaceMVK" => (
+            254usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateIOSSurfaceMVK\0") },
+            create_ios_surface_mvk as *const c_void,
+        ),
+        b"vkCreateImage" => (
+            255usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateImage\0") },
+            create_image as *const c_void,
+        ),
+        b"vkCreateImagePipeSurfaceFUCHSIA" => (
+            256usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateImagePipeSurfaceFUCHSIA\0") },
+            create_image_pipe_surface_fuchsia as *const c_void,
+        ),
+        b"vkCreateImageView" => (
+            257usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateImageView\0") },
+            create_image_view as *const c_void,
+        ),
+        b"vkCreateIndirectCommandsLayoutNV" => (
+            258usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateIndirectCommandsLayoutNV\0") },
+            create_indirect_commands_layout_nv as *const c_void,
+        ),
+        b"vkCreateInstance" => (
+            259usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateInstance\0") },
+            create_instance as *const c_void,
+        ),
+        b"vkCreateMacOSSurfaceMVK" => (
+            260usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateMacOSSurfaceMVK\0") },
+            create_mac_os_surface_mvk as *const c_void,
+        ),
+        b"vkCreateMetalSurfaceEXT" => (
+            261usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateMetalSurfaceEXT\0") },
+            create_metal_surface_ext as *const c_void,
+        ),
+        b"vkCreateMicromapEXT" => (
+            262usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateMicromapEXT\0") },
+            create_micromap_ext as *const c_void,
+        ),
+        b"vkCreateOpticalFlowSessionNV" => (
+            263usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateOpticalFlowSessionNV\0") },
+            create_optical_flow_session_nv as *const c_void,
+        ),
+        b"vkCreatePipelineCache" => (
+            264usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreatePipelineCache\0") },
+            create_pipeline_cache as *const c_void,
+        ),
+        b"vkCreatePipelineLayout" => (
+            265usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreatePipelineLayout\0") },
+            create_pipeline_layout as *const c_void,
+        ),
+        b"vkCreatePrivateDataSlot" => (
+            266usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreatePrivateDataSlot\0") },
+            create_private_data_slot as *const c_void,
+        ),
+        b"vkCreatePrivateDataSlotEXT" => (
+            266usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreatePrivateDataSlot\0") },
+            create_private_data_slot as *const c_void,
+        ),
+        b"vkCreateQueryPool" => (
+            267usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateQueryPool\0") },
+            create_query_pool as *const c_void,
+        ),
+        b"vkCreateRayTracingPipelinesKHR" => (
+            268usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateRayTracingPipelinesKHR\0") },
+            create_ray_tracing_pipelines_khr as *const c_void,
+        ),
+        b"vkCreateRayTracingPipelinesNV" => (
+            269usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateRayTracingPipelinesNV\0") },
+            create_ray_tracing_pipelines_nv as *const c_void,
+        ),
+        b"vkCreateRenderPass" => (
+            270usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateRenderPass\0") },
+            create_render_pass as *const c_void,
+        ),
+        b"vkCreateRenderPass2" => (
+            271usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateRenderPass2\0") },
+            create_render_pass2 as *const c_void,
+        ),
+        b"vkCreateRenderPass2KHR" => (
+            271usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateRenderPass2\0") },
+            create_render_pass2 as *const c_void,
+        ),
+        b"vkCreateSampler" => (
+            272usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateSampler\0") },
+            create_sampler as *const c_void,
+        ),
+        b"vkCreateSamplerYcbcrConversion" => (
+            273usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateSamplerYcbcrConversion\0") },
+            create_sampler_ycbcr_conversion as *const c_void,
+        ),
+        b"vkCreateSamplerYcbcrConversionKHR" => (
+            273usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateSamplerYcbcrConversion\0") },
+            create_sampler_ycbcr_conversion as *const c_void,
+        ),
+        b"vkCreateScreenSurfaceQNX" => (
+            274usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateScreenSurfaceQNX\0") },
+            create_screen_surface_qnx as *const c_void,
+        ),
+        b"vkCreateSemaphore" => (
+            275usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateSemaphore\0") },
+            create_semaphore as *const c_void,
+        ),
+        b"vkCreateShaderModule" => (
+            276usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateShaderModule\0") },
+            create_shader_module as *const c_void,
+        ),
+        b"vkCreateShadersEXT" => (
+            277usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateShadersEXT\0") },
+            create_shaders_ext as *const c_void,
+        ),
+        b"vkCreateSharedSwapchainsKHR" => (
+            278usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateSharedSwapchainsKHR\0") },
+            create_shared_swapchains_khr as *const c_void,
+        ),
+        b"vkCreateStreamDescriptorSurfaceGGP" => (
+            279usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateStreamDescriptorSurfaceGGP\0") },
+            create_stream_descriptor_surface_ggp as *const c_void,
+        ),
+        b"vkCreateSwapchainKHR" => (
+            280usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateSwapchainKHR\0") },
+            create_swapchain_khr as *const c_void,
+        ),
+        b"vkCreateValidationCacheEXT" => (
+            281usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateValidationCacheEXT\0") },
+            create_validation_cache_ext as *const c_void,
+        ),
+        b"vkCreateViSurfaceNN" => (
+            282usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateViSurfaceNN\0") },
+            create_vi_surface_nn as *const c_void,
+        ),
+        b"vkCreateVideoSessionKHR" => (
+            283usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateVideoSessionKHR\0") },
+            create_video_session_khr as *const c_void,
+        ),
+        b"vkCreateVideoSessionParametersKHR" => (
+            284usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateVideoSessionParametersKHR\0") },
+            create_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkCreateWaylandSurfaceKHR" => (
+            285usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateWaylandSurfaceKHR\0") },
+            create_wayland_surface_khr as *const c_void,
+        ),
+        b"vkCreateWin32SurfaceKHR" => (
+            286usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateWin32SurfaceKHR\0") },
+            create_win32_surface_khr as *const c_void,
+        ),
+        b"vkCreateXcbSurfaceKHR" => (
+            287usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateXcbSurfaceKHR\0") },
+            create_xcb_surface_khr as *const c_void,
+        ),
+        b"vkCreateXlibSurfaceKHR" => (
+            288usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkCreateXlibSurfaceKHR\0") },
+            create_xlib_surface_khr as *const c_void,
+        ),
+        b"vkDebugMarkerSetObjectNameEXT" => (
+            289usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDebugMarkerSetObjectNameEXT\0") },
+            debug_marker_set_object_name_ext as *const c_void,
+        ),
+        b"vkDebugMarkerSetObjectTagEXT" => (
+            290usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDebugMarkerSetObjectTagEXT\0") },
+            debug_marker_set_object_tag_ext as *const c_void,
+        ),
+        b"vkDebugReportMessageEXT" => (
+            291usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDebugReportMessageEXT\0") },
+            debug_report_message_ext as *const c_void,
+        ),
+        b"vkDeferredOperationJoinKHR" => (
+            292usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDeferredOperationJoinKHR\0") },
+            deferred_operation_join_khr as *const c_void,
+        ),
+        b"vkDestroyAccelerationStructureKHR" => (
+            293usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyAccelerationStructureKHR\0") },
+            destroy_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkDestroyAccelerationStructureNV" => (
+            294usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyAccelerationStructureNV\0") },
+            destroy_acceleration_structure_nv as *const c_void,
+        ),
+        b"vkDestroyBuffer" => (
+            295usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyBuffer\0") },
+            destroy_buffer as *const c_void,
+        ),
+        b"vkDestroyBufferCollectionFUCHSIA" => (
+            296usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyBufferCollectionFUCHSIA\0") },
+            destroy_buffer_collection_fuchsia as *const c_void,
+        ),
+        b"vkDestroyBufferView" => (
+            297usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyBufferView\0") },
+            destroy_buffer_view as *const c_void,
+        ),
+        b"vkDestroyCommandPool" => (
+            298usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyCommandPool\0") },
+            destroy_command_pool as *const c_void,
+        ),
+        b"vkDestroyCuFunctionNVX" => (
+            299usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyCuFunctionNVX\0") },
+            destroy_cu_function_nvx as *const c_void,
+        ),
+        b"vkDestroyCuModuleNVX" => (
+            300usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyCuModuleNVX\0") },
+            destroy_cu_module_nvx as *const c_void,
+        ),
+        b"vkDestroyDebugReportCallbackEXT" => (
+            301usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyDebugReportCallbackEXT\0") },
+            destroy_debug_report_callback_ext as *const c_void,
+        ),
+        b"vkDestroyDebugUtilsMessengerEXT" => (
+            302usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyDebugUtilsMessengerEXT\0") },
+            destroy_debug_utils_messenger_ext as *const c_void,
+        ),
+        b"vkDestroyDeferredOperationKHR" => (
+            303usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyDeferredOperationKHR\0") },
+            destroy_deferred_operation_khr as *const c_void,
+        ),
+        b"vkDestroyDescriptorPool" => (
+            304usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyDescriptorPool\0") },
+            destroy_descriptor_pool as *const c_void,
+        ),
+        b"vkDestroyDescriptorSetLayout" => (
+            305usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyDescriptorSetLayout\0") },
+            destroy_descriptor_set_layout as *const c_void,
+        ),
+        b"vkDestroyDescriptorUpdateTemplate" => (
+            306usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyDescriptorUpdateTemplate\0") },
+            destroy_descriptor_update_template as *const c_void,
+        ),
+        b"vkDestroyDescriptorUpdateTemplateKHR" => (
+            306usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyDescriptorUpdateTemplate\0") },
+            destroy_descriptor_update_template as *const c_void,
+        ),
+        b"vkDestroyDevice" => (
+            307usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyDevice\0") },
+            destroy_device as *const c_void,
+        ),
+        b"vkDestroyEvent" => (
+            308usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyEvent\0") },
+            destroy_event as *const c_void,
+        ),
+        b"vkDestroyFence" => (
+            309usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyFence\0") },
+            destroy_fence as *const c_void,
+        ),
+        b"vkDestroyFramebuffer" => (
+            310usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyFramebuffer\0") },
+            destroy_framebuffer as *const c_void,
+        ),
+        b"vkDestroyImage" => (
+            311usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyImage\0") },
+            destroy_image as *const c_void,
+        ),
+        b"vkDestroyImageView" => (
+            312usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyImageView\0") },
+            destroy_image_view as *const c_void,
+        ),
+        b"vkDestroyIndirectCommandsLayoutNV" => (
+            313usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyIndirectCommandsLayoutNV\0") },
+            destroy_indirect_commands_layout_nv as *const c_void,
+        ),
+        b"vkDestroyInstance" => (
+            314usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyInstance\0") },
+            destroy_instance as *const c_void,
+        ),
+        b"vkDestroyMicromapEXT" => (
+            315usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyMicromapEXT\0") },
+            destroy_micromap_ext as *const c_void,
+        ),
+        b"vkDestroyOpticalFlowSessionNV" => (
+            316usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyOpticalFlowSessionNV\0") },
+            destroy_optical_flow_session_nv as *const c_void,
+        ),
+        b"vkDestroyPipeline" => (
+            317usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyPipeline\0") },
+            destroy_pipeline as *const c_void,
+        ),
+        b"vkDestroyPipelineCache" => (
+            318usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyPipelineCache\0") },
+            destroy_pipeline_cache as *const c_void,
+        ),
+        b"vkDestroyPipelineLayout" => (
+            319usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyPipelineLayout\0") },
+            destroy_pipeline_layout as *const c_void,
+        ),
+        b"vkDestroyPrivateDataSlot" => (
+            320usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyPrivateDataSlot\0") },
+            destroy_private_data_slot as *const c_void,
+        ),
+        b"vkDestroyPrivateDataSlotEXT" => (
+            320usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyPrivateDataSlot\0") },
+            destroy_private_data_slot as *const c_void,
+        ),
+        b"vkDestroyQueryPool" => (
+            321usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyQueryPool\0") },
+            destroy_query_pool as *const c_void,
+        ),
+        b"vkDestroyRenderPass" => (
+            322usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyRenderPass\0") },
+            destroy_render_pass as *const c_void,
+        ),
+        b"vkDestroySampler" => (
+            323usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroySampler\0") },
+            destroy_sampler as *const c_void,
+        ),
+        b"vkDestroySamplerYcbcrConversion" => (
+            324usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroySamplerYcbcrConversion\0") },
+            destroy_sampler_ycbcr_conversion as *const c_void,
+        ),
+        b"vkDestroySamplerYcbcrConversionKHR" => (
+            324usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroySamplerYcbcrConversion\0") },
+            destroy_sampler_ycbcr_conversion as *const c_void,
+        ),
+        b"vkDestroySemaphore" => (
+            325usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroySemaphore\0") },
+            destroy_semaphore as *const c_void,
+        ),
+        b"vkDestroyShaderEXT" => (
+            326usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyShaderEXT\0") },
+            destroy_shader_ext as *const c_void,
+        ),
+        b"vkDestroyShaderModule" => (
+            327usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyShaderModule\0") },
+            destroy_shader_module as *const c_void,
+        ),
+        b"vkDestroySurfaceKHR" => (
+            328usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroySurfaceKHR\0") },
+            destroy_surface_khr as *const c_void,
+        ),
+        b"vkDestroySwapchainKHR" => (
+            329usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroySwapchainKHR\0") },
+            destroy_swapchain_khr as *const c_void,
+        ),
+        b"vkDestroyValidationCacheEXT" => (
+            330usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyValidationCacheEXT\0") },
+            destroy_validation_cache_ext as *const c_void,
+        ),
+        b"vkDestroyVideoSessionKHR" => (
+            331usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyVideoSessionKHR\0") },
+            destroy_video_session_khr as *const c_void,
+        ),
+        b"vkDestroyVideoSessionParametersKHR" => (
+            332usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDestroyVideoSessionParametersKHR\0") },
+            destroy_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkDeviceWaitIdle" => (
+            333usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDeviceWaitIdle\0") },
+            device_wait_idle as *const c_void,
+        ),
+        b"vkDisplayPowerControlEXT" => (
+            334usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkDisplayPowerControlEXT\0") },
+            display_power_control_ext as *const c_void,
+        ),
+        b"vkEndCommandBuffer" => (
+            335usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkEndCommandBuffer\0") },
+            end_command_buffer as *const c_void,
+        ),
+        b"vkEnumerateDeviceExtensionProperties" => (
+            336usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkEnumerateDeviceExtensionProperties\0")
+            },
+            enumerate_device_extension_properties as *const c_void,
+        ),
+        b"vkEnumerateDeviceLayerProperties" => (
+            337usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkEnumerateDeviceLayerProperties\0") },
+            enumerate_device_layer_properties as *const c_void,
+        ),
+        b"vkEnumerateInstanceExtensionProperties" => (
+            338usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkEnumerateInstanceExtensionProperties\0")
+            },
+            enumerate_instance_extension_properties as *const c_void,
+        ),
+        b"vkEnumerateInstanceLayerProperties" => (
+            339usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkEnumerateInstanceLayerProperties\0") },
+            enumerate_instance_layer_properties as *const c_void,
+        ),
+        b"vkEnumerateInstanceVersion" => (
+            340usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkEnumerateInstanceVersion\0") },
+            enumerate_instance_version as *const c_void,
+        ),
+        b"vkEnumeratePhysicalDeviceGroups" => (
+            341usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkEnumeratePhysicalDeviceGroups\0") },
+            enumerate_physical_device_groups as *const c_void,
+        ),
+        b"vkEnumeratePhysicalDeviceGroupsKHR" => (
+            341usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkEnumeratePhysicalDeviceGroups\0") },
+            enumerate_physical_device_groups as *const c_void,
+        ),
+        b"vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR" => (
+            342usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR\0",
+                )
+            },
+            enumerate_physical_device_queue_family_performance_query_counters_khr as *const c_void,
+        ),
+        b"vkEnumeratePhysicalDevices" => (
+            343usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkEnumeratePhysicalDevices\0") },
+            enumerate_physical_devices as *const c_void,
+        ),
+        b"vkExportMetalObjectsEXT" => (
+            344usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkExportMetalObjectsEXT\0") },
+            export_metal_objects_ext as *const c_void,
+        ),
+        b"vkFlushMappedMemoryRanges" => (
+            345usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkFlushMappedMemoryRanges\0") },
+            flush_mapped_memory_ranges as *const c_void,
+        ),
+        b"vkFreeCommandBuffers" => (
+            346usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkFreeCommandBuffers\0") },
+            free_command_buffers as *const c_void,
+        ),
+        b"vkFreeDescriptorSets" => (
+            347usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkFreeDescriptorSets\0") },
+            free_descriptor_sets as *const c_void,
+        ),
+        b"vkFreeMemory" => (
+            348usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkFreeMemory\0") },
+            free_memory as *const c_void,
+        ),
+        b"vkGetAccelerationStructureBuildSizesKHR" => (
+            349usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetAccelerationStructureBuildSizesKHR\0")
+            },
+            get_acceleration_structure_build_sizes_khr as *const c_void,
+        ),
+        b"vkGetAccelerationStructureDeviceAddressKHR" => (
+            350usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetAccelerationStructureDeviceAddressKHR\0")
+            },
+            get_acceleration_structure_device_address_khr as *const c_void,
+        ),
+        b"vkGetAccelerationStructureHandleNV" => (
+            351usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetAccelerationStructureHandleNV\0") },
+            get_acceleration_structure_handle_nv as *const c_void,
+        ),
+        b"vkGetAccelerationStructureMemoryRequirementsNV" => (
+            352usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetAccelerationStructureMemoryRequirementsNV\0",
+                )
+            },
+            get_acceleration_structure_memory_requirements_nv as *const c_void,
+        ),
+        b"vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT" => (
+            353usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT\0",
+                )
+            },
+            get_acceleration_structure_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetAndroidHardwareBufferPropertiesANDROID" => (
+            354usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetAndroidHardwareBufferPropertiesANDROID\0",
+                )
+            },
+            get_android_hardware_buffer_properties_android as *const c_void,
+        ),
+        b"vkGetBufferCollectionPropertiesFUCHSIA" => (
+            355usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetBufferCollectionPropertiesFUCHSIA\0")
+            },
+            get_buffer_collection_properties_fuchsia as *const c_void,
+        ),
+        b"vkGetBufferDeviceAddress" => (
+            356usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetBufferDeviceAddress\0") },
+            get_buffer_device_address as *const c_void,
+        ),
+        b"vkGetBufferDeviceAddressEXT" => (
+            356usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetBufferDeviceAddress\0") },
+            get_buffer_device_address as *const c_void,
+        ),
+        b"vkGetBufferDeviceAddressKHR" => (
+            356usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetBufferDeviceAddress\0") },
+            get_buffer_device_address as *const c_void,
+        ),
+        b"vkGetBufferMemoryRequirements" => (
+            357usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetBufferMemoryRequirements\0") },
+            get_buffer_memory_requirements as *const c_void,
+        ),
+        b"vkGetBufferMemoryRequirements2" => (
+            358usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetBufferMemoryRequirements2\0") },
+            get_buffer_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetBufferMemoryRequirements2KHR" => (
+            358usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetBufferMemoryRequirements2\0") },
+            get_buffer_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetBufferOpaqueCaptureAddress" => (
+            359usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetBufferOpaqueCaptureAddress\0") },
+            get_buffer_opaque_capture_address as *const c_void,
+        ),
+        b"vkGetBufferOpaqueCaptureAddressKHR" => (
+            359usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetBufferOpaqueCaptureAddress\0") },
+            get_buffer_opaque_capture_address as *const c_void,
+        ),
+        b"vkGetBufferOpaqueCaptureDescriptorDataEXT" => (
+            360usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetBufferOpaqueCaptureDescriptorDataEXT\0")
+            },
+            get_buffer_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetCalibratedTimestampsEXT" => (
+            361usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetCalibratedTimestampsEXT\0") },
+            get_calibrated_timestamps_ext as *const c_void,
+        ),
+        b"vkGetDeferredOperationMaxConcurrencyKHR" => (
+            362usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeferredOperationMaxConcurrencyKHR\0")
+            },
+            get_deferred_operation_max_concurrency_khr as *const c_void,
+        ),
+        b"vkGetDeferredOperationResultKHR" => (
+            363usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDeferredOperationResultKHR\0") },
+            get_deferred_operation_result_khr as *const c_void,
+        ),
+        b"vkGetDescriptorEXT" => (
+            364usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorEXT\0") },
+            get_descriptor_ext as *const c_void,
+        ),
+        b"vkGetDescriptorSetHostMappingVALVE" => (
+            365usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetHostMappingVALVE\0") },
+            get_descriptor_set_host_mapping_valve as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutBindingOffsetEXT" => (
+            366usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetLayoutBindingOffsetEXT\0")
+            },
+            get_descriptor_set_layout_binding_offset_ext as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutHostMappingInfoVALVE" => (
+            367usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetDescriptorSetLayoutHostMappingInfoVALVE\0",
+                )
+            },
+            get_descriptor_set_layout_host_mapping_info_valve as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutSizeEXT" => (
+            368usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetLayoutSizeEXT\0") },
+            get_descriptor_set_layout_size_ext as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutSupport" => (
+            369usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetLayoutSupport\0") },
+            get_descriptor_set_layout_support as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutSupportKHR" => (
+            369usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetLayoutSupport\0") },
+            get_descriptor_set_layout_support as *const c_void,
+        ),
+        b"vkGetDeviceAccelerationStructureCompatibilityKHR" => (
+            370usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetDeviceAccelerationStructureCompatibilityKHR\0",
+                )
+            },
+            get_device_acceleration_structure_compatibility_khr as *const c_void,
+        ),
+        b"vkGetDeviceBufferMemoryRequirements" => (
+            371usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceBufferMemoryRequirements\0")
+            },
+            get_device_buffer_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceBufferMemoryRequirementsKHR" => (
+            371usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceBufferMemoryRequirements\0")
+            },
+            get_device_buffer_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceFaultInfoEXT" => (
+            372usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceFaultInfoEXT\0") },
+            get_device_fault_info_ext as *const c_void,
+        ),
+        b"vkGetDeviceGroupPeerMemoryFeatures" => (
+            373usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupPeerMemoryFeatures\0") },
+            get_device_group_peer_memory_features as *const c_void,
+        ),
+        b"vkGetDeviceGroupPeerMemoryFeaturesKHR" => (
+            373usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupPeerMemoryFeatures\0") },
+            get_device_group_peer_memory_features as *const c_void,
+        ),
+        b"vkGetDeviceGroupPresentCapabilitiesKHR" => (
+            374usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupPresentCapabilitiesKHR\0")
+            },
+            get_device_group_present_capabilities_khr as *const c_void,
+        ),
+        b"vkGetDeviceGroupSurfacePresentModes2EXT" => (
+            375usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupSurfacePresentModes2EXT\0")
+            },
+            get_device_group_surface_present_modes2_ext as *const c_void,
+        ),
+        b"vkGetDeviceGroupSurfacePresentModesKHR" => (
+            376usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupSurfacePresentModesKHR\0")
+            },
+            get_device_group_surface_present_modes_khr as *const c_void,
+        ),
+        b"vkGetDeviceImageMemoryRequirements" => (
+            377usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceImageMemoryRequirements\0") },
+            get_device_image_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceImageMemoryRequirementsKHR" => (
+            377usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceImageMemoryRequirements\0") },
+            get_device_image_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceImageSparseMemoryRequirements" => (
+            378usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceImageSparseMemoryRequirements\0")
+            },
+            get_device_image_sparse_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceImageSparseMemoryRequirementsKHR" => (
+            378usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceImageSparseMemoryRequirements\0")
+            },
+            get_device_image_sparse_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceMemoryCommitment" => (
+            379usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceMemoryCommitment\0") },
+            get_device_memory_commitment as *const c_void,
+        ),
+        b"vkGetDeviceMemoryOpaqueCaptureAddress" => (
+            380usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceMemoryOpaqueCaptureAddress\0")
+            },
+            get_device_memory_opaque_capture_address as *const c_void,
+        ),
+        b"vkGetDeviceMemoryOpaqueCaptureAddressKHR" => (
+            380usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceMemoryOpaqueCaptureAddress\0")
+            },
+            get_device_memory_opaque_capture_address as *const c_void,
+        ),
+        b"vkGetDeviceMicromapCompatibilityEXT" => (
+            381usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceMicromapCompatibilityEXT\0")
+            },
+            get_device_micromap_compatibility_ext as *const c_void,
+        ),
+        b"vkGetDeviceQueue" => (
+            382usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceQueue\0") },
+            get_device_queue as *const c_void,
+        ),
+        b"vkGetDeviceQueue2" => (
+            383usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceQueue2\0") },
+            get_device_queue2 as *const c_void,
+        ),
+        b"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI" => (
+            384usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI\0",
+                )
+            },
+            get_device_subpass_shading_max_workgroup_size_huawei as *const c_void,
+        ),
+        b"vkGetDisplayModeProperties2KHR" => (
+            385usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayModeProperties2KHR\0") },
+            get_display_mode_properties2_khr as *const c_void,
+        ),
+        b"vkGetDisplayModePropertiesKHR" => (
+            386usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayModePropertiesKHR\0") },
+            get_display_mode_properties_khr as *const c_void,
+        ),
+        b"vkGetDisplayPlaneCapabilities2KHR" => (
+            387usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayPlaneCapabilities2KHR\0") },
+            get_display_plane_capabilities2_khr as *const c_void,
+        ),
+        b"vkGetDisplayPlaneCapabilitiesKHR" => (
+            388usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayPlaneCapabilitiesKHR\0") },
+            get_display_plane_capabilities_khr as *const c_void,
+        ),
+        b"vkGetDisplayPlaneSupportedDisplaysKHR" => (
+            389usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayPlaneSupportedDisplaysKHR\0")
+            },
+            get_display_plane_supported_displays_khr as *const c_void,
+        ),
+        b"vkGetDrmDisplayEXT" => (
+            390usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetDrmDisplayEXT\0") },
+            get_drm_display_ext as *const c_void,
+        ),
+        b"vkGetDynamicRenderingTilePropertiesQCOM" => (
+            391usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetDynamicRenderingTilePropertiesQCOM\0")
+            },
+            get_dynamic_rendering_tile_properties_qcom as *const c_void,
+        ),
+        b"vkGetEncodedVideoSessionParametersKHR" => (
+            392usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetEncodedVideoSessionParametersKHR\0")
+            },
+            get_encoded_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkGetEventStatus" => (
+            393usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetEventStatus\0") },
+            get_event_status as *const c_void,
+        ),
+        b"vkGetFenceFdKHR" => (
+            394usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetFenceFdKHR\0") },
+            get_fence_fd_khr as *const c_void,
+        ),
+        b"vkGetFenceStatus" => (
+            395usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetFenceStatus\0") },
+            get_fence_status as *const c_void,
+        ),
+        b"vkGetFenceWin32HandleKHR" => (
+            396usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetFenceWin32HandleKHR\0") },
+            get_fence_win32_handle_khr as *const c_void,
+        ),
+        b"vkGetFramebufferTilePropertiesQCOM" => (
+            397usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetFramebufferTilePropertiesQCOM\0") },
+            get_framebuffer_tile_properties_qcom as *const c_void,
+        ),
+        b"vkGetGeneratedCommandsMemoryRequirementsNV" => (
+            398usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetGeneratedCommandsMemoryRequirementsNV\0")
+            },
+            get_generated_commands_memory_requirements_nv as *const c_void,
+        ),
+        b"vkGetImageDrmFormatModifierPropertiesEXT" => (
+            399usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetImageDrmFormatModifierPropertiesEXT\0")
+            },
+            get_image_drm_format_modifier_properties_ext as *const c_void,
+        ),
+        b"vkGetImageMemoryRequirements" => (
+            400usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetImageMemoryRequirements\0") },
+            get_image_memory_requirements as *const c_void,
+        ),
+        b"vkGetImageMemoryRequirements2" => (
+            401usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetImageMemoryRequirements2\0") },
+            get_image_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetImageMemoryRequirements2KHR" => (
+            401usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetImageMemoryRequirements2\0") },
+            get_image_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetImageOpaqueCaptureDescriptorDataEXT" => (
+            402usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetImageOpaqueCaptureDescriptorDataEXT\0")
+            },
+            get_image_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements" => (
+            403usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetImageSparseMemoryRequirements\0") },
+            get_image_sparse_memory_requirements as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements2" => (
+            404usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetImageSparseMemoryRequirements2\0")
+            },
+            get_image_sparse_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements2KHR" => (
+            404usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetImageSparseMemoryRequirements2\0")
+            },
+            get_image_sparse_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetImageSubresourceLayout" => (
+            405usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetImageSubresourceLayout\0") },
+            get_image_subresource_layout as *const c_void,
+        ),
+        b"vkGetImageSubresourceLayout2EXT" => (
+            406usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetImageSubresourceLayout2EXT\0") },
+            get_image_subresource_layout2_ext as *const c_void,
+        ),
+        b"vkGetImageViewAddressNVX" => (
+            407usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetImageViewAddressNVX\0") },
+            get_image_view_address_nvx as *const c_void,
+        ),
+        b"vkGetImageViewHandleNVX" => (
+            408usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetImageViewHandleNVX\0") },
+            get_image_view_handle_nvx as *const c_void,
+        ),
+        b"vkGetImageViewOpaqueCaptureDescriptorDataEXT" => (
+            409usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetImageViewOpaqueCaptureDescriptorDataEXT\0",
+                )
+            },
+            get_image_view_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetMemoryAndroidHardwareBufferANDROID" => (
+            410usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryAndroidHardwareBufferANDROID\0")
+            },
+            get_memory_android_hardware_buffer_android as *const c_void,
+        ),
+        b"vkGetMemoryFdKHR" => (
+            411usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryFdKHR\0") },
+            get_memory_fd_khr as *const c_void,
+        ),
+        b"vkGetMemoryFdPropertiesKHR" => (
+            412usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryFdPropertiesKHR\0") },
+            get_memory_fd_properties_khr as *const c_void,
+        ),
+        b"vkGetMemoryHostPointerPropertiesEXT" => (
+            413usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryHostPointerPropertiesEXT\0")
+            },
+            get_memory_host_pointer_properties_ext as *const c_void,
+        ),
+        b"vkGetMemoryRemoteAddressNV" => (
+            414usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryRemoteAddressNV\0") },
+            get_memory_remote_address_nv as *const c_void,
+        ),
+        b"vkGetMemoryWin32HandleKHR" => (
+            415usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryWin32HandleKHR\0") },
+            get_memory_win32_handle_khr as *const c_void,
+        ),
+        b"vkGetMemoryWin32HandleNV" => (
+            416usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryWin32HandleNV\0") },
+            get_memory_win32_handle_nv as *const c_void,
+        ),
+        b"vkGetMemoryWin32HandlePropertiesKHR" => (
+            417usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryWin32HandlePropertiesKHR\0")
+            },
+            get_memory_win32_handle_properties_khr as *const c_void,
+        ),
+        b"vkGetMemoryZirconHandleFUCHSIA" => (
+            418usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryZirconHandleFUCHSIA\0") },
+            get_memory_zircon_handle_fuchsia as *const c_void,
+        ),
+        b"vkGetMemoryZirconHandlePropertiesFUCHSIA" => (
+            419usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryZirconHandlePropertiesFUCHSIA\0")
+            },
+            get_memory_zircon_handle_properties_fuchsia as *const c_void,
+        ),
+        b"vkGetMicromapBuildSizesEXT" => (
+            420usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetMicromapBuildSizesEXT\0") },
+            get_micromap_build_sizes_ext as *const c_void,
+        ),
+        b"vkGetPastPresentationTimingGOOGLE" => (
+            421usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPastPresentationTimingGOOGLE\0") },
+            get_past_presentation_timing_google as *const c_void,
+        ),
+        b"vkGetPerformanceParameterINTEL" => (
+            422usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPerformanceParameterINTEL\0") },
+            get_performance_parameter_intel as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceCalibrateableTimeDomainsEXT" => (
+            423usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceCalibrateableTimeDomainsEXT\0",
+                )
+            },
+            get_physical_device_calibrateable_time_domains_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceCooperativeMatrixPropertiesNV" => (
+            424usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceCooperativeMatrixPropertiesNV\0",
+                )
+            },
+            get_physical_device_cooperative_matrix_properties_nv as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDirectFBPresentationSupportEXT" => (
+            425usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceDirectFBPresentationSupportEXT\0",
+                )
+            },
+            get_physical_device_direct_fb_presentation_support_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayPlaneProperties2KHR" => (
+            426usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceDisplayPlaneProperties2KHR\0",
+                )
+            },
+            get_physical_device_display_plane_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayPlanePropertiesKHR" => (
+            427usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceDisplayPlanePropertiesKHR\0",
+                )
+            },
+            get_physical_device_display_plane_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayProperties2KHR" => (
+            428usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceDisplayProperties2KHR\0")
+            },
+            get_physical_device_display_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayPropertiesKHR" => (
+            429usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceDisplayPropertiesKHR\0")
+            },
+            get_physical_device_display_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalBufferProperties" => (
+            430usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceExternalBufferProperties\0",
+                )
+            },
+            get_physical_device_external_buffer_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalBufferPropertiesKHR" => (
+            430usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceExternalBufferProperties\0",
+                )
+            },
+            get_physical_device_external_buffer_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalFenceProperties" => (
+            431usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceExternalFenceProperties\0")
+            },
+            get_physical_device_external_fence_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalFencePropertiesKHR" => (
+            431usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceExternalFenceProperties\0")
+            },
+            get_physical_device_external_fence_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalImageFormatPropertiesNV" => (
+            432usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceExternalImageFormatPropertiesNV\0",
+                )
+            },
+            get_physical_device_external_image_format_properties_nv as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalSemaphoreProperties" => (
+            433usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceExternalSemaphoreProperties\0",
+                )
+            },
+            get_physical_device_external_semaphore_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalSemaphorePropertiesKHR" => (
+            433usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceExternalSemaphoreProperties\0",
+                )
+            },
+            get_physical_device_external_semaphore_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFeatures" => (
+            434usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFeatures\0") },
+            get_physical_device_features as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFeatures2" => (
+            435usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFeatures2\0") },
+            get_physical_device_features2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFeatures2KHR" => (
+            435usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFeatures2\0") },
+            get_physical_device_features2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFormatProperties" => (
+            436usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFormatProperties\0")
+            },
+            get_physical_device_format_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFormatProperties2" => (
+            437usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFormatProperties2\0")
+            },
+            get_physical_device_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFormatProperties2KHR" => (
+            437usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFormatProperties2\0")
+            },
+            get_physical_device_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFragmentShadingRatesKHR" => (
+            438usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFragmentShadingRatesKHR\0")
+            },
+            get_physical_device_fragment_shading_rates_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceImageFormatProperties" => (
+            439usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceImageFormatProperties\0")
+            },
+            get_physical_device_image_format_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceImageFormatProperties2" => (
+            440usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceImageFormatProperties2\0")
+            },
+            get_physical_device_image_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceImageFormatProperties2KHR" => (
+            440usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceImageFormatProperties2\0")
+            },
+            get_physical_device_image_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMemoryProperties" => (
+            441usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceMemoryProperties\0")
+            },
+            get_physical_device_memory_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMemoryProperties2" => (
+            442usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceMemoryProperties2\0")
+            },
+            get_physical_device_memory_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMemoryProperties2KHR" => (
+            442usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceMemoryProperties2\0")
+            },
+            get_physical_device_memory_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMultisamplePropertiesEXT" => (
+            443usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceMultisamplePropertiesEXT\0",
+                )
+            },
+            get_physical_device_multisample_properties_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceOpticalFlowImageFormatsNV" => (
+            444usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceOpticalFlowImageFormatsNV\0",
+                )
+            },
+            get_physical_device_optical_flow_image_formats_nv as *const c_void,
+        ),
+        b"vkGetPhysicalDevicePresentRectanglesKHR" => (
+            445usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDevicePresentRectanglesKHR\0")
+            },
+            get_physical_device_present_rectangles_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceProperties" => (
+            446usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceProperties\0") },
+            get_physical_device_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceProperties2" => (
+            447usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceProperties2\0") },
+            get_physical_device_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceProperties2KHR" => (
+            447usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceProperties2\0") },
+            get_physical_device_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR" => (
+            448usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR\0",
+                )
+            },
+            get_physical_device_queue_family_performance_query_passes_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyProperties" => (
+            449usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceQueueFamilyProperties\0")
+            },
+            get_physical_device_queue_family_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyProperties2" => (
+            450usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceQueueFamilyProperties2\0")
+            },
+            get_physical_device_queue_family_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyProperties2KHR" => (
+            450usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceQueueFamilyProperties2\0")
+            },
+            get_physical_device_queue_family_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceScreenPresentationSupportQNX" => (
+            451usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceScreenPresentationSupportQNX\0",
+                )
+            },
+            get_physical_device_screen_presentation_support_qnx as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSparseImageFormatProperties" => (
+            452usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceSparseImageFormatProperties\0",
+                )
+            },
+            get_physical_device_sparse_image_format_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSparseImageFormatProperties2" => (
+            453usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceSparseImageFormatProperties2\0",
+                )
+            },
+            get_physical_device_sparse_image_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSparseImageFormatProperties2KHR" => (
+            453usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceSparseImageFormatProperties2\0",
+                )
+            },
+            get_physical_device_sparse_image_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV" => (
+            454usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV\0",
+                )
+            },
+            get_physical_device_supported_framebuffer_mixed_samples_combinations_nv
+                as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceCapabilities2EXT" => (
+            455usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceCapabilities2EXT\0")
+            },
+            get_physical_device_surface_capabilities2_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceCapabilities2KHR" => (
+            456usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceCapabilities2KHR\0")
+            },
+            get_physical_device_surface_capabilities2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => (
+            457usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR\0")
+            },
+            get_physical_device_surface_capabilities_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceFormats2KHR" => (
+            458usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceFormats2KHR\0")
+            },
+            get_physical_device_surface_formats2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceFormatsKHR" => (
+            459usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceFormatsKHR\0")
+            },
+            get_physical_device_surface_formats_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfacePresentModes2EXT" => (
+            460usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfacePresentModes2EXT\0")
+            },
+            get_physical_device_surface_present_modes2_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfacePresentModesKHR" => (
+            461usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfacePresentModesKHR\0")
+            },
+            get_physical_device_surface_present_modes_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceSupportKHR" => (
+            462usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceSupportKHR\0")
+            },
+            get_physical_device_surface_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceToolProperties" => (
+            463usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceToolProperties\0") },
+            get_physical_device_tool_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceToolPropertiesEXT" => (
+            463usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceToolProperties\0") },
+            get_physical_device_tool_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceVideoCapabilitiesKHR" => (
+            464usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceVideoCapabilitiesKHR\0")
+            },
+            get_physical_device_video_capabilities_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR" => (
+            465usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR\0",
+                )
+            },
+            get_physical_device_video_encode_quality_level_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceVideoFormatPropertiesKHR" => (
+            466usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceVideoFormatPropertiesKHR\0",
+                )
+            },
+            get_physical_device_video_format_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceWaylandPresentationSupportKHR" => (
+            467usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceWaylandPresentationSupportKHR\0",
+                )
+            },
+            get_physical_device_wayland_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceWin32PresentationSupportKHR" => (
+            468usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceWin32PresentationSupportKHR\0",
+                )
+            },
+            get_physical_device_win32_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceXcbPresentationSupportKHR" => (
+            469usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceXcbPresentationSupportKHR\0",
+                )
+            },
+            get_physical_device_xcb_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceXlibPresentationSupportKHR" => (
+            470usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPhysicalDeviceXlibPresentationSupportKHR\0",
+                )
+            },
+            get_physical_device_xlib_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPipelineCacheData" => (
+            471usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPipelineCacheData\0") },
+            get_pipeline_cache_data as *const c_void,
+        ),
+        b"vkGetPipelineExecutableInternalRepresentationsKHR" => (
+            472usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetPipelineExecutableInternalRepresentationsKHR\0",
+                )
+            },
+            get_pipeline_executable_internal_representations_khr as *const c_void,
+        ),
+        b"vkGetPipelineExecutablePropertiesKHR" => (
+            473usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPipelineExecutablePropertiesKHR\0")
+            },
+            get_pipeline_executable_properties_khr as *const c_void,
+        ),
+        b"vkGetPipelineExecutableStatisticsKHR" => (
+            474usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetPipelineExecutableStatisticsKHR\0")
+            },
+            get_pipeline_executable_statistics_khr as *const c_void,
+        ),
+        b"vkGetPipelinePropertiesEXT" => (
+            475usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPipelinePropertiesEXT\0") },
+            get_pipeline_properties_ext as *const c_void,
+        ),
+        b"vkGetPrivateData" => (
+            476usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPrivateData\0") },
+            get_private_data as *const c_void,
+        ),
+        b"vkGetPrivateDataEXT" => (
+            476usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetPrivateData\0") },
+            get_private_data as *const c_void,
+        ),
+        b"vkGetQueryPoolResults" => (
+            477usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetQueryPoolResults\0") },
+            get_query_pool_results as *const c_void,
+        ),
+        b"vkGetQueueCheckpointData2NV" => (
+            478usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetQueueCheckpointData2NV\0") },
+            get_queue_checkpoint_data2_nv as *const c_void,
+        ),
+        b"vkGetQueueCheckpointDataNV" => (
+            479usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetQueueCheckpointDataNV\0") },
+            get_queue_checkpoint_data_nv as *const c_void,
+        ),
+        b"vkGetRandROutputDisplayEXT" => (
+            480usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetRandROutputDisplayEXT\0") },
+            get_rand_r_output_display_ext as *const c_void,
+        ),
+        b"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR" => (
+            481usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR\0",
+                )
+            },
+            get_ray_tracing_capture_replay_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupHandlesKHR" => (
+            482usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetRayTracingShaderGroupHandlesKHR\0")
+            },
+            get_ray_tracing_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupHandlesNV" => (
+            482usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetRayTracingShaderGroupHandlesKHR\0")
+            },
+            get_ray_tracing_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupStackSizeKHR" => (
+            483usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetRayTracingShaderGroupStackSizeKHR\0")
+            },
+            get_ray_tracing_shader_group_stack_size_khr as *const c_void,
+        ),
+        b"vkGetRefreshCycleDurationGOOGLE" => (
+            484usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetRefreshCycleDurationGOOGLE\0") },
+            get_refresh_cycle_duration_google as *const c_void,
+        ),
+        b"vkGetRenderAreaGranularity" => (
+            485usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetRenderAreaGranularity\0") },
+            get_render_area_granularity as *const c_void,
+        ),
+        b"vkGetSamplerOpaqueCaptureDescriptorDataEXT" => (
+            486usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetSamplerOpaqueCaptureDescriptorDataEXT\0")
+            },
+            get_sampler_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetScreenBufferPropertiesQNX" => (
+            487usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetScreenBufferPropertiesQNX\0") },
+            get_screen_buffer_properties_qnx as *const c_void,
+        ),
+        b"vkGetSemaphoreCounterValue" => (
+            488usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetSemaphoreCounterValue\0") },
+            get_semaphore_counter_value as *const c_void,
+        ),
+        b"vkGetSemaphoreCounterValueKHR" => (
+            488usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetSemaphoreCounterValue\0") },
+            get_semaphore_counter_value as *const c_void,
+        ),
+        b"vkGetSemaphoreFdKHR" => (
+            489usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetSemaphoreFdKHR\0") },
+            get_semaphore_fd_khr as *const c_void,
+        ),
+        b"vkGetSemaphoreWin32HandleKHR" => (
+            490usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetSemaphoreWin32HandleKHR\0") },
+            get_semaphore_win32_handle_khr as *const c_void,
+        ),
+        b"vkGetSemaphoreZirconHandleFUCHSIA" => (
+            491usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetSemaphoreZirconHandleFUCHSIA\0") },
+            get_semaphore_zircon_handle_fuchsia as *const c_void,
+        ),
+        b"vkGetShaderBinaryDataEXT" => (
+            492usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetShaderBinaryDataEXT\0") },
+            get_shader_binary_data_ext as *const c_void,
+        ),
+        b"vkGetShaderInfoAMD" => (
+            493usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetShaderInfoAMD\0") },
+            get_shader_info_amd as *const c_void,
+        ),
+        b"vkGetShaderModuleCreateInfoIdentifierEXT" => (
+            494usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetShaderModuleCreateInfoIdentifierEXT\0")
+            },
+            get_shader_module_create_info_identifier_ext as *const c_void,
+        ),
+        b"vkGetShaderModuleIdentifierEXT" => (
+            495usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetShaderModuleIdentifierEXT\0") },
+            get_shader_module_identifier_ext as *const c_void,
+        ),
+        b"vkGetSwapchainCounterEXT" => (
+            496usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainCounterEXT\0") },
+            get_swapchain_counter_ext as *const c_void,
+        ),
+        b"vkGetSwapchainGrallocUsage2ANDROID" => (
+            497usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainGrallocUsage2ANDROID\0") },
+            get_swapchain_gralloc_usage2_android as *const c_void,
+        ),
+        b"vkGetSwapchainGrallocUsageANDROID" => (
+            498usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainGrallocUsageANDROID\0") },
+            get_swapchain_gralloc_usage_android as *const c_void,
+        ),
+        b"vkGetSwapchainImagesKHR" => (
+            499usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainImagesKHR\0") },
+            get_swapchain_images_khr as *const c_void,
+        ),
+        b"vkGetSwapchainStatusKHR" => (
+            500usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainStatusKHR\0") },
+            get_swapchain_status_khr as *const c_void,
+        ),
+        b"vkGetValidationCacheDataEXT" => (
+            501usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetValidationCacheDataEXT\0") },
+            get_validation_cache_data_ext as *const c_void,
+        ),
+        b"vkGetVideoSessionMemoryRequirementsKHR" => (
+            502usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkGetVideoSessionMemoryRequirementsKHR\0")
+            },
+            get_video_session_memory_requirements_khr as *const c_void,
+        ),
+        b"vkGetWinrtDisplayNV" => (
+            503usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkGetWinrtDisplayNV\0") },
+            get_winrt_display_nv as *const c_void,
+        ),
+        b"vkImportFenceFdKHR" => (
+            504usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkImportFenceFdKHR\0") },
+            import_fence_fd_khr as *const c_void,
+        ),
+        b"vkImportFenceWin32HandleKHR" => (
+            505usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkImportFenceWin32HandleKHR\0") },
+            import_fence_win32_handle_khr as *const c_void,
+        ),
+        b"vkImportSemaphoreFdKHR" => (
+            506usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkImportSemaphoreFdKHR\0") },
+            import_semaphore_fd_khr as *const c_void,
+        ),
+        b"vkImportSemaphoreWin32HandleKHR" => (
+            507usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkImportSemaphoreWin32HandleKHR\0") },
+            import_semaphore_win32_handle_khr as *const c_void,
+        ),
+        b"vkImportSemaphoreZirconHandleFUCHSIA" => (
+            508usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkImportSemaphoreZirconHandleFUCHSIA\0")
+            },
+            import_semaphore_zircon_handle_fuchsia as *const c_void,
+        ),
+        b"vkInitializePerformanceApiINTEL" => (
+            509usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkInitializePerformanceApiINTEL\0") },
+            initialize_performance_api_intel as *const c_void,
+        ),
+        b"vkInvalidateMappedMemoryRanges" => (
+            510usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkInvalidateMappedMemoryRanges\0") },
+            invalidate_mapped_memory_ranges as *const c_void,
+        ),
+        b"vkMapMemory" => (
+            511usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkMapMemory\0") },
+            map_memory as *const c_void,
+        ),
+        b"vkMapMemory2KHR" => (
+            512usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkMapMemory2KHR\0") },
+            map_memory2_khr as *const c_void,
+        ),
+        b"vkMergePipelineCaches" => (
+            513usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkMergePipelineCaches\0") },
+            merge_pipeline_caches as *const c_void,
+        ),
+        b"vkMergeValidationCachesEXT" => (
+            514usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkMergeValidationCachesEXT\0") },
+            merge_validation_caches_ext as *const c_void,
+        ),
+        b"vkQueueBeginDebugUtilsLabelEXT" => (
+            515usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkQueueBeginDebugUtilsLabelEXT\0") },
+            queue_begin_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkQueueBindSparse" => (
+            516usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkQueueBindSparse\0") },
+            queue_bind_sparse as *const c_void,
+        ),
+        b"vkQueueEndDebugUtilsLabelEXT" => (
+            517usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkQueueEndDebugUtilsLabelEXT\0") },
+            queue_end_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkQueueInsertDebugUtilsLabelEXT" => (
+            518usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkQueueInsertDebugUtilsLabelEXT\0") },
+            queue_insert_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkQueuePresentKHR" => (
+            519usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkQueuePresentKHR\0") },
+            queue_present_khr as *const c_void,
+        ),
+        b"vkQueueSetPerformanceConfigurationINTEL" => (
+            520usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkQueueSetPerformanceConfigurationINTEL\0")
+            },
+            queue_set_performance_configuration_intel as *const c_void,
+        ),
+        b"vkQueueSignalReleaseImageANDROID" => (
+            521usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkQueueSignalReleaseImageANDROID\0") },
+            queue_signal_release_image_android as *const c_void,
+        ),
+        b"vkQueueSubmit" => (
+            522usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkQueueSubmit\0") },
+            queue_submit as *const c_void,
+        ),
+        b"vkQueueSubmit2" => (
+            523usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkQueueSubmit2\0") },
+            queue_submit2 as *const c_void,
+        ),
+        b"vkQueueSubmit2KHR" => (
+            523usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkQueueSubmit2\0") },
+            queue_submit2 as *const c_void,
+        ),
+        b"vkQueueWaitIdle" => (
+            524usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkQueueWaitIdle\0") },
+            queue_wait_idle as *const c_void,
+        ),
+        b"vkRegisterDeviceEventEXT" => (
+            525usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkRegisterDeviceEventEXT\0") },
+            register_device_event_ext as *const c_void,
+        ),
+        b"vkRegisterDisplayEventEXT" => (
+            526usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkRegisterDisplayEventEXT\0") },
+            register_display_event_ext as *const c_void,
+        ),
+        b"vkReleaseDisplayEXT" => (
+            527usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkReleaseDisplayEXT\0") },
+            release_display_ext as *const c_void,
+        ),
+        b"vkReleaseFullScreenExclusiveModeEXT" => (
+            528usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkReleaseFullScreenExclusiveModeEXT\0")
+            },
+            release_full_screen_exclusive_mode_ext as *const c_void,
+        ),
+        b"vkReleasePerformanceConfigurationINTEL" => (
+            529usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkReleasePerformanceConfigurationINTEL\0")
+            },
+            release_performance_configuration_intel as *const c_void,
+        ),
+        b"vkReleaseProfilingLockKHR" => (
+            530usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkReleaseProfilingLockKHR\0") },
+            release_profiling_lock_khr as *const c_void,
+        ),
+        b"vkReleaseSwapchainImagesEXT" => (
+            531usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkReleaseSwapchainImagesEXT\0") },
+            release_swapchain_images_ext as *const c_void,
+        ),
+        b"vkResetCommandBuffer" => (
+            532usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkResetCommandBuffer\0") },
+            reset_command_buffer as *const c_void,
+        ),
+        b"vkResetCommandPool" => (
+            533usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkResetCommandPool\0") },
+            reset_command_pool as *const c_void,
+        ),
+        b"vkResetDescriptorPool" => (
+            534usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkResetDescriptorPool\0") },
+            reset_descriptor_pool as *const c_void,
+        ),
+        b"vkResetEvent" => (
+            535usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkResetEvent\0") },
+            reset_event as *const c_void,
+        ),
+        b"vkResetFences" => (
+            536usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkResetFences\0") },
+            reset_fences as *const c_void,
+        ),
+        b"vkResetQueryPool" => (
+            537usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkResetQueryPool\0") },
+            reset_query_pool as *const c_void,
+        ),
+        b"vkResetQueryPoolEXT" => (
+            537usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkResetQueryPool\0") },
+            reset_query_pool as *const c_void,
+        ),
+        b"vkSetBufferCollectionBufferConstraintsFUCHSIA" => (
+            538usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkSetBufferCollectionBufferConstraintsFUCHSIA\0",
+                )
+            },
+            set_buffer_collection_buffer_constraints_fuchsia as *const c_void,
+        ),
+        b"vkSetBufferCollectionImageConstraintsFUCHSIA" => (
+            539usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(
+                    b"vkSetBufferCollectionImageConstraintsFUCHSIA\0",
+                )
+            },
+            set_buffer_collection_image_constraints_fuchsia as *const c_void,
+        ),
+        b"vkSetDebugUtilsObjectNameEXT" => (
+            540usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSetDebugUtilsObjectNameEXT\0") },
+            set_debug_utils_object_name_ext as *const c_void,
+        ),
+        b"vkSetDebugUtilsObjectTagEXT" => (
+            541usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSetDebugUtilsObjectTagEXT\0") },
+            set_debug_utils_object_tag_ext as *const c_void,
+        ),
+        b"vkSetDeviceMemoryPriorityEXT" => (
+            542usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSetDeviceMemoryPriorityEXT\0") },
+            set_device_memory_priority_ext as *const c_void,
+        ),
+        b"vkSetEvent" => (
+            543usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSetEvent\0") },
+            set_event as *const c_void,
+        ),
+        b"vkSetHdrMetadataEXT" => (
+            544usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSetHdrMetadataEXT\0") },
+            set_hdr_metadata_ext as *const c_void,
+        ),
+        b"vkSetLocalDimmingAMD" => (
+            545usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSetLocalDimmingAMD\0") },
+            set_local_dimming_amd as *const c_void,
+        ),
+        b"vkSetPrivateData" => (
+            546usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSetPrivateData\0") },
+            set_private_data as *const c_void,
+        ),
+        b"vkSetPrivateDataEXT" => (
+            546usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSetPrivateData\0") },
+            set_private_data as *const c_void,
+        ),
+        b"vkSignalSemaphore" => (
+            547usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSignalSemaphore\0") },
+            signal_semaphore as *const c_void,
+        ),
+        b"vkSignalSemaphoreKHR" => (
+            547usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSignalSemaphore\0") },
+            signal_semaphore as *const c_void,
+        ),
+        b"vkSubmitDebugUtilsMessageEXT" => (
+            548usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkSubmitDebugUtilsMessageEXT\0") },
+            submit_debug_utils_message_ext as *const c_void,
+        ),
+        b"vkTrimCommandPool" => (
+            549usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkTrimCommandPool\0") },
+            trim_command_pool as *const c_void,
+        ),
+        b"vkTrimCommandPoolKHR" => (
+            549usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkTrimCommandPool\0") },
+            trim_command_pool as *const c_void,
+        ),
+        b"vkUninitializePerformanceApiINTEL" => (
+            550usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkUninitializePerformanceApiINTEL\0") },
+            uninitialize_performance_api_intel as *const c_void,
+        ),
+        b"vkUnmapMemory" => (
+            551usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkUnmapMemory\0") },
+            unmap_memory as *const c_void,
+        ),
+        b"vkUnmapMemory2KHR" => (
+            552usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkUnmapMemory2KHR\0") },
+            unmap_memory2_khr as *const c_void,
+        ),
+        b"vkUpdateDescriptorSetWithTemplate" => (
+            553usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkUpdateDescriptorSetWithTemplate\0") },
+            update_descriptor_set_with_template as *const c_void,
+        ),
+        b"vkUpdateDescriptorSetWithTemplateKHR" => (
+            553usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkUpdateDescriptorSetWithTemplate\0") },
+            update_descriptor_set_with_template as *const c_void,
+        ),
+        b"vkUpdateDescriptorSets" => (
+            554usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkUpdateDescriptorSets\0") },
+            update_descriptor_sets as *const c_void,
+        ),
+        b"vkUpdateVideoSessionParametersKHR" => (
+            555usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkUpdateVideoSessionParametersKHR\0") },
+            update_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkWaitForFences" => (
+            556usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkWaitForFences\0") },
+            wait_for_fences as *const c_void,
+        ),
+        b"vkWaitForPresentKHR" => (
+            557usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkWaitForPresentKHR\0") },
+            wait_for_present_khr as *const c_void,
+        ),
+        b"vkWaitSemaphores" => (
+            558usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkWaitSemaphores\0") },
+            wait_semaphores as *const c_void,
+        ),
+        b"vkWaitSemaphoresKHR" => (
+            558usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkWaitSemaphores\0") },
+            wait_semaphores as *const c_void,
+        ),
+        b"vkWriteAccelerationStructuresPropertiesKHR" => (
+            559usize,
+            unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"vkWriteAccelerationStructuresPropertiesKHR\0")
+            },
+            write_acceleration_structures_properties_khr as *const c_void,
+        ),
+        b"vkWriteMicromapsPropertiesEXT" => (
+            560usize,
+            unsafe { CStr::from_bytes_with_nul_unchecked(b"vkWriteMicromapsPropertiesEXT\0") },
+            write_micromaps_properties_ext as *const c_void,
+        ),
+        _ => return None,
+    })
+}
+unsafe extern "system" fn acquire_drm_display_ext(
+    physical_device: vk::PhysicalDevice,
+    drm_fd: i32,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAcquireDrmDisplayEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAcquireDrmDisplayEXT>(next(
+        0usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<i32>(<*const _>::cast(&drm_fd)),
+        Arg::new::<vk::DisplayKHR>(<*const _>::cast(&display)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, drm_fd, display);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn acquire_full_screen_exclusive_mode_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAcquireFullScreenExclusiveModeEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAcquireFullScreenExclusiveModeEXT>(next(
+        1usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swapchain)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, swapchain);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn acquire_image_android(
+    device: vk::Device,
+    image: vk::Image,
+    native_fence_fd: c_int,
+    semaphore: vk::Semaphore,
+    fence: vk::Fence,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAcquireImageANDROID\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAcquireImageANDROID>(next(
+        2usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<c_int>(<*const _>::cast(&native_fence_fd)),
+        Arg::new::<vk::Semaphore>(<*const _>::cast(&semaphore)),
+        Arg::new::<vk::Fence>(<*const _>::cast(&fence)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, image, native_fence_fd, semaphore, fence);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn acquire_next_image2_khr(
+    device: vk::Device,
+    p_acquire_info: *const vk::AcquireNextImageInfoKHR<'_>,
+    p_image_index: *mut u32,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAcquireNextImage2KHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAcquireNextImage2KHR>(next(
+        3usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AcquireNextImageInfoKHR<'static>>(<*const _>::cast(&p_acquire_info)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_image_index)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_acquire_info, p_image_index);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn acquire_next_image_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    timeout: u64,
+    semaphore: vk::Semaphore,
+    fence: vk::Fence,
+    p_image_index: *mut u32,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAcquireNextImageKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAcquireNextImageKHR>(next(
+        4usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swapchain)),
+        Arg::new::<u64>(<*const _>::cast(&timeout)),
+        Arg::new::<vk::Semaphore>(<*const _>::cast(&semaphore)),
+        Arg::new::<vk::Fence>(<*const _>::cast(&fence)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_image_index)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, swapchain, timeout, semaphore, fence, p_image_index);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn acquire_performance_configuration_intel(
+    device: vk::Device,
+    p_acquire_info: *const vk::PerformanceConfigurationAcquireInfoINTEL<'_>,
+    p_configuration: *mut vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAcquirePerformanceConfigurationINTEL\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAcquirePerformanceConfigurationINTEL>(
+        next(5usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::PerformanceConfigurationAcquireInfoINTEL<'static>>(<*const _>::cast(
+            &p_acquire_info,
+        )),
+        Arg::new::<*mut vk::PerformanceConfigurationINTEL>(<*const _>::cast(&p_configuration)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_acquire_info, p_configuration);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn acquire_profiling_lock_khr(
+    device: vk::Device,
+    p_info: *const vk::AcquireProfilingLockInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAcquireProfilingLockKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAcquireProfilingLockKHR>(next(
+        6usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AcquireProfilingLockInfoKHR<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn acquire_winrt_display_nv(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAcquireWinrtDisplayNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAcquireWinrtDisplayNV>(next(
+        7usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::DisplayKHR>(<*const _>::cast(&display)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, display);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn acquire_xlib_display_ext(
+    physical_device: vk::PhysicalDevice,
+    dpy: *mut vk::Display,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAcquireXlibDisplayEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAcquireXlibDisplayEXT>(next(
+        8usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut vk::Display>(<*const _>::cast(&dpy)),
+        Arg::new::<vk::DisplayKHR>(<*const _>::cast(&display)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, dpy, display);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn allocate_command_buffers(
+    device: vk::Device,
+    p_allocate_info: *const vk::CommandBufferAllocateInfo<'_>,
+    p_command_buffers: *mut vk::CommandBuffer,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAllocateCommandBuffers\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAllocateCommandBuffers>(next(
+        9usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::CommandBufferAllocateInfo<'static>>(<*const _>::cast(
+            &p_allocate_info,
+        )),
+        Arg::new::<*mut vk::CommandBuffer>(<*const _>::cast(&p_command_buffers)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_allocate_info, p_command_buffers);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn allocate_descriptor_sets(
+    device: vk::Device,
+    p_allocate_info: *const vk::DescriptorSetAllocateInfo<'_>,
+    p_descriptor_sets: *mut vk::DescriptorSet,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAllocateDescriptorSets\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAllocateDescriptorSets>(next(
+        10usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DescriptorSetAllocateInfo<'static>>(<*const _>::cast(
+            &p_allocate_info,
+        )),
+        Arg::new::<*mut vk::DescriptorSet>(<*const _>::cast(&p_descriptor_sets)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_allocate_info, p_descriptor_sets);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn allocate_memory(
+    device: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkAllocateMemory\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkAllocateMemory>(next(
+        11usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::MemoryAllocateInfo<'static>>(<*const _>::cast(&p_allocate_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::DeviceMemory>(<*const _>::cast(&p_memory)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_allocate_info, p_allocator, p_memory);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn begin_command_buffer(
+    command_buffer: vk::CommandBuffer,
+    p_begin_info: *const vk::CommandBufferBeginInfo<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkBeginCommandBuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkBeginCommandBuffer>(next(
+        12usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CommandBufferBeginInfo<'static>>(<*const _>::cast(&p_begin_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(command_buffer, p_begin_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn bind_acceleration_structure_memory_nv(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindAccelerationStructureMemoryInfoNV<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkBindAccelerationStructureMemoryNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkBindAccelerationStructureMemoryNV>(next(
+        13usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&bind_info_count)),
+        Arg::new::<*const vk::BindAccelerationStructureMemoryInfoNV<'static>>(<*const _>::cast(
+            &p_bind_infos,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, bind_info_count, p_bind_infos);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn bind_buffer_memory(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    memory: vk::DeviceMemory,
+    memory_offset: vk::DeviceSize,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkBindBufferMemory\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkBindBufferMemory>(next(
+        14usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceMemory>(<*const _>::cast(&memory)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&memory_offset)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, buffer, memory, memory_offset);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn bind_buffer_memory2(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindBufferMemoryInfo<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkBindBufferMemory2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkBindBufferMemory2>(next(
+        15usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&bind_info_count)),
+        Arg::new::<*const vk::BindBufferMemoryInfo<'static>>(<*const _>::cast(&p_bind_infos)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, bind_info_count, p_bind_infos);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn bind_image_memory(
+    device: vk::Device,
+    image: vk::Image,
+    memory: vk::DeviceMemory,
+    memory_offset: vk::DeviceSize,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkBindImageMemory\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkBindImageMemory>(next(
+        16usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<vk::DeviceMemory>(<*const _>::cast(&memory)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&memory_offset)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, image, memory, memory_offset);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn bind_image_memory2(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindImageMemoryInfo<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkBindImageMemory2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkBindImageMemory2>(next(
+        17usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&bind_info_count)),
+        Arg::new::<*const vk::BindImageMemoryInfo<'static>>(<*const _>::cast(&p_bind_infos)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, bind_info_count, p_bind_infos);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn bind_optical_flow_session_image_nv(
+    device: vk::Device,
+    session: vk::OpticalFlowSessionNV,
+    binding_point: vk::OpticalFlowSessionBindingPointNV,
+    view: vk::ImageView,
+    layout: vk::ImageLayout,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkBindOpticalFlowSessionImageNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkBindOpticalFlowSessionImageNV>(next(
+        18usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::OpticalFlowSessionNV>(<*const _>::cast(&session)),
+        Arg::new::<vk::OpticalFlowSessionBindingPointNV>(<*const _>::cast(&binding_point)),
+        Arg::new::<vk::ImageView>(<*const _>::cast(&view)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&layout)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, session, binding_point, view, layout);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn bind_video_session_memory_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    bind_session_memory_info_count: u32,
+    p_bind_session_memory_infos: *const vk::BindVideoSessionMemoryInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkBindVideoSessionMemoryKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkBindVideoSessionMemoryKHR>(next(
+        19usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::VideoSessionKHR>(<*const _>::cast(&video_session)),
+        Arg::new::<u32>(<*const _>::cast(&bind_session_memory_info_count)),
+        Arg::new::<*const vk::BindVideoSessionMemoryInfoKHR<'static>>(<*const _>::cast(
+            &p_bind_session_memory_infos,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        video_session,
+        bind_session_memory_info_count,
+        p_bind_session_memory_infos,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn build_acceleration_structures_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkBuildAccelerationStructuresKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkBuildAccelerationStructuresKHR>(next(
+        20usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&deferred_operation)),
+        Arg::new::<u32>(<*const _>::cast(&info_count)),
+        Arg::new::<*const vk::AccelerationStructureBuildGeometryInfoKHR<'static>>(
+            <*const _>::cast(&p_infos),
+        ),
+        Arg::new::<*const *const vk::AccelerationStructureBuildRangeInfoKHR>(<*const _>::cast(
+            &pp_build_range_infos,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        deferred_operation,
+        info_count,
+        p_infos,
+        pp_build_range_infos,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn build_micromaps_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    info_count: u32,
+    p_infos: *const vk::MicromapBuildInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkBuildMicromapsEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkBuildMicromapsEXT>(next(
+        21usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&deferred_operation)),
+        Arg::new::<u32>(<*const _>::cast(&info_count)),
+        Arg::new::<*const vk::MicromapBuildInfoEXT<'static>>(<*const _>::cast(&p_infos)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, deferred_operation, info_count, p_infos);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn cmd_begin_conditional_rendering_ext(
+    command_buffer: vk::CommandBuffer,
+    p_conditional_rendering_begin: *const vk::ConditionalRenderingBeginInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginConditionalRenderingEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBeginConditionalRenderingEXT>(next(
+        22usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::ConditionalRenderingBeginInfoEXT<'static>>(<*const _>::cast(
+            &p_conditional_rendering_begin,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_conditional_rendering_begin);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_begin_debug_utils_label_ext(
+    command_buffer: vk::CommandBuffer,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginDebugUtilsLabelEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBeginDebugUtilsLabelEXT>(next(
+        23usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::DebugUtilsLabelEXT<'static>>(<*const _>::cast(&p_label_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_label_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_begin_query(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    flags: vk::QueryControlFlags,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginQuery\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBeginQuery>(next(
+        24usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&query)),
+        Arg::new::<vk::QueryControlFlags>(<*const _>::cast(&flags)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, query_pool, query, flags);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_begin_query_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    flags: vk::QueryControlFlags,
+    index: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginQueryIndexedEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBeginQueryIndexedEXT>(next(
+        25usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&query)),
+        Arg::new::<vk::QueryControlFlags>(<*const _>::cast(&flags)),
+        Arg::new::<u32>(<*const _>::cast(&index)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, query_pool, query, flags, index);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_begin_render_pass(
+    command_buffer: vk::CommandBuffer,
+    p_render_pass_begin: *const vk::RenderPassBeginInfo<'_>,
+    contents: vk::SubpassContents,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRenderPass\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBeginRenderPass>(next(
+        26usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::RenderPassBeginInfo<'static>>(<*const _>::cast(&p_render_pass_begin)),
+        Arg::new::<vk::SubpassContents>(<*const _>::cast(&contents)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_render_pass_begin, contents);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_begin_render_pass2(
+    command_buffer: vk::CommandBuffer,
+    p_render_pass_begin: *const vk::RenderPassBeginInfo<'_>,
+    p_subpass_begin_info: *const vk::SubpassBeginInfo<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRenderPass2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBeginRenderPass2>(next(
+        27usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::RenderPassBeginInfo<'static>>(<*const _>::cast(&p_render_pass_begin)),
+        Arg::new::<*const vk::SubpassBeginInfo<'static>>(<*const _>::cast(&p_subpass_begin_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_render_pass_begin, p_subpass_begin_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_begin_rendering(
+    command_buffer: vk::CommandBuffer,
+    p_rendering_info: *const vk::RenderingInfo<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRendering\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBeginRendering>(next(
+        28usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::RenderingInfo<'static>>(<*const _>::cast(&p_rendering_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_rendering_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_begin_transform_feedback_ext(
+    command_buffer: vk::CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const vk::Buffer,
+    p_counter_buffer_offsets: *const vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginTransformFeedbackEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBeginTransformFeedbackEXT>(next(
+        29usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_counter_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&counter_buffer_count)),
+        Arg::new::<*const vk::Buffer>(<*const _>::cast(&p_counter_buffers)),
+        Arg::new::<*const vk::DeviceSize>(<*const _>::cast(&p_counter_buffer_offsets)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_begin_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_begin_info: *const vk::VideoBeginCodingInfoKHR<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginVideoCodingKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBeginVideoCodingKHR>(next(
+        30usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::VideoBeginCodingInfoKHR<'static>>(<*const _>::cast(&p_begin_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_begin_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_descriptor_buffer_embedded_samplers_ext(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    set: u32,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkCmdBindDescriptorBufferEmbeddedSamplersEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindDescriptorBufferEmbeddedSamplersEXT>(
+        next(31usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineBindPoint>(<*const _>::cast(&pipeline_bind_point)),
+        Arg::new::<vk::PipelineLayout>(<*const _>::cast(&layout)),
+        Arg::new::<u32>(<*const _>::cast(&set)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, pipeline_bind_point, layout, set);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_descriptor_buffers_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer_count: u32,
+    p_binding_infos: *const vk::DescriptorBufferBindingInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindDescriptorBuffersEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindDescriptorBuffersEXT>(next(
+        32usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&buffer_count)),
+        Arg::new::<*const vk::DescriptorBufferBindingInfoEXT<'static>>(<*const _>::cast(
+            &p_binding_infos,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, buffer_count, p_binding_infos);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_descriptor_sets(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    first_set: u32,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const vk::DescriptorSet,
+    dynamic_offset_count: u32,
+    p_dynamic_offsets: *const u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindDescriptorSets\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindDescriptorSets>(next(
+        33usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineBindPoint>(<*const _>::cast(&pipeline_bind_point)),
+        Arg::new::<vk::PipelineLayout>(<*const _>::cast(&layout)),
+        Arg::new::<u32>(<*const _>::cast(&first_set)),
+        Arg::new::<u32>(<*const _>::cast(&descriptor_set_count)),
+        Arg::new::<*const vk::DescriptorSet>(<*const _>::cast(&p_descriptor_sets)),
+        Arg::new::<u32>(<*const _>::cast(&dynamic_offset_count)),
+        Arg::new::<*const u32>(<*const _>::cast(&p_dynamic_offsets)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        first_set,
+        descriptor_set_count,
+        p_descriptor_sets,
+        dynamic_offset_count,
+        p_dynamic_offsets,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_index_buffer(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    index_type: vk::IndexType,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindIndexBuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindIndexBuffer>(next(
+        34usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+        Arg::new::<vk::IndexType>(<*const _>::cast(&index_type)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, buffer, offset, index_type);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_invocation_mask_huawei(
+    command_buffer: vk::CommandBuffer,
+    image_view: vk::ImageView,
+    image_layout: vk::ImageLayout,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindInvocationMaskHUAWEI\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindInvocationMaskHUAWEI>(next(
+        35usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::ImageView>(<*const _>::cast(&image_view)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&image_layout)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, image_view, image_layout);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_pipeline(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    pipeline: vk::Pipeline,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindPipeline\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindPipeline>(next(
+        36usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineBindPoint>(<*const _>::cast(&pipeline_bind_point)),
+        Arg::new::<vk::Pipeline>(<*const _>::cast(&pipeline)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, pipeline_bind_point, pipeline);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_pipeline_shader_group_nv(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    pipeline: vk::Pipeline,
+    group_index: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindPipelineShaderGroupNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindPipelineShaderGroupNV>(next(
+        37usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineBindPoint>(<*const _>::cast(&pipeline_bind_point)),
+        Arg::new::<vk::Pipeline>(<*const _>::cast(&pipeline)),
+        Arg::new::<u32>(<*const _>::cast(&group_index)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, pipeline_bind_point, pipeline, group_index);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_shaders_ext(
+    command_buffer: vk::CommandBuffer,
+    stage_count: u32,
+    p_stages: *const vk::ShaderStageFlags,
+    p_shaders: *const vk::ShaderEXT,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindShadersEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindShadersEXT>(next(
+        38usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&stage_count)),
+        Arg::new::<*const vk::ShaderStageFlags>(<*const _>::cast(&p_stages)),
+        Arg::new::<*const vk::ShaderEXT>(<*const _>::cast(&p_shaders)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, stage_count, p_stages, p_shaders);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_shading_rate_image_nv(
+    command_buffer: vk::CommandBuffer,
+    image_view: vk::ImageView,
+    image_layout: vk::ImageLayout,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindShadingRateImageNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindShadingRateImageNV>(next(
+        39usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::ImageView>(<*const _>::cast(&image_view)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&image_layout)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, image_view, image_layout);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_transform_feedback_buffers_ext(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+    p_sizes: *const vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindTransformFeedbackBuffersEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindTransformFeedbackBuffersEXT>(next(
+        40usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_binding)),
+        Arg::new::<u32>(<*const _>::cast(&binding_count)),
+        Arg::new::<*const vk::Buffer>(<*const _>::cast(&p_buffers)),
+        Arg::new::<*const vk::DeviceSize>(<*const _>::cast(&p_offsets)),
+        Arg::new::<*const vk::DeviceSize>(<*const _>::cast(&p_sizes)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_vertex_buffers(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindVertexBuffers\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindVertexBuffers>(next(
+        41usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_binding)),
+        Arg::new::<u32>(<*const _>::cast(&binding_count)),
+        Arg::new::<*const vk::Buffer>(<*const _>::cast(&p_buffers)),
+        Arg::new::<*const vk::DeviceSize>(<*const _>::cast(&p_offsets)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_bind_vertex_buffers2(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+    p_sizes: *const vk::DeviceSize,
+    p_strides: *const vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBindVertexBuffers2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBindVertexBuffers2>(next(
+        42usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_binding)),
+        Arg::new::<u32>(<*const _>::cast(&binding_count)),
+        Arg::new::<*const vk::Buffer>(<*const _>::cast(&p_buffers)),
+        Arg::new::<*const vk::DeviceSize>(<*const _>::cast(&p_offsets)),
+        Arg::new::<*const vk::DeviceSize>(<*const _>::cast(&p_sizes)),
+        Arg::new::<*const vk::DeviceSize>(<*const _>::cast(&p_strides)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+        p_strides,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_blit_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageBlit,
+    filter: vk::Filter,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBlitImage\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBlitImage>(next(
+        43usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Image>(<*const _>::cast(&src_image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&src_image_layout)),
+        Arg::new::<vk::Image>(<*const _>::cast(&dst_image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&dst_image_layout)),
+        Arg::new::<u32>(<*const _>::cast(&region_count)),
+        Arg::new::<*const vk::ImageBlit>(<*const _>::cast(&p_regions)),
+        Arg::new::<vk::Filter>(<*const _>::cast(&filter)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+        filter,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_blit_image2(
+    command_buffer: vk::CommandBuffer,
+    p_blit_image_info: *const vk::BlitImageInfo2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBlitImage2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBlitImage2>(next(
+        44usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::BlitImageInfo2<'static>>(<*const _>::cast(&p_blit_image_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_blit_image_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_build_acceleration_structure_nv(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::AccelerationStructureInfoNV<'_>,
+    instance_data: vk::Buffer,
+    instance_offset: vk::DeviceSize,
+    update: vk::Bool32,
+    dst: vk::AccelerationStructureNV,
+    src: vk::AccelerationStructureNV,
+    scratch: vk::Buffer,
+    scratch_offset: vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBuildAccelerationStructureNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBuildAccelerationStructureNV>(next(
+        45usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::AccelerationStructureInfoNV<'static>>(<*const _>::cast(&p_info)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&instance_data)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&instance_offset)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&update)),
+        Arg::new::<vk::AccelerationStructureNV>(<*const _>::cast(&dst)),
+        Arg::new::<vk::AccelerationStructureNV>(<*const _>::cast(&src)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&scratch)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&scratch_offset)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        p_info,
+        instance_data,
+        instance_offset,
+        update,
+        dst,
+        src,
+        scratch,
+        scratch_offset,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_build_acceleration_structures_indirect_khr(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    p_indirect_device_addresses: *const vk::DeviceAddress,
+    p_indirect_strides: *const u32,
+    pp_max_primitive_counts: *const *const u32,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkCmdBuildAccelerationStructuresIndirectKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBuildAccelerationStructuresIndirectKHR>(
+        next(46usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&info_count)),
+        Arg::new::<*const vk::AccelerationStructureBuildGeometryInfoKHR<'static>>(
+            <*const _>::cast(&p_infos),
+        ),
+        Arg::new::<*const vk::DeviceAddress>(<*const _>::cast(&p_indirect_device_addresses)),
+        Arg::new::<*const u32>(<*const _>::cast(&p_indirect_strides)),
+        Arg::new::<*const *const u32>(<*const _>::cast(&pp_max_primitive_counts)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        info_count,
+        p_infos,
+        p_indirect_device_addresses,
+        p_indirect_strides,
+        pp_max_primitive_counts,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_build_acceleration_structures_khr(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBuildAccelerationStructuresKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBuildAccelerationStructuresKHR>(next(
+        47usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&info_count)),
+        Arg::new::<*const vk::AccelerationStructureBuildGeometryInfoKHR<'static>>(
+            <*const _>::cast(&p_infos),
+        ),
+        Arg::new::<*const *const vk::AccelerationStructureBuildRangeInfoKHR>(<*const _>::cast(
+            &pp_build_range_infos,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, info_count, p_infos, pp_build_range_infos);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_build_micromaps_ext(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::MicromapBuildInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdBuildMicromapsEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdBuildMicromapsEXT>(next(
+        48usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&info_count)),
+        Arg::new::<*const vk::MicromapBuildInfoEXT<'static>>(<*const _>::cast(&p_infos)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, info_count, p_infos);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_clear_attachments(
+    command_buffer: vk::CommandBuffer,
+    attachment_count: u32,
+    p_attachments: *const vk::ClearAttachment,
+    rect_count: u32,
+    p_rects: *const vk::ClearRect,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdClearAttachments\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdClearAttachments>(next(
+        49usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&attachment_count)),
+        Arg::new::<*const vk::ClearAttachment>(<*const _>::cast(&p_attachments)),
+        Arg::new::<u32>(<*const _>::cast(&rect_count)),
+        Arg::new::<*const vk::ClearRect>(<*const _>::cast(&p_rects)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        attachment_count,
+        p_attachments,
+        rect_count,
+        p_rects,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_clear_color_image(
+    command_buffer: vk::CommandBuffer,
+    image: vk::Image,
+    image_layout: vk::ImageLayout,
+    p_color: *const vk::ClearColorValue,
+    range_count: u32,
+    p_ranges: *const vk::ImageSubresourceRange,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdClearColorImage\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdClearColorImage>(next(
+        50usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&image_layout)),
+        Arg::new::<*const vk::ClearColorValue>(<*const _>::cast(&p_color)),
+        Arg::new::<u32>(<*const _>::cast(&range_count)),
+        Arg::new::<*const vk::ImageSubresourceRange>(<*const _>::cast(&p_ranges)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        image,
+        image_layout,
+        p_color,
+        range_count,
+        p_ranges,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_clear_depth_stencil_image(
+    command_buffer: vk::CommandBuffer,
+    image: vk::Image,
+    image_layout: vk::ImageLayout,
+    p_depth_stencil: *const vk::ClearDepthStencilValue,
+    range_count: u32,
+    p_ranges: *const vk::ImageSubresourceRange,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdClearDepthStencilImage\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdClearDepthStencilImage>(next(
+        51usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&image_layout)),
+        Arg::new::<*const vk::ClearDepthStencilValue>(<*const _>::cast(&p_depth_stencil)),
+        Arg::new::<u32>(<*const _>::cast(&range_count)),
+        Arg::new::<*const vk::ImageSubresourceRange>(<*const _>::cast(&p_ranges)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        image,
+        image_layout,
+        p_depth_stencil,
+        range_count,
+        p_ranges,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_control_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_coding_control_info: *const vk::VideoCodingControlInfoKHR<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdControlVideoCodingKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdControlVideoCodingKHR>(next(
+        52usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::VideoCodingControlInfoKHR<'static>>(<*const _>::cast(
+            &p_coding_control_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_coding_control_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_acceleration_structure_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyAccelerationStructureInfoKHR<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyAccelerationStructureKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyAccelerationStructureKHR>(next(
+        53usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CopyAccelerationStructureInfoKHR<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_acceleration_structure_nv(
+    command_buffer: vk::CommandBuffer,
+    dst: vk::AccelerationStructureNV,
+    src: vk::AccelerationStructureNV,
+    mode: vk::CopyAccelerationStructureModeKHR,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyAccelerationStructureNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyAccelerationStructureNV>(next(
+        54usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::AccelerationStructureNV>(<*const _>::cast(&dst)),
+        Arg::new::<vk::AccelerationStructureNV>(<*const _>::cast(&src)),
+        Arg::new::<vk::CopyAccelerationStructureModeKHR>(<*const _>::cast(&mode)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, dst, src, mode);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_acceleration_structure_to_memory_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyAccelerationStructureToMemoryKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyAccelerationStructureToMemoryKHR>(
+        next(55usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CopyAccelerationStructureToMemoryInfoKHR<'static>>(<*const _>::cast(
+            &p_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_buffer(
+    command_buffer: vk::CommandBuffer,
+    src_buffer: vk::Buffer,
+    dst_buffer: vk::Buffer,
+    region_count: u32,
+    p_regions: *const vk::BufferCopy,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyBuffer>(next(
+        56usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&src_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&dst_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&region_count)),
+        Arg::new::<*const vk::BufferCopy>(<*const _>::cast(&p_regions)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        src_buffer,
+        dst_buffer,
+        region_count,
+        p_regions,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_buffer2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_buffer_info: *const vk::CopyBufferInfo2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBuffer2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyBuffer2>(next(
+        57usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CopyBufferInfo2<'static>>(<*const _>::cast(&p_copy_buffer_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_copy_buffer_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_buffer_to_image(
+    command_buffer: vk::CommandBuffer,
+    src_buffer: vk::Buffer,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::BufferImageCopy,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBufferToImage\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyBufferToImage>(next(
+        58usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&src_buffer)),
+        Arg::new::<vk::Image>(<*const _>::cast(&dst_image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&dst_image_layout)),
+        Arg::new::<u32>(<*const _>::cast(&region_count)),
+        Arg::new::<*const vk::BufferImageCopy>(<*const _>::cast(&p_regions)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        src_buffer,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_buffer_to_image2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_buffer_to_image_info: *const vk::CopyBufferToImageInfo2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBufferToImage2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyBufferToImage2>(next(
+        59usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CopyBufferToImageInfo2<'static>>(<*const _>::cast(
+            &p_copy_buffer_to_image_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_copy_buffer_to_image_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageCopy,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImage\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyImage>(next(
+        60usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Image>(<*const _>::cast(&src_image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&src_image_layout)),
+        Arg::new::<vk::Image>(<*const _>::cast(&dst_image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&dst_image_layout)),
+        Arg::new::<u32>(<*const _>::cast(&region_count)),
+        Arg::new::<*const vk::ImageCopy>(<*const _>::cast(&p_regions)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_image2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_image_info: *const vk::CopyImageInfo2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImage2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyImage2>(next(
+        61usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CopyImageInfo2<'static>>(<*const _>::cast(&p_copy_image_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_copy_image_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_image_to_buffer(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_buffer: vk::Buffer,
+    region_count: u32,
+    p_regions: *const vk::BufferImageCopy,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImageToBuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyImageToBuffer>(next(
+        62usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Image>(<*const _>::cast(&src_image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&src_image_layout)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&dst_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&region_count)),
+        Arg::new::<*const vk::BufferImageCopy>(<*const _>::cast(&p_regions)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_buffer,
+        region_count,
+        p_regions,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_image_to_buffer2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_image_to_buffer_info: *const vk::CopyImageToBufferInfo2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImageToBuffer2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyImageToBuffer2>(next(
+        63usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CopyImageToBufferInfo2<'static>>(<*const _>::cast(
+            &p_copy_image_to_buffer_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_copy_image_to_buffer_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_memory_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    copy_buffer_address: vk::DeviceAddress,
+    copy_count: u32,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMemoryIndirectNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyMemoryIndirectNV>(next(
+        64usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::DeviceAddress>(<*const _>::cast(&copy_buffer_address)),
+        Arg::new::<u32>(<*const _>::cast(&copy_count)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, copy_buffer_address, copy_count, stride);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_memory_to_acceleration_structure_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMemoryToAccelerationStructureKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyMemoryToAccelerationStructureKHR>(
+        next(65usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CopyMemoryToAccelerationStructureInfoKHR<'static>>(<*const _>::cast(
+            &p_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_memory_to_image_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    copy_buffer_address: vk::DeviceAddress,
+    copy_count: u32,
+    stride: u32,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    p_image_subresources: *const vk::ImageSubresourceLayers,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMemoryToImageIndirectNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyMemoryToImageIndirectNV>(next(
+        66usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::DeviceAddress>(<*const _>::cast(&copy_buffer_address)),
+        Arg::new::<u32>(<*const _>::cast(&copy_count)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+        Arg::new::<vk::Image>(<*const _>::cast(&dst_image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&dst_image_layout)),
+        Arg::new::<*const vk::ImageSubresourceLayers>(<*const _>::cast(&p_image_subresources)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        copy_buffer_address,
+        copy_count,
+        stride,
+        dst_image,
+        dst_image_layout,
+        p_image_subresources,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_memory_to_micromap_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMemoryToMicromapInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMemoryToMicromapEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyMemoryToMicromapEXT>(next(
+        67usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CopyMemoryToMicromapInfoEXT<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_micromap_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMicromapInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMicromapEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyMicromapEXT>(next(
+        68usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CopyMicromapInfoEXT<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_micromap_to_memory_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMicromapToMemoryInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMicromapToMemoryEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyMicromapToMemoryEXT>(next(
+        69usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CopyMicromapToMemoryInfoEXT<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_copy_query_pool_results(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    stride: vk::DeviceSize,
+    flags: vk::QueryResultFlags,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyQueryPoolResults\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCopyQueryPoolResults>(next(
+        70usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&first_query)),
+        Arg::new::<u32>(<*const _>::cast(&query_count)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&dst_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&dst_offset)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&stride)),
+        Arg::new::<vk::QueryResultFlags>(<*const _>::cast(&flags)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        query_pool,
+        first_query,
+        query_count,
+        dst_buffer,
+        dst_offset,
+        stride,
+        flags,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_cu_launch_kernel_nvx(
+    command_buffer: vk::CommandBuffer,
+    p_launch_info: *const vk::CuLaunchInfoNVX<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdCuLaunchKernelNVX\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdCuLaunchKernelNVX>(next(
+        71usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::CuLaunchInfoNVX<'static>>(<*const _>::cast(&p_launch_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_launch_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_debug_marker_begin_ext(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::DebugMarkerMarkerInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDebugMarkerBeginEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDebugMarkerBeginEXT>(next(
+        72usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::DebugMarkerMarkerInfoEXT<'static>>(<*const _>::cast(&p_marker_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_marker_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_debug_marker_end_ext(command_buffer: vk::CommandBuffer) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDebugMarkerEndEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDebugMarkerEndEXT>(next(
+        73usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [Arg::new::<vk::CommandBuffer>(<*const _>::cast(
+        &command_buffer,
+    ))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_debug_marker_insert_ext(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::DebugMarkerMarkerInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDebugMarkerInsertEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDebugMarkerInsertEXT>(next(
+        74usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::DebugMarkerMarkerInfoEXT<'static>>(<*const _>::cast(&p_marker_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_marker_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_decode_video_khr(
+    command_buffer: vk::CommandBuffer,
+    p_decode_info: *const vk::VideoDecodeInfoKHR<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDecodeVideoKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDecodeVideoKHR>(next(
+        75usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::VideoDecodeInfoKHR<'static>>(<*const _>::cast(&p_decode_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_decode_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_decompress_memory_indirect_count_nv(
+    command_buffer: vk::CommandBuffer,
+    indirect_commands_address: vk::DeviceAddress,
+    indirect_commands_count_address: vk::DeviceAddress,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDecompressMemoryIndirectCountNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDecompressMemoryIndirectCountNV>(next(
+        76usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::DeviceAddress>(<*const _>::cast(&indirect_commands_address)),
+        Arg::new::<vk::DeviceAddress>(<*const _>::cast(&indirect_commands_count_address)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        indirect_commands_address,
+        indirect_commands_count_address,
+        stride,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_decompress_memory_nv(
+    command_buffer: vk::CommandBuffer,
+    decompress_region_count: u32,
+    p_decompress_memory_regions: *const vk::DecompressMemoryRegionNV,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDecompressMemoryNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDecompressMemoryNV>(next(
+        77usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&decompress_region_count)),
+        Arg::new::<*const vk::DecompressMemoryRegionNV>(<*const _>::cast(
+            &p_decompress_memory_regions,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        decompress_region_count,
+        p_decompress_memory_regions,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_dispatch(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatch\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDispatch>(next(
+        78usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_x)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_y)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_z)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, group_count_x, group_count_y, group_count_z);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_dispatch_base(
+    command_buffer: vk::CommandBuffer,
+    base_group_x: u32,
+    base_group_y: u32,
+    base_group_z: u32,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatchBase\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDispatchBase>(next(
+        79usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&base_group_x)),
+        Arg::new::<u32>(<*const _>::cast(&base_group_y)),
+        Arg::new::<u32>(<*const _>::cast(&base_group_z)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_x)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_y)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_z)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        base_group_x,
+        base_group_y,
+        base_group_z,
+        group_count_x,
+        group_count_y,
+        group_count_z,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_dispatch_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatchIndirect\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDispatchIndirect>(next(
+        80usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, buffer, offset);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw(
+    command_buffer: vk::CommandBuffer,
+    vertex_count: u32,
+    instance_count: u32,
+    first_vertex: u32,
+    first_instance: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDraw\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDraw>(next(
+        81usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&vertex_count)),
+        Arg::new::<u32>(<*const _>::cast(&instance_count)),
+        Arg::new::<u32>(<*const _>::cast(&first_vertex)),
+        Arg::new::<u32>(<*const _>::cast(&first_instance)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        vertex_count,
+        instance_count,
+        first_vertex,
+        first_instance,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_cluster_huawei(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawClusterHUAWEI\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawClusterHUAWEI>(next(
+        82usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_x)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_y)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_z)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, group_count_x, group_count_y, group_count_z);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_cluster_indirect_huawei(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawClusterIndirectHUAWEI\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawClusterIndirectHUAWEI>(next(
+        83usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, buffer, offset);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_indexed(
+    command_buffer: vk::CommandBuffer,
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    vertex_offset: i32,
+    first_instance: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexed\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawIndexed>(next(
+        84usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&index_count)),
+        Arg::new::<u32>(<*const _>::cast(&instance_count)),
+        Arg::new::<u32>(<*const _>::cast(&first_index)),
+        Arg::new::<i32>(<*const _>::cast(&vertex_offset)),
+        Arg::new::<u32>(<*const _>::cast(&first_instance)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        index_count,
+        instance_count,
+        first_index,
+        vertex_offset,
+        first_instance,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_indexed_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexedIndirect\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawIndexedIndirect>(next(
+        85usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+        Arg::new::<u32>(<*const _>::cast(&draw_count)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, buffer, offset, draw_count, stride);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_indexed_indirect_count(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexedIndirectCount\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawIndexedIndirectCount>(next(
+        86usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&count_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&count_buffer_offset)),
+        Arg::new::<u32>(<*const _>::cast(&max_draw_count)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirect\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawIndirect>(next(
+        87usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+        Arg::new::<u32>(<*const _>::cast(&draw_count)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, buffer, offset, draw_count, stride);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_indirect_byte_count_ext(
+    command_buffer: vk::CommandBuffer,
+    instance_count: u32,
+    first_instance: u32,
+    counter_buffer: vk::Buffer,
+    counter_buffer_offset: vk::DeviceSize,
+    counter_offset: u32,
+    vertex_stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirectByteCountEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawIndirectByteCountEXT>(next(
+        88usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&instance_count)),
+        Arg::new::<u32>(<*const _>::cast(&first_instance)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&counter_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&counter_buffer_offset)),
+        Arg::new::<u32>(<*const _>::cast(&counter_offset)),
+        Arg::new::<u32>(<*const _>::cast(&vertex_stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        instance_count,
+        first_instance,
+        counter_buffer,
+        counter_buffer_offset,
+        counter_offset,
+        vertex_stride,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_indirect_count(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirectCount\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawIndirectCount>(next(
+        89usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&count_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&count_buffer_offset)),
+        Arg::new::<u32>(<*const _>::cast(&max_draw_count)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_ext(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawMeshTasksEXT>(next(
+        90usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_x)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_y)),
+        Arg::new::<u32>(<*const _>::cast(&group_count_z)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, group_count_x, group_count_y, group_count_z);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_count_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksIndirectCountEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawMeshTasksIndirectCountEXT>(next(
+        91usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&count_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&count_buffer_offset)),
+        Arg::new::<u32>(<*const _>::cast(&max_draw_count)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_count_nv(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksIndirectCountNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawMeshTasksIndirectCountNV>(next(
+        92usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&count_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&count_buffer_offset)),
+        Arg::new::<u32>(<*const _>::cast(&max_draw_count)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksIndirectEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawMeshTasksIndirectEXT>(next(
+        93usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+        Arg::new::<u32>(<*const _>::cast(&draw_count)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, buffer, offset, draw_count, stride);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksIndirectNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawMeshTasksIndirectNV>(next(
+        94usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+        Arg::new::<u32>(<*const _>::cast(&draw_count)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, buffer, offset, draw_count, stride);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_nv(
+    command_buffer: vk::CommandBuffer,
+    task_count: u32,
+    first_task: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawMeshTasksNV>(next(
+        95usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&task_count)),
+        Arg::new::<u32>(<*const _>::cast(&first_task)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, task_count, first_task);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_multi_ext(
+    command_buffer: vk::CommandBuffer,
+    draw_count: u32,
+    p_vertex_info: *const vk::MultiDrawInfoEXT,
+    instance_count: u32,
+    first_instance: u32,
+    stride: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMultiEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawMultiEXT>(next(
+        96usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&draw_count)),
+        Arg::new::<*const vk::MultiDrawInfoEXT>(<*const _>::cast(&p_vertex_info)),
+        Arg::new::<u32>(<*const _>::cast(&instance_count)),
+        Arg::new::<u32>(<*const _>::cast(&first_instance)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        draw_count,
+        p_vertex_info,
+        instance_count,
+        first_instance,
+        stride,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_draw_multi_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    draw_count: u32,
+    p_index_info: *const vk::MultiDrawIndexedInfoEXT,
+    instance_count: u32,
+    first_instance: u32,
+    stride: u32,
+    p_vertex_offset: *const i32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMultiIndexedEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdDrawMultiIndexedEXT>(next(
+        97usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&draw_count)),
+        Arg::new::<*const vk::MultiDrawIndexedInfoEXT>(<*const _>::cast(&p_index_info)),
+        Arg::new::<u32>(<*const _>::cast(&instance_count)),
+        Arg::new::<u32>(<*const _>::cast(&first_instance)),
+        Arg::new::<u32>(<*const _>::cast(&stride)),
+        Arg::new::<*const i32>(<*const _>::cast(&p_vertex_offset)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        draw_count,
+        p_index_info,
+        instance_count,
+        first_instance,
+        stride,
+        p_vertex_offset,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_encode_video_khr(
+    command_buffer: vk::CommandBuffer,
+    p_encode_info: *const vk::VideoEncodeInfoKHR<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdEncodeVideoKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdEncodeVideoKHR>(next(
+        98usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::VideoEncodeInfoKHR<'static>>(<*const _>::cast(&p_encode_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_encode_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_end_conditional_rendering_ext(command_buffer: vk::CommandBuffer) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdEndConditionalRenderingEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdEndConditionalRenderingEXT>(next(
+        99usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [Arg::new::<vk::CommandBuffer>(<*const _>::cast(
+        &command_buffer,
+    ))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_end_debug_utils_label_ext(command_buffer: vk::CommandBuffer) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdEndDebugUtilsLabelEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdEndDebugUtilsLabelEXT>(next(
+        100usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [Arg::new::<vk::CommandBuffer>(<*const _>::cast(
+        &command_buffer,
+    ))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_end_query(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdEndQuery\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdEndQuery>(next(
+        101usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&query)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, query_pool, query);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_end_query_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    index: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdEndQueryIndexedEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdEndQueryIndexedEXT>(next(
+        102usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&query)),
+        Arg::new::<u32>(<*const _>::cast(&index)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, query_pool, query, index);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_end_render_pass(command_buffer: vk::CommandBuffer) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRenderPass\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdEndRenderPass>(next(
+        103usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [Arg::new::<vk::CommandBuffer>(<*const _>::cast(
+        &command_buffer,
+    ))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_end_render_pass2(
+    command_buffer: vk::CommandBuffer,
+    p_subpass_end_info: *const vk::SubpassEndInfo<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRenderPass2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdEndRenderPass2>(next(
+        104usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::SubpassEndInfo<'static>>(<*const _>::cast(&p_subpass_end_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_subpass_end_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_end_rendering(command_buffer: vk::CommandBuffer) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRendering\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdEndRendering>(next(
+        105usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [Arg::new::<vk::CommandBuffer>(<*const _>::cast(
+        &command_buffer,
+    ))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_end_transform_feedback_ext(
+    command_buffer: vk::CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const vk::Buffer,
+    p_counter_buffer_offsets: *const vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdEndTransformFeedbackEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdEndTransformFeedbackEXT>(next(
+        106usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_counter_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&counter_buffer_count)),
+        Arg::new::<*const vk::Buffer>(<*const _>::cast(&p_counter_buffers)),
+        Arg::new::<*const vk::DeviceSize>(<*const _>::cast(&p_counter_buffer_offsets)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_end_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_end_coding_info: *const vk::VideoEndCodingInfoKHR<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdEndVideoCodingKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdEndVideoCodingKHR>(next(
+        107usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::VideoEndCodingInfoKHR<'static>>(<*const _>::cast(&p_end_coding_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_end_coding_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_execute_commands(
+    command_buffer: vk::CommandBuffer,
+    command_buffer_count: u32,
+    p_command_buffers: *const vk::CommandBuffer,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdExecuteCommands\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdExecuteCommands>(next(
+        108usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&command_buffer_count)),
+        Arg::new::<*const vk::CommandBuffer>(<*const _>::cast(&p_command_buffers)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, command_buffer_count, p_command_buffers);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_execute_generated_commands_nv(
+    command_buffer: vk::CommandBuffer,
+    is_preprocessed: vk::Bool32,
+    p_generated_commands_info: *const vk::GeneratedCommandsInfoNV<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdExecuteGeneratedCommandsNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdExecuteGeneratedCommandsNV>(next(
+        109usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&is_preprocessed)),
+        Arg::new::<*const vk::GeneratedCommandsInfoNV<'static>>(<*const _>::cast(
+            &p_generated_commands_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, is_preprocessed, p_generated_commands_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_fill_buffer(
+    command_buffer: vk::CommandBuffer,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    data: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdFillBuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdFillBuffer>(next(
+        110usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&dst_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&dst_offset)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&size)),
+        Arg::new::<u32>(<*const _>::cast(&data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, dst_buffer, dst_offset, size, data);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_insert_debug_utils_label_ext(
+    command_buffer: vk::CommandBuffer,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdInsertDebugUtilsLabelEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdInsertDebugUtilsLabelEXT>(next(
+        111usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::DebugUtilsLabelEXT<'static>>(<*const _>::cast(&p_label_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_label_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_next_subpass(
+    command_buffer: vk::CommandBuffer,
+    contents: vk::SubpassContents,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdNextSubpass\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdNextSubpass>(next(
+        112usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::SubpassContents>(<*const _>::cast(&contents)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, contents);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_next_subpass2(
+    command_buffer: vk::CommandBuffer,
+    p_subpass_begin_info: *const vk::SubpassBeginInfo<'_>,
+    p_subpass_end_info: *const vk::SubpassEndInfo<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdNextSubpass2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdNextSubpass2>(next(
+        113usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::SubpassBeginInfo<'static>>(<*const _>::cast(&p_subpass_begin_info)),
+        Arg::new::<*const vk::SubpassEndInfo<'static>>(<*const _>::cast(&p_subpass_end_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_subpass_begin_info, p_subpass_end_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_optical_flow_execute_nv(
+    command_buffer: vk::CommandBuffer,
+    session: vk::OpticalFlowSessionNV,
+    p_execute_info: *const vk::OpticalFlowExecuteInfoNV<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdOpticalFlowExecuteNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdOpticalFlowExecuteNV>(next(
+        114usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::OpticalFlowSessionNV>(<*const _>::cast(&session)),
+        Arg::new::<*const vk::OpticalFlowExecuteInfoNV<'static>>(<*const _>::cast(&p_execute_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, session, p_execute_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_pipeline_barrier(
+    command_buffer: vk::CommandBuffer,
+    src_stage_mask: vk::PipelineStageFlags,
+    dst_stage_mask: vk::PipelineStageFlags,
+    dependency_flags: vk::DependencyFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const vk::MemoryBarrier<'_>,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const vk::BufferMemoryBarrier<'_>,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const vk::ImageMemoryBarrier<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdPipelineBarrier\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdPipelineBarrier>(next(
+        115usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineStageFlags>(<*const _>::cast(&src_stage_mask)),
+        Arg::new::<vk::PipelineStageFlags>(<*const _>::cast(&dst_stage_mask)),
+        Arg::new::<vk::DependencyFlags>(<*const _>::cast(&dependency_flags)),
+        Arg::new::<u32>(<*const _>::cast(&memory_barrier_count)),
+        Arg::new::<*const vk::MemoryBarrier<'static>>(<*const _>::cast(&p_memory_barriers)),
+        Arg::new::<u32>(<*const _>::cast(&buffer_memory_barrier_count)),
+        Arg::new::<*const vk::BufferMemoryBarrier<'static>>(<*const _>::cast(
+            &p_buffer_memory_barriers,
+        )),
+        Arg::new::<u32>(<*const _>::cast(&image_memory_barrier_count)),
+        Arg::new::<*const vk::ImageMemoryBarrier<'static>>(<*const _>::cast(
+            &p_image_memory_barriers,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        src_stage_mask,
+        dst_stage_mask,
+        dependency_flags,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_pipeline_barrier2(
+    command_buffer: vk::CommandBuffer,
+    p_dependency_info: *const vk::DependencyInfo<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdPipelineBarrier2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdPipelineBarrier2>(next(
+        116usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::DependencyInfo<'static>>(<*const _>::cast(&p_dependency_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_dependency_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_preprocess_generated_commands_nv(
+    command_buffer: vk::CommandBuffer,
+    p_generated_commands_info: *const vk::GeneratedCommandsInfoNV<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdPreprocessGeneratedCommandsNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdPreprocessGeneratedCommandsNV>(next(
+        117usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::GeneratedCommandsInfoNV<'static>>(<*const _>::cast(
+            &p_generated_commands_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_generated_commands_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_push_constants(
+    command_buffer: vk::CommandBuffer,
+    layout: vk::PipelineLayout,
+    stage_flags: vk::ShaderStageFlags,
+    offset: u32,
+    size: u32,
+    p_values: *const c_void,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdPushConstants\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdPushConstants>(next(
+        118usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineLayout>(<*const _>::cast(&layout)),
+        Arg::new::<vk::ShaderStageFlags>(<*const _>::cast(&stage_flags)),
+        Arg::new::<u32>(<*const _>::cast(&offset)),
+        Arg::new::<u32>(<*const _>::cast(&size)),
+        Arg::new::<*const c_void>(<*const _>::cast(&p_values)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, layout, stage_flags, offset, size, p_values);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_push_descriptor_set_khr(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    set: u32,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const vk::WriteDescriptorSet<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdPushDescriptorSetKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdPushDescriptorSetKHR>(next(
+        119usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineBindPoint>(<*const _>::cast(&pipeline_bind_point)),
+        Arg::new::<vk::PipelineLayout>(<*const _>::cast(&layout)),
+        Arg::new::<u32>(<*const _>::cast(&set)),
+        Arg::new::<u32>(<*const _>::cast(&descriptor_write_count)),
+        Arg::new::<*const vk::WriteDescriptorSet<'static>>(<*const _>::cast(&p_descriptor_writes)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        set,
+        descriptor_write_count,
+        p_descriptor_writes,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_push_descriptor_set_with_template_khr(
+    command_buffer: vk::CommandBuffer,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    layout: vk::PipelineLayout,
+    set: u32,
+    p_data: *const c_void,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdPushDescriptorSetWithTemplateKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdPushDescriptorSetWithTemplateKHR>(
+        next(120usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::DescriptorUpdateTemplate>(<*const _>::cast(&descriptor_update_template)),
+        Arg::new::<vk::PipelineLayout>(<*const _>::cast(&layout)),
+        Arg::new::<u32>(<*const _>::cast(&set)),
+        Arg::new::<*const c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        descriptor_update_template,
+        layout,
+        set,
+        p_data,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_reset_event(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdResetEvent\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdResetEvent>(next(
+        121usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Event>(<*const _>::cast(&event)),
+        Arg::new::<vk::PipelineStageFlags>(<*const _>::cast(&stage_mask)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, event, stage_mask);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_reset_event2(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags2,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdResetEvent2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdResetEvent2>(next(
+        122usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Event>(<*const _>::cast(&event)),
+        Arg::new::<vk::PipelineStageFlags2>(<*const _>::cast(&stage_mask)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, event, stage_mask);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_reset_query_pool(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdResetQueryPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdResetQueryPool>(next(
+        123usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&first_query)),
+        Arg::new::<u32>(<*const _>::cast(&query_count)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, query_pool, first_query, query_count);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_resolve_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageResolve,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdResolveImage\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdResolveImage>(next(
+        124usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Image>(<*const _>::cast(&src_image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&src_image_layout)),
+        Arg::new::<vk::Image>(<*const _>::cast(&dst_image)),
+        Arg::new::<vk::ImageLayout>(<*const _>::cast(&dst_image_layout)),
+        Arg::new::<u32>(<*const _>::cast(&region_count)),
+        Arg::new::<*const vk::ImageResolve>(<*const _>::cast(&p_regions)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_resolve_image2(
+    command_buffer: vk::CommandBuffer,
+    p_resolve_image_info: *const vk::ResolveImageInfo2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdResolveImage2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdResolveImage2>(next(
+        125usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::ResolveImageInfo2<'static>>(<*const _>::cast(&p_resolve_image_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_resolve_image_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_alpha_to_coverage_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    alpha_to_coverage_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetAlphaToCoverageEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetAlphaToCoverageEnableEXT>(next(
+        126usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&alpha_to_coverage_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, alpha_to_coverage_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_alpha_to_one_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    alpha_to_one_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetAlphaToOneEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetAlphaToOneEnableEXT>(next(
+        127usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&alpha_to_one_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, alpha_to_one_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_attachment_feedback_loop_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    aspect_mask: vk::ImageAspectFlags,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetAttachmentFeedbackLoopEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetAttachmentFeedbackLoopEnableEXT>(
+        next(128usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::ImageAspectFlags>(<*const _>::cast(&aspect_mask)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, aspect_mask);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_blend_constants(
+    command_buffer: vk::CommandBuffer,
+    blend_constants: *const [f32; 4usize],
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetBlendConstants\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetBlendConstants>(next(
+        129usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const [f32; 4usize]>(<*const _>::cast(&blend_constants)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, blend_constants);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_checkpoint_nv(
+    command_buffer: vk::CommandBuffer,
+    p_checkpoint_marker: *const c_void,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCheckpointNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetCheckpointNV>(next(
+        130usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const c_void>(<*const _>::cast(&p_checkpoint_marker)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_checkpoint_marker);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_coarse_sample_order_nv(
+    command_buffer: vk::CommandBuffer,
+    sample_order_type: vk::CoarseSampleOrderTypeNV,
+    custom_sample_order_count: u32,
+    p_custom_sample_orders: *const vk::CoarseSampleOrderCustomNV<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoarseSampleOrderNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetCoarseSampleOrderNV>(next(
+        131usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::CoarseSampleOrderTypeNV>(<*const _>::cast(&sample_order_type)),
+        Arg::new::<u32>(<*const _>::cast(&custom_sample_order_count)),
+        Arg::new::<*const vk::CoarseSampleOrderCustomNV<'static>>(<*const _>::cast(
+            &p_custom_sample_orders,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        sample_order_type,
+        custom_sample_order_count,
+        p_custom_sample_orders,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_color_blend_advanced_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_advanced: *const vk::ColorBlendAdvancedEXT,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorBlendAdvancedEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetColorBlendAdvancedEXT>(next(
+        132usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_attachment)),
+        Arg::new::<u32>(<*const _>::cast(&attachment_count)),
+        Arg::new::<*const vk::ColorBlendAdvancedEXT>(<*const _>::cast(&p_color_blend_advanced)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_advanced,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_color_blend_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_enables: *const vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorBlendEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetColorBlendEnableEXT>(next(
+        133usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_attachment)),
+        Arg::new::<u32>(<*const _>::cast(&attachment_count)),
+        Arg::new::<*const vk::Bool32>(<*const _>::cast(&p_color_blend_enables)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_enables,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_color_blend_equation_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_equations: *const vk::ColorBlendEquationEXT,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorBlendEquationEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetColorBlendEquationEXT>(next(
+        134usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_attachment)),
+        Arg::new::<u32>(<*const _>::cast(&attachment_count)),
+        Arg::new::<*const vk::ColorBlendEquationEXT>(<*const _>::cast(&p_color_blend_equations)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_equations,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_color_write_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    attachment_count: u32,
+    p_color_write_enables: *const vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorWriteEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetColorWriteEnableEXT>(next(
+        135usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&attachment_count)),
+        Arg::new::<*const vk::Bool32>(<*const _>::cast(&p_color_write_enables)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, attachment_count, p_color_write_enables);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_color_write_mask_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_write_masks: *const vk::ColorComponentFlags,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorWriteMaskEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetColorWriteMaskEXT>(next(
+        136usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_attachment)),
+        Arg::new::<u32>(<*const _>::cast(&attachment_count)),
+        Arg::new::<*const vk::ColorComponentFlags>(<*const _>::cast(&p_color_write_masks)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_write_masks,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_conservative_rasterization_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    conservative_rasterization_mode: vk::ConservativeRasterizationModeEXT,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkCmdSetConservativeRasterizationModeEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetConservativeRasterizationModeEXT>(
+        next(137usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::ConservativeRasterizationModeEXT>(<*const _>::cast(
+            &conservative_rasterization_mode,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, conservative_rasterization_mode);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_coverage_modulation_mode_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_mode: vk::CoverageModulationModeNV,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageModulationModeNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetCoverageModulationModeNV>(next(
+        138usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::CoverageModulationModeNV>(<*const _>::cast(&coverage_modulation_mode)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, coverage_modulation_mode);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_coverage_modulation_table_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_table_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageModulationTableEnableNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetCoverageModulationTableEnableNV>(
+        next(139usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&coverage_modulation_table_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, coverage_modulation_table_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_coverage_modulation_table_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_table_count: u32,
+    p_coverage_modulation_table: *const f32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageModulationTableNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetCoverageModulationTableNV>(next(
+        140usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&coverage_modulation_table_count)),
+        Arg::new::<*const f32>(<*const _>::cast(&p_coverage_modulation_table)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        coverage_modulation_table_count,
+        p_coverage_modulation_table,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_coverage_reduction_mode_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_reduction_mode: vk::CoverageReductionModeNV,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageReductionModeNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetCoverageReductionModeNV>(next(
+        141usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::CoverageReductionModeNV>(<*const _>::cast(&coverage_reduction_mode)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, coverage_reduction_mode);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_coverage_to_color_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_to_color_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageToColorEnableNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetCoverageToColorEnableNV>(next(
+        142usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&coverage_to_color_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, coverage_to_color_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_coverage_to_color_location_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_to_color_location: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCoverageToColorLocationNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetCoverageToColorLocationNV>(next(
+        143usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&coverage_to_color_location)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, coverage_to_color_location);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_cull_mode(
+    command_buffer: vk::CommandBuffer,
+    cull_mode: vk::CullModeFlags,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCullMode\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetCullMode>(next(
+        144usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::CullModeFlags>(<*const _>::cast(&cull_mode)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, cull_mode);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_bias(
+    command_buffer: vk::CommandBuffer,
+    depth_bias_constant_factor: f32,
+    depth_bias_clamp: f32,
+    depth_bias_slope_factor: f32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBias\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthBias>(next(
+        145usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<f32>(<*const _>::cast(&depth_bias_constant_factor)),
+        Arg::new::<f32>(<*const _>::cast(&depth_bias_clamp)),
+        Arg::new::<f32>(<*const _>::cast(&depth_bias_slope_factor)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        depth_bias_constant_factor,
+        depth_bias_clamp,
+        depth_bias_slope_factor,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_bias2_ext(
+    command_buffer: vk::CommandBuffer,
+    p_depth_bias_info: *const vk::DepthBiasInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBias2EXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthBias2EXT>(next(
+        146usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::DepthBiasInfoEXT<'static>>(<*const _>::cast(&p_depth_bias_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_depth_bias_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_bias_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_bias_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBiasEnable\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthBiasEnable>(next(
+        147usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&depth_bias_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, depth_bias_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_bounds(
+    command_buffer: vk::CommandBuffer,
+    min_depth_bounds: f32,
+    max_depth_bounds: f32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBounds\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthBounds>(next(
+        148usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<f32>(<*const _>::cast(&min_depth_bounds)),
+        Arg::new::<f32>(<*const _>::cast(&max_depth_bounds)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, min_depth_bounds, max_depth_bounds);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_bounds_test_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_bounds_test_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBoundsTestEnable\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthBoundsTestEnable>(next(
+        149usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&depth_bounds_test_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, depth_bounds_test_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_clamp_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    depth_clamp_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthClampEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthClampEnableEXT>(next(
+        150usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&depth_clamp_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, depth_clamp_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_clip_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    depth_clip_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthClipEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthClipEnableEXT>(next(
+        151usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&depth_clip_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, depth_clip_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_clip_negative_one_to_one_ext(
+    command_buffer: vk::CommandBuffer,
+    negative_one_to_one: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthClipNegativeOneToOneEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthClipNegativeOneToOneEXT>(next(
+        152usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&negative_one_to_one)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, negative_one_to_one);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_compare_op(
+    command_buffer: vk::CommandBuffer,
+    depth_compare_op: vk::CompareOp,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthCompareOp\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthCompareOp>(next(
+        153usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::CompareOp>(<*const _>::cast(&depth_compare_op)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, depth_compare_op);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_test_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_test_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthTestEnable\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthTestEnable>(next(
+        154usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&depth_test_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, depth_test_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_depth_write_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_write_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthWriteEnable\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDepthWriteEnable>(next(
+        155usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&depth_write_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, depth_write_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_descriptor_buffer_offsets_ext(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    first_set: u32,
+    set_count: u32,
+    p_buffer_indices: *const u32,
+    p_offsets: *const vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDescriptorBufferOffsetsEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDescriptorBufferOffsetsEXT>(next(
+        156usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineBindPoint>(<*const _>::cast(&pipeline_bind_point)),
+        Arg::new::<vk::PipelineLayout>(<*const _>::cast(&layout)),
+        Arg::new::<u32>(<*const _>::cast(&first_set)),
+        Arg::new::<u32>(<*const _>::cast(&set_count)),
+        Arg::new::<*const u32>(<*const _>::cast(&p_buffer_indices)),
+        Arg::new::<*const vk::DeviceSize>(<*const _>::cast(&p_offsets)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        first_set,
+        set_count,
+        p_buffer_indices,
+        p_offsets,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_device_mask(command_buffer: vk::CommandBuffer, device_mask: u32) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDeviceMask\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDeviceMask>(next(
+        157usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&device_mask)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, device_mask);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_discard_rectangle_ext(
+    command_buffer: vk::CommandBuffer,
+    first_discard_rectangle: u32,
+    discard_rectangle_count: u32,
+    p_discard_rectangles: *const vk::Rect2D,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDiscardRectangleEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDiscardRectangleEXT>(next(
+        158usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_discard_rectangle)),
+        Arg::new::<u32>(<*const _>::cast(&discard_rectangle_count)),
+        Arg::new::<*const vk::Rect2D>(<*const _>::cast(&p_discard_rectangles)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_discard_rectangle,
+        discard_rectangle_count,
+        p_discard_rectangles,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_discard_rectangle_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    discard_rectangle_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDiscardRectangleEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDiscardRectangleEnableEXT>(next(
+        159usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&discard_rectangle_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, discard_rectangle_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_discard_rectangle_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    discard_rectangle_mode: vk::DiscardRectangleModeEXT,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDiscardRectangleModeEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetDiscardRectangleModeEXT>(next(
+        160usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::DiscardRectangleModeEXT>(<*const _>::cast(&discard_rectangle_mode)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, discard_rectangle_mode);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_event(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetEvent\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetEvent>(next(
+        161usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Event>(<*const _>::cast(&event)),
+        Arg::new::<vk::PipelineStageFlags>(<*const _>::cast(&stage_mask)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, event, stage_mask);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_event2(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    p_dependency_info: *const vk::DependencyInfo<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetEvent2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetEvent2>(next(
+        162usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Event>(<*const _>::cast(&event)),
+        Arg::new::<*const vk::DependencyInfo<'static>>(<*const _>::cast(&p_dependency_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, event, p_dependency_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_exclusive_scissor_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissor_enables: *const vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetExclusiveScissorEnableNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetExclusiveScissorEnableNV>(next(
+        163usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_exclusive_scissor)),
+        Arg::new::<u32>(<*const _>::cast(&exclusive_scissor_count)),
+        Arg::new::<*const vk::Bool32>(<*const _>::cast(&p_exclusive_scissor_enables)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_exclusive_scissor,
+        exclusive_scissor_count,
+        p_exclusive_scissor_enables,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_exclusive_scissor_nv(
+    command_buffer: vk::CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissors: *const vk::Rect2D,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetExclusiveScissorNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetExclusiveScissorNV>(next(
+        164usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_exclusive_scissor)),
+        Arg::new::<u32>(<*const _>::cast(&exclusive_scissor_count)),
+        Arg::new::<*const vk::Rect2D>(<*const _>::cast(&p_exclusive_scissors)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_exclusive_scissor,
+        exclusive_scissor_count,
+        p_exclusive_scissors,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_extra_primitive_overestimation_size_ext(
+    command_buffer: vk::CommandBuffer,
+    extra_primitive_overestimation_size: f32,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkCmdSetExtraPrimitiveOverestimationSizeEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetExtraPrimitiveOverestimationSizeEXT>(
+        next(165usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<f32>(<*const _>::cast(&extra_primitive_overestimation_size)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, extra_primitive_overestimation_size);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_fragment_shading_rate_enum_nv(
+    command_buffer: vk::CommandBuffer,
+    shading_rate: vk::FragmentShadingRateNV,
+    combiner_ops: *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetFragmentShadingRateEnumNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetFragmentShadingRateEnumNV>(next(
+        166usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::FragmentShadingRateNV>(<*const _>::cast(&shading_rate)),
+        Arg::new::<*const [vk::FragmentShadingRateCombinerOpKHR; 2usize]>(<*const _>::cast(
+            &combiner_ops,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, shading_rate, combiner_ops);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_fragment_shading_rate_khr(
+    command_buffer: vk::CommandBuffer,
+    p_fragment_size: *const vk::Extent2D,
+    combiner_ops: *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetFragmentShadingRateKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetFragmentShadingRateKHR>(next(
+        167usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::Extent2D>(<*const _>::cast(&p_fragment_size)),
+        Arg::new::<*const [vk::FragmentShadingRateCombinerOpKHR; 2usize]>(<*const _>::cast(
+            &combiner_ops,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_fragment_size, combiner_ops);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_front_face(
+    command_buffer: vk::CommandBuffer,
+    front_face: vk::FrontFace,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetFrontFace\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetFrontFace>(next(
+        168usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::FrontFace>(<*const _>::cast(&front_face)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, front_face);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_line_rasterization_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    line_rasterization_mode: vk::LineRasterizationModeEXT,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLineRasterizationModeEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetLineRasterizationModeEXT>(next(
+        169usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::LineRasterizationModeEXT>(<*const _>::cast(&line_rasterization_mode)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, line_rasterization_mode);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_line_stipple_ext(
+    command_buffer: vk::CommandBuffer,
+    line_stipple_factor: u32,
+    line_stipple_pattern: u16,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLineStippleEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetLineStippleEXT>(next(
+        170usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&line_stipple_factor)),
+        Arg::new::<u16>(<*const _>::cast(&line_stipple_pattern)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, line_stipple_factor, line_stipple_pattern);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_line_stipple_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    stippled_line_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLineStippleEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetLineStippleEnableEXT>(next(
+        171usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&stippled_line_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, stippled_line_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_line_width(command_buffer: vk::CommandBuffer, line_width: f32) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLineWidth\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetLineWidth>(next(
+        172usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<f32>(<*const _>::cast(&line_width)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, line_width);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_logic_op_ext(
+    command_buffer: vk::CommandBuffer,
+    logic_op: vk::LogicOp,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLogicOpEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetLogicOpEXT>(next(
+        173usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::LogicOp>(<*const _>::cast(&logic_op)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, logic_op);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_logic_op_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    logic_op_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLogicOpEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetLogicOpEnableEXT>(next(
+        174usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&logic_op_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, logic_op_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_patch_control_points_ext(
+    command_buffer: vk::CommandBuffer,
+    patch_control_points: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPatchControlPointsEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetPatchControlPointsEXT>(next(
+        175usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&patch_control_points)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, patch_control_points);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_performance_marker_intel(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::PerformanceMarkerInfoINTEL<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPerformanceMarkerINTEL\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetPerformanceMarkerINTEL>(next(
+        176usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::PerformanceMarkerInfoINTEL<'static>>(<*const _>::cast(
+            &p_marker_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(command_buffer, p_marker_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn cmd_set_performance_override_intel(
+    command_buffer: vk::CommandBuffer,
+    p_override_info: *const vk::PerformanceOverrideInfoINTEL<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPerformanceOverrideINTEL\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetPerformanceOverrideINTEL>(next(
+        177usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::PerformanceOverrideInfoINTEL<'static>>(<*const _>::cast(
+            &p_override_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(command_buffer, p_override_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn cmd_set_performance_stream_marker_intel(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::PerformanceStreamMarkerInfoINTEL<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPerformanceStreamMarkerINTEL\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetPerformanceStreamMarkerINTEL>(next(
+        178usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::PerformanceStreamMarkerInfoINTEL<'static>>(<*const _>::cast(
+            &p_marker_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(command_buffer, p_marker_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn cmd_set_polygon_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    polygon_mode: vk::PolygonMode,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPolygonModeEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetPolygonModeEXT>(next(
+        179usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PolygonMode>(<*const _>::cast(&polygon_mode)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, polygon_mode);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_primitive_restart_enable(
+    command_buffer: vk::CommandBuffer,
+    primitive_restart_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPrimitiveRestartEnable\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetPrimitiveRestartEnable>(next(
+        180usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&primitive_restart_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, primitive_restart_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_primitive_topology(
+    command_buffer: vk::CommandBuffer,
+    primitive_topology: vk::PrimitiveTopology,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPrimitiveTopology\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetPrimitiveTopology>(next(
+        181usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PrimitiveTopology>(<*const _>::cast(&primitive_topology)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, primitive_topology);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_provoking_vertex_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    provoking_vertex_mode: vk::ProvokingVertexModeEXT,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetProvokingVertexModeEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetProvokingVertexModeEXT>(next(
+        182usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::ProvokingVertexModeEXT>(<*const _>::cast(&provoking_vertex_mode)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, provoking_vertex_mode);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_rasterization_samples_ext(
+    command_buffer: vk::CommandBuffer,
+    rasterization_samples: vk::SampleCountFlags,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRasterizationSamplesEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetRasterizationSamplesEXT>(next(
+        183usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::SampleCountFlags>(<*const _>::cast(&rasterization_samples)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, rasterization_samples);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_rasterization_stream_ext(
+    command_buffer: vk::CommandBuffer,
+    rasterization_stream: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRasterizationStreamEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetRasterizationStreamEXT>(next(
+        184usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&rasterization_stream)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, rasterization_stream);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_rasterizer_discard_enable(
+    command_buffer: vk::CommandBuffer,
+    rasterizer_discard_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRasterizerDiscardEnable\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetRasterizerDiscardEnable>(next(
+        185usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&rasterizer_discard_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, rasterizer_discard_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_ray_tracing_pipeline_stack_size_khr(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stack_size: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRayTracingPipelineStackSizeKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetRayTracingPipelineStackSizeKHR>(
+        next(186usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&pipeline_stack_size)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, pipeline_stack_size);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_representative_fragment_test_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    representative_fragment_test_enable: vk::Bool32,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkCmdSetRepresentativeFragmentTestEnableNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetRepresentativeFragmentTestEnableNV>(
+        next(187usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&representative_fragment_test_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, representative_fragment_test_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_sample_locations_ext(
+    command_buffer: vk::CommandBuffer,
+    p_sample_locations_info: *const vk::SampleLocationsInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetSampleLocationsEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetSampleLocationsEXT>(next(
+        188usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::SampleLocationsInfoEXT<'static>>(<*const _>::cast(
+            &p_sample_locations_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, p_sample_locations_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_sample_locations_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    sample_locations_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetSampleLocationsEnableEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetSampleLocationsEnableEXT>(next(
+        189usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&sample_locations_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, sample_locations_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_sample_mask_ext(
+    command_buffer: vk::CommandBuffer,
+    samples: vk::SampleCountFlags,
+    p_sample_mask: *const vk::SampleMask,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetSampleMaskEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetSampleMaskEXT>(next(
+        190usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::SampleCountFlags>(<*const _>::cast(&samples)),
+        Arg::new::<*const vk::SampleMask>(<*const _>::cast(&p_sample_mask)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, samples, p_sample_mask);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_scissor(
+    command_buffer: vk::CommandBuffer,
+    first_scissor: u32,
+    scissor_count: u32,
+    p_scissors: *const vk::Rect2D,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetScissor\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetScissor>(next(
+        191usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_scissor)),
+        Arg::new::<u32>(<*const _>::cast(&scissor_count)),
+        Arg::new::<*const vk::Rect2D>(<*const _>::cast(&p_scissors)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, first_scissor, scissor_count, p_scissors);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_scissor_with_count(
+    command_buffer: vk::CommandBuffer,
+    scissor_count: u32,
+    p_scissors: *const vk::Rect2D,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetScissorWithCount\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetScissorWithCount>(next(
+        192usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&scissor_count)),
+        Arg::new::<*const vk::Rect2D>(<*const _>::cast(&p_scissors)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, scissor_count, p_scissors);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_shading_rate_image_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    shading_rate_image_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetShadingRateImageEnableNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetShadingRateImageEnableNV>(next(
+        193usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&shading_rate_image_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, shading_rate_image_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_stencil_compare_mask(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    compare_mask: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilCompareMask\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetStencilCompareMask>(next(
+        194usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::StencilFaceFlags>(<*const _>::cast(&face_mask)),
+        Arg::new::<u32>(<*const _>::cast(&compare_mask)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, face_mask, compare_mask);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_stencil_op(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    fail_op: vk::StencilOp,
+    pass_op: vk::StencilOp,
+    depth_fail_op: vk::StencilOp,
+    compare_op: vk::CompareOp,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilOp\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetStencilOp>(next(
+        195usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::StencilFaceFlags>(<*const _>::cast(&face_mask)),
+        Arg::new::<vk::StencilOp>(<*const _>::cast(&fail_op)),
+        Arg::new::<vk::StencilOp>(<*const _>::cast(&pass_op)),
+        Arg::new::<vk::StencilOp>(<*const _>::cast(&depth_fail_op)),
+        Arg::new::<vk::CompareOp>(<*const _>::cast(&compare_op)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        face_mask,
+        fail_op,
+        pass_op,
+        depth_fail_op,
+        compare_op,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_stencil_reference(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    reference: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilReference\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetStencilReference>(next(
+        196usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::StencilFaceFlags>(<*const _>::cast(&face_mask)),
+        Arg::new::<u32>(<*const _>::cast(&reference)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, face_mask, reference);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_stencil_test_enable(
+    command_buffer: vk::CommandBuffer,
+    stencil_test_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilTestEnable\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetStencilTestEnable>(next(
+        197usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&stencil_test_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, stencil_test_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_stencil_write_mask(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    write_mask: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilWriteMask\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetStencilWriteMask>(next(
+        198usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::StencilFaceFlags>(<*const _>::cast(&face_mask)),
+        Arg::new::<u32>(<*const _>::cast(&write_mask)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, face_mask, write_mask);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_tessellation_domain_origin_ext(
+    command_buffer: vk::CommandBuffer,
+    domain_origin: vk::TessellationDomainOrigin,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetTessellationDomainOriginEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetTessellationDomainOriginEXT>(next(
+        199usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::TessellationDomainOrigin>(<*const _>::cast(&domain_origin)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, domain_origin);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_vertex_input_ext(
+    command_buffer: vk::CommandBuffer,
+    vertex_binding_description_count: u32,
+    p_vertex_binding_descriptions: *const vk::VertexInputBindingDescription2EXT<'_>,
+    vertex_attribute_description_count: u32,
+    p_vertex_attribute_descriptions: *const vk::VertexInputAttributeDescription2EXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetVertexInputEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetVertexInputEXT>(next(
+        200usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&vertex_binding_description_count)),
+        Arg::new::<*const vk::VertexInputBindingDescription2EXT<'static>>(<*const _>::cast(
+            &p_vertex_binding_descriptions,
+        )),
+        Arg::new::<u32>(<*const _>::cast(&vertex_attribute_description_count)),
+        Arg::new::<*const vk::VertexInputAttributeDescription2EXT<'static>>(<*const _>::cast(
+            &p_vertex_attribute_descriptions,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        vertex_binding_description_count,
+        p_vertex_binding_descriptions,
+        vertex_attribute_description_count,
+        p_vertex_attribute_descriptions,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_viewport(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewports: *const vk::Viewport,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewport\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetViewport>(next(
+        201usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_viewport)),
+        Arg::new::<u32>(<*const _>::cast(&viewport_count)),
+        Arg::new::<*const vk::Viewport>(<*const _>::cast(&p_viewports)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, first_viewport, viewport_count, p_viewports);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_viewport_shading_rate_palette_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_shading_rate_palettes: *const vk::ShadingRatePaletteNV<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportShadingRatePaletteNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetViewportShadingRatePaletteNV>(next(
+        202usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_viewport)),
+        Arg::new::<u32>(<*const _>::cast(&viewport_count)),
+        Arg::new::<*const vk::ShadingRatePaletteNV<'static>>(<*const _>::cast(
+            &p_shading_rate_palettes,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_shading_rate_palettes,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_viewport_swizzle_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_swizzles: *const vk::ViewportSwizzleNV,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportSwizzleNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetViewportSwizzleNV>(next(
+        203usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_viewport)),
+        Arg::new::<u32>(<*const _>::cast(&viewport_count)),
+        Arg::new::<*const vk::ViewportSwizzleNV>(<*const _>::cast(&p_viewport_swizzles)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_viewport_swizzles,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_viewport_w_scaling_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    viewport_w_scaling_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportWScalingEnableNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetViewportWScalingEnableNV>(next(
+        204usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&viewport_w_scaling_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, viewport_w_scaling_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_viewport_w_scaling_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_w_scalings: *const vk::ViewportWScalingNV,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportWScalingNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetViewportWScalingNV>(next(
+        205usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&first_viewport)),
+        Arg::new::<u32>(<*const _>::cast(&viewport_count)),
+        Arg::new::<*const vk::ViewportWScalingNV>(<*const _>::cast(&p_viewport_w_scalings)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_viewport_w_scalings,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_set_viewport_with_count(
+    command_buffer: vk::CommandBuffer,
+    viewport_count: u32,
+    p_viewports: *const vk::Viewport,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportWithCount\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSetViewportWithCount>(next(
+        206usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&viewport_count)),
+        Arg::new::<*const vk::Viewport>(<*const _>::cast(&p_viewports)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, viewport_count, p_viewports);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_subpass_shading_huawei(command_buffer: vk::CommandBuffer) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdSubpassShadingHUAWEI\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdSubpassShadingHUAWEI>(next(
+        207usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [Arg::new::<vk::CommandBuffer>(<*const _>::cast(
+        &command_buffer,
+    ))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_trace_rays_indirect2_khr(
+    command_buffer: vk::CommandBuffer,
+    indirect_device_address: vk::DeviceAddress,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysIndirect2KHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdTraceRaysIndirect2KHR>(next(
+        208usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::DeviceAddress>(<*const _>::cast(&indirect_device_address)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, indirect_device_address);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_trace_rays_indirect_khr(
+    command_buffer: vk::CommandBuffer,
+    p_raygen_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_miss_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_hit_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_callable_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    indirect_device_address: vk::DeviceAddress,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysIndirectKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdTraceRaysIndirectKHR>(next(
+        209usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::StridedDeviceAddressRegionKHR>(<*const _>::cast(
+            &p_raygen_shader_binding_table,
+        )),
+        Arg::new::<*const vk::StridedDeviceAddressRegionKHR>(<*const _>::cast(
+            &p_miss_shader_binding_table,
+        )),
+        Arg::new::<*const vk::StridedDeviceAddressRegionKHR>(<*const _>::cast(
+            &p_hit_shader_binding_table,
+        )),
+        Arg::new::<*const vk::StridedDeviceAddressRegionKHR>(<*const _>::cast(
+            &p_callable_shader_binding_table,
+        )),
+        Arg::new::<vk::DeviceAddress>(<*const _>::cast(&indirect_device_address)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        p_raygen_shader_binding_table,
+        p_miss_shader_binding_table,
+        p_hit_shader_binding_table,
+        p_callable_shader_binding_table,
+        indirect_device_address,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_trace_rays_khr(
+    command_buffer: vk::CommandBuffer,
+    p_raygen_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_miss_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_hit_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_callable_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    width: u32,
+    height: u32,
+    depth: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdTraceRaysKHR>(next(
+        210usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<*const vk::StridedDeviceAddressRegionKHR>(<*const _>::cast(
+            &p_raygen_shader_binding_table,
+        )),
+        Arg::new::<*const vk::StridedDeviceAddressRegionKHR>(<*const _>::cast(
+            &p_miss_shader_binding_table,
+        )),
+        Arg::new::<*const vk::StridedDeviceAddressRegionKHR>(<*const _>::cast(
+            &p_hit_shader_binding_table,
+        )),
+        Arg::new::<*const vk::StridedDeviceAddressRegionKHR>(<*const _>::cast(
+            &p_callable_shader_binding_table,
+        )),
+        Arg::new::<u32>(<*const _>::cast(&width)),
+        Arg::new::<u32>(<*const _>::cast(&height)),
+        Arg::new::<u32>(<*const _>::cast(&depth)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        p_raygen_shader_binding_table,
+        p_miss_shader_binding_table,
+        p_hit_shader_binding_table,
+        p_callable_shader_binding_table,
+        width,
+        height,
+        depth,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_trace_rays_nv(
+    command_buffer: vk::CommandBuffer,
+    raygen_shader_binding_table_buffer: vk::Buffer,
+    raygen_shader_binding_offset: vk::DeviceSize,
+    miss_shader_binding_table_buffer: vk::Buffer,
+    miss_shader_binding_offset: vk::DeviceSize,
+    miss_shader_binding_stride: vk::DeviceSize,
+    hit_shader_binding_table_buffer: vk::Buffer,
+    hit_shader_binding_offset: vk::DeviceSize,
+    hit_shader_binding_stride: vk::DeviceSize,
+    callable_shader_binding_table_buffer: vk::Buffer,
+    callable_shader_binding_offset: vk::DeviceSize,
+    callable_shader_binding_stride: vk::DeviceSize,
+    width: u32,
+    height: u32,
+    depth: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdTraceRaysNV>(next(
+        211usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&raygen_shader_binding_table_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&raygen_shader_binding_offset)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&miss_shader_binding_table_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&miss_shader_binding_offset)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&miss_shader_binding_stride)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&hit_shader_binding_table_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&hit_shader_binding_offset)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&hit_shader_binding_stride)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&callable_shader_binding_table_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&callable_shader_binding_offset)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&callable_shader_binding_stride)),
+        Arg::new::<u32>(<*const _>::cast(&width)),
+        Arg::new::<u32>(<*const _>::cast(&height)),
+        Arg::new::<u32>(<*const _>::cast(&depth)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        raygen_shader_binding_table_buffer,
+        raygen_shader_binding_offset,
+        miss_shader_binding_table_buffer,
+        miss_shader_binding_offset,
+        miss_shader_binding_stride,
+        hit_shader_binding_table_buffer,
+        hit_shader_binding_offset,
+        hit_shader_binding_stride,
+        callable_shader_binding_table_buffer,
+        callable_shader_binding_offset,
+        callable_shader_binding_stride,
+        width,
+        height,
+        depth,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_update_buffer(
+    command_buffer: vk::CommandBuffer,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    data_size: vk::DeviceSize,
+    p_data: *const c_void,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdUpdateBuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdUpdateBuffer>(next(
+        212usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&dst_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&dst_offset)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&data_size)),
+        Arg::new::<*const c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, dst_buffer, dst_offset, data_size, p_data);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_wait_events(
+    command_buffer: vk::CommandBuffer,
+    event_count: u32,
+    p_events: *const vk::Event,
+    src_stage_mask: vk::PipelineStageFlags,
+    dst_stage_mask: vk::PipelineStageFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const vk::MemoryBarrier<'_>,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const vk::BufferMemoryBarrier<'_>,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const vk::ImageMemoryBarrier<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdWaitEvents\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdWaitEvents>(next(
+        213usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&event_count)),
+        Arg::new::<*const vk::Event>(<*const _>::cast(&p_events)),
+        Arg::new::<vk::PipelineStageFlags>(<*const _>::cast(&src_stage_mask)),
+        Arg::new::<vk::PipelineStageFlags>(<*const _>::cast(&dst_stage_mask)),
+        Arg::new::<u32>(<*const _>::cast(&memory_barrier_count)),
+        Arg::new::<*const vk::MemoryBarrier<'static>>(<*const _>::cast(&p_memory_barriers)),
+        Arg::new::<u32>(<*const _>::cast(&buffer_memory_barrier_count)),
+        Arg::new::<*const vk::BufferMemoryBarrier<'static>>(<*const _>::cast(
+            &p_buffer_memory_barriers,
+        )),
+        Arg::new::<u32>(<*const _>::cast(&image_memory_barrier_count)),
+        Arg::new::<*const vk::ImageMemoryBarrier<'static>>(<*const _>::cast(
+            &p_image_memory_barriers,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        event_count,
+        p_events,
+        src_stage_mask,
+        dst_stage_mask,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_wait_events2(
+    command_buffer: vk::CommandBuffer,
+    event_count: u32,
+    p_events: *const vk::Event,
+    p_dependency_infos: *const vk::DependencyInfo<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdWaitEvents2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdWaitEvents2>(next(
+        214usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&event_count)),
+        Arg::new::<*const vk::Event>(<*const _>::cast(&p_events)),
+        Arg::new::<*const vk::DependencyInfo<'static>>(<*const _>::cast(&p_dependency_infos)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, event_count, p_events, p_dependency_infos);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_write_acceleration_structures_properties_khr(
+    command_buffer: vk::CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureKHR,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteAccelerationStructuresPropertiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdWriteAccelerationStructuresPropertiesKHR>(
+        next(215usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&acceleration_structure_count)),
+        Arg::new::<*const vk::AccelerationStructureKHR>(<*const _>::cast(
+            &p_acceleration_structures,
+        )),
+        Arg::new::<vk::QueryType>(<*const _>::cast(&query_type)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&first_query)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_write_acceleration_structures_properties_nv(
+    command_buffer: vk::CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureNV,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteAccelerationStructuresPropertiesNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdWriteAccelerationStructuresPropertiesNV>(
+        next(216usize, dispatch_key(command_buffer.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&acceleration_structure_count)),
+        Arg::new::<*const vk::AccelerationStructureNV>(<*const _>::cast(
+            &p_acceleration_structures,
+        )),
+        Arg::new::<vk::QueryType>(<*const _>::cast(&query_type)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&first_query)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_write_buffer_marker2_amd(
+    command_buffer: vk::CommandBuffer,
+    stage: vk::PipelineStageFlags2,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    marker: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteBufferMarker2AMD\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdWriteBufferMarker2AMD>(next(
+        217usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineStageFlags2>(<*const _>::cast(&stage)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&dst_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&dst_offset)),
+        Arg::new::<u32>(<*const _>::cast(&marker)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, stage, dst_buffer, dst_offset, marker);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_write_buffer_marker_amd(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stage: vk::PipelineStageFlags,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    marker: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteBufferMarkerAMD\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdWriteBufferMarkerAMD>(next(
+        218usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineStageFlags>(<*const _>::cast(&pipeline_stage)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&dst_buffer)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&dst_offset)),
+        Arg::new::<u32>(<*const _>::cast(&marker)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        pipeline_stage,
+        dst_buffer,
+        dst_offset,
+        marker,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_write_micromaps_properties_ext(
+    command_buffer: vk::CommandBuffer,
+    micromap_count: u32,
+    p_micromaps: *const vk::MicromapEXT,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteMicromapsPropertiesEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdWriteMicromapsPropertiesEXT>(next(
+        219usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<u32>(<*const _>::cast(&micromap_count)),
+        Arg::new::<*const vk::MicromapEXT>(<*const _>::cast(&p_micromaps)),
+        Arg::new::<vk::QueryType>(<*const _>::cast(&query_type)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&first_query)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        command_buffer,
+        micromap_count,
+        p_micromaps,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_write_timestamp(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stage: vk::PipelineStageFlags,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteTimestamp\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdWriteTimestamp>(next(
+        220usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineStageFlags>(<*const _>::cast(&pipeline_stage)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&query)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, pipeline_stage, query_pool, query);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn cmd_write_timestamp2(
+    command_buffer: vk::CommandBuffer,
+    stage: vk::PipelineStageFlags2,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteTimestamp2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCmdWriteTimestamp2>(next(
+        221usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::PipelineStageFlags2>(<*const _>::cast(&stage)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&query)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(command_buffer, stage, query_pool, query);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn compile_deferred_nv(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    shader: u32,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCompileDeferredNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCompileDeferredNV>(next(
+        222usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Pipeline>(<*const _>::cast(&pipeline)),
+        Arg::new::<u32>(<*const _>::cast(&shader)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, pipeline, shader);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn copy_acceleration_structure_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyAccelerationStructureInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCopyAccelerationStructureKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCopyAccelerationStructureKHR>(next(
+        223usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&deferred_operation)),
+        Arg::new::<*const vk::CopyAccelerationStructureInfoKHR<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, deferred_operation, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn copy_acceleration_structure_to_memory_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCopyAccelerationStructureToMemoryKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCopyAccelerationStructureToMemoryKHR>(
+        next(224usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&deferred_operation)),
+        Arg::new::<*const vk::CopyAccelerationStructureToMemoryInfoKHR<'static>>(<*const _>::cast(
+            &p_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, deferred_operation, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn copy_memory_to_acceleration_structure_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCopyMemoryToAccelerationStructureKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCopyMemoryToAccelerationStructureKHR>(
+        next(225usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&deferred_operation)),
+        Arg::new::<*const vk::CopyMemoryToAccelerationStructureInfoKHR<'static>>(<*const _>::cast(
+            &p_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, deferred_operation, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn copy_memory_to_micromap_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMemoryToMicromapInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCopyMemoryToMicromapEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCopyMemoryToMicromapEXT>(next(
+        226usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&deferred_operation)),
+        Arg::new::<*const vk::CopyMemoryToMicromapInfoEXT<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, deferred_operation, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn copy_micromap_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMicromapInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCopyMicromapEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCopyMicromapEXT>(next(
+        227usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&deferred_operation)),
+        Arg::new::<*const vk::CopyMicromapInfoEXT<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, deferred_operation, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn copy_micromap_to_memory_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMicromapToMemoryInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCopyMicromapToMemoryEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCopyMicromapToMemoryEXT>(next(
+        228usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&deferred_operation)),
+        Arg::new::<*const vk::CopyMicromapToMemoryInfoEXT<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, deferred_operation, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_acceleration_structure_khr(
+    device: vk::Device,
+    p_create_info: *const vk::AccelerationStructureCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_acceleration_structure: *mut vk::AccelerationStructureKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateAccelerationStructureKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateAccelerationStructureKHR>(next(
+        229usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AccelerationStructureCreateInfoKHR<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::AccelerationStructureKHR>(<*const _>::cast(&p_acceleration_structure)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_acceleration_structure);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_acceleration_structure_nv(
+    device: vk::Device,
+    p_create_info: *const vk::AccelerationStructureCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_acceleration_structure: *mut vk::AccelerationStructureNV,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateAccelerationStructureNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateAccelerationStructureNV>(next(
+        230usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AccelerationStructureCreateInfoNV<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::AccelerationStructureNV>(<*const _>::cast(&p_acceleration_structure)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_acceleration_structure);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_android_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::AndroidSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateAndroidSurfaceKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateAndroidSurfaceKHR>(next(
+        231usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::AndroidSurfaceCreateInfoKHR<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_buffer(
+    device: vk::Device,
+    p_create_info: *const vk::BufferCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_buffer: *mut vk::Buffer,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateBuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateBuffer>(next(
+        232usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::BufferCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Buffer>(<*const _>::cast(&p_buffer)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_buffer);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_buffer_collection_fuchsia(
+    device: vk::Device,
+    p_create_info: *const vk::BufferCollectionCreateInfoFUCHSIA<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_collection: *mut vk::BufferCollectionFUCHSIA,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateBufferCollectionFUCHSIA\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateBufferCollectionFUCHSIA>(next(
+        233usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::BufferCollectionCreateInfoFUCHSIA<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::BufferCollectionFUCHSIA>(<*const _>::cast(&p_collection)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_collection);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_buffer_view(
+    device: vk::Device,
+    p_create_info: *const vk::BufferViewCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_view: *mut vk::BufferView,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateBufferView\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateBufferView>(next(
+        234usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::BufferViewCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::BufferView>(<*const _>::cast(&p_view)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_view);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_command_pool(
+    device: vk::Device,
+    p_create_info: *const vk::CommandPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_command_pool: *mut vk::CommandPool,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateCommandPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateCommandPool>(next(
+        235usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::CommandPoolCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::CommandPool>(<*const _>::cast(&p_command_pool)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_command_pool);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_compute_pipelines(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::ComputePipelineCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateComputePipelines\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateComputePipelines>(next(
+        236usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::PipelineCache>(<*const _>::cast(&pipeline_cache)),
+        Arg::new::<u32>(<*const _>::cast(&create_info_count)),
+        Arg::new::<*const vk::ComputePipelineCreateInfo<'static>>(<*const _>::cast(
+            &p_create_infos,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Pipeline>(<*const _>::cast(&p_pipelines)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_cu_function_nvx(
+    device: vk::Device,
+    p_create_info: *const vk::CuFunctionCreateInfoNVX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_function: *mut vk::CuFunctionNVX,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateCuFunctionNVX\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateCuFunctionNVX>(next(
+        237usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::CuFunctionCreateInfoNVX<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::CuFunctionNVX>(<*const _>::cast(&p_function)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_function);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_cu_module_nvx(
+    device: vk::Device,
+    p_create_info: *const vk::CuModuleCreateInfoNVX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_module: *mut vk::CuModuleNVX,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateCuModuleNVX\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateCuModuleNVX>(next(
+        238usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::CuModuleCreateInfoNVX<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::CuModuleNVX>(<*const _>::cast(&p_module)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_module);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_debug_report_callback_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::DebugReportCallbackCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_callback: *mut vk::DebugReportCallbackEXT,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateDebugReportCallbackEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateDebugReportCallbackEXT>(next(
+        239usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::DebugReportCallbackCreateInfoEXT<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::DebugReportCallbackEXT>(<*const _>::cast(&p_callback)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_callback);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_debug_utils_messenger_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::DebugUtilsMessengerCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_messenger: *mut vk::DebugUtilsMessengerEXT,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateDebugUtilsMessengerEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateDebugUtilsMessengerEXT>(next(
+        240usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::DebugUtilsMessengerCreateInfoEXT<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::DebugUtilsMessengerEXT>(<*const _>::cast(&p_messenger)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_messenger);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_deferred_operation_khr(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_deferred_operation: *mut vk::DeferredOperationKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateDeferredOperationKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateDeferredOperationKHR>(next(
+        241usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::DeferredOperationKHR>(<*const _>::cast(&p_deferred_operation)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_allocator, p_deferred_operation);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_descriptor_pool(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_descriptor_pool: *mut vk::DescriptorPool,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateDescriptorPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateDescriptorPool>(next(
+        242usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DescriptorPoolCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::DescriptorPool>(<*const _>::cast(&p_descriptor_pool)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_descriptor_pool);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_descriptor_set_layout(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorSetLayoutCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_set_layout: *mut vk::DescriptorSetLayout,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateDescriptorSetLayout\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateDescriptorSetLayout>(next(
+        243usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DescriptorSetLayoutCreateInfo<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::DescriptorSetLayout>(<*const _>::cast(&p_set_layout)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_set_layout);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_descriptor_update_template(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorUpdateTemplateCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_descriptor_update_template: *mut vk::DescriptorUpdateTemplate,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateDescriptorUpdateTemplate\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateDescriptorUpdateTemplate>(next(
+        244usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DescriptorUpdateTemplateCreateInfo<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::DescriptorUpdateTemplate>(<*const _>::cast(
+            &p_descriptor_update_template,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        p_create_info,
+        p_allocator,
+        p_descriptor_update_template,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_device(
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateDevice\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateDevice>(next(
+        245usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::DeviceCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Device>(<*const _>::cast(&p_device)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_create_info, p_allocator, p_device);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_direct_fb_surface_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::DirectFBSurfaceCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateDirectFBSurfaceEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateDirectFBSurfaceEXT>(next(
+        246usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::DirectFBSurfaceCreateInfoEXT<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_display_mode_khr(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+    p_create_info: *const vk::DisplayModeCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_mode: *mut vk::DisplayModeKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateDisplayModeKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateDisplayModeKHR>(next(
+        247usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::DisplayKHR>(<*const _>::cast(&display)),
+        Arg::new::<*const vk::DisplayModeCreateInfoKHR<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::DisplayModeKHR>(<*const _>::cast(&p_mode)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, display, p_create_info, p_allocator, p_mode);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_display_plane_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::DisplaySurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateDisplayPlaneSurfaceKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateDisplayPlaneSurfaceKHR>(next(
+        248usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::DisplaySurfaceCreateInfoKHR<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_event(
+    device: vk::Device,
+    p_create_info: *const vk::EventCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_event: *mut vk::Event,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateEvent\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateEvent>(next(
+        249usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::EventCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Event>(<*const _>::cast(&p_event)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_event);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_fence(
+    device: vk::Device,
+    p_create_info: *const vk::FenceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateFence\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateFence>(next(
+        250usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::FenceCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Fence>(<*const _>::cast(&p_fence)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_fence);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_framebuffer(
+    device: vk::Device,
+    p_create_info: *const vk::FramebufferCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_framebuffer: *mut vk::Framebuffer,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateFramebuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateFramebuffer>(next(
+        251usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::FramebufferCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Framebuffer>(<*const _>::cast(&p_framebuffer)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_framebuffer);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_graphics_pipelines(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::GraphicsPipelineCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateGraphicsPipelines\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateGraphicsPipelines>(next(
+        252usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::PipelineCache>(<*const _>::cast(&pipeline_cache)),
+        Arg::new::<u32>(<*const _>::cast(&create_info_count)),
+        Arg::new::<*const vk::GraphicsPipelineCreateInfo<'static>>(<*const _>::cast(
+            &p_create_infos,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Pipeline>(<*const _>::cast(&p_pipelines)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_headless_surface_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::HeadlessSurfaceCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateHeadlessSurfaceEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateHeadlessSurfaceEXT>(next(
+        253usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::HeadlessSurfaceCreateInfoEXT<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_ios_surface_mvk(
+    instance: vk::Instance,
+    p_create_info: *const vk::IOSSurfaceCreateInfoMVK<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateIOSSurfaceMVK\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateIOSSurfaceMVK>(next(
+        254usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::IOSSurfaceCreateInfoMVK<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_image(
+    device: vk::Device,
+    p_create_info: *const vk::ImageCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_image: *mut vk::Image,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateImage\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateImage>(next(
+        255usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImageCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Image>(<*const _>::cast(&p_image)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_image);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_image_pipe_surface_fuchsia(
+    instance: vk::Instance,
+    p_create_info: *const vk::ImagePipeSurfaceCreateInfoFUCHSIA<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateImagePipeSurfaceFUCHSIA\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateImagePipeSurfaceFUCHSIA>(next(
+        256usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::ImagePipeSurfaceCreateInfoFUCHSIA<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_image_view(
+    device: vk::Device,
+    p_create_info: *const vk::ImageViewCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_view: *mut vk::ImageView,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateImageView\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateImageView>(next(
+        257usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImageViewCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::ImageView>(<*const _>::cast(&p_view)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_view);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_indirect_commands_layout_nv(
+    device: vk::Device,
+    p_create_info: *const vk::IndirectCommandsLayoutCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_indirect_commands_layout: *mut vk::IndirectCommandsLayoutNV,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateIndirectCommandsLayoutNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateIndirectCommandsLayoutNV>(next(
+        258usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::IndirectCommandsLayoutCreateInfoNV<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::IndirectCommandsLayoutNV>(<*const _>::cast(
+            &p_indirect_commands_layout,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        p_create_info,
+        p_allocator,
+        p_indirect_commands_layout,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_instance(
+    p_create_info: *const vk::InstanceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateInstance\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateInstance>(next(259usize, 0));
+    let args = [
+        Arg::new::<*const vk::InstanceCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Instance>(<*const _>::cast(&p_instance)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(p_create_info, p_allocator, p_instance);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_mac_os_surface_mvk(
+    instance: vk::Instance,
+    p_create_info: *const vk::MacOSSurfaceCreateInfoMVK<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateMacOSSurfaceMVK\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateMacOSSurfaceMVK>(next(
+        260usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::MacOSSurfaceCreateInfoMVK<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_metal_surface_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::MetalSurfaceCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateMetalSurfaceEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateMetalSurfaceEXT>(next(
+        261usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::MetalSurfaceCreateInfoEXT<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_micromap_ext(
+    device: vk::Device,
+    p_create_info: *const vk::MicromapCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_micromap: *mut vk::MicromapEXT,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateMicromapEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateMicromapEXT>(next(
+        262usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::MicromapCreateInfoEXT<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::MicromapEXT>(<*const _>::cast(&p_micromap)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_micromap);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_optical_flow_session_nv(
+    device: vk::Device,
+    p_create_info: *const vk::OpticalFlowSessionCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_session: *mut vk::OpticalFlowSessionNV,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateOpticalFlowSessionNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateOpticalFlowSessionNV>(next(
+        263usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::OpticalFlowSessionCreateInfoNV<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::OpticalFlowSessionNV>(<*const _>::cast(&p_session)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_session);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_pipeline_cache(
+    device: vk::Device,
+    p_create_info: *const vk::PipelineCacheCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipeline_cache: *mut vk::PipelineCache,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreatePipelineCache\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreatePipelineCache>(next(
+        264usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::PipelineCacheCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::PipelineCache>(<*const _>::cast(&p_pipeline_cache)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_pipeline_cache);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_pipeline_layout(
+    device: vk::Device,
+    p_create_info: *const vk::PipelineLayoutCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipeline_layout: *mut vk::PipelineLayout,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreatePipelineLayout\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreatePipelineLayout>(next(
+        265usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::PipelineLayoutCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::PipelineLayout>(<*const _>::cast(&p_pipeline_layout)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_pipeline_layout);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_private_data_slot(
+    device: vk::Device,
+    p_create_info: *const vk::PrivateDataSlotCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_private_data_slot: *mut vk::PrivateDataSlot,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreatePrivateDataSlot\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreatePrivateDataSlot>(next(
+        266usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::PrivateDataSlotCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::PrivateDataSlot>(<*const _>::cast(&p_private_data_slot)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_private_data_slot);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_query_pool(
+    device: vk::Device,
+    p_create_info: *const vk::QueryPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_query_pool: *mut vk::QueryPool,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateQueryPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateQueryPool>(next(
+        267usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::QueryPoolCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::QueryPool>(<*const _>::cast(&p_query_pool)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_query_pool);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_ray_tracing_pipelines_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::RayTracingPipelineCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateRayTracingPipelinesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateRayTracingPipelinesKHR>(next(
+        268usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&deferred_operation)),
+        Arg::new::<vk::PipelineCache>(<*const _>::cast(&pipeline_cache)),
+        Arg::new::<u32>(<*const _>::cast(&create_info_count)),
+        Arg::new::<*const vk::RayTracingPipelineCreateInfoKHR<'static>>(<*const _>::cast(
+            &p_create_infos,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Pipeline>(<*const _>::cast(&p_pipelines)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        deferred_operation,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_ray_tracing_pipelines_nv(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::RayTracingPipelineCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateRayTracingPipelinesNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateRayTracingPipelinesNV>(next(
+        269usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::PipelineCache>(<*const _>::cast(&pipeline_cache)),
+        Arg::new::<u32>(<*const _>::cast(&create_info_count)),
+        Arg::new::<*const vk::RayTracingPipelineCreateInfoNV<'static>>(<*const _>::cast(
+            &p_create_infos,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Pipeline>(<*const _>::cast(&p_pipelines)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_render_pass(
+    device: vk::Device,
+    p_create_info: *const vk::RenderPassCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_render_pass: *mut vk::RenderPass,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateRenderPass\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateRenderPass>(next(
+        270usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::RenderPassCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::RenderPass>(<*const _>::cast(&p_render_pass)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_render_pass);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_render_pass2(
+    device: vk::Device,
+    p_create_info: *const vk::RenderPassCreateInfo2<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_render_pass: *mut vk::RenderPass,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateRenderPass2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateRenderPass2>(next(
+        271usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::RenderPassCreateInfo2<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::RenderPass>(<*const _>::cast(&p_render_pass)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_render_pass);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_sampler(
+    device: vk::Device,
+    p_create_info: *const vk::SamplerCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_sampler: *mut vk::Sampler,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateSampler\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateSampler>(next(
+        272usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::SamplerCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Sampler>(<*const _>::cast(&p_sampler)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_sampler);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_sampler_ycbcr_conversion(
+    device: vk::Device,
+    p_create_info: *const vk::SamplerYcbcrConversionCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_ycbcr_conversion: *mut vk::SamplerYcbcrConversion,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateSamplerYcbcrConversion\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateSamplerYcbcrConversion>(next(
+        273usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::SamplerYcbcrConversionCreateInfo<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SamplerYcbcrConversion>(<*const _>::cast(&p_ycbcr_conversion)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_ycbcr_conversion);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_screen_surface_qnx(
+    instance: vk::Instance,
+    p_create_info: *const vk::ScreenSurfaceCreateInfoQNX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateScreenSurfaceQNX\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateScreenSurfaceQNX>(next(
+        274usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::ScreenSurfaceCreateInfoQNX<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_semaphore(
+    device: vk::Device,
+    p_create_info: *const vk::SemaphoreCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_semaphore: *mut vk::Semaphore,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateSemaphore\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateSemaphore>(next(
+        275usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::SemaphoreCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Semaphore>(<*const _>::cast(&p_semaphore)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_semaphore);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_shader_module(
+    device: vk::Device,
+    p_create_info: *const vk::ShaderModuleCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_shader_module: *mut vk::ShaderModule,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateShaderModule\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateShaderModule>(next(
+        276usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ShaderModuleCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::ShaderModule>(<*const _>::cast(&p_shader_module)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_shader_module);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_shaders_ext(
+    device: vk::Device,
+    create_info_count: u32,
+    p_create_infos: *const vk::ShaderCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_shaders: *mut vk::ShaderEXT,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateShadersEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateShadersEXT>(next(
+        277usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&create_info_count)),
+        Arg::new::<*const vk::ShaderCreateInfoEXT<'static>>(<*const _>::cast(&p_create_infos)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::ShaderEXT>(<*const _>::cast(&p_shaders)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_shaders,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_shared_swapchains_khr(
+    device: vk::Device,
+    swapchain_count: u32,
+    p_create_infos: *const vk::SwapchainCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_swapchains: *mut vk::SwapchainKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateSharedSwapchainsKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateSharedSwapchainsKHR>(next(
+        278usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&swapchain_count)),
+        Arg::new::<*const vk::SwapchainCreateInfoKHR<'static>>(<*const _>::cast(&p_create_infos)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SwapchainKHR>(<*const _>::cast(&p_swapchains)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        swapchain_count,
+        p_create_infos,
+        p_allocator,
+        p_swapchains,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_stream_descriptor_surface_ggp(
+    instance: vk::Instance,
+    p_create_info: *const vk::StreamDescriptorSurfaceCreateInfoGGP<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateStreamDescriptorSurfaceGGP\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateStreamDescriptorSurfaceGGP>(next(
+        279usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::StreamDescriptorSurfaceCreateInfoGGP<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_swapchain_khr(
+    device: vk::Device,
+    p_create_info: *const vk::SwapchainCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_swapchain: *mut vk::SwapchainKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateSwapchainKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateSwapchainKHR>(next(
+        280usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::SwapchainCreateInfoKHR<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SwapchainKHR>(<*const _>::cast(&p_swapchain)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_swapchain);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_validation_cache_ext(
+    device: vk::Device,
+    p_create_info: *const vk::ValidationCacheCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_validation_cache: *mut vk::ValidationCacheEXT,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateValidationCacheEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateValidationCacheEXT>(next(
+        281usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ValidationCacheCreateInfoEXT<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::ValidationCacheEXT>(<*const _>::cast(&p_validation_cache)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_validation_cache);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_vi_surface_nn(
+    instance: vk::Instance,
+    p_create_info: *const vk::ViSurfaceCreateInfoNN<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateViSurfaceNN\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateViSurfaceNN>(next(
+        282usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::ViSurfaceCreateInfoNN<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_video_session_khr(
+    device: vk::Device,
+    p_create_info: *const vk::VideoSessionCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_video_session: *mut vk::VideoSessionKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateVideoSessionKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateVideoSessionKHR>(next(
+        283usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::VideoSessionCreateInfoKHR<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::VideoSessionKHR>(<*const _>::cast(&p_video_session)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_create_info, p_allocator, p_video_session);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_video_session_parameters_khr(
+    device: vk::Device,
+    p_create_info: *const vk::VideoSessionParametersCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_video_session_parameters: *mut vk::VideoSessionParametersKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateVideoSessionParametersKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateVideoSessionParametersKHR>(next(
+        284usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::VideoSessionParametersCreateInfoKHR<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::VideoSessionParametersKHR>(<*const _>::cast(
+            &p_video_session_parameters,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        p_create_info,
+        p_allocator,
+        p_video_session_parameters,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_wayland_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::WaylandSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateWaylandSurfaceKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateWaylandSurfaceKHR>(next(
+        285usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::WaylandSurfaceCreateInfoKHR<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_win32_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::Win32SurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateWin32SurfaceKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateWin32SurfaceKHR>(next(
+        286usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::Win32SurfaceCreateInfoKHR<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_xcb_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::XcbSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateXcbSurfaceKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateXcbSurfaceKHR>(next(
+        287usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::XcbSurfaceCreateInfoKHR<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn create_xlib_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::XlibSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkCreateXlibSurfaceKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkCreateXlibSurfaceKHR>(next(
+        288usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::XlibSurfaceCreateInfoKHR<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::SurfaceKHR>(<*const _>::cast(&p_surface)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn debug_marker_set_object_name_ext(
+    device: vk::Device,
+    p_name_info: *const vk::DebugMarkerObjectNameInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDebugMarkerSetObjectNameEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDebugMarkerSetObjectNameEXT>(next(
+        289usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DebugMarkerObjectNameInfoEXT<'static>>(<*const _>::cast(
+            &p_name_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_name_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn debug_marker_set_object_tag_ext(
+    device: vk::Device,
+    p_tag_info: *const vk::DebugMarkerObjectTagInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDebugMarkerSetObjectTagEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDebugMarkerSetObjectTagEXT>(next(
+        290usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DebugMarkerObjectTagInfoEXT<'static>>(<*const _>::cast(&p_tag_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_tag_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn debug_report_message_ext(
+    instance: vk::Instance,
+    flags: vk::DebugReportFlagsEXT,
+    object_type: vk::DebugReportObjectTypeEXT,
+    object: u64,
+    location: usize,
+    message_code: i32,
+    p_layer_prefix: *const c_char,
+    p_message: *const c_char,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDebugReportMessageEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDebugReportMessageEXT>(next(
+        291usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<vk::DebugReportFlagsEXT>(<*const _>::cast(&flags)),
+        Arg::new::<vk::DebugReportObjectTypeEXT>(<*const _>::cast(&object_type)),
+        Arg::new::<u64>(<*const _>::cast(&object)),
+        Arg::new::<usize>(<*const _>::cast(&location)),
+        Arg::new::<i32>(<*const _>::cast(&message_code)),
+        Arg::new::<*const c_char>(<*const _>::cast(&p_layer_prefix)),
+        Arg::new::<*const c_char>(<*const _>::cast(&p_message)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        instance,
+        flags,
+        object_type,
+        object,
+        location,
+        message_code,
+        p_layer_prefix,
+        p_message,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn deferred_operation_join_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDeferredOperationJoinKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDeferredOperationJoinKHR>(next(
+        292usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&operation)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, operation);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn destroy_acceleration_structure_khr(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyAccelerationStructureKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyAccelerationStructureKHR>(next(
+        293usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::AccelerationStructureKHR>(<*const _>::cast(&acceleration_structure)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, acceleration_structure, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_acceleration_structure_nv(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyAccelerationStructureNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyAccelerationStructureNV>(next(
+        294usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::AccelerationStructureNV>(<*const _>::cast(&acceleration_structure)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, acceleration_structure, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_buffer(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyBuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyBuffer>(next(
+        295usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, buffer, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_buffer_collection_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyBufferCollectionFUCHSIA\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyBufferCollectionFUCHSIA>(next(
+        296usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::BufferCollectionFUCHSIA>(<*const _>::cast(&collection)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, collection, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_buffer_view(
+    device: vk::Device,
+    buffer_view: vk::BufferView,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyBufferView\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyBufferView>(next(
+        297usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::BufferView>(<*const _>::cast(&buffer_view)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, buffer_view, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyCommandPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyCommandPool>(next(
+        298usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::CommandPool>(<*const _>::cast(&command_pool)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, command_pool, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_cu_function_nvx(
+    device: vk::Device,
+    function: vk::CuFunctionNVX,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyCuFunctionNVX\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyCuFunctionNVX>(next(
+        299usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::CuFunctionNVX>(<*const _>::cast(&function)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, function, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_cu_module_nvx(
+    device: vk::Device,
+    module: vk::CuModuleNVX,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyCuModuleNVX\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyCuModuleNVX>(next(
+        300usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::CuModuleNVX>(<*const _>::cast(&module)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, module, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_debug_report_callback_ext(
+    instance: vk::Instance,
+    callback: vk::DebugReportCallbackEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyDebugReportCallbackEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyDebugReportCallbackEXT>(next(
+        301usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<vk::DebugReportCallbackEXT>(<*const _>::cast(&callback)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(instance, callback, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_debug_utils_messenger_ext(
+    instance: vk::Instance,
+    messenger: vk::DebugUtilsMessengerEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyDebugUtilsMessengerEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyDebugUtilsMessengerEXT>(next(
+        302usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<vk::DebugUtilsMessengerEXT>(<*const _>::cast(&messenger)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(instance, messenger, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_deferred_operation_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyDeferredOperationKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyDeferredOperationKHR>(next(
+        303usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&operation)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, operation, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_descriptor_pool(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyDescriptorPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyDescriptorPool>(next(
+        304usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DescriptorPool>(<*const _>::cast(&descriptor_pool)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, descriptor_pool, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_descriptor_set_layout(
+    device: vk::Device,
+    descriptor_set_layout: vk::DescriptorSetLayout,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyDescriptorSetLayout\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyDescriptorSetLayout>(next(
+        305usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DescriptorSetLayout>(<*const _>::cast(&descriptor_set_layout)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, descriptor_set_layout, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_descriptor_update_template(
+    device: vk::Device,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyDescriptorUpdateTemplate\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyDescriptorUpdateTemplate>(next(
+        306usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DescriptorUpdateTemplate>(<*const _>::cast(&descriptor_update_template)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, descriptor_update_template, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_device(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyDevice\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyDevice>(next(
+        307usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_event(
+    device: vk::Device,
+    event: vk::Event,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyEvent\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyEvent>(next(
+        308usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Event>(<*const _>::cast(&event)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, event, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_fence(
+    device: vk::Device,
+    fence: vk::Fence,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyFence\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyFence>(next(
+        309usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Fence>(<*const _>::cast(&fence)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, fence, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_framebuffer(
+    device: vk::Device,
+    framebuffer: vk::Framebuffer,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyFramebuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyFramebuffer>(next(
+        310usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Framebuffer>(<*const _>::cast(&framebuffer)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, framebuffer, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_image(
+    device: vk::Device,
+    image: vk::Image,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyImage\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyImage>(next(
+        311usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, image, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_image_view(
+    device: vk::Device,
+    image_view: vk::ImageView,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyImageView\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyImageView>(next(
+        312usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ImageView>(<*const _>::cast(&image_view)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, image_view, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_indirect_commands_layout_nv(
+    device: vk::Device,
+    indirect_commands_layout: vk::IndirectCommandsLayoutNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyIndirectCommandsLayoutNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyIndirectCommandsLayoutNV>(next(
+        313usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::IndirectCommandsLayoutNV>(<*const _>::cast(&indirect_commands_layout)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, indirect_commands_layout, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_instance(
+    instance: vk::Instance,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyInstance\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyInstance>(next(
+        314usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(instance, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_micromap_ext(
+    device: vk::Device,
+    micromap: vk::MicromapEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyMicromapEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyMicromapEXT>(next(
+        315usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::MicromapEXT>(<*const _>::cast(&micromap)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, micromap, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_optical_flow_session_nv(
+    device: vk::Device,
+    session: vk::OpticalFlowSessionNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyOpticalFlowSessionNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyOpticalFlowSessionNV>(next(
+        316usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::OpticalFlowSessionNV>(<*const _>::cast(&session)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, session, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_pipeline(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyPipeline\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyPipeline>(next(
+        317usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Pipeline>(<*const _>::cast(&pipeline)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, pipeline, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_pipeline_cache(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyPipelineCache\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyPipelineCache>(next(
+        318usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::PipelineCache>(<*const _>::cast(&pipeline_cache)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, pipeline_cache, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_pipeline_layout(
+    device: vk::Device,
+    pipeline_layout: vk::PipelineLayout,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyPipelineLayout\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyPipelineLayout>(next(
+        319usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::PipelineLayout>(<*const _>::cast(&pipeline_layout)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, pipeline_layout, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_private_data_slot(
+    device: vk::Device,
+    private_data_slot: vk::PrivateDataSlot,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyPrivateDataSlot\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyPrivateDataSlot>(next(
+        320usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::PrivateDataSlot>(<*const _>::cast(&private_data_slot)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, private_data_slot, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_query_pool(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyQueryPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyQueryPool>(next(
+        321usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, query_pool, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_render_pass(
+    device: vk::Device,
+    render_pass: vk::RenderPass,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyRenderPass\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyRenderPass>(next(
+        322usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::RenderPass>(<*const _>::cast(&render_pass)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, render_pass, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_sampler(
+    device: vk::Device,
+    sampler: vk::Sampler,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroySampler\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroySampler>(next(
+        323usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Sampler>(<*const _>::cast(&sampler)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, sampler, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_sampler_ycbcr_conversion(
+    device: vk::Device,
+    ycbcr_conversion: vk::SamplerYcbcrConversion,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroySamplerYcbcrConversion\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroySamplerYcbcrConversion>(next(
+        324usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SamplerYcbcrConversion>(<*const _>::cast(&ycbcr_conversion)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, ycbcr_conversion, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_semaphore(
+    device: vk::Device,
+    semaphore: vk::Semaphore,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroySemaphore\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroySemaphore>(next(
+        325usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Semaphore>(<*const _>::cast(&semaphore)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, semaphore, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_shader_ext(
+    device: vk::Device,
+    shader: vk::ShaderEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyShaderEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyShaderEXT>(next(
+        326usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ShaderEXT>(<*const _>::cast(&shader)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, shader, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_shader_module(
+    device: vk::Device,
+    shader_module: vk::ShaderModule,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyShaderModule\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyShaderModule>(next(
+        327usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ShaderModule>(<*const _>::cast(&shader_module)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, shader_module, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_surface_khr(
+    instance: vk::Instance,
+    surface: vk::SurfaceKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroySurfaceKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroySurfaceKHR>(next(
+        328usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<vk::SurfaceKHR>(<*const _>::cast(&surface)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(instance, surface, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_swapchain_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroySwapchainKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroySwapchainKHR>(next(
+        329usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swapchain)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, swapchain, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_validation_cache_ext(
+    device: vk::Device,
+    validation_cache: vk::ValidationCacheEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyValidationCacheEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyValidationCacheEXT>(next(
+        330usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ValidationCacheEXT>(<*const _>::cast(&validation_cache)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, validation_cache, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_video_session_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyVideoSessionKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyVideoSessionKHR>(next(
+        331usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::VideoSessionKHR>(<*const _>::cast(&video_session)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, video_session, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn destroy_video_session_parameters_khr(
+    device: vk::Device,
+    video_session_parameters: vk::VideoSessionParametersKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDestroyVideoSessionParametersKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDestroyVideoSessionParametersKHR>(next(
+        332usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::VideoSessionParametersKHR>(<*const _>::cast(&video_session_parameters)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, video_session_parameters, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn device_wait_idle(device: vk::Device) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDeviceWaitIdle\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDeviceWaitIdle>(next(
+        333usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [Arg::new::<vk::Device>(<*const _>::cast(&device))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn display_power_control_ext(
+    device: vk::Device,
+    display: vk::DisplayKHR,
+    p_display_power_info: *const vk::DisplayPowerInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkDisplayPowerControlEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkDisplayPowerControlEXT>(next(
+        334usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DisplayKHR>(<*const _>::cast(&display)),
+        Arg::new::<*const vk::DisplayPowerInfoEXT<'static>>(<*const _>::cast(
+            &p_display_power_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, display, p_display_power_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn end_command_buffer(command_buffer: vk::CommandBuffer) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkEndCommandBuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkEndCommandBuffer>(next(
+        335usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [Arg::new::<vk::CommandBuffer>(<*const _>::cast(
+        &command_buffer,
+    ))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(command_buffer);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn enumerate_device_extension_properties(
+    physical_device: vk::PhysicalDevice,
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkEnumerateDeviceExtensionProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkEnumerateDeviceExtensionProperties>(next(
+        336usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const c_char>(<*const _>::cast(&p_layer_name)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::ExtensionProperties>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        p_layer_name,
+        p_property_count,
+        p_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn enumerate_device_layer_properties(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkEnumerateDeviceLayerProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkEnumerateDeviceLayerProperties>(next(
+        337usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::LayerProperties>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_property_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkEnumerateInstanceExtensionProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkEnumerateInstanceExtensionProperties>(
+        next(338usize, 0),
+    );
+    let args = [
+        Arg::new::<*const c_char>(<*const _>::cast(&p_layer_name)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::ExtensionProperties>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(p_layer_name, p_property_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn enumerate_instance_layer_properties(
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkEnumerateInstanceLayerProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkEnumerateInstanceLayerProperties>(next(
+        339usize, 0,
+    ));
+    let args = [
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::LayerProperties>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(p_property_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn enumerate_instance_version(p_api_version: *mut u32) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkEnumerateInstanceVersion\0");
+    let next =
+        mem::transmute::<*const c_void, vk::PFN_vkEnumerateInstanceVersion>(next(340usize, 0));
+    let args = [Arg::new::<*mut u32>(<*const _>::cast(&p_api_version))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(p_api_version);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn enumerate_physical_device_groups(
+    instance: vk::Instance,
+    p_physical_device_group_count: *mut u32,
+    p_physical_device_group_properties: *mut vk::PhysicalDeviceGroupProperties<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkEnumeratePhysicalDeviceGroups\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkEnumeratePhysicalDeviceGroups>(next(
+        341usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_physical_device_group_count)),
+        Arg::new::<*mut vk::PhysicalDeviceGroupProperties<'static>>(<*const _>::cast(
+            &p_physical_device_group_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        instance,
+        p_physical_device_group_count,
+        p_physical_device_group_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn enumerate_physical_device_queue_family_performance_query_counters_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    p_counter_count: *mut u32,
+    p_counters: *mut vk::PerformanceCounterKHR<'_>,
+    p_counter_descriptions: *mut vk::PerformanceCounterDescriptionKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(
+        b"vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR\0",
+    );
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR,
+    >(next(342usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<u32>(<*const _>::cast(&queue_family_index)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_counter_count)),
+        Arg::new::<*mut vk::PerformanceCounterKHR<'static>>(<*const _>::cast(&p_counters)),
+        Arg::new::<*mut vk::PerformanceCounterDescriptionKHR<'static>>(<*const _>::cast(
+            &p_counter_descriptions,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        queue_family_index,
+        p_counter_count,
+        p_counters,
+        p_counter_descriptions,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn enumerate_physical_devices(
+    instance: vk::Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkEnumeratePhysicalDevices\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkEnumeratePhysicalDevices>(next(
+        343usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_physical_device_count)),
+        Arg::new::<*mut vk::PhysicalDevice>(<*const _>::cast(&p_physical_devices)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(instance, p_physical_device_count, p_physical_devices);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn export_metal_objects_ext(
+    device: vk::Device,
+    p_metal_objects_info: *mut vk::ExportMetalObjectsInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkExportMetalObjectsEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkExportMetalObjectsEXT>(next(
+        344usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*mut vk::ExportMetalObjectsInfoEXT<'static>>(<*const _>::cast(
+            &p_metal_objects_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_metal_objects_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn flush_mapped_memory_ranges(
+    device: vk::Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const vk::MappedMemoryRange<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkFlushMappedMemoryRanges\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkFlushMappedMemoryRanges>(next(
+        345usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&memory_range_count)),
+        Arg::new::<*const vk::MappedMemoryRange<'static>>(<*const _>::cast(&p_memory_ranges)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, memory_range_count, p_memory_ranges);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn free_command_buffers(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    command_buffer_count: u32,
+    p_command_buffers: *const vk::CommandBuffer,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkFreeCommandBuffers\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkFreeCommandBuffers>(next(
+        346usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::CommandPool>(<*const _>::cast(&command_pool)),
+        Arg::new::<u32>(<*const _>::cast(&command_buffer_count)),
+        Arg::new::<*const vk::CommandBuffer>(<*const _>::cast(&p_command_buffers)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        device,
+        command_pool,
+        command_buffer_count,
+        p_command_buffers,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn free_descriptor_sets(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const vk::DescriptorSet,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkFreeDescriptorSets\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkFreeDescriptorSets>(next(
+        347usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DescriptorPool>(<*const _>::cast(&descriptor_pool)),
+        Arg::new::<u32>(<*const _>::cast(&descriptor_set_count)),
+        Arg::new::<*const vk::DescriptorSet>(<*const _>::cast(&p_descriptor_sets)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        descriptor_pool,
+        descriptor_set_count,
+        p_descriptor_sets,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn free_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkFreeMemory\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkFreeMemory>(next(
+        348usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeviceMemory>(<*const _>::cast(&memory)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, memory, p_allocator);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_acceleration_structure_build_sizes_khr(
+    device: vk::Device,
+    build_type: vk::AccelerationStructureBuildTypeKHR,
+    p_build_info: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    p_max_primitive_counts: *const u32,
+    p_size_info: *mut vk::AccelerationStructureBuildSizesInfoKHR<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetAccelerationStructureBuildSizesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetAccelerationStructureBuildSizesKHR>(
+        next(349usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::AccelerationStructureBuildTypeKHR>(<*const _>::cast(&build_type)),
+        Arg::new::<*const vk::AccelerationStructureBuildGeometryInfoKHR<'static>>(
+            <*const _>::cast(&p_build_info),
+        ),
+        Arg::new::<*const u32>(<*const _>::cast(&p_max_primitive_counts)),
+        Arg::new::<*mut vk::AccelerationStructureBuildSizesInfoKHR<'static>>(<*const _>::cast(
+            &p_size_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        device,
+        build_type,
+        p_build_info,
+        p_max_primitive_counts,
+        p_size_info,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_acceleration_structure_device_address_khr(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureDeviceAddressInfoKHR<'_>,
+) -> vk::DeviceAddress {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetAccelerationStructureDeviceAddressKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetAccelerationStructureDeviceAddressKHR>(
+        next(350usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AccelerationStructureDeviceAddressInfoKHR<'static>>(
+            <*const _>::cast(&p_info),
+        ),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::DeviceAddress>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_acceleration_structure_handle_nv(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureNV,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetAccelerationStructureHandleNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetAccelerationStructureHandleNV>(next(
+        351usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::AccelerationStructureNV>(<*const _>::cast(&acceleration_structure)),
+        Arg::new::<usize>(<*const _>::cast(&data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, acceleration_structure, data_size, p_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_acceleration_structure_memory_requirements_nv(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureMemoryRequirementsInfoNV<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2KHR<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetAccelerationStructureMemoryRequirementsNV\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetAccelerationStructureMemoryRequirementsNV,
+    >(next(352usize, dispatch_key(device.as_raw())));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AccelerationStructureMemoryRequirementsInfoNV<'static>>(
+            <*const _>::cast(&p_info),
+        ),
+        Arg::new::<*mut vk::MemoryRequirements2KHR<'static>>(<*const _>::cast(
+            &p_memory_requirements,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_info, p_memory_requirements);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_acceleration_structure_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(
+        b"vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT\0",
+    );
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT,
+    >(next(353usize, dispatch_key(device.as_raw())));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AccelerationStructureCaptureDescriptorDataInfoEXT<'static>>(
+            <*const _>::cast(&p_info),
+        ),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info, p_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_android_hardware_buffer_properties_android(
+    device: vk::Device,
+    buffer: *const vk::AHardwareBuffer,
+    p_properties: *mut vk::AndroidHardwareBufferPropertiesANDROID<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetAndroidHardwareBufferPropertiesANDROID\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetAndroidHardwareBufferPropertiesANDROID>(
+        next(354usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AHardwareBuffer>(<*const _>::cast(&buffer)),
+        Arg::new::<*mut vk::AndroidHardwareBufferPropertiesANDROID<'static>>(<*const _>::cast(
+            &p_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, buffer, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_buffer_collection_properties_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_properties: *mut vk::BufferCollectionPropertiesFUCHSIA<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetBufferCollectionPropertiesFUCHSIA\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetBufferCollectionPropertiesFUCHSIA>(
+        next(355usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::BufferCollectionFUCHSIA>(<*const _>::cast(&collection)),
+        Arg::new::<*mut vk::BufferCollectionPropertiesFUCHSIA<'static>>(<*const _>::cast(
+            &p_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, collection, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_buffer_device_address(
+    device: vk::Device,
+    p_info: *const vk::BufferDeviceAddressInfo<'_>,
+) -> vk::DeviceAddress {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetBufferDeviceAddress\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetBufferDeviceAddress>(next(
+        356usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::BufferDeviceAddressInfo<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::DeviceAddress>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_buffer_memory_requirements(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetBufferMemoryRequirements\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetBufferMemoryRequirements>(next(
+        357usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<*mut vk::MemoryRequirements>(<*const _>::cast(&p_memory_requirements)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, buffer, p_memory_requirements);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_buffer_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::BufferMemoryRequirementsInfo2<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetBufferMemoryRequirements2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetBufferMemoryRequirements2>(next(
+        358usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::BufferMemoryRequirementsInfo2<'static>>(<*const _>::cast(&p_info)),
+        Arg::new::<*mut vk::MemoryRequirements2<'static>>(<*const _>::cast(&p_memory_requirements)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_info, p_memory_requirements);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_buffer_opaque_capture_address(
+    device: vk::Device,
+    p_info: *const vk::BufferDeviceAddressInfo<'_>,
+) -> u64 {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetBufferOpaqueCaptureAddress\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetBufferOpaqueCaptureAddress>(next(
+        359usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::BufferDeviceAddressInfo<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<u64>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_buffer_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::BufferCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetBufferOpaqueCaptureDescriptorDataEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetBufferOpaqueCaptureDescriptorDataEXT>(
+        next(360usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::BufferCaptureDescriptorDataInfoEXT<'static>>(<*const _>::cast(
+            &p_info,
+        )),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info, p_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_calibrated_timestamps_ext(
+    device: vk::Device,
+    timestamp_count: u32,
+    p_timestamp_infos: *const vk::CalibratedTimestampInfoEXT<'_>,
+    p_timestamps: *mut u64,
+    p_max_deviation: *mut u64,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetCalibratedTimestampsEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetCalibratedTimestampsEXT>(next(
+        361usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&timestamp_count)),
+        Arg::new::<*const vk::CalibratedTimestampInfoEXT<'static>>(<*const _>::cast(
+            &p_timestamp_infos,
+        )),
+        Arg::new::<*mut u64>(<*const _>::cast(&p_timestamps)),
+        Arg::new::<*mut u64>(<*const _>::cast(&p_max_deviation)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        timestamp_count,
+        p_timestamp_infos,
+        p_timestamps,
+        p_max_deviation,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_deferred_operation_max_concurrency_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> u32 {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeferredOperationMaxConcurrencyKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeferredOperationMaxConcurrencyKHR>(
+        next(362usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&operation)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, operation);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<u32>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_deferred_operation_result_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeferredOperationResultKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeferredOperationResultKHR>(next(
+        363usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeferredOperationKHR>(<*const _>::cast(&operation)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, operation);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_descriptor_ext(
+    device: vk::Device,
+    p_descriptor_info: *const vk::DescriptorGetInfoEXT<'_>,
+    data_size: usize,
+    p_descriptor: *mut c_void,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDescriptorEXT>(next(
+        364usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DescriptorGetInfoEXT<'static>>(<*const _>::cast(&p_descriptor_info)),
+        Arg::new::<usize>(<*const _>::cast(&data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_descriptor)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_descriptor_info, data_size, p_descriptor);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_descriptor_set_host_mapping_valve(
+    device: vk::Device,
+    descriptor_set: vk::DescriptorSet,
+    pp_data: *mut *mut c_void,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetHostMappingVALVE\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDescriptorSetHostMappingVALVE>(next(
+        365usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DescriptorSet>(<*const _>::cast(&descriptor_set)),
+        Arg::new::<*mut *mut c_void>(<*const _>::cast(&pp_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, descriptor_set, pp_data);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_descriptor_set_layout_binding_offset_ext(
+    device: vk::Device,
+    layout: vk::DescriptorSetLayout,
+    binding: u32,
+    p_offset: *mut vk::DeviceSize,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetLayoutBindingOffsetEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDescriptorSetLayoutBindingOffsetEXT>(
+        next(366usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DescriptorSetLayout>(<*const _>::cast(&layout)),
+        Arg::new::<u32>(<*const _>::cast(&binding)),
+        Arg::new::<*mut vk::DeviceSize>(<*const _>::cast(&p_offset)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, layout, binding, p_offset);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_descriptor_set_layout_host_mapping_info_valve(
+    device: vk::Device,
+    p_binding_reference: *const vk::DescriptorSetBindingReferenceVALVE<'_>,
+    p_host_mapping: *mut vk::DescriptorSetLayoutHostMappingInfoVALVE<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetLayoutHostMappingInfoVALVE\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDescriptorSetLayoutHostMappingInfoVALVE>(
+        next(367usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DescriptorSetBindingReferenceVALVE<'static>>(<*const _>::cast(
+            &p_binding_reference,
+        )),
+        Arg::new::<*mut vk::DescriptorSetLayoutHostMappingInfoVALVE<'static>>(<*const _>::cast(
+            &p_host_mapping,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_binding_reference, p_host_mapping);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_descriptor_set_layout_size_ext(
+    device: vk::Device,
+    layout: vk::DescriptorSetLayout,
+    p_layout_size_in_bytes: *mut vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetLayoutSizeEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDescriptorSetLayoutSizeEXT>(next(
+        368usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DescriptorSetLayout>(<*const _>::cast(&layout)),
+        Arg::new::<*mut vk::DeviceSize>(<*const _>::cast(&p_layout_size_in_bytes)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, layout, p_layout_size_in_bytes);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_descriptor_set_layout_support(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorSetLayoutCreateInfo<'_>,
+    p_support: *mut vk::DescriptorSetLayoutSupport<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorSetLayoutSupport\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDescriptorSetLayoutSupport>(next(
+        369usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DescriptorSetLayoutCreateInfo<'static>>(<*const _>::cast(
+            &p_create_info,
+        )),
+        Arg::new::<*mut vk::DescriptorSetLayoutSupport<'static>>(<*const _>::cast(&p_support)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_create_info, p_support);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_device_acceleration_structure_compatibility_khr(
+    device: vk::Device,
+    p_version_info: *const vk::AccelerationStructureVersionInfoKHR<'_>,
+    p_compatibility: *mut vk::AccelerationStructureCompatibilityKHR,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceAccelerationStructureCompatibilityKHR\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetDeviceAccelerationStructureCompatibilityKHR,
+    >(next(370usize, dispatch_key(device.as_raw())));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::AccelerationStructureVersionInfoKHR<'static>>(<*const _>::cast(
+            &p_version_info,
+        )),
+        Arg::new::<*mut vk::AccelerationStructureCompatibilityKHR>(<*const _>::cast(
+            &p_compatibility,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_version_info, p_compatibility);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_device_buffer_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceBufferMemoryRequirements<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceBufferMemoryRequirements\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceBufferMemoryRequirements>(next(
+        371usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DeviceBufferMemoryRequirements<'static>>(<*const _>::cast(&p_info)),
+        Arg::new::<*mut vk::MemoryRequirements2<'static>>(<*const _>::cast(&p_memory_requirements)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_info, p_memory_requirements);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_device_fault_info_ext(
+    device: vk::Device,
+    p_fault_counts: *mut vk::DeviceFaultCountsEXT<'_>,
+    p_fault_info: *mut vk::DeviceFaultInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceFaultInfoEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceFaultInfoEXT>(next(
+        372usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*mut vk::DeviceFaultCountsEXT<'static>>(<*const _>::cast(&p_fault_counts)),
+        Arg::new::<*mut vk::DeviceFaultInfoEXT<'static>>(<*const _>::cast(&p_fault_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_fault_counts, p_fault_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_device_group_peer_memory_features(
+    device: vk::Device,
+    heap_index: u32,
+    local_device_index: u32,
+    remote_device_index: u32,
+    p_peer_memory_features: *mut vk::PeerMemoryFeatureFlags,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupPeerMemoryFeatures\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceGroupPeerMemoryFeatures>(next(
+        373usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&heap_index)),
+        Arg::new::<u32>(<*const _>::cast(&local_device_index)),
+        Arg::new::<u32>(<*const _>::cast(&remote_device_index)),
+        Arg::new::<*mut vk::PeerMemoryFeatureFlags>(<*const _>::cast(&p_peer_memory_features)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        device,
+        heap_index,
+        local_device_index,
+        remote_device_index,
+        p_peer_memory_features,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_device_group_present_capabilities_khr(
+    device: vk::Device,
+    p_device_group_present_capabilities: *mut vk::DeviceGroupPresentCapabilitiesKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupPresentCapabilitiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceGroupPresentCapabilitiesKHR>(
+        next(374usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*mut vk::DeviceGroupPresentCapabilitiesKHR<'static>>(<*const _>::cast(
+            &p_device_group_present_capabilities,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_device_group_present_capabilities);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_device_group_surface_present_modes2_ext(
+    device: vk::Device,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_modes: *mut vk::DeviceGroupPresentModeFlagsKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupSurfacePresentModes2EXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceGroupSurfacePresentModes2EXT>(
+        next(375usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::PhysicalDeviceSurfaceInfo2KHR<'static>>(<*const _>::cast(
+            &p_surface_info,
+        )),
+        Arg::new::<*mut vk::DeviceGroupPresentModeFlagsKHR>(<*const _>::cast(&p_modes)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_surface_info, p_modes);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_device_group_surface_present_modes_khr(
+    device: vk::Device,
+    surface: vk::SurfaceKHR,
+    p_modes: *mut vk::DeviceGroupPresentModeFlagsKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceGroupSurfacePresentModesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceGroupSurfacePresentModesKHR>(
+        next(376usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SurfaceKHR>(<*const _>::cast(&surface)),
+        Arg::new::<*mut vk::DeviceGroupPresentModeFlagsKHR>(<*const _>::cast(&p_modes)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, surface, p_modes);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_device_image_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceImageMemoryRequirements<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceImageMemoryRequirements\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceImageMemoryRequirements>(next(
+        377usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DeviceImageMemoryRequirements<'static>>(<*const _>::cast(&p_info)),
+        Arg::new::<*mut vk::MemoryRequirements2<'static>>(<*const _>::cast(&p_memory_requirements)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_info, p_memory_requirements);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_device_image_sparse_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceImageMemoryRequirements<'_>,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements2<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceImageSparseMemoryRequirements\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceImageSparseMemoryRequirements>(
+        next(378usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DeviceImageMemoryRequirements<'static>>(<*const _>::cast(&p_info)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_sparse_memory_requirement_count)),
+        Arg::new::<*mut vk::SparseImageMemoryRequirements2<'static>>(<*const _>::cast(
+            &p_sparse_memory_requirements,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_device_memory_commitment(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    p_committed_memory_in_bytes: *mut vk::DeviceSize,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceMemoryCommitment\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceMemoryCommitment>(next(
+        379usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeviceMemory>(<*const _>::cast(&memory)),
+        Arg::new::<*mut vk::DeviceSize>(<*const _>::cast(&p_committed_memory_in_bytes)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, memory, p_committed_memory_in_bytes);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_device_memory_opaque_capture_address(
+    device: vk::Device,
+    p_info: *const vk::DeviceMemoryOpaqueCaptureAddressInfo<'_>,
+) -> u64 {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceMemoryOpaqueCaptureAddress\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceMemoryOpaqueCaptureAddress>(
+        next(380usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DeviceMemoryOpaqueCaptureAddressInfo<'static>>(<*const _>::cast(
+            &p_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<u64>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_device_micromap_compatibility_ext(
+    device: vk::Device,
+    p_version_info: *const vk::MicromapVersionInfoEXT<'_>,
+    p_compatibility: *mut vk::AccelerationStructureCompatibilityKHR,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceMicromapCompatibilityEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceMicromapCompatibilityEXT>(next(
+        381usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::MicromapVersionInfoEXT<'static>>(<*const _>::cast(&p_version_info)),
+        Arg::new::<*mut vk::AccelerationStructureCompatibilityKHR>(<*const _>::cast(
+            &p_compatibility,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_version_info, p_compatibility);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_device_queue(
+    device: vk::Device,
+    queue_family_index: u32,
+    queue_index: u32,
+    p_queue: *mut vk::Queue,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceQueue\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceQueue>(next(
+        382usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&queue_family_index)),
+        Arg::new::<u32>(<*const _>::cast(&queue_index)),
+        Arg::new::<*mut vk::Queue>(<*const _>::cast(&p_queue)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, queue_family_index, queue_index, p_queue);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_device_queue2(
+    device: vk::Device,
+    p_queue_info: *const vk::DeviceQueueInfo2<'_>,
+    p_queue: *mut vk::Queue,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceQueue2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDeviceQueue2>(next(
+        383usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DeviceQueueInfo2<'static>>(<*const _>::cast(&p_queue_info)),
+        Arg::new::<*mut vk::Queue>(<*const _>::cast(&p_queue)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_queue_info, p_queue);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_device_subpass_shading_max_workgroup_size_huawei(
+    device: vk::Device,
+    renderpass: vk::RenderPass,
+    p_max_workgroup_size: *mut vk::Extent2D,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI,
+    >(next(384usize, dispatch_key(device.as_raw())));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::RenderPass>(<*const _>::cast(&renderpass)),
+        Arg::new::<*mut vk::Extent2D>(<*const _>::cast(&p_max_workgroup_size)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, renderpass, p_max_workgroup_size);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_display_mode_properties2_khr(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayModeProperties2KHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayModeProperties2KHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDisplayModeProperties2KHR>(next(
+        385usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::DisplayKHR>(<*const _>::cast(&display)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::DisplayModeProperties2KHR<'static>>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, display, p_property_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_display_mode_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayModePropertiesKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayModePropertiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDisplayModePropertiesKHR>(next(
+        386usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::DisplayKHR>(<*const _>::cast(&display)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::DisplayModePropertiesKHR>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, display, p_property_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_display_plane_capabilities2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_display_plane_info: *const vk::DisplayPlaneInfo2KHR<'_>,
+    p_capabilities: *mut vk::DisplayPlaneCapabilities2KHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayPlaneCapabilities2KHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDisplayPlaneCapabilities2KHR>(next(
+        387usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::DisplayPlaneInfo2KHR<'static>>(<*const _>::cast(
+            &p_display_plane_info,
+        )),
+        Arg::new::<*mut vk::DisplayPlaneCapabilities2KHR<'static>>(<*const _>::cast(
+            &p_capabilities,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_display_plane_info, p_capabilities);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_display_plane_capabilities_khr(
+    physical_device: vk::PhysicalDevice,
+    mode: vk::DisplayModeKHR,
+    plane_index: u32,
+    p_capabilities: *mut vk::DisplayPlaneCapabilitiesKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayPlaneCapabilitiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDisplayPlaneCapabilitiesKHR>(next(
+        388usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::DisplayModeKHR>(<*const _>::cast(&mode)),
+        Arg::new::<u32>(<*const _>::cast(&plane_index)),
+        Arg::new::<*mut vk::DisplayPlaneCapabilitiesKHR>(<*const _>::cast(&p_capabilities)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, mode, plane_index, p_capabilities);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_display_plane_supported_displays_khr(
+    physical_device: vk::PhysicalDevice,
+    plane_index: u32,
+    p_display_count: *mut u32,
+    p_displays: *mut vk::DisplayKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDisplayPlaneSupportedDisplaysKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDisplayPlaneSupportedDisplaysKHR>(
+        next(389usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<u32>(<*const _>::cast(&plane_index)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_display_count)),
+        Arg::new::<*mut vk::DisplayKHR>(<*const _>::cast(&p_displays)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, plane_index, p_display_count, p_displays);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_drm_display_ext(
+    physical_device: vk::PhysicalDevice,
+    drm_fd: i32,
+    connector_id: u32,
+    display: *mut vk::DisplayKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDrmDisplayEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDrmDisplayEXT>(next(
+        390usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<i32>(<*const _>::cast(&drm_fd)),
+        Arg::new::<u32>(<*const _>::cast(&connector_id)),
+        Arg::new::<*mut vk::DisplayKHR>(<*const _>::cast(&display)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, drm_fd, connector_id, display);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_dynamic_rendering_tile_properties_qcom(
+    device: vk::Device,
+    p_rendering_info: *const vk::RenderingInfo<'_>,
+    p_properties: *mut vk::TilePropertiesQCOM<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetDynamicRenderingTilePropertiesQCOM\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetDynamicRenderingTilePropertiesQCOM>(
+        next(391usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::RenderingInfo<'static>>(<*const _>::cast(&p_rendering_info)),
+        Arg::new::<*mut vk::TilePropertiesQCOM<'static>>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_rendering_info, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_encoded_video_session_parameters_khr(
+    device: vk::Device,
+    p_video_session_parameters_info: *const vk::VideoEncodeSessionParametersGetInfoKHR<'_>,
+    p_feedback_info: *mut vk::VideoEncodeSessionParametersFeedbackInfoKHR<'_>,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetEncodedVideoSessionParametersKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetEncodedVideoSessionParametersKHR>(
+        next(392usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::VideoEncodeSessionParametersGetInfoKHR<'static>>(<*const _>::cast(
+            &p_video_session_parameters_info,
+        )),
+        Arg::new::<*mut vk::VideoEncodeSessionParametersFeedbackInfoKHR<'static>>(
+            <*const _>::cast(&p_feedback_info),
+        ),
+        Arg::new::<*mut usize>(<*const _>::cast(&p_data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        p_video_session_parameters_info,
+        p_feedback_info,
+        p_data_size,
+        p_data,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_event_status(device: vk::Device, event: vk::Event) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetEventStatus\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetEventStatus>(next(
+        393usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Event>(<*const _>::cast(&event)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, event);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_fence_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::FenceGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetFenceFdKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetFenceFdKHR>(next(
+        394usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::FenceGetFdInfoKHR<'static>>(<*const _>::cast(&p_get_fd_info)),
+        Arg::new::<*mut c_int>(<*const _>::cast(&p_fd)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_get_fd_info, p_fd);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_fence_status(device: vk::Device, fence: vk::Fence) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetFenceStatus\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetFenceStatus>(next(
+        395usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Fence>(<*const _>::cast(&fence)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, fence);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_fence_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::FenceGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetFenceWin32HandleKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetFenceWin32HandleKHR>(next(
+        396usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::FenceGetWin32HandleInfoKHR<'static>>(<*const _>::cast(
+            &p_get_win32_handle_info,
+        )),
+        Arg::new::<*mut vk::HANDLE>(<*const _>::cast(&p_handle)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_get_win32_handle_info, p_handle);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_framebuffer_tile_properties_qcom(
+    device: vk::Device,
+    framebuffer: vk::Framebuffer,
+    p_properties_count: *mut u32,
+    p_properties: *mut vk::TilePropertiesQCOM<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetFramebufferTilePropertiesQCOM\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetFramebufferTilePropertiesQCOM>(next(
+        397usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Framebuffer>(<*const _>::cast(&framebuffer)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_properties_count)),
+        Arg::new::<*mut vk::TilePropertiesQCOM<'static>>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, framebuffer, p_properties_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_generated_commands_memory_requirements_nv(
+    device: vk::Device,
+    p_info: *const vk::GeneratedCommandsMemoryRequirementsInfoNV<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetGeneratedCommandsMemoryRequirementsNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetGeneratedCommandsMemoryRequirementsNV>(
+        next(398usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::GeneratedCommandsMemoryRequirementsInfoNV<'static>>(
+            <*const _>::cast(&p_info),
+        ),
+        Arg::new::<*mut vk::MemoryRequirements2<'static>>(<*const _>::cast(&p_memory_requirements)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_info, p_memory_requirements);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_image_drm_format_modifier_properties_ext(
+    device: vk::Device,
+    image: vk::Image,
+    p_properties: *mut vk::ImageDrmFormatModifierPropertiesEXT<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetImageDrmFormatModifierPropertiesEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageDrmFormatModifierPropertiesEXT>(
+        next(399usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<*mut vk::ImageDrmFormatModifierPropertiesEXT<'static>>(<*const _>::cast(
+            &p_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, image, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_image_memory_requirements(
+    device: vk::Device,
+    image: vk::Image,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetImageMemoryRequirements\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageMemoryRequirements>(next(
+        400usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<*mut vk::MemoryRequirements>(<*const _>::cast(&p_memory_requirements)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, image, p_memory_requirements);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_image_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::ImageMemoryRequirementsInfo2<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetImageMemoryRequirements2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageMemoryRequirements2>(next(
+        401usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImageMemoryRequirementsInfo2<'static>>(<*const _>::cast(&p_info)),
+        Arg::new::<*mut vk::MemoryRequirements2<'static>>(<*const _>::cast(&p_memory_requirements)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_info, p_memory_requirements);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_image_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::ImageCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetImageOpaqueCaptureDescriptorDataEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageOpaqueCaptureDescriptorDataEXT>(
+        next(402usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImageCaptureDescriptorDataInfoEXT<'static>>(<*const _>::cast(
+            &p_info,
+        )),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info, p_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_image_sparse_memory_requirements(
+    device: vk::Device,
+    image: vk::Image,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetImageSparseMemoryRequirements\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageSparseMemoryRequirements>(next(
+        403usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_sparse_memory_requirement_count)),
+        Arg::new::<*mut vk::SparseImageMemoryRequirements>(<*const _>::cast(
+            &p_sparse_memory_requirements,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        device,
+        image,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_image_sparse_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::ImageSparseMemoryRequirementsInfo2<'_>,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetImageSparseMemoryRequirements2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageSparseMemoryRequirements2>(next(
+        404usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImageSparseMemoryRequirementsInfo2<'static>>(<*const _>::cast(
+            &p_info,
+        )),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_sparse_memory_requirement_count)),
+        Arg::new::<*mut vk::SparseImageMemoryRequirements2<'static>>(<*const _>::cast(
+            &p_sparse_memory_requirements,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_image_subresource_layout(
+    device: vk::Device,
+    image: vk::Image,
+    p_subresource: *const vk::ImageSubresource,
+    p_layout: *mut vk::SubresourceLayout,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetImageSubresourceLayout\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageSubresourceLayout>(next(
+        405usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<*const vk::ImageSubresource>(<*const _>::cast(&p_subresource)),
+        Arg::new::<*mut vk::SubresourceLayout>(<*const _>::cast(&p_layout)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, image, p_subresource, p_layout);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_image_subresource_layout2_ext(
+    device: vk::Device,
+    image: vk::Image,
+    p_subresource: *const vk::ImageSubresource2EXT<'_>,
+    p_layout: *mut vk::SubresourceLayout2EXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetImageSubresourceLayout2EXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageSubresourceLayout2EXT>(next(
+        406usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<*const vk::ImageSubresource2EXT<'static>>(<*const _>::cast(&p_subresource)),
+        Arg::new::<*mut vk::SubresourceLayout2EXT<'static>>(<*const _>::cast(&p_layout)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, image, p_subresource, p_layout);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_image_view_address_nvx(
+    device: vk::Device,
+    image_view: vk::ImageView,
+    p_properties: *mut vk::ImageViewAddressPropertiesNVX<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetImageViewAddressNVX\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageViewAddressNVX>(next(
+        407usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ImageView>(<*const _>::cast(&image_view)),
+        Arg::new::<*mut vk::ImageViewAddressPropertiesNVX<'static>>(<*const _>::cast(
+            &p_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, image_view, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_image_view_handle_nvx(
+    device: vk::Device,
+    p_info: *const vk::ImageViewHandleInfoNVX<'_>,
+) -> u32 {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetImageViewHandleNVX\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageViewHandleNVX>(next(
+        408usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImageViewHandleInfoNVX<'static>>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<u32>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_image_view_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::ImageViewCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetImageViewOpaqueCaptureDescriptorDataEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetImageViewOpaqueCaptureDescriptorDataEXT>(
+        next(409usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImageViewCaptureDescriptorDataInfoEXT<'static>>(<*const _>::cast(
+            &p_info,
+        )),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info, p_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_memory_android_hardware_buffer_android(
+    device: vk::Device,
+    p_info: *const vk::MemoryGetAndroidHardwareBufferInfoANDROID<'_>,
+    p_buffer: *mut *mut vk::AHardwareBuffer,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryAndroidHardwareBufferANDROID\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMemoryAndroidHardwareBufferANDROID>(
+        next(410usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::MemoryGetAndroidHardwareBufferInfoANDROID<'static>>(
+            <*const _>::cast(&p_info),
+        ),
+        Arg::new::<*mut *mut vk::AHardwareBuffer>(<*const _>::cast(&p_buffer)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info, p_buffer);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_memory_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::MemoryGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryFdKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMemoryFdKHR>(next(
+        411usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::MemoryGetFdInfoKHR<'static>>(<*const _>::cast(&p_get_fd_info)),
+        Arg::new::<*mut c_int>(<*const _>::cast(&p_fd)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_get_fd_info, p_fd);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_memory_fd_properties_khr(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    fd: c_int,
+    p_memory_fd_properties: *mut vk::MemoryFdPropertiesKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryFdPropertiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMemoryFdPropertiesKHR>(next(
+        412usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ExternalMemoryHandleTypeFlags>(<*const _>::cast(&handle_type)),
+        Arg::new::<c_int>(<*const _>::cast(&fd)),
+        Arg::new::<*mut vk::MemoryFdPropertiesKHR<'static>>(<*const _>::cast(
+            &p_memory_fd_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, handle_type, fd, p_memory_fd_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_memory_host_pointer_properties_ext(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    p_host_pointer: *const c_void,
+    p_memory_host_pointer_properties: *mut vk::MemoryHostPointerPropertiesEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryHostPointerPropertiesEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMemoryHostPointerPropertiesEXT>(next(
+        413usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ExternalMemoryHandleTypeFlags>(<*const _>::cast(&handle_type)),
+        Arg::new::<*const c_void>(<*const _>::cast(&p_host_pointer)),
+        Arg::new::<*mut vk::MemoryHostPointerPropertiesEXT<'static>>(<*const _>::cast(
+            &p_memory_host_pointer_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        handle_type,
+        p_host_pointer,
+        p_memory_host_pointer_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_memory_remote_address_nv(
+    device: vk::Device,
+    p_memory_get_remote_address_info: *const vk::MemoryGetRemoteAddressInfoNV<'_>,
+    p_address: *mut vk::RemoteAddressNV,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryRemoteAddressNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMemoryRemoteAddressNV>(next(
+        414usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::MemoryGetRemoteAddressInfoNV<'static>>(<*const _>::cast(
+            &p_memory_get_remote_address_info,
+        )),
+        Arg::new::<*mut vk::RemoteAddressNV>(<*const _>::cast(&p_address)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_memory_get_remote_address_info, p_address);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_memory_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::MemoryGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryWin32HandleKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMemoryWin32HandleKHR>(next(
+        415usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::MemoryGetWin32HandleInfoKHR<'static>>(<*const _>::cast(
+            &p_get_win32_handle_info,
+        )),
+        Arg::new::<*mut vk::HANDLE>(<*const _>::cast(&p_handle)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_get_win32_handle_info, p_handle);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_memory_win32_handle_nv(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryWin32HandleNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMemoryWin32HandleNV>(next(
+        416usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeviceMemory>(<*const _>::cast(&memory)),
+        Arg::new::<vk::ExternalMemoryHandleTypeFlagsNV>(<*const _>::cast(&handle_type)),
+        Arg::new::<*mut vk::HANDLE>(<*const _>::cast(&p_handle)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, memory, handle_type, p_handle);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_memory_win32_handle_properties_khr(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    handle: vk::HANDLE,
+    p_memory_win32_handle_properties: *mut vk::MemoryWin32HandlePropertiesKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryWin32HandlePropertiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMemoryWin32HandlePropertiesKHR>(next(
+        417usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ExternalMemoryHandleTypeFlags>(<*const _>::cast(&handle_type)),
+        Arg::new::<vk::HANDLE>(<*const _>::cast(&handle)),
+        Arg::new::<*mut vk::MemoryWin32HandlePropertiesKHR<'static>>(<*const _>::cast(
+            &p_memory_win32_handle_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        handle_type,
+        handle,
+        p_memory_win32_handle_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_memory_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_get_zircon_handle_info: *const vk::MemoryGetZirconHandleInfoFUCHSIA<'_>,
+    p_zircon_handle: *mut vk::zx_handle_t,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryZirconHandleFUCHSIA\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMemoryZirconHandleFUCHSIA>(next(
+        418usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::MemoryGetZirconHandleInfoFUCHSIA<'static>>(<*const _>::cast(
+            &p_get_zircon_handle_info,
+        )),
+        Arg::new::<*mut vk::zx_handle_t>(<*const _>::cast(&p_zircon_handle)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_get_zircon_handle_info, p_zircon_handle);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_memory_zircon_handle_properties_fuchsia(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    zircon_handle: vk::zx_handle_t,
+    p_memory_zircon_handle_properties: *mut vk::MemoryZirconHandlePropertiesFUCHSIA<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryZirconHandlePropertiesFUCHSIA\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMemoryZirconHandlePropertiesFUCHSIA>(
+        next(419usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ExternalMemoryHandleTypeFlags>(<*const _>::cast(&handle_type)),
+        Arg::new::<vk::zx_handle_t>(<*const _>::cast(&zircon_handle)),
+        Arg::new::<*mut vk::MemoryZirconHandlePropertiesFUCHSIA<'static>>(<*const _>::cast(
+            &p_memory_zircon_handle_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        handle_type,
+        zircon_handle,
+        p_memory_zircon_handle_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_micromap_build_sizes_ext(
+    device: vk::Device,
+    build_type: vk::AccelerationStructureBuildTypeKHR,
+    p_build_info: *const vk::MicromapBuildInfoEXT<'_>,
+    p_size_info: *mut vk::MicromapBuildSizesInfoEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetMicromapBuildSizesEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetMicromapBuildSizesEXT>(next(
+        420usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::AccelerationStructureBuildTypeKHR>(<*const _>::cast(&build_type)),
+        Arg::new::<*const vk::MicromapBuildInfoEXT<'static>>(<*const _>::cast(&p_build_info)),
+        Arg::new::<*mut vk::MicromapBuildSizesInfoEXT<'static>>(<*const _>::cast(&p_size_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, build_type, p_build_info, p_size_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_past_presentation_timing_google(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_presentation_timing_count: *mut u32,
+    p_presentation_timings: *mut vk::PastPresentationTimingGOOGLE,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPastPresentationTimingGOOGLE\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPastPresentationTimingGOOGLE>(next(
+        421usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swapchain)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_presentation_timing_count)),
+        Arg::new::<*mut vk::PastPresentationTimingGOOGLE>(<*const _>::cast(
+            &p_presentation_timings,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        swapchain,
+        p_presentation_timing_count,
+        p_presentation_timings,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_performance_parameter_intel(
+    device: vk::Device,
+    parameter: vk::PerformanceParameterTypeINTEL,
+    p_value: *mut vk::PerformanceValueINTEL,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPerformanceParameterINTEL\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPerformanceParameterINTEL>(next(
+        422usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::PerformanceParameterTypeINTEL>(<*const _>::cast(&parameter)),
+        Arg::new::<*mut vk::PerformanceValueINTEL>(<*const _>::cast(&p_value)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, parameter, p_value);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_calibrateable_time_domains_ext(
+    physical_device: vk::PhysicalDevice,
+    p_time_domain_count: *mut u32,
+    p_time_domains: *mut vk::TimeDomainEXT,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceCalibrateableTimeDomainsEXT\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceCalibrateableTimeDomainsEXT,
+    >(next(423usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_time_domain_count)),
+        Arg::new::<*mut vk::TimeDomainEXT>(<*const _>::cast(&p_time_domains)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_time_domain_count, p_time_domains);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_cooperative_matrix_properties_nv(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::CooperativeMatrixPropertiesNV<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceCooperativeMatrixPropertiesNV\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceCooperativeMatrixPropertiesNV,
+    >(next(424usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::CooperativeMatrixPropertiesNV<'static>>(<*const _>::cast(
+            &p_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_property_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_direct_fb_presentation_support_ext(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    dfb: *mut vk::IDirectFB,
+) -> vk::Bool32 {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceDirectFBPresentationSupportEXT\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceDirectFBPresentationSupportEXT,
+    >(next(425usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<u32>(<*const _>::cast(&queue_family_index)),
+        Arg::new::<*mut vk::IDirectFB>(<*const _>::cast(&dfb)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, queue_family_index, dfb);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Bool32>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_display_plane_properties2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayPlaneProperties2KHR<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceDisplayPlaneProperties2KHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceDisplayPlaneProperties2KHR>(
+        next(426usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::DisplayPlaneProperties2KHR<'static>>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_property_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_display_plane_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayPlanePropertiesKHR,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceDisplayPlanePropertiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR>(
+        next(427usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::DisplayPlanePropertiesKHR>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_property_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_display_properties2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayProperties2KHR<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceDisplayProperties2KHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceDisplayProperties2KHR>(
+        next(428usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::DisplayProperties2KHR<'static>>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_property_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_display_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayPropertiesKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceDisplayPropertiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceDisplayPropertiesKHR>(
+        next(429usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::DisplayPropertiesKHR<'static>>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_property_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_external_buffer_properties(
+    physical_device: vk::PhysicalDevice,
+    p_external_buffer_info: *const vk::PhysicalDeviceExternalBufferInfo<'_>,
+    p_external_buffer_properties: *mut vk::ExternalBufferProperties<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceExternalBufferProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceExternalBufferProperties>(
+        next(430usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::PhysicalDeviceExternalBufferInfo<'static>>(<*const _>::cast(
+            &p_external_buffer_info,
+        )),
+        Arg::new::<*mut vk::ExternalBufferProperties<'static>>(<*const _>::cast(
+            &p_external_buffer_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        physical_device,
+        p_external_buffer_info,
+        p_external_buffer_properties,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_external_fence_properties(
+    physical_device: vk::PhysicalDevice,
+    p_external_fence_info: *const vk::PhysicalDeviceExternalFenceInfo<'_>,
+    p_external_fence_properties: *mut vk::ExternalFenceProperties<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceExternalFenceProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceExternalFenceProperties>(
+        next(431usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::PhysicalDeviceExternalFenceInfo<'static>>(<*const _>::cast(
+            &p_external_fence_info,
+        )),
+        Arg::new::<*mut vk::ExternalFenceProperties<'static>>(<*const _>::cast(
+            &p_external_fence_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        physical_device,
+        p_external_fence_info,
+        p_external_fence_properties,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_external_image_format_properties_nv(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    ty: vk::ImageType,
+    tiling: vk::ImageTiling,
+    usage: vk::ImageUsageFlags,
+    flags: vk::ImageCreateFlags,
+    external_handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+    p_external_image_format_properties: *mut vk::ExternalImageFormatPropertiesNV,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(
+        b"vkGetPhysicalDeviceExternalImageFormatPropertiesNV\0",
+    );
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceExternalImageFormatPropertiesNV,
+    >(next(432usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::Format>(<*const _>::cast(&format)),
+        Arg::new::<vk::ImageType>(<*const _>::cast(&ty)),
+        Arg::new::<vk::ImageTiling>(<*const _>::cast(&tiling)),
+        Arg::new::<vk::ImageUsageFlags>(<*const _>::cast(&usage)),
+        Arg::new::<vk::ImageCreateFlags>(<*const _>::cast(&flags)),
+        Arg::new::<vk::ExternalMemoryHandleTypeFlagsNV>(<*const _>::cast(&external_handle_type)),
+        Arg::new::<*mut vk::ExternalImageFormatPropertiesNV>(<*const _>::cast(
+            &p_external_image_format_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        format,
+        ty,
+        tiling,
+        usage,
+        flags,
+        external_handle_type,
+        p_external_image_format_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_external_semaphore_properties(
+    physical_device: vk::PhysicalDevice,
+    p_external_semaphore_info: *const vk::PhysicalDeviceExternalSemaphoreInfo<'_>,
+    p_external_semaphore_properties: *mut vk::ExternalSemaphoreProperties<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceExternalSemaphoreProperties\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceExternalSemaphoreProperties,
+    >(next(433usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::PhysicalDeviceExternalSemaphoreInfo<'static>>(<*const _>::cast(
+            &p_external_semaphore_info,
+        )),
+        Arg::new::<*mut vk::ExternalSemaphoreProperties<'static>>(<*const _>::cast(
+            &p_external_semaphore_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        physical_device,
+        p_external_semaphore_info,
+        p_external_semaphore_properties,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_features(
+    physical_device: vk::PhysicalDevice,
+    p_features: *mut vk::PhysicalDeviceFeatures,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFeatures\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceFeatures>(next(
+        434usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut vk::PhysicalDeviceFeatures>(<*const _>::cast(&p_features)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(physical_device, p_features);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_features2(
+    physical_device: vk::PhysicalDevice,
+    p_features: *mut vk::PhysicalDeviceFeatures2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFeatures2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceFeatures2>(next(
+        435usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut vk::PhysicalDeviceFeatures2<'static>>(<*const _>::cast(&p_features)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(physical_device, p_features);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_format_properties(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    p_format_properties: *mut vk::FormatProperties,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFormatProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceFormatProperties>(next(
+        436usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::Format>(<*const _>::cast(&format)),
+        Arg::new::<*mut vk::FormatProperties>(<*const _>::cast(&p_format_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(physical_device, format, p_format_properties);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_format_properties2(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    p_format_properties: *mut vk::FormatProperties2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFormatProperties2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceFormatProperties2>(next(
+        437usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::Format>(<*const _>::cast(&format)),
+        Arg::new::<*mut vk::FormatProperties2<'static>>(<*const _>::cast(&p_format_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(physical_device, format, p_format_properties);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_fragment_shading_rates_khr(
+    physical_device: vk::PhysicalDevice,
+    p_fragment_shading_rate_count: *mut u32,
+    p_fragment_shading_rates: *mut vk::PhysicalDeviceFragmentShadingRateKHR<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceFragmentShadingRatesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceFragmentShadingRatesKHR>(
+        next(438usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_fragment_shading_rate_count)),
+        Arg::new::<*mut vk::PhysicalDeviceFragmentShadingRateKHR<'static>>(<*const _>::cast(
+            &p_fragment_shading_rates,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        p_fragment_shading_rate_count,
+        p_fragment_shading_rates,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_image_format_properties(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    ty: vk::ImageType,
+    tiling: vk::ImageTiling,
+    usage: vk::ImageUsageFlags,
+    flags: vk::ImageCreateFlags,
+    p_image_format_properties: *mut vk::ImageFormatProperties,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceImageFormatProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceImageFormatProperties>(
+        next(439usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::Format>(<*const _>::cast(&format)),
+        Arg::new::<vk::ImageType>(<*const _>::cast(&ty)),
+        Arg::new::<vk::ImageTiling>(<*const _>::cast(&tiling)),
+        Arg::new::<vk::ImageUsageFlags>(<*const _>::cast(&usage)),
+        Arg::new::<vk::ImageCreateFlags>(<*const _>::cast(&flags)),
+        Arg::new::<*mut vk::ImageFormatProperties>(<*const _>::cast(&p_image_format_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        format,
+        ty,
+        tiling,
+        usage,
+        flags,
+        p_image_format_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_image_format_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_image_format_info: *const vk::PhysicalDeviceImageFormatInfo2<'_>,
+    p_image_format_properties: *mut vk::ImageFormatProperties2<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceImageFormatProperties2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceImageFormatProperties2>(
+        next(440usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::PhysicalDeviceImageFormatInfo2<'static>>(<*const _>::cast(
+            &p_image_format_info,
+        )),
+        Arg::new::<*mut vk::ImageFormatProperties2<'static>>(<*const _>::cast(
+            &p_image_format_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        p_image_format_info,
+        p_image_format_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_memory_properties(
+    physical_device: vk::PhysicalDevice,
+    p_memory_properties: *mut vk::PhysicalDeviceMemoryProperties,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceMemoryProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceMemoryProperties>(next(
+        441usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut vk::PhysicalDeviceMemoryProperties>(<*const _>::cast(&p_memory_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(physical_device, p_memory_properties);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_memory_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_memory_properties: *mut vk::PhysicalDeviceMemoryProperties2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceMemoryProperties2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceMemoryProperties2>(next(
+        442usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut vk::PhysicalDeviceMemoryProperties2<'static>>(<*const _>::cast(
+            &p_memory_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(physical_device, p_memory_properties);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_multisample_properties_ext(
+    physical_device: vk::PhysicalDevice,
+    samples: vk::SampleCountFlags,
+    p_multisample_properties: *mut vk::MultisamplePropertiesEXT<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceMultisamplePropertiesEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceMultisamplePropertiesEXT>(
+        next(443usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::SampleCountFlags>(<*const _>::cast(&samples)),
+        Arg::new::<*mut vk::MultisamplePropertiesEXT<'static>>(<*const _>::cast(
+            &p_multisample_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(physical_device, samples, p_multisample_properties);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_optical_flow_image_formats_nv(
+    physical_device: vk::PhysicalDevice,
+    p_optical_flow_image_format_info: *const vk::OpticalFlowImageFormatInfoNV<'_>,
+    p_format_count: *mut u32,
+    p_image_format_properties: *mut vk::OpticalFlowImageFormatPropertiesNV<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceOpticalFlowImageFormatsNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceOpticalFlowImageFormatsNV>(
+        next(444usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::OpticalFlowImageFormatInfoNV<'static>>(<*const _>::cast(
+            &p_optical_flow_image_format_info,
+        )),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_format_count)),
+        Arg::new::<*mut vk::OpticalFlowImageFormatPropertiesNV<'static>>(<*const _>::cast(
+            &p_image_format_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        p_optical_flow_image_format_info,
+        p_format_count,
+        p_image_format_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_present_rectangles_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_rect_count: *mut u32,
+    p_rects: *mut vk::Rect2D,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDevicePresentRectanglesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDevicePresentRectanglesKHR>(
+        next(445usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::SurfaceKHR>(<*const _>::cast(&surface)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_rect_count)),
+        Arg::new::<*mut vk::Rect2D>(<*const _>::cast(&p_rects)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, surface, p_rect_count, p_rects);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_properties(
+    physical_device: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceProperties,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceProperties>(next(
+        446usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut vk::PhysicalDeviceProperties>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(physical_device, p_properties);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceProperties2<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceProperties2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceProperties2>(next(
+        447usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut vk::PhysicalDeviceProperties2<'static>>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(physical_device, p_properties);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_queue_family_performance_query_passes_khr(
+    physical_device: vk::PhysicalDevice,
+    p_performance_query_create_info: *const vk::QueryPoolPerformanceCreateInfoKHR<'_>,
+    p_num_passes: *mut u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(
+        b"vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR\0",
+    );
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR,
+    >(next(448usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::QueryPoolPerformanceCreateInfoKHR<'static>>(<*const _>::cast(
+            &p_performance_query_create_info,
+        )),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_num_passes)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        physical_device,
+        p_performance_query_create_info,
+        p_num_passes,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_queue_family_properties(
+    physical_device: vk::PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut vk::QueueFamilyProperties,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceQueueFamilyProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties>(
+        next(449usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_queue_family_property_count)),
+        Arg::new::<*mut vk::QueueFamilyProperties>(<*const _>::cast(&p_queue_family_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        physical_device,
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_queue_family_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut vk::QueueFamilyProperties2<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceQueueFamilyProperties2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2>(
+        next(450usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_queue_family_property_count)),
+        Arg::new::<*mut vk::QueueFamilyProperties2<'static>>(<*const _>::cast(
+            &p_queue_family_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        physical_device,
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_screen_presentation_support_qnx(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    window: *mut vk::_screen_window,
+) -> vk::Bool32 {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceScreenPresentationSupportQNX\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceScreenPresentationSupportQNX,
+    >(next(451usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<u32>(<*const _>::cast(&queue_family_index)),
+        Arg::new::<*mut vk::_screen_window>(<*const _>::cast(&window)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, queue_family_index, window);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Bool32>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_sparse_image_format_properties(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    ty: vk::ImageType,
+    samples: vk::SampleCountFlags,
+    usage: vk::ImageUsageFlags,
+    tiling: vk::ImageTiling,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::SparseImageFormatProperties,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSparseImageFormatProperties\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties,
+    >(next(452usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::Format>(<*const _>::cast(&format)),
+        Arg::new::<vk::ImageType>(<*const _>::cast(&ty)),
+        Arg::new::<vk::SampleCountFlags>(<*const _>::cast(&samples)),
+        Arg::new::<vk::ImageUsageFlags>(<*const _>::cast(&usage)),
+        Arg::new::<vk::ImageTiling>(<*const _>::cast(&tiling)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::SparseImageFormatProperties>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        physical_device,
+        format,
+        ty,
+        samples,
+        usage,
+        tiling,
+        p_property_count,
+        p_properties,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_sparse_image_format_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_format_info: *const vk::PhysicalDeviceSparseImageFormatInfo2<'_>,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::SparseImageFormatProperties2<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSparseImageFormatProperties2\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties2,
+    >(next(453usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::PhysicalDeviceSparseImageFormatInfo2<'static>>(<*const _>::cast(
+            &p_format_info,
+        )),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_property_count)),
+        Arg::new::<*mut vk::SparseImageFormatProperties2<'static>>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        physical_device,
+        p_format_info,
+        p_property_count,
+        p_properties,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_physical_device_supported_framebuffer_mixed_samples_combinations_nv(
+    physical_device: vk::PhysicalDevice,
+    p_combination_count: *mut u32,
+    p_combinations: *mut vk::FramebufferMixedSamplesCombinationNV<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(
+        b"vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV\0",
+    );
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV,
+    >(next(454usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_combination_count)),
+        Arg::new::<*mut vk::FramebufferMixedSamplesCombinationNV<'static>>(<*const _>::cast(
+            &p_combinations,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_combination_count, p_combinations);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_surface_capabilities2_ext(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_surface_capabilities: *mut vk::SurfaceCapabilities2EXT<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceCapabilities2EXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceSurfaceCapabilities2EXT>(
+        next(455usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::SurfaceKHR>(<*const _>::cast(&surface)),
+        Arg::new::<*mut vk::SurfaceCapabilities2EXT<'static>>(<*const _>::cast(
+            &p_surface_capabilities,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, surface, p_surface_capabilities);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_surface_capabilities2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_surface_capabilities: *mut vk::SurfaceCapabilities2KHR<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceCapabilities2KHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR>(
+        next(456usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::PhysicalDeviceSurfaceInfo2KHR<'static>>(<*const _>::cast(
+            &p_surface_info,
+        )),
+        Arg::new::<*mut vk::SurfaceCapabilities2KHR<'static>>(<*const _>::cast(
+            &p_surface_capabilities,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_surface_info, p_surface_capabilities);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_surface_capabilities_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_surface_capabilities: *mut vk::SurfaceCapabilitiesKHR,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR>(
+        next(457usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::SurfaceKHR>(<*const _>::cast(&surface)),
+        Arg::new::<*mut vk::SurfaceCapabilitiesKHR>(<*const _>::cast(&p_surface_capabilities)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, surface, p_surface_capabilities);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_surface_formats2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut vk::SurfaceFormat2KHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceFormats2KHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceSurfaceFormats2KHR>(
+        next(458usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::PhysicalDeviceSurfaceInfo2KHR<'static>>(<*const _>::cast(
+            &p_surface_info,
+        )),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_surface_format_count)),
+        Arg::new::<*mut vk::SurfaceFormat2KHR<'static>>(<*const _>::cast(&p_surface_formats)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        p_surface_info,
+        p_surface_format_count,
+        p_surface_formats,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_surface_formats_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut vk::SurfaceFormatKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceFormatsKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceSurfaceFormatsKHR>(next(
+        459usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::SurfaceKHR>(<*const _>::cast(&surface)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_surface_format_count)),
+        Arg::new::<*mut vk::SurfaceFormatKHR>(<*const _>::cast(&p_surface_formats)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        surface,
+        p_surface_format_count,
+        p_surface_formats,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_surface_present_modes2_ext(
+    physical_device: vk::PhysicalDevice,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_present_mode_count: *mut u32,
+    p_present_modes: *mut vk::PresentModeKHR,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfacePresentModes2EXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceSurfacePresentModes2EXT>(
+        next(460usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::PhysicalDeviceSurfaceInfo2KHR<'static>>(<*const _>::cast(
+            &p_surface_info,
+        )),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_present_mode_count)),
+        Arg::new::<*mut vk::PresentModeKHR>(<*const _>::cast(&p_present_modes)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        p_surface_info,
+        p_present_mode_count,
+        p_present_modes,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_surface_present_modes_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_present_mode_count: *mut u32,
+    p_present_modes: *mut vk::PresentModeKHR,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfacePresentModesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceSurfacePresentModesKHR>(
+        next(461usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::SurfaceKHR>(<*const _>::cast(&surface)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_present_mode_count)),
+        Arg::new::<*mut vk::PresentModeKHR>(<*const _>::cast(&p_present_modes)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        surface,
+        p_present_mode_count,
+        p_present_modes,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_surface_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    surface: vk::SurfaceKHR,
+    p_supported: *mut vk::Bool32,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceSurfaceSupportKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceSurfaceSupportKHR>(next(
+        462usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<u32>(<*const _>::cast(&queue_family_index)),
+        Arg::new::<vk::SurfaceKHR>(<*const _>::cast(&surface)),
+        Arg::new::<*mut vk::Bool32>(<*const _>::cast(&p_supported)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, queue_family_index, surface, p_supported);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_tool_properties(
+    physical_device: vk::PhysicalDevice,
+    p_tool_count: *mut u32,
+    p_tool_properties: *mut vk::PhysicalDeviceToolProperties<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceToolProperties\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceToolProperties>(next(
+        463usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_tool_count)),
+        Arg::new::<*mut vk::PhysicalDeviceToolProperties<'static>>(<*const _>::cast(
+            &p_tool_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_tool_count, p_tool_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_video_capabilities_khr(
+    physical_device: vk::PhysicalDevice,
+    p_video_profile: *const vk::VideoProfileInfoKHR<'_>,
+    p_capabilities: *mut vk::VideoCapabilitiesKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceVideoCapabilitiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceVideoCapabilitiesKHR>(
+        next(464usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::VideoProfileInfoKHR<'static>>(<*const _>::cast(&p_video_profile)),
+        Arg::new::<*mut vk::VideoCapabilitiesKHR<'static>>(<*const _>::cast(&p_capabilities)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, p_video_profile, p_capabilities);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_video_encode_quality_level_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_quality_level_info: *const vk::PhysicalDeviceVideoEncodeQualityLevelInfoKHR<'_>,
+    p_quality_level_properties: *mut vk::VideoEncodeQualityLevelPropertiesKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(
+        b"vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR\0",
+    );
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR,
+    >(next(465usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::PhysicalDeviceVideoEncodeQualityLevelInfoKHR<'static>>(
+            <*const _>::cast(&p_quality_level_info),
+        ),
+        Arg::new::<*mut vk::VideoEncodeQualityLevelPropertiesKHR<'static>>(<*const _>::cast(
+            &p_quality_level_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        p_quality_level_info,
+        p_quality_level_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_video_format_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_video_format_info: *const vk::PhysicalDeviceVideoFormatInfoKHR<'_>,
+    p_video_format_property_count: *mut u32,
+    p_video_format_properties: *mut vk::VideoFormatPropertiesKHR<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceVideoFormatPropertiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceVideoFormatPropertiesKHR>(
+        next(466usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*const vk::PhysicalDeviceVideoFormatInfoKHR<'static>>(<*const _>::cast(
+            &p_video_format_info,
+        )),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_video_format_property_count)),
+        Arg::new::<*mut vk::VideoFormatPropertiesKHR<'static>>(<*const _>::cast(
+            &p_video_format_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        physical_device,
+        p_video_format_info,
+        p_video_format_property_count,
+        p_video_format_properties,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_wayland_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    display: *mut vk::wl_display,
+) -> vk::Bool32 {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceWaylandPresentationSupportKHR\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceWaylandPresentationSupportKHR,
+    >(next(467usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<u32>(<*const _>::cast(&queue_family_index)),
+        Arg::new::<*mut vk::wl_display>(<*const _>::cast(&display)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, queue_family_index, display);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Bool32>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_win32_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+) -> vk::Bool32 {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceWin32PresentationSupportKHR\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR,
+    >(next(468usize, dispatch_key(physical_device.as_raw())));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<u32>(<*const _>::cast(&queue_family_index)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, queue_family_index);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Bool32>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_xcb_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    connection: *mut vk::xcb_connection_t,
+    visual_id: vk::xcb_visualid_t,
+) -> vk::Bool32 {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceXcbPresentationSupportKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceXcbPresentationSupportKHR>(
+        next(469usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<u32>(<*const _>::cast(&queue_family_index)),
+        Arg::new::<*mut vk::xcb_connection_t>(<*const _>::cast(&connection)),
+        Arg::new::<vk::xcb_visualid_t>(<*const _>::cast(&visual_id)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, queue_family_index, connection, visual_id);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Bool32>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_physical_device_xlib_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    dpy: *mut vk::Display,
+    visual_id: vk::VisualID,
+) -> vk::Bool32 {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPhysicalDeviceXlibPresentationSupportKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPhysicalDeviceXlibPresentationSupportKHR>(
+        next(470usize, dispatch_key(physical_device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<u32>(<*const _>::cast(&queue_family_index)),
+        Arg::new::<*mut vk::Display>(<*const _>::cast(&dpy)),
+        Arg::new::<vk::VisualID>(<*const _>::cast(&visual_id)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, queue_family_index, dpy, visual_id);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Bool32>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_pipeline_cache_data(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPipelineCacheData\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPipelineCacheData>(next(
+        471usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::PipelineCache>(<*const _>::cast(&pipeline_cache)),
+        Arg::new::<*mut usize>(<*const _>::cast(&p_data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, pipeline_cache, p_data_size, p_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_pipeline_executable_internal_representations_khr(
+    device: vk::Device,
+    p_executable_info: *const vk::PipelineExecutableInfoKHR<'_>,
+    p_internal_representation_count: *mut u32,
+    p_internal_representations: *mut vk::PipelineExecutableInternalRepresentationKHR<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetPipelineExecutableInternalRepresentationsKHR\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetPipelineExecutableInternalRepresentationsKHR,
+    >(next(472usize, dispatch_key(device.as_raw())));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::PipelineExecutableInfoKHR<'static>>(<*const _>::cast(
+            &p_executable_info,
+        )),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_internal_representation_count)),
+        Arg::new::<*mut vk::PipelineExecutableInternalRepresentationKHR<'static>>(
+            <*const _>::cast(&p_internal_representations),
+        ),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        p_executable_info,
+        p_internal_representation_count,
+        p_internal_representations,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_pipeline_executable_properties_khr(
+    device: vk::Device,
+    p_pipeline_info: *const vk::PipelineInfoKHR<'_>,
+    p_executable_count: *mut u32,
+    p_properties: *mut vk::PipelineExecutablePropertiesKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPipelineExecutablePropertiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPipelineExecutablePropertiesKHR>(next(
+        473usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::PipelineInfoKHR<'static>>(<*const _>::cast(&p_pipeline_info)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_executable_count)),
+        Arg::new::<*mut vk::PipelineExecutablePropertiesKHR<'static>>(<*const _>::cast(
+            &p_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_pipeline_info, p_executable_count, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_pipeline_executable_statistics_khr(
+    device: vk::Device,
+    p_executable_info: *const vk::PipelineExecutableInfoKHR<'_>,
+    p_statistic_count: *mut u32,
+    p_statistics: *mut vk::PipelineExecutableStatisticKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPipelineExecutableStatisticsKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPipelineExecutableStatisticsKHR>(next(
+        474usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::PipelineExecutableInfoKHR<'static>>(<*const _>::cast(
+            &p_executable_info,
+        )),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_statistic_count)),
+        Arg::new::<*mut vk::PipelineExecutableStatisticKHR<'static>>(<*const _>::cast(
+            &p_statistics,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_executable_info, p_statistic_count, p_statistics);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_pipeline_properties_ext(
+    device: vk::Device,
+    p_pipeline_info: *const vk::PipelineInfoEXT<'_>,
+    p_pipeline_properties: *mut vk::BaseOutStructure<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPipelinePropertiesEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPipelinePropertiesEXT>(next(
+        475usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::PipelineInfoEXT<'static>>(<*const _>::cast(&p_pipeline_info)),
+        Arg::new::<*mut vk::BaseOutStructure<'static>>(<*const _>::cast(&p_pipeline_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_pipeline_info, p_pipeline_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_private_data(
+    device: vk::Device,
+    object_type: vk::ObjectType,
+    object_handle: u64,
+    private_data_slot: vk::PrivateDataSlot,
+    p_data: *mut u64,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetPrivateData\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetPrivateData>(next(
+        476usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ObjectType>(<*const _>::cast(&object_type)),
+        Arg::new::<u64>(<*const _>::cast(&object_handle)),
+        Arg::new::<vk::PrivateDataSlot>(<*const _>::cast(&private_data_slot)),
+        Arg::new::<*mut u64>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        device,
+        object_type,
+        object_handle,
+        private_data_slot,
+        p_data,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_query_pool_results(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: vk::DeviceSize,
+    flags: vk::QueryResultFlags,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetQueryPoolResults\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetQueryPoolResults>(next(
+        477usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&first_query)),
+        Arg::new::<u32>(<*const _>::cast(&query_count)),
+        Arg::new::<usize>(<*const _>::cast(&data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&stride)),
+        Arg::new::<vk::QueryResultFlags>(<*const _>::cast(&flags)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        query_pool,
+        first_query,
+        query_count,
+        data_size,
+        p_data,
+        stride,
+        flags,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_queue_checkpoint_data2_nv(
+    queue: vk::Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut vk::CheckpointData2NV<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetQueueCheckpointData2NV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetQueueCheckpointData2NV>(next(
+        478usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Queue>(<*const _>::cast(&queue)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_checkpoint_data_count)),
+        Arg::new::<*mut vk::CheckpointData2NV<'static>>(<*const _>::cast(&p_checkpoint_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(queue, p_checkpoint_data_count, p_checkpoint_data);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_queue_checkpoint_data_nv(
+    queue: vk::Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut vk::CheckpointDataNV<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetQueueCheckpointDataNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetQueueCheckpointDataNV>(next(
+        479usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Queue>(<*const _>::cast(&queue)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_checkpoint_data_count)),
+        Arg::new::<*mut vk::CheckpointDataNV<'static>>(<*const _>::cast(&p_checkpoint_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(queue, p_checkpoint_data_count, p_checkpoint_data);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_rand_r_output_display_ext(
+    physical_device: vk::PhysicalDevice,
+    dpy: *mut vk::Display,
+    rr_output: vk::RROutput,
+    p_display: *mut vk::DisplayKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetRandROutputDisplayEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetRandROutputDisplayEXT>(next(
+        480usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<*mut vk::Display>(<*const _>::cast(&dpy)),
+        Arg::new::<vk::RROutput>(<*const _>::cast(&rr_output)),
+        Arg::new::<*mut vk::DisplayKHR>(<*const _>::cast(&p_display)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, dpy, rr_output, p_display);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_ray_tracing_capture_replay_shader_group_handles_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR\0");
+    let next = mem::transmute::<
+        *const c_void,
+        vk::PFN_vkGetRayTracingCaptureReplayShaderGroupHandlesKHR,
+    >(next(481usize, dispatch_key(device.as_raw())));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Pipeline>(<*const _>::cast(&pipeline)),
+        Arg::new::<u32>(<*const _>::cast(&first_group)),
+        Arg::new::<u32>(<*const _>::cast(&group_count)),
+        Arg::new::<usize>(<*const _>::cast(&data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_ray_tracing_shader_group_handles_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetRayTracingShaderGroupHandlesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetRayTracingShaderGroupHandlesKHR>(next(
+        482usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Pipeline>(<*const _>::cast(&pipeline)),
+        Arg::new::<u32>(<*const _>::cast(&first_group)),
+        Arg::new::<u32>(<*const _>::cast(&group_count)),
+        Arg::new::<usize>(<*const _>::cast(&data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_ray_tracing_shader_group_stack_size_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    group: u32,
+    group_shader: vk::ShaderGroupShaderKHR,
+) -> vk::DeviceSize {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetRayTracingShaderGroupStackSizeKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetRayTracingShaderGroupStackSizeKHR>(
+        next(483usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Pipeline>(<*const _>::cast(&pipeline)),
+        Arg::new::<u32>(<*const _>::cast(&group)),
+        Arg::new::<vk::ShaderGroupShaderKHR>(<*const _>::cast(&group_shader)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, pipeline, group, group_shader);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::DeviceSize>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_refresh_cycle_duration_google(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_display_timing_properties: *mut vk::RefreshCycleDurationGOOGLE,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetRefreshCycleDurationGOOGLE\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetRefreshCycleDurationGOOGLE>(next(
+        484usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swapchain)),
+        Arg::new::<*mut vk::RefreshCycleDurationGOOGLE>(<*const _>::cast(
+            &p_display_timing_properties,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, swapchain, p_display_timing_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_render_area_granularity(
+    device: vk::Device,
+    render_pass: vk::RenderPass,
+    p_granularity: *mut vk::Extent2D,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetRenderAreaGranularity\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetRenderAreaGranularity>(next(
+        485usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::RenderPass>(<*const _>::cast(&render_pass)),
+        Arg::new::<*mut vk::Extent2D>(<*const _>::cast(&p_granularity)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, render_pass, p_granularity);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_sampler_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::SamplerCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetSamplerOpaqueCaptureDescriptorDataEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetSamplerOpaqueCaptureDescriptorDataEXT>(
+        next(486usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::SamplerCaptureDescriptorDataInfoEXT<'static>>(<*const _>::cast(
+            &p_info,
+        )),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_info, p_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_screen_buffer_properties_qnx(
+    device: vk::Device,
+    buffer: *const vk::_screen_buffer,
+    p_properties: *mut vk::ScreenBufferPropertiesQNX<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetScreenBufferPropertiesQNX\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetScreenBufferPropertiesQNX>(next(
+        487usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::_screen_buffer>(<*const _>::cast(&buffer)),
+        Arg::new::<*mut vk::ScreenBufferPropertiesQNX<'static>>(<*const _>::cast(&p_properties)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, buffer, p_properties);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_semaphore_counter_value(
+    device: vk::Device,
+    semaphore: vk::Semaphore,
+    p_value: *mut u64,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetSemaphoreCounterValue\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetSemaphoreCounterValue>(next(
+        488usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Semaphore>(<*const _>::cast(&semaphore)),
+        Arg::new::<*mut u64>(<*const _>::cast(&p_value)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, semaphore, p_value);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_semaphore_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::SemaphoreGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetSemaphoreFdKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetSemaphoreFdKHR>(next(
+        489usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::SemaphoreGetFdInfoKHR<'static>>(<*const _>::cast(&p_get_fd_info)),
+        Arg::new::<*mut c_int>(<*const _>::cast(&p_fd)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_get_fd_info, p_fd);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_semaphore_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::SemaphoreGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetSemaphoreWin32HandleKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetSemaphoreWin32HandleKHR>(next(
+        490usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::SemaphoreGetWin32HandleInfoKHR<'static>>(<*const _>::cast(
+            &p_get_win32_handle_info,
+        )),
+        Arg::new::<*mut vk::HANDLE>(<*const _>::cast(&p_handle)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_get_win32_handle_info, p_handle);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_semaphore_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_get_zircon_handle_info: *const vk::SemaphoreGetZirconHandleInfoFUCHSIA<'_>,
+    p_zircon_handle: *mut vk::zx_handle_t,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetSemaphoreZirconHandleFUCHSIA\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetSemaphoreZirconHandleFUCHSIA>(next(
+        491usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::SemaphoreGetZirconHandleInfoFUCHSIA<'static>>(<*const _>::cast(
+            &p_get_zircon_handle_info,
+        )),
+        Arg::new::<*mut vk::zx_handle_t>(<*const _>::cast(&p_zircon_handle)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_get_zircon_handle_info, p_zircon_handle);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_shader_binary_data_ext(
+    device: vk::Device,
+    shader: vk::ShaderEXT,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetShaderBinaryDataEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetShaderBinaryDataEXT>(next(
+        492usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ShaderEXT>(<*const _>::cast(&shader)),
+        Arg::new::<*mut usize>(<*const _>::cast(&p_data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, shader, p_data_size, p_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_shader_info_amd(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    shader_stage: vk::ShaderStageFlags,
+    info_type: vk::ShaderInfoTypeAMD,
+    p_info_size: *mut usize,
+    p_info: *mut c_void,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetShaderInfoAMD\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetShaderInfoAMD>(next(
+        493usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Pipeline>(<*const _>::cast(&pipeline)),
+        Arg::new::<vk::ShaderStageFlags>(<*const _>::cast(&shader_stage)),
+        Arg::new::<vk::ShaderInfoTypeAMD>(<*const _>::cast(&info_type)),
+        Arg::new::<*mut usize>(<*const _>::cast(&p_info_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        pipeline,
+        shader_stage,
+        info_type,
+        p_info_size,
+        p_info,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_shader_module_create_info_identifier_ext(
+    device: vk::Device,
+    p_create_info: *const vk::ShaderModuleCreateInfo<'_>,
+    p_identifier: *mut vk::ShaderModuleIdentifierEXT<'_>,
+) {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkGetShaderModuleCreateInfoIdentifierEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetShaderModuleCreateInfoIdentifierEXT>(
+        next(494usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ShaderModuleCreateInfo<'static>>(<*const _>::cast(&p_create_info)),
+        Arg::new::<*mut vk::ShaderModuleIdentifierEXT<'static>>(<*const _>::cast(&p_identifier)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, p_create_info, p_identifier);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_shader_module_identifier_ext(
+    device: vk::Device,
+    shader_module: vk::ShaderModule,
+    p_identifier: *mut vk::ShaderModuleIdentifierEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetShaderModuleIdentifierEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetShaderModuleIdentifierEXT>(next(
+        495usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ShaderModule>(<*const _>::cast(&shader_module)),
+        Arg::new::<*mut vk::ShaderModuleIdentifierEXT<'static>>(<*const _>::cast(&p_identifier)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, shader_module, p_identifier);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn get_swapchain_counter_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    counter: vk::SurfaceCounterFlagsEXT,
+    p_counter_value: *mut u64,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainCounterEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetSwapchainCounterEXT>(next(
+        496usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swapchain)),
+        Arg::new::<vk::SurfaceCounterFlagsEXT>(<*const _>::cast(&counter)),
+        Arg::new::<*mut u64>(<*const _>::cast(&p_counter_value)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, swapchain, counter, p_counter_value);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_swapchain_gralloc_usage2_android(
+    device: vk::Device,
+    format: vk::Format,
+    image_usage: vk::ImageUsageFlags,
+    swapchain_image_usage: vk::SwapchainImageUsageFlagsANDROID,
+    gralloc_consumer_usage: *mut u64,
+    gralloc_producer_usage: *mut u64,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainGrallocUsage2ANDROID\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetSwapchainGrallocUsage2ANDROID>(next(
+        497usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Format>(<*const _>::cast(&format)),
+        Arg::new::<vk::ImageUsageFlags>(<*const _>::cast(&image_usage)),
+        Arg::new::<vk::SwapchainImageUsageFlagsANDROID>(<*const _>::cast(&swapchain_image_usage)),
+        Arg::new::<*mut u64>(<*const _>::cast(&gralloc_consumer_usage)),
+        Arg::new::<*mut u64>(<*const _>::cast(&gralloc_producer_usage)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        format,
+        image_usage,
+        swapchain_image_usage,
+        gralloc_consumer_usage,
+        gralloc_producer_usage,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_swapchain_gralloc_usage_android(
+    device: vk::Device,
+    format: vk::Format,
+    image_usage: vk::ImageUsageFlags,
+    gralloc_usage: *mut c_int,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainGrallocUsageANDROID\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetSwapchainGrallocUsageANDROID>(next(
+        498usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Format>(<*const _>::cast(&format)),
+        Arg::new::<vk::ImageUsageFlags>(<*const _>::cast(&image_usage)),
+        Arg::new::<*mut c_int>(<*const _>::cast(&gralloc_usage)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, format, image_usage, gralloc_usage);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_swapchain_images_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_swapchain_image_count: *mut u32,
+    p_swapchain_images: *mut vk::Image,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainImagesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetSwapchainImagesKHR>(next(
+        499usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swapchain)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_swapchain_image_count)),
+        Arg::new::<*mut vk::Image>(<*const _>::cast(&p_swapchain_images)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        swapchain,
+        p_swapchain_image_count,
+        p_swapchain_images,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_swapchain_status_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainStatusKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetSwapchainStatusKHR>(next(
+        500usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swapchain)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, swapchain);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_validation_cache_data_ext(
+    device: vk::Device,
+    validation_cache: vk::ValidationCacheEXT,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetValidationCacheDataEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetValidationCacheDataEXT>(next(
+        501usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ValidationCacheEXT>(<*const _>::cast(&validation_cache)),
+        Arg::new::<*mut usize>(<*const _>::cast(&p_data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, validation_cache, p_data_size, p_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_video_session_memory_requirements_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    p_memory_requirements_count: *mut u32,
+    p_memory_requirements: *mut vk::VideoSessionMemoryRequirementsKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetVideoSessionMemoryRequirementsKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetVideoSessionMemoryRequirementsKHR>(
+        next(502usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::VideoSessionKHR>(<*const _>::cast(&video_session)),
+        Arg::new::<*mut u32>(<*const _>::cast(&p_memory_requirements_count)),
+        Arg::new::<*mut vk::VideoSessionMemoryRequirementsKHR<'static>>(<*const _>::cast(
+            &p_memory_requirements,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        video_session,
+        p_memory_requirements_count,
+        p_memory_requirements,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn get_winrt_display_nv(
+    physical_device: vk::PhysicalDevice,
+    device_relative_id: u32,
+    p_display: *mut vk::DisplayKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkGetWinrtDisplayNV\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkGetWinrtDisplayNV>(next(
+        503usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<u32>(<*const _>::cast(&device_relative_id)),
+        Arg::new::<*mut vk::DisplayKHR>(<*const _>::cast(&p_display)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, device_relative_id, p_display);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn import_fence_fd_khr(
+    device: vk::Device,
+    p_import_fence_fd_info: *const vk::ImportFenceFdInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkImportFenceFdKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkImportFenceFdKHR>(next(
+        504usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImportFenceFdInfoKHR<'static>>(<*const _>::cast(
+            &p_import_fence_fd_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_import_fence_fd_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn import_fence_win32_handle_khr(
+    device: vk::Device,
+    p_import_fence_win32_handle_info: *const vk::ImportFenceWin32HandleInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkImportFenceWin32HandleKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkImportFenceWin32HandleKHR>(next(
+        505usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImportFenceWin32HandleInfoKHR<'static>>(<*const _>::cast(
+            &p_import_fence_win32_handle_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_import_fence_win32_handle_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn import_semaphore_fd_khr(
+    device: vk::Device,
+    p_import_semaphore_fd_info: *const vk::ImportSemaphoreFdInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkImportSemaphoreFdKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkImportSemaphoreFdKHR>(next(
+        506usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImportSemaphoreFdInfoKHR<'static>>(<*const _>::cast(
+            &p_import_semaphore_fd_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_import_semaphore_fd_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn import_semaphore_win32_handle_khr(
+    device: vk::Device,
+    p_import_semaphore_win32_handle_info: *const vk::ImportSemaphoreWin32HandleInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkImportSemaphoreWin32HandleKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkImportSemaphoreWin32HandleKHR>(next(
+        507usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImportSemaphoreWin32HandleInfoKHR<'static>>(<*const _>::cast(
+            &p_import_semaphore_win32_handle_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_import_semaphore_win32_handle_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn import_semaphore_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_import_semaphore_zircon_handle_info: *const vk::ImportSemaphoreZirconHandleInfoFUCHSIA<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkImportSemaphoreZirconHandleFUCHSIA\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkImportSemaphoreZirconHandleFUCHSIA>(next(
+        508usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ImportSemaphoreZirconHandleInfoFUCHSIA<'static>>(<*const _>::cast(
+            &p_import_semaphore_zircon_handle_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_import_semaphore_zircon_handle_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn initialize_performance_api_intel(
+    device: vk::Device,
+    p_initialize_info: *const vk::InitializePerformanceApiInfoINTEL<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkInitializePerformanceApiINTEL\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkInitializePerformanceApiINTEL>(next(
+        509usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::InitializePerformanceApiInfoINTEL<'static>>(<*const _>::cast(
+            &p_initialize_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_initialize_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn invalidate_mapped_memory_ranges(
+    device: vk::Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const vk::MappedMemoryRange<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkInvalidateMappedMemoryRanges\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkInvalidateMappedMemoryRanges>(next(
+        510usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&memory_range_count)),
+        Arg::new::<*const vk::MappedMemoryRange<'static>>(<*const _>::cast(&p_memory_ranges)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, memory_range_count, p_memory_ranges);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn map_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    flags: vk::MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkMapMemory\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkMapMemory>(next(
+        511usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeviceMemory>(<*const _>::cast(&memory)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&offset)),
+        Arg::new::<vk::DeviceSize>(<*const _>::cast(&size)),
+        Arg::new::<vk::MemoryMapFlags>(<*const _>::cast(&flags)),
+        Arg::new::<*mut *mut c_void>(<*const _>::cast(&pp_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, memory, offset, size, flags, pp_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn map_memory2_khr(
+    device: vk::Device,
+    p_memory_map_info: *const vk::MemoryMapInfoKHR<'_>,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkMapMemory2KHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkMapMemory2KHR>(next(
+        512usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::MemoryMapInfoKHR<'static>>(<*const _>::cast(&p_memory_map_info)),
+        Arg::new::<*mut *mut c_void>(<*const _>::cast(&pp_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_memory_map_info, pp_data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn merge_pipeline_caches(
+    device: vk::Device,
+    dst_cache: vk::PipelineCache,
+    src_cache_count: u32,
+    p_src_caches: *const vk::PipelineCache,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkMergePipelineCaches\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkMergePipelineCaches>(next(
+        513usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::PipelineCache>(<*const _>::cast(&dst_cache)),
+        Arg::new::<u32>(<*const _>::cast(&src_cache_count)),
+        Arg::new::<*const vk::PipelineCache>(<*const _>::cast(&p_src_caches)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, dst_cache, src_cache_count, p_src_caches);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn merge_validation_caches_ext(
+    device: vk::Device,
+    dst_cache: vk::ValidationCacheEXT,
+    src_cache_count: u32,
+    p_src_caches: *const vk::ValidationCacheEXT,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkMergeValidationCachesEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkMergeValidationCachesEXT>(next(
+        514usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ValidationCacheEXT>(<*const _>::cast(&dst_cache)),
+        Arg::new::<u32>(<*const _>::cast(&src_cache_count)),
+        Arg::new::<*const vk::ValidationCacheEXT>(<*const _>::cast(&p_src_caches)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, dst_cache, src_cache_count, p_src_caches);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn queue_begin_debug_utils_label_ext(
+    queue: vk::Queue,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkQueueBeginDebugUtilsLabelEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkQueueBeginDebugUtilsLabelEXT>(next(
+        515usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Queue>(<*const _>::cast(&queue)),
+        Arg::new::<*const vk::DebugUtilsLabelEXT<'static>>(<*const _>::cast(&p_label_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(queue, p_label_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn queue_bind_sparse(
+    queue: vk::Queue,
+    bind_info_count: u32,
+    p_bind_info: *const vk::BindSparseInfo<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkQueueBindSparse\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkQueueBindSparse>(next(
+        516usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Queue>(<*const _>::cast(&queue)),
+        Arg::new::<u32>(<*const _>::cast(&bind_info_count)),
+        Arg::new::<*const vk::BindSparseInfo<'static>>(<*const _>::cast(&p_bind_info)),
+        Arg::new::<vk::Fence>(<*const _>::cast(&fence)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(queue, bind_info_count, p_bind_info, fence);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn queue_end_debug_utils_label_ext(queue: vk::Queue) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkQueueEndDebugUtilsLabelEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkQueueEndDebugUtilsLabelEXT>(next(
+        517usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [Arg::new::<vk::Queue>(<*const _>::cast(&queue))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(queue);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn queue_insert_debug_utils_label_ext(
+    queue: vk::Queue,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkQueueInsertDebugUtilsLabelEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkQueueInsertDebugUtilsLabelEXT>(next(
+        518usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Queue>(<*const _>::cast(&queue)),
+        Arg::new::<*const vk::DebugUtilsLabelEXT<'static>>(<*const _>::cast(&p_label_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(queue, p_label_info);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn queue_present_khr(
+    queue: vk::Queue,
+    p_present_info: *const vk::PresentInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkQueuePresentKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkQueuePresentKHR>(next(
+        519usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Queue>(<*const _>::cast(&queue)),
+        Arg::new::<*const vk::PresentInfoKHR<'static>>(<*const _>::cast(&p_present_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(queue, p_present_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn queue_set_performance_configuration_intel(
+    queue: vk::Queue,
+    configuration: vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkQueueSetPerformanceConfigurationINTEL\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkQueueSetPerformanceConfigurationINTEL>(
+        next(520usize, dispatch_key(queue.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Queue>(<*const _>::cast(&queue)),
+        Arg::new::<vk::PerformanceConfigurationINTEL>(<*const _>::cast(&configuration)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(queue, configuration);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn queue_signal_release_image_android(
+    queue: vk::Queue,
+    wait_semaphore_count: u32,
+    p_wait_semaphores: *const vk::Semaphore,
+    image: vk::Image,
+    p_native_fence_fd: *mut c_int,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkQueueSignalReleaseImageANDROID\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkQueueSignalReleaseImageANDROID>(next(
+        521usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Queue>(<*const _>::cast(&queue)),
+        Arg::new::<u32>(<*const _>::cast(&wait_semaphore_count)),
+        Arg::new::<*const vk::Semaphore>(<*const _>::cast(&p_wait_semaphores)),
+        Arg::new::<vk::Image>(<*const _>::cast(&image)),
+        Arg::new::<*mut c_int>(<*const _>::cast(&p_native_fence_fd)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        queue,
+        wait_semaphore_count,
+        p_wait_semaphores,
+        image,
+        p_native_fence_fd,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn queue_submit(
+    queue: vk::Queue,
+    submit_count: u32,
+    p_submits: *const vk::SubmitInfo<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkQueueSubmit\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkQueueSubmit>(next(
+        522usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Queue>(<*const _>::cast(&queue)),
+        Arg::new::<u32>(<*const _>::cast(&submit_count)),
+        Arg::new::<*const vk::SubmitInfo<'static>>(<*const _>::cast(&p_submits)),
+        Arg::new::<vk::Fence>(<*const _>::cast(&fence)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(queue, submit_count, p_submits, fence);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn queue_submit2(
+    queue: vk::Queue,
+    submit_count: u32,
+    p_submits: *const vk::SubmitInfo2<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkQueueSubmit2\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkQueueSubmit2>(next(
+        523usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Queue>(<*const _>::cast(&queue)),
+        Arg::new::<u32>(<*const _>::cast(&submit_count)),
+        Arg::new::<*const vk::SubmitInfo2<'static>>(<*const _>::cast(&p_submits)),
+        Arg::new::<vk::Fence>(<*const _>::cast(&fence)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(queue, submit_count, p_submits, fence);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn queue_wait_idle(queue: vk::Queue) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkQueueWaitIdle\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkQueueWaitIdle>(next(
+        524usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    let args = [Arg::new::<vk::Queue>(<*const _>::cast(&queue))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(queue);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn register_device_event_ext(
+    device: vk::Device,
+    p_device_event_info: *const vk::DeviceEventInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkRegisterDeviceEventEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkRegisterDeviceEventEXT>(next(
+        525usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DeviceEventInfoEXT<'static>>(<*const _>::cast(&p_device_event_info)),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Fence>(<*const _>::cast(&p_fence)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_device_event_info, p_allocator, p_fence);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn register_display_event_ext(
+    device: vk::Device,
+    display: vk::DisplayKHR,
+    p_display_event_info: *const vk::DisplayEventInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkRegisterDisplayEventEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkRegisterDisplayEventEXT>(next(
+        526usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DisplayKHR>(<*const _>::cast(&display)),
+        Arg::new::<*const vk::DisplayEventInfoEXT<'static>>(<*const _>::cast(
+            &p_display_event_info,
+        )),
+        Arg::new::<*const vk::AllocationCallbacks<'static>>(<*const _>::cast(&p_allocator)),
+        Arg::new::<*mut vk::Fence>(<*const _>::cast(&p_fence)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, display, p_display_event_info, p_allocator, p_fence);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn release_display_ext(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkReleaseDisplayEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkReleaseDisplayEXT>(next(
+        527usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::PhysicalDevice>(<*const _>::cast(&physical_device)),
+        Arg::new::<vk::DisplayKHR>(<*const _>::cast(&display)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(physical_device, display);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn release_full_screen_exclusive_mode_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkReleaseFullScreenExclusiveModeEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkReleaseFullScreenExclusiveModeEXT>(next(
+        528usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swapchain)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, swapchain);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn release_performance_configuration_intel(
+    device: vk::Device,
+    configuration: vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkReleasePerformanceConfigurationINTEL\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkReleasePerformanceConfigurationINTEL>(
+        next(529usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::PerformanceConfigurationINTEL>(<*const _>::cast(&configuration)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, configuration);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn release_profiling_lock_khr(device: vk::Device) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkReleaseProfilingLockKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkReleaseProfilingLockKHR>(next(
+        530usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [Arg::new::<vk::Device>(<*const _>::cast(&device))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn release_swapchain_images_ext(
+    device: vk::Device,
+    p_release_info: *const vk::ReleaseSwapchainImagesInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkReleaseSwapchainImagesEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkReleaseSwapchainImagesEXT>(next(
+        531usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::ReleaseSwapchainImagesInfoEXT<'static>>(<*const _>::cast(
+            &p_release_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_release_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn reset_command_buffer(
+    command_buffer: vk::CommandBuffer,
+    flags: vk::CommandBufferResetFlags,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkResetCommandBuffer\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkResetCommandBuffer>(next(
+        532usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::CommandBuffer>(<*const _>::cast(&command_buffer)),
+        Arg::new::<vk::CommandBufferResetFlags>(<*const _>::cast(&flags)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(command_buffer, flags);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn reset_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    flags: vk::CommandPoolResetFlags,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkResetCommandPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkResetCommandPool>(next(
+        533usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::CommandPool>(<*const _>::cast(&command_pool)),
+        Arg::new::<vk::CommandPoolResetFlags>(<*const _>::cast(&flags)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, command_pool, flags);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn reset_descriptor_pool(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    flags: vk::DescriptorPoolResetFlags,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkResetDescriptorPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkResetDescriptorPool>(next(
+        534usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DescriptorPool>(<*const _>::cast(&descriptor_pool)),
+        Arg::new::<vk::DescriptorPoolResetFlags>(<*const _>::cast(&flags)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, descriptor_pool, flags);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn reset_event(device: vk::Device, event: vk::Event) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkResetEvent\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkResetEvent>(next(
+        535usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Event>(<*const _>::cast(&event)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, event);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn reset_fences(
+    device: vk::Device,
+    fence_count: u32,
+    p_fences: *const vk::Fence,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkResetFences\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkResetFences>(next(
+        536usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&fence_count)),
+        Arg::new::<*const vk::Fence>(<*const _>::cast(&p_fences)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, fence_count, p_fences);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn reset_query_pool(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkResetQueryPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkResetQueryPool>(next(
+        537usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::QueryPool>(<*const _>::cast(&query_pool)),
+        Arg::new::<u32>(<*const _>::cast(&first_query)),
+        Arg::new::<u32>(<*const _>::cast(&query_count)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, query_pool, first_query, query_count);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn set_buffer_collection_buffer_constraints_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_buffer_constraints_info: *const vk::BufferConstraintsInfoFUCHSIA<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkSetBufferCollectionBufferConstraintsFUCHSIA\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSetBufferCollectionBufferConstraintsFUCHSIA>(
+        next(538usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::BufferCollectionFUCHSIA>(<*const _>::cast(&collection)),
+        Arg::new::<*const vk::BufferConstraintsInfoFUCHSIA<'static>>(<*const _>::cast(
+            &p_buffer_constraints_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, collection, p_buffer_constraints_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn set_buffer_collection_image_constraints_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_image_constraints_info: *const vk::ImageConstraintsInfoFUCHSIA<'_>,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkSetBufferCollectionImageConstraintsFUCHSIA\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSetBufferCollectionImageConstraintsFUCHSIA>(
+        next(539usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::BufferCollectionFUCHSIA>(<*const _>::cast(&collection)),
+        Arg::new::<*const vk::ImageConstraintsInfoFUCHSIA<'static>>(<*const _>::cast(
+            &p_image_constraints_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, collection, p_image_constraints_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn set_debug_utils_object_name_ext(
+    device: vk::Device,
+    p_name_info: *const vk::DebugUtilsObjectNameInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkSetDebugUtilsObjectNameEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSetDebugUtilsObjectNameEXT>(next(
+        540usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DebugUtilsObjectNameInfoEXT<'static>>(<*const _>::cast(&p_name_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_name_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn set_debug_utils_object_tag_ext(
+    device: vk::Device,
+    p_tag_info: *const vk::DebugUtilsObjectTagInfoEXT<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkSetDebugUtilsObjectTagEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSetDebugUtilsObjectTagEXT>(next(
+        541usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::DebugUtilsObjectTagInfoEXT<'static>>(<*const _>::cast(&p_tag_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_tag_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn set_device_memory_priority_ext(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    priority: f32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkSetDeviceMemoryPriorityEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSetDeviceMemoryPriorityEXT>(next(
+        542usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeviceMemory>(<*const _>::cast(&memory)),
+        Arg::new::<f32>(<*const _>::cast(&priority)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, memory, priority);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn set_event(device: vk::Device, event: vk::Event) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkSetEvent\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSetEvent>(next(
+        543usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::Event>(<*const _>::cast(&event)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, event);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn set_hdr_metadata_ext(
+    device: vk::Device,
+    swapchain_count: u32,
+    p_swapchains: *const vk::SwapchainKHR,
+    p_metadata: *const vk::HdrMetadataEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkSetHdrMetadataEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSetHdrMetadataEXT>(next(
+        544usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&swapchain_count)),
+        Arg::new::<*const vk::SwapchainKHR>(<*const _>::cast(&p_swapchains)),
+        Arg::new::<*const vk::HdrMetadataEXT<'static>>(<*const _>::cast(&p_metadata)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, swapchain_count, p_swapchains, p_metadata);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn set_local_dimming_amd(
+    device: vk::Device,
+    swap_chain: vk::SwapchainKHR,
+    local_dimming_enable: vk::Bool32,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkSetLocalDimmingAMD\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSetLocalDimmingAMD>(next(
+        545usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swap_chain)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&local_dimming_enable)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, swap_chain, local_dimming_enable);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn set_private_data(
+    device: vk::Device,
+    object_type: vk::ObjectType,
+    object_handle: u64,
+    private_data_slot: vk::PrivateDataSlot,
+    data: u64,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkSetPrivateData\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSetPrivateData>(next(
+        546usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::ObjectType>(<*const _>::cast(&object_type)),
+        Arg::new::<u64>(<*const _>::cast(&object_handle)),
+        Arg::new::<vk::PrivateDataSlot>(<*const _>::cast(&private_data_slot)),
+        Arg::new::<u64>(<*const _>::cast(&data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, object_type, object_handle, private_data_slot, data);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn signal_semaphore(
+    device: vk::Device,
+    p_signal_info: *const vk::SemaphoreSignalInfo<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkSignalSemaphore\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSignalSemaphore>(next(
+        547usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::SemaphoreSignalInfo<'static>>(<*const _>::cast(&p_signal_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_signal_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn submit_debug_utils_message_ext(
+    instance: vk::Instance,
+    message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    message_types: vk::DebugUtilsMessageTypeFlagsEXT,
+    p_callback_data: *const vk::DebugUtilsMessengerCallbackDataEXT<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkSubmitDebugUtilsMessageEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkSubmitDebugUtilsMessageEXT>(next(
+        548usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Instance>(<*const _>::cast(&instance)),
+        Arg::new::<vk::DebugUtilsMessageSeverityFlagsEXT>(<*const _>::cast(&message_severity)),
+        Arg::new::<vk::DebugUtilsMessageTypeFlagsEXT>(<*const _>::cast(&message_types)),
+        Arg::new::<*const vk::DebugUtilsMessengerCallbackDataEXT<'static>>(<*const _>::cast(
+            &p_callback_data,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(instance, message_severity, message_types, p_callback_data);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn trim_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    flags: vk::CommandPoolTrimFlags,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkTrimCommandPool\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkTrimCommandPool>(next(
+        549usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::CommandPool>(<*const _>::cast(&command_pool)),
+        Arg::new::<vk::CommandPoolTrimFlags>(<*const _>::cast(&flags)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, command_pool, flags);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn uninitialize_performance_api_intel(device: vk::Device) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkUninitializePerformanceApiINTEL\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkUninitializePerformanceApiINTEL>(next(
+        550usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [Arg::new::<vk::Device>(<*const _>::cast(&device))];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn unmap_memory(device: vk::Device, memory: vk::DeviceMemory) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkUnmapMemory\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkUnmapMemory>(next(
+        551usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DeviceMemory>(<*const _>::cast(&memory)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, memory);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn unmap_memory2_khr(
+    device: vk::Device,
+    p_memory_unmap_info: *const vk::MemoryUnmapInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkUnmapMemory2KHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkUnmapMemory2KHR>(next(
+        552usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::MemoryUnmapInfoKHR<'static>>(<*const _>::cast(&p_memory_unmap_info)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_memory_unmap_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn update_descriptor_set_with_template(
+    device: vk::Device,
+    descriptor_set: vk::DescriptorSet,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    p_data: *const c_void,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkUpdateDescriptorSetWithTemplate\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkUpdateDescriptorSetWithTemplate>(next(
+        553usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::DescriptorSet>(<*const _>::cast(&descriptor_set)),
+        Arg::new::<vk::DescriptorUpdateTemplate>(<*const _>::cast(&descriptor_update_template)),
+        Arg::new::<*const c_void>(<*const _>::cast(&p_data)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(device, descriptor_set, descriptor_update_template, p_data);
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn update_descriptor_sets(
+    device: vk::Device,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const vk::WriteDescriptorSet<'_>,
+    descriptor_copy_count: u32,
+    p_descriptor_copies: *const vk::CopyDescriptorSet<'_>,
+) {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkUpdateDescriptorSets\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkUpdateDescriptorSets>(next(
+        554usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&descriptor_write_count)),
+        Arg::new::<*const vk::WriteDescriptorSet<'static>>(<*const _>::cast(&p_descriptor_writes)),
+        Arg::new::<u32>(<*const _>::cast(&descriptor_copy_count)),
+        Arg::new::<*const vk::CopyDescriptorSet<'static>>(<*const _>::cast(&p_descriptor_copies)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    next(
+        device,
+        descriptor_write_count,
+        p_descriptor_writes,
+        descriptor_copy_count,
+        p_descriptor_copies,
+    );
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.after);
+}
+unsafe extern "system" fn update_video_session_parameters_khr(
+    device: vk::Device,
+    video_session_parameters: vk::VideoSessionParametersKHR,
+    p_update_info: *const vk::VideoSessionParametersUpdateInfoKHR<'_>,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkUpdateVideoSessionParametersKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkUpdateVideoSessionParametersKHR>(next(
+        555usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::VideoSessionParametersKHR>(<*const _>::cast(&video_session_parameters)),
+        Arg::new::<*const vk::VideoSessionParametersUpdateInfoKHR<'static>>(<*const _>::cast(
+            &p_update_info,
+        )),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, video_session_parameters, p_update_info);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn wait_for_fences(
+    device: vk::Device,
+    fence_count: u32,
+    p_fences: *const vk::Fence,
+    wait_all: vk::Bool32,
+    timeout: u64,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkWaitForFences\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkWaitForFences>(next(
+        556usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&fence_count)),
+        Arg::new::<*const vk::Fence>(<*const _>::cast(&p_fences)),
+        Arg::new::<vk::Bool32>(<*const _>::cast(&wait_all)),
+        Arg::new::<u64>(<*const _>::cast(&timeout)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, fence_count, p_fences, wait_all, timeout);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn wait_for_present_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    present_id: u64,
+    timeout: u64,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkWaitForPresentKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkWaitForPresentKHR>(next(
+        557usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<vk::SwapchainKHR>(<*const _>::cast(&swapchain)),
+        Arg::new::<u64>(<*const _>::cast(&present_id)),
+        Arg::new::<u64>(<*const _>::cast(&timeout)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, swapchain, present_id, timeout);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn wait_semaphores(
+    device: vk::Device,
+    p_wait_info: *const vk::SemaphoreWaitInfo<'_>,
+    timeout: u64,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkWaitSemaphores\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkWaitSemaphores>(next(
+        558usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<*const vk::SemaphoreWaitInfo<'static>>(<*const _>::cast(&p_wait_info)),
+        Arg::new::<u64>(<*const _>::cast(&timeout)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(device, p_wait_info, timeout);
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn write_acceleration_structures_properties_khr(
+    device: vk::Device,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureKHR,
+    query_type: vk::QueryType,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: usize,
+) -> vk::Result {
+    let command =
+        CStr::from_bytes_with_nul_unchecked(b"vkWriteAccelerationStructuresPropertiesKHR\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkWriteAccelerationStructuresPropertiesKHR>(
+        next(559usize, dispatch_key(device.as_raw())),
+    );
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&acceleration_structure_count)),
+        Arg::new::<*const vk::AccelerationStructureKHR>(<*const _>::cast(
+            &p_acceleration_structures,
+        )),
+        Arg::new::<vk::QueryType>(<*const _>::cast(&query_type)),
+        Arg::new::<usize>(<*const _>::cast(&data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+        Arg::new::<usize>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        data_size,
+        p_data,
+        stride,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
+unsafe extern "system" fn write_micromaps_properties_ext(
+    device: vk::Device,
+    micromap_count: u32,
+    p_micromaps: *const vk::MicromapEXT,
+    query_type: vk::QueryType,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: usize,
+) -> vk::Result {
+    let command = CStr::from_bytes_with_nul_unchecked(b"vkWriteMicromapsPropertiesEXT\0");
+    let next = mem::transmute::<*const c_void, vk::PFN_vkWriteMicromapsPropertiesEXT>(next(
+        560usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let args = [
+        Arg::new::<vk::Device>(<*const _>::cast(&device)),
+        Arg::new::<u32>(<*const _>::cast(&micromap_count)),
+        Arg::new::<*const vk::MicromapEXT>(<*const _>::cast(&p_micromaps)),
+        Arg::new::<vk::QueryType>(<*const _>::cast(&query_type)),
+        Arg::new::<usize>(<*const _>::cast(&data_size)),
+        Arg::new::<*mut c_void>(<*const _>::cast(&p_data)),
+        Arg::new::<usize>(<*const _>::cast(&stride)),
+    ];
+    run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+    let result = next(
+        device,
+        micromap_count,
+        p_micromaps,
+        query_type,
+        data_size,
+        p_data,
+        stride,
+    );
+    run_hook(
+        &Call::new(
+            command,
+            &args,
+            Some(Arg::new::<vk::Result>(<*const _>::cast(&result))),
+        ),
+        |hooks| &mut hooks.after,
+    );
+    result
+}
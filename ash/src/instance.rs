@@ -4,14 +4,24 @@ use crate::device::Device;
 use crate::prelude::*;
 use crate::vk;
 use crate::RawPtr;
+use std::ffi::{CStr, CString};
 use std::mem;
+use std::ops::Deref;
 use std::os::raw::c_char;
 use std::ptr;
 
+mod generated;
+
 /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkInstance.html>
+///
+/// The commands of every core version are loaded, but only those of Vulkan 1.0 can be called on
+/// an [`Instance`]. Those of later versions are called through views such as [`InstanceV1_1`],
+/// returned by [`Instance::v1_1()`] if the instance was created for that version.
 #[derive(Clone)]
 pub struct Instance {
     pub(crate) handle: vk::Instance,
+    pub(crate) api_version: u32,
+    pub(crate) enabled_extensions: Vec<CString>,
 
     pub(crate) instance_fn_1_0: vk::InstanceFnV1_0,
     pub(crate) instance_fn_1_1: vk::InstanceFnV1_1,
@@ -19,9 +29,30 @@ pub struct Instance {
 }
 
 impl Instance {
+    /// Loads an instance, assuming that it was created for Vulkan 1.0 with no extensions enabled.
+    ///
+    /// Like the `try_new()` constructors of extension wrappers, [`Instance::v1_1()`] and later
+    /// fail until the version that the instance was created for is recorded, see
+    /// [`Instance::load_with_version()`] and [`Instance::with_enabled_extensions()`].
     pub unsafe fn load(static_fn: &vk::StaticFn, instance: vk::Instance) -> Self {
-        let load_fn = |name: &std::ffi::CStr| {
-            mem::transmute((static_fn.get_instance_proc_addr)(instance, name.as_ptr()))
+        Self::load_with_version(static_fn, instance, vk::API_VERSION_1_0)
+    }
+
+    /// Loads an instance created with [`vk::ApplicationInfo::api_version`] set to
+    /// `api_version`.
+    ///
+    /// The `try_new()` constructors of extension wrappers fail until the extensions that the
+    /// instance was created with are recorded with [`Instance::with_enabled_extensions()`], which
+    /// [`Entry::create_instance()`] does.
+    pub unsafe fn load_with_version(
+        static_fn: &vk::StaticFn,
+        instance: vk::Instance,
+        api_version: u32,
+    ) -> Self {
+        let load_fn = |name: &CStr| {
+            resolve_command(name, instance, || {
+                mem::transmute((static_fn.get_instance_proc_addr)(instance, name.as_ptr()))
+            })
         };
 
         Self::from_parts_1_3(
@@ -30,8 +61,13 @@ impl Instance {
             vk::InstanceFnV1_1::load(load_fn),
             vk::InstanceFnV1_3::load(load_fn),
         )
+        .with_api_version(api_version)
     }
 
+    /// Builds an instance from its function tables, assuming that it was created for Vulkan 1.0
+    /// with no extensions enabled. Call [`Instance::with_api_version()`] and
+    /// [`Instance::with_enabled_extensions()`] to record the version and extensions it was
+    /// created with.
     #[inline]
     pub fn from_parts_1_3(
         handle: vk::Instance,
@@ -41,6 +77,8 @@ impl Instance {
     ) -> Self {
         Self {
             handle,
+            api_version: vk::API_VERSION_1_0,
+            enabled_extensions: Vec::new(),
 
             instance_fn_1_0,
             instance_fn_1_1,
@@ -52,6 +90,102 @@ impl Instance {
     pub fn handle(&self) -> vk::Instance {
         self.handle
     }
+
+    /// The Vulkan version that the instance was created for.
+    #[inline]
+    pub fn api_version(&self) -> u32 {
+        self.api_version
+    }
+
+    /// Records the Vulkan version that the instance was created for, for instances built with
+    /// [`Instance::from_parts_1_3()`].
+    #[inline]
+    pub fn with_api_version(mut self, api_version: u32) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Returns the commands of Vulkan 1.1 if the instance was created for that version or a later
+    /// one.
+    #[inline]
+    pub fn v1_1(&self) -> Result<&InstanceV1_1, ApiVersionError> {
+        require_version(self.api_version, vk::API_VERSION_1_1)?;
+        // SAFETY: `InstanceV1_1` is a `repr(transparent)` `Instance`
+        Ok(unsafe { &*<*const Self>::cast(self) })
+    }
+
+    /// Returns the commands of Vulkan 1.3 if the instance was created for that version or a later
+    /// one.
+    #[inline]
+    pub fn v1_3(&self) -> Result<&InstanceV1_3, ApiVersionError> {
+        require_version(self.api_version, vk::API_VERSION_1_3)?;
+        // SAFETY: `InstanceV1_3` is a `repr(transparent)` `Instance`
+        Ok(unsafe { &*<*const Self>::cast(self) })
+    }
+
+    /// Records the extensions that the instance was created with, for instances that were not
+    /// created through [`Entry::create_instance()`].
+    #[inline]
+    pub fn with_enabled_extensions(mut self, extension_names: &[&CStr]) -> Self {
+        self.enabled_extensions = extension_names.iter().map(|&n| n.to_owned()).collect();
+        self
+    }
+
+    /// The extensions from [`vk::InstanceCreateInfo::enabled_extension_names()`] that the
+    /// instance was created with.
+    #[inline]
+    pub fn enabled_extensions(&self) -> &[CString] {
+        &self.enabled_extensions
+    }
+
+    #[inline]
+    pub fn is_extension_enabled(&self, extension_name: &CStr) -> bool {
+        self.enabled_extensions
+            .iter()
+            .any(|enabled| enabled.as_c_str() == extension_name)
+    }
+
+    /// Used by the `try_new()` constructors of instance extension wrappers.
+    pub(crate) fn require_extension(
+        &self,
+        extension: &'static CStr,
+        depends: &vk::Depends,
+    ) -> Result<(), MissingExtensionError> {
+        require_extension(extension, depends, self.api_version, &|name| {
+            self.is_extension_enabled(name)
+        })
+    }
+}
+
+/// The commands of Vulkan 1.1, returned by [`Instance::v1_1()`] if the instance was created for
+/// that version. The commands of Vulkan 1.0 are available through [`Deref`].
+#[repr(transparent)]
+pub struct InstanceV1_1(Instance);
+
+impl Deref for InstanceV1_1 {
+    type Target = Instance;
+
+    #[inline]
+    fn deref(&self) -> &Instance {
+        &self.0
+    }
+}
+
+/// The commands of Vulkan 1.3, returned by [`Instance::v1_3()`] if the instance was created for
+/// that version. The commands of earlier versions are available through [`Deref`]; Vulkan 1.2
+/// added no instance-level commands.
+#[repr(transparent)]
+pub struct InstanceV1_3(Instance);
+
+impl Deref for InstanceV1_3 {
+    type Target = InstanceV1_1;
+
+    #[inline]
+    fn deref(&self) -> &InstanceV1_1 {
+        // SAFETY: `InstanceV1_1` is a `repr(transparent)` `Instance`, which was created for Vulkan
+        // 1.1 or later as it was created for Vulkan 1.3
+        unsafe { &*<*const Self>::cast(self) }
+    }
 }
 
 /// Vulkan core 1.3
@@ -60,7 +194,10 @@ impl Instance {
     pub fn fp_v1_3(&self) -> &vk::InstanceFnV1_3 {
         &self.instance_fn_1_3
     }
+}
 
+/// Vulkan core 1.3
+impl InstanceV1_3 {
     /// Retrieve the number of elements to pass to [`get_physical_device_tool_properties()`][Self::get_physical_device_tool_properties()]
     #[inline]
     pub unsafe fn get_physical_device_tool_properties_len(
@@ -104,7 +241,10 @@ impl Instance {
     pub fn fp_v1_1(&self) -> &vk::InstanceFnV1_1 {
         &self.instance_fn_1_1
     }
+}
 
+/// Vulkan core 1.1
+impl InstanceV1_1 {
     /// Retrieve the number of elements to pass to [`enumerate_physical_device_groups()`][Self::enumerate_physical_device_groups()]
     #[inline]
     pub unsafe fn enumerate_physical_device_groups_len(&self) -> VkResult<usize> {
@@ -136,196 +276,6 @@ impl Instance {
         assert_eq!(count as usize, out.len());
         Ok(())
     }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2.html>
-    #[inline]
-    pub unsafe fn get_physical_device_features2(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        features: &mut vk::PhysicalDeviceFeatures2,
-    ) {
-        (self.instance_fn_1_1.get_physical_device_features2)(physical_device, features);
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2.html>
-    #[inline]
-    pub unsafe fn get_physical_device_properties2(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        prop: &mut vk::PhysicalDeviceProperties2,
-    ) {
-        (self.instance_fn_1_1.get_physical_device_properties2)(physical_device, prop);
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2.html>
-    #[inline]
-    pub unsafe fn get_physical_device_format_properties2(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        format: vk::Format,
-        out: &mut vk::FormatProperties2,
-    ) {
-        (self.instance_fn_1_1.get_physical_device_format_properties2)(physical_device, format, out);
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2.html>
-    #[inline]
-    pub unsafe fn get_physical_device_image_format_properties2(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        format_info: &vk::PhysicalDeviceImageFormatInfo2,
-        image_format_prop: &mut vk::ImageFormatProperties2,
-    ) -> VkResult<()> {
-        (self
-            .instance_fn_1_1
-            .get_physical_device_image_format_properties2)(
-            physical_device,
-            format_info,
-            image_format_prop,
-        )
-        .result()
-    }
-
-    /// Retrieve the number of elements to pass to [`get_physical_device_queue_family_properties2()`][Self::get_physical_device_queue_family_properties2()]
-    #[inline]
-    pub unsafe fn get_physical_device_queue_family_properties2_len(
-        &self,
-        physical_device: vk::PhysicalDevice,
-    ) -> usize {
-        let mut queue_count = 0;
-        (self
-            .instance_fn_1_1
-            .get_physical_device_queue_family_properties2)(
-            physical_device,
-            &mut queue_count,
-            ptr::null_mut(),
-        );
-        queue_count as usize
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties2.html>
-    ///
-    /// Call [`get_physical_device_queue_family_properties2_len()`][Self::get_physical_device_queue_family_properties2_len()] to query the number of elements to pass to `out`.
-    /// Be sure to [`Default::default()`]-initialize these elements and optionally set their `p_next` pointer.
-    #[inline]
-    pub unsafe fn get_physical_device_queue_family_properties2(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        out: &mut [vk::QueueFamilyProperties2],
-    ) {
-        let mut count = out.len() as u32;
-        (self
-            .instance_fn_1_1
-            .get_physical_device_queue_family_properties2)(
-            physical_device,
-            &mut count,
-            out.as_mut_ptr(),
-        );
-        assert_eq!(count as usize, out.len());
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2.html>
-    #[inline]
-    pub unsafe fn get_physical_device_memory_properties2(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        out: &mut vk::PhysicalDeviceMemoryProperties2,
-    ) {
-        (self.instance_fn_1_1.get_physical_device_memory_properties2)(physical_device, out);
-    }
-
-    /// Retrieve the number of elements to pass to [`get_physical_device_sparse_image_format_properties2()`][Self::get_physical_device_sparse_image_format_properties2()]
-    #[inline]
-    pub unsafe fn get_physical_device_sparse_image_format_properties2_len(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        format_info: &vk::PhysicalDeviceSparseImageFormatInfo2,
-    ) -> usize {
-        let mut format_count = 0;
-        (self
-            .instance_fn_1_1
-            .get_physical_device_sparse_image_format_properties2)(
-            physical_device,
-            format_info,
-            &mut format_count,
-            ptr::null_mut(),
-        );
-        format_count as usize
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSparseImageFormatProperties2.html>
-    ///
-    /// Call [`get_physical_device_sparse_image_format_properties2_len()`][Self::get_physical_device_sparse_image_format_properties2_len()] to query the number of elements to pass to `out`.
-    /// Be sure to [`Default::default()`]-initialize these elements and optionally set their `p_next` pointer.
-    #[inline]
-    pub unsafe fn get_physical_device_sparse_image_format_properties2(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        format_info: &vk::PhysicalDeviceSparseImageFormatInfo2,
-        out: &mut [vk::SparseImageFormatProperties2],
-    ) {
-        let mut count = out.len() as u32;
-        (self
-            .instance_fn_1_1
-            .get_physical_device_sparse_image_format_properties2)(
-            physical_device,
-            format_info,
-            &mut count,
-            out.as_mut_ptr(),
-        );
-        assert_eq!(count as usize, out.len());
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalBufferProperties.html>
-    #[inline]
-    pub unsafe fn get_physical_device_external_buffer_properties(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        external_buffer_info: &vk::PhysicalDeviceExternalBufferInfo,
-        out: &mut vk::ExternalBufferProperties,
-    ) {
-        (self
-            .instance_fn_1_1
-            .get_physical_device_external_buffer_properties)(
-            physical_device,
-            external_buffer_info,
-            out,
-        );
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFenceProperties.html>
-    #[inline]
-    pub unsafe fn get_physical_device_external_fence_properties(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        external_fence_info: &vk::PhysicalDeviceExternalFenceInfo,
-        out: &mut vk::ExternalFenceProperties,
-    ) {
-        (self
-            .instance_fn_1_1
-            .get_physical_device_external_fence_properties)(
-            physical_device,
-            external_fence_info,
-            out,
-        );
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphoreProperties.html>
-    #[inline]
-    pub unsafe fn get_physical_device_external_semaphore_properties(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        external_semaphore_info: &vk::PhysicalDeviceExternalSemaphoreInfo,
-        out: &mut vk::ExternalSemaphoreProperties,
-    ) {
-        (self
-            .instance_fn_1_1
-            .get_physical_device_external_semaphore_properties)(
-            physical_device,
-            external_semaphore_info,
-            out,
-        );
-    }
 }
 
 /// Vulkan core 1.0
@@ -337,6 +287,10 @@ impl Instance {
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDevice.html>
     ///
+    /// The returned [`Device`] supports the lower of [`Instance::api_version()`] and the
+    /// `api_version` of `physical_device`, which is queried with an additional call to
+    /// [`Instance::get_physical_device_properties()`] after the device was created.
+    ///
     /// # Safety
     ///
     /// There is a [parent/child relation] between [`Instance`] and the resulting [`Device`].  The
@@ -364,7 +318,16 @@ impl Instance {
             &mut device,
         )
         .result()?;
-        Ok(Device::load(&self.instance_fn_1_0, device))
+        // Devices support the lower of the instance and physical device versions
+        let properties = self.get_physical_device_properties(physical_device);
+        let api_version = self.api_version.min(properties.api_version);
+        Ok(
+            Device::load_with_version(&self.instance_fn_1_0, device, api_version)
+                .with_enabled_extensions(&c_str_array(
+                    create_info.enabled_extension_count,
+                    create_info.pp_enabled_extension_names,
+                )),
+        )
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceProcAddr.html>
@@ -374,117 +337,9 @@ impl Instance {
         device: vk::Device,
         p_name: *const c_char,
     ) -> vk::PFN_vkVoidFunction {
-        (self.instance_fn_1_0.get_device_proc_addr)(device, p_name)
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyInstance.html>
-    #[inline]
-    pub unsafe fn destroy_instance(&self, allocation_callbacks: Option<&vk::AllocationCallbacks>) {
-        (self.instance_fn_1_0.destroy_instance)(self.handle(), allocation_callbacks.as_raw_ptr());
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties.html>
-    #[inline]
-    pub unsafe fn get_physical_device_format_properties(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        format: vk::Format,
-    ) -> vk::FormatProperties {
-        let mut format_prop = mem::zeroed();
-        (self.instance_fn_1_0.get_physical_device_format_properties)(
-            physical_device,
-            format,
-            &mut format_prop,
-        );
-        format_prop
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties.html>
-    #[inline]
-    pub unsafe fn get_physical_device_image_format_properties(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        format: vk::Format,
-        typ: vk::ImageType,
-        tiling: vk::ImageTiling,
-        usage: vk::ImageUsageFlags,
-        flags: vk::ImageCreateFlags,
-    ) -> VkResult<vk::ImageFormatProperties> {
-        let mut image_format_prop = mem::zeroed();
-        (self
-            .instance_fn_1_0
-            .get_physical_device_image_format_properties)(
-            physical_device,
-            format,
-            typ,
-            tiling,
-            usage,
-            flags,
-            &mut image_format_prop,
-        )
-        .result_with_success(image_format_prop)
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties.html>
-    #[inline]
-    pub unsafe fn get_physical_device_memory_properties(
-        &self,
-        physical_device: vk::PhysicalDevice,
-    ) -> vk::PhysicalDeviceMemoryProperties {
-        let mut memory_prop = mem::zeroed();
-        (self.instance_fn_1_0.get_physical_device_memory_properties)(
-            physical_device,
-            &mut memory_prop,
-        );
-        memory_prop
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties.html>
-    #[inline]
-    pub unsafe fn get_physical_device_properties(
-        &self,
-        physical_device: vk::PhysicalDevice,
-    ) -> vk::PhysicalDeviceProperties {
-        let mut prop = mem::zeroed();
-        (self.instance_fn_1_0.get_physical_device_properties)(physical_device, &mut prop);
-        prop
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties.html>
-    #[inline]
-    pub unsafe fn get_physical_device_queue_family_properties(
-        &self,
-        physical_device: vk::PhysicalDevice,
-    ) -> Vec<vk::QueueFamilyProperties> {
-        read_into_uninitialized_vector(|count, data| {
-            (self
-                .instance_fn_1_0
-                .get_physical_device_queue_family_properties)(
-                physical_device, count, data
-            );
-            vk::Result::SUCCESS
-        })
-        // The closure always returns SUCCESS
-        .unwrap()
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures.html>
-    #[inline]
-    pub unsafe fn get_physical_device_features(
-        &self,
-        physical_device: vk::PhysicalDevice,
-    ) -> vk::PhysicalDeviceFeatures {
-        let mut prop = mem::zeroed();
-        (self.instance_fn_1_0.get_physical_device_features)(physical_device, &mut prop);
-        prop
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDevices.html>
-    #[inline]
-    pub unsafe fn enumerate_physical_devices(&self) -> VkResult<Vec<vk::PhysicalDevice>> {
-        read_into_uninitialized_vector(|count, data| {
-            (self.instance_fn_1_0.enumerate_physical_devices)(self.handle(), count, data)
-        })
+        mem::transmute(resolve_command(CStr::from_ptr(p_name), device, || {
+            mem::transmute((self.instance_fn_1_0.get_device_proc_addr)(device, p_name))
+        }))
     }
 
     /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateDeviceExtensionProperties.html>
@@ -502,45 +357,4 @@ impl Instance {
             )
         })
     }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateDeviceLayerProperties.html>
-    #[inline]
-    pub unsafe fn enumerate_device_layer_properties(
-        &self,
-        device: vk::PhysicalDevice,
-    ) -> VkResult<Vec<vk::LayerProperties>> {
-        read_into_uninitialized_vector(|count, data| {
-            (self.instance_fn_1_0.enumerate_device_layer_properties)(device, count, data)
-        })
-    }
-
-    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSparseImageFormatProperties.html>
-    #[inline]
-    pub unsafe fn get_physical_device_sparse_image_format_properties(
-        &self,
-        physical_device: vk::PhysicalDevice,
-        format: vk::Format,
-        typ: vk::ImageType,
-        samples: vk::SampleCountFlags,
-        usage: vk::ImageUsageFlags,
-        tiling: vk::ImageTiling,
-    ) -> Vec<vk::SparseImageFormatProperties> {
-        read_into_uninitialized_vector(|count, data| {
-            (self
-                .instance_fn_1_0
-                .get_physical_device_sparse_image_format_properties)(
-                physical_device,
-                format,
-                typ,
-                samples,
-                usage,
-                tiling,
-                count,
-                data,
-            );
-            vk::Result::SUCCESS
-        })
-        // The closure always returns SUCCESS
-        .unwrap()
-    }
 }
@@ -0,0 +1,357 @@
+use super::{Instance, InstanceV1_1};
+use crate::prelude::*;
+use crate::vk;
+use crate::RawPtr;
+use std::mem;
+use std::ptr;
+#[doc = " Vulkan core 1.1"]
+impl InstanceV1_1 {
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_features2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        features: &mut vk::PhysicalDeviceFeatures2<'_>,
+    ) {
+        (self.instance_fn_1_1.get_physical_device_features2)(physical_device, features);
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        properties: &mut vk::PhysicalDeviceProperties2<'_>,
+    ) {
+        (self.instance_fn_1_1.get_physical_device_properties2)(physical_device, properties);
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_format_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        format: vk::Format,
+        format_properties: &mut vk::FormatProperties2<'_>,
+    ) {
+        (self.instance_fn_1_1.get_physical_device_format_properties2)(
+            physical_device,
+            format,
+            format_properties,
+        );
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_image_format_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        image_format_info: &vk::PhysicalDeviceImageFormatInfo2<'_>,
+        image_format_properties: &mut vk::ImageFormatProperties2<'_>,
+    ) -> VkResult<()> {
+        (self
+            .instance_fn_1_1
+            .get_physical_device_image_format_properties2)(
+            physical_device,
+            image_format_info,
+            image_format_properties,
+        )
+        .result()
+    }
+    #[doc = " Retrieve the number of elements to pass to [`get_physical_device_queue_family_properties2()`][Self::get_physical_device_queue_family_properties2()]"]
+    #[inline]
+    pub unsafe fn get_physical_device_queue_family_properties2_len(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> usize {
+        let mut count = mem::MaybeUninit::uninit();
+        (self
+            .instance_fn_1_1
+            .get_physical_device_queue_family_properties2)(
+            physical_device,
+            count.as_mut_ptr(),
+            ptr::null_mut(),
+        );
+        count.assume_init() as usize
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties2.html>"]
+    #[doc = r""]
+    #[doc = " Call [`get_physical_device_queue_family_properties2_len()`][Self::get_physical_device_queue_family_properties2_len()] to query the number of elements to pass to `queue_family_properties`."]
+    #[doc = r" Be sure to [`Default::default()`]-initialize these elements and optionally set their `p_next` pointer."]
+    #[inline]
+    pub unsafe fn get_physical_device_queue_family_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        queue_family_properties: &mut [vk::QueueFamilyProperties2<'_>],
+    ) {
+        let mut count = queue_family_properties.len() as u32;
+        (self
+            .instance_fn_1_1
+            .get_physical_device_queue_family_properties2)(
+            physical_device,
+            &mut count,
+            queue_family_properties.as_mut_ptr(),
+        );
+        assert_eq!(count as usize, queue_family_properties.len());
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_memory_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        memory_properties: &mut vk::PhysicalDeviceMemoryProperties2<'_>,
+    ) {
+        (self.instance_fn_1_1.get_physical_device_memory_properties2)(
+            physical_device,
+            memory_properties,
+        );
+    }
+    #[doc = " Retrieve the number of elements to pass to [`get_physical_device_sparse_image_format_properties2()`][Self::get_physical_device_sparse_image_format_properties2()]"]
+    #[inline]
+    pub unsafe fn get_physical_device_sparse_image_format_properties2_len(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        format_info: &vk::PhysicalDeviceSparseImageFormatInfo2<'_>,
+    ) -> usize {
+        let mut count = mem::MaybeUninit::uninit();
+        (self
+            .instance_fn_1_1
+            .get_physical_device_sparse_image_format_properties2)(
+            physical_device,
+            format_info,
+            count.as_mut_ptr(),
+            ptr::null_mut(),
+        );
+        count.assume_init() as usize
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSparseImageFormatProperties2.html>"]
+    #[doc = r""]
+    #[doc = " Call [`get_physical_device_sparse_image_format_properties2_len()`][Self::get_physical_device_sparse_image_format_properties2_len()] to query the number of elements to pass to `properties`."]
+    #[doc = r" Be sure to [`Default::default()`]-initialize these elements and optionally set their `p_next` pointer."]
+    #[inline]
+    pub unsafe fn get_physical_device_sparse_image_format_properties2(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        format_info: &vk::PhysicalDeviceSparseImageFormatInfo2<'_>,
+        properties: &mut [vk::SparseImageFormatProperties2<'_>],
+    ) {
+        let mut count = properties.len() as u32;
+        (self
+            .instance_fn_1_1
+            .get_physical_device_sparse_image_format_properties2)(
+            physical_device,
+            format_info,
+            &mut count,
+            properties.as_mut_ptr(),
+        );
+        assert_eq!(count as usize, properties.len());
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalBufferProperties.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_external_buffer_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        external_buffer_info: &vk::PhysicalDeviceExternalBufferInfo<'_>,
+        external_buffer_properties: &mut vk::ExternalBufferProperties<'_>,
+    ) {
+        (self
+            .instance_fn_1_1
+            .get_physical_device_external_buffer_properties)(
+            physical_device,
+            external_buffer_info,
+            external_buffer_properties,
+        );
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFenceProperties.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_external_fence_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        external_fence_info: &vk::PhysicalDeviceExternalFenceInfo<'_>,
+        external_fence_properties: &mut vk::ExternalFenceProperties<'_>,
+    ) {
+        (self
+            .instance_fn_1_1
+            .get_physical_device_external_fence_properties)(
+            physical_device,
+            external_fence_info,
+            external_fence_properties,
+        );
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphoreProperties.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_external_semaphore_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        external_semaphore_info: &vk::PhysicalDeviceExternalSemaphoreInfo<'_>,
+        external_semaphore_properties: &mut vk::ExternalSemaphoreProperties<'_>,
+    ) {
+        (self
+            .instance_fn_1_1
+            .get_physical_device_external_semaphore_properties)(
+            physical_device,
+            external_semaphore_info,
+            external_semaphore_properties,
+        );
+    }
+}
+#[doc = " Vulkan core 1.0"]
+impl Instance {
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyInstance.html>"]
+    #[inline]
+    pub unsafe fn destroy_instance(&self, allocator: Option<&vk::AllocationCallbacks>) {
+        (self.instance_fn_1_0.destroy_instance)(self.handle, allocator.as_raw_ptr());
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDevices.html>"]
+    #[inline]
+    pub unsafe fn enumerate_physical_devices(&self) -> VkResult<Vec<vk::PhysicalDevice>> {
+        read_into_uninitialized_vector(|count, data| {
+            (self.instance_fn_1_0.enumerate_physical_devices)(self.handle, count, data)
+        })
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_features(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> vk::PhysicalDeviceFeatures {
+        let mut features: vk::PhysicalDeviceFeatures = mem::zeroed();
+        (self.instance_fn_1_0.get_physical_device_features)(physical_device, &mut features);
+        features
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_format_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        format: vk::Format,
+    ) -> vk::FormatProperties {
+        let mut format_properties: vk::FormatProperties = mem::zeroed();
+        (self.instance_fn_1_0.get_physical_device_format_properties)(
+            physical_device,
+            format,
+            &mut format_properties,
+        );
+        format_properties
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_image_format_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        format: vk::Format,
+        ty: vk::ImageType,
+        tiling: vk::ImageTiling,
+        usage: vk::ImageUsageFlags,
+        flags: vk::ImageCreateFlags,
+    ) -> VkResult<vk::ImageFormatProperties> {
+        let mut image_format_properties: vk::ImageFormatProperties = mem::zeroed();
+        (self
+            .instance_fn_1_0
+            .get_physical_device_image_format_properties)(
+            physical_device,
+            format,
+            ty,
+            tiling,
+            usage,
+            flags,
+            &mut image_format_properties,
+        )
+        .result_with_success(image_format_properties)
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> vk::PhysicalDeviceProperties {
+        let mut properties: vk::PhysicalDeviceProperties = mem::zeroed();
+        (self.instance_fn_1_0.get_physical_device_properties)(physical_device, &mut properties);
+        properties
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_queue_family_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> Vec<vk::QueueFamilyProperties> {
+        let mut count = 0;
+        (self
+            .instance_fn_1_0
+            .get_physical_device_queue_family_properties)(
+            physical_device,
+            &mut count,
+            ptr::null_mut(),
+        );
+        let mut data = Vec::with_capacity(count as usize);
+        (self
+            .instance_fn_1_0
+            .get_physical_device_queue_family_properties)(
+            physical_device,
+            &mut count,
+            data.as_mut_ptr(),
+        );
+        data.set_len(count as usize);
+        data
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_memory_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> vk::PhysicalDeviceMemoryProperties {
+        let mut memory_properties: vk::PhysicalDeviceMemoryProperties = mem::zeroed();
+        (self.instance_fn_1_0.get_physical_device_memory_properties)(
+            physical_device,
+            &mut memory_properties,
+        );
+        memory_properties
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateDeviceLayerProperties.html>"]
+    #[inline]
+    pub unsafe fn enumerate_device_layer_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+    ) -> VkResult<Vec<vk::LayerProperties>> {
+        read_into_uninitialized_vector(|count, data| {
+            (self.instance_fn_1_0.enumerate_device_layer_properties)(physical_device, count, data)
+        })
+    }
+    #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSparseImageFormatProperties.html>"]
+    #[inline]
+    pub unsafe fn get_physical_device_sparse_image_format_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        format: vk::Format,
+        ty: vk::ImageType,
+        samples: vk::SampleCountFlags,
+        usage: vk::ImageUsageFlags,
+        tiling: vk::ImageTiling,
+    ) -> Vec<vk::SparseImageFormatProperties> {
+        let mut count = 0;
+        (self
+            .instance_fn_1_0
+            .get_physical_device_sparse_image_format_properties)(
+            physical_device,
+            format,
+            ty,
+            samples,
+            usage,
+            tiling,
+            &mut count,
+            ptr::null_mut(),
+        );
+        let mut data = Vec::with_capacity(count as usize);
+        (self
+            .instance_fn_1_0
+            .get_physical_device_sparse_image_format_properties)(
+            physical_device,
+            format,
+            ty,
+            samples,
+            usage,
+            tiling,
+            &mut count,
+            data.as_mut_ptr(),
+        );
+        data.set_len(count as usize);
+        data
+    }
+}
@@ -0,0 +1,542 @@
+//! Vulkan layers written in Rust, built as a `cdylib` and loaded by the Vulkan loader through a
+//! layer manifest.
+//!
+//! A layer implements [`Layer`] to return its implementation of the commands it intercepts, and
+//! exports the loader interface with [`export_layer!`][crate::export_layer!]. This module handles
+//! `vkCreateInstance`, `vkCreateDevice`, their `Destroy` counterparts and the `GetProcAddr`
+//! commands: it follows the [`LayerInstanceCreateInfo`] and [`LayerDeviceCreateInfo`] chain links
+//! of the loader and loads an [`Instance`] and a [`Device`] from the next layer, which intercepted
+//! commands retrieve with [`instance()`] and [`device()`] to call down the chain.
+//!
+//! Panics in the commands handled by this module, including those of [`Layer::intercept()`], are
+//! caught before reaching the loader and turned into [`vk::Result::ERROR_UNKNOWN`]. The commands
+//! returned by [`Layer::intercept()`] are `extern "system"` functions as well, which must not
+//! unwind either.
+//!
+//! ```no_run
+//! use ash::{layer, vk};
+//! use std::ffi::CStr;
+//!
+//! struct Logger;
+//!
+//! unsafe extern "system" fn queue_submit(
+//!     queue: vk::Queue,
+//!     submit_count: u32,
+//!     p_submits: *const vk::SubmitInfo<'_>,
+//!     fence: vk::Fence,
+//! ) -> vk::Result {
+//!     println!("vkQueueSubmit with {submit_count} batches");
+//!     let device = layer::device(queue).unwrap();
+//!     (device.fp_v1_0().queue_submit)(queue, submit_count, p_submits, fence)
+//! }
+//!
+//! impl layer::Layer for Logger {
+//!     fn intercept(name: &CStr) -> vk::PFN_vkVoidFunction {
+//!         match name.to_bytes() {
+//!             b"vkQueueSubmit" => Some(unsafe {
+//!                 std::mem::transmute(queue_submit as vk::PFN_vkQueueSubmit)
+//!             }),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//!
+//! ash::export_layer!(Logger);
+//! ```
+//!
+//! See the [loader and layer interface] for the manifest that makes the loader find the layer.
+//!
+//! [loader and layer interface]: https://github.com/KhronosGroup/Vulkan-Loader/blob/main/docs/LoaderLayerInterface.md
+
+use crate::prelude::*;
+use crate::vk::{self, Handle};
+use crate::{Device, Instance};
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr};
+use std::mem;
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// The loader and layer interface version implemented by this module.
+pub const CURRENT_LOADER_LAYER_INTERFACE_VERSION: u32 = 2;
+
+#[allow(non_camel_case_types)]
+pub type PFN_GetPhysicalDeviceProcAddr = unsafe extern "system" fn(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction;
+#[allow(non_camel_case_types)]
+pub type PFN_vkSetInstanceLoaderData =
+    unsafe extern "system" fn(instance: vk::Instance, object: *mut c_void) -> vk::Result;
+#[allow(non_camel_case_types)]
+pub type PFN_vkSetDeviceLoaderData =
+    unsafe extern "system" fn(device: vk::Device, object: *mut c_void) -> vk::Result;
+#[allow(non_camel_case_types)]
+pub type PFN_vkLayerCreateDevice = unsafe extern "system" fn(
+    instance: vk::Instance,
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_device: *mut vk::Device,
+    layer_gipa: vk::PFN_vkGetInstanceProcAddr,
+    next_gdpa: *mut vk::PFN_vkGetDeviceProcAddr,
+) -> vk::Result;
+#[allow(non_camel_case_types)]
+pub type PFN_vkLayerDestroyDevice = unsafe extern "system" fn(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    destroy_function: vk::PFN_vkDestroyDevice,
+);
+
+/// `VkLayerFunction`, the kind of a [`LayerInstanceCreateInfo`] or [`LayerDeviceCreateInfo`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[repr(transparent)]
+pub struct LayerFunction(pub(crate) i32);
+
+impl LayerFunction {
+    #[inline]
+    pub const fn from_raw(x: i32) -> Self {
+        Self(x)
+    }
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+
+    pub const LAYER_LINK_INFO: Self = Self(0);
+    pub const LOADER_DATA_CALLBACK: Self = Self(1);
+    pub const LOADER_LAYER_CREATE_DEVICE_CALLBACK: Self = Self(2);
+    pub const LOADER_FEATURES: Self = Self(3);
+}
+
+/// `VkLayerInstanceLink`, the next layer in the instance chain.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LayerInstanceLink {
+    pub p_next: *mut Self,
+    pub pfn_next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    pub pfn_next_get_physical_device_proc_addr: Option<PFN_GetPhysicalDeviceProcAddr>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LayerDeviceCallbacks {
+    pub pfn_layer_create_device: Option<PFN_vkLayerCreateDevice>,
+    pub pfn_layer_destroy_device: Option<PFN_vkLayerDestroyDevice>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union LayerInstanceCreateInfoU {
+    pub p_layer_info: *mut LayerInstanceLink,
+    pub pfn_set_instance_loader_data: Option<PFN_vkSetInstanceLoaderData>,
+    pub layer_device: LayerDeviceCallbacks,
+    pub loader_features: u32,
+}
+
+/// `VkLayerInstanceCreateInfo`, which the loader chains to the [`vk::InstanceCreateInfo`] passed
+/// to layers with [`vk::StructureType::LOADER_INSTANCE_CREATE_INFO`].
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LayerInstanceCreateInfo {
+    pub s_type: vk::StructureType,
+    pub p_next: *const c_void,
+    pub function: LayerFunction,
+    pub u: LayerInstanceCreateInfoU,
+}
+
+/// `VkLayerDeviceLink`, the next layer in the device chain.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LayerDeviceLink {
+    pub p_next: *mut Self,
+    pub pfn_next_get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    pub pfn_next_get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union LayerDeviceCreateInfoU {
+    pub p_layer_info: *mut LayerDeviceLink,
+    pub pfn_set_device_loader_data: Option<PFN_vkSetDeviceLoaderData>,
+}
+
+/// `VkLayerDeviceCreateInfo`, which the loader chains to the [`vk::DeviceCreateInfo`] passed to
+/// layers with [`vk::StructureType::LOADER_DEVICE_CREATE_INFO`].
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LayerDeviceCreateInfo {
+    pub s_type: vk::StructureType,
+    pub p_next: *const c_void,
+    pub function: LayerFunction,
+    pub u: LayerDeviceCreateInfoU,
+}
+
+/// `VkNegotiateLayerStructType`
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[repr(transparent)]
+pub struct NegotiateLayerStructType(pub(crate) i32);
+
+impl NegotiateLayerStructType {
+    #[inline]
+    pub const fn from_raw(x: i32) -> Self {
+        Self(x)
+    }
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+
+    pub const UNINTIALIZED: Self = Self(0);
+    pub const INTERFACE_STRUCT: Self = Self(1);
+}
+
+/// `VkNegotiateLayerInterface`, passed by the loader to `vkNegotiateLoaderLayerInterfaceVersion`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct NegotiateLayerInterface {
+    pub s_type: NegotiateLayerStructType,
+    pub p_next: *mut c_void,
+    pub loader_layer_interface_version: u32,
+    pub pfn_get_instance_proc_addr: Option<vk::PFN_vkGetInstanceProcAddr>,
+    pub pfn_get_device_proc_addr: Option<vk::PFN_vkGetDeviceProcAddr>,
+    pub pfn_get_physical_device_proc_addr: Option<PFN_GetPhysicalDeviceProcAddr>,
+}
+
+/// The commands implemented by a layer.
+pub trait Layer: 'static {
+    /// Returns the implementation of the instance- or device-level command `name`, which is
+    /// called instead of the one of the next layer, or `None` to pass the command through.
+    ///
+    /// The commands handled by this module, such as `vkCreateDevice`, cannot be intercepted.
+    fn intercept(name: &CStr) -> vk::PFN_vkVoidFunction;
+}
+
+/// Exports `vkNegotiateLoaderLayerInterfaceVersion` for a type implementing
+/// [`layer::Layer`][crate::layer::Layer], from the `cdylib` crate of the layer.
+#[macro_export]
+macro_rules! export_layer {
+    ($layer:ty) => {
+        #[no_mangle]
+        pub unsafe extern "system" fn vkNegotiateLoaderLayerInterfaceVersion(
+            p_version_struct: *mut $crate::layer::NegotiateLayerInterface,
+        ) -> $crate::vk::Result {
+            $crate::layer::negotiate_loader_layer_interface_version::<$layer>(p_version_struct)
+        }
+    };
+}
+
+/// The instance or device of the next layer, and its `GetProcAddr` command for commands that
+/// are not loaded into the tables of `ash`.
+struct Next<T, F> {
+    object: Arc<T>,
+    get_proc_addr: F,
+}
+
+#[derive(Default)]
+struct Tables {
+    instances: HashMap<usize, Next<Instance, vk::PFN_vkGetInstanceProcAddr>>,
+    devices: HashMap<usize, Next<Device, vk::PFN_vkGetDeviceProcAddr>>,
+}
+
+/// The instances and devices by dispatch key, allocated on first use and never freed.
+static TABLES: AtomicPtr<Mutex<Tables>> = AtomicPtr::new(ptr::null_mut());
+
+fn tables() -> MutexGuard<'static, Tables> {
+    let mut tables = TABLES.load(Ordering::Acquire);
+    if tables.is_null() {
+        let new = Box::into_raw(Box::default());
+        tables = match TABLES.compare_exchange(
+            ptr::null_mut(),
+            new,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => new,
+            Err(existing) => {
+                drop(unsafe { Box::from_raw(new) });
+                existing
+            }
+        };
+    }
+    let tables = unsafe { &*tables };
+    tables
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Returns the instance of the next layer for `handle`, a [`vk::Instance`] or
+/// [`vk::PhysicalDevice`].
+///
+/// # Safety
+///
+/// `handle` must be a valid dispatchable handle.
+pub unsafe fn instance(handle: impl Handle) -> Option<Arc<Instance>> {
+    let key = dispatch_key(handle.as_raw());
+    tables().instances.get(&key).map(|next| next.object.clone())
+}
+
+/// Returns the device of the next layer for `handle`, a [`vk::Device`], [`vk::Queue`] or
+/// [`vk::CommandBuffer`].
+///
+/// # Safety
+///
+/// `handle` must be a valid dispatchable handle.
+pub unsafe fn device(handle: impl Handle) -> Option<Arc<Device>> {
+    let key = dispatch_key(handle.as_raw());
+    tables().devices.get(&key).map(|next| next.object.clone())
+}
+
+/// Implements `vkNegotiateLoaderLayerInterfaceVersion` for `L`, see
+/// [`export_layer!`][crate::export_layer!].
+pub unsafe extern "system" fn negotiate_loader_layer_interface_version<L: Layer>(
+    p_version_struct: *mut NegotiateLayerInterface,
+) -> vk::Result {
+    catch_unwind(vk::Result::ERROR_UNKNOWN, || {
+        let version_struct = match p_version_struct.as_mut() {
+            Some(v) if v.s_type == NegotiateLayerStructType::INTERFACE_STRUCT => v,
+            _ => return vk::Result::ERROR_INITIALIZATION_FAILED,
+        };
+        // Version 1 loaders call exported vkGetInstanceProcAddr and vkGetDeviceProcAddr instead
+        if version_struct.loader_layer_interface_version < 2 {
+            return vk::Result::ERROR_INITIALIZATION_FAILED;
+        }
+        version_struct.loader_layer_interface_version = version_struct
+            .loader_layer_interface_version
+            .min(CURRENT_LOADER_LAYER_INTERFACE_VERSION);
+        version_struct.pfn_get_instance_proc_addr = Some(get_instance_proc_addr::<L>);
+        version_struct.pfn_get_device_proc_addr = Some(get_device_proc_addr::<L>);
+        version_struct.pfn_get_physical_device_proc_addr = None;
+        vk::Result::SUCCESS
+    })
+}
+
+/// Runs `f`, the body of a command called by the loader or the previous layer, returning
+/// `panicked` if it panics as it cannot unwind across `extern "system"` functions.
+fn catch_unwind<R>(panicked: R, f: impl FnOnce() -> R) -> R {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or(panicked)
+}
+
+/// The commands implemented by this module.
+fn layer_command<L: Layer>(name: &CStr) -> vk::PFN_vkVoidFunction {
+    let f = match name.to_bytes() {
+        b"vkGetInstanceProcAddr" => get_instance_proc_addr::<L> as *const c_void,
+        b"vkGetDeviceProcAddr" => get_device_proc_addr::<L> as *const c_void,
+        b"vkCreateInstance" => create_instance as *const c_void,
+        b"vkDestroyInstance" => destroy_instance as *const c_void,
+        b"vkCreateDevice" => create_device as *const c_void,
+        b"vkDestroyDevice" => destroy_device as *const c_void,
+        _ => return None,
+    };
+    Some(unsafe { mem::transmute::<*const c_void, unsafe extern "system" fn()>(f) })
+}
+
+unsafe extern "system" fn get_instance_proc_addr<L: Layer>(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    catch_unwind(None, || {
+        let name = CStr::from_ptr(p_name);
+        if let Some(f) = layer_command::<L>(name).or_else(|| L::intercept(name)) {
+            return Some(f);
+        }
+        if instance == vk::Instance::null() {
+            return None;
+        }
+        let next = tables()
+            .instances
+            .get(&dispatch_key(instance.as_raw()))
+            .map(|next| next.get_proc_addr)?;
+        next(instance, p_name)
+    })
+}
+
+unsafe extern "system" fn get_device_proc_addr<L: Layer>(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    catch_unwind(None, || {
+        let name = CStr::from_ptr(p_name);
+        let f = match name.to_bytes() {
+            b"vkGetDeviceProcAddr" | b"vkDestroyDevice" => layer_command::<L>(name),
+            _ => L::intercept(name),
+        };
+        if f.is_some() {
+            return f;
+        }
+        let next = tables()
+            .devices
+            .get(&dispatch_key(device.as_raw()))
+            .map(|next| next.get_proc_addr)?;
+        next(device, p_name)
+    })
+}
+
+/// Finds the [`LayerInstanceCreateInfo`] or [`LayerDeviceCreateInfo`] of type `s_type` with the
+/// chain link of the next layer.
+unsafe fn find_link_info<T>(mut p_next: *const c_void, s_type: vk::StructureType) -> *mut T {
+    while let Some(base) = p_next.cast::<vk::BaseInStructure<'_>>().as_ref() {
+        // Both structs start with the members of `LayerInstanceCreateInfo`
+        let info = p_next.cast::<LayerInstanceCreateInfo>();
+        if base.s_type == s_type && (*info).function == LayerFunction::LAYER_LINK_INFO {
+            return p_next as *mut T;
+        }
+        p_next = base.p_next.cast();
+    }
+    ptr::null_mut()
+}
+
+unsafe extern "system" fn create_instance(
+    p_create_info: *const vk::InstanceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    catch_unwind(vk::Result::ERROR_UNKNOWN, || {
+        let create_info = &*p_create_info;
+        let link_info = find_link_info::<LayerInstanceCreateInfo>(
+            create_info.p_next,
+            vk::StructureType::LOADER_INSTANCE_CREATE_INFO,
+        );
+        let link = match link_info.as_mut() {
+            Some(link_info) if !link_info.u.p_layer_info.is_null() => {
+                let link = *link_info.u.p_layer_info;
+                // Move the chain forward for the next layer
+                link_info.u.p_layer_info = link.p_next;
+                link
+            }
+            _ => return vk::Result::ERROR_INITIALIZATION_FAILED,
+        };
+        let get_instance_proc_addr = link.pfn_next_get_instance_proc_addr;
+        let create_instance = match get_instance_proc_addr(
+            vk::Instance::null(),
+            b"vkCreateInstance\0".as_ptr().cast(),
+        ) {
+            Some(f) => mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateInstance>(f),
+            None => return vk::Result::ERROR_INITIALIZATION_FAILED,
+        };
+        let result = create_instance(p_create_info, p_allocator, p_instance);
+        if result != vk::Result::SUCCESS {
+            return result;
+        }
+
+        let api_version = create_info
+            .p_application_info
+            .as_ref()
+            .map_or(0, |application_info| application_info.api_version);
+        let instance = Instance::load_with_version(
+            &vk::StaticFn {
+                get_instance_proc_addr,
+            },
+            *p_instance,
+            api_version.max(vk::API_VERSION_1_0),
+        )
+        .with_enabled_extensions(&c_str_array(
+            create_info.enabled_extension_count,
+            create_info.pp_enabled_extension_names,
+        ));
+        tables().instances.insert(
+            dispatch_key((*p_instance).as_raw()),
+            Next {
+                object: Arc::new(instance),
+                get_proc_addr: get_instance_proc_addr,
+            },
+        );
+        vk::Result::SUCCESS
+    })
+}
+
+unsafe extern "system" fn destroy_instance(
+    instance: vk::Instance,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    catch_unwind((), || {
+        if instance == vk::Instance::null() {
+            return;
+        }
+        let next = tables().instances.remove(&dispatch_key(instance.as_raw()));
+        if let Some(next) = next {
+            next.object.destroy_instance(p_allocator.as_ref());
+        }
+    })
+}
+
+unsafe extern "system" fn create_device(
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    catch_unwind(vk::Result::ERROR_UNKNOWN, || {
+        let create_info = &*p_create_info;
+        let link_info = find_link_info::<LayerDeviceCreateInfo>(
+            create_info.p_next,
+            vk::StructureType::LOADER_DEVICE_CREATE_INFO,
+        );
+        let link = match link_info.as_mut() {
+            Some(link_info) if !link_info.u.p_layer_info.is_null() => {
+                let link = *link_info.u.p_layer_info;
+                // Move the chain forward for the next layer
+                link_info.u.p_layer_info = link.p_next;
+                link
+            }
+            _ => return vk::Result::ERROR_INITIALIZATION_FAILED,
+        };
+        let instance = match instance(physical_device) {
+            Some(instance) => instance,
+            None => return vk::Result::ERROR_INITIALIZATION_FAILED,
+        };
+        let create_device = match (link.pfn_next_get_instance_proc_addr)(
+            instance.handle(),
+            b"vkCreateDevice\0".as_ptr().cast(),
+        ) {
+            Some(f) => mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateDevice>(f),
+            None => return vk::Result::ERROR_INITIALIZATION_FAILED,
+        };
+        let result = create_device(physical_device, p_create_info, p_allocator, p_device);
+        if result != vk::Result::SUCCESS {
+            return result;
+        }
+
+        // Devices support the lower of the instance and physical device versions
+        let properties = instance.get_physical_device_properties(physical_device);
+        let get_device_proc_addr = link.pfn_next_get_device_proc_addr;
+        let instance_fn = vk::InstanceFnV1_0 {
+            get_device_proc_addr,
+            ..instance.fp_v1_0().clone()
+        };
+        let device = Device::load_with_version(
+            &instance_fn,
+            *p_device,
+            instance.api_version().min(properties.api_version),
+        )
+        .with_enabled_extensions(&c_str_array(
+            create_info.enabled_extension_count,
+            create_info.pp_enabled_extension_names,
+        ));
+        tables().devices.insert(
+            dispatch_key((*p_device).as_raw()),
+            Next {
+                object: Arc::new(device),
+                get_proc_addr: get_device_proc_addr,
+            },
+        );
+        vk::Result::SUCCESS
+    })
+}
+
+unsafe extern "system" fn destroy_device(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    catch_unwind((), || {
+        if device == vk::Device::null() {
+            return;
+        }
+        let next = tables().devices.remove(&dispatch_key(device.as_raw()));
+        if let Some(next) = next {
+            next.object.destroy_device(p_allocator.as_ref());
+        }
+    })
+}
@@ -39,17 +39,69 @@
 //! * **debug** (default): Whether Vulkan structs should implement `Debug`.
 //! * **loaded** (default): Support searching for the Vulkan loader manually at runtime.
 //! * **linked**: Link the Vulkan loader at compile time.
+//! * **deep_copy**: Owned copies of Vulkan structs and their `p_next` chains, see [`deep_copy`].
+//! * **features**: Query, compare and enable every struct of device features together, see
+//!   [`features`].
+//! * **hooks**: Intercept the commands loaded by [`Entry`], [`Instance`], [`Device`] and extension
+//!   wrappers, see [`hooks`].
+//! * **layer**: Write Vulkan layers on top of [`Instance`] and [`Device`], see [`layer`].
+//! * **mock**: A software Vulkan driver for testing without a GPU, see [`mock`].
+//! * **owned**: Handles that destroy themselves through their [`Device`] or extension wrapper when
+//!   dropped, see [`owned`].
+//! * **properties**: Snapshots of every struct of physical device properties, compared with
+//!   `Debug`, see [`properties`]. Enables **debug**.
+//! * **serde**: `Serialize` and `Deserialize` for enums, flags and structs, through
+//!   [`deep_copy::DeepCopy`] for structs that point to other data, and for
+//!   `properties::Properties` with the **properties** feature.
+//! * **trace**: Record the commands called through [`Device`] and replay them, see [`trace`].
+//! * **track**: Track the lifetime of every handle to report leaks and uses of destroyed handles,
+//!   see [`track`]. Requires Rust 1.65.
 
-pub use crate::device::Device;
+pub use crate::device::{Device, DeviceV1_1, DeviceV1_2, DeviceV1_3};
 pub use crate::entry::Entry;
 #[cfg(feature = "loaded")]
-pub use crate::entry::LoadingError;
-pub use crate::instance::Instance;
+pub use crate::entry::{LoadAttempt, LoadingError};
+pub use crate::instance::{Instance, InstanceV1_1, InstanceV1_3};
 
+#[cfg(feature = "deep_copy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "deep_copy")))]
+pub mod deep_copy;
 mod device;
 mod entry;
+#[cfg(feature = "features")]
+#[cfg_attr(docsrs, doc(cfg(feature = "features")))]
+pub mod features;
+#[cfg(feature = "hooks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hooks")))]
+pub mod hooks;
 mod instance;
+#[cfg(feature = "layer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "layer")))]
+pub mod layer;
+#[cfg(feature = "loaded")]
+#[cfg_attr(docsrs, doc(cfg(feature = "loaded")))]
+pub mod manifest;
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub mod mock;
+#[cfg(feature = "owned")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owned")))]
+pub mod owned;
 pub mod prelude;
+#[cfg(feature = "properties")]
+#[cfg_attr(docsrs, doc(cfg(feature = "properties")))]
+pub mod properties;
+#[cfg(feature = "serde")]
+mod serde;
+/// Success codes of commands that can succeed in more than one way, generated from `vk.xml`
+pub mod success_codes;
+#[cfg(feature = "trace")]
+#[cfg_attr(docsrs, doc(cfg(feature = "trace")))]
+pub mod trace;
+#[cfg(feature = "track")]
+#[cfg_attr(docsrs, doc(cfg(feature = "track")))]
+#[clippy::msrv = "1.65"]
+pub mod track;
 pub mod util;
 /// Raw Vulkan bindings and types, generated from `vk.xml`
 #[macro_use]
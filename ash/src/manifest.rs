@@ -0,0 +1,804 @@
+//! The JSON manifests through which the Vulkan loader finds drivers and layers, to load a driver
+//! with [`Entry::load_icd()`] or find out why a layer is not reported by
+//! [`Entry::enumerate_instance_layer_properties()`].
+//!
+//! ```no_run
+//! use ash::manifest::{correlate_layers, SearchPaths};
+//! # unsafe fn list(entry: &ash::Entry) -> ash::prelude::VkResult<()> {
+//! let search = SearchPaths::from_env();
+//! for driver in search.drivers() {
+//!     println!("{}: {:?}", driver.path.display(), driver.manifest);
+//! }
+//! let layers = search.layers();
+//! let reported = entry.enumerate_instance_layer_properties()?;
+//! for status in correlate_layers(&layers, &reported, |var| std::env::var_os(var)) {
+//!     if !status.reported {
+//!         println!("{} is not available: {:?}", status.manifest.name, status.problems);
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::vk;
+#[cfg(doc)]
+use crate::Entry;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::ffi::{CStr, OsString};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An error reading a manifest.
+#[derive(Debug)]
+pub enum ManifestError {
+    Io(io::Error),
+    /// The manifest is not valid JSON at the given byte offset.
+    Syntax {
+        offset: usize,
+        message: &'static str,
+    },
+    /// A required member is missing or has the wrong type, such as `"ICD.library_path"`.
+    InvalidMember(&'static str),
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => fmt::Display::fmt(err, f),
+            Self::Syntax { offset, message } => write!(f, "{message} at byte {offset}"),
+            Self::InvalidMember(member) => write!(f, "Missing or invalid member `{member}`"),
+        }
+    }
+}
+
+impl Error for ManifestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ManifestError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// The manifest of an installable client driver, as installed by drivers such as lavapipe or
+/// SwiftShader.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcdManifest {
+    pub file_format_version: String,
+    /// The driver library, resolved against the directory of the manifest when it is a relative
+    /// path. A bare file name is searched for by the dynamic linker.
+    pub library_path: PathBuf,
+    /// The highest Vulkan version supported by the driver, if the manifest specifies it.
+    pub api_version: Option<u32>,
+    pub is_portability_driver: bool,
+    /// `"32"` or `"64"`, if the manifest specifies the architecture of the library.
+    pub library_arch: Option<String>,
+}
+
+impl IcdManifest {
+    /// Reads the manifest at `path`.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ManifestError> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)?;
+        Self::parse(&json, path.parent().unwrap_or_else(|| Path::new("")))
+    }
+
+    /// Parses a manifest, resolving its relative library path against `dir`.
+    pub fn parse(json: &str, dir: &Path) -> Result<Self, ManifestError> {
+        let json = Json::parse(json)?;
+        let icd = json.get("ICD").ok_or(ManifestError::InvalidMember("ICD"))?;
+        Ok(Self {
+            file_format_version: json
+                .get("file_format_version")
+                .and_then(Json::as_str)
+                .ok_or(ManifestError::InvalidMember("file_format_version"))?
+                .to_owned(),
+            library_path: library_path(
+                icd.get("library_path")
+                    .and_then(Json::as_str)
+                    .ok_or(ManifestError::InvalidMember("ICD.library_path"))?,
+                dir,
+            ),
+            api_version: icd
+                .get("api_version")
+                .map(|version| {
+                    version
+                        .as_str()
+                        .and_then(parse_api_version)
+                        .ok_or(ManifestError::InvalidMember("ICD.api_version"))
+                })
+                .transpose()?,
+            is_portability_driver: match icd.get("is_portability_driver") {
+                Some(&Json::Bool(portability)) => portability,
+                Some(_) => return Err(ManifestError::InvalidMember("ICD.is_portability_driver")),
+                None => false,
+            },
+            library_arch: icd
+                .get("library_arch")
+                .and_then(Json::as_str)
+                .map(str::to_owned),
+        })
+    }
+}
+
+/// An instance or device extension implemented by a layer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtensionManifest {
+    pub name: String,
+    pub spec_version: u32,
+    /// The commands of a device extension, which the loader resolves through the layer.
+    pub entrypoints: Vec<String>,
+}
+
+/// A layer declared in a layer manifest, which may declare several.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayerManifest {
+    pub name: String,
+    /// `"GLOBAL"` or the deprecated `"INSTANCE"`.
+    pub ty: String,
+    /// The layer library, resolved like [`IcdManifest::library_path`]. Meta-layers, which
+    /// enable their [`component_layers`][Self::component_layers], have none.
+    pub library_path: Option<PathBuf>,
+    pub api_version: u32,
+    pub implementation_version: u32,
+    pub description: String,
+    /// Names of the exported commands that the loader should call, by command, such as
+    /// `vkNegotiateLoaderLayerInterfaceVersion`.
+    pub functions: BTreeMap<String, String>,
+    pub instance_extensions: Vec<ExtensionManifest>,
+    pub device_extensions: Vec<ExtensionManifest>,
+    /// The environment variables that must be set to these values to enable an implicit layer.
+    pub enable_environment: BTreeMap<String, String>,
+    /// The environment variables that disable an implicit layer when they are set.
+    pub disable_environment: BTreeMap<String, String>,
+    pub component_layers: Vec<String>,
+    /// Whether the layer was found in an implicit layer directory, in which case the loader
+    /// enables it without the application requesting it.
+    pub implicit: bool,
+}
+
+impl LayerManifest {
+    /// Reads the layers declared in the manifest at `path`.
+    pub fn from_file(path: impl AsRef<Path>, implicit: bool) -> Result<Vec<Self>, ManifestError> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)?;
+        Self::parse(
+            &json,
+            path.parent().unwrap_or_else(|| Path::new("")),
+            implicit,
+        )
+    }
+
+    /// Parses the layers declared in a manifest, resolving their relative library paths against
+    /// `dir`.
+    pub fn parse(json: &str, dir: &Path, implicit: bool) -> Result<Vec<Self>, ManifestError> {
+        let json = Json::parse(json)?;
+        json.get("file_format_version")
+            .and_then(Json::as_str)
+            .ok_or(ManifestError::InvalidMember("file_format_version"))?;
+        match (json.get("layer"), json.get("layers")) {
+            (Some(layer), None) => Ok(vec![Self::from_json(layer, dir, implicit)?]),
+            (None, Some(Json::Array(layers))) => layers
+                .iter()
+                .map(|layer| Self::from_json(layer, dir, implicit))
+                .collect(),
+            _ => Err(ManifestError::InvalidMember("layer")),
+        }
+    }
+
+    fn from_json(layer: &Json, dir: &Path, implicit: bool) -> Result<Self, ManifestError> {
+        let component_layers = strings(layer, "layer.component_layers")?;
+        let library_path =
+            string(layer, "layer.library_path")?.map(|path| library_path(&path, dir));
+        if library_path.is_none() && component_layers.is_empty() {
+            return Err(ManifestError::InvalidMember("layer.library_path"));
+        }
+        Ok(Self {
+            name: string(layer, "layer.name")?.ok_or(ManifestError::InvalidMember("layer.name"))?,
+            ty: string(layer, "layer.type")?.unwrap_or_default(),
+            library_path,
+            api_version: layer
+                .get("api_version")
+                .and_then(Json::as_str)
+                .and_then(parse_api_version)
+                .ok_or(ManifestError::InvalidMember("layer.api_version"))?,
+            implementation_version: match layer.get("implementation_version") {
+                Some(version) => version
+                    .as_u32()
+                    .ok_or(ManifestError::InvalidMember("layer.implementation_version"))?,
+                None => 0,
+            },
+            description: string(layer, "layer.description")?.unwrap_or_default(),
+            functions: map(layer, "layer.functions")?,
+            instance_extensions: extensions(layer, "layer.instance_extensions")?,
+            device_extensions: extensions(layer, "layer.device_extensions")?,
+            enable_environment: map(layer, "layer.enable_environment")?,
+            disable_environment: map(layer, "layer.disable_environment")?,
+            component_layers,
+            implicit,
+        })
+    }
+
+    /// Finds the reasons why the loader would not report or enable this layer, when
+    /// environment variables have the values returned by `env`.
+    pub fn problems(&self, env: impl Fn(&str) -> Option<OsString>) -> Vec<LayerProblem> {
+        let mut problems = Vec::new();
+        if let Some(path) = &self.library_path {
+            // File names are searched for by the dynamic linker
+            if path.components().nth(1).is_some() && !path.is_file() {
+                problems.push(LayerProblem::MissingLibrary(path.clone()));
+            }
+        }
+        if self.implicit {
+            for var in self.disable_environment.keys() {
+                if env(var).is_some() {
+                    problems.push(LayerProblem::DisabledBy(var.clone()));
+                }
+            }
+            for (var, value) in &self.enable_environment {
+                if env(var).as_deref() != Some(value.as_ref()) {
+                    problems.push(LayerProblem::NotEnabledBy(var.clone(), value.clone()));
+                }
+            }
+        }
+        problems
+    }
+}
+
+/// The name of `member`, a path such as `"layer.name"`, in its object.
+fn key(member: &str) -> &str {
+    member.rsplit('.').next().unwrap()
+}
+
+/// Reads an optional string member of `json`.
+fn string(json: &Json, member: &'static str) -> Result<Option<String>, ManifestError> {
+    json.get(key(member))
+        .map(|value| {
+            value
+                .as_str()
+                .map(str::to_owned)
+                .ok_or(ManifestError::InvalidMember(member))
+        })
+        .transpose()
+}
+
+/// Reads an optional array of strings of `json`.
+fn strings(json: &Json, member: &'static str) -> Result<Vec<String>, ManifestError> {
+    match json.get(key(member)) {
+        Some(values) => values
+            .as_array()
+            .and_then(|values| {
+                values
+                    .iter()
+                    .map(|value| value.as_str().map(str::to_owned))
+                    .collect()
+            })
+            .ok_or(ManifestError::InvalidMember(member)),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Reads an optional object of strings of `json`.
+fn map(json: &Json, member: &'static str) -> Result<BTreeMap<String, String>, ManifestError> {
+    match json.get(key(member)) {
+        Some(Json::Object(members)) => members
+            .iter()
+            .map(|(name, value)| Some((name.clone(), value.as_str()?.to_owned())))
+            .collect::<Option<_>>()
+            .ok_or(ManifestError::InvalidMember(member)),
+        Some(_) => Err(ManifestError::InvalidMember(member)),
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+/// Reads an optional array of extensions of `json`.
+fn extensions(json: &Json, member: &'static str) -> Result<Vec<ExtensionManifest>, ManifestError> {
+    let extensions = match json.get(key(member)) {
+        Some(extensions) => extensions
+            .as_array()
+            .ok_or(ManifestError::InvalidMember(member))?,
+        None => return Ok(Vec::new()),
+    };
+    extensions
+        .iter()
+        .map(|extension| {
+            Ok(ExtensionManifest {
+                name: extension
+                    .get("name")
+                    .and_then(Json::as_str)
+                    .ok_or(ManifestError::InvalidMember(member))?
+                    .to_owned(),
+                spec_version: extension
+                    .get("spec_version")
+                    .and_then(Json::as_u32)
+                    .ok_or(ManifestError::InvalidMember(member))?,
+                entrypoints: strings(extension, "entrypoints")
+                    .map_err(|_| ManifestError::InvalidMember(member))?,
+            })
+        })
+        .collect()
+}
+
+/// A reason for the loader not to report or enable a layer, see [`LayerManifest::problems()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LayerProblem {
+    /// The library of the layer does not exist.
+    MissingLibrary(PathBuf),
+    /// The implicit layer is disabled by this environment variable of `disable_environment`.
+    DisabledBy(String),
+    /// The implicit layer is only enabled when this environment variable of
+    /// `enable_environment` has the given value.
+    NotEnabledBy(String, String),
+}
+
+/// A manifest file found by [`SearchPaths`].
+#[derive(Debug)]
+pub struct Found<M> {
+    pub path: PathBuf,
+    pub manifest: Result<M, ManifestError>,
+}
+
+/// The files and directories in which the Vulkan loader looks for manifests, in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchPaths {
+    pub drivers: Vec<PathBuf>,
+    pub explicit_layers: Vec<PathBuf>,
+    pub implicit_layers: Vec<PathBuf>,
+}
+
+impl SearchPaths {
+    /// The search paths of the loader on Linux and other Unix systems in the current
+    /// environment.
+    #[cfg(all(unix, not(target_os = "android")))]
+    pub fn from_env() -> Self {
+        Self::with_env(|var| std::env::var_os(var))
+    }
+
+    /// The search paths of the loader on Linux and other Unix systems, when environment
+    /// variables have the values returned by `env`:
+    ///
+    /// * `VK_DRIVER_FILES` (or the older `VK_ICD_FILENAMES`), `VK_LAYER_PATH` and
+    ///   `VK_IMPLICIT_LAYER_PATH` replace the standard directories.
+    /// * `VK_ADD_DRIVER_FILES`, `VK_ADD_LAYER_PATH` and `VK_ADD_IMPLICIT_LAYER_PATH` are searched
+    ///   first.
+    /// * The standard directories are the `vulkan/icd.d`, `vulkan/explicit_layer.d` and
+    ///   `vulkan/implicit_layer.d` subdirectories of `$XDG_CONFIG_HOME`, `$XDG_CONFIG_DIRS`,
+    ///   `/etc`, `$XDG_DATA_HOME` and `$XDG_DATA_DIRS`, or of their defaults.
+    #[cfg(all(unix, not(target_os = "android")))]
+    pub fn with_env(env: impl Fn(&str) -> Option<OsString>) -> Self {
+        let split = |var: &str| {
+            env(var)
+                .map(|paths| {
+                    std::env::split_paths(&paths)
+                        .filter(|path| !path.as_os_str().is_empty())
+                        .collect::<Vec<_>>()
+                })
+                .filter(|paths| !paths.is_empty())
+        };
+        let home = env("HOME").map(PathBuf::from);
+        let or_home = |var, default: &str| {
+            split(var).or_else(|| home.as_ref().map(|home| vec![home.join(default)]))
+        };
+        let mut roots = Vec::new();
+        roots.extend(or_home("XDG_CONFIG_HOME", ".config").unwrap_or_default());
+        roots.extend(split("XDG_CONFIG_DIRS").unwrap_or_else(|| vec!["/etc/xdg".into()]));
+        roots.push("/etc".into());
+        roots.extend(or_home("XDG_DATA_HOME", ".local/share").unwrap_or_default());
+        roots.extend(
+            split("XDG_DATA_DIRS")
+                .unwrap_or_else(|| vec!["/usr/local/share".into(), "/usr/share".into()]),
+        );
+
+        let search = |add: &str, replace: &[&str], dir: &str| {
+            let mut paths = split(add).unwrap_or_default();
+            match replace.iter().find_map(|&var| split(var)) {
+                Some(replaced) => paths.extend(replaced),
+                None => paths.extend(roots.iter().map(|root| root.join("vulkan").join(dir))),
+            }
+            let mut unique = Vec::new();
+            for path in paths {
+                if !unique.contains(&path) {
+                    unique.push(path);
+                }
+            }
+            unique
+        };
+        Self {
+            drivers: search(
+                "VK_ADD_DRIVER_FILES",
+                &["VK_DRIVER_FILES", "VK_ICD_FILENAMES"],
+                "icd.d",
+            ),
+            explicit_layers: search("VK_ADD_LAYER_PATH", &["VK_LAYER_PATH"], "explicit_layer.d"),
+            implicit_layers: search(
+                "VK_ADD_IMPLICIT_LAYER_PATH",
+                &["VK_IMPLICIT_LAYER_PATH"],
+                "implicit_layer.d",
+            ),
+        }
+    }
+
+    /// Reads the driver manifests, in the order in which the loader would find them.
+    pub fn drivers(&self) -> Vec<Found<IcdManifest>> {
+        manifest_files(&self.drivers)
+            .map(|path| Found {
+                manifest: IcdManifest::from_file(&path),
+                path,
+            })
+            .collect()
+    }
+
+    /// Reads the implicit and then the explicit layer manifests, in the order in which the loader
+    /// would find them.
+    pub fn layers(&self) -> Vec<Found<Vec<LayerManifest>>> {
+        let implicit = manifest_files(&self.implicit_layers).map(|path| (path, true));
+        let explicit = manifest_files(&self.explicit_layers).map(|path| (path, false));
+        implicit
+            .chain(explicit)
+            .map(|(path, implicit)| Found {
+                manifest: LayerManifest::from_file(&path, implicit),
+                path,
+            })
+            .collect()
+    }
+}
+
+/// The files of `paths` and the `.json` files in its directories, sorted by name. Paths that do
+/// not exist are skipped, as the loader does.
+fn manifest_files(paths: &[PathBuf]) -> impl Iterator<Item = PathBuf> + '_ {
+    paths.iter().flat_map(|path| {
+        if path.is_file() {
+            return vec![path.clone()];
+        }
+        let mut files = fs::read_dir(path)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| {
+                path.extension()
+                    .map_or(false, |extension| extension == "json")
+            })
+            .collect::<Vec<_>>();
+        files.sort();
+        files
+    })
+}
+
+/// A layer found in a manifest, see [`correlate_layers()`].
+#[derive(Debug)]
+pub struct LayerStatus<'a> {
+    pub path: &'a Path,
+    pub manifest: &'a LayerManifest,
+    /// Whether a layer of the same name is reported by the loader.
+    pub reported: bool,
+    pub problems: Vec<LayerProblem>,
+}
+
+/// Matches the layers found by [`SearchPaths::layers()`] with those `reported` by
+/// [`Entry::enumerate_instance_layer_properties()`], and finds the [`LayerProblem`]s of each
+/// one when environment variables have the values returned by `env`.
+pub fn correlate_layers<'a>(
+    layers: &'a [Found<Vec<LayerManifest>>],
+    reported: &[vk::LayerProperties],
+    env: impl Fn(&str) -> Option<OsString>,
+) -> Vec<LayerStatus<'a>> {
+    let reported = reported
+        .iter()
+        .filter_map(|properties| {
+            let name = &properties.layer_name;
+            // Layer names are fixed-size arrays, which should contain a null terminator
+            let len = name.iter().position(|&c| c == 0)?;
+            let bytes = unsafe { std::slice::from_raw_parts(name.as_ptr().cast(), len + 1) };
+            CStr::from_bytes_with_nul(bytes).ok()?.to_str().ok()
+        })
+        .collect::<Vec<_>>();
+    layers
+        .iter()
+        .filter_map(|found| Some((&found.path, found.manifest.as_ref().ok()?)))
+        .flat_map(|(path, manifests)| manifests.iter().map(move |manifest| (path, manifest)))
+        .map(|(path, manifest)| LayerStatus {
+            path,
+            manifest,
+            reported: reported.contains(&manifest.name.as_str()),
+            problems: manifest.problems(&env),
+        })
+        .collect()
+}
+
+/// Resolves a library path of a manifest in `dir` like the Vulkan loader: relative paths with
+/// several components are relative to the manifest, file names are left to the dynamic linker.
+pub(crate) fn library_path(path: &str, dir: &Path) -> PathBuf {
+    let path = Path::new(path);
+    if path.is_relative() && path.components().nth(1).is_some() {
+        dir.join(path)
+    } else {
+        path.to_owned()
+    }
+}
+
+/// Parses a version such as `"1.3.250"`.
+pub(crate) fn parse_api_version(version: &str) -> Option<u32> {
+    let mut parts = version.trim().split('.').map(str::parse::<u32>);
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(vk::make_api_version(0, major, minor, patch))
+}
+
+/// A JSON value, with numbers as they are written.
+#[derive(Debug)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Self>),
+    Object(Vec<(String, Self)>),
+}
+
+impl Json {
+    pub(crate) fn parse(json: &str) -> Result<Self, ManifestError> {
+        let mut parser = Parser {
+            json: json.as_bytes(),
+            offset: 0,
+            depth: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.offset != json.len() {
+            return Err(parser.error("Trailing characters"));
+        }
+        Ok(value)
+    }
+
+    /// Returns the member `key` of an object.
+    pub(crate) fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Object(members) => members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[Self]> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns an integer, which manifests write as a number or a string.
+    pub(crate) fn as_u32(&self) -> Option<u32> {
+        match self {
+            Self::Number(s) | Self::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// The deepest nesting of arrays and objects that is parsed, far beyond that of manifests, to
+/// fail on malicious files instead of overflowing the stack.
+const MAX_DEPTH: usize = 128;
+
+struct Parser<'a> {
+    json: &'a [u8],
+    offset: usize,
+    /// The number of arrays and objects being parsed.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &'static str) -> ManifestError {
+        ManifestError::Syntax {
+            offset: self.offset,
+            message,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.json.get(self.offset) {
+            self.offset += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.json.get(self.offset).copied()
+    }
+
+    fn expect(&mut self, byte: u8, message: &'static str) -> Result<(), ManifestError> {
+        if self.peek() != Some(byte) {
+            return Err(self.error(message));
+        }
+        self.offset += 1;
+        Ok(())
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Json) -> Result<Json, ManifestError> {
+        if !self.json[self.offset..].starts_with(keyword.as_bytes()) {
+            return Err(self.error("Expected a value"));
+        }
+        self.offset += keyword.len();
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Json, ManifestError> {
+        match self.peek() {
+            Some(b'{') => self.nested(Self::object),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b't') => self.keyword("true", Json::Bool(true)),
+            Some(b'f') => self.keyword("false", Json::Bool(false)),
+            Some(b'n') => self.keyword("null", Json::Null),
+            Some(b'-' | b'0'..=b'9') => {
+                let start = self.offset;
+                while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') =
+                    self.json.get(self.offset)
+                {
+                    self.offset += 1;
+                }
+                let number = std::str::from_utf8(&self.json[start..self.offset]).unwrap();
+                if number.parse::<f64>().is_err() {
+                    self.offset = start;
+                    return Err(self.error("Invalid number"));
+                }
+                Ok(Json::Number(number.to_owned()))
+            }
+            _ => Err(self.error("Expected a value")),
+        }
+    }
+
+    /// Parses an object or an array with `parse`, failing if they are nested too deeply.
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Json, ManifestError>,
+    ) -> Result<Json, ManifestError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("Arrays and objects are nested too deeply"));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    /// Parses the object starting at the current `{`.
+    fn object(&mut self) -> Result<Json, ManifestError> {
+        self.offset += 1;
+        let mut members = Vec::new();
+        if self.peek() == Some(b'}') {
+            self.offset += 1;
+            return Ok(Json::Object(members));
+        }
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(self.error("Expected a member name"));
+            }
+            let name = self.string()?;
+            self.expect(b':', "Expected `:`")?;
+            members.push((name, self.value()?));
+            match self.peek() {
+                Some(b',') => self.offset += 1,
+                Some(b'}') => {
+                    self.offset += 1;
+                    return Ok(Json::Object(members));
+                }
+                _ => return Err(self.error("Expected `,` or `}`")),
+            }
+        }
+    }
+
+    /// Parses the array starting at the current `[`.
+    fn array(&mut self) -> Result<Json, ManifestError> {
+        self.offset += 1;
+        let mut values = Vec::new();
+        if self.peek() == Some(b']') {
+            self.offset += 1;
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            match self.peek() {
+                Some(b',') => self.offset += 1,
+                Some(b']') => {
+                    self.offset += 1;
+                    return Ok(Json::Array(values));
+                }
+                _ => return Err(self.error("Expected `,` or `]`")),
+            }
+        }
+    }
+
+    /// Parses the string starting at the current `"`.
+    fn string(&mut self) -> Result<String, ManifestError> {
+        self.offset += 1;
+        let mut bytes = Vec::new();
+        loop {
+            let byte = match self.json.get(self.offset) {
+                Some(&byte) => byte,
+                None => return Err(self.error("Unterminated string")),
+            };
+            self.offset += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escape = self.json.get(self.offset).copied();
+                    self.offset += 1;
+                    let escaped = match escape {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => self.unicode_escape()?,
+                        _ => {
+                            self.offset -= 1;
+                            return Err(self.error("Invalid escape sequence"));
+                        }
+                    };
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                }
+                0..=0x1f => return Err(self.error("Control character in string")),
+                _ => bytes.push(byte),
+            }
+        }
+        // The input is a `str`, and escapes were encoded as UTF-8
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    /// Parses the four hexadecimal digits after `\u`, and the low surrogate following a high one.
+    fn unicode_escape(&mut self) -> Result<char, ManifestError> {
+        let high = self.hex_digits()?;
+        let code = if (0xd800..0xdc00).contains(&high) {
+            if !self.json[self.offset..].starts_with(b"\\u") {
+                return Err(self.error("Unpaired surrogate"));
+            }
+            self.offset += 2;
+            let low = self.hex_digits()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(self.error("Unpaired surrogate"));
+            }
+            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.error("Unpaired surrogate"))
+    }
+
+    fn hex_digits(&mut self) -> Result<u32, ManifestError> {
+        let digits = self
+            .json
+            .get(self.offset..self.offset + 4)
+            // `from_str_radix()` would accept a sign
+            .filter(|digits| digits.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error("Invalid unicode escape"))?;
+        self.offset += 4;
+        Ok(digits)
+    }
+}
@@ -0,0 +1,260 @@
+//! A software Vulkan driver for testing code that uses `ash` without a GPU or Vulkan loader.
+//!
+//! [`MockDriver::entry()`] returns an [`Entry`] whose `vkGetInstanceProcAddr` resolves every core
+//! and extension command known to `vk.xml`. Every call is recorded, and can be overridden with a
+//! closure through the `MockDriver::on_*()` method of that command. Commands without an override
+//! return their first success code, allocate the handles they create and release the handles
+//! they destroy or free, leaving all other outputs zeroed or untouched. Like the objects returned
+//! through the Vulkan loader, dispatchable handles point to a slot holding a dispatch key, which
+//! is distinct for every instance and device and shared by an instance and its physical devices,
+//! and by a device and its queues and command buffers.
+//!
+//! ```
+//! use ash::{mock::MockDriver, vk};
+//!
+//! let driver = MockDriver::new();
+//! driver.on_enumerate_instance_version(|p_api_version| unsafe {
+//!     *p_api_version = vk::API_VERSION_1_2;
+//!     vk::Result::SUCCESS
+//! });
+//!
+//! let entry = driver.entry();
+//! let version = unsafe { entry.try_enumerate_instance_version() }.unwrap();
+//! assert_eq!(version, Some(vk::API_VERSION_1_2));
+//!
+//! let instance = unsafe { entry.create_instance(&Default::default(), None) }.unwrap();
+//! assert_eq!(driver.live_handles().len(), 1);
+//! unsafe { instance.destroy_instance(None) };
+//! assert!(driver.live_handles().is_empty());
+//! ```
+//!
+//! The stubs handed out by the [`Entry`] find their driver through the slot of the first
+//! dispatchable handle they are called with, which also records the driver that allocated it.
+//! Commands without one, such as `vkCreateInstance`, go to the driver whose
+//! [`MockDriver::entry()`] was called last on the calling thread. Commands called on another
+//! thread than that of their driver, or after it was dropped, fail with
+//! [`vk::Result::ERROR_INITIALIZATION_FAILED`] or return zeroes, and are not recorded. Overrides
+//! must not panic, as the stubs are `extern "system"` functions that cannot unwind.
+
+use crate::vk::{self, Handle};
+use crate::Entry;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+use std::thread::{self, ThreadId};
+
+mod commands;
+
+fn is_dispatchable(ty: vk::ObjectType) -> bool {
+    matches!(
+        ty,
+        vk::ObjectType::INSTANCE
+            | vk::ObjectType::PHYSICAL_DEVICE
+            | vk::ObjectType::DEVICE
+            | vk::ObjectType::QUEUE
+            | vk::ObjectType::COMMAND_BUFFER
+    )
+}
+
+/// What dispatchable handles point to: the dispatch key that the loader reads, followed by the
+/// driver that allocated the handle.
+#[repr(C)]
+struct Slot {
+    key: usize,
+    driver: Weak<RefCell<State>>,
+    thread: ThreadId,
+}
+
+/// A command called through a [`MockDriver`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Call {
+    /// The name of the command, such as `"vkCreateInstance"`.
+    pub name: &'static str,
+    /// The raw values of the handles passed by value, in parameter order.
+    pub handles: Vec<u64>,
+}
+
+#[derive(Default)]
+struct State {
+    calls: Vec<Call>,
+    handlers: HashMap<&'static str, Box<dyn Any>>,
+    next_handle: u64,
+    /// The first handle passed to the command being called, which dispatchable handles allocated
+    /// by the command take their dispatch key from.
+    parent: Option<u64>,
+    /// The allocation order and type of live handles, by raw value.
+    live: HashMap<u64, (u64, vk::ObjectType)>,
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        for (&raw, &(_, ty)) in &self.live {
+            if is_dispatchable(ty) {
+                drop(unsafe { Box::from_raw(raw as usize as *mut Slot) });
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// The driver of the commands that take no dispatchable handle.
+    static CURRENT: RefCell<Option<Weak<RefCell<State>>>> = const { RefCell::new(None) };
+}
+
+/// A software Vulkan driver that records calls and dispatches them to configurable closures.
+///
+/// Clones refer to the same driver, which allows overrides to allocate handles through a clone.
+#[derive(Clone)]
+pub struct MockDriver {
+    state: Rc<RefCell<State>>,
+}
+
+impl MockDriver {
+    /// Creates a driver without overrides, calls or handles.
+    pub fn new() -> Self {
+        Self {
+            state: Default::default(),
+        }
+    }
+
+    /// An [`Entry`] that resolves every command to this driver. The commands that take no
+    /// dispatchable handle, or a null one, are called on the driver whose `entry()` was called last
+    /// on this thread.
+    pub fn entry(&self) -> Entry {
+        CURRENT.with(|current| *current.borrow_mut() = Some(Rc::downgrade(&self.state)));
+        // Sound because the stubs handle every command with valid pointers as arguments
+        unsafe {
+            Entry::from_static_fn(vk::StaticFn {
+                get_instance_proc_addr: commands::get_instance_proc_addr,
+            })
+        }
+    }
+
+    /// All calls made so far, in order.
+    pub fn calls(&self) -> Vec<Call> {
+        self.state.borrow().calls.clone()
+    }
+
+    /// The driver that allocated the dispatchable handle `raw`, or if `raw` is null the driver
+    /// whose [`MockDriver::entry()`] was called last on this thread. `None` if that driver was
+    /// dropped or belongs to another thread.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be null or a live dispatchable handle allocated by a [`MockDriver`].
+    unsafe fn of(raw: u64) -> Option<Self> {
+        let state = if raw == 0 {
+            CURRENT.with(|current| current.borrow().as_ref().and_then(Weak::upgrade))
+        } else {
+            let slot = &*(raw as usize as *const Slot);
+            // `Weak` can only be touched from the thread of its `Rc`
+            if slot.thread != thread::current().id() {
+                return None;
+            }
+            slot.driver.upgrade()
+        };
+        state.map(|state| Self { state })
+    }
+
+    /// Forgets the calls made so far.
+    pub fn clear_calls(&self) {
+        self.state.borrow_mut().calls.clear();
+    }
+
+    /// The raw values and types of the handles that were allocated and not yet released.
+    pub fn live_handles(&self) -> Vec<(u64, vk::ObjectType)> {
+        let state = self.state.borrow();
+        let mut live = state.live.iter().collect::<Vec<_>>();
+        live.sort_unstable_by_key(|(_, &(order, _))| order);
+        live.into_iter().map(|(&raw, &(_, ty))| (raw, ty)).collect()
+    }
+
+    /// Allocates a new, unique handle, for use by overrides that create objects.
+    pub fn allocate<H: Handle>(&self) -> H {
+        let mut state = self.state.borrow_mut();
+        state.next_handle += 1;
+        let order = state.next_handle;
+        let raw = if is_dispatchable(H::TYPE) {
+            let slot = Box::into_raw(Box::new(Slot {
+                key: 0,
+                driver: Rc::downgrade(&self.state),
+                thread: thread::current().id(),
+            }));
+            let parent = match H::TYPE {
+                vk::ObjectType::INSTANCE | vk::ObjectType::DEVICE => None,
+                _ => state.parent.filter(|parent| {
+                    state
+                        .live
+                        .get(parent)
+                        .map_or(false, |&(_, ty)| is_dispatchable(ty))
+                }),
+            };
+            // Instances and devices, and the objects allocated without a parent, are their own key
+            let key = parent.map_or(slot as usize, |parent| unsafe {
+                *(parent as usize as *const usize)
+            });
+            unsafe { (*slot).key = key };
+            slot as usize as u64
+        } else {
+            order
+        };
+        state.live.insert(raw, (order, H::TYPE));
+        H::from_raw(raw)
+    }
+
+    /// Releases a handle returned by [`MockDriver::allocate()`], for use by overrides that
+    /// destroy objects.
+    pub fn release(&self, raw: u64) {
+        let live = self.state.borrow_mut().live.remove(&raw);
+        if live.map_or(false, |(_, ty)| is_dispatchable(ty)) {
+            drop(unsafe { Box::from_raw(raw as usize as *mut Slot) });
+        }
+    }
+
+    fn set_handler<F: ?Sized + 'static>(&self, name: &'static str, f: Box<F>) -> &Self {
+        self.state.borrow_mut().handlers.insert(name, Box::new(f));
+        self
+    }
+}
+
+impl Default for MockDriver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Records a call to `name` on the driver of its first handle and forwards it to its override, or
+/// to `default` if there is none. Returns `None` without a driver, see [`MockDriver::of()`].
+unsafe fn dispatch<F: ?Sized + 'static, R>(
+    name: &'static str,
+    handles: &[u64],
+    call: impl FnOnce(&mut F) -> R,
+    default: impl FnOnce(&MockDriver) -> R,
+) -> Option<R> {
+    let driver = MockDriver::of(handles.first().copied().unwrap_or(0))?;
+    let handler = {
+        let mut state = driver.state.borrow_mut();
+        state.calls.push(Call {
+            name,
+            handles: handles.to_vec(),
+        });
+        state.parent = handles.first().copied();
+        state.handlers.remove(name)
+    };
+    // The override is taken out while it runs, so it can call into the driver
+    match handler {
+        Some(mut handler) => {
+            let f = handler.downcast_mut::<Box<F>>().unwrap();
+            let result = call(f);
+            driver
+                .state
+                .borrow_mut()
+                .handlers
+                .entry(name)
+                .or_insert(handler);
+            Some(result)
+        }
+        None => Some(default(&driver)),
+    }
+}
@@ -0,0 +1,19518 @@
+use super::{dispatch, MockDriver};
+use crate::vk::{self, Handle};
+use std::ffi::CStr;
+use std::os::raw::*;
+use std::{mem, ptr};
+#[doc = r" Resolves every command known to `vk.xml` to its stub."]
+fn lookup(name: &[u8]) -> vk::PFN_vkVoidFunction {
+    let f = match name {
+        b"vkAcquireDrmDisplayEXT" => acquire_drm_display_ext as *const c_void,
+        b"vkAcquireFullScreenExclusiveModeEXT" => {
+            acquire_full_screen_exclusive_mode_ext as *const c_void
+        }
+        b"vkAcquireImageANDROID" => acquire_image_android as *const c_void,
+        b"vkAcquireNextImage2KHR" => acquire_next_image2_khr as *const c_void,
+        b"vkAcquireNextImageKHR" => acquire_next_image_khr as *const c_void,
+        b"vkAcquirePerformanceConfigurationINTEL" => {
+            acquire_performance_configuration_intel as *const c_void
+        }
+        b"vkAcquireProfilingLockKHR" => acquire_profiling_lock_khr as *const c_void,
+        b"vkAcquireWinrtDisplayNV" => acquire_winrt_display_nv as *const c_void,
+        b"vkAcquireXlibDisplayEXT" => acquire_xlib_display_ext as *const c_void,
+        b"vkAllocateCommandBuffers" => allocate_command_buffers as *const c_void,
+        b"vkAllocateDescriptorSets" => allocate_descriptor_sets as *const c_void,
+        b"vkAllocateMemory" => allocate_memory as *const c_void,
+        b"vkBeginCommandBuffer" => begin_command_buffer as *const c_void,
+        b"vkBindAccelerationStructureMemoryNV" => {
+            bind_acceleration_structure_memory_nv as *const c_void
+        }
+        b"vkBindBufferMemory" => bind_buffer_memory as *const c_void,
+        b"vkBindBufferMemory2" => bind_buffer_memory2 as *const c_void,
+        b"vkBindBufferMemory2KHR" => bind_buffer_memory2 as *const c_void,
+        b"vkBindImageMemory" => bind_image_memory as *const c_void,
+        b"vkBindImageMemory2" => bind_image_memory2 as *const c_void,
+        b"vkBindImageMemory2KHR" => bind_image_memory2 as *const c_void,
+        b"vkBindOpticalFlowSessionImageNV" => bind_optical_flow_session_image_nv as *const c_void,
+        b"vkBindVideoSessionMemoryKHR" => bind_video_session_memory_khr as *const c_void,
+        b"vkBuildAccelerationStructuresKHR" => build_acceleration_structures_khr as *const c_void,
+        b"vkBuildMicromapsEXT" => build_micromaps_ext as *const c_void,
+        b"vkCmdBeginConditionalRenderingEXT" => {
+            cmd_begin_conditional_rendering_ext as *const c_void
+        }
+        b"vkCmdBeginDebugUtilsLabelEXT" => cmd_begin_debug_utils_label_ext as *const c_void,
+        b"vkCmdBeginQuery" => cmd_begin_query as *const c_void,
+        b"vkCmdBeginQueryIndexedEXT" => cmd_begin_query_indexed_ext as *const c_void,
+        b"vkCmdBeginRenderPass" => cmd_begin_render_pass as *const c_void,
+        b"vkCmdBeginRenderPass2" => cmd_begin_render_pass2 as *const c_void,
+        b"vkCmdBeginRenderPass2KHR" => cmd_begin_render_pass2 as *const c_void,
+        b"vkCmdBeginRendering" => cmd_begin_rendering as *const c_void,
+        b"vkCmdBeginRenderingKHR" => cmd_begin_rendering as *const c_void,
+        b"vkCmdBeginTransformFeedbackEXT" => cmd_begin_transform_feedback_ext as *const c_void,
+        b"vkCmdBeginVideoCodingKHR" => cmd_begin_video_coding_khr as *const c_void,
+        b"vkCmdBindDescriptorBufferEmbeddedSamplersEXT" => {
+            cmd_bind_descriptor_buffer_embedded_samplers_ext as *const c_void
+        }
+        b"vkCmdBindDescriptorBuffersEXT" => cmd_bind_descriptor_buffers_ext as *const c_void,
+        b"vkCmdBindDescriptorSets" => cmd_bind_descriptor_sets as *const c_void,
+        b"vkCmdBindIndexBuffer" => cmd_bind_index_buffer as *const c_void,
+        b"vkCmdBindInvocationMaskHUAWEI" => cmd_bind_invocation_mask_huawei as *const c_void,
+        b"vkCmdBindPipeline" => cmd_bind_pipeline as *const c_void,
+        b"vkCmdBindPipelineShaderGroupNV" => cmd_bind_pipeline_shader_group_nv as *const c_void,
+        b"vkCmdBindShadersEXT" => cmd_bind_shaders_ext as *const c_void,
+        b"vkCmdBindShadingRateImageNV" => cmd_bind_shading_rate_image_nv as *const c_void,
+        b"vkCmdBindTransformFeedbackBuffersEXT" => {
+            cmd_bind_transform_feedback_buffers_ext as *const c_void
+        }
+        b"vkCmdBindVertexBuffers" => cmd_bind_vertex_buffers as *const c_void,
+        b"vkCmdBindVertexBuffers2" => cmd_bind_vertex_buffers2 as *const c_void,
+        b"vkCmdBindVertexBuffers2EXT" => cmd_bind_vertex_buffers2 as *const c_void,
+        b"vkCmdBlitImage" => cmd_blit_image as *const c_void,
+        b"vkCmdBlitImage2" => cmd_blit_image2 as *const c_void,
+        b"vkCmdBlitImage2KHR" => cmd_blit_image2 as *const c_void,
+        b"vkCmdBuildAccelerationStructureNV" => {
+            cmd_build_acceleration_structure_nv as *const c_void
+        }
+        b"vkCmdBuildAccelerationStructuresIndirectKHR" => {
+            cmd_build_acceleration_structures_indirect_khr as *const c_void
+        }
+        b"vkCmdBuildAccelerationStructuresKHR" => {
+            cmd_build_acceleration_structures_khr as *const c_void
+        }
+        b"vkCmdBuildMicromapsEXT" => cmd_build_micromaps_ext as *const c_void,
+        b"vkCmdClearAttachments" => cmd_clear_attachments as *const c_void,
+        b"vkCmdClearColorImage" => cmd_clear_color_image as *const c_void,
+        b"vkCmdClearDepthStencilImage" => cmd_clear_depth_stencil_image as *const c_void,
+        b"vkCmdControlVideoCodingKHR" => cmd_control_video_coding_khr as *const c_void,
+        b"vkCmdCopyAccelerationStructureKHR" => {
+            cmd_copy_acceleration_structure_khr as *const c_void
+        }
+        b"vkCmdCopyAccelerationStructureNV" => cmd_copy_acceleration_structure_nv as *const c_void,
+        b"vkCmdCopyAccelerationStructureToMemoryKHR" => {
+            cmd_copy_acceleration_structure_to_memory_khr as *const c_void
+        }
+        b"vkCmdCopyBuffer" => cmd_copy_buffer as *const c_void,
+        b"vkCmdCopyBuffer2" => cmd_copy_buffer2 as *const c_void,
+        b"vkCmdCopyBuffer2KHR" => cmd_copy_buffer2 as *const c_void,
+        b"vkCmdCopyBufferToImage" => cmd_copy_buffer_to_image as *const c_void,
+        b"vkCmdCopyBufferToImage2" => cmd_copy_buffer_to_image2 as *const c_void,
+        b"vkCmdCopyBufferToImage2KHR" => cmd_copy_buffer_to_image2 as *const c_void,
+        b"vkCmdCopyImage" => cmd_copy_image as *const c_void,
+        b"vkCmdCopyImage2" => cmd_copy_image2 as *const c_void,
+        b"vkCmdCopyImage2KHR" => cmd_copy_image2 as *const c_void,
+        b"vkCmdCopyImageToBuffer" => cmd_copy_image_to_buffer as *const c_void,
+        b"vkCmdCopyImageToBuffer2" => cmd_copy_image_to_buffer2 as *const c_void,
+        b"vkCmdCopyImageToBuffer2KHR" => cmd_copy_image_to_buffer2 as *const c_void,
+        b"vkCmdCopyMemoryIndirectNV" => cmd_copy_memory_indirect_nv as *const c_void,
+        b"vkCmdCopyMemoryToAccelerationStructureKHR" => {
+            cmd_copy_memory_to_acceleration_structure_khr as *const c_void
+        }
+        b"vkCmdCopyMemoryToImageIndirectNV" => {
+            cmd_copy_memory_to_image_indirect_nv as *const c_void
+        }
+        b"vkCmdCopyMemoryToMicromapEXT" => cmd_copy_memory_to_micromap_ext as *const c_void,
+        b"vkCmdCopyMicromapEXT" => cmd_copy_micromap_ext as *const c_void,
+        b"vkCmdCopyMicromapToMemoryEXT" => cmd_copy_micromap_to_memory_ext as *const c_void,
+        b"vkCmdCopyQueryPoolResults" => cmd_copy_query_pool_results as *const c_void,
+        b"vkCmdCuLaunchKernelNVX" => cmd_cu_launch_kernel_nvx as *const c_void,
+        b"vkCmdDebugMarkerBeginEXT" => cmd_debug_marker_begin_ext as *const c_void,
+        b"vkCmdDebugMarkerEndEXT" => cmd_debug_marker_end_ext as *const c_void,
+        b"vkCmdDebugMarkerInsertEXT" => cmd_debug_marker_insert_ext as *const c_void,
+        b"vkCmdDecodeVideoKHR" => cmd_decode_video_khr as *const c_void,
+        b"vkCmdDecompressMemoryIndirectCountNV" => {
+            cmd_decompress_memory_indirect_count_nv as *const c_void
+        }
+        b"vkCmdDecompressMemoryNV" => cmd_decompress_memory_nv as *const c_void,
+        b"vkCmdDispatch" => cmd_dispatch as *const c_void,
+        b"vkCmdDispatchBase" => cmd_dispatch_base as *const c_void,
+        b"vkCmdDispatchBaseKHR" => cmd_dispatch_base as *const c_void,
+        b"vkCmdDispatchIndirect" => cmd_dispatch_indirect as *const c_void,
+        b"vkCmdDraw" => cmd_draw as *const c_void,
+        b"vkCmdDrawClusterHUAWEI" => cmd_draw_cluster_huawei as *const c_void,
+        b"vkCmdDrawClusterIndirectHUAWEI" => cmd_draw_cluster_indirect_huawei as *const c_void,
+        b"vkCmdDrawIndexed" => cmd_draw_indexed as *const c_void,
+        b"vkCmdDrawIndexedIndirect" => cmd_draw_indexed_indirect as *const c_void,
+        b"vkCmdDrawIndexedIndirectCount" => cmd_draw_indexed_indirect_count as *const c_void,
+        b"vkCmdDrawIndexedIndirectCountAMD" => cmd_draw_indexed_indirect_count as *const c_void,
+        b"vkCmdDrawIndexedIndirectCountKHR" => cmd_draw_indexed_indirect_count as *const c_void,
+        b"vkCmdDrawIndirect" => cmd_draw_indirect as *const c_void,
+        b"vkCmdDrawIndirectByteCountEXT" => cmd_draw_indirect_byte_count_ext as *const c_void,
+        b"vkCmdDrawIndirectCount" => cmd_draw_indirect_count as *const c_void,
+        b"vkCmdDrawIndirectCountAMD" => cmd_draw_indirect_count as *const c_void,
+        b"vkCmdDrawIndirectCountKHR" => cmd_draw_indirect_count as *const c_void,
+        b"vkCmdDrawMeshTasksEXT" => cmd_draw_mesh_tasks_ext as *const c_void,
+        b"vkCmdDrawMeshTasksIndirectCountEXT" => {
+            cmd_draw_mesh_tasks_indirect_count_ext as *const c_void
+        }
+        b"vkCmdDrawMeshTasksIndirectCountNV" => {
+            cmd_draw_mesh_tasks_indirect_count_nv as *const c_void
+        }
+        b"vkCmdDrawMeshTasksIndirectEXT" => cmd_draw_mesh_tasks_indirect_ext as *const c_void,
+        b"vkCmdDrawMeshTasksIndirectNV" => cmd_draw_mesh_tasks_indirect_nv as *const c_void,
+        b"vkCmdDrawMeshTasksNV" => cmd_draw_mesh_tasks_nv as *const c_void,
+        b"vkCmdDrawMultiEXT" => cmd_draw_multi_ext as *const c_void,
+        b"vkCmdDrawMultiIndexedEXT" => cmd_draw_multi_indexed_ext as *const c_void,
+        b"vkCmdEncodeVideoKHR" => cmd_encode_video_khr as *const c_void,
+        b"vkCmdEndConditionalRenderingEXT" => cmd_end_conditional_rendering_ext as *const c_void,
+        b"vkCmdEndDebugUtilsLabelEXT" => cmd_end_debug_utils_label_ext as *const c_void,
+        b"vkCmdEndQuery" => cmd_end_query as *const c_void,
+        b"vkCmdEndQueryIndexedEXT" => cmd_end_query_indexed_ext as *const c_void,
+        b"vkCmdEndRenderPass" => cmd_end_render_pass as *const c_void,
+        b"vkCmdEndRenderPass2" => cmd_end_render_pass2 as *const c_void,
+        b"vkCmdEndRenderPass2KHR" => cmd_end_render_pass2 as *const c_void,
+        b"vkCmdEndRendering" => cmd_end_rendering as *const c_void,
+        b"vkCmdEndRenderingKHR" => cmd_end_rendering as *const c_void,
+        b"vkCmdEndTransformFeedbackEXT" => cmd_end_transform_feedback_ext as *const c_void,
+        b"vkCmdEndVideoCodingKHR" => cmd_end_video_coding_khr as *const c_void,
+        b"vkCmdExecuteCommands" => cmd_execute_commands as *const c_void,
+        b"vkCmdExecuteGeneratedCommandsNV" => cmd_execute_generated_commands_nv as *const c_void,
+        b"vkCmdFillBuffer" => cmd_fill_buffer as *const c_void,
+        b"vkCmdInsertDebugUtilsLabelEXT" => cmd_insert_debug_utils_label_ext as *const c_void,
+        b"vkCmdNextSubpass" => cmd_next_subpass as *const c_void,
+        b"vkCmdNextSubpass2" => cmd_next_subpass2 as *const c_void,
+        b"vkCmdNextSubpass2KHR" => cmd_next_subpass2 as *const c_void,
+        b"vkCmdOpticalFlowExecuteNV" => cmd_optical_flow_execute_nv as *const c_void,
+        b"vkCmdPipelineBarrier" => cmd_pipeline_barrier as *const c_void,
+        b"vkCmdPipelineBarrier2" => cmd_pipeline_barrier2 as *const c_void,
+        b"vkCmdPipelineBarrier2KHR" => cmd_pipeline_barrier2 as *const c_void,
+        b"vkCmdPreprocessGeneratedCommandsNV" => {
+            cmd_preprocess_generated_commands_nv as *const c_void
+        }
+        b"vkCmdPushConstants" => cmd_push_constants as *const c_void,
+        b"vkCmdPushDescriptorSetKHR" => cmd_push_descriptor_set_khr as *const c_void,
+        b"vkCmdPushDescriptorSetWithTemplateKHR" => {
+            cmd_push_descriptor_set_with_template_khr as *const c_void
+        }
+        b"vkCmdResetEvent" => cmd_reset_event as *const c_void,
+        b"vkCmdResetEvent2" => cmd_reset_event2 as *const c_void,
+        b"vkCmdResetEvent2KHR" => cmd_reset_event2 as *const c_void,
+        b"vkCmdResetQueryPool" => cmd_reset_query_pool as *const c_void,
+        b"vkCmdResolveImage" => cmd_resolve_image as *const c_void,
+        b"vkCmdResolveImage2" => cmd_resolve_image2 as *const c_void,
+        b"vkCmdResolveImage2KHR" => cmd_resolve_image2 as *const c_void,
+        b"vkCmdSetAlphaToCoverageEnableEXT" => {
+            cmd_set_alpha_to_coverage_enable_ext as *const c_void
+        }
+        b"vkCmdSetAlphaToOneEnableEXT" => cmd_set_alpha_to_one_enable_ext as *const c_void,
+        b"vkCmdSetAttachmentFeedbackLoopEnableEXT" => {
+            cmd_set_attachment_feedback_loop_enable_ext as *const c_void
+        }
+        b"vkCmdSetBlendConstants" => cmd_set_blend_constants as *const c_void,
+        b"vkCmdSetCheckpointNV" => cmd_set_checkpoint_nv as *const c_void,
+        b"vkCmdSetCoarseSampleOrderNV" => cmd_set_coarse_sample_order_nv as *const c_void,
+        b"vkCmdSetColorBlendAdvancedEXT" => cmd_set_color_blend_advanced_ext as *const c_void,
+        b"vkCmdSetColorBlendEnableEXT" => cmd_set_color_blend_enable_ext as *const c_void,
+        b"vkCmdSetColorBlendEquationEXT" => cmd_set_color_blend_equation_ext as *const c_void,
+        b"vkCmdSetColorWriteEnableEXT" => cmd_set_color_write_enable_ext as *const c_void,
+        b"vkCmdSetColorWriteMaskEXT" => cmd_set_color_write_mask_ext as *const c_void,
+        b"vkCmdSetConservativeRasterizationModeEXT" => {
+            cmd_set_conservative_rasterization_mode_ext as *const c_void
+        }
+        b"vkCmdSetCoverageModulationModeNV" => cmd_set_coverage_modulation_mode_nv as *const c_void,
+        b"vkCmdSetCoverageModulationTableEnableNV" => {
+            cmd_set_coverage_modulation_table_enable_nv as *const c_void
+        }
+        b"vkCmdSetCoverageModulationTableNV" => {
+            cmd_set_coverage_modulation_table_nv as *const c_void
+        }
+        b"vkCmdSetCoverageReductionModeNV" => cmd_set_coverage_reduction_mode_nv as *const c_void,
+        b"vkCmdSetCoverageToColorEnableNV" => cmd_set_coverage_to_color_enable_nv as *const c_void,
+        b"vkCmdSetCoverageToColorLocationNV" => {
+            cmd_set_coverage_to_color_location_nv as *const c_void
+        }
+        b"vkCmdSetCullMode" => cmd_set_cull_mode as *const c_void,
+        b"vkCmdSetCullModeEXT" => cmd_set_cull_mode as *const c_void,
+        b"vkCmdSetDepthBias" => cmd_set_depth_bias as *const c_void,
+        b"vkCmdSetDepthBias2EXT" => cmd_set_depth_bias2_ext as *const c_void,
+        b"vkCmdSetDepthBiasEnable" => cmd_set_depth_bias_enable as *const c_void,
+        b"vkCmdSetDepthBiasEnableEXT" => cmd_set_depth_bias_enable as *const c_void,
+        b"vkCmdSetDepthBounds" => cmd_set_depth_bounds as *const c_void,
+        b"vkCmdSetDepthBoundsTestEnable" => cmd_set_depth_bounds_test_enable as *const c_void,
+        b"vkCmdSetDepthBoundsTestEnableEXT" => cmd_set_depth_bounds_test_enable as *const c_void,
+        b"vkCmdSetDepthClampEnableEXT" => cmd_set_depth_clamp_enable_ext as *const c_void,
+        b"vkCmdSetDepthClipEnableEXT" => cmd_set_depth_clip_enable_ext as *const c_void,
+        b"vkCmdSetDepthClipNegativeOneToOneEXT" => {
+            cmd_set_depth_clip_negative_one_to_one_ext as *const c_void
+        }
+        b"vkCmdSetDepthCompareOp" => cmd_set_depth_compare_op as *const c_void,
+        b"vkCmdSetDepthCompareOpEXT" => cmd_set_depth_compare_op as *const c_void,
+        b"vkCmdSetDepthTestEnable" => cmd_set_depth_test_enable as *const c_void,
+        b"vkCmdSetDepthTestEnableEXT" => cmd_set_depth_test_enable as *const c_void,
+        b"vkCmdSetDepthWriteEnable" => cmd_set_depth_write_enable as *const c_void,
+        b"vkCmdSetDepthWriteEnableEXT" => cmd_set_depth_write_enable as *const c_void,
+        b"vkCmdSetDescriptorBufferOffsetsEXT" => {
+            cmd_set_descriptor_buffer_offsets_ext as *const c_void
+        }
+        b"vkCmdSetDeviceMask" => cmd_set_device_mask as *const c_void,
+        b"vkCmdSetDeviceMaskKHR" => cmd_set_device_mask as *const c_void,
+        b"vkCmdSetDiscardRectangleEXT" => cmd_set_discard_rectangle_ext as *const c_void,
+        b"vkCmdSetDiscardRectangleEnableEXT" => {
+            cmd_set_discard_rectangle_enable_ext as *const c_void
+        }
+        b"vkCmdSetDiscardRectangleModeEXT" => cmd_set_discard_rectangle_mode_ext as *const c_void,
+        b"vkCmdSetEvent" => cmd_set_event as *const c_void,
+        b"vkCmdSetEvent2" => cmd_set_event2 as *const c_void,
+        b"vkCmdSetEvent2KHR" => cmd_set_event2 as *const c_void,
+        b"vkCmdSetExclusiveScissorEnableNV" => cmd_set_exclusive_scissor_enable_nv as *const c_void,
+        b"vkCmdSetExclusiveScissorNV" => cmd_set_exclusive_scissor_nv as *const c_void,
+        b"vkCmdSetExtraPrimitiveOverestimationSizeEXT" => {
+            cmd_set_extra_primitive_overestimation_size_ext as *const c_void
+        }
+        b"vkCmdSetFragmentShadingRateEnumNV" => {
+            cmd_set_fragment_shading_rate_enum_nv as *const c_void
+        }
+        b"vkCmdSetFragmentShadingRateKHR" => cmd_set_fragment_shading_rate_khr as *const c_void,
+        b"vkCmdSetFrontFace" => cmd_set_front_face as *const c_void,
+        b"vkCmdSetFrontFaceEXT" => cmd_set_front_face as *const c_void,
+        b"vkCmdSetLineRasterizationModeEXT" => cmd_set_line_rasterization_mode_ext as *const c_void,
+        b"vkCmdSetLineStippleEXT" => cmd_set_line_stipple_ext as *const c_void,
+        b"vkCmdSetLineStippleEnableEXT" => cmd_set_line_stipple_enable_ext as *const c_void,
+        b"vkCmdSetLineWidth" => cmd_set_line_width as *const c_void,
+        b"vkCmdSetLogicOpEXT" => cmd_set_logic_op_ext as *const c_void,
+        b"vkCmdSetLogicOpEnableEXT" => cmd_set_logic_op_enable_ext as *const c_void,
+        b"vkCmdSetPatchControlPointsEXT" => cmd_set_patch_control_points_ext as *const c_void,
+        b"vkCmdSetPerformanceMarkerINTEL" => cmd_set_performance_marker_intel as *const c_void,
+        b"vkCmdSetPerformanceOverrideINTEL" => cmd_set_performance_override_intel as *const c_void,
+        b"vkCmdSetPerformanceStreamMarkerINTEL" => {
+            cmd_set_performance_stream_marker_intel as *const c_void
+        }
+        b"vkCmdSetPolygonModeEXT" => cmd_set_polygon_mode_ext as *const c_void,
+        b"vkCmdSetPrimitiveRestartEnable" => cmd_set_primitive_restart_enable as *const c_void,
+        b"vkCmdSetPrimitiveRestartEnableEXT" => cmd_set_primitive_restart_enable as *const c_void,
+        b"vkCmdSetPrimitiveTopology" => cmd_set_primitive_topology as *const c_void,
+        b"vkCmdSetPrimitiveTopologyEXT" => cmd_set_primitive_topology as *const c_void,
+        b"vkCmdSetProvokingVertexModeEXT" => cmd_set_provoking_vertex_mode_ext as *const c_void,
+        b"vkCmdSetRasterizationSamplesEXT" => cmd_set_rasterization_samples_ext as *const c_void,
+        b"vkCmdSetRasterizationStreamEXT" => cmd_set_rasterization_stream_ext as *const c_void,
+        b"vkCmdSetRasterizerDiscardEnable" => cmd_set_rasterizer_discard_enable as *const c_void,
+        b"vkCmdSetRasterizerDiscardEnableEXT" => cmd_set_rasterizer_discard_enable as *const c_void,
+        b"vkCmdSetRayTracingPipelineStackSizeKHR" => {
+            cmd_set_ray_tracing_pipeline_stack_size_khr as *const c_void
+        }
+        b"vkCmdSetRepresentativeFragmentTestEnableNV" => {
+            cmd_set_representative_fragment_test_enable_nv as *const c_void
+        }
+        b"vkCmdSetSampleLocationsEXT" => cmd_set_sample_locations_ext as *const c_void,
+        b"vkCmdSetSampleLocationsEnableEXT" => cmd_set_sample_locations_enable_ext as *const c_void,
+        b"vkCmdSetSampleMaskEXT" => cmd_set_sample_mask_ext as *const c_void,
+        b"vkCmdSetScissor" => cmd_set_scissor as *const c_void,
+        b"vkCmdSetScissorWithCount" => cmd_set_scissor_with_count as *const c_void,
+        b"vkCmdSetScissorWithCountEXT" => cmd_set_scissor_with_count as *const c_void,
+        b"vkCmdSetShadingRateImageEnableNV" => {
+            cmd_set_shading_rate_image_enable_nv as *const c_void
+        }
+        b"vkCmdSetStencilCompareMask" => cmd_set_stencil_compare_mask as *const c_void,
+        b"vkCmdSetStencilOp" => cmd_set_stencil_op as *const c_void,
+        b"vkCmdSetStencilOpEXT" => cmd_set_stencil_op as *const c_void,
+        b"vkCmdSetStencilReference" => cmd_set_stencil_reference as *const c_void,
+        b"vkCmdSetStencilTestEnable" => cmd_set_stencil_test_enable as *const c_void,
+        b"vkCmdSetStencilTestEnableEXT" => cmd_set_stencil_test_enable as *const c_void,
+        b"vkCmdSetStencilWriteMask" => cmd_set_stencil_write_mask as *const c_void,
+        b"vkCmdSetTessellationDomainOriginEXT" => {
+            cmd_set_tessellation_domain_origin_ext as *const c_void
+        }
+        b"vkCmdSetVertexInputEXT" => cmd_set_vertex_input_ext as *const c_void,
+        b"vkCmdSetViewport" => cmd_set_viewport as *const c_void,
+        b"vkCmdSetViewportShadingRatePaletteNV" => {
+            cmd_set_viewport_shading_rate_palette_nv as *const c_void
+        }
+        b"vkCmdSetViewportSwizzleNV" => cmd_set_viewport_swizzle_nv as *const c_void,
+        b"vkCmdSetViewportWScalingEnableNV" => {
+            cmd_set_viewport_w_scaling_enable_nv as *const c_void
+        }
+        b"vkCmdSetViewportWScalingNV" => cmd_set_viewport_w_scaling_nv as *const c_void,
+        b"vkCmdSetViewportWithCount" => cmd_set_viewport_with_count as *const c_void,
+        b"vkCmdSetViewportWithCountEXT" => cmd_set_viewport_with_count as *const c_void,
+        b"vkCmdSubpassShadingHUAWEI" => cmd_subpass_shading_huawei as *const c_void,
+        b"vkCmdTraceRaysIndirect2KHR" => cmd_trace_rays_indirect2_khr as *const c_void,
+        b"vkCmdTraceRaysIndirectKHR" => cmd_trace_rays_indirect_khr as *const c_void,
+        b"vkCmdTraceRaysKHR" => cmd_trace_rays_khr as *const c_void,
+        b"vkCmdTraceRaysNV" => cmd_trace_rays_nv as *const c_void,
+        b"vkCmdUpdateBuffer" => cmd_update_buffer as *const c_void,
+        b"vkCmdWaitEvents" => cmd_wait_events as *const c_void,
+        b"vkCmdWaitEvents2" => cmd_wait_events2 as *const c_void,
+        b"vkCmdWaitEvents2KHR" => cmd_wait_events2 as *const c_void,
+        b"vkCmdWriteAccelerationStructuresPropertiesKHR" => {
+            cmd_write_acceleration_structures_properties_khr as *const c_void
+        }
+        b"vkCmdWriteAccelerationStructuresPropertiesNV" => {
+            cmd_write_acceleration_structures_properties_nv as *const c_void
+        }
+        b"vkCmdWriteBufferMarker2AMD" => cmd_write_buffer_marker2_amd as *const c_void,
+        b"vkCmdWriteBufferMarkerAMD" => cmd_write_buffer_marker_amd as *const c_void,
+        b"vkCmdWriteMicromapsPropertiesEXT" => cmd_write_micromaps_properties_ext as *const c_void,
+        b"vkCmdWriteTimestamp" => cmd_write_timestamp as *const c_void,
+        b"vkCmdWriteTimestamp2" => cmd_write_timestamp2 as *const c_void,
+        b"vkCmdWriteTimestamp2KHR" => cmd_write_timestamp2 as *const c_void,
+        b"vkCompileDeferredNV" => compile_deferred_nv as *const c_void,
+        b"vkCopyAccelerationStructureKHR" => copy_acceleration_structure_khr as *const c_void,
+        b"vkCopyAccelerationStructureToMemoryKHR" => {
+            copy_acceleration_structure_to_memory_khr as *const c_void
+        }
+        b"vkCopyMemoryToAccelerationStructureKHR" => {
+            copy_memory_to_acceleration_structure_khr as *const c_void
+        }
+        b"vkCopyMemoryToMicromapEXT" => copy_memory_to_micromap_ext as *const c_void,
+        b"vkCopyMicromapEXT" => copy_micromap_ext as *const c_void,
+        b"vkCopyMicromapToMemoryEXT" => copy_micromap_to_memory_ext as *const c_void,
+        b"vkCreateAccelerationStructureKHR" => create_acceleration_structure_khr as *const c_void,
+        b"vkCreateAccelerationStructureNV" => create_acceleration_structure_nv as *const c_void,
+        b"vkCreateAndroidSurfaceKHR" => create_android_surface_khr as *const c_void,
+        b"vkCreateBuffer" => create_buffer as *const c_void,
+        b"vkCreateBufferCollectionFUCHSIA" => create_buffer_collection_fuchsia as *const c_void,
+        b"vkCreateBufferView" => create_buffer_view as *const c_void,
+        b"vkCreateCommandPool" => create_command_pool as *const c_void,
+        b"vkCreateComputePipelines" => create_compute_pipelines as *const c_void,
+        b"vkCreateCuFunctionNVX" => create_cu_function_nvx as *const c_void,
+        b"vkCreateCuModuleNVX" => create_cu_module_nvx as *const c_void,
+        b"vkCreateDebugReportCallbackEXT" => create_debug_report_callback_ext as *const c_void,
+        b"vkCreateDebugUtilsMessengerEXT" => create_debug_utils_messenger_ext as *const c_void,
+        b"vkCreateDeferredOperationKHR" => create_deferred_operation_khr as *const c_void,
+        b"vkCreateDescriptorPool" => create_descriptor_pool as *const c_void,
+        b"vkCreateDescriptorSetLayout" => create_descriptor_set_layout as *const c_void,
+        b"vkCreateDescriptorUpdateTemplate" => create_descriptor_update_template as *const c_void,
+        b"vkCreateDescriptorUpdateTemplateKHR" => {
+            create_descriptor_update_template as *const c_void
+        }
+        b"vkCreateDevice" => create_device as *const c_void,
+        b"vkCreateDirectFBSurfaceEXT" => create_direct_fb_surface_ext as *const c_void,
+        b"vkCreateDisplayModeKHR" => create_display_mode_khr as *const c_void,
+        b"vkCreateDisplayPlaneSurfaceKHR" => create_display_plane_surface_khr as *const c_void,
+        b"vkCreateEvent" => create_event as *const c_void,
+        b"vkCreateFence" => create_fence as *const c_void,
+        b"vkCreateFramebuffer" => create_framebuffer as *const c_void,
+        b"vkCreateGraphicsPipelines" => create_graphics_pipelines as *const c_void,
+        b"vkCreateHeadlessSurfaceEXT" => create_headless_surface_ext as *const c_void,
+        b"vkCreateIOSSurfaceMVK" => create_ios_surface_mvk as *const c_void,
+        b"vkCreateImage" => create_image as *const c_void,
+        b"vkCreateImagePipeSurfaceFUCHSIA" => create_image_pipe_surface_fuchsia as *const c_void,
+        b"vkCreateImageView" => create_image_view as *const c_void,
+        b"vkCreateIndirectCommandsLayoutNV" => create_indirect_commands_layout_nv as *const c_void,
+        b"vkCreateInstance" => create_instance as *const c_void,
+        b"vkCreateMacOSSurfaceMVK" => create_mac_os_surface_mvk as *const c_void,
+        b"vkCreateMetalSurfaceEXT" => create_metal_surface_ext as *const c_void,
+        b"vkCreateMicromapEXT" => create_micromap_ext as *const c_void,
+        b"vkCreateOpticalFlowSessionNV" => create_optical_flow_session_nv as *const c_void,
+        b"vkCreatePipelineCache" => create_pipeline_cache as *const c_void,
+        b"vkCreatePipelineLayout" => create_pipeline_layout as *const c_void,
+        b"vkCreatePrivateDataSlot" => create_private_data_slot as *const c_void,
+        b"vkCreatePrivateDataSlotEXT" => create_private_data_slot as *const c_void,
+        b"vkCreateQueryPool" => create_query_pool as *const c_void,
+        b"vkCreateRayTracingPipelinesKHR" => create_ray_tracing_pipelines_khr as *const c_void,
+        b"vkCreateRayTracingPipelinesNV" => create_ray_tracing_pipelines_nv as *const c_void,
+        b"vkCreateRenderPass" => create_render_pass as *const c_void,
+        b"vkCreateRenderPass2" => create_render_pass2 as *const c_void,
+        b"vkCreateRenderPass2KHR" => create_render_pass2 as *const c_void,
+        b"vkCreateSampler" => create_sampler as *const c_void,
+        b"vkCreateSamplerYcbcrConversion" => create_sampler_ycbcr_conversion as *const c_void,
+        b"vkCreateSamplerYcbcrConversionKHR" => create_sampler_ycbcr_conversion as *const c_void,
+        b"vkCreateScreenSurfaceQNX" => create_screen_surface_qnx as *const c_void,
+        b"vkCreateSemaphore" => create_semaphore as *const c_void,
+        b"vkCreateShaderModule" => create_shader_module as *const c_void,
+        b"vkCreateShadersEXT" => create_shaders_ext as *const c_void,
+        b"vkCreateSharedSwapchainsKHR" => create_shared_swapchains_khr as *const c_void,
+        b"vkCreateStreamDescriptorSurfaceGGP" => {
+            create_stream_descriptor_surface_ggp as *const c_void
+        }
+        b"vkCreateSwapchainKHR" => create_swapchain_khr as *const c_void,
+        b"vkCreateValidationCacheEXT" => create_validation_cache_ext as *const c_void,
+        b"vkCreateViSurfaceNN" => create_vi_surface_nn as *const c_void,
+        b"vkCreateVideoSessionKHR" => create_video_session_khr as *const c_void,
+        b"vkCreateVideoSessionParametersKHR" => {
+            create_video_session_parameters_khr as *const c_void
+        }
+        b"vkCreateWaylandSurfaceKHR" => create_wayland_surface_khr as *const c_void,
+        b"vkCreateWin32SurfaceKHR" => create_win32_surface_khr as *const c_void,
+        b"vkCreateXcbSurfaceKHR" => create_xcb_surface_khr as *const c_void,
+        b"vkCreateXlibSurfaceKHR" => create_xlib_surface_khr as *const c_void,
+        b"vkDebugMarkerSetObjectNameEXT" => debug_marker_set_object_name_ext as *const c_void,
+        b"vkDebugMarkerSetObjectTagEXT" => debug_marker_set_object_tag_ext as *const c_void,
+        b"vkDebugReportMessageEXT" => debug_report_message_ext as *const c_void,
+        b"vkDeferredOperationJoinKHR" => deferred_operation_join_khr as *const c_void,
+        b"vkDestroyAccelerationStructureKHR" => destroy_acceleration_structure_khr as *const c_void,
+        b"vkDestroyAccelerationStructureNV" => destroy_acceleration_structure_nv as *const c_void,
+        b"vkDestroyBuffer" => destroy_buffer as *const c_void,
+        b"vkDestroyBufferCollectionFUCHSIA" => destroy_buffer_collection_fuchsia as *const c_void,
+        b"vkDestroyBufferView" => destroy_buffer_view as *const c_void,
+        b"vkDestroyCommandPool" => destroy_command_pool as *const c_void,
+        b"vkDestroyCuFunctionNVX" => destroy_cu_function_nvx as *const c_void,
+        b"vkDestroyCuModuleNVX" => destroy_cu_module_nvx as *const c_void,
+        b"vkDestroyDebugReportCallbackEXT" => destroy_debug_report_callback_ext as *const c_void,
+        b"vkDestroyDebugUtilsMessengerEXT" => destroy_debug_utils_messenger_ext as *const c_void,
+        b"vkDestroyDeferredOperationKHR" => destroy_deferred_operation_khr as *const c_void,
+        b"vkDestroyDescriptorPool" => destroy_descriptor_pool as *const c_void,
+        b"vkDestroyDescriptorSetLayout" => destroy_descriptor_set_layout as *const c_void,
+        b"vkDestroyDescriptorUpdateTemplate" => destroy_descriptor_update_template as *const c_void,
+        b"vkDestroyDescriptorUpdateTemplateKHR" => {
+            destroy_descriptor_update_template as *const c_void
+        }
+        b"vkDestroyDevice" => destroy_device as *const c_void,
+        b"vkDestroyEvent" => destroy_event as *const c_void,
+        b"vkDestroyFence" => destroy_fence as *const c_void,
+        b"vkDestroyFramebuffer" => destroy_framebuffer as *const c_void,
+        b"vkDestroyImage" => destroy_image as *const c_void,
+        b"vkDestroyImageView" => destroy_image_view as *const c_void,
+        b"vkDestroyIndirectCommandsLayoutNV" => {
+            destroy_indirect_commands_layout_nv as *const c_void
+        }
+        b"vkDestroyInstance" => destroy_instance as *const c_void,
+        b"vkDestroyMicromapEXT" => destroy_micromap_ext as *const c_void,
+        b"vkDestroyOpticalFlowSessionNV" => destroy_optical_flow_session_nv as *const c_void,
+        b"vkDestroyPipeline" => destroy_pipeline as *const c_void,
+        b"vkDestroyPipelineCache" => destroy_pipeline_cache as *const c_void,
+        b"vkDestroyPipelineLayout" => destroy_pipeline_layout as *const c_void,
+        b"vkDestroyPrivateDataSlot" => destroy_private_data_slot as *const c_void,
+        b"vkDestroyPrivateDataSlotEXT" => destroy_private_data_slot as *const c_void,
+        b"vkDestroyQueryPool" => destroy_query_pool as *const c_void,
+        b"vkDestroyRenderPass" => destroy_render_pass as *const c_void,
+        b"vkDestroySampler" => destroy_sampler as *const c_void,
+        b"vkDestroySamplerYcbcrConversion" => destroy_sampler_ycbcr_conversion as *const c_void,
+        b"vkDestroySamplerYcbcrConversionKHR" => destroy_sampler_ycbcr_conversion as *const c_void,
+        b"vkDestroySemaphore" => destroy_semaphore as *const c_void,
+        b"vkDestroyShaderEXT" => destroy_shader_ext as *const c_void,
+        b"vkDestroyShaderModule" => destroy_shader_module as *const c_void,
+        b"vkDestroySurfaceKHR" => destroy_surface_khr as *const c_void,
+        b"vkDestroySwapchainKHR" => destroy_swapchain_khr as *const c_void,
+        b"vkDestroyValidationCacheEXT" => destroy_validation_cache_ext as *const c_void,
+        b"vkDestroyVideoSessionKHR" => destroy_video_session_khr as *const c_void,
+        b"vkDestroyVideoSessionParametersKHR" => {
+            destroy_video_session_parameters_khr as *const c_void
+        }
+        b"vkDeviceWaitIdle" => device_wait_idle as *const c_void,
+        b"vkDisplayPowerControlEXT" => display_power_control_ext as *const c_void,
+        b"vkEndCommandBuffer" => end_command_buffer as *const c_void,
+        b"vkEnumerateDeviceExtensionProperties" => {
+            enumerate_device_extension_properties as *const c_void
+        }
+        b"vkEnumerateDeviceLayerProperties" => enumerate_device_layer_properties as *const c_void,
+        b"vkEnumerateInstanceExtensionProperties" => {
+            enumerate_instance_extension_properties as *const c_void
+        }
+        b"vkEnumerateInstanceLayerProperties" => {
+            enumerate_instance_layer_properties as *const c_void
+        }
+        b"vkEnumerateInstanceVersion" => enumerate_instance_version as *const c_void,
+        b"vkEnumeratePhysicalDeviceGroups" => enumerate_physical_device_groups as *const c_void,
+        b"vkEnumeratePhysicalDeviceGroupsKHR" => enumerate_physical_device_groups as *const c_void,
+        b"vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR" => {
+            enumerate_physical_device_queue_family_performance_query_counters_khr as *const c_void
+        }
+        b"vkEnumeratePhysicalDevices" => enumerate_physical_devices as *const c_void,
+        b"vkExportMetalObjectsEXT" => export_metal_objects_ext as *const c_void,
+        b"vkFlushMappedMemoryRanges" => flush_mapped_memory_ranges as *const c_void,
+        b"vkFreeCommandBuffers" => free_command_buffers as *const c_void,
+        b"vkFreeDescriptorSets" => free_descriptor_sets as *const c_void,
+        b"vkFreeMemory" => free_memory as *const c_void,
+        b"vkGetAccelerationStructureBuildSizesKHR" => {
+            get_acceleration_structure_build_sizes_khr as *const c_void
+        }
+        b"vkGetAccelerationStructureDeviceAddressKHR" => {
+            get_acceleration_structure_device_address_khr as *const c_void
+        }
+        b"vkGetAccelerationStructureHandleNV" => {
+            get_acceleration_structure_handle_nv as *const c_void
+        }
+        b"vkGetAccelerationStructureMemoryRequirementsNV" => {
+            get_acceleration_structure_memory_requirements_nv as *const c_void
+        }
+        b"vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT" => {
+            get_acceleration_structure_opaque_capture_descriptor_data_ext as *const c_void
+        }
+        b"vkGetAndroidHardwareBufferPropertiesANDROID" => {
+            get_android_hardware_buffer_properties_android as *const c_void
+        }
+        b"vkGetBufferCollectionPropertiesFUCHSIA" => {
+            get_buffer_collection_properties_fuchsia as *const c_void
+        }
+        b"vkGetBufferDeviceAddress" => get_buffer_device_address as *const c_void,
+        b"vkGetBufferDeviceAddressEXT" => get_buffer_device_address as *const c_void,
+        b"vkGetBufferDeviceAddressKHR" => get_buffer_device_address as *const c_void,
+        b"vkGetBufferMemoryRequirements" => get_buffer_memory_requirements as *const c_void,
+        b"vkGetBufferMemoryRequirements2" => get_buffer_memory_requirements2 as *const c_void,
+        b"vkGetBufferMemoryRequirements2KHR" => get_buffer_memory_requirements2 as *const c_void,
+        b"vkGetBufferOpaqueCaptureAddress" => get_buffer_opaque_capture_address as *const c_void,
+        b"vkGetBufferOpaqueCaptureAddressKHR" => get_buffer_opaque_capture_address as *const c_void,
+        b"vkGetBufferOpaqueCaptureDescriptorDataEXT" => {
+            get_buffer_opaque_capture_descriptor_data_ext as *const c_void
+        }
+        b"vkGetCalibratedTimestampsEXT" => get_calibrated_timestamps_ext as *const c_void,
+        b"vkGetDeferredOperationMaxConcurrencyKHR" => {
+            get_deferred_operation_max_concurrency_khr as *const c_void
+        }
+        b"vkGetDeferredOperationResultKHR" => get_deferred_operation_result_khr as *const c_void,
+        b"vkGetDescriptorEXT" => get_descriptor_ext as *const c_void,
+        b"vkGetDescriptorSetHostMappingVALVE" => {
+            get_descriptor_set_host_mapping_valve as *const c_void
+        }
+        b"vkGetDescriptorSetLayoutBindingOffsetEXT" => {
+            get_descriptor_set_layout_binding_offset_ext as *const c_void
+        }
+        b"vkGetDescriptorSetLayoutHostMappingInfoVALVE" => {
+            get_descriptor_set_layout_host_mapping_info_valve as *const c_void
+        }
+        b"vkGetDescriptorSetLayoutSizeEXT" => get_descriptor_set_layout_size_ext as *const c_void,
+        b"vkGetDescriptorSetLayoutSupport" => get_descriptor_set_layout_support as *const c_void,
+        b"vkGetDescriptorSetLayoutSupportKHR" => get_descriptor_set_layout_support as *const c_void,
+        b"vkGetDeviceAccelerationStructureCompatibilityKHR" => {
+            get_device_acceleration_structure_compatibility_khr as *const c_void
+        }
+        b"vkGetDeviceBufferMemoryRequirements" => {
+            get_device_buffer_memory_requirements as *const c_void
+        }
+        b"vkGetDeviceBufferMemoryRequirementsKHR" => {
+            get_device_buffer_memory_requirements as *const c_void
+        }
+        b"vkGetDeviceFaultInfoEXT" => get_device_fault_info_ext as *const c_void,
+        b"vkGetDeviceGroupPeerMemoryFeatures" => {
+            get_device_group_peer_memory_features as *const c_void
+        }
+        b"vkGetDeviceGroupPeerMemoryFeaturesKHR" => {
+            get_device_group_peer_memory_features as *const c_void
+        }
+        b"vkGetDeviceGroupPresentCapabilitiesKHR" => {
+            get_device_group_present_capabilities_khr as *const c_void
+        }
+        b"vkGetDeviceGroupSurfacePresentModes2EXT" => {
+            get_device_group_surface_present_modes2_ext as *const c_void
+        }
+        b"vkGetDeviceGroupSurfacePresentModesKHR" => {
+            get_device_group_surface_present_modes_khr as *const c_void
+        }
+        b"vkGetDeviceImageMemoryRequirements" => {
+            get_device_image_memory_requirements as *const c_void
+        }
+        b"vkGetDeviceImageMemoryRequirementsKHR" => {
+            get_device_image_memory_requirements as *const c_void
+        }
+        b"vkGetDeviceImageSparseMemoryRequirements" => {
+            get_device_image_sparse_memory_requirements as *const c_void
+        }
+        b"vkGetDeviceImageSparseMemoryRequirementsKHR" => {
+            get_device_image_sparse_memory_requirements as *const c_void
+        }
+        b"vkGetDeviceMemoryCommitment" => get_device_memory_commitment as *const c_void,
+        b"vkGetDeviceMemoryOpaqueCaptureAddress" => {
+            get_device_memory_opaque_capture_address as *const c_void
+        }
+        b"vkGetDeviceMemoryOpaqueCaptureAddressKHR" => {
+            get_device_memory_opaque_capture_address as *const c_void
+        }
+        b"vkGetDeviceMicromapCompatibilityEXT" => {
+            get_device_micromap_compatibility_ext as *const c_void
+        }
+        b"vkGetDeviceProcAddr" => get_device_proc_addr as *const c_void,
+        b"vkGetDeviceQueue" => get_device_queue as *const c_void,
+        b"vkGetDeviceQueue2" => get_device_queue2 as *const c_void,
+        b"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI" => {
+            get_device_subpass_shading_max_workgroup_size_huawei as *const c_void
+        }
+        b"vkGetDisplayModeProperties2KHR" => get_display_mode_properties2_khr as *const c_void,
+        b"vkGetDisplayModePropertiesKHR" => get_display_mode_properties_khr as *const c_void,
+        b"vkGetDisplayPlaneCapabilities2KHR" => {
+            get_display_plane_capabilities2_khr as *const c_void
+        }
+        b"vkGetDisplayPlaneCapabilitiesKHR" => get_display_plane_capabilities_khr as *const c_void,
+        b"vkGetDisplayPlaneSupportedDisplaysKHR" => {
+            get_display_plane_supported_displays_khr as *const c_void
+        }
+        b"vkGetDrmDisplayEXT" => get_drm_display_ext as *const c_void,
+        b"vkGetDynamicRenderingTilePropertiesQCOM" => {
+            get_dynamic_rendering_tile_properties_qcom as *const c_void
+        }
+        b"vkGetEncodedVideoSessionParametersKHR" => {
+            get_encoded_video_session_parameters_khr as *const c_void
+        }
+        b"vkGetEventStatus" => get_event_status as *const c_void,
+        b"vkGetFenceFdKHR" => get_fence_fd_khr as *const c_void,
+        b"vkGetFenceStatus" => get_fence_status as *const c_void,
+        b"vkGetFenceWin32HandleKHR" => get_fence_win32_handle_khr as *const c_void,
+        b"vkGetFramebufferTilePropertiesQCOM" => {
+            get_framebuffer_tile_properties_qcom as *const c_void
+        }
+        b"vkGetGeneratedCommandsMemoryRequirementsNV" => {
+            get_generated_commands_memory_requirements_nv as *const c_void
+        }
+        b"vkGetImageDrmFormatModifierPropertiesEXT" => {
+            get_image_drm_format_modifier_properties_ext as *const c_void
+        }
+        b"vkGetImageMemoryRequirements" => get_image_memory_requirements as *const c_void,
+        b"vkGetImageMemoryRequirements2" => get_image_memory_requirements2 as *const c_void,
+        b"vkGetImageMemoryRequirements2KHR" => get_image_memory_requirements2 as *const c_void,
+        b"vkGetImageOpaqueCaptureDescriptorDataEXT" => {
+            get_image_opaque_capture_descriptor_data_ext as *const c_void
+        }
+        b"vkGetImageSparseMemoryRequirements" => {
+            get_image_sparse_memory_requirements as *const c_void
+        }
+        b"vkGetImageSparseMemoryRequirements2" => {
+            get_image_sparse_memory_requirements2 as *const c_void
+        }
+        b"vkGetImageSparseMemoryRequirements2KHR" => {
+            get_image_sparse_memory_requirements2 as *const c_void
+        }
+        b"vkGetImageSubresourceLayout" => get_image_subresource_layout as *const c_void,
+        b"vkGetImageSubresourceLayout2EXT" => get_image_subresource_layout2_ext as *const c_void,
+        b"vkGetImageViewAddressNVX" => get_image_view_address_nvx as *const c_void,
+        b"vkGetImageViewHandleNVX" => get_image_view_handle_nvx as *const c_void,
+        b"vkGetImageViewOpaqueCaptureDescriptorDataEXT" => {
+            get_image_view_opaque_capture_descriptor_data_ext as *const c_void
+        }
+        b"vkGetInstanceProcAddr" => get_instance_proc_addr as *const c_void,
+        b"vkGetMemoryAndroidHardwareBufferANDROID" => {
+            get_memory_android_hardware_buffer_android as *const c_void
+        }
+        b"vkGetMemoryFdKHR" => get_memory_fd_khr as *const c_void,
+        b"vkGetMemoryFdPropertiesKHR" => get_memory_fd_properties_khr as *const c_void,
+        b"vkGetMemoryHostPointerPropertiesEXT" => {
+            get_memory_host_pointer_properties_ext as *const c_void
+        }
+        b"vkGetMemoryRemoteAddressNV" => get_memory_remote_address_nv as *const c_void,
+        b"vkGetMemoryWin32HandleKHR" => get_memory_win32_handle_khr as *const c_void,
+        b"vkGetMemoryWin32HandleNV" => get_memory_win32_handle_nv as *const c_void,
+        b"vkGetMemoryWin32HandlePropertiesKHR" => {
+            get_memory_win32_handle_properties_khr as *const c_void
+        }
+        b"vkGetMemoryZirconHandleFUCHSIA" => get_memory_zircon_handle_fuchsia as *const c_void,
+        b"vkGetMemoryZirconHandlePropertiesFUCHSIA" => {
+            get_memory_zircon_handle_properties_fuchsia as *const c_void
+        }
+        b"vkGetMicromapBuildSizesEXT" => get_micromap_build_sizes_ext as *const c_void,
+        b"vkGetPastPresentationTimingGOOGLE" => {
+            get_past_presentation_timing_google as *const c_void
+        }
+        b"vkGetPerformanceParameterINTEL" => get_performance_parameter_intel as *const c_void,
+        b"vkGetPhysicalDeviceCalibrateableTimeDomainsEXT" => {
+            get_physical_device_calibrateable_time_domains_ext as *const c_void
+        }
+        b"vkGetPhysicalDeviceCooperativeMatrixPropertiesNV" => {
+            get_physical_device_cooperative_matrix_properties_nv as *const c_void
+        }
+        b"vkGetPhysicalDeviceDirectFBPresentationSupportEXT" => {
+            get_physical_device_direct_fb_presentation_support_ext as *const c_void
+        }
+        b"vkGetPhysicalDeviceDisplayPlaneProperties2KHR" => {
+            get_physical_device_display_plane_properties2_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceDisplayPlanePropertiesKHR" => {
+            get_physical_device_display_plane_properties_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceDisplayProperties2KHR" => {
+            get_physical_device_display_properties2_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceDisplayPropertiesKHR" => {
+            get_physical_device_display_properties_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceExternalBufferProperties" => {
+            get_physical_device_external_buffer_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceExternalBufferPropertiesKHR" => {
+            get_physical_device_external_buffer_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceExternalFenceProperties" => {
+            get_physical_device_external_fence_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceExternalFencePropertiesKHR" => {
+            get_physical_device_external_fence_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceExternalImageFormatPropertiesNV" => {
+            get_physical_device_external_image_format_properties_nv as *const c_void
+        }
+        b"vkGetPhysicalDeviceExternalSemaphoreProperties" => {
+            get_physical_device_external_semaphore_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceExternalSemaphorePropertiesKHR" => {
+            get_physical_device_external_semaphore_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceFeatures" => get_physical_device_features as *const c_void,
+        b"vkGetPhysicalDeviceFeatures2" => get_physical_device_features2 as *const c_void,
+        b"vkGetPhysicalDeviceFeatures2KHR" => get_physical_device_features2 as *const c_void,
+        b"vkGetPhysicalDeviceFormatProperties" => {
+            get_physical_device_format_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceFormatProperties2" => {
+            get_physical_device_format_properties2 as *const c_void
+        }
+        b"vkGetPhysicalDeviceFormatProperties2KHR" => {
+            get_physical_device_format_properties2 as *const c_void
+        }
+        b"vkGetPhysicalDeviceFragmentShadingRatesKHR" => {
+            get_physical_device_fragment_shading_rates_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceImageFormatProperties" => {
+            get_physical_device_image_format_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceImageFormatProperties2" => {
+            get_physical_device_image_format_properties2 as *const c_void
+        }
+        b"vkGetPhysicalDeviceImageFormatProperties2KHR" => {
+            get_physical_device_image_format_properties2 as *const c_void
+        }
+        b"vkGetPhysicalDeviceMemoryProperties" => {
+            get_physical_device_memory_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceMemoryProperties2" => {
+            get_physical_device_memory_properties2 as *const c_void
+        }
+        b"vkGetPhysicalDeviceMemoryProperties2KHR" => {
+            get_physical_device_memory_properties2 as *const c_void
+        }
+        b"vkGetPhysicalDeviceMultisamplePropertiesEXT" => {
+            get_physical_device_multisample_properties_ext as *const c_void
+        }
+        b"vkGetPhysicalDeviceOpticalFlowImageFormatsNV" => {
+            get_physical_device_optical_flow_image_formats_nv as *const c_void
+        }
+        b"vkGetPhysicalDevicePresentRectanglesKHR" => {
+            get_physical_device_present_rectangles_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceProperties" => get_physical_device_properties as *const c_void,
+        b"vkGetPhysicalDeviceProperties2" => get_physical_device_properties2 as *const c_void,
+        b"vkGetPhysicalDeviceProperties2KHR" => get_physical_device_properties2 as *const c_void,
+        b"vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR" => {
+            get_physical_device_queue_family_performance_query_passes_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceQueueFamilyProperties" => {
+            get_physical_device_queue_family_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceQueueFamilyProperties2" => {
+            get_physical_device_queue_family_properties2 as *const c_void
+        }
+        b"vkGetPhysicalDeviceQueueFamilyProperties2KHR" => {
+            get_physical_device_queue_family_properties2 as *const c_void
+        }
+        b"vkGetPhysicalDeviceScreenPresentationSupportQNX" => {
+            get_physical_device_screen_presentation_support_qnx as *const c_void
+        }
+        b"vkGetPhysicalDeviceSparseImageFormatProperties" => {
+            get_physical_device_sparse_image_format_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceSparseImageFormatProperties2" => {
+            get_physical_device_sparse_image_format_properties2 as *const c_void
+        }
+        b"vkGetPhysicalDeviceSparseImageFormatProperties2KHR" => {
+            get_physical_device_sparse_image_format_properties2 as *const c_void
+        }
+        b"vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV" => {
+            get_physical_device_supported_framebuffer_mixed_samples_combinations_nv as *const c_void
+        }
+        b"vkGetPhysicalDeviceSurfaceCapabilities2EXT" => {
+            get_physical_device_surface_capabilities2_ext as *const c_void
+        }
+        b"vkGetPhysicalDeviceSurfaceCapabilities2KHR" => {
+            get_physical_device_surface_capabilities2_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => {
+            get_physical_device_surface_capabilities_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceSurfaceFormats2KHR" => {
+            get_physical_device_surface_formats2_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceSurfaceFormatsKHR" => {
+            get_physical_device_surface_formats_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceSurfacePresentModes2EXT" => {
+            get_physical_device_surface_present_modes2_ext as *const c_void
+        }
+        b"vkGetPhysicalDeviceSurfacePresentModesKHR" => {
+            get_physical_device_surface_present_modes_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceSurfaceSupportKHR" => {
+            get_physical_device_surface_support_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceToolProperties" => {
+            get_physical_device_tool_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceToolPropertiesEXT" => {
+            get_physical_device_tool_properties as *const c_void
+        }
+        b"vkGetPhysicalDeviceVideoCapabilitiesKHR" => {
+            get_physical_device_video_capabilities_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR" => {
+            get_physical_device_video_encode_quality_level_properties_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceVideoFormatPropertiesKHR" => {
+            get_physical_device_video_format_properties_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceWaylandPresentationSupportKHR" => {
+            get_physical_device_wayland_presentation_support_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceWin32PresentationSupportKHR" => {
+            get_physical_device_win32_presentation_support_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceXcbPresentationSupportKHR" => {
+            get_physical_device_xcb_presentation_support_khr as *const c_void
+        }
+        b"vkGetPhysicalDeviceXlibPresentationSupportKHR" => {
+            get_physical_device_xlib_presentation_support_khr as *const c_void
+        }
+        b"vkGetPipelineCacheData" => get_pipeline_cache_data as *const c_void,
+        b"vkGetPipelineExecutableInternalRepresentationsKHR" => {
+            get_pipeline_executable_internal_representations_khr as *const c_void
+        }
+        b"vkGetPipelineExecutablePropertiesKHR" => {
+            get_pipeline_executable_properties_khr as *const c_void
+        }
+        b"vkGetPipelineExecutableStatisticsKHR" => {
+            get_pipeline_executable_statistics_khr as *const c_void
+        }
+        b"vkGetPipelinePropertiesEXT" => get_pipeline_properties_ext as *const c_void,
+        b"vkGetPrivateData" => get_private_data as *const c_void,
+        b"vkGetPrivateDataEXT" => get_private_data as *const c_void,
+        b"vkGetQueryPoolResults" => get_query_pool_results as *const c_void,
+        b"vkGetQueueCheckpointData2NV" => get_queue_checkpoint_data2_nv as *const c_void,
+        b"vkGetQueueCheckpointDataNV" => get_queue_checkpoint_data_nv as *const c_void,
+        b"vkGetRandROutputDisplayEXT" => get_rand_r_output_display_ext as *const c_void,
+        b"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR" => {
+            get_ray_tracing_capture_replay_shader_group_handles_khr as *const c_void
+        }
+        b"vkGetRayTracingShaderGroupHandlesKHR" => {
+            get_ray_tracing_shader_group_handles_khr as *const c_void
+        }
+        b"vkGetRayTracingShaderGroupHandlesNV" => {
+            get_ray_tracing_shader_group_handles_khr as *const c_void
+        }
+        b"vkGetRayTracingShaderGroupStackSizeKHR" => {
+            get_ray_tracing_shader_group_stack_size_khr as *const c_void
+        }
+        b"vkGetRefreshCycleDurationGOOGLE" => get_refresh_cycle_duration_google as *const c_void,
+        b"vkGetRenderAreaGranularity" => get_render_area_granularity as *const c_void,
+        b"vkGetSamplerOpaqueCaptureDescriptorDataEXT" => {
+            get_sampler_opaque_capture_descriptor_data_ext as *const c_void
+        }
+        b"vkGetScreenBufferPropertiesQNX" => get_screen_buffer_properties_qnx as *const c_void,
+        b"vkGetSemaphoreCounterValue" => get_semaphore_counter_value as *const c_void,
+        b"vkGetSemaphoreCounterValueKHR" => get_semaphore_counter_value as *const c_void,
+        b"vkGetSemaphoreFdKHR" => get_semaphore_fd_khr as *const c_void,
+        b"vkGetSemaphoreWin32HandleKHR" => get_semaphore_win32_handle_khr as *const c_void,
+        b"vkGetSemaphoreZirconHandleFUCHSIA" => {
+            get_semaphore_zircon_handle_fuchsia as *const c_void
+        }
+        b"vkGetShaderBinaryDataEXT" => get_shader_binary_data_ext as *const c_void,
+        b"vkGetShaderInfoAMD" => get_shader_info_amd as *const c_void,
+        b"vkGetShaderModuleCreateInfoIdentifierEXT" => {
+            get_shader_module_create_info_identifier_ext as *const c_void
+        }
+        b"vkGetShaderModuleIdentifierEXT" => get_shader_module_identifier_ext as *const c_void,
+        b"vkGetSwapchainCounterEXT" => get_swapchain_counter_ext as *const c_void,
+        b"vkGetSwapchainGrallocUsage2ANDROID" => {
+            get_swapchain_gralloc_usage2_android as *const c_void
+        }
+        b"vkGetSwapchainGrallocUsageANDROID" => {
+            get_swapchain_gralloc_usage_android as *const c_void
+        }
+        b"vkGetSwapchainImagesKHR" => get_swapchain_images_khr as *const c_void,
+        b"vkGetSwapchainStatusKHR" => get_swapchain_status_khr as *const c_void,
+        b"vkGetValidationCacheDataEXT" => get_validation_cache_data_ext as *const c_void,
+        b"vkGetVideoSessionMemoryRequirementsKHR" => {
+            get_video_session_memory_requirements_khr as *const c_void
+        }
+        b"vkGetWinrtDisplayNV" => get_winrt_display_nv as *const c_void,
+        b"vkImportFenceFdKHR" => import_fence_fd_khr as *const c_void,
+        b"vkImportFenceWin32HandleKHR" => import_fence_win32_handle_khr as *const c_void,
+        b"vkImportSemaphoreFdKHR" => import_semaphore_fd_khr as *const c_void,
+        b"vkImportSemaphoreWin32HandleKHR" => import_semaphore_win32_handle_khr as *const c_void,
+        b"vkImportSemaphoreZirconHandleFUCHSIA" => {
+            import_semaphore_zircon_handle_fuchsia as *const c_void
+        }
+        b"vkInitializePerformanceApiINTEL" => initialize_performance_api_intel as *const c_void,
+        b"vkInvalidateMappedMemoryRanges" => invalidate_mapped_memory_ranges as *const c_void,
+        b"vkMapMemory" => map_memory as *const c_void,
+        b"vkMapMemory2KHR" => map_memory2_khr as *const c_void,
+        b"vkMergePipelineCaches" => merge_pipeline_caches as *const c_void,
+        b"vkMergeValidationCachesEXT" => merge_validation_caches_ext as *const c_void,
+        b"vkQueueBeginDebugUtilsLabelEXT" => queue_begin_debug_utils_label_ext as *const c_void,
+        b"vkQueueBindSparse" => queue_bind_sparse as *const c_void,
+        b"vkQueueEndDebugUtilsLabelEXT" => queue_end_debug_utils_label_ext as *const c_void,
+        b"vkQueueInsertDebugUtilsLabelEXT" => queue_insert_debug_utils_label_ext as *const c_void,
+        b"vkQueuePresentKHR" => queue_present_khr as *const c_void,
+        b"vkQueueSetPerformanceConfigurationINTEL" => {
+            queue_set_performance_configuration_intel as *const c_void
+        }
+        b"vkQueueSignalReleaseImageANDROID" => queue_signal_release_image_android as *const c_void,
+        b"vkQueueSubmit" => queue_submit as *const c_void,
+        b"vkQueueSubmit2" => queue_submit2 as *const c_void,
+        b"vkQueueSubmit2KHR" => queue_submit2 as *const c_void,
+        b"vkQueueWaitIdle" => queue_wait_idle as *const c_void,
+        b"vkRegisterDeviceEventEXT" => register_device_event_ext as *const c_void,
+        b"vkRegisterDisplayEventEXT" => register_display_event_ext as *const c_void,
+        b"vkReleaseDisplayEXT" => release_display_ext as *const c_void,
+        b"vkReleaseFullScreenExclusiveModeEXT" => {
+            release_full_screen_exclusive_mode_ext as *const c_void
+        }
+        b"vkReleasePerformanceConfigurationINTEL" => {
+            release_performance_configuration_intel as *const c_void
+        }
+        b"vkReleaseProfilingLockKHR" => release_profiling_lock_khr as *const c_void,
+        b"vkReleaseSwapchainImagesEXT" => release_swapchain_images_ext as *const c_void,
+        b"vkResetCommandBuffer" => reset_command_buffer as *const c_void,
+        b"vkResetCommandPool" => reset_command_pool as *const c_void,
+        b"vkResetDescriptorPool" => reset_descriptor_pool as *const c_void,
+        b"vkResetEvent" => reset_event as *const c_void,
+        b"vkResetFences" => reset_fences as *const c_void,
+        b"vkResetQueryPool" => reset_query_pool as *const c_void,
+        b"vkResetQueryPoolEXT" => reset_query_pool as *const c_void,
+        b"vkSetBufferCollectionBufferConstraintsFUCHSIA" => {
+            set_buffer_collection_buffer_constraints_fuchsia as *const c_void
+        }
+        b"vkSetBufferCollectionImageConstraintsFUCHSIA" => {
+            set_buffer_collection_image_constraints_fuchsia as *const c_void
+        }
+        b"vkSetDebugUtilsObjectNameEXT" => set_debug_utils_object_name_ext as *const c_void,
+        b"vkSetDebugUtilsObjectTagEXT" => set_debug_utils_object_tag_ext as *const c_void,
+        b"vkSetDeviceMemoryPriorityEXT" => set_device_memory_priority_ext as *const c_void,
+        b"vkSetEvent" => set_event as *const c_void,
+        b"vkSetHdrMetadataEXT" => set_hdr_metadata_ext as *const c_void,
+        b"vkSetLocalDimmingAMD" => set_local_dimming_amd as *const c_void,
+        b"vkSetPrivateData" => set_private_data as *const c_void,
+        b"vkSetPrivateDataEXT" => set_private_data as *const c_void,
+        b"vkSignalSemaphore" => signal_semaphore as *const c_void,
+        b"vkSignalSemaphoreKHR" => signal_semaphore as *const c_void,
+        b"vkSubmitDebugUtilsMessageEXT" => submit_debug_utils_message_ext as *const c_void,
+        b"vkTrimCommandPool" => trim_command_pool as *const c_void,
+        b"vkTrimCommandPoolKHR" => trim_command_pool as *const c_void,
+        b"vkUninitializePerformanceApiINTEL" => uninitialize_performance_api_intel as *const c_void,
+        b"vkUnmapMemory" => unmap_memory as *const c_void,
+        b"vkUnmapMemory2KHR" => unmap_memory2_khr as *const c_void,
+        b"vkUpdateDescriptorSetWithTemplate" => {
+            update_descriptor_set_with_template as *const c_void
+        }
+        b"vkUpdateDescriptorSetWithTemplateKHR" => {
+            update_descriptor_set_with_template as *const c_void
+        }
+        b"vkUpdateDescriptorSets" => update_descriptor_sets as *const c_void,
+        b"vkUpdateVideoSessionParametersKHR" => {
+            update_video_session_parameters_khr as *const c_void
+        }
+        b"vkWaitForFences" => wait_for_fences as *const c_void,
+        b"vkWaitForPresentKHR" => wait_for_present_khr as *const c_void,
+        b"vkWaitSemaphores" => wait_semaphores as *const c_void,
+        b"vkWaitSemaphoresKHR" => wait_semaphores as *const c_void,
+        b"vkWriteAccelerationStructuresPropertiesKHR" => {
+            write_acceleration_structures_properties_khr as *const c_void
+        }
+        b"vkWriteMicromapsPropertiesEXT" => write_micromaps_properties_ext as *const c_void,
+        _ => return None,
+    };
+    Some(unsafe { mem::transmute::<*const c_void, unsafe extern "system" fn()>(f) })
+}
+pub(super) unsafe extern "system" fn get_instance_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    MockDriver::of(instance.as_raw())?;
+    lookup(CStr::from_ptr(p_name).to_bytes())
+}
+unsafe extern "system" fn get_device_proc_addr(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    MockDriver::of(device.as_raw())?;
+    lookup(CStr::from_ptr(p_name).to_bytes())
+}
+type AcquireDrmDisplayEXTFn = dyn FnMut(vk::PhysicalDevice, i32, vk::DisplayKHR) -> vk::Result;
+unsafe extern "system" fn acquire_drm_display_ext(
+    physical_device: vk::PhysicalDevice,
+    drm_fd: i32,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    dispatch::<AcquireDrmDisplayEXTFn, _>(
+        "vkAcquireDrmDisplayEXT",
+        &[physical_device.as_raw(), display.as_raw()],
+        |f| f(physical_device, drm_fd, display),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAcquireDrmDisplayEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireDrmDisplayEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_acquire_drm_display_ext(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, i32, vk::DisplayKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<AcquireDrmDisplayEXTFn>("vkAcquireDrmDisplayEXT", Box::new(f))
+    }
+}
+type AcquireFullScreenExclusiveModeEXTFn = dyn FnMut(vk::Device, vk::SwapchainKHR) -> vk::Result;
+unsafe extern "system" fn acquire_full_screen_exclusive_mode_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    dispatch::<AcquireFullScreenExclusiveModeEXTFn, _>(
+        "vkAcquireFullScreenExclusiveModeEXT",
+        &[device.as_raw(), swapchain.as_raw()],
+        |f| f(device, swapchain),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAcquireFullScreenExclusiveModeEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireFullScreenExclusiveModeEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_acquire_full_screen_exclusive_mode_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::SwapchainKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<AcquireFullScreenExclusiveModeEXTFn>(
+            "vkAcquireFullScreenExclusiveModeEXT",
+            Box::new(f),
+        )
+    }
+}
+type AcquireImageANDROIDFn =
+    dyn FnMut(vk::Device, vk::Image, c_int, vk::Semaphore, vk::Fence) -> vk::Result;
+unsafe extern "system" fn acquire_image_android(
+    device: vk::Device,
+    image: vk::Image,
+    native_fence_fd: c_int,
+    semaphore: vk::Semaphore,
+    fence: vk::Fence,
+) -> vk::Result {
+    dispatch::<AcquireImageANDROIDFn, _>(
+        "vkAcquireImageANDROID",
+        &[
+            device.as_raw(),
+            image.as_raw(),
+            semaphore.as_raw(),
+            fence.as_raw(),
+        ],
+        |f| f(device, image, native_fence_fd, semaphore, fence),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAcquireImageANDROID`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireImageANDROID.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_acquire_image_android(
+        &self,
+        f: impl FnMut(vk::Device, vk::Image, c_int, vk::Semaphore, vk::Fence) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<AcquireImageANDROIDFn>("vkAcquireImageANDROID", Box::new(f))
+    }
+}
+type AcquireNextImage2KHRFn =
+    dyn FnMut(vk::Device, *const vk::AcquireNextImageInfoKHR<'_>, *mut u32) -> vk::Result;
+unsafe extern "system" fn acquire_next_image2_khr(
+    device: vk::Device,
+    p_acquire_info: *const vk::AcquireNextImageInfoKHR<'_>,
+    p_image_index: *mut u32,
+) -> vk::Result {
+    dispatch::<AcquireNextImage2KHRFn, _>(
+        "vkAcquireNextImage2KHR",
+        &[device.as_raw()],
+        |f| f(device, p_acquire_info, p_image_index),
+        |_| {
+            if !p_image_index.is_null() {
+                ptr::write_bytes(p_image_index, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAcquireNextImage2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireNextImage2KHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_acquire_next_image2_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::AcquireNextImageInfoKHR<'_>, *mut u32) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<AcquireNextImage2KHRFn>("vkAcquireNextImage2KHR", Box::new(f))
+    }
+}
+type AcquireNextImageKHRFn =
+    dyn FnMut(vk::Device, vk::SwapchainKHR, u64, vk::Semaphore, vk::Fence, *mut u32) -> vk::Result;
+unsafe extern "system" fn acquire_next_image_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    timeout: u64,
+    semaphore: vk::Semaphore,
+    fence: vk::Fence,
+    p_image_index: *mut u32,
+) -> vk::Result {
+    dispatch::<AcquireNextImageKHRFn, _>(
+        "vkAcquireNextImageKHR",
+        &[
+            device.as_raw(),
+            swapchain.as_raw(),
+            semaphore.as_raw(),
+            fence.as_raw(),
+        ],
+        |f| f(device, swapchain, timeout, semaphore, fence, p_image_index),
+        |_| {
+            if !p_image_index.is_null() {
+                ptr::write_bytes(p_image_index, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAcquireNextImageKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireNextImageKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_acquire_next_image_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::SwapchainKHR,
+                u64,
+                vk::Semaphore,
+                vk::Fence,
+                *mut u32,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<AcquireNextImageKHRFn>("vkAcquireNextImageKHR", Box::new(f))
+    }
+}
+type AcquirePerformanceConfigurationINTELFn = dyn FnMut(
+    vk::Device,
+    *const vk::PerformanceConfigurationAcquireInfoINTEL<'_>,
+    *mut vk::PerformanceConfigurationINTEL,
+) -> vk::Result;
+unsafe extern "system" fn acquire_performance_configuration_intel(
+    device: vk::Device,
+    p_acquire_info: *const vk::PerformanceConfigurationAcquireInfoINTEL<'_>,
+    p_configuration: *mut vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    dispatch::<AcquirePerformanceConfigurationINTELFn, _>(
+        "vkAcquirePerformanceConfigurationINTEL",
+        &[device.as_raw()],
+        |f| f(device, p_acquire_info, p_configuration),
+        |driver| {
+            if !p_configuration.is_null() {
+                *p_configuration = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAcquirePerformanceConfigurationINTEL`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquirePerformanceConfigurationINTEL.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_acquire_performance_configuration_intel(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::PerformanceConfigurationAcquireInfoINTEL<'_>,
+                *mut vk::PerformanceConfigurationINTEL,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<AcquirePerformanceConfigurationINTELFn>(
+            "vkAcquirePerformanceConfigurationINTEL",
+            Box::new(f),
+        )
+    }
+}
+type AcquireProfilingLockKHRFn =
+    dyn FnMut(vk::Device, *const vk::AcquireProfilingLockInfoKHR<'_>) -> vk::Result;
+unsafe extern "system" fn acquire_profiling_lock_khr(
+    device: vk::Device,
+    p_info: *const vk::AcquireProfilingLockInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<AcquireProfilingLockKHRFn, _>(
+        "vkAcquireProfilingLockKHR",
+        &[device.as_raw()],
+        |f| f(device, p_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAcquireProfilingLockKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireProfilingLockKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_acquire_profiling_lock_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::AcquireProfilingLockInfoKHR<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<AcquireProfilingLockKHRFn>("vkAcquireProfilingLockKHR", Box::new(f))
+    }
+}
+type AcquireWinrtDisplayNVFn = dyn FnMut(vk::PhysicalDevice, vk::DisplayKHR) -> vk::Result;
+unsafe extern "system" fn acquire_winrt_display_nv(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    dispatch::<AcquireWinrtDisplayNVFn, _>(
+        "vkAcquireWinrtDisplayNV",
+        &[physical_device.as_raw(), display.as_raw()],
+        |f| f(physical_device, display),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAcquireWinrtDisplayNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireWinrtDisplayNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_acquire_winrt_display_nv(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, vk::DisplayKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<AcquireWinrtDisplayNVFn>("vkAcquireWinrtDisplayNV", Box::new(f))
+    }
+}
+type AcquireXlibDisplayEXTFn =
+    dyn FnMut(vk::PhysicalDevice, *mut vk::Display, vk::DisplayKHR) -> vk::Result;
+unsafe extern "system" fn acquire_xlib_display_ext(
+    physical_device: vk::PhysicalDevice,
+    dpy: *mut vk::Display,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    dispatch::<AcquireXlibDisplayEXTFn, _>(
+        "vkAcquireXlibDisplayEXT",
+        &[physical_device.as_raw(), display.as_raw()],
+        |f| f(physical_device, dpy, display),
+        |_| {
+            if !dpy.is_null() {
+                ptr::write_bytes(dpy, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAcquireXlibDisplayEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireXlibDisplayEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_acquire_xlib_display_ext(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut vk::Display, vk::DisplayKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<AcquireXlibDisplayEXTFn>("vkAcquireXlibDisplayEXT", Box::new(f))
+    }
+}
+type AllocateCommandBuffersFn = dyn FnMut(
+    vk::Device,
+    *const vk::CommandBufferAllocateInfo<'_>,
+    *mut vk::CommandBuffer,
+) -> vk::Result;
+unsafe extern "system" fn allocate_command_buffers(
+    device: vk::Device,
+    p_allocate_info: *const vk::CommandBufferAllocateInfo<'_>,
+    p_command_buffers: *mut vk::CommandBuffer,
+) -> vk::Result {
+    dispatch::<AllocateCommandBuffersFn, _>(
+        "vkAllocateCommandBuffers",
+        &[device.as_raw()],
+        |f| f(device, p_allocate_info, p_command_buffers),
+        |driver| {
+            if !p_command_buffers.is_null() {
+                for i in 0..(*p_allocate_info).command_buffer_count as usize {
+                    *p_command_buffers.add(i) = driver.allocate();
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAllocateCommandBuffers`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAllocateCommandBuffers.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_allocate_command_buffers(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::CommandBufferAllocateInfo<'_>,
+                *mut vk::CommandBuffer,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<AllocateCommandBuffersFn>("vkAllocateCommandBuffers", Box::new(f))
+    }
+}
+type AllocateDescriptorSetsFn = dyn FnMut(
+    vk::Device,
+    *const vk::DescriptorSetAllocateInfo<'_>,
+    *mut vk::DescriptorSet,
+) -> vk::Result;
+unsafe extern "system" fn allocate_descriptor_sets(
+    device: vk::Device,
+    p_allocate_info: *const vk::DescriptorSetAllocateInfo<'_>,
+    p_descriptor_sets: *mut vk::DescriptorSet,
+) -> vk::Result {
+    dispatch::<AllocateDescriptorSetsFn, _>(
+        "vkAllocateDescriptorSets",
+        &[device.as_raw()],
+        |f| f(device, p_allocate_info, p_descriptor_sets),
+        |driver| {
+            if !p_descriptor_sets.is_null() {
+                for i in 0..(*p_allocate_info).descriptor_set_count as usize {
+                    *p_descriptor_sets.add(i) = driver.allocate();
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAllocateDescriptorSets`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAllocateDescriptorSets.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_allocate_descriptor_sets(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::DescriptorSetAllocateInfo<'_>,
+                *mut vk::DescriptorSet,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<AllocateDescriptorSetsFn>("vkAllocateDescriptorSets", Box::new(f))
+    }
+}
+type AllocateMemoryFn = dyn FnMut(
+    vk::Device,
+    *const vk::MemoryAllocateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::DeviceMemory,
+) -> vk::Result;
+unsafe extern "system" fn allocate_memory(
+    device: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    dispatch::<AllocateMemoryFn, _>(
+        "vkAllocateMemory",
+        &[device.as_raw()],
+        |f| f(device, p_allocate_info, p_allocator, p_memory),
+        |driver| {
+            if !p_memory.is_null() {
+                *p_memory = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkAllocateMemory`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAllocateMemory.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_allocate_memory(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::MemoryAllocateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::DeviceMemory,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<AllocateMemoryFn>("vkAllocateMemory", Box::new(f))
+    }
+}
+type BeginCommandBufferFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::CommandBufferBeginInfo<'_>) -> vk::Result;
+unsafe extern "system" fn begin_command_buffer(
+    command_buffer: vk::CommandBuffer,
+    p_begin_info: *const vk::CommandBufferBeginInfo<'_>,
+) -> vk::Result {
+    dispatch::<BeginCommandBufferFn, _>(
+        "vkBeginCommandBuffer",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_begin_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkBeginCommandBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBeginCommandBuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_begin_command_buffer(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CommandBufferBeginInfo<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<BeginCommandBufferFn>("vkBeginCommandBuffer", Box::new(f))
+    }
+}
+type BindAccelerationStructureMemoryNVFn =
+    dyn FnMut(vk::Device, u32, *const vk::BindAccelerationStructureMemoryInfoNV<'_>) -> vk::Result;
+unsafe extern "system" fn bind_acceleration_structure_memory_nv(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindAccelerationStructureMemoryInfoNV<'_>,
+) -> vk::Result {
+    dispatch::<BindAccelerationStructureMemoryNVFn, _>(
+        "vkBindAccelerationStructureMemoryNV",
+        &[device.as_raw()],
+        |f| f(device, bind_info_count, p_bind_infos),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkBindAccelerationStructureMemoryNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindAccelerationStructureMemoryNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_bind_acceleration_structure_memory_nv(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                u32,
+                *const vk::BindAccelerationStructureMemoryInfoNV<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<BindAccelerationStructureMemoryNVFn>(
+            "vkBindAccelerationStructureMemoryNV",
+            Box::new(f),
+        )
+    }
+}
+type BindBufferMemoryFn =
+    dyn FnMut(vk::Device, vk::Buffer, vk::DeviceMemory, vk::DeviceSize) -> vk::Result;
+unsafe extern "system" fn bind_buffer_memory(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    memory: vk::DeviceMemory,
+    memory_offset: vk::DeviceSize,
+) -> vk::Result {
+    dispatch::<BindBufferMemoryFn, _>(
+        "vkBindBufferMemory",
+        &[device.as_raw(), buffer.as_raw(), memory.as_raw()],
+        |f| f(device, buffer, memory, memory_offset),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkBindBufferMemory`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindBufferMemory.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_bind_buffer_memory(
+        &self,
+        f: impl FnMut(vk::Device, vk::Buffer, vk::DeviceMemory, vk::DeviceSize) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<BindBufferMemoryFn>("vkBindBufferMemory", Box::new(f))
+    }
+}
+type BindBufferMemory2Fn =
+    dyn FnMut(vk::Device, u32, *const vk::BindBufferMemoryInfo<'_>) -> vk::Result;
+unsafe extern "system" fn bind_buffer_memory2(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindBufferMemoryInfo<'_>,
+) -> vk::Result {
+    dispatch::<BindBufferMemory2Fn, _>(
+        "vkBindBufferMemory2",
+        &[device.as_raw()],
+        |f| f(device, bind_info_count, p_bind_infos),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkBindBufferMemory2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindBufferMemory2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_bind_buffer_memory2(
+        &self,
+        f: impl FnMut(vk::Device, u32, *const vk::BindBufferMemoryInfo<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<BindBufferMemory2Fn>("vkBindBufferMemory2", Box::new(f))
+    }
+}
+type BindImageMemoryFn =
+    dyn FnMut(vk::Device, vk::Image, vk::DeviceMemory, vk::DeviceSize) -> vk::Result;
+unsafe extern "system" fn bind_image_memory(
+    device: vk::Device,
+    image: vk::Image,
+    memory: vk::DeviceMemory,
+    memory_offset: vk::DeviceSize,
+) -> vk::Result {
+    dispatch::<BindImageMemoryFn, _>(
+        "vkBindImageMemory",
+        &[device.as_raw(), image.as_raw(), memory.as_raw()],
+        |f| f(device, image, memory, memory_offset),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkBindImageMemory`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindImageMemory.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_bind_image_memory(
+        &self,
+        f: impl FnMut(vk::Device, vk::Image, vk::DeviceMemory, vk::DeviceSize) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<BindImageMemoryFn>("vkBindImageMemory", Box::new(f))
+    }
+}
+type BindImageMemory2Fn =
+    dyn FnMut(vk::Device, u32, *const vk::BindImageMemoryInfo<'_>) -> vk::Result;
+unsafe extern "system" fn bind_image_memory2(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindImageMemoryInfo<'_>,
+) -> vk::Result {
+    dispatch::<BindImageMemory2Fn, _>(
+        "vkBindImageMemory2",
+        &[device.as_raw()],
+        |f| f(device, bind_info_count, p_bind_infos),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkBindImageMemory2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindImageMemory2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_bind_image_memory2(
+        &self,
+        f: impl FnMut(vk::Device, u32, *const vk::BindImageMemoryInfo<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<BindImageMemory2Fn>("vkBindImageMemory2", Box::new(f))
+    }
+}
+type BindOpticalFlowSessionImageNVFn = dyn FnMut(
+    vk::Device,
+    vk::OpticalFlowSessionNV,
+    vk::OpticalFlowSessionBindingPointNV,
+    vk::ImageView,
+    vk::ImageLayout,
+) -> vk::Result;
+unsafe extern "system" fn bind_optical_flow_session_image_nv(
+    device: vk::Device,
+    session: vk::OpticalFlowSessionNV,
+    binding_point: vk::OpticalFlowSessionBindingPointNV,
+    view: vk::ImageView,
+    layout: vk::ImageLayout,
+) -> vk::Result {
+    dispatch::<BindOpticalFlowSessionImageNVFn, _>(
+        "vkBindOpticalFlowSessionImageNV",
+        &[device.as_raw(), session.as_raw(), view.as_raw()],
+        |f| f(device, session, binding_point, view, layout),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkBindOpticalFlowSessionImageNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindOpticalFlowSessionImageNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_bind_optical_flow_session_image_nv(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::OpticalFlowSessionNV,
+                vk::OpticalFlowSessionBindingPointNV,
+                vk::ImageView,
+                vk::ImageLayout,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<BindOpticalFlowSessionImageNVFn>(
+            "vkBindOpticalFlowSessionImageNV",
+            Box::new(f),
+        )
+    }
+}
+type BindVideoSessionMemoryKHRFn = dyn FnMut(
+    vk::Device,
+    vk::VideoSessionKHR,
+    u32,
+    *const vk::BindVideoSessionMemoryInfoKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn bind_video_session_memory_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    bind_session_memory_info_count: u32,
+    p_bind_session_memory_infos: *const vk::BindVideoSessionMemoryInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<BindVideoSessionMemoryKHRFn, _>(
+        "vkBindVideoSessionMemoryKHR",
+        &[device.as_raw(), video_session.as_raw()],
+        |f| {
+            f(
+                device,
+                video_session,
+                bind_session_memory_info_count,
+                p_bind_session_memory_infos,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkBindVideoSessionMemoryKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindVideoSessionMemoryKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_bind_video_session_memory_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::VideoSessionKHR,
+                u32,
+                *const vk::BindVideoSessionMemoryInfoKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<BindVideoSessionMemoryKHRFn>("vkBindVideoSessionMemoryKHR", Box::new(f))
+    }
+}
+type BuildAccelerationStructuresKHRFn = dyn FnMut(
+    vk::Device,
+    vk::DeferredOperationKHR,
+    u32,
+    *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+) -> vk::Result;
+unsafe extern "system" fn build_acceleration_structures_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+) -> vk::Result {
+    dispatch::<BuildAccelerationStructuresKHRFn, _>(
+        "vkBuildAccelerationStructuresKHR",
+        &[device.as_raw(), deferred_operation.as_raw()],
+        |f| {
+            f(
+                device,
+                deferred_operation,
+                info_count,
+                p_infos,
+                pp_build_range_infos,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkBuildAccelerationStructuresKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBuildAccelerationStructuresKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_build_acceleration_structures_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeferredOperationKHR,
+                u32,
+                *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+                *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<BuildAccelerationStructuresKHRFn>(
+            "vkBuildAccelerationStructuresKHR",
+            Box::new(f),
+        )
+    }
+}
+type BuildMicromapsEXTFn = dyn FnMut(
+    vk::Device,
+    vk::DeferredOperationKHR,
+    u32,
+    *const vk::MicromapBuildInfoEXT<'_>,
+) -> vk::Result;
+unsafe extern "system" fn build_micromaps_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    info_count: u32,
+    p_infos: *const vk::MicromapBuildInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<BuildMicromapsEXTFn, _>(
+        "vkBuildMicromapsEXT",
+        &[device.as_raw(), deferred_operation.as_raw()],
+        |f| f(device, deferred_operation, info_count, p_infos),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkBuildMicromapsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBuildMicromapsEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_build_micromaps_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeferredOperationKHR,
+                u32,
+                *const vk::MicromapBuildInfoEXT<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<BuildMicromapsEXTFn>("vkBuildMicromapsEXT", Box::new(f))
+    }
+}
+type CmdBeginConditionalRenderingEXTFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::ConditionalRenderingBeginInfoEXT<'_>);
+unsafe extern "system" fn cmd_begin_conditional_rendering_ext(
+    command_buffer: vk::CommandBuffer,
+    p_conditional_rendering_begin: *const vk::ConditionalRenderingBeginInfoEXT<'_>,
+) {
+    dispatch::<CmdBeginConditionalRenderingEXTFn, _>(
+        "vkCmdBeginConditionalRenderingEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_conditional_rendering_begin),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBeginConditionalRenderingEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginConditionalRenderingEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_begin_conditional_rendering_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::ConditionalRenderingBeginInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBeginConditionalRenderingEXTFn>(
+            "vkCmdBeginConditionalRenderingEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdBeginDebugUtilsLabelEXTFn = dyn FnMut(vk::CommandBuffer, *const vk::DebugUtilsLabelEXT<'_>);
+unsafe extern "system" fn cmd_begin_debug_utils_label_ext(
+    command_buffer: vk::CommandBuffer,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    dispatch::<CmdBeginDebugUtilsLabelEXTFn, _>(
+        "vkCmdBeginDebugUtilsLabelEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_label_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBeginDebugUtilsLabelEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginDebugUtilsLabelEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_begin_debug_utils_label_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::DebugUtilsLabelEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBeginDebugUtilsLabelEXTFn>(
+            "vkCmdBeginDebugUtilsLabelEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdBeginQueryFn = dyn FnMut(vk::CommandBuffer, vk::QueryPool, u32, vk::QueryControlFlags);
+unsafe extern "system" fn cmd_begin_query(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    flags: vk::QueryControlFlags,
+) {
+    dispatch::<CmdBeginQueryFn, _>(
+        "vkCmdBeginQuery",
+        &[command_buffer.as_raw(), query_pool.as_raw()],
+        |f| f(command_buffer, query_pool, query, flags),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBeginQuery`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginQuery.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_begin_query(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::QueryPool, u32, vk::QueryControlFlags) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBeginQueryFn>("vkCmdBeginQuery", Box::new(f))
+    }
+}
+type CmdBeginQueryIndexedEXTFn =
+    dyn FnMut(vk::CommandBuffer, vk::QueryPool, u32, vk::QueryControlFlags, u32);
+unsafe extern "system" fn cmd_begin_query_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    flags: vk::QueryControlFlags,
+    index: u32,
+) {
+    dispatch::<CmdBeginQueryIndexedEXTFn, _>(
+        "vkCmdBeginQueryIndexedEXT",
+        &[command_buffer.as_raw(), query_pool.as_raw()],
+        |f| f(command_buffer, query_pool, query, flags, index),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBeginQueryIndexedEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginQueryIndexedEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_begin_query_indexed_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::QueryPool, u32, vk::QueryControlFlags, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBeginQueryIndexedEXTFn>("vkCmdBeginQueryIndexedEXT", Box::new(f))
+    }
+}
+type CmdBeginRenderPassFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::RenderPassBeginInfo<'_>, vk::SubpassContents);
+unsafe extern "system" fn cmd_begin_render_pass(
+    command_buffer: vk::CommandBuffer,
+    p_render_pass_begin: *const vk::RenderPassBeginInfo<'_>,
+    contents: vk::SubpassContents,
+) {
+    dispatch::<CmdBeginRenderPassFn, _>(
+        "vkCmdBeginRenderPass",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_render_pass_begin, contents),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBeginRenderPass`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRenderPass.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_begin_render_pass(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::RenderPassBeginInfo<'_>, vk::SubpassContents)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBeginRenderPassFn>("vkCmdBeginRenderPass", Box::new(f))
+    }
+}
+type CmdBeginRenderPass2Fn = dyn FnMut(
+    vk::CommandBuffer,
+    *const vk::RenderPassBeginInfo<'_>,
+    *const vk::SubpassBeginInfo<'_>,
+);
+unsafe extern "system" fn cmd_begin_render_pass2(
+    command_buffer: vk::CommandBuffer,
+    p_render_pass_begin: *const vk::RenderPassBeginInfo<'_>,
+    p_subpass_begin_info: *const vk::SubpassBeginInfo<'_>,
+) {
+    dispatch::<CmdBeginRenderPass2Fn, _>(
+        "vkCmdBeginRenderPass2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_render_pass_begin, p_subpass_begin_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBeginRenderPass2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRenderPass2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_begin_render_pass2(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                *const vk::RenderPassBeginInfo<'_>,
+                *const vk::SubpassBeginInfo<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBeginRenderPass2Fn>("vkCmdBeginRenderPass2", Box::new(f))
+    }
+}
+type CmdBeginRenderingFn = dyn FnMut(vk::CommandBuffer, *const vk::RenderingInfo<'_>);
+unsafe extern "system" fn cmd_begin_rendering(
+    command_buffer: vk::CommandBuffer,
+    p_rendering_info: *const vk::RenderingInfo<'_>,
+) {
+    dispatch::<CmdBeginRenderingFn, _>(
+        "vkCmdBeginRendering",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_rendering_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBeginRendering`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRendering.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_begin_rendering(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::RenderingInfo<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBeginRenderingFn>("vkCmdBeginRendering", Box::new(f))
+    }
+}
+type CmdBeginTransformFeedbackEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::Buffer, *const vk::DeviceSize);
+unsafe extern "system" fn cmd_begin_transform_feedback_ext(
+    command_buffer: vk::CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const vk::Buffer,
+    p_counter_buffer_offsets: *const vk::DeviceSize,
+) {
+    dispatch::<CmdBeginTransformFeedbackEXTFn, _>(
+        "vkCmdBeginTransformFeedbackEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_counter_buffer,
+                counter_buffer_count,
+                p_counter_buffers,
+                p_counter_buffer_offsets,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBeginTransformFeedbackEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginTransformFeedbackEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_begin_transform_feedback_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::Buffer, *const vk::DeviceSize) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBeginTransformFeedbackEXTFn>(
+            "vkCmdBeginTransformFeedbackEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdBeginVideoCodingKHRFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::VideoBeginCodingInfoKHR<'_>);
+unsafe extern "system" fn cmd_begin_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_begin_info: *const vk::VideoBeginCodingInfoKHR<'_>,
+) {
+    dispatch::<CmdBeginVideoCodingKHRFn, _>(
+        "vkCmdBeginVideoCodingKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_begin_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBeginVideoCodingKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginVideoCodingKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_begin_video_coding_khr(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::VideoBeginCodingInfoKHR<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBeginVideoCodingKHRFn>("vkCmdBeginVideoCodingKHR", Box::new(f))
+    }
+}
+type CmdBindDescriptorBufferEmbeddedSamplersEXTFn =
+    dyn FnMut(vk::CommandBuffer, vk::PipelineBindPoint, vk::PipelineLayout, u32);
+unsafe extern "system" fn cmd_bind_descriptor_buffer_embedded_samplers_ext(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    set: u32,
+) {
+    dispatch::<CmdBindDescriptorBufferEmbeddedSamplersEXTFn, _>(
+        "vkCmdBindDescriptorBufferEmbeddedSamplersEXT",
+        &[command_buffer.as_raw(), layout.as_raw()],
+        |f| f(command_buffer, pipeline_bind_point, layout, set),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindDescriptorBufferEmbeddedSamplersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorBufferEmbeddedSamplersEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_descriptor_buffer_embedded_samplers_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::PipelineBindPoint, vk::PipelineLayout, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindDescriptorBufferEmbeddedSamplersEXTFn>(
+            "vkCmdBindDescriptorBufferEmbeddedSamplersEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdBindDescriptorBuffersEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, *const vk::DescriptorBufferBindingInfoEXT<'_>);
+unsafe extern "system" fn cmd_bind_descriptor_buffers_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer_count: u32,
+    p_binding_infos: *const vk::DescriptorBufferBindingInfoEXT<'_>,
+) {
+    dispatch::<CmdBindDescriptorBuffersEXTFn, _>(
+        "vkCmdBindDescriptorBuffersEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, buffer_count, p_binding_infos),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindDescriptorBuffersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorBuffersEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_descriptor_buffers_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::DescriptorBufferBindingInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindDescriptorBuffersEXTFn>(
+            "vkCmdBindDescriptorBuffersEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdBindDescriptorSetsFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::PipelineBindPoint,
+    vk::PipelineLayout,
+    u32,
+    u32,
+    *const vk::DescriptorSet,
+    u32,
+    *const u32,
+);
+unsafe extern "system" fn cmd_bind_descriptor_sets(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    first_set: u32,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const vk::DescriptorSet,
+    dynamic_offset_count: u32,
+    p_dynamic_offsets: *const u32,
+) {
+    dispatch::<CmdBindDescriptorSetsFn, _>(
+        "vkCmdBindDescriptorSets",
+        &[command_buffer.as_raw(), layout.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                pipeline_bind_point,
+                layout,
+                first_set,
+                descriptor_set_count,
+                p_descriptor_sets,
+                dynamic_offset_count,
+                p_dynamic_offsets,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindDescriptorSets`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorSets.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_descriptor_sets(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::PipelineBindPoint,
+                vk::PipelineLayout,
+                u32,
+                u32,
+                *const vk::DescriptorSet,
+                u32,
+                *const u32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindDescriptorSetsFn>("vkCmdBindDescriptorSets", Box::new(f))
+    }
+}
+type CmdBindIndexBufferFn = dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, vk::IndexType);
+unsafe extern "system" fn cmd_bind_index_buffer(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    index_type: vk::IndexType,
+) {
+    dispatch::<CmdBindIndexBufferFn, _>(
+        "vkCmdBindIndexBuffer",
+        &[command_buffer.as_raw(), buffer.as_raw()],
+        |f| f(command_buffer, buffer, offset, index_type),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindIndexBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindIndexBuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_index_buffer(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, vk::IndexType) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindIndexBufferFn>("vkCmdBindIndexBuffer", Box::new(f))
+    }
+}
+type CmdBindInvocationMaskHUAWEIFn = dyn FnMut(vk::CommandBuffer, vk::ImageView, vk::ImageLayout);
+unsafe extern "system" fn cmd_bind_invocation_mask_huawei(
+    command_buffer: vk::CommandBuffer,
+    image_view: vk::ImageView,
+    image_layout: vk::ImageLayout,
+) {
+    dispatch::<CmdBindInvocationMaskHUAWEIFn, _>(
+        "vkCmdBindInvocationMaskHUAWEI",
+        &[command_buffer.as_raw(), image_view.as_raw()],
+        |f| f(command_buffer, image_view, image_layout),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindInvocationMaskHUAWEI`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindInvocationMaskHUAWEI.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_invocation_mask_huawei(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::ImageView, vk::ImageLayout) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindInvocationMaskHUAWEIFn>(
+            "vkCmdBindInvocationMaskHUAWEI",
+            Box::new(f),
+        )
+    }
+}
+type CmdBindPipelineFn = dyn FnMut(vk::CommandBuffer, vk::PipelineBindPoint, vk::Pipeline);
+unsafe extern "system" fn cmd_bind_pipeline(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    pipeline: vk::Pipeline,
+) {
+    dispatch::<CmdBindPipelineFn, _>(
+        "vkCmdBindPipeline",
+        &[command_buffer.as_raw(), pipeline.as_raw()],
+        |f| f(command_buffer, pipeline_bind_point, pipeline),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindPipeline`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindPipeline.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_pipeline(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::PipelineBindPoint, vk::Pipeline) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindPipelineFn>("vkCmdBindPipeline", Box::new(f))
+    }
+}
+type CmdBindPipelineShaderGroupNVFn =
+    dyn FnMut(vk::CommandBuffer, vk::PipelineBindPoint, vk::Pipeline, u32);
+unsafe extern "system" fn cmd_bind_pipeline_shader_group_nv(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    pipeline: vk::Pipeline,
+    group_index: u32,
+) {
+    dispatch::<CmdBindPipelineShaderGroupNVFn, _>(
+        "vkCmdBindPipelineShaderGroupNV",
+        &[command_buffer.as_raw(), pipeline.as_raw()],
+        |f| f(command_buffer, pipeline_bind_point, pipeline, group_index),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindPipelineShaderGroupNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindPipelineShaderGroupNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_pipeline_shader_group_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::PipelineBindPoint, vk::Pipeline, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindPipelineShaderGroupNVFn>(
+            "vkCmdBindPipelineShaderGroupNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdBindShadersEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, *const vk::ShaderStageFlags, *const vk::ShaderEXT);
+unsafe extern "system" fn cmd_bind_shaders_ext(
+    command_buffer: vk::CommandBuffer,
+    stage_count: u32,
+    p_stages: *const vk::ShaderStageFlags,
+    p_shaders: *const vk::ShaderEXT,
+) {
+    dispatch::<CmdBindShadersEXTFn, _>(
+        "vkCmdBindShadersEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, stage_count, p_stages, p_shaders),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindShadersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindShadersEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_shaders_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::ShaderStageFlags, *const vk::ShaderEXT)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindShadersEXTFn>("vkCmdBindShadersEXT", Box::new(f))
+    }
+}
+type CmdBindShadingRateImageNVFn = dyn FnMut(vk::CommandBuffer, vk::ImageView, vk::ImageLayout);
+unsafe extern "system" fn cmd_bind_shading_rate_image_nv(
+    command_buffer: vk::CommandBuffer,
+    image_view: vk::ImageView,
+    image_layout: vk::ImageLayout,
+) {
+    dispatch::<CmdBindShadingRateImageNVFn, _>(
+        "vkCmdBindShadingRateImageNV",
+        &[command_buffer.as_raw(), image_view.as_raw()],
+        |f| f(command_buffer, image_view, image_layout),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindShadingRateImageNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindShadingRateImageNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_shading_rate_image_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::ImageView, vk::ImageLayout) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindShadingRateImageNVFn>("vkCmdBindShadingRateImageNV", Box::new(f))
+    }
+}
+type CmdBindTransformFeedbackBuffersEXTFn = dyn FnMut(
+    vk::CommandBuffer,
+    u32,
+    u32,
+    *const vk::Buffer,
+    *const vk::DeviceSize,
+    *const vk::DeviceSize,
+);
+unsafe extern "system" fn cmd_bind_transform_feedback_buffers_ext(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+    p_sizes: *const vk::DeviceSize,
+) {
+    dispatch::<CmdBindTransformFeedbackBuffersEXTFn, _>(
+        "vkCmdBindTransformFeedbackBuffersEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_binding,
+                binding_count,
+                p_buffers,
+                p_offsets,
+                p_sizes,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindTransformFeedbackBuffersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindTransformFeedbackBuffersEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_transform_feedback_buffers_ext(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                u32,
+                u32,
+                *const vk::Buffer,
+                *const vk::DeviceSize,
+                *const vk::DeviceSize,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindTransformFeedbackBuffersEXTFn>(
+            "vkCmdBindTransformFeedbackBuffersEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdBindVertexBuffersFn =
+    dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::Buffer, *const vk::DeviceSize);
+unsafe extern "system" fn cmd_bind_vertex_buffers(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+) {
+    dispatch::<CmdBindVertexBuffersFn, _>(
+        "vkCmdBindVertexBuffers",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_binding,
+                binding_count,
+                p_buffers,
+                p_offsets,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindVertexBuffers`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindVertexBuffers.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_vertex_buffers(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::Buffer, *const vk::DeviceSize) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindVertexBuffersFn>("vkCmdBindVertexBuffers", Box::new(f))
+    }
+}
+type CmdBindVertexBuffers2Fn = dyn FnMut(
+    vk::CommandBuffer,
+    u32,
+    u32,
+    *const vk::Buffer,
+    *const vk::DeviceSize,
+    *const vk::DeviceSize,
+    *const vk::DeviceSize,
+);
+unsafe extern "system" fn cmd_bind_vertex_buffers2(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+    p_sizes: *const vk::DeviceSize,
+    p_strides: *const vk::DeviceSize,
+) {
+    dispatch::<CmdBindVertexBuffers2Fn, _>(
+        "vkCmdBindVertexBuffers2",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_binding,
+                binding_count,
+                p_buffers,
+                p_offsets,
+                p_sizes,
+                p_strides,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBindVertexBuffers2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindVertexBuffers2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_bind_vertex_buffers2(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                u32,
+                u32,
+                *const vk::Buffer,
+                *const vk::DeviceSize,
+                *const vk::DeviceSize,
+                *const vk::DeviceSize,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBindVertexBuffers2Fn>("vkCmdBindVertexBuffers2", Box::new(f))
+    }
+}
+type CmdBlitImageFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::Image,
+    vk::ImageLayout,
+    vk::Image,
+    vk::ImageLayout,
+    u32,
+    *const vk::ImageBlit,
+    vk::Filter,
+);
+unsafe extern "system" fn cmd_blit_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageBlit,
+    filter: vk::Filter,
+) {
+    dispatch::<CmdBlitImageFn, _>(
+        "vkCmdBlitImage",
+        &[
+            command_buffer.as_raw(),
+            src_image.as_raw(),
+            dst_image.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                src_image,
+                src_image_layout,
+                dst_image,
+                dst_image_layout,
+                region_count,
+                p_regions,
+                filter,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBlitImage`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBlitImage.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_blit_image(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Image,
+                vk::ImageLayout,
+                vk::Image,
+                vk::ImageLayout,
+                u32,
+                *const vk::ImageBlit,
+                vk::Filter,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBlitImageFn>("vkCmdBlitImage", Box::new(f))
+    }
+}
+type CmdBlitImage2Fn = dyn FnMut(vk::CommandBuffer, *const vk::BlitImageInfo2<'_>);
+unsafe extern "system" fn cmd_blit_image2(
+    command_buffer: vk::CommandBuffer,
+    p_blit_image_info: *const vk::BlitImageInfo2<'_>,
+) {
+    dispatch::<CmdBlitImage2Fn, _>(
+        "vkCmdBlitImage2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_blit_image_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBlitImage2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBlitImage2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_blit_image2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::BlitImageInfo2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBlitImage2Fn>("vkCmdBlitImage2", Box::new(f))
+    }
+}
+type CmdBuildAccelerationStructureNVFn = dyn FnMut(
+    vk::CommandBuffer,
+    *const vk::AccelerationStructureInfoNV<'_>,
+    vk::Buffer,
+    vk::DeviceSize,
+    vk::Bool32,
+    vk::AccelerationStructureNV,
+    vk::AccelerationStructureNV,
+    vk::Buffer,
+    vk::DeviceSize,
+);
+unsafe extern "system" fn cmd_build_acceleration_structure_nv(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::AccelerationStructureInfoNV<'_>,
+    instance_data: vk::Buffer,
+    instance_offset: vk::DeviceSize,
+    update: vk::Bool32,
+    dst: vk::AccelerationStructureNV,
+    src: vk::AccelerationStructureNV,
+    scratch: vk::Buffer,
+    scratch_offset: vk::DeviceSize,
+) {
+    dispatch::<CmdBuildAccelerationStructureNVFn, _>(
+        "vkCmdBuildAccelerationStructureNV",
+        &[
+            command_buffer.as_raw(),
+            instance_data.as_raw(),
+            dst.as_raw(),
+            src.as_raw(),
+            scratch.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                p_info,
+                instance_data,
+                instance_offset,
+                update,
+                dst,
+                src,
+                scratch,
+                scratch_offset,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBuildAccelerationStructureNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildAccelerationStructureNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_build_acceleration_structure_nv(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                *const vk::AccelerationStructureInfoNV<'_>,
+                vk::Buffer,
+                vk::DeviceSize,
+                vk::Bool32,
+                vk::AccelerationStructureNV,
+                vk::AccelerationStructureNV,
+                vk::Buffer,
+                vk::DeviceSize,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBuildAccelerationStructureNVFn>(
+            "vkCmdBuildAccelerationStructureNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdBuildAccelerationStructuresIndirectKHRFn = dyn FnMut(
+    vk::CommandBuffer,
+    u32,
+    *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    *const vk::DeviceAddress,
+    *const u32,
+    *const *const u32,
+);
+unsafe extern "system" fn cmd_build_acceleration_structures_indirect_khr(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    p_indirect_device_addresses: *const vk::DeviceAddress,
+    p_indirect_strides: *const u32,
+    pp_max_primitive_counts: *const *const u32,
+) {
+    dispatch::<CmdBuildAccelerationStructuresIndirectKHRFn, _>(
+        "vkCmdBuildAccelerationStructuresIndirectKHR",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                info_count,
+                p_infos,
+                p_indirect_device_addresses,
+                p_indirect_strides,
+                pp_max_primitive_counts,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBuildAccelerationStructuresIndirectKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildAccelerationStructuresIndirectKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_build_acceleration_structures_indirect_khr(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                u32,
+                *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+                *const vk::DeviceAddress,
+                *const u32,
+                *const *const u32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBuildAccelerationStructuresIndirectKHRFn>(
+            "vkCmdBuildAccelerationStructuresIndirectKHR",
+            Box::new(f),
+        )
+    }
+}
+type CmdBuildAccelerationStructuresKHRFn = dyn FnMut(
+    vk::CommandBuffer,
+    u32,
+    *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+);
+unsafe extern "system" fn cmd_build_acceleration_structures_khr(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+) {
+    dispatch::<CmdBuildAccelerationStructuresKHRFn, _>(
+        "vkCmdBuildAccelerationStructuresKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, info_count, p_infos, pp_build_range_infos),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBuildAccelerationStructuresKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildAccelerationStructuresKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_build_acceleration_structures_khr(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                u32,
+                *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+                *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBuildAccelerationStructuresKHRFn>(
+            "vkCmdBuildAccelerationStructuresKHR",
+            Box::new(f),
+        )
+    }
+}
+type CmdBuildMicromapsEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, *const vk::MicromapBuildInfoEXT<'_>);
+unsafe extern "system" fn cmd_build_micromaps_ext(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::MicromapBuildInfoEXT<'_>,
+) {
+    dispatch::<CmdBuildMicromapsEXTFn, _>(
+        "vkCmdBuildMicromapsEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, info_count, p_infos),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdBuildMicromapsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildMicromapsEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_build_micromaps_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::MicromapBuildInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdBuildMicromapsEXTFn>("vkCmdBuildMicromapsEXT", Box::new(f))
+    }
+}
+type CmdClearAttachmentsFn =
+    dyn FnMut(vk::CommandBuffer, u32, *const vk::ClearAttachment, u32, *const vk::ClearRect);
+unsafe extern "system" fn cmd_clear_attachments(
+    command_buffer: vk::CommandBuffer,
+    attachment_count: u32,
+    p_attachments: *const vk::ClearAttachment,
+    rect_count: u32,
+    p_rects: *const vk::ClearRect,
+) {
+    dispatch::<CmdClearAttachmentsFn, _>(
+        "vkCmdClearAttachments",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                attachment_count,
+                p_attachments,
+                rect_count,
+                p_rects,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdClearAttachments`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdClearAttachments.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_clear_attachments(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::ClearAttachment, u32, *const vk::ClearRect)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdClearAttachmentsFn>("vkCmdClearAttachments", Box::new(f))
+    }
+}
+type CmdClearColorImageFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::Image,
+    vk::ImageLayout,
+    *const vk::ClearColorValue,
+    u32,
+    *const vk::ImageSubresourceRange,
+);
+unsafe extern "system" fn cmd_clear_color_image(
+    command_buffer: vk::CommandBuffer,
+    image: vk::Image,
+    image_layout: vk::ImageLayout,
+    p_color: *const vk::ClearColorValue,
+    range_count: u32,
+    p_ranges: *const vk::ImageSubresourceRange,
+) {
+    dispatch::<CmdClearColorImageFn, _>(
+        "vkCmdClearColorImage",
+        &[command_buffer.as_raw(), image.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                image,
+                image_layout,
+                p_color,
+                range_count,
+                p_ranges,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdClearColorImage`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdClearColorImage.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_clear_color_image(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Image,
+                vk::ImageLayout,
+                *const vk::ClearColorValue,
+                u32,
+                *const vk::ImageSubresourceRange,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdClearColorImageFn>("vkCmdClearColorImage", Box::new(f))
+    }
+}
+type CmdClearDepthStencilImageFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::Image,
+    vk::ImageLayout,
+    *const vk::ClearDepthStencilValue,
+    u32,
+    *const vk::ImageSubresourceRange,
+);
+unsafe extern "system" fn cmd_clear_depth_stencil_image(
+    command_buffer: vk::CommandBuffer,
+    image: vk::Image,
+    image_layout: vk::ImageLayout,
+    p_depth_stencil: *const vk::ClearDepthStencilValue,
+    range_count: u32,
+    p_ranges: *const vk::ImageSubresourceRange,
+) {
+    dispatch::<CmdClearDepthStencilImageFn, _>(
+        "vkCmdClearDepthStencilImage",
+        &[command_buffer.as_raw(), image.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                image,
+                image_layout,
+                p_depth_stencil,
+                range_count,
+                p_ranges,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdClearDepthStencilImage`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdClearDepthStencilImage.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_clear_depth_stencil_image(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Image,
+                vk::ImageLayout,
+                *const vk::ClearDepthStencilValue,
+                u32,
+                *const vk::ImageSubresourceRange,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdClearDepthStencilImageFn>("vkCmdClearDepthStencilImage", Box::new(f))
+    }
+}
+type CmdControlVideoCodingKHRFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::VideoCodingControlInfoKHR<'_>);
+unsafe extern "system" fn cmd_control_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_coding_control_info: *const vk::VideoCodingControlInfoKHR<'_>,
+) {
+    dispatch::<CmdControlVideoCodingKHRFn, _>(
+        "vkCmdControlVideoCodingKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_coding_control_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdControlVideoCodingKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdControlVideoCodingKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_control_video_coding_khr(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::VideoCodingControlInfoKHR<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdControlVideoCodingKHRFn>("vkCmdControlVideoCodingKHR", Box::new(f))
+    }
+}
+type CmdCopyAccelerationStructureKHRFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::CopyAccelerationStructureInfoKHR<'_>);
+unsafe extern "system" fn cmd_copy_acceleration_structure_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyAccelerationStructureInfoKHR<'_>,
+) {
+    dispatch::<CmdCopyAccelerationStructureKHRFn, _>(
+        "vkCmdCopyAccelerationStructureKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyAccelerationStructureKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyAccelerationStructureKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_acceleration_structure_khr(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CopyAccelerationStructureInfoKHR<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyAccelerationStructureKHRFn>(
+            "vkCmdCopyAccelerationStructureKHR",
+            Box::new(f),
+        )
+    }
+}
+type CmdCopyAccelerationStructureNVFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::AccelerationStructureNV,
+    vk::AccelerationStructureNV,
+    vk::CopyAccelerationStructureModeKHR,
+);
+unsafe extern "system" fn cmd_copy_acceleration_structure_nv(
+    command_buffer: vk::CommandBuffer,
+    dst: vk::AccelerationStructureNV,
+    src: vk::AccelerationStructureNV,
+    mode: vk::CopyAccelerationStructureModeKHR,
+) {
+    dispatch::<CmdCopyAccelerationStructureNVFn, _>(
+        "vkCmdCopyAccelerationStructureNV",
+        &[command_buffer.as_raw(), dst.as_raw(), src.as_raw()],
+        |f| f(command_buffer, dst, src, mode),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyAccelerationStructureNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyAccelerationStructureNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_acceleration_structure_nv(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::AccelerationStructureNV,
+                vk::AccelerationStructureNV,
+                vk::CopyAccelerationStructureModeKHR,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyAccelerationStructureNVFn>(
+            "vkCmdCopyAccelerationStructureNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdCopyAccelerationStructureToMemoryKHRFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>);
+unsafe extern "system" fn cmd_copy_acceleration_structure_to_memory_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>,
+) {
+    dispatch::<CmdCopyAccelerationStructureToMemoryKHRFn, _>(
+        "vkCmdCopyAccelerationStructureToMemoryKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyAccelerationStructureToMemoryKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyAccelerationStructureToMemoryKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_acceleration_structure_to_memory_khr(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyAccelerationStructureToMemoryKHRFn>(
+            "vkCmdCopyAccelerationStructureToMemoryKHR",
+            Box::new(f),
+        )
+    }
+}
+type CmdCopyBufferFn =
+    dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::Buffer, u32, *const vk::BufferCopy);
+unsafe extern "system" fn cmd_copy_buffer(
+    command_buffer: vk::CommandBuffer,
+    src_buffer: vk::Buffer,
+    dst_buffer: vk::Buffer,
+    region_count: u32,
+    p_regions: *const vk::BufferCopy,
+) {
+    dispatch::<CmdCopyBufferFn, _>(
+        "vkCmdCopyBuffer",
+        &[
+            command_buffer.as_raw(),
+            src_buffer.as_raw(),
+            dst_buffer.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                src_buffer,
+                dst_buffer,
+                region_count,
+                p_regions,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_buffer(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Buffer, vk::Buffer, u32, *const vk::BufferCopy) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyBufferFn>("vkCmdCopyBuffer", Box::new(f))
+    }
+}
+type CmdCopyBuffer2Fn = dyn FnMut(vk::CommandBuffer, *const vk::CopyBufferInfo2<'_>);
+unsafe extern "system" fn cmd_copy_buffer2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_buffer_info: *const vk::CopyBufferInfo2<'_>,
+) {
+    dispatch::<CmdCopyBuffer2Fn, _>(
+        "vkCmdCopyBuffer2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_copy_buffer_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyBuffer2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBuffer2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_buffer2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CopyBufferInfo2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyBuffer2Fn>("vkCmdCopyBuffer2", Box::new(f))
+    }
+}
+type CmdCopyBufferToImageFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::Buffer,
+    vk::Image,
+    vk::ImageLayout,
+    u32,
+    *const vk::BufferImageCopy,
+);
+unsafe extern "system" fn cmd_copy_buffer_to_image(
+    command_buffer: vk::CommandBuffer,
+    src_buffer: vk::Buffer,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::BufferImageCopy,
+) {
+    dispatch::<CmdCopyBufferToImageFn, _>(
+        "vkCmdCopyBufferToImage",
+        &[
+            command_buffer.as_raw(),
+            src_buffer.as_raw(),
+            dst_image.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                src_buffer,
+                dst_image,
+                dst_image_layout,
+                region_count,
+                p_regions,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyBufferToImage`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBufferToImage.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_buffer_to_image(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Buffer,
+                vk::Image,
+                vk::ImageLayout,
+                u32,
+                *const vk::BufferImageCopy,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyBufferToImageFn>("vkCmdCopyBufferToImage", Box::new(f))
+    }
+}
+type CmdCopyBufferToImage2Fn = dyn FnMut(vk::CommandBuffer, *const vk::CopyBufferToImageInfo2<'_>);
+unsafe extern "system" fn cmd_copy_buffer_to_image2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_buffer_to_image_info: *const vk::CopyBufferToImageInfo2<'_>,
+) {
+    dispatch::<CmdCopyBufferToImage2Fn, _>(
+        "vkCmdCopyBufferToImage2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_copy_buffer_to_image_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyBufferToImage2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBufferToImage2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_buffer_to_image2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CopyBufferToImageInfo2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyBufferToImage2Fn>("vkCmdCopyBufferToImage2", Box::new(f))
+    }
+}
+type CmdCopyImageFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::Image,
+    vk::ImageLayout,
+    vk::Image,
+    vk::ImageLayout,
+    u32,
+    *const vk::ImageCopy,
+);
+unsafe extern "system" fn cmd_copy_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageCopy,
+) {
+    dispatch::<CmdCopyImageFn, _>(
+        "vkCmdCopyImage",
+        &[
+            command_buffer.as_raw(),
+            src_image.as_raw(),
+            dst_image.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                src_image,
+                src_image_layout,
+                dst_image,
+                dst_image_layout,
+                region_count,
+                p_regions,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyImage`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImage.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_image(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Image,
+                vk::ImageLayout,
+                vk::Image,
+                vk::ImageLayout,
+                u32,
+                *const vk::ImageCopy,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyImageFn>("vkCmdCopyImage", Box::new(f))
+    }
+}
+type CmdCopyImage2Fn = dyn FnMut(vk::CommandBuffer, *const vk::CopyImageInfo2<'_>);
+unsafe extern "system" fn cmd_copy_image2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_image_info: *const vk::CopyImageInfo2<'_>,
+) {
+    dispatch::<CmdCopyImage2Fn, _>(
+        "vkCmdCopyImage2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_copy_image_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyImage2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImage2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_image2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CopyImageInfo2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyImage2Fn>("vkCmdCopyImage2", Box::new(f))
+    }
+}
+type CmdCopyImageToBufferFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::Image,
+    vk::ImageLayout,
+    vk::Buffer,
+    u32,
+    *const vk::BufferImageCopy,
+);
+unsafe extern "system" fn cmd_copy_image_to_buffer(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_buffer: vk::Buffer,
+    region_count: u32,
+    p_regions: *const vk::BufferImageCopy,
+) {
+    dispatch::<CmdCopyImageToBufferFn, _>(
+        "vkCmdCopyImageToBuffer",
+        &[
+            command_buffer.as_raw(),
+            src_image.as_raw(),
+            dst_buffer.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                src_image,
+                src_image_layout,
+                dst_buffer,
+                region_count,
+                p_regions,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyImageToBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImageToBuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_image_to_buffer(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Image,
+                vk::ImageLayout,
+                vk::Buffer,
+                u32,
+                *const vk::BufferImageCopy,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyImageToBufferFn>("vkCmdCopyImageToBuffer", Box::new(f))
+    }
+}
+type CmdCopyImageToBuffer2Fn = dyn FnMut(vk::CommandBuffer, *const vk::CopyImageToBufferInfo2<'_>);
+unsafe extern "system" fn cmd_copy_image_to_buffer2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_image_to_buffer_info: *const vk::CopyImageToBufferInfo2<'_>,
+) {
+    dispatch::<CmdCopyImageToBuffer2Fn, _>(
+        "vkCmdCopyImageToBuffer2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_copy_image_to_buffer_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyImageToBuffer2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImageToBuffer2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_image_to_buffer2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CopyImageToBufferInfo2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyImageToBuffer2Fn>("vkCmdCopyImageToBuffer2", Box::new(f))
+    }
+}
+type CmdCopyMemoryIndirectNVFn = dyn FnMut(vk::CommandBuffer, vk::DeviceAddress, u32, u32);
+unsafe extern "system" fn cmd_copy_memory_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    copy_buffer_address: vk::DeviceAddress,
+    copy_count: u32,
+    stride: u32,
+) {
+    dispatch::<CmdCopyMemoryIndirectNVFn, _>(
+        "vkCmdCopyMemoryIndirectNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, copy_buffer_address, copy_count, stride),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyMemoryIndirectNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryIndirectNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_memory_indirect_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::DeviceAddress, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyMemoryIndirectNVFn>("vkCmdCopyMemoryIndirectNV", Box::new(f))
+    }
+}
+type CmdCopyMemoryToAccelerationStructureKHRFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>);
+unsafe extern "system" fn cmd_copy_memory_to_acceleration_structure_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>,
+) {
+    dispatch::<CmdCopyMemoryToAccelerationStructureKHRFn, _>(
+        "vkCmdCopyMemoryToAccelerationStructureKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyMemoryToAccelerationStructureKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToAccelerationStructureKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_memory_to_acceleration_structure_khr(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyMemoryToAccelerationStructureKHRFn>(
+            "vkCmdCopyMemoryToAccelerationStructureKHR",
+            Box::new(f),
+        )
+    }
+}
+type CmdCopyMemoryToImageIndirectNVFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::DeviceAddress,
+    u32,
+    u32,
+    vk::Image,
+    vk::ImageLayout,
+    *const vk::ImageSubresourceLayers,
+);
+unsafe extern "system" fn cmd_copy_memory_to_image_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    copy_buffer_address: vk::DeviceAddress,
+    copy_count: u32,
+    stride: u32,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    p_image_subresources: *const vk::ImageSubresourceLayers,
+) {
+    dispatch::<CmdCopyMemoryToImageIndirectNVFn, _>(
+        "vkCmdCopyMemoryToImageIndirectNV",
+        &[command_buffer.as_raw(), dst_image.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                copy_buffer_address,
+                copy_count,
+                stride,
+                dst_image,
+                dst_image_layout,
+                p_image_subresources,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyMemoryToImageIndirectNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToImageIndirectNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_memory_to_image_indirect_nv(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::DeviceAddress,
+                u32,
+                u32,
+                vk::Image,
+                vk::ImageLayout,
+                *const vk::ImageSubresourceLayers,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyMemoryToImageIndirectNVFn>(
+            "vkCmdCopyMemoryToImageIndirectNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdCopyMemoryToMicromapEXTFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::CopyMemoryToMicromapInfoEXT<'_>);
+unsafe extern "system" fn cmd_copy_memory_to_micromap_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMemoryToMicromapInfoEXT<'_>,
+) {
+    dispatch::<CmdCopyMemoryToMicromapEXTFn, _>(
+        "vkCmdCopyMemoryToMicromapEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyMemoryToMicromapEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMemoryToMicromapEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_memory_to_micromap_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CopyMemoryToMicromapInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyMemoryToMicromapEXTFn>(
+            "vkCmdCopyMemoryToMicromapEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdCopyMicromapEXTFn = dyn FnMut(vk::CommandBuffer, *const vk::CopyMicromapInfoEXT<'_>);
+unsafe extern "system" fn cmd_copy_micromap_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMicromapInfoEXT<'_>,
+) {
+    dispatch::<CmdCopyMicromapEXTFn, _>(
+        "vkCmdCopyMicromapEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyMicromapEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMicromapEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_micromap_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CopyMicromapInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyMicromapEXTFn>("vkCmdCopyMicromapEXT", Box::new(f))
+    }
+}
+type CmdCopyMicromapToMemoryEXTFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::CopyMicromapToMemoryInfoEXT<'_>);
+unsafe extern "system" fn cmd_copy_micromap_to_memory_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMicromapToMemoryInfoEXT<'_>,
+) {
+    dispatch::<CmdCopyMicromapToMemoryEXTFn, _>(
+        "vkCmdCopyMicromapToMemoryEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyMicromapToMemoryEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyMicromapToMemoryEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_micromap_to_memory_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CopyMicromapToMemoryInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyMicromapToMemoryEXTFn>(
+            "vkCmdCopyMicromapToMemoryEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdCopyQueryPoolResultsFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::QueryPool,
+    u32,
+    u32,
+    vk::Buffer,
+    vk::DeviceSize,
+    vk::DeviceSize,
+    vk::QueryResultFlags,
+);
+unsafe extern "system" fn cmd_copy_query_pool_results(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    stride: vk::DeviceSize,
+    flags: vk::QueryResultFlags,
+) {
+    dispatch::<CmdCopyQueryPoolResultsFn, _>(
+        "vkCmdCopyQueryPoolResults",
+        &[
+            command_buffer.as_raw(),
+            query_pool.as_raw(),
+            dst_buffer.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                query_pool,
+                first_query,
+                query_count,
+                dst_buffer,
+                dst_offset,
+                stride,
+                flags,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCopyQueryPoolResults`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyQueryPoolResults.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_copy_query_pool_results(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::QueryPool,
+                u32,
+                u32,
+                vk::Buffer,
+                vk::DeviceSize,
+                vk::DeviceSize,
+                vk::QueryResultFlags,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCopyQueryPoolResultsFn>("vkCmdCopyQueryPoolResults", Box::new(f))
+    }
+}
+type CmdCuLaunchKernelNVXFn = dyn FnMut(vk::CommandBuffer, *const vk::CuLaunchInfoNVX<'_>);
+unsafe extern "system" fn cmd_cu_launch_kernel_nvx(
+    command_buffer: vk::CommandBuffer,
+    p_launch_info: *const vk::CuLaunchInfoNVX<'_>,
+) {
+    dispatch::<CmdCuLaunchKernelNVXFn, _>(
+        "vkCmdCuLaunchKernelNVX",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_launch_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdCuLaunchKernelNVX`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCuLaunchKernelNVX.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_cu_launch_kernel_nvx(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::CuLaunchInfoNVX<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdCuLaunchKernelNVXFn>("vkCmdCuLaunchKernelNVX", Box::new(f))
+    }
+}
+type CmdDebugMarkerBeginEXTFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::DebugMarkerMarkerInfoEXT<'_>);
+unsafe extern "system" fn cmd_debug_marker_begin_ext(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::DebugMarkerMarkerInfoEXT<'_>,
+) {
+    dispatch::<CmdDebugMarkerBeginEXTFn, _>(
+        "vkCmdDebugMarkerBeginEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_marker_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDebugMarkerBeginEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDebugMarkerBeginEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_debug_marker_begin_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::DebugMarkerMarkerInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDebugMarkerBeginEXTFn>("vkCmdDebugMarkerBeginEXT", Box::new(f))
+    }
+}
+type CmdDebugMarkerEndEXTFn = dyn FnMut(vk::CommandBuffer);
+unsafe extern "system" fn cmd_debug_marker_end_ext(command_buffer: vk::CommandBuffer) {
+    dispatch::<CmdDebugMarkerEndEXTFn, _>(
+        "vkCmdDebugMarkerEndEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDebugMarkerEndEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDebugMarkerEndEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_debug_marker_end_ext(&self, f: impl FnMut(vk::CommandBuffer) + 'static) -> &Self {
+        self.set_handler::<CmdDebugMarkerEndEXTFn>("vkCmdDebugMarkerEndEXT", Box::new(f))
+    }
+}
+type CmdDebugMarkerInsertEXTFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::DebugMarkerMarkerInfoEXT<'_>);
+unsafe extern "system" fn cmd_debug_marker_insert_ext(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::DebugMarkerMarkerInfoEXT<'_>,
+) {
+    dispatch::<CmdDebugMarkerInsertEXTFn, _>(
+        "vkCmdDebugMarkerInsertEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_marker_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDebugMarkerInsertEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDebugMarkerInsertEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_debug_marker_insert_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::DebugMarkerMarkerInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDebugMarkerInsertEXTFn>("vkCmdDebugMarkerInsertEXT", Box::new(f))
+    }
+}
+type CmdDecodeVideoKHRFn = dyn FnMut(vk::CommandBuffer, *const vk::VideoDecodeInfoKHR<'_>);
+unsafe extern "system" fn cmd_decode_video_khr(
+    command_buffer: vk::CommandBuffer,
+    p_decode_info: *const vk::VideoDecodeInfoKHR<'_>,
+) {
+    dispatch::<CmdDecodeVideoKHRFn, _>(
+        "vkCmdDecodeVideoKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_decode_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDecodeVideoKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecodeVideoKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_decode_video_khr(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::VideoDecodeInfoKHR<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDecodeVideoKHRFn>("vkCmdDecodeVideoKHR", Box::new(f))
+    }
+}
+type CmdDecompressMemoryIndirectCountNVFn =
+    dyn FnMut(vk::CommandBuffer, vk::DeviceAddress, vk::DeviceAddress, u32);
+unsafe extern "system" fn cmd_decompress_memory_indirect_count_nv(
+    command_buffer: vk::CommandBuffer,
+    indirect_commands_address: vk::DeviceAddress,
+    indirect_commands_count_address: vk::DeviceAddress,
+    stride: u32,
+) {
+    dispatch::<CmdDecompressMemoryIndirectCountNVFn, _>(
+        "vkCmdDecompressMemoryIndirectCountNV",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                indirect_commands_address,
+                indirect_commands_count_address,
+                stride,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDecompressMemoryIndirectCountNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecompressMemoryIndirectCountNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_decompress_memory_indirect_count_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::DeviceAddress, vk::DeviceAddress, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDecompressMemoryIndirectCountNVFn>(
+            "vkCmdDecompressMemoryIndirectCountNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdDecompressMemoryNVFn =
+    dyn FnMut(vk::CommandBuffer, u32, *const vk::DecompressMemoryRegionNV);
+unsafe extern "system" fn cmd_decompress_memory_nv(
+    command_buffer: vk::CommandBuffer,
+    decompress_region_count: u32,
+    p_decompress_memory_regions: *const vk::DecompressMemoryRegionNV,
+) {
+    dispatch::<CmdDecompressMemoryNVFn, _>(
+        "vkCmdDecompressMemoryNV",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                decompress_region_count,
+                p_decompress_memory_regions,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDecompressMemoryNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDecompressMemoryNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_decompress_memory_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::DecompressMemoryRegionNV) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDecompressMemoryNVFn>("vkCmdDecompressMemoryNV", Box::new(f))
+    }
+}
+type CmdDispatchFn = dyn FnMut(vk::CommandBuffer, u32, u32, u32);
+unsafe extern "system" fn cmd_dispatch(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    dispatch::<CmdDispatchFn, _>(
+        "vkCmdDispatch",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, group_count_x, group_count_y, group_count_z),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDispatch`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatch.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_dispatch(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDispatchFn>("vkCmdDispatch", Box::new(f))
+    }
+}
+type CmdDispatchBaseFn = dyn FnMut(vk::CommandBuffer, u32, u32, u32, u32, u32, u32);
+unsafe extern "system" fn cmd_dispatch_base(
+    command_buffer: vk::CommandBuffer,
+    base_group_x: u32,
+    base_group_y: u32,
+    base_group_z: u32,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    dispatch::<CmdDispatchBaseFn, _>(
+        "vkCmdDispatchBase",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                base_group_x,
+                base_group_y,
+                base_group_z,
+                group_count_x,
+                group_count_y,
+                group_count_z,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDispatchBase`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchBase.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_dispatch_base(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, u32, u32, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDispatchBaseFn>("vkCmdDispatchBase", Box::new(f))
+    }
+}
+type CmdDispatchIndirectFn = dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize);
+unsafe extern "system" fn cmd_dispatch_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+) {
+    dispatch::<CmdDispatchIndirectFn, _>(
+        "vkCmdDispatchIndirect",
+        &[command_buffer.as_raw(), buffer.as_raw()],
+        |f| f(command_buffer, buffer, offset),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDispatchIndirect`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchIndirect.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_dispatch_indirect(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDispatchIndirectFn>("vkCmdDispatchIndirect", Box::new(f))
+    }
+}
+type CmdDrawFn = dyn FnMut(vk::CommandBuffer, u32, u32, u32, u32);
+unsafe extern "system" fn cmd_draw(
+    command_buffer: vk::CommandBuffer,
+    vertex_count: u32,
+    instance_count: u32,
+    first_vertex: u32,
+    first_instance: u32,
+) {
+    dispatch::<CmdDrawFn, _>(
+        "vkCmdDraw",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                vertex_count,
+                instance_count,
+                first_vertex,
+                first_instance,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDraw`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDraw.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawFn>("vkCmdDraw", Box::new(f))
+    }
+}
+type CmdDrawClusterHUAWEIFn = dyn FnMut(vk::CommandBuffer, u32, u32, u32);
+unsafe extern "system" fn cmd_draw_cluster_huawei(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    dispatch::<CmdDrawClusterHUAWEIFn, _>(
+        "vkCmdDrawClusterHUAWEI",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, group_count_x, group_count_y, group_count_z),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawClusterHUAWEI`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawClusterHUAWEI.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_cluster_huawei(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawClusterHUAWEIFn>("vkCmdDrawClusterHUAWEI", Box::new(f))
+    }
+}
+type CmdDrawClusterIndirectHUAWEIFn = dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize);
+unsafe extern "system" fn cmd_draw_cluster_indirect_huawei(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+) {
+    dispatch::<CmdDrawClusterIndirectHUAWEIFn, _>(
+        "vkCmdDrawClusterIndirectHUAWEI",
+        &[command_buffer.as_raw(), buffer.as_raw()],
+        |f| f(command_buffer, buffer, offset),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawClusterIndirectHUAWEI`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawClusterIndirectHUAWEI.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_cluster_indirect_huawei(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawClusterIndirectHUAWEIFn>(
+            "vkCmdDrawClusterIndirectHUAWEI",
+            Box::new(f),
+        )
+    }
+}
+type CmdDrawIndexedFn = dyn FnMut(vk::CommandBuffer, u32, u32, u32, i32, u32);
+unsafe extern "system" fn cmd_draw_indexed(
+    command_buffer: vk::CommandBuffer,
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    vertex_offset: i32,
+    first_instance: u32,
+) {
+    dispatch::<CmdDrawIndexedFn, _>(
+        "vkCmdDrawIndexed",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                index_count,
+                instance_count,
+                first_index,
+                vertex_offset,
+                first_instance,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawIndexed`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexed.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_indexed(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, u32, i32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawIndexedFn>("vkCmdDrawIndexed", Box::new(f))
+    }
+}
+type CmdDrawIndexedIndirectFn = dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, u32, u32);
+unsafe extern "system" fn cmd_draw_indexed_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    dispatch::<CmdDrawIndexedIndirectFn, _>(
+        "vkCmdDrawIndexedIndirect",
+        &[command_buffer.as_raw(), buffer.as_raw()],
+        |f| f(command_buffer, buffer, offset, draw_count, stride),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawIndexedIndirect`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexedIndirect.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_indexed_indirect(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawIndexedIndirectFn>("vkCmdDrawIndexedIndirect", Box::new(f))
+    }
+}
+type CmdDrawIndexedIndirectCountFn =
+    dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, vk::Buffer, vk::DeviceSize, u32, u32);
+unsafe extern "system" fn cmd_draw_indexed_indirect_count(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    dispatch::<CmdDrawIndexedIndirectCountFn, _>(
+        "vkCmdDrawIndexedIndirectCount",
+        &[
+            command_buffer.as_raw(),
+            buffer.as_raw(),
+            count_buffer.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawIndexedIndirectCount`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndexedIndirectCount.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_indexed_indirect_count(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Buffer,
+                vk::DeviceSize,
+                vk::Buffer,
+                vk::DeviceSize,
+                u32,
+                u32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawIndexedIndirectCountFn>(
+            "vkCmdDrawIndexedIndirectCount",
+            Box::new(f),
+        )
+    }
+}
+type CmdDrawIndirectFn = dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, u32, u32);
+unsafe extern "system" fn cmd_draw_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    dispatch::<CmdDrawIndirectFn, _>(
+        "vkCmdDrawIndirect",
+        &[command_buffer.as_raw(), buffer.as_raw()],
+        |f| f(command_buffer, buffer, offset, draw_count, stride),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawIndirect`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirect.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_indirect(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawIndirectFn>("vkCmdDrawIndirect", Box::new(f))
+    }
+}
+type CmdDrawIndirectByteCountEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, u32, vk::Buffer, vk::DeviceSize, u32, u32);
+unsafe extern "system" fn cmd_draw_indirect_byte_count_ext(
+    command_buffer: vk::CommandBuffer,
+    instance_count: u32,
+    first_instance: u32,
+    counter_buffer: vk::Buffer,
+    counter_buffer_offset: vk::DeviceSize,
+    counter_offset: u32,
+    vertex_stride: u32,
+) {
+    dispatch::<CmdDrawIndirectByteCountEXTFn, _>(
+        "vkCmdDrawIndirectByteCountEXT",
+        &[command_buffer.as_raw(), counter_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                instance_count,
+                first_instance,
+                counter_buffer,
+                counter_buffer_offset,
+                counter_offset,
+                vertex_stride,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawIndirectByteCountEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectByteCountEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_indirect_byte_count_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, vk::Buffer, vk::DeviceSize, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawIndirectByteCountEXTFn>(
+            "vkCmdDrawIndirectByteCountEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdDrawIndirectCountFn =
+    dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, vk::Buffer, vk::DeviceSize, u32, u32);
+unsafe extern "system" fn cmd_draw_indirect_count(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    dispatch::<CmdDrawIndirectCountFn, _>(
+        "vkCmdDrawIndirectCount",
+        &[
+            command_buffer.as_raw(),
+            buffer.as_raw(),
+            count_buffer.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawIndirectCount`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawIndirectCount.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_indirect_count(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Buffer,
+                vk::DeviceSize,
+                vk::Buffer,
+                vk::DeviceSize,
+                u32,
+                u32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawIndirectCountFn>("vkCmdDrawIndirectCount", Box::new(f))
+    }
+}
+type CmdDrawMeshTasksEXTFn = dyn FnMut(vk::CommandBuffer, u32, u32, u32);
+unsafe extern "system" fn cmd_draw_mesh_tasks_ext(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    dispatch::<CmdDrawMeshTasksEXTFn, _>(
+        "vkCmdDrawMeshTasksEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, group_count_x, group_count_y, group_count_z),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawMeshTasksEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_mesh_tasks_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawMeshTasksEXTFn>("vkCmdDrawMeshTasksEXT", Box::new(f))
+    }
+}
+type CmdDrawMeshTasksIndirectCountEXTFn =
+    dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, vk::Buffer, vk::DeviceSize, u32, u32);
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_count_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    dispatch::<CmdDrawMeshTasksIndirectCountEXTFn, _>(
+        "vkCmdDrawMeshTasksIndirectCountEXT",
+        &[
+            command_buffer.as_raw(),
+            buffer.as_raw(),
+            count_buffer.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawMeshTasksIndirectCountEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectCountEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_mesh_tasks_indirect_count_ext(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Buffer,
+                vk::DeviceSize,
+                vk::Buffer,
+                vk::DeviceSize,
+                u32,
+                u32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawMeshTasksIndirectCountEXTFn>(
+            "vkCmdDrawMeshTasksIndirectCountEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdDrawMeshTasksIndirectCountNVFn =
+    dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, vk::Buffer, vk::DeviceSize, u32, u32);
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_count_nv(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    dispatch::<CmdDrawMeshTasksIndirectCountNVFn, _>(
+        "vkCmdDrawMeshTasksIndirectCountNV",
+        &[
+            command_buffer.as_raw(),
+            buffer.as_raw(),
+            count_buffer.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                buffer,
+                offset,
+                count_buffer,
+                count_buffer_offset,
+                max_draw_count,
+                stride,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawMeshTasksIndirectCountNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectCountNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_mesh_tasks_indirect_count_nv(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Buffer,
+                vk::DeviceSize,
+                vk::Buffer,
+                vk::DeviceSize,
+                u32,
+                u32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawMeshTasksIndirectCountNVFn>(
+            "vkCmdDrawMeshTasksIndirectCountNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdDrawMeshTasksIndirectEXTFn =
+    dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, u32, u32);
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    dispatch::<CmdDrawMeshTasksIndirectEXTFn, _>(
+        "vkCmdDrawMeshTasksIndirectEXT",
+        &[command_buffer.as_raw(), buffer.as_raw()],
+        |f| f(command_buffer, buffer, offset, draw_count, stride),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawMeshTasksIndirectEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_mesh_tasks_indirect_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawMeshTasksIndirectEXTFn>(
+            "vkCmdDrawMeshTasksIndirectEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdDrawMeshTasksIndirectNVFn =
+    dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, u32, u32);
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    dispatch::<CmdDrawMeshTasksIndirectNVFn, _>(
+        "vkCmdDrawMeshTasksIndirectNV",
+        &[command_buffer.as_raw(), buffer.as_raw()],
+        |f| f(command_buffer, buffer, offset, draw_count, stride),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawMeshTasksIndirectNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_mesh_tasks_indirect_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawMeshTasksIndirectNVFn>(
+            "vkCmdDrawMeshTasksIndirectNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdDrawMeshTasksNVFn = dyn FnMut(vk::CommandBuffer, u32, u32);
+unsafe extern "system" fn cmd_draw_mesh_tasks_nv(
+    command_buffer: vk::CommandBuffer,
+    task_count: u32,
+    first_task: u32,
+) {
+    dispatch::<CmdDrawMeshTasksNVFn, _>(
+        "vkCmdDrawMeshTasksNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, task_count, first_task),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawMeshTasksNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_mesh_tasks_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawMeshTasksNVFn>("vkCmdDrawMeshTasksNV", Box::new(f))
+    }
+}
+type CmdDrawMultiEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, *const vk::MultiDrawInfoEXT, u32, u32, u32);
+unsafe extern "system" fn cmd_draw_multi_ext(
+    command_buffer: vk::CommandBuffer,
+    draw_count: u32,
+    p_vertex_info: *const vk::MultiDrawInfoEXT,
+    instance_count: u32,
+    first_instance: u32,
+    stride: u32,
+) {
+    dispatch::<CmdDrawMultiEXTFn, _>(
+        "vkCmdDrawMultiEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                draw_count,
+                p_vertex_info,
+                instance_count,
+                first_instance,
+                stride,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawMultiEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMultiEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_multi_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::MultiDrawInfoEXT, u32, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawMultiEXTFn>("vkCmdDrawMultiEXT", Box::new(f))
+    }
+}
+type CmdDrawMultiIndexedEXTFn = dyn FnMut(
+    vk::CommandBuffer,
+    u32,
+    *const vk::MultiDrawIndexedInfoEXT,
+    u32,
+    u32,
+    u32,
+    *const i32,
+);
+unsafe extern "system" fn cmd_draw_multi_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    draw_count: u32,
+    p_index_info: *const vk::MultiDrawIndexedInfoEXT,
+    instance_count: u32,
+    first_instance: u32,
+    stride: u32,
+    p_vertex_offset: *const i32,
+) {
+    dispatch::<CmdDrawMultiIndexedEXTFn, _>(
+        "vkCmdDrawMultiIndexedEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                draw_count,
+                p_index_info,
+                instance_count,
+                first_instance,
+                stride,
+                p_vertex_offset,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdDrawMultiIndexedEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMultiIndexedEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_draw_multi_indexed_ext(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                u32,
+                *const vk::MultiDrawIndexedInfoEXT,
+                u32,
+                u32,
+                u32,
+                *const i32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdDrawMultiIndexedEXTFn>("vkCmdDrawMultiIndexedEXT", Box::new(f))
+    }
+}
+type CmdEncodeVideoKHRFn = dyn FnMut(vk::CommandBuffer, *const vk::VideoEncodeInfoKHR<'_>);
+unsafe extern "system" fn cmd_encode_video_khr(
+    command_buffer: vk::CommandBuffer,
+    p_encode_info: *const vk::VideoEncodeInfoKHR<'_>,
+) {
+    dispatch::<CmdEncodeVideoKHRFn, _>(
+        "vkCmdEncodeVideoKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_encode_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdEncodeVideoKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEncodeVideoKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_encode_video_khr(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::VideoEncodeInfoKHR<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdEncodeVideoKHRFn>("vkCmdEncodeVideoKHR", Box::new(f))
+    }
+}
+type CmdEndConditionalRenderingEXTFn = dyn FnMut(vk::CommandBuffer);
+unsafe extern "system" fn cmd_end_conditional_rendering_ext(command_buffer: vk::CommandBuffer) {
+    dispatch::<CmdEndConditionalRenderingEXTFn, _>(
+        "vkCmdEndConditionalRenderingEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdEndConditionalRenderingEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndConditionalRenderingEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_end_conditional_rendering_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdEndConditionalRenderingEXTFn>(
+            "vkCmdEndConditionalRenderingEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdEndDebugUtilsLabelEXTFn = dyn FnMut(vk::CommandBuffer);
+unsafe extern "system" fn cmd_end_debug_utils_label_ext(command_buffer: vk::CommandBuffer) {
+    dispatch::<CmdEndDebugUtilsLabelEXTFn, _>(
+        "vkCmdEndDebugUtilsLabelEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdEndDebugUtilsLabelEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndDebugUtilsLabelEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_end_debug_utils_label_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdEndDebugUtilsLabelEXTFn>("vkCmdEndDebugUtilsLabelEXT", Box::new(f))
+    }
+}
+type CmdEndQueryFn = dyn FnMut(vk::CommandBuffer, vk::QueryPool, u32);
+unsafe extern "system" fn cmd_end_query(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    dispatch::<CmdEndQueryFn, _>(
+        "vkCmdEndQuery",
+        &[command_buffer.as_raw(), query_pool.as_raw()],
+        |f| f(command_buffer, query_pool, query),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdEndQuery`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndQuery.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_end_query(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::QueryPool, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdEndQueryFn>("vkCmdEndQuery", Box::new(f))
+    }
+}
+type CmdEndQueryIndexedEXTFn = dyn FnMut(vk::CommandBuffer, vk::QueryPool, u32, u32);
+unsafe extern "system" fn cmd_end_query_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    index: u32,
+) {
+    dispatch::<CmdEndQueryIndexedEXTFn, _>(
+        "vkCmdEndQueryIndexedEXT",
+        &[command_buffer.as_raw(), query_pool.as_raw()],
+        |f| f(command_buffer, query_pool, query, index),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdEndQueryIndexedEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndQueryIndexedEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_end_query_indexed_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::QueryPool, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdEndQueryIndexedEXTFn>("vkCmdEndQueryIndexedEXT", Box::new(f))
+    }
+}
+type CmdEndRenderPassFn = dyn FnMut(vk::CommandBuffer);
+unsafe extern "system" fn cmd_end_render_pass(command_buffer: vk::CommandBuffer) {
+    dispatch::<CmdEndRenderPassFn, _>(
+        "vkCmdEndRenderPass",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdEndRenderPass`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRenderPass.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_end_render_pass(&self, f: impl FnMut(vk::CommandBuffer) + 'static) -> &Self {
+        self.set_handler::<CmdEndRenderPassFn>("vkCmdEndRenderPass", Box::new(f))
+    }
+}
+type CmdEndRenderPass2Fn = dyn FnMut(vk::CommandBuffer, *const vk::SubpassEndInfo<'_>);
+unsafe extern "system" fn cmd_end_render_pass2(
+    command_buffer: vk::CommandBuffer,
+    p_subpass_end_info: *const vk::SubpassEndInfo<'_>,
+) {
+    dispatch::<CmdEndRenderPass2Fn, _>(
+        "vkCmdEndRenderPass2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_subpass_end_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdEndRenderPass2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRenderPass2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_end_render_pass2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::SubpassEndInfo<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdEndRenderPass2Fn>("vkCmdEndRenderPass2", Box::new(f))
+    }
+}
+type CmdEndRenderingFn = dyn FnMut(vk::CommandBuffer);
+unsafe extern "system" fn cmd_end_rendering(command_buffer: vk::CommandBuffer) {
+    dispatch::<CmdEndRenderingFn, _>(
+        "vkCmdEndRendering",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdEndRendering`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRendering.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_end_rendering(&self, f: impl FnMut(vk::CommandBuffer) + 'static) -> &Self {
+        self.set_handler::<CmdEndRenderingFn>("vkCmdEndRendering", Box::new(f))
+    }
+}
+type CmdEndTransformFeedbackEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::Buffer, *const vk::DeviceSize);
+unsafe extern "system" fn cmd_end_transform_feedback_ext(
+    command_buffer: vk::CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const vk::Buffer,
+    p_counter_buffer_offsets: *const vk::DeviceSize,
+) {
+    dispatch::<CmdEndTransformFeedbackEXTFn, _>(
+        "vkCmdEndTransformFeedbackEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_counter_buffer,
+                counter_buffer_count,
+                p_counter_buffers,
+                p_counter_buffer_offsets,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdEndTransformFeedbackEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndTransformFeedbackEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_end_transform_feedback_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::Buffer, *const vk::DeviceSize) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdEndTransformFeedbackEXTFn>(
+            "vkCmdEndTransformFeedbackEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdEndVideoCodingKHRFn = dyn FnMut(vk::CommandBuffer, *const vk::VideoEndCodingInfoKHR<'_>);
+unsafe extern "system" fn cmd_end_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_end_coding_info: *const vk::VideoEndCodingInfoKHR<'_>,
+) {
+    dispatch::<CmdEndVideoCodingKHRFn, _>(
+        "vkCmdEndVideoCodingKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_end_coding_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdEndVideoCodingKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndVideoCodingKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_end_video_coding_khr(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::VideoEndCodingInfoKHR<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdEndVideoCodingKHRFn>("vkCmdEndVideoCodingKHR", Box::new(f))
+    }
+}
+type CmdExecuteCommandsFn = dyn FnMut(vk::CommandBuffer, u32, *const vk::CommandBuffer);
+unsafe extern "system" fn cmd_execute_commands(
+    command_buffer: vk::CommandBuffer,
+    command_buffer_count: u32,
+    p_command_buffers: *const vk::CommandBuffer,
+) {
+    dispatch::<CmdExecuteCommandsFn, _>(
+        "vkCmdExecuteCommands",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, command_buffer_count, p_command_buffers),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdExecuteCommands`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdExecuteCommands.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_execute_commands(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::CommandBuffer) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdExecuteCommandsFn>("vkCmdExecuteCommands", Box::new(f))
+    }
+}
+type CmdExecuteGeneratedCommandsNVFn =
+    dyn FnMut(vk::CommandBuffer, vk::Bool32, *const vk::GeneratedCommandsInfoNV<'_>);
+unsafe extern "system" fn cmd_execute_generated_commands_nv(
+    command_buffer: vk::CommandBuffer,
+    is_preprocessed: vk::Bool32,
+    p_generated_commands_info: *const vk::GeneratedCommandsInfoNV<'_>,
+) {
+    dispatch::<CmdExecuteGeneratedCommandsNVFn, _>(
+        "vkCmdExecuteGeneratedCommandsNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, is_preprocessed, p_generated_commands_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdExecuteGeneratedCommandsNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdExecuteGeneratedCommandsNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_execute_generated_commands_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32, *const vk::GeneratedCommandsInfoNV<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdExecuteGeneratedCommandsNVFn>(
+            "vkCmdExecuteGeneratedCommandsNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdFillBufferFn =
+    dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, vk::DeviceSize, u32);
+unsafe extern "system" fn cmd_fill_buffer(
+    command_buffer: vk::CommandBuffer,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    data: u32,
+) {
+    dispatch::<CmdFillBufferFn, _>(
+        "vkCmdFillBuffer",
+        &[command_buffer.as_raw(), dst_buffer.as_raw()],
+        |f| f(command_buffer, dst_buffer, dst_offset, size, data),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdFillBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdFillBuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_fill_buffer(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, vk::DeviceSize, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdFillBufferFn>("vkCmdFillBuffer", Box::new(f))
+    }
+}
+type CmdInsertDebugUtilsLabelEXTFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::DebugUtilsLabelEXT<'_>);
+unsafe extern "system" fn cmd_insert_debug_utils_label_ext(
+    command_buffer: vk::CommandBuffer,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    dispatch::<CmdInsertDebugUtilsLabelEXTFn, _>(
+        "vkCmdInsertDebugUtilsLabelEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_label_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdInsertDebugUtilsLabelEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdInsertDebugUtilsLabelEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_insert_debug_utils_label_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::DebugUtilsLabelEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdInsertDebugUtilsLabelEXTFn>(
+            "vkCmdInsertDebugUtilsLabelEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdNextSubpassFn = dyn FnMut(vk::CommandBuffer, vk::SubpassContents);
+unsafe extern "system" fn cmd_next_subpass(
+    command_buffer: vk::CommandBuffer,
+    contents: vk::SubpassContents,
+) {
+    dispatch::<CmdNextSubpassFn, _>(
+        "vkCmdNextSubpass",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, contents),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdNextSubpass`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdNextSubpass.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_next_subpass(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::SubpassContents) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdNextSubpassFn>("vkCmdNextSubpass", Box::new(f))
+    }
+}
+type CmdNextSubpass2Fn =
+    dyn FnMut(vk::CommandBuffer, *const vk::SubpassBeginInfo<'_>, *const vk::SubpassEndInfo<'_>);
+unsafe extern "system" fn cmd_next_subpass2(
+    command_buffer: vk::CommandBuffer,
+    p_subpass_begin_info: *const vk::SubpassBeginInfo<'_>,
+    p_subpass_end_info: *const vk::SubpassEndInfo<'_>,
+) {
+    dispatch::<CmdNextSubpass2Fn, _>(
+        "vkCmdNextSubpass2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_subpass_begin_info, p_subpass_end_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdNextSubpass2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdNextSubpass2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_next_subpass2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::SubpassBeginInfo<'_>, *const vk::SubpassEndInfo<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdNextSubpass2Fn>("vkCmdNextSubpass2", Box::new(f))
+    }
+}
+type CmdOpticalFlowExecuteNVFn =
+    dyn FnMut(vk::CommandBuffer, vk::OpticalFlowSessionNV, *const vk::OpticalFlowExecuteInfoNV<'_>);
+unsafe extern "system" fn cmd_optical_flow_execute_nv(
+    command_buffer: vk::CommandBuffer,
+    session: vk::OpticalFlowSessionNV,
+    p_execute_info: *const vk::OpticalFlowExecuteInfoNV<'_>,
+) {
+    dispatch::<CmdOpticalFlowExecuteNVFn, _>(
+        "vkCmdOpticalFlowExecuteNV",
+        &[command_buffer.as_raw(), session.as_raw()],
+        |f| f(command_buffer, session, p_execute_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdOpticalFlowExecuteNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdOpticalFlowExecuteNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_optical_flow_execute_nv(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::OpticalFlowSessionNV,
+                *const vk::OpticalFlowExecuteInfoNV<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdOpticalFlowExecuteNVFn>("vkCmdOpticalFlowExecuteNV", Box::new(f))
+    }
+}
+type CmdPipelineBarrierFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::PipelineStageFlags,
+    vk::PipelineStageFlags,
+    vk::DependencyFlags,
+    u32,
+    *const vk::MemoryBarrier<'_>,
+    u32,
+    *const vk::BufferMemoryBarrier<'_>,
+    u32,
+    *const vk::ImageMemoryBarrier<'_>,
+);
+unsafe extern "system" fn cmd_pipeline_barrier(
+    command_buffer: vk::CommandBuffer,
+    src_stage_mask: vk::PipelineStageFlags,
+    dst_stage_mask: vk::PipelineStageFlags,
+    dependency_flags: vk::DependencyFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const vk::MemoryBarrier<'_>,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const vk::BufferMemoryBarrier<'_>,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const vk::ImageMemoryBarrier<'_>,
+) {
+    dispatch::<CmdPipelineBarrierFn, _>(
+        "vkCmdPipelineBarrier",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                src_stage_mask,
+                dst_stage_mask,
+                dependency_flags,
+                memory_barrier_count,
+                p_memory_barriers,
+                buffer_memory_barrier_count,
+                p_buffer_memory_barriers,
+                image_memory_barrier_count,
+                p_image_memory_barriers,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdPipelineBarrier`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPipelineBarrier.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_pipeline_barrier(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::PipelineStageFlags,
+                vk::PipelineStageFlags,
+                vk::DependencyFlags,
+                u32,
+                *const vk::MemoryBarrier<'_>,
+                u32,
+                *const vk::BufferMemoryBarrier<'_>,
+                u32,
+                *const vk::ImageMemoryBarrier<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdPipelineBarrierFn>("vkCmdPipelineBarrier", Box::new(f))
+    }
+}
+type CmdPipelineBarrier2Fn = dyn FnMut(vk::CommandBuffer, *const vk::DependencyInfo<'_>);
+unsafe extern "system" fn cmd_pipeline_barrier2(
+    command_buffer: vk::CommandBuffer,
+    p_dependency_info: *const vk::DependencyInfo<'_>,
+) {
+    dispatch::<CmdPipelineBarrier2Fn, _>(
+        "vkCmdPipelineBarrier2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_dependency_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdPipelineBarrier2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPipelineBarrier2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_pipeline_barrier2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::DependencyInfo<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdPipelineBarrier2Fn>("vkCmdPipelineBarrier2", Box::new(f))
+    }
+}
+type CmdPreprocessGeneratedCommandsNVFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::GeneratedCommandsInfoNV<'_>);
+unsafe extern "system" fn cmd_preprocess_generated_commands_nv(
+    command_buffer: vk::CommandBuffer,
+    p_generated_commands_info: *const vk::GeneratedCommandsInfoNV<'_>,
+) {
+    dispatch::<CmdPreprocessGeneratedCommandsNVFn, _>(
+        "vkCmdPreprocessGeneratedCommandsNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_generated_commands_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdPreprocessGeneratedCommandsNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPreprocessGeneratedCommandsNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_preprocess_generated_commands_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::GeneratedCommandsInfoNV<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdPreprocessGeneratedCommandsNVFn>(
+            "vkCmdPreprocessGeneratedCommandsNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdPushConstantsFn =
+    dyn FnMut(vk::CommandBuffer, vk::PipelineLayout, vk::ShaderStageFlags, u32, u32, *const c_void);
+unsafe extern "system" fn cmd_push_constants(
+    command_buffer: vk::CommandBuffer,
+    layout: vk::PipelineLayout,
+    stage_flags: vk::ShaderStageFlags,
+    offset: u32,
+    size: u32,
+    p_values: *const c_void,
+) {
+    dispatch::<CmdPushConstantsFn, _>(
+        "vkCmdPushConstants",
+        &[command_buffer.as_raw(), layout.as_raw()],
+        |f| f(command_buffer, layout, stage_flags, offset, size, p_values),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdPushConstants`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushConstants.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_push_constants(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::PipelineLayout,
+                vk::ShaderStageFlags,
+                u32,
+                u32,
+                *const c_void,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdPushConstantsFn>("vkCmdPushConstants", Box::new(f))
+    }
+}
+type CmdPushDescriptorSetKHRFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::PipelineBindPoint,
+    vk::PipelineLayout,
+    u32,
+    u32,
+    *const vk::WriteDescriptorSet<'_>,
+);
+unsafe extern "system" fn cmd_push_descriptor_set_khr(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    set: u32,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const vk::WriteDescriptorSet<'_>,
+) {
+    dispatch::<CmdPushDescriptorSetKHRFn, _>(
+        "vkCmdPushDescriptorSetKHR",
+        &[command_buffer.as_raw(), layout.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                pipeline_bind_point,
+                layout,
+                set,
+                descriptor_write_count,
+                p_descriptor_writes,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdPushDescriptorSetKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushDescriptorSetKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_push_descriptor_set_khr(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::PipelineBindPoint,
+                vk::PipelineLayout,
+                u32,
+                u32,
+                *const vk::WriteDescriptorSet<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdPushDescriptorSetKHRFn>("vkCmdPushDescriptorSetKHR", Box::new(f))
+    }
+}
+type CmdPushDescriptorSetWithTemplateKHRFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::DescriptorUpdateTemplate,
+    vk::PipelineLayout,
+    u32,
+    *const c_void,
+);
+unsafe extern "system" fn cmd_push_descriptor_set_with_template_khr(
+    command_buffer: vk::CommandBuffer,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    layout: vk::PipelineLayout,
+    set: u32,
+    p_data: *const c_void,
+) {
+    dispatch::<CmdPushDescriptorSetWithTemplateKHRFn, _>(
+        "vkCmdPushDescriptorSetWithTemplateKHR",
+        &[
+            command_buffer.as_raw(),
+            descriptor_update_template.as_raw(),
+            layout.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                descriptor_update_template,
+                layout,
+                set,
+                p_data,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdPushDescriptorSetWithTemplateKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushDescriptorSetWithTemplateKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_push_descriptor_set_with_template_khr(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::DescriptorUpdateTemplate,
+                vk::PipelineLayout,
+                u32,
+                *const c_void,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdPushDescriptorSetWithTemplateKHRFn>(
+            "vkCmdPushDescriptorSetWithTemplateKHR",
+            Box::new(f),
+        )
+    }
+}
+type CmdResetEventFn = dyn FnMut(vk::CommandBuffer, vk::Event, vk::PipelineStageFlags);
+unsafe extern "system" fn cmd_reset_event(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags,
+) {
+    dispatch::<CmdResetEventFn, _>(
+        "vkCmdResetEvent",
+        &[command_buffer.as_raw(), event.as_raw()],
+        |f| f(command_buffer, event, stage_mask),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdResetEvent`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetEvent.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_reset_event(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Event, vk::PipelineStageFlags) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdResetEventFn>("vkCmdResetEvent", Box::new(f))
+    }
+}
+type CmdResetEvent2Fn = dyn FnMut(vk::CommandBuffer, vk::Event, vk::PipelineStageFlags2);
+unsafe extern "system" fn cmd_reset_event2(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags2,
+) {
+    dispatch::<CmdResetEvent2Fn, _>(
+        "vkCmdResetEvent2",
+        &[command_buffer.as_raw(), event.as_raw()],
+        |f| f(command_buffer, event, stage_mask),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdResetEvent2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetEvent2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_reset_event2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Event, vk::PipelineStageFlags2) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdResetEvent2Fn>("vkCmdResetEvent2", Box::new(f))
+    }
+}
+type CmdResetQueryPoolFn = dyn FnMut(vk::CommandBuffer, vk::QueryPool, u32, u32);
+unsafe extern "system" fn cmd_reset_query_pool(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+) {
+    dispatch::<CmdResetQueryPoolFn, _>(
+        "vkCmdResetQueryPool",
+        &[command_buffer.as_raw(), query_pool.as_raw()],
+        |f| f(command_buffer, query_pool, first_query, query_count),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdResetQueryPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResetQueryPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_reset_query_pool(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::QueryPool, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdResetQueryPoolFn>("vkCmdResetQueryPool", Box::new(f))
+    }
+}
+type CmdResolveImageFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::Image,
+    vk::ImageLayout,
+    vk::Image,
+    vk::ImageLayout,
+    u32,
+    *const vk::ImageResolve,
+);
+unsafe extern "system" fn cmd_resolve_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageResolve,
+) {
+    dispatch::<CmdResolveImageFn, _>(
+        "vkCmdResolveImage",
+        &[
+            command_buffer.as_raw(),
+            src_image.as_raw(),
+            dst_image.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                src_image,
+                src_image_layout,
+                dst_image,
+                dst_image_layout,
+                region_count,
+                p_regions,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdResolveImage`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResolveImage.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_resolve_image(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Image,
+                vk::ImageLayout,
+                vk::Image,
+                vk::ImageLayout,
+                u32,
+                *const vk::ImageResolve,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdResolveImageFn>("vkCmdResolveImage", Box::new(f))
+    }
+}
+type CmdResolveImage2Fn = dyn FnMut(vk::CommandBuffer, *const vk::ResolveImageInfo2<'_>);
+unsafe extern "system" fn cmd_resolve_image2(
+    command_buffer: vk::CommandBuffer,
+    p_resolve_image_info: *const vk::ResolveImageInfo2<'_>,
+) {
+    dispatch::<CmdResolveImage2Fn, _>(
+        "vkCmdResolveImage2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_resolve_image_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdResolveImage2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdResolveImage2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_resolve_image2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::ResolveImageInfo2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdResolveImage2Fn>("vkCmdResolveImage2", Box::new(f))
+    }
+}
+type CmdSetAlphaToCoverageEnableEXTFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_alpha_to_coverage_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    alpha_to_coverage_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetAlphaToCoverageEnableEXTFn, _>(
+        "vkCmdSetAlphaToCoverageEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, alpha_to_coverage_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetAlphaToCoverageEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetAlphaToCoverageEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_alpha_to_coverage_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetAlphaToCoverageEnableEXTFn>(
+            "vkCmdSetAlphaToCoverageEnableEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetAlphaToOneEnableEXTFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_alpha_to_one_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    alpha_to_one_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetAlphaToOneEnableEXTFn, _>(
+        "vkCmdSetAlphaToOneEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, alpha_to_one_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetAlphaToOneEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetAlphaToOneEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_alpha_to_one_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetAlphaToOneEnableEXTFn>("vkCmdSetAlphaToOneEnableEXT", Box::new(f))
+    }
+}
+type CmdSetAttachmentFeedbackLoopEnableEXTFn = dyn FnMut(vk::CommandBuffer, vk::ImageAspectFlags);
+unsafe extern "system" fn cmd_set_attachment_feedback_loop_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    aspect_mask: vk::ImageAspectFlags,
+) {
+    dispatch::<CmdSetAttachmentFeedbackLoopEnableEXTFn, _>(
+        "vkCmdSetAttachmentFeedbackLoopEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, aspect_mask),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetAttachmentFeedbackLoopEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetAttachmentFeedbackLoopEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_attachment_feedback_loop_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::ImageAspectFlags) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetAttachmentFeedbackLoopEnableEXTFn>(
+            "vkCmdSetAttachmentFeedbackLoopEnableEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetBlendConstantsFn = dyn FnMut(vk::CommandBuffer, *const [f32; 4usize]);
+unsafe extern "system" fn cmd_set_blend_constants(
+    command_buffer: vk::CommandBuffer,
+    blend_constants: *const [f32; 4usize],
+) {
+    dispatch::<CmdSetBlendConstantsFn, _>(
+        "vkCmdSetBlendConstants",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, blend_constants),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetBlendConstants`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetBlendConstants.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_blend_constants(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const [f32; 4usize]) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetBlendConstantsFn>("vkCmdSetBlendConstants", Box::new(f))
+    }
+}
+type CmdSetCheckpointNVFn = dyn FnMut(vk::CommandBuffer, *const c_void);
+unsafe extern "system" fn cmd_set_checkpoint_nv(
+    command_buffer: vk::CommandBuffer,
+    p_checkpoint_marker: *const c_void,
+) {
+    dispatch::<CmdSetCheckpointNVFn, _>(
+        "vkCmdSetCheckpointNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_checkpoint_marker),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetCheckpointNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCheckpointNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_checkpoint_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const c_void) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetCheckpointNVFn>("vkCmdSetCheckpointNV", Box::new(f))
+    }
+}
+type CmdSetCoarseSampleOrderNVFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::CoarseSampleOrderTypeNV,
+    u32,
+    *const vk::CoarseSampleOrderCustomNV<'_>,
+);
+unsafe extern "system" fn cmd_set_coarse_sample_order_nv(
+    command_buffer: vk::CommandBuffer,
+    sample_order_type: vk::CoarseSampleOrderTypeNV,
+    custom_sample_order_count: u32,
+    p_custom_sample_orders: *const vk::CoarseSampleOrderCustomNV<'_>,
+) {
+    dispatch::<CmdSetCoarseSampleOrderNVFn, _>(
+        "vkCmdSetCoarseSampleOrderNV",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                sample_order_type,
+                custom_sample_order_count,
+                p_custom_sample_orders,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetCoarseSampleOrderNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoarseSampleOrderNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_coarse_sample_order_nv(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::CoarseSampleOrderTypeNV,
+                u32,
+                *const vk::CoarseSampleOrderCustomNV<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetCoarseSampleOrderNVFn>("vkCmdSetCoarseSampleOrderNV", Box::new(f))
+    }
+}
+type CmdSetColorBlendAdvancedEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::ColorBlendAdvancedEXT);
+unsafe extern "system" fn cmd_set_color_blend_advanced_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_advanced: *const vk::ColorBlendAdvancedEXT,
+) {
+    dispatch::<CmdSetColorBlendAdvancedEXTFn, _>(
+        "vkCmdSetColorBlendAdvancedEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_attachment,
+                attachment_count,
+                p_color_blend_advanced,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetColorBlendAdvancedEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorBlendAdvancedEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_color_blend_advanced_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::ColorBlendAdvancedEXT) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetColorBlendAdvancedEXTFn>(
+            "vkCmdSetColorBlendAdvancedEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetColorBlendEnableEXTFn = dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::Bool32);
+unsafe extern "system" fn cmd_set_color_blend_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_enables: *const vk::Bool32,
+) {
+    dispatch::<CmdSetColorBlendEnableEXTFn, _>(
+        "vkCmdSetColorBlendEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_attachment,
+                attachment_count,
+                p_color_blend_enables,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetColorBlendEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorBlendEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_color_blend_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetColorBlendEnableEXTFn>("vkCmdSetColorBlendEnableEXT", Box::new(f))
+    }
+}
+type CmdSetColorBlendEquationEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::ColorBlendEquationEXT);
+unsafe extern "system" fn cmd_set_color_blend_equation_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_equations: *const vk::ColorBlendEquationEXT,
+) {
+    dispatch::<CmdSetColorBlendEquationEXTFn, _>(
+        "vkCmdSetColorBlendEquationEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_attachment,
+                attachment_count,
+                p_color_blend_equations,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetColorBlendEquationEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorBlendEquationEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_color_blend_equation_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::ColorBlendEquationEXT) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetColorBlendEquationEXTFn>(
+            "vkCmdSetColorBlendEquationEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetColorWriteEnableEXTFn = dyn FnMut(vk::CommandBuffer, u32, *const vk::Bool32);
+unsafe extern "system" fn cmd_set_color_write_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    attachment_count: u32,
+    p_color_write_enables: *const vk::Bool32,
+) {
+    dispatch::<CmdSetColorWriteEnableEXTFn, _>(
+        "vkCmdSetColorWriteEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, attachment_count, p_color_write_enables),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetColorWriteEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorWriteEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_color_write_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetColorWriteEnableEXTFn>("vkCmdSetColorWriteEnableEXT", Box::new(f))
+    }
+}
+type CmdSetColorWriteMaskEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::ColorComponentFlags);
+unsafe extern "system" fn cmd_set_color_write_mask_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_write_masks: *const vk::ColorComponentFlags,
+) {
+    dispatch::<CmdSetColorWriteMaskEXTFn, _>(
+        "vkCmdSetColorWriteMaskEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_attachment,
+                attachment_count,
+                p_color_write_masks,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetColorWriteMaskEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorWriteMaskEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_color_write_mask_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::ColorComponentFlags) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetColorWriteMaskEXTFn>("vkCmdSetColorWriteMaskEXT", Box::new(f))
+    }
+}
+type CmdSetConservativeRasterizationModeEXTFn =
+    dyn FnMut(vk::CommandBuffer, vk::ConservativeRasterizationModeEXT);
+unsafe extern "system" fn cmd_set_conservative_rasterization_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    conservative_rasterization_mode: vk::ConservativeRasterizationModeEXT,
+) {
+    dispatch::<CmdSetConservativeRasterizationModeEXTFn, _>(
+        "vkCmdSetConservativeRasterizationModeEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, conservative_rasterization_mode),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetConservativeRasterizationModeEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetConservativeRasterizationModeEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_conservative_rasterization_mode_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::ConservativeRasterizationModeEXT) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetConservativeRasterizationModeEXTFn>(
+            "vkCmdSetConservativeRasterizationModeEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetCoverageModulationModeNVFn = dyn FnMut(vk::CommandBuffer, vk::CoverageModulationModeNV);
+unsafe extern "system" fn cmd_set_coverage_modulation_mode_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_mode: vk::CoverageModulationModeNV,
+) {
+    dispatch::<CmdSetCoverageModulationModeNVFn, _>(
+        "vkCmdSetCoverageModulationModeNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, coverage_modulation_mode),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetCoverageModulationModeNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationModeNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_coverage_modulation_mode_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::CoverageModulationModeNV) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetCoverageModulationModeNVFn>(
+            "vkCmdSetCoverageModulationModeNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetCoverageModulationTableEnableNVFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_coverage_modulation_table_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_table_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetCoverageModulationTableEnableNVFn, _>(
+        "vkCmdSetCoverageModulationTableEnableNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, coverage_modulation_table_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetCoverageModulationTableEnableNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationTableEnableNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_coverage_modulation_table_enable_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetCoverageModulationTableEnableNVFn>(
+            "vkCmdSetCoverageModulationTableEnableNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetCoverageModulationTableNVFn = dyn FnMut(vk::CommandBuffer, u32, *const f32);
+unsafe extern "system" fn cmd_set_coverage_modulation_table_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_table_count: u32,
+    p_coverage_modulation_table: *const f32,
+) {
+    dispatch::<CmdSetCoverageModulationTableNVFn, _>(
+        "vkCmdSetCoverageModulationTableNV",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                coverage_modulation_table_count,
+                p_coverage_modulation_table,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetCoverageModulationTableNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageModulationTableNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_coverage_modulation_table_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const f32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetCoverageModulationTableNVFn>(
+            "vkCmdSetCoverageModulationTableNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetCoverageReductionModeNVFn = dyn FnMut(vk::CommandBuffer, vk::CoverageReductionModeNV);
+unsafe extern "system" fn cmd_set_coverage_reduction_mode_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_reduction_mode: vk::CoverageReductionModeNV,
+) {
+    dispatch::<CmdSetCoverageReductionModeNVFn, _>(
+        "vkCmdSetCoverageReductionModeNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, coverage_reduction_mode),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetCoverageReductionModeNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageReductionModeNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_coverage_reduction_mode_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::CoverageReductionModeNV) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetCoverageReductionModeNVFn>(
+            "vkCmdSetCoverageReductionModeNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetCoverageToColorEnableNVFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_coverage_to_color_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_to_color_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetCoverageToColorEnableNVFn, _>(
+        "vkCmdSetCoverageToColorEnableNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, coverage_to_color_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetCoverageToColorEnableNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageToColorEnableNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_coverage_to_color_enable_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetCoverageToColorEnableNVFn>(
+            "vkCmdSetCoverageToColorEnableNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetCoverageToColorLocationNVFn = dyn FnMut(vk::CommandBuffer, u32);
+unsafe extern "system" fn cmd_set_coverage_to_color_location_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_to_color_location: u32,
+) {
+    dispatch::<CmdSetCoverageToColorLocationNVFn, _>(
+        "vkCmdSetCoverageToColorLocationNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, coverage_to_color_location),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetCoverageToColorLocationNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCoverageToColorLocationNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_coverage_to_color_location_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetCoverageToColorLocationNVFn>(
+            "vkCmdSetCoverageToColorLocationNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetCullModeFn = dyn FnMut(vk::CommandBuffer, vk::CullModeFlags);
+unsafe extern "system" fn cmd_set_cull_mode(
+    command_buffer: vk::CommandBuffer,
+    cull_mode: vk::CullModeFlags,
+) {
+    dispatch::<CmdSetCullModeFn, _>(
+        "vkCmdSetCullMode",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, cull_mode),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetCullMode`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCullMode.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_cull_mode(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::CullModeFlags) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetCullModeFn>("vkCmdSetCullMode", Box::new(f))
+    }
+}
+type CmdSetDepthBiasFn = dyn FnMut(vk::CommandBuffer, f32, f32, f32);
+unsafe extern "system" fn cmd_set_depth_bias(
+    command_buffer: vk::CommandBuffer,
+    depth_bias_constant_factor: f32,
+    depth_bias_clamp: f32,
+    depth_bias_slope_factor: f32,
+) {
+    dispatch::<CmdSetDepthBiasFn, _>(
+        "vkCmdSetDepthBias",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                depth_bias_constant_factor,
+                depth_bias_clamp,
+                depth_bias_slope_factor,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthBias`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBias.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_bias(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, f32, f32, f32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthBiasFn>("vkCmdSetDepthBias", Box::new(f))
+    }
+}
+type CmdSetDepthBias2EXTFn = dyn FnMut(vk::CommandBuffer, *const vk::DepthBiasInfoEXT<'_>);
+unsafe extern "system" fn cmd_set_depth_bias2_ext(
+    command_buffer: vk::CommandBuffer,
+    p_depth_bias_info: *const vk::DepthBiasInfoEXT<'_>,
+) {
+    dispatch::<CmdSetDepthBias2EXTFn, _>(
+        "vkCmdSetDepthBias2EXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_depth_bias_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthBias2EXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBias2EXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_bias2_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::DepthBiasInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthBias2EXTFn>("vkCmdSetDepthBias2EXT", Box::new(f))
+    }
+}
+type CmdSetDepthBiasEnableFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_depth_bias_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_bias_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetDepthBiasEnableFn, _>(
+        "vkCmdSetDepthBiasEnable",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, depth_bias_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthBiasEnable`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBiasEnable.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_bias_enable(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthBiasEnableFn>("vkCmdSetDepthBiasEnable", Box::new(f))
+    }
+}
+type CmdSetDepthBoundsFn = dyn FnMut(vk::CommandBuffer, f32, f32);
+unsafe extern "system" fn cmd_set_depth_bounds(
+    command_buffer: vk::CommandBuffer,
+    min_depth_bounds: f32,
+    max_depth_bounds: f32,
+) {
+    dispatch::<CmdSetDepthBoundsFn, _>(
+        "vkCmdSetDepthBounds",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, min_depth_bounds, max_depth_bounds),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthBounds`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBounds.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_bounds(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, f32, f32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthBoundsFn>("vkCmdSetDepthBounds", Box::new(f))
+    }
+}
+type CmdSetDepthBoundsTestEnableFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_depth_bounds_test_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_bounds_test_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetDepthBoundsTestEnableFn, _>(
+        "vkCmdSetDepthBoundsTestEnable",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, depth_bounds_test_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthBoundsTestEnable`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthBoundsTestEnable.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_bounds_test_enable(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthBoundsTestEnableFn>(
+            "vkCmdSetDepthBoundsTestEnable",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetDepthClampEnableEXTFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_depth_clamp_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    depth_clamp_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetDepthClampEnableEXTFn, _>(
+        "vkCmdSetDepthClampEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, depth_clamp_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthClampEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthClampEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_clamp_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthClampEnableEXTFn>("vkCmdSetDepthClampEnableEXT", Box::new(f))
+    }
+}
+type CmdSetDepthClipEnableEXTFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_depth_clip_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    depth_clip_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetDepthClipEnableEXTFn, _>(
+        "vkCmdSetDepthClipEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, depth_clip_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthClipEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthClipEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_clip_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthClipEnableEXTFn>("vkCmdSetDepthClipEnableEXT", Box::new(f))
+    }
+}
+type CmdSetDepthClipNegativeOneToOneEXTFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_depth_clip_negative_one_to_one_ext(
+    command_buffer: vk::CommandBuffer,
+    negative_one_to_one: vk::Bool32,
+) {
+    dispatch::<CmdSetDepthClipNegativeOneToOneEXTFn, _>(
+        "vkCmdSetDepthClipNegativeOneToOneEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, negative_one_to_one),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthClipNegativeOneToOneEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthClipNegativeOneToOneEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_clip_negative_one_to_one_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthClipNegativeOneToOneEXTFn>(
+            "vkCmdSetDepthClipNegativeOneToOneEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetDepthCompareOpFn = dyn FnMut(vk::CommandBuffer, vk::CompareOp);
+unsafe extern "system" fn cmd_set_depth_compare_op(
+    command_buffer: vk::CommandBuffer,
+    depth_compare_op: vk::CompareOp,
+) {
+    dispatch::<CmdSetDepthCompareOpFn, _>(
+        "vkCmdSetDepthCompareOp",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, depth_compare_op),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthCompareOp`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthCompareOp.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_compare_op(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::CompareOp) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthCompareOpFn>("vkCmdSetDepthCompareOp", Box::new(f))
+    }
+}
+type CmdSetDepthTestEnableFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_depth_test_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_test_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetDepthTestEnableFn, _>(
+        "vkCmdSetDepthTestEnable",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, depth_test_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthTestEnable`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthTestEnable.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_test_enable(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthTestEnableFn>("vkCmdSetDepthTestEnable", Box::new(f))
+    }
+}
+type CmdSetDepthWriteEnableFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_depth_write_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_write_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetDepthWriteEnableFn, _>(
+        "vkCmdSetDepthWriteEnable",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, depth_write_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDepthWriteEnable`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthWriteEnable.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_depth_write_enable(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDepthWriteEnableFn>("vkCmdSetDepthWriteEnable", Box::new(f))
+    }
+}
+type CmdSetDescriptorBufferOffsetsEXTFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::PipelineBindPoint,
+    vk::PipelineLayout,
+    u32,
+    u32,
+    *const u32,
+    *const vk::DeviceSize,
+);
+unsafe extern "system" fn cmd_set_descriptor_buffer_offsets_ext(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    first_set: u32,
+    set_count: u32,
+    p_buffer_indices: *const u32,
+    p_offsets: *const vk::DeviceSize,
+) {
+    dispatch::<CmdSetDescriptorBufferOffsetsEXTFn, _>(
+        "vkCmdSetDescriptorBufferOffsetsEXT",
+        &[command_buffer.as_raw(), layout.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                pipeline_bind_point,
+                layout,
+                first_set,
+                set_count,
+                p_buffer_indices,
+                p_offsets,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDescriptorBufferOffsetsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDescriptorBufferOffsetsEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_descriptor_buffer_offsets_ext(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::PipelineBindPoint,
+                vk::PipelineLayout,
+                u32,
+                u32,
+                *const u32,
+                *const vk::DeviceSize,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDescriptorBufferOffsetsEXTFn>(
+            "vkCmdSetDescriptorBufferOffsetsEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetDeviceMaskFn = dyn FnMut(vk::CommandBuffer, u32);
+unsafe extern "system" fn cmd_set_device_mask(command_buffer: vk::CommandBuffer, device_mask: u32) {
+    dispatch::<CmdSetDeviceMaskFn, _>(
+        "vkCmdSetDeviceMask",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, device_mask),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDeviceMask`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDeviceMask.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_device_mask(&self, f: impl FnMut(vk::CommandBuffer, u32) + 'static) -> &Self {
+        self.set_handler::<CmdSetDeviceMaskFn>("vkCmdSetDeviceMask", Box::new(f))
+    }
+}
+type CmdSetDiscardRectangleEXTFn = dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::Rect2D);
+unsafe extern "system" fn cmd_set_discard_rectangle_ext(
+    command_buffer: vk::CommandBuffer,
+    first_discard_rectangle: u32,
+    discard_rectangle_count: u32,
+    p_discard_rectangles: *const vk::Rect2D,
+) {
+    dispatch::<CmdSetDiscardRectangleEXTFn, _>(
+        "vkCmdSetDiscardRectangleEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_discard_rectangle,
+                discard_rectangle_count,
+                p_discard_rectangles,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDiscardRectangleEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_discard_rectangle_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::Rect2D) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDiscardRectangleEXTFn>("vkCmdSetDiscardRectangleEXT", Box::new(f))
+    }
+}
+type CmdSetDiscardRectangleEnableEXTFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_discard_rectangle_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    discard_rectangle_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetDiscardRectangleEnableEXTFn, _>(
+        "vkCmdSetDiscardRectangleEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, discard_rectangle_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDiscardRectangleEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_discard_rectangle_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDiscardRectangleEnableEXTFn>(
+            "vkCmdSetDiscardRectangleEnableEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetDiscardRectangleModeEXTFn = dyn FnMut(vk::CommandBuffer, vk::DiscardRectangleModeEXT);
+unsafe extern "system" fn cmd_set_discard_rectangle_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    discard_rectangle_mode: vk::DiscardRectangleModeEXT,
+) {
+    dispatch::<CmdSetDiscardRectangleModeEXTFn, _>(
+        "vkCmdSetDiscardRectangleModeEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, discard_rectangle_mode),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetDiscardRectangleModeEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDiscardRectangleModeEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_discard_rectangle_mode_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::DiscardRectangleModeEXT) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetDiscardRectangleModeEXTFn>(
+            "vkCmdSetDiscardRectangleModeEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetEventFn = dyn FnMut(vk::CommandBuffer, vk::Event, vk::PipelineStageFlags);
+unsafe extern "system" fn cmd_set_event(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags,
+) {
+    dispatch::<CmdSetEventFn, _>(
+        "vkCmdSetEvent",
+        &[command_buffer.as_raw(), event.as_raw()],
+        |f| f(command_buffer, event, stage_mask),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetEvent`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetEvent.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_event(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Event, vk::PipelineStageFlags) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetEventFn>("vkCmdSetEvent", Box::new(f))
+    }
+}
+type CmdSetEvent2Fn = dyn FnMut(vk::CommandBuffer, vk::Event, *const vk::DependencyInfo<'_>);
+unsafe extern "system" fn cmd_set_event2(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    p_dependency_info: *const vk::DependencyInfo<'_>,
+) {
+    dispatch::<CmdSetEvent2Fn, _>(
+        "vkCmdSetEvent2",
+        &[command_buffer.as_raw(), event.as_raw()],
+        |f| f(command_buffer, event, p_dependency_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetEvent2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetEvent2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_event2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Event, *const vk::DependencyInfo<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetEvent2Fn>("vkCmdSetEvent2", Box::new(f))
+    }
+}
+type CmdSetExclusiveScissorEnableNVFn = dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::Bool32);
+unsafe extern "system" fn cmd_set_exclusive_scissor_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissor_enables: *const vk::Bool32,
+) {
+    dispatch::<CmdSetExclusiveScissorEnableNVFn, _>(
+        "vkCmdSetExclusiveScissorEnableNV",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_exclusive_scissor,
+                exclusive_scissor_count,
+                p_exclusive_scissor_enables,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetExclusiveScissorEnableNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExclusiveScissorEnableNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_exclusive_scissor_enable_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetExclusiveScissorEnableNVFn>(
+            "vkCmdSetExclusiveScissorEnableNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetExclusiveScissorNVFn = dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::Rect2D);
+unsafe extern "system" fn cmd_set_exclusive_scissor_nv(
+    command_buffer: vk::CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissors: *const vk::Rect2D,
+) {
+    dispatch::<CmdSetExclusiveScissorNVFn, _>(
+        "vkCmdSetExclusiveScissorNV",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_exclusive_scissor,
+                exclusive_scissor_count,
+                p_exclusive_scissors,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetExclusiveScissorNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExclusiveScissorNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_exclusive_scissor_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::Rect2D) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetExclusiveScissorNVFn>("vkCmdSetExclusiveScissorNV", Box::new(f))
+    }
+}
+type CmdSetExtraPrimitiveOverestimationSizeEXTFn = dyn FnMut(vk::CommandBuffer, f32);
+unsafe extern "system" fn cmd_set_extra_primitive_overestimation_size_ext(
+    command_buffer: vk::CommandBuffer,
+    extra_primitive_overestimation_size: f32,
+) {
+    dispatch::<CmdSetExtraPrimitiveOverestimationSizeEXTFn, _>(
+        "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, extra_primitive_overestimation_size),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetExtraPrimitiveOverestimationSizeEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetExtraPrimitiveOverestimationSizeEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_extra_primitive_overestimation_size_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, f32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetExtraPrimitiveOverestimationSizeEXTFn>(
+            "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetFragmentShadingRateEnumNVFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::FragmentShadingRateNV,
+    *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+);
+unsafe extern "system" fn cmd_set_fragment_shading_rate_enum_nv(
+    command_buffer: vk::CommandBuffer,
+    shading_rate: vk::FragmentShadingRateNV,
+    combiner_ops: *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+) {
+    dispatch::<CmdSetFragmentShadingRateEnumNVFn, _>(
+        "vkCmdSetFragmentShadingRateEnumNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, shading_rate, combiner_ops),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetFragmentShadingRateEnumNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFragmentShadingRateEnumNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_fragment_shading_rate_enum_nv(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::FragmentShadingRateNV,
+                *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetFragmentShadingRateEnumNVFn>(
+            "vkCmdSetFragmentShadingRateEnumNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetFragmentShadingRateKHRFn = dyn FnMut(
+    vk::CommandBuffer,
+    *const vk::Extent2D,
+    *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+);
+unsafe extern "system" fn cmd_set_fragment_shading_rate_khr(
+    command_buffer: vk::CommandBuffer,
+    p_fragment_size: *const vk::Extent2D,
+    combiner_ops: *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+) {
+    dispatch::<CmdSetFragmentShadingRateKHRFn, _>(
+        "vkCmdSetFragmentShadingRateKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_fragment_size, combiner_ops),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetFragmentShadingRateKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFragmentShadingRateKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_fragment_shading_rate_khr(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                *const vk::Extent2D,
+                *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetFragmentShadingRateKHRFn>(
+            "vkCmdSetFragmentShadingRateKHR",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetFrontFaceFn = dyn FnMut(vk::CommandBuffer, vk::FrontFace);
+unsafe extern "system" fn cmd_set_front_face(
+    command_buffer: vk::CommandBuffer,
+    front_face: vk::FrontFace,
+) {
+    dispatch::<CmdSetFrontFaceFn, _>(
+        "vkCmdSetFrontFace",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, front_face),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetFrontFace`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFrontFace.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_front_face(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::FrontFace) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetFrontFaceFn>("vkCmdSetFrontFace", Box::new(f))
+    }
+}
+type CmdSetLineRasterizationModeEXTFn = dyn FnMut(vk::CommandBuffer, vk::LineRasterizationModeEXT);
+unsafe extern "system" fn cmd_set_line_rasterization_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    line_rasterization_mode: vk::LineRasterizationModeEXT,
+) {
+    dispatch::<CmdSetLineRasterizationModeEXTFn, _>(
+        "vkCmdSetLineRasterizationModeEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, line_rasterization_mode),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetLineRasterizationModeEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineRasterizationModeEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_line_rasterization_mode_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::LineRasterizationModeEXT) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetLineRasterizationModeEXTFn>(
+            "vkCmdSetLineRasterizationModeEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetLineStippleEXTFn = dyn FnMut(vk::CommandBuffer, u32, u16);
+unsafe extern "system" fn cmd_set_line_stipple_ext(
+    command_buffer: vk::CommandBuffer,
+    line_stipple_factor: u32,
+    line_stipple_pattern: u16,
+) {
+    dispatch::<CmdSetLineStippleEXTFn, _>(
+        "vkCmdSetLineStippleEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, line_stipple_factor, line_stipple_pattern),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetLineStippleEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineStippleEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_line_stipple_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u16) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetLineStippleEXTFn>("vkCmdSetLineStippleEXT", Box::new(f))
+    }
+}
+type CmdSetLineStippleEnableEXTFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_line_stipple_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    stippled_line_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetLineStippleEnableEXTFn, _>(
+        "vkCmdSetLineStippleEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, stippled_line_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetLineStippleEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineStippleEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_line_stipple_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetLineStippleEnableEXTFn>(
+            "vkCmdSetLineStippleEnableEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetLineWidthFn = dyn FnMut(vk::CommandBuffer, f32);
+unsafe extern "system" fn cmd_set_line_width(command_buffer: vk::CommandBuffer, line_width: f32) {
+    dispatch::<CmdSetLineWidthFn, _>(
+        "vkCmdSetLineWidth",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, line_width),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetLineWidth`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLineWidth.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_line_width(&self, f: impl FnMut(vk::CommandBuffer, f32) + 'static) -> &Self {
+        self.set_handler::<CmdSetLineWidthFn>("vkCmdSetLineWidth", Box::new(f))
+    }
+}
+type CmdSetLogicOpEXTFn = dyn FnMut(vk::CommandBuffer, vk::LogicOp);
+unsafe extern "system" fn cmd_set_logic_op_ext(
+    command_buffer: vk::CommandBuffer,
+    logic_op: vk::LogicOp,
+) {
+    dispatch::<CmdSetLogicOpEXTFn, _>(
+        "vkCmdSetLogicOpEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, logic_op),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetLogicOpEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLogicOpEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_logic_op_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::LogicOp) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetLogicOpEXTFn>("vkCmdSetLogicOpEXT", Box::new(f))
+    }
+}
+type CmdSetLogicOpEnableEXTFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_logic_op_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    logic_op_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetLogicOpEnableEXTFn, _>(
+        "vkCmdSetLogicOpEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, logic_op_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetLogicOpEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetLogicOpEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_logic_op_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetLogicOpEnableEXTFn>("vkCmdSetLogicOpEnableEXT", Box::new(f))
+    }
+}
+type CmdSetPatchControlPointsEXTFn = dyn FnMut(vk::CommandBuffer, u32);
+unsafe extern "system" fn cmd_set_patch_control_points_ext(
+    command_buffer: vk::CommandBuffer,
+    patch_control_points: u32,
+) {
+    dispatch::<CmdSetPatchControlPointsEXTFn, _>(
+        "vkCmdSetPatchControlPointsEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, patch_control_points),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetPatchControlPointsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPatchControlPointsEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_patch_control_points_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetPatchControlPointsEXTFn>(
+            "vkCmdSetPatchControlPointsEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetPerformanceMarkerINTELFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::PerformanceMarkerInfoINTEL<'_>) -> vk::Result;
+unsafe extern "system" fn cmd_set_performance_marker_intel(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::PerformanceMarkerInfoINTEL<'_>,
+) -> vk::Result {
+    dispatch::<CmdSetPerformanceMarkerINTELFn, _>(
+        "vkCmdSetPerformanceMarkerINTEL",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_marker_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetPerformanceMarkerINTEL`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceMarkerINTEL.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_performance_marker_intel(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::PerformanceMarkerInfoINTEL<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetPerformanceMarkerINTELFn>(
+            "vkCmdSetPerformanceMarkerINTEL",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetPerformanceOverrideINTELFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::PerformanceOverrideInfoINTEL<'_>) -> vk::Result;
+unsafe extern "system" fn cmd_set_performance_override_intel(
+    command_buffer: vk::CommandBuffer,
+    p_override_info: *const vk::PerformanceOverrideInfoINTEL<'_>,
+) -> vk::Result {
+    dispatch::<CmdSetPerformanceOverrideINTELFn, _>(
+        "vkCmdSetPerformanceOverrideINTEL",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_override_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetPerformanceOverrideINTEL`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceOverrideINTEL.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_performance_override_intel(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::PerformanceOverrideInfoINTEL<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetPerformanceOverrideINTELFn>(
+            "vkCmdSetPerformanceOverrideINTEL",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetPerformanceStreamMarkerINTELFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::PerformanceStreamMarkerInfoINTEL<'_>) -> vk::Result;
+unsafe extern "system" fn cmd_set_performance_stream_marker_intel(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::PerformanceStreamMarkerInfoINTEL<'_>,
+) -> vk::Result {
+    dispatch::<CmdSetPerformanceStreamMarkerINTELFn, _>(
+        "vkCmdSetPerformanceStreamMarkerINTEL",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_marker_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetPerformanceStreamMarkerINTEL`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPerformanceStreamMarkerINTEL.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_performance_stream_marker_intel(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::PerformanceStreamMarkerInfoINTEL<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetPerformanceStreamMarkerINTELFn>(
+            "vkCmdSetPerformanceStreamMarkerINTEL",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetPolygonModeEXTFn = dyn FnMut(vk::CommandBuffer, vk::PolygonMode);
+unsafe extern "system" fn cmd_set_polygon_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    polygon_mode: vk::PolygonMode,
+) {
+    dispatch::<CmdSetPolygonModeEXTFn, _>(
+        "vkCmdSetPolygonModeEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, polygon_mode),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetPolygonModeEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPolygonModeEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_polygon_mode_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::PolygonMode) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetPolygonModeEXTFn>("vkCmdSetPolygonModeEXT", Box::new(f))
+    }
+}
+type CmdSetPrimitiveRestartEnableFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_primitive_restart_enable(
+    command_buffer: vk::CommandBuffer,
+    primitive_restart_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetPrimitiveRestartEnableFn, _>(
+        "vkCmdSetPrimitiveRestartEnable",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, primitive_restart_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetPrimitiveRestartEnable`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPrimitiveRestartEnable.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_primitive_restart_enable(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetPrimitiveRestartEnableFn>(
+            "vkCmdSetPrimitiveRestartEnable",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetPrimitiveTopologyFn = dyn FnMut(vk::CommandBuffer, vk::PrimitiveTopology);
+unsafe extern "system" fn cmd_set_primitive_topology(
+    command_buffer: vk::CommandBuffer,
+    primitive_topology: vk::PrimitiveTopology,
+) {
+    dispatch::<CmdSetPrimitiveTopologyFn, _>(
+        "vkCmdSetPrimitiveTopology",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, primitive_topology),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetPrimitiveTopology`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetPrimitiveTopology.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_primitive_topology(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::PrimitiveTopology) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetPrimitiveTopologyFn>("vkCmdSetPrimitiveTopology", Box::new(f))
+    }
+}
+type CmdSetProvokingVertexModeEXTFn = dyn FnMut(vk::CommandBuffer, vk::ProvokingVertexModeEXT);
+unsafe extern "system" fn cmd_set_provoking_vertex_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    provoking_vertex_mode: vk::ProvokingVertexModeEXT,
+) {
+    dispatch::<CmdSetProvokingVertexModeEXTFn, _>(
+        "vkCmdSetProvokingVertexModeEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, provoking_vertex_mode),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetProvokingVertexModeEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetProvokingVertexModeEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_provoking_vertex_mode_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::ProvokingVertexModeEXT) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetProvokingVertexModeEXTFn>(
+            "vkCmdSetProvokingVertexModeEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetRasterizationSamplesEXTFn = dyn FnMut(vk::CommandBuffer, vk::SampleCountFlags);
+unsafe extern "system" fn cmd_set_rasterization_samples_ext(
+    command_buffer: vk::CommandBuffer,
+    rasterization_samples: vk::SampleCountFlags,
+) {
+    dispatch::<CmdSetRasterizationSamplesEXTFn, _>(
+        "vkCmdSetRasterizationSamplesEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, rasterization_samples),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetRasterizationSamplesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizationSamplesEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_rasterization_samples_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::SampleCountFlags) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetRasterizationSamplesEXTFn>(
+            "vkCmdSetRasterizationSamplesEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetRasterizationStreamEXTFn = dyn FnMut(vk::CommandBuffer, u32);
+unsafe extern "system" fn cmd_set_rasterization_stream_ext(
+    command_buffer: vk::CommandBuffer,
+    rasterization_stream: u32,
+) {
+    dispatch::<CmdSetRasterizationStreamEXTFn, _>(
+        "vkCmdSetRasterizationStreamEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, rasterization_stream),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetRasterizationStreamEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizationStreamEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_rasterization_stream_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetRasterizationStreamEXTFn>(
+            "vkCmdSetRasterizationStreamEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetRasterizerDiscardEnableFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_rasterizer_discard_enable(
+    command_buffer: vk::CommandBuffer,
+    rasterizer_discard_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetRasterizerDiscardEnableFn, _>(
+        "vkCmdSetRasterizerDiscardEnable",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, rasterizer_discard_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetRasterizerDiscardEnable`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizerDiscardEnable.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_rasterizer_discard_enable(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetRasterizerDiscardEnableFn>(
+            "vkCmdSetRasterizerDiscardEnable",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetRayTracingPipelineStackSizeKHRFn = dyn FnMut(vk::CommandBuffer, u32);
+unsafe extern "system" fn cmd_set_ray_tracing_pipeline_stack_size_khr(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stack_size: u32,
+) {
+    dispatch::<CmdSetRayTracingPipelineStackSizeKHRFn, _>(
+        "vkCmdSetRayTracingPipelineStackSizeKHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, pipeline_stack_size),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetRayTracingPipelineStackSizeKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRayTracingPipelineStackSizeKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_ray_tracing_pipeline_stack_size_khr(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetRayTracingPipelineStackSizeKHRFn>(
+            "vkCmdSetRayTracingPipelineStackSizeKHR",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetRepresentativeFragmentTestEnableNVFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_representative_fragment_test_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    representative_fragment_test_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetRepresentativeFragmentTestEnableNVFn, _>(
+        "vkCmdSetRepresentativeFragmentTestEnableNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, representative_fragment_test_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetRepresentativeFragmentTestEnableNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRepresentativeFragmentTestEnableNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_representative_fragment_test_enable_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetRepresentativeFragmentTestEnableNVFn>(
+            "vkCmdSetRepresentativeFragmentTestEnableNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetSampleLocationsEXTFn =
+    dyn FnMut(vk::CommandBuffer, *const vk::SampleLocationsInfoEXT<'_>);
+unsafe extern "system" fn cmd_set_sample_locations_ext(
+    command_buffer: vk::CommandBuffer,
+    p_sample_locations_info: *const vk::SampleLocationsInfoEXT<'_>,
+) {
+    dispatch::<CmdSetSampleLocationsEXTFn, _>(
+        "vkCmdSetSampleLocationsEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, p_sample_locations_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetSampleLocationsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetSampleLocationsEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_sample_locations_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, *const vk::SampleLocationsInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetSampleLocationsEXTFn>("vkCmdSetSampleLocationsEXT", Box::new(f))
+    }
+}
+type CmdSetSampleLocationsEnableEXTFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_sample_locations_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    sample_locations_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetSampleLocationsEnableEXTFn, _>(
+        "vkCmdSetSampleLocationsEnableEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, sample_locations_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetSampleLocationsEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetSampleLocationsEnableEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_sample_locations_enable_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetSampleLocationsEnableEXTFn>(
+            "vkCmdSetSampleLocationsEnableEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetSampleMaskEXTFn =
+    dyn FnMut(vk::CommandBuffer, vk::SampleCountFlags, *const vk::SampleMask);
+unsafe extern "system" fn cmd_set_sample_mask_ext(
+    command_buffer: vk::CommandBuffer,
+    samples: vk::SampleCountFlags,
+    p_sample_mask: *const vk::SampleMask,
+) {
+    dispatch::<CmdSetSampleMaskEXTFn, _>(
+        "vkCmdSetSampleMaskEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, samples, p_sample_mask),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetSampleMaskEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetSampleMaskEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_sample_mask_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::SampleCountFlags, *const vk::SampleMask) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetSampleMaskEXTFn>("vkCmdSetSampleMaskEXT", Box::new(f))
+    }
+}
+type CmdSetScissorFn = dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::Rect2D);
+unsafe extern "system" fn cmd_set_scissor(
+    command_buffer: vk::CommandBuffer,
+    first_scissor: u32,
+    scissor_count: u32,
+    p_scissors: *const vk::Rect2D,
+) {
+    dispatch::<CmdSetScissorFn, _>(
+        "vkCmdSetScissor",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, first_scissor, scissor_count, p_scissors),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetScissor`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetScissor.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_scissor(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::Rect2D) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetScissorFn>("vkCmdSetScissor", Box::new(f))
+    }
+}
+type CmdSetScissorWithCountFn = dyn FnMut(vk::CommandBuffer, u32, *const vk::Rect2D);
+unsafe extern "system" fn cmd_set_scissor_with_count(
+    command_buffer: vk::CommandBuffer,
+    scissor_count: u32,
+    p_scissors: *const vk::Rect2D,
+) {
+    dispatch::<CmdSetScissorWithCountFn, _>(
+        "vkCmdSetScissorWithCount",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, scissor_count, p_scissors),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetScissorWithCount`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetScissorWithCount.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_scissor_with_count(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::Rect2D) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetScissorWithCountFn>("vkCmdSetScissorWithCount", Box::new(f))
+    }
+}
+type CmdSetShadingRateImageEnableNVFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_shading_rate_image_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    shading_rate_image_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetShadingRateImageEnableNVFn, _>(
+        "vkCmdSetShadingRateImageEnableNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, shading_rate_image_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetShadingRateImageEnableNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetShadingRateImageEnableNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_shading_rate_image_enable_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetShadingRateImageEnableNVFn>(
+            "vkCmdSetShadingRateImageEnableNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetStencilCompareMaskFn = dyn FnMut(vk::CommandBuffer, vk::StencilFaceFlags, u32);
+unsafe extern "system" fn cmd_set_stencil_compare_mask(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    compare_mask: u32,
+) {
+    dispatch::<CmdSetStencilCompareMaskFn, _>(
+        "vkCmdSetStencilCompareMask",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, face_mask, compare_mask),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetStencilCompareMask`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilCompareMask.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_stencil_compare_mask(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::StencilFaceFlags, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetStencilCompareMaskFn>("vkCmdSetStencilCompareMask", Box::new(f))
+    }
+}
+type CmdSetStencilOpFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::StencilFaceFlags,
+    vk::StencilOp,
+    vk::StencilOp,
+    vk::StencilOp,
+    vk::CompareOp,
+);
+unsafe extern "system" fn cmd_set_stencil_op(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    fail_op: vk::StencilOp,
+    pass_op: vk::StencilOp,
+    depth_fail_op: vk::StencilOp,
+    compare_op: vk::CompareOp,
+) {
+    dispatch::<CmdSetStencilOpFn, _>(
+        "vkCmdSetStencilOp",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                face_mask,
+                fail_op,
+                pass_op,
+                depth_fail_op,
+                compare_op,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetStencilOp`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilOp.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_stencil_op(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::StencilFaceFlags,
+                vk::StencilOp,
+                vk::StencilOp,
+                vk::StencilOp,
+                vk::CompareOp,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetStencilOpFn>("vkCmdSetStencilOp", Box::new(f))
+    }
+}
+type CmdSetStencilReferenceFn = dyn FnMut(vk::CommandBuffer, vk::StencilFaceFlags, u32);
+unsafe extern "system" fn cmd_set_stencil_reference(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    reference: u32,
+) {
+    dispatch::<CmdSetStencilReferenceFn, _>(
+        "vkCmdSetStencilReference",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, face_mask, reference),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetStencilReference`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilReference.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_stencil_reference(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::StencilFaceFlags, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetStencilReferenceFn>("vkCmdSetStencilReference", Box::new(f))
+    }
+}
+type CmdSetStencilTestEnableFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_stencil_test_enable(
+    command_buffer: vk::CommandBuffer,
+    stencil_test_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetStencilTestEnableFn, _>(
+        "vkCmdSetStencilTestEnable",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, stencil_test_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetStencilTestEnable`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilTestEnable.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_stencil_test_enable(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetStencilTestEnableFn>("vkCmdSetStencilTestEnable", Box::new(f))
+    }
+}
+type CmdSetStencilWriteMaskFn = dyn FnMut(vk::CommandBuffer, vk::StencilFaceFlags, u32);
+unsafe extern "system" fn cmd_set_stencil_write_mask(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    write_mask: u32,
+) {
+    dispatch::<CmdSetStencilWriteMaskFn, _>(
+        "vkCmdSetStencilWriteMask",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, face_mask, write_mask),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetStencilWriteMask`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetStencilWriteMask.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_stencil_write_mask(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::StencilFaceFlags, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetStencilWriteMaskFn>("vkCmdSetStencilWriteMask", Box::new(f))
+    }
+}
+type CmdSetTessellationDomainOriginEXTFn =
+    dyn FnMut(vk::CommandBuffer, vk::TessellationDomainOrigin);
+unsafe extern "system" fn cmd_set_tessellation_domain_origin_ext(
+    command_buffer: vk::CommandBuffer,
+    domain_origin: vk::TessellationDomainOrigin,
+) {
+    dispatch::<CmdSetTessellationDomainOriginEXTFn, _>(
+        "vkCmdSetTessellationDomainOriginEXT",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, domain_origin),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetTessellationDomainOriginEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetTessellationDomainOriginEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_tessellation_domain_origin_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::TessellationDomainOrigin) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetTessellationDomainOriginEXTFn>(
+            "vkCmdSetTessellationDomainOriginEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetVertexInputEXTFn = dyn FnMut(
+    vk::CommandBuffer,
+    u32,
+    *const vk::VertexInputBindingDescription2EXT<'_>,
+    u32,
+    *const vk::VertexInputAttributeDescription2EXT<'_>,
+);
+unsafe extern "system" fn cmd_set_vertex_input_ext(
+    command_buffer: vk::CommandBuffer,
+    vertex_binding_description_count: u32,
+    p_vertex_binding_descriptions: *const vk::VertexInputBindingDescription2EXT<'_>,
+    vertex_attribute_description_count: u32,
+    p_vertex_attribute_descriptions: *const vk::VertexInputAttributeDescription2EXT<'_>,
+) {
+    dispatch::<CmdSetVertexInputEXTFn, _>(
+        "vkCmdSetVertexInputEXT",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                vertex_binding_description_count,
+                p_vertex_binding_descriptions,
+                vertex_attribute_description_count,
+                p_vertex_attribute_descriptions,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetVertexInputEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetVertexInputEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_vertex_input_ext(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                u32,
+                *const vk::VertexInputBindingDescription2EXT<'_>,
+                u32,
+                *const vk::VertexInputAttributeDescription2EXT<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetVertexInputEXTFn>("vkCmdSetVertexInputEXT", Box::new(f))
+    }
+}
+type CmdSetViewportFn = dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::Viewport);
+unsafe extern "system" fn cmd_set_viewport(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewports: *const vk::Viewport,
+) {
+    dispatch::<CmdSetViewportFn, _>(
+        "vkCmdSetViewport",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, first_viewport, viewport_count, p_viewports),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetViewport`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewport.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_viewport(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::Viewport) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetViewportFn>("vkCmdSetViewport", Box::new(f))
+    }
+}
+type CmdSetViewportShadingRatePaletteNVFn =
+    dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::ShadingRatePaletteNV<'_>);
+unsafe extern "system" fn cmd_set_viewport_shading_rate_palette_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_shading_rate_palettes: *const vk::ShadingRatePaletteNV<'_>,
+) {
+    dispatch::<CmdSetViewportShadingRatePaletteNVFn, _>(
+        "vkCmdSetViewportShadingRatePaletteNV",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_viewport,
+                viewport_count,
+                p_shading_rate_palettes,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetViewportShadingRatePaletteNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportShadingRatePaletteNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_viewport_shading_rate_palette_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::ShadingRatePaletteNV<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetViewportShadingRatePaletteNVFn>(
+            "vkCmdSetViewportShadingRatePaletteNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetViewportSwizzleNVFn =
+    dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::ViewportSwizzleNV);
+unsafe extern "system" fn cmd_set_viewport_swizzle_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_swizzles: *const vk::ViewportSwizzleNV,
+) {
+    dispatch::<CmdSetViewportSwizzleNVFn, _>(
+        "vkCmdSetViewportSwizzleNV",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_viewport,
+                viewport_count,
+                p_viewport_swizzles,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetViewportSwizzleNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportSwizzleNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_viewport_swizzle_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::ViewportSwizzleNV) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetViewportSwizzleNVFn>("vkCmdSetViewportSwizzleNV", Box::new(f))
+    }
+}
+type CmdSetViewportWScalingEnableNVFn = dyn FnMut(vk::CommandBuffer, vk::Bool32);
+unsafe extern "system" fn cmd_set_viewport_w_scaling_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    viewport_w_scaling_enable: vk::Bool32,
+) {
+    dispatch::<CmdSetViewportWScalingEnableNVFn, _>(
+        "vkCmdSetViewportWScalingEnableNV",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, viewport_w_scaling_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetViewportWScalingEnableNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWScalingEnableNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_viewport_w_scaling_enable_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetViewportWScalingEnableNVFn>(
+            "vkCmdSetViewportWScalingEnableNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdSetViewportWScalingNVFn =
+    dyn FnMut(vk::CommandBuffer, u32, u32, *const vk::ViewportWScalingNV);
+unsafe extern "system" fn cmd_set_viewport_w_scaling_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_w_scalings: *const vk::ViewportWScalingNV,
+) {
+    dispatch::<CmdSetViewportWScalingNVFn, _>(
+        "vkCmdSetViewportWScalingNV",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                first_viewport,
+                viewport_count,
+                p_viewport_w_scalings,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetViewportWScalingNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWScalingNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_viewport_w_scaling_nv(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, u32, *const vk::ViewportWScalingNV) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetViewportWScalingNVFn>("vkCmdSetViewportWScalingNV", Box::new(f))
+    }
+}
+type CmdSetViewportWithCountFn = dyn FnMut(vk::CommandBuffer, u32, *const vk::Viewport);
+unsafe extern "system" fn cmd_set_viewport_with_count(
+    command_buffer: vk::CommandBuffer,
+    viewport_count: u32,
+    p_viewports: *const vk::Viewport,
+) {
+    dispatch::<CmdSetViewportWithCountFn, _>(
+        "vkCmdSetViewportWithCount",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, viewport_count, p_viewports),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSetViewportWithCount`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWithCount.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_set_viewport_with_count(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::Viewport) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSetViewportWithCountFn>("vkCmdSetViewportWithCount", Box::new(f))
+    }
+}
+type CmdSubpassShadingHUAWEIFn = dyn FnMut(vk::CommandBuffer);
+unsafe extern "system" fn cmd_subpass_shading_huawei(command_buffer: vk::CommandBuffer) {
+    dispatch::<CmdSubpassShadingHUAWEIFn, _>(
+        "vkCmdSubpassShadingHUAWEI",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdSubpassShadingHUAWEI`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSubpassShadingHUAWEI.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_subpass_shading_huawei(
+        &self,
+        f: impl FnMut(vk::CommandBuffer) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdSubpassShadingHUAWEIFn>("vkCmdSubpassShadingHUAWEI", Box::new(f))
+    }
+}
+type CmdTraceRaysIndirect2KHRFn = dyn FnMut(vk::CommandBuffer, vk::DeviceAddress);
+unsafe extern "system" fn cmd_trace_rays_indirect2_khr(
+    command_buffer: vk::CommandBuffer,
+    indirect_device_address: vk::DeviceAddress,
+) {
+    dispatch::<CmdTraceRaysIndirect2KHRFn, _>(
+        "vkCmdTraceRaysIndirect2KHR",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, indirect_device_address),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdTraceRaysIndirect2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysIndirect2KHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_trace_rays_indirect2_khr(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::DeviceAddress) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdTraceRaysIndirect2KHRFn>("vkCmdTraceRaysIndirect2KHR", Box::new(f))
+    }
+}
+type CmdTraceRaysIndirectKHRFn = dyn FnMut(
+    vk::CommandBuffer,
+    *const vk::StridedDeviceAddressRegionKHR,
+    *const vk::StridedDeviceAddressRegionKHR,
+    *const vk::StridedDeviceAddressRegionKHR,
+    *const vk::StridedDeviceAddressRegionKHR,
+    vk::DeviceAddress,
+);
+unsafe extern "system" fn cmd_trace_rays_indirect_khr(
+    command_buffer: vk::CommandBuffer,
+    p_raygen_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_miss_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_hit_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_callable_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    indirect_device_address: vk::DeviceAddress,
+) {
+    dispatch::<CmdTraceRaysIndirectKHRFn, _>(
+        "vkCmdTraceRaysIndirectKHR",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                p_raygen_shader_binding_table,
+                p_miss_shader_binding_table,
+                p_hit_shader_binding_table,
+                p_callable_shader_binding_table,
+                indirect_device_address,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdTraceRaysIndirectKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysIndirectKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_trace_rays_indirect_khr(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                *const vk::StridedDeviceAddressRegionKHR,
+                *const vk::StridedDeviceAddressRegionKHR,
+                *const vk::StridedDeviceAddressRegionKHR,
+                *const vk::StridedDeviceAddressRegionKHR,
+                vk::DeviceAddress,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdTraceRaysIndirectKHRFn>("vkCmdTraceRaysIndirectKHR", Box::new(f))
+    }
+}
+type CmdTraceRaysKHRFn = dyn FnMut(
+    vk::CommandBuffer,
+    *const vk::StridedDeviceAddressRegionKHR,
+    *const vk::StridedDeviceAddressRegionKHR,
+    *const vk::StridedDeviceAddressRegionKHR,
+    *const vk::StridedDeviceAddressRegionKHR,
+    u32,
+    u32,
+    u32,
+);
+unsafe extern "system" fn cmd_trace_rays_khr(
+    command_buffer: vk::CommandBuffer,
+    p_raygen_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_miss_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_hit_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_callable_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    width: u32,
+    height: u32,
+    depth: u32,
+) {
+    dispatch::<CmdTraceRaysKHRFn, _>(
+        "vkCmdTraceRaysKHR",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                p_raygen_shader_binding_table,
+                p_miss_shader_binding_table,
+                p_hit_shader_binding_table,
+                p_callable_shader_binding_table,
+                width,
+                height,
+                depth,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdTraceRaysKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_trace_rays_khr(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                *const vk::StridedDeviceAddressRegionKHR,
+                *const vk::StridedDeviceAddressRegionKHR,
+                *const vk::StridedDeviceAddressRegionKHR,
+                *const vk::StridedDeviceAddressRegionKHR,
+                u32,
+                u32,
+                u32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdTraceRaysKHRFn>("vkCmdTraceRaysKHR", Box::new(f))
+    }
+}
+type CmdTraceRaysNVFn = dyn FnMut(
+    vk::CommandBuffer,
+    vk::Buffer,
+    vk::DeviceSize,
+    vk::Buffer,
+    vk::DeviceSize,
+    vk::DeviceSize,
+    vk::Buffer,
+    vk::DeviceSize,
+    vk::DeviceSize,
+    vk::Buffer,
+    vk::DeviceSize,
+    vk::DeviceSize,
+    u32,
+    u32,
+    u32,
+);
+unsafe extern "system" fn cmd_trace_rays_nv(
+    command_buffer: vk::CommandBuffer,
+    raygen_shader_binding_table_buffer: vk::Buffer,
+    raygen_shader_binding_offset: vk::DeviceSize,
+    miss_shader_binding_table_buffer: vk::Buffer,
+    miss_shader_binding_offset: vk::DeviceSize,
+    miss_shader_binding_stride: vk::DeviceSize,
+    hit_shader_binding_table_buffer: vk::Buffer,
+    hit_shader_binding_offset: vk::DeviceSize,
+    hit_shader_binding_stride: vk::DeviceSize,
+    callable_shader_binding_table_buffer: vk::Buffer,
+    callable_shader_binding_offset: vk::DeviceSize,
+    callable_shader_binding_stride: vk::DeviceSize,
+    width: u32,
+    height: u32,
+    depth: u32,
+) {
+    dispatch::<CmdTraceRaysNVFn, _>(
+        "vkCmdTraceRaysNV",
+        &[
+            command_buffer.as_raw(),
+            raygen_shader_binding_table_buffer.as_raw(),
+            miss_shader_binding_table_buffer.as_raw(),
+            hit_shader_binding_table_buffer.as_raw(),
+            callable_shader_binding_table_buffer.as_raw(),
+        ],
+        |f| {
+            f(
+                command_buffer,
+                raygen_shader_binding_table_buffer,
+                raygen_shader_binding_offset,
+                miss_shader_binding_table_buffer,
+                miss_shader_binding_offset,
+                miss_shader_binding_stride,
+                hit_shader_binding_table_buffer,
+                hit_shader_binding_offset,
+                hit_shader_binding_stride,
+                callable_shader_binding_table_buffer,
+                callable_shader_binding_offset,
+                callable_shader_binding_stride,
+                width,
+                height,
+                depth,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdTraceRaysNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_trace_rays_nv(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                vk::Buffer,
+                vk::DeviceSize,
+                vk::Buffer,
+                vk::DeviceSize,
+                vk::DeviceSize,
+                vk::Buffer,
+                vk::DeviceSize,
+                vk::DeviceSize,
+                vk::Buffer,
+                vk::DeviceSize,
+                vk::DeviceSize,
+                u32,
+                u32,
+                u32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdTraceRaysNVFn>("vkCmdTraceRaysNV", Box::new(f))
+    }
+}
+type CmdUpdateBufferFn =
+    dyn FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, vk::DeviceSize, *const c_void);
+unsafe extern "system" fn cmd_update_buffer(
+    command_buffer: vk::CommandBuffer,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    data_size: vk::DeviceSize,
+    p_data: *const c_void,
+) {
+    dispatch::<CmdUpdateBufferFn, _>(
+        "vkCmdUpdateBuffer",
+        &[command_buffer.as_raw(), dst_buffer.as_raw()],
+        |f| f(command_buffer, dst_buffer, dst_offset, data_size, p_data),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdUpdateBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdUpdateBuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_update_buffer(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::Buffer, vk::DeviceSize, vk::DeviceSize, *const c_void)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdUpdateBufferFn>("vkCmdUpdateBuffer", Box::new(f))
+    }
+}
+type CmdWaitEventsFn = dyn FnMut(
+    vk::CommandBuffer,
+    u32,
+    *const vk::Event,
+    vk::PipelineStageFlags,
+    vk::PipelineStageFlags,
+    u32,
+    *const vk::MemoryBarrier<'_>,
+    u32,
+    *const vk::BufferMemoryBarrier<'_>,
+    u32,
+    *const vk::ImageMemoryBarrier<'_>,
+);
+unsafe extern "system" fn cmd_wait_events(
+    command_buffer: vk::CommandBuffer,
+    event_count: u32,
+    p_events: *const vk::Event,
+    src_stage_mask: vk::PipelineStageFlags,
+    dst_stage_mask: vk::PipelineStageFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const vk::MemoryBarrier<'_>,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const vk::BufferMemoryBarrier<'_>,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const vk::ImageMemoryBarrier<'_>,
+) {
+    dispatch::<CmdWaitEventsFn, _>(
+        "vkCmdWaitEvents",
+        &[command_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                event_count,
+                p_events,
+                src_stage_mask,
+                dst_stage_mask,
+                memory_barrier_count,
+                p_memory_barriers,
+                buffer_memory_barrier_count,
+                p_buffer_memory_barriers,
+                image_memory_barrier_count,
+                p_image_memory_barriers,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdWaitEvents`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWaitEvents.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_wait_events(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                u32,
+                *const vk::Event,
+                vk::PipelineStageFlags,
+                vk::PipelineStageFlags,
+                u32,
+                *const vk::MemoryBarrier<'_>,
+                u32,
+                *const vk::BufferMemoryBarrier<'_>,
+                u32,
+                *const vk::ImageMemoryBarrier<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdWaitEventsFn>("vkCmdWaitEvents", Box::new(f))
+    }
+}
+type CmdWaitEvents2Fn =
+    dyn FnMut(vk::CommandBuffer, u32, *const vk::Event, *const vk::DependencyInfo<'_>);
+unsafe extern "system" fn cmd_wait_events2(
+    command_buffer: vk::CommandBuffer,
+    event_count: u32,
+    p_events: *const vk::Event,
+    p_dependency_infos: *const vk::DependencyInfo<'_>,
+) {
+    dispatch::<CmdWaitEvents2Fn, _>(
+        "vkCmdWaitEvents2",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, event_count, p_events, p_dependency_infos),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdWaitEvents2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWaitEvents2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_wait_events2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::Event, *const vk::DependencyInfo<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdWaitEvents2Fn>("vkCmdWaitEvents2", Box::new(f))
+    }
+}
+type CmdWriteAccelerationStructuresPropertiesKHRFn = dyn FnMut(
+    vk::CommandBuffer,
+    u32,
+    *const vk::AccelerationStructureKHR,
+    vk::QueryType,
+    vk::QueryPool,
+    u32,
+);
+unsafe extern "system" fn cmd_write_acceleration_structures_properties_khr(
+    command_buffer: vk::CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureKHR,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    dispatch::<CmdWriteAccelerationStructuresPropertiesKHRFn, _>(
+        "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        &[command_buffer.as_raw(), query_pool.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                acceleration_structure_count,
+                p_acceleration_structures,
+                query_type,
+                query_pool,
+                first_query,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdWriteAccelerationStructuresPropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteAccelerationStructuresPropertiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_write_acceleration_structures_properties_khr(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                u32,
+                *const vk::AccelerationStructureKHR,
+                vk::QueryType,
+                vk::QueryPool,
+                u32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdWriteAccelerationStructuresPropertiesKHRFn>(
+            "vkCmdWriteAccelerationStructuresPropertiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type CmdWriteAccelerationStructuresPropertiesNVFn = dyn FnMut(
+    vk::CommandBuffer,
+    u32,
+    *const vk::AccelerationStructureNV,
+    vk::QueryType,
+    vk::QueryPool,
+    u32,
+);
+unsafe extern "system" fn cmd_write_acceleration_structures_properties_nv(
+    command_buffer: vk::CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureNV,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    dispatch::<CmdWriteAccelerationStructuresPropertiesNVFn, _>(
+        "vkCmdWriteAccelerationStructuresPropertiesNV",
+        &[command_buffer.as_raw(), query_pool.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                acceleration_structure_count,
+                p_acceleration_structures,
+                query_type,
+                query_pool,
+                first_query,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdWriteAccelerationStructuresPropertiesNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteAccelerationStructuresPropertiesNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_write_acceleration_structures_properties_nv(
+        &self,
+        f: impl FnMut(
+                vk::CommandBuffer,
+                u32,
+                *const vk::AccelerationStructureNV,
+                vk::QueryType,
+                vk::QueryPool,
+                u32,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdWriteAccelerationStructuresPropertiesNVFn>(
+            "vkCmdWriteAccelerationStructuresPropertiesNV",
+            Box::new(f),
+        )
+    }
+}
+type CmdWriteBufferMarker2AMDFn =
+    dyn FnMut(vk::CommandBuffer, vk::PipelineStageFlags2, vk::Buffer, vk::DeviceSize, u32);
+unsafe extern "system" fn cmd_write_buffer_marker2_amd(
+    command_buffer: vk::CommandBuffer,
+    stage: vk::PipelineStageFlags2,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    marker: u32,
+) {
+    dispatch::<CmdWriteBufferMarker2AMDFn, _>(
+        "vkCmdWriteBufferMarker2AMD",
+        &[command_buffer.as_raw(), dst_buffer.as_raw()],
+        |f| f(command_buffer, stage, dst_buffer, dst_offset, marker),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdWriteBufferMarker2AMD`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteBufferMarker2AMD.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_write_buffer_marker2_amd(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::PipelineStageFlags2, vk::Buffer, vk::DeviceSize, u32)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdWriteBufferMarker2AMDFn>("vkCmdWriteBufferMarker2AMD", Box::new(f))
+    }
+}
+type CmdWriteBufferMarkerAMDFn =
+    dyn FnMut(vk::CommandBuffer, vk::PipelineStageFlags, vk::Buffer, vk::DeviceSize, u32);
+unsafe extern "system" fn cmd_write_buffer_marker_amd(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stage: vk::PipelineStageFlags,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    marker: u32,
+) {
+    dispatch::<CmdWriteBufferMarkerAMDFn, _>(
+        "vkCmdWriteBufferMarkerAMD",
+        &[command_buffer.as_raw(), dst_buffer.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                pipeline_stage,
+                dst_buffer,
+                dst_offset,
+                marker,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdWriteBufferMarkerAMD`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteBufferMarkerAMD.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_write_buffer_marker_amd(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::PipelineStageFlags, vk::Buffer, vk::DeviceSize, u32)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdWriteBufferMarkerAMDFn>("vkCmdWriteBufferMarkerAMD", Box::new(f))
+    }
+}
+type CmdWriteMicromapsPropertiesEXTFn =
+    dyn FnMut(vk::CommandBuffer, u32, *const vk::MicromapEXT, vk::QueryType, vk::QueryPool, u32);
+unsafe extern "system" fn cmd_write_micromaps_properties_ext(
+    command_buffer: vk::CommandBuffer,
+    micromap_count: u32,
+    p_micromaps: *const vk::MicromapEXT,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    dispatch::<CmdWriteMicromapsPropertiesEXTFn, _>(
+        "vkCmdWriteMicromapsPropertiesEXT",
+        &[command_buffer.as_raw(), query_pool.as_raw()],
+        |f| {
+            f(
+                command_buffer,
+                micromap_count,
+                p_micromaps,
+                query_type,
+                query_pool,
+                first_query,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdWriteMicromapsPropertiesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteMicromapsPropertiesEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_write_micromaps_properties_ext(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, u32, *const vk::MicromapEXT, vk::QueryType, vk::QueryPool, u32)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdWriteMicromapsPropertiesEXTFn>(
+            "vkCmdWriteMicromapsPropertiesEXT",
+            Box::new(f),
+        )
+    }
+}
+type CmdWriteTimestampFn = dyn FnMut(vk::CommandBuffer, vk::PipelineStageFlags, vk::QueryPool, u32);
+unsafe extern "system" fn cmd_write_timestamp(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stage: vk::PipelineStageFlags,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    dispatch::<CmdWriteTimestampFn, _>(
+        "vkCmdWriteTimestamp",
+        &[command_buffer.as_raw(), query_pool.as_raw()],
+        |f| f(command_buffer, pipeline_stage, query_pool, query),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdWriteTimestamp`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteTimestamp.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_write_timestamp(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::PipelineStageFlags, vk::QueryPool, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdWriteTimestampFn>("vkCmdWriteTimestamp", Box::new(f))
+    }
+}
+type CmdWriteTimestamp2Fn =
+    dyn FnMut(vk::CommandBuffer, vk::PipelineStageFlags2, vk::QueryPool, u32);
+unsafe extern "system" fn cmd_write_timestamp2(
+    command_buffer: vk::CommandBuffer,
+    stage: vk::PipelineStageFlags2,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    dispatch::<CmdWriteTimestamp2Fn, _>(
+        "vkCmdWriteTimestamp2",
+        &[command_buffer.as_raw(), query_pool.as_raw()],
+        |f| f(command_buffer, stage, query_pool, query),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCmdWriteTimestamp2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteTimestamp2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_cmd_write_timestamp2(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::PipelineStageFlags2, vk::QueryPool, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<CmdWriteTimestamp2Fn>("vkCmdWriteTimestamp2", Box::new(f))
+    }
+}
+type CompileDeferredNVFn = dyn FnMut(vk::Device, vk::Pipeline, u32) -> vk::Result;
+unsafe extern "system" fn compile_deferred_nv(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    shader: u32,
+) -> vk::Result {
+    dispatch::<CompileDeferredNVFn, _>(
+        "vkCompileDeferredNV",
+        &[device.as_raw(), pipeline.as_raw()],
+        |f| f(device, pipeline, shader),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCompileDeferredNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCompileDeferredNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_compile_deferred_nv(
+        &self,
+        f: impl FnMut(vk::Device, vk::Pipeline, u32) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<CompileDeferredNVFn>("vkCompileDeferredNV", Box::new(f))
+    }
+}
+type CopyAccelerationStructureKHRFn = dyn FnMut(
+    vk::Device,
+    vk::DeferredOperationKHR,
+    *const vk::CopyAccelerationStructureInfoKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn copy_acceleration_structure_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyAccelerationStructureInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<CopyAccelerationStructureKHRFn, _>(
+        "vkCopyAccelerationStructureKHR",
+        &[device.as_raw(), deferred_operation.as_raw()],
+        |f| f(device, deferred_operation, p_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCopyAccelerationStructureKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyAccelerationStructureKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_copy_acceleration_structure_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeferredOperationKHR,
+                *const vk::CopyAccelerationStructureInfoKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CopyAccelerationStructureKHRFn>(
+            "vkCopyAccelerationStructureKHR",
+            Box::new(f),
+        )
+    }
+}
+type CopyAccelerationStructureToMemoryKHRFn = dyn FnMut(
+    vk::Device,
+    vk::DeferredOperationKHR,
+    *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn copy_acceleration_structure_to_memory_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<CopyAccelerationStructureToMemoryKHRFn, _>(
+        "vkCopyAccelerationStructureToMemoryKHR",
+        &[device.as_raw(), deferred_operation.as_raw()],
+        |f| f(device, deferred_operation, p_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCopyAccelerationStructureToMemoryKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyAccelerationStructureToMemoryKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_copy_acceleration_structure_to_memory_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeferredOperationKHR,
+                *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CopyAccelerationStructureToMemoryKHRFn>(
+            "vkCopyAccelerationStructureToMemoryKHR",
+            Box::new(f),
+        )
+    }
+}
+type CopyMemoryToAccelerationStructureKHRFn = dyn FnMut(
+    vk::Device,
+    vk::DeferredOperationKHR,
+    *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn copy_memory_to_acceleration_structure_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<CopyMemoryToAccelerationStructureKHRFn, _>(
+        "vkCopyMemoryToAccelerationStructureKHR",
+        &[device.as_raw(), deferred_operation.as_raw()],
+        |f| f(device, deferred_operation, p_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCopyMemoryToAccelerationStructureKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMemoryToAccelerationStructureKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_copy_memory_to_acceleration_structure_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeferredOperationKHR,
+                *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CopyMemoryToAccelerationStructureKHRFn>(
+            "vkCopyMemoryToAccelerationStructureKHR",
+            Box::new(f),
+        )
+    }
+}
+type CopyMemoryToMicromapEXTFn = dyn FnMut(
+    vk::Device,
+    vk::DeferredOperationKHR,
+    *const vk::CopyMemoryToMicromapInfoEXT<'_>,
+) -> vk::Result;
+unsafe extern "system" fn copy_memory_to_micromap_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMemoryToMicromapInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<CopyMemoryToMicromapEXTFn, _>(
+        "vkCopyMemoryToMicromapEXT",
+        &[device.as_raw(), deferred_operation.as_raw()],
+        |f| f(device, deferred_operation, p_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCopyMemoryToMicromapEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMemoryToMicromapEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_copy_memory_to_micromap_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeferredOperationKHR,
+                *const vk::CopyMemoryToMicromapInfoEXT<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CopyMemoryToMicromapEXTFn>("vkCopyMemoryToMicromapEXT", Box::new(f))
+    }
+}
+type CopyMicromapEXTFn = dyn FnMut(
+    vk::Device,
+    vk::DeferredOperationKHR,
+    *const vk::CopyMicromapInfoEXT<'_>,
+) -> vk::Result;
+unsafe extern "system" fn copy_micromap_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMicromapInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<CopyMicromapEXTFn, _>(
+        "vkCopyMicromapEXT",
+        &[device.as_raw(), deferred_operation.as_raw()],
+        |f| f(device, deferred_operation, p_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCopyMicromapEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMicromapEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_copy_micromap_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeferredOperationKHR,
+                *const vk::CopyMicromapInfoEXT<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CopyMicromapEXTFn>("vkCopyMicromapEXT", Box::new(f))
+    }
+}
+type CopyMicromapToMemoryEXTFn = dyn FnMut(
+    vk::Device,
+    vk::DeferredOperationKHR,
+    *const vk::CopyMicromapToMemoryInfoEXT<'_>,
+) -> vk::Result;
+unsafe extern "system" fn copy_micromap_to_memory_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMicromapToMemoryInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<CopyMicromapToMemoryEXTFn, _>(
+        "vkCopyMicromapToMemoryEXT",
+        &[device.as_raw(), deferred_operation.as_raw()],
+        |f| f(device, deferred_operation, p_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCopyMicromapToMemoryEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMicromapToMemoryEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_copy_micromap_to_memory_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeferredOperationKHR,
+                *const vk::CopyMicromapToMemoryInfoEXT<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CopyMicromapToMemoryEXTFn>("vkCopyMicromapToMemoryEXT", Box::new(f))
+    }
+}
+type CreateAccelerationStructureKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::AccelerationStructureCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::AccelerationStructureKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_acceleration_structure_khr(
+    device: vk::Device,
+    p_create_info: *const vk::AccelerationStructureCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_acceleration_structure: *mut vk::AccelerationStructureKHR,
+) -> vk::Result {
+    dispatch::<CreateAccelerationStructureKHRFn, _>(
+        "vkCreateAccelerationStructureKHR",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_acceleration_structure),
+        |driver| {
+            if !p_acceleration_structure.is_null() {
+                *p_acceleration_structure = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateAccelerationStructureKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAccelerationStructureKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_acceleration_structure_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::AccelerationStructureCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::AccelerationStructureKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateAccelerationStructureKHRFn>(
+            "vkCreateAccelerationStructureKHR",
+            Box::new(f),
+        )
+    }
+}
+type CreateAccelerationStructureNVFn = dyn FnMut(
+    vk::Device,
+    *const vk::AccelerationStructureCreateInfoNV<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::AccelerationStructureNV,
+) -> vk::Result;
+unsafe extern "system" fn create_acceleration_structure_nv(
+    device: vk::Device,
+    p_create_info: *const vk::AccelerationStructureCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_acceleration_structure: *mut vk::AccelerationStructureNV,
+) -> vk::Result {
+    dispatch::<CreateAccelerationStructureNVFn, _>(
+        "vkCreateAccelerationStructureNV",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_acceleration_structure),
+        |driver| {
+            if !p_acceleration_structure.is_null() {
+                *p_acceleration_structure = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateAccelerationStructureNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAccelerationStructureNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_acceleration_structure_nv(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::AccelerationStructureCreateInfoNV<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::AccelerationStructureNV,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateAccelerationStructureNVFn>(
+            "vkCreateAccelerationStructureNV",
+            Box::new(f),
+        )
+    }
+}
+type CreateAndroidSurfaceKHRFn = dyn FnMut(
+    vk::Instance,
+    *const vk::AndroidSurfaceCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_android_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::AndroidSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateAndroidSurfaceKHRFn, _>(
+        "vkCreateAndroidSurfaceKHR",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateAndroidSurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAndroidSurfaceKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_android_surface_khr(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::AndroidSurfaceCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateAndroidSurfaceKHRFn>("vkCreateAndroidSurfaceKHR", Box::new(f))
+    }
+}
+type CreateBufferFn = dyn FnMut(
+    vk::Device,
+    *const vk::BufferCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Buffer,
+) -> vk::Result;
+unsafe extern "system" fn create_buffer(
+    device: vk::Device,
+    p_create_info: *const vk::BufferCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_buffer: *mut vk::Buffer,
+) -> vk::Result {
+    dispatch::<CreateBufferFn, _>(
+        "vkCreateBuffer",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_buffer),
+        |driver| {
+            if !p_buffer.is_null() {
+                *p_buffer = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateBuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_buffer(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::BufferCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Buffer,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateBufferFn>("vkCreateBuffer", Box::new(f))
+    }
+}
+type CreateBufferCollectionFUCHSIAFn = dyn FnMut(
+    vk::Device,
+    *const vk::BufferCollectionCreateInfoFUCHSIA<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::BufferCollectionFUCHSIA,
+) -> vk::Result;
+unsafe extern "system" fn create_buffer_collection_fuchsia(
+    device: vk::Device,
+    p_create_info: *const vk::BufferCollectionCreateInfoFUCHSIA<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_collection: *mut vk::BufferCollectionFUCHSIA,
+) -> vk::Result {
+    dispatch::<CreateBufferCollectionFUCHSIAFn, _>(
+        "vkCreateBufferCollectionFUCHSIA",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_collection),
+        |driver| {
+            if !p_collection.is_null() {
+                *p_collection = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateBufferCollectionFUCHSIA`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateBufferCollectionFUCHSIA.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_buffer_collection_fuchsia(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::BufferCollectionCreateInfoFUCHSIA<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::BufferCollectionFUCHSIA,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateBufferCollectionFUCHSIAFn>(
+            "vkCreateBufferCollectionFUCHSIA",
+            Box::new(f),
+        )
+    }
+}
+type CreateBufferViewFn = dyn FnMut(
+    vk::Device,
+    *const vk::BufferViewCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::BufferView,
+) -> vk::Result;
+unsafe extern "system" fn create_buffer_view(
+    device: vk::Device,
+    p_create_info: *const vk::BufferViewCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_view: *mut vk::BufferView,
+) -> vk::Result {
+    dispatch::<CreateBufferViewFn, _>(
+        "vkCreateBufferView",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_view),
+        |driver| {
+            if !p_view.is_null() {
+                *p_view = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateBufferView`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateBufferView.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_buffer_view(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::BufferViewCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::BufferView,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateBufferViewFn>("vkCreateBufferView", Box::new(f))
+    }
+}
+type CreateCommandPoolFn = dyn FnMut(
+    vk::Device,
+    *const vk::CommandPoolCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::CommandPool,
+) -> vk::Result;
+unsafe extern "system" fn create_command_pool(
+    device: vk::Device,
+    p_create_info: *const vk::CommandPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_command_pool: *mut vk::CommandPool,
+) -> vk::Result {
+    dispatch::<CreateCommandPoolFn, _>(
+        "vkCreateCommandPool",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_command_pool),
+        |driver| {
+            if !p_command_pool.is_null() {
+                *p_command_pool = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateCommandPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCommandPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_command_pool(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::CommandPoolCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::CommandPool,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateCommandPoolFn>("vkCreateCommandPool", Box::new(f))
+    }
+}
+type CreateComputePipelinesFn = dyn FnMut(
+    vk::Device,
+    vk::PipelineCache,
+    u32,
+    *const vk::ComputePipelineCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Pipeline,
+) -> vk::Result;
+unsafe extern "system" fn create_compute_pipelines(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::ComputePipelineCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    dispatch::<CreateComputePipelinesFn, _>(
+        "vkCreateComputePipelines",
+        &[device.as_raw(), pipeline_cache.as_raw()],
+        |f| {
+            f(
+                device,
+                pipeline_cache,
+                create_info_count,
+                p_create_infos,
+                p_allocator,
+                p_pipelines,
+            )
+        },
+        |driver| {
+            if !p_pipelines.is_null() {
+                for i in 0..create_info_count as usize {
+                    *p_pipelines.add(i) = driver.allocate();
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateComputePipelines`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateComputePipelines.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_compute_pipelines(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::PipelineCache,
+                u32,
+                *const vk::ComputePipelineCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Pipeline,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateComputePipelinesFn>("vkCreateComputePipelines", Box::new(f))
+    }
+}
+type CreateCuFunctionNVXFn = dyn FnMut(
+    vk::Device,
+    *const vk::CuFunctionCreateInfoNVX<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::CuFunctionNVX,
+) -> vk::Result;
+unsafe extern "system" fn create_cu_function_nvx(
+    device: vk::Device,
+    p_create_info: *const vk::CuFunctionCreateInfoNVX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_function: *mut vk::CuFunctionNVX,
+) -> vk::Result {
+    dispatch::<CreateCuFunctionNVXFn, _>(
+        "vkCreateCuFunctionNVX",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_function),
+        |driver| {
+            if !p_function.is_null() {
+                *p_function = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateCuFunctionNVX`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCuFunctionNVX.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_cu_function_nvx(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::CuFunctionCreateInfoNVX<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::CuFunctionNVX,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateCuFunctionNVXFn>("vkCreateCuFunctionNVX", Box::new(f))
+    }
+}
+type CreateCuModuleNVXFn = dyn FnMut(
+    vk::Device,
+    *const vk::CuModuleCreateInfoNVX<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::CuModuleNVX,
+) -> vk::Result;
+unsafe extern "system" fn create_cu_module_nvx(
+    device: vk::Device,
+    p_create_info: *const vk::CuModuleCreateInfoNVX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_module: *mut vk::CuModuleNVX,
+) -> vk::Result {
+    dispatch::<CreateCuModuleNVXFn, _>(
+        "vkCreateCuModuleNVX",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_module),
+        |driver| {
+            if !p_module.is_null() {
+                *p_module = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateCuModuleNVX`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCuModuleNVX.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_cu_module_nvx(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::CuModuleCreateInfoNVX<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::CuModuleNVX,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateCuModuleNVXFn>("vkCreateCuModuleNVX", Box::new(f))
+    }
+}
+type CreateDebugReportCallbackEXTFn = dyn FnMut(
+    vk::Instance,
+    *const vk::DebugReportCallbackCreateInfoEXT<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::DebugReportCallbackEXT,
+) -> vk::Result;
+unsafe extern "system" fn create_debug_report_callback_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::DebugReportCallbackCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_callback: *mut vk::DebugReportCallbackEXT,
+) -> vk::Result {
+    dispatch::<CreateDebugReportCallbackEXTFn, _>(
+        "vkCreateDebugReportCallbackEXT",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_callback),
+        |driver| {
+            if !p_callback.is_null() {
+                *p_callback = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateDebugReportCallbackEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDebugReportCallbackEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_debug_report_callback_ext(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::DebugReportCallbackCreateInfoEXT<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::DebugReportCallbackEXT,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateDebugReportCallbackEXTFn>(
+            "vkCreateDebugReportCallbackEXT",
+            Box::new(f),
+        )
+    }
+}
+type CreateDebugUtilsMessengerEXTFn = dyn FnMut(
+    vk::Instance,
+    *const vk::DebugUtilsMessengerCreateInfoEXT<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::DebugUtilsMessengerEXT,
+) -> vk::Result;
+unsafe extern "system" fn create_debug_utils_messenger_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::DebugUtilsMessengerCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_messenger: *mut vk::DebugUtilsMessengerEXT,
+) -> vk::Result {
+    dispatch::<CreateDebugUtilsMessengerEXTFn, _>(
+        "vkCreateDebugUtilsMessengerEXT",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_messenger),
+        |driver| {
+            if !p_messenger.is_null() {
+                *p_messenger = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateDebugUtilsMessengerEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDebugUtilsMessengerEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_debug_utils_messenger_ext(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::DebugUtilsMessengerCreateInfoEXT<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::DebugUtilsMessengerEXT,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateDebugUtilsMessengerEXTFn>(
+            "vkCreateDebugUtilsMessengerEXT",
+            Box::new(f),
+        )
+    }
+}
+type CreateDeferredOperationKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::DeferredOperationKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_deferred_operation_khr(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_deferred_operation: *mut vk::DeferredOperationKHR,
+) -> vk::Result {
+    dispatch::<CreateDeferredOperationKHRFn, _>(
+        "vkCreateDeferredOperationKHR",
+        &[device.as_raw()],
+        |f| f(device, p_allocator, p_deferred_operation),
+        |driver| {
+            if !p_deferred_operation.is_null() {
+                *p_deferred_operation = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateDeferredOperationKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDeferredOperationKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_deferred_operation_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::DeferredOperationKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateDeferredOperationKHRFn>(
+            "vkCreateDeferredOperationKHR",
+            Box::new(f),
+        )
+    }
+}
+type CreateDescriptorPoolFn = dyn FnMut(
+    vk::Device,
+    *const vk::DescriptorPoolCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::DescriptorPool,
+) -> vk::Result;
+unsafe extern "system" fn create_descriptor_pool(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_descriptor_pool: *mut vk::DescriptorPool,
+) -> vk::Result {
+    dispatch::<CreateDescriptorPoolFn, _>(
+        "vkCreateDescriptorPool",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_descriptor_pool),
+        |driver| {
+            if !p_descriptor_pool.is_null() {
+                *p_descriptor_pool = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateDescriptorPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDescriptorPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_descriptor_pool(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::DescriptorPoolCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::DescriptorPool,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateDescriptorPoolFn>("vkCreateDescriptorPool", Box::new(f))
+    }
+}
+type CreateDescriptorSetLayoutFn = dyn FnMut(
+    vk::Device,
+    *const vk::DescriptorSetLayoutCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::DescriptorSetLayout,
+) -> vk::Result;
+unsafe extern "system" fn create_descriptor_set_layout(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorSetLayoutCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_set_layout: *mut vk::DescriptorSetLayout,
+) -> vk::Result {
+    dispatch::<CreateDescriptorSetLayoutFn, _>(
+        "vkCreateDescriptorSetLayout",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_set_layout),
+        |driver| {
+            if !p_set_layout.is_null() {
+                *p_set_layout = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateDescriptorSetLayout`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDescriptorSetLayout.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_descriptor_set_layout(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::DescriptorSetLayoutCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::DescriptorSetLayout,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateDescriptorSetLayoutFn>("vkCreateDescriptorSetLayout", Box::new(f))
+    }
+}
+type CreateDescriptorUpdateTemplateFn = dyn FnMut(
+    vk::Device,
+    *const vk::DescriptorUpdateTemplateCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::DescriptorUpdateTemplate,
+) -> vk::Result;
+unsafe extern "system" fn create_descriptor_update_template(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorUpdateTemplateCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_descriptor_update_template: *mut vk::DescriptorUpdateTemplate,
+) -> vk::Result {
+    dispatch::<CreateDescriptorUpdateTemplateFn, _>(
+        "vkCreateDescriptorUpdateTemplate",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                p_create_info,
+                p_allocator,
+                p_descriptor_update_template,
+            )
+        },
+        |driver| {
+            if !p_descriptor_update_template.is_null() {
+                *p_descriptor_update_template = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateDescriptorUpdateTemplate`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDescriptorUpdateTemplate.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_descriptor_update_template(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::DescriptorUpdateTemplateCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::DescriptorUpdateTemplate,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateDescriptorUpdateTemplateFn>(
+            "vkCreateDescriptorUpdateTemplate",
+            Box::new(f),
+        )
+    }
+}
+type CreateDeviceFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::DeviceCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Device,
+) -> vk::Result;
+unsafe extern "system" fn create_device(
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    dispatch::<CreateDeviceFn, _>(
+        "vkCreateDevice",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_create_info, p_allocator, p_device),
+        |driver| {
+            if !p_device.is_null() {
+                *p_device = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateDevice`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDevice.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_device(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::DeviceCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Device,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateDeviceFn>("vkCreateDevice", Box::new(f))
+    }
+}
+type CreateDirectFBSurfaceEXTFn = dyn FnMut(
+    vk::Instance,
+    *const vk::DirectFBSurfaceCreateInfoEXT<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_direct_fb_surface_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::DirectFBSurfaceCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateDirectFBSurfaceEXTFn, _>(
+        "vkCreateDirectFBSurfaceEXT",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateDirectFBSurfaceEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDirectFBSurfaceEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_direct_fb_surface_ext(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::DirectFBSurfaceCreateInfoEXT<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateDirectFBSurfaceEXTFn>("vkCreateDirectFBSurfaceEXT", Box::new(f))
+    }
+}
+type CreateDisplayModeKHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    vk::DisplayKHR,
+    *const vk::DisplayModeCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::DisplayModeKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_display_mode_khr(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+    p_create_info: *const vk::DisplayModeCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_mode: *mut vk::DisplayModeKHR,
+) -> vk::Result {
+    dispatch::<CreateDisplayModeKHRFn, _>(
+        "vkCreateDisplayModeKHR",
+        &[physical_device.as_raw(), display.as_raw()],
+        |f| f(physical_device, display, p_create_info, p_allocator, p_mode),
+        |driver| {
+            if !p_mode.is_null() {
+                *p_mode = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateDisplayModeKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDisplayModeKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_display_mode_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                vk::DisplayKHR,
+                *const vk::DisplayModeCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::DisplayModeKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateDisplayModeKHRFn>("vkCreateDisplayModeKHR", Box::new(f))
+    }
+}
+type CreateDisplayPlaneSurfaceKHRFn = dyn FnMut(
+    vk::Instance,
+    *const vk::DisplaySurfaceCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_display_plane_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::DisplaySurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateDisplayPlaneSurfaceKHRFn, _>(
+        "vkCreateDisplayPlaneSurfaceKHR",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateDisplayPlaneSurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDisplayPlaneSurfaceKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_display_plane_surface_khr(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::DisplaySurfaceCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateDisplayPlaneSurfaceKHRFn>(
+            "vkCreateDisplayPlaneSurfaceKHR",
+            Box::new(f),
+        )
+    }
+}
+type CreateEventFn = dyn FnMut(
+    vk::Device,
+    *const vk::EventCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Event,
+) -> vk::Result;
+unsafe extern "system" fn create_event(
+    device: vk::Device,
+    p_create_info: *const vk::EventCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_event: *mut vk::Event,
+) -> vk::Result {
+    dispatch::<CreateEventFn, _>(
+        "vkCreateEvent",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_event),
+        |driver| {
+            if !p_event.is_null() {
+                *p_event = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateEvent`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateEvent.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_event(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::EventCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Event,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateEventFn>("vkCreateEvent", Box::new(f))
+    }
+}
+type CreateFenceFn = dyn FnMut(
+    vk::Device,
+    *const vk::FenceCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Fence,
+) -> vk::Result;
+unsafe extern "system" fn create_fence(
+    device: vk::Device,
+    p_create_info: *const vk::FenceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    dispatch::<CreateFenceFn, _>(
+        "vkCreateFence",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_fence),
+        |driver| {
+            if !p_fence.is_null() {
+                *p_fence = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateFence`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateFence.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_fence(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::FenceCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Fence,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateFenceFn>("vkCreateFence", Box::new(f))
+    }
+}
+type CreateFramebufferFn = dyn FnMut(
+    vk::Device,
+    *const vk::FramebufferCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Framebuffer,
+) -> vk::Result;
+unsafe extern "system" fn create_framebuffer(
+    device: vk::Device,
+    p_create_info: *const vk::FramebufferCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_framebuffer: *mut vk::Framebuffer,
+) -> vk::Result {
+    dispatch::<CreateFramebufferFn, _>(
+        "vkCreateFramebuffer",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_framebuffer),
+        |driver| {
+            if !p_framebuffer.is_null() {
+                *p_framebuffer = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateFramebuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateFramebuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_framebuffer(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::FramebufferCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Framebuffer,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateFramebufferFn>("vkCreateFramebuffer", Box::new(f))
+    }
+}
+type CreateGraphicsPipelinesFn = dyn FnMut(
+    vk::Device,
+    vk::PipelineCache,
+    u32,
+    *const vk::GraphicsPipelineCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Pipeline,
+) -> vk::Result;
+unsafe extern "system" fn create_graphics_pipelines(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::GraphicsPipelineCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    dispatch::<CreateGraphicsPipelinesFn, _>(
+        "vkCreateGraphicsPipelines",
+        &[device.as_raw(), pipeline_cache.as_raw()],
+        |f| {
+            f(
+                device,
+                pipeline_cache,
+                create_info_count,
+                p_create_infos,
+                p_allocator,
+                p_pipelines,
+            )
+        },
+        |driver| {
+            if !p_pipelines.is_null() {
+                for i in 0..create_info_count as usize {
+                    *p_pipelines.add(i) = driver.allocate();
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateGraphicsPipelines`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateGraphicsPipelines.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_graphics_pipelines(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::PipelineCache,
+                u32,
+                *const vk::GraphicsPipelineCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Pipeline,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateGraphicsPipelinesFn>("vkCreateGraphicsPipelines", Box::new(f))
+    }
+}
+type CreateHeadlessSurfaceEXTFn = dyn FnMut(
+    vk::Instance,
+    *const vk::HeadlessSurfaceCreateInfoEXT<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_headless_surface_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::HeadlessSurfaceCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateHeadlessSurfaceEXTFn, _>(
+        "vkCreateHeadlessSurfaceEXT",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateHeadlessSurfaceEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateHeadlessSurfaceEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_headless_surface_ext(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::HeadlessSurfaceCreateInfoEXT<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateHeadlessSurfaceEXTFn>("vkCreateHeadlessSurfaceEXT", Box::new(f))
+    }
+}
+type CreateIOSSurfaceMVKFn = dyn FnMut(
+    vk::Instance,
+    *const vk::IOSSurfaceCreateInfoMVK<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_ios_surface_mvk(
+    instance: vk::Instance,
+    p_create_info: *const vk::IOSSurfaceCreateInfoMVK<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateIOSSurfaceMVKFn, _>(
+        "vkCreateIOSSurfaceMVK",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateIOSSurfaceMVK`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateIOSSurfaceMVK.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_ios_surface_mvk(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::IOSSurfaceCreateInfoMVK<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateIOSSurfaceMVKFn>("vkCreateIOSSurfaceMVK", Box::new(f))
+    }
+}
+type CreateImageFn = dyn FnMut(
+    vk::Device,
+    *const vk::ImageCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Image,
+) -> vk::Result;
+unsafe extern "system" fn create_image(
+    device: vk::Device,
+    p_create_info: *const vk::ImageCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_image: *mut vk::Image,
+) -> vk::Result {
+    dispatch::<CreateImageFn, _>(
+        "vkCreateImage",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_image),
+        |driver| {
+            if !p_image.is_null() {
+                *p_image = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateImage`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateImage.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_image(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::ImageCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Image,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateImageFn>("vkCreateImage", Box::new(f))
+    }
+}
+type CreateImagePipeSurfaceFUCHSIAFn = dyn FnMut(
+    vk::Instance,
+    *const vk::ImagePipeSurfaceCreateInfoFUCHSIA<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_image_pipe_surface_fuchsia(
+    instance: vk::Instance,
+    p_create_info: *const vk::ImagePipeSurfaceCreateInfoFUCHSIA<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateImagePipeSurfaceFUCHSIAFn, _>(
+        "vkCreateImagePipeSurfaceFUCHSIA",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateImagePipeSurfaceFUCHSIA`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateImagePipeSurfaceFUCHSIA.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_image_pipe_surface_fuchsia(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::ImagePipeSurfaceCreateInfoFUCHSIA<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateImagePipeSurfaceFUCHSIAFn>(
+            "vkCreateImagePipeSurfaceFUCHSIA",
+            Box::new(f),
+        )
+    }
+}
+type CreateImageViewFn = dyn FnMut(
+    vk::Device,
+    *const vk::ImageViewCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::ImageView,
+) -> vk::Result;
+unsafe extern "system" fn create_image_view(
+    device: vk::Device,
+    p_create_info: *const vk::ImageViewCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_view: *mut vk::ImageView,
+) -> vk::Result {
+    dispatch::<CreateImageViewFn, _>(
+        "vkCreateImageView",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_view),
+        |driver| {
+            if !p_view.is_null() {
+                *p_view = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateImageView`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateImageView.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_image_view(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::ImageViewCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::ImageView,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateImageViewFn>("vkCreateImageView", Box::new(f))
+    }
+}
+type CreateIndirectCommandsLayoutNVFn = dyn FnMut(
+    vk::Device,
+    *const vk::IndirectCommandsLayoutCreateInfoNV<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::IndirectCommandsLayoutNV,
+) -> vk::Result;
+unsafe extern "system" fn create_indirect_commands_layout_nv(
+    device: vk::Device,
+    p_create_info: *const vk::IndirectCommandsLayoutCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_indirect_commands_layout: *mut vk::IndirectCommandsLayoutNV,
+) -> vk::Result {
+    dispatch::<CreateIndirectCommandsLayoutNVFn, _>(
+        "vkCreateIndirectCommandsLayoutNV",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                p_create_info,
+                p_allocator,
+                p_indirect_commands_layout,
+            )
+        },
+        |driver| {
+            if !p_indirect_commands_layout.is_null() {
+                *p_indirect_commands_layout = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateIndirectCommandsLayoutNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateIndirectCommandsLayoutNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_indirect_commands_layout_nv(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::IndirectCommandsLayoutCreateInfoNV<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::IndirectCommandsLayoutNV,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateIndirectCommandsLayoutNVFn>(
+            "vkCreateIndirectCommandsLayoutNV",
+            Box::new(f),
+        )
+    }
+}
+type CreateInstanceFn = dyn FnMut(
+    *const vk::InstanceCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Instance,
+) -> vk::Result;
+unsafe extern "system" fn create_instance(
+    p_create_info: *const vk::InstanceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    dispatch::<CreateInstanceFn, _>(
+        "vkCreateInstance",
+        &[],
+        |f| f(p_create_info, p_allocator, p_instance),
+        |driver| {
+            if !p_instance.is_null() {
+                *p_instance = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateInstance`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateInstance.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_instance(
+        &self,
+        f: impl FnMut(
+                *const vk::InstanceCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Instance,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateInstanceFn>("vkCreateInstance", Box::new(f))
+    }
+}
+type CreateMacOSSurfaceMVKFn = dyn FnMut(
+    vk::Instance,
+    *const vk::MacOSSurfaceCreateInfoMVK<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_mac_os_surface_mvk(
+    instance: vk::Instance,
+    p_create_info: *const vk::MacOSSurfaceCreateInfoMVK<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateMacOSSurfaceMVKFn, _>(
+        "vkCreateMacOSSurfaceMVK",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateMacOSSurfaceMVK`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateMacOSSurfaceMVK.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_mac_os_surface_mvk(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::MacOSSurfaceCreateInfoMVK<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateMacOSSurfaceMVKFn>("vkCreateMacOSSurfaceMVK", Box::new(f))
+    }
+}
+type CreateMetalSurfaceEXTFn = dyn FnMut(
+    vk::Instance,
+    *const vk::MetalSurfaceCreateInfoEXT<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_metal_surface_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::MetalSurfaceCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateMetalSurfaceEXTFn, _>(
+        "vkCreateMetalSurfaceEXT",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateMetalSurfaceEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateMetalSurfaceEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_metal_surface_ext(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::MetalSurfaceCreateInfoEXT<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateMetalSurfaceEXTFn>("vkCreateMetalSurfaceEXT", Box::new(f))
+    }
+}
+type CreateMicromapEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::MicromapCreateInfoEXT<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::MicromapEXT,
+) -> vk::Result;
+unsafe extern "system" fn create_micromap_ext(
+    device: vk::Device,
+    p_create_info: *const vk::MicromapCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_micromap: *mut vk::MicromapEXT,
+) -> vk::Result {
+    dispatch::<CreateMicromapEXTFn, _>(
+        "vkCreateMicromapEXT",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_micromap),
+        |driver| {
+            if !p_micromap.is_null() {
+                *p_micromap = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateMicromapEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateMicromapEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_micromap_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::MicromapCreateInfoEXT<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::MicromapEXT,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateMicromapEXTFn>("vkCreateMicromapEXT", Box::new(f))
+    }
+}
+type CreateOpticalFlowSessionNVFn = dyn FnMut(
+    vk::Device,
+    *const vk::OpticalFlowSessionCreateInfoNV<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::OpticalFlowSessionNV,
+) -> vk::Result;
+unsafe extern "system" fn create_optical_flow_session_nv(
+    device: vk::Device,
+    p_create_info: *const vk::OpticalFlowSessionCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_session: *mut vk::OpticalFlowSessionNV,
+) -> vk::Result {
+    dispatch::<CreateOpticalFlowSessionNVFn, _>(
+        "vkCreateOpticalFlowSessionNV",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_session),
+        |driver| {
+            if !p_session.is_null() {
+                *p_session = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateOpticalFlowSessionNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateOpticalFlowSessionNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_optical_flow_session_nv(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::OpticalFlowSessionCreateInfoNV<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::OpticalFlowSessionNV,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateOpticalFlowSessionNVFn>(
+            "vkCreateOpticalFlowSessionNV",
+            Box::new(f),
+        )
+    }
+}
+type CreatePipelineCacheFn = dyn FnMut(
+    vk::Device,
+    *const vk::PipelineCacheCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::PipelineCache,
+) -> vk::Result;
+unsafe extern "system" fn create_pipeline_cache(
+    device: vk::Device,
+    p_create_info: *const vk::PipelineCacheCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipeline_cache: *mut vk::PipelineCache,
+) -> vk::Result {
+    dispatch::<CreatePipelineCacheFn, _>(
+        "vkCreatePipelineCache",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_pipeline_cache),
+        |driver| {
+            if !p_pipeline_cache.is_null() {
+                *p_pipeline_cache = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreatePipelineCache`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreatePipelineCache.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_pipeline_cache(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::PipelineCacheCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::PipelineCache,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreatePipelineCacheFn>("vkCreatePipelineCache", Box::new(f))
+    }
+}
+type CreatePipelineLayoutFn = dyn FnMut(
+    vk::Device,
+    *const vk::PipelineLayoutCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::PipelineLayout,
+) -> vk::Result;
+unsafe extern "system" fn create_pipeline_layout(
+    device: vk::Device,
+    p_create_info: *const vk::PipelineLayoutCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipeline_layout: *mut vk::PipelineLayout,
+) -> vk::Result {
+    dispatch::<CreatePipelineLayoutFn, _>(
+        "vkCreatePipelineLayout",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_pipeline_layout),
+        |driver| {
+            if !p_pipeline_layout.is_null() {
+                *p_pipeline_layout = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreatePipelineLayout`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreatePipelineLayout.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_pipeline_layout(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::PipelineLayoutCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::PipelineLayout,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreatePipelineLayoutFn>("vkCreatePipelineLayout", Box::new(f))
+    }
+}
+type CreatePrivateDataSlotFn = dyn FnMut(
+    vk::Device,
+    *const vk::PrivateDataSlotCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::PrivateDataSlot,
+) -> vk::Result;
+unsafe extern "system" fn create_private_data_slot(
+    device: vk::Device,
+    p_create_info: *const vk::PrivateDataSlotCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_private_data_slot: *mut vk::PrivateDataSlot,
+) -> vk::Result {
+    dispatch::<CreatePrivateDataSlotFn, _>(
+        "vkCreatePrivateDataSlot",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_private_data_slot),
+        |driver| {
+            if !p_private_data_slot.is_null() {
+                *p_private_data_slot = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreatePrivateDataSlot`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreatePrivateDataSlot.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_private_data_slot(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::PrivateDataSlotCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::PrivateDataSlot,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreatePrivateDataSlotFn>("vkCreatePrivateDataSlot", Box::new(f))
+    }
+}
+type CreateQueryPoolFn = dyn FnMut(
+    vk::Device,
+    *const vk::QueryPoolCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::QueryPool,
+) -> vk::Result;
+unsafe extern "system" fn create_query_pool(
+    device: vk::Device,
+    p_create_info: *const vk::QueryPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_query_pool: *mut vk::QueryPool,
+) -> vk::Result {
+    dispatch::<CreateQueryPoolFn, _>(
+        "vkCreateQueryPool",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_query_pool),
+        |driver| {
+            if !p_query_pool.is_null() {
+                *p_query_pool = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateQueryPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateQueryPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_query_pool(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::QueryPoolCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::QueryPool,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateQueryPoolFn>("vkCreateQueryPool", Box::new(f))
+    }
+}
+type CreateRayTracingPipelinesKHRFn = dyn FnMut(
+    vk::Device,
+    vk::DeferredOperationKHR,
+    vk::PipelineCache,
+    u32,
+    *const vk::RayTracingPipelineCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Pipeline,
+) -> vk::Result;
+unsafe extern "system" fn create_ray_tracing_pipelines_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::RayTracingPipelineCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    dispatch::<CreateRayTracingPipelinesKHRFn, _>(
+        "vkCreateRayTracingPipelinesKHR",
+        &[
+            device.as_raw(),
+            deferred_operation.as_raw(),
+            pipeline_cache.as_raw(),
+        ],
+        |f| {
+            f(
+                device,
+                deferred_operation,
+                pipeline_cache,
+                create_info_count,
+                p_create_infos,
+                p_allocator,
+                p_pipelines,
+            )
+        },
+        |driver| {
+            if !p_pipelines.is_null() {
+                for i in 0..create_info_count as usize {
+                    *p_pipelines.add(i) = driver.allocate();
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateRayTracingPipelinesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRayTracingPipelinesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_ray_tracing_pipelines_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeferredOperationKHR,
+                vk::PipelineCache,
+                u32,
+                *const vk::RayTracingPipelineCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Pipeline,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateRayTracingPipelinesKHRFn>(
+            "vkCreateRayTracingPipelinesKHR",
+            Box::new(f),
+        )
+    }
+}
+type CreateRayTracingPipelinesNVFn = dyn FnMut(
+    vk::Device,
+    vk::PipelineCache,
+    u32,
+    *const vk::RayTracingPipelineCreateInfoNV<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Pipeline,
+) -> vk::Result;
+unsafe extern "system" fn create_ray_tracing_pipelines_nv(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::RayTracingPipelineCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    dispatch::<CreateRayTracingPipelinesNVFn, _>(
+        "vkCreateRayTracingPipelinesNV",
+        &[device.as_raw(), pipeline_cache.as_raw()],
+        |f| {
+            f(
+                device,
+                pipeline_cache,
+                create_info_count,
+                p_create_infos,
+                p_allocator,
+                p_pipelines,
+            )
+        },
+        |driver| {
+            if !p_pipelines.is_null() {
+                for i in 0..create_info_count as usize {
+                    *p_pipelines.add(i) = driver.allocate();
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateRayTracingPipelinesNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRayTracingPipelinesNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_ray_tracing_pipelines_nv(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::PipelineCache,
+                u32,
+                *const vk::RayTracingPipelineCreateInfoNV<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Pipeline,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateRayTracingPipelinesNVFn>(
+            "vkCreateRayTracingPipelinesNV",
+            Box::new(f),
+        )
+    }
+}
+type CreateRenderPassFn = dyn FnMut(
+    vk::Device,
+    *const vk::RenderPassCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::RenderPass,
+) -> vk::Result;
+unsafe extern "system" fn create_render_pass(
+    device: vk::Device,
+    p_create_info: *const vk::RenderPassCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_render_pass: *mut vk::RenderPass,
+) -> vk::Result {
+    dispatch::<CreateRenderPassFn, _>(
+        "vkCreateRenderPass",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_render_pass),
+        |driver| {
+            if !p_render_pass.is_null() {
+                *p_render_pass = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateRenderPass`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRenderPass.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_render_pass(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::RenderPassCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::RenderPass,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateRenderPassFn>("vkCreateRenderPass", Box::new(f))
+    }
+}
+type CreateRenderPass2Fn = dyn FnMut(
+    vk::Device,
+    *const vk::RenderPassCreateInfo2<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::RenderPass,
+) -> vk::Result;
+unsafe extern "system" fn create_render_pass2(
+    device: vk::Device,
+    p_create_info: *const vk::RenderPassCreateInfo2<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_render_pass: *mut vk::RenderPass,
+) -> vk::Result {
+    dispatch::<CreateRenderPass2Fn, _>(
+        "vkCreateRenderPass2",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_render_pass),
+        |driver| {
+            if !p_render_pass.is_null() {
+                *p_render_pass = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateRenderPass2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRenderPass2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_render_pass2(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::RenderPassCreateInfo2<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::RenderPass,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateRenderPass2Fn>("vkCreateRenderPass2", Box::new(f))
+    }
+}
+type CreateSamplerFn = dyn FnMut(
+    vk::Device,
+    *const vk::SamplerCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Sampler,
+) -> vk::Result;
+unsafe extern "system" fn create_sampler(
+    device: vk::Device,
+    p_create_info: *const vk::SamplerCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_sampler: *mut vk::Sampler,
+) -> vk::Result {
+    dispatch::<CreateSamplerFn, _>(
+        "vkCreateSampler",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_sampler),
+        |driver| {
+            if !p_sampler.is_null() {
+                *p_sampler = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateSampler`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSampler.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_sampler(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::SamplerCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Sampler,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateSamplerFn>("vkCreateSampler", Box::new(f))
+    }
+}
+type CreateSamplerYcbcrConversionFn = dyn FnMut(
+    vk::Device,
+    *const vk::SamplerYcbcrConversionCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SamplerYcbcrConversion,
+) -> vk::Result;
+unsafe extern "system" fn create_sampler_ycbcr_conversion(
+    device: vk::Device,
+    p_create_info: *const vk::SamplerYcbcrConversionCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_ycbcr_conversion: *mut vk::SamplerYcbcrConversion,
+) -> vk::Result {
+    dispatch::<CreateSamplerYcbcrConversionFn, _>(
+        "vkCreateSamplerYcbcrConversion",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_ycbcr_conversion),
+        |driver| {
+            if !p_ycbcr_conversion.is_null() {
+                *p_ycbcr_conversion = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateSamplerYcbcrConversion`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSamplerYcbcrConversion.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_sampler_ycbcr_conversion(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::SamplerYcbcrConversionCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SamplerYcbcrConversion,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateSamplerYcbcrConversionFn>(
+            "vkCreateSamplerYcbcrConversion",
+            Box::new(f),
+        )
+    }
+}
+type CreateScreenSurfaceQNXFn = dyn FnMut(
+    vk::Instance,
+    *const vk::ScreenSurfaceCreateInfoQNX<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_screen_surface_qnx(
+    instance: vk::Instance,
+    p_create_info: *const vk::ScreenSurfaceCreateInfoQNX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateScreenSurfaceQNXFn, _>(
+        "vkCreateScreenSurfaceQNX",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateScreenSurfaceQNX`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateScreenSurfaceQNX.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_screen_surface_qnx(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::ScreenSurfaceCreateInfoQNX<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateScreenSurfaceQNXFn>("vkCreateScreenSurfaceQNX", Box::new(f))
+    }
+}
+type CreateSemaphoreFn = dyn FnMut(
+    vk::Device,
+    *const vk::SemaphoreCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Semaphore,
+) -> vk::Result;
+unsafe extern "system" fn create_semaphore(
+    device: vk::Device,
+    p_create_info: *const vk::SemaphoreCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_semaphore: *mut vk::Semaphore,
+) -> vk::Result {
+    dispatch::<CreateSemaphoreFn, _>(
+        "vkCreateSemaphore",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_semaphore),
+        |driver| {
+            if !p_semaphore.is_null() {
+                *p_semaphore = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateSemaphore`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSemaphore.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_semaphore(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::SemaphoreCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Semaphore,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateSemaphoreFn>("vkCreateSemaphore", Box::new(f))
+    }
+}
+type CreateShaderModuleFn = dyn FnMut(
+    vk::Device,
+    *const vk::ShaderModuleCreateInfo<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::ShaderModule,
+) -> vk::Result;
+unsafe extern "system" fn create_shader_module(
+    device: vk::Device,
+    p_create_info: *const vk::ShaderModuleCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_shader_module: *mut vk::ShaderModule,
+) -> vk::Result {
+    dispatch::<CreateShaderModuleFn, _>(
+        "vkCreateShaderModule",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_shader_module),
+        |driver| {
+            if !p_shader_module.is_null() {
+                *p_shader_module = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateShaderModule`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateShaderModule.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_shader_module(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::ShaderModuleCreateInfo<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::ShaderModule,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateShaderModuleFn>("vkCreateShaderModule", Box::new(f))
+    }
+}
+type CreateShadersEXTFn = dyn FnMut(
+    vk::Device,
+    u32,
+    *const vk::ShaderCreateInfoEXT<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::ShaderEXT,
+) -> vk::Result;
+unsafe extern "system" fn create_shaders_ext(
+    device: vk::Device,
+    create_info_count: u32,
+    p_create_infos: *const vk::ShaderCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_shaders: *mut vk::ShaderEXT,
+) -> vk::Result {
+    dispatch::<CreateShadersEXTFn, _>(
+        "vkCreateShadersEXT",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                create_info_count,
+                p_create_infos,
+                p_allocator,
+                p_shaders,
+            )
+        },
+        |driver| {
+            if !p_shaders.is_null() {
+                for i in 0..create_info_count as usize {
+                    *p_shaders.add(i) = driver.allocate();
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateShadersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateShadersEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_shaders_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                u32,
+                *const vk::ShaderCreateInfoEXT<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::ShaderEXT,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateShadersEXTFn>("vkCreateShadersEXT", Box::new(f))
+    }
+}
+type CreateSharedSwapchainsKHRFn = dyn FnMut(
+    vk::Device,
+    u32,
+    *const vk::SwapchainCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SwapchainKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_shared_swapchains_khr(
+    device: vk::Device,
+    swapchain_count: u32,
+    p_create_infos: *const vk::SwapchainCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_swapchains: *mut vk::SwapchainKHR,
+) -> vk::Result {
+    dispatch::<CreateSharedSwapchainsKHRFn, _>(
+        "vkCreateSharedSwapchainsKHR",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                swapchain_count,
+                p_create_infos,
+                p_allocator,
+                p_swapchains,
+            )
+        },
+        |driver| {
+            if !p_swapchains.is_null() {
+                for i in 0..swapchain_count as usize {
+                    *p_swapchains.add(i) = driver.allocate();
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateSharedSwapchainsKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSharedSwapchainsKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_shared_swapchains_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                u32,
+                *const vk::SwapchainCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SwapchainKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateSharedSwapchainsKHRFn>("vkCreateSharedSwapchainsKHR", Box::new(f))
+    }
+}
+type CreateStreamDescriptorSurfaceGGPFn = dyn FnMut(
+    vk::Instance,
+    *const vk::StreamDescriptorSurfaceCreateInfoGGP<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_stream_descriptor_surface_ggp(
+    instance: vk::Instance,
+    p_create_info: *const vk::StreamDescriptorSurfaceCreateInfoGGP<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateStreamDescriptorSurfaceGGPFn, _>(
+        "vkCreateStreamDescriptorSurfaceGGP",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateStreamDescriptorSurfaceGGP`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateStreamDescriptorSurfaceGGP.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_stream_descriptor_surface_ggp(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::StreamDescriptorSurfaceCreateInfoGGP<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateStreamDescriptorSurfaceGGPFn>(
+            "vkCreateStreamDescriptorSurfaceGGP",
+            Box::new(f),
+        )
+    }
+}
+type CreateSwapchainKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::SwapchainCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SwapchainKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_swapchain_khr(
+    device: vk::Device,
+    p_create_info: *const vk::SwapchainCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_swapchain: *mut vk::SwapchainKHR,
+) -> vk::Result {
+    dispatch::<CreateSwapchainKHRFn, _>(
+        "vkCreateSwapchainKHR",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_swapchain),
+        |driver| {
+            if !p_swapchain.is_null() {
+                *p_swapchain = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateSwapchainKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSwapchainKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_swapchain_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::SwapchainCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SwapchainKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateSwapchainKHRFn>("vkCreateSwapchainKHR", Box::new(f))
+    }
+}
+type CreateValidationCacheEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::ValidationCacheCreateInfoEXT<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::ValidationCacheEXT,
+) -> vk::Result;
+unsafe extern "system" fn create_validation_cache_ext(
+    device: vk::Device,
+    p_create_info: *const vk::ValidationCacheCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_validation_cache: *mut vk::ValidationCacheEXT,
+) -> vk::Result {
+    dispatch::<CreateValidationCacheEXTFn, _>(
+        "vkCreateValidationCacheEXT",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_validation_cache),
+        |driver| {
+            if !p_validation_cache.is_null() {
+                *p_validation_cache = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateValidationCacheEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateValidationCacheEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_validation_cache_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::ValidationCacheCreateInfoEXT<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::ValidationCacheEXT,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateValidationCacheEXTFn>("vkCreateValidationCacheEXT", Box::new(f))
+    }
+}
+type CreateViSurfaceNNFn = dyn FnMut(
+    vk::Instance,
+    *const vk::ViSurfaceCreateInfoNN<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_vi_surface_nn(
+    instance: vk::Instance,
+    p_create_info: *const vk::ViSurfaceCreateInfoNN<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateViSurfaceNNFn, _>(
+        "vkCreateViSurfaceNN",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateViSurfaceNN`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateViSurfaceNN.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_vi_surface_nn(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::ViSurfaceCreateInfoNN<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateViSurfaceNNFn>("vkCreateViSurfaceNN", Box::new(f))
+    }
+}
+type CreateVideoSessionKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::VideoSessionCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::VideoSessionKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_video_session_khr(
+    device: vk::Device,
+    p_create_info: *const vk::VideoSessionCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_video_session: *mut vk::VideoSessionKHR,
+) -> vk::Result {
+    dispatch::<CreateVideoSessionKHRFn, _>(
+        "vkCreateVideoSessionKHR",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_allocator, p_video_session),
+        |driver| {
+            if !p_video_session.is_null() {
+                *p_video_session = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateVideoSessionKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateVideoSessionKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_video_session_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::VideoSessionCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::VideoSessionKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateVideoSessionKHRFn>("vkCreateVideoSessionKHR", Box::new(f))
+    }
+}
+type CreateVideoSessionParametersKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::VideoSessionParametersCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::VideoSessionParametersKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_video_session_parameters_khr(
+    device: vk::Device,
+    p_create_info: *const vk::VideoSessionParametersCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_video_session_parameters: *mut vk::VideoSessionParametersKHR,
+) -> vk::Result {
+    dispatch::<CreateVideoSessionParametersKHRFn, _>(
+        "vkCreateVideoSessionParametersKHR",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                p_create_info,
+                p_allocator,
+                p_video_session_parameters,
+            )
+        },
+        |driver| {
+            if !p_video_session_parameters.is_null() {
+                *p_video_session_parameters = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateVideoSessionParametersKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateVideoSessionParametersKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_video_session_parameters_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::VideoSessionParametersCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::VideoSessionParametersKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateVideoSessionParametersKHRFn>(
+            "vkCreateVideoSessionParametersKHR",
+            Box::new(f),
+        )
+    }
+}
+type CreateWaylandSurfaceKHRFn = dyn FnMut(
+    vk::Instance,
+    *const vk::WaylandSurfaceCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_wayland_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::WaylandSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateWaylandSurfaceKHRFn, _>(
+        "vkCreateWaylandSurfaceKHR",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateWaylandSurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateWaylandSurfaceKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_wayland_surface_khr(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::WaylandSurfaceCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateWaylandSurfaceKHRFn>("vkCreateWaylandSurfaceKHR", Box::new(f))
+    }
+}
+type CreateWin32SurfaceKHRFn = dyn FnMut(
+    vk::Instance,
+    *const vk::Win32SurfaceCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_win32_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::Win32SurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateWin32SurfaceKHRFn, _>(
+        "vkCreateWin32SurfaceKHR",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateWin32SurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateWin32SurfaceKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_win32_surface_khr(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::Win32SurfaceCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateWin32SurfaceKHRFn>("vkCreateWin32SurfaceKHR", Box::new(f))
+    }
+}
+type CreateXcbSurfaceKHRFn = dyn FnMut(
+    vk::Instance,
+    *const vk::XcbSurfaceCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_xcb_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::XcbSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateXcbSurfaceKHRFn, _>(
+        "vkCreateXcbSurfaceKHR",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateXcbSurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateXcbSurfaceKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_xcb_surface_khr(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::XcbSurfaceCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateXcbSurfaceKHRFn>("vkCreateXcbSurfaceKHR", Box::new(f))
+    }
+}
+type CreateXlibSurfaceKHRFn = dyn FnMut(
+    vk::Instance,
+    *const vk::XlibSurfaceCreateInfoKHR<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::SurfaceKHR,
+) -> vk::Result;
+unsafe extern "system" fn create_xlib_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::XlibSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    dispatch::<CreateXlibSurfaceKHRFn, _>(
+        "vkCreateXlibSurfaceKHR",
+        &[instance.as_raw()],
+        |f| f(instance, p_create_info, p_allocator, p_surface),
+        |driver| {
+            if !p_surface.is_null() {
+                *p_surface = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkCreateXlibSurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateXlibSurfaceKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_create_xlib_surface_khr(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                *const vk::XlibSurfaceCreateInfoKHR<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::SurfaceKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<CreateXlibSurfaceKHRFn>("vkCreateXlibSurfaceKHR", Box::new(f))
+    }
+}
+type DebugMarkerSetObjectNameEXTFn =
+    dyn FnMut(vk::Device, *const vk::DebugMarkerObjectNameInfoEXT<'_>) -> vk::Result;
+unsafe extern "system" fn debug_marker_set_object_name_ext(
+    device: vk::Device,
+    p_name_info: *const vk::DebugMarkerObjectNameInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<DebugMarkerSetObjectNameEXTFn, _>(
+        "vkDebugMarkerSetObjectNameEXT",
+        &[device.as_raw()],
+        |f| f(device, p_name_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDebugMarkerSetObjectNameEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDebugMarkerSetObjectNameEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_debug_marker_set_object_name_ext(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::DebugMarkerObjectNameInfoEXT<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<DebugMarkerSetObjectNameEXTFn>(
+            "vkDebugMarkerSetObjectNameEXT",
+            Box::new(f),
+        )
+    }
+}
+type DebugMarkerSetObjectTagEXTFn =
+    dyn FnMut(vk::Device, *const vk::DebugMarkerObjectTagInfoEXT<'_>) -> vk::Result;
+unsafe extern "system" fn debug_marker_set_object_tag_ext(
+    device: vk::Device,
+    p_tag_info: *const vk::DebugMarkerObjectTagInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<DebugMarkerSetObjectTagEXTFn, _>(
+        "vkDebugMarkerSetObjectTagEXT",
+        &[device.as_raw()],
+        |f| f(device, p_tag_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDebugMarkerSetObjectTagEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDebugMarkerSetObjectTagEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_debug_marker_set_object_tag_ext(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::DebugMarkerObjectTagInfoEXT<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<DebugMarkerSetObjectTagEXTFn>(
+            "vkDebugMarkerSetObjectTagEXT",
+            Box::new(f),
+        )
+    }
+}
+type DebugReportMessageEXTFn = dyn FnMut(
+    vk::Instance,
+    vk::DebugReportFlagsEXT,
+    vk::DebugReportObjectTypeEXT,
+    u64,
+    usize,
+    i32,
+    *const c_char,
+    *const c_char,
+);
+unsafe extern "system" fn debug_report_message_ext(
+    instance: vk::Instance,
+    flags: vk::DebugReportFlagsEXT,
+    object_type: vk::DebugReportObjectTypeEXT,
+    object: u64,
+    location: usize,
+    message_code: i32,
+    p_layer_prefix: *const c_char,
+    p_message: *const c_char,
+) {
+    dispatch::<DebugReportMessageEXTFn, _>(
+        "vkDebugReportMessageEXT",
+        &[instance.as_raw()],
+        |f| {
+            f(
+                instance,
+                flags,
+                object_type,
+                object,
+                location,
+                message_code,
+                p_layer_prefix,
+                p_message,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDebugReportMessageEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDebugReportMessageEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_debug_report_message_ext(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                vk::DebugReportFlagsEXT,
+                vk::DebugReportObjectTypeEXT,
+                u64,
+                usize,
+                i32,
+                *const c_char,
+                *const c_char,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<DebugReportMessageEXTFn>("vkDebugReportMessageEXT", Box::new(f))
+    }
+}
+type DeferredOperationJoinKHRFn = dyn FnMut(vk::Device, vk::DeferredOperationKHR) -> vk::Result;
+unsafe extern "system" fn deferred_operation_join_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> vk::Result {
+    dispatch::<DeferredOperationJoinKHRFn, _>(
+        "vkDeferredOperationJoinKHR",
+        &[device.as_raw(), operation.as_raw()],
+        |f| f(device, operation),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDeferredOperationJoinKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDeferredOperationJoinKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_deferred_operation_join_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::DeferredOperationKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<DeferredOperationJoinKHRFn>("vkDeferredOperationJoinKHR", Box::new(f))
+    }
+}
+type DestroyAccelerationStructureKHRFn =
+    dyn FnMut(vk::Device, vk::AccelerationStructureKHR, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_acceleration_structure_khr(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyAccelerationStructureKHRFn, _>(
+        "vkDestroyAccelerationStructureKHR",
+        &[device.as_raw(), acceleration_structure.as_raw()],
+        |f| f(device, acceleration_structure, p_allocator),
+        |driver| {
+            driver.release(acceleration_structure.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyAccelerationStructureKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyAccelerationStructureKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_acceleration_structure_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::AccelerationStructureKHR, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyAccelerationStructureKHRFn>(
+            "vkDestroyAccelerationStructureKHR",
+            Box::new(f),
+        )
+    }
+}
+type DestroyAccelerationStructureNVFn =
+    dyn FnMut(vk::Device, vk::AccelerationStructureNV, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_acceleration_structure_nv(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyAccelerationStructureNVFn, _>(
+        "vkDestroyAccelerationStructureNV",
+        &[device.as_raw(), acceleration_structure.as_raw()],
+        |f| f(device, acceleration_structure, p_allocator),
+        |driver| {
+            driver.release(acceleration_structure.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyAccelerationStructureNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyAccelerationStructureNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_acceleration_structure_nv(
+        &self,
+        f: impl FnMut(vk::Device, vk::AccelerationStructureNV, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyAccelerationStructureNVFn>(
+            "vkDestroyAccelerationStructureNV",
+            Box::new(f),
+        )
+    }
+}
+type DestroyBufferFn = dyn FnMut(vk::Device, vk::Buffer, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_buffer(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyBufferFn, _>(
+        "vkDestroyBuffer",
+        &[device.as_raw(), buffer.as_raw()],
+        |f| f(device, buffer, p_allocator),
+        |driver| {
+            driver.release(buffer.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyBuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_buffer(
+        &self,
+        f: impl FnMut(vk::Device, vk::Buffer, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyBufferFn>("vkDestroyBuffer", Box::new(f))
+    }
+}
+type DestroyBufferCollectionFUCHSIAFn =
+    dyn FnMut(vk::Device, vk::BufferCollectionFUCHSIA, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_buffer_collection_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyBufferCollectionFUCHSIAFn, _>(
+        "vkDestroyBufferCollectionFUCHSIA",
+        &[device.as_raw(), collection.as_raw()],
+        |f| f(device, collection, p_allocator),
+        |driver| {
+            driver.release(collection.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyBufferCollectionFUCHSIA`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyBufferCollectionFUCHSIA.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_buffer_collection_fuchsia(
+        &self,
+        f: impl FnMut(vk::Device, vk::BufferCollectionFUCHSIA, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyBufferCollectionFUCHSIAFn>(
+            "vkDestroyBufferCollectionFUCHSIA",
+            Box::new(f),
+        )
+    }
+}
+type DestroyBufferViewFn =
+    dyn FnMut(vk::Device, vk::BufferView, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_buffer_view(
+    device: vk::Device,
+    buffer_view: vk::BufferView,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyBufferViewFn, _>(
+        "vkDestroyBufferView",
+        &[device.as_raw(), buffer_view.as_raw()],
+        |f| f(device, buffer_view, p_allocator),
+        |driver| {
+            driver.release(buffer_view.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyBufferView`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyBufferView.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_buffer_view(
+        &self,
+        f: impl FnMut(vk::Device, vk::BufferView, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyBufferViewFn>("vkDestroyBufferView", Box::new(f))
+    }
+}
+type DestroyCommandPoolFn =
+    dyn FnMut(vk::Device, vk::CommandPool, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyCommandPoolFn, _>(
+        "vkDestroyCommandPool",
+        &[device.as_raw(), command_pool.as_raw()],
+        |f| f(device, command_pool, p_allocator),
+        |driver| {
+            driver.release(command_pool.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyCommandPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCommandPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_command_pool(
+        &self,
+        f: impl FnMut(vk::Device, vk::CommandPool, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyCommandPoolFn>("vkDestroyCommandPool", Box::new(f))
+    }
+}
+type DestroyCuFunctionNVXFn =
+    dyn FnMut(vk::Device, vk::CuFunctionNVX, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_cu_function_nvx(
+    device: vk::Device,
+    function: vk::CuFunctionNVX,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyCuFunctionNVXFn, _>(
+        "vkDestroyCuFunctionNVX",
+        &[device.as_raw(), function.as_raw()],
+        |f| f(device, function, p_allocator),
+        |driver| {
+            driver.release(function.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyCuFunctionNVX`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCuFunctionNVX.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_cu_function_nvx(
+        &self,
+        f: impl FnMut(vk::Device, vk::CuFunctionNVX, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyCuFunctionNVXFn>("vkDestroyCuFunctionNVX", Box::new(f))
+    }
+}
+type DestroyCuModuleNVXFn =
+    dyn FnMut(vk::Device, vk::CuModuleNVX, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_cu_module_nvx(
+    device: vk::Device,
+    module: vk::CuModuleNVX,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyCuModuleNVXFn, _>(
+        "vkDestroyCuModuleNVX",
+        &[device.as_raw(), module.as_raw()],
+        |f| f(device, module, p_allocator),
+        |driver| {
+            driver.release(module.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyCuModuleNVX`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCuModuleNVX.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_cu_module_nvx(
+        &self,
+        f: impl FnMut(vk::Device, vk::CuModuleNVX, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyCuModuleNVXFn>("vkDestroyCuModuleNVX", Box::new(f))
+    }
+}
+type DestroyDebugReportCallbackEXTFn =
+    dyn FnMut(vk::Instance, vk::DebugReportCallbackEXT, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_debug_report_callback_ext(
+    instance: vk::Instance,
+    callback: vk::DebugReportCallbackEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyDebugReportCallbackEXTFn, _>(
+        "vkDestroyDebugReportCallbackEXT",
+        &[instance.as_raw(), callback.as_raw()],
+        |f| f(instance, callback, p_allocator),
+        |driver| {
+            driver.release(callback.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyDebugReportCallbackEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDebugReportCallbackEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_debug_report_callback_ext(
+        &self,
+        f: impl FnMut(vk::Instance, vk::DebugReportCallbackEXT, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyDebugReportCallbackEXTFn>(
+            "vkDestroyDebugReportCallbackEXT",
+            Box::new(f),
+        )
+    }
+}
+type DestroyDebugUtilsMessengerEXTFn =
+    dyn FnMut(vk::Instance, vk::DebugUtilsMessengerEXT, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_debug_utils_messenger_ext(
+    instance: vk::Instance,
+    messenger: vk::DebugUtilsMessengerEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyDebugUtilsMessengerEXTFn, _>(
+        "vkDestroyDebugUtilsMessengerEXT",
+        &[instance.as_raw(), messenger.as_raw()],
+        |f| f(instance, messenger, p_allocator),
+        |driver| {
+            driver.release(messenger.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyDebugUtilsMessengerEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDebugUtilsMessengerEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_debug_utils_messenger_ext(
+        &self,
+        f: impl FnMut(vk::Instance, vk::DebugUtilsMessengerEXT, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyDebugUtilsMessengerEXTFn>(
+            "vkDestroyDebugUtilsMessengerEXT",
+            Box::new(f),
+        )
+    }
+}
+type DestroyDeferredOperationKHRFn =
+    dyn FnMut(vk::Device, vk::DeferredOperationKHR, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_deferred_operation_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyDeferredOperationKHRFn, _>(
+        "vkDestroyDeferredOperationKHR",
+        &[device.as_raw(), operation.as_raw()],
+        |f| f(device, operation, p_allocator),
+        |driver| {
+            driver.release(operation.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyDeferredOperationKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDeferredOperationKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_deferred_operation_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::DeferredOperationKHR, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyDeferredOperationKHRFn>(
+            "vkDestroyDeferredOperationKHR",
+            Box::new(f),
+        )
+    }
+}
+type DestroyDescriptorPoolFn =
+    dyn FnMut(vk::Device, vk::DescriptorPool, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_descriptor_pool(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyDescriptorPoolFn, _>(
+        "vkDestroyDescriptorPool",
+        &[device.as_raw(), descriptor_pool.as_raw()],
+        |f| f(device, descriptor_pool, p_allocator),
+        |driver| {
+            driver.release(descriptor_pool.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyDescriptorPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDescriptorPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_descriptor_pool(
+        &self,
+        f: impl FnMut(vk::Device, vk::DescriptorPool, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyDescriptorPoolFn>("vkDestroyDescriptorPool", Box::new(f))
+    }
+}
+type DestroyDescriptorSetLayoutFn =
+    dyn FnMut(vk::Device, vk::DescriptorSetLayout, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_descriptor_set_layout(
+    device: vk::Device,
+    descriptor_set_layout: vk::DescriptorSetLayout,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyDescriptorSetLayoutFn, _>(
+        "vkDestroyDescriptorSetLayout",
+        &[device.as_raw(), descriptor_set_layout.as_raw()],
+        |f| f(device, descriptor_set_layout, p_allocator),
+        |driver| {
+            driver.release(descriptor_set_layout.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyDescriptorSetLayout`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDescriptorSetLayout.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_descriptor_set_layout(
+        &self,
+        f: impl FnMut(vk::Device, vk::DescriptorSetLayout, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyDescriptorSetLayoutFn>(
+            "vkDestroyDescriptorSetLayout",
+            Box::new(f),
+        )
+    }
+}
+type DestroyDescriptorUpdateTemplateFn =
+    dyn FnMut(vk::Device, vk::DescriptorUpdateTemplate, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_descriptor_update_template(
+    device: vk::Device,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyDescriptorUpdateTemplateFn, _>(
+        "vkDestroyDescriptorUpdateTemplate",
+        &[device.as_raw(), descriptor_update_template.as_raw()],
+        |f| f(device, descriptor_update_template, p_allocator),
+        |driver| {
+            driver.release(descriptor_update_template.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyDescriptorUpdateTemplate`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDescriptorUpdateTemplate.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_descriptor_update_template(
+        &self,
+        f: impl FnMut(vk::Device, vk::DescriptorUpdateTemplate, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyDescriptorUpdateTemplateFn>(
+            "vkDestroyDescriptorUpdateTemplate",
+            Box::new(f),
+        )
+    }
+}
+type DestroyDeviceFn = dyn FnMut(vk::Device, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_device(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyDeviceFn, _>(
+        "vkDestroyDevice",
+        &[device.as_raw()],
+        |f| f(device, p_allocator),
+        |driver| {
+            driver.release(device.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyDevice`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDevice.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_device(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyDeviceFn>("vkDestroyDevice", Box::new(f))
+    }
+}
+type DestroyEventFn = dyn FnMut(vk::Device, vk::Event, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_event(
+    device: vk::Device,
+    event: vk::Event,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyEventFn, _>(
+        "vkDestroyEvent",
+        &[device.as_raw(), event.as_raw()],
+        |f| f(device, event, p_allocator),
+        |driver| {
+            driver.release(event.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyEvent`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyEvent.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_event(
+        &self,
+        f: impl FnMut(vk::Device, vk::Event, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyEventFn>("vkDestroyEvent", Box::new(f))
+    }
+}
+type DestroyFenceFn = dyn FnMut(vk::Device, vk::Fence, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_fence(
+    device: vk::Device,
+    fence: vk::Fence,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyFenceFn, _>(
+        "vkDestroyFence",
+        &[device.as_raw(), fence.as_raw()],
+        |f| f(device, fence, p_allocator),
+        |driver| {
+            driver.release(fence.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyFence`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyFence.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_fence(
+        &self,
+        f: impl FnMut(vk::Device, vk::Fence, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyFenceFn>("vkDestroyFence", Box::new(f))
+    }
+}
+type DestroyFramebufferFn =
+    dyn FnMut(vk::Device, vk::Framebuffer, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_framebuffer(
+    device: vk::Device,
+    framebuffer: vk::Framebuffer,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyFramebufferFn, _>(
+        "vkDestroyFramebuffer",
+        &[device.as_raw(), framebuffer.as_raw()],
+        |f| f(device, framebuffer, p_allocator),
+        |driver| {
+            driver.release(framebuffer.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyFramebuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyFramebuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_framebuffer(
+        &self,
+        f: impl FnMut(vk::Device, vk::Framebuffer, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyFramebufferFn>("vkDestroyFramebuffer", Box::new(f))
+    }
+}
+type DestroyImageFn = dyn FnMut(vk::Device, vk::Image, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_image(
+    device: vk::Device,
+    image: vk::Image,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyImageFn, _>(
+        "vkDestroyImage",
+        &[device.as_raw(), image.as_raw()],
+        |f| f(device, image, p_allocator),
+        |driver| {
+            driver.release(image.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyImage`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyImage.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_image(
+        &self,
+        f: impl FnMut(vk::Device, vk::Image, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyImageFn>("vkDestroyImage", Box::new(f))
+    }
+}
+type DestroyImageViewFn = dyn FnMut(vk::Device, vk::ImageView, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_image_view(
+    device: vk::Device,
+    image_view: vk::ImageView,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyImageViewFn, _>(
+        "vkDestroyImageView",
+        &[device.as_raw(), image_view.as_raw()],
+        |f| f(device, image_view, p_allocator),
+        |driver| {
+            driver.release(image_view.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyImageView`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyImageView.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_image_view(
+        &self,
+        f: impl FnMut(vk::Device, vk::ImageView, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyImageViewFn>("vkDestroyImageView", Box::new(f))
+    }
+}
+type DestroyIndirectCommandsLayoutNVFn =
+    dyn FnMut(vk::Device, vk::IndirectCommandsLayoutNV, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_indirect_commands_layout_nv(
+    device: vk::Device,
+    indirect_commands_layout: vk::IndirectCommandsLayoutNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyIndirectCommandsLayoutNVFn, _>(
+        "vkDestroyIndirectCommandsLayoutNV",
+        &[device.as_raw(), indirect_commands_layout.as_raw()],
+        |f| f(device, indirect_commands_layout, p_allocator),
+        |driver| {
+            driver.release(indirect_commands_layout.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyIndirectCommandsLayoutNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyIndirectCommandsLayoutNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_indirect_commands_layout_nv(
+        &self,
+        f: impl FnMut(vk::Device, vk::IndirectCommandsLayoutNV, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyIndirectCommandsLayoutNVFn>(
+            "vkDestroyIndirectCommandsLayoutNV",
+            Box::new(f),
+        )
+    }
+}
+type DestroyInstanceFn = dyn FnMut(vk::Instance, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_instance(
+    instance: vk::Instance,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyInstanceFn, _>(
+        "vkDestroyInstance",
+        &[instance.as_raw()],
+        |f| f(instance, p_allocator),
+        |driver| {
+            driver.release(instance.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyInstance`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyInstance.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_instance(
+        &self,
+        f: impl FnMut(vk::Instance, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyInstanceFn>("vkDestroyInstance", Box::new(f))
+    }
+}
+type DestroyMicromapEXTFn =
+    dyn FnMut(vk::Device, vk::MicromapEXT, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_micromap_ext(
+    device: vk::Device,
+    micromap: vk::MicromapEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyMicromapEXTFn, _>(
+        "vkDestroyMicromapEXT",
+        &[device.as_raw(), micromap.as_raw()],
+        |f| f(device, micromap, p_allocator),
+        |driver| {
+            driver.release(micromap.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyMicromapEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyMicromapEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_micromap_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::MicromapEXT, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyMicromapEXTFn>("vkDestroyMicromapEXT", Box::new(f))
+    }
+}
+type DestroyOpticalFlowSessionNVFn =
+    dyn FnMut(vk::Device, vk::OpticalFlowSessionNV, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_optical_flow_session_nv(
+    device: vk::Device,
+    session: vk::OpticalFlowSessionNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyOpticalFlowSessionNVFn, _>(
+        "vkDestroyOpticalFlowSessionNV",
+        &[device.as_raw(), session.as_raw()],
+        |f| f(device, session, p_allocator),
+        |driver| {
+            driver.release(session.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyOpticalFlowSessionNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyOpticalFlowSessionNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_optical_flow_session_nv(
+        &self,
+        f: impl FnMut(vk::Device, vk::OpticalFlowSessionNV, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyOpticalFlowSessionNVFn>(
+            "vkDestroyOpticalFlowSessionNV",
+            Box::new(f),
+        )
+    }
+}
+type DestroyPipelineFn = dyn FnMut(vk::Device, vk::Pipeline, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_pipeline(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyPipelineFn, _>(
+        "vkDestroyPipeline",
+        &[device.as_raw(), pipeline.as_raw()],
+        |f| f(device, pipeline, p_allocator),
+        |driver| {
+            driver.release(pipeline.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyPipeline`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyPipeline.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_pipeline(
+        &self,
+        f: impl FnMut(vk::Device, vk::Pipeline, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyPipelineFn>("vkDestroyPipeline", Box::new(f))
+    }
+}
+type DestroyPipelineCacheFn =
+    dyn FnMut(vk::Device, vk::PipelineCache, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_pipeline_cache(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyPipelineCacheFn, _>(
+        "vkDestroyPipelineCache",
+        &[device.as_raw(), pipeline_cache.as_raw()],
+        |f| f(device, pipeline_cache, p_allocator),
+        |driver| {
+            driver.release(pipeline_cache.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyPipelineCache`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyPipelineCache.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_pipeline_cache(
+        &self,
+        f: impl FnMut(vk::Device, vk::PipelineCache, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyPipelineCacheFn>("vkDestroyPipelineCache", Box::new(f))
+    }
+}
+type DestroyPipelineLayoutFn =
+    dyn FnMut(vk::Device, vk::PipelineLayout, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_pipeline_layout(
+    device: vk::Device,
+    pipeline_layout: vk::PipelineLayout,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyPipelineLayoutFn, _>(
+        "vkDestroyPipelineLayout",
+        &[device.as_raw(), pipeline_layout.as_raw()],
+        |f| f(device, pipeline_layout, p_allocator),
+        |driver| {
+            driver.release(pipeline_layout.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyPipelineLayout`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyPipelineLayout.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_pipeline_layout(
+        &self,
+        f: impl FnMut(vk::Device, vk::PipelineLayout, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyPipelineLayoutFn>("vkDestroyPipelineLayout", Box::new(f))
+    }
+}
+type DestroyPrivateDataSlotFn =
+    dyn FnMut(vk::Device, vk::PrivateDataSlot, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_private_data_slot(
+    device: vk::Device,
+    private_data_slot: vk::PrivateDataSlot,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyPrivateDataSlotFn, _>(
+        "vkDestroyPrivateDataSlot",
+        &[device.as_raw(), private_data_slot.as_raw()],
+        |f| f(device, private_data_slot, p_allocator),
+        |driver| {
+            driver.release(private_data_slot.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyPrivateDataSlot`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyPrivateDataSlot.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_private_data_slot(
+        &self,
+        f: impl FnMut(vk::Device, vk::PrivateDataSlot, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyPrivateDataSlotFn>("vkDestroyPrivateDataSlot", Box::new(f))
+    }
+}
+type DestroyQueryPoolFn = dyn FnMut(vk::Device, vk::QueryPool, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_query_pool(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyQueryPoolFn, _>(
+        "vkDestroyQueryPool",
+        &[device.as_raw(), query_pool.as_raw()],
+        |f| f(device, query_pool, p_allocator),
+        |driver| {
+            driver.release(query_pool.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyQueryPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyQueryPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_query_pool(
+        &self,
+        f: impl FnMut(vk::Device, vk::QueryPool, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyQueryPoolFn>("vkDestroyQueryPool", Box::new(f))
+    }
+}
+type DestroyRenderPassFn =
+    dyn FnMut(vk::Device, vk::RenderPass, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_render_pass(
+    device: vk::Device,
+    render_pass: vk::RenderPass,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyRenderPassFn, _>(
+        "vkDestroyRenderPass",
+        &[device.as_raw(), render_pass.as_raw()],
+        |f| f(device, render_pass, p_allocator),
+        |driver| {
+            driver.release(render_pass.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyRenderPass`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyRenderPass.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_render_pass(
+        &self,
+        f: impl FnMut(vk::Device, vk::RenderPass, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyRenderPassFn>("vkDestroyRenderPass", Box::new(f))
+    }
+}
+type DestroySamplerFn = dyn FnMut(vk::Device, vk::Sampler, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_sampler(
+    device: vk::Device,
+    sampler: vk::Sampler,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroySamplerFn, _>(
+        "vkDestroySampler",
+        &[device.as_raw(), sampler.as_raw()],
+        |f| f(device, sampler, p_allocator),
+        |driver| {
+            driver.release(sampler.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroySampler`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySampler.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_sampler(
+        &self,
+        f: impl FnMut(vk::Device, vk::Sampler, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroySamplerFn>("vkDestroySampler", Box::new(f))
+    }
+}
+type DestroySamplerYcbcrConversionFn =
+    dyn FnMut(vk::Device, vk::SamplerYcbcrConversion, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_sampler_ycbcr_conversion(
+    device: vk::Device,
+    ycbcr_conversion: vk::SamplerYcbcrConversion,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroySamplerYcbcrConversionFn, _>(
+        "vkDestroySamplerYcbcrConversion",
+        &[device.as_raw(), ycbcr_conversion.as_raw()],
+        |f| f(device, ycbcr_conversion, p_allocator),
+        |driver| {
+            driver.release(ycbcr_conversion.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroySamplerYcbcrConversion`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySamplerYcbcrConversion.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_sampler_ycbcr_conversion(
+        &self,
+        f: impl FnMut(vk::Device, vk::SamplerYcbcrConversion, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroySamplerYcbcrConversionFn>(
+            "vkDestroySamplerYcbcrConversion",
+            Box::new(f),
+        )
+    }
+}
+type DestroySemaphoreFn = dyn FnMut(vk::Device, vk::Semaphore, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_semaphore(
+    device: vk::Device,
+    semaphore: vk::Semaphore,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroySemaphoreFn, _>(
+        "vkDestroySemaphore",
+        &[device.as_raw(), semaphore.as_raw()],
+        |f| f(device, semaphore, p_allocator),
+        |driver| {
+            driver.release(semaphore.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroySemaphore`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySemaphore.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_semaphore(
+        &self,
+        f: impl FnMut(vk::Device, vk::Semaphore, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroySemaphoreFn>("vkDestroySemaphore", Box::new(f))
+    }
+}
+type DestroyShaderEXTFn = dyn FnMut(vk::Device, vk::ShaderEXT, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_shader_ext(
+    device: vk::Device,
+    shader: vk::ShaderEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyShaderEXTFn, _>(
+        "vkDestroyShaderEXT",
+        &[device.as_raw(), shader.as_raw()],
+        |f| f(device, shader, p_allocator),
+        |driver| {
+            driver.release(shader.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyShaderEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyShaderEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_shader_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::ShaderEXT, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyShaderEXTFn>("vkDestroyShaderEXT", Box::new(f))
+    }
+}
+type DestroyShaderModuleFn =
+    dyn FnMut(vk::Device, vk::ShaderModule, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_shader_module(
+    device: vk::Device,
+    shader_module: vk::ShaderModule,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyShaderModuleFn, _>(
+        "vkDestroyShaderModule",
+        &[device.as_raw(), shader_module.as_raw()],
+        |f| f(device, shader_module, p_allocator),
+        |driver| {
+            driver.release(shader_module.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyShaderModule`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyShaderModule.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_shader_module(
+        &self,
+        f: impl FnMut(vk::Device, vk::ShaderModule, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyShaderModuleFn>("vkDestroyShaderModule", Box::new(f))
+    }
+}
+type DestroySurfaceKHRFn =
+    dyn FnMut(vk::Instance, vk::SurfaceKHR, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_surface_khr(
+    instance: vk::Instance,
+    surface: vk::SurfaceKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroySurfaceKHRFn, _>(
+        "vkDestroySurfaceKHR",
+        &[instance.as_raw(), surface.as_raw()],
+        |f| f(instance, surface, p_allocator),
+        |driver| {
+            driver.release(surface.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroySurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySurfaceKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_surface_khr(
+        &self,
+        f: impl FnMut(vk::Instance, vk::SurfaceKHR, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroySurfaceKHRFn>("vkDestroySurfaceKHR", Box::new(f))
+    }
+}
+type DestroySwapchainKHRFn =
+    dyn FnMut(vk::Device, vk::SwapchainKHR, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_swapchain_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroySwapchainKHRFn, _>(
+        "vkDestroySwapchainKHR",
+        &[device.as_raw(), swapchain.as_raw()],
+        |f| f(device, swapchain, p_allocator),
+        |driver| {
+            driver.release(swapchain.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroySwapchainKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySwapchainKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_swapchain_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::SwapchainKHR, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroySwapchainKHRFn>("vkDestroySwapchainKHR", Box::new(f))
+    }
+}
+type DestroyValidationCacheEXTFn =
+    dyn FnMut(vk::Device, vk::ValidationCacheEXT, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_validation_cache_ext(
+    device: vk::Device,
+    validation_cache: vk::ValidationCacheEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyValidationCacheEXTFn, _>(
+        "vkDestroyValidationCacheEXT",
+        &[device.as_raw(), validation_cache.as_raw()],
+        |f| f(device, validation_cache, p_allocator),
+        |driver| {
+            driver.release(validation_cache.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyValidationCacheEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyValidationCacheEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_validation_cache_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::ValidationCacheEXT, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyValidationCacheEXTFn>("vkDestroyValidationCacheEXT", Box::new(f))
+    }
+}
+type DestroyVideoSessionKHRFn =
+    dyn FnMut(vk::Device, vk::VideoSessionKHR, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_video_session_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyVideoSessionKHRFn, _>(
+        "vkDestroyVideoSessionKHR",
+        &[device.as_raw(), video_session.as_raw()],
+        |f| f(device, video_session, p_allocator),
+        |driver| {
+            driver.release(video_session.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyVideoSessionKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyVideoSessionKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_video_session_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::VideoSessionKHR, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyVideoSessionKHRFn>("vkDestroyVideoSessionKHR", Box::new(f))
+    }
+}
+type DestroyVideoSessionParametersKHRFn =
+    dyn FnMut(vk::Device, vk::VideoSessionParametersKHR, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn destroy_video_session_parameters_khr(
+    device: vk::Device,
+    video_session_parameters: vk::VideoSessionParametersKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<DestroyVideoSessionParametersKHRFn, _>(
+        "vkDestroyVideoSessionParametersKHR",
+        &[device.as_raw(), video_session_parameters.as_raw()],
+        |f| f(device, video_session_parameters, p_allocator),
+        |driver| {
+            driver.release(video_session_parameters.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDestroyVideoSessionParametersKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyVideoSessionParametersKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_destroy_video_session_parameters_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::VideoSessionParametersKHR, *const vk::AllocationCallbacks<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DestroyVideoSessionParametersKHRFn>(
+            "vkDestroyVideoSessionParametersKHR",
+            Box::new(f),
+        )
+    }
+}
+type DeviceWaitIdleFn = dyn FnMut(vk::Device) -> vk::Result;
+unsafe extern "system" fn device_wait_idle(device: vk::Device) -> vk::Result {
+    dispatch::<DeviceWaitIdleFn, _>(
+        "vkDeviceWaitIdle",
+        &[device.as_raw()],
+        |f| f(device),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDeviceWaitIdle`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDeviceWaitIdle.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_device_wait_idle(&self, f: impl FnMut(vk::Device) -> vk::Result + 'static) -> &Self {
+        self.set_handler::<DeviceWaitIdleFn>("vkDeviceWaitIdle", Box::new(f))
+    }
+}
+type DisplayPowerControlEXTFn =
+    dyn FnMut(vk::Device, vk::DisplayKHR, *const vk::DisplayPowerInfoEXT<'_>) -> vk::Result;
+unsafe extern "system" fn display_power_control_ext(
+    device: vk::Device,
+    display: vk::DisplayKHR,
+    p_display_power_info: *const vk::DisplayPowerInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<DisplayPowerControlEXTFn, _>(
+        "vkDisplayPowerControlEXT",
+        &[device.as_raw(), display.as_raw()],
+        |f| f(device, display, p_display_power_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkDisplayPowerControlEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDisplayPowerControlEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_display_power_control_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::DisplayKHR, *const vk::DisplayPowerInfoEXT<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<DisplayPowerControlEXTFn>("vkDisplayPowerControlEXT", Box::new(f))
+    }
+}
+type EndCommandBufferFn = dyn FnMut(vk::CommandBuffer) -> vk::Result;
+unsafe extern "system" fn end_command_buffer(command_buffer: vk::CommandBuffer) -> vk::Result {
+    dispatch::<EndCommandBufferFn, _>(
+        "vkEndCommandBuffer",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkEndCommandBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEndCommandBuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_end_command_buffer(
+        &self,
+        f: impl FnMut(vk::CommandBuffer) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<EndCommandBufferFn>("vkEndCommandBuffer", Box::new(f))
+    }
+}
+type EnumerateDeviceExtensionPropertiesFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const c_char,
+    *mut u32,
+    *mut vk::ExtensionProperties,
+) -> vk::Result;
+unsafe extern "system" fn enumerate_device_extension_properties(
+    physical_device: vk::PhysicalDevice,
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    dispatch::<EnumerateDeviceExtensionPropertiesFn, _>(
+        "vkEnumerateDeviceExtensionProperties",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_layer_name,
+                p_property_count,
+                p_properties,
+            )
+        },
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkEnumerateDeviceExtensionProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateDeviceExtensionProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_enumerate_device_extension_properties(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const c_char,
+                *mut u32,
+                *mut vk::ExtensionProperties,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<EnumerateDeviceExtensionPropertiesFn>(
+            "vkEnumerateDeviceExtensionProperties",
+            Box::new(f),
+        )
+    }
+}
+type EnumerateDeviceLayerPropertiesFn =
+    dyn FnMut(vk::PhysicalDevice, *mut u32, *mut vk::LayerProperties) -> vk::Result;
+unsafe extern "system" fn enumerate_device_layer_properties(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    dispatch::<EnumerateDeviceLayerPropertiesFn, _>(
+        "vkEnumerateDeviceLayerProperties",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_property_count, p_properties),
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkEnumerateDeviceLayerProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateDeviceLayerProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_enumerate_device_layer_properties(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut u32, *mut vk::LayerProperties) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<EnumerateDeviceLayerPropertiesFn>(
+            "vkEnumerateDeviceLayerProperties",
+            Box::new(f),
+        )
+    }
+}
+type EnumerateInstanceExtensionPropertiesFn =
+    dyn FnMut(*const c_char, *mut u32, *mut vk::ExtensionProperties) -> vk::Result;
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    dispatch::<EnumerateInstanceExtensionPropertiesFn, _>(
+        "vkEnumerateInstanceExtensionProperties",
+        &[],
+        |f| f(p_layer_name, p_property_count, p_properties),
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkEnumerateInstanceExtensionProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceExtensionProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_enumerate_instance_extension_properties(
+        &self,
+        f: impl FnMut(*const c_char, *mut u32, *mut vk::ExtensionProperties) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<EnumerateInstanceExtensionPropertiesFn>(
+            "vkEnumerateInstanceExtensionProperties",
+            Box::new(f),
+        )
+    }
+}
+type EnumerateInstanceLayerPropertiesFn =
+    dyn FnMut(*mut u32, *mut vk::LayerProperties) -> vk::Result;
+unsafe extern "system" fn enumerate_instance_layer_properties(
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    dispatch::<EnumerateInstanceLayerPropertiesFn, _>(
+        "vkEnumerateInstanceLayerProperties",
+        &[],
+        |f| f(p_property_count, p_properties),
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkEnumerateInstanceLayerProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceLayerProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_enumerate_instance_layer_properties(
+        &self,
+        f: impl FnMut(*mut u32, *mut vk::LayerProperties) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<EnumerateInstanceLayerPropertiesFn>(
+            "vkEnumerateInstanceLayerProperties",
+            Box::new(f),
+        )
+    }
+}
+type EnumerateInstanceVersionFn = dyn FnMut(*mut u32) -> vk::Result;
+unsafe extern "system" fn enumerate_instance_version(p_api_version: *mut u32) -> vk::Result {
+    dispatch::<EnumerateInstanceVersionFn, _>(
+        "vkEnumerateInstanceVersion",
+        &[],
+        |f| f(p_api_version),
+        |_| {
+            if !p_api_version.is_null() {
+                ptr::write_bytes(p_api_version, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkEnumerateInstanceVersion`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceVersion.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_enumerate_instance_version(
+        &self,
+        f: impl FnMut(*mut u32) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<EnumerateInstanceVersionFn>("vkEnumerateInstanceVersion", Box::new(f))
+    }
+}
+type EnumeratePhysicalDeviceGroupsFn =
+    dyn FnMut(vk::Instance, *mut u32, *mut vk::PhysicalDeviceGroupProperties<'_>) -> vk::Result;
+unsafe extern "system" fn enumerate_physical_device_groups(
+    instance: vk::Instance,
+    p_physical_device_group_count: *mut u32,
+    p_physical_device_group_properties: *mut vk::PhysicalDeviceGroupProperties<'_>,
+) -> vk::Result {
+    dispatch::<EnumeratePhysicalDeviceGroupsFn, _>(
+        "vkEnumeratePhysicalDeviceGroups",
+        &[instance.as_raw()],
+        |f| {
+            f(
+                instance,
+                p_physical_device_group_count,
+                p_physical_device_group_properties,
+            )
+        },
+        |_| {
+            if !p_physical_device_group_count.is_null() {
+                ptr::write_bytes(p_physical_device_group_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkEnumeratePhysicalDeviceGroups`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDeviceGroups.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_enumerate_physical_device_groups(
+        &self,
+        f: impl FnMut(vk::Instance, *mut u32, *mut vk::PhysicalDeviceGroupProperties<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<EnumeratePhysicalDeviceGroupsFn>(
+            "vkEnumeratePhysicalDeviceGroups",
+            Box::new(f),
+        )
+    }
+}
+type EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    u32,
+    *mut u32,
+    *mut vk::PerformanceCounterKHR<'_>,
+    *mut vk::PerformanceCounterDescriptionKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn enumerate_physical_device_queue_family_performance_query_counters_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    p_counter_count: *mut u32,
+    p_counters: *mut vk::PerformanceCounterKHR<'_>,
+    p_counter_descriptions: *mut vk::PerformanceCounterDescriptionKHR<'_>,
+) -> vk::Result {
+    dispatch::<EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHRFn, _>(
+        "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                queue_family_index,
+                p_counter_count,
+                p_counters,
+                p_counter_descriptions,
+            )
+        },
+        |_| {
+            if !p_counter_count.is_null() {
+                ptr::write_bytes(p_counter_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_enumerate_physical_device_queue_family_performance_query_counters_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                u32,
+                *mut u32,
+                *mut vk::PerformanceCounterKHR<'_>,
+                *mut vk::PerformanceCounterDescriptionKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHRFn>(
+            "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+            Box::new(f),
+        )
+    }
+}
+type EnumeratePhysicalDevicesFn =
+    dyn FnMut(vk::Instance, *mut u32, *mut vk::PhysicalDevice) -> vk::Result;
+unsafe extern "system" fn enumerate_physical_devices(
+    instance: vk::Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    dispatch::<EnumeratePhysicalDevicesFn, _>(
+        "vkEnumeratePhysicalDevices",
+        &[instance.as_raw()],
+        |f| f(instance, p_physical_device_count, p_physical_devices),
+        |driver| {
+            if !p_physical_device_count.is_null() {
+                if p_physical_devices.is_null() {
+                    *p_physical_device_count = 1;
+                } else {
+                    let len = (*p_physical_device_count).min(1);
+                    for i in 0..len as usize {
+                        *p_physical_devices.add(i) = driver.allocate();
+                    }
+                    *p_physical_device_count = len;
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkEnumeratePhysicalDevices`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDevices.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_enumerate_physical_devices(
+        &self,
+        f: impl FnMut(vk::Instance, *mut u32, *mut vk::PhysicalDevice) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<EnumeratePhysicalDevicesFn>("vkEnumeratePhysicalDevices", Box::new(f))
+    }
+}
+type ExportMetalObjectsEXTFn = dyn FnMut(vk::Device, *mut vk::ExportMetalObjectsInfoEXT<'_>);
+unsafe extern "system" fn export_metal_objects_ext(
+    device: vk::Device,
+    p_metal_objects_info: *mut vk::ExportMetalObjectsInfoEXT<'_>,
+) {
+    dispatch::<ExportMetalObjectsEXTFn, _>(
+        "vkExportMetalObjectsEXT",
+        &[device.as_raw()],
+        |f| f(device, p_metal_objects_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkExportMetalObjectsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkExportMetalObjectsEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_export_metal_objects_ext(
+        &self,
+        f: impl FnMut(vk::Device, *mut vk::ExportMetalObjectsInfoEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<ExportMetalObjectsEXTFn>("vkExportMetalObjectsEXT", Box::new(f))
+    }
+}
+type FlushMappedMemoryRangesFn =
+    dyn FnMut(vk::Device, u32, *const vk::MappedMemoryRange<'_>) -> vk::Result;
+unsafe extern "system" fn flush_mapped_memory_ranges(
+    device: vk::Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const vk::MappedMemoryRange<'_>,
+) -> vk::Result {
+    dispatch::<FlushMappedMemoryRangesFn, _>(
+        "vkFlushMappedMemoryRanges",
+        &[device.as_raw()],
+        |f| f(device, memory_range_count, p_memory_ranges),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkFlushMappedMemoryRanges`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkFlushMappedMemoryRanges.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_flush_mapped_memory_ranges(
+        &self,
+        f: impl FnMut(vk::Device, u32, *const vk::MappedMemoryRange<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<FlushMappedMemoryRangesFn>("vkFlushMappedMemoryRanges", Box::new(f))
+    }
+}
+type FreeCommandBuffersFn = dyn FnMut(vk::Device, vk::CommandPool, u32, *const vk::CommandBuffer);
+unsafe extern "system" fn free_command_buffers(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    command_buffer_count: u32,
+    p_command_buffers: *const vk::CommandBuffer,
+) {
+    dispatch::<FreeCommandBuffersFn, _>(
+        "vkFreeCommandBuffers",
+        &[device.as_raw(), command_pool.as_raw()],
+        |f| {
+            f(
+                device,
+                command_pool,
+                command_buffer_count,
+                p_command_buffers,
+            )
+        },
+        |driver| {
+            if !p_command_buffers.is_null() {
+                for i in 0..command_buffer_count as usize {
+                    driver.release((*p_command_buffers.add(i)).as_raw());
+                }
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkFreeCommandBuffers`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkFreeCommandBuffers.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_free_command_buffers(
+        &self,
+        f: impl FnMut(vk::Device, vk::CommandPool, u32, *const vk::CommandBuffer) + 'static,
+    ) -> &Self {
+        self.set_handler::<FreeCommandBuffersFn>("vkFreeCommandBuffers", Box::new(f))
+    }
+}
+type FreeDescriptorSetsFn =
+    dyn FnMut(vk::Device, vk::DescriptorPool, u32, *const vk::DescriptorSet) -> vk::Result;
+unsafe extern "system" fn free_descriptor_sets(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const vk::DescriptorSet,
+) -> vk::Result {
+    dispatch::<FreeDescriptorSetsFn, _>(
+        "vkFreeDescriptorSets",
+        &[device.as_raw(), descriptor_pool.as_raw()],
+        |f| {
+            f(
+                device,
+                descriptor_pool,
+                descriptor_set_count,
+                p_descriptor_sets,
+            )
+        },
+        |driver| {
+            if !p_descriptor_sets.is_null() {
+                for i in 0..descriptor_set_count as usize {
+                    driver.release((*p_descriptor_sets.add(i)).as_raw());
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkFreeDescriptorSets`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkFreeDescriptorSets.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_free_descriptor_sets(
+        &self,
+        f: impl FnMut(vk::Device, vk::DescriptorPool, u32, *const vk::DescriptorSet) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<FreeDescriptorSetsFn>("vkFreeDescriptorSets", Box::new(f))
+    }
+}
+type FreeMemoryFn = dyn FnMut(vk::Device, vk::DeviceMemory, *const vk::AllocationCallbacks<'_>);
+unsafe extern "system" fn free_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    dispatch::<FreeMemoryFn, _>(
+        "vkFreeMemory",
+        &[device.as_raw(), memory.as_raw()],
+        |f| f(device, memory, p_allocator),
+        |driver| {
+            driver.release(memory.as_raw());
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkFreeMemory`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkFreeMemory.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_free_memory(
+        &self,
+        f: impl FnMut(vk::Device, vk::DeviceMemory, *const vk::AllocationCallbacks<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<FreeMemoryFn>("vkFreeMemory", Box::new(f))
+    }
+}
+type GetAccelerationStructureBuildSizesKHRFn = dyn FnMut(
+    vk::Device,
+    vk::AccelerationStructureBuildTypeKHR,
+    *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    *const u32,
+    *mut vk::AccelerationStructureBuildSizesInfoKHR<'_>,
+);
+unsafe extern "system" fn get_acceleration_structure_build_sizes_khr(
+    device: vk::Device,
+    build_type: vk::AccelerationStructureBuildTypeKHR,
+    p_build_info: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    p_max_primitive_counts: *const u32,
+    p_size_info: *mut vk::AccelerationStructureBuildSizesInfoKHR<'_>,
+) {
+    dispatch::<GetAccelerationStructureBuildSizesKHRFn, _>(
+        "vkGetAccelerationStructureBuildSizesKHR",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                build_type,
+                p_build_info,
+                p_max_primitive_counts,
+                p_size_info,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetAccelerationStructureBuildSizesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureBuildSizesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_acceleration_structure_build_sizes_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::AccelerationStructureBuildTypeKHR,
+                *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+                *const u32,
+                *mut vk::AccelerationStructureBuildSizesInfoKHR<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetAccelerationStructureBuildSizesKHRFn>(
+            "vkGetAccelerationStructureBuildSizesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetAccelerationStructureDeviceAddressKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::AccelerationStructureDeviceAddressInfoKHR<'_>,
+) -> vk::DeviceAddress;
+unsafe extern "system" fn get_acceleration_structure_device_address_khr(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureDeviceAddressInfoKHR<'_>,
+) -> vk::DeviceAddress {
+    dispatch::<GetAccelerationStructureDeviceAddressKHRFn, _>(
+        "vkGetAccelerationStructureDeviceAddressKHR",
+        &[device.as_raw()],
+        |f| f(device, p_info),
+        |_| Default::default(),
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetAccelerationStructureDeviceAddressKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureDeviceAddressKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_acceleration_structure_device_address_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::AccelerationStructureDeviceAddressInfoKHR<'_>,
+            ) -> vk::DeviceAddress
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetAccelerationStructureDeviceAddressKHRFn>(
+            "vkGetAccelerationStructureDeviceAddressKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetAccelerationStructureHandleNVFn =
+    dyn FnMut(vk::Device, vk::AccelerationStructureNV, usize, *mut c_void) -> vk::Result;
+unsafe extern "system" fn get_acceleration_structure_handle_nv(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureNV,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetAccelerationStructureHandleNVFn, _>(
+        "vkGetAccelerationStructureHandleNV",
+        &[device.as_raw(), acceleration_structure.as_raw()],
+        |f| f(device, acceleration_structure, data_size, p_data),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetAccelerationStructureHandleNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureHandleNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_acceleration_structure_handle_nv(
+        &self,
+        f: impl FnMut(vk::Device, vk::AccelerationStructureNV, usize, *mut c_void) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetAccelerationStructureHandleNVFn>(
+            "vkGetAccelerationStructureHandleNV",
+            Box::new(f),
+        )
+    }
+}
+type GetAccelerationStructureMemoryRequirementsNVFn = dyn FnMut(
+    vk::Device,
+    *const vk::AccelerationStructureMemoryRequirementsInfoNV<'_>,
+    *mut vk::MemoryRequirements2KHR<'_>,
+);
+unsafe extern "system" fn get_acceleration_structure_memory_requirements_nv(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureMemoryRequirementsInfoNV<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2KHR<'_>,
+) {
+    dispatch::<GetAccelerationStructureMemoryRequirementsNVFn, _>(
+        "vkGetAccelerationStructureMemoryRequirementsNV",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_memory_requirements),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetAccelerationStructureMemoryRequirementsNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureMemoryRequirementsNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_acceleration_structure_memory_requirements_nv(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::AccelerationStructureMemoryRequirementsInfoNV<'_>,
+                *mut vk::MemoryRequirements2KHR<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetAccelerationStructureMemoryRequirementsNVFn>(
+            "vkGetAccelerationStructureMemoryRequirementsNV",
+            Box::new(f),
+        )
+    }
+}
+type GetAccelerationStructureOpaqueCaptureDescriptorDataEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::AccelerationStructureCaptureDescriptorDataInfoEXT<'_>,
+    *mut c_void,
+) -> vk::Result;
+unsafe extern "system" fn get_acceleration_structure_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetAccelerationStructureOpaqueCaptureDescriptorDataEXTFn, _>(
+        "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_data),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_acceleration_structure_opaque_capture_descriptor_data_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::AccelerationStructureCaptureDescriptorDataInfoEXT<'_>,
+                *mut c_void,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetAccelerationStructureOpaqueCaptureDescriptorDataEXTFn>(
+            "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetAndroidHardwareBufferPropertiesANDROIDFn = dyn FnMut(
+    vk::Device,
+    *const vk::AHardwareBuffer,
+    *mut vk::AndroidHardwareBufferPropertiesANDROID<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_android_hardware_buffer_properties_android(
+    device: vk::Device,
+    buffer: *const vk::AHardwareBuffer,
+    p_properties: *mut vk::AndroidHardwareBufferPropertiesANDROID<'_>,
+) -> vk::Result {
+    dispatch::<GetAndroidHardwareBufferPropertiesANDROIDFn, _>(
+        "vkGetAndroidHardwareBufferPropertiesANDROID",
+        &[device.as_raw()],
+        |f| f(device, buffer, p_properties),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetAndroidHardwareBufferPropertiesANDROID`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAndroidHardwareBufferPropertiesANDROID.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_android_hardware_buffer_properties_android(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::AHardwareBuffer,
+                *mut vk::AndroidHardwareBufferPropertiesANDROID<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetAndroidHardwareBufferPropertiesANDROIDFn>(
+            "vkGetAndroidHardwareBufferPropertiesANDROID",
+            Box::new(f),
+        )
+    }
+}
+type GetBufferCollectionPropertiesFUCHSIAFn = dyn FnMut(
+    vk::Device,
+    vk::BufferCollectionFUCHSIA,
+    *mut vk::BufferCollectionPropertiesFUCHSIA<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_buffer_collection_properties_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_properties: *mut vk::BufferCollectionPropertiesFUCHSIA<'_>,
+) -> vk::Result {
+    dispatch::<GetBufferCollectionPropertiesFUCHSIAFn, _>(
+        "vkGetBufferCollectionPropertiesFUCHSIA",
+        &[device.as_raw(), collection.as_raw()],
+        |f| f(device, collection, p_properties),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetBufferCollectionPropertiesFUCHSIA`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferCollectionPropertiesFUCHSIA.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_buffer_collection_properties_fuchsia(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::BufferCollectionFUCHSIA,
+                *mut vk::BufferCollectionPropertiesFUCHSIA<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetBufferCollectionPropertiesFUCHSIAFn>(
+            "vkGetBufferCollectionPropertiesFUCHSIA",
+            Box::new(f),
+        )
+    }
+}
+type GetBufferDeviceAddressFn =
+    dyn FnMut(vk::Device, *const vk::BufferDeviceAddressInfo<'_>) -> vk::DeviceAddress;
+unsafe extern "system" fn get_buffer_device_address(
+    device: vk::Device,
+    p_info: *const vk::BufferDeviceAddressInfo<'_>,
+) -> vk::DeviceAddress {
+    dispatch::<GetBufferDeviceAddressFn, _>(
+        "vkGetBufferDeviceAddress",
+        &[device.as_raw()],
+        |f| f(device, p_info),
+        |_| Default::default(),
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetBufferDeviceAddress`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferDeviceAddress.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_buffer_device_address(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::BufferDeviceAddressInfo<'_>) -> vk::DeviceAddress + 'static,
+    ) -> &Self {
+        self.set_handler::<GetBufferDeviceAddressFn>("vkGetBufferDeviceAddress", Box::new(f))
+    }
+}
+type GetBufferMemoryRequirementsFn = dyn FnMut(vk::Device, vk::Buffer, *mut vk::MemoryRequirements);
+unsafe extern "system" fn get_buffer_memory_requirements(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) {
+    dispatch::<GetBufferMemoryRequirementsFn, _>(
+        "vkGetBufferMemoryRequirements",
+        &[device.as_raw(), buffer.as_raw()],
+        |f| f(device, buffer, p_memory_requirements),
+        |_| {
+            if !p_memory_requirements.is_null() {
+                ptr::write_bytes(p_memory_requirements, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetBufferMemoryRequirements`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_buffer_memory_requirements(
+        &self,
+        f: impl FnMut(vk::Device, vk::Buffer, *mut vk::MemoryRequirements) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetBufferMemoryRequirementsFn>(
+            "vkGetBufferMemoryRequirements",
+            Box::new(f),
+        )
+    }
+}
+type GetBufferMemoryRequirements2Fn = dyn FnMut(
+    vk::Device,
+    *const vk::BufferMemoryRequirementsInfo2<'_>,
+    *mut vk::MemoryRequirements2<'_>,
+);
+unsafe extern "system" fn get_buffer_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::BufferMemoryRequirementsInfo2<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    dispatch::<GetBufferMemoryRequirements2Fn, _>(
+        "vkGetBufferMemoryRequirements2",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_memory_requirements),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetBufferMemoryRequirements2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferMemoryRequirements2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_buffer_memory_requirements2(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::BufferMemoryRequirementsInfo2<'_>,
+                *mut vk::MemoryRequirements2<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetBufferMemoryRequirements2Fn>(
+            "vkGetBufferMemoryRequirements2",
+            Box::new(f),
+        )
+    }
+}
+type GetBufferOpaqueCaptureAddressFn =
+    dyn FnMut(vk::Device, *const vk::BufferDeviceAddressInfo<'_>) -> u64;
+unsafe extern "system" fn get_buffer_opaque_capture_address(
+    device: vk::Device,
+    p_info: *const vk::BufferDeviceAddressInfo<'_>,
+) -> u64 {
+    dispatch::<GetBufferOpaqueCaptureAddressFn, _>(
+        "vkGetBufferOpaqueCaptureAddress",
+        &[device.as_raw()],
+        |f| f(device, p_info),
+        |_| Default::default(),
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetBufferOpaqueCaptureAddress`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferOpaqueCaptureAddress.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_buffer_opaque_capture_address(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::BufferDeviceAddressInfo<'_>) -> u64 + 'static,
+    ) -> &Self {
+        self.set_handler::<GetBufferOpaqueCaptureAddressFn>(
+            "vkGetBufferOpaqueCaptureAddress",
+            Box::new(f),
+        )
+    }
+}
+type GetBufferOpaqueCaptureDescriptorDataEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::BufferCaptureDescriptorDataInfoEXT<'_>,
+    *mut c_void,
+) -> vk::Result;
+unsafe extern "system" fn get_buffer_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::BufferCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetBufferOpaqueCaptureDescriptorDataEXTFn, _>(
+        "vkGetBufferOpaqueCaptureDescriptorDataEXT",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_data),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetBufferOpaqueCaptureDescriptorDataEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferOpaqueCaptureDescriptorDataEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_buffer_opaque_capture_descriptor_data_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::BufferCaptureDescriptorDataInfoEXT<'_>,
+                *mut c_void,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetBufferOpaqueCaptureDescriptorDataEXTFn>(
+            "vkGetBufferOpaqueCaptureDescriptorDataEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetCalibratedTimestampsEXTFn = dyn FnMut(
+    vk::Device,
+    u32,
+    *const vk::CalibratedTimestampInfoEXT<'_>,
+    *mut u64,
+    *mut u64,
+) -> vk::Result;
+unsafe extern "system" fn get_calibrated_timestamps_ext(
+    device: vk::Device,
+    timestamp_count: u32,
+    p_timestamp_infos: *const vk::CalibratedTimestampInfoEXT<'_>,
+    p_timestamps: *mut u64,
+    p_max_deviation: *mut u64,
+) -> vk::Result {
+    dispatch::<GetCalibratedTimestampsEXTFn, _>(
+        "vkGetCalibratedTimestampsEXT",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                timestamp_count,
+                p_timestamp_infos,
+                p_timestamps,
+                p_max_deviation,
+            )
+        },
+        |_| {
+            if !p_max_deviation.is_null() {
+                ptr::write_bytes(p_max_deviation, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetCalibratedTimestampsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetCalibratedTimestampsEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_calibrated_timestamps_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                u32,
+                *const vk::CalibratedTimestampInfoEXT<'_>,
+                *mut u64,
+                *mut u64,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetCalibratedTimestampsEXTFn>(
+            "vkGetCalibratedTimestampsEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetDeferredOperationMaxConcurrencyKHRFn =
+    dyn FnMut(vk::Device, vk::DeferredOperationKHR) -> u32;
+unsafe extern "system" fn get_deferred_operation_max_concurrency_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> u32 {
+    dispatch::<GetDeferredOperationMaxConcurrencyKHRFn, _>(
+        "vkGetDeferredOperationMaxConcurrencyKHR",
+        &[device.as_raw(), operation.as_raw()],
+        |f| f(device, operation),
+        |_| Default::default(),
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeferredOperationMaxConcurrencyKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeferredOperationMaxConcurrencyKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_deferred_operation_max_concurrency_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::DeferredOperationKHR) -> u32 + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeferredOperationMaxConcurrencyKHRFn>(
+            "vkGetDeferredOperationMaxConcurrencyKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetDeferredOperationResultKHRFn =
+    dyn FnMut(vk::Device, vk::DeferredOperationKHR) -> vk::Result;
+unsafe extern "system" fn get_deferred_operation_result_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> vk::Result {
+    dispatch::<GetDeferredOperationResultKHRFn, _>(
+        "vkGetDeferredOperationResultKHR",
+        &[device.as_raw(), operation.as_raw()],
+        |f| f(device, operation),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeferredOperationResultKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeferredOperationResultKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_deferred_operation_result_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::DeferredOperationKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeferredOperationResultKHRFn>(
+            "vkGetDeferredOperationResultKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetDescriptorEXTFn =
+    dyn FnMut(vk::Device, *const vk::DescriptorGetInfoEXT<'_>, usize, *mut c_void);
+unsafe extern "system" fn get_descriptor_ext(
+    device: vk::Device,
+    p_descriptor_info: *const vk::DescriptorGetInfoEXT<'_>,
+    data_size: usize,
+    p_descriptor: *mut c_void,
+) {
+    dispatch::<GetDescriptorEXTFn, _>(
+        "vkGetDescriptorEXT",
+        &[device.as_raw()],
+        |f| f(device, p_descriptor_info, data_size, p_descriptor),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDescriptorEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_descriptor_ext(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::DescriptorGetInfoEXT<'_>, usize, *mut c_void) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDescriptorEXTFn>("vkGetDescriptorEXT", Box::new(f))
+    }
+}
+type GetDescriptorSetHostMappingVALVEFn =
+    dyn FnMut(vk::Device, vk::DescriptorSet, *mut *mut c_void);
+unsafe extern "system" fn get_descriptor_set_host_mapping_valve(
+    device: vk::Device,
+    descriptor_set: vk::DescriptorSet,
+    pp_data: *mut *mut c_void,
+) {
+    dispatch::<GetDescriptorSetHostMappingVALVEFn, _>(
+        "vkGetDescriptorSetHostMappingVALVE",
+        &[device.as_raw(), descriptor_set.as_raw()],
+        |f| f(device, descriptor_set, pp_data),
+        |_| {
+            if !pp_data.is_null() {
+                *pp_data = ptr::null_mut();
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDescriptorSetHostMappingVALVE`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetHostMappingVALVE.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_descriptor_set_host_mapping_valve(
+        &self,
+        f: impl FnMut(vk::Device, vk::DescriptorSet, *mut *mut c_void) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDescriptorSetHostMappingVALVEFn>(
+            "vkGetDescriptorSetHostMappingVALVE",
+            Box::new(f),
+        )
+    }
+}
+type GetDescriptorSetLayoutBindingOffsetEXTFn =
+    dyn FnMut(vk::Device, vk::DescriptorSetLayout, u32, *mut vk::DeviceSize);
+unsafe extern "system" fn get_descriptor_set_layout_binding_offset_ext(
+    device: vk::Device,
+    layout: vk::DescriptorSetLayout,
+    binding: u32,
+    p_offset: *mut vk::DeviceSize,
+) {
+    dispatch::<GetDescriptorSetLayoutBindingOffsetEXTFn, _>(
+        "vkGetDescriptorSetLayoutBindingOffsetEXT",
+        &[device.as_raw(), layout.as_raw()],
+        |f| f(device, layout, binding, p_offset),
+        |_| {
+            if !p_offset.is_null() {
+                ptr::write_bytes(p_offset, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDescriptorSetLayoutBindingOffsetEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutBindingOffsetEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_descriptor_set_layout_binding_offset_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::DescriptorSetLayout, u32, *mut vk::DeviceSize) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDescriptorSetLayoutBindingOffsetEXTFn>(
+            "vkGetDescriptorSetLayoutBindingOffsetEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetDescriptorSetLayoutHostMappingInfoVALVEFn = dyn FnMut(
+    vk::Device,
+    *const vk::DescriptorSetBindingReferenceVALVE<'_>,
+    *mut vk::DescriptorSetLayoutHostMappingInfoVALVE<'_>,
+);
+unsafe extern "system" fn get_descriptor_set_layout_host_mapping_info_valve(
+    device: vk::Device,
+    p_binding_reference: *const vk::DescriptorSetBindingReferenceVALVE<'_>,
+    p_host_mapping: *mut vk::DescriptorSetLayoutHostMappingInfoVALVE<'_>,
+) {
+    dispatch::<GetDescriptorSetLayoutHostMappingInfoVALVEFn, _>(
+        "vkGetDescriptorSetLayoutHostMappingInfoVALVE",
+        &[device.as_raw()],
+        |f| f(device, p_binding_reference, p_host_mapping),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDescriptorSetLayoutHostMappingInfoVALVE`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutHostMappingInfoVALVE.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_descriptor_set_layout_host_mapping_info_valve(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::DescriptorSetBindingReferenceVALVE<'_>,
+                *mut vk::DescriptorSetLayoutHostMappingInfoVALVE<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDescriptorSetLayoutHostMappingInfoVALVEFn>(
+            "vkGetDescriptorSetLayoutHostMappingInfoVALVE",
+            Box::new(f),
+        )
+    }
+}
+type GetDescriptorSetLayoutSizeEXTFn =
+    dyn FnMut(vk::Device, vk::DescriptorSetLayout, *mut vk::DeviceSize);
+unsafe extern "system" fn get_descriptor_set_layout_size_ext(
+    device: vk::Device,
+    layout: vk::DescriptorSetLayout,
+    p_layout_size_in_bytes: *mut vk::DeviceSize,
+) {
+    dispatch::<GetDescriptorSetLayoutSizeEXTFn, _>(
+        "vkGetDescriptorSetLayoutSizeEXT",
+        &[device.as_raw(), layout.as_raw()],
+        |f| f(device, layout, p_layout_size_in_bytes),
+        |_| {
+            if !p_layout_size_in_bytes.is_null() {
+                ptr::write_bytes(p_layout_size_in_bytes, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDescriptorSetLayoutSizeEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSizeEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_descriptor_set_layout_size_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::DescriptorSetLayout, *mut vk::DeviceSize) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDescriptorSetLayoutSizeEXTFn>(
+            "vkGetDescriptorSetLayoutSizeEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetDescriptorSetLayoutSupportFn = dyn FnMut(
+    vk::Device,
+    *const vk::DescriptorSetLayoutCreateInfo<'_>,
+    *mut vk::DescriptorSetLayoutSupport<'_>,
+);
+unsafe extern "system" fn get_descriptor_set_layout_support(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorSetLayoutCreateInfo<'_>,
+    p_support: *mut vk::DescriptorSetLayoutSupport<'_>,
+) {
+    dispatch::<GetDescriptorSetLayoutSupportFn, _>(
+        "vkGetDescriptorSetLayoutSupport",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_support),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDescriptorSetLayoutSupport`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSupport.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_descriptor_set_layout_support(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::DescriptorSetLayoutCreateInfo<'_>,
+                *mut vk::DescriptorSetLayoutSupport<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDescriptorSetLayoutSupportFn>(
+            "vkGetDescriptorSetLayoutSupport",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceAccelerationStructureCompatibilityKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::AccelerationStructureVersionInfoKHR<'_>,
+    *mut vk::AccelerationStructureCompatibilityKHR,
+);
+unsafe extern "system" fn get_device_acceleration_structure_compatibility_khr(
+    device: vk::Device,
+    p_version_info: *const vk::AccelerationStructureVersionInfoKHR<'_>,
+    p_compatibility: *mut vk::AccelerationStructureCompatibilityKHR,
+) {
+    dispatch::<GetDeviceAccelerationStructureCompatibilityKHRFn, _>(
+        "vkGetDeviceAccelerationStructureCompatibilityKHR",
+        &[device.as_raw()],
+        |f| f(device, p_version_info, p_compatibility),
+        |_| {
+            if !p_compatibility.is_null() {
+                ptr::write_bytes(p_compatibility, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceAccelerationStructureCompatibilityKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceAccelerationStructureCompatibilityKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_acceleration_structure_compatibility_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::AccelerationStructureVersionInfoKHR<'_>,
+                *mut vk::AccelerationStructureCompatibilityKHR,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceAccelerationStructureCompatibilityKHRFn>(
+            "vkGetDeviceAccelerationStructureCompatibilityKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceBufferMemoryRequirementsFn = dyn FnMut(
+    vk::Device,
+    *const vk::DeviceBufferMemoryRequirements<'_>,
+    *mut vk::MemoryRequirements2<'_>,
+);
+unsafe extern "system" fn get_device_buffer_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceBufferMemoryRequirements<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    dispatch::<GetDeviceBufferMemoryRequirementsFn, _>(
+        "vkGetDeviceBufferMemoryRequirements",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_memory_requirements),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceBufferMemoryRequirements`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceBufferMemoryRequirements.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_buffer_memory_requirements(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::DeviceBufferMemoryRequirements<'_>,
+                *mut vk::MemoryRequirements2<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceBufferMemoryRequirementsFn>(
+            "vkGetDeviceBufferMemoryRequirements",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceFaultInfoEXTFn = dyn FnMut(
+    vk::Device,
+    *mut vk::DeviceFaultCountsEXT<'_>,
+    *mut vk::DeviceFaultInfoEXT<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_device_fault_info_ext(
+    device: vk::Device,
+    p_fault_counts: *mut vk::DeviceFaultCountsEXT<'_>,
+    p_fault_info: *mut vk::DeviceFaultInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<GetDeviceFaultInfoEXTFn, _>(
+        "vkGetDeviceFaultInfoEXT",
+        &[device.as_raw()],
+        |f| f(device, p_fault_counts, p_fault_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceFaultInfoEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceFaultInfoEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_fault_info_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *mut vk::DeviceFaultCountsEXT<'_>,
+                *mut vk::DeviceFaultInfoEXT<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceFaultInfoEXTFn>("vkGetDeviceFaultInfoEXT", Box::new(f))
+    }
+}
+type GetDeviceGroupPeerMemoryFeaturesFn =
+    dyn FnMut(vk::Device, u32, u32, u32, *mut vk::PeerMemoryFeatureFlags);
+unsafe extern "system" fn get_device_group_peer_memory_features(
+    device: vk::Device,
+    heap_index: u32,
+    local_device_index: u32,
+    remote_device_index: u32,
+    p_peer_memory_features: *mut vk::PeerMemoryFeatureFlags,
+) {
+    dispatch::<GetDeviceGroupPeerMemoryFeaturesFn, _>(
+        "vkGetDeviceGroupPeerMemoryFeatures",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                heap_index,
+                local_device_index,
+                remote_device_index,
+                p_peer_memory_features,
+            )
+        },
+        |_| {
+            if !p_peer_memory_features.is_null() {
+                ptr::write_bytes(p_peer_memory_features, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceGroupPeerMemoryFeatures`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupPeerMemoryFeatures.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_group_peer_memory_features(
+        &self,
+        f: impl FnMut(vk::Device, u32, u32, u32, *mut vk::PeerMemoryFeatureFlags) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceGroupPeerMemoryFeaturesFn>(
+            "vkGetDeviceGroupPeerMemoryFeatures",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceGroupPresentCapabilitiesKHRFn =
+    dyn FnMut(vk::Device, *mut vk::DeviceGroupPresentCapabilitiesKHR<'_>) -> vk::Result;
+unsafe extern "system" fn get_device_group_present_capabilities_khr(
+    device: vk::Device,
+    p_device_group_present_capabilities: *mut vk::DeviceGroupPresentCapabilitiesKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetDeviceGroupPresentCapabilitiesKHRFn, _>(
+        "vkGetDeviceGroupPresentCapabilitiesKHR",
+        &[device.as_raw()],
+        |f| f(device, p_device_group_present_capabilities),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceGroupPresentCapabilitiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupPresentCapabilitiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_group_present_capabilities_khr(
+        &self,
+        f: impl FnMut(vk::Device, *mut vk::DeviceGroupPresentCapabilitiesKHR<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceGroupPresentCapabilitiesKHRFn>(
+            "vkGetDeviceGroupPresentCapabilitiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceGroupSurfacePresentModes2EXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    *mut vk::DeviceGroupPresentModeFlagsKHR,
+) -> vk::Result;
+unsafe extern "system" fn get_device_group_surface_present_modes2_ext(
+    device: vk::Device,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_modes: *mut vk::DeviceGroupPresentModeFlagsKHR,
+) -> vk::Result {
+    dispatch::<GetDeviceGroupSurfacePresentModes2EXTFn, _>(
+        "vkGetDeviceGroupSurfacePresentModes2EXT",
+        &[device.as_raw()],
+        |f| f(device, p_surface_info, p_modes),
+        |_| {
+            if !p_modes.is_null() {
+                ptr::write_bytes(p_modes, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceGroupSurfacePresentModes2EXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupSurfacePresentModes2EXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_group_surface_present_modes2_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+                *mut vk::DeviceGroupPresentModeFlagsKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceGroupSurfacePresentModes2EXTFn>(
+            "vkGetDeviceGroupSurfacePresentModes2EXT",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceGroupSurfacePresentModesKHRFn =
+    dyn FnMut(vk::Device, vk::SurfaceKHR, *mut vk::DeviceGroupPresentModeFlagsKHR) -> vk::Result;
+unsafe extern "system" fn get_device_group_surface_present_modes_khr(
+    device: vk::Device,
+    surface: vk::SurfaceKHR,
+    p_modes: *mut vk::DeviceGroupPresentModeFlagsKHR,
+) -> vk::Result {
+    dispatch::<GetDeviceGroupSurfacePresentModesKHRFn, _>(
+        "vkGetDeviceGroupSurfacePresentModesKHR",
+        &[device.as_raw(), surface.as_raw()],
+        |f| f(device, surface, p_modes),
+        |_| {
+            if !p_modes.is_null() {
+                ptr::write_bytes(p_modes, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceGroupSurfacePresentModesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceGroupSurfacePresentModesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_group_surface_present_modes_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::SurfaceKHR, *mut vk::DeviceGroupPresentModeFlagsKHR) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceGroupSurfacePresentModesKHRFn>(
+            "vkGetDeviceGroupSurfacePresentModesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceImageMemoryRequirementsFn = dyn FnMut(
+    vk::Device,
+    *const vk::DeviceImageMemoryRequirements<'_>,
+    *mut vk::MemoryRequirements2<'_>,
+);
+unsafe extern "system" fn get_device_image_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceImageMemoryRequirements<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    dispatch::<GetDeviceImageMemoryRequirementsFn, _>(
+        "vkGetDeviceImageMemoryRequirements",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_memory_requirements),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceImageMemoryRequirements`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageMemoryRequirements.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_image_memory_requirements(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::DeviceImageMemoryRequirements<'_>,
+                *mut vk::MemoryRequirements2<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceImageMemoryRequirementsFn>(
+            "vkGetDeviceImageMemoryRequirements",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceImageSparseMemoryRequirementsFn = dyn FnMut(
+    vk::Device,
+    *const vk::DeviceImageMemoryRequirements<'_>,
+    *mut u32,
+    *mut vk::SparseImageMemoryRequirements2<'_>,
+);
+unsafe extern "system" fn get_device_image_sparse_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceImageMemoryRequirements<'_>,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements2<'_>,
+) {
+    dispatch::<GetDeviceImageSparseMemoryRequirementsFn, _>(
+        "vkGetDeviceImageSparseMemoryRequirements",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                p_info,
+                p_sparse_memory_requirement_count,
+                p_sparse_memory_requirements,
+            )
+        },
+        |_| {
+            if !p_sparse_memory_requirement_count.is_null() {
+                ptr::write_bytes(p_sparse_memory_requirement_count, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceImageSparseMemoryRequirements`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageSparseMemoryRequirements.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_image_sparse_memory_requirements(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::DeviceImageMemoryRequirements<'_>,
+                *mut u32,
+                *mut vk::SparseImageMemoryRequirements2<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceImageSparseMemoryRequirementsFn>(
+            "vkGetDeviceImageSparseMemoryRequirements",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceMemoryCommitmentFn = dyn FnMut(vk::Device, vk::DeviceMemory, *mut vk::DeviceSize);
+unsafe extern "system" fn get_device_memory_commitment(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    p_committed_memory_in_bytes: *mut vk::DeviceSize,
+) {
+    dispatch::<GetDeviceMemoryCommitmentFn, _>(
+        "vkGetDeviceMemoryCommitment",
+        &[device.as_raw(), memory.as_raw()],
+        |f| f(device, memory, p_committed_memory_in_bytes),
+        |_| {
+            if !p_committed_memory_in_bytes.is_null() {
+                ptr::write_bytes(p_committed_memory_in_bytes, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceMemoryCommitment`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceMemoryCommitment.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_memory_commitment(
+        &self,
+        f: impl FnMut(vk::Device, vk::DeviceMemory, *mut vk::DeviceSize) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceMemoryCommitmentFn>("vkGetDeviceMemoryCommitment", Box::new(f))
+    }
+}
+type GetDeviceMemoryOpaqueCaptureAddressFn =
+    dyn FnMut(vk::Device, *const vk::DeviceMemoryOpaqueCaptureAddressInfo<'_>) -> u64;
+unsafe extern "system" fn get_device_memory_opaque_capture_address(
+    device: vk::Device,
+    p_info: *const vk::DeviceMemoryOpaqueCaptureAddressInfo<'_>,
+) -> u64 {
+    dispatch::<GetDeviceMemoryOpaqueCaptureAddressFn, _>(
+        "vkGetDeviceMemoryOpaqueCaptureAddress",
+        &[device.as_raw()],
+        |f| f(device, p_info),
+        |_| Default::default(),
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceMemoryOpaqueCaptureAddress`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceMemoryOpaqueCaptureAddress.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_memory_opaque_capture_address(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::DeviceMemoryOpaqueCaptureAddressInfo<'_>) -> u64 + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceMemoryOpaqueCaptureAddressFn>(
+            "vkGetDeviceMemoryOpaqueCaptureAddress",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceMicromapCompatibilityEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::MicromapVersionInfoEXT<'_>,
+    *mut vk::AccelerationStructureCompatibilityKHR,
+);
+unsafe extern "system" fn get_device_micromap_compatibility_ext(
+    device: vk::Device,
+    p_version_info: *const vk::MicromapVersionInfoEXT<'_>,
+    p_compatibility: *mut vk::AccelerationStructureCompatibilityKHR,
+) {
+    dispatch::<GetDeviceMicromapCompatibilityEXTFn, _>(
+        "vkGetDeviceMicromapCompatibilityEXT",
+        &[device.as_raw()],
+        |f| f(device, p_version_info, p_compatibility),
+        |_| {
+            if !p_compatibility.is_null() {
+                ptr::write_bytes(p_compatibility, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceMicromapCompatibilityEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceMicromapCompatibilityEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_micromap_compatibility_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::MicromapVersionInfoEXT<'_>,
+                *mut vk::AccelerationStructureCompatibilityKHR,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceMicromapCompatibilityEXTFn>(
+            "vkGetDeviceMicromapCompatibilityEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetDeviceQueueFn = dyn FnMut(vk::Device, u32, u32, *mut vk::Queue);
+unsafe extern "system" fn get_device_queue(
+    device: vk::Device,
+    queue_family_index: u32,
+    queue_index: u32,
+    p_queue: *mut vk::Queue,
+) {
+    dispatch::<GetDeviceQueueFn, _>(
+        "vkGetDeviceQueue",
+        &[device.as_raw()],
+        |f| f(device, queue_family_index, queue_index, p_queue),
+        |driver| {
+            if !p_queue.is_null() {
+                *p_queue = driver.allocate();
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceQueue`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceQueue.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_queue(
+        &self,
+        f: impl FnMut(vk::Device, u32, u32, *mut vk::Queue) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceQueueFn>("vkGetDeviceQueue", Box::new(f))
+    }
+}
+type GetDeviceQueue2Fn = dyn FnMut(vk::Device, *const vk::DeviceQueueInfo2<'_>, *mut vk::Queue);
+unsafe extern "system" fn get_device_queue2(
+    device: vk::Device,
+    p_queue_info: *const vk::DeviceQueueInfo2<'_>,
+    p_queue: *mut vk::Queue,
+) {
+    dispatch::<GetDeviceQueue2Fn, _>(
+        "vkGetDeviceQueue2",
+        &[device.as_raw()],
+        |f| f(device, p_queue_info, p_queue),
+        |driver| {
+            if !p_queue.is_null() {
+                *p_queue = driver.allocate();
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceQueue2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceQueue2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_queue2(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::DeviceQueueInfo2<'_>, *mut vk::Queue) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceQueue2Fn>("vkGetDeviceQueue2", Box::new(f))
+    }
+}
+type GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEIFn =
+    dyn FnMut(vk::Device, vk::RenderPass, *mut vk::Extent2D) -> vk::Result;
+unsafe extern "system" fn get_device_subpass_shading_max_workgroup_size_huawei(
+    device: vk::Device,
+    renderpass: vk::RenderPass,
+    p_max_workgroup_size: *mut vk::Extent2D,
+) -> vk::Result {
+    dispatch::<GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEIFn, _>(
+        "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI",
+        &[device.as_raw(), renderpass.as_raw()],
+        |f| f(device, renderpass, p_max_workgroup_size),
+        |_| {
+            if !p_max_workgroup_size.is_null() {
+                ptr::write_bytes(p_max_workgroup_size, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_device_subpass_shading_max_workgroup_size_huawei(
+        &self,
+        f: impl FnMut(vk::Device, vk::RenderPass, *mut vk::Extent2D) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEIFn>(
+            "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI",
+            Box::new(f),
+        )
+    }
+}
+type GetDisplayModeProperties2KHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    vk::DisplayKHR,
+    *mut u32,
+    *mut vk::DisplayModeProperties2KHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_display_mode_properties2_khr(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayModeProperties2KHR<'_>,
+) -> vk::Result {
+    dispatch::<GetDisplayModeProperties2KHRFn, _>(
+        "vkGetDisplayModeProperties2KHR",
+        &[physical_device.as_raw(), display.as_raw()],
+        |f| f(physical_device, display, p_property_count, p_properties),
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDisplayModeProperties2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayModeProperties2KHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_display_mode_properties2_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                vk::DisplayKHR,
+                *mut u32,
+                *mut vk::DisplayModeProperties2KHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDisplayModeProperties2KHRFn>(
+            "vkGetDisplayModeProperties2KHR",
+            Box::new(f),
+        )
+    }
+}
+type GetDisplayModePropertiesKHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    vk::DisplayKHR,
+    *mut u32,
+    *mut vk::DisplayModePropertiesKHR,
+) -> vk::Result;
+unsafe extern "system" fn get_display_mode_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayModePropertiesKHR,
+) -> vk::Result {
+    dispatch::<GetDisplayModePropertiesKHRFn, _>(
+        "vkGetDisplayModePropertiesKHR",
+        &[physical_device.as_raw(), display.as_raw()],
+        |f| f(physical_device, display, p_property_count, p_properties),
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDisplayModePropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayModePropertiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_display_mode_properties_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                vk::DisplayKHR,
+                *mut u32,
+                *mut vk::DisplayModePropertiesKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDisplayModePropertiesKHRFn>(
+            "vkGetDisplayModePropertiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetDisplayPlaneCapabilities2KHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::DisplayPlaneInfo2KHR<'_>,
+    *mut vk::DisplayPlaneCapabilities2KHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_display_plane_capabilities2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_display_plane_info: *const vk::DisplayPlaneInfo2KHR<'_>,
+    p_capabilities: *mut vk::DisplayPlaneCapabilities2KHR<'_>,
+) -> vk::Result {
+    dispatch::<GetDisplayPlaneCapabilities2KHRFn, _>(
+        "vkGetDisplayPlaneCapabilities2KHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_display_plane_info, p_capabilities),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDisplayPlaneCapabilities2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayPlaneCapabilities2KHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_display_plane_capabilities2_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::DisplayPlaneInfo2KHR<'_>,
+                *mut vk::DisplayPlaneCapabilities2KHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDisplayPlaneCapabilities2KHRFn>(
+            "vkGetDisplayPlaneCapabilities2KHR",
+            Box::new(f),
+        )
+    }
+}
+type GetDisplayPlaneCapabilitiesKHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    vk::DisplayModeKHR,
+    u32,
+    *mut vk::DisplayPlaneCapabilitiesKHR,
+) -> vk::Result;
+unsafe extern "system" fn get_display_plane_capabilities_khr(
+    physical_device: vk::PhysicalDevice,
+    mode: vk::DisplayModeKHR,
+    plane_index: u32,
+    p_capabilities: *mut vk::DisplayPlaneCapabilitiesKHR,
+) -> vk::Result {
+    dispatch::<GetDisplayPlaneCapabilitiesKHRFn, _>(
+        "vkGetDisplayPlaneCapabilitiesKHR",
+        &[physical_device.as_raw(), mode.as_raw()],
+        |f| f(physical_device, mode, plane_index, p_capabilities),
+        |_| {
+            if !p_capabilities.is_null() {
+                ptr::write_bytes(p_capabilities, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDisplayPlaneCapabilitiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayPlaneCapabilitiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_display_plane_capabilities_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                vk::DisplayModeKHR,
+                u32,
+                *mut vk::DisplayPlaneCapabilitiesKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDisplayPlaneCapabilitiesKHRFn>(
+            "vkGetDisplayPlaneCapabilitiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetDisplayPlaneSupportedDisplaysKHRFn =
+    dyn FnMut(vk::PhysicalDevice, u32, *mut u32, *mut vk::DisplayKHR) -> vk::Result;
+unsafe extern "system" fn get_display_plane_supported_displays_khr(
+    physical_device: vk::PhysicalDevice,
+    plane_index: u32,
+    p_display_count: *mut u32,
+    p_displays: *mut vk::DisplayKHR,
+) -> vk::Result {
+    dispatch::<GetDisplayPlaneSupportedDisplaysKHRFn, _>(
+        "vkGetDisplayPlaneSupportedDisplaysKHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, plane_index, p_display_count, p_displays),
+        |driver| {
+            if !p_display_count.is_null() {
+                if p_displays.is_null() {
+                    *p_display_count = 1;
+                } else {
+                    let len = (*p_display_count).min(1);
+                    for i in 0..len as usize {
+                        *p_displays.add(i) = driver.allocate();
+                    }
+                    *p_display_count = len;
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDisplayPlaneSupportedDisplaysKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayPlaneSupportedDisplaysKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_display_plane_supported_displays_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, u32, *mut u32, *mut vk::DisplayKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDisplayPlaneSupportedDisplaysKHRFn>(
+            "vkGetDisplayPlaneSupportedDisplaysKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetDrmDisplayEXTFn =
+    dyn FnMut(vk::PhysicalDevice, i32, u32, *mut vk::DisplayKHR) -> vk::Result;
+unsafe extern "system" fn get_drm_display_ext(
+    physical_device: vk::PhysicalDevice,
+    drm_fd: i32,
+    connector_id: u32,
+    display: *mut vk::DisplayKHR,
+) -> vk::Result {
+    dispatch::<GetDrmDisplayEXTFn, _>(
+        "vkGetDrmDisplayEXT",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, drm_fd, connector_id, display),
+        |driver| {
+            if !display.is_null() {
+                *display = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDrmDisplayEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDrmDisplayEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_drm_display_ext(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, i32, u32, *mut vk::DisplayKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDrmDisplayEXTFn>("vkGetDrmDisplayEXT", Box::new(f))
+    }
+}
+type GetDynamicRenderingTilePropertiesQCOMFn = dyn FnMut(
+    vk::Device,
+    *const vk::RenderingInfo<'_>,
+    *mut vk::TilePropertiesQCOM<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_dynamic_rendering_tile_properties_qcom(
+    device: vk::Device,
+    p_rendering_info: *const vk::RenderingInfo<'_>,
+    p_properties: *mut vk::TilePropertiesQCOM<'_>,
+) -> vk::Result {
+    dispatch::<GetDynamicRenderingTilePropertiesQCOMFn, _>(
+        "vkGetDynamicRenderingTilePropertiesQCOM",
+        &[device.as_raw()],
+        |f| f(device, p_rendering_info, p_properties),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetDynamicRenderingTilePropertiesQCOM`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDynamicRenderingTilePropertiesQCOM.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_dynamic_rendering_tile_properties_qcom(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::RenderingInfo<'_>,
+                *mut vk::TilePropertiesQCOM<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetDynamicRenderingTilePropertiesQCOMFn>(
+            "vkGetDynamicRenderingTilePropertiesQCOM",
+            Box::new(f),
+        )
+    }
+}
+type GetEncodedVideoSessionParametersKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::VideoEncodeSessionParametersGetInfoKHR<'_>,
+    *mut vk::VideoEncodeSessionParametersFeedbackInfoKHR<'_>,
+    *mut usize,
+    *mut c_void,
+) -> vk::Result;
+unsafe extern "system" fn get_encoded_video_session_parameters_khr(
+    device: vk::Device,
+    p_video_session_parameters_info: *const vk::VideoEncodeSessionParametersGetInfoKHR<'_>,
+    p_feedback_info: *mut vk::VideoEncodeSessionParametersFeedbackInfoKHR<'_>,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetEncodedVideoSessionParametersKHRFn, _>(
+        "vkGetEncodedVideoSessionParametersKHR",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                p_video_session_parameters_info,
+                p_feedback_info,
+                p_data_size,
+                p_data,
+            )
+        },
+        |_| {
+            if !p_data_size.is_null() {
+                ptr::write_bytes(p_data_size, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetEncodedVideoSessionParametersKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetEncodedVideoSessionParametersKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_encoded_video_session_parameters_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::VideoEncodeSessionParametersGetInfoKHR<'_>,
+                *mut vk::VideoEncodeSessionParametersFeedbackInfoKHR<'_>,
+                *mut usize,
+                *mut c_void,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetEncodedVideoSessionParametersKHRFn>(
+            "vkGetEncodedVideoSessionParametersKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetEventStatusFn = dyn FnMut(vk::Device, vk::Event) -> vk::Result;
+unsafe extern "system" fn get_event_status(device: vk::Device, event: vk::Event) -> vk::Result {
+    dispatch::<GetEventStatusFn, _>(
+        "vkGetEventStatus",
+        &[device.as_raw(), event.as_raw()],
+        |f| f(device, event),
+        |_| vk::Result::EVENT_SET,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetEventStatus`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetEventStatus.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_event_status(
+        &self,
+        f: impl FnMut(vk::Device, vk::Event) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetEventStatusFn>("vkGetEventStatus", Box::new(f))
+    }
+}
+type GetFenceFdKHRFn =
+    dyn FnMut(vk::Device, *const vk::FenceGetFdInfoKHR<'_>, *mut c_int) -> vk::Result;
+unsafe extern "system" fn get_fence_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::FenceGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    dispatch::<GetFenceFdKHRFn, _>(
+        "vkGetFenceFdKHR",
+        &[device.as_raw()],
+        |f| f(device, p_get_fd_info, p_fd),
+        |_| {
+            if !p_fd.is_null() {
+                ptr::write_bytes(p_fd, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetFenceFdKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFenceFdKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_fence_fd_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::FenceGetFdInfoKHR<'_>, *mut c_int) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetFenceFdKHRFn>("vkGetFenceFdKHR", Box::new(f))
+    }
+}
+type GetFenceStatusFn = dyn FnMut(vk::Device, vk::Fence) -> vk::Result;
+unsafe extern "system" fn get_fence_status(device: vk::Device, fence: vk::Fence) -> vk::Result {
+    dispatch::<GetFenceStatusFn, _>(
+        "vkGetFenceStatus",
+        &[device.as_raw(), fence.as_raw()],
+        |f| f(device, fence),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetFenceStatus`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFenceStatus.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_fence_status(
+        &self,
+        f: impl FnMut(vk::Device, vk::Fence) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetFenceStatusFn>("vkGetFenceStatus", Box::new(f))
+    }
+}
+type GetFenceWin32HandleKHRFn =
+    dyn FnMut(vk::Device, *const vk::FenceGetWin32HandleInfoKHR<'_>, *mut vk::HANDLE) -> vk::Result;
+unsafe extern "system" fn get_fence_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::FenceGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    dispatch::<GetFenceWin32HandleKHRFn, _>(
+        "vkGetFenceWin32HandleKHR",
+        &[device.as_raw()],
+        |f| f(device, p_get_win32_handle_info, p_handle),
+        |_| {
+            if !p_handle.is_null() {
+                ptr::write_bytes(p_handle, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetFenceWin32HandleKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFenceWin32HandleKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_fence_win32_handle_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::FenceGetWin32HandleInfoKHR<'_>,
+                *mut vk::HANDLE,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetFenceWin32HandleKHRFn>("vkGetFenceWin32HandleKHR", Box::new(f))
+    }
+}
+type GetFramebufferTilePropertiesQCOMFn =
+    dyn FnMut(vk::Device, vk::Framebuffer, *mut u32, *mut vk::TilePropertiesQCOM<'_>) -> vk::Result;
+unsafe extern "system" fn get_framebuffer_tile_properties_qcom(
+    device: vk::Device,
+    framebuffer: vk::Framebuffer,
+    p_properties_count: *mut u32,
+    p_properties: *mut vk::TilePropertiesQCOM<'_>,
+) -> vk::Result {
+    dispatch::<GetFramebufferTilePropertiesQCOMFn, _>(
+        "vkGetFramebufferTilePropertiesQCOM",
+        &[device.as_raw(), framebuffer.as_raw()],
+        |f| f(device, framebuffer, p_properties_count, p_properties),
+        |_| {
+            if !p_properties_count.is_null() {
+                ptr::write_bytes(p_properties_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetFramebufferTilePropertiesQCOM`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFramebufferTilePropertiesQCOM.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_framebuffer_tile_properties_qcom(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::Framebuffer,
+                *mut u32,
+                *mut vk::TilePropertiesQCOM<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetFramebufferTilePropertiesQCOMFn>(
+            "vkGetFramebufferTilePropertiesQCOM",
+            Box::new(f),
+        )
+    }
+}
+type GetGeneratedCommandsMemoryRequirementsNVFn = dyn FnMut(
+    vk::Device,
+    *const vk::GeneratedCommandsMemoryRequirementsInfoNV<'_>,
+    *mut vk::MemoryRequirements2<'_>,
+);
+unsafe extern "system" fn get_generated_commands_memory_requirements_nv(
+    device: vk::Device,
+    p_info: *const vk::GeneratedCommandsMemoryRequirementsInfoNV<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    dispatch::<GetGeneratedCommandsMemoryRequirementsNVFn, _>(
+        "vkGetGeneratedCommandsMemoryRequirementsNV",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_memory_requirements),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetGeneratedCommandsMemoryRequirementsNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetGeneratedCommandsMemoryRequirementsNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_generated_commands_memory_requirements_nv(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::GeneratedCommandsMemoryRequirementsInfoNV<'_>,
+                *mut vk::MemoryRequirements2<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetGeneratedCommandsMemoryRequirementsNVFn>(
+            "vkGetGeneratedCommandsMemoryRequirementsNV",
+            Box::new(f),
+        )
+    }
+}
+type GetImageDrmFormatModifierPropertiesEXTFn = dyn FnMut(
+    vk::Device,
+    vk::Image,
+    *mut vk::ImageDrmFormatModifierPropertiesEXT<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_image_drm_format_modifier_properties_ext(
+    device: vk::Device,
+    image: vk::Image,
+    p_properties: *mut vk::ImageDrmFormatModifierPropertiesEXT<'_>,
+) -> vk::Result {
+    dispatch::<GetImageDrmFormatModifierPropertiesEXTFn, _>(
+        "vkGetImageDrmFormatModifierPropertiesEXT",
+        &[device.as_raw(), image.as_raw()],
+        |f| f(device, image, p_properties),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageDrmFormatModifierPropertiesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageDrmFormatModifierPropertiesEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_drm_format_modifier_properties_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::Image,
+                *mut vk::ImageDrmFormatModifierPropertiesEXT<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageDrmFormatModifierPropertiesEXTFn>(
+            "vkGetImageDrmFormatModifierPropertiesEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetImageMemoryRequirementsFn = dyn FnMut(vk::Device, vk::Image, *mut vk::MemoryRequirements);
+unsafe extern "system" fn get_image_memory_requirements(
+    device: vk::Device,
+    image: vk::Image,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) {
+    dispatch::<GetImageMemoryRequirementsFn, _>(
+        "vkGetImageMemoryRequirements",
+        &[device.as_raw(), image.as_raw()],
+        |f| f(device, image, p_memory_requirements),
+        |_| {
+            if !p_memory_requirements.is_null() {
+                ptr::write_bytes(p_memory_requirements, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageMemoryRequirements`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageMemoryRequirements.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_memory_requirements(
+        &self,
+        f: impl FnMut(vk::Device, vk::Image, *mut vk::MemoryRequirements) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageMemoryRequirementsFn>(
+            "vkGetImageMemoryRequirements",
+            Box::new(f),
+        )
+    }
+}
+type GetImageMemoryRequirements2Fn = dyn FnMut(
+    vk::Device,
+    *const vk::ImageMemoryRequirementsInfo2<'_>,
+    *mut vk::MemoryRequirements2<'_>,
+);
+unsafe extern "system" fn get_image_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::ImageMemoryRequirementsInfo2<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    dispatch::<GetImageMemoryRequirements2Fn, _>(
+        "vkGetImageMemoryRequirements2",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_memory_requirements),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageMemoryRequirements2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageMemoryRequirements2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_memory_requirements2(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::ImageMemoryRequirementsInfo2<'_>,
+                *mut vk::MemoryRequirements2<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageMemoryRequirements2Fn>(
+            "vkGetImageMemoryRequirements2",
+            Box::new(f),
+        )
+    }
+}
+type GetImageOpaqueCaptureDescriptorDataEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::ImageCaptureDescriptorDataInfoEXT<'_>,
+    *mut c_void,
+) -> vk::Result;
+unsafe extern "system" fn get_image_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::ImageCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetImageOpaqueCaptureDescriptorDataEXTFn, _>(
+        "vkGetImageOpaqueCaptureDescriptorDataEXT",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_data),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageOpaqueCaptureDescriptorDataEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageOpaqueCaptureDescriptorDataEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_opaque_capture_descriptor_data_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::ImageCaptureDescriptorDataInfoEXT<'_>,
+                *mut c_void,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageOpaqueCaptureDescriptorDataEXTFn>(
+            "vkGetImageOpaqueCaptureDescriptorDataEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetImageSparseMemoryRequirementsFn =
+    dyn FnMut(vk::Device, vk::Image, *mut u32, *mut vk::SparseImageMemoryRequirements);
+unsafe extern "system" fn get_image_sparse_memory_requirements(
+    device: vk::Device,
+    image: vk::Image,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements,
+) {
+    dispatch::<GetImageSparseMemoryRequirementsFn, _>(
+        "vkGetImageSparseMemoryRequirements",
+        &[device.as_raw(), image.as_raw()],
+        |f| {
+            f(
+                device,
+                image,
+                p_sparse_memory_requirement_count,
+                p_sparse_memory_requirements,
+            )
+        },
+        |_| {
+            if !p_sparse_memory_requirement_count.is_null() {
+                ptr::write_bytes(p_sparse_memory_requirement_count, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageSparseMemoryRequirements`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSparseMemoryRequirements.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_sparse_memory_requirements(
+        &self,
+        f: impl FnMut(vk::Device, vk::Image, *mut u32, *mut vk::SparseImageMemoryRequirements) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageSparseMemoryRequirementsFn>(
+            "vkGetImageSparseMemoryRequirements",
+            Box::new(f),
+        )
+    }
+}
+type GetImageSparseMemoryRequirements2Fn = dyn FnMut(
+    vk::Device,
+    *const vk::ImageSparseMemoryRequirementsInfo2<'_>,
+    *mut u32,
+    *mut vk::SparseImageMemoryRequirements2<'_>,
+);
+unsafe extern "system" fn get_image_sparse_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::ImageSparseMemoryRequirementsInfo2<'_>,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements2<'_>,
+) {
+    dispatch::<GetImageSparseMemoryRequirements2Fn, _>(
+        "vkGetImageSparseMemoryRequirements2",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                p_info,
+                p_sparse_memory_requirement_count,
+                p_sparse_memory_requirements,
+            )
+        },
+        |_| {
+            if !p_sparse_memory_requirement_count.is_null() {
+                ptr::write_bytes(p_sparse_memory_requirement_count, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageSparseMemoryRequirements2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSparseMemoryRequirements2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_sparse_memory_requirements2(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::ImageSparseMemoryRequirementsInfo2<'_>,
+                *mut u32,
+                *mut vk::SparseImageMemoryRequirements2<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageSparseMemoryRequirements2Fn>(
+            "vkGetImageSparseMemoryRequirements2",
+            Box::new(f),
+        )
+    }
+}
+type GetImageSubresourceLayoutFn =
+    dyn FnMut(vk::Device, vk::Image, *const vk::ImageSubresource, *mut vk::SubresourceLayout);
+unsafe extern "system" fn get_image_subresource_layout(
+    device: vk::Device,
+    image: vk::Image,
+    p_subresource: *const vk::ImageSubresource,
+    p_layout: *mut vk::SubresourceLayout,
+) {
+    dispatch::<GetImageSubresourceLayoutFn, _>(
+        "vkGetImageSubresourceLayout",
+        &[device.as_raw(), image.as_raw()],
+        |f| f(device, image, p_subresource, p_layout),
+        |_| {
+            if !p_layout.is_null() {
+                ptr::write_bytes(p_layout, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageSubresourceLayout`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_subresource_layout(
+        &self,
+        f: impl FnMut(vk::Device, vk::Image, *const vk::ImageSubresource, *mut vk::SubresourceLayout)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageSubresourceLayoutFn>("vkGetImageSubresourceLayout", Box::new(f))
+    }
+}
+type GetImageSubresourceLayout2EXTFn = dyn FnMut(
+    vk::Device,
+    vk::Image,
+    *const vk::ImageSubresource2EXT<'_>,
+    *mut vk::SubresourceLayout2EXT<'_>,
+);
+unsafe extern "system" fn get_image_subresource_layout2_ext(
+    device: vk::Device,
+    image: vk::Image,
+    p_subresource: *const vk::ImageSubresource2EXT<'_>,
+    p_layout: *mut vk::SubresourceLayout2EXT<'_>,
+) {
+    dispatch::<GetImageSubresourceLayout2EXTFn, _>(
+        "vkGetImageSubresourceLayout2EXT",
+        &[device.as_raw(), image.as_raw()],
+        |f| f(device, image, p_subresource, p_layout),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageSubresourceLayout2EXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageSubresourceLayout2EXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_subresource_layout2_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::Image,
+                *const vk::ImageSubresource2EXT<'_>,
+                *mut vk::SubresourceLayout2EXT<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageSubresourceLayout2EXTFn>(
+            "vkGetImageSubresourceLayout2EXT",
+            Box::new(f),
+        )
+    }
+}
+type GetImageViewAddressNVXFn =
+    dyn FnMut(vk::Device, vk::ImageView, *mut vk::ImageViewAddressPropertiesNVX<'_>) -> vk::Result;
+unsafe extern "system" fn get_image_view_address_nvx(
+    device: vk::Device,
+    image_view: vk::ImageView,
+    p_properties: *mut vk::ImageViewAddressPropertiesNVX<'_>,
+) -> vk::Result {
+    dispatch::<GetImageViewAddressNVXFn, _>(
+        "vkGetImageViewAddressNVX",
+        &[device.as_raw(), image_view.as_raw()],
+        |f| f(device, image_view, p_properties),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageViewAddressNVX`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewAddressNVX.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_view_address_nvx(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::ImageView,
+                *mut vk::ImageViewAddressPropertiesNVX<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageViewAddressNVXFn>("vkGetImageViewAddressNVX", Box::new(f))
+    }
+}
+type GetImageViewHandleNVXFn = dyn FnMut(vk::Device, *const vk::ImageViewHandleInfoNVX<'_>) -> u32;
+unsafe extern "system" fn get_image_view_handle_nvx(
+    device: vk::Device,
+    p_info: *const vk::ImageViewHandleInfoNVX<'_>,
+) -> u32 {
+    dispatch::<GetImageViewHandleNVXFn, _>(
+        "vkGetImageViewHandleNVX",
+        &[device.as_raw()],
+        |f| f(device, p_info),
+        |_| Default::default(),
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageViewHandleNVX`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewHandleNVX.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_view_handle_nvx(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::ImageViewHandleInfoNVX<'_>) -> u32 + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageViewHandleNVXFn>("vkGetImageViewHandleNVX", Box::new(f))
+    }
+}
+type GetImageViewOpaqueCaptureDescriptorDataEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::ImageViewCaptureDescriptorDataInfoEXT<'_>,
+    *mut c_void,
+) -> vk::Result;
+unsafe extern "system" fn get_image_view_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::ImageViewCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetImageViewOpaqueCaptureDescriptorDataEXTFn, _>(
+        "vkGetImageViewOpaqueCaptureDescriptorDataEXT",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_data),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetImageViewOpaqueCaptureDescriptorDataEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetImageViewOpaqueCaptureDescriptorDataEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_image_view_opaque_capture_descriptor_data_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::ImageViewCaptureDescriptorDataInfoEXT<'_>,
+                *mut c_void,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetImageViewOpaqueCaptureDescriptorDataEXTFn>(
+            "vkGetImageViewOpaqueCaptureDescriptorDataEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetMemoryAndroidHardwareBufferANDROIDFn = dyn FnMut(
+    vk::Device,
+    *const vk::MemoryGetAndroidHardwareBufferInfoANDROID<'_>,
+    *mut *mut vk::AHardwareBuffer,
+) -> vk::Result;
+unsafe extern "system" fn get_memory_android_hardware_buffer_android(
+    device: vk::Device,
+    p_info: *const vk::MemoryGetAndroidHardwareBufferInfoANDROID<'_>,
+    p_buffer: *mut *mut vk::AHardwareBuffer,
+) -> vk::Result {
+    dispatch::<GetMemoryAndroidHardwareBufferANDROIDFn, _>(
+        "vkGetMemoryAndroidHardwareBufferANDROID",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_buffer),
+        |_| {
+            if !p_buffer.is_null() {
+                *p_buffer = ptr::null_mut();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMemoryAndroidHardwareBufferANDROID`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryAndroidHardwareBufferANDROID.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_memory_android_hardware_buffer_android(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::MemoryGetAndroidHardwareBufferInfoANDROID<'_>,
+                *mut *mut vk::AHardwareBuffer,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMemoryAndroidHardwareBufferANDROIDFn>(
+            "vkGetMemoryAndroidHardwareBufferANDROID",
+            Box::new(f),
+        )
+    }
+}
+type GetMemoryFdKHRFn =
+    dyn FnMut(vk::Device, *const vk::MemoryGetFdInfoKHR<'_>, *mut c_int) -> vk::Result;
+unsafe extern "system" fn get_memory_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::MemoryGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    dispatch::<GetMemoryFdKHRFn, _>(
+        "vkGetMemoryFdKHR",
+        &[device.as_raw()],
+        |f| f(device, p_get_fd_info, p_fd),
+        |_| {
+            if !p_fd.is_null() {
+                ptr::write_bytes(p_fd, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMemoryFdKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryFdKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_memory_fd_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::MemoryGetFdInfoKHR<'_>, *mut c_int) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMemoryFdKHRFn>("vkGetMemoryFdKHR", Box::new(f))
+    }
+}
+type GetMemoryFdPropertiesKHRFn = dyn FnMut(
+    vk::Device,
+    vk::ExternalMemoryHandleTypeFlags,
+    c_int,
+    *mut vk::MemoryFdPropertiesKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_memory_fd_properties_khr(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    fd: c_int,
+    p_memory_fd_properties: *mut vk::MemoryFdPropertiesKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetMemoryFdPropertiesKHRFn, _>(
+        "vkGetMemoryFdPropertiesKHR",
+        &[device.as_raw()],
+        |f| f(device, handle_type, fd, p_memory_fd_properties),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMemoryFdPropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryFdPropertiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_memory_fd_properties_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::ExternalMemoryHandleTypeFlags,
+                c_int,
+                *mut vk::MemoryFdPropertiesKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMemoryFdPropertiesKHRFn>("vkGetMemoryFdPropertiesKHR", Box::new(f))
+    }
+}
+type GetMemoryHostPointerPropertiesEXTFn = dyn FnMut(
+    vk::Device,
+    vk::ExternalMemoryHandleTypeFlags,
+    *const c_void,
+    *mut vk::MemoryHostPointerPropertiesEXT<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_memory_host_pointer_properties_ext(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    p_host_pointer: *const c_void,
+    p_memory_host_pointer_properties: *mut vk::MemoryHostPointerPropertiesEXT<'_>,
+) -> vk::Result {
+    dispatch::<GetMemoryHostPointerPropertiesEXTFn, _>(
+        "vkGetMemoryHostPointerPropertiesEXT",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                handle_type,
+                p_host_pointer,
+                p_memory_host_pointer_properties,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMemoryHostPointerPropertiesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryHostPointerPropertiesEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_memory_host_pointer_properties_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::ExternalMemoryHandleTypeFlags,
+                *const c_void,
+                *mut vk::MemoryHostPointerPropertiesEXT<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMemoryHostPointerPropertiesEXTFn>(
+            "vkGetMemoryHostPointerPropertiesEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetMemoryRemoteAddressNVFn = dyn FnMut(
+    vk::Device,
+    *const vk::MemoryGetRemoteAddressInfoNV<'_>,
+    *mut vk::RemoteAddressNV,
+) -> vk::Result;
+unsafe extern "system" fn get_memory_remote_address_nv(
+    device: vk::Device,
+    p_memory_get_remote_address_info: *const vk::MemoryGetRemoteAddressInfoNV<'_>,
+    p_address: *mut vk::RemoteAddressNV,
+) -> vk::Result {
+    dispatch::<GetMemoryRemoteAddressNVFn, _>(
+        "vkGetMemoryRemoteAddressNV",
+        &[device.as_raw()],
+        |f| f(device, p_memory_get_remote_address_info, p_address),
+        |_| {
+            if !p_address.is_null() {
+                ptr::write_bytes(p_address, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMemoryRemoteAddressNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryRemoteAddressNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_memory_remote_address_nv(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::MemoryGetRemoteAddressInfoNV<'_>,
+                *mut vk::RemoteAddressNV,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMemoryRemoteAddressNVFn>("vkGetMemoryRemoteAddressNV", Box::new(f))
+    }
+}
+type GetMemoryWin32HandleKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::MemoryGetWin32HandleInfoKHR<'_>,
+    *mut vk::HANDLE,
+) -> vk::Result;
+unsafe extern "system" fn get_memory_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::MemoryGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    dispatch::<GetMemoryWin32HandleKHRFn, _>(
+        "vkGetMemoryWin32HandleKHR",
+        &[device.as_raw()],
+        |f| f(device, p_get_win32_handle_info, p_handle),
+        |_| {
+            if !p_handle.is_null() {
+                ptr::write_bytes(p_handle, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMemoryWin32HandleKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandleKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_memory_win32_handle_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::MemoryGetWin32HandleInfoKHR<'_>,
+                *mut vk::HANDLE,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMemoryWin32HandleKHRFn>("vkGetMemoryWin32HandleKHR", Box::new(f))
+    }
+}
+type GetMemoryWin32HandleNVFn = dyn FnMut(
+    vk::Device,
+    vk::DeviceMemory,
+    vk::ExternalMemoryHandleTypeFlagsNV,
+    *mut vk::HANDLE,
+) -> vk::Result;
+unsafe extern "system" fn get_memory_win32_handle_nv(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    dispatch::<GetMemoryWin32HandleNVFn, _>(
+        "vkGetMemoryWin32HandleNV",
+        &[device.as_raw(), memory.as_raw()],
+        |f| f(device, memory, handle_type, p_handle),
+        |_| {
+            if !p_handle.is_null() {
+                ptr::write_bytes(p_handle, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMemoryWin32HandleNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandleNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_memory_win32_handle_nv(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeviceMemory,
+                vk::ExternalMemoryHandleTypeFlagsNV,
+                *mut vk::HANDLE,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMemoryWin32HandleNVFn>("vkGetMemoryWin32HandleNV", Box::new(f))
+    }
+}
+type GetMemoryWin32HandlePropertiesKHRFn = dyn FnMut(
+    vk::Device,
+    vk::ExternalMemoryHandleTypeFlags,
+    vk::HANDLE,
+    *mut vk::MemoryWin32HandlePropertiesKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_memory_win32_handle_properties_khr(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    handle: vk::HANDLE,
+    p_memory_win32_handle_properties: *mut vk::MemoryWin32HandlePropertiesKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetMemoryWin32HandlePropertiesKHRFn, _>(
+        "vkGetMemoryWin32HandlePropertiesKHR",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                handle_type,
+                handle,
+                p_memory_win32_handle_properties,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMemoryWin32HandlePropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryWin32HandlePropertiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_memory_win32_handle_properties_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::ExternalMemoryHandleTypeFlags,
+                vk::HANDLE,
+                *mut vk::MemoryWin32HandlePropertiesKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMemoryWin32HandlePropertiesKHRFn>(
+            "vkGetMemoryWin32HandlePropertiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetMemoryZirconHandleFUCHSIAFn = dyn FnMut(
+    vk::Device,
+    *const vk::MemoryGetZirconHandleInfoFUCHSIA<'_>,
+    *mut vk::zx_handle_t,
+) -> vk::Result;
+unsafe extern "system" fn get_memory_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_get_zircon_handle_info: *const vk::MemoryGetZirconHandleInfoFUCHSIA<'_>,
+    p_zircon_handle: *mut vk::zx_handle_t,
+) -> vk::Result {
+    dispatch::<GetMemoryZirconHandleFUCHSIAFn, _>(
+        "vkGetMemoryZirconHandleFUCHSIA",
+        &[device.as_raw()],
+        |f| f(device, p_get_zircon_handle_info, p_zircon_handle),
+        |_| {
+            if !p_zircon_handle.is_null() {
+                ptr::write_bytes(p_zircon_handle, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMemoryZirconHandleFUCHSIA`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryZirconHandleFUCHSIA.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_memory_zircon_handle_fuchsia(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::MemoryGetZirconHandleInfoFUCHSIA<'_>,
+                *mut vk::zx_handle_t,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMemoryZirconHandleFUCHSIAFn>(
+            "vkGetMemoryZirconHandleFUCHSIA",
+            Box::new(f),
+        )
+    }
+}
+type GetMemoryZirconHandlePropertiesFUCHSIAFn = dyn FnMut(
+    vk::Device,
+    vk::ExternalMemoryHandleTypeFlags,
+    vk::zx_handle_t,
+    *mut vk::MemoryZirconHandlePropertiesFUCHSIA<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_memory_zircon_handle_properties_fuchsia(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    zircon_handle: vk::zx_handle_t,
+    p_memory_zircon_handle_properties: *mut vk::MemoryZirconHandlePropertiesFUCHSIA<'_>,
+) -> vk::Result {
+    dispatch::<GetMemoryZirconHandlePropertiesFUCHSIAFn, _>(
+        "vkGetMemoryZirconHandlePropertiesFUCHSIA",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                handle_type,
+                zircon_handle,
+                p_memory_zircon_handle_properties,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMemoryZirconHandlePropertiesFUCHSIA`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMemoryZirconHandlePropertiesFUCHSIA.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_memory_zircon_handle_properties_fuchsia(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::ExternalMemoryHandleTypeFlags,
+                vk::zx_handle_t,
+                *mut vk::MemoryZirconHandlePropertiesFUCHSIA<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMemoryZirconHandlePropertiesFUCHSIAFn>(
+            "vkGetMemoryZirconHandlePropertiesFUCHSIA",
+            Box::new(f),
+        )
+    }
+}
+type GetMicromapBuildSizesEXTFn = dyn FnMut(
+    vk::Device,
+    vk::AccelerationStructureBuildTypeKHR,
+    *const vk::MicromapBuildInfoEXT<'_>,
+    *mut vk::MicromapBuildSizesInfoEXT<'_>,
+);
+unsafe extern "system" fn get_micromap_build_sizes_ext(
+    device: vk::Device,
+    build_type: vk::AccelerationStructureBuildTypeKHR,
+    p_build_info: *const vk::MicromapBuildInfoEXT<'_>,
+    p_size_info: *mut vk::MicromapBuildSizesInfoEXT<'_>,
+) {
+    dispatch::<GetMicromapBuildSizesEXTFn, _>(
+        "vkGetMicromapBuildSizesEXT",
+        &[device.as_raw()],
+        |f| f(device, build_type, p_build_info, p_size_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetMicromapBuildSizesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetMicromapBuildSizesEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_micromap_build_sizes_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::AccelerationStructureBuildTypeKHR,
+                *const vk::MicromapBuildInfoEXT<'_>,
+                *mut vk::MicromapBuildSizesInfoEXT<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetMicromapBuildSizesEXTFn>("vkGetMicromapBuildSizesEXT", Box::new(f))
+    }
+}
+type GetPastPresentationTimingGOOGLEFn = dyn FnMut(
+    vk::Device,
+    vk::SwapchainKHR,
+    *mut u32,
+    *mut vk::PastPresentationTimingGOOGLE,
+) -> vk::Result;
+unsafe extern "system" fn get_past_presentation_timing_google(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_presentation_timing_count: *mut u32,
+    p_presentation_timings: *mut vk::PastPresentationTimingGOOGLE,
+) -> vk::Result {
+    dispatch::<GetPastPresentationTimingGOOGLEFn, _>(
+        "vkGetPastPresentationTimingGOOGLE",
+        &[device.as_raw(), swapchain.as_raw()],
+        |f| {
+            f(
+                device,
+                swapchain,
+                p_presentation_timing_count,
+                p_presentation_timings,
+            )
+        },
+        |_| {
+            if !p_presentation_timing_count.is_null() {
+                ptr::write_bytes(p_presentation_timing_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPastPresentationTimingGOOGLE`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPastPresentationTimingGOOGLE.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_past_presentation_timing_google(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::SwapchainKHR,
+                *mut u32,
+                *mut vk::PastPresentationTimingGOOGLE,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPastPresentationTimingGOOGLEFn>(
+            "vkGetPastPresentationTimingGOOGLE",
+            Box::new(f),
+        )
+    }
+}
+type GetPerformanceParameterINTELFn = dyn FnMut(
+    vk::Device,
+    vk::PerformanceParameterTypeINTEL,
+    *mut vk::PerformanceValueINTEL,
+) -> vk::Result;
+unsafe extern "system" fn get_performance_parameter_intel(
+    device: vk::Device,
+    parameter: vk::PerformanceParameterTypeINTEL,
+    p_value: *mut vk::PerformanceValueINTEL,
+) -> vk::Result {
+    dispatch::<GetPerformanceParameterINTELFn, _>(
+        "vkGetPerformanceParameterINTEL",
+        &[device.as_raw()],
+        |f| f(device, parameter, p_value),
+        |_| {
+            if !p_value.is_null() {
+                ptr::write_bytes(p_value, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPerformanceParameterINTEL`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPerformanceParameterINTEL.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_performance_parameter_intel(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::PerformanceParameterTypeINTEL,
+                *mut vk::PerformanceValueINTEL,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPerformanceParameterINTELFn>(
+            "vkGetPerformanceParameterINTEL",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceCalibrateableTimeDomainsEXTFn =
+    dyn FnMut(vk::PhysicalDevice, *mut u32, *mut vk::TimeDomainEXT) -> vk::Result;
+unsafe extern "system" fn get_physical_device_calibrateable_time_domains_ext(
+    physical_device: vk::PhysicalDevice,
+    p_time_domain_count: *mut u32,
+    p_time_domains: *mut vk::TimeDomainEXT,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceCalibrateableTimeDomainsEXTFn, _>(
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_time_domain_count, p_time_domains),
+        |_| {
+            if !p_time_domain_count.is_null() {
+                ptr::write_bytes(p_time_domain_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceCalibrateableTimeDomainsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceCalibrateableTimeDomainsEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_calibrateable_time_domains_ext(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut u32, *mut vk::TimeDomainEXT) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceCalibrateableTimeDomainsEXTFn>(
+            "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceCooperativeMatrixPropertiesNVFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *mut u32,
+    *mut vk::CooperativeMatrixPropertiesNV<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_cooperative_matrix_properties_nv(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::CooperativeMatrixPropertiesNV<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceCooperativeMatrixPropertiesNVFn, _>(
+        "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_property_count, p_properties),
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceCooperativeMatrixPropertiesNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceCooperativeMatrixPropertiesNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_cooperative_matrix_properties_nv(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *mut u32,
+                *mut vk::CooperativeMatrixPropertiesNV<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceCooperativeMatrixPropertiesNVFn>(
+            "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceDirectFBPresentationSupportEXTFn =
+    dyn FnMut(vk::PhysicalDevice, u32, *mut vk::IDirectFB) -> vk::Bool32;
+unsafe extern "system" fn get_physical_device_direct_fb_presentation_support_ext(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    dfb: *mut vk::IDirectFB,
+) -> vk::Bool32 {
+    dispatch::<GetPhysicalDeviceDirectFBPresentationSupportEXTFn, _>(
+        "vkGetPhysicalDeviceDirectFBPresentationSupportEXT",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, queue_family_index, dfb),
+        |_| {
+            if !dfb.is_null() {
+                ptr::write_bytes(dfb, 0, 1);
+            }
+            Default::default()
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceDirectFBPresentationSupportEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDirectFBPresentationSupportEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_direct_fb_presentation_support_ext(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, u32, *mut vk::IDirectFB) -> vk::Bool32 + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceDirectFBPresentationSupportEXTFn>(
+            "vkGetPhysicalDeviceDirectFBPresentationSupportEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceDisplayPlaneProperties2KHRFn =
+    dyn FnMut(vk::PhysicalDevice, *mut u32, *mut vk::DisplayPlaneProperties2KHR<'_>) -> vk::Result;
+unsafe extern "system" fn get_physical_device_display_plane_properties2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayPlaneProperties2KHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceDisplayPlaneProperties2KHRFn, _>(
+        "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_property_count, p_properties),
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceDisplayPlaneProperties2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayPlaneProperties2KHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_display_plane_properties2_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *mut u32,
+                *mut vk::DisplayPlaneProperties2KHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceDisplayPlaneProperties2KHRFn>(
+            "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceDisplayPlanePropertiesKHRFn =
+    dyn FnMut(vk::PhysicalDevice, *mut u32, *mut vk::DisplayPlanePropertiesKHR) -> vk::Result;
+unsafe extern "system" fn get_physical_device_display_plane_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayPlanePropertiesKHR,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceDisplayPlanePropertiesKHRFn, _>(
+        "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_property_count, p_properties),
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceDisplayPlanePropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayPlanePropertiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_display_plane_properties_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut u32, *mut vk::DisplayPlanePropertiesKHR) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceDisplayPlanePropertiesKHRFn>(
+            "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceDisplayProperties2KHRFn =
+    dyn FnMut(vk::PhysicalDevice, *mut u32, *mut vk::DisplayProperties2KHR<'_>) -> vk::Result;
+unsafe extern "system" fn get_physical_device_display_properties2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayProperties2KHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceDisplayProperties2KHRFn, _>(
+        "vkGetPhysicalDeviceDisplayProperties2KHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_property_count, p_properties),
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceDisplayProperties2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayProperties2KHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_display_properties2_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut u32, *mut vk::DisplayProperties2KHR<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceDisplayProperties2KHRFn>(
+            "vkGetPhysicalDeviceDisplayProperties2KHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceDisplayPropertiesKHRFn =
+    dyn FnMut(vk::PhysicalDevice, *mut u32, *mut vk::DisplayPropertiesKHR<'_>) -> vk::Result;
+unsafe extern "system" fn get_physical_device_display_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayPropertiesKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceDisplayPropertiesKHRFn, _>(
+        "vkGetPhysicalDeviceDisplayPropertiesKHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_property_count, p_properties),
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceDisplayPropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayPropertiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_display_properties_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut u32, *mut vk::DisplayPropertiesKHR<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceDisplayPropertiesKHRFn>(
+            "vkGetPhysicalDeviceDisplayPropertiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceExternalBufferPropertiesFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::PhysicalDeviceExternalBufferInfo<'_>,
+    *mut vk::ExternalBufferProperties<'_>,
+);
+unsafe extern "system" fn get_physical_device_external_buffer_properties(
+    physical_device: vk::PhysicalDevice,
+    p_external_buffer_info: *const vk::PhysicalDeviceExternalBufferInfo<'_>,
+    p_external_buffer_properties: *mut vk::ExternalBufferProperties<'_>,
+) {
+    dispatch::<GetPhysicalDeviceExternalBufferPropertiesFn, _>(
+        "vkGetPhysicalDeviceExternalBufferProperties",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_external_buffer_info,
+                p_external_buffer_properties,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceExternalBufferProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalBufferProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_external_buffer_properties(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::PhysicalDeviceExternalBufferInfo<'_>,
+                *mut vk::ExternalBufferProperties<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceExternalBufferPropertiesFn>(
+            "vkGetPhysicalDeviceExternalBufferProperties",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceExternalFencePropertiesFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::PhysicalDeviceExternalFenceInfo<'_>,
+    *mut vk::ExternalFenceProperties<'_>,
+);
+unsafe extern "system" fn get_physical_device_external_fence_properties(
+    physical_device: vk::PhysicalDevice,
+    p_external_fence_info: *const vk::PhysicalDeviceExternalFenceInfo<'_>,
+    p_external_fence_properties: *mut vk::ExternalFenceProperties<'_>,
+) {
+    dispatch::<GetPhysicalDeviceExternalFencePropertiesFn, _>(
+        "vkGetPhysicalDeviceExternalFenceProperties",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_external_fence_info,
+                p_external_fence_properties,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceExternalFenceProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalFenceProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_external_fence_properties(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::PhysicalDeviceExternalFenceInfo<'_>,
+                *mut vk::ExternalFenceProperties<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceExternalFencePropertiesFn>(
+            "vkGetPhysicalDeviceExternalFenceProperties",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceExternalImageFormatPropertiesNVFn = dyn FnMut(
+    vk::PhysicalDevice,
+    vk::Format,
+    vk::ImageType,
+    vk::ImageTiling,
+    vk::ImageUsageFlags,
+    vk::ImageCreateFlags,
+    vk::ExternalMemoryHandleTypeFlagsNV,
+    *mut vk::ExternalImageFormatPropertiesNV,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_external_image_format_properties_nv(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    ty: vk::ImageType,
+    tiling: vk::ImageTiling,
+    usage: vk::ImageUsageFlags,
+    flags: vk::ImageCreateFlags,
+    external_handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+    p_external_image_format_properties: *mut vk::ExternalImageFormatPropertiesNV,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceExternalImageFormatPropertiesNVFn, _>(
+        "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                format,
+                ty,
+                tiling,
+                usage,
+                flags,
+                external_handle_type,
+                p_external_image_format_properties,
+            )
+        },
+        |_| {
+            if !p_external_image_format_properties.is_null() {
+                ptr::write_bytes(p_external_image_format_properties, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceExternalImageFormatPropertiesNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalImageFormatPropertiesNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_external_image_format_properties_nv(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                vk::Format,
+                vk::ImageType,
+                vk::ImageTiling,
+                vk::ImageUsageFlags,
+                vk::ImageCreateFlags,
+                vk::ExternalMemoryHandleTypeFlagsNV,
+                *mut vk::ExternalImageFormatPropertiesNV,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceExternalImageFormatPropertiesNVFn>(
+            "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceExternalSemaphorePropertiesFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::PhysicalDeviceExternalSemaphoreInfo<'_>,
+    *mut vk::ExternalSemaphoreProperties<'_>,
+);
+unsafe extern "system" fn get_physical_device_external_semaphore_properties(
+    physical_device: vk::PhysicalDevice,
+    p_external_semaphore_info: *const vk::PhysicalDeviceExternalSemaphoreInfo<'_>,
+    p_external_semaphore_properties: *mut vk::ExternalSemaphoreProperties<'_>,
+) {
+    dispatch::<GetPhysicalDeviceExternalSemaphorePropertiesFn, _>(
+        "vkGetPhysicalDeviceExternalSemaphoreProperties",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_external_semaphore_info,
+                p_external_semaphore_properties,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceExternalSemaphoreProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceExternalSemaphoreProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_external_semaphore_properties(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::PhysicalDeviceExternalSemaphoreInfo<'_>,
+                *mut vk::ExternalSemaphoreProperties<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceExternalSemaphorePropertiesFn>(
+            "vkGetPhysicalDeviceExternalSemaphoreProperties",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceFeaturesFn = dyn FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceFeatures);
+unsafe extern "system" fn get_physical_device_features(
+    physical_device: vk::PhysicalDevice,
+    p_features: *mut vk::PhysicalDeviceFeatures,
+) {
+    dispatch::<GetPhysicalDeviceFeaturesFn, _>(
+        "vkGetPhysicalDeviceFeatures",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_features),
+        |_| {
+            if !p_features.is_null() {
+                ptr::write_bytes(p_features, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceFeatures`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_features(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceFeatures) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceFeaturesFn>("vkGetPhysicalDeviceFeatures", Box::new(f))
+    }
+}
+type GetPhysicalDeviceFeatures2Fn =
+    dyn FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceFeatures2<'_>);
+unsafe extern "system" fn get_physical_device_features2(
+    physical_device: vk::PhysicalDevice,
+    p_features: *mut vk::PhysicalDeviceFeatures2<'_>,
+) {
+    dispatch::<GetPhysicalDeviceFeatures2Fn, _>(
+        "vkGetPhysicalDeviceFeatures2",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_features),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceFeatures2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_features2(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceFeatures2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceFeatures2Fn>(
+            "vkGetPhysicalDeviceFeatures2",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceFormatPropertiesFn =
+    dyn FnMut(vk::PhysicalDevice, vk::Format, *mut vk::FormatProperties);
+unsafe extern "system" fn get_physical_device_format_properties(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    p_format_properties: *mut vk::FormatProperties,
+) {
+    dispatch::<GetPhysicalDeviceFormatPropertiesFn, _>(
+        "vkGetPhysicalDeviceFormatProperties",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, format, p_format_properties),
+        |_| {
+            if !p_format_properties.is_null() {
+                ptr::write_bytes(p_format_properties, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceFormatProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_format_properties(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, vk::Format, *mut vk::FormatProperties) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceFormatPropertiesFn>(
+            "vkGetPhysicalDeviceFormatProperties",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceFormatProperties2Fn =
+    dyn FnMut(vk::PhysicalDevice, vk::Format, *mut vk::FormatProperties2<'_>);
+unsafe extern "system" fn get_physical_device_format_properties2(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    p_format_properties: *mut vk::FormatProperties2<'_>,
+) {
+    dispatch::<GetPhysicalDeviceFormatProperties2Fn, _>(
+        "vkGetPhysicalDeviceFormatProperties2",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, format, p_format_properties),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceFormatProperties2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFormatProperties2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_format_properties2(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, vk::Format, *mut vk::FormatProperties2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceFormatProperties2Fn>(
+            "vkGetPhysicalDeviceFormatProperties2",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceFragmentShadingRatesKHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *mut u32,
+    *mut vk::PhysicalDeviceFragmentShadingRateKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_fragment_shading_rates_khr(
+    physical_device: vk::PhysicalDevice,
+    p_fragment_shading_rate_count: *mut u32,
+    p_fragment_shading_rates: *mut vk::PhysicalDeviceFragmentShadingRateKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceFragmentShadingRatesKHRFn, _>(
+        "vkGetPhysicalDeviceFragmentShadingRatesKHR",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_fragment_shading_rate_count,
+                p_fragment_shading_rates,
+            )
+        },
+        |_| {
+            if !p_fragment_shading_rate_count.is_null() {
+                ptr::write_bytes(p_fragment_shading_rate_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceFragmentShadingRatesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFragmentShadingRatesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_fragment_shading_rates_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *mut u32,
+                *mut vk::PhysicalDeviceFragmentShadingRateKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceFragmentShadingRatesKHRFn>(
+            "vkGetPhysicalDeviceFragmentShadingRatesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceImageFormatPropertiesFn = dyn FnMut(
+    vk::PhysicalDevice,
+    vk::Format,
+    vk::ImageType,
+    vk::ImageTiling,
+    vk::ImageUsageFlags,
+    vk::ImageCreateFlags,
+    *mut vk::ImageFormatProperties,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_image_format_properties(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    ty: vk::ImageType,
+    tiling: vk::ImageTiling,
+    usage: vk::ImageUsageFlags,
+    flags: vk::ImageCreateFlags,
+    p_image_format_properties: *mut vk::ImageFormatProperties,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceImageFormatPropertiesFn, _>(
+        "vkGetPhysicalDeviceImageFormatProperties",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                format,
+                ty,
+                tiling,
+                usage,
+                flags,
+                p_image_format_properties,
+            )
+        },
+        |_| {
+            if !p_image_format_properties.is_null() {
+                ptr::write_bytes(p_image_format_properties, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceImageFormatProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_image_format_properties(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                vk::Format,
+                vk::ImageType,
+                vk::ImageTiling,
+                vk::ImageUsageFlags,
+                vk::ImageCreateFlags,
+                *mut vk::ImageFormatProperties,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceImageFormatPropertiesFn>(
+            "vkGetPhysicalDeviceImageFormatProperties",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceImageFormatProperties2Fn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::PhysicalDeviceImageFormatInfo2<'_>,
+    *mut vk::ImageFormatProperties2<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_image_format_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_image_format_info: *const vk::PhysicalDeviceImageFormatInfo2<'_>,
+    p_image_format_properties: *mut vk::ImageFormatProperties2<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceImageFormatProperties2Fn, _>(
+        "vkGetPhysicalDeviceImageFormatProperties2",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_image_format_info,
+                p_image_format_properties,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceImageFormatProperties2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceImageFormatProperties2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_image_format_properties2(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::PhysicalDeviceImageFormatInfo2<'_>,
+                *mut vk::ImageFormatProperties2<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceImageFormatProperties2Fn>(
+            "vkGetPhysicalDeviceImageFormatProperties2",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceMemoryPropertiesFn =
+    dyn FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceMemoryProperties);
+unsafe extern "system" fn get_physical_device_memory_properties(
+    physical_device: vk::PhysicalDevice,
+    p_memory_properties: *mut vk::PhysicalDeviceMemoryProperties,
+) {
+    dispatch::<GetPhysicalDeviceMemoryPropertiesFn, _>(
+        "vkGetPhysicalDeviceMemoryProperties",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_memory_properties),
+        |_| {
+            if !p_memory_properties.is_null() {
+                ptr::write_bytes(p_memory_properties, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceMemoryProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_memory_properties(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceMemoryProperties) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceMemoryPropertiesFn>(
+            "vkGetPhysicalDeviceMemoryProperties",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceMemoryProperties2Fn =
+    dyn FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceMemoryProperties2<'_>);
+unsafe extern "system" fn get_physical_device_memory_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_memory_properties: *mut vk::PhysicalDeviceMemoryProperties2<'_>,
+) {
+    dispatch::<GetPhysicalDeviceMemoryProperties2Fn, _>(
+        "vkGetPhysicalDeviceMemoryProperties2",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_memory_properties),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceMemoryProperties2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_memory_properties2(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceMemoryProperties2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceMemoryProperties2Fn>(
+            "vkGetPhysicalDeviceMemoryProperties2",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceMultisamplePropertiesEXTFn =
+    dyn FnMut(vk::PhysicalDevice, vk::SampleCountFlags, *mut vk::MultisamplePropertiesEXT<'_>);
+unsafe extern "system" fn get_physical_device_multisample_properties_ext(
+    physical_device: vk::PhysicalDevice,
+    samples: vk::SampleCountFlags,
+    p_multisample_properties: *mut vk::MultisamplePropertiesEXT<'_>,
+) {
+    dispatch::<GetPhysicalDeviceMultisamplePropertiesEXTFn, _>(
+        "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, samples, p_multisample_properties),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceMultisamplePropertiesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMultisamplePropertiesEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_multisample_properties_ext(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, vk::SampleCountFlags, *mut vk::MultisamplePropertiesEXT<'_>)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceMultisamplePropertiesEXTFn>(
+            "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceOpticalFlowImageFormatsNVFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::OpticalFlowImageFormatInfoNV<'_>,
+    *mut u32,
+    *mut vk::OpticalFlowImageFormatPropertiesNV<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_optical_flow_image_formats_nv(
+    physical_device: vk::PhysicalDevice,
+    p_optical_flow_image_format_info: *const vk::OpticalFlowImageFormatInfoNV<'_>,
+    p_format_count: *mut u32,
+    p_image_format_properties: *mut vk::OpticalFlowImageFormatPropertiesNV<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceOpticalFlowImageFormatsNVFn, _>(
+        "vkGetPhysicalDeviceOpticalFlowImageFormatsNV",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_optical_flow_image_format_info,
+                p_format_count,
+                p_image_format_properties,
+            )
+        },
+        |_| {
+            if !p_format_count.is_null() {
+                ptr::write_bytes(p_format_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceOpticalFlowImageFormatsNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceOpticalFlowImageFormatsNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_optical_flow_image_formats_nv(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::OpticalFlowImageFormatInfoNV<'_>,
+                *mut u32,
+                *mut vk::OpticalFlowImageFormatPropertiesNV<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceOpticalFlowImageFormatsNVFn>(
+            "vkGetPhysicalDeviceOpticalFlowImageFormatsNV",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDevicePresentRectanglesKHRFn =
+    dyn FnMut(vk::PhysicalDevice, vk::SurfaceKHR, *mut u32, *mut vk::Rect2D) -> vk::Result;
+unsafe extern "system" fn get_physical_device_present_rectangles_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_rect_count: *mut u32,
+    p_rects: *mut vk::Rect2D,
+) -> vk::Result {
+    dispatch::<GetPhysicalDevicePresentRectanglesKHRFn, _>(
+        "vkGetPhysicalDevicePresentRectanglesKHR",
+        &[physical_device.as_raw(), surface.as_raw()],
+        |f| f(physical_device, surface, p_rect_count, p_rects),
+        |_| {
+            if !p_rect_count.is_null() {
+                ptr::write_bytes(p_rect_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDevicePresentRectanglesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDevicePresentRectanglesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_present_rectangles_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, vk::SurfaceKHR, *mut u32, *mut vk::Rect2D) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDevicePresentRectanglesKHRFn>(
+            "vkGetPhysicalDevicePresentRectanglesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDevicePropertiesFn =
+    dyn FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceProperties);
+unsafe extern "system" fn get_physical_device_properties(
+    physical_device: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceProperties,
+) {
+    dispatch::<GetPhysicalDevicePropertiesFn, _>(
+        "vkGetPhysicalDeviceProperties",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_properties),
+        |_| {
+            if !p_properties.is_null() {
+                ptr::write_bytes(p_properties, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_properties(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceProperties) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDevicePropertiesFn>(
+            "vkGetPhysicalDeviceProperties",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceProperties2Fn =
+    dyn FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceProperties2<'_>);
+unsafe extern "system" fn get_physical_device_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceProperties2<'_>,
+) {
+    dispatch::<GetPhysicalDeviceProperties2Fn, _>(
+        "vkGetPhysicalDeviceProperties2",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_properties),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceProperties2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_properties2(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut vk::PhysicalDeviceProperties2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceProperties2Fn>(
+            "vkGetPhysicalDeviceProperties2",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHRFn =
+    dyn FnMut(vk::PhysicalDevice, *const vk::QueryPoolPerformanceCreateInfoKHR<'_>, *mut u32);
+unsafe extern "system" fn get_physical_device_queue_family_performance_query_passes_khr(
+    physical_device: vk::PhysicalDevice,
+    p_performance_query_create_info: *const vk::QueryPoolPerformanceCreateInfoKHR<'_>,
+    p_num_passes: *mut u32,
+) {
+    dispatch::<GetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHRFn, _>(
+        "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_performance_query_create_info,
+                p_num_passes,
+            )
+        },
+        |_| {
+            if !p_num_passes.is_null() {
+                ptr::write_bytes(p_num_passes, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_queue_family_performance_query_passes_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *const vk::QueryPoolPerformanceCreateInfoKHR<'_>, *mut u32)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHRFn>(
+            "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceQueueFamilyPropertiesFn =
+    dyn FnMut(vk::PhysicalDevice, *mut u32, *mut vk::QueueFamilyProperties);
+unsafe extern "system" fn get_physical_device_queue_family_properties(
+    physical_device: vk::PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut vk::QueueFamilyProperties,
+) {
+    dispatch::<GetPhysicalDeviceQueueFamilyPropertiesFn, _>(
+        "vkGetPhysicalDeviceQueueFamilyProperties",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_queue_family_property_count,
+                p_queue_family_properties,
+            )
+        },
+        |_| {
+            if !p_queue_family_property_count.is_null() {
+                ptr::write_bytes(p_queue_family_property_count, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceQueueFamilyProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_queue_family_properties(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut u32, *mut vk::QueueFamilyProperties) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceQueueFamilyPropertiesFn>(
+            "vkGetPhysicalDeviceQueueFamilyProperties",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceQueueFamilyProperties2Fn =
+    dyn FnMut(vk::PhysicalDevice, *mut u32, *mut vk::QueueFamilyProperties2<'_>);
+unsafe extern "system" fn get_physical_device_queue_family_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut vk::QueueFamilyProperties2<'_>,
+) {
+    dispatch::<GetPhysicalDeviceQueueFamilyProperties2Fn, _>(
+        "vkGetPhysicalDeviceQueueFamilyProperties2",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_queue_family_property_count,
+                p_queue_family_properties,
+            )
+        },
+        |_| {
+            if !p_queue_family_property_count.is_null() {
+                ptr::write_bytes(p_queue_family_property_count, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceQueueFamilyProperties2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_queue_family_properties2(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, *mut u32, *mut vk::QueueFamilyProperties2<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceQueueFamilyProperties2Fn>(
+            "vkGetPhysicalDeviceQueueFamilyProperties2",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceScreenPresentationSupportQNXFn =
+    dyn FnMut(vk::PhysicalDevice, u32, *mut vk::_screen_window) -> vk::Bool32;
+unsafe extern "system" fn get_physical_device_screen_presentation_support_qnx(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    window: *mut vk::_screen_window,
+) -> vk::Bool32 {
+    dispatch::<GetPhysicalDeviceScreenPresentationSupportQNXFn, _>(
+        "vkGetPhysicalDeviceScreenPresentationSupportQNX",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, queue_family_index, window),
+        |_| {
+            if !window.is_null() {
+                ptr::write_bytes(window, 0, 1);
+            }
+            Default::default()
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceScreenPresentationSupportQNX`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceScreenPresentationSupportQNX.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_screen_presentation_support_qnx(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, u32, *mut vk::_screen_window) -> vk::Bool32 + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceScreenPresentationSupportQNXFn>(
+            "vkGetPhysicalDeviceScreenPresentationSupportQNX",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSparseImageFormatPropertiesFn = dyn FnMut(
+    vk::PhysicalDevice,
+    vk::Format,
+    vk::ImageType,
+    vk::SampleCountFlags,
+    vk::ImageUsageFlags,
+    vk::ImageTiling,
+    *mut u32,
+    *mut vk::SparseImageFormatProperties,
+);
+unsafe extern "system" fn get_physical_device_sparse_image_format_properties(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    ty: vk::ImageType,
+    samples: vk::SampleCountFlags,
+    usage: vk::ImageUsageFlags,
+    tiling: vk::ImageTiling,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::SparseImageFormatProperties,
+) {
+    dispatch::<GetPhysicalDeviceSparseImageFormatPropertiesFn, _>(
+        "vkGetPhysicalDeviceSparseImageFormatProperties",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                format,
+                ty,
+                samples,
+                usage,
+                tiling,
+                p_property_count,
+                p_properties,
+            )
+        },
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSparseImageFormatProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSparseImageFormatProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_sparse_image_format_properties(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                vk::Format,
+                vk::ImageType,
+                vk::SampleCountFlags,
+                vk::ImageUsageFlags,
+                vk::ImageTiling,
+                *mut u32,
+                *mut vk::SparseImageFormatProperties,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSparseImageFormatPropertiesFn>(
+            "vkGetPhysicalDeviceSparseImageFormatProperties",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSparseImageFormatProperties2Fn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::PhysicalDeviceSparseImageFormatInfo2<'_>,
+    *mut u32,
+    *mut vk::SparseImageFormatProperties2<'_>,
+);
+unsafe extern "system" fn get_physical_device_sparse_image_format_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_format_info: *const vk::PhysicalDeviceSparseImageFormatInfo2<'_>,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::SparseImageFormatProperties2<'_>,
+) {
+    dispatch::<GetPhysicalDeviceSparseImageFormatProperties2Fn, _>(
+        "vkGetPhysicalDeviceSparseImageFormatProperties2",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_format_info,
+                p_property_count,
+                p_properties,
+            )
+        },
+        |_| {
+            if !p_property_count.is_null() {
+                ptr::write_bytes(p_property_count, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSparseImageFormatProperties2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSparseImageFormatProperties2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_sparse_image_format_properties2(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::PhysicalDeviceSparseImageFormatInfo2<'_>,
+                *mut u32,
+                *mut vk::SparseImageFormatProperties2<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSparseImageFormatProperties2Fn>(
+            "vkGetPhysicalDeviceSparseImageFormatProperties2",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNVFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *mut u32,
+    *mut vk::FramebufferMixedSamplesCombinationNV<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_supported_framebuffer_mixed_samples_combinations_nv(
+    physical_device: vk::PhysicalDevice,
+    p_combination_count: *mut u32,
+    p_combinations: *mut vk::FramebufferMixedSamplesCombinationNV<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNVFn, _>(
+        "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_combination_count, p_combinations),
+        |_| {
+            if !p_combination_count.is_null() {
+                ptr::write_bytes(p_combination_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_supported_framebuffer_mixed_samples_combinations_nv(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *mut u32,
+                *mut vk::FramebufferMixedSamplesCombinationNV<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNVFn>(
+            "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSurfaceCapabilities2EXTFn = dyn FnMut(
+    vk::PhysicalDevice,
+    vk::SurfaceKHR,
+    *mut vk::SurfaceCapabilities2EXT<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_surface_capabilities2_ext(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_surface_capabilities: *mut vk::SurfaceCapabilities2EXT<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceSurfaceCapabilities2EXTFn, _>(
+        "vkGetPhysicalDeviceSurfaceCapabilities2EXT",
+        &[physical_device.as_raw(), surface.as_raw()],
+        |f| f(physical_device, surface, p_surface_capabilities),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSurfaceCapabilities2EXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceCapabilities2EXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_surface_capabilities2_ext(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                vk::SurfaceKHR,
+                *mut vk::SurfaceCapabilities2EXT<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSurfaceCapabilities2EXTFn>(
+            "vkGetPhysicalDeviceSurfaceCapabilities2EXT",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSurfaceCapabilities2KHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    *mut vk::SurfaceCapabilities2KHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_surface_capabilities2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_surface_capabilities: *mut vk::SurfaceCapabilities2KHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceSurfaceCapabilities2KHRFn, _>(
+        "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_surface_info, p_surface_capabilities),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSurfaceCapabilities2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceCapabilities2KHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_surface_capabilities2_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+                *mut vk::SurfaceCapabilities2KHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSurfaceCapabilities2KHRFn>(
+            "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSurfaceCapabilitiesKHRFn =
+    dyn FnMut(vk::PhysicalDevice, vk::SurfaceKHR, *mut vk::SurfaceCapabilitiesKHR) -> vk::Result;
+unsafe extern "system" fn get_physical_device_surface_capabilities_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_surface_capabilities: *mut vk::SurfaceCapabilitiesKHR,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceSurfaceCapabilitiesKHRFn, _>(
+        "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+        &[physical_device.as_raw(), surface.as_raw()],
+        |f| f(physical_device, surface, p_surface_capabilities),
+        |_| {
+            if !p_surface_capabilities.is_null() {
+                ptr::write_bytes(p_surface_capabilities, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSurfaceCapabilitiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceCapabilitiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_surface_capabilities_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, vk::SurfaceKHR, *mut vk::SurfaceCapabilitiesKHR) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSurfaceCapabilitiesKHRFn>(
+            "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSurfaceFormats2KHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    *mut u32,
+    *mut vk::SurfaceFormat2KHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_surface_formats2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut vk::SurfaceFormat2KHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceSurfaceFormats2KHRFn, _>(
+        "vkGetPhysicalDeviceSurfaceFormats2KHR",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_surface_info,
+                p_surface_format_count,
+                p_surface_formats,
+            )
+        },
+        |_| {
+            if !p_surface_format_count.is_null() {
+                ptr::write_bytes(p_surface_format_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSurfaceFormats2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceFormats2KHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_surface_formats2_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+                *mut u32,
+                *mut vk::SurfaceFormat2KHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSurfaceFormats2KHRFn>(
+            "vkGetPhysicalDeviceSurfaceFormats2KHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSurfaceFormatsKHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    vk::SurfaceKHR,
+    *mut u32,
+    *mut vk::SurfaceFormatKHR,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_surface_formats_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut vk::SurfaceFormatKHR,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceSurfaceFormatsKHRFn, _>(
+        "vkGetPhysicalDeviceSurfaceFormatsKHR",
+        &[physical_device.as_raw(), surface.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                surface,
+                p_surface_format_count,
+                p_surface_formats,
+            )
+        },
+        |_| {
+            if !p_surface_format_count.is_null() {
+                ptr::write_bytes(p_surface_format_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSurfaceFormatsKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceFormatsKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_surface_formats_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                vk::SurfaceKHR,
+                *mut u32,
+                *mut vk::SurfaceFormatKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSurfaceFormatsKHRFn>(
+            "vkGetPhysicalDeviceSurfaceFormatsKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSurfacePresentModes2EXTFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    *mut u32,
+    *mut vk::PresentModeKHR,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_surface_present_modes2_ext(
+    physical_device: vk::PhysicalDevice,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_present_mode_count: *mut u32,
+    p_present_modes: *mut vk::PresentModeKHR,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceSurfacePresentModes2EXTFn, _>(
+        "vkGetPhysicalDeviceSurfacePresentModes2EXT",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_surface_info,
+                p_present_mode_count,
+                p_present_modes,
+            )
+        },
+        |_| {
+            if !p_present_mode_count.is_null() {
+                ptr::write_bytes(p_present_mode_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSurfacePresentModes2EXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfacePresentModes2EXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_surface_present_modes2_ext(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+                *mut u32,
+                *mut vk::PresentModeKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSurfacePresentModes2EXTFn>(
+            "vkGetPhysicalDeviceSurfacePresentModes2EXT",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSurfacePresentModesKHRFn =
+    dyn FnMut(vk::PhysicalDevice, vk::SurfaceKHR, *mut u32, *mut vk::PresentModeKHR) -> vk::Result;
+unsafe extern "system" fn get_physical_device_surface_present_modes_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_present_mode_count: *mut u32,
+    p_present_modes: *mut vk::PresentModeKHR,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceSurfacePresentModesKHRFn, _>(
+        "vkGetPhysicalDeviceSurfacePresentModesKHR",
+        &[physical_device.as_raw(), surface.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                surface,
+                p_present_mode_count,
+                p_present_modes,
+            )
+        },
+        |_| {
+            if !p_present_mode_count.is_null() {
+                ptr::write_bytes(p_present_mode_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSurfacePresentModesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfacePresentModesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_surface_present_modes_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                vk::SurfaceKHR,
+                *mut u32,
+                *mut vk::PresentModeKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSurfacePresentModesKHRFn>(
+            "vkGetPhysicalDeviceSurfacePresentModesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceSurfaceSupportKHRFn =
+    dyn FnMut(vk::PhysicalDevice, u32, vk::SurfaceKHR, *mut vk::Bool32) -> vk::Result;
+unsafe extern "system" fn get_physical_device_surface_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    surface: vk::SurfaceKHR,
+    p_supported: *mut vk::Bool32,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceSurfaceSupportKHRFn, _>(
+        "vkGetPhysicalDeviceSurfaceSupportKHR",
+        &[physical_device.as_raw(), surface.as_raw()],
+        |f| f(physical_device, queue_family_index, surface, p_supported),
+        |_| {
+            if !p_supported.is_null() {
+                ptr::write_bytes(p_supported, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceSurfaceSupportKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceSupportKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_surface_support_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, u32, vk::SurfaceKHR, *mut vk::Bool32) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceSurfaceSupportKHRFn>(
+            "vkGetPhysicalDeviceSurfaceSupportKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceToolPropertiesFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *mut u32,
+    *mut vk::PhysicalDeviceToolProperties<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_tool_properties(
+    physical_device: vk::PhysicalDevice,
+    p_tool_count: *mut u32,
+    p_tool_properties: *mut vk::PhysicalDeviceToolProperties<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceToolPropertiesFn, _>(
+        "vkGetPhysicalDeviceToolProperties",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_tool_count, p_tool_properties),
+        |_| {
+            if !p_tool_count.is_null() {
+                ptr::write_bytes(p_tool_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceToolProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceToolProperties.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_tool_properties(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *mut u32,
+                *mut vk::PhysicalDeviceToolProperties<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceToolPropertiesFn>(
+            "vkGetPhysicalDeviceToolProperties",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceVideoCapabilitiesKHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::VideoProfileInfoKHR<'_>,
+    *mut vk::VideoCapabilitiesKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_video_capabilities_khr(
+    physical_device: vk::PhysicalDevice,
+    p_video_profile: *const vk::VideoProfileInfoKHR<'_>,
+    p_capabilities: *mut vk::VideoCapabilitiesKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceVideoCapabilitiesKHRFn, _>(
+        "vkGetPhysicalDeviceVideoCapabilitiesKHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, p_video_profile, p_capabilities),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceVideoCapabilitiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoCapabilitiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_video_capabilities_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::VideoProfileInfoKHR<'_>,
+                *mut vk::VideoCapabilitiesKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceVideoCapabilitiesKHRFn>(
+            "vkGetPhysicalDeviceVideoCapabilitiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::PhysicalDeviceVideoEncodeQualityLevelInfoKHR<'_>,
+    *mut vk::VideoEncodeQualityLevelPropertiesKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_video_encode_quality_level_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_quality_level_info: *const vk::PhysicalDeviceVideoEncodeQualityLevelInfoKHR<'_>,
+    p_quality_level_properties: *mut vk::VideoEncodeQualityLevelPropertiesKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHRFn, _>(
+        "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_quality_level_info,
+                p_quality_level_properties,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_video_encode_quality_level_properties_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::PhysicalDeviceVideoEncodeQualityLevelInfoKHR<'_>,
+                *mut vk::VideoEncodeQualityLevelPropertiesKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHRFn>(
+            "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceVideoFormatPropertiesKHRFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *const vk::PhysicalDeviceVideoFormatInfoKHR<'_>,
+    *mut u32,
+    *mut vk::VideoFormatPropertiesKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_physical_device_video_format_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_video_format_info: *const vk::PhysicalDeviceVideoFormatInfoKHR<'_>,
+    p_video_format_property_count: *mut u32,
+    p_video_format_properties: *mut vk::VideoFormatPropertiesKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPhysicalDeviceVideoFormatPropertiesKHRFn, _>(
+        "vkGetPhysicalDeviceVideoFormatPropertiesKHR",
+        &[physical_device.as_raw()],
+        |f| {
+            f(
+                physical_device,
+                p_video_format_info,
+                p_video_format_property_count,
+                p_video_format_properties,
+            )
+        },
+        |_| {
+            if !p_video_format_property_count.is_null() {
+                ptr::write_bytes(p_video_format_property_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceVideoFormatPropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoFormatPropertiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_video_format_properties_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *const vk::PhysicalDeviceVideoFormatInfoKHR<'_>,
+                *mut u32,
+                *mut vk::VideoFormatPropertiesKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceVideoFormatPropertiesKHRFn>(
+            "vkGetPhysicalDeviceVideoFormatPropertiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceWaylandPresentationSupportKHRFn =
+    dyn FnMut(vk::PhysicalDevice, u32, *mut vk::wl_display) -> vk::Bool32;
+unsafe extern "system" fn get_physical_device_wayland_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    display: *mut vk::wl_display,
+) -> vk::Bool32 {
+    dispatch::<GetPhysicalDeviceWaylandPresentationSupportKHRFn, _>(
+        "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, queue_family_index, display),
+        |_| {
+            if !display.is_null() {
+                ptr::write_bytes(display, 0, 1);
+            }
+            Default::default()
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceWaylandPresentationSupportKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceWaylandPresentationSupportKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_wayland_presentation_support_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, u32, *mut vk::wl_display) -> vk::Bool32 + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceWaylandPresentationSupportKHRFn>(
+            "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceWin32PresentationSupportKHRFn =
+    dyn FnMut(vk::PhysicalDevice, u32) -> vk::Bool32;
+unsafe extern "system" fn get_physical_device_win32_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+) -> vk::Bool32 {
+    dispatch::<GetPhysicalDeviceWin32PresentationSupportKHRFn, _>(
+        "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, queue_family_index),
+        |_| Default::default(),
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceWin32PresentationSupportKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceWin32PresentationSupportKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_win32_presentation_support_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, u32) -> vk::Bool32 + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceWin32PresentationSupportKHRFn>(
+            "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceXcbPresentationSupportKHRFn =
+    dyn FnMut(vk::PhysicalDevice, u32, *mut vk::xcb_connection_t, vk::xcb_visualid_t) -> vk::Bool32;
+unsafe extern "system" fn get_physical_device_xcb_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    connection: *mut vk::xcb_connection_t,
+    visual_id: vk::xcb_visualid_t,
+) -> vk::Bool32 {
+    dispatch::<GetPhysicalDeviceXcbPresentationSupportKHRFn, _>(
+        "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, queue_family_index, connection, visual_id),
+        |_| {
+            if !connection.is_null() {
+                ptr::write_bytes(connection, 0, 1);
+            }
+            Default::default()
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceXcbPresentationSupportKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceXcbPresentationSupportKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_xcb_presentation_support_khr(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                u32,
+                *mut vk::xcb_connection_t,
+                vk::xcb_visualid_t,
+            ) -> vk::Bool32
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceXcbPresentationSupportKHRFn>(
+            "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPhysicalDeviceXlibPresentationSupportKHRFn =
+    dyn FnMut(vk::PhysicalDevice, u32, *mut vk::Display, vk::VisualID) -> vk::Bool32;
+unsafe extern "system" fn get_physical_device_xlib_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    dpy: *mut vk::Display,
+    visual_id: vk::VisualID,
+) -> vk::Bool32 {
+    dispatch::<GetPhysicalDeviceXlibPresentationSupportKHRFn, _>(
+        "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, queue_family_index, dpy, visual_id),
+        |_| {
+            if !dpy.is_null() {
+                ptr::write_bytes(dpy, 0, 1);
+            }
+            Default::default()
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPhysicalDeviceXlibPresentationSupportKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceXlibPresentationSupportKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_physical_device_xlib_presentation_support_khr(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, u32, *mut vk::Display, vk::VisualID) -> vk::Bool32 + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPhysicalDeviceXlibPresentationSupportKHRFn>(
+            "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPipelineCacheDataFn =
+    dyn FnMut(vk::Device, vk::PipelineCache, *mut usize, *mut c_void) -> vk::Result;
+unsafe extern "system" fn get_pipeline_cache_data(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetPipelineCacheDataFn, _>(
+        "vkGetPipelineCacheData",
+        &[device.as_raw(), pipeline_cache.as_raw()],
+        |f| f(device, pipeline_cache, p_data_size, p_data),
+        |_| {
+            if !p_data_size.is_null() {
+                ptr::write_bytes(p_data_size, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPipelineCacheData`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineCacheData.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_pipeline_cache_data(
+        &self,
+        f: impl FnMut(vk::Device, vk::PipelineCache, *mut usize, *mut c_void) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPipelineCacheDataFn>("vkGetPipelineCacheData", Box::new(f))
+    }
+}
+type GetPipelineExecutableInternalRepresentationsKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::PipelineExecutableInfoKHR<'_>,
+    *mut u32,
+    *mut vk::PipelineExecutableInternalRepresentationKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_pipeline_executable_internal_representations_khr(
+    device: vk::Device,
+    p_executable_info: *const vk::PipelineExecutableInfoKHR<'_>,
+    p_internal_representation_count: *mut u32,
+    p_internal_representations: *mut vk::PipelineExecutableInternalRepresentationKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPipelineExecutableInternalRepresentationsKHRFn, _>(
+        "vkGetPipelineExecutableInternalRepresentationsKHR",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                p_executable_info,
+                p_internal_representation_count,
+                p_internal_representations,
+            )
+        },
+        |_| {
+            if !p_internal_representation_count.is_null() {
+                ptr::write_bytes(p_internal_representation_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPipelineExecutableInternalRepresentationsKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineExecutableInternalRepresentationsKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_pipeline_executable_internal_representations_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::PipelineExecutableInfoKHR<'_>,
+                *mut u32,
+                *mut vk::PipelineExecutableInternalRepresentationKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPipelineExecutableInternalRepresentationsKHRFn>(
+            "vkGetPipelineExecutableInternalRepresentationsKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPipelineExecutablePropertiesKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::PipelineInfoKHR<'_>,
+    *mut u32,
+    *mut vk::PipelineExecutablePropertiesKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_pipeline_executable_properties_khr(
+    device: vk::Device,
+    p_pipeline_info: *const vk::PipelineInfoKHR<'_>,
+    p_executable_count: *mut u32,
+    p_properties: *mut vk::PipelineExecutablePropertiesKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPipelineExecutablePropertiesKHRFn, _>(
+        "vkGetPipelineExecutablePropertiesKHR",
+        &[device.as_raw()],
+        |f| f(device, p_pipeline_info, p_executable_count, p_properties),
+        |_| {
+            if !p_executable_count.is_null() {
+                ptr::write_bytes(p_executable_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPipelineExecutablePropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineExecutablePropertiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_pipeline_executable_properties_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::PipelineInfoKHR<'_>,
+                *mut u32,
+                *mut vk::PipelineExecutablePropertiesKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPipelineExecutablePropertiesKHRFn>(
+            "vkGetPipelineExecutablePropertiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPipelineExecutableStatisticsKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::PipelineExecutableInfoKHR<'_>,
+    *mut u32,
+    *mut vk::PipelineExecutableStatisticKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_pipeline_executable_statistics_khr(
+    device: vk::Device,
+    p_executable_info: *const vk::PipelineExecutableInfoKHR<'_>,
+    p_statistic_count: *mut u32,
+    p_statistics: *mut vk::PipelineExecutableStatisticKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetPipelineExecutableStatisticsKHRFn, _>(
+        "vkGetPipelineExecutableStatisticsKHR",
+        &[device.as_raw()],
+        |f| f(device, p_executable_info, p_statistic_count, p_statistics),
+        |_| {
+            if !p_statistic_count.is_null() {
+                ptr::write_bytes(p_statistic_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPipelineExecutableStatisticsKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineExecutableStatisticsKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_pipeline_executable_statistics_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::PipelineExecutableInfoKHR<'_>,
+                *mut u32,
+                *mut vk::PipelineExecutableStatisticKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPipelineExecutableStatisticsKHRFn>(
+            "vkGetPipelineExecutableStatisticsKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetPipelinePropertiesEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::PipelineInfoEXT<'_>,
+    *mut vk::BaseOutStructure<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_pipeline_properties_ext(
+    device: vk::Device,
+    p_pipeline_info: *const vk::PipelineInfoEXT<'_>,
+    p_pipeline_properties: *mut vk::BaseOutStructure<'_>,
+) -> vk::Result {
+    dispatch::<GetPipelinePropertiesEXTFn, _>(
+        "vkGetPipelinePropertiesEXT",
+        &[device.as_raw()],
+        |f| f(device, p_pipeline_info, p_pipeline_properties),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPipelinePropertiesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelinePropertiesEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_pipeline_properties_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::PipelineInfoEXT<'_>,
+                *mut vk::BaseOutStructure<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPipelinePropertiesEXTFn>("vkGetPipelinePropertiesEXT", Box::new(f))
+    }
+}
+type GetPrivateDataFn = dyn FnMut(vk::Device, vk::ObjectType, u64, vk::PrivateDataSlot, *mut u64);
+unsafe extern "system" fn get_private_data(
+    device: vk::Device,
+    object_type: vk::ObjectType,
+    object_handle: u64,
+    private_data_slot: vk::PrivateDataSlot,
+    p_data: *mut u64,
+) {
+    dispatch::<GetPrivateDataFn, _>(
+        "vkGetPrivateData",
+        &[device.as_raw(), private_data_slot.as_raw()],
+        |f| {
+            f(
+                device,
+                object_type,
+                object_handle,
+                private_data_slot,
+                p_data,
+            )
+        },
+        |_| {
+            if !p_data.is_null() {
+                ptr::write_bytes(p_data, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetPrivateData`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPrivateData.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_private_data(
+        &self,
+        f: impl FnMut(vk::Device, vk::ObjectType, u64, vk::PrivateDataSlot, *mut u64) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetPrivateDataFn>("vkGetPrivateData", Box::new(f))
+    }
+}
+type GetQueryPoolResultsFn = dyn FnMut(
+    vk::Device,
+    vk::QueryPool,
+    u32,
+    u32,
+    usize,
+    *mut c_void,
+    vk::DeviceSize,
+    vk::QueryResultFlags,
+) -> vk::Result;
+unsafe extern "system" fn get_query_pool_results(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: vk::DeviceSize,
+    flags: vk::QueryResultFlags,
+) -> vk::Result {
+    dispatch::<GetQueryPoolResultsFn, _>(
+        "vkGetQueryPoolResults",
+        &[device.as_raw(), query_pool.as_raw()],
+        |f| {
+            f(
+                device,
+                query_pool,
+                first_query,
+                query_count,
+                data_size,
+                p_data,
+                stride,
+                flags,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetQueryPoolResults`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetQueryPoolResults.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_query_pool_results(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::QueryPool,
+                u32,
+                u32,
+                usize,
+                *mut c_void,
+                vk::DeviceSize,
+                vk::QueryResultFlags,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetQueryPoolResultsFn>("vkGetQueryPoolResults", Box::new(f))
+    }
+}
+type GetQueueCheckpointData2NVFn = dyn FnMut(vk::Queue, *mut u32, *mut vk::CheckpointData2NV<'_>);
+unsafe extern "system" fn get_queue_checkpoint_data2_nv(
+    queue: vk::Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut vk::CheckpointData2NV<'_>,
+) {
+    dispatch::<GetQueueCheckpointData2NVFn, _>(
+        "vkGetQueueCheckpointData2NV",
+        &[queue.as_raw()],
+        |f| f(queue, p_checkpoint_data_count, p_checkpoint_data),
+        |_| {
+            if !p_checkpoint_data_count.is_null() {
+                ptr::write_bytes(p_checkpoint_data_count, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetQueueCheckpointData2NV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetQueueCheckpointData2NV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_queue_checkpoint_data2_nv(
+        &self,
+        f: impl FnMut(vk::Queue, *mut u32, *mut vk::CheckpointData2NV<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetQueueCheckpointData2NVFn>("vkGetQueueCheckpointData2NV", Box::new(f))
+    }
+}
+type GetQueueCheckpointDataNVFn = dyn FnMut(vk::Queue, *mut u32, *mut vk::CheckpointDataNV<'_>);
+unsafe extern "system" fn get_queue_checkpoint_data_nv(
+    queue: vk::Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut vk::CheckpointDataNV<'_>,
+) {
+    dispatch::<GetQueueCheckpointDataNVFn, _>(
+        "vkGetQueueCheckpointDataNV",
+        &[queue.as_raw()],
+        |f| f(queue, p_checkpoint_data_count, p_checkpoint_data),
+        |_| {
+            if !p_checkpoint_data_count.is_null() {
+                ptr::write_bytes(p_checkpoint_data_count, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetQueueCheckpointDataNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetQueueCheckpointDataNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_queue_checkpoint_data_nv(
+        &self,
+        f: impl FnMut(vk::Queue, *mut u32, *mut vk::CheckpointDataNV<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetQueueCheckpointDataNVFn>("vkGetQueueCheckpointDataNV", Box::new(f))
+    }
+}
+type GetRandROutputDisplayEXTFn = dyn FnMut(
+    vk::PhysicalDevice,
+    *mut vk::Display,
+    vk::RROutput,
+    *mut vk::DisplayKHR,
+) -> vk::Result;
+unsafe extern "system" fn get_rand_r_output_display_ext(
+    physical_device: vk::PhysicalDevice,
+    dpy: *mut vk::Display,
+    rr_output: vk::RROutput,
+    p_display: *mut vk::DisplayKHR,
+) -> vk::Result {
+    dispatch::<GetRandROutputDisplayEXTFn, _>(
+        "vkGetRandROutputDisplayEXT",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, dpy, rr_output, p_display),
+        |driver| {
+            if !dpy.is_null() {
+                ptr::write_bytes(dpy, 0, 1);
+            }
+            if !p_display.is_null() {
+                *p_display = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetRandROutputDisplayEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRandROutputDisplayEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_rand_r_output_display_ext(
+        &self,
+        f: impl FnMut(
+                vk::PhysicalDevice,
+                *mut vk::Display,
+                vk::RROutput,
+                *mut vk::DisplayKHR,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetRandROutputDisplayEXTFn>("vkGetRandROutputDisplayEXT", Box::new(f))
+    }
+}
+type GetRayTracingCaptureReplayShaderGroupHandlesKHRFn =
+    dyn FnMut(vk::Device, vk::Pipeline, u32, u32, usize, *mut c_void) -> vk::Result;
+unsafe extern "system" fn get_ray_tracing_capture_replay_shader_group_handles_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetRayTracingCaptureReplayShaderGroupHandlesKHRFn, _>(
+        "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+        &[device.as_raw(), pipeline.as_raw()],
+        |f| {
+            f(
+                device,
+                pipeline,
+                first_group,
+                group_count,
+                data_size,
+                p_data,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetRayTracingCaptureReplayShaderGroupHandlesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRayTracingCaptureReplayShaderGroupHandlesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_ray_tracing_capture_replay_shader_group_handles_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::Pipeline, u32, u32, usize, *mut c_void) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetRayTracingCaptureReplayShaderGroupHandlesKHRFn>(
+            "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetRayTracingShaderGroupHandlesKHRFn =
+    dyn FnMut(vk::Device, vk::Pipeline, u32, u32, usize, *mut c_void) -> vk::Result;
+unsafe extern "system" fn get_ray_tracing_shader_group_handles_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetRayTracingShaderGroupHandlesKHRFn, _>(
+        "vkGetRayTracingShaderGroupHandlesKHR",
+        &[device.as_raw(), pipeline.as_raw()],
+        |f| {
+            f(
+                device,
+                pipeline,
+                first_group,
+                group_count,
+                data_size,
+                p_data,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetRayTracingShaderGroupHandlesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRayTracingShaderGroupHandlesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_ray_tracing_shader_group_handles_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::Pipeline, u32, u32, usize, *mut c_void) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetRayTracingShaderGroupHandlesKHRFn>(
+            "vkGetRayTracingShaderGroupHandlesKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetRayTracingShaderGroupStackSizeKHRFn =
+    dyn FnMut(vk::Device, vk::Pipeline, u32, vk::ShaderGroupShaderKHR) -> vk::DeviceSize;
+unsafe extern "system" fn get_ray_tracing_shader_group_stack_size_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    group: u32,
+    group_shader: vk::ShaderGroupShaderKHR,
+) -> vk::DeviceSize {
+    dispatch::<GetRayTracingShaderGroupStackSizeKHRFn, _>(
+        "vkGetRayTracingShaderGroupStackSizeKHR",
+        &[device.as_raw(), pipeline.as_raw()],
+        |f| f(device, pipeline, group, group_shader),
+        |_| Default::default(),
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetRayTracingShaderGroupStackSizeKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRayTracingShaderGroupStackSizeKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_ray_tracing_shader_group_stack_size_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::Pipeline, u32, vk::ShaderGroupShaderKHR) -> vk::DeviceSize
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetRayTracingShaderGroupStackSizeKHRFn>(
+            "vkGetRayTracingShaderGroupStackSizeKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetRefreshCycleDurationGOOGLEFn =
+    dyn FnMut(vk::Device, vk::SwapchainKHR, *mut vk::RefreshCycleDurationGOOGLE) -> vk::Result;
+unsafe extern "system" fn get_refresh_cycle_duration_google(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_display_timing_properties: *mut vk::RefreshCycleDurationGOOGLE,
+) -> vk::Result {
+    dispatch::<GetRefreshCycleDurationGOOGLEFn, _>(
+        "vkGetRefreshCycleDurationGOOGLE",
+        &[device.as_raw(), swapchain.as_raw()],
+        |f| f(device, swapchain, p_display_timing_properties),
+        |_| {
+            if !p_display_timing_properties.is_null() {
+                ptr::write_bytes(p_display_timing_properties, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetRefreshCycleDurationGOOGLE`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRefreshCycleDurationGOOGLE.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_refresh_cycle_duration_google(
+        &self,
+        f: impl FnMut(vk::Device, vk::SwapchainKHR, *mut vk::RefreshCycleDurationGOOGLE) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetRefreshCycleDurationGOOGLEFn>(
+            "vkGetRefreshCycleDurationGOOGLE",
+            Box::new(f),
+        )
+    }
+}
+type GetRenderAreaGranularityFn = dyn FnMut(vk::Device, vk::RenderPass, *mut vk::Extent2D);
+unsafe extern "system" fn get_render_area_granularity(
+    device: vk::Device,
+    render_pass: vk::RenderPass,
+    p_granularity: *mut vk::Extent2D,
+) {
+    dispatch::<GetRenderAreaGranularityFn, _>(
+        "vkGetRenderAreaGranularity",
+        &[device.as_raw(), render_pass.as_raw()],
+        |f| f(device, render_pass, p_granularity),
+        |_| {
+            if !p_granularity.is_null() {
+                ptr::write_bytes(p_granularity, 0, 1);
+            }
+        },
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetRenderAreaGranularity`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRenderAreaGranularity.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_render_area_granularity(
+        &self,
+        f: impl FnMut(vk::Device, vk::RenderPass, *mut vk::Extent2D) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetRenderAreaGranularityFn>("vkGetRenderAreaGranularity", Box::new(f))
+    }
+}
+type GetSamplerOpaqueCaptureDescriptorDataEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::SamplerCaptureDescriptorDataInfoEXT<'_>,
+    *mut c_void,
+) -> vk::Result;
+unsafe extern "system" fn get_sampler_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::SamplerCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetSamplerOpaqueCaptureDescriptorDataEXTFn, _>(
+        "vkGetSamplerOpaqueCaptureDescriptorDataEXT",
+        &[device.as_raw()],
+        |f| f(device, p_info, p_data),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetSamplerOpaqueCaptureDescriptorDataEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSamplerOpaqueCaptureDescriptorDataEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_sampler_opaque_capture_descriptor_data_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::SamplerCaptureDescriptorDataInfoEXT<'_>,
+                *mut c_void,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetSamplerOpaqueCaptureDescriptorDataEXTFn>(
+            "vkGetSamplerOpaqueCaptureDescriptorDataEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetScreenBufferPropertiesQNXFn = dyn FnMut(
+    vk::Device,
+    *const vk::_screen_buffer,
+    *mut vk::ScreenBufferPropertiesQNX<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_screen_buffer_properties_qnx(
+    device: vk::Device,
+    buffer: *const vk::_screen_buffer,
+    p_properties: *mut vk::ScreenBufferPropertiesQNX<'_>,
+) -> vk::Result {
+    dispatch::<GetScreenBufferPropertiesQNXFn, _>(
+        "vkGetScreenBufferPropertiesQNX",
+        &[device.as_raw()],
+        |f| f(device, buffer, p_properties),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetScreenBufferPropertiesQNX`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetScreenBufferPropertiesQNX.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_screen_buffer_properties_qnx(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::_screen_buffer,
+                *mut vk::ScreenBufferPropertiesQNX<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetScreenBufferPropertiesQNXFn>(
+            "vkGetScreenBufferPropertiesQNX",
+            Box::new(f),
+        )
+    }
+}
+type GetSemaphoreCounterValueFn = dyn FnMut(vk::Device, vk::Semaphore, *mut u64) -> vk::Result;
+unsafe extern "system" fn get_semaphore_counter_value(
+    device: vk::Device,
+    semaphore: vk::Semaphore,
+    p_value: *mut u64,
+) -> vk::Result {
+    dispatch::<GetSemaphoreCounterValueFn, _>(
+        "vkGetSemaphoreCounterValue",
+        &[device.as_raw(), semaphore.as_raw()],
+        |f| f(device, semaphore, p_value),
+        |_| {
+            if !p_value.is_null() {
+                ptr::write_bytes(p_value, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetSemaphoreCounterValue`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreCounterValue.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_semaphore_counter_value(
+        &self,
+        f: impl FnMut(vk::Device, vk::Semaphore, *mut u64) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetSemaphoreCounterValueFn>("vkGetSemaphoreCounterValue", Box::new(f))
+    }
+}
+type GetSemaphoreFdKHRFn =
+    dyn FnMut(vk::Device, *const vk::SemaphoreGetFdInfoKHR<'_>, *mut c_int) -> vk::Result;
+unsafe extern "system" fn get_semaphore_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::SemaphoreGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    dispatch::<GetSemaphoreFdKHRFn, _>(
+        "vkGetSemaphoreFdKHR",
+        &[device.as_raw()],
+        |f| f(device, p_get_fd_info, p_fd),
+        |_| {
+            if !p_fd.is_null() {
+                ptr::write_bytes(p_fd, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetSemaphoreFdKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreFdKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_semaphore_fd_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::SemaphoreGetFdInfoKHR<'_>, *mut c_int) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetSemaphoreFdKHRFn>("vkGetSemaphoreFdKHR", Box::new(f))
+    }
+}
+type GetSemaphoreWin32HandleKHRFn = dyn FnMut(
+    vk::Device,
+    *const vk::SemaphoreGetWin32HandleInfoKHR<'_>,
+    *mut vk::HANDLE,
+) -> vk::Result;
+unsafe extern "system" fn get_semaphore_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::SemaphoreGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    dispatch::<GetSemaphoreWin32HandleKHRFn, _>(
+        "vkGetSemaphoreWin32HandleKHR",
+        &[device.as_raw()],
+        |f| f(device, p_get_win32_handle_info, p_handle),
+        |_| {
+            if !p_handle.is_null() {
+                ptr::write_bytes(p_handle, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetSemaphoreWin32HandleKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreWin32HandleKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_semaphore_win32_handle_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::SemaphoreGetWin32HandleInfoKHR<'_>,
+                *mut vk::HANDLE,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetSemaphoreWin32HandleKHRFn>(
+            "vkGetSemaphoreWin32HandleKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetSemaphoreZirconHandleFUCHSIAFn = dyn FnMut(
+    vk::Device,
+    *const vk::SemaphoreGetZirconHandleInfoFUCHSIA<'_>,
+    *mut vk::zx_handle_t,
+) -> vk::Result;
+unsafe extern "system" fn get_semaphore_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_get_zircon_handle_info: *const vk::SemaphoreGetZirconHandleInfoFUCHSIA<'_>,
+    p_zircon_handle: *mut vk::zx_handle_t,
+) -> vk::Result {
+    dispatch::<GetSemaphoreZirconHandleFUCHSIAFn, _>(
+        "vkGetSemaphoreZirconHandleFUCHSIA",
+        &[device.as_raw()],
+        |f| f(device, p_get_zircon_handle_info, p_zircon_handle),
+        |_| {
+            if !p_zircon_handle.is_null() {
+                ptr::write_bytes(p_zircon_handle, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetSemaphoreZirconHandleFUCHSIA`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreZirconHandleFUCHSIA.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_semaphore_zircon_handle_fuchsia(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::SemaphoreGetZirconHandleInfoFUCHSIA<'_>,
+                *mut vk::zx_handle_t,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetSemaphoreZirconHandleFUCHSIAFn>(
+            "vkGetSemaphoreZirconHandleFUCHSIA",
+            Box::new(f),
+        )
+    }
+}
+type GetShaderBinaryDataEXTFn =
+    dyn FnMut(vk::Device, vk::ShaderEXT, *mut usize, *mut c_void) -> vk::Result;
+unsafe extern "system" fn get_shader_binary_data_ext(
+    device: vk::Device,
+    shader: vk::ShaderEXT,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetShaderBinaryDataEXTFn, _>(
+        "vkGetShaderBinaryDataEXT",
+        &[device.as_raw(), shader.as_raw()],
+        |f| f(device, shader, p_data_size, p_data),
+        |_| {
+            if !p_data_size.is_null() {
+                ptr::write_bytes(p_data_size, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetShaderBinaryDataEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderBinaryDataEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_shader_binary_data_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::ShaderEXT, *mut usize, *mut c_void) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetShaderBinaryDataEXTFn>("vkGetShaderBinaryDataEXT", Box::new(f))
+    }
+}
+type GetShaderInfoAMDFn = dyn FnMut(
+    vk::Device,
+    vk::Pipeline,
+    vk::ShaderStageFlags,
+    vk::ShaderInfoTypeAMD,
+    *mut usize,
+    *mut c_void,
+) -> vk::Result;
+unsafe extern "system" fn get_shader_info_amd(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    shader_stage: vk::ShaderStageFlags,
+    info_type: vk::ShaderInfoTypeAMD,
+    p_info_size: *mut usize,
+    p_info: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetShaderInfoAMDFn, _>(
+        "vkGetShaderInfoAMD",
+        &[device.as_raw(), pipeline.as_raw()],
+        |f| {
+            f(
+                device,
+                pipeline,
+                shader_stage,
+                info_type,
+                p_info_size,
+                p_info,
+            )
+        },
+        |_| {
+            if !p_info_size.is_null() {
+                ptr::write_bytes(p_info_size, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetShaderInfoAMD`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderInfoAMD.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_shader_info_amd(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::Pipeline,
+                vk::ShaderStageFlags,
+                vk::ShaderInfoTypeAMD,
+                *mut usize,
+                *mut c_void,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetShaderInfoAMDFn>("vkGetShaderInfoAMD", Box::new(f))
+    }
+}
+type GetShaderModuleCreateInfoIdentifierEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::ShaderModuleCreateInfo<'_>,
+    *mut vk::ShaderModuleIdentifierEXT<'_>,
+);
+unsafe extern "system" fn get_shader_module_create_info_identifier_ext(
+    device: vk::Device,
+    p_create_info: *const vk::ShaderModuleCreateInfo<'_>,
+    p_identifier: *mut vk::ShaderModuleIdentifierEXT<'_>,
+) {
+    dispatch::<GetShaderModuleCreateInfoIdentifierEXTFn, _>(
+        "vkGetShaderModuleCreateInfoIdentifierEXT",
+        &[device.as_raw()],
+        |f| f(device, p_create_info, p_identifier),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetShaderModuleCreateInfoIdentifierEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderModuleCreateInfoIdentifierEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_shader_module_create_info_identifier_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::ShaderModuleCreateInfo<'_>,
+                *mut vk::ShaderModuleIdentifierEXT<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetShaderModuleCreateInfoIdentifierEXTFn>(
+            "vkGetShaderModuleCreateInfoIdentifierEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetShaderModuleIdentifierEXTFn =
+    dyn FnMut(vk::Device, vk::ShaderModule, *mut vk::ShaderModuleIdentifierEXT<'_>);
+unsafe extern "system" fn get_shader_module_identifier_ext(
+    device: vk::Device,
+    shader_module: vk::ShaderModule,
+    p_identifier: *mut vk::ShaderModuleIdentifierEXT<'_>,
+) {
+    dispatch::<GetShaderModuleIdentifierEXTFn, _>(
+        "vkGetShaderModuleIdentifierEXT",
+        &[device.as_raw(), shader_module.as_raw()],
+        |f| f(device, shader_module, p_identifier),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetShaderModuleIdentifierEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderModuleIdentifierEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_shader_module_identifier_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::ShaderModule, *mut vk::ShaderModuleIdentifierEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<GetShaderModuleIdentifierEXTFn>(
+            "vkGetShaderModuleIdentifierEXT",
+            Box::new(f),
+        )
+    }
+}
+type GetSwapchainCounterEXTFn =
+    dyn FnMut(vk::Device, vk::SwapchainKHR, vk::SurfaceCounterFlagsEXT, *mut u64) -> vk::Result;
+unsafe extern "system" fn get_swapchain_counter_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    counter: vk::SurfaceCounterFlagsEXT,
+    p_counter_value: *mut u64,
+) -> vk::Result {
+    dispatch::<GetSwapchainCounterEXTFn, _>(
+        "vkGetSwapchainCounterEXT",
+        &[device.as_raw(), swapchain.as_raw()],
+        |f| f(device, swapchain, counter, p_counter_value),
+        |_| {
+            if !p_counter_value.is_null() {
+                ptr::write_bytes(p_counter_value, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetSwapchainCounterEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainCounterEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_swapchain_counter_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::SwapchainKHR, vk::SurfaceCounterFlagsEXT, *mut u64) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetSwapchainCounterEXTFn>("vkGetSwapchainCounterEXT", Box::new(f))
+    }
+}
+type GetSwapchainGrallocUsage2ANDROIDFn = dyn FnMut(
+    vk::Device,
+    vk::Format,
+    vk::ImageUsageFlags,
+    vk::SwapchainImageUsageFlagsANDROID,
+    *mut u64,
+    *mut u64,
+) -> vk::Result;
+unsafe extern "system" fn get_swapchain_gralloc_usage2_android(
+    device: vk::Device,
+    format: vk::Format,
+    image_usage: vk::ImageUsageFlags,
+    swapchain_image_usage: vk::SwapchainImageUsageFlagsANDROID,
+    gralloc_consumer_usage: *mut u64,
+    gralloc_producer_usage: *mut u64,
+) -> vk::Result {
+    dispatch::<GetSwapchainGrallocUsage2ANDROIDFn, _>(
+        "vkGetSwapchainGrallocUsage2ANDROID",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                format,
+                image_usage,
+                swapchain_image_usage,
+                gralloc_consumer_usage,
+                gralloc_producer_usage,
+            )
+        },
+        |_| {
+            if !gralloc_consumer_usage.is_null() {
+                ptr::write_bytes(gralloc_consumer_usage, 0, 1);
+            }
+            if !gralloc_producer_usage.is_null() {
+                ptr::write_bytes(gralloc_producer_usage, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetSwapchainGrallocUsage2ANDROID`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainGrallocUsage2ANDROID.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_swapchain_gralloc_usage2_android(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::Format,
+                vk::ImageUsageFlags,
+                vk::SwapchainImageUsageFlagsANDROID,
+                *mut u64,
+                *mut u64,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetSwapchainGrallocUsage2ANDROIDFn>(
+            "vkGetSwapchainGrallocUsage2ANDROID",
+            Box::new(f),
+        )
+    }
+}
+type GetSwapchainGrallocUsageANDROIDFn =
+    dyn FnMut(vk::Device, vk::Format, vk::ImageUsageFlags, *mut c_int) -> vk::Result;
+unsafe extern "system" fn get_swapchain_gralloc_usage_android(
+    device: vk::Device,
+    format: vk::Format,
+    image_usage: vk::ImageUsageFlags,
+    gralloc_usage: *mut c_int,
+) -> vk::Result {
+    dispatch::<GetSwapchainGrallocUsageANDROIDFn, _>(
+        "vkGetSwapchainGrallocUsageANDROID",
+        &[device.as_raw()],
+        |f| f(device, format, image_usage, gralloc_usage),
+        |_| {
+            if !gralloc_usage.is_null() {
+                ptr::write_bytes(gralloc_usage, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetSwapchainGrallocUsageANDROID`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainGrallocUsageANDROID.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_swapchain_gralloc_usage_android(
+        &self,
+        f: impl FnMut(vk::Device, vk::Format, vk::ImageUsageFlags, *mut c_int) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetSwapchainGrallocUsageANDROIDFn>(
+            "vkGetSwapchainGrallocUsageANDROID",
+            Box::new(f),
+        )
+    }
+}
+type GetSwapchainImagesKHRFn =
+    dyn FnMut(vk::Device, vk::SwapchainKHR, *mut u32, *mut vk::Image) -> vk::Result;
+unsafe extern "system" fn get_swapchain_images_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_swapchain_image_count: *mut u32,
+    p_swapchain_images: *mut vk::Image,
+) -> vk::Result {
+    dispatch::<GetSwapchainImagesKHRFn, _>(
+        "vkGetSwapchainImagesKHR",
+        &[device.as_raw(), swapchain.as_raw()],
+        |f| {
+            f(
+                device,
+                swapchain,
+                p_swapchain_image_count,
+                p_swapchain_images,
+            )
+        },
+        |driver| {
+            if !p_swapchain_image_count.is_null() {
+                if p_swapchain_images.is_null() {
+                    *p_swapchain_image_count = 1;
+                } else {
+                    let len = (*p_swapchain_image_count).min(1);
+                    for i in 0..len as usize {
+                        *p_swapchain_images.add(i) = driver.allocate();
+                    }
+                    *p_swapchain_image_count = len;
+                }
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetSwapchainImagesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainImagesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_swapchain_images_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::SwapchainKHR, *mut u32, *mut vk::Image) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetSwapchainImagesKHRFn>("vkGetSwapchainImagesKHR", Box::new(f))
+    }
+}
+type GetSwapchainStatusKHRFn = dyn FnMut(vk::Device, vk::SwapchainKHR) -> vk::Result;
+unsafe extern "system" fn get_swapchain_status_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    dispatch::<GetSwapchainStatusKHRFn, _>(
+        "vkGetSwapchainStatusKHR",
+        &[device.as_raw(), swapchain.as_raw()],
+        |f| f(device, swapchain),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetSwapchainStatusKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainStatusKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_swapchain_status_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::SwapchainKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetSwapchainStatusKHRFn>("vkGetSwapchainStatusKHR", Box::new(f))
+    }
+}
+type GetValidationCacheDataEXTFn =
+    dyn FnMut(vk::Device, vk::ValidationCacheEXT, *mut usize, *mut c_void) -> vk::Result;
+unsafe extern "system" fn get_validation_cache_data_ext(
+    device: vk::Device,
+    validation_cache: vk::ValidationCacheEXT,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    dispatch::<GetValidationCacheDataEXTFn, _>(
+        "vkGetValidationCacheDataEXT",
+        &[device.as_raw(), validation_cache.as_raw()],
+        |f| f(device, validation_cache, p_data_size, p_data),
+        |_| {
+            if !p_data_size.is_null() {
+                ptr::write_bytes(p_data_size, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetValidationCacheDataEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetValidationCacheDataEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_validation_cache_data_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::ValidationCacheEXT, *mut usize, *mut c_void) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetValidationCacheDataEXTFn>("vkGetValidationCacheDataEXT", Box::new(f))
+    }
+}
+type GetVideoSessionMemoryRequirementsKHRFn = dyn FnMut(
+    vk::Device,
+    vk::VideoSessionKHR,
+    *mut u32,
+    *mut vk::VideoSessionMemoryRequirementsKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn get_video_session_memory_requirements_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    p_memory_requirements_count: *mut u32,
+    p_memory_requirements: *mut vk::VideoSessionMemoryRequirementsKHR<'_>,
+) -> vk::Result {
+    dispatch::<GetVideoSessionMemoryRequirementsKHRFn, _>(
+        "vkGetVideoSessionMemoryRequirementsKHR",
+        &[device.as_raw(), video_session.as_raw()],
+        |f| {
+            f(
+                device,
+                video_session,
+                p_memory_requirements_count,
+                p_memory_requirements,
+            )
+        },
+        |_| {
+            if !p_memory_requirements_count.is_null() {
+                ptr::write_bytes(p_memory_requirements_count, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetVideoSessionMemoryRequirementsKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetVideoSessionMemoryRequirementsKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_video_session_memory_requirements_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::VideoSessionKHR,
+                *mut u32,
+                *mut vk::VideoSessionMemoryRequirementsKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<GetVideoSessionMemoryRequirementsKHRFn>(
+            "vkGetVideoSessionMemoryRequirementsKHR",
+            Box::new(f),
+        )
+    }
+}
+type GetWinrtDisplayNVFn = dyn FnMut(vk::PhysicalDevice, u32, *mut vk::DisplayKHR) -> vk::Result;
+unsafe extern "system" fn get_winrt_display_nv(
+    physical_device: vk::PhysicalDevice,
+    device_relative_id: u32,
+    p_display: *mut vk::DisplayKHR,
+) -> vk::Result {
+    dispatch::<GetWinrtDisplayNVFn, _>(
+        "vkGetWinrtDisplayNV",
+        &[physical_device.as_raw()],
+        |f| f(physical_device, device_relative_id, p_display),
+        |driver| {
+            if !p_display.is_null() {
+                *p_display = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkGetWinrtDisplayNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetWinrtDisplayNV.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_get_winrt_display_nv(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, u32, *mut vk::DisplayKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<GetWinrtDisplayNVFn>("vkGetWinrtDisplayNV", Box::new(f))
+    }
+}
+type ImportFenceFdKHRFn = dyn FnMut(vk::Device, *const vk::ImportFenceFdInfoKHR<'_>) -> vk::Result;
+unsafe extern "system" fn import_fence_fd_khr(
+    device: vk::Device,
+    p_import_fence_fd_info: *const vk::ImportFenceFdInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<ImportFenceFdKHRFn, _>(
+        "vkImportFenceFdKHR",
+        &[device.as_raw()],
+        |f| f(device, p_import_fence_fd_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkImportFenceFdKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportFenceFdKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_import_fence_fd_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::ImportFenceFdInfoKHR<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ImportFenceFdKHRFn>("vkImportFenceFdKHR", Box::new(f))
+    }
+}
+type ImportFenceWin32HandleKHRFn =
+    dyn FnMut(vk::Device, *const vk::ImportFenceWin32HandleInfoKHR<'_>) -> vk::Result;
+unsafe extern "system" fn import_fence_win32_handle_khr(
+    device: vk::Device,
+    p_import_fence_win32_handle_info: *const vk::ImportFenceWin32HandleInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<ImportFenceWin32HandleKHRFn, _>(
+        "vkImportFenceWin32HandleKHR",
+        &[device.as_raw()],
+        |f| f(device, p_import_fence_win32_handle_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkImportFenceWin32HandleKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportFenceWin32HandleKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_import_fence_win32_handle_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::ImportFenceWin32HandleInfoKHR<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ImportFenceWin32HandleKHRFn>("vkImportFenceWin32HandleKHR", Box::new(f))
+    }
+}
+type ImportSemaphoreFdKHRFn =
+    dyn FnMut(vk::Device, *const vk::ImportSemaphoreFdInfoKHR<'_>) -> vk::Result;
+unsafe extern "system" fn import_semaphore_fd_khr(
+    device: vk::Device,
+    p_import_semaphore_fd_info: *const vk::ImportSemaphoreFdInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<ImportSemaphoreFdKHRFn, _>(
+        "vkImportSemaphoreFdKHR",
+        &[device.as_raw()],
+        |f| f(device, p_import_semaphore_fd_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkImportSemaphoreFdKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportSemaphoreFdKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_import_semaphore_fd_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::ImportSemaphoreFdInfoKHR<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ImportSemaphoreFdKHRFn>("vkImportSemaphoreFdKHR", Box::new(f))
+    }
+}
+type ImportSemaphoreWin32HandleKHRFn =
+    dyn FnMut(vk::Device, *const vk::ImportSemaphoreWin32HandleInfoKHR<'_>) -> vk::Result;
+unsafe extern "system" fn import_semaphore_win32_handle_khr(
+    device: vk::Device,
+    p_import_semaphore_win32_handle_info: *const vk::ImportSemaphoreWin32HandleInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<ImportSemaphoreWin32HandleKHRFn, _>(
+        "vkImportSemaphoreWin32HandleKHR",
+        &[device.as_raw()],
+        |f| f(device, p_import_semaphore_win32_handle_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkImportSemaphoreWin32HandleKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportSemaphoreWin32HandleKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_import_semaphore_win32_handle_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::ImportSemaphoreWin32HandleInfoKHR<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<ImportSemaphoreWin32HandleKHRFn>(
+            "vkImportSemaphoreWin32HandleKHR",
+            Box::new(f),
+        )
+    }
+}
+type ImportSemaphoreZirconHandleFUCHSIAFn =
+    dyn FnMut(vk::Device, *const vk::ImportSemaphoreZirconHandleInfoFUCHSIA<'_>) -> vk::Result;
+unsafe extern "system" fn import_semaphore_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_import_semaphore_zircon_handle_info: *const vk::ImportSemaphoreZirconHandleInfoFUCHSIA<'_>,
+) -> vk::Result {
+    dispatch::<ImportSemaphoreZirconHandleFUCHSIAFn, _>(
+        "vkImportSemaphoreZirconHandleFUCHSIA",
+        &[device.as_raw()],
+        |f| f(device, p_import_semaphore_zircon_handle_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkImportSemaphoreZirconHandleFUCHSIA`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkImportSemaphoreZirconHandleFUCHSIA.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_import_semaphore_zircon_handle_fuchsia(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::ImportSemaphoreZirconHandleInfoFUCHSIA<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<ImportSemaphoreZirconHandleFUCHSIAFn>(
+            "vkImportSemaphoreZirconHandleFUCHSIA",
+            Box::new(f),
+        )
+    }
+}
+type InitializePerformanceApiINTELFn =
+    dyn FnMut(vk::Device, *const vk::InitializePerformanceApiInfoINTEL<'_>) -> vk::Result;
+unsafe extern "system" fn initialize_performance_api_intel(
+    device: vk::Device,
+    p_initialize_info: *const vk::InitializePerformanceApiInfoINTEL<'_>,
+) -> vk::Result {
+    dispatch::<InitializePerformanceApiINTELFn, _>(
+        "vkInitializePerformanceApiINTEL",
+        &[device.as_raw()],
+        |f| f(device, p_initialize_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkInitializePerformanceApiINTEL`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkInitializePerformanceApiINTEL.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_initialize_performance_api_intel(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::InitializePerformanceApiInfoINTEL<'_>) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<InitializePerformanceApiINTELFn>(
+            "vkInitializePerformanceApiINTEL",
+            Box::new(f),
+        )
+    }
+}
+type InvalidateMappedMemoryRangesFn =
+    dyn FnMut(vk::Device, u32, *const vk::MappedMemoryRange<'_>) -> vk::Result;
+unsafe extern "system" fn invalidate_mapped_memory_ranges(
+    device: vk::Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const vk::MappedMemoryRange<'_>,
+) -> vk::Result {
+    dispatch::<InvalidateMappedMemoryRangesFn, _>(
+        "vkInvalidateMappedMemoryRanges",
+        &[device.as_raw()],
+        |f| f(device, memory_range_count, p_memory_ranges),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkInvalidateMappedMemoryRanges`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkInvalidateMappedMemoryRanges.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_invalidate_mapped_memory_ranges(
+        &self,
+        f: impl FnMut(vk::Device, u32, *const vk::MappedMemoryRange<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<InvalidateMappedMemoryRangesFn>(
+            "vkInvalidateMappedMemoryRanges",
+            Box::new(f),
+        )
+    }
+}
+type MapMemoryFn = dyn FnMut(
+    vk::Device,
+    vk::DeviceMemory,
+    vk::DeviceSize,
+    vk::DeviceSize,
+    vk::MemoryMapFlags,
+    *mut *mut c_void,
+) -> vk::Result;
+unsafe extern "system" fn map_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    flags: vk::MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    dispatch::<MapMemoryFn, _>(
+        "vkMapMemory",
+        &[device.as_raw(), memory.as_raw()],
+        |f| f(device, memory, offset, size, flags, pp_data),
+        |_| {
+            if !pp_data.is_null() {
+                *pp_data = ptr::null_mut();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkMapMemory`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkMapMemory.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_map_memory(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DeviceMemory,
+                vk::DeviceSize,
+                vk::DeviceSize,
+                vk::MemoryMapFlags,
+                *mut *mut c_void,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<MapMemoryFn>("vkMapMemory", Box::new(f))
+    }
+}
+type MapMemory2KHRFn =
+    dyn FnMut(vk::Device, *const vk::MemoryMapInfoKHR<'_>, *mut *mut c_void) -> vk::Result;
+unsafe extern "system" fn map_memory2_khr(
+    device: vk::Device,
+    p_memory_map_info: *const vk::MemoryMapInfoKHR<'_>,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    dispatch::<MapMemory2KHRFn, _>(
+        "vkMapMemory2KHR",
+        &[device.as_raw()],
+        |f| f(device, p_memory_map_info, pp_data),
+        |_| {
+            if !pp_data.is_null() {
+                *pp_data = ptr::null_mut();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkMapMemory2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkMapMemory2KHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_map_memory2_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::MemoryMapInfoKHR<'_>, *mut *mut c_void) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<MapMemory2KHRFn>("vkMapMemory2KHR", Box::new(f))
+    }
+}
+type MergePipelineCachesFn =
+    dyn FnMut(vk::Device, vk::PipelineCache, u32, *const vk::PipelineCache) -> vk::Result;
+unsafe extern "system" fn merge_pipeline_caches(
+    device: vk::Device,
+    dst_cache: vk::PipelineCache,
+    src_cache_count: u32,
+    p_src_caches: *const vk::PipelineCache,
+) -> vk::Result {
+    dispatch::<MergePipelineCachesFn, _>(
+        "vkMergePipelineCaches",
+        &[device.as_raw(), dst_cache.as_raw()],
+        |f| f(device, dst_cache, src_cache_count, p_src_caches),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkMergePipelineCaches`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkMergePipelineCaches.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_merge_pipeline_caches(
+        &self,
+        f: impl FnMut(vk::Device, vk::PipelineCache, u32, *const vk::PipelineCache) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<MergePipelineCachesFn>("vkMergePipelineCaches", Box::new(f))
+    }
+}
+type MergeValidationCachesEXTFn =
+    dyn FnMut(vk::Device, vk::ValidationCacheEXT, u32, *const vk::ValidationCacheEXT) -> vk::Result;
+unsafe extern "system" fn merge_validation_caches_ext(
+    device: vk::Device,
+    dst_cache: vk::ValidationCacheEXT,
+    src_cache_count: u32,
+    p_src_caches: *const vk::ValidationCacheEXT,
+) -> vk::Result {
+    dispatch::<MergeValidationCachesEXTFn, _>(
+        "vkMergeValidationCachesEXT",
+        &[device.as_raw(), dst_cache.as_raw()],
+        |f| f(device, dst_cache, src_cache_count, p_src_caches),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkMergeValidationCachesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkMergeValidationCachesEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_merge_validation_caches_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::ValidationCacheEXT,
+                u32,
+                *const vk::ValidationCacheEXT,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<MergeValidationCachesEXTFn>("vkMergeValidationCachesEXT", Box::new(f))
+    }
+}
+type QueueBeginDebugUtilsLabelEXTFn = dyn FnMut(vk::Queue, *const vk::DebugUtilsLabelEXT<'_>);
+unsafe extern "system" fn queue_begin_debug_utils_label_ext(
+    queue: vk::Queue,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    dispatch::<QueueBeginDebugUtilsLabelEXTFn, _>(
+        "vkQueueBeginDebugUtilsLabelEXT",
+        &[queue.as_raw()],
+        |f| f(queue, p_label_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkQueueBeginDebugUtilsLabelEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueBeginDebugUtilsLabelEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_queue_begin_debug_utils_label_ext(
+        &self,
+        f: impl FnMut(vk::Queue, *const vk::DebugUtilsLabelEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<QueueBeginDebugUtilsLabelEXTFn>(
+            "vkQueueBeginDebugUtilsLabelEXT",
+            Box::new(f),
+        )
+    }
+}
+type QueueBindSparseFn =
+    dyn FnMut(vk::Queue, u32, *const vk::BindSparseInfo<'_>, vk::Fence) -> vk::Result;
+unsafe extern "system" fn queue_bind_sparse(
+    queue: vk::Queue,
+    bind_info_count: u32,
+    p_bind_info: *const vk::BindSparseInfo<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    dispatch::<QueueBindSparseFn, _>(
+        "vkQueueBindSparse",
+        &[queue.as_raw(), fence.as_raw()],
+        |f| f(queue, bind_info_count, p_bind_info, fence),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkQueueBindSparse`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueBindSparse.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_queue_bind_sparse(
+        &self,
+        f: impl FnMut(vk::Queue, u32, *const vk::BindSparseInfo<'_>, vk::Fence) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<QueueBindSparseFn>("vkQueueBindSparse", Box::new(f))
+    }
+}
+type QueueEndDebugUtilsLabelEXTFn = dyn FnMut(vk::Queue);
+unsafe extern "system" fn queue_end_debug_utils_label_ext(queue: vk::Queue) {
+    dispatch::<QueueEndDebugUtilsLabelEXTFn, _>(
+        "vkQueueEndDebugUtilsLabelEXT",
+        &[queue.as_raw()],
+        |f| f(queue),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkQueueEndDebugUtilsLabelEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueEndDebugUtilsLabelEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_queue_end_debug_utils_label_ext(&self, f: impl FnMut(vk::Queue) + 'static) -> &Self {
+        self.set_handler::<QueueEndDebugUtilsLabelEXTFn>(
+            "vkQueueEndDebugUtilsLabelEXT",
+            Box::new(f),
+        )
+    }
+}
+type QueueInsertDebugUtilsLabelEXTFn = dyn FnMut(vk::Queue, *const vk::DebugUtilsLabelEXT<'_>);
+unsafe extern "system" fn queue_insert_debug_utils_label_ext(
+    queue: vk::Queue,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    dispatch::<QueueInsertDebugUtilsLabelEXTFn, _>(
+        "vkQueueInsertDebugUtilsLabelEXT",
+        &[queue.as_raw()],
+        |f| f(queue, p_label_info),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkQueueInsertDebugUtilsLabelEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueInsertDebugUtilsLabelEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_queue_insert_debug_utils_label_ext(
+        &self,
+        f: impl FnMut(vk::Queue, *const vk::DebugUtilsLabelEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<QueueInsertDebugUtilsLabelEXTFn>(
+            "vkQueueInsertDebugUtilsLabelEXT",
+            Box::new(f),
+        )
+    }
+}
+type QueuePresentKHRFn = dyn FnMut(vk::Queue, *const vk::PresentInfoKHR<'_>) -> vk::Result;
+unsafe extern "system" fn queue_present_khr(
+    queue: vk::Queue,
+    p_present_info: *const vk::PresentInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<QueuePresentKHRFn, _>(
+        "vkQueuePresentKHR",
+        &[queue.as_raw()],
+        |f| f(queue, p_present_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkQueuePresentKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueuePresentKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_queue_present_khr(
+        &self,
+        f: impl FnMut(vk::Queue, *const vk::PresentInfoKHR<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<QueuePresentKHRFn>("vkQueuePresentKHR", Box::new(f))
+    }
+}
+type QueueSetPerformanceConfigurationINTELFn =
+    dyn FnMut(vk::Queue, vk::PerformanceConfigurationINTEL) -> vk::Result;
+unsafe extern "system" fn queue_set_performance_configuration_intel(
+    queue: vk::Queue,
+    configuration: vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    dispatch::<QueueSetPerformanceConfigurationINTELFn, _>(
+        "vkQueueSetPerformanceConfigurationINTEL",
+        &[queue.as_raw(), configuration.as_raw()],
+        |f| f(queue, configuration),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkQueueSetPerformanceConfigurationINTEL`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueSetPerformanceConfigurationINTEL.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_queue_set_performance_configuration_intel(
+        &self,
+        f: impl FnMut(vk::Queue, vk::PerformanceConfigurationINTEL) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<QueueSetPerformanceConfigurationINTELFn>(
+            "vkQueueSetPerformanceConfigurationINTEL",
+            Box::new(f),
+        )
+    }
+}
+type QueueSignalReleaseImageANDROIDFn =
+    dyn FnMut(vk::Queue, u32, *const vk::Semaphore, vk::Image, *mut c_int) -> vk::Result;
+unsafe extern "system" fn queue_signal_release_image_android(
+    queue: vk::Queue,
+    wait_semaphore_count: u32,
+    p_wait_semaphores: *const vk::Semaphore,
+    image: vk::Image,
+    p_native_fence_fd: *mut c_int,
+) -> vk::Result {
+    dispatch::<QueueSignalReleaseImageANDROIDFn, _>(
+        "vkQueueSignalReleaseImageANDROID",
+        &[queue.as_raw(), image.as_raw()],
+        |f| {
+            f(
+                queue,
+                wait_semaphore_count,
+                p_wait_semaphores,
+                image,
+                p_native_fence_fd,
+            )
+        },
+        |_| {
+            if !p_native_fence_fd.is_null() {
+                ptr::write_bytes(p_native_fence_fd, 0, 1);
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkQueueSignalReleaseImageANDROID`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueSignalReleaseImageANDROID.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_queue_signal_release_image_android(
+        &self,
+        f: impl FnMut(vk::Queue, u32, *const vk::Semaphore, vk::Image, *mut c_int) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<QueueSignalReleaseImageANDROIDFn>(
+            "vkQueueSignalReleaseImageANDROID",
+            Box::new(f),
+        )
+    }
+}
+type QueueSubmitFn = dyn FnMut(vk::Queue, u32, *const vk::SubmitInfo<'_>, vk::Fence) -> vk::Result;
+unsafe extern "system" fn queue_submit(
+    queue: vk::Queue,
+    submit_count: u32,
+    p_submits: *const vk::SubmitInfo<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    dispatch::<QueueSubmitFn, _>(
+        "vkQueueSubmit",
+        &[queue.as_raw(), fence.as_raw()],
+        |f| f(queue, submit_count, p_submits, fence),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkQueueSubmit`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueSubmit.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_queue_submit(
+        &self,
+        f: impl FnMut(vk::Queue, u32, *const vk::SubmitInfo<'_>, vk::Fence) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<QueueSubmitFn>("vkQueueSubmit", Box::new(f))
+    }
+}
+type QueueSubmit2Fn =
+    dyn FnMut(vk::Queue, u32, *const vk::SubmitInfo2<'_>, vk::Fence) -> vk::Result;
+unsafe extern "system" fn queue_submit2(
+    queue: vk::Queue,
+    submit_count: u32,
+    p_submits: *const vk::SubmitInfo2<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    dispatch::<QueueSubmit2Fn, _>(
+        "vkQueueSubmit2",
+        &[queue.as_raw(), fence.as_raw()],
+        |f| f(queue, submit_count, p_submits, fence),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkQueueSubmit2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueSubmit2.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_queue_submit2(
+        &self,
+        f: impl FnMut(vk::Queue, u32, *const vk::SubmitInfo2<'_>, vk::Fence) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<QueueSubmit2Fn>("vkQueueSubmit2", Box::new(f))
+    }
+}
+type QueueWaitIdleFn = dyn FnMut(vk::Queue) -> vk::Result;
+unsafe extern "system" fn queue_wait_idle(queue: vk::Queue) -> vk::Result {
+    dispatch::<QueueWaitIdleFn, _>(
+        "vkQueueWaitIdle",
+        &[queue.as_raw()],
+        |f| f(queue),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkQueueWaitIdle`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueWaitIdle.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_queue_wait_idle(&self, f: impl FnMut(vk::Queue) -> vk::Result + 'static) -> &Self {
+        self.set_handler::<QueueWaitIdleFn>("vkQueueWaitIdle", Box::new(f))
+    }
+}
+type RegisterDeviceEventEXTFn = dyn FnMut(
+    vk::Device,
+    *const vk::DeviceEventInfoEXT<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Fence,
+) -> vk::Result;
+unsafe extern "system" fn register_device_event_ext(
+    device: vk::Device,
+    p_device_event_info: *const vk::DeviceEventInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    dispatch::<RegisterDeviceEventEXTFn, _>(
+        "vkRegisterDeviceEventEXT",
+        &[device.as_raw()],
+        |f| f(device, p_device_event_info, p_allocator, p_fence),
+        |driver| {
+            if !p_fence.is_null() {
+                *p_fence = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkRegisterDeviceEventEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkRegisterDeviceEventEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_register_device_event_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                *const vk::DeviceEventInfoEXT<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Fence,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<RegisterDeviceEventEXTFn>("vkRegisterDeviceEventEXT", Box::new(f))
+    }
+}
+type RegisterDisplayEventEXTFn = dyn FnMut(
+    vk::Device,
+    vk::DisplayKHR,
+    *const vk::DisplayEventInfoEXT<'_>,
+    *const vk::AllocationCallbacks<'_>,
+    *mut vk::Fence,
+) -> vk::Result;
+unsafe extern "system" fn register_display_event_ext(
+    device: vk::Device,
+    display: vk::DisplayKHR,
+    p_display_event_info: *const vk::DisplayEventInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    dispatch::<RegisterDisplayEventEXTFn, _>(
+        "vkRegisterDisplayEventEXT",
+        &[device.as_raw(), display.as_raw()],
+        |f| f(device, display, p_display_event_info, p_allocator, p_fence),
+        |driver| {
+            if !p_fence.is_null() {
+                *p_fence = driver.allocate();
+            }
+            vk::Result::SUCCESS
+        },
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkRegisterDisplayEventEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkRegisterDisplayEventEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_register_display_event_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::DisplayKHR,
+                *const vk::DisplayEventInfoEXT<'_>,
+                *const vk::AllocationCallbacks<'_>,
+                *mut vk::Fence,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<RegisterDisplayEventEXTFn>("vkRegisterDisplayEventEXT", Box::new(f))
+    }
+}
+type ReleaseDisplayEXTFn = dyn FnMut(vk::PhysicalDevice, vk::DisplayKHR) -> vk::Result;
+unsafe extern "system" fn release_display_ext(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    dispatch::<ReleaseDisplayEXTFn, _>(
+        "vkReleaseDisplayEXT",
+        &[physical_device.as_raw(), display.as_raw()],
+        |f| f(physical_device, display),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkReleaseDisplayEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleaseDisplayEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_release_display_ext(
+        &self,
+        f: impl FnMut(vk::PhysicalDevice, vk::DisplayKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ReleaseDisplayEXTFn>("vkReleaseDisplayEXT", Box::new(f))
+    }
+}
+type ReleaseFullScreenExclusiveModeEXTFn = dyn FnMut(vk::Device, vk::SwapchainKHR) -> vk::Result;
+unsafe extern "system" fn release_full_screen_exclusive_mode_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    dispatch::<ReleaseFullScreenExclusiveModeEXTFn, _>(
+        "vkReleaseFullScreenExclusiveModeEXT",
+        &[device.as_raw(), swapchain.as_raw()],
+        |f| f(device, swapchain),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkReleaseFullScreenExclusiveModeEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleaseFullScreenExclusiveModeEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_release_full_screen_exclusive_mode_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::SwapchainKHR) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ReleaseFullScreenExclusiveModeEXTFn>(
+            "vkReleaseFullScreenExclusiveModeEXT",
+            Box::new(f),
+        )
+    }
+}
+type ReleasePerformanceConfigurationINTELFn =
+    dyn FnMut(vk::Device, vk::PerformanceConfigurationINTEL) -> vk::Result;
+unsafe extern "system" fn release_performance_configuration_intel(
+    device: vk::Device,
+    configuration: vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    dispatch::<ReleasePerformanceConfigurationINTELFn, _>(
+        "vkReleasePerformanceConfigurationINTEL",
+        &[device.as_raw(), configuration.as_raw()],
+        |f| f(device, configuration),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkReleasePerformanceConfigurationINTEL`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleasePerformanceConfigurationINTEL.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_release_performance_configuration_intel(
+        &self,
+        f: impl FnMut(vk::Device, vk::PerformanceConfigurationINTEL) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ReleasePerformanceConfigurationINTELFn>(
+            "vkReleasePerformanceConfigurationINTEL",
+            Box::new(f),
+        )
+    }
+}
+type ReleaseProfilingLockKHRFn = dyn FnMut(vk::Device);
+unsafe extern "system" fn release_profiling_lock_khr(device: vk::Device) {
+    dispatch::<ReleaseProfilingLockKHRFn, _>(
+        "vkReleaseProfilingLockKHR",
+        &[device.as_raw()],
+        |f| f(device),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkReleaseProfilingLockKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleaseProfilingLockKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_release_profiling_lock_khr(&self, f: impl FnMut(vk::Device) + 'static) -> &Self {
+        self.set_handler::<ReleaseProfilingLockKHRFn>("vkReleaseProfilingLockKHR", Box::new(f))
+    }
+}
+type ReleaseSwapchainImagesEXTFn =
+    dyn FnMut(vk::Device, *const vk::ReleaseSwapchainImagesInfoEXT<'_>) -> vk::Result;
+unsafe extern "system" fn release_swapchain_images_ext(
+    device: vk::Device,
+    p_release_info: *const vk::ReleaseSwapchainImagesInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<ReleaseSwapchainImagesEXTFn, _>(
+        "vkReleaseSwapchainImagesEXT",
+        &[device.as_raw()],
+        |f| f(device, p_release_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkReleaseSwapchainImagesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkReleaseSwapchainImagesEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_release_swapchain_images_ext(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::ReleaseSwapchainImagesInfoEXT<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ReleaseSwapchainImagesEXTFn>("vkReleaseSwapchainImagesEXT", Box::new(f))
+    }
+}
+type ResetCommandBufferFn = dyn FnMut(vk::CommandBuffer, vk::CommandBufferResetFlags) -> vk::Result;
+unsafe extern "system" fn reset_command_buffer(
+    command_buffer: vk::CommandBuffer,
+    flags: vk::CommandBufferResetFlags,
+) -> vk::Result {
+    dispatch::<ResetCommandBufferFn, _>(
+        "vkResetCommandBuffer",
+        &[command_buffer.as_raw()],
+        |f| f(command_buffer, flags),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkResetCommandBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetCommandBuffer.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_reset_command_buffer(
+        &self,
+        f: impl FnMut(vk::CommandBuffer, vk::CommandBufferResetFlags) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ResetCommandBufferFn>("vkResetCommandBuffer", Box::new(f))
+    }
+}
+type ResetCommandPoolFn =
+    dyn FnMut(vk::Device, vk::CommandPool, vk::CommandPoolResetFlags) -> vk::Result;
+unsafe extern "system" fn reset_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    flags: vk::CommandPoolResetFlags,
+) -> vk::Result {
+    dispatch::<ResetCommandPoolFn, _>(
+        "vkResetCommandPool",
+        &[device.as_raw(), command_pool.as_raw()],
+        |f| f(device, command_pool, flags),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkResetCommandPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetCommandPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_reset_command_pool(
+        &self,
+        f: impl FnMut(vk::Device, vk::CommandPool, vk::CommandPoolResetFlags) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ResetCommandPoolFn>("vkResetCommandPool", Box::new(f))
+    }
+}
+type ResetDescriptorPoolFn =
+    dyn FnMut(vk::Device, vk::DescriptorPool, vk::DescriptorPoolResetFlags) -> vk::Result;
+unsafe extern "system" fn reset_descriptor_pool(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    flags: vk::DescriptorPoolResetFlags,
+) -> vk::Result {
+    dispatch::<ResetDescriptorPoolFn, _>(
+        "vkResetDescriptorPool",
+        &[device.as_raw(), descriptor_pool.as_raw()],
+        |f| f(device, descriptor_pool, flags),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkResetDescriptorPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetDescriptorPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_reset_descriptor_pool(
+        &self,
+        f: impl FnMut(vk::Device, vk::DescriptorPool, vk::DescriptorPoolResetFlags) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<ResetDescriptorPoolFn>("vkResetDescriptorPool", Box::new(f))
+    }
+}
+type ResetEventFn = dyn FnMut(vk::Device, vk::Event) -> vk::Result;
+unsafe extern "system" fn reset_event(device: vk::Device, event: vk::Event) -> vk::Result {
+    dispatch::<ResetEventFn, _>(
+        "vkResetEvent",
+        &[device.as_raw(), event.as_raw()],
+        |f| f(device, event),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkResetEvent`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetEvent.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_reset_event(
+        &self,
+        f: impl FnMut(vk::Device, vk::Event) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ResetEventFn>("vkResetEvent", Box::new(f))
+    }
+}
+type ResetFencesFn = dyn FnMut(vk::Device, u32, *const vk::Fence) -> vk::Result;
+unsafe extern "system" fn reset_fences(
+    device: vk::Device,
+    fence_count: u32,
+    p_fences: *const vk::Fence,
+) -> vk::Result {
+    dispatch::<ResetFencesFn, _>(
+        "vkResetFences",
+        &[device.as_raw()],
+        |f| f(device, fence_count, p_fences),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkResetFences`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetFences.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_reset_fences(
+        &self,
+        f: impl FnMut(vk::Device, u32, *const vk::Fence) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<ResetFencesFn>("vkResetFences", Box::new(f))
+    }
+}
+type ResetQueryPoolFn = dyn FnMut(vk::Device, vk::QueryPool, u32, u32);
+unsafe extern "system" fn reset_query_pool(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+) {
+    dispatch::<ResetQueryPoolFn, _>(
+        "vkResetQueryPool",
+        &[device.as_raw(), query_pool.as_raw()],
+        |f| f(device, query_pool, first_query, query_count),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkResetQueryPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetQueryPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_reset_query_pool(
+        &self,
+        f: impl FnMut(vk::Device, vk::QueryPool, u32, u32) + 'static,
+    ) -> &Self {
+        self.set_handler::<ResetQueryPoolFn>("vkResetQueryPool", Box::new(f))
+    }
+}
+type SetBufferCollectionBufferConstraintsFUCHSIAFn = dyn FnMut(
+    vk::Device,
+    vk::BufferCollectionFUCHSIA,
+    *const vk::BufferConstraintsInfoFUCHSIA<'_>,
+) -> vk::Result;
+unsafe extern "system" fn set_buffer_collection_buffer_constraints_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_buffer_constraints_info: *const vk::BufferConstraintsInfoFUCHSIA<'_>,
+) -> vk::Result {
+    dispatch::<SetBufferCollectionBufferConstraintsFUCHSIAFn, _>(
+        "vkSetBufferCollectionBufferConstraintsFUCHSIA",
+        &[device.as_raw(), collection.as_raw()],
+        |f| f(device, collection, p_buffer_constraints_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSetBufferCollectionBufferConstraintsFUCHSIA`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetBufferCollectionBufferConstraintsFUCHSIA.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_set_buffer_collection_buffer_constraints_fuchsia(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::BufferCollectionFUCHSIA,
+                *const vk::BufferConstraintsInfoFUCHSIA<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<SetBufferCollectionBufferConstraintsFUCHSIAFn>(
+            "vkSetBufferCollectionBufferConstraintsFUCHSIA",
+            Box::new(f),
+        )
+    }
+}
+type SetBufferCollectionImageConstraintsFUCHSIAFn = dyn FnMut(
+    vk::Device,
+    vk::BufferCollectionFUCHSIA,
+    *const vk::ImageConstraintsInfoFUCHSIA<'_>,
+) -> vk::Result;
+unsafe extern "system" fn set_buffer_collection_image_constraints_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_image_constraints_info: *const vk::ImageConstraintsInfoFUCHSIA<'_>,
+) -> vk::Result {
+    dispatch::<SetBufferCollectionImageConstraintsFUCHSIAFn, _>(
+        "vkSetBufferCollectionImageConstraintsFUCHSIA",
+        &[device.as_raw(), collection.as_raw()],
+        |f| f(device, collection, p_image_constraints_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSetBufferCollectionImageConstraintsFUCHSIA`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetBufferCollectionImageConstraintsFUCHSIA.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_set_buffer_collection_image_constraints_fuchsia(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::BufferCollectionFUCHSIA,
+                *const vk::ImageConstraintsInfoFUCHSIA<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<SetBufferCollectionImageConstraintsFUCHSIAFn>(
+            "vkSetBufferCollectionImageConstraintsFUCHSIA",
+            Box::new(f),
+        )
+    }
+}
+type SetDebugUtilsObjectNameEXTFn =
+    dyn FnMut(vk::Device, *const vk::DebugUtilsObjectNameInfoEXT<'_>) -> vk::Result;
+unsafe extern "system" fn set_debug_utils_object_name_ext(
+    device: vk::Device,
+    p_name_info: *const vk::DebugUtilsObjectNameInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<SetDebugUtilsObjectNameEXTFn, _>(
+        "vkSetDebugUtilsObjectNameEXT",
+        &[device.as_raw()],
+        |f| f(device, p_name_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSetDebugUtilsObjectNameEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetDebugUtilsObjectNameEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_set_debug_utils_object_name_ext(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::DebugUtilsObjectNameInfoEXT<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<SetDebugUtilsObjectNameEXTFn>(
+            "vkSetDebugUtilsObjectNameEXT",
+            Box::new(f),
+        )
+    }
+}
+type SetDebugUtilsObjectTagEXTFn =
+    dyn FnMut(vk::Device, *const vk::DebugUtilsObjectTagInfoEXT<'_>) -> vk::Result;
+unsafe extern "system" fn set_debug_utils_object_tag_ext(
+    device: vk::Device,
+    p_tag_info: *const vk::DebugUtilsObjectTagInfoEXT<'_>,
+) -> vk::Result {
+    dispatch::<SetDebugUtilsObjectTagEXTFn, _>(
+        "vkSetDebugUtilsObjectTagEXT",
+        &[device.as_raw()],
+        |f| f(device, p_tag_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSetDebugUtilsObjectTagEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetDebugUtilsObjectTagEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_set_debug_utils_object_tag_ext(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::DebugUtilsObjectTagInfoEXT<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<SetDebugUtilsObjectTagEXTFn>("vkSetDebugUtilsObjectTagEXT", Box::new(f))
+    }
+}
+type SetDeviceMemoryPriorityEXTFn = dyn FnMut(vk::Device, vk::DeviceMemory, f32);
+unsafe extern "system" fn set_device_memory_priority_ext(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    priority: f32,
+) {
+    dispatch::<SetDeviceMemoryPriorityEXTFn, _>(
+        "vkSetDeviceMemoryPriorityEXT",
+        &[device.as_raw(), memory.as_raw()],
+        |f| f(device, memory, priority),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSetDeviceMemoryPriorityEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetDeviceMemoryPriorityEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_set_device_memory_priority_ext(
+        &self,
+        f: impl FnMut(vk::Device, vk::DeviceMemory, f32) + 'static,
+    ) -> &Self {
+        self.set_handler::<SetDeviceMemoryPriorityEXTFn>(
+            "vkSetDeviceMemoryPriorityEXT",
+            Box::new(f),
+        )
+    }
+}
+type SetEventFn = dyn FnMut(vk::Device, vk::Event) -> vk::Result;
+unsafe extern "system" fn set_event(device: vk::Device, event: vk::Event) -> vk::Result {
+    dispatch::<SetEventFn, _>(
+        "vkSetEvent",
+        &[device.as_raw(), event.as_raw()],
+        |f| f(device, event),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSetEvent`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetEvent.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_set_event(
+        &self,
+        f: impl FnMut(vk::Device, vk::Event) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<SetEventFn>("vkSetEvent", Box::new(f))
+    }
+}
+type SetHdrMetadataEXTFn =
+    dyn FnMut(vk::Device, u32, *const vk::SwapchainKHR, *const vk::HdrMetadataEXT<'_>);
+unsafe extern "system" fn set_hdr_metadata_ext(
+    device: vk::Device,
+    swapchain_count: u32,
+    p_swapchains: *const vk::SwapchainKHR,
+    p_metadata: *const vk::HdrMetadataEXT<'_>,
+) {
+    dispatch::<SetHdrMetadataEXTFn, _>(
+        "vkSetHdrMetadataEXT",
+        &[device.as_raw()],
+        |f| f(device, swapchain_count, p_swapchains, p_metadata),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSetHdrMetadataEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetHdrMetadataEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_set_hdr_metadata_ext(
+        &self,
+        f: impl FnMut(vk::Device, u32, *const vk::SwapchainKHR, *const vk::HdrMetadataEXT<'_>) + 'static,
+    ) -> &Self {
+        self.set_handler::<SetHdrMetadataEXTFn>("vkSetHdrMetadataEXT", Box::new(f))
+    }
+}
+type SetLocalDimmingAMDFn = dyn FnMut(vk::Device, vk::SwapchainKHR, vk::Bool32);
+unsafe extern "system" fn set_local_dimming_amd(
+    device: vk::Device,
+    swap_chain: vk::SwapchainKHR,
+    local_dimming_enable: vk::Bool32,
+) {
+    dispatch::<SetLocalDimmingAMDFn, _>(
+        "vkSetLocalDimmingAMD",
+        &[device.as_raw(), swap_chain.as_raw()],
+        |f| f(device, swap_chain, local_dimming_enable),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSetLocalDimmingAMD`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetLocalDimmingAMD.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_set_local_dimming_amd(
+        &self,
+        f: impl FnMut(vk::Device, vk::SwapchainKHR, vk::Bool32) + 'static,
+    ) -> &Self {
+        self.set_handler::<SetLocalDimmingAMDFn>("vkSetLocalDimmingAMD", Box::new(f))
+    }
+}
+type SetPrivateDataFn =
+    dyn FnMut(vk::Device, vk::ObjectType, u64, vk::PrivateDataSlot, u64) -> vk::Result;
+unsafe extern "system" fn set_private_data(
+    device: vk::Device,
+    object_type: vk::ObjectType,
+    object_handle: u64,
+    private_data_slot: vk::PrivateDataSlot,
+    data: u64,
+) -> vk::Result {
+    dispatch::<SetPrivateDataFn, _>(
+        "vkSetPrivateData",
+        &[device.as_raw(), private_data_slot.as_raw()],
+        |f| f(device, object_type, object_handle, private_data_slot, data),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSetPrivateData`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSetPrivateData.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_set_private_data(
+        &self,
+        f: impl FnMut(vk::Device, vk::ObjectType, u64, vk::PrivateDataSlot, u64) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<SetPrivateDataFn>("vkSetPrivateData", Box::new(f))
+    }
+}
+type SignalSemaphoreFn = dyn FnMut(vk::Device, *const vk::SemaphoreSignalInfo<'_>) -> vk::Result;
+unsafe extern "system" fn signal_semaphore(
+    device: vk::Device,
+    p_signal_info: *const vk::SemaphoreSignalInfo<'_>,
+) -> vk::Result {
+    dispatch::<SignalSemaphoreFn, _>(
+        "vkSignalSemaphore",
+        &[device.as_raw()],
+        |f| f(device, p_signal_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSignalSemaphore`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSignalSemaphore.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_signal_semaphore(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::SemaphoreSignalInfo<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<SignalSemaphoreFn>("vkSignalSemaphore", Box::new(f))
+    }
+}
+type SubmitDebugUtilsMessageEXTFn = dyn FnMut(
+    vk::Instance,
+    vk::DebugUtilsMessageSeverityFlagsEXT,
+    vk::DebugUtilsMessageTypeFlagsEXT,
+    *const vk::DebugUtilsMessengerCallbackDataEXT<'_>,
+);
+unsafe extern "system" fn submit_debug_utils_message_ext(
+    instance: vk::Instance,
+    message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    message_types: vk::DebugUtilsMessageTypeFlagsEXT,
+    p_callback_data: *const vk::DebugUtilsMessengerCallbackDataEXT<'_>,
+) {
+    dispatch::<SubmitDebugUtilsMessageEXTFn, _>(
+        "vkSubmitDebugUtilsMessageEXT",
+        &[instance.as_raw()],
+        |f| f(instance, message_severity, message_types, p_callback_data),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkSubmitDebugUtilsMessageEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSubmitDebugUtilsMessageEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_submit_debug_utils_message_ext(
+        &self,
+        f: impl FnMut(
+                vk::Instance,
+                vk::DebugUtilsMessageSeverityFlagsEXT,
+                vk::DebugUtilsMessageTypeFlagsEXT,
+                *const vk::DebugUtilsMessengerCallbackDataEXT<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<SubmitDebugUtilsMessageEXTFn>(
+            "vkSubmitDebugUtilsMessageEXT",
+            Box::new(f),
+        )
+    }
+}
+type TrimCommandPoolFn = dyn FnMut(vk::Device, vk::CommandPool, vk::CommandPoolTrimFlags);
+unsafe extern "system" fn trim_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    flags: vk::CommandPoolTrimFlags,
+) {
+    dispatch::<TrimCommandPoolFn, _>(
+        "vkTrimCommandPool",
+        &[device.as_raw(), command_pool.as_raw()],
+        |f| f(device, command_pool, flags),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkTrimCommandPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkTrimCommandPool.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_trim_command_pool(
+        &self,
+        f: impl FnMut(vk::Device, vk::CommandPool, vk::CommandPoolTrimFlags) + 'static,
+    ) -> &Self {
+        self.set_handler::<TrimCommandPoolFn>("vkTrimCommandPool", Box::new(f))
+    }
+}
+type UninitializePerformanceApiINTELFn = dyn FnMut(vk::Device);
+unsafe extern "system" fn uninitialize_performance_api_intel(device: vk::Device) {
+    dispatch::<UninitializePerformanceApiINTELFn, _>(
+        "vkUninitializePerformanceApiINTEL",
+        &[device.as_raw()],
+        |f| f(device),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkUninitializePerformanceApiINTEL`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUninitializePerformanceApiINTEL.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_uninitialize_performance_api_intel(
+        &self,
+        f: impl FnMut(vk::Device) + 'static,
+    ) -> &Self {
+        self.set_handler::<UninitializePerformanceApiINTELFn>(
+            "vkUninitializePerformanceApiINTEL",
+            Box::new(f),
+        )
+    }
+}
+type UnmapMemoryFn = dyn FnMut(vk::Device, vk::DeviceMemory);
+unsafe extern "system" fn unmap_memory(device: vk::Device, memory: vk::DeviceMemory) {
+    dispatch::<UnmapMemoryFn, _>(
+        "vkUnmapMemory",
+        &[device.as_raw(), memory.as_raw()],
+        |f| f(device, memory),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkUnmapMemory`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUnmapMemory.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_unmap_memory(&self, f: impl FnMut(vk::Device, vk::DeviceMemory) + 'static) -> &Self {
+        self.set_handler::<UnmapMemoryFn>("vkUnmapMemory", Box::new(f))
+    }
+}
+type UnmapMemory2KHRFn = dyn FnMut(vk::Device, *const vk::MemoryUnmapInfoKHR<'_>) -> vk::Result;
+unsafe extern "system" fn unmap_memory2_khr(
+    device: vk::Device,
+    p_memory_unmap_info: *const vk::MemoryUnmapInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<UnmapMemory2KHRFn, _>(
+        "vkUnmapMemory2KHR",
+        &[device.as_raw()],
+        |f| f(device, p_memory_unmap_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkUnmapMemory2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUnmapMemory2KHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_unmap_memory2_khr(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::MemoryUnmapInfoKHR<'_>) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<UnmapMemory2KHRFn>("vkUnmapMemory2KHR", Box::new(f))
+    }
+}
+type UpdateDescriptorSetWithTemplateFn =
+    dyn FnMut(vk::Device, vk::DescriptorSet, vk::DescriptorUpdateTemplate, *const c_void);
+unsafe extern "system" fn update_descriptor_set_with_template(
+    device: vk::Device,
+    descriptor_set: vk::DescriptorSet,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    p_data: *const c_void,
+) {
+    dispatch::<UpdateDescriptorSetWithTemplateFn, _>(
+        "vkUpdateDescriptorSetWithTemplate",
+        &[
+            device.as_raw(),
+            descriptor_set.as_raw(),
+            descriptor_update_template.as_raw(),
+        ],
+        |f| f(device, descriptor_set, descriptor_update_template, p_data),
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkUpdateDescriptorSetWithTemplate`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUpdateDescriptorSetWithTemplate.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_update_descriptor_set_with_template(
+        &self,
+        f: impl FnMut(vk::Device, vk::DescriptorSet, vk::DescriptorUpdateTemplate, *const c_void)
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<UpdateDescriptorSetWithTemplateFn>(
+            "vkUpdateDescriptorSetWithTemplate",
+            Box::new(f),
+        )
+    }
+}
+type UpdateDescriptorSetsFn = dyn FnMut(
+    vk::Device,
+    u32,
+    *const vk::WriteDescriptorSet<'_>,
+    u32,
+    *const vk::CopyDescriptorSet<'_>,
+);
+unsafe extern "system" fn update_descriptor_sets(
+    device: vk::Device,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const vk::WriteDescriptorSet<'_>,
+    descriptor_copy_count: u32,
+    p_descriptor_copies: *const vk::CopyDescriptorSet<'_>,
+) {
+    dispatch::<UpdateDescriptorSetsFn, _>(
+        "vkUpdateDescriptorSets",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                descriptor_write_count,
+                p_descriptor_writes,
+                descriptor_copy_count,
+                p_descriptor_copies,
+            )
+        },
+        |_| {},
+    )
+    .unwrap_or_default()
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkUpdateDescriptorSets`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUpdateDescriptorSets.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_update_descriptor_sets(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                u32,
+                *const vk::WriteDescriptorSet<'_>,
+                u32,
+                *const vk::CopyDescriptorSet<'_>,
+            ) + 'static,
+    ) -> &Self {
+        self.set_handler::<UpdateDescriptorSetsFn>("vkUpdateDescriptorSets", Box::new(f))
+    }
+}
+type UpdateVideoSessionParametersKHRFn = dyn FnMut(
+    vk::Device,
+    vk::VideoSessionParametersKHR,
+    *const vk::VideoSessionParametersUpdateInfoKHR<'_>,
+) -> vk::Result;
+unsafe extern "system" fn update_video_session_parameters_khr(
+    device: vk::Device,
+    video_session_parameters: vk::VideoSessionParametersKHR,
+    p_update_info: *const vk::VideoSessionParametersUpdateInfoKHR<'_>,
+) -> vk::Result {
+    dispatch::<UpdateVideoSessionParametersKHRFn, _>(
+        "vkUpdateVideoSessionParametersKHR",
+        &[device.as_raw(), video_session_parameters.as_raw()],
+        |f| f(device, video_session_parameters, p_update_info),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkUpdateVideoSessionParametersKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUpdateVideoSessionParametersKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_update_video_session_parameters_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                vk::VideoSessionParametersKHR,
+                *const vk::VideoSessionParametersUpdateInfoKHR<'_>,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<UpdateVideoSessionParametersKHRFn>(
+            "vkUpdateVideoSessionParametersKHR",
+            Box::new(f),
+        )
+    }
+}
+type WaitForFencesFn = dyn FnMut(vk::Device, u32, *const vk::Fence, vk::Bool32, u64) -> vk::Result;
+unsafe extern "system" fn wait_for_fences(
+    device: vk::Device,
+    fence_count: u32,
+    p_fences: *const vk::Fence,
+    wait_all: vk::Bool32,
+    timeout: u64,
+) -> vk::Result {
+    dispatch::<WaitForFencesFn, _>(
+        "vkWaitForFences",
+        &[device.as_raw()],
+        |f| f(device, fence_count, p_fences, wait_all, timeout),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkWaitForFences`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitForFences.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_wait_for_fences(
+        &self,
+        f: impl FnMut(vk::Device, u32, *const vk::Fence, vk::Bool32, u64) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<WaitForFencesFn>("vkWaitForFences", Box::new(f))
+    }
+}
+type WaitForPresentKHRFn = dyn FnMut(vk::Device, vk::SwapchainKHR, u64, u64) -> vk::Result;
+unsafe extern "system" fn wait_for_present_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    present_id: u64,
+    timeout: u64,
+) -> vk::Result {
+    dispatch::<WaitForPresentKHRFn, _>(
+        "vkWaitForPresentKHR",
+        &[device.as_raw(), swapchain.as_raw()],
+        |f| f(device, swapchain, present_id, timeout),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkWaitForPresentKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitForPresentKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_wait_for_present_khr(
+        &self,
+        f: impl FnMut(vk::Device, vk::SwapchainKHR, u64, u64) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<WaitForPresentKHRFn>("vkWaitForPresentKHR", Box::new(f))
+    }
+}
+type WaitSemaphoresFn = dyn FnMut(vk::Device, *const vk::SemaphoreWaitInfo<'_>, u64) -> vk::Result;
+unsafe extern "system" fn wait_semaphores(
+    device: vk::Device,
+    p_wait_info: *const vk::SemaphoreWaitInfo<'_>,
+    timeout: u64,
+) -> vk::Result {
+    dispatch::<WaitSemaphoresFn, _>(
+        "vkWaitSemaphores",
+        &[device.as_raw()],
+        |f| f(device, p_wait_info, timeout),
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkWaitSemaphores`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitSemaphores.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_wait_semaphores(
+        &self,
+        f: impl FnMut(vk::Device, *const vk::SemaphoreWaitInfo<'_>, u64) -> vk::Result + 'static,
+    ) -> &Self {
+        self.set_handler::<WaitSemaphoresFn>("vkWaitSemaphores", Box::new(f))
+    }
+}
+type WriteAccelerationStructuresPropertiesKHRFn = dyn FnMut(
+    vk::Device,
+    u32,
+    *const vk::AccelerationStructureKHR,
+    vk::QueryType,
+    usize,
+    *mut c_void,
+    usize,
+) -> vk::Result;
+unsafe extern "system" fn write_acceleration_structures_properties_khr(
+    device: vk::Device,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureKHR,
+    query_type: vk::QueryType,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: usize,
+) -> vk::Result {
+    dispatch::<WriteAccelerationStructuresPropertiesKHRFn, _>(
+        "vkWriteAccelerationStructuresPropertiesKHR",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                acceleration_structure_count,
+                p_acceleration_structures,
+                query_type,
+                data_size,
+                p_data,
+                stride,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkWriteAccelerationStructuresPropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWriteAccelerationStructuresPropertiesKHR.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_write_acceleration_structures_properties_khr(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                u32,
+                *const vk::AccelerationStructureKHR,
+                vk::QueryType,
+                usize,
+                *mut c_void,
+                usize,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<WriteAccelerationStructuresPropertiesKHRFn>(
+            "vkWriteAccelerationStructuresPropertiesKHR",
+            Box::new(f),
+        )
+    }
+}
+type WriteMicromapsPropertiesEXTFn = dyn FnMut(
+    vk::Device,
+    u32,
+    *const vk::MicromapEXT,
+    vk::QueryType,
+    usize,
+    *mut c_void,
+    usize,
+) -> vk::Result;
+unsafe extern "system" fn write_micromaps_properties_ext(
+    device: vk::Device,
+    micromap_count: u32,
+    p_micromaps: *const vk::MicromapEXT,
+    query_type: vk::QueryType,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: usize,
+) -> vk::Result {
+    dispatch::<WriteMicromapsPropertiesEXTFn, _>(
+        "vkWriteMicromapsPropertiesEXT",
+        &[device.as_raw()],
+        |f| {
+            f(
+                device,
+                micromap_count,
+                p_micromaps,
+                query_type,
+                data_size,
+                p_data,
+                stride,
+            )
+        },
+        |_| vk::Result::SUCCESS,
+    )
+    .unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED)
+}
+impl MockDriver {
+    #[doc = " Overrides [`vkWriteMicromapsPropertiesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWriteMicromapsPropertiesEXT.html), which otherwise records the call and falls back to its default behaviour."]
+    pub fn on_write_micromaps_properties_ext(
+        &self,
+        f: impl FnMut(
+                vk::Device,
+                u32,
+                *const vk::MicromapEXT,
+                vk::QueryType,
+                usize,
+                *mut c_void,
+                usize,
+            ) -> vk::Result
+            + 'static,
+    ) -> &Self {
+        self.set_handler::<WriteMicromapsPropertiesEXTFn>(
+            "vkWriteMicromapsPropertiesEXT",
+            Box::new(f),
+        )
+    }
+}
@@ -0,0 +1,116 @@
+//! Handles that destroy themselves when dropped.
+//!
+//! An [`Owned`] handle keeps its [`Parent`], a shared [`Device`] and, for command buffers and
+//! descriptor sets, the pool they were allocated from, or a shared extension wrapper. When
+//! dropped, it calls the command of its parent that destroys or frees handles of its
+//! [`Handle::TYPE`], such as `vkDestroyBuffer`, `vkFreeMemory` or `vkFreeCommandBuffers`:
+//!
+//! ```no_run
+//! # use ash::{owned::{Owned, Parent}, vk, Device};
+//! # use std::sync::Arc;
+//! # unsafe fn f(device: Arc<Device>, buffer_info: &vk::BufferCreateInfo<'_>, memory_info: &vk::MemoryAllocateInfo<'_>) -> ash::prelude::VkResult<()> {
+//! let parent = Parent::Device(device.clone());
+//! let buffer = Owned::from_raw(parent.clone(), device.create_buffer(buffer_info, None)?);
+//! // `buffer` is destroyed if the allocation fails
+//! let memory = Owned::from_raw(parent, device.allocate_memory(memory_info, None)?);
+//! device.bind_buffer_memory(*buffer, *memory, 0)?;
+//! # Ok(()) }
+//! ```
+//!
+//! The handles of extensions are destroyed through their extension wrapper, such as
+//! [`vk::SwapchainKHR`] through [`Parent::KhrSwapchain`] and [`vk::SurfaceKHR`], which belongs to
+//! an instance, through [`Parent::KhrSurface`]. Handles of extensions that were promoted to core,
+//! such as [`vk::SamplerYcbcrConversion`], are destroyed by [`Parent::Device`] with the core
+//! command if the device supports that Vulkan version, and otherwise with the command of the
+//! extension that was enabled to create them, through its own parent such as
+//! [`Parent::KhrSamplerYcbcrConversion`].
+//!
+//! Handles are destroyed without allocation callbacks, and must be dropped before the device or
+//! instance is destroyed with [`Device::destroy_device()`] or
+//! [`Instance::destroy_instance()`][crate::Instance::destroy_instance()], and before their pool is
+//! destroyed or reset.
+
+use crate::vk::{self, Handle};
+use crate::Device;
+use std::fmt;
+use std::mem;
+use std::ops::Deref;
+use std::ptr;
+use std::sync::Arc;
+
+#[allow(deprecated)]
+mod generated;
+
+pub use self::generated::Parent;
+
+/// A handle that is destroyed or freed through its [`Parent`] when dropped, see the
+/// [module documentation][self].
+pub struct Owned<H: Handle + Copy> {
+    handle: H,
+    parent: Parent,
+}
+
+impl<H: Handle + Copy> Owned<H> {
+    /// Takes ownership of `handle`, to destroy or free it through `parent` when dropped.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid handle created or allocated through `parent` without allocation
+    /// callbacks, and must not be destroyed or freed elsewhere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` does not destroy or free handles of type `H`.
+    pub unsafe fn from_raw(parent: Parent, handle: H) -> Self {
+        assert!(
+            generated::destroys(&parent, H::TYPE),
+            "{parent:?} does not destroy or free {:?}",
+            H::TYPE
+        );
+        Self { handle, parent }
+    }
+
+    /// Releases ownership of the handle without destroying it.
+    pub fn into_raw(self) -> H {
+        let handle = self.handle;
+        // Drops the parent without running `Drop for Owned`
+        drop(unsafe { ptr::read(&self.parent) });
+        mem::forget(self);
+        handle
+    }
+
+    /// The handle, which remains owned.
+    pub fn handle(&self) -> H {
+        self.handle
+    }
+
+    /// What destroys or frees the handle.
+    pub fn parent(&self) -> &Parent {
+        &self.parent
+    }
+}
+
+impl<H: Handle + Copy> Deref for Owned<H> {
+    type Target = H;
+
+    fn deref(&self) -> &H {
+        &self.handle
+    }
+}
+
+impl<H: Handle + Copy + fmt::Debug> fmt::Debug for Owned<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Owned")
+            .field("handle", &self.handle)
+            .field("parent", &self.parent)
+            .finish()
+    }
+}
+
+impl<H: Handle + Copy> Drop for Owned<H> {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { generated::destroy(&self.parent, H::TYPE, self.handle.as_raw()) };
+        }
+    }
+}
@@ -0,0 +1,520 @@
+use super::*;
+use crate::extensions::{ext, fuchsia, khr, nv, nvx};
+#[doc = r" What destroys or frees an [`Owned`] handle."]
+#[derive(Clone)]
+pub enum Parent {
+    #[doc = r" Destroys handles with `vkDestroy*()` or `vkFreeMemory()`. The handles of"]
+    #[doc = r" extensions that were promoted to core, such as [`vk::SamplerYcbcrConversion`], are"]
+    #[doc = r" only destroyed if [`Device::api_version()`] includes their core version, and"]
+    #[doc = r" otherwise by the parent of their extension, such as"]
+    #[doc = r" [`Parent::KhrSamplerYcbcrConversion`]."]
+    Device(Arc<Device>),
+    #[doc = r" Frees command buffers allocated from a pool."]
+    CommandPool(Arc<Device>, vk::CommandPool),
+    #[doc = r" Frees descriptor sets allocated from a pool created with"]
+    #[doc = r" [`vk::DescriptorPoolCreateFlags::FREE_DESCRIPTOR_SET`]."]
+    DescriptorPool(Arc<Device>, vk::DescriptorPool),
+    #[doc = " Destroys handles with [`ext::DebugReport`]."]
+    ExtDebugReport(Arc<ext::DebugReport>),
+    #[doc = " Destroys handles with [`ext::DebugUtils`]."]
+    ExtDebugUtils(Arc<ext::DebugUtils>),
+    #[doc = " Destroys handles with [`ext::OpacityMicromap`]."]
+    ExtOpacityMicromap(Arc<ext::OpacityMicromap>),
+    #[doc = " Destroys handles with [`ext::PrivateData`]."]
+    ExtPrivateData(Arc<ext::PrivateData>),
+    #[doc = " Destroys handles with [`ext::ShaderObject`]."]
+    ExtShaderObject(Arc<ext::ShaderObject>),
+    #[doc = " Destroys handles with [`ext::ValidationCache`]."]
+    ExtValidationCache(Arc<ext::ValidationCache>),
+    #[doc = " Destroys handles with [`fuchsia::BufferCollection`]."]
+    FuchsiaBufferCollection(Arc<fuchsia::BufferCollection>),
+    #[doc = " Destroys handles with [`khr::AccelerationStructure`]."]
+    KhrAccelerationStructure(Arc<khr::AccelerationStructure>),
+    #[doc = " Destroys handles with [`khr::DeferredHostOperations`]."]
+    KhrDeferredHostOperations(Arc<khr::DeferredHostOperations>),
+    #[doc = " Destroys handles with [`khr::DescriptorUpdateTemplate`]."]
+    KhrDescriptorUpdateTemplate(Arc<khr::DescriptorUpdateTemplate>),
+    #[doc = " Destroys handles with [`khr::SamplerYcbcrConversion`]."]
+    KhrSamplerYcbcrConversion(Arc<khr::SamplerYcbcrConversion>),
+    #[doc = " Destroys handles with [`khr::Surface`]."]
+    KhrSurface(Arc<khr::Surface>),
+    #[doc = " Destroys handles with [`khr::Swapchain`]."]
+    KhrSwapchain(Arc<khr::Swapchain>),
+    #[doc = " Destroys handles with [`khr::VideoQueue`]."]
+    KhrVideoQueue(Arc<khr::VideoQueue>),
+    #[doc = " Destroys handles with [`nv::DeviceGeneratedCommands`]."]
+    NvDeviceGeneratedCommands(Arc<nv::DeviceGeneratedCommands>),
+    #[doc = " Destroys handles with [`nv::OpticalFlow`]."]
+    NvOpticalFlow(Arc<nv::OpticalFlow>),
+    #[doc = " Destroys handles with [`nv::RayTracing`]."]
+    NvRayTracing(Arc<nv::RayTracing>),
+    #[doc = " Destroys handles with [`nvx::BinaryImport`]."]
+    NvxBinaryImport(Arc<nvx::BinaryImport>),
+}
+impl fmt::Debug for Parent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Device(device) => f.debug_tuple("Device").field(&device.handle()).finish(),
+            Self::CommandPool(device, pool) => f
+                .debug_tuple("CommandPool")
+                .field(&device.handle())
+                .field(pool)
+                .finish(),
+            Self::DescriptorPool(device, pool) => f
+                .debug_tuple("DescriptorPool")
+                .field(&device.handle())
+                .field(pool)
+                .finish(),
+            Self::ExtDebugReport(wrapper) => f
+                .debug_tuple("ExtDebugReport")
+                .field(&wrapper.instance())
+                .finish(),
+            Self::ExtDebugUtils(wrapper) => f
+                .debug_tuple("ExtDebugUtils")
+                .field(&wrapper.instance())
+                .finish(),
+            Self::ExtOpacityMicromap(wrapper) => f
+                .debug_tuple("ExtOpacityMicromap")
+                .field(&wrapper.device())
+                .finish(),
+            Self::ExtPrivateData(wrapper) => f
+                .debug_tuple("ExtPrivateData")
+                .field(&wrapper.device())
+                .finish(),
+            Self::ExtShaderObject(wrapper) => f
+                .debug_tuple("ExtShaderObject")
+                .field(&wrapper.device())
+                .finish(),
+            Self::ExtValidationCache(wrapper) => f
+                .debug_tuple("ExtValidationCache")
+                .field(&wrapper.device())
+                .finish(),
+            Self::FuchsiaBufferCollection(wrapper) => f
+                .debug_tuple("FuchsiaBufferCollection")
+                .field(&wrapper.device())
+                .finish(),
+            Self::KhrAccelerationStructure(wrapper) => f
+                .debug_tuple("KhrAccelerationStructure")
+                .field(&wrapper.device())
+                .finish(),
+            Self::KhrDeferredHostOperations(wrapper) => f
+                .debug_tuple("KhrDeferredHostOperations")
+                .field(&wrapper.device())
+                .finish(),
+            Self::KhrDescriptorUpdateTemplate(wrapper) => f
+                .debug_tuple("KhrDescriptorUpdateTemplate")
+                .field(&wrapper.device())
+                .finish(),
+            Self::KhrSamplerYcbcrConversion(wrapper) => f
+                .debug_tuple("KhrSamplerYcbcrConversion")
+                .field(&wrapper.device())
+                .finish(),
+            Self::KhrSurface(wrapper) => f
+                .debug_tuple("KhrSurface")
+                .field(&wrapper.instance())
+                .finish(),
+            Self::KhrSwapchain(wrapper) => f
+                .debug_tuple("KhrSwapchain")
+                .field(&wrapper.device())
+                .finish(),
+            Self::KhrVideoQueue(wrapper) => f
+                .debug_tuple("KhrVideoQueue")
+                .field(&wrapper.device())
+                .finish(),
+            Self::NvDeviceGeneratedCommands(wrapper) => f
+                .debug_tuple("NvDeviceGeneratedCommands")
+                .field(&wrapper.device())
+                .finish(),
+            Self::NvOpticalFlow(wrapper) => f
+                .debug_tuple("NvOpticalFlow")
+                .field(&wrapper.device())
+                .finish(),
+            Self::NvRayTracing(wrapper) => f
+                .debug_tuple("NvRayTracing")
+                .field(&wrapper.device())
+                .finish(),
+            Self::NvxBinaryImport(wrapper) => f
+                .debug_tuple("NvxBinaryImport")
+                .field(&wrapper.device())
+                .finish(),
+        }
+    }
+}
+#[doc = r" Whether `parent` destroys or frees handles of type `ty`."]
+pub(super) fn destroys(parent: &Parent, ty: vk::ObjectType) -> bool {
+    match (parent, ty) {
+        (Parent::Device(_), vk::ObjectType::DEVICE_MEMORY)
+        | (Parent::Device(_), vk::ObjectType::FENCE)
+        | (Parent::Device(_), vk::ObjectType::SEMAPHORE)
+        | (Parent::Device(_), vk::ObjectType::EVENT)
+        | (Parent::Device(_), vk::ObjectType::QUERY_POOL)
+        | (Parent::Device(_), vk::ObjectType::BUFFER)
+        | (Parent::Device(_), vk::ObjectType::BUFFER_VIEW)
+        | (Parent::Device(_), vk::ObjectType::IMAGE)
+        | (Parent::Device(_), vk::ObjectType::IMAGE_VIEW)
+        | (Parent::Device(_), vk::ObjectType::SHADER_MODULE)
+        | (Parent::Device(_), vk::ObjectType::PIPELINE_CACHE)
+        | (Parent::Device(_), vk::ObjectType::PIPELINE)
+        | (Parent::Device(_), vk::ObjectType::PIPELINE_LAYOUT)
+        | (Parent::Device(_), vk::ObjectType::SAMPLER)
+        | (Parent::Device(_), vk::ObjectType::DESCRIPTOR_SET_LAYOUT)
+        | (Parent::Device(_), vk::ObjectType::DESCRIPTOR_POOL)
+        | (Parent::DescriptorPool(..), vk::ObjectType::DESCRIPTOR_SET)
+        | (Parent::Device(_), vk::ObjectType::FRAMEBUFFER)
+        | (Parent::Device(_), vk::ObjectType::RENDER_PASS)
+        | (Parent::Device(_), vk::ObjectType::COMMAND_POOL)
+        | (Parent::CommandPool(..), vk::ObjectType::COMMAND_BUFFER)
+        | (Parent::KhrSurface(_), vk::ObjectType::SURFACE_KHR)
+        | (Parent::KhrSwapchain(_), vk::ObjectType::SWAPCHAIN_KHR)
+        | (Parent::ExtDebugReport(_), vk::ObjectType::DEBUG_REPORT_CALLBACK_EXT)
+        | (Parent::KhrVideoQueue(_), vk::ObjectType::VIDEO_SESSION_KHR)
+        | (Parent::KhrVideoQueue(_), vk::ObjectType::VIDEO_SESSION_PARAMETERS_KHR)
+        | (Parent::NvxBinaryImport(_), vk::ObjectType::CU_MODULE_NVX)
+        | (Parent::NvxBinaryImport(_), vk::ObjectType::CU_FUNCTION_NVX)
+        | (Parent::KhrDescriptorUpdateTemplate(_), vk::ObjectType::DESCRIPTOR_UPDATE_TEMPLATE)
+        | (Parent::ExtDebugUtils(_), vk::ObjectType::DEBUG_UTILS_MESSENGER_EXT)
+        | (Parent::KhrAccelerationStructure(_), vk::ObjectType::ACCELERATION_STRUCTURE_KHR)
+        | (Parent::KhrSamplerYcbcrConversion(_), vk::ObjectType::SAMPLER_YCBCR_CONVERSION)
+        | (Parent::ExtValidationCache(_), vk::ObjectType::VALIDATION_CACHE_EXT)
+        | (Parent::NvRayTracing(_), vk::ObjectType::ACCELERATION_STRUCTURE_NV)
+        | (Parent::KhrDeferredHostOperations(_), vk::ObjectType::DEFERRED_OPERATION_KHR)
+        | (Parent::NvDeviceGeneratedCommands(_), vk::ObjectType::INDIRECT_COMMANDS_LAYOUT_NV)
+        | (Parent::ExtPrivateData(_), vk::ObjectType::PRIVATE_DATA_SLOT)
+        | (Parent::FuchsiaBufferCollection(_), vk::ObjectType::BUFFER_COLLECTION_FUCHSIA)
+        | (Parent::ExtOpacityMicromap(_), vk::ObjectType::MICROMAP_EXT)
+        | (Parent::NvOpticalFlow(_), vk::ObjectType::OPTICAL_FLOW_SESSION_NV)
+        | (Parent::ExtShaderObject(_), vk::ObjectType::SHADER_EXT) => true,
+        (Parent::Device(device), vk::ObjectType::SAMPLER_YCBCR_CONVERSION) => {
+            device.api_version() >= vk::API_VERSION_1_1
+        }
+        (Parent::Device(device), vk::ObjectType::DESCRIPTOR_UPDATE_TEMPLATE) => {
+            device.api_version() >= vk::API_VERSION_1_1
+        }
+        (Parent::Device(device), vk::ObjectType::PRIVATE_DATA_SLOT) => {
+            device.api_version() >= vk::API_VERSION_1_3
+        }
+        _ => false,
+    }
+}
+#[doc = r" Destroys or frees the handle `raw` of type `ty` through `parent`."]
+pub(super) unsafe fn destroy(parent: &Parent, ty: vk::ObjectType, raw: u64) {
+    match (parent, ty) {
+        (Parent::Device(device), vk::ObjectType::DEVICE_MEMORY) => {
+            (device.fp_v1_0().free_memory)(
+                device.handle(),
+                vk::DeviceMemory::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::FENCE) => {
+            (device.fp_v1_0().destroy_fence)(
+                device.handle(),
+                vk::Fence::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::SEMAPHORE) => {
+            (device.fp_v1_0().destroy_semaphore)(
+                device.handle(),
+                vk::Semaphore::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::EVENT) => {
+            (device.fp_v1_0().destroy_event)(
+                device.handle(),
+                vk::Event::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::QUERY_POOL) => {
+            (device.fp_v1_0().destroy_query_pool)(
+                device.handle(),
+                vk::QueryPool::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::BUFFER) => {
+            (device.fp_v1_0().destroy_buffer)(
+                device.handle(),
+                vk::Buffer::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::BUFFER_VIEW) => {
+            (device.fp_v1_0().destroy_buffer_view)(
+                device.handle(),
+                vk::BufferView::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::IMAGE) => {
+            (device.fp_v1_0().destroy_image)(
+                device.handle(),
+                vk::Image::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::IMAGE_VIEW) => {
+            (device.fp_v1_0().destroy_image_view)(
+                device.handle(),
+                vk::ImageView::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::SHADER_MODULE) => {
+            (device.fp_v1_0().destroy_shader_module)(
+                device.handle(),
+                vk::ShaderModule::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::PIPELINE_CACHE) => {
+            (device.fp_v1_0().destroy_pipeline_cache)(
+                device.handle(),
+                vk::PipelineCache::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::PIPELINE) => {
+            (device.fp_v1_0().destroy_pipeline)(
+                device.handle(),
+                vk::Pipeline::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::PIPELINE_LAYOUT) => {
+            (device.fp_v1_0().destroy_pipeline_layout)(
+                device.handle(),
+                vk::PipelineLayout::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::SAMPLER) => {
+            (device.fp_v1_0().destroy_sampler)(
+                device.handle(),
+                vk::Sampler::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::DESCRIPTOR_SET_LAYOUT) => {
+            (device.fp_v1_0().destroy_descriptor_set_layout)(
+                device.handle(),
+                vk::DescriptorSetLayout::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::DESCRIPTOR_POOL) => {
+            (device.fp_v1_0().destroy_descriptor_pool)(
+                device.handle(),
+                vk::DescriptorPool::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::DescriptorPool(device, pool), vk::ObjectType::DESCRIPTOR_SET) => {
+            let _ = (device.fp_v1_0().free_descriptor_sets)(
+                device.handle(),
+                *pool,
+                1,
+                &vk::DescriptorSet::from_raw(raw),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::FRAMEBUFFER) => {
+            (device.fp_v1_0().destroy_framebuffer)(
+                device.handle(),
+                vk::Framebuffer::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::RENDER_PASS) => {
+            (device.fp_v1_0().destroy_render_pass)(
+                device.handle(),
+                vk::RenderPass::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::COMMAND_POOL) => {
+            (device.fp_v1_0().destroy_command_pool)(
+                device.handle(),
+                vk::CommandPool::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::CommandPool(device, pool), vk::ObjectType::COMMAND_BUFFER) => {
+            (device.fp_v1_0().free_command_buffers)(
+                device.handle(),
+                *pool,
+                1,
+                &vk::CommandBuffer::from_raw(raw),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::SAMPLER_YCBCR_CONVERSION) => {
+            (device.fp_v1_1().destroy_sampler_ycbcr_conversion)(
+                device.handle(),
+                vk::SamplerYcbcrConversion::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::DESCRIPTOR_UPDATE_TEMPLATE) => {
+            (device.fp_v1_1().destroy_descriptor_update_template)(
+                device.handle(),
+                vk::DescriptorUpdateTemplate::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::Device(device), vk::ObjectType::PRIVATE_DATA_SLOT) => {
+            (device.fp_v1_3().destroy_private_data_slot)(
+                device.handle(),
+                vk::PrivateDataSlot::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::KhrSurface(wrapper), vk::ObjectType::SURFACE_KHR) => {
+            (wrapper.fp().destroy_surface_khr)(
+                wrapper.instance(),
+                vk::SurfaceKHR::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::KhrSwapchain(wrapper), vk::ObjectType::SWAPCHAIN_KHR) => {
+            (wrapper.fp().destroy_swapchain_khr)(
+                wrapper.device(),
+                vk::SwapchainKHR::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::ExtDebugReport(wrapper), vk::ObjectType::DEBUG_REPORT_CALLBACK_EXT) => {
+            (wrapper.fp().destroy_debug_report_callback_ext)(
+                wrapper.instance(),
+                vk::DebugReportCallbackEXT::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::KhrVideoQueue(wrapper), vk::ObjectType::VIDEO_SESSION_KHR) => {
+            (wrapper.fp().destroy_video_session_khr)(
+                wrapper.device(),
+                vk::VideoSessionKHR::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::KhrVideoQueue(wrapper), vk::ObjectType::VIDEO_SESSION_PARAMETERS_KHR) => {
+            (wrapper.fp().destroy_video_session_parameters_khr)(
+                wrapper.device(),
+                vk::VideoSessionParametersKHR::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::NvxBinaryImport(wrapper), vk::ObjectType::CU_MODULE_NVX) => {
+            (wrapper.fp().destroy_cu_module_nvx)(
+                wrapper.device(),
+                vk::CuModuleNVX::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::NvxBinaryImport(wrapper), vk::ObjectType::CU_FUNCTION_NVX) => {
+            (wrapper.fp().destroy_cu_function_nvx)(
+                wrapper.device(),
+                vk::CuFunctionNVX::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (
+            Parent::KhrDescriptorUpdateTemplate(wrapper),
+            vk::ObjectType::DESCRIPTOR_UPDATE_TEMPLATE,
+        ) => {
+            (wrapper.fp().destroy_descriptor_update_template_khr)(
+                wrapper.device(),
+                vk::DescriptorUpdateTemplate::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::ExtDebugUtils(wrapper), vk::ObjectType::DEBUG_UTILS_MESSENGER_EXT) => {
+            (wrapper.fp().destroy_debug_utils_messenger_ext)(
+                wrapper.instance(),
+                vk::DebugUtilsMessengerEXT::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::KhrAccelerationStructure(wrapper), vk::ObjectType::ACCELERATION_STRUCTURE_KHR) => {
+            (wrapper.fp().destroy_acceleration_structure_khr)(
+                wrapper.device(),
+                vk::AccelerationStructureKHR::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::KhrSamplerYcbcrConversion(wrapper), vk::ObjectType::SAMPLER_YCBCR_CONVERSION) => {
+            (wrapper.fp().destroy_sampler_ycbcr_conversion_khr)(
+                wrapper.device(),
+                vk::SamplerYcbcrConversion::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::ExtValidationCache(wrapper), vk::ObjectType::VALIDATION_CACHE_EXT) => {
+            (wrapper.fp().destroy_validation_cache_ext)(
+                wrapper.device(),
+                vk::ValidationCacheEXT::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::NvRayTracing(wrapper), vk::ObjectType::ACCELERATION_STRUCTURE_NV) => {
+            (wrapper.fp().destroy_acceleration_structure_nv)(
+                wrapper.device(),
+                vk::AccelerationStructureNV::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::KhrDeferredHostOperations(wrapper), vk::ObjectType::DEFERRED_OPERATION_KHR) => {
+            (wrapper.fp().destroy_deferred_operation_khr)(
+                wrapper.device(),
+                vk::DeferredOperationKHR::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (
+            Parent::NvDeviceGeneratedCommands(wrapper),
+            vk::ObjectType::INDIRECT_COMMANDS_LAYOUT_NV,
+        ) => {
+            (wrapper.fp().destroy_indirect_commands_layout_nv)(
+                wrapper.device(),
+                vk::IndirectCommandsLayoutNV::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::ExtPrivateData(wrapper), vk::ObjectType::PRIVATE_DATA_SLOT) => {
+            (wrapper.fp().destroy_private_data_slot_ext)(
+                wrapper.device(),
+                vk::PrivateDataSlot::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::FuchsiaBufferCollection(wrapper), vk::ObjectType::BUFFER_COLLECTION_FUCHSIA) => {
+            (wrapper.fp().destroy_buffer_collection_fuchsia)(
+                wrapper.device(),
+                vk::BufferCollectionFUCHSIA::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::ExtOpacityMicromap(wrapper), vk::ObjectType::MICROMAP_EXT) => {
+            (wrapper.fp().destroy_micromap_ext)(
+                wrapper.device(),
+                vk::MicromapEXT::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::NvOpticalFlow(wrapper), vk::ObjectType::OPTICAL_FLOW_SESSION_NV) => {
+            (wrapper.fp().destroy_optical_flow_session_nv)(
+                wrapper.device(),
+                vk::OpticalFlowSessionNV::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        (Parent::ExtShaderObject(wrapper), vk::ObjectType::SHADER_EXT) => {
+            (wrapper.fp().destroy_shader_ext)(
+                wrapper.device(),
+                vk::ShaderEXT::from_raw(raw),
+                ptr::null(),
+            );
+        }
+        _ => unreachable!("{:?} does not destroy {:?}", parent, ty),
+    }
+}
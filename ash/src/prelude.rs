@@ -1,7 +1,8 @@
 use std::convert::TryInto;
-#[cfg(feature = "debug")]
+use std::ffi::CStr;
 use std::fmt;
 use std::mem;
+use std::os::raw::{c_char, c_void};
 
 use crate::vk;
 pub type VkResult<T> = Result<T, vk::Result>;
@@ -20,12 +21,169 @@ impl vk::Result {
         }
     }
 
+    /// Converts the result of a command with several success codes into its
+    /// [`success_codes`][crate::success_codes] enum, returning other codes as errors.
+    #[inline]
+    pub fn success_code<C: TryFrom<Self, Error = Self>>(self) -> VkResult<C> {
+        C::try_from(self)
+    }
+
     #[inline]
     pub unsafe fn assume_init_on_success<T>(self, v: mem::MaybeUninit<T>) -> VkResult<T> {
         self.result().map(move |()| v.assume_init())
     }
 }
 
+/// The error returned by version views such as [`Instance::v1_1()`][crate::Instance::v1_1()] and
+/// [`Device::v1_3()`][crate::Device::v1_3()] when the instance or device was created for a lower
+/// Vulkan version than that of the commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ApiVersionError {
+    /// The version that the commands were added in.
+    pub required: u32,
+    /// The version that the instance or device was created for.
+    pub api_version: u32,
+}
+
+impl fmt::Display for ApiVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Vulkan {}.{} is required, but only {}.{} is available",
+            vk::api_version_major(self.required),
+            vk::api_version_minor(self.required),
+            vk::api_version_major(self.api_version),
+            vk::api_version_minor(self.api_version),
+        )
+    }
+}
+
+impl std::error::Error for ApiVersionError {}
+
+/// The error returned by the `try_new()` constructors of extension wrappers, such as
+/// [`khr::Swapchain::try_new()`][crate::extensions::khr::Swapchain::try_new()], when the
+/// extension or one of its dependencies was not enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MissingExtensionError {
+    /// The extension of the wrapper.
+    pub extension: &'static CStr,
+    /// The first [`vk::Depends::Extension`] or [`vk::Depends::Version`] that is not enabled,
+    /// which is [`vk::Depends::Extension`] of [`extension`][Self::extension] itself if that
+    /// was not enabled.
+    pub missing: vk::Depends,
+}
+
+impl fmt::Display for MissingExtensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let extension = self.extension.to_string_lossy();
+        match self.missing {
+            vk::Depends::Extension(name) if name == self.extension => {
+                write!(f, "{extension} is not enabled")
+            }
+            vk::Depends::Version(version) => write!(
+                f,
+                "{extension} requires Vulkan {}.{}",
+                vk::api_version_major(version),
+                vk::api_version_minor(version),
+            ),
+            missing => write!(f, "{extension} requires {missing}, which is not enabled"),
+        }
+    }
+}
+
+impl std::error::Error for MissingExtensionError {}
+
+/// Returns `Ok(())` if `extension` and its dependencies are enabled.
+pub(crate) fn require_extension(
+    extension: &'static CStr,
+    depends: &vk::Depends,
+    api_version: u32,
+    is_enabled: &dyn Fn(&CStr) -> bool,
+) -> Result<(), MissingExtensionError> {
+    let missing = if is_enabled(extension) {
+        depends.missing(api_version, is_enabled)
+    } else {
+        Some(vk::Depends::Extension(extension))
+    };
+    match missing {
+        Some(missing) => Err(MissingExtensionError { extension, missing }),
+        None => Ok(()),
+    }
+}
+
+/// Returns `Ok(())` if `api_version` is at least `required`, ignoring patch versions.
+pub(crate) fn require_version(api_version: u32, required: u32) -> Result<(), ApiVersionError> {
+    let minor = |version| {
+        vk::make_api_version(
+            0,
+            vk::api_version_major(version),
+            vk::api_version_minor(version),
+            0,
+        )
+    };
+    if minor(api_version) >= minor(required) {
+        Ok(())
+    } else {
+        Err(ApiVersionError {
+            required,
+            api_version,
+        })
+    }
+}
+
+/// Resolves the command `name` for `handle`, the dispatchable handle it is loaded with or a null
+/// handle for global commands, with `load`, applying the installed [`hooks`][crate::hooks].
+#[inline]
+pub(crate) unsafe fn resolve_command(
+    name: &CStr,
+    handle: impl vk::Handle,
+    load: impl FnOnce() -> *const c_void,
+) -> *const c_void {
+    #[cfg(feature = "hooks")]
+    {
+        crate::hooks::resolve(name, handle.as_raw(), load)
+    }
+    #[cfg(not(feature = "hooks"))]
+    {
+        let _ = (name, handle);
+        load()
+    }
+}
+
+/// The dispatch table pointer that the loader stores at the start of every dispatchable object,
+/// which is shared by an instance and its physical devices, and by a device and its queues and
+/// command buffers. Null handles have the key `0`, like the commands that take no dispatchable
+/// handle.
+///
+/// # Safety
+///
+/// `handle` must be null or the raw value of a valid dispatchable handle.
+#[cfg(any(
+    feature = "hooks",
+    feature = "layer",
+    feature = "trace",
+    feature = "track"
+))]
+pub(crate) unsafe fn dispatch_key(handle: u64) -> usize {
+    if handle == 0 {
+        0
+    } else {
+        *(handle as usize as *const usize)
+    }
+}
+
+/// Borrows `count` strings from a `pp_*_names` member such as
+/// [`vk::InstanceCreateInfo::pp_enabled_extension_names`].
+pub(crate) unsafe fn c_str_array<'a>(count: u32, names: *const *const c_char) -> Vec<&'a CStr> {
+    if count == 0 {
+        return Vec::new();
+    }
+    std::slice::from_raw_parts(names, count as usize)
+        .iter()
+        .map(|&name| CStr::from_ptr(name))
+        .collect()
+}
+
 /// Repeatedly calls `f` until it does not return [`vk::Result::INCOMPLETE`] anymore, ensuring all
 /// available data has been read into the vector.
 ///
@@ -91,15 +249,26 @@ where
     }
 }
 
+/// The known values of an enum or flags type, as their raw value, the name of their constant and
+/// their name in the Vulkan specification.
+pub(crate) type Known<Value> = &'static [(Value, &'static str, &'static str)];
+
+pub(crate) fn debug_enum(f: &mut fmt::Formatter, known: Known<i32>, value: i32) -> fmt::Result {
+    match known.iter().find(|&&(known, _, _)| known == value) {
+        Some(&(_, name, _)) => f.write_str(name),
+        None => fmt::Debug::fmt(&value, f),
+    }
+}
+
 #[cfg(feature = "debug")]
 pub(crate) fn debug_flags<Value: Into<u64> + Copy>(
     f: &mut fmt::Formatter,
-    known: &[(Value, &'static str)],
+    known: Known<Value>,
     value: Value,
 ) -> fmt::Result {
     let mut first = true;
     let mut accum = value.into();
-    for &(bit, name) in known {
+    for &(bit, name, _) in known {
         let bit = bit.into();
         if bit != 0 && accum & bit == bit {
             if !first {
@@ -118,3 +287,49 @@ pub(crate) fn debug_flags<Value: Into<u64> + Copy>(
     }
     Ok(())
 }
+
+/// Parses the value of an enum from the name of its constant, its name in the Vulkan
+/// specification or its raw value.
+pub(crate) fn parse_enum(
+    type_name: &'static str,
+    known: Known<i32>,
+    s: &str,
+) -> Result<i32, vk::ParseValueError> {
+    let s = s.trim();
+    known
+        .iter()
+        .find(|&&(_, name, spec_name)| s == name || s == spec_name)
+        .map(|&(value, _, _)| value)
+        .or_else(|| s.parse().ok())
+        .ok_or_else(|| vk::ParseValueError::new(type_name, s))
+}
+
+/// Parses flags from the names or raw values of their bits, separated by `|`.
+pub(crate) fn parse_flags<Value: Into<u64> + TryFrom<u64> + Copy>(
+    type_name: &'static str,
+    known: Known<Value>,
+    s: &str,
+) -> Result<Value, vk::ParseValueError> {
+    let mut bits = 0;
+    for s in s.split('|').map(str::trim).filter(|s| !s.is_empty()) {
+        let known = known
+            .iter()
+            .find(|&&(_, name, spec_name)| s == name || s == spec_name);
+        bits |= match known {
+            Some(&(bit, _, _)) => bit.into(),
+            None => parse_bits(s).ok_or_else(|| vk::ParseValueError::new(type_name, s))?,
+        };
+    }
+    Value::try_from(bits).map_err(|_| vk::ParseValueError::new(type_name, s.trim()))
+}
+
+/// Parses a raw flags value, in hexadecimal or binary with a `0x` or `0b` prefix, or in decimal.
+fn parse_bits(s: &str) -> Option<u64> {
+    if let Some(hex) = s.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16).ok()
+    } else if let Some(binary) = s.strip_prefix("0b") {
+        u64::from_str_radix(binary, 2).ok()
+    } else {
+        s.parse().ok()
+    }
+}
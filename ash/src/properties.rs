@@ -0,0 +1,220 @@
+//! A snapshot of every struct of physical device properties known to `vk.xml`.
+//!
+//! [`Properties::query()`] chains every struct that extends [`vk::PhysicalDeviceProperties2`]
+//! and that the physical device supports, such as [`vk::PhysicalDeviceVulkan12Properties`] or
+//! [`vk::PhysicalDeviceRayTracingPipelinePropertiesKHR`], into a single call, and keeps an owned
+//! copy of them:
+//!
+//! ```no_run
+//! # use ash::{properties::Properties, vk};
+//! # unsafe fn f(instance: &ash::Instance, physical_devices: &[vk::PhysicalDevice]) -> ash::prelude::VkResult<()> {
+//! let a = Properties::query(instance, physical_devices[0])?;
+//! let b = Properties::query(instance, physical_devices[1])?;
+//! println!("{:?}", a.properties().device_name_as_c_str());
+//! if let Some(ray_tracing) = a.get::<vk::PhysicalDeviceRayTracingPipelinePropertiesKHR>() {
+//!     println!("{}", ray_tracing.max_ray_recursion_depth);
+//! }
+//! for difference in a.diff(&b) {
+//!     println!("{difference}");
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! With the **serde** feature, [`Properties`] is serialized as a [`vk::PhysicalDeviceProperties2`]
+//! with the queried structs in its `p_next` chain.
+
+use crate::prelude::VkResult;
+use crate::{vk, Instance};
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::c_char;
+use std::ptr;
+
+mod generated;
+
+use generated::{Structs, COUNT, DEPENDS};
+
+/// A struct of properties owned by [`Properties`]: [`vk::PhysicalDeviceProperties`] or a struct
+/// that extends [`vk::PhysicalDeviceProperties2`].
+pub trait Property {
+    /// Returns the struct, or `None` if it was not queried.
+    fn get(properties: &Properties) -> Option<&Self>;
+}
+
+/// The properties of a physical device, see the [module documentation][self].
+#[derive(Clone)]
+pub struct Properties {
+    structs: Structs,
+    /// Whether each struct was filled in by the physical device.
+    queried: [bool; COUNT],
+}
+
+// The `p_next` pointers are null outside of `Properties::query()` and serialization
+unsafe impl Send for Properties {}
+unsafe impl Sync for Properties {}
+
+impl fmt::Debug for Properties {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+        self.structs.zip(&self.structs, |index, name, _, value, _| {
+            if self.queried[index] {
+                map.entry(&name, value);
+            }
+        });
+        map.finish()
+    }
+}
+
+impl Properties {
+    /// Returns the properties of a physical device.
+    ///
+    /// Only the structs that the physical device supports are queried, according to the version
+    /// of `instance` and of the physical device, and the device extensions it supports. Instances
+    /// created for Vulkan 1.0 only query [`vk::PhysicalDeviceProperties`].
+    ///
+    /// <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2.html>
+    pub unsafe fn query(
+        instance: &Instance,
+        physical_device: vk::PhysicalDevice,
+    ) -> VkResult<Self> {
+        let mut properties = Self {
+            structs: Structs::default(),
+            queried: [false; COUNT],
+        };
+        properties.queried[0] = true;
+        properties.structs.physical_device_properties2.properties =
+            instance.get_physical_device_properties(physical_device);
+        let instance_1_1 = match instance.v1_1() {
+            Ok(instance_1_1) => instance_1_1,
+            Err(_) => return Ok(properties),
+        };
+
+        let api_version = instance
+            .api_version()
+            .min(properties.properties().api_version);
+        let extensions = instance.enumerate_device_extension_properties(physical_device)?;
+        let is_supported = |name: &CStr| {
+            extensions
+                .iter()
+                .any(|extension| extension.extension_name_as_c_str() == Some(name))
+        };
+        for (queried, depends) in properties.queried.iter_mut().zip(&DEPENDS) {
+            *queried = depends.missing(api_version, &is_supported).is_none();
+        }
+        properties.link();
+        instance_1_1.get_physical_device_properties2(
+            physical_device,
+            &mut properties.structs.physical_device_properties2,
+        );
+        properties.unlink();
+        Ok(properties)
+    }
+
+    /// The core properties of the physical device.
+    pub fn properties(&self) -> &vk::PhysicalDeviceProperties {
+        &self.structs.physical_device_properties2.properties
+    }
+
+    /// Returns the struct of properties of type `T`, or `None` if the physical device does not
+    /// support it.
+    pub fn get<T: Property>(&self) -> Option<&T> {
+        T::get(self)
+    }
+
+    /// Returns the properties whose values differ between `self` and `other`, named by the struct
+    /// and member in the specification, such as
+    /// `"VkPhysicalDeviceProperties::limits.maxImageDimension2D"`.
+    ///
+    /// Every property of a struct that was only queried on one side is returned.
+    pub fn diff(&self, other: &Self) -> Vec<Difference> {
+        let mut differences = Vec::new();
+        self.structs
+            .zip(&other.structs, |index, name, equal, left, right| {
+                let (in_left, in_right) = (self.queried[index], other.queried[index]);
+                if (in_left || in_right) && !(in_left && in_right && equal) {
+                    differences.push(Difference {
+                        name,
+                        left: in_left.then(|| format!("{left:?}")),
+                        right: in_right.then(|| format!("{right:?}")),
+                    });
+                }
+            });
+        differences
+    }
+
+    /// Links the queried structs behind [`vk::PhysicalDeviceProperties2`].
+    pub(crate) fn link(&mut self) -> &mut vk::PhysicalDeviceProperties2<'static> {
+        let bases = self.structs.bases();
+        let mut last = bases[0];
+        for (&base, _) in bases.iter().zip(&self.queried).skip(1).filter(|(_, &q)| q) {
+            unsafe { (*last).p_next = base };
+            last = base;
+        }
+        unsafe { (*last).p_next = ptr::null_mut() };
+        &mut self.structs.physical_device_properties2
+    }
+
+    fn unlink(&mut self) {
+        for base in self.structs.bases() {
+            unsafe { (*base).p_next = ptr::null_mut() };
+        }
+    }
+
+    /// Copies the structs of properties in the `p_next` chain of `properties2`, skipping other
+    /// structs.
+    #[cfg(feature = "serde")]
+    pub(crate) unsafe fn from_chain(properties2: &vk::PhysicalDeviceProperties2<'_>) -> Self {
+        let mut properties = Self {
+            structs: Structs::default(),
+            queried: [false; COUNT],
+        };
+        properties.queried[0] = true;
+        properties.structs.physical_device_properties2.properties = properties2.properties;
+        let mut next = properties2.p_next.cast::<vk::BaseOutStructure<'_>>();
+        while let Some(base) = next.as_ref() {
+            if let Some(index) = properties.structs.read(next) {
+                properties.queried[index] = true;
+            }
+            next = base.p_next;
+        }
+        properties
+    }
+}
+
+/// A property whose value differs between two [`Properties`], see [`Properties::diff()`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Difference {
+    /// The struct and member in the specification.
+    pub name: &'static str,
+    /// The value on the left, formatted with [`fmt::Debug`], or `None` if the struct was not
+    /// queried.
+    pub left: Option<String>,
+    /// The value on the right, formatted with [`fmt::Debug`], or `None` if the struct was not
+    /// queried.
+    pub right: Option<String>,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = |value: &Option<String>| value.clone().unwrap_or_else(|| "unsupported".into());
+        write!(
+            f,
+            "{}: {} != {}",
+            self.name,
+            side(&self.left),
+            side(&self.right)
+        )
+    }
+}
+
+/// Formats a fixed-size string member as a string.
+struct CStrArray<'a>(&'a [c_char]);
+
+impl fmt::Debug for CStrArray<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match vk::wrap_c_str_slice_until_nul(self.0) {
+            Some(str) => fmt::Debug::fmt(str, f),
+            None => fmt::Debug::fmt(self.0, f),
+        }
+    }
+}
@@ -0,0 +1,9710 @@
+use super::*;
+pub(super) const COUNT: usize = 75usize;
+#[derive(Clone, Default)]
+pub(super) struct Structs {
+    pub(super) physical_device_properties2: vk::PhysicalDeviceProperties2<'static>,
+    pub(super) physical_device_device_generated_commands_properties_nv:
+        vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>,
+    pub(super) physical_device_multi_draw_properties_ext:
+        vk::PhysicalDeviceMultiDrawPropertiesEXT<'static>,
+    pub(super) physical_device_push_descriptor_properties_khr:
+        vk::PhysicalDevicePushDescriptorPropertiesKHR<'static>,
+    pub(super) physical_device_driver_properties: vk::PhysicalDeviceDriverProperties<'static>,
+    pub(super) physical_device_id_properties: vk::PhysicalDeviceIDProperties<'static>,
+    pub(super) physical_device_multiview_properties: vk::PhysicalDeviceMultiviewProperties<'static>,
+    pub(super) physical_device_discard_rectangle_properties_ext:
+        vk::PhysicalDeviceDiscardRectanglePropertiesEXT<'static>,
+    pub(super) physical_device_multiview_per_view_attributes_properties_nvx:
+        vk::PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX<'static>,
+    pub(super) physical_device_subgroup_properties: vk::PhysicalDeviceSubgroupProperties<'static>,
+    pub(super) physical_device_point_clipping_properties:
+        vk::PhysicalDevicePointClippingProperties<'static>,
+    pub(super) physical_device_protected_memory_properties:
+        vk::PhysicalDeviceProtectedMemoryProperties<'static>,
+    pub(super) physical_device_sampler_filter_minmax_properties:
+        vk::PhysicalDeviceSamplerFilterMinmaxProperties<'static>,
+    pub(super) physical_device_sample_locations_properties_ext:
+        vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static>,
+    pub(super) physical_device_blend_operation_advanced_properties_ext:
+        vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT<'static>,
+    pub(super) physical_device_inline_uniform_block_properties:
+        vk::PhysicalDeviceInlineUniformBlockProperties<'static>,
+    pub(super) physical_device_maintenance3_properties:
+        vk::PhysicalDeviceMaintenance3Properties<'static>,
+    pub(super) physical_device_maintenance4_properties:
+        vk::PhysicalDeviceMaintenance4Properties<'static>,
+    pub(super) physical_device_float_controls_properties:
+        vk::PhysicalDeviceFloatControlsProperties<'static>,
+    pub(super) physical_device_external_memory_host_properties_ext:
+        vk::PhysicalDeviceExternalMemoryHostPropertiesEXT<'static>,
+    pub(super) physical_device_conservative_rasterization_properties_ext:
+        vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>,
+    pub(super) physical_device_shader_core_properties_amd:
+        vk::PhysicalDeviceShaderCorePropertiesAMD<'static>,
+    pub(super) physical_device_shader_core_properties2_amd:
+        vk::PhysicalDeviceShaderCoreProperties2AMD<'static>,
+    pub(super) physical_device_descriptor_indexing_properties:
+        vk::PhysicalDeviceDescriptorIndexingProperties<'static>,
+    pub(super) physical_device_timeline_semaphore_properties:
+        vk::PhysicalDeviceTimelineSemaphoreProperties<'static>,
+    pub(super) physical_device_vertex_attribute_divisor_properties_ext:
+        vk::PhysicalDeviceVertexAttributeDivisorPropertiesEXT<'static>,
+    pub(super) physical_device_pci_bus_info_properties_ext:
+        vk::PhysicalDevicePCIBusInfoPropertiesEXT<'static>,
+    pub(super) physical_device_depth_stencil_resolve_properties:
+        vk::PhysicalDeviceDepthStencilResolveProperties<'static>,
+    pub(super) physical_device_transform_feedback_properties_ext:
+        vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>,
+    pub(super) physical_device_copy_memory_indirect_properties_nv:
+        vk::PhysicalDeviceCopyMemoryIndirectPropertiesNV<'static>,
+    pub(super) physical_device_memory_decompression_properties_nv:
+        vk::PhysicalDeviceMemoryDecompressionPropertiesNV<'static>,
+    pub(super) physical_device_shading_rate_image_properties_nv:
+        vk::PhysicalDeviceShadingRateImagePropertiesNV<'static>,
+    pub(super) physical_device_mesh_shader_properties_nv:
+        vk::PhysicalDeviceMeshShaderPropertiesNV<'static>,
+    pub(super) physical_device_mesh_shader_properties_ext:
+        vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>,
+    pub(super) physical_device_acceleration_structure_properties_khr:
+        vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>,
+    pub(super) physical_device_ray_tracing_pipeline_properties_khr:
+        vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>,
+    pub(super) physical_device_ray_tracing_properties_nv:
+        vk::PhysicalDeviceRayTracingPropertiesNV<'static>,
+    pub(super) physical_device_fragment_density_map_properties_ext:
+        vk::PhysicalDeviceFragmentDensityMapPropertiesEXT<'static>,
+    pub(super) physical_device_fragment_density_map2_properties_ext:
+        vk::PhysicalDeviceFragmentDensityMap2PropertiesEXT<'static>,
+    pub(super) physical_device_fragment_density_map_offset_properties_qcom:
+        vk::PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM<'static>,
+    pub(super) physical_device_cooperative_matrix_properties_nv:
+        vk::PhysicalDeviceCooperativeMatrixPropertiesNV<'static>,
+    pub(super) physical_device_performance_query_properties_khr:
+        vk::PhysicalDevicePerformanceQueryPropertiesKHR<'static>,
+    pub(super) physical_device_shader_sm_builtins_properties_nv:
+        vk::PhysicalDeviceShaderSMBuiltinsPropertiesNV<'static>,
+    pub(super) physical_device_texel_buffer_alignment_properties:
+        vk::PhysicalDeviceTexelBufferAlignmentProperties<'static>,
+    pub(super) physical_device_subgroup_size_control_properties:
+        vk::PhysicalDeviceSubgroupSizeControlProperties<'static>,
+    pub(super) physical_device_subpass_shading_properties_huawei:
+        vk::PhysicalDeviceSubpassShadingPropertiesHUAWEI<'static>,
+    pub(super) physical_device_cluster_culling_shader_properties_huawei:
+        vk::PhysicalDeviceClusterCullingShaderPropertiesHUAWEI<'static>,
+    pub(super) physical_device_line_rasterization_properties_ext:
+        vk::PhysicalDeviceLineRasterizationPropertiesEXT<'static>,
+    pub(super) physical_device_vulkan11_properties: vk::PhysicalDeviceVulkan11Properties<'static>,
+    pub(super) physical_device_vulkan12_properties: vk::PhysicalDeviceVulkan12Properties<'static>,
+    pub(super) physical_device_vulkan13_properties: vk::PhysicalDeviceVulkan13Properties<'static>,
+    pub(super) physical_device_custom_border_color_properties_ext:
+        vk::PhysicalDeviceCustomBorderColorPropertiesEXT<'static>,
+    pub(super) physical_device_extended_dynamic_state3_properties_ext:
+        vk::PhysicalDeviceExtendedDynamicState3PropertiesEXT<'static>,
+    pub(super) physical_device_robustness2_properties_ext:
+        vk::PhysicalDeviceRobustness2PropertiesEXT<'static>,
+    pub(super) physical_device_portability_subset_properties_khr:
+        vk::PhysicalDevicePortabilitySubsetPropertiesKHR<'static>,
+    pub(super) physical_device_fragment_shading_rate_properties_khr:
+        vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>,
+    pub(super) physical_device_fragment_shading_rate_enums_properties_nv:
+        vk::PhysicalDeviceFragmentShadingRateEnumsPropertiesNV<'static>,
+    pub(super) physical_device_provoking_vertex_properties_ext:
+        vk::PhysicalDeviceProvokingVertexPropertiesEXT<'static>,
+    pub(super) physical_device_descriptor_buffer_properties_ext:
+        vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>,
+    pub(super) physical_device_descriptor_buffer_density_map_properties_ext:
+        vk::PhysicalDeviceDescriptorBufferDensityMapPropertiesEXT<'static>,
+    pub(super) physical_device_shader_integer_dot_product_properties:
+        vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>,
+    pub(super) physical_device_drm_properties_ext: vk::PhysicalDeviceDrmPropertiesEXT<'static>,
+    pub(super) physical_device_fragment_shader_barycentric_properties_khr:
+        vk::PhysicalDeviceFragmentShaderBarycentricPropertiesKHR<'static>,
+    pub(super) physical_device_graphics_pipeline_library_properties_ext:
+        vk::PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT<'static>,
+    pub(super) physical_device_shader_module_identifier_properties_ext:
+        vk::PhysicalDeviceShaderModuleIdentifierPropertiesEXT<'static>,
+    pub(super) physical_device_opacity_micromap_properties_ext:
+        vk::PhysicalDeviceOpacityMicromapPropertiesEXT<'static>,
+    pub(super) physical_device_displacement_micromap_properties_nv:
+        vk::PhysicalDeviceDisplacementMicromapPropertiesNV<'static>,
+    pub(super) physical_device_pipeline_robustness_properties_ext:
+        vk::PhysicalDevicePipelineRobustnessPropertiesEXT<'static>,
+    pub(super) physical_device_image_processing_properties_qcom:
+        vk::PhysicalDeviceImageProcessingPropertiesQCOM<'static>,
+    pub(super) physical_device_optical_flow_properties_nv:
+        vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>,
+    pub(super) physical_device_shader_core_builtins_properties_arm:
+        vk::PhysicalDeviceShaderCoreBuiltinsPropertiesARM<'static>,
+    pub(super) physical_device_ray_tracing_invocation_reorder_properties_nv:
+        vk::PhysicalDeviceRayTracingInvocationReorderPropertiesNV<'static>,
+    pub(super) physical_device_shader_core_properties_arm:
+        vk::PhysicalDeviceShaderCorePropertiesARM<'static>,
+    pub(super) physical_device_shader_object_properties_ext:
+        vk::PhysicalDeviceShaderObjectPropertiesEXT<'static>,
+    pub(super) physical_device_shader_tile_image_properties_ext:
+        vk::PhysicalDeviceShaderTileImagePropertiesEXT<'static>,
+}
+#[doc = r" The versions and extensions that provide each struct."]
+pub(super) const DEPENDS: [vk::Depends; COUNT] = [
+    vk::Depends::NONE,
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_device_generated_commands\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_multi_draw\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_push_descriptor\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_driver_properties\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_external_memory_capabilities\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_external_semaphore_capabilities\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_fence_capabilities\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_multiview\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_discard_rectangles\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NVX_multiview_per_view_attributes\0")
+    }),
+    crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance2\0")
+        }),
+    ]),
+    crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_sampler_filter_minmax\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_sample_locations\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_blend_operation_advanced\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_inline_uniform_block\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance3\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance4\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_float_controls\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_external_memory_host\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_conservative_rasterization\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_shader_core_properties\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_shader_core_properties2\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_descriptor_indexing\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_timeline_semaphore\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_vertex_attribute_divisor\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pci_bus_info\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_depth_stencil_resolve\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_transform_feedback\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_copy_memory_indirect\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_memory_decompression\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_shading_rate_image\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_mesh_shader\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_mesh_shader\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_acceleration_structure\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_pipeline\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_ray_tracing\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_fragment_density_map\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_fragment_density_map2\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_fragment_density_map_offset\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_cooperative_matrix\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_performance_query\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_shader_sm_builtins\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_texel_buffer_alignment\0")
+        }),
+    ]),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_subgroup_size_control\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_HUAWEI_subpass_shading\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_HUAWEI_cluster_culling_shader\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_line_rasterization\0")
+    }),
+    crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+    crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+    crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_custom_border_color\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_extended_dynamic_state3\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_robustness2\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_portability_subset\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_fragment_shading_rate\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_fragment_shading_rate_enums\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_provoking_vertex\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_descriptor_buffer\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_descriptor_buffer\0")
+    }),
+    crate::vk::Depends::Any(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_integer_dot_product\0")
+        }),
+    ]),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_physical_device_drm\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_fragment_shader_barycentric\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_graphics_pipeline_library\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_module_identifier\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_opacity_micromap\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_displacement_micromap\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_robustness\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_image_processing\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_optical_flow\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_ARM_shader_core_builtins\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_ray_tracing_invocation_reorder\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_ARM_shader_core_properties\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_object\0")
+    }),
+    crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_tile_image\0")
+    }),
+];
+impl Structs {
+    pub(super) fn bases(&mut self) -> [*mut vk::BaseOutStructure<'static>; COUNT] {
+        [
+            <*mut _>::cast(&mut self.physical_device_properties2),
+            <*mut _>::cast(&mut self.physical_device_device_generated_commands_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_multi_draw_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_push_descriptor_properties_khr),
+            <*mut _>::cast(&mut self.physical_device_driver_properties),
+            <*mut _>::cast(&mut self.physical_device_id_properties),
+            <*mut _>::cast(&mut self.physical_device_multiview_properties),
+            <*mut _>::cast(&mut self.physical_device_discard_rectangle_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_multiview_per_view_attributes_properties_nvx),
+            <*mut _>::cast(&mut self.physical_device_subgroup_properties),
+            <*mut _>::cast(&mut self.physical_device_point_clipping_properties),
+            <*mut _>::cast(&mut self.physical_device_protected_memory_properties),
+            <*mut _>::cast(&mut self.physical_device_sampler_filter_minmax_properties),
+            <*mut _>::cast(&mut self.physical_device_sample_locations_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_blend_operation_advanced_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_inline_uniform_block_properties),
+            <*mut _>::cast(&mut self.physical_device_maintenance3_properties),
+            <*mut _>::cast(&mut self.physical_device_maintenance4_properties),
+            <*mut _>::cast(&mut self.physical_device_float_controls_properties),
+            <*mut _>::cast(&mut self.physical_device_external_memory_host_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_conservative_rasterization_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_shader_core_properties_amd),
+            <*mut _>::cast(&mut self.physical_device_shader_core_properties2_amd),
+            <*mut _>::cast(&mut self.physical_device_descriptor_indexing_properties),
+            <*mut _>::cast(&mut self.physical_device_timeline_semaphore_properties),
+            <*mut _>::cast(&mut self.physical_device_vertex_attribute_divisor_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_pci_bus_info_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_depth_stencil_resolve_properties),
+            <*mut _>::cast(&mut self.physical_device_transform_feedback_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_copy_memory_indirect_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_memory_decompression_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_shading_rate_image_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_mesh_shader_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_mesh_shader_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_acceleration_structure_properties_khr),
+            <*mut _>::cast(&mut self.physical_device_ray_tracing_pipeline_properties_khr),
+            <*mut _>::cast(&mut self.physical_device_ray_tracing_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_fragment_density_map_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_fragment_density_map2_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_fragment_density_map_offset_properties_qcom),
+            <*mut _>::cast(&mut self.physical_device_cooperative_matrix_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_performance_query_properties_khr),
+            <*mut _>::cast(&mut self.physical_device_shader_sm_builtins_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_texel_buffer_alignment_properties),
+            <*mut _>::cast(&mut self.physical_device_subgroup_size_control_properties),
+            <*mut _>::cast(&mut self.physical_device_subpass_shading_properties_huawei),
+            <*mut _>::cast(&mut self.physical_device_cluster_culling_shader_properties_huawei),
+            <*mut _>::cast(&mut self.physical_device_line_rasterization_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_vulkan11_properties),
+            <*mut _>::cast(&mut self.physical_device_vulkan12_properties),
+            <*mut _>::cast(&mut self.physical_device_vulkan13_properties),
+            <*mut _>::cast(&mut self.physical_device_custom_border_color_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_extended_dynamic_state3_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_robustness2_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_portability_subset_properties_khr),
+            <*mut _>::cast(&mut self.physical_device_fragment_shading_rate_properties_khr),
+            <*mut _>::cast(&mut self.physical_device_fragment_shading_rate_enums_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_provoking_vertex_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_descriptor_buffer_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_descriptor_buffer_density_map_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_shader_integer_dot_product_properties),
+            <*mut _>::cast(&mut self.physical_device_drm_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_fragment_shader_barycentric_properties_khr),
+            <*mut _>::cast(&mut self.physical_device_graphics_pipeline_library_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_shader_module_identifier_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_opacity_micromap_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_displacement_micromap_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_pipeline_robustness_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_image_processing_properties_qcom),
+            <*mut _>::cast(&mut self.physical_device_optical_flow_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_shader_core_builtins_properties_arm),
+            <*mut _>::cast(&mut self.physical_device_ray_tracing_invocation_reorder_properties_nv),
+            <*mut _>::cast(&mut self.physical_device_shader_core_properties_arm),
+            <*mut _>::cast(&mut self.physical_device_shader_object_properties_ext),
+            <*mut _>::cast(&mut self.physical_device_shader_tile_image_properties_ext),
+        ]
+    }
+    #[doc = r" Copies the struct of properties at `ptr`, returning its index."]
+    #[cfg(feature = "serde")]
+    pub(super) unsafe fn read(&mut self, ptr: *const vk::BaseOutStructure<'_>) -> Option<usize> {
+        use crate::vk::TaggedStructure;
+        match (*ptr).s_type {
+            vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_device_generated_commands_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_device_generated_commands_properties_nv
+                    .p_next = ptr::null_mut();
+                Some(1usize)
+            }
+            vk::PhysicalDeviceMultiDrawPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_multi_draw_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceMultiDrawPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_multi_draw_properties_ext.p_next = ptr::null_mut();
+                Some(2usize)
+            }
+            vk::PhysicalDevicePushDescriptorPropertiesKHR::STRUCTURE_TYPE => {
+                self.physical_device_push_descriptor_properties_khr = ptr
+                    .cast::<vk::PhysicalDevicePushDescriptorPropertiesKHR<'static>>()
+                    .read();
+                self.physical_device_push_descriptor_properties_khr.p_next = ptr::null_mut();
+                Some(3usize)
+            }
+            vk::PhysicalDeviceDriverProperties::STRUCTURE_TYPE => {
+                self.physical_device_driver_properties = ptr
+                    .cast::<vk::PhysicalDeviceDriverProperties<'static>>()
+                    .read();
+                self.physical_device_driver_properties.p_next = ptr::null_mut();
+                Some(4usize)
+            }
+            vk::PhysicalDeviceIDProperties::STRUCTURE_TYPE => {
+                self.physical_device_id_properties =
+                    ptr.cast::<vk::PhysicalDeviceIDProperties<'static>>().read();
+                self.physical_device_id_properties.p_next = ptr::null_mut();
+                Some(5usize)
+            }
+            vk::PhysicalDeviceMultiviewProperties::STRUCTURE_TYPE => {
+                self.physical_device_multiview_properties = ptr
+                    .cast::<vk::PhysicalDeviceMultiviewProperties<'static>>()
+                    .read();
+                self.physical_device_multiview_properties.p_next = ptr::null_mut();
+                Some(6usize)
+            }
+            vk::PhysicalDeviceDiscardRectanglePropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_discard_rectangle_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceDiscardRectanglePropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_discard_rectangle_properties_ext.p_next = ptr::null_mut();
+                Some(7usize)
+            }
+            vk::PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX::STRUCTURE_TYPE => {
+                self.physical_device_multiview_per_view_attributes_properties_nvx = ptr
+                    .cast::<vk::PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX<'static>>()
+                    .read();
+                self.physical_device_multiview_per_view_attributes_properties_nvx
+                    .p_next = ptr::null_mut();
+                Some(8usize)
+            }
+            vk::PhysicalDeviceSubgroupProperties::STRUCTURE_TYPE => {
+                self.physical_device_subgroup_properties = ptr
+                    .cast::<vk::PhysicalDeviceSubgroupProperties<'static>>()
+                    .read();
+                self.physical_device_subgroup_properties.p_next = ptr::null_mut();
+                Some(9usize)
+            }
+            vk::PhysicalDevicePointClippingProperties::STRUCTURE_TYPE => {
+                self.physical_device_point_clipping_properties = ptr
+                    .cast::<vk::PhysicalDevicePointClippingProperties<'static>>()
+                    .read();
+                self.physical_device_point_clipping_properties.p_next = ptr::null_mut();
+                Some(10usize)
+            }
+            vk::PhysicalDeviceProtectedMemoryProperties::STRUCTURE_TYPE => {
+                self.physical_device_protected_memory_properties = ptr
+                    .cast::<vk::PhysicalDeviceProtectedMemoryProperties<'static>>()
+                    .read();
+                self.physical_device_protected_memory_properties.p_next = ptr::null_mut();
+                Some(11usize)
+            }
+            vk::PhysicalDeviceSamplerFilterMinmaxProperties::STRUCTURE_TYPE => {
+                self.physical_device_sampler_filter_minmax_properties = ptr
+                    .cast::<vk::PhysicalDeviceSamplerFilterMinmaxProperties<'static>>()
+                    .read();
+                self.physical_device_sampler_filter_minmax_properties.p_next = ptr::null_mut();
+                Some(12usize)
+            }
+            vk::PhysicalDeviceSampleLocationsPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_sample_locations_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_sample_locations_properties_ext.p_next = ptr::null_mut();
+                Some(13usize)
+            }
+            vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_blend_operation_advanced_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_blend_operation_advanced_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(14usize)
+            }
+            vk::PhysicalDeviceInlineUniformBlockProperties::STRUCTURE_TYPE => {
+                self.physical_device_inline_uniform_block_properties = ptr
+                    .cast::<vk::PhysicalDeviceInlineUniformBlockProperties<'static>>()
+                    .read();
+                self.physical_device_inline_uniform_block_properties.p_next = ptr::null_mut();
+                Some(15usize)
+            }
+            vk::PhysicalDeviceMaintenance3Properties::STRUCTURE_TYPE => {
+                self.physical_device_maintenance3_properties = ptr
+                    .cast::<vk::PhysicalDeviceMaintenance3Properties<'static>>()
+                    .read();
+                self.physical_device_maintenance3_properties.p_next = ptr::null_mut();
+                Some(16usize)
+            }
+            vk::PhysicalDeviceMaintenance4Properties::STRUCTURE_TYPE => {
+                self.physical_device_maintenance4_properties = ptr
+                    .cast::<vk::PhysicalDeviceMaintenance4Properties<'static>>()
+                    .read();
+                self.physical_device_maintenance4_properties.p_next = ptr::null_mut();
+                Some(17usize)
+            }
+            vk::PhysicalDeviceFloatControlsProperties::STRUCTURE_TYPE => {
+                self.physical_device_float_controls_properties = ptr
+                    .cast::<vk::PhysicalDeviceFloatControlsProperties<'static>>()
+                    .read();
+                self.physical_device_float_controls_properties.p_next = ptr::null_mut();
+                Some(18usize)
+            }
+            vk::PhysicalDeviceExternalMemoryHostPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_external_memory_host_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceExternalMemoryHostPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_external_memory_host_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(19usize)
+            }
+            vk::PhysicalDeviceConservativeRasterizationPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_conservative_rasterization_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_conservative_rasterization_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(20usize)
+            }
+            vk::PhysicalDeviceShaderCorePropertiesAMD::STRUCTURE_TYPE => {
+                self.physical_device_shader_core_properties_amd = ptr
+                    .cast::<vk::PhysicalDeviceShaderCorePropertiesAMD<'static>>()
+                    .read();
+                self.physical_device_shader_core_properties_amd.p_next = ptr::null_mut();
+                Some(21usize)
+            }
+            vk::PhysicalDeviceShaderCoreProperties2AMD::STRUCTURE_TYPE => {
+                self.physical_device_shader_core_properties2_amd = ptr
+                    .cast::<vk::PhysicalDeviceShaderCoreProperties2AMD<'static>>()
+                    .read();
+                self.physical_device_shader_core_properties2_amd.p_next = ptr::null_mut();
+                Some(22usize)
+            }
+            vk::PhysicalDeviceDescriptorIndexingProperties::STRUCTURE_TYPE => {
+                self.physical_device_descriptor_indexing_properties = ptr
+                    .cast::<vk::PhysicalDeviceDescriptorIndexingProperties<'static>>()
+                    .read();
+                self.physical_device_descriptor_indexing_properties.p_next = ptr::null_mut();
+                Some(23usize)
+            }
+            vk::PhysicalDeviceTimelineSemaphoreProperties::STRUCTURE_TYPE => {
+                self.physical_device_timeline_semaphore_properties = ptr
+                    .cast::<vk::PhysicalDeviceTimelineSemaphoreProperties<'static>>()
+                    .read();
+                self.physical_device_timeline_semaphore_properties.p_next = ptr::null_mut();
+                Some(24usize)
+            }
+            vk::PhysicalDeviceVertexAttributeDivisorPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_vertex_attribute_divisor_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceVertexAttributeDivisorPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_vertex_attribute_divisor_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(25usize)
+            }
+            vk::PhysicalDevicePCIBusInfoPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_pci_bus_info_properties_ext = ptr
+                    .cast::<vk::PhysicalDevicePCIBusInfoPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_pci_bus_info_properties_ext.p_next = ptr::null_mut();
+                Some(26usize)
+            }
+            vk::PhysicalDeviceDepthStencilResolveProperties::STRUCTURE_TYPE => {
+                self.physical_device_depth_stencil_resolve_properties = ptr
+                    .cast::<vk::PhysicalDeviceDepthStencilResolveProperties<'static>>()
+                    .read();
+                self.physical_device_depth_stencil_resolve_properties.p_next = ptr::null_mut();
+                Some(27usize)
+            }
+            vk::PhysicalDeviceTransformFeedbackPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_transform_feedback_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_transform_feedback_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(28usize)
+            }
+            vk::PhysicalDeviceCopyMemoryIndirectPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_copy_memory_indirect_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceCopyMemoryIndirectPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_copy_memory_indirect_properties_nv
+                    .p_next = ptr::null_mut();
+                Some(29usize)
+            }
+            vk::PhysicalDeviceMemoryDecompressionPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_memory_decompression_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceMemoryDecompressionPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_memory_decompression_properties_nv
+                    .p_next = ptr::null_mut();
+                Some(30usize)
+            }
+            vk::PhysicalDeviceShadingRateImagePropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_shading_rate_image_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceShadingRateImagePropertiesNV<'static>>()
+                    .read();
+                self.physical_device_shading_rate_image_properties_nv.p_next = ptr::null_mut();
+                Some(31usize)
+            }
+            vk::PhysicalDeviceMeshShaderPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_mesh_shader_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceMeshShaderPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_mesh_shader_properties_nv.p_next = ptr::null_mut();
+                Some(32usize)
+            }
+            vk::PhysicalDeviceMeshShaderPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_mesh_shader_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_mesh_shader_properties_ext.p_next = ptr::null_mut();
+                Some(33usize)
+            }
+            vk::PhysicalDeviceAccelerationStructurePropertiesKHR::STRUCTURE_TYPE => {
+                self.physical_device_acceleration_structure_properties_khr = ptr
+                    .cast::<vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static>>()
+                    .read();
+                self.physical_device_acceleration_structure_properties_khr
+                    .p_next = ptr::null_mut();
+                Some(34usize)
+            }
+            vk::PhysicalDeviceRayTracingPipelinePropertiesKHR::STRUCTURE_TYPE => {
+                self.physical_device_ray_tracing_pipeline_properties_khr = ptr
+                    .cast::<vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>>()
+                    .read();
+                self.physical_device_ray_tracing_pipeline_properties_khr
+                    .p_next = ptr::null_mut();
+                Some(35usize)
+            }
+            vk::PhysicalDeviceRayTracingPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_ray_tracing_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceRayTracingPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_ray_tracing_properties_nv.p_next = ptr::null_mut();
+                Some(36usize)
+            }
+            vk::PhysicalDeviceFragmentDensityMapPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_fragment_density_map_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceFragmentDensityMapPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_fragment_density_map_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(37usize)
+            }
+            vk::PhysicalDeviceFragmentDensityMap2PropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_fragment_density_map2_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceFragmentDensityMap2PropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_fragment_density_map2_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(38usize)
+            }
+            vk::PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM::STRUCTURE_TYPE => {
+                self.physical_device_fragment_density_map_offset_properties_qcom = ptr
+                    .cast::<vk::PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM<'static>>()
+                    .read();
+                self.physical_device_fragment_density_map_offset_properties_qcom
+                    .p_next = ptr::null_mut();
+                Some(39usize)
+            }
+            vk::PhysicalDeviceCooperativeMatrixPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_cooperative_matrix_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceCooperativeMatrixPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_cooperative_matrix_properties_nv.p_next = ptr::null_mut();
+                Some(40usize)
+            }
+            vk::PhysicalDevicePerformanceQueryPropertiesKHR::STRUCTURE_TYPE => {
+                self.physical_device_performance_query_properties_khr = ptr
+                    .cast::<vk::PhysicalDevicePerformanceQueryPropertiesKHR<'static>>()
+                    .read();
+                self.physical_device_performance_query_properties_khr.p_next = ptr::null_mut();
+                Some(41usize)
+            }
+            vk::PhysicalDeviceShaderSMBuiltinsPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_shader_sm_builtins_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceShaderSMBuiltinsPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_shader_sm_builtins_properties_nv.p_next = ptr::null_mut();
+                Some(42usize)
+            }
+            vk::PhysicalDeviceTexelBufferAlignmentProperties::STRUCTURE_TYPE => {
+                self.physical_device_texel_buffer_alignment_properties = ptr
+                    .cast::<vk::PhysicalDeviceTexelBufferAlignmentProperties<'static>>()
+                    .read();
+                self.physical_device_texel_buffer_alignment_properties
+                    .p_next = ptr::null_mut();
+                Some(43usize)
+            }
+            vk::PhysicalDeviceSubgroupSizeControlProperties::STRUCTURE_TYPE => {
+                self.physical_device_subgroup_size_control_properties = ptr
+                    .cast::<vk::PhysicalDeviceSubgroupSizeControlProperties<'static>>()
+                    .read();
+                self.physical_device_subgroup_size_control_properties.p_next = ptr::null_mut();
+                Some(44usize)
+            }
+            vk::PhysicalDeviceSubpassShadingPropertiesHUAWEI::STRUCTURE_TYPE => {
+                self.physical_device_subpass_shading_properties_huawei = ptr
+                    .cast::<vk::PhysicalDeviceSubpassShadingPropertiesHUAWEI<'static>>()
+                    .read();
+                self.physical_device_subpass_shading_properties_huawei
+                    .p_next = ptr::null_mut();
+                Some(45usize)
+            }
+            vk::PhysicalDeviceClusterCullingShaderPropertiesHUAWEI::STRUCTURE_TYPE => {
+                self.physical_device_cluster_culling_shader_properties_huawei = ptr
+                    .cast::<vk::PhysicalDeviceClusterCullingShaderPropertiesHUAWEI<'static>>()
+                    .read();
+                self.physical_device_cluster_culling_shader_properties_huawei
+                    .p_next = ptr::null_mut();
+                Some(46usize)
+            }
+            vk::PhysicalDeviceLineRasterizationPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_line_rasterization_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceLineRasterizationPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_line_rasterization_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(47usize)
+            }
+            vk::PhysicalDeviceVulkan11Properties::STRUCTURE_TYPE => {
+                self.physical_device_vulkan11_properties = ptr
+                    .cast::<vk::PhysicalDeviceVulkan11Properties<'static>>()
+                    .read();
+                self.physical_device_vulkan11_properties.p_next = ptr::null_mut();
+                Some(48usize)
+            }
+            vk::PhysicalDeviceVulkan12Properties::STRUCTURE_TYPE => {
+                self.physical_device_vulkan12_properties = ptr
+                    .cast::<vk::PhysicalDeviceVulkan12Properties<'static>>()
+                    .read();
+                self.physical_device_vulkan12_properties.p_next = ptr::null_mut();
+                Some(49usize)
+            }
+            vk::PhysicalDeviceVulkan13Properties::STRUCTURE_TYPE => {
+                self.physical_device_vulkan13_properties = ptr
+                    .cast::<vk::PhysicalDeviceVulkan13Properties<'static>>()
+                    .read();
+                self.physical_device_vulkan13_properties.p_next = ptr::null_mut();
+                Some(50usize)
+            }
+            vk::PhysicalDeviceCustomBorderColorPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_custom_border_color_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceCustomBorderColorPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_custom_border_color_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(51usize)
+            }
+            vk::PhysicalDeviceExtendedDynamicState3PropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_extended_dynamic_state3_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceExtendedDynamicState3PropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_extended_dynamic_state3_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(52usize)
+            }
+            vk::PhysicalDeviceRobustness2PropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_robustness2_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceRobustness2PropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_robustness2_properties_ext.p_next = ptr::null_mut();
+                Some(53usize)
+            }
+            vk::PhysicalDevicePortabilitySubsetPropertiesKHR::STRUCTURE_TYPE => {
+                self.physical_device_portability_subset_properties_khr = ptr
+                    .cast::<vk::PhysicalDevicePortabilitySubsetPropertiesKHR<'static>>()
+                    .read();
+                self.physical_device_portability_subset_properties_khr
+                    .p_next = ptr::null_mut();
+                Some(54usize)
+            }
+            vk::PhysicalDeviceFragmentShadingRatePropertiesKHR::STRUCTURE_TYPE => {
+                self.physical_device_fragment_shading_rate_properties_khr = ptr
+                    .cast::<vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static>>()
+                    .read();
+                self.physical_device_fragment_shading_rate_properties_khr
+                    .p_next = ptr::null_mut();
+                Some(55usize)
+            }
+            vk::PhysicalDeviceFragmentShadingRateEnumsPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_fragment_shading_rate_enums_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceFragmentShadingRateEnumsPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_fragment_shading_rate_enums_properties_nv
+                    .p_next = ptr::null_mut();
+                Some(56usize)
+            }
+            vk::PhysicalDeviceProvokingVertexPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_provoking_vertex_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceProvokingVertexPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_provoking_vertex_properties_ext.p_next = ptr::null_mut();
+                Some(57usize)
+            }
+            vk::PhysicalDeviceDescriptorBufferPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_descriptor_buffer_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_descriptor_buffer_properties_ext.p_next = ptr::null_mut();
+                Some(58usize)
+            }
+            vk::PhysicalDeviceDescriptorBufferDensityMapPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_descriptor_buffer_density_map_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceDescriptorBufferDensityMapPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_descriptor_buffer_density_map_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(59usize)
+            }
+            vk::PhysicalDeviceShaderIntegerDotProductProperties::STRUCTURE_TYPE => {
+                self.physical_device_shader_integer_dot_product_properties = ptr
+                    .cast::<vk::PhysicalDeviceShaderIntegerDotProductProperties<'static>>()
+                    .read();
+                self.physical_device_shader_integer_dot_product_properties
+                    .p_next = ptr::null_mut();
+                Some(60usize)
+            }
+            vk::PhysicalDeviceDrmPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_drm_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceDrmPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_drm_properties_ext.p_next = ptr::null_mut();
+                Some(61usize)
+            }
+            vk::PhysicalDeviceFragmentShaderBarycentricPropertiesKHR::STRUCTURE_TYPE => {
+                self.physical_device_fragment_shader_barycentric_properties_khr = ptr
+                    .cast::<vk::PhysicalDeviceFragmentShaderBarycentricPropertiesKHR<'static>>()
+                    .read();
+                self.physical_device_fragment_shader_barycentric_properties_khr
+                    .p_next = ptr::null_mut();
+                Some(62usize)
+            }
+            vk::PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_graphics_pipeline_library_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_graphics_pipeline_library_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(63usize)
+            }
+            vk::PhysicalDeviceShaderModuleIdentifierPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_shader_module_identifier_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceShaderModuleIdentifierPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_shader_module_identifier_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(64usize)
+            }
+            vk::PhysicalDeviceOpacityMicromapPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_opacity_micromap_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceOpacityMicromapPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_opacity_micromap_properties_ext.p_next = ptr::null_mut();
+                Some(65usize)
+            }
+            vk::PhysicalDeviceDisplacementMicromapPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_displacement_micromap_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceDisplacementMicromapPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_displacement_micromap_properties_nv
+                    .p_next = ptr::null_mut();
+                Some(66usize)
+            }
+            vk::PhysicalDevicePipelineRobustnessPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_pipeline_robustness_properties_ext = ptr
+                    .cast::<vk::PhysicalDevicePipelineRobustnessPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_pipeline_robustness_properties_ext
+                    .p_next = ptr::null_mut();
+                Some(67usize)
+            }
+            vk::PhysicalDeviceImageProcessingPropertiesQCOM::STRUCTURE_TYPE => {
+                self.physical_device_image_processing_properties_qcom = ptr
+                    .cast::<vk::PhysicalDeviceImageProcessingPropertiesQCOM<'static>>()
+                    .read();
+                self.physical_device_image_processing_properties_qcom.p_next = ptr::null_mut();
+                Some(68usize)
+            }
+            vk::PhysicalDeviceOpticalFlowPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_optical_flow_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_optical_flow_properties_nv.p_next = ptr::null_mut();
+                Some(69usize)
+            }
+            vk::PhysicalDeviceShaderCoreBuiltinsPropertiesARM::STRUCTURE_TYPE => {
+                self.physical_device_shader_core_builtins_properties_arm = ptr
+                    .cast::<vk::PhysicalDeviceShaderCoreBuiltinsPropertiesARM<'static>>()
+                    .read();
+                self.physical_device_shader_core_builtins_properties_arm
+                    .p_next = ptr::null_mut();
+                Some(70usize)
+            }
+            vk::PhysicalDeviceRayTracingInvocationReorderPropertiesNV::STRUCTURE_TYPE => {
+                self.physical_device_ray_tracing_invocation_reorder_properties_nv = ptr
+                    .cast::<vk::PhysicalDeviceRayTracingInvocationReorderPropertiesNV<'static>>()
+                    .read();
+                self.physical_device_ray_tracing_invocation_reorder_properties_nv
+                    .p_next = ptr::null_mut();
+                Some(71usize)
+            }
+            vk::PhysicalDeviceShaderCorePropertiesARM::STRUCTURE_TYPE => {
+                self.physical_device_shader_core_properties_arm = ptr
+                    .cast::<vk::PhysicalDeviceShaderCorePropertiesARM<'static>>()
+                    .read();
+                self.physical_device_shader_core_properties_arm.p_next = ptr::null_mut();
+                Some(72usize)
+            }
+            vk::PhysicalDeviceShaderObjectPropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_shader_object_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceShaderObjectPropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_shader_object_properties_ext.p_next = ptr::null_mut();
+                Some(73usize)
+            }
+            vk::PhysicalDeviceShaderTileImagePropertiesEXT::STRUCTURE_TYPE => {
+                self.physical_device_shader_tile_image_properties_ext = ptr
+                    .cast::<vk::PhysicalDeviceShaderTileImagePropertiesEXT<'static>>()
+                    .read();
+                self.physical_device_shader_tile_image_properties_ext.p_next = ptr::null_mut();
+                Some(74usize)
+            }
+            _ => None,
+        }
+    }
+    #[doc = r" Calls `f` with the index of the struct, the name of every property, whether it"]
+    #[doc = r" is equal in `self` and `other`, and its values."]
+    pub(super) fn zip(
+        &self,
+        other: &Self,
+        mut f: impl FnMut(usize, &'static str, bool, &dyn fmt::Debug, &dyn fmt::Debug),
+    ) {
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::apiVersion",
+            self.physical_device_properties2.properties.api_version
+                == other.physical_device_properties2.properties.api_version,
+            &self.physical_device_properties2.properties.api_version,
+            &other.physical_device_properties2.properties.api_version,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::driverVersion",
+            self.physical_device_properties2.properties.driver_version
+                == other.physical_device_properties2.properties.driver_version,
+            &self.physical_device_properties2.properties.driver_version,
+            &other.physical_device_properties2.properties.driver_version,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::vendorID",
+            self.physical_device_properties2.properties.vendor_id
+                == other.physical_device_properties2.properties.vendor_id,
+            &self.physical_device_properties2.properties.vendor_id,
+            &other.physical_device_properties2.properties.vendor_id,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::deviceID",
+            self.physical_device_properties2.properties.device_id
+                == other.physical_device_properties2.properties.device_id,
+            &self.physical_device_properties2.properties.device_id,
+            &other.physical_device_properties2.properties.device_id,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::deviceType",
+            self.physical_device_properties2.properties.device_type
+                == other.physical_device_properties2.properties.device_type,
+            &self.physical_device_properties2.properties.device_type,
+            &other.physical_device_properties2.properties.device_type,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::deviceName",
+            self.physical_device_properties2.properties.device_name
+                == other.physical_device_properties2.properties.device_name,
+            &CStrArray(&self.physical_device_properties2.properties.device_name),
+            &CStrArray(&other.physical_device_properties2.properties.device_name),
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::pipelineCacheUUID",
+            self.physical_device_properties2
+                .properties
+                .pipeline_cache_uuid
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .pipeline_cache_uuid,
+            &self
+                .physical_device_properties2
+                .properties
+                .pipeline_cache_uuid,
+            &other
+                .physical_device_properties2
+                .properties
+                .pipeline_cache_uuid,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxImageDimension1D",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension1_d
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_image_dimension1_d,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension1_d,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension1_d,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxImageDimension2D",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension2_d
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_image_dimension2_d,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension2_d,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension2_d,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxImageDimension3D",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension3_d
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_image_dimension3_d,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension3_d,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension3_d,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxImageDimensionCube",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension_cube
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_image_dimension_cube,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension_cube,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_image_dimension_cube,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxImageArrayLayers",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_image_array_layers
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_image_array_layers,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_image_array_layers,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_image_array_layers,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTexelBufferElements",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_texel_buffer_elements
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_texel_buffer_elements,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_texel_buffer_elements,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_texel_buffer_elements,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxUniformBufferRange",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_uniform_buffer_range
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_uniform_buffer_range,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_uniform_buffer_range,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_uniform_buffer_range,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxStorageBufferRange",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_storage_buffer_range
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_storage_buffer_range,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_storage_buffer_range,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_storage_buffer_range,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxPushConstantsSize",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_push_constants_size
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_push_constants_size,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_push_constants_size,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_push_constants_size,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxMemoryAllocationCount",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_memory_allocation_count
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_memory_allocation_count,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_memory_allocation_count,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_memory_allocation_count,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxSamplerAllocationCount",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_sampler_allocation_count
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_sampler_allocation_count,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_sampler_allocation_count,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_sampler_allocation_count,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.bufferImageGranularity",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .buffer_image_granularity
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .buffer_image_granularity,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .buffer_image_granularity,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .buffer_image_granularity,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.sparseAddressSpaceSize",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .sparse_address_space_size
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .sparse_address_space_size,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .sparse_address_space_size,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .sparse_address_space_size,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxBoundDescriptorSets",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_bound_descriptor_sets
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_bound_descriptor_sets,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_bound_descriptor_sets,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_bound_descriptor_sets,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxPerStageDescriptorSamplers",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_samplers
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_per_stage_descriptor_samplers,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_samplers,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_samplers,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxPerStageDescriptorUniformBuffers",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_uniform_buffers
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_per_stage_descriptor_uniform_buffers,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_uniform_buffers,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_uniform_buffers,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxPerStageDescriptorStorageBuffers",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_storage_buffers
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_per_stage_descriptor_storage_buffers,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_storage_buffers,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_storage_buffers,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxPerStageDescriptorSampledImages",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_sampled_images
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_per_stage_descriptor_sampled_images,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_sampled_images,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_sampled_images,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxPerStageDescriptorStorageImages",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_storage_images
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_per_stage_descriptor_storage_images,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_storage_images,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_storage_images,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxPerStageDescriptorInputAttachments",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_input_attachments
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_per_stage_descriptor_input_attachments,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_input_attachments,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_descriptor_input_attachments,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxPerStageResources",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_resources
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_per_stage_resources,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_resources,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_per_stage_resources,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxDescriptorSetSamplers",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_samplers
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_descriptor_set_samplers,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_samplers,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_samplers,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxDescriptorSetUniformBuffers",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_uniform_buffers
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_descriptor_set_uniform_buffers,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_uniform_buffers,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_uniform_buffers,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxDescriptorSetUniformBuffersDynamic",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_uniform_buffers_dynamic
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_descriptor_set_uniform_buffers_dynamic,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_uniform_buffers_dynamic,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_uniform_buffers_dynamic,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxDescriptorSetStorageBuffers",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_storage_buffers
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_descriptor_set_storage_buffers,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_storage_buffers,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_storage_buffers,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxDescriptorSetStorageBuffersDynamic",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_storage_buffers_dynamic
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_descriptor_set_storage_buffers_dynamic,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_storage_buffers_dynamic,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_storage_buffers_dynamic,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxDescriptorSetSampledImages",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_sampled_images
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_descriptor_set_sampled_images,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_sampled_images,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_sampled_images,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxDescriptorSetStorageImages",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_storage_images
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_descriptor_set_storage_images,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_storage_images,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_storage_images,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxDescriptorSetInputAttachments",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_input_attachments
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_descriptor_set_input_attachments,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_input_attachments,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_descriptor_set_input_attachments,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxVertexInputAttributes",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_attributes
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_vertex_input_attributes,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_attributes,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_attributes,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxVertexInputBindings",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_bindings
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_vertex_input_bindings,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_bindings,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_bindings,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxVertexInputAttributeOffset",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_attribute_offset
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_vertex_input_attribute_offset,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_attribute_offset,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_attribute_offset,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxVertexInputBindingStride",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_binding_stride
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_vertex_input_binding_stride,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_binding_stride,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_input_binding_stride,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxVertexOutputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_output_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_vertex_output_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_output_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_vertex_output_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTessellationGenerationLevel",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_generation_level
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_tessellation_generation_level,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_generation_level,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_generation_level,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTessellationPatchSize",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_patch_size
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_tessellation_patch_size,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_patch_size,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_patch_size,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTessellationControlPerVertexInputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_per_vertex_input_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_tessellation_control_per_vertex_input_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_per_vertex_input_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_per_vertex_input_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTessellationControlPerVertexOutputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_per_vertex_output_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_tessellation_control_per_vertex_output_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_per_vertex_output_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_per_vertex_output_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTessellationControlPerPatchOutputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_per_patch_output_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_tessellation_control_per_patch_output_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_per_patch_output_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_per_patch_output_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTessellationControlTotalOutputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_total_output_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_tessellation_control_total_output_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_total_output_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_control_total_output_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTessellationEvaluationInputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_evaluation_input_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_tessellation_evaluation_input_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_evaluation_input_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_evaluation_input_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTessellationEvaluationOutputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_evaluation_output_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_tessellation_evaluation_output_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_evaluation_output_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_tessellation_evaluation_output_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxGeometryShaderInvocations",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_shader_invocations
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_geometry_shader_invocations,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_shader_invocations,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_shader_invocations,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxGeometryInputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_input_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_geometry_input_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_input_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_input_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxGeometryOutputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_output_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_geometry_output_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_output_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_output_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxGeometryOutputVertices",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_output_vertices
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_geometry_output_vertices,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_output_vertices,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_output_vertices,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxGeometryTotalOutputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_total_output_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_geometry_total_output_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_total_output_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_geometry_total_output_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxFragmentInputComponents",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_input_components
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_fragment_input_components,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_input_components,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_input_components,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxFragmentOutputAttachments",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_output_attachments
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_fragment_output_attachments,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_output_attachments,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_output_attachments,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxFragmentDualSrcAttachments",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_dual_src_attachments
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_fragment_dual_src_attachments,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_dual_src_attachments,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_dual_src_attachments,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxFragmentCombinedOutputResources",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_combined_output_resources
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_fragment_combined_output_resources,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_combined_output_resources,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_fragment_combined_output_resources,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxComputeSharedMemorySize",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_compute_shared_memory_size
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_compute_shared_memory_size,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_compute_shared_memory_size,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_compute_shared_memory_size,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxComputeWorkGroupCount",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_compute_work_group_count
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_compute_work_group_count,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_compute_work_group_count,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_compute_work_group_count,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxComputeWorkGroupInvocations",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_compute_work_group_invocations
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_compute_work_group_invocations,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_compute_work_group_invocations,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_compute_work_group_invocations,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxComputeWorkGroupSize",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_compute_work_group_size
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_compute_work_group_size,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_compute_work_group_size,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_compute_work_group_size,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.subPixelPrecisionBits",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .sub_pixel_precision_bits
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .sub_pixel_precision_bits,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .sub_pixel_precision_bits,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .sub_pixel_precision_bits,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.subTexelPrecisionBits",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .sub_texel_precision_bits
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .sub_texel_precision_bits,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .sub_texel_precision_bits,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .sub_texel_precision_bits,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.mipmapPrecisionBits",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .mipmap_precision_bits
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .mipmap_precision_bits,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .mipmap_precision_bits,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .mipmap_precision_bits,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxDrawIndexedIndexValue",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_draw_indexed_index_value
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_draw_indexed_index_value,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_draw_indexed_index_value,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_draw_indexed_index_value,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxDrawIndirectCount",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_draw_indirect_count
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_draw_indirect_count,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_draw_indirect_count,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_draw_indirect_count,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxSamplerLodBias",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_sampler_lod_bias
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_sampler_lod_bias,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_sampler_lod_bias,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_sampler_lod_bias,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxSamplerAnisotropy",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_sampler_anisotropy
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_sampler_anisotropy,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_sampler_anisotropy,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_sampler_anisotropy,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxViewports",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_viewports
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_viewports,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_viewports,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_viewports,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxViewportDimensions",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_viewport_dimensions
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_viewport_dimensions,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_viewport_dimensions,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_viewport_dimensions,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.viewportBoundsRange",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .viewport_bounds_range
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .viewport_bounds_range,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .viewport_bounds_range,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .viewport_bounds_range,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.viewportSubPixelBits",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .viewport_sub_pixel_bits
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .viewport_sub_pixel_bits,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .viewport_sub_pixel_bits,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .viewport_sub_pixel_bits,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.minMemoryMapAlignment",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .min_memory_map_alignment
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .min_memory_map_alignment,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_memory_map_alignment,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_memory_map_alignment,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.minTexelBufferOffsetAlignment",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .min_texel_buffer_offset_alignment
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .min_texel_buffer_offset_alignment,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_texel_buffer_offset_alignment,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_texel_buffer_offset_alignment,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.minUniformBufferOffsetAlignment",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .min_uniform_buffer_offset_alignment
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .min_uniform_buffer_offset_alignment,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_uniform_buffer_offset_alignment,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_uniform_buffer_offset_alignment,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.minStorageBufferOffsetAlignment",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .min_storage_buffer_offset_alignment
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .min_storage_buffer_offset_alignment,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_storage_buffer_offset_alignment,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_storage_buffer_offset_alignment,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.minTexelOffset",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .min_texel_offset
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .min_texel_offset,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_texel_offset,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_texel_offset,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTexelOffset",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_texel_offset
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_texel_offset,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_texel_offset,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_texel_offset,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.minTexelGatherOffset",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .min_texel_gather_offset
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .min_texel_gather_offset,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_texel_gather_offset,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_texel_gather_offset,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxTexelGatherOffset",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_texel_gather_offset
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_texel_gather_offset,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_texel_gather_offset,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_texel_gather_offset,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.minInterpolationOffset",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .min_interpolation_offset
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .min_interpolation_offset,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_interpolation_offset,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .min_interpolation_offset,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxInterpolationOffset",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_interpolation_offset
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_interpolation_offset,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_interpolation_offset,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_interpolation_offset,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.subPixelInterpolationOffsetBits",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .sub_pixel_interpolation_offset_bits
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .sub_pixel_interpolation_offset_bits,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .sub_pixel_interpolation_offset_bits,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .sub_pixel_interpolation_offset_bits,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxFramebufferWidth",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_framebuffer_width
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_framebuffer_width,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_framebuffer_width,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_framebuffer_width,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxFramebufferHeight",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_framebuffer_height
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_framebuffer_height,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_framebuffer_height,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_framebuffer_height,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxFramebufferLayers",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_framebuffer_layers
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_framebuffer_layers,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_framebuffer_layers,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_framebuffer_layers,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.framebufferColorSampleCounts",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_color_sample_counts
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .framebuffer_color_sample_counts,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_color_sample_counts,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_color_sample_counts,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.framebufferDepthSampleCounts",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_depth_sample_counts
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .framebuffer_depth_sample_counts,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_depth_sample_counts,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_depth_sample_counts,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.framebufferStencilSampleCounts",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_stencil_sample_counts
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .framebuffer_stencil_sample_counts,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_stencil_sample_counts,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_stencil_sample_counts,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.framebufferNoAttachmentsSampleCounts",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_no_attachments_sample_counts
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .framebuffer_no_attachments_sample_counts,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_no_attachments_sample_counts,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .framebuffer_no_attachments_sample_counts,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxColorAttachments",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_color_attachments
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_color_attachments,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_color_attachments,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_color_attachments,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.sampledImageColorSampleCounts",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_color_sample_counts
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .sampled_image_color_sample_counts,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_color_sample_counts,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_color_sample_counts,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.sampledImageIntegerSampleCounts",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_integer_sample_counts
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .sampled_image_integer_sample_counts,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_integer_sample_counts,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_integer_sample_counts,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.sampledImageDepthSampleCounts",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_depth_sample_counts
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .sampled_image_depth_sample_counts,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_depth_sample_counts,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_depth_sample_counts,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.sampledImageStencilSampleCounts",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_stencil_sample_counts
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .sampled_image_stencil_sample_counts,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_stencil_sample_counts,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .sampled_image_stencil_sample_counts,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.storageImageSampleCounts",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .storage_image_sample_counts
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .storage_image_sample_counts,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .storage_image_sample_counts,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .storage_image_sample_counts,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxSampleMaskWords",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_sample_mask_words
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_sample_mask_words,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_sample_mask_words,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_sample_mask_words,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.timestampComputeAndGraphics",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .timestamp_compute_and_graphics
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .timestamp_compute_and_graphics,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .timestamp_compute_and_graphics,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .timestamp_compute_and_graphics,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.timestampPeriod",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .timestamp_period
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .timestamp_period,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .timestamp_period,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .timestamp_period,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxClipDistances",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_clip_distances
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_clip_distances,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_clip_distances,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_clip_distances,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxCullDistances",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_cull_distances
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_cull_distances,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_cull_distances,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_cull_distances,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.maxCombinedClipAndCullDistances",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .max_combined_clip_and_cull_distances
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .max_combined_clip_and_cull_distances,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_combined_clip_and_cull_distances,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .max_combined_clip_and_cull_distances,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.discreteQueuePriorities",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .discrete_queue_priorities
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .discrete_queue_priorities,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .discrete_queue_priorities,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .discrete_queue_priorities,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.pointSizeRange",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .point_size_range
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .point_size_range,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .point_size_range,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .point_size_range,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.lineWidthRange",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .line_width_range
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .line_width_range,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .line_width_range,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .line_width_range,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.pointSizeGranularity",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .point_size_granularity
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .point_size_granularity,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .point_size_granularity,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .point_size_granularity,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.lineWidthGranularity",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .line_width_granularity
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .line_width_granularity,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .line_width_granularity,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .line_width_granularity,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.strictLines",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .strict_lines
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .strict_lines,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .strict_lines,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .strict_lines,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.standardSampleLocations",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .standard_sample_locations
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .standard_sample_locations,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .standard_sample_locations,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .standard_sample_locations,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.optimalBufferCopyOffsetAlignment",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .optimal_buffer_copy_offset_alignment
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .optimal_buffer_copy_offset_alignment,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .optimal_buffer_copy_offset_alignment,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .optimal_buffer_copy_offset_alignment,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.optimalBufferCopyRowPitchAlignment",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .optimal_buffer_copy_row_pitch_alignment
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .optimal_buffer_copy_row_pitch_alignment,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .optimal_buffer_copy_row_pitch_alignment,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .optimal_buffer_copy_row_pitch_alignment,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::limits.nonCoherentAtomSize",
+            self.physical_device_properties2
+                .properties
+                .limits
+                .non_coherent_atom_size
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .limits
+                    .non_coherent_atom_size,
+            &self
+                .physical_device_properties2
+                .properties
+                .limits
+                .non_coherent_atom_size,
+            &other
+                .physical_device_properties2
+                .properties
+                .limits
+                .non_coherent_atom_size,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::sparseProperties.residencyStandard2DBlockShape",
+            self.physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_standard2_d_block_shape
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .sparse_properties
+                    .residency_standard2_d_block_shape,
+            &self
+                .physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_standard2_d_block_shape,
+            &other
+                .physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_standard2_d_block_shape,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::sparseProperties.residencyStandard2DMultisampleBlockShape",
+            self.physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_standard2_d_multisample_block_shape
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .sparse_properties
+                    .residency_standard2_d_multisample_block_shape,
+            &self
+                .physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_standard2_d_multisample_block_shape,
+            &other
+                .physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_standard2_d_multisample_block_shape,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::sparseProperties.residencyStandard3DBlockShape",
+            self.physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_standard3_d_block_shape
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .sparse_properties
+                    .residency_standard3_d_block_shape,
+            &self
+                .physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_standard3_d_block_shape,
+            &other
+                .physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_standard3_d_block_shape,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::sparseProperties.residencyAlignedMipSize",
+            self.physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_aligned_mip_size
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .sparse_properties
+                    .residency_aligned_mip_size,
+            &self
+                .physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_aligned_mip_size,
+            &other
+                .physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_aligned_mip_size,
+        );
+        f(
+            0usize,
+            "VkPhysicalDeviceProperties::sparseProperties.residencyNonResidentStrict",
+            self.physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_non_resident_strict
+                == other
+                    .physical_device_properties2
+                    .properties
+                    .sparse_properties
+                    .residency_non_resident_strict,
+            &self
+                .physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_non_resident_strict,
+            &other
+                .physical_device_properties2
+                .properties
+                .sparse_properties
+                .residency_non_resident_strict,
+        );
+        f(
+            1usize,
+            "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV::maxGraphicsShaderGroupCount",
+            self.physical_device_device_generated_commands_properties_nv
+                .max_graphics_shader_group_count
+                == other
+                    .physical_device_device_generated_commands_properties_nv
+                    .max_graphics_shader_group_count,
+            &self
+                .physical_device_device_generated_commands_properties_nv
+                .max_graphics_shader_group_count,
+            &other
+                .physical_device_device_generated_commands_properties_nv
+                .max_graphics_shader_group_count,
+        );
+        f(
+            1usize,
+            "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV::maxIndirectSequenceCount",
+            self.physical_device_device_generated_commands_properties_nv
+                .max_indirect_sequence_count
+                == other
+                    .physical_device_device_generated_commands_properties_nv
+                    .max_indirect_sequence_count,
+            &self
+                .physical_device_device_generated_commands_properties_nv
+                .max_indirect_sequence_count,
+            &other
+                .physical_device_device_generated_commands_properties_nv
+                .max_indirect_sequence_count,
+        );
+        f(
+            1usize,
+            "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV::maxIndirectCommandsTokenCount",
+            self.physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_token_count
+                == other
+                    .physical_device_device_generated_commands_properties_nv
+                    .max_indirect_commands_token_count,
+            &self
+                .physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_token_count,
+            &other
+                .physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_token_count,
+        );
+        f(
+            1usize,
+            "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV::maxIndirectCommandsStreamCount",
+            self.physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_stream_count
+                == other
+                    .physical_device_device_generated_commands_properties_nv
+                    .max_indirect_commands_stream_count,
+            &self
+                .physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_stream_count,
+            &other
+                .physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_stream_count,
+        );
+        f(
+            1usize,
+            "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV::maxIndirectCommandsTokenOffset",
+            self.physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_token_offset
+                == other
+                    .physical_device_device_generated_commands_properties_nv
+                    .max_indirect_commands_token_offset,
+            &self
+                .physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_token_offset,
+            &other
+                .physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_token_offset,
+        );
+        f(
+            1usize,
+            "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV::maxIndirectCommandsStreamStride",
+            self.physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_stream_stride
+                == other
+                    .physical_device_device_generated_commands_properties_nv
+                    .max_indirect_commands_stream_stride,
+            &self
+                .physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_stream_stride,
+            &other
+                .physical_device_device_generated_commands_properties_nv
+                .max_indirect_commands_stream_stride,
+        );
+        f (1usize , "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV::minSequencesCountBufferOffsetAlignment" , self . physical_device_device_generated_commands_properties_nv . min_sequences_count_buffer_offset_alignment == other . physical_device_device_generated_commands_properties_nv . min_sequences_count_buffer_offset_alignment , & self . physical_device_device_generated_commands_properties_nv . min_sequences_count_buffer_offset_alignment , & other . physical_device_device_generated_commands_properties_nv . min_sequences_count_buffer_offset_alignment) ;
+        f (1usize , "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV::minSequencesIndexBufferOffsetAlignment" , self . physical_device_device_generated_commands_properties_nv . min_sequences_index_buffer_offset_alignment == other . physical_device_device_generated_commands_properties_nv . min_sequences_index_buffer_offset_alignment , & self . physical_device_device_generated_commands_properties_nv . min_sequences_index_buffer_offset_alignment , & other . physical_device_device_generated_commands_properties_nv . min_sequences_index_buffer_offset_alignment) ;
+        f (1usize , "VkPhysicalDeviceDeviceGeneratedCommandsPropertiesNV::minIndirectCommandsBufferOffsetAlignment" , self . physical_device_device_generated_commands_properties_nv . min_indirect_commands_buffer_offset_alignment == other . physical_device_device_generated_commands_properties_nv . min_indirect_commands_buffer_offset_alignment , & self . physical_device_device_generated_commands_properties_nv . min_indirect_commands_buffer_offset_alignment , & other . physical_device_device_generated_commands_properties_nv . min_indirect_commands_buffer_offset_alignment) ;
+        f(
+            2usize,
+            "VkPhysicalDeviceMultiDrawPropertiesEXT::maxMultiDrawCount",
+            self.physical_device_multi_draw_properties_ext
+                .max_multi_draw_count
+                == other
+                    .physical_device_multi_draw_properties_ext
+                    .max_multi_draw_count,
+            &self
+                .physical_device_multi_draw_properties_ext
+                .max_multi_draw_count,
+            &other
+                .physical_device_multi_draw_properties_ext
+                .max_multi_draw_count,
+        );
+        f(
+            3usize,
+            "VkPhysicalDevicePushDescriptorPropertiesKHR::maxPushDescriptors",
+            self.physical_device_push_descriptor_properties_khr
+                .max_push_descriptors
+                == other
+                    .physical_device_push_descriptor_properties_khr
+                    .max_push_descriptors,
+            &self
+                .physical_device_push_descriptor_properties_khr
+                .max_push_descriptors,
+            &other
+                .physical_device_push_descriptor_properties_khr
+                .max_push_descriptors,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceDriverProperties::driverID",
+            self.physical_device_driver_properties.driver_id
+                == other.physical_device_driver_properties.driver_id,
+            &self.physical_device_driver_properties.driver_id,
+            &other.physical_device_driver_properties.driver_id,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceDriverProperties::driverName",
+            self.physical_device_driver_properties.driver_name
+                == other.physical_device_driver_properties.driver_name,
+            &CStrArray(&self.physical_device_driver_properties.driver_name),
+            &CStrArray(&other.physical_device_driver_properties.driver_name),
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceDriverProperties::driverInfo",
+            self.physical_device_driver_properties.driver_info
+                == other.physical_device_driver_properties.driver_info,
+            &CStrArray(&self.physical_device_driver_properties.driver_info),
+            &CStrArray(&other.physical_device_driver_properties.driver_info),
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceDriverProperties::conformanceVersion.major",
+            self.physical_device_driver_properties
+                .conformance_version
+                .major
+                == other
+                    .physical_device_driver_properties
+                    .conformance_version
+                    .major,
+            &self
+                .physical_device_driver_properties
+                .conformance_version
+                .major,
+            &other
+                .physical_device_driver_properties
+                .conformance_version
+                .major,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceDriverProperties::conformanceVersion.minor",
+            self.physical_device_driver_properties
+                .conformance_version
+                .minor
+                == other
+                    .physical_device_driver_properties
+                    .conformance_version
+                    .minor,
+            &self
+                .physical_device_driver_properties
+                .conformance_version
+                .minor,
+            &other
+                .physical_device_driver_properties
+                .conformance_version
+                .minor,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceDriverProperties::conformanceVersion.subminor",
+            self.physical_device_driver_properties
+                .conformance_version
+                .subminor
+                == other
+                    .physical_device_driver_properties
+                    .conformance_version
+                    .subminor,
+            &self
+                .physical_device_driver_properties
+                .conformance_version
+                .subminor,
+            &other
+                .physical_device_driver_properties
+                .conformance_version
+                .subminor,
+        );
+        f(
+            4usize,
+            "VkPhysicalDeviceDriverProperties::conformanceVersion.patch",
+            self.physical_device_driver_properties
+                .conformance_version
+                .patch
+                == other
+                    .physical_device_driver_properties
+                    .conformance_version
+                    .patch,
+            &self
+                .physical_device_driver_properties
+                .conformance_version
+                .patch,
+            &other
+                .physical_device_driver_properties
+                .conformance_version
+                .patch,
+        );
+        f(
+            5usize,
+            "VkPhysicalDeviceIDProperties::deviceUUID",
+            self.physical_device_id_properties.device_uuid
+                == other.physical_device_id_properties.device_uuid,
+            &self.physical_device_id_properties.device_uuid,
+            &other.physical_device_id_properties.device_uuid,
+        );
+        f(
+            5usize,
+            "VkPhysicalDeviceIDProperties::driverUUID",
+            self.physical_device_id_properties.driver_uuid
+                == other.physical_device_id_properties.driver_uuid,
+            &self.physical_device_id_properties.driver_uuid,
+            &other.physical_device_id_properties.driver_uuid,
+        );
+        f(
+            5usize,
+            "VkPhysicalDeviceIDProperties::deviceLUID",
+            self.physical_device_id_properties.device_luid
+                == other.physical_device_id_properties.device_luid,
+            &self.physical_device_id_properties.device_luid,
+            &other.physical_device_id_properties.device_luid,
+        );
+        f(
+            5usize,
+            "VkPhysicalDeviceIDProperties::deviceNodeMask",
+            self.physical_device_id_properties.device_node_mask
+                == other.physical_device_id_properties.device_node_mask,
+            &self.physical_device_id_properties.device_node_mask,
+            &other.physical_device_id_properties.device_node_mask,
+        );
+        f(
+            5usize,
+            "VkPhysicalDeviceIDProperties::deviceLUIDValid",
+            self.physical_device_id_properties.device_luid_valid
+                == other.physical_device_id_properties.device_luid_valid,
+            &self.physical_device_id_properties.device_luid_valid,
+            &other.physical_device_id_properties.device_luid_valid,
+        );
+        f(
+            6usize,
+            "VkPhysicalDeviceMultiviewProperties::maxMultiviewViewCount",
+            self.physical_device_multiview_properties
+                .max_multiview_view_count
+                == other
+                    .physical_device_multiview_properties
+                    .max_multiview_view_count,
+            &self
+                .physical_device_multiview_properties
+                .max_multiview_view_count,
+            &other
+                .physical_device_multiview_properties
+                .max_multiview_view_count,
+        );
+        f(
+            6usize,
+            "VkPhysicalDeviceMultiviewProperties::maxMultiviewInstanceIndex",
+            self.physical_device_multiview_properties
+                .max_multiview_instance_index
+                == other
+                    .physical_device_multiview_properties
+                    .max_multiview_instance_index,
+            &self
+                .physical_device_multiview_properties
+                .max_multiview_instance_index,
+            &other
+                .physical_device_multiview_properties
+                .max_multiview_instance_index,
+        );
+        f(
+            7usize,
+            "VkPhysicalDeviceDiscardRectanglePropertiesEXT::maxDiscardRectangles",
+            self.physical_device_discard_rectangle_properties_ext
+                .max_discard_rectangles
+                == other
+                    .physical_device_discard_rectangle_properties_ext
+                    .max_discard_rectangles,
+            &self
+                .physical_device_discard_rectangle_properties_ext
+                .max_discard_rectangles,
+            &other
+                .physical_device_discard_rectangle_properties_ext
+                .max_discard_rectangles,
+        );
+        f(
+            8usize,
+            "VkPhysicalDeviceMultiviewPerViewAttributesPropertiesNVX::perViewPositionAllComponents",
+            self.physical_device_multiview_per_view_attributes_properties_nvx
+                .per_view_position_all_components
+                == other
+                    .physical_device_multiview_per_view_attributes_properties_nvx
+                    .per_view_position_all_components,
+            &self
+                .physical_device_multiview_per_view_attributes_properties_nvx
+                .per_view_position_all_components,
+            &other
+                .physical_device_multiview_per_view_attributes_properties_nvx
+                .per_view_position_all_components,
+        );
+        f(
+            9usize,
+            "VkPhysicalDeviceSubgroupProperties::subgroupSize",
+            self.physical_device_subgroup_properties.subgroup_size
+                == other.physical_device_subgroup_properties.subgroup_size,
+            &self.physical_device_subgroup_properties.subgroup_size,
+            &other.physical_device_subgroup_properties.subgroup_size,
+        );
+        f(
+            9usize,
+            "VkPhysicalDeviceSubgroupProperties::supportedStages",
+            self.physical_device_subgroup_properties.supported_stages
+                == other.physical_device_subgroup_properties.supported_stages,
+            &self.physical_device_subgroup_properties.supported_stages,
+            &other.physical_device_subgroup_properties.supported_stages,
+        );
+        f(
+            9usize,
+            "VkPhysicalDeviceSubgroupProperties::supportedOperations",
+            self.physical_device_subgroup_properties
+                .supported_operations
+                == other
+                    .physical_device_subgroup_properties
+                    .supported_operations,
+            &self
+                .physical_device_subgroup_properties
+                .supported_operations,
+            &other
+                .physical_device_subgroup_properties
+                .supported_operations,
+        );
+        f(
+            9usize,
+            "VkPhysicalDeviceSubgroupProperties::quadOperationsInAllStages",
+            self.physical_device_subgroup_properties
+                .quad_operations_in_all_stages
+                == other
+                    .physical_device_subgroup_properties
+                    .quad_operations_in_all_stages,
+            &self
+                .physical_device_subgroup_properties
+                .quad_operations_in_all_stages,
+            &other
+                .physical_device_subgroup_properties
+                .quad_operations_in_all_stages,
+        );
+        f(
+            10usize,
+            "VkPhysicalDevicePointClippingProperties::pointClippingBehavior",
+            self.physical_device_point_clipping_properties
+                .point_clipping_behavior
+                == other
+                    .physical_device_point_clipping_properties
+                    .point_clipping_behavior,
+            &self
+                .physical_device_point_clipping_properties
+                .point_clipping_behavior,
+            &other
+                .physical_device_point_clipping_properties
+                .point_clipping_behavior,
+        );
+        f(
+            11usize,
+            "VkPhysicalDeviceProtectedMemoryProperties::protectedNoFault",
+            self.physical_device_protected_memory_properties
+                .protected_no_fault
+                == other
+                    .physical_device_protected_memory_properties
+                    .protected_no_fault,
+            &self
+                .physical_device_protected_memory_properties
+                .protected_no_fault,
+            &other
+                .physical_device_protected_memory_properties
+                .protected_no_fault,
+        );
+        f(
+            12usize,
+            "VkPhysicalDeviceSamplerFilterMinmaxProperties::filterMinmaxSingleComponentFormats",
+            self.physical_device_sampler_filter_minmax_properties
+                .filter_minmax_single_component_formats
+                == other
+                    .physical_device_sampler_filter_minmax_properties
+                    .filter_minmax_single_component_formats,
+            &self
+                .physical_device_sampler_filter_minmax_properties
+                .filter_minmax_single_component_formats,
+            &other
+                .physical_device_sampler_filter_minmax_properties
+                .filter_minmax_single_component_formats,
+        );
+        f(
+            12usize,
+            "VkPhysicalDeviceSamplerFilterMinmaxProperties::filterMinmaxImageComponentMapping",
+            self.physical_device_sampler_filter_minmax_properties
+                .filter_minmax_image_component_mapping
+                == other
+                    .physical_device_sampler_filter_minmax_properties
+                    .filter_minmax_image_component_mapping,
+            &self
+                .physical_device_sampler_filter_minmax_properties
+                .filter_minmax_image_component_mapping,
+            &other
+                .physical_device_sampler_filter_minmax_properties
+                .filter_minmax_image_component_mapping,
+        );
+        f(
+            13usize,
+            "VkPhysicalDeviceSampleLocationsPropertiesEXT::sampleLocationSampleCounts",
+            self.physical_device_sample_locations_properties_ext
+                .sample_location_sample_counts
+                == other
+                    .physical_device_sample_locations_properties_ext
+                    .sample_location_sample_counts,
+            &self
+                .physical_device_sample_locations_properties_ext
+                .sample_location_sample_counts,
+            &other
+                .physical_device_sample_locations_properties_ext
+                .sample_location_sample_counts,
+        );
+        f(
+            13usize,
+            "VkPhysicalDeviceSampleLocationsPropertiesEXT::maxSampleLocationGridSize.width",
+            self.physical_device_sample_locations_properties_ext
+                .max_sample_location_grid_size
+                .width
+                == other
+                    .physical_device_sample_locations_properties_ext
+                    .max_sample_location_grid_size
+                    .width,
+            &self
+                .physical_device_sample_locations_properties_ext
+                .max_sample_location_grid_size
+                .width,
+            &other
+                .physical_device_sample_locations_properties_ext
+                .max_sample_location_grid_size
+                .width,
+        );
+        f(
+            13usize,
+            "VkPhysicalDeviceSampleLocationsPropertiesEXT::maxSampleLocationGridSize.height",
+            self.physical_device_sample_locations_properties_ext
+                .max_sample_location_grid_size
+                .height
+                == other
+                    .physical_device_sample_locations_properties_ext
+                    .max_sample_location_grid_size
+                    .height,
+            &self
+                .physical_device_sample_locations_properties_ext
+                .max_sample_location_grid_size
+                .height,
+            &other
+                .physical_device_sample_locations_properties_ext
+                .max_sample_location_grid_size
+                .height,
+        );
+        f(
+            13usize,
+            "VkPhysicalDeviceSampleLocationsPropertiesEXT::sampleLocationCoordinateRange",
+            self.physical_device_sample_locations_properties_ext
+                .sample_location_coordinate_range
+                == other
+                    .physical_device_sample_locations_properties_ext
+                    .sample_location_coordinate_range,
+            &self
+                .physical_device_sample_locations_properties_ext
+                .sample_location_coordinate_range,
+            &other
+                .physical_device_sample_locations_properties_ext
+                .sample_location_coordinate_range,
+        );
+        f(
+            13usize,
+            "VkPhysicalDeviceSampleLocationsPropertiesEXT::sampleLocationSubPixelBits",
+            self.physical_device_sample_locations_properties_ext
+                .sample_location_sub_pixel_bits
+                == other
+                    .physical_device_sample_locations_properties_ext
+                    .sample_location_sub_pixel_bits,
+            &self
+                .physical_device_sample_locations_properties_ext
+                .sample_location_sub_pixel_bits,
+            &other
+                .physical_device_sample_locations_properties_ext
+                .sample_location_sub_pixel_bits,
+        );
+        f(
+            13usize,
+            "VkPhysicalDeviceSampleLocationsPropertiesEXT::variableSampleLocations",
+            self.physical_device_sample_locations_properties_ext
+                .variable_sample_locations
+                == other
+                    .physical_device_sample_locations_properties_ext
+                    .variable_sample_locations,
+            &self
+                .physical_device_sample_locations_properties_ext
+                .variable_sample_locations,
+            &other
+                .physical_device_sample_locations_properties_ext
+                .variable_sample_locations,
+        );
+        f(
+            14usize,
+            "VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT::advancedBlendMaxColorAttachments",
+            self.physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_max_color_attachments
+                == other
+                    .physical_device_blend_operation_advanced_properties_ext
+                    .advanced_blend_max_color_attachments,
+            &self
+                .physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_max_color_attachments,
+            &other
+                .physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_max_color_attachments,
+        );
+        f(
+            14usize,
+            "VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT::advancedBlendIndependentBlend",
+            self.physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_independent_blend
+                == other
+                    .physical_device_blend_operation_advanced_properties_ext
+                    .advanced_blend_independent_blend,
+            &self
+                .physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_independent_blend,
+            &other
+                .physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_independent_blend,
+        );
+        f (14usize , "VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT::advancedBlendNonPremultipliedSrcColor" , self . physical_device_blend_operation_advanced_properties_ext . advanced_blend_non_premultiplied_src_color == other . physical_device_blend_operation_advanced_properties_ext . advanced_blend_non_premultiplied_src_color , & self . physical_device_blend_operation_advanced_properties_ext . advanced_blend_non_premultiplied_src_color , & other . physical_device_blend_operation_advanced_properties_ext . advanced_blend_non_premultiplied_src_color) ;
+        f (14usize , "VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT::advancedBlendNonPremultipliedDstColor" , self . physical_device_blend_operation_advanced_properties_ext . advanced_blend_non_premultiplied_dst_color == other . physical_device_blend_operation_advanced_properties_ext . advanced_blend_non_premultiplied_dst_color , & self . physical_device_blend_operation_advanced_properties_ext . advanced_blend_non_premultiplied_dst_color , & other . physical_device_blend_operation_advanced_properties_ext . advanced_blend_non_premultiplied_dst_color) ;
+        f(
+            14usize,
+            "VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT::advancedBlendCorrelatedOverlap",
+            self.physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_correlated_overlap
+                == other
+                    .physical_device_blend_operation_advanced_properties_ext
+                    .advanced_blend_correlated_overlap,
+            &self
+                .physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_correlated_overlap,
+            &other
+                .physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_correlated_overlap,
+        );
+        f(
+            14usize,
+            "VkPhysicalDeviceBlendOperationAdvancedPropertiesEXT::advancedBlendAllOperations",
+            self.physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_all_operations
+                == other
+                    .physical_device_blend_operation_advanced_properties_ext
+                    .advanced_blend_all_operations,
+            &self
+                .physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_all_operations,
+            &other
+                .physical_device_blend_operation_advanced_properties_ext
+                .advanced_blend_all_operations,
+        );
+        f(
+            15usize,
+            "VkPhysicalDeviceInlineUniformBlockProperties::maxInlineUniformBlockSize",
+            self.physical_device_inline_uniform_block_properties
+                .max_inline_uniform_block_size
+                == other
+                    .physical_device_inline_uniform_block_properties
+                    .max_inline_uniform_block_size,
+            &self
+                .physical_device_inline_uniform_block_properties
+                .max_inline_uniform_block_size,
+            &other
+                .physical_device_inline_uniform_block_properties
+                .max_inline_uniform_block_size,
+        );
+        f (15usize , "VkPhysicalDeviceInlineUniformBlockProperties::maxPerStageDescriptorInlineUniformBlocks" , self . physical_device_inline_uniform_block_properties . max_per_stage_descriptor_inline_uniform_blocks == other . physical_device_inline_uniform_block_properties . max_per_stage_descriptor_inline_uniform_blocks , & self . physical_device_inline_uniform_block_properties . max_per_stage_descriptor_inline_uniform_blocks , & other . physical_device_inline_uniform_block_properties . max_per_stage_descriptor_inline_uniform_blocks) ;
+        f (15usize , "VkPhysicalDeviceInlineUniformBlockProperties::maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks" , self . physical_device_inline_uniform_block_properties . max_per_stage_descriptor_update_after_bind_inline_uniform_blocks == other . physical_device_inline_uniform_block_properties . max_per_stage_descriptor_update_after_bind_inline_uniform_blocks , & self . physical_device_inline_uniform_block_properties . max_per_stage_descriptor_update_after_bind_inline_uniform_blocks , & other . physical_device_inline_uniform_block_properties . max_per_stage_descriptor_update_after_bind_inline_uniform_blocks) ;
+        f(
+            15usize,
+            "VkPhysicalDeviceInlineUniformBlockProperties::maxDescriptorSetInlineUniformBlocks",
+            self.physical_device_inline_uniform_block_properties
+                .max_descriptor_set_inline_uniform_blocks
+                == other
+                    .physical_device_inline_uniform_block_properties
+                    .max_descriptor_set_inline_uniform_blocks,
+            &self
+                .physical_device_inline_uniform_block_properties
+                .max_descriptor_set_inline_uniform_blocks,
+            &other
+                .physical_device_inline_uniform_block_properties
+                .max_descriptor_set_inline_uniform_blocks,
+        );
+        f (15usize , "VkPhysicalDeviceInlineUniformBlockProperties::maxDescriptorSetUpdateAfterBindInlineUniformBlocks" , self . physical_device_inline_uniform_block_properties . max_descriptor_set_update_after_bind_inline_uniform_blocks == other . physical_device_inline_uniform_block_properties . max_descriptor_set_update_after_bind_inline_uniform_blocks , & self . physical_device_inline_uniform_block_properties . max_descriptor_set_update_after_bind_inline_uniform_blocks , & other . physical_device_inline_uniform_block_properties . max_descriptor_set_update_after_bind_inline_uniform_blocks) ;
+        f(
+            16usize,
+            "VkPhysicalDeviceMaintenance3Properties::maxPerSetDescriptors",
+            self.physical_device_maintenance3_properties
+                .max_per_set_descriptors
+                == other
+                    .physical_device_maintenance3_properties
+                    .max_per_set_descriptors,
+            &self
+                .physical_device_maintenance3_properties
+                .max_per_set_descriptors,
+            &other
+                .physical_device_maintenance3_properties
+                .max_per_set_descriptors,
+        );
+        f(
+            16usize,
+            "VkPhysicalDeviceMaintenance3Properties::maxMemoryAllocationSize",
+            self.physical_device_maintenance3_properties
+                .max_memory_allocation_size
+                == other
+                    .physical_device_maintenance3_properties
+                    .max_memory_allocation_size,
+            &self
+                .physical_device_maintenance3_properties
+                .max_memory_allocation_size,
+            &other
+                .physical_device_maintenance3_properties
+                .max_memory_allocation_size,
+        );
+        f(
+            17usize,
+            "VkPhysicalDeviceMaintenance4Properties::maxBufferSize",
+            self.physical_device_maintenance4_properties.max_buffer_size
+                == other
+                    .physical_device_maintenance4_properties
+                    .max_buffer_size,
+            &self.physical_device_maintenance4_properties.max_buffer_size,
+            &other
+                .physical_device_maintenance4_properties
+                .max_buffer_size,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::denormBehaviorIndependence",
+            self.physical_device_float_controls_properties
+                .denorm_behavior_independence
+                == other
+                    .physical_device_float_controls_properties
+                    .denorm_behavior_independence,
+            &self
+                .physical_device_float_controls_properties
+                .denorm_behavior_independence,
+            &other
+                .physical_device_float_controls_properties
+                .denorm_behavior_independence,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::roundingModeIndependence",
+            self.physical_device_float_controls_properties
+                .rounding_mode_independence
+                == other
+                    .physical_device_float_controls_properties
+                    .rounding_mode_independence,
+            &self
+                .physical_device_float_controls_properties
+                .rounding_mode_independence,
+            &other
+                .physical_device_float_controls_properties
+                .rounding_mode_independence,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderSignedZeroInfNanPreserveFloat16",
+            self.physical_device_float_controls_properties
+                .shader_signed_zero_inf_nan_preserve_float16
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_signed_zero_inf_nan_preserve_float16,
+            &self
+                .physical_device_float_controls_properties
+                .shader_signed_zero_inf_nan_preserve_float16,
+            &other
+                .physical_device_float_controls_properties
+                .shader_signed_zero_inf_nan_preserve_float16,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderSignedZeroInfNanPreserveFloat32",
+            self.physical_device_float_controls_properties
+                .shader_signed_zero_inf_nan_preserve_float32
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_signed_zero_inf_nan_preserve_float32,
+            &self
+                .physical_device_float_controls_properties
+                .shader_signed_zero_inf_nan_preserve_float32,
+            &other
+                .physical_device_float_controls_properties
+                .shader_signed_zero_inf_nan_preserve_float32,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderSignedZeroInfNanPreserveFloat64",
+            self.physical_device_float_controls_properties
+                .shader_signed_zero_inf_nan_preserve_float64
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_signed_zero_inf_nan_preserve_float64,
+            &self
+                .physical_device_float_controls_properties
+                .shader_signed_zero_inf_nan_preserve_float64,
+            &other
+                .physical_device_float_controls_properties
+                .shader_signed_zero_inf_nan_preserve_float64,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderDenormPreserveFloat16",
+            self.physical_device_float_controls_properties
+                .shader_denorm_preserve_float16
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_denorm_preserve_float16,
+            &self
+                .physical_device_float_controls_properties
+                .shader_denorm_preserve_float16,
+            &other
+                .physical_device_float_controls_properties
+                .shader_denorm_preserve_float16,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderDenormPreserveFloat32",
+            self.physical_device_float_controls_properties
+                .shader_denorm_preserve_float32
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_denorm_preserve_float32,
+            &self
+                .physical_device_float_controls_properties
+                .shader_denorm_preserve_float32,
+            &other
+                .physical_device_float_controls_properties
+                .shader_denorm_preserve_float32,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderDenormPreserveFloat64",
+            self.physical_device_float_controls_properties
+                .shader_denorm_preserve_float64
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_denorm_preserve_float64,
+            &self
+                .physical_device_float_controls_properties
+                .shader_denorm_preserve_float64,
+            &other
+                .physical_device_float_controls_properties
+                .shader_denorm_preserve_float64,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderDenormFlushToZeroFloat16",
+            self.physical_device_float_controls_properties
+                .shader_denorm_flush_to_zero_float16
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_denorm_flush_to_zero_float16,
+            &self
+                .physical_device_float_controls_properties
+                .shader_denorm_flush_to_zero_float16,
+            &other
+                .physical_device_float_controls_properties
+                .shader_denorm_flush_to_zero_float16,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderDenormFlushToZeroFloat32",
+            self.physical_device_float_controls_properties
+                .shader_denorm_flush_to_zero_float32
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_denorm_flush_to_zero_float32,
+            &self
+                .physical_device_float_controls_properties
+                .shader_denorm_flush_to_zero_float32,
+            &other
+                .physical_device_float_controls_properties
+                .shader_denorm_flush_to_zero_float32,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderDenormFlushToZeroFloat64",
+            self.physical_device_float_controls_properties
+                .shader_denorm_flush_to_zero_float64
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_denorm_flush_to_zero_float64,
+            &self
+                .physical_device_float_controls_properties
+                .shader_denorm_flush_to_zero_float64,
+            &other
+                .physical_device_float_controls_properties
+                .shader_denorm_flush_to_zero_float64,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderRoundingModeRTEFloat16",
+            self.physical_device_float_controls_properties
+                .shader_rounding_mode_rte_float16
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_rounding_mode_rte_float16,
+            &self
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rte_float16,
+            &other
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rte_float16,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderRoundingModeRTEFloat32",
+            self.physical_device_float_controls_properties
+                .shader_rounding_mode_rte_float32
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_rounding_mode_rte_float32,
+            &self
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rte_float32,
+            &other
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rte_float32,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderRoundingModeRTEFloat64",
+            self.physical_device_float_controls_properties
+                .shader_rounding_mode_rte_float64
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_rounding_mode_rte_float64,
+            &self
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rte_float64,
+            &other
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rte_float64,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderRoundingModeRTZFloat16",
+            self.physical_device_float_controls_properties
+                .shader_rounding_mode_rtz_float16
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_rounding_mode_rtz_float16,
+            &self
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rtz_float16,
+            &other
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rtz_float16,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderRoundingModeRTZFloat32",
+            self.physical_device_float_controls_properties
+                .shader_rounding_mode_rtz_float32
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_rounding_mode_rtz_float32,
+            &self
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rtz_float32,
+            &other
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rtz_float32,
+        );
+        f(
+            18usize,
+            "VkPhysicalDeviceFloatControlsProperties::shaderRoundingModeRTZFloat64",
+            self.physical_device_float_controls_properties
+                .shader_rounding_mode_rtz_float64
+                == other
+                    .physical_device_float_controls_properties
+                    .shader_rounding_mode_rtz_float64,
+            &self
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rtz_float64,
+            &other
+                .physical_device_float_controls_properties
+                .shader_rounding_mode_rtz_float64,
+        );
+        f(
+            19usize,
+            "VkPhysicalDeviceExternalMemoryHostPropertiesEXT::minImportedHostPointerAlignment",
+            self.physical_device_external_memory_host_properties_ext
+                .min_imported_host_pointer_alignment
+                == other
+                    .physical_device_external_memory_host_properties_ext
+                    .min_imported_host_pointer_alignment,
+            &self
+                .physical_device_external_memory_host_properties_ext
+                .min_imported_host_pointer_alignment,
+            &other
+                .physical_device_external_memory_host_properties_ext
+                .min_imported_host_pointer_alignment,
+        );
+        f(
+            20usize,
+            "VkPhysicalDeviceConservativeRasterizationPropertiesEXT::primitiveOverestimationSize",
+            self.physical_device_conservative_rasterization_properties_ext
+                .primitive_overestimation_size
+                == other
+                    .physical_device_conservative_rasterization_properties_ext
+                    .primitive_overestimation_size,
+            &self
+                .physical_device_conservative_rasterization_properties_ext
+                .primitive_overestimation_size,
+            &other
+                .physical_device_conservative_rasterization_properties_ext
+                .primitive_overestimation_size,
+        );
+        f (20usize , "VkPhysicalDeviceConservativeRasterizationPropertiesEXT::maxExtraPrimitiveOverestimationSize" , self . physical_device_conservative_rasterization_properties_ext . max_extra_primitive_overestimation_size == other . physical_device_conservative_rasterization_properties_ext . max_extra_primitive_overestimation_size , & self . physical_device_conservative_rasterization_properties_ext . max_extra_primitive_overestimation_size , & other . physical_device_conservative_rasterization_properties_ext . max_extra_primitive_overestimation_size) ;
+        f (20usize , "VkPhysicalDeviceConservativeRasterizationPropertiesEXT::extraPrimitiveOverestimationSizeGranularity" , self . physical_device_conservative_rasterization_properties_ext . extra_primitive_overestimation_size_granularity == other . physical_device_conservative_rasterization_properties_ext . extra_primitive_overestimation_size_granularity , & self . physical_device_conservative_rasterization_properties_ext . extra_primitive_overestimation_size_granularity , & other . physical_device_conservative_rasterization_properties_ext . extra_primitive_overestimation_size_granularity) ;
+        f(
+            20usize,
+            "VkPhysicalDeviceConservativeRasterizationPropertiesEXT::primitiveUnderestimation",
+            self.physical_device_conservative_rasterization_properties_ext
+                .primitive_underestimation
+                == other
+                    .physical_device_conservative_rasterization_properties_ext
+                    .primitive_underestimation,
+            &self
+                .physical_device_conservative_rasterization_properties_ext
+                .primitive_underestimation,
+            &other
+                .physical_device_conservative_rasterization_properties_ext
+                .primitive_underestimation,
+        );
+        f (20usize , "VkPhysicalDeviceConservativeRasterizationPropertiesEXT::conservativePointAndLineRasterization" , self . physical_device_conservative_rasterization_properties_ext . conservative_point_and_line_rasterization == other . physical_device_conservative_rasterization_properties_ext . conservative_point_and_line_rasterization , & self . physical_device_conservative_rasterization_properties_ext . conservative_point_and_line_rasterization , & other . physical_device_conservative_rasterization_properties_ext . conservative_point_and_line_rasterization) ;
+        f(
+            20usize,
+            "VkPhysicalDeviceConservativeRasterizationPropertiesEXT::degenerateTrianglesRasterized",
+            self.physical_device_conservative_rasterization_properties_ext
+                .degenerate_triangles_rasterized
+                == other
+                    .physical_device_conservative_rasterization_properties_ext
+                    .degenerate_triangles_rasterized,
+            &self
+                .physical_device_conservative_rasterization_properties_ext
+                .degenerate_triangles_rasterized,
+            &other
+                .physical_device_conservative_rasterization_properties_ext
+                .degenerate_triangles_rasterized,
+        );
+        f(
+            20usize,
+            "VkPhysicalDeviceConservativeRasterizationPropertiesEXT::degenerateLinesRasterized",
+            self.physical_device_conservative_rasterization_properties_ext
+                .degenerate_lines_rasterized
+                == other
+                    .physical_device_conservative_rasterization_properties_ext
+                    .degenerate_lines_rasterized,
+            &self
+                .physical_device_conservative_rasterization_properties_ext
+                .degenerate_lines_rasterized,
+            &other
+                .physical_device_conservative_rasterization_properties_ext
+                .degenerate_lines_rasterized,
+        );
+        f (20usize , "VkPhysicalDeviceConservativeRasterizationPropertiesEXT::fullyCoveredFragmentShaderInputVariable" , self . physical_device_conservative_rasterization_properties_ext . fully_covered_fragment_shader_input_variable == other . physical_device_conservative_rasterization_properties_ext . fully_covered_fragment_shader_input_variable , & self . physical_device_conservative_rasterization_properties_ext . fully_covered_fragment_shader_input_variable , & other . physical_device_conservative_rasterization_properties_ext . fully_covered_fragment_shader_input_variable) ;
+        f (20usize , "VkPhysicalDeviceConservativeRasterizationPropertiesEXT::conservativeRasterizationPostDepthCoverage" , self . physical_device_conservative_rasterization_properties_ext . conservative_rasterization_post_depth_coverage == other . physical_device_conservative_rasterization_properties_ext . conservative_rasterization_post_depth_coverage , & self . physical_device_conservative_rasterization_properties_ext . conservative_rasterization_post_depth_coverage , & other . physical_device_conservative_rasterization_properties_ext . conservative_rasterization_post_depth_coverage) ;
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::shaderEngineCount",
+            self.physical_device_shader_core_properties_amd
+                .shader_engine_count
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .shader_engine_count,
+            &self
+                .physical_device_shader_core_properties_amd
+                .shader_engine_count,
+            &other
+                .physical_device_shader_core_properties_amd
+                .shader_engine_count,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::shaderArraysPerEngineCount",
+            self.physical_device_shader_core_properties_amd
+                .shader_arrays_per_engine_count
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .shader_arrays_per_engine_count,
+            &self
+                .physical_device_shader_core_properties_amd
+                .shader_arrays_per_engine_count,
+            &other
+                .physical_device_shader_core_properties_amd
+                .shader_arrays_per_engine_count,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::computeUnitsPerShaderArray",
+            self.physical_device_shader_core_properties_amd
+                .compute_units_per_shader_array
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .compute_units_per_shader_array,
+            &self
+                .physical_device_shader_core_properties_amd
+                .compute_units_per_shader_array,
+            &other
+                .physical_device_shader_core_properties_amd
+                .compute_units_per_shader_array,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::simdPerComputeUnit",
+            self.physical_device_shader_core_properties_amd
+                .simd_per_compute_unit
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .simd_per_compute_unit,
+            &self
+                .physical_device_shader_core_properties_amd
+                .simd_per_compute_unit,
+            &other
+                .physical_device_shader_core_properties_amd
+                .simd_per_compute_unit,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::wavefrontsPerSimd",
+            self.physical_device_shader_core_properties_amd
+                .wavefronts_per_simd
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .wavefronts_per_simd,
+            &self
+                .physical_device_shader_core_properties_amd
+                .wavefronts_per_simd,
+            &other
+                .physical_device_shader_core_properties_amd
+                .wavefronts_per_simd,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::wavefrontSize",
+            self.physical_device_shader_core_properties_amd
+                .wavefront_size
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .wavefront_size,
+            &self
+                .physical_device_shader_core_properties_amd
+                .wavefront_size,
+            &other
+                .physical_device_shader_core_properties_amd
+                .wavefront_size,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::sgprsPerSimd",
+            self.physical_device_shader_core_properties_amd
+                .sgprs_per_simd
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .sgprs_per_simd,
+            &self
+                .physical_device_shader_core_properties_amd
+                .sgprs_per_simd,
+            &other
+                .physical_device_shader_core_properties_amd
+                .sgprs_per_simd,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::minSgprAllocation",
+            self.physical_device_shader_core_properties_amd
+                .min_sgpr_allocation
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .min_sgpr_allocation,
+            &self
+                .physical_device_shader_core_properties_amd
+                .min_sgpr_allocation,
+            &other
+                .physical_device_shader_core_properties_amd
+                .min_sgpr_allocation,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::maxSgprAllocation",
+            self.physical_device_shader_core_properties_amd
+                .max_sgpr_allocation
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .max_sgpr_allocation,
+            &self
+                .physical_device_shader_core_properties_amd
+                .max_sgpr_allocation,
+            &other
+                .physical_device_shader_core_properties_amd
+                .max_sgpr_allocation,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::sgprAllocationGranularity",
+            self.physical_device_shader_core_properties_amd
+                .sgpr_allocation_granularity
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .sgpr_allocation_granularity,
+            &self
+                .physical_device_shader_core_properties_amd
+                .sgpr_allocation_granularity,
+            &other
+                .physical_device_shader_core_properties_amd
+                .sgpr_allocation_granularity,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::vgprsPerSimd",
+            self.physical_device_shader_core_properties_amd
+                .vgprs_per_simd
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .vgprs_per_simd,
+            &self
+                .physical_device_shader_core_properties_amd
+                .vgprs_per_simd,
+            &other
+                .physical_device_shader_core_properties_amd
+                .vgprs_per_simd,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::minVgprAllocation",
+            self.physical_device_shader_core_properties_amd
+                .min_vgpr_allocation
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .min_vgpr_allocation,
+            &self
+                .physical_device_shader_core_properties_amd
+                .min_vgpr_allocation,
+            &other
+                .physical_device_shader_core_properties_amd
+                .min_vgpr_allocation,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::maxVgprAllocation",
+            self.physical_device_shader_core_properties_amd
+                .max_vgpr_allocation
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .max_vgpr_allocation,
+            &self
+                .physical_device_shader_core_properties_amd
+                .max_vgpr_allocation,
+            &other
+                .physical_device_shader_core_properties_amd
+                .max_vgpr_allocation,
+        );
+        f(
+            21usize,
+            "VkPhysicalDeviceShaderCorePropertiesAMD::vgprAllocationGranularity",
+            self.physical_device_shader_core_properties_amd
+                .vgpr_allocation_granularity
+                == other
+                    .physical_device_shader_core_properties_amd
+                    .vgpr_allocation_granularity,
+            &self
+                .physical_device_shader_core_properties_amd
+                .vgpr_allocation_granularity,
+            &other
+                .physical_device_shader_core_properties_amd
+                .vgpr_allocation_granularity,
+        );
+        f(
+            22usize,
+            "VkPhysicalDeviceShaderCoreProperties2AMD::shaderCoreFeatures",
+            self.physical_device_shader_core_properties2_amd
+                .shader_core_features
+                == other
+                    .physical_device_shader_core_properties2_amd
+                    .shader_core_features,
+            &self
+                .physical_device_shader_core_properties2_amd
+                .shader_core_features,
+            &other
+                .physical_device_shader_core_properties2_amd
+                .shader_core_features,
+        );
+        f(
+            22usize,
+            "VkPhysicalDeviceShaderCoreProperties2AMD::activeComputeUnitCount",
+            self.physical_device_shader_core_properties2_amd
+                .active_compute_unit_count
+                == other
+                    .physical_device_shader_core_properties2_amd
+                    .active_compute_unit_count,
+            &self
+                .physical_device_shader_core_properties2_amd
+                .active_compute_unit_count,
+            &other
+                .physical_device_shader_core_properties2_amd
+                .active_compute_unit_count,
+        );
+        f(
+            23usize,
+            "VkPhysicalDeviceDescriptorIndexingProperties::maxUpdateAfterBindDescriptorsInAllPools",
+            self.physical_device_descriptor_indexing_properties
+                .max_update_after_bind_descriptors_in_all_pools
+                == other
+                    .physical_device_descriptor_indexing_properties
+                    .max_update_after_bind_descriptors_in_all_pools,
+            &self
+                .physical_device_descriptor_indexing_properties
+                .max_update_after_bind_descriptors_in_all_pools,
+            &other
+                .physical_device_descriptor_indexing_properties
+                .max_update_after_bind_descriptors_in_all_pools,
+        );
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::shaderUniformBufferArrayNonUniformIndexingNative" , self . physical_device_descriptor_indexing_properties . shader_uniform_buffer_array_non_uniform_indexing_native == other . physical_device_descriptor_indexing_properties . shader_uniform_buffer_array_non_uniform_indexing_native , & self . physical_device_descriptor_indexing_properties . shader_uniform_buffer_array_non_uniform_indexing_native , & other . physical_device_descriptor_indexing_properties . shader_uniform_buffer_array_non_uniform_indexing_native) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::shaderSampledImageArrayNonUniformIndexingNative" , self . physical_device_descriptor_indexing_properties . shader_sampled_image_array_non_uniform_indexing_native == other . physical_device_descriptor_indexing_properties . shader_sampled_image_array_non_uniform_indexing_native , & self . physical_device_descriptor_indexing_properties . shader_sampled_image_array_non_uniform_indexing_native , & other . physical_device_descriptor_indexing_properties . shader_sampled_image_array_non_uniform_indexing_native) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::shaderStorageBufferArrayNonUniformIndexingNative" , self . physical_device_descriptor_indexing_properties . shader_storage_buffer_array_non_uniform_indexing_native == other . physical_device_descriptor_indexing_properties . shader_storage_buffer_array_non_uniform_indexing_native , & self . physical_device_descriptor_indexing_properties . shader_storage_buffer_array_non_uniform_indexing_native , & other . physical_device_descriptor_indexing_properties . shader_storage_buffer_array_non_uniform_indexing_native) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::shaderStorageImageArrayNonUniformIndexingNative" , self . physical_device_descriptor_indexing_properties . shader_storage_image_array_non_uniform_indexing_native == other . physical_device_descriptor_indexing_properties . shader_storage_image_array_non_uniform_indexing_native , & self . physical_device_descriptor_indexing_properties . shader_storage_image_array_non_uniform_indexing_native , & other . physical_device_descriptor_indexing_properties . shader_storage_image_array_non_uniform_indexing_native) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::shaderInputAttachmentArrayNonUniformIndexingNative" , self . physical_device_descriptor_indexing_properties . shader_input_attachment_array_non_uniform_indexing_native == other . physical_device_descriptor_indexing_properties . shader_input_attachment_array_non_uniform_indexing_native , & self . physical_device_descriptor_indexing_properties . shader_input_attachment_array_non_uniform_indexing_native , & other . physical_device_descriptor_indexing_properties . shader_input_attachment_array_non_uniform_indexing_native) ;
+        f(
+            23usize,
+            "VkPhysicalDeviceDescriptorIndexingProperties::robustBufferAccessUpdateAfterBind",
+            self.physical_device_descriptor_indexing_properties
+                .robust_buffer_access_update_after_bind
+                == other
+                    .physical_device_descriptor_indexing_properties
+                    .robust_buffer_access_update_after_bind,
+            &self
+                .physical_device_descriptor_indexing_properties
+                .robust_buffer_access_update_after_bind,
+            &other
+                .physical_device_descriptor_indexing_properties
+                .robust_buffer_access_update_after_bind,
+        );
+        f(
+            23usize,
+            "VkPhysicalDeviceDescriptorIndexingProperties::quadDivergentImplicitLod",
+            self.physical_device_descriptor_indexing_properties
+                .quad_divergent_implicit_lod
+                == other
+                    .physical_device_descriptor_indexing_properties
+                    .quad_divergent_implicit_lod,
+            &self
+                .physical_device_descriptor_indexing_properties
+                .quad_divergent_implicit_lod,
+            &other
+                .physical_device_descriptor_indexing_properties
+                .quad_divergent_implicit_lod,
+        );
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxPerStageDescriptorUpdateAfterBindSamplers" , self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_samplers == other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_samplers , & self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_samplers , & other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_samplers) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxPerStageDescriptorUpdateAfterBindUniformBuffers" , self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_uniform_buffers == other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_uniform_buffers , & self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_uniform_buffers , & other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_uniform_buffers) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxPerStageDescriptorUpdateAfterBindStorageBuffers" , self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_storage_buffers == other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_storage_buffers , & self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_storage_buffers , & other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_storage_buffers) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxPerStageDescriptorUpdateAfterBindSampledImages" , self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_sampled_images == other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_sampled_images , & self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_sampled_images , & other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_sampled_images) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxPerStageDescriptorUpdateAfterBindStorageImages" , self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_storage_images == other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_storage_images , & self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_storage_images , & other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_storage_images) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxPerStageDescriptorUpdateAfterBindInputAttachments" , self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_input_attachments == other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_input_attachments , & self . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_input_attachments , & other . physical_device_descriptor_indexing_properties . max_per_stage_descriptor_update_after_bind_input_attachments) ;
+        f(
+            23usize,
+            "VkPhysicalDeviceDescriptorIndexingProperties::maxPerStageUpdateAfterBindResources",
+            self.physical_device_descriptor_indexing_properties
+                .max_per_stage_update_after_bind_resources
+                == other
+                    .physical_device_descriptor_indexing_properties
+                    .max_per_stage_update_after_bind_resources,
+            &self
+                .physical_device_descriptor_indexing_properties
+                .max_per_stage_update_after_bind_resources,
+            &other
+                .physical_device_descriptor_indexing_properties
+                .max_per_stage_update_after_bind_resources,
+        );
+        f(
+            23usize,
+            "VkPhysicalDeviceDescriptorIndexingProperties::maxDescriptorSetUpdateAfterBindSamplers",
+            self.physical_device_descriptor_indexing_properties
+                .max_descriptor_set_update_after_bind_samplers
+                == other
+                    .physical_device_descriptor_indexing_properties
+                    .max_descriptor_set_update_after_bind_samplers,
+            &self
+                .physical_device_descriptor_indexing_properties
+                .max_descriptor_set_update_after_bind_samplers,
+            &other
+                .physical_device_descriptor_indexing_properties
+                .max_descriptor_set_update_after_bind_samplers,
+        );
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxDescriptorSetUpdateAfterBindUniformBuffers" , self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_uniform_buffers == other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_uniform_buffers , & self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_uniform_buffers , & other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_uniform_buffers) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxDescriptorSetUpdateAfterBindUniformBuffersDynamic" , self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_uniform_buffers_dynamic == other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_uniform_buffers_dynamic , & self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_uniform_buffers_dynamic , & other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_uniform_buffers_dynamic) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxDescriptorSetUpdateAfterBindStorageBuffers" , self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_buffers == other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_buffers , & self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_buffers , & other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_buffers) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxDescriptorSetUpdateAfterBindStorageBuffersDynamic" , self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_buffers_dynamic == other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_buffers_dynamic , & self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_buffers_dynamic , & other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_buffers_dynamic) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxDescriptorSetUpdateAfterBindSampledImages" , self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_sampled_images == other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_sampled_images , & self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_sampled_images , & other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_sampled_images) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxDescriptorSetUpdateAfterBindStorageImages" , self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_images == other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_images , & self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_images , & other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_storage_images) ;
+        f (23usize , "VkPhysicalDeviceDescriptorIndexingProperties::maxDescriptorSetUpdateAfterBindInputAttachments" , self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_input_attachments == other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_input_attachments , & self . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_input_attachments , & other . physical_device_descriptor_indexing_properties . max_descriptor_set_update_after_bind_input_attachments) ;
+        f(
+            24usize,
+            "VkPhysicalDeviceTimelineSemaphoreProperties::maxTimelineSemaphoreValueDifference",
+            self.physical_device_timeline_semaphore_properties
+                .max_timeline_semaphore_value_difference
+                == other
+                    .physical_device_timeline_semaphore_properties
+                    .max_timeline_semaphore_value_difference,
+            &self
+                .physical_device_timeline_semaphore_properties
+                .max_timeline_semaphore_value_difference,
+            &other
+                .physical_device_timeline_semaphore_properties
+                .max_timeline_semaphore_value_difference,
+        );
+        f(
+            25usize,
+            "VkPhysicalDeviceVertexAttributeDivisorPropertiesEXT::maxVertexAttribDivisor",
+            self.physical_device_vertex_attribute_divisor_properties_ext
+                .max_vertex_attrib_divisor
+                == other
+                    .physical_device_vertex_attribute_divisor_properties_ext
+                    .max_vertex_attrib_divisor,
+            &self
+                .physical_device_vertex_attribute_divisor_properties_ext
+                .max_vertex_attrib_divisor,
+            &other
+                .physical_device_vertex_attribute_divisor_properties_ext
+                .max_vertex_attrib_divisor,
+        );
+        f(
+            26usize,
+            "VkPhysicalDevicePCIBusInfoPropertiesEXT::pciDomain",
+            self.physical_device_pci_bus_info_properties_ext.pci_domain
+                == other.physical_device_pci_bus_info_properties_ext.pci_domain,
+            &self.physical_device_pci_bus_info_properties_ext.pci_domain,
+            &other.physical_device_pci_bus_info_properties_ext.pci_domain,
+        );
+        f(
+            26usize,
+            "VkPhysicalDevicePCIBusInfoPropertiesEXT::pciBus",
+            self.physical_device_pci_bus_info_properties_ext.pci_bus
+                == other.physical_device_pci_bus_info_properties_ext.pci_bus,
+            &self.physical_device_pci_bus_info_properties_ext.pci_bus,
+            &other.physical_device_pci_bus_info_properties_ext.pci_bus,
+        );
+        f(
+            26usize,
+            "VkPhysicalDevicePCIBusInfoPropertiesEXT::pciDevice",
+            self.physical_device_pci_bus_info_properties_ext.pci_device
+                == other.physical_device_pci_bus_info_properties_ext.pci_device,
+            &self.physical_device_pci_bus_info_properties_ext.pci_device,
+            &other.physical_device_pci_bus_info_properties_ext.pci_device,
+        );
+        f(
+            26usize,
+            "VkPhysicalDevicePCIBusInfoPropertiesEXT::pciFunction",
+            self.physical_device_pci_bus_info_properties_ext
+                .pci_function
+                == other
+                    .physical_device_pci_bus_info_properties_ext
+                    .pci_function,
+            &self
+                .physical_device_pci_bus_info_properties_ext
+                .pci_function,
+            &other
+                .physical_device_pci_bus_info_properties_ext
+                .pci_function,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceDepthStencilResolveProperties::supportedDepthResolveModes",
+            self.physical_device_depth_stencil_resolve_properties
+                .supported_depth_resolve_modes
+                == other
+                    .physical_device_depth_stencil_resolve_properties
+                    .supported_depth_resolve_modes,
+            &self
+                .physical_device_depth_stencil_resolve_properties
+                .supported_depth_resolve_modes,
+            &other
+                .physical_device_depth_stencil_resolve_properties
+                .supported_depth_resolve_modes,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceDepthStencilResolveProperties::supportedStencilResolveModes",
+            self.physical_device_depth_stencil_resolve_properties
+                .supported_stencil_resolve_modes
+                == other
+                    .physical_device_depth_stencil_resolve_properties
+                    .supported_stencil_resolve_modes,
+            &self
+                .physical_device_depth_stencil_resolve_properties
+                .supported_stencil_resolve_modes,
+            &other
+                .physical_device_depth_stencil_resolve_properties
+                .supported_stencil_resolve_modes,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceDepthStencilResolveProperties::independentResolveNone",
+            self.physical_device_depth_stencil_resolve_properties
+                .independent_resolve_none
+                == other
+                    .physical_device_depth_stencil_resolve_properties
+                    .independent_resolve_none,
+            &self
+                .physical_device_depth_stencil_resolve_properties
+                .independent_resolve_none,
+            &other
+                .physical_device_depth_stencil_resolve_properties
+                .independent_resolve_none,
+        );
+        f(
+            27usize,
+            "VkPhysicalDeviceDepthStencilResolveProperties::independentResolve",
+            self.physical_device_depth_stencil_resolve_properties
+                .independent_resolve
+                == other
+                    .physical_device_depth_stencil_resolve_properties
+                    .independent_resolve,
+            &self
+                .physical_device_depth_stencil_resolve_properties
+                .independent_resolve,
+            &other
+                .physical_device_depth_stencil_resolve_properties
+                .independent_resolve,
+        );
+        f(
+            28usize,
+            "VkPhysicalDeviceTransformFeedbackPropertiesEXT::maxTransformFeedbackStreams",
+            self.physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_streams
+                == other
+                    .physical_device_transform_feedback_properties_ext
+                    .max_transform_feedback_streams,
+            &self
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_streams,
+            &other
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_streams,
+        );
+        f(
+            28usize,
+            "VkPhysicalDeviceTransformFeedbackPropertiesEXT::maxTransformFeedbackBuffers",
+            self.physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffers
+                == other
+                    .physical_device_transform_feedback_properties_ext
+                    .max_transform_feedback_buffers,
+            &self
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffers,
+            &other
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffers,
+        );
+        f(
+            28usize,
+            "VkPhysicalDeviceTransformFeedbackPropertiesEXT::maxTransformFeedbackBufferSize",
+            self.physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffer_size
+                == other
+                    .physical_device_transform_feedback_properties_ext
+                    .max_transform_feedback_buffer_size,
+            &self
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffer_size,
+            &other
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffer_size,
+        );
+        f(
+            28usize,
+            "VkPhysicalDeviceTransformFeedbackPropertiesEXT::maxTransformFeedbackStreamDataSize",
+            self.physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_stream_data_size
+                == other
+                    .physical_device_transform_feedback_properties_ext
+                    .max_transform_feedback_stream_data_size,
+            &self
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_stream_data_size,
+            &other
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_stream_data_size,
+        );
+        f(
+            28usize,
+            "VkPhysicalDeviceTransformFeedbackPropertiesEXT::maxTransformFeedbackBufferDataSize",
+            self.physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffer_data_size
+                == other
+                    .physical_device_transform_feedback_properties_ext
+                    .max_transform_feedback_buffer_data_size,
+            &self
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffer_data_size,
+            &other
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffer_data_size,
+        );
+        f(
+            28usize,
+            "VkPhysicalDeviceTransformFeedbackPropertiesEXT::maxTransformFeedbackBufferDataStride",
+            self.physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffer_data_stride
+                == other
+                    .physical_device_transform_feedback_properties_ext
+                    .max_transform_feedback_buffer_data_stride,
+            &self
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffer_data_stride,
+            &other
+                .physical_device_transform_feedback_properties_ext
+                .max_transform_feedback_buffer_data_stride,
+        );
+        f(
+            28usize,
+            "VkPhysicalDeviceTransformFeedbackPropertiesEXT::transformFeedbackQueries",
+            self.physical_device_transform_feedback_properties_ext
+                .transform_feedback_queries
+                == other
+                    .physical_device_transform_feedback_properties_ext
+                    .transform_feedback_queries,
+            &self
+                .physical_device_transform_feedback_properties_ext
+                .transform_feedback_queries,
+            &other
+                .physical_device_transform_feedback_properties_ext
+                .transform_feedback_queries,
+        );
+        f (28usize , "VkPhysicalDeviceTransformFeedbackPropertiesEXT::transformFeedbackStreamsLinesTriangles" , self . physical_device_transform_feedback_properties_ext . transform_feedback_streams_lines_triangles == other . physical_device_transform_feedback_properties_ext . transform_feedback_streams_lines_triangles , & self . physical_device_transform_feedback_properties_ext . transform_feedback_streams_lines_triangles , & other . physical_device_transform_feedback_properties_ext . transform_feedback_streams_lines_triangles) ;
+        f (28usize , "VkPhysicalDeviceTransformFeedbackPropertiesEXT::transformFeedbackRasterizationStreamSelect" , self . physical_device_transform_feedback_properties_ext . transform_feedback_rasterization_stream_select == other . physical_device_transform_feedback_properties_ext . transform_feedback_rasterization_stream_select , & self . physical_device_transform_feedback_properties_ext . transform_feedback_rasterization_stream_select , & other . physical_device_transform_feedback_properties_ext . transform_feedback_rasterization_stream_select) ;
+        f(
+            28usize,
+            "VkPhysicalDeviceTransformFeedbackPropertiesEXT::transformFeedbackDraw",
+            self.physical_device_transform_feedback_properties_ext
+                .transform_feedback_draw
+                == other
+                    .physical_device_transform_feedback_properties_ext
+                    .transform_feedback_draw,
+            &self
+                .physical_device_transform_feedback_properties_ext
+                .transform_feedback_draw,
+            &other
+                .physical_device_transform_feedback_properties_ext
+                .transform_feedback_draw,
+        );
+        f(
+            29usize,
+            "VkPhysicalDeviceCopyMemoryIndirectPropertiesNV::supportedQueues",
+            self.physical_device_copy_memory_indirect_properties_nv
+                .supported_queues
+                == other
+                    .physical_device_copy_memory_indirect_properties_nv
+                    .supported_queues,
+            &self
+                .physical_device_copy_memory_indirect_properties_nv
+                .supported_queues,
+            &other
+                .physical_device_copy_memory_indirect_properties_nv
+                .supported_queues,
+        );
+        f(
+            30usize,
+            "VkPhysicalDeviceMemoryDecompressionPropertiesNV::decompressionMethods",
+            self.physical_device_memory_decompression_properties_nv
+                .decompression_methods
+                == other
+                    .physical_device_memory_decompression_properties_nv
+                    .decompression_methods,
+            &self
+                .physical_device_memory_decompression_properties_nv
+                .decompression_methods,
+            &other
+                .physical_device_memory_decompression_properties_nv
+                .decompression_methods,
+        );
+        f(
+            30usize,
+            "VkPhysicalDeviceMemoryDecompressionPropertiesNV::maxDecompressionIndirectCount",
+            self.physical_device_memory_decompression_properties_nv
+                .max_decompression_indirect_count
+                == other
+                    .physical_device_memory_decompression_properties_nv
+                    .max_decompression_indirect_count,
+            &self
+                .physical_device_memory_decompression_properties_nv
+                .max_decompression_indirect_count,
+            &other
+                .physical_device_memory_decompression_properties_nv
+                .max_decompression_indirect_count,
+        );
+        f(
+            31usize,
+            "VkPhysicalDeviceShadingRateImagePropertiesNV::shadingRateTexelSize.width",
+            self.physical_device_shading_rate_image_properties_nv
+                .shading_rate_texel_size
+                .width
+                == other
+                    .physical_device_shading_rate_image_properties_nv
+                    .shading_rate_texel_size
+                    .width,
+            &self
+                .physical_device_shading_rate_image_properties_nv
+                .shading_rate_texel_size
+                .width,
+            &other
+                .physical_device_shading_rate_image_properties_nv
+                .shading_rate_texel_size
+                .width,
+        );
+        f(
+            31usize,
+            "VkPhysicalDeviceShadingRateImagePropertiesNV::shadingRateTexelSize.height",
+            self.physical_device_shading_rate_image_properties_nv
+                .shading_rate_texel_size
+                .height
+                == other
+                    .physical_device_shading_rate_image_properties_nv
+                    .shading_rate_texel_size
+                    .height,
+            &self
+                .physical_device_shading_rate_image_properties_nv
+                .shading_rate_texel_size
+                .height,
+            &other
+                .physical_device_shading_rate_image_properties_nv
+                .shading_rate_texel_size
+                .height,
+        );
+        f(
+            31usize,
+            "VkPhysicalDeviceShadingRateImagePropertiesNV::shadingRatePaletteSize",
+            self.physical_device_shading_rate_image_properties_nv
+                .shading_rate_palette_size
+                == other
+                    .physical_device_shading_rate_image_properties_nv
+                    .shading_rate_palette_size,
+            &self
+                .physical_device_shading_rate_image_properties_nv
+                .shading_rate_palette_size,
+            &other
+                .physical_device_shading_rate_image_properties_nv
+                .shading_rate_palette_size,
+        );
+        f(
+            31usize,
+            "VkPhysicalDeviceShadingRateImagePropertiesNV::shadingRateMaxCoarseSamples",
+            self.physical_device_shading_rate_image_properties_nv
+                .shading_rate_max_coarse_samples
+                == other
+                    .physical_device_shading_rate_image_properties_nv
+                    .shading_rate_max_coarse_samples,
+            &self
+                .physical_device_shading_rate_image_properties_nv
+                .shading_rate_max_coarse_samples,
+            &other
+                .physical_device_shading_rate_image_properties_nv
+                .shading_rate_max_coarse_samples,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxDrawMeshTasksCount",
+            self.physical_device_mesh_shader_properties_nv
+                .max_draw_mesh_tasks_count
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_draw_mesh_tasks_count,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_draw_mesh_tasks_count,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_draw_mesh_tasks_count,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxTaskWorkGroupInvocations",
+            self.physical_device_mesh_shader_properties_nv
+                .max_task_work_group_invocations
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_task_work_group_invocations,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_task_work_group_invocations,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_task_work_group_invocations,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxTaskWorkGroupSize",
+            self.physical_device_mesh_shader_properties_nv
+                .max_task_work_group_size
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_task_work_group_size,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_task_work_group_size,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_task_work_group_size,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxTaskTotalMemorySize",
+            self.physical_device_mesh_shader_properties_nv
+                .max_task_total_memory_size
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_task_total_memory_size,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_task_total_memory_size,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_task_total_memory_size,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxTaskOutputCount",
+            self.physical_device_mesh_shader_properties_nv
+                .max_task_output_count
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_task_output_count,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_task_output_count,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_task_output_count,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxMeshWorkGroupInvocations",
+            self.physical_device_mesh_shader_properties_nv
+                .max_mesh_work_group_invocations
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_mesh_work_group_invocations,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_work_group_invocations,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_work_group_invocations,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxMeshWorkGroupSize",
+            self.physical_device_mesh_shader_properties_nv
+                .max_mesh_work_group_size
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_mesh_work_group_size,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_work_group_size,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_work_group_size,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxMeshTotalMemorySize",
+            self.physical_device_mesh_shader_properties_nv
+                .max_mesh_total_memory_size
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_mesh_total_memory_size,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_total_memory_size,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_total_memory_size,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxMeshOutputVertices",
+            self.physical_device_mesh_shader_properties_nv
+                .max_mesh_output_vertices
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_mesh_output_vertices,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_output_vertices,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_output_vertices,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxMeshOutputPrimitives",
+            self.physical_device_mesh_shader_properties_nv
+                .max_mesh_output_primitives
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_mesh_output_primitives,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_output_primitives,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_output_primitives,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::maxMeshMultiviewViewCount",
+            self.physical_device_mesh_shader_properties_nv
+                .max_mesh_multiview_view_count
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .max_mesh_multiview_view_count,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_multiview_view_count,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .max_mesh_multiview_view_count,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::meshOutputPerVertexGranularity",
+            self.physical_device_mesh_shader_properties_nv
+                .mesh_output_per_vertex_granularity
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .mesh_output_per_vertex_granularity,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .mesh_output_per_vertex_granularity,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .mesh_output_per_vertex_granularity,
+        );
+        f(
+            32usize,
+            "VkPhysicalDeviceMeshShaderPropertiesNV::meshOutputPerPrimitiveGranularity",
+            self.physical_device_mesh_shader_properties_nv
+                .mesh_output_per_primitive_granularity
+                == other
+                    .physical_device_mesh_shader_properties_nv
+                    .mesh_output_per_primitive_granularity,
+            &self
+                .physical_device_mesh_shader_properties_nv
+                .mesh_output_per_primitive_granularity,
+            &other
+                .physical_device_mesh_shader_properties_nv
+                .mesh_output_per_primitive_granularity,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxTaskWorkGroupTotalCount",
+            self.physical_device_mesh_shader_properties_ext
+                .max_task_work_group_total_count
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_task_work_group_total_count,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_task_work_group_total_count,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_task_work_group_total_count,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxTaskWorkGroupCount",
+            self.physical_device_mesh_shader_properties_ext
+                .max_task_work_group_count
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_task_work_group_count,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_task_work_group_count,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_task_work_group_count,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxTaskWorkGroupInvocations",
+            self.physical_device_mesh_shader_properties_ext
+                .max_task_work_group_invocations
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_task_work_group_invocations,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_task_work_group_invocations,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_task_work_group_invocations,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxTaskWorkGroupSize",
+            self.physical_device_mesh_shader_properties_ext
+                .max_task_work_group_size
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_task_work_group_size,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_task_work_group_size,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_task_work_group_size,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxTaskPayloadSize",
+            self.physical_device_mesh_shader_properties_ext
+                .max_task_payload_size
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_task_payload_size,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_task_payload_size,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_task_payload_size,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxTaskSharedMemorySize",
+            self.physical_device_mesh_shader_properties_ext
+                .max_task_shared_memory_size
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_task_shared_memory_size,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_task_shared_memory_size,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_task_shared_memory_size,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxTaskPayloadAndSharedMemorySize",
+            self.physical_device_mesh_shader_properties_ext
+                .max_task_payload_and_shared_memory_size
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_task_payload_and_shared_memory_size,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_task_payload_and_shared_memory_size,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_task_payload_and_shared_memory_size,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshWorkGroupTotalCount",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_total_count
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_work_group_total_count,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_total_count,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_total_count,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshWorkGroupCount",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_count
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_work_group_count,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_count,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_count,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshWorkGroupInvocations",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_invocations
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_work_group_invocations,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_invocations,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_invocations,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshWorkGroupSize",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_size
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_work_group_size,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_size,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_work_group_size,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshSharedMemorySize",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_shared_memory_size
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_shared_memory_size,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_shared_memory_size,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_shared_memory_size,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshPayloadAndSharedMemorySize",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_payload_and_shared_memory_size
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_payload_and_shared_memory_size,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_payload_and_shared_memory_size,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_payload_and_shared_memory_size,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshOutputMemorySize",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_output_memory_size
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_output_memory_size,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_output_memory_size,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_output_memory_size,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshPayloadAndOutputMemorySize",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_payload_and_output_memory_size
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_payload_and_output_memory_size,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_payload_and_output_memory_size,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_payload_and_output_memory_size,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshOutputComponents",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_output_components
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_output_components,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_output_components,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_output_components,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshOutputVertices",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_output_vertices
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_output_vertices,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_output_vertices,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_output_vertices,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshOutputPrimitives",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_output_primitives
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_output_primitives,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_output_primitives,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_output_primitives,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshOutputLayers",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_output_layers
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_output_layers,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_output_layers,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_output_layers,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxMeshMultiviewViewCount",
+            self.physical_device_mesh_shader_properties_ext
+                .max_mesh_multiview_view_count
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_mesh_multiview_view_count,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_multiview_view_count,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_mesh_multiview_view_count,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::meshOutputPerVertexGranularity",
+            self.physical_device_mesh_shader_properties_ext
+                .mesh_output_per_vertex_granularity
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .mesh_output_per_vertex_granularity,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .mesh_output_per_vertex_granularity,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .mesh_output_per_vertex_granularity,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::meshOutputPerPrimitiveGranularity",
+            self.physical_device_mesh_shader_properties_ext
+                .mesh_output_per_primitive_granularity
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .mesh_output_per_primitive_granularity,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .mesh_output_per_primitive_granularity,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .mesh_output_per_primitive_granularity,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxPreferredTaskWorkGroupInvocations",
+            self.physical_device_mesh_shader_properties_ext
+                .max_preferred_task_work_group_invocations
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_preferred_task_work_group_invocations,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_preferred_task_work_group_invocations,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_preferred_task_work_group_invocations,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::maxPreferredMeshWorkGroupInvocations",
+            self.physical_device_mesh_shader_properties_ext
+                .max_preferred_mesh_work_group_invocations
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .max_preferred_mesh_work_group_invocations,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .max_preferred_mesh_work_group_invocations,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .max_preferred_mesh_work_group_invocations,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::prefersLocalInvocationVertexOutput",
+            self.physical_device_mesh_shader_properties_ext
+                .prefers_local_invocation_vertex_output
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .prefers_local_invocation_vertex_output,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .prefers_local_invocation_vertex_output,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .prefers_local_invocation_vertex_output,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::prefersLocalInvocationPrimitiveOutput",
+            self.physical_device_mesh_shader_properties_ext
+                .prefers_local_invocation_primitive_output
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .prefers_local_invocation_primitive_output,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .prefers_local_invocation_primitive_output,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .prefers_local_invocation_primitive_output,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::prefersCompactVertexOutput",
+            self.physical_device_mesh_shader_properties_ext
+                .prefers_compact_vertex_output
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .prefers_compact_vertex_output,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .prefers_compact_vertex_output,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .prefers_compact_vertex_output,
+        );
+        f(
+            33usize,
+            "VkPhysicalDeviceMeshShaderPropertiesEXT::prefersCompactPrimitiveOutput",
+            self.physical_device_mesh_shader_properties_ext
+                .prefers_compact_primitive_output
+                == other
+                    .physical_device_mesh_shader_properties_ext
+                    .prefers_compact_primitive_output,
+            &self
+                .physical_device_mesh_shader_properties_ext
+                .prefers_compact_primitive_output,
+            &other
+                .physical_device_mesh_shader_properties_ext
+                .prefers_compact_primitive_output,
+        );
+        f(
+            34usize,
+            "VkPhysicalDeviceAccelerationStructurePropertiesKHR::maxGeometryCount",
+            self.physical_device_acceleration_structure_properties_khr
+                .max_geometry_count
+                == other
+                    .physical_device_acceleration_structure_properties_khr
+                    .max_geometry_count,
+            &self
+                .physical_device_acceleration_structure_properties_khr
+                .max_geometry_count,
+            &other
+                .physical_device_acceleration_structure_properties_khr
+                .max_geometry_count,
+        );
+        f(
+            34usize,
+            "VkPhysicalDeviceAccelerationStructurePropertiesKHR::maxInstanceCount",
+            self.physical_device_acceleration_structure_properties_khr
+                .max_instance_count
+                == other
+                    .physical_device_acceleration_structure_properties_khr
+                    .max_instance_count,
+            &self
+                .physical_device_acceleration_structure_properties_khr
+                .max_instance_count,
+            &other
+                .physical_device_acceleration_structure_properties_khr
+                .max_instance_count,
+        );
+        f(
+            34usize,
+            "VkPhysicalDeviceAccelerationStructurePropertiesKHR::maxPrimitiveCount",
+            self.physical_device_acceleration_structure_properties_khr
+                .max_primitive_count
+                == other
+                    .physical_device_acceleration_structure_properties_khr
+                    .max_primitive_count,
+            &self
+                .physical_device_acceleration_structure_properties_khr
+                .max_primitive_count,
+            &other
+                .physical_device_acceleration_structure_properties_khr
+                .max_primitive_count,
+        );
+        f (34usize , "VkPhysicalDeviceAccelerationStructurePropertiesKHR::maxPerStageDescriptorAccelerationStructures" , self . physical_device_acceleration_structure_properties_khr . max_per_stage_descriptor_acceleration_structures == other . physical_device_acceleration_structure_properties_khr . max_per_stage_descriptor_acceleration_structures , & self . physical_device_acceleration_structure_properties_khr . max_per_stage_descriptor_acceleration_structures , & other . physical_device_acceleration_structure_properties_khr . max_per_stage_descriptor_acceleration_structures) ;
+        f (34usize , "VkPhysicalDeviceAccelerationStructurePropertiesKHR::maxPerStageDescriptorUpdateAfterBindAccelerationStructures" , self . physical_device_acceleration_structure_properties_khr . max_per_stage_descriptor_update_after_bind_acceleration_structures == other . physical_device_acceleration_structure_properties_khr . max_per_stage_descriptor_update_after_bind_acceleration_structures , & self . physical_device_acceleration_structure_properties_khr . max_per_stage_descriptor_update_after_bind_acceleration_structures , & other . physical_device_acceleration_structure_properties_khr . max_per_stage_descriptor_update_after_bind_acceleration_structures) ;
+        f (34usize , "VkPhysicalDeviceAccelerationStructurePropertiesKHR::maxDescriptorSetAccelerationStructures" , self . physical_device_acceleration_structure_properties_khr . max_descriptor_set_acceleration_structures == other . physical_device_acceleration_structure_properties_khr . max_descriptor_set_acceleration_structures , & self . physical_device_acceleration_structure_properties_khr . max_descriptor_set_acceleration_structures , & other . physical_device_acceleration_structure_properties_khr . max_descriptor_set_acceleration_structures) ;
+        f (34usize , "VkPhysicalDeviceAccelerationStructurePropertiesKHR::maxDescriptorSetUpdateAfterBindAccelerationStructures" , self . physical_device_acceleration_structure_properties_khr . max_descriptor_set_update_after_bind_acceleration_structures == other . physical_device_acceleration_structure_properties_khr . max_descriptor_set_update_after_bind_acceleration_structures , & self . physical_device_acceleration_structure_properties_khr . max_descriptor_set_update_after_bind_acceleration_structures , & other . physical_device_acceleration_structure_properties_khr . max_descriptor_set_update_after_bind_acceleration_structures) ;
+        f (34usize , "VkPhysicalDeviceAccelerationStructurePropertiesKHR::minAccelerationStructureScratchOffsetAlignment" , self . physical_device_acceleration_structure_properties_khr . min_acceleration_structure_scratch_offset_alignment == other . physical_device_acceleration_structure_properties_khr . min_acceleration_structure_scratch_offset_alignment , & self . physical_device_acceleration_structure_properties_khr . min_acceleration_structure_scratch_offset_alignment , & other . physical_device_acceleration_structure_properties_khr . min_acceleration_structure_scratch_offset_alignment) ;
+        f(
+            35usize,
+            "VkPhysicalDeviceRayTracingPipelinePropertiesKHR::shaderGroupHandleSize",
+            self.physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_handle_size
+                == other
+                    .physical_device_ray_tracing_pipeline_properties_khr
+                    .shader_group_handle_size,
+            &self
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_handle_size,
+            &other
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_handle_size,
+        );
+        f(
+            35usize,
+            "VkPhysicalDeviceRayTracingPipelinePropertiesKHR::maxRayRecursionDepth",
+            self.physical_device_ray_tracing_pipeline_properties_khr
+                .max_ray_recursion_depth
+                == other
+                    .physical_device_ray_tracing_pipeline_properties_khr
+                    .max_ray_recursion_depth,
+            &self
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .max_ray_recursion_depth,
+            &other
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .max_ray_recursion_depth,
+        );
+        f(
+            35usize,
+            "VkPhysicalDeviceRayTracingPipelinePropertiesKHR::maxShaderGroupStride",
+            self.physical_device_ray_tracing_pipeline_properties_khr
+                .max_shader_group_stride
+                == other
+                    .physical_device_ray_tracing_pipeline_properties_khr
+                    .max_shader_group_stride,
+            &self
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .max_shader_group_stride,
+            &other
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .max_shader_group_stride,
+        );
+        f(
+            35usize,
+            "VkPhysicalDeviceRayTracingPipelinePropertiesKHR::shaderGroupBaseAlignment",
+            self.physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_base_alignment
+                == other
+                    .physical_device_ray_tracing_pipeline_properties_khr
+                    .shader_group_base_alignment,
+            &self
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_base_alignment,
+            &other
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_base_alignment,
+        );
+        f(
+            35usize,
+            "VkPhysicalDeviceRayTracingPipelinePropertiesKHR::shaderGroupHandleCaptureReplaySize",
+            self.physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_handle_capture_replay_size
+                == other
+                    .physical_device_ray_tracing_pipeline_properties_khr
+                    .shader_group_handle_capture_replay_size,
+            &self
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_handle_capture_replay_size,
+            &other
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_handle_capture_replay_size,
+        );
+        f(
+            35usize,
+            "VkPhysicalDeviceRayTracingPipelinePropertiesKHR::maxRayDispatchInvocationCount",
+            self.physical_device_ray_tracing_pipeline_properties_khr
+                .max_ray_dispatch_invocation_count
+                == other
+                    .physical_device_ray_tracing_pipeline_properties_khr
+                    .max_ray_dispatch_invocation_count,
+            &self
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .max_ray_dispatch_invocation_count,
+            &other
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .max_ray_dispatch_invocation_count,
+        );
+        f(
+            35usize,
+            "VkPhysicalDeviceRayTracingPipelinePropertiesKHR::shaderGroupHandleAlignment",
+            self.physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_handle_alignment
+                == other
+                    .physical_device_ray_tracing_pipeline_properties_khr
+                    .shader_group_handle_alignment,
+            &self
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_handle_alignment,
+            &other
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .shader_group_handle_alignment,
+        );
+        f(
+            35usize,
+            "VkPhysicalDeviceRayTracingPipelinePropertiesKHR::maxRayHitAttributeSize",
+            self.physical_device_ray_tracing_pipeline_properties_khr
+                .max_ray_hit_attribute_size
+                == other
+                    .physical_device_ray_tracing_pipeline_properties_khr
+                    .max_ray_hit_attribute_size,
+            &self
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .max_ray_hit_attribute_size,
+            &other
+                .physical_device_ray_tracing_pipeline_properties_khr
+                .max_ray_hit_attribute_size,
+        );
+        f(
+            36usize,
+            "VkPhysicalDeviceRayTracingPropertiesNV::shaderGroupHandleSize",
+            self.physical_device_ray_tracing_properties_nv
+                .shader_group_handle_size
+                == other
+                    .physical_device_ray_tracing_properties_nv
+                    .shader_group_handle_size,
+            &self
+                .physical_device_ray_tracing_properties_nv
+                .shader_group_handle_size,
+            &other
+                .physical_device_ray_tracing_properties_nv
+                .shader_group_handle_size,
+        );
+        f(
+            36usize,
+            "VkPhysicalDeviceRayTracingPropertiesNV::maxRecursionDepth",
+            self.physical_device_ray_tracing_properties_nv
+                .max_recursion_depth
+                == other
+                    .physical_device_ray_tracing_properties_nv
+                    .max_recursion_depth,
+            &self
+                .physical_device_ray_tracing_properties_nv
+                .max_recursion_depth,
+            &other
+                .physical_device_ray_tracing_properties_nv
+                .max_recursion_depth,
+        );
+        f(
+            36usize,
+            "VkPhysicalDeviceRayTracingPropertiesNV::maxShaderGroupStride",
+            self.physical_device_ray_tracing_properties_nv
+                .max_shader_group_stride
+                == other
+                    .physical_device_ray_tracing_properties_nv
+                    .max_shader_group_stride,
+            &self
+                .physical_device_ray_tracing_properties_nv
+                .max_shader_group_stride,
+            &other
+                .physical_device_ray_tracing_properties_nv
+                .max_shader_group_stride,
+        );
+        f(
+            36usize,
+            "VkPhysicalDeviceRayTracingPropertiesNV::shaderGroupBaseAlignment",
+            self.physical_device_ray_tracing_properties_nv
+                .shader_group_base_alignment
+                == other
+                    .physical_device_ray_tracing_properties_nv
+                    .shader_group_base_alignment,
+            &self
+                .physical_device_ray_tracing_properties_nv
+                .shader_group_base_alignment,
+            &other
+                .physical_device_ray_tracing_properties_nv
+                .shader_group_base_alignment,
+        );
+        f(
+            36usize,
+            "VkPhysicalDeviceRayTracingPropertiesNV::maxGeometryCount",
+            self.physical_device_ray_tracing_properties_nv
+                .max_geometry_count
+                == other
+                    .physical_device_ray_tracing_properties_nv
+                    .max_geometry_count,
+            &self
+                .physical_device_ray_tracing_properties_nv
+                .max_geometry_count,
+            &other
+                .physical_device_ray_tracing_properties_nv
+                .max_geometry_count,
+        );
+        f(
+            36usize,
+            "VkPhysicalDeviceRayTracingPropertiesNV::maxInstanceCount",
+            self.physical_device_ray_tracing_properties_nv
+                .max_instance_count
+                == other
+                    .physical_device_ray_tracing_properties_nv
+                    .max_instance_count,
+            &self
+                .physical_device_ray_tracing_properties_nv
+                .max_instance_count,
+            &other
+                .physical_device_ray_tracing_properties_nv
+                .max_instance_count,
+        );
+        f(
+            36usize,
+            "VkPhysicalDeviceRayTracingPropertiesNV::maxTriangleCount",
+            self.physical_device_ray_tracing_properties_nv
+                .max_triangle_count
+                == other
+                    .physical_device_ray_tracing_properties_nv
+                    .max_triangle_count,
+            &self
+                .physical_device_ray_tracing_properties_nv
+                .max_triangle_count,
+            &other
+                .physical_device_ray_tracing_properties_nv
+                .max_triangle_count,
+        );
+        f(
+            36usize,
+            "VkPhysicalDeviceRayTracingPropertiesNV::maxDescriptorSetAccelerationStructures",
+            self.physical_device_ray_tracing_properties_nv
+                .max_descriptor_set_acceleration_structures
+                == other
+                    .physical_device_ray_tracing_properties_nv
+                    .max_descriptor_set_acceleration_structures,
+            &self
+                .physical_device_ray_tracing_properties_nv
+                .max_descriptor_set_acceleration_structures,
+            &other
+                .physical_device_ray_tracing_properties_nv
+                .max_descriptor_set_acceleration_structures,
+        );
+        f(
+            37usize,
+            "VkPhysicalDeviceFragmentDensityMapPropertiesEXT::minFragmentDensityTexelSize.width",
+            self.physical_device_fragment_density_map_properties_ext
+                .min_fragment_density_texel_size
+                .width
+                == other
+                    .physical_device_fragment_density_map_properties_ext
+                    .min_fragment_density_texel_size
+                    .width,
+            &self
+                .physical_device_fragment_density_map_properties_ext
+                .min_fragment_density_texel_size
+                .width,
+            &other
+                .physical_device_fragment_density_map_properties_ext
+                .min_fragment_density_texel_size
+                .width,
+        );
+        f(
+            37usize,
+            "VkPhysicalDeviceFragmentDensityMapPropertiesEXT::minFragmentDensityTexelSize.height",
+            self.physical_device_fragment_density_map_properties_ext
+                .min_fragment_density_texel_size
+                .height
+                == other
+                    .physical_device_fragment_density_map_properties_ext
+                    .min_fragment_density_texel_size
+                    .height,
+            &self
+                .physical_device_fragment_density_map_properties_ext
+                .min_fragment_density_texel_size
+                .height,
+            &other
+                .physical_device_fragment_density_map_properties_ext
+                .min_fragment_density_texel_size
+                .height,
+        );
+        f(
+            37usize,
+            "VkPhysicalDeviceFragmentDensityMapPropertiesEXT::maxFragmentDensityTexelSize.width",
+            self.physical_device_fragment_density_map_properties_ext
+                .max_fragment_density_texel_size
+                .width
+                == other
+                    .physical_device_fragment_density_map_properties_ext
+                    .max_fragment_density_texel_size
+                    .width,
+            &self
+                .physical_device_fragment_density_map_properties_ext
+                .max_fragment_density_texel_size
+                .width,
+            &other
+                .physical_device_fragment_density_map_properties_ext
+                .max_fragment_density_texel_size
+                .width,
+        );
+        f(
+            37usize,
+            "VkPhysicalDeviceFragmentDensityMapPropertiesEXT::maxFragmentDensityTexelSize.height",
+            self.physical_device_fragment_density_map_properties_ext
+                .max_fragment_density_texel_size
+                .height
+                == other
+                    .physical_device_fragment_density_map_properties_ext
+                    .max_fragment_density_texel_size
+                    .height,
+            &self
+                .physical_device_fragment_density_map_properties_ext
+                .max_fragment_density_texel_size
+                .height,
+            &other
+                .physical_device_fragment_density_map_properties_ext
+                .max_fragment_density_texel_size
+                .height,
+        );
+        f(
+            37usize,
+            "VkPhysicalDeviceFragmentDensityMapPropertiesEXT::fragmentDensityInvocations",
+            self.physical_device_fragment_density_map_properties_ext
+                .fragment_density_invocations
+                == other
+                    .physical_device_fragment_density_map_properties_ext
+                    .fragment_density_invocations,
+            &self
+                .physical_device_fragment_density_map_properties_ext
+                .fragment_density_invocations,
+            &other
+                .physical_device_fragment_density_map_properties_ext
+                .fragment_density_invocations,
+        );
+        f(
+            38usize,
+            "VkPhysicalDeviceFragmentDensityMap2PropertiesEXT::subsampledLoads",
+            self.physical_device_fragment_density_map2_properties_ext
+                .subsampled_loads
+                == other
+                    .physical_device_fragment_density_map2_properties_ext
+                    .subsampled_loads,
+            &self
+                .physical_device_fragment_density_map2_properties_ext
+                .subsampled_loads,
+            &other
+                .physical_device_fragment_density_map2_properties_ext
+                .subsampled_loads,
+        );
+        f (38usize , "VkPhysicalDeviceFragmentDensityMap2PropertiesEXT::subsampledCoarseReconstructionEarlyAccess" , self . physical_device_fragment_density_map2_properties_ext . subsampled_coarse_reconstruction_early_access == other . physical_device_fragment_density_map2_properties_ext . subsampled_coarse_reconstruction_early_access , & self . physical_device_fragment_density_map2_properties_ext . subsampled_coarse_reconstruction_early_access , & other . physical_device_fragment_density_map2_properties_ext . subsampled_coarse_reconstruction_early_access) ;
+        f(
+            38usize,
+            "VkPhysicalDeviceFragmentDensityMap2PropertiesEXT::maxSubsampledArrayLayers",
+            self.physical_device_fragment_density_map2_properties_ext
+                .max_subsampled_array_layers
+                == other
+                    .physical_device_fragment_density_map2_properties_ext
+                    .max_subsampled_array_layers,
+            &self
+                .physical_device_fragment_density_map2_properties_ext
+                .max_subsampled_array_layers,
+            &other
+                .physical_device_fragment_density_map2_properties_ext
+                .max_subsampled_array_layers,
+        );
+        f(
+            38usize,
+            "VkPhysicalDeviceFragmentDensityMap2PropertiesEXT::maxDescriptorSetSubsampledSamplers",
+            self.physical_device_fragment_density_map2_properties_ext
+                .max_descriptor_set_subsampled_samplers
+                == other
+                    .physical_device_fragment_density_map2_properties_ext
+                    .max_descriptor_set_subsampled_samplers,
+            &self
+                .physical_device_fragment_density_map2_properties_ext
+                .max_descriptor_set_subsampled_samplers,
+            &other
+                .physical_device_fragment_density_map2_properties_ext
+                .max_descriptor_set_subsampled_samplers,
+        );
+        f (39usize , "VkPhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM::fragmentDensityOffsetGranularity.width" , self . physical_device_fragment_density_map_offset_properties_qcom . fragment_density_offset_granularity . width == other . physical_device_fragment_density_map_offset_properties_qcom . fragment_density_offset_granularity . width , & self . physical_device_fragment_density_map_offset_properties_qcom . fragment_density_offset_granularity . width , & other . physical_device_fragment_density_map_offset_properties_qcom . fragment_density_offset_granularity . width) ;
+        f (39usize , "VkPhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM::fragmentDensityOffsetGranularity.height" , self . physical_device_fragment_density_map_offset_properties_qcom . fragment_density_offset_granularity . height == other . physical_device_fragment_density_map_offset_properties_qcom . fragment_density_offset_granularity . height , & self . physical_device_fragment_density_map_offset_properties_qcom . fragment_density_offset_granularity . height , & other . physical_device_fragment_density_map_offset_properties_qcom . fragment_density_offset_granularity . height) ;
+        f(
+            40usize,
+            "VkPhysicalDeviceCooperativeMatrixPropertiesNV::cooperativeMatrixSupportedStages",
+            self.physical_device_cooperative_matrix_properties_nv
+                .cooperative_matrix_supported_stages
+                == other
+                    .physical_device_cooperative_matrix_properties_nv
+                    .cooperative_matrix_supported_stages,
+            &self
+                .physical_device_cooperative_matrix_properties_nv
+                .cooperative_matrix_supported_stages,
+            &other
+                .physical_device_cooperative_matrix_properties_nv
+                .cooperative_matrix_supported_stages,
+        );
+        f(
+            41usize,
+            "VkPhysicalDevicePerformanceQueryPropertiesKHR::allowCommandBufferQueryCopies",
+            self.physical_device_performance_query_properties_khr
+                .allow_command_buffer_query_copies
+                == other
+                    .physical_device_performance_query_properties_khr
+                    .allow_command_buffer_query_copies,
+            &self
+                .physical_device_performance_query_properties_khr
+                .allow_command_buffer_query_copies,
+            &other
+                .physical_device_performance_query_properties_khr
+                .allow_command_buffer_query_copies,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceShaderSMBuiltinsPropertiesNV::shaderSMCount",
+            self.physical_device_shader_sm_builtins_properties_nv
+                .shader_sm_count
+                == other
+                    .physical_device_shader_sm_builtins_properties_nv
+                    .shader_sm_count,
+            &self
+                .physical_device_shader_sm_builtins_properties_nv
+                .shader_sm_count,
+            &other
+                .physical_device_shader_sm_builtins_properties_nv
+                .shader_sm_count,
+        );
+        f(
+            42usize,
+            "VkPhysicalDeviceShaderSMBuiltinsPropertiesNV::shaderWarpsPerSM",
+            self.physical_device_shader_sm_builtins_properties_nv
+                .shader_warps_per_sm
+                == other
+                    .physical_device_shader_sm_builtins_properties_nv
+                    .shader_warps_per_sm,
+            &self
+                .physical_device_shader_sm_builtins_properties_nv
+                .shader_warps_per_sm,
+            &other
+                .physical_device_shader_sm_builtins_properties_nv
+                .shader_warps_per_sm,
+        );
+        f (43usize , "VkPhysicalDeviceTexelBufferAlignmentProperties::storageTexelBufferOffsetAlignmentBytes" , self . physical_device_texel_buffer_alignment_properties . storage_texel_buffer_offset_alignment_bytes == other . physical_device_texel_buffer_alignment_properties . storage_texel_buffer_offset_alignment_bytes , & self . physical_device_texel_buffer_alignment_properties . storage_texel_buffer_offset_alignment_bytes , & other . physical_device_texel_buffer_alignment_properties . storage_texel_buffer_offset_alignment_bytes) ;
+        f (43usize , "VkPhysicalDeviceTexelBufferAlignmentProperties::storageTexelBufferOffsetSingleTexelAlignment" , self . physical_device_texel_buffer_alignment_properties . storage_texel_buffer_offset_single_texel_alignment == other . physical_device_texel_buffer_alignment_properties . storage_texel_buffer_offset_single_texel_alignment , & self . physical_device_texel_buffer_alignment_properties . storage_texel_buffer_offset_single_texel_alignment , & other . physical_device_texel_buffer_alignment_properties . storage_texel_buffer_offset_single_texel_alignment) ;
+        f (43usize , "VkPhysicalDeviceTexelBufferAlignmentProperties::uniformTexelBufferOffsetAlignmentBytes" , self . physical_device_texel_buffer_alignment_properties . uniform_texel_buffer_offset_alignment_bytes == other . physical_device_texel_buffer_alignment_properties . uniform_texel_buffer_offset_alignment_bytes , & self . physical_device_texel_buffer_alignment_properties . uniform_texel_buffer_offset_alignment_bytes , & other . physical_device_texel_buffer_alignment_properties . uniform_texel_buffer_offset_alignment_bytes) ;
+        f (43usize , "VkPhysicalDeviceTexelBufferAlignmentProperties::uniformTexelBufferOffsetSingleTexelAlignment" , self . physical_device_texel_buffer_alignment_properties . uniform_texel_buffer_offset_single_texel_alignment == other . physical_device_texel_buffer_alignment_properties . uniform_texel_buffer_offset_single_texel_alignment , & self . physical_device_texel_buffer_alignment_properties . uniform_texel_buffer_offset_single_texel_alignment , & other . physical_device_texel_buffer_alignment_properties . uniform_texel_buffer_offset_single_texel_alignment) ;
+        f(
+            44usize,
+            "VkPhysicalDeviceSubgroupSizeControlProperties::minSubgroupSize",
+            self.physical_device_subgroup_size_control_properties
+                .min_subgroup_size
+                == other
+                    .physical_device_subgroup_size_control_properties
+                    .min_subgroup_size,
+            &self
+                .physical_device_subgroup_size_control_properties
+                .min_subgroup_size,
+            &other
+                .physical_device_subgroup_size_control_properties
+                .min_subgroup_size,
+        );
+        f(
+            44usize,
+            "VkPhysicalDeviceSubgroupSizeControlProperties::maxSubgroupSize",
+            self.physical_device_subgroup_size_control_properties
+                .max_subgroup_size
+                == other
+                    .physical_device_subgroup_size_control_properties
+                    .max_subgroup_size,
+            &self
+                .physical_device_subgroup_size_control_properties
+                .max_subgroup_size,
+            &other
+                .physical_device_subgroup_size_control_properties
+                .max_subgroup_size,
+        );
+        f(
+            44usize,
+            "VkPhysicalDeviceSubgroupSizeControlProperties::maxComputeWorkgroupSubgroups",
+            self.physical_device_subgroup_size_control_properties
+                .max_compute_workgroup_subgroups
+                == other
+                    .physical_device_subgroup_size_control_properties
+                    .max_compute_workgroup_subgroups,
+            &self
+                .physical_device_subgroup_size_control_properties
+                .max_compute_workgroup_subgroups,
+            &other
+                .physical_device_subgroup_size_control_properties
+                .max_compute_workgroup_subgroups,
+        );
+        f(
+            44usize,
+            "VkPhysicalDeviceSubgroupSizeControlProperties::requiredSubgroupSizeStages",
+            self.physical_device_subgroup_size_control_properties
+                .required_subgroup_size_stages
+                == other
+                    .physical_device_subgroup_size_control_properties
+                    .required_subgroup_size_stages,
+            &self
+                .physical_device_subgroup_size_control_properties
+                .required_subgroup_size_stages,
+            &other
+                .physical_device_subgroup_size_control_properties
+                .required_subgroup_size_stages,
+        );
+        f (45usize , "VkPhysicalDeviceSubpassShadingPropertiesHUAWEI::maxSubpassShadingWorkgroupSizeAspectRatio" , self . physical_device_subpass_shading_properties_huawei . max_subpass_shading_workgroup_size_aspect_ratio == other . physical_device_subpass_shading_properties_huawei . max_subpass_shading_workgroup_size_aspect_ratio , & self . physical_device_subpass_shading_properties_huawei . max_subpass_shading_workgroup_size_aspect_ratio , & other . physical_device_subpass_shading_properties_huawei . max_subpass_shading_workgroup_size_aspect_ratio) ;
+        f(
+            46usize,
+            "VkPhysicalDeviceClusterCullingShaderPropertiesHUAWEI::maxWorkGroupCount",
+            self.physical_device_cluster_culling_shader_properties_huawei
+                .max_work_group_count
+                == other
+                    .physical_device_cluster_culling_shader_properties_huawei
+                    .max_work_group_count,
+            &self
+                .physical_device_cluster_culling_shader_properties_huawei
+                .max_work_group_count,
+            &other
+                .physical_device_cluster_culling_shader_properties_huawei
+                .max_work_group_count,
+        );
+        f(
+            46usize,
+            "VkPhysicalDeviceClusterCullingShaderPropertiesHUAWEI::maxWorkGroupSize",
+            self.physical_device_cluster_culling_shader_properties_huawei
+                .max_work_group_size
+                == other
+                    .physical_device_cluster_culling_shader_properties_huawei
+                    .max_work_group_size,
+            &self
+                .physical_device_cluster_culling_shader_properties_huawei
+                .max_work_group_size,
+            &other
+                .physical_device_cluster_culling_shader_properties_huawei
+                .max_work_group_size,
+        );
+        f(
+            46usize,
+            "VkPhysicalDeviceClusterCullingShaderPropertiesHUAWEI::maxOutputClusterCount",
+            self.physical_device_cluster_culling_shader_properties_huawei
+                .max_output_cluster_count
+                == other
+                    .physical_device_cluster_culling_shader_properties_huawei
+                    .max_output_cluster_count,
+            &self
+                .physical_device_cluster_culling_shader_properties_huawei
+                .max_output_cluster_count,
+            &other
+                .physical_device_cluster_culling_shader_properties_huawei
+                .max_output_cluster_count,
+        );
+        f(
+            46usize,
+            "VkPhysicalDeviceClusterCullingShaderPropertiesHUAWEI::indirectBufferOffsetAlignment",
+            self.physical_device_cluster_culling_shader_properties_huawei
+                .indirect_buffer_offset_alignment
+                == other
+                    .physical_device_cluster_culling_shader_properties_huawei
+                    .indirect_buffer_offset_alignment,
+            &self
+                .physical_device_cluster_culling_shader_properties_huawei
+                .indirect_buffer_offset_alignment,
+            &other
+                .physical_device_cluster_culling_shader_properties_huawei
+                .indirect_buffer_offset_alignment,
+        );
+        f(
+            47usize,
+            "VkPhysicalDeviceLineRasterizationPropertiesEXT::lineSubPixelPrecisionBits",
+            self.physical_device_line_rasterization_properties_ext
+                .line_sub_pixel_precision_bits
+                == other
+                    .physical_device_line_rasterization_properties_ext
+                    .line_sub_pixel_precision_bits,
+            &self
+                .physical_device_line_rasterization_properties_ext
+                .line_sub_pixel_precision_bits,
+            &other
+                .physical_device_line_rasterization_properties_ext
+                .line_sub_pixel_precision_bits,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::deviceUUID",
+            self.physical_device_vulkan11_properties.device_uuid
+                == other.physical_device_vulkan11_properties.device_uuid,
+            &self.physical_device_vulkan11_properties.device_uuid,
+            &other.physical_device_vulkan11_properties.device_uuid,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::driverUUID",
+            self.physical_device_vulkan11_properties.driver_uuid
+                == other.physical_device_vulkan11_properties.driver_uuid,
+            &self.physical_device_vulkan11_properties.driver_uuid,
+            &other.physical_device_vulkan11_properties.driver_uuid,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::deviceLUID",
+            self.physical_device_vulkan11_properties.device_luid
+                == other.physical_device_vulkan11_properties.device_luid,
+            &self.physical_device_vulkan11_properties.device_luid,
+            &other.physical_device_vulkan11_properties.device_luid,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::deviceNodeMask",
+            self.physical_device_vulkan11_properties.device_node_mask
+                == other.physical_device_vulkan11_properties.device_node_mask,
+            &self.physical_device_vulkan11_properties.device_node_mask,
+            &other.physical_device_vulkan11_properties.device_node_mask,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::deviceLUIDValid",
+            self.physical_device_vulkan11_properties.device_luid_valid
+                == other.physical_device_vulkan11_properties.device_luid_valid,
+            &self.physical_device_vulkan11_properties.device_luid_valid,
+            &other.physical_device_vulkan11_properties.device_luid_valid,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::subgroupSize",
+            self.physical_device_vulkan11_properties.subgroup_size
+                == other.physical_device_vulkan11_properties.subgroup_size,
+            &self.physical_device_vulkan11_properties.subgroup_size,
+            &other.physical_device_vulkan11_properties.subgroup_size,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::subgroupSupportedStages",
+            self.physical_device_vulkan11_properties
+                .subgroup_supported_stages
+                == other
+                    .physical_device_vulkan11_properties
+                    .subgroup_supported_stages,
+            &self
+                .physical_device_vulkan11_properties
+                .subgroup_supported_stages,
+            &other
+                .physical_device_vulkan11_properties
+                .subgroup_supported_stages,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::subgroupSupportedOperations",
+            self.physical_device_vulkan11_properties
+                .subgroup_supported_operations
+                == other
+                    .physical_device_vulkan11_properties
+                    .subgroup_supported_operations,
+            &self
+                .physical_device_vulkan11_properties
+                .subgroup_supported_operations,
+            &other
+                .physical_device_vulkan11_properties
+                .subgroup_supported_operations,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::subgroupQuadOperationsInAllStages",
+            self.physical_device_vulkan11_properties
+                .subgroup_quad_operations_in_all_stages
+                == other
+                    .physical_device_vulkan11_properties
+                    .subgroup_quad_operations_in_all_stages,
+            &self
+                .physical_device_vulkan11_properties
+                .subgroup_quad_operations_in_all_stages,
+            &other
+                .physical_device_vulkan11_properties
+                .subgroup_quad_operations_in_all_stages,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::pointClippingBehavior",
+            self.physical_device_vulkan11_properties
+                .point_clipping_behavior
+                == other
+                    .physical_device_vulkan11_properties
+                    .point_clipping_behavior,
+            &self
+                .physical_device_vulkan11_properties
+                .point_clipping_behavior,
+            &other
+                .physical_device_vulkan11_properties
+                .point_clipping_behavior,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::maxMultiviewViewCount",
+            self.physical_device_vulkan11_properties
+                .max_multiview_view_count
+                == other
+                    .physical_device_vulkan11_properties
+                    .max_multiview_view_count,
+            &self
+                .physical_device_vulkan11_properties
+                .max_multiview_view_count,
+            &other
+                .physical_device_vulkan11_properties
+                .max_multiview_view_count,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::maxMultiviewInstanceIndex",
+            self.physical_device_vulkan11_properties
+                .max_multiview_instance_index
+                == other
+                    .physical_device_vulkan11_properties
+                    .max_multiview_instance_index,
+            &self
+                .physical_device_vulkan11_properties
+                .max_multiview_instance_index,
+            &other
+                .physical_device_vulkan11_properties
+                .max_multiview_instance_index,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::protectedNoFault",
+            self.physical_device_vulkan11_properties.protected_no_fault
+                == other.physical_device_vulkan11_properties.protected_no_fault,
+            &self.physical_device_vulkan11_properties.protected_no_fault,
+            &other.physical_device_vulkan11_properties.protected_no_fault,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::maxPerSetDescriptors",
+            self.physical_device_vulkan11_properties
+                .max_per_set_descriptors
+                == other
+                    .physical_device_vulkan11_properties
+                    .max_per_set_descriptors,
+            &self
+                .physical_device_vulkan11_properties
+                .max_per_set_descriptors,
+            &other
+                .physical_device_vulkan11_properties
+                .max_per_set_descriptors,
+        );
+        f(
+            48usize,
+            "VkPhysicalDeviceVulkan11Properties::maxMemoryAllocationSize",
+            self.physical_device_vulkan11_properties
+                .max_memory_allocation_size
+                == other
+                    .physical_device_vulkan11_properties
+                    .max_memory_allocation_size,
+            &self
+                .physical_device_vulkan11_properties
+                .max_memory_allocation_size,
+            &other
+                .physical_device_vulkan11_properties
+                .max_memory_allocation_size,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::driverID",
+            self.physical_device_vulkan12_properties.driver_id
+                == other.physical_device_vulkan12_properties.driver_id,
+            &self.physical_device_vulkan12_properties.driver_id,
+            &other.physical_device_vulkan12_properties.driver_id,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::driverName",
+            self.physical_device_vulkan12_properties.driver_name
+                == other.physical_device_vulkan12_properties.driver_name,
+            &CStrArray(&self.physical_device_vulkan12_properties.driver_name),
+            &CStrArray(&other.physical_device_vulkan12_properties.driver_name),
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::driverInfo",
+            self.physical_device_vulkan12_properties.driver_info
+                == other.physical_device_vulkan12_properties.driver_info,
+            &CStrArray(&self.physical_device_vulkan12_properties.driver_info),
+            &CStrArray(&other.physical_device_vulkan12_properties.driver_info),
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::conformanceVersion.major",
+            self.physical_device_vulkan12_properties
+                .conformance_version
+                .major
+                == other
+                    .physical_device_vulkan12_properties
+                    .conformance_version
+                    .major,
+            &self
+                .physical_device_vulkan12_properties
+                .conformance_version
+                .major,
+            &other
+                .physical_device_vulkan12_properties
+                .conformance_version
+                .major,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::conformanceVersion.minor",
+            self.physical_device_vulkan12_properties
+                .conformance_version
+                .minor
+                == other
+                    .physical_device_vulkan12_properties
+                    .conformance_version
+                    .minor,
+            &self
+                .physical_device_vulkan12_properties
+                .conformance_version
+                .minor,
+            &other
+                .physical_device_vulkan12_properties
+                .conformance_version
+                .minor,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::conformanceVersion.subminor",
+            self.physical_device_vulkan12_properties
+                .conformance_version
+                .subminor
+                == other
+                    .physical_device_vulkan12_properties
+                    .conformance_version
+                    .subminor,
+            &self
+                .physical_device_vulkan12_properties
+                .conformance_version
+                .subminor,
+            &other
+                .physical_device_vulkan12_properties
+                .conformance_version
+                .subminor,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::conformanceVersion.patch",
+            self.physical_device_vulkan12_properties
+                .conformance_version
+                .patch
+                == other
+                    .physical_device_vulkan12_properties
+                    .conformance_version
+                    .patch,
+            &self
+                .physical_device_vulkan12_properties
+                .conformance_version
+                .patch,
+            &other
+                .physical_device_vulkan12_properties
+                .conformance_version
+                .patch,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::denormBehaviorIndependence",
+            self.physical_device_vulkan12_properties
+                .denorm_behavior_independence
+                == other
+                    .physical_device_vulkan12_properties
+                    .denorm_behavior_independence,
+            &self
+                .physical_device_vulkan12_properties
+                .denorm_behavior_independence,
+            &other
+                .physical_device_vulkan12_properties
+                .denorm_behavior_independence,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::roundingModeIndependence",
+            self.physical_device_vulkan12_properties
+                .rounding_mode_independence
+                == other
+                    .physical_device_vulkan12_properties
+                    .rounding_mode_independence,
+            &self
+                .physical_device_vulkan12_properties
+                .rounding_mode_independence,
+            &other
+                .physical_device_vulkan12_properties
+                .rounding_mode_independence,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderSignedZeroInfNanPreserveFloat16",
+            self.physical_device_vulkan12_properties
+                .shader_signed_zero_inf_nan_preserve_float16
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_signed_zero_inf_nan_preserve_float16,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_signed_zero_inf_nan_preserve_float16,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_signed_zero_inf_nan_preserve_float16,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderSignedZeroInfNanPreserveFloat32",
+            self.physical_device_vulkan12_properties
+                .shader_signed_zero_inf_nan_preserve_float32
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_signed_zero_inf_nan_preserve_float32,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_signed_zero_inf_nan_preserve_float32,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_signed_zero_inf_nan_preserve_float32,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderSignedZeroInfNanPreserveFloat64",
+            self.physical_device_vulkan12_properties
+                .shader_signed_zero_inf_nan_preserve_float64
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_signed_zero_inf_nan_preserve_float64,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_signed_zero_inf_nan_preserve_float64,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_signed_zero_inf_nan_preserve_float64,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderDenormPreserveFloat16",
+            self.physical_device_vulkan12_properties
+                .shader_denorm_preserve_float16
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_denorm_preserve_float16,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_denorm_preserve_float16,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_denorm_preserve_float16,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderDenormPreserveFloat32",
+            self.physical_device_vulkan12_properties
+                .shader_denorm_preserve_float32
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_denorm_preserve_float32,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_denorm_preserve_float32,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_denorm_preserve_float32,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderDenormPreserveFloat64",
+            self.physical_device_vulkan12_properties
+                .shader_denorm_preserve_float64
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_denorm_preserve_float64,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_denorm_preserve_float64,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_denorm_preserve_float64,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderDenormFlushToZeroFloat16",
+            self.physical_device_vulkan12_properties
+                .shader_denorm_flush_to_zero_float16
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_denorm_flush_to_zero_float16,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_denorm_flush_to_zero_float16,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_denorm_flush_to_zero_float16,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderDenormFlushToZeroFloat32",
+            self.physical_device_vulkan12_properties
+                .shader_denorm_flush_to_zero_float32
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_denorm_flush_to_zero_float32,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_denorm_flush_to_zero_float32,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_denorm_flush_to_zero_float32,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderDenormFlushToZeroFloat64",
+            self.physical_device_vulkan12_properties
+                .shader_denorm_flush_to_zero_float64
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_denorm_flush_to_zero_float64,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_denorm_flush_to_zero_float64,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_denorm_flush_to_zero_float64,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderRoundingModeRTEFloat16",
+            self.physical_device_vulkan12_properties
+                .shader_rounding_mode_rte_float16
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_rounding_mode_rte_float16,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rte_float16,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rte_float16,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderRoundingModeRTEFloat32",
+            self.physical_device_vulkan12_properties
+                .shader_rounding_mode_rte_float32
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_rounding_mode_rte_float32,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rte_float32,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rte_float32,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderRoundingModeRTEFloat64",
+            self.physical_device_vulkan12_properties
+                .shader_rounding_mode_rte_float64
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_rounding_mode_rte_float64,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rte_float64,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rte_float64,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderRoundingModeRTZFloat16",
+            self.physical_device_vulkan12_properties
+                .shader_rounding_mode_rtz_float16
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_rounding_mode_rtz_float16,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rtz_float16,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rtz_float16,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderRoundingModeRTZFloat32",
+            self.physical_device_vulkan12_properties
+                .shader_rounding_mode_rtz_float32
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_rounding_mode_rtz_float32,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rtz_float32,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rtz_float32,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderRoundingModeRTZFloat64",
+            self.physical_device_vulkan12_properties
+                .shader_rounding_mode_rtz_float64
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_rounding_mode_rtz_float64,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rtz_float64,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_rounding_mode_rtz_float64,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxUpdateAfterBindDescriptorsInAllPools",
+            self.physical_device_vulkan12_properties
+                .max_update_after_bind_descriptors_in_all_pools
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_update_after_bind_descriptors_in_all_pools,
+            &self
+                .physical_device_vulkan12_properties
+                .max_update_after_bind_descriptors_in_all_pools,
+            &other
+                .physical_device_vulkan12_properties
+                .max_update_after_bind_descriptors_in_all_pools,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderUniformBufferArrayNonUniformIndexingNative",
+            self.physical_device_vulkan12_properties
+                .shader_uniform_buffer_array_non_uniform_indexing_native
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_uniform_buffer_array_non_uniform_indexing_native,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_uniform_buffer_array_non_uniform_indexing_native,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_uniform_buffer_array_non_uniform_indexing_native,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderSampledImageArrayNonUniformIndexingNative",
+            self.physical_device_vulkan12_properties
+                .shader_sampled_image_array_non_uniform_indexing_native
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_sampled_image_array_non_uniform_indexing_native,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_sampled_image_array_non_uniform_indexing_native,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_sampled_image_array_non_uniform_indexing_native,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderStorageBufferArrayNonUniformIndexingNative",
+            self.physical_device_vulkan12_properties
+                .shader_storage_buffer_array_non_uniform_indexing_native
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_storage_buffer_array_non_uniform_indexing_native,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_storage_buffer_array_non_uniform_indexing_native,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_storage_buffer_array_non_uniform_indexing_native,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::shaderStorageImageArrayNonUniformIndexingNative",
+            self.physical_device_vulkan12_properties
+                .shader_storage_image_array_non_uniform_indexing_native
+                == other
+                    .physical_device_vulkan12_properties
+                    .shader_storage_image_array_non_uniform_indexing_native,
+            &self
+                .physical_device_vulkan12_properties
+                .shader_storage_image_array_non_uniform_indexing_native,
+            &other
+                .physical_device_vulkan12_properties
+                .shader_storage_image_array_non_uniform_indexing_native,
+        );
+        f (49usize , "VkPhysicalDeviceVulkan12Properties::shaderInputAttachmentArrayNonUniformIndexingNative" , self . physical_device_vulkan12_properties . shader_input_attachment_array_non_uniform_indexing_native == other . physical_device_vulkan12_properties . shader_input_attachment_array_non_uniform_indexing_native , & self . physical_device_vulkan12_properties . shader_input_attachment_array_non_uniform_indexing_native , & other . physical_device_vulkan12_properties . shader_input_attachment_array_non_uniform_indexing_native) ;
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::robustBufferAccessUpdateAfterBind",
+            self.physical_device_vulkan12_properties
+                .robust_buffer_access_update_after_bind
+                == other
+                    .physical_device_vulkan12_properties
+                    .robust_buffer_access_update_after_bind,
+            &self
+                .physical_device_vulkan12_properties
+                .robust_buffer_access_update_after_bind,
+            &other
+                .physical_device_vulkan12_properties
+                .robust_buffer_access_update_after_bind,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::quadDivergentImplicitLod",
+            self.physical_device_vulkan12_properties
+                .quad_divergent_implicit_lod
+                == other
+                    .physical_device_vulkan12_properties
+                    .quad_divergent_implicit_lod,
+            &self
+                .physical_device_vulkan12_properties
+                .quad_divergent_implicit_lod,
+            &other
+                .physical_device_vulkan12_properties
+                .quad_divergent_implicit_lod,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxPerStageDescriptorUpdateAfterBindSamplers",
+            self.physical_device_vulkan12_properties
+                .max_per_stage_descriptor_update_after_bind_samplers
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_per_stage_descriptor_update_after_bind_samplers,
+            &self
+                .physical_device_vulkan12_properties
+                .max_per_stage_descriptor_update_after_bind_samplers,
+            &other
+                .physical_device_vulkan12_properties
+                .max_per_stage_descriptor_update_after_bind_samplers,
+        );
+        f (49usize , "VkPhysicalDeviceVulkan12Properties::maxPerStageDescriptorUpdateAfterBindUniformBuffers" , self . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_uniform_buffers == other . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_uniform_buffers , & self . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_uniform_buffers , & other . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_uniform_buffers) ;
+        f (49usize , "VkPhysicalDeviceVulkan12Properties::maxPerStageDescriptorUpdateAfterBindStorageBuffers" , self . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_storage_buffers == other . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_storage_buffers , & self . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_storage_buffers , & other . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_storage_buffers) ;
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxPerStageDescriptorUpdateAfterBindSampledImages",
+            self.physical_device_vulkan12_properties
+                .max_per_stage_descriptor_update_after_bind_sampled_images
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_per_stage_descriptor_update_after_bind_sampled_images,
+            &self
+                .physical_device_vulkan12_properties
+                .max_per_stage_descriptor_update_after_bind_sampled_images,
+            &other
+                .physical_device_vulkan12_properties
+                .max_per_stage_descriptor_update_after_bind_sampled_images,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxPerStageDescriptorUpdateAfterBindStorageImages",
+            self.physical_device_vulkan12_properties
+                .max_per_stage_descriptor_update_after_bind_storage_images
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_per_stage_descriptor_update_after_bind_storage_images,
+            &self
+                .physical_device_vulkan12_properties
+                .max_per_stage_descriptor_update_after_bind_storage_images,
+            &other
+                .physical_device_vulkan12_properties
+                .max_per_stage_descriptor_update_after_bind_storage_images,
+        );
+        f (49usize , "VkPhysicalDeviceVulkan12Properties::maxPerStageDescriptorUpdateAfterBindInputAttachments" , self . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_input_attachments == other . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_input_attachments , & self . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_input_attachments , & other . physical_device_vulkan12_properties . max_per_stage_descriptor_update_after_bind_input_attachments) ;
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxPerStageUpdateAfterBindResources",
+            self.physical_device_vulkan12_properties
+                .max_per_stage_update_after_bind_resources
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_per_stage_update_after_bind_resources,
+            &self
+                .physical_device_vulkan12_properties
+                .max_per_stage_update_after_bind_resources,
+            &other
+                .physical_device_vulkan12_properties
+                .max_per_stage_update_after_bind_resources,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxDescriptorSetUpdateAfterBindSamplers",
+            self.physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_samplers
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_descriptor_set_update_after_bind_samplers,
+            &self
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_samplers,
+            &other
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_samplers,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxDescriptorSetUpdateAfterBindUniformBuffers",
+            self.physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_uniform_buffers
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_descriptor_set_update_after_bind_uniform_buffers,
+            &self
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_uniform_buffers,
+            &other
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_uniform_buffers,
+        );
+        f (49usize , "VkPhysicalDeviceVulkan12Properties::maxDescriptorSetUpdateAfterBindUniformBuffersDynamic" , self . physical_device_vulkan12_properties . max_descriptor_set_update_after_bind_uniform_buffers_dynamic == other . physical_device_vulkan12_properties . max_descriptor_set_update_after_bind_uniform_buffers_dynamic , & self . physical_device_vulkan12_properties . max_descriptor_set_update_after_bind_uniform_buffers_dynamic , & other . physical_device_vulkan12_properties . max_descriptor_set_update_after_bind_uniform_buffers_dynamic) ;
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxDescriptorSetUpdateAfterBindStorageBuffers",
+            self.physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_storage_buffers
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_descriptor_set_update_after_bind_storage_buffers,
+            &self
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_storage_buffers,
+            &other
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_storage_buffers,
+        );
+        f (49usize , "VkPhysicalDeviceVulkan12Properties::maxDescriptorSetUpdateAfterBindStorageBuffersDynamic" , self . physical_device_vulkan12_properties . max_descriptor_set_update_after_bind_storage_buffers_dynamic == other . physical_device_vulkan12_properties . max_descriptor_set_update_after_bind_storage_buffers_dynamic , & self . physical_device_vulkan12_properties . max_descriptor_set_update_after_bind_storage_buffers_dynamic , & other . physical_device_vulkan12_properties . max_descriptor_set_update_after_bind_storage_buffers_dynamic) ;
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxDescriptorSetUpdateAfterBindSampledImages",
+            self.physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_sampled_images
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_descriptor_set_update_after_bind_sampled_images,
+            &self
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_sampled_images,
+            &other
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_sampled_images,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxDescriptorSetUpdateAfterBindStorageImages",
+            self.physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_storage_images
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_descriptor_set_update_after_bind_storage_images,
+            &self
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_storage_images,
+            &other
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_storage_images,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxDescriptorSetUpdateAfterBindInputAttachments",
+            self.physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_input_attachments
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_descriptor_set_update_after_bind_input_attachments,
+            &self
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_input_attachments,
+            &other
+                .physical_device_vulkan12_properties
+                .max_descriptor_set_update_after_bind_input_attachments,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::supportedDepthResolveModes",
+            self.physical_device_vulkan12_properties
+                .supported_depth_resolve_modes
+                == other
+                    .physical_device_vulkan12_properties
+                    .supported_depth_resolve_modes,
+            &self
+                .physical_device_vulkan12_properties
+                .supported_depth_resolve_modes,
+            &other
+                .physical_device_vulkan12_properties
+                .supported_depth_resolve_modes,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::supportedStencilResolveModes",
+            self.physical_device_vulkan12_properties
+                .supported_stencil_resolve_modes
+                == other
+                    .physical_device_vulkan12_properties
+                    .supported_stencil_resolve_modes,
+            &self
+                .physical_device_vulkan12_properties
+                .supported_stencil_resolve_modes,
+            &other
+                .physical_device_vulkan12_properties
+                .supported_stencil_resolve_modes,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::independentResolveNone",
+            self.physical_device_vulkan12_properties
+                .independent_resolve_none
+                == other
+                    .physical_device_vulkan12_properties
+                    .independent_resolve_none,
+            &self
+                .physical_device_vulkan12_properties
+                .independent_resolve_none,
+            &other
+                .physical_device_vulkan12_properties
+                .independent_resolve_none,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::independentResolve",
+            self.physical_device_vulkan12_properties.independent_resolve
+                == other
+                    .physical_device_vulkan12_properties
+                    .independent_resolve,
+            &self.physical_device_vulkan12_properties.independent_resolve,
+            &other
+                .physical_device_vulkan12_properties
+                .independent_resolve,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::filterMinmaxSingleComponentFormats",
+            self.physical_device_vulkan12_properties
+                .filter_minmax_single_component_formats
+                == other
+                    .physical_device_vulkan12_properties
+                    .filter_minmax_single_component_formats,
+            &self
+                .physical_device_vulkan12_properties
+                .filter_minmax_single_component_formats,
+            &other
+                .physical_device_vulkan12_properties
+                .filter_minmax_single_component_formats,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::filterMinmaxImageComponentMapping",
+            self.physical_device_vulkan12_properties
+                .filter_minmax_image_component_mapping
+                == other
+                    .physical_device_vulkan12_properties
+                    .filter_minmax_image_component_mapping,
+            &self
+                .physical_device_vulkan12_properties
+                .filter_minmax_image_component_mapping,
+            &other
+                .physical_device_vulkan12_properties
+                .filter_minmax_image_component_mapping,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::maxTimelineSemaphoreValueDifference",
+            self.physical_device_vulkan12_properties
+                .max_timeline_semaphore_value_difference
+                == other
+                    .physical_device_vulkan12_properties
+                    .max_timeline_semaphore_value_difference,
+            &self
+                .physical_device_vulkan12_properties
+                .max_timeline_semaphore_value_difference,
+            &other
+                .physical_device_vulkan12_properties
+                .max_timeline_semaphore_value_difference,
+        );
+        f(
+            49usize,
+            "VkPhysicalDeviceVulkan12Properties::framebufferIntegerColorSampleCounts",
+            self.physical_device_vulkan12_properties
+                .framebuffer_integer_color_sample_counts
+                == other
+                    .physical_device_vulkan12_properties
+                    .framebuffer_integer_color_sample_counts,
+            &self
+                .physical_device_vulkan12_properties
+                .framebuffer_integer_color_sample_counts,
+            &other
+                .physical_device_vulkan12_properties
+                .framebuffer_integer_color_sample_counts,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::minSubgroupSize",
+            self.physical_device_vulkan13_properties.min_subgroup_size
+                == other.physical_device_vulkan13_properties.min_subgroup_size,
+            &self.physical_device_vulkan13_properties.min_subgroup_size,
+            &other.physical_device_vulkan13_properties.min_subgroup_size,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::maxSubgroupSize",
+            self.physical_device_vulkan13_properties.max_subgroup_size
+                == other.physical_device_vulkan13_properties.max_subgroup_size,
+            &self.physical_device_vulkan13_properties.max_subgroup_size,
+            &other.physical_device_vulkan13_properties.max_subgroup_size,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::maxComputeWorkgroupSubgroups",
+            self.physical_device_vulkan13_properties
+                .max_compute_workgroup_subgroups
+                == other
+                    .physical_device_vulkan13_properties
+                    .max_compute_workgroup_subgroups,
+            &self
+                .physical_device_vulkan13_properties
+                .max_compute_workgroup_subgroups,
+            &other
+                .physical_device_vulkan13_properties
+                .max_compute_workgroup_subgroups,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::requiredSubgroupSizeStages",
+            self.physical_device_vulkan13_properties
+                .required_subgroup_size_stages
+                == other
+                    .physical_device_vulkan13_properties
+                    .required_subgroup_size_stages,
+            &self
+                .physical_device_vulkan13_properties
+                .required_subgroup_size_stages,
+            &other
+                .physical_device_vulkan13_properties
+                .required_subgroup_size_stages,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::maxInlineUniformBlockSize",
+            self.physical_device_vulkan13_properties
+                .max_inline_uniform_block_size
+                == other
+                    .physical_device_vulkan13_properties
+                    .max_inline_uniform_block_size,
+            &self
+                .physical_device_vulkan13_properties
+                .max_inline_uniform_block_size,
+            &other
+                .physical_device_vulkan13_properties
+                .max_inline_uniform_block_size,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::maxPerStageDescriptorInlineUniformBlocks",
+            self.physical_device_vulkan13_properties
+                .max_per_stage_descriptor_inline_uniform_blocks
+                == other
+                    .physical_device_vulkan13_properties
+                    .max_per_stage_descriptor_inline_uniform_blocks,
+            &self
+                .physical_device_vulkan13_properties
+                .max_per_stage_descriptor_inline_uniform_blocks,
+            &other
+                .physical_device_vulkan13_properties
+                .max_per_stage_descriptor_inline_uniform_blocks,
+        );
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks" , self . physical_device_vulkan13_properties . max_per_stage_descriptor_update_after_bind_inline_uniform_blocks == other . physical_device_vulkan13_properties . max_per_stage_descriptor_update_after_bind_inline_uniform_blocks , & self . physical_device_vulkan13_properties . max_per_stage_descriptor_update_after_bind_inline_uniform_blocks , & other . physical_device_vulkan13_properties . max_per_stage_descriptor_update_after_bind_inline_uniform_blocks) ;
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::maxDescriptorSetInlineUniformBlocks",
+            self.physical_device_vulkan13_properties
+                .max_descriptor_set_inline_uniform_blocks
+                == other
+                    .physical_device_vulkan13_properties
+                    .max_descriptor_set_inline_uniform_blocks,
+            &self
+                .physical_device_vulkan13_properties
+                .max_descriptor_set_inline_uniform_blocks,
+            &other
+                .physical_device_vulkan13_properties
+                .max_descriptor_set_inline_uniform_blocks,
+        );
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::maxDescriptorSetUpdateAfterBindInlineUniformBlocks" , self . physical_device_vulkan13_properties . max_descriptor_set_update_after_bind_inline_uniform_blocks == other . physical_device_vulkan13_properties . max_descriptor_set_update_after_bind_inline_uniform_blocks , & self . physical_device_vulkan13_properties . max_descriptor_set_update_after_bind_inline_uniform_blocks , & other . physical_device_vulkan13_properties . max_descriptor_set_update_after_bind_inline_uniform_blocks) ;
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::maxInlineUniformTotalSize",
+            self.physical_device_vulkan13_properties
+                .max_inline_uniform_total_size
+                == other
+                    .physical_device_vulkan13_properties
+                    .max_inline_uniform_total_size,
+            &self
+                .physical_device_vulkan13_properties
+                .max_inline_uniform_total_size,
+            &other
+                .physical_device_vulkan13_properties
+                .max_inline_uniform_total_size,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct8BitUnsignedAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product8_bit_unsigned_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product8_bit_unsigned_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product8_bit_unsigned_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product8_bit_unsigned_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct8BitSignedAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product8_bit_signed_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product8_bit_signed_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product8_bit_signed_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product8_bit_signed_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct8BitMixedSignednessAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product8_bit_mixed_signedness_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product8_bit_mixed_signedness_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product8_bit_mixed_signedness_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product8_bit_mixed_signedness_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct4x8BitPackedUnsignedAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product4x8_bit_packed_unsigned_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product4x8_bit_packed_unsigned_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product4x8_bit_packed_unsigned_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product4x8_bit_packed_unsigned_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct4x8BitPackedSignedAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product4x8_bit_packed_signed_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product4x8_bit_packed_signed_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product4x8_bit_packed_signed_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product4x8_bit_packed_signed_accelerated,
+        );
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProduct4x8BitPackedMixedSignednessAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product4x8_bit_packed_mixed_signedness_accelerated == other . physical_device_vulkan13_properties . integer_dot_product4x8_bit_packed_mixed_signedness_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product4x8_bit_packed_mixed_signedness_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product4x8_bit_packed_mixed_signedness_accelerated) ;
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct16BitUnsignedAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product16_bit_unsigned_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product16_bit_unsigned_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product16_bit_unsigned_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product16_bit_unsigned_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct16BitSignedAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product16_bit_signed_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product16_bit_signed_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product16_bit_signed_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product16_bit_signed_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct16BitMixedSignednessAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product16_bit_mixed_signedness_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product16_bit_mixed_signedness_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product16_bit_mixed_signedness_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product16_bit_mixed_signedness_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct32BitUnsignedAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product32_bit_unsigned_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product32_bit_unsigned_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product32_bit_unsigned_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product32_bit_unsigned_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct32BitSignedAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product32_bit_signed_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product32_bit_signed_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product32_bit_signed_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product32_bit_signed_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct32BitMixedSignednessAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product32_bit_mixed_signedness_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product32_bit_mixed_signedness_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product32_bit_mixed_signedness_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product32_bit_mixed_signedness_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct64BitUnsignedAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product64_bit_unsigned_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product64_bit_unsigned_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product64_bit_unsigned_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product64_bit_unsigned_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct64BitSignedAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product64_bit_signed_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product64_bit_signed_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product64_bit_signed_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product64_bit_signed_accelerated,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::integerDotProduct64BitMixedSignednessAccelerated",
+            self.physical_device_vulkan13_properties
+                .integer_dot_product64_bit_mixed_signedness_accelerated
+                == other
+                    .physical_device_vulkan13_properties
+                    .integer_dot_product64_bit_mixed_signedness_accelerated,
+            &self
+                .physical_device_vulkan13_properties
+                .integer_dot_product64_bit_mixed_signedness_accelerated,
+            &other
+                .physical_device_vulkan13_properties
+                .integer_dot_product64_bit_mixed_signedness_accelerated,
+        );
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating8BitUnsignedAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating8BitSignedAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_signed_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_signed_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_signed_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_signed_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating16BitUnsignedAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating16BitSignedAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_signed_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_signed_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_signed_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_signed_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating32BitUnsignedAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating32BitSignedAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_signed_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_signed_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_signed_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_signed_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating64BitUnsignedAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating64BitSignedAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_signed_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_signed_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_signed_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_signed_accelerated) ;
+        f (50usize , "VkPhysicalDeviceVulkan13Properties::integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated" , self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated == other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated , & self . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated , & other . physical_device_vulkan13_properties . integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated) ;
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::storageTexelBufferOffsetAlignmentBytes",
+            self.physical_device_vulkan13_properties
+                .storage_texel_buffer_offset_alignment_bytes
+                == other
+                    .physical_device_vulkan13_properties
+                    .storage_texel_buffer_offset_alignment_bytes,
+            &self
+                .physical_device_vulkan13_properties
+                .storage_texel_buffer_offset_alignment_bytes,
+            &other
+                .physical_device_vulkan13_properties
+                .storage_texel_buffer_offset_alignment_bytes,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::storageTexelBufferOffsetSingleTexelAlignment",
+            self.physical_device_vulkan13_properties
+                .storage_texel_buffer_offset_single_texel_alignment
+                == other
+                    .physical_device_vulkan13_properties
+                    .storage_texel_buffer_offset_single_texel_alignment,
+            &self
+                .physical_device_vulkan13_properties
+                .storage_texel_buffer_offset_single_texel_alignment,
+            &other
+                .physical_device_vulkan13_properties
+                .storage_texel_buffer_offset_single_texel_alignment,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::uniformTexelBufferOffsetAlignmentBytes",
+            self.physical_device_vulkan13_properties
+                .uniform_texel_buffer_offset_alignment_bytes
+                == other
+                    .physical_device_vulkan13_properties
+                    .uniform_texel_buffer_offset_alignment_bytes,
+            &self
+                .physical_device_vulkan13_properties
+                .uniform_texel_buffer_offset_alignment_bytes,
+            &other
+                .physical_device_vulkan13_properties
+                .uniform_texel_buffer_offset_alignment_bytes,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::uniformTexelBufferOffsetSingleTexelAlignment",
+            self.physical_device_vulkan13_properties
+                .uniform_texel_buffer_offset_single_texel_alignment
+                == other
+                    .physical_device_vulkan13_properties
+                    .uniform_texel_buffer_offset_single_texel_alignment,
+            &self
+                .physical_device_vulkan13_properties
+                .uniform_texel_buffer_offset_single_texel_alignment,
+            &other
+                .physical_device_vulkan13_properties
+                .uniform_texel_buffer_offset_single_texel_alignment,
+        );
+        f(
+            50usize,
+            "VkPhysicalDeviceVulkan13Properties::maxBufferSize",
+            self.physical_device_vulkan13_properties.max_buffer_size
+                == other.physical_device_vulkan13_properties.max_buffer_size,
+            &self.physical_device_vulkan13_properties.max_buffer_size,
+            &other.physical_device_vulkan13_properties.max_buffer_size,
+        );
+        f(
+            51usize,
+            "VkPhysicalDeviceCustomBorderColorPropertiesEXT::maxCustomBorderColorSamplers",
+            self.physical_device_custom_border_color_properties_ext
+                .max_custom_border_color_samplers
+                == other
+                    .physical_device_custom_border_color_properties_ext
+                    .max_custom_border_color_samplers,
+            &self
+                .physical_device_custom_border_color_properties_ext
+                .max_custom_border_color_samplers,
+            &other
+                .physical_device_custom_border_color_properties_ext
+                .max_custom_border_color_samplers,
+        );
+        f (52usize , "VkPhysicalDeviceExtendedDynamicState3PropertiesEXT::dynamicPrimitiveTopologyUnrestricted" , self . physical_device_extended_dynamic_state3_properties_ext . dynamic_primitive_topology_unrestricted == other . physical_device_extended_dynamic_state3_properties_ext . dynamic_primitive_topology_unrestricted , & self . physical_device_extended_dynamic_state3_properties_ext . dynamic_primitive_topology_unrestricted , & other . physical_device_extended_dynamic_state3_properties_ext . dynamic_primitive_topology_unrestricted) ;
+        f(
+            53usize,
+            "VkPhysicalDeviceRobustness2PropertiesEXT::robustStorageBufferAccessSizeAlignment",
+            self.physical_device_robustness2_properties_ext
+                .robust_storage_buffer_access_size_alignment
+                == other
+                    .physical_device_robustness2_properties_ext
+                    .robust_storage_buffer_access_size_alignment,
+            &self
+                .physical_device_robustness2_properties_ext
+                .robust_storage_buffer_access_size_alignment,
+            &other
+                .physical_device_robustness2_properties_ext
+                .robust_storage_buffer_access_size_alignment,
+        );
+        f(
+            53usize,
+            "VkPhysicalDeviceRobustness2PropertiesEXT::robustUniformBufferAccessSizeAlignment",
+            self.physical_device_robustness2_properties_ext
+                .robust_uniform_buffer_access_size_alignment
+                == other
+                    .physical_device_robustness2_properties_ext
+                    .robust_uniform_buffer_access_size_alignment,
+            &self
+                .physical_device_robustness2_properties_ext
+                .robust_uniform_buffer_access_size_alignment,
+            &other
+                .physical_device_robustness2_properties_ext
+                .robust_uniform_buffer_access_size_alignment,
+        );
+        f(
+            54usize,
+            "VkPhysicalDevicePortabilitySubsetPropertiesKHR::minVertexInputBindingStrideAlignment",
+            self.physical_device_portability_subset_properties_khr
+                .min_vertex_input_binding_stride_alignment
+                == other
+                    .physical_device_portability_subset_properties_khr
+                    .min_vertex_input_binding_stride_alignment,
+            &self
+                .physical_device_portability_subset_properties_khr
+                .min_vertex_input_binding_stride_alignment,
+            &other
+                .physical_device_portability_subset_properties_khr
+                .min_vertex_input_binding_stride_alignment,
+        );
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::minFragmentShadingRateAttachmentTexelSize.width" , self . physical_device_fragment_shading_rate_properties_khr . min_fragment_shading_rate_attachment_texel_size . width == other . physical_device_fragment_shading_rate_properties_khr . min_fragment_shading_rate_attachment_texel_size . width , & self . physical_device_fragment_shading_rate_properties_khr . min_fragment_shading_rate_attachment_texel_size . width , & other . physical_device_fragment_shading_rate_properties_khr . min_fragment_shading_rate_attachment_texel_size . width) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::minFragmentShadingRateAttachmentTexelSize.height" , self . physical_device_fragment_shading_rate_properties_khr . min_fragment_shading_rate_attachment_texel_size . height == other . physical_device_fragment_shading_rate_properties_khr . min_fragment_shading_rate_attachment_texel_size . height , & self . physical_device_fragment_shading_rate_properties_khr . min_fragment_shading_rate_attachment_texel_size . height , & other . physical_device_fragment_shading_rate_properties_khr . min_fragment_shading_rate_attachment_texel_size . height) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::maxFragmentShadingRateAttachmentTexelSize.width" , self . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size . width == other . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size . width , & self . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size . width , & other . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size . width) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::maxFragmentShadingRateAttachmentTexelSize.height" , self . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size . height == other . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size . height , & self . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size . height , & other . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size . height) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::maxFragmentShadingRateAttachmentTexelSizeAspectRatio" , self . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size_aspect_ratio == other . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size_aspect_ratio , & self . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size_aspect_ratio , & other . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_attachment_texel_size_aspect_ratio) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::primitiveFragmentShadingRateWithMultipleViewports" , self . physical_device_fragment_shading_rate_properties_khr . primitive_fragment_shading_rate_with_multiple_viewports == other . physical_device_fragment_shading_rate_properties_khr . primitive_fragment_shading_rate_with_multiple_viewports , & self . physical_device_fragment_shading_rate_properties_khr . primitive_fragment_shading_rate_with_multiple_viewports , & other . physical_device_fragment_shading_rate_properties_khr . primitive_fragment_shading_rate_with_multiple_viewports) ;
+        f(
+            55usize,
+            "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::layeredShadingRateAttachments",
+            self.physical_device_fragment_shading_rate_properties_khr
+                .layered_shading_rate_attachments
+                == other
+                    .physical_device_fragment_shading_rate_properties_khr
+                    .layered_shading_rate_attachments,
+            &self
+                .physical_device_fragment_shading_rate_properties_khr
+                .layered_shading_rate_attachments,
+            &other
+                .physical_device_fragment_shading_rate_properties_khr
+                .layered_shading_rate_attachments,
+        );
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::fragmentShadingRateNonTrivialCombinerOps" , self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_non_trivial_combiner_ops == other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_non_trivial_combiner_ops , & self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_non_trivial_combiner_ops , & other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_non_trivial_combiner_ops) ;
+        f(
+            55usize,
+            "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::maxFragmentSize.width",
+            self.physical_device_fragment_shading_rate_properties_khr
+                .max_fragment_size
+                .width
+                == other
+                    .physical_device_fragment_shading_rate_properties_khr
+                    .max_fragment_size
+                    .width,
+            &self
+                .physical_device_fragment_shading_rate_properties_khr
+                .max_fragment_size
+                .width,
+            &other
+                .physical_device_fragment_shading_rate_properties_khr
+                .max_fragment_size
+                .width,
+        );
+        f(
+            55usize,
+            "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::maxFragmentSize.height",
+            self.physical_device_fragment_shading_rate_properties_khr
+                .max_fragment_size
+                .height
+                == other
+                    .physical_device_fragment_shading_rate_properties_khr
+                    .max_fragment_size
+                    .height,
+            &self
+                .physical_device_fragment_shading_rate_properties_khr
+                .max_fragment_size
+                .height,
+            &other
+                .physical_device_fragment_shading_rate_properties_khr
+                .max_fragment_size
+                .height,
+        );
+        f(
+            55usize,
+            "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::maxFragmentSizeAspectRatio",
+            self.physical_device_fragment_shading_rate_properties_khr
+                .max_fragment_size_aspect_ratio
+                == other
+                    .physical_device_fragment_shading_rate_properties_khr
+                    .max_fragment_size_aspect_ratio,
+            &self
+                .physical_device_fragment_shading_rate_properties_khr
+                .max_fragment_size_aspect_ratio,
+            &other
+                .physical_device_fragment_shading_rate_properties_khr
+                .max_fragment_size_aspect_ratio,
+        );
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::maxFragmentShadingRateCoverageSamples" , self . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_coverage_samples == other . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_coverage_samples , & self . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_coverage_samples , & other . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_coverage_samples) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::maxFragmentShadingRateRasterizationSamples" , self . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_rasterization_samples == other . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_rasterization_samples , & self . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_rasterization_samples , & other . physical_device_fragment_shading_rate_properties_khr . max_fragment_shading_rate_rasterization_samples) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::fragmentShadingRateWithShaderDepthStencilWrites" , self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_shader_depth_stencil_writes == other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_shader_depth_stencil_writes , & self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_shader_depth_stencil_writes , & other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_shader_depth_stencil_writes) ;
+        f(
+            55usize,
+            "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::fragmentShadingRateWithSampleMask",
+            self.physical_device_fragment_shading_rate_properties_khr
+                .fragment_shading_rate_with_sample_mask
+                == other
+                    .physical_device_fragment_shading_rate_properties_khr
+                    .fragment_shading_rate_with_sample_mask,
+            &self
+                .physical_device_fragment_shading_rate_properties_khr
+                .fragment_shading_rate_with_sample_mask,
+            &other
+                .physical_device_fragment_shading_rate_properties_khr
+                .fragment_shading_rate_with_sample_mask,
+        );
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::fragmentShadingRateWithShaderSampleMask" , self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_shader_sample_mask == other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_shader_sample_mask , & self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_shader_sample_mask , & other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_shader_sample_mask) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::fragmentShadingRateWithConservativeRasterization" , self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_conservative_rasterization == other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_conservative_rasterization , & self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_conservative_rasterization , & other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_conservative_rasterization) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::fragmentShadingRateWithFragmentShaderInterlock" , self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_fragment_shader_interlock == other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_fragment_shader_interlock , & self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_fragment_shader_interlock , & other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_fragment_shader_interlock) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::fragmentShadingRateWithCustomSampleLocations" , self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_custom_sample_locations == other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_custom_sample_locations , & self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_custom_sample_locations , & other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_with_custom_sample_locations) ;
+        f (55usize , "VkPhysicalDeviceFragmentShadingRatePropertiesKHR::fragmentShadingRateStrictMultiplyCombiner" , self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_strict_multiply_combiner == other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_strict_multiply_combiner , & self . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_strict_multiply_combiner , & other . physical_device_fragment_shading_rate_properties_khr . fragment_shading_rate_strict_multiply_combiner) ;
+        f (56usize , "VkPhysicalDeviceFragmentShadingRateEnumsPropertiesNV::maxFragmentShadingRateInvocationCount" , self . physical_device_fragment_shading_rate_enums_properties_nv . max_fragment_shading_rate_invocation_count == other . physical_device_fragment_shading_rate_enums_properties_nv . max_fragment_shading_rate_invocation_count , & self . physical_device_fragment_shading_rate_enums_properties_nv . max_fragment_shading_rate_invocation_count , & other . physical_device_fragment_shading_rate_enums_properties_nv . max_fragment_shading_rate_invocation_count) ;
+        f(
+            57usize,
+            "VkPhysicalDeviceProvokingVertexPropertiesEXT::provokingVertexModePerPipeline",
+            self.physical_device_provoking_vertex_properties_ext
+                .provoking_vertex_mode_per_pipeline
+                == other
+                    .physical_device_provoking_vertex_properties_ext
+                    .provoking_vertex_mode_per_pipeline,
+            &self
+                .physical_device_provoking_vertex_properties_ext
+                .provoking_vertex_mode_per_pipeline,
+            &other
+                .physical_device_provoking_vertex_properties_ext
+                .provoking_vertex_mode_per_pipeline,
+        );
+        f (57usize , "VkPhysicalDeviceProvokingVertexPropertiesEXT::transformFeedbackPreservesTriangleFanProvokingVertex" , self . physical_device_provoking_vertex_properties_ext . transform_feedback_preserves_triangle_fan_provoking_vertex == other . physical_device_provoking_vertex_properties_ext . transform_feedback_preserves_triangle_fan_provoking_vertex , & self . physical_device_provoking_vertex_properties_ext . transform_feedback_preserves_triangle_fan_provoking_vertex , & other . physical_device_provoking_vertex_properties_ext . transform_feedback_preserves_triangle_fan_provoking_vertex) ;
+        f (58usize , "VkPhysicalDeviceDescriptorBufferPropertiesEXT::combinedImageSamplerDescriptorSingleArray" , self . physical_device_descriptor_buffer_properties_ext . combined_image_sampler_descriptor_single_array == other . physical_device_descriptor_buffer_properties_ext . combined_image_sampler_descriptor_single_array , & self . physical_device_descriptor_buffer_properties_ext . combined_image_sampler_descriptor_single_array , & other . physical_device_descriptor_buffer_properties_ext . combined_image_sampler_descriptor_single_array) ;
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::bufferlessPushDescriptors",
+            self.physical_device_descriptor_buffer_properties_ext
+                .bufferless_push_descriptors
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .bufferless_push_descriptors,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .bufferless_push_descriptors,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .bufferless_push_descriptors,
+        );
+        f (58usize , "VkPhysicalDeviceDescriptorBufferPropertiesEXT::allowSamplerImageViewPostSubmitCreation" , self . physical_device_descriptor_buffer_properties_ext . allow_sampler_image_view_post_submit_creation == other . physical_device_descriptor_buffer_properties_ext . allow_sampler_image_view_post_submit_creation , & self . physical_device_descriptor_buffer_properties_ext . allow_sampler_image_view_post_submit_creation , & other . physical_device_descriptor_buffer_properties_ext . allow_sampler_image_view_post_submit_creation) ;
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::descriptorBufferOffsetAlignment",
+            self.physical_device_descriptor_buffer_properties_ext
+                .descriptor_buffer_offset_alignment
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .descriptor_buffer_offset_alignment,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .descriptor_buffer_offset_alignment,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .descriptor_buffer_offset_alignment,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::maxDescriptorBufferBindings",
+            self.physical_device_descriptor_buffer_properties_ext
+                .max_descriptor_buffer_bindings
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .max_descriptor_buffer_bindings,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .max_descriptor_buffer_bindings,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .max_descriptor_buffer_bindings,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::maxResourceDescriptorBufferBindings",
+            self.physical_device_descriptor_buffer_properties_ext
+                .max_resource_descriptor_buffer_bindings
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .max_resource_descriptor_buffer_bindings,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .max_resource_descriptor_buffer_bindings,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .max_resource_descriptor_buffer_bindings,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::maxSamplerDescriptorBufferBindings",
+            self.physical_device_descriptor_buffer_properties_ext
+                .max_sampler_descriptor_buffer_bindings
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .max_sampler_descriptor_buffer_bindings,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .max_sampler_descriptor_buffer_bindings,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .max_sampler_descriptor_buffer_bindings,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::maxEmbeddedImmutableSamplerBindings",
+            self.physical_device_descriptor_buffer_properties_ext
+                .max_embedded_immutable_sampler_bindings
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .max_embedded_immutable_sampler_bindings,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .max_embedded_immutable_sampler_bindings,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .max_embedded_immutable_sampler_bindings,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::maxEmbeddedImmutableSamplers",
+            self.physical_device_descriptor_buffer_properties_ext
+                .max_embedded_immutable_samplers
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .max_embedded_immutable_samplers,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .max_embedded_immutable_samplers,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .max_embedded_immutable_samplers,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::bufferCaptureReplayDescriptorDataSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .buffer_capture_replay_descriptor_data_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .buffer_capture_replay_descriptor_data_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .buffer_capture_replay_descriptor_data_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .buffer_capture_replay_descriptor_data_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::imageCaptureReplayDescriptorDataSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .image_capture_replay_descriptor_data_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .image_capture_replay_descriptor_data_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .image_capture_replay_descriptor_data_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .image_capture_replay_descriptor_data_size,
+        );
+        f (58usize , "VkPhysicalDeviceDescriptorBufferPropertiesEXT::imageViewCaptureReplayDescriptorDataSize" , self . physical_device_descriptor_buffer_properties_ext . image_view_capture_replay_descriptor_data_size == other . physical_device_descriptor_buffer_properties_ext . image_view_capture_replay_descriptor_data_size , & self . physical_device_descriptor_buffer_properties_ext . image_view_capture_replay_descriptor_data_size , & other . physical_device_descriptor_buffer_properties_ext . image_view_capture_replay_descriptor_data_size) ;
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::samplerCaptureReplayDescriptorDataSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .sampler_capture_replay_descriptor_data_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .sampler_capture_replay_descriptor_data_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .sampler_capture_replay_descriptor_data_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .sampler_capture_replay_descriptor_data_size,
+        );
+        f (58usize , "VkPhysicalDeviceDescriptorBufferPropertiesEXT::accelerationStructureCaptureReplayDescriptorDataSize" , self . physical_device_descriptor_buffer_properties_ext . acceleration_structure_capture_replay_descriptor_data_size == other . physical_device_descriptor_buffer_properties_ext . acceleration_structure_capture_replay_descriptor_data_size , & self . physical_device_descriptor_buffer_properties_ext . acceleration_structure_capture_replay_descriptor_data_size , & other . physical_device_descriptor_buffer_properties_ext . acceleration_structure_capture_replay_descriptor_data_size) ;
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::samplerDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .sampler_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .sampler_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .sampler_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .sampler_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::combinedImageSamplerDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .combined_image_sampler_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .combined_image_sampler_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .combined_image_sampler_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .combined_image_sampler_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::sampledImageDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .sampled_image_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .sampled_image_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .sampled_image_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .sampled_image_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::storageImageDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .storage_image_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .storage_image_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .storage_image_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .storage_image_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::uniformTexelBufferDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .uniform_texel_buffer_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .uniform_texel_buffer_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .uniform_texel_buffer_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .uniform_texel_buffer_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::robustUniformTexelBufferDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .robust_uniform_texel_buffer_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .robust_uniform_texel_buffer_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .robust_uniform_texel_buffer_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .robust_uniform_texel_buffer_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::storageTexelBufferDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .storage_texel_buffer_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .storage_texel_buffer_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .storage_texel_buffer_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .storage_texel_buffer_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::robustStorageTexelBufferDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .robust_storage_texel_buffer_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .robust_storage_texel_buffer_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .robust_storage_texel_buffer_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .robust_storage_texel_buffer_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::uniformBufferDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .uniform_buffer_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .uniform_buffer_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .uniform_buffer_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .uniform_buffer_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::robustUniformBufferDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .robust_uniform_buffer_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .robust_uniform_buffer_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .robust_uniform_buffer_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .robust_uniform_buffer_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::storageBufferDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .storage_buffer_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .storage_buffer_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .storage_buffer_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .storage_buffer_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::robustStorageBufferDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .robust_storage_buffer_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .robust_storage_buffer_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .robust_storage_buffer_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .robust_storage_buffer_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::inputAttachmentDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .input_attachment_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .input_attachment_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .input_attachment_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .input_attachment_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::accelerationStructureDescriptorSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .acceleration_structure_descriptor_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .acceleration_structure_descriptor_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .acceleration_structure_descriptor_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .acceleration_structure_descriptor_size,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::maxSamplerDescriptorBufferRange",
+            self.physical_device_descriptor_buffer_properties_ext
+                .max_sampler_descriptor_buffer_range
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .max_sampler_descriptor_buffer_range,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .max_sampler_descriptor_buffer_range,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .max_sampler_descriptor_buffer_range,
+        );
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::maxResourceDescriptorBufferRange",
+            self.physical_device_descriptor_buffer_properties_ext
+                .max_resource_descriptor_buffer_range
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .max_resource_descriptor_buffer_range,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .max_resource_descriptor_buffer_range,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .max_resource_descriptor_buffer_range,
+        );
+        f (58usize , "VkPhysicalDeviceDescriptorBufferPropertiesEXT::samplerDescriptorBufferAddressSpaceSize" , self . physical_device_descriptor_buffer_properties_ext . sampler_descriptor_buffer_address_space_size == other . physical_device_descriptor_buffer_properties_ext . sampler_descriptor_buffer_address_space_size , & self . physical_device_descriptor_buffer_properties_ext . sampler_descriptor_buffer_address_space_size , & other . physical_device_descriptor_buffer_properties_ext . sampler_descriptor_buffer_address_space_size) ;
+        f (58usize , "VkPhysicalDeviceDescriptorBufferPropertiesEXT::resourceDescriptorBufferAddressSpaceSize" , self . physical_device_descriptor_buffer_properties_ext . resource_descriptor_buffer_address_space_size == other . physical_device_descriptor_buffer_properties_ext . resource_descriptor_buffer_address_space_size , & self . physical_device_descriptor_buffer_properties_ext . resource_descriptor_buffer_address_space_size , & other . physical_device_descriptor_buffer_properties_ext . resource_descriptor_buffer_address_space_size) ;
+        f(
+            58usize,
+            "VkPhysicalDeviceDescriptorBufferPropertiesEXT::descriptorBufferAddressSpaceSize",
+            self.physical_device_descriptor_buffer_properties_ext
+                .descriptor_buffer_address_space_size
+                == other
+                    .physical_device_descriptor_buffer_properties_ext
+                    .descriptor_buffer_address_space_size,
+            &self
+                .physical_device_descriptor_buffer_properties_ext
+                .descriptor_buffer_address_space_size,
+            &other
+                .physical_device_descriptor_buffer_properties_ext
+                .descriptor_buffer_address_space_size,
+        );
+        f (59usize , "VkPhysicalDeviceDescriptorBufferDensityMapPropertiesEXT::combinedImageSamplerDensityMapDescriptorSize" , self . physical_device_descriptor_buffer_density_map_properties_ext . combined_image_sampler_density_map_descriptor_size == other . physical_device_descriptor_buffer_density_map_properties_ext . combined_image_sampler_density_map_descriptor_size , & self . physical_device_descriptor_buffer_density_map_properties_ext . combined_image_sampler_density_map_descriptor_size , & other . physical_device_descriptor_buffer_density_map_properties_ext . combined_image_sampler_density_map_descriptor_size) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct8BitUnsignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_unsigned_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_unsigned_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_unsigned_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_unsigned_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct8BitSignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_signed_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_signed_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_signed_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_signed_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct8BitMixedSignednessAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_mixed_signedness_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_mixed_signedness_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_mixed_signedness_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product8_bit_mixed_signedness_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct4x8BitPackedUnsignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_unsigned_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_unsigned_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_unsigned_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_unsigned_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct4x8BitPackedSignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_signed_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_signed_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_signed_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_signed_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct4x8BitPackedMixedSignednessAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_mixed_signedness_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_mixed_signedness_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_mixed_signedness_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product4x8_bit_packed_mixed_signedness_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct16BitUnsignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_unsigned_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_unsigned_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_unsigned_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_unsigned_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct16BitSignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_signed_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_signed_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_signed_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_signed_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct16BitMixedSignednessAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_mixed_signedness_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_mixed_signedness_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_mixed_signedness_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product16_bit_mixed_signedness_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct32BitUnsignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_unsigned_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_unsigned_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_unsigned_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_unsigned_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct32BitSignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_signed_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_signed_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_signed_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_signed_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct32BitMixedSignednessAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_mixed_signedness_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_mixed_signedness_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_mixed_signedness_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product32_bit_mixed_signedness_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct64BitUnsignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_unsigned_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_unsigned_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_unsigned_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_unsigned_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct64BitSignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_signed_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_signed_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_signed_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_signed_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProduct64BitMixedSignednessAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_mixed_signedness_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_mixed_signedness_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_mixed_signedness_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product64_bit_mixed_signedness_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating8BitUnsignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating8BitSignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_signed_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_signed_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_signed_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_signed_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating16BitUnsignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating16BitSignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_signed_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_signed_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_signed_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_signed_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating32BitUnsignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating32BitSignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_signed_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_signed_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_signed_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_signed_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating64BitUnsignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating64BitSignedAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_signed_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_signed_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_signed_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_signed_accelerated) ;
+        f (60usize , "VkPhysicalDeviceShaderIntegerDotProductProperties::integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated" , self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated == other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated , & self . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated , & other . physical_device_shader_integer_dot_product_properties . integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated) ;
+        f(
+            61usize,
+            "VkPhysicalDeviceDrmPropertiesEXT::hasPrimary",
+            self.physical_device_drm_properties_ext.has_primary
+                == other.physical_device_drm_properties_ext.has_primary,
+            &self.physical_device_drm_properties_ext.has_primary,
+            &other.physical_device_drm_properties_ext.has_primary,
+        );
+        f(
+            61usize,
+            "VkPhysicalDeviceDrmPropertiesEXT::hasRender",
+            self.physical_device_drm_properties_ext.has_render
+                == other.physical_device_drm_properties_ext.has_render,
+            &self.physical_device_drm_properties_ext.has_render,
+            &other.physical_device_drm_properties_ext.has_render,
+        );
+        f(
+            61usize,
+            "VkPhysicalDeviceDrmPropertiesEXT::primaryMajor",
+            self.physical_device_drm_properties_ext.primary_major
+                == other.physical_device_drm_properties_ext.primary_major,
+            &self.physical_device_drm_properties_ext.primary_major,
+            &other.physical_device_drm_properties_ext.primary_major,
+        );
+        f(
+            61usize,
+            "VkPhysicalDeviceDrmPropertiesEXT::primaryMinor",
+            self.physical_device_drm_properties_ext.primary_minor
+                == other.physical_device_drm_properties_ext.primary_minor,
+            &self.physical_device_drm_properties_ext.primary_minor,
+            &other.physical_device_drm_properties_ext.primary_minor,
+        );
+        f(
+            61usize,
+            "VkPhysicalDeviceDrmPropertiesEXT::renderMajor",
+            self.physical_device_drm_properties_ext.render_major
+                == other.physical_device_drm_properties_ext.render_major,
+            &self.physical_device_drm_properties_ext.render_major,
+            &other.physical_device_drm_properties_ext.render_major,
+        );
+        f(
+            61usize,
+            "VkPhysicalDeviceDrmPropertiesEXT::renderMinor",
+            self.physical_device_drm_properties_ext.render_minor
+                == other.physical_device_drm_properties_ext.render_minor,
+            &self.physical_device_drm_properties_ext.render_minor,
+            &other.physical_device_drm_properties_ext.render_minor,
+        );
+        f (62usize , "VkPhysicalDeviceFragmentShaderBarycentricPropertiesKHR::triStripVertexOrderIndependentOfProvokingVertex" , self . physical_device_fragment_shader_barycentric_properties_khr . tri_strip_vertex_order_independent_of_provoking_vertex == other . physical_device_fragment_shader_barycentric_properties_khr . tri_strip_vertex_order_independent_of_provoking_vertex , & self . physical_device_fragment_shader_barycentric_properties_khr . tri_strip_vertex_order_independent_of_provoking_vertex , & other . physical_device_fragment_shader_barycentric_properties_khr . tri_strip_vertex_order_independent_of_provoking_vertex) ;
+        f (63usize , "VkPhysicalDeviceGraphicsPipelineLibraryPropertiesEXT::graphicsPipelineLibraryFastLinking" , self . physical_device_graphics_pipeline_library_properties_ext . graphics_pipeline_library_fast_linking == other . physical_device_graphics_pipeline_library_properties_ext . graphics_pipeline_library_fast_linking , & self . physical_device_graphics_pipeline_library_properties_ext . graphics_pipeline_library_fast_linking , & other . physical_device_graphics_pipeline_library_properties_ext . graphics_pipeline_library_fast_linking) ;
+        f (63usize , "VkPhysicalDeviceGraphicsPipelineLibraryPropertiesEXT::graphicsPipelineLibraryIndependentInterpolationDecoration" , self . physical_device_graphics_pipeline_library_properties_ext . graphics_pipeline_library_independent_interpolation_decoration == other . physical_device_graphics_pipeline_library_properties_ext . graphics_pipeline_library_independent_interpolation_decoration , & self . physical_device_graphics_pipeline_library_properties_ext . graphics_pipeline_library_independent_interpolation_decoration , & other . physical_device_graphics_pipeline_library_properties_ext . graphics_pipeline_library_independent_interpolation_decoration) ;
+        f (64usize , "VkPhysicalDeviceShaderModuleIdentifierPropertiesEXT::shaderModuleIdentifierAlgorithmUUID" , self . physical_device_shader_module_identifier_properties_ext . shader_module_identifier_algorithm_uuid == other . physical_device_shader_module_identifier_properties_ext . shader_module_identifier_algorithm_uuid , & self . physical_device_shader_module_identifier_properties_ext . shader_module_identifier_algorithm_uuid , & other . physical_device_shader_module_identifier_properties_ext . shader_module_identifier_algorithm_uuid) ;
+        f(
+            65usize,
+            "VkPhysicalDeviceOpacityMicromapPropertiesEXT::maxOpacity2StateSubdivisionLevel",
+            self.physical_device_opacity_micromap_properties_ext
+                .max_opacity2_state_subdivision_level
+                == other
+                    .physical_device_opacity_micromap_properties_ext
+                    .max_opacity2_state_subdivision_level,
+            &self
+                .physical_device_opacity_micromap_properties_ext
+                .max_opacity2_state_subdivision_level,
+            &other
+                .physical_device_opacity_micromap_properties_ext
+                .max_opacity2_state_subdivision_level,
+        );
+        f(
+            65usize,
+            "VkPhysicalDeviceOpacityMicromapPropertiesEXT::maxOpacity4StateSubdivisionLevel",
+            self.physical_device_opacity_micromap_properties_ext
+                .max_opacity4_state_subdivision_level
+                == other
+                    .physical_device_opacity_micromap_properties_ext
+                    .max_opacity4_state_subdivision_level,
+            &self
+                .physical_device_opacity_micromap_properties_ext
+                .max_opacity4_state_subdivision_level,
+            &other
+                .physical_device_opacity_micromap_properties_ext
+                .max_opacity4_state_subdivision_level,
+        );
+        f (66usize , "VkPhysicalDeviceDisplacementMicromapPropertiesNV::maxDisplacementMicromapSubdivisionLevel" , self . physical_device_displacement_micromap_properties_nv . max_displacement_micromap_subdivision_level == other . physical_device_displacement_micromap_properties_nv . max_displacement_micromap_subdivision_level , & self . physical_device_displacement_micromap_properties_nv . max_displacement_micromap_subdivision_level , & other . physical_device_displacement_micromap_properties_nv . max_displacement_micromap_subdivision_level) ;
+        f(
+            67usize,
+            "VkPhysicalDevicePipelineRobustnessPropertiesEXT::defaultRobustnessStorageBuffers",
+            self.physical_device_pipeline_robustness_properties_ext
+                .default_robustness_storage_buffers
+                == other
+                    .physical_device_pipeline_robustness_properties_ext
+                    .default_robustness_storage_buffers,
+            &self
+                .physical_device_pipeline_robustness_properties_ext
+                .default_robustness_storage_buffers,
+            &other
+                .physical_device_pipeline_robustness_properties_ext
+                .default_robustness_storage_buffers,
+        );
+        f(
+            67usize,
+            "VkPhysicalDevicePipelineRobustnessPropertiesEXT::defaultRobustnessUniformBuffers",
+            self.physical_device_pipeline_robustness_properties_ext
+                .default_robustness_uniform_buffers
+                == other
+                    .physical_device_pipeline_robustness_properties_ext
+                    .default_robustness_uniform_buffers,
+            &self
+                .physical_device_pipeline_robustness_properties_ext
+                .default_robustness_uniform_buffers,
+            &other
+                .physical_device_pipeline_robustness_properties_ext
+                .default_robustness_uniform_buffers,
+        );
+        f(
+            67usize,
+            "VkPhysicalDevicePipelineRobustnessPropertiesEXT::defaultRobustnessVertexInputs",
+            self.physical_device_pipeline_robustness_properties_ext
+                .default_robustness_vertex_inputs
+                == other
+                    .physical_device_pipeline_robustness_properties_ext
+                    .default_robustness_vertex_inputs,
+            &self
+                .physical_device_pipeline_robustness_properties_ext
+                .default_robustness_vertex_inputs,
+            &other
+                .physical_device_pipeline_robustness_properties_ext
+                .default_robustness_vertex_inputs,
+        );
+        f(
+            67usize,
+            "VkPhysicalDevicePipelineRobustnessPropertiesEXT::defaultRobustnessImages",
+            self.physical_device_pipeline_robustness_properties_ext
+                .default_robustness_images
+                == other
+                    .physical_device_pipeline_robustness_properties_ext
+                    .default_robustness_images,
+            &self
+                .physical_device_pipeline_robustness_properties_ext
+                .default_robustness_images,
+            &other
+                .physical_device_pipeline_robustness_properties_ext
+                .default_robustness_images,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceImageProcessingPropertiesQCOM::maxWeightFilterPhases",
+            self.physical_device_image_processing_properties_qcom
+                .max_weight_filter_phases
+                == other
+                    .physical_device_image_processing_properties_qcom
+                    .max_weight_filter_phases,
+            &self
+                .physical_device_image_processing_properties_qcom
+                .max_weight_filter_phases,
+            &other
+                .physical_device_image_processing_properties_qcom
+                .max_weight_filter_phases,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceImageProcessingPropertiesQCOM::maxWeightFilterDimension.width",
+            self.physical_device_image_processing_properties_qcom
+                .max_weight_filter_dimension
+                .width
+                == other
+                    .physical_device_image_processing_properties_qcom
+                    .max_weight_filter_dimension
+                    .width,
+            &self
+                .physical_device_image_processing_properties_qcom
+                .max_weight_filter_dimension
+                .width,
+            &other
+                .physical_device_image_processing_properties_qcom
+                .max_weight_filter_dimension
+                .width,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceImageProcessingPropertiesQCOM::maxWeightFilterDimension.height",
+            self.physical_device_image_processing_properties_qcom
+                .max_weight_filter_dimension
+                .height
+                == other
+                    .physical_device_image_processing_properties_qcom
+                    .max_weight_filter_dimension
+                    .height,
+            &self
+                .physical_device_image_processing_properties_qcom
+                .max_weight_filter_dimension
+                .height,
+            &other
+                .physical_device_image_processing_properties_qcom
+                .max_weight_filter_dimension
+                .height,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceImageProcessingPropertiesQCOM::maxBlockMatchRegion.width",
+            self.physical_device_image_processing_properties_qcom
+                .max_block_match_region
+                .width
+                == other
+                    .physical_device_image_processing_properties_qcom
+                    .max_block_match_region
+                    .width,
+            &self
+                .physical_device_image_processing_properties_qcom
+                .max_block_match_region
+                .width,
+            &other
+                .physical_device_image_processing_properties_qcom
+                .max_block_match_region
+                .width,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceImageProcessingPropertiesQCOM::maxBlockMatchRegion.height",
+            self.physical_device_image_processing_properties_qcom
+                .max_block_match_region
+                .height
+                == other
+                    .physical_device_image_processing_properties_qcom
+                    .max_block_match_region
+                    .height,
+            &self
+                .physical_device_image_processing_properties_qcom
+                .max_block_match_region
+                .height,
+            &other
+                .physical_device_image_processing_properties_qcom
+                .max_block_match_region
+                .height,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceImageProcessingPropertiesQCOM::maxBoxFilterBlockSize.width",
+            self.physical_device_image_processing_properties_qcom
+                .max_box_filter_block_size
+                .width
+                == other
+                    .physical_device_image_processing_properties_qcom
+                    .max_box_filter_block_size
+                    .width,
+            &self
+                .physical_device_image_processing_properties_qcom
+                .max_box_filter_block_size
+                .width,
+            &other
+                .physical_device_image_processing_properties_qcom
+                .max_box_filter_block_size
+                .width,
+        );
+        f(
+            68usize,
+            "VkPhysicalDeviceImageProcessingPropertiesQCOM::maxBoxFilterBlockSize.height",
+            self.physical_device_image_processing_properties_qcom
+                .max_box_filter_block_size
+                .height
+                == other
+                    .physical_device_image_processing_properties_qcom
+                    .max_box_filter_block_size
+                    .height,
+            &self
+                .physical_device_image_processing_properties_qcom
+                .max_box_filter_block_size
+                .height,
+            &other
+                .physical_device_image_processing_properties_qcom
+                .max_box_filter_block_size
+                .height,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::supportedOutputGridSizes",
+            self.physical_device_optical_flow_properties_nv
+                .supported_output_grid_sizes
+                == other
+                    .physical_device_optical_flow_properties_nv
+                    .supported_output_grid_sizes,
+            &self
+                .physical_device_optical_flow_properties_nv
+                .supported_output_grid_sizes,
+            &other
+                .physical_device_optical_flow_properties_nv
+                .supported_output_grid_sizes,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::supportedHintGridSizes",
+            self.physical_device_optical_flow_properties_nv
+                .supported_hint_grid_sizes
+                == other
+                    .physical_device_optical_flow_properties_nv
+                    .supported_hint_grid_sizes,
+            &self
+                .physical_device_optical_flow_properties_nv
+                .supported_hint_grid_sizes,
+            &other
+                .physical_device_optical_flow_properties_nv
+                .supported_hint_grid_sizes,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::hintSupported",
+            self.physical_device_optical_flow_properties_nv
+                .hint_supported
+                == other
+                    .physical_device_optical_flow_properties_nv
+                    .hint_supported,
+            &self
+                .physical_device_optical_flow_properties_nv
+                .hint_supported,
+            &other
+                .physical_device_optical_flow_properties_nv
+                .hint_supported,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::costSupported",
+            self.physical_device_optical_flow_properties_nv
+                .cost_supported
+                == other
+                    .physical_device_optical_flow_properties_nv
+                    .cost_supported,
+            &self
+                .physical_device_optical_flow_properties_nv
+                .cost_supported,
+            &other
+                .physical_device_optical_flow_properties_nv
+                .cost_supported,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::bidirectionalFlowSupported",
+            self.physical_device_optical_flow_properties_nv
+                .bidirectional_flow_supported
+                == other
+                    .physical_device_optical_flow_properties_nv
+                    .bidirectional_flow_supported,
+            &self
+                .physical_device_optical_flow_properties_nv
+                .bidirectional_flow_supported,
+            &other
+                .physical_device_optical_flow_properties_nv
+                .bidirectional_flow_supported,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::globalFlowSupported",
+            self.physical_device_optical_flow_properties_nv
+                .global_flow_supported
+                == other
+                    .physical_device_optical_flow_properties_nv
+                    .global_flow_supported,
+            &self
+                .physical_device_optical_flow_properties_nv
+                .global_flow_supported,
+            &other
+                .physical_device_optical_flow_properties_nv
+                .global_flow_supported,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::minWidth",
+            self.physical_device_optical_flow_properties_nv.min_width
+                == other.physical_device_optical_flow_properties_nv.min_width,
+            &self.physical_device_optical_flow_properties_nv.min_width,
+            &other.physical_device_optical_flow_properties_nv.min_width,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::minHeight",
+            self.physical_device_optical_flow_properties_nv.min_height
+                == other.physical_device_optical_flow_properties_nv.min_height,
+            &self.physical_device_optical_flow_properties_nv.min_height,
+            &other.physical_device_optical_flow_properties_nv.min_height,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::maxWidth",
+            self.physical_device_optical_flow_properties_nv.max_width
+                == other.physical_device_optical_flow_properties_nv.max_width,
+            &self.physical_device_optical_flow_properties_nv.max_width,
+            &other.physical_device_optical_flow_properties_nv.max_width,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::maxHeight",
+            self.physical_device_optical_flow_properties_nv.max_height
+                == other.physical_device_optical_flow_properties_nv.max_height,
+            &self.physical_device_optical_flow_properties_nv.max_height,
+            &other.physical_device_optical_flow_properties_nv.max_height,
+        );
+        f(
+            69usize,
+            "VkPhysicalDeviceOpticalFlowPropertiesNV::maxNumRegionsOfInterest",
+            self.physical_device_optical_flow_properties_nv
+                .max_num_regions_of_interest
+                == other
+                    .physical_device_optical_flow_properties_nv
+                    .max_num_regions_of_interest,
+            &self
+                .physical_device_optical_flow_properties_nv
+                .max_num_regions_of_interest,
+            &other
+                .physical_device_optical_flow_properties_nv
+                .max_num_regions_of_interest,
+        );
+        f(
+            70usize,
+            "VkPhysicalDeviceShaderCoreBuiltinsPropertiesARM::shaderCoreMask",
+            self.physical_device_shader_core_builtins_properties_arm
+                .shader_core_mask
+                == other
+                    .physical_device_shader_core_builtins_properties_arm
+                    .shader_core_mask,
+            &self
+                .physical_device_shader_core_builtins_properties_arm
+                .shader_core_mask,
+            &other
+                .physical_device_shader_core_builtins_properties_arm
+                .shader_core_mask,
+        );
+        f(
+            70usize,
+            "VkPhysicalDeviceShaderCoreBuiltinsPropertiesARM::shaderCoreCount",
+            self.physical_device_shader_core_builtins_properties_arm
+                .shader_core_count
+                == other
+                    .physical_device_shader_core_builtins_properties_arm
+                    .shader_core_count,
+            &self
+                .physical_device_shader_core_builtins_properties_arm
+                .shader_core_count,
+            &other
+                .physical_device_shader_core_builtins_properties_arm
+                .shader_core_count,
+        );
+        f(
+            70usize,
+            "VkPhysicalDeviceShaderCoreBuiltinsPropertiesARM::shaderWarpsPerCore",
+            self.physical_device_shader_core_builtins_properties_arm
+                .shader_warps_per_core
+                == other
+                    .physical_device_shader_core_builtins_properties_arm
+                    .shader_warps_per_core,
+            &self
+                .physical_device_shader_core_builtins_properties_arm
+                .shader_warps_per_core,
+            &other
+                .physical_device_shader_core_builtins_properties_arm
+                .shader_warps_per_core,
+        );
+        f (71usize , "VkPhysicalDeviceRayTracingInvocationReorderPropertiesNV::rayTracingInvocationReorderReorderingHint" , self . physical_device_ray_tracing_invocation_reorder_properties_nv . ray_tracing_invocation_reorder_reordering_hint == other . physical_device_ray_tracing_invocation_reorder_properties_nv . ray_tracing_invocation_reorder_reordering_hint , & self . physical_device_ray_tracing_invocation_reorder_properties_nv . ray_tracing_invocation_reorder_reordering_hint , & other . physical_device_ray_tracing_invocation_reorder_properties_nv . ray_tracing_invocation_reorder_reordering_hint) ;
+        f(
+            72usize,
+            "VkPhysicalDeviceShaderCorePropertiesARM::pixelRate",
+            self.physical_device_shader_core_properties_arm.pixel_rate
+                == other.physical_device_shader_core_properties_arm.pixel_rate,
+            &self.physical_device_shader_core_properties_arm.pixel_rate,
+            &other.physical_device_shader_core_properties_arm.pixel_rate,
+        );
+        f(
+            72usize,
+            "VkPhysicalDeviceShaderCorePropertiesARM::texelRate",
+            self.physical_device_shader_core_properties_arm.texel_rate
+                == other.physical_device_shader_core_properties_arm.texel_rate,
+            &self.physical_device_shader_core_properties_arm.texel_rate,
+            &other.physical_device_shader_core_properties_arm.texel_rate,
+        );
+        f(
+            72usize,
+            "VkPhysicalDeviceShaderCorePropertiesARM::fmaRate",
+            self.physical_device_shader_core_properties_arm.fma_rate
+                == other.physical_device_shader_core_properties_arm.fma_rate,
+            &self.physical_device_shader_core_properties_arm.fma_rate,
+            &other.physical_device_shader_core_properties_arm.fma_rate,
+        );
+        f(
+            73usize,
+            "VkPhysicalDeviceShaderObjectPropertiesEXT::shaderBinaryUUID",
+            self.physical_device_shader_object_properties_ext
+                .shader_binary_uuid
+                == other
+                    .physical_device_shader_object_properties_ext
+                    .shader_binary_uuid,
+            &self
+                .physical_device_shader_object_properties_ext
+                .shader_binary_uuid,
+            &other
+                .physical_device_shader_object_properties_ext
+                .shader_binary_uuid,
+        );
+        f(
+            73usize,
+            "VkPhysicalDeviceShaderObjectPropertiesEXT::shaderBinaryVersion",
+            self.physical_device_shader_object_properties_ext
+                .shader_binary_version
+                == other
+                    .physical_device_shader_object_properties_ext
+                    .shader_binary_version,
+            &self
+                .physical_device_shader_object_properties_ext
+                .shader_binary_version,
+            &other
+                .physical_device_shader_object_properties_ext
+                .shader_binary_version,
+        );
+        f(
+            74usize,
+            "VkPhysicalDeviceShaderTileImagePropertiesEXT::shaderTileImageCoherentReadAccelerated",
+            self.physical_device_shader_tile_image_properties_ext
+                .shader_tile_image_coherent_read_accelerated
+                == other
+                    .physical_device_shader_tile_image_properties_ext
+                    .shader_tile_image_coherent_read_accelerated,
+            &self
+                .physical_device_shader_tile_image_properties_ext
+                .shader_tile_image_coherent_read_accelerated,
+            &other
+                .physical_device_shader_tile_image_properties_ext
+                .shader_tile_image_coherent_read_accelerated,
+        );
+        f (74usize , "VkPhysicalDeviceShaderTileImagePropertiesEXT::shaderTileImageReadSampleFromPixelRateInvocation" , self . physical_device_shader_tile_image_properties_ext . shader_tile_image_read_sample_from_pixel_rate_invocation == other . physical_device_shader_tile_image_properties_ext . shader_tile_image_read_sample_from_pixel_rate_invocation , & self . physical_device_shader_tile_image_properties_ext . shader_tile_image_read_sample_from_pixel_rate_invocation , & other . physical_device_shader_tile_image_properties_ext . shader_tile_image_read_sample_from_pixel_rate_invocation) ;
+        f(
+            74usize,
+            "VkPhysicalDeviceShaderTileImagePropertiesEXT::shaderTileImageReadFromHelperInvocation",
+            self.physical_device_shader_tile_image_properties_ext
+                .shader_tile_image_read_from_helper_invocation
+                == other
+                    .physical_device_shader_tile_image_properties_ext
+                    .shader_tile_image_read_from_helper_invocation,
+            &self
+                .physical_device_shader_tile_image_properties_ext
+                .shader_tile_image_read_from_helper_invocation,
+            &other
+                .physical_device_shader_tile_image_properties_ext
+                .shader_tile_image_read_from_helper_invocation,
+        );
+    }
+}
+impl Property for vk::PhysicalDeviceProperties {
+    fn get(properties: &Properties) -> Option<&Self> {
+        Some(&properties.structs.physical_device_properties2.properties)
+    }
+}
+impl Property for vk::PhysicalDeviceDeviceGeneratedCommandsPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[1usize].then(|| {
+            &properties
+                .structs
+                .physical_device_device_generated_commands_properties_nv
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceMultiDrawPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[2usize]
+            .then(|| &properties.structs.physical_device_multi_draw_properties_ext)
+    }
+}
+impl Property for vk::PhysicalDevicePushDescriptorPropertiesKHR<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[3usize].then(|| {
+            &properties
+                .structs
+                .physical_device_push_descriptor_properties_khr
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceDriverProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[4usize].then(|| &properties.structs.physical_device_driver_properties)
+    }
+}
+impl Property for vk::PhysicalDeviceIDProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[5usize].then(|| &properties.structs.physical_device_id_properties)
+    }
+}
+impl Property for vk::PhysicalDeviceMultiviewProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[6usize].then(|| &properties.structs.physical_device_multiview_properties)
+    }
+}
+impl Property for vk::PhysicalDeviceDiscardRectanglePropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[7usize].then(|| {
+            &properties
+                .structs
+                .physical_device_discard_rectangle_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[8usize].then(|| {
+            &properties
+                .structs
+                .physical_device_multiview_per_view_attributes_properties_nvx
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceSubgroupProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[9usize].then(|| &properties.structs.physical_device_subgroup_properties)
+    }
+}
+impl Property for vk::PhysicalDevicePointClippingProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[10usize]
+            .then(|| &properties.structs.physical_device_point_clipping_properties)
+    }
+}
+impl Property for vk::PhysicalDeviceProtectedMemoryProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[11usize].then(|| {
+            &properties
+                .structs
+                .physical_device_protected_memory_properties
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceSamplerFilterMinmaxProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[12usize].then(|| {
+            &properties
+                .structs
+                .physical_device_sampler_filter_minmax_properties
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[13usize].then(|| {
+            &properties
+                .structs
+                .physical_device_sample_locations_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceBlendOperationAdvancedPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[14usize].then(|| {
+            &properties
+                .structs
+                .physical_device_blend_operation_advanced_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceInlineUniformBlockProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[15usize].then(|| {
+            &properties
+                .structs
+                .physical_device_inline_uniform_block_properties
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceMaintenance3Properties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[16usize]
+            .then(|| &properties.structs.physical_device_maintenance3_properties)
+    }
+}
+impl Property for vk::PhysicalDeviceMaintenance4Properties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[17usize]
+            .then(|| &properties.structs.physical_device_maintenance4_properties)
+    }
+}
+impl Property for vk::PhysicalDeviceFloatControlsProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[18usize]
+            .then(|| &properties.structs.physical_device_float_controls_properties)
+    }
+}
+impl Property for vk::PhysicalDeviceExternalMemoryHostPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[19usize].then(|| {
+            &properties
+                .structs
+                .physical_device_external_memory_host_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceConservativeRasterizationPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[20usize].then(|| {
+            &properties
+                .structs
+                .physical_device_conservative_rasterization_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceShaderCorePropertiesAMD<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[21usize].then(|| {
+            &properties
+                .structs
+                .physical_device_shader_core_properties_amd
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceShaderCoreProperties2AMD<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[22usize].then(|| {
+            &properties
+                .structs
+                .physical_device_shader_core_properties2_amd
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceDescriptorIndexingProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[23usize].then(|| {
+            &properties
+                .structs
+                .physical_device_descriptor_indexing_properties
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceTimelineSemaphoreProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[24usize].then(|| {
+            &properties
+                .structs
+                .physical_device_timeline_semaphore_properties
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceVertexAttributeDivisorPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[25usize].then(|| {
+            &properties
+                .structs
+                .physical_device_vertex_attribute_divisor_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDevicePCIBusInfoPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[26usize].then(|| {
+            &properties
+                .structs
+                .physical_device_pci_bus_info_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceDepthStencilResolveProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[27usize].then(|| {
+            &properties
+                .structs
+                .physical_device_depth_stencil_resolve_properties
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[28usize].then(|| {
+            &properties
+                .structs
+                .physical_device_transform_feedback_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceCopyMemoryIndirectPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[29usize].then(|| {
+            &properties
+                .structs
+                .physical_device_copy_memory_indirect_properties_nv
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceMemoryDecompressionPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[30usize].then(|| {
+            &properties
+                .structs
+                .physical_device_memory_decompression_properties_nv
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceShadingRateImagePropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[31usize].then(|| {
+            &properties
+                .structs
+                .physical_device_shading_rate_image_properties_nv
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceMeshShaderPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[32usize]
+            .then(|| &properties.structs.physical_device_mesh_shader_properties_nv)
+    }
+}
+impl Property for vk::PhysicalDeviceMeshShaderPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[33usize].then(|| {
+            &properties
+                .structs
+                .physical_device_mesh_shader_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceAccelerationStructurePropertiesKHR<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[34usize].then(|| {
+            &properties
+                .structs
+                .physical_device_acceleration_structure_properties_khr
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[35usize].then(|| {
+            &properties
+                .structs
+                .physical_device_ray_tracing_pipeline_properties_khr
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceRayTracingPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[36usize]
+            .then(|| &properties.structs.physical_device_ray_tracing_properties_nv)
+    }
+}
+impl Property for vk::PhysicalDeviceFragmentDensityMapPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[37usize].then(|| {
+            &properties
+                .structs
+                .physical_device_fragment_density_map_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceFragmentDensityMap2PropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[38usize].then(|| {
+            &properties
+                .structs
+                .physical_device_fragment_density_map2_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[39usize].then(|| {
+            &properties
+                .structs
+                .physical_device_fragment_density_map_offset_properties_qcom
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceCooperativeMatrixPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[40usize].then(|| {
+            &properties
+                .structs
+                .physical_device_cooperative_matrix_properties_nv
+        })
+    }
+}
+impl Property for vk::PhysicalDevicePerformanceQueryPropertiesKHR<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[41usize].then(|| {
+            &properties
+                .structs
+                .physical_device_performance_query_properties_khr
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceShaderSMBuiltinsPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[42usize].then(|| {
+            &properties
+                .structs
+                .physical_device_shader_sm_builtins_properties_nv
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceTexelBufferAlignmentProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[43usize].then(|| {
+            &properties
+                .structs
+                .physical_device_texel_buffer_alignment_properties
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceSubgroupSizeControlProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[44usize].then(|| {
+            &properties
+                .structs
+                .physical_device_subgroup_size_control_properties
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceSubpassShadingPropertiesHUAWEI<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[45usize].then(|| {
+            &properties
+                .structs
+                .physical_device_subpass_shading_properties_huawei
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceClusterCullingShaderPropertiesHUAWEI<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[46usize].then(|| {
+            &properties
+                .structs
+                .physical_device_cluster_culling_shader_properties_huawei
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceLineRasterizationPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[47usize].then(|| {
+            &properties
+                .structs
+                .physical_device_line_rasterization_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceVulkan11Properties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[48usize].then(|| &properties.structs.physical_device_vulkan11_properties)
+    }
+}
+impl Property for vk::PhysicalDeviceVulkan12Properties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[49usize].then(|| &properties.structs.physical_device_vulkan12_properties)
+    }
+}
+impl Property for vk::PhysicalDeviceVulkan13Properties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[50usize].then(|| &properties.structs.physical_device_vulkan13_properties)
+    }
+}
+impl Property for vk::PhysicalDeviceCustomBorderColorPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[51usize].then(|| {
+            &properties
+                .structs
+                .physical_device_custom_border_color_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceExtendedDynamicState3PropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[52usize].then(|| {
+            &properties
+                .structs
+                .physical_device_extended_dynamic_state3_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceRobustness2PropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[53usize].then(|| {
+            &properties
+                .structs
+                .physical_device_robustness2_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDevicePortabilitySubsetPropertiesKHR<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[54usize].then(|| {
+            &properties
+                .structs
+                .physical_device_portability_subset_properties_khr
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceFragmentShadingRatePropertiesKHR<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[55usize].then(|| {
+            &properties
+                .structs
+                .physical_device_fragment_shading_rate_properties_khr
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceFragmentShadingRateEnumsPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[56usize].then(|| {
+            &properties
+                .structs
+                .physical_device_fragment_shading_rate_enums_properties_nv
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceProvokingVertexPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[57usize].then(|| {
+            &properties
+                .structs
+                .physical_device_provoking_vertex_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[58usize].then(|| {
+            &properties
+                .structs
+                .physical_device_descriptor_buffer_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceDescriptorBufferDensityMapPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[59usize].then(|| {
+            &properties
+                .structs
+                .physical_device_descriptor_buffer_density_map_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceShaderIntegerDotProductProperties<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[60usize].then(|| {
+            &properties
+                .structs
+                .physical_device_shader_integer_dot_product_properties
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceDrmPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[61usize].then(|| &properties.structs.physical_device_drm_properties_ext)
+    }
+}
+impl Property for vk::PhysicalDeviceFragmentShaderBarycentricPropertiesKHR<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[62usize].then(|| {
+            &properties
+                .structs
+                .physical_device_fragment_shader_barycentric_properties_khr
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[63usize].then(|| {
+            &properties
+                .structs
+                .physical_device_graphics_pipeline_library_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceShaderModuleIdentifierPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[64usize].then(|| {
+            &properties
+                .structs
+                .physical_device_shader_module_identifier_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceOpacityMicromapPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[65usize].then(|| {
+            &properties
+                .structs
+                .physical_device_opacity_micromap_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceDisplacementMicromapPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[66usize].then(|| {
+            &properties
+                .structs
+                .physical_device_displacement_micromap_properties_nv
+        })
+    }
+}
+impl Property for vk::PhysicalDevicePipelineRobustnessPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[67usize].then(|| {
+            &properties
+                .structs
+                .physical_device_pipeline_robustness_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceImageProcessingPropertiesQCOM<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[68usize].then(|| {
+            &properties
+                .structs
+                .physical_device_image_processing_properties_qcom
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceOpticalFlowPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[69usize].then(|| {
+            &properties
+                .structs
+                .physical_device_optical_flow_properties_nv
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceShaderCoreBuiltinsPropertiesARM<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[70usize].then(|| {
+            &properties
+                .structs
+                .physical_device_shader_core_builtins_properties_arm
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceRayTracingInvocationReorderPropertiesNV<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[71usize].then(|| {
+            &properties
+                .structs
+                .physical_device_ray_tracing_invocation_reorder_properties_nv
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceShaderCorePropertiesARM<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[72usize].then(|| {
+            &properties
+                .structs
+                .physical_device_shader_core_properties_arm
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceShaderObjectPropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[73usize].then(|| {
+            &properties
+                .structs
+                .physical_device_shader_object_properties_ext
+        })
+    }
+}
+impl Property for vk::PhysicalDeviceShaderTileImagePropertiesEXT<'static> {
+    fn get(properties: &Properties) -> Option<&Self> {
+        properties.queried[74usize].then(|| {
+            &properties
+                .structs
+                .physical_device_shader_tile_image_properties_ext
+        })
+    }
+}
@@ -0,0 +1,824 @@
+//! [`Serialize`] and [`Deserialize`] implementations for the types of [`vk`].
+//!
+//! Enums and flags are serialized by the names of their values in human-readable formats, and by
+//! their raw value otherwise. Structs are serialized by the names of their members, except
+//! `s_type`, which is implied by the struct. Missing members keep their default value when
+//! deserializing.
+//!
+//! Structs that point to other data are implemented through their [`DeepCopy`], which owns that
+//! data. Their `p_next` chain is serialized as a list of maps with a single entry, from the
+//! `s_type` of an extension struct to its members.
+
+use crate::deep_copy::{Arena, DeepCopy, DeepCopyable};
+#[cfg(feature = "properties")]
+use crate::properties::Properties;
+use crate::vk::{self, Handle};
+use serde::de::{
+    self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor,
+};
+use serde::ser::{SerializeMap, SerializeStruct, SerializeTuple, Serializer};
+use serde::{Deserialize, Serialize};
+use std::ffi::{c_void, CStr, CString};
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::str::FromStr;
+use std::{fmt, mem, ptr, slice};
+
+/// Types that can be serialized as a struct member, following the pointers they hold.
+///
+/// Public to bound the implementations for [`DeepCopy`], but not reachable outside of `ash`.
+///
+/// # Safety
+///
+/// [`Field::deserialize()`] must only point into allocations of the given [`Arena`].
+pub unsafe trait Field: Sized {
+    /// # Safety
+    ///
+    /// All pointers in `self` must be valid as required by the Vulkan specification.
+    unsafe fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    /// Deserializes a value, allocating the data it points to in `arena`.
+    fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        arena: &mut Arena,
+    ) -> Result<Self, D::Error>;
+}
+
+/// Structs, deserialized by setting the members found in a map on their default value.
+pub trait Struct: Field + Default {
+    /// The name of the struct, without the `Vk` prefix.
+    const NAME: &'static str;
+    /// The names of the serialized members.
+    const FIELDS: &'static [&'static str];
+
+    /// Deserializes the member named `field` from the next value of `map`.
+    fn deserialize_field<'de, A: MapAccess<'de>>(
+        &mut self,
+        field: &str,
+        map: &mut A,
+        arena: &mut Arena,
+    ) -> Result<(), A::Error>;
+
+    /// The name of the first pointer member whose allocation in `arena` is shorter than the
+    /// length given by the other members.
+    fn short_array(&self, arena: &Arena) -> Option<&'static str>;
+}
+
+macro_rules! serde_field {
+    ($($ty:ty),*) => {
+        $(unsafe impl Field for $ty {
+            unsafe fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                Serialize::serialize(self, serializer)
+            }
+
+            fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+                _arena: &mut Arena,
+            ) -> Result<Self, D::Error> {
+                Deserialize::deserialize(deserializer)
+            }
+        })*
+    };
+}
+
+serde_field!(u8, u16, u32, u64, usize, i8, i16, i32, i64, f32, f64);
+
+/// Handles are serialized by their raw value.
+unsafe impl<H: Handle + Copy> Field for H {
+    unsafe fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.as_raw())
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        _arena: &mut Arena,
+    ) -> Result<Self, D::Error> {
+        <u64 as Deserialize>::deserialize(deserializer).map(H::from_raw)
+    }
+}
+
+unsafe impl<T: Field, const N: usize> Field for [T; N] {
+    unsafe fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for element in self {
+            tuple.serialize_element(&Member(element))?;
+        }
+        tuple.end()
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        arena: &mut Arena,
+    ) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(N, FixedArrayVisitor(arena, PhantomData))
+    }
+}
+
+struct FixedArrayVisitor<'a, T, const N: usize>(&'a mut Arena, PhantomData<T>);
+
+impl<'de, T: Field, const N: usize> Visitor<'de> for FixedArrayVisitor<'_, T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an array of {N} elements")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut elements = Vec::with_capacity(N);
+        for i in 0..N {
+            let element = seq
+                .next_element_seed(FieldSeed::new(self.0))?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            elements.push(element);
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+        match elements.try_into() {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+/// Implements [`Serialize`], [`Deserialize`] and [`Field`] for enums, using the names of their
+/// values.
+macro_rules! serde_enums {
+    ($($ty:ident),*) => {
+        $(impl Serialize for vk::$ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let name = Self::KNOWN
+                    .iter()
+                    .find(|&&(value, _, _)| value == self.as_raw())
+                    .map(|&(_, name, _)| name);
+                match name {
+                    Some(name) if serializer.is_human_readable() => serializer.serialize_str(name),
+                    _ => serializer.serialize_i32(self.as_raw()),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for vk::$ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let visitor = ValueVisitor(stringify!($ty), PhantomData);
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(visitor)
+                } else {
+                    deserializer.deserialize_i32(visitor)
+                }
+            }
+        }
+
+        impl Value for vk::$ty {
+            fn from_u64(v: u64) -> Option<Self> {
+                v.try_into().ok().map(Self::from_raw)
+            }
+
+            fn from_i64(v: i64) -> Option<Self> {
+                v.try_into().ok().map(Self::from_raw)
+            }
+        }
+
+        serde_field!(vk::$ty);)*
+    };
+}
+
+/// Implements [`Serialize`], [`Deserialize`] and [`Field`] for flags, as the names of their bits
+/// separated by `|`.
+macro_rules! serde_flags {
+    ($($ty:ident),*) => {
+        $(impl Serialize for vk::$ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if !serializer.is_human_readable() {
+                    return serializer.serialize_u64(self.as_raw().into());
+                }
+                let mut names = Vec::new();
+                let mut rest = self.as_raw();
+                for &(bit, name, _) in Self::KNOWN {
+                    if bit != 0 && rest & bit == bit {
+                        names.push(name.to_owned());
+                        rest &= !bit;
+                    }
+                }
+                if rest != 0 {
+                    names.push(format!("{rest:#x}"));
+                }
+                serializer.serialize_str(&names.join(" | "))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for vk::$ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let visitor = ValueVisitor(stringify!($ty), PhantomData);
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(visitor)
+                } else {
+                    deserializer.deserialize_u64(visitor)
+                }
+            }
+        }
+
+        impl Value for vk::$ty {
+            fn from_u64(v: u64) -> Option<Self> {
+                v.try_into().ok().map(Self::from_raw)
+            }
+
+            fn from_i64(v: i64) -> Option<Self> {
+                u64::try_from(v).ok().and_then(Self::from_u64)
+            }
+        }
+
+        serde_field!(vk::$ty);)*
+    };
+}
+
+/// Enums and flags, deserialized from the names of their values as parsed by [`FromStr`], or
+/// from their raw value.
+trait Value: FromStr<Err = vk::ParseValueError> {
+    fn from_u64(v: u64) -> Option<Self>;
+    fn from_i64(v: i64) -> Option<Self>;
+}
+
+struct ValueVisitor<T>(&'static str, PhantomData<T>);
+
+impl<'de, T: Value> Visitor<'de> for ValueVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the names or raw value of a {}", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(|error: vk::ParseValueError| {
+            E::invalid_value(Unexpected::Str(error.value()), &self)
+        })
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        T::from_i64(v).ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        T::from_u64(v).ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+}
+
+/// Implements [`Field`] for unions and other types whose members are not described by `vk.xml`,
+/// as their bytes.
+macro_rules! serde_bytes {
+    ($($ty:ty),*) => {
+        $(unsafe impl Field for $ty {
+            unsafe fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let ptr: *const Self = self;
+                let bytes = slice::from_raw_parts(ptr.cast::<u8>(), mem::size_of::<Self>());
+                serializer.serialize_bytes(bytes)
+            }
+
+            fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+                _arena: &mut Arena,
+            ) -> Result<Self, D::Error> {
+                let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
+                if bytes.len() != mem::size_of::<Self>() {
+                    return Err(de::Error::invalid_length(
+                        bytes.len(),
+                        &stringify!($ty),
+                    ));
+                }
+                Ok(unsafe { ptr::read_unaligned(bytes.as_ptr().cast()) })
+            }
+        })*
+    };
+}
+
+/// Implements [`Serialize`] and [`Deserialize`] for structs that don't point to other data,
+/// through their [`Field`] implementation.
+macro_rules! serde_structs {
+    ($($ty:ident),*) => {
+        $(impl Serialize for vk::$ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                // Structs without a lifetime don't hold pointers that are followed
+                unsafe { Field::serialize(self, serializer) }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for vk::$ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Field::deserialize(deserializer, &mut Arena::new())
+            }
+        })*
+    };
+}
+
+#[allow(deprecated)]
+mod generated;
+
+impl<T: Struct + DeepCopyable> Serialize for DeepCopy<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // The view only points into the copy
+        unsafe { Field::serialize(self.view(), serializer) }
+    }
+}
+
+impl<'de, T: Struct + DeepCopyable> Deserialize<'de> for DeepCopy<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut arena = Arena::new();
+        let view = T::deserialize(deserializer, &mut arena)?;
+        // Every struct checked the lengths of its arrays against their allocations
+        Ok(unsafe { Self::from_parts(view, arena) })
+    }
+}
+
+/// Serialized as a [`vk::PhysicalDeviceProperties2`] with the queried structs in its chain.
+#[cfg(feature = "properties")]
+impl Serialize for Properties {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut properties = self.clone();
+        // The chain only points into the clone
+        unsafe { Field::serialize(properties.link(), serializer) }
+    }
+}
+
+#[cfg(feature = "properties")]
+impl<'de> Deserialize<'de> for Properties {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut arena = Arena::new();
+        let properties2: vk::PhysicalDeviceProperties2<'_> =
+            Field::deserialize(deserializer, &mut arena)?;
+        // The chain points into `arena`, and the structs of properties are copied out of it
+        Ok(unsafe { Self::from_chain(&properties2) })
+    }
+}
+
+fn deserialize_struct<'de, T: Struct, D: Deserializer<'de>>(
+    deserializer: D,
+    arena: &mut Arena,
+) -> Result<T, D::Error> {
+    deserializer.deserialize_struct(T::NAME, T::FIELDS, StructVisitor(arena, PhantomData))
+}
+
+struct StructVisitor<'a, T>(&'a mut Arena, PhantomData<T>);
+
+impl<'de, T: Struct> Visitor<'de> for StructVisitor<'_, T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct {}", T::NAME)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+        let mut value = T::default();
+        while let Some(field) = map.next_key::<String>()? {
+            value.deserialize_field(&field, &mut map, self.0)?;
+        }
+        match value.short_array(self.0) {
+            Some(field) => Err(de::Error::custom(format_args!(
+                "`{field}` of {} has fewer elements than its length",
+                T::NAME
+            ))),
+            None => Ok(value),
+        }
+    }
+}
+
+/// Serializes a member through [`Field`].
+struct Member<'a, T>(&'a T);
+
+impl<T: Field> Serialize for Member<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Only created for members of structs whose pointers are valid
+        unsafe { self.0.serialize(serializer) }
+    }
+}
+
+/// Serializes the elements behind a pointer, or `None` if it is null.
+struct Array<T>(*const T, usize);
+
+impl<T: Field> Serialize for Array<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.is_null() {
+            return serializer.serialize_none();
+        }
+        let elements = unsafe { slice::from_raw_parts(self.0, self.1) };
+        serializer.serialize_some(&Elements(elements))
+    }
+}
+
+struct Elements<'a, T>(&'a [T]);
+
+impl<T: Field> Serialize for Elements<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(Member))
+    }
+}
+
+/// Serializes the element behind a pointer, or `None` if it is null.
+struct Pointer<T>(*const T);
+
+impl<T: Field> Serialize for Pointer<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match unsafe { self.0.as_ref() } {
+            Some(element) => serializer.serialize_some(&Member(element)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Serializes a null-terminated string, or `None` if it is null.
+struct Str(*const c_char);
+
+impl Serialize for Str {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.is_null() {
+            return serializer.serialize_none();
+        }
+        let string = unsafe { CStr::from_ptr(self.0) };
+        serializer.serialize_some(&string.to_string_lossy())
+    }
+}
+
+/// Serializes an array of null-terminated strings, or `None` if it is null.
+struct Strs(*const *const c_char, usize);
+
+impl Serialize for Strs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.is_null() {
+            return serializer.serialize_none();
+        }
+        let strings = unsafe { slice::from_raw_parts(self.0, self.1) };
+        serializer.serialize_some(&StrElements(strings))
+    }
+}
+
+struct StrElements<'a>(&'a [*const c_char]);
+
+impl Serialize for StrElements<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|&string| Str(string)))
+    }
+}
+
+/// Serializes the bytes behind a `void` pointer, or `None` if it is null.
+struct Bytes(*const u8, usize);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.is_null() {
+            return serializer.serialize_none();
+        }
+        let bytes = unsafe { slice::from_raw_parts(self.0, self.1) };
+        serializer.serialize_some(&ByteSlice(bytes))
+    }
+}
+
+struct ByteSlice<'a>(&'a [u8]);
+
+impl Serialize for ByteSlice<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Serializes a fixed-size `char` array as a string, up to its null terminator.
+struct Chars<'a>(&'a [c_char]);
+
+impl Serialize for Chars<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = self
+            .0
+            .iter()
+            .take_while(|&&c| c != 0)
+            .map(|&c| c as u8)
+            .collect::<Vec<_>>();
+        serializer.serialize_str(&String::from_utf8_lossy(&bytes))
+    }
+}
+
+/// Serializes the extension structs of a `p_next` chain, or `None` if it is null.
+struct Chain(*const c_void);
+
+impl Serialize for Chain {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.is_null() {
+            return serializer.serialize_none();
+        }
+        let mut extensions = Vec::new();
+        let mut ptr = self.0.cast::<vk::BaseInStructure<'_>>();
+        while !ptr.is_null() {
+            extensions.push(Extension(ptr));
+            ptr = unsafe { (*ptr).p_next };
+        }
+        serializer.serialize_some(&extensions)
+    }
+}
+
+/// Serializes an extension struct as a map from its `s_type` to its members.
+struct Extension<'a>(*const vk::BaseInStructure<'a>);
+
+impl Serialize for Extension<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        unsafe { generated::serialize_extension(self.0, serializer) }
+    }
+}
+
+/// Serializes an extension struct found by [`generated::serialize_extension()`].
+unsafe fn serialize_extension_as<T: Field, S: Serializer>(
+    extension: &T,
+    s_type: vk::StructureType,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry(&s_type, &Member(extension))?;
+    map.end()
+}
+
+fn unknown_extension<E: serde::ser::Error>(s_type: vk::StructureType) -> E {
+    E::custom(format_args!(
+        "Extension struct of type {} can't be serialized",
+        s_type.as_raw()
+    ))
+}
+
+/// Deserializes a [`Field`].
+struct FieldSeed<'a, T>(&'a mut Arena, PhantomData<T>);
+
+impl<'a, T> FieldSeed<'a, T> {
+    fn new(arena: &'a mut Arena) -> Self {
+        Self(arena, PhantomData)
+    }
+}
+
+impl<'de, T: Field> DeserializeSeed<'de> for FieldSeed<'_, T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize(deserializer, self.0)
+    }
+}
+
+/// Deserializes a pointer with the inner seed, or a null pointer from `None`.
+struct Nullable<S>(S);
+
+impl<'de, T, S: DeserializeSeed<'de, Value = *mut T>> DeserializeSeed<'de> for Nullable<S> {
+    type Value = *mut T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<*mut T, D::Error> {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de, T, S: DeserializeSeed<'de, Value = *mut T>> Visitor<'de> for Nullable<S> {
+    type Value = *mut T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an optional value")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<*mut T, E> {
+        Ok(ptr::null_mut())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<*mut T, E> {
+        Ok(ptr::null_mut())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<*mut T, D::Error> {
+        self.0.deserialize(deserializer)
+    }
+}
+
+/// Deserializes a sequence of [`Field`]s into an allocation.
+struct ArraySeed<'a, T>(&'a mut Arena, PhantomData<T>);
+
+impl<'a, T> ArraySeed<'a, T> {
+    fn new(arena: &'a mut Arena) -> Self {
+        Self(arena, PhantomData)
+    }
+}
+
+impl<'de, T: Field> DeserializeSeed<'de> for ArraySeed<'_, T> {
+    type Value = *mut T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<*mut T, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: Field> Visitor<'de> for ArraySeed<'_, T> {
+    type Value = *mut T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an array")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<*mut T, A::Error> {
+        let mut elements = Vec::new();
+        while let Some(element) = seq.next_element_seed(FieldSeed::new(self.0))? {
+            elements.push(element);
+        }
+        let ptr = self.0.alloc::<T>(elements.len());
+        for (i, element) in elements.into_iter().enumerate() {
+            unsafe { ptr.add(i).write(element) };
+        }
+        Ok(ptr)
+    }
+}
+
+/// Deserializes a single [`Field`] into an allocation.
+struct PointerSeed<'a, T>(&'a mut Arena, PhantomData<T>);
+
+impl<'a, T> PointerSeed<'a, T> {
+    fn new(arena: &'a mut Arena) -> Self {
+        Self(arena, PhantomData)
+    }
+}
+
+impl<'de, T: Field> DeserializeSeed<'de> for PointerSeed<'_, T> {
+    type Value = *mut T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<*mut T, D::Error> {
+        let element = T::deserialize(deserializer, self.0)?;
+        Ok(self.0.alloc_value(element))
+    }
+}
+
+/// Deserializes a string into a null-terminated allocation.
+struct StrSeed<'a>(&'a mut Arena);
+
+impl<'de> DeserializeSeed<'de> for StrSeed<'_> {
+    type Value = *mut c_char;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<*mut c_char, D::Error> {
+        let string = CString::new(String::deserialize(deserializer)?).map_err(de::Error::custom)?;
+        let bytes = string.as_bytes_with_nul();
+        let ptr = self.0.alloc::<c_char>(bytes.len());
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.cast(), bytes.len()) };
+        Ok(ptr)
+    }
+}
+
+/// Deserializes a sequence of optional strings into an allocation.
+struct StrsSeed<'a>(&'a mut Arena);
+
+impl<'de> DeserializeSeed<'de> for StrsSeed<'_> {
+    type Value = *mut *const c_char;
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<*mut *const c_char, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for StrsSeed<'_> {
+    type Value = *mut *const c_char;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an array of strings")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut strings = Vec::new();
+        while let Some(string) = seq.next_element_seed(Nullable(StrSeed(self.0)))? {
+            strings.push(string as *const c_char);
+        }
+        let ptr = self.0.alloc::<*const c_char>(strings.len());
+        unsafe { ptr::copy_nonoverlapping(strings.as_ptr(), ptr, strings.len()) };
+        Ok(ptr)
+    }
+}
+
+/// Deserializes bytes into an allocation.
+struct BytesSeed<'a>(&'a mut Arena);
+
+impl<'de> DeserializeSeed<'de> for BytesSeed<'_> {
+    type Value = *mut u8;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<*mut u8, D::Error> {
+        let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
+        let ptr = self.0.alloc::<u8>(bytes.len());
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len()) };
+        Ok(ptr)
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut bytes = Vec::new();
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+/// Deserializes a string into a fixed-size `char` array, which must have room for its null
+/// terminator.
+struct CharsSeed<const N: usize>;
+
+impl<'de, const N: usize> DeserializeSeed<'de> for CharsSeed<N> {
+    type Value = [c_char; N];
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<[c_char; N], D::Error> {
+        let string = String::deserialize(deserializer)?;
+        if string.len() >= N {
+            return Err(de::Error::invalid_length(
+                string.len(),
+                &format!("a string shorter than {N} bytes").as_str(),
+            ));
+        }
+        let mut chars = [0; N];
+        for (c, &byte) in chars.iter_mut().zip(string.as_bytes()) {
+            *c = byte as c_char;
+        }
+        Ok(chars)
+    }
+}
+
+/// Deserializes the extension structs of a `p_next` chain into allocations, in order.
+struct ChainSeed<'a>(&'a mut Arena);
+
+impl<'de> DeserializeSeed<'de> for ChainSeed<'_> {
+    type Value = *mut c_void;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<*mut c_void, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ChainSeed<'_> {
+    type Value = *mut c_void;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an array of extension structs")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<*mut c_void, A::Error> {
+        let mut extensions = Vec::new();
+        while let Some(extension) = seq.next_element_seed(ExtensionSeed(self.0))? {
+            extensions.push(extension);
+        }
+        let mut p_next = ptr::null_mut::<vk::BaseOutStructure<'_>>();
+        for &extension in extensions.iter().rev() {
+            unsafe { (*extension).p_next = p_next };
+            p_next = extension;
+        }
+        Ok(p_next.cast())
+    }
+}
+
+/// Deserializes an extension struct from a map with a single entry.
+struct ExtensionSeed<'a>(&'a mut Arena);
+
+impl<'de> DeserializeSeed<'de> for ExtensionSeed<'_> {
+    type Value = *mut vk::BaseOutStructure<'static>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ExtensionSeed<'_> {
+    type Value = *mut vk::BaseOutStructure<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map from the `s_type` of an extension struct to its members")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let s_type = map
+            .next_key::<vk::StructureType>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let extension = generated::deserialize_extension(s_type, &mut map, self.0)?;
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(2, &self));
+        }
+        Ok(extension)
+    }
+}
+
+/// Deserializes an extension struct found by [`generated::deserialize_extension()`].
+fn deserialize_extension_as<'de, T: Field, A: MapAccess<'de>>(
+    map: &mut A,
+    arena: &mut Arena,
+) -> Result<*mut vk::BaseOutStructure<'static>, A::Error> {
+    let extension = map.next_value_seed(FieldSeed::<T>::new(arena))?;
+    Ok(arena.alloc_value(extension).cast())
+}
@@ -0,0 +1,1851 @@
+use crate::vk;
+#[doc = "Success codes of [`vkAcquireNextImage2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireNextImage2KHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AcquireNextImage2KHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::TIMEOUT`]"]
+    Timeout,
+    #[doc = "[`vk::Result::NOT_READY`]"]
+    NotReady,
+    #[doc = "[`vk::Result::SUBOPTIMAL_KHR`]"]
+    SuboptimalKHR,
+}
+impl TryFrom<vk::Result> for AcquireNextImage2KHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::TIMEOUT => Ok(Self::Timeout),
+            vk::Result::NOT_READY => Ok(Self::NotReady),
+            vk::Result::SUBOPTIMAL_KHR => Ok(Self::SuboptimalKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<AcquireNextImage2KHR> for vk::Result {
+    #[inline]
+    fn from(code: AcquireNextImage2KHR) -> Self {
+        match code {
+            AcquireNextImage2KHR::Success => Self::SUCCESS,
+            AcquireNextImage2KHR::Timeout => Self::TIMEOUT,
+            AcquireNextImage2KHR::NotReady => Self::NOT_READY,
+            AcquireNextImage2KHR::SuboptimalKHR => Self::SUBOPTIMAL_KHR,
+        }
+    }
+}
+#[doc = "Success codes of [`vkAcquireNextImageKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireNextImageKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AcquireNextImageKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::TIMEOUT`]"]
+    Timeout,
+    #[doc = "[`vk::Result::NOT_READY`]"]
+    NotReady,
+    #[doc = "[`vk::Result::SUBOPTIMAL_KHR`]"]
+    SuboptimalKHR,
+}
+impl TryFrom<vk::Result> for AcquireNextImageKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::TIMEOUT => Ok(Self::Timeout),
+            vk::Result::NOT_READY => Ok(Self::NotReady),
+            vk::Result::SUBOPTIMAL_KHR => Ok(Self::SuboptimalKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<AcquireNextImageKHR> for vk::Result {
+    #[inline]
+    fn from(code: AcquireNextImageKHR) -> Self {
+        match code {
+            AcquireNextImageKHR::Success => Self::SUCCESS,
+            AcquireNextImageKHR::Timeout => Self::TIMEOUT,
+            AcquireNextImageKHR::NotReady => Self::NOT_READY,
+            AcquireNextImageKHR::SuboptimalKHR => Self::SUBOPTIMAL_KHR,
+        }
+    }
+}
+#[doc = "Success codes of [`vkBuildAccelerationStructuresKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBuildAccelerationStructuresKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BuildAccelerationStructuresKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::OPERATION_DEFERRED_KHR`]"]
+    OperationDeferredKHR,
+    #[doc = "[`vk::Result::OPERATION_NOT_DEFERRED_KHR`]"]
+    OperationNotDeferredKHR,
+}
+impl TryFrom<vk::Result> for BuildAccelerationStructuresKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::OPERATION_DEFERRED_KHR => Ok(Self::OperationDeferredKHR),
+            vk::Result::OPERATION_NOT_DEFERRED_KHR => Ok(Self::OperationNotDeferredKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<BuildAccelerationStructuresKHR> for vk::Result {
+    #[inline]
+    fn from(code: BuildAccelerationStructuresKHR) -> Self {
+        match code {
+            BuildAccelerationStructuresKHR::Success => Self::SUCCESS,
+            BuildAccelerationStructuresKHR::OperationDeferredKHR => Self::OPERATION_DEFERRED_KHR,
+            BuildAccelerationStructuresKHR::OperationNotDeferredKHR => {
+                Self::OPERATION_NOT_DEFERRED_KHR
+            }
+        }
+    }
+}
+#[doc = "Success codes of [`vkBuildMicromapsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBuildMicromapsEXT.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BuildMicromapsEXT {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::OPERATION_DEFERRED_KHR`]"]
+    OperationDeferredKHR,
+    #[doc = "[`vk::Result::OPERATION_NOT_DEFERRED_KHR`]"]
+    OperationNotDeferredKHR,
+}
+impl TryFrom<vk::Result> for BuildMicromapsEXT {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::OPERATION_DEFERRED_KHR => Ok(Self::OperationDeferredKHR),
+            vk::Result::OPERATION_NOT_DEFERRED_KHR => Ok(Self::OperationNotDeferredKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<BuildMicromapsEXT> for vk::Result {
+    #[inline]
+    fn from(code: BuildMicromapsEXT) -> Self {
+        match code {
+            BuildMicromapsEXT::Success => Self::SUCCESS,
+            BuildMicromapsEXT::OperationDeferredKHR => Self::OPERATION_DEFERRED_KHR,
+            BuildMicromapsEXT::OperationNotDeferredKHR => Self::OPERATION_NOT_DEFERRED_KHR,
+        }
+    }
+}
+#[doc = "Success codes of [`vkCopyAccelerationStructureKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyAccelerationStructureKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CopyAccelerationStructureKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::OPERATION_DEFERRED_KHR`]"]
+    OperationDeferredKHR,
+    #[doc = "[`vk::Result::OPERATION_NOT_DEFERRED_KHR`]"]
+    OperationNotDeferredKHR,
+}
+impl TryFrom<vk::Result> for CopyAccelerationStructureKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::OPERATION_DEFERRED_KHR => Ok(Self::OperationDeferredKHR),
+            vk::Result::OPERATION_NOT_DEFERRED_KHR => Ok(Self::OperationNotDeferredKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<CopyAccelerationStructureKHR> for vk::Result {
+    #[inline]
+    fn from(code: CopyAccelerationStructureKHR) -> Self {
+        match code {
+            CopyAccelerationStructureKHR::Success => Self::SUCCESS,
+            CopyAccelerationStructureKHR::OperationDeferredKHR => Self::OPERATION_DEFERRED_KHR,
+            CopyAccelerationStructureKHR::OperationNotDeferredKHR => {
+                Self::OPERATION_NOT_DEFERRED_KHR
+            }
+        }
+    }
+}
+#[doc = "Success codes of [`vkCopyAccelerationStructureToMemoryKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyAccelerationStructureToMemoryKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CopyAccelerationStructureToMemoryKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::OPERATION_DEFERRED_KHR`]"]
+    OperationDeferredKHR,
+    #[doc = "[`vk::Result::OPERATION_NOT_DEFERRED_KHR`]"]
+    OperationNotDeferredKHR,
+}
+impl TryFrom<vk::Result> for CopyAccelerationStructureToMemoryKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::OPERATION_DEFERRED_KHR => Ok(Self::OperationDeferredKHR),
+            vk::Result::OPERATION_NOT_DEFERRED_KHR => Ok(Self::OperationNotDeferredKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<CopyAccelerationStructureToMemoryKHR> for vk::Result {
+    #[inline]
+    fn from(code: CopyAccelerationStructureToMemoryKHR) -> Self {
+        match code {
+            CopyAccelerationStructureToMemoryKHR::Success => Self::SUCCESS,
+            CopyAccelerationStructureToMemoryKHR::OperationDeferredKHR => {
+                Self::OPERATION_DEFERRED_KHR
+            }
+            CopyAccelerationStructureToMemoryKHR::OperationNotDeferredKHR => {
+                Self::OPERATION_NOT_DEFERRED_KHR
+            }
+        }
+    }
+}
+#[doc = "Success codes of [`vkCopyMemoryToAccelerationStructureKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMemoryToAccelerationStructureKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CopyMemoryToAccelerationStructureKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::OPERATION_DEFERRED_KHR`]"]
+    OperationDeferredKHR,
+    #[doc = "[`vk::Result::OPERATION_NOT_DEFERRED_KHR`]"]
+    OperationNotDeferredKHR,
+}
+impl TryFrom<vk::Result> for CopyMemoryToAccelerationStructureKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::OPERATION_DEFERRED_KHR => Ok(Self::OperationDeferredKHR),
+            vk::Result::OPERATION_NOT_DEFERRED_KHR => Ok(Self::OperationNotDeferredKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<CopyMemoryToAccelerationStructureKHR> for vk::Result {
+    #[inline]
+    fn from(code: CopyMemoryToAccelerationStructureKHR) -> Self {
+        match code {
+            CopyMemoryToAccelerationStructureKHR::Success => Self::SUCCESS,
+            CopyMemoryToAccelerationStructureKHR::OperationDeferredKHR => {
+                Self::OPERATION_DEFERRED_KHR
+            }
+            CopyMemoryToAccelerationStructureKHR::OperationNotDeferredKHR => {
+                Self::OPERATION_NOT_DEFERRED_KHR
+            }
+        }
+    }
+}
+#[doc = "Success codes of [`vkCopyMemoryToMicromapEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMemoryToMicromapEXT.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CopyMemoryToMicromapEXT {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::OPERATION_DEFERRED_KHR`]"]
+    OperationDeferredKHR,
+    #[doc = "[`vk::Result::OPERATION_NOT_DEFERRED_KHR`]"]
+    OperationNotDeferredKHR,
+}
+impl TryFrom<vk::Result> for CopyMemoryToMicromapEXT {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::OPERATION_DEFERRED_KHR => Ok(Self::OperationDeferredKHR),
+            vk::Result::OPERATION_NOT_DEFERRED_KHR => Ok(Self::OperationNotDeferredKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<CopyMemoryToMicromapEXT> for vk::Result {
+    #[inline]
+    fn from(code: CopyMemoryToMicromapEXT) -> Self {
+        match code {
+            CopyMemoryToMicromapEXT::Success => Self::SUCCESS,
+            CopyMemoryToMicromapEXT::OperationDeferredKHR => Self::OPERATION_DEFERRED_KHR,
+            CopyMemoryToMicromapEXT::OperationNotDeferredKHR => Self::OPERATION_NOT_DEFERRED_KHR,
+        }
+    }
+}
+#[doc = "Success codes of [`vkCopyMicromapEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMicromapEXT.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CopyMicromapEXT {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::OPERATION_DEFERRED_KHR`]"]
+    OperationDeferredKHR,
+    #[doc = "[`vk::Result::OPERATION_NOT_DEFERRED_KHR`]"]
+    OperationNotDeferredKHR,
+}
+impl TryFrom<vk::Result> for CopyMicromapEXT {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::OPERATION_DEFERRED_KHR => Ok(Self::OperationDeferredKHR),
+            vk::Result::OPERATION_NOT_DEFERRED_KHR => Ok(Self::OperationNotDeferredKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<CopyMicromapEXT> for vk::Result {
+    #[inline]
+    fn from(code: CopyMicromapEXT) -> Self {
+        match code {
+            CopyMicromapEXT::Success => Self::SUCCESS,
+            CopyMicromapEXT::OperationDeferredKHR => Self::OPERATION_DEFERRED_KHR,
+            CopyMicromapEXT::OperationNotDeferredKHR => Self::OPERATION_NOT_DEFERRED_KHR,
+        }
+    }
+}
+#[doc = "Success codes of [`vkCopyMicromapToMemoryEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCopyMicromapToMemoryEXT.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CopyMicromapToMemoryEXT {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::OPERATION_DEFERRED_KHR`]"]
+    OperationDeferredKHR,
+    #[doc = "[`vk::Result::OPERATION_NOT_DEFERRED_KHR`]"]
+    OperationNotDeferredKHR,
+}
+impl TryFrom<vk::Result> for CopyMicromapToMemoryEXT {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::OPERATION_DEFERRED_KHR => Ok(Self::OperationDeferredKHR),
+            vk::Result::OPERATION_NOT_DEFERRED_KHR => Ok(Self::OperationNotDeferredKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<CopyMicromapToMemoryEXT> for vk::Result {
+    #[inline]
+    fn from(code: CopyMicromapToMemoryEXT) -> Self {
+        match code {
+            CopyMicromapToMemoryEXT::Success => Self::SUCCESS,
+            CopyMicromapToMemoryEXT::OperationDeferredKHR => Self::OPERATION_DEFERRED_KHR,
+            CopyMicromapToMemoryEXT::OperationNotDeferredKHR => Self::OPERATION_NOT_DEFERRED_KHR,
+        }
+    }
+}
+#[doc = "Success codes of [`vkCreateComputePipelines`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateComputePipelines.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CreateComputePipelines {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::PIPELINE_COMPILE_REQUIRED`]"]
+    PipelineCompileRequired,
+}
+impl TryFrom<vk::Result> for CreateComputePipelines {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::PIPELINE_COMPILE_REQUIRED => Ok(Self::PipelineCompileRequired),
+            err => Err(err),
+        }
+    }
+}
+impl From<CreateComputePipelines> for vk::Result {
+    #[inline]
+    fn from(code: CreateComputePipelines) -> Self {
+        match code {
+            CreateComputePipelines::Success => Self::SUCCESS,
+            CreateComputePipelines::PipelineCompileRequired => Self::PIPELINE_COMPILE_REQUIRED,
+        }
+    }
+}
+#[doc = "Success codes of [`vkCreateGraphicsPipelines`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateGraphicsPipelines.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CreateGraphicsPipelines {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::PIPELINE_COMPILE_REQUIRED`]"]
+    PipelineCompileRequired,
+}
+impl TryFrom<vk::Result> for CreateGraphicsPipelines {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::PIPELINE_COMPILE_REQUIRED => Ok(Self::PipelineCompileRequired),
+            err => Err(err),
+        }
+    }
+}
+impl From<CreateGraphicsPipelines> for vk::Result {
+    #[inline]
+    fn from(code: CreateGraphicsPipelines) -> Self {
+        match code {
+            CreateGraphicsPipelines::Success => Self::SUCCESS,
+            CreateGraphicsPipelines::PipelineCompileRequired => Self::PIPELINE_COMPILE_REQUIRED,
+        }
+    }
+}
+#[doc = "Success codes of [`vkCreateRayTracingPipelinesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRayTracingPipelinesKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CreateRayTracingPipelinesKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::OPERATION_DEFERRED_KHR`]"]
+    OperationDeferredKHR,
+    #[doc = "[`vk::Result::OPERATION_NOT_DEFERRED_KHR`]"]
+    OperationNotDeferredKHR,
+    #[doc = "[`vk::Result::PIPELINE_COMPILE_REQUIRED`]"]
+    PipelineCompileRequired,
+}
+impl TryFrom<vk::Result> for CreateRayTracingPipelinesKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::OPERATION_DEFERRED_KHR => Ok(Self::OperationDeferredKHR),
+            vk::Result::OPERATION_NOT_DEFERRED_KHR => Ok(Self::OperationNotDeferredKHR),
+            vk::Result::PIPELINE_COMPILE_REQUIRED => Ok(Self::PipelineCompileRequired),
+            err => Err(err),
+        }
+    }
+}
+impl From<CreateRayTracingPipelinesKHR> for vk::Result {
+    #[inline]
+    fn from(code: CreateRayTracingPipelinesKHR) -> Self {
+        match code {
+            CreateRayTracingPipelinesKHR::Success => Self::SUCCESS,
+            CreateRayTracingPipelinesKHR::OperationDeferredKHR => Self::OPERATION_DEFERRED_KHR,
+            CreateRayTracingPipelinesKHR::OperationNotDeferredKHR => {
+                Self::OPERATION_NOT_DEFERRED_KHR
+            }
+            CreateRayTracingPipelinesKHR::PipelineCompileRequired => {
+                Self::PIPELINE_COMPILE_REQUIRED
+            }
+        }
+    }
+}
+#[doc = "Success codes of [`vkCreateRayTracingPipelinesNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRayTracingPipelinesNV.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CreateRayTracingPipelinesNV {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::PIPELINE_COMPILE_REQUIRED`]"]
+    PipelineCompileRequired,
+}
+impl TryFrom<vk::Result> for CreateRayTracingPipelinesNV {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::PIPELINE_COMPILE_REQUIRED => Ok(Self::PipelineCompileRequired),
+            err => Err(err),
+        }
+    }
+}
+impl From<CreateRayTracingPipelinesNV> for vk::Result {
+    #[inline]
+    fn from(code: CreateRayTracingPipelinesNV) -> Self {
+        match code {
+            CreateRayTracingPipelinesNV::Success => Self::SUCCESS,
+            CreateRayTracingPipelinesNV::PipelineCompileRequired => Self::PIPELINE_COMPILE_REQUIRED,
+        }
+    }
+}
+#[doc = "Success codes of [`vkDeferredOperationJoinKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDeferredOperationJoinKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeferredOperationJoinKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::THREAD_DONE_KHR`]"]
+    ThreadDoneKHR,
+    #[doc = "[`vk::Result::THREAD_IDLE_KHR`]"]
+    ThreadIdleKHR,
+}
+impl TryFrom<vk::Result> for DeferredOperationJoinKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::THREAD_DONE_KHR => Ok(Self::ThreadDoneKHR),
+            vk::Result::THREAD_IDLE_KHR => Ok(Self::ThreadIdleKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<DeferredOperationJoinKHR> for vk::Result {
+    #[inline]
+    fn from(code: DeferredOperationJoinKHR) -> Self {
+        match code {
+            DeferredOperationJoinKHR::Success => Self::SUCCESS,
+            DeferredOperationJoinKHR::ThreadDoneKHR => Self::THREAD_DONE_KHR,
+            DeferredOperationJoinKHR::ThreadIdleKHR => Self::THREAD_IDLE_KHR,
+        }
+    }
+}
+#[doc = "Success codes of [`vkEnumerateDeviceExtensionProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateDeviceExtensionProperties.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EnumerateDeviceExtensionProperties {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for EnumerateDeviceExtensionProperties {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<EnumerateDeviceExtensionProperties> for vk::Result {
+    #[inline]
+    fn from(code: EnumerateDeviceExtensionProperties) -> Self {
+        match code {
+            EnumerateDeviceExtensionProperties::Success => Self::SUCCESS,
+            EnumerateDeviceExtensionProperties::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkEnumerateInstanceExtensionProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceExtensionProperties.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EnumerateInstanceExtensionProperties {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for EnumerateInstanceExtensionProperties {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<EnumerateInstanceExtensionProperties> for vk::Result {
+    #[inline]
+    fn from(code: EnumerateInstanceExtensionProperties) -> Self {
+        match code {
+            EnumerateInstanceExtensionProperties::Success => Self::SUCCESS,
+            EnumerateInstanceExtensionProperties::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkEnumerateInstanceLayerProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceLayerProperties.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EnumerateInstanceLayerProperties {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for EnumerateInstanceLayerProperties {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<EnumerateInstanceLayerProperties> for vk::Result {
+    #[inline]
+    fn from(code: EnumerateInstanceLayerProperties) -> Self {
+        match code {
+            EnumerateInstanceLayerProperties::Success => Self::SUCCESS,
+            EnumerateInstanceLayerProperties::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkEnumeratePhysicalDeviceGroups`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDeviceGroups.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EnumeratePhysicalDeviceGroups {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for EnumeratePhysicalDeviceGroups {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<EnumeratePhysicalDeviceGroups> for vk::Result {
+    #[inline]
+    fn from(code: EnumeratePhysicalDeviceGroups) -> Self {
+        match code {
+            EnumeratePhysicalDeviceGroups::Success => Self::SUCCESS,
+            EnumeratePhysicalDeviceGroups::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR> for vk::Result {
+    #[inline]
+    fn from(code: EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR) -> Self {
+        match code {
+            EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR::Success => Self::SUCCESS,
+            EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR::Incomplete => {
+                Self::INCOMPLETE
+            }
+        }
+    }
+}
+#[doc = "Success codes of [`vkEnumeratePhysicalDevices`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDevices.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EnumeratePhysicalDevices {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for EnumeratePhysicalDevices {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<EnumeratePhysicalDevices> for vk::Result {
+    #[inline]
+    fn from(code: EnumeratePhysicalDevices) -> Self {
+        match code {
+            EnumeratePhysicalDevices::Success => Self::SUCCESS,
+            EnumeratePhysicalDevices::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetDeferredOperationResultKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeferredOperationResultKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetDeferredOperationResultKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::NOT_READY`]"]
+    NotReady,
+}
+impl TryFrom<vk::Result> for GetDeferredOperationResultKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::NOT_READY => Ok(Self::NotReady),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetDeferredOperationResultKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetDeferredOperationResultKHR) -> Self {
+        match code {
+            GetDeferredOperationResultKHR::Success => Self::SUCCESS,
+            GetDeferredOperationResultKHR::NotReady => Self::NOT_READY,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetDeviceFaultInfoEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceFaultInfoEXT.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetDeviceFaultInfoEXT {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetDeviceFaultInfoEXT {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetDeviceFaultInfoEXT> for vk::Result {
+    #[inline]
+    fn from(code: GetDeviceFaultInfoEXT) -> Self {
+        match code {
+            GetDeviceFaultInfoEXT::Success => Self::SUCCESS,
+            GetDeviceFaultInfoEXT::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI> for vk::Result {
+    #[inline]
+    fn from(code: GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI) -> Self {
+        match code {
+            GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI::Success => Self::SUCCESS,
+            GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetDisplayModeProperties2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayModeProperties2KHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetDisplayModeProperties2KHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetDisplayModeProperties2KHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetDisplayModeProperties2KHR> for vk::Result {
+    #[inline]
+    fn from(code: GetDisplayModeProperties2KHR) -> Self {
+        match code {
+            GetDisplayModeProperties2KHR::Success => Self::SUCCESS,
+            GetDisplayModeProperties2KHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetDisplayModePropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayModePropertiesKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetDisplayModePropertiesKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetDisplayModePropertiesKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetDisplayModePropertiesKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetDisplayModePropertiesKHR) -> Self {
+        match code {
+            GetDisplayModePropertiesKHR::Success => Self::SUCCESS,
+            GetDisplayModePropertiesKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetDisplayPlaneSupportedDisplaysKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDisplayPlaneSupportedDisplaysKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetDisplayPlaneSupportedDisplaysKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetDisplayPlaneSupportedDisplaysKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetDisplayPlaneSupportedDisplaysKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetDisplayPlaneSupportedDisplaysKHR) -> Self {
+        match code {
+            GetDisplayPlaneSupportedDisplaysKHR::Success => Self::SUCCESS,
+            GetDisplayPlaneSupportedDisplaysKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetEncodedVideoSessionParametersKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetEncodedVideoSessionParametersKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetEncodedVideoSessionParametersKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetEncodedVideoSessionParametersKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetEncodedVideoSessionParametersKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetEncodedVideoSessionParametersKHR) -> Self {
+        match code {
+            GetEncodedVideoSessionParametersKHR::Success => Self::SUCCESS,
+            GetEncodedVideoSessionParametersKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetEventStatus`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetEventStatus.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetEventStatus {
+    #[doc = "[`vk::Result::EVENT_SET`]"]
+    EventSet,
+    #[doc = "[`vk::Result::EVENT_RESET`]"]
+    EventReset,
+}
+impl TryFrom<vk::Result> for GetEventStatus {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::EVENT_SET => Ok(Self::EventSet),
+            vk::Result::EVENT_RESET => Ok(Self::EventReset),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetEventStatus> for vk::Result {
+    #[inline]
+    fn from(code: GetEventStatus) -> Self {
+        match code {
+            GetEventStatus::EventSet => Self::EVENT_SET,
+            GetEventStatus::EventReset => Self::EVENT_RESET,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetFenceStatus`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFenceStatus.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetFenceStatus {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::NOT_READY`]"]
+    NotReady,
+}
+impl TryFrom<vk::Result> for GetFenceStatus {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::NOT_READY => Ok(Self::NotReady),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetFenceStatus> for vk::Result {
+    #[inline]
+    fn from(code: GetFenceStatus) -> Self {
+        match code {
+            GetFenceStatus::Success => Self::SUCCESS,
+            GetFenceStatus::NotReady => Self::NOT_READY,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetFramebufferTilePropertiesQCOM`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetFramebufferTilePropertiesQCOM.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetFramebufferTilePropertiesQCOM {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetFramebufferTilePropertiesQCOM {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetFramebufferTilePropertiesQCOM> for vk::Result {
+    #[inline]
+    fn from(code: GetFramebufferTilePropertiesQCOM) -> Self {
+        match code {
+            GetFramebufferTilePropertiesQCOM::Success => Self::SUCCESS,
+            GetFramebufferTilePropertiesQCOM::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPastPresentationTimingGOOGLE`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPastPresentationTimingGOOGLE.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPastPresentationTimingGOOGLE {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPastPresentationTimingGOOGLE {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPastPresentationTimingGOOGLE> for vk::Result {
+    #[inline]
+    fn from(code: GetPastPresentationTimingGOOGLE) -> Self {
+        match code {
+            GetPastPresentationTimingGOOGLE::Success => Self::SUCCESS,
+            GetPastPresentationTimingGOOGLE::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceCalibrateableTimeDomainsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceCalibrateableTimeDomainsEXT.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceCalibrateableTimeDomainsEXT {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceCalibrateableTimeDomainsEXT {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceCalibrateableTimeDomainsEXT> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceCalibrateableTimeDomainsEXT) -> Self {
+        match code {
+            GetPhysicalDeviceCalibrateableTimeDomainsEXT::Success => Self::SUCCESS,
+            GetPhysicalDeviceCalibrateableTimeDomainsEXT::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceCooperativeMatrixPropertiesNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceCooperativeMatrixPropertiesNV.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceCooperativeMatrixPropertiesNV {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceCooperativeMatrixPropertiesNV {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceCooperativeMatrixPropertiesNV> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceCooperativeMatrixPropertiesNV) -> Self {
+        match code {
+            GetPhysicalDeviceCooperativeMatrixPropertiesNV::Success => Self::SUCCESS,
+            GetPhysicalDeviceCooperativeMatrixPropertiesNV::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceDisplayPlaneProperties2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayPlaneProperties2KHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceDisplayPlaneProperties2KHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceDisplayPlaneProperties2KHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceDisplayPlaneProperties2KHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceDisplayPlaneProperties2KHR) -> Self {
+        match code {
+            GetPhysicalDeviceDisplayPlaneProperties2KHR::Success => Self::SUCCESS,
+            GetPhysicalDeviceDisplayPlaneProperties2KHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceDisplayPlanePropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayPlanePropertiesKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceDisplayPlanePropertiesKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceDisplayPlanePropertiesKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceDisplayPlanePropertiesKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceDisplayPlanePropertiesKHR) -> Self {
+        match code {
+            GetPhysicalDeviceDisplayPlanePropertiesKHR::Success => Self::SUCCESS,
+            GetPhysicalDeviceDisplayPlanePropertiesKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceDisplayProperties2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayProperties2KHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceDisplayProperties2KHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceDisplayProperties2KHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceDisplayProperties2KHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceDisplayProperties2KHR) -> Self {
+        match code {
+            GetPhysicalDeviceDisplayProperties2KHR::Success => Self::SUCCESS,
+            GetPhysicalDeviceDisplayProperties2KHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceDisplayPropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceDisplayPropertiesKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceDisplayPropertiesKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceDisplayPropertiesKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceDisplayPropertiesKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceDisplayPropertiesKHR) -> Self {
+        match code {
+            GetPhysicalDeviceDisplayPropertiesKHR::Success => Self::SUCCESS,
+            GetPhysicalDeviceDisplayPropertiesKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceFragmentShadingRatesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFragmentShadingRatesKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceFragmentShadingRatesKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceFragmentShadingRatesKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceFragmentShadingRatesKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceFragmentShadingRatesKHR) -> Self {
+        match code {
+            GetPhysicalDeviceFragmentShadingRatesKHR::Success => Self::SUCCESS,
+            GetPhysicalDeviceFragmentShadingRatesKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceOpticalFlowImageFormatsNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceOpticalFlowImageFormatsNV.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceOpticalFlowImageFormatsNV {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceOpticalFlowImageFormatsNV {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceOpticalFlowImageFormatsNV> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceOpticalFlowImageFormatsNV) -> Self {
+        match code {
+            GetPhysicalDeviceOpticalFlowImageFormatsNV::Success => Self::SUCCESS,
+            GetPhysicalDeviceOpticalFlowImageFormatsNV::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDevicePresentRectanglesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDevicePresentRectanglesKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDevicePresentRectanglesKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDevicePresentRectanglesKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDevicePresentRectanglesKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDevicePresentRectanglesKHR) -> Self {
+        match code {
+            GetPhysicalDevicePresentRectanglesKHR::Success => Self::SUCCESS,
+            GetPhysicalDevicePresentRectanglesKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV) -> Self {
+        match code {
+            GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV::Success => {
+                Self::SUCCESS
+            }
+            GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV::Incomplete => {
+                Self::INCOMPLETE
+            }
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceSurfaceFormats2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceFormats2KHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceSurfaceFormats2KHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceSurfaceFormats2KHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceSurfaceFormats2KHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceSurfaceFormats2KHR) -> Self {
+        match code {
+            GetPhysicalDeviceSurfaceFormats2KHR::Success => Self::SUCCESS,
+            GetPhysicalDeviceSurfaceFormats2KHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceSurfaceFormatsKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceFormatsKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceSurfaceFormatsKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceSurfaceFormatsKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceSurfaceFormatsKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceSurfaceFormatsKHR) -> Self {
+        match code {
+            GetPhysicalDeviceSurfaceFormatsKHR::Success => Self::SUCCESS,
+            GetPhysicalDeviceSurfaceFormatsKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceSurfacePresentModes2EXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfacePresentModes2EXT.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceSurfacePresentModes2EXT {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceSurfacePresentModes2EXT {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceSurfacePresentModes2EXT> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceSurfacePresentModes2EXT) -> Self {
+        match code {
+            GetPhysicalDeviceSurfacePresentModes2EXT::Success => Self::SUCCESS,
+            GetPhysicalDeviceSurfacePresentModes2EXT::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceSurfacePresentModesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfacePresentModesKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceSurfacePresentModesKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceSurfacePresentModesKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceSurfacePresentModesKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceSurfacePresentModesKHR) -> Self {
+        match code {
+            GetPhysicalDeviceSurfacePresentModesKHR::Success => Self::SUCCESS,
+            GetPhysicalDeviceSurfacePresentModesKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceToolProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceToolProperties.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceToolProperties {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceToolProperties {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceToolProperties> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceToolProperties) -> Self {
+        match code {
+            GetPhysicalDeviceToolProperties::Success => Self::SUCCESS,
+            GetPhysicalDeviceToolProperties::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPhysicalDeviceVideoFormatPropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceVideoFormatPropertiesKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPhysicalDeviceVideoFormatPropertiesKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPhysicalDeviceVideoFormatPropertiesKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPhysicalDeviceVideoFormatPropertiesKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPhysicalDeviceVideoFormatPropertiesKHR) -> Self {
+        match code {
+            GetPhysicalDeviceVideoFormatPropertiesKHR::Success => Self::SUCCESS,
+            GetPhysicalDeviceVideoFormatPropertiesKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPipelineCacheData`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineCacheData.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPipelineCacheData {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPipelineCacheData {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPipelineCacheData> for vk::Result {
+    #[inline]
+    fn from(code: GetPipelineCacheData) -> Self {
+        match code {
+            GetPipelineCacheData::Success => Self::SUCCESS,
+            GetPipelineCacheData::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPipelineExecutableInternalRepresentationsKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineExecutableInternalRepresentationsKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPipelineExecutableInternalRepresentationsKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPipelineExecutableInternalRepresentationsKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPipelineExecutableInternalRepresentationsKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPipelineExecutableInternalRepresentationsKHR) -> Self {
+        match code {
+            GetPipelineExecutableInternalRepresentationsKHR::Success => Self::SUCCESS,
+            GetPipelineExecutableInternalRepresentationsKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPipelineExecutablePropertiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineExecutablePropertiesKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPipelineExecutablePropertiesKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPipelineExecutablePropertiesKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPipelineExecutablePropertiesKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPipelineExecutablePropertiesKHR) -> Self {
+        match code {
+            GetPipelineExecutablePropertiesKHR::Success => Self::SUCCESS,
+            GetPipelineExecutablePropertiesKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetPipelineExecutableStatisticsKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPipelineExecutableStatisticsKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetPipelineExecutableStatisticsKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetPipelineExecutableStatisticsKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetPipelineExecutableStatisticsKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetPipelineExecutableStatisticsKHR) -> Self {
+        match code {
+            GetPipelineExecutableStatisticsKHR::Success => Self::SUCCESS,
+            GetPipelineExecutableStatisticsKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetQueryPoolResults`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetQueryPoolResults.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetQueryPoolResults {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::NOT_READY`]"]
+    NotReady,
+}
+impl TryFrom<vk::Result> for GetQueryPoolResults {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::NOT_READY => Ok(Self::NotReady),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetQueryPoolResults> for vk::Result {
+    #[inline]
+    fn from(code: GetQueryPoolResults) -> Self {
+        match code {
+            GetQueryPoolResults::Success => Self::SUCCESS,
+            GetQueryPoolResults::NotReady => Self::NOT_READY,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetShaderBinaryDataEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderBinaryDataEXT.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetShaderBinaryDataEXT {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetShaderBinaryDataEXT {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetShaderBinaryDataEXT> for vk::Result {
+    #[inline]
+    fn from(code: GetShaderBinaryDataEXT) -> Self {
+        match code {
+            GetShaderBinaryDataEXT::Success => Self::SUCCESS,
+            GetShaderBinaryDataEXT::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetShaderInfoAMD`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetShaderInfoAMD.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetShaderInfoAMD {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetShaderInfoAMD {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetShaderInfoAMD> for vk::Result {
+    #[inline]
+    fn from(code: GetShaderInfoAMD) -> Self {
+        match code {
+            GetShaderInfoAMD::Success => Self::SUCCESS,
+            GetShaderInfoAMD::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetSwapchainImagesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainImagesKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetSwapchainImagesKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetSwapchainImagesKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetSwapchainImagesKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetSwapchainImagesKHR) -> Self {
+        match code {
+            GetSwapchainImagesKHR::Success => Self::SUCCESS,
+            GetSwapchainImagesKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetSwapchainStatusKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainStatusKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetSwapchainStatusKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::SUBOPTIMAL_KHR`]"]
+    SuboptimalKHR,
+}
+impl TryFrom<vk::Result> for GetSwapchainStatusKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::SUBOPTIMAL_KHR => Ok(Self::SuboptimalKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetSwapchainStatusKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetSwapchainStatusKHR) -> Self {
+        match code {
+            GetSwapchainStatusKHR::Success => Self::SUCCESS,
+            GetSwapchainStatusKHR::SuboptimalKHR => Self::SUBOPTIMAL_KHR,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetValidationCacheDataEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetValidationCacheDataEXT.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetValidationCacheDataEXT {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetValidationCacheDataEXT {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetValidationCacheDataEXT> for vk::Result {
+    #[inline]
+    fn from(code: GetValidationCacheDataEXT) -> Self {
+        match code {
+            GetValidationCacheDataEXT::Success => Self::SUCCESS,
+            GetValidationCacheDataEXT::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkGetVideoSessionMemoryRequirementsKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetVideoSessionMemoryRequirementsKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GetVideoSessionMemoryRequirementsKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::INCOMPLETE`]"]
+    Incomplete,
+}
+impl TryFrom<vk::Result> for GetVideoSessionMemoryRequirementsKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::INCOMPLETE => Ok(Self::Incomplete),
+            err => Err(err),
+        }
+    }
+}
+impl From<GetVideoSessionMemoryRequirementsKHR> for vk::Result {
+    #[inline]
+    fn from(code: GetVideoSessionMemoryRequirementsKHR) -> Self {
+        match code {
+            GetVideoSessionMemoryRequirementsKHR::Success => Self::SUCCESS,
+            GetVideoSessionMemoryRequirementsKHR::Incomplete => Self::INCOMPLETE,
+        }
+    }
+}
+#[doc = "Success codes of [`vkQueuePresentKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueuePresentKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum QueuePresentKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::SUBOPTIMAL_KHR`]"]
+    SuboptimalKHR,
+}
+impl TryFrom<vk::Result> for QueuePresentKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::SUBOPTIMAL_KHR => Ok(Self::SuboptimalKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<QueuePresentKHR> for vk::Result {
+    #[inline]
+    fn from(code: QueuePresentKHR) -> Self {
+        match code {
+            QueuePresentKHR::Success => Self::SUCCESS,
+            QueuePresentKHR::SuboptimalKHR => Self::SUBOPTIMAL_KHR,
+        }
+    }
+}
+#[doc = "Success codes of [`vkWaitForFences`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitForFences.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WaitForFences {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::TIMEOUT`]"]
+    Timeout,
+}
+impl TryFrom<vk::Result> for WaitForFences {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::TIMEOUT => Ok(Self::Timeout),
+            err => Err(err),
+        }
+    }
+}
+impl From<WaitForFences> for vk::Result {
+    #[inline]
+    fn from(code: WaitForFences) -> Self {
+        match code {
+            WaitForFences::Success => Self::SUCCESS,
+            WaitForFences::Timeout => Self::TIMEOUT,
+        }
+    }
+}
+#[doc = "Success codes of [`vkWaitForPresentKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitForPresentKHR.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WaitForPresentKHR {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::TIMEOUT`]"]
+    Timeout,
+    #[doc = "[`vk::Result::SUBOPTIMAL_KHR`]"]
+    SuboptimalKHR,
+}
+impl TryFrom<vk::Result> for WaitForPresentKHR {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::TIMEOUT => Ok(Self::Timeout),
+            vk::Result::SUBOPTIMAL_KHR => Ok(Self::SuboptimalKHR),
+            err => Err(err),
+        }
+    }
+}
+impl From<WaitForPresentKHR> for vk::Result {
+    #[inline]
+    fn from(code: WaitForPresentKHR) -> Self {
+        match code {
+            WaitForPresentKHR::Success => Self::SUCCESS,
+            WaitForPresentKHR::Timeout => Self::TIMEOUT,
+            WaitForPresentKHR::SuboptimalKHR => Self::SUBOPTIMAL_KHR,
+        }
+    }
+}
+#[doc = "Success codes of [`vkWaitSemaphores`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitSemaphores.html)"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WaitSemaphores {
+    #[doc = "[`vk::Result::SUCCESS`]"]
+    Success,
+    #[doc = "[`vk::Result::TIMEOUT`]"]
+    Timeout,
+}
+impl TryFrom<vk::Result> for WaitSemaphores {
+    type Error = vk::Result;
+    #[inline]
+    fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+        match result {
+            vk::Result::SUCCESS => Ok(Self::Success),
+            vk::Result::TIMEOUT => Ok(Self::Timeout),
+            err => Err(err),
+        }
+    }
+}
+impl From<WaitSemaphores> for vk::Result {
+    #[inline]
+    fn from(code: WaitSemaphores) -> Self {
+        match code {
+            WaitSemaphores::Success => Self::SUCCESS,
+            WaitSemaphores::Timeout => Self::TIMEOUT,
+        }
+    }
+}
@@ -0,0 +1,499 @@
+//! Recording of the device-level commands called through [`Device`] and extension wrappers into a
+//! [`Trace`], and replay of such a trace against another [`Device`].
+//!
+//! A [`Recorder`] wraps an [`Instance`], and every [`Device`] loaded through the wrapped instance,
+//! with [`Instance::create_device()`] or an extension wrapper such as `khr::Swapchain::new()`,
+//! calls into recording stubs. Each [`Call`] holds the command name and its arguments as
+//! [`Value`]s: pointed-to arrays, strings and structs are deep-copied, including every extension
+//! struct in their `p_next` chain that is known through [`vk::TaggedStructure`]. Handles and other
+//! values the driver writes back are captured as outputs.
+//!
+//! A [`Replayer`] re-issues the calls of a trace against another [`Device`], rebuilding every
+//! argument and substituting the handles created during the replay for the recorded ones.
+//!
+//! ```no_run
+//! # use ash::{trace::{Recorder, Replayer}, vk, Entry, Instance};
+//! # unsafe fn capture(entry: &Entry, instance: &Instance, physical_device: vk::PhysicalDevice) -> ash::prelude::VkResult<()> {
+//! let recorder = Recorder::new(instance);
+//! let device = recorder
+//!     .instance()
+//!     .create_device(physical_device, &Default::default(), None)?;
+//! device.device_wait_idle()?;
+//!
+//! let mut file = std::fs::File::create("repro.trace").unwrap();
+//! recorder.take_trace().write_to(&mut file).unwrap();
+//! # Ok(()) }
+//! ```
+//!
+//! Some data is not captured:
+//!
+//! * Host memory written through pointers returned by `vkMapMemory()`.
+//! * Allocation callbacks, which are always replayed as null.
+//! * `void` pointers without a known size and function pointers, which are recorded by address,
+//!   and unions and video `StdVideo*` structs, which are copied without following their pointers.
+//!
+//! Recording is global to the process: creating a [`Recorder`] starts a new trace, and devices are
+//! loaded through the `vkGetDeviceProcAddr()` of the instance wrapped by the most recent one. The
+//! stubs forward to the commands loaded for the device, queue or command buffer they are called
+//! on.
+
+use crate::deep_copy::{
+    record_array, record_data, record_data_output, record_output, replay_array, replay_data, Arena,
+    DeepCopyable, Value, ValueError,
+};
+use crate::prelude::dispatch_key;
+use crate::vk::{self, Handle};
+use crate::{Device, Instance};
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::error::Error;
+use std::ffi::{c_void, CStr, CString};
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+#[allow(deprecated)]
+mod generated;
+
+/// Pairs the handles of recorded and replayed outputs.
+fn map_handles(recorded: &Value, replayed: &Value, arena: &mut Arena) {
+    match (recorded, replayed) {
+        (&Value::Handle(_, recorded), &Value::Handle(_, replayed)) => {
+            arena.map_handle(recorded, replayed)
+        }
+        (Value::Array(recorded), Value::Array(replayed))
+        | (Value::Struct(recorded), Value::Struct(replayed)) => {
+            for (recorded, replayed) in recorded.iter().zip(replayed) {
+                map_handles(recorded, replayed, arena);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A recorded device-level command.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Call {
+    /// The name of the command, such as `"vkCreateFence"`. Commands promoted to core are
+    /// recorded under their core name.
+    pub command: String,
+    /// The arguments, in parameter order. Values the driver writes to are recorded as their
+    /// storage, with [`Value::Null`] elements.
+    pub args: Vec<Value>,
+    /// The values written by the driver to the pointer parameters that are not `const`, in
+    /// parameter order.
+    pub outputs: Vec<Value>,
+    /// The return value, or [`Value::Null`] for `void` commands.
+    pub result: Value,
+}
+
+/// A sequence of recorded calls.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trace {
+    /// The calls, in the order they were made.
+    pub calls: Vec<Call>,
+}
+
+const MAGIC: &[u8; 8] = b"ASHTRACE";
+const VERSION: u32 = 1;
+
+impl Trace {
+    /// Writes the trace in a compact binary format, which can be read back with
+    /// [`Trace::read_from()`].
+    pub fn write_to(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        write_len(&mut writer, self.calls.len())?;
+        for call in &self.calls {
+            write_len(&mut writer, call.command.len())?;
+            writer.write_all(call.command.as_bytes())?;
+            for values in [&call.args, &call.outputs] {
+                write_len(&mut writer, values.len())?;
+                for value in values {
+                    write_value(&mut writer, value)?;
+                }
+            }
+            write_value(&mut writer, &call.result)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a trace written by [`Trace::write_to()`].
+    pub fn read_from(mut reader: impl Read) -> io::Result<Self> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC || read_u32(&mut reader)? != VERSION {
+            return Err(invalid_data("Not a trace of a supported version"));
+        }
+        let calls = (0..read_len(&mut reader)?)
+            .map(|_| {
+                let command = String::from_utf8(read_bytes(&mut reader)?)
+                    .map_err(|_| invalid_data("Command name is not UTF-8"))?;
+                let args = read_values(&mut reader)?;
+                let outputs = read_values(&mut reader)?;
+                let result = read_value(&mut reader)?;
+                Ok(Call {
+                    command,
+                    args,
+                    outputs,
+                    result,
+                })
+            })
+            .collect::<io::Result<_>>()?;
+        Ok(Self { calls })
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn write_len(writer: &mut impl Write, len: usize) -> io::Result<()> {
+    writer.write_all(&(len as u64).to_le_bytes())
+}
+
+fn write_value(writer: &mut impl Write, value: &Value) -> io::Result<()> {
+    match value {
+        Value::Null => writer.write_all(&[0]),
+        Value::Int(value) => {
+            writer.write_all(&[1])?;
+            writer.write_all(&value.to_le_bytes())
+        }
+        Value::Float(value) => {
+            writer.write_all(&[2])?;
+            writer.write_all(&value.to_le_bytes())
+        }
+        Value::Handle(ty, raw) => {
+            writer.write_all(&[3])?;
+            writer.write_all(&ty.as_raw().to_le_bytes())?;
+            writer.write_all(&raw.to_le_bytes())
+        }
+        Value::Bytes(bytes) => {
+            writer.write_all(&[4])?;
+            write_len(writer, bytes.len())?;
+            writer.write_all(bytes)
+        }
+        Value::String(string) => {
+            writer.write_all(&[5])?;
+            write_len(writer, string.as_bytes().len())?;
+            writer.write_all(string.as_bytes())
+        }
+        Value::Array(values) | Value::Struct(values) => {
+            writer.write_all(&[if let Value::Array(_) = value { 6 } else { 7 }])?;
+            write_len(writer, values.len())?;
+            values
+                .iter()
+                .try_for_each(|value| write_value(writer, value))
+        }
+    }
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_len(reader: &mut impl Read) -> io::Result<usize> {
+    usize::try_from(read_u64(reader)?).map_err(|_| invalid_data("Length out of range"))
+}
+
+fn read_bytes(reader: &mut impl Read) -> io::Result<Vec<u8>> {
+    let len = read_len(reader)?;
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(bytes)
+}
+
+fn read_values(reader: &mut impl Read) -> io::Result<Vec<Value>> {
+    (0..read_len(reader)?).map(|_| read_value(reader)).collect()
+}
+
+fn read_value(reader: &mut impl Read) -> io::Result<Value> {
+    let mut tag = [0];
+    reader.read_exact(&mut tag)?;
+    Ok(match tag[0] {
+        0 => Value::Null,
+        1 => Value::Int(read_u64(reader)?),
+        2 => Value::Float(f64::from_bits(read_u64(reader)?)),
+        3 => {
+            let ty = vk::ObjectType::from_raw(read_u32(reader)? as i32);
+            Value::Handle(ty, read_u64(reader)?)
+        }
+        4 => Value::Bytes(read_bytes(reader)?),
+        5 => Value::String(
+            CString::new(read_bytes(reader)?)
+                .map_err(|_| invalid_data("String contains a null byte"))?,
+        ),
+        6 => Value::Array(read_values(reader)?),
+        7 => Value::Struct(read_values(reader)?),
+        _ => return Err(invalid_data("Unknown value tag")),
+    })
+}
+
+/// Whether a [`Recorder`] is alive.
+static RECORDING: AtomicBool = AtomicBool::new(false);
+/// The `vkGetDeviceProcAddr` of the instance wrapped by the last [`Recorder`].
+static GET_DEVICE_PROC_ADDR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+/// The calls recorded so far.
+static CALLS: AtomicPtr<Mutex<Vec<Call>>> = AtomicPtr::new(ptr::null_mut());
+
+/// The function pointers that the stubs forward to.
+#[derive(Default)]
+struct Forwarding {
+    /// By dispatch key and command index.
+    next: HashMap<(usize, usize), usize>,
+    /// The pointer loaded last, by command index.
+    last: HashMap<usize, usize>,
+}
+
+static FORWARDING: AtomicPtr<Mutex<Forwarding>> = AtomicPtr::new(ptr::null_mut());
+
+/// Allocates a global on first use, which is never freed, and locks it. It remains usable after
+/// a panic.
+fn lock<T: Default>(global: &'static AtomicPtr<Mutex<T>>) -> MutexGuard<'static, T> {
+    let mut value = global.load(Ordering::Acquire);
+    if value.is_null() {
+        let new = Box::into_raw(Box::default());
+        value = match global.compare_exchange(
+            ptr::null_mut(),
+            new,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => new,
+            Err(existing) => {
+                drop(unsafe { Box::from_raw(new) });
+                existing
+            }
+        };
+    }
+    let value = unsafe { &*value };
+    value
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn calls() -> MutexGuard<'static, Vec<Call>> {
+    lock(&CALLS)
+}
+
+/// The function pointer that the stub of command `index` forwards to for `key`, falling back to
+/// the one loaded last for handles that no pointer was loaded for. Stubs are only returned after
+/// storing a pointer for them, so there is always one.
+fn next(index: usize, key: usize) -> *mut c_void {
+    let forwarding = lock(&FORWARDING);
+    let next = forwarding
+        .next
+        .get(&(key, index))
+        .or_else(|| forwarding.last.get(&index));
+    *next.unwrap() as *mut c_void
+}
+
+fn is_recording() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+/// Called by the stubs after each call. Never panics, as it runs inside `extern "system"`
+/// functions.
+fn push_call(command: &str, args: Vec<Value>, outputs: Vec<Value>, result: Value) {
+    calls().push(Call {
+        command: command.to_owned(),
+        args,
+        outputs,
+        result,
+    });
+}
+
+/// Replaces device-level commands with recording stubs.
+unsafe extern "system" fn get_device_proc_addr(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    let next = GET_DEVICE_PROC_ADDR.load(Ordering::Relaxed);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceProcAddr>(next);
+    let f = next(device, p_name)?;
+    match generated::stub(CStr::from_ptr(p_name).to_bytes()) {
+        Some((index, stub)) => {
+            let key = dispatch_key(device.as_raw());
+            let mut forwarding = lock(&FORWARDING);
+            forwarding.next.insert((key, index), f as usize);
+            forwarding.last.insert(index, f as usize);
+            Some(mem::transmute::<*const c_void, unsafe extern "system" fn()>(stub))
+        }
+        None => Some(f),
+    }
+}
+
+/// Records the device-level commands called through the devices loaded from [`Recorder::instance()`].
+pub struct Recorder {
+    instance: Instance,
+}
+
+impl Recorder {
+    /// Starts a new trace, recording devices loaded through [`Recorder::instance()`].
+    ///
+    /// # Safety
+    ///
+    /// `instance` must remain valid for as long as the devices loaded through
+    /// [`Recorder::instance()`] are used.
+    pub unsafe fn new(instance: &Instance) -> Self {
+        let fp_v1_0 = instance.fp_v1_0();
+        GET_DEVICE_PROC_ADDR.store(
+            fp_v1_0.get_device_proc_addr as *mut c_void,
+            Ordering::Relaxed,
+        );
+        calls().clear();
+        RECORDING.store(true, Ordering::Relaxed);
+
+        let instance = Instance {
+            api_version: instance.api_version(),
+            enabled_extensions: instance.enabled_extensions().to_vec(),
+            ..Instance::from_parts_1_3(
+                instance.handle(),
+                vk::InstanceFnV1_0 {
+                    get_device_proc_addr,
+                    ..fp_v1_0.clone()
+                },
+                instance.fp_v1_1().clone(),
+                instance.fp_v1_3().clone(),
+            )
+        };
+        Self { instance }
+    }
+
+    /// The wrapped instance, whose devices and device-level extension wrappers are recorded.
+    pub fn instance(&self) -> &Instance {
+        &self.instance
+    }
+
+    /// Removes the calls recorded so far.
+    pub fn take_trace(&self) -> Trace {
+        Trace {
+            calls: mem::take(&mut *calls()),
+        }
+    }
+}
+
+impl Drop for Recorder {
+    /// Stops recording, the devices loaded through [`Recorder::instance()`] remain usable.
+    fn drop(&mut self) {
+        RECORDING.store(false, Ordering::Relaxed);
+    }
+}
+
+/// The failure to replay a [`Call`].
+#[derive(Clone, Debug)]
+pub enum ReplayError {
+    /// The command is not a device-level command known to `ash`.
+    UnknownCommand(String),
+    /// The device does not provide the command.
+    MissingCommand(String),
+    /// The arguments do not match the parameters of the command, such as in a truncated or
+    /// edited trace.
+    InvalidArguments(String, ValueError),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCommand(command) => write!(f, "Unknown device command {command}"),
+            Self::MissingCommand(command) => write!(f, "Device does not provide {command}"),
+            Self::InvalidArguments(command, error) => {
+                write!(f, "Invalid arguments to {command}: {error}")
+            }
+        }
+    }
+}
+
+impl Error for ReplayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidArguments(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Rebuilds the arguments of a [`Call`] and passes them to the given command.
+type ReplayFn =
+    unsafe fn(unsafe extern "system" fn(), &Call, &mut Arena) -> Result<Value, ValueError>;
+
+/// The arguments of `call`, which has one for each of the `N` parameters of its command.
+fn args<const N: usize>(call: &Call) -> Result<&[Value; N], ValueError> {
+    call.args.as_slice().try_into().map_err(|_| {
+        ValueError::new(
+            "an argument for every parameter",
+            &Value::Array(call.args.clone()),
+        )
+    })
+}
+
+/// Re-issues recorded calls against a [`Device`].
+pub struct Replayer {
+    get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    device: vk::Device,
+    arena: Arena,
+}
+
+impl Replayer {
+    /// Replays calls against `device`, which is substituted for the recorded device.
+    pub fn new(instance: &Instance, device: &Device) -> Self {
+        Self {
+            get_device_proc_addr: instance.fp_v1_0().get_device_proc_addr,
+            device: device.handle(),
+            arena: Arena::new(),
+        }
+    }
+
+    /// The data of the replayed calls and the mapping from recorded to replayed handles.
+    pub fn arena(&mut self) -> &mut Arena {
+        &mut self.arena
+    }
+
+    /// Replays all calls of `trace`, in order.
+    ///
+    /// # Safety
+    ///
+    /// The calls must be valid for the device, as they were for the recorded one.
+    pub unsafe fn replay(&mut self, trace: &Trace) -> Result<(), ReplayError> {
+        for call in &trace.calls {
+            self.replay_call(call)?;
+        }
+        Ok(())
+    }
+
+    /// Replays a single call, returning its result.
+    ///
+    /// # Safety
+    ///
+    /// The call must be valid for the device, as it was for the recorded one.
+    pub unsafe fn replay_call(&mut self, call: &Call) -> Result<Value, ReplayError> {
+        let (names, replay) = generated::replay_fn(&call.command)
+            .ok_or_else(|| ReplayError::UnknownCommand(call.command.clone()))?;
+        let f = names
+            .iter()
+            .find_map(|name| (self.get_device_proc_addr)(self.device, name.as_ptr().cast()))
+            .ok_or_else(|| ReplayError::MissingCommand(call.command.clone()))?;
+        if let Some(&Value::Handle(vk::ObjectType::DEVICE, raw)) = call.args.first() {
+            if !self.arena.is_mapped(raw) {
+                self.arena.map_handle(raw, self.device.as_raw());
+            }
+        }
+        replay(f, call, &mut self.arena)
+            .map_err(|error| ReplayError::InvalidArguments(call.command.clone(), error))
+    }
+}
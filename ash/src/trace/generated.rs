@@ -0,0 +1,25898 @@
+use super::*;
+use std::os::raw::c_int;
+#[doc = r" Returns the index of a device-level command in the forwarding tables and its recording"]
+#[doc = r" stub."]
+pub(super) fn stub(name: &[u8]) -> Option<(usize, *const c_void)> {
+    Some(match name {
+        b"vkAcquireFullScreenExclusiveModeEXT" => (
+            0usize,
+            acquire_full_screen_exclusive_mode_ext as *const c_void,
+        ),
+        b"vkAcquireImageANDROID" => (1usize, acquire_image_android as *const c_void),
+        b"vkAcquireNextImage2KHR" => (2usize, acquire_next_image2_khr as *const c_void),
+        b"vkAcquireNextImageKHR" => (3usize, acquire_next_image_khr as *const c_void),
+        b"vkAcquirePerformanceConfigurationINTEL" => (
+            4usize,
+            acquire_performance_configuration_intel as *const c_void,
+        ),
+        b"vkAcquireProfilingLockKHR" => (5usize, acquire_profiling_lock_khr as *const c_void),
+        b"vkAllocateCommandBuffers" => (6usize, allocate_command_buffers as *const c_void),
+        b"vkAllocateDescriptorSets" => (7usize, allocate_descriptor_sets as *const c_void),
+        b"vkAllocateMemory" => (8usize, allocate_memory as *const c_void),
+        b"vkBeginCommandBuffer" => (9usize, begin_command_buffer as *const c_void),
+        b"vkBindAccelerationStructureMemoryNV" => (
+            10usize,
+            bind_acceleration_structure_memory_nv as *const c_void,
+        ),
+        b"vkBindBufferMemory" => (11usize, bind_buffer_memory as *const c_void),
+        b"vkBindBufferMemory2" => (12usize, bind_buffer_memory2 as *const c_void),
+        b"vkBindBufferMemory2KHR" => (12usize, bind_buffer_memory2 as *const c_void),
+        b"vkBindImageMemory" => (13usize, bind_image_memory as *const c_void),
+        b"vkBindImageMemory2" => (14usize, bind_image_memory2 as *const c_void),
+        b"vkBindImageMemory2KHR" => (14usize, bind_image_memory2 as *const c_void),
+        b"vkBindOpticalFlowSessionImageNV" => {
+            (15usize, bind_optical_flow_session_image_nv as *const c_void)
+        }
+        b"vkBindVideoSessionMemoryKHR" => (16usize, bind_video_session_memory_khr as *const c_void),
+        b"vkBuildAccelerationStructuresKHR" => {
+            (17usize, build_acceleration_structures_khr as *const c_void)
+        }
+        b"vkBuildMicromapsEXT" => (18usize, build_micromaps_ext as *const c_void),
+        b"vkCmdBeginConditionalRenderingEXT" => (
+            19usize,
+            cmd_begin_conditional_rendering_ext as *const c_void,
+        ),
+        b"vkCmdBeginDebugUtilsLabelEXT" => {
+            (20usize, cmd_begin_debug_utils_label_ext as *const c_void)
+        }
+        b"vkCmdBeginQuery" => (21usize, cmd_begin_query as *const c_void),
+        b"vkCmdBeginQueryIndexedEXT" => (22usize, cmd_begin_query_indexed_ext as *const c_void),
+        b"vkCmdBeginRenderPass" => (23usize, cmd_begin_render_pass as *const c_void),
+        b"vkCmdBeginRenderPass2" => (24usize, cmd_begin_render_pass2 as *const c_void),
+        b"vkCmdBeginRenderPass2KHR" => (24usize, cmd_begin_render_pass2 as *const c_void),
+        b"vkCmdBeginRendering" => (25usize, cmd_begin_rendering as *const c_void),
+        b"vkCmdBeginRenderingKHR" => (25usize, cmd_begin_rendering as *const c_void),
+        b"vkCmdBeginTransformFeedbackEXT" => {
+            (26usize, cmd_begin_transform_feedback_ext as *const c_void)
+        }
+        b"vkCmdBeginVideoCodingKHR" => (27usize, cmd_begin_video_coding_khr as *const c_void),
+        b"vkCmdBindDescriptorBufferEmbeddedSamplersEXT" => (
+            28usize,
+            cmd_bind_descriptor_buffer_embedded_samplers_ext as *const c_void,
+        ),
+        b"vkCmdBindDescriptorBuffersEXT" => {
+            (29usize, cmd_bind_descriptor_buffers_ext as *const c_void)
+        }
+        b"vkCmdBindDescriptorSets" => (30usize, cmd_bind_descriptor_sets as *const c_void),
+        b"vkCmdBindIndexBuffer" => (31usize, cmd_bind_index_buffer as *const c_void),
+        b"vkCmdBindInvocationMaskHUAWEI" => {
+            (32usize, cmd_bind_invocation_mask_huawei as *const c_void)
+        }
+        b"vkCmdBindPipeline" => (33usize, cmd_bind_pipeline as *const c_void),
+        b"vkCmdBindPipelineShaderGroupNV" => {
+            (34usize, cmd_bind_pipeline_shader_group_nv as *const c_void)
+        }
+        b"vkCmdBindShadersEXT" => (35usize, cmd_bind_shaders_ext as *const c_void),
+        b"vkCmdBindShadingRateImageNV" => {
+            (36usize, cmd_bind_shading_rate_image_nv as *const c_void)
+        }
+        b"vkCmdBindTransformFeedbackBuffersEXT" => (
+            37usize,
+            cmd_bind_transform_feedback_buffers_ext as *const c_void,
+        ),
+        b"vkCmdBindVertexBuffers" => (38usize, cmd_bind_vertex_buffers as *const c_void),
+        b"vkCmdBindVertexBuffers2" => (39usize, cmd_bind_vertex_buffers2 as *const c_void),
+        b"vkCmdBindVertexBuffers2EXT" => (39usize, cmd_bind_vertex_buffers2 as *const c_void),
+        b"vkCmdBlitImage" => (40usize, cmd_blit_image as *const c_void),
+        b"vkCmdBlitImage2" => (41usize, cmd_blit_image2 as *const c_void),
+        b"vkCmdBlitImage2KHR" => (41usize, cmd_blit_image2 as *const c_void),
+        b"vkCmdBuildAccelerationStructureNV" => (
+            42usize,
+            cmd_build_acceleration_structure_nv as *const c_void,
+        ),
+        b"vkCmdBuildAccelerationStructuresIndirectKHR" => (
+            43usize,
+            cmd_build_acceleration_structures_indirect_khr as *const c_void,
+        ),
+        b"vkCmdBuildAccelerationStructuresKHR" => (
+            44usize,
+            cmd_build_acceleration_structures_khr as *const c_void,
+        ),
+        b"vkCmdBuildMicromapsEXT" => (45usize, cmd_build_micromaps_ext as *const c_void),
+        b"vkCmdClearAttachments" => (46usize, cmd_clear_attachments as *const c_void),
+        b"vkCmdClearColorImage" => (47usize, cmd_clear_color_image as *const c_void),
+        b"vkCmdClearDepthStencilImage" => (48usize, cmd_clear_depth_stencil_image as *const c_void),
+        b"vkCmdControlVideoCodingKHR" => (49usize, cmd_control_video_coding_khr as *const c_void),
+        b"vkCmdCopyAccelerationStructureKHR" => (
+            50usize,
+            cmd_copy_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCmdCopyAccelerationStructureNV" => {
+            (51usize, cmd_copy_acceleration_structure_nv as *const c_void)
+        }
+        b"vkCmdCopyAccelerationStructureToMemoryKHR" => (
+            52usize,
+            cmd_copy_acceleration_structure_to_memory_khr as *const c_void,
+        ),
+        b"vkCmdCopyBuffer" => (53usize, cmd_copy_buffer as *const c_void),
+        b"vkCmdCopyBuffer2" => (54usize, cmd_copy_buffer2 as *const c_void),
+        b"vkCmdCopyBuffer2KHR" => (54usize, cmd_copy_buffer2 as *const c_void),
+        b"vkCmdCopyBufferToImage" => (55usize, cmd_copy_buffer_to_image as *const c_void),
+        b"vkCmdCopyBufferToImage2" => (56usize, cmd_copy_buffer_to_image2 as *const c_void),
+        b"vkCmdCopyBufferToImage2KHR" => (56usize, cmd_copy_buffer_to_image2 as *const c_void),
+        b"vkCmdCopyImage" => (57usize, cmd_copy_image as *const c_void),
+        b"vkCmdCopyImage2" => (58usize, cmd_copy_image2 as *const c_void),
+        b"vkCmdCopyImage2KHR" => (58usize, cmd_copy_image2 as *const c_void),
+        b"vkCmdCopyImageToBuffer" => (59usize, cmd_copy_image_to_buffer as *const c_void),
+        b"vkCmdCopyImageToBuffer2" => (60usize, cmd_copy_image_to_buffer2 as *const c_void),
+        b"vkCmdCopyImageToBuffer2KHR" => (60usize, cmd_copy_image_to_buffer2 as *const c_void),
+        b"vkCmdCopyMemoryIndirectNV" => (61usize, cmd_copy_memory_indirect_nv as *const c_void),
+        b"vkCmdCopyMemoryToAccelerationStructureKHR" => (
+            62usize,
+            cmd_copy_memory_to_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCmdCopyMemoryToImageIndirectNV" => (
+            63usize,
+            cmd_copy_memory_to_image_indirect_nv as *const c_void,
+        ),
+        b"vkCmdCopyMemoryToMicromapEXT" => {
+            (64usize, cmd_copy_memory_to_micromap_ext as *const c_void)
+        }
+        b"vkCmdCopyMicromapEXT" => (65usize, cmd_copy_micromap_ext as *const c_void),
+        b"vkCmdCopyMicromapToMemoryEXT" => {
+            (66usize, cmd_copy_micromap_to_memory_ext as *const c_void)
+        }
+        b"vkCmdCopyQueryPoolResults" => (67usize, cmd_copy_query_pool_results as *const c_void),
+        b"vkCmdCuLaunchKernelNVX" => (68usize, cmd_cu_launch_kernel_nvx as *const c_void),
+        b"vkCmdDebugMarkerBeginEXT" => (69usize, cmd_debug_marker_begin_ext as *const c_void),
+        b"vkCmdDebugMarkerEndEXT" => (70usize, cmd_debug_marker_end_ext as *const c_void),
+        b"vkCmdDebugMarkerInsertEXT" => (71usize, cmd_debug_marker_insert_ext as *const c_void),
+        b"vkCmdDecodeVideoKHR" => (72usize, cmd_decode_video_khr as *const c_void),
+        b"vkCmdDecompressMemoryIndirectCountNV" => (
+            73usize,
+            cmd_decompress_memory_indirect_count_nv as *const c_void,
+        ),
+        b"vkCmdDecompressMemoryNV" => (74usize, cmd_decompress_memory_nv as *const c_void),
+        b"vkCmdDispatch" => (75usize, cmd_dispatch as *const c_void),
+        b"vkCmdDispatchBase" => (76usize, cmd_dispatch_base as *const c_void),
+        b"vkCmdDispatchBaseKHR" => (76usize, cmd_dispatch_base as *const c_void),
+        b"vkCmdDispatchIndirect" => (77usize, cmd_dispatch_indirect as *const c_void),
+        b"vkCmdDraw" => (78usize, cmd_draw as *const c_void),
+        b"vkCmdDrawClusterHUAWEI" => (79usize, cmd_draw_cluster_huawei as *const c_void),
+        b"vkCmdDrawClusterIndirectHUAWEI" => {
+            (80usize, cmd_draw_cluster_indirect_huawei as *const c_void)
+        }
+        b"vkCmdDrawIndexed" => (81usize, cmd_draw_indexed as *const c_void),
+        b"vkCmdDrawIndexedIndirect" => (82usize, cmd_draw_indexed_indirect as *const c_void),
+        b"vkCmdDrawIndexedIndirectCount" => {
+            (83usize, cmd_draw_indexed_indirect_count as *const c_void)
+        }
+        b"vkCmdDrawIndexedIndirectCountAMD" => {
+            (83usize, cmd_draw_indexed_indirect_count as *const c_void)
+        }
+        b"vkCmdDrawIndexedIndirectCountKHR" => {
+            (83usize, cmd_draw_indexed_indirect_count as *const c_void)
+        }
+        b"vkCmdDrawIndirect" => (84usize, cmd_draw_indirect as *const c_void),
+        b"vkCmdDrawIndirectByteCountEXT" => {
+            (85usize, cmd_draw_indirect_byte_count_ext as *const c_void)
+        }
+        b"vkCmdDrawIndirectCount" => (86usize, cmd_draw_indirect_count as *const c_void),
+        b"vkCmdDrawIndirectCountAMD" => (86usize, cmd_draw_indirect_count as *const c_void),
+        b"vkCmdDrawIndirectCountKHR" => (86usize, cmd_draw_indirect_count as *const c_void),
+        b"vkCmdDrawMeshTasksEXT" => (87usize, cmd_draw_mesh_tasks_ext as *const c_void),
+        b"vkCmdDrawMeshTasksIndirectCountEXT" => (
+            88usize,
+            cmd_draw_mesh_tasks_indirect_count_ext as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksIndirectCountNV" => (
+            89usize,
+            cmd_draw_mesh_tasks_indirect_count_nv as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksIndirectEXT" => {
+            (90usize, cmd_draw_mesh_tasks_indirect_ext as *const c_void)
+        }
+        b"vkCmdDrawMeshTasksIndirectNV" => {
+            (91usize, cmd_draw_mesh_tasks_indirect_nv as *const c_void)
+        }
+        b"vkCmdDrawMeshTasksNV" => (92usize, cmd_draw_mesh_tasks_nv as *const c_void),
+        b"vkCmdDrawMultiEXT" => (93usize, cmd_draw_multi_ext as *const c_void),
+        b"vkCmdDrawMultiIndexedEXT" => (94usize, cmd_draw_multi_indexed_ext as *const c_void),
+        b"vkCmdEncodeVideoKHR" => (95usize, cmd_encode_video_khr as *const c_void),
+        b"vkCmdEndConditionalRenderingEXT" => {
+            (96usize, cmd_end_conditional_rendering_ext as *const c_void)
+        }
+        b"vkCmdEndDebugUtilsLabelEXT" => (97usize, cmd_end_debug_utils_label_ext as *const c_void),
+        b"vkCmdEndQuery" => (98usize, cmd_end_query as *const c_void),
+        b"vkCmdEndQueryIndexedEXT" => (99usize, cmd_end_query_indexed_ext as *const c_void),
+        b"vkCmdEndRenderPass" => (100usize, cmd_end_render_pass as *const c_void),
+        b"vkCmdEndRenderPass2" => (101usize, cmd_end_render_pass2 as *const c_void),
+        b"vkCmdEndRenderPass2KHR" => (101usize, cmd_end_render_pass2 as *const c_void),
+        b"vkCmdEndRendering" => (102usize, cmd_end_rendering as *const c_void),
+        b"vkCmdEndRenderingKHR" => (102usize, cmd_end_rendering as *const c_void),
+        b"vkCmdEndTransformFeedbackEXT" => {
+            (103usize, cmd_end_transform_feedback_ext as *const c_void)
+        }
+        b"vkCmdEndVideoCodingKHR" => (104usize, cmd_end_video_coding_khr as *const c_void),
+        b"vkCmdExecuteCommands" => (105usize, cmd_execute_commands as *const c_void),
+        b"vkCmdExecuteGeneratedCommandsNV" => {
+            (106usize, cmd_execute_generated_commands_nv as *const c_void)
+        }
+        b"vkCmdFillBuffer" => (107usize, cmd_fill_buffer as *const c_void),
+        b"vkCmdInsertDebugUtilsLabelEXT" => {
+            (108usize, cmd_insert_debug_utils_label_ext as *const c_void)
+        }
+        b"vkCmdNextSubpass" => (109usize, cmd_next_subpass as *const c_void),
+        b"vkCmdNextSubpass2" => (110usize, cmd_next_subpass2 as *const c_void),
+        b"vkCmdNextSubpass2KHR" => (110usize, cmd_next_subpass2 as *const c_void),
+        b"vkCmdOpticalFlowExecuteNV" => (111usize, cmd_optical_flow_execute_nv as *const c_void),
+        b"vkCmdPipelineBarrier" => (112usize, cmd_pipeline_barrier as *const c_void),
+        b"vkCmdPipelineBarrier2" => (113usize, cmd_pipeline_barrier2 as *const c_void),
+        b"vkCmdPipelineBarrier2KHR" => (113usize, cmd_pipeline_barrier2 as *const c_void),
+        b"vkCmdPreprocessGeneratedCommandsNV" => (
+            114usize,
+            cmd_preprocess_generated_commands_nv as *const c_void,
+        ),
+        b"vkCmdPushConstants" => (115usize, cmd_push_constants as *const c_void),
+        b"vkCmdPushDescriptorSetKHR" => (116usize, cmd_push_descriptor_set_khr as *const c_void),
+        b"vkCmdPushDescriptorSetWithTemplateKHR" => (
+            117usize,
+            cmd_push_descriptor_set_with_template_khr as *const c_void,
+        ),
+        b"vkCmdResetEvent" => (118usize, cmd_reset_event as *const c_void),
+        b"vkCmdResetEvent2" => (119usize, cmd_reset_event2 as *const c_void),
+        b"vkCmdResetEvent2KHR" => (119usize, cmd_reset_event2 as *const c_void),
+        b"vkCmdResetQueryPool" => (120usize, cmd_reset_query_pool as *const c_void),
+        b"vkCmdResolveImage" => (121usize, cmd_resolve_image as *const c_void),
+        b"vkCmdResolveImage2" => (122usize, cmd_resolve_image2 as *const c_void),
+        b"vkCmdResolveImage2KHR" => (122usize, cmd_resolve_image2 as *const c_void),
+        b"vkCmdSetAlphaToCoverageEnableEXT" => (
+            123usize,
+            cmd_set_alpha_to_coverage_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetAlphaToOneEnableEXT" => {
+            (124usize, cmd_set_alpha_to_one_enable_ext as *const c_void)
+        }
+        b"vkCmdSetAttachmentFeedbackLoopEnableEXT" => (
+            125usize,
+            cmd_set_attachment_feedback_loop_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetBlendConstants" => (126usize, cmd_set_blend_constants as *const c_void),
+        b"vkCmdSetCheckpointNV" => (127usize, cmd_set_checkpoint_nv as *const c_void),
+        b"vkCmdSetCoarseSampleOrderNV" => {
+            (128usize, cmd_set_coarse_sample_order_nv as *const c_void)
+        }
+        b"vkCmdSetColorBlendAdvancedEXT" => {
+            (129usize, cmd_set_color_blend_advanced_ext as *const c_void)
+        }
+        b"vkCmdSetColorBlendEnableEXT" => {
+            (130usize, cmd_set_color_blend_enable_ext as *const c_void)
+        }
+        b"vkCmdSetColorBlendEquationEXT" => {
+            (131usize, cmd_set_color_blend_equation_ext as *const c_void)
+        }
+        b"vkCmdSetColorWriteEnableEXT" => {
+            (132usize, cmd_set_color_write_enable_ext as *const c_void)
+        }
+        b"vkCmdSetColorWriteMaskEXT" => (133usize, cmd_set_color_write_mask_ext as *const c_void),
+        b"vkCmdSetConservativeRasterizationModeEXT" => (
+            134usize,
+            cmd_set_conservative_rasterization_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetCoverageModulationModeNV" => (
+            135usize,
+            cmd_set_coverage_modulation_mode_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageModulationTableEnableNV" => (
+            136usize,
+            cmd_set_coverage_modulation_table_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageModulationTableNV" => (
+            137usize,
+            cmd_set_coverage_modulation_table_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageReductionModeNV" => (
+            138usize,
+            cmd_set_coverage_reduction_mode_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageToColorEnableNV" => (
+            139usize,
+            cmd_set_coverage_to_color_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageToColorLocationNV" => (
+            140usize,
+            cmd_set_coverage_to_color_location_nv as *const c_void,
+        ),
+        b"vkCmdSetCullMode" => (141usize, cmd_set_cull_mode as *const c_void),
+        b"vkCmdSetCullModeEXT" => (141usize, cmd_set_cull_mode as *const c_void),
+        b"vkCmdSetDepthBias" => (142usize, cmd_set_depth_bias as *const c_void),
+        b"vkCmdSetDepthBias2EXT" => (143usize, cmd_set_depth_bias2_ext as *const c_void),
+        b"vkCmdSetDepthBiasEnable" => (144usize, cmd_set_depth_bias_enable as *const c_void),
+        b"vkCmdSetDepthBiasEnableEXT" => (144usize, cmd_set_depth_bias_enable as *const c_void),
+        b"vkCmdSetDepthBounds" => (145usize, cmd_set_depth_bounds as *const c_void),
+        b"vkCmdSetDepthBoundsTestEnable" => {
+            (146usize, cmd_set_depth_bounds_test_enable as *const c_void)
+        }
+        b"vkCmdSetDepthBoundsTestEnableEXT" => {
+            (146usize, cmd_set_depth_bounds_test_enable as *const c_void)
+        }
+        b"vkCmdSetDepthClampEnableEXT" => {
+            (147usize, cmd_set_depth_clamp_enable_ext as *const c_void)
+        }
+        b"vkCmdSetDepthClipEnableEXT" => (148usize, cmd_set_depth_clip_enable_ext as *const c_void),
+        b"vkCmdSetDepthClipNegativeOneToOneEXT" => (
+            149usize,
+            cmd_set_depth_clip_negative_one_to_one_ext as *const c_void,
+        ),
+        b"vkCmdSetDepthCompareOp" => (150usize, cmd_set_depth_compare_op as *const c_void),
+        b"vkCmdSetDepthCompareOpEXT" => (150usize, cmd_set_depth_compare_op as *const c_void),
+        b"vkCmdSetDepthTestEnable" => (151usize, cmd_set_depth_test_enable as *const c_void),
+        b"vkCmdSetDepthTestEnableEXT" => (151usize, cmd_set_depth_test_enable as *const c_void),
+        b"vkCmdSetDepthWriteEnable" => (152usize, cmd_set_depth_write_enable as *const c_void),
+        b"vkCmdSetDepthWriteEnableEXT" => (152usize, cmd_set_depth_write_enable as *const c_void),
+        b"vkCmdSetDescriptorBufferOffsetsEXT" => (
+            153usize,
+            cmd_set_descriptor_buffer_offsets_ext as *const c_void,
+        ),
+        b"vkCmdSetDeviceMask" => (154usize, cmd_set_device_mask as *const c_void),
+        b"vkCmdSetDeviceMaskKHR" => (154usize, cmd_set_device_mask as *const c_void),
+        b"vkCmdSetDiscardRectangleEXT" => {
+            (155usize, cmd_set_discard_rectangle_ext as *const c_void)
+        }
+        b"vkCmdSetDiscardRectangleEnableEXT" => (
+            156usize,
+            cmd_set_discard_rectangle_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetDiscardRectangleModeEXT" => (
+            157usize,
+            cmd_set_discard_rectangle_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetEvent" => (158usize, cmd_set_event as *const c_void),
+        b"vkCmdSetEvent2" => (159usize, cmd_set_event2 as *const c_void),
+        b"vkCmdSetEvent2KHR" => (159usize, cmd_set_event2 as *const c_void),
+        b"vkCmdSetExclusiveScissorEnableNV" => (
+            160usize,
+            cmd_set_exclusive_scissor_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetExclusiveScissorNV" => (161usize, cmd_set_exclusive_scissor_nv as *const c_void),
+        b"vkCmdSetExtraPrimitiveOverestimationSizeEXT" => (
+            162usize,
+            cmd_set_extra_primitive_overestimation_size_ext as *const c_void,
+        ),
+        b"vkCmdSetFragmentShadingRateEnumNV" => (
+            163usize,
+            cmd_set_fragment_shading_rate_enum_nv as *const c_void,
+        ),
+        b"vkCmdSetFragmentShadingRateKHR" => {
+            (164usize, cmd_set_fragment_shading_rate_khr as *const c_void)
+        }
+        b"vkCmdSetFrontFace" => (165usize, cmd_set_front_face as *const c_void),
+        b"vkCmdSetFrontFaceEXT" => (165usize, cmd_set_front_face as *const c_void),
+        b"vkCmdSetLineRasterizationModeEXT" => (
+            166usize,
+            cmd_set_line_rasterization_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetLineStippleEXT" => (167usize, cmd_set_line_stipple_ext as *const c_void),
+        b"vkCmdSetLineStippleEnableEXT" => {
+            (168usize, cmd_set_line_stipple_enable_ext as *const c_void)
+        }
+        b"vkCmdSetLineWidth" => (169usize, cmd_set_line_width as *const c_void),
+        b"vkCmdSetLogicOpEXT" => (170usize, cmd_set_logic_op_ext as *const c_void),
+        b"vkCmdSetLogicOpEnableEXT" => (171usize, cmd_set_logic_op_enable_ext as *const c_void),
+        b"vkCmdSetPatchControlPointsEXT" => {
+            (172usize, cmd_set_patch_control_points_ext as *const c_void)
+        }
+        b"vkCmdSetPerformanceMarkerINTEL" => {
+            (173usize, cmd_set_performance_marker_intel as *const c_void)
+        }
+        b"vkCmdSetPerformanceOverrideINTEL" => (
+            174usize,
+            cmd_set_performance_override_intel as *const c_void,
+        ),
+        b"vkCmdSetPerformanceStreamMarkerINTEL" => (
+            175usize,
+            cmd_set_performance_stream_marker_intel as *const c_void,
+        ),
+        b"vkCmdSetPolygonModeEXT" => (176usize, cmd_set_polygon_mode_ext as *const c_void),
+        b"vkCmdSetPrimitiveRestartEnable" => {
+            (177usize, cmd_set_primitive_restart_enable as *const c_void)
+        }
+        b"vkCmdSetPrimitiveRestartEnableEXT" => {
+            (177usize, cmd_set_primitive_restart_enable as *const c_void)
+        }
+        b"vkCmdSetPrimitiveTopology" => (178usize, cmd_set_primitive_topology as *const c_void),
+        b"vkCmdSetPrimitiveTopologyEXT" => (178usize, cmd_set_primitive_topology as *const c_void),
+        b"vkCmdSetProvokingVertexModeEXT" => {
+            (179usize, cmd_set_provoking_vertex_mode_ext as *const c_void)
+        }
+        b"vkCmdSetRasterizationSamplesEXT" => {
+            (180usize, cmd_set_rasterization_samples_ext as *const c_void)
+        }
+        b"vkCmdSetRasterizationStreamEXT" => {
+            (181usize, cmd_set_rasterization_stream_ext as *const c_void)
+        }
+        b"vkCmdSetRasterizerDiscardEnable" => {
+            (182usize, cmd_set_rasterizer_discard_enable as *const c_void)
+        }
+        b"vkCmdSetRasterizerDiscardEnableEXT" => {
+            (182usize, cmd_set_rasterizer_discard_enable as *const c_void)
+        }
+        b"vkCmdSetRayTracingPipelineStackSizeKHR" => (
+            183usize,
+            cmd_set_ray_tracing_pipeline_stack_size_khr as *const c_void,
+        ),
+        b"vkCmdSetRepresentativeFragmentTestEnableNV" => (
+            184usize,
+            cmd_set_representative_fragment_test_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetSampleLocationsEXT" => (185usize, cmd_set_sample_locations_ext as *const c_void),
+        b"vkCmdSetSampleLocationsEnableEXT" => (
+            186usize,
+            cmd_set_sample_locations_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetSampleMaskEXT" => (187usize, cmd_set_sample_mask_ext as *const c_void),
+        b"vkCmdSetScissor" => (188usize, cmd_set_scissor as *const c_void),
+        b"vkCmdSetScissorWithCount" => (189usize, cmd_set_scissor_with_count as *const c_void),
+        b"vkCmdSetScissorWithCountEXT" => (189usize, cmd_set_scissor_with_count as *const c_void),
+        b"vkCmdSetShadingRateImageEnableNV" => (
+            190usize,
+            cmd_set_shading_rate_image_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetStencilCompareMask" => (191usize, cmd_set_stencil_compare_mask as *const c_void),
+        b"vkCmdSetStencilOp" => (192usize, cmd_set_stencil_op as *const c_void),
+        b"vkCmdSetStencilOpEXT" => (192usize, cmd_set_stencil_op as *const c_void),
+        b"vkCmdSetStencilReference" => (193usize, cmd_set_stencil_reference as *const c_void),
+        b"vkCmdSetStencilTestEnable" => (194usize, cmd_set_stencil_test_enable as *const c_void),
+        b"vkCmdSetStencilTestEnableEXT" => (194usize, cmd_set_stencil_test_enable as *const c_void),
+        b"vkCmdSetStencilWriteMask" => (195usize, cmd_set_stencil_write_mask as *const c_void),
+        b"vkCmdSetTessellationDomainOriginEXT" => (
+            196usize,
+            cmd_set_tessellation_domain_origin_ext as *const c_void,
+        ),
+        b"vkCmdSetVertexInputEXT" => (197usize, cmd_set_vertex_input_ext as *const c_void),
+        b"vkCmdSetViewport" => (198usize, cmd_set_viewport as *const c_void),
+        b"vkCmdSetViewportShadingRatePaletteNV" => (
+            199usize,
+            cmd_set_viewport_shading_rate_palette_nv as *const c_void,
+        ),
+        b"vkCmdSetViewportSwizzleNV" => (200usize, cmd_set_viewport_swizzle_nv as *const c_void),
+        b"vkCmdSetViewportWScalingEnableNV" => (
+            201usize,
+            cmd_set_viewport_w_scaling_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetViewportWScalingNV" => (202usize, cmd_set_viewport_w_scaling_nv as *const c_void),
+        b"vkCmdSetViewportWithCount" => (203usize, cmd_set_viewport_with_count as *const c_void),
+        b"vkCmdSetViewportWithCountEXT" => (203usize, cmd_set_viewport_with_count as *const c_void),
+        b"vkCmdSubpassShadingHUAWEI" => (204usize, cmd_subpass_shading_huawei as *const c_void),
+        b"vkCmdTraceRaysIndirect2KHR" => (205usize, cmd_trace_rays_indirect2_khr as *const c_void),
+        b"vkCmdTraceRaysIndirectKHR" => (206usize, cmd_trace_rays_indirect_khr as *const c_void),
+        b"vkCmdTraceRaysKHR" => (207usize, cmd_trace_rays_khr as *const c_void),
+        b"vkCmdTraceRaysNV" => (208usize, cmd_trace_rays_nv as *const c_void),
+        b"vkCmdUpdateBuffer" => (209usize, cmd_update_buffer as *const c_void),
+        b"vkCmdWaitEvents" => (210usize, cmd_wait_events as *const c_void),
+        b"vkCmdWaitEvents2" => (211usize, cmd_wait_events2 as *const c_void),
+        b"vkCmdWaitEvents2KHR" => (211usize, cmd_wait_events2 as *const c_void),
+        b"vkCmdWriteAccelerationStructuresPropertiesKHR" => (
+            212usize,
+            cmd_write_acceleration_structures_properties_khr as *const c_void,
+        ),
+        b"vkCmdWriteAccelerationStructuresPropertiesNV" => (
+            213usize,
+            cmd_write_acceleration_structures_properties_nv as *const c_void,
+        ),
+        b"vkCmdWriteBufferMarker2AMD" => (214usize, cmd_write_buffer_marker2_amd as *const c_void),
+        b"vkCmdWriteBufferMarkerAMD" => (215usize, cmd_write_buffer_marker_amd as *const c_void),
+        b"vkCmdWriteMicromapsPropertiesEXT" => (
+            216usize,
+            cmd_write_micromaps_properties_ext as *const c_void,
+        ),
+        b"vkCmdWriteTimestamp" => (217usize, cmd_write_timestamp as *const c_void),
+        b"vkCmdWriteTimestamp2" => (218usize, cmd_write_timestamp2 as *const c_void),
+        b"vkCmdWriteTimestamp2KHR" => (218usize, cmd_write_timestamp2 as *const c_void),
+        b"vkCompileDeferredNV" => (219usize, compile_deferred_nv as *const c_void),
+        b"vkCopyAccelerationStructureKHR" => {
+            (220usize, copy_acceleration_structure_khr as *const c_void)
+        }
+        b"vkCopyAccelerationStructureToMemoryKHR" => (
+            221usize,
+            copy_acceleration_structure_to_memory_khr as *const c_void,
+        ),
+        b"vkCopyMemoryToAccelerationStructureKHR" => (
+            222usize,
+            copy_memory_to_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCopyMemoryToMicromapEXT" => (223usize, copy_memory_to_micromap_ext as *const c_void),
+        b"vkCopyMicromapEXT" => (224usize, copy_micromap_ext as *const c_void),
+        b"vkCopyMicromapToMemoryEXT" => (225usize, copy_micromap_to_memory_ext as *const c_void),
+        b"vkCreateAccelerationStructureKHR" => {
+            (226usize, create_acceleration_structure_khr as *const c_void)
+        }
+        b"vkCreateAccelerationStructureNV" => {
+            (227usize, create_acceleration_structure_nv as *const c_void)
+        }
+        b"vkCreateBuffer" => (228usize, create_buffer as *const c_void),
+        b"vkCreateBufferCollectionFUCHSIA" => {
+            (229usize, create_buffer_collection_fuchsia as *const c_void)
+        }
+        b"vkCreateBufferView" => (230usize, create_buffer_view as *const c_void),
+        b"vkCreateCommandPool" => (231usize, create_command_pool as *const c_void),
+        b"vkCreateComputePipelines" => (232usize, create_compute_pipelines as *const c_void),
+        b"vkCreateCuFunctionNVX" => (233usize, create_cu_function_nvx as *const c_void),
+        b"vkCreateCuModuleNVX" => (234usize, create_cu_module_nvx as *const c_void),
+        b"vkCreateDeferredOperationKHR" => {
+            (235usize, create_deferred_operation_khr as *const c_void)
+        }
+        b"vkCreateDescriptorPool" => (236usize, create_descriptor_pool as *const c_void),
+        b"vkCreateDescriptorSetLayout" => (237usize, create_descriptor_set_layout as *const c_void),
+        b"vkCreateDescriptorUpdateTemplate" => {
+            (238usize, create_descriptor_update_template as *const c_void)
+        }
+        b"vkCreateDescriptorUpdateTemplateKHR" => {
+            (238usize, create_descriptor_update_template as *const c_void)
+        }
+        b"vkCreateEvent" => (239usize, create_event as *const c_void),
+        b"vkCreateFence" => (240usize, create_fence as *const c_void),
+        b"vkCreateFramebuffer" => (241usize, create_framebuffer as *const c_void),
+        b"vkCreateGraphicsPipelines" => (242usize, create_graphics_pipelines as *const c_void),
+        b"vkCreateImage" => (243usize, create_image as *const c_void),
+        b"vkCreateImageView" => (244usize, create_image_view as *const c_void),
+        b"vkCreateIndirectCommandsLayoutNV" => (
+            245usize,
+            create_indirect_commands_layout_nv as *const c_void,
+        ),
+        b"vkCreateMicromapEXT" => (246usize, create_micromap_ext as *const c_void),
+        b"vkCreateOpticalFlowSessionNV" => {
+            (247usize, create_optical_flow_session_nv as *const c_void)
+        }
+        b"vkCreatePipelineCache" => (248usize, create_pipeline_cache as *const c_void),
+        b"vkCreatePipelineLayout" => (249usize, create_pipeline_layout as *const c_void),
+        b"vkCreatePrivateDataSlot" => (250usize, create_private_data_slot as *const c_void),
+        b"vkCreatePrivateDataSlotEXT" => (250usize, create_private_data_slot as *const c_void),
+        b"vkCreateQueryPool" => (251usize, create_query_pool as *const c_void),
+        b"vkCreateRayTracingPipelinesKHR" => {
+            (252usize, create_ray_tracing_pipelines_khr as *const c_void)
+        }
+        b"vkCreateRayTracingPipelinesNV" => {
+            (253usize, create_ray_tracing_pipelines_nv as *const c_void)
+        }
+        b"vkCreateRenderPass" => (254usize, create_render_pass as *const c_void),
+        b"vkCreateRenderPass2" => (255usize, create_render_pass2 as *const c_void),
+        b"vkCreateRenderPass2KHR" => (255usize, create_render_pass2 as *const c_void),
+        b"vkCreateSampler" => (256usize, create_sampler as *const c_void),
+        b"vkCreateSamplerYcbcrConversion" => {
+            (257usize, create_sampler_ycbcr_conversion as *const c_void)
+        }
+        b"vkCreateSamplerYcbcrConversionKHR" => {
+            (257usize, create_sampler_ycbcr_conversion as *const c_void)
+        }
+        b"vkCreateSemaphore" => (258usize, create_semaphore as *const c_void),
+        b"vkCreateShaderModule" => (259usize, create_shader_module as *const c_void),
+        b"vkCreateShadersEXT" => (260usize, create_shaders_ext as *const c_void),
+        b"vkCreateSharedSwapchainsKHR" => (261usize, create_shared_swapchains_khr as *const c_void),
+        b"vkCreateSwapchainKHR" => (262usize, create_swapchain_khr as *const c_void),
+        b"vkCreateValidationCacheEXT" => (263usize, create_validation_cache_ext as *const c_void),
+        b"vkCreateVideoSessionKHR" => (264usize, create_video_session_khr as *const c_void),
+        b"vkCreateVideoSessionParametersKHR" => (
+            265usize,
+            create_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkDebugMarkerSetObjectNameEXT" => {
+            (266usize, debug_marker_set_object_name_ext as *const c_void)
+        }
+        b"vkDebugMarkerSetObjectTagEXT" => {
+            (267usize, debug_marker_set_object_tag_ext as *const c_void)
+        }
+        b"vkDeferredOperationJoinKHR" => (268usize, deferred_operation_join_khr as *const c_void),
+        b"vkDestroyAccelerationStructureKHR" => (
+            269usize,
+            destroy_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkDestroyAccelerationStructureNV" => {
+            (270usize, destroy_acceleration_structure_nv as *const c_void)
+        }
+        b"vkDestroyBuffer" => (271usize, destroy_buffer as *const c_void),
+        b"vkDestroyBufferCollectionFUCHSIA" => {
+            (272usize, destroy_buffer_collection_fuchsia as *const c_void)
+        }
+        b"vkDestroyBufferView" => (273usize, destroy_buffer_view as *const c_void),
+        b"vkDestroyCommandPool" => (274usize, destroy_command_pool as *const c_void),
+        b"vkDestroyCuFunctionNVX" => (275usize, destroy_cu_function_nvx as *const c_void),
+        b"vkDestroyCuModuleNVX" => (276usize, destroy_cu_module_nvx as *const c_void),
+        b"vkDestroyDeferredOperationKHR" => {
+            (277usize, destroy_deferred_operation_khr as *const c_void)
+        }
+        b"vkDestroyDescriptorPool" => (278usize, destroy_descriptor_pool as *const c_void),
+        b"vkDestroyDescriptorSetLayout" => {
+            (279usize, destroy_descriptor_set_layout as *const c_void)
+        }
+        b"vkDestroyDescriptorUpdateTemplate" => (
+            280usize,
+            destroy_descriptor_update_template as *const c_void,
+        ),
+        b"vkDestroyDescriptorUpdateTemplateKHR" => (
+            280usize,
+            destroy_descriptor_update_template as *const c_void,
+        ),
+        b"vkDestroyDevice" => (281usize, destroy_device as *const c_void),
+        b"vkDestroyEvent" => (282usize, destroy_event as *const c_void),
+        b"vkDestroyFence" => (283usize, destroy_fence as *const c_void),
+        b"vkDestroyFramebuffer" => (284usize, destroy_framebuffer as *const c_void),
+        b"vkDestroyImage" => (285usize, destroy_image as *const c_void),
+        b"vkDestroyImageView" => (286usize, destroy_image_view as *const c_void),
+        b"vkDestroyIndirectCommandsLayoutNV" => (
+            287usize,
+            destroy_indirect_commands_layout_nv as *const c_void,
+        ),
+        b"vkDestroyMicromapEXT" => (288usize, destroy_micromap_ext as *const c_void),
+        b"vkDestroyOpticalFlowSessionNV" => {
+            (289usize, destroy_optical_flow_session_nv as *const c_void)
+        }
+        b"vkDestroyPipeline" => (290usize, destroy_pipeline as *const c_void),
+        b"vkDestroyPipelineCache" => (291usize, destroy_pipeline_cache as *const c_void),
+        b"vkDestroyPipelineLayout" => (292usize, destroy_pipeline_layout as *const c_void),
+        b"vkDestroyPrivateDataSlot" => (293usize, destroy_private_data_slot as *const c_void),
+        b"vkDestroyPrivateDataSlotEXT" => (293usize, destroy_private_data_slot as *const c_void),
+        b"vkDestroyQueryPool" => (294usize, destroy_query_pool as *const c_void),
+        b"vkDestroyRenderPass" => (295usize, destroy_render_pass as *const c_void),
+        b"vkDestroySampler" => (296usize, destroy_sampler as *const c_void),
+        b"vkDestroySamplerYcbcrConversion" => {
+            (297usize, destroy_sampler_ycbcr_conversion as *const c_void)
+        }
+        b"vkDestroySamplerYcbcrConversionKHR" => {
+            (297usize, destroy_sampler_ycbcr_conversion as *const c_void)
+        }
+        b"vkDestroySemaphore" => (298usize, destroy_semaphore as *const c_void),
+        b"vkDestroyShaderEXT" => (299usize, destroy_shader_ext as *const c_void),
+        b"vkDestroyShaderModule" => (300usize, destroy_shader_module as *const c_void),
+        b"vkDestroySwapchainKHR" => (301usize, destroy_swapchain_khr as *const c_void),
+        b"vkDestroyValidationCacheEXT" => (302usize, destroy_validation_cache_ext as *const c_void),
+        b"vkDestroyVideoSessionKHR" => (303usize, destroy_video_session_khr as *const c_void),
+        b"vkDestroyVideoSessionParametersKHR" => (
+            304usize,
+            destroy_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkDeviceWaitIdle" => (305usize, device_wait_idle as *const c_void),
+        b"vkDisplayPowerControlEXT" => (306usize, display_power_control_ext as *const c_void),
+        b"vkEndCommandBuffer" => (307usize, end_command_buffer as *const c_void),
+        b"vkExportMetalObjectsEXT" => (308usize, export_metal_objects_ext as *const c_void),
+        b"vkFlushMappedMemoryRanges" => (309usize, flush_mapped_memory_ranges as *const c_void),
+        b"vkFreeCommandBuffers" => (310usize, free_command_buffers as *const c_void),
+        b"vkFreeDescriptorSets" => (311usize, free_descriptor_sets as *const c_void),
+        b"vkFreeMemory" => (312usize, free_memory as *const c_void),
+        b"vkGetAccelerationStructureBuildSizesKHR" => (
+            313usize,
+            get_acceleration_structure_build_sizes_khr as *const c_void,
+        ),
+        b"vkGetAccelerationStructureDeviceAddressKHR" => (
+            314usize,
+            get_acceleration_structure_device_address_khr as *const c_void,
+        ),
+        b"vkGetAccelerationStructureHandleNV" => (
+            315usize,
+            get_acceleration_structure_handle_nv as *const c_void,
+        ),
+        b"vkGetAccelerationStructureMemoryRequirementsNV" => (
+            316usize,
+            get_acceleration_structure_memory_requirements_nv as *const c_void,
+        ),
+        b"vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT" => (
+            317usize,
+            get_acceleration_structure_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetAndroidHardwareBufferPropertiesANDROID" => (
+            318usize,
+            get_android_hardware_buffer_properties_android as *const c_void,
+        ),
+        b"vkGetBufferCollectionPropertiesFUCHSIA" => (
+            319usize,
+            get_buffer_collection_properties_fuchsia as *const c_void,
+        ),
+        b"vkGetBufferDeviceAddress" => (320usize, get_buffer_device_address as *const c_void),
+        b"vkGetBufferDeviceAddressEXT" => (320usize, get_buffer_device_address as *const c_void),
+        b"vkGetBufferDeviceAddressKHR" => (320usize, get_buffer_device_address as *const c_void),
+        b"vkGetBufferMemoryRequirements" => {
+            (321usize, get_buffer_memory_requirements as *const c_void)
+        }
+        b"vkGetBufferMemoryRequirements2" => {
+            (322usize, get_buffer_memory_requirements2 as *const c_void)
+        }
+        b"vkGetBufferMemoryRequirements2KHR" => {
+            (322usize, get_buffer_memory_requirements2 as *const c_void)
+        }
+        b"vkGetBufferOpaqueCaptureAddress" => {
+            (323usize, get_buffer_opaque_capture_address as *const c_void)
+        }
+        b"vkGetBufferOpaqueCaptureAddressKHR" => {
+            (323usize, get_buffer_opaque_capture_address as *const c_void)
+        }
+        b"vkGetBufferOpaqueCaptureDescriptorDataEXT" => (
+            324usize,
+            get_buffer_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetCalibratedTimestampsEXT" => {
+            (325usize, get_calibrated_timestamps_ext as *const c_void)
+        }
+        b"vkGetDeferredOperationMaxConcurrencyKHR" => (
+            326usize,
+            get_deferred_operation_max_concurrency_khr as *const c_void,
+        ),
+        b"vkGetDeferredOperationResultKHR" => {
+            (327usize, get_deferred_operation_result_khr as *const c_void)
+        }
+        b"vkGetDescriptorEXT" => (328usize, get_descriptor_ext as *const c_void),
+        b"vkGetDescriptorSetHostMappingVALVE" => (
+            329usize,
+            get_descriptor_set_host_mapping_valve as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutBindingOffsetEXT" => (
+            330usize,
+            get_descriptor_set_layout_binding_offset_ext as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutHostMappingInfoVALVE" => (
+            331usize,
+            get_descriptor_set_layout_host_mapping_info_valve as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutSizeEXT" => (
+            332usize,
+            get_descriptor_set_layout_size_ext as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutSupport" => {
+            (333usize, get_descriptor_set_layout_support as *const c_void)
+        }
+        b"vkGetDescriptorSetLayoutSupportKHR" => {
+            (333usize, get_descriptor_set_layout_support as *const c_void)
+        }
+        b"vkGetDeviceAccelerationStructureCompatibilityKHR" => (
+            334usize,
+            get_device_acceleration_structure_compatibility_khr as *const c_void,
+        ),
+        b"vkGetDeviceBufferMemoryRequirements" => (
+            335usize,
+            get_device_buffer_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceBufferMemoryRequirementsKHR" => (
+            335usize,
+            get_device_buffer_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceFaultInfoEXT" => (336usize, get_device_fault_info_ext as *const c_void),
+        b"vkGetDeviceGroupPeerMemoryFeatures" => (
+            337usize,
+            get_device_group_peer_memory_features as *const c_void,
+        ),
+        b"vkGetDeviceGroupPeerMemoryFeaturesKHR" => (
+            337usize,
+            get_device_group_peer_memory_features as *const c_void,
+        ),
+        b"vkGetDeviceGroupPresentCapabilitiesKHR" => (
+            338usize,
+            get_device_group_present_capabilities_khr as *const c_void,
+        ),
+        b"vkGetDeviceGroupSurfacePresentModes2EXT" => (
+            339usize,
+            get_device_group_surface_present_modes2_ext as *const c_void,
+        ),
+        b"vkGetDeviceGroupSurfacePresentModesKHR" => (
+            340usize,
+            get_device_group_surface_present_modes_khr as *const c_void,
+        ),
+        b"vkGetDeviceImageMemoryRequirements" => (
+            341usize,
+            get_device_image_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceImageMemoryRequirementsKHR" => (
+            341usize,
+            get_device_image_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceImageSparseMemoryRequirements" => (
+            342usize,
+            get_device_image_sparse_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceImageSparseMemoryRequirementsKHR" => (
+            342usize,
+            get_device_image_sparse_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceMemoryCommitment" => (343usize, get_device_memory_commitment as *const c_void),
+        b"vkGetDeviceMemoryOpaqueCaptureAddress" => (
+            344usize,
+            get_device_memory_opaque_capture_address as *const c_void,
+        ),
+        b"vkGetDeviceMemoryOpaqueCaptureAddressKHR" => (
+            344usize,
+            get_device_memory_opaque_capture_address as *const c_void,
+        ),
+        b"vkGetDeviceMicromapCompatibilityEXT" => (
+            345usize,
+            get_device_micromap_compatibility_ext as *const c_void,
+        ),
+        b"vkGetDeviceQueue" => (346usize, get_device_queue as *const c_void),
+        b"vkGetDeviceQueue2" => (347usize, get_device_queue2 as *const c_void),
+        b"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI" => (
+            348usize,
+            get_device_subpass_shading_max_workgroup_size_huawei as *const c_void,
+        ),
+        b"vkGetDynamicRenderingTilePropertiesQCOM" => (
+            349usize,
+            get_dynamic_rendering_tile_properties_qcom as *const c_void,
+        ),
+        b"vkGetEncodedVideoSessionParametersKHR" => (
+            350usize,
+            get_encoded_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkGetEventStatus" => (351usize, get_event_status as *const c_void),
+        b"vkGetFenceFdKHR" => (352usize, get_fence_fd_khr as *const c_void),
+        b"vkGetFenceStatus" => (353usize, get_fence_status as *const c_void),
+        b"vkGetFenceWin32HandleKHR" => (354usize, get_fence_win32_handle_khr as *const c_void),
+        b"vkGetFramebufferTilePropertiesQCOM" => (
+            355usize,
+            get_framebuffer_tile_properties_qcom as *const c_void,
+        ),
+        b"vkGetGeneratedCommandsMemoryRequirementsNV" => (
+            356usize,
+            get_generated_commands_memory_requirements_nv as *const c_void,
+        ),
+        b"vkGetImageDrmFormatModifierPropertiesEXT" => (
+            357usize,
+            get_image_drm_format_modifier_properties_ext as *const c_void,
+        ),
+        b"vkGetImageMemoryRequirements" => {
+            (358usize, get_image_memory_requirements as *const c_void)
+        }
+        b"vkGetImageMemoryRequirements2" => {
+            (359usize, get_image_memory_requirements2 as *const c_void)
+        }
+        b"vkGetImageMemoryRequirements2KHR" => {
+            (359usize, get_image_memory_requirements2 as *const c_void)
+        }
+        b"vkGetImageOpaqueCaptureDescriptorDataEXT" => (
+            360usize,
+            get_image_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements" => (
+            361usize,
+            get_image_sparse_memory_requirements as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements2" => (
+            362usize,
+            get_image_sparse_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements2KHR" => (
+            362usize,
+            get_image_sparse_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetImageSubresourceLayout" => (363usize, get_image_subresource_layout as *const c_void),
+        b"vkGetImageSubresourceLayout2EXT" => {
+            (364usize, get_image_subresource_layout2_ext as *const c_void)
+        }
+        b"vkGetImageViewAddressNVX" => (365usize, get_image_view_address_nvx as *const c_void),
+        b"vkGetImageViewHandleNVX" => (366usize, get_image_view_handle_nvx as *const c_void),
+        b"vkGetImageViewOpaqueCaptureDescriptorDataEXT" => (
+            367usize,
+            get_image_view_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetMemoryAndroidHardwareBufferANDROID" => (
+            368usize,
+            get_memory_android_hardware_buffer_android as *const c_void,
+        ),
+        b"vkGetMemoryFdKHR" => (369usize, get_memory_fd_khr as *const c_void),
+        b"vkGetMemoryFdPropertiesKHR" => (370usize, get_memory_fd_properties_khr as *const c_void),
+        b"vkGetMemoryHostPointerPropertiesEXT" => (
+            371usize,
+            get_memory_host_pointer_properties_ext as *const c_void,
+        ),
+        b"vkGetMemoryRemoteAddressNV" => (372usize, get_memory_remote_address_nv as *const c_void),
+        b"vkGetMemoryWin32HandleKHR" => (373usize, get_memory_win32_handle_khr as *const c_void),
+        b"vkGetMemoryWin32HandleNV" => (374usize, get_memory_win32_handle_nv as *const c_void),
+        b"vkGetMemoryWin32HandlePropertiesKHR" => (
+            375usize,
+            get_memory_win32_handle_properties_khr as *const c_void,
+        ),
+        b"vkGetMemoryZirconHandleFUCHSIA" => {
+            (376usize, get_memory_zircon_handle_fuchsia as *const c_void)
+        }
+        b"vkGetMemoryZirconHandlePropertiesFUCHSIA" => (
+            377usize,
+            get_memory_zircon_handle_properties_fuchsia as *const c_void,
+        ),
+        b"vkGetMicromapBuildSizesEXT" => (378usize, get_micromap_build_sizes_ext as *const c_void),
+        b"vkGetPastPresentationTimingGOOGLE" => (
+            379usize,
+            get_past_presentation_timing_google as *const c_void,
+        ),
+        b"vkGetPerformanceParameterINTEL" => {
+            (380usize, get_performance_parameter_intel as *const c_void)
+        }
+        b"vkGetPipelineCacheData" => (381usize, get_pipeline_cache_data as *const c_void),
+        b"vkGetPipelineExecutableInternalRepresentationsKHR" => (
+            382usize,
+            get_pipeline_executable_internal_representations_khr as *const c_void,
+        ),
+        b"vkGetPipelineExecutablePropertiesKHR" => (
+            383usize,
+            get_pipeline_executable_properties_khr as *const c_void,
+        ),
+        b"vkGetPipelineExecutableStatisticsKHR" => (
+            384usize,
+            get_pipeline_executable_statistics_khr as *const c_void,
+        ),
+        b"vkGetPipelinePropertiesEXT" => (385usize, get_pipeline_properties_ext as *const c_void),
+        b"vkGetPrivateData" => (386usize, get_private_data as *const c_void),
+        b"vkGetPrivateDataEXT" => (386usize, get_private_data as *const c_void),
+        b"vkGetQueryPoolResults" => (387usize, get_query_pool_results as *const c_void),
+        b"vkGetQueueCheckpointData2NV" => {
+            (388usize, get_queue_checkpoint_data2_nv as *const c_void)
+        }
+        b"vkGetQueueCheckpointDataNV" => (389usize, get_queue_checkpoint_data_nv as *const c_void),
+        b"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR" => (
+            390usize,
+            get_ray_tracing_capture_replay_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupHandlesKHR" => (
+            391usize,
+            get_ray_tracing_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupHandlesNV" => (
+            391usize,
+            get_ray_tracing_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupStackSizeKHR" => (
+            392usize,
+            get_ray_tracing_shader_group_stack_size_khr as *const c_void,
+        ),
+        b"vkGetRefreshCycleDurationGOOGLE" => {
+            (393usize, get_refresh_cycle_duration_google as *const c_void)
+        }
+        b"vkGetRenderAreaGranularity" => (394usize, get_render_area_granularity as *const c_void),
+        b"vkGetSamplerOpaqueCaptureDescriptorDataEXT" => (
+            395usize,
+            get_sampler_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetScreenBufferPropertiesQNX" => {
+            (396usize, get_screen_buffer_properties_qnx as *const c_void)
+        }
+        b"vkGetSemaphoreCounterValue" => (397usize, get_semaphore_counter_value as *const c_void),
+        b"vkGetSemaphoreCounterValueKHR" => {
+            (397usize, get_semaphore_counter_value as *const c_void)
+        }
+        b"vkGetSemaphoreFdKHR" => (398usize, get_semaphore_fd_khr as *const c_void),
+        b"vkGetSemaphoreWin32HandleKHR" => {
+            (399usize, get_semaphore_win32_handle_khr as *const c_void)
+        }
+        b"vkGetSemaphoreZirconHandleFUCHSIA" => (
+            400usize,
+            get_semaphore_zircon_handle_fuchsia as *const c_void,
+        ),
+        b"vkGetShaderBinaryDataEXT" => (401usize, get_shader_binary_data_ext as *const c_void),
+        b"vkGetShaderInfoAMD" => (402usize, get_shader_info_amd as *const c_void),
+        b"vkGetShaderModuleCreateInfoIdentifierEXT" => (
+            403usize,
+            get_shader_module_create_info_identifier_ext as *const c_void,
+        ),
+        b"vkGetShaderModuleIdentifierEXT" => {
+            (404usize, get_shader_module_identifier_ext as *const c_void)
+        }
+        b"vkGetSwapchainCounterEXT" => (405usize, get_swapchain_counter_ext as *const c_void),
+        b"vkGetSwapchainGrallocUsage2ANDROID" => (
+            406usize,
+            get_swapchain_gralloc_usage2_android as *const c_void,
+        ),
+        b"vkGetSwapchainGrallocUsageANDROID" => (
+            407usize,
+            get_swapchain_gralloc_usage_android as *const c_void,
+        ),
+        b"vkGetSwapchainImagesKHR" => (408usize, get_swapchain_images_khr as *const c_void),
+        b"vkGetSwapchainStatusKHR" => (409usize, get_swapchain_status_khr as *const c_void),
+        b"vkGetValidationCacheDataEXT" => {
+            (410usize, get_validation_cache_data_ext as *const c_void)
+        }
+        b"vkGetVideoSessionMemoryRequirementsKHR" => (
+            411usize,
+            get_video_session_memory_requirements_khr as *const c_void,
+        ),
+        b"vkImportFenceFdKHR" => (412usize, import_fence_fd_khr as *const c_void),
+        b"vkImportFenceWin32HandleKHR" => {
+            (413usize, import_fence_win32_handle_khr as *const c_void)
+        }
+        b"vkImportSemaphoreFdKHR" => (414usize, import_semaphore_fd_khr as *const c_void),
+        b"vkImportSemaphoreWin32HandleKHR" => {
+            (415usize, import_semaphore_win32_handle_khr as *const c_void)
+        }
+        b"vkImportSemaphoreZirconHandleFUCHSIA" => (
+            416usize,
+            import_semaphore_zircon_handle_fuchsia as *const c_void,
+        ),
+        b"vkInitializePerformanceApiINTEL" => {
+            (417usize, initialize_performance_api_intel as *const c_void)
+        }
+        b"vkInvalidateMappedMemoryRanges" => {
+            (418usize, invalidate_mapped_memory_ranges as *const c_void)
+        }
+        b"vkMapMemory" => (419usize, map_memory as *const c_void),
+        b"vkMapMemory2KHR" => (420usize, map_memory2_khr as *const c_void),
+        b"vkMergePipelineCaches" => (421usize, merge_pipeline_caches as *const c_void),
+        b"vkMergeValidationCachesEXT" => (422usize, merge_validation_caches_ext as *const c_void),
+        b"vkQueueBeginDebugUtilsLabelEXT" => {
+            (423usize, queue_begin_debug_utils_label_ext as *const c_void)
+        }
+        b"vkQueueBindSparse" => (424usize, queue_bind_sparse as *const c_void),
+        b"vkQueueEndDebugUtilsLabelEXT" => {
+            (425usize, queue_end_debug_utils_label_ext as *const c_void)
+        }
+        b"vkQueueInsertDebugUtilsLabelEXT" => (
+            426usize,
+            queue_insert_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkQueuePresentKHR" => (427usize, queue_present_khr as *const c_void),
+        b"vkQueueSetPerformanceConfigurationINTEL" => (
+            428usize,
+            queue_set_performance_configuration_intel as *const c_void,
+        ),
+        b"vkQueueSignalReleaseImageANDROID" => (
+            429usize,
+            queue_signal_release_image_android as *const c_void,
+        ),
+        b"vkQueueSubmit" => (430usize, queue_submit as *const c_void),
+        b"vkQueueSubmit2" => (431usize, queue_submit2 as *const c_void),
+        b"vkQueueSubmit2KHR" => (431usize, queue_submit2 as *const c_void),
+        b"vkQueueWaitIdle" => (432usize, queue_wait_idle as *const c_void),
+        b"vkRegisterDeviceEventEXT" => (433usize, register_device_event_ext as *const c_void),
+        b"vkRegisterDisplayEventEXT" => (434usize, register_display_event_ext as *const c_void),
+        b"vkReleaseFullScreenExclusiveModeEXT" => (
+            435usize,
+            release_full_screen_exclusive_mode_ext as *const c_void,
+        ),
+        b"vkReleasePerformanceConfigurationINTEL" => (
+            436usize,
+            release_performance_configuration_intel as *const c_void,
+        ),
+        b"vkReleaseProfilingLockKHR" => (437usize, release_profiling_lock_khr as *const c_void),
+        b"vkReleaseSwapchainImagesEXT" => (438usize, release_swapchain_images_ext as *const c_void),
+        b"vkResetCommandBuffer" => (439usize, reset_command_buffer as *const c_void),
+        b"vkResetCommandPool" => (440usize, reset_command_pool as *const c_void),
+        b"vkResetDescriptorPool" => (441usize, reset_descriptor_pool as *const c_void),
+        b"vkResetEvent" => (442usize, reset_event as *const c_void),
+        b"vkResetFences" => (443usize, reset_fences as *const c_void),
+        b"vkResetQueryPool" => (444usize, reset_query_pool as *const c_void),
+        b"vkResetQueryPoolEXT" => (444usize, reset_query_pool as *const c_void),
+        b"vkSetBufferCollectionBufferConstraintsFUCHSIA" => (
+            445usize,
+            set_buffer_collection_buffer_constraints_fuchsia as *const c_void,
+        ),
+        b"vkSetBufferCollectionImageConstraintsFUCHSIA" => (
+            446usize,
+            set_buffer_collection_image_constraints_fuchsia as *const c_void,
+        ),
+        b"vkSetDebugUtilsObjectNameEXT" => {
+            (447usize, set_debug_utils_object_name_ext as *const c_void)
+        }
+        b"vkSetDebugUtilsObjectTagEXT" => {
+            (448usize, set_debug_utils_object_tag_ext as *const c_void)
+        }
+        b"vkSetDeviceMemoryPriorityEXT" => {
+            (449usize, set_device_memory_priority_ext as *const c_void)
+        }
+        b"vkSetEvent" => (450usize, set_event as *const c_void),
+        b"vkSetHdrMetadataEXT" => (451usize, set_hdr_metadata_ext as *const c_void),
+        b"vkSetLocalDimmingAMD" => (452usize, set_local_dimming_amd as *const c_void),
+        b"vkSetPrivateData" => (453usize, set_private_data as *const c_void),
+        b"vkSetPrivateDataEXT" => (453usize, set_private_data as *const c_void),
+        b"vkSignalSemaphore" => (454usize, signal_semaphore as *const c_void),
+        b"vkSignalSemaphoreKHR" => (454usize, signal_semaphore as *const c_void),
+        b"vkTrimCommandPool" => (455usize, trim_command_pool as *const c_void),
+        b"vkTrimCommandPoolKHR" => (455usize, trim_command_pool as *const c_void),
+        b"vkUninitializePerformanceApiINTEL" => (
+            456usize,
+            uninitialize_performance_api_intel as *const c_void,
+        ),
+        b"vkUnmapMemory" => (457usize, unmap_memory as *const c_void),
+        b"vkUnmapMemory2KHR" => (458usize, unmap_memory2_khr as *const c_void),
+        b"vkUpdateDescriptorSetWithTemplate" => (
+            459usize,
+            update_descriptor_set_with_template as *const c_void,
+        ),
+        b"vkUpdateDescriptorSetWithTemplateKHR" => (
+            459usize,
+            update_descriptor_set_with_template as *const c_void,
+        ),
+        b"vkUpdateDescriptorSets" => (460usize, update_descriptor_sets as *const c_void),
+        b"vkUpdateVideoSessionParametersKHR" => (
+            461usize,
+            update_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkWaitForFences" => (462usize, wait_for_fences as *const c_void),
+        b"vkWaitForPresentKHR" => (463usize, wait_for_present_khr as *const c_void),
+        b"vkWaitSemaphores" => (464usize, wait_semaphores as *const c_void),
+        b"vkWaitSemaphoresKHR" => (464usize, wait_semaphores as *const c_void),
+        b"vkWriteAccelerationStructuresPropertiesKHR" => (
+            465usize,
+            write_acceleration_structures_properties_khr as *const c_void,
+        ),
+        b"vkWriteMicromapsPropertiesEXT" => {
+            (466usize, write_micromaps_properties_ext as *const c_void)
+        }
+        _ => return None,
+    })
+}
+#[doc = r" The names that a recorded command can be loaded with, null-terminated, and its replay"]
+#[doc = r" function."]
+pub(super) fn replay_fn(command: &str) -> Option<(&'static [&'static [u8]], ReplayFn)> {
+    Some(match command {
+        "vkAcquireFullScreenExclusiveModeEXT" => (
+            &[b"vkAcquireFullScreenExclusiveModeEXT\0"],
+            replay_acquire_full_screen_exclusive_mode_ext,
+        ),
+        "vkAcquireImageANDROID" => (&[b"vkAcquireImageANDROID\0"], replay_acquire_image_android),
+        "vkAcquireNextImage2KHR" => (
+            &[b"vkAcquireNextImage2KHR\0"],
+            replay_acquire_next_image2_khr,
+        ),
+        "vkAcquireNextImageKHR" => (&[b"vkAcquireNextImageKHR\0"], replay_acquire_next_image_khr),
+        "vkAcquirePerformanceConfigurationINTEL" => (
+            &[b"vkAcquirePerformanceConfigurationINTEL\0"],
+            replay_acquire_performance_configuration_intel,
+        ),
+        "vkAcquireProfilingLockKHR" => (
+            &[b"vkAcquireProfilingLockKHR\0"],
+            replay_acquire_profiling_lock_khr,
+        ),
+        "vkAllocateCommandBuffers" => (
+            &[b"vkAllocateCommandBuffers\0"],
+            replay_allocate_command_buffers,
+        ),
+        "vkAllocateDescriptorSets" => (
+            &[b"vkAllocateDescriptorSets\0"],
+            replay_allocate_descriptor_sets,
+        ),
+        "vkAllocateMemory" => (&[b"vkAllocateMemory\0"], replay_allocate_memory),
+        "vkBeginCommandBuffer" => (&[b"vkBeginCommandBuffer\0"], replay_begin_command_buffer),
+        "vkBindAccelerationStructureMemoryNV" => (
+            &[b"vkBindAccelerationStructureMemoryNV\0"],
+            replay_bind_acceleration_structure_memory_nv,
+        ),
+        "vkBindBufferMemory" => (&[b"vkBindBufferMemory\0"], replay_bind_buffer_memory),
+        "vkBindBufferMemory2" => (
+            &[b"vkBindBufferMemory2\0", b"vkBindBufferMemory2KHR\0"],
+            replay_bind_buffer_memory2,
+        ),
+        "vkBindImageMemory" => (&[b"vkBindImageMemory\0"], replay_bind_image_memory),
+        "vkBindImageMemory2" => (
+            &[b"vkBindImageMemory2\0", b"vkBindImageMemory2KHR\0"],
+            replay_bind_image_memory2,
+        ),
+        "vkBindOpticalFlowSessionImageNV" => (
+            &[b"vkBindOpticalFlowSessionImageNV\0"],
+            replay_bind_optical_flow_session_image_nv,
+        ),
+        "vkBindVideoSessionMemoryKHR" => (
+            &[b"vkBindVideoSessionMemoryKHR\0"],
+            replay_bind_video_session_memory_khr,
+        ),
+        "vkBuildAccelerationStructuresKHR" => (
+            &[b"vkBuildAccelerationStructuresKHR\0"],
+            replay_build_acceleration_structures_khr,
+        ),
+        "vkBuildMicromapsEXT" => (&[b"vkBuildMicromapsEXT\0"], replay_build_micromaps_ext),
+        "vkCmdBeginConditionalRenderingEXT" => (
+            &[b"vkCmdBeginConditionalRenderingEXT\0"],
+            replay_cmd_begin_conditional_rendering_ext,
+        ),
+        "vkCmdBeginDebugUtilsLabelEXT" => (
+            &[b"vkCmdBeginDebugUtilsLabelEXT\0"],
+            replay_cmd_begin_debug_utils_label_ext,
+        ),
+        "vkCmdBeginQuery" => (&[b"vkCmdBeginQuery\0"], replay_cmd_begin_query),
+        "vkCmdBeginQueryIndexedEXT" => (
+            &[b"vkCmdBeginQueryIndexedEXT\0"],
+            replay_cmd_begin_query_indexed_ext,
+        ),
+        "vkCmdBeginRenderPass" => (&[b"vkCmdBeginRenderPass\0"], replay_cmd_begin_render_pass),
+        "vkCmdBeginRenderPass2" => (
+            &[b"vkCmdBeginRenderPass2\0", b"vkCmdBeginRenderPass2KHR\0"],
+            replay_cmd_begin_render_pass2,
+        ),
+        "vkCmdBeginRendering" => (
+            &[b"vkCmdBeginRendering\0", b"vkCmdBeginRenderingKHR\0"],
+            replay_cmd_begin_rendering,
+        ),
+        "vkCmdBeginTransformFeedbackEXT" => (
+            &[b"vkCmdBeginTransformFeedbackEXT\0"],
+            replay_cmd_begin_transform_feedback_ext,
+        ),
+        "vkCmdBeginVideoCodingKHR" => (
+            &[b"vkCmdBeginVideoCodingKHR\0"],
+            replay_cmd_begin_video_coding_khr,
+        ),
+        "vkCmdBindDescriptorBufferEmbeddedSamplersEXT" => (
+            &[b"vkCmdBindDescriptorBufferEmbeddedSamplersEXT\0"],
+            replay_cmd_bind_descriptor_buffer_embedded_samplers_ext,
+        ),
+        "vkCmdBindDescriptorBuffersEXT" => (
+            &[b"vkCmdBindDescriptorBuffersEXT\0"],
+            replay_cmd_bind_descriptor_buffers_ext,
+        ),
+        "vkCmdBindDescriptorSets" => (
+            &[b"vkCmdBindDescriptorSets\0"],
+            replay_cmd_bind_descriptor_sets,
+        ),
+        "vkCmdBindIndexBuffer" => (&[b"vkCmdBindIndexBuffer\0"], replay_cmd_bind_index_buffer),
+        "vkCmdBindInvocationMaskHUAWEI" => (
+            &[b"vkCmdBindInvocationMaskHUAWEI\0"],
+            replay_cmd_bind_invocation_mask_huawei,
+        ),
+        "vkCmdBindPipeline" => (&[b"vkCmdBindPipeline\0"], replay_cmd_bind_pipeline),
+        "vkCmdBindPipelineShaderGroupNV" => (
+            &[b"vkCmdBindPipelineShaderGroupNV\0"],
+            replay_cmd_bind_pipeline_shader_group_nv,
+        ),
+        "vkCmdBindShadersEXT" => (&[b"vkCmdBindShadersEXT\0"], replay_cmd_bind_shaders_ext),
+        "vkCmdBindShadingRateImageNV" => (
+            &[b"vkCmdBindShadingRateImageNV\0"],
+            replay_cmd_bind_shading_rate_image_nv,
+        ),
+        "vkCmdBindTransformFeedbackBuffersEXT" => (
+            &[b"vkCmdBindTransformFeedbackBuffersEXT\0"],
+            replay_cmd_bind_transform_feedback_buffers_ext,
+        ),
+        "vkCmdBindVertexBuffers" => (
+            &[b"vkCmdBindVertexBuffers\0"],
+            replay_cmd_bind_vertex_buffers,
+        ),
+        "vkCmdBindVertexBuffers2" => (
+            &[
+                b"vkCmdBindVertexBuffers2\0",
+                b"vkCmdBindVertexBuffers2EXT\0",
+            ],
+            replay_cmd_bind_vertex_buffers2,
+        ),
+        "vkCmdBlitImage" => (&[b"vkCmdBlitImage\0"], replay_cmd_blit_image),
+        "vkCmdBlitImage2" => (
+            &[b"vkCmdBlitImage2\0", b"vkCmdBlitImage2KHR\0"],
+            replay_cmd_blit_image2,
+        ),
+        "vkCmdBuildAccelerationStructureNV" => (
+            &[b"vkCmdBuildAccelerationStructureNV\0"],
+            replay_cmd_build_acceleration_structure_nv,
+        ),
+        "vkCmdBuildAccelerationStructuresIndirectKHR" => (
+            &[b"vkCmdBuildAccelerationStructuresIndirectKHR\0"],
+            replay_cmd_build_acceleration_structures_indirect_khr,
+        ),
+        "vkCmdBuildAccelerationStructuresKHR" => (
+            &[b"vkCmdBuildAccelerationStructuresKHR\0"],
+            replay_cmd_build_acceleration_structures_khr,
+        ),
+        "vkCmdBuildMicromapsEXT" => (
+            &[b"vkCmdBuildMicromapsEXT\0"],
+            replay_cmd_build_micromaps_ext,
+        ),
+        "vkCmdClearAttachments" => (&[b"vkCmdClearAttachments\0"], replay_cmd_clear_attachments),
+        "vkCmdClearColorImage" => (&[b"vkCmdClearColorImage\0"], replay_cmd_clear_color_image),
+        "vkCmdClearDepthStencilImage" => (
+            &[b"vkCmdClearDepthStencilImage\0"],
+            replay_cmd_clear_depth_stencil_image,
+        ),
+        "vkCmdControlVideoCodingKHR" => (
+            &[b"vkCmdControlVideoCodingKHR\0"],
+            replay_cmd_control_video_coding_khr,
+        ),
+        "vkCmdCopyAccelerationStructureKHR" => (
+            &[b"vkCmdCopyAccelerationStructureKHR\0"],
+            replay_cmd_copy_acceleration_structure_khr,
+        ),
+        "vkCmdCopyAccelerationStructureNV" => (
+            &[b"vkCmdCopyAccelerationStructureNV\0"],
+            replay_cmd_copy_acceleration_structure_nv,
+        ),
+        "vkCmdCopyAccelerationStructureToMemoryKHR" => (
+            &[b"vkCmdCopyAccelerationStructureToMemoryKHR\0"],
+            replay_cmd_copy_acceleration_structure_to_memory_khr,
+        ),
+        "vkCmdCopyBuffer" => (&[b"vkCmdCopyBuffer\0"], replay_cmd_copy_buffer),
+        "vkCmdCopyBuffer2" => (
+            &[b"vkCmdCopyBuffer2\0", b"vkCmdCopyBuffer2KHR\0"],
+            replay_cmd_copy_buffer2,
+        ),
+        "vkCmdCopyBufferToImage" => (
+            &[b"vkCmdCopyBufferToImage\0"],
+            replay_cmd_copy_buffer_to_image,
+        ),
+        "vkCmdCopyBufferToImage2" => (
+            &[
+                b"vkCmdCopyBufferToImage2\0",
+                b"vkCmdCopyBufferToImage2KHR\0",
+            ],
+            replay_cmd_copy_buffer_to_image2,
+        ),
+        "vkCmdCopyImage" => (&[b"vkCmdCopyImage\0"], replay_cmd_copy_image),
+        "vkCmdCopyImage2" => (
+            &[b"vkCmdCopyImage2\0", b"vkCmdCopyImage2KHR\0"],
+            replay_cmd_copy_image2,
+        ),
+        "vkCmdCopyImageToBuffer" => (
+            &[b"vkCmdCopyImageToBuffer\0"],
+            replay_cmd_copy_image_to_buffer,
+        ),
+        "vkCmdCopyImageToBuffer2" => (
+            &[
+                b"vkCmdCopyImageToBuffer2\0",
+                b"vkCmdCopyImageToBuffer2KHR\0",
+            ],
+            replay_cmd_copy_image_to_buffer2,
+        ),
+        "vkCmdCopyMemoryIndirectNV" => (
+            &[b"vkCmdCopyMemoryIndirectNV\0"],
+            replay_cmd_copy_memory_indirect_nv,
+        ),
+        "vkCmdCopyMemoryToAccelerationStructureKHR" => (
+            &[b"vkCmdCopyMemoryToAccelerationStructureKHR\0"],
+            replay_cmd_copy_memory_to_acceleration_structure_khr,
+        ),
+        "vkCmdCopyMemoryToImageIndirectNV" => (
+            &[b"vkCmdCopyMemoryToImageIndirectNV\0"],
+            replay_cmd_copy_memory_to_image_indirect_nv,
+        ),
+        "vkCmdCopyMemoryToMicromapEXT" => (
+            &[b"vkCmdCopyMemoryToMicromapEXT\0"],
+            replay_cmd_copy_memory_to_micromap_ext,
+        ),
+        "vkCmdCopyMicromapEXT" => (&[b"vkCmdCopyMicromapEXT\0"], replay_cmd_copy_micromap_ext),
+        "vkCmdCopyMicromapToMemoryEXT" => (
+            &[b"vkCmdCopyMicromapToMemoryEXT\0"],
+            replay_cmd_copy_micromap_to_memory_ext,
+        ),
+        "vkCmdCopyQueryPoolResults" => (
+            &[b"vkCmdCopyQueryPoolResults\0"],
+            replay_cmd_copy_query_pool_results,
+        ),
+        "vkCmdCuLaunchKernelNVX" => (
+            &[b"vkCmdCuLaunchKernelNVX\0"],
+            replay_cmd_cu_launch_kernel_nvx,
+        ),
+        "vkCmdDebugMarkerBeginEXT" => (
+            &[b"vkCmdDebugMarkerBeginEXT\0"],
+            replay_cmd_debug_marker_begin_ext,
+        ),
+        "vkCmdDebugMarkerEndEXT" => (
+            &[b"vkCmdDebugMarkerEndEXT\0"],
+            replay_cmd_debug_marker_end_ext,
+        ),
+        "vkCmdDebugMarkerInsertEXT" => (
+            &[b"vkCmdDebugMarkerInsertEXT\0"],
+            replay_cmd_debug_marker_insert_ext,
+        ),
+        "vkCmdDecodeVideoKHR" => (&[b"vkCmdDecodeVideoKHR\0"], replay_cmd_decode_video_khr),
+        "vkCmdDecompressMemoryIndirectCountNV" => (
+            &[b"vkCmdDecompressMemoryIndirectCountNV\0"],
+            replay_cmd_decompress_memory_indirect_count_nv,
+        ),
+        "vkCmdDecompressMemoryNV" => (
+            &[b"vkCmdDecompressMemoryNV\0"],
+            replay_cmd_decompress_memory_nv,
+        ),
+        "vkCmdDispatch" => (&[b"vkCmdDispatch\0"], replay_cmd_dispatch),
+        "vkCmdDispatchBase" => (
+            &[b"vkCmdDispatchBase\0", b"vkCmdDispatchBaseKHR\0"],
+            replay_cmd_dispatch_base,
+        ),
+        "vkCmdDispatchIndirect" => (&[b"vkCmdDispatchIndirect\0"], replay_cmd_dispatch_indirect),
+        "vkCmdDraw" => (&[b"vkCmdDraw\0"], replay_cmd_draw),
+        "vkCmdDrawClusterHUAWEI" => (
+            &[b"vkCmdDrawClusterHUAWEI\0"],
+            replay_cmd_draw_cluster_huawei,
+        ),
+        "vkCmdDrawClusterIndirectHUAWEI" => (
+            &[b"vkCmdDrawClusterIndirectHUAWEI\0"],
+            replay_cmd_draw_cluster_indirect_huawei,
+        ),
+        "vkCmdDrawIndexed" => (&[b"vkCmdDrawIndexed\0"], replay_cmd_draw_indexed),
+        "vkCmdDrawIndexedIndirect" => (
+            &[b"vkCmdDrawIndexedIndirect\0"],
+            replay_cmd_draw_indexed_indirect,
+        ),
+        "vkCmdDrawIndexedIndirectCount" => (
+            &[
+                b"vkCmdDrawIndexedIndirectCount\0",
+                b"vkCmdDrawIndexedIndirectCountAMD\0",
+                b"vkCmdDrawIndexedIndirectCountKHR\0",
+            ],
+            replay_cmd_draw_indexed_indirect_count,
+        ),
+        "vkCmdDrawIndirect" => (&[b"vkCmdDrawIndirect\0"], replay_cmd_draw_indirect),
+        "vkCmdDrawIndirectByteCountEXT" => (
+            &[b"vkCmdDrawIndirectByteCountEXT\0"],
+            replay_cmd_draw_indirect_byte_count_ext,
+        ),
+        "vkCmdDrawIndirectCount" => (
+            &[
+                b"vkCmdDrawIndirectCount\0",
+                b"vkCmdDrawIndirectCountAMD\0",
+                b"vkCmdDrawIndirectCountKHR\0",
+            ],
+            replay_cmd_draw_indirect_count,
+        ),
+        "vkCmdDrawMeshTasksEXT" => (
+            &[b"vkCmdDrawMeshTasksEXT\0"],
+            replay_cmd_draw_mesh_tasks_ext,
+        ),
+        "vkCmdDrawMeshTasksIndirectCountEXT" => (
+            &[b"vkCmdDrawMeshTasksIndirectCountEXT\0"],
+            replay_cmd_draw_mesh_tasks_indirect_count_ext,
+        ),
+        "vkCmdDrawMeshTasksIndirectCountNV" => (
+            &[b"vkCmdDrawMeshTasksIndirectCountNV\0"],
+            replay_cmd_draw_mesh_tasks_indirect_count_nv,
+        ),
+        "vkCmdDrawMeshTasksIndirectEXT" => (
+            &[b"vkCmdDrawMeshTasksIndirectEXT\0"],
+            replay_cmd_draw_mesh_tasks_indirect_ext,
+        ),
+        "vkCmdDrawMeshTasksIndirectNV" => (
+            &[b"vkCmdDrawMeshTasksIndirectNV\0"],
+            replay_cmd_draw_mesh_tasks_indirect_nv,
+        ),
+        "vkCmdDrawMeshTasksNV" => (&[b"vkCmdDrawMeshTasksNV\0"], replay_cmd_draw_mesh_tasks_nv),
+        "vkCmdDrawMultiEXT" => (&[b"vkCmdDrawMultiEXT\0"], replay_cmd_draw_multi_ext),
+        "vkCmdDrawMultiIndexedEXT" => (
+            &[b"vkCmdDrawMultiIndexedEXT\0"],
+            replay_cmd_draw_multi_indexed_ext,
+        ),
+        "vkCmdEncodeVideoKHR" => (&[b"vkCmdEncodeVideoKHR\0"], replay_cmd_encode_video_khr),
+        "vkCmdEndConditionalRenderingEXT" => (
+            &[b"vkCmdEndConditionalRenderingEXT\0"],
+            replay_cmd_end_conditional_rendering_ext,
+        ),
+        "vkCmdEndDebugUtilsLabelEXT" => (
+            &[b"vkCmdEndDebugUtilsLabelEXT\0"],
+            replay_cmd_end_debug_utils_label_ext,
+        ),
+        "vkCmdEndQuery" => (&[b"vkCmdEndQuery\0"], replay_cmd_end_query),
+        "vkCmdEndQueryIndexedEXT" => (
+            &[b"vkCmdEndQueryIndexedEXT\0"],
+            replay_cmd_end_query_indexed_ext,
+        ),
+        "vkCmdEndRenderPass" => (&[b"vkCmdEndRenderPass\0"], replay_cmd_end_render_pass),
+        "vkCmdEndRenderPass2" => (
+            &[b"vkCmdEndRenderPass2\0", b"vkCmdEndRenderPass2KHR\0"],
+            replay_cmd_end_render_pass2,
+        ),
+        "vkCmdEndRendering" => (
+            &[b"vkCmdEndRendering\0", b"vkCmdEndRenderingKHR\0"],
+            replay_cmd_end_rendering,
+        ),
+        "vkCmdEndTransformFeedbackEXT" => (
+            &[b"vkCmdEndTransformFeedbackEXT\0"],
+            replay_cmd_end_transform_feedback_ext,
+        ),
+        "vkCmdEndVideoCodingKHR" => (
+            &[b"vkCmdEndVideoCodingKHR\0"],
+            replay_cmd_end_video_coding_khr,
+        ),
+        "vkCmdExecuteCommands" => (&[b"vkCmdExecuteCommands\0"], replay_cmd_execute_commands),
+        "vkCmdExecuteGeneratedCommandsNV" => (
+            &[b"vkCmdExecuteGeneratedCommandsNV\0"],
+            replay_cmd_execute_generated_commands_nv,
+        ),
+        "vkCmdFillBuffer" => (&[b"vkCmdFillBuffer\0"], replay_cmd_fill_buffer),
+        "vkCmdInsertDebugUtilsLabelEXT" => (
+            &[b"vkCmdInsertDebugUtilsLabelEXT\0"],
+            replay_cmd_insert_debug_utils_label_ext,
+        ),
+        "vkCmdNextSubpass" => (&[b"vkCmdNextSubpass\0"], replay_cmd_next_subpass),
+        "vkCmdNextSubpass2" => (
+            &[b"vkCmdNextSubpass2\0", b"vkCmdNextSubpass2KHR\0"],
+            replay_cmd_next_subpass2,
+        ),
+        "vkCmdOpticalFlowExecuteNV" => (
+            &[b"vkCmdOpticalFlowExecuteNV\0"],
+            replay_cmd_optical_flow_execute_nv,
+        ),
+        "vkCmdPipelineBarrier" => (&[b"vkCmdPipelineBarrier\0"], replay_cmd_pipeline_barrier),
+        "vkCmdPipelineBarrier2" => (
+            &[b"vkCmdPipelineBarrier2\0", b"vkCmdPipelineBarrier2KHR\0"],
+            replay_cmd_pipeline_barrier2,
+        ),
+        "vkCmdPreprocessGeneratedCommandsNV" => (
+            &[b"vkCmdPreprocessGeneratedCommandsNV\0"],
+            replay_cmd_preprocess_generated_commands_nv,
+        ),
+        "vkCmdPushConstants" => (&[b"vkCmdPushConstants\0"], replay_cmd_push_constants),
+        "vkCmdPushDescriptorSetKHR" => (
+            &[b"vkCmdPushDescriptorSetKHR\0"],
+            replay_cmd_push_descriptor_set_khr,
+        ),
+        "vkCmdPushDescriptorSetWithTemplateKHR" => (
+            &[b"vkCmdPushDescriptorSetWithTemplateKHR\0"],
+            replay_cmd_push_descriptor_set_with_template_khr,
+        ),
+        "vkCmdResetEvent" => (&[b"vkCmdResetEvent\0"], replay_cmd_reset_event),
+        "vkCmdResetEvent2" => (
+            &[b"vkCmdResetEvent2\0", b"vkCmdResetEvent2KHR\0"],
+            replay_cmd_reset_event2,
+        ),
+        "vkCmdResetQueryPool" => (&[b"vkCmdResetQueryPool\0"], replay_cmd_reset_query_pool),
+        "vkCmdResolveImage" => (&[b"vkCmdResolveImage\0"], replay_cmd_resolve_image),
+        "vkCmdResolveImage2" => (
+            &[b"vkCmdResolveImage2\0", b"vkCmdResolveImage2KHR\0"],
+            replay_cmd_resolve_image2,
+        ),
+        "vkCmdSetAlphaToCoverageEnableEXT" => (
+            &[b"vkCmdSetAlphaToCoverageEnableEXT\0"],
+            replay_cmd_set_alpha_to_coverage_enable_ext,
+        ),
+        "vkCmdSetAlphaToOneEnableEXT" => (
+            &[b"vkCmdSetAlphaToOneEnableEXT\0"],
+            replay_cmd_set_alpha_to_one_enable_ext,
+        ),
+        "vkCmdSetAttachmentFeedbackLoopEnableEXT" => (
+            &[b"vkCmdSetAttachmentFeedbackLoopEnableEXT\0"],
+            replay_cmd_set_attachment_feedback_loop_enable_ext,
+        ),
+        "vkCmdSetBlendConstants" => (
+            &[b"vkCmdSetBlendConstants\0"],
+            replay_cmd_set_blend_constants,
+        ),
+        "vkCmdSetCheckpointNV" => (&[b"vkCmdSetCheckpointNV\0"], replay_cmd_set_checkpoint_nv),
+        "vkCmdSetCoarseSampleOrderNV" => (
+            &[b"vkCmdSetCoarseSampleOrderNV\0"],
+            replay_cmd_set_coarse_sample_order_nv,
+        ),
+        "vkCmdSetColorBlendAdvancedEXT" => (
+            &[b"vkCmdSetColorBlendAdvancedEXT\0"],
+            replay_cmd_set_color_blend_advanced_ext,
+        ),
+        "vkCmdSetColorBlendEnableEXT" => (
+            &[b"vkCmdSetColorBlendEnableEXT\0"],
+            replay_cmd_set_color_blend_enable_ext,
+        ),
+        "vkCmdSetColorBlendEquationEXT" => (
+            &[b"vkCmdSetColorBlendEquationEXT\0"],
+            replay_cmd_set_color_blend_equation_ext,
+        ),
+        "vkCmdSetColorWriteEnableEXT" => (
+            &[b"vkCmdSetColorWriteEnableEXT\0"],
+            replay_cmd_set_color_write_enable_ext,
+        ),
+        "vkCmdSetColorWriteMaskEXT" => (
+            &[b"vkCmdSetColorWriteMaskEXT\0"],
+            replay_cmd_set_color_write_mask_ext,
+        ),
+        "vkCmdSetConservativeRasterizationModeEXT" => (
+            &[b"vkCmdSetConservativeRasterizationModeEXT\0"],
+            replay_cmd_set_conservative_rasterization_mode_ext,
+        ),
+        "vkCmdSetCoverageModulationModeNV" => (
+            &[b"vkCmdSetCoverageModulationModeNV\0"],
+            replay_cmd_set_coverage_modulation_mode_nv,
+        ),
+        "vkCmdSetCoverageModulationTableEnableNV" => (
+            &[b"vkCmdSetCoverageModulationTableEnableNV\0"],
+            replay_cmd_set_coverage_modulation_table_enable_nv,
+        ),
+        "vkCmdSetCoverageModulationTableNV" => (
+            &[b"vkCmdSetCoverageModulationTableNV\0"],
+            replay_cmd_set_coverage_modulation_table_nv,
+        ),
+        "vkCmdSetCoverageReductionModeNV" => (
+            &[b"vkCmdSetCoverageReductionModeNV\0"],
+            replay_cmd_set_coverage_reduction_mode_nv,
+        ),
+        "vkCmdSetCoverageToColorEnableNV" => (
+            &[b"vkCmdSetCoverageToColorEnableNV\0"],
+            replay_cmd_set_coverage_to_color_enable_nv,
+        ),
+        "vkCmdSetCoverageToColorLocationNV" => (
+            &[b"vkCmdSetCoverageToColorLocationNV\0"],
+            replay_cmd_set_coverage_to_color_location_nv,
+        ),
+        "vkCmdSetCullMode" => (
+            &[b"vkCmdSetCullMode\0", b"vkCmdSetCullModeEXT\0"],
+            replay_cmd_set_cull_mode,
+        ),
+        "vkCmdSetDepthBias" => (&[b"vkCmdSetDepthBias\0"], replay_cmd_set_depth_bias),
+        "vkCmdSetDepthBias2EXT" => (
+            &[b"vkCmdSetDepthBias2EXT\0"],
+            replay_cmd_set_depth_bias2_ext,
+        ),
+        "vkCmdSetDepthBiasEnable" => (
+            &[
+                b"vkCmdSetDepthBiasEnable\0",
+                b"vkCmdSetDepthBiasEnableEXT\0",
+            ],
+            replay_cmd_set_depth_bias_enable,
+        ),
+        "vkCmdSetDepthBounds" => (&[b"vkCmdSetDepthBounds\0"], replay_cmd_set_depth_bounds),
+        "vkCmdSetDepthBoundsTestEnable" => (
+            &[
+                b"vkCmdSetDepthBoundsTestEnable\0",
+                b"vkCmdSetDepthBoundsTestEnableEXT\0",
+            ],
+            replay_cmd_set_depth_bounds_test_enable,
+        ),
+        "vkCmdSetDepthClampEnableEXT" => (
+            &[b"vkCmdSetDepthClampEnableEXT\0"],
+            replay_cmd_set_depth_clamp_enable_ext,
+        ),
+        "vkCmdSetDepthClipEnableEXT" => (
+            &[b"vkCmdSetDepthClipEnableEXT\0"],
+            replay_cmd_set_depth_clip_enable_ext,
+        ),
+        "vkCmdSetDepthClipNegativeOneToOneEXT" => (
+            &[b"vkCmdSetDepthClipNegativeOneToOneEXT\0"],
+            replay_cmd_set_depth_clip_negative_one_to_one_ext,
+        ),
+        "vkCmdSetDepthCompareOp" => (
+            &[b"vkCmdSetDepthCompareOp\0", b"vkCmdSetDepthCompareOpEXT\0"],
+            replay_cmd_set_depth_compare_op,
+        ),
+        "vkCmdSetDepthTestEnable" => (
+            &[
+                b"vkCmdSetDepthTestEnable\0",
+                b"vkCmdSetDepthTestEnableEXT\0",
+            ],
+            replay_cmd_set_depth_test_enable,
+        ),
+        "vkCmdSetDepthWriteEnable" => (
+            &[
+                b"vkCmdSetDepthWriteEnable\0",
+                b"vkCmdSetDepthWriteEnableEXT\0",
+            ],
+            replay_cmd_set_depth_write_enable,
+        ),
+        "vkCmdSetDescriptorBufferOffsetsEXT" => (
+            &[b"vkCmdSetDescriptorBufferOffsetsEXT\0"],
+            replay_cmd_set_descriptor_buffer_offsets_ext,
+        ),
+        "vkCmdSetDeviceMask" => (
+            &[b"vkCmdSetDeviceMask\0", b"vkCmdSetDeviceMaskKHR\0"],
+            replay_cmd_set_device_mask,
+        ),
+        "vkCmdSetDiscardRectangleEXT" => (
+            &[b"vkCmdSetDiscardRectangleEXT\0"],
+            replay_cmd_set_discard_rectangle_ext,
+        ),
+        "vkCmdSetDiscardRectangleEnableEXT" => (
+            &[b"vkCmdSetDiscardRectangleEnableEXT\0"],
+            replay_cmd_set_discard_rectangle_enable_ext,
+        ),
+        "vkCmdSetDiscardRectangleModeEXT" => (
+            &[b"vkCmdSetDiscardRectangleModeEXT\0"],
+            replay_cmd_set_discard_rectangle_mode_ext,
+        ),
+        "vkCmdSetEvent" => (&[b"vkCmdSetEvent\0"], replay_cmd_set_event),
+        "vkCmdSetEvent2" => (
+            &[b"vkCmdSetEvent2\0", b"vkCmdSetEvent2KHR\0"],
+            replay_cmd_set_event2,
+        ),
+        "vkCmdSetExclusiveScissorEnableNV" => (
+            &[b"vkCmdSetExclusiveScissorEnableNV\0"],
+            replay_cmd_set_exclusive_scissor_enable_nv,
+        ),
+        "vkCmdSetExclusiveScissorNV" => (
+            &[b"vkCmdSetExclusiveScissorNV\0"],
+            replay_cmd_set_exclusive_scissor_nv,
+        ),
+        "vkCmdSetExtraPrimitiveOverestimationSizeEXT" => (
+            &[b"vkCmdSetExtraPrimitiveOverestimationSizeEXT\0"],
+            replay_cmd_set_extra_primitive_overestimation_size_ext,
+        ),
+        "vkCmdSetFragmentShadingRateEnumNV" => (
+            &[b"vkCmdSetFragmentShadingRateEnumNV\0"],
+            replay_cmd_set_fragment_shading_rate_enum_nv,
+        ),
+        "vkCmdSetFragmentShadingRateKHR" => (
+            &[b"vkCmdSetFragmentShadingRateKHR\0"],
+            replay_cmd_set_fragment_shading_rate_khr,
+        ),
+        "vkCmdSetFrontFace" => (
+            &[b"vkCmdSetFrontFace\0", b"vkCmdSetFrontFaceEXT\0"],
+            replay_cmd_set_front_face,
+        ),
+        "vkCmdSetLineRasterizationModeEXT" => (
+            &[b"vkCmdSetLineRasterizationModeEXT\0"],
+            replay_cmd_set_line_rasterization_mode_ext,
+        ),
+        "vkCmdSetLineStippleEXT" => (
+            &[b"vkCmdSetLineStippleEXT\0"],
+            replay_cmd_set_line_stipple_ext,
+        ),
+        "vkCmdSetLineStippleEnableEXT" => (
+            &[b"vkCmdSetLineStippleEnableEXT\0"],
+            replay_cmd_set_line_stipple_enable_ext,
+        ),
+        "vkCmdSetLineWidth" => (&[b"vkCmdSetLineWidth\0"], replay_cmd_set_line_width),
+        "vkCmdSetLogicOpEXT" => (&[b"vkCmdSetLogicOpEXT\0"], replay_cmd_set_logic_op_ext),
+        "vkCmdSetLogicOpEnableEXT" => (
+            &[b"vkCmdSetLogicOpEnableEXT\0"],
+            replay_cmd_set_logic_op_enable_ext,
+        ),
+        "vkCmdSetPatchControlPointsEXT" => (
+            &[b"vkCmdSetPatchControlPointsEXT\0"],
+            replay_cmd_set_patch_control_points_ext,
+        ),
+        "vkCmdSetPerformanceMarkerINTEL" => (
+            &[b"vkCmdSetPerformanceMarkerINTEL\0"],
+            replay_cmd_set_performance_marker_intel,
+        ),
+        "vkCmdSetPerformanceOverrideINTEL" => (
+            &[b"vkCmdSetPerformanceOverrideINTEL\0"],
+            replay_cmd_set_performance_override_intel,
+        ),
+        "vkCmdSetPerformanceStreamMarkerINTEL" => (
+            &[b"vkCmdSetPerformanceStreamMarkerINTEL\0"],
+            replay_cmd_set_performance_stream_marker_intel,
+        ),
+        "vkCmdSetPolygonModeEXT" => (
+            &[b"vkCmdSetPolygonModeEXT\0"],
+            replay_cmd_set_polygon_mode_ext,
+        ),
+        "vkCmdSetPrimitiveRestartEnable" => (
+            &[
+                b"vkCmdSetPrimitiveRestartEnable\0",
+                b"vkCmdSetPrimitiveRestartEnableEXT\0",
+            ],
+            replay_cmd_set_primitive_restart_enable,
+        ),
+        "vkCmdSetPrimitiveTopology" => (
+            &[
+                b"vkCmdSetPrimitiveTopology\0",
+                b"vkCmdSetPrimitiveTopologyEXT\0",
+            ],
+            replay_cmd_set_primitive_topology,
+        ),
+        "vkCmdSetProvokingVertexModeEXT" => (
+            &[b"vkCmdSetProvokingVertexModeEXT\0"],
+            replay_cmd_set_provoking_vertex_mode_ext,
+        ),
+        "vkCmdSetRasterizationSamplesEXT" => (
+            &[b"vkCmdSetRasterizationSamplesEXT\0"],
+            replay_cmd_set_rasterization_samples_ext,
+        ),
+        "vkCmdSetRasterizationStreamEXT" => (
+            &[b"vkCmdSetRasterizationStreamEXT\0"],
+            replay_cmd_set_rasterization_stream_ext,
+        ),
+        "vkCmdSetRasterizerDiscardEnable" => (
+            &[
+                b"vkCmdSetRasterizerDiscardEnable\0",
+                b"vkCmdSetRasterizerDiscardEnableEXT\0",
+            ],
+            replay_cmd_set_rasterizer_discard_enable,
+        ),
+        "vkCmdSetRayTracingPipelineStackSizeKHR" => (
+            &[b"vkCmdSetRayTracingPipelineStackSizeKHR\0"],
+            replay_cmd_set_ray_tracing_pipeline_stack_size_khr,
+        ),
+        "vkCmdSetRepresentativeFragmentTestEnableNV" => (
+            &[b"vkCmdSetRepresentativeFragmentTestEnableNV\0"],
+            replay_cmd_set_representative_fragment_test_enable_nv,
+        ),
+        "vkCmdSetSampleLocationsEXT" => (
+            &[b"vkCmdSetSampleLocationsEXT\0"],
+            replay_cmd_set_sample_locations_ext,
+        ),
+        "vkCmdSetSampleLocationsEnableEXT" => (
+            &[b"vkCmdSetSampleLocationsEnableEXT\0"],
+            replay_cmd_set_sample_locations_enable_ext,
+        ),
+        "vkCmdSetSampleMaskEXT" => (
+            &[b"vkCmdSetSampleMaskEXT\0"],
+            replay_cmd_set_sample_mask_ext,
+        ),
+        "vkCmdSetScissor" => (&[b"vkCmdSetScissor\0"], replay_cmd_set_scissor),
+        "vkCmdSetScissorWithCount" => (
+            &[
+                b"vkCmdSetScissorWithCount\0",
+                b"vkCmdSetScissorWithCountEXT\0",
+            ],
+            replay_cmd_set_scissor_with_count,
+        ),
+        "vkCmdSetShadingRateImageEnableNV" => (
+            &[b"vkCmdSetShadingRateImageEnableNV\0"],
+            replay_cmd_set_shading_rate_image_enable_nv,
+        ),
+        "vkCmdSetStencilCompareMask" => (
+            &[b"vkCmdSetStencilCompareMask\0"],
+            replay_cmd_set_stencil_compare_mask,
+        ),
+        "vkCmdSetStencilOp" => (
+            &[b"vkCmdSetStencilOp\0", b"vkCmdSetStencilOpEXT\0"],
+            replay_cmd_set_stencil_op,
+        ),
+        "vkCmdSetStencilReference" => (
+            &[b"vkCmdSetStencilReference\0"],
+            replay_cmd_set_stencil_reference,
+        ),
+        "vkCmdSetStencilTestEnable" => (
+            &[
+                b"vkCmdSetStencilTestEnable\0",
+                b"vkCmdSetStencilTestEnableEXT\0",
+            ],
+            replay_cmd_set_stencil_test_enable,
+        ),
+        "vkCmdSetStencilWriteMask" => (
+            &[b"vkCmdSetStencilWriteMask\0"],
+            replay_cmd_set_stencil_write_mask,
+        ),
+        "vkCmdSetTessellationDomainOriginEXT" => (
+            &[b"vkCmdSetTessellationDomainOriginEXT\0"],
+            replay_cmd_set_tessellation_domain_origin_ext,
+        ),
+        "vkCmdSetVertexInputEXT" => (
+            &[b"vkCmdSetVertexInputEXT\0"],
+            replay_cmd_set_vertex_input_ext,
+        ),
+        "vkCmdSetViewport" => (&[b"vkCmdSetViewport\0"], replay_cmd_set_viewport),
+        "vkCmdSetViewportShadingRatePaletteNV" => (
+            &[b"vkCmdSetViewportShadingRatePaletteNV\0"],
+            replay_cmd_set_viewport_shading_rate_palette_nv,
+        ),
+        "vkCmdSetViewportSwizzleNV" => (
+            &[b"vkCmdSetViewportSwizzleNV\0"],
+            replay_cmd_set_viewport_swizzle_nv,
+        ),
+        "vkCmdSetViewportWScalingEnableNV" => (
+            &[b"vkCmdSetViewportWScalingEnableNV\0"],
+            replay_cmd_set_viewport_w_scaling_enable_nv,
+        ),
+        "vkCmdSetViewportWScalingNV" => (
+            &[b"vkCmdSetViewportWScalingNV\0"],
+            replay_cmd_set_viewport_w_scaling_nv,
+        ),
+        "vkCmdSetViewportWithCount" => (
+            &[
+                b"vkCmdSetViewportWithCount\0",
+                b"vkCmdSetViewportWithCountEXT\0",
+            ],
+            replay_cmd_set_viewport_with_count,
+        ),
+        "vkCmdSubpassShadingHUAWEI" => (
+            &[b"vkCmdSubpassShadingHUAWEI\0"],
+            replay_cmd_subpass_shading_huawei,
+        ),
+        "vkCmdTraceRaysIndirect2KHR" => (
+            &[b"vkCmdTraceRaysIndirect2KHR\0"],
+            replay_cmd_trace_rays_indirect2_khr,
+        ),
+        "vkCmdTraceRaysIndirectKHR" => (
+            &[b"vkCmdTraceRaysIndirectKHR\0"],
+            replay_cmd_trace_rays_indirect_khr,
+        ),
+        "vkCmdTraceRaysKHR" => (&[b"vkCmdTraceRaysKHR\0"], replay_cmd_trace_rays_khr),
+        "vkCmdTraceRaysNV" => (&[b"vkCmdTraceRaysNV\0"], replay_cmd_trace_rays_nv),
+        "vkCmdUpdateBuffer" => (&[b"vkCmdUpdateBuffer\0"], replay_cmd_update_buffer),
+        "vkCmdWaitEvents" => (&[b"vkCmdWaitEvents\0"], replay_cmd_wait_events),
+        "vkCmdWaitEvents2" => (
+            &[b"vkCmdWaitEvents2\0", b"vkCmdWaitEvents2KHR\0"],
+            replay_cmd_wait_events2,
+        ),
+        "vkCmdWriteAccelerationStructuresPropertiesKHR" => (
+            &[b"vkCmdWriteAccelerationStructuresPropertiesKHR\0"],
+            replay_cmd_write_acceleration_structures_properties_khr,
+        ),
+        "vkCmdWriteAccelerationStructuresPropertiesNV" => (
+            &[b"vkCmdWriteAccelerationStructuresPropertiesNV\0"],
+            replay_cmd_write_acceleration_structures_properties_nv,
+        ),
+        "vkCmdWriteBufferMarker2AMD" => (
+            &[b"vkCmdWriteBufferMarker2AMD\0"],
+            replay_cmd_write_buffer_marker2_amd,
+        ),
+        "vkCmdWriteBufferMarkerAMD" => (
+            &[b"vkCmdWriteBufferMarkerAMD\0"],
+            replay_cmd_write_buffer_marker_amd,
+        ),
+        "vkCmdWriteMicromapsPropertiesEXT" => (
+            &[b"vkCmdWriteMicromapsPropertiesEXT\0"],
+            replay_cmd_write_micromaps_properties_ext,
+        ),
+        "vkCmdWriteTimestamp" => (&[b"vkCmdWriteTimestamp\0"], replay_cmd_write_timestamp),
+        "vkCmdWriteTimestamp2" => (
+            &[b"vkCmdWriteTimestamp2\0", b"vkCmdWriteTimestamp2KHR\0"],
+            replay_cmd_write_timestamp2,
+        ),
+        "vkCompileDeferredNV" => (&[b"vkCompileDeferredNV\0"], replay_compile_deferred_nv),
+        "vkCopyAccelerationStructureKHR" => (
+            &[b"vkCopyAccelerationStructureKHR\0"],
+            replay_copy_acceleration_structure_khr,
+        ),
+        "vkCopyAccelerationStructureToMemoryKHR" => (
+            &[b"vkCopyAccelerationStructureToMemoryKHR\0"],
+            replay_copy_acceleration_structure_to_memory_khr,
+        ),
+        "vkCopyMemoryToAccelerationStructureKHR" => (
+            &[b"vkCopyMemoryToAccelerationStructureKHR\0"],
+            replay_copy_memory_to_acceleration_structure_khr,
+        ),
+        "vkCopyMemoryToMicromapEXT" => (
+            &[b"vkCopyMemoryToMicromapEXT\0"],
+            replay_copy_memory_to_micromap_ext,
+        ),
+        "vkCopyMicromapEXT" => (&[b"vkCopyMicromapEXT\0"], replay_copy_micromap_ext),
+        "vkCopyMicromapToMemoryEXT" => (
+            &[b"vkCopyMicromapToMemoryEXT\0"],
+            replay_copy_micromap_to_memory_ext,
+        ),
+        "vkCreateAccelerationStructureKHR" => (
+            &[b"vkCreateAccelerationStructureKHR\0"],
+            replay_create_acceleration_structure_khr,
+        ),
+        "vkCreateAccelerationStructureNV" => (
+            &[b"vkCreateAccelerationStructureNV\0"],
+            replay_create_acceleration_structure_nv,
+        ),
+        "vkCreateBuffer" => (&[b"vkCreateBuffer\0"], replay_create_buffer),
+        "vkCreateBufferCollectionFUCHSIA" => (
+            &[b"vkCreateBufferCollectionFUCHSIA\0"],
+            replay_create_buffer_collection_fuchsia,
+        ),
+        "vkCreateBufferView" => (&[b"vkCreateBufferView\0"], replay_create_buffer_view),
+        "vkCreateCommandPool" => (&[b"vkCreateCommandPool\0"], replay_create_command_pool),
+        "vkCreateComputePipelines" => (
+            &[b"vkCreateComputePipelines\0"],
+            replay_create_compute_pipelines,
+        ),
+        "vkCreateCuFunctionNVX" => (&[b"vkCreateCuFunctionNVX\0"], replay_create_cu_function_nvx),
+        "vkCreateCuModuleNVX" => (&[b"vkCreateCuModuleNVX\0"], replay_create_cu_module_nvx),
+        "vkCreateDeferredOperationKHR" => (
+            &[b"vkCreateDeferredOperationKHR\0"],
+            replay_create_deferred_operation_khr,
+        ),
+        "vkCreateDescriptorPool" => (
+            &[b"vkCreateDescriptorPool\0"],
+            replay_create_descriptor_pool,
+        ),
+        "vkCreateDescriptorSetLayout" => (
+            &[b"vkCreateDescriptorSetLayout\0"],
+            replay_create_descriptor_set_layout,
+        ),
+        "vkCreateDescriptorUpdateTemplate" => (
+            &[
+                b"vkCreateDescriptorUpdateTemplate\0",
+                b"vkCreateDescriptorUpdateTemplateKHR\0",
+            ],
+            replay_create_descriptor_update_template,
+        ),
+        "vkCreateEvent" => (&[b"vkCreateEvent\0"], replay_create_event),
+        "vkCreateFence" => (&[b"vkCreateFence\0"], replay_create_fence),
+        "vkCreateFramebuffer" => (&[b"vkCreateFramebuffer\0"], replay_create_framebuffer),
+        "vkCreateGraphicsPipelines" => (
+            &[b"vkCreateGraphicsPipelines\0"],
+            replay_create_graphics_pipelines,
+        ),
+        "vkCreateImage" => (&[b"vkCreateImage\0"], replay_create_image),
+        "vkCreateImageView" => (&[b"vkCreateImageView\0"], replay_create_image_view),
+        "vkCreateIndirectCommandsLayoutNV" => (
+            &[b"vkCreateIndirectCommandsLayoutNV\0"],
+            replay_create_indirect_commands_layout_nv,
+        ),
+        "vkCreateMicromapEXT" => (&[b"vkCreateMicromapEXT\0"], replay_create_micromap_ext),
+        "vkCreateOpticalFlowSessionNV" => (
+            &[b"vkCreateOpticalFlowSessionNV\0"],
+            replay_create_optical_flow_session_nv,
+        ),
+        "vkCreatePipelineCache" => (&[b"vkCreatePipelineCache\0"], replay_create_pipeline_cache),
+        "vkCreatePipelineLayout" => (
+            &[b"vkCreatePipelineLayout\0"],
+            replay_create_pipeline_layout,
+        ),
+        "vkCreatePrivateDataSlot" => (
+            &[
+                b"vkCreatePrivateDataSlot\0",
+                b"vkCreatePrivateDataSlotEXT\0",
+            ],
+            replay_create_private_data_slot,
+        ),
+        "vkCreateQueryPool" => (&[b"vkCreateQueryPool\0"], replay_create_query_pool),
+        "vkCreateRayTracingPipelinesKHR" => (
+            &[b"vkCreateRayTracingPipelinesKHR\0"],
+            replay_create_ray_tracing_pipelines_khr,
+        ),
+        "vkCreateRayTracingPipelinesNV" => (
+            &[b"vkCreateRayTracingPipelinesNV\0"],
+            replay_create_ray_tracing_pipelines_nv,
+        ),
+        "vkCreateRenderPass" => (&[b"vkCreateRenderPass\0"], replay_create_render_pass),
+        "vkCreateRenderPass2" => (
+            &[b"vkCreateRenderPass2\0", b"vkCreateRenderPass2KHR\0"],
+            replay_create_render_pass2,
+        ),
+        "vkCreateSampler" => (&[b"vkCreateSampler\0"], replay_create_sampler),
+        "vkCreateSamplerYcbcrConversion" => (
+            &[
+                b"vkCreateSamplerYcbcrConversion\0",
+                b"vkCreateSamplerYcbcrConversionKHR\0",
+            ],
+            replay_create_sampler_ycbcr_conversion,
+        ),
+        "vkCreateSemaphore" => (&[b"vkCreateSemaphore\0"], replay_create_semaphore),
+        "vkCreateShaderModule" => (&[b"vkCreateShaderModule\0"], replay_create_shader_module),
+        "vkCreateShadersEXT" => (&[b"vkCreateShadersEXT\0"], replay_create_shaders_ext),
+        "vkCreateSharedSwapchainsKHR" => (
+            &[b"vkCreateSharedSwapchainsKHR\0"],
+            replay_create_shared_swapchains_khr,
+        ),
+        "vkCreateSwapchainKHR" => (&[b"vkCreateSwapchainKHR\0"], replay_create_swapchain_khr),
+        "vkCreateValidationCacheEXT" => (
+            &[b"vkCreateValidationCacheEXT\0"],
+            replay_create_validation_cache_ext,
+        ),
+        "vkCreateVideoSessionKHR" => (
+            &[b"vkCreateVideoSessionKHR\0"],
+            replay_create_video_session_khr,
+        ),
+        "vkCreateVideoSessionParametersKHR" => (
+            &[b"vkCreateVideoSessionParametersKHR\0"],
+            replay_create_video_session_parameters_khr,
+        ),
+        "vkDebugMarkerSetObjectNameEXT" => (
+            &[b"vkDebugMarkerSetObjectNameEXT\0"],
+            replay_debug_marker_set_object_name_ext,
+        ),
+        "vkDebugMarkerSetObjectTagEXT" => (
+            &[b"vkDebugMarkerSetObjectTagEXT\0"],
+            replay_debug_marker_set_object_tag_ext,
+        ),
+        "vkDeferredOperationJoinKHR" => (
+            &[b"vkDeferredOperationJoinKHR\0"],
+            replay_deferred_operation_join_khr,
+        ),
+        "vkDestroyAccelerationStructureKHR" => (
+            &[b"vkDestroyAccelerationStructureKHR\0"],
+            replay_destroy_acceleration_structure_khr,
+        ),
+        "vkDestroyAccelerationStructureNV" => (
+            &[b"vkDestroyAccelerationStructureNV\0"],
+            replay_destroy_acceleration_structure_nv,
+        ),
+        "vkDestroyBuffer" => (&[b"vkDestroyBuffer\0"], replay_destroy_buffer),
+        "vkDestroyBufferCollectionFUCHSIA" => (
+            &[b"vkDestroyBufferCollectionFUCHSIA\0"],
+            replay_destroy_buffer_collection_fuchsia,
+        ),
+        "vkDestroyBufferView" => (&[b"vkDestroyBufferView\0"], replay_destroy_buffer_view),
+        "vkDestroyCommandPool" => (&[b"vkDestroyCommandPool\0"], replay_destroy_command_pool),
+        "vkDestroyCuFunctionNVX" => (
+            &[b"vkDestroyCuFunctionNVX\0"],
+            replay_destroy_cu_function_nvx,
+        ),
+        "vkDestroyCuModuleNVX" => (&[b"vkDestroyCuModuleNVX\0"], replay_destroy_cu_module_nvx),
+        "vkDestroyDeferredOperationKHR" => (
+            &[b"vkDestroyDeferredOperationKHR\0"],
+            replay_destroy_deferred_operation_khr,
+        ),
+        "vkDestroyDescriptorPool" => (
+            &[b"vkDestroyDescriptorPool\0"],
+            replay_destroy_descriptor_pool,
+        ),
+        "vkDestroyDescriptorSetLayout" => (
+            &[b"vkDestroyDescriptorSetLayout\0"],
+            replay_destroy_descriptor_set_layout,
+        ),
+        "vkDestroyDescriptorUpdateTemplate" => (
+            &[
+                b"vkDestroyDescriptorUpdateTemplate\0",
+                b"vkDestroyDescriptorUpdateTemplateKHR\0",
+            ],
+            replay_destroy_descriptor_update_template,
+        ),
+        "vkDestroyDevice" => (&[b"vkDestroyDevice\0"], replay_destroy_device),
+        "vkDestroyEvent" => (&[b"vkDestroyEvent\0"], replay_destroy_event),
+        "vkDestroyFence" => (&[b"vkDestroyFence\0"], replay_destroy_fence),
+        "vkDestroyFramebuffer" => (&[b"vkDestroyFramebuffer\0"], replay_destroy_framebuffer),
+        "vkDestroyImage" => (&[b"vkDestroyImage\0"], replay_destroy_image),
+        "vkDestroyImageView" => (&[b"vkDestroyImageView\0"], replay_destroy_image_view),
+        "vkDestroyIndirectCommandsLayoutNV" => (
+            &[b"vkDestroyIndirectCommandsLayoutNV\0"],
+            replay_destroy_indirect_commands_layout_nv,
+        ),
+        "vkDestroyMicromapEXT" => (&[b"vkDestroyMicromapEXT\0"], replay_destroy_micromap_ext),
+        "vkDestroyOpticalFlowSessionNV" => (
+            &[b"vkDestroyOpticalFlowSessionNV\0"],
+            replay_destroy_optical_flow_session_nv,
+        ),
+        "vkDestroyPipeline" => (&[b"vkDestroyPipeline\0"], replay_destroy_pipeline),
+        "vkDestroyPipelineCache" => (
+            &[b"vkDestroyPipelineCache\0"],
+            replay_destroy_pipeline_cache,
+        ),
+        "vkDestroyPipelineLayout" => (
+            &[b"vkDestroyPipelineLayout\0"],
+            replay_destroy_pipeline_layout,
+        ),
+        "vkDestroyPrivateDataSlot" => (
+            &[
+                b"vkDestroyPrivateDataSlot\0",
+                b"vkDestroyPrivateDataSlotEXT\0",
+            ],
+            replay_destroy_private_data_slot,
+        ),
+        "vkDestroyQueryPool" => (&[b"vkDestroyQueryPool\0"], replay_destroy_query_pool),
+        "vkDestroyRenderPass" => (&[b"vkDestroyRenderPass\0"], replay_destroy_render_pass),
+        "vkDestroySampler" => (&[b"vkDestroySampler\0"], replay_destroy_sampler),
+        "vkDestroySamplerYcbcrConversion" => (
+            &[
+                b"vkDestroySamplerYcbcrConversion\0",
+                b"vkDestroySamplerYcbcrConversionKHR\0",
+            ],
+            replay_destroy_sampler_ycbcr_conversion,
+        ),
+        "vkDestroySemaphore" => (&[b"vkDestroySemaphore\0"], replay_destroy_semaphore),
+        "vkDestroyShaderEXT" => (&[b"vkDestroyShaderEXT\0"], replay_destroy_shader_ext),
+        "vkDestroyShaderModule" => (&[b"vkDestroyShaderModule\0"], replay_destroy_shader_module),
+        "vkDestroySwapchainKHR" => (&[b"vkDestroySwapchainKHR\0"], replay_destroy_swapchain_khr),
+        "vkDestroyValidationCacheEXT" => (
+            &[b"vkDestroyValidationCacheEXT\0"],
+            replay_destroy_validation_cache_ext,
+        ),
+        "vkDestroyVideoSessionKHR" => (
+            &[b"vkDestroyVideoSessionKHR\0"],
+            replay_destroy_video_session_khr,
+        ),
+        "vkDestroyVideoSessionParametersKHR" => (
+            &[b"vkDestroyVideoSessionParametersKHR\0"],
+            replay_destroy_video_session_parameters_khr,
+        ),
+        "vkDeviceWaitIdle" => (&[b"vkDeviceWaitIdle\0"], replay_device_wait_idle),
+        "vkDisplayPowerControlEXT" => (
+            &[b"vkDisplayPowerControlEXT\0"],
+            replay_display_power_control_ext,
+        ),
+        "vkEndCommandBuffer" => (&[b"vkEndCommandBuffer\0"], replay_end_command_buffer),
+        "vkExportMetalObjectsEXT" => (
+            &[b"vkExportMetalObjectsEXT\0"],
+            replay_export_metal_objects_ext,
+        ),
+        "vkFlushMappedMemoryRanges" => (
+            &[b"vkFlushMappedMemoryRanges\0"],
+            replay_flush_mapped_memory_ranges,
+        ),
+        "vkFreeCommandBuffers" => (&[b"vkFreeCommandBuffers\0"], replay_free_command_buffers),
+        "vkFreeDescriptorSets" => (&[b"vkFreeDescriptorSets\0"], replay_free_descriptor_sets),
+        "vkFreeMemory" => (&[b"vkFreeMemory\0"], replay_free_memory),
+        "vkGetAccelerationStructureBuildSizesKHR" => (
+            &[b"vkGetAccelerationStructureBuildSizesKHR\0"],
+            replay_get_acceleration_structure_build_sizes_khr,
+        ),
+        "vkGetAccelerationStructureDeviceAddressKHR" => (
+            &[b"vkGetAccelerationStructureDeviceAddressKHR\0"],
+            replay_get_acceleration_structure_device_address_khr,
+        ),
+        "vkGetAccelerationStructureHandleNV" => (
+            &[b"vkGetAccelerationStructureHandleNV\0"],
+            replay_get_acceleration_structure_handle_nv,
+        ),
+        "vkGetAccelerationStructureMemoryRequirementsNV" => (
+            &[b"vkGetAccelerationStructureMemoryRequirementsNV\0"],
+            replay_get_acceleration_structure_memory_requirements_nv,
+        ),
+        "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT" => (
+            &[b"vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT\0"],
+            replay_get_acceleration_structure_opaque_capture_descriptor_data_ext,
+        ),
+        "vkGetAndroidHardwareBufferPropertiesANDROID" => (
+            &[b"vkGetAndroidHardwareBufferPropertiesANDROID\0"],
+            replay_get_android_hardware_buffer_properties_android,
+        ),
+        "vkGetBufferCollectionPropertiesFUCHSIA" => (
+            &[b"vkGetBufferCollectionPropertiesFUCHSIA\0"],
+            replay_get_buffer_collection_properties_fuchsia,
+        ),
+        "vkGetBufferDeviceAddress" => (
+            &[
+                b"vkGetBufferDeviceAddress\0",
+                b"vkGetBufferDeviceAddressEXT\0",
+                b"vkGetBufferDeviceAddressKHR\0",
+            ],
+            replay_get_buffer_device_address,
+        ),
+        "vkGetBufferMemoryRequirements" => (
+            &[b"vkGetBufferMemoryRequirements\0"],
+            replay_get_buffer_memory_requirements,
+        ),
+        "vkGetBufferMemoryRequirements2" => (
+            &[
+                b"vkGetBufferMemoryRequirements2\0",
+                b"vkGetBufferMemoryRequirements2KHR\0",
+            ],
+            replay_get_buffer_memory_requirements2,
+        ),
+        "vkGetBufferOpaqueCaptureAddress" => (
+            &[
+                b"vkGetBufferOpaqueCaptureAddress\0",
+                b"vkGetBufferOpaqueCaptureAddressKHR\0",
+            ],
+            replay_get_buffer_opaque_capture_address,
+        ),
+        "vkGetBufferOpaqueCaptureDescriptorDataEXT" => (
+            &[b"vkGetBufferOpaqueCaptureDescriptorDataEXT\0"],
+            replay_get_buffer_opaque_capture_descriptor_data_ext,
+        ),
+        "vkGetCalibratedTimestampsEXT" => (
+            &[b"vkGetCalibratedTimestampsEXT\0"],
+            replay_get_calibrated_timestamps_ext,
+        ),
+        "vkGetDeferredOperationMaxConcurrencyKHR" => (
+            &[b"vkGetDeferredOperationMaxConcurrencyKHR\0"],
+            replay_get_deferred_operation_max_concurrency_khr,
+        ),
+        "vkGetDeferredOperationResultKHR" => (
+            &[b"vkGetDeferredOperationResultKHR\0"],
+            replay_get_deferred_operation_result_khr,
+        ),
+        "vkGetDescriptorEXT" => (&[b"vkGetDescriptorEXT\0"], replay_get_descriptor_ext),
+        "vkGetDescriptorSetHostMappingVALVE" => (
+            &[b"vkGetDescriptorSetHostMappingVALVE\0"],
+            replay_get_descriptor_set_host_mapping_valve,
+        ),
+        "vkGetDescriptorSetLayoutBindingOffsetEXT" => (
+            &[b"vkGetDescriptorSetLayoutBindingOffsetEXT\0"],
+            replay_get_descriptor_set_layout_binding_offset_ext,
+        ),
+        "vkGetDescriptorSetLayoutHostMappingInfoVALVE" => (
+            &[b"vkGetDescriptorSetLayoutHostMappingInfoVALVE\0"],
+            replay_get_descriptor_set_layout_host_mapping_info_valve,
+        ),
+        "vkGetDescriptorSetLayoutSizeEXT" => (
+            &[b"vkGetDescriptorSetLayoutSizeEXT\0"],
+            replay_get_descriptor_set_layout_size_ext,
+        ),
+        "vkGetDescriptorSetLayoutSupport" => (
+            &[
+                b"vkGetDescriptorSetLayoutSupport\0",
+                b"vkGetDescriptorSetLayoutSupportKHR\0",
+            ],
+            replay_get_descriptor_set_layout_support,
+        ),
+        "vkGetDeviceAccelerationStructureCompatibilityKHR" => (
+            &[b"vkGetDeviceAccelerationStructureCompatibilityKHR\0"],
+            replay_get_device_acceleration_structure_compatibility_khr,
+        ),
+        "vkGetDeviceBufferMemoryRequirements" => (
+            &[
+                b"vkGetDeviceBufferMemoryRequirements\0",
+                b"vkGetDeviceBufferMemoryRequirementsKHR\0",
+            ],
+            replay_get_device_buffer_memory_requirements,
+        ),
+        "vkGetDeviceFaultInfoEXT" => (
+            &[b"vkGetDeviceFaultInfoEXT\0"],
+            replay_get_device_fault_info_ext,
+        ),
+        "vkGetDeviceGroupPeerMemoryFeatures" => (
+            &[
+                b"vkGetDeviceGroupPeerMemoryFeatures\0",
+                b"vkGetDeviceGroupPeerMemoryFeaturesKHR\0",
+            ],
+            replay_get_device_group_peer_memory_features,
+        ),
+        "vkGetDeviceGroupPresentCapabilitiesKHR" => (
+            &[b"vkGetDeviceGroupPresentCapabilitiesKHR\0"],
+            replay_get_device_group_present_capabilities_khr,
+        ),
+        "vkGetDeviceGroupSurfacePresentModes2EXT" => (
+            &[b"vkGetDeviceGroupSurfacePresentModes2EXT\0"],
+            replay_get_device_group_surface_present_modes2_ext,
+        ),
+        "vkGetDeviceGroupSurfacePresentModesKHR" => (
+            &[b"vkGetDeviceGroupSurfacePresentModesKHR\0"],
+            replay_get_device_group_surface_present_modes_khr,
+        ),
+        "vkGetDeviceImageMemoryRequirements" => (
+            &[
+                b"vkGetDeviceImageMemoryRequirements\0",
+                b"vkGetDeviceImageMemoryRequirementsKHR\0",
+            ],
+            replay_get_device_image_memory_requirements,
+        ),
+        "vkGetDeviceImageSparseMemoryRequirements" => (
+            &[
+                b"vkGetDeviceImageSparseMemoryRequirements\0",
+                b"vkGetDeviceImageSparseMemoryRequirementsKHR\0",
+            ],
+            replay_get_device_image_sparse_memory_requirements,
+        ),
+        "vkGetDeviceMemoryCommitment" => (
+            &[b"vkGetDeviceMemoryCommitment\0"],
+            replay_get_device_memory_commitment,
+        ),
+        "vkGetDeviceMemoryOpaqueCaptureAddress" => (
+            &[
+                b"vkGetDeviceMemoryOpaqueCaptureAddress\0",
+                b"vkGetDeviceMemoryOpaqueCaptureAddressKHR\0",
+            ],
+            replay_get_device_memory_opaque_capture_address,
+        ),
+        "vkGetDeviceMicromapCompatibilityEXT" => (
+            &[b"vkGetDeviceMicromapCompatibilityEXT\0"],
+            replay_get_device_micromap_compatibility_ext,
+        ),
+        "vkGetDeviceQueue" => (&[b"vkGetDeviceQueue\0"], replay_get_device_queue),
+        "vkGetDeviceQueue2" => (&[b"vkGetDeviceQueue2\0"], replay_get_device_queue2),
+        "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI" => (
+            &[b"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI\0"],
+            replay_get_device_subpass_shading_max_workgroup_size_huawei,
+        ),
+        "vkGetDynamicRenderingTilePropertiesQCOM" => (
+            &[b"vkGetDynamicRenderingTilePropertiesQCOM\0"],
+            replay_get_dynamic_rendering_tile_properties_qcom,
+        ),
+        "vkGetEncodedVideoSessionParametersKHR" => (
+            &[b"vkGetEncodedVideoSessionParametersKHR\0"],
+            replay_get_encoded_video_session_parameters_khr,
+        ),
+        "vkGetEventStatus" => (&[b"vkGetEventStatus\0"], replay_get_event_status),
+        "vkGetFenceFdKHR" => (&[b"vkGetFenceFdKHR\0"], replay_get_fence_fd_khr),
+        "vkGetFenceStatus" => (&[b"vkGetFenceStatus\0"], replay_get_fence_status),
+        "vkGetFenceWin32HandleKHR" => (
+            &[b"vkGetFenceWin32HandleKHR\0"],
+            replay_get_fence_win32_handle_khr,
+        ),
+        "vkGetFramebufferTilePropertiesQCOM" => (
+            &[b"vkGetFramebufferTilePropertiesQCOM\0"],
+            replay_get_framebuffer_tile_properties_qcom,
+        ),
+        "vkGetGeneratedCommandsMemoryRequirementsNV" => (
+            &[b"vkGetGeneratedCommandsMemoryRequirementsNV\0"],
+            replay_get_generated_commands_memory_requirements_nv,
+        ),
+        "vkGetImageDrmFormatModifierPropertiesEXT" => (
+            &[b"vkGetImageDrmFormatModifierPropertiesEXT\0"],
+            replay_get_image_drm_format_modifier_properties_ext,
+        ),
+        "vkGetImageMemoryRequirements" => (
+            &[b"vkGetImageMemoryRequirements\0"],
+            replay_get_image_memory_requirements,
+        ),
+        "vkGetImageMemoryRequirements2" => (
+            &[
+                b"vkGetImageMemoryRequirements2\0",
+                b"vkGetImageMemoryRequirements2KHR\0",
+            ],
+            replay_get_image_memory_requirements2,
+        ),
+        "vkGetImageOpaqueCaptureDescriptorDataEXT" => (
+            &[b"vkGetImageOpaqueCaptureDescriptorDataEXT\0"],
+            replay_get_image_opaque_capture_descriptor_data_ext,
+        ),
+        "vkGetImageSparseMemoryRequirements" => (
+            &[b"vkGetImageSparseMemoryRequirements\0"],
+            replay_get_image_sparse_memory_requirements,
+        ),
+        "vkGetImageSparseMemoryRequirements2" => (
+            &[
+                b"vkGetImageSparseMemoryRequirements2\0",
+                b"vkGetImageSparseMemoryRequirements2KHR\0",
+            ],
+            replay_get_image_sparse_memory_requirements2,
+        ),
+        "vkGetImageSubresourceLayout" => (
+            &[b"vkGetImageSubresourceLayout\0"],
+            replay_get_image_subresource_layout,
+        ),
+        "vkGetImageSubresourceLayout2EXT" => (
+            &[b"vkGetImageSubresourceLayout2EXT\0"],
+            replay_get_image_subresource_layout2_ext,
+        ),
+        "vkGetImageViewAddressNVX" => (
+            &[b"vkGetImageViewAddressNVX\0"],
+            replay_get_image_view_address_nvx,
+        ),
+        "vkGetImageViewHandleNVX" => (
+            &[b"vkGetImageViewHandleNVX\0"],
+            replay_get_image_view_handle_nvx,
+        ),
+        "vkGetImageViewOpaqueCaptureDescriptorDataEXT" => (
+            &[b"vkGetImageViewOpaqueCaptureDescriptorDataEXT\0"],
+            replay_get_image_view_opaque_capture_descriptor_data_ext,
+        ),
+        "vkGetMemoryAndroidHardwareBufferANDROID" => (
+            &[b"vkGetMemoryAndroidHardwareBufferANDROID\0"],
+            replay_get_memory_android_hardware_buffer_android,
+        ),
+        "vkGetMemoryFdKHR" => (&[b"vkGetMemoryFdKHR\0"], replay_get_memory_fd_khr),
+        "vkGetMemoryFdPropertiesKHR" => (
+            &[b"vkGetMemoryFdPropertiesKHR\0"],
+            replay_get_memory_fd_properties_khr,
+        ),
+        "vkGetMemoryHostPointerPropertiesEXT" => (
+            &[b"vkGetMemoryHostPointerPropertiesEXT\0"],
+            replay_get_memory_host_pointer_properties_ext,
+        ),
+        "vkGetMemoryRemoteAddressNV" => (
+            &[b"vkGetMemoryRemoteAddressNV\0"],
+            replay_get_memory_remote_address_nv,
+        ),
+        "vkGetMemoryWin32HandleKHR" => (
+            &[b"vkGetMemoryWin32HandleKHR\0"],
+            replay_get_memory_win32_handle_khr,
+        ),
+        "vkGetMemoryWin32HandleNV" => (
+            &[b"vkGetMemoryWin32HandleNV\0"],
+            replay_get_memory_win32_handle_nv,
+        ),
+        "vkGetMemoryWin32HandlePropertiesKHR" => (
+            &[b"vkGetMemoryWin32HandlePropertiesKHR\0"],
+            replay_get_memory_win32_handle_properties_khr,
+        ),
+        "vkGetMemoryZirconHandleFUCHSIA" => (
+            &[b"vkGetMemoryZirconHandleFUCHSIA\0"],
+            replay_get_memory_zircon_handle_fuchsia,
+        ),
+        "vkGetMemoryZirconHandlePropertiesFUCHSIA" => (
+            &[b"vkGetMemoryZirconHandlePropertiesFUCHSIA\0"],
+            replay_get_memory_zircon_handle_properties_fuchsia,
+        ),
+        "vkGetMicromapBuildSizesEXT" => (
+            &[b"vkGetMicromapBuildSizesEXT\0"],
+            replay_get_micromap_build_sizes_ext,
+        ),
+        "vkGetPastPresentationTimingGOOGLE" => (
+            &[b"vkGetPastPresentationTimingGOOGLE\0"],
+            replay_get_past_presentation_timing_google,
+        ),
+        "vkGetPerformanceParameterINTEL" => (
+            &[b"vkGetPerformanceParameterINTEL\0"],
+            replay_get_performance_parameter_intel,
+        ),
+        "vkGetPipelineCacheData" => (
+            &[b"vkGetPipelineCacheData\0"],
+            replay_get_pipeline_cache_data,
+        ),
+        "vkGetPipelineExecutableInternalRepresentationsKHR" => (
+            &[b"vkGetPipelineExecutableInternalRepresentationsKHR\0"],
+            replay_get_pipeline_executable_internal_representations_khr,
+        ),
+        "vkGetPipelineExecutablePropertiesKHR" => (
+            &[b"vkGetPipelineExecutablePropertiesKHR\0"],
+            replay_get_pipeline_executable_properties_khr,
+        ),
+        "vkGetPipelineExecutableStatisticsKHR" => (
+            &[b"vkGetPipelineExecutableStatisticsKHR\0"],
+            replay_get_pipeline_executable_statistics_khr,
+        ),
+        "vkGetPipelinePropertiesEXT" => (
+            &[b"vkGetPipelinePropertiesEXT\0"],
+            replay_get_pipeline_properties_ext,
+        ),
+        "vkGetPrivateData" => (
+            &[b"vkGetPrivateData\0", b"vkGetPrivateDataEXT\0"],
+            replay_get_private_data,
+        ),
+        "vkGetQueryPoolResults" => (&[b"vkGetQueryPoolResults\0"], replay_get_query_pool_results),
+        "vkGetQueueCheckpointData2NV" => (
+            &[b"vkGetQueueCheckpointData2NV\0"],
+            replay_get_queue_checkpoint_data2_nv,
+        ),
+        "vkGetQueueCheckpointDataNV" => (
+            &[b"vkGetQueueCheckpointDataNV\0"],
+            replay_get_queue_checkpoint_data_nv,
+        ),
+        "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR" => (
+            &[b"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR\0"],
+            replay_get_ray_tracing_capture_replay_shader_group_handles_khr,
+        ),
+        "vkGetRayTracingShaderGroupHandlesKHR" => (
+            &[
+                b"vkGetRayTracingShaderGroupHandlesKHR\0",
+                b"vkGetRayTracingShaderGroupHandlesNV\0",
+            ],
+            replay_get_ray_tracing_shader_group_handles_khr,
+        ),
+        "vkGetRayTracingShaderGroupStackSizeKHR" => (
+            &[b"vkGetRayTracingShaderGroupStackSizeKHR\0"],
+            replay_get_ray_tracing_shader_group_stack_size_khr,
+        ),
+        "vkGetRefreshCycleDurationGOOGLE" => (
+            &[b"vkGetRefreshCycleDurationGOOGLE\0"],
+            replay_get_refresh_cycle_duration_google,
+        ),
+        "vkGetRenderAreaGranularity" => (
+            &[b"vkGetRenderAreaGranularity\0"],
+            replay_get_render_area_granularity,
+        ),
+        "vkGetSamplerOpaqueCaptureDescriptorDataEXT" => (
+            &[b"vkGetSamplerOpaqueCaptureDescriptorDataEXT\0"],
+            replay_get_sampler_opaque_capture_descriptor_data_ext,
+        ),
+        "vkGetScreenBufferPropertiesQNX" => (
+            &[b"vkGetScreenBufferPropertiesQNX\0"],
+            replay_get_screen_buffer_properties_qnx,
+        ),
+        "vkGetSemaphoreCounterValue" => (
+            &[
+                b"vkGetSemaphoreCounterValue\0",
+                b"vkGetSemaphoreCounterValueKHR\0",
+            ],
+            replay_get_semaphore_counter_value,
+        ),
+        "vkGetSemaphoreFdKHR" => (&[b"vkGetSemaphoreFdKHR\0"], replay_get_semaphore_fd_khr),
+        "vkGetSemaphoreWin32HandleKHR" => (
+            &[b"vkGetSemaphoreWin32HandleKHR\0"],
+            replay_get_semaphore_win32_handle_khr,
+        ),
+        "vkGetSemaphoreZirconHandleFUCHSIA" => (
+            &[b"vkGetSemaphoreZirconHandleFUCHSIA\0"],
+            replay_get_semaphore_zircon_handle_fuchsia,
+        ),
+        "vkGetShaderBinaryDataEXT" => (
+            &[b"vkGetShaderBinaryDataEXT\0"],
+            replay_get_shader_binary_data_ext,
+        ),
+        "vkGetShaderInfoAMD" => (&[b"vkGetShaderInfoAMD\0"], replay_get_shader_info_amd),
+        "vkGetShaderModuleCreateInfoIdentifierEXT" => (
+            &[b"vkGetShaderModuleCreateInfoIdentifierEXT\0"],
+            replay_get_shader_module_create_info_identifier_ext,
+        ),
+        "vkGetShaderModuleIdentifierEXT" => (
+            &[b"vkGetShaderModuleIdentifierEXT\0"],
+            replay_get_shader_module_identifier_ext,
+        ),
+        "vkGetSwapchainCounterEXT" => (
+            &[b"vkGetSwapchainCounterEXT\0"],
+            replay_get_swapchain_counter_ext,
+        ),
+        "vkGetSwapchainGrallocUsage2ANDROID" => (
+            &[b"vkGetSwapchainGrallocUsage2ANDROID\0"],
+            replay_get_swapchain_gralloc_usage2_android,
+        ),
+        "vkGetSwapchainGrallocUsageANDROID" => (
+            &[b"vkGetSwapchainGrallocUsageANDROID\0"],
+            replay_get_swapchain_gralloc_usage_android,
+        ),
+        "vkGetSwapchainImagesKHR" => (
+            &[b"vkGetSwapchainImagesKHR\0"],
+            replay_get_swapchain_images_khr,
+        ),
+        "vkGetSwapchainStatusKHR" => (
+            &[b"vkGetSwapchainStatusKHR\0"],
+            replay_get_swapchain_status_khr,
+        ),
+        "vkGetValidationCacheDataEXT" => (
+            &[b"vkGetValidationCacheDataEXT\0"],
+            replay_get_validation_cache_data_ext,
+        ),
+        "vkGetVideoSessionMemoryRequirementsKHR" => (
+            &[b"vkGetVideoSessionMemoryRequirementsKHR\0"],
+            replay_get_video_session_memory_requirements_khr,
+        ),
+        "vkImportFenceFdKHR" => (&[b"vkImportFenceFdKHR\0"], replay_import_fence_fd_khr),
+        "vkImportFenceWin32HandleKHR" => (
+            &[b"vkImportFenceWin32HandleKHR\0"],
+            replay_import_fence_win32_handle_khr,
+        ),
+        "vkImportSemaphoreFdKHR" => (
+            &[b"vkImportSemaphoreFdKHR\0"],
+            replay_import_semaphore_fd_khr,
+        ),
+        "vkImportSemaphoreWin32HandleKHR" => (
+            &[b"vkImportSemaphoreWin32HandleKHR\0"],
+            replay_import_semaphore_win32_handle_khr,
+        ),
+        "vkImportSemaphoreZirconHandleFUCHSIA" => (
+            &[b"vkImportSemaphoreZirconHandleFUCHSIA\0"],
+            replay_import_semaphore_zircon_handle_fuchsia,
+        ),
+        "vkInitializePerformanceApiINTEL" => (
+            &[b"vkInitializePerformanceApiINTEL\0"],
+            replay_initialize_performance_api_intel,
+        ),
+        "vkInvalidateMappedMemoryRanges" => (
+            &[b"vkInvalidateMappedMemoryRanges\0"],
+            replay_invalidate_mapped_memory_ranges,
+        ),
+        "vkMapMemory" => (&[b"vkMapMemory\0"], replay_map_memory),
+        "vkMapMemory2KHR" => (&[b"vkMapMemory2KHR\0"], replay_map_memory2_khr),
+        "vkMergePipelineCaches" => (&[b"vkMergePipelineCaches\0"], replay_merge_pipeline_caches),
+        "vkMergeValidationCachesEXT" => (
+            &[b"vkMergeValidationCachesEXT\0"],
+            replay_merge_validation_caches_ext,
+        ),
+        "vkQueueBeginDebugUtilsLabelEXT" => (
+            &[b"vkQueueBeginDebugUtilsLabelEXT\0"],
+            replay_queue_begin_debug_utils_label_ext,
+        ),
+        "vkQueueBindSparse" => (&[b"vkQueueBindSparse\0"], replay_queue_bind_sparse),
+        "vkQueueEndDebugUtilsLabelEXT" => (
+            &[b"vkQueueEndDebugUtilsLabelEXT\0"],
+            replay_queue_end_debug_utils_label_ext,
+        ),
+        "vkQueueInsertDebugUtilsLabelEXT" => (
+            &[b"vkQueueInsertDebugUtilsLabelEXT\0"],
+            replay_queue_insert_debug_utils_label_ext,
+        ),
+        "vkQueuePresentKHR" => (&[b"vkQueuePresentKHR\0"], replay_queue_present_khr),
+        "vkQueueSetPerformanceConfigurationINTEL" => (
+            &[b"vkQueueSetPerformanceConfigurationINTEL\0"],
+            replay_queue_set_performance_configuration_intel,
+        ),
+        "vkQueueSignalReleaseImageANDROID" => (
+            &[b"vkQueueSignalReleaseImageANDROID\0"],
+            replay_queue_signal_release_image_android,
+        ),
+        "vkQueueSubmit" => (&[b"vkQueueSubmit\0"], replay_queue_submit),
+        "vkQueueSubmit2" => (
+            &[b"vkQueueSubmit2\0", b"vkQueueSubmit2KHR\0"],
+            replay_queue_submit2,
+        ),
+        "vkQueueWaitIdle" => (&[b"vkQueueWaitIdle\0"], replay_queue_wait_idle),
+        "vkRegisterDeviceEventEXT" => (
+            &[b"vkRegisterDeviceEventEXT\0"],
+            replay_register_device_event_ext,
+        ),
+        "vkRegisterDisplayEventEXT" => (
+            &[b"vkRegisterDisplayEventEXT\0"],
+            replay_register_display_event_ext,
+        ),
+        "vkReleaseFullScreenExclusiveModeEXT" => (
+            &[b"vkReleaseFullScreenExclusiveModeEXT\0"],
+            replay_release_full_screen_exclusive_mode_ext,
+        ),
+        "vkReleasePerformanceConfigurationINTEL" => (
+            &[b"vkReleasePerformanceConfigurationINTEL\0"],
+            replay_release_performance_configuration_intel,
+        ),
+        "vkReleaseProfilingLockKHR" => (
+            &[b"vkReleaseProfilingLockKHR\0"],
+            replay_release_profiling_lock_khr,
+        ),
+        "vkReleaseSwapchainImagesEXT" => (
+            &[b"vkReleaseSwapchainImagesEXT\0"],
+            replay_release_swapchain_images_ext,
+        ),
+        "vkResetCommandBuffer" => (&[b"vkResetCommandBuffer\0"], replay_reset_command_buffer),
+        "vkResetCommandPool" => (&[b"vkResetCommandPool\0"], replay_reset_command_pool),
+        "vkResetDescriptorPool" => (&[b"vkResetDescriptorPool\0"], replay_reset_descriptor_pool),
+        "vkResetEvent" => (&[b"vkResetEvent\0"], replay_reset_event),
+        "vkResetFences" => (&[b"vkResetFences\0"], replay_reset_fences),
+        "vkResetQueryPool" => (
+            &[b"vkResetQueryPool\0", b"vkResetQueryPoolEXT\0"],
+            replay_reset_query_pool,
+        ),
+        "vkSetBufferCollectionBufferConstraintsFUCHSIA" => (
+            &[b"vkSetBufferCollectionBufferConstraintsFUCHSIA\0"],
+            replay_set_buffer_collection_buffer_constraints_fuchsia,
+        ),
+        "vkSetBufferCollectionImageConstraintsFUCHSIA" => (
+            &[b"vkSetBufferCollectionImageConstraintsFUCHSIA\0"],
+            replay_set_buffer_collection_image_constraints_fuchsia,
+        ),
+        "vkSetDebugUtilsObjectNameEXT" => (
+            &[b"vkSetDebugUtilsObjectNameEXT\0"],
+            replay_set_debug_utils_object_name_ext,
+        ),
+        "vkSetDebugUtilsObjectTagEXT" => (
+            &[b"vkSetDebugUtilsObjectTagEXT\0"],
+            replay_set_debug_utils_object_tag_ext,
+        ),
+        "vkSetDeviceMemoryPriorityEXT" => (
+            &[b"vkSetDeviceMemoryPriorityEXT\0"],
+            replay_set_device_memory_priority_ext,
+        ),
+        "vkSetEvent" => (&[b"vkSetEvent\0"], replay_set_event),
+        "vkSetHdrMetadataEXT" => (&[b"vkSetHdrMetadataEXT\0"], replay_set_hdr_metadata_ext),
+        "vkSetLocalDimmingAMD" => (&[b"vkSetLocalDimmingAMD\0"], replay_set_local_dimming_amd),
+        "vkSetPrivateData" => (
+            &[b"vkSetPrivateData\0", b"vkSetPrivateDataEXT\0"],
+            replay_set_private_data,
+        ),
+        "vkSignalSemaphore" => (
+            &[b"vkSignalSemaphore\0", b"vkSignalSemaphoreKHR\0"],
+            replay_signal_semaphore,
+        ),
+        "vkTrimCommandPool" => (
+            &[b"vkTrimCommandPool\0", b"vkTrimCommandPoolKHR\0"],
+            replay_trim_command_pool,
+        ),
+        "vkUninitializePerformanceApiINTEL" => (
+            &[b"vkUninitializePerformanceApiINTEL\0"],
+            replay_uninitialize_performance_api_intel,
+        ),
+        "vkUnmapMemory" => (&[b"vkUnmapMemory\0"], replay_unmap_memory),
+        "vkUnmapMemory2KHR" => (&[b"vkUnmapMemory2KHR\0"], replay_unmap_memory2_khr),
+        "vkUpdateDescriptorSetWithTemplate" => (
+            &[
+                b"vkUpdateDescriptorSetWithTemplate\0",
+                b"vkUpdateDescriptorSetWithTemplateKHR\0",
+            ],
+            replay_update_descriptor_set_with_template,
+        ),
+        "vkUpdateDescriptorSets" => (
+            &[b"vkUpdateDescriptorSets\0"],
+            replay_update_descriptor_sets,
+        ),
+        "vkUpdateVideoSessionParametersKHR" => (
+            &[b"vkUpdateVideoSessionParametersKHR\0"],
+            replay_update_video_session_parameters_khr,
+        ),
+        "vkWaitForFences" => (&[b"vkWaitForFences\0"], replay_wait_for_fences),
+        "vkWaitForPresentKHR" => (&[b"vkWaitForPresentKHR\0"], replay_wait_for_present_khr),
+        "vkWaitSemaphores" => (
+            &[b"vkWaitSemaphores\0", b"vkWaitSemaphoresKHR\0"],
+            replay_wait_semaphores,
+        ),
+        "vkWriteAccelerationStructuresPropertiesKHR" => (
+            &[b"vkWriteAccelerationStructuresPropertiesKHR\0"],
+            replay_write_acceleration_structures_properties_khr,
+        ),
+        "vkWriteMicromapsPropertiesEXT" => (
+            &[b"vkWriteMicromapsPropertiesEXT\0"],
+            replay_write_micromaps_properties_ext,
+        ),
+        _ => return None,
+    })
+}
+unsafe extern "system" fn acquire_full_screen_exclusive_mode_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireFullScreenExclusiveModeEXT>(next(
+        0usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, swapchain);
+    }
+    let args = vec![device.record(), swapchain.record()];
+    let result = next(device, swapchain);
+    push_call(
+        "vkAcquireFullScreenExclusiveModeEXT",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_acquire_full_screen_exclusive_mode_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkAcquireFullScreenExclusiveModeEXT,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, swapchain);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn acquire_image_android(
+    device: vk::Device,
+    image: vk::Image,
+    native_fence_fd: c_int,
+    semaphore: vk::Semaphore,
+    fence: vk::Fence,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireImageANDROID>(next(
+        1usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, image, native_fence_fd, semaphore, fence);
+    }
+    let args = vec![
+        device.record(),
+        image.record(),
+        native_fence_fd.record(),
+        semaphore.record(),
+        fence.record(),
+    ];
+    let result = next(device, image, native_fence_fd, semaphore, fence);
+    push_call("vkAcquireImageANDROID", args, vec![], result.record());
+    result
+}
+unsafe fn replay_acquire_image_android(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkAcquireImageANDROID>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let native_fence_fd: c_int = DeepCopyable::replay(&args[2usize], arena)?;
+    let semaphore: vk::Semaphore = DeepCopyable::replay(&args[3usize], arena)?;
+    let fence: vk::Fence = DeepCopyable::replay(&args[4usize], arena)?;
+    let result = f(device, image, native_fence_fd, semaphore, fence);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn acquire_next_image2_khr(
+    device: vk::Device,
+    p_acquire_info: *const vk::AcquireNextImageInfoKHR<'_>,
+    p_image_index: *mut u32,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireNextImage2KHR>(next(
+        2usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_acquire_info, p_image_index);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_acquire_info, 1, |element| element.record()),
+        record_output(p_image_index, 1),
+    ];
+    let result = next(device, p_acquire_info, p_image_index);
+    push_call(
+        "vkAcquireNextImage2KHR",
+        args,
+        vec![record_array(p_image_index, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_acquire_next_image2_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkAcquireNextImage2KHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_acquire_info: *const vk::AcquireNextImageInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_image_index: *mut u32 = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_acquire_info, p_image_index);
+    let outputs: Vec<Value> = vec![record_array(p_image_index, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn acquire_next_image_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    timeout: u64,
+    semaphore: vk::Semaphore,
+    fence: vk::Fence,
+    p_image_index: *mut u32,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireNextImageKHR>(next(
+        3usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, swapchain, timeout, semaphore, fence, p_image_index);
+    }
+    let args = vec![
+        device.record(),
+        swapchain.record(),
+        timeout.record(),
+        semaphore.record(),
+        fence.record(),
+        record_output(p_image_index, 1),
+    ];
+    let result = next(device, swapchain, timeout, semaphore, fence, p_image_index);
+    push_call(
+        "vkAcquireNextImageKHR",
+        args,
+        vec![record_array(p_image_index, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_acquire_next_image_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkAcquireNextImageKHR>(f);
+    let args = args::<6usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let timeout: u64 = DeepCopyable::replay(&args[2usize], arena)?;
+    let semaphore: vk::Semaphore = DeepCopyable::replay(&args[3usize], arena)?;
+    let fence: vk::Fence = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_image_index: *mut u32 = replay_array(&args[5usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, swapchain, timeout, semaphore, fence, p_image_index);
+    let outputs: Vec<Value> = vec![record_array(p_image_index, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn acquire_performance_configuration_intel(
+    device: vk::Device,
+    p_acquire_info: *const vk::PerformanceConfigurationAcquireInfoINTEL<'_>,
+    p_configuration: *mut vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquirePerformanceConfigurationINTEL>(next(
+        4usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_acquire_info, p_configuration);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_acquire_info, 1, |element| element.record()),
+        record_output(p_configuration, 1),
+    ];
+    let result = next(device, p_acquire_info, p_configuration);
+    push_call(
+        "vkAcquirePerformanceConfigurationINTEL",
+        args,
+        vec![record_array(p_configuration, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_acquire_performance_configuration_intel(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkAcquirePerformanceConfigurationINTEL,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_acquire_info: *const vk::PerformanceConfigurationAcquireInfoINTEL<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_configuration: *mut vk::PerformanceConfigurationINTEL =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_acquire_info, p_configuration);
+    let outputs: Vec<Value> = vec![record_array(p_configuration, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn acquire_profiling_lock_khr(
+    device: vk::Device,
+    p_info: *const vk::AcquireProfilingLockInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireProfilingLockKHR>(next(
+        5usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_info);
+    push_call("vkAcquireProfilingLockKHR", args, vec![], result.record());
+    result
+}
+unsafe fn replay_acquire_profiling_lock_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkAcquireProfilingLockKHR>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::AcquireProfilingLockInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn allocate_command_buffers(
+    device: vk::Device,
+    p_allocate_info: *const vk::CommandBufferAllocateInfo<'_>,
+    p_command_buffers: *mut vk::CommandBuffer,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAllocateCommandBuffers>(next(
+        6usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_allocate_info, p_command_buffers);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_allocate_info, 1, |element| element.record()),
+        record_output(
+            p_command_buffers,
+            (*p_allocate_info).command_buffer_count as usize,
+        ),
+    ];
+    let result = next(device, p_allocate_info, p_command_buffers);
+    push_call(
+        "vkAllocateCommandBuffers",
+        args,
+        vec![record_array(
+            p_command_buffers,
+            (*p_allocate_info).command_buffer_count as usize,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_allocate_command_buffers(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkAllocateCommandBuffers>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_allocate_info: *const vk::CommandBufferAllocateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_command_buffers: *mut vk::CommandBuffer =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_allocate_info, p_command_buffers);
+    let outputs: Vec<Value> = vec![record_array(
+        p_command_buffers,
+        (*p_allocate_info).command_buffer_count as usize,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn allocate_descriptor_sets(
+    device: vk::Device,
+    p_allocate_info: *const vk::DescriptorSetAllocateInfo<'_>,
+    p_descriptor_sets: *mut vk::DescriptorSet,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAllocateDescriptorSets>(next(
+        7usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_allocate_info, p_descriptor_sets);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_allocate_info, 1, |element| element.record()),
+        record_output(
+            p_descriptor_sets,
+            (*p_allocate_info).descriptor_set_count as usize,
+        ),
+    ];
+    let result = next(device, p_allocate_info, p_descriptor_sets);
+    push_call(
+        "vkAllocateDescriptorSets",
+        args,
+        vec![record_array(
+            p_descriptor_sets,
+            (*p_allocate_info).descriptor_set_count as usize,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_allocate_descriptor_sets(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkAllocateDescriptorSets>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_allocate_info: *const vk::DescriptorSetAllocateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_descriptor_sets: *mut vk::DescriptorSet =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_allocate_info, p_descriptor_sets);
+    let outputs: Vec<Value> = vec![record_array(
+        p_descriptor_sets,
+        (*p_allocate_info).descriptor_set_count as usize,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn allocate_memory(
+    device: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAllocateMemory>(next(
+        8usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_allocate_info, p_allocator, p_memory);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_allocate_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_memory, 1),
+    ];
+    let result = next(device, p_allocate_info, p_allocator, p_memory);
+    push_call(
+        "vkAllocateMemory",
+        args,
+        vec![record_array(p_memory, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_allocate_memory(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkAllocateMemory>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_allocate_info: *const vk::MemoryAllocateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_memory: *mut vk::DeviceMemory = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_allocate_info, p_allocator, p_memory);
+    let outputs: Vec<Value> = vec![record_array(p_memory, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn begin_command_buffer(
+    command_buffer: vk::CommandBuffer,
+    p_begin_info: *const vk::CommandBufferBeginInfo<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBeginCommandBuffer>(next(
+        9usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_begin_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_begin_info, 1, |element| element.record()),
+    ];
+    let result = next(command_buffer, p_begin_info);
+    push_call("vkBeginCommandBuffer", args, vec![], result.record());
+    result
+}
+unsafe fn replay_begin_command_buffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkBeginCommandBuffer>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_begin_info: *const vk::CommandBufferBeginInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(command_buffer, p_begin_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn bind_acceleration_structure_memory_nv(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindAccelerationStructureMemoryInfoNV<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindAccelerationStructureMemoryNV>(next(
+        10usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, bind_info_count, p_bind_infos);
+    }
+    let args = vec![
+        device.record(),
+        bind_info_count.record(),
+        record_array(p_bind_infos, bind_info_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, bind_info_count, p_bind_infos);
+    push_call(
+        "vkBindAccelerationStructureMemoryNV",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_bind_acceleration_structure_memory_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkBindAccelerationStructureMemoryNV,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let bind_info_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_bind_infos: *const vk::BindAccelerationStructureMemoryInfoNV<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, bind_info_count, p_bind_infos);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn bind_buffer_memory(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    memory: vk::DeviceMemory,
+    memory_offset: vk::DeviceSize,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindBufferMemory>(next(
+        11usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, buffer, memory, memory_offset);
+    }
+    let args = vec![
+        device.record(),
+        buffer.record(),
+        memory.record(),
+        memory_offset.record(),
+    ];
+    let result = next(device, buffer, memory, memory_offset);
+    push_call("vkBindBufferMemory", args, vec![], result.record());
+    result
+}
+unsafe fn replay_bind_buffer_memory(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkBindBufferMemory>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let memory: vk::DeviceMemory = DeepCopyable::replay(&args[2usize], arena)?;
+    let memory_offset: vk::DeviceSize = DeepCopyable::replay(&args[3usize], arena)?;
+    let result = f(device, buffer, memory, memory_offset);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn bind_buffer_memory2(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindBufferMemoryInfo<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindBufferMemory2>(next(
+        12usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, bind_info_count, p_bind_infos);
+    }
+    let args = vec![
+        device.record(),
+        bind_info_count.record(),
+        record_array(p_bind_infos, bind_info_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, bind_info_count, p_bind_infos);
+    push_call("vkBindBufferMemory2", args, vec![], result.record());
+    result
+}
+unsafe fn replay_bind_buffer_memory2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkBindBufferMemory2>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let bind_info_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_bind_infos: *const vk::BindBufferMemoryInfo<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, bind_info_count, p_bind_infos);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn bind_image_memory(
+    device: vk::Device,
+    image: vk::Image,
+    memory: vk::DeviceMemory,
+    memory_offset: vk::DeviceSize,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindImageMemory>(next(
+        13usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, image, memory, memory_offset);
+    }
+    let args = vec![
+        device.record(),
+        image.record(),
+        memory.record(),
+        memory_offset.record(),
+    ];
+    let result = next(device, image, memory, memory_offset);
+    push_call("vkBindImageMemory", args, vec![], result.record());
+    result
+}
+unsafe fn replay_bind_image_memory(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkBindImageMemory>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let memory: vk::DeviceMemory = DeepCopyable::replay(&args[2usize], arena)?;
+    let memory_offset: vk::DeviceSize = DeepCopyable::replay(&args[3usize], arena)?;
+    let result = f(device, image, memory, memory_offset);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn bind_image_memory2(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindImageMemoryInfo<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindImageMemory2>(next(
+        14usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, bind_info_count, p_bind_infos);
+    }
+    let args = vec![
+        device.record(),
+        bind_info_count.record(),
+        record_array(p_bind_infos, bind_info_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, bind_info_count, p_bind_infos);
+    push_call("vkBindImageMemory2", args, vec![], result.record());
+    result
+}
+unsafe fn replay_bind_image_memory2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkBindImageMemory2>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let bind_info_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_bind_infos: *const vk::BindImageMemoryInfo<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, bind_info_count, p_bind_infos);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn bind_optical_flow_session_image_nv(
+    device: vk::Device,
+    session: vk::OpticalFlowSessionNV,
+    binding_point: vk::OpticalFlowSessionBindingPointNV,
+    view: vk::ImageView,
+    layout: vk::ImageLayout,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindOpticalFlowSessionImageNV>(next(
+        15usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, session, binding_point, view, layout);
+    }
+    let args = vec![
+        device.record(),
+        session.record(),
+        binding_point.record(),
+        view.record(),
+        layout.record(),
+    ];
+    let result = next(device, session, binding_point, view, layout);
+    push_call(
+        "vkBindOpticalFlowSessionImageNV",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_bind_optical_flow_session_image_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkBindOpticalFlowSessionImageNV>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let session: vk::OpticalFlowSessionNV = DeepCopyable::replay(&args[1usize], arena)?;
+    let binding_point: vk::OpticalFlowSessionBindingPointNV =
+        DeepCopyable::replay(&args[2usize], arena)?;
+    let view: vk::ImageView = DeepCopyable::replay(&args[3usize], arena)?;
+    let layout: vk::ImageLayout = DeepCopyable::replay(&args[4usize], arena)?;
+    let result = f(device, session, binding_point, view, layout);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn bind_video_session_memory_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    bind_session_memory_info_count: u32,
+    p_bind_session_memory_infos: *const vk::BindVideoSessionMemoryInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindVideoSessionMemoryKHR>(next(
+        16usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            video_session,
+            bind_session_memory_info_count,
+            p_bind_session_memory_infos,
+        );
+    }
+    let args = vec![
+        device.record(),
+        video_session.record(),
+        bind_session_memory_info_count.record(),
+        record_array(
+            p_bind_session_memory_infos,
+            bind_session_memory_info_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    let result = next(
+        device,
+        video_session,
+        bind_session_memory_info_count,
+        p_bind_session_memory_infos,
+    );
+    push_call("vkBindVideoSessionMemoryKHR", args, vec![], result.record());
+    result
+}
+unsafe fn replay_bind_video_session_memory_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkBindVideoSessionMemoryKHR>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let video_session: vk::VideoSessionKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let bind_session_memory_info_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_bind_session_memory_infos: *const vk::BindVideoSessionMemoryInfoKHR<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        video_session,
+        bind_session_memory_info_count,
+        p_bind_session_memory_infos,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn build_acceleration_structures_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBuildAccelerationStructuresKHR>(next(
+        17usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            deferred_operation,
+            info_count,
+            p_infos,
+            pp_build_range_infos,
+        );
+    }
+    let args = vec![
+        device.record(),
+        deferred_operation.record(),
+        info_count.record(),
+        record_array(p_infos, info_count as usize, |element| element.record()),
+        Value::Null,
+    ];
+    let result = next(
+        device,
+        deferred_operation,
+        info_count,
+        p_infos,
+        pp_build_range_infos,
+    );
+    push_call(
+        "vkBuildAccelerationStructuresKHR",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_build_acceleration_structures_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkBuildAccelerationStructuresKHR>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let deferred_operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let info_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR =
+        ptr::null_mut();
+    let result = f(
+        device,
+        deferred_operation,
+        info_count,
+        p_infos,
+        pp_build_range_infos,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn build_micromaps_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    info_count: u32,
+    p_infos: *const vk::MicromapBuildInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBuildMicromapsEXT>(next(
+        18usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, deferred_operation, info_count, p_infos);
+    }
+    let args = vec![
+        device.record(),
+        deferred_operation.record(),
+        info_count.record(),
+        record_array(p_infos, info_count as usize, |element| element.record()),
+    ];
+    let result = next(device, deferred_operation, info_count, p_infos);
+    push_call("vkBuildMicromapsEXT", args, vec![], result.record());
+    result
+}
+unsafe fn replay_build_micromaps_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkBuildMicromapsEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let deferred_operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let info_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_infos: *const vk::MicromapBuildInfoEXT<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, deferred_operation, info_count, p_infos);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn cmd_begin_conditional_rendering_ext(
+    command_buffer: vk::CommandBuffer,
+    p_conditional_rendering_begin: *const vk::ConditionalRenderingBeginInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginConditionalRenderingEXT>(next(
+        19usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_conditional_rendering_begin);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_conditional_rendering_begin, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_conditional_rendering_begin);
+    push_call(
+        "vkCmdBeginConditionalRenderingEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_begin_conditional_rendering_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBeginConditionalRenderingEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_conditional_rendering_begin: *const vk::ConditionalRenderingBeginInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_conditional_rendering_begin);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_begin_debug_utils_label_ext(
+    command_buffer: vk::CommandBuffer,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginDebugUtilsLabelEXT>(next(
+        20usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_label_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_label_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_label_info);
+    push_call("vkCmdBeginDebugUtilsLabelEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_begin_debug_utils_label_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBeginDebugUtilsLabelEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_label_info: *const vk::DebugUtilsLabelEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_label_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_begin_query(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    flags: vk::QueryControlFlags,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginQuery>(next(
+        21usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, query_pool, query, flags);
+    }
+    let args = vec![
+        command_buffer.record(),
+        query_pool.record(),
+        query.record(),
+        flags.record(),
+    ];
+    next(command_buffer, query_pool, query, flags);
+    push_call("vkCmdBeginQuery", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_begin_query(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBeginQuery>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let query: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let flags: vk::QueryControlFlags = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, query_pool, query, flags);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_begin_query_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    flags: vk::QueryControlFlags,
+    index: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginQueryIndexedEXT>(next(
+        22usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, query_pool, query, flags, index);
+    }
+    let args = vec![
+        command_buffer.record(),
+        query_pool.record(),
+        query.record(),
+        flags.record(),
+        index.record(),
+    ];
+    next(command_buffer, query_pool, query, flags, index);
+    push_call("vkCmdBeginQueryIndexedEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_begin_query_indexed_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBeginQueryIndexedEXT>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let query: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let flags: vk::QueryControlFlags = DeepCopyable::replay(&args[3usize], arena)?;
+    let index: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    f(command_buffer, query_pool, query, flags, index);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_begin_render_pass(
+    command_buffer: vk::CommandBuffer,
+    p_render_pass_begin: *const vk::RenderPassBeginInfo<'_>,
+    contents: vk::SubpassContents,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginRenderPass>(next(
+        23usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_render_pass_begin, contents);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_render_pass_begin, 1, |element| element.record()),
+        contents.record(),
+    ];
+    next(command_buffer, p_render_pass_begin, contents);
+    push_call("vkCmdBeginRenderPass", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_begin_render_pass(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBeginRenderPass>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_render_pass_begin: *const vk::RenderPassBeginInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let contents: vk::SubpassContents = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, p_render_pass_begin, contents);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_begin_render_pass2(
+    command_buffer: vk::CommandBuffer,
+    p_render_pass_begin: *const vk::RenderPassBeginInfo<'_>,
+    p_subpass_begin_info: *const vk::SubpassBeginInfo<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginRenderPass2>(next(
+        24usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_render_pass_begin, p_subpass_begin_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_render_pass_begin, 1, |element| element.record()),
+        record_array(p_subpass_begin_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_render_pass_begin, p_subpass_begin_info);
+    push_call("vkCmdBeginRenderPass2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_begin_render_pass2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBeginRenderPass2>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_render_pass_begin: *const vk::RenderPassBeginInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_subpass_begin_info: *const vk::SubpassBeginInfo<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_render_pass_begin, p_subpass_begin_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_begin_rendering(
+    command_buffer: vk::CommandBuffer,
+    p_rendering_info: *const vk::RenderingInfo<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginRendering>(next(
+        25usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_rendering_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_rendering_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_rendering_info);
+    push_call("vkCmdBeginRendering", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_begin_rendering(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBeginRendering>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_rendering_info: *const vk::RenderingInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_rendering_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_begin_transform_feedback_ext(
+    command_buffer: vk::CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const vk::Buffer,
+    p_counter_buffer_offsets: *const vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginTransformFeedbackEXT>(next(
+        26usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_counter_buffer,
+            counter_buffer_count,
+            p_counter_buffers,
+            p_counter_buffer_offsets,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_counter_buffer.record(),
+        counter_buffer_count.record(),
+        record_array(
+            p_counter_buffers,
+            counter_buffer_count as usize,
+            |element| element.record(),
+        ),
+        record_array(
+            p_counter_buffer_offsets,
+            counter_buffer_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    );
+    push_call("vkCmdBeginTransformFeedbackEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_begin_transform_feedback_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBeginTransformFeedbackEXT>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_counter_buffer: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let counter_buffer_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_counter_buffers: *const vk::Buffer =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_counter_buffer_offsets: *const vk::DeviceSize =
+        replay_array(&args[4usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_begin_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_begin_info: *const vk::VideoBeginCodingInfoKHR<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginVideoCodingKHR>(next(
+        27usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_begin_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_begin_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_begin_info);
+    push_call("vkCmdBeginVideoCodingKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_begin_video_coding_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBeginVideoCodingKHR>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_begin_info: *const vk::VideoBeginCodingInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_begin_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_descriptor_buffer_embedded_samplers_ext(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    set: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindDescriptorBufferEmbeddedSamplersEXT>(
+        next(28usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(command_buffer, pipeline_bind_point, layout, set);
+    }
+    let args = vec![
+        command_buffer.record(),
+        pipeline_bind_point.record(),
+        layout.record(),
+        set.record(),
+    ];
+    next(command_buffer, pipeline_bind_point, layout, set);
+    push_call(
+        "vkCmdBindDescriptorBufferEmbeddedSamplersEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_bind_descriptor_buffer_embedded_samplers_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdBindDescriptorBufferEmbeddedSamplersEXT,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_bind_point: vk::PipelineBindPoint = DeepCopyable::replay(&args[1usize], arena)?;
+    let layout: vk::PipelineLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let set: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, pipeline_bind_point, layout, set);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_descriptor_buffers_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer_count: u32,
+    p_binding_infos: *const vk::DescriptorBufferBindingInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindDescriptorBuffersEXT>(next(
+        29usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, buffer_count, p_binding_infos);
+    }
+    let args = vec![
+        command_buffer.record(),
+        buffer_count.record(),
+        record_array(p_binding_infos, buffer_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(command_buffer, buffer_count, p_binding_infos);
+    push_call("vkCmdBindDescriptorBuffersEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_bind_descriptor_buffers_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBindDescriptorBuffersEXT>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_binding_infos: *const vk::DescriptorBufferBindingInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, buffer_count, p_binding_infos);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_descriptor_sets(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    first_set: u32,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const vk::DescriptorSet,
+    dynamic_offset_count: u32,
+    p_dynamic_offsets: *const u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindDescriptorSets>(next(
+        30usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            pipeline_bind_point,
+            layout,
+            first_set,
+            descriptor_set_count,
+            p_descriptor_sets,
+            dynamic_offset_count,
+            p_dynamic_offsets,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        pipeline_bind_point.record(),
+        layout.record(),
+        first_set.record(),
+        descriptor_set_count.record(),
+        record_array(
+            p_descriptor_sets,
+            descriptor_set_count as usize,
+            |element| element.record(),
+        ),
+        dynamic_offset_count.record(),
+        record_data(p_dynamic_offsets, dynamic_offset_count as usize),
+    ];
+    next(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        first_set,
+        descriptor_set_count,
+        p_descriptor_sets,
+        dynamic_offset_count,
+        p_dynamic_offsets,
+    );
+    push_call("vkCmdBindDescriptorSets", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_bind_descriptor_sets(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBindDescriptorSets>(f);
+    let args = args::<8usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_bind_point: vk::PipelineBindPoint = DeepCopyable::replay(&args[1usize], arena)?;
+    let layout: vk::PipelineLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let first_set: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let descriptor_set_count: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_descriptor_sets: *const vk::DescriptorSet =
+        replay_array(&args[5usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let dynamic_offset_count: u32 = DeepCopyable::replay(&args[6usize], arena)?;
+    let p_dynamic_offsets: *const u32 = replay_data(&args[7usize], arena)?;
+    f(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        first_set,
+        descriptor_set_count,
+        p_descriptor_sets,
+        dynamic_offset_count,
+        p_dynamic_offsets,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_index_buffer(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    index_type: vk::IndexType,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindIndexBuffer>(next(
+        31usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, buffer, offset, index_type);
+    }
+    let args = vec![
+        command_buffer.record(),
+        buffer.record(),
+        offset.record(),
+        index_type.record(),
+    ];
+    next(command_buffer, buffer, offset, index_type);
+    push_call("vkCmdBindIndexBuffer", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_bind_index_buffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBindIndexBuffer>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let index_type: vk::IndexType = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, buffer, offset, index_type);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_invocation_mask_huawei(
+    command_buffer: vk::CommandBuffer,
+    image_view: vk::ImageView,
+    image_layout: vk::ImageLayout,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindInvocationMaskHUAWEI>(next(
+        32usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, image_view, image_layout);
+    }
+    let args = vec![
+        command_buffer.record(),
+        image_view.record(),
+        image_layout.record(),
+    ];
+    next(command_buffer, image_view, image_layout);
+    push_call("vkCmdBindInvocationMaskHUAWEI", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_bind_invocation_mask_huawei(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBindInvocationMaskHUAWEI>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let image_view: vk::ImageView = DeepCopyable::replay(&args[1usize], arena)?;
+    let image_layout: vk::ImageLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, image_view, image_layout);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_pipeline(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    pipeline: vk::Pipeline,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindPipeline>(next(
+        33usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, pipeline_bind_point, pipeline);
+    }
+    let args = vec![
+        command_buffer.record(),
+        pipeline_bind_point.record(),
+        pipeline.record(),
+    ];
+    next(command_buffer, pipeline_bind_point, pipeline);
+    push_call("vkCmdBindPipeline", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_bind_pipeline(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBindPipeline>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_bind_point: vk::PipelineBindPoint = DeepCopyable::replay(&args[1usize], arena)?;
+    let pipeline: vk::Pipeline = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, pipeline_bind_point, pipeline);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_pipeline_shader_group_nv(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    pipeline: vk::Pipeline,
+    group_index: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindPipelineShaderGroupNV>(next(
+        34usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, pipeline_bind_point, pipeline, group_index);
+    }
+    let args = vec![
+        command_buffer.record(),
+        pipeline_bind_point.record(),
+        pipeline.record(),
+        group_index.record(),
+    ];
+    next(command_buffer, pipeline_bind_point, pipeline, group_index);
+    push_call("vkCmdBindPipelineShaderGroupNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_bind_pipeline_shader_group_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBindPipelineShaderGroupNV>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_bind_point: vk::PipelineBindPoint = DeepCopyable::replay(&args[1usize], arena)?;
+    let pipeline: vk::Pipeline = DeepCopyable::replay(&args[2usize], arena)?;
+    let group_index: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, pipeline_bind_point, pipeline, group_index);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_shaders_ext(
+    command_buffer: vk::CommandBuffer,
+    stage_count: u32,
+    p_stages: *const vk::ShaderStageFlags,
+    p_shaders: *const vk::ShaderEXT,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindShadersEXT>(next(
+        35usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, stage_count, p_stages, p_shaders);
+    }
+    let args = vec![
+        command_buffer.record(),
+        stage_count.record(),
+        record_array(p_stages, stage_count as usize, |element| element.record()),
+        record_array(p_shaders, stage_count as usize, |element| element.record()),
+    ];
+    next(command_buffer, stage_count, p_stages, p_shaders);
+    push_call("vkCmdBindShadersEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_bind_shaders_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBindShadersEXT>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let stage_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_stages: *const vk::ShaderStageFlags =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_shaders: *const vk::ShaderEXT = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(command_buffer, stage_count, p_stages, p_shaders);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_shading_rate_image_nv(
+    command_buffer: vk::CommandBuffer,
+    image_view: vk::ImageView,
+    image_layout: vk::ImageLayout,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindShadingRateImageNV>(next(
+        36usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, image_view, image_layout);
+    }
+    let args = vec![
+        command_buffer.record(),
+        image_view.record(),
+        image_layout.record(),
+    ];
+    next(command_buffer, image_view, image_layout);
+    push_call("vkCmdBindShadingRateImageNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_bind_shading_rate_image_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBindShadingRateImageNV>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let image_view: vk::ImageView = DeepCopyable::replay(&args[1usize], arena)?;
+    let image_layout: vk::ImageLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, image_view, image_layout);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_transform_feedback_buffers_ext(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+    p_sizes: *const vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindTransformFeedbackBuffersEXT>(next(
+        37usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_binding,
+            binding_count,
+            p_buffers,
+            p_offsets,
+            p_sizes,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_binding.record(),
+        binding_count.record(),
+        record_array(p_buffers, binding_count as usize, |element| {
+            element.record()
+        }),
+        record_array(p_offsets, binding_count as usize, |element| {
+            element.record()
+        }),
+        record_array(p_sizes, binding_count as usize, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+    );
+    push_call(
+        "vkCmdBindTransformFeedbackBuffersEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_bind_transform_feedback_buffers_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdBindTransformFeedbackBuffersEXT,
+    >(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_binding: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let binding_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_buffers: *const vk::Buffer = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_offsets: *const vk::DeviceSize = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_sizes: *const vk::DeviceSize = replay_array(&args[5usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_vertex_buffers(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindVertexBuffers>(next(
+        38usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_binding,
+            binding_count,
+            p_buffers,
+            p_offsets,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_binding.record(),
+        binding_count.record(),
+        record_array(p_buffers, binding_count as usize, |element| {
+            element.record()
+        }),
+        record_array(p_offsets, binding_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+    );
+    push_call("vkCmdBindVertexBuffers", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_bind_vertex_buffers(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBindVertexBuffers>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_binding: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let binding_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_buffers: *const vk::Buffer = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_offsets: *const vk::DeviceSize = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_bind_vertex_buffers2(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+    p_sizes: *const vk::DeviceSize,
+    p_strides: *const vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindVertexBuffers2>(next(
+        39usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_binding,
+            binding_count,
+            p_buffers,
+            p_offsets,
+            p_sizes,
+            p_strides,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_binding.record(),
+        binding_count.record(),
+        record_array(p_buffers, binding_count as usize, |element| {
+            element.record()
+        }),
+        record_array(p_offsets, binding_count as usize, |element| {
+            element.record()
+        }),
+        record_array(p_sizes, binding_count as usize, |element| element.record()),
+        record_array(p_strides, binding_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+        p_strides,
+    );
+    push_call("vkCmdBindVertexBuffers2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_bind_vertex_buffers2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBindVertexBuffers2>(f);
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_binding: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let binding_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_buffers: *const vk::Buffer = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_offsets: *const vk::DeviceSize = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_sizes: *const vk::DeviceSize = replay_array(&args[5usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_strides: *const vk::DeviceSize = replay_array(&args[6usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+        p_strides,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_blit_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageBlit,
+    filter: vk::Filter,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBlitImage>(next(
+        40usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            region_count,
+            p_regions,
+            filter,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        src_image.record(),
+        src_image_layout.record(),
+        dst_image.record(),
+        dst_image_layout.record(),
+        region_count.record(),
+        record_array(p_regions, region_count as usize, |element| element.record()),
+        filter.record(),
+    ];
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+        filter,
+    );
+    push_call("vkCmdBlitImage", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_blit_image(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBlitImage>(f);
+    let args = args::<8usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let src_image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let src_image_layout: vk::ImageLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let dst_image: vk::Image = DeepCopyable::replay(&args[3usize], arena)?;
+    let dst_image_layout: vk::ImageLayout = DeepCopyable::replay(&args[4usize], arena)?;
+    let region_count: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let p_regions: *const vk::ImageBlit = replay_array(&args[6usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let filter: vk::Filter = DeepCopyable::replay(&args[7usize], arena)?;
+    f(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+        filter,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_blit_image2(
+    command_buffer: vk::CommandBuffer,
+    p_blit_image_info: *const vk::BlitImageInfo2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBlitImage2>(next(
+        41usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_blit_image_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_blit_image_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_blit_image_info);
+    push_call("vkCmdBlitImage2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_blit_image2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBlitImage2>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_blit_image_info: *const vk::BlitImageInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_blit_image_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_build_acceleration_structure_nv(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::AccelerationStructureInfoNV<'_>,
+    instance_data: vk::Buffer,
+    instance_offset: vk::DeviceSize,
+    update: vk::Bool32,
+    dst: vk::AccelerationStructureNV,
+    src: vk::AccelerationStructureNV,
+    scratch: vk::Buffer,
+    scratch_offset: vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBuildAccelerationStructureNV>(next(
+        42usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            p_info,
+            instance_data,
+            instance_offset,
+            update,
+            dst,
+            src,
+            scratch,
+            scratch_offset,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_info, 1, |element| element.record()),
+        instance_data.record(),
+        instance_offset.record(),
+        update.record(),
+        dst.record(),
+        src.record(),
+        scratch.record(),
+        scratch_offset.record(),
+    ];
+    next(
+        command_buffer,
+        p_info,
+        instance_data,
+        instance_offset,
+        update,
+        dst,
+        src,
+        scratch,
+        scratch_offset,
+    );
+    push_call(
+        "vkCmdBuildAccelerationStructureNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_build_acceleration_structure_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBuildAccelerationStructureNV>(f);
+    let args = args::<9usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::AccelerationStructureInfoNV<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let instance_data: vk::Buffer = DeepCopyable::replay(&args[2usize], arena)?;
+    let instance_offset: vk::DeviceSize = DeepCopyable::replay(&args[3usize], arena)?;
+    let update: vk::Bool32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let dst: vk::AccelerationStructureNV = DeepCopyable::replay(&args[5usize], arena)?;
+    let src: vk::AccelerationStructureNV = DeepCopyable::replay(&args[6usize], arena)?;
+    let scratch: vk::Buffer = DeepCopyable::replay(&args[7usize], arena)?;
+    let scratch_offset: vk::DeviceSize = DeepCopyable::replay(&args[8usize], arena)?;
+    f(
+        command_buffer,
+        p_info,
+        instance_data,
+        instance_offset,
+        update,
+        dst,
+        src,
+        scratch,
+        scratch_offset,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_build_acceleration_structures_indirect_khr(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    p_indirect_device_addresses: *const vk::DeviceAddress,
+    p_indirect_strides: *const u32,
+    pp_max_primitive_counts: *const *const u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBuildAccelerationStructuresIndirectKHR>(
+        next(43usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(
+            command_buffer,
+            info_count,
+            p_infos,
+            p_indirect_device_addresses,
+            p_indirect_strides,
+            pp_max_primitive_counts,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        info_count.record(),
+        record_array(p_infos, info_count as usize, |element| element.record()),
+        record_array(
+            p_indirect_device_addresses,
+            info_count as usize,
+            |element| element.record(),
+        ),
+        record_data(p_indirect_strides, info_count as usize),
+        Value::Null,
+    ];
+    next(
+        command_buffer,
+        info_count,
+        p_infos,
+        p_indirect_device_addresses,
+        p_indirect_strides,
+        pp_max_primitive_counts,
+    );
+    push_call(
+        "vkCmdBuildAccelerationStructuresIndirectKHR",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_build_acceleration_structures_indirect_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdBuildAccelerationStructuresIndirectKHR,
+    >(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let info_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_indirect_device_addresses: *const vk::DeviceAddress =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_indirect_strides: *const u32 = replay_data(&args[4usize], arena)?;
+    let pp_max_primitive_counts: *const *const u32 = ptr::null_mut();
+    f(
+        command_buffer,
+        info_count,
+        p_infos,
+        p_indirect_device_addresses,
+        p_indirect_strides,
+        pp_max_primitive_counts,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_build_acceleration_structures_khr(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBuildAccelerationStructuresKHR>(next(
+        44usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, info_count, p_infos, pp_build_range_infos);
+    }
+    let args = vec![
+        command_buffer.record(),
+        info_count.record(),
+        record_array(p_infos, info_count as usize, |element| element.record()),
+        Value::Null,
+    ];
+    next(command_buffer, info_count, p_infos, pp_build_range_infos);
+    push_call(
+        "vkCmdBuildAccelerationStructuresKHR",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_build_acceleration_structures_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdBuildAccelerationStructuresKHR,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let info_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR =
+        ptr::null_mut();
+    f(command_buffer, info_count, p_infos, pp_build_range_infos);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_build_micromaps_ext(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::MicromapBuildInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBuildMicromapsEXT>(next(
+        45usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, info_count, p_infos);
+    }
+    let args = vec![
+        command_buffer.record(),
+        info_count.record(),
+        record_array(p_infos, info_count as usize, |element| element.record()),
+    ];
+    next(command_buffer, info_count, p_infos);
+    push_call("vkCmdBuildMicromapsEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_build_micromaps_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdBuildMicromapsEXT>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let info_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_infos: *const vk::MicromapBuildInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, info_count, p_infos);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_clear_attachments(
+    command_buffer: vk::CommandBuffer,
+    attachment_count: u32,
+    p_attachments: *const vk::ClearAttachment,
+    rect_count: u32,
+    p_rects: *const vk::ClearRect,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdClearAttachments>(next(
+        46usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            attachment_count,
+            p_attachments,
+            rect_count,
+            p_rects,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        attachment_count.record(),
+        record_array(p_attachments, attachment_count as usize, |element| {
+            element.record()
+        }),
+        rect_count.record(),
+        record_array(p_rects, rect_count as usize, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        attachment_count,
+        p_attachments,
+        rect_count,
+        p_rects,
+    );
+    push_call("vkCmdClearAttachments", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_clear_attachments(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdClearAttachments>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let attachment_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_attachments: *const vk::ClearAttachment =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let rect_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_rects: *const vk::ClearRect = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(
+        command_buffer,
+        attachment_count,
+        p_attachments,
+        rect_count,
+        p_rects,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_clear_color_image(
+    command_buffer: vk::CommandBuffer,
+    image: vk::Image,
+    image_layout: vk::ImageLayout,
+    p_color: *const vk::ClearColorValue,
+    range_count: u32,
+    p_ranges: *const vk::ImageSubresourceRange,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdClearColorImage>(next(
+        47usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            image,
+            image_layout,
+            p_color,
+            range_count,
+            p_ranges,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        image.record(),
+        image_layout.record(),
+        record_array(p_color, 1, |element| element.record()),
+        range_count.record(),
+        record_array(p_ranges, range_count as usize, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        image,
+        image_layout,
+        p_color,
+        range_count,
+        p_ranges,
+    );
+    push_call("vkCmdClearColorImage", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_clear_color_image(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdClearColorImage>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let image_layout: vk::ImageLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_color: *const vk::ClearColorValue =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let range_count: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_ranges: *const vk::ImageSubresourceRange =
+        replay_array(&args[5usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        image,
+        image_layout,
+        p_color,
+        range_count,
+        p_ranges,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_clear_depth_stencil_image(
+    command_buffer: vk::CommandBuffer,
+    image: vk::Image,
+    image_layout: vk::ImageLayout,
+    p_depth_stencil: *const vk::ClearDepthStencilValue,
+    range_count: u32,
+    p_ranges: *const vk::ImageSubresourceRange,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdClearDepthStencilImage>(next(
+        48usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            image,
+            image_layout,
+            p_depth_stencil,
+            range_count,
+            p_ranges,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        image.record(),
+        image_layout.record(),
+        record_array(p_depth_stencil, 1, |element| element.record()),
+        range_count.record(),
+        record_array(p_ranges, range_count as usize, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        image,
+        image_layout,
+        p_depth_stencil,
+        range_count,
+        p_ranges,
+    );
+    push_call("vkCmdClearDepthStencilImage", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_clear_depth_stencil_image(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdClearDepthStencilImage>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let image_layout: vk::ImageLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_depth_stencil: *const vk::ClearDepthStencilValue =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let range_count: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_ranges: *const vk::ImageSubresourceRange =
+        replay_array(&args[5usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        image,
+        image_layout,
+        p_depth_stencil,
+        range_count,
+        p_ranges,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_control_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_coding_control_info: *const vk::VideoCodingControlInfoKHR<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdControlVideoCodingKHR>(next(
+        49usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_coding_control_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_coding_control_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_coding_control_info);
+    push_call("vkCmdControlVideoCodingKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_control_video_coding_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdControlVideoCodingKHR>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_coding_control_info: *const vk::VideoCodingControlInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_coding_control_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_acceleration_structure_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyAccelerationStructureInfoKHR<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyAccelerationStructureKHR>(next(
+        50usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_info);
+    push_call(
+        "vkCmdCopyAccelerationStructureKHR",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_copy_acceleration_structure_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyAccelerationStructureKHR>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::CopyAccelerationStructureInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_acceleration_structure_nv(
+    command_buffer: vk::CommandBuffer,
+    dst: vk::AccelerationStructureNV,
+    src: vk::AccelerationStructureNV,
+    mode: vk::CopyAccelerationStructureModeKHR,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyAccelerationStructureNV>(next(
+        51usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, dst, src, mode);
+    }
+    let args = vec![
+        command_buffer.record(),
+        dst.record(),
+        src.record(),
+        mode.record(),
+    ];
+    next(command_buffer, dst, src, mode);
+    push_call(
+        "vkCmdCopyAccelerationStructureNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_copy_acceleration_structure_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyAccelerationStructureNV>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let dst: vk::AccelerationStructureNV = DeepCopyable::replay(&args[1usize], arena)?;
+    let src: vk::AccelerationStructureNV = DeepCopyable::replay(&args[2usize], arena)?;
+    let mode: vk::CopyAccelerationStructureModeKHR = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, dst, src, mode);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_acceleration_structure_to_memory_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyAccelerationStructureToMemoryKHR>(
+        next(52usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(command_buffer, p_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_info);
+    push_call(
+        "vkCmdCopyAccelerationStructureToMemoryKHR",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_copy_acceleration_structure_to_memory_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdCopyAccelerationStructureToMemoryKHR,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_buffer(
+    command_buffer: vk::CommandBuffer,
+    src_buffer: vk::Buffer,
+    dst_buffer: vk::Buffer,
+    region_count: u32,
+    p_regions: *const vk::BufferCopy,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyBuffer>(next(
+        53usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            src_buffer,
+            dst_buffer,
+            region_count,
+            p_regions,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        src_buffer.record(),
+        dst_buffer.record(),
+        region_count.record(),
+        record_array(p_regions, region_count as usize, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        src_buffer,
+        dst_buffer,
+        region_count,
+        p_regions,
+    );
+    push_call("vkCmdCopyBuffer", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_buffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyBuffer>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let src_buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let dst_buffer: vk::Buffer = DeepCopyable::replay(&args[2usize], arena)?;
+    let region_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_regions: *const vk::BufferCopy = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(
+        command_buffer,
+        src_buffer,
+        dst_buffer,
+        region_count,
+        p_regions,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_buffer2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_buffer_info: *const vk::CopyBufferInfo2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyBuffer2>(next(
+        54usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_copy_buffer_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_copy_buffer_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_copy_buffer_info);
+    push_call("vkCmdCopyBuffer2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_buffer2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyBuffer2>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_copy_buffer_info: *const vk::CopyBufferInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_copy_buffer_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_buffer_to_image(
+    command_buffer: vk::CommandBuffer,
+    src_buffer: vk::Buffer,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::BufferImageCopy,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyBufferToImage>(next(
+        55usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            src_buffer,
+            dst_image,
+            dst_image_layout,
+            region_count,
+            p_regions,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        src_buffer.record(),
+        dst_image.record(),
+        dst_image_layout.record(),
+        region_count.record(),
+        record_array(p_regions, region_count as usize, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        src_buffer,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    push_call("vkCmdCopyBufferToImage", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_buffer_to_image(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyBufferToImage>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let src_buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let dst_image: vk::Image = DeepCopyable::replay(&args[2usize], arena)?;
+    let dst_image_layout: vk::ImageLayout = DeepCopyable::replay(&args[3usize], arena)?;
+    let region_count: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_regions: *const vk::BufferImageCopy =
+        replay_array(&args[5usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        src_buffer,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_buffer_to_image2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_buffer_to_image_info: *const vk::CopyBufferToImageInfo2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyBufferToImage2>(next(
+        56usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_copy_buffer_to_image_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_copy_buffer_to_image_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_copy_buffer_to_image_info);
+    push_call("vkCmdCopyBufferToImage2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_buffer_to_image2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyBufferToImage2>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_copy_buffer_to_image_info: *const vk::CopyBufferToImageInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_copy_buffer_to_image_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageCopy,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyImage>(next(
+        57usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            region_count,
+            p_regions,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        src_image.record(),
+        src_image_layout.record(),
+        dst_image.record(),
+        dst_image_layout.record(),
+        region_count.record(),
+        record_array(p_regions, region_count as usize, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    push_call("vkCmdCopyImage", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_image(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyImage>(f);
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let src_image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let src_image_layout: vk::ImageLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let dst_image: vk::Image = DeepCopyable::replay(&args[3usize], arena)?;
+    let dst_image_layout: vk::ImageLayout = DeepCopyable::replay(&args[4usize], arena)?;
+    let region_count: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let p_regions: *const vk::ImageCopy = replay_array(&args[6usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_image2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_image_info: *const vk::CopyImageInfo2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyImage2>(next(
+        58usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_copy_image_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_copy_image_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_copy_image_info);
+    push_call("vkCmdCopyImage2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_image2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyImage2>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_copy_image_info: *const vk::CopyImageInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_copy_image_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_image_to_buffer(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_buffer: vk::Buffer,
+    region_count: u32,
+    p_regions: *const vk::BufferImageCopy,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyImageToBuffer>(next(
+        59usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            src_image,
+            src_image_layout,
+            dst_buffer,
+            region_count,
+            p_regions,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        src_image.record(),
+        src_image_layout.record(),
+        dst_buffer.record(),
+        region_count.record(),
+        record_array(p_regions, region_count as usize, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_buffer,
+        region_count,
+        p_regions,
+    );
+    push_call("vkCmdCopyImageToBuffer", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_image_to_buffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyImageToBuffer>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let src_image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let src_image_layout: vk::ImageLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let dst_buffer: vk::Buffer = DeepCopyable::replay(&args[3usize], arena)?;
+    let region_count: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_regions: *const vk::BufferImageCopy =
+        replay_array(&args[5usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_buffer,
+        region_count,
+        p_regions,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_image_to_buffer2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_image_to_buffer_info: *const vk::CopyImageToBufferInfo2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyImageToBuffer2>(next(
+        60usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_copy_image_to_buffer_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_copy_image_to_buffer_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_copy_image_to_buffer_info);
+    push_call("vkCmdCopyImageToBuffer2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_image_to_buffer2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyImageToBuffer2>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_copy_image_to_buffer_info: *const vk::CopyImageToBufferInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_copy_image_to_buffer_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_memory_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    copy_buffer_address: vk::DeviceAddress,
+    copy_count: u32,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMemoryIndirectNV>(next(
+        61usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, copy_buffer_address, copy_count, stride);
+    }
+    let args = vec![
+        command_buffer.record(),
+        copy_buffer_address.record(),
+        copy_count.record(),
+        stride.record(),
+    ];
+    next(command_buffer, copy_buffer_address, copy_count, stride);
+    push_call("vkCmdCopyMemoryIndirectNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_memory_indirect_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyMemoryIndirectNV>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let copy_buffer_address: vk::DeviceAddress = DeepCopyable::replay(&args[1usize], arena)?;
+    let copy_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, copy_buffer_address, copy_count, stride);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_memory_to_acceleration_structure_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMemoryToAccelerationStructureKHR>(
+        next(62usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(command_buffer, p_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_info);
+    push_call(
+        "vkCmdCopyMemoryToAccelerationStructureKHR",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_copy_memory_to_acceleration_structure_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdCopyMemoryToAccelerationStructureKHR,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_memory_to_image_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    copy_buffer_address: vk::DeviceAddress,
+    copy_count: u32,
+    stride: u32,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    p_image_subresources: *const vk::ImageSubresourceLayers,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMemoryToImageIndirectNV>(next(
+        63usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            copy_buffer_address,
+            copy_count,
+            stride,
+            dst_image,
+            dst_image_layout,
+            p_image_subresources,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        copy_buffer_address.record(),
+        copy_count.record(),
+        stride.record(),
+        dst_image.record(),
+        dst_image_layout.record(),
+        record_array(p_image_subresources, copy_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(
+        command_buffer,
+        copy_buffer_address,
+        copy_count,
+        stride,
+        dst_image,
+        dst_image_layout,
+        p_image_subresources,
+    );
+    push_call(
+        "vkCmdCopyMemoryToImageIndirectNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_copy_memory_to_image_indirect_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyMemoryToImageIndirectNV>(f);
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let copy_buffer_address: vk::DeviceAddress = DeepCopyable::replay(&args[1usize], arena)?;
+    let copy_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let dst_image: vk::Image = DeepCopyable::replay(&args[4usize], arena)?;
+    let dst_image_layout: vk::ImageLayout = DeepCopyable::replay(&args[5usize], arena)?;
+    let p_image_subresources: *const vk::ImageSubresourceLayers =
+        replay_array(&args[6usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        copy_buffer_address,
+        copy_count,
+        stride,
+        dst_image,
+        dst_image_layout,
+        p_image_subresources,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_memory_to_micromap_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMemoryToMicromapInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMemoryToMicromapEXT>(next(
+        64usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_info);
+    push_call("vkCmdCopyMemoryToMicromapEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_memory_to_micromap_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyMemoryToMicromapEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::CopyMemoryToMicromapInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_micromap_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMicromapInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMicromapEXT>(next(
+        65usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_info);
+    push_call("vkCmdCopyMicromapEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_micromap_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyMicromapEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::CopyMicromapInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_micromap_to_memory_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMicromapToMemoryInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMicromapToMemoryEXT>(next(
+        66usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_info);
+    push_call("vkCmdCopyMicromapToMemoryEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_micromap_to_memory_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyMicromapToMemoryEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::CopyMicromapToMemoryInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_copy_query_pool_results(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    stride: vk::DeviceSize,
+    flags: vk::QueryResultFlags,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyQueryPoolResults>(next(
+        67usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            query_pool,
+            first_query,
+            query_count,
+            dst_buffer,
+            dst_offset,
+            stride,
+            flags,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        query_pool.record(),
+        first_query.record(),
+        query_count.record(),
+        dst_buffer.record(),
+        dst_offset.record(),
+        stride.record(),
+        flags.record(),
+    ];
+    next(
+        command_buffer,
+        query_pool,
+        first_query,
+        query_count,
+        dst_buffer,
+        dst_offset,
+        stride,
+        flags,
+    );
+    push_call("vkCmdCopyQueryPoolResults", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_copy_query_pool_results(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCopyQueryPoolResults>(f);
+    let args = args::<8usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let first_query: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let query_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let dst_buffer: vk::Buffer = DeepCopyable::replay(&args[4usize], arena)?;
+    let dst_offset: vk::DeviceSize = DeepCopyable::replay(&args[5usize], arena)?;
+    let stride: vk::DeviceSize = DeepCopyable::replay(&args[6usize], arena)?;
+    let flags: vk::QueryResultFlags = DeepCopyable::replay(&args[7usize], arena)?;
+    f(
+        command_buffer,
+        query_pool,
+        first_query,
+        query_count,
+        dst_buffer,
+        dst_offset,
+        stride,
+        flags,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_cu_launch_kernel_nvx(
+    command_buffer: vk::CommandBuffer,
+    p_launch_info: *const vk::CuLaunchInfoNVX<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCuLaunchKernelNVX>(next(
+        68usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_launch_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_launch_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_launch_info);
+    push_call("vkCmdCuLaunchKernelNVX", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_cu_launch_kernel_nvx(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdCuLaunchKernelNVX>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_launch_info: *const vk::CuLaunchInfoNVX<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_launch_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_debug_marker_begin_ext(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::DebugMarkerMarkerInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDebugMarkerBeginEXT>(next(
+        69usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_marker_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_marker_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_marker_info);
+    push_call("vkCmdDebugMarkerBeginEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_debug_marker_begin_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDebugMarkerBeginEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_marker_info: *const vk::DebugMarkerMarkerInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_marker_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_debug_marker_end_ext(command_buffer: vk::CommandBuffer) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDebugMarkerEndEXT>(next(
+        70usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer);
+    }
+    let args = vec![command_buffer.record()];
+    next(command_buffer);
+    push_call("vkCmdDebugMarkerEndEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_debug_marker_end_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDebugMarkerEndEXT>(f);
+    let args = args::<1usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    f(command_buffer);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_debug_marker_insert_ext(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::DebugMarkerMarkerInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDebugMarkerInsertEXT>(next(
+        71usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_marker_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_marker_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_marker_info);
+    push_call("vkCmdDebugMarkerInsertEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_debug_marker_insert_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDebugMarkerInsertEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_marker_info: *const vk::DebugMarkerMarkerInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_marker_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_decode_video_khr(
+    command_buffer: vk::CommandBuffer,
+    p_decode_info: *const vk::VideoDecodeInfoKHR<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDecodeVideoKHR>(next(
+        72usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_decode_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_decode_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_decode_info);
+    push_call("vkCmdDecodeVideoKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_decode_video_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDecodeVideoKHR>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_decode_info: *const vk::VideoDecodeInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_decode_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_decompress_memory_indirect_count_nv(
+    command_buffer: vk::CommandBuffer,
+    indirect_commands_address: vk::DeviceAddress,
+    indirect_commands_count_address: vk::DeviceAddress,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDecompressMemoryIndirectCountNV>(next(
+        73usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            indirect_commands_address,
+            indirect_commands_count_address,
+            stride,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        indirect_commands_address.record(),
+        indirect_commands_count_address.record(),
+        stride.record(),
+    ];
+    next(
+        command_buffer,
+        indirect_commands_address,
+        indirect_commands_count_address,
+        stride,
+    );
+    push_call(
+        "vkCmdDecompressMemoryIndirectCountNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_decompress_memory_indirect_count_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdDecompressMemoryIndirectCountNV,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let indirect_commands_address: vk::DeviceAddress = DeepCopyable::replay(&args[1usize], arena)?;
+    let indirect_commands_count_address: vk::DeviceAddress =
+        DeepCopyable::replay(&args[2usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(
+        command_buffer,
+        indirect_commands_address,
+        indirect_commands_count_address,
+        stride,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_decompress_memory_nv(
+    command_buffer: vk::CommandBuffer,
+    decompress_region_count: u32,
+    p_decompress_memory_regions: *const vk::DecompressMemoryRegionNV,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDecompressMemoryNV>(next(
+        74usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            decompress_region_count,
+            p_decompress_memory_regions,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        decompress_region_count.record(),
+        record_array(
+            p_decompress_memory_regions,
+            decompress_region_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        decompress_region_count,
+        p_decompress_memory_regions,
+    );
+    push_call("vkCmdDecompressMemoryNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_decompress_memory_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDecompressMemoryNV>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let decompress_region_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_decompress_memory_regions: *const vk::DecompressMemoryRegionNV =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        decompress_region_count,
+        p_decompress_memory_regions,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_dispatch(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDispatch>(next(
+        75usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, group_count_x, group_count_y, group_count_z);
+    }
+    let args = vec![
+        command_buffer.record(),
+        group_count_x.record(),
+        group_count_y.record(),
+        group_count_z.record(),
+    ];
+    next(command_buffer, group_count_x, group_count_y, group_count_z);
+    push_call("vkCmdDispatch", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_dispatch(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDispatch>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let group_count_x: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let group_count_y: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let group_count_z: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, group_count_x, group_count_y, group_count_z);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_dispatch_base(
+    command_buffer: vk::CommandBuffer,
+    base_group_x: u32,
+    base_group_y: u32,
+    base_group_z: u32,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDispatchBase>(next(
+        76usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            base_group_x,
+            base_group_y,
+            base_group_z,
+            group_count_x,
+            group_count_y,
+            group_count_z,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        base_group_x.record(),
+        base_group_y.record(),
+        base_group_z.record(),
+        group_count_x.record(),
+        group_count_y.record(),
+        group_count_z.record(),
+    ];
+    next(
+        command_buffer,
+        base_group_x,
+        base_group_y,
+        base_group_z,
+        group_count_x,
+        group_count_y,
+        group_count_z,
+    );
+    push_call("vkCmdDispatchBase", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_dispatch_base(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDispatchBase>(f);
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let base_group_x: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let base_group_y: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let base_group_z: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let group_count_x: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let group_count_y: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let group_count_z: u32 = DeepCopyable::replay(&args[6usize], arena)?;
+    f(
+        command_buffer,
+        base_group_x,
+        base_group_y,
+        base_group_z,
+        group_count_x,
+        group_count_y,
+        group_count_z,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_dispatch_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDispatchIndirect>(next(
+        77usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, buffer, offset);
+    }
+    let args = vec![command_buffer.record(), buffer.record(), offset.record()];
+    next(command_buffer, buffer, offset);
+    push_call("vkCmdDispatchIndirect", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_dispatch_indirect(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDispatchIndirect>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, buffer, offset);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw(
+    command_buffer: vk::CommandBuffer,
+    vertex_count: u32,
+    instance_count: u32,
+    first_vertex: u32,
+    first_instance: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDraw>(next(
+        78usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            vertex_count,
+            instance_count,
+            first_vertex,
+            first_instance,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        vertex_count.record(),
+        instance_count.record(),
+        first_vertex.record(),
+        first_instance.record(),
+    ];
+    next(
+        command_buffer,
+        vertex_count,
+        instance_count,
+        first_vertex,
+        first_instance,
+    );
+    push_call("vkCmdDraw", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDraw>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let vertex_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let instance_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let first_vertex: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let first_instance: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    f(
+        command_buffer,
+        vertex_count,
+        instance_count,
+        first_vertex,
+        first_instance,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_cluster_huawei(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawClusterHUAWEI>(next(
+        79usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, group_count_x, group_count_y, group_count_z);
+    }
+    let args = vec![
+        command_buffer.record(),
+        group_count_x.record(),
+        group_count_y.record(),
+        group_count_z.record(),
+    ];
+    next(command_buffer, group_count_x, group_count_y, group_count_z);
+    push_call("vkCmdDrawClusterHUAWEI", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_cluster_huawei(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawClusterHUAWEI>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let group_count_x: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let group_count_y: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let group_count_z: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, group_count_x, group_count_y, group_count_z);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_cluster_indirect_huawei(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawClusterIndirectHUAWEI>(next(
+        80usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, buffer, offset);
+    }
+    let args = vec![command_buffer.record(), buffer.record(), offset.record()];
+    next(command_buffer, buffer, offset);
+    push_call("vkCmdDrawClusterIndirectHUAWEI", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_cluster_indirect_huawei(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawClusterIndirectHUAWEI>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, buffer, offset);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_indexed(
+    command_buffer: vk::CommandBuffer,
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    vertex_offset: i32,
+    first_instance: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndexed>(next(
+        81usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            index_count,
+            instance_count,
+            first_index,
+            vertex_offset,
+            first_instance,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        index_count.record(),
+        instance_count.record(),
+        first_index.record(),
+        vertex_offset.record(),
+        first_instance.record(),
+    ];
+    next(
+        command_buffer,
+        index_count,
+        instance_count,
+        first_index,
+        vertex_offset,
+        first_instance,
+    );
+    push_call("vkCmdDrawIndexed", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_indexed(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawIndexed>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let index_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let instance_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let first_index: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let vertex_offset: i32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let first_instance: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    f(
+        command_buffer,
+        index_count,
+        instance_count,
+        first_index,
+        vertex_offset,
+        first_instance,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_indexed_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndexedIndirect>(next(
+        82usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, buffer, offset, draw_count, stride);
+    }
+    let args = vec![
+        command_buffer.record(),
+        buffer.record(),
+        offset.record(),
+        draw_count.record(),
+        stride.record(),
+    ];
+    next(command_buffer, buffer, offset, draw_count, stride);
+    push_call("vkCmdDrawIndexedIndirect", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_indexed_indirect(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawIndexedIndirect>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let draw_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    f(command_buffer, buffer, offset, draw_count, stride);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_indexed_indirect_count(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndexedIndirectCount>(next(
+        83usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        buffer.record(),
+        offset.record(),
+        count_buffer.record(),
+        count_buffer_offset.record(),
+        max_draw_count.record(),
+        stride.record(),
+    ];
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    push_call("vkCmdDrawIndexedIndirectCount", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_indexed_indirect_count(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawIndexedIndirectCount>(f);
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let count_buffer: vk::Buffer = DeepCopyable::replay(&args[3usize], arena)?;
+    let count_buffer_offset: vk::DeviceSize = DeepCopyable::replay(&args[4usize], arena)?;
+    let max_draw_count: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[6usize], arena)?;
+    f(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndirect>(next(
+        84usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, buffer, offset, draw_count, stride);
+    }
+    let args = vec![
+        command_buffer.record(),
+        buffer.record(),
+        offset.record(),
+        draw_count.record(),
+        stride.record(),
+    ];
+    next(command_buffer, buffer, offset, draw_count, stride);
+    push_call("vkCmdDrawIndirect", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_indirect(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawIndirect>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let draw_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    f(command_buffer, buffer, offset, draw_count, stride);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_indirect_byte_count_ext(
+    command_buffer: vk::CommandBuffer,
+    instance_count: u32,
+    first_instance: u32,
+    counter_buffer: vk::Buffer,
+    counter_buffer_offset: vk::DeviceSize,
+    counter_offset: u32,
+    vertex_stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndirectByteCountEXT>(next(
+        85usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            instance_count,
+            first_instance,
+            counter_buffer,
+            counter_buffer_offset,
+            counter_offset,
+            vertex_stride,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        instance_count.record(),
+        first_instance.record(),
+        counter_buffer.record(),
+        counter_buffer_offset.record(),
+        counter_offset.record(),
+        vertex_stride.record(),
+    ];
+    next(
+        command_buffer,
+        instance_count,
+        first_instance,
+        counter_buffer,
+        counter_buffer_offset,
+        counter_offset,
+        vertex_stride,
+    );
+    push_call("vkCmdDrawIndirectByteCountEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_indirect_byte_count_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawIndirectByteCountEXT>(f);
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let instance_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let first_instance: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let counter_buffer: vk::Buffer = DeepCopyable::replay(&args[3usize], arena)?;
+    let counter_buffer_offset: vk::DeviceSize = DeepCopyable::replay(&args[4usize], arena)?;
+    let counter_offset: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let vertex_stride: u32 = DeepCopyable::replay(&args[6usize], arena)?;
+    f(
+        command_buffer,
+        instance_count,
+        first_instance,
+        counter_buffer,
+        counter_buffer_offset,
+        counter_offset,
+        vertex_stride,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_indirect_count(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndirectCount>(next(
+        86usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        buffer.record(),
+        offset.record(),
+        count_buffer.record(),
+        count_buffer_offset.record(),
+        max_draw_count.record(),
+        stride.record(),
+    ];
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    push_call("vkCmdDrawIndirectCount", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_indirect_count(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawIndirectCount>(f);
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let count_buffer: vk::Buffer = DeepCopyable::replay(&args[3usize], arena)?;
+    let count_buffer_offset: vk::DeviceSize = DeepCopyable::replay(&args[4usize], arena)?;
+    let max_draw_count: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[6usize], arena)?;
+    f(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_ext(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksEXT>(next(
+        87usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, group_count_x, group_count_y, group_count_z);
+    }
+    let args = vec![
+        command_buffer.record(),
+        group_count_x.record(),
+        group_count_y.record(),
+        group_count_z.record(),
+    ];
+    next(command_buffer, group_count_x, group_count_y, group_count_z);
+    push_call("vkCmdDrawMeshTasksEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_mesh_tasks_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawMeshTasksEXT>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let group_count_x: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let group_count_y: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let group_count_z: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, group_count_x, group_count_y, group_count_z);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_count_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksIndirectCountEXT>(next(
+        88usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        buffer.record(),
+        offset.record(),
+        count_buffer.record(),
+        count_buffer_offset.record(),
+        max_draw_count.record(),
+        stride.record(),
+    ];
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    push_call(
+        "vkCmdDrawMeshTasksIndirectCountEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_draw_mesh_tasks_indirect_count_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawMeshTasksIndirectCountEXT>(
+        f,
+    );
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let count_buffer: vk::Buffer = DeepCopyable::replay(&args[3usize], arena)?;
+    let count_buffer_offset: vk::DeviceSize = DeepCopyable::replay(&args[4usize], arena)?;
+    let max_draw_count: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[6usize], arena)?;
+    f(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_count_nv(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksIndirectCountNV>(next(
+        89usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            buffer,
+            offset,
+            count_buffer,
+            count_buffer_offset,
+            max_draw_count,
+            stride,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        buffer.record(),
+        offset.record(),
+        count_buffer.record(),
+        count_buffer_offset.record(),
+        max_draw_count.record(),
+        stride.record(),
+    ];
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    push_call(
+        "vkCmdDrawMeshTasksIndirectCountNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_draw_mesh_tasks_indirect_count_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawMeshTasksIndirectCountNV>(f);
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let count_buffer: vk::Buffer = DeepCopyable::replay(&args[3usize], arena)?;
+    let count_buffer_offset: vk::DeviceSize = DeepCopyable::replay(&args[4usize], arena)?;
+    let max_draw_count: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[6usize], arena)?;
+    f(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksIndirectEXT>(next(
+        90usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, buffer, offset, draw_count, stride);
+    }
+    let args = vec![
+        command_buffer.record(),
+        buffer.record(),
+        offset.record(),
+        draw_count.record(),
+        stride.record(),
+    ];
+    next(command_buffer, buffer, offset, draw_count, stride);
+    push_call("vkCmdDrawMeshTasksIndirectEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_mesh_tasks_indirect_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawMeshTasksIndirectEXT>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let draw_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    f(command_buffer, buffer, offset, draw_count, stride);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksIndirectNV>(next(
+        91usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, buffer, offset, draw_count, stride);
+    }
+    let args = vec![
+        command_buffer.record(),
+        buffer.record(),
+        offset.record(),
+        draw_count.record(),
+        stride.record(),
+    ];
+    next(command_buffer, buffer, offset, draw_count, stride);
+    push_call("vkCmdDrawMeshTasksIndirectNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_mesh_tasks_indirect_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawMeshTasksIndirectNV>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let draw_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    f(command_buffer, buffer, offset, draw_count, stride);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_nv(
+    command_buffer: vk::CommandBuffer,
+    task_count: u32,
+    first_task: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksNV>(next(
+        92usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, task_count, first_task);
+    }
+    let args = vec![
+        command_buffer.record(),
+        task_count.record(),
+        first_task.record(),
+    ];
+    next(command_buffer, task_count, first_task);
+    push_call("vkCmdDrawMeshTasksNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_mesh_tasks_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawMeshTasksNV>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let task_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let first_task: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, task_count, first_task);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_multi_ext(
+    command_buffer: vk::CommandBuffer,
+    draw_count: u32,
+    p_vertex_info: *const vk::MultiDrawInfoEXT,
+    instance_count: u32,
+    first_instance: u32,
+    stride: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMultiEXT>(next(
+        93usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            draw_count,
+            p_vertex_info,
+            instance_count,
+            first_instance,
+            stride,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        draw_count.record(),
+        record_array(p_vertex_info, draw_count as usize, |element| {
+            element.record()
+        }),
+        instance_count.record(),
+        first_instance.record(),
+        stride.record(),
+    ];
+    next(
+        command_buffer,
+        draw_count,
+        p_vertex_info,
+        instance_count,
+        first_instance,
+        stride,
+    );
+    push_call("vkCmdDrawMultiEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_multi_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawMultiEXT>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let draw_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_vertex_info: *const vk::MultiDrawInfoEXT =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let instance_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let first_instance: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    f(
+        command_buffer,
+        draw_count,
+        p_vertex_info,
+        instance_count,
+        first_instance,
+        stride,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_draw_multi_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    draw_count: u32,
+    p_index_info: *const vk::MultiDrawIndexedInfoEXT,
+    instance_count: u32,
+    first_instance: u32,
+    stride: u32,
+    p_vertex_offset: *const i32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMultiIndexedEXT>(next(
+        94usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            draw_count,
+            p_index_info,
+            instance_count,
+            first_instance,
+            stride,
+            p_vertex_offset,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        draw_count.record(),
+        record_array(p_index_info, draw_count as usize, |element| {
+            element.record()
+        }),
+        instance_count.record(),
+        first_instance.record(),
+        stride.record(),
+        record_array(p_vertex_offset, 1, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        draw_count,
+        p_index_info,
+        instance_count,
+        first_instance,
+        stride,
+        p_vertex_offset,
+    );
+    push_call("vkCmdDrawMultiIndexedEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_draw_multi_indexed_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdDrawMultiIndexedEXT>(f);
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let draw_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_index_info: *const vk::MultiDrawIndexedInfoEXT =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let instance_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let first_instance: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let stride: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let p_vertex_offset: *const i32 = replay_array(&args[6usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(
+        command_buffer,
+        draw_count,
+        p_index_info,
+        instance_count,
+        first_instance,
+        stride,
+        p_vertex_offset,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_encode_video_khr(
+    command_buffer: vk::CommandBuffer,
+    p_encode_info: *const vk::VideoEncodeInfoKHR<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEncodeVideoKHR>(next(
+        95usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_encode_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_encode_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_encode_info);
+    push_call("vkCmdEncodeVideoKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_encode_video_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdEncodeVideoKHR>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_encode_info: *const vk::VideoEncodeInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_encode_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_end_conditional_rendering_ext(command_buffer: vk::CommandBuffer) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndConditionalRenderingEXT>(next(
+        96usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer);
+    }
+    let args = vec![command_buffer.record()];
+    next(command_buffer);
+    push_call("vkCmdEndConditionalRenderingEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_end_conditional_rendering_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdEndConditionalRenderingEXT>(f);
+    let args = args::<1usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    f(command_buffer);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_end_debug_utils_label_ext(command_buffer: vk::CommandBuffer) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndDebugUtilsLabelEXT>(next(
+        97usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer);
+    }
+    let args = vec![command_buffer.record()];
+    next(command_buffer);
+    push_call("vkCmdEndDebugUtilsLabelEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_end_debug_utils_label_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdEndDebugUtilsLabelEXT>(f);
+    let args = args::<1usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    f(command_buffer);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_end_query(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndQuery>(next(
+        98usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, query_pool, query);
+    }
+    let args = vec![command_buffer.record(), query_pool.record(), query.record()];
+    next(command_buffer, query_pool, query);
+    push_call("vkCmdEndQuery", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_end_query(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdEndQuery>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let query: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, query_pool, query);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_end_query_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    index: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndQueryIndexedEXT>(next(
+        99usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, query_pool, query, index);
+    }
+    let args = vec![
+        command_buffer.record(),
+        query_pool.record(),
+        query.record(),
+        index.record(),
+    ];
+    next(command_buffer, query_pool, query, index);
+    push_call("vkCmdEndQueryIndexedEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_end_query_indexed_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdEndQueryIndexedEXT>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let query: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let index: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, query_pool, query, index);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_end_render_pass(command_buffer: vk::CommandBuffer) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndRenderPass>(next(
+        100usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer);
+    }
+    let args = vec![command_buffer.record()];
+    next(command_buffer);
+    push_call("vkCmdEndRenderPass", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_end_render_pass(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdEndRenderPass>(f);
+    let args = args::<1usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    f(command_buffer);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_end_render_pass2(
+    command_buffer: vk::CommandBuffer,
+    p_subpass_end_info: *const vk::SubpassEndInfo<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndRenderPass2>(next(
+        101usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_subpass_end_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_subpass_end_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_subpass_end_info);
+    push_call("vkCmdEndRenderPass2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_end_render_pass2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdEndRenderPass2>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_subpass_end_info: *const vk::SubpassEndInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_subpass_end_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_end_rendering(command_buffer: vk::CommandBuffer) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndRendering>(next(
+        102usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer);
+    }
+    let args = vec![command_buffer.record()];
+    next(command_buffer);
+    push_call("vkCmdEndRendering", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_end_rendering(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdEndRendering>(f);
+    let args = args::<1usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    f(command_buffer);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_end_transform_feedback_ext(
+    command_buffer: vk::CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const vk::Buffer,
+    p_counter_buffer_offsets: *const vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndTransformFeedbackEXT>(next(
+        103usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_counter_buffer,
+            counter_buffer_count,
+            p_counter_buffers,
+            p_counter_buffer_offsets,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_counter_buffer.record(),
+        counter_buffer_count.record(),
+        record_array(
+            p_counter_buffers,
+            counter_buffer_count as usize,
+            |element| element.record(),
+        ),
+        record_array(
+            p_counter_buffer_offsets,
+            counter_buffer_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    );
+    push_call("vkCmdEndTransformFeedbackEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_end_transform_feedback_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdEndTransformFeedbackEXT>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_counter_buffer: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let counter_buffer_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_counter_buffers: *const vk::Buffer =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_counter_buffer_offsets: *const vk::DeviceSize =
+        replay_array(&args[4usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_end_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_end_coding_info: *const vk::VideoEndCodingInfoKHR<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndVideoCodingKHR>(next(
+        104usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_end_coding_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_end_coding_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_end_coding_info);
+    push_call("vkCmdEndVideoCodingKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_end_video_coding_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdEndVideoCodingKHR>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_end_coding_info: *const vk::VideoEndCodingInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_end_coding_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_execute_commands(
+    command_buffer: vk::CommandBuffer,
+    command_buffer_count: u32,
+    p_command_buffers: *const vk::CommandBuffer,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdExecuteCommands>(next(
+        105usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, command_buffer_count, p_command_buffers);
+    }
+    let args = vec![
+        command_buffer.record(),
+        command_buffer_count.record(),
+        record_array(
+            p_command_buffers,
+            command_buffer_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(command_buffer, command_buffer_count, p_command_buffers);
+    push_call("vkCmdExecuteCommands", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_execute_commands(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdExecuteCommands>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let command_buffer_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_command_buffers: *const vk::CommandBuffer =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, command_buffer_count, p_command_buffers);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_execute_generated_commands_nv(
+    command_buffer: vk::CommandBuffer,
+    is_preprocessed: vk::Bool32,
+    p_generated_commands_info: *const vk::GeneratedCommandsInfoNV<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdExecuteGeneratedCommandsNV>(next(
+        106usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, is_preprocessed, p_generated_commands_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        is_preprocessed.record(),
+        record_array(p_generated_commands_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, is_preprocessed, p_generated_commands_info);
+    push_call("vkCmdExecuteGeneratedCommandsNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_execute_generated_commands_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdExecuteGeneratedCommandsNV>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let is_preprocessed: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_generated_commands_info: *const vk::GeneratedCommandsInfoNV<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, is_preprocessed, p_generated_commands_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_fill_buffer(
+    command_buffer: vk::CommandBuffer,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    data: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdFillBuffer>(next(
+        107usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, dst_buffer, dst_offset, size, data);
+    }
+    let args = vec![
+        command_buffer.record(),
+        dst_buffer.record(),
+        dst_offset.record(),
+        size.record(),
+        data.record(),
+    ];
+    next(command_buffer, dst_buffer, dst_offset, size, data);
+    push_call("vkCmdFillBuffer", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_fill_buffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdFillBuffer>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let dst_buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let dst_offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let size: vk::DeviceSize = DeepCopyable::replay(&args[3usize], arena)?;
+    let data: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    f(command_buffer, dst_buffer, dst_offset, size, data);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_insert_debug_utils_label_ext(
+    command_buffer: vk::CommandBuffer,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdInsertDebugUtilsLabelEXT>(next(
+        108usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_label_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_label_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_label_info);
+    push_call("vkCmdInsertDebugUtilsLabelEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_insert_debug_utils_label_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdInsertDebugUtilsLabelEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_label_info: *const vk::DebugUtilsLabelEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_label_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_next_subpass(
+    command_buffer: vk::CommandBuffer,
+    contents: vk::SubpassContents,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdNextSubpass>(next(
+        109usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, contents);
+    }
+    let args = vec![command_buffer.record(), contents.record()];
+    next(command_buffer, contents);
+    push_call("vkCmdNextSubpass", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_next_subpass(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdNextSubpass>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let contents: vk::SubpassContents = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, contents);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_next_subpass2(
+    command_buffer: vk::CommandBuffer,
+    p_subpass_begin_info: *const vk::SubpassBeginInfo<'_>,
+    p_subpass_end_info: *const vk::SubpassEndInfo<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdNextSubpass2>(next(
+        110usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_subpass_begin_info, p_subpass_end_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_subpass_begin_info, 1, |element| element.record()),
+        record_array(p_subpass_end_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_subpass_begin_info, p_subpass_end_info);
+    push_call("vkCmdNextSubpass2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_next_subpass2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdNextSubpass2>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_subpass_begin_info: *const vk::SubpassBeginInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_subpass_end_info: *const vk::SubpassEndInfo<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_subpass_begin_info, p_subpass_end_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_optical_flow_execute_nv(
+    command_buffer: vk::CommandBuffer,
+    session: vk::OpticalFlowSessionNV,
+    p_execute_info: *const vk::OpticalFlowExecuteInfoNV<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdOpticalFlowExecuteNV>(next(
+        111usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, session, p_execute_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        session.record(),
+        record_array(p_execute_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, session, p_execute_info);
+    push_call("vkCmdOpticalFlowExecuteNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_optical_flow_execute_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdOpticalFlowExecuteNV>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let session: vk::OpticalFlowSessionNV = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_execute_info: *const vk::OpticalFlowExecuteInfoNV<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, session, p_execute_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_pipeline_barrier(
+    command_buffer: vk::CommandBuffer,
+    src_stage_mask: vk::PipelineStageFlags,
+    dst_stage_mask: vk::PipelineStageFlags,
+    dependency_flags: vk::DependencyFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const vk::MemoryBarrier<'_>,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const vk::BufferMemoryBarrier<'_>,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const vk::ImageMemoryBarrier<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPipelineBarrier>(next(
+        112usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            src_stage_mask,
+            dst_stage_mask,
+            dependency_flags,
+            memory_barrier_count,
+            p_memory_barriers,
+            buffer_memory_barrier_count,
+            p_buffer_memory_barriers,
+            image_memory_barrier_count,
+            p_image_memory_barriers,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        src_stage_mask.record(),
+        dst_stage_mask.record(),
+        dependency_flags.record(),
+        memory_barrier_count.record(),
+        record_array(
+            p_memory_barriers,
+            memory_barrier_count as usize,
+            |element| element.record(),
+        ),
+        buffer_memory_barrier_count.record(),
+        record_array(
+            p_buffer_memory_barriers,
+            buffer_memory_barrier_count as usize,
+            |element| element.record(),
+        ),
+        image_memory_barrier_count.record(),
+        record_array(
+            p_image_memory_barriers,
+            image_memory_barrier_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        src_stage_mask,
+        dst_stage_mask,
+        dependency_flags,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    );
+    push_call("vkCmdPipelineBarrier", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_pipeline_barrier(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdPipelineBarrier>(f);
+    let args = args::<10usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let src_stage_mask: vk::PipelineStageFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    let dst_stage_mask: vk::PipelineStageFlags = DeepCopyable::replay(&args[2usize], arena)?;
+    let dependency_flags: vk::DependencyFlags = DeepCopyable::replay(&args[3usize], arena)?;
+    let memory_barrier_count: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_memory_barriers: *const vk::MemoryBarrier<'_> =
+        replay_array(&args[5usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let buffer_memory_barrier_count: u32 = DeepCopyable::replay(&args[6usize], arena)?;
+    let p_buffer_memory_barriers: *const vk::BufferMemoryBarrier<'_> =
+        replay_array(&args[7usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let image_memory_barrier_count: u32 = DeepCopyable::replay(&args[8usize], arena)?;
+    let p_image_memory_barriers: *const vk::ImageMemoryBarrier<'_> =
+        replay_array(&args[9usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        src_stage_mask,
+        dst_stage_mask,
+        dependency_flags,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_pipeline_barrier2(
+    command_buffer: vk::CommandBuffer,
+    p_dependency_info: *const vk::DependencyInfo<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPipelineBarrier2>(next(
+        113usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_dependency_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_dependency_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_dependency_info);
+    push_call("vkCmdPipelineBarrier2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_pipeline_barrier2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdPipelineBarrier2>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_dependency_info: *const vk::DependencyInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_dependency_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_preprocess_generated_commands_nv(
+    command_buffer: vk::CommandBuffer,
+    p_generated_commands_info: *const vk::GeneratedCommandsInfoNV<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPreprocessGeneratedCommandsNV>(next(
+        114usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_generated_commands_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_generated_commands_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_generated_commands_info);
+    push_call(
+        "vkCmdPreprocessGeneratedCommandsNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_preprocess_generated_commands_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdPreprocessGeneratedCommandsNV>(
+        f,
+    );
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_generated_commands_info: *const vk::GeneratedCommandsInfoNV<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_generated_commands_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_push_constants(
+    command_buffer: vk::CommandBuffer,
+    layout: vk::PipelineLayout,
+    stage_flags: vk::ShaderStageFlags,
+    offset: u32,
+    size: u32,
+    p_values: *const c_void,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPushConstants>(next(
+        115usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, layout, stage_flags, offset, size, p_values);
+    }
+    let args = vec![
+        command_buffer.record(),
+        layout.record(),
+        stage_flags.record(),
+        offset.record(),
+        size.record(),
+        record_data(p_values.cast::<u8>(), size as usize),
+    ];
+    next(command_buffer, layout, stage_flags, offset, size, p_values);
+    push_call("vkCmdPushConstants", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_push_constants(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdPushConstants>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let layout: vk::PipelineLayout = DeepCopyable::replay(&args[1usize], arena)?;
+    let stage_flags: vk::ShaderStageFlags = DeepCopyable::replay(&args[2usize], arena)?;
+    let offset: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let size: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_values: *const c_void = replay_data::<u8>(&args[5usize], arena)?.cast::<c_void>();
+    f(command_buffer, layout, stage_flags, offset, size, p_values);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_push_descriptor_set_khr(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    set: u32,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const vk::WriteDescriptorSet<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPushDescriptorSetKHR>(next(
+        116usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            pipeline_bind_point,
+            layout,
+            set,
+            descriptor_write_count,
+            p_descriptor_writes,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        pipeline_bind_point.record(),
+        layout.record(),
+        set.record(),
+        descriptor_write_count.record(),
+        record_array(
+            p_descriptor_writes,
+            descriptor_write_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        set,
+        descriptor_write_count,
+        p_descriptor_writes,
+    );
+    push_call("vkCmdPushDescriptorSetKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_push_descriptor_set_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdPushDescriptorSetKHR>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_bind_point: vk::PipelineBindPoint = DeepCopyable::replay(&args[1usize], arena)?;
+    let layout: vk::PipelineLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let set: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let descriptor_write_count: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_descriptor_writes: *const vk::WriteDescriptorSet<'_> =
+        replay_array(&args[5usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        set,
+        descriptor_write_count,
+        p_descriptor_writes,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_push_descriptor_set_with_template_khr(
+    command_buffer: vk::CommandBuffer,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    layout: vk::PipelineLayout,
+    set: u32,
+    p_data: *const c_void,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPushDescriptorSetWithTemplateKHR>(next(
+        117usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            descriptor_update_template,
+            layout,
+            set,
+            p_data,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        descriptor_update_template.record(),
+        layout.record(),
+        set.record(),
+        p_data.record(),
+    ];
+    next(
+        command_buffer,
+        descriptor_update_template,
+        layout,
+        set,
+        p_data,
+    );
+    push_call(
+        "vkCmdPushDescriptorSetWithTemplateKHR",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_push_descriptor_set_with_template_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdPushDescriptorSetWithTemplateKHR,
+    >(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let descriptor_update_template: vk::DescriptorUpdateTemplate =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let layout: vk::PipelineLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let set: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_data: *const c_void = DeepCopyable::replay(&args[4usize], arena)?;
+    f(
+        command_buffer,
+        descriptor_update_template,
+        layout,
+        set,
+        p_data,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_reset_event(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdResetEvent>(next(
+        118usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, event, stage_mask);
+    }
+    let args = vec![command_buffer.record(), event.record(), stage_mask.record()];
+    next(command_buffer, event, stage_mask);
+    push_call("vkCmdResetEvent", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_reset_event(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdResetEvent>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let event: vk::Event = DeepCopyable::replay(&args[1usize], arena)?;
+    let stage_mask: vk::PipelineStageFlags = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, event, stage_mask);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_reset_event2(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags2,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdResetEvent2>(next(
+        119usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, event, stage_mask);
+    }
+    let args = vec![command_buffer.record(), event.record(), stage_mask.record()];
+    next(command_buffer, event, stage_mask);
+    push_call("vkCmdResetEvent2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_reset_event2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdResetEvent2>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let event: vk::Event = DeepCopyable::replay(&args[1usize], arena)?;
+    let stage_mask: vk::PipelineStageFlags2 = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, event, stage_mask);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_reset_query_pool(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdResetQueryPool>(next(
+        120usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, query_pool, first_query, query_count);
+    }
+    let args = vec![
+        command_buffer.record(),
+        query_pool.record(),
+        first_query.record(),
+        query_count.record(),
+    ];
+    next(command_buffer, query_pool, first_query, query_count);
+    push_call("vkCmdResetQueryPool", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_reset_query_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdResetQueryPool>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let first_query: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let query_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, query_pool, first_query, query_count);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_resolve_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageResolve,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdResolveImage>(next(
+        121usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            src_image,
+            src_image_layout,
+            dst_image,
+            dst_image_layout,
+            region_count,
+            p_regions,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        src_image.record(),
+        src_image_layout.record(),
+        dst_image.record(),
+        dst_image_layout.record(),
+        region_count.record(),
+        record_array(p_regions, region_count as usize, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    push_call("vkCmdResolveImage", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_resolve_image(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdResolveImage>(f);
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let src_image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let src_image_layout: vk::ImageLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let dst_image: vk::Image = DeepCopyable::replay(&args[3usize], arena)?;
+    let dst_image_layout: vk::ImageLayout = DeepCopyable::replay(&args[4usize], arena)?;
+    let region_count: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let p_regions: *const vk::ImageResolve = replay_array(&args[6usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_resolve_image2(
+    command_buffer: vk::CommandBuffer,
+    p_resolve_image_info: *const vk::ResolveImageInfo2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdResolveImage2>(next(
+        122usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_resolve_image_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_resolve_image_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_resolve_image_info);
+    push_call("vkCmdResolveImage2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_resolve_image2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdResolveImage2>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_resolve_image_info: *const vk::ResolveImageInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_resolve_image_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_alpha_to_coverage_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    alpha_to_coverage_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetAlphaToCoverageEnableEXT>(next(
+        123usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, alpha_to_coverage_enable);
+    }
+    let args = vec![command_buffer.record(), alpha_to_coverage_enable.record()];
+    next(command_buffer, alpha_to_coverage_enable);
+    push_call(
+        "vkCmdSetAlphaToCoverageEnableEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_alpha_to_coverage_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetAlphaToCoverageEnableEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let alpha_to_coverage_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, alpha_to_coverage_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_alpha_to_one_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    alpha_to_one_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetAlphaToOneEnableEXT>(next(
+        124usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, alpha_to_one_enable);
+    }
+    let args = vec![command_buffer.record(), alpha_to_one_enable.record()];
+    next(command_buffer, alpha_to_one_enable);
+    push_call("vkCmdSetAlphaToOneEnableEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_alpha_to_one_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetAlphaToOneEnableEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let alpha_to_one_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, alpha_to_one_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_attachment_feedback_loop_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    aspect_mask: vk::ImageAspectFlags,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetAttachmentFeedbackLoopEnableEXT>(
+        next(125usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(command_buffer, aspect_mask);
+    }
+    let args = vec![command_buffer.record(), aspect_mask.record()];
+    next(command_buffer, aspect_mask);
+    push_call(
+        "vkCmdSetAttachmentFeedbackLoopEnableEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_attachment_feedback_loop_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdSetAttachmentFeedbackLoopEnableEXT,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let aspect_mask: vk::ImageAspectFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, aspect_mask);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_blend_constants(
+    command_buffer: vk::CommandBuffer,
+    blend_constants: *const [f32; 4usize],
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetBlendConstants>(next(
+        126usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, blend_constants);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(blend_constants, 1, |element| element.record()),
+    ];
+    next(command_buffer, blend_constants);
+    push_call("vkCmdSetBlendConstants", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_blend_constants(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetBlendConstants>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let blend_constants: *const [f32; 4usize] =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, blend_constants);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_checkpoint_nv(
+    command_buffer: vk::CommandBuffer,
+    p_checkpoint_marker: *const c_void,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCheckpointNV>(next(
+        127usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_checkpoint_marker);
+    }
+    let args = vec![command_buffer.record(), p_checkpoint_marker.record()];
+    next(command_buffer, p_checkpoint_marker);
+    push_call("vkCmdSetCheckpointNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_checkpoint_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetCheckpointNV>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_checkpoint_marker: *const c_void = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, p_checkpoint_marker);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_coarse_sample_order_nv(
+    command_buffer: vk::CommandBuffer,
+    sample_order_type: vk::CoarseSampleOrderTypeNV,
+    custom_sample_order_count: u32,
+    p_custom_sample_orders: *const vk::CoarseSampleOrderCustomNV<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoarseSampleOrderNV>(next(
+        128usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            sample_order_type,
+            custom_sample_order_count,
+            p_custom_sample_orders,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        sample_order_type.record(),
+        custom_sample_order_count.record(),
+        record_array(
+            p_custom_sample_orders,
+            custom_sample_order_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        sample_order_type,
+        custom_sample_order_count,
+        p_custom_sample_orders,
+    );
+    push_call("vkCmdSetCoarseSampleOrderNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_coarse_sample_order_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetCoarseSampleOrderNV>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let sample_order_type: vk::CoarseSampleOrderTypeNV =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let custom_sample_order_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_custom_sample_orders: *const vk::CoarseSampleOrderCustomNV<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        sample_order_type,
+        custom_sample_order_count,
+        p_custom_sample_orders,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_color_blend_advanced_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_advanced: *const vk::ColorBlendAdvancedEXT,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetColorBlendAdvancedEXT>(next(
+        129usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_attachment,
+            attachment_count,
+            p_color_blend_advanced,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_attachment.record(),
+        attachment_count.record(),
+        record_array(
+            p_color_blend_advanced,
+            attachment_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_advanced,
+    );
+    push_call("vkCmdSetColorBlendAdvancedEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_color_blend_advanced_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetColorBlendAdvancedEXT>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_attachment: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let attachment_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_color_blend_advanced: *const vk::ColorBlendAdvancedEXT =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_advanced,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_color_blend_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_enables: *const vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetColorBlendEnableEXT>(next(
+        130usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_attachment,
+            attachment_count,
+            p_color_blend_enables,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_attachment.record(),
+        attachment_count.record(),
+        record_array(
+            p_color_blend_enables,
+            attachment_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_enables,
+    );
+    push_call("vkCmdSetColorBlendEnableEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_color_blend_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetColorBlendEnableEXT>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_attachment: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let attachment_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_color_blend_enables: *const vk::Bool32 =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_enables,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_color_blend_equation_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_equations: *const vk::ColorBlendEquationEXT,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetColorBlendEquationEXT>(next(
+        131usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_attachment,
+            attachment_count,
+            p_color_blend_equations,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_attachment.record(),
+        attachment_count.record(),
+        record_array(
+            p_color_blend_equations,
+            attachment_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_equations,
+    );
+    push_call("vkCmdSetColorBlendEquationEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_color_blend_equation_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetColorBlendEquationEXT>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_attachment: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let attachment_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_color_blend_equations: *const vk::ColorBlendEquationEXT =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_equations,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_color_write_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    attachment_count: u32,
+    p_color_write_enables: *const vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetColorWriteEnableEXT>(next(
+        132usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, attachment_count, p_color_write_enables);
+    }
+    let args = vec![
+        command_buffer.record(),
+        attachment_count.record(),
+        record_array(
+            p_color_write_enables,
+            attachment_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(command_buffer, attachment_count, p_color_write_enables);
+    push_call("vkCmdSetColorWriteEnableEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_color_write_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetColorWriteEnableEXT>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let attachment_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_color_write_enables: *const vk::Bool32 =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, attachment_count, p_color_write_enables);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_color_write_mask_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_write_masks: *const vk::ColorComponentFlags,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetColorWriteMaskEXT>(next(
+        133usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_attachment,
+            attachment_count,
+            p_color_write_masks,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_attachment.record(),
+        attachment_count.record(),
+        record_array(p_color_write_masks, attachment_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_write_masks,
+    );
+    push_call("vkCmdSetColorWriteMaskEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_color_write_mask_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetColorWriteMaskEXT>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_attachment: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let attachment_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_color_write_masks: *const vk::ColorComponentFlags =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_write_masks,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_conservative_rasterization_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    conservative_rasterization_mode: vk::ConservativeRasterizationModeEXT,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetConservativeRasterizationModeEXT>(
+        next(134usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(command_buffer, conservative_rasterization_mode);
+    }
+    let args = vec![
+        command_buffer.record(),
+        conservative_rasterization_mode.record(),
+    ];
+    next(command_buffer, conservative_rasterization_mode);
+    push_call(
+        "vkCmdSetConservativeRasterizationModeEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_conservative_rasterization_mode_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdSetConservativeRasterizationModeEXT,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let conservative_rasterization_mode: vk::ConservativeRasterizationModeEXT =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, conservative_rasterization_mode);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_coverage_modulation_mode_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_mode: vk::CoverageModulationModeNV,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageModulationModeNV>(next(
+        135usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, coverage_modulation_mode);
+    }
+    let args = vec![command_buffer.record(), coverage_modulation_mode.record()];
+    next(command_buffer, coverage_modulation_mode);
+    push_call(
+        "vkCmdSetCoverageModulationModeNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_coverage_modulation_mode_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetCoverageModulationModeNV>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let coverage_modulation_mode: vk::CoverageModulationModeNV =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, coverage_modulation_mode);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_coverage_modulation_table_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_table_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageModulationTableEnableNV>(
+        next(136usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(command_buffer, coverage_modulation_table_enable);
+    }
+    let args = vec![
+        command_buffer.record(),
+        coverage_modulation_table_enable.record(),
+    ];
+    next(command_buffer, coverage_modulation_table_enable);
+    push_call(
+        "vkCmdSetCoverageModulationTableEnableNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_coverage_modulation_table_enable_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdSetCoverageModulationTableEnableNV,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let coverage_modulation_table_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, coverage_modulation_table_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_coverage_modulation_table_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_table_count: u32,
+    p_coverage_modulation_table: *const f32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageModulationTableNV>(next(
+        137usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            coverage_modulation_table_count,
+            p_coverage_modulation_table,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        coverage_modulation_table_count.record(),
+        record_data(
+            p_coverage_modulation_table,
+            coverage_modulation_table_count as usize,
+        ),
+    ];
+    next(
+        command_buffer,
+        coverage_modulation_table_count,
+        p_coverage_modulation_table,
+    );
+    push_call(
+        "vkCmdSetCoverageModulationTableNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_coverage_modulation_table_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetCoverageModulationTableNV>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let coverage_modulation_table_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_coverage_modulation_table: *const f32 = replay_data(&args[2usize], arena)?;
+    f(
+        command_buffer,
+        coverage_modulation_table_count,
+        p_coverage_modulation_table,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_coverage_reduction_mode_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_reduction_mode: vk::CoverageReductionModeNV,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageReductionModeNV>(next(
+        138usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, coverage_reduction_mode);
+    }
+    let args = vec![command_buffer.record(), coverage_reduction_mode.record()];
+    next(command_buffer, coverage_reduction_mode);
+    push_call("vkCmdSetCoverageReductionModeNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_coverage_reduction_mode_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetCoverageReductionModeNV>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let coverage_reduction_mode: vk::CoverageReductionModeNV =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, coverage_reduction_mode);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_coverage_to_color_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_to_color_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageToColorEnableNV>(next(
+        139usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, coverage_to_color_enable);
+    }
+    let args = vec![command_buffer.record(), coverage_to_color_enable.record()];
+    next(command_buffer, coverage_to_color_enable);
+    push_call("vkCmdSetCoverageToColorEnableNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_coverage_to_color_enable_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetCoverageToColorEnableNV>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let coverage_to_color_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, coverage_to_color_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_coverage_to_color_location_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_to_color_location: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageToColorLocationNV>(next(
+        140usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, coverage_to_color_location);
+    }
+    let args = vec![command_buffer.record(), coverage_to_color_location.record()];
+    next(command_buffer, coverage_to_color_location);
+    push_call(
+        "vkCmdSetCoverageToColorLocationNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_coverage_to_color_location_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetCoverageToColorLocationNV>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let coverage_to_color_location: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, coverage_to_color_location);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_cull_mode(
+    command_buffer: vk::CommandBuffer,
+    cull_mode: vk::CullModeFlags,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCullMode>(next(
+        141usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, cull_mode);
+    }
+    let args = vec![command_buffer.record(), cull_mode.record()];
+    next(command_buffer, cull_mode);
+    push_call("vkCmdSetCullMode", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_cull_mode(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetCullMode>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let cull_mode: vk::CullModeFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, cull_mode);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_bias(
+    command_buffer: vk::CommandBuffer,
+    depth_bias_constant_factor: f32,
+    depth_bias_clamp: f32,
+    depth_bias_slope_factor: f32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthBias>(next(
+        142usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            depth_bias_constant_factor,
+            depth_bias_clamp,
+            depth_bias_slope_factor,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        depth_bias_constant_factor.record(),
+        depth_bias_clamp.record(),
+        depth_bias_slope_factor.record(),
+    ];
+    next(
+        command_buffer,
+        depth_bias_constant_factor,
+        depth_bias_clamp,
+        depth_bias_slope_factor,
+    );
+    push_call("vkCmdSetDepthBias", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_depth_bias(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDepthBias>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let depth_bias_constant_factor: f32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let depth_bias_clamp: f32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let depth_bias_slope_factor: f32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(
+        command_buffer,
+        depth_bias_constant_factor,
+        depth_bias_clamp,
+        depth_bias_slope_factor,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_bias2_ext(
+    command_buffer: vk::CommandBuffer,
+    p_depth_bias_info: *const vk::DepthBiasInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthBias2EXT>(next(
+        143usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_depth_bias_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_depth_bias_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_depth_bias_info);
+    push_call("vkCmdSetDepthBias2EXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_depth_bias2_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDepthBias2EXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_depth_bias_info: *const vk::DepthBiasInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_depth_bias_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_bias_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_bias_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthBiasEnable>(next(
+        144usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, depth_bias_enable);
+    }
+    let args = vec![command_buffer.record(), depth_bias_enable.record()];
+    next(command_buffer, depth_bias_enable);
+    push_call("vkCmdSetDepthBiasEnable", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_depth_bias_enable(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDepthBiasEnable>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let depth_bias_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, depth_bias_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_bounds(
+    command_buffer: vk::CommandBuffer,
+    min_depth_bounds: f32,
+    max_depth_bounds: f32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthBounds>(next(
+        145usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, min_depth_bounds, max_depth_bounds);
+    }
+    let args = vec![
+        command_buffer.record(),
+        min_depth_bounds.record(),
+        max_depth_bounds.record(),
+    ];
+    next(command_buffer, min_depth_bounds, max_depth_bounds);
+    push_call("vkCmdSetDepthBounds", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_depth_bounds(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDepthBounds>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let min_depth_bounds: f32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let max_depth_bounds: f32 = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, min_depth_bounds, max_depth_bounds);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_bounds_test_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_bounds_test_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthBoundsTestEnable>(next(
+        146usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, depth_bounds_test_enable);
+    }
+    let args = vec![command_buffer.record(), depth_bounds_test_enable.record()];
+    next(command_buffer, depth_bounds_test_enable);
+    push_call("vkCmdSetDepthBoundsTestEnable", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_depth_bounds_test_enable(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDepthBoundsTestEnable>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let depth_bounds_test_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, depth_bounds_test_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_clamp_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    depth_clamp_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthClampEnableEXT>(next(
+        147usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, depth_clamp_enable);
+    }
+    let args = vec![command_buffer.record(), depth_clamp_enable.record()];
+    next(command_buffer, depth_clamp_enable);
+    push_call("vkCmdSetDepthClampEnableEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_depth_clamp_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDepthClampEnableEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let depth_clamp_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, depth_clamp_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_clip_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    depth_clip_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthClipEnableEXT>(next(
+        148usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, depth_clip_enable);
+    }
+    let args = vec![command_buffer.record(), depth_clip_enable.record()];
+    next(command_buffer, depth_clip_enable);
+    push_call("vkCmdSetDepthClipEnableEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_depth_clip_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDepthClipEnableEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let depth_clip_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, depth_clip_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_clip_negative_one_to_one_ext(
+    command_buffer: vk::CommandBuffer,
+    negative_one_to_one: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthClipNegativeOneToOneEXT>(next(
+        149usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, negative_one_to_one);
+    }
+    let args = vec![command_buffer.record(), negative_one_to_one.record()];
+    next(command_buffer, negative_one_to_one);
+    push_call(
+        "vkCmdSetDepthClipNegativeOneToOneEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_depth_clip_negative_one_to_one_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdSetDepthClipNegativeOneToOneEXT,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let negative_one_to_one: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, negative_one_to_one);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_compare_op(
+    command_buffer: vk::CommandBuffer,
+    depth_compare_op: vk::CompareOp,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthCompareOp>(next(
+        150usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, depth_compare_op);
+    }
+    let args = vec![command_buffer.record(), depth_compare_op.record()];
+    next(command_buffer, depth_compare_op);
+    push_call("vkCmdSetDepthCompareOp", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_depth_compare_op(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDepthCompareOp>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let depth_compare_op: vk::CompareOp = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, depth_compare_op);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_test_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_test_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthTestEnable>(next(
+        151usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, depth_test_enable);
+    }
+    let args = vec![command_buffer.record(), depth_test_enable.record()];
+    next(command_buffer, depth_test_enable);
+    push_call("vkCmdSetDepthTestEnable", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_depth_test_enable(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDepthTestEnable>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let depth_test_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, depth_test_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_depth_write_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_write_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthWriteEnable>(next(
+        152usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, depth_write_enable);
+    }
+    let args = vec![command_buffer.record(), depth_write_enable.record()];
+    next(command_buffer, depth_write_enable);
+    push_call("vkCmdSetDepthWriteEnable", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_depth_write_enable(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDepthWriteEnable>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let depth_write_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, depth_write_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_descriptor_buffer_offsets_ext(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    first_set: u32,
+    set_count: u32,
+    p_buffer_indices: *const u32,
+    p_offsets: *const vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDescriptorBufferOffsetsEXT>(next(
+        153usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            pipeline_bind_point,
+            layout,
+            first_set,
+            set_count,
+            p_buffer_indices,
+            p_offsets,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        pipeline_bind_point.record(),
+        layout.record(),
+        first_set.record(),
+        set_count.record(),
+        record_data(p_buffer_indices, set_count as usize),
+        record_array(p_offsets, set_count as usize, |element| element.record()),
+    ];
+    next(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        first_set,
+        set_count,
+        p_buffer_indices,
+        p_offsets,
+    );
+    push_call(
+        "vkCmdSetDescriptorBufferOffsetsEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_descriptor_buffer_offsets_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDescriptorBufferOffsetsEXT>(
+        f,
+    );
+    let args = args::<7usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_bind_point: vk::PipelineBindPoint = DeepCopyable::replay(&args[1usize], arena)?;
+    let layout: vk::PipelineLayout = DeepCopyable::replay(&args[2usize], arena)?;
+    let first_set: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let set_count: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_buffer_indices: *const u32 = replay_data(&args[5usize], arena)?;
+    let p_offsets: *const vk::DeviceSize = replay_array(&args[6usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        first_set,
+        set_count,
+        p_buffer_indices,
+        p_offsets,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_device_mask(command_buffer: vk::CommandBuffer, device_mask: u32) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDeviceMask>(next(
+        154usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, device_mask);
+    }
+    let args = vec![command_buffer.record(), device_mask.record()];
+    next(command_buffer, device_mask);
+    push_call("vkCmdSetDeviceMask", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_device_mask(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDeviceMask>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let device_mask: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, device_mask);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_discard_rectangle_ext(
+    command_buffer: vk::CommandBuffer,
+    first_discard_rectangle: u32,
+    discard_rectangle_count: u32,
+    p_discard_rectangles: *const vk::Rect2D,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDiscardRectangleEXT>(next(
+        155usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_discard_rectangle,
+            discard_rectangle_count,
+            p_discard_rectangles,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_discard_rectangle.record(),
+        discard_rectangle_count.record(),
+        record_array(
+            p_discard_rectangles,
+            discard_rectangle_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        first_discard_rectangle,
+        discard_rectangle_count,
+        p_discard_rectangles,
+    );
+    push_call("vkCmdSetDiscardRectangleEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_discard_rectangle_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDiscardRectangleEXT>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_discard_rectangle: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let discard_rectangle_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_discard_rectangles: *const vk::Rect2D =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_discard_rectangle,
+        discard_rectangle_count,
+        p_discard_rectangles,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_discard_rectangle_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    discard_rectangle_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDiscardRectangleEnableEXT>(next(
+        156usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, discard_rectangle_enable);
+    }
+    let args = vec![command_buffer.record(), discard_rectangle_enable.record()];
+    next(command_buffer, discard_rectangle_enable);
+    push_call(
+        "vkCmdSetDiscardRectangleEnableEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_discard_rectangle_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDiscardRectangleEnableEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let discard_rectangle_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, discard_rectangle_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_discard_rectangle_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    discard_rectangle_mode: vk::DiscardRectangleModeEXT,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDiscardRectangleModeEXT>(next(
+        157usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, discard_rectangle_mode);
+    }
+    let args = vec![command_buffer.record(), discard_rectangle_mode.record()];
+    next(command_buffer, discard_rectangle_mode);
+    push_call("vkCmdSetDiscardRectangleModeEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_discard_rectangle_mode_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetDiscardRectangleModeEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let discard_rectangle_mode: vk::DiscardRectangleModeEXT =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, discard_rectangle_mode);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_event(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetEvent>(next(
+        158usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, event, stage_mask);
+    }
+    let args = vec![command_buffer.record(), event.record(), stage_mask.record()];
+    next(command_buffer, event, stage_mask);
+    push_call("vkCmdSetEvent", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_event(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetEvent>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let event: vk::Event = DeepCopyable::replay(&args[1usize], arena)?;
+    let stage_mask: vk::PipelineStageFlags = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, event, stage_mask);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_event2(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    p_dependency_info: *const vk::DependencyInfo<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetEvent2>(next(
+        159usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, event, p_dependency_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        event.record(),
+        record_array(p_dependency_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, event, p_dependency_info);
+    push_call("vkCmdSetEvent2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_event2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetEvent2>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let event: vk::Event = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_dependency_info: *const vk::DependencyInfo<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, event, p_dependency_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_exclusive_scissor_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissor_enables: *const vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetExclusiveScissorEnableNV>(next(
+        160usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_exclusive_scissor,
+            exclusive_scissor_count,
+            p_exclusive_scissor_enables,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_exclusive_scissor.record(),
+        exclusive_scissor_count.record(),
+        record_array(
+            p_exclusive_scissor_enables,
+            exclusive_scissor_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        first_exclusive_scissor,
+        exclusive_scissor_count,
+        p_exclusive_scissor_enables,
+    );
+    push_call(
+        "vkCmdSetExclusiveScissorEnableNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_exclusive_scissor_enable_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetExclusiveScissorEnableNV>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_exclusive_scissor: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let exclusive_scissor_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_exclusive_scissor_enables: *const vk::Bool32 =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_exclusive_scissor,
+        exclusive_scissor_count,
+        p_exclusive_scissor_enables,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_exclusive_scissor_nv(
+    command_buffer: vk::CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissors: *const vk::Rect2D,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetExclusiveScissorNV>(next(
+        161usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_exclusive_scissor,
+            exclusive_scissor_count,
+            p_exclusive_scissors,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_exclusive_scissor.record(),
+        exclusive_scissor_count.record(),
+        record_array(
+            p_exclusive_scissors,
+            exclusive_scissor_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        first_exclusive_scissor,
+        exclusive_scissor_count,
+        p_exclusive_scissors,
+    );
+    push_call("vkCmdSetExclusiveScissorNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_exclusive_scissor_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetExclusiveScissorNV>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_exclusive_scissor: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let exclusive_scissor_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_exclusive_scissors: *const vk::Rect2D =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_exclusive_scissor,
+        exclusive_scissor_count,
+        p_exclusive_scissors,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_extra_primitive_overestimation_size_ext(
+    command_buffer: vk::CommandBuffer,
+    extra_primitive_overestimation_size: f32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetExtraPrimitiveOverestimationSizeEXT>(
+        next(162usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(command_buffer, extra_primitive_overestimation_size);
+    }
+    let args = vec![
+        command_buffer.record(),
+        extra_primitive_overestimation_size.record(),
+    ];
+    next(command_buffer, extra_primitive_overestimation_size);
+    push_call(
+        "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_extra_primitive_overestimation_size_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdSetExtraPrimitiveOverestimationSizeEXT,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let extra_primitive_overestimation_size: f32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, extra_primitive_overestimation_size);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_fragment_shading_rate_enum_nv(
+    command_buffer: vk::CommandBuffer,
+    shading_rate: vk::FragmentShadingRateNV,
+    combiner_ops: *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetFragmentShadingRateEnumNV>(next(
+        163usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, shading_rate, combiner_ops);
+    }
+    let args = vec![
+        command_buffer.record(),
+        shading_rate.record(),
+        record_array(combiner_ops, 1, |element| element.record()),
+    ];
+    next(command_buffer, shading_rate, combiner_ops);
+    push_call(
+        "vkCmdSetFragmentShadingRateEnumNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_fragment_shading_rate_enum_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetFragmentShadingRateEnumNV>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let shading_rate: vk::FragmentShadingRateNV = DeepCopyable::replay(&args[1usize], arena)?;
+    let combiner_ops: *const [vk::FragmentShadingRateCombinerOpKHR; 2usize] =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, shading_rate, combiner_ops);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_fragment_shading_rate_khr(
+    command_buffer: vk::CommandBuffer,
+    p_fragment_size: *const vk::Extent2D,
+    combiner_ops: *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetFragmentShadingRateKHR>(next(
+        164usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_fragment_size, combiner_ops);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_fragment_size, 1, |element| element.record()),
+        record_array(combiner_ops, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_fragment_size, combiner_ops);
+    push_call("vkCmdSetFragmentShadingRateKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_fragment_shading_rate_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetFragmentShadingRateKHR>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_fragment_size: *const vk::Extent2D =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let combiner_ops: *const [vk::FragmentShadingRateCombinerOpKHR; 2usize] =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_fragment_size, combiner_ops);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_front_face(
+    command_buffer: vk::CommandBuffer,
+    front_face: vk::FrontFace,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetFrontFace>(next(
+        165usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, front_face);
+    }
+    let args = vec![command_buffer.record(), front_face.record()];
+    next(command_buffer, front_face);
+    push_call("vkCmdSetFrontFace", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_front_face(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetFrontFace>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let front_face: vk::FrontFace = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, front_face);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_line_rasterization_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    line_rasterization_mode: vk::LineRasterizationModeEXT,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLineRasterizationModeEXT>(next(
+        166usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, line_rasterization_mode);
+    }
+    let args = vec![command_buffer.record(), line_rasterization_mode.record()];
+    next(command_buffer, line_rasterization_mode);
+    push_call(
+        "vkCmdSetLineRasterizationModeEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_line_rasterization_mode_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetLineRasterizationModeEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let line_rasterization_mode: vk::LineRasterizationModeEXT =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, line_rasterization_mode);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_line_stipple_ext(
+    command_buffer: vk::CommandBuffer,
+    line_stipple_factor: u32,
+    line_stipple_pattern: u16,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLineStippleEXT>(next(
+        167usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, line_stipple_factor, line_stipple_pattern);
+    }
+    let args = vec![
+        command_buffer.record(),
+        line_stipple_factor.record(),
+        line_stipple_pattern.record(),
+    ];
+    next(command_buffer, line_stipple_factor, line_stipple_pattern);
+    push_call("vkCmdSetLineStippleEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_line_stipple_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetLineStippleEXT>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let line_stipple_factor: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let line_stipple_pattern: u16 = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, line_stipple_factor, line_stipple_pattern);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_line_stipple_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    stippled_line_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLineStippleEnableEXT>(next(
+        168usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, stippled_line_enable);
+    }
+    let args = vec![command_buffer.record(), stippled_line_enable.record()];
+    next(command_buffer, stippled_line_enable);
+    push_call("vkCmdSetLineStippleEnableEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_line_stipple_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetLineStippleEnableEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let stippled_line_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, stippled_line_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_line_width(command_buffer: vk::CommandBuffer, line_width: f32) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLineWidth>(next(
+        169usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, line_width);
+    }
+    let args = vec![command_buffer.record(), line_width.record()];
+    next(command_buffer, line_width);
+    push_call("vkCmdSetLineWidth", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_line_width(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetLineWidth>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let line_width: f32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, line_width);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_logic_op_ext(
+    command_buffer: vk::CommandBuffer,
+    logic_op: vk::LogicOp,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLogicOpEXT>(next(
+        170usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, logic_op);
+    }
+    let args = vec![command_buffer.record(), logic_op.record()];
+    next(command_buffer, logic_op);
+    push_call("vkCmdSetLogicOpEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_logic_op_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetLogicOpEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let logic_op: vk::LogicOp = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, logic_op);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_logic_op_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    logic_op_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLogicOpEnableEXT>(next(
+        171usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, logic_op_enable);
+    }
+    let args = vec![command_buffer.record(), logic_op_enable.record()];
+    next(command_buffer, logic_op_enable);
+    push_call("vkCmdSetLogicOpEnableEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_logic_op_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetLogicOpEnableEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let logic_op_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, logic_op_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_patch_control_points_ext(
+    command_buffer: vk::CommandBuffer,
+    patch_control_points: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPatchControlPointsEXT>(next(
+        172usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, patch_control_points);
+    }
+    let args = vec![command_buffer.record(), patch_control_points.record()];
+    next(command_buffer, patch_control_points);
+    push_call("vkCmdSetPatchControlPointsEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_patch_control_points_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetPatchControlPointsEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let patch_control_points: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, patch_control_points);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_performance_marker_intel(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::PerformanceMarkerInfoINTEL<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPerformanceMarkerINTEL>(next(
+        173usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_marker_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_marker_info, 1, |element| element.record()),
+    ];
+    let result = next(command_buffer, p_marker_info);
+    push_call(
+        "vkCmdSetPerformanceMarkerINTEL",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_cmd_set_performance_marker_intel(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetPerformanceMarkerINTEL>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_marker_info: *const vk::PerformanceMarkerInfoINTEL<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(command_buffer, p_marker_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn cmd_set_performance_override_intel(
+    command_buffer: vk::CommandBuffer,
+    p_override_info: *const vk::PerformanceOverrideInfoINTEL<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPerformanceOverrideINTEL>(next(
+        174usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_override_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_override_info, 1, |element| element.record()),
+    ];
+    let result = next(command_buffer, p_override_info);
+    push_call(
+        "vkCmdSetPerformanceOverrideINTEL",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_cmd_set_performance_override_intel(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetPerformanceOverrideINTEL>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_override_info: *const vk::PerformanceOverrideInfoINTEL<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(command_buffer, p_override_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn cmd_set_performance_stream_marker_intel(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::PerformanceStreamMarkerInfoINTEL<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPerformanceStreamMarkerINTEL>(next(
+        175usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_marker_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_marker_info, 1, |element| element.record()),
+    ];
+    let result = next(command_buffer, p_marker_info);
+    push_call(
+        "vkCmdSetPerformanceStreamMarkerINTEL",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_cmd_set_performance_stream_marker_intel(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdSetPerformanceStreamMarkerINTEL,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_marker_info: *const vk::PerformanceStreamMarkerInfoINTEL<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(command_buffer, p_marker_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn cmd_set_polygon_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    polygon_mode: vk::PolygonMode,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPolygonModeEXT>(next(
+        176usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, polygon_mode);
+    }
+    let args = vec![command_buffer.record(), polygon_mode.record()];
+    next(command_buffer, polygon_mode);
+    push_call("vkCmdSetPolygonModeEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_polygon_mode_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetPolygonModeEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let polygon_mode: vk::PolygonMode = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, polygon_mode);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_primitive_restart_enable(
+    command_buffer: vk::CommandBuffer,
+    primitive_restart_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPrimitiveRestartEnable>(next(
+        177usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, primitive_restart_enable);
+    }
+    let args = vec![command_buffer.record(), primitive_restart_enable.record()];
+    next(command_buffer, primitive_restart_enable);
+    push_call("vkCmdSetPrimitiveRestartEnable", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_primitive_restart_enable(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetPrimitiveRestartEnable>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let primitive_restart_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, primitive_restart_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_primitive_topology(
+    command_buffer: vk::CommandBuffer,
+    primitive_topology: vk::PrimitiveTopology,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPrimitiveTopology>(next(
+        178usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, primitive_topology);
+    }
+    let args = vec![command_buffer.record(), primitive_topology.record()];
+    next(command_buffer, primitive_topology);
+    push_call("vkCmdSetPrimitiveTopology", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_primitive_topology(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetPrimitiveTopology>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let primitive_topology: vk::PrimitiveTopology = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, primitive_topology);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_provoking_vertex_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    provoking_vertex_mode: vk::ProvokingVertexModeEXT,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetProvokingVertexModeEXT>(next(
+        179usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, provoking_vertex_mode);
+    }
+    let args = vec![command_buffer.record(), provoking_vertex_mode.record()];
+    next(command_buffer, provoking_vertex_mode);
+    push_call("vkCmdSetProvokingVertexModeEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_provoking_vertex_mode_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetProvokingVertexModeEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let provoking_vertex_mode: vk::ProvokingVertexModeEXT =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, provoking_vertex_mode);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_rasterization_samples_ext(
+    command_buffer: vk::CommandBuffer,
+    rasterization_samples: vk::SampleCountFlags,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetRasterizationSamplesEXT>(next(
+        180usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, rasterization_samples);
+    }
+    let args = vec![command_buffer.record(), rasterization_samples.record()];
+    next(command_buffer, rasterization_samples);
+    push_call("vkCmdSetRasterizationSamplesEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_rasterization_samples_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetRasterizationSamplesEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let rasterization_samples: vk::SampleCountFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, rasterization_samples);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_rasterization_stream_ext(
+    command_buffer: vk::CommandBuffer,
+    rasterization_stream: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetRasterizationStreamEXT>(next(
+        181usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, rasterization_stream);
+    }
+    let args = vec![command_buffer.record(), rasterization_stream.record()];
+    next(command_buffer, rasterization_stream);
+    push_call("vkCmdSetRasterizationStreamEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_rasterization_stream_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetRasterizationStreamEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let rasterization_stream: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, rasterization_stream);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_rasterizer_discard_enable(
+    command_buffer: vk::CommandBuffer,
+    rasterizer_discard_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetRasterizerDiscardEnable>(next(
+        182usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, rasterizer_discard_enable);
+    }
+    let args = vec![command_buffer.record(), rasterizer_discard_enable.record()];
+    next(command_buffer, rasterizer_discard_enable);
+    push_call("vkCmdSetRasterizerDiscardEnable", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_rasterizer_discard_enable(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetRasterizerDiscardEnable>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let rasterizer_discard_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, rasterizer_discard_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_ray_tracing_pipeline_stack_size_khr(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stack_size: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetRayTracingPipelineStackSizeKHR>(next(
+        183usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, pipeline_stack_size);
+    }
+    let args = vec![command_buffer.record(), pipeline_stack_size.record()];
+    next(command_buffer, pipeline_stack_size);
+    push_call(
+        "vkCmdSetRayTracingPipelineStackSizeKHR",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_ray_tracing_pipeline_stack_size_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdSetRayTracingPipelineStackSizeKHR,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_stack_size: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, pipeline_stack_size);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_representative_fragment_test_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    representative_fragment_test_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetRepresentativeFragmentTestEnableNV>(
+        next(184usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(command_buffer, representative_fragment_test_enable);
+    }
+    let args = vec![
+        command_buffer.record(),
+        representative_fragment_test_enable.record(),
+    ];
+    next(command_buffer, representative_fragment_test_enable);
+    push_call(
+        "vkCmdSetRepresentativeFragmentTestEnableNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_representative_fragment_test_enable_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdSetRepresentativeFragmentTestEnableNV,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let representative_fragment_test_enable: vk::Bool32 =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, representative_fragment_test_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_sample_locations_ext(
+    command_buffer: vk::CommandBuffer,
+    p_sample_locations_info: *const vk::SampleLocationsInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetSampleLocationsEXT>(next(
+        185usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, p_sample_locations_info);
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_sample_locations_info, 1, |element| element.record()),
+    ];
+    next(command_buffer, p_sample_locations_info);
+    push_call("vkCmdSetSampleLocationsEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_sample_locations_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetSampleLocationsEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_sample_locations_info: *const vk::SampleLocationsInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, p_sample_locations_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_sample_locations_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    sample_locations_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetSampleLocationsEnableEXT>(next(
+        186usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, sample_locations_enable);
+    }
+    let args = vec![command_buffer.record(), sample_locations_enable.record()];
+    next(command_buffer, sample_locations_enable);
+    push_call(
+        "vkCmdSetSampleLocationsEnableEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_sample_locations_enable_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetSampleLocationsEnableEXT>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let sample_locations_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, sample_locations_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_sample_mask_ext(
+    command_buffer: vk::CommandBuffer,
+    samples: vk::SampleCountFlags,
+    p_sample_mask: *const vk::SampleMask,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetSampleMaskEXT>(next(
+        187usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, samples, p_sample_mask);
+    }
+    let args = vec![
+        command_buffer.record(),
+        samples.record(),
+        record_array(
+            p_sample_mask,
+            (samples.as_raw() as usize + 31) / 32,
+            |element| element.record(),
+        ),
+    ];
+    next(command_buffer, samples, p_sample_mask);
+    push_call("vkCmdSetSampleMaskEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_sample_mask_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetSampleMaskEXT>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let samples: vk::SampleCountFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_sample_mask: *const vk::SampleMask =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, samples, p_sample_mask);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_scissor(
+    command_buffer: vk::CommandBuffer,
+    first_scissor: u32,
+    scissor_count: u32,
+    p_scissors: *const vk::Rect2D,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetScissor>(next(
+        188usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, first_scissor, scissor_count, p_scissors);
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_scissor.record(),
+        scissor_count.record(),
+        record_array(p_scissors, scissor_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(command_buffer, first_scissor, scissor_count, p_scissors);
+    push_call("vkCmdSetScissor", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_scissor(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetScissor>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_scissor: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let scissor_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_scissors: *const vk::Rect2D = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(command_buffer, first_scissor, scissor_count, p_scissors);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_scissor_with_count(
+    command_buffer: vk::CommandBuffer,
+    scissor_count: u32,
+    p_scissors: *const vk::Rect2D,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetScissorWithCount>(next(
+        189usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, scissor_count, p_scissors);
+    }
+    let args = vec![
+        command_buffer.record(),
+        scissor_count.record(),
+        record_array(p_scissors, scissor_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(command_buffer, scissor_count, p_scissors);
+    push_call("vkCmdSetScissorWithCount", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_scissor_with_count(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetScissorWithCount>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let scissor_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_scissors: *const vk::Rect2D = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(command_buffer, scissor_count, p_scissors);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_shading_rate_image_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    shading_rate_image_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetShadingRateImageEnableNV>(next(
+        190usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, shading_rate_image_enable);
+    }
+    let args = vec![command_buffer.record(), shading_rate_image_enable.record()];
+    next(command_buffer, shading_rate_image_enable);
+    push_call(
+        "vkCmdSetShadingRateImageEnableNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_shading_rate_image_enable_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetShadingRateImageEnableNV>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let shading_rate_image_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, shading_rate_image_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_stencil_compare_mask(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    compare_mask: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetStencilCompareMask>(next(
+        191usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, face_mask, compare_mask);
+    }
+    let args = vec![
+        command_buffer.record(),
+        face_mask.record(),
+        compare_mask.record(),
+    ];
+    next(command_buffer, face_mask, compare_mask);
+    push_call("vkCmdSetStencilCompareMask", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_stencil_compare_mask(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetStencilCompareMask>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let face_mask: vk::StencilFaceFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    let compare_mask: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, face_mask, compare_mask);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_stencil_op(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    fail_op: vk::StencilOp,
+    pass_op: vk::StencilOp,
+    depth_fail_op: vk::StencilOp,
+    compare_op: vk::CompareOp,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetStencilOp>(next(
+        192usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            face_mask,
+            fail_op,
+            pass_op,
+            depth_fail_op,
+            compare_op,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        face_mask.record(),
+        fail_op.record(),
+        pass_op.record(),
+        depth_fail_op.record(),
+        compare_op.record(),
+    ];
+    next(
+        command_buffer,
+        face_mask,
+        fail_op,
+        pass_op,
+        depth_fail_op,
+        compare_op,
+    );
+    push_call("vkCmdSetStencilOp", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_stencil_op(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetStencilOp>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let face_mask: vk::StencilFaceFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    let fail_op: vk::StencilOp = DeepCopyable::replay(&args[2usize], arena)?;
+    let pass_op: vk::StencilOp = DeepCopyable::replay(&args[3usize], arena)?;
+    let depth_fail_op: vk::StencilOp = DeepCopyable::replay(&args[4usize], arena)?;
+    let compare_op: vk::CompareOp = DeepCopyable::replay(&args[5usize], arena)?;
+    f(
+        command_buffer,
+        face_mask,
+        fail_op,
+        pass_op,
+        depth_fail_op,
+        compare_op,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_stencil_reference(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    reference: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetStencilReference>(next(
+        193usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, face_mask, reference);
+    }
+    let args = vec![
+        command_buffer.record(),
+        face_mask.record(),
+        reference.record(),
+    ];
+    next(command_buffer, face_mask, reference);
+    push_call("vkCmdSetStencilReference", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_stencil_reference(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetStencilReference>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let face_mask: vk::StencilFaceFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    let reference: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, face_mask, reference);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_stencil_test_enable(
+    command_buffer: vk::CommandBuffer,
+    stencil_test_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetStencilTestEnable>(next(
+        194usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, stencil_test_enable);
+    }
+    let args = vec![command_buffer.record(), stencil_test_enable.record()];
+    next(command_buffer, stencil_test_enable);
+    push_call("vkCmdSetStencilTestEnable", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_stencil_test_enable(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetStencilTestEnable>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let stencil_test_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, stencil_test_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_stencil_write_mask(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    write_mask: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetStencilWriteMask>(next(
+        195usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, face_mask, write_mask);
+    }
+    let args = vec![
+        command_buffer.record(),
+        face_mask.record(),
+        write_mask.record(),
+    ];
+    next(command_buffer, face_mask, write_mask);
+    push_call("vkCmdSetStencilWriteMask", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_stencil_write_mask(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetStencilWriteMask>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let face_mask: vk::StencilFaceFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    let write_mask: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    f(command_buffer, face_mask, write_mask);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_tessellation_domain_origin_ext(
+    command_buffer: vk::CommandBuffer,
+    domain_origin: vk::TessellationDomainOrigin,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetTessellationDomainOriginEXT>(next(
+        196usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, domain_origin);
+    }
+    let args = vec![command_buffer.record(), domain_origin.record()];
+    next(command_buffer, domain_origin);
+    push_call(
+        "vkCmdSetTessellationDomainOriginEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_tessellation_domain_origin_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdSetTessellationDomainOriginEXT,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let domain_origin: vk::TessellationDomainOrigin = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, domain_origin);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_vertex_input_ext(
+    command_buffer: vk::CommandBuffer,
+    vertex_binding_description_count: u32,
+    p_vertex_binding_descriptions: *const vk::VertexInputBindingDescription2EXT<'_>,
+    vertex_attribute_description_count: u32,
+    p_vertex_attribute_descriptions: *const vk::VertexInputAttributeDescription2EXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetVertexInputEXT>(next(
+        197usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            vertex_binding_description_count,
+            p_vertex_binding_descriptions,
+            vertex_attribute_description_count,
+            p_vertex_attribute_descriptions,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        vertex_binding_description_count.record(),
+        record_array(
+            p_vertex_binding_descriptions,
+            vertex_binding_description_count as usize,
+            |element| element.record(),
+        ),
+        vertex_attribute_description_count.record(),
+        record_array(
+            p_vertex_attribute_descriptions,
+            vertex_attribute_description_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        vertex_binding_description_count,
+        p_vertex_binding_descriptions,
+        vertex_attribute_description_count,
+        p_vertex_attribute_descriptions,
+    );
+    push_call("vkCmdSetVertexInputEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_vertex_input_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetVertexInputEXT>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let vertex_binding_description_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_vertex_binding_descriptions: *const vk::VertexInputBindingDescription2EXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let vertex_attribute_description_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_vertex_attribute_descriptions: *const vk::VertexInputAttributeDescription2EXT<'_> =
+        replay_array(&args[4usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        vertex_binding_description_count,
+        p_vertex_binding_descriptions,
+        vertex_attribute_description_count,
+        p_vertex_attribute_descriptions,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_viewport(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewports: *const vk::Viewport,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewport>(next(
+        198usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, first_viewport, viewport_count, p_viewports);
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_viewport.record(),
+        viewport_count.record(),
+        record_array(p_viewports, viewport_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(command_buffer, first_viewport, viewport_count, p_viewports);
+    push_call("vkCmdSetViewport", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_viewport(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetViewport>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_viewport: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let viewport_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_viewports: *const vk::Viewport = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(command_buffer, first_viewport, viewport_count, p_viewports);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_viewport_shading_rate_palette_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_shading_rate_palettes: *const vk::ShadingRatePaletteNV<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewportShadingRatePaletteNV>(next(
+        199usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_viewport,
+            viewport_count,
+            p_shading_rate_palettes,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_viewport.record(),
+        viewport_count.record(),
+        record_array(
+            p_shading_rate_palettes,
+            viewport_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_shading_rate_palettes,
+    );
+    push_call(
+        "vkCmdSetViewportShadingRatePaletteNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_viewport_shading_rate_palette_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdSetViewportShadingRatePaletteNV,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_viewport: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let viewport_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_shading_rate_palettes: *const vk::ShadingRatePaletteNV<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_shading_rate_palettes,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_viewport_swizzle_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_swizzles: *const vk::ViewportSwizzleNV,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewportSwizzleNV>(next(
+        200usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_viewport,
+            viewport_count,
+            p_viewport_swizzles,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_viewport.record(),
+        viewport_count.record(),
+        record_array(p_viewport_swizzles, viewport_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_viewport_swizzles,
+    );
+    push_call("vkCmdSetViewportSwizzleNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_viewport_swizzle_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetViewportSwizzleNV>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_viewport: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let viewport_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_viewport_swizzles: *const vk::ViewportSwizzleNV =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_viewport_swizzles,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_viewport_w_scaling_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    viewport_w_scaling_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewportWScalingEnableNV>(next(
+        201usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, viewport_w_scaling_enable);
+    }
+    let args = vec![command_buffer.record(), viewport_w_scaling_enable.record()];
+    next(command_buffer, viewport_w_scaling_enable);
+    push_call(
+        "vkCmdSetViewportWScalingEnableNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_set_viewport_w_scaling_enable_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetViewportWScalingEnableNV>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let viewport_w_scaling_enable: vk::Bool32 = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, viewport_w_scaling_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_viewport_w_scaling_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_w_scalings: *const vk::ViewportWScalingNV,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewportWScalingNV>(next(
+        202usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            first_viewport,
+            viewport_count,
+            p_viewport_w_scalings,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        first_viewport.record(),
+        viewport_count.record(),
+        record_array(p_viewport_w_scalings, viewport_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_viewport_w_scalings,
+    );
+    push_call("vkCmdSetViewportWScalingNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_viewport_w_scaling_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetViewportWScalingNV>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let first_viewport: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let viewport_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_viewport_w_scalings: *const vk::ViewportWScalingNV =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_viewport_w_scalings,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_set_viewport_with_count(
+    command_buffer: vk::CommandBuffer,
+    viewport_count: u32,
+    p_viewports: *const vk::Viewport,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewportWithCount>(next(
+        203usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, viewport_count, p_viewports);
+    }
+    let args = vec![
+        command_buffer.record(),
+        viewport_count.record(),
+        record_array(p_viewports, viewport_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(command_buffer, viewport_count, p_viewports);
+    push_call("vkCmdSetViewportWithCount", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_set_viewport_with_count(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSetViewportWithCount>(f);
+    let args = args::<3usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let viewport_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_viewports: *const vk::Viewport = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(command_buffer, viewport_count, p_viewports);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_subpass_shading_huawei(command_buffer: vk::CommandBuffer) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSubpassShadingHUAWEI>(next(
+        204usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer);
+    }
+    let args = vec![command_buffer.record()];
+    next(command_buffer);
+    push_call("vkCmdSubpassShadingHUAWEI", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_subpass_shading_huawei(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdSubpassShadingHUAWEI>(f);
+    let args = args::<1usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    f(command_buffer);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_trace_rays_indirect2_khr(
+    command_buffer: vk::CommandBuffer,
+    indirect_device_address: vk::DeviceAddress,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdTraceRaysIndirect2KHR>(next(
+        205usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, indirect_device_address);
+    }
+    let args = vec![command_buffer.record(), indirect_device_address.record()];
+    next(command_buffer, indirect_device_address);
+    push_call("vkCmdTraceRaysIndirect2KHR", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_trace_rays_indirect2_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdTraceRaysIndirect2KHR>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let indirect_device_address: vk::DeviceAddress = DeepCopyable::replay(&args[1usize], arena)?;
+    f(command_buffer, indirect_device_address);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_trace_rays_indirect_khr(
+    command_buffer: vk::CommandBuffer,
+    p_raygen_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_miss_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_hit_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_callable_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    indirect_device_address: vk::DeviceAddress,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdTraceRaysIndirectKHR>(next(
+        206usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            p_raygen_shader_binding_table,
+            p_miss_shader_binding_table,
+            p_hit_shader_binding_table,
+            p_callable_shader_binding_table,
+            indirect_device_address,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_raygen_shader_binding_table, 1, |element| element.record()),
+        record_array(p_miss_shader_binding_table, 1, |element| element.record()),
+        record_array(p_hit_shader_binding_table, 1, |element| element.record()),
+        record_array(p_callable_shader_binding_table, 1, |element| {
+            element.record()
+        }),
+        indirect_device_address.record(),
+    ];
+    next(
+        command_buffer,
+        p_raygen_shader_binding_table,
+        p_miss_shader_binding_table,
+        p_hit_shader_binding_table,
+        p_callable_shader_binding_table,
+        indirect_device_address,
+    );
+    push_call("vkCmdTraceRaysIndirectKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_trace_rays_indirect_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdTraceRaysIndirectKHR>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_raygen_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_miss_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_hit_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_callable_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR =
+        replay_array(&args[4usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let indirect_device_address: vk::DeviceAddress = DeepCopyable::replay(&args[5usize], arena)?;
+    f(
+        command_buffer,
+        p_raygen_shader_binding_table,
+        p_miss_shader_binding_table,
+        p_hit_shader_binding_table,
+        p_callable_shader_binding_table,
+        indirect_device_address,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_trace_rays_khr(
+    command_buffer: vk::CommandBuffer,
+    p_raygen_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_miss_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_hit_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_callable_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    width: u32,
+    height: u32,
+    depth: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdTraceRaysKHR>(next(
+        207usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            p_raygen_shader_binding_table,
+            p_miss_shader_binding_table,
+            p_hit_shader_binding_table,
+            p_callable_shader_binding_table,
+            width,
+            height,
+            depth,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        record_array(p_raygen_shader_binding_table, 1, |element| element.record()),
+        record_array(p_miss_shader_binding_table, 1, |element| element.record()),
+        record_array(p_hit_shader_binding_table, 1, |element| element.record()),
+        record_array(p_callable_shader_binding_table, 1, |element| {
+            element.record()
+        }),
+        width.record(),
+        height.record(),
+        depth.record(),
+    ];
+    next(
+        command_buffer,
+        p_raygen_shader_binding_table,
+        p_miss_shader_binding_table,
+        p_hit_shader_binding_table,
+        p_callable_shader_binding_table,
+        width,
+        height,
+        depth,
+    );
+    push_call("vkCmdTraceRaysKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_trace_rays_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdTraceRaysKHR>(f);
+    let args = args::<8usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_raygen_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_miss_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_hit_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_callable_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR =
+        replay_array(&args[4usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let width: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let height: u32 = DeepCopyable::replay(&args[6usize], arena)?;
+    let depth: u32 = DeepCopyable::replay(&args[7usize], arena)?;
+    f(
+        command_buffer,
+        p_raygen_shader_binding_table,
+        p_miss_shader_binding_table,
+        p_hit_shader_binding_table,
+        p_callable_shader_binding_table,
+        width,
+        height,
+        depth,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_trace_rays_nv(
+    command_buffer: vk::CommandBuffer,
+    raygen_shader_binding_table_buffer: vk::Buffer,
+    raygen_shader_binding_offset: vk::DeviceSize,
+    miss_shader_binding_table_buffer: vk::Buffer,
+    miss_shader_binding_offset: vk::DeviceSize,
+    miss_shader_binding_stride: vk::DeviceSize,
+    hit_shader_binding_table_buffer: vk::Buffer,
+    hit_shader_binding_offset: vk::DeviceSize,
+    hit_shader_binding_stride: vk::DeviceSize,
+    callable_shader_binding_table_buffer: vk::Buffer,
+    callable_shader_binding_offset: vk::DeviceSize,
+    callable_shader_binding_stride: vk::DeviceSize,
+    width: u32,
+    height: u32,
+    depth: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdTraceRaysNV>(next(
+        208usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            raygen_shader_binding_table_buffer,
+            raygen_shader_binding_offset,
+            miss_shader_binding_table_buffer,
+            miss_shader_binding_offset,
+            miss_shader_binding_stride,
+            hit_shader_binding_table_buffer,
+            hit_shader_binding_offset,
+            hit_shader_binding_stride,
+            callable_shader_binding_table_buffer,
+            callable_shader_binding_offset,
+            callable_shader_binding_stride,
+            width,
+            height,
+            depth,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        raygen_shader_binding_table_buffer.record(),
+        raygen_shader_binding_offset.record(),
+        miss_shader_binding_table_buffer.record(),
+        miss_shader_binding_offset.record(),
+        miss_shader_binding_stride.record(),
+        hit_shader_binding_table_buffer.record(),
+        hit_shader_binding_offset.record(),
+        hit_shader_binding_stride.record(),
+        callable_shader_binding_table_buffer.record(),
+        callable_shader_binding_offset.record(),
+        callable_shader_binding_stride.record(),
+        width.record(),
+        height.record(),
+        depth.record(),
+    ];
+    next(
+        command_buffer,
+        raygen_shader_binding_table_buffer,
+        raygen_shader_binding_offset,
+        miss_shader_binding_table_buffer,
+        miss_shader_binding_offset,
+        miss_shader_binding_stride,
+        hit_shader_binding_table_buffer,
+        hit_shader_binding_offset,
+        hit_shader_binding_stride,
+        callable_shader_binding_table_buffer,
+        callable_shader_binding_offset,
+        callable_shader_binding_stride,
+        width,
+        height,
+        depth,
+    );
+    push_call("vkCmdTraceRaysNV", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_trace_rays_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdTraceRaysNV>(f);
+    let args = args::<15usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let raygen_shader_binding_table_buffer: vk::Buffer =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let raygen_shader_binding_offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let miss_shader_binding_table_buffer: vk::Buffer = DeepCopyable::replay(&args[3usize], arena)?;
+    let miss_shader_binding_offset: vk::DeviceSize = DeepCopyable::replay(&args[4usize], arena)?;
+    let miss_shader_binding_stride: vk::DeviceSize = DeepCopyable::replay(&args[5usize], arena)?;
+    let hit_shader_binding_table_buffer: vk::Buffer = DeepCopyable::replay(&args[6usize], arena)?;
+    let hit_shader_binding_offset: vk::DeviceSize = DeepCopyable::replay(&args[7usize], arena)?;
+    let hit_shader_binding_stride: vk::DeviceSize = DeepCopyable::replay(&args[8usize], arena)?;
+    let callable_shader_binding_table_buffer: vk::Buffer =
+        DeepCopyable::replay(&args[9usize], arena)?;
+    let callable_shader_binding_offset: vk::DeviceSize =
+        DeepCopyable::replay(&args[10usize], arena)?;
+    let callable_shader_binding_stride: vk::DeviceSize =
+        DeepCopyable::replay(&args[11usize], arena)?;
+    let width: u32 = DeepCopyable::replay(&args[12usize], arena)?;
+    let height: u32 = DeepCopyable::replay(&args[13usize], arena)?;
+    let depth: u32 = DeepCopyable::replay(&args[14usize], arena)?;
+    f(
+        command_buffer,
+        raygen_shader_binding_table_buffer,
+        raygen_shader_binding_offset,
+        miss_shader_binding_table_buffer,
+        miss_shader_binding_offset,
+        miss_shader_binding_stride,
+        hit_shader_binding_table_buffer,
+        hit_shader_binding_offset,
+        hit_shader_binding_stride,
+        callable_shader_binding_table_buffer,
+        callable_shader_binding_offset,
+        callable_shader_binding_stride,
+        width,
+        height,
+        depth,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_update_buffer(
+    command_buffer: vk::CommandBuffer,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    data_size: vk::DeviceSize,
+    p_data: *const c_void,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdUpdateBuffer>(next(
+        209usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, dst_buffer, dst_offset, data_size, p_data);
+    }
+    let args = vec![
+        command_buffer.record(),
+        dst_buffer.record(),
+        dst_offset.record(),
+        data_size.record(),
+        record_data(p_data.cast::<u8>(), data_size as usize),
+    ];
+    next(command_buffer, dst_buffer, dst_offset, data_size, p_data);
+    push_call("vkCmdUpdateBuffer", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_update_buffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdUpdateBuffer>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let dst_buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let dst_offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let data_size: vk::DeviceSize = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_data: *const c_void = replay_data::<u8>(&args[4usize], arena)?.cast::<c_void>();
+    f(command_buffer, dst_buffer, dst_offset, data_size, p_data);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_wait_events(
+    command_buffer: vk::CommandBuffer,
+    event_count: u32,
+    p_events: *const vk::Event,
+    src_stage_mask: vk::PipelineStageFlags,
+    dst_stage_mask: vk::PipelineStageFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const vk::MemoryBarrier<'_>,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const vk::BufferMemoryBarrier<'_>,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const vk::ImageMemoryBarrier<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWaitEvents>(next(
+        210usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            event_count,
+            p_events,
+            src_stage_mask,
+            dst_stage_mask,
+            memory_barrier_count,
+            p_memory_barriers,
+            buffer_memory_barrier_count,
+            p_buffer_memory_barriers,
+            image_memory_barrier_count,
+            p_image_memory_barriers,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        event_count.record(),
+        record_array(p_events, event_count as usize, |element| element.record()),
+        src_stage_mask.record(),
+        dst_stage_mask.record(),
+        memory_barrier_count.record(),
+        record_array(
+            p_memory_barriers,
+            memory_barrier_count as usize,
+            |element| element.record(),
+        ),
+        buffer_memory_barrier_count.record(),
+        record_array(
+            p_buffer_memory_barriers,
+            buffer_memory_barrier_count as usize,
+            |element| element.record(),
+        ),
+        image_memory_barrier_count.record(),
+        record_array(
+            p_image_memory_barriers,
+            image_memory_barrier_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        command_buffer,
+        event_count,
+        p_events,
+        src_stage_mask,
+        dst_stage_mask,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    );
+    push_call("vkCmdWaitEvents", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_wait_events(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdWaitEvents>(f);
+    let args = args::<11usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let event_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_events: *const vk::Event = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let src_stage_mask: vk::PipelineStageFlags = DeepCopyable::replay(&args[3usize], arena)?;
+    let dst_stage_mask: vk::PipelineStageFlags = DeepCopyable::replay(&args[4usize], arena)?;
+    let memory_barrier_count: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    let p_memory_barriers: *const vk::MemoryBarrier<'_> =
+        replay_array(&args[6usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let buffer_memory_barrier_count: u32 = DeepCopyable::replay(&args[7usize], arena)?;
+    let p_buffer_memory_barriers: *const vk::BufferMemoryBarrier<'_> =
+        replay_array(&args[8usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let image_memory_barrier_count: u32 = DeepCopyable::replay(&args[9usize], arena)?;
+    let p_image_memory_barriers: *const vk::ImageMemoryBarrier<'_> =
+        replay_array(&args[10usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        command_buffer,
+        event_count,
+        p_events,
+        src_stage_mask,
+        dst_stage_mask,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_wait_events2(
+    command_buffer: vk::CommandBuffer,
+    event_count: u32,
+    p_events: *const vk::Event,
+    p_dependency_infos: *const vk::DependencyInfo<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWaitEvents2>(next(
+        211usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, event_count, p_events, p_dependency_infos);
+    }
+    let args = vec![
+        command_buffer.record(),
+        event_count.record(),
+        record_array(p_events, event_count as usize, |element| element.record()),
+        record_array(p_dependency_infos, event_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(command_buffer, event_count, p_events, p_dependency_infos);
+    push_call("vkCmdWaitEvents2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_wait_events2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdWaitEvents2>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let event_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_events: *const vk::Event = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_dependency_infos: *const vk::DependencyInfo<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(command_buffer, event_count, p_events, p_dependency_infos);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_write_acceleration_structures_properties_khr(
+    command_buffer: vk::CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureKHR,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteAccelerationStructuresPropertiesKHR>(
+        next(212usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(
+            command_buffer,
+            acceleration_structure_count,
+            p_acceleration_structures,
+            query_type,
+            query_pool,
+            first_query,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        acceleration_structure_count.record(),
+        record_array(
+            p_acceleration_structures,
+            acceleration_structure_count as usize,
+            |element| element.record(),
+        ),
+        query_type.record(),
+        query_pool.record(),
+        first_query.record(),
+    ];
+    next(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    push_call(
+        "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_write_acceleration_structures_properties_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdWriteAccelerationStructuresPropertiesKHR,
+    >(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let acceleration_structure_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_acceleration_structures: *const vk::AccelerationStructureKHR =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let query_type: vk::QueryType = DeepCopyable::replay(&args[3usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[4usize], arena)?;
+    let first_query: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    f(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_write_acceleration_structures_properties_nv(
+    command_buffer: vk::CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureNV,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteAccelerationStructuresPropertiesNV>(
+        next(213usize, dispatch_key(command_buffer.as_raw())),
+    );
+    if !is_recording() {
+        return next(
+            command_buffer,
+            acceleration_structure_count,
+            p_acceleration_structures,
+            query_type,
+            query_pool,
+            first_query,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        acceleration_structure_count.record(),
+        record_array(
+            p_acceleration_structures,
+            acceleration_structure_count as usize,
+            |element| element.record(),
+        ),
+        query_type.record(),
+        query_pool.record(),
+        first_query.record(),
+    ];
+    next(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    push_call(
+        "vkCmdWriteAccelerationStructuresPropertiesNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_write_acceleration_structures_properties_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCmdWriteAccelerationStructuresPropertiesNV,
+    >(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let acceleration_structure_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_acceleration_structures: *const vk::AccelerationStructureNV =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let query_type: vk::QueryType = DeepCopyable::replay(&args[3usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[4usize], arena)?;
+    let first_query: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    f(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_write_buffer_marker2_amd(
+    command_buffer: vk::CommandBuffer,
+    stage: vk::PipelineStageFlags2,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    marker: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteBufferMarker2AMD>(next(
+        214usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, stage, dst_buffer, dst_offset, marker);
+    }
+    let args = vec![
+        command_buffer.record(),
+        stage.record(),
+        dst_buffer.record(),
+        dst_offset.record(),
+        marker.record(),
+    ];
+    next(command_buffer, stage, dst_buffer, dst_offset, marker);
+    push_call("vkCmdWriteBufferMarker2AMD", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_write_buffer_marker2_amd(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdWriteBufferMarker2AMD>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let stage: vk::PipelineStageFlags2 = DeepCopyable::replay(&args[1usize], arena)?;
+    let dst_buffer: vk::Buffer = DeepCopyable::replay(&args[2usize], arena)?;
+    let dst_offset: vk::DeviceSize = DeepCopyable::replay(&args[3usize], arena)?;
+    let marker: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    f(command_buffer, stage, dst_buffer, dst_offset, marker);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_write_buffer_marker_amd(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stage: vk::PipelineStageFlags,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    marker: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteBufferMarkerAMD>(next(
+        215usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            pipeline_stage,
+            dst_buffer,
+            dst_offset,
+            marker,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        pipeline_stage.record(),
+        dst_buffer.record(),
+        dst_offset.record(),
+        marker.record(),
+    ];
+    next(
+        command_buffer,
+        pipeline_stage,
+        dst_buffer,
+        dst_offset,
+        marker,
+    );
+    push_call("vkCmdWriteBufferMarkerAMD", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_write_buffer_marker_amd(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdWriteBufferMarkerAMD>(f);
+    let args = args::<5usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_stage: vk::PipelineStageFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    let dst_buffer: vk::Buffer = DeepCopyable::replay(&args[2usize], arena)?;
+    let dst_offset: vk::DeviceSize = DeepCopyable::replay(&args[3usize], arena)?;
+    let marker: u32 = DeepCopyable::replay(&args[4usize], arena)?;
+    f(
+        command_buffer,
+        pipeline_stage,
+        dst_buffer,
+        dst_offset,
+        marker,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_write_micromaps_properties_ext(
+    command_buffer: vk::CommandBuffer,
+    micromap_count: u32,
+    p_micromaps: *const vk::MicromapEXT,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteMicromapsPropertiesEXT>(next(
+        216usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            command_buffer,
+            micromap_count,
+            p_micromaps,
+            query_type,
+            query_pool,
+            first_query,
+        );
+    }
+    let args = vec![
+        command_buffer.record(),
+        micromap_count.record(),
+        record_array(p_micromaps, micromap_count as usize, |element| {
+            element.record()
+        }),
+        query_type.record(),
+        query_pool.record(),
+        first_query.record(),
+    ];
+    next(
+        command_buffer,
+        micromap_count,
+        p_micromaps,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    push_call(
+        "vkCmdWriteMicromapsPropertiesEXT",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_cmd_write_micromaps_properties_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdWriteMicromapsPropertiesEXT>(f);
+    let args = args::<6usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let micromap_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_micromaps: *const vk::MicromapEXT =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let query_type: vk::QueryType = DeepCopyable::replay(&args[3usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[4usize], arena)?;
+    let first_query: u32 = DeepCopyable::replay(&args[5usize], arena)?;
+    f(
+        command_buffer,
+        micromap_count,
+        p_micromaps,
+        query_type,
+        query_pool,
+        first_query,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_write_timestamp(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stage: vk::PipelineStageFlags,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteTimestamp>(next(
+        217usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, pipeline_stage, query_pool, query);
+    }
+    let args = vec![
+        command_buffer.record(),
+        pipeline_stage.record(),
+        query_pool.record(),
+        query.record(),
+    ];
+    next(command_buffer, pipeline_stage, query_pool, query);
+    push_call("vkCmdWriteTimestamp", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_write_timestamp(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdWriteTimestamp>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_stage: vk::PipelineStageFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[2usize], arena)?;
+    let query: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, pipeline_stage, query_pool, query);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn cmd_write_timestamp2(
+    command_buffer: vk::CommandBuffer,
+    stage: vk::PipelineStageFlags2,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteTimestamp2>(next(
+        218usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, stage, query_pool, query);
+    }
+    let args = vec![
+        command_buffer.record(),
+        stage.record(),
+        query_pool.record(),
+        query.record(),
+    ];
+    next(command_buffer, stage, query_pool, query);
+    push_call("vkCmdWriteTimestamp2", args, vec![], Value::Null);
+}
+unsafe fn replay_cmd_write_timestamp2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCmdWriteTimestamp2>(f);
+    let args = args::<4usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let stage: vk::PipelineStageFlags2 = DeepCopyable::replay(&args[1usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[2usize], arena)?;
+    let query: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(command_buffer, stage, query_pool, query);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn compile_deferred_nv(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    shader: u32,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCompileDeferredNV>(next(
+        219usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, pipeline, shader);
+    }
+    let args = vec![device.record(), pipeline.record(), shader.record()];
+    let result = next(device, pipeline, shader);
+    push_call("vkCompileDeferredNV", args, vec![], result.record());
+    result
+}
+unsafe fn replay_compile_deferred_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCompileDeferredNV>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline: vk::Pipeline = DeepCopyable::replay(&args[1usize], arena)?;
+    let shader: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let result = f(device, pipeline, shader);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn copy_acceleration_structure_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyAccelerationStructureInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyAccelerationStructureKHR>(next(
+        220usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, deferred_operation, p_info);
+    }
+    let args = vec![
+        device.record(),
+        deferred_operation.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, deferred_operation, p_info);
+    push_call(
+        "vkCopyAccelerationStructureKHR",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_copy_acceleration_structure_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCopyAccelerationStructureKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let deferred_operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_info: *const vk::CopyAccelerationStructureInfoKHR<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, deferred_operation, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn copy_acceleration_structure_to_memory_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyAccelerationStructureToMemoryKHR>(next(
+        221usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, deferred_operation, p_info);
+    }
+    let args = vec![
+        device.record(),
+        deferred_operation.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, deferred_operation, p_info);
+    push_call(
+        "vkCopyAccelerationStructureToMemoryKHR",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_copy_acceleration_structure_to_memory_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCopyAccelerationStructureToMemoryKHR,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let deferred_operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_info: *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, deferred_operation, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn copy_memory_to_acceleration_structure_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyMemoryToAccelerationStructureKHR>(next(
+        222usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, deferred_operation, p_info);
+    }
+    let args = vec![
+        device.record(),
+        deferred_operation.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, deferred_operation, p_info);
+    push_call(
+        "vkCopyMemoryToAccelerationStructureKHR",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_copy_memory_to_acceleration_structure_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkCopyMemoryToAccelerationStructureKHR,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let deferred_operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_info: *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, deferred_operation, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn copy_memory_to_micromap_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMemoryToMicromapInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyMemoryToMicromapEXT>(next(
+        223usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, deferred_operation, p_info);
+    }
+    let args = vec![
+        device.record(),
+        deferred_operation.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, deferred_operation, p_info);
+    push_call("vkCopyMemoryToMicromapEXT", args, vec![], result.record());
+    result
+}
+unsafe fn replay_copy_memory_to_micromap_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCopyMemoryToMicromapEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let deferred_operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_info: *const vk::CopyMemoryToMicromapInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, deferred_operation, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn copy_micromap_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMicromapInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyMicromapEXT>(next(
+        224usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, deferred_operation, p_info);
+    }
+    let args = vec![
+        device.record(),
+        deferred_operation.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, deferred_operation, p_info);
+    push_call("vkCopyMicromapEXT", args, vec![], result.record());
+    result
+}
+unsafe fn replay_copy_micromap_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCopyMicromapEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let deferred_operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_info: *const vk::CopyMicromapInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, deferred_operation, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn copy_micromap_to_memory_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMicromapToMemoryInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyMicromapToMemoryEXT>(next(
+        225usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, deferred_operation, p_info);
+    }
+    let args = vec![
+        device.record(),
+        deferred_operation.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, deferred_operation, p_info);
+    push_call("vkCopyMicromapToMemoryEXT", args, vec![], result.record());
+    result
+}
+unsafe fn replay_copy_micromap_to_memory_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCopyMicromapToMemoryEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let deferred_operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_info: *const vk::CopyMicromapToMemoryInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, deferred_operation, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_acceleration_structure_khr(
+    device: vk::Device,
+    p_create_info: *const vk::AccelerationStructureCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_acceleration_structure: *mut vk::AccelerationStructureKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateAccelerationStructureKHR>(next(
+        226usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_acceleration_structure);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_acceleration_structure, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_acceleration_structure);
+    push_call(
+        "vkCreateAccelerationStructureKHR",
+        args,
+        vec![record_array(p_acceleration_structure, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_acceleration_structure_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateAccelerationStructureKHR>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::AccelerationStructureCreateInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_acceleration_structure: *mut vk::AccelerationStructureKHR =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_acceleration_structure);
+    let outputs: Vec<Value> = vec![record_array(p_acceleration_structure, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_acceleration_structure_nv(
+    device: vk::Device,
+    p_create_info: *const vk::AccelerationStructureCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_acceleration_structure: *mut vk::AccelerationStructureNV,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateAccelerationStructureNV>(next(
+        227usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_acceleration_structure);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_acceleration_structure, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_acceleration_structure);
+    push_call(
+        "vkCreateAccelerationStructureNV",
+        args,
+        vec![record_array(p_acceleration_structure, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_acceleration_structure_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateAccelerationStructureNV>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::AccelerationStructureCreateInfoNV<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_acceleration_structure: *mut vk::AccelerationStructureNV =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_acceleration_structure);
+    let outputs: Vec<Value> = vec![record_array(p_acceleration_structure, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_buffer(
+    device: vk::Device,
+    p_create_info: *const vk::BufferCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_buffer: *mut vk::Buffer,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateBuffer>(next(
+        228usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_buffer);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_buffer, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_buffer);
+    push_call(
+        "vkCreateBuffer",
+        args,
+        vec![record_array(p_buffer, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_buffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateBuffer>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::BufferCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_buffer: *mut vk::Buffer = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_buffer);
+    let outputs: Vec<Value> = vec![record_array(p_buffer, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_buffer_collection_fuchsia(
+    device: vk::Device,
+    p_create_info: *const vk::BufferCollectionCreateInfoFUCHSIA<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_collection: *mut vk::BufferCollectionFUCHSIA,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateBufferCollectionFUCHSIA>(next(
+        229usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_collection);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_collection, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_collection);
+    push_call(
+        "vkCreateBufferCollectionFUCHSIA",
+        args,
+        vec![record_array(p_collection, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_buffer_collection_fuchsia(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateBufferCollectionFUCHSIA>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::BufferCollectionCreateInfoFUCHSIA<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_collection: *mut vk::BufferCollectionFUCHSIA =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_collection);
+    let outputs: Vec<Value> = vec![record_array(p_collection, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_buffer_view(
+    device: vk::Device,
+    p_create_info: *const vk::BufferViewCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_view: *mut vk::BufferView,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateBufferView>(next(
+        230usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_view);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_view, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_view);
+    push_call(
+        "vkCreateBufferView",
+        args,
+        vec![record_array(p_view, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_buffer_view(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateBufferView>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::BufferViewCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_view: *mut vk::BufferView = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_view);
+    let outputs: Vec<Value> = vec![record_array(p_view, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_command_pool(
+    device: vk::Device,
+    p_create_info: *const vk::CommandPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_command_pool: *mut vk::CommandPool,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateCommandPool>(next(
+        231usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_command_pool);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_command_pool, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_command_pool);
+    push_call(
+        "vkCreateCommandPool",
+        args,
+        vec![record_array(p_command_pool, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_command_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateCommandPool>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::CommandPoolCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_command_pool: *mut vk::CommandPool =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_command_pool);
+    let outputs: Vec<Value> = vec![record_array(p_command_pool, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_compute_pipelines(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::ComputePipelineCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateComputePipelines>(next(
+        232usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            pipeline_cache,
+            create_info_count,
+            p_create_infos,
+            p_allocator,
+            p_pipelines,
+        );
+    }
+    let args = vec![
+        device.record(),
+        pipeline_cache.record(),
+        create_info_count.record(),
+        record_array(p_create_infos, create_info_count as usize, |element| {
+            element.record()
+        }),
+        Value::Null,
+        record_output(p_pipelines, create_info_count as usize),
+    ];
+    let result = next(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    push_call(
+        "vkCreateComputePipelines",
+        args,
+        vec![record_array(
+            p_pipelines,
+            create_info_count as usize,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_compute_pipelines(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateComputePipelines>(f);
+    let args = args::<6usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_cache: vk::PipelineCache = DeepCopyable::replay(&args[1usize], arena)?;
+    let create_info_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_create_infos: *const vk::ComputePipelineCreateInfo<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_pipelines: *mut vk::Pipeline = replay_array(&args[5usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    let outputs: Vec<Value> = vec![record_array(
+        p_pipelines,
+        create_info_count as usize,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_cu_function_nvx(
+    device: vk::Device,
+    p_create_info: *const vk::CuFunctionCreateInfoNVX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_function: *mut vk::CuFunctionNVX,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateCuFunctionNVX>(next(
+        233usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_function);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_function, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_function);
+    push_call(
+        "vkCreateCuFunctionNVX",
+        args,
+        vec![record_array(p_function, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_cu_function_nvx(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateCuFunctionNVX>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::CuFunctionCreateInfoNVX<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_function: *mut vk::CuFunctionNVX = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_function);
+    let outputs: Vec<Value> = vec![record_array(p_function, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_cu_module_nvx(
+    device: vk::Device,
+    p_create_info: *const vk::CuModuleCreateInfoNVX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_module: *mut vk::CuModuleNVX,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateCuModuleNVX>(next(
+        234usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_module);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_module, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_module);
+    push_call(
+        "vkCreateCuModuleNVX",
+        args,
+        vec![record_array(p_module, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_cu_module_nvx(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateCuModuleNVX>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::CuModuleCreateInfoNVX<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_module: *mut vk::CuModuleNVX = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_module);
+    let outputs: Vec<Value> = vec![record_array(p_module, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_deferred_operation_khr(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_deferred_operation: *mut vk::DeferredOperationKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDeferredOperationKHR>(next(
+        235usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_allocator, p_deferred_operation);
+    }
+    let args = vec![
+        device.record(),
+        Value::Null,
+        record_output(p_deferred_operation, 1),
+    ];
+    let result = next(device, p_allocator, p_deferred_operation);
+    push_call(
+        "vkCreateDeferredOperationKHR",
+        args,
+        vec![record_array(p_deferred_operation, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_deferred_operation_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateDeferredOperationKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_deferred_operation: *mut vk::DeferredOperationKHR =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_allocator, p_deferred_operation);
+    let outputs: Vec<Value> = vec![record_array(p_deferred_operation, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_descriptor_pool(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_descriptor_pool: *mut vk::DescriptorPool,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDescriptorPool>(next(
+        236usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_descriptor_pool);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_descriptor_pool, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_descriptor_pool);
+    push_call(
+        "vkCreateDescriptorPool",
+        args,
+        vec![record_array(p_descriptor_pool, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_descriptor_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateDescriptorPool>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::DescriptorPoolCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_descriptor_pool: *mut vk::DescriptorPool =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_descriptor_pool);
+    let outputs: Vec<Value> = vec![record_array(p_descriptor_pool, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_descriptor_set_layout(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorSetLayoutCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_set_layout: *mut vk::DescriptorSetLayout,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDescriptorSetLayout>(next(
+        237usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_set_layout);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_set_layout, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_set_layout);
+    push_call(
+        "vkCreateDescriptorSetLayout",
+        args,
+        vec![record_array(p_set_layout, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_descriptor_set_layout(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateDescriptorSetLayout>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::DescriptorSetLayoutCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_set_layout: *mut vk::DescriptorSetLayout =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_set_layout);
+    let outputs: Vec<Value> = vec![record_array(p_set_layout, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_descriptor_update_template(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorUpdateTemplateCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_descriptor_update_template: *mut vk::DescriptorUpdateTemplate,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDescriptorUpdateTemplate>(next(
+        238usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            p_create_info,
+            p_allocator,
+            p_descriptor_update_template,
+        );
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_descriptor_update_template, 1),
+    ];
+    let result = next(
+        device,
+        p_create_info,
+        p_allocator,
+        p_descriptor_update_template,
+    );
+    push_call(
+        "vkCreateDescriptorUpdateTemplate",
+        args,
+        vec![record_array(p_descriptor_update_template, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_descriptor_update_template(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateDescriptorUpdateTemplate>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::DescriptorUpdateTemplateCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_descriptor_update_template: *mut vk::DescriptorUpdateTemplate =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        p_create_info,
+        p_allocator,
+        p_descriptor_update_template,
+    );
+    let outputs: Vec<Value> = vec![record_array(p_descriptor_update_template, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_event(
+    device: vk::Device,
+    p_create_info: *const vk::EventCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_event: *mut vk::Event,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateEvent>(next(
+        239usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_event);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_event, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_event);
+    push_call(
+        "vkCreateEvent",
+        args,
+        vec![record_array(p_event, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_event(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateEvent>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::EventCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_event: *mut vk::Event = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_event);
+    let outputs: Vec<Value> = vec![record_array(p_event, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_fence(
+    device: vk::Device,
+    p_create_info: *const vk::FenceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateFence>(next(
+        240usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_fence);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_fence, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_fence);
+    push_call(
+        "vkCreateFence",
+        args,
+        vec![record_array(p_fence, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_fence(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateFence>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::FenceCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_fence: *mut vk::Fence = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_fence);
+    let outputs: Vec<Value> = vec![record_array(p_fence, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_framebuffer(
+    device: vk::Device,
+    p_create_info: *const vk::FramebufferCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_framebuffer: *mut vk::Framebuffer,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateFramebuffer>(next(
+        241usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_framebuffer);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_framebuffer, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_framebuffer);
+    push_call(
+        "vkCreateFramebuffer",
+        args,
+        vec![record_array(p_framebuffer, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_framebuffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateFramebuffer>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::FramebufferCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_framebuffer: *mut vk::Framebuffer =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_framebuffer);
+    let outputs: Vec<Value> = vec![record_array(p_framebuffer, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_graphics_pipelines(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::GraphicsPipelineCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateGraphicsPipelines>(next(
+        242usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            pipeline_cache,
+            create_info_count,
+            p_create_infos,
+            p_allocator,
+            p_pipelines,
+        );
+    }
+    let args = vec![
+        device.record(),
+        pipeline_cache.record(),
+        create_info_count.record(),
+        record_array(p_create_infos, create_info_count as usize, |element| {
+            element.record()
+        }),
+        Value::Null,
+        record_output(p_pipelines, create_info_count as usize),
+    ];
+    let result = next(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    push_call(
+        "vkCreateGraphicsPipelines",
+        args,
+        vec![record_array(
+            p_pipelines,
+            create_info_count as usize,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_graphics_pipelines(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateGraphicsPipelines>(f);
+    let args = args::<6usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_cache: vk::PipelineCache = DeepCopyable::replay(&args[1usize], arena)?;
+    let create_info_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_create_infos: *const vk::GraphicsPipelineCreateInfo<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_pipelines: *mut vk::Pipeline = replay_array(&args[5usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    let outputs: Vec<Value> = vec![record_array(
+        p_pipelines,
+        create_info_count as usize,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_image(
+    device: vk::Device,
+    p_create_info: *const vk::ImageCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_image: *mut vk::Image,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateImage>(next(
+        243usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_image);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_image, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_image);
+    push_call(
+        "vkCreateImage",
+        args,
+        vec![record_array(p_image, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_image(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateImage>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::ImageCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_image: *mut vk::Image = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_image);
+    let outputs: Vec<Value> = vec![record_array(p_image, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_image_view(
+    device: vk::Device,
+    p_create_info: *const vk::ImageViewCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_view: *mut vk::ImageView,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateImageView>(next(
+        244usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_view);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_view, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_view);
+    push_call(
+        "vkCreateImageView",
+        args,
+        vec![record_array(p_view, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_image_view(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateImageView>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::ImageViewCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_view: *mut vk::ImageView = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_view);
+    let outputs: Vec<Value> = vec![record_array(p_view, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_indirect_commands_layout_nv(
+    device: vk::Device,
+    p_create_info: *const vk::IndirectCommandsLayoutCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_indirect_commands_layout: *mut vk::IndirectCommandsLayoutNV,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateIndirectCommandsLayoutNV>(next(
+        245usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            p_create_info,
+            p_allocator,
+            p_indirect_commands_layout,
+        );
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_indirect_commands_layout, 1),
+    ];
+    let result = next(
+        device,
+        p_create_info,
+        p_allocator,
+        p_indirect_commands_layout,
+    );
+    push_call(
+        "vkCreateIndirectCommandsLayoutNV",
+        args,
+        vec![record_array(p_indirect_commands_layout, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_indirect_commands_layout_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateIndirectCommandsLayoutNV>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::IndirectCommandsLayoutCreateInfoNV<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_indirect_commands_layout: *mut vk::IndirectCommandsLayoutNV =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        p_create_info,
+        p_allocator,
+        p_indirect_commands_layout,
+    );
+    let outputs: Vec<Value> = vec![record_array(p_indirect_commands_layout, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_micromap_ext(
+    device: vk::Device,
+    p_create_info: *const vk::MicromapCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_micromap: *mut vk::MicromapEXT,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateMicromapEXT>(next(
+        246usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_micromap);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_micromap, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_micromap);
+    push_call(
+        "vkCreateMicromapEXT",
+        args,
+        vec![record_array(p_micromap, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_micromap_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateMicromapEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::MicromapCreateInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_micromap: *mut vk::MicromapEXT = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_micromap);
+    let outputs: Vec<Value> = vec![record_array(p_micromap, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_optical_flow_session_nv(
+    device: vk::Device,
+    p_create_info: *const vk::OpticalFlowSessionCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_session: *mut vk::OpticalFlowSessionNV,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateOpticalFlowSessionNV>(next(
+        247usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_session);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_session, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_session);
+    push_call(
+        "vkCreateOpticalFlowSessionNV",
+        args,
+        vec![record_array(p_session, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_optical_flow_session_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateOpticalFlowSessionNV>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::OpticalFlowSessionCreateInfoNV<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_session: *mut vk::OpticalFlowSessionNV =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_session);
+    let outputs: Vec<Value> = vec![record_array(p_session, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_pipeline_cache(
+    device: vk::Device,
+    p_create_info: *const vk::PipelineCacheCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipeline_cache: *mut vk::PipelineCache,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreatePipelineCache>(next(
+        248usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_pipeline_cache);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_pipeline_cache, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_pipeline_cache);
+    push_call(
+        "vkCreatePipelineCache",
+        args,
+        vec![record_array(p_pipeline_cache, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_pipeline_cache(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreatePipelineCache>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::PipelineCacheCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_pipeline_cache: *mut vk::PipelineCache =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_pipeline_cache);
+    let outputs: Vec<Value> = vec![record_array(p_pipeline_cache, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_pipeline_layout(
+    device: vk::Device,
+    p_create_info: *const vk::PipelineLayoutCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipeline_layout: *mut vk::PipelineLayout,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreatePipelineLayout>(next(
+        249usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_pipeline_layout);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_pipeline_layout, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_pipeline_layout);
+    push_call(
+        "vkCreatePipelineLayout",
+        args,
+        vec![record_array(p_pipeline_layout, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_pipeline_layout(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreatePipelineLayout>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::PipelineLayoutCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_pipeline_layout: *mut vk::PipelineLayout =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_pipeline_layout);
+    let outputs: Vec<Value> = vec![record_array(p_pipeline_layout, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_private_data_slot(
+    device: vk::Device,
+    p_create_info: *const vk::PrivateDataSlotCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_private_data_slot: *mut vk::PrivateDataSlot,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreatePrivateDataSlot>(next(
+        250usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_private_data_slot);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_private_data_slot, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_private_data_slot);
+    push_call(
+        "vkCreatePrivateDataSlot",
+        args,
+        vec![record_array(p_private_data_slot, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_private_data_slot(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreatePrivateDataSlot>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::PrivateDataSlotCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_private_data_slot: *mut vk::PrivateDataSlot =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_private_data_slot);
+    let outputs: Vec<Value> = vec![record_array(p_private_data_slot, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_query_pool(
+    device: vk::Device,
+    p_create_info: *const vk::QueryPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_query_pool: *mut vk::QueryPool,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateQueryPool>(next(
+        251usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_query_pool);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_query_pool, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_query_pool);
+    push_call(
+        "vkCreateQueryPool",
+        args,
+        vec![record_array(p_query_pool, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_query_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateQueryPool>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::QueryPoolCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_query_pool: *mut vk::QueryPool = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_query_pool);
+    let outputs: Vec<Value> = vec![record_array(p_query_pool, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_ray_tracing_pipelines_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::RayTracingPipelineCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateRayTracingPipelinesKHR>(next(
+        252usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            deferred_operation,
+            pipeline_cache,
+            create_info_count,
+            p_create_infos,
+            p_allocator,
+            p_pipelines,
+        );
+    }
+    let args = vec![
+        device.record(),
+        deferred_operation.record(),
+        pipeline_cache.record(),
+        create_info_count.record(),
+        record_array(p_create_infos, create_info_count as usize, |element| {
+            element.record()
+        }),
+        Value::Null,
+        record_output(p_pipelines, create_info_count as usize),
+    ];
+    let result = next(
+        device,
+        deferred_operation,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    push_call(
+        "vkCreateRayTracingPipelinesKHR",
+        args,
+        vec![record_array(
+            p_pipelines,
+            create_info_count as usize,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_ray_tracing_pipelines_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateRayTracingPipelinesKHR>(f);
+    let args = args::<7usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let deferred_operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let pipeline_cache: vk::PipelineCache = DeepCopyable::replay(&args[2usize], arena)?;
+    let create_info_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_create_infos: *const vk::RayTracingPipelineCreateInfoKHR<'_> =
+        replay_array(&args[4usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_pipelines: *mut vk::Pipeline = replay_array(&args[6usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(
+        device,
+        deferred_operation,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    let outputs: Vec<Value> = vec![record_array(
+        p_pipelines,
+        create_info_count as usize,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_ray_tracing_pipelines_nv(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::RayTracingPipelineCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateRayTracingPipelinesNV>(next(
+        253usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            pipeline_cache,
+            create_info_count,
+            p_create_infos,
+            p_allocator,
+            p_pipelines,
+        );
+    }
+    let args = vec![
+        device.record(),
+        pipeline_cache.record(),
+        create_info_count.record(),
+        record_array(p_create_infos, create_info_count as usize, |element| {
+            element.record()
+        }),
+        Value::Null,
+        record_output(p_pipelines, create_info_count as usize),
+    ];
+    let result = next(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    push_call(
+        "vkCreateRayTracingPipelinesNV",
+        args,
+        vec![record_array(
+            p_pipelines,
+            create_info_count as usize,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_ray_tracing_pipelines_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateRayTracingPipelinesNV>(f);
+    let args = args::<6usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_cache: vk::PipelineCache = DeepCopyable::replay(&args[1usize], arena)?;
+    let create_info_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_create_infos: *const vk::RayTracingPipelineCreateInfoNV<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_pipelines: *mut vk::Pipeline = replay_array(&args[5usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    let outputs: Vec<Value> = vec![record_array(
+        p_pipelines,
+        create_info_count as usize,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_render_pass(
+    device: vk::Device,
+    p_create_info: *const vk::RenderPassCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_render_pass: *mut vk::RenderPass,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateRenderPass>(next(
+        254usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_render_pass);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_render_pass, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_render_pass);
+    push_call(
+        "vkCreateRenderPass",
+        args,
+        vec![record_array(p_render_pass, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_render_pass(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateRenderPass>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::RenderPassCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_render_pass: *mut vk::RenderPass = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_render_pass);
+    let outputs: Vec<Value> = vec![record_array(p_render_pass, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_render_pass2(
+    device: vk::Device,
+    p_create_info: *const vk::RenderPassCreateInfo2<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_render_pass: *mut vk::RenderPass,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateRenderPass2>(next(
+        255usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_render_pass);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_render_pass, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_render_pass);
+    push_call(
+        "vkCreateRenderPass2",
+        args,
+        vec![record_array(p_render_pass, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_render_pass2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateRenderPass2>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::RenderPassCreateInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_render_pass: *mut vk::RenderPass = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_render_pass);
+    let outputs: Vec<Value> = vec![record_array(p_render_pass, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_sampler(
+    device: vk::Device,
+    p_create_info: *const vk::SamplerCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_sampler: *mut vk::Sampler,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateSampler>(next(
+        256usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_sampler);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_sampler, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_sampler);
+    push_call(
+        "vkCreateSampler",
+        args,
+        vec![record_array(p_sampler, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_sampler(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateSampler>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::SamplerCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_sampler: *mut vk::Sampler = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_sampler);
+    let outputs: Vec<Value> = vec![record_array(p_sampler, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_sampler_ycbcr_conversion(
+    device: vk::Device,
+    p_create_info: *const vk::SamplerYcbcrConversionCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_ycbcr_conversion: *mut vk::SamplerYcbcrConversion,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateSamplerYcbcrConversion>(next(
+        257usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_ycbcr_conversion);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_ycbcr_conversion, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_ycbcr_conversion);
+    push_call(
+        "vkCreateSamplerYcbcrConversion",
+        args,
+        vec![record_array(p_ycbcr_conversion, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_sampler_ycbcr_conversion(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateSamplerYcbcrConversion>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::SamplerYcbcrConversionCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_ycbcr_conversion: *mut vk::SamplerYcbcrConversion =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_ycbcr_conversion);
+    let outputs: Vec<Value> = vec![record_array(p_ycbcr_conversion, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_semaphore(
+    device: vk::Device,
+    p_create_info: *const vk::SemaphoreCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_semaphore: *mut vk::Semaphore,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateSemaphore>(next(
+        258usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_semaphore);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_semaphore, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_semaphore);
+    push_call(
+        "vkCreateSemaphore",
+        args,
+        vec![record_array(p_semaphore, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_semaphore(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateSemaphore>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::SemaphoreCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_semaphore: *mut vk::Semaphore = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_semaphore);
+    let outputs: Vec<Value> = vec![record_array(p_semaphore, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_shader_module(
+    device: vk::Device,
+    p_create_info: *const vk::ShaderModuleCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_shader_module: *mut vk::ShaderModule,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateShaderModule>(next(
+        259usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_shader_module);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_shader_module, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_shader_module);
+    push_call(
+        "vkCreateShaderModule",
+        args,
+        vec![record_array(p_shader_module, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_shader_module(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateShaderModule>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::ShaderModuleCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_shader_module: *mut vk::ShaderModule =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_shader_module);
+    let outputs: Vec<Value> = vec![record_array(p_shader_module, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_shaders_ext(
+    device: vk::Device,
+    create_info_count: u32,
+    p_create_infos: *const vk::ShaderCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_shaders: *mut vk::ShaderEXT,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateShadersEXT>(next(
+        260usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            create_info_count,
+            p_create_infos,
+            p_allocator,
+            p_shaders,
+        );
+    }
+    let args = vec![
+        device.record(),
+        create_info_count.record(),
+        record_array(p_create_infos, create_info_count as usize, |element| {
+            element.record()
+        }),
+        Value::Null,
+        record_output(p_shaders, create_info_count as usize),
+    ];
+    let result = next(
+        device,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_shaders,
+    );
+    push_call(
+        "vkCreateShadersEXT",
+        args,
+        vec![record_array(
+            p_shaders,
+            create_info_count as usize,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_shaders_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateShadersEXT>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let create_info_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_create_infos: *const vk::ShaderCreateInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_shaders: *mut vk::ShaderEXT = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(
+        device,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_shaders,
+    );
+    let outputs: Vec<Value> = vec![record_array(
+        p_shaders,
+        create_info_count as usize,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_shared_swapchains_khr(
+    device: vk::Device,
+    swapchain_count: u32,
+    p_create_infos: *const vk::SwapchainCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_swapchains: *mut vk::SwapchainKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateSharedSwapchainsKHR>(next(
+        261usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            swapchain_count,
+            p_create_infos,
+            p_allocator,
+            p_swapchains,
+        );
+    }
+    let args = vec![
+        device.record(),
+        swapchain_count.record(),
+        record_array(p_create_infos, swapchain_count as usize, |element| {
+            element.record()
+        }),
+        Value::Null,
+        record_output(p_swapchains, swapchain_count as usize),
+    ];
+    let result = next(
+        device,
+        swapchain_count,
+        p_create_infos,
+        p_allocator,
+        p_swapchains,
+    );
+    push_call(
+        "vkCreateSharedSwapchainsKHR",
+        args,
+        vec![record_array(
+            p_swapchains,
+            swapchain_count as usize,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_shared_swapchains_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateSharedSwapchainsKHR>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_create_infos: *const vk::SwapchainCreateInfoKHR<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_swapchains: *mut vk::SwapchainKHR =
+        replay_array(&args[4usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        swapchain_count,
+        p_create_infos,
+        p_allocator,
+        p_swapchains,
+    );
+    let outputs: Vec<Value> = vec![record_array(
+        p_swapchains,
+        swapchain_count as usize,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_swapchain_khr(
+    device: vk::Device,
+    p_create_info: *const vk::SwapchainCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_swapchain: *mut vk::SwapchainKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateSwapchainKHR>(next(
+        262usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_swapchain);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_swapchain, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_swapchain);
+    push_call(
+        "vkCreateSwapchainKHR",
+        args,
+        vec![record_array(p_swapchain, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_swapchain_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateSwapchainKHR>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::SwapchainCreateInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_swapchain: *mut vk::SwapchainKHR = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_create_info, p_allocator, p_swapchain);
+    let outputs: Vec<Value> = vec![record_array(p_swapchain, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_validation_cache_ext(
+    device: vk::Device,
+    p_create_info: *const vk::ValidationCacheCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_validation_cache: *mut vk::ValidationCacheEXT,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateValidationCacheEXT>(next(
+        263usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_validation_cache);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_validation_cache, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_validation_cache);
+    push_call(
+        "vkCreateValidationCacheEXT",
+        args,
+        vec![record_array(p_validation_cache, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_validation_cache_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateValidationCacheEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::ValidationCacheCreateInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_validation_cache: *mut vk::ValidationCacheEXT =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_validation_cache);
+    let outputs: Vec<Value> = vec![record_array(p_validation_cache, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_video_session_khr(
+    device: vk::Device,
+    p_create_info: *const vk::VideoSessionCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_video_session: *mut vk::VideoSessionKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateVideoSessionKHR>(next(
+        264usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_allocator, p_video_session);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_video_session, 1),
+    ];
+    let result = next(device, p_create_info, p_allocator, p_video_session);
+    push_call(
+        "vkCreateVideoSessionKHR",
+        args,
+        vec![record_array(p_video_session, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_video_session_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateVideoSessionKHR>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::VideoSessionCreateInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_video_session: *mut vk::VideoSessionKHR =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_create_info, p_allocator, p_video_session);
+    let outputs: Vec<Value> = vec![record_array(p_video_session, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn create_video_session_parameters_khr(
+    device: vk::Device,
+    p_create_info: *const vk::VideoSessionParametersCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_video_session_parameters: *mut vk::VideoSessionParametersKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateVideoSessionParametersKHR>(next(
+        265usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            p_create_info,
+            p_allocator,
+            p_video_session_parameters,
+        );
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_video_session_parameters, 1),
+    ];
+    let result = next(
+        device,
+        p_create_info,
+        p_allocator,
+        p_video_session_parameters,
+    );
+    push_call(
+        "vkCreateVideoSessionParametersKHR",
+        args,
+        vec![record_array(p_video_session_parameters, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_create_video_session_parameters_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateVideoSessionParametersKHR>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::VideoSessionParametersCreateInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_video_session_parameters: *mut vk::VideoSessionParametersKHR =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        p_create_info,
+        p_allocator,
+        p_video_session_parameters,
+    );
+    let outputs: Vec<Value> = vec![record_array(p_video_session_parameters, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn debug_marker_set_object_name_ext(
+    device: vk::Device,
+    p_name_info: *const vk::DebugMarkerObjectNameInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDebugMarkerSetObjectNameEXT>(next(
+        266usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_name_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_name_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_name_info);
+    push_call(
+        "vkDebugMarkerSetObjectNameEXT",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_debug_marker_set_object_name_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDebugMarkerSetObjectNameEXT>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_name_info: *const vk::DebugMarkerObjectNameInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_name_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn debug_marker_set_object_tag_ext(
+    device: vk::Device,
+    p_tag_info: *const vk::DebugMarkerObjectTagInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDebugMarkerSetObjectTagEXT>(next(
+        267usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_tag_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_tag_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_tag_info);
+    push_call(
+        "vkDebugMarkerSetObjectTagEXT",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_debug_marker_set_object_tag_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDebugMarkerSetObjectTagEXT>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_tag_info: *const vk::DebugMarkerObjectTagInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_tag_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn deferred_operation_join_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDeferredOperationJoinKHR>(next(
+        268usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, operation);
+    }
+    let args = vec![device.record(), operation.record()];
+    let result = next(device, operation);
+    push_call("vkDeferredOperationJoinKHR", args, vec![], result.record());
+    result
+}
+unsafe fn replay_deferred_operation_join_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDeferredOperationJoinKHR>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, operation);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn destroy_acceleration_structure_khr(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyAccelerationStructureKHR>(next(
+        269usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, acceleration_structure, p_allocator);
+    }
+    let args = vec![
+        device.record(),
+        acceleration_structure.record(),
+        Value::Null,
+    ];
+    next(device, acceleration_structure, p_allocator);
+    push_call(
+        "vkDestroyAccelerationStructureKHR",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_destroy_acceleration_structure_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyAccelerationStructureKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let acceleration_structure: vk::AccelerationStructureKHR =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, acceleration_structure, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_acceleration_structure_nv(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyAccelerationStructureNV>(next(
+        270usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, acceleration_structure, p_allocator);
+    }
+    let args = vec![
+        device.record(),
+        acceleration_structure.record(),
+        Value::Null,
+    ];
+    next(device, acceleration_structure, p_allocator);
+    push_call(
+        "vkDestroyAccelerationStructureNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_destroy_acceleration_structure_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyAccelerationStructureNV>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let acceleration_structure: vk::AccelerationStructureNV =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, acceleration_structure, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_buffer(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyBuffer>(next(
+        271usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, buffer, p_allocator);
+    }
+    let args = vec![device.record(), buffer.record(), Value::Null];
+    next(device, buffer, p_allocator);
+    push_call("vkDestroyBuffer", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_buffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyBuffer>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, buffer, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_buffer_collection_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyBufferCollectionFUCHSIA>(next(
+        272usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, collection, p_allocator);
+    }
+    let args = vec![device.record(), collection.record(), Value::Null];
+    next(device, collection, p_allocator);
+    push_call(
+        "vkDestroyBufferCollectionFUCHSIA",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_destroy_buffer_collection_fuchsia(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyBufferCollectionFUCHSIA>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let collection: vk::BufferCollectionFUCHSIA = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, collection, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_buffer_view(
+    device: vk::Device,
+    buffer_view: vk::BufferView,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyBufferView>(next(
+        273usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, buffer_view, p_allocator);
+    }
+    let args = vec![device.record(), buffer_view.record(), Value::Null];
+    next(device, buffer_view, p_allocator);
+    push_call("vkDestroyBufferView", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_buffer_view(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyBufferView>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer_view: vk::BufferView = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, buffer_view, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyCommandPool>(next(
+        274usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, command_pool, p_allocator);
+    }
+    let args = vec![device.record(), command_pool.record(), Value::Null];
+    next(device, command_pool, p_allocator);
+    push_call("vkDestroyCommandPool", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_command_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyCommandPool>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let command_pool: vk::CommandPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, command_pool, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_cu_function_nvx(
+    device: vk::Device,
+    function: vk::CuFunctionNVX,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyCuFunctionNVX>(next(
+        275usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, function, p_allocator);
+    }
+    let args = vec![device.record(), function.record(), Value::Null];
+    next(device, function, p_allocator);
+    push_call("vkDestroyCuFunctionNVX", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_cu_function_nvx(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyCuFunctionNVX>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let function: vk::CuFunctionNVX = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, function, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_cu_module_nvx(
+    device: vk::Device,
+    module: vk::CuModuleNVX,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyCuModuleNVX>(next(
+        276usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, module, p_allocator);
+    }
+    let args = vec![device.record(), module.record(), Value::Null];
+    next(device, module, p_allocator);
+    push_call("vkDestroyCuModuleNVX", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_cu_module_nvx(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyCuModuleNVX>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let module: vk::CuModuleNVX = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, module, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_deferred_operation_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDeferredOperationKHR>(next(
+        277usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, operation, p_allocator);
+    }
+    let args = vec![device.record(), operation.record(), Value::Null];
+    next(device, operation, p_allocator);
+    push_call("vkDestroyDeferredOperationKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_deferred_operation_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyDeferredOperationKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, operation, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_descriptor_pool(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDescriptorPool>(next(
+        278usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, descriptor_pool, p_allocator);
+    }
+    let args = vec![device.record(), descriptor_pool.record(), Value::Null];
+    next(device, descriptor_pool, p_allocator);
+    push_call("vkDestroyDescriptorPool", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_descriptor_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyDescriptorPool>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let descriptor_pool: vk::DescriptorPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, descriptor_pool, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_descriptor_set_layout(
+    device: vk::Device,
+    descriptor_set_layout: vk::DescriptorSetLayout,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDescriptorSetLayout>(next(
+        279usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, descriptor_set_layout, p_allocator);
+    }
+    let args = vec![device.record(), descriptor_set_layout.record(), Value::Null];
+    next(device, descriptor_set_layout, p_allocator);
+    push_call("vkDestroyDescriptorSetLayout", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_descriptor_set_layout(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyDescriptorSetLayout>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let descriptor_set_layout: vk::DescriptorSetLayout =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, descriptor_set_layout, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_descriptor_update_template(
+    device: vk::Device,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDescriptorUpdateTemplate>(next(
+        280usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, descriptor_update_template, p_allocator);
+    }
+    let args = vec![
+        device.record(),
+        descriptor_update_template.record(),
+        Value::Null,
+    ];
+    next(device, descriptor_update_template, p_allocator);
+    push_call(
+        "vkDestroyDescriptorUpdateTemplate",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_destroy_descriptor_update_template(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyDescriptorUpdateTemplate>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let descriptor_update_template: vk::DescriptorUpdateTemplate =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, descriptor_update_template, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_device(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDevice>(next(
+        281usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_allocator);
+    }
+    let args = vec![device.record(), Value::Null];
+    next(device, p_allocator);
+    push_call("vkDestroyDevice", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_device(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyDevice>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_event(
+    device: vk::Device,
+    event: vk::Event,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyEvent>(next(
+        282usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, event, p_allocator);
+    }
+    let args = vec![device.record(), event.record(), Value::Null];
+    next(device, event, p_allocator);
+    push_call("vkDestroyEvent", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_event(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyEvent>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let event: vk::Event = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, event, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_fence(
+    device: vk::Device,
+    fence: vk::Fence,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyFence>(next(
+        283usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, fence, p_allocator);
+    }
+    let args = vec![device.record(), fence.record(), Value::Null];
+    next(device, fence, p_allocator);
+    push_call("vkDestroyFence", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_fence(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyFence>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let fence: vk::Fence = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, fence, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_framebuffer(
+    device: vk::Device,
+    framebuffer: vk::Framebuffer,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyFramebuffer>(next(
+        284usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, framebuffer, p_allocator);
+    }
+    let args = vec![device.record(), framebuffer.record(), Value::Null];
+    next(device, framebuffer, p_allocator);
+    push_call("vkDestroyFramebuffer", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_framebuffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyFramebuffer>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let framebuffer: vk::Framebuffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, framebuffer, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_image(
+    device: vk::Device,
+    image: vk::Image,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyImage>(next(
+        285usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, image, p_allocator);
+    }
+    let args = vec![device.record(), image.record(), Value::Null];
+    next(device, image, p_allocator);
+    push_call("vkDestroyImage", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_image(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyImage>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, image, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_image_view(
+    device: vk::Device,
+    image_view: vk::ImageView,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyImageView>(next(
+        286usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, image_view, p_allocator);
+    }
+    let args = vec![device.record(), image_view.record(), Value::Null];
+    next(device, image_view, p_allocator);
+    push_call("vkDestroyImageView", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_image_view(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyImageView>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let image_view: vk::ImageView = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, image_view, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_indirect_commands_layout_nv(
+    device: vk::Device,
+    indirect_commands_layout: vk::IndirectCommandsLayoutNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyIndirectCommandsLayoutNV>(next(
+        287usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, indirect_commands_layout, p_allocator);
+    }
+    let args = vec![
+        device.record(),
+        indirect_commands_layout.record(),
+        Value::Null,
+    ];
+    next(device, indirect_commands_layout, p_allocator);
+    push_call(
+        "vkDestroyIndirectCommandsLayoutNV",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_destroy_indirect_commands_layout_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyIndirectCommandsLayoutNV>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let indirect_commands_layout: vk::IndirectCommandsLayoutNV =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, indirect_commands_layout, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_micromap_ext(
+    device: vk::Device,
+    micromap: vk::MicromapEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyMicromapEXT>(next(
+        288usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, micromap, p_allocator);
+    }
+    let args = vec![device.record(), micromap.record(), Value::Null];
+    next(device, micromap, p_allocator);
+    push_call("vkDestroyMicromapEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_micromap_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyMicromapEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let micromap: vk::MicromapEXT = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, micromap, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_optical_flow_session_nv(
+    device: vk::Device,
+    session: vk::OpticalFlowSessionNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyOpticalFlowSessionNV>(next(
+        289usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, session, p_allocator);
+    }
+    let args = vec![device.record(), session.record(), Value::Null];
+    next(device, session, p_allocator);
+    push_call("vkDestroyOpticalFlowSessionNV", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_optical_flow_session_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyOpticalFlowSessionNV>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let session: vk::OpticalFlowSessionNV = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, session, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_pipeline(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyPipeline>(next(
+        290usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, pipeline, p_allocator);
+    }
+    let args = vec![device.record(), pipeline.record(), Value::Null];
+    next(device, pipeline, p_allocator);
+    push_call("vkDestroyPipeline", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_pipeline(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyPipeline>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline: vk::Pipeline = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, pipeline, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_pipeline_cache(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyPipelineCache>(next(
+        291usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, pipeline_cache, p_allocator);
+    }
+    let args = vec![device.record(), pipeline_cache.record(), Value::Null];
+    next(device, pipeline_cache, p_allocator);
+    push_call("vkDestroyPipelineCache", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_pipeline_cache(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyPipelineCache>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_cache: vk::PipelineCache = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, pipeline_cache, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_pipeline_layout(
+    device: vk::Device,
+    pipeline_layout: vk::PipelineLayout,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyPipelineLayout>(next(
+        292usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, pipeline_layout, p_allocator);
+    }
+    let args = vec![device.record(), pipeline_layout.record(), Value::Null];
+    next(device, pipeline_layout, p_allocator);
+    push_call("vkDestroyPipelineLayout", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_pipeline_layout(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyPipelineLayout>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_layout: vk::PipelineLayout = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, pipeline_layout, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_private_data_slot(
+    device: vk::Device,
+    private_data_slot: vk::PrivateDataSlot,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyPrivateDataSlot>(next(
+        293usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, private_data_slot, p_allocator);
+    }
+    let args = vec![device.record(), private_data_slot.record(), Value::Null];
+    next(device, private_data_slot, p_allocator);
+    push_call("vkDestroyPrivateDataSlot", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_private_data_slot(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyPrivateDataSlot>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let private_data_slot: vk::PrivateDataSlot = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, private_data_slot, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_query_pool(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyQueryPool>(next(
+        294usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, query_pool, p_allocator);
+    }
+    let args = vec![device.record(), query_pool.record(), Value::Null];
+    next(device, query_pool, p_allocator);
+    push_call("vkDestroyQueryPool", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_query_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyQueryPool>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, query_pool, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_render_pass(
+    device: vk::Device,
+    render_pass: vk::RenderPass,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyRenderPass>(next(
+        295usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, render_pass, p_allocator);
+    }
+    let args = vec![device.record(), render_pass.record(), Value::Null];
+    next(device, render_pass, p_allocator);
+    push_call("vkDestroyRenderPass", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_render_pass(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyRenderPass>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let render_pass: vk::RenderPass = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, render_pass, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_sampler(
+    device: vk::Device,
+    sampler: vk::Sampler,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroySampler>(next(
+        296usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, sampler, p_allocator);
+    }
+    let args = vec![device.record(), sampler.record(), Value::Null];
+    next(device, sampler, p_allocator);
+    push_call("vkDestroySampler", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_sampler(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroySampler>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let sampler: vk::Sampler = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, sampler, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_sampler_ycbcr_conversion(
+    device: vk::Device,
+    ycbcr_conversion: vk::SamplerYcbcrConversion,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroySamplerYcbcrConversion>(next(
+        297usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, ycbcr_conversion, p_allocator);
+    }
+    let args = vec![device.record(), ycbcr_conversion.record(), Value::Null];
+    next(device, ycbcr_conversion, p_allocator);
+    push_call("vkDestroySamplerYcbcrConversion", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_sampler_ycbcr_conversion(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroySamplerYcbcrConversion>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let ycbcr_conversion: vk::SamplerYcbcrConversion = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, ycbcr_conversion, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_semaphore(
+    device: vk::Device,
+    semaphore: vk::Semaphore,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroySemaphore>(next(
+        298usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, semaphore, p_allocator);
+    }
+    let args = vec![device.record(), semaphore.record(), Value::Null];
+    next(device, semaphore, p_allocator);
+    push_call("vkDestroySemaphore", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_semaphore(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroySemaphore>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let semaphore: vk::Semaphore = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, semaphore, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_shader_ext(
+    device: vk::Device,
+    shader: vk::ShaderEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyShaderEXT>(next(
+        299usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, shader, p_allocator);
+    }
+    let args = vec![device.record(), shader.record(), Value::Null];
+    next(device, shader, p_allocator);
+    push_call("vkDestroyShaderEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_shader_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyShaderEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let shader: vk::ShaderEXT = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, shader, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_shader_module(
+    device: vk::Device,
+    shader_module: vk::ShaderModule,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyShaderModule>(next(
+        300usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, shader_module, p_allocator);
+    }
+    let args = vec![device.record(), shader_module.record(), Value::Null];
+    next(device, shader_module, p_allocator);
+    push_call("vkDestroyShaderModule", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_shader_module(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyShaderModule>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let shader_module: vk::ShaderModule = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, shader_module, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_swapchain_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroySwapchainKHR>(next(
+        301usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, swapchain, p_allocator);
+    }
+    let args = vec![device.record(), swapchain.record(), Value::Null];
+    next(device, swapchain, p_allocator);
+    push_call("vkDestroySwapchainKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_swapchain_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroySwapchainKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, swapchain, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_validation_cache_ext(
+    device: vk::Device,
+    validation_cache: vk::ValidationCacheEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyValidationCacheEXT>(next(
+        302usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, validation_cache, p_allocator);
+    }
+    let args = vec![device.record(), validation_cache.record(), Value::Null];
+    next(device, validation_cache, p_allocator);
+    push_call("vkDestroyValidationCacheEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_validation_cache_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyValidationCacheEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let validation_cache: vk::ValidationCacheEXT = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, validation_cache, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_video_session_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyVideoSessionKHR>(next(
+        303usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, video_session, p_allocator);
+    }
+    let args = vec![device.record(), video_session.record(), Value::Null];
+    next(device, video_session, p_allocator);
+    push_call("vkDestroyVideoSessionKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_destroy_video_session_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyVideoSessionKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let video_session: vk::VideoSessionKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, video_session, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn destroy_video_session_parameters_khr(
+    device: vk::Device,
+    video_session_parameters: vk::VideoSessionParametersKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyVideoSessionParametersKHR>(next(
+        304usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, video_session_parameters, p_allocator);
+    }
+    let args = vec![
+        device.record(),
+        video_session_parameters.record(),
+        Value::Null,
+    ];
+    next(device, video_session_parameters, p_allocator);
+    push_call(
+        "vkDestroyVideoSessionParametersKHR",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_destroy_video_session_parameters_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyVideoSessionParametersKHR>(
+        f,
+    );
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let video_session_parameters: vk::VideoSessionParametersKHR =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, video_session_parameters, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn device_wait_idle(device: vk::Device) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDeviceWaitIdle>(next(
+        305usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device);
+    }
+    let args = vec![device.record()];
+    let result = next(device);
+    push_call("vkDeviceWaitIdle", args, vec![], result.record());
+    result
+}
+unsafe fn replay_device_wait_idle(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDeviceWaitIdle>(f);
+    let args = args::<1usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let result = f(device);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn display_power_control_ext(
+    device: vk::Device,
+    display: vk::DisplayKHR,
+    p_display_power_info: *const vk::DisplayPowerInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDisplayPowerControlEXT>(next(
+        306usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, display, p_display_power_info);
+    }
+    let args = vec![
+        device.record(),
+        display.record(),
+        record_array(p_display_power_info, 1, |element| element.record()),
+    ];
+    let result = next(device, display, p_display_power_info);
+    push_call("vkDisplayPowerControlEXT", args, vec![], result.record());
+    result
+}
+unsafe fn replay_display_power_control_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDisplayPowerControlEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let display: vk::DisplayKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_display_power_info: *const vk::DisplayPowerInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, display, p_display_power_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn end_command_buffer(command_buffer: vk::CommandBuffer) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkEndCommandBuffer>(next(
+        307usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer);
+    }
+    let args = vec![command_buffer.record()];
+    let result = next(command_buffer);
+    push_call("vkEndCommandBuffer", args, vec![], result.record());
+    result
+}
+unsafe fn replay_end_command_buffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkEndCommandBuffer>(f);
+    let args = args::<1usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let result = f(command_buffer);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn export_metal_objects_ext(
+    device: vk::Device,
+    p_metal_objects_info: *mut vk::ExportMetalObjectsInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkExportMetalObjectsEXT>(next(
+        308usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_metal_objects_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_metal_objects_info, 1, |element| element.record()),
+    ];
+    next(device, p_metal_objects_info);
+    push_call(
+        "vkExportMetalObjectsEXT",
+        args,
+        vec![record_array(p_metal_objects_info, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_export_metal_objects_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkExportMetalObjectsEXT>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_metal_objects_info: *mut vk::ExportMetalObjectsInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_metal_objects_info);
+    let outputs: Vec<Value> = vec![record_array(p_metal_objects_info, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn flush_mapped_memory_ranges(
+    device: vk::Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const vk::MappedMemoryRange<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkFlushMappedMemoryRanges>(next(
+        309usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, memory_range_count, p_memory_ranges);
+    }
+    let args = vec![
+        device.record(),
+        memory_range_count.record(),
+        record_array(p_memory_ranges, memory_range_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, memory_range_count, p_memory_ranges);
+    push_call("vkFlushMappedMemoryRanges", args, vec![], result.record());
+    result
+}
+unsafe fn replay_flush_mapped_memory_ranges(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkFlushMappedMemoryRanges>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let memory_range_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_memory_ranges: *const vk::MappedMemoryRange<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, memory_range_count, p_memory_ranges);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn free_command_buffers(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    command_buffer_count: u32,
+    p_command_buffers: *const vk::CommandBuffer,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkFreeCommandBuffers>(next(
+        310usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            command_pool,
+            command_buffer_count,
+            p_command_buffers,
+        );
+    }
+    let args = vec![
+        device.record(),
+        command_pool.record(),
+        command_buffer_count.record(),
+        record_array(
+            p_command_buffers,
+            command_buffer_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        device,
+        command_pool,
+        command_buffer_count,
+        p_command_buffers,
+    );
+    push_call("vkFreeCommandBuffers", args, vec![], Value::Null);
+}
+unsafe fn replay_free_command_buffers(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkFreeCommandBuffers>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let command_pool: vk::CommandPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let command_buffer_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_command_buffers: *const vk::CommandBuffer =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        device,
+        command_pool,
+        command_buffer_count,
+        p_command_buffers,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn free_descriptor_sets(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const vk::DescriptorSet,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkFreeDescriptorSets>(next(
+        311usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            descriptor_pool,
+            descriptor_set_count,
+            p_descriptor_sets,
+        );
+    }
+    let args = vec![
+        device.record(),
+        descriptor_pool.record(),
+        descriptor_set_count.record(),
+        record_array(
+            p_descriptor_sets,
+            descriptor_set_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    let result = next(
+        device,
+        descriptor_pool,
+        descriptor_set_count,
+        p_descriptor_sets,
+    );
+    push_call("vkFreeDescriptorSets", args, vec![], result.record());
+    result
+}
+unsafe fn replay_free_descriptor_sets(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkFreeDescriptorSets>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let descriptor_pool: vk::DescriptorPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let descriptor_set_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_descriptor_sets: *const vk::DescriptorSet =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        descriptor_pool,
+        descriptor_set_count,
+        p_descriptor_sets,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn free_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkFreeMemory>(next(
+        312usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, memory, p_allocator);
+    }
+    let args = vec![device.record(), memory.record(), Value::Null];
+    next(device, memory, p_allocator);
+    push_call("vkFreeMemory", args, vec![], Value::Null);
+}
+unsafe fn replay_free_memory(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkFreeMemory>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let memory: vk::DeviceMemory = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    f(device, memory, p_allocator);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_acceleration_structure_build_sizes_khr(
+    device: vk::Device,
+    build_type: vk::AccelerationStructureBuildTypeKHR,
+    p_build_info: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    p_max_primitive_counts: *const u32,
+    p_size_info: *mut vk::AccelerationStructureBuildSizesInfoKHR<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetAccelerationStructureBuildSizesKHR>(
+        next(313usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(
+            device,
+            build_type,
+            p_build_info,
+            p_max_primitive_counts,
+            p_size_info,
+        );
+    }
+    let args = vec![
+        device.record(),
+        build_type.record(),
+        record_array(p_build_info, 1, |element| element.record()),
+        record_data(
+            p_max_primitive_counts,
+            (*p_build_info).geometry_count as usize,
+        ),
+        record_array(p_size_info, 1, |element| element.record()),
+    ];
+    next(
+        device,
+        build_type,
+        p_build_info,
+        p_max_primitive_counts,
+        p_size_info,
+    );
+    push_call(
+        "vkGetAccelerationStructureBuildSizesKHR",
+        args,
+        vec![record_array(p_size_info, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_acceleration_structure_build_sizes_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetAccelerationStructureBuildSizesKHR,
+    >(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let build_type: vk::AccelerationStructureBuildTypeKHR =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let p_build_info: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_max_primitive_counts: *const u32 = replay_data(&args[3usize], arena)?;
+    let p_size_info: *mut vk::AccelerationStructureBuildSizesInfoKHR<'_> =
+        replay_array(&args[4usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        device,
+        build_type,
+        p_build_info,
+        p_max_primitive_counts,
+        p_size_info,
+    );
+    let outputs: Vec<Value> = vec![record_array(p_size_info, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_acceleration_structure_device_address_khr(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureDeviceAddressInfoKHR<'_>,
+) -> vk::DeviceAddress {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetAccelerationStructureDeviceAddressKHR>(
+        next(314usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_info);
+    push_call(
+        "vkGetAccelerationStructureDeviceAddressKHR",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_acceleration_structure_device_address_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetAccelerationStructureDeviceAddressKHR,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::AccelerationStructureDeviceAddressInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_acceleration_structure_handle_nv(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureNV,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetAccelerationStructureHandleNV>(next(
+        315usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, acceleration_structure, data_size, p_data);
+    }
+    let args = vec![
+        device.record(),
+        acceleration_structure.record(),
+        data_size.record(),
+        record_data_output(p_data.cast::<u8>(), data_size),
+    ];
+    let result = next(device, acceleration_structure, data_size, p_data);
+    push_call(
+        "vkGetAccelerationStructureHandleNV",
+        args,
+        vec![record_data(p_data.cast::<u8>(), data_size)],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_acceleration_structure_handle_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetAccelerationStructureHandleNV>(
+        f,
+    );
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let acceleration_structure: vk::AccelerationStructureNV =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let data_size: usize = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_data: *mut c_void = replay_data::<u8>(&args[3usize], arena)?.cast::<c_void>();
+    let result = f(device, acceleration_structure, data_size, p_data);
+    let outputs: Vec<Value> = vec![record_data(p_data.cast::<u8>(), data_size)];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_acceleration_structure_memory_requirements_nv(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureMemoryRequirementsInfoNV<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2KHR<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetAccelerationStructureMemoryRequirementsNV>(
+        next(316usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_info, p_memory_requirements);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        record_array(p_memory_requirements, 1, |element| element.record()),
+    ];
+    next(device, p_info, p_memory_requirements);
+    push_call(
+        "vkGetAccelerationStructureMemoryRequirementsNV",
+        args,
+        vec![record_array(p_memory_requirements, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_acceleration_structure_memory_requirements_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetAccelerationStructureMemoryRequirementsNV,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::AccelerationStructureMemoryRequirementsInfoNV<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_memory_requirements: *mut vk::MemoryRequirements2KHR<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_info, p_memory_requirements);
+    let outputs: Vec<Value> = vec![record_array(p_memory_requirements, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_acceleration_structure_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT,
+    >(next(317usize, dispatch_key(device.as_raw())));
+    if !is_recording() {
+        return next(device, p_info, p_data);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        Value::Null,
+    ];
+    let result = next(device, p_info, p_data);
+    push_call(
+        "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT",
+        args,
+        vec![Value::Null],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_acceleration_structure_opaque_capture_descriptor_data_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::AccelerationStructureCaptureDescriptorDataInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_data: *mut c_void = ptr::null_mut();
+    let result = f(device, p_info, p_data);
+    let outputs: Vec<Value> = vec![Value::Null];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_android_hardware_buffer_properties_android(
+    device: vk::Device,
+    buffer: *const vk::AHardwareBuffer,
+    p_properties: *mut vk::AndroidHardwareBufferPropertiesANDROID<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetAndroidHardwareBufferPropertiesANDROID>(
+        next(318usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, buffer, p_properties);
+    }
+    let args = vec![
+        device.record(),
+        buffer.record(),
+        record_array(p_properties, 1, |element| element.record()),
+    ];
+    let result = next(device, buffer, p_properties);
+    push_call(
+        "vkGetAndroidHardwareBufferPropertiesANDROID",
+        args,
+        vec![record_array(p_properties, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_android_hardware_buffer_properties_android(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetAndroidHardwareBufferPropertiesANDROID,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: *const vk::AHardwareBuffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_properties: *mut vk::AndroidHardwareBufferPropertiesANDROID<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, buffer, p_properties);
+    let outputs: Vec<Value> = vec![record_array(p_properties, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_buffer_collection_properties_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_properties: *mut vk::BufferCollectionPropertiesFUCHSIA<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferCollectionPropertiesFUCHSIA>(next(
+        319usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, collection, p_properties);
+    }
+    let args = vec![
+        device.record(),
+        collection.record(),
+        record_array(p_properties, 1, |element| element.record()),
+    ];
+    let result = next(device, collection, p_properties);
+    push_call(
+        "vkGetBufferCollectionPropertiesFUCHSIA",
+        args,
+        vec![record_array(p_properties, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_buffer_collection_properties_fuchsia(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetBufferCollectionPropertiesFUCHSIA,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let collection: vk::BufferCollectionFUCHSIA = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_properties: *mut vk::BufferCollectionPropertiesFUCHSIA<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, collection, p_properties);
+    let outputs: Vec<Value> = vec![record_array(p_properties, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_buffer_device_address(
+    device: vk::Device,
+    p_info: *const vk::BufferDeviceAddressInfo<'_>,
+) -> vk::DeviceAddress {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferDeviceAddress>(next(
+        320usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_info);
+    push_call("vkGetBufferDeviceAddress", args, vec![], result.record());
+    result
+}
+unsafe fn replay_get_buffer_device_address(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetBufferDeviceAddress>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::BufferDeviceAddressInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_buffer_memory_requirements(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferMemoryRequirements>(next(
+        321usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, buffer, p_memory_requirements);
+    }
+    let args = vec![
+        device.record(),
+        buffer.record(),
+        record_output(p_memory_requirements, 1),
+    ];
+    next(device, buffer, p_memory_requirements);
+    push_call(
+        "vkGetBufferMemoryRequirements",
+        args,
+        vec![record_array(p_memory_requirements, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_buffer_memory_requirements(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetBufferMemoryRequirements>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: vk::Buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_memory_requirements: *mut vk::MemoryRequirements =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, buffer, p_memory_requirements);
+    let outputs: Vec<Value> = vec![record_array(p_memory_requirements, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_buffer_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::BufferMemoryRequirementsInfo2<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferMemoryRequirements2>(next(
+        322usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_info, p_memory_requirements);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        record_array(p_memory_requirements, 1, |element| element.record()),
+    ];
+    next(device, p_info, p_memory_requirements);
+    push_call(
+        "vkGetBufferMemoryRequirements2",
+        args,
+        vec![record_array(p_memory_requirements, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_buffer_memory_requirements2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetBufferMemoryRequirements2>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::BufferMemoryRequirementsInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_memory_requirements: *mut vk::MemoryRequirements2<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_info, p_memory_requirements);
+    let outputs: Vec<Value> = vec![record_array(p_memory_requirements, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_buffer_opaque_capture_address(
+    device: vk::Device,
+    p_info: *const vk::BufferDeviceAddressInfo<'_>,
+) -> u64 {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferOpaqueCaptureAddress>(next(
+        323usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_info);
+    push_call(
+        "vkGetBufferOpaqueCaptureAddress",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_buffer_opaque_capture_address(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetBufferOpaqueCaptureAddress>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::BufferDeviceAddressInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_buffer_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::BufferCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferOpaqueCaptureDescriptorDataEXT>(
+        next(324usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_info, p_data);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        Value::Null,
+    ];
+    let result = next(device, p_info, p_data);
+    push_call(
+        "vkGetBufferOpaqueCaptureDescriptorDataEXT",
+        args,
+        vec![Value::Null],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_buffer_opaque_capture_descriptor_data_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetBufferOpaqueCaptureDescriptorDataEXT,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::BufferCaptureDescriptorDataInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_data: *mut c_void = ptr::null_mut();
+    let result = f(device, p_info, p_data);
+    let outputs: Vec<Value> = vec![Value::Null];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_calibrated_timestamps_ext(
+    device: vk::Device,
+    timestamp_count: u32,
+    p_timestamp_infos: *const vk::CalibratedTimestampInfoEXT<'_>,
+    p_timestamps: *mut u64,
+    p_max_deviation: *mut u64,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetCalibratedTimestampsEXT>(next(
+        325usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            timestamp_count,
+            p_timestamp_infos,
+            p_timestamps,
+            p_max_deviation,
+        );
+    }
+    let args = vec![
+        device.record(),
+        timestamp_count.record(),
+        record_array(p_timestamp_infos, timestamp_count as usize, |element| {
+            element.record()
+        }),
+        record_output(p_timestamps, timestamp_count as usize),
+        record_output(p_max_deviation, 1),
+    ];
+    let result = next(
+        device,
+        timestamp_count,
+        p_timestamp_infos,
+        p_timestamps,
+        p_max_deviation,
+    );
+    push_call(
+        "vkGetCalibratedTimestampsEXT",
+        args,
+        vec![
+            record_array(p_timestamps, timestamp_count as usize, |element| {
+                element.record()
+            }),
+            record_array(p_max_deviation, 1, |element| element.record()),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_calibrated_timestamps_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetCalibratedTimestampsEXT>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let timestamp_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_timestamp_infos: *const vk::CalibratedTimestampInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_timestamps: *mut u64 = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_max_deviation: *mut u64 = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(
+        device,
+        timestamp_count,
+        p_timestamp_infos,
+        p_timestamps,
+        p_max_deviation,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(p_timestamps, timestamp_count as usize, |element| {
+            element.record()
+        }),
+        record_array(p_max_deviation, 1, |element| element.record()),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_deferred_operation_max_concurrency_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> u32 {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeferredOperationMaxConcurrencyKHR>(
+        next(326usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, operation);
+    }
+    let args = vec![device.record(), operation.record()];
+    let result = next(device, operation);
+    push_call(
+        "vkGetDeferredOperationMaxConcurrencyKHR",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_deferred_operation_max_concurrency_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDeferredOperationMaxConcurrencyKHR,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, operation);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_deferred_operation_result_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeferredOperationResultKHR>(next(
+        327usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, operation);
+    }
+    let args = vec![device.record(), operation.record()];
+    let result = next(device, operation);
+    push_call(
+        "vkGetDeferredOperationResultKHR",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_deferred_operation_result_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDeferredOperationResultKHR>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let operation: vk::DeferredOperationKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, operation);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_descriptor_ext(
+    device: vk::Device,
+    p_descriptor_info: *const vk::DescriptorGetInfoEXT<'_>,
+    data_size: usize,
+    p_descriptor: *mut c_void,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorEXT>(next(
+        328usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_descriptor_info, data_size, p_descriptor);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_descriptor_info, 1, |element| element.record()),
+        data_size.record(),
+        record_data_output(p_descriptor.cast::<u8>(), data_size),
+    ];
+    next(device, p_descriptor_info, data_size, p_descriptor);
+    push_call(
+        "vkGetDescriptorEXT",
+        args,
+        vec![record_data(p_descriptor.cast::<u8>(), data_size)],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_descriptor_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDescriptorEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_descriptor_info: *const vk::DescriptorGetInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let data_size: usize = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_descriptor: *mut c_void = replay_data::<u8>(&args[3usize], arena)?.cast::<c_void>();
+    f(device, p_descriptor_info, data_size, p_descriptor);
+    let outputs: Vec<Value> = vec![record_data(p_descriptor.cast::<u8>(), data_size)];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_descriptor_set_host_mapping_valve(
+    device: vk::Device,
+    descriptor_set: vk::DescriptorSet,
+    pp_data: *mut *mut c_void,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorSetHostMappingVALVE>(next(
+        329usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, descriptor_set, pp_data);
+    }
+    let args = vec![
+        device.record(),
+        descriptor_set.record(),
+        record_output(pp_data, 1),
+    ];
+    next(device, descriptor_set, pp_data);
+    push_call(
+        "vkGetDescriptorSetHostMappingVALVE",
+        args,
+        vec![record_array(pp_data, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_descriptor_set_host_mapping_valve(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDescriptorSetHostMappingVALVE>(
+        f,
+    );
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let descriptor_set: vk::DescriptorSet = DeepCopyable::replay(&args[1usize], arena)?;
+    let pp_data: *mut *mut c_void = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(device, descriptor_set, pp_data);
+    let outputs: Vec<Value> = vec![record_array(pp_data, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_descriptor_set_layout_binding_offset_ext(
+    device: vk::Device,
+    layout: vk::DescriptorSetLayout,
+    binding: u32,
+    p_offset: *mut vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorSetLayoutBindingOffsetEXT>(
+        next(330usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, layout, binding, p_offset);
+    }
+    let args = vec![
+        device.record(),
+        layout.record(),
+        binding.record(),
+        record_output(p_offset, 1),
+    ];
+    next(device, layout, binding, p_offset);
+    push_call(
+        "vkGetDescriptorSetLayoutBindingOffsetEXT",
+        args,
+        vec![record_array(p_offset, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_descriptor_set_layout_binding_offset_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDescriptorSetLayoutBindingOffsetEXT,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let layout: vk::DescriptorSetLayout = DeepCopyable::replay(&args[1usize], arena)?;
+    let binding: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_offset: *mut vk::DeviceSize = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(device, layout, binding, p_offset);
+    let outputs: Vec<Value> = vec![record_array(p_offset, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_descriptor_set_layout_host_mapping_info_valve(
+    device: vk::Device,
+    p_binding_reference: *const vk::DescriptorSetBindingReferenceVALVE<'_>,
+    p_host_mapping: *mut vk::DescriptorSetLayoutHostMappingInfoVALVE<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorSetLayoutHostMappingInfoVALVE>(
+        next(331usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_binding_reference, p_host_mapping);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_binding_reference, 1, |element| element.record()),
+        record_array(p_host_mapping, 1, |element| element.record()),
+    ];
+    next(device, p_binding_reference, p_host_mapping);
+    push_call(
+        "vkGetDescriptorSetLayoutHostMappingInfoVALVE",
+        args,
+        vec![record_array(p_host_mapping, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_descriptor_set_layout_host_mapping_info_valve(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDescriptorSetLayoutHostMappingInfoVALVE,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_binding_reference: *const vk::DescriptorSetBindingReferenceVALVE<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_host_mapping: *mut vk::DescriptorSetLayoutHostMappingInfoVALVE<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_binding_reference, p_host_mapping);
+    let outputs: Vec<Value> = vec![record_array(p_host_mapping, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_descriptor_set_layout_size_ext(
+    device: vk::Device,
+    layout: vk::DescriptorSetLayout,
+    p_layout_size_in_bytes: *mut vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorSetLayoutSizeEXT>(next(
+        332usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, layout, p_layout_size_in_bytes);
+    }
+    let args = vec![
+        device.record(),
+        layout.record(),
+        record_output(p_layout_size_in_bytes, 1),
+    ];
+    next(device, layout, p_layout_size_in_bytes);
+    push_call(
+        "vkGetDescriptorSetLayoutSizeEXT",
+        args,
+        vec![record_array(p_layout_size_in_bytes, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_descriptor_set_layout_size_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDescriptorSetLayoutSizeEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let layout: vk::DescriptorSetLayout = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_layout_size_in_bytes: *mut vk::DeviceSize =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, layout, p_layout_size_in_bytes);
+    let outputs: Vec<Value> = vec![record_array(p_layout_size_in_bytes, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_descriptor_set_layout_support(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorSetLayoutCreateInfo<'_>,
+    p_support: *mut vk::DescriptorSetLayoutSupport<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorSetLayoutSupport>(next(
+        333usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_create_info, p_support);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        record_array(p_support, 1, |element| element.record()),
+    ];
+    next(device, p_create_info, p_support);
+    push_call(
+        "vkGetDescriptorSetLayoutSupport",
+        args,
+        vec![record_array(p_support, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_descriptor_set_layout_support(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDescriptorSetLayoutSupport>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::DescriptorSetLayoutCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_support: *mut vk::DescriptorSetLayoutSupport<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_create_info, p_support);
+    let outputs: Vec<Value> = vec![record_array(p_support, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_device_acceleration_structure_compatibility_khr(
+    device: vk::Device,
+    p_version_info: *const vk::AccelerationStructureVersionInfoKHR<'_>,
+    p_compatibility: *mut vk::AccelerationStructureCompatibilityKHR,
+) {
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetDeviceAccelerationStructureCompatibilityKHR,
+    >(next(334usize, dispatch_key(device.as_raw())));
+    if !is_recording() {
+        return next(device, p_version_info, p_compatibility);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_version_info, 1, |element| element.record()),
+        record_output(p_compatibility, 1),
+    ];
+    next(device, p_version_info, p_compatibility);
+    push_call(
+        "vkGetDeviceAccelerationStructureCompatibilityKHR",
+        args,
+        vec![record_array(p_compatibility, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_device_acceleration_structure_compatibility_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDeviceAccelerationStructureCompatibilityKHR,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_version_info: *const vk::AccelerationStructureVersionInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_compatibility: *mut vk::AccelerationStructureCompatibilityKHR =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_version_info, p_compatibility);
+    let outputs: Vec<Value> = vec![record_array(p_compatibility, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_device_buffer_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceBufferMemoryRequirements<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceBufferMemoryRequirements>(next(
+        335usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_info, p_memory_requirements);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        record_array(p_memory_requirements, 1, |element| element.record()),
+    ];
+    next(device, p_info, p_memory_requirements);
+    push_call(
+        "vkGetDeviceBufferMemoryRequirements",
+        args,
+        vec![record_array(p_memory_requirements, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_device_buffer_memory_requirements(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDeviceBufferMemoryRequirements,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::DeviceBufferMemoryRequirements<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_memory_requirements: *mut vk::MemoryRequirements2<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_info, p_memory_requirements);
+    let outputs: Vec<Value> = vec![record_array(p_memory_requirements, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_device_fault_info_ext(
+    device: vk::Device,
+    p_fault_counts: *mut vk::DeviceFaultCountsEXT<'_>,
+    p_fault_info: *mut vk::DeviceFaultInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceFaultInfoEXT>(next(
+        336usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_fault_counts, p_fault_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_fault_counts, 1, |element| element.record()),
+        record_array(p_fault_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_fault_counts, p_fault_info);
+    push_call(
+        "vkGetDeviceFaultInfoEXT",
+        args,
+        vec![
+            record_array(p_fault_counts, 1, |element| element.record()),
+            record_array(p_fault_info, 1, |element| element.record()),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_device_fault_info_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDeviceFaultInfoEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_fault_counts: *mut vk::DeviceFaultCountsEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_fault_info: *mut vk::DeviceFaultInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_fault_counts, p_fault_info);
+    let outputs: Vec<Value> = vec![
+        record_array(p_fault_counts, 1, |element| element.record()),
+        record_array(p_fault_info, 1, |element| element.record()),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_device_group_peer_memory_features(
+    device: vk::Device,
+    heap_index: u32,
+    local_device_index: u32,
+    remote_device_index: u32,
+    p_peer_memory_features: *mut vk::PeerMemoryFeatureFlags,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceGroupPeerMemoryFeatures>(next(
+        337usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            heap_index,
+            local_device_index,
+            remote_device_index,
+            p_peer_memory_features,
+        );
+    }
+    let args = vec![
+        device.record(),
+        heap_index.record(),
+        local_device_index.record(),
+        remote_device_index.record(),
+        record_output(p_peer_memory_features, 1),
+    ];
+    next(
+        device,
+        heap_index,
+        local_device_index,
+        remote_device_index,
+        p_peer_memory_features,
+    );
+    push_call(
+        "vkGetDeviceGroupPeerMemoryFeatures",
+        args,
+        vec![record_array(p_peer_memory_features, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_device_group_peer_memory_features(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDeviceGroupPeerMemoryFeatures>(
+        f,
+    );
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let heap_index: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let local_device_index: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let remote_device_index: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_peer_memory_features: *mut vk::PeerMemoryFeatureFlags =
+        replay_array(&args[4usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        device,
+        heap_index,
+        local_device_index,
+        remote_device_index,
+        p_peer_memory_features,
+    );
+    let outputs: Vec<Value> = vec![record_array(p_peer_memory_features, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_device_group_present_capabilities_khr(
+    device: vk::Device,
+    p_device_group_present_capabilities: *mut vk::DeviceGroupPresentCapabilitiesKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceGroupPresentCapabilitiesKHR>(next(
+        338usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_device_group_present_capabilities);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_device_group_present_capabilities, 1, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, p_device_group_present_capabilities);
+    push_call(
+        "vkGetDeviceGroupPresentCapabilitiesKHR",
+        args,
+        vec![record_array(
+            p_device_group_present_capabilities,
+            1,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_device_group_present_capabilities_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDeviceGroupPresentCapabilitiesKHR,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_device_group_present_capabilities: *mut vk::DeviceGroupPresentCapabilitiesKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_device_group_present_capabilities);
+    let outputs: Vec<Value> = vec![record_array(
+        p_device_group_present_capabilities,
+        1,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_device_group_surface_present_modes2_ext(
+    device: vk::Device,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_modes: *mut vk::DeviceGroupPresentModeFlagsKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceGroupSurfacePresentModes2EXT>(
+        next(339usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_surface_info, p_modes);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_surface_info, 1, |element| element.record()),
+        record_output(p_modes, 1),
+    ];
+    let result = next(device, p_surface_info, p_modes);
+    push_call(
+        "vkGetDeviceGroupSurfacePresentModes2EXT",
+        args,
+        vec![record_array(p_modes, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_device_group_surface_present_modes2_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDeviceGroupSurfacePresentModes2EXT,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_modes: *mut vk::DeviceGroupPresentModeFlagsKHR =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_surface_info, p_modes);
+    let outputs: Vec<Value> = vec![record_array(p_modes, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_device_group_surface_present_modes_khr(
+    device: vk::Device,
+    surface: vk::SurfaceKHR,
+    p_modes: *mut vk::DeviceGroupPresentModeFlagsKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceGroupSurfacePresentModesKHR>(next(
+        340usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, surface, p_modes);
+    }
+    let args = vec![device.record(), surface.record(), record_output(p_modes, 1)];
+    let result = next(device, surface, p_modes);
+    push_call(
+        "vkGetDeviceGroupSurfacePresentModesKHR",
+        args,
+        vec![record_array(p_modes, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_device_group_surface_present_modes_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDeviceGroupSurfacePresentModesKHR,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let surface: vk::SurfaceKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_modes: *mut vk::DeviceGroupPresentModeFlagsKHR =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, surface, p_modes);
+    let outputs: Vec<Value> = vec![record_array(p_modes, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_device_image_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceImageMemoryRequirements<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceImageMemoryRequirements>(next(
+        341usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_info, p_memory_requirements);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        record_array(p_memory_requirements, 1, |element| element.record()),
+    ];
+    next(device, p_info, p_memory_requirements);
+    push_call(
+        "vkGetDeviceImageMemoryRequirements",
+        args,
+        vec![record_array(p_memory_requirements, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_device_image_memory_requirements(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDeviceImageMemoryRequirements>(
+        f,
+    );
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::DeviceImageMemoryRequirements<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_memory_requirements: *mut vk::MemoryRequirements2<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_info, p_memory_requirements);
+    let outputs: Vec<Value> = vec![record_array(p_memory_requirements, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_device_image_sparse_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceImageMemoryRequirements<'_>,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceImageSparseMemoryRequirements>(
+        next(342usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(
+            device,
+            p_info,
+            p_sparse_memory_requirement_count,
+            p_sparse_memory_requirements,
+        );
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        record_array(p_sparse_memory_requirement_count, 1, |element| {
+            element.record()
+        }),
+        record_array(
+            p_sparse_memory_requirements,
+            if p_sparse_memory_requirement_count.is_null() {
+                0
+            } else {
+                *p_sparse_memory_requirement_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    next(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    push_call(
+        "vkGetDeviceImageSparseMemoryRequirements",
+        args,
+        vec![
+            record_array(p_sparse_memory_requirement_count, 1, |element| {
+                element.record()
+            }),
+            record_array(
+                p_sparse_memory_requirements,
+                if p_sparse_memory_requirement_count.is_null() {
+                    0
+                } else {
+                    *p_sparse_memory_requirement_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_device_image_sparse_memory_requirements(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDeviceImageSparseMemoryRequirements,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::DeviceImageMemoryRequirements<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_sparse_memory_requirement_count: *mut u32 =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements2<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(p_sparse_memory_requirement_count, 1, |element| {
+            element.record()
+        }),
+        record_array(
+            p_sparse_memory_requirements,
+            if p_sparse_memory_requirement_count.is_null() {
+                0
+            } else {
+                *p_sparse_memory_requirement_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_device_memory_commitment(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    p_committed_memory_in_bytes: *mut vk::DeviceSize,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceMemoryCommitment>(next(
+        343usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, memory, p_committed_memory_in_bytes);
+    }
+    let args = vec![
+        device.record(),
+        memory.record(),
+        record_output(p_committed_memory_in_bytes, 1),
+    ];
+    next(device, memory, p_committed_memory_in_bytes);
+    push_call(
+        "vkGetDeviceMemoryCommitment",
+        args,
+        vec![record_array(p_committed_memory_in_bytes, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_device_memory_commitment(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDeviceMemoryCommitment>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let memory: vk::DeviceMemory = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_committed_memory_in_bytes: *mut vk::DeviceSize =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, memory, p_committed_memory_in_bytes);
+    let outputs: Vec<Value> = vec![record_array(p_committed_memory_in_bytes, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_device_memory_opaque_capture_address(
+    device: vk::Device,
+    p_info: *const vk::DeviceMemoryOpaqueCaptureAddressInfo<'_>,
+) -> u64 {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceMemoryOpaqueCaptureAddress>(next(
+        344usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_info);
+    push_call(
+        "vkGetDeviceMemoryOpaqueCaptureAddress",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_device_memory_opaque_capture_address(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDeviceMemoryOpaqueCaptureAddress,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::DeviceMemoryOpaqueCaptureAddressInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_device_micromap_compatibility_ext(
+    device: vk::Device,
+    p_version_info: *const vk::MicromapVersionInfoEXT<'_>,
+    p_compatibility: *mut vk::AccelerationStructureCompatibilityKHR,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceMicromapCompatibilityEXT>(next(
+        345usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_version_info, p_compatibility);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_version_info, 1, |element| element.record()),
+        record_output(p_compatibility, 1),
+    ];
+    next(device, p_version_info, p_compatibility);
+    push_call(
+        "vkGetDeviceMicromapCompatibilityEXT",
+        args,
+        vec![record_array(p_compatibility, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_device_micromap_compatibility_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDeviceMicromapCompatibilityEXT,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_version_info: *const vk::MicromapVersionInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_compatibility: *mut vk::AccelerationStructureCompatibilityKHR =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_version_info, p_compatibility);
+    let outputs: Vec<Value> = vec![record_array(p_compatibility, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_device_queue(
+    device: vk::Device,
+    queue_family_index: u32,
+    queue_index: u32,
+    p_queue: *mut vk::Queue,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceQueue>(next(
+        346usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, queue_family_index, queue_index, p_queue);
+    }
+    let args = vec![
+        device.record(),
+        queue_family_index.record(),
+        queue_index.record(),
+        record_output(p_queue, 1),
+    ];
+    next(device, queue_family_index, queue_index, p_queue);
+    push_call(
+        "vkGetDeviceQueue",
+        args,
+        vec![record_array(p_queue, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_device_queue(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDeviceQueue>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let queue_family_index: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let queue_index: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_queue: *mut vk::Queue = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(device, queue_family_index, queue_index, p_queue);
+    let outputs: Vec<Value> = vec![record_array(p_queue, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_device_queue2(
+    device: vk::Device,
+    p_queue_info: *const vk::DeviceQueueInfo2<'_>,
+    p_queue: *mut vk::Queue,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceQueue2>(next(
+        347usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_queue_info, p_queue);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_queue_info, 1, |element| element.record()),
+        record_output(p_queue, 1),
+    ];
+    next(device, p_queue_info, p_queue);
+    push_call(
+        "vkGetDeviceQueue2",
+        args,
+        vec![record_array(p_queue, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_device_queue2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetDeviceQueue2>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_queue_info: *const vk::DeviceQueueInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_queue: *mut vk::Queue = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(device, p_queue_info, p_queue);
+    let outputs: Vec<Value> = vec![record_array(p_queue, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_device_subpass_shading_max_workgroup_size_huawei(
+    device: vk::Device,
+    renderpass: vk::RenderPass,
+    p_max_workgroup_size: *mut vk::Extent2D,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI>(
+        next(348usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, renderpass, p_max_workgroup_size);
+    }
+    let args = vec![
+        device.record(),
+        renderpass.record(),
+        record_output(p_max_workgroup_size, 1),
+    ];
+    let result = next(device, renderpass, p_max_workgroup_size);
+    push_call(
+        "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI",
+        args,
+        vec![record_array(p_max_workgroup_size, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_device_subpass_shading_max_workgroup_size_huawei(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let renderpass: vk::RenderPass = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_max_workgroup_size: *mut vk::Extent2D =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, renderpass, p_max_workgroup_size);
+    let outputs: Vec<Value> = vec![record_array(p_max_workgroup_size, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_dynamic_rendering_tile_properties_qcom(
+    device: vk::Device,
+    p_rendering_info: *const vk::RenderingInfo<'_>,
+    p_properties: *mut vk::TilePropertiesQCOM<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDynamicRenderingTilePropertiesQCOM>(
+        next(349usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_rendering_info, p_properties);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_rendering_info, 1, |element| element.record()),
+        record_array(p_properties, 1, |element| element.record()),
+    ];
+    let result = next(device, p_rendering_info, p_properties);
+    push_call(
+        "vkGetDynamicRenderingTilePropertiesQCOM",
+        args,
+        vec![record_array(p_properties, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_dynamic_rendering_tile_properties_qcom(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetDynamicRenderingTilePropertiesQCOM,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_rendering_info: *const vk::RenderingInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_properties: *mut vk::TilePropertiesQCOM<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_rendering_info, p_properties);
+    let outputs: Vec<Value> = vec![record_array(p_properties, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_encoded_video_session_parameters_khr(
+    device: vk::Device,
+    p_video_session_parameters_info: *const vk::VideoEncodeSessionParametersGetInfoKHR<'_>,
+    p_feedback_info: *mut vk::VideoEncodeSessionParametersFeedbackInfoKHR<'_>,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetEncodedVideoSessionParametersKHR>(next(
+        350usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            p_video_session_parameters_info,
+            p_feedback_info,
+            p_data_size,
+            p_data,
+        );
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_video_session_parameters_info, 1, |element| {
+            element.record()
+        }),
+        record_array(p_feedback_info, 1, |element| element.record()),
+        record_array(p_data_size, 1, |element| element.record()),
+        record_data_output(
+            p_data.cast::<u8>(),
+            if p_data_size.is_null() {
+                0
+            } else {
+                *p_data_size
+            },
+        ),
+    ];
+    let result = next(
+        device,
+        p_video_session_parameters_info,
+        p_feedback_info,
+        p_data_size,
+        p_data,
+    );
+    push_call(
+        "vkGetEncodedVideoSessionParametersKHR",
+        args,
+        vec![
+            record_array(p_feedback_info, 1, |element| element.record()),
+            record_array(p_data_size, 1, |element| element.record()),
+            record_data(
+                p_data.cast::<u8>(),
+                if p_data_size.is_null() {
+                    0
+                } else {
+                    *p_data_size
+                },
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_encoded_video_session_parameters_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetEncodedVideoSessionParametersKHR,
+    >(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_video_session_parameters_info: *const vk::VideoEncodeSessionParametersGetInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_feedback_info: *mut vk::VideoEncodeSessionParametersFeedbackInfoKHR<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_data_size: *mut usize = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_data: *mut c_void = replay_data::<u8>(&args[4usize], arena)?.cast::<c_void>();
+    let result = f(
+        device,
+        p_video_session_parameters_info,
+        p_feedback_info,
+        p_data_size,
+        p_data,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(p_feedback_info, 1, |element| element.record()),
+        record_array(p_data_size, 1, |element| element.record()),
+        record_data(
+            p_data.cast::<u8>(),
+            if p_data_size.is_null() {
+                0
+            } else {
+                *p_data_size
+            },
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_event_status(device: vk::Device, event: vk::Event) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetEventStatus>(next(
+        351usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, event);
+    }
+    let args = vec![device.record(), event.record()];
+    let result = next(device, event);
+    push_call("vkGetEventStatus", args, vec![], result.record());
+    result
+}
+unsafe fn replay_get_event_status(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetEventStatus>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let event: vk::Event = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, event);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_fence_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::FenceGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetFenceFdKHR>(next(
+        352usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_get_fd_info, p_fd);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_get_fd_info, 1, |element| element.record()),
+        record_output(p_fd, 1),
+    ];
+    let result = next(device, p_get_fd_info, p_fd);
+    push_call(
+        "vkGetFenceFdKHR",
+        args,
+        vec![record_array(p_fd, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_fence_fd_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetFenceFdKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_get_fd_info: *const vk::FenceGetFdInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_fd: *mut c_int = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_get_fd_info, p_fd);
+    let outputs: Vec<Value> = vec![record_array(p_fd, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_fence_status(device: vk::Device, fence: vk::Fence) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetFenceStatus>(next(
+        353usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, fence);
+    }
+    let args = vec![device.record(), fence.record()];
+    let result = next(device, fence);
+    push_call("vkGetFenceStatus", args, vec![], result.record());
+    result
+}
+unsafe fn replay_get_fence_status(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetFenceStatus>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let fence: vk::Fence = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, fence);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_fence_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::FenceGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetFenceWin32HandleKHR>(next(
+        354usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_get_win32_handle_info, p_handle);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_get_win32_handle_info, 1, |element| element.record()),
+        record_output(p_handle, 1),
+    ];
+    let result = next(device, p_get_win32_handle_info, p_handle);
+    push_call(
+        "vkGetFenceWin32HandleKHR",
+        args,
+        vec![record_array(p_handle, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_fence_win32_handle_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetFenceWin32HandleKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_get_win32_handle_info: *const vk::FenceGetWin32HandleInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_handle: *mut vk::HANDLE = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_get_win32_handle_info, p_handle);
+    let outputs: Vec<Value> = vec![record_array(p_handle, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_framebuffer_tile_properties_qcom(
+    device: vk::Device,
+    framebuffer: vk::Framebuffer,
+    p_properties_count: *mut u32,
+    p_properties: *mut vk::TilePropertiesQCOM<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetFramebufferTilePropertiesQCOM>(next(
+        355usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, framebuffer, p_properties_count, p_properties);
+    }
+    let args = vec![
+        device.record(),
+        framebuffer.record(),
+        record_array(p_properties_count, 1, |element| element.record()),
+        record_array(
+            p_properties,
+            if p_properties_count.is_null() {
+                0
+            } else {
+                *p_properties_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    let result = next(device, framebuffer, p_properties_count, p_properties);
+    push_call(
+        "vkGetFramebufferTilePropertiesQCOM",
+        args,
+        vec![
+            record_array(p_properties_count, 1, |element| element.record()),
+            record_array(
+                p_properties,
+                if p_properties_count.is_null() {
+                    0
+                } else {
+                    *p_properties_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_framebuffer_tile_properties_qcom(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetFramebufferTilePropertiesQCOM>(
+        f,
+    );
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let framebuffer: vk::Framebuffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_properties_count: *mut u32 = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_properties: *mut vk::TilePropertiesQCOM<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, framebuffer, p_properties_count, p_properties);
+    let outputs: Vec<Value> = vec![
+        record_array(p_properties_count, 1, |element| element.record()),
+        record_array(
+            p_properties,
+            if p_properties_count.is_null() {
+                0
+            } else {
+                *p_properties_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_generated_commands_memory_requirements_nv(
+    device: vk::Device,
+    p_info: *const vk::GeneratedCommandsMemoryRequirementsInfoNV<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetGeneratedCommandsMemoryRequirementsNV>(
+        next(356usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_info, p_memory_requirements);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        record_array(p_memory_requirements, 1, |element| element.record()),
+    ];
+    next(device, p_info, p_memory_requirements);
+    push_call(
+        "vkGetGeneratedCommandsMemoryRequirementsNV",
+        args,
+        vec![record_array(p_memory_requirements, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_generated_commands_memory_requirements_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetGeneratedCommandsMemoryRequirementsNV,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::GeneratedCommandsMemoryRequirementsInfoNV<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_memory_requirements: *mut vk::MemoryRequirements2<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_info, p_memory_requirements);
+    let outputs: Vec<Value> = vec![record_array(p_memory_requirements, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_image_drm_format_modifier_properties_ext(
+    device: vk::Device,
+    image: vk::Image,
+    p_properties: *mut vk::ImageDrmFormatModifierPropertiesEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageDrmFormatModifierPropertiesEXT>(
+        next(357usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, image, p_properties);
+    }
+    let args = vec![
+        device.record(),
+        image.record(),
+        record_array(p_properties, 1, |element| element.record()),
+    ];
+    let result = next(device, image, p_properties);
+    push_call(
+        "vkGetImageDrmFormatModifierPropertiesEXT",
+        args,
+        vec![record_array(p_properties, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_image_drm_format_modifier_properties_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetImageDrmFormatModifierPropertiesEXT,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_properties: *mut vk::ImageDrmFormatModifierPropertiesEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, image, p_properties);
+    let outputs: Vec<Value> = vec![record_array(p_properties, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_image_memory_requirements(
+    device: vk::Device,
+    image: vk::Image,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageMemoryRequirements>(next(
+        358usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, image, p_memory_requirements);
+    }
+    let args = vec![
+        device.record(),
+        image.record(),
+        record_output(p_memory_requirements, 1),
+    ];
+    next(device, image, p_memory_requirements);
+    push_call(
+        "vkGetImageMemoryRequirements",
+        args,
+        vec![record_array(p_memory_requirements, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_image_memory_requirements(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetImageMemoryRequirements>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_memory_requirements: *mut vk::MemoryRequirements =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, image, p_memory_requirements);
+    let outputs: Vec<Value> = vec![record_array(p_memory_requirements, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_image_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::ImageMemoryRequirementsInfo2<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageMemoryRequirements2>(next(
+        359usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_info, p_memory_requirements);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        record_array(p_memory_requirements, 1, |element| element.record()),
+    ];
+    next(device, p_info, p_memory_requirements);
+    push_call(
+        "vkGetImageMemoryRequirements2",
+        args,
+        vec![record_array(p_memory_requirements, 1, |element| {
+            element.record()
+        })],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_image_memory_requirements2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetImageMemoryRequirements2>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::ImageMemoryRequirementsInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_memory_requirements: *mut vk::MemoryRequirements2<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_info, p_memory_requirements);
+    let outputs: Vec<Value> = vec![record_array(p_memory_requirements, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_image_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::ImageCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageOpaqueCaptureDescriptorDataEXT>(
+        next(360usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_info, p_data);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        Value::Null,
+    ];
+    let result = next(device, p_info, p_data);
+    push_call(
+        "vkGetImageOpaqueCaptureDescriptorDataEXT",
+        args,
+        vec![Value::Null],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_image_opaque_capture_descriptor_data_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetImageOpaqueCaptureDescriptorDataEXT,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::ImageCaptureDescriptorDataInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_data: *mut c_void = ptr::null_mut();
+    let result = f(device, p_info, p_data);
+    let outputs: Vec<Value> = vec![Value::Null];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_image_sparse_memory_requirements(
+    device: vk::Device,
+    image: vk::Image,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageSparseMemoryRequirements>(next(
+        361usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            image,
+            p_sparse_memory_requirement_count,
+            p_sparse_memory_requirements,
+        );
+    }
+    let args = vec![
+        device.record(),
+        image.record(),
+        record_array(p_sparse_memory_requirement_count, 1, |element| {
+            element.record()
+        }),
+        record_output(
+            p_sparse_memory_requirements,
+            if p_sparse_memory_requirement_count.is_null() {
+                0
+            } else {
+                *p_sparse_memory_requirement_count as usize
+            },
+        ),
+    ];
+    next(
+        device,
+        image,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    push_call(
+        "vkGetImageSparseMemoryRequirements",
+        args,
+        vec![
+            record_array(p_sparse_memory_requirement_count, 1, |element| {
+                element.record()
+            }),
+            record_array(
+                p_sparse_memory_requirements,
+                if p_sparse_memory_requirement_count.is_null() {
+                    0
+                } else {
+                    *p_sparse_memory_requirement_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_image_sparse_memory_requirements(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetImageSparseMemoryRequirements>(
+        f,
+    );
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_sparse_memory_requirement_count: *mut u32 =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        device,
+        image,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(p_sparse_memory_requirement_count, 1, |element| {
+            element.record()
+        }),
+        record_array(
+            p_sparse_memory_requirements,
+            if p_sparse_memory_requirement_count.is_null() {
+                0
+            } else {
+                *p_sparse_memory_requirement_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_image_sparse_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::ImageSparseMemoryRequirementsInfo2<'_>,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements2<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageSparseMemoryRequirements2>(next(
+        362usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            p_info,
+            p_sparse_memory_requirement_count,
+            p_sparse_memory_requirements,
+        );
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        record_array(p_sparse_memory_requirement_count, 1, |element| {
+            element.record()
+        }),
+        record_array(
+            p_sparse_memory_requirements,
+            if p_sparse_memory_requirement_count.is_null() {
+                0
+            } else {
+                *p_sparse_memory_requirement_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    next(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    push_call(
+        "vkGetImageSparseMemoryRequirements2",
+        args,
+        vec![
+            record_array(p_sparse_memory_requirement_count, 1, |element| {
+                element.record()
+            }),
+            record_array(
+                p_sparse_memory_requirements,
+                if p_sparse_memory_requirement_count.is_null() {
+                    0
+                } else {
+                    *p_sparse_memory_requirement_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_image_sparse_memory_requirements2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetImageSparseMemoryRequirements2,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::ImageSparseMemoryRequirementsInfo2<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_sparse_memory_requirement_count: *mut u32 =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements2<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(p_sparse_memory_requirement_count, 1, |element| {
+            element.record()
+        }),
+        record_array(
+            p_sparse_memory_requirements,
+            if p_sparse_memory_requirement_count.is_null() {
+                0
+            } else {
+                *p_sparse_memory_requirement_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_image_subresource_layout(
+    device: vk::Device,
+    image: vk::Image,
+    p_subresource: *const vk::ImageSubresource,
+    p_layout: *mut vk::SubresourceLayout,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageSubresourceLayout>(next(
+        363usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, image, p_subresource, p_layout);
+    }
+    let args = vec![
+        device.record(),
+        image.record(),
+        record_array(p_subresource, 1, |element| element.record()),
+        record_output(p_layout, 1),
+    ];
+    next(device, image, p_subresource, p_layout);
+    push_call(
+        "vkGetImageSubresourceLayout",
+        args,
+        vec![record_array(p_layout, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_image_subresource_layout(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetImageSubresourceLayout>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_subresource: *const vk::ImageSubresource =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_layout: *mut vk::SubresourceLayout =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, image, p_subresource, p_layout);
+    let outputs: Vec<Value> = vec![record_array(p_layout, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_image_subresource_layout2_ext(
+    device: vk::Device,
+    image: vk::Image,
+    p_subresource: *const vk::ImageSubresource2EXT<'_>,
+    p_layout: *mut vk::SubresourceLayout2EXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageSubresourceLayout2EXT>(next(
+        364usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, image, p_subresource, p_layout);
+    }
+    let args = vec![
+        device.record(),
+        image.record(),
+        record_array(p_subresource, 1, |element| element.record()),
+        record_array(p_layout, 1, |element| element.record()),
+    ];
+    next(device, image, p_subresource, p_layout);
+    push_call(
+        "vkGetImageSubresourceLayout2EXT",
+        args,
+        vec![record_array(p_layout, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_image_subresource_layout2_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetImageSubresourceLayout2EXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let image: vk::Image = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_subresource: *const vk::ImageSubresource2EXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_layout: *mut vk::SubresourceLayout2EXT<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, image, p_subresource, p_layout);
+    let outputs: Vec<Value> = vec![record_array(p_layout, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_image_view_address_nvx(
+    device: vk::Device,
+    image_view: vk::ImageView,
+    p_properties: *mut vk::ImageViewAddressPropertiesNVX<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageViewAddressNVX>(next(
+        365usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, image_view, p_properties);
+    }
+    let args = vec![
+        device.record(),
+        image_view.record(),
+        record_array(p_properties, 1, |element| element.record()),
+    ];
+    let result = next(device, image_view, p_properties);
+    push_call(
+        "vkGetImageViewAddressNVX",
+        args,
+        vec![record_array(p_properties, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_image_view_address_nvx(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetImageViewAddressNVX>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let image_view: vk::ImageView = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_properties: *mut vk::ImageViewAddressPropertiesNVX<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, image_view, p_properties);
+    let outputs: Vec<Value> = vec![record_array(p_properties, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_image_view_handle_nvx(
+    device: vk::Device,
+    p_info: *const vk::ImageViewHandleInfoNVX<'_>,
+) -> u32 {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageViewHandleNVX>(next(
+        366usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_info);
+    push_call("vkGetImageViewHandleNVX", args, vec![], result.record());
+    result
+}
+unsafe fn replay_get_image_view_handle_nvx(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetImageViewHandleNVX>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::ImageViewHandleInfoNVX<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_image_view_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::ImageViewCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageViewOpaqueCaptureDescriptorDataEXT>(
+        next(367usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_info, p_data);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        Value::Null,
+    ];
+    let result = next(device, p_info, p_data);
+    push_call(
+        "vkGetImageViewOpaqueCaptureDescriptorDataEXT",
+        args,
+        vec![Value::Null],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_image_view_opaque_capture_descriptor_data_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetImageViewOpaqueCaptureDescriptorDataEXT,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::ImageViewCaptureDescriptorDataInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_data: *mut c_void = ptr::null_mut();
+    let result = f(device, p_info, p_data);
+    let outputs: Vec<Value> = vec![Value::Null];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_memory_android_hardware_buffer_android(
+    device: vk::Device,
+    p_info: *const vk::MemoryGetAndroidHardwareBufferInfoANDROID<'_>,
+    p_buffer: *mut *mut vk::AHardwareBuffer,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryAndroidHardwareBufferANDROID>(
+        next(368usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_info, p_buffer);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        record_output(p_buffer, 1),
+    ];
+    let result = next(device, p_info, p_buffer);
+    push_call(
+        "vkGetMemoryAndroidHardwareBufferANDROID",
+        args,
+        vec![record_array(p_buffer, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_memory_android_hardware_buffer_android(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetMemoryAndroidHardwareBufferANDROID,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::MemoryGetAndroidHardwareBufferInfoANDROID<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_buffer: *mut *mut vk::AHardwareBuffer =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_info, p_buffer);
+    let outputs: Vec<Value> = vec![record_array(p_buffer, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_memory_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::MemoryGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryFdKHR>(next(
+        369usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_get_fd_info, p_fd);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_get_fd_info, 1, |element| element.record()),
+        record_output(p_fd, 1),
+    ];
+    let result = next(device, p_get_fd_info, p_fd);
+    push_call(
+        "vkGetMemoryFdKHR",
+        args,
+        vec![record_array(p_fd, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_memory_fd_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetMemoryFdKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_get_fd_info: *const vk::MemoryGetFdInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_fd: *mut c_int = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_get_fd_info, p_fd);
+    let outputs: Vec<Value> = vec![record_array(p_fd, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_memory_fd_properties_khr(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    fd: c_int,
+    p_memory_fd_properties: *mut vk::MemoryFdPropertiesKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryFdPropertiesKHR>(next(
+        370usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, handle_type, fd, p_memory_fd_properties);
+    }
+    let args = vec![
+        device.record(),
+        handle_type.record(),
+        fd.record(),
+        record_array(p_memory_fd_properties, 1, |element| element.record()),
+    ];
+    let result = next(device, handle_type, fd, p_memory_fd_properties);
+    push_call(
+        "vkGetMemoryFdPropertiesKHR",
+        args,
+        vec![record_array(p_memory_fd_properties, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_memory_fd_properties_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetMemoryFdPropertiesKHR>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let handle_type: vk::ExternalMemoryHandleTypeFlags =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let fd: c_int = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_memory_fd_properties: *mut vk::MemoryFdPropertiesKHR<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, handle_type, fd, p_memory_fd_properties);
+    let outputs: Vec<Value> = vec![record_array(p_memory_fd_properties, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_memory_host_pointer_properties_ext(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    p_host_pointer: *const c_void,
+    p_memory_host_pointer_properties: *mut vk::MemoryHostPointerPropertiesEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryHostPointerPropertiesEXT>(next(
+        371usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            handle_type,
+            p_host_pointer,
+            p_memory_host_pointer_properties,
+        );
+    }
+    let args = vec![
+        device.record(),
+        handle_type.record(),
+        p_host_pointer.record(),
+        record_array(p_memory_host_pointer_properties, 1, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(
+        device,
+        handle_type,
+        p_host_pointer,
+        p_memory_host_pointer_properties,
+    );
+    push_call(
+        "vkGetMemoryHostPointerPropertiesEXT",
+        args,
+        vec![record_array(
+            p_memory_host_pointer_properties,
+            1,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_memory_host_pointer_properties_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetMemoryHostPointerPropertiesEXT,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let handle_type: vk::ExternalMemoryHandleTypeFlags =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let p_host_pointer: *const c_void = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_memory_host_pointer_properties: *mut vk::MemoryHostPointerPropertiesEXT<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        handle_type,
+        p_host_pointer,
+        p_memory_host_pointer_properties,
+    );
+    let outputs: Vec<Value> = vec![record_array(
+        p_memory_host_pointer_properties,
+        1,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_memory_remote_address_nv(
+    device: vk::Device,
+    p_memory_get_remote_address_info: *const vk::MemoryGetRemoteAddressInfoNV<'_>,
+    p_address: *mut vk::RemoteAddressNV,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryRemoteAddressNV>(next(
+        372usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_memory_get_remote_address_info, p_address);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_memory_get_remote_address_info, 1, |element| {
+            element.record()
+        }),
+        Value::Null,
+    ];
+    let result = next(device, p_memory_get_remote_address_info, p_address);
+    push_call(
+        "vkGetMemoryRemoteAddressNV",
+        args,
+        vec![Value::Null],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_memory_remote_address_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetMemoryRemoteAddressNV>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_memory_get_remote_address_info: *const vk::MemoryGetRemoteAddressInfoNV<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_address: *mut vk::RemoteAddressNV = ptr::null_mut();
+    let result = f(device, p_memory_get_remote_address_info, p_address);
+    let outputs: Vec<Value> = vec![Value::Null];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_memory_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::MemoryGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryWin32HandleKHR>(next(
+        373usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_get_win32_handle_info, p_handle);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_get_win32_handle_info, 1, |element| element.record()),
+        record_output(p_handle, 1),
+    ];
+    let result = next(device, p_get_win32_handle_info, p_handle);
+    push_call(
+        "vkGetMemoryWin32HandleKHR",
+        args,
+        vec![record_array(p_handle, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_memory_win32_handle_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetMemoryWin32HandleKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_get_win32_handle_info: *const vk::MemoryGetWin32HandleInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_handle: *mut vk::HANDLE = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_get_win32_handle_info, p_handle);
+    let outputs: Vec<Value> = vec![record_array(p_handle, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_memory_win32_handle_nv(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryWin32HandleNV>(next(
+        374usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, memory, handle_type, p_handle);
+    }
+    let args = vec![
+        device.record(),
+        memory.record(),
+        handle_type.record(),
+        record_output(p_handle, 1),
+    ];
+    let result = next(device, memory, handle_type, p_handle);
+    push_call(
+        "vkGetMemoryWin32HandleNV",
+        args,
+        vec![record_array(p_handle, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_memory_win32_handle_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetMemoryWin32HandleNV>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let memory: vk::DeviceMemory = DeepCopyable::replay(&args[1usize], arena)?;
+    let handle_type: vk::ExternalMemoryHandleTypeFlagsNV =
+        DeepCopyable::replay(&args[2usize], arena)?;
+    let p_handle: *mut vk::HANDLE = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, memory, handle_type, p_handle);
+    let outputs: Vec<Value> = vec![record_array(p_handle, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_memory_win32_handle_properties_khr(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    handle: vk::HANDLE,
+    p_memory_win32_handle_properties: *mut vk::MemoryWin32HandlePropertiesKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryWin32HandlePropertiesKHR>(next(
+        375usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            handle_type,
+            handle,
+            p_memory_win32_handle_properties,
+        );
+    }
+    let args = vec![
+        device.record(),
+        handle_type.record(),
+        handle.record(),
+        record_array(p_memory_win32_handle_properties, 1, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(
+        device,
+        handle_type,
+        handle,
+        p_memory_win32_handle_properties,
+    );
+    push_call(
+        "vkGetMemoryWin32HandlePropertiesKHR",
+        args,
+        vec![record_array(
+            p_memory_win32_handle_properties,
+            1,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_memory_win32_handle_properties_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetMemoryWin32HandlePropertiesKHR,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let handle_type: vk::ExternalMemoryHandleTypeFlags =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let handle: vk::HANDLE = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_memory_win32_handle_properties: *mut vk::MemoryWin32HandlePropertiesKHR<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        handle_type,
+        handle,
+        p_memory_win32_handle_properties,
+    );
+    let outputs: Vec<Value> = vec![record_array(
+        p_memory_win32_handle_properties,
+        1,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_memory_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_get_zircon_handle_info: *const vk::MemoryGetZirconHandleInfoFUCHSIA<'_>,
+    p_zircon_handle: *mut vk::zx_handle_t,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryZirconHandleFUCHSIA>(next(
+        376usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_get_zircon_handle_info, p_zircon_handle);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_get_zircon_handle_info, 1, |element| element.record()),
+        record_output(p_zircon_handle, 1),
+    ];
+    let result = next(device, p_get_zircon_handle_info, p_zircon_handle);
+    push_call(
+        "vkGetMemoryZirconHandleFUCHSIA",
+        args,
+        vec![record_array(p_zircon_handle, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_memory_zircon_handle_fuchsia(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetMemoryZirconHandleFUCHSIA>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_get_zircon_handle_info: *const vk::MemoryGetZirconHandleInfoFUCHSIA<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_zircon_handle: *mut vk::zx_handle_t =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_get_zircon_handle_info, p_zircon_handle);
+    let outputs: Vec<Value> = vec![record_array(p_zircon_handle, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_memory_zircon_handle_properties_fuchsia(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    zircon_handle: vk::zx_handle_t,
+    p_memory_zircon_handle_properties: *mut vk::MemoryZirconHandlePropertiesFUCHSIA<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryZirconHandlePropertiesFUCHSIA>(
+        next(377usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(
+            device,
+            handle_type,
+            zircon_handle,
+            p_memory_zircon_handle_properties,
+        );
+    }
+    let args = vec![
+        device.record(),
+        handle_type.record(),
+        zircon_handle.record(),
+        record_array(p_memory_zircon_handle_properties, 1, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(
+        device,
+        handle_type,
+        zircon_handle,
+        p_memory_zircon_handle_properties,
+    );
+    push_call(
+        "vkGetMemoryZirconHandlePropertiesFUCHSIA",
+        args,
+        vec![record_array(
+            p_memory_zircon_handle_properties,
+            1,
+            |element| element.record(),
+        )],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_memory_zircon_handle_properties_fuchsia(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetMemoryZirconHandlePropertiesFUCHSIA,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let handle_type: vk::ExternalMemoryHandleTypeFlags =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let zircon_handle: vk::zx_handle_t = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_memory_zircon_handle_properties: *mut vk::MemoryZirconHandlePropertiesFUCHSIA<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        handle_type,
+        zircon_handle,
+        p_memory_zircon_handle_properties,
+    );
+    let outputs: Vec<Value> = vec![record_array(
+        p_memory_zircon_handle_properties,
+        1,
+        |element| element.record(),
+    )];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_micromap_build_sizes_ext(
+    device: vk::Device,
+    build_type: vk::AccelerationStructureBuildTypeKHR,
+    p_build_info: *const vk::MicromapBuildInfoEXT<'_>,
+    p_size_info: *mut vk::MicromapBuildSizesInfoEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMicromapBuildSizesEXT>(next(
+        378usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, build_type, p_build_info, p_size_info);
+    }
+    let args = vec![
+        device.record(),
+        build_type.record(),
+        record_array(p_build_info, 1, |element| element.record()),
+        record_array(p_size_info, 1, |element| element.record()),
+    ];
+    next(device, build_type, p_build_info, p_size_info);
+    push_call(
+        "vkGetMicromapBuildSizesEXT",
+        args,
+        vec![record_array(p_size_info, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_micromap_build_sizes_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetMicromapBuildSizesEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let build_type: vk::AccelerationStructureBuildTypeKHR =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let p_build_info: *const vk::MicromapBuildInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_size_info: *mut vk::MicromapBuildSizesInfoEXT<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, build_type, p_build_info, p_size_info);
+    let outputs: Vec<Value> = vec![record_array(p_size_info, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_past_presentation_timing_google(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_presentation_timing_count: *mut u32,
+    p_presentation_timings: *mut vk::PastPresentationTimingGOOGLE,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPastPresentationTimingGOOGLE>(next(
+        379usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            swapchain,
+            p_presentation_timing_count,
+            p_presentation_timings,
+        );
+    }
+    let args = vec![
+        device.record(),
+        swapchain.record(),
+        record_array(p_presentation_timing_count, 1, |element| element.record()),
+        record_output(
+            p_presentation_timings,
+            if p_presentation_timing_count.is_null() {
+                0
+            } else {
+                *p_presentation_timing_count as usize
+            },
+        ),
+    ];
+    let result = next(
+        device,
+        swapchain,
+        p_presentation_timing_count,
+        p_presentation_timings,
+    );
+    push_call(
+        "vkGetPastPresentationTimingGOOGLE",
+        args,
+        vec![
+            record_array(p_presentation_timing_count, 1, |element| element.record()),
+            record_array(
+                p_presentation_timings,
+                if p_presentation_timing_count.is_null() {
+                    0
+                } else {
+                    *p_presentation_timing_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_past_presentation_timing_google(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetPastPresentationTimingGOOGLE>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_presentation_timing_count: *mut u32 =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_presentation_timings: *mut vk::PastPresentationTimingGOOGLE =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        swapchain,
+        p_presentation_timing_count,
+        p_presentation_timings,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(p_presentation_timing_count, 1, |element| element.record()),
+        record_array(
+            p_presentation_timings,
+            if p_presentation_timing_count.is_null() {
+                0
+            } else {
+                *p_presentation_timing_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_performance_parameter_intel(
+    device: vk::Device,
+    parameter: vk::PerformanceParameterTypeINTEL,
+    p_value: *mut vk::PerformanceValueINTEL,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPerformanceParameterINTEL>(next(
+        380usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, parameter, p_value);
+    }
+    let args = vec![
+        device.record(),
+        parameter.record(),
+        record_output(p_value, 1),
+    ];
+    let result = next(device, parameter, p_value);
+    push_call(
+        "vkGetPerformanceParameterINTEL",
+        args,
+        vec![record_array(p_value, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_performance_parameter_intel(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetPerformanceParameterINTEL>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let parameter: vk::PerformanceParameterTypeINTEL = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_value: *mut vk::PerformanceValueINTEL =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, parameter, p_value);
+    let outputs: Vec<Value> = vec![record_array(p_value, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_pipeline_cache_data(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPipelineCacheData>(next(
+        381usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, pipeline_cache, p_data_size, p_data);
+    }
+    let args = vec![
+        device.record(),
+        pipeline_cache.record(),
+        record_array(p_data_size, 1, |element| element.record()),
+        record_data_output(
+            p_data.cast::<u8>(),
+            if p_data_size.is_null() {
+                0
+            } else {
+                *p_data_size
+            },
+        ),
+    ];
+    let result = next(device, pipeline_cache, p_data_size, p_data);
+    push_call(
+        "vkGetPipelineCacheData",
+        args,
+        vec![
+            record_array(p_data_size, 1, |element| element.record()),
+            record_data(
+                p_data.cast::<u8>(),
+                if p_data_size.is_null() {
+                    0
+                } else {
+                    *p_data_size
+                },
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_pipeline_cache_data(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetPipelineCacheData>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline_cache: vk::PipelineCache = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_data_size: *mut usize = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_data: *mut c_void = replay_data::<u8>(&args[3usize], arena)?.cast::<c_void>();
+    let result = f(device, pipeline_cache, p_data_size, p_data);
+    let outputs: Vec<Value> = vec![
+        record_array(p_data_size, 1, |element| element.record()),
+        record_data(
+            p_data.cast::<u8>(),
+            if p_data_size.is_null() {
+                0
+            } else {
+                *p_data_size
+            },
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_pipeline_executable_internal_representations_khr(
+    device: vk::Device,
+    p_executable_info: *const vk::PipelineExecutableInfoKHR<'_>,
+    p_internal_representation_count: *mut u32,
+    p_internal_representations: *mut vk::PipelineExecutableInternalRepresentationKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetPipelineExecutableInternalRepresentationsKHR,
+    >(next(382usize, dispatch_key(device.as_raw())));
+    if !is_recording() {
+        return next(
+            device,
+            p_executable_info,
+            p_internal_representation_count,
+            p_internal_representations,
+        );
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_executable_info, 1, |element| element.record()),
+        record_array(p_internal_representation_count, 1, |element| {
+            element.record()
+        }),
+        record_array(
+            p_internal_representations,
+            if p_internal_representation_count.is_null() {
+                0
+            } else {
+                *p_internal_representation_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    let result = next(
+        device,
+        p_executable_info,
+        p_internal_representation_count,
+        p_internal_representations,
+    );
+    push_call(
+        "vkGetPipelineExecutableInternalRepresentationsKHR",
+        args,
+        vec![
+            record_array(p_internal_representation_count, 1, |element| {
+                element.record()
+            }),
+            record_array(
+                p_internal_representations,
+                if p_internal_representation_count.is_null() {
+                    0
+                } else {
+                    *p_internal_representation_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_pipeline_executable_internal_representations_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetPipelineExecutableInternalRepresentationsKHR,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_executable_info: *const vk::PipelineExecutableInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_internal_representation_count: *mut u32 =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_internal_representations: *mut vk::PipelineExecutableInternalRepresentationKHR<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        p_executable_info,
+        p_internal_representation_count,
+        p_internal_representations,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(p_internal_representation_count, 1, |element| {
+            element.record()
+        }),
+        record_array(
+            p_internal_representations,
+            if p_internal_representation_count.is_null() {
+                0
+            } else {
+                *p_internal_representation_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_pipeline_executable_properties_khr(
+    device: vk::Device,
+    p_pipeline_info: *const vk::PipelineInfoKHR<'_>,
+    p_executable_count: *mut u32,
+    p_properties: *mut vk::PipelineExecutablePropertiesKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPipelineExecutablePropertiesKHR>(next(
+        383usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_pipeline_info, p_executable_count, p_properties);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_pipeline_info, 1, |element| element.record()),
+        record_array(p_executable_count, 1, |element| element.record()),
+        record_array(
+            p_properties,
+            if p_executable_count.is_null() {
+                0
+            } else {
+                *p_executable_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    let result = next(device, p_pipeline_info, p_executable_count, p_properties);
+    push_call(
+        "vkGetPipelineExecutablePropertiesKHR",
+        args,
+        vec![
+            record_array(p_executable_count, 1, |element| element.record()),
+            record_array(
+                p_properties,
+                if p_executable_count.is_null() {
+                    0
+                } else {
+                    *p_executable_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_pipeline_executable_properties_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetPipelineExecutablePropertiesKHR,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_pipeline_info: *const vk::PipelineInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_executable_count: *mut u32 = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_properties: *mut vk::PipelineExecutablePropertiesKHR<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_pipeline_info, p_executable_count, p_properties);
+    let outputs: Vec<Value> = vec![
+        record_array(p_executable_count, 1, |element| element.record()),
+        record_array(
+            p_properties,
+            if p_executable_count.is_null() {
+                0
+            } else {
+                *p_executable_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_pipeline_executable_statistics_khr(
+    device: vk::Device,
+    p_executable_info: *const vk::PipelineExecutableInfoKHR<'_>,
+    p_statistic_count: *mut u32,
+    p_statistics: *mut vk::PipelineExecutableStatisticKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPipelineExecutableStatisticsKHR>(next(
+        384usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_executable_info, p_statistic_count, p_statistics);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_executable_info, 1, |element| element.record()),
+        record_array(p_statistic_count, 1, |element| element.record()),
+        record_array(
+            p_statistics,
+            if p_statistic_count.is_null() {
+                0
+            } else {
+                *p_statistic_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    let result = next(device, p_executable_info, p_statistic_count, p_statistics);
+    push_call(
+        "vkGetPipelineExecutableStatisticsKHR",
+        args,
+        vec![
+            record_array(p_statistic_count, 1, |element| element.record()),
+            record_array(
+                p_statistics,
+                if p_statistic_count.is_null() {
+                    0
+                } else {
+                    *p_statistic_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_pipeline_executable_statistics_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetPipelineExecutableStatisticsKHR,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_executable_info: *const vk::PipelineExecutableInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_statistic_count: *mut u32 = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_statistics: *mut vk::PipelineExecutableStatisticKHR<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_executable_info, p_statistic_count, p_statistics);
+    let outputs: Vec<Value> = vec![
+        record_array(p_statistic_count, 1, |element| element.record()),
+        record_array(
+            p_statistics,
+            if p_statistic_count.is_null() {
+                0
+            } else {
+                *p_statistic_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_pipeline_properties_ext(
+    device: vk::Device,
+    p_pipeline_info: *const vk::PipelineInfoEXT<'_>,
+    p_pipeline_properties: *mut vk::BaseOutStructure<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPipelinePropertiesEXT>(next(
+        385usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_pipeline_info, p_pipeline_properties);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_pipeline_info, 1, |element| element.record()),
+        record_array(p_pipeline_properties, 1, |element| element.record()),
+    ];
+    let result = next(device, p_pipeline_info, p_pipeline_properties);
+    push_call(
+        "vkGetPipelinePropertiesEXT",
+        args,
+        vec![record_array(p_pipeline_properties, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_pipeline_properties_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetPipelinePropertiesEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_pipeline_info: *const vk::PipelineInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_pipeline_properties: *mut vk::BaseOutStructure<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_pipeline_info, p_pipeline_properties);
+    let outputs: Vec<Value> = vec![record_array(p_pipeline_properties, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_private_data(
+    device: vk::Device,
+    object_type: vk::ObjectType,
+    object_handle: u64,
+    private_data_slot: vk::PrivateDataSlot,
+    p_data: *mut u64,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPrivateData>(next(
+        386usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            object_type,
+            object_handle,
+            private_data_slot,
+            p_data,
+        );
+    }
+    let args = vec![
+        device.record(),
+        object_type.record(),
+        object_handle.record(),
+        private_data_slot.record(),
+        record_output(p_data, 1),
+    ];
+    next(
+        device,
+        object_type,
+        object_handle,
+        private_data_slot,
+        p_data,
+    );
+    push_call(
+        "vkGetPrivateData",
+        args,
+        vec![record_array(p_data, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_private_data(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetPrivateData>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let object_type: vk::ObjectType = DeepCopyable::replay(&args[1usize], arena)?;
+    let object_handle: u64 = DeepCopyable::replay(&args[2usize], arena)?;
+    let private_data_slot: vk::PrivateDataSlot = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_data: *mut u64 = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(
+        device,
+        object_type,
+        object_handle,
+        private_data_slot,
+        p_data,
+    );
+    let outputs: Vec<Value> = vec![record_array(p_data, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_query_pool_results(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: vk::DeviceSize,
+    flags: vk::QueryResultFlags,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetQueryPoolResults>(next(
+        387usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            query_pool,
+            first_query,
+            query_count,
+            data_size,
+            p_data,
+            stride,
+            flags,
+        );
+    }
+    let args = vec![
+        device.record(),
+        query_pool.record(),
+        first_query.record(),
+        query_count.record(),
+        data_size.record(),
+        record_data_output(p_data.cast::<u8>(), data_size),
+        stride.record(),
+        flags.record(),
+    ];
+    let result = next(
+        device,
+        query_pool,
+        first_query,
+        query_count,
+        data_size,
+        p_data,
+        stride,
+        flags,
+    );
+    push_call(
+        "vkGetQueryPoolResults",
+        args,
+        vec![record_data(p_data.cast::<u8>(), data_size)],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_query_pool_results(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetQueryPoolResults>(f);
+    let args = args::<8usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let first_query: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let query_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let data_size: usize = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_data: *mut c_void = replay_data::<u8>(&args[5usize], arena)?.cast::<c_void>();
+    let stride: vk::DeviceSize = DeepCopyable::replay(&args[6usize], arena)?;
+    let flags: vk::QueryResultFlags = DeepCopyable::replay(&args[7usize], arena)?;
+    let result = f(
+        device,
+        query_pool,
+        first_query,
+        query_count,
+        data_size,
+        p_data,
+        stride,
+        flags,
+    );
+    let outputs: Vec<Value> = vec![record_data(p_data.cast::<u8>(), data_size)];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_queue_checkpoint_data2_nv(
+    queue: vk::Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut vk::CheckpointData2NV<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetQueueCheckpointData2NV>(next(
+        388usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(queue, p_checkpoint_data_count, p_checkpoint_data);
+    }
+    let args = vec![
+        queue.record(),
+        record_array(p_checkpoint_data_count, 1, |element| element.record()),
+        record_array(
+            p_checkpoint_data,
+            if p_checkpoint_data_count.is_null() {
+                0
+            } else {
+                *p_checkpoint_data_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    next(queue, p_checkpoint_data_count, p_checkpoint_data);
+    push_call(
+        "vkGetQueueCheckpointData2NV",
+        args,
+        vec![
+            record_array(p_checkpoint_data_count, 1, |element| element.record()),
+            record_array(
+                p_checkpoint_data,
+                if p_checkpoint_data_count.is_null() {
+                    0
+                } else {
+                    *p_checkpoint_data_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_queue_checkpoint_data2_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetQueueCheckpointData2NV>(f);
+    let args = args::<3usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_checkpoint_data_count: *mut u32 = replay_array(&args[1usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_checkpoint_data: *mut vk::CheckpointData2NV<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(queue, p_checkpoint_data_count, p_checkpoint_data);
+    let outputs: Vec<Value> = vec![
+        record_array(p_checkpoint_data_count, 1, |element| element.record()),
+        record_array(
+            p_checkpoint_data,
+            if p_checkpoint_data_count.is_null() {
+                0
+            } else {
+                *p_checkpoint_data_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_queue_checkpoint_data_nv(
+    queue: vk::Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut vk::CheckpointDataNV<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetQueueCheckpointDataNV>(next(
+        389usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(queue, p_checkpoint_data_count, p_checkpoint_data);
+    }
+    let args = vec![
+        queue.record(),
+        record_array(p_checkpoint_data_count, 1, |element| element.record()),
+        record_array(
+            p_checkpoint_data,
+            if p_checkpoint_data_count.is_null() {
+                0
+            } else {
+                *p_checkpoint_data_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    next(queue, p_checkpoint_data_count, p_checkpoint_data);
+    push_call(
+        "vkGetQueueCheckpointDataNV",
+        args,
+        vec![
+            record_array(p_checkpoint_data_count, 1, |element| element.record()),
+            record_array(
+                p_checkpoint_data,
+                if p_checkpoint_data_count.is_null() {
+                    0
+                } else {
+                    *p_checkpoint_data_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_queue_checkpoint_data_nv(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetQueueCheckpointDataNV>(f);
+    let args = args::<3usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_checkpoint_data_count: *mut u32 = replay_array(&args[1usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_checkpoint_data: *mut vk::CheckpointDataNV<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(queue, p_checkpoint_data_count, p_checkpoint_data);
+    let outputs: Vec<Value> = vec![
+        record_array(p_checkpoint_data_count, 1, |element| element.record()),
+        record_array(
+            p_checkpoint_data,
+            if p_checkpoint_data_count.is_null() {
+                0
+            } else {
+                *p_checkpoint_data_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_ray_tracing_capture_replay_shader_group_handles_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetRayTracingCaptureReplayShaderGroupHandlesKHR,
+    >(next(390usize, dispatch_key(device.as_raw())));
+    if !is_recording() {
+        return next(
+            device,
+            pipeline,
+            first_group,
+            group_count,
+            data_size,
+            p_data,
+        );
+    }
+    let args = vec![
+        device.record(),
+        pipeline.record(),
+        first_group.record(),
+        group_count.record(),
+        data_size.record(),
+        record_data_output(p_data.cast::<u8>(), data_size),
+    ];
+    let result = next(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    );
+    push_call(
+        "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+        args,
+        vec![record_data(p_data.cast::<u8>(), data_size)],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_ray_tracing_capture_replay_shader_group_handles_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetRayTracingCaptureReplayShaderGroupHandlesKHR,
+    >(f);
+    let args = args::<6usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline: vk::Pipeline = DeepCopyable::replay(&args[1usize], arena)?;
+    let first_group: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let group_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let data_size: usize = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_data: *mut c_void = replay_data::<u8>(&args[5usize], arena)?.cast::<c_void>();
+    let result = f(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    );
+    let outputs: Vec<Value> = vec![record_data(p_data.cast::<u8>(), data_size)];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_ray_tracing_shader_group_handles_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetRayTracingShaderGroupHandlesKHR>(next(
+        391usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            pipeline,
+            first_group,
+            group_count,
+            data_size,
+            p_data,
+        );
+    }
+    let args = vec![
+        device.record(),
+        pipeline.record(),
+        first_group.record(),
+        group_count.record(),
+        data_size.record(),
+        record_data_output(p_data.cast::<u8>(), data_size),
+    ];
+    let result = next(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    );
+    push_call(
+        "vkGetRayTracingShaderGroupHandlesKHR",
+        args,
+        vec![record_data(p_data.cast::<u8>(), data_size)],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_ray_tracing_shader_group_handles_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetRayTracingShaderGroupHandlesKHR,
+    >(f);
+    let args = args::<6usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline: vk::Pipeline = DeepCopyable::replay(&args[1usize], arena)?;
+    let first_group: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let group_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let data_size: usize = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_data: *mut c_void = replay_data::<u8>(&args[5usize], arena)?.cast::<c_void>();
+    let result = f(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    );
+    let outputs: Vec<Value> = vec![record_data(p_data.cast::<u8>(), data_size)];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_ray_tracing_shader_group_stack_size_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    group: u32,
+    group_shader: vk::ShaderGroupShaderKHR,
+) -> vk::DeviceSize {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetRayTracingShaderGroupStackSizeKHR>(next(
+        392usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, pipeline, group, group_shader);
+    }
+    let args = vec![
+        device.record(),
+        pipeline.record(),
+        group.record(),
+        group_shader.record(),
+    ];
+    let result = next(device, pipeline, group, group_shader);
+    push_call(
+        "vkGetRayTracingShaderGroupStackSizeKHR",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_ray_tracing_shader_group_stack_size_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetRayTracingShaderGroupStackSizeKHR,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline: vk::Pipeline = DeepCopyable::replay(&args[1usize], arena)?;
+    let group: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let group_shader: vk::ShaderGroupShaderKHR = DeepCopyable::replay(&args[3usize], arena)?;
+    let result = f(device, pipeline, group, group_shader);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_refresh_cycle_duration_google(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_display_timing_properties: *mut vk::RefreshCycleDurationGOOGLE,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetRefreshCycleDurationGOOGLE>(next(
+        393usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, swapchain, p_display_timing_properties);
+    }
+    let args = vec![
+        device.record(),
+        swapchain.record(),
+        record_output(p_display_timing_properties, 1),
+    ];
+    let result = next(device, swapchain, p_display_timing_properties);
+    push_call(
+        "vkGetRefreshCycleDurationGOOGLE",
+        args,
+        vec![record_array(p_display_timing_properties, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_refresh_cycle_duration_google(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetRefreshCycleDurationGOOGLE>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_display_timing_properties: *mut vk::RefreshCycleDurationGOOGLE =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, swapchain, p_display_timing_properties);
+    let outputs: Vec<Value> = vec![record_array(p_display_timing_properties, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_render_area_granularity(
+    device: vk::Device,
+    render_pass: vk::RenderPass,
+    p_granularity: *mut vk::Extent2D,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetRenderAreaGranularity>(next(
+        394usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, render_pass, p_granularity);
+    }
+    let args = vec![
+        device.record(),
+        render_pass.record(),
+        record_output(p_granularity, 1),
+    ];
+    next(device, render_pass, p_granularity);
+    push_call(
+        "vkGetRenderAreaGranularity",
+        args,
+        vec![record_array(p_granularity, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_render_area_granularity(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetRenderAreaGranularity>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let render_pass: vk::RenderPass = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_granularity: *mut vk::Extent2D = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    f(device, render_pass, p_granularity);
+    let outputs: Vec<Value> = vec![record_array(p_granularity, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_sampler_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::SamplerCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSamplerOpaqueCaptureDescriptorDataEXT>(
+        next(395usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_info, p_data);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_info, 1, |element| element.record()),
+        Value::Null,
+    ];
+    let result = next(device, p_info, p_data);
+    push_call(
+        "vkGetSamplerOpaqueCaptureDescriptorDataEXT",
+        args,
+        vec![Value::Null],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_sampler_opaque_capture_descriptor_data_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetSamplerOpaqueCaptureDescriptorDataEXT,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_info: *const vk::SamplerCaptureDescriptorDataInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_data: *mut c_void = ptr::null_mut();
+    let result = f(device, p_info, p_data);
+    let outputs: Vec<Value> = vec![Value::Null];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_screen_buffer_properties_qnx(
+    device: vk::Device,
+    buffer: *const vk::_screen_buffer,
+    p_properties: *mut vk::ScreenBufferPropertiesQNX<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetScreenBufferPropertiesQNX>(next(
+        396usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, buffer, p_properties);
+    }
+    let args = vec![
+        device.record(),
+        buffer.record(),
+        record_array(p_properties, 1, |element| element.record()),
+    ];
+    let result = next(device, buffer, p_properties);
+    push_call(
+        "vkGetScreenBufferPropertiesQNX",
+        args,
+        vec![record_array(p_properties, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_screen_buffer_properties_qnx(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetScreenBufferPropertiesQNX>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let buffer: *const vk::_screen_buffer = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_properties: *mut vk::ScreenBufferPropertiesQNX<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, buffer, p_properties);
+    let outputs: Vec<Value> = vec![record_array(p_properties, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_semaphore_counter_value(
+    device: vk::Device,
+    semaphore: vk::Semaphore,
+    p_value: *mut u64,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSemaphoreCounterValue>(next(
+        397usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, semaphore, p_value);
+    }
+    let args = vec![
+        device.record(),
+        semaphore.record(),
+        record_output(p_value, 1),
+    ];
+    let result = next(device, semaphore, p_value);
+    push_call(
+        "vkGetSemaphoreCounterValue",
+        args,
+        vec![record_array(p_value, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_semaphore_counter_value(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetSemaphoreCounterValue>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let semaphore: vk::Semaphore = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_value: *mut u64 = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, semaphore, p_value);
+    let outputs: Vec<Value> = vec![record_array(p_value, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_semaphore_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::SemaphoreGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSemaphoreFdKHR>(next(
+        398usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_get_fd_info, p_fd);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_get_fd_info, 1, |element| element.record()),
+        record_output(p_fd, 1),
+    ];
+    let result = next(device, p_get_fd_info, p_fd);
+    push_call(
+        "vkGetSemaphoreFdKHR",
+        args,
+        vec![record_array(p_fd, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_semaphore_fd_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetSemaphoreFdKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_get_fd_info: *const vk::SemaphoreGetFdInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_fd: *mut c_int = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_get_fd_info, p_fd);
+    let outputs: Vec<Value> = vec![record_array(p_fd, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_semaphore_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::SemaphoreGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSemaphoreWin32HandleKHR>(next(
+        399usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_get_win32_handle_info, p_handle);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_get_win32_handle_info, 1, |element| element.record()),
+        record_output(p_handle, 1),
+    ];
+    let result = next(device, p_get_win32_handle_info, p_handle);
+    push_call(
+        "vkGetSemaphoreWin32HandleKHR",
+        args,
+        vec![record_array(p_handle, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_semaphore_win32_handle_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetSemaphoreWin32HandleKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_get_win32_handle_info: *const vk::SemaphoreGetWin32HandleInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_handle: *mut vk::HANDLE = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_get_win32_handle_info, p_handle);
+    let outputs: Vec<Value> = vec![record_array(p_handle, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_semaphore_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_get_zircon_handle_info: *const vk::SemaphoreGetZirconHandleInfoFUCHSIA<'_>,
+    p_zircon_handle: *mut vk::zx_handle_t,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSemaphoreZirconHandleFUCHSIA>(next(
+        400usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_get_zircon_handle_info, p_zircon_handle);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_get_zircon_handle_info, 1, |element| element.record()),
+        record_output(p_zircon_handle, 1),
+    ];
+    let result = next(device, p_get_zircon_handle_info, p_zircon_handle);
+    push_call(
+        "vkGetSemaphoreZirconHandleFUCHSIA",
+        args,
+        vec![record_array(p_zircon_handle, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_semaphore_zircon_handle_fuchsia(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetSemaphoreZirconHandleFUCHSIA>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_get_zircon_handle_info: *const vk::SemaphoreGetZirconHandleInfoFUCHSIA<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_zircon_handle: *mut vk::zx_handle_t =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_get_zircon_handle_info, p_zircon_handle);
+    let outputs: Vec<Value> = vec![record_array(p_zircon_handle, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_shader_binary_data_ext(
+    device: vk::Device,
+    shader: vk::ShaderEXT,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetShaderBinaryDataEXT>(next(
+        401usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, shader, p_data_size, p_data);
+    }
+    let args = vec![
+        device.record(),
+        shader.record(),
+        record_array(p_data_size, 1, |element| element.record()),
+        record_data_output(
+            p_data.cast::<u8>(),
+            if p_data_size.is_null() {
+                0
+            } else {
+                *p_data_size
+            },
+        ),
+    ];
+    let result = next(device, shader, p_data_size, p_data);
+    push_call(
+        "vkGetShaderBinaryDataEXT",
+        args,
+        vec![
+            record_array(p_data_size, 1, |element| element.record()),
+            record_data(
+                p_data.cast::<u8>(),
+                if p_data_size.is_null() {
+                    0
+                } else {
+                    *p_data_size
+                },
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_shader_binary_data_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetShaderBinaryDataEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let shader: vk::ShaderEXT = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_data_size: *mut usize = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_data: *mut c_void = replay_data::<u8>(&args[3usize], arena)?.cast::<c_void>();
+    let result = f(device, shader, p_data_size, p_data);
+    let outputs: Vec<Value> = vec![
+        record_array(p_data_size, 1, |element| element.record()),
+        record_data(
+            p_data.cast::<u8>(),
+            if p_data_size.is_null() {
+                0
+            } else {
+                *p_data_size
+            },
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_shader_info_amd(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    shader_stage: vk::ShaderStageFlags,
+    info_type: vk::ShaderInfoTypeAMD,
+    p_info_size: *mut usize,
+    p_info: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetShaderInfoAMD>(next(
+        402usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            pipeline,
+            shader_stage,
+            info_type,
+            p_info_size,
+            p_info,
+        );
+    }
+    let args = vec![
+        device.record(),
+        pipeline.record(),
+        shader_stage.record(),
+        info_type.record(),
+        record_array(p_info_size, 1, |element| element.record()),
+        record_data_output(
+            p_info.cast::<u8>(),
+            if p_info_size.is_null() {
+                0
+            } else {
+                *p_info_size
+            },
+        ),
+    ];
+    let result = next(
+        device,
+        pipeline,
+        shader_stage,
+        info_type,
+        p_info_size,
+        p_info,
+    );
+    push_call(
+        "vkGetShaderInfoAMD",
+        args,
+        vec![
+            record_array(p_info_size, 1, |element| element.record()),
+            record_data(
+                p_info.cast::<u8>(),
+                if p_info_size.is_null() {
+                    0
+                } else {
+                    *p_info_size
+                },
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_shader_info_amd(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetShaderInfoAMD>(f);
+    let args = args::<6usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let pipeline: vk::Pipeline = DeepCopyable::replay(&args[1usize], arena)?;
+    let shader_stage: vk::ShaderStageFlags = DeepCopyable::replay(&args[2usize], arena)?;
+    let info_type: vk::ShaderInfoTypeAMD = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_info_size: *mut usize = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_info: *mut c_void = replay_data::<u8>(&args[5usize], arena)?.cast::<c_void>();
+    let result = f(
+        device,
+        pipeline,
+        shader_stage,
+        info_type,
+        p_info_size,
+        p_info,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(p_info_size, 1, |element| element.record()),
+        record_data(
+            p_info.cast::<u8>(),
+            if p_info_size.is_null() {
+                0
+            } else {
+                *p_info_size
+            },
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_shader_module_create_info_identifier_ext(
+    device: vk::Device,
+    p_create_info: *const vk::ShaderModuleCreateInfo<'_>,
+    p_identifier: *mut vk::ShaderModuleIdentifierEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetShaderModuleCreateInfoIdentifierEXT>(
+        next(403usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, p_create_info, p_identifier);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_create_info, 1, |element| element.record()),
+        record_array(p_identifier, 1, |element| element.record()),
+    ];
+    next(device, p_create_info, p_identifier);
+    push_call(
+        "vkGetShaderModuleCreateInfoIdentifierEXT",
+        args,
+        vec![record_array(p_identifier, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_shader_module_create_info_identifier_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetShaderModuleCreateInfoIdentifierEXT,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_create_info: *const vk::ShaderModuleCreateInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_identifier: *mut vk::ShaderModuleIdentifierEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, p_create_info, p_identifier);
+    let outputs: Vec<Value> = vec![record_array(p_identifier, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_shader_module_identifier_ext(
+    device: vk::Device,
+    shader_module: vk::ShaderModule,
+    p_identifier: *mut vk::ShaderModuleIdentifierEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetShaderModuleIdentifierEXT>(next(
+        404usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, shader_module, p_identifier);
+    }
+    let args = vec![
+        device.record(),
+        shader_module.record(),
+        record_array(p_identifier, 1, |element| element.record()),
+    ];
+    next(device, shader_module, p_identifier);
+    push_call(
+        "vkGetShaderModuleIdentifierEXT",
+        args,
+        vec![record_array(p_identifier, 1, |element| element.record())],
+        Value::Null,
+    );
+}
+unsafe fn replay_get_shader_module_identifier_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetShaderModuleIdentifierEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let shader_module: vk::ShaderModule = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_identifier: *mut vk::ShaderModuleIdentifierEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, shader_module, p_identifier);
+    let outputs: Vec<Value> = vec![record_array(p_identifier, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn get_swapchain_counter_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    counter: vk::SurfaceCounterFlagsEXT,
+    p_counter_value: *mut u64,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSwapchainCounterEXT>(next(
+        405usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, swapchain, counter, p_counter_value);
+    }
+    let args = vec![
+        device.record(),
+        swapchain.record(),
+        counter.record(),
+        record_output(p_counter_value, 1),
+    ];
+    let result = next(device, swapchain, counter, p_counter_value);
+    push_call(
+        "vkGetSwapchainCounterEXT",
+        args,
+        vec![record_array(p_counter_value, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_swapchain_counter_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetSwapchainCounterEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let counter: vk::SurfaceCounterFlagsEXT = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_counter_value: *mut u64 = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, swapchain, counter, p_counter_value);
+    let outputs: Vec<Value> = vec![record_array(p_counter_value, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_swapchain_gralloc_usage2_android(
+    device: vk::Device,
+    format: vk::Format,
+    image_usage: vk::ImageUsageFlags,
+    swapchain_image_usage: vk::SwapchainImageUsageFlagsANDROID,
+    gralloc_consumer_usage: *mut u64,
+    gralloc_producer_usage: *mut u64,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSwapchainGrallocUsage2ANDROID>(next(
+        406usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            format,
+            image_usage,
+            swapchain_image_usage,
+            gralloc_consumer_usage,
+            gralloc_producer_usage,
+        );
+    }
+    let args = vec![
+        device.record(),
+        format.record(),
+        image_usage.record(),
+        swapchain_image_usage.record(),
+        record_output(gralloc_consumer_usage, 1),
+        record_output(gralloc_producer_usage, 1),
+    ];
+    let result = next(
+        device,
+        format,
+        image_usage,
+        swapchain_image_usage,
+        gralloc_consumer_usage,
+        gralloc_producer_usage,
+    );
+    push_call(
+        "vkGetSwapchainGrallocUsage2ANDROID",
+        args,
+        vec![
+            record_array(gralloc_consumer_usage, 1, |element| element.record()),
+            record_array(gralloc_producer_usage, 1, |element| element.record()),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_swapchain_gralloc_usage2_android(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetSwapchainGrallocUsage2ANDROID>(
+        f,
+    );
+    let args = args::<6usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let format: vk::Format = DeepCopyable::replay(&args[1usize], arena)?;
+    let image_usage: vk::ImageUsageFlags = DeepCopyable::replay(&args[2usize], arena)?;
+    let swapchain_image_usage: vk::SwapchainImageUsageFlagsANDROID =
+        DeepCopyable::replay(&args[3usize], arena)?;
+    let gralloc_consumer_usage: *mut u64 = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let gralloc_producer_usage: *mut u64 = replay_array(&args[5usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(
+        device,
+        format,
+        image_usage,
+        swapchain_image_usage,
+        gralloc_consumer_usage,
+        gralloc_producer_usage,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(gralloc_consumer_usage, 1, |element| element.record()),
+        record_array(gralloc_producer_usage, 1, |element| element.record()),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_swapchain_gralloc_usage_android(
+    device: vk::Device,
+    format: vk::Format,
+    image_usage: vk::ImageUsageFlags,
+    gralloc_usage: *mut c_int,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSwapchainGrallocUsageANDROID>(next(
+        407usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, format, image_usage, gralloc_usage);
+    }
+    let args = vec![
+        device.record(),
+        format.record(),
+        image_usage.record(),
+        record_output(gralloc_usage, 1),
+    ];
+    let result = next(device, format, image_usage, gralloc_usage);
+    push_call(
+        "vkGetSwapchainGrallocUsageANDROID",
+        args,
+        vec![record_array(gralloc_usage, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_swapchain_gralloc_usage_android(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetSwapchainGrallocUsageANDROID>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let format: vk::Format = DeepCopyable::replay(&args[1usize], arena)?;
+    let image_usage: vk::ImageUsageFlags = DeepCopyable::replay(&args[2usize], arena)?;
+    let gralloc_usage: *mut c_int = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, format, image_usage, gralloc_usage);
+    let outputs: Vec<Value> = vec![record_array(gralloc_usage, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_swapchain_images_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_swapchain_image_count: *mut u32,
+    p_swapchain_images: *mut vk::Image,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSwapchainImagesKHR>(next(
+        408usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            swapchain,
+            p_swapchain_image_count,
+            p_swapchain_images,
+        );
+    }
+    let args = vec![
+        device.record(),
+        swapchain.record(),
+        record_array(p_swapchain_image_count, 1, |element| element.record()),
+        record_output(
+            p_swapchain_images,
+            if p_swapchain_image_count.is_null() {
+                0
+            } else {
+                *p_swapchain_image_count as usize
+            },
+        ),
+    ];
+    let result = next(
+        device,
+        swapchain,
+        p_swapchain_image_count,
+        p_swapchain_images,
+    );
+    push_call(
+        "vkGetSwapchainImagesKHR",
+        args,
+        vec![
+            record_array(p_swapchain_image_count, 1, |element| element.record()),
+            record_array(
+                p_swapchain_images,
+                if p_swapchain_image_count.is_null() {
+                    0
+                } else {
+                    *p_swapchain_image_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_swapchain_images_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetSwapchainImagesKHR>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_swapchain_image_count: *mut u32 = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_swapchain_images: *mut vk::Image = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(
+        device,
+        swapchain,
+        p_swapchain_image_count,
+        p_swapchain_images,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(p_swapchain_image_count, 1, |element| element.record()),
+        record_array(
+            p_swapchain_images,
+            if p_swapchain_image_count.is_null() {
+                0
+            } else {
+                *p_swapchain_image_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_swapchain_status_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSwapchainStatusKHR>(next(
+        409usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, swapchain);
+    }
+    let args = vec![device.record(), swapchain.record()];
+    let result = next(device, swapchain);
+    push_call("vkGetSwapchainStatusKHR", args, vec![], result.record());
+    result
+}
+unsafe fn replay_get_swapchain_status_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetSwapchainStatusKHR>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, swapchain);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_validation_cache_data_ext(
+    device: vk::Device,
+    validation_cache: vk::ValidationCacheEXT,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetValidationCacheDataEXT>(next(
+        410usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, validation_cache, p_data_size, p_data);
+    }
+    let args = vec![
+        device.record(),
+        validation_cache.record(),
+        record_array(p_data_size, 1, |element| element.record()),
+        record_data_output(
+            p_data.cast::<u8>(),
+            if p_data_size.is_null() {
+                0
+            } else {
+                *p_data_size
+            },
+        ),
+    ];
+    let result = next(device, validation_cache, p_data_size, p_data);
+    push_call(
+        "vkGetValidationCacheDataEXT",
+        args,
+        vec![
+            record_array(p_data_size, 1, |element| element.record()),
+            record_data(
+                p_data.cast::<u8>(),
+                if p_data_size.is_null() {
+                    0
+                } else {
+                    *p_data_size
+                },
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_validation_cache_data_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkGetValidationCacheDataEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let validation_cache: vk::ValidationCacheEXT = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_data_size: *mut usize = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let p_data: *mut c_void = replay_data::<u8>(&args[3usize], arena)?.cast::<c_void>();
+    let result = f(device, validation_cache, p_data_size, p_data);
+    let outputs: Vec<Value> = vec![
+        record_array(p_data_size, 1, |element| element.record()),
+        record_data(
+            p_data.cast::<u8>(),
+            if p_data_size.is_null() {
+                0
+            } else {
+                *p_data_size
+            },
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn get_video_session_memory_requirements_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    p_memory_requirements_count: *mut u32,
+    p_memory_requirements: *mut vk::VideoSessionMemoryRequirementsKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetVideoSessionMemoryRequirementsKHR>(next(
+        411usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            video_session,
+            p_memory_requirements_count,
+            p_memory_requirements,
+        );
+    }
+    let args = vec![
+        device.record(),
+        video_session.record(),
+        record_array(p_memory_requirements_count, 1, |element| element.record()),
+        record_array(
+            p_memory_requirements,
+            if p_memory_requirements_count.is_null() {
+                0
+            } else {
+                *p_memory_requirements_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    let result = next(
+        device,
+        video_session,
+        p_memory_requirements_count,
+        p_memory_requirements,
+    );
+    push_call(
+        "vkGetVideoSessionMemoryRequirementsKHR",
+        args,
+        vec![
+            record_array(p_memory_requirements_count, 1, |element| element.record()),
+            record_array(
+                p_memory_requirements,
+                if p_memory_requirements_count.is_null() {
+                    0
+                } else {
+                    *p_memory_requirements_count as usize
+                },
+                |element| element.record(),
+            ),
+        ],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_get_video_session_memory_requirements_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkGetVideoSessionMemoryRequirementsKHR,
+    >(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let video_session: vk::VideoSessionKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_memory_requirements_count: *mut u32 =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_memory_requirements: *mut vk::VideoSessionMemoryRequirementsKHR<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(
+        device,
+        video_session,
+        p_memory_requirements_count,
+        p_memory_requirements,
+    );
+    let outputs: Vec<Value> = vec![
+        record_array(p_memory_requirements_count, 1, |element| element.record()),
+        record_array(
+            p_memory_requirements,
+            if p_memory_requirements_count.is_null() {
+                0
+            } else {
+                *p_memory_requirements_count as usize
+            },
+            |element| element.record(),
+        ),
+    ];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn import_fence_fd_khr(
+    device: vk::Device,
+    p_import_fence_fd_info: *const vk::ImportFenceFdInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkImportFenceFdKHR>(next(
+        412usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_import_fence_fd_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_import_fence_fd_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_import_fence_fd_info);
+    push_call("vkImportFenceFdKHR", args, vec![], result.record());
+    result
+}
+unsafe fn replay_import_fence_fd_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkImportFenceFdKHR>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_import_fence_fd_info: *const vk::ImportFenceFdInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_import_fence_fd_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn import_fence_win32_handle_khr(
+    device: vk::Device,
+    p_import_fence_win32_handle_info: *const vk::ImportFenceWin32HandleInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkImportFenceWin32HandleKHR>(next(
+        413usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_import_fence_win32_handle_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_import_fence_win32_handle_info, 1, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, p_import_fence_win32_handle_info);
+    push_call("vkImportFenceWin32HandleKHR", args, vec![], result.record());
+    result
+}
+unsafe fn replay_import_fence_win32_handle_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkImportFenceWin32HandleKHR>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_import_fence_win32_handle_info: *const vk::ImportFenceWin32HandleInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_import_fence_win32_handle_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn import_semaphore_fd_khr(
+    device: vk::Device,
+    p_import_semaphore_fd_info: *const vk::ImportSemaphoreFdInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkImportSemaphoreFdKHR>(next(
+        414usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_import_semaphore_fd_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_import_semaphore_fd_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_import_semaphore_fd_info);
+    push_call("vkImportSemaphoreFdKHR", args, vec![], result.record());
+    result
+}
+unsafe fn replay_import_semaphore_fd_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkImportSemaphoreFdKHR>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_import_semaphore_fd_info: *const vk::ImportSemaphoreFdInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_import_semaphore_fd_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn import_semaphore_win32_handle_khr(
+    device: vk::Device,
+    p_import_semaphore_win32_handle_info: *const vk::ImportSemaphoreWin32HandleInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkImportSemaphoreWin32HandleKHR>(next(
+        415usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_import_semaphore_win32_handle_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_import_semaphore_win32_handle_info, 1, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, p_import_semaphore_win32_handle_info);
+    push_call(
+        "vkImportSemaphoreWin32HandleKHR",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_import_semaphore_win32_handle_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkImportSemaphoreWin32HandleKHR>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_import_semaphore_win32_handle_info: *const vk::ImportSemaphoreWin32HandleInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_import_semaphore_win32_handle_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn import_semaphore_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_import_semaphore_zircon_handle_info: *const vk::ImportSemaphoreZirconHandleInfoFUCHSIA<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkImportSemaphoreZirconHandleFUCHSIA>(next(
+        416usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_import_semaphore_zircon_handle_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_import_semaphore_zircon_handle_info, 1, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, p_import_semaphore_zircon_handle_info);
+    push_call(
+        "vkImportSemaphoreZirconHandleFUCHSIA",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_import_semaphore_zircon_handle_fuchsia(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkImportSemaphoreZirconHandleFUCHSIA,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_import_semaphore_zircon_handle_info: *const vk::ImportSemaphoreZirconHandleInfoFUCHSIA<
+        '_,
+    > = replay_array(&args[1usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_import_semaphore_zircon_handle_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn initialize_performance_api_intel(
+    device: vk::Device,
+    p_initialize_info: *const vk::InitializePerformanceApiInfoINTEL<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkInitializePerformanceApiINTEL>(next(
+        417usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_initialize_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_initialize_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_initialize_info);
+    push_call(
+        "vkInitializePerformanceApiINTEL",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_initialize_performance_api_intel(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkInitializePerformanceApiINTEL>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_initialize_info: *const vk::InitializePerformanceApiInfoINTEL<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_initialize_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn invalidate_mapped_memory_ranges(
+    device: vk::Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const vk::MappedMemoryRange<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkInvalidateMappedMemoryRanges>(next(
+        418usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, memory_range_count, p_memory_ranges);
+    }
+    let args = vec![
+        device.record(),
+        memory_range_count.record(),
+        record_array(p_memory_ranges, memory_range_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, memory_range_count, p_memory_ranges);
+    push_call(
+        "vkInvalidateMappedMemoryRanges",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_invalidate_mapped_memory_ranges(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkInvalidateMappedMemoryRanges>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let memory_range_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_memory_ranges: *const vk::MappedMemoryRange<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, memory_range_count, p_memory_ranges);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn map_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    flags: vk::MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkMapMemory>(next(
+        419usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, memory, offset, size, flags, pp_data);
+    }
+    let args = vec![
+        device.record(),
+        memory.record(),
+        offset.record(),
+        size.record(),
+        flags.record(),
+        record_output(pp_data, 1),
+    ];
+    let result = next(device, memory, offset, size, flags, pp_data);
+    push_call(
+        "vkMapMemory",
+        args,
+        vec![record_array(pp_data, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_map_memory(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkMapMemory>(f);
+    let args = args::<6usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let memory: vk::DeviceMemory = DeepCopyable::replay(&args[1usize], arena)?;
+    let offset: vk::DeviceSize = DeepCopyable::replay(&args[2usize], arena)?;
+    let size: vk::DeviceSize = DeepCopyable::replay(&args[3usize], arena)?;
+    let flags: vk::MemoryMapFlags = DeepCopyable::replay(&args[4usize], arena)?;
+    let pp_data: *mut *mut c_void = replay_array(&args[5usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, memory, offset, size, flags, pp_data);
+    let outputs: Vec<Value> = vec![record_array(pp_data, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn map_memory2_khr(
+    device: vk::Device,
+    p_memory_map_info: *const vk::MemoryMapInfoKHR<'_>,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkMapMemory2KHR>(next(
+        420usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_memory_map_info, pp_data);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_memory_map_info, 1, |element| element.record()),
+        record_output(pp_data, 1),
+    ];
+    let result = next(device, p_memory_map_info, pp_data);
+    push_call(
+        "vkMapMemory2KHR",
+        args,
+        vec![record_array(pp_data, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_map_memory2_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkMapMemory2KHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_memory_map_info: *const vk::MemoryMapInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let pp_data: *mut *mut c_void = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_memory_map_info, pp_data);
+    let outputs: Vec<Value> = vec![record_array(pp_data, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn merge_pipeline_caches(
+    device: vk::Device,
+    dst_cache: vk::PipelineCache,
+    src_cache_count: u32,
+    p_src_caches: *const vk::PipelineCache,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkMergePipelineCaches>(next(
+        421usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, dst_cache, src_cache_count, p_src_caches);
+    }
+    let args = vec![
+        device.record(),
+        dst_cache.record(),
+        src_cache_count.record(),
+        record_array(p_src_caches, src_cache_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, dst_cache, src_cache_count, p_src_caches);
+    push_call("vkMergePipelineCaches", args, vec![], result.record());
+    result
+}
+unsafe fn replay_merge_pipeline_caches(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkMergePipelineCaches>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let dst_cache: vk::PipelineCache = DeepCopyable::replay(&args[1usize], arena)?;
+    let src_cache_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_src_caches: *const vk::PipelineCache =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, dst_cache, src_cache_count, p_src_caches);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn merge_validation_caches_ext(
+    device: vk::Device,
+    dst_cache: vk::ValidationCacheEXT,
+    src_cache_count: u32,
+    p_src_caches: *const vk::ValidationCacheEXT,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkMergeValidationCachesEXT>(next(
+        422usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, dst_cache, src_cache_count, p_src_caches);
+    }
+    let args = vec![
+        device.record(),
+        dst_cache.record(),
+        src_cache_count.record(),
+        record_array(p_src_caches, src_cache_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    let result = next(device, dst_cache, src_cache_count, p_src_caches);
+    push_call("vkMergeValidationCachesEXT", args, vec![], result.record());
+    result
+}
+unsafe fn replay_merge_validation_caches_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkMergeValidationCachesEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let dst_cache: vk::ValidationCacheEXT = DeepCopyable::replay(&args[1usize], arena)?;
+    let src_cache_count: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let p_src_caches: *const vk::ValidationCacheEXT =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, dst_cache, src_cache_count, p_src_caches);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn queue_begin_debug_utils_label_ext(
+    queue: vk::Queue,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueBeginDebugUtilsLabelEXT>(next(
+        423usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(queue, p_label_info);
+    }
+    let args = vec![
+        queue.record(),
+        record_array(p_label_info, 1, |element| element.record()),
+    ];
+    next(queue, p_label_info);
+    push_call("vkQueueBeginDebugUtilsLabelEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_queue_begin_debug_utils_label_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkQueueBeginDebugUtilsLabelEXT>(f);
+    let args = args::<2usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_label_info: *const vk::DebugUtilsLabelEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(queue, p_label_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn queue_bind_sparse(
+    queue: vk::Queue,
+    bind_info_count: u32,
+    p_bind_info: *const vk::BindSparseInfo<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueBindSparse>(next(
+        424usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(queue, bind_info_count, p_bind_info, fence);
+    }
+    let args = vec![
+        queue.record(),
+        bind_info_count.record(),
+        record_array(p_bind_info, bind_info_count as usize, |element| {
+            element.record()
+        }),
+        fence.record(),
+    ];
+    let result = next(queue, bind_info_count, p_bind_info, fence);
+    push_call("vkQueueBindSparse", args, vec![], result.record());
+    result
+}
+unsafe fn replay_queue_bind_sparse(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkQueueBindSparse>(f);
+    let args = args::<4usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let bind_info_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_bind_info: *const vk::BindSparseInfo<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let fence: vk::Fence = DeepCopyable::replay(&args[3usize], arena)?;
+    let result = f(queue, bind_info_count, p_bind_info, fence);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn queue_end_debug_utils_label_ext(queue: vk::Queue) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueEndDebugUtilsLabelEXT>(next(
+        425usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(queue);
+    }
+    let args = vec![queue.record()];
+    next(queue);
+    push_call("vkQueueEndDebugUtilsLabelEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_queue_end_debug_utils_label_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkQueueEndDebugUtilsLabelEXT>(f);
+    let args = args::<1usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    f(queue);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn queue_insert_debug_utils_label_ext(
+    queue: vk::Queue,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueInsertDebugUtilsLabelEXT>(next(
+        426usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(queue, p_label_info);
+    }
+    let args = vec![
+        queue.record(),
+        record_array(p_label_info, 1, |element| element.record()),
+    ];
+    next(queue, p_label_info);
+    push_call("vkQueueInsertDebugUtilsLabelEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_queue_insert_debug_utils_label_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkQueueInsertDebugUtilsLabelEXT>(f);
+    let args = args::<2usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_label_info: *const vk::DebugUtilsLabelEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(queue, p_label_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn queue_present_khr(
+    queue: vk::Queue,
+    p_present_info: *const vk::PresentInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueuePresentKHR>(next(
+        427usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(queue, p_present_info);
+    }
+    let args = vec![
+        queue.record(),
+        record_array(p_present_info, 1, |element| element.record()),
+    ];
+    let result = next(queue, p_present_info);
+    push_call("vkQueuePresentKHR", args, vec![], result.record());
+    result
+}
+unsafe fn replay_queue_present_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkQueuePresentKHR>(f);
+    let args = args::<2usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_present_info: *const vk::PresentInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(queue, p_present_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn queue_set_performance_configuration_intel(
+    queue: vk::Queue,
+    configuration: vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueSetPerformanceConfigurationINTEL>(
+        next(428usize, dispatch_key(queue.as_raw())),
+    );
+    if !is_recording() {
+        return next(queue, configuration);
+    }
+    let args = vec![queue.record(), configuration.record()];
+    let result = next(queue, configuration);
+    push_call(
+        "vkQueueSetPerformanceConfigurationINTEL",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_queue_set_performance_configuration_intel(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkQueueSetPerformanceConfigurationINTEL,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let configuration: vk::PerformanceConfigurationINTEL =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(queue, configuration);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn queue_signal_release_image_android(
+    queue: vk::Queue,
+    wait_semaphore_count: u32,
+    p_wait_semaphores: *const vk::Semaphore,
+    image: vk::Image,
+    p_native_fence_fd: *mut c_int,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueSignalReleaseImageANDROID>(next(
+        429usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            queue,
+            wait_semaphore_count,
+            p_wait_semaphores,
+            image,
+            p_native_fence_fd,
+        );
+    }
+    let args = vec![
+        queue.record(),
+        wait_semaphore_count.record(),
+        record_array(
+            p_wait_semaphores,
+            wait_semaphore_count as usize,
+            |element| element.record(),
+        ),
+        image.record(),
+        record_output(p_native_fence_fd, 1),
+    ];
+    let result = next(
+        queue,
+        wait_semaphore_count,
+        p_wait_semaphores,
+        image,
+        p_native_fence_fd,
+    );
+    push_call(
+        "vkQueueSignalReleaseImageANDROID",
+        args,
+        vec![record_array(p_native_fence_fd, 1, |element| {
+            element.record()
+        })],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_queue_signal_release_image_android(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkQueueSignalReleaseImageANDROID>(f);
+    let args = args::<5usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let wait_semaphore_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_wait_semaphores: *const vk::Semaphore =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let image: vk::Image = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_native_fence_fd: *mut c_int = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(
+        queue,
+        wait_semaphore_count,
+        p_wait_semaphores,
+        image,
+        p_native_fence_fd,
+    );
+    let outputs: Vec<Value> = vec![record_array(p_native_fence_fd, 1, |element| {
+        element.record()
+    })];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn queue_submit(
+    queue: vk::Queue,
+    submit_count: u32,
+    p_submits: *const vk::SubmitInfo<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueSubmit>(next(
+        430usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(queue, submit_count, p_submits, fence);
+    }
+    let args = vec![
+        queue.record(),
+        submit_count.record(),
+        record_array(p_submits, submit_count as usize, |element| element.record()),
+        fence.record(),
+    ];
+    let result = next(queue, submit_count, p_submits, fence);
+    push_call("vkQueueSubmit", args, vec![], result.record());
+    result
+}
+unsafe fn replay_queue_submit(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkQueueSubmit>(f);
+    let args = args::<4usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let submit_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_submits: *const vk::SubmitInfo<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let fence: vk::Fence = DeepCopyable::replay(&args[3usize], arena)?;
+    let result = f(queue, submit_count, p_submits, fence);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn queue_submit2(
+    queue: vk::Queue,
+    submit_count: u32,
+    p_submits: *const vk::SubmitInfo2<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueSubmit2>(next(
+        431usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(queue, submit_count, p_submits, fence);
+    }
+    let args = vec![
+        queue.record(),
+        submit_count.record(),
+        record_array(p_submits, submit_count as usize, |element| element.record()),
+        fence.record(),
+    ];
+    let result = next(queue, submit_count, p_submits, fence);
+    push_call("vkQueueSubmit2", args, vec![], result.record());
+    result
+}
+unsafe fn replay_queue_submit2(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkQueueSubmit2>(f);
+    let args = args::<4usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let submit_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_submits: *const vk::SubmitInfo2<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let fence: vk::Fence = DeepCopyable::replay(&args[3usize], arena)?;
+    let result = f(queue, submit_count, p_submits, fence);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn queue_wait_idle(queue: vk::Queue) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueWaitIdle>(next(
+        432usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    if !is_recording() {
+        return next(queue);
+    }
+    let args = vec![queue.record()];
+    let result = next(queue);
+    push_call("vkQueueWaitIdle", args, vec![], result.record());
+    result
+}
+unsafe fn replay_queue_wait_idle(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkQueueWaitIdle>(f);
+    let args = args::<1usize>(call)?;
+    let queue: vk::Queue = DeepCopyable::replay(&args[0usize], arena)?;
+    let result = f(queue);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn register_device_event_ext(
+    device: vk::Device,
+    p_device_event_info: *const vk::DeviceEventInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkRegisterDeviceEventEXT>(next(
+        433usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_device_event_info, p_allocator, p_fence);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_device_event_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_fence, 1),
+    ];
+    let result = next(device, p_device_event_info, p_allocator, p_fence);
+    push_call(
+        "vkRegisterDeviceEventEXT",
+        args,
+        vec![record_array(p_fence, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_register_device_event_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkRegisterDeviceEventEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_device_event_info: *const vk::DeviceEventInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_fence: *mut vk::Fence = replay_array(&args[3usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, p_device_event_info, p_allocator, p_fence);
+    let outputs: Vec<Value> = vec![record_array(p_fence, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn register_display_event_ext(
+    device: vk::Device,
+    display: vk::DisplayKHR,
+    p_display_event_info: *const vk::DisplayEventInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkRegisterDisplayEventEXT>(next(
+        434usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, display, p_display_event_info, p_allocator, p_fence);
+    }
+    let args = vec![
+        device.record(),
+        display.record(),
+        record_array(p_display_event_info, 1, |element| element.record()),
+        Value::Null,
+        record_output(p_fence, 1),
+    ];
+    let result = next(device, display, p_display_event_info, p_allocator, p_fence);
+    push_call(
+        "vkRegisterDisplayEventEXT",
+        args,
+        vec![record_array(p_fence, 1, |element| element.record())],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_register_display_event_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkRegisterDisplayEventEXT>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let display: vk::DisplayKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_display_event_info: *const vk::DisplayEventInfoEXT<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_allocator: *const vk::AllocationCallbacks<'_> = ptr::null_mut();
+    let p_fence: *mut vk::Fence = replay_array(&args[4usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, display, p_display_event_info, p_allocator, p_fence);
+    let outputs: Vec<Value> = vec![record_array(p_fence, 1, |element| element.record())];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn release_full_screen_exclusive_mode_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkReleaseFullScreenExclusiveModeEXT>(next(
+        435usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, swapchain);
+    }
+    let args = vec![device.record(), swapchain.record()];
+    let result = next(device, swapchain);
+    push_call(
+        "vkReleaseFullScreenExclusiveModeEXT",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_release_full_screen_exclusive_mode_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkReleaseFullScreenExclusiveModeEXT,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, swapchain);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn release_performance_configuration_intel(
+    device: vk::Device,
+    configuration: vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkReleasePerformanceConfigurationINTEL>(next(
+        436usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, configuration);
+    }
+    let args = vec![device.record(), configuration.record()];
+    let result = next(device, configuration);
+    push_call(
+        "vkReleasePerformanceConfigurationINTEL",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_release_performance_configuration_intel(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkReleasePerformanceConfigurationINTEL,
+    >(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let configuration: vk::PerformanceConfigurationINTEL =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, configuration);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn release_profiling_lock_khr(device: vk::Device) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkReleaseProfilingLockKHR>(next(
+        437usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device);
+    }
+    let args = vec![device.record()];
+    next(device);
+    push_call("vkReleaseProfilingLockKHR", args, vec![], Value::Null);
+}
+unsafe fn replay_release_profiling_lock_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkReleaseProfilingLockKHR>(f);
+    let args = args::<1usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    f(device);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn release_swapchain_images_ext(
+    device: vk::Device,
+    p_release_info: *const vk::ReleaseSwapchainImagesInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkReleaseSwapchainImagesEXT>(next(
+        438usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_release_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_release_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_release_info);
+    push_call("vkReleaseSwapchainImagesEXT", args, vec![], result.record());
+    result
+}
+unsafe fn replay_release_swapchain_images_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkReleaseSwapchainImagesEXT>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_release_info: *const vk::ReleaseSwapchainImagesInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_release_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn reset_command_buffer(
+    command_buffer: vk::CommandBuffer,
+    flags: vk::CommandBufferResetFlags,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetCommandBuffer>(next(
+        439usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    if !is_recording() {
+        return next(command_buffer, flags);
+    }
+    let args = vec![command_buffer.record(), flags.record()];
+    let result = next(command_buffer, flags);
+    push_call("vkResetCommandBuffer", args, vec![], result.record());
+    result
+}
+unsafe fn replay_reset_command_buffer(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkResetCommandBuffer>(f);
+    let args = args::<2usize>(call)?;
+    let command_buffer: vk::CommandBuffer = DeepCopyable::replay(&args[0usize], arena)?;
+    let flags: vk::CommandBufferResetFlags = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(command_buffer, flags);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn reset_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    flags: vk::CommandPoolResetFlags,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetCommandPool>(next(
+        440usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, command_pool, flags);
+    }
+    let args = vec![device.record(), command_pool.record(), flags.record()];
+    let result = next(device, command_pool, flags);
+    push_call("vkResetCommandPool", args, vec![], result.record());
+    result
+}
+unsafe fn replay_reset_command_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkResetCommandPool>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let command_pool: vk::CommandPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let flags: vk::CommandPoolResetFlags = DeepCopyable::replay(&args[2usize], arena)?;
+    let result = f(device, command_pool, flags);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn reset_descriptor_pool(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    flags: vk::DescriptorPoolResetFlags,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetDescriptorPool>(next(
+        441usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, descriptor_pool, flags);
+    }
+    let args = vec![device.record(), descriptor_pool.record(), flags.record()];
+    let result = next(device, descriptor_pool, flags);
+    push_call("vkResetDescriptorPool", args, vec![], result.record());
+    result
+}
+unsafe fn replay_reset_descriptor_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkResetDescriptorPool>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let descriptor_pool: vk::DescriptorPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let flags: vk::DescriptorPoolResetFlags = DeepCopyable::replay(&args[2usize], arena)?;
+    let result = f(device, descriptor_pool, flags);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn reset_event(device: vk::Device, event: vk::Event) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetEvent>(next(
+        442usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, event);
+    }
+    let args = vec![device.record(), event.record()];
+    let result = next(device, event);
+    push_call("vkResetEvent", args, vec![], result.record());
+    result
+}
+unsafe fn replay_reset_event(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkResetEvent>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let event: vk::Event = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, event);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn reset_fences(
+    device: vk::Device,
+    fence_count: u32,
+    p_fences: *const vk::Fence,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetFences>(next(
+        443usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, fence_count, p_fences);
+    }
+    let args = vec![
+        device.record(),
+        fence_count.record(),
+        record_array(p_fences, fence_count as usize, |element| element.record()),
+    ];
+    let result = next(device, fence_count, p_fences);
+    push_call("vkResetFences", args, vec![], result.record());
+    result
+}
+unsafe fn replay_reset_fences(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkResetFences>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let fence_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_fences: *const vk::Fence = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let result = f(device, fence_count, p_fences);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn reset_query_pool(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetQueryPool>(next(
+        444usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, query_pool, first_query, query_count);
+    }
+    let args = vec![
+        device.record(),
+        query_pool.record(),
+        first_query.record(),
+        query_count.record(),
+    ];
+    next(device, query_pool, first_query, query_count);
+    push_call("vkResetQueryPool", args, vec![], Value::Null);
+}
+unsafe fn replay_reset_query_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkResetQueryPool>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let query_pool: vk::QueryPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let first_query: u32 = DeepCopyable::replay(&args[2usize], arena)?;
+    let query_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    f(device, query_pool, first_query, query_count);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn set_buffer_collection_buffer_constraints_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_buffer_constraints_info: *const vk::BufferConstraintsInfoFUCHSIA<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetBufferCollectionBufferConstraintsFUCHSIA>(
+        next(445usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, collection, p_buffer_constraints_info);
+    }
+    let args = vec![
+        device.record(),
+        collection.record(),
+        record_array(p_buffer_constraints_info, 1, |element| element.record()),
+    ];
+    let result = next(device, collection, p_buffer_constraints_info);
+    push_call(
+        "vkSetBufferCollectionBufferConstraintsFUCHSIA",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_set_buffer_collection_buffer_constraints_fuchsia(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkSetBufferCollectionBufferConstraintsFUCHSIA,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let collection: vk::BufferCollectionFUCHSIA = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_buffer_constraints_info: *const vk::BufferConstraintsInfoFUCHSIA<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, collection, p_buffer_constraints_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn set_buffer_collection_image_constraints_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_image_constraints_info: *const vk::ImageConstraintsInfoFUCHSIA<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetBufferCollectionImageConstraintsFUCHSIA>(
+        next(446usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(device, collection, p_image_constraints_info);
+    }
+    let args = vec![
+        device.record(),
+        collection.record(),
+        record_array(p_image_constraints_info, 1, |element| element.record()),
+    ];
+    let result = next(device, collection, p_image_constraints_info);
+    push_call(
+        "vkSetBufferCollectionImageConstraintsFUCHSIA",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_set_buffer_collection_image_constraints_fuchsia(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkSetBufferCollectionImageConstraintsFUCHSIA,
+    >(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let collection: vk::BufferCollectionFUCHSIA = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_image_constraints_info: *const vk::ImageConstraintsInfoFUCHSIA<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, collection, p_image_constraints_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn set_debug_utils_object_name_ext(
+    device: vk::Device,
+    p_name_info: *const vk::DebugUtilsObjectNameInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetDebugUtilsObjectNameEXT>(next(
+        447usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_name_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_name_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_name_info);
+    push_call(
+        "vkSetDebugUtilsObjectNameEXT",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_set_debug_utils_object_name_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkSetDebugUtilsObjectNameEXT>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_name_info: *const vk::DebugUtilsObjectNameInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_name_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn set_debug_utils_object_tag_ext(
+    device: vk::Device,
+    p_tag_info: *const vk::DebugUtilsObjectTagInfoEXT<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetDebugUtilsObjectTagEXT>(next(
+        448usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_tag_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_tag_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_tag_info);
+    push_call("vkSetDebugUtilsObjectTagEXT", args, vec![], result.record());
+    result
+}
+unsafe fn replay_set_debug_utils_object_tag_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkSetDebugUtilsObjectTagEXT>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_tag_info: *const vk::DebugUtilsObjectTagInfoEXT<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_tag_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn set_device_memory_priority_ext(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    priority: f32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetDeviceMemoryPriorityEXT>(next(
+        449usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, memory, priority);
+    }
+    let args = vec![device.record(), memory.record(), priority.record()];
+    next(device, memory, priority);
+    push_call("vkSetDeviceMemoryPriorityEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_set_device_memory_priority_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkSetDeviceMemoryPriorityEXT>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let memory: vk::DeviceMemory = DeepCopyable::replay(&args[1usize], arena)?;
+    let priority: f32 = DeepCopyable::replay(&args[2usize], arena)?;
+    f(device, memory, priority);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn set_event(device: vk::Device, event: vk::Event) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetEvent>(next(
+        450usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, event);
+    }
+    let args = vec![device.record(), event.record()];
+    let result = next(device, event);
+    push_call("vkSetEvent", args, vec![], result.record());
+    result
+}
+unsafe fn replay_set_event(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkSetEvent>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let event: vk::Event = DeepCopyable::replay(&args[1usize], arena)?;
+    let result = f(device, event);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn set_hdr_metadata_ext(
+    device: vk::Device,
+    swapchain_count: u32,
+    p_swapchains: *const vk::SwapchainKHR,
+    p_metadata: *const vk::HdrMetadataEXT<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetHdrMetadataEXT>(next(
+        451usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, swapchain_count, p_swapchains, p_metadata);
+    }
+    let args = vec![
+        device.record(),
+        swapchain_count.record(),
+        record_array(p_swapchains, swapchain_count as usize, |element| {
+            element.record()
+        }),
+        record_array(p_metadata, swapchain_count as usize, |element| {
+            element.record()
+        }),
+    ];
+    next(device, swapchain_count, p_swapchains, p_metadata);
+    push_call("vkSetHdrMetadataEXT", args, vec![], Value::Null);
+}
+unsafe fn replay_set_hdr_metadata_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkSetHdrMetadataEXT>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_swapchains: *const vk::SwapchainKHR =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let p_metadata: *const vk::HdrMetadataEXT<'_> =
+        replay_array(&args[3usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(device, swapchain_count, p_swapchains, p_metadata);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn set_local_dimming_amd(
+    device: vk::Device,
+    swap_chain: vk::SwapchainKHR,
+    local_dimming_enable: vk::Bool32,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetLocalDimmingAMD>(next(
+        452usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, swap_chain, local_dimming_enable);
+    }
+    let args = vec![
+        device.record(),
+        swap_chain.record(),
+        local_dimming_enable.record(),
+    ];
+    next(device, swap_chain, local_dimming_enable);
+    push_call("vkSetLocalDimmingAMD", args, vec![], Value::Null);
+}
+unsafe fn replay_set_local_dimming_amd(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkSetLocalDimmingAMD>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swap_chain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let local_dimming_enable: vk::Bool32 = DeepCopyable::replay(&args[2usize], arena)?;
+    f(device, swap_chain, local_dimming_enable);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn set_private_data(
+    device: vk::Device,
+    object_type: vk::ObjectType,
+    object_handle: u64,
+    private_data_slot: vk::PrivateDataSlot,
+    data: u64,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetPrivateData>(next(
+        453usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, object_type, object_handle, private_data_slot, data);
+    }
+    let args = vec![
+        device.record(),
+        object_type.record(),
+        object_handle.record(),
+        private_data_slot.record(),
+        data.record(),
+    ];
+    let result = next(device, object_type, object_handle, private_data_slot, data);
+    push_call("vkSetPrivateData", args, vec![], result.record());
+    result
+}
+unsafe fn replay_set_private_data(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkSetPrivateData>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let object_type: vk::ObjectType = DeepCopyable::replay(&args[1usize], arena)?;
+    let object_handle: u64 = DeepCopyable::replay(&args[2usize], arena)?;
+    let private_data_slot: vk::PrivateDataSlot = DeepCopyable::replay(&args[3usize], arena)?;
+    let data: u64 = DeepCopyable::replay(&args[4usize], arena)?;
+    let result = f(device, object_type, object_handle, private_data_slot, data);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn signal_semaphore(
+    device: vk::Device,
+    p_signal_info: *const vk::SemaphoreSignalInfo<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSignalSemaphore>(next(
+        454usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_signal_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_signal_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_signal_info);
+    push_call("vkSignalSemaphore", args, vec![], result.record());
+    result
+}
+unsafe fn replay_signal_semaphore(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkSignalSemaphore>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_signal_info: *const vk::SemaphoreSignalInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_signal_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn trim_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    flags: vk::CommandPoolTrimFlags,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkTrimCommandPool>(next(
+        455usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, command_pool, flags);
+    }
+    let args = vec![device.record(), command_pool.record(), flags.record()];
+    next(device, command_pool, flags);
+    push_call("vkTrimCommandPool", args, vec![], Value::Null);
+}
+unsafe fn replay_trim_command_pool(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkTrimCommandPool>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let command_pool: vk::CommandPool = DeepCopyable::replay(&args[1usize], arena)?;
+    let flags: vk::CommandPoolTrimFlags = DeepCopyable::replay(&args[2usize], arena)?;
+    f(device, command_pool, flags);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn uninitialize_performance_api_intel(device: vk::Device) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUninitializePerformanceApiINTEL>(next(
+        456usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device);
+    }
+    let args = vec![device.record()];
+    next(device);
+    push_call(
+        "vkUninitializePerformanceApiINTEL",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_uninitialize_performance_api_intel(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkUninitializePerformanceApiINTEL>(f);
+    let args = args::<1usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    f(device);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn unmap_memory(device: vk::Device, memory: vk::DeviceMemory) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUnmapMemory>(next(
+        457usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, memory);
+    }
+    let args = vec![device.record(), memory.record()];
+    next(device, memory);
+    push_call("vkUnmapMemory", args, vec![], Value::Null);
+}
+unsafe fn replay_unmap_memory(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkUnmapMemory>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let memory: vk::DeviceMemory = DeepCopyable::replay(&args[1usize], arena)?;
+    f(device, memory);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn unmap_memory2_khr(
+    device: vk::Device,
+    p_memory_unmap_info: *const vk::MemoryUnmapInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUnmapMemory2KHR>(next(
+        458usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_memory_unmap_info);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_memory_unmap_info, 1, |element| element.record()),
+    ];
+    let result = next(device, p_memory_unmap_info);
+    push_call("vkUnmapMemory2KHR", args, vec![], result.record());
+    result
+}
+unsafe fn replay_unmap_memory2_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkUnmapMemory2KHR>(f);
+    let args = args::<2usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_memory_unmap_info: *const vk::MemoryUnmapInfoKHR<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, p_memory_unmap_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn update_descriptor_set_with_template(
+    device: vk::Device,
+    descriptor_set: vk::DescriptorSet,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    p_data: *const c_void,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUpdateDescriptorSetWithTemplate>(next(
+        459usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, descriptor_set, descriptor_update_template, p_data);
+    }
+    let args = vec![
+        device.record(),
+        descriptor_set.record(),
+        descriptor_update_template.record(),
+        p_data.record(),
+    ];
+    next(device, descriptor_set, descriptor_update_template, p_data);
+    push_call(
+        "vkUpdateDescriptorSetWithTemplate",
+        args,
+        vec![],
+        Value::Null,
+    );
+}
+unsafe fn replay_update_descriptor_set_with_template(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkUpdateDescriptorSetWithTemplate>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let descriptor_set: vk::DescriptorSet = DeepCopyable::replay(&args[1usize], arena)?;
+    let descriptor_update_template: vk::DescriptorUpdateTemplate =
+        DeepCopyable::replay(&args[2usize], arena)?;
+    let p_data: *const c_void = DeepCopyable::replay(&args[3usize], arena)?;
+    f(device, descriptor_set, descriptor_update_template, p_data);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn update_descriptor_sets(
+    device: vk::Device,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const vk::WriteDescriptorSet<'_>,
+    descriptor_copy_count: u32,
+    p_descriptor_copies: *const vk::CopyDescriptorSet<'_>,
+) {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUpdateDescriptorSets>(next(
+        460usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            descriptor_write_count,
+            p_descriptor_writes,
+            descriptor_copy_count,
+            p_descriptor_copies,
+        );
+    }
+    let args = vec![
+        device.record(),
+        descriptor_write_count.record(),
+        record_array(
+            p_descriptor_writes,
+            descriptor_write_count as usize,
+            |element| element.record(),
+        ),
+        descriptor_copy_count.record(),
+        record_array(
+            p_descriptor_copies,
+            descriptor_copy_count as usize,
+            |element| element.record(),
+        ),
+    ];
+    next(
+        device,
+        descriptor_write_count,
+        p_descriptor_writes,
+        descriptor_copy_count,
+        p_descriptor_copies,
+    );
+    push_call("vkUpdateDescriptorSets", args, vec![], Value::Null);
+}
+unsafe fn replay_update_descriptor_sets(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkUpdateDescriptorSets>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let descriptor_write_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_descriptor_writes: *const vk::WriteDescriptorSet<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let descriptor_copy_count: u32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let p_descriptor_copies: *const vk::CopyDescriptorSet<'_> =
+        replay_array(&args[4usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    f(
+        device,
+        descriptor_write_count,
+        p_descriptor_writes,
+        descriptor_copy_count,
+        p_descriptor_copies,
+    );
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(Value::Null)
+}
+unsafe extern "system" fn update_video_session_parameters_khr(
+    device: vk::Device,
+    video_session_parameters: vk::VideoSessionParametersKHR,
+    p_update_info: *const vk::VideoSessionParametersUpdateInfoKHR<'_>,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUpdateVideoSessionParametersKHR>(next(
+        461usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, video_session_parameters, p_update_info);
+    }
+    let args = vec![
+        device.record(),
+        video_session_parameters.record(),
+        record_array(p_update_info, 1, |element| element.record()),
+    ];
+    let result = next(device, video_session_parameters, p_update_info);
+    push_call(
+        "vkUpdateVideoSessionParametersKHR",
+        args,
+        vec![],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_update_video_session_parameters_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f =
+        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkUpdateVideoSessionParametersKHR>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let video_session_parameters: vk::VideoSessionParametersKHR =
+        DeepCopyable::replay(&args[1usize], arena)?;
+    let p_update_info: *const vk::VideoSessionParametersUpdateInfoKHR<'_> =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let result = f(device, video_session_parameters, p_update_info);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn wait_for_fences(
+    device: vk::Device,
+    fence_count: u32,
+    p_fences: *const vk::Fence,
+    wait_all: vk::Bool32,
+    timeout: u64,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkWaitForFences>(next(
+        462usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, fence_count, p_fences, wait_all, timeout);
+    }
+    let args = vec![
+        device.record(),
+        fence_count.record(),
+        record_array(p_fences, fence_count as usize, |element| element.record()),
+        wait_all.record(),
+        timeout.record(),
+    ];
+    let result = next(device, fence_count, p_fences, wait_all, timeout);
+    push_call("vkWaitForFences", args, vec![], result.record());
+    result
+}
+unsafe fn replay_wait_for_fences(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkWaitForFences>(f);
+    let args = args::<5usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let fence_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_fences: *const vk::Fence = replay_array(&args[2usize], arena, |value, arena| {
+        DeepCopyable::replay(value, arena)
+    })?;
+    let wait_all: vk::Bool32 = DeepCopyable::replay(&args[3usize], arena)?;
+    let timeout: u64 = DeepCopyable::replay(&args[4usize], arena)?;
+    let result = f(device, fence_count, p_fences, wait_all, timeout);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn wait_for_present_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    present_id: u64,
+    timeout: u64,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkWaitForPresentKHR>(next(
+        463usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, swapchain, present_id, timeout);
+    }
+    let args = vec![
+        device.record(),
+        swapchain.record(),
+        present_id.record(),
+        timeout.record(),
+    ];
+    let result = next(device, swapchain, present_id, timeout);
+    push_call("vkWaitForPresentKHR", args, vec![], result.record());
+    result
+}
+unsafe fn replay_wait_for_present_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkWaitForPresentKHR>(f);
+    let args = args::<4usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let swapchain: vk::SwapchainKHR = DeepCopyable::replay(&args[1usize], arena)?;
+    let present_id: u64 = DeepCopyable::replay(&args[2usize], arena)?;
+    let timeout: u64 = DeepCopyable::replay(&args[3usize], arena)?;
+    let result = f(device, swapchain, present_id, timeout);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn wait_semaphores(
+    device: vk::Device,
+    p_wait_info: *const vk::SemaphoreWaitInfo<'_>,
+    timeout: u64,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkWaitSemaphores>(next(
+        464usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(device, p_wait_info, timeout);
+    }
+    let args = vec![
+        device.record(),
+        record_array(p_wait_info, 1, |element| element.record()),
+        timeout.record(),
+    ];
+    let result = next(device, p_wait_info, timeout);
+    push_call("vkWaitSemaphores", args, vec![], result.record());
+    result
+}
+unsafe fn replay_wait_semaphores(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkWaitSemaphores>(f);
+    let args = args::<3usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let p_wait_info: *const vk::SemaphoreWaitInfo<'_> =
+        replay_array(&args[1usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let timeout: u64 = DeepCopyable::replay(&args[2usize], arena)?;
+    let result = f(device, p_wait_info, timeout);
+    let outputs: Vec<Value> = vec![];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn write_acceleration_structures_properties_khr(
+    device: vk::Device,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureKHR,
+    query_type: vk::QueryType,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: usize,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkWriteAccelerationStructuresPropertiesKHR>(
+        next(465usize, dispatch_key(device.as_raw())),
+    );
+    if !is_recording() {
+        return next(
+            device,
+            acceleration_structure_count,
+            p_acceleration_structures,
+            query_type,
+            data_size,
+            p_data,
+            stride,
+        );
+    }
+    let args = vec![
+        device.record(),
+        acceleration_structure_count.record(),
+        record_array(
+            p_acceleration_structures,
+            acceleration_structure_count as usize,
+            |element| element.record(),
+        ),
+        query_type.record(),
+        data_size.record(),
+        record_data_output(p_data.cast::<u8>(), data_size),
+        stride.record(),
+    ];
+    let result = next(
+        device,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        data_size,
+        p_data,
+        stride,
+    );
+    push_call(
+        "vkWriteAccelerationStructuresPropertiesKHR",
+        args,
+        vec![record_data(p_data.cast::<u8>(), data_size)],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_write_acceleration_structures_properties_khr(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<
+        unsafe extern "system" fn(),
+        vk::PFN_vkWriteAccelerationStructuresPropertiesKHR,
+    >(f);
+    let args = args::<7usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let acceleration_structure_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_acceleration_structures: *const vk::AccelerationStructureKHR =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let query_type: vk::QueryType = DeepCopyable::replay(&args[3usize], arena)?;
+    let data_size: usize = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_data: *mut c_void = replay_data::<u8>(&args[5usize], arena)?.cast::<c_void>();
+    let stride: usize = DeepCopyable::replay(&args[6usize], arena)?;
+    let result = f(
+        device,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        data_size,
+        p_data,
+        stride,
+    );
+    let outputs: Vec<Value> = vec![record_data(p_data.cast::<u8>(), data_size)];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
+unsafe extern "system" fn write_micromaps_properties_ext(
+    device: vk::Device,
+    micromap_count: u32,
+    p_micromaps: *const vk::MicromapEXT,
+    query_type: vk::QueryType,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: usize,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkWriteMicromapsPropertiesEXT>(next(
+        466usize,
+        dispatch_key(device.as_raw()),
+    ));
+    if !is_recording() {
+        return next(
+            device,
+            micromap_count,
+            p_micromaps,
+            query_type,
+            data_size,
+            p_data,
+            stride,
+        );
+    }
+    let args = vec![
+        device.record(),
+        micromap_count.record(),
+        record_array(p_micromaps, micromap_count as usize, |element| {
+            element.record()
+        }),
+        query_type.record(),
+        data_size.record(),
+        record_data_output(p_data.cast::<u8>(), data_size),
+        stride.record(),
+    ];
+    let result = next(
+        device,
+        micromap_count,
+        p_micromaps,
+        query_type,
+        data_size,
+        p_data,
+        stride,
+    );
+    push_call(
+        "vkWriteMicromapsPropertiesEXT",
+        args,
+        vec![record_data(p_data.cast::<u8>(), data_size)],
+        result.record(),
+    );
+    result
+}
+unsafe fn replay_write_micromaps_properties_ext(
+    f: unsafe extern "system" fn(),
+    call: &Call,
+    arena: &mut Arena,
+) -> Result<Value, ValueError> {
+    let f = mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkWriteMicromapsPropertiesEXT>(f);
+    let args = args::<7usize>(call)?;
+    let device: vk::Device = DeepCopyable::replay(&args[0usize], arena)?;
+    let micromap_count: u32 = DeepCopyable::replay(&args[1usize], arena)?;
+    let p_micromaps: *const vk::MicromapEXT =
+        replay_array(&args[2usize], arena, |value, arena| {
+            DeepCopyable::replay(value, arena)
+        })?;
+    let query_type: vk::QueryType = DeepCopyable::replay(&args[3usize], arena)?;
+    let data_size: usize = DeepCopyable::replay(&args[4usize], arena)?;
+    let p_data: *mut c_void = replay_data::<u8>(&args[5usize], arena)?.cast::<c_void>();
+    let stride: usize = DeepCopyable::replay(&args[6usize], arena)?;
+    let result = f(
+        device,
+        micromap_count,
+        p_micromaps,
+        query_type,
+        data_size,
+        p_data,
+        stride,
+    );
+    let outputs: Vec<Value> = vec![record_data(p_data.cast::<u8>(), data_size)];
+    for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+        map_handles(recorded, replayed, arena);
+    }
+    Ok(result.record())
+}
@@ -0,0 +1,513 @@
+//! Tracking of the lifetime of every handle created through an [`Entry`], its [`Instance`]s and
+//! their [`Device`]s, to catch leaks and uses of destroyed handles while debugging.
+//!
+//! A [`Tracker`] wraps an [`Entry`], and every command loaded through the wrapped entry, by
+//! [`Entry::create_instance()`], [`Instance::create_device()`] or an extension wrapper, calls into
+//! a tracking stub that records:
+//!
+//! * The handles that a command creates, allocates or retrieves, as an [`Object`] with its parent
+//!   in `vk.xml`, such as the [`vk::CommandPool`] of a [`vk::CommandBuffer`], and the backtrace of
+//!   the call.
+//! * The handles that `vkDestroy*()` and `vkFree*()` commands destroy. Destroying a command or
+//!   descriptor pool, or resetting a descriptor pool, frees the handles allocated from it.
+//!
+//! Misuses are collected as [`Violation`]s:
+//!
+//! * [`Violation::Leak`]: a handle that was still alive when its parent, such as the
+//!   [`vk::Device`] in [`Device::destroy_device()`] or the [`vk::Instance`] in
+//!   [`Instance::destroy_instance()`], was destroyed.
+//! * [`Violation::DoubleDestroy`]: a handle that was destroyed again.
+//! * [`Violation::UseAfterDestroy`]: a destroyed handle that was passed by value to a command.
+//!
+//! ```no_run
+//! # use ash::{track::Tracker, vk, Entry};
+//! # unsafe fn f(entry: &Entry) -> ash::prelude::VkResult<()> {
+//! let tracker = Tracker::new(entry);
+//! let instance = tracker.entry().create_instance(&Default::default(), None)?;
+//! // ...
+//! instance.destroy_instance(None);
+//! for violation in tracker.take_violations() {
+//!     eprintln!("{violation}");
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! Violations are reported, not prevented: the commands are still called with the offending
+//! handles. Backtraces are captured with [`Backtrace::capture()`], and are thus only resolved when
+//! the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables enable them.
+//!
+//! The stubs forward to the function pointers that were loaded for the instance or device that
+//! the dispatchable handle passed to them belongs to, found through the dispatch table pointer
+//! that the Vulkan loader stores in every dispatchable object, or to those loaded last for
+//! commands without one. Tracking is global to the process: creating a [`Tracker`] forgets the
+//! handles tracked so far.
+//!
+//! Unlike the rest of `ash`, this feature requires Rust 1.65, for [`std::backtrace`].
+
+use crate::prelude::dispatch_key;
+use crate::vk::{self, Handle};
+use crate::Entry;
+#[cfg(doc)]
+use crate::{Device, Instance};
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr};
+use std::fmt;
+use std::mem;
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+#[allow(deprecated)]
+mod generated;
+
+/// The type and raw value of a handle.
+type Key = (vk::ObjectType, u64);
+
+/// A handle recorded by a [`Tracker`].
+#[derive(Clone, Debug)]
+pub struct Object {
+    pub ty: vk::ObjectType,
+    pub raw: u64,
+    /// The type and raw value of the parent of the handle in `vk.xml`, if the command received
+    /// it.
+    pub parent: Option<(vk::ObjectType, u64)>,
+    /// The command that returned the handle, such as `"vkCreateBuffer"`.
+    pub command: &'static str,
+    /// Whether the command created the handle, rather than retrieving an existing one such as a
+    /// [`vk::Queue`], which is not destroyed by the application.
+    pub created: bool,
+    /// Where the command was called.
+    pub backtrace: Arc<Backtrace>,
+}
+
+/// The destruction of a handle.
+#[derive(Clone, Debug)]
+pub struct Destroyed {
+    /// The command that destroyed the handle or its parent, such as `"vkDestroyBuffer"`.
+    pub command: &'static str,
+    /// Where the command was called.
+    pub backtrace: Arc<Backtrace>,
+}
+
+/// A misuse of a handle, see the [module documentation][self].
+#[derive(Clone, Debug)]
+pub enum Violation {
+    /// `object` was alive when `command` destroyed its parent.
+    Leak {
+        command: &'static str,
+        object: Object,
+    },
+    /// `command` destroyed `object` after it was `destroyed`.
+    DoubleDestroy {
+        command: &'static str,
+        object: Object,
+        destroyed: Destroyed,
+    },
+    /// `command` was called with `object` after it was `destroyed`.
+    UseAfterDestroy {
+        command: &'static str,
+        object: Object,
+        destroyed: Destroyed,
+    },
+}
+
+impl Violation {
+    /// The handle that was misused.
+    pub fn object(&self) -> &Object {
+        match self {
+            Self::Leak { object, .. }
+            | Self::DoubleDestroy { object, .. }
+            | Self::UseAfterDestroy { object, .. } => object,
+        }
+    }
+}
+
+fn write_backtrace(f: &mut fmt::Formatter<'_>, what: &str, backtrace: &Backtrace) -> fmt::Result {
+    match backtrace.status() {
+        BacktraceStatus::Captured => write!(f, "\n{what} at:\n{backtrace}"),
+        _ => Ok(()),
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let object = self.object();
+        let (ty, raw) = (object.ty, object.raw);
+        match self {
+            Self::Leak { command, .. } => write!(
+                f,
+                "{ty:?} {raw:#x} from {} was not destroyed before {command}",
+                object.command
+            )?,
+            Self::DoubleDestroy {
+                command, destroyed, ..
+            } => write!(
+                f,
+                "{command} destroyed {ty:?} {raw:#x}, which was already destroyed by {}",
+                destroyed.command
+            )?,
+            Self::UseAfterDestroy {
+                command, destroyed, ..
+            } => write!(
+                f,
+                "{command} was called with {ty:?} {raw:#x}, which was destroyed by {}",
+                destroyed.command
+            )?,
+        }
+        write_backtrace(f, "Created", &object.backtrace)?;
+        if let Self::DoubleDestroy { destroyed, .. } | Self::UseAfterDestroy { destroyed, .. } =
+            self
+        {
+            write_backtrace(f, "Destroyed", &destroyed.backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// The live handles, with the order in which they were recorded.
+    live: HashMap<Key, (u64, Object)>,
+    destroyed: HashMap<Key, (Object, Destroyed)>,
+    violations: Vec<Violation>,
+    next_order: u64,
+}
+
+impl State {
+    /// Moves `key` and its descendants to the destroyed handles, reporting the descendants that
+    /// were created by the application and are not freed along with a pool.
+    fn destroy(&mut self, key: Key, destroyed: &Destroyed) {
+        let mut stack = vec![key];
+        while let Some(parent) = stack.pop() {
+            let report = !matches!(
+                parent.0,
+                vk::ObjectType::COMMAND_POOL | vk::ObjectType::DESCRIPTOR_POOL
+            );
+            self.release_children(parent, destroyed, report, &mut stack);
+        }
+        if let Some((_, object)) = self.live.remove(&key) {
+            self.destroyed.insert(key, (object, destroyed.clone()));
+        }
+    }
+
+    fn release_children(
+        &mut self,
+        parent: Key,
+        destroyed: &Destroyed,
+        report: bool,
+        released: &mut Vec<Key>,
+    ) {
+        let mut children = self
+            .live
+            .iter()
+            .filter(|(_, (_, object))| object.parent == Some(parent))
+            .map(|(&key, &(order, _))| (order, key))
+            .collect::<Vec<_>>();
+        children.sort_unstable();
+        for (_, key) in children {
+            let (_, object) = self.live.remove(&key).unwrap();
+            if report && object.created {
+                self.violations.push(Violation::Leak {
+                    command: destroyed.command,
+                    object: object.clone(),
+                });
+            }
+            self.destroyed.insert(key, (object, destroyed.clone()));
+            released.push(key);
+        }
+    }
+}
+
+/// Whether a [`Tracker`] is alive.
+static TRACKING: AtomicBool = AtomicBool::new(false);
+/// The `vkGetInstanceProcAddr` of the entry wrapped by the last [`Tracker`].
+static GET_INSTANCE_PROC_ADDR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+/// The tracked handles, allocated by the first [`Tracker`] and never freed.
+static STATE: AtomicPtr<Mutex<State>> = AtomicPtr::new(ptr::null_mut());
+
+/// The function pointers that the stubs forward to.
+#[derive(Default)]
+struct Forwarding {
+    /// By dispatch key and command index.
+    next: HashMap<(usize, usize), usize>,
+    /// The pointer loaded last, by command index.
+    last: HashMap<usize, usize>,
+    /// The `vkGetDeviceProcAddr` of the instance that a dispatch key belongs to, for the keys of
+    /// instances and of the devices created from their physical devices.
+    get_device_proc_addr: HashMap<usize, usize>,
+    /// The `vkGetDeviceProcAddr` loaded last.
+    last_get_device_proc_addr: usize,
+}
+
+static FORWARDING: AtomicPtr<Mutex<Forwarding>> = AtomicPtr::new(ptr::null_mut());
+
+/// Allocates a global on first use, which is never freed, and locks it. It remains usable after
+/// a panic.
+fn lock<T: Default>(global: &'static AtomicPtr<Mutex<T>>) -> MutexGuard<'static, T> {
+    let mut value = global.load(Ordering::Acquire);
+    if value.is_null() {
+        let new = Box::into_raw(Box::default());
+        value = match global.compare_exchange(
+            ptr::null_mut(),
+            new,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => new,
+            Err(existing) => {
+                drop(unsafe { Box::from_raw(new) });
+                existing
+            }
+        };
+    }
+    let value = unsafe { &*value };
+    value
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Locks the tracked handles.
+fn state() -> MutexGuard<'static, State> {
+    lock(&STATE)
+}
+
+/// The function pointer that the stub of command `index` forwards to for `key`, falling back to
+/// the one loaded last for keys that no pointer was loaded for. Stubs are only returned after
+/// storing a pointer for them, so there is always one.
+fn next(index: usize, key: usize) -> *mut c_void {
+    let forwarding = lock(&FORWARDING);
+    let next = forwarding
+        .next
+        .get(&(key, index))
+        .or_else(|| forwarding.last.get(&index));
+    *next.unwrap() as *mut c_void
+}
+
+/// Locks the tracked handles if a [`Tracker`] is alive. The functions below are called by the
+/// stubs and never panic, as they run inside `extern "system"` functions.
+fn tracking() -> Option<MutexGuard<'static, State>> {
+    TRACKING.load(Ordering::Relaxed).then(state)
+}
+
+fn key<H: Handle>(handle: H) -> Option<Key> {
+    let raw = handle.as_raw();
+    (raw != 0).then_some((H::TYPE, raw))
+}
+
+/// The parent of the handles created by a command.
+fn parent_of<H: Handle>(handle: H) -> Option<Key> {
+    key(handle)
+}
+
+/// Reports `handle` if it was destroyed.
+fn used<H: Handle>(command: &'static str, handle: H) {
+    let (key, mut state) = match (key(handle), tracking()) {
+        (Some(key), Some(state)) => (key, state),
+        _ => return,
+    };
+    if state.live.contains_key(&key) {
+        return;
+    }
+    if let Some((object, destroyed)) = state.destroyed.get(&key) {
+        let violation = Violation::UseAfterDestroy {
+            command,
+            object: object.clone(),
+            destroyed: destroyed.clone(),
+        };
+        state.violations.push(violation);
+    }
+}
+
+/// Records the destruction of `handle` and of its descendants.
+fn destroy<H: Handle>(command: &'static str, handle: H) {
+    let (key, mut state) = match (key(handle), tracking()) {
+        (Some(key), Some(state)) => (key, state),
+        _ => return,
+    };
+    if state.live.contains_key(&key) {
+        let destroyed = Destroyed {
+            command,
+            backtrace: Arc::new(Backtrace::capture()),
+        };
+        state.destroy(key, &destroyed);
+    } else if let Some((object, destroyed)) = state.destroyed.get(&key) {
+        let violation = Violation::DoubleDestroy {
+            command,
+            object: object.clone(),
+            destroyed: destroyed.clone(),
+        };
+        state.violations.push(violation);
+    }
+}
+
+/// Records the handles freed by resetting the pool `handle`.
+fn reset<H: Handle>(command: &'static str, handle: H) {
+    let (key, mut state) = match (key(handle), tracking()) {
+        (Some(key), Some(state)) => (key, state),
+        _ => return,
+    };
+    let destroyed = Destroyed {
+        command,
+        backtrace: Arc::new(Backtrace::capture()),
+    };
+    state.release_children(key, &destroyed, false, &mut Vec::new());
+}
+
+/// Records a handle returned by `command`.
+///
+/// # Safety
+///
+/// `handle` must be valid if it is a device, as must be its parent.
+unsafe fn created<H: Handle>(command: &'static str, handle: H, parent: Option<Key>, created: bool) {
+    let (key, mut state) = match (key(handle), tracking()) {
+        (Some(key), Some(state)) => (key, state),
+        _ => return,
+    };
+    // Devices are loaded with the `vkGetDeviceProcAddr` of the instance of their physical device
+    if let (vk::ObjectType::DEVICE, Some((vk::ObjectType::PHYSICAL_DEVICE, physical_device))) =
+        (key.0, parent)
+    {
+        let mut forwarding = lock(&FORWARDING);
+        let instance_key = dispatch_key(physical_device);
+        if let Some(&f) = forwarding.get_device_proc_addr.get(&instance_key) {
+            forwarding
+                .get_device_proc_addr
+                .insert(dispatch_key(key.1), f);
+        }
+    }
+    // Retrieving a handle again, such as a queue, keeps its first record
+    if !created && state.live.contains_key(&key) {
+        return;
+    }
+    state.destroyed.remove(&key);
+    let object = Object {
+        ty: key.0,
+        raw: key.1,
+        parent,
+        command,
+        created,
+        backtrace: Arc::new(Backtrace::capture()),
+    };
+    state.next_order += 1;
+    let order = state.next_order;
+    state.live.insert(key, (order, object));
+}
+
+/// Replaces every command but `vkGetInstanceProcAddr` and `vkGetDeviceProcAddr` with a tracking
+/// stub, forwarding to `f` for the dispatch key `key` of the instance or device it was loaded
+/// for.
+fn hook(name: &[u8], key: usize, f: unsafe extern "system" fn()) -> unsafe extern "system" fn() {
+    match name {
+        b"vkGetInstanceProcAddr" => unsafe {
+            mem::transmute::<vk::PFN_vkGetInstanceProcAddr, unsafe extern "system" fn()>(
+                get_instance_proc_addr,
+            )
+        },
+        b"vkGetDeviceProcAddr" => {
+            let mut forwarding = lock(&FORWARDING);
+            forwarding.get_device_proc_addr.insert(key, f as usize);
+            forwarding.last_get_device_proc_addr = f as usize;
+            unsafe {
+                mem::transmute::<vk::PFN_vkGetDeviceProcAddr, unsafe extern "system" fn()>(
+                    get_device_proc_addr,
+                )
+            }
+        }
+        _ => match generated::stub(name) {
+            Some((index, stub)) => {
+                let mut forwarding = lock(&FORWARDING);
+                forwarding.next.insert((key, index), f as usize);
+                forwarding.last.insert(index, f as usize);
+                unsafe { mem::transmute::<*const c_void, unsafe extern "system" fn()>(stub) }
+            }
+            None => f,
+        },
+    }
+}
+
+unsafe extern "system" fn get_instance_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    let next = GET_INSTANCE_PROC_ADDR.load(Ordering::Relaxed);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetInstanceProcAddr>(next);
+    let f = next(instance, p_name)?;
+    let key = dispatch_key(instance.as_raw());
+    Some(hook(CStr::from_ptr(p_name).to_bytes(), key, f))
+}
+
+unsafe extern "system" fn get_device_proc_addr(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    let key = dispatch_key(device.as_raw());
+    let next = {
+        let forwarding = lock(&FORWARDING);
+        *forwarding
+            .get_device_proc_addr
+            .get(&key)
+            .unwrap_or(&forwarding.last_get_device_proc_addr)
+    };
+    let next = mem::transmute::<usize, vk::PFN_vkGetDeviceProcAddr>(next);
+    let f = next(device, p_name)?;
+    Some(hook(CStr::from_ptr(p_name).to_bytes(), key, f))
+}
+
+/// Tracks the handles of the instances and devices created through [`Tracker::entry()`].
+pub struct Tracker {
+    entry: Entry,
+    /// Keeps the library of the wrapped entry loaded.
+    _next: Entry,
+}
+
+impl Tracker {
+    /// Starts tracking the commands loaded through [`Tracker::entry()`], forgetting the handles
+    /// tracked so far.
+    ///
+    /// # Safety
+    ///
+    /// The instances and devices created through [`Tracker::entry()`] must be loaded through it,
+    /// rather than through the `vkGetInstanceProcAddr` of `entry`.
+    pub unsafe fn new(entry: &Entry) -> Self {
+        GET_INSTANCE_PROC_ADDR.store(
+            entry.static_fn().get_instance_proc_addr as *mut c_void,
+            Ordering::Relaxed,
+        );
+        *state() = State::default();
+        TRACKING.store(true, Ordering::Relaxed);
+        Self {
+            entry: Entry::from_static_fn(vk::StaticFn {
+                get_instance_proc_addr,
+            }),
+            _next: entry.clone(),
+        }
+    }
+
+    /// The wrapped entry, whose instances and devices are tracked.
+    pub fn entry(&self) -> &Entry {
+        &self.entry
+    }
+
+    /// The handles that are alive, in the order they were recorded.
+    pub fn live_objects(&self) -> Vec<Object> {
+        let state = state();
+        let mut live = state.live.values().collect::<Vec<_>>();
+        live.sort_unstable_by_key(|(order, _)| *order);
+        live.into_iter().map(|(_, object)| object.clone()).collect()
+    }
+
+    /// Removes the violations found so far.
+    pub fn take_violations(&self) -> Vec<Violation> {
+        mem::take(&mut state().violations)
+    }
+}
+
+impl Drop for Tracker {
+    /// Stops tracking, the instances and devices created through [`Tracker::entry()`] remain
+    /// usable.
+    fn drop(&mut self) {
+        TRACKING.store(false, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,10259 @@
+use super::*;
+use std::os::raw::c_int;
+#[doc = r" Returns the index of a command in the forwarding tables and its tracking stub."]
+pub(super) fn stub(name: &[u8]) -> Option<(usize, *const c_void)> {
+    Some(match name {
+        b"vkAcquireDrmDisplayEXT" => (0usize, acquire_drm_display_ext as *const c_void),
+        b"vkAcquireFullScreenExclusiveModeEXT" => (
+            1usize,
+            acquire_full_screen_exclusive_mode_ext as *const c_void,
+        ),
+        b"vkAcquireImageANDROID" => (2usize, acquire_image_android as *const c_void),
+        b"vkAcquireNextImage2KHR" => (3usize, acquire_next_image2_khr as *const c_void),
+        b"vkAcquireNextImageKHR" => (4usize, acquire_next_image_khr as *const c_void),
+        b"vkAcquirePerformanceConfigurationINTEL" => (
+            5usize,
+            acquire_performance_configuration_intel as *const c_void,
+        ),
+        b"vkAcquireProfilingLockKHR" => (6usize, acquire_profiling_lock_khr as *const c_void),
+        b"vkAcquireWinrtDisplayNV" => (7usize, acquire_winrt_display_nv as *const c_void),
+        b"vkAcquireXlibDisplayEXT" => (8usize, acquire_xlib_display_ext as *const c_void),
+        b"vkAllocateCommandBuffers" => (9usize, allocate_command_buffers as *const c_void),
+        b"vkAllocateDescriptorSets" => (10usize, allocate_descriptor_sets as *const c_void),
+        b"vkAllocateMemory" => (11usize, allocate_memory as *const c_void),
+        b"vkBeginCommandBuffer" => (12usize, begin_command_buffer as *const c_void),
+        b"vkBindAccelerationStructureMemoryNV" => (
+            13usize,
+            bind_acceleration_structure_memory_nv as *const c_void,
+        ),
+        b"vkBindBufferMemory" => (14usize, bind_buffer_memory as *const c_void),
+        b"vkBindBufferMemory2" => (15usize, bind_buffer_memory2 as *const c_void),
+        b"vkBindBufferMemory2KHR" => (15usize, bind_buffer_memory2 as *const c_void),
+        b"vkBindImageMemory" => (16usize, bind_image_memory as *const c_void),
+        b"vkBindImageMemory2" => (17usize, bind_image_memory2 as *const c_void),
+        b"vkBindImageMemory2KHR" => (17usize, bind_image_memory2 as *const c_void),
+        b"vkBindOpticalFlowSessionImageNV" => {
+            (18usize, bind_optical_flow_session_image_nv as *const c_void)
+        }
+        b"vkBindVideoSessionMemoryKHR" => (19usize, bind_video_session_memory_khr as *const c_void),
+        b"vkBuildAccelerationStructuresKHR" => {
+            (20usize, build_acceleration_structures_khr as *const c_void)
+        }
+        b"vkBuildMicromapsEXT" => (21usize, build_micromaps_ext as *const c_void),
+        b"vkCmdBeginConditionalRenderingEXT" => (
+            22usize,
+            cmd_begin_conditional_rendering_ext as *const c_void,
+        ),
+        b"vkCmdBeginDebugUtilsLabelEXT" => {
+            (23usize, cmd_begin_debug_utils_label_ext as *const c_void)
+        }
+        b"vkCmdBeginQuery" => (24usize, cmd_begin_query as *const c_void),
+        b"vkCmdBeginQueryIndexedEXT" => (25usize, cmd_begin_query_indexed_ext as *const c_void),
+        b"vkCmdBeginRenderPass" => (26usize, cmd_begin_render_pass as *const c_void),
+        b"vkCmdBeginRenderPass2" => (27usize, cmd_begin_render_pass2 as *const c_void),
+        b"vkCmdBeginRenderPass2KHR" => (27usize, cmd_begin_render_pass2 as *const c_void),
+        b"vkCmdBeginRendering" => (28usize, cmd_begin_rendering as *const c_void),
+        b"vkCmdBeginRenderingKHR" => (28usize, cmd_begin_rendering as *const c_void),
+        b"vkCmdBeginTransformFeedbackEXT" => {
+            (29usize, cmd_begin_transform_feedback_ext as *const c_void)
+        }
+        b"vkCmdBeginVideoCodingKHR" => (30usize, cmd_begin_video_coding_khr as *const c_void),
+        b"vkCmdBindDescriptorBufferEmbeddedSamplersEXT" => (
+            31usize,
+            cmd_bind_descriptor_buffer_embedded_samplers_ext as *const c_void,
+        ),
+        b"vkCmdBindDescriptorBuffersEXT" => {
+            (32usize, cmd_bind_descriptor_buffers_ext as *const c_void)
+        }
+        b"vkCmdBindDescriptorSets" => (33usize, cmd_bind_descriptor_sets as *const c_void),
+        b"vkCmdBindIndexBuffer" => (34usize, cmd_bind_index_buffer as *const c_void),
+        b"vkCmdBindInvocationMaskHUAWEI" => {
+            (35usize, cmd_bind_invocation_mask_huawei as *const c_void)
+        }
+        b"vkCmdBindPipeline" => (36usize, cmd_bind_pipeline as *const c_void),
+        b"vkCmdBindPipelineShaderGroupNV" => {
+            (37usize, cmd_bind_pipeline_shader_group_nv as *const c_void)
+        }
+        b"vkCmdBindShadersEXT" => (38usize, cmd_bind_shaders_ext as *const c_void),
+        b"vkCmdBindShadingRateImageNV" => {
+            (39usize, cmd_bind_shading_rate_image_nv as *const c_void)
+        }
+        b"vkCmdBindTransformFeedbackBuffersEXT" => (
+            40usize,
+            cmd_bind_transform_feedback_buffers_ext as *const c_void,
+        ),
+        b"vkCmdBindVertexBuffers" => (41usize, cmd_bind_vertex_buffers as *const c_void),
+        b"vkCmdBindVertexBuffers2" => (42usize, cmd_bind_vertex_buffers2 as *const c_void),
+        b"vkCmdBindVertexBuffers2EXT" => (42usize, cmd_bind_vertex_buffers2 as *const c_void),
+        b"vkCmdBlitImage" => (43usize, cmd_blit_image as *const c_void),
+        b"vkCmdBlitImage2" => (44usize, cmd_blit_image2 as *const c_void),
+        b"vkCmdBlitImage2KHR" => (44usize, cmd_blit_image2 as *const c_void),
+        b"vkCmdBuildAccelerationStructureNV" => (
+            45usize,
+            cmd_build_acceleration_structure_nv as *const c_void,
+        ),
+        b"vkCmdBuildAccelerationStructuresIndirectKHR" => (
+            46usize,
+            cmd_build_acceleration_structures_indirect_khr as *const c_void,
+        ),
+        b"vkCmdBuildAccelerationStructuresKHR" => (
+            47usize,
+            cmd_build_acceleration_structures_khr as *const c_void,
+        ),
+        b"vkCmdBuildMicromapsEXT" => (48usize, cmd_build_micromaps_ext as *const c_void),
+        b"vkCmdClearAttachments" => (49usize, cmd_clear_attachments as *const c_void),
+        b"vkCmdClearColorImage" => (50usize, cmd_clear_color_image as *const c_void),
+        b"vkCmdClearDepthStencilImage" => (51usize, cmd_clear_depth_stencil_image as *const c_void),
+        b"vkCmdControlVideoCodingKHR" => (52usize, cmd_control_video_coding_khr as *const c_void),
+        b"vkCmdCopyAccelerationStructureKHR" => (
+            53usize,
+            cmd_copy_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCmdCopyAccelerationStructureNV" => {
+            (54usize, cmd_copy_acceleration_structure_nv as *const c_void)
+        }
+        b"vkCmdCopyAccelerationStructureToMemoryKHR" => (
+            55usize,
+            cmd_copy_acceleration_structure_to_memory_khr as *const c_void,
+        ),
+        b"vkCmdCopyBuffer" => (56usize, cmd_copy_buffer as *const c_void),
+        b"vkCmdCopyBuffer2" => (57usize, cmd_copy_buffer2 as *const c_void),
+        b"vkCmdCopyBuffer2KHR" => (57usize, cmd_copy_buffer2 as *const c_void),
+        b"vkCmdCopyBufferToImage" => (58usize, cmd_copy_buffer_to_image as *const c_void),
+        b"vkCmdCopyBufferToImage2" => (59usize, cmd_copy_buffer_to_image2 as *const c_void),
+        b"vkCmdCopyBufferToImage2KHR" => (59usize, cmd_copy_buffer_to_image2 as *const c_void),
+        b"vkCmdCopyImage" => (60usize, cmd_copy_image as *const c_void),
+        b"vkCmdCopyImage2" => (61usize, cmd_copy_image2 as *const c_void),
+        b"vkCmdCopyImage2KHR" => (61usize, cmd_copy_image2 as *const c_void),
+        b"vkCmdCopyImageToBuffer" => (62usize, cmd_copy_image_to_buffer as *const c_void),
+        b"vkCmdCopyImageToBuffer2" => (63usize, cmd_copy_image_to_buffer2 as *const c_void),
+        b"vkCmdCopyImageToBuffer2KHR" => (63usize, cmd_copy_image_to_buffer2 as *const c_void),
+        b"vkCmdCopyMemoryIndirectNV" => (64usize, cmd_copy_memory_indirect_nv as *const c_void),
+        b"vkCmdCopyMemoryToAccelerationStructureKHR" => (
+            65usize,
+            cmd_copy_memory_to_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCmdCopyMemoryToImageIndirectNV" => (
+            66usize,
+            cmd_copy_memory_to_image_indirect_nv as *const c_void,
+        ),
+        b"vkCmdCopyMemoryToMicromapEXT" => {
+            (67usize, cmd_copy_memory_to_micromap_ext as *const c_void)
+        }
+        b"vkCmdCopyMicromapEXT" => (68usize, cmd_copy_micromap_ext as *const c_void),
+        b"vkCmdCopyMicromapToMemoryEXT" => {
+            (69usize, cmd_copy_micromap_to_memory_ext as *const c_void)
+        }
+        b"vkCmdCopyQueryPoolResults" => (70usize, cmd_copy_query_pool_results as *const c_void),
+        b"vkCmdCuLaunchKernelNVX" => (71usize, cmd_cu_launch_kernel_nvx as *const c_void),
+        b"vkCmdDebugMarkerBeginEXT" => (72usize, cmd_debug_marker_begin_ext as *const c_void),
+        b"vkCmdDebugMarkerEndEXT" => (73usize, cmd_debug_marker_end_ext as *const c_void),
+        b"vkCmdDebugMarkerInsertEXT" => (74usize, cmd_debug_marker_insert_ext as *const c_void),
+        b"vkCmdDecodeVideoKHR" => (75usize, cmd_decode_video_khr as *const c_void),
+        b"vkCmdDecompressMemoryIndirectCountNV" => (
+            76usize,
+            cmd_decompress_memory_indirect_count_nv as *const c_void,
+        ),
+        b"vkCmdDecompressMemoryNV" => (77usize, cmd_decompress_memory_nv as *const c_void),
+        b"vkCmdDispatch" => (78usize, cmd_dispatch as *const c_void),
+        b"vkCmdDispatchBase" => (79usize, cmd_dispatch_base as *const c_void),
+        b"vkCmdDispatchBaseKHR" => (79usize, cmd_dispatch_base as *const c_void),
+        b"vkCmdDispatchIndirect" => (80usize, cmd_dispatch_indirect as *const c_void),
+        b"vkCmdDraw" => (81usize, cmd_draw as *const c_void),
+        b"vkCmdDrawClusterHUAWEI" => (82usize, cmd_draw_cluster_huawei as *const c_void),
+        b"vkCmdDrawClusterIndirectHUAWEI" => {
+            (83usize, cmd_draw_cluster_indirect_huawei as *const c_void)
+        }
+        b"vkCmdDrawIndexed" => (84usize, cmd_draw_indexed as *const c_void),
+        b"vkCmdDrawIndexedIndirect" => (85usize, cmd_draw_indexed_indirect as *const c_void),
+        b"vkCmdDrawIndexedIndirectCount" => {
+            (86usize, cmd_draw_indexed_indirect_count as *const c_void)
+        }
+        b"vkCmdDrawIndexedIndirectCountAMD" => {
+            (86usize, cmd_draw_indexed_indirect_count as *const c_void)
+        }
+        b"vkCmdDrawIndexedIndirectCountKHR" => {
+            (86usize, cmd_draw_indexed_indirect_count as *const c_void)
+        }
+        b"vkCmdDrawIndirect" => (87usize, cmd_draw_indirect as *const c_void),
+        b"vkCmdDrawIndirectByteCountEXT" => {
+            (88usize, cmd_draw_indirect_byte_count_ext as *const c_void)
+        }
+        b"vkCmdDrawIndirectCount" => (89usize, cmd_draw_indirect_count as *const c_void),
+        b"vkCmdDrawIndirectCountAMD" => (89usize, cmd_draw_indirect_count as *const c_void),
+        b"vkCmdDrawIndirectCountKHR" => (89usize, cmd_draw_indirect_count as *const c_void),
+        b"vkCmdDrawMeshTasksEXT" => (90usize, cmd_draw_mesh_tasks_ext as *const c_void),
+        b"vkCmdDrawMeshTasksIndirectCountEXT" => (
+            91usize,
+            cmd_draw_mesh_tasks_indirect_count_ext as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksIndirectCountNV" => (
+            92usize,
+            cmd_draw_mesh_tasks_indirect_count_nv as *const c_void,
+        ),
+        b"vkCmdDrawMeshTasksIndirectEXT" => {
+            (93usize, cmd_draw_mesh_tasks_indirect_ext as *const c_void)
+        }
+        b"vkCmdDrawMeshTasksIndirectNV" => {
+            (94usize, cmd_draw_mesh_tasks_indirect_nv as *const c_void)
+        }
+        b"vkCmdDrawMeshTasksNV" => (95usize, cmd_draw_mesh_tasks_nv as *const c_void),
+        b"vkCmdDrawMultiEXT" => (96usize, cmd_draw_multi_ext as *const c_void),
+        b"vkCmdDrawMultiIndexedEXT" => (97usize, cmd_draw_multi_indexed_ext as *const c_void),
+        b"vkCmdEncodeVideoKHR" => (98usize, cmd_encode_video_khr as *const c_void),
+        b"vkCmdEndConditionalRenderingEXT" => {
+            (99usize, cmd_end_conditional_rendering_ext as *const c_void)
+        }
+        b"vkCmdEndDebugUtilsLabelEXT" => (100usize, cmd_end_debug_utils_label_ext as *const c_void),
+        b"vkCmdEndQuery" => (101usize, cmd_end_query as *const c_void),
+        b"vkCmdEndQueryIndexedEXT" => (102usize, cmd_end_query_indexed_ext as *const c_void),
+        b"vkCmdEndRenderPass" => (103usize, cmd_end_render_pass as *const c_void),
+        b"vkCmdEndRenderPass2" => (104usize, cmd_end_render_pass2 as *const c_void),
+        b"vkCmdEndRenderPass2KHR" => (104usize, cmd_end_render_pass2 as *const c_void),
+        b"vkCmdEndRendering" => (105usize, cmd_end_rendering as *const c_void),
+        b"vkCmdEndRenderingKHR" => (105usize, cmd_end_rendering as *const c_void),
+        b"vkCmdEndTransformFeedbackEXT" => {
+            (106usize, cmd_end_transform_feedback_ext as *const c_void)
+        }
+        b"vkCmdEndVideoCodingKHR" => (107usize, cmd_end_video_coding_khr as *const c_void),
+        b"vkCmdExecuteCommands" => (108usize, cmd_execute_commands as *const c_void),
+        b"vkCmdExecuteGeneratedCommandsNV" => {
+            (109usize, cmd_execute_generated_commands_nv as *const c_void)
+        }
+        b"vkCmdFillBuffer" => (110usize, cmd_fill_buffer as *const c_void),
+        b"vkCmdInsertDebugUtilsLabelEXT" => {
+            (111usize, cmd_insert_debug_utils_label_ext as *const c_void)
+        }
+        b"vkCmdNextSubpass" => (112usize, cmd_next_subpass as *const c_void),
+        b"vkCmdNextSubpass2" => (113usize, cmd_next_subpass2 as *const c_void),
+        b"vkCmdNextSubpass2KHR" => (113usize, cmd_next_subpass2 as *const c_void),
+        b"vkCmdOpticalFlowExecuteNV" => (114usize, cmd_optical_flow_execute_nv as *const c_void),
+        b"vkCmdPipelineBarrier" => (115usize, cmd_pipeline_barrier as *const c_void),
+        b"vkCmdPipelineBarrier2" => (116usize, cmd_pipeline_barrier2 as *const c_void),
+        b"vkCmdPipelineBarrier2KHR" => (116usize, cmd_pipeline_barrier2 as *const c_void),
+        b"vkCmdPreprocessGeneratedCommandsNV" => (
+            117usize,
+            cmd_preprocess_generated_commands_nv as *const c_void,
+        ),
+        b"vkCmdPushConstants" => (118usize, cmd_push_constants as *const c_void),
+        b"vkCmdPushDescriptorSetKHR" => (119usize, cmd_push_descriptor_set_khr as *const c_void),
+        b"vkCmdPushDescriptorSetWithTemplateKHR" => (
+            120usize,
+            cmd_push_descriptor_set_with_template_khr as *const c_void,
+        ),
+        b"vkCmdResetEvent" => (121usize, cmd_reset_event as *const c_void),
+        b"vkCmdResetEvent2" => (122usize, cmd_reset_event2 as *const c_void),
+        b"vkCmdResetEvent2KHR" => (122usize, cmd_reset_event2 as *const c_void),
+        b"vkCmdResetQueryPool" => (123usize, cmd_reset_query_pool as *const c_void),
+        b"vkCmdResolveImage" => (124usize, cmd_resolve_image as *const c_void),
+        b"vkCmdResolveImage2" => (125usize, cmd_resolve_image2 as *const c_void),
+        b"vkCmdResolveImage2KHR" => (125usize, cmd_resolve_image2 as *const c_void),
+        b"vkCmdSetAlphaToCoverageEnableEXT" => (
+            126usize,
+            cmd_set_alpha_to_coverage_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetAlphaToOneEnableEXT" => {
+            (127usize, cmd_set_alpha_to_one_enable_ext as *const c_void)
+        }
+        b"vkCmdSetAttachmentFeedbackLoopEnableEXT" => (
+            128usize,
+            cmd_set_attachment_feedback_loop_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetBlendConstants" => (129usize, cmd_set_blend_constants as *const c_void),
+        b"vkCmdSetCheckpointNV" => (130usize, cmd_set_checkpoint_nv as *const c_void),
+        b"vkCmdSetCoarseSampleOrderNV" => {
+            (131usize, cmd_set_coarse_sample_order_nv as *const c_void)
+        }
+        b"vkCmdSetColorBlendAdvancedEXT" => {
+            (132usize, cmd_set_color_blend_advanced_ext as *const c_void)
+        }
+        b"vkCmdSetColorBlendEnableEXT" => {
+            (133usize, cmd_set_color_blend_enable_ext as *const c_void)
+        }
+        b"vkCmdSetColorBlendEquationEXT" => {
+            (134usize, cmd_set_color_blend_equation_ext as *const c_void)
+        }
+        b"vkCmdSetColorWriteEnableEXT" => {
+            (135usize, cmd_set_color_write_enable_ext as *const c_void)
+        }
+        b"vkCmdSetColorWriteMaskEXT" => (136usize, cmd_set_color_write_mask_ext as *const c_void),
+        b"vkCmdSetConservativeRasterizationModeEXT" => (
+            137usize,
+            cmd_set_conservative_rasterization_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetCoverageModulationModeNV" => (
+            138usize,
+            cmd_set_coverage_modulation_mode_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageModulationTableEnableNV" => (
+            139usize,
+            cmd_set_coverage_modulation_table_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageModulationTableNV" => (
+            140usize,
+            cmd_set_coverage_modulation_table_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageReductionModeNV" => (
+            141usize,
+            cmd_set_coverage_reduction_mode_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageToColorEnableNV" => (
+            142usize,
+            cmd_set_coverage_to_color_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetCoverageToColorLocationNV" => (
+            143usize,
+            cmd_set_coverage_to_color_location_nv as *const c_void,
+        ),
+        b"vkCmdSetCullMode" => (144usize, cmd_set_cull_mode as *const c_void),
+        b"vkCmdSetCullModeEXT" => (144usize, cmd_set_cull_mode as *const c_void),
+        b"vkCmdSetDepthBias" => (145usize, cmd_set_depth_bias as *const c_void),
+        b"vkCmdSetDepthBias2EXT" => (146usize, cmd_set_depth_bias2_ext as *const c_void),
+        b"vkCmdSetDepthBiasEnable" => (147usize, cmd_set_depth_bias_enable as *const c_void),
+        b"vkCmdSetDepthBiasEnableEXT" => (147usize, cmd_set_depth_bias_enable as *const c_void),
+        b"vkCmdSetDepthBounds" => (148usize, cmd_set_depth_bounds as *const c_void),
+        b"vkCmdSetDepthBoundsTestEnable" => {
+            (149usize, cmd_set_depth_bounds_test_enable as *const c_void)
+        }
+        b"vkCmdSetDepthBoundsTestEnableEXT" => {
+            (149usize, cmd_set_depth_bounds_test_enable as *const c_void)
+        }
+        b"vkCmdSetDepthClampEnableEXT" => {
+            (150usize, cmd_set_depth_clamp_enable_ext as *const c_void)
+        }
+        b"vkCmdSetDepthClipEnableEXT" => (151usize, cmd_set_depth_clip_enable_ext as *const c_void),
+        b"vkCmdSetDepthClipNegativeOneToOneEXT" => (
+            152usize,
+            cmd_set_depth_clip_negative_one_to_one_ext as *const c_void,
+        ),
+        b"vkCmdSetDepthCompareOp" => (153usize, cmd_set_depth_compare_op as *const c_void),
+        b"vkCmdSetDepthCompareOpEXT" => (153usize, cmd_set_depth_compare_op as *const c_void),
+        b"vkCmdSetDepthTestEnable" => (154usize, cmd_set_depth_test_enable as *const c_void),
+        b"vkCmdSetDepthTestEnableEXT" => (154usize, cmd_set_depth_test_enable as *const c_void),
+        b"vkCmdSetDepthWriteEnable" => (155usize, cmd_set_depth_write_enable as *const c_void),
+        b"vkCmdSetDepthWriteEnableEXT" => (155usize, cmd_set_depth_write_enable as *const c_void),
+        b"vkCmdSetDescriptorBufferOffsetsEXT" => (
+            156usize,
+            cmd_set_descriptor_buffer_offsets_ext as *const c_void,
+        ),
+        b"vkCmdSetDeviceMask" => (157usize, cmd_set_device_mask as *const c_void),
+        b"vkCmdSetDeviceMaskKHR" => (157usize, cmd_set_device_mask as *const c_void),
+        b"vkCmdSetDiscardRectangleEXT" => {
+            (158usize, cmd_set_discard_rectangle_ext as *const c_void)
+        }
+        b"vkCmdSetDiscardRectangleEnableEXT" => (
+            159usize,
+            cmd_set_discard_rectangle_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetDiscardRectangleModeEXT" => (
+            160usize,
+            cmd_set_discard_rectangle_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetEvent" => (161usize, cmd_set_event as *const c_void),
+        b"vkCmdSetEvent2" => (162usize, cmd_set_event2 as *const c_void),
+        b"vkCmdSetEvent2KHR" => (162usize, cmd_set_event2 as *const c_void),
+        b"vkCmdSetExclusiveScissorEnableNV" => (
+            163usize,
+            cmd_set_exclusive_scissor_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetExclusiveScissorNV" => (164usize, cmd_set_exclusive_scissor_nv as *const c_void),
+        b"vkCmdSetExtraPrimitiveOverestimationSizeEXT" => (
+            165usize,
+            cmd_set_extra_primitive_overestimation_size_ext as *const c_void,
+        ),
+        b"vkCmdSetFragmentShadingRateEnumNV" => (
+            166usize,
+            cmd_set_fragment_shading_rate_enum_nv as *const c_void,
+        ),
+        b"vkCmdSetFragmentShadingRateKHR" => {
+            (167usize, cmd_set_fragment_shading_rate_khr as *const c_void)
+        }
+        b"vkCmdSetFrontFace" => (168usize, cmd_set_front_face as *const c_void),
+        b"vkCmdSetFrontFaceEXT" => (168usize, cmd_set_front_face as *const c_void),
+        b"vkCmdSetLineRasterizationModeEXT" => (
+            169usize,
+            cmd_set_line_rasterization_mode_ext as *const c_void,
+        ),
+        b"vkCmdSetLineStippleEXT" => (170usize, cmd_set_line_stipple_ext as *const c_void),
+        b"vkCmdSetLineStippleEnableEXT" => {
+            (171usize, cmd_set_line_stipple_enable_ext as *const c_void)
+        }
+        b"vkCmdSetLineWidth" => (172usize, cmd_set_line_width as *const c_void),
+        b"vkCmdSetLogicOpEXT" => (173usize, cmd_set_logic_op_ext as *const c_void),
+        b"vkCmdSetLogicOpEnableEXT" => (174usize, cmd_set_logic_op_enable_ext as *const c_void),
+        b"vkCmdSetPatchControlPointsEXT" => {
+            (175usize, cmd_set_patch_control_points_ext as *const c_void)
+        }
+        b"vkCmdSetPerformanceMarkerINTEL" => {
+            (176usize, cmd_set_performance_marker_intel as *const c_void)
+        }
+        b"vkCmdSetPerformanceOverrideINTEL" => (
+            177usize,
+            cmd_set_performance_override_intel as *const c_void,
+        ),
+        b"vkCmdSetPerformanceStreamMarkerINTEL" => (
+            178usize,
+            cmd_set_performance_stream_marker_intel as *const c_void,
+        ),
+        b"vkCmdSetPolygonModeEXT" => (179usize, cmd_set_polygon_mode_ext as *const c_void),
+        b"vkCmdSetPrimitiveRestartEnable" => {
+            (180usize, cmd_set_primitive_restart_enable as *const c_void)
+        }
+        b"vkCmdSetPrimitiveRestartEnableEXT" => {
+            (180usize, cmd_set_primitive_restart_enable as *const c_void)
+        }
+        b"vkCmdSetPrimitiveTopology" => (181usize, cmd_set_primitive_topology as *const c_void),
+        b"vkCmdSetPrimitiveTopologyEXT" => (181usize, cmd_set_primitive_topology as *const c_void),
+        b"vkCmdSetProvokingVertexModeEXT" => {
+            (182usize, cmd_set_provoking_vertex_mode_ext as *const c_void)
+        }
+        b"vkCmdSetRasterizationSamplesEXT" => {
+            (183usize, cmd_set_rasterization_samples_ext as *const c_void)
+        }
+        b"vkCmdSetRasterizationStreamEXT" => {
+            (184usize, cmd_set_rasterization_stream_ext as *const c_void)
+        }
+        b"vkCmdSetRasterizerDiscardEnable" => {
+            (185usize, cmd_set_rasterizer_discard_enable as *const c_void)
+        }
+        b"vkCmdSetRasterizerDiscardEnableEXT" => {
+            (185usize, cmd_set_rasterizer_discard_enable as *const c_void)
+        }
+        b"vkCmdSetRayTracingPipelineStackSizeKHR" => (
+            186usize,
+            cmd_set_ray_tracing_pipeline_stack_size_khr as *const c_void,
+        ),
+        b"vkCmdSetRepresentativeFragmentTestEnableNV" => (
+            187usize,
+            cmd_set_representative_fragment_test_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetSampleLocationsEXT" => (188usize, cmd_set_sample_locations_ext as *const c_void),
+        b"vkCmdSetSampleLocationsEnableEXT" => (
+            189usize,
+            cmd_set_sample_locations_enable_ext as *const c_void,
+        ),
+        b"vkCmdSetSampleMaskEXT" => (190usize, cmd_set_sample_mask_ext as *const c_void),
+        b"vkCmdSetScissor" => (191usize, cmd_set_scissor as *const c_void),
+        b"vkCmdSetScissorWithCount" => (192usize, cmd_set_scissor_with_count as *const c_void),
+        b"vkCmdSetScissorWithCountEXT" => (192usize, cmd_set_scissor_with_count as *const c_void),
+        b"vkCmdSetShadingRateImageEnableNV" => (
+            193usize,
+            cmd_set_shading_rate_image_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetStencilCompareMask" => (194usize, cmd_set_stencil_compare_mask as *const c_void),
+        b"vkCmdSetStencilOp" => (195usize, cmd_set_stencil_op as *const c_void),
+        b"vkCmdSetStencilOpEXT" => (195usize, cmd_set_stencil_op as *const c_void),
+        b"vkCmdSetStencilReference" => (196usize, cmd_set_stencil_reference as *const c_void),
+        b"vkCmdSetStencilTestEnable" => (197usize, cmd_set_stencil_test_enable as *const c_void),
+        b"vkCmdSetStencilTestEnableEXT" => (197usize, cmd_set_stencil_test_enable as *const c_void),
+        b"vkCmdSetStencilWriteMask" => (198usize, cmd_set_stencil_write_mask as *const c_void),
+        b"vkCmdSetTessellationDomainOriginEXT" => (
+            199usize,
+            cmd_set_tessellation_domain_origin_ext as *const c_void,
+        ),
+        b"vkCmdSetVertexInputEXT" => (200usize, cmd_set_vertex_input_ext as *const c_void),
+        b"vkCmdSetViewport" => (201usize, cmd_set_viewport as *const c_void),
+        b"vkCmdSetViewportShadingRatePaletteNV" => (
+            202usize,
+            cmd_set_viewport_shading_rate_palette_nv as *const c_void,
+        ),
+        b"vkCmdSetViewportSwizzleNV" => (203usize, cmd_set_viewport_swizzle_nv as *const c_void),
+        b"vkCmdSetViewportWScalingEnableNV" => (
+            204usize,
+            cmd_set_viewport_w_scaling_enable_nv as *const c_void,
+        ),
+        b"vkCmdSetViewportWScalingNV" => (205usize, cmd_set_viewport_w_scaling_nv as *const c_void),
+        b"vkCmdSetViewportWithCount" => (206usize, cmd_set_viewport_with_count as *const c_void),
+        b"vkCmdSetViewportWithCountEXT" => (206usize, cmd_set_viewport_with_count as *const c_void),
+        b"vkCmdSubpassShadingHUAWEI" => (207usize, cmd_subpass_shading_huawei as *const c_void),
+        b"vkCmdTraceRaysIndirect2KHR" => (208usize, cmd_trace_rays_indirect2_khr as *const c_void),
+        b"vkCmdTraceRaysIndirectKHR" => (209usize, cmd_trace_rays_indirect_khr as *const c_void),
+        b"vkCmdTraceRaysKHR" => (210usize, cmd_trace_rays_khr as *const c_void),
+        b"vkCmdTraceRaysNV" => (211usize, cmd_trace_rays_nv as *const c_void),
+        b"vkCmdUpdateBuffer" => (212usize, cmd_update_buffer as *const c_void),
+        b"vkCmdWaitEvents" => (213usize, cmd_wait_events as *const c_void),
+        b"vkCmdWaitEvents2" => (214usize, cmd_wait_events2 as *const c_void),
+        b"vkCmdWaitEvents2KHR" => (214usize, cmd_wait_events2 as *const c_void),
+        b"vkCmdWriteAccelerationStructuresPropertiesKHR" => (
+            215usize,
+            cmd_write_acceleration_structures_properties_khr as *const c_void,
+        ),
+        b"vkCmdWriteAccelerationStructuresPropertiesNV" => (
+            216usize,
+            cmd_write_acceleration_structures_properties_nv as *const c_void,
+        ),
+        b"vkCmdWriteBufferMarker2AMD" => (217usize, cmd_write_buffer_marker2_amd as *const c_void),
+        b"vkCmdWriteBufferMarkerAMD" => (218usize, cmd_write_buffer_marker_amd as *const c_void),
+        b"vkCmdWriteMicromapsPropertiesEXT" => (
+            219usize,
+            cmd_write_micromaps_properties_ext as *const c_void,
+        ),
+        b"vkCmdWriteTimestamp" => (220usize, cmd_write_timestamp as *const c_void),
+        b"vkCmdWriteTimestamp2" => (221usize, cmd_write_timestamp2 as *const c_void),
+        b"vkCmdWriteTimestamp2KHR" => (221usize, cmd_write_timestamp2 as *const c_void),
+        b"vkCompileDeferredNV" => (222usize, compile_deferred_nv as *const c_void),
+        b"vkCopyAccelerationStructureKHR" => {
+            (223usize, copy_acceleration_structure_khr as *const c_void)
+        }
+        b"vkCopyAccelerationStructureToMemoryKHR" => (
+            224usize,
+            copy_acceleration_structure_to_memory_khr as *const c_void,
+        ),
+        b"vkCopyMemoryToAccelerationStructureKHR" => (
+            225usize,
+            copy_memory_to_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkCopyMemoryToMicromapEXT" => (226usize, copy_memory_to_micromap_ext as *const c_void),
+        b"vkCopyMicromapEXT" => (227usize, copy_micromap_ext as *const c_void),
+        b"vkCopyMicromapToMemoryEXT" => (228usize, copy_micromap_to_memory_ext as *const c_void),
+        b"vkCreateAccelerationStructureKHR" => {
+            (229usize, create_acceleration_structure_khr as *const c_void)
+        }
+        b"vkCreateAccelerationStructureNV" => {
+            (230usize, create_acceleration_structure_nv as *const c_void)
+        }
+        b"vkCreateAndroidSurfaceKHR" => (231usize, create_android_surface_khr as *const c_void),
+        b"vkCreateBuffer" => (232usize, create_buffer as *const c_void),
+        b"vkCreateBufferCollectionFUCHSIA" => {
+            (233usize, create_buffer_collection_fuchsia as *const c_void)
+        }
+        b"vkCreateBufferView" => (234usize, create_buffer_view as *const c_void),
+        b"vkCreateCommandPool" => (235usize, create_command_pool as *const c_void),
+        b"vkCreateComputePipelines" => (236usize, create_compute_pipelines as *const c_void),
+        b"vkCreateCuFunctionNVX" => (237usize, create_cu_function_nvx as *const c_void),
+        b"vkCreateCuModuleNVX" => (238usize, create_cu_module_nvx as *const c_void),
+        b"vkCreateDebugReportCallbackEXT" => {
+            (239usize, create_debug_report_callback_ext as *const c_void)
+        }
+        b"vkCreateDebugUtilsMessengerEXT" => {
+            (240usize, create_debug_utils_messenger_ext as *const c_void)
+        }
+        b"vkCreateDeferredOperationKHR" => {
+            (241usize, create_deferred_operation_khr as *const c_void)
+        }
+        b"vkCreateDescriptorPool" => (242usize, create_descriptor_pool as *const c_void),
+        b"vkCreateDescriptorSetLayout" => (243usize, create_descriptor_set_layout as *const c_void),
+        b"vkCreateDescriptorUpdateTemplate" => {
+            (244usize, create_descriptor_update_template as *const c_void)
+        }
+        b"vkCreateDescriptorUpdateTemplateKHR" => {
+            (244usize, create_descriptor_update_template as *const c_void)
+        }
+        b"vkCreateDevice" => (245usize, create_device as *const c_void),
+        b"vkCreateDirectFBSurfaceEXT" => (246usize, create_direct_fb_surface_ext as *const c_void),
+        b"vkCreateDisplayModeKHR" => (247usize, create_display_mode_khr as *const c_void),
+        b"vkCreateDisplayPlaneSurfaceKHR" => {
+            (248usize, create_display_plane_surface_khr as *const c_void)
+        }
+        b"vkCreateEvent" => (249usize, create_event as *const c_void),
+        b"vkCreateFence" => (250usize, create_fence as *const c_void),
+        b"vkCreateFramebuffer" => (251usize, create_framebuffer as *const c_void),
+        b"vkCreateGraphicsPipelines" => (252usize, create_graphics_pipelines as *const c_void),
+        b"vkCreateHeadlessSurfaceEXT" => (253usize, create_headless_surface_ext as *const c_void),
+        b"vkCreateIOSSurfaceMVK" => (254usize, create_ios_surface_mvk as *const c_void),
+        b"vkCreateImage" => (255usize, create_image as *const c_void),
+        b"vkCreateImagePipeSurfaceFUCHSIA" => {
+            (256usize, create_image_pipe_surface_fuchsia as *const c_void)
+        }
+        b"vkCreateImageView" => (257usize, create_image_view as *const c_void),
+        b"vkCreateIndirectCommandsLayoutNV" => (
+            258usize,
+            create_indirect_commands_layout_nv as *const c_void,
+        ),
+        b"vkCreateInstance" => (259usize, create_instance as *const c_void),
+        b"vkCreateMacOSSurfaceMVK" => (260usize, create_mac_os_surface_mvk as *const c_void),
+        b"vkCreateMetalSurfaceEXT" => (261usize, create_metal_surface_ext as *const c_void),
+        b"vkCreateMicromapEXT" => (262usize, create_micromap_ext as *const c_void),
+        b"vkCreateOpticalFlowSessionNV" => {
+            (263usize, create_optical_flow_session_nv as *const c_void)
+        }
+        b"vkCreatePipelineCache" => (264usize, create_pipeline_cache as *const c_void),
+        b"vkCreatePipelineLayout" => (265usize, create_pipeline_layout as *const c_void),
+        b"vkCreatePrivateDataSlot" => (266usize, create_private_data_slot as *const c_void),
+        b"vkCreatePrivateDataSlotEXT" => (266usize, create_private_data_slot as *const c_void),
+        b"vkCreateQueryPool" => (267usize, create_query_pool as *const c_void),
+        b"vkCreateRayTracingPipelinesKHR" => {
+            (268usize, create_ray_tracing_pipelines_khr as *const c_void)
+        }
+        b"vkCreateRayTracingPipelinesNV" => {
+            (269usize, create_ray_tracing_pipelines_nv as *const c_void)
+        }
+        b"vkCreateRenderPass" => (270usize, create_render_pass as *const c_void),
+        b"vkCreateRenderPass2" => (271usize, create_render_pass2 as *const c_void),
+        b"vkCreateRenderPass2KHR" => (271usize, create_render_pass2 as *const c_void),
+        b"vkCreateSampler" => (272usize, create_sampler as *const c_void),
+        b"vkCreateSamplerYcbcrConversion" => {
+            (273usize, create_sampler_ycbcr_conversion as *const c_void)
+        }
+        b"vkCreateSamplerYcbcrConversionKHR" => {
+            (273usize, create_sampler_ycbcr_conversion as *const c_void)
+        }
+        b"vkCreateScreenSurfaceQNX" => (274usize, create_screen_surface_qnx as *const c_void),
+        b"vkCreateSemaphore" => (275usize, create_semaphore as *const c_void),
+        b"vkCreateShaderModule" => (276usize, create_shader_module as *const c_void),
+        b"vkCreateShadersEXT" => (277usize, create_shaders_ext as *const c_void),
+        b"vkCreateSharedSwapchainsKHR" => (278usize, create_shared_swapchains_khr as *const c_void),
+        b"vkCreateStreamDescriptorSurfaceGGP" => (
+            279usize,
+            create_stream_descriptor_surface_ggp as *const c_void,
+        ),
+        b"vkCreateSwapchainKHR" => (280usize, create_swapchain_khr as *const c_void),
+        b"vkCreateValidationCacheEXT" => (281usize, create_validation_cache_ext as *const c_void),
+        b"vkCreateViSurfaceNN" => (282usize, create_vi_surface_nn as *const c_void),
+        b"vkCreateVideoSessionKHR" => (283usize, create_video_session_khr as *const c_void),
+        b"vkCreateVideoSessionParametersKHR" => (
+            284usize,
+            create_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkCreateWaylandSurfaceKHR" => (285usize, create_wayland_surface_khr as *const c_void),
+        b"vkCreateWin32SurfaceKHR" => (286usize, create_win32_surface_khr as *const c_void),
+        b"vkCreateXcbSurfaceKHR" => (287usize, create_xcb_surface_khr as *const c_void),
+        b"vkCreateXlibSurfaceKHR" => (288usize, create_xlib_surface_khr as *const c_void),
+        b"vkDebugMarkerSetObjectNameEXT" => {
+            (289usize, debug_marker_set_object_name_ext as *const c_void)
+        }
+        b"vkDebugMarkerSetObjectTagEXT" => {
+            (290usize, debug_marker_set_object_tag_ext as *const c_void)
+        }
+        b"vkDebugReportMessageEXT" => (291usize, debug_report_message_ext as *const c_void),
+        b"vkDeferredOperationJoinKHR" => (292usize, deferred_operation_join_khr as *const c_void),
+        b"vkDestroyAccelerationStructureKHR" => (
+            293usize,
+            destroy_acceleration_structure_khr as *const c_void,
+        ),
+        b"vkDestroyAccelerationStructureNV" => {
+            (294usize, destroy_acceleration_structure_nv as *const c_void)
+        }
+        b"vkDestroyBuffer" => (295usize, destroy_buffer as *const c_void),
+        b"vkDestroyBufferCollectionFUCHSIA" => {
+            (296usize, destroy_buffer_collection_fuchsia as *const c_void)
+        }
+        b"vkDestroyBufferView" => (297usize, destroy_buffer_view as *const c_void),
+        b"vkDestroyCommandPool" => (298usize, destroy_command_pool as *const c_void),
+        b"vkDestroyCuFunctionNVX" => (299usize, destroy_cu_function_nvx as *const c_void),
+        b"vkDestroyCuModuleNVX" => (300usize, destroy_cu_module_nvx as *const c_void),
+        b"vkDestroyDebugReportCallbackEXT" => {
+            (301usize, destroy_debug_report_callback_ext as *const c_void)
+        }
+        b"vkDestroyDebugUtilsMessengerEXT" => {
+            (302usize, destroy_debug_utils_messenger_ext as *const c_void)
+        }
+        b"vkDestroyDeferredOperationKHR" => {
+            (303usize, destroy_deferred_operation_khr as *const c_void)
+        }
+        b"vkDestroyDescriptorPool" => (304usize, destroy_descriptor_pool as *const c_void),
+        b"vkDestroyDescriptorSetLayout" => {
+            (305usize, destroy_descriptor_set_layout as *const c_void)
+        }
+        b"vkDestroyDescriptorUpdateTemplate" => (
+            306usize,
+            destroy_descriptor_update_template as *const c_void,
+        ),
+        b"vkDestroyDescriptorUpdateTemplateKHR" => (
+            306usize,
+            destroy_descriptor_update_template as *const c_void,
+        ),
+        b"vkDestroyDevice" => (307usize, destroy_device as *const c_void),
+        b"vkDestroyEvent" => (308usize, destroy_event as *const c_void),
+        b"vkDestroyFence" => (309usize, destroy_fence as *const c_void),
+        b"vkDestroyFramebuffer" => (310usize, destroy_framebuffer as *const c_void),
+        b"vkDestroyImage" => (311usize, destroy_image as *const c_void),
+        b"vkDestroyImageView" => (312usize, destroy_image_view as *const c_void),
+        b"vkDestroyIndirectCommandsLayoutNV" => (
+            313usize,
+            destroy_indirect_commands_layout_nv as *const c_void,
+        ),
+        b"vkDestroyInstance" => (314usize, destroy_instance as *const c_void),
+        b"vkDestroyMicromapEXT" => (315usize, destroy_micromap_ext as *const c_void),
+        b"vkDestroyOpticalFlowSessionNV" => {
+            (316usize, destroy_optical_flow_session_nv as *const c_void)
+        }
+        b"vkDestroyPipeline" => (317usize, destroy_pipeline as *const c_void),
+        b"vkDestroyPipelineCache" => (318usize, destroy_pipeline_cache as *const c_void),
+        b"vkDestroyPipelineLayout" => (319usize, destroy_pipeline_layout as *const c_void),
+        b"vkDestroyPrivateDataSlot" => (320usize, destroy_private_data_slot as *const c_void),
+        b"vkDestroyPrivateDataSlotEXT" => (320usize, destroy_private_data_slot as *const c_void),
+        b"vkDestroyQueryPool" => (321usize, destroy_query_pool as *const c_void),
+        b"vkDestroyRenderPass" => (322usize, destroy_render_pass as *const c_void),
+        b"vkDestroySampler" => (323usize, destroy_sampler as *const c_void),
+        b"vkDestroySamplerYcbcrConversion" => {
+            (324usize, destroy_sampler_ycbcr_conversion as *const c_void)
+        }
+        b"vkDestroySamplerYcbcrConversionKHR" => {
+            (324usize, destroy_sampler_ycbcr_conversion as *const c_void)
+        }
+        b"vkDestroySemaphore" => (325usize, destroy_semaphore as *const c_void),
+        b"vkDestroyShaderEXT" => (326usize, destroy_shader_ext as *const c_void),
+        b"vkDestroyShaderModule" => (327usize, destroy_shader_module as *const c_void),
+        b"vkDestroySurfaceKHR" => (328usize, destroy_surface_khr as *const c_void),
+        b"vkDestroySwapchainKHR" => (329usize, destroy_swapchain_khr as *const c_void),
+        b"vkDestroyValidationCacheEXT" => (330usize, destroy_validation_cache_ext as *const c_void),
+        b"vkDestroyVideoSessionKHR" => (331usize, destroy_video_session_khr as *const c_void),
+        b"vkDestroyVideoSessionParametersKHR" => (
+            332usize,
+            destroy_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkDeviceWaitIdle" => (333usize, device_wait_idle as *const c_void),
+        b"vkDisplayPowerControlEXT" => (334usize, display_power_control_ext as *const c_void),
+        b"vkEndCommandBuffer" => (335usize, end_command_buffer as *const c_void),
+        b"vkEnumerateDeviceExtensionProperties" => (
+            336usize,
+            enumerate_device_extension_properties as *const c_void,
+        ),
+        b"vkEnumerateDeviceLayerProperties" => {
+            (337usize, enumerate_device_layer_properties as *const c_void)
+        }
+        b"vkEnumerateInstanceExtensionProperties" => (
+            338usize,
+            enumerate_instance_extension_properties as *const c_void,
+        ),
+        b"vkEnumerateInstanceLayerProperties" => (
+            339usize,
+            enumerate_instance_layer_properties as *const c_void,
+        ),
+        b"vkEnumerateInstanceVersion" => (340usize, enumerate_instance_version as *const c_void),
+        b"vkEnumeratePhysicalDeviceGroups" => {
+            (341usize, enumerate_physical_device_groups as *const c_void)
+        }
+        b"vkEnumeratePhysicalDeviceGroupsKHR" => {
+            (341usize, enumerate_physical_device_groups as *const c_void)
+        }
+        b"vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR" => (
+            342usize,
+            enumerate_physical_device_queue_family_performance_query_counters_khr as *const c_void,
+        ),
+        b"vkEnumeratePhysicalDevices" => (343usize, enumerate_physical_devices as *const c_void),
+        b"vkExportMetalObjectsEXT" => (344usize, export_metal_objects_ext as *const c_void),
+        b"vkFlushMappedMemoryRanges" => (345usize, flush_mapped_memory_ranges as *const c_void),
+        b"vkFreeCommandBuffers" => (346usize, free_command_buffers as *const c_void),
+        b"vkFreeDescriptorSets" => (347usize, free_descriptor_sets as *const c_void),
+        b"vkFreeMemory" => (348usize, free_memory as *const c_void),
+        b"vkGetAccelerationStructureBuildSizesKHR" => (
+            349usize,
+            get_acceleration_structure_build_sizes_khr as *const c_void,
+        ),
+        b"vkGetAccelerationStructureDeviceAddressKHR" => (
+            350usize,
+            get_acceleration_structure_device_address_khr as *const c_void,
+        ),
+        b"vkGetAccelerationStructureHandleNV" => (
+            351usize,
+            get_acceleration_structure_handle_nv as *const c_void,
+        ),
+        b"vkGetAccelerationStructureMemoryRequirementsNV" => (
+            352usize,
+            get_acceleration_structure_memory_requirements_nv as *const c_void,
+        ),
+        b"vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT" => (
+            353usize,
+            get_acceleration_structure_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetAndroidHardwareBufferPropertiesANDROID" => (
+            354usize,
+            get_android_hardware_buffer_properties_android as *const c_void,
+        ),
+        b"vkGetBufferCollectionPropertiesFUCHSIA" => (
+            355usize,
+            get_buffer_collection_properties_fuchsia as *const c_void,
+        ),
+        b"vkGetBufferDeviceAddress" => (356usize, get_buffer_device_address as *const c_void),
+        b"vkGetBufferDeviceAddressEXT" => (356usize, get_buffer_device_address as *const c_void),
+        b"vkGetBufferDeviceAddressKHR" => (356usize, get_buffer_device_address as *const c_void),
+        b"vkGetBufferMemoryRequirements" => {
+            (357usize, get_buffer_memory_requirements as *const c_void)
+        }
+        b"vkGetBufferMemoryRequirements2" => {
+            (358usize, get_buffer_memory_requirements2 as *const c_void)
+        }
+        b"vkGetBufferMemoryRequirements2KHR" => {
+            (358usize, get_buffer_memory_requirements2 as *const c_void)
+        }
+        b"vkGetBufferOpaqueCaptureAddress" => {
+            (359usize, get_buffer_opaque_capture_address as *const c_void)
+        }
+        b"vkGetBufferOpaqueCaptureAddressKHR" => {
+            (359usize, get_buffer_opaque_capture_address as *const c_void)
+        }
+        b"vkGetBufferOpaqueCaptureDescriptorDataEXT" => (
+            360usize,
+            get_buffer_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetCalibratedTimestampsEXT" => {
+            (361usize, get_calibrated_timestamps_ext as *const c_void)
+        }
+        b"vkGetDeferredOperationMaxConcurrencyKHR" => (
+            362usize,
+            get_deferred_operation_max_concurrency_khr as *const c_void,
+        ),
+        b"vkGetDeferredOperationResultKHR" => {
+            (363usize, get_deferred_operation_result_khr as *const c_void)
+        }
+        b"vkGetDescriptorEXT" => (364usize, get_descriptor_ext as *const c_void),
+        b"vkGetDescriptorSetHostMappingVALVE" => (
+            365usize,
+            get_descriptor_set_host_mapping_valve as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutBindingOffsetEXT" => (
+            366usize,
+            get_descriptor_set_layout_binding_offset_ext as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutHostMappingInfoVALVE" => (
+            367usize,
+            get_descriptor_set_layout_host_mapping_info_valve as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutSizeEXT" => (
+            368usize,
+            get_descriptor_set_layout_size_ext as *const c_void,
+        ),
+        b"vkGetDescriptorSetLayoutSupport" => {
+            (369usize, get_descriptor_set_layout_support as *const c_void)
+        }
+        b"vkGetDescriptorSetLayoutSupportKHR" => {
+            (369usize, get_descriptor_set_layout_support as *const c_void)
+        }
+        b"vkGetDeviceAccelerationStructureCompatibilityKHR" => (
+            370usize,
+            get_device_acceleration_structure_compatibility_khr as *const c_void,
+        ),
+        b"vkGetDeviceBufferMemoryRequirements" => (
+            371usize,
+            get_device_buffer_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceBufferMemoryRequirementsKHR" => (
+            371usize,
+            get_device_buffer_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceFaultInfoEXT" => (372usize, get_device_fault_info_ext as *const c_void),
+        b"vkGetDeviceGroupPeerMemoryFeatures" => (
+            373usize,
+            get_device_group_peer_memory_features as *const c_void,
+        ),
+        b"vkGetDeviceGroupPeerMemoryFeaturesKHR" => (
+            373usize,
+            get_device_group_peer_memory_features as *const c_void,
+        ),
+        b"vkGetDeviceGroupPresentCapabilitiesKHR" => (
+            374usize,
+            get_device_group_present_capabilities_khr as *const c_void,
+        ),
+        b"vkGetDeviceGroupSurfacePresentModes2EXT" => (
+            375usize,
+            get_device_group_surface_present_modes2_ext as *const c_void,
+        ),
+        b"vkGetDeviceGroupSurfacePresentModesKHR" => (
+            376usize,
+            get_device_group_surface_present_modes_khr as *const c_void,
+        ),
+        b"vkGetDeviceImageMemoryRequirements" => (
+            377usize,
+            get_device_image_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceImageMemoryRequirementsKHR" => (
+            377usize,
+            get_device_image_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceImageSparseMemoryRequirements" => (
+            378usize,
+            get_device_image_sparse_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceImageSparseMemoryRequirementsKHR" => (
+            378usize,
+            get_device_image_sparse_memory_requirements as *const c_void,
+        ),
+        b"vkGetDeviceMemoryCommitment" => (379usize, get_device_memory_commitment as *const c_void),
+        b"vkGetDeviceMemoryOpaqueCaptureAddress" => (
+            380usize,
+            get_device_memory_opaque_capture_address as *const c_void,
+        ),
+        b"vkGetDeviceMemoryOpaqueCaptureAddressKHR" => (
+            380usize,
+            get_device_memory_opaque_capture_address as *const c_void,
+        ),
+        b"vkGetDeviceMicromapCompatibilityEXT" => (
+            381usize,
+            get_device_micromap_compatibility_ext as *const c_void,
+        ),
+        b"vkGetDeviceQueue" => (382usize, get_device_queue as *const c_void),
+        b"vkGetDeviceQueue2" => (383usize, get_device_queue2 as *const c_void),
+        b"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI" => (
+            384usize,
+            get_device_subpass_shading_max_workgroup_size_huawei as *const c_void,
+        ),
+        b"vkGetDisplayModeProperties2KHR" => {
+            (385usize, get_display_mode_properties2_khr as *const c_void)
+        }
+        b"vkGetDisplayModePropertiesKHR" => {
+            (386usize, get_display_mode_properties_khr as *const c_void)
+        }
+        b"vkGetDisplayPlaneCapabilities2KHR" => (
+            387usize,
+            get_display_plane_capabilities2_khr as *const c_void,
+        ),
+        b"vkGetDisplayPlaneCapabilitiesKHR" => (
+            388usize,
+            get_display_plane_capabilities_khr as *const c_void,
+        ),
+        b"vkGetDisplayPlaneSupportedDisplaysKHR" => (
+            389usize,
+            get_display_plane_supported_displays_khr as *const c_void,
+        ),
+        b"vkGetDrmDisplayEXT" => (390usize, get_drm_display_ext as *const c_void),
+        b"vkGetDynamicRenderingTilePropertiesQCOM" => (
+            391usize,
+            get_dynamic_rendering_tile_properties_qcom as *const c_void,
+        ),
+        b"vkGetEncodedVideoSessionParametersKHR" => (
+            392usize,
+            get_encoded_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkGetEventStatus" => (393usize, get_event_status as *const c_void),
+        b"vkGetFenceFdKHR" => (394usize, get_fence_fd_khr as *const c_void),
+        b"vkGetFenceStatus" => (395usize, get_fence_status as *const c_void),
+        b"vkGetFenceWin32HandleKHR" => (396usize, get_fence_win32_handle_khr as *const c_void),
+        b"vkGetFramebufferTilePropertiesQCOM" => (
+            397usize,
+            get_framebuffer_tile_properties_qcom as *const c_void,
+        ),
+        b"vkGetGeneratedCommandsMemoryRequirementsNV" => (
+            398usize,
+            get_generated_commands_memory_requirements_nv as *const c_void,
+        ),
+        b"vkGetImageDrmFormatModifierPropertiesEXT" => (
+            399usize,
+            get_image_drm_format_modifier_properties_ext as *const c_void,
+        ),
+        b"vkGetImageMemoryRequirements" => {
+            (400usize, get_image_memory_requirements as *const c_void)
+        }
+        b"vkGetImageMemoryRequirements2" => {
+            (401usize, get_image_memory_requirements2 as *const c_void)
+        }
+        b"vkGetImageMemoryRequirements2KHR" => {
+            (401usize, get_image_memory_requirements2 as *const c_void)
+        }
+        b"vkGetImageOpaqueCaptureDescriptorDataEXT" => (
+            402usize,
+            get_image_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements" => (
+            403usize,
+            get_image_sparse_memory_requirements as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements2" => (
+            404usize,
+            get_image_sparse_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetImageSparseMemoryRequirements2KHR" => (
+            404usize,
+            get_image_sparse_memory_requirements2 as *const c_void,
+        ),
+        b"vkGetImageSubresourceLayout" => (405usize, get_image_subresource_layout as *const c_void),
+        b"vkGetImageSubresourceLayout2EXT" => {
+            (406usize, get_image_subresource_layout2_ext as *const c_void)
+        }
+        b"vkGetImageViewAddressNVX" => (407usize, get_image_view_address_nvx as *const c_void),
+        b"vkGetImageViewHandleNVX" => (408usize, get_image_view_handle_nvx as *const c_void),
+        b"vkGetImageViewOpaqueCaptureDescriptorDataEXT" => (
+            409usize,
+            get_image_view_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetMemoryAndroidHardwareBufferANDROID" => (
+            410usize,
+            get_memory_android_hardware_buffer_android as *const c_void,
+        ),
+        b"vkGetMemoryFdKHR" => (411usize, get_memory_fd_khr as *const c_void),
+        b"vkGetMemoryFdPropertiesKHR" => (412usize, get_memory_fd_properties_khr as *const c_void),
+        b"vkGetMemoryHostPointerPropertiesEXT" => (
+            413usize,
+            get_memory_host_pointer_properties_ext as *const c_void,
+        ),
+        b"vkGetMemoryRemoteAddressNV" => (414usize, get_memory_remote_address_nv as *const c_void),
+        b"vkGetMemoryWin32HandleKHR" => (415usize, get_memory_win32_handle_khr as *const c_void),
+        b"vkGetMemoryWin32HandleNV" => (416usize, get_memory_win32_handle_nv as *const c_void),
+        b"vkGetMemoryWin32HandlePropertiesKHR" => (
+            417usize,
+            get_memory_win32_handle_properties_khr as *const c_void,
+        ),
+        b"vkGetMemoryZirconHandleFUCHSIA" => {
+            (418usize, get_memory_zircon_handle_fuchsia as *const c_void)
+        }
+        b"vkGetMemoryZirconHandlePropertiesFUCHSIA" => (
+            419usize,
+            get_memory_zircon_handle_properties_fuchsia as *const c_void,
+        ),
+        b"vkGetMicromapBuildSizesEXT" => (420usize, get_micromap_build_sizes_ext as *const c_void),
+        b"vkGetPastPresentationTimingGOOGLE" => (
+            421usize,
+            get_past_presentation_timing_google as *const c_void,
+        ),
+        b"vkGetPerformanceParameterINTEL" => {
+            (422usize, get_performance_parameter_intel as *const c_void)
+        }
+        b"vkGetPhysicalDeviceCalibrateableTimeDomainsEXT" => (
+            423usize,
+            get_physical_device_calibrateable_time_domains_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceCooperativeMatrixPropertiesNV" => (
+            424usize,
+            get_physical_device_cooperative_matrix_properties_nv as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDirectFBPresentationSupportEXT" => (
+            425usize,
+            get_physical_device_direct_fb_presentation_support_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayPlaneProperties2KHR" => (
+            426usize,
+            get_physical_device_display_plane_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayPlanePropertiesKHR" => (
+            427usize,
+            get_physical_device_display_plane_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayProperties2KHR" => (
+            428usize,
+            get_physical_device_display_properties2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceDisplayPropertiesKHR" => (
+            429usize,
+            get_physical_device_display_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalBufferProperties" => (
+            430usize,
+            get_physical_device_external_buffer_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalBufferPropertiesKHR" => (
+            430usize,
+            get_physical_device_external_buffer_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalFenceProperties" => (
+            431usize,
+            get_physical_device_external_fence_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalFencePropertiesKHR" => (
+            431usize,
+            get_physical_device_external_fence_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalImageFormatPropertiesNV" => (
+            432usize,
+            get_physical_device_external_image_format_properties_nv as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalSemaphoreProperties" => (
+            433usize,
+            get_physical_device_external_semaphore_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceExternalSemaphorePropertiesKHR" => (
+            433usize,
+            get_physical_device_external_semaphore_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFeatures" => (434usize, get_physical_device_features as *const c_void),
+        b"vkGetPhysicalDeviceFeatures2" => {
+            (435usize, get_physical_device_features2 as *const c_void)
+        }
+        b"vkGetPhysicalDeviceFeatures2KHR" => {
+            (435usize, get_physical_device_features2 as *const c_void)
+        }
+        b"vkGetPhysicalDeviceFormatProperties" => (
+            436usize,
+            get_physical_device_format_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFormatProperties2" => (
+            437usize,
+            get_physical_device_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFormatProperties2KHR" => (
+            437usize,
+            get_physical_device_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceFragmentShadingRatesKHR" => (
+            438usize,
+            get_physical_device_fragment_shading_rates_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceImageFormatProperties" => (
+            439usize,
+            get_physical_device_image_format_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceImageFormatProperties2" => (
+            440usize,
+            get_physical_device_image_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceImageFormatProperties2KHR" => (
+            440usize,
+            get_physical_device_image_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMemoryProperties" => (
+            441usize,
+            get_physical_device_memory_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMemoryProperties2" => (
+            442usize,
+            get_physical_device_memory_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMemoryProperties2KHR" => (
+            442usize,
+            get_physical_device_memory_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceMultisamplePropertiesEXT" => (
+            443usize,
+            get_physical_device_multisample_properties_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceOpticalFlowImageFormatsNV" => (
+            444usize,
+            get_physical_device_optical_flow_image_formats_nv as *const c_void,
+        ),
+        b"vkGetPhysicalDevicePresentRectanglesKHR" => (
+            445usize,
+            get_physical_device_present_rectangles_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceProperties" => {
+            (446usize, get_physical_device_properties as *const c_void)
+        }
+        b"vkGetPhysicalDeviceProperties2" => {
+            (447usize, get_physical_device_properties2 as *const c_void)
+        }
+        b"vkGetPhysicalDeviceProperties2KHR" => {
+            (447usize, get_physical_device_properties2 as *const c_void)
+        }
+        b"vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR" => (
+            448usize,
+            get_physical_device_queue_family_performance_query_passes_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyProperties" => (
+            449usize,
+            get_physical_device_queue_family_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyProperties2" => (
+            450usize,
+            get_physical_device_queue_family_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyProperties2KHR" => (
+            450usize,
+            get_physical_device_queue_family_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceScreenPresentationSupportQNX" => (
+            451usize,
+            get_physical_device_screen_presentation_support_qnx as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSparseImageFormatProperties" => (
+            452usize,
+            get_physical_device_sparse_image_format_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSparseImageFormatProperties2" => (
+            453usize,
+            get_physical_device_sparse_image_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSparseImageFormatProperties2KHR" => (
+            453usize,
+            get_physical_device_sparse_image_format_properties2 as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV" => (
+            454usize,
+            get_physical_device_supported_framebuffer_mixed_samples_combinations_nv
+                as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceCapabilities2EXT" => (
+            455usize,
+            get_physical_device_surface_capabilities2_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceCapabilities2KHR" => (
+            456usize,
+            get_physical_device_surface_capabilities2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => (
+            457usize,
+            get_physical_device_surface_capabilities_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceFormats2KHR" => (
+            458usize,
+            get_physical_device_surface_formats2_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceFormatsKHR" => (
+            459usize,
+            get_physical_device_surface_formats_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfacePresentModes2EXT" => (
+            460usize,
+            get_physical_device_surface_present_modes2_ext as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfacePresentModesKHR" => (
+            461usize,
+            get_physical_device_surface_present_modes_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceSurfaceSupportKHR" => (
+            462usize,
+            get_physical_device_surface_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceToolProperties" => (
+            463usize,
+            get_physical_device_tool_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceToolPropertiesEXT" => (
+            463usize,
+            get_physical_device_tool_properties as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceVideoCapabilitiesKHR" => (
+            464usize,
+            get_physical_device_video_capabilities_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR" => (
+            465usize,
+            get_physical_device_video_encode_quality_level_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceVideoFormatPropertiesKHR" => (
+            466usize,
+            get_physical_device_video_format_properties_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceWaylandPresentationSupportKHR" => (
+            467usize,
+            get_physical_device_wayland_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceWin32PresentationSupportKHR" => (
+            468usize,
+            get_physical_device_win32_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceXcbPresentationSupportKHR" => (
+            469usize,
+            get_physical_device_xcb_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPhysicalDeviceXlibPresentationSupportKHR" => (
+            470usize,
+            get_physical_device_xlib_presentation_support_khr as *const c_void,
+        ),
+        b"vkGetPipelineCacheData" => (471usize, get_pipeline_cache_data as *const c_void),
+        b"vkGetPipelineExecutableInternalRepresentationsKHR" => (
+            472usize,
+            get_pipeline_executable_internal_representations_khr as *const c_void,
+        ),
+        b"vkGetPipelineExecutablePropertiesKHR" => (
+            473usize,
+            get_pipeline_executable_properties_khr as *const c_void,
+        ),
+        b"vkGetPipelineExecutableStatisticsKHR" => (
+            474usize,
+            get_pipeline_executable_statistics_khr as *const c_void,
+        ),
+        b"vkGetPipelinePropertiesEXT" => (475usize, get_pipeline_properties_ext as *const c_void),
+        b"vkGetPrivateData" => (476usize, get_private_data as *const c_void),
+        b"vkGetPrivateDataEXT" => (476usize, get_private_data as *const c_void),
+        b"vkGetQueryPoolResults" => (477usize, get_query_pool_results as *const c_void),
+        b"vkGetQueueCheckpointData2NV" => {
+            (478usize, get_queue_checkpoint_data2_nv as *const c_void)
+        }
+        b"vkGetQueueCheckpointDataNV" => (479usize, get_queue_checkpoint_data_nv as *const c_void),
+        b"vkGetRandROutputDisplayEXT" => (480usize, get_rand_r_output_display_ext as *const c_void),
+        b"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR" => (
+            481usize,
+            get_ray_tracing_capture_replay_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupHandlesKHR" => (
+            482usize,
+            get_ray_tracing_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupHandlesNV" => (
+            482usize,
+            get_ray_tracing_shader_group_handles_khr as *const c_void,
+        ),
+        b"vkGetRayTracingShaderGroupStackSizeKHR" => (
+            483usize,
+            get_ray_tracing_shader_group_stack_size_khr as *const c_void,
+        ),
+        b"vkGetRefreshCycleDurationGOOGLE" => {
+            (484usize, get_refresh_cycle_duration_google as *const c_void)
+        }
+        b"vkGetRenderAreaGranularity" => (485usize, get_render_area_granularity as *const c_void),
+        b"vkGetSamplerOpaqueCaptureDescriptorDataEXT" => (
+            486usize,
+            get_sampler_opaque_capture_descriptor_data_ext as *const c_void,
+        ),
+        b"vkGetScreenBufferPropertiesQNX" => {
+            (487usize, get_screen_buffer_properties_qnx as *const c_void)
+        }
+        b"vkGetSemaphoreCounterValue" => (488usize, get_semaphore_counter_value as *const c_void),
+        b"vkGetSemaphoreCounterValueKHR" => {
+            (488usize, get_semaphore_counter_value as *const c_void)
+        }
+        b"vkGetSemaphoreFdKHR" => (489usize, get_semaphore_fd_khr as *const c_void),
+        b"vkGetSemaphoreWin32HandleKHR" => {
+            (490usize, get_semaphore_win32_handle_khr as *const c_void)
+        }
+        b"vkGetSemaphoreZirconHandleFUCHSIA" => (
+            491usize,
+            get_semaphore_zircon_handle_fuchsia as *const c_void,
+        ),
+        b"vkGetShaderBinaryDataEXT" => (492usize, get_shader_binary_data_ext as *const c_void),
+        b"vkGetShaderInfoAMD" => (493usize, get_shader_info_amd as *const c_void),
+        b"vkGetShaderModuleCreateInfoIdentifierEXT" => (
+            494usize,
+            get_shader_module_create_info_identifier_ext as *const c_void,
+        ),
+        b"vkGetShaderModuleIdentifierEXT" => {
+            (495usize, get_shader_module_identifier_ext as *const c_void)
+        }
+        b"vkGetSwapchainCounterEXT" => (496usize, get_swapchain_counter_ext as *const c_void),
+        b"vkGetSwapchainGrallocUsage2ANDROID" => (
+            497usize,
+            get_swapchain_gralloc_usage2_android as *const c_void,
+        ),
+        b"vkGetSwapchainGrallocUsageANDROID" => (
+            498usize,
+            get_swapchain_gralloc_usage_android as *const c_void,
+        ),
+        b"vkGetSwapchainImagesKHR" => (499usize, get_swapchain_images_khr as *const c_void),
+        b"vkGetSwapchainStatusKHR" => (500usize, get_swapchain_status_khr as *const c_void),
+        b"vkGetValidationCacheDataEXT" => {
+            (501usize, get_validation_cache_data_ext as *const c_void)
+        }
+        b"vkGetVideoSessionMemoryRequirementsKHR" => (
+            502usize,
+            get_video_session_memory_requirements_khr as *const c_void,
+        ),
+        b"vkGetWinrtDisplayNV" => (503usize, get_winrt_display_nv as *const c_void),
+        b"vkImportFenceFdKHR" => (504usize, import_fence_fd_khr as *const c_void),
+        b"vkImportFenceWin32HandleKHR" => {
+            (505usize, import_fence_win32_handle_khr as *const c_void)
+        }
+        b"vkImportSemaphoreFdKHR" => (506usize, import_semaphore_fd_khr as *const c_void),
+        b"vkImportSemaphoreWin32HandleKHR" => {
+            (507usize, import_semaphore_win32_handle_khr as *const c_void)
+        }
+        b"vkImportSemaphoreZirconHandleFUCHSIA" => (
+            508usize,
+            import_semaphore_zircon_handle_fuchsia as *const c_void,
+        ),
+        b"vkInitializePerformanceApiINTEL" => {
+            (509usize, initialize_performance_api_intel as *const c_void)
+        }
+        b"vkInvalidateMappedMemoryRanges" => {
+            (510usize, invalidate_mapped_memory_ranges as *const c_void)
+        }
+        b"vkMapMemory" => (511usize, map_memory as *const c_void),
+        b"vkMapMemory2KHR" => (512usize, map_memory2_khr as *const c_void),
+        b"vkMergePipelineCaches" => (513usize, merge_pipeline_caches as *const c_void),
+        b"vkMergeValidationCachesEXT" => (514usize, merge_validation_caches_ext as *const c_void),
+        b"vkQueueBeginDebugUtilsLabelEXT" => {
+            (515usize, queue_begin_debug_utils_label_ext as *const c_void)
+        }
+        b"vkQueueBindSparse" => (516usize, queue_bind_sparse as *const c_void),
+        b"vkQueueEndDebugUtilsLabelEXT" => {
+            (517usize, queue_end_debug_utils_label_ext as *const c_void)
+        }
+        b"vkQueueInsertDebugUtilsLabelEXT" => (
+            518usize,
+            queue_insert_debug_utils_label_ext as *const c_void,
+        ),
+        b"vkQueuePresentKHR" => (519usize, queue_present_khr as *const c_void),
+        b"vkQueueSetPerformanceConfigurationINTEL" => (
+            520usize,
+            queue_set_performance_configuration_intel as *const c_void,
+        ),
+        b"vkQueueSignalReleaseImageANDROID" => (
+            521usize,
+            queue_signal_release_image_android as *const c_void,
+        ),
+        b"vkQueueSubmit" => (522usize, queue_submit as *const c_void),
+        b"vkQueueSubmit2" => (523usize, queue_submit2 as *const c_void),
+        b"vkQueueSubmit2KHR" => (523usize, queue_submit2 as *const c_void),
+        b"vkQueueWaitIdle" => (524usize, queue_wait_idle as *const c_void),
+        b"vkRegisterDeviceEventEXT" => (525usize, register_device_event_ext as *const c_void),
+        b"vkRegisterDisplayEventEXT" => (526usize, register_display_event_ext as *const c_void),
+        b"vkReleaseDisplayEXT" => (527usize, release_display_ext as *const c_void),
+        b"vkReleaseFullScreenExclusiveModeEXT" => (
+            528usize,
+            release_full_screen_exclusive_mode_ext as *const c_void,
+        ),
+        b"vkReleasePerformanceConfigurationINTEL" => (
+            529usize,
+            release_performance_configuration_intel as *const c_void,
+        ),
+        b"vkReleaseProfilingLockKHR" => (530usize, release_profiling_lock_khr as *const c_void),
+        b"vkReleaseSwapchainImagesEXT" => (531usize, release_swapchain_images_ext as *const c_void),
+        b"vkResetCommandBuffer" => (532usize, reset_command_buffer as *const c_void),
+        b"vkResetCommandPool" => (533usize, reset_command_pool as *const c_void),
+        b"vkResetDescriptorPool" => (534usize, reset_descriptor_pool as *const c_void),
+        b"vkResetEvent" => (535usize, reset_event as *const c_void),
+        b"vkResetFences" => (536usize, reset_fences as *const c_void),
+        b"vkResetQueryPool" => (537usize, reset_query_pool as *const c_void),
+        b"vkResetQueryPoolEXT" => (537usize, reset_query_pool as *const c_void),
+        b"vkSetBufferCollectionBufferConstraintsFUCHSIA" => (
+            538usize,
+            set_buffer_collection_buffer_constraints_fuchsia as *const c_void,
+        ),
+        b"vkSetBufferCollectionImageConstraintsFUCHSIA" => (
+            539usize,
+            set_buffer_collection_image_constraints_fuchsia as *const c_void,
+        ),
+        b"vkSetDebugUtilsObjectNameEXT" => {
+            (540usize, set_debug_utils_object_name_ext as *const c_void)
+        }
+        b"vkSetDebugUtilsObjectTagEXT" => {
+            (541usize, set_debug_utils_object_tag_ext as *const c_void)
+        }
+        b"vkSetDeviceMemoryPriorityEXT" => {
+            (542usize, set_device_memory_priority_ext as *const c_void)
+        }
+        b"vkSetEvent" => (543usize, set_event as *const c_void),
+        b"vkSetHdrMetadataEXT" => (544usize, set_hdr_metadata_ext as *const c_void),
+        b"vkSetLocalDimmingAMD" => (545usize, set_local_dimming_amd as *const c_void),
+        b"vkSetPrivateData" => (546usize, set_private_data as *const c_void),
+        b"vkSetPrivateDataEXT" => (546usize, set_private_data as *const c_void),
+        b"vkSignalSemaphore" => (547usize, signal_semaphore as *const c_void),
+        b"vkSignalSemaphoreKHR" => (547usize, signal_semaphore as *const c_void),
+        b"vkSubmitDebugUtilsMessageEXT" => {
+            (548usize, submit_debug_utils_message_ext as *const c_void)
+        }
+        b"vkTrimCommandPool" => (549usize, trim_command_pool as *const c_void),
+        b"vkTrimCommandPoolKHR" => (549usize, trim_command_pool as *const c_void),
+        b"vkUninitializePerformanceApiINTEL" => (
+            550usize,
+            uninitialize_performance_api_intel as *const c_void,
+        ),
+        b"vkUnmapMemory" => (551usize, unmap_memory as *const c_void),
+        b"vkUnmapMemory2KHR" => (552usize, unmap_memory2_khr as *const c_void),
+        b"vkUpdateDescriptorSetWithTemplate" => (
+            553usize,
+            update_descriptor_set_with_template as *const c_void,
+        ),
+        b"vkUpdateDescriptorSetWithTemplateKHR" => (
+            553usize,
+            update_descriptor_set_with_template as *const c_void,
+        ),
+        b"vkUpdateDescriptorSets" => (554usize, update_descriptor_sets as *const c_void),
+        b"vkUpdateVideoSessionParametersKHR" => (
+            555usize,
+            update_video_session_parameters_khr as *const c_void,
+        ),
+        b"vkWaitForFences" => (556usize, wait_for_fences as *const c_void),
+        b"vkWaitForPresentKHR" => (557usize, wait_for_present_khr as *const c_void),
+        b"vkWaitSemaphores" => (558usize, wait_semaphores as *const c_void),
+        b"vkWaitSemaphoresKHR" => (558usize, wait_semaphores as *const c_void),
+        b"vkWriteAccelerationStructuresPropertiesKHR" => (
+            559usize,
+            write_acceleration_structures_properties_khr as *const c_void,
+        ),
+        b"vkWriteMicromapsPropertiesEXT" => {
+            (560usize, write_micromaps_properties_ext as *const c_void)
+        }
+        _ => return None,
+    })
+}
+unsafe extern "system" fn acquire_drm_display_ext(
+    physical_device: vk::PhysicalDevice,
+    drm_fd: i32,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    used("vkAcquireDrmDisplayEXT", physical_device);
+    used("vkAcquireDrmDisplayEXT", display);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireDrmDisplayEXT>(next(
+        0usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, drm_fd, display)
+}
+unsafe extern "system" fn acquire_full_screen_exclusive_mode_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    used("vkAcquireFullScreenExclusiveModeEXT", device);
+    used("vkAcquireFullScreenExclusiveModeEXT", swapchain);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireFullScreenExclusiveModeEXT>(next(
+        1usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, swapchain)
+}
+unsafe extern "system" fn acquire_image_android(
+    device: vk::Device,
+    image: vk::Image,
+    native_fence_fd: c_int,
+    semaphore: vk::Semaphore,
+    fence: vk::Fence,
+) -> vk::Result {
+    used("vkAcquireImageANDROID", device);
+    used("vkAcquireImageANDROID", image);
+    used("vkAcquireImageANDROID", semaphore);
+    used("vkAcquireImageANDROID", fence);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireImageANDROID>(next(
+        2usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, image, native_fence_fd, semaphore, fence)
+}
+unsafe extern "system" fn acquire_next_image2_khr(
+    device: vk::Device,
+    p_acquire_info: *const vk::AcquireNextImageInfoKHR<'_>,
+    p_image_index: *mut u32,
+) -> vk::Result {
+    used("vkAcquireNextImage2KHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireNextImage2KHR>(next(
+        3usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_acquire_info, p_image_index)
+}
+unsafe extern "system" fn acquire_next_image_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    timeout: u64,
+    semaphore: vk::Semaphore,
+    fence: vk::Fence,
+    p_image_index: *mut u32,
+) -> vk::Result {
+    used("vkAcquireNextImageKHR", device);
+    used("vkAcquireNextImageKHR", swapchain);
+    used("vkAcquireNextImageKHR", semaphore);
+    used("vkAcquireNextImageKHR", fence);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireNextImageKHR>(next(
+        4usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, swapchain, timeout, semaphore, fence, p_image_index)
+}
+unsafe extern "system" fn acquire_performance_configuration_intel(
+    device: vk::Device,
+    p_acquire_info: *const vk::PerformanceConfigurationAcquireInfoINTEL<'_>,
+    p_configuration: *mut vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    used("vkAcquirePerformanceConfigurationINTEL", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquirePerformanceConfigurationINTEL>(next(
+        5usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_acquire_info, p_configuration);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_configuration.is_null() {
+        created(
+            "vkAcquirePerformanceConfigurationINTEL",
+            *p_configuration,
+            parent_of(device),
+            false,
+        );
+    }
+    result
+}
+unsafe extern "system" fn acquire_profiling_lock_khr(
+    device: vk::Device,
+    p_info: *const vk::AcquireProfilingLockInfoKHR<'_>,
+) -> vk::Result {
+    used("vkAcquireProfilingLockKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireProfilingLockKHR>(next(
+        6usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_info)
+}
+unsafe extern "system" fn acquire_winrt_display_nv(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    used("vkAcquireWinrtDisplayNV", physical_device);
+    used("vkAcquireWinrtDisplayNV", display);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireWinrtDisplayNV>(next(
+        7usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, display)
+}
+unsafe extern "system" fn acquire_xlib_display_ext(
+    physical_device: vk::PhysicalDevice,
+    dpy: *mut vk::Display,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    used("vkAcquireXlibDisplayEXT", physical_device);
+    used("vkAcquireXlibDisplayEXT", display);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAcquireXlibDisplayEXT>(next(
+        8usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, dpy, display)
+}
+unsafe extern "system" fn allocate_command_buffers(
+    device: vk::Device,
+    p_allocate_info: *const vk::CommandBufferAllocateInfo<'_>,
+    p_command_buffers: *mut vk::CommandBuffer,
+) -> vk::Result {
+    used("vkAllocateCommandBuffers", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAllocateCommandBuffers>(next(
+        9usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_allocate_info, p_command_buffers);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_command_buffers.is_null() {
+        let parent = if p_allocate_info.is_null() {
+            None
+        } else {
+            parent_of((*p_allocate_info).command_pool)
+        };
+        for i in 0..(*p_allocate_info).command_buffer_count as usize {
+            created(
+                "vkAllocateCommandBuffers",
+                *p_command_buffers.add(i),
+                parent,
+                true,
+            );
+        }
+    }
+    result
+}
+unsafe extern "system" fn allocate_descriptor_sets(
+    device: vk::Device,
+    p_allocate_info: *const vk::DescriptorSetAllocateInfo<'_>,
+    p_descriptor_sets: *mut vk::DescriptorSet,
+) -> vk::Result {
+    used("vkAllocateDescriptorSets", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAllocateDescriptorSets>(next(
+        10usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_allocate_info, p_descriptor_sets);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_descriptor_sets.is_null() {
+        let parent = if p_allocate_info.is_null() {
+            None
+        } else {
+            parent_of((*p_allocate_info).descriptor_pool)
+        };
+        for i in 0..(*p_allocate_info).descriptor_set_count as usize {
+            created(
+                "vkAllocateDescriptorSets",
+                *p_descriptor_sets.add(i),
+                parent,
+                true,
+            );
+        }
+    }
+    result
+}
+unsafe extern "system" fn allocate_memory(
+    device: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    used("vkAllocateMemory", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkAllocateMemory>(next(
+        11usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_allocate_info, p_allocator, p_memory);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_memory.is_null() {
+        created("vkAllocateMemory", *p_memory, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn begin_command_buffer(
+    command_buffer: vk::CommandBuffer,
+    p_begin_info: *const vk::CommandBufferBeginInfo<'_>,
+) -> vk::Result {
+    used("vkBeginCommandBuffer", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBeginCommandBuffer>(next(
+        12usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_begin_info)
+}
+unsafe extern "system" fn bind_acceleration_structure_memory_nv(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindAccelerationStructureMemoryInfoNV<'_>,
+) -> vk::Result {
+    used("vkBindAccelerationStructureMemoryNV", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindAccelerationStructureMemoryNV>(next(
+        13usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, bind_info_count, p_bind_infos)
+}
+unsafe extern "system" fn bind_buffer_memory(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    memory: vk::DeviceMemory,
+    memory_offset: vk::DeviceSize,
+) -> vk::Result {
+    used("vkBindBufferMemory", device);
+    used("vkBindBufferMemory", buffer);
+    used("vkBindBufferMemory", memory);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindBufferMemory>(next(
+        14usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, buffer, memory, memory_offset)
+}
+unsafe extern "system" fn bind_buffer_memory2(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindBufferMemoryInfo<'_>,
+) -> vk::Result {
+    used("vkBindBufferMemory2", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindBufferMemory2>(next(
+        15usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, bind_info_count, p_bind_infos)
+}
+unsafe extern "system" fn bind_image_memory(
+    device: vk::Device,
+    image: vk::Image,
+    memory: vk::DeviceMemory,
+    memory_offset: vk::DeviceSize,
+) -> vk::Result {
+    used("vkBindImageMemory", device);
+    used("vkBindImageMemory", image);
+    used("vkBindImageMemory", memory);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindImageMemory>(next(
+        16usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, image, memory, memory_offset)
+}
+unsafe extern "system" fn bind_image_memory2(
+    device: vk::Device,
+    bind_info_count: u32,
+    p_bind_infos: *const vk::BindImageMemoryInfo<'_>,
+) -> vk::Result {
+    used("vkBindImageMemory2", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindImageMemory2>(next(
+        17usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, bind_info_count, p_bind_infos)
+}
+unsafe extern "system" fn bind_optical_flow_session_image_nv(
+    device: vk::Device,
+    session: vk::OpticalFlowSessionNV,
+    binding_point: vk::OpticalFlowSessionBindingPointNV,
+    view: vk::ImageView,
+    layout: vk::ImageLayout,
+) -> vk::Result {
+    used("vkBindOpticalFlowSessionImageNV", device);
+    used("vkBindOpticalFlowSessionImageNV", session);
+    used("vkBindOpticalFlowSessionImageNV", view);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindOpticalFlowSessionImageNV>(next(
+        18usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, session, binding_point, view, layout)
+}
+unsafe extern "system" fn bind_video_session_memory_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    bind_session_memory_info_count: u32,
+    p_bind_session_memory_infos: *const vk::BindVideoSessionMemoryInfoKHR<'_>,
+) -> vk::Result {
+    used("vkBindVideoSessionMemoryKHR", device);
+    used("vkBindVideoSessionMemoryKHR", video_session);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBindVideoSessionMemoryKHR>(next(
+        19usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        video_session,
+        bind_session_memory_info_count,
+        p_bind_session_memory_infos,
+    )
+}
+unsafe extern "system" fn build_acceleration_structures_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+) -> vk::Result {
+    used("vkBuildAccelerationStructuresKHR", device);
+    used("vkBuildAccelerationStructuresKHR", deferred_operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBuildAccelerationStructuresKHR>(next(
+        20usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        deferred_operation,
+        info_count,
+        p_infos,
+        pp_build_range_infos,
+    )
+}
+unsafe extern "system" fn build_micromaps_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    info_count: u32,
+    p_infos: *const vk::MicromapBuildInfoEXT<'_>,
+) -> vk::Result {
+    used("vkBuildMicromapsEXT", device);
+    used("vkBuildMicromapsEXT", deferred_operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkBuildMicromapsEXT>(next(
+        21usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, deferred_operation, info_count, p_infos)
+}
+unsafe extern "system" fn cmd_begin_conditional_rendering_ext(
+    command_buffer: vk::CommandBuffer,
+    p_conditional_rendering_begin: *const vk::ConditionalRenderingBeginInfoEXT<'_>,
+) {
+    used("vkCmdBeginConditionalRenderingEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginConditionalRenderingEXT>(next(
+        22usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_conditional_rendering_begin)
+}
+unsafe extern "system" fn cmd_begin_debug_utils_label_ext(
+    command_buffer: vk::CommandBuffer,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    used("vkCmdBeginDebugUtilsLabelEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginDebugUtilsLabelEXT>(next(
+        23usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_label_info)
+}
+unsafe extern "system" fn cmd_begin_query(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    flags: vk::QueryControlFlags,
+) {
+    used("vkCmdBeginQuery", command_buffer);
+    used("vkCmdBeginQuery", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginQuery>(next(
+        24usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, query_pool, query, flags)
+}
+unsafe extern "system" fn cmd_begin_query_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    flags: vk::QueryControlFlags,
+    index: u32,
+) {
+    used("vkCmdBeginQueryIndexedEXT", command_buffer);
+    used("vkCmdBeginQueryIndexedEXT", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginQueryIndexedEXT>(next(
+        25usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, query_pool, query, flags, index)
+}
+unsafe extern "system" fn cmd_begin_render_pass(
+    command_buffer: vk::CommandBuffer,
+    p_render_pass_begin: *const vk::RenderPassBeginInfo<'_>,
+    contents: vk::SubpassContents,
+) {
+    used("vkCmdBeginRenderPass", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginRenderPass>(next(
+        26usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_render_pass_begin, contents)
+}
+unsafe extern "system" fn cmd_begin_render_pass2(
+    command_buffer: vk::CommandBuffer,
+    p_render_pass_begin: *const vk::RenderPassBeginInfo<'_>,
+    p_subpass_begin_info: *const vk::SubpassBeginInfo<'_>,
+) {
+    used("vkCmdBeginRenderPass2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginRenderPass2>(next(
+        27usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_render_pass_begin, p_subpass_begin_info)
+}
+unsafe extern "system" fn cmd_begin_rendering(
+    command_buffer: vk::CommandBuffer,
+    p_rendering_info: *const vk::RenderingInfo<'_>,
+) {
+    used("vkCmdBeginRendering", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginRendering>(next(
+        28usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_rendering_info)
+}
+unsafe extern "system" fn cmd_begin_transform_feedback_ext(
+    command_buffer: vk::CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const vk::Buffer,
+    p_counter_buffer_offsets: *const vk::DeviceSize,
+) {
+    used("vkCmdBeginTransformFeedbackEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginTransformFeedbackEXT>(next(
+        29usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    )
+}
+unsafe extern "system" fn cmd_begin_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_begin_info: *const vk::VideoBeginCodingInfoKHR<'_>,
+) {
+    used("vkCmdBeginVideoCodingKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBeginVideoCodingKHR>(next(
+        30usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_begin_info)
+}
+unsafe extern "system" fn cmd_bind_descriptor_buffer_embedded_samplers_ext(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    set: u32,
+) {
+    used(
+        "vkCmdBindDescriptorBufferEmbeddedSamplersEXT",
+        command_buffer,
+    );
+    used("vkCmdBindDescriptorBufferEmbeddedSamplersEXT", layout);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindDescriptorBufferEmbeddedSamplersEXT>(
+        next(31usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(command_buffer, pipeline_bind_point, layout, set)
+}
+unsafe extern "system" fn cmd_bind_descriptor_buffers_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer_count: u32,
+    p_binding_infos: *const vk::DescriptorBufferBindingInfoEXT<'_>,
+) {
+    used("vkCmdBindDescriptorBuffersEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindDescriptorBuffersEXT>(next(
+        32usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, buffer_count, p_binding_infos)
+}
+unsafe extern "system" fn cmd_bind_descriptor_sets(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    first_set: u32,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const vk::DescriptorSet,
+    dynamic_offset_count: u32,
+    p_dynamic_offsets: *const u32,
+) {
+    used("vkCmdBindDescriptorSets", command_buffer);
+    used("vkCmdBindDescriptorSets", layout);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindDescriptorSets>(next(
+        33usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        first_set,
+        descriptor_set_count,
+        p_descriptor_sets,
+        dynamic_offset_count,
+        p_dynamic_offsets,
+    )
+}
+unsafe extern "system" fn cmd_bind_index_buffer(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    index_type: vk::IndexType,
+) {
+    used("vkCmdBindIndexBuffer", command_buffer);
+    used("vkCmdBindIndexBuffer", buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindIndexBuffer>(next(
+        34usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, buffer, offset, index_type)
+}
+unsafe extern "system" fn cmd_bind_invocation_mask_huawei(
+    command_buffer: vk::CommandBuffer,
+    image_view: vk::ImageView,
+    image_layout: vk::ImageLayout,
+) {
+    used("vkCmdBindInvocationMaskHUAWEI", command_buffer);
+    used("vkCmdBindInvocationMaskHUAWEI", image_view);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindInvocationMaskHUAWEI>(next(
+        35usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, image_view, image_layout)
+}
+unsafe extern "system" fn cmd_bind_pipeline(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    pipeline: vk::Pipeline,
+) {
+    used("vkCmdBindPipeline", command_buffer);
+    used("vkCmdBindPipeline", pipeline);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindPipeline>(next(
+        36usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, pipeline_bind_point, pipeline)
+}
+unsafe extern "system" fn cmd_bind_pipeline_shader_group_nv(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    pipeline: vk::Pipeline,
+    group_index: u32,
+) {
+    used("vkCmdBindPipelineShaderGroupNV", command_buffer);
+    used("vkCmdBindPipelineShaderGroupNV", pipeline);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindPipelineShaderGroupNV>(next(
+        37usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, pipeline_bind_point, pipeline, group_index)
+}
+unsafe extern "system" fn cmd_bind_shaders_ext(
+    command_buffer: vk::CommandBuffer,
+    stage_count: u32,
+    p_stages: *const vk::ShaderStageFlags,
+    p_shaders: *const vk::ShaderEXT,
+) {
+    used("vkCmdBindShadersEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindShadersEXT>(next(
+        38usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, stage_count, p_stages, p_shaders)
+}
+unsafe extern "system" fn cmd_bind_shading_rate_image_nv(
+    command_buffer: vk::CommandBuffer,
+    image_view: vk::ImageView,
+    image_layout: vk::ImageLayout,
+) {
+    used("vkCmdBindShadingRateImageNV", command_buffer);
+    used("vkCmdBindShadingRateImageNV", image_view);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindShadingRateImageNV>(next(
+        39usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, image_view, image_layout)
+}
+unsafe extern "system" fn cmd_bind_transform_feedback_buffers_ext(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+    p_sizes: *const vk::DeviceSize,
+) {
+    used("vkCmdBindTransformFeedbackBuffersEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindTransformFeedbackBuffersEXT>(next(
+        40usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+    )
+}
+unsafe extern "system" fn cmd_bind_vertex_buffers(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+) {
+    used("vkCmdBindVertexBuffers", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindVertexBuffers>(next(
+        41usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+    )
+}
+unsafe extern "system" fn cmd_bind_vertex_buffers2(
+    command_buffer: vk::CommandBuffer,
+    first_binding: u32,
+    binding_count: u32,
+    p_buffers: *const vk::Buffer,
+    p_offsets: *const vk::DeviceSize,
+    p_sizes: *const vk::DeviceSize,
+    p_strides: *const vk::DeviceSize,
+) {
+    used("vkCmdBindVertexBuffers2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBindVertexBuffers2>(next(
+        42usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_binding,
+        binding_count,
+        p_buffers,
+        p_offsets,
+        p_sizes,
+        p_strides,
+    )
+}
+unsafe extern "system" fn cmd_blit_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageBlit,
+    filter: vk::Filter,
+) {
+    used("vkCmdBlitImage", command_buffer);
+    used("vkCmdBlitImage", src_image);
+    used("vkCmdBlitImage", dst_image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBlitImage>(next(
+        43usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+        filter,
+    )
+}
+unsafe extern "system" fn cmd_blit_image2(
+    command_buffer: vk::CommandBuffer,
+    p_blit_image_info: *const vk::BlitImageInfo2<'_>,
+) {
+    used("vkCmdBlitImage2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBlitImage2>(next(
+        44usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_blit_image_info)
+}
+unsafe extern "system" fn cmd_build_acceleration_structure_nv(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::AccelerationStructureInfoNV<'_>,
+    instance_data: vk::Buffer,
+    instance_offset: vk::DeviceSize,
+    update: vk::Bool32,
+    dst: vk::AccelerationStructureNV,
+    src: vk::AccelerationStructureNV,
+    scratch: vk::Buffer,
+    scratch_offset: vk::DeviceSize,
+) {
+    used("vkCmdBuildAccelerationStructureNV", command_buffer);
+    used("vkCmdBuildAccelerationStructureNV", instance_data);
+    used("vkCmdBuildAccelerationStructureNV", dst);
+    used("vkCmdBuildAccelerationStructureNV", src);
+    used("vkCmdBuildAccelerationStructureNV", scratch);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBuildAccelerationStructureNV>(next(
+        45usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        p_info,
+        instance_data,
+        instance_offset,
+        update,
+        dst,
+        src,
+        scratch,
+        scratch_offset,
+    )
+}
+unsafe extern "system" fn cmd_build_acceleration_structures_indirect_khr(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    p_indirect_device_addresses: *const vk::DeviceAddress,
+    p_indirect_strides: *const u32,
+    pp_max_primitive_counts: *const *const u32,
+) {
+    used(
+        "vkCmdBuildAccelerationStructuresIndirectKHR",
+        command_buffer,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBuildAccelerationStructuresIndirectKHR>(
+        next(46usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(
+        command_buffer,
+        info_count,
+        p_infos,
+        p_indirect_device_addresses,
+        p_indirect_strides,
+        pp_max_primitive_counts,
+    )
+}
+unsafe extern "system" fn cmd_build_acceleration_structures_khr(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+) {
+    used("vkCmdBuildAccelerationStructuresKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBuildAccelerationStructuresKHR>(next(
+        47usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, info_count, p_infos, pp_build_range_infos)
+}
+unsafe extern "system" fn cmd_build_micromaps_ext(
+    command_buffer: vk::CommandBuffer,
+    info_count: u32,
+    p_infos: *const vk::MicromapBuildInfoEXT<'_>,
+) {
+    used("vkCmdBuildMicromapsEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdBuildMicromapsEXT>(next(
+        48usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, info_count, p_infos)
+}
+unsafe extern "system" fn cmd_clear_attachments(
+    command_buffer: vk::CommandBuffer,
+    attachment_count: u32,
+    p_attachments: *const vk::ClearAttachment,
+    rect_count: u32,
+    p_rects: *const vk::ClearRect,
+) {
+    used("vkCmdClearAttachments", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdClearAttachments>(next(
+        49usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        attachment_count,
+        p_attachments,
+        rect_count,
+        p_rects,
+    )
+}
+unsafe extern "system" fn cmd_clear_color_image(
+    command_buffer: vk::CommandBuffer,
+    image: vk::Image,
+    image_layout: vk::ImageLayout,
+    p_color: *const vk::ClearColorValue,
+    range_count: u32,
+    p_ranges: *const vk::ImageSubresourceRange,
+) {
+    used("vkCmdClearColorImage", command_buffer);
+    used("vkCmdClearColorImage", image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdClearColorImage>(next(
+        50usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        image,
+        image_layout,
+        p_color,
+        range_count,
+        p_ranges,
+    )
+}
+unsafe extern "system" fn cmd_clear_depth_stencil_image(
+    command_buffer: vk::CommandBuffer,
+    image: vk::Image,
+    image_layout: vk::ImageLayout,
+    p_depth_stencil: *const vk::ClearDepthStencilValue,
+    range_count: u32,
+    p_ranges: *const vk::ImageSubresourceRange,
+) {
+    used("vkCmdClearDepthStencilImage", command_buffer);
+    used("vkCmdClearDepthStencilImage", image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdClearDepthStencilImage>(next(
+        51usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        image,
+        image_layout,
+        p_depth_stencil,
+        range_count,
+        p_ranges,
+    )
+}
+unsafe extern "system" fn cmd_control_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_coding_control_info: *const vk::VideoCodingControlInfoKHR<'_>,
+) {
+    used("vkCmdControlVideoCodingKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdControlVideoCodingKHR>(next(
+        52usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_coding_control_info)
+}
+unsafe extern "system" fn cmd_copy_acceleration_structure_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyAccelerationStructureInfoKHR<'_>,
+) {
+    used("vkCmdCopyAccelerationStructureKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyAccelerationStructureKHR>(next(
+        53usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_info)
+}
+unsafe extern "system" fn cmd_copy_acceleration_structure_nv(
+    command_buffer: vk::CommandBuffer,
+    dst: vk::AccelerationStructureNV,
+    src: vk::AccelerationStructureNV,
+    mode: vk::CopyAccelerationStructureModeKHR,
+) {
+    used("vkCmdCopyAccelerationStructureNV", command_buffer);
+    used("vkCmdCopyAccelerationStructureNV", dst);
+    used("vkCmdCopyAccelerationStructureNV", src);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyAccelerationStructureNV>(next(
+        54usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, dst, src, mode)
+}
+unsafe extern "system" fn cmd_copy_acceleration_structure_to_memory_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>,
+) {
+    used("vkCmdCopyAccelerationStructureToMemoryKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyAccelerationStructureToMemoryKHR>(
+        next(55usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(command_buffer, p_info)
+}
+unsafe extern "system" fn cmd_copy_buffer(
+    command_buffer: vk::CommandBuffer,
+    src_buffer: vk::Buffer,
+    dst_buffer: vk::Buffer,
+    region_count: u32,
+    p_regions: *const vk::BufferCopy,
+) {
+    used("vkCmdCopyBuffer", command_buffer);
+    used("vkCmdCopyBuffer", src_buffer);
+    used("vkCmdCopyBuffer", dst_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyBuffer>(next(
+        56usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        src_buffer,
+        dst_buffer,
+        region_count,
+        p_regions,
+    )
+}
+unsafe extern "system" fn cmd_copy_buffer2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_buffer_info: *const vk::CopyBufferInfo2<'_>,
+) {
+    used("vkCmdCopyBuffer2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyBuffer2>(next(
+        57usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_copy_buffer_info)
+}
+unsafe extern "system" fn cmd_copy_buffer_to_image(
+    command_buffer: vk::CommandBuffer,
+    src_buffer: vk::Buffer,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::BufferImageCopy,
+) {
+    used("vkCmdCopyBufferToImage", command_buffer);
+    used("vkCmdCopyBufferToImage", src_buffer);
+    used("vkCmdCopyBufferToImage", dst_image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyBufferToImage>(next(
+        58usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        src_buffer,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    )
+}
+unsafe extern "system" fn cmd_copy_buffer_to_image2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_buffer_to_image_info: *const vk::CopyBufferToImageInfo2<'_>,
+) {
+    used("vkCmdCopyBufferToImage2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyBufferToImage2>(next(
+        59usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_copy_buffer_to_image_info)
+}
+unsafe extern "system" fn cmd_copy_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageCopy,
+) {
+    used("vkCmdCopyImage", command_buffer);
+    used("vkCmdCopyImage", src_image);
+    used("vkCmdCopyImage", dst_image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyImage>(next(
+        60usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    )
+}
+unsafe extern "system" fn cmd_copy_image2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_image_info: *const vk::CopyImageInfo2<'_>,
+) {
+    used("vkCmdCopyImage2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyImage2>(next(
+        61usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_copy_image_info)
+}
+unsafe extern "system" fn cmd_copy_image_to_buffer(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_buffer: vk::Buffer,
+    region_count: u32,
+    p_regions: *const vk::BufferImageCopy,
+) {
+    used("vkCmdCopyImageToBuffer", command_buffer);
+    used("vkCmdCopyImageToBuffer", src_image);
+    used("vkCmdCopyImageToBuffer", dst_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyImageToBuffer>(next(
+        62usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_buffer,
+        region_count,
+        p_regions,
+    )
+}
+unsafe extern "system" fn cmd_copy_image_to_buffer2(
+    command_buffer: vk::CommandBuffer,
+    p_copy_image_to_buffer_info: *const vk::CopyImageToBufferInfo2<'_>,
+) {
+    used("vkCmdCopyImageToBuffer2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyImageToBuffer2>(next(
+        63usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_copy_image_to_buffer_info)
+}
+unsafe extern "system" fn cmd_copy_memory_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    copy_buffer_address: vk::DeviceAddress,
+    copy_count: u32,
+    stride: u32,
+) {
+    used("vkCmdCopyMemoryIndirectNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMemoryIndirectNV>(next(
+        64usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, copy_buffer_address, copy_count, stride)
+}
+unsafe extern "system" fn cmd_copy_memory_to_acceleration_structure_khr(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>,
+) {
+    used("vkCmdCopyMemoryToAccelerationStructureKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMemoryToAccelerationStructureKHR>(
+        next(65usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(command_buffer, p_info)
+}
+unsafe extern "system" fn cmd_copy_memory_to_image_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    copy_buffer_address: vk::DeviceAddress,
+    copy_count: u32,
+    stride: u32,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    p_image_subresources: *const vk::ImageSubresourceLayers,
+) {
+    used("vkCmdCopyMemoryToImageIndirectNV", command_buffer);
+    used("vkCmdCopyMemoryToImageIndirectNV", dst_image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMemoryToImageIndirectNV>(next(
+        66usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        copy_buffer_address,
+        copy_count,
+        stride,
+        dst_image,
+        dst_image_layout,
+        p_image_subresources,
+    )
+}
+unsafe extern "system" fn cmd_copy_memory_to_micromap_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMemoryToMicromapInfoEXT<'_>,
+) {
+    used("vkCmdCopyMemoryToMicromapEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMemoryToMicromapEXT>(next(
+        67usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_info)
+}
+unsafe extern "system" fn cmd_copy_micromap_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMicromapInfoEXT<'_>,
+) {
+    used("vkCmdCopyMicromapEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMicromapEXT>(next(
+        68usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_info)
+}
+unsafe extern "system" fn cmd_copy_micromap_to_memory_ext(
+    command_buffer: vk::CommandBuffer,
+    p_info: *const vk::CopyMicromapToMemoryInfoEXT<'_>,
+) {
+    used("vkCmdCopyMicromapToMemoryEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyMicromapToMemoryEXT>(next(
+        69usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_info)
+}
+unsafe extern "system" fn cmd_copy_query_pool_results(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    stride: vk::DeviceSize,
+    flags: vk::QueryResultFlags,
+) {
+    used("vkCmdCopyQueryPoolResults", command_buffer);
+    used("vkCmdCopyQueryPoolResults", query_pool);
+    used("vkCmdCopyQueryPoolResults", dst_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCopyQueryPoolResults>(next(
+        70usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        query_pool,
+        first_query,
+        query_count,
+        dst_buffer,
+        dst_offset,
+        stride,
+        flags,
+    )
+}
+unsafe extern "system" fn cmd_cu_launch_kernel_nvx(
+    command_buffer: vk::CommandBuffer,
+    p_launch_info: *const vk::CuLaunchInfoNVX<'_>,
+) {
+    used("vkCmdCuLaunchKernelNVX", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdCuLaunchKernelNVX>(next(
+        71usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_launch_info)
+}
+unsafe extern "system" fn cmd_debug_marker_begin_ext(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::DebugMarkerMarkerInfoEXT<'_>,
+) {
+    used("vkCmdDebugMarkerBeginEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDebugMarkerBeginEXT>(next(
+        72usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_marker_info)
+}
+unsafe extern "system" fn cmd_debug_marker_end_ext(command_buffer: vk::CommandBuffer) {
+    used("vkCmdDebugMarkerEndEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDebugMarkerEndEXT>(next(
+        73usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer)
+}
+unsafe extern "system" fn cmd_debug_marker_insert_ext(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::DebugMarkerMarkerInfoEXT<'_>,
+) {
+    used("vkCmdDebugMarkerInsertEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDebugMarkerInsertEXT>(next(
+        74usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_marker_info)
+}
+unsafe extern "system" fn cmd_decode_video_khr(
+    command_buffer: vk::CommandBuffer,
+    p_decode_info: *const vk::VideoDecodeInfoKHR<'_>,
+) {
+    used("vkCmdDecodeVideoKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDecodeVideoKHR>(next(
+        75usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_decode_info)
+}
+unsafe extern "system" fn cmd_decompress_memory_indirect_count_nv(
+    command_buffer: vk::CommandBuffer,
+    indirect_commands_address: vk::DeviceAddress,
+    indirect_commands_count_address: vk::DeviceAddress,
+    stride: u32,
+) {
+    used("vkCmdDecompressMemoryIndirectCountNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDecompressMemoryIndirectCountNV>(next(
+        76usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        indirect_commands_address,
+        indirect_commands_count_address,
+        stride,
+    )
+}
+unsafe extern "system" fn cmd_decompress_memory_nv(
+    command_buffer: vk::CommandBuffer,
+    decompress_region_count: u32,
+    p_decompress_memory_regions: *const vk::DecompressMemoryRegionNV,
+) {
+    used("vkCmdDecompressMemoryNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDecompressMemoryNV>(next(
+        77usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        decompress_region_count,
+        p_decompress_memory_regions,
+    )
+}
+unsafe extern "system" fn cmd_dispatch(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    used("vkCmdDispatch", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDispatch>(next(
+        78usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, group_count_x, group_count_y, group_count_z)
+}
+unsafe extern "system" fn cmd_dispatch_base(
+    command_buffer: vk::CommandBuffer,
+    base_group_x: u32,
+    base_group_y: u32,
+    base_group_z: u32,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    used("vkCmdDispatchBase", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDispatchBase>(next(
+        79usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        base_group_x,
+        base_group_y,
+        base_group_z,
+        group_count_x,
+        group_count_y,
+        group_count_z,
+    )
+}
+unsafe extern "system" fn cmd_dispatch_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+) {
+    used("vkCmdDispatchIndirect", command_buffer);
+    used("vkCmdDispatchIndirect", buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDispatchIndirect>(next(
+        80usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, buffer, offset)
+}
+unsafe extern "system" fn cmd_draw(
+    command_buffer: vk::CommandBuffer,
+    vertex_count: u32,
+    instance_count: u32,
+    first_vertex: u32,
+    first_instance: u32,
+) {
+    used("vkCmdDraw", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDraw>(next(
+        81usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        vertex_count,
+        instance_count,
+        first_vertex,
+        first_instance,
+    )
+}
+unsafe extern "system" fn cmd_draw_cluster_huawei(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    used("vkCmdDrawClusterHUAWEI", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawClusterHUAWEI>(next(
+        82usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, group_count_x, group_count_y, group_count_z)
+}
+unsafe extern "system" fn cmd_draw_cluster_indirect_huawei(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+) {
+    used("vkCmdDrawClusterIndirectHUAWEI", command_buffer);
+    used("vkCmdDrawClusterIndirectHUAWEI", buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawClusterIndirectHUAWEI>(next(
+        83usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, buffer, offset)
+}
+unsafe extern "system" fn cmd_draw_indexed(
+    command_buffer: vk::CommandBuffer,
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    vertex_offset: i32,
+    first_instance: u32,
+) {
+    used("vkCmdDrawIndexed", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndexed>(next(
+        84usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        index_count,
+        instance_count,
+        first_index,
+        vertex_offset,
+        first_instance,
+    )
+}
+unsafe extern "system" fn cmd_draw_indexed_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    used("vkCmdDrawIndexedIndirect", command_buffer);
+    used("vkCmdDrawIndexedIndirect", buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndexedIndirect>(next(
+        85usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, buffer, offset, draw_count, stride)
+}
+unsafe extern "system" fn cmd_draw_indexed_indirect_count(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    used("vkCmdDrawIndexedIndirectCount", command_buffer);
+    used("vkCmdDrawIndexedIndirectCount", buffer);
+    used("vkCmdDrawIndexedIndirectCount", count_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndexedIndirectCount>(next(
+        86usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    )
+}
+unsafe extern "system" fn cmd_draw_indirect(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    used("vkCmdDrawIndirect", command_buffer);
+    used("vkCmdDrawIndirect", buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndirect>(next(
+        87usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, buffer, offset, draw_count, stride)
+}
+unsafe extern "system" fn cmd_draw_indirect_byte_count_ext(
+    command_buffer: vk::CommandBuffer,
+    instance_count: u32,
+    first_instance: u32,
+    counter_buffer: vk::Buffer,
+    counter_buffer_offset: vk::DeviceSize,
+    counter_offset: u32,
+    vertex_stride: u32,
+) {
+    used("vkCmdDrawIndirectByteCountEXT", command_buffer);
+    used("vkCmdDrawIndirectByteCountEXT", counter_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndirectByteCountEXT>(next(
+        88usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        instance_count,
+        first_instance,
+        counter_buffer,
+        counter_buffer_offset,
+        counter_offset,
+        vertex_stride,
+    )
+}
+unsafe extern "system" fn cmd_draw_indirect_count(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    used("vkCmdDrawIndirectCount", command_buffer);
+    used("vkCmdDrawIndirectCount", buffer);
+    used("vkCmdDrawIndirectCount", count_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawIndirectCount>(next(
+        89usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    )
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_ext(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    used("vkCmdDrawMeshTasksEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksEXT>(next(
+        90usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, group_count_x, group_count_y, group_count_z)
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_count_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    used("vkCmdDrawMeshTasksIndirectCountEXT", command_buffer);
+    used("vkCmdDrawMeshTasksIndirectCountEXT", buffer);
+    used("vkCmdDrawMeshTasksIndirectCountEXT", count_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksIndirectCountEXT>(next(
+        91usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    )
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_count_nv(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    count_buffer: vk::Buffer,
+    count_buffer_offset: vk::DeviceSize,
+    max_draw_count: u32,
+    stride: u32,
+) {
+    used("vkCmdDrawMeshTasksIndirectCountNV", command_buffer);
+    used("vkCmdDrawMeshTasksIndirectCountNV", buffer);
+    used("vkCmdDrawMeshTasksIndirectCountNV", count_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksIndirectCountNV>(next(
+        92usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        buffer,
+        offset,
+        count_buffer,
+        count_buffer_offset,
+        max_draw_count,
+        stride,
+    )
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_ext(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    used("vkCmdDrawMeshTasksIndirectEXT", command_buffer);
+    used("vkCmdDrawMeshTasksIndirectEXT", buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksIndirectEXT>(next(
+        93usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, buffer, offset, draw_count, stride)
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_indirect_nv(
+    command_buffer: vk::CommandBuffer,
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    draw_count: u32,
+    stride: u32,
+) {
+    used("vkCmdDrawMeshTasksIndirectNV", command_buffer);
+    used("vkCmdDrawMeshTasksIndirectNV", buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksIndirectNV>(next(
+        94usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, buffer, offset, draw_count, stride)
+}
+unsafe extern "system" fn cmd_draw_mesh_tasks_nv(
+    command_buffer: vk::CommandBuffer,
+    task_count: u32,
+    first_task: u32,
+) {
+    used("vkCmdDrawMeshTasksNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMeshTasksNV>(next(
+        95usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, task_count, first_task)
+}
+unsafe extern "system" fn cmd_draw_multi_ext(
+    command_buffer: vk::CommandBuffer,
+    draw_count: u32,
+    p_vertex_info: *const vk::MultiDrawInfoEXT,
+    instance_count: u32,
+    first_instance: u32,
+    stride: u32,
+) {
+    used("vkCmdDrawMultiEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMultiEXT>(next(
+        96usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        draw_count,
+        p_vertex_info,
+        instance_count,
+        first_instance,
+        stride,
+    )
+}
+unsafe extern "system" fn cmd_draw_multi_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    draw_count: u32,
+    p_index_info: *const vk::MultiDrawIndexedInfoEXT,
+    instance_count: u32,
+    first_instance: u32,
+    stride: u32,
+    p_vertex_offset: *const i32,
+) {
+    used("vkCmdDrawMultiIndexedEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdDrawMultiIndexedEXT>(next(
+        97usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        draw_count,
+        p_index_info,
+        instance_count,
+        first_instance,
+        stride,
+        p_vertex_offset,
+    )
+}
+unsafe extern "system" fn cmd_encode_video_khr(
+    command_buffer: vk::CommandBuffer,
+    p_encode_info: *const vk::VideoEncodeInfoKHR<'_>,
+) {
+    used("vkCmdEncodeVideoKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEncodeVideoKHR>(next(
+        98usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_encode_info)
+}
+unsafe extern "system" fn cmd_end_conditional_rendering_ext(command_buffer: vk::CommandBuffer) {
+    used("vkCmdEndConditionalRenderingEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndConditionalRenderingEXT>(next(
+        99usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer)
+}
+unsafe extern "system" fn cmd_end_debug_utils_label_ext(command_buffer: vk::CommandBuffer) {
+    used("vkCmdEndDebugUtilsLabelEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndDebugUtilsLabelEXT>(next(
+        100usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer)
+}
+unsafe extern "system" fn cmd_end_query(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    used("vkCmdEndQuery", command_buffer);
+    used("vkCmdEndQuery", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndQuery>(next(
+        101usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, query_pool, query)
+}
+unsafe extern "system" fn cmd_end_query_indexed_ext(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    query: u32,
+    index: u32,
+) {
+    used("vkCmdEndQueryIndexedEXT", command_buffer);
+    used("vkCmdEndQueryIndexedEXT", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndQueryIndexedEXT>(next(
+        102usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, query_pool, query, index)
+}
+unsafe extern "system" fn cmd_end_render_pass(command_buffer: vk::CommandBuffer) {
+    used("vkCmdEndRenderPass", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndRenderPass>(next(
+        103usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer)
+}
+unsafe extern "system" fn cmd_end_render_pass2(
+    command_buffer: vk::CommandBuffer,
+    p_subpass_end_info: *const vk::SubpassEndInfo<'_>,
+) {
+    used("vkCmdEndRenderPass2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndRenderPass2>(next(
+        104usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_subpass_end_info)
+}
+unsafe extern "system" fn cmd_end_rendering(command_buffer: vk::CommandBuffer) {
+    used("vkCmdEndRendering", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndRendering>(next(
+        105usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer)
+}
+unsafe extern "system" fn cmd_end_transform_feedback_ext(
+    command_buffer: vk::CommandBuffer,
+    first_counter_buffer: u32,
+    counter_buffer_count: u32,
+    p_counter_buffers: *const vk::Buffer,
+    p_counter_buffer_offsets: *const vk::DeviceSize,
+) {
+    used("vkCmdEndTransformFeedbackEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndTransformFeedbackEXT>(next(
+        106usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_counter_buffer,
+        counter_buffer_count,
+        p_counter_buffers,
+        p_counter_buffer_offsets,
+    )
+}
+unsafe extern "system" fn cmd_end_video_coding_khr(
+    command_buffer: vk::CommandBuffer,
+    p_end_coding_info: *const vk::VideoEndCodingInfoKHR<'_>,
+) {
+    used("vkCmdEndVideoCodingKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdEndVideoCodingKHR>(next(
+        107usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_end_coding_info)
+}
+unsafe extern "system" fn cmd_execute_commands(
+    command_buffer: vk::CommandBuffer,
+    command_buffer_count: u32,
+    p_command_buffers: *const vk::CommandBuffer,
+) {
+    used("vkCmdExecuteCommands", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdExecuteCommands>(next(
+        108usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, command_buffer_count, p_command_buffers)
+}
+unsafe extern "system" fn cmd_execute_generated_commands_nv(
+    command_buffer: vk::CommandBuffer,
+    is_preprocessed: vk::Bool32,
+    p_generated_commands_info: *const vk::GeneratedCommandsInfoNV<'_>,
+) {
+    used("vkCmdExecuteGeneratedCommandsNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdExecuteGeneratedCommandsNV>(next(
+        109usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, is_preprocessed, p_generated_commands_info)
+}
+unsafe extern "system" fn cmd_fill_buffer(
+    command_buffer: vk::CommandBuffer,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    data: u32,
+) {
+    used("vkCmdFillBuffer", command_buffer);
+    used("vkCmdFillBuffer", dst_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdFillBuffer>(next(
+        110usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, dst_buffer, dst_offset, size, data)
+}
+unsafe extern "system" fn cmd_insert_debug_utils_label_ext(
+    command_buffer: vk::CommandBuffer,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    used("vkCmdInsertDebugUtilsLabelEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdInsertDebugUtilsLabelEXT>(next(
+        111usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_label_info)
+}
+unsafe extern "system" fn cmd_next_subpass(
+    command_buffer: vk::CommandBuffer,
+    contents: vk::SubpassContents,
+) {
+    used("vkCmdNextSubpass", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdNextSubpass>(next(
+        112usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, contents)
+}
+unsafe extern "system" fn cmd_next_subpass2(
+    command_buffer: vk::CommandBuffer,
+    p_subpass_begin_info: *const vk::SubpassBeginInfo<'_>,
+    p_subpass_end_info: *const vk::SubpassEndInfo<'_>,
+) {
+    used("vkCmdNextSubpass2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdNextSubpass2>(next(
+        113usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_subpass_begin_info, p_subpass_end_info)
+}
+unsafe extern "system" fn cmd_optical_flow_execute_nv(
+    command_buffer: vk::CommandBuffer,
+    session: vk::OpticalFlowSessionNV,
+    p_execute_info: *const vk::OpticalFlowExecuteInfoNV<'_>,
+) {
+    used("vkCmdOpticalFlowExecuteNV", command_buffer);
+    used("vkCmdOpticalFlowExecuteNV", session);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdOpticalFlowExecuteNV>(next(
+        114usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, session, p_execute_info)
+}
+unsafe extern "system" fn cmd_pipeline_barrier(
+    command_buffer: vk::CommandBuffer,
+    src_stage_mask: vk::PipelineStageFlags,
+    dst_stage_mask: vk::PipelineStageFlags,
+    dependency_flags: vk::DependencyFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const vk::MemoryBarrier<'_>,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const vk::BufferMemoryBarrier<'_>,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const vk::ImageMemoryBarrier<'_>,
+) {
+    used("vkCmdPipelineBarrier", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPipelineBarrier>(next(
+        115usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        src_stage_mask,
+        dst_stage_mask,
+        dependency_flags,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    )
+}
+unsafe extern "system" fn cmd_pipeline_barrier2(
+    command_buffer: vk::CommandBuffer,
+    p_dependency_info: *const vk::DependencyInfo<'_>,
+) {
+    used("vkCmdPipelineBarrier2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPipelineBarrier2>(next(
+        116usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_dependency_info)
+}
+unsafe extern "system" fn cmd_preprocess_generated_commands_nv(
+    command_buffer: vk::CommandBuffer,
+    p_generated_commands_info: *const vk::GeneratedCommandsInfoNV<'_>,
+) {
+    used("vkCmdPreprocessGeneratedCommandsNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPreprocessGeneratedCommandsNV>(next(
+        117usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_generated_commands_info)
+}
+unsafe extern "system" fn cmd_push_constants(
+    command_buffer: vk::CommandBuffer,
+    layout: vk::PipelineLayout,
+    stage_flags: vk::ShaderStageFlags,
+    offset: u32,
+    size: u32,
+    p_values: *const c_void,
+) {
+    used("vkCmdPushConstants", command_buffer);
+    used("vkCmdPushConstants", layout);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPushConstants>(next(
+        118usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, layout, stage_flags, offset, size, p_values)
+}
+unsafe extern "system" fn cmd_push_descriptor_set_khr(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    set: u32,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const vk::WriteDescriptorSet<'_>,
+) {
+    used("vkCmdPushDescriptorSetKHR", command_buffer);
+    used("vkCmdPushDescriptorSetKHR", layout);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPushDescriptorSetKHR>(next(
+        119usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        set,
+        descriptor_write_count,
+        p_descriptor_writes,
+    )
+}
+unsafe extern "system" fn cmd_push_descriptor_set_with_template_khr(
+    command_buffer: vk::CommandBuffer,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    layout: vk::PipelineLayout,
+    set: u32,
+    p_data: *const c_void,
+) {
+    used("vkCmdPushDescriptorSetWithTemplateKHR", command_buffer);
+    used(
+        "vkCmdPushDescriptorSetWithTemplateKHR",
+        descriptor_update_template,
+    );
+    used("vkCmdPushDescriptorSetWithTemplateKHR", layout);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdPushDescriptorSetWithTemplateKHR>(next(
+        120usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        descriptor_update_template,
+        layout,
+        set,
+        p_data,
+    )
+}
+unsafe extern "system" fn cmd_reset_event(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags,
+) {
+    used("vkCmdResetEvent", command_buffer);
+    used("vkCmdResetEvent", event);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdResetEvent>(next(
+        121usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, event, stage_mask)
+}
+unsafe extern "system" fn cmd_reset_event2(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags2,
+) {
+    used("vkCmdResetEvent2", command_buffer);
+    used("vkCmdResetEvent2", event);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdResetEvent2>(next(
+        122usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, event, stage_mask)
+}
+unsafe extern "system" fn cmd_reset_query_pool(
+    command_buffer: vk::CommandBuffer,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+) {
+    used("vkCmdResetQueryPool", command_buffer);
+    used("vkCmdResetQueryPool", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdResetQueryPool>(next(
+        123usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, query_pool, first_query, query_count)
+}
+unsafe extern "system" fn cmd_resolve_image(
+    command_buffer: vk::CommandBuffer,
+    src_image: vk::Image,
+    src_image_layout: vk::ImageLayout,
+    dst_image: vk::Image,
+    dst_image_layout: vk::ImageLayout,
+    region_count: u32,
+    p_regions: *const vk::ImageResolve,
+) {
+    used("vkCmdResolveImage", command_buffer);
+    used("vkCmdResolveImage", src_image);
+    used("vkCmdResolveImage", dst_image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdResolveImage>(next(
+        124usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        src_image,
+        src_image_layout,
+        dst_image,
+        dst_image_layout,
+        region_count,
+        p_regions,
+    )
+}
+unsafe extern "system" fn cmd_resolve_image2(
+    command_buffer: vk::CommandBuffer,
+    p_resolve_image_info: *const vk::ResolveImageInfo2<'_>,
+) {
+    used("vkCmdResolveImage2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdResolveImage2>(next(
+        125usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_resolve_image_info)
+}
+unsafe extern "system" fn cmd_set_alpha_to_coverage_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    alpha_to_coverage_enable: vk::Bool32,
+) {
+    used("vkCmdSetAlphaToCoverageEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetAlphaToCoverageEnableEXT>(next(
+        126usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, alpha_to_coverage_enable)
+}
+unsafe extern "system" fn cmd_set_alpha_to_one_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    alpha_to_one_enable: vk::Bool32,
+) {
+    used("vkCmdSetAlphaToOneEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetAlphaToOneEnableEXT>(next(
+        127usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, alpha_to_one_enable)
+}
+unsafe extern "system" fn cmd_set_attachment_feedback_loop_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    aspect_mask: vk::ImageAspectFlags,
+) {
+    used("vkCmdSetAttachmentFeedbackLoopEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetAttachmentFeedbackLoopEnableEXT>(
+        next(128usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(command_buffer, aspect_mask)
+}
+unsafe extern "system" fn cmd_set_blend_constants(
+    command_buffer: vk::CommandBuffer,
+    blend_constants: *const [f32; 4usize],
+) {
+    used("vkCmdSetBlendConstants", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetBlendConstants>(next(
+        129usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, blend_constants)
+}
+unsafe extern "system" fn cmd_set_checkpoint_nv(
+    command_buffer: vk::CommandBuffer,
+    p_checkpoint_marker: *const c_void,
+) {
+    used("vkCmdSetCheckpointNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCheckpointNV>(next(
+        130usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_checkpoint_marker)
+}
+unsafe extern "system" fn cmd_set_coarse_sample_order_nv(
+    command_buffer: vk::CommandBuffer,
+    sample_order_type: vk::CoarseSampleOrderTypeNV,
+    custom_sample_order_count: u32,
+    p_custom_sample_orders: *const vk::CoarseSampleOrderCustomNV<'_>,
+) {
+    used("vkCmdSetCoarseSampleOrderNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoarseSampleOrderNV>(next(
+        131usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        sample_order_type,
+        custom_sample_order_count,
+        p_custom_sample_orders,
+    )
+}
+unsafe extern "system" fn cmd_set_color_blend_advanced_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_advanced: *const vk::ColorBlendAdvancedEXT,
+) {
+    used("vkCmdSetColorBlendAdvancedEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetColorBlendAdvancedEXT>(next(
+        132usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_advanced,
+    )
+}
+unsafe extern "system" fn cmd_set_color_blend_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_enables: *const vk::Bool32,
+) {
+    used("vkCmdSetColorBlendEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetColorBlendEnableEXT>(next(
+        133usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_enables,
+    )
+}
+unsafe extern "system" fn cmd_set_color_blend_equation_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_blend_equations: *const vk::ColorBlendEquationEXT,
+) {
+    used("vkCmdSetColorBlendEquationEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetColorBlendEquationEXT>(next(
+        134usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_blend_equations,
+    )
+}
+unsafe extern "system" fn cmd_set_color_write_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    attachment_count: u32,
+    p_color_write_enables: *const vk::Bool32,
+) {
+    used("vkCmdSetColorWriteEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetColorWriteEnableEXT>(next(
+        135usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, attachment_count, p_color_write_enables)
+}
+unsafe extern "system" fn cmd_set_color_write_mask_ext(
+    command_buffer: vk::CommandBuffer,
+    first_attachment: u32,
+    attachment_count: u32,
+    p_color_write_masks: *const vk::ColorComponentFlags,
+) {
+    used("vkCmdSetColorWriteMaskEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetColorWriteMaskEXT>(next(
+        136usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_attachment,
+        attachment_count,
+        p_color_write_masks,
+    )
+}
+unsafe extern "system" fn cmd_set_conservative_rasterization_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    conservative_rasterization_mode: vk::ConservativeRasterizationModeEXT,
+) {
+    used("vkCmdSetConservativeRasterizationModeEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetConservativeRasterizationModeEXT>(
+        next(137usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(command_buffer, conservative_rasterization_mode)
+}
+unsafe extern "system" fn cmd_set_coverage_modulation_mode_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_mode: vk::CoverageModulationModeNV,
+) {
+    used("vkCmdSetCoverageModulationModeNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageModulationModeNV>(next(
+        138usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, coverage_modulation_mode)
+}
+unsafe extern "system" fn cmd_set_coverage_modulation_table_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_table_enable: vk::Bool32,
+) {
+    used("vkCmdSetCoverageModulationTableEnableNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageModulationTableEnableNV>(
+        next(139usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(command_buffer, coverage_modulation_table_enable)
+}
+unsafe extern "system" fn cmd_set_coverage_modulation_table_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_modulation_table_count: u32,
+    p_coverage_modulation_table: *const f32,
+) {
+    used("vkCmdSetCoverageModulationTableNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageModulationTableNV>(next(
+        140usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        coverage_modulation_table_count,
+        p_coverage_modulation_table,
+    )
+}
+unsafe extern "system" fn cmd_set_coverage_reduction_mode_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_reduction_mode: vk::CoverageReductionModeNV,
+) {
+    used("vkCmdSetCoverageReductionModeNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageReductionModeNV>(next(
+        141usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, coverage_reduction_mode)
+}
+unsafe extern "system" fn cmd_set_coverage_to_color_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_to_color_enable: vk::Bool32,
+) {
+    used("vkCmdSetCoverageToColorEnableNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageToColorEnableNV>(next(
+        142usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, coverage_to_color_enable)
+}
+unsafe extern "system" fn cmd_set_coverage_to_color_location_nv(
+    command_buffer: vk::CommandBuffer,
+    coverage_to_color_location: u32,
+) {
+    used("vkCmdSetCoverageToColorLocationNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCoverageToColorLocationNV>(next(
+        143usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, coverage_to_color_location)
+}
+unsafe extern "system" fn cmd_set_cull_mode(
+    command_buffer: vk::CommandBuffer,
+    cull_mode: vk::CullModeFlags,
+) {
+    used("vkCmdSetCullMode", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetCullMode>(next(
+        144usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, cull_mode)
+}
+unsafe extern "system" fn cmd_set_depth_bias(
+    command_buffer: vk::CommandBuffer,
+    depth_bias_constant_factor: f32,
+    depth_bias_clamp: f32,
+    depth_bias_slope_factor: f32,
+) {
+    used("vkCmdSetDepthBias", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthBias>(next(
+        145usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        depth_bias_constant_factor,
+        depth_bias_clamp,
+        depth_bias_slope_factor,
+    )
+}
+unsafe extern "system" fn cmd_set_depth_bias2_ext(
+    command_buffer: vk::CommandBuffer,
+    p_depth_bias_info: *const vk::DepthBiasInfoEXT<'_>,
+) {
+    used("vkCmdSetDepthBias2EXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthBias2EXT>(next(
+        146usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_depth_bias_info)
+}
+unsafe extern "system" fn cmd_set_depth_bias_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_bias_enable: vk::Bool32,
+) {
+    used("vkCmdSetDepthBiasEnable", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthBiasEnable>(next(
+        147usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, depth_bias_enable)
+}
+unsafe extern "system" fn cmd_set_depth_bounds(
+    command_buffer: vk::CommandBuffer,
+    min_depth_bounds: f32,
+    max_depth_bounds: f32,
+) {
+    used("vkCmdSetDepthBounds", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthBounds>(next(
+        148usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, min_depth_bounds, max_depth_bounds)
+}
+unsafe extern "system" fn cmd_set_depth_bounds_test_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_bounds_test_enable: vk::Bool32,
+) {
+    used("vkCmdSetDepthBoundsTestEnable", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthBoundsTestEnable>(next(
+        149usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, depth_bounds_test_enable)
+}
+unsafe extern "system" fn cmd_set_depth_clamp_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    depth_clamp_enable: vk::Bool32,
+) {
+    used("vkCmdSetDepthClampEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthClampEnableEXT>(next(
+        150usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, depth_clamp_enable)
+}
+unsafe extern "system" fn cmd_set_depth_clip_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    depth_clip_enable: vk::Bool32,
+) {
+    used("vkCmdSetDepthClipEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthClipEnableEXT>(next(
+        151usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, depth_clip_enable)
+}
+unsafe extern "system" fn cmd_set_depth_clip_negative_one_to_one_ext(
+    command_buffer: vk::CommandBuffer,
+    negative_one_to_one: vk::Bool32,
+) {
+    used("vkCmdSetDepthClipNegativeOneToOneEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthClipNegativeOneToOneEXT>(next(
+        152usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, negative_one_to_one)
+}
+unsafe extern "system" fn cmd_set_depth_compare_op(
+    command_buffer: vk::CommandBuffer,
+    depth_compare_op: vk::CompareOp,
+) {
+    used("vkCmdSetDepthCompareOp", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthCompareOp>(next(
+        153usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, depth_compare_op)
+}
+unsafe extern "system" fn cmd_set_depth_test_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_test_enable: vk::Bool32,
+) {
+    used("vkCmdSetDepthTestEnable", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthTestEnable>(next(
+        154usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, depth_test_enable)
+}
+unsafe extern "system" fn cmd_set_depth_write_enable(
+    command_buffer: vk::CommandBuffer,
+    depth_write_enable: vk::Bool32,
+) {
+    used("vkCmdSetDepthWriteEnable", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDepthWriteEnable>(next(
+        155usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, depth_write_enable)
+}
+unsafe extern "system" fn cmd_set_descriptor_buffer_offsets_ext(
+    command_buffer: vk::CommandBuffer,
+    pipeline_bind_point: vk::PipelineBindPoint,
+    layout: vk::PipelineLayout,
+    first_set: u32,
+    set_count: u32,
+    p_buffer_indices: *const u32,
+    p_offsets: *const vk::DeviceSize,
+) {
+    used("vkCmdSetDescriptorBufferOffsetsEXT", command_buffer);
+    used("vkCmdSetDescriptorBufferOffsetsEXT", layout);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDescriptorBufferOffsetsEXT>(next(
+        156usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        pipeline_bind_point,
+        layout,
+        first_set,
+        set_count,
+        p_buffer_indices,
+        p_offsets,
+    )
+}
+unsafe extern "system" fn cmd_set_device_mask(command_buffer: vk::CommandBuffer, device_mask: u32) {
+    used("vkCmdSetDeviceMask", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDeviceMask>(next(
+        157usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, device_mask)
+}
+unsafe extern "system" fn cmd_set_discard_rectangle_ext(
+    command_buffer: vk::CommandBuffer,
+    first_discard_rectangle: u32,
+    discard_rectangle_count: u32,
+    p_discard_rectangles: *const vk::Rect2D,
+) {
+    used("vkCmdSetDiscardRectangleEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDiscardRectangleEXT>(next(
+        158usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_discard_rectangle,
+        discard_rectangle_count,
+        p_discard_rectangles,
+    )
+}
+unsafe extern "system" fn cmd_set_discard_rectangle_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    discard_rectangle_enable: vk::Bool32,
+) {
+    used("vkCmdSetDiscardRectangleEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDiscardRectangleEnableEXT>(next(
+        159usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, discard_rectangle_enable)
+}
+unsafe extern "system" fn cmd_set_discard_rectangle_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    discard_rectangle_mode: vk::DiscardRectangleModeEXT,
+) {
+    used("vkCmdSetDiscardRectangleModeEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetDiscardRectangleModeEXT>(next(
+        160usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, discard_rectangle_mode)
+}
+unsafe extern "system" fn cmd_set_event(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    stage_mask: vk::PipelineStageFlags,
+) {
+    used("vkCmdSetEvent", command_buffer);
+    used("vkCmdSetEvent", event);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetEvent>(next(
+        161usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, event, stage_mask)
+}
+unsafe extern "system" fn cmd_set_event2(
+    command_buffer: vk::CommandBuffer,
+    event: vk::Event,
+    p_dependency_info: *const vk::DependencyInfo<'_>,
+) {
+    used("vkCmdSetEvent2", command_buffer);
+    used("vkCmdSetEvent2", event);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetEvent2>(next(
+        162usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, event, p_dependency_info)
+}
+unsafe extern "system" fn cmd_set_exclusive_scissor_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissor_enables: *const vk::Bool32,
+) {
+    used("vkCmdSetExclusiveScissorEnableNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetExclusiveScissorEnableNV>(next(
+        163usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_exclusive_scissor,
+        exclusive_scissor_count,
+        p_exclusive_scissor_enables,
+    )
+}
+unsafe extern "system" fn cmd_set_exclusive_scissor_nv(
+    command_buffer: vk::CommandBuffer,
+    first_exclusive_scissor: u32,
+    exclusive_scissor_count: u32,
+    p_exclusive_scissors: *const vk::Rect2D,
+) {
+    used("vkCmdSetExclusiveScissorNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetExclusiveScissorNV>(next(
+        164usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_exclusive_scissor,
+        exclusive_scissor_count,
+        p_exclusive_scissors,
+    )
+}
+unsafe extern "system" fn cmd_set_extra_primitive_overestimation_size_ext(
+    command_buffer: vk::CommandBuffer,
+    extra_primitive_overestimation_size: f32,
+) {
+    used(
+        "vkCmdSetExtraPrimitiveOverestimationSizeEXT",
+        command_buffer,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetExtraPrimitiveOverestimationSizeEXT>(
+        next(165usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(command_buffer, extra_primitive_overestimation_size)
+}
+unsafe extern "system" fn cmd_set_fragment_shading_rate_enum_nv(
+    command_buffer: vk::CommandBuffer,
+    shading_rate: vk::FragmentShadingRateNV,
+    combiner_ops: *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+) {
+    used("vkCmdSetFragmentShadingRateEnumNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetFragmentShadingRateEnumNV>(next(
+        166usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, shading_rate, combiner_ops)
+}
+unsafe extern "system" fn cmd_set_fragment_shading_rate_khr(
+    command_buffer: vk::CommandBuffer,
+    p_fragment_size: *const vk::Extent2D,
+    combiner_ops: *const [vk::FragmentShadingRateCombinerOpKHR; 2usize],
+) {
+    used("vkCmdSetFragmentShadingRateKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetFragmentShadingRateKHR>(next(
+        167usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_fragment_size, combiner_ops)
+}
+unsafe extern "system" fn cmd_set_front_face(
+    command_buffer: vk::CommandBuffer,
+    front_face: vk::FrontFace,
+) {
+    used("vkCmdSetFrontFace", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetFrontFace>(next(
+        168usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, front_face)
+}
+unsafe extern "system" fn cmd_set_line_rasterization_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    line_rasterization_mode: vk::LineRasterizationModeEXT,
+) {
+    used("vkCmdSetLineRasterizationModeEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLineRasterizationModeEXT>(next(
+        169usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, line_rasterization_mode)
+}
+unsafe extern "system" fn cmd_set_line_stipple_ext(
+    command_buffer: vk::CommandBuffer,
+    line_stipple_factor: u32,
+    line_stipple_pattern: u16,
+) {
+    used("vkCmdSetLineStippleEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLineStippleEXT>(next(
+        170usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, line_stipple_factor, line_stipple_pattern)
+}
+unsafe extern "system" fn cmd_set_line_stipple_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    stippled_line_enable: vk::Bool32,
+) {
+    used("vkCmdSetLineStippleEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLineStippleEnableEXT>(next(
+        171usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, stippled_line_enable)
+}
+unsafe extern "system" fn cmd_set_line_width(command_buffer: vk::CommandBuffer, line_width: f32) {
+    used("vkCmdSetLineWidth", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLineWidth>(next(
+        172usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, line_width)
+}
+unsafe extern "system" fn cmd_set_logic_op_ext(
+    command_buffer: vk::CommandBuffer,
+    logic_op: vk::LogicOp,
+) {
+    used("vkCmdSetLogicOpEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLogicOpEXT>(next(
+        173usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, logic_op)
+}
+unsafe extern "system" fn cmd_set_logic_op_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    logic_op_enable: vk::Bool32,
+) {
+    used("vkCmdSetLogicOpEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetLogicOpEnableEXT>(next(
+        174usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, logic_op_enable)
+}
+unsafe extern "system" fn cmd_set_patch_control_points_ext(
+    command_buffer: vk::CommandBuffer,
+    patch_control_points: u32,
+) {
+    used("vkCmdSetPatchControlPointsEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPatchControlPointsEXT>(next(
+        175usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, patch_control_points)
+}
+unsafe extern "system" fn cmd_set_performance_marker_intel(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::PerformanceMarkerInfoINTEL<'_>,
+) -> vk::Result {
+    used("vkCmdSetPerformanceMarkerINTEL", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPerformanceMarkerINTEL>(next(
+        176usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_marker_info)
+}
+unsafe extern "system" fn cmd_set_performance_override_intel(
+    command_buffer: vk::CommandBuffer,
+    p_override_info: *const vk::PerformanceOverrideInfoINTEL<'_>,
+) -> vk::Result {
+    used("vkCmdSetPerformanceOverrideINTEL", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPerformanceOverrideINTEL>(next(
+        177usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_override_info)
+}
+unsafe extern "system" fn cmd_set_performance_stream_marker_intel(
+    command_buffer: vk::CommandBuffer,
+    p_marker_info: *const vk::PerformanceStreamMarkerInfoINTEL<'_>,
+) -> vk::Result {
+    used("vkCmdSetPerformanceStreamMarkerINTEL", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPerformanceStreamMarkerINTEL>(next(
+        178usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_marker_info)
+}
+unsafe extern "system" fn cmd_set_polygon_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    polygon_mode: vk::PolygonMode,
+) {
+    used("vkCmdSetPolygonModeEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPolygonModeEXT>(next(
+        179usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, polygon_mode)
+}
+unsafe extern "system" fn cmd_set_primitive_restart_enable(
+    command_buffer: vk::CommandBuffer,
+    primitive_restart_enable: vk::Bool32,
+) {
+    used("vkCmdSetPrimitiveRestartEnable", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPrimitiveRestartEnable>(next(
+        180usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, primitive_restart_enable)
+}
+unsafe extern "system" fn cmd_set_primitive_topology(
+    command_buffer: vk::CommandBuffer,
+    primitive_topology: vk::PrimitiveTopology,
+) {
+    used("vkCmdSetPrimitiveTopology", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetPrimitiveTopology>(next(
+        181usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, primitive_topology)
+}
+unsafe extern "system" fn cmd_set_provoking_vertex_mode_ext(
+    command_buffer: vk::CommandBuffer,
+    provoking_vertex_mode: vk::ProvokingVertexModeEXT,
+) {
+    used("vkCmdSetProvokingVertexModeEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetProvokingVertexModeEXT>(next(
+        182usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, provoking_vertex_mode)
+}
+unsafe extern "system" fn cmd_set_rasterization_samples_ext(
+    command_buffer: vk::CommandBuffer,
+    rasterization_samples: vk::SampleCountFlags,
+) {
+    used("vkCmdSetRasterizationSamplesEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetRasterizationSamplesEXT>(next(
+        183usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, rasterization_samples)
+}
+unsafe extern "system" fn cmd_set_rasterization_stream_ext(
+    command_buffer: vk::CommandBuffer,
+    rasterization_stream: u32,
+) {
+    used("vkCmdSetRasterizationStreamEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetRasterizationStreamEXT>(next(
+        184usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, rasterization_stream)
+}
+unsafe extern "system" fn cmd_set_rasterizer_discard_enable(
+    command_buffer: vk::CommandBuffer,
+    rasterizer_discard_enable: vk::Bool32,
+) {
+    used("vkCmdSetRasterizerDiscardEnable", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetRasterizerDiscardEnable>(next(
+        185usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, rasterizer_discard_enable)
+}
+unsafe extern "system" fn cmd_set_ray_tracing_pipeline_stack_size_khr(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stack_size: u32,
+) {
+    used("vkCmdSetRayTracingPipelineStackSizeKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetRayTracingPipelineStackSizeKHR>(next(
+        186usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, pipeline_stack_size)
+}
+unsafe extern "system" fn cmd_set_representative_fragment_test_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    representative_fragment_test_enable: vk::Bool32,
+) {
+    used("vkCmdSetRepresentativeFragmentTestEnableNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetRepresentativeFragmentTestEnableNV>(
+        next(187usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(command_buffer, representative_fragment_test_enable)
+}
+unsafe extern "system" fn cmd_set_sample_locations_ext(
+    command_buffer: vk::CommandBuffer,
+    p_sample_locations_info: *const vk::SampleLocationsInfoEXT<'_>,
+) {
+    used("vkCmdSetSampleLocationsEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetSampleLocationsEXT>(next(
+        188usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, p_sample_locations_info)
+}
+unsafe extern "system" fn cmd_set_sample_locations_enable_ext(
+    command_buffer: vk::CommandBuffer,
+    sample_locations_enable: vk::Bool32,
+) {
+    used("vkCmdSetSampleLocationsEnableEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetSampleLocationsEnableEXT>(next(
+        189usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, sample_locations_enable)
+}
+unsafe extern "system" fn cmd_set_sample_mask_ext(
+    command_buffer: vk::CommandBuffer,
+    samples: vk::SampleCountFlags,
+    p_sample_mask: *const vk::SampleMask,
+) {
+    used("vkCmdSetSampleMaskEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetSampleMaskEXT>(next(
+        190usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, samples, p_sample_mask)
+}
+unsafe extern "system" fn cmd_set_scissor(
+    command_buffer: vk::CommandBuffer,
+    first_scissor: u32,
+    scissor_count: u32,
+    p_scissors: *const vk::Rect2D,
+) {
+    used("vkCmdSetScissor", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetScissor>(next(
+        191usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, first_scissor, scissor_count, p_scissors)
+}
+unsafe extern "system" fn cmd_set_scissor_with_count(
+    command_buffer: vk::CommandBuffer,
+    scissor_count: u32,
+    p_scissors: *const vk::Rect2D,
+) {
+    used("vkCmdSetScissorWithCount", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetScissorWithCount>(next(
+        192usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, scissor_count, p_scissors)
+}
+unsafe extern "system" fn cmd_set_shading_rate_image_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    shading_rate_image_enable: vk::Bool32,
+) {
+    used("vkCmdSetShadingRateImageEnableNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetShadingRateImageEnableNV>(next(
+        193usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, shading_rate_image_enable)
+}
+unsafe extern "system" fn cmd_set_stencil_compare_mask(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    compare_mask: u32,
+) {
+    used("vkCmdSetStencilCompareMask", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetStencilCompareMask>(next(
+        194usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, face_mask, compare_mask)
+}
+unsafe extern "system" fn cmd_set_stencil_op(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    fail_op: vk::StencilOp,
+    pass_op: vk::StencilOp,
+    depth_fail_op: vk::StencilOp,
+    compare_op: vk::CompareOp,
+) {
+    used("vkCmdSetStencilOp", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetStencilOp>(next(
+        195usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        face_mask,
+        fail_op,
+        pass_op,
+        depth_fail_op,
+        compare_op,
+    )
+}
+unsafe extern "system" fn cmd_set_stencil_reference(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    reference: u32,
+) {
+    used("vkCmdSetStencilReference", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetStencilReference>(next(
+        196usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, face_mask, reference)
+}
+unsafe extern "system" fn cmd_set_stencil_test_enable(
+    command_buffer: vk::CommandBuffer,
+    stencil_test_enable: vk::Bool32,
+) {
+    used("vkCmdSetStencilTestEnable", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetStencilTestEnable>(next(
+        197usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, stencil_test_enable)
+}
+unsafe extern "system" fn cmd_set_stencil_write_mask(
+    command_buffer: vk::CommandBuffer,
+    face_mask: vk::StencilFaceFlags,
+    write_mask: u32,
+) {
+    used("vkCmdSetStencilWriteMask", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetStencilWriteMask>(next(
+        198usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, face_mask, write_mask)
+}
+unsafe extern "system" fn cmd_set_tessellation_domain_origin_ext(
+    command_buffer: vk::CommandBuffer,
+    domain_origin: vk::TessellationDomainOrigin,
+) {
+    used("vkCmdSetTessellationDomainOriginEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetTessellationDomainOriginEXT>(next(
+        199usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, domain_origin)
+}
+unsafe extern "system" fn cmd_set_vertex_input_ext(
+    command_buffer: vk::CommandBuffer,
+    vertex_binding_description_count: u32,
+    p_vertex_binding_descriptions: *const vk::VertexInputBindingDescription2EXT<'_>,
+    vertex_attribute_description_count: u32,
+    p_vertex_attribute_descriptions: *const vk::VertexInputAttributeDescription2EXT<'_>,
+) {
+    used("vkCmdSetVertexInputEXT", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetVertexInputEXT>(next(
+        200usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        vertex_binding_description_count,
+        p_vertex_binding_descriptions,
+        vertex_attribute_description_count,
+        p_vertex_attribute_descriptions,
+    )
+}
+unsafe extern "system" fn cmd_set_viewport(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewports: *const vk::Viewport,
+) {
+    used("vkCmdSetViewport", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewport>(next(
+        201usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, first_viewport, viewport_count, p_viewports)
+}
+unsafe extern "system" fn cmd_set_viewport_shading_rate_palette_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_shading_rate_palettes: *const vk::ShadingRatePaletteNV<'_>,
+) {
+    used("vkCmdSetViewportShadingRatePaletteNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewportShadingRatePaletteNV>(next(
+        202usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_shading_rate_palettes,
+    )
+}
+unsafe extern "system" fn cmd_set_viewport_swizzle_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_swizzles: *const vk::ViewportSwizzleNV,
+) {
+    used("vkCmdSetViewportSwizzleNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewportSwizzleNV>(next(
+        203usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_viewport_swizzles,
+    )
+}
+unsafe extern "system" fn cmd_set_viewport_w_scaling_enable_nv(
+    command_buffer: vk::CommandBuffer,
+    viewport_w_scaling_enable: vk::Bool32,
+) {
+    used("vkCmdSetViewportWScalingEnableNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewportWScalingEnableNV>(next(
+        204usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, viewport_w_scaling_enable)
+}
+unsafe extern "system" fn cmd_set_viewport_w_scaling_nv(
+    command_buffer: vk::CommandBuffer,
+    first_viewport: u32,
+    viewport_count: u32,
+    p_viewport_w_scalings: *const vk::ViewportWScalingNV,
+) {
+    used("vkCmdSetViewportWScalingNV", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewportWScalingNV>(next(
+        205usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        first_viewport,
+        viewport_count,
+        p_viewport_w_scalings,
+    )
+}
+unsafe extern "system" fn cmd_set_viewport_with_count(
+    command_buffer: vk::CommandBuffer,
+    viewport_count: u32,
+    p_viewports: *const vk::Viewport,
+) {
+    used("vkCmdSetViewportWithCount", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSetViewportWithCount>(next(
+        206usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, viewport_count, p_viewports)
+}
+unsafe extern "system" fn cmd_subpass_shading_huawei(command_buffer: vk::CommandBuffer) {
+    used("vkCmdSubpassShadingHUAWEI", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdSubpassShadingHUAWEI>(next(
+        207usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer)
+}
+unsafe extern "system" fn cmd_trace_rays_indirect2_khr(
+    command_buffer: vk::CommandBuffer,
+    indirect_device_address: vk::DeviceAddress,
+) {
+    used("vkCmdTraceRaysIndirect2KHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdTraceRaysIndirect2KHR>(next(
+        208usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, indirect_device_address)
+}
+unsafe extern "system" fn cmd_trace_rays_indirect_khr(
+    command_buffer: vk::CommandBuffer,
+    p_raygen_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_miss_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_hit_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_callable_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    indirect_device_address: vk::DeviceAddress,
+) {
+    used("vkCmdTraceRaysIndirectKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdTraceRaysIndirectKHR>(next(
+        209usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        p_raygen_shader_binding_table,
+        p_miss_shader_binding_table,
+        p_hit_shader_binding_table,
+        p_callable_shader_binding_table,
+        indirect_device_address,
+    )
+}
+unsafe extern "system" fn cmd_trace_rays_khr(
+    command_buffer: vk::CommandBuffer,
+    p_raygen_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_miss_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_hit_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    p_callable_shader_binding_table: *const vk::StridedDeviceAddressRegionKHR,
+    width: u32,
+    height: u32,
+    depth: u32,
+) {
+    used("vkCmdTraceRaysKHR", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdTraceRaysKHR>(next(
+        210usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        p_raygen_shader_binding_table,
+        p_miss_shader_binding_table,
+        p_hit_shader_binding_table,
+        p_callable_shader_binding_table,
+        width,
+        height,
+        depth,
+    )
+}
+unsafe extern "system" fn cmd_trace_rays_nv(
+    command_buffer: vk::CommandBuffer,
+    raygen_shader_binding_table_buffer: vk::Buffer,
+    raygen_shader_binding_offset: vk::DeviceSize,
+    miss_shader_binding_table_buffer: vk::Buffer,
+    miss_shader_binding_offset: vk::DeviceSize,
+    miss_shader_binding_stride: vk::DeviceSize,
+    hit_shader_binding_table_buffer: vk::Buffer,
+    hit_shader_binding_offset: vk::DeviceSize,
+    hit_shader_binding_stride: vk::DeviceSize,
+    callable_shader_binding_table_buffer: vk::Buffer,
+    callable_shader_binding_offset: vk::DeviceSize,
+    callable_shader_binding_stride: vk::DeviceSize,
+    width: u32,
+    height: u32,
+    depth: u32,
+) {
+    used("vkCmdTraceRaysNV", command_buffer);
+    used("vkCmdTraceRaysNV", raygen_shader_binding_table_buffer);
+    used("vkCmdTraceRaysNV", miss_shader_binding_table_buffer);
+    used("vkCmdTraceRaysNV", hit_shader_binding_table_buffer);
+    used("vkCmdTraceRaysNV", callable_shader_binding_table_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdTraceRaysNV>(next(
+        211usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        raygen_shader_binding_table_buffer,
+        raygen_shader_binding_offset,
+        miss_shader_binding_table_buffer,
+        miss_shader_binding_offset,
+        miss_shader_binding_stride,
+        hit_shader_binding_table_buffer,
+        hit_shader_binding_offset,
+        hit_shader_binding_stride,
+        callable_shader_binding_table_buffer,
+        callable_shader_binding_offset,
+        callable_shader_binding_stride,
+        width,
+        height,
+        depth,
+    )
+}
+unsafe extern "system" fn cmd_update_buffer(
+    command_buffer: vk::CommandBuffer,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    data_size: vk::DeviceSize,
+    p_data: *const c_void,
+) {
+    used("vkCmdUpdateBuffer", command_buffer);
+    used("vkCmdUpdateBuffer", dst_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdUpdateBuffer>(next(
+        212usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, dst_buffer, dst_offset, data_size, p_data)
+}
+unsafe extern "system" fn cmd_wait_events(
+    command_buffer: vk::CommandBuffer,
+    event_count: u32,
+    p_events: *const vk::Event,
+    src_stage_mask: vk::PipelineStageFlags,
+    dst_stage_mask: vk::PipelineStageFlags,
+    memory_barrier_count: u32,
+    p_memory_barriers: *const vk::MemoryBarrier<'_>,
+    buffer_memory_barrier_count: u32,
+    p_buffer_memory_barriers: *const vk::BufferMemoryBarrier<'_>,
+    image_memory_barrier_count: u32,
+    p_image_memory_barriers: *const vk::ImageMemoryBarrier<'_>,
+) {
+    used("vkCmdWaitEvents", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWaitEvents>(next(
+        213usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        event_count,
+        p_events,
+        src_stage_mask,
+        dst_stage_mask,
+        memory_barrier_count,
+        p_memory_barriers,
+        buffer_memory_barrier_count,
+        p_buffer_memory_barriers,
+        image_memory_barrier_count,
+        p_image_memory_barriers,
+    )
+}
+unsafe extern "system" fn cmd_wait_events2(
+    command_buffer: vk::CommandBuffer,
+    event_count: u32,
+    p_events: *const vk::Event,
+    p_dependency_infos: *const vk::DependencyInfo<'_>,
+) {
+    used("vkCmdWaitEvents2", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWaitEvents2>(next(
+        214usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, event_count, p_events, p_dependency_infos)
+}
+unsafe extern "system" fn cmd_write_acceleration_structures_properties_khr(
+    command_buffer: vk::CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureKHR,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    used(
+        "vkCmdWriteAccelerationStructuresPropertiesKHR",
+        command_buffer,
+    );
+    used("vkCmdWriteAccelerationStructuresPropertiesKHR", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteAccelerationStructuresPropertiesKHR>(
+        next(215usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    )
+}
+unsafe extern "system" fn cmd_write_acceleration_structures_properties_nv(
+    command_buffer: vk::CommandBuffer,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureNV,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    used(
+        "vkCmdWriteAccelerationStructuresPropertiesNV",
+        command_buffer,
+    );
+    used("vkCmdWriteAccelerationStructuresPropertiesNV", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteAccelerationStructuresPropertiesNV>(
+        next(216usize, dispatch_key(command_buffer.as_raw())),
+    );
+    next(
+        command_buffer,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        query_pool,
+        first_query,
+    )
+}
+unsafe extern "system" fn cmd_write_buffer_marker2_amd(
+    command_buffer: vk::CommandBuffer,
+    stage: vk::PipelineStageFlags2,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    marker: u32,
+) {
+    used("vkCmdWriteBufferMarker2AMD", command_buffer);
+    used("vkCmdWriteBufferMarker2AMD", dst_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteBufferMarker2AMD>(next(
+        217usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, stage, dst_buffer, dst_offset, marker)
+}
+unsafe extern "system" fn cmd_write_buffer_marker_amd(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stage: vk::PipelineStageFlags,
+    dst_buffer: vk::Buffer,
+    dst_offset: vk::DeviceSize,
+    marker: u32,
+) {
+    used("vkCmdWriteBufferMarkerAMD", command_buffer);
+    used("vkCmdWriteBufferMarkerAMD", dst_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteBufferMarkerAMD>(next(
+        218usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        pipeline_stage,
+        dst_buffer,
+        dst_offset,
+        marker,
+    )
+}
+unsafe extern "system" fn cmd_write_micromaps_properties_ext(
+    command_buffer: vk::CommandBuffer,
+    micromap_count: u32,
+    p_micromaps: *const vk::MicromapEXT,
+    query_type: vk::QueryType,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+) {
+    used("vkCmdWriteMicromapsPropertiesEXT", command_buffer);
+    used("vkCmdWriteMicromapsPropertiesEXT", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteMicromapsPropertiesEXT>(next(
+        219usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(
+        command_buffer,
+        micromap_count,
+        p_micromaps,
+        query_type,
+        query_pool,
+        first_query,
+    )
+}
+unsafe extern "system" fn cmd_write_timestamp(
+    command_buffer: vk::CommandBuffer,
+    pipeline_stage: vk::PipelineStageFlags,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    used("vkCmdWriteTimestamp", command_buffer);
+    used("vkCmdWriteTimestamp", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteTimestamp>(next(
+        220usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, pipeline_stage, query_pool, query)
+}
+unsafe extern "system" fn cmd_write_timestamp2(
+    command_buffer: vk::CommandBuffer,
+    stage: vk::PipelineStageFlags2,
+    query_pool: vk::QueryPool,
+    query: u32,
+) {
+    used("vkCmdWriteTimestamp2", command_buffer);
+    used("vkCmdWriteTimestamp2", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCmdWriteTimestamp2>(next(
+        221usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, stage, query_pool, query)
+}
+unsafe extern "system" fn compile_deferred_nv(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    shader: u32,
+) -> vk::Result {
+    used("vkCompileDeferredNV", device);
+    used("vkCompileDeferredNV", pipeline);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCompileDeferredNV>(next(
+        222usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, pipeline, shader)
+}
+unsafe extern "system" fn copy_acceleration_structure_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyAccelerationStructureInfoKHR<'_>,
+) -> vk::Result {
+    used("vkCopyAccelerationStructureKHR", device);
+    used("vkCopyAccelerationStructureKHR", deferred_operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyAccelerationStructureKHR>(next(
+        223usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, deferred_operation, p_info)
+}
+unsafe extern "system" fn copy_acceleration_structure_to_memory_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyAccelerationStructureToMemoryInfoKHR<'_>,
+) -> vk::Result {
+    used("vkCopyAccelerationStructureToMemoryKHR", device);
+    used("vkCopyAccelerationStructureToMemoryKHR", deferred_operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyAccelerationStructureToMemoryKHR>(next(
+        224usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, deferred_operation, p_info)
+}
+unsafe extern "system" fn copy_memory_to_acceleration_structure_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMemoryToAccelerationStructureInfoKHR<'_>,
+) -> vk::Result {
+    used("vkCopyMemoryToAccelerationStructureKHR", device);
+    used("vkCopyMemoryToAccelerationStructureKHR", deferred_operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyMemoryToAccelerationStructureKHR>(next(
+        225usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, deferred_operation, p_info)
+}
+unsafe extern "system" fn copy_memory_to_micromap_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMemoryToMicromapInfoEXT<'_>,
+) -> vk::Result {
+    used("vkCopyMemoryToMicromapEXT", device);
+    used("vkCopyMemoryToMicromapEXT", deferred_operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyMemoryToMicromapEXT>(next(
+        226usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, deferred_operation, p_info)
+}
+unsafe extern "system" fn copy_micromap_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMicromapInfoEXT<'_>,
+) -> vk::Result {
+    used("vkCopyMicromapEXT", device);
+    used("vkCopyMicromapEXT", deferred_operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyMicromapEXT>(next(
+        227usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, deferred_operation, p_info)
+}
+unsafe extern "system" fn copy_micromap_to_memory_ext(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    p_info: *const vk::CopyMicromapToMemoryInfoEXT<'_>,
+) -> vk::Result {
+    used("vkCopyMicromapToMemoryEXT", device);
+    used("vkCopyMicromapToMemoryEXT", deferred_operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCopyMicromapToMemoryEXT>(next(
+        228usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, deferred_operation, p_info)
+}
+unsafe extern "system" fn create_acceleration_structure_khr(
+    device: vk::Device,
+    p_create_info: *const vk::AccelerationStructureCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_acceleration_structure: *mut vk::AccelerationStructureKHR,
+) -> vk::Result {
+    used("vkCreateAccelerationStructureKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateAccelerationStructureKHR>(next(
+        229usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_acceleration_structure);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_acceleration_structure.is_null() {
+        created(
+            "vkCreateAccelerationStructureKHR",
+            *p_acceleration_structure,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_acceleration_structure_nv(
+    device: vk::Device,
+    p_create_info: *const vk::AccelerationStructureCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_acceleration_structure: *mut vk::AccelerationStructureNV,
+) -> vk::Result {
+    used("vkCreateAccelerationStructureNV", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateAccelerationStructureNV>(next(
+        230usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_acceleration_structure);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_acceleration_structure.is_null() {
+        created(
+            "vkCreateAccelerationStructureNV",
+            *p_acceleration_structure,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_android_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::AndroidSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateAndroidSurfaceKHR", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateAndroidSurfaceKHR>(next(
+        231usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateAndroidSurfaceKHR",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_buffer(
+    device: vk::Device,
+    p_create_info: *const vk::BufferCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_buffer: *mut vk::Buffer,
+) -> vk::Result {
+    used("vkCreateBuffer", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateBuffer>(next(
+        232usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_buffer);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_buffer.is_null() {
+        created("vkCreateBuffer", *p_buffer, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_buffer_collection_fuchsia(
+    device: vk::Device,
+    p_create_info: *const vk::BufferCollectionCreateInfoFUCHSIA<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_collection: *mut vk::BufferCollectionFUCHSIA,
+) -> vk::Result {
+    used("vkCreateBufferCollectionFUCHSIA", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateBufferCollectionFUCHSIA>(next(
+        233usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_collection);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_collection.is_null() {
+        created(
+            "vkCreateBufferCollectionFUCHSIA",
+            *p_collection,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_buffer_view(
+    device: vk::Device,
+    p_create_info: *const vk::BufferViewCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_view: *mut vk::BufferView,
+) -> vk::Result {
+    used("vkCreateBufferView", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateBufferView>(next(
+        234usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_view);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_view.is_null() {
+        created("vkCreateBufferView", *p_view, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_command_pool(
+    device: vk::Device,
+    p_create_info: *const vk::CommandPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_command_pool: *mut vk::CommandPool,
+) -> vk::Result {
+    used("vkCreateCommandPool", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateCommandPool>(next(
+        235usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_command_pool);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_command_pool.is_null() {
+        created(
+            "vkCreateCommandPool",
+            *p_command_pool,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_compute_pipelines(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::ComputePipelineCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    used("vkCreateComputePipelines", device);
+    used("vkCreateComputePipelines", pipeline_cache);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateComputePipelines>(next(
+        236usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_pipelines.is_null() {
+        let parent = parent_of(device);
+        for i in 0..create_info_count as usize {
+            created(
+                "vkCreateComputePipelines",
+                *p_pipelines.add(i),
+                parent,
+                true,
+            );
+        }
+    }
+    result
+}
+unsafe extern "system" fn create_cu_function_nvx(
+    device: vk::Device,
+    p_create_info: *const vk::CuFunctionCreateInfoNVX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_function: *mut vk::CuFunctionNVX,
+) -> vk::Result {
+    used("vkCreateCuFunctionNVX", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateCuFunctionNVX>(next(
+        237usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_function);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_function.is_null() {
+        created(
+            "vkCreateCuFunctionNVX",
+            *p_function,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_cu_module_nvx(
+    device: vk::Device,
+    p_create_info: *const vk::CuModuleCreateInfoNVX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_module: *mut vk::CuModuleNVX,
+) -> vk::Result {
+    used("vkCreateCuModuleNVX", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateCuModuleNVX>(next(
+        238usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_module);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_module.is_null() {
+        created("vkCreateCuModuleNVX", *p_module, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_debug_report_callback_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::DebugReportCallbackCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_callback: *mut vk::DebugReportCallbackEXT,
+) -> vk::Result {
+    used("vkCreateDebugReportCallbackEXT", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDebugReportCallbackEXT>(next(
+        239usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_callback);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_callback.is_null() {
+        created(
+            "vkCreateDebugReportCallbackEXT",
+            *p_callback,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_debug_utils_messenger_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::DebugUtilsMessengerCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_messenger: *mut vk::DebugUtilsMessengerEXT,
+) -> vk::Result {
+    used("vkCreateDebugUtilsMessengerEXT", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDebugUtilsMessengerEXT>(next(
+        240usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_messenger);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_messenger.is_null() {
+        created(
+            "vkCreateDebugUtilsMessengerEXT",
+            *p_messenger,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_deferred_operation_khr(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_deferred_operation: *mut vk::DeferredOperationKHR,
+) -> vk::Result {
+    used("vkCreateDeferredOperationKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDeferredOperationKHR>(next(
+        241usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_allocator, p_deferred_operation);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_deferred_operation.is_null() {
+        created(
+            "vkCreateDeferredOperationKHR",
+            *p_deferred_operation,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_descriptor_pool(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_descriptor_pool: *mut vk::DescriptorPool,
+) -> vk::Result {
+    used("vkCreateDescriptorPool", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDescriptorPool>(next(
+        242usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_descriptor_pool);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_descriptor_pool.is_null() {
+        created(
+            "vkCreateDescriptorPool",
+            *p_descriptor_pool,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_descriptor_set_layout(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorSetLayoutCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_set_layout: *mut vk::DescriptorSetLayout,
+) -> vk::Result {
+    used("vkCreateDescriptorSetLayout", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDescriptorSetLayout>(next(
+        243usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_set_layout);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_set_layout.is_null() {
+        created(
+            "vkCreateDescriptorSetLayout",
+            *p_set_layout,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_descriptor_update_template(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorUpdateTemplateCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_descriptor_update_template: *mut vk::DescriptorUpdateTemplate,
+) -> vk::Result {
+    used("vkCreateDescriptorUpdateTemplate", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDescriptorUpdateTemplate>(next(
+        244usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(
+        device,
+        p_create_info,
+        p_allocator,
+        p_descriptor_update_template,
+    );
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_descriptor_update_template.is_null() {
+        created(
+            "vkCreateDescriptorUpdateTemplate",
+            *p_descriptor_update_template,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_device(
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    used("vkCreateDevice", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDevice>(next(
+        245usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let result = next(physical_device, p_create_info, p_allocator, p_device);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_device.is_null() {
+        created(
+            "vkCreateDevice",
+            *p_device,
+            parent_of(physical_device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_direct_fb_surface_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::DirectFBSurfaceCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateDirectFBSurfaceEXT", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDirectFBSurfaceEXT>(next(
+        246usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateDirectFBSurfaceEXT",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_display_mode_khr(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+    p_create_info: *const vk::DisplayModeCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_mode: *mut vk::DisplayModeKHR,
+) -> vk::Result {
+    used("vkCreateDisplayModeKHR", physical_device);
+    used("vkCreateDisplayModeKHR", display);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDisplayModeKHR>(next(
+        247usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let result = next(physical_device, display, p_create_info, p_allocator, p_mode);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_mode.is_null() {
+        created("vkCreateDisplayModeKHR", *p_mode, parent_of(display), true);
+    }
+    result
+}
+unsafe extern "system" fn create_display_plane_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::DisplaySurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateDisplayPlaneSurfaceKHR", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateDisplayPlaneSurfaceKHR>(next(
+        248usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateDisplayPlaneSurfaceKHR",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_event(
+    device: vk::Device,
+    p_create_info: *const vk::EventCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_event: *mut vk::Event,
+) -> vk::Result {
+    used("vkCreateEvent", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateEvent>(next(
+        249usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_event);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_event.is_null() {
+        created("vkCreateEvent", *p_event, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_fence(
+    device: vk::Device,
+    p_create_info: *const vk::FenceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    used("vkCreateFence", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateFence>(next(
+        250usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_fence);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_fence.is_null() {
+        created("vkCreateFence", *p_fence, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_framebuffer(
+    device: vk::Device,
+    p_create_info: *const vk::FramebufferCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_framebuffer: *mut vk::Framebuffer,
+) -> vk::Result {
+    used("vkCreateFramebuffer", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateFramebuffer>(next(
+        251usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_framebuffer);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_framebuffer.is_null() {
+        created(
+            "vkCreateFramebuffer",
+            *p_framebuffer,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_graphics_pipelines(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::GraphicsPipelineCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    used("vkCreateGraphicsPipelines", device);
+    used("vkCreateGraphicsPipelines", pipeline_cache);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateGraphicsPipelines>(next(
+        252usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_pipelines.is_null() {
+        let parent = parent_of(device);
+        for i in 0..create_info_count as usize {
+            created(
+                "vkCreateGraphicsPipelines",
+                *p_pipelines.add(i),
+                parent,
+                true,
+            );
+        }
+    }
+    result
+}
+unsafe extern "system" fn create_headless_surface_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::HeadlessSurfaceCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateHeadlessSurfaceEXT", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateHeadlessSurfaceEXT>(next(
+        253usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateHeadlessSurfaceEXT",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_ios_surface_mvk(
+    instance: vk::Instance,
+    p_create_info: *const vk::IOSSurfaceCreateInfoMVK<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateIOSSurfaceMVK", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateIOSSurfaceMVK>(next(
+        254usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateIOSSurfaceMVK",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_image(
+    device: vk::Device,
+    p_create_info: *const vk::ImageCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_image: *mut vk::Image,
+) -> vk::Result {
+    used("vkCreateImage", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateImage>(next(
+        255usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_image);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_image.is_null() {
+        created("vkCreateImage", *p_image, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_image_pipe_surface_fuchsia(
+    instance: vk::Instance,
+    p_create_info: *const vk::ImagePipeSurfaceCreateInfoFUCHSIA<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateImagePipeSurfaceFUCHSIA", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateImagePipeSurfaceFUCHSIA>(next(
+        256usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateImagePipeSurfaceFUCHSIA",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_image_view(
+    device: vk::Device,
+    p_create_info: *const vk::ImageViewCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_view: *mut vk::ImageView,
+) -> vk::Result {
+    used("vkCreateImageView", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateImageView>(next(
+        257usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_view);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_view.is_null() {
+        created("vkCreateImageView", *p_view, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_indirect_commands_layout_nv(
+    device: vk::Device,
+    p_create_info: *const vk::IndirectCommandsLayoutCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_indirect_commands_layout: *mut vk::IndirectCommandsLayoutNV,
+) -> vk::Result {
+    used("vkCreateIndirectCommandsLayoutNV", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateIndirectCommandsLayoutNV>(next(
+        258usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(
+        device,
+        p_create_info,
+        p_allocator,
+        p_indirect_commands_layout,
+    );
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_indirect_commands_layout.is_null() {
+        created(
+            "vkCreateIndirectCommandsLayoutNV",
+            *p_indirect_commands_layout,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_instance(
+    p_create_info: *const vk::InstanceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateInstance>(next(259usize, 0));
+    let result = next(p_create_info, p_allocator, p_instance);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_instance.is_null() {
+        created("vkCreateInstance", *p_instance, None, true);
+    }
+    result
+}
+unsafe extern "system" fn create_mac_os_surface_mvk(
+    instance: vk::Instance,
+    p_create_info: *const vk::MacOSSurfaceCreateInfoMVK<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateMacOSSurfaceMVK", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateMacOSSurfaceMVK>(next(
+        260usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateMacOSSurfaceMVK",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_metal_surface_ext(
+    instance: vk::Instance,
+    p_create_info: *const vk::MetalSurfaceCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateMetalSurfaceEXT", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateMetalSurfaceEXT>(next(
+        261usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateMetalSurfaceEXT",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_micromap_ext(
+    device: vk::Device,
+    p_create_info: *const vk::MicromapCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_micromap: *mut vk::MicromapEXT,
+) -> vk::Result {
+    used("vkCreateMicromapEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateMicromapEXT>(next(
+        262usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_micromap);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_micromap.is_null() {
+        created("vkCreateMicromapEXT", *p_micromap, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_optical_flow_session_nv(
+    device: vk::Device,
+    p_create_info: *const vk::OpticalFlowSessionCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_session: *mut vk::OpticalFlowSessionNV,
+) -> vk::Result {
+    used("vkCreateOpticalFlowSessionNV", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateOpticalFlowSessionNV>(next(
+        263usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_session);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_session.is_null() {
+        created(
+            "vkCreateOpticalFlowSessionNV",
+            *p_session,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_pipeline_cache(
+    device: vk::Device,
+    p_create_info: *const vk::PipelineCacheCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipeline_cache: *mut vk::PipelineCache,
+) -> vk::Result {
+    used("vkCreatePipelineCache", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreatePipelineCache>(next(
+        264usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_pipeline_cache);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_pipeline_cache.is_null() {
+        created(
+            "vkCreatePipelineCache",
+            *p_pipeline_cache,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_pipeline_layout(
+    device: vk::Device,
+    p_create_info: *const vk::PipelineLayoutCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipeline_layout: *mut vk::PipelineLayout,
+) -> vk::Result {
+    used("vkCreatePipelineLayout", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreatePipelineLayout>(next(
+        265usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_pipeline_layout);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_pipeline_layout.is_null() {
+        created(
+            "vkCreatePipelineLayout",
+            *p_pipeline_layout,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_private_data_slot(
+    device: vk::Device,
+    p_create_info: *const vk::PrivateDataSlotCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_private_data_slot: *mut vk::PrivateDataSlot,
+) -> vk::Result {
+    used("vkCreatePrivateDataSlot", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreatePrivateDataSlot>(next(
+        266usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_private_data_slot);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_private_data_slot.is_null() {
+        created(
+            "vkCreatePrivateDataSlot",
+            *p_private_data_slot,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_query_pool(
+    device: vk::Device,
+    p_create_info: *const vk::QueryPoolCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_query_pool: *mut vk::QueryPool,
+) -> vk::Result {
+    used("vkCreateQueryPool", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateQueryPool>(next(
+        267usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_query_pool);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_query_pool.is_null() {
+        created("vkCreateQueryPool", *p_query_pool, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_ray_tracing_pipelines_khr(
+    device: vk::Device,
+    deferred_operation: vk::DeferredOperationKHR,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::RayTracingPipelineCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    used("vkCreateRayTracingPipelinesKHR", device);
+    used("vkCreateRayTracingPipelinesKHR", deferred_operation);
+    used("vkCreateRayTracingPipelinesKHR", pipeline_cache);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateRayTracingPipelinesKHR>(next(
+        268usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(
+        device,
+        deferred_operation,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_pipelines.is_null() {
+        let parent = parent_of(device);
+        for i in 0..create_info_count as usize {
+            created(
+                "vkCreateRayTracingPipelinesKHR",
+                *p_pipelines.add(i),
+                parent,
+                true,
+            );
+        }
+    }
+    result
+}
+unsafe extern "system" fn create_ray_tracing_pipelines_nv(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    p_create_infos: *const vk::RayTracingPipelineCreateInfoNV<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    used("vkCreateRayTracingPipelinesNV", device);
+    used("vkCreateRayTracingPipelinesNV", pipeline_cache);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateRayTracingPipelinesNV>(next(
+        269usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(
+        device,
+        pipeline_cache,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_pipelines,
+    );
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_pipelines.is_null() {
+        let parent = parent_of(device);
+        for i in 0..create_info_count as usize {
+            created(
+                "vkCreateRayTracingPipelinesNV",
+                *p_pipelines.add(i),
+                parent,
+                true,
+            );
+        }
+    }
+    result
+}
+unsafe extern "system" fn create_render_pass(
+    device: vk::Device,
+    p_create_info: *const vk::RenderPassCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_render_pass: *mut vk::RenderPass,
+) -> vk::Result {
+    used("vkCreateRenderPass", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateRenderPass>(next(
+        270usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_render_pass);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_render_pass.is_null() {
+        created(
+            "vkCreateRenderPass",
+            *p_render_pass,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_render_pass2(
+    device: vk::Device,
+    p_create_info: *const vk::RenderPassCreateInfo2<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_render_pass: *mut vk::RenderPass,
+) -> vk::Result {
+    used("vkCreateRenderPass2", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateRenderPass2>(next(
+        271usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_render_pass);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_render_pass.is_null() {
+        created(
+            "vkCreateRenderPass2",
+            *p_render_pass,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_sampler(
+    device: vk::Device,
+    p_create_info: *const vk::SamplerCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_sampler: *mut vk::Sampler,
+) -> vk::Result {
+    used("vkCreateSampler", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateSampler>(next(
+        272usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_sampler);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_sampler.is_null() {
+        created("vkCreateSampler", *p_sampler, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_sampler_ycbcr_conversion(
+    device: vk::Device,
+    p_create_info: *const vk::SamplerYcbcrConversionCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_ycbcr_conversion: *mut vk::SamplerYcbcrConversion,
+) -> vk::Result {
+    used("vkCreateSamplerYcbcrConversion", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateSamplerYcbcrConversion>(next(
+        273usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_ycbcr_conversion);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_ycbcr_conversion.is_null() {
+        created(
+            "vkCreateSamplerYcbcrConversion",
+            *p_ycbcr_conversion,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_screen_surface_qnx(
+    instance: vk::Instance,
+    p_create_info: *const vk::ScreenSurfaceCreateInfoQNX<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateScreenSurfaceQNX", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateScreenSurfaceQNX>(next(
+        274usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateScreenSurfaceQNX",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_semaphore(
+    device: vk::Device,
+    p_create_info: *const vk::SemaphoreCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_semaphore: *mut vk::Semaphore,
+) -> vk::Result {
+    used("vkCreateSemaphore", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateSemaphore>(next(
+        275usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_semaphore);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_semaphore.is_null() {
+        created("vkCreateSemaphore", *p_semaphore, parent_of(device), true);
+    }
+    result
+}
+unsafe extern "system" fn create_shader_module(
+    device: vk::Device,
+    p_create_info: *const vk::ShaderModuleCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_shader_module: *mut vk::ShaderModule,
+) -> vk::Result {
+    used("vkCreateShaderModule", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateShaderModule>(next(
+        276usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_shader_module);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_shader_module.is_null() {
+        created(
+            "vkCreateShaderModule",
+            *p_shader_module,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_shaders_ext(
+    device: vk::Device,
+    create_info_count: u32,
+    p_create_infos: *const vk::ShaderCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_shaders: *mut vk::ShaderEXT,
+) -> vk::Result {
+    used("vkCreateShadersEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateShadersEXT>(next(
+        277usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(
+        device,
+        create_info_count,
+        p_create_infos,
+        p_allocator,
+        p_shaders,
+    );
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_shaders.is_null() {
+        let parent = parent_of(device);
+        for i in 0..create_info_count as usize {
+            created("vkCreateShadersEXT", *p_shaders.add(i), parent, true);
+        }
+    }
+    result
+}
+unsafe extern "system" fn create_shared_swapchains_khr(
+    device: vk::Device,
+    swapchain_count: u32,
+    p_create_infos: *const vk::SwapchainCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_swapchains: *mut vk::SwapchainKHR,
+) -> vk::Result {
+    used("vkCreateSharedSwapchainsKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateSharedSwapchainsKHR>(next(
+        278usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(
+        device,
+        swapchain_count,
+        p_create_infos,
+        p_allocator,
+        p_swapchains,
+    );
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_swapchains.is_null() {
+        let parent = parent_of(device);
+        for i in 0..swapchain_count as usize {
+            created(
+                "vkCreateSharedSwapchainsKHR",
+                *p_swapchains.add(i),
+                parent,
+                true,
+            );
+        }
+    }
+    result
+}
+unsafe extern "system" fn create_stream_descriptor_surface_ggp(
+    instance: vk::Instance,
+    p_create_info: *const vk::StreamDescriptorSurfaceCreateInfoGGP<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateStreamDescriptorSurfaceGGP", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateStreamDescriptorSurfaceGGP>(next(
+        279usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateStreamDescriptorSurfaceGGP",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_swapchain_khr(
+    device: vk::Device,
+    p_create_info: *const vk::SwapchainCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_swapchain: *mut vk::SwapchainKHR,
+) -> vk::Result {
+    used("vkCreateSwapchainKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateSwapchainKHR>(next(
+        280usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_swapchain);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_swapchain.is_null() {
+        created(
+            "vkCreateSwapchainKHR",
+            *p_swapchain,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_validation_cache_ext(
+    device: vk::Device,
+    p_create_info: *const vk::ValidationCacheCreateInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_validation_cache: *mut vk::ValidationCacheEXT,
+) -> vk::Result {
+    used("vkCreateValidationCacheEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateValidationCacheEXT>(next(
+        281usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_validation_cache);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_validation_cache.is_null() {
+        created(
+            "vkCreateValidationCacheEXT",
+            *p_validation_cache,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_vi_surface_nn(
+    instance: vk::Instance,
+    p_create_info: *const vk::ViSurfaceCreateInfoNN<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateViSurfaceNN", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateViSurfaceNN>(next(
+        282usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created("vkCreateViSurfaceNN", *p_surface, parent_of(instance), true);
+    }
+    result
+}
+unsafe extern "system" fn create_video_session_khr(
+    device: vk::Device,
+    p_create_info: *const vk::VideoSessionCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_video_session: *mut vk::VideoSessionKHR,
+) -> vk::Result {
+    used("vkCreateVideoSessionKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateVideoSessionKHR>(next(
+        283usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_create_info, p_allocator, p_video_session);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_video_session.is_null() {
+        created(
+            "vkCreateVideoSessionKHR",
+            *p_video_session,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_video_session_parameters_khr(
+    device: vk::Device,
+    p_create_info: *const vk::VideoSessionParametersCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_video_session_parameters: *mut vk::VideoSessionParametersKHR,
+) -> vk::Result {
+    used("vkCreateVideoSessionParametersKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateVideoSessionParametersKHR>(next(
+        284usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(
+        device,
+        p_create_info,
+        p_allocator,
+        p_video_session_parameters,
+    );
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_video_session_parameters.is_null() {
+        created(
+            "vkCreateVideoSessionParametersKHR",
+            *p_video_session_parameters,
+            if p_create_info.is_null() {
+                None
+            } else {
+                parent_of((*p_create_info).video_session)
+            },
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_wayland_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::WaylandSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateWaylandSurfaceKHR", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateWaylandSurfaceKHR>(next(
+        285usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateWaylandSurfaceKHR",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_win32_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::Win32SurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateWin32SurfaceKHR", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateWin32SurfaceKHR>(next(
+        286usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateWin32SurfaceKHR",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_xcb_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::XcbSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateXcbSurfaceKHR", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateXcbSurfaceKHR>(next(
+        287usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateXcbSurfaceKHR",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn create_xlib_surface_khr(
+    instance: vk::Instance,
+    p_create_info: *const vk::XlibSurfaceCreateInfoKHR<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_surface: *mut vk::SurfaceKHR,
+) -> vk::Result {
+    used("vkCreateXlibSurfaceKHR", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkCreateXlibSurfaceKHR>(next(
+        288usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_create_info, p_allocator, p_surface);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_surface.is_null() {
+        created(
+            "vkCreateXlibSurfaceKHR",
+            *p_surface,
+            parent_of(instance),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn debug_marker_set_object_name_ext(
+    device: vk::Device,
+    p_name_info: *const vk::DebugMarkerObjectNameInfoEXT<'_>,
+) -> vk::Result {
+    used("vkDebugMarkerSetObjectNameEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDebugMarkerSetObjectNameEXT>(next(
+        289usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_name_info)
+}
+unsafe extern "system" fn debug_marker_set_object_tag_ext(
+    device: vk::Device,
+    p_tag_info: *const vk::DebugMarkerObjectTagInfoEXT<'_>,
+) -> vk::Result {
+    used("vkDebugMarkerSetObjectTagEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDebugMarkerSetObjectTagEXT>(next(
+        290usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_tag_info)
+}
+unsafe extern "system" fn debug_report_message_ext(
+    instance: vk::Instance,
+    flags: vk::DebugReportFlagsEXT,
+    object_type: vk::DebugReportObjectTypeEXT,
+    object: u64,
+    location: usize,
+    message_code: i32,
+    p_layer_prefix: *const c_char,
+    p_message: *const c_char,
+) {
+    used("vkDebugReportMessageEXT", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDebugReportMessageEXT>(next(
+        291usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    next(
+        instance,
+        flags,
+        object_type,
+        object,
+        location,
+        message_code,
+        p_layer_prefix,
+        p_message,
+    )
+}
+unsafe extern "system" fn deferred_operation_join_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> vk::Result {
+    used("vkDeferredOperationJoinKHR", device);
+    used("vkDeferredOperationJoinKHR", operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDeferredOperationJoinKHR>(next(
+        292usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, operation)
+}
+unsafe extern "system" fn destroy_acceleration_structure_khr(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyAccelerationStructureKHR", device);
+    destroy("vkDestroyAccelerationStructureKHR", acceleration_structure);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyAccelerationStructureKHR>(next(
+        293usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, acceleration_structure, p_allocator)
+}
+unsafe extern "system" fn destroy_acceleration_structure_nv(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyAccelerationStructureNV", device);
+    destroy("vkDestroyAccelerationStructureNV", acceleration_structure);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyAccelerationStructureNV>(next(
+        294usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, acceleration_structure, p_allocator)
+}
+unsafe extern "system" fn destroy_buffer(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyBuffer", device);
+    destroy("vkDestroyBuffer", buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyBuffer>(next(
+        295usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, buffer, p_allocator)
+}
+unsafe extern "system" fn destroy_buffer_collection_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyBufferCollectionFUCHSIA", device);
+    destroy("vkDestroyBufferCollectionFUCHSIA", collection);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyBufferCollectionFUCHSIA>(next(
+        296usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, collection, p_allocator)
+}
+unsafe extern "system" fn destroy_buffer_view(
+    device: vk::Device,
+    buffer_view: vk::BufferView,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyBufferView", device);
+    destroy("vkDestroyBufferView", buffer_view);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyBufferView>(next(
+        297usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, buffer_view, p_allocator)
+}
+unsafe extern "system" fn destroy_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyCommandPool", device);
+    destroy("vkDestroyCommandPool", command_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyCommandPool>(next(
+        298usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, command_pool, p_allocator)
+}
+unsafe extern "system" fn destroy_cu_function_nvx(
+    device: vk::Device,
+    function: vk::CuFunctionNVX,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyCuFunctionNVX", device);
+    destroy("vkDestroyCuFunctionNVX", function);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyCuFunctionNVX>(next(
+        299usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, function, p_allocator)
+}
+unsafe extern "system" fn destroy_cu_module_nvx(
+    device: vk::Device,
+    module: vk::CuModuleNVX,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyCuModuleNVX", device);
+    destroy("vkDestroyCuModuleNVX", module);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyCuModuleNVX>(next(
+        300usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, module, p_allocator)
+}
+unsafe extern "system" fn destroy_debug_report_callback_ext(
+    instance: vk::Instance,
+    callback: vk::DebugReportCallbackEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyDebugReportCallbackEXT", instance);
+    destroy("vkDestroyDebugReportCallbackEXT", callback);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDebugReportCallbackEXT>(next(
+        301usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    next(instance, callback, p_allocator)
+}
+unsafe extern "system" fn destroy_debug_utils_messenger_ext(
+    instance: vk::Instance,
+    messenger: vk::DebugUtilsMessengerEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyDebugUtilsMessengerEXT", instance);
+    destroy("vkDestroyDebugUtilsMessengerEXT", messenger);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDebugUtilsMessengerEXT>(next(
+        302usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    next(instance, messenger, p_allocator)
+}
+unsafe extern "system" fn destroy_deferred_operation_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyDeferredOperationKHR", device);
+    destroy("vkDestroyDeferredOperationKHR", operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDeferredOperationKHR>(next(
+        303usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, operation, p_allocator)
+}
+unsafe extern "system" fn destroy_descriptor_pool(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyDescriptorPool", device);
+    destroy("vkDestroyDescriptorPool", descriptor_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDescriptorPool>(next(
+        304usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, descriptor_pool, p_allocator)
+}
+unsafe extern "system" fn destroy_descriptor_set_layout(
+    device: vk::Device,
+    descriptor_set_layout: vk::DescriptorSetLayout,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyDescriptorSetLayout", device);
+    destroy("vkDestroyDescriptorSetLayout", descriptor_set_layout);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDescriptorSetLayout>(next(
+        305usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, descriptor_set_layout, p_allocator)
+}
+unsafe extern "system" fn destroy_descriptor_update_template(
+    device: vk::Device,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyDescriptorUpdateTemplate", device);
+    destroy(
+        "vkDestroyDescriptorUpdateTemplate",
+        descriptor_update_template,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDescriptorUpdateTemplate>(next(
+        306usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, descriptor_update_template, p_allocator)
+}
+unsafe extern "system" fn destroy_device(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    destroy("vkDestroyDevice", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyDevice>(next(
+        307usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_allocator)
+}
+unsafe extern "system" fn destroy_event(
+    device: vk::Device,
+    event: vk::Event,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyEvent", device);
+    destroy("vkDestroyEvent", event);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyEvent>(next(
+        308usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, event, p_allocator)
+}
+unsafe extern "system" fn destroy_fence(
+    device: vk::Device,
+    fence: vk::Fence,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyFence", device);
+    destroy("vkDestroyFence", fence);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyFence>(next(
+        309usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, fence, p_allocator)
+}
+unsafe extern "system" fn destroy_framebuffer(
+    device: vk::Device,
+    framebuffer: vk::Framebuffer,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyFramebuffer", device);
+    destroy("vkDestroyFramebuffer", framebuffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyFramebuffer>(next(
+        310usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, framebuffer, p_allocator)
+}
+unsafe extern "system" fn destroy_image(
+    device: vk::Device,
+    image: vk::Image,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyImage", device);
+    destroy("vkDestroyImage", image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyImage>(next(
+        311usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, image, p_allocator)
+}
+unsafe extern "system" fn destroy_image_view(
+    device: vk::Device,
+    image_view: vk::ImageView,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyImageView", device);
+    destroy("vkDestroyImageView", image_view);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyImageView>(next(
+        312usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, image_view, p_allocator)
+}
+unsafe extern "system" fn destroy_indirect_commands_layout_nv(
+    device: vk::Device,
+    indirect_commands_layout: vk::IndirectCommandsLayoutNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyIndirectCommandsLayoutNV", device);
+    destroy(
+        "vkDestroyIndirectCommandsLayoutNV",
+        indirect_commands_layout,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyIndirectCommandsLayoutNV>(next(
+        313usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, indirect_commands_layout, p_allocator)
+}
+unsafe extern "system" fn destroy_instance(
+    instance: vk::Instance,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    destroy("vkDestroyInstance", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyInstance>(next(
+        314usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    next(instance, p_allocator)
+}
+unsafe extern "system" fn destroy_micromap_ext(
+    device: vk::Device,
+    micromap: vk::MicromapEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyMicromapEXT", device);
+    destroy("vkDestroyMicromapEXT", micromap);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyMicromapEXT>(next(
+        315usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, micromap, p_allocator)
+}
+unsafe extern "system" fn destroy_optical_flow_session_nv(
+    device: vk::Device,
+    session: vk::OpticalFlowSessionNV,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyOpticalFlowSessionNV", device);
+    destroy("vkDestroyOpticalFlowSessionNV", session);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyOpticalFlowSessionNV>(next(
+        316usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, session, p_allocator)
+}
+unsafe extern "system" fn destroy_pipeline(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyPipeline", device);
+    destroy("vkDestroyPipeline", pipeline);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyPipeline>(next(
+        317usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, pipeline, p_allocator)
+}
+unsafe extern "system" fn destroy_pipeline_cache(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyPipelineCache", device);
+    destroy("vkDestroyPipelineCache", pipeline_cache);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyPipelineCache>(next(
+        318usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, pipeline_cache, p_allocator)
+}
+unsafe extern "system" fn destroy_pipeline_layout(
+    device: vk::Device,
+    pipeline_layout: vk::PipelineLayout,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyPipelineLayout", device);
+    destroy("vkDestroyPipelineLayout", pipeline_layout);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyPipelineLayout>(next(
+        319usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, pipeline_layout, p_allocator)
+}
+unsafe extern "system" fn destroy_private_data_slot(
+    device: vk::Device,
+    private_data_slot: vk::PrivateDataSlot,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyPrivateDataSlot", device);
+    destroy("vkDestroyPrivateDataSlot", private_data_slot);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyPrivateDataSlot>(next(
+        320usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, private_data_slot, p_allocator)
+}
+unsafe extern "system" fn destroy_query_pool(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyQueryPool", device);
+    destroy("vkDestroyQueryPool", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyQueryPool>(next(
+        321usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, query_pool, p_allocator)
+}
+unsafe extern "system" fn destroy_render_pass(
+    device: vk::Device,
+    render_pass: vk::RenderPass,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyRenderPass", device);
+    destroy("vkDestroyRenderPass", render_pass);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyRenderPass>(next(
+        322usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, render_pass, p_allocator)
+}
+unsafe extern "system" fn destroy_sampler(
+    device: vk::Device,
+    sampler: vk::Sampler,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroySampler", device);
+    destroy("vkDestroySampler", sampler);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroySampler>(next(
+        323usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, sampler, p_allocator)
+}
+unsafe extern "system" fn destroy_sampler_ycbcr_conversion(
+    device: vk::Device,
+    ycbcr_conversion: vk::SamplerYcbcrConversion,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroySamplerYcbcrConversion", device);
+    destroy("vkDestroySamplerYcbcrConversion", ycbcr_conversion);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroySamplerYcbcrConversion>(next(
+        324usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, ycbcr_conversion, p_allocator)
+}
+unsafe extern "system" fn destroy_semaphore(
+    device: vk::Device,
+    semaphore: vk::Semaphore,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroySemaphore", device);
+    destroy("vkDestroySemaphore", semaphore);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroySemaphore>(next(
+        325usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, semaphore, p_allocator)
+}
+unsafe extern "system" fn destroy_shader_ext(
+    device: vk::Device,
+    shader: vk::ShaderEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyShaderEXT", device);
+    destroy("vkDestroyShaderEXT", shader);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyShaderEXT>(next(
+        326usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, shader, p_allocator)
+}
+unsafe extern "system" fn destroy_shader_module(
+    device: vk::Device,
+    shader_module: vk::ShaderModule,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyShaderModule", device);
+    destroy("vkDestroyShaderModule", shader_module);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyShaderModule>(next(
+        327usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, shader_module, p_allocator)
+}
+unsafe extern "system" fn destroy_surface_khr(
+    instance: vk::Instance,
+    surface: vk::SurfaceKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroySurfaceKHR", instance);
+    destroy("vkDestroySurfaceKHR", surface);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroySurfaceKHR>(next(
+        328usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    next(instance, surface, p_allocator)
+}
+unsafe extern "system" fn destroy_swapchain_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroySwapchainKHR", device);
+    destroy("vkDestroySwapchainKHR", swapchain);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroySwapchainKHR>(next(
+        329usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, swapchain, p_allocator)
+}
+unsafe extern "system" fn destroy_validation_cache_ext(
+    device: vk::Device,
+    validation_cache: vk::ValidationCacheEXT,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyValidationCacheEXT", device);
+    destroy("vkDestroyValidationCacheEXT", validation_cache);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyValidationCacheEXT>(next(
+        330usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, validation_cache, p_allocator)
+}
+unsafe extern "system" fn destroy_video_session_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyVideoSessionKHR", device);
+    destroy("vkDestroyVideoSessionKHR", video_session);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyVideoSessionKHR>(next(
+        331usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, video_session, p_allocator)
+}
+unsafe extern "system" fn destroy_video_session_parameters_khr(
+    device: vk::Device,
+    video_session_parameters: vk::VideoSessionParametersKHR,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkDestroyVideoSessionParametersKHR", device);
+    destroy(
+        "vkDestroyVideoSessionParametersKHR",
+        video_session_parameters,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDestroyVideoSessionParametersKHR>(next(
+        332usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, video_session_parameters, p_allocator)
+}
+unsafe extern "system" fn device_wait_idle(device: vk::Device) -> vk::Result {
+    used("vkDeviceWaitIdle", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDeviceWaitIdle>(next(
+        333usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device)
+}
+unsafe extern "system" fn display_power_control_ext(
+    device: vk::Device,
+    display: vk::DisplayKHR,
+    p_display_power_info: *const vk::DisplayPowerInfoEXT<'_>,
+) -> vk::Result {
+    used("vkDisplayPowerControlEXT", device);
+    used("vkDisplayPowerControlEXT", display);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkDisplayPowerControlEXT>(next(
+        334usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, display, p_display_power_info)
+}
+unsafe extern "system" fn end_command_buffer(command_buffer: vk::CommandBuffer) -> vk::Result {
+    used("vkEndCommandBuffer", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkEndCommandBuffer>(next(
+        335usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer)
+}
+unsafe extern "system" fn enumerate_device_extension_properties(
+    physical_device: vk::PhysicalDevice,
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    used("vkEnumerateDeviceExtensionProperties", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkEnumerateDeviceExtensionProperties>(next(
+        336usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(
+        physical_device,
+        p_layer_name,
+        p_property_count,
+        p_properties,
+    )
+}
+unsafe extern "system" fn enumerate_device_layer_properties(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    used("vkEnumerateDeviceLayerProperties", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkEnumerateDeviceLayerProperties>(next(
+        337usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, p_property_count, p_properties)
+}
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkEnumerateInstanceExtensionProperties>(next(
+        338usize, 0,
+    ));
+    next(p_layer_name, p_property_count, p_properties)
+}
+unsafe extern "system" fn enumerate_instance_layer_properties(
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkEnumerateInstanceLayerProperties>(next(
+        339usize, 0,
+    ));
+    next(p_property_count, p_properties)
+}
+unsafe extern "system" fn enumerate_instance_version(p_api_version: *mut u32) -> vk::Result {
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkEnumerateInstanceVersion>(next(340usize, 0));
+    next(p_api_version)
+}
+unsafe extern "system" fn enumerate_physical_device_groups(
+    instance: vk::Instance,
+    p_physical_device_group_count: *mut u32,
+    p_physical_device_group_properties: *mut vk::PhysicalDeviceGroupProperties<'_>,
+) -> vk::Result {
+    used("vkEnumeratePhysicalDeviceGroups", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkEnumeratePhysicalDeviceGroups>(next(
+        341usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    next(
+        instance,
+        p_physical_device_group_count,
+        p_physical_device_group_properties,
+    )
+}
+unsafe extern "system" fn enumerate_physical_device_queue_family_performance_query_counters_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    p_counter_count: *mut u32,
+    p_counters: *mut vk::PerformanceCounterKHR<'_>,
+    p_counter_descriptions: *mut vk::PerformanceCounterDescriptionKHR<'_>,
+) -> vk::Result {
+    used(
+        "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR",
+        physical_device,
+    );
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR,
+    >(next(342usize, dispatch_key(physical_device.as_raw())));
+    next(
+        physical_device,
+        queue_family_index,
+        p_counter_count,
+        p_counters,
+        p_counter_descriptions,
+    )
+}
+unsafe extern "system" fn enumerate_physical_devices(
+    instance: vk::Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    used("vkEnumeratePhysicalDevices", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkEnumeratePhysicalDevices>(next(
+        343usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    let result = next(instance, p_physical_device_count, p_physical_devices);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_physical_devices.is_null() {
+        let parent = parent_of(instance);
+        for i in 0..*p_physical_device_count as usize {
+            created(
+                "vkEnumeratePhysicalDevices",
+                *p_physical_devices.add(i),
+                parent,
+                false,
+            );
+        }
+    }
+    result
+}
+unsafe extern "system" fn export_metal_objects_ext(
+    device: vk::Device,
+    p_metal_objects_info: *mut vk::ExportMetalObjectsInfoEXT<'_>,
+) {
+    used("vkExportMetalObjectsEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkExportMetalObjectsEXT>(next(
+        344usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_metal_objects_info)
+}
+unsafe extern "system" fn flush_mapped_memory_ranges(
+    device: vk::Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const vk::MappedMemoryRange<'_>,
+) -> vk::Result {
+    used("vkFlushMappedMemoryRanges", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkFlushMappedMemoryRanges>(next(
+        345usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, memory_range_count, p_memory_ranges)
+}
+unsafe extern "system" fn free_command_buffers(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    command_buffer_count: u32,
+    p_command_buffers: *const vk::CommandBuffer,
+) {
+    used("vkFreeCommandBuffers", device);
+    used("vkFreeCommandBuffers", command_pool);
+    if !p_command_buffers.is_null() {
+        for i in 0..command_buffer_count as usize {
+            destroy("vkFreeCommandBuffers", *p_command_buffers.add(i));
+        }
+    }
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkFreeCommandBuffers>(next(
+        346usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        command_pool,
+        command_buffer_count,
+        p_command_buffers,
+    )
+}
+unsafe extern "system" fn free_descriptor_sets(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    descriptor_set_count: u32,
+    p_descriptor_sets: *const vk::DescriptorSet,
+) -> vk::Result {
+    used("vkFreeDescriptorSets", device);
+    used("vkFreeDescriptorSets", descriptor_pool);
+    if !p_descriptor_sets.is_null() {
+        for i in 0..descriptor_set_count as usize {
+            destroy("vkFreeDescriptorSets", *p_descriptor_sets.add(i));
+        }
+    }
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkFreeDescriptorSets>(next(
+        347usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        descriptor_pool,
+        descriptor_set_count,
+        p_descriptor_sets,
+    )
+}
+unsafe extern "system" fn free_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    used("vkFreeMemory", device);
+    destroy("vkFreeMemory", memory);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkFreeMemory>(next(
+        348usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, memory, p_allocator)
+}
+unsafe extern "system" fn get_acceleration_structure_build_sizes_khr(
+    device: vk::Device,
+    build_type: vk::AccelerationStructureBuildTypeKHR,
+    p_build_info: *const vk::AccelerationStructureBuildGeometryInfoKHR<'_>,
+    p_max_primitive_counts: *const u32,
+    p_size_info: *mut vk::AccelerationStructureBuildSizesInfoKHR<'_>,
+) {
+    used("vkGetAccelerationStructureBuildSizesKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetAccelerationStructureBuildSizesKHR>(
+        next(349usize, dispatch_key(device.as_raw())),
+    );
+    next(
+        device,
+        build_type,
+        p_build_info,
+        p_max_primitive_counts,
+        p_size_info,
+    )
+}
+unsafe extern "system" fn get_acceleration_structure_device_address_khr(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureDeviceAddressInfoKHR<'_>,
+) -> vk::DeviceAddress {
+    used("vkGetAccelerationStructureDeviceAddressKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetAccelerationStructureDeviceAddressKHR>(
+        next(350usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_info)
+}
+unsafe extern "system" fn get_acceleration_structure_handle_nv(
+    device: vk::Device,
+    acceleration_structure: vk::AccelerationStructureNV,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetAccelerationStructureHandleNV", device);
+    used("vkGetAccelerationStructureHandleNV", acceleration_structure);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetAccelerationStructureHandleNV>(next(
+        351usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, acceleration_structure, data_size, p_data)
+}
+unsafe extern "system" fn get_acceleration_structure_memory_requirements_nv(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureMemoryRequirementsInfoNV<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2KHR<'_>,
+) {
+    used("vkGetAccelerationStructureMemoryRequirementsNV", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetAccelerationStructureMemoryRequirementsNV>(
+        next(352usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_info, p_memory_requirements)
+}
+unsafe extern "system" fn get_acceleration_structure_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::AccelerationStructureCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used(
+        "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT",
+        device,
+    );
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT,
+    >(next(353usize, dispatch_key(device.as_raw())));
+    next(device, p_info, p_data)
+}
+unsafe extern "system" fn get_android_hardware_buffer_properties_android(
+    device: vk::Device,
+    buffer: *const vk::AHardwareBuffer,
+    p_properties: *mut vk::AndroidHardwareBufferPropertiesANDROID<'_>,
+) -> vk::Result {
+    used("vkGetAndroidHardwareBufferPropertiesANDROID", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetAndroidHardwareBufferPropertiesANDROID>(
+        next(354usize, dispatch_key(device.as_raw())),
+    );
+    next(device, buffer, p_properties)
+}
+unsafe extern "system" fn get_buffer_collection_properties_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_properties: *mut vk::BufferCollectionPropertiesFUCHSIA<'_>,
+) -> vk::Result {
+    used("vkGetBufferCollectionPropertiesFUCHSIA", device);
+    used("vkGetBufferCollectionPropertiesFUCHSIA", collection);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferCollectionPropertiesFUCHSIA>(next(
+        355usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, collection, p_properties)
+}
+unsafe extern "system" fn get_buffer_device_address(
+    device: vk::Device,
+    p_info: *const vk::BufferDeviceAddressInfo<'_>,
+) -> vk::DeviceAddress {
+    used("vkGetBufferDeviceAddress", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferDeviceAddress>(next(
+        356usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_info)
+}
+unsafe extern "system" fn get_buffer_memory_requirements(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) {
+    used("vkGetBufferMemoryRequirements", device);
+    used("vkGetBufferMemoryRequirements", buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferMemoryRequirements>(next(
+        357usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, buffer, p_memory_requirements)
+}
+unsafe extern "system" fn get_buffer_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::BufferMemoryRequirementsInfo2<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    used("vkGetBufferMemoryRequirements2", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferMemoryRequirements2>(next(
+        358usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_info, p_memory_requirements)
+}
+unsafe extern "system" fn get_buffer_opaque_capture_address(
+    device: vk::Device,
+    p_info: *const vk::BufferDeviceAddressInfo<'_>,
+) -> u64 {
+    used("vkGetBufferOpaqueCaptureAddress", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferOpaqueCaptureAddress>(next(
+        359usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_info)
+}
+unsafe extern "system" fn get_buffer_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::BufferCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetBufferOpaqueCaptureDescriptorDataEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetBufferOpaqueCaptureDescriptorDataEXT>(
+        next(360usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_info, p_data)
+}
+unsafe extern "system" fn get_calibrated_timestamps_ext(
+    device: vk::Device,
+    timestamp_count: u32,
+    p_timestamp_infos: *const vk::CalibratedTimestampInfoEXT<'_>,
+    p_timestamps: *mut u64,
+    p_max_deviation: *mut u64,
+) -> vk::Result {
+    used("vkGetCalibratedTimestampsEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetCalibratedTimestampsEXT>(next(
+        361usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        timestamp_count,
+        p_timestamp_infos,
+        p_timestamps,
+        p_max_deviation,
+    )
+}
+unsafe extern "system" fn get_deferred_operation_max_concurrency_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> u32 {
+    used("vkGetDeferredOperationMaxConcurrencyKHR", device);
+    used("vkGetDeferredOperationMaxConcurrencyKHR", operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeferredOperationMaxConcurrencyKHR>(
+        next(362usize, dispatch_key(device.as_raw())),
+    );
+    next(device, operation)
+}
+unsafe extern "system" fn get_deferred_operation_result_khr(
+    device: vk::Device,
+    operation: vk::DeferredOperationKHR,
+) -> vk::Result {
+    used("vkGetDeferredOperationResultKHR", device);
+    used("vkGetDeferredOperationResultKHR", operation);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeferredOperationResultKHR>(next(
+        363usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, operation)
+}
+unsafe extern "system" fn get_descriptor_ext(
+    device: vk::Device,
+    p_descriptor_info: *const vk::DescriptorGetInfoEXT<'_>,
+    data_size: usize,
+    p_descriptor: *mut c_void,
+) {
+    used("vkGetDescriptorEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorEXT>(next(
+        364usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_descriptor_info, data_size, p_descriptor)
+}
+unsafe extern "system" fn get_descriptor_set_host_mapping_valve(
+    device: vk::Device,
+    descriptor_set: vk::DescriptorSet,
+    pp_data: *mut *mut c_void,
+) {
+    used("vkGetDescriptorSetHostMappingVALVE", device);
+    used("vkGetDescriptorSetHostMappingVALVE", descriptor_set);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorSetHostMappingVALVE>(next(
+        365usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, descriptor_set, pp_data)
+}
+unsafe extern "system" fn get_descriptor_set_layout_binding_offset_ext(
+    device: vk::Device,
+    layout: vk::DescriptorSetLayout,
+    binding: u32,
+    p_offset: *mut vk::DeviceSize,
+) {
+    used("vkGetDescriptorSetLayoutBindingOffsetEXT", device);
+    used("vkGetDescriptorSetLayoutBindingOffsetEXT", layout);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorSetLayoutBindingOffsetEXT>(
+        next(366usize, dispatch_key(device.as_raw())),
+    );
+    next(device, layout, binding, p_offset)
+}
+unsafe extern "system" fn get_descriptor_set_layout_host_mapping_info_valve(
+    device: vk::Device,
+    p_binding_reference: *const vk::DescriptorSetBindingReferenceVALVE<'_>,
+    p_host_mapping: *mut vk::DescriptorSetLayoutHostMappingInfoVALVE<'_>,
+) {
+    used("vkGetDescriptorSetLayoutHostMappingInfoVALVE", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorSetLayoutHostMappingInfoVALVE>(
+        next(367usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_binding_reference, p_host_mapping)
+}
+unsafe extern "system" fn get_descriptor_set_layout_size_ext(
+    device: vk::Device,
+    layout: vk::DescriptorSetLayout,
+    p_layout_size_in_bytes: *mut vk::DeviceSize,
+) {
+    used("vkGetDescriptorSetLayoutSizeEXT", device);
+    used("vkGetDescriptorSetLayoutSizeEXT", layout);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorSetLayoutSizeEXT>(next(
+        368usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, layout, p_layout_size_in_bytes)
+}
+unsafe extern "system" fn get_descriptor_set_layout_support(
+    device: vk::Device,
+    p_create_info: *const vk::DescriptorSetLayoutCreateInfo<'_>,
+    p_support: *mut vk::DescriptorSetLayoutSupport<'_>,
+) {
+    used("vkGetDescriptorSetLayoutSupport", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDescriptorSetLayoutSupport>(next(
+        369usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_create_info, p_support)
+}
+unsafe extern "system" fn get_device_acceleration_structure_compatibility_khr(
+    device: vk::Device,
+    p_version_info: *const vk::AccelerationStructureVersionInfoKHR<'_>,
+    p_compatibility: *mut vk::AccelerationStructureCompatibilityKHR,
+) {
+    used("vkGetDeviceAccelerationStructureCompatibilityKHR", device);
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetDeviceAccelerationStructureCompatibilityKHR,
+    >(next(370usize, dispatch_key(device.as_raw())));
+    next(device, p_version_info, p_compatibility)
+}
+unsafe extern "system" fn get_device_buffer_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceBufferMemoryRequirements<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    used("vkGetDeviceBufferMemoryRequirements", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceBufferMemoryRequirements>(next(
+        371usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_info, p_memory_requirements)
+}
+unsafe extern "system" fn get_device_fault_info_ext(
+    device: vk::Device,
+    p_fault_counts: *mut vk::DeviceFaultCountsEXT<'_>,
+    p_fault_info: *mut vk::DeviceFaultInfoEXT<'_>,
+) -> vk::Result {
+    used("vkGetDeviceFaultInfoEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceFaultInfoEXT>(next(
+        372usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_fault_counts, p_fault_info)
+}
+unsafe extern "system" fn get_device_group_peer_memory_features(
+    device: vk::Device,
+    heap_index: u32,
+    local_device_index: u32,
+    remote_device_index: u32,
+    p_peer_memory_features: *mut vk::PeerMemoryFeatureFlags,
+) {
+    used("vkGetDeviceGroupPeerMemoryFeatures", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceGroupPeerMemoryFeatures>(next(
+        373usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        heap_index,
+        local_device_index,
+        remote_device_index,
+        p_peer_memory_features,
+    )
+}
+unsafe extern "system" fn get_device_group_present_capabilities_khr(
+    device: vk::Device,
+    p_device_group_present_capabilities: *mut vk::DeviceGroupPresentCapabilitiesKHR<'_>,
+) -> vk::Result {
+    used("vkGetDeviceGroupPresentCapabilitiesKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceGroupPresentCapabilitiesKHR>(next(
+        374usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_device_group_present_capabilities)
+}
+unsafe extern "system" fn get_device_group_surface_present_modes2_ext(
+    device: vk::Device,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_modes: *mut vk::DeviceGroupPresentModeFlagsKHR,
+) -> vk::Result {
+    used("vkGetDeviceGroupSurfacePresentModes2EXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceGroupSurfacePresentModes2EXT>(
+        next(375usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_surface_info, p_modes)
+}
+unsafe extern "system" fn get_device_group_surface_present_modes_khr(
+    device: vk::Device,
+    surface: vk::SurfaceKHR,
+    p_modes: *mut vk::DeviceGroupPresentModeFlagsKHR,
+) -> vk::Result {
+    used("vkGetDeviceGroupSurfacePresentModesKHR", device);
+    used("vkGetDeviceGroupSurfacePresentModesKHR", surface);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceGroupSurfacePresentModesKHR>(next(
+        376usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, surface, p_modes)
+}
+unsafe extern "system" fn get_device_image_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceImageMemoryRequirements<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    used("vkGetDeviceImageMemoryRequirements", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceImageMemoryRequirements>(next(
+        377usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_info, p_memory_requirements)
+}
+unsafe extern "system" fn get_device_image_sparse_memory_requirements(
+    device: vk::Device,
+    p_info: *const vk::DeviceImageMemoryRequirements<'_>,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements2<'_>,
+) {
+    used("vkGetDeviceImageSparseMemoryRequirements", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceImageSparseMemoryRequirements>(
+        next(378usize, dispatch_key(device.as_raw())),
+    );
+    next(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    )
+}
+unsafe extern "system" fn get_device_memory_commitment(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    p_committed_memory_in_bytes: *mut vk::DeviceSize,
+) {
+    used("vkGetDeviceMemoryCommitment", device);
+    used("vkGetDeviceMemoryCommitment", memory);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceMemoryCommitment>(next(
+        379usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, memory, p_committed_memory_in_bytes)
+}
+unsafe extern "system" fn get_device_memory_opaque_capture_address(
+    device: vk::Device,
+    p_info: *const vk::DeviceMemoryOpaqueCaptureAddressInfo<'_>,
+) -> u64 {
+    used("vkGetDeviceMemoryOpaqueCaptureAddress", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceMemoryOpaqueCaptureAddress>(next(
+        380usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_info)
+}
+unsafe extern "system" fn get_device_micromap_compatibility_ext(
+    device: vk::Device,
+    p_version_info: *const vk::MicromapVersionInfoEXT<'_>,
+    p_compatibility: *mut vk::AccelerationStructureCompatibilityKHR,
+) {
+    used("vkGetDeviceMicromapCompatibilityEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceMicromapCompatibilityEXT>(next(
+        381usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_version_info, p_compatibility)
+}
+unsafe extern "system" fn get_device_queue(
+    device: vk::Device,
+    queue_family_index: u32,
+    queue_index: u32,
+    p_queue: *mut vk::Queue,
+) {
+    used("vkGetDeviceQueue", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceQueue>(next(
+        382usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, queue_family_index, queue_index, p_queue);
+    if !p_queue.is_null() {
+        created("vkGetDeviceQueue", *p_queue, parent_of(device), false);
+    }
+}
+unsafe extern "system" fn get_device_queue2(
+    device: vk::Device,
+    p_queue_info: *const vk::DeviceQueueInfo2<'_>,
+    p_queue: *mut vk::Queue,
+) {
+    used("vkGetDeviceQueue2", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceQueue2>(next(
+        383usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_queue_info, p_queue);
+    if !p_queue.is_null() {
+        created("vkGetDeviceQueue2", *p_queue, parent_of(device), false);
+    }
+}
+unsafe extern "system" fn get_device_subpass_shading_max_workgroup_size_huawei(
+    device: vk::Device,
+    renderpass: vk::RenderPass,
+    p_max_workgroup_size: *mut vk::Extent2D,
+) -> vk::Result {
+    used("vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI", device);
+    used(
+        "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI",
+        renderpass,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI>(
+        next(384usize, dispatch_key(device.as_raw())),
+    );
+    next(device, renderpass, p_max_workgroup_size)
+}
+unsafe extern "system" fn get_display_mode_properties2_khr(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayModeProperties2KHR<'_>,
+) -> vk::Result {
+    used("vkGetDisplayModeProperties2KHR", physical_device);
+    used("vkGetDisplayModeProperties2KHR", display);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDisplayModeProperties2KHR>(next(
+        385usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, display, p_property_count, p_properties)
+}
+unsafe extern "system" fn get_display_mode_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayModePropertiesKHR,
+) -> vk::Result {
+    used("vkGetDisplayModePropertiesKHR", physical_device);
+    used("vkGetDisplayModePropertiesKHR", display);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDisplayModePropertiesKHR>(next(
+        386usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, display, p_property_count, p_properties)
+}
+unsafe extern "system" fn get_display_plane_capabilities2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_display_plane_info: *const vk::DisplayPlaneInfo2KHR<'_>,
+    p_capabilities: *mut vk::DisplayPlaneCapabilities2KHR<'_>,
+) -> vk::Result {
+    used("vkGetDisplayPlaneCapabilities2KHR", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDisplayPlaneCapabilities2KHR>(next(
+        387usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, p_display_plane_info, p_capabilities)
+}
+unsafe extern "system" fn get_display_plane_capabilities_khr(
+    physical_device: vk::PhysicalDevice,
+    mode: vk::DisplayModeKHR,
+    plane_index: u32,
+    p_capabilities: *mut vk::DisplayPlaneCapabilitiesKHR,
+) -> vk::Result {
+    used("vkGetDisplayPlaneCapabilitiesKHR", physical_device);
+    used("vkGetDisplayPlaneCapabilitiesKHR", mode);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDisplayPlaneCapabilitiesKHR>(next(
+        388usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, mode, plane_index, p_capabilities)
+}
+unsafe extern "system" fn get_display_plane_supported_displays_khr(
+    physical_device: vk::PhysicalDevice,
+    plane_index: u32,
+    p_display_count: *mut u32,
+    p_displays: *mut vk::DisplayKHR,
+) -> vk::Result {
+    used("vkGetDisplayPlaneSupportedDisplaysKHR", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDisplayPlaneSupportedDisplaysKHR>(next(
+        389usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let result = next(physical_device, plane_index, p_display_count, p_displays);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_displays.is_null() {
+        let parent = parent_of(physical_device);
+        for i in 0..*p_display_count as usize {
+            created(
+                "vkGetDisplayPlaneSupportedDisplaysKHR",
+                *p_displays.add(i),
+                parent,
+                false,
+            );
+        }
+    }
+    result
+}
+unsafe extern "system" fn get_drm_display_ext(
+    physical_device: vk::PhysicalDevice,
+    drm_fd: i32,
+    connector_id: u32,
+    display: *mut vk::DisplayKHR,
+) -> vk::Result {
+    used("vkGetDrmDisplayEXT", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDrmDisplayEXT>(next(
+        390usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let result = next(physical_device, drm_fd, connector_id, display);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !display.is_null() {
+        created(
+            "vkGetDrmDisplayEXT",
+            *display,
+            parent_of(physical_device),
+            false,
+        );
+    }
+    result
+}
+unsafe extern "system" fn get_dynamic_rendering_tile_properties_qcom(
+    device: vk::Device,
+    p_rendering_info: *const vk::RenderingInfo<'_>,
+    p_properties: *mut vk::TilePropertiesQCOM<'_>,
+) -> vk::Result {
+    used("vkGetDynamicRenderingTilePropertiesQCOM", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetDynamicRenderingTilePropertiesQCOM>(
+        next(391usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_rendering_info, p_properties)
+}
+unsafe extern "system" fn get_encoded_video_session_parameters_khr(
+    device: vk::Device,
+    p_video_session_parameters_info: *const vk::VideoEncodeSessionParametersGetInfoKHR<'_>,
+    p_feedback_info: *mut vk::VideoEncodeSessionParametersFeedbackInfoKHR<'_>,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetEncodedVideoSessionParametersKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetEncodedVideoSessionParametersKHR>(next(
+        392usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        p_video_session_parameters_info,
+        p_feedback_info,
+        p_data_size,
+        p_data,
+    )
+}
+unsafe extern "system" fn get_event_status(device: vk::Device, event: vk::Event) -> vk::Result {
+    used("vkGetEventStatus", device);
+    used("vkGetEventStatus", event);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetEventStatus>(next(
+        393usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, event)
+}
+unsafe extern "system" fn get_fence_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::FenceGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    used("vkGetFenceFdKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetFenceFdKHR>(next(
+        394usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_get_fd_info, p_fd)
+}
+unsafe extern "system" fn get_fence_status(device: vk::Device, fence: vk::Fence) -> vk::Result {
+    used("vkGetFenceStatus", device);
+    used("vkGetFenceStatus", fence);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetFenceStatus>(next(
+        395usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, fence)
+}
+unsafe extern "system" fn get_fence_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::FenceGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    used("vkGetFenceWin32HandleKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetFenceWin32HandleKHR>(next(
+        396usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_get_win32_handle_info, p_handle)
+}
+unsafe extern "system" fn get_framebuffer_tile_properties_qcom(
+    device: vk::Device,
+    framebuffer: vk::Framebuffer,
+    p_properties_count: *mut u32,
+    p_properties: *mut vk::TilePropertiesQCOM<'_>,
+) -> vk::Result {
+    used("vkGetFramebufferTilePropertiesQCOM", device);
+    used("vkGetFramebufferTilePropertiesQCOM", framebuffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetFramebufferTilePropertiesQCOM>(next(
+        397usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, framebuffer, p_properties_count, p_properties)
+}
+unsafe extern "system" fn get_generated_commands_memory_requirements_nv(
+    device: vk::Device,
+    p_info: *const vk::GeneratedCommandsMemoryRequirementsInfoNV<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    used("vkGetGeneratedCommandsMemoryRequirementsNV", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetGeneratedCommandsMemoryRequirementsNV>(
+        next(398usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_info, p_memory_requirements)
+}
+unsafe extern "system" fn get_image_drm_format_modifier_properties_ext(
+    device: vk::Device,
+    image: vk::Image,
+    p_properties: *mut vk::ImageDrmFormatModifierPropertiesEXT<'_>,
+) -> vk::Result {
+    used("vkGetImageDrmFormatModifierPropertiesEXT", device);
+    used("vkGetImageDrmFormatModifierPropertiesEXT", image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageDrmFormatModifierPropertiesEXT>(
+        next(399usize, dispatch_key(device.as_raw())),
+    );
+    next(device, image, p_properties)
+}
+unsafe extern "system" fn get_image_memory_requirements(
+    device: vk::Device,
+    image: vk::Image,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) {
+    used("vkGetImageMemoryRequirements", device);
+    used("vkGetImageMemoryRequirements", image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageMemoryRequirements>(next(
+        400usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, image, p_memory_requirements)
+}
+unsafe extern "system" fn get_image_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::ImageMemoryRequirementsInfo2<'_>,
+    p_memory_requirements: *mut vk::MemoryRequirements2<'_>,
+) {
+    used("vkGetImageMemoryRequirements2", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageMemoryRequirements2>(next(
+        401usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_info, p_memory_requirements)
+}
+unsafe extern "system" fn get_image_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::ImageCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetImageOpaqueCaptureDescriptorDataEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageOpaqueCaptureDescriptorDataEXT>(
+        next(402usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_info, p_data)
+}
+unsafe extern "system" fn get_image_sparse_memory_requirements(
+    device: vk::Device,
+    image: vk::Image,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements,
+) {
+    used("vkGetImageSparseMemoryRequirements", device);
+    used("vkGetImageSparseMemoryRequirements", image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageSparseMemoryRequirements>(next(
+        403usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        image,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    )
+}
+unsafe extern "system" fn get_image_sparse_memory_requirements2(
+    device: vk::Device,
+    p_info: *const vk::ImageSparseMemoryRequirementsInfo2<'_>,
+    p_sparse_memory_requirement_count: *mut u32,
+    p_sparse_memory_requirements: *mut vk::SparseImageMemoryRequirements2<'_>,
+) {
+    used("vkGetImageSparseMemoryRequirements2", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageSparseMemoryRequirements2>(next(
+        404usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        p_info,
+        p_sparse_memory_requirement_count,
+        p_sparse_memory_requirements,
+    )
+}
+unsafe extern "system" fn get_image_subresource_layout(
+    device: vk::Device,
+    image: vk::Image,
+    p_subresource: *const vk::ImageSubresource,
+    p_layout: *mut vk::SubresourceLayout,
+) {
+    used("vkGetImageSubresourceLayout", device);
+    used("vkGetImageSubresourceLayout", image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageSubresourceLayout>(next(
+        405usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, image, p_subresource, p_layout)
+}
+unsafe extern "system" fn get_image_subresource_layout2_ext(
+    device: vk::Device,
+    image: vk::Image,
+    p_subresource: *const vk::ImageSubresource2EXT<'_>,
+    p_layout: *mut vk::SubresourceLayout2EXT<'_>,
+) {
+    used("vkGetImageSubresourceLayout2EXT", device);
+    used("vkGetImageSubresourceLayout2EXT", image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageSubresourceLayout2EXT>(next(
+        406usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, image, p_subresource, p_layout)
+}
+unsafe extern "system" fn get_image_view_address_nvx(
+    device: vk::Device,
+    image_view: vk::ImageView,
+    p_properties: *mut vk::ImageViewAddressPropertiesNVX<'_>,
+) -> vk::Result {
+    used("vkGetImageViewAddressNVX", device);
+    used("vkGetImageViewAddressNVX", image_view);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageViewAddressNVX>(next(
+        407usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, image_view, p_properties)
+}
+unsafe extern "system" fn get_image_view_handle_nvx(
+    device: vk::Device,
+    p_info: *const vk::ImageViewHandleInfoNVX<'_>,
+) -> u32 {
+    used("vkGetImageViewHandleNVX", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageViewHandleNVX>(next(
+        408usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_info)
+}
+unsafe extern "system" fn get_image_view_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::ImageViewCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetImageViewOpaqueCaptureDescriptorDataEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetImageViewOpaqueCaptureDescriptorDataEXT>(
+        next(409usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_info, p_data)
+}
+unsafe extern "system" fn get_memory_android_hardware_buffer_android(
+    device: vk::Device,
+    p_info: *const vk::MemoryGetAndroidHardwareBufferInfoANDROID<'_>,
+    p_buffer: *mut *mut vk::AHardwareBuffer,
+) -> vk::Result {
+    used("vkGetMemoryAndroidHardwareBufferANDROID", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryAndroidHardwareBufferANDROID>(
+        next(410usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_info, p_buffer)
+}
+unsafe extern "system" fn get_memory_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::MemoryGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    used("vkGetMemoryFdKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryFdKHR>(next(
+        411usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_get_fd_info, p_fd)
+}
+unsafe extern "system" fn get_memory_fd_properties_khr(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    fd: c_int,
+    p_memory_fd_properties: *mut vk::MemoryFdPropertiesKHR<'_>,
+) -> vk::Result {
+    used("vkGetMemoryFdPropertiesKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryFdPropertiesKHR>(next(
+        412usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, handle_type, fd, p_memory_fd_properties)
+}
+unsafe extern "system" fn get_memory_host_pointer_properties_ext(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    p_host_pointer: *const c_void,
+    p_memory_host_pointer_properties: *mut vk::MemoryHostPointerPropertiesEXT<'_>,
+) -> vk::Result {
+    used("vkGetMemoryHostPointerPropertiesEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryHostPointerPropertiesEXT>(next(
+        413usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        handle_type,
+        p_host_pointer,
+        p_memory_host_pointer_properties,
+    )
+}
+unsafe extern "system" fn get_memory_remote_address_nv(
+    device: vk::Device,
+    p_memory_get_remote_address_info: *const vk::MemoryGetRemoteAddressInfoNV<'_>,
+    p_address: *mut vk::RemoteAddressNV,
+) -> vk::Result {
+    used("vkGetMemoryRemoteAddressNV", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryRemoteAddressNV>(next(
+        414usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_memory_get_remote_address_info, p_address)
+}
+unsafe extern "system" fn get_memory_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::MemoryGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    used("vkGetMemoryWin32HandleKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryWin32HandleKHR>(next(
+        415usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_get_win32_handle_info, p_handle)
+}
+unsafe extern "system" fn get_memory_win32_handle_nv(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    used("vkGetMemoryWin32HandleNV", device);
+    used("vkGetMemoryWin32HandleNV", memory);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryWin32HandleNV>(next(
+        416usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, memory, handle_type, p_handle)
+}
+unsafe extern "system" fn get_memory_win32_handle_properties_khr(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    handle: vk::HANDLE,
+    p_memory_win32_handle_properties: *mut vk::MemoryWin32HandlePropertiesKHR<'_>,
+) -> vk::Result {
+    used("vkGetMemoryWin32HandlePropertiesKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryWin32HandlePropertiesKHR>(next(
+        417usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        handle_type,
+        handle,
+        p_memory_win32_handle_properties,
+    )
+}
+unsafe extern "system" fn get_memory_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_get_zircon_handle_info: *const vk::MemoryGetZirconHandleInfoFUCHSIA<'_>,
+    p_zircon_handle: *mut vk::zx_handle_t,
+) -> vk::Result {
+    used("vkGetMemoryZirconHandleFUCHSIA", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryZirconHandleFUCHSIA>(next(
+        418usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_get_zircon_handle_info, p_zircon_handle)
+}
+unsafe extern "system" fn get_memory_zircon_handle_properties_fuchsia(
+    device: vk::Device,
+    handle_type: vk::ExternalMemoryHandleTypeFlags,
+    zircon_handle: vk::zx_handle_t,
+    p_memory_zircon_handle_properties: *mut vk::MemoryZirconHandlePropertiesFUCHSIA<'_>,
+) -> vk::Result {
+    used("vkGetMemoryZirconHandlePropertiesFUCHSIA", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMemoryZirconHandlePropertiesFUCHSIA>(
+        next(419usize, dispatch_key(device.as_raw())),
+    );
+    next(
+        device,
+        handle_type,
+        zircon_handle,
+        p_memory_zircon_handle_properties,
+    )
+}
+unsafe extern "system" fn get_micromap_build_sizes_ext(
+    device: vk::Device,
+    build_type: vk::AccelerationStructureBuildTypeKHR,
+    p_build_info: *const vk::MicromapBuildInfoEXT<'_>,
+    p_size_info: *mut vk::MicromapBuildSizesInfoEXT<'_>,
+) {
+    used("vkGetMicromapBuildSizesEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetMicromapBuildSizesEXT>(next(
+        420usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, build_type, p_build_info, p_size_info)
+}
+unsafe extern "system" fn get_past_presentation_timing_google(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_presentation_timing_count: *mut u32,
+    p_presentation_timings: *mut vk::PastPresentationTimingGOOGLE,
+) -> vk::Result {
+    used("vkGetPastPresentationTimingGOOGLE", device);
+    used("vkGetPastPresentationTimingGOOGLE", swapchain);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPastPresentationTimingGOOGLE>(next(
+        421usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        swapchain,
+        p_presentation_timing_count,
+        p_presentation_timings,
+    )
+}
+unsafe extern "system" fn get_performance_parameter_intel(
+    device: vk::Device,
+    parameter: vk::PerformanceParameterTypeINTEL,
+    p_value: *mut vk::PerformanceValueINTEL,
+) -> vk::Result {
+    used("vkGetPerformanceParameterINTEL", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPerformanceParameterINTEL>(next(
+        422usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, parameter, p_value)
+}
+unsafe extern "system" fn get_physical_device_calibrateable_time_domains_ext(
+    physical_device: vk::PhysicalDevice,
+    p_time_domain_count: *mut u32,
+    p_time_domains: *mut vk::TimeDomainEXT,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceCalibrateableTimeDomainsEXT>(
+        next(423usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, p_time_domain_count, p_time_domains)
+}
+unsafe extern "system" fn get_physical_device_cooperative_matrix_properties_nv(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::CooperativeMatrixPropertiesNV<'_>,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV",
+        physical_device,
+    );
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetPhysicalDeviceCooperativeMatrixPropertiesNV,
+    >(next(424usize, dispatch_key(physical_device.as_raw())));
+    next(physical_device, p_property_count, p_properties)
+}
+unsafe extern "system" fn get_physical_device_direct_fb_presentation_support_ext(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    dfb: *mut vk::IDirectFB,
+) -> vk::Bool32 {
+    used(
+        "vkGetPhysicalDeviceDirectFBPresentationSupportEXT",
+        physical_device,
+    );
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetPhysicalDeviceDirectFBPresentationSupportEXT,
+    >(next(425usize, dispatch_key(physical_device.as_raw())));
+    next(physical_device, queue_family_index, dfb)
+}
+unsafe extern "system" fn get_physical_device_display_plane_properties2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayPlaneProperties2KHR<'_>,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceDisplayPlaneProperties2KHR",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceDisplayPlaneProperties2KHR>(
+        next(426usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, p_property_count, p_properties)
+}
+unsafe extern "system" fn get_physical_device_display_plane_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayPlanePropertiesKHR,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceDisplayPlanePropertiesKHR",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR>(
+        next(427usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, p_property_count, p_properties)
+}
+unsafe extern "system" fn get_physical_device_display_properties2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayProperties2KHR<'_>,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceDisplayProperties2KHR", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceDisplayProperties2KHR>(
+        next(428usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, p_property_count, p_properties)
+}
+unsafe extern "system" fn get_physical_device_display_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::DisplayPropertiesKHR<'_>,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceDisplayPropertiesKHR", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceDisplayPropertiesKHR>(
+        next(429usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, p_property_count, p_properties)
+}
+unsafe extern "system" fn get_physical_device_external_buffer_properties(
+    physical_device: vk::PhysicalDevice,
+    p_external_buffer_info: *const vk::PhysicalDeviceExternalBufferInfo<'_>,
+    p_external_buffer_properties: *mut vk::ExternalBufferProperties<'_>,
+) {
+    used(
+        "vkGetPhysicalDeviceExternalBufferProperties",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceExternalBufferProperties>(
+        next(430usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_external_buffer_info,
+        p_external_buffer_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_external_fence_properties(
+    physical_device: vk::PhysicalDevice,
+    p_external_fence_info: *const vk::PhysicalDeviceExternalFenceInfo<'_>,
+    p_external_fence_properties: *mut vk::ExternalFenceProperties<'_>,
+) {
+    used(
+        "vkGetPhysicalDeviceExternalFenceProperties",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceExternalFenceProperties>(
+        next(431usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_external_fence_info,
+        p_external_fence_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_external_image_format_properties_nv(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    ty: vk::ImageType,
+    tiling: vk::ImageTiling,
+    usage: vk::ImageUsageFlags,
+    flags: vk::ImageCreateFlags,
+    external_handle_type: vk::ExternalMemoryHandleTypeFlagsNV,
+    p_external_image_format_properties: *mut vk::ExternalImageFormatPropertiesNV,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceExternalImageFormatPropertiesNV",
+        physical_device,
+    );
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetPhysicalDeviceExternalImageFormatPropertiesNV,
+    >(next(432usize, dispatch_key(physical_device.as_raw())));
+    next(
+        physical_device,
+        format,
+        ty,
+        tiling,
+        usage,
+        flags,
+        external_handle_type,
+        p_external_image_format_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_external_semaphore_properties(
+    physical_device: vk::PhysicalDevice,
+    p_external_semaphore_info: *const vk::PhysicalDeviceExternalSemaphoreInfo<'_>,
+    p_external_semaphore_properties: *mut vk::ExternalSemaphoreProperties<'_>,
+) {
+    used(
+        "vkGetPhysicalDeviceExternalSemaphoreProperties",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceExternalSemaphoreProperties>(
+        next(433usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_external_semaphore_info,
+        p_external_semaphore_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_features(
+    physical_device: vk::PhysicalDevice,
+    p_features: *mut vk::PhysicalDeviceFeatures,
+) {
+    used("vkGetPhysicalDeviceFeatures", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceFeatures>(next(
+        434usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, p_features)
+}
+unsafe extern "system" fn get_physical_device_features2(
+    physical_device: vk::PhysicalDevice,
+    p_features: *mut vk::PhysicalDeviceFeatures2<'_>,
+) {
+    used("vkGetPhysicalDeviceFeatures2", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceFeatures2>(next(
+        435usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, p_features)
+}
+unsafe extern "system" fn get_physical_device_format_properties(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    p_format_properties: *mut vk::FormatProperties,
+) {
+    used("vkGetPhysicalDeviceFormatProperties", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceFormatProperties>(next(
+        436usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, format, p_format_properties)
+}
+unsafe extern "system" fn get_physical_device_format_properties2(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    p_format_properties: *mut vk::FormatProperties2<'_>,
+) {
+    used("vkGetPhysicalDeviceFormatProperties2", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceFormatProperties2>(next(
+        437usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, format, p_format_properties)
+}
+unsafe extern "system" fn get_physical_device_fragment_shading_rates_khr(
+    physical_device: vk::PhysicalDevice,
+    p_fragment_shading_rate_count: *mut u32,
+    p_fragment_shading_rates: *mut vk::PhysicalDeviceFragmentShadingRateKHR<'_>,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceFragmentShadingRatesKHR",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceFragmentShadingRatesKHR>(
+        next(438usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_fragment_shading_rate_count,
+        p_fragment_shading_rates,
+    )
+}
+unsafe extern "system" fn get_physical_device_image_format_properties(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    ty: vk::ImageType,
+    tiling: vk::ImageTiling,
+    usage: vk::ImageUsageFlags,
+    flags: vk::ImageCreateFlags,
+    p_image_format_properties: *mut vk::ImageFormatProperties,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceImageFormatProperties", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceImageFormatProperties>(
+        next(439usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        format,
+        ty,
+        tiling,
+        usage,
+        flags,
+        p_image_format_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_image_format_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_image_format_info: *const vk::PhysicalDeviceImageFormatInfo2<'_>,
+    p_image_format_properties: *mut vk::ImageFormatProperties2<'_>,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceImageFormatProperties2", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceImageFormatProperties2>(
+        next(440usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_image_format_info,
+        p_image_format_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_memory_properties(
+    physical_device: vk::PhysicalDevice,
+    p_memory_properties: *mut vk::PhysicalDeviceMemoryProperties,
+) {
+    used("vkGetPhysicalDeviceMemoryProperties", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceMemoryProperties>(next(
+        441usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, p_memory_properties)
+}
+unsafe extern "system" fn get_physical_device_memory_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_memory_properties: *mut vk::PhysicalDeviceMemoryProperties2<'_>,
+) {
+    used("vkGetPhysicalDeviceMemoryProperties2", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceMemoryProperties2>(next(
+        442usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, p_memory_properties)
+}
+unsafe extern "system" fn get_physical_device_multisample_properties_ext(
+    physical_device: vk::PhysicalDevice,
+    samples: vk::SampleCountFlags,
+    p_multisample_properties: *mut vk::MultisamplePropertiesEXT<'_>,
+) {
+    used(
+        "vkGetPhysicalDeviceMultisamplePropertiesEXT",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceMultisamplePropertiesEXT>(
+        next(443usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, samples, p_multisample_properties)
+}
+unsafe extern "system" fn get_physical_device_optical_flow_image_formats_nv(
+    physical_device: vk::PhysicalDevice,
+    p_optical_flow_image_format_info: *const vk::OpticalFlowImageFormatInfoNV<'_>,
+    p_format_count: *mut u32,
+    p_image_format_properties: *mut vk::OpticalFlowImageFormatPropertiesNV<'_>,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceOpticalFlowImageFormatsNV",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceOpticalFlowImageFormatsNV>(
+        next(444usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_optical_flow_image_format_info,
+        p_format_count,
+        p_image_format_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_present_rectangles_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_rect_count: *mut u32,
+    p_rects: *mut vk::Rect2D,
+) -> vk::Result {
+    used("vkGetPhysicalDevicePresentRectanglesKHR", physical_device);
+    used("vkGetPhysicalDevicePresentRectanglesKHR", surface);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDevicePresentRectanglesKHR>(
+        next(445usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, surface, p_rect_count, p_rects)
+}
+unsafe extern "system" fn get_physical_device_properties(
+    physical_device: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceProperties,
+) {
+    used("vkGetPhysicalDeviceProperties", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceProperties>(next(
+        446usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, p_properties)
+}
+unsafe extern "system" fn get_physical_device_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceProperties2<'_>,
+) {
+    used("vkGetPhysicalDeviceProperties2", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceProperties2>(next(
+        447usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, p_properties)
+}
+unsafe extern "system" fn get_physical_device_queue_family_performance_query_passes_khr(
+    physical_device: vk::PhysicalDevice,
+    p_performance_query_create_info: *const vk::QueryPoolPerformanceCreateInfoKHR<'_>,
+    p_num_passes: *mut u32,
+) {
+    used(
+        "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR",
+        physical_device,
+    );
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR,
+    >(next(448usize, dispatch_key(physical_device.as_raw())));
+    next(
+        physical_device,
+        p_performance_query_create_info,
+        p_num_passes,
+    )
+}
+unsafe extern "system" fn get_physical_device_queue_family_properties(
+    physical_device: vk::PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut vk::QueueFamilyProperties,
+) {
+    used("vkGetPhysicalDeviceQueueFamilyProperties", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties>(
+        next(449usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_queue_family_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut vk::QueueFamilyProperties2<'_>,
+) {
+    used("vkGetPhysicalDeviceQueueFamilyProperties2", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2>(
+        next(450usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_screen_presentation_support_qnx(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    window: *mut vk::_screen_window,
+) -> vk::Bool32 {
+    used(
+        "vkGetPhysicalDeviceScreenPresentationSupportQNX",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceScreenPresentationSupportQNX>(
+        next(451usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, queue_family_index, window)
+}
+unsafe extern "system" fn get_physical_device_sparse_image_format_properties(
+    physical_device: vk::PhysicalDevice,
+    format: vk::Format,
+    ty: vk::ImageType,
+    samples: vk::SampleCountFlags,
+    usage: vk::ImageUsageFlags,
+    tiling: vk::ImageTiling,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::SparseImageFormatProperties,
+) {
+    used(
+        "vkGetPhysicalDeviceSparseImageFormatProperties",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties>(
+        next(452usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        format,
+        ty,
+        samples,
+        usage,
+        tiling,
+        p_property_count,
+        p_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_sparse_image_format_properties2(
+    physical_device: vk::PhysicalDevice,
+    p_format_info: *const vk::PhysicalDeviceSparseImageFormatInfo2<'_>,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::SparseImageFormatProperties2<'_>,
+) {
+    used(
+        "vkGetPhysicalDeviceSparseImageFormatProperties2",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties2>(
+        next(453usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_format_info,
+        p_property_count,
+        p_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_supported_framebuffer_mixed_samples_combinations_nv(
+    physical_device: vk::PhysicalDevice,
+    p_combination_count: *mut u32,
+    p_combinations: *mut vk::FramebufferMixedSamplesCombinationNV<'_>,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV",
+        physical_device,
+    );
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV,
+    >(next(454usize, dispatch_key(physical_device.as_raw())));
+    next(physical_device, p_combination_count, p_combinations)
+}
+unsafe extern "system" fn get_physical_device_surface_capabilities2_ext(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_surface_capabilities: *mut vk::SurfaceCapabilities2EXT<'_>,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceSurfaceCapabilities2EXT",
+        physical_device,
+    );
+    used("vkGetPhysicalDeviceSurfaceCapabilities2EXT", surface);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceSurfaceCapabilities2EXT>(
+        next(455usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, surface, p_surface_capabilities)
+}
+unsafe extern "system" fn get_physical_device_surface_capabilities2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_surface_capabilities: *mut vk::SurfaceCapabilities2KHR<'_>,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceSurfaceCapabilities2KHR",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR>(
+        next(456usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, p_surface_info, p_surface_capabilities)
+}
+unsafe extern "system" fn get_physical_device_surface_capabilities_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_surface_capabilities: *mut vk::SurfaceCapabilitiesKHR,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceSurfaceCapabilitiesKHR", physical_device);
+    used("vkGetPhysicalDeviceSurfaceCapabilitiesKHR", surface);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR>(
+        next(457usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, surface, p_surface_capabilities)
+}
+unsafe extern "system" fn get_physical_device_surface_formats2_khr(
+    physical_device: vk::PhysicalDevice,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut vk::SurfaceFormat2KHR<'_>,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceSurfaceFormats2KHR", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceSurfaceFormats2KHR>(next(
+        458usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(
+        physical_device,
+        p_surface_info,
+        p_surface_format_count,
+        p_surface_formats,
+    )
+}
+unsafe extern "system" fn get_physical_device_surface_formats_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_surface_format_count: *mut u32,
+    p_surface_formats: *mut vk::SurfaceFormatKHR,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceSurfaceFormatsKHR", physical_device);
+    used("vkGetPhysicalDeviceSurfaceFormatsKHR", surface);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceSurfaceFormatsKHR>(next(
+        459usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(
+        physical_device,
+        surface,
+        p_surface_format_count,
+        p_surface_formats,
+    )
+}
+unsafe extern "system" fn get_physical_device_surface_present_modes2_ext(
+    physical_device: vk::PhysicalDevice,
+    p_surface_info: *const vk::PhysicalDeviceSurfaceInfo2KHR<'_>,
+    p_present_mode_count: *mut u32,
+    p_present_modes: *mut vk::PresentModeKHR,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceSurfacePresentModes2EXT",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceSurfacePresentModes2EXT>(
+        next(460usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_surface_info,
+        p_present_mode_count,
+        p_present_modes,
+    )
+}
+unsafe extern "system" fn get_physical_device_surface_present_modes_khr(
+    physical_device: vk::PhysicalDevice,
+    surface: vk::SurfaceKHR,
+    p_present_mode_count: *mut u32,
+    p_present_modes: *mut vk::PresentModeKHR,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceSurfacePresentModesKHR", physical_device);
+    used("vkGetPhysicalDeviceSurfacePresentModesKHR", surface);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceSurfacePresentModesKHR>(
+        next(461usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        surface,
+        p_present_mode_count,
+        p_present_modes,
+    )
+}
+unsafe extern "system" fn get_physical_device_surface_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    surface: vk::SurfaceKHR,
+    p_supported: *mut vk::Bool32,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceSurfaceSupportKHR", physical_device);
+    used("vkGetPhysicalDeviceSurfaceSupportKHR", surface);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceSurfaceSupportKHR>(next(
+        462usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, queue_family_index, surface, p_supported)
+}
+unsafe extern "system" fn get_physical_device_tool_properties(
+    physical_device: vk::PhysicalDevice,
+    p_tool_count: *mut u32,
+    p_tool_properties: *mut vk::PhysicalDeviceToolProperties<'_>,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceToolProperties", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceToolProperties>(next(
+        463usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, p_tool_count, p_tool_properties)
+}
+unsafe extern "system" fn get_physical_device_video_capabilities_khr(
+    physical_device: vk::PhysicalDevice,
+    p_video_profile: *const vk::VideoProfileInfoKHR<'_>,
+    p_capabilities: *mut vk::VideoCapabilitiesKHR<'_>,
+) -> vk::Result {
+    used("vkGetPhysicalDeviceVideoCapabilitiesKHR", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceVideoCapabilitiesKHR>(
+        next(464usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, p_video_profile, p_capabilities)
+}
+unsafe extern "system" fn get_physical_device_video_encode_quality_level_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_quality_level_info: *const vk::PhysicalDeviceVideoEncodeQualityLevelInfoKHR<'_>,
+    p_quality_level_properties: *mut vk::VideoEncodeQualityLevelPropertiesKHR<'_>,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR",
+        physical_device,
+    );
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR,
+    >(next(465usize, dispatch_key(physical_device.as_raw())));
+    next(
+        physical_device,
+        p_quality_level_info,
+        p_quality_level_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_video_format_properties_khr(
+    physical_device: vk::PhysicalDevice,
+    p_video_format_info: *const vk::PhysicalDeviceVideoFormatInfoKHR<'_>,
+    p_video_format_property_count: *mut u32,
+    p_video_format_properties: *mut vk::VideoFormatPropertiesKHR<'_>,
+) -> vk::Result {
+    used(
+        "vkGetPhysicalDeviceVideoFormatPropertiesKHR",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceVideoFormatPropertiesKHR>(
+        next(466usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(
+        physical_device,
+        p_video_format_info,
+        p_video_format_property_count,
+        p_video_format_properties,
+    )
+}
+unsafe extern "system" fn get_physical_device_wayland_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    display: *mut vk::wl_display,
+) -> vk::Bool32 {
+    used(
+        "vkGetPhysicalDeviceWaylandPresentationSupportKHR",
+        physical_device,
+    );
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetPhysicalDeviceWaylandPresentationSupportKHR,
+    >(next(467usize, dispatch_key(physical_device.as_raw())));
+    next(physical_device, queue_family_index, display)
+}
+unsafe extern "system" fn get_physical_device_win32_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+) -> vk::Bool32 {
+    used(
+        "vkGetPhysicalDeviceWin32PresentationSupportKHR",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR>(
+        next(468usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, queue_family_index)
+}
+unsafe extern "system" fn get_physical_device_xcb_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    connection: *mut vk::xcb_connection_t,
+    visual_id: vk::xcb_visualid_t,
+) -> vk::Bool32 {
+    used(
+        "vkGetPhysicalDeviceXcbPresentationSupportKHR",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceXcbPresentationSupportKHR>(
+        next(469usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, queue_family_index, connection, visual_id)
+}
+unsafe extern "system" fn get_physical_device_xlib_presentation_support_khr(
+    physical_device: vk::PhysicalDevice,
+    queue_family_index: u32,
+    dpy: *mut vk::Display,
+    visual_id: vk::VisualID,
+) -> vk::Bool32 {
+    used(
+        "vkGetPhysicalDeviceXlibPresentationSupportKHR",
+        physical_device,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPhysicalDeviceXlibPresentationSupportKHR>(
+        next(470usize, dispatch_key(physical_device.as_raw())),
+    );
+    next(physical_device, queue_family_index, dpy, visual_id)
+}
+unsafe extern "system" fn get_pipeline_cache_data(
+    device: vk::Device,
+    pipeline_cache: vk::PipelineCache,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetPipelineCacheData", device);
+    used("vkGetPipelineCacheData", pipeline_cache);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPipelineCacheData>(next(
+        471usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, pipeline_cache, p_data_size, p_data)
+}
+unsafe extern "system" fn get_pipeline_executable_internal_representations_khr(
+    device: vk::Device,
+    p_executable_info: *const vk::PipelineExecutableInfoKHR<'_>,
+    p_internal_representation_count: *mut u32,
+    p_internal_representations: *mut vk::PipelineExecutableInternalRepresentationKHR<'_>,
+) -> vk::Result {
+    used("vkGetPipelineExecutableInternalRepresentationsKHR", device);
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetPipelineExecutableInternalRepresentationsKHR,
+    >(next(472usize, dispatch_key(device.as_raw())));
+    next(
+        device,
+        p_executable_info,
+        p_internal_representation_count,
+        p_internal_representations,
+    )
+}
+unsafe extern "system" fn get_pipeline_executable_properties_khr(
+    device: vk::Device,
+    p_pipeline_info: *const vk::PipelineInfoKHR<'_>,
+    p_executable_count: *mut u32,
+    p_properties: *mut vk::PipelineExecutablePropertiesKHR<'_>,
+) -> vk::Result {
+    used("vkGetPipelineExecutablePropertiesKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPipelineExecutablePropertiesKHR>(next(
+        473usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_pipeline_info, p_executable_count, p_properties)
+}
+unsafe extern "system" fn get_pipeline_executable_statistics_khr(
+    device: vk::Device,
+    p_executable_info: *const vk::PipelineExecutableInfoKHR<'_>,
+    p_statistic_count: *mut u32,
+    p_statistics: *mut vk::PipelineExecutableStatisticKHR<'_>,
+) -> vk::Result {
+    used("vkGetPipelineExecutableStatisticsKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPipelineExecutableStatisticsKHR>(next(
+        474usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_executable_info, p_statistic_count, p_statistics)
+}
+unsafe extern "system" fn get_pipeline_properties_ext(
+    device: vk::Device,
+    p_pipeline_info: *const vk::PipelineInfoEXT<'_>,
+    p_pipeline_properties: *mut vk::BaseOutStructure<'_>,
+) -> vk::Result {
+    used("vkGetPipelinePropertiesEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPipelinePropertiesEXT>(next(
+        475usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_pipeline_info, p_pipeline_properties)
+}
+unsafe extern "system" fn get_private_data(
+    device: vk::Device,
+    object_type: vk::ObjectType,
+    object_handle: u64,
+    private_data_slot: vk::PrivateDataSlot,
+    p_data: *mut u64,
+) {
+    used("vkGetPrivateData", device);
+    used("vkGetPrivateData", private_data_slot);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetPrivateData>(next(
+        476usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        object_type,
+        object_handle,
+        private_data_slot,
+        p_data,
+    )
+}
+unsafe extern "system" fn get_query_pool_results(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: vk::DeviceSize,
+    flags: vk::QueryResultFlags,
+) -> vk::Result {
+    used("vkGetQueryPoolResults", device);
+    used("vkGetQueryPoolResults", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetQueryPoolResults>(next(
+        477usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        query_pool,
+        first_query,
+        query_count,
+        data_size,
+        p_data,
+        stride,
+        flags,
+    )
+}
+unsafe extern "system" fn get_queue_checkpoint_data2_nv(
+    queue: vk::Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut vk::CheckpointData2NV<'_>,
+) {
+    used("vkGetQueueCheckpointData2NV", queue);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetQueueCheckpointData2NV>(next(
+        478usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(queue, p_checkpoint_data_count, p_checkpoint_data)
+}
+unsafe extern "system" fn get_queue_checkpoint_data_nv(
+    queue: vk::Queue,
+    p_checkpoint_data_count: *mut u32,
+    p_checkpoint_data: *mut vk::CheckpointDataNV<'_>,
+) {
+    used("vkGetQueueCheckpointDataNV", queue);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetQueueCheckpointDataNV>(next(
+        479usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(queue, p_checkpoint_data_count, p_checkpoint_data)
+}
+unsafe extern "system" fn get_rand_r_output_display_ext(
+    physical_device: vk::PhysicalDevice,
+    dpy: *mut vk::Display,
+    rr_output: vk::RROutput,
+    p_display: *mut vk::DisplayKHR,
+) -> vk::Result {
+    used("vkGetRandROutputDisplayEXT", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetRandROutputDisplayEXT>(next(
+        480usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let result = next(physical_device, dpy, rr_output, p_display);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_display.is_null() {
+        created(
+            "vkGetRandROutputDisplayEXT",
+            *p_display,
+            parent_of(physical_device),
+            false,
+        );
+    }
+    result
+}
+unsafe extern "system" fn get_ray_tracing_capture_replay_shader_group_handles_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetRayTracingCaptureReplayShaderGroupHandlesKHR", device);
+    used(
+        "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR",
+        pipeline,
+    );
+    let next = mem::transmute::<
+        *mut c_void,
+        vk::PFN_vkGetRayTracingCaptureReplayShaderGroupHandlesKHR,
+    >(next(481usize, dispatch_key(device.as_raw())));
+    next(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    )
+}
+unsafe extern "system" fn get_ray_tracing_shader_group_handles_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    first_group: u32,
+    group_count: u32,
+    data_size: usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetRayTracingShaderGroupHandlesKHR", device);
+    used("vkGetRayTracingShaderGroupHandlesKHR", pipeline);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetRayTracingShaderGroupHandlesKHR>(next(
+        482usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        pipeline,
+        first_group,
+        group_count,
+        data_size,
+        p_data,
+    )
+}
+unsafe extern "system" fn get_ray_tracing_shader_group_stack_size_khr(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    group: u32,
+    group_shader: vk::ShaderGroupShaderKHR,
+) -> vk::DeviceSize {
+    used("vkGetRayTracingShaderGroupStackSizeKHR", device);
+    used("vkGetRayTracingShaderGroupStackSizeKHR", pipeline);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetRayTracingShaderGroupStackSizeKHR>(next(
+        483usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, pipeline, group, group_shader)
+}
+unsafe extern "system" fn get_refresh_cycle_duration_google(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_display_timing_properties: *mut vk::RefreshCycleDurationGOOGLE,
+) -> vk::Result {
+    used("vkGetRefreshCycleDurationGOOGLE", device);
+    used("vkGetRefreshCycleDurationGOOGLE", swapchain);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetRefreshCycleDurationGOOGLE>(next(
+        484usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, swapchain, p_display_timing_properties)
+}
+unsafe extern "system" fn get_render_area_granularity(
+    device: vk::Device,
+    render_pass: vk::RenderPass,
+    p_granularity: *mut vk::Extent2D,
+) {
+    used("vkGetRenderAreaGranularity", device);
+    used("vkGetRenderAreaGranularity", render_pass);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetRenderAreaGranularity>(next(
+        485usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, render_pass, p_granularity)
+}
+unsafe extern "system" fn get_sampler_opaque_capture_descriptor_data_ext(
+    device: vk::Device,
+    p_info: *const vk::SamplerCaptureDescriptorDataInfoEXT<'_>,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetSamplerOpaqueCaptureDescriptorDataEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSamplerOpaqueCaptureDescriptorDataEXT>(
+        next(486usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_info, p_data)
+}
+unsafe extern "system" fn get_screen_buffer_properties_qnx(
+    device: vk::Device,
+    buffer: *const vk::_screen_buffer,
+    p_properties: *mut vk::ScreenBufferPropertiesQNX<'_>,
+) -> vk::Result {
+    used("vkGetScreenBufferPropertiesQNX", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetScreenBufferPropertiesQNX>(next(
+        487usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, buffer, p_properties)
+}
+unsafe extern "system" fn get_semaphore_counter_value(
+    device: vk::Device,
+    semaphore: vk::Semaphore,
+    p_value: *mut u64,
+) -> vk::Result {
+    used("vkGetSemaphoreCounterValue", device);
+    used("vkGetSemaphoreCounterValue", semaphore);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSemaphoreCounterValue>(next(
+        488usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, semaphore, p_value)
+}
+unsafe extern "system" fn get_semaphore_fd_khr(
+    device: vk::Device,
+    p_get_fd_info: *const vk::SemaphoreGetFdInfoKHR<'_>,
+    p_fd: *mut c_int,
+) -> vk::Result {
+    used("vkGetSemaphoreFdKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSemaphoreFdKHR>(next(
+        489usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_get_fd_info, p_fd)
+}
+unsafe extern "system" fn get_semaphore_win32_handle_khr(
+    device: vk::Device,
+    p_get_win32_handle_info: *const vk::SemaphoreGetWin32HandleInfoKHR<'_>,
+    p_handle: *mut vk::HANDLE,
+) -> vk::Result {
+    used("vkGetSemaphoreWin32HandleKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSemaphoreWin32HandleKHR>(next(
+        490usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_get_win32_handle_info, p_handle)
+}
+unsafe extern "system" fn get_semaphore_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_get_zircon_handle_info: *const vk::SemaphoreGetZirconHandleInfoFUCHSIA<'_>,
+    p_zircon_handle: *mut vk::zx_handle_t,
+) -> vk::Result {
+    used("vkGetSemaphoreZirconHandleFUCHSIA", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSemaphoreZirconHandleFUCHSIA>(next(
+        491usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_get_zircon_handle_info, p_zircon_handle)
+}
+unsafe extern "system" fn get_shader_binary_data_ext(
+    device: vk::Device,
+    shader: vk::ShaderEXT,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetShaderBinaryDataEXT", device);
+    used("vkGetShaderBinaryDataEXT", shader);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetShaderBinaryDataEXT>(next(
+        492usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, shader, p_data_size, p_data)
+}
+unsafe extern "system" fn get_shader_info_amd(
+    device: vk::Device,
+    pipeline: vk::Pipeline,
+    shader_stage: vk::ShaderStageFlags,
+    info_type: vk::ShaderInfoTypeAMD,
+    p_info_size: *mut usize,
+    p_info: *mut c_void,
+) -> vk::Result {
+    used("vkGetShaderInfoAMD", device);
+    used("vkGetShaderInfoAMD", pipeline);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetShaderInfoAMD>(next(
+        493usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        pipeline,
+        shader_stage,
+        info_type,
+        p_info_size,
+        p_info,
+    )
+}
+unsafe extern "system" fn get_shader_module_create_info_identifier_ext(
+    device: vk::Device,
+    p_create_info: *const vk::ShaderModuleCreateInfo<'_>,
+    p_identifier: *mut vk::ShaderModuleIdentifierEXT<'_>,
+) {
+    used("vkGetShaderModuleCreateInfoIdentifierEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetShaderModuleCreateInfoIdentifierEXT>(
+        next(494usize, dispatch_key(device.as_raw())),
+    );
+    next(device, p_create_info, p_identifier)
+}
+unsafe extern "system" fn get_shader_module_identifier_ext(
+    device: vk::Device,
+    shader_module: vk::ShaderModule,
+    p_identifier: *mut vk::ShaderModuleIdentifierEXT<'_>,
+) {
+    used("vkGetShaderModuleIdentifierEXT", device);
+    used("vkGetShaderModuleIdentifierEXT", shader_module);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetShaderModuleIdentifierEXT>(next(
+        495usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, shader_module, p_identifier)
+}
+unsafe extern "system" fn get_swapchain_counter_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    counter: vk::SurfaceCounterFlagsEXT,
+    p_counter_value: *mut u64,
+) -> vk::Result {
+    used("vkGetSwapchainCounterEXT", device);
+    used("vkGetSwapchainCounterEXT", swapchain);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSwapchainCounterEXT>(next(
+        496usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, swapchain, counter, p_counter_value)
+}
+unsafe extern "system" fn get_swapchain_gralloc_usage2_android(
+    device: vk::Device,
+    format: vk::Format,
+    image_usage: vk::ImageUsageFlags,
+    swapchain_image_usage: vk::SwapchainImageUsageFlagsANDROID,
+    gralloc_consumer_usage: *mut u64,
+    gralloc_producer_usage: *mut u64,
+) -> vk::Result {
+    used("vkGetSwapchainGrallocUsage2ANDROID", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSwapchainGrallocUsage2ANDROID>(next(
+        497usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        format,
+        image_usage,
+        swapchain_image_usage,
+        gralloc_consumer_usage,
+        gralloc_producer_usage,
+    )
+}
+unsafe extern "system" fn get_swapchain_gralloc_usage_android(
+    device: vk::Device,
+    format: vk::Format,
+    image_usage: vk::ImageUsageFlags,
+    gralloc_usage: *mut c_int,
+) -> vk::Result {
+    used("vkGetSwapchainGrallocUsageANDROID", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSwapchainGrallocUsageANDROID>(next(
+        498usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, format, image_usage, gralloc_usage)
+}
+unsafe extern "system" fn get_swapchain_images_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    p_swapchain_image_count: *mut u32,
+    p_swapchain_images: *mut vk::Image,
+) -> vk::Result {
+    used("vkGetSwapchainImagesKHR", device);
+    used("vkGetSwapchainImagesKHR", swapchain);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSwapchainImagesKHR>(next(
+        499usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(
+        device,
+        swapchain,
+        p_swapchain_image_count,
+        p_swapchain_images,
+    );
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_swapchain_images.is_null() {
+        let parent = parent_of(device);
+        for i in 0..*p_swapchain_image_count as usize {
+            created(
+                "vkGetSwapchainImagesKHR",
+                *p_swapchain_images.add(i),
+                parent,
+                false,
+            );
+        }
+    }
+    result
+}
+unsafe extern "system" fn get_swapchain_status_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    used("vkGetSwapchainStatusKHR", device);
+    used("vkGetSwapchainStatusKHR", swapchain);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetSwapchainStatusKHR>(next(
+        500usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, swapchain)
+}
+unsafe extern "system" fn get_validation_cache_data_ext(
+    device: vk::Device,
+    validation_cache: vk::ValidationCacheEXT,
+    p_data_size: *mut usize,
+    p_data: *mut c_void,
+) -> vk::Result {
+    used("vkGetValidationCacheDataEXT", device);
+    used("vkGetValidationCacheDataEXT", validation_cache);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetValidationCacheDataEXT>(next(
+        501usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, validation_cache, p_data_size, p_data)
+}
+unsafe extern "system" fn get_video_session_memory_requirements_khr(
+    device: vk::Device,
+    video_session: vk::VideoSessionKHR,
+    p_memory_requirements_count: *mut u32,
+    p_memory_requirements: *mut vk::VideoSessionMemoryRequirementsKHR<'_>,
+) -> vk::Result {
+    used("vkGetVideoSessionMemoryRequirementsKHR", device);
+    used("vkGetVideoSessionMemoryRequirementsKHR", video_session);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetVideoSessionMemoryRequirementsKHR>(next(
+        502usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        video_session,
+        p_memory_requirements_count,
+        p_memory_requirements,
+    )
+}
+unsafe extern "system" fn get_winrt_display_nv(
+    physical_device: vk::PhysicalDevice,
+    device_relative_id: u32,
+    p_display: *mut vk::DisplayKHR,
+) -> vk::Result {
+    used("vkGetWinrtDisplayNV", physical_device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkGetWinrtDisplayNV>(next(
+        503usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    let result = next(physical_device, device_relative_id, p_display);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_display.is_null() {
+        created(
+            "vkGetWinrtDisplayNV",
+            *p_display,
+            parent_of(physical_device),
+            false,
+        );
+    }
+    result
+}
+unsafe extern "system" fn import_fence_fd_khr(
+    device: vk::Device,
+    p_import_fence_fd_info: *const vk::ImportFenceFdInfoKHR<'_>,
+) -> vk::Result {
+    used("vkImportFenceFdKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkImportFenceFdKHR>(next(
+        504usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_import_fence_fd_info)
+}
+unsafe extern "system" fn import_fence_win32_handle_khr(
+    device: vk::Device,
+    p_import_fence_win32_handle_info: *const vk::ImportFenceWin32HandleInfoKHR<'_>,
+) -> vk::Result {
+    used("vkImportFenceWin32HandleKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkImportFenceWin32HandleKHR>(next(
+        505usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_import_fence_win32_handle_info)
+}
+unsafe extern "system" fn import_semaphore_fd_khr(
+    device: vk::Device,
+    p_import_semaphore_fd_info: *const vk::ImportSemaphoreFdInfoKHR<'_>,
+) -> vk::Result {
+    used("vkImportSemaphoreFdKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkImportSemaphoreFdKHR>(next(
+        506usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_import_semaphore_fd_info)
+}
+unsafe extern "system" fn import_semaphore_win32_handle_khr(
+    device: vk::Device,
+    p_import_semaphore_win32_handle_info: *const vk::ImportSemaphoreWin32HandleInfoKHR<'_>,
+) -> vk::Result {
+    used("vkImportSemaphoreWin32HandleKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkImportSemaphoreWin32HandleKHR>(next(
+        507usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_import_semaphore_win32_handle_info)
+}
+unsafe extern "system" fn import_semaphore_zircon_handle_fuchsia(
+    device: vk::Device,
+    p_import_semaphore_zircon_handle_info: *const vk::ImportSemaphoreZirconHandleInfoFUCHSIA<'_>,
+) -> vk::Result {
+    used("vkImportSemaphoreZirconHandleFUCHSIA", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkImportSemaphoreZirconHandleFUCHSIA>(next(
+        508usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_import_semaphore_zircon_handle_info)
+}
+unsafe extern "system" fn initialize_performance_api_intel(
+    device: vk::Device,
+    p_initialize_info: *const vk::InitializePerformanceApiInfoINTEL<'_>,
+) -> vk::Result {
+    used("vkInitializePerformanceApiINTEL", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkInitializePerformanceApiINTEL>(next(
+        509usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_initialize_info)
+}
+unsafe extern "system" fn invalidate_mapped_memory_ranges(
+    device: vk::Device,
+    memory_range_count: u32,
+    p_memory_ranges: *const vk::MappedMemoryRange<'_>,
+) -> vk::Result {
+    used("vkInvalidateMappedMemoryRanges", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkInvalidateMappedMemoryRanges>(next(
+        510usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, memory_range_count, p_memory_ranges)
+}
+unsafe extern "system" fn map_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    flags: vk::MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    used("vkMapMemory", device);
+    used("vkMapMemory", memory);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkMapMemory>(next(
+        511usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, memory, offset, size, flags, pp_data)
+}
+unsafe extern "system" fn map_memory2_khr(
+    device: vk::Device,
+    p_memory_map_info: *const vk::MemoryMapInfoKHR<'_>,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    used("vkMapMemory2KHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkMapMemory2KHR>(next(
+        512usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_memory_map_info, pp_data)
+}
+unsafe extern "system" fn merge_pipeline_caches(
+    device: vk::Device,
+    dst_cache: vk::PipelineCache,
+    src_cache_count: u32,
+    p_src_caches: *const vk::PipelineCache,
+) -> vk::Result {
+    used("vkMergePipelineCaches", device);
+    used("vkMergePipelineCaches", dst_cache);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkMergePipelineCaches>(next(
+        513usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, dst_cache, src_cache_count, p_src_caches)
+}
+unsafe extern "system" fn merge_validation_caches_ext(
+    device: vk::Device,
+    dst_cache: vk::ValidationCacheEXT,
+    src_cache_count: u32,
+    p_src_caches: *const vk::ValidationCacheEXT,
+) -> vk::Result {
+    used("vkMergeValidationCachesEXT", device);
+    used("vkMergeValidationCachesEXT", dst_cache);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkMergeValidationCachesEXT>(next(
+        514usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, dst_cache, src_cache_count, p_src_caches)
+}
+unsafe extern "system" fn queue_begin_debug_utils_label_ext(
+    queue: vk::Queue,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    used("vkQueueBeginDebugUtilsLabelEXT", queue);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueBeginDebugUtilsLabelEXT>(next(
+        515usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(queue, p_label_info)
+}
+unsafe extern "system" fn queue_bind_sparse(
+    queue: vk::Queue,
+    bind_info_count: u32,
+    p_bind_info: *const vk::BindSparseInfo<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    used("vkQueueBindSparse", queue);
+    used("vkQueueBindSparse", fence);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueBindSparse>(next(
+        516usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(queue, bind_info_count, p_bind_info, fence)
+}
+unsafe extern "system" fn queue_end_debug_utils_label_ext(queue: vk::Queue) {
+    used("vkQueueEndDebugUtilsLabelEXT", queue);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueEndDebugUtilsLabelEXT>(next(
+        517usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(queue)
+}
+unsafe extern "system" fn queue_insert_debug_utils_label_ext(
+    queue: vk::Queue,
+    p_label_info: *const vk::DebugUtilsLabelEXT<'_>,
+) {
+    used("vkQueueInsertDebugUtilsLabelEXT", queue);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueInsertDebugUtilsLabelEXT>(next(
+        518usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(queue, p_label_info)
+}
+unsafe extern "system" fn queue_present_khr(
+    queue: vk::Queue,
+    p_present_info: *const vk::PresentInfoKHR<'_>,
+) -> vk::Result {
+    used("vkQueuePresentKHR", queue);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueuePresentKHR>(next(
+        519usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(queue, p_present_info)
+}
+unsafe extern "system" fn queue_set_performance_configuration_intel(
+    queue: vk::Queue,
+    configuration: vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    used("vkQueueSetPerformanceConfigurationINTEL", queue);
+    used("vkQueueSetPerformanceConfigurationINTEL", configuration);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueSetPerformanceConfigurationINTEL>(
+        next(520usize, dispatch_key(queue.as_raw())),
+    );
+    next(queue, configuration)
+}
+unsafe extern "system" fn queue_signal_release_image_android(
+    queue: vk::Queue,
+    wait_semaphore_count: u32,
+    p_wait_semaphores: *const vk::Semaphore,
+    image: vk::Image,
+    p_native_fence_fd: *mut c_int,
+) -> vk::Result {
+    used("vkQueueSignalReleaseImageANDROID", queue);
+    used("vkQueueSignalReleaseImageANDROID", image);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueSignalReleaseImageANDROID>(next(
+        521usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(
+        queue,
+        wait_semaphore_count,
+        p_wait_semaphores,
+        image,
+        p_native_fence_fd,
+    )
+}
+unsafe extern "system" fn queue_submit(
+    queue: vk::Queue,
+    submit_count: u32,
+    p_submits: *const vk::SubmitInfo<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    used("vkQueueSubmit", queue);
+    used("vkQueueSubmit", fence);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueSubmit>(next(
+        522usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(queue, submit_count, p_submits, fence)
+}
+unsafe extern "system" fn queue_submit2(
+    queue: vk::Queue,
+    submit_count: u32,
+    p_submits: *const vk::SubmitInfo2<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    used("vkQueueSubmit2", queue);
+    used("vkQueueSubmit2", fence);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueSubmit2>(next(
+        523usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(queue, submit_count, p_submits, fence)
+}
+unsafe extern "system" fn queue_wait_idle(queue: vk::Queue) -> vk::Result {
+    used("vkQueueWaitIdle", queue);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkQueueWaitIdle>(next(
+        524usize,
+        dispatch_key(queue.as_raw()),
+    ));
+    next(queue)
+}
+unsafe extern "system" fn register_device_event_ext(
+    device: vk::Device,
+    p_device_event_info: *const vk::DeviceEventInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    used("vkRegisterDeviceEventEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkRegisterDeviceEventEXT>(next(
+        525usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, p_device_event_info, p_allocator, p_fence);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_fence.is_null() {
+        created(
+            "vkRegisterDeviceEventEXT",
+            *p_fence,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn register_display_event_ext(
+    device: vk::Device,
+    display: vk::DisplayKHR,
+    p_display_event_info: *const vk::DisplayEventInfoEXT<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_fence: *mut vk::Fence,
+) -> vk::Result {
+    used("vkRegisterDisplayEventEXT", device);
+    used("vkRegisterDisplayEventEXT", display);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkRegisterDisplayEventEXT>(next(
+        526usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, display, p_display_event_info, p_allocator, p_fence);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    if !p_fence.is_null() {
+        created(
+            "vkRegisterDisplayEventEXT",
+            *p_fence,
+            parent_of(device),
+            true,
+        );
+    }
+    result
+}
+unsafe extern "system" fn release_display_ext(
+    physical_device: vk::PhysicalDevice,
+    display: vk::DisplayKHR,
+) -> vk::Result {
+    used("vkReleaseDisplayEXT", physical_device);
+    used("vkReleaseDisplayEXT", display);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkReleaseDisplayEXT>(next(
+        527usize,
+        dispatch_key(physical_device.as_raw()),
+    ));
+    next(physical_device, display)
+}
+unsafe extern "system" fn release_full_screen_exclusive_mode_ext(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+) -> vk::Result {
+    used("vkReleaseFullScreenExclusiveModeEXT", device);
+    used("vkReleaseFullScreenExclusiveModeEXT", swapchain);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkReleaseFullScreenExclusiveModeEXT>(next(
+        528usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, swapchain)
+}
+unsafe extern "system" fn release_performance_configuration_intel(
+    device: vk::Device,
+    configuration: vk::PerformanceConfigurationINTEL,
+) -> vk::Result {
+    used("vkReleasePerformanceConfigurationINTEL", device);
+    used("vkReleasePerformanceConfigurationINTEL", configuration);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkReleasePerformanceConfigurationINTEL>(next(
+        529usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, configuration)
+}
+unsafe extern "system" fn release_profiling_lock_khr(device: vk::Device) {
+    used("vkReleaseProfilingLockKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkReleaseProfilingLockKHR>(next(
+        530usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device)
+}
+unsafe extern "system" fn release_swapchain_images_ext(
+    device: vk::Device,
+    p_release_info: *const vk::ReleaseSwapchainImagesInfoEXT<'_>,
+) -> vk::Result {
+    used("vkReleaseSwapchainImagesEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkReleaseSwapchainImagesEXT>(next(
+        531usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_release_info)
+}
+unsafe extern "system" fn reset_command_buffer(
+    command_buffer: vk::CommandBuffer,
+    flags: vk::CommandBufferResetFlags,
+) -> vk::Result {
+    used("vkResetCommandBuffer", command_buffer);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetCommandBuffer>(next(
+        532usize,
+        dispatch_key(command_buffer.as_raw()),
+    ));
+    next(command_buffer, flags)
+}
+unsafe extern "system" fn reset_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    flags: vk::CommandPoolResetFlags,
+) -> vk::Result {
+    used("vkResetCommandPool", device);
+    used("vkResetCommandPool", command_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetCommandPool>(next(
+        533usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, command_pool, flags)
+}
+unsafe extern "system" fn reset_descriptor_pool(
+    device: vk::Device,
+    descriptor_pool: vk::DescriptorPool,
+    flags: vk::DescriptorPoolResetFlags,
+) -> vk::Result {
+    used("vkResetDescriptorPool", device);
+    used("vkResetDescriptorPool", descriptor_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetDescriptorPool>(next(
+        534usize,
+        dispatch_key(device.as_raw()),
+    ));
+    let result = next(device, descriptor_pool, flags);
+    if result.as_raw() < 0 {
+        return result;
+    }
+    reset("vkResetDescriptorPool", descriptor_pool);
+    result
+}
+unsafe extern "system" fn reset_event(device: vk::Device, event: vk::Event) -> vk::Result {
+    used("vkResetEvent", device);
+    used("vkResetEvent", event);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetEvent>(next(
+        535usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, event)
+}
+unsafe extern "system" fn reset_fences(
+    device: vk::Device,
+    fence_count: u32,
+    p_fences: *const vk::Fence,
+) -> vk::Result {
+    used("vkResetFences", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetFences>(next(
+        536usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, fence_count, p_fences)
+}
+unsafe extern "system" fn reset_query_pool(
+    device: vk::Device,
+    query_pool: vk::QueryPool,
+    first_query: u32,
+    query_count: u32,
+) {
+    used("vkResetQueryPool", device);
+    used("vkResetQueryPool", query_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkResetQueryPool>(next(
+        537usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, query_pool, first_query, query_count)
+}
+unsafe extern "system" fn set_buffer_collection_buffer_constraints_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_buffer_constraints_info: *const vk::BufferConstraintsInfoFUCHSIA<'_>,
+) -> vk::Result {
+    used("vkSetBufferCollectionBufferConstraintsFUCHSIA", device);
+    used("vkSetBufferCollectionBufferConstraintsFUCHSIA", collection);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetBufferCollectionBufferConstraintsFUCHSIA>(
+        next(538usize, dispatch_key(device.as_raw())),
+    );
+    next(device, collection, p_buffer_constraints_info)
+}
+unsafe extern "system" fn set_buffer_collection_image_constraints_fuchsia(
+    device: vk::Device,
+    collection: vk::BufferCollectionFUCHSIA,
+    p_image_constraints_info: *const vk::ImageConstraintsInfoFUCHSIA<'_>,
+) -> vk::Result {
+    used("vkSetBufferCollectionImageConstraintsFUCHSIA", device);
+    used("vkSetBufferCollectionImageConstraintsFUCHSIA", collection);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetBufferCollectionImageConstraintsFUCHSIA>(
+        next(539usize, dispatch_key(device.as_raw())),
+    );
+    next(device, collection, p_image_constraints_info)
+}
+unsafe extern "system" fn set_debug_utils_object_name_ext(
+    device: vk::Device,
+    p_name_info: *const vk::DebugUtilsObjectNameInfoEXT<'_>,
+) -> vk::Result {
+    used("vkSetDebugUtilsObjectNameEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetDebugUtilsObjectNameEXT>(next(
+        540usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_name_info)
+}
+unsafe extern "system" fn set_debug_utils_object_tag_ext(
+    device: vk::Device,
+    p_tag_info: *const vk::DebugUtilsObjectTagInfoEXT<'_>,
+) -> vk::Result {
+    used("vkSetDebugUtilsObjectTagEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetDebugUtilsObjectTagEXT>(next(
+        541usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_tag_info)
+}
+unsafe extern "system" fn set_device_memory_priority_ext(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    priority: f32,
+) {
+    used("vkSetDeviceMemoryPriorityEXT", device);
+    used("vkSetDeviceMemoryPriorityEXT", memory);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetDeviceMemoryPriorityEXT>(next(
+        542usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, memory, priority)
+}
+unsafe extern "system" fn set_event(device: vk::Device, event: vk::Event) -> vk::Result {
+    used("vkSetEvent", device);
+    used("vkSetEvent", event);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetEvent>(next(
+        543usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, event)
+}
+unsafe extern "system" fn set_hdr_metadata_ext(
+    device: vk::Device,
+    swapchain_count: u32,
+    p_swapchains: *const vk::SwapchainKHR,
+    p_metadata: *const vk::HdrMetadataEXT<'_>,
+) {
+    used("vkSetHdrMetadataEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetHdrMetadataEXT>(next(
+        544usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, swapchain_count, p_swapchains, p_metadata)
+}
+unsafe extern "system" fn set_local_dimming_amd(
+    device: vk::Device,
+    swap_chain: vk::SwapchainKHR,
+    local_dimming_enable: vk::Bool32,
+) {
+    used("vkSetLocalDimmingAMD", device);
+    used("vkSetLocalDimmingAMD", swap_chain);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetLocalDimmingAMD>(next(
+        545usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, swap_chain, local_dimming_enable)
+}
+unsafe extern "system" fn set_private_data(
+    device: vk::Device,
+    object_type: vk::ObjectType,
+    object_handle: u64,
+    private_data_slot: vk::PrivateDataSlot,
+    data: u64,
+) -> vk::Result {
+    used("vkSetPrivateData", device);
+    used("vkSetPrivateData", private_data_slot);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSetPrivateData>(next(
+        546usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, object_type, object_handle, private_data_slot, data)
+}
+unsafe extern "system" fn signal_semaphore(
+    device: vk::Device,
+    p_signal_info: *const vk::SemaphoreSignalInfo<'_>,
+) -> vk::Result {
+    used("vkSignalSemaphore", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSignalSemaphore>(next(
+        547usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_signal_info)
+}
+unsafe extern "system" fn submit_debug_utils_message_ext(
+    instance: vk::Instance,
+    message_severity: vk::DebugUtilsMessageSeverityFlagsEXT,
+    message_types: vk::DebugUtilsMessageTypeFlagsEXT,
+    p_callback_data: *const vk::DebugUtilsMessengerCallbackDataEXT<'_>,
+) {
+    used("vkSubmitDebugUtilsMessageEXT", instance);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkSubmitDebugUtilsMessageEXT>(next(
+        548usize,
+        dispatch_key(instance.as_raw()),
+    ));
+    next(instance, message_severity, message_types, p_callback_data)
+}
+unsafe extern "system" fn trim_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    flags: vk::CommandPoolTrimFlags,
+) {
+    used("vkTrimCommandPool", device);
+    used("vkTrimCommandPool", command_pool);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkTrimCommandPool>(next(
+        549usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, command_pool, flags)
+}
+unsafe extern "system" fn uninitialize_performance_api_intel(device: vk::Device) {
+    used("vkUninitializePerformanceApiINTEL", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUninitializePerformanceApiINTEL>(next(
+        550usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device)
+}
+unsafe extern "system" fn unmap_memory(device: vk::Device, memory: vk::DeviceMemory) {
+    used("vkUnmapMemory", device);
+    used("vkUnmapMemory", memory);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUnmapMemory>(next(
+        551usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, memory)
+}
+unsafe extern "system" fn unmap_memory2_khr(
+    device: vk::Device,
+    p_memory_unmap_info: *const vk::MemoryUnmapInfoKHR<'_>,
+) -> vk::Result {
+    used("vkUnmapMemory2KHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUnmapMemory2KHR>(next(
+        552usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_memory_unmap_info)
+}
+unsafe extern "system" fn update_descriptor_set_with_template(
+    device: vk::Device,
+    descriptor_set: vk::DescriptorSet,
+    descriptor_update_template: vk::DescriptorUpdateTemplate,
+    p_data: *const c_void,
+) {
+    used("vkUpdateDescriptorSetWithTemplate", device);
+    used("vkUpdateDescriptorSetWithTemplate", descriptor_set);
+    used(
+        "vkUpdateDescriptorSetWithTemplate",
+        descriptor_update_template,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUpdateDescriptorSetWithTemplate>(next(
+        553usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, descriptor_set, descriptor_update_template, p_data)
+}
+unsafe extern "system" fn update_descriptor_sets(
+    device: vk::Device,
+    descriptor_write_count: u32,
+    p_descriptor_writes: *const vk::WriteDescriptorSet<'_>,
+    descriptor_copy_count: u32,
+    p_descriptor_copies: *const vk::CopyDescriptorSet<'_>,
+) {
+    used("vkUpdateDescriptorSets", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUpdateDescriptorSets>(next(
+        554usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        descriptor_write_count,
+        p_descriptor_writes,
+        descriptor_copy_count,
+        p_descriptor_copies,
+    )
+}
+unsafe extern "system" fn update_video_session_parameters_khr(
+    device: vk::Device,
+    video_session_parameters: vk::VideoSessionParametersKHR,
+    p_update_info: *const vk::VideoSessionParametersUpdateInfoKHR<'_>,
+) -> vk::Result {
+    used("vkUpdateVideoSessionParametersKHR", device);
+    used(
+        "vkUpdateVideoSessionParametersKHR",
+        video_session_parameters,
+    );
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkUpdateVideoSessionParametersKHR>(next(
+        555usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, video_session_parameters, p_update_info)
+}
+unsafe extern "system" fn wait_for_fences(
+    device: vk::Device,
+    fence_count: u32,
+    p_fences: *const vk::Fence,
+    wait_all: vk::Bool32,
+    timeout: u64,
+) -> vk::Result {
+    used("vkWaitForFences", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkWaitForFences>(next(
+        556usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, fence_count, p_fences, wait_all, timeout)
+}
+unsafe extern "system" fn wait_for_present_khr(
+    device: vk::Device,
+    swapchain: vk::SwapchainKHR,
+    present_id: u64,
+    timeout: u64,
+) -> vk::Result {
+    used("vkWaitForPresentKHR", device);
+    used("vkWaitForPresentKHR", swapchain);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkWaitForPresentKHR>(next(
+        557usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, swapchain, present_id, timeout)
+}
+unsafe extern "system" fn wait_semaphores(
+    device: vk::Device,
+    p_wait_info: *const vk::SemaphoreWaitInfo<'_>,
+    timeout: u64,
+) -> vk::Result {
+    used("vkWaitSemaphores", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkWaitSemaphores>(next(
+        558usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(device, p_wait_info, timeout)
+}
+unsafe extern "system" fn write_acceleration_structures_properties_khr(
+    device: vk::Device,
+    acceleration_structure_count: u32,
+    p_acceleration_structures: *const vk::AccelerationStructureKHR,
+    query_type: vk::QueryType,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: usize,
+) -> vk::Result {
+    used("vkWriteAccelerationStructuresPropertiesKHR", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkWriteAccelerationStructuresPropertiesKHR>(
+        next(559usize, dispatch_key(device.as_raw())),
+    );
+    next(
+        device,
+        acceleration_structure_count,
+        p_acceleration_structures,
+        query_type,
+        data_size,
+        p_data,
+        stride,
+    )
+}
+unsafe extern "system" fn write_micromaps_properties_ext(
+    device: vk::Device,
+    micromap_count: u32,
+    p_micromaps: *const vk::MicromapEXT,
+    query_type: vk::QueryType,
+    data_size: usize,
+    p_data: *mut c_void,
+    stride: usize,
+) -> vk::Result {
+    used("vkWriteMicromapsPropertiesEXT", device);
+    let next = mem::transmute::<*mut c_void, vk::PFN_vkWriteMicromapsPropertiesEXT>(next(
+        560usize,
+        dispatch_key(device.as_raw()),
+    ));
+    next(
+        device,
+        micromap_count,
+        p_micromaps,
+        query_type,
+        data_size,
+        p_data,
+        stride,
+    )
+}
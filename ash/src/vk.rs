@@ -45,6 +45,14 @@ pub(crate) unsafe fn ptr_chain_iter<T>(ptr: &mut T) -> impl Iterator<Item = *mut
         Some(old)
     })
 }
+/// Reads a fixed-size string member up to its first nul character, or returns `None` if it is not
+/// nul-terminated.
+pub(crate) fn wrap_c_str_slice_until_nul(str: &[std::os::raw::c_char]) -> Option<&std::ffi::CStr> {
+    // SAFETY: `c_char` is either `i8` or `u8`, with the layout of `u8`
+    let bytes = unsafe { std::slice::from_raw_parts(str.as_ptr().cast::<u8>(), str.len()) };
+    let nul = bytes.iter().position(|&c| c == 0)?;
+    std::ffi::CStr::from_bytes_with_nul(&bytes[..=nul]).ok()
+}
 pub trait Handle: Sized {
     const TYPE: ObjectType;
     fn as_raw(self) -> u64;
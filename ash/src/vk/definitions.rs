@@ -5,7 +5,7 @@ use crate::vk::enums::*;
 use crate::vk::native::*;
 use crate::vk::platform_types::*;
 use crate::vk::prelude::*;
-use crate::vk::{ptr_chain_iter, Handle};
+use crate::vk::{ptr_chain_iter, wrap_c_str_slice_until_nul, Handle};
 use std::fmt;
 use std::marker::PhantomData;
 use std::os::raw::*;
@@ -874,6 +874,10 @@ impl PhysicalDeviceProperties {
         self
     }
     #[inline]
+    pub fn device_name_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.device_name)
+    }
+    #[inline]
     pub fn pipeline_cache_uuid(mut self, pipeline_cache_uuid: [u8; UUID_SIZE]) -> Self {
         self.pipeline_cache_uuid = pipeline_cache_uuid;
         self
@@ -923,6 +927,10 @@ impl ExtensionProperties {
         self
     }
     #[inline]
+    pub fn extension_name_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.extension_name)
+    }
+    #[inline]
     pub fn spec_version(mut self, spec_version: u32) -> Self {
         self.spec_version = spec_version;
         self
@@ -970,6 +978,10 @@ impl LayerProperties {
         self
     }
     #[inline]
+    pub fn layer_name_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.layer_name)
+    }
+    #[inline]
     pub fn spec_version(mut self, spec_version: u32) -> Self {
         self.spec_version = spec_version;
         self
@@ -984,6 +996,10 @@ impl LayerProperties {
         self.description = description;
         self
     }
+    #[inline]
+    pub fn description_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.description)
+    }
 }
 #[repr(C)]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -10712,11 +10728,19 @@ impl<'a> PhysicalDeviceDriverProperties<'a> {
         self
     }
     #[inline]
+    pub fn driver_name_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.driver_name)
+    }
+    #[inline]
     pub fn driver_info(mut self, driver_info: [c_char; MAX_DRIVER_INFO_SIZE]) -> Self {
         self.driver_info = driver_info;
         self
     }
     #[inline]
+    pub fn driver_info_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.driver_info)
+    }
+    #[inline]
     pub fn conformance_version(mut self, conformance_version: ConformanceVersion) -> Self {
         self.conformance_version = conformance_version;
         self
@@ -26669,15 +26693,27 @@ impl<'a> PerformanceCounterDescriptionKHR<'a> {
         self
     }
     #[inline]
+    pub fn name_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.name)
+    }
+    #[inline]
     pub fn category(mut self, category: [c_char; MAX_DESCRIPTION_SIZE]) -> Self {
         self.category = category;
         self
     }
     #[inline]
+    pub fn category_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.category)
+    }
+    #[inline]
     pub fn description(mut self, description: [c_char; MAX_DESCRIPTION_SIZE]) -> Self {
         self.description = description;
         self
     }
+    #[inline]
+    pub fn description_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.description)
+    }
 }
 #[repr(C)]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -27754,11 +27790,19 @@ impl<'a> PipelineExecutablePropertiesKHR<'a> {
         self
     }
     #[inline]
+    pub fn name_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.name)
+    }
+    #[inline]
     pub fn description(mut self, description: [c_char; MAX_DESCRIPTION_SIZE]) -> Self {
         self.description = description;
         self
     }
     #[inline]
+    pub fn description_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.description)
+    }
+    #[inline]
     pub fn subgroup_size(mut self, subgroup_size: u32) -> Self {
         self.subgroup_size = subgroup_size;
         self
@@ -27870,11 +27914,19 @@ impl<'a> PipelineExecutableStatisticKHR<'a> {
         self
     }
     #[inline]
+    pub fn name_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.name)
+    }
+    #[inline]
     pub fn description(mut self, description: [c_char; MAX_DESCRIPTION_SIZE]) -> Self {
         self.description = description;
         self
     }
     #[inline]
+    pub fn description_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.description)
+    }
+    #[inline]
     pub fn format(mut self, format: PipelineExecutableStatisticFormatKHR) -> Self {
         self.format = format;
         self
@@ -27942,11 +27994,19 @@ impl<'a> PipelineExecutableInternalRepresentationKHR<'a> {
         self
     }
     #[inline]
+    pub fn name_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.name)
+    }
+    #[inline]
     pub fn description(mut self, description: [c_char; MAX_DESCRIPTION_SIZE]) -> Self {
         self.description = description;
         self
     }
     #[inline]
+    pub fn description_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.description)
+    }
+    #[inline]
     pub fn is_text(mut self, is_text: bool) -> Self {
         self.is_text = is_text.into();
         self
@@ -29686,11 +29746,19 @@ impl<'a> PhysicalDeviceVulkan12Properties<'a> {
         self
     }
     #[inline]
+    pub fn driver_name_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.driver_name)
+    }
+    #[inline]
     pub fn driver_info(mut self, driver_info: [c_char; MAX_DRIVER_INFO_SIZE]) -> Self {
         self.driver_info = driver_info;
         self
     }
     #[inline]
+    pub fn driver_info_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.driver_info)
+    }
+    #[inline]
     pub fn conformance_version(mut self, conformance_version: ConformanceVersion) -> Self {
         self.conformance_version = conformance_version;
         self
@@ -30805,11 +30873,19 @@ impl<'a> PhysicalDeviceToolProperties<'a> {
         self
     }
     #[inline]
+    pub fn name_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.name)
+    }
+    #[inline]
     pub fn version(mut self, version: [c_char; MAX_EXTENSION_NAME_SIZE]) -> Self {
         self.version = version;
         self
     }
     #[inline]
+    pub fn version_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.version)
+    }
+    #[inline]
     pub fn purposes(mut self, purposes: ToolPurposeFlags) -> Self {
         self.purposes = purposes;
         self
@@ -30820,10 +30896,18 @@ impl<'a> PhysicalDeviceToolProperties<'a> {
         self
     }
     #[inline]
+    pub fn description_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.description)
+    }
+    #[inline]
     pub fn layer(mut self, layer: [c_char; MAX_EXTENSION_NAME_SIZE]) -> Self {
         self.layer = layer;
         self
     }
+    #[inline]
+    pub fn layer_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.layer)
+    }
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -44438,6 +44522,10 @@ impl RenderPassSubpassFeedbackInfoEXT {
         self
     }
     #[inline]
+    pub fn description_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.description)
+    }
+    #[inline]
     pub fn post_merge_index(mut self, post_merge_index: u32) -> Self {
         self.post_merge_index = post_merge_index;
         self
@@ -47205,6 +47293,10 @@ impl DeviceFaultVendorInfoEXT {
         self
     }
     #[inline]
+    pub fn description_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.description)
+    }
+    #[inline]
     pub fn vendor_fault_code(mut self, vendor_fault_code: u64) -> Self {
         self.vendor_fault_code = vendor_fault_code;
         self
@@ -47311,6 +47403,10 @@ impl<'a> DeviceFaultInfoEXT<'a> {
         self
     }
     #[inline]
+    pub fn description_as_c_str(&self) -> Option<&::std::ffi::CStr> {
+        wrap_c_str_slice_until_nul(&self.description)
+    }
+    #[inline]
     pub fn address_infos(mut self, address_infos: &'a mut DeviceFaultAddressInfoEXT) -> Self {
         self.p_address_infos = address_infos;
         self
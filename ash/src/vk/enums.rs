@@ -1,4 +1,7 @@
+use crate::prelude::{debug_enum, parse_enum, Known};
+use crate::vk::ParseValueError;
 use std::fmt;
+use std::str::FromStr;
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
 #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkImageLayout.html>"]
@@ -2802,145 +2805,11922 @@ impl ShaderCodeTypeEXT {
     pub const BINARY: Self = Self(0);
     pub const SPIRV: Self = Self(1);
 }
+impl AccelerationStructureBuildTypeKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::HOST.0,
+            "HOST",
+            "VK_ACCELERATION_STRUCTURE_BUILD_TYPE_HOST_KHR",
+        ),
+        (
+            Self::DEVICE.0,
+            "DEVICE",
+            "VK_ACCELERATION_STRUCTURE_BUILD_TYPE_DEVICE_KHR",
+        ),
+        (
+            Self::HOST_OR_DEVICE.0,
+            "HOST_OR_DEVICE",
+            "VK_ACCELERATION_STRUCTURE_BUILD_TYPE_HOST_OR_DEVICE_KHR",
+        ),
+    ];
+}
+impl FromStr for AccelerationStructureBuildTypeKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("AccelerationStructureBuildTypeKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AccelerationStructureBuildTypeKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AccelerationStructureCompatibilityKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::COMPATIBLE.0,
+            "COMPATIBLE",
+            "VK_ACCELERATION_STRUCTURE_COMPATIBILITY_COMPATIBLE_KHR",
+        ),
+        (
+            Self::INCOMPATIBLE.0,
+            "INCOMPATIBLE",
+            "VK_ACCELERATION_STRUCTURE_COMPATIBILITY_INCOMPATIBLE_KHR",
+        ),
+    ];
+}
+impl FromStr for AccelerationStructureCompatibilityKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("AccelerationStructureCompatibilityKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AccelerationStructureCompatibilityKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AccelerationStructureMemoryRequirementsTypeNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::OBJECT.0,
+            "OBJECT",
+            "VK_ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_TYPE_OBJECT_NV",
+        ),
+        (
+            Self::BUILD_SCRATCH.0,
+            "BUILD_SCRATCH",
+            "VK_ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_TYPE_BUILD_SCRATCH_NV",
+        ),
+        (
+            Self::UPDATE_SCRATCH.0,
+            "UPDATE_SCRATCH",
+            "VK_ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_TYPE_UPDATE_SCRATCH_NV",
+        ),
+    ];
+}
+impl FromStr for AccelerationStructureMemoryRequirementsTypeNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum(
+            "AccelerationStructureMemoryRequirementsTypeNV",
+            Self::KNOWN,
+            s,
+        )
+        .map(Self)
+    }
+}
+impl TryFrom<&str> for AccelerationStructureMemoryRequirementsTypeNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AccelerationStructureMotionInstanceTypeNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::STATIC.0,
+            "STATIC",
+            "VK_ACCELERATION_STRUCTURE_MOTION_INSTANCE_TYPE_STATIC_NV",
+        ),
+        (
+            Self::MATRIX_MOTION.0,
+            "MATRIX_MOTION",
+            "VK_ACCELERATION_STRUCTURE_MOTION_INSTANCE_TYPE_MATRIX_MOTION_NV",
+        ),
+        (
+            Self::SRT_MOTION.0,
+            "SRT_MOTION",
+            "VK_ACCELERATION_STRUCTURE_MOTION_INSTANCE_TYPE_SRT_MOTION_NV",
+        ),
+    ];
+}
+impl FromStr for AccelerationStructureMotionInstanceTypeNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("AccelerationStructureMotionInstanceTypeNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AccelerationStructureMotionInstanceTypeNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AccelerationStructureTypeKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::TOP_LEVEL.0,
+            "TOP_LEVEL",
+            "VK_ACCELERATION_STRUCTURE_TYPE_TOP_LEVEL_KHR",
+        ),
+        (
+            Self::BOTTOM_LEVEL.0,
+            "BOTTOM_LEVEL",
+            "VK_ACCELERATION_STRUCTURE_TYPE_BOTTOM_LEVEL_KHR",
+        ),
+        (
+            Self::GENERIC.0,
+            "GENERIC",
+            "VK_ACCELERATION_STRUCTURE_TYPE_GENERIC_KHR",
+        ),
+        (
+            Self::TOP_LEVEL_NV.0,
+            "TOP_LEVEL_NV",
+            "VK_ACCELERATION_STRUCTURE_TYPE_TOP_LEVEL_NV",
+        ),
+        (
+            Self::BOTTOM_LEVEL_NV.0,
+            "BOTTOM_LEVEL_NV",
+            "VK_ACCELERATION_STRUCTURE_TYPE_BOTTOM_LEVEL_NV",
+        ),
+    ];
+}
+impl FromStr for AccelerationStructureTypeKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("AccelerationStructureTypeKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AccelerationStructureTypeKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AttachmentLoadOp {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::LOAD.0, "LOAD", "VK_ATTACHMENT_LOAD_OP_LOAD"),
+        (Self::CLEAR.0, "CLEAR", "VK_ATTACHMENT_LOAD_OP_CLEAR"),
+        (
+            Self::DONT_CARE.0,
+            "DONT_CARE",
+            "VK_ATTACHMENT_LOAD_OP_DONT_CARE",
+        ),
+        (
+            Self::NONE_EXT.0,
+            "NONE_EXT",
+            "VK_ATTACHMENT_LOAD_OP_NONE_EXT",
+        ),
+    ];
+}
+impl FromStr for AttachmentLoadOp {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("AttachmentLoadOp", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AttachmentLoadOp {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl AttachmentStoreOp {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::STORE.0, "STORE", "VK_ATTACHMENT_STORE_OP_STORE"),
+        (
+            Self::DONT_CARE.0,
+            "DONT_CARE",
+            "VK_ATTACHMENT_STORE_OP_DONT_CARE",
+        ),
+        (Self::NONE.0, "NONE", "VK_ATTACHMENT_STORE_OP_NONE"),
+        (
+            Self::NONE_KHR.0,
+            "NONE_KHR",
+            "VK_ATTACHMENT_STORE_OP_NONE_KHR",
+        ),
+        (
+            Self::NONE_QCOM.0,
+            "NONE_QCOM",
+            "VK_ATTACHMENT_STORE_OP_NONE_QCOM",
+        ),
+        (
+            Self::NONE_EXT.0,
+            "NONE_EXT",
+            "VK_ATTACHMENT_STORE_OP_NONE_EXT",
+        ),
+    ];
+}
+impl FromStr for AttachmentStoreOp {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("AttachmentStoreOp", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for AttachmentStoreOp {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BlendFactor {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::ZERO.0, "ZERO", "VK_BLEND_FACTOR_ZERO"),
+        (Self::ONE.0, "ONE", "VK_BLEND_FACTOR_ONE"),
+        (Self::SRC_COLOR.0, "SRC_COLOR", "VK_BLEND_FACTOR_SRC_COLOR"),
+        (
+            Self::ONE_MINUS_SRC_COLOR.0,
+            "ONE_MINUS_SRC_COLOR",
+            "VK_BLEND_FACTOR_ONE_MINUS_SRC_COLOR",
+        ),
+        (Self::DST_COLOR.0, "DST_COLOR", "VK_BLEND_FACTOR_DST_COLOR"),
+        (
+            Self::ONE_MINUS_DST_COLOR.0,
+            "ONE_MINUS_DST_COLOR",
+            "VK_BLEND_FACTOR_ONE_MINUS_DST_COLOR",
+        ),
+        (Self::SRC_ALPHA.0, "SRC_ALPHA", "VK_BLEND_FACTOR_SRC_ALPHA"),
+        (
+            Self::ONE_MINUS_SRC_ALPHA.0,
+            "ONE_MINUS_SRC_ALPHA",
+            "VK_BLEND_FACTOR_ONE_MINUS_SRC_ALPHA",
+        ),
+        (Self::DST_ALPHA.0, "DST_ALPHA", "VK_BLEND_FACTOR_DST_ALPHA"),
+        (
+            Self::ONE_MINUS_DST_ALPHA.0,
+            "ONE_MINUS_DST_ALPHA",
+            "VK_BLEND_FACTOR_ONE_MINUS_DST_ALPHA",
+        ),
+        (
+            Self::CONSTANT_COLOR.0,
+            "CONSTANT_COLOR",
+            "VK_BLEND_FACTOR_CONSTANT_COLOR",
+        ),
+        (
+            Self::ONE_MINUS_CONSTANT_COLOR.0,
+            "ONE_MINUS_CONSTANT_COLOR",
+            "VK_BLEND_FACTOR_ONE_MINUS_CONSTANT_COLOR",
+        ),
+        (
+            Self::CONSTANT_ALPHA.0,
+            "CONSTANT_ALPHA",
+            "VK_BLEND_FACTOR_CONSTANT_ALPHA",
+        ),
+        (
+            Self::ONE_MINUS_CONSTANT_ALPHA.0,
+            "ONE_MINUS_CONSTANT_ALPHA",
+            "VK_BLEND_FACTOR_ONE_MINUS_CONSTANT_ALPHA",
+        ),
+        (
+            Self::SRC_ALPHA_SATURATE.0,
+            "SRC_ALPHA_SATURATE",
+            "VK_BLEND_FACTOR_SRC_ALPHA_SATURATE",
+        ),
+        (
+            Self::SRC1_COLOR.0,
+            "SRC1_COLOR",
+            "VK_BLEND_FACTOR_SRC1_COLOR",
+        ),
+        (
+            Self::ONE_MINUS_SRC1_COLOR.0,
+            "ONE_MINUS_SRC1_COLOR",
+            "VK_BLEND_FACTOR_ONE_MINUS_SRC1_COLOR",
+        ),
+        (
+            Self::SRC1_ALPHA.0,
+            "SRC1_ALPHA",
+            "VK_BLEND_FACTOR_SRC1_ALPHA",
+        ),
+        (
+            Self::ONE_MINUS_SRC1_ALPHA.0,
+            "ONE_MINUS_SRC1_ALPHA",
+            "VK_BLEND_FACTOR_ONE_MINUS_SRC1_ALPHA",
+        ),
+    ];
+}
+impl FromStr for BlendFactor {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("BlendFactor", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BlendFactor {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BlendOp {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::ADD.0, "ADD", "VK_BLEND_OP_ADD"),
+        (Self::SUBTRACT.0, "SUBTRACT", "VK_BLEND_OP_SUBTRACT"),
+        (
+            Self::REVERSE_SUBTRACT.0,
+            "REVERSE_SUBTRACT",
+            "VK_BLEND_OP_REVERSE_SUBTRACT",
+        ),
+        (Self::MIN.0, "MIN", "VK_BLEND_OP_MIN"),
+        (Self::MAX.0, "MAX", "VK_BLEND_OP_MAX"),
+        (Self::ZERO_EXT.0, "ZERO_EXT", "VK_BLEND_OP_ZERO_EXT"),
+        (Self::SRC_EXT.0, "SRC_EXT", "VK_BLEND_OP_SRC_EXT"),
+        (Self::DST_EXT.0, "DST_EXT", "VK_BLEND_OP_DST_EXT"),
+        (
+            Self::SRC_OVER_EXT.0,
+            "SRC_OVER_EXT",
+            "VK_BLEND_OP_SRC_OVER_EXT",
+        ),
+        (
+            Self::DST_OVER_EXT.0,
+            "DST_OVER_EXT",
+            "VK_BLEND_OP_DST_OVER_EXT",
+        ),
+        (Self::SRC_IN_EXT.0, "SRC_IN_EXT", "VK_BLEND_OP_SRC_IN_EXT"),
+        (Self::DST_IN_EXT.0, "DST_IN_EXT", "VK_BLEND_OP_DST_IN_EXT"),
+        (
+            Self::SRC_OUT_EXT.0,
+            "SRC_OUT_EXT",
+            "VK_BLEND_OP_SRC_OUT_EXT",
+        ),
+        (
+            Self::DST_OUT_EXT.0,
+            "DST_OUT_EXT",
+            "VK_BLEND_OP_DST_OUT_EXT",
+        ),
+        (
+            Self::SRC_ATOP_EXT.0,
+            "SRC_ATOP_EXT",
+            "VK_BLEND_OP_SRC_ATOP_EXT",
+        ),
+        (
+            Self::DST_ATOP_EXT.0,
+            "DST_ATOP_EXT",
+            "VK_BLEND_OP_DST_ATOP_EXT",
+        ),
+        (Self::XOR_EXT.0, "XOR_EXT", "VK_BLEND_OP_XOR_EXT"),
+        (
+            Self::MULTIPLY_EXT.0,
+            "MULTIPLY_EXT",
+            "VK_BLEND_OP_MULTIPLY_EXT",
+        ),
+        (Self::SCREEN_EXT.0, "SCREEN_EXT", "VK_BLEND_OP_SCREEN_EXT"),
+        (
+            Self::OVERLAY_EXT.0,
+            "OVERLAY_EXT",
+            "VK_BLEND_OP_OVERLAY_EXT",
+        ),
+        (Self::DARKEN_EXT.0, "DARKEN_EXT", "VK_BLEND_OP_DARKEN_EXT"),
+        (
+            Self::LIGHTEN_EXT.0,
+            "LIGHTEN_EXT",
+            "VK_BLEND_OP_LIGHTEN_EXT",
+        ),
+        (
+            Self::COLORDODGE_EXT.0,
+            "COLORDODGE_EXT",
+            "VK_BLEND_OP_COLORDODGE_EXT",
+        ),
+        (
+            Self::COLORBURN_EXT.0,
+            "COLORBURN_EXT",
+            "VK_BLEND_OP_COLORBURN_EXT",
+        ),
+        (
+            Self::HARDLIGHT_EXT.0,
+            "HARDLIGHT_EXT",
+            "VK_BLEND_OP_HARDLIGHT_EXT",
+        ),
+        (
+            Self::SOFTLIGHT_EXT.0,
+            "SOFTLIGHT_EXT",
+            "VK_BLEND_OP_SOFTLIGHT_EXT",
+        ),
+        (
+            Self::DIFFERENCE_EXT.0,
+            "DIFFERENCE_EXT",
+            "VK_BLEND_OP_DIFFERENCE_EXT",
+        ),
+        (
+            Self::EXCLUSION_EXT.0,
+            "EXCLUSION_EXT",
+            "VK_BLEND_OP_EXCLUSION_EXT",
+        ),
+        (Self::INVERT_EXT.0, "INVERT_EXT", "VK_BLEND_OP_INVERT_EXT"),
+        (
+            Self::INVERT_RGB_EXT.0,
+            "INVERT_RGB_EXT",
+            "VK_BLEND_OP_INVERT_RGB_EXT",
+        ),
+        (
+            Self::LINEARDODGE_EXT.0,
+            "LINEARDODGE_EXT",
+            "VK_BLEND_OP_LINEARDODGE_EXT",
+        ),
+        (
+            Self::LINEARBURN_EXT.0,
+            "LINEARBURN_EXT",
+            "VK_BLEND_OP_LINEARBURN_EXT",
+        ),
+        (
+            Self::VIVIDLIGHT_EXT.0,
+            "VIVIDLIGHT_EXT",
+            "VK_BLEND_OP_VIVIDLIGHT_EXT",
+        ),
+        (
+            Self::LINEARLIGHT_EXT.0,
+            "LINEARLIGHT_EXT",
+            "VK_BLEND_OP_LINEARLIGHT_EXT",
+        ),
+        (
+            Self::PINLIGHT_EXT.0,
+            "PINLIGHT_EXT",
+            "VK_BLEND_OP_PINLIGHT_EXT",
+        ),
+        (
+            Self::HARDMIX_EXT.0,
+            "HARDMIX_EXT",
+            "VK_BLEND_OP_HARDMIX_EXT",
+        ),
+        (
+            Self::HSL_HUE_EXT.0,
+            "HSL_HUE_EXT",
+            "VK_BLEND_OP_HSL_HUE_EXT",
+        ),
+        (
+            Self::HSL_SATURATION_EXT.0,
+            "HSL_SATURATION_EXT",
+            "VK_BLEND_OP_HSL_SATURATION_EXT",
+        ),
+        (
+            Self::HSL_COLOR_EXT.0,
+            "HSL_COLOR_EXT",
+            "VK_BLEND_OP_HSL_COLOR_EXT",
+        ),
+        (
+            Self::HSL_LUMINOSITY_EXT.0,
+            "HSL_LUMINOSITY_EXT",
+            "VK_BLEND_OP_HSL_LUMINOSITY_EXT",
+        ),
+        (Self::PLUS_EXT.0, "PLUS_EXT", "VK_BLEND_OP_PLUS_EXT"),
+        (
+            Self::PLUS_CLAMPED_EXT.0,
+            "PLUS_CLAMPED_EXT",
+            "VK_BLEND_OP_PLUS_CLAMPED_EXT",
+        ),
+        (
+            Self::PLUS_CLAMPED_ALPHA_EXT.0,
+            "PLUS_CLAMPED_ALPHA_EXT",
+            "VK_BLEND_OP_PLUS_CLAMPED_ALPHA_EXT",
+        ),
+        (
+            Self::PLUS_DARKER_EXT.0,
+            "PLUS_DARKER_EXT",
+            "VK_BLEND_OP_PLUS_DARKER_EXT",
+        ),
+        (Self::MINUS_EXT.0, "MINUS_EXT", "VK_BLEND_OP_MINUS_EXT"),
+        (
+            Self::MINUS_CLAMPED_EXT.0,
+            "MINUS_CLAMPED_EXT",
+            "VK_BLEND_OP_MINUS_CLAMPED_EXT",
+        ),
+        (
+            Self::CONTRAST_EXT.0,
+            "CONTRAST_EXT",
+            "VK_BLEND_OP_CONTRAST_EXT",
+        ),
+        (
+            Self::INVERT_OVG_EXT.0,
+            "INVERT_OVG_EXT",
+            "VK_BLEND_OP_INVERT_OVG_EXT",
+        ),
+        (Self::RED_EXT.0, "RED_EXT", "VK_BLEND_OP_RED_EXT"),
+        (Self::GREEN_EXT.0, "GREEN_EXT", "VK_BLEND_OP_GREEN_EXT"),
+        (Self::BLUE_EXT.0, "BLUE_EXT", "VK_BLEND_OP_BLUE_EXT"),
+    ];
+}
+impl FromStr for BlendOp {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("BlendOp", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BlendOp {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BlendOverlapEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::UNCORRELATED.0,
+            "UNCORRELATED",
+            "VK_BLEND_OVERLAP_UNCORRELATED_EXT",
+        ),
+        (
+            Self::DISJOINT.0,
+            "DISJOINT",
+            "VK_BLEND_OVERLAP_DISJOINT_EXT",
+        ),
+        (
+            Self::CONJOINT.0,
+            "CONJOINT",
+            "VK_BLEND_OVERLAP_CONJOINT_EXT",
+        ),
+    ];
+}
+impl FromStr for BlendOverlapEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("BlendOverlapEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BlendOverlapEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BorderColor {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::FLOAT_TRANSPARENT_BLACK.0,
+            "FLOAT_TRANSPARENT_BLACK",
+            "VK_BORDER_COLOR_FLOAT_TRANSPARENT_BLACK",
+        ),
+        (
+            Self::INT_TRANSPARENT_BLACK.0,
+            "INT_TRANSPARENT_BLACK",
+            "VK_BORDER_COLOR_INT_TRANSPARENT_BLACK",
+        ),
+        (
+            Self::FLOAT_OPAQUE_BLACK.0,
+            "FLOAT_OPAQUE_BLACK",
+            "VK_BORDER_COLOR_FLOAT_OPAQUE_BLACK",
+        ),
+        (
+            Self::INT_OPAQUE_BLACK.0,
+            "INT_OPAQUE_BLACK",
+            "VK_BORDER_COLOR_INT_OPAQUE_BLACK",
+        ),
+        (
+            Self::FLOAT_OPAQUE_WHITE.0,
+            "FLOAT_OPAQUE_WHITE",
+            "VK_BORDER_COLOR_FLOAT_OPAQUE_WHITE",
+        ),
+        (
+            Self::INT_OPAQUE_WHITE.0,
+            "INT_OPAQUE_WHITE",
+            "VK_BORDER_COLOR_INT_OPAQUE_WHITE",
+        ),
+        (
+            Self::FLOAT_CUSTOM_EXT.0,
+            "FLOAT_CUSTOM_EXT",
+            "VK_BORDER_COLOR_FLOAT_CUSTOM_EXT",
+        ),
+        (
+            Self::INT_CUSTOM_EXT.0,
+            "INT_CUSTOM_EXT",
+            "VK_BORDER_COLOR_INT_CUSTOM_EXT",
+        ),
+    ];
+}
+impl FromStr for BorderColor {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("BorderColor", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BorderColor {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BuildAccelerationStructureModeKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::BUILD.0,
+            "BUILD",
+            "VK_BUILD_ACCELERATION_STRUCTURE_MODE_BUILD_KHR",
+        ),
+        (
+            Self::UPDATE.0,
+            "UPDATE",
+            "VK_BUILD_ACCELERATION_STRUCTURE_MODE_UPDATE_KHR",
+        ),
+    ];
+}
+impl FromStr for BuildAccelerationStructureModeKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("BuildAccelerationStructureModeKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BuildAccelerationStructureModeKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl BuildMicromapModeEXT {
+    pub(crate) const KNOWN: Known<i32> =
+        &[(Self::BUILD.0, "BUILD", "VK_BUILD_MICROMAP_MODE_BUILD_EXT")];
+}
+impl FromStr for BuildMicromapModeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("BuildMicromapModeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for BuildMicromapModeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ChromaLocation {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::COSITED_EVEN.0,
+            "COSITED_EVEN",
+            "VK_CHROMA_LOCATION_COSITED_EVEN",
+        ),
+        (Self::MIDPOINT.0, "MIDPOINT", "VK_CHROMA_LOCATION_MIDPOINT"),
+        (
+            Self::COSITED_EVEN_KHR.0,
+            "COSITED_EVEN_KHR",
+            "VK_CHROMA_LOCATION_COSITED_EVEN_KHR",
+        ),
+        (
+            Self::MIDPOINT_KHR.0,
+            "MIDPOINT_KHR",
+            "VK_CHROMA_LOCATION_MIDPOINT_KHR",
+        ),
+    ];
+}
+impl FromStr for ChromaLocation {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ChromaLocation", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ChromaLocation {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CoarseSampleOrderTypeNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::DEFAULT.0,
+            "DEFAULT",
+            "VK_COARSE_SAMPLE_ORDER_TYPE_DEFAULT_NV",
+        ),
+        (
+            Self::CUSTOM.0,
+            "CUSTOM",
+            "VK_COARSE_SAMPLE_ORDER_TYPE_CUSTOM_NV",
+        ),
+        (
+            Self::PIXEL_MAJOR.0,
+            "PIXEL_MAJOR",
+            "VK_COARSE_SAMPLE_ORDER_TYPE_PIXEL_MAJOR_NV",
+        ),
+        (
+            Self::SAMPLE_MAJOR.0,
+            "SAMPLE_MAJOR",
+            "VK_COARSE_SAMPLE_ORDER_TYPE_SAMPLE_MAJOR_NV",
+        ),
+    ];
+}
+impl FromStr for CoarseSampleOrderTypeNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("CoarseSampleOrderTypeNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CoarseSampleOrderTypeNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ColorSpaceKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::SRGB_NONLINEAR.0,
+            "SRGB_NONLINEAR",
+            "VK_COLOR_SPACE_SRGB_NONLINEAR_KHR",
+        ),
+        (
+            Self::DISPLAY_P3_NONLINEAR_EXT.0,
+            "DISPLAY_P3_NONLINEAR_EXT",
+            "VK_COLOR_SPACE_DISPLAY_P3_NONLINEAR_EXT",
+        ),
+        (
+            Self::EXTENDED_SRGB_LINEAR_EXT.0,
+            "EXTENDED_SRGB_LINEAR_EXT",
+            "VK_COLOR_SPACE_EXTENDED_SRGB_LINEAR_EXT",
+        ),
+        (
+            Self::DISPLAY_P3_LINEAR_EXT.0,
+            "DISPLAY_P3_LINEAR_EXT",
+            "VK_COLOR_SPACE_DISPLAY_P3_LINEAR_EXT",
+        ),
+        (
+            Self::DCI_P3_NONLINEAR_EXT.0,
+            "DCI_P3_NONLINEAR_EXT",
+            "VK_COLOR_SPACE_DCI_P3_NONLINEAR_EXT",
+        ),
+        (
+            Self::BT709_LINEAR_EXT.0,
+            "BT709_LINEAR_EXT",
+            "VK_COLOR_SPACE_BT709_LINEAR_EXT",
+        ),
+        (
+            Self::BT709_NONLINEAR_EXT.0,
+            "BT709_NONLINEAR_EXT",
+            "VK_COLOR_SPACE_BT709_NONLINEAR_EXT",
+        ),
+        (
+            Self::BT2020_LINEAR_EXT.0,
+            "BT2020_LINEAR_EXT",
+            "VK_COLOR_SPACE_BT2020_LINEAR_EXT",
+        ),
+        (
+            Self::HDR10_ST2084_EXT.0,
+            "HDR10_ST2084_EXT",
+            "VK_COLOR_SPACE_HDR10_ST2084_EXT",
+        ),
+        (
+            Self::DOLBYVISION_EXT.0,
+            "DOLBYVISION_EXT",
+            "VK_COLOR_SPACE_DOLBYVISION_EXT",
+        ),
+        (
+            Self::HDR10_HLG_EXT.0,
+            "HDR10_HLG_EXT",
+            "VK_COLOR_SPACE_HDR10_HLG_EXT",
+        ),
+        (
+            Self::ADOBERGB_LINEAR_EXT.0,
+            "ADOBERGB_LINEAR_EXT",
+            "VK_COLOR_SPACE_ADOBERGB_LINEAR_EXT",
+        ),
+        (
+            Self::ADOBERGB_NONLINEAR_EXT.0,
+            "ADOBERGB_NONLINEAR_EXT",
+            "VK_COLOR_SPACE_ADOBERGB_NONLINEAR_EXT",
+        ),
+        (
+            Self::PASS_THROUGH_EXT.0,
+            "PASS_THROUGH_EXT",
+            "VK_COLOR_SPACE_PASS_THROUGH_EXT",
+        ),
+        (
+            Self::EXTENDED_SRGB_NONLINEAR_EXT.0,
+            "EXTENDED_SRGB_NONLINEAR_EXT",
+            "VK_COLOR_SPACE_EXTENDED_SRGB_NONLINEAR_EXT",
+        ),
+        (
+            Self::DISPLAY_NATIVE_AMD.0,
+            "DISPLAY_NATIVE_AMD",
+            "VK_COLOR_SPACE_DISPLAY_NATIVE_AMD",
+        ),
+    ];
+}
+impl FromStr for ColorSpaceKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ColorSpaceKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ColorSpaceKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CommandBufferLevel {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::PRIMARY.0,
+            "PRIMARY",
+            "VK_COMMAND_BUFFER_LEVEL_PRIMARY",
+        ),
+        (
+            Self::SECONDARY.0,
+            "SECONDARY",
+            "VK_COMMAND_BUFFER_LEVEL_SECONDARY",
+        ),
+    ];
+}
+impl FromStr for CommandBufferLevel {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("CommandBufferLevel", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CommandBufferLevel {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CompareOp {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::NEVER.0, "NEVER", "VK_COMPARE_OP_NEVER"),
+        (Self::LESS.0, "LESS", "VK_COMPARE_OP_LESS"),
+        (Self::EQUAL.0, "EQUAL", "VK_COMPARE_OP_EQUAL"),
+        (
+            Self::LESS_OR_EQUAL.0,
+            "LESS_OR_EQUAL",
+            "VK_COMPARE_OP_LESS_OR_EQUAL",
+        ),
+        (Self::GREATER.0, "GREATER", "VK_COMPARE_OP_GREATER"),
+        (Self::NOT_EQUAL.0, "NOT_EQUAL", "VK_COMPARE_OP_NOT_EQUAL"),
+        (
+            Self::GREATER_OR_EQUAL.0,
+            "GREATER_OR_EQUAL",
+            "VK_COMPARE_OP_GREATER_OR_EQUAL",
+        ),
+        (Self::ALWAYS.0, "ALWAYS", "VK_COMPARE_OP_ALWAYS"),
+    ];
+}
+impl FromStr for CompareOp {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("CompareOp", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CompareOp {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ComponentSwizzle {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::IDENTITY.0,
+            "IDENTITY",
+            "VK_COMPONENT_SWIZZLE_IDENTITY",
+        ),
+        (Self::ZERO.0, "ZERO", "VK_COMPONENT_SWIZZLE_ZERO"),
+        (Self::ONE.0, "ONE", "VK_COMPONENT_SWIZZLE_ONE"),
+        (Self::R.0, "R", "VK_COMPONENT_SWIZZLE_R"),
+        (Self::G.0, "G", "VK_COMPONENT_SWIZZLE_G"),
+        (Self::B.0, "B", "VK_COMPONENT_SWIZZLE_B"),
+        (Self::A.0, "A", "VK_COMPONENT_SWIZZLE_A"),
+    ];
+}
+impl FromStr for ComponentSwizzle {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ComponentSwizzle", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ComponentSwizzle {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ComponentTypeNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::FLOAT16.0, "FLOAT16", "VK_COMPONENT_TYPE_FLOAT16_NV"),
+        (Self::FLOAT32.0, "FLOAT32", "VK_COMPONENT_TYPE_FLOAT32_NV"),
+        (Self::FLOAT64.0, "FLOAT64", "VK_COMPONENT_TYPE_FLOAT64_NV"),
+        (Self::SINT8.0, "SINT8", "VK_COMPONENT_TYPE_SINT8_NV"),
+        (Self::SINT16.0, "SINT16", "VK_COMPONENT_TYPE_SINT16_NV"),
+        (Self::SINT32.0, "SINT32", "VK_COMPONENT_TYPE_SINT32_NV"),
+        (Self::SINT64.0, "SINT64", "VK_COMPONENT_TYPE_SINT64_NV"),
+        (Self::UINT8.0, "UINT8", "VK_COMPONENT_TYPE_UINT8_NV"),
+        (Self::UINT16.0, "UINT16", "VK_COMPONENT_TYPE_UINT16_NV"),
+        (Self::UINT32.0, "UINT32", "VK_COMPONENT_TYPE_UINT32_NV"),
+        (Self::UINT64.0, "UINT64", "VK_COMPONENT_TYPE_UINT64_NV"),
+    ];
+}
+impl FromStr for ComponentTypeNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ComponentTypeNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ComponentTypeNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ConservativeRasterizationModeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::DISABLED.0,
+            "DISABLED",
+            "VK_CONSERVATIVE_RASTERIZATION_MODE_DISABLED_EXT",
+        ),
+        (
+            Self::OVERESTIMATE.0,
+            "OVERESTIMATE",
+            "VK_CONSERVATIVE_RASTERIZATION_MODE_OVERESTIMATE_EXT",
+        ),
+        (
+            Self::UNDERESTIMATE.0,
+            "UNDERESTIMATE",
+            "VK_CONSERVATIVE_RASTERIZATION_MODE_UNDERESTIMATE_EXT",
+        ),
+    ];
+}
+impl FromStr for ConservativeRasterizationModeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ConservativeRasterizationModeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ConservativeRasterizationModeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CopyAccelerationStructureModeKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::CLONE.0,
+            "CLONE",
+            "VK_COPY_ACCELERATION_STRUCTURE_MODE_CLONE_KHR",
+        ),
+        (
+            Self::COMPACT.0,
+            "COMPACT",
+            "VK_COPY_ACCELERATION_STRUCTURE_MODE_COMPACT_KHR",
+        ),
+        (
+            Self::SERIALIZE.0,
+            "SERIALIZE",
+            "VK_COPY_ACCELERATION_STRUCTURE_MODE_SERIALIZE_KHR",
+        ),
+        (
+            Self::DESERIALIZE.0,
+            "DESERIALIZE",
+            "VK_COPY_ACCELERATION_STRUCTURE_MODE_DESERIALIZE_KHR",
+        ),
+        (
+            Self::CLONE_NV.0,
+            "CLONE_NV",
+            "VK_COPY_ACCELERATION_STRUCTURE_MODE_CLONE_NV",
+        ),
+        (
+            Self::COMPACT_NV.0,
+            "COMPACT_NV",
+            "VK_COPY_ACCELERATION_STRUCTURE_MODE_COMPACT_NV",
+        ),
+    ];
+}
+impl FromStr for CopyAccelerationStructureModeKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("CopyAccelerationStructureModeKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CopyAccelerationStructureModeKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CopyMicromapModeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::CLONE.0, "CLONE", "VK_COPY_MICROMAP_MODE_CLONE_EXT"),
+        (
+            Self::SERIALIZE.0,
+            "SERIALIZE",
+            "VK_COPY_MICROMAP_MODE_SERIALIZE_EXT",
+        ),
+        (
+            Self::DESERIALIZE.0,
+            "DESERIALIZE",
+            "VK_COPY_MICROMAP_MODE_DESERIALIZE_EXT",
+        ),
+        (
+            Self::COMPACT.0,
+            "COMPACT",
+            "VK_COPY_MICROMAP_MODE_COMPACT_EXT",
+        ),
+    ];
+}
+impl FromStr for CopyMicromapModeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("CopyMicromapModeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CopyMicromapModeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CoverageModulationModeNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::NONE.0, "NONE", "VK_COVERAGE_MODULATION_MODE_NONE_NV"),
+        (Self::RGB.0, "RGB", "VK_COVERAGE_MODULATION_MODE_RGB_NV"),
+        (
+            Self::ALPHA.0,
+            "ALPHA",
+            "VK_COVERAGE_MODULATION_MODE_ALPHA_NV",
+        ),
+        (Self::RGBA.0, "RGBA", "VK_COVERAGE_MODULATION_MODE_RGBA_NV"),
+    ];
+}
+impl FromStr for CoverageModulationModeNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("CoverageModulationModeNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CoverageModulationModeNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl CoverageReductionModeNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::MERGE.0,
+            "MERGE",
+            "VK_COVERAGE_REDUCTION_MODE_MERGE_NV",
+        ),
+        (
+            Self::TRUNCATE.0,
+            "TRUNCATE",
+            "VK_COVERAGE_REDUCTION_MODE_TRUNCATE_NV",
+        ),
+    ];
+}
+impl FromStr for CoverageReductionModeNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("CoverageReductionModeNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for CoverageReductionModeNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DebugReportObjectTypeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::UNKNOWN.0,
+            "UNKNOWN",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_UNKNOWN_EXT",
+        ),
+        (
+            Self::INSTANCE.0,
+            "INSTANCE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_INSTANCE_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE.0,
+            "PHYSICAL_DEVICE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_PHYSICAL_DEVICE_EXT",
+        ),
+        (
+            Self::DEVICE.0,
+            "DEVICE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT",
+        ),
+        (
+            Self::QUEUE.0,
+            "QUEUE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_QUEUE_EXT",
+        ),
+        (
+            Self::SEMAPHORE.0,
+            "SEMAPHORE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_SEMAPHORE_EXT",
+        ),
+        (
+            Self::COMMAND_BUFFER.0,
+            "COMMAND_BUFFER",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT",
+        ),
+        (
+            Self::FENCE.0,
+            "FENCE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_FENCE_EXT",
+        ),
+        (
+            Self::DEVICE_MEMORY.0,
+            "DEVICE_MEMORY",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_MEMORY_EXT",
+        ),
+        (
+            Self::BUFFER.0,
+            "BUFFER",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_EXT",
+        ),
+        (
+            Self::IMAGE.0,
+            "IMAGE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT",
+        ),
+        (
+            Self::EVENT.0,
+            "EVENT",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_EVENT_EXT",
+        ),
+        (
+            Self::QUERY_POOL.0,
+            "QUERY_POOL",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_QUERY_POOL_EXT",
+        ),
+        (
+            Self::BUFFER_VIEW.0,
+            "BUFFER_VIEW",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_VIEW_EXT",
+        ),
+        (
+            Self::IMAGE_VIEW.0,
+            "IMAGE_VIEW",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_VIEW_EXT",
+        ),
+        (
+            Self::SHADER_MODULE.0,
+            "SHADER_MODULE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_SHADER_MODULE_EXT",
+        ),
+        (
+            Self::PIPELINE_CACHE.0,
+            "PIPELINE_CACHE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_CACHE_EXT",
+        ),
+        (
+            Self::PIPELINE_LAYOUT.0,
+            "PIPELINE_LAYOUT",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_LAYOUT_EXT",
+        ),
+        (
+            Self::RENDER_PASS.0,
+            "RENDER_PASS",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_RENDER_PASS_EXT",
+        ),
+        (
+            Self::PIPELINE.0,
+            "PIPELINE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_PIPELINE_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_SET_LAYOUT.0,
+            "DESCRIPTOR_SET_LAYOUT",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_SET_LAYOUT_EXT",
+        ),
+        (
+            Self::SAMPLER.0,
+            "SAMPLER",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_SAMPLER_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_POOL.0,
+            "DESCRIPTOR_POOL",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_POOL_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_SET.0,
+            "DESCRIPTOR_SET",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_SET_EXT",
+        ),
+        (
+            Self::FRAMEBUFFER.0,
+            "FRAMEBUFFER",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_FRAMEBUFFER_EXT",
+        ),
+        (
+            Self::COMMAND_POOL.0,
+            "COMMAND_POOL",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_POOL_EXT",
+        ),
+        (
+            Self::SURFACE_KHR.0,
+            "SURFACE_KHR",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_SURFACE_KHR_EXT",
+        ),
+        (
+            Self::SWAPCHAIN_KHR.0,
+            "SWAPCHAIN_KHR",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_SWAPCHAIN_KHR_EXT",
+        ),
+        (
+            Self::DEBUG_REPORT_CALLBACK_EXT.0,
+            "DEBUG_REPORT_CALLBACK_EXT",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_DEBUG_REPORT_CALLBACK_EXT_EXT",
+        ),
+        (
+            Self::DISPLAY_KHR.0,
+            "DISPLAY_KHR",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_DISPLAY_KHR_EXT",
+        ),
+        (
+            Self::DISPLAY_MODE_KHR.0,
+            "DISPLAY_MODE_KHR",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_DISPLAY_MODE_KHR_EXT",
+        ),
+        (
+            Self::VALIDATION_CACHE_EXT.0,
+            "VALIDATION_CACHE_EXT",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_VALIDATION_CACHE_EXT_EXT",
+        ),
+        (
+            Self::SAMPLER_YCBCR_CONVERSION.0,
+            "SAMPLER_YCBCR_CONVERSION",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_SAMPLER_YCBCR_CONVERSION_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_UPDATE_TEMPLATE.0,
+            "DESCRIPTOR_UPDATE_TEMPLATE",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_EXT",
+        ),
+        (
+            Self::CU_MODULE_NVX.0,
+            "CU_MODULE_NVX",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_CU_MODULE_NVX_EXT",
+        ),
+        (
+            Self::CU_FUNCTION_NVX.0,
+            "CU_FUNCTION_NVX",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_CU_FUNCTION_NVX_EXT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_KHR.0,
+            "ACCELERATION_STRUCTURE_KHR",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_ACCELERATION_STRUCTURE_KHR_EXT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_NV.0,
+            "ACCELERATION_STRUCTURE_NV",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_ACCELERATION_STRUCTURE_NV_EXT",
+        ),
+        (
+            Self::BUFFER_COLLECTION_FUCHSIA.0,
+            "BUFFER_COLLECTION_FUCHSIA",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_COLLECTION_FUCHSIA_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_UPDATE_TEMPLATE_KHR.0,
+            "DESCRIPTOR_UPDATE_TEMPLATE_KHR",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_KHR_EXT",
+        ),
+        (
+            Self::SAMPLER_YCBCR_CONVERSION_KHR.0,
+            "SAMPLER_YCBCR_CONVERSION_KHR",
+            "VK_DEBUG_REPORT_OBJECT_TYPE_SAMPLER_YCBCR_CONVERSION_KHR_EXT",
+        ),
+    ];
+}
+impl FromStr for DebugReportObjectTypeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DebugReportObjectTypeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DebugReportObjectTypeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DepthBiasRepresentationEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::LEAST_REPRESENTABLE_VALUE_FORMAT.0,
+            "LEAST_REPRESENTABLE_VALUE_FORMAT",
+            "VK_DEPTH_BIAS_REPRESENTATION_LEAST_REPRESENTABLE_VALUE_FORMAT_EXT",
+        ),
+        (
+            Self::LEAST_REPRESENTABLE_VALUE_FORCE_UNORM.0,
+            "LEAST_REPRESENTABLE_VALUE_FORCE_UNORM",
+            "VK_DEPTH_BIAS_REPRESENTATION_LEAST_REPRESENTABLE_VALUE_FORCE_UNORM_EXT",
+        ),
+        (
+            Self::FLOAT.0,
+            "FLOAT",
+            "VK_DEPTH_BIAS_REPRESENTATION_FLOAT_EXT",
+        ),
+    ];
+}
+impl FromStr for DepthBiasRepresentationEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DepthBiasRepresentationEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DepthBiasRepresentationEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DescriptorType {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::SAMPLER.0, "SAMPLER", "VK_DESCRIPTOR_TYPE_SAMPLER"),
+        (
+            Self::COMBINED_IMAGE_SAMPLER.0,
+            "COMBINED_IMAGE_SAMPLER",
+            "VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER",
+        ),
+        (
+            Self::SAMPLED_IMAGE.0,
+            "SAMPLED_IMAGE",
+            "VK_DESCRIPTOR_TYPE_SAMPLED_IMAGE",
+        ),
+        (
+            Self::STORAGE_IMAGE.0,
+            "STORAGE_IMAGE",
+            "VK_DESCRIPTOR_TYPE_STORAGE_IMAGE",
+        ),
+        (
+            Self::UNIFORM_TEXEL_BUFFER.0,
+            "UNIFORM_TEXEL_BUFFER",
+            "VK_DESCRIPTOR_TYPE_UNIFORM_TEXEL_BUFFER",
+        ),
+        (
+            Self::STORAGE_TEXEL_BUFFER.0,
+            "STORAGE_TEXEL_BUFFER",
+            "VK_DESCRIPTOR_TYPE_STORAGE_TEXEL_BUFFER",
+        ),
+        (
+            Self::UNIFORM_BUFFER.0,
+            "UNIFORM_BUFFER",
+            "VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER",
+        ),
+        (
+            Self::STORAGE_BUFFER.0,
+            "STORAGE_BUFFER",
+            "VK_DESCRIPTOR_TYPE_STORAGE_BUFFER",
+        ),
+        (
+            Self::UNIFORM_BUFFER_DYNAMIC.0,
+            "UNIFORM_BUFFER_DYNAMIC",
+            "VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC",
+        ),
+        (
+            Self::STORAGE_BUFFER_DYNAMIC.0,
+            "STORAGE_BUFFER_DYNAMIC",
+            "VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC",
+        ),
+        (
+            Self::INPUT_ATTACHMENT.0,
+            "INPUT_ATTACHMENT",
+            "VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_KHR.0,
+            "ACCELERATION_STRUCTURE_KHR",
+            "VK_DESCRIPTOR_TYPE_ACCELERATION_STRUCTURE_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_NV.0,
+            "ACCELERATION_STRUCTURE_NV",
+            "VK_DESCRIPTOR_TYPE_ACCELERATION_STRUCTURE_NV",
+        ),
+        (
+            Self::SAMPLE_WEIGHT_IMAGE_QCOM.0,
+            "SAMPLE_WEIGHT_IMAGE_QCOM",
+            "VK_DESCRIPTOR_TYPE_SAMPLE_WEIGHT_IMAGE_QCOM",
+        ),
+        (
+            Self::BLOCK_MATCH_IMAGE_QCOM.0,
+            "BLOCK_MATCH_IMAGE_QCOM",
+            "VK_DESCRIPTOR_TYPE_BLOCK_MATCH_IMAGE_QCOM",
+        ),
+        (
+            Self::MUTABLE_EXT.0,
+            "MUTABLE_EXT",
+            "VK_DESCRIPTOR_TYPE_MUTABLE_EXT",
+        ),
+        (
+            Self::INLINE_UNIFORM_BLOCK.0,
+            "INLINE_UNIFORM_BLOCK",
+            "VK_DESCRIPTOR_TYPE_INLINE_UNIFORM_BLOCK",
+        ),
+        (
+            Self::INLINE_UNIFORM_BLOCK_EXT.0,
+            "INLINE_UNIFORM_BLOCK_EXT",
+            "VK_DESCRIPTOR_TYPE_INLINE_UNIFORM_BLOCK_EXT",
+        ),
+        (
+            Self::MUTABLE_VALVE.0,
+            "MUTABLE_VALVE",
+            "VK_DESCRIPTOR_TYPE_MUTABLE_VALVE",
+        ),
+    ];
+}
+impl FromStr for DescriptorType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DescriptorType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DescriptorType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DescriptorUpdateTemplateType {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::DESCRIPTOR_SET.0,
+            "DESCRIPTOR_SET",
+            "VK_DESCRIPTOR_UPDATE_TEMPLATE_TYPE_DESCRIPTOR_SET",
+        ),
+        (
+            Self::PUSH_DESCRIPTORS_KHR.0,
+            "PUSH_DESCRIPTORS_KHR",
+            "VK_DESCRIPTOR_UPDATE_TEMPLATE_TYPE_PUSH_DESCRIPTORS_KHR",
+        ),
+        (
+            Self::DESCRIPTOR_SET_KHR.0,
+            "DESCRIPTOR_SET_KHR",
+            "VK_DESCRIPTOR_UPDATE_TEMPLATE_TYPE_DESCRIPTOR_SET_KHR",
+        ),
+    ];
+}
+impl FromStr for DescriptorUpdateTemplateType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DescriptorUpdateTemplateType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DescriptorUpdateTemplateType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceAddressBindingTypeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::BIND.0,
+            "BIND",
+            "VK_DEVICE_ADDRESS_BINDING_TYPE_BIND_EXT",
+        ),
+        (
+            Self::UNBIND.0,
+            "UNBIND",
+            "VK_DEVICE_ADDRESS_BINDING_TYPE_UNBIND_EXT",
+        ),
+    ];
+}
+impl FromStr for DeviceAddressBindingTypeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DeviceAddressBindingTypeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceAddressBindingTypeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceEventTypeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[(
+        Self::DISPLAY_HOTPLUG.0,
+        "DISPLAY_HOTPLUG",
+        "VK_DEVICE_EVENT_TYPE_DISPLAY_HOTPLUG_EXT",
+    )];
+}
+impl FromStr for DeviceEventTypeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DeviceEventTypeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceEventTypeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceFaultAddressTypeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::NONE.0,
+            "NONE",
+            "VK_DEVICE_FAULT_ADDRESS_TYPE_NONE_EXT",
+        ),
+        (
+            Self::READ_INVALID.0,
+            "READ_INVALID",
+            "VK_DEVICE_FAULT_ADDRESS_TYPE_READ_INVALID_EXT",
+        ),
+        (
+            Self::WRITE_INVALID.0,
+            "WRITE_INVALID",
+            "VK_DEVICE_FAULT_ADDRESS_TYPE_WRITE_INVALID_EXT",
+        ),
+        (
+            Self::EXECUTE_INVALID.0,
+            "EXECUTE_INVALID",
+            "VK_DEVICE_FAULT_ADDRESS_TYPE_EXECUTE_INVALID_EXT",
+        ),
+        (
+            Self::INSTRUCTION_POINTER_UNKNOWN.0,
+            "INSTRUCTION_POINTER_UNKNOWN",
+            "VK_DEVICE_FAULT_ADDRESS_TYPE_INSTRUCTION_POINTER_UNKNOWN_EXT",
+        ),
+        (
+            Self::INSTRUCTION_POINTER_INVALID.0,
+            "INSTRUCTION_POINTER_INVALID",
+            "VK_DEVICE_FAULT_ADDRESS_TYPE_INSTRUCTION_POINTER_INVALID_EXT",
+        ),
+        (
+            Self::INSTRUCTION_POINTER_FAULT.0,
+            "INSTRUCTION_POINTER_FAULT",
+            "VK_DEVICE_FAULT_ADDRESS_TYPE_INSTRUCTION_POINTER_FAULT_EXT",
+        ),
+    ];
+}
+impl FromStr for DeviceFaultAddressTypeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DeviceFaultAddressTypeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceFaultAddressTypeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceFaultVendorBinaryHeaderVersionEXT {
+    pub(crate) const KNOWN: Known<i32> = &[(
+        Self::ONE.0,
+        "ONE",
+        "VK_DEVICE_FAULT_VENDOR_BINARY_HEADER_VERSION_ONE_EXT",
+    )];
+}
+impl FromStr for DeviceFaultVendorBinaryHeaderVersionEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DeviceFaultVendorBinaryHeaderVersionEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceFaultVendorBinaryHeaderVersionEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DeviceMemoryReportEventTypeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::ALLOCATE.0,
+            "ALLOCATE",
+            "VK_DEVICE_MEMORY_REPORT_EVENT_TYPE_ALLOCATE_EXT",
+        ),
+        (
+            Self::FREE.0,
+            "FREE",
+            "VK_DEVICE_MEMORY_REPORT_EVENT_TYPE_FREE_EXT",
+        ),
+        (
+            Self::IMPORT.0,
+            "IMPORT",
+            "VK_DEVICE_MEMORY_REPORT_EVENT_TYPE_IMPORT_EXT",
+        ),
+        (
+            Self::UNIMPORT.0,
+            "UNIMPORT",
+            "VK_DEVICE_MEMORY_REPORT_EVENT_TYPE_UNIMPORT_EXT",
+        ),
+        (
+            Self::ALLOCATION_FAILED.0,
+            "ALLOCATION_FAILED",
+            "VK_DEVICE_MEMORY_REPORT_EVENT_TYPE_ALLOCATION_FAILED_EXT",
+        ),
+    ];
+}
+impl FromStr for DeviceMemoryReportEventTypeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DeviceMemoryReportEventTypeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DeviceMemoryReportEventTypeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DirectDriverLoadingModeLUNARG {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::EXCLUSIVE.0,
+            "EXCLUSIVE",
+            "VK_DIRECT_DRIVER_LOADING_MODE_EXCLUSIVE_LUNARG",
+        ),
+        (
+            Self::INCLUSIVE.0,
+            "INCLUSIVE",
+            "VK_DIRECT_DRIVER_LOADING_MODE_INCLUSIVE_LUNARG",
+        ),
+    ];
+}
+impl FromStr for DirectDriverLoadingModeLUNARG {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DirectDriverLoadingModeLUNARG", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DirectDriverLoadingModeLUNARG {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DiscardRectangleModeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::INCLUSIVE.0,
+            "INCLUSIVE",
+            "VK_DISCARD_RECTANGLE_MODE_INCLUSIVE_EXT",
+        ),
+        (
+            Self::EXCLUSIVE.0,
+            "EXCLUSIVE",
+            "VK_DISCARD_RECTANGLE_MODE_EXCLUSIVE_EXT",
+        ),
+    ];
+}
+impl FromStr for DiscardRectangleModeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DiscardRectangleModeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DiscardRectangleModeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DisplacementMicromapFormatNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::TYPE_64_TRIANGLES_64_BYTES.0,
+            "TYPE_64_TRIANGLES_64_BYTES",
+            "VK_DISPLACEMENT_MICROMAP_FORMAT_64_TRIANGLES_64_BYTES_NV",
+        ),
+        (
+            Self::TYPE_256_TRIANGLES_128_BYTES.0,
+            "TYPE_256_TRIANGLES_128_BYTES",
+            "VK_DISPLACEMENT_MICROMAP_FORMAT_256_TRIANGLES_128_BYTES_NV",
+        ),
+        (
+            Self::TYPE_1024_TRIANGLES_128_BYTES.0,
+            "TYPE_1024_TRIANGLES_128_BYTES",
+            "VK_DISPLACEMENT_MICROMAP_FORMAT_1024_TRIANGLES_128_BYTES_NV",
+        ),
+    ];
+}
+impl FromStr for DisplacementMicromapFormatNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DisplacementMicromapFormatNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DisplacementMicromapFormatNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DisplayEventTypeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[(
+        Self::FIRST_PIXEL_OUT.0,
+        "FIRST_PIXEL_OUT",
+        "VK_DISPLAY_EVENT_TYPE_FIRST_PIXEL_OUT_EXT",
+    )];
+}
+impl FromStr for DisplayEventTypeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DisplayEventTypeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DisplayEventTypeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DisplayPowerStateEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::OFF.0, "OFF", "VK_DISPLAY_POWER_STATE_OFF_EXT"),
+        (
+            Self::SUSPEND.0,
+            "SUSPEND",
+            "VK_DISPLAY_POWER_STATE_SUSPEND_EXT",
+        ),
+        (Self::ON.0, "ON", "VK_DISPLAY_POWER_STATE_ON_EXT"),
+    ];
+}
+impl FromStr for DisplayPowerStateEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DisplayPowerStateEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DisplayPowerStateEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DriverId {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::AMD_PROPRIETARY.0,
+            "AMD_PROPRIETARY",
+            "VK_DRIVER_ID_AMD_PROPRIETARY",
+        ),
+        (
+            Self::AMD_OPEN_SOURCE.0,
+            "AMD_OPEN_SOURCE",
+            "VK_DRIVER_ID_AMD_OPEN_SOURCE",
+        ),
+        (Self::MESA_RADV.0, "MESA_RADV", "VK_DRIVER_ID_MESA_RADV"),
+        (
+            Self::NVIDIA_PROPRIETARY.0,
+            "NVIDIA_PROPRIETARY",
+            "VK_DRIVER_ID_NVIDIA_PROPRIETARY",
+        ),
+        (
+            Self::INTEL_PROPRIETARY_WINDOWS.0,
+            "INTEL_PROPRIETARY_WINDOWS",
+            "VK_DRIVER_ID_INTEL_PROPRIETARY_WINDOWS",
+        ),
+        (
+            Self::INTEL_OPEN_SOURCE_MESA.0,
+            "INTEL_OPEN_SOURCE_MESA",
+            "VK_DRIVER_ID_INTEL_OPEN_SOURCE_MESA",
+        ),
+        (
+            Self::IMAGINATION_PROPRIETARY.0,
+            "IMAGINATION_PROPRIETARY",
+            "VK_DRIVER_ID_IMAGINATION_PROPRIETARY",
+        ),
+        (
+            Self::QUALCOMM_PROPRIETARY.0,
+            "QUALCOMM_PROPRIETARY",
+            "VK_DRIVER_ID_QUALCOMM_PROPRIETARY",
+        ),
+        (
+            Self::ARM_PROPRIETARY.0,
+            "ARM_PROPRIETARY",
+            "VK_DRIVER_ID_ARM_PROPRIETARY",
+        ),
+        (
+            Self::GOOGLE_SWIFTSHADER.0,
+            "GOOGLE_SWIFTSHADER",
+            "VK_DRIVER_ID_GOOGLE_SWIFTSHADER",
+        ),
+        (
+            Self::GGP_PROPRIETARY.0,
+            "GGP_PROPRIETARY",
+            "VK_DRIVER_ID_GGP_PROPRIETARY",
+        ),
+        (
+            Self::BROADCOM_PROPRIETARY.0,
+            "BROADCOM_PROPRIETARY",
+            "VK_DRIVER_ID_BROADCOM_PROPRIETARY",
+        ),
+        (
+            Self::MESA_LLVMPIPE.0,
+            "MESA_LLVMPIPE",
+            "VK_DRIVER_ID_MESA_LLVMPIPE",
+        ),
+        (Self::MOLTENVK.0, "MOLTENVK", "VK_DRIVER_ID_MOLTENVK"),
+        (
+            Self::COREAVI_PROPRIETARY.0,
+            "COREAVI_PROPRIETARY",
+            "VK_DRIVER_ID_COREAVI_PROPRIETARY",
+        ),
+        (
+            Self::JUICE_PROPRIETARY.0,
+            "JUICE_PROPRIETARY",
+            "VK_DRIVER_ID_JUICE_PROPRIETARY",
+        ),
+        (
+            Self::VERISILICON_PROPRIETARY.0,
+            "VERISILICON_PROPRIETARY",
+            "VK_DRIVER_ID_VERISILICON_PROPRIETARY",
+        ),
+        (
+            Self::MESA_TURNIP.0,
+            "MESA_TURNIP",
+            "VK_DRIVER_ID_MESA_TURNIP",
+        ),
+        (Self::MESA_V3DV.0, "MESA_V3DV", "VK_DRIVER_ID_MESA_V3DV"),
+        (Self::MESA_PANVK.0, "MESA_PANVK", "VK_DRIVER_ID_MESA_PANVK"),
+        (
+            Self::SAMSUNG_PROPRIETARY.0,
+            "SAMSUNG_PROPRIETARY",
+            "VK_DRIVER_ID_SAMSUNG_PROPRIETARY",
+        ),
+        (Self::MESA_VENUS.0, "MESA_VENUS", "VK_DRIVER_ID_MESA_VENUS"),
+        (Self::MESA_DOZEN.0, "MESA_DOZEN", "VK_DRIVER_ID_MESA_DOZEN"),
+        (Self::MESA_NVK.0, "MESA_NVK", "VK_DRIVER_ID_MESA_NVK"),
+        (
+            Self::IMAGINATION_OPEN_SOURCE_MESA.0,
+            "IMAGINATION_OPEN_SOURCE_MESA",
+            "VK_DRIVER_ID_IMAGINATION_OPEN_SOURCE_MESA",
+        ),
+        (
+            Self::AMD_PROPRIETARY_KHR.0,
+            "AMD_PROPRIETARY_KHR",
+            "VK_DRIVER_ID_AMD_PROPRIETARY_KHR",
+        ),
+        (
+            Self::AMD_OPEN_SOURCE_KHR.0,
+            "AMD_OPEN_SOURCE_KHR",
+            "VK_DRIVER_ID_AMD_OPEN_SOURCE_KHR",
+        ),
+        (
+            Self::MESA_RADV_KHR.0,
+            "MESA_RADV_KHR",
+            "VK_DRIVER_ID_MESA_RADV_KHR",
+        ),
+        (
+            Self::NVIDIA_PROPRIETARY_KHR.0,
+            "NVIDIA_PROPRIETARY_KHR",
+            "VK_DRIVER_ID_NVIDIA_PROPRIETARY_KHR",
+        ),
+        (
+            Self::INTEL_PROPRIETARY_WINDOWS_KHR.0,
+            "INTEL_PROPRIETARY_WINDOWS_KHR",
+            "VK_DRIVER_ID_INTEL_PROPRIETARY_WINDOWS_KHR",
+        ),
+        (
+            Self::INTEL_OPEN_SOURCE_MESA_KHR.0,
+            "INTEL_OPEN_SOURCE_MESA_KHR",
+            "VK_DRIVER_ID_INTEL_OPEN_SOURCE_MESA_KHR",
+        ),
+        (
+            Self::IMAGINATION_PROPRIETARY_KHR.0,
+            "IMAGINATION_PROPRIETARY_KHR",
+            "VK_DRIVER_ID_IMAGINATION_PROPRIETARY_KHR",
+        ),
+        (
+            Self::QUALCOMM_PROPRIETARY_KHR.0,
+            "QUALCOMM_PROPRIETARY_KHR",
+            "VK_DRIVER_ID_QUALCOMM_PROPRIETARY_KHR",
+        ),
+        (
+            Self::ARM_PROPRIETARY_KHR.0,
+            "ARM_PROPRIETARY_KHR",
+            "VK_DRIVER_ID_ARM_PROPRIETARY_KHR",
+        ),
+        (
+            Self::GOOGLE_SWIFTSHADER_KHR.0,
+            "GOOGLE_SWIFTSHADER_KHR",
+            "VK_DRIVER_ID_GOOGLE_SWIFTSHADER_KHR",
+        ),
+        (
+            Self::GGP_PROPRIETARY_KHR.0,
+            "GGP_PROPRIETARY_KHR",
+            "VK_DRIVER_ID_GGP_PROPRIETARY_KHR",
+        ),
+        (
+            Self::BROADCOM_PROPRIETARY_KHR.0,
+            "BROADCOM_PROPRIETARY_KHR",
+            "VK_DRIVER_ID_BROADCOM_PROPRIETARY_KHR",
+        ),
+    ];
+}
+impl FromStr for DriverId {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DriverId", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DriverId {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl DynamicState {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::VIEWPORT.0, "VIEWPORT", "VK_DYNAMIC_STATE_VIEWPORT"),
+        (Self::SCISSOR.0, "SCISSOR", "VK_DYNAMIC_STATE_SCISSOR"),
+        (
+            Self::LINE_WIDTH.0,
+            "LINE_WIDTH",
+            "VK_DYNAMIC_STATE_LINE_WIDTH",
+        ),
+        (
+            Self::DEPTH_BIAS.0,
+            "DEPTH_BIAS",
+            "VK_DYNAMIC_STATE_DEPTH_BIAS",
+        ),
+        (
+            Self::BLEND_CONSTANTS.0,
+            "BLEND_CONSTANTS",
+            "VK_DYNAMIC_STATE_BLEND_CONSTANTS",
+        ),
+        (
+            Self::DEPTH_BOUNDS.0,
+            "DEPTH_BOUNDS",
+            "VK_DYNAMIC_STATE_DEPTH_BOUNDS",
+        ),
+        (
+            Self::STENCIL_COMPARE_MASK.0,
+            "STENCIL_COMPARE_MASK",
+            "VK_DYNAMIC_STATE_STENCIL_COMPARE_MASK",
+        ),
+        (
+            Self::STENCIL_WRITE_MASK.0,
+            "STENCIL_WRITE_MASK",
+            "VK_DYNAMIC_STATE_STENCIL_WRITE_MASK",
+        ),
+        (
+            Self::STENCIL_REFERENCE.0,
+            "STENCIL_REFERENCE",
+            "VK_DYNAMIC_STATE_STENCIL_REFERENCE",
+        ),
+        (
+            Self::VIEWPORT_W_SCALING_NV.0,
+            "VIEWPORT_W_SCALING_NV",
+            "VK_DYNAMIC_STATE_VIEWPORT_W_SCALING_NV",
+        ),
+        (
+            Self::DISCARD_RECTANGLE_EXT.0,
+            "DISCARD_RECTANGLE_EXT",
+            "VK_DYNAMIC_STATE_DISCARD_RECTANGLE_EXT",
+        ),
+        (
+            Self::DISCARD_RECTANGLE_ENABLE_EXT.0,
+            "DISCARD_RECTANGLE_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_DISCARD_RECTANGLE_ENABLE_EXT",
+        ),
+        (
+            Self::DISCARD_RECTANGLE_MODE_EXT.0,
+            "DISCARD_RECTANGLE_MODE_EXT",
+            "VK_DYNAMIC_STATE_DISCARD_RECTANGLE_MODE_EXT",
+        ),
+        (
+            Self::SAMPLE_LOCATIONS_EXT.0,
+            "SAMPLE_LOCATIONS_EXT",
+            "VK_DYNAMIC_STATE_SAMPLE_LOCATIONS_EXT",
+        ),
+        (
+            Self::RAY_TRACING_PIPELINE_STACK_SIZE_KHR.0,
+            "RAY_TRACING_PIPELINE_STACK_SIZE_KHR",
+            "VK_DYNAMIC_STATE_RAY_TRACING_PIPELINE_STACK_SIZE_KHR",
+        ),
+        (
+            Self::VIEWPORT_SHADING_RATE_PALETTE_NV.0,
+            "VIEWPORT_SHADING_RATE_PALETTE_NV",
+            "VK_DYNAMIC_STATE_VIEWPORT_SHADING_RATE_PALETTE_NV",
+        ),
+        (
+            Self::VIEWPORT_COARSE_SAMPLE_ORDER_NV.0,
+            "VIEWPORT_COARSE_SAMPLE_ORDER_NV",
+            "VK_DYNAMIC_STATE_VIEWPORT_COARSE_SAMPLE_ORDER_NV",
+        ),
+        (
+            Self::EXCLUSIVE_SCISSOR_ENABLE_NV.0,
+            "EXCLUSIVE_SCISSOR_ENABLE_NV",
+            "VK_DYNAMIC_STATE_EXCLUSIVE_SCISSOR_ENABLE_NV",
+        ),
+        (
+            Self::EXCLUSIVE_SCISSOR_NV.0,
+            "EXCLUSIVE_SCISSOR_NV",
+            "VK_DYNAMIC_STATE_EXCLUSIVE_SCISSOR_NV",
+        ),
+        (
+            Self::FRAGMENT_SHADING_RATE_KHR.0,
+            "FRAGMENT_SHADING_RATE_KHR",
+            "VK_DYNAMIC_STATE_FRAGMENT_SHADING_RATE_KHR",
+        ),
+        (
+            Self::LINE_STIPPLE_EXT.0,
+            "LINE_STIPPLE_EXT",
+            "VK_DYNAMIC_STATE_LINE_STIPPLE_EXT",
+        ),
+        (
+            Self::VERTEX_INPUT_EXT.0,
+            "VERTEX_INPUT_EXT",
+            "VK_DYNAMIC_STATE_VERTEX_INPUT_EXT",
+        ),
+        (
+            Self::PATCH_CONTROL_POINTS_EXT.0,
+            "PATCH_CONTROL_POINTS_EXT",
+            "VK_DYNAMIC_STATE_PATCH_CONTROL_POINTS_EXT",
+        ),
+        (
+            Self::LOGIC_OP_EXT.0,
+            "LOGIC_OP_EXT",
+            "VK_DYNAMIC_STATE_LOGIC_OP_EXT",
+        ),
+        (
+            Self::COLOR_WRITE_ENABLE_EXT.0,
+            "COLOR_WRITE_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_COLOR_WRITE_ENABLE_EXT",
+        ),
+        (
+            Self::TESSELLATION_DOMAIN_ORIGIN_EXT.0,
+            "TESSELLATION_DOMAIN_ORIGIN_EXT",
+            "VK_DYNAMIC_STATE_TESSELLATION_DOMAIN_ORIGIN_EXT",
+        ),
+        (
+            Self::DEPTH_CLAMP_ENABLE_EXT.0,
+            "DEPTH_CLAMP_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_DEPTH_CLAMP_ENABLE_EXT",
+        ),
+        (
+            Self::POLYGON_MODE_EXT.0,
+            "POLYGON_MODE_EXT",
+            "VK_DYNAMIC_STATE_POLYGON_MODE_EXT",
+        ),
+        (
+            Self::RASTERIZATION_SAMPLES_EXT.0,
+            "RASTERIZATION_SAMPLES_EXT",
+            "VK_DYNAMIC_STATE_RASTERIZATION_SAMPLES_EXT",
+        ),
+        (
+            Self::SAMPLE_MASK_EXT.0,
+            "SAMPLE_MASK_EXT",
+            "VK_DYNAMIC_STATE_SAMPLE_MASK_EXT",
+        ),
+        (
+            Self::ALPHA_TO_COVERAGE_ENABLE_EXT.0,
+            "ALPHA_TO_COVERAGE_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_ALPHA_TO_COVERAGE_ENABLE_EXT",
+        ),
+        (
+            Self::ALPHA_TO_ONE_ENABLE_EXT.0,
+            "ALPHA_TO_ONE_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_ALPHA_TO_ONE_ENABLE_EXT",
+        ),
+        (
+            Self::LOGIC_OP_ENABLE_EXT.0,
+            "LOGIC_OP_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_LOGIC_OP_ENABLE_EXT",
+        ),
+        (
+            Self::COLOR_BLEND_ENABLE_EXT.0,
+            "COLOR_BLEND_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_COLOR_BLEND_ENABLE_EXT",
+        ),
+        (
+            Self::COLOR_BLEND_EQUATION_EXT.0,
+            "COLOR_BLEND_EQUATION_EXT",
+            "VK_DYNAMIC_STATE_COLOR_BLEND_EQUATION_EXT",
+        ),
+        (
+            Self::COLOR_WRITE_MASK_EXT.0,
+            "COLOR_WRITE_MASK_EXT",
+            "VK_DYNAMIC_STATE_COLOR_WRITE_MASK_EXT",
+        ),
+        (
+            Self::RASTERIZATION_STREAM_EXT.0,
+            "RASTERIZATION_STREAM_EXT",
+            "VK_DYNAMIC_STATE_RASTERIZATION_STREAM_EXT",
+        ),
+        (
+            Self::CONSERVATIVE_RASTERIZATION_MODE_EXT.0,
+            "CONSERVATIVE_RASTERIZATION_MODE_EXT",
+            "VK_DYNAMIC_STATE_CONSERVATIVE_RASTERIZATION_MODE_EXT",
+        ),
+        (
+            Self::EXTRA_PRIMITIVE_OVERESTIMATION_SIZE_EXT.0,
+            "EXTRA_PRIMITIVE_OVERESTIMATION_SIZE_EXT",
+            "VK_DYNAMIC_STATE_EXTRA_PRIMITIVE_OVERESTIMATION_SIZE_EXT",
+        ),
+        (
+            Self::DEPTH_CLIP_ENABLE_EXT.0,
+            "DEPTH_CLIP_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_DEPTH_CLIP_ENABLE_EXT",
+        ),
+        (
+            Self::SAMPLE_LOCATIONS_ENABLE_EXT.0,
+            "SAMPLE_LOCATIONS_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_SAMPLE_LOCATIONS_ENABLE_EXT",
+        ),
+        (
+            Self::COLOR_BLEND_ADVANCED_EXT.0,
+            "COLOR_BLEND_ADVANCED_EXT",
+            "VK_DYNAMIC_STATE_COLOR_BLEND_ADVANCED_EXT",
+        ),
+        (
+            Self::PROVOKING_VERTEX_MODE_EXT.0,
+            "PROVOKING_VERTEX_MODE_EXT",
+            "VK_DYNAMIC_STATE_PROVOKING_VERTEX_MODE_EXT",
+        ),
+        (
+            Self::LINE_RASTERIZATION_MODE_EXT.0,
+            "LINE_RASTERIZATION_MODE_EXT",
+            "VK_DYNAMIC_STATE_LINE_RASTERIZATION_MODE_EXT",
+        ),
+        (
+            Self::LINE_STIPPLE_ENABLE_EXT.0,
+            "LINE_STIPPLE_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_LINE_STIPPLE_ENABLE_EXT",
+        ),
+        (
+            Self::DEPTH_CLIP_NEGATIVE_ONE_TO_ONE_EXT.0,
+            "DEPTH_CLIP_NEGATIVE_ONE_TO_ONE_EXT",
+            "VK_DYNAMIC_STATE_DEPTH_CLIP_NEGATIVE_ONE_TO_ONE_EXT",
+        ),
+        (
+            Self::VIEWPORT_W_SCALING_ENABLE_NV.0,
+            "VIEWPORT_W_SCALING_ENABLE_NV",
+            "VK_DYNAMIC_STATE_VIEWPORT_W_SCALING_ENABLE_NV",
+        ),
+        (
+            Self::VIEWPORT_SWIZZLE_NV.0,
+            "VIEWPORT_SWIZZLE_NV",
+            "VK_DYNAMIC_STATE_VIEWPORT_SWIZZLE_NV",
+        ),
+        (
+            Self::COVERAGE_TO_COLOR_ENABLE_NV.0,
+            "COVERAGE_TO_COLOR_ENABLE_NV",
+            "VK_DYNAMIC_STATE_COVERAGE_TO_COLOR_ENABLE_NV",
+        ),
+        (
+            Self::COVERAGE_TO_COLOR_LOCATION_NV.0,
+            "COVERAGE_TO_COLOR_LOCATION_NV",
+            "VK_DYNAMIC_STATE_COVERAGE_TO_COLOR_LOCATION_NV",
+        ),
+        (
+            Self::COVERAGE_MODULATION_MODE_NV.0,
+            "COVERAGE_MODULATION_MODE_NV",
+            "VK_DYNAMIC_STATE_COVERAGE_MODULATION_MODE_NV",
+        ),
+        (
+            Self::COVERAGE_MODULATION_TABLE_ENABLE_NV.0,
+            "COVERAGE_MODULATION_TABLE_ENABLE_NV",
+            "VK_DYNAMIC_STATE_COVERAGE_MODULATION_TABLE_ENABLE_NV",
+        ),
+        (
+            Self::COVERAGE_MODULATION_TABLE_NV.0,
+            "COVERAGE_MODULATION_TABLE_NV",
+            "VK_DYNAMIC_STATE_COVERAGE_MODULATION_TABLE_NV",
+        ),
+        (
+            Self::SHADING_RATE_IMAGE_ENABLE_NV.0,
+            "SHADING_RATE_IMAGE_ENABLE_NV",
+            "VK_DYNAMIC_STATE_SHADING_RATE_IMAGE_ENABLE_NV",
+        ),
+        (
+            Self::REPRESENTATIVE_FRAGMENT_TEST_ENABLE_NV.0,
+            "REPRESENTATIVE_FRAGMENT_TEST_ENABLE_NV",
+            "VK_DYNAMIC_STATE_REPRESENTATIVE_FRAGMENT_TEST_ENABLE_NV",
+        ),
+        (
+            Self::COVERAGE_REDUCTION_MODE_NV.0,
+            "COVERAGE_REDUCTION_MODE_NV",
+            "VK_DYNAMIC_STATE_COVERAGE_REDUCTION_MODE_NV",
+        ),
+        (
+            Self::ATTACHMENT_FEEDBACK_LOOP_ENABLE_EXT.0,
+            "ATTACHMENT_FEEDBACK_LOOP_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_ATTACHMENT_FEEDBACK_LOOP_ENABLE_EXT",
+        ),
+        (Self::CULL_MODE.0, "CULL_MODE", "VK_DYNAMIC_STATE_CULL_MODE"),
+        (
+            Self::FRONT_FACE.0,
+            "FRONT_FACE",
+            "VK_DYNAMIC_STATE_FRONT_FACE",
+        ),
+        (
+            Self::PRIMITIVE_TOPOLOGY.0,
+            "PRIMITIVE_TOPOLOGY",
+            "VK_DYNAMIC_STATE_PRIMITIVE_TOPOLOGY",
+        ),
+        (
+            Self::VIEWPORT_WITH_COUNT.0,
+            "VIEWPORT_WITH_COUNT",
+            "VK_DYNAMIC_STATE_VIEWPORT_WITH_COUNT",
+        ),
+        (
+            Self::SCISSOR_WITH_COUNT.0,
+            "SCISSOR_WITH_COUNT",
+            "VK_DYNAMIC_STATE_SCISSOR_WITH_COUNT",
+        ),
+        (
+            Self::VERTEX_INPUT_BINDING_STRIDE.0,
+            "VERTEX_INPUT_BINDING_STRIDE",
+            "VK_DYNAMIC_STATE_VERTEX_INPUT_BINDING_STRIDE",
+        ),
+        (
+            Self::DEPTH_TEST_ENABLE.0,
+            "DEPTH_TEST_ENABLE",
+            "VK_DYNAMIC_STATE_DEPTH_TEST_ENABLE",
+        ),
+        (
+            Self::DEPTH_WRITE_ENABLE.0,
+            "DEPTH_WRITE_ENABLE",
+            "VK_DYNAMIC_STATE_DEPTH_WRITE_ENABLE",
+        ),
+        (
+            Self::DEPTH_COMPARE_OP.0,
+            "DEPTH_COMPARE_OP",
+            "VK_DYNAMIC_STATE_DEPTH_COMPARE_OP",
+        ),
+        (
+            Self::DEPTH_BOUNDS_TEST_ENABLE.0,
+            "DEPTH_BOUNDS_TEST_ENABLE",
+            "VK_DYNAMIC_STATE_DEPTH_BOUNDS_TEST_ENABLE",
+        ),
+        (
+            Self::STENCIL_TEST_ENABLE.0,
+            "STENCIL_TEST_ENABLE",
+            "VK_DYNAMIC_STATE_STENCIL_TEST_ENABLE",
+        ),
+        (
+            Self::STENCIL_OP.0,
+            "STENCIL_OP",
+            "VK_DYNAMIC_STATE_STENCIL_OP",
+        ),
+        (
+            Self::RASTERIZER_DISCARD_ENABLE.0,
+            "RASTERIZER_DISCARD_ENABLE",
+            "VK_DYNAMIC_STATE_RASTERIZER_DISCARD_ENABLE",
+        ),
+        (
+            Self::DEPTH_BIAS_ENABLE.0,
+            "DEPTH_BIAS_ENABLE",
+            "VK_DYNAMIC_STATE_DEPTH_BIAS_ENABLE",
+        ),
+        (
+            Self::PRIMITIVE_RESTART_ENABLE.0,
+            "PRIMITIVE_RESTART_ENABLE",
+            "VK_DYNAMIC_STATE_PRIMITIVE_RESTART_ENABLE",
+        ),
+        (
+            Self::CULL_MODE_EXT.0,
+            "CULL_MODE_EXT",
+            "VK_DYNAMIC_STATE_CULL_MODE_EXT",
+        ),
+        (
+            Self::FRONT_FACE_EXT.0,
+            "FRONT_FACE_EXT",
+            "VK_DYNAMIC_STATE_FRONT_FACE_EXT",
+        ),
+        (
+            Self::PRIMITIVE_TOPOLOGY_EXT.0,
+            "PRIMITIVE_TOPOLOGY_EXT",
+            "VK_DYNAMIC_STATE_PRIMITIVE_TOPOLOGY_EXT",
+        ),
+        (
+            Self::VIEWPORT_WITH_COUNT_EXT.0,
+            "VIEWPORT_WITH_COUNT_EXT",
+            "VK_DYNAMIC_STATE_VIEWPORT_WITH_COUNT_EXT",
+        ),
+        (
+            Self::SCISSOR_WITH_COUNT_EXT.0,
+            "SCISSOR_WITH_COUNT_EXT",
+            "VK_DYNAMIC_STATE_SCISSOR_WITH_COUNT_EXT",
+        ),
+        (
+            Self::VERTEX_INPUT_BINDING_STRIDE_EXT.0,
+            "VERTEX_INPUT_BINDING_STRIDE_EXT",
+            "VK_DYNAMIC_STATE_VERTEX_INPUT_BINDING_STRIDE_EXT",
+        ),
+        (
+            Self::DEPTH_TEST_ENABLE_EXT.0,
+            "DEPTH_TEST_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_DEPTH_TEST_ENABLE_EXT",
+        ),
+        (
+            Self::DEPTH_WRITE_ENABLE_EXT.0,
+            "DEPTH_WRITE_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_DEPTH_WRITE_ENABLE_EXT",
+        ),
+        (
+            Self::DEPTH_COMPARE_OP_EXT.0,
+            "DEPTH_COMPARE_OP_EXT",
+            "VK_DYNAMIC_STATE_DEPTH_COMPARE_OP_EXT",
+        ),
+        (
+            Self::DEPTH_BOUNDS_TEST_ENABLE_EXT.0,
+            "DEPTH_BOUNDS_TEST_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_DEPTH_BOUNDS_TEST_ENABLE_EXT",
+        ),
+        (
+            Self::STENCIL_TEST_ENABLE_EXT.0,
+            "STENCIL_TEST_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_STENCIL_TEST_ENABLE_EXT",
+        ),
+        (
+            Self::STENCIL_OP_EXT.0,
+            "STENCIL_OP_EXT",
+            "VK_DYNAMIC_STATE_STENCIL_OP_EXT",
+        ),
+        (
+            Self::RASTERIZER_DISCARD_ENABLE_EXT.0,
+            "RASTERIZER_DISCARD_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_RASTERIZER_DISCARD_ENABLE_EXT",
+        ),
+        (
+            Self::DEPTH_BIAS_ENABLE_EXT.0,
+            "DEPTH_BIAS_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_DEPTH_BIAS_ENABLE_EXT",
+        ),
+        (
+            Self::PRIMITIVE_RESTART_ENABLE_EXT.0,
+            "PRIMITIVE_RESTART_ENABLE_EXT",
+            "VK_DYNAMIC_STATE_PRIMITIVE_RESTART_ENABLE_EXT",
+        ),
+    ];
+}
+impl FromStr for DynamicState {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("DynamicState", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for DynamicState {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl Filter {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::NEAREST.0, "NEAREST", "VK_FILTER_NEAREST"),
+        (Self::LINEAR.0, "LINEAR", "VK_FILTER_LINEAR"),
+        (Self::CUBIC_EXT.0, "CUBIC_EXT", "VK_FILTER_CUBIC_EXT"),
+        (Self::CUBIC_IMG.0, "CUBIC_IMG", "VK_FILTER_CUBIC_IMG"),
+    ];
+}
+impl FromStr for Filter {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("Filter", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for Filter {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl Format {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::UNDEFINED.0, "UNDEFINED", "VK_FORMAT_UNDEFINED"),
+        (
+            Self::R4G4_UNORM_PACK8.0,
+            "R4G4_UNORM_PACK8",
+            "VK_FORMAT_R4G4_UNORM_PACK8",
+        ),
+        (
+            Self::R4G4B4A4_UNORM_PACK16.0,
+            "R4G4B4A4_UNORM_PACK16",
+            "VK_FORMAT_R4G4B4A4_UNORM_PACK16",
+        ),
+        (
+            Self::B4G4R4A4_UNORM_PACK16.0,
+            "B4G4R4A4_UNORM_PACK16",
+            "VK_FORMAT_B4G4R4A4_UNORM_PACK16",
+        ),
+        (
+            Self::R5G6B5_UNORM_PACK16.0,
+            "R5G6B5_UNORM_PACK16",
+            "VK_FORMAT_R5G6B5_UNORM_PACK16",
+        ),
+        (
+            Self::B5G6R5_UNORM_PACK16.0,
+            "B5G6R5_UNORM_PACK16",
+            "VK_FORMAT_B5G6R5_UNORM_PACK16",
+        ),
+        (
+            Self::R5G5B5A1_UNORM_PACK16.0,
+            "R5G5B5A1_UNORM_PACK16",
+            "VK_FORMAT_R5G5B5A1_UNORM_PACK16",
+        ),
+        (
+            Self::B5G5R5A1_UNORM_PACK16.0,
+            "B5G5R5A1_UNORM_PACK16",
+            "VK_FORMAT_B5G5R5A1_UNORM_PACK16",
+        ),
+        (
+            Self::A1R5G5B5_UNORM_PACK16.0,
+            "A1R5G5B5_UNORM_PACK16",
+            "VK_FORMAT_A1R5G5B5_UNORM_PACK16",
+        ),
+        (Self::R8_UNORM.0, "R8_UNORM", "VK_FORMAT_R8_UNORM"),
+        (Self::R8_SNORM.0, "R8_SNORM", "VK_FORMAT_R8_SNORM"),
+        (Self::R8_USCALED.0, "R8_USCALED", "VK_FORMAT_R8_USCALED"),
+        (Self::R8_SSCALED.0, "R8_SSCALED", "VK_FORMAT_R8_SSCALED"),
+        (Self::R8_UINT.0, "R8_UINT", "VK_FORMAT_R8_UINT"),
+        (Self::R8_SINT.0, "R8_SINT", "VK_FORMAT_R8_SINT"),
+        (Self::R8_SRGB.0, "R8_SRGB", "VK_FORMAT_R8_SRGB"),
+        (Self::R8G8_UNORM.0, "R8G8_UNORM", "VK_FORMAT_R8G8_UNORM"),
+        (Self::R8G8_SNORM.0, "R8G8_SNORM", "VK_FORMAT_R8G8_SNORM"),
+        (
+            Self::R8G8_USCALED.0,
+            "R8G8_USCALED",
+            "VK_FORMAT_R8G8_USCALED",
+        ),
+        (
+            Self::R8G8_SSCALED.0,
+            "R8G8_SSCALED",
+            "VK_FORMAT_R8G8_SSCALED",
+        ),
+        (Self::R8G8_UINT.0, "R8G8_UINT", "VK_FORMAT_R8G8_UINT"),
+        (Self::R8G8_SINT.0, "R8G8_SINT", "VK_FORMAT_R8G8_SINT"),
+        (Self::R8G8_SRGB.0, "R8G8_SRGB", "VK_FORMAT_R8G8_SRGB"),
+        (
+            Self::R8G8B8_UNORM.0,
+            "R8G8B8_UNORM",
+            "VK_FORMAT_R8G8B8_UNORM",
+        ),
+        (
+            Self::R8G8B8_SNORM.0,
+            "R8G8B8_SNORM",
+            "VK_FORMAT_R8G8B8_SNORM",
+        ),
+        (
+            Self::R8G8B8_USCALED.0,
+            "R8G8B8_USCALED",
+            "VK_FORMAT_R8G8B8_USCALED",
+        ),
+        (
+            Self::R8G8B8_SSCALED.0,
+            "R8G8B8_SSCALED",
+            "VK_FORMAT_R8G8B8_SSCALED",
+        ),
+        (Self::R8G8B8_UINT.0, "R8G8B8_UINT", "VK_FORMAT_R8G8B8_UINT"),
+        (Self::R8G8B8_SINT.0, "R8G8B8_SINT", "VK_FORMAT_R8G8B8_SINT"),
+        (Self::R8G8B8_SRGB.0, "R8G8B8_SRGB", "VK_FORMAT_R8G8B8_SRGB"),
+        (
+            Self::B8G8R8_UNORM.0,
+            "B8G8R8_UNORM",
+            "VK_FORMAT_B8G8R8_UNORM",
+        ),
+        (
+            Self::B8G8R8_SNORM.0,
+            "B8G8R8_SNORM",
+            "VK_FORMAT_B8G8R8_SNORM",
+        ),
+        (
+            Self::B8G8R8_USCALED.0,
+            "B8G8R8_USCALED",
+            "VK_FORMAT_B8G8R8_USCALED",
+        ),
+        (
+            Self::B8G8R8_SSCALED.0,
+            "B8G8R8_SSCALED",
+            "VK_FORMAT_B8G8R8_SSCALED",
+        ),
+        (Self::B8G8R8_UINT.0, "B8G8R8_UINT", "VK_FORMAT_B8G8R8_UINT"),
+        (Self::B8G8R8_SINT.0, "B8G8R8_SINT", "VK_FORMAT_B8G8R8_SINT"),
+        (Self::B8G8R8_SRGB.0, "B8G8R8_SRGB", "VK_FORMAT_B8G8R8_SRGB"),
+        (
+            Self::R8G8B8A8_UNORM.0,
+            "R8G8B8A8_UNORM",
+            "VK_FORMAT_R8G8B8A8_UNORM",
+        ),
+        (
+            Self::R8G8B8A8_SNORM.0,
+            "R8G8B8A8_SNORM",
+            "VK_FORMAT_R8G8B8A8_SNORM",
+        ),
+        (
+            Self::R8G8B8A8_USCALED.0,
+            "R8G8B8A8_USCALED",
+            "VK_FORMAT_R8G8B8A8_USCALED",
+        ),
+        (
+            Self::R8G8B8A8_SSCALED.0,
+            "R8G8B8A8_SSCALED",
+            "VK_FORMAT_R8G8B8A8_SSCALED",
+        ),
+        (
+            Self::R8G8B8A8_UINT.0,
+            "R8G8B8A8_UINT",
+            "VK_FORMAT_R8G8B8A8_UINT",
+        ),
+        (
+            Self::R8G8B8A8_SINT.0,
+            "R8G8B8A8_SINT",
+            "VK_FORMAT_R8G8B8A8_SINT",
+        ),
+        (
+            Self::R8G8B8A8_SRGB.0,
+            "R8G8B8A8_SRGB",
+            "VK_FORMAT_R8G8B8A8_SRGB",
+        ),
+        (
+            Self::B8G8R8A8_UNORM.0,
+            "B8G8R8A8_UNORM",
+            "VK_FORMAT_B8G8R8A8_UNORM",
+        ),
+        (
+            Self::B8G8R8A8_SNORM.0,
+            "B8G8R8A8_SNORM",
+            "VK_FORMAT_B8G8R8A8_SNORM",
+        ),
+        (
+            Self::B8G8R8A8_USCALED.0,
+            "B8G8R8A8_USCALED",
+            "VK_FORMAT_B8G8R8A8_USCALED",
+        ),
+        (
+            Self::B8G8R8A8_SSCALED.0,
+            "B8G8R8A8_SSCALED",
+            "VK_FORMAT_B8G8R8A8_SSCALED",
+        ),
+        (
+            Self::B8G8R8A8_UINT.0,
+            "B8G8R8A8_UINT",
+            "VK_FORMAT_B8G8R8A8_UINT",
+        ),
+        (
+            Self::B8G8R8A8_SINT.0,
+            "B8G8R8A8_SINT",
+            "VK_FORMAT_B8G8R8A8_SINT",
+        ),
+        (
+            Self::B8G8R8A8_SRGB.0,
+            "B8G8R8A8_SRGB",
+            "VK_FORMAT_B8G8R8A8_SRGB",
+        ),
+        (
+            Self::A8B8G8R8_UNORM_PACK32.0,
+            "A8B8G8R8_UNORM_PACK32",
+            "VK_FORMAT_A8B8G8R8_UNORM_PACK32",
+        ),
+        (
+            Self::A8B8G8R8_SNORM_PACK32.0,
+            "A8B8G8R8_SNORM_PACK32",
+            "VK_FORMAT_A8B8G8R8_SNORM_PACK32",
+        ),
+        (
+            Self::A8B8G8R8_USCALED_PACK32.0,
+            "A8B8G8R8_USCALED_PACK32",
+            "VK_FORMAT_A8B8G8R8_USCALED_PACK32",
+        ),
+        (
+            Self::A8B8G8R8_SSCALED_PACK32.0,
+            "A8B8G8R8_SSCALED_PACK32",
+            "VK_FORMAT_A8B8G8R8_SSCALED_PACK32",
+        ),
+        (
+            Self::A8B8G8R8_UINT_PACK32.0,
+            "A8B8G8R8_UINT_PACK32",
+            "VK_FORMAT_A8B8G8R8_UINT_PACK32",
+        ),
+        (
+            Self::A8B8G8R8_SINT_PACK32.0,
+            "A8B8G8R8_SINT_PACK32",
+            "VK_FORMAT_A8B8G8R8_SINT_PACK32",
+        ),
+        (
+            Self::A8B8G8R8_SRGB_PACK32.0,
+            "A8B8G8R8_SRGB_PACK32",
+            "VK_FORMAT_A8B8G8R8_SRGB_PACK32",
+        ),
+        (
+            Self::A2R10G10B10_UNORM_PACK32.0,
+            "A2R10G10B10_UNORM_PACK32",
+            "VK_FORMAT_A2R10G10B10_UNORM_PACK32",
+        ),
+        (
+            Self::A2R10G10B10_SNORM_PACK32.0,
+            "A2R10G10B10_SNORM_PACK32",
+            "VK_FORMAT_A2R10G10B10_SNORM_PACK32",
+        ),
+        (
+            Self::A2R10G10B10_USCALED_PACK32.0,
+            "A2R10G10B10_USCALED_PACK32",
+            "VK_FORMAT_A2R10G10B10_USCALED_PACK32",
+        ),
+        (
+            Self::A2R10G10B10_SSCALED_PACK32.0,
+            "A2R10G10B10_SSCALED_PACK32",
+            "VK_FORMAT_A2R10G10B10_SSCALED_PACK32",
+        ),
+        (
+            Self::A2R10G10B10_UINT_PACK32.0,
+            "A2R10G10B10_UINT_PACK32",
+            "VK_FORMAT_A2R10G10B10_UINT_PACK32",
+        ),
+        (
+            Self::A2R10G10B10_SINT_PACK32.0,
+            "A2R10G10B10_SINT_PACK32",
+            "VK_FORMAT_A2R10G10B10_SINT_PACK32",
+        ),
+        (
+            Self::A2B10G10R10_UNORM_PACK32.0,
+            "A2B10G10R10_UNORM_PACK32",
+            "VK_FORMAT_A2B10G10R10_UNORM_PACK32",
+        ),
+        (
+            Self::A2B10G10R10_SNORM_PACK32.0,
+            "A2B10G10R10_SNORM_PACK32",
+            "VK_FORMAT_A2B10G10R10_SNORM_PACK32",
+        ),
+        (
+            Self::A2B10G10R10_USCALED_PACK32.0,
+            "A2B10G10R10_USCALED_PACK32",
+            "VK_FORMAT_A2B10G10R10_USCALED_PACK32",
+        ),
+        (
+            Self::A2B10G10R10_SSCALED_PACK32.0,
+            "A2B10G10R10_SSCALED_PACK32",
+            "VK_FORMAT_A2B10G10R10_SSCALED_PACK32",
+        ),
+        (
+            Self::A2B10G10R10_UINT_PACK32.0,
+            "A2B10G10R10_UINT_PACK32",
+            "VK_FORMAT_A2B10G10R10_UINT_PACK32",
+        ),
+        (
+            Self::A2B10G10R10_SINT_PACK32.0,
+            "A2B10G10R10_SINT_PACK32",
+            "VK_FORMAT_A2B10G10R10_SINT_PACK32",
+        ),
+        (Self::R16_UNORM.0, "R16_UNORM", "VK_FORMAT_R16_UNORM"),
+        (Self::R16_SNORM.0, "R16_SNORM", "VK_FORMAT_R16_SNORM"),
+        (Self::R16_USCALED.0, "R16_USCALED", "VK_FORMAT_R16_USCALED"),
+        (Self::R16_SSCALED.0, "R16_SSCALED", "VK_FORMAT_R16_SSCALED"),
+        (Self::R16_UINT.0, "R16_UINT", "VK_FORMAT_R16_UINT"),
+        (Self::R16_SINT.0, "R16_SINT", "VK_FORMAT_R16_SINT"),
+        (Self::R16_SFLOAT.0, "R16_SFLOAT", "VK_FORMAT_R16_SFLOAT"),
+        (
+            Self::R16G16_UNORM.0,
+            "R16G16_UNORM",
+            "VK_FORMAT_R16G16_UNORM",
+        ),
+        (
+            Self::R16G16_SNORM.0,
+            "R16G16_SNORM",
+            "VK_FORMAT_R16G16_SNORM",
+        ),
+        (
+            Self::R16G16_USCALED.0,
+            "R16G16_USCALED",
+            "VK_FORMAT_R16G16_USCALED",
+        ),
+        (
+            Self::R16G16_SSCALED.0,
+            "R16G16_SSCALED",
+            "VK_FORMAT_R16G16_SSCALED",
+        ),
+        (Self::R16G16_UINT.0, "R16G16_UINT", "VK_FORMAT_R16G16_UINT"),
+        (Self::R16G16_SINT.0, "R16G16_SINT", "VK_FORMAT_R16G16_SINT"),
+        (
+            Self::R16G16_SFLOAT.0,
+            "R16G16_SFLOAT",
+            "VK_FORMAT_R16G16_SFLOAT",
+        ),
+        (
+            Self::R16G16B16_UNORM.0,
+            "R16G16B16_UNORM",
+            "VK_FORMAT_R16G16B16_UNORM",
+        ),
+        (
+            Self::R16G16B16_SNORM.0,
+            "R16G16B16_SNORM",
+            "VK_FORMAT_R16G16B16_SNORM",
+        ),
+        (
+            Self::R16G16B16_USCALED.0,
+            "R16G16B16_USCALED",
+            "VK_FORMAT_R16G16B16_USCALED",
+        ),
+        (
+            Self::R16G16B16_SSCALED.0,
+            "R16G16B16_SSCALED",
+            "VK_FORMAT_R16G16B16_SSCALED",
+        ),
+        (
+            Self::R16G16B16_UINT.0,
+            "R16G16B16_UINT",
+            "VK_FORMAT_R16G16B16_UINT",
+        ),
+        (
+            Self::R16G16B16_SINT.0,
+            "R16G16B16_SINT",
+            "VK_FORMAT_R16G16B16_SINT",
+        ),
+        (
+            Self::R16G16B16_SFLOAT.0,
+            "R16G16B16_SFLOAT",
+            "VK_FORMAT_R16G16B16_SFLOAT",
+        ),
+        (
+            Self::R16G16B16A16_UNORM.0,
+            "R16G16B16A16_UNORM",
+            "VK_FORMAT_R16G16B16A16_UNORM",
+        ),
+        (
+            Self::R16G16B16A16_SNORM.0,
+            "R16G16B16A16_SNORM",
+            "VK_FORMAT_R16G16B16A16_SNORM",
+        ),
+        (
+            Self::R16G16B16A16_USCALED.0,
+            "R16G16B16A16_USCALED",
+            "VK_FORMAT_R16G16B16A16_USCALED",
+        ),
+        (
+            Self::R16G16B16A16_SSCALED.0,
+            "R16G16B16A16_SSCALED",
+            "VK_FORMAT_R16G16B16A16_SSCALED",
+        ),
+        (
+            Self::R16G16B16A16_UINT.0,
+            "R16G16B16A16_UINT",
+            "VK_FORMAT_R16G16B16A16_UINT",
+        ),
+        (
+            Self::R16G16B16A16_SINT.0,
+            "R16G16B16A16_SINT",
+            "VK_FORMAT_R16G16B16A16_SINT",
+        ),
+        (
+            Self::R16G16B16A16_SFLOAT.0,
+            "R16G16B16A16_SFLOAT",
+            "VK_FORMAT_R16G16B16A16_SFLOAT",
+        ),
+        (Self::R32_UINT.0, "R32_UINT", "VK_FORMAT_R32_UINT"),
+        (Self::R32_SINT.0, "R32_SINT", "VK_FORMAT_R32_SINT"),
+        (Self::R32_SFLOAT.0, "R32_SFLOAT", "VK_FORMAT_R32_SFLOAT"),
+        (Self::R32G32_UINT.0, "R32G32_UINT", "VK_FORMAT_R32G32_UINT"),
+        (Self::R32G32_SINT.0, "R32G32_SINT", "VK_FORMAT_R32G32_SINT"),
+        (
+            Self::R32G32_SFLOAT.0,
+            "R32G32_SFLOAT",
+            "VK_FORMAT_R32G32_SFLOAT",
+        ),
+        (
+            Self::R32G32B32_UINT.0,
+            "R32G32B32_UINT",
+            "VK_FORMAT_R32G32B32_UINT",
+        ),
+        (
+            Self::R32G32B32_SINT.0,
+            "R32G32B32_SINT",
+            "VK_FORMAT_R32G32B32_SINT",
+        ),
+        (
+            Self::R32G32B32_SFLOAT.0,
+            "R32G32B32_SFLOAT",
+            "VK_FORMAT_R32G32B32_SFLOAT",
+        ),
+        (
+            Self::R32G32B32A32_UINT.0,
+            "R32G32B32A32_UINT",
+            "VK_FORMAT_R32G32B32A32_UINT",
+        ),
+        (
+            Self::R32G32B32A32_SINT.0,
+            "R32G32B32A32_SINT",
+            "VK_FORMAT_R32G32B32A32_SINT",
+        ),
+        (
+            Self::R32G32B32A32_SFLOAT.0,
+            "R32G32B32A32_SFLOAT",
+            "VK_FORMAT_R32G32B32A32_SFLOAT",
+        ),
+        (Self::R64_UINT.0, "R64_UINT", "VK_FORMAT_R64_UINT"),
+        (Self::R64_SINT.0, "R64_SINT", "VK_FORMAT_R64_SINT"),
+        (Self::R64_SFLOAT.0, "R64_SFLOAT", "VK_FORMAT_R64_SFLOAT"),
+        (Self::R64G64_UINT.0, "R64G64_UINT", "VK_FORMAT_R64G64_UINT"),
+        (Self::R64G64_SINT.0, "R64G64_SINT", "VK_FORMAT_R64G64_SINT"),
+        (
+            Self::R64G64_SFLOAT.0,
+            "R64G64_SFLOAT",
+            "VK_FORMAT_R64G64_SFLOAT",
+        ),
+        (
+            Self::R64G64B64_UINT.0,
+            "R64G64B64_UINT",
+            "VK_FORMAT_R64G64B64_UINT",
+        ),
+        (
+            Self::R64G64B64_SINT.0,
+            "R64G64B64_SINT",
+            "VK_FORMAT_R64G64B64_SINT",
+        ),
+        (
+            Self::R64G64B64_SFLOAT.0,
+            "R64G64B64_SFLOAT",
+            "VK_FORMAT_R64G64B64_SFLOAT",
+        ),
+        (
+            Self::R64G64B64A64_UINT.0,
+            "R64G64B64A64_UINT",
+            "VK_FORMAT_R64G64B64A64_UINT",
+        ),
+        (
+            Self::R64G64B64A64_SINT.0,
+            "R64G64B64A64_SINT",
+            "VK_FORMAT_R64G64B64A64_SINT",
+        ),
+        (
+            Self::R64G64B64A64_SFLOAT.0,
+            "R64G64B64A64_SFLOAT",
+            "VK_FORMAT_R64G64B64A64_SFLOAT",
+        ),
+        (
+            Self::B10G11R11_UFLOAT_PACK32.0,
+            "B10G11R11_UFLOAT_PACK32",
+            "VK_FORMAT_B10G11R11_UFLOAT_PACK32",
+        ),
+        (
+            Self::E5B9G9R9_UFLOAT_PACK32.0,
+            "E5B9G9R9_UFLOAT_PACK32",
+            "VK_FORMAT_E5B9G9R9_UFLOAT_PACK32",
+        ),
+        (Self::D16_UNORM.0, "D16_UNORM", "VK_FORMAT_D16_UNORM"),
+        (
+            Self::X8_D24_UNORM_PACK32.0,
+            "X8_D24_UNORM_PACK32",
+            "VK_FORMAT_X8_D24_UNORM_PACK32",
+        ),
+        (Self::D32_SFLOAT.0, "D32_SFLOAT", "VK_FORMAT_D32_SFLOAT"),
+        (Self::S8_UINT.0, "S8_UINT", "VK_FORMAT_S8_UINT"),
+        (
+            Self::D16_UNORM_S8_UINT.0,
+            "D16_UNORM_S8_UINT",
+            "VK_FORMAT_D16_UNORM_S8_UINT",
+        ),
+        (
+            Self::D24_UNORM_S8_UINT.0,
+            "D24_UNORM_S8_UINT",
+            "VK_FORMAT_D24_UNORM_S8_UINT",
+        ),
+        (
+            Self::D32_SFLOAT_S8_UINT.0,
+            "D32_SFLOAT_S8_UINT",
+            "VK_FORMAT_D32_SFLOAT_S8_UINT",
+        ),
+        (
+            Self::BC1_RGB_UNORM_BLOCK.0,
+            "BC1_RGB_UNORM_BLOCK",
+            "VK_FORMAT_BC1_RGB_UNORM_BLOCK",
+        ),
+        (
+            Self::BC1_RGB_SRGB_BLOCK.0,
+            "BC1_RGB_SRGB_BLOCK",
+            "VK_FORMAT_BC1_RGB_SRGB_BLOCK",
+        ),
+        (
+            Self::BC1_RGBA_UNORM_BLOCK.0,
+            "BC1_RGBA_UNORM_BLOCK",
+            "VK_FORMAT_BC1_RGBA_UNORM_BLOCK",
+        ),
+        (
+            Self::BC1_RGBA_SRGB_BLOCK.0,
+            "BC1_RGBA_SRGB_BLOCK",
+            "VK_FORMAT_BC1_RGBA_SRGB_BLOCK",
+        ),
+        (
+            Self::BC2_UNORM_BLOCK.0,
+            "BC2_UNORM_BLOCK",
+            "VK_FORMAT_BC2_UNORM_BLOCK",
+        ),
+        (
+            Self::BC2_SRGB_BLOCK.0,
+            "BC2_SRGB_BLOCK",
+            "VK_FORMAT_BC2_SRGB_BLOCK",
+        ),
+        (
+            Self::BC3_UNORM_BLOCK.0,
+            "BC3_UNORM_BLOCK",
+            "VK_FORMAT_BC3_UNORM_BLOCK",
+        ),
+        (
+            Self::BC3_SRGB_BLOCK.0,
+            "BC3_SRGB_BLOCK",
+            "VK_FORMAT_BC3_SRGB_BLOCK",
+        ),
+        (
+            Self::BC4_UNORM_BLOCK.0,
+            "BC4_UNORM_BLOCK",
+            "VK_FORMAT_BC4_UNORM_BLOCK",
+        ),
+        (
+            Self::BC4_SNORM_BLOCK.0,
+            "BC4_SNORM_BLOCK",
+            "VK_FORMAT_BC4_SNORM_BLOCK",
+        ),
+        (
+            Self::BC5_UNORM_BLOCK.0,
+            "BC5_UNORM_BLOCK",
+            "VK_FORMAT_BC5_UNORM_BLOCK",
+        ),
+        (
+            Self::BC5_SNORM_BLOCK.0,
+            "BC5_SNORM_BLOCK",
+            "VK_FORMAT_BC5_SNORM_BLOCK",
+        ),
+        (
+            Self::BC6H_UFLOAT_BLOCK.0,
+            "BC6H_UFLOAT_BLOCK",
+            "VK_FORMAT_BC6H_UFLOAT_BLOCK",
+        ),
+        (
+            Self::BC6H_SFLOAT_BLOCK.0,
+            "BC6H_SFLOAT_BLOCK",
+            "VK_FORMAT_BC6H_SFLOAT_BLOCK",
+        ),
+        (
+            Self::BC7_UNORM_BLOCK.0,
+            "BC7_UNORM_BLOCK",
+            "VK_FORMAT_BC7_UNORM_BLOCK",
+        ),
+        (
+            Self::BC7_SRGB_BLOCK.0,
+            "BC7_SRGB_BLOCK",
+            "VK_FORMAT_BC7_SRGB_BLOCK",
+        ),
+        (
+            Self::ETC2_R8G8B8_UNORM_BLOCK.0,
+            "ETC2_R8G8B8_UNORM_BLOCK",
+            "VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK",
+        ),
+        (
+            Self::ETC2_R8G8B8_SRGB_BLOCK.0,
+            "ETC2_R8G8B8_SRGB_BLOCK",
+            "VK_FORMAT_ETC2_R8G8B8_SRGB_BLOCK",
+        ),
+        (
+            Self::ETC2_R8G8B8A1_UNORM_BLOCK.0,
+            "ETC2_R8G8B8A1_UNORM_BLOCK",
+            "VK_FORMAT_ETC2_R8G8B8A1_UNORM_BLOCK",
+        ),
+        (
+            Self::ETC2_R8G8B8A1_SRGB_BLOCK.0,
+            "ETC2_R8G8B8A1_SRGB_BLOCK",
+            "VK_FORMAT_ETC2_R8G8B8A1_SRGB_BLOCK",
+        ),
+        (
+            Self::ETC2_R8G8B8A8_UNORM_BLOCK.0,
+            "ETC2_R8G8B8A8_UNORM_BLOCK",
+            "VK_FORMAT_ETC2_R8G8B8A8_UNORM_BLOCK",
+        ),
+        (
+            Self::ETC2_R8G8B8A8_SRGB_BLOCK.0,
+            "ETC2_R8G8B8A8_SRGB_BLOCK",
+            "VK_FORMAT_ETC2_R8G8B8A8_SRGB_BLOCK",
+        ),
+        (
+            Self::EAC_R11_UNORM_BLOCK.0,
+            "EAC_R11_UNORM_BLOCK",
+            "VK_FORMAT_EAC_R11_UNORM_BLOCK",
+        ),
+        (
+            Self::EAC_R11_SNORM_BLOCK.0,
+            "EAC_R11_SNORM_BLOCK",
+            "VK_FORMAT_EAC_R11_SNORM_BLOCK",
+        ),
+        (
+            Self::EAC_R11G11_UNORM_BLOCK.0,
+            "EAC_R11G11_UNORM_BLOCK",
+            "VK_FORMAT_EAC_R11G11_UNORM_BLOCK",
+        ),
+        (
+            Self::EAC_R11G11_SNORM_BLOCK.0,
+            "EAC_R11G11_SNORM_BLOCK",
+            "VK_FORMAT_EAC_R11G11_SNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_4X4_UNORM_BLOCK.0,
+            "ASTC_4X4_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_4x4_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_4X4_SRGB_BLOCK.0,
+            "ASTC_4X4_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_4x4_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_5X4_UNORM_BLOCK.0,
+            "ASTC_5X4_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_5x4_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_5X4_SRGB_BLOCK.0,
+            "ASTC_5X4_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_5x4_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_5X5_UNORM_BLOCK.0,
+            "ASTC_5X5_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_5x5_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_5X5_SRGB_BLOCK.0,
+            "ASTC_5X5_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_5x5_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_6X5_UNORM_BLOCK.0,
+            "ASTC_6X5_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_6x5_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_6X5_SRGB_BLOCK.0,
+            "ASTC_6X5_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_6x5_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_6X6_UNORM_BLOCK.0,
+            "ASTC_6X6_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_6x6_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_6X6_SRGB_BLOCK.0,
+            "ASTC_6X6_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_6x6_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_8X5_UNORM_BLOCK.0,
+            "ASTC_8X5_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_8x5_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_8X5_SRGB_BLOCK.0,
+            "ASTC_8X5_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_8x5_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_8X6_UNORM_BLOCK.0,
+            "ASTC_8X6_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_8x6_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_8X6_SRGB_BLOCK.0,
+            "ASTC_8X6_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_8x6_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_8X8_UNORM_BLOCK.0,
+            "ASTC_8X8_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_8x8_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_8X8_SRGB_BLOCK.0,
+            "ASTC_8X8_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_8x8_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_10X5_UNORM_BLOCK.0,
+            "ASTC_10X5_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_10x5_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_10X5_SRGB_BLOCK.0,
+            "ASTC_10X5_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_10x5_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_10X6_UNORM_BLOCK.0,
+            "ASTC_10X6_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_10x6_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_10X6_SRGB_BLOCK.0,
+            "ASTC_10X6_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_10x6_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_10X8_UNORM_BLOCK.0,
+            "ASTC_10X8_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_10x8_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_10X8_SRGB_BLOCK.0,
+            "ASTC_10X8_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_10x8_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_10X10_UNORM_BLOCK.0,
+            "ASTC_10X10_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_10x10_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_10X10_SRGB_BLOCK.0,
+            "ASTC_10X10_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_10x10_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_12X10_UNORM_BLOCK.0,
+            "ASTC_12X10_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_12x10_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_12X10_SRGB_BLOCK.0,
+            "ASTC_12X10_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_12x10_SRGB_BLOCK",
+        ),
+        (
+            Self::ASTC_12X12_UNORM_BLOCK.0,
+            "ASTC_12X12_UNORM_BLOCK",
+            "VK_FORMAT_ASTC_12x12_UNORM_BLOCK",
+        ),
+        (
+            Self::ASTC_12X12_SRGB_BLOCK.0,
+            "ASTC_12X12_SRGB_BLOCK",
+            "VK_FORMAT_ASTC_12x12_SRGB_BLOCK",
+        ),
+        (
+            Self::PVRTC1_2BPP_UNORM_BLOCK_IMG.0,
+            "PVRTC1_2BPP_UNORM_BLOCK_IMG",
+            "VK_FORMAT_PVRTC1_2BPP_UNORM_BLOCK_IMG",
+        ),
+        (
+            Self::PVRTC1_4BPP_UNORM_BLOCK_IMG.0,
+            "PVRTC1_4BPP_UNORM_BLOCK_IMG",
+            "VK_FORMAT_PVRTC1_4BPP_UNORM_BLOCK_IMG",
+        ),
+        (
+            Self::PVRTC2_2BPP_UNORM_BLOCK_IMG.0,
+            "PVRTC2_2BPP_UNORM_BLOCK_IMG",
+            "VK_FORMAT_PVRTC2_2BPP_UNORM_BLOCK_IMG",
+        ),
+        (
+            Self::PVRTC2_4BPP_UNORM_BLOCK_IMG.0,
+            "PVRTC2_4BPP_UNORM_BLOCK_IMG",
+            "VK_FORMAT_PVRTC2_4BPP_UNORM_BLOCK_IMG",
+        ),
+        (
+            Self::PVRTC1_2BPP_SRGB_BLOCK_IMG.0,
+            "PVRTC1_2BPP_SRGB_BLOCK_IMG",
+            "VK_FORMAT_PVRTC1_2BPP_SRGB_BLOCK_IMG",
+        ),
+        (
+            Self::PVRTC1_4BPP_SRGB_BLOCK_IMG.0,
+            "PVRTC1_4BPP_SRGB_BLOCK_IMG",
+            "VK_FORMAT_PVRTC1_4BPP_SRGB_BLOCK_IMG",
+        ),
+        (
+            Self::PVRTC2_2BPP_SRGB_BLOCK_IMG.0,
+            "PVRTC2_2BPP_SRGB_BLOCK_IMG",
+            "VK_FORMAT_PVRTC2_2BPP_SRGB_BLOCK_IMG",
+        ),
+        (
+            Self::PVRTC2_4BPP_SRGB_BLOCK_IMG.0,
+            "PVRTC2_4BPP_SRGB_BLOCK_IMG",
+            "VK_FORMAT_PVRTC2_4BPP_SRGB_BLOCK_IMG",
+        ),
+        (
+            Self::R16G16_S10_5_NV.0,
+            "R16G16_S10_5_NV",
+            "VK_FORMAT_R16G16_S10_5_NV",
+        ),
+        (
+            Self::G8B8G8R8_422_UNORM.0,
+            "G8B8G8R8_422_UNORM",
+            "VK_FORMAT_G8B8G8R8_422_UNORM",
+        ),
+        (
+            Self::B8G8R8G8_422_UNORM.0,
+            "B8G8R8G8_422_UNORM",
+            "VK_FORMAT_B8G8R8G8_422_UNORM",
+        ),
+        (
+            Self::G8_B8_R8_3PLANE_420_UNORM.0,
+            "G8_B8_R8_3PLANE_420_UNORM",
+            "VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM",
+        ),
+        (
+            Self::G8_B8R8_2PLANE_420_UNORM.0,
+            "G8_B8R8_2PLANE_420_UNORM",
+            "VK_FORMAT_G8_B8R8_2PLANE_420_UNORM",
+        ),
+        (
+            Self::G8_B8_R8_3PLANE_422_UNORM.0,
+            "G8_B8_R8_3PLANE_422_UNORM",
+            "VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM",
+        ),
+        (
+            Self::G8_B8R8_2PLANE_422_UNORM.0,
+            "G8_B8R8_2PLANE_422_UNORM",
+            "VK_FORMAT_G8_B8R8_2PLANE_422_UNORM",
+        ),
+        (
+            Self::G8_B8_R8_3PLANE_444_UNORM.0,
+            "G8_B8_R8_3PLANE_444_UNORM",
+            "VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM",
+        ),
+        (
+            Self::R10X6_UNORM_PACK16.0,
+            "R10X6_UNORM_PACK16",
+            "VK_FORMAT_R10X6_UNORM_PACK16",
+        ),
+        (
+            Self::R10X6G10X6_UNORM_2PACK16.0,
+            "R10X6G10X6_UNORM_2PACK16",
+            "VK_FORMAT_R10X6G10X6_UNORM_2PACK16",
+        ),
+        (
+            Self::R10X6G10X6B10X6A10X6_UNORM_4PACK16.0,
+            "R10X6G10X6B10X6A10X6_UNORM_4PACK16",
+            "VK_FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16",
+        ),
+        (
+            Self::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16.0,
+            "G10X6B10X6G10X6R10X6_422_UNORM_4PACK16",
+            "VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16",
+        ),
+        (
+            Self::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16.0,
+            "B10X6G10X6R10X6G10X6_422_UNORM_4PACK16",
+            "VK_FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16",
+        ),
+        (
+            Self::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16.0,
+            "G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16",
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16",
+        ),
+        (
+            Self::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16.0,
+            "G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16",
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16",
+        ),
+        (
+            Self::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16.0,
+            "G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16",
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16",
+        ),
+        (
+            Self::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16.0,
+            "G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16",
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16",
+        ),
+        (
+            Self::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16.0,
+            "G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16",
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16",
+        ),
+        (
+            Self::R12X4_UNORM_PACK16.0,
+            "R12X4_UNORM_PACK16",
+            "VK_FORMAT_R12X4_UNORM_PACK16",
+        ),
+        (
+            Self::R12X4G12X4_UNORM_2PACK16.0,
+            "R12X4G12X4_UNORM_2PACK16",
+            "VK_FORMAT_R12X4G12X4_UNORM_2PACK16",
+        ),
+        (
+            Self::R12X4G12X4B12X4A12X4_UNORM_4PACK16.0,
+            "R12X4G12X4B12X4A12X4_UNORM_4PACK16",
+            "VK_FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16",
+        ),
+        (
+            Self::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16.0,
+            "G12X4B12X4G12X4R12X4_422_UNORM_4PACK16",
+            "VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16",
+        ),
+        (
+            Self::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16.0,
+            "B12X4G12X4R12X4G12X4_422_UNORM_4PACK16",
+            "VK_FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16",
+        ),
+        (
+            Self::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16.0,
+            "G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16",
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16",
+        ),
+        (
+            Self::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16.0,
+            "G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16",
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16",
+        ),
+        (
+            Self::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16.0,
+            "G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16",
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16",
+        ),
+        (
+            Self::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16.0,
+            "G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16",
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16",
+        ),
+        (
+            Self::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16.0,
+            "G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16",
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16",
+        ),
+        (
+            Self::G16B16G16R16_422_UNORM.0,
+            "G16B16G16R16_422_UNORM",
+            "VK_FORMAT_G16B16G16R16_422_UNORM",
+        ),
+        (
+            Self::B16G16R16G16_422_UNORM.0,
+            "B16G16R16G16_422_UNORM",
+            "VK_FORMAT_B16G16R16G16_422_UNORM",
+        ),
+        (
+            Self::G16_B16_R16_3PLANE_420_UNORM.0,
+            "G16_B16_R16_3PLANE_420_UNORM",
+            "VK_FORMAT_G16_B16_R16_3PLANE_420_UNORM",
+        ),
+        (
+            Self::G16_B16R16_2PLANE_420_UNORM.0,
+            "G16_B16R16_2PLANE_420_UNORM",
+            "VK_FORMAT_G16_B16R16_2PLANE_420_UNORM",
+        ),
+        (
+            Self::G16_B16_R16_3PLANE_422_UNORM.0,
+            "G16_B16_R16_3PLANE_422_UNORM",
+            "VK_FORMAT_G16_B16_R16_3PLANE_422_UNORM",
+        ),
+        (
+            Self::G16_B16R16_2PLANE_422_UNORM.0,
+            "G16_B16R16_2PLANE_422_UNORM",
+            "VK_FORMAT_G16_B16R16_2PLANE_422_UNORM",
+        ),
+        (
+            Self::G16_B16_R16_3PLANE_444_UNORM.0,
+            "G16_B16_R16_3PLANE_444_UNORM",
+            "VK_FORMAT_G16_B16_R16_3PLANE_444_UNORM",
+        ),
+        (
+            Self::G8_B8R8_2PLANE_444_UNORM.0,
+            "G8_B8R8_2PLANE_444_UNORM",
+            "VK_FORMAT_G8_B8R8_2PLANE_444_UNORM",
+        ),
+        (
+            Self::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16.0,
+            "G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16",
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16",
+        ),
+        (
+            Self::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16.0,
+            "G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16",
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16",
+        ),
+        (
+            Self::G16_B16R16_2PLANE_444_UNORM.0,
+            "G16_B16R16_2PLANE_444_UNORM",
+            "VK_FORMAT_G16_B16R16_2PLANE_444_UNORM",
+        ),
+        (
+            Self::A4R4G4B4_UNORM_PACK16.0,
+            "A4R4G4B4_UNORM_PACK16",
+            "VK_FORMAT_A4R4G4B4_UNORM_PACK16",
+        ),
+        (
+            Self::A4B4G4R4_UNORM_PACK16.0,
+            "A4B4G4R4_UNORM_PACK16",
+            "VK_FORMAT_A4B4G4R4_UNORM_PACK16",
+        ),
+        (
+            Self::ASTC_4X4_SFLOAT_BLOCK.0,
+            "ASTC_4X4_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_5X4_SFLOAT_BLOCK.0,
+            "ASTC_5X4_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_5x4_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_5X5_SFLOAT_BLOCK.0,
+            "ASTC_5X5_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_5x5_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_6X5_SFLOAT_BLOCK.0,
+            "ASTC_6X5_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_6x5_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_6X6_SFLOAT_BLOCK.0,
+            "ASTC_6X6_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_6x6_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_8X5_SFLOAT_BLOCK.0,
+            "ASTC_8X5_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_8x5_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_8X6_SFLOAT_BLOCK.0,
+            "ASTC_8X6_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_8x6_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_8X8_SFLOAT_BLOCK.0,
+            "ASTC_8X8_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_8x8_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_10X5_SFLOAT_BLOCK.0,
+            "ASTC_10X5_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_10x5_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_10X6_SFLOAT_BLOCK.0,
+            "ASTC_10X6_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_10x6_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_10X8_SFLOAT_BLOCK.0,
+            "ASTC_10X8_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_10x8_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_10X10_SFLOAT_BLOCK.0,
+            "ASTC_10X10_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_10x10_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_12X10_SFLOAT_BLOCK.0,
+            "ASTC_12X10_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_12x10_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_12X12_SFLOAT_BLOCK.0,
+            "ASTC_12X12_SFLOAT_BLOCK",
+            "VK_FORMAT_ASTC_12x12_SFLOAT_BLOCK",
+        ),
+        (
+            Self::ASTC_4X4_SFLOAT_BLOCK_EXT.0,
+            "ASTC_4X4_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_4x4_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_5X4_SFLOAT_BLOCK_EXT.0,
+            "ASTC_5X4_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_5x4_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_5X5_SFLOAT_BLOCK_EXT.0,
+            "ASTC_5X5_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_5x5_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_6X5_SFLOAT_BLOCK_EXT.0,
+            "ASTC_6X5_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_6x5_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_6X6_SFLOAT_BLOCK_EXT.0,
+            "ASTC_6X6_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_6x6_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_8X5_SFLOAT_BLOCK_EXT.0,
+            "ASTC_8X5_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_8x5_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_8X6_SFLOAT_BLOCK_EXT.0,
+            "ASTC_8X6_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_8x6_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_8X8_SFLOAT_BLOCK_EXT.0,
+            "ASTC_8X8_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_8x8_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_10X5_SFLOAT_BLOCK_EXT.0,
+            "ASTC_10X5_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_10x5_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_10X6_SFLOAT_BLOCK_EXT.0,
+            "ASTC_10X6_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_10x6_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_10X8_SFLOAT_BLOCK_EXT.0,
+            "ASTC_10X8_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_10x8_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_10X10_SFLOAT_BLOCK_EXT.0,
+            "ASTC_10X10_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_10x10_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_12X10_SFLOAT_BLOCK_EXT.0,
+            "ASTC_12X10_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_12x10_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::ASTC_12X12_SFLOAT_BLOCK_EXT.0,
+            "ASTC_12X12_SFLOAT_BLOCK_EXT",
+            "VK_FORMAT_ASTC_12x12_SFLOAT_BLOCK_EXT",
+        ),
+        (
+            Self::G8B8G8R8_422_UNORM_KHR.0,
+            "G8B8G8R8_422_UNORM_KHR",
+            "VK_FORMAT_G8B8G8R8_422_UNORM_KHR",
+        ),
+        (
+            Self::B8G8R8G8_422_UNORM_KHR.0,
+            "B8G8R8G8_422_UNORM_KHR",
+            "VK_FORMAT_B8G8R8G8_422_UNORM_KHR",
+        ),
+        (
+            Self::G8_B8_R8_3PLANE_420_UNORM_KHR.0,
+            "G8_B8_R8_3PLANE_420_UNORM_KHR",
+            "VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM_KHR",
+        ),
+        (
+            Self::G8_B8R8_2PLANE_420_UNORM_KHR.0,
+            "G8_B8R8_2PLANE_420_UNORM_KHR",
+            "VK_FORMAT_G8_B8R8_2PLANE_420_UNORM_KHR",
+        ),
+        (
+            Self::G8_B8_R8_3PLANE_422_UNORM_KHR.0,
+            "G8_B8_R8_3PLANE_422_UNORM_KHR",
+            "VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM_KHR",
+        ),
+        (
+            Self::G8_B8R8_2PLANE_422_UNORM_KHR.0,
+            "G8_B8R8_2PLANE_422_UNORM_KHR",
+            "VK_FORMAT_G8_B8R8_2PLANE_422_UNORM_KHR",
+        ),
+        (
+            Self::G8_B8_R8_3PLANE_444_UNORM_KHR.0,
+            "G8_B8_R8_3PLANE_444_UNORM_KHR",
+            "VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM_KHR",
+        ),
+        (
+            Self::R10X6_UNORM_PACK16_KHR.0,
+            "R10X6_UNORM_PACK16_KHR",
+            "VK_FORMAT_R10X6_UNORM_PACK16_KHR",
+        ),
+        (
+            Self::R10X6G10X6_UNORM_2PACK16_KHR.0,
+            "R10X6G10X6_UNORM_2PACK16_KHR",
+            "VK_FORMAT_R10X6G10X6_UNORM_2PACK16_KHR",
+        ),
+        (
+            Self::R10X6G10X6B10X6A10X6_UNORM_4PACK16_KHR.0,
+            "R10X6G10X6B10X6A10X6_UNORM_4PACK16_KHR",
+            "VK_FORMAT_R10X6G10X6B10X6A10X6_UNORM_4PACK16_KHR",
+        ),
+        (
+            Self::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16_KHR.0,
+            "G10X6B10X6G10X6R10X6_422_UNORM_4PACK16_KHR",
+            "VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16_KHR",
+        ),
+        (
+            Self::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16_KHR.0,
+            "B10X6G10X6R10X6G10X6_422_UNORM_4PACK16_KHR",
+            "VK_FORMAT_B10X6G10X6R10X6G10X6_422_UNORM_4PACK16_KHR",
+        ),
+        (
+            Self::G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16_KHR.0,
+            "G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16_KHR",
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16_KHR",
+        ),
+        (
+            Self::G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16_KHR.0,
+            "G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16_KHR",
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16_KHR",
+        ),
+        (
+            Self::G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16_KHR.0,
+            "G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16_KHR",
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16_KHR",
+        ),
+        (
+            Self::G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16_KHR.0,
+            "G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16_KHR",
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16_KHR",
+        ),
+        (
+            Self::G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16_KHR.0,
+            "G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16_KHR",
+            "VK_FORMAT_G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16_KHR",
+        ),
+        (
+            Self::R12X4_UNORM_PACK16_KHR.0,
+            "R12X4_UNORM_PACK16_KHR",
+            "VK_FORMAT_R12X4_UNORM_PACK16_KHR",
+        ),
+        (
+            Self::R12X4G12X4_UNORM_2PACK16_KHR.0,
+            "R12X4G12X4_UNORM_2PACK16_KHR",
+            "VK_FORMAT_R12X4G12X4_UNORM_2PACK16_KHR",
+        ),
+        (
+            Self::R12X4G12X4B12X4A12X4_UNORM_4PACK16_KHR.0,
+            "R12X4G12X4B12X4A12X4_UNORM_4PACK16_KHR",
+            "VK_FORMAT_R12X4G12X4B12X4A12X4_UNORM_4PACK16_KHR",
+        ),
+        (
+            Self::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16_KHR.0,
+            "G12X4B12X4G12X4R12X4_422_UNORM_4PACK16_KHR",
+            "VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16_KHR",
+        ),
+        (
+            Self::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16_KHR.0,
+            "B12X4G12X4R12X4G12X4_422_UNORM_4PACK16_KHR",
+            "VK_FORMAT_B12X4G12X4R12X4G12X4_422_UNORM_4PACK16_KHR",
+        ),
+        (
+            Self::G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16_KHR.0,
+            "G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16_KHR",
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16_KHR",
+        ),
+        (
+            Self::G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16_KHR.0,
+            "G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16_KHR",
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16_KHR",
+        ),
+        (
+            Self::G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16_KHR.0,
+            "G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16_KHR",
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16_KHR",
+        ),
+        (
+            Self::G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16_KHR.0,
+            "G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16_KHR",
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16_KHR",
+        ),
+        (
+            Self::G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16_KHR.0,
+            "G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16_KHR",
+            "VK_FORMAT_G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16_KHR",
+        ),
+        (
+            Self::G16B16G16R16_422_UNORM_KHR.0,
+            "G16B16G16R16_422_UNORM_KHR",
+            "VK_FORMAT_G16B16G16R16_422_UNORM_KHR",
+        ),
+        (
+            Self::B16G16R16G16_422_UNORM_KHR.0,
+            "B16G16R16G16_422_UNORM_KHR",
+            "VK_FORMAT_B16G16R16G16_422_UNORM_KHR",
+        ),
+        (
+            Self::G16_B16_R16_3PLANE_420_UNORM_KHR.0,
+            "G16_B16_R16_3PLANE_420_UNORM_KHR",
+            "VK_FORMAT_G16_B16_R16_3PLANE_420_UNORM_KHR",
+        ),
+        (
+            Self::G16_B16R16_2PLANE_420_UNORM_KHR.0,
+            "G16_B16R16_2PLANE_420_UNORM_KHR",
+            "VK_FORMAT_G16_B16R16_2PLANE_420_UNORM_KHR",
+        ),
+        (
+            Self::G16_B16_R16_3PLANE_422_UNORM_KHR.0,
+            "G16_B16_R16_3PLANE_422_UNORM_KHR",
+            "VK_FORMAT_G16_B16_R16_3PLANE_422_UNORM_KHR",
+        ),
+        (
+            Self::G16_B16R16_2PLANE_422_UNORM_KHR.0,
+            "G16_B16R16_2PLANE_422_UNORM_KHR",
+            "VK_FORMAT_G16_B16R16_2PLANE_422_UNORM_KHR",
+        ),
+        (
+            Self::G16_B16_R16_3PLANE_444_UNORM_KHR.0,
+            "G16_B16_R16_3PLANE_444_UNORM_KHR",
+            "VK_FORMAT_G16_B16_R16_3PLANE_444_UNORM_KHR",
+        ),
+        (
+            Self::G8_B8R8_2PLANE_444_UNORM_EXT.0,
+            "G8_B8R8_2PLANE_444_UNORM_EXT",
+            "VK_FORMAT_G8_B8R8_2PLANE_444_UNORM_EXT",
+        ),
+        (
+            Self::G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16_EXT.0,
+            "G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16_EXT",
+            "VK_FORMAT_G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16_EXT",
+        ),
+        (
+            Self::G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16_EXT.0,
+            "G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16_EXT",
+            "VK_FORMAT_G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16_EXT",
+        ),
+        (
+            Self::G16_B16R16_2PLANE_444_UNORM_EXT.0,
+            "G16_B16R16_2PLANE_444_UNORM_EXT",
+            "VK_FORMAT_G16_B16R16_2PLANE_444_UNORM_EXT",
+        ),
+        (
+            Self::A4R4G4B4_UNORM_PACK16_EXT.0,
+            "A4R4G4B4_UNORM_PACK16_EXT",
+            "VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT",
+        ),
+        (
+            Self::A4B4G4R4_UNORM_PACK16_EXT.0,
+            "A4B4G4R4_UNORM_PACK16_EXT",
+            "VK_FORMAT_A4B4G4R4_UNORM_PACK16_EXT",
+        ),
+    ];
+}
+impl FromStr for Format {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("Format", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for Format {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl FragmentShadingRateCombinerOpKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::KEEP.0,
+            "KEEP",
+            "VK_FRAGMENT_SHADING_RATE_COMBINER_OP_KEEP_KHR",
+        ),
+        (
+            Self::REPLACE.0,
+            "REPLACE",
+            "VK_FRAGMENT_SHADING_RATE_COMBINER_OP_REPLACE_KHR",
+        ),
+        (
+            Self::MIN.0,
+            "MIN",
+            "VK_FRAGMENT_SHADING_RATE_COMBINER_OP_MIN_KHR",
+        ),
+        (
+            Self::MAX.0,
+            "MAX",
+            "VK_FRAGMENT_SHADING_RATE_COMBINER_OP_MAX_KHR",
+        ),
+        (
+            Self::MUL.0,
+            "MUL",
+            "VK_FRAGMENT_SHADING_RATE_COMBINER_OP_MUL_KHR",
+        ),
+    ];
+}
+impl FromStr for FragmentShadingRateCombinerOpKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("FragmentShadingRateCombinerOpKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for FragmentShadingRateCombinerOpKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl FragmentShadingRateNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::TYPE_1_INVOCATION_PER_PIXEL.0,
+            "TYPE_1_INVOCATION_PER_PIXEL",
+            "VK_FRAGMENT_SHADING_RATE_1_INVOCATION_PER_PIXEL_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_1X2_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_1X2_PIXELS",
+            "VK_FRAGMENT_SHADING_RATE_1_INVOCATION_PER_1X2_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_2X1_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_2X1_PIXELS",
+            "VK_FRAGMENT_SHADING_RATE_1_INVOCATION_PER_2X1_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_2X2_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_2X2_PIXELS",
+            "VK_FRAGMENT_SHADING_RATE_1_INVOCATION_PER_2X2_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_2X4_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_2X4_PIXELS",
+            "VK_FRAGMENT_SHADING_RATE_1_INVOCATION_PER_2X4_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_4X2_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_4X2_PIXELS",
+            "VK_FRAGMENT_SHADING_RATE_1_INVOCATION_PER_4X2_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_4X4_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_4X4_PIXELS",
+            "VK_FRAGMENT_SHADING_RATE_1_INVOCATION_PER_4X4_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_2_INVOCATIONS_PER_PIXEL.0,
+            "TYPE_2_INVOCATIONS_PER_PIXEL",
+            "VK_FRAGMENT_SHADING_RATE_2_INVOCATIONS_PER_PIXEL_NV",
+        ),
+        (
+            Self::TYPE_4_INVOCATIONS_PER_PIXEL.0,
+            "TYPE_4_INVOCATIONS_PER_PIXEL",
+            "VK_FRAGMENT_SHADING_RATE_4_INVOCATIONS_PER_PIXEL_NV",
+        ),
+        (
+            Self::TYPE_8_INVOCATIONS_PER_PIXEL.0,
+            "TYPE_8_INVOCATIONS_PER_PIXEL",
+            "VK_FRAGMENT_SHADING_RATE_8_INVOCATIONS_PER_PIXEL_NV",
+        ),
+        (
+            Self::TYPE_16_INVOCATIONS_PER_PIXEL.0,
+            "TYPE_16_INVOCATIONS_PER_PIXEL",
+            "VK_FRAGMENT_SHADING_RATE_16_INVOCATIONS_PER_PIXEL_NV",
+        ),
+        (
+            Self::NO_INVOCATIONS.0,
+            "NO_INVOCATIONS",
+            "VK_FRAGMENT_SHADING_RATE_NO_INVOCATIONS_NV",
+        ),
+    ];
+}
+impl FromStr for FragmentShadingRateNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("FragmentShadingRateNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for FragmentShadingRateNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl FragmentShadingRateTypeNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::FRAGMENT_SIZE.0,
+            "FRAGMENT_SIZE",
+            "VK_FRAGMENT_SHADING_RATE_TYPE_FRAGMENT_SIZE_NV",
+        ),
+        (
+            Self::ENUMS.0,
+            "ENUMS",
+            "VK_FRAGMENT_SHADING_RATE_TYPE_ENUMS_NV",
+        ),
+    ];
+}
+impl FromStr for FragmentShadingRateTypeNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("FragmentShadingRateTypeNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for FragmentShadingRateTypeNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl FrontFace {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::COUNTER_CLOCKWISE.0,
+            "COUNTER_CLOCKWISE",
+            "VK_FRONT_FACE_COUNTER_CLOCKWISE",
+        ),
+        (Self::CLOCKWISE.0, "CLOCKWISE", "VK_FRONT_FACE_CLOCKWISE"),
+    ];
+}
+impl FromStr for FrontFace {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("FrontFace", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for FrontFace {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl FullScreenExclusiveEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::DEFAULT.0,
+            "DEFAULT",
+            "VK_FULL_SCREEN_EXCLUSIVE_DEFAULT_EXT",
+        ),
+        (
+            Self::ALLOWED.0,
+            "ALLOWED",
+            "VK_FULL_SCREEN_EXCLUSIVE_ALLOWED_EXT",
+        ),
+        (
+            Self::DISALLOWED.0,
+            "DISALLOWED",
+            "VK_FULL_SCREEN_EXCLUSIVE_DISALLOWED_EXT",
+        ),
+        (
+            Self::APPLICATION_CONTROLLED.0,
+            "APPLICATION_CONTROLLED",
+            "VK_FULL_SCREEN_EXCLUSIVE_APPLICATION_CONTROLLED_EXT",
+        ),
+    ];
+}
+impl FromStr for FullScreenExclusiveEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("FullScreenExclusiveEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for FullScreenExclusiveEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl GeometryTypeKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::TRIANGLES.0,
+            "TRIANGLES",
+            "VK_GEOMETRY_TYPE_TRIANGLES_KHR",
+        ),
+        (Self::AABBS.0, "AABBS", "VK_GEOMETRY_TYPE_AABBS_KHR"),
+        (
+            Self::INSTANCES.0,
+            "INSTANCES",
+            "VK_GEOMETRY_TYPE_INSTANCES_KHR",
+        ),
+        (
+            Self::TRIANGLES_NV.0,
+            "TRIANGLES_NV",
+            "VK_GEOMETRY_TYPE_TRIANGLES_NV",
+        ),
+        (Self::AABBS_NV.0, "AABBS_NV", "VK_GEOMETRY_TYPE_AABBS_NV"),
+    ];
+}
+impl FromStr for GeometryTypeKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("GeometryTypeKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for GeometryTypeKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageLayout {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::UNDEFINED.0, "UNDEFINED", "VK_IMAGE_LAYOUT_UNDEFINED"),
+        (Self::GENERAL.0, "GENERAL", "VK_IMAGE_LAYOUT_GENERAL"),
+        (
+            Self::COLOR_ATTACHMENT_OPTIMAL.0,
+            "COLOR_ATTACHMENT_OPTIMAL",
+            "VK_IMAGE_LAYOUT_COLOR_ATTACHMENT_OPTIMAL",
+        ),
+        (
+            Self::DEPTH_STENCIL_ATTACHMENT_OPTIMAL.0,
+            "DEPTH_STENCIL_ATTACHMENT_OPTIMAL",
+            "VK_IMAGE_LAYOUT_DEPTH_STENCIL_ATTACHMENT_OPTIMAL",
+        ),
+        (
+            Self::DEPTH_STENCIL_READ_ONLY_OPTIMAL.0,
+            "DEPTH_STENCIL_READ_ONLY_OPTIMAL",
+            "VK_IMAGE_LAYOUT_DEPTH_STENCIL_READ_ONLY_OPTIMAL",
+        ),
+        (
+            Self::SHADER_READ_ONLY_OPTIMAL.0,
+            "SHADER_READ_ONLY_OPTIMAL",
+            "VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL",
+        ),
+        (
+            Self::TRANSFER_SRC_OPTIMAL.0,
+            "TRANSFER_SRC_OPTIMAL",
+            "VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL",
+        ),
+        (
+            Self::TRANSFER_DST_OPTIMAL.0,
+            "TRANSFER_DST_OPTIMAL",
+            "VK_IMAGE_LAYOUT_TRANSFER_DST_OPTIMAL",
+        ),
+        (
+            Self::PREINITIALIZED.0,
+            "PREINITIALIZED",
+            "VK_IMAGE_LAYOUT_PREINITIALIZED",
+        ),
+        (
+            Self::PRESENT_SRC_KHR.0,
+            "PRESENT_SRC_KHR",
+            "VK_IMAGE_LAYOUT_PRESENT_SRC_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_DST_KHR.0,
+            "VIDEO_DECODE_DST_KHR",
+            "VK_IMAGE_LAYOUT_VIDEO_DECODE_DST_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_SRC_KHR.0,
+            "VIDEO_DECODE_SRC_KHR",
+            "VK_IMAGE_LAYOUT_VIDEO_DECODE_SRC_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_DPB_KHR.0,
+            "VIDEO_DECODE_DPB_KHR",
+            "VK_IMAGE_LAYOUT_VIDEO_DECODE_DPB_KHR",
+        ),
+        (
+            Self::SHARED_PRESENT_KHR.0,
+            "SHARED_PRESENT_KHR",
+            "VK_IMAGE_LAYOUT_SHARED_PRESENT_KHR",
+        ),
+        (
+            Self::FRAGMENT_DENSITY_MAP_OPTIMAL_EXT.0,
+            "FRAGMENT_DENSITY_MAP_OPTIMAL_EXT",
+            "VK_IMAGE_LAYOUT_FRAGMENT_DENSITY_MAP_OPTIMAL_EXT",
+        ),
+        (
+            Self::FRAGMENT_SHADING_RATE_ATTACHMENT_OPTIMAL_KHR.0,
+            "FRAGMENT_SHADING_RATE_ATTACHMENT_OPTIMAL_KHR",
+            "VK_IMAGE_LAYOUT_FRAGMENT_SHADING_RATE_ATTACHMENT_OPTIMAL_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_DST_KHR.0,
+            "VIDEO_ENCODE_DST_KHR",
+            "VK_IMAGE_LAYOUT_VIDEO_ENCODE_DST_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_SRC_KHR.0,
+            "VIDEO_ENCODE_SRC_KHR",
+            "VK_IMAGE_LAYOUT_VIDEO_ENCODE_SRC_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_DPB_KHR.0,
+            "VIDEO_ENCODE_DPB_KHR",
+            "VK_IMAGE_LAYOUT_VIDEO_ENCODE_DPB_KHR",
+        ),
+        (
+            Self::ATTACHMENT_FEEDBACK_LOOP_OPTIMAL_EXT.0,
+            "ATTACHMENT_FEEDBACK_LOOP_OPTIMAL_EXT",
+            "VK_IMAGE_LAYOUT_ATTACHMENT_FEEDBACK_LOOP_OPTIMAL_EXT",
+        ),
+        (
+            Self::DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL.0,
+            "DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL",
+            "VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL",
+        ),
+        (
+            Self::DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL.0,
+            "DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL",
+            "VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL",
+        ),
+        (
+            Self::DEPTH_ATTACHMENT_OPTIMAL.0,
+            "DEPTH_ATTACHMENT_OPTIMAL",
+            "VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_OPTIMAL",
+        ),
+        (
+            Self::DEPTH_READ_ONLY_OPTIMAL.0,
+            "DEPTH_READ_ONLY_OPTIMAL",
+            "VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_OPTIMAL",
+        ),
+        (
+            Self::STENCIL_ATTACHMENT_OPTIMAL.0,
+            "STENCIL_ATTACHMENT_OPTIMAL",
+            "VK_IMAGE_LAYOUT_STENCIL_ATTACHMENT_OPTIMAL",
+        ),
+        (
+            Self::STENCIL_READ_ONLY_OPTIMAL.0,
+            "STENCIL_READ_ONLY_OPTIMAL",
+            "VK_IMAGE_LAYOUT_STENCIL_READ_ONLY_OPTIMAL",
+        ),
+        (
+            Self::READ_ONLY_OPTIMAL.0,
+            "READ_ONLY_OPTIMAL",
+            "VK_IMAGE_LAYOUT_READ_ONLY_OPTIMAL",
+        ),
+        (
+            Self::ATTACHMENT_OPTIMAL.0,
+            "ATTACHMENT_OPTIMAL",
+            "VK_IMAGE_LAYOUT_ATTACHMENT_OPTIMAL",
+        ),
+        (
+            Self::DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL_KHR.0,
+            "DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL_KHR",
+            "VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL_KHR",
+        ),
+        (
+            Self::DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL_KHR.0,
+            "DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL_KHR",
+            "VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL_KHR",
+        ),
+        (
+            Self::SHADING_RATE_OPTIMAL_NV.0,
+            "SHADING_RATE_OPTIMAL_NV",
+            "VK_IMAGE_LAYOUT_SHADING_RATE_OPTIMAL_NV",
+        ),
+        (
+            Self::DEPTH_ATTACHMENT_OPTIMAL_KHR.0,
+            "DEPTH_ATTACHMENT_OPTIMAL_KHR",
+            "VK_IMAGE_LAYOUT_DEPTH_ATTACHMENT_OPTIMAL_KHR",
+        ),
+        (
+            Self::DEPTH_READ_ONLY_OPTIMAL_KHR.0,
+            "DEPTH_READ_ONLY_OPTIMAL_KHR",
+            "VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_OPTIMAL_KHR",
+        ),
+        (
+            Self::STENCIL_ATTACHMENT_OPTIMAL_KHR.0,
+            "STENCIL_ATTACHMENT_OPTIMAL_KHR",
+            "VK_IMAGE_LAYOUT_STENCIL_ATTACHMENT_OPTIMAL_KHR",
+        ),
+        (
+            Self::STENCIL_READ_ONLY_OPTIMAL_KHR.0,
+            "STENCIL_READ_ONLY_OPTIMAL_KHR",
+            "VK_IMAGE_LAYOUT_STENCIL_READ_ONLY_OPTIMAL_KHR",
+        ),
+        (
+            Self::READ_ONLY_OPTIMAL_KHR.0,
+            "READ_ONLY_OPTIMAL_KHR",
+            "VK_IMAGE_LAYOUT_READ_ONLY_OPTIMAL_KHR",
+        ),
+        (
+            Self::ATTACHMENT_OPTIMAL_KHR.0,
+            "ATTACHMENT_OPTIMAL_KHR",
+            "VK_IMAGE_LAYOUT_ATTACHMENT_OPTIMAL_KHR",
+        ),
+    ];
+}
+impl FromStr for ImageLayout {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ImageLayout", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageLayout {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageTiling {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::OPTIMAL.0, "OPTIMAL", "VK_IMAGE_TILING_OPTIMAL"),
+        (Self::LINEAR.0, "LINEAR", "VK_IMAGE_TILING_LINEAR"),
+        (
+            Self::DRM_FORMAT_MODIFIER_EXT.0,
+            "DRM_FORMAT_MODIFIER_EXT",
+            "VK_IMAGE_TILING_DRM_FORMAT_MODIFIER_EXT",
+        ),
+    ];
+}
+impl FromStr for ImageTiling {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ImageTiling", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageTiling {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageType {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::TYPE_1D.0, "TYPE_1D", "VK_IMAGE_TYPE_1D"),
+        (Self::TYPE_2D.0, "TYPE_2D", "VK_IMAGE_TYPE_2D"),
+        (Self::TYPE_3D.0, "TYPE_3D", "VK_IMAGE_TYPE_3D"),
+    ];
+}
+impl FromStr for ImageType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ImageType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ImageViewType {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::TYPE_1D.0, "TYPE_1D", "VK_IMAGE_VIEW_TYPE_1D"),
+        (Self::TYPE_2D.0, "TYPE_2D", "VK_IMAGE_VIEW_TYPE_2D"),
+        (Self::TYPE_3D.0, "TYPE_3D", "VK_IMAGE_VIEW_TYPE_3D"),
+        (Self::CUBE.0, "CUBE", "VK_IMAGE_VIEW_TYPE_CUBE"),
+        (
+            Self::TYPE_1D_ARRAY.0,
+            "TYPE_1D_ARRAY",
+            "VK_IMAGE_VIEW_TYPE_1D_ARRAY",
+        ),
+        (
+            Self::TYPE_2D_ARRAY.0,
+            "TYPE_2D_ARRAY",
+            "VK_IMAGE_VIEW_TYPE_2D_ARRAY",
+        ),
+        (
+            Self::CUBE_ARRAY.0,
+            "CUBE_ARRAY",
+            "VK_IMAGE_VIEW_TYPE_CUBE_ARRAY",
+        ),
+    ];
+}
+impl FromStr for ImageViewType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ImageViewType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ImageViewType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl IndexType {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::UINT16.0, "UINT16", "VK_INDEX_TYPE_UINT16"),
+        (Self::UINT32.0, "UINT32", "VK_INDEX_TYPE_UINT32"),
+        (Self::NONE_KHR.0, "NONE_KHR", "VK_INDEX_TYPE_NONE_KHR"),
+        (Self::UINT8_EXT.0, "UINT8_EXT", "VK_INDEX_TYPE_UINT8_EXT"),
+        (Self::NONE_NV.0, "NONE_NV", "VK_INDEX_TYPE_NONE_NV"),
+    ];
+}
+impl FromStr for IndexType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("IndexType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for IndexType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl IndirectCommandsTokenTypeNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::SHADER_GROUP.0,
+            "SHADER_GROUP",
+            "VK_INDIRECT_COMMANDS_TOKEN_TYPE_SHADER_GROUP_NV",
+        ),
+        (
+            Self::STATE_FLAGS.0,
+            "STATE_FLAGS",
+            "VK_INDIRECT_COMMANDS_TOKEN_TYPE_STATE_FLAGS_NV",
+        ),
+        (
+            Self::INDEX_BUFFER.0,
+            "INDEX_BUFFER",
+            "VK_INDIRECT_COMMANDS_TOKEN_TYPE_INDEX_BUFFER_NV",
+        ),
+        (
+            Self::VERTEX_BUFFER.0,
+            "VERTEX_BUFFER",
+            "VK_INDIRECT_COMMANDS_TOKEN_TYPE_VERTEX_BUFFER_NV",
+        ),
+        (
+            Self::PUSH_CONSTANT.0,
+            "PUSH_CONSTANT",
+            "VK_INDIRECT_COMMANDS_TOKEN_TYPE_PUSH_CONSTANT_NV",
+        ),
+        (
+            Self::DRAW_INDEXED.0,
+            "DRAW_INDEXED",
+            "VK_INDIRECT_COMMANDS_TOKEN_TYPE_DRAW_INDEXED_NV",
+        ),
+        (
+            Self::DRAW.0,
+            "DRAW",
+            "VK_INDIRECT_COMMANDS_TOKEN_TYPE_DRAW_NV",
+        ),
+        (
+            Self::DRAW_TASKS.0,
+            "DRAW_TASKS",
+            "VK_INDIRECT_COMMANDS_TOKEN_TYPE_DRAW_TASKS_NV",
+        ),
+        (
+            Self::DRAW_MESH_TASKS.0,
+            "DRAW_MESH_TASKS",
+            "VK_INDIRECT_COMMANDS_TOKEN_TYPE_DRAW_MESH_TASKS_NV",
+        ),
+    ];
+}
+impl FromStr for IndirectCommandsTokenTypeNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("IndirectCommandsTokenTypeNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for IndirectCommandsTokenTypeNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl InternalAllocationType {
+    pub(crate) const KNOWN: Known<i32> = &[(
+        Self::EXECUTABLE.0,
+        "EXECUTABLE",
+        "VK_INTERNAL_ALLOCATION_TYPE_EXECUTABLE",
+    )];
+}
+impl FromStr for InternalAllocationType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("InternalAllocationType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for InternalAllocationType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl LineRasterizationModeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::DEFAULT.0,
+            "DEFAULT",
+            "VK_LINE_RASTERIZATION_MODE_DEFAULT_EXT",
+        ),
+        (
+            Self::RECTANGULAR.0,
+            "RECTANGULAR",
+            "VK_LINE_RASTERIZATION_MODE_RECTANGULAR_EXT",
+        ),
+        (
+            Self::BRESENHAM.0,
+            "BRESENHAM",
+            "VK_LINE_RASTERIZATION_MODE_BRESENHAM_EXT",
+        ),
+        (
+            Self::RECTANGULAR_SMOOTH.0,
+            "RECTANGULAR_SMOOTH",
+            "VK_LINE_RASTERIZATION_MODE_RECTANGULAR_SMOOTH_EXT",
+        ),
+    ];
+}
+impl FromStr for LineRasterizationModeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("LineRasterizationModeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for LineRasterizationModeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl LogicOp {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::CLEAR.0, "CLEAR", "VK_LOGIC_OP_CLEAR"),
+        (Self::AND.0, "AND", "VK_LOGIC_OP_AND"),
+        (
+            Self::AND_REVERSE.0,
+            "AND_REVERSE",
+            "VK_LOGIC_OP_AND_REVERSE",
+        ),
+        (Self::COPY.0, "COPY", "VK_LOGIC_OP_COPY"),
+        (
+            Self::AND_INVERTED.0,
+            "AND_INVERTED",
+            "VK_LOGIC_OP_AND_INVERTED",
+        ),
+        (Self::NO_OP.0, "NO_OP", "VK_LOGIC_OP_NO_OP"),
+        (Self::XOR.0, "XOR", "VK_LOGIC_OP_XOR"),
+        (Self::OR.0, "OR", "VK_LOGIC_OP_OR"),
+        (Self::NOR.0, "NOR", "VK_LOGIC_OP_NOR"),
+        (Self::EQUIVALENT.0, "EQUIVALENT", "VK_LOGIC_OP_EQUIVALENT"),
+        (Self::INVERT.0, "INVERT", "VK_LOGIC_OP_INVERT"),
+        (Self::OR_REVERSE.0, "OR_REVERSE", "VK_LOGIC_OP_OR_REVERSE"),
+        (
+            Self::COPY_INVERTED.0,
+            "COPY_INVERTED",
+            "VK_LOGIC_OP_COPY_INVERTED",
+        ),
+        (
+            Self::OR_INVERTED.0,
+            "OR_INVERTED",
+            "VK_LOGIC_OP_OR_INVERTED",
+        ),
+        (Self::NAND.0, "NAND", "VK_LOGIC_OP_NAND"),
+        (Self::SET.0, "SET", "VK_LOGIC_OP_SET"),
+    ];
+}
+impl FromStr for LogicOp {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("LogicOp", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for LogicOp {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MemoryOverallocationBehaviorAMD {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::DEFAULT.0,
+            "DEFAULT",
+            "VK_MEMORY_OVERALLOCATION_BEHAVIOR_DEFAULT_AMD",
+        ),
+        (
+            Self::ALLOWED.0,
+            "ALLOWED",
+            "VK_MEMORY_OVERALLOCATION_BEHAVIOR_ALLOWED_AMD",
+        ),
+        (
+            Self::DISALLOWED.0,
+            "DISALLOWED",
+            "VK_MEMORY_OVERALLOCATION_BEHAVIOR_DISALLOWED_AMD",
+        ),
+    ];
+}
+impl FromStr for MemoryOverallocationBehaviorAMD {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("MemoryOverallocationBehaviorAMD", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MemoryOverallocationBehaviorAMD {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl MicromapTypeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::OPACITY_MICROMAP.0,
+            "OPACITY_MICROMAP",
+            "VK_MICROMAP_TYPE_OPACITY_MICROMAP_EXT",
+        ),
+        (
+            Self::DISPLACEMENT_MICROMAP_NV.0,
+            "DISPLACEMENT_MICROMAP_NV",
+            "VK_MICROMAP_TYPE_DISPLACEMENT_MICROMAP_NV",
+        ),
+    ];
+}
+impl FromStr for MicromapTypeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("MicromapTypeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for MicromapTypeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ObjectType {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::UNKNOWN.0, "UNKNOWN", "VK_OBJECT_TYPE_UNKNOWN"),
+        (Self::INSTANCE.0, "INSTANCE", "VK_OBJECT_TYPE_INSTANCE"),
+        (
+            Self::PHYSICAL_DEVICE.0,
+            "PHYSICAL_DEVICE",
+            "VK_OBJECT_TYPE_PHYSICAL_DEVICE",
+        ),
+        (Self::DEVICE.0, "DEVICE", "VK_OBJECT_TYPE_DEVICE"),
+        (Self::QUEUE.0, "QUEUE", "VK_OBJECT_TYPE_QUEUE"),
+        (Self::SEMAPHORE.0, "SEMAPHORE", "VK_OBJECT_TYPE_SEMAPHORE"),
+        (
+            Self::COMMAND_BUFFER.0,
+            "COMMAND_BUFFER",
+            "VK_OBJECT_TYPE_COMMAND_BUFFER",
+        ),
+        (Self::FENCE.0, "FENCE", "VK_OBJECT_TYPE_FENCE"),
+        (
+            Self::DEVICE_MEMORY.0,
+            "DEVICE_MEMORY",
+            "VK_OBJECT_TYPE_DEVICE_MEMORY",
+        ),
+        (Self::BUFFER.0, "BUFFER", "VK_OBJECT_TYPE_BUFFER"),
+        (Self::IMAGE.0, "IMAGE", "VK_OBJECT_TYPE_IMAGE"),
+        (Self::EVENT.0, "EVENT", "VK_OBJECT_TYPE_EVENT"),
+        (
+            Self::QUERY_POOL.0,
+            "QUERY_POOL",
+            "VK_OBJECT_TYPE_QUERY_POOL",
+        ),
+        (
+            Self::BUFFER_VIEW.0,
+            "BUFFER_VIEW",
+            "VK_OBJECT_TYPE_BUFFER_VIEW",
+        ),
+        (
+            Self::IMAGE_VIEW.0,
+            "IMAGE_VIEW",
+            "VK_OBJECT_TYPE_IMAGE_VIEW",
+        ),
+        (
+            Self::SHADER_MODULE.0,
+            "SHADER_MODULE",
+            "VK_OBJECT_TYPE_SHADER_MODULE",
+        ),
+        (
+            Self::PIPELINE_CACHE.0,
+            "PIPELINE_CACHE",
+            "VK_OBJECT_TYPE_PIPELINE_CACHE",
+        ),
+        (
+            Self::PIPELINE_LAYOUT.0,
+            "PIPELINE_LAYOUT",
+            "VK_OBJECT_TYPE_PIPELINE_LAYOUT",
+        ),
+        (
+            Self::RENDER_PASS.0,
+            "RENDER_PASS",
+            "VK_OBJECT_TYPE_RENDER_PASS",
+        ),
+        (Self::PIPELINE.0, "PIPELINE", "VK_OBJECT_TYPE_PIPELINE"),
+        (
+            Self::DESCRIPTOR_SET_LAYOUT.0,
+            "DESCRIPTOR_SET_LAYOUT",
+            "VK_OBJECT_TYPE_DESCRIPTOR_SET_LAYOUT",
+        ),
+        (Self::SAMPLER.0, "SAMPLER", "VK_OBJECT_TYPE_SAMPLER"),
+        (
+            Self::DESCRIPTOR_POOL.0,
+            "DESCRIPTOR_POOL",
+            "VK_OBJECT_TYPE_DESCRIPTOR_POOL",
+        ),
+        (
+            Self::DESCRIPTOR_SET.0,
+            "DESCRIPTOR_SET",
+            "VK_OBJECT_TYPE_DESCRIPTOR_SET",
+        ),
+        (
+            Self::FRAMEBUFFER.0,
+            "FRAMEBUFFER",
+            "VK_OBJECT_TYPE_FRAMEBUFFER",
+        ),
+        (
+            Self::COMMAND_POOL.0,
+            "COMMAND_POOL",
+            "VK_OBJECT_TYPE_COMMAND_POOL",
+        ),
+        (
+            Self::SURFACE_KHR.0,
+            "SURFACE_KHR",
+            "VK_OBJECT_TYPE_SURFACE_KHR",
+        ),
+        (
+            Self::SWAPCHAIN_KHR.0,
+            "SWAPCHAIN_KHR",
+            "VK_OBJECT_TYPE_SWAPCHAIN_KHR",
+        ),
+        (
+            Self::DISPLAY_KHR.0,
+            "DISPLAY_KHR",
+            "VK_OBJECT_TYPE_DISPLAY_KHR",
+        ),
+        (
+            Self::DISPLAY_MODE_KHR.0,
+            "DISPLAY_MODE_KHR",
+            "VK_OBJECT_TYPE_DISPLAY_MODE_KHR",
+        ),
+        (
+            Self::DEBUG_REPORT_CALLBACK_EXT.0,
+            "DEBUG_REPORT_CALLBACK_EXT",
+            "VK_OBJECT_TYPE_DEBUG_REPORT_CALLBACK_EXT",
+        ),
+        (
+            Self::VIDEO_SESSION_KHR.0,
+            "VIDEO_SESSION_KHR",
+            "VK_OBJECT_TYPE_VIDEO_SESSION_KHR",
+        ),
+        (
+            Self::VIDEO_SESSION_PARAMETERS_KHR.0,
+            "VIDEO_SESSION_PARAMETERS_KHR",
+            "VK_OBJECT_TYPE_VIDEO_SESSION_PARAMETERS_KHR",
+        ),
+        (
+            Self::CU_MODULE_NVX.0,
+            "CU_MODULE_NVX",
+            "VK_OBJECT_TYPE_CU_MODULE_NVX",
+        ),
+        (
+            Self::CU_FUNCTION_NVX.0,
+            "CU_FUNCTION_NVX",
+            "VK_OBJECT_TYPE_CU_FUNCTION_NVX",
+        ),
+        (
+            Self::DEBUG_UTILS_MESSENGER_EXT.0,
+            "DEBUG_UTILS_MESSENGER_EXT",
+            "VK_OBJECT_TYPE_DEBUG_UTILS_MESSENGER_EXT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_KHR.0,
+            "ACCELERATION_STRUCTURE_KHR",
+            "VK_OBJECT_TYPE_ACCELERATION_STRUCTURE_KHR",
+        ),
+        (
+            Self::VALIDATION_CACHE_EXT.0,
+            "VALIDATION_CACHE_EXT",
+            "VK_OBJECT_TYPE_VALIDATION_CACHE_EXT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_NV.0,
+            "ACCELERATION_STRUCTURE_NV",
+            "VK_OBJECT_TYPE_ACCELERATION_STRUCTURE_NV",
+        ),
+        (
+            Self::PERFORMANCE_CONFIGURATION_INTEL.0,
+            "PERFORMANCE_CONFIGURATION_INTEL",
+            "VK_OBJECT_TYPE_PERFORMANCE_CONFIGURATION_INTEL",
+        ),
+        (
+            Self::DEFERRED_OPERATION_KHR.0,
+            "DEFERRED_OPERATION_KHR",
+            "VK_OBJECT_TYPE_DEFERRED_OPERATION_KHR",
+        ),
+        (
+            Self::INDIRECT_COMMANDS_LAYOUT_NV.0,
+            "INDIRECT_COMMANDS_LAYOUT_NV",
+            "VK_OBJECT_TYPE_INDIRECT_COMMANDS_LAYOUT_NV",
+        ),
+        (
+            Self::BUFFER_COLLECTION_FUCHSIA.0,
+            "BUFFER_COLLECTION_FUCHSIA",
+            "VK_OBJECT_TYPE_BUFFER_COLLECTION_FUCHSIA",
+        ),
+        (
+            Self::MICROMAP_EXT.0,
+            "MICROMAP_EXT",
+            "VK_OBJECT_TYPE_MICROMAP_EXT",
+        ),
+        (
+            Self::OPTICAL_FLOW_SESSION_NV.0,
+            "OPTICAL_FLOW_SESSION_NV",
+            "VK_OBJECT_TYPE_OPTICAL_FLOW_SESSION_NV",
+        ),
+        (
+            Self::SHADER_EXT.0,
+            "SHADER_EXT",
+            "VK_OBJECT_TYPE_SHADER_EXT",
+        ),
+        (
+            Self::SAMPLER_YCBCR_CONVERSION.0,
+            "SAMPLER_YCBCR_CONVERSION",
+            "VK_OBJECT_TYPE_SAMPLER_YCBCR_CONVERSION",
+        ),
+        (
+            Self::DESCRIPTOR_UPDATE_TEMPLATE.0,
+            "DESCRIPTOR_UPDATE_TEMPLATE",
+            "VK_OBJECT_TYPE_DESCRIPTOR_UPDATE_TEMPLATE",
+        ),
+        (
+            Self::PRIVATE_DATA_SLOT.0,
+            "PRIVATE_DATA_SLOT",
+            "VK_OBJECT_TYPE_PRIVATE_DATA_SLOT",
+        ),
+        (
+            Self::DESCRIPTOR_UPDATE_TEMPLATE_KHR.0,
+            "DESCRIPTOR_UPDATE_TEMPLATE_KHR",
+            "VK_OBJECT_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_KHR",
+        ),
+        (
+            Self::SAMPLER_YCBCR_CONVERSION_KHR.0,
+            "SAMPLER_YCBCR_CONVERSION_KHR",
+            "VK_OBJECT_TYPE_SAMPLER_YCBCR_CONVERSION_KHR",
+        ),
+        (
+            Self::PRIVATE_DATA_SLOT_EXT.0,
+            "PRIVATE_DATA_SLOT_EXT",
+            "VK_OBJECT_TYPE_PRIVATE_DATA_SLOT_EXT",
+        ),
+    ];
+}
+impl FromStr for ObjectType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ObjectType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ObjectType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl OpacityMicromapFormatEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::TYPE_2_STATE.0,
+            "TYPE_2_STATE",
+            "VK_OPACITY_MICROMAP_FORMAT_2_STATE_EXT",
+        ),
+        (
+            Self::TYPE_4_STATE.0,
+            "TYPE_4_STATE",
+            "VK_OPACITY_MICROMAP_FORMAT_4_STATE_EXT",
+        ),
+    ];
+}
+impl FromStr for OpacityMicromapFormatEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("OpacityMicromapFormatEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for OpacityMicromapFormatEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl OpacityMicromapSpecialIndexEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::FULLY_TRANSPARENT.0,
+            "FULLY_TRANSPARENT",
+            "VK_OPACITY_MICROMAP_SPECIAL_INDEX_FULLY_TRANSPARENT_EXT",
+        ),
+        (
+            Self::FULLY_OPAQUE.0,
+            "FULLY_OPAQUE",
+            "VK_OPACITY_MICROMAP_SPECIAL_INDEX_FULLY_OPAQUE_EXT",
+        ),
+        (
+            Self::FULLY_UNKNOWN_TRANSPARENT.0,
+            "FULLY_UNKNOWN_TRANSPARENT",
+            "VK_OPACITY_MICROMAP_SPECIAL_INDEX_FULLY_UNKNOWN_TRANSPARENT_EXT",
+        ),
+        (
+            Self::FULLY_UNKNOWN_OPAQUE.0,
+            "FULLY_UNKNOWN_OPAQUE",
+            "VK_OPACITY_MICROMAP_SPECIAL_INDEX_FULLY_UNKNOWN_OPAQUE_EXT",
+        ),
+    ];
+}
+impl FromStr for OpacityMicromapSpecialIndexEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("OpacityMicromapSpecialIndexEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for OpacityMicromapSpecialIndexEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl OpticalFlowPerformanceLevelNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::UNKNOWN.0,
+            "UNKNOWN",
+            "VK_OPTICAL_FLOW_PERFORMANCE_LEVEL_UNKNOWN_NV",
+        ),
+        (
+            Self::SLOW.0,
+            "SLOW",
+            "VK_OPTICAL_FLOW_PERFORMANCE_LEVEL_SLOW_NV",
+        ),
+        (
+            Self::MEDIUM.0,
+            "MEDIUM",
+            "VK_OPTICAL_FLOW_PERFORMANCE_LEVEL_MEDIUM_NV",
+        ),
+        (
+            Self::FAST.0,
+            "FAST",
+            "VK_OPTICAL_FLOW_PERFORMANCE_LEVEL_FAST_NV",
+        ),
+    ];
+}
+impl FromStr for OpticalFlowPerformanceLevelNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("OpticalFlowPerformanceLevelNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for OpticalFlowPerformanceLevelNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl OpticalFlowSessionBindingPointNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::UNKNOWN.0,
+            "UNKNOWN",
+            "VK_OPTICAL_FLOW_SESSION_BINDING_POINT_UNKNOWN_NV",
+        ),
+        (
+            Self::INPUT.0,
+            "INPUT",
+            "VK_OPTICAL_FLOW_SESSION_BINDING_POINT_INPUT_NV",
+        ),
+        (
+            Self::REFERENCE.0,
+            "REFERENCE",
+            "VK_OPTICAL_FLOW_SESSION_BINDING_POINT_REFERENCE_NV",
+        ),
+        (
+            Self::HINT.0,
+            "HINT",
+            "VK_OPTICAL_FLOW_SESSION_BINDING_POINT_HINT_NV",
+        ),
+        (
+            Self::FLOW_VECTOR.0,
+            "FLOW_VECTOR",
+            "VK_OPTICAL_FLOW_SESSION_BINDING_POINT_FLOW_VECTOR_NV",
+        ),
+        (
+            Self::BACKWARD_FLOW_VECTOR.0,
+            "BACKWARD_FLOW_VECTOR",
+            "VK_OPTICAL_FLOW_SESSION_BINDING_POINT_BACKWARD_FLOW_VECTOR_NV",
+        ),
+        (
+            Self::COST.0,
+            "COST",
+            "VK_OPTICAL_FLOW_SESSION_BINDING_POINT_COST_NV",
+        ),
+        (
+            Self::BACKWARD_COST.0,
+            "BACKWARD_COST",
+            "VK_OPTICAL_FLOW_SESSION_BINDING_POINT_BACKWARD_COST_NV",
+        ),
+        (
+            Self::GLOBAL_FLOW.0,
+            "GLOBAL_FLOW",
+            "VK_OPTICAL_FLOW_SESSION_BINDING_POINT_GLOBAL_FLOW_NV",
+        ),
+    ];
+}
+impl FromStr for OpticalFlowSessionBindingPointNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("OpticalFlowSessionBindingPointNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for OpticalFlowSessionBindingPointNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PerformanceConfigurationTypeINTEL {
+    pub(crate) const KNOWN: Known<i32> = &[(
+        Self::COMMAND_QUEUE_METRICS_DISCOVERY_ACTIVATED.0,
+        "COMMAND_QUEUE_METRICS_DISCOVERY_ACTIVATED",
+        "VK_PERFORMANCE_CONFIGURATION_TYPE_COMMAND_QUEUE_METRICS_DISCOVERY_ACTIVATED_INTEL",
+    )];
+}
+impl FromStr for PerformanceConfigurationTypeINTEL {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PerformanceConfigurationTypeINTEL", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PerformanceConfigurationTypeINTEL {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PerformanceCounterScopeKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::COMMAND_BUFFER.0,
+            "COMMAND_BUFFER",
+            "VK_PERFORMANCE_COUNTER_SCOPE_COMMAND_BUFFER_KHR",
+        ),
+        (
+            Self::RENDER_PASS.0,
+            "RENDER_PASS",
+            "VK_PERFORMANCE_COUNTER_SCOPE_RENDER_PASS_KHR",
+        ),
+        (
+            Self::COMMAND.0,
+            "COMMAND",
+            "VK_PERFORMANCE_COUNTER_SCOPE_COMMAND_KHR",
+        ),
+    ];
+}
+impl FromStr for PerformanceCounterScopeKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PerformanceCounterScopeKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PerformanceCounterScopeKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PerformanceCounterStorageKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::INT32.0,
+            "INT32",
+            "VK_PERFORMANCE_COUNTER_STORAGE_INT32_KHR",
+        ),
+        (
+            Self::INT64.0,
+            "INT64",
+            "VK_PERFORMANCE_COUNTER_STORAGE_INT64_KHR",
+        ),
+        (
+            Self::UINT32.0,
+            "UINT32",
+            "VK_PERFORMANCE_COUNTER_STORAGE_UINT32_KHR",
+        ),
+        (
+            Self::UINT64.0,
+            "UINT64",
+            "VK_PERFORMANCE_COUNTER_STORAGE_UINT64_KHR",
+        ),
+        (
+            Self::FLOAT32.0,
+            "FLOAT32",
+            "VK_PERFORMANCE_COUNTER_STORAGE_FLOAT32_KHR",
+        ),
+        (
+            Self::FLOAT64.0,
+            "FLOAT64",
+            "VK_PERFORMANCE_COUNTER_STORAGE_FLOAT64_KHR",
+        ),
+    ];
+}
+impl FromStr for PerformanceCounterStorageKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PerformanceCounterStorageKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PerformanceCounterStorageKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PerformanceCounterUnitKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::GENERIC.0,
+            "GENERIC",
+            "VK_PERFORMANCE_COUNTER_UNIT_GENERIC_KHR",
+        ),
+        (
+            Self::PERCENTAGE.0,
+            "PERCENTAGE",
+            "VK_PERFORMANCE_COUNTER_UNIT_PERCENTAGE_KHR",
+        ),
+        (
+            Self::NANOSECONDS.0,
+            "NANOSECONDS",
+            "VK_PERFORMANCE_COUNTER_UNIT_NANOSECONDS_KHR",
+        ),
+        (
+            Self::BYTES.0,
+            "BYTES",
+            "VK_PERFORMANCE_COUNTER_UNIT_BYTES_KHR",
+        ),
+        (
+            Self::BYTES_PER_SECOND.0,
+            "BYTES_PER_SECOND",
+            "VK_PERFORMANCE_COUNTER_UNIT_BYTES_PER_SECOND_KHR",
+        ),
+        (
+            Self::KELVIN.0,
+            "KELVIN",
+            "VK_PERFORMANCE_COUNTER_UNIT_KELVIN_KHR",
+        ),
+        (
+            Self::WATTS.0,
+            "WATTS",
+            "VK_PERFORMANCE_COUNTER_UNIT_WATTS_KHR",
+        ),
+        (
+            Self::VOLTS.0,
+            "VOLTS",
+            "VK_PERFORMANCE_COUNTER_UNIT_VOLTS_KHR",
+        ),
+        (Self::AMPS.0, "AMPS", "VK_PERFORMANCE_COUNTER_UNIT_AMPS_KHR"),
+        (
+            Self::HERTZ.0,
+            "HERTZ",
+            "VK_PERFORMANCE_COUNTER_UNIT_HERTZ_KHR",
+        ),
+        (
+            Self::CYCLES.0,
+            "CYCLES",
+            "VK_PERFORMANCE_COUNTER_UNIT_CYCLES_KHR",
+        ),
+    ];
+}
+impl FromStr for PerformanceCounterUnitKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PerformanceCounterUnitKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PerformanceCounterUnitKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PerformanceOverrideTypeINTEL {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::NULL_HARDWARE.0,
+            "NULL_HARDWARE",
+            "VK_PERFORMANCE_OVERRIDE_TYPE_NULL_HARDWARE_INTEL",
+        ),
+        (
+            Self::FLUSH_GPU_CACHES.0,
+            "FLUSH_GPU_CACHES",
+            "VK_PERFORMANCE_OVERRIDE_TYPE_FLUSH_GPU_CACHES_INTEL",
+        ),
+    ];
+}
+impl FromStr for PerformanceOverrideTypeINTEL {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PerformanceOverrideTypeINTEL", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PerformanceOverrideTypeINTEL {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PerformanceParameterTypeINTEL {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::HW_COUNTERS_SUPPORTED.0,
+            "HW_COUNTERS_SUPPORTED",
+            "VK_PERFORMANCE_PARAMETER_TYPE_HW_COUNTERS_SUPPORTED_INTEL",
+        ),
+        (
+            Self::STREAM_MARKER_VALIDS.0,
+            "STREAM_MARKER_VALIDS",
+            "VK_PERFORMANCE_PARAMETER_TYPE_STREAM_MARKER_VALID_BITS_INTEL",
+        ),
+    ];
+}
+impl FromStr for PerformanceParameterTypeINTEL {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PerformanceParameterTypeINTEL", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PerformanceParameterTypeINTEL {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PerformanceValueTypeINTEL {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::UINT32.0,
+            "UINT32",
+            "VK_PERFORMANCE_VALUE_TYPE_UINT32_INTEL",
+        ),
+        (
+            Self::UINT64.0,
+            "UINT64",
+            "VK_PERFORMANCE_VALUE_TYPE_UINT64_INTEL",
+        ),
+        (
+            Self::FLOAT.0,
+            "FLOAT",
+            "VK_PERFORMANCE_VALUE_TYPE_FLOAT_INTEL",
+        ),
+        (Self::BOOL.0, "BOOL", "VK_PERFORMANCE_VALUE_TYPE_BOOL_INTEL"),
+        (
+            Self::STRING.0,
+            "STRING",
+            "VK_PERFORMANCE_VALUE_TYPE_STRING_INTEL",
+        ),
+    ];
+}
+impl FromStr for PerformanceValueTypeINTEL {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PerformanceValueTypeINTEL", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PerformanceValueTypeINTEL {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PhysicalDeviceType {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::OTHER.0, "OTHER", "VK_PHYSICAL_DEVICE_TYPE_OTHER"),
+        (
+            Self::INTEGRATED_GPU.0,
+            "INTEGRATED_GPU",
+            "VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU",
+        ),
+        (
+            Self::DISCRETE_GPU.0,
+            "DISCRETE_GPU",
+            "VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU",
+        ),
+        (
+            Self::VIRTUAL_GPU.0,
+            "VIRTUAL_GPU",
+            "VK_PHYSICAL_DEVICE_TYPE_VIRTUAL_GPU",
+        ),
+        (Self::CPU.0, "CPU", "VK_PHYSICAL_DEVICE_TYPE_CPU"),
+    ];
+}
+impl FromStr for PhysicalDeviceType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PhysicalDeviceType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PhysicalDeviceType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineBindPoint {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::GRAPHICS.0,
+            "GRAPHICS",
+            "VK_PIPELINE_BIND_POINT_GRAPHICS",
+        ),
+        (Self::COMPUTE.0, "COMPUTE", "VK_PIPELINE_BIND_POINT_COMPUTE"),
+        (
+            Self::RAY_TRACING_KHR.0,
+            "RAY_TRACING_KHR",
+            "VK_PIPELINE_BIND_POINT_RAY_TRACING_KHR",
+        ),
+        (
+            Self::SUBPASS_SHADING_HUAWEI.0,
+            "SUBPASS_SHADING_HUAWEI",
+            "VK_PIPELINE_BIND_POINT_SUBPASS_SHADING_HUAWEI",
+        ),
+        (
+            Self::RAY_TRACING_NV.0,
+            "RAY_TRACING_NV",
+            "VK_PIPELINE_BIND_POINT_RAY_TRACING_NV",
+        ),
+    ];
+}
+impl FromStr for PipelineBindPoint {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PipelineBindPoint", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineBindPoint {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineCacheHeaderVersion {
+    pub(crate) const KNOWN: Known<i32> =
+        &[(Self::ONE.0, "ONE", "VK_PIPELINE_CACHE_HEADER_VERSION_ONE")];
+}
+impl FromStr for PipelineCacheHeaderVersion {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PipelineCacheHeaderVersion", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineCacheHeaderVersion {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineExecutableStatisticFormatKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::BOOL32.0,
+            "BOOL32",
+            "VK_PIPELINE_EXECUTABLE_STATISTIC_FORMAT_BOOL32_KHR",
+        ),
+        (
+            Self::INT64.0,
+            "INT64",
+            "VK_PIPELINE_EXECUTABLE_STATISTIC_FORMAT_INT64_KHR",
+        ),
+        (
+            Self::UINT64.0,
+            "UINT64",
+            "VK_PIPELINE_EXECUTABLE_STATISTIC_FORMAT_UINT64_KHR",
+        ),
+        (
+            Self::FLOAT64.0,
+            "FLOAT64",
+            "VK_PIPELINE_EXECUTABLE_STATISTIC_FORMAT_FLOAT64_KHR",
+        ),
+    ];
+}
+impl FromStr for PipelineExecutableStatisticFormatKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PipelineExecutableStatisticFormatKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineExecutableStatisticFormatKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineRobustnessBufferBehaviorEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::DEVICE_DEFAULT.0,
+            "DEVICE_DEFAULT",
+            "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_DEVICE_DEFAULT_EXT",
+        ),
+        (
+            Self::DISABLED.0,
+            "DISABLED",
+            "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_DISABLED_EXT",
+        ),
+        (
+            Self::ROBUST_BUFFER_ACCESS.0,
+            "ROBUST_BUFFER_ACCESS",
+            "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_ROBUST_BUFFER_ACCESS_EXT",
+        ),
+        (
+            Self::ROBUST_BUFFER_ACCESS_2.0,
+            "ROBUST_BUFFER_ACCESS_2",
+            "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_ROBUST_BUFFER_ACCESS_2_EXT",
+        ),
+    ];
+}
+impl FromStr for PipelineRobustnessBufferBehaviorEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PipelineRobustnessBufferBehaviorEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineRobustnessBufferBehaviorEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PipelineRobustnessImageBehaviorEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::DEVICE_DEFAULT.0,
+            "DEVICE_DEFAULT",
+            "VK_PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_DEVICE_DEFAULT_EXT",
+        ),
+        (
+            Self::DISABLED.0,
+            "DISABLED",
+            "VK_PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_DISABLED_EXT",
+        ),
+        (
+            Self::ROBUST_IMAGE_ACCESS.0,
+            "ROBUST_IMAGE_ACCESS",
+            "VK_PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_ROBUST_IMAGE_ACCESS_EXT",
+        ),
+        (
+            Self::ROBUST_IMAGE_ACCESS_2.0,
+            "ROBUST_IMAGE_ACCESS_2",
+            "VK_PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_ROBUST_IMAGE_ACCESS_2_EXT",
+        ),
+    ];
+}
+impl FromStr for PipelineRobustnessImageBehaviorEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PipelineRobustnessImageBehaviorEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PipelineRobustnessImageBehaviorEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PointClippingBehavior {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::ALL_CLIP_PLANES.0,
+            "ALL_CLIP_PLANES",
+            "VK_POINT_CLIPPING_BEHAVIOR_ALL_CLIP_PLANES",
+        ),
+        (
+            Self::USER_CLIP_PLANES_ONLY.0,
+            "USER_CLIP_PLANES_ONLY",
+            "VK_POINT_CLIPPING_BEHAVIOR_USER_CLIP_PLANES_ONLY",
+        ),
+        (
+            Self::ALL_CLIP_PLANES_KHR.0,
+            "ALL_CLIP_PLANES_KHR",
+            "VK_POINT_CLIPPING_BEHAVIOR_ALL_CLIP_PLANES_KHR",
+        ),
+        (
+            Self::USER_CLIP_PLANES_ONLY_KHR.0,
+            "USER_CLIP_PLANES_ONLY_KHR",
+            "VK_POINT_CLIPPING_BEHAVIOR_USER_CLIP_PLANES_ONLY_KHR",
+        ),
+    ];
+}
+impl FromStr for PointClippingBehavior {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PointClippingBehavior", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PointClippingBehavior {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PolygonMode {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::FILL.0, "FILL", "VK_POLYGON_MODE_FILL"),
+        (Self::LINE.0, "LINE", "VK_POLYGON_MODE_LINE"),
+        (Self::POINT.0, "POINT", "VK_POLYGON_MODE_POINT"),
+        (
+            Self::FILL_RECTANGLE_NV.0,
+            "FILL_RECTANGLE_NV",
+            "VK_POLYGON_MODE_FILL_RECTANGLE_NV",
+        ),
+    ];
+}
+impl FromStr for PolygonMode {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PolygonMode", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PolygonMode {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PresentModeKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::IMMEDIATE.0,
+            "IMMEDIATE",
+            "VK_PRESENT_MODE_IMMEDIATE_KHR",
+        ),
+        (Self::MAILBOX.0, "MAILBOX", "VK_PRESENT_MODE_MAILBOX_KHR"),
+        (Self::FIFO.0, "FIFO", "VK_PRESENT_MODE_FIFO_KHR"),
+        (
+            Self::FIFO_RELAXED.0,
+            "FIFO_RELAXED",
+            "VK_PRESENT_MODE_FIFO_RELAXED_KHR",
+        ),
+        (
+            Self::SHARED_DEMAND_REFRESH.0,
+            "SHARED_DEMAND_REFRESH",
+            "VK_PRESENT_MODE_SHARED_DEMAND_REFRESH_KHR",
+        ),
+        (
+            Self::SHARED_CONTINUOUS_REFRESH.0,
+            "SHARED_CONTINUOUS_REFRESH",
+            "VK_PRESENT_MODE_SHARED_CONTINUOUS_REFRESH_KHR",
+        ),
+    ];
+}
+impl FromStr for PresentModeKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PresentModeKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PresentModeKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl PrimitiveTopology {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::POINT_LIST.0,
+            "POINT_LIST",
+            "VK_PRIMITIVE_TOPOLOGY_POINT_LIST",
+        ),
+        (
+            Self::LINE_LIST.0,
+            "LINE_LIST",
+            "VK_PRIMITIVE_TOPOLOGY_LINE_LIST",
+        ),
+        (
+            Self::LINE_STRIP.0,
+            "LINE_STRIP",
+            "VK_PRIMITIVE_TOPOLOGY_LINE_STRIP",
+        ),
+        (
+            Self::TRIANGLE_LIST.0,
+            "TRIANGLE_LIST",
+            "VK_PRIMITIVE_TOPOLOGY_TRIANGLE_LIST",
+        ),
+        (
+            Self::TRIANGLE_STRIP.0,
+            "TRIANGLE_STRIP",
+            "VK_PRIMITIVE_TOPOLOGY_TRIANGLE_STRIP",
+        ),
+        (
+            Self::TRIANGLE_FAN.0,
+            "TRIANGLE_FAN",
+            "VK_PRIMITIVE_TOPOLOGY_TRIANGLE_FAN",
+        ),
+        (
+            Self::LINE_LIST_WITH_ADJACENCY.0,
+            "LINE_LIST_WITH_ADJACENCY",
+            "VK_PRIMITIVE_TOPOLOGY_LINE_LIST_WITH_ADJACENCY",
+        ),
+        (
+            Self::LINE_STRIP_WITH_ADJACENCY.0,
+            "LINE_STRIP_WITH_ADJACENCY",
+            "VK_PRIMITIVE_TOPOLOGY_LINE_STRIP_WITH_ADJACENCY",
+        ),
+        (
+            Self::TRIANGLE_LIST_WITH_ADJACENCY.0,
+            "TRIANGLE_LIST_WITH_ADJACENCY",
+            "VK_PRIMITIVE_TOPOLOGY_TRIANGLE_LIST_WITH_ADJACENCY",
+        ),
+        (
+            Self::TRIANGLE_STRIP_WITH_ADJACENCY.0,
+            "TRIANGLE_STRIP_WITH_ADJACENCY",
+            "VK_PRIMITIVE_TOPOLOGY_TRIANGLE_STRIP_WITH_ADJACENCY",
+        ),
+        (
+            Self::PATCH_LIST.0,
+            "PATCH_LIST",
+            "VK_PRIMITIVE_TOPOLOGY_PATCH_LIST",
+        ),
+    ];
+}
+impl FromStr for PrimitiveTopology {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("PrimitiveTopology", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for PrimitiveTopology {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ProvokingVertexModeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::FIRST_VERTEX.0,
+            "FIRST_VERTEX",
+            "VK_PROVOKING_VERTEX_MODE_FIRST_VERTEX_EXT",
+        ),
+        (
+            Self::LAST_VERTEX.0,
+            "LAST_VERTEX",
+            "VK_PROVOKING_VERTEX_MODE_LAST_VERTEX_EXT",
+        ),
+    ];
+}
+impl FromStr for ProvokingVertexModeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ProvokingVertexModeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ProvokingVertexModeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl QueryPoolSamplingModeINTEL {
+    pub(crate) const KNOWN: Known<i32> = &[(
+        Self::MANUAL.0,
+        "MANUAL",
+        "VK_QUERY_POOL_SAMPLING_MODE_MANUAL_INTEL",
+    )];
+}
+impl FromStr for QueryPoolSamplingModeINTEL {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("QueryPoolSamplingModeINTEL", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for QueryPoolSamplingModeINTEL {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl QueryResultStatusKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::ERROR.0, "ERROR", "VK_QUERY_RESULT_STATUS_ERROR_KHR"),
+        (
+            Self::NOT_READY.0,
+            "NOT_READY",
+            "VK_QUERY_RESULT_STATUS_NOT_READY_KHR",
+        ),
+        (
+            Self::COMPLETE.0,
+            "COMPLETE",
+            "VK_QUERY_RESULT_STATUS_COMPLETE_KHR",
+        ),
+    ];
+}
+impl FromStr for QueryResultStatusKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("QueryResultStatusKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for QueryResultStatusKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl QueryType {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::OCCLUSION.0, "OCCLUSION", "VK_QUERY_TYPE_OCCLUSION"),
+        (
+            Self::PIPELINE_STATISTICS.0,
+            "PIPELINE_STATISTICS",
+            "VK_QUERY_TYPE_PIPELINE_STATISTICS",
+        ),
+        (Self::TIMESTAMP.0, "TIMESTAMP", "VK_QUERY_TYPE_TIMESTAMP"),
+        (
+            Self::RESULT_STATUS_ONLY_KHR.0,
+            "RESULT_STATUS_ONLY_KHR",
+            "VK_QUERY_TYPE_RESULT_STATUS_ONLY_KHR",
+        ),
+        (
+            Self::TRANSFORM_FEEDBACK_STREAM_EXT.0,
+            "TRANSFORM_FEEDBACK_STREAM_EXT",
+            "VK_QUERY_TYPE_TRANSFORM_FEEDBACK_STREAM_EXT",
+        ),
+        (
+            Self::PERFORMANCE_QUERY_KHR.0,
+            "PERFORMANCE_QUERY_KHR",
+            "VK_QUERY_TYPE_PERFORMANCE_QUERY_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_COMPACTED_SIZE_KHR.0,
+            "ACCELERATION_STRUCTURE_COMPACTED_SIZE_KHR",
+            "VK_QUERY_TYPE_ACCELERATION_STRUCTURE_COMPACTED_SIZE_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_SERIALIZATION_SIZE_KHR.0,
+            "ACCELERATION_STRUCTURE_SERIALIZATION_SIZE_KHR",
+            "VK_QUERY_TYPE_ACCELERATION_STRUCTURE_SERIALIZATION_SIZE_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_COMPACTED_SIZE_NV.0,
+            "ACCELERATION_STRUCTURE_COMPACTED_SIZE_NV",
+            "VK_QUERY_TYPE_ACCELERATION_STRUCTURE_COMPACTED_SIZE_NV",
+        ),
+        (
+            Self::PERFORMANCE_QUERY_INTEL.0,
+            "PERFORMANCE_QUERY_INTEL",
+            "VK_QUERY_TYPE_PERFORMANCE_QUERY_INTEL",
+        ),
+        (
+            Self::VIDEO_ENCODE_FEEDBACK_KHR.0,
+            "VIDEO_ENCODE_FEEDBACK_KHR",
+            "VK_QUERY_TYPE_VIDEO_ENCODE_FEEDBACK_KHR",
+        ),
+        (
+            Self::MESH_PRIMITIVES_GENERATED_EXT.0,
+            "MESH_PRIMITIVES_GENERATED_EXT",
+            "VK_QUERY_TYPE_MESH_PRIMITIVES_GENERATED_EXT",
+        ),
+        (
+            Self::PRIMITIVES_GENERATED_EXT.0,
+            "PRIMITIVES_GENERATED_EXT",
+            "VK_QUERY_TYPE_PRIMITIVES_GENERATED_EXT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_SERIALIZATION_BOTTOM_LEVEL_POINTERS_KHR.0,
+            "ACCELERATION_STRUCTURE_SERIALIZATION_BOTTOM_LEVEL_POINTERS_KHR",
+            "VK_QUERY_TYPE_ACCELERATION_STRUCTURE_SERIALIZATION_BOTTOM_LEVEL_POINTERS_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_SIZE_KHR.0,
+            "ACCELERATION_STRUCTURE_SIZE_KHR",
+            "VK_QUERY_TYPE_ACCELERATION_STRUCTURE_SIZE_KHR",
+        ),
+        (
+            Self::MICROMAP_SERIALIZATION_SIZE_EXT.0,
+            "MICROMAP_SERIALIZATION_SIZE_EXT",
+            "VK_QUERY_TYPE_MICROMAP_SERIALIZATION_SIZE_EXT",
+        ),
+        (
+            Self::MICROMAP_COMPACTED_SIZE_EXT.0,
+            "MICROMAP_COMPACTED_SIZE_EXT",
+            "VK_QUERY_TYPE_MICROMAP_COMPACTED_SIZE_EXT",
+        ),
+    ];
+}
+impl FromStr for QueryType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("QueryType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for QueryType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl QueueGlobalPriorityKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::LOW.0, "LOW", "VK_QUEUE_GLOBAL_PRIORITY_LOW_KHR"),
+        (
+            Self::MEDIUM.0,
+            "MEDIUM",
+            "VK_QUEUE_GLOBAL_PRIORITY_MEDIUM_KHR",
+        ),
+        (Self::HIGH.0, "HIGH", "VK_QUEUE_GLOBAL_PRIORITY_HIGH_KHR"),
+        (
+            Self::REALTIME.0,
+            "REALTIME",
+            "VK_QUEUE_GLOBAL_PRIORITY_REALTIME_KHR",
+        ),
+        (
+            Self::LOW_EXT.0,
+            "LOW_EXT",
+            "VK_QUEUE_GLOBAL_PRIORITY_LOW_EXT",
+        ),
+        (
+            Self::MEDIUM_EXT.0,
+            "MEDIUM_EXT",
+            "VK_QUEUE_GLOBAL_PRIORITY_MEDIUM_EXT",
+        ),
+        (
+            Self::HIGH_EXT.0,
+            "HIGH_EXT",
+            "VK_QUEUE_GLOBAL_PRIORITY_HIGH_EXT",
+        ),
+        (
+            Self::REALTIME_EXT.0,
+            "REALTIME_EXT",
+            "VK_QUEUE_GLOBAL_PRIORITY_REALTIME_EXT",
+        ),
+    ];
+}
+impl FromStr for QueueGlobalPriorityKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("QueueGlobalPriorityKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for QueueGlobalPriorityKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl RasterizationOrderAMD {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::STRICT.0,
+            "STRICT",
+            "VK_RASTERIZATION_ORDER_STRICT_AMD",
+        ),
+        (
+            Self::RELAXED.0,
+            "RELAXED",
+            "VK_RASTERIZATION_ORDER_RELAXED_AMD",
+        ),
+    ];
+}
+impl FromStr for RasterizationOrderAMD {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("RasterizationOrderAMD", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for RasterizationOrderAMD {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl RayTracingInvocationReorderModeNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::NONE.0,
+            "NONE",
+            "VK_RAY_TRACING_INVOCATION_REORDER_MODE_NONE_NV",
+        ),
+        (
+            Self::REORDER.0,
+            "REORDER",
+            "VK_RAY_TRACING_INVOCATION_REORDER_MODE_REORDER_NV",
+        ),
+    ];
+}
+impl FromStr for RayTracingInvocationReorderModeNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("RayTracingInvocationReorderModeNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for RayTracingInvocationReorderModeNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl RayTracingShaderGroupTypeKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::GENERAL.0,
+            "GENERAL",
+            "VK_RAY_TRACING_SHADER_GROUP_TYPE_GENERAL_KHR",
+        ),
+        (
+            Self::TRIANGLES_HIT_GROUP.0,
+            "TRIANGLES_HIT_GROUP",
+            "VK_RAY_TRACING_SHADER_GROUP_TYPE_TRIANGLES_HIT_GROUP_KHR",
+        ),
+        (
+            Self::PROCEDURAL_HIT_GROUP.0,
+            "PROCEDURAL_HIT_GROUP",
+            "VK_RAY_TRACING_SHADER_GROUP_TYPE_PROCEDURAL_HIT_GROUP_KHR",
+        ),
+        (
+            Self::GENERAL_NV.0,
+            "GENERAL_NV",
+            "VK_RAY_TRACING_SHADER_GROUP_TYPE_GENERAL_NV",
+        ),
+        (
+            Self::TRIANGLES_HIT_GROUP_NV.0,
+            "TRIANGLES_HIT_GROUP_NV",
+            "VK_RAY_TRACING_SHADER_GROUP_TYPE_TRIANGLES_HIT_GROUP_NV",
+        ),
+        (
+            Self::PROCEDURAL_HIT_GROUP_NV.0,
+            "PROCEDURAL_HIT_GROUP_NV",
+            "VK_RAY_TRACING_SHADER_GROUP_TYPE_PROCEDURAL_HIT_GROUP_NV",
+        ),
+    ];
+}
+impl FromStr for RayTracingShaderGroupTypeKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("RayTracingShaderGroupTypeKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for RayTracingShaderGroupTypeKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl Result {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::SUCCESS.0, "SUCCESS", "VK_SUCCESS"),
+        (Self::NOT_READY.0, "NOT_READY", "VK_NOT_READY"),
+        (Self::TIMEOUT.0, "TIMEOUT", "VK_TIMEOUT"),
+        (Self::EVENT_SET.0, "EVENT_SET", "VK_EVENT_SET"),
+        (Self::EVENT_RESET.0, "EVENT_RESET", "VK_EVENT_RESET"),
+        (Self::INCOMPLETE.0, "INCOMPLETE", "VK_INCOMPLETE"),
+        (
+            Self::ERROR_OUT_OF_HOST_MEMORY.0,
+            "ERROR_OUT_OF_HOST_MEMORY",
+            "VK_ERROR_OUT_OF_HOST_MEMORY",
+        ),
+        (
+            Self::ERROR_OUT_OF_DEVICE_MEMORY.0,
+            "ERROR_OUT_OF_DEVICE_MEMORY",
+            "VK_ERROR_OUT_OF_DEVICE_MEMORY",
+        ),
+        (
+            Self::ERROR_INITIALIZATION_FAILED.0,
+            "ERROR_INITIALIZATION_FAILED",
+            "VK_ERROR_INITIALIZATION_FAILED",
+        ),
+        (
+            Self::ERROR_DEVICE_LOST.0,
+            "ERROR_DEVICE_LOST",
+            "VK_ERROR_DEVICE_LOST",
+        ),
+        (
+            Self::ERROR_MEMORY_MAP_FAILED.0,
+            "ERROR_MEMORY_MAP_FAILED",
+            "VK_ERROR_MEMORY_MAP_FAILED",
+        ),
+        (
+            Self::ERROR_LAYER_NOT_PRESENT.0,
+            "ERROR_LAYER_NOT_PRESENT",
+            "VK_ERROR_LAYER_NOT_PRESENT",
+        ),
+        (
+            Self::ERROR_EXTENSION_NOT_PRESENT.0,
+            "ERROR_EXTENSION_NOT_PRESENT",
+            "VK_ERROR_EXTENSION_NOT_PRESENT",
+        ),
+        (
+            Self::ERROR_FEATURE_NOT_PRESENT.0,
+            "ERROR_FEATURE_NOT_PRESENT",
+            "VK_ERROR_FEATURE_NOT_PRESENT",
+        ),
+        (
+            Self::ERROR_INCOMPATIBLE_DRIVER.0,
+            "ERROR_INCOMPATIBLE_DRIVER",
+            "VK_ERROR_INCOMPATIBLE_DRIVER",
+        ),
+        (
+            Self::ERROR_TOO_MANY_OBJECTS.0,
+            "ERROR_TOO_MANY_OBJECTS",
+            "VK_ERROR_TOO_MANY_OBJECTS",
+        ),
+        (
+            Self::ERROR_FORMAT_NOT_SUPPORTED.0,
+            "ERROR_FORMAT_NOT_SUPPORTED",
+            "VK_ERROR_FORMAT_NOT_SUPPORTED",
+        ),
+        (
+            Self::ERROR_FRAGMENTED_POOL.0,
+            "ERROR_FRAGMENTED_POOL",
+            "VK_ERROR_FRAGMENTED_POOL",
+        ),
+        (Self::ERROR_UNKNOWN.0, "ERROR_UNKNOWN", "VK_ERROR_UNKNOWN"),
+        (
+            Self::ERROR_SURFACE_LOST_KHR.0,
+            "ERROR_SURFACE_LOST_KHR",
+            "VK_ERROR_SURFACE_LOST_KHR",
+        ),
+        (
+            Self::ERROR_NATIVE_WINDOW_IN_USE_KHR.0,
+            "ERROR_NATIVE_WINDOW_IN_USE_KHR",
+            "VK_ERROR_NATIVE_WINDOW_IN_USE_KHR",
+        ),
+        (
+            Self::SUBOPTIMAL_KHR.0,
+            "SUBOPTIMAL_KHR",
+            "VK_SUBOPTIMAL_KHR",
+        ),
+        (
+            Self::ERROR_OUT_OF_DATE_KHR.0,
+            "ERROR_OUT_OF_DATE_KHR",
+            "VK_ERROR_OUT_OF_DATE_KHR",
+        ),
+        (
+            Self::ERROR_INCOMPATIBLE_DISPLAY_KHR.0,
+            "ERROR_INCOMPATIBLE_DISPLAY_KHR",
+            "VK_ERROR_INCOMPATIBLE_DISPLAY_KHR",
+        ),
+        (
+            Self::ERROR_VALIDATION_FAILED_EXT.0,
+            "ERROR_VALIDATION_FAILED_EXT",
+            "VK_ERROR_VALIDATION_FAILED_EXT",
+        ),
+        (
+            Self::ERROR_INVALID_SHADER_NV.0,
+            "ERROR_INVALID_SHADER_NV",
+            "VK_ERROR_INVALID_SHADER_NV",
+        ),
+        (
+            Self::ERROR_IMAGE_USAGE_NOT_SUPPORTED_KHR.0,
+            "ERROR_IMAGE_USAGE_NOT_SUPPORTED_KHR",
+            "VK_ERROR_IMAGE_USAGE_NOT_SUPPORTED_KHR",
+        ),
+        (
+            Self::ERROR_VIDEO_PICTURE_LAYOUT_NOT_SUPPORTED_KHR.0,
+            "ERROR_VIDEO_PICTURE_LAYOUT_NOT_SUPPORTED_KHR",
+            "VK_ERROR_VIDEO_PICTURE_LAYOUT_NOT_SUPPORTED_KHR",
+        ),
+        (
+            Self::ERROR_VIDEO_PROFILE_OPERATION_NOT_SUPPORTED_KHR.0,
+            "ERROR_VIDEO_PROFILE_OPERATION_NOT_SUPPORTED_KHR",
+            "VK_ERROR_VIDEO_PROFILE_OPERATION_NOT_SUPPORTED_KHR",
+        ),
+        (
+            Self::ERROR_VIDEO_PROFILE_FORMAT_NOT_SUPPORTED_KHR.0,
+            "ERROR_VIDEO_PROFILE_FORMAT_NOT_SUPPORTED_KHR",
+            "VK_ERROR_VIDEO_PROFILE_FORMAT_NOT_SUPPORTED_KHR",
+        ),
+        (
+            Self::ERROR_VIDEO_PROFILE_CODEC_NOT_SUPPORTED_KHR.0,
+            "ERROR_VIDEO_PROFILE_CODEC_NOT_SUPPORTED_KHR",
+            "VK_ERROR_VIDEO_PROFILE_CODEC_NOT_SUPPORTED_KHR",
+        ),
+        (
+            Self::ERROR_VIDEO_STD_VERSION_NOT_SUPPORTED_KHR.0,
+            "ERROR_VIDEO_STD_VERSION_NOT_SUPPORTED_KHR",
+            "VK_ERROR_VIDEO_STD_VERSION_NOT_SUPPORTED_KHR",
+        ),
+        (
+            Self::ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT.0,
+            "ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT",
+            "VK_ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT",
+        ),
+        (
+            Self::ERROR_NOT_PERMITTED_KHR.0,
+            "ERROR_NOT_PERMITTED_KHR",
+            "VK_ERROR_NOT_PERMITTED_KHR",
+        ),
+        (
+            Self::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT.0,
+            "ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT",
+            "VK_ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT",
+        ),
+        (
+            Self::THREAD_IDLE_KHR.0,
+            "THREAD_IDLE_KHR",
+            "VK_THREAD_IDLE_KHR",
+        ),
+        (
+            Self::THREAD_DONE_KHR.0,
+            "THREAD_DONE_KHR",
+            "VK_THREAD_DONE_KHR",
+        ),
+        (
+            Self::OPERATION_DEFERRED_KHR.0,
+            "OPERATION_DEFERRED_KHR",
+            "VK_OPERATION_DEFERRED_KHR",
+        ),
+        (
+            Self::OPERATION_NOT_DEFERRED_KHR.0,
+            "OPERATION_NOT_DEFERRED_KHR",
+            "VK_OPERATION_NOT_DEFERRED_KHR",
+        ),
+        (
+            Self::ERROR_INVALID_VIDEO_STD_PARAMETERS_KHR.0,
+            "ERROR_INVALID_VIDEO_STD_PARAMETERS_KHR",
+            "VK_ERROR_INVALID_VIDEO_STD_PARAMETERS_KHR",
+        ),
+        (
+            Self::ERROR_COMPRESSION_EXHAUSTED_EXT.0,
+            "ERROR_COMPRESSION_EXHAUSTED_EXT",
+            "VK_ERROR_COMPRESSION_EXHAUSTED_EXT",
+        ),
+        (
+            Self::ERROR_INCOMPATIBLE_SHADER_BINARY_EXT.0,
+            "ERROR_INCOMPATIBLE_SHADER_BINARY_EXT",
+            "VK_ERROR_INCOMPATIBLE_SHADER_BINARY_EXT",
+        ),
+        (
+            Self::ERROR_OUT_OF_POOL_MEMORY.0,
+            "ERROR_OUT_OF_POOL_MEMORY",
+            "VK_ERROR_OUT_OF_POOL_MEMORY",
+        ),
+        (
+            Self::ERROR_INVALID_EXTERNAL_HANDLE.0,
+            "ERROR_INVALID_EXTERNAL_HANDLE",
+            "VK_ERROR_INVALID_EXTERNAL_HANDLE",
+        ),
+        (
+            Self::ERROR_FRAGMENTATION.0,
+            "ERROR_FRAGMENTATION",
+            "VK_ERROR_FRAGMENTATION",
+        ),
+        (
+            Self::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS.0,
+            "ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS",
+            "VK_ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS",
+        ),
+        (
+            Self::PIPELINE_COMPILE_REQUIRED.0,
+            "PIPELINE_COMPILE_REQUIRED",
+            "VK_PIPELINE_COMPILE_REQUIRED",
+        ),
+        (
+            Self::ERROR_OUT_OF_POOL_MEMORY_KHR.0,
+            "ERROR_OUT_OF_POOL_MEMORY_KHR",
+            "VK_ERROR_OUT_OF_POOL_MEMORY_KHR",
+        ),
+        (
+            Self::ERROR_INVALID_EXTERNAL_HANDLE_KHR.0,
+            "ERROR_INVALID_EXTERNAL_HANDLE_KHR",
+            "VK_ERROR_INVALID_EXTERNAL_HANDLE_KHR",
+        ),
+        (
+            Self::ERROR_FRAGMENTATION_EXT.0,
+            "ERROR_FRAGMENTATION_EXT",
+            "VK_ERROR_FRAGMENTATION_EXT",
+        ),
+        (
+            Self::ERROR_NOT_PERMITTED_EXT.0,
+            "ERROR_NOT_PERMITTED_EXT",
+            "VK_ERROR_NOT_PERMITTED_EXT",
+        ),
+        (
+            Self::ERROR_INVALID_DEVICE_ADDRESS_EXT.0,
+            "ERROR_INVALID_DEVICE_ADDRESS_EXT",
+            "VK_ERROR_INVALID_DEVICE_ADDRESS_EXT",
+        ),
+        (
+            Self::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR.0,
+            "ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR",
+            "VK_ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR",
+        ),
+        (
+            Self::PIPELINE_COMPILE_REQUIRED_EXT.0,
+            "PIPELINE_COMPILE_REQUIRED_EXT",
+            "VK_PIPELINE_COMPILE_REQUIRED_EXT",
+        ),
+        (
+            Self::ERROR_PIPELINE_COMPILE_REQUIRED_EXT.0,
+            "ERROR_PIPELINE_COMPILE_REQUIRED_EXT",
+            "VK_ERROR_PIPELINE_COMPILE_REQUIRED_EXT",
+        ),
+    ];
+}
+impl FromStr for Result {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("Result", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for Result {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SamplerAddressMode {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::REPEAT.0, "REPEAT", "VK_SAMPLER_ADDRESS_MODE_REPEAT"),
+        (
+            Self::MIRRORED_REPEAT.0,
+            "MIRRORED_REPEAT",
+            "VK_SAMPLER_ADDRESS_MODE_MIRRORED_REPEAT",
+        ),
+        (
+            Self::CLAMP_TO_EDGE.0,
+            "CLAMP_TO_EDGE",
+            "VK_SAMPLER_ADDRESS_MODE_CLAMP_TO_EDGE",
+        ),
+        (
+            Self::CLAMP_TO_BORDER.0,
+            "CLAMP_TO_BORDER",
+            "VK_SAMPLER_ADDRESS_MODE_CLAMP_TO_BORDER",
+        ),
+        (
+            Self::MIRROR_CLAMP_TO_EDGE.0,
+            "MIRROR_CLAMP_TO_EDGE",
+            "VK_SAMPLER_ADDRESS_MODE_MIRROR_CLAMP_TO_EDGE",
+        ),
+    ];
+}
+impl FromStr for SamplerAddressMode {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("SamplerAddressMode", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SamplerAddressMode {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SamplerMipmapMode {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::NEAREST.0, "NEAREST", "VK_SAMPLER_MIPMAP_MODE_NEAREST"),
+        (Self::LINEAR.0, "LINEAR", "VK_SAMPLER_MIPMAP_MODE_LINEAR"),
+    ];
+}
+impl FromStr for SamplerMipmapMode {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("SamplerMipmapMode", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SamplerMipmapMode {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SamplerReductionMode {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::WEIGHTED_AVERAGE.0,
+            "WEIGHTED_AVERAGE",
+            "VK_SAMPLER_REDUCTION_MODE_WEIGHTED_AVERAGE",
+        ),
+        (Self::MIN.0, "MIN", "VK_SAMPLER_REDUCTION_MODE_MIN"),
+        (Self::MAX.0, "MAX", "VK_SAMPLER_REDUCTION_MODE_MAX"),
+        (
+            Self::WEIGHTED_AVERAGE_EXT.0,
+            "WEIGHTED_AVERAGE_EXT",
+            "VK_SAMPLER_REDUCTION_MODE_WEIGHTED_AVERAGE_EXT",
+        ),
+        (
+            Self::MIN_EXT.0,
+            "MIN_EXT",
+            "VK_SAMPLER_REDUCTION_MODE_MIN_EXT",
+        ),
+        (
+            Self::MAX_EXT.0,
+            "MAX_EXT",
+            "VK_SAMPLER_REDUCTION_MODE_MAX_EXT",
+        ),
+    ];
+}
+impl FromStr for SamplerReductionMode {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("SamplerReductionMode", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SamplerReductionMode {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SamplerYcbcrModelConversion {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::RGB_IDENTITY.0,
+            "RGB_IDENTITY",
+            "VK_SAMPLER_YCBCR_MODEL_CONVERSION_RGB_IDENTITY",
+        ),
+        (
+            Self::YCBCR_IDENTITY.0,
+            "YCBCR_IDENTITY",
+            "VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_IDENTITY",
+        ),
+        (
+            Self::YCBCR_709.0,
+            "YCBCR_709",
+            "VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_709",
+        ),
+        (
+            Self::YCBCR_601.0,
+            "YCBCR_601",
+            "VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_601",
+        ),
+        (
+            Self::YCBCR_2020.0,
+            "YCBCR_2020",
+            "VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_2020",
+        ),
+        (
+            Self::RGB_IDENTITY_KHR.0,
+            "RGB_IDENTITY_KHR",
+            "VK_SAMPLER_YCBCR_MODEL_CONVERSION_RGB_IDENTITY_KHR",
+        ),
+        (
+            Self::YCBCR_IDENTITY_KHR.0,
+            "YCBCR_IDENTITY_KHR",
+            "VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_IDENTITY_KHR",
+        ),
+        (
+            Self::YCBCR_709_KHR.0,
+            "YCBCR_709_KHR",
+            "VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_709_KHR",
+        ),
+        (
+            Self::YCBCR_601_KHR.0,
+            "YCBCR_601_KHR",
+            "VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_601_KHR",
+        ),
+        (
+            Self::YCBCR_2020_KHR.0,
+            "YCBCR_2020_KHR",
+            "VK_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_2020_KHR",
+        ),
+    ];
+}
+impl FromStr for SamplerYcbcrModelConversion {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("SamplerYcbcrModelConversion", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SamplerYcbcrModelConversion {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SamplerYcbcrRange {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::ITU_FULL.0,
+            "ITU_FULL",
+            "VK_SAMPLER_YCBCR_RANGE_ITU_FULL",
+        ),
+        (
+            Self::ITU_NARROW.0,
+            "ITU_NARROW",
+            "VK_SAMPLER_YCBCR_RANGE_ITU_NARROW",
+        ),
+        (
+            Self::ITU_FULL_KHR.0,
+            "ITU_FULL_KHR",
+            "VK_SAMPLER_YCBCR_RANGE_ITU_FULL_KHR",
+        ),
+        (
+            Self::ITU_NARROW_KHR.0,
+            "ITU_NARROW_KHR",
+            "VK_SAMPLER_YCBCR_RANGE_ITU_NARROW_KHR",
+        ),
+    ];
+}
+impl FromStr for SamplerYcbcrRange {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("SamplerYcbcrRange", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SamplerYcbcrRange {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ScopeNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::DEVICE.0, "DEVICE", "VK_SCOPE_DEVICE_NV"),
+        (Self::WORKGROUP.0, "WORKGROUP", "VK_SCOPE_WORKGROUP_NV"),
+        (Self::SUBGROUP.0, "SUBGROUP", "VK_SCOPE_SUBGROUP_NV"),
+        (
+            Self::QUEUE_FAMILY.0,
+            "QUEUE_FAMILY",
+            "VK_SCOPE_QUEUE_FAMILY_NV",
+        ),
+    ];
+}
+impl FromStr for ScopeNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ScopeNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ScopeNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SemaphoreType {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::BINARY.0, "BINARY", "VK_SEMAPHORE_TYPE_BINARY"),
+        (Self::TIMELINE.0, "TIMELINE", "VK_SEMAPHORE_TYPE_TIMELINE"),
+        (
+            Self::BINARY_KHR.0,
+            "BINARY_KHR",
+            "VK_SEMAPHORE_TYPE_BINARY_KHR",
+        ),
+        (
+            Self::TIMELINE_KHR.0,
+            "TIMELINE_KHR",
+            "VK_SEMAPHORE_TYPE_TIMELINE_KHR",
+        ),
+    ];
+}
+impl FromStr for SemaphoreType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("SemaphoreType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SemaphoreType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ShaderCodeTypeEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::BINARY.0, "BINARY", "VK_SHADER_CODE_TYPE_BINARY_EXT"),
+        (Self::SPIRV.0, "SPIRV", "VK_SHADER_CODE_TYPE_SPIRV_EXT"),
+    ];
+}
+impl FromStr for ShaderCodeTypeEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ShaderCodeTypeEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ShaderCodeTypeEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ShaderFloatControlsIndependence {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::TYPE_32_ONLY.0,
+            "TYPE_32_ONLY",
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_32_BIT_ONLY",
+        ),
+        (
+            Self::ALL.0,
+            "ALL",
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_ALL",
+        ),
+        (
+            Self::NONE.0,
+            "NONE",
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_NONE",
+        ),
+        (
+            Self::TYPE_32_ONLY_KHR.0,
+            "TYPE_32_ONLY_KHR",
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_32_BIT_ONLY_KHR",
+        ),
+        (
+            Self::ALL_KHR.0,
+            "ALL_KHR",
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_ALL_KHR",
+        ),
+        (
+            Self::NONE_KHR.0,
+            "NONE_KHR",
+            "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_NONE_KHR",
+        ),
+    ];
+}
+impl FromStr for ShaderFloatControlsIndependence {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ShaderFloatControlsIndependence", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ShaderFloatControlsIndependence {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ShaderGroupShaderKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::GENERAL.0,
+            "GENERAL",
+            "VK_SHADER_GROUP_SHADER_GENERAL_KHR",
+        ),
+        (
+            Self::CLOSEST_HIT.0,
+            "CLOSEST_HIT",
+            "VK_SHADER_GROUP_SHADER_CLOSEST_HIT_KHR",
+        ),
+        (
+            Self::ANY_HIT.0,
+            "ANY_HIT",
+            "VK_SHADER_GROUP_SHADER_ANY_HIT_KHR",
+        ),
+        (
+            Self::INTERSECTION.0,
+            "INTERSECTION",
+            "VK_SHADER_GROUP_SHADER_INTERSECTION_KHR",
+        ),
+    ];
+}
+impl FromStr for ShaderGroupShaderKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ShaderGroupShaderKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ShaderGroupShaderKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ShaderInfoTypeAMD {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::STATISTICS.0,
+            "STATISTICS",
+            "VK_SHADER_INFO_TYPE_STATISTICS_AMD",
+        ),
+        (Self::BINARY.0, "BINARY", "VK_SHADER_INFO_TYPE_BINARY_AMD"),
+        (
+            Self::DISASSEMBLY.0,
+            "DISASSEMBLY",
+            "VK_SHADER_INFO_TYPE_DISASSEMBLY_AMD",
+        ),
+    ];
+}
+impl FromStr for ShaderInfoTypeAMD {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ShaderInfoTypeAMD", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ShaderInfoTypeAMD {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ShadingRatePaletteEntryNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::NO_INVOCATIONS.0,
+            "NO_INVOCATIONS",
+            "VK_SHADING_RATE_PALETTE_ENTRY_NO_INVOCATIONS_NV",
+        ),
+        (
+            Self::TYPE_16_INVOCATIONS_PER_PIXEL.0,
+            "TYPE_16_INVOCATIONS_PER_PIXEL",
+            "VK_SHADING_RATE_PALETTE_ENTRY_16_INVOCATIONS_PER_PIXEL_NV",
+        ),
+        (
+            Self::TYPE_8_INVOCATIONS_PER_PIXEL.0,
+            "TYPE_8_INVOCATIONS_PER_PIXEL",
+            "VK_SHADING_RATE_PALETTE_ENTRY_8_INVOCATIONS_PER_PIXEL_NV",
+        ),
+        (
+            Self::TYPE_4_INVOCATIONS_PER_PIXEL.0,
+            "TYPE_4_INVOCATIONS_PER_PIXEL",
+            "VK_SHADING_RATE_PALETTE_ENTRY_4_INVOCATIONS_PER_PIXEL_NV",
+        ),
+        (
+            Self::TYPE_2_INVOCATIONS_PER_PIXEL.0,
+            "TYPE_2_INVOCATIONS_PER_PIXEL",
+            "VK_SHADING_RATE_PALETTE_ENTRY_2_INVOCATIONS_PER_PIXEL_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_PIXEL.0,
+            "TYPE_1_INVOCATION_PER_PIXEL",
+            "VK_SHADING_RATE_PALETTE_ENTRY_1_INVOCATION_PER_PIXEL_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_2X1_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_2X1_PIXELS",
+            "VK_SHADING_RATE_PALETTE_ENTRY_1_INVOCATION_PER_2X1_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_1X2_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_1X2_PIXELS",
+            "VK_SHADING_RATE_PALETTE_ENTRY_1_INVOCATION_PER_1X2_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_2X2_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_2X2_PIXELS",
+            "VK_SHADING_RATE_PALETTE_ENTRY_1_INVOCATION_PER_2X2_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_4X2_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_4X2_PIXELS",
+            "VK_SHADING_RATE_PALETTE_ENTRY_1_INVOCATION_PER_4X2_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_2X4_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_2X4_PIXELS",
+            "VK_SHADING_RATE_PALETTE_ENTRY_1_INVOCATION_PER_2X4_PIXELS_NV",
+        ),
+        (
+            Self::TYPE_1_INVOCATION_PER_4X4_PIXELS.0,
+            "TYPE_1_INVOCATION_PER_4X4_PIXELS",
+            "VK_SHADING_RATE_PALETTE_ENTRY_1_INVOCATION_PER_4X4_PIXELS_NV",
+        ),
+    ];
+}
+impl FromStr for ShadingRatePaletteEntryNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ShadingRatePaletteEntryNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ShadingRatePaletteEntryNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SharingMode {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::EXCLUSIVE.0, "EXCLUSIVE", "VK_SHARING_MODE_EXCLUSIVE"),
+        (
+            Self::CONCURRENT.0,
+            "CONCURRENT",
+            "VK_SHARING_MODE_CONCURRENT",
+        ),
+    ];
+}
+impl FromStr for SharingMode {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("SharingMode", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SharingMode {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl StencilOp {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::KEEP.0, "KEEP", "VK_STENCIL_OP_KEEP"),
+        (Self::ZERO.0, "ZERO", "VK_STENCIL_OP_ZERO"),
+        (Self::REPLACE.0, "REPLACE", "VK_STENCIL_OP_REPLACE"),
+        (
+            Self::INCREMENT_AND_CLAMP.0,
+            "INCREMENT_AND_CLAMP",
+            "VK_STENCIL_OP_INCREMENT_AND_CLAMP",
+        ),
+        (
+            Self::DECREMENT_AND_CLAMP.0,
+            "DECREMENT_AND_CLAMP",
+            "VK_STENCIL_OP_DECREMENT_AND_CLAMP",
+        ),
+        (Self::INVERT.0, "INVERT", "VK_STENCIL_OP_INVERT"),
+        (
+            Self::INCREMENT_AND_WRAP.0,
+            "INCREMENT_AND_WRAP",
+            "VK_STENCIL_OP_INCREMENT_AND_WRAP",
+        ),
+        (
+            Self::DECREMENT_AND_WRAP.0,
+            "DECREMENT_AND_WRAP",
+            "VK_STENCIL_OP_DECREMENT_AND_WRAP",
+        ),
+    ];
+}
+impl FromStr for StencilOp {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("StencilOp", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for StencilOp {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl StructureType {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::APPLICATION_INFO.0,
+            "APPLICATION_INFO",
+            "VK_STRUCTURE_TYPE_APPLICATION_INFO",
+        ),
+        (
+            Self::INSTANCE_CREATE_INFO.0,
+            "INSTANCE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO",
+        ),
+        (
+            Self::DEVICE_QUEUE_CREATE_INFO.0,
+            "DEVICE_QUEUE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_DEVICE_QUEUE_CREATE_INFO",
+        ),
+        (
+            Self::DEVICE_CREATE_INFO.0,
+            "DEVICE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO",
+        ),
+        (
+            Self::SUBMIT_INFO.0,
+            "SUBMIT_INFO",
+            "VK_STRUCTURE_TYPE_SUBMIT_INFO",
+        ),
+        (
+            Self::MEMORY_ALLOCATE_INFO.0,
+            "MEMORY_ALLOCATE_INFO",
+            "VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO",
+        ),
+        (
+            Self::MAPPED_MEMORY_RANGE.0,
+            "MAPPED_MEMORY_RANGE",
+            "VK_STRUCTURE_TYPE_MAPPED_MEMORY_RANGE",
+        ),
+        (
+            Self::BIND_SPARSE_INFO.0,
+            "BIND_SPARSE_INFO",
+            "VK_STRUCTURE_TYPE_BIND_SPARSE_INFO",
+        ),
+        (
+            Self::FENCE_CREATE_INFO.0,
+            "FENCE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_FENCE_CREATE_INFO",
+        ),
+        (
+            Self::SEMAPHORE_CREATE_INFO.0,
+            "SEMAPHORE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO",
+        ),
+        (
+            Self::EVENT_CREATE_INFO.0,
+            "EVENT_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_EVENT_CREATE_INFO",
+        ),
+        (
+            Self::QUERY_POOL_CREATE_INFO.0,
+            "QUERY_POOL_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_QUERY_POOL_CREATE_INFO",
+        ),
+        (
+            Self::BUFFER_CREATE_INFO.0,
+            "BUFFER_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO",
+        ),
+        (
+            Self::BUFFER_VIEW_CREATE_INFO.0,
+            "BUFFER_VIEW_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_BUFFER_VIEW_CREATE_INFO",
+        ),
+        (
+            Self::IMAGE_CREATE_INFO.0,
+            "IMAGE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_IMAGE_CREATE_INFO",
+        ),
+        (
+            Self::IMAGE_VIEW_CREATE_INFO.0,
+            "IMAGE_VIEW_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_IMAGE_VIEW_CREATE_INFO",
+        ),
+        (
+            Self::SHADER_MODULE_CREATE_INFO.0,
+            "SHADER_MODULE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_SHADER_MODULE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_CACHE_CREATE_INFO.0,
+            "PIPELINE_CACHE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_CACHE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_SHADER_STAGE_CREATE_INFO.0,
+            "PIPELINE_SHADER_STAGE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO.0,
+            "PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO.0,
+            "PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_TESSELLATION_STATE_CREATE_INFO.0,
+            "PIPELINE_TESSELLATION_STATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_TESSELLATION_STATE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_VIEWPORT_STATE_CREATE_INFO.0,
+            "PIPELINE_VIEWPORT_STATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_STATE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_RASTERIZATION_STATE_CREATE_INFO.0,
+            "PIPELINE_RASTERIZATION_STATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_STATE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO.0,
+            "PIPELINE_MULTISAMPLE_STATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_MULTISAMPLE_STATE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO.0,
+            "PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO.0,
+            "PIPELINE_COLOR_BLEND_STATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_COLOR_BLEND_STATE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_DYNAMIC_STATE_CREATE_INFO.0,
+            "PIPELINE_DYNAMIC_STATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_DYNAMIC_STATE_CREATE_INFO",
+        ),
+        (
+            Self::GRAPHICS_PIPELINE_CREATE_INFO.0,
+            "GRAPHICS_PIPELINE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_GRAPHICS_PIPELINE_CREATE_INFO",
+        ),
+        (
+            Self::COMPUTE_PIPELINE_CREATE_INFO.0,
+            "COMPUTE_PIPELINE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_COMPUTE_PIPELINE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_LAYOUT_CREATE_INFO.0,
+            "PIPELINE_LAYOUT_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_LAYOUT_CREATE_INFO",
+        ),
+        (
+            Self::SAMPLER_CREATE_INFO.0,
+            "SAMPLER_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_SAMPLER_CREATE_INFO",
+        ),
+        (
+            Self::DESCRIPTOR_SET_LAYOUT_CREATE_INFO.0,
+            "DESCRIPTOR_SET_LAYOUT_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_CREATE_INFO",
+        ),
+        (
+            Self::DESCRIPTOR_POOL_CREATE_INFO.0,
+            "DESCRIPTOR_POOL_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_POOL_CREATE_INFO",
+        ),
+        (
+            Self::DESCRIPTOR_SET_ALLOCATE_INFO.0,
+            "DESCRIPTOR_SET_ALLOCATE_INFO",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_ALLOCATE_INFO",
+        ),
+        (
+            Self::WRITE_DESCRIPTOR_SET.0,
+            "WRITE_DESCRIPTOR_SET",
+            "VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET",
+        ),
+        (
+            Self::COPY_DESCRIPTOR_SET.0,
+            "COPY_DESCRIPTOR_SET",
+            "VK_STRUCTURE_TYPE_COPY_DESCRIPTOR_SET",
+        ),
+        (
+            Self::FRAMEBUFFER_CREATE_INFO.0,
+            "FRAMEBUFFER_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_FRAMEBUFFER_CREATE_INFO",
+        ),
+        (
+            Self::RENDER_PASS_CREATE_INFO.0,
+            "RENDER_PASS_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_CREATE_INFO",
+        ),
+        (
+            Self::COMMAND_POOL_CREATE_INFO.0,
+            "COMMAND_POOL_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_COMMAND_POOL_CREATE_INFO",
+        ),
+        (
+            Self::COMMAND_BUFFER_ALLOCATE_INFO.0,
+            "COMMAND_BUFFER_ALLOCATE_INFO",
+            "VK_STRUCTURE_TYPE_COMMAND_BUFFER_ALLOCATE_INFO",
+        ),
+        (
+            Self::COMMAND_BUFFER_INHERITANCE_INFO.0,
+            "COMMAND_BUFFER_INHERITANCE_INFO",
+            "VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_INFO",
+        ),
+        (
+            Self::COMMAND_BUFFER_BEGIN_INFO.0,
+            "COMMAND_BUFFER_BEGIN_INFO",
+            "VK_STRUCTURE_TYPE_COMMAND_BUFFER_BEGIN_INFO",
+        ),
+        (
+            Self::RENDER_PASS_BEGIN_INFO.0,
+            "RENDER_PASS_BEGIN_INFO",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_BEGIN_INFO",
+        ),
+        (
+            Self::BUFFER_MEMORY_BARRIER.0,
+            "BUFFER_MEMORY_BARRIER",
+            "VK_STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER",
+        ),
+        (
+            Self::IMAGE_MEMORY_BARRIER.0,
+            "IMAGE_MEMORY_BARRIER",
+            "VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER",
+        ),
+        (
+            Self::MEMORY_BARRIER.0,
+            "MEMORY_BARRIER",
+            "VK_STRUCTURE_TYPE_MEMORY_BARRIER",
+        ),
+        (
+            Self::LOADER_INSTANCE_CREATE_INFO.0,
+            "LOADER_INSTANCE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_LOADER_INSTANCE_CREATE_INFO",
+        ),
+        (
+            Self::LOADER_DEVICE_CREATE_INFO.0,
+            "LOADER_DEVICE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_LOADER_DEVICE_CREATE_INFO",
+        ),
+        (
+            Self::SWAPCHAIN_CREATE_INFO_KHR.0,
+            "SWAPCHAIN_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PRESENT_INFO_KHR.0,
+            "PRESENT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PRESENT_INFO_KHR",
+        ),
+        (
+            Self::DEVICE_GROUP_PRESENT_CAPABILITIES_KHR.0,
+            "DEVICE_GROUP_PRESENT_CAPABILITIES_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_PRESENT_CAPABILITIES_KHR",
+        ),
+        (
+            Self::IMAGE_SWAPCHAIN_CREATE_INFO_KHR.0,
+            "IMAGE_SWAPCHAIN_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_SWAPCHAIN_CREATE_INFO_KHR",
+        ),
+        (
+            Self::BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR.0,
+            "BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR",
+            "VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR",
+        ),
+        (
+            Self::ACQUIRE_NEXT_IMAGE_INFO_KHR.0,
+            "ACQUIRE_NEXT_IMAGE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_ACQUIRE_NEXT_IMAGE_INFO_KHR",
+        ),
+        (
+            Self::DEVICE_GROUP_PRESENT_INFO_KHR.0,
+            "DEVICE_GROUP_PRESENT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_PRESENT_INFO_KHR",
+        ),
+        (
+            Self::DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR.0,
+            "DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR",
+        ),
+        (
+            Self::DISPLAY_MODE_CREATE_INFO_KHR.0,
+            "DISPLAY_MODE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DISPLAY_MODE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::DISPLAY_SURFACE_CREATE_INFO_KHR.0,
+            "DISPLAY_SURFACE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DISPLAY_SURFACE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::DISPLAY_PRESENT_INFO_KHR.0,
+            "DISPLAY_PRESENT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DISPLAY_PRESENT_INFO_KHR",
+        ),
+        (
+            Self::XLIB_SURFACE_CREATE_INFO_KHR.0,
+            "XLIB_SURFACE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_XLIB_SURFACE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::XCB_SURFACE_CREATE_INFO_KHR.0,
+            "XCB_SURFACE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_XCB_SURFACE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::WAYLAND_SURFACE_CREATE_INFO_KHR.0,
+            "WAYLAND_SURFACE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_WAYLAND_SURFACE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::ANDROID_SURFACE_CREATE_INFO_KHR.0,
+            "ANDROID_SURFACE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_ANDROID_SURFACE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::WIN32_SURFACE_CREATE_INFO_KHR.0,
+            "WIN32_SURFACE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_WIN32_SURFACE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::NATIVE_BUFFER_ANDROID.0,
+            "NATIVE_BUFFER_ANDROID",
+            "VK_STRUCTURE_TYPE_NATIVE_BUFFER_ANDROID",
+        ),
+        (
+            Self::SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID.0,
+            "SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID",
+            "VK_STRUCTURE_TYPE_SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID.0,
+            "PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID",
+        ),
+        (
+            Self::DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT.0,
+            "DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD.0,
+            "PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD",
+            "VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD",
+        ),
+        (
+            Self::DEBUG_MARKER_OBJECT_NAME_INFO_EXT.0,
+            "DEBUG_MARKER_OBJECT_NAME_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEBUG_MARKER_OBJECT_NAME_INFO_EXT",
+        ),
+        (
+            Self::DEBUG_MARKER_OBJECT_TAG_INFO_EXT.0,
+            "DEBUG_MARKER_OBJECT_TAG_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEBUG_MARKER_OBJECT_TAG_INFO_EXT",
+        ),
+        (
+            Self::DEBUG_MARKER_MARKER_INFO_EXT.0,
+            "DEBUG_MARKER_MARKER_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEBUG_MARKER_MARKER_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_PROFILE_INFO_KHR.0,
+            "VIDEO_PROFILE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_PROFILE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_CAPABILITIES_KHR.0,
+            "VIDEO_CAPABILITIES_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_CAPABILITIES_KHR",
+        ),
+        (
+            Self::VIDEO_PICTURE_RESOURCE_INFO_KHR.0,
+            "VIDEO_PICTURE_RESOURCE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_PICTURE_RESOURCE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_SESSION_MEMORY_REQUIREMENTS_KHR.0,
+            "VIDEO_SESSION_MEMORY_REQUIREMENTS_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_SESSION_MEMORY_REQUIREMENTS_KHR",
+        ),
+        (
+            Self::BIND_VIDEO_SESSION_MEMORY_INFO_KHR.0,
+            "BIND_VIDEO_SESSION_MEMORY_INFO_KHR",
+            "VK_STRUCTURE_TYPE_BIND_VIDEO_SESSION_MEMORY_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_SESSION_CREATE_INFO_KHR.0,
+            "VIDEO_SESSION_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_SESSION_CREATE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_SESSION_PARAMETERS_CREATE_INFO_KHR.0,
+            "VIDEO_SESSION_PARAMETERS_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_SESSION_PARAMETERS_CREATE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_SESSION_PARAMETERS_UPDATE_INFO_KHR.0,
+            "VIDEO_SESSION_PARAMETERS_UPDATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_SESSION_PARAMETERS_UPDATE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_BEGIN_CODING_INFO_KHR.0,
+            "VIDEO_BEGIN_CODING_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_BEGIN_CODING_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_END_CODING_INFO_KHR.0,
+            "VIDEO_END_CODING_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_END_CODING_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_CODING_CONTROL_INFO_KHR.0,
+            "VIDEO_CODING_CONTROL_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_CODING_CONTROL_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_REFERENCE_SLOT_INFO_KHR.0,
+            "VIDEO_REFERENCE_SLOT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_REFERENCE_SLOT_INFO_KHR",
+        ),
+        (
+            Self::QUEUE_FAMILY_VIDEO_PROPERTIES_KHR.0,
+            "QUEUE_FAMILY_VIDEO_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_QUEUE_FAMILY_VIDEO_PROPERTIES_KHR",
+        ),
+        (
+            Self::VIDEO_PROFILE_LIST_INFO_KHR.0,
+            "VIDEO_PROFILE_LIST_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_PROFILE_LIST_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VIDEO_FORMAT_INFO_KHR.0,
+            "PHYSICAL_DEVICE_VIDEO_FORMAT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VIDEO_FORMAT_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_FORMAT_PROPERTIES_KHR.0,
+            "VIDEO_FORMAT_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_FORMAT_PROPERTIES_KHR",
+        ),
+        (
+            Self::QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR.0,
+            "QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_INFO_KHR.0,
+            "VIDEO_DECODE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_CAPABILITIES_KHR.0,
+            "VIDEO_DECODE_CAPABILITIES_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_CAPABILITIES_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_USAGE_INFO_KHR.0,
+            "VIDEO_DECODE_USAGE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_USAGE_INFO_KHR",
+        ),
+        (
+            Self::DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV.0,
+            "DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV",
+        ),
+        (
+            Self::DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV.0,
+            "DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV",
+        ),
+        (
+            Self::DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV.0,
+            "DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT",
+        ),
+        (
+            Self::PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT.0,
+            "PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT",
+        ),
+        (
+            Self::CU_MODULE_CREATE_INFO_NVX.0,
+            "CU_MODULE_CREATE_INFO_NVX",
+            "VK_STRUCTURE_TYPE_CU_MODULE_CREATE_INFO_NVX",
+        ),
+        (
+            Self::CU_FUNCTION_CREATE_INFO_NVX.0,
+            "CU_FUNCTION_CREATE_INFO_NVX",
+            "VK_STRUCTURE_TYPE_CU_FUNCTION_CREATE_INFO_NVX",
+        ),
+        (
+            Self::CU_LAUNCH_INFO_NVX.0,
+            "CU_LAUNCH_INFO_NVX",
+            "VK_STRUCTURE_TYPE_CU_LAUNCH_INFO_NVX",
+        ),
+        (
+            Self::IMAGE_VIEW_HANDLE_INFO_NVX.0,
+            "IMAGE_VIEW_HANDLE_INFO_NVX",
+            "VK_STRUCTURE_TYPE_IMAGE_VIEW_HANDLE_INFO_NVX",
+        ),
+        (
+            Self::IMAGE_VIEW_ADDRESS_PROPERTIES_NVX.0,
+            "IMAGE_VIEW_ADDRESS_PROPERTIES_NVX",
+            "VK_STRUCTURE_TYPE_IMAGE_VIEW_ADDRESS_PROPERTIES_NVX",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_CAPABILITIES_EXT.0,
+            "VIDEO_ENCODE_H264_CAPABILITIES_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_CAPABILITIES_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_SESSION_PARAMETERS_CREATE_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_SESSION_PARAMETERS_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_SESSION_PARAMETERS_CREATE_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_SESSION_PARAMETERS_ADD_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_SESSION_PARAMETERS_ADD_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_SESSION_PARAMETERS_ADD_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_PICTURE_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_PICTURE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_PICTURE_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_DPB_SLOT_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_DPB_SLOT_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_DPB_SLOT_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_NALU_SLICE_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_NALU_SLICE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_NALU_SLICE_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_GOP_REMAINING_FRAME_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_GOP_REMAINING_FRAME_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_GOP_REMAINING_FRAME_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_PROFILE_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_PROFILE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_PROFILE_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_RATE_CONTROL_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_RATE_CONTROL_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_RATE_CONTROL_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_RATE_CONTROL_LAYER_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_RATE_CONTROL_LAYER_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_RATE_CONTROL_LAYER_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_SESSION_CREATE_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_SESSION_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_SESSION_CREATE_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_QUALITY_LEVEL_PROPERTIES_EXT.0,
+            "VIDEO_ENCODE_H264_QUALITY_LEVEL_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_QUALITY_LEVEL_PROPERTIES_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_SESSION_PARAMETERS_GET_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_SESSION_PARAMETERS_GET_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_SESSION_PARAMETERS_GET_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H264_SESSION_PARAMETERS_FEEDBACK_INFO_EXT.0,
+            "VIDEO_ENCODE_H264_SESSION_PARAMETERS_FEEDBACK_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H264_SESSION_PARAMETERS_FEEDBACK_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_CAPABILITIES_EXT.0,
+            "VIDEO_ENCODE_H265_CAPABILITIES_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_CAPABILITIES_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_SESSION_PARAMETERS_CREATE_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_SESSION_PARAMETERS_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_SESSION_PARAMETERS_CREATE_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_SESSION_PARAMETERS_ADD_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_SESSION_PARAMETERS_ADD_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_SESSION_PARAMETERS_ADD_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_PICTURE_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_PICTURE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_PICTURE_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_DPB_SLOT_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_DPB_SLOT_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_DPB_SLOT_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_NALU_SLICE_SEGMENT_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_NALU_SLICE_SEGMENT_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_NALU_SLICE_SEGMENT_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_GOP_REMAINING_FRAME_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_GOP_REMAINING_FRAME_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_GOP_REMAINING_FRAME_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_PROFILE_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_PROFILE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_PROFILE_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_RATE_CONTROL_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_RATE_CONTROL_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_RATE_CONTROL_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_RATE_CONTROL_LAYER_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_RATE_CONTROL_LAYER_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_RATE_CONTROL_LAYER_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_SESSION_CREATE_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_SESSION_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_SESSION_CREATE_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_QUALITY_LEVEL_PROPERTIES_EXT.0,
+            "VIDEO_ENCODE_H265_QUALITY_LEVEL_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_QUALITY_LEVEL_PROPERTIES_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_SESSION_PARAMETERS_GET_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_SESSION_PARAMETERS_GET_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_SESSION_PARAMETERS_GET_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_ENCODE_H265_SESSION_PARAMETERS_FEEDBACK_INFO_EXT.0,
+            "VIDEO_ENCODE_H265_SESSION_PARAMETERS_FEEDBACK_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_H265_SESSION_PARAMETERS_FEEDBACK_INFO_EXT",
+        ),
+        (
+            Self::VIDEO_DECODE_H264_CAPABILITIES_KHR.0,
+            "VIDEO_DECODE_H264_CAPABILITIES_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H264_CAPABILITIES_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_H264_PICTURE_INFO_KHR.0,
+            "VIDEO_DECODE_H264_PICTURE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H264_PICTURE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_H264_PROFILE_INFO_KHR.0,
+            "VIDEO_DECODE_H264_PROFILE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H264_PROFILE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_H264_SESSION_PARAMETERS_CREATE_INFO_KHR.0,
+            "VIDEO_DECODE_H264_SESSION_PARAMETERS_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H264_SESSION_PARAMETERS_CREATE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_H264_SESSION_PARAMETERS_ADD_INFO_KHR.0,
+            "VIDEO_DECODE_H264_SESSION_PARAMETERS_ADD_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H264_SESSION_PARAMETERS_ADD_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_H264_DPB_SLOT_INFO_KHR.0,
+            "VIDEO_DECODE_H264_DPB_SLOT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H264_DPB_SLOT_INFO_KHR",
+        ),
+        (
+            Self::TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD.0,
+            "TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD",
+            "VK_STRUCTURE_TYPE_TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD",
+        ),
+        (
+            Self::RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR.0,
+            "RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR",
+        ),
+        (
+            Self::RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_INFO_EXT.0,
+            "RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_INFO_EXT",
+            "VK_STRUCTURE_TYPE_RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_INFO_EXT",
+        ),
+        (
+            Self::ATTACHMENT_SAMPLE_COUNT_INFO_AMD.0,
+            "ATTACHMENT_SAMPLE_COUNT_INFO_AMD",
+            "VK_STRUCTURE_TYPE_ATTACHMENT_SAMPLE_COUNT_INFO_AMD",
+        ),
+        (
+            Self::MULTIVIEW_PER_VIEW_ATTRIBUTES_INFO_NVX.0,
+            "MULTIVIEW_PER_VIEW_ATTRIBUTES_INFO_NVX",
+            "VK_STRUCTURE_TYPE_MULTIVIEW_PER_VIEW_ATTRIBUTES_INFO_NVX",
+        ),
+        (
+            Self::STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP.0,
+            "STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP",
+            "VK_STRUCTURE_TYPE_STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV",
+        ),
+        (
+            Self::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV.0,
+            "EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV",
+        ),
+        (
+            Self::EXPORT_MEMORY_ALLOCATE_INFO_NV.0,
+            "EXPORT_MEMORY_ALLOCATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_EXPORT_MEMORY_ALLOCATE_INFO_NV",
+        ),
+        (
+            Self::IMPORT_MEMORY_WIN32_HANDLE_INFO_NV.0,
+            "IMPORT_MEMORY_WIN32_HANDLE_INFO_NV",
+            "VK_STRUCTURE_TYPE_IMPORT_MEMORY_WIN32_HANDLE_INFO_NV",
+        ),
+        (
+            Self::EXPORT_MEMORY_WIN32_HANDLE_INFO_NV.0,
+            "EXPORT_MEMORY_WIN32_HANDLE_INFO_NV",
+            "VK_STRUCTURE_TYPE_EXPORT_MEMORY_WIN32_HANDLE_INFO_NV",
+        ),
+        (
+            Self::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV.0,
+            "WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV",
+            "VK_STRUCTURE_TYPE_WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV",
+        ),
+        (
+            Self::VALIDATION_FLAGS_EXT.0,
+            "VALIDATION_FLAGS_EXT",
+            "VK_STRUCTURE_TYPE_VALIDATION_FLAGS_EXT",
+        ),
+        (
+            Self::VI_SURFACE_CREATE_INFO_NN.0,
+            "VI_SURFACE_CREATE_INFO_NN",
+            "VK_STRUCTURE_TYPE_VI_SURFACE_CREATE_INFO_NN",
+        ),
+        (
+            Self::IMAGE_VIEW_ASTC_DECODE_MODE_EXT.0,
+            "IMAGE_VIEW_ASTC_DECODE_MODE_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_VIEW_ASTC_DECODE_MODE_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT",
+        ),
+        (
+            Self::PIPELINE_ROBUSTNESS_CREATE_INFO_EXT.0,
+            "PIPELINE_ROBUSTNESS_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_ROBUSTNESS_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES_EXT",
+        ),
+        (
+            Self::IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR.0,
+            "IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR",
+        ),
+        (
+            Self::EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR.0,
+            "EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR",
+        ),
+        (
+            Self::MEMORY_WIN32_HANDLE_PROPERTIES_KHR.0,
+            "MEMORY_WIN32_HANDLE_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_WIN32_HANDLE_PROPERTIES_KHR",
+        ),
+        (
+            Self::MEMORY_GET_WIN32_HANDLE_INFO_KHR.0,
+            "MEMORY_GET_WIN32_HANDLE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_GET_WIN32_HANDLE_INFO_KHR",
+        ),
+        (
+            Self::IMPORT_MEMORY_FD_INFO_KHR.0,
+            "IMPORT_MEMORY_FD_INFO_KHR",
+            "VK_STRUCTURE_TYPE_IMPORT_MEMORY_FD_INFO_KHR",
+        ),
+        (
+            Self::MEMORY_FD_PROPERTIES_KHR.0,
+            "MEMORY_FD_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_FD_PROPERTIES_KHR",
+        ),
+        (
+            Self::MEMORY_GET_FD_INFO_KHR.0,
+            "MEMORY_GET_FD_INFO_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_GET_FD_INFO_KHR",
+        ),
+        (
+            Self::WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR.0,
+            "WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR",
+        ),
+        (
+            Self::IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR.0,
+            "IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR",
+        ),
+        (
+            Self::EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR.0,
+            "EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR",
+        ),
+        (
+            Self::D3D12_FENCE_SUBMIT_INFO_KHR.0,
+            "D3D12_FENCE_SUBMIT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_D3D12_FENCE_SUBMIT_INFO_KHR",
+        ),
+        (
+            Self::SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR.0,
+            "SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR",
+        ),
+        (
+            Self::IMPORT_SEMAPHORE_FD_INFO_KHR.0,
+            "IMPORT_SEMAPHORE_FD_INFO_KHR",
+            "VK_STRUCTURE_TYPE_IMPORT_SEMAPHORE_FD_INFO_KHR",
+        ),
+        (
+            Self::SEMAPHORE_GET_FD_INFO_KHR.0,
+            "SEMAPHORE_GET_FD_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_GET_FD_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR",
+        ),
+        (
+            Self::COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT.0,
+            "COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT",
+            "VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT",
+        ),
+        (
+            Self::CONDITIONAL_RENDERING_BEGIN_INFO_EXT.0,
+            "CONDITIONAL_RENDERING_BEGIN_INFO_EXT",
+            "VK_STRUCTURE_TYPE_CONDITIONAL_RENDERING_BEGIN_INFO_EXT",
+        ),
+        (
+            Self::PRESENT_REGIONS_KHR.0,
+            "PRESENT_REGIONS_KHR",
+            "VK_STRUCTURE_TYPE_PRESENT_REGIONS_KHR",
+        ),
+        (
+            Self::PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV.0,
+            "PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV",
+        ),
+        (
+            Self::SURFACE_CAPABILITIES_2_EXT.0,
+            "SURFACE_CAPABILITIES_2_EXT",
+            "VK_STRUCTURE_TYPE_SURFACE_CAPABILITIES_2_EXT",
+        ),
+        (
+            Self::DISPLAY_POWER_INFO_EXT.0,
+            "DISPLAY_POWER_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DISPLAY_POWER_INFO_EXT",
+        ),
+        (
+            Self::DEVICE_EVENT_INFO_EXT.0,
+            "DEVICE_EVENT_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEVICE_EVENT_INFO_EXT",
+        ),
+        (
+            Self::DISPLAY_EVENT_INFO_EXT.0,
+            "DISPLAY_EVENT_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DISPLAY_EVENT_INFO_EXT",
+        ),
+        (
+            Self::SWAPCHAIN_COUNTER_CREATE_INFO_EXT.0,
+            "SWAPCHAIN_COUNTER_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SWAPCHAIN_COUNTER_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PRESENT_TIMES_INFO_GOOGLE.0,
+            "PRESENT_TIMES_INFO_GOOGLE",
+            "VK_STRUCTURE_TYPE_PRESENT_TIMES_INFO_GOOGLE",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX.0,
+            "PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX",
+        ),
+        (
+            Self::PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV.0,
+            "PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT",
+        ),
+        (
+            Self::PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT.0,
+            "PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT",
+        ),
+        (
+            Self::PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT.0,
+            "PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT",
+        ),
+        (
+            Self::PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT.0,
+            "PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::HDR_METADATA_EXT.0,
+            "HDR_METADATA_EXT",
+            "VK_STRUCTURE_TYPE_HDR_METADATA_EXT",
+        ),
+        (
+            Self::SHARED_PRESENT_SURFACE_CAPABILITIES_KHR.0,
+            "SHARED_PRESENT_SURFACE_CAPABILITIES_KHR",
+            "VK_STRUCTURE_TYPE_SHARED_PRESENT_SURFACE_CAPABILITIES_KHR",
+        ),
+        (
+            Self::IMPORT_FENCE_WIN32_HANDLE_INFO_KHR.0,
+            "IMPORT_FENCE_WIN32_HANDLE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_IMPORT_FENCE_WIN32_HANDLE_INFO_KHR",
+        ),
+        (
+            Self::EXPORT_FENCE_WIN32_HANDLE_INFO_KHR.0,
+            "EXPORT_FENCE_WIN32_HANDLE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_EXPORT_FENCE_WIN32_HANDLE_INFO_KHR",
+        ),
+        (
+            Self::FENCE_GET_WIN32_HANDLE_INFO_KHR.0,
+            "FENCE_GET_WIN32_HANDLE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_FENCE_GET_WIN32_HANDLE_INFO_KHR",
+        ),
+        (
+            Self::IMPORT_FENCE_FD_INFO_KHR.0,
+            "IMPORT_FENCE_FD_INFO_KHR",
+            "VK_STRUCTURE_TYPE_IMPORT_FENCE_FD_INFO_KHR",
+        ),
+        (
+            Self::FENCE_GET_FD_INFO_KHR.0,
+            "FENCE_GET_FD_INFO_KHR",
+            "VK_STRUCTURE_TYPE_FENCE_GET_FD_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR",
+        ),
+        (
+            Self::QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR.0,
+            "QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PERFORMANCE_QUERY_SUBMIT_INFO_KHR.0,
+            "PERFORMANCE_QUERY_SUBMIT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PERFORMANCE_QUERY_SUBMIT_INFO_KHR",
+        ),
+        (
+            Self::ACQUIRE_PROFILING_LOCK_INFO_KHR.0,
+            "ACQUIRE_PROFILING_LOCK_INFO_KHR",
+            "VK_STRUCTURE_TYPE_ACQUIRE_PROFILING_LOCK_INFO_KHR",
+        ),
+        (
+            Self::PERFORMANCE_COUNTER_KHR.0,
+            "PERFORMANCE_COUNTER_KHR",
+            "VK_STRUCTURE_TYPE_PERFORMANCE_COUNTER_KHR",
+        ),
+        (
+            Self::PERFORMANCE_COUNTER_DESCRIPTION_KHR.0,
+            "PERFORMANCE_COUNTER_DESCRIPTION_KHR",
+            "VK_STRUCTURE_TYPE_PERFORMANCE_COUNTER_DESCRIPTION_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SURFACE_INFO_2_KHR.0,
+            "PHYSICAL_DEVICE_SURFACE_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SURFACE_INFO_2_KHR",
+        ),
+        (
+            Self::SURFACE_CAPABILITIES_2_KHR.0,
+            "SURFACE_CAPABILITIES_2_KHR",
+            "VK_STRUCTURE_TYPE_SURFACE_CAPABILITIES_2_KHR",
+        ),
+        (
+            Self::SURFACE_FORMAT_2_KHR.0,
+            "SURFACE_FORMAT_2_KHR",
+            "VK_STRUCTURE_TYPE_SURFACE_FORMAT_2_KHR",
+        ),
+        (
+            Self::DISPLAY_PROPERTIES_2_KHR.0,
+            "DISPLAY_PROPERTIES_2_KHR",
+            "VK_STRUCTURE_TYPE_DISPLAY_PROPERTIES_2_KHR",
+        ),
+        (
+            Self::DISPLAY_PLANE_PROPERTIES_2_KHR.0,
+            "DISPLAY_PLANE_PROPERTIES_2_KHR",
+            "VK_STRUCTURE_TYPE_DISPLAY_PLANE_PROPERTIES_2_KHR",
+        ),
+        (
+            Self::DISPLAY_MODE_PROPERTIES_2_KHR.0,
+            "DISPLAY_MODE_PROPERTIES_2_KHR",
+            "VK_STRUCTURE_TYPE_DISPLAY_MODE_PROPERTIES_2_KHR",
+        ),
+        (
+            Self::DISPLAY_PLANE_INFO_2_KHR.0,
+            "DISPLAY_PLANE_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_DISPLAY_PLANE_INFO_2_KHR",
+        ),
+        (
+            Self::DISPLAY_PLANE_CAPABILITIES_2_KHR.0,
+            "DISPLAY_PLANE_CAPABILITIES_2_KHR",
+            "VK_STRUCTURE_TYPE_DISPLAY_PLANE_CAPABILITIES_2_KHR",
+        ),
+        (
+            Self::IOS_SURFACE_CREATE_INFO_MVK.0,
+            "IOS_SURFACE_CREATE_INFO_MVK",
+            "VK_STRUCTURE_TYPE_IOS_SURFACE_CREATE_INFO_MVK",
+        ),
+        (
+            Self::MACOS_SURFACE_CREATE_INFO_MVK.0,
+            "MACOS_SURFACE_CREATE_INFO_MVK",
+            "VK_STRUCTURE_TYPE_MACOS_SURFACE_CREATE_INFO_MVK",
+        ),
+        (
+            Self::DEBUG_UTILS_OBJECT_NAME_INFO_EXT.0,
+            "DEBUG_UTILS_OBJECT_NAME_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_NAME_INFO_EXT",
+        ),
+        (
+            Self::DEBUG_UTILS_OBJECT_TAG_INFO_EXT.0,
+            "DEBUG_UTILS_OBJECT_TAG_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_TAG_INFO_EXT",
+        ),
+        (
+            Self::DEBUG_UTILS_LABEL_EXT.0,
+            "DEBUG_UTILS_LABEL_EXT",
+            "VK_STRUCTURE_TYPE_DEBUG_UTILS_LABEL_EXT",
+        ),
+        (
+            Self::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT.0,
+            "DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT",
+            "VK_STRUCTURE_TYPE_DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT",
+        ),
+        (
+            Self::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT.0,
+            "DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT",
+        ),
+        (
+            Self::ANDROID_HARDWARE_BUFFER_USAGE_ANDROID.0,
+            "ANDROID_HARDWARE_BUFFER_USAGE_ANDROID",
+            "VK_STRUCTURE_TYPE_ANDROID_HARDWARE_BUFFER_USAGE_ANDROID",
+        ),
+        (
+            Self::ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID.0,
+            "ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID",
+            "VK_STRUCTURE_TYPE_ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID",
+        ),
+        (
+            Self::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID.0,
+            "ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID",
+            "VK_STRUCTURE_TYPE_ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID",
+        ),
+        (
+            Self::IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID.0,
+            "IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID",
+            "VK_STRUCTURE_TYPE_IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID",
+        ),
+        (
+            Self::MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID.0,
+            "MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID",
+            "VK_STRUCTURE_TYPE_MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID",
+        ),
+        (
+            Self::EXTERNAL_FORMAT_ANDROID.0,
+            "EXTERNAL_FORMAT_ANDROID",
+            "VK_STRUCTURE_TYPE_EXTERNAL_FORMAT_ANDROID",
+        ),
+        (
+            Self::ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_2_ANDROID.0,
+            "ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_2_ANDROID",
+            "VK_STRUCTURE_TYPE_ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_2_ANDROID",
+        ),
+        (
+            Self::SAMPLE_LOCATIONS_INFO_EXT.0,
+            "SAMPLE_LOCATIONS_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SAMPLE_LOCATIONS_INFO_EXT",
+        ),
+        (
+            Self::RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT.0,
+            "RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT",
+        ),
+        (
+            Self::PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT.0,
+            "PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT",
+        ),
+        (
+            Self::MULTISAMPLE_PROPERTIES_EXT.0,
+            "MULTISAMPLE_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_MULTISAMPLE_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT",
+        ),
+        (
+            Self::PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT.0,
+            "PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV.0,
+            "PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV",
+        ),
+        (
+            Self::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR.0,
+            "WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR",
+            "VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR.0,
+            "ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR.0,
+            "ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR.0,
+            "ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR.0,
+            "ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR.0,
+            "ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_GEOMETRY_KHR.0,
+            "ACCELERATION_STRUCTURE_GEOMETRY_KHR",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_GEOMETRY_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_VERSION_INFO_KHR.0,
+            "ACCELERATION_STRUCTURE_VERSION_INFO_KHR",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_VERSION_INFO_KHR",
+        ),
+        (
+            Self::COPY_ACCELERATION_STRUCTURE_INFO_KHR.0,
+            "COPY_ACCELERATION_STRUCTURE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_COPY_ACCELERATION_STRUCTURE_INFO_KHR",
+        ),
+        (
+            Self::COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR.0,
+            "COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR",
+            "VK_STRUCTURE_TYPE_COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR",
+        ),
+        (
+            Self::COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR.0,
+            "COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_CREATE_INFO_KHR.0,
+            "ACCELERATION_STRUCTURE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR.0,
+            "ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR",
+        ),
+        (
+            Self::RAY_TRACING_PIPELINE_CREATE_INFO_KHR.0,
+            "RAY_TRACING_PIPELINE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_RAY_TRACING_PIPELINE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR.0,
+            "RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR",
+        ),
+        (
+            Self::RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR.0,
+            "RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR",
+        ),
+        (
+            Self::PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV.0,
+            "PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV",
+        ),
+        (
+            Self::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT.0,
+            "DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT",
+            "VK_STRUCTURE_TYPE_DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT.0,
+            "PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT",
+        ),
+        (
+            Self::IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT.0,
+            "IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT",
+        ),
+        (
+            Self::IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT.0,
+            "IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT",
+        ),
+        (
+            Self::IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT.0,
+            "IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT",
+        ),
+        (
+            Self::DRM_FORMAT_MODIFIER_PROPERTIES_LIST_2_EXT.0,
+            "DRM_FORMAT_MODIFIER_PROPERTIES_LIST_2_EXT",
+            "VK_STRUCTURE_TYPE_DRM_FORMAT_MODIFIER_PROPERTIES_LIST_2_EXT",
+        ),
+        (
+            Self::VALIDATION_CACHE_CREATE_INFO_EXT.0,
+            "VALIDATION_CACHE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_VALIDATION_CACHE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT.0,
+            "SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR",
+        ),
+        (
+            Self::PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV.0,
+            "PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV",
+        ),
+        (
+            Self::PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV.0,
+            "PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV",
+        ),
+        (
+            Self::RAY_TRACING_PIPELINE_CREATE_INFO_NV.0,
+            "RAY_TRACING_PIPELINE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_RAY_TRACING_PIPELINE_CREATE_INFO_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_CREATE_INFO_NV.0,
+            "ACCELERATION_STRUCTURE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_CREATE_INFO_NV",
+        ),
+        (
+            Self::GEOMETRY_NV.0,
+            "GEOMETRY_NV",
+            "VK_STRUCTURE_TYPE_GEOMETRY_NV",
+        ),
+        (
+            Self::GEOMETRY_TRIANGLES_NV.0,
+            "GEOMETRY_TRIANGLES_NV",
+            "VK_STRUCTURE_TYPE_GEOMETRY_TRIANGLES_NV",
+        ),
+        (
+            Self::GEOMETRY_AABB_NV.0,
+            "GEOMETRY_AABB_NV",
+            "VK_STRUCTURE_TYPE_GEOMETRY_AABB_NV",
+        ),
+        (
+            Self::BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV.0,
+            "BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV",
+            "VK_STRUCTURE_TYPE_BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV",
+        ),
+        (
+            Self::WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV.0,
+            "WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV",
+            "VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV.0,
+            "ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV",
+        ),
+        (
+            Self::RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV.0,
+            "RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_INFO_NV.0,
+            "ACCELERATION_STRUCTURE_INFO_NV",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV",
+        ),
+        (
+            Self::PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV.0,
+            "PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT.0,
+            "PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT",
+        ),
+        (
+            Self::FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT.0,
+            "FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT",
+        ),
+        (
+            Self::IMPORT_MEMORY_HOST_POINTER_INFO_EXT.0,
+            "IMPORT_MEMORY_HOST_POINTER_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMPORT_MEMORY_HOST_POINTER_INFO_EXT",
+        ),
+        (
+            Self::MEMORY_HOST_POINTER_PROPERTIES_EXT.0,
+            "MEMORY_HOST_POINTER_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_MEMORY_HOST_POINTER_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR",
+        ),
+        (
+            Self::PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD.0,
+            "PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD",
+            "VK_STRUCTURE_TYPE_PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD",
+        ),
+        (
+            Self::CALIBRATED_TIMESTAMP_INFO_EXT.0,
+            "CALIBRATED_TIMESTAMP_INFO_EXT",
+            "VK_STRUCTURE_TYPE_CALIBRATED_TIMESTAMP_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD.0,
+            "PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD",
+        ),
+        (
+            Self::VIDEO_DECODE_H265_CAPABILITIES_KHR.0,
+            "VIDEO_DECODE_H265_CAPABILITIES_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H265_CAPABILITIES_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_H265_SESSION_PARAMETERS_CREATE_INFO_KHR.0,
+            "VIDEO_DECODE_H265_SESSION_PARAMETERS_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H265_SESSION_PARAMETERS_CREATE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_H265_SESSION_PARAMETERS_ADD_INFO_KHR.0,
+            "VIDEO_DECODE_H265_SESSION_PARAMETERS_ADD_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H265_SESSION_PARAMETERS_ADD_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_H265_PROFILE_INFO_KHR.0,
+            "VIDEO_DECODE_H265_PROFILE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H265_PROFILE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_H265_PICTURE_INFO_KHR.0,
+            "VIDEO_DECODE_H265_PICTURE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H265_PICTURE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_DECODE_H265_DPB_SLOT_INFO_KHR.0,
+            "VIDEO_DECODE_H265_DPB_SLOT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_DECODE_H265_DPB_SLOT_INFO_KHR",
+        ),
+        (
+            Self::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_KHR.0,
+            "DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_KHR",
+        ),
+        (
+            Self::QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR.0,
+            "QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR",
+        ),
+        (
+            Self::DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD.0,
+            "DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD",
+            "VK_STRUCTURE_TYPE_DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT",
+        ),
+        (
+            Self::PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT.0,
+            "PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT",
+        ),
+        (
+            Self::PRESENT_FRAME_TOKEN_GGP.0,
+            "PRESENT_FRAME_TOKEN_GGP",
+            "VK_STRUCTURE_TYPE_PRESENT_FRAME_TOKEN_GGP",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV",
+        ),
+        (
+            Self::PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV.0,
+            "PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV",
+        ),
+        (
+            Self::CHECKPOINT_DATA_NV.0,
+            "CHECKPOINT_DATA_NV",
+            "VK_STRUCTURE_TYPE_CHECKPOINT_DATA_NV",
+        ),
+        (
+            Self::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV.0,
+            "QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL.0,
+            "PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL",
+        ),
+        (
+            Self::QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL.0,
+            "QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL",
+            "VK_STRUCTURE_TYPE_QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL",
+        ),
+        (
+            Self::INITIALIZE_PERFORMANCE_API_INFO_INTEL.0,
+            "INITIALIZE_PERFORMANCE_API_INFO_INTEL",
+            "VK_STRUCTURE_TYPE_INITIALIZE_PERFORMANCE_API_INFO_INTEL",
+        ),
+        (
+            Self::PERFORMANCE_MARKER_INFO_INTEL.0,
+            "PERFORMANCE_MARKER_INFO_INTEL",
+            "VK_STRUCTURE_TYPE_PERFORMANCE_MARKER_INFO_INTEL",
+        ),
+        (
+            Self::PERFORMANCE_STREAM_MARKER_INFO_INTEL.0,
+            "PERFORMANCE_STREAM_MARKER_INFO_INTEL",
+            "VK_STRUCTURE_TYPE_PERFORMANCE_STREAM_MARKER_INFO_INTEL",
+        ),
+        (
+            Self::PERFORMANCE_OVERRIDE_INFO_INTEL.0,
+            "PERFORMANCE_OVERRIDE_INFO_INTEL",
+            "VK_STRUCTURE_TYPE_PERFORMANCE_OVERRIDE_INFO_INTEL",
+        ),
+        (
+            Self::PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL.0,
+            "PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL",
+            "VK_STRUCTURE_TYPE_PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT",
+        ),
+        (
+            Self::DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD.0,
+            "DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD",
+            "VK_STRUCTURE_TYPE_DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD",
+        ),
+        (
+            Self::SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD.0,
+            "SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD",
+            "VK_STRUCTURE_TYPE_SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD",
+        ),
+        (
+            Self::IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA.0,
+            "IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA",
+        ),
+        (
+            Self::METAL_SURFACE_CREATE_INFO_EXT.0,
+            "METAL_SURFACE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_METAL_SURFACE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT",
+        ),
+        (
+            Self::RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT.0,
+            "RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT",
+        ),
+        (
+            Self::FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR.0,
+            "FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR",
+        ),
+        (
+            Self::PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR.0,
+            "PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR.0,
+            "PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD.0,
+            "PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD.0,
+            "PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT",
+        ),
+        (
+            Self::MEMORY_PRIORITY_ALLOCATE_INFO_EXT.0,
+            "MEMORY_PRIORITY_ALLOCATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_MEMORY_PRIORITY_ALLOCATE_INFO_EXT",
+        ),
+        (
+            Self::SURFACE_PROTECTED_CAPABILITIES_KHR.0,
+            "SURFACE_PROTECTED_CAPABILITIES_KHR",
+            "VK_STRUCTURE_TYPE_SURFACE_PROTECTED_CAPABILITIES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT",
+        ),
+        (
+            Self::BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT.0,
+            "BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT",
+        ),
+        (
+            Self::VALIDATION_FEATURES_EXT.0,
+            "VALIDATION_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_VALIDATION_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV",
+        ),
+        (
+            Self::COOPERATIVE_MATRIX_PROPERTIES_NV.0,
+            "COOPERATIVE_MATRIX_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_COOPERATIVE_MATRIX_PROPERTIES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV",
+        ),
+        (
+            Self::PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV.0,
+            "PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV",
+        ),
+        (
+            Self::FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV.0,
+            "FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV",
+            "VK_STRUCTURE_TYPE_FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT",
+        ),
+        (
+            Self::PIPELINE_RASTERIZATION_PROVOKING_VERTEX_STATE_CREATE_INFO_EXT.0,
+            "PIPELINE_RASTERIZATION_PROVOKING_VERTEX_STATE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_PROVOKING_VERTEX_STATE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT",
+        ),
+        (
+            Self::SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT.0,
+            "SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT",
+        ),
+        (
+            Self::SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT.0,
+            "SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT",
+            "VK_STRUCTURE_TYPE_SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT",
+        ),
+        (
+            Self::SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT.0,
+            "SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT",
+        ),
+        (
+            Self::HEADLESS_SURFACE_CREATE_INFO_EXT.0,
+            "HEADLESS_SURFACE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_HEADLESS_SURFACE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT",
+        ),
+        (
+            Self::PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT.0,
+            "PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR",
+        ),
+        (
+            Self::PIPELINE_INFO_KHR.0,
+            "PIPELINE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PIPELINE_INFO_KHR",
+        ),
+        (
+            Self::PIPELINE_EXECUTABLE_PROPERTIES_KHR.0,
+            "PIPELINE_EXECUTABLE_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PIPELINE_EXECUTABLE_PROPERTIES_KHR",
+        ),
+        (
+            Self::PIPELINE_EXECUTABLE_INFO_KHR.0,
+            "PIPELINE_EXECUTABLE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PIPELINE_EXECUTABLE_INFO_KHR",
+        ),
+        (
+            Self::PIPELINE_EXECUTABLE_STATISTIC_KHR.0,
+            "PIPELINE_EXECUTABLE_STATISTIC_KHR",
+            "VK_STRUCTURE_TYPE_PIPELINE_EXECUTABLE_STATISTIC_KHR",
+        ),
+        (
+            Self::PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR.0,
+            "PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR",
+            "VK_STRUCTURE_TYPE_PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR",
+        ),
+        (
+            Self::MEMORY_MAP_INFO_KHR.0,
+            "MEMORY_MAP_INFO_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_MAP_INFO_KHR",
+        ),
+        (
+            Self::MEMORY_UNMAP_INFO_KHR.0,
+            "MEMORY_UNMAP_INFO_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_UNMAP_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_2_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_2_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_2_FEATURES_EXT",
+        ),
+        (
+            Self::SURFACE_PRESENT_MODE_EXT.0,
+            "SURFACE_PRESENT_MODE_EXT",
+            "VK_STRUCTURE_TYPE_SURFACE_PRESENT_MODE_EXT",
+        ),
+        (
+            Self::SURFACE_PRESENT_SCALING_CAPABILITIES_EXT.0,
+            "SURFACE_PRESENT_SCALING_CAPABILITIES_EXT",
+            "VK_STRUCTURE_TYPE_SURFACE_PRESENT_SCALING_CAPABILITIES_EXT",
+        ),
+        (
+            Self::SURFACE_PRESENT_MODE_COMPATIBILITY_EXT.0,
+            "SURFACE_PRESENT_MODE_COMPATIBILITY_EXT",
+            "VK_STRUCTURE_TYPE_SURFACE_PRESENT_MODE_COMPATIBILITY_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SWAPCHAIN_MAINTENANCE_1_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SWAPCHAIN_MAINTENANCE_1_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SWAPCHAIN_MAINTENANCE_1_FEATURES_EXT",
+        ),
+        (
+            Self::SWAPCHAIN_PRESENT_FENCE_INFO_EXT.0,
+            "SWAPCHAIN_PRESENT_FENCE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SWAPCHAIN_PRESENT_FENCE_INFO_EXT",
+        ),
+        (
+            Self::SWAPCHAIN_PRESENT_MODES_CREATE_INFO_EXT.0,
+            "SWAPCHAIN_PRESENT_MODES_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SWAPCHAIN_PRESENT_MODES_CREATE_INFO_EXT",
+        ),
+        (
+            Self::SWAPCHAIN_PRESENT_MODE_INFO_EXT.0,
+            "SWAPCHAIN_PRESENT_MODE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SWAPCHAIN_PRESENT_MODE_INFO_EXT",
+        ),
+        (
+            Self::SWAPCHAIN_PRESENT_SCALING_CREATE_INFO_EXT.0,
+            "SWAPCHAIN_PRESENT_SCALING_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SWAPCHAIN_PRESENT_SCALING_CREATE_INFO_EXT",
+        ),
+        (
+            Self::RELEASE_SWAPCHAIN_IMAGES_INFO_EXT.0,
+            "RELEASE_SWAPCHAIN_IMAGES_INFO_EXT",
+            "VK_STRUCTURE_TYPE_RELEASE_SWAPCHAIN_IMAGES_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV",
+        ),
+        (
+            Self::GRAPHICS_SHADER_GROUP_CREATE_INFO_NV.0,
+            "GRAPHICS_SHADER_GROUP_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_GRAPHICS_SHADER_GROUP_CREATE_INFO_NV",
+        ),
+        (
+            Self::GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV.0,
+            "GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV",
+        ),
+        (
+            Self::INDIRECT_COMMANDS_LAYOUT_TOKEN_NV.0,
+            "INDIRECT_COMMANDS_LAYOUT_TOKEN_NV",
+            "VK_STRUCTURE_TYPE_INDIRECT_COMMANDS_LAYOUT_TOKEN_NV",
+        ),
+        (
+            Self::INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV.0,
+            "INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV",
+        ),
+        (
+            Self::GENERATED_COMMANDS_INFO_NV.0,
+            "GENERATED_COMMANDS_INFO_NV",
+            "VK_STRUCTURE_TYPE_GENERATED_COMMANDS_INFO_NV",
+        ),
+        (
+            Self::GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV.0,
+            "GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV",
+            "VK_STRUCTURE_TYPE_GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_INHERITED_VIEWPORT_SCISSOR_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_INHERITED_VIEWPORT_SCISSOR_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INHERITED_VIEWPORT_SCISSOR_FEATURES_NV",
+        ),
+        (
+            Self::COMMAND_BUFFER_INHERITANCE_VIEWPORT_SCISSOR_INFO_NV.0,
+            "COMMAND_BUFFER_INHERITANCE_VIEWPORT_SCISSOR_INFO_NV",
+            "VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_VIEWPORT_SCISSOR_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT",
+        ),
+        (
+            Self::COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM.0,
+            "COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM",
+            "VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM",
+        ),
+        (
+            Self::RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM.0,
+            "RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DEPTH_BIAS_CONTROL_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_DEPTH_BIAS_CONTROL_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEPTH_BIAS_CONTROL_FEATURES_EXT",
+        ),
+        (
+            Self::DEPTH_BIAS_INFO_EXT.0,
+            "DEPTH_BIAS_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEPTH_BIAS_INFO_EXT",
+        ),
+        (
+            Self::DEPTH_BIAS_REPRESENTATION_INFO_EXT.0,
+            "DEPTH_BIAS_REPRESENTATION_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEPTH_BIAS_REPRESENTATION_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT",
+        ),
+        (
+            Self::DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT.0,
+            "DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT",
+        ),
+        (
+            Self::DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT.0,
+            "DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT",
+            "VK_STRUCTURE_TYPE_DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT",
+        ),
+        (
+            Self::SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT.0,
+            "SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT",
+        ),
+        (
+            Self::PIPELINE_LIBRARY_CREATE_INFO_KHR.0,
+            "PIPELINE_LIBRARY_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PIPELINE_LIBRARY_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV",
+        ),
+        (
+            Self::SURFACE_CAPABILITIES_PRESENT_BARRIER_NV.0,
+            "SURFACE_CAPABILITIES_PRESENT_BARRIER_NV",
+            "VK_STRUCTURE_TYPE_SURFACE_CAPABILITIES_PRESENT_BARRIER_NV",
+        ),
+        (
+            Self::SWAPCHAIN_PRESENT_BARRIER_CREATE_INFO_NV.0,
+            "SWAPCHAIN_PRESENT_BARRIER_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_SWAPCHAIN_PRESENT_BARRIER_CREATE_INFO_NV",
+        ),
+        (
+            Self::PRESENT_ID_KHR.0,
+            "PRESENT_ID_KHR",
+            "VK_STRUCTURE_TYPE_PRESENT_ID_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_INFO_KHR.0,
+            "VIDEO_ENCODE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_RATE_CONTROL_INFO_KHR.0,
+            "VIDEO_ENCODE_RATE_CONTROL_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_RATE_CONTROL_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_RATE_CONTROL_LAYER_INFO_KHR.0,
+            "VIDEO_ENCODE_RATE_CONTROL_LAYER_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_RATE_CONTROL_LAYER_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_CAPABILITIES_KHR.0,
+            "VIDEO_ENCODE_CAPABILITIES_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_CAPABILITIES_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_USAGE_INFO_KHR.0,
+            "VIDEO_ENCODE_USAGE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_USAGE_INFO_KHR",
+        ),
+        (
+            Self::QUERY_POOL_VIDEO_ENCODE_FEEDBACK_CREATE_INFO_KHR.0,
+            "QUERY_POOL_VIDEO_ENCODE_FEEDBACK_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_QUERY_POOL_VIDEO_ENCODE_FEEDBACK_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VIDEO_ENCODE_QUALITY_LEVEL_INFO_KHR.0,
+            "PHYSICAL_DEVICE_VIDEO_ENCODE_QUALITY_LEVEL_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VIDEO_ENCODE_QUALITY_LEVEL_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_QUALITY_LEVEL_PROPERTIES_KHR.0,
+            "VIDEO_ENCODE_QUALITY_LEVEL_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_QUALITY_LEVEL_PROPERTIES_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_QUALITY_LEVEL_INFO_KHR.0,
+            "VIDEO_ENCODE_QUALITY_LEVEL_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_QUALITY_LEVEL_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_SESSION_PARAMETERS_GET_INFO_KHR.0,
+            "VIDEO_ENCODE_SESSION_PARAMETERS_GET_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_SESSION_PARAMETERS_GET_INFO_KHR",
+        ),
+        (
+            Self::VIDEO_ENCODE_SESSION_PARAMETERS_FEEDBACK_INFO_KHR.0,
+            "VIDEO_ENCODE_SESSION_PARAMETERS_FEEDBACK_INFO_KHR",
+            "VK_STRUCTURE_TYPE_VIDEO_ENCODE_SESSION_PARAMETERS_FEEDBACK_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV",
+        ),
+        (
+            Self::DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV.0,
+            "DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV",
+        ),
+        (
+            Self::QUERY_LOW_LATENCY_SUPPORT_NV.0,
+            "QUERY_LOW_LATENCY_SUPPORT_NV",
+            "VK_STRUCTURE_TYPE_QUERY_LOW_LATENCY_SUPPORT_NV",
+        ),
+        (
+            Self::EXPORT_METAL_OBJECT_CREATE_INFO_EXT.0,
+            "EXPORT_METAL_OBJECT_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_EXPORT_METAL_OBJECT_CREATE_INFO_EXT",
+        ),
+        (
+            Self::EXPORT_METAL_OBJECTS_INFO_EXT.0,
+            "EXPORT_METAL_OBJECTS_INFO_EXT",
+            "VK_STRUCTURE_TYPE_EXPORT_METAL_OBJECTS_INFO_EXT",
+        ),
+        (
+            Self::EXPORT_METAL_DEVICE_INFO_EXT.0,
+            "EXPORT_METAL_DEVICE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_EXPORT_METAL_DEVICE_INFO_EXT",
+        ),
+        (
+            Self::EXPORT_METAL_COMMAND_QUEUE_INFO_EXT.0,
+            "EXPORT_METAL_COMMAND_QUEUE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_EXPORT_METAL_COMMAND_QUEUE_INFO_EXT",
+        ),
+        (
+            Self::EXPORT_METAL_BUFFER_INFO_EXT.0,
+            "EXPORT_METAL_BUFFER_INFO_EXT",
+            "VK_STRUCTURE_TYPE_EXPORT_METAL_BUFFER_INFO_EXT",
+        ),
+        (
+            Self::IMPORT_METAL_BUFFER_INFO_EXT.0,
+            "IMPORT_METAL_BUFFER_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMPORT_METAL_BUFFER_INFO_EXT",
+        ),
+        (
+            Self::EXPORT_METAL_TEXTURE_INFO_EXT.0,
+            "EXPORT_METAL_TEXTURE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_EXPORT_METAL_TEXTURE_INFO_EXT",
+        ),
+        (
+            Self::IMPORT_METAL_TEXTURE_INFO_EXT.0,
+            "IMPORT_METAL_TEXTURE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMPORT_METAL_TEXTURE_INFO_EXT",
+        ),
+        (
+            Self::EXPORT_METAL_IO_SURFACE_INFO_EXT.0,
+            "EXPORT_METAL_IO_SURFACE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_EXPORT_METAL_IO_SURFACE_INFO_EXT",
+        ),
+        (
+            Self::IMPORT_METAL_IO_SURFACE_INFO_EXT.0,
+            "IMPORT_METAL_IO_SURFACE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMPORT_METAL_IO_SURFACE_INFO_EXT",
+        ),
+        (
+            Self::EXPORT_METAL_SHARED_EVENT_INFO_EXT.0,
+            "EXPORT_METAL_SHARED_EVENT_INFO_EXT",
+            "VK_STRUCTURE_TYPE_EXPORT_METAL_SHARED_EVENT_INFO_EXT",
+        ),
+        (
+            Self::IMPORT_METAL_SHARED_EVENT_INFO_EXT.0,
+            "IMPORT_METAL_SHARED_EVENT_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMPORT_METAL_SHARED_EVENT_INFO_EXT",
+        ),
+        (
+            Self::QUEUE_FAMILY_CHECKPOINT_PROPERTIES_2_NV.0,
+            "QUEUE_FAMILY_CHECKPOINT_PROPERTIES_2_NV",
+            "VK_STRUCTURE_TYPE_QUEUE_FAMILY_CHECKPOINT_PROPERTIES_2_NV",
+        ),
+        (
+            Self::CHECKPOINT_DATA_2_NV.0,
+            "CHECKPOINT_DATA_2_NV",
+            "VK_STRUCTURE_TYPE_CHECKPOINT_DATA_2_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_DENSITY_MAP_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_DENSITY_MAP_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_DENSITY_MAP_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_FEATURES_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_ADDRESS_INFO_EXT.0,
+            "DESCRIPTOR_ADDRESS_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_ADDRESS_INFO_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_GET_INFO_EXT.0,
+            "DESCRIPTOR_GET_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_GET_INFO_EXT",
+        ),
+        (
+            Self::BUFFER_CAPTURE_DESCRIPTOR_DATA_INFO_EXT.0,
+            "BUFFER_CAPTURE_DESCRIPTOR_DATA_INFO_EXT",
+            "VK_STRUCTURE_TYPE_BUFFER_CAPTURE_DESCRIPTOR_DATA_INFO_EXT",
+        ),
+        (
+            Self::IMAGE_CAPTURE_DESCRIPTOR_DATA_INFO_EXT.0,
+            "IMAGE_CAPTURE_DESCRIPTOR_DATA_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_CAPTURE_DESCRIPTOR_DATA_INFO_EXT",
+        ),
+        (
+            Self::IMAGE_VIEW_CAPTURE_DESCRIPTOR_DATA_INFO_EXT.0,
+            "IMAGE_VIEW_CAPTURE_DESCRIPTOR_DATA_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_VIEW_CAPTURE_DESCRIPTOR_DATA_INFO_EXT",
+        ),
+        (
+            Self::SAMPLER_CAPTURE_DESCRIPTOR_DATA_INFO_EXT.0,
+            "SAMPLER_CAPTURE_DESCRIPTOR_DATA_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SAMPLER_CAPTURE_DESCRIPTOR_DATA_INFO_EXT",
+        ),
+        (
+            Self::OPAQUE_CAPTURE_DESCRIPTOR_DATA_CREATE_INFO_EXT.0,
+            "OPAQUE_CAPTURE_DESCRIPTOR_DATA_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_OPAQUE_CAPTURE_DESCRIPTOR_DATA_CREATE_INFO_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_BUFFER_BINDING_INFO_EXT.0,
+            "DESCRIPTOR_BUFFER_BINDING_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_BUFFER_BINDING_INFO_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_BUFFER_BINDING_PUSH_DESCRIPTOR_BUFFER_HANDLE_EXT.0,
+            "DESCRIPTOR_BUFFER_BINDING_PUSH_DESCRIPTOR_BUFFER_HANDLE_EXT",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_BUFFER_BINDING_PUSH_DESCRIPTOR_BUFFER_HANDLE_EXT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_CAPTURE_DESCRIPTOR_DATA_INFO_EXT.0,
+            "ACCELERATION_STRUCTURE_CAPTURE_DESCRIPTOR_DATA_INFO_EXT",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_CAPTURE_DESCRIPTOR_DATA_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_PROPERTIES_EXT",
+        ),
+        (
+            Self::GRAPHICS_PIPELINE_LIBRARY_CREATE_INFO_EXT.0,
+            "GRAPHICS_PIPELINE_LIBRARY_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_GRAPHICS_PIPELINE_LIBRARY_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_FEATURES_AMD.0,
+            "PHYSICAL_DEVICE_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_FEATURES_AMD",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_FEATURES_AMD",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_PROPERTIES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV",
+        ),
+        (
+            Self::PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV.0,
+            "PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_GEOMETRY_MOTION_TRIANGLES_DATA_NV.0,
+            "ACCELERATION_STRUCTURE_GEOMETRY_MOTION_TRIANGLES_DATA_NV",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_GEOMETRY_MOTION_TRIANGLES_DATA_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_MOTION_INFO_NV.0,
+            "ACCELERATION_STRUCTURE_MOTION_INFO_NV",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_MOTION_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_YCBCR_2_PLANE_444_FORMATS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_YCBCR_2_PLANE_444_FORMATS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_YCBCR_2_PLANE_444_FORMATS_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT",
+        ),
+        (
+            Self::COPY_COMMAND_TRANSFORM_INFO_QCOM.0,
+            "COPY_COMMAND_TRANSFORM_INFO_QCOM",
+            "VK_STRUCTURE_TYPE_COPY_COMMAND_TRANSFORM_INFO_QCOM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_FEATURES_EXT",
+        ),
+        (
+            Self::IMAGE_COMPRESSION_CONTROL_EXT.0,
+            "IMAGE_COMPRESSION_CONTROL_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_COMPRESSION_CONTROL_EXT",
+        ),
+        (
+            Self::SUBRESOURCE_LAYOUT_2_EXT.0,
+            "SUBRESOURCE_LAYOUT_2_EXT",
+            "VK_STRUCTURE_TYPE_SUBRESOURCE_LAYOUT_2_EXT",
+        ),
+        (
+            Self::IMAGE_SUBRESOURCE_2_EXT.0,
+            "IMAGE_SUBRESOURCE_2_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_SUBRESOURCE_2_EXT",
+        ),
+        (
+            Self::IMAGE_COMPRESSION_PROPERTIES_EXT.0,
+            "IMAGE_COMPRESSION_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_COMPRESSION_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FAULT_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_FAULT_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FAULT_FEATURES_EXT",
+        ),
+        (
+            Self::DEVICE_FAULT_COUNTS_EXT.0,
+            "DEVICE_FAULT_COUNTS_EXT",
+            "VK_STRUCTURE_TYPE_DEVICE_FAULT_COUNTS_EXT",
+        ),
+        (
+            Self::DEVICE_FAULT_INFO_EXT.0,
+            "DEVICE_FAULT_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEVICE_FAULT_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RGBA10X6_FORMATS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_RGBA10X6_FORMATS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RGBA10X6_FORMATS_FEATURES_EXT",
+        ),
+        (
+            Self::DIRECTFB_SURFACE_CREATE_INFO_EXT.0,
+            "DIRECTFB_SURFACE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DIRECTFB_SURFACE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VERTEX_INPUT_DYNAMIC_STATE_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_VERTEX_INPUT_DYNAMIC_STATE_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VERTEX_INPUT_DYNAMIC_STATE_FEATURES_EXT",
+        ),
+        (
+            Self::VERTEX_INPUT_BINDING_DESCRIPTION_2_EXT.0,
+            "VERTEX_INPUT_BINDING_DESCRIPTION_2_EXT",
+            "VK_STRUCTURE_TYPE_VERTEX_INPUT_BINDING_DESCRIPTION_2_EXT",
+        ),
+        (
+            Self::VERTEX_INPUT_ATTRIBUTE_DESCRIPTION_2_EXT.0,
+            "VERTEX_INPUT_ATTRIBUTE_DESCRIPTION_2_EXT",
+            "VK_STRUCTURE_TYPE_VERTEX_INPUT_ATTRIBUTE_DESCRIPTION_2_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DRM_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_DRM_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DRM_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT",
+        ),
+        (
+            Self::DEVICE_ADDRESS_BINDING_CALLBACK_DATA_EXT.0,
+            "DEVICE_ADDRESS_BINDING_CALLBACK_DATA_EXT",
+            "VK_STRUCTURE_TYPE_DEVICE_ADDRESS_BINDING_CALLBACK_DATA_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT",
+        ),
+        (
+            Self::PIPELINE_VIEWPORT_DEPTH_CLIP_CONTROL_CREATE_INFO_EXT.0,
+            "PIPELINE_VIEWPORT_DEPTH_CLIP_CONTROL_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_VIEWPORT_DEPTH_CLIP_CONTROL_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PRIMITIVE_TOPOLOGY_LIST_RESTART_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_PRIMITIVE_TOPOLOGY_LIST_RESTART_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PRIMITIVE_TOPOLOGY_LIST_RESTART_FEATURES_EXT",
+        ),
+        (
+            Self::IMPORT_MEMORY_ZIRCON_HANDLE_INFO_FUCHSIA.0,
+            "IMPORT_MEMORY_ZIRCON_HANDLE_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_IMPORT_MEMORY_ZIRCON_HANDLE_INFO_FUCHSIA",
+        ),
+        (
+            Self::MEMORY_ZIRCON_HANDLE_PROPERTIES_FUCHSIA.0,
+            "MEMORY_ZIRCON_HANDLE_PROPERTIES_FUCHSIA",
+            "VK_STRUCTURE_TYPE_MEMORY_ZIRCON_HANDLE_PROPERTIES_FUCHSIA",
+        ),
+        (
+            Self::MEMORY_GET_ZIRCON_HANDLE_INFO_FUCHSIA.0,
+            "MEMORY_GET_ZIRCON_HANDLE_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_MEMORY_GET_ZIRCON_HANDLE_INFO_FUCHSIA",
+        ),
+        (
+            Self::IMPORT_SEMAPHORE_ZIRCON_HANDLE_INFO_FUCHSIA.0,
+            "IMPORT_SEMAPHORE_ZIRCON_HANDLE_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_IMPORT_SEMAPHORE_ZIRCON_HANDLE_INFO_FUCHSIA",
+        ),
+        (
+            Self::SEMAPHORE_GET_ZIRCON_HANDLE_INFO_FUCHSIA.0,
+            "SEMAPHORE_GET_ZIRCON_HANDLE_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_GET_ZIRCON_HANDLE_INFO_FUCHSIA",
+        ),
+        (
+            Self::BUFFER_COLLECTION_CREATE_INFO_FUCHSIA.0,
+            "BUFFER_COLLECTION_CREATE_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_BUFFER_COLLECTION_CREATE_INFO_FUCHSIA",
+        ),
+        (
+            Self::IMPORT_MEMORY_BUFFER_COLLECTION_FUCHSIA.0,
+            "IMPORT_MEMORY_BUFFER_COLLECTION_FUCHSIA",
+            "VK_STRUCTURE_TYPE_IMPORT_MEMORY_BUFFER_COLLECTION_FUCHSIA",
+        ),
+        (
+            Self::BUFFER_COLLECTION_IMAGE_CREATE_INFO_FUCHSIA.0,
+            "BUFFER_COLLECTION_IMAGE_CREATE_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_BUFFER_COLLECTION_IMAGE_CREATE_INFO_FUCHSIA",
+        ),
+        (
+            Self::BUFFER_COLLECTION_PROPERTIES_FUCHSIA.0,
+            "BUFFER_COLLECTION_PROPERTIES_FUCHSIA",
+            "VK_STRUCTURE_TYPE_BUFFER_COLLECTION_PROPERTIES_FUCHSIA",
+        ),
+        (
+            Self::BUFFER_CONSTRAINTS_INFO_FUCHSIA.0,
+            "BUFFER_CONSTRAINTS_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_BUFFER_CONSTRAINTS_INFO_FUCHSIA",
+        ),
+        (
+            Self::BUFFER_COLLECTION_BUFFER_CREATE_INFO_FUCHSIA.0,
+            "BUFFER_COLLECTION_BUFFER_CREATE_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_BUFFER_COLLECTION_BUFFER_CREATE_INFO_FUCHSIA",
+        ),
+        (
+            Self::IMAGE_CONSTRAINTS_INFO_FUCHSIA.0,
+            "IMAGE_CONSTRAINTS_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_IMAGE_CONSTRAINTS_INFO_FUCHSIA",
+        ),
+        (
+            Self::IMAGE_FORMAT_CONSTRAINTS_INFO_FUCHSIA.0,
+            "IMAGE_FORMAT_CONSTRAINTS_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_IMAGE_FORMAT_CONSTRAINTS_INFO_FUCHSIA",
+        ),
+        (
+            Self::SYSMEM_COLOR_SPACE_FUCHSIA.0,
+            "SYSMEM_COLOR_SPACE_FUCHSIA",
+            "VK_STRUCTURE_TYPE_SYSMEM_COLOR_SPACE_FUCHSIA",
+        ),
+        (
+            Self::BUFFER_COLLECTION_CONSTRAINTS_INFO_FUCHSIA.0,
+            "BUFFER_COLLECTION_CONSTRAINTS_INFO_FUCHSIA",
+            "VK_STRUCTURE_TYPE_BUFFER_COLLECTION_CONSTRAINTS_INFO_FUCHSIA",
+        ),
+        (
+            Self::SUBPASS_SHADING_PIPELINE_CREATE_INFO_HUAWEI.0,
+            "SUBPASS_SHADING_PIPELINE_CREATE_INFO_HUAWEI",
+            "VK_STRUCTURE_TYPE_SUBPASS_SHADING_PIPELINE_CREATE_INFO_HUAWEI",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI.0,
+            "PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI.0,
+            "PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI.0,
+            "PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI",
+        ),
+        (
+            Self::MEMORY_GET_REMOTE_ADDRESS_INFO_NV.0,
+            "MEMORY_GET_REMOTE_ADDRESS_INFO_NV",
+            "VK_STRUCTURE_TYPE_MEMORY_GET_REMOTE_ADDRESS_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV",
+        ),
+        (
+            Self::PIPELINE_PROPERTIES_IDENTIFIER_EXT.0,
+            "PIPELINE_PROPERTIES_IDENTIFIER_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_PROPERTIES_IDENTIFIER_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_FEATURES_EXT",
+        ),
+        (
+            Self::SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT.0,
+            "SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT",
+            "VK_STRUCTURE_TYPE_SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT",
+        ),
+        (
+            Self::MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_INFO_EXT.0,
+            "MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_INFO_EXT",
+            "VK_STRUCTURE_TYPE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_2_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_2_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_2_FEATURES_EXT",
+        ),
+        (
+            Self::SCREEN_SURFACE_CREATE_INFO_QNX.0,
+            "SCREEN_SURFACE_CREATE_INFO_QNX",
+            "VK_STRUCTURE_TYPE_SCREEN_SURFACE_CREATE_INFO_QNX",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT",
+        ),
+        (
+            Self::PIPELINE_COLOR_WRITE_CREATE_INFO_EXT.0,
+            "PIPELINE_COLOR_WRITE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_COLOR_WRITE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PRIMITIVES_GENERATED_QUERY_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_PRIMITIVES_GENERATED_QUERY_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PRIMITIVES_GENERATED_QUERY_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE_1_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE_1_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE_1_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT",
+        ),
+        (
+            Self::IMAGE_VIEW_MIN_LOD_CREATE_INFO_EXT.0,
+            "IMAGE_VIEW_MIN_LOD_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_VIEW_MIN_LOD_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_2D_VIEW_OF_3D_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_IMAGE_2D_VIEW_OF_3D_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_2D_VIEW_OF_3D_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_TILE_IMAGE_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SHADER_TILE_IMAGE_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_TILE_IMAGE_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_TILE_IMAGE_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_SHADER_TILE_IMAGE_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_TILE_IMAGE_PROPERTIES_EXT",
+        ),
+        (
+            Self::MICROMAP_BUILD_INFO_EXT.0,
+            "MICROMAP_BUILD_INFO_EXT",
+            "VK_STRUCTURE_TYPE_MICROMAP_BUILD_INFO_EXT",
+        ),
+        (
+            Self::MICROMAP_VERSION_INFO_EXT.0,
+            "MICROMAP_VERSION_INFO_EXT",
+            "VK_STRUCTURE_TYPE_MICROMAP_VERSION_INFO_EXT",
+        ),
+        (
+            Self::COPY_MICROMAP_INFO_EXT.0,
+            "COPY_MICROMAP_INFO_EXT",
+            "VK_STRUCTURE_TYPE_COPY_MICROMAP_INFO_EXT",
+        ),
+        (
+            Self::COPY_MICROMAP_TO_MEMORY_INFO_EXT.0,
+            "COPY_MICROMAP_TO_MEMORY_INFO_EXT",
+            "VK_STRUCTURE_TYPE_COPY_MICROMAP_TO_MEMORY_INFO_EXT",
+        ),
+        (
+            Self::COPY_MEMORY_TO_MICROMAP_INFO_EXT.0,
+            "COPY_MEMORY_TO_MICROMAP_INFO_EXT",
+            "VK_STRUCTURE_TYPE_COPY_MEMORY_TO_MICROMAP_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT",
+        ),
+        (
+            Self::MICROMAP_CREATE_INFO_EXT.0,
+            "MICROMAP_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_MICROMAP_CREATE_INFO_EXT",
+        ),
+        (
+            Self::MICROMAP_BUILD_SIZES_INFO_EXT.0,
+            "MICROMAP_BUILD_SIZES_INFO_EXT",
+            "VK_STRUCTURE_TYPE_MICROMAP_BUILD_SIZES_INFO_EXT",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_TRIANGLES_OPACITY_MICROMAP_EXT.0,
+            "ACCELERATION_STRUCTURE_TRIANGLES_OPACITY_MICROMAP_EXT",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_TRIANGLES_OPACITY_MICROMAP_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_PROPERTIES_NV",
+        ),
+        (
+            Self::ACCELERATION_STRUCTURE_TRIANGLES_DISPLACEMENT_MICROMAP_NV.0,
+            "ACCELERATION_STRUCTURE_TRIANGLES_DISPLACEMENT_MICROMAP_NV",
+            "VK_STRUCTURE_TYPE_ACCELERATION_STRUCTURE_TRIANGLES_DISPLACEMENT_MICROMAP_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_FEATURES_HUAWEI.0,
+            "PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_FEATURES_HUAWEI",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_FEATURES_HUAWEI",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_PROPERTIES_HUAWEI.0,
+            "PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_PROPERTIES_HUAWEI",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_CLUSTER_CULLING_SHADER_PROPERTIES_HUAWEI",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT",
+        ),
+        (
+            Self::SAMPLER_BORDER_COLOR_COMPONENT_MAPPING_CREATE_INFO_EXT.0,
+            "SAMPLER_BORDER_COLOR_COMPONENT_MAPPING_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SAMPLER_BORDER_COLOR_COMPONENT_MAPPING_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PAGEABLE_DEVICE_LOCAL_MEMORY_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_PAGEABLE_DEVICE_LOCAL_MEMORY_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PAGEABLE_DEVICE_LOCAL_MEMORY_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_ARM.0,
+            "PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_ARM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_ARM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_SLICED_VIEW_OF_3D_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_IMAGE_SLICED_VIEW_OF_3D_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_SLICED_VIEW_OF_3D_FEATURES_EXT",
+        ),
+        (
+            Self::IMAGE_VIEW_SLICED_CREATE_INFO_EXT.0,
+            "IMAGE_VIEW_SLICED_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_VIEW_SLICED_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DESCRIPTOR_SET_HOST_MAPPING_FEATURES_VALVE.0,
+            "PHYSICAL_DEVICE_DESCRIPTOR_SET_HOST_MAPPING_FEATURES_VALVE",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_SET_HOST_MAPPING_FEATURES_VALVE",
+        ),
+        (
+            Self::DESCRIPTOR_SET_BINDING_REFERENCE_VALVE.0,
+            "DESCRIPTOR_SET_BINDING_REFERENCE_VALVE",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_BINDING_REFERENCE_VALVE",
+        ),
+        (
+            Self::DESCRIPTOR_SET_LAYOUT_HOST_MAPPING_INFO_VALVE.0,
+            "DESCRIPTOR_SET_LAYOUT_HOST_MAPPING_INFO_VALVE",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_HOST_MAPPING_INFO_VALVE",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_FEATURES_QCOM.0,
+            "PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_FEATURES_QCOM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_FEATURES_QCOM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_PROPERTIES_QCOM.0,
+            "PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_PROPERTIES_QCOM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_PROPERTIES_QCOM",
+        ),
+        (
+            Self::SUBPASS_FRAGMENT_DENSITY_MAP_OFFSET_END_INFO_QCOM.0,
+            "SUBPASS_FRAGMENT_DENSITY_MAP_OFFSET_END_INFO_QCOM",
+            "VK_STRUCTURE_TYPE_SUBPASS_FRAGMENT_DENSITY_MAP_OFFSET_END_INFO_QCOM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_PROPERTIES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_PROPERTIES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM.0,
+            "PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM.0,
+            "PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM",
+        ),
+        (
+            Self::IMAGE_VIEW_SAMPLE_WEIGHT_CREATE_INFO_QCOM.0,
+            "IMAGE_VIEW_SAMPLE_WEIGHT_CREATE_INFO_QCOM",
+            "VK_STRUCTURE_TYPE_IMAGE_VIEW_SAMPLE_WEIGHT_CREATE_INFO_QCOM",
+        ),
+        (
+            Self::EXTERNAL_MEMORY_ACQUIRE_UNMODIFIED_EXT.0,
+            "EXTERNAL_MEMORY_ACQUIRE_UNMODIFIED_EXT",
+            "VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_ACQUIRE_UNMODIFIED_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT",
+        ),
+        (
+            Self::RENDER_PASS_CREATION_CONTROL_EXT.0,
+            "RENDER_PASS_CREATION_CONTROL_EXT",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_CREATION_CONTROL_EXT",
+        ),
+        (
+            Self::RENDER_PASS_CREATION_FEEDBACK_CREATE_INFO_EXT.0,
+            "RENDER_PASS_CREATION_FEEDBACK_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_CREATION_FEEDBACK_CREATE_INFO_EXT",
+        ),
+        (
+            Self::RENDER_PASS_SUBPASS_FEEDBACK_CREATE_INFO_EXT.0,
+            "RENDER_PASS_SUBPASS_FEEDBACK_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_SUBPASS_FEEDBACK_CREATE_INFO_EXT",
+        ),
+        (
+            Self::DIRECT_DRIVER_LOADING_INFO_LUNARG.0,
+            "DIRECT_DRIVER_LOADING_INFO_LUNARG",
+            "VK_STRUCTURE_TYPE_DIRECT_DRIVER_LOADING_INFO_LUNARG",
+        ),
+        (
+            Self::DIRECT_DRIVER_LOADING_LIST_LUNARG.0,
+            "DIRECT_DRIVER_LOADING_LIST_LUNARG",
+            "VK_STRUCTURE_TYPE_DIRECT_DRIVER_LOADING_LIST_LUNARG",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_PROPERTIES_EXT",
+        ),
+        (
+            Self::PIPELINE_SHADER_STAGE_MODULE_IDENTIFIER_CREATE_INFO_EXT.0,
+            "PIPELINE_SHADER_STAGE_MODULE_IDENTIFIER_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_MODULE_IDENTIFIER_CREATE_INFO_EXT",
+        ),
+        (
+            Self::SHADER_MODULE_IDENTIFIER_EXT.0,
+            "SHADER_MODULE_IDENTIFIER_EXT",
+            "VK_STRUCTURE_TYPE_SHADER_MODULE_IDENTIFIER_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV",
+        ),
+        (
+            Self::OPTICAL_FLOW_IMAGE_FORMAT_INFO_NV.0,
+            "OPTICAL_FLOW_IMAGE_FORMAT_INFO_NV",
+            "VK_STRUCTURE_TYPE_OPTICAL_FLOW_IMAGE_FORMAT_INFO_NV",
+        ),
+        (
+            Self::OPTICAL_FLOW_IMAGE_FORMAT_PROPERTIES_NV.0,
+            "OPTICAL_FLOW_IMAGE_FORMAT_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_OPTICAL_FLOW_IMAGE_FORMAT_PROPERTIES_NV",
+        ),
+        (
+            Self::OPTICAL_FLOW_SESSION_CREATE_INFO_NV.0,
+            "OPTICAL_FLOW_SESSION_CREATE_INFO_NV",
+            "VK_STRUCTURE_TYPE_OPTICAL_FLOW_SESSION_CREATE_INFO_NV",
+        ),
+        (
+            Self::OPTICAL_FLOW_EXECUTE_INFO_NV.0,
+            "OPTICAL_FLOW_EXECUTE_INFO_NV",
+            "VK_STRUCTURE_TYPE_OPTICAL_FLOW_EXECUTE_INFO_NV",
+        ),
+        (
+            Self::OPTICAL_FLOW_SESSION_CREATE_PRIVATE_DATA_INFO_NV.0,
+            "OPTICAL_FLOW_SESSION_CREATE_PRIVATE_DATA_INFO_NV",
+            "VK_STRUCTURE_TYPE_OPTICAL_FLOW_SESSION_CREATE_PRIVATE_DATA_INFO_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PIPELINE_PROTECTED_ACCESS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_PIPELINE_PROTECTED_ACCESS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PIPELINE_PROTECTED_ACCESS_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RAY_TRACING_POSITION_FETCH_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_RAY_TRACING_POSITION_FETCH_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_POSITION_FETCH_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_OBJECT_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SHADER_OBJECT_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_OBJECT_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_OBJECT_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_SHADER_OBJECT_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_OBJECT_PROPERTIES_EXT",
+        ),
+        (
+            Self::SHADER_CREATE_INFO_EXT.0,
+            "SHADER_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SHADER_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM.0,
+            "PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM",
+        ),
+        (
+            Self::TILE_PROPERTIES_QCOM.0,
+            "TILE_PROPERTIES_QCOM",
+            "VK_STRUCTURE_TYPE_TILE_PROPERTIES_QCOM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC.0,
+            "PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC",
+        ),
+        (
+            Self::AMIGO_PROFILING_SUBMIT_INFO_SEC.0,
+            "AMIGO_PROFILING_SUBMIT_INFO_SEC",
+            "VK_STRUCTURE_TYPE_AMIGO_PROFILING_SUBMIT_INFO_SEC",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_VIEWPORTS_FEATURES_QCOM.0,
+            "PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_VIEWPORTS_FEATURES_QCOM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_VIEWPORTS_FEATURES_QCOM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_PROPERTIES_NV.0,
+            "PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_PROPERTIES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RAY_TRACING_INVOCATION_REORDER_PROPERTIES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT",
+        ),
+        (
+            Self::MUTABLE_DESCRIPTOR_TYPE_CREATE_INFO_EXT.0,
+            "MUTABLE_DESCRIPTOR_TYPE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_MUTABLE_DESCRIPTOR_TYPE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_FEATURES_ARM.0,
+            "PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_FEATURES_ARM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_FEATURES_ARM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_PROPERTIES_ARM.0,
+            "PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_PROPERTIES_ARM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_PROPERTIES_ARM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PIPELINE_LIBRARY_GROUP_HANDLES_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_PIPELINE_LIBRARY_GROUP_HANDLES_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PIPELINE_LIBRARY_GROUP_HANDLES_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DYNAMIC_RENDERING_UNUSED_ATTACHMENTS_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_RENDER_AREAS_FEATURES_QCOM.0,
+            "PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_RENDER_AREAS_FEATURES_QCOM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_RENDER_AREAS_FEATURES_QCOM",
+        ),
+        (
+            Self::MULTIVIEW_PER_VIEW_RENDER_AREAS_RENDER_PASS_BEGIN_INFO_QCOM.0,
+            "MULTIVIEW_PER_VIEW_RENDER_AREAS_RENDER_PASS_BEGIN_INFO_QCOM",
+            "VK_STRUCTURE_TYPE_MULTIVIEW_PER_VIEW_RENDER_AREAS_RENDER_PASS_BEGIN_INFO_QCOM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_DYNAMIC_STATE_FEATURES_EXT",
+        ),
+        (
+            Self::SCREEN_BUFFER_PROPERTIES_QNX.0,
+            "SCREEN_BUFFER_PROPERTIES_QNX",
+            "VK_STRUCTURE_TYPE_SCREEN_BUFFER_PROPERTIES_QNX",
+        ),
+        (
+            Self::SCREEN_BUFFER_FORMAT_PROPERTIES_QNX.0,
+            "SCREEN_BUFFER_FORMAT_PROPERTIES_QNX",
+            "VK_STRUCTURE_TYPE_SCREEN_BUFFER_FORMAT_PROPERTIES_QNX",
+        ),
+        (
+            Self::IMPORT_SCREEN_BUFFER_INFO_QNX.0,
+            "IMPORT_SCREEN_BUFFER_INFO_QNX",
+            "VK_STRUCTURE_TYPE_IMPORT_SCREEN_BUFFER_INFO_QNX",
+        ),
+        (
+            Self::EXTERNAL_FORMAT_QNX.0,
+            "EXTERNAL_FORMAT_QNX",
+            "VK_STRUCTURE_TYPE_EXTERNAL_FORMAT_QNX",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_MEMORY_SCREEN_BUFFER_FEATURES_QNX.0,
+            "PHYSICAL_DEVICE_EXTERNAL_MEMORY_SCREEN_BUFFER_FEATURES_QNX",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_MEMORY_SCREEN_BUFFER_FEATURES_QNX",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SUBGROUP_PROPERTIES.0,
+            "PHYSICAL_DEVICE_SUBGROUP_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_PROPERTIES",
+        ),
+        (
+            Self::BIND_BUFFER_MEMORY_INFO.0,
+            "BIND_BUFFER_MEMORY_INFO",
+            "VK_STRUCTURE_TYPE_BIND_BUFFER_MEMORY_INFO",
+        ),
+        (
+            Self::BIND_IMAGE_MEMORY_INFO.0,
+            "BIND_IMAGE_MEMORY_INFO",
+            "VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES.0,
+            "PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES",
+        ),
+        (
+            Self::MEMORY_DEDICATED_REQUIREMENTS.0,
+            "MEMORY_DEDICATED_REQUIREMENTS",
+            "VK_STRUCTURE_TYPE_MEMORY_DEDICATED_REQUIREMENTS",
+        ),
+        (
+            Self::MEMORY_DEDICATED_ALLOCATE_INFO.0,
+            "MEMORY_DEDICATED_ALLOCATE_INFO",
+            "VK_STRUCTURE_TYPE_MEMORY_DEDICATED_ALLOCATE_INFO",
+        ),
+        (
+            Self::MEMORY_ALLOCATE_FLAGS_INFO.0,
+            "MEMORY_ALLOCATE_FLAGS_INFO",
+            "VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_FLAGS_INFO",
+        ),
+        (
+            Self::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO.0,
+            "DEVICE_GROUP_RENDER_PASS_BEGIN_INFO",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_RENDER_PASS_BEGIN_INFO",
+        ),
+        (
+            Self::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO.0,
+            "DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO",
+        ),
+        (
+            Self::DEVICE_GROUP_SUBMIT_INFO.0,
+            "DEVICE_GROUP_SUBMIT_INFO",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_SUBMIT_INFO",
+        ),
+        (
+            Self::DEVICE_GROUP_BIND_SPARSE_INFO.0,
+            "DEVICE_GROUP_BIND_SPARSE_INFO",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_BIND_SPARSE_INFO",
+        ),
+        (
+            Self::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO.0,
+            "BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO",
+            "VK_STRUCTURE_TYPE_BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO",
+        ),
+        (
+            Self::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO.0,
+            "BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO",
+            "VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_GROUP_PROPERTIES.0,
+            "PHYSICAL_DEVICE_GROUP_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_GROUP_PROPERTIES",
+        ),
+        (
+            Self::DEVICE_GROUP_DEVICE_CREATE_INFO.0,
+            "DEVICE_GROUP_DEVICE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_DEVICE_CREATE_INFO",
+        ),
+        (
+            Self::BUFFER_MEMORY_REQUIREMENTS_INFO_2.0,
+            "BUFFER_MEMORY_REQUIREMENTS_INFO_2",
+            "VK_STRUCTURE_TYPE_BUFFER_MEMORY_REQUIREMENTS_INFO_2",
+        ),
+        (
+            Self::IMAGE_MEMORY_REQUIREMENTS_INFO_2.0,
+            "IMAGE_MEMORY_REQUIREMENTS_INFO_2",
+            "VK_STRUCTURE_TYPE_IMAGE_MEMORY_REQUIREMENTS_INFO_2",
+        ),
+        (
+            Self::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2.0,
+            "IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2",
+            "VK_STRUCTURE_TYPE_IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2",
+        ),
+        (
+            Self::MEMORY_REQUIREMENTS_2.0,
+            "MEMORY_REQUIREMENTS_2",
+            "VK_STRUCTURE_TYPE_MEMORY_REQUIREMENTS_2",
+        ),
+        (
+            Self::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2.0,
+            "SPARSE_IMAGE_MEMORY_REQUIREMENTS_2",
+            "VK_STRUCTURE_TYPE_SPARSE_IMAGE_MEMORY_REQUIREMENTS_2",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FEATURES_2.0,
+            "PHYSICAL_DEVICE_FEATURES_2",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PROPERTIES_2.0,
+            "PHYSICAL_DEVICE_PROPERTIES_2",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROPERTIES_2",
+        ),
+        (
+            Self::FORMAT_PROPERTIES_2.0,
+            "FORMAT_PROPERTIES_2",
+            "VK_STRUCTURE_TYPE_FORMAT_PROPERTIES_2",
+        ),
+        (
+            Self::IMAGE_FORMAT_PROPERTIES_2.0,
+            "IMAGE_FORMAT_PROPERTIES_2",
+            "VK_STRUCTURE_TYPE_IMAGE_FORMAT_PROPERTIES_2",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2.0,
+            "PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2",
+        ),
+        (
+            Self::QUEUE_FAMILY_PROPERTIES_2.0,
+            "QUEUE_FAMILY_PROPERTIES_2",
+            "VK_STRUCTURE_TYPE_QUEUE_FAMILY_PROPERTIES_2",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2.0,
+            "PHYSICAL_DEVICE_MEMORY_PROPERTIES_2",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MEMORY_PROPERTIES_2",
+        ),
+        (
+            Self::SPARSE_IMAGE_FORMAT_PROPERTIES_2.0,
+            "SPARSE_IMAGE_FORMAT_PROPERTIES_2",
+            "VK_STRUCTURE_TYPE_SPARSE_IMAGE_FORMAT_PROPERTIES_2",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2.0,
+            "PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES.0,
+            "PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES",
+        ),
+        (
+            Self::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO.0,
+            "RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO",
+        ),
+        (
+            Self::IMAGE_VIEW_USAGE_CREATE_INFO.0,
+            "IMAGE_VIEW_USAGE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_IMAGE_VIEW_USAGE_CREATE_INFO",
+        ),
+        (
+            Self::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO.0,
+            "PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO",
+        ),
+        (
+            Self::RENDER_PASS_MULTIVIEW_CREATE_INFO.0,
+            "RENDER_PASS_MULTIVIEW_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_MULTIVIEW_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MULTIVIEW_FEATURES.0,
+            "PHYSICAL_DEVICE_MULTIVIEW_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES.0,
+            "PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES.0,
+            "PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES",
+        ),
+        (
+            Self::PROTECTED_SUBMIT_INFO.0,
+            "PROTECTED_SUBMIT_INFO",
+            "VK_STRUCTURE_TYPE_PROTECTED_SUBMIT_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES.0,
+            "PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES.0,
+            "PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES",
+        ),
+        (
+            Self::DEVICE_QUEUE_INFO_2.0,
+            "DEVICE_QUEUE_INFO_2",
+            "VK_STRUCTURE_TYPE_DEVICE_QUEUE_INFO_2",
+        ),
+        (
+            Self::SAMPLER_YCBCR_CONVERSION_CREATE_INFO.0,
+            "SAMPLER_YCBCR_CONVERSION_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_CREATE_INFO",
+        ),
+        (
+            Self::SAMPLER_YCBCR_CONVERSION_INFO.0,
+            "SAMPLER_YCBCR_CONVERSION_INFO",
+            "VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_INFO",
+        ),
+        (
+            Self::BIND_IMAGE_PLANE_MEMORY_INFO.0,
+            "BIND_IMAGE_PLANE_MEMORY_INFO",
+            "VK_STRUCTURE_TYPE_BIND_IMAGE_PLANE_MEMORY_INFO",
+        ),
+        (
+            Self::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO.0,
+            "IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO",
+            "VK_STRUCTURE_TYPE_IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES.0,
+            "PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES",
+        ),
+        (
+            Self::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES.0,
+            "SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES",
+            "VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES",
+        ),
+        (
+            Self::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO.0,
+            "DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO.0,
+            "PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO",
+        ),
+        (
+            Self::EXTERNAL_IMAGE_FORMAT_PROPERTIES.0,
+            "EXTERNAL_IMAGE_FORMAT_PROPERTIES",
+            "VK_STRUCTURE_TYPE_EXTERNAL_IMAGE_FORMAT_PROPERTIES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO.0,
+            "PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO",
+        ),
+        (
+            Self::EXTERNAL_BUFFER_PROPERTIES.0,
+            "EXTERNAL_BUFFER_PROPERTIES",
+            "VK_STRUCTURE_TYPE_EXTERNAL_BUFFER_PROPERTIES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ID_PROPERTIES.0,
+            "PHYSICAL_DEVICE_ID_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ID_PROPERTIES",
+        ),
+        (
+            Self::EXTERNAL_MEMORY_BUFFER_CREATE_INFO.0,
+            "EXTERNAL_MEMORY_BUFFER_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_BUFFER_CREATE_INFO",
+        ),
+        (
+            Self::EXTERNAL_MEMORY_IMAGE_CREATE_INFO.0,
+            "EXTERNAL_MEMORY_IMAGE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_IMAGE_CREATE_INFO",
+        ),
+        (
+            Self::EXPORT_MEMORY_ALLOCATE_INFO.0,
+            "EXPORT_MEMORY_ALLOCATE_INFO",
+            "VK_STRUCTURE_TYPE_EXPORT_MEMORY_ALLOCATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO.0,
+            "PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO",
+        ),
+        (
+            Self::EXTERNAL_FENCE_PROPERTIES.0,
+            "EXTERNAL_FENCE_PROPERTIES",
+            "VK_STRUCTURE_TYPE_EXTERNAL_FENCE_PROPERTIES",
+        ),
+        (
+            Self::EXPORT_FENCE_CREATE_INFO.0,
+            "EXPORT_FENCE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_EXPORT_FENCE_CREATE_INFO",
+        ),
+        (
+            Self::EXPORT_SEMAPHORE_CREATE_INFO.0,
+            "EXPORT_SEMAPHORE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_EXPORT_SEMAPHORE_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO.0,
+            "PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO",
+        ),
+        (
+            Self::EXTERNAL_SEMAPHORE_PROPERTIES.0,
+            "EXTERNAL_SEMAPHORE_PROPERTIES",
+            "VK_STRUCTURE_TYPE_EXTERNAL_SEMAPHORE_PROPERTIES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES.0,
+            "PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES",
+        ),
+        (
+            Self::DESCRIPTOR_SET_LAYOUT_SUPPORT.0,
+            "DESCRIPTOR_SET_LAYOUT_SUPPORT",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_SUPPORT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES.0,
+            "PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES.0,
+            "PHYSICAL_DEVICE_VULKAN_1_1_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES.0,
+            "PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES.0,
+            "PHYSICAL_DEVICE_VULKAN_1_2_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_2_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES.0,
+            "PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES",
+        ),
+        (
+            Self::IMAGE_FORMAT_LIST_CREATE_INFO.0,
+            "IMAGE_FORMAT_LIST_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_IMAGE_FORMAT_LIST_CREATE_INFO",
+        ),
+        (
+            Self::ATTACHMENT_DESCRIPTION_2.0,
+            "ATTACHMENT_DESCRIPTION_2",
+            "VK_STRUCTURE_TYPE_ATTACHMENT_DESCRIPTION_2",
+        ),
+        (
+            Self::ATTACHMENT_REFERENCE_2.0,
+            "ATTACHMENT_REFERENCE_2",
+            "VK_STRUCTURE_TYPE_ATTACHMENT_REFERENCE_2",
+        ),
+        (
+            Self::SUBPASS_DESCRIPTION_2.0,
+            "SUBPASS_DESCRIPTION_2",
+            "VK_STRUCTURE_TYPE_SUBPASS_DESCRIPTION_2",
+        ),
+        (
+            Self::SUBPASS_DEPENDENCY_2.0,
+            "SUBPASS_DEPENDENCY_2",
+            "VK_STRUCTURE_TYPE_SUBPASS_DEPENDENCY_2",
+        ),
+        (
+            Self::RENDER_PASS_CREATE_INFO_2.0,
+            "RENDER_PASS_CREATE_INFO_2",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_CREATE_INFO_2",
+        ),
+        (
+            Self::SUBPASS_BEGIN_INFO.0,
+            "SUBPASS_BEGIN_INFO",
+            "VK_STRUCTURE_TYPE_SUBPASS_BEGIN_INFO",
+        ),
+        (
+            Self::SUBPASS_END_INFO.0,
+            "SUBPASS_END_INFO",
+            "VK_STRUCTURE_TYPE_SUBPASS_END_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES.0,
+            "PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DRIVER_PROPERTIES.0,
+            "PHYSICAL_DEVICE_DRIVER_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DRIVER_PROPERTIES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES.0,
+            "PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES.0,
+            "PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES.0,
+            "PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES",
+        ),
+        (
+            Self::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO.0,
+            "DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES.0,
+            "PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES.0,
+            "PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES",
+        ),
+        (
+            Self::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO.0,
+            "DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO",
+        ),
+        (
+            Self::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT.0,
+            "DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES.0,
+            "PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES",
+        ),
+        (
+            Self::SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE.0,
+            "SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE",
+            "VK_STRUCTURE_TYPE_SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES.0,
+            "PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES",
+        ),
+        (
+            Self::IMAGE_STENCIL_USAGE_CREATE_INFO.0,
+            "IMAGE_STENCIL_USAGE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_IMAGE_STENCIL_USAGE_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES.0,
+            "PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES",
+        ),
+        (
+            Self::SAMPLER_REDUCTION_MODE_CREATE_INFO.0,
+            "SAMPLER_REDUCTION_MODE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_SAMPLER_REDUCTION_MODE_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES.0,
+            "PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES.0,
+            "PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES",
+        ),
+        (
+            Self::FRAMEBUFFER_ATTACHMENTS_CREATE_INFO.0,
+            "FRAMEBUFFER_ATTACHMENTS_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_FRAMEBUFFER_ATTACHMENTS_CREATE_INFO",
+        ),
+        (
+            Self::FRAMEBUFFER_ATTACHMENT_IMAGE_INFO.0,
+            "FRAMEBUFFER_ATTACHMENT_IMAGE_INFO",
+            "VK_STRUCTURE_TYPE_FRAMEBUFFER_ATTACHMENT_IMAGE_INFO",
+        ),
+        (
+            Self::RENDER_PASS_ATTACHMENT_BEGIN_INFO.0,
+            "RENDER_PASS_ATTACHMENT_BEGIN_INFO",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_ATTACHMENT_BEGIN_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES.0,
+            "PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES.0,
+            "PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES.0,
+            "PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES",
+        ),
+        (
+            Self::ATTACHMENT_REFERENCE_STENCIL_LAYOUT.0,
+            "ATTACHMENT_REFERENCE_STENCIL_LAYOUT",
+            "VK_STRUCTURE_TYPE_ATTACHMENT_REFERENCE_STENCIL_LAYOUT",
+        ),
+        (
+            Self::ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT.0,
+            "ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT",
+            "VK_STRUCTURE_TYPE_ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES.0,
+            "PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES.0,
+            "PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES.0,
+            "PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES",
+        ),
+        (
+            Self::SEMAPHORE_TYPE_CREATE_INFO.0,
+            "SEMAPHORE_TYPE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_TYPE_CREATE_INFO",
+        ),
+        (
+            Self::TIMELINE_SEMAPHORE_SUBMIT_INFO.0,
+            "TIMELINE_SEMAPHORE_SUBMIT_INFO",
+            "VK_STRUCTURE_TYPE_TIMELINE_SEMAPHORE_SUBMIT_INFO",
+        ),
+        (
+            Self::SEMAPHORE_WAIT_INFO.0,
+            "SEMAPHORE_WAIT_INFO",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_WAIT_INFO",
+        ),
+        (
+            Self::SEMAPHORE_SIGNAL_INFO.0,
+            "SEMAPHORE_SIGNAL_INFO",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_SIGNAL_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES.0,
+            "PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES",
+        ),
+        (
+            Self::BUFFER_DEVICE_ADDRESS_INFO.0,
+            "BUFFER_DEVICE_ADDRESS_INFO",
+            "VK_STRUCTURE_TYPE_BUFFER_DEVICE_ADDRESS_INFO",
+        ),
+        (
+            Self::BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO.0,
+            "BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO",
+        ),
+        (
+            Self::MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO.0,
+            "MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO",
+            "VK_STRUCTURE_TYPE_MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO",
+        ),
+        (
+            Self::DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO.0,
+            "DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO",
+            "VK_STRUCTURE_TYPE_DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES.0,
+            "PHYSICAL_DEVICE_VULKAN_1_3_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_3_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES.0,
+            "PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES",
+        ),
+        (
+            Self::PIPELINE_CREATION_FEEDBACK_CREATE_INFO.0,
+            "PIPELINE_CREATION_FEEDBACK_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_CREATION_FEEDBACK_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES.0,
+            "PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TOOL_PROPERTIES.0,
+            "PHYSICAL_DEVICE_TOOL_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TOOL_PROPERTIES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES.0,
+            "PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES.0,
+            "PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES",
+        ),
+        (
+            Self::DEVICE_PRIVATE_DATA_CREATE_INFO.0,
+            "DEVICE_PRIVATE_DATA_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_DEVICE_PRIVATE_DATA_CREATE_INFO",
+        ),
+        (
+            Self::PRIVATE_DATA_SLOT_CREATE_INFO.0,
+            "PRIVATE_DATA_SLOT_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PRIVATE_DATA_SLOT_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES.0,
+            "PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES",
+        ),
+        (
+            Self::MEMORY_BARRIER_2.0,
+            "MEMORY_BARRIER_2",
+            "VK_STRUCTURE_TYPE_MEMORY_BARRIER_2",
+        ),
+        (
+            Self::BUFFER_MEMORY_BARRIER_2.0,
+            "BUFFER_MEMORY_BARRIER_2",
+            "VK_STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER_2",
+        ),
+        (
+            Self::IMAGE_MEMORY_BARRIER_2.0,
+            "IMAGE_MEMORY_BARRIER_2",
+            "VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER_2",
+        ),
+        (
+            Self::DEPENDENCY_INFO.0,
+            "DEPENDENCY_INFO",
+            "VK_STRUCTURE_TYPE_DEPENDENCY_INFO",
+        ),
+        (
+            Self::SUBMIT_INFO_2.0,
+            "SUBMIT_INFO_2",
+            "VK_STRUCTURE_TYPE_SUBMIT_INFO_2",
+        ),
+        (
+            Self::SEMAPHORE_SUBMIT_INFO.0,
+            "SEMAPHORE_SUBMIT_INFO",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_SUBMIT_INFO",
+        ),
+        (
+            Self::COMMAND_BUFFER_SUBMIT_INFO.0,
+            "COMMAND_BUFFER_SUBMIT_INFO",
+            "VK_STRUCTURE_TYPE_COMMAND_BUFFER_SUBMIT_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES.0,
+            "PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES.0,
+            "PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES.0,
+            "PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES",
+        ),
+        (
+            Self::COPY_BUFFER_INFO_2.0,
+            "COPY_BUFFER_INFO_2",
+            "VK_STRUCTURE_TYPE_COPY_BUFFER_INFO_2",
+        ),
+        (
+            Self::COPY_IMAGE_INFO_2.0,
+            "COPY_IMAGE_INFO_2",
+            "VK_STRUCTURE_TYPE_COPY_IMAGE_INFO_2",
+        ),
+        (
+            Self::COPY_BUFFER_TO_IMAGE_INFO_2.0,
+            "COPY_BUFFER_TO_IMAGE_INFO_2",
+            "VK_STRUCTURE_TYPE_COPY_BUFFER_TO_IMAGE_INFO_2",
+        ),
+        (
+            Self::COPY_IMAGE_TO_BUFFER_INFO_2.0,
+            "COPY_IMAGE_TO_BUFFER_INFO_2",
+            "VK_STRUCTURE_TYPE_COPY_IMAGE_TO_BUFFER_INFO_2",
+        ),
+        (
+            Self::BLIT_IMAGE_INFO_2.0,
+            "BLIT_IMAGE_INFO_2",
+            "VK_STRUCTURE_TYPE_BLIT_IMAGE_INFO_2",
+        ),
+        (
+            Self::RESOLVE_IMAGE_INFO_2.0,
+            "RESOLVE_IMAGE_INFO_2",
+            "VK_STRUCTURE_TYPE_RESOLVE_IMAGE_INFO_2",
+        ),
+        (
+            Self::BUFFER_COPY_2.0,
+            "BUFFER_COPY_2",
+            "VK_STRUCTURE_TYPE_BUFFER_COPY_2",
+        ),
+        (
+            Self::IMAGE_COPY_2.0,
+            "IMAGE_COPY_2",
+            "VK_STRUCTURE_TYPE_IMAGE_COPY_2",
+        ),
+        (
+            Self::IMAGE_BLIT_2.0,
+            "IMAGE_BLIT_2",
+            "VK_STRUCTURE_TYPE_IMAGE_BLIT_2",
+        ),
+        (
+            Self::BUFFER_IMAGE_COPY_2.0,
+            "BUFFER_IMAGE_COPY_2",
+            "VK_STRUCTURE_TYPE_BUFFER_IMAGE_COPY_2",
+        ),
+        (
+            Self::IMAGE_RESOLVE_2.0,
+            "IMAGE_RESOLVE_2",
+            "VK_STRUCTURE_TYPE_IMAGE_RESOLVE_2",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES.0,
+            "PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES",
+        ),
+        (
+            Self::PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO.0,
+            "PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES.0,
+            "PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES.0,
+            "PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES.0,
+            "PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES",
+        ),
+        (
+            Self::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK.0,
+            "WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK",
+            "VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK",
+        ),
+        (
+            Self::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO.0,
+            "DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES.0,
+            "PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES",
+        ),
+        (
+            Self::RENDERING_INFO.0,
+            "RENDERING_INFO",
+            "VK_STRUCTURE_TYPE_RENDERING_INFO",
+        ),
+        (
+            Self::RENDERING_ATTACHMENT_INFO.0,
+            "RENDERING_ATTACHMENT_INFO",
+            "VK_STRUCTURE_TYPE_RENDERING_ATTACHMENT_INFO",
+        ),
+        (
+            Self::PIPELINE_RENDERING_CREATE_INFO.0,
+            "PIPELINE_RENDERING_CREATE_INFO",
+            "VK_STRUCTURE_TYPE_PIPELINE_RENDERING_CREATE_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES.0,
+            "PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES",
+        ),
+        (
+            Self::COMMAND_BUFFER_INHERITANCE_RENDERING_INFO.0,
+            "COMMAND_BUFFER_INHERITANCE_RENDERING_INFO",
+            "VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_RENDERING_INFO",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES.0,
+            "PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES.0,
+            "PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES.0,
+            "PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES",
+        ),
+        (
+            Self::FORMAT_PROPERTIES_3.0,
+            "FORMAT_PROPERTIES_3",
+            "VK_STRUCTURE_TYPE_FORMAT_PROPERTIES_3",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES.0,
+            "PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES.0,
+            "PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES",
+        ),
+        (
+            Self::DEVICE_BUFFER_MEMORY_REQUIREMENTS.0,
+            "DEVICE_BUFFER_MEMORY_REQUIREMENTS",
+            "VK_STRUCTURE_TYPE_DEVICE_BUFFER_MEMORY_REQUIREMENTS",
+        ),
+        (
+            Self::DEVICE_IMAGE_MEMORY_REQUIREMENTS.0,
+            "DEVICE_IMAGE_MEMORY_REQUIREMENTS",
+            "VK_STRUCTURE_TYPE_DEVICE_IMAGE_MEMORY_REQUIREMENTS",
+        ),
+        (
+            Self::RENDERING_INFO_KHR.0,
+            "RENDERING_INFO_KHR",
+            "VK_STRUCTURE_TYPE_RENDERING_INFO_KHR",
+        ),
+        (
+            Self::RENDERING_ATTACHMENT_INFO_KHR.0,
+            "RENDERING_ATTACHMENT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_RENDERING_ATTACHMENT_INFO_KHR",
+        ),
+        (
+            Self::PIPELINE_RENDERING_CREATE_INFO_KHR.0,
+            "PIPELINE_RENDERING_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PIPELINE_RENDERING_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES_KHR",
+        ),
+        (
+            Self::COMMAND_BUFFER_INHERITANCE_RENDERING_INFO_KHR.0,
+            "COMMAND_BUFFER_INHERITANCE_RENDERING_INFO_KHR",
+            "VK_STRUCTURE_TYPE_COMMAND_BUFFER_INHERITANCE_RENDERING_INFO_KHR",
+        ),
+        (
+            Self::ATTACHMENT_SAMPLE_COUNT_INFO_NV.0,
+            "ATTACHMENT_SAMPLE_COUNT_INFO_NV",
+            "VK_STRUCTURE_TYPE_ATTACHMENT_SAMPLE_COUNT_INFO_NV",
+        ),
+        (
+            Self::RENDER_PASS_MULTIVIEW_CREATE_INFO_KHR.0,
+            "RENDER_PASS_MULTIVIEW_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_MULTIVIEW_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MULTIVIEW_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_MULTIVIEW_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FEATURES_2_KHR.0,
+            "PHYSICAL_DEVICE_FEATURES_2_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PROPERTIES_2_KHR.0,
+            "PHYSICAL_DEVICE_PROPERTIES_2_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROPERTIES_2_KHR",
+        ),
+        (
+            Self::FORMAT_PROPERTIES_2_KHR.0,
+            "FORMAT_PROPERTIES_2_KHR",
+            "VK_STRUCTURE_TYPE_FORMAT_PROPERTIES_2_KHR",
+        ),
+        (
+            Self::IMAGE_FORMAT_PROPERTIES_2_KHR.0,
+            "IMAGE_FORMAT_PROPERTIES_2_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_FORMAT_PROPERTIES_2_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2_KHR.0,
+            "PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2_KHR",
+        ),
+        (
+            Self::QUEUE_FAMILY_PROPERTIES_2_KHR.0,
+            "QUEUE_FAMILY_PROPERTIES_2_KHR",
+            "VK_STRUCTURE_TYPE_QUEUE_FAMILY_PROPERTIES_2_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MEMORY_PROPERTIES_2_KHR.0,
+            "PHYSICAL_DEVICE_MEMORY_PROPERTIES_2_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MEMORY_PROPERTIES_2_KHR",
+        ),
+        (
+            Self::SPARSE_IMAGE_FORMAT_PROPERTIES_2_KHR.0,
+            "SPARSE_IMAGE_FORMAT_PROPERTIES_2_KHR",
+            "VK_STRUCTURE_TYPE_SPARSE_IMAGE_FORMAT_PROPERTIES_2_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2_KHR.0,
+            "PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2_KHR",
+        ),
+        (
+            Self::MEMORY_ALLOCATE_FLAGS_INFO_KHR.0,
+            "MEMORY_ALLOCATE_FLAGS_INFO_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_FLAGS_INFO_KHR",
+        ),
+        (
+            Self::DEVICE_GROUP_RENDER_PASS_BEGIN_INFO_KHR.0,
+            "DEVICE_GROUP_RENDER_PASS_BEGIN_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_RENDER_PASS_BEGIN_INFO_KHR",
+        ),
+        (
+            Self::DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO_KHR.0,
+            "DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO_KHR",
+        ),
+        (
+            Self::DEVICE_GROUP_SUBMIT_INFO_KHR.0,
+            "DEVICE_GROUP_SUBMIT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_SUBMIT_INFO_KHR",
+        ),
+        (
+            Self::DEVICE_GROUP_BIND_SPARSE_INFO_KHR.0,
+            "DEVICE_GROUP_BIND_SPARSE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_BIND_SPARSE_INFO_KHR",
+        ),
+        (
+            Self::BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO_KHR.0,
+            "BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO_KHR",
+            "VK_STRUCTURE_TYPE_BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO_KHR",
+        ),
+        (
+            Self::BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO_KHR.0,
+            "BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO_KHR",
+            "VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_GROUP_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_GROUP_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_GROUP_PROPERTIES_KHR",
+        ),
+        (
+            Self::DEVICE_GROUP_DEVICE_CREATE_INFO_KHR.0,
+            "DEVICE_GROUP_DEVICE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_GROUP_DEVICE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO_KHR.0,
+            "PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO_KHR",
+        ),
+        (
+            Self::EXTERNAL_IMAGE_FORMAT_PROPERTIES_KHR.0,
+            "EXTERNAL_IMAGE_FORMAT_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_EXTERNAL_IMAGE_FORMAT_PROPERTIES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO_KHR.0,
+            "PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO_KHR",
+        ),
+        (
+            Self::EXTERNAL_BUFFER_PROPERTIES_KHR.0,
+            "EXTERNAL_BUFFER_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_EXTERNAL_BUFFER_PROPERTIES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ID_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_ID_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ID_PROPERTIES_KHR",
+        ),
+        (
+            Self::EXTERNAL_MEMORY_BUFFER_CREATE_INFO_KHR.0,
+            "EXTERNAL_MEMORY_BUFFER_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_BUFFER_CREATE_INFO_KHR",
+        ),
+        (
+            Self::EXTERNAL_MEMORY_IMAGE_CREATE_INFO_KHR.0,
+            "EXTERNAL_MEMORY_IMAGE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_EXTERNAL_MEMORY_IMAGE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::EXPORT_MEMORY_ALLOCATE_INFO_KHR.0,
+            "EXPORT_MEMORY_ALLOCATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_EXPORT_MEMORY_ALLOCATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO_KHR.0,
+            "PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO_KHR",
+        ),
+        (
+            Self::EXTERNAL_SEMAPHORE_PROPERTIES_KHR.0,
+            "EXTERNAL_SEMAPHORE_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_EXTERNAL_SEMAPHORE_PROPERTIES_KHR",
+        ),
+        (
+            Self::EXPORT_SEMAPHORE_CREATE_INFO_KHR.0,
+            "EXPORT_SEMAPHORE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_EXPORT_SEMAPHORE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FLOAT16_INT8_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_FLOAT16_INT8_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FLOAT16_INT8_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES_KHR",
+        ),
+        (
+            Self::DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO_KHR.0,
+            "DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES_KHR",
+        ),
+        (
+            Self::FRAMEBUFFER_ATTACHMENTS_CREATE_INFO_KHR.0,
+            "FRAMEBUFFER_ATTACHMENTS_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_FRAMEBUFFER_ATTACHMENTS_CREATE_INFO_KHR",
+        ),
+        (
+            Self::FRAMEBUFFER_ATTACHMENT_IMAGE_INFO_KHR.0,
+            "FRAMEBUFFER_ATTACHMENT_IMAGE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_FRAMEBUFFER_ATTACHMENT_IMAGE_INFO_KHR",
+        ),
+        (
+            Self::RENDER_PASS_ATTACHMENT_BEGIN_INFO_KHR.0,
+            "RENDER_PASS_ATTACHMENT_BEGIN_INFO_KHR",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_ATTACHMENT_BEGIN_INFO_KHR",
+        ),
+        (
+            Self::ATTACHMENT_DESCRIPTION_2_KHR.0,
+            "ATTACHMENT_DESCRIPTION_2_KHR",
+            "VK_STRUCTURE_TYPE_ATTACHMENT_DESCRIPTION_2_KHR",
+        ),
+        (
+            Self::ATTACHMENT_REFERENCE_2_KHR.0,
+            "ATTACHMENT_REFERENCE_2_KHR",
+            "VK_STRUCTURE_TYPE_ATTACHMENT_REFERENCE_2_KHR",
+        ),
+        (
+            Self::SUBPASS_DESCRIPTION_2_KHR.0,
+            "SUBPASS_DESCRIPTION_2_KHR",
+            "VK_STRUCTURE_TYPE_SUBPASS_DESCRIPTION_2_KHR",
+        ),
+        (
+            Self::SUBPASS_DEPENDENCY_2_KHR.0,
+            "SUBPASS_DEPENDENCY_2_KHR",
+            "VK_STRUCTURE_TYPE_SUBPASS_DEPENDENCY_2_KHR",
+        ),
+        (
+            Self::RENDER_PASS_CREATE_INFO_2_KHR.0,
+            "RENDER_PASS_CREATE_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_CREATE_INFO_2_KHR",
+        ),
+        (
+            Self::SUBPASS_BEGIN_INFO_KHR.0,
+            "SUBPASS_BEGIN_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SUBPASS_BEGIN_INFO_KHR",
+        ),
+        (
+            Self::SUBPASS_END_INFO_KHR.0,
+            "SUBPASS_END_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SUBPASS_END_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO_KHR.0,
+            "PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO_KHR",
+        ),
+        (
+            Self::EXTERNAL_FENCE_PROPERTIES_KHR.0,
+            "EXTERNAL_FENCE_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_EXTERNAL_FENCE_PROPERTIES_KHR",
+        ),
+        (
+            Self::EXPORT_FENCE_CREATE_INFO_KHR.0,
+            "EXPORT_FENCE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_EXPORT_FENCE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES_KHR",
+        ),
+        (
+            Self::RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO_KHR.0,
+            "RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO_KHR",
+        ),
+        (
+            Self::IMAGE_VIEW_USAGE_CREATE_INFO_KHR.0,
+            "IMAGE_VIEW_USAGE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_VIEW_USAGE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO_KHR.0,
+            "PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES_KHR",
+        ),
+        (
+            Self::MEMORY_DEDICATED_REQUIREMENTS_KHR.0,
+            "MEMORY_DEDICATED_REQUIREMENTS_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_DEDICATED_REQUIREMENTS_KHR",
+        ),
+        (
+            Self::MEMORY_DEDICATED_ALLOCATE_INFO_KHR.0,
+            "MEMORY_DEDICATED_ALLOCATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_DEDICATED_ALLOCATE_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES_EXT",
+        ),
+        (
+            Self::SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT.0,
+            "SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SAMPLER_REDUCTION_MODE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES_EXT",
+        ),
+        (
+            Self::WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT.0,
+            "WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT",
+            "VK_STRUCTURE_TYPE_WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT.0,
+            "DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO_EXT",
+        ),
+        (
+            Self::BUFFER_MEMORY_REQUIREMENTS_INFO_2_KHR.0,
+            "BUFFER_MEMORY_REQUIREMENTS_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_BUFFER_MEMORY_REQUIREMENTS_INFO_2_KHR",
+        ),
+        (
+            Self::IMAGE_MEMORY_REQUIREMENTS_INFO_2_KHR.0,
+            "IMAGE_MEMORY_REQUIREMENTS_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_MEMORY_REQUIREMENTS_INFO_2_KHR",
+        ),
+        (
+            Self::IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2_KHR.0,
+            "IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2_KHR",
+        ),
+        (
+            Self::MEMORY_REQUIREMENTS_2_KHR.0,
+            "MEMORY_REQUIREMENTS_2_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_REQUIREMENTS_2_KHR",
+        ),
+        (
+            Self::SPARSE_IMAGE_MEMORY_REQUIREMENTS_2_KHR.0,
+            "SPARSE_IMAGE_MEMORY_REQUIREMENTS_2_KHR",
+            "VK_STRUCTURE_TYPE_SPARSE_IMAGE_MEMORY_REQUIREMENTS_2_KHR",
+        ),
+        (
+            Self::IMAGE_FORMAT_LIST_CREATE_INFO_KHR.0,
+            "IMAGE_FORMAT_LIST_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_FORMAT_LIST_CREATE_INFO_KHR",
+        ),
+        (
+            Self::SAMPLER_YCBCR_CONVERSION_CREATE_INFO_KHR.0,
+            "SAMPLER_YCBCR_CONVERSION_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_CREATE_INFO_KHR",
+        ),
+        (
+            Self::SAMPLER_YCBCR_CONVERSION_INFO_KHR.0,
+            "SAMPLER_YCBCR_CONVERSION_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_INFO_KHR",
+        ),
+        (
+            Self::BIND_IMAGE_PLANE_MEMORY_INFO_KHR.0,
+            "BIND_IMAGE_PLANE_MEMORY_INFO_KHR",
+            "VK_STRUCTURE_TYPE_BIND_IMAGE_PLANE_MEMORY_INFO_KHR",
+        ),
+        (
+            Self::IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO_KHR.0,
+            "IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES_KHR",
+        ),
+        (
+            Self::SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES_KHR.0,
+            "SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES_KHR",
+        ),
+        (
+            Self::BIND_BUFFER_MEMORY_INFO_KHR.0,
+            "BIND_BUFFER_MEMORY_INFO_KHR",
+            "VK_STRUCTURE_TYPE_BIND_BUFFER_MEMORY_INFO_KHR",
+        ),
+        (
+            Self::BIND_IMAGE_MEMORY_INFO_KHR.0,
+            "BIND_IMAGE_MEMORY_INFO_KHR",
+            "VK_STRUCTURE_TYPE_BIND_IMAGE_MEMORY_INFO_KHR",
+        ),
+        (
+            Self::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT.0,
+            "DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT.0,
+            "DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT",
+        ),
+        (
+            Self::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT_EXT.0,
+            "DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT_EXT",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES_KHR",
+        ),
+        (
+            Self::DESCRIPTOR_SET_LAYOUT_SUPPORT_KHR.0,
+            "DESCRIPTOR_SET_LAYOUT_SUPPORT_KHR",
+            "VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_SUPPORT_KHR",
+        ),
+        (
+            Self::DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT.0,
+            "DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES_KHR",
+        ),
+        (
+            Self::PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT.0,
+            "PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_CREATION_FEEDBACK_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DRIVER_PROPERTIES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES_KHR",
+        ),
+        (
+            Self::SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE_KHR.0,
+            "SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE_KHR",
+            "VK_STRUCTURE_TYPE_SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV.0,
+            "PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_NV",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES_KHR",
+        ),
+        (
+            Self::SEMAPHORE_TYPE_CREATE_INFO_KHR.0,
+            "SEMAPHORE_TYPE_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_TYPE_CREATE_INFO_KHR",
+        ),
+        (
+            Self::TIMELINE_SEMAPHORE_SUBMIT_INFO_KHR.0,
+            "TIMELINE_SEMAPHORE_SUBMIT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_TIMELINE_SEMAPHORE_SUBMIT_INFO_KHR",
+        ),
+        (
+            Self::SEMAPHORE_WAIT_INFO_KHR.0,
+            "SEMAPHORE_WAIT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_WAIT_INFO_KHR",
+        ),
+        (
+            Self::SEMAPHORE_SIGNAL_INFO_KHR.0,
+            "SEMAPHORE_SIGNAL_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_SIGNAL_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES_EXT",
+        ),
+        (
+            Self::PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT.0,
+            "PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES_KHR",
+        ),
+        (
+            Self::ATTACHMENT_REFERENCE_STENCIL_LAYOUT_KHR.0,
+            "ATTACHMENT_REFERENCE_STENCIL_LAYOUT_KHR",
+            "VK_STRUCTURE_TYPE_ATTACHMENT_REFERENCE_STENCIL_LAYOUT_KHR",
+        ),
+        (
+            Self::ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT_KHR.0,
+            "ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT_KHR",
+            "VK_STRUCTURE_TYPE_ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_BUFFER_ADDRESS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_BUFFER_ADDRESS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BUFFER_ADDRESS_FEATURES_EXT",
+        ),
+        (
+            Self::BUFFER_DEVICE_ADDRESS_INFO_EXT.0,
+            "BUFFER_DEVICE_ADDRESS_INFO_EXT",
+            "VK_STRUCTURE_TYPE_BUFFER_DEVICE_ADDRESS_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TOOL_PROPERTIES_EXT",
+        ),
+        (
+            Self::IMAGE_STENCIL_USAGE_CREATE_INFO_EXT.0,
+            "IMAGE_STENCIL_USAGE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_IMAGE_STENCIL_USAGE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_KHR",
+        ),
+        (
+            Self::BUFFER_DEVICE_ADDRESS_INFO_KHR.0,
+            "BUFFER_DEVICE_ADDRESS_INFO_KHR",
+            "VK_STRUCTURE_TYPE_BUFFER_DEVICE_ADDRESS_INFO_KHR",
+        ),
+        (
+            Self::BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO_KHR.0,
+            "BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO_KHR",
+        ),
+        (
+            Self::MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO_KHR.0,
+            "MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO_KHR",
+        ),
+        (
+            Self::DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO_KHR.0,
+            "DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT.0,
+            "PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES_EXT",
+        ),
+        (
+            Self::DEVICE_PRIVATE_DATA_CREATE_INFO_EXT.0,
+            "DEVICE_PRIVATE_DATA_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_DEVICE_PRIVATE_DATA_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PRIVATE_DATA_SLOT_CREATE_INFO_EXT.0,
+            "PRIVATE_DATA_SLOT_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PRIVATE_DATA_SLOT_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES_EXT",
+        ),
+        (
+            Self::MEMORY_BARRIER_2_KHR.0,
+            "MEMORY_BARRIER_2_KHR",
+            "VK_STRUCTURE_TYPE_MEMORY_BARRIER_2_KHR",
+        ),
+        (
+            Self::BUFFER_MEMORY_BARRIER_2_KHR.0,
+            "BUFFER_MEMORY_BARRIER_2_KHR",
+            "VK_STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER_2_KHR",
+        ),
+        (
+            Self::IMAGE_MEMORY_BARRIER_2_KHR.0,
+            "IMAGE_MEMORY_BARRIER_2_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER_2_KHR",
+        ),
+        (
+            Self::DEPENDENCY_INFO_KHR.0,
+            "DEPENDENCY_INFO_KHR",
+            "VK_STRUCTURE_TYPE_DEPENDENCY_INFO_KHR",
+        ),
+        (
+            Self::SUBMIT_INFO_2_KHR.0,
+            "SUBMIT_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_SUBMIT_INFO_2_KHR",
+        ),
+        (
+            Self::SEMAPHORE_SUBMIT_INFO_KHR.0,
+            "SEMAPHORE_SUBMIT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_SEMAPHORE_SUBMIT_INFO_KHR",
+        ),
+        (
+            Self::COMMAND_BUFFER_SUBMIT_INFO_KHR.0,
+            "COMMAND_BUFFER_SUBMIT_INFO_KHR",
+            "VK_STRUCTURE_TYPE_COMMAND_BUFFER_SUBMIT_INFO_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES_EXT",
+        ),
+        (
+            Self::COPY_BUFFER_INFO_2_KHR.0,
+            "COPY_BUFFER_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_COPY_BUFFER_INFO_2_KHR",
+        ),
+        (
+            Self::COPY_IMAGE_INFO_2_KHR.0,
+            "COPY_IMAGE_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_COPY_IMAGE_INFO_2_KHR",
+        ),
+        (
+            Self::COPY_BUFFER_TO_IMAGE_INFO_2_KHR.0,
+            "COPY_BUFFER_TO_IMAGE_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_COPY_BUFFER_TO_IMAGE_INFO_2_KHR",
+        ),
+        (
+            Self::COPY_IMAGE_TO_BUFFER_INFO_2_KHR.0,
+            "COPY_IMAGE_TO_BUFFER_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_COPY_IMAGE_TO_BUFFER_INFO_2_KHR",
+        ),
+        (
+            Self::BLIT_IMAGE_INFO_2_KHR.0,
+            "BLIT_IMAGE_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_BLIT_IMAGE_INFO_2_KHR",
+        ),
+        (
+            Self::RESOLVE_IMAGE_INFO_2_KHR.0,
+            "RESOLVE_IMAGE_INFO_2_KHR",
+            "VK_STRUCTURE_TYPE_RESOLVE_IMAGE_INFO_2_KHR",
+        ),
+        (
+            Self::BUFFER_COPY_2_KHR.0,
+            "BUFFER_COPY_2_KHR",
+            "VK_STRUCTURE_TYPE_BUFFER_COPY_2_KHR",
+        ),
+        (
+            Self::IMAGE_COPY_2_KHR.0,
+            "IMAGE_COPY_2_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_COPY_2_KHR",
+        ),
+        (
+            Self::IMAGE_BLIT_2_KHR.0,
+            "IMAGE_BLIT_2_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_BLIT_2_KHR",
+        ),
+        (
+            Self::BUFFER_IMAGE_COPY_2_KHR.0,
+            "BUFFER_IMAGE_COPY_2_KHR",
+            "VK_STRUCTURE_TYPE_BUFFER_IMAGE_COPY_2_KHR",
+        ),
+        (
+            Self::IMAGE_RESOLVE_2_KHR.0,
+            "IMAGE_RESOLVE_2_KHR",
+            "VK_STRUCTURE_TYPE_IMAGE_RESOLVE_2_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_ARM.0,
+            "PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_ARM",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_ARM",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_VALVE.0,
+            "PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_VALVE",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_VALVE",
+        ),
+        (
+            Self::MUTABLE_DESCRIPTOR_TYPE_CREATE_INFO_VALVE.0,
+            "MUTABLE_DESCRIPTOR_TYPE_CREATE_INFO_VALVE",
+            "VK_STRUCTURE_TYPE_MUTABLE_DESCRIPTOR_TYPE_CREATE_INFO_VALVE",
+        ),
+        (
+            Self::FORMAT_PROPERTIES_3_KHR.0,
+            "FORMAT_PROPERTIES_3_KHR",
+            "VK_STRUCTURE_TYPE_FORMAT_PROPERTIES_3_KHR",
+        ),
+        (
+            Self::PIPELINE_INFO_EXT.0,
+            "PIPELINE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_PIPELINE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_EXT.0,
+            "PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_EXT",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_EXT",
+        ),
+        (
+            Self::QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_EXT.0,
+            "QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_EXT",
+            "VK_STRUCTURE_TYPE_QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES_KHR.0,
+            "PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES_KHR",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES_KHR.0,
+            "PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES_KHR",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES_KHR",
+        ),
+        (
+            Self::DEVICE_BUFFER_MEMORY_REQUIREMENTS_KHR.0,
+            "DEVICE_BUFFER_MEMORY_REQUIREMENTS_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_BUFFER_MEMORY_REQUIREMENTS_KHR",
+        ),
+        (
+            Self::DEVICE_IMAGE_MEMORY_REQUIREMENTS_KHR.0,
+            "DEVICE_IMAGE_MEMORY_REQUIREMENTS_KHR",
+            "VK_STRUCTURE_TYPE_DEVICE_IMAGE_MEMORY_REQUIREMENTS_KHR",
+        ),
+        (
+            Self::SHADER_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT.0,
+            "SHADER_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT",
+            "VK_STRUCTURE_TYPE_SHADER_REQUIRED_SUBGROUP_SIZE_CREATE_INFO_EXT",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES.0,
+            "PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VARIABLE_POINTER_FEATURES",
+        ),
+        (
+            Self::PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES.0,
+            "PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES",
+            "VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SHADER_DRAW_PARAMETER_FEATURES",
+        ),
+    ];
+}
+impl FromStr for StructureType {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("StructureType", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for StructureType {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SubpassContents {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::INLINE.0, "INLINE", "VK_SUBPASS_CONTENTS_INLINE"),
+        (
+            Self::SECONDARY_COMMAND_BUFFERS.0,
+            "SECONDARY_COMMAND_BUFFERS",
+            "VK_SUBPASS_CONTENTS_SECONDARY_COMMAND_BUFFERS",
+        ),
+    ];
+}
+impl FromStr for SubpassContents {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("SubpassContents", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SubpassContents {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SubpassMergeStatusEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::MERGED.0,
+            "MERGED",
+            "VK_SUBPASS_MERGE_STATUS_MERGED_EXT",
+        ),
+        (
+            Self::DISALLOWED.0,
+            "DISALLOWED",
+            "VK_SUBPASS_MERGE_STATUS_DISALLOWED_EXT",
+        ),
+        (
+            Self::NOT_MERGED_SIDE_EFFECTS.0,
+            "NOT_MERGED_SIDE_EFFECTS",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_SIDE_EFFECTS_EXT",
+        ),
+        (
+            Self::NOT_MERGED_SAMPLES_MISMATCH.0,
+            "NOT_MERGED_SAMPLES_MISMATCH",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_SAMPLES_MISMATCH_EXT",
+        ),
+        (
+            Self::NOT_MERGED_VIEWS_MISMATCH.0,
+            "NOT_MERGED_VIEWS_MISMATCH",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_VIEWS_MISMATCH_EXT",
+        ),
+        (
+            Self::NOT_MERGED_ALIASING.0,
+            "NOT_MERGED_ALIASING",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_ALIASING_EXT",
+        ),
+        (
+            Self::NOT_MERGED_DEPENDENCIES.0,
+            "NOT_MERGED_DEPENDENCIES",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_DEPENDENCIES_EXT",
+        ),
+        (
+            Self::NOT_MERGED_INCOMPATIBLE_INPUT_ATTACHMENT.0,
+            "NOT_MERGED_INCOMPATIBLE_INPUT_ATTACHMENT",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_INCOMPATIBLE_INPUT_ATTACHMENT_EXT",
+        ),
+        (
+            Self::NOT_MERGED_TOO_MANY_ATTACHMENTS.0,
+            "NOT_MERGED_TOO_MANY_ATTACHMENTS",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_TOO_MANY_ATTACHMENTS_EXT",
+        ),
+        (
+            Self::NOT_MERGED_INSUFFICIENT_STORAGE.0,
+            "NOT_MERGED_INSUFFICIENT_STORAGE",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_INSUFFICIENT_STORAGE_EXT",
+        ),
+        (
+            Self::NOT_MERGED_DEPTH_STENCIL_COUNT.0,
+            "NOT_MERGED_DEPTH_STENCIL_COUNT",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_DEPTH_STENCIL_COUNT_EXT",
+        ),
+        (
+            Self::NOT_MERGED_RESOLVE_ATTACHMENT_REUSE.0,
+            "NOT_MERGED_RESOLVE_ATTACHMENT_REUSE",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_RESOLVE_ATTACHMENT_REUSE_EXT",
+        ),
+        (
+            Self::NOT_MERGED_SINGLE_SUBPASS.0,
+            "NOT_MERGED_SINGLE_SUBPASS",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_SINGLE_SUBPASS_EXT",
+        ),
+        (
+            Self::NOT_MERGED_UNSPECIFIED.0,
+            "NOT_MERGED_UNSPECIFIED",
+            "VK_SUBPASS_MERGE_STATUS_NOT_MERGED_UNSPECIFIED_EXT",
+        ),
+    ];
+}
+impl FromStr for SubpassMergeStatusEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("SubpassMergeStatusEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SubpassMergeStatusEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl SystemAllocationScope {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::COMMAND.0,
+            "COMMAND",
+            "VK_SYSTEM_ALLOCATION_SCOPE_COMMAND",
+        ),
+        (
+            Self::OBJECT.0,
+            "OBJECT",
+            "VK_SYSTEM_ALLOCATION_SCOPE_OBJECT",
+        ),
+        (Self::CACHE.0, "CACHE", "VK_SYSTEM_ALLOCATION_SCOPE_CACHE"),
+        (
+            Self::DEVICE.0,
+            "DEVICE",
+            "VK_SYSTEM_ALLOCATION_SCOPE_DEVICE",
+        ),
+        (
+            Self::INSTANCE.0,
+            "INSTANCE",
+            "VK_SYSTEM_ALLOCATION_SCOPE_INSTANCE",
+        ),
+    ];
+}
+impl FromStr for SystemAllocationScope {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("SystemAllocationScope", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for SystemAllocationScope {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl TessellationDomainOrigin {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::UPPER_LEFT.0,
+            "UPPER_LEFT",
+            "VK_TESSELLATION_DOMAIN_ORIGIN_UPPER_LEFT",
+        ),
+        (
+            Self::LOWER_LEFT.0,
+            "LOWER_LEFT",
+            "VK_TESSELLATION_DOMAIN_ORIGIN_LOWER_LEFT",
+        ),
+        (
+            Self::UPPER_LEFT_KHR.0,
+            "UPPER_LEFT_KHR",
+            "VK_TESSELLATION_DOMAIN_ORIGIN_UPPER_LEFT_KHR",
+        ),
+        (
+            Self::LOWER_LEFT_KHR.0,
+            "LOWER_LEFT_KHR",
+            "VK_TESSELLATION_DOMAIN_ORIGIN_LOWER_LEFT_KHR",
+        ),
+    ];
+}
+impl FromStr for TessellationDomainOrigin {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("TessellationDomainOrigin", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for TessellationDomainOrigin {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl TimeDomainEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::DEVICE.0, "DEVICE", "VK_TIME_DOMAIN_DEVICE_EXT"),
+        (
+            Self::CLOCK_MONOTONIC.0,
+            "CLOCK_MONOTONIC",
+            "VK_TIME_DOMAIN_CLOCK_MONOTONIC_EXT",
+        ),
+        (
+            Self::CLOCK_MONOTONIC_RAW.0,
+            "CLOCK_MONOTONIC_RAW",
+            "VK_TIME_DOMAIN_CLOCK_MONOTONIC_RAW_EXT",
+        ),
+        (
+            Self::QUERY_PERFORMANCE_COUNTER.0,
+            "QUERY_PERFORMANCE_COUNTER",
+            "VK_TIME_DOMAIN_QUERY_PERFORMANCE_COUNTER_EXT",
+        ),
+    ];
+}
+impl FromStr for TimeDomainEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("TimeDomainEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for TimeDomainEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ValidationCacheHeaderVersionEXT {
+    pub(crate) const KNOWN: Known<i32> = &[(
+        Self::ONE.0,
+        "ONE",
+        "VK_VALIDATION_CACHE_HEADER_VERSION_ONE_EXT",
+    )];
+}
+impl FromStr for ValidationCacheHeaderVersionEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ValidationCacheHeaderVersionEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ValidationCacheHeaderVersionEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ValidationCheckEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::ALL.0, "ALL", "VK_VALIDATION_CHECK_ALL_EXT"),
+        (
+            Self::SHADERS.0,
+            "SHADERS",
+            "VK_VALIDATION_CHECK_SHADERS_EXT",
+        ),
+    ];
+}
+impl FromStr for ValidationCheckEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ValidationCheckEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ValidationCheckEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ValidationFeatureDisableEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::ALL.0, "ALL", "VK_VALIDATION_FEATURE_DISABLE_ALL_EXT"),
+        (
+            Self::SHADERS.0,
+            "SHADERS",
+            "VK_VALIDATION_FEATURE_DISABLE_SHADERS_EXT",
+        ),
+        (
+            Self::THREAD_SAFETY.0,
+            "THREAD_SAFETY",
+            "VK_VALIDATION_FEATURE_DISABLE_THREAD_SAFETY_EXT",
+        ),
+        (
+            Self::API_PARAMETERS.0,
+            "API_PARAMETERS",
+            "VK_VALIDATION_FEATURE_DISABLE_API_PARAMETERS_EXT",
+        ),
+        (
+            Self::OBJECT_LIFETIMES.0,
+            "OBJECT_LIFETIMES",
+            "VK_VALIDATION_FEATURE_DISABLE_OBJECT_LIFETIMES_EXT",
+        ),
+        (
+            Self::CORE_CHECKS.0,
+            "CORE_CHECKS",
+            "VK_VALIDATION_FEATURE_DISABLE_CORE_CHECKS_EXT",
+        ),
+        (
+            Self::UNIQUE_HANDLES.0,
+            "UNIQUE_HANDLES",
+            "VK_VALIDATION_FEATURE_DISABLE_UNIQUE_HANDLES_EXT",
+        ),
+        (
+            Self::SHADER_VALIDATION_CACHE.0,
+            "SHADER_VALIDATION_CACHE",
+            "VK_VALIDATION_FEATURE_DISABLE_SHADER_VALIDATION_CACHE_EXT",
+        ),
+    ];
+}
+impl FromStr for ValidationFeatureDisableEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ValidationFeatureDisableEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ValidationFeatureDisableEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ValidationFeatureEnableEXT {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::GPU_ASSISTED.0,
+            "GPU_ASSISTED",
+            "VK_VALIDATION_FEATURE_ENABLE_GPU_ASSISTED_EXT",
+        ),
+        (
+            Self::GPU_ASSISTED_RESERVE_BINDING_SLOT.0,
+            "GPU_ASSISTED_RESERVE_BINDING_SLOT",
+            "VK_VALIDATION_FEATURE_ENABLE_GPU_ASSISTED_RESERVE_BINDING_SLOT_EXT",
+        ),
+        (
+            Self::BEST_PRACTICES.0,
+            "BEST_PRACTICES",
+            "VK_VALIDATION_FEATURE_ENABLE_BEST_PRACTICES_EXT",
+        ),
+        (
+            Self::DEBUG_PRINTF.0,
+            "DEBUG_PRINTF",
+            "VK_VALIDATION_FEATURE_ENABLE_DEBUG_PRINTF_EXT",
+        ),
+        (
+            Self::SYNCHRONIZATION_VALIDATION.0,
+            "SYNCHRONIZATION_VALIDATION",
+            "VK_VALIDATION_FEATURE_ENABLE_SYNCHRONIZATION_VALIDATION_EXT",
+        ),
+    ];
+}
+impl FromStr for ValidationFeatureEnableEXT {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ValidationFeatureEnableEXT", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ValidationFeatureEnableEXT {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VendorId {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::VIV.0, "VIV", "VK_VENDOR_ID_VIV"),
+        (Self::VSI.0, "VSI", "VK_VENDOR_ID_VSI"),
+        (Self::KAZAN.0, "KAZAN", "VK_VENDOR_ID_KAZAN"),
+        (Self::CODEPLAY.0, "CODEPLAY", "VK_VENDOR_ID_CODEPLAY"),
+        (Self::MESA.0, "MESA", "VK_VENDOR_ID_MESA"),
+        (Self::POCL.0, "POCL", "VK_VENDOR_ID_POCL"),
+        (Self::MOBILEYE.0, "MOBILEYE", "VK_VENDOR_ID_MOBILEYE"),
+    ];
+}
+impl FromStr for VendorId {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("VendorId", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VendorId {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VertexInputRate {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (Self::VERTEX.0, "VERTEX", "VK_VERTEX_INPUT_RATE_VERTEX"),
+        (
+            Self::INSTANCE.0,
+            "INSTANCE",
+            "VK_VERTEX_INPUT_RATE_INSTANCE",
+        ),
+    ];
+}
+impl FromStr for VertexInputRate {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("VertexInputRate", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VertexInputRate {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl VideoEncodeTuningModeKHR {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::DEFAULT.0,
+            "DEFAULT",
+            "VK_VIDEO_ENCODE_TUNING_MODE_DEFAULT_KHR",
+        ),
+        (
+            Self::HIGH_QUALITY.0,
+            "HIGH_QUALITY",
+            "VK_VIDEO_ENCODE_TUNING_MODE_HIGH_QUALITY_KHR",
+        ),
+        (
+            Self::LOW_LATENCY.0,
+            "LOW_LATENCY",
+            "VK_VIDEO_ENCODE_TUNING_MODE_LOW_LATENCY_KHR",
+        ),
+        (
+            Self::ULTRA_LOW_LATENCY.0,
+            "ULTRA_LOW_LATENCY",
+            "VK_VIDEO_ENCODE_TUNING_MODE_ULTRA_LOW_LATENCY_KHR",
+        ),
+        (
+            Self::LOSSLESS.0,
+            "LOSSLESS",
+            "VK_VIDEO_ENCODE_TUNING_MODE_LOSSLESS_KHR",
+        ),
+    ];
+}
+impl FromStr for VideoEncodeTuningModeKHR {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("VideoEncodeTuningModeKHR", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for VideoEncodeTuningModeKHR {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+impl ViewportCoordinateSwizzleNV {
+    pub(crate) const KNOWN: Known<i32> = &[
+        (
+            Self::POSITIVE_X.0,
+            "POSITIVE_X",
+            "VK_VIEWPORT_COORDINATE_SWIZZLE_POSITIVE_X_NV",
+        ),
+        (
+            Self::NEGATIVE_X.0,
+            "NEGATIVE_X",
+            "VK_VIEWPORT_COORDINATE_SWIZZLE_NEGATIVE_X_NV",
+        ),
+        (
+            Self::POSITIVE_Y.0,
+            "POSITIVE_Y",
+            "VK_VIEWPORT_COORDINATE_SWIZZLE_POSITIVE_Y_NV",
+        ),
+        (
+            Self::NEGATIVE_Y.0,
+            "NEGATIVE_Y",
+            "VK_VIEWPORT_COORDINATE_SWIZZLE_NEGATIVE_Y_NV",
+        ),
+        (
+            Self::POSITIVE_Z.0,
+            "POSITIVE_Z",
+            "VK_VIEWPORT_COORDINATE_SWIZZLE_POSITIVE_Z_NV",
+        ),
+        (
+            Self::NEGATIVE_Z.0,
+            "NEGATIVE_Z",
+            "VK_VIEWPORT_COORDINATE_SWIZZLE_NEGATIVE_Z_NV",
+        ),
+        (
+            Self::POSITIVE_W.0,
+            "POSITIVE_W",
+            "VK_VIEWPORT_COORDINATE_SWIZZLE_POSITIVE_W_NV",
+        ),
+        (
+            Self::NEGATIVE_W.0,
+            "NEGATIVE_W",
+            "VK_VIEWPORT_COORDINATE_SWIZZLE_NEGATIVE_W_NV",
+        ),
+    ];
+}
+impl FromStr for ViewportCoordinateSwizzleNV {
+    type Err = ParseValueError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_enum("ViewportCoordinateSwizzleNV", Self::KNOWN, s).map(Self)
+    }
+}
+impl TryFrom<&str> for ViewportCoordinateSwizzleNV {
+    type Error = ParseValueError;
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
 impl fmt::Debug for ObjectType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let name = match *self {
-            Self::UNKNOWN => Some("UNKNOWN"),
-            Self::INSTANCE => Some("INSTANCE"),
-            Self::PHYSICAL_DEVICE => Some("PHYSICAL_DEVICE"),
-            Self::DEVICE => Some("DEVICE"),
-            Self::QUEUE => Some("QUEUE"),
-            Self::SEMAPHORE => Some("SEMAPHORE"),
-            Self::COMMAND_BUFFER => Some("COMMAND_BUFFER"),
-            Self::FENCE => Some("FENCE"),
-            Self::DEVICE_MEMORY => Some("DEVICE_MEMORY"),
-            Self::BUFFER => Some("BUFFER"),
-            Self::IMAGE => Some("IMAGE"),
-            Self::EVENT => Some("EVENT"),
-            Self::QUERY_POOL => Some("QUERY_POOL"),
-            Self::BUFFER_VIEW => Some("BUFFER_VIEW"),
-            Self::IMAGE_VIEW => Some("IMAGE_VIEW"),
-            Self::SHADER_MODULE => Some("SHADER_MODULE"),
-            Self::PIPELINE_CACHE => Some("PIPELINE_CACHE"),
-            Self::PIPELINE_LAYOUT => Some("PIPELINE_LAYOUT"),
-            Self::RENDER_PASS => Some("RENDER_PASS"),
-            Self::PIPELINE => Some("PIPELINE"),
-            Self::DESCRIPTOR_SET_LAYOUT => Some("DESCRIPTOR_SET_LAYOUT"),
-            Self::SAMPLER => Some("SAMPLER"),
-            Self::DESCRIPTOR_POOL => Some("DESCRIPTOR_POOL"),
-            Self::DESCRIPTOR_SET => Some("DESCRIPTOR_SET"),
-            Self::FRAMEBUFFER => Some("FRAMEBUFFER"),
-            Self::COMMAND_POOL => Some("COMMAND_POOL"),
-            Self::SURFACE_KHR => Some("SURFACE_KHR"),
-            Self::SWAPCHAIN_KHR => Some("SWAPCHAIN_KHR"),
-            Self::DISPLAY_KHR => Some("DISPLAY_KHR"),
-            Self::DISPLAY_MODE_KHR => Some("DISPLAY_MODE_KHR"),
-            Self::DEBUG_REPORT_CALLBACK_EXT => Some("DEBUG_REPORT_CALLBACK_EXT"),
-            Self::VIDEO_SESSION_KHR => Some("VIDEO_SESSION_KHR"),
-            Self::VIDEO_SESSION_PARAMETERS_KHR => Some("VIDEO_SESSION_PARAMETERS_KHR"),
-            Self::CU_MODULE_NVX => Some("CU_MODULE_NVX"),
-            Self::CU_FUNCTION_NVX => Some("CU_FUNCTION_NVX"),
-            Self::DEBUG_UTILS_MESSENGER_EXT => Some("DEBUG_UTILS_MESSENGER_EXT"),
-            Self::ACCELERATION_STRUCTURE_KHR => Some("ACCELERATION_STRUCTURE_KHR"),
-            Self::VALIDATION_CACHE_EXT => Some("VALIDATION_CACHE_EXT"),
-            Self::ACCELERATION_STRUCTURE_NV => Some("ACCELERATION_STRUCTURE_NV"),
-            Self::PERFORMANCE_CONFIGURATION_INTEL => Some("PERFORMANCE_CONFIGURATION_INTEL"),
-            Self::DEFERRED_OPERATION_KHR => Some("DEFERRED_OPERATION_KHR"),
-            Self::INDIRECT_COMMANDS_LAYOUT_NV => Some("INDIRECT_COMMANDS_LAYOUT_NV"),
-            Self::BUFFER_COLLECTION_FUCHSIA => Some("BUFFER_COLLECTION_FUCHSIA"),
-            Self::MICROMAP_EXT => Some("MICROMAP_EXT"),
-            Self::OPTICAL_FLOW_SESSION_NV => Some("OPTICAL_FLOW_SESSION_NV"),
-            Self::SHADER_EXT => Some("SHADER_EXT"),
-            Self::SAMPLER_YCBCR_CONVERSION => Some("SAMPLER_YCBCR_CONVERSION"),
-            Self::DESCRIPTOR_UPDATE_TEMPLATE => Some("DESCRIPTOR_UPDATE_TEMPLATE"),
-            Self::PRIVATE_DATA_SLOT => Some("PRIVATE_DATA_SLOT"),
-            _ => None,
-        };
-        if let Some(x) = name {
-            f.write_str(x)
-        } else {
-            self.0.fmt(f)
-        }
+        debug_enum(f, Self::KNOWN, self.0)
     }
 }
 impl fmt::Debug for Result {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let name = match *self {
-            Self::SUCCESS => Some("SUCCESS"),
-            Self::NOT_READY => Some("NOT_READY"),
-            Self::TIMEOUT => Some("TIMEOUT"),
-            Self::EVENT_SET => Some("EVENT_SET"),
-            Self::EVENT_RESET => Some("EVENT_RESET"),
-            Self::INCOMPLETE => Some("INCOMPLETE"),
-            Self::ERROR_OUT_OF_HOST_MEMORY => Some("ERROR_OUT_OF_HOST_MEMORY"),
-            Self::ERROR_OUT_OF_DEVICE_MEMORY => Some("ERROR_OUT_OF_DEVICE_MEMORY"),
-            Self::ERROR_INITIALIZATION_FAILED => Some("ERROR_INITIALIZATION_FAILED"),
-            Self::ERROR_DEVICE_LOST => Some("ERROR_DEVICE_LOST"),
-            Self::ERROR_MEMORY_MAP_FAILED => Some("ERROR_MEMORY_MAP_FAILED"),
-            Self::ERROR_LAYER_NOT_PRESENT => Some("ERROR_LAYER_NOT_PRESENT"),
-            Self::ERROR_EXTENSION_NOT_PRESENT => Some("ERROR_EXTENSION_NOT_PRESENT"),
-            Self::ERROR_FEATURE_NOT_PRESENT => Some("ERROR_FEATURE_NOT_PRESENT"),
-            Self::ERROR_INCOMPATIBLE_DRIVER => Some("ERROR_INCOMPATIBLE_DRIVER"),
-            Self::ERROR_TOO_MANY_OBJECTS => Some("ERROR_TOO_MANY_OBJECTS"),
-            Self::ERROR_FORMAT_NOT_SUPPORTED => Some("ERROR_FORMAT_NOT_SUPPORTED"),
-            Self::ERROR_FRAGMENTED_POOL => Some("ERROR_FRAGMENTED_POOL"),
-            Self::ERROR_UNKNOWN => Some("ERROR_UNKNOWN"),
-            Self::ERROR_SURFACE_LOST_KHR => Some("ERROR_SURFACE_LOST_KHR"),
-            Self::ERROR_NATIVE_WINDOW_IN_USE_KHR => Some("ERROR_NATIVE_WINDOW_IN_USE_KHR"),
-            Self::SUBOPTIMAL_KHR => Some("SUBOPTIMAL_KHR"),
-            Self::ERROR_OUT_OF_DATE_KHR => Some("ERROR_OUT_OF_DATE_KHR"),
-            Self::ERROR_INCOMPATIBLE_DISPLAY_KHR => Some("ERROR_INCOMPATIBLE_DISPLAY_KHR"),
-            Self::ERROR_VALIDATION_FAILED_EXT => Some("ERROR_VALIDATION_FAILED_EXT"),
-            Self::ERROR_INVALID_SHADER_NV => Some("ERROR_INVALID_SHADER_NV"),
-            Self::ERROR_IMAGE_USAGE_NOT_SUPPORTED_KHR => {
-                Some("ERROR_IMAGE_USAGE_NOT_SUPPORTED_KHR")
-            }
-            Self::ERROR_VIDEO_PICTURE_LAYOUT_NOT_SUPPORTED_KHR => {
-                Some("ERROR_VIDEO_PICTURE_LAYOUT_NOT_SUPPORTED_KHR")
-            }
-            Self::ERROR_VIDEO_PROFILE_OPERATION_NOT_SUPPORTED_KHR => {
-                Some("ERROR_VIDEO_PROFILE_OPERATION_NOT_SUPPORTED_KHR")
-            }
-            Self::ERROR_VIDEO_PROFILE_FORMAT_NOT_SUPPORTED_KHR => {
-                Some("ERROR_VIDEO_PROFILE_FORMAT_NOT_SUPPORTED_KHR")
-            }
-            Self::ERROR_VIDEO_PROFILE_CODEC_NOT_SUPPORTED_KHR => {
-                Some("ERROR_VIDEO_PROFILE_CODEC_NOT_SUPPORTED_KHR")
-            }
-            Self::ERROR_VIDEO_STD_VERSION_NOT_SUPPORTED_KHR => {
-                Some("ERROR_VIDEO_STD_VERSION_NOT_SUPPORTED_KHR")
-            }
-            Self::ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT => {
-                Some("ERROR_INVALID_DRM_FORMAT_MODIFIER_PLANE_LAYOUT_EXT")
-            }
-            Self::ERROR_NOT_PERMITTED_KHR => Some("ERROR_NOT_PERMITTED_KHR"),
-            Self::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT => {
-                Some("ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT")
-            }
-            Self::THREAD_IDLE_KHR => Some("THREAD_IDLE_KHR"),
-            Self::THREAD_DONE_KHR => Some("THREAD_DONE_KHR"),
-            Self::OPERATION_DEFERRED_KHR => Some("OPERATION_DEFERRED_KHR"),
-            Self::OPERATION_NOT_DEFERRED_KHR => Some("OPERATION_NOT_DEFERRED_KHR"),
-            Self::ERROR_INVALID_VIDEO_STD_PARAMETERS_KHR => {
-                Some("ERROR_INVALID_VIDEO_STD_PARAMETERS_KHR")
-            }
-            Self::ERROR_COMPRESSION_EXHAUSTED_EXT => Some("ERROR_COMPRESSION_EXHAUSTED_EXT"),
-            Self::ERROR_INCOMPATIBLE_SHADER_BINARY_EXT => {
-                Some("ERROR_INCOMPATIBLE_SHADER_BINARY_EXT")
-            }
-            Self::ERROR_OUT_OF_POOL_MEMORY => Some("ERROR_OUT_OF_POOL_MEMORY"),
-            Self::ERROR_INVALID_EXTERNAL_HANDLE => Some("ERROR_INVALID_EXTERNAL_HANDLE"),
-            Self::ERROR_FRAGMENTATION => Some("ERROR_FRAGMENTATION"),
-            Self::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS => {
-                Some("ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS")
-            }
-            Self::PIPELINE_COMPILE_REQUIRED => Some("PIPELINE_COMPILE_REQUIRED"),
-            _ => None,
-        };
-        if let Some(x) = name {
-            f.write_str(x)
-        } else {
-            self.0.fmt(f)
-        }
+        debug_enum(f, Self::KNOWN, self.0)
     }
 }
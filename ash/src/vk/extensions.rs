@@ -8,6 +8,7 @@ impl KhrSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0") };
     pub const SPEC_VERSION: u32 = 25u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkDestroySurfaceKHR = unsafe extern "system" fn(
@@ -54,9 +55,25 @@ pub struct KhrSurfaceFn {
 unsafe impl Send for KhrSurfaceFn {}
 unsafe impl Sync for KhrSurfaceFn {}
 impl KhrSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             destroy_surface_khr: unsafe {
@@ -70,6 +87,7 @@ impl KhrSurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroySurfaceKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_surface_khr
                 } else {
@@ -92,6 +110,7 @@ impl KhrSurfaceFn {
                     b"vkGetPhysicalDeviceSurfaceSupportKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_surface_support_khr
                 } else {
@@ -113,6 +132,7 @@ impl KhrSurfaceFn {
                     b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_surface_capabilities_khr
                 } else {
@@ -135,6 +155,7 @@ impl KhrSurfaceFn {
                     b"vkGetPhysicalDeviceSurfaceFormatsKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_surface_formats_khr
                 } else {
@@ -157,6 +178,7 @@ impl KhrSurfaceFn {
                     b"vkGetPhysicalDeviceSurfacePresentModesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_surface_present_modes_khr
                 } else {
@@ -179,6 +201,9 @@ impl KhrSwapchainFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0") };
     pub const SPEC_VERSION: u32 = 70u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateSwapchainKHR = unsafe extern "system" fn(
@@ -251,9 +276,25 @@ pub struct KhrSwapchainFn {
 unsafe impl Send for KhrSwapchainFn {}
 unsafe impl Sync for KhrSwapchainFn {}
 impl KhrSwapchainFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_swapchain_khr: unsafe {
@@ -268,6 +309,7 @@ impl KhrSwapchainFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateSwapchainKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_swapchain_khr
                 } else {
@@ -288,6 +330,7 @@ impl KhrSwapchainFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroySwapchainKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_swapchain_khr
                 } else {
@@ -309,6 +352,7 @@ impl KhrSwapchainFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainImagesKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_swapchain_images_khr
                 } else {
@@ -332,6 +376,7 @@ impl KhrSwapchainFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAcquireNextImageKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     acquire_next_image_khr
                 } else {
@@ -347,6 +392,7 @@ impl KhrSwapchainFn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkQueuePresentKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_present_khr
                 } else {
@@ -367,6 +413,7 @@ impl KhrSwapchainFn {
                     b"vkGetDeviceGroupPresentCapabilitiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_group_present_capabilities_khr
                 } else {
@@ -388,6 +435,7 @@ impl KhrSwapchainFn {
                     b"vkGetDeviceGroupSurfacePresentModesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_group_surface_present_modes_khr
                 } else {
@@ -410,6 +458,7 @@ impl KhrSwapchainFn {
                     b"vkGetPhysicalDevicePresentRectanglesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_present_rectangles_khr
                 } else {
@@ -430,6 +479,7 @@ impl KhrSwapchainFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAcquireNextImage2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     acquire_next_image2_khr
                 } else {
@@ -474,6 +524,9 @@ impl KhrDisplayFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_display\0") };
     pub const SPEC_VERSION: u32 = 23u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceDisplayPropertiesKHR = unsafe extern "system" fn(
@@ -537,9 +590,25 @@ pub struct KhrDisplayFn {
 unsafe impl Send for KhrDisplayFn {}
 unsafe impl Sync for KhrDisplayFn {}
 impl KhrDisplayFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_display_properties_khr: unsafe {
@@ -557,6 +626,7 @@ impl KhrDisplayFn {
                     b"vkGetPhysicalDeviceDisplayPropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_display_properties_khr
                 } else {
@@ -578,6 +648,7 @@ impl KhrDisplayFn {
                     b"vkGetPhysicalDeviceDisplayPlanePropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_display_plane_properties_khr
                 } else {
@@ -600,6 +671,7 @@ impl KhrDisplayFn {
                     b"vkGetDisplayPlaneSupportedDisplaysKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_display_plane_supported_displays_khr
                 } else {
@@ -622,6 +694,7 @@ impl KhrDisplayFn {
                     b"vkGetDisplayModePropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_display_mode_properties_khr
                 } else {
@@ -644,6 +717,7 @@ impl KhrDisplayFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateDisplayModeKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_display_mode_khr
                 } else {
@@ -666,6 +740,7 @@ impl KhrDisplayFn {
                     b"vkGetDisplayPlaneCapabilitiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_display_plane_capabilities_khr
                 } else {
@@ -688,6 +763,7 @@ impl KhrDisplayFn {
                     b"vkCreateDisplayPlaneSurfaceKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_display_plane_surface_khr
                 } else {
@@ -711,6 +787,14 @@ impl KhrDisplaySwapchainFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_display_swapchain\0") };
     pub const SPEC_VERSION: u32 = 10u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_display\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateSharedSwapchainsKHR = unsafe extern "system" fn(
@@ -727,9 +811,25 @@ pub struct KhrDisplaySwapchainFn {
 unsafe impl Send for KhrDisplaySwapchainFn {}
 unsafe impl Sync for KhrDisplaySwapchainFn {}
 impl KhrDisplaySwapchainFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_shared_swapchains_khr: unsafe {
@@ -749,6 +849,7 @@ impl KhrDisplaySwapchainFn {
                     b"vkCreateSharedSwapchainsKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_shared_swapchains_khr
                 } else {
@@ -770,6 +871,9 @@ impl KhrXlibSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_xlib_surface\0") };
     pub const SPEC_VERSION: u32 = 6u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateXlibSurfaceKHR = unsafe extern "system" fn(
@@ -794,9 +898,25 @@ pub struct KhrXlibSurfaceFn {
 unsafe impl Send for KhrXlibSurfaceFn {}
 unsafe impl Sync for KhrXlibSurfaceFn {}
 impl KhrXlibSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_xlib_surface_khr: unsafe {
@@ -814,6 +934,7 @@ impl KhrXlibSurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateXlibSurfaceKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_xlib_surface_khr
                 } else {
@@ -836,6 +957,7 @@ impl KhrXlibSurfaceFn {
                     b"vkGetPhysicalDeviceXlibPresentationSupportKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_xlib_presentation_support_khr
                 } else {
@@ -853,6 +975,9 @@ impl KhrXcbSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_xcb_surface\0") };
     pub const SPEC_VERSION: u32 = 6u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateXcbSurfaceKHR = unsafe extern "system" fn(
@@ -877,9 +1002,25 @@ pub struct KhrXcbSurfaceFn {
 unsafe impl Send for KhrXcbSurfaceFn {}
 unsafe impl Sync for KhrXcbSurfaceFn {}
 impl KhrXcbSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_xcb_surface_khr: unsafe {
@@ -897,6 +1038,7 @@ impl KhrXcbSurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateXcbSurfaceKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_xcb_surface_khr
                 } else {
@@ -919,6 +1061,7 @@ impl KhrXcbSurfaceFn {
                     b"vkGetPhysicalDeviceXcbPresentationSupportKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_xcb_presentation_support_khr
                 } else {
@@ -936,6 +1079,9 @@ impl KhrWaylandSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_wayland_surface\0") };
     pub const SPEC_VERSION: u32 = 6u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateWaylandSurfaceKHR = unsafe extern "system" fn(
@@ -960,9 +1106,25 @@ pub struct KhrWaylandSurfaceFn {
 unsafe impl Send for KhrWaylandSurfaceFn {}
 unsafe impl Sync for KhrWaylandSurfaceFn {}
 impl KhrWaylandSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_wayland_surface_khr: unsafe {
@@ -980,6 +1142,7 @@ impl KhrWaylandSurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateWaylandSurfaceKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_wayland_surface_khr
                 } else {
@@ -1001,6 +1164,7 @@ impl KhrWaylandSurfaceFn {
                     b"vkGetPhysicalDeviceWaylandPresentationSupportKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_wayland_presentation_support_khr
                 } else {
@@ -1018,6 +1182,9 @@ impl KhrAndroidSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_android_surface\0") };
     pub const SPEC_VERSION: u32 = 6u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateAndroidSurfaceKHR = unsafe extern "system" fn(
@@ -1033,9 +1200,25 @@ pub struct KhrAndroidSurfaceFn {
 unsafe impl Send for KhrAndroidSurfaceFn {}
 unsafe impl Sync for KhrAndroidSurfaceFn {}
 impl KhrAndroidSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_android_surface_khr: unsafe {
@@ -1053,6 +1236,7 @@ impl KhrAndroidSurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateAndroidSurfaceKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_android_surface_khr
                 } else {
@@ -1070,6 +1254,9 @@ impl KhrWin32SurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_win32_surface\0") };
     pub const SPEC_VERSION: u32 = 6u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateWin32SurfaceKHR = unsafe extern "system" fn(
@@ -1090,9 +1277,25 @@ pub struct KhrWin32SurfaceFn {
 unsafe impl Send for KhrWin32SurfaceFn {}
 unsafe impl Sync for KhrWin32SurfaceFn {}
 impl KhrWin32SurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_win32_surface_khr: unsafe {
@@ -1110,6 +1313,7 @@ impl KhrWin32SurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateWin32SurfaceKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_win32_surface_khr
                 } else {
@@ -1130,6 +1334,7 @@ impl KhrWin32SurfaceFn {
                     b"vkGetPhysicalDeviceWin32PresentationSupportKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_win32_presentation_support_khr
                 } else {
@@ -1147,6 +1352,7 @@ impl AndroidNativeBufferFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_ANDROID_native_buffer\0") };
     pub const SPEC_VERSION: u32 = 8u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetSwapchainGrallocUsageANDROID = unsafe extern "system" fn(
@@ -1190,9 +1396,25 @@ pub struct AndroidNativeBufferFn {
 unsafe impl Send for AndroidNativeBufferFn {}
 unsafe impl Sync for AndroidNativeBufferFn {}
 impl AndroidNativeBufferFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_swapchain_gralloc_usage_android: unsafe {
@@ -1211,6 +1433,7 @@ impl AndroidNativeBufferFn {
                     b"vkGetSwapchainGrallocUsageANDROID\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_swapchain_gralloc_usage_android
                 } else {
@@ -1233,6 +1456,7 @@ impl AndroidNativeBufferFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAcquireImageANDROID\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     acquire_image_android
                 } else {
@@ -1256,6 +1480,7 @@ impl AndroidNativeBufferFn {
                     b"vkQueueSignalReleaseImageANDROID\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_signal_release_image_android
                 } else {
@@ -1280,6 +1505,7 @@ impl AndroidNativeBufferFn {
                     b"vkGetSwapchainGrallocUsage2ANDROID\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_swapchain_gralloc_usage2_android
                 } else {
@@ -1299,6 +1525,7 @@ impl ExtDebugReportFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_debug_report\0") };
     pub const SPEC_VERSION: u32 = 10u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateDebugReportCallbackEXT = unsafe extern "system" fn(
@@ -1333,9 +1560,25 @@ pub struct ExtDebugReportFn {
 unsafe impl Send for ExtDebugReportFn {}
 unsafe impl Sync for ExtDebugReportFn {}
 impl ExtDebugReportFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_debug_report_callback_ext: unsafe {
@@ -1354,6 +1597,7 @@ impl ExtDebugReportFn {
                     b"vkCreateDebugReportCallbackEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_debug_report_callback_ext
                 } else {
@@ -1375,6 +1619,7 @@ impl ExtDebugReportFn {
                     b"vkDestroyDebugReportCallbackEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_debug_report_callback_ext
                 } else {
@@ -1400,6 +1645,7 @@ impl ExtDebugReportFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDebugReportMessageEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     debug_report_message_ext
                 } else {
@@ -1430,6 +1676,7 @@ impl NvGlslShaderFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_glsl_shader\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct NvGlslShaderFn;
@@ -1442,6 +1689,7 @@ impl ExtDepthRangeUnrestrictedFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_depth_range_unrestricted\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtDepthRangeUnrestrictedFn;
@@ -1450,6 +1698,7 @@ impl KhrSamplerMirrorClampToEdgeFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_sampler_mirror_clamp_to_edge\0")
     };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct KhrSamplerMirrorClampToEdgeFn;
@@ -1462,6 +1711,7 @@ impl ImgFilterCubicFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_IMG_filter_cubic\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ImgFilterCubicFn;
@@ -1478,6 +1728,7 @@ impl AmdRasterizationOrderFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_rasterization_order\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdRasterizationOrderFn;
@@ -1490,6 +1741,7 @@ impl AmdShaderTrinaryMinmaxFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_shader_trinary_minmax\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdShaderTrinaryMinmaxFn;
@@ -1500,6 +1752,7 @@ impl AmdShaderExplicitVertexParameterFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdShaderExplicitVertexParameterFn;
@@ -1507,6 +1760,9 @@ impl ExtDebugMarkerFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_debug_marker\0") };
     pub const SPEC_VERSION: u32 = 4u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_debug_report\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkDebugMarkerSetObjectTagEXT = unsafe extern "system" fn(
@@ -1541,9 +1797,25 @@ pub struct ExtDebugMarkerFn {
 unsafe impl Send for ExtDebugMarkerFn {}
 unsafe impl Sync for ExtDebugMarkerFn {}
 impl ExtDebugMarkerFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             debug_marker_set_object_tag_ext: unsafe {
@@ -1560,6 +1832,7 @@ impl ExtDebugMarkerFn {
                     b"vkDebugMarkerSetObjectTagEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     debug_marker_set_object_tag_ext
                 } else {
@@ -1580,6 +1853,7 @@ impl ExtDebugMarkerFn {
                     b"vkDebugMarkerSetObjectNameEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     debug_marker_set_object_name_ext
                 } else {
@@ -1599,6 +1873,7 @@ impl ExtDebugMarkerFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDebugMarkerBeginEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_debug_marker_begin_ext
                 } else {
@@ -1615,6 +1890,7 @@ impl ExtDebugMarkerFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDebugMarkerEndEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_debug_marker_end_ext
                 } else {
@@ -1634,6 +1910,7 @@ impl ExtDebugMarkerFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDebugMarkerInsertEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_debug_marker_insert_ext
                 } else {
@@ -1653,6 +1930,12 @@ impl KhrVideoQueueFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_queue\0") };
     pub const SPEC_VERSION: u32 = 8u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_synchronization2\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceVideoCapabilitiesKHR = unsafe extern "system" fn(
@@ -1747,9 +2030,25 @@ pub struct KhrVideoQueueFn {
 unsafe impl Send for KhrVideoQueueFn {}
 unsafe impl Sync for KhrVideoQueueFn {}
 impl KhrVideoQueueFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_video_capabilities_khr: unsafe {
@@ -1767,6 +2066,7 @@ impl KhrVideoQueueFn {
                     b"vkGetPhysicalDeviceVideoCapabilitiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_video_capabilities_khr
                 } else {
@@ -1789,6 +2089,7 @@ impl KhrVideoQueueFn {
                     b"vkGetPhysicalDeviceVideoFormatPropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_video_format_properties_khr
                 } else {
@@ -1810,6 +2111,7 @@ impl KhrVideoQueueFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateVideoSessionKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_video_session_khr
                 } else {
@@ -1830,6 +2132,7 @@ impl KhrVideoQueueFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyVideoSessionKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_video_session_khr
                 } else {
@@ -1852,6 +2155,7 @@ impl KhrVideoQueueFn {
                     b"vkGetVideoSessionMemoryRequirementsKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_video_session_memory_requirements_khr
                 } else {
@@ -1874,6 +2178,7 @@ impl KhrVideoQueueFn {
                     b"vkBindVideoSessionMemoryKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     bind_video_session_memory_khr
                 } else {
@@ -1896,6 +2201,7 @@ impl KhrVideoQueueFn {
                     b"vkCreateVideoSessionParametersKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_video_session_parameters_khr
                 } else {
@@ -1917,6 +2223,7 @@ impl KhrVideoQueueFn {
                     b"vkUpdateVideoSessionParametersKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     update_video_session_parameters_khr
                 } else {
@@ -1938,6 +2245,7 @@ impl KhrVideoQueueFn {
                     b"vkDestroyVideoSessionParametersKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_video_session_parameters_khr
                 } else {
@@ -1957,6 +2265,7 @@ impl KhrVideoQueueFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginVideoCodingKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_video_coding_khr
                 } else {
@@ -1976,6 +2285,7 @@ impl KhrVideoQueueFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdEndVideoCodingKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_video_coding_khr
                 } else {
@@ -1996,6 +2306,7 @@ impl KhrVideoQueueFn {
                     b"vkCmdControlVideoCodingKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_control_video_coding_khr
                 } else {
@@ -2053,6 +2364,14 @@ impl KhrVideoDecodeQueueFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_decode_queue\0") };
     pub const SPEC_VERSION: u32 = 7u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_queue\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_synchronization2\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdDecodeVideoKHR = unsafe extern "system" fn(
@@ -2066,9 +2385,25 @@ pub struct KhrVideoDecodeQueueFn {
 unsafe impl Send for KhrVideoDecodeQueueFn {}
 unsafe impl Sync for KhrVideoDecodeQueueFn {}
 impl KhrVideoDecodeQueueFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_decode_video_khr: unsafe {
@@ -2081,6 +2416,7 @@ impl KhrVideoDecodeQueueFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDecodeVideoKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_decode_video_khr
                 } else {
@@ -2140,6 +2476,7 @@ impl AmdGcnShaderFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_gcn_shader\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdGcnShaderFn;
@@ -2147,6 +2484,7 @@ impl NvDedicatedAllocationFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_dedicated_allocation\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct NvDedicatedAllocationFn;
@@ -2160,6 +2498,9 @@ impl ExtTransformFeedbackFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_transform_feedback\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdBindTransformFeedbackBuffersEXT = unsafe extern "system" fn(
@@ -2223,9 +2564,25 @@ pub struct ExtTransformFeedbackFn {
 unsafe impl Send for ExtTransformFeedbackFn {}
 unsafe impl Sync for ExtTransformFeedbackFn {}
 impl ExtTransformFeedbackFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_bind_transform_feedback_buffers_ext: unsafe {
@@ -2246,6 +2603,7 @@ impl ExtTransformFeedbackFn {
                     b"vkCmdBindTransformFeedbackBuffersEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_transform_feedback_buffers_ext
                 } else {
@@ -2269,6 +2627,7 @@ impl ExtTransformFeedbackFn {
                     b"vkCmdBeginTransformFeedbackEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_transform_feedback_ext
                 } else {
@@ -2292,6 +2651,7 @@ impl ExtTransformFeedbackFn {
                     b"vkCmdEndTransformFeedbackEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_transform_feedback_ext
                 } else {
@@ -2314,6 +2674,7 @@ impl ExtTransformFeedbackFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginQueryIndexedEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_query_indexed_ext
                 } else {
@@ -2335,6 +2696,7 @@ impl ExtTransformFeedbackFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdEndQueryIndexedEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_query_indexed_ext
                 } else {
@@ -2360,6 +2722,7 @@ impl ExtTransformFeedbackFn {
                     b"vkCmdDrawIndirectByteCountEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_indirect_byte_count_ext
                 } else {
@@ -2399,6 +2762,7 @@ impl NvxBinaryImportFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NVX_binary_import\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateCuModuleNVX = unsafe extern "system" fn(
@@ -2440,9 +2804,25 @@ pub struct NvxBinaryImportFn {
 unsafe impl Send for NvxBinaryImportFn {}
 unsafe impl Sync for NvxBinaryImportFn {}
 impl NvxBinaryImportFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_cu_module_nvx: unsafe {
@@ -2457,6 +2837,7 @@ impl NvxBinaryImportFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateCuModuleNVX\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_cu_module_nvx
                 } else {
@@ -2478,6 +2859,7 @@ impl NvxBinaryImportFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateCuFunctionNVX\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_cu_function_nvx
                 } else {
@@ -2498,6 +2880,7 @@ impl NvxBinaryImportFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyCuModuleNVX\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_cu_module_nvx
                 } else {
@@ -2518,6 +2901,7 @@ impl NvxBinaryImportFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyCuFunctionNVX\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_cu_function_nvx
                 } else {
@@ -2537,6 +2921,7 @@ impl NvxBinaryImportFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCuLaunchKernelNVX\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_cu_launch_kernel_nvx
                 } else {
@@ -2566,6 +2951,7 @@ impl NvxImageViewHandleFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NVX_image_view_handle\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetImageViewHandleNVX =
@@ -2584,9 +2970,25 @@ pub struct NvxImageViewHandleFn {
 unsafe impl Send for NvxImageViewHandleFn {}
 unsafe impl Sync for NvxImageViewHandleFn {}
 impl NvxImageViewHandleFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_image_view_handle_nvx: unsafe {
@@ -2602,6 +3004,7 @@ impl NvxImageViewHandleFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetImageViewHandleNVX\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_view_handle_nvx
                 } else {
@@ -2622,6 +3025,7 @@ impl NvxImageViewHandleFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetImageViewAddressNVX\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_view_address_nvx
                 } else {
@@ -2640,6 +3044,7 @@ impl AmdDrawIndirectCountFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_draw_indirect_count\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdDrawIndirectCount = unsafe extern "system" fn(
@@ -2669,9 +3074,25 @@ pub struct AmdDrawIndirectCountFn {
 unsafe impl Send for AmdDrawIndirectCountFn {}
 unsafe impl Sync for AmdDrawIndirectCountFn {}
 impl AmdDrawIndirectCountFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_draw_indirect_count_amd: unsafe {
@@ -2692,6 +3113,7 @@ impl AmdDrawIndirectCountFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirectCountAMD\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_indirect_count_amd
                 } else {
@@ -2717,6 +3139,7 @@ impl AmdDrawIndirectCountFn {
                     b"vkCmdDrawIndexedIndirectCountAMD\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_indexed_indirect_count_amd
                 } else {
@@ -2731,6 +3154,7 @@ impl AmdNegativeViewportHeightFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_negative_viewport_height\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdNegativeViewportHeightFn;
@@ -2739,6 +3163,7 @@ impl AmdGpuShaderHalfFloatFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_gpu_shader_half_float\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdGpuShaderHalfFloatFn;
@@ -2746,6 +3171,7 @@ impl AmdShaderBallotFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_shader_ballot\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdShaderBallotFn;
@@ -2753,6 +3179,9 @@ impl ExtVideoEncodeH264Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_video_encode_h264\0") };
     pub const SPEC_VERSION: u32 = 11u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_encode_queue\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtVideoEncodeH264Fn;
@@ -2781,6 +3210,9 @@ impl ExtVideoEncodeH265Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_video_encode_h265\0") };
     pub const SPEC_VERSION: u32 = 11u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_encode_queue\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtVideoEncodeH265Fn;
@@ -2809,6 +3241,9 @@ impl KhrVideoDecodeH264Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_decode_h264\0") };
     pub const SPEC_VERSION: u32 = 8u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_decode_queue\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrVideoDecodeH264Fn;
@@ -2830,6 +3265,9 @@ impl AmdTextureGatherBiasLodFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_texture_gather_bias_lod\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct AmdTextureGatherBiasLodFn;
@@ -2841,6 +3279,7 @@ impl AmdShaderInfoFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_shader_info\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetShaderInfoAMD = unsafe extern "system" fn(
@@ -2858,9 +3297,25 @@ pub struct AmdShaderInfoFn {
 unsafe impl Send for AmdShaderInfoFn {}
 unsafe impl Sync for AmdShaderInfoFn {}
 impl AmdShaderInfoFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_shader_info_amd: unsafe {
@@ -2877,6 +3332,7 @@ impl AmdShaderInfoFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetShaderInfoAMD\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_shader_info_amd
                 } else {
@@ -2890,6 +3346,16 @@ impl KhrDynamicRenderingFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_dynamic_rendering\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_depth_stencil_resolve\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdBeginRendering = unsafe extern "system" fn(
@@ -2906,9 +3372,25 @@ pub struct KhrDynamicRenderingFn {
 unsafe impl Send for KhrDynamicRenderingFn {}
 unsafe impl Sync for KhrDynamicRenderingFn {}
 impl KhrDynamicRenderingFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_begin_rendering_khr: unsafe {
@@ -2924,6 +3406,7 @@ impl KhrDynamicRenderingFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRenderingKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_rendering_khr
                 } else {
@@ -2940,6 +3423,7 @@ impl KhrDynamicRenderingFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRenderingKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_rendering_khr
                 } else {
@@ -2980,6 +3464,7 @@ impl AmdShaderImageLoadStoreLodFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_shader_image_load_store_lod\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdShaderImageLoadStoreLodFn;
@@ -2988,6 +3473,9 @@ impl GgpStreamDescriptorSurfaceFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_GGP_stream_descriptor_surface\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateStreamDescriptorSurfaceGGP = unsafe extern "system" fn(
@@ -3003,9 +3491,25 @@ pub struct GgpStreamDescriptorSurfaceFn {
 unsafe impl Send for GgpStreamDescriptorSurfaceFn {}
 unsafe impl Sync for GgpStreamDescriptorSurfaceFn {}
 impl GgpStreamDescriptorSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_stream_descriptor_surface_ggp: unsafe {
@@ -3024,6 +3528,7 @@ impl GgpStreamDescriptorSurfaceFn {
                     b"vkCreateStreamDescriptorSurfaceGGP\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_stream_descriptor_surface_ggp
                 } else {
@@ -3041,6 +3546,9 @@ impl NvCornerSampledImageFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_corner_sampled_image\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvCornerSampledImageFn;
@@ -3056,6 +3564,9 @@ impl KhrMultiviewFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_multiview\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrMultiviewFn;
@@ -3075,6 +3586,7 @@ impl ImgFormatPvrtcFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_IMG_format_pvrtc\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ImgFormatPvrtcFn;
@@ -3094,6 +3606,7 @@ impl NvExternalMemoryCapabilitiesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_external_memory_capabilities\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceExternalImageFormatPropertiesNV =
@@ -3115,9 +3628,25 @@ pub struct NvExternalMemoryCapabilitiesFn {
 unsafe impl Send for NvExternalMemoryCapabilitiesFn {}
 unsafe impl Sync for NvExternalMemoryCapabilitiesFn {}
 impl NvExternalMemoryCapabilitiesFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_external_image_format_properties_nv: unsafe {
@@ -3140,6 +3669,7 @@ impl NvExternalMemoryCapabilitiesFn {
                     b"vkGetPhysicalDeviceExternalImageFormatPropertiesNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_external_image_format_properties_nv
                 } else {
@@ -3153,6 +3683,9 @@ impl NvExternalMemoryFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_external_memory\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_external_memory_capabilities\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvExternalMemoryFn;
@@ -3166,6 +3699,9 @@ impl NvExternalMemoryWin32Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_external_memory_win32\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_external_memory\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetMemoryWin32HandleNV = unsafe extern "system" fn(
@@ -3181,9 +3717,25 @@ pub struct NvExternalMemoryWin32Fn {
 unsafe impl Send for NvExternalMemoryWin32Fn {}
 unsafe impl Sync for NvExternalMemoryWin32Fn {}
 impl NvExternalMemoryWin32Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_memory_win32_handle_nv: unsafe {
@@ -3201,6 +3753,7 @@ impl NvExternalMemoryWin32Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryWin32HandleNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_memory_win32_handle_nv
                 } else {
@@ -3219,6 +3772,9 @@ impl NvWin32KeyedMutexFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_win32_keyed_mutex\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_external_memory_win32\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvWin32KeyedMutexFn;
@@ -3231,6 +3787,7 @@ impl KhrGetPhysicalDeviceProperties2Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceFeatures2 = unsafe extern "system" fn(
@@ -3288,9 +3845,25 @@ pub struct KhrGetPhysicalDeviceProperties2Fn {
 unsafe impl Send for KhrGetPhysicalDeviceProperties2Fn {}
 unsafe impl Sync for KhrGetPhysicalDeviceProperties2Fn {}
 impl KhrGetPhysicalDeviceProperties2Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_features2_khr: unsafe {
@@ -3307,6 +3880,7 @@ impl KhrGetPhysicalDeviceProperties2Fn {
                     b"vkGetPhysicalDeviceFeatures2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_features2_khr
                 } else {
@@ -3327,6 +3901,7 @@ impl KhrGetPhysicalDeviceProperties2Fn {
                     b"vkGetPhysicalDeviceProperties2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_properties2_khr
                 } else {
@@ -3348,6 +3923,7 @@ impl KhrGetPhysicalDeviceProperties2Fn {
                     b"vkGetPhysicalDeviceFormatProperties2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_format_properties2_khr
                 } else {
@@ -3369,6 +3945,7 @@ impl KhrGetPhysicalDeviceProperties2Fn {
                     b"vkGetPhysicalDeviceImageFormatProperties2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_image_format_properties2_khr
                 } else {
@@ -3390,6 +3967,7 @@ impl KhrGetPhysicalDeviceProperties2Fn {
                     b"vkGetPhysicalDeviceQueueFamilyProperties2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_queue_family_properties2_khr
                 } else {
@@ -3410,6 +3988,7 @@ impl KhrGetPhysicalDeviceProperties2Fn {
                     b"vkGetPhysicalDeviceMemoryProperties2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_memory_properties2_khr
                 } else {
@@ -3432,6 +4011,7 @@ impl KhrGetPhysicalDeviceProperties2Fn {
                     b"vkGetPhysicalDeviceSparseImageFormatProperties2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_sparse_image_format_properties2_khr
                 } else {
@@ -3460,6 +4040,9 @@ impl KhrDeviceGroupFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_device_group\0") };
     pub const SPEC_VERSION: u32 = 4u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_device_group_creation\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetDeviceGroupPeerMemoryFeatures = unsafe extern "system" fn(
@@ -3498,9 +4081,25 @@ pub struct KhrDeviceGroupFn {
 unsafe impl Send for KhrDeviceGroupFn {}
 unsafe impl Sync for KhrDeviceGroupFn {}
 impl KhrDeviceGroupFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_device_group_peer_memory_features_khr: unsafe {
@@ -3520,6 +4119,7 @@ impl KhrDeviceGroupFn {
                     b"vkGetDeviceGroupPeerMemoryFeaturesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_group_peer_memory_features_khr
                 } else {
@@ -3539,6 +4139,7 @@ impl KhrDeviceGroupFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDeviceMaskKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_device_mask_khr
                 } else {
@@ -3563,6 +4164,7 @@ impl KhrDeviceGroupFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatchBaseKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_dispatch_base_khr
                 } else {
@@ -3583,6 +4185,7 @@ impl KhrDeviceGroupFn {
                     b"vkGetDeviceGroupPresentCapabilitiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_group_present_capabilities_khr
                 } else {
@@ -3604,6 +4207,7 @@ impl KhrDeviceGroupFn {
                     b"vkGetDeviceGroupSurfacePresentModesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_group_surface_present_modes_khr
                 } else {
@@ -3626,6 +4230,7 @@ impl KhrDeviceGroupFn {
                     b"vkGetPhysicalDevicePresentRectanglesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_present_rectangles_khr
                 } else {
@@ -3646,6 +4251,7 @@ impl KhrDeviceGroupFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAcquireNextImage2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     acquire_next_image2_khr
                 } else {
@@ -3696,6 +4302,7 @@ impl ExtValidationFlagsFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_validation_flags\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtValidationFlagsFn;
@@ -3707,6 +4314,9 @@ impl NnViSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NN_vi_surface\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateViSurfaceNN = unsafe extern "system" fn(
@@ -3722,9 +4332,25 @@ pub struct NnViSurfaceFn {
 unsafe impl Send for NnViSurfaceFn {}
 unsafe impl Sync for NnViSurfaceFn {}
 impl NnViSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_vi_surface_nn: unsafe {
@@ -3739,6 +4365,7 @@ impl NnViSurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateViSurfaceNN\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_vi_surface_nn
                 } else {
@@ -3757,6 +4384,7 @@ impl KhrShaderDrawParametersFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_draw_parameters\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct KhrShaderDrawParametersFn;
@@ -3765,6 +4393,7 @@ impl ExtShaderSubgroupBallotFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_subgroup_ballot\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtShaderSubgroupBallotFn;
@@ -3773,6 +4402,7 @@ impl ExtShaderSubgroupVoteFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_subgroup_vote\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtShaderSubgroupVoteFn;
@@ -3781,6 +4411,14 @@ impl ExtTextureCompressionAstcHdrFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_texture_compression_astc_hdr\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtTextureCompressionAstcHdrFn;
@@ -3810,6 +4448,14 @@ impl ExtAstcDecodeModeFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_astc_decode_mode\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtAstcDecodeModeFn;
@@ -3822,6 +4468,9 @@ impl ExtPipelineRobustnessFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_robustness\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtPipelineRobustnessFn;
@@ -3835,6 +4484,7 @@ impl KhrMaintenance1Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance1\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkTrimCommandPool = unsafe extern "system" fn(
@@ -3849,9 +4499,25 @@ pub struct KhrMaintenance1Fn {
 unsafe impl Send for KhrMaintenance1Fn {}
 unsafe impl Sync for KhrMaintenance1Fn {}
 impl KhrMaintenance1Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             trim_command_pool_khr: unsafe {
@@ -3868,6 +4534,7 @@ impl KhrMaintenance1Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkTrimCommandPoolKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     trim_command_pool_khr
                 } else {
@@ -3895,6 +4562,7 @@ impl KhrDeviceGroupCreationFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_device_group_creation\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkEnumeratePhysicalDeviceGroups = unsafe extern "system" fn(
@@ -3909,9 +4577,25 @@ pub struct KhrDeviceGroupCreationFn {
 unsafe impl Send for KhrDeviceGroupCreationFn {}
 unsafe impl Sync for KhrDeviceGroupCreationFn {}
 impl KhrDeviceGroupCreationFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             enumerate_physical_device_groups_khr: unsafe {
@@ -3929,6 +4613,7 @@ impl KhrDeviceGroupCreationFn {
                     b"vkEnumeratePhysicalDeviceGroupsKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     enumerate_physical_device_groups_khr
                 } else {
@@ -3952,6 +4637,9 @@ impl KhrExternalMemoryCapabilitiesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory_capabilities\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceExternalBufferProperties = unsafe extern "system" fn(
@@ -3967,9 +4655,25 @@ pub struct KhrExternalMemoryCapabilitiesFn {
 unsafe impl Send for KhrExternalMemoryCapabilitiesFn {}
 unsafe impl Sync for KhrExternalMemoryCapabilitiesFn {}
 impl KhrExternalMemoryCapabilitiesFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_external_buffer_properties_khr: unsafe {
@@ -3987,6 +4691,7 @@ impl KhrExternalMemoryCapabilitiesFn {
                     b"vkGetPhysicalDeviceExternalBufferPropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_external_buffer_properties_khr
                 } else {
@@ -4026,6 +4731,9 @@ impl KhrExternalMemoryFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory_capabilities\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrExternalMemoryFn;
@@ -4045,6 +4753,9 @@ impl KhrExternalMemoryWin32Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory_win32\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetMemoryWin32HandleKHR = unsafe extern "system" fn(
@@ -4067,9 +4778,25 @@ pub struct KhrExternalMemoryWin32Fn {
 unsafe impl Send for KhrExternalMemoryWin32Fn {}
 unsafe impl Sync for KhrExternalMemoryWin32Fn {}
 impl KhrExternalMemoryWin32Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_memory_win32_handle_khr: unsafe {
@@ -4086,6 +4813,7 @@ impl KhrExternalMemoryWin32Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryWin32HandleKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_memory_win32_handle_khr
                 } else {
@@ -4108,6 +4836,7 @@ impl KhrExternalMemoryWin32Fn {
                     b"vkGetMemoryWin32HandlePropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_memory_win32_handle_properties_khr
                 } else {
@@ -4128,6 +4857,12 @@ impl KhrExternalMemoryFdFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory_fd\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory\0")
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetMemoryFdKHR = unsafe extern "system" fn(
@@ -4150,9 +4885,25 @@ pub struct KhrExternalMemoryFdFn {
 unsafe impl Send for KhrExternalMemoryFdFn {}
 unsafe impl Sync for KhrExternalMemoryFdFn {}
 impl KhrExternalMemoryFdFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_memory_fd_khr: unsafe {
@@ -4165,6 +4916,7 @@ impl KhrExternalMemoryFdFn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetMemoryFdKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_memory_fd_khr
                 } else {
@@ -4187,6 +4939,7 @@ impl KhrExternalMemoryFdFn {
                     b"vkGetMemoryFdPropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_memory_fd_properties_khr
                 } else {
@@ -4206,6 +4959,9 @@ impl KhrWin32KeyedMutexFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_win32_keyed_mutex\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory_win32\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrWin32KeyedMutexFn;
@@ -4218,6 +4974,9 @@ impl KhrExternalSemaphoreCapabilitiesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_semaphore_capabilities\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceExternalSemaphoreProperties = unsafe extern "system" fn(
@@ -4233,9 +4992,25 @@ pub struct KhrExternalSemaphoreCapabilitiesFn {
 unsafe impl Send for KhrExternalSemaphoreCapabilitiesFn {}
 unsafe impl Sync for KhrExternalSemaphoreCapabilitiesFn {}
 impl KhrExternalSemaphoreCapabilitiesFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_external_semaphore_properties_khr: unsafe {
@@ -4253,6 +5028,7 @@ impl KhrExternalSemaphoreCapabilitiesFn {
                     b"vkGetPhysicalDeviceExternalSemaphorePropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_external_semaphore_properties_khr
                 } else {
@@ -4285,6 +5061,9 @@ impl KhrExternalSemaphoreFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_semaphore\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_semaphore_capabilities\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrExternalSemaphoreFn;
@@ -4301,6 +5080,9 @@ impl KhrExternalSemaphoreWin32Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_semaphore_win32\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_semaphore\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkImportSemaphoreWin32HandleKHR = unsafe extern "system" fn(
@@ -4321,9 +5103,25 @@ pub struct KhrExternalSemaphoreWin32Fn {
 unsafe impl Send for KhrExternalSemaphoreWin32Fn {}
 unsafe impl Sync for KhrExternalSemaphoreWin32Fn {}
 impl KhrExternalSemaphoreWin32Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             import_semaphore_win32_handle_khr: unsafe {
@@ -4340,6 +5138,7 @@ impl KhrExternalSemaphoreWin32Fn {
                     b"vkImportSemaphoreWin32HandleKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     import_semaphore_win32_handle_khr
                 } else {
@@ -4361,6 +5160,7 @@ impl KhrExternalSemaphoreWin32Fn {
                     b"vkGetSemaphoreWin32HandleKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_semaphore_win32_handle_khr
                 } else {
@@ -4382,6 +5182,12 @@ impl KhrExternalSemaphoreFdFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_semaphore_fd\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_semaphore\0")
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkImportSemaphoreFdKHR = unsafe extern "system" fn(
@@ -4402,9 +5208,25 @@ pub struct KhrExternalSemaphoreFdFn {
 unsafe impl Send for KhrExternalSemaphoreFdFn {}
 unsafe impl Sync for KhrExternalSemaphoreFdFn {}
 impl KhrExternalSemaphoreFdFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             import_semaphore_fd_khr: unsafe {
@@ -4420,6 +5242,7 @@ impl KhrExternalSemaphoreFdFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkImportSemaphoreFdKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     import_semaphore_fd_khr
                 } else {
@@ -4437,6 +5260,7 @@ impl KhrExternalSemaphoreFdFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetSemaphoreFdKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_semaphore_fd_khr
                 } else {
@@ -4455,6 +5279,9 @@ impl KhrPushDescriptorFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_push_descriptor\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdPushDescriptorSetKHR = unsafe extern "system" fn(
@@ -4481,9 +5308,25 @@ pub struct KhrPushDescriptorFn {
 unsafe impl Send for KhrPushDescriptorFn {}
 unsafe impl Sync for KhrPushDescriptorFn {}
 impl KhrPushDescriptorFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_push_descriptor_set_khr: unsafe {
@@ -4503,6 +5346,7 @@ impl KhrPushDescriptorFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdPushDescriptorSetKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_push_descriptor_set_khr
                 } else {
@@ -4526,6 +5370,7 @@ impl KhrPushDescriptorFn {
                     b"vkCmdPushDescriptorSetWithTemplateKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_push_descriptor_set_with_template_khr
                 } else {
@@ -4554,6 +5399,9 @@ impl ExtConditionalRenderingFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_conditional_rendering\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdBeginConditionalRenderingEXT = unsafe extern "system" fn(
@@ -4571,9 +5419,25 @@ pub struct ExtConditionalRenderingFn {
 unsafe impl Send for ExtConditionalRenderingFn {}
 unsafe impl Sync for ExtConditionalRenderingFn {}
 impl ExtConditionalRenderingFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_begin_conditional_rendering_ext: unsafe {
@@ -4590,6 +5454,7 @@ impl ExtConditionalRenderingFn {
                     b"vkCmdBeginConditionalRenderingEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_conditional_rendering_ext
                 } else {
@@ -4609,6 +5474,7 @@ impl ExtConditionalRenderingFn {
                     b"vkCmdEndConditionalRenderingEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_conditional_rendering_ext
                 } else {
@@ -4643,6 +5509,9 @@ impl KhrShaderFloat16Int8Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_float16_int8\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrShaderFloat16Int8Fn;
@@ -4657,6 +5526,18 @@ impl Khr16bitStorageFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_16bit_storage\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_storage_buffer_storage_class\0",
+            )
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct Khr16bitStorageFn;
@@ -4669,6 +5550,9 @@ impl KhrIncrementalPresentFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_incremental_present\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrIncrementalPresentFn;
@@ -4681,6 +5565,7 @@ impl KhrDescriptorUpdateTemplateFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_descriptor_update_template\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateDescriptorUpdateTemplate = unsafe extern "system" fn(
@@ -4713,9 +5598,25 @@ pub struct KhrDescriptorUpdateTemplateFn {
 unsafe impl Send for KhrDescriptorUpdateTemplateFn {}
 unsafe impl Sync for KhrDescriptorUpdateTemplateFn {}
 impl KhrDescriptorUpdateTemplateFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_descriptor_update_template_khr: unsafe {
@@ -4734,6 +5635,7 @@ impl KhrDescriptorUpdateTemplateFn {
                     b"vkCreateDescriptorUpdateTemplateKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_descriptor_update_template_khr
                 } else {
@@ -4755,6 +5657,7 @@ impl KhrDescriptorUpdateTemplateFn {
                     b"vkDestroyDescriptorUpdateTemplateKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_descriptor_update_template_khr
                 } else {
@@ -4777,6 +5680,7 @@ impl KhrDescriptorUpdateTemplateFn {
                     b"vkUpdateDescriptorSetWithTemplateKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     update_descriptor_set_with_template_khr
                 } else {
@@ -4800,6 +5704,7 @@ impl KhrDescriptorUpdateTemplateFn {
                     b"vkCmdPushDescriptorSetWithTemplateKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_push_descriptor_set_with_template_khr
                 } else {
@@ -4830,6 +5735,7 @@ impl NvClipSpaceWScalingFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_clip_space_w_scaling\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetViewportWScalingNV = unsafe extern "system" fn(
@@ -4845,9 +5751,25 @@ pub struct NvClipSpaceWScalingFn {
 unsafe impl Send for NvClipSpaceWScalingFn {}
 unsafe impl Sync for NvClipSpaceWScalingFn {}
 impl NvClipSpaceWScalingFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_viewport_w_scaling_nv: unsafe {
@@ -4866,6 +5788,7 @@ impl NvClipSpaceWScalingFn {
                     b"vkCmdSetViewportWScalingNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_viewport_w_scaling_nv
                 } else {
@@ -4887,6 +5810,9 @@ impl ExtDirectModeDisplayFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_direct_mode_display\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_display\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkReleaseDisplayEXT =
@@ -4898,9 +5824,25 @@ pub struct ExtDirectModeDisplayFn {
 unsafe impl Send for ExtDirectModeDisplayFn {}
 unsafe impl Sync for ExtDirectModeDisplayFn {}
 impl ExtDirectModeDisplayFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             release_display_ext: unsafe {
@@ -4913,6 +5855,7 @@ impl ExtDirectModeDisplayFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkReleaseDisplayEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     release_display_ext
                 } else {
@@ -4927,6 +5870,9 @@ impl ExtAcquireXlibDisplayFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_acquire_xlib_display\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_direct_mode_display\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkAcquireXlibDisplayEXT = unsafe extern "system" fn(
@@ -4949,9 +5895,25 @@ pub struct ExtAcquireXlibDisplayFn {
 unsafe impl Send for ExtAcquireXlibDisplayFn {}
 unsafe impl Sync for ExtAcquireXlibDisplayFn {}
 impl ExtAcquireXlibDisplayFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             acquire_xlib_display_ext: unsafe {
@@ -4968,6 +5930,7 @@ impl ExtAcquireXlibDisplayFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAcquireXlibDisplayEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     acquire_xlib_display_ext
                 } else {
@@ -4990,6 +5953,7 @@ impl ExtAcquireXlibDisplayFn {
                     b"vkGetRandROutputDisplayEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_rand_r_output_display_ext
                 } else {
@@ -5004,6 +5968,9 @@ impl ExtDisplaySurfaceCounterFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_display_surface_counter\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_display\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceSurfaceCapabilities2EXT = unsafe extern "system" fn(
@@ -5019,9 +5986,25 @@ pub struct ExtDisplaySurfaceCounterFn {
 unsafe impl Send for ExtDisplaySurfaceCounterFn {}
 unsafe impl Sync for ExtDisplaySurfaceCounterFn {}
 impl ExtDisplaySurfaceCounterFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_surface_capabilities2_ext: unsafe {
@@ -5039,6 +6022,7 @@ impl ExtDisplaySurfaceCounterFn {
                     b"vkGetPhysicalDeviceSurfaceCapabilities2EXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_surface_capabilities2_ext
                 } else {
@@ -5056,6 +6040,14 @@ impl ExtDisplayControlFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_display_control\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_display_surface_counter\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkDisplayPowerControlEXT = unsafe extern "system" fn(
@@ -5095,9 +6087,25 @@ pub struct ExtDisplayControlFn {
 unsafe impl Send for ExtDisplayControlFn {}
 unsafe impl Sync for ExtDisplayControlFn {}
 impl ExtDisplayControlFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             display_power_control_ext: unsafe {
@@ -5114,6 +6122,7 @@ impl ExtDisplayControlFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDisplayPowerControlEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     display_power_control_ext
                 } else {
@@ -5135,6 +6144,7 @@ impl ExtDisplayControlFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkRegisterDeviceEventEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     register_device_event_ext
                 } else {
@@ -5157,6 +6167,7 @@ impl ExtDisplayControlFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkRegisterDisplayEventEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     register_display_event_ext
                 } else {
@@ -5178,6 +6189,7 @@ impl ExtDisplayControlFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainCounterEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_swapchain_counter_ext
                 } else {
@@ -5198,6 +6210,9 @@ impl GoogleDisplayTimingFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_GOOGLE_display_timing\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetRefreshCycleDurationGOOGLE = unsafe extern "system" fn(
@@ -5220,9 +6235,25 @@ pub struct GoogleDisplayTimingFn {
 unsafe impl Send for GoogleDisplayTimingFn {}
 unsafe impl Sync for GoogleDisplayTimingFn {}
 impl GoogleDisplayTimingFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_refresh_cycle_duration_google: unsafe {
@@ -5240,6 +6271,7 @@ impl GoogleDisplayTimingFn {
                     b"vkGetRefreshCycleDurationGOOGLE\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_refresh_cycle_duration_google
                 } else {
@@ -5262,6 +6294,7 @@ impl GoogleDisplayTimingFn {
                     b"vkGetPastPresentationTimingGOOGLE\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_past_presentation_timing_google
                 } else {
@@ -5280,6 +6313,7 @@ impl NvSampleMaskOverrideCoverageFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_sample_mask_override_coverage\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct NvSampleMaskOverrideCoverageFn;
@@ -5288,6 +6322,7 @@ impl NvGeometryShaderPassthroughFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_geometry_shader_passthrough\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct NvGeometryShaderPassthroughFn;
@@ -5295,6 +6330,7 @@ impl NvViewportArray2Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_viewport_array2\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct NvViewportArray2Fn;
@@ -5303,6 +6339,9 @@ impl NvxMultiviewPerViewAttributesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NVX_multiview_per_view_attributes\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_multiview\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvxMultiviewPerViewAttributesFn;
@@ -5320,6 +6359,7 @@ impl NvViewportSwizzleFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_viewport_swizzle\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct NvViewportSwizzleFn;
@@ -5331,6 +6371,14 @@ impl ExtDiscardRectanglesFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_discard_rectangles\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetDiscardRectangleEXT = unsafe extern "system" fn(
@@ -5356,9 +6404,25 @@ pub struct ExtDiscardRectanglesFn {
 unsafe impl Send for ExtDiscardRectanglesFn {}
 unsafe impl Sync for ExtDiscardRectanglesFn {}
 impl ExtDiscardRectanglesFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_discard_rectangle_ext: unsafe {
@@ -5377,6 +6441,7 @@ impl ExtDiscardRectanglesFn {
                     b"vkCmdSetDiscardRectangleEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_discard_rectangle_ext
                 } else {
@@ -5397,6 +6462,7 @@ impl ExtDiscardRectanglesFn {
                     b"vkCmdSetDiscardRectangleEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_discard_rectangle_enable_ext
                 } else {
@@ -5417,6 +6483,7 @@ impl ExtDiscardRectanglesFn {
                     b"vkCmdSetDiscardRectangleModeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_discard_rectangle_mode_ext
                 } else {
@@ -5442,6 +6509,14 @@ impl ExtConservativeRasterizationFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_conservative_rasterization\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtConservativeRasterizationFn;
@@ -5454,6 +6529,14 @@ impl ExtDepthClipEnableFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_depth_clip_enable\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtDepthClipEnableFn;
@@ -5467,6 +6550,9 @@ impl ExtSwapchainColorspaceFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_swapchain_colorspace\0")
     };
     pub const SPEC_VERSION: u32 = 4u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtSwapchainColorspaceFn;
@@ -5491,6 +6577,9 @@ impl ExtHdrMetadataFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_hdr_metadata\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkSetHdrMetadataEXT = unsafe extern "system" fn(
@@ -5506,9 +6595,25 @@ pub struct ExtHdrMetadataFn {
 unsafe impl Send for ExtHdrMetadataFn {}
 unsafe impl Sync for ExtHdrMetadataFn {}
 impl ExtHdrMetadataFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             set_hdr_metadata_ext: unsafe {
@@ -5523,6 +6628,7 @@ impl ExtHdrMetadataFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkSetHdrMetadataEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     set_hdr_metadata_ext
                 } else {
@@ -5541,6 +6647,19 @@ impl KhrImagelessFramebufferFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_imageless_framebuffer\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance2\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_image_format_list\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct KhrImagelessFramebufferFn;
@@ -5561,6 +6680,14 @@ impl KhrCreateRenderpass2Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_create_renderpass2\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_multiview\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance2\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateRenderPass2 = unsafe extern "system" fn(
@@ -5596,9 +6723,25 @@ pub struct KhrCreateRenderpass2Fn {
 unsafe impl Send for KhrCreateRenderpass2Fn {}
 unsafe impl Sync for KhrCreateRenderpass2Fn {}
 impl KhrCreateRenderpass2Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_render_pass2_khr: unsafe {
@@ -5616,6 +6759,7 @@ impl KhrCreateRenderpass2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateRenderPass2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_render_pass2_khr
                 } else {
@@ -5636,6 +6780,7 @@ impl KhrCreateRenderpass2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRenderPass2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_render_pass2_khr
                 } else {
@@ -5656,6 +6801,7 @@ impl KhrCreateRenderpass2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdNextSubpass2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_next_subpass2_khr
                 } else {
@@ -5675,6 +6821,7 @@ impl KhrCreateRenderpass2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRenderPass2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_render_pass2_khr
                 } else {
@@ -5699,6 +6846,22 @@ impl KhrSharedPresentableImageFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shared_presentable_image\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_surface_capabilities2\0")
+        }),
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                    b"VK_KHR_get_physical_device_properties2\0",
+                )
+            }),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        ]),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetSwapchainStatusKHR =
@@ -5710,9 +6873,25 @@ pub struct KhrSharedPresentableImageFn {
 unsafe impl Send for KhrSharedPresentableImageFn {}
 unsafe impl Sync for KhrSharedPresentableImageFn {}
 impl KhrSharedPresentableImageFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_swapchain_status_khr: unsafe {
@@ -5728,6 +6907,7 @@ impl KhrSharedPresentableImageFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetSwapchainStatusKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_swapchain_status_khr
                 } else {
@@ -5755,6 +6935,9 @@ impl KhrExternalFenceCapabilitiesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_fence_capabilities\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceExternalFenceProperties = unsafe extern "system" fn(
@@ -5770,9 +6953,25 @@ pub struct KhrExternalFenceCapabilitiesFn {
 unsafe impl Send for KhrExternalFenceCapabilitiesFn {}
 unsafe impl Sync for KhrExternalFenceCapabilitiesFn {}
 impl KhrExternalFenceCapabilitiesFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_external_fence_properties_khr: unsafe {
@@ -5790,6 +6989,7 @@ impl KhrExternalFenceCapabilitiesFn {
                     b"vkGetPhysicalDeviceExternalFencePropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_external_fence_properties_khr
                 } else {
@@ -5821,6 +7021,9 @@ impl KhrExternalFenceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_fence\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_fence_capabilities\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrExternalFenceFn;
@@ -5837,6 +7040,9 @@ impl KhrExternalFenceWin32Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_fence_win32\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_fence\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkImportFenceWin32HandleKHR = unsafe extern "system" fn(
@@ -5857,9 +7063,25 @@ pub struct KhrExternalFenceWin32Fn {
 unsafe impl Send for KhrExternalFenceWin32Fn {}
 unsafe impl Sync for KhrExternalFenceWin32Fn {}
 impl KhrExternalFenceWin32Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             import_fence_win32_handle_khr: unsafe {
@@ -5876,6 +7098,7 @@ impl KhrExternalFenceWin32Fn {
                     b"vkImportFenceWin32HandleKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     import_fence_win32_handle_khr
                 } else {
@@ -5896,6 +7119,7 @@ impl KhrExternalFenceWin32Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetFenceWin32HandleKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_fence_win32_handle_khr
                 } else {
@@ -5915,6 +7139,12 @@ impl KhrExternalFenceFdFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_fence_fd\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_fence\0")
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkImportFenceFdKHR = unsafe extern "system" fn(
@@ -5935,9 +7165,25 @@ pub struct KhrExternalFenceFdFn {
 unsafe impl Send for KhrExternalFenceFdFn {}
 unsafe impl Sync for KhrExternalFenceFdFn {}
 impl KhrExternalFenceFdFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             import_fence_fd_khr: unsafe {
@@ -5950,6 +7196,7 @@ impl KhrExternalFenceFdFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkImportFenceFdKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     import_fence_fd_khr
                 } else {
@@ -5966,6 +7213,7 @@ impl KhrExternalFenceFdFn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetFenceFdKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_fence_fd_khr
                 } else {
@@ -5984,6 +7232,14 @@ impl KhrPerformanceQueryFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_performance_query\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR =
@@ -6018,9 +7274,25 @@ pub struct KhrPerformanceQueryFn {
 unsafe impl Send for KhrPerformanceQueryFn {}
 unsafe impl Sync for KhrPerformanceQueryFn {}
 impl KhrPerformanceQueryFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             enumerate_physical_device_queue_family_performance_query_counters_khr: unsafe {
@@ -6042,6 +7314,7 @@ impl KhrPerformanceQueryFn {
                     b"vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     enumerate_physical_device_queue_family_performance_query_counters_khr
                 } else {
@@ -6063,6 +7336,7 @@ impl KhrPerformanceQueryFn {
                     b"vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_queue_family_performance_query_passes_khr
                 } else {
@@ -6082,6 +7356,7 @@ impl KhrPerformanceQueryFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAcquireProfilingLockKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     acquire_profiling_lock_khr
                 } else {
@@ -6098,6 +7373,7 @@ impl KhrPerformanceQueryFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkReleaseProfilingLockKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     release_profiling_lock_khr
                 } else {
@@ -6125,6 +7401,7 @@ impl KhrMaintenance2Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance2\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct KhrMaintenance2Fn;
@@ -6165,6 +7442,9 @@ impl KhrGetSurfaceCapabilities2Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_surface_capabilities2\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR = unsafe extern "system" fn(
@@ -6188,9 +7468,25 @@ pub struct KhrGetSurfaceCapabilities2Fn {
 unsafe impl Send for KhrGetSurfaceCapabilities2Fn {}
 unsafe impl Sync for KhrGetSurfaceCapabilities2Fn {}
 impl KhrGetSurfaceCapabilities2Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_surface_capabilities2_khr: unsafe {
@@ -6208,6 +7504,7 @@ impl KhrGetSurfaceCapabilities2Fn {
                     b"vkGetPhysicalDeviceSurfaceCapabilities2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_surface_capabilities2_khr
                 } else {
@@ -6230,6 +7527,7 @@ impl KhrGetSurfaceCapabilities2Fn {
                     b"vkGetPhysicalDeviceSurfaceFormats2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_surface_formats2_khr
                 } else {
@@ -6249,6 +7547,18 @@ impl KhrVariablePointersFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_variable_pointers\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_storage_buffer_storage_class\0",
+            )
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct KhrVariablePointersFn;
@@ -6264,6 +7574,9 @@ impl KhrGetDisplayProperties2Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_display_properties2\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_display\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceDisplayProperties2KHR = unsafe extern "system" fn(
@@ -6301,9 +7614,25 @@ pub struct KhrGetDisplayProperties2Fn {
 unsafe impl Send for KhrGetDisplayProperties2Fn {}
 unsafe impl Sync for KhrGetDisplayProperties2Fn {}
 impl KhrGetDisplayProperties2Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_display_properties2_khr: unsafe {
@@ -6321,6 +7650,7 @@ impl KhrGetDisplayProperties2Fn {
                     b"vkGetPhysicalDeviceDisplayProperties2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_display_properties2_khr
                 } else {
@@ -6342,6 +7672,7 @@ impl KhrGetDisplayProperties2Fn {
                     b"vkGetPhysicalDeviceDisplayPlaneProperties2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_display_plane_properties2_khr
                 } else {
@@ -6364,6 +7695,7 @@ impl KhrGetDisplayProperties2Fn {
                     b"vkGetDisplayModeProperties2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_display_mode_properties2_khr
                 } else {
@@ -6385,6 +7717,7 @@ impl KhrGetDisplayProperties2Fn {
                     b"vkGetDisplayPlaneCapabilities2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_display_plane_capabilities2_khr
                 } else {
@@ -6406,6 +7739,9 @@ impl MvkIosSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_MVK_ios_surface\0") };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateIOSSurfaceMVK = unsafe extern "system" fn(
@@ -6421,9 +7757,25 @@ pub struct MvkIosSurfaceFn {
 unsafe impl Send for MvkIosSurfaceFn {}
 unsafe impl Sync for MvkIosSurfaceFn {}
 impl MvkIosSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_ios_surface_mvk: unsafe {
@@ -6441,6 +7793,7 @@ impl MvkIosSurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateIOSSurfaceMVK\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_ios_surface_mvk
                 } else {
@@ -6458,6 +7811,9 @@ impl MvkMacosSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_MVK_macos_surface\0") };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateMacOSSurfaceMVK = unsafe extern "system" fn(
@@ -6473,9 +7829,25 @@ pub struct MvkMacosSurfaceFn {
 unsafe impl Send for MvkMacosSurfaceFn {}
 unsafe impl Sync for MvkMacosSurfaceFn {}
 impl MvkMacosSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_mac_os_surface_mvk: unsafe {
@@ -6493,6 +7865,7 @@ impl MvkMacosSurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateMacOSSurfaceMVK\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_mac_os_surface_mvk
                 } else {
@@ -6511,6 +7884,9 @@ impl ExtExternalMemoryDmaBufFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_external_memory_dma_buf\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory_fd\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtExternalMemoryDmaBufFn;
@@ -6523,6 +7899,12 @@ impl ExtQueueFamilyForeignFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_queue_family_foreign\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory\0")
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtQueueFamilyForeignFn;
@@ -6531,6 +7913,9 @@ impl KhrDedicatedAllocationFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_dedicated_allocation\0")
     };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_memory_requirements2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrDedicatedAllocationFn;
@@ -6543,6 +7928,7 @@ impl ExtDebugUtilsFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_debug_utils\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkSetDebugUtilsObjectNameEXT = unsafe extern "system" fn(
@@ -6611,9 +7997,25 @@ pub struct ExtDebugUtilsFn {
 unsafe impl Send for ExtDebugUtilsFn {}
 unsafe impl Sync for ExtDebugUtilsFn {}
 impl ExtDebugUtilsFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             set_debug_utils_object_name_ext: unsafe {
@@ -6630,6 +8032,7 @@ impl ExtDebugUtilsFn {
                     b"vkSetDebugUtilsObjectNameEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     set_debug_utils_object_name_ext
                 } else {
@@ -6650,6 +8053,7 @@ impl ExtDebugUtilsFn {
                     b"vkSetDebugUtilsObjectTagEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     set_debug_utils_object_tag_ext
                 } else {
@@ -6670,6 +8074,7 @@ impl ExtDebugUtilsFn {
                     b"vkQueueBeginDebugUtilsLabelEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_begin_debug_utils_label_ext
                 } else {
@@ -6687,6 +8092,7 @@ impl ExtDebugUtilsFn {
                     b"vkQueueEndDebugUtilsLabelEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_end_debug_utils_label_ext
                 } else {
@@ -6707,6 +8113,7 @@ impl ExtDebugUtilsFn {
                     b"vkQueueInsertDebugUtilsLabelEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_insert_debug_utils_label_ext
                 } else {
@@ -6727,6 +8134,7 @@ impl ExtDebugUtilsFn {
                     b"vkCmdBeginDebugUtilsLabelEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_debug_utils_label_ext
                 } else {
@@ -6746,6 +8154,7 @@ impl ExtDebugUtilsFn {
                     b"vkCmdEndDebugUtilsLabelEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_debug_utils_label_ext
                 } else {
@@ -6766,6 +8175,7 @@ impl ExtDebugUtilsFn {
                     b"vkCmdInsertDebugUtilsLabelEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_insert_debug_utils_label_ext
                 } else {
@@ -6788,6 +8198,7 @@ impl ExtDebugUtilsFn {
                     b"vkCreateDebugUtilsMessengerEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_debug_utils_messenger_ext
                 } else {
@@ -6809,6 +8220,7 @@ impl ExtDebugUtilsFn {
                     b"vkDestroyDebugUtilsMessengerEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_debug_utils_messenger_ext
                 } else {
@@ -6831,6 +8243,7 @@ impl ExtDebugUtilsFn {
                     b"vkSubmitDebugUtilsMessageEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     submit_debug_utils_message_ext
                 } else {
@@ -6859,6 +8272,20 @@ impl AndroidExternalMemoryAndroidHardwareBufferFn {
         )
     };
     pub const SPEC_VERSION: u32 = 5u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_sampler_ycbcr_conversion\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_queue_family_foreign\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_dedicated_allocation\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetAndroidHardwareBufferPropertiesANDROID = unsafe extern "system" fn(
@@ -6881,9 +8308,25 @@ pub struct AndroidExternalMemoryAndroidHardwareBufferFn {
 unsafe impl Send for AndroidExternalMemoryAndroidHardwareBufferFn {}
 unsafe impl Sync for AndroidExternalMemoryAndroidHardwareBufferFn {}
 impl AndroidExternalMemoryAndroidHardwareBufferFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_android_hardware_buffer_properties_android: unsafe {
@@ -6901,6 +8344,7 @@ impl AndroidExternalMemoryAndroidHardwareBufferFn {
                     b"vkGetAndroidHardwareBufferPropertiesANDROID\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_android_hardware_buffer_properties_android
                 } else {
@@ -6922,6 +8366,7 @@ impl AndroidExternalMemoryAndroidHardwareBufferFn {
                     b"vkGetMemoryAndroidHardwareBufferANDROID\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_memory_android_hardware_buffer_android
                 } else {
@@ -6950,6 +8395,9 @@ impl ExtSamplerFilterMinmaxFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_sampler_filter_minmax\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtSamplerFilterMinmaxFn;
@@ -6975,6 +8423,7 @@ impl KhrStorageBufferStorageClassFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_storage_buffer_storage_class\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct KhrStorageBufferStorageClassFn;
@@ -6982,6 +8431,7 @@ impl AmdGpuShaderInt16Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_gpu_shader_int16\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdGpuShaderInt16Fn;
@@ -6990,6 +8440,7 @@ impl AmdMixedAttachmentSamplesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_mixed_attachment_samples\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdMixedAttachmentSamplesFn;
@@ -6998,6 +8449,7 @@ impl AmdShaderFragmentMaskFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_shader_fragment_mask\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdShaderFragmentMaskFn;
@@ -7006,6 +8458,16 @@ impl ExtInlineUniformBlockFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_inline_uniform_block\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance1\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtInlineUniformBlockFn;
@@ -7029,6 +8491,7 @@ impl ExtShaderStencilExportFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_stencil_export\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtShaderStencilExportFn;
@@ -7036,6 +8499,14 @@ impl ExtSampleLocationsFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_sample_locations\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetSampleLocationsEXT = unsafe extern "system" fn(
@@ -7057,9 +8528,25 @@ pub struct ExtSampleLocationsFn {
 unsafe impl Send for ExtSampleLocationsFn {}
 unsafe impl Sync for ExtSampleLocationsFn {}
 impl ExtSampleLocationsFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_sample_locations_ext: unsafe {
@@ -7076,6 +8563,7 @@ impl ExtSampleLocationsFn {
                     b"vkCmdSetSampleLocationsEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_sample_locations_ext
                 } else {
@@ -7097,6 +8585,7 @@ impl ExtSampleLocationsFn {
                     b"vkGetPhysicalDeviceMultisamplePropertiesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_multisample_properties_ext
                 } else {
@@ -7127,6 +8616,7 @@ impl KhrRelaxedBlockLayoutFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_relaxed_block_layout\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct KhrRelaxedBlockLayoutFn;
@@ -7135,6 +8625,7 @@ impl KhrGetMemoryRequirements2Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_memory_requirements2\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetImageMemoryRequirements2 = unsafe extern "system" fn(
@@ -7164,9 +8655,25 @@ pub struct KhrGetMemoryRequirements2Fn {
 unsafe impl Send for KhrGetMemoryRequirements2Fn {}
 unsafe impl Sync for KhrGetMemoryRequirements2Fn {}
 impl KhrGetMemoryRequirements2Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_image_memory_requirements2_khr: unsafe {
@@ -7184,6 +8691,7 @@ impl KhrGetMemoryRequirements2Fn {
                     b"vkGetImageMemoryRequirements2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_memory_requirements2_khr
                 } else {
@@ -7205,6 +8713,7 @@ impl KhrGetMemoryRequirements2Fn {
                     b"vkGetBufferMemoryRequirements2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_buffer_memory_requirements2_khr
                 } else {
@@ -7227,6 +8736,7 @@ impl KhrGetMemoryRequirements2Fn {
                     b"vkGetImageSparseMemoryRequirements2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_sparse_memory_requirements2_khr
                 } else {
@@ -7250,6 +8760,7 @@ impl KhrImageFormatListFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_image_format_list\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct KhrImageFormatListFn;
@@ -7262,6 +8773,14 @@ impl ExtBlendOperationAdvancedFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_blend_operation_advanced\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtBlendOperationAdvancedFn;
@@ -7329,6 +8848,7 @@ impl NvFragmentCoverageToColorFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_fragment_coverage_to_color\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct NvFragmentCoverageToColorFn;
@@ -7341,6 +8861,18 @@ impl KhrAccelerationStructureFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_acceleration_structure\0")
     };
     pub const SPEC_VERSION: u32 = 13u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_descriptor_indexing\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_buffer_device_address\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_deferred_host_operations\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateAccelerationStructureKHR = unsafe extern "system" fn(
@@ -7480,9 +9012,25 @@ pub struct KhrAccelerationStructureFn {
 unsafe impl Send for KhrAccelerationStructureFn {}
 unsafe impl Sync for KhrAccelerationStructureFn {}
 impl KhrAccelerationStructureFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_acceleration_structure_khr: unsafe {
@@ -7501,6 +9049,7 @@ impl KhrAccelerationStructureFn {
                     b"vkCreateAccelerationStructureKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_acceleration_structure_khr
                 } else {
@@ -7522,6 +9071,7 @@ impl KhrAccelerationStructureFn {
                     b"vkDestroyAccelerationStructureKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_acceleration_structure_khr
                 } else {
@@ -7544,6 +9094,7 @@ impl KhrAccelerationStructureFn {
                     b"vkCmdBuildAccelerationStructuresKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_build_acceleration_structures_khr
                 } else {
@@ -7568,6 +9119,7 @@ impl KhrAccelerationStructureFn {
                     b"vkCmdBuildAccelerationStructuresIndirectKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_build_acceleration_structures_indirect_khr
                 } else {
@@ -7591,6 +9143,7 @@ impl KhrAccelerationStructureFn {
                     b"vkBuildAccelerationStructuresKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     build_acceleration_structures_khr
                 } else {
@@ -7612,6 +9165,7 @@ impl KhrAccelerationStructureFn {
                     b"vkCopyAccelerationStructureKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     copy_acceleration_structure_khr
                 } else {
@@ -7633,6 +9187,7 @@ impl KhrAccelerationStructureFn {
                     b"vkCopyAccelerationStructureToMemoryKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     copy_acceleration_structure_to_memory_khr
                 } else {
@@ -7654,6 +9209,7 @@ impl KhrAccelerationStructureFn {
                     b"vkCopyMemoryToAccelerationStructureKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     copy_memory_to_acceleration_structure_khr
                 } else {
@@ -7679,6 +9235,7 @@ impl KhrAccelerationStructureFn {
                     b"vkWriteAccelerationStructuresPropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     write_acceleration_structures_properties_khr
                 } else {
@@ -7699,6 +9256,7 @@ impl KhrAccelerationStructureFn {
                     b"vkCmdCopyAccelerationStructureKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_acceleration_structure_khr
                 } else {
@@ -7719,6 +9277,7 @@ impl KhrAccelerationStructureFn {
                     b"vkCmdCopyAccelerationStructureToMemoryKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_acceleration_structure_to_memory_khr
                 } else {
@@ -7739,6 +9298,7 @@ impl KhrAccelerationStructureFn {
                     b"vkCmdCopyMemoryToAccelerationStructureKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_memory_to_acceleration_structure_khr
                 } else {
@@ -7759,6 +9319,7 @@ impl KhrAccelerationStructureFn {
                     b"vkGetAccelerationStructureDeviceAddressKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_acceleration_structure_device_address_khr
                 } else {
@@ -7783,6 +9344,7 @@ impl KhrAccelerationStructureFn {
                     b"vkCmdWriteAccelerationStructuresPropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_write_acceleration_structures_properties_khr
                 } else {
@@ -7804,6 +9366,7 @@ impl KhrAccelerationStructureFn {
                     b"vkGetDeviceAccelerationStructureCompatibilityKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_acceleration_structure_compatibility_khr
                 } else {
@@ -7827,6 +9390,7 @@ impl KhrAccelerationStructureFn {
                     b"vkGetAccelerationStructureBuildSizesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_acceleration_structure_build_sizes_khr
                 } else {
@@ -7905,6 +9469,14 @@ impl KhrRayTracingPipelineFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_pipeline\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_spirv_1_4\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_acceleration_structure\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdTraceRaysKHR = unsafe extern "system" fn(
@@ -7979,9 +9551,25 @@ pub struct KhrRayTracingPipelineFn {
 unsafe impl Send for KhrRayTracingPipelineFn {}
 unsafe impl Sync for KhrRayTracingPipelineFn {}
 impl KhrRayTracingPipelineFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_trace_rays_khr: unsafe {
@@ -7999,6 +9587,7 @@ impl KhrRayTracingPipelineFn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_trace_rays_khr
                 } else {
@@ -8024,6 +9613,7 @@ impl KhrRayTracingPipelineFn {
                     b"vkCreateRayTracingPipelinesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_ray_tracing_pipelines_khr
                 } else {
@@ -8048,6 +9638,7 @@ impl KhrRayTracingPipelineFn {
                     b"vkGetRayTracingShaderGroupHandlesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_ray_tracing_shader_group_handles_khr
                 } else {
@@ -8072,6 +9663,7 @@ impl KhrRayTracingPipelineFn {
                     b"vkGetRayTracingCaptureReplayShaderGroupHandlesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_ray_tracing_capture_replay_shader_group_handles_khr
                 } else {
@@ -8095,6 +9687,7 @@ impl KhrRayTracingPipelineFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysIndirectKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_trace_rays_indirect_khr
                 } else {
@@ -8117,6 +9710,7 @@ impl KhrRayTracingPipelineFn {
                     b"vkGetRayTracingShaderGroupStackSizeKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_ray_tracing_shader_group_stack_size_khr
                 } else {
@@ -8137,6 +9731,7 @@ impl KhrRayTracingPipelineFn {
                     b"vkCmdSetRayTracingPipelineStackSizeKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_ray_tracing_pipeline_stack_size_khr
                 } else {
@@ -8194,6 +9789,14 @@ impl KhrRayQueryFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_query\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_spirv_1_4\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_acceleration_structure\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct KhrRayQueryFn;
@@ -8206,6 +9809,7 @@ impl NvFramebufferMixedSamplesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_framebuffer_mixed_samples\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct NvFramebufferMixedSamplesFn;
@@ -8217,6 +9821,7 @@ impl NvFillRectangleFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_fill_rectangle\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct NvFillRectangleFn;
@@ -8228,6 +9833,7 @@ impl NvShaderSmBuiltinsFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_shader_sm_builtins\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Version(crate::vk::API_VERSION_1_1);
 }
 #[derive(Clone)]
 pub struct NvShaderSmBuiltinsFn;
@@ -8240,6 +9846,7 @@ impl ExtPostDepthCoverageFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_post_depth_coverage\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtPostDepthCoverageFn;
@@ -8248,6 +9855,22 @@ impl KhrSamplerYcbcrConversionFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_sampler_ycbcr_conversion\0")
     };
     pub const SPEC_VERSION: u32 = 14u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance1\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_bind_memory2\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_memory_requirements2\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateSamplerYcbcrConversion = unsafe extern "system" fn(
@@ -8270,9 +9893,25 @@ pub struct KhrSamplerYcbcrConversionFn {
 unsafe impl Send for KhrSamplerYcbcrConversionFn {}
 unsafe impl Sync for KhrSamplerYcbcrConversionFn {}
 impl KhrSamplerYcbcrConversionFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_sampler_ycbcr_conversion_khr: unsafe {
@@ -8291,6 +9930,7 @@ impl KhrSamplerYcbcrConversionFn {
                     b"vkCreateSamplerYcbcrConversionKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_sampler_ycbcr_conversion_khr
                 } else {
@@ -8312,6 +9952,7 @@ impl KhrSamplerYcbcrConversionFn {
                     b"vkDestroySamplerYcbcrConversionKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_sampler_ycbcr_conversion_khr
                 } else {
@@ -8441,6 +10082,7 @@ impl KhrBindMemory2Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_bind_memory2\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkBindBufferMemory2 = unsafe extern "system" fn(
@@ -8462,9 +10104,25 @@ pub struct KhrBindMemory2Fn {
 unsafe impl Send for KhrBindMemory2Fn {}
 unsafe impl Sync for KhrBindMemory2Fn {}
 impl KhrBindMemory2Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             bind_buffer_memory2_khr: unsafe {
@@ -8481,6 +10139,7 @@ impl KhrBindMemory2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkBindBufferMemory2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     bind_buffer_memory2_khr
                 } else {
@@ -8501,6 +10160,7 @@ impl KhrBindMemory2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkBindImageMemory2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     bind_image_memory2_khr
                 } else {
@@ -8524,6 +10184,32 @@ impl ExtImageDrmFormatModifierFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_drm_format_modifier\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::All(&[
+                crate::vk::Depends::Extension(unsafe {
+                    ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_bind_memory2\0")
+                }),
+                crate::vk::Depends::Extension(unsafe {
+                    ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                        b"VK_KHR_get_physical_device_properties2\0",
+                    )
+                }),
+                crate::vk::Depends::Extension(unsafe {
+                    ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                        b"VK_KHR_sampler_ycbcr_conversion\0",
+                    )
+                }),
+            ]),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        ]),
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_image_format_list\0")
+            }),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        ]),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetImageDrmFormatModifierPropertiesEXT = unsafe extern "system" fn(
@@ -8538,9 +10224,25 @@ pub struct ExtImageDrmFormatModifierFn {
 unsafe impl Send for ExtImageDrmFormatModifierFn {}
 unsafe impl Sync for ExtImageDrmFormatModifierFn {}
 impl ExtImageDrmFormatModifierFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_image_drm_format_modifier_properties_ext: unsafe {
@@ -8558,6 +10260,7 @@ impl ExtImageDrmFormatModifierFn {
                     b"vkGetImageDrmFormatModifierPropertiesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_drm_format_modifier_properties_ext
                 } else {
@@ -8595,6 +10298,7 @@ impl ExtValidationCacheFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_validation_cache\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateValidationCacheEXT = unsafe extern "system" fn(
@@ -8633,9 +10337,25 @@ pub struct ExtValidationCacheFn {
 unsafe impl Send for ExtValidationCacheFn {}
 unsafe impl Sync for ExtValidationCacheFn {}
 impl ExtValidationCacheFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_validation_cache_ext: unsafe {
@@ -8654,6 +10374,7 @@ impl ExtValidationCacheFn {
                     b"vkCreateValidationCacheEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_validation_cache_ext
                 } else {
@@ -8675,6 +10396,7 @@ impl ExtValidationCacheFn {
                     b"vkDestroyValidationCacheEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_validation_cache_ext
                 } else {
@@ -8697,6 +10419,7 @@ impl ExtValidationCacheFn {
                     b"vkMergeValidationCachesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     merge_validation_caches_ext
                 } else {
@@ -8719,6 +10442,7 @@ impl ExtValidationCacheFn {
                     b"vkGetValidationCacheDataEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_validation_cache_data_ext
                 } else {
@@ -8741,6 +10465,16 @@ impl ExtDescriptorIndexingFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_descriptor_indexing\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance3\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtDescriptorIndexingFn;
@@ -8781,6 +10515,7 @@ impl ExtShaderViewportIndexLayerFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_viewport_index_layer\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtShaderViewportIndexLayerFn;
@@ -8788,6 +10523,9 @@ impl KhrPortabilitySubsetFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_portability_subset\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrPortabilitySubsetFn;
@@ -8800,6 +10538,9 @@ impl NvShadingRateImageFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_shading_rate_image\0") };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdBindShadingRateImageNV = unsafe extern "system" fn(
@@ -8830,9 +10571,25 @@ pub struct NvShadingRateImageFn {
 unsafe impl Send for NvShadingRateImageFn {}
 unsafe impl Sync for NvShadingRateImageFn {}
 impl NvShadingRateImageFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_bind_shading_rate_image_nv: unsafe {
@@ -8850,6 +10607,7 @@ impl NvShadingRateImageFn {
                     b"vkCmdBindShadingRateImageNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_shading_rate_image_nv
                 } else {
@@ -8872,6 +10630,7 @@ impl NvShadingRateImageFn {
                     b"vkCmdSetViewportShadingRatePaletteNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_viewport_shading_rate_palette_nv
                 } else {
@@ -8894,6 +10653,7 @@ impl NvShadingRateImageFn {
                     b"vkCmdSetCoarseSampleOrderNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coarse_sample_order_nv
                 } else {
@@ -8936,6 +10696,16 @@ impl NvRayTracingFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_ray_tracing\0") };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_memory_requirements2\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateAccelerationStructureNV = unsafe extern "system" fn(
@@ -9048,9 +10818,25 @@ pub struct NvRayTracingFn {
 unsafe impl Send for NvRayTracingFn {}
 unsafe impl Sync for NvRayTracingFn {}
 impl NvRayTracingFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_acceleration_structure_nv: unsafe {
@@ -9069,6 +10855,7 @@ impl NvRayTracingFn {
                     b"vkCreateAccelerationStructureNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_acceleration_structure_nv
                 } else {
@@ -9090,6 +10877,7 @@ impl NvRayTracingFn {
                     b"vkDestroyAccelerationStructureNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_acceleration_structure_nv
                 } else {
@@ -9111,6 +10899,7 @@ impl NvRayTracingFn {
                     b"vkGetAccelerationStructureMemoryRequirementsNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_acceleration_structure_memory_requirements_nv
                 } else {
@@ -9132,6 +10921,7 @@ impl NvRayTracingFn {
                     b"vkBindAccelerationStructureMemoryNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     bind_acceleration_structure_memory_nv
                 } else {
@@ -9159,6 +10949,7 @@ impl NvRayTracingFn {
                     b"vkCmdBuildAccelerationStructureNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_build_acceleration_structure_nv
                 } else {
@@ -9181,6 +10972,7 @@ impl NvRayTracingFn {
                     b"vkCmdCopyAccelerationStructureNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_acceleration_structure_nv
                 } else {
@@ -9209,6 +11001,7 @@ impl NvRayTracingFn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdTraceRaysNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_trace_rays_nv
                 } else {
@@ -9233,6 +11026,7 @@ impl NvRayTracingFn {
                     b"vkCreateRayTracingPipelinesNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_ray_tracing_pipelines_nv
                 } else {
@@ -9257,6 +11051,7 @@ impl NvRayTracingFn {
                     b"vkGetRayTracingShaderGroupHandlesNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_ray_tracing_shader_group_handles_nv
                 } else {
@@ -9279,6 +11074,7 @@ impl NvRayTracingFn {
                     b"vkGetAccelerationStructureHandleNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_acceleration_structure_handle_nv
                 } else {
@@ -9303,6 +11099,7 @@ impl NvRayTracingFn {
                     b"vkCmdWriteAccelerationStructuresPropertiesNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_write_acceleration_structures_properties_nv
                 } else {
@@ -9320,6 +11117,7 @@ impl NvRayTracingFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCompileDeferredNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     compile_deferred_nv
                 } else {
@@ -9440,6 +11238,9 @@ impl NvRepresentativeFragmentTestFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_representative_fragment_test\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvRepresentativeFragmentTestFn;
@@ -9453,6 +11254,9 @@ impl KhrMaintenance3Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance3\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetDescriptorSetLayoutSupport = unsafe extern "system" fn(
@@ -9467,9 +11271,25 @@ pub struct KhrMaintenance3Fn {
 unsafe impl Send for KhrMaintenance3Fn {}
 unsafe impl Sync for KhrMaintenance3Fn {}
 impl KhrMaintenance3Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_descriptor_set_layout_support_khr: unsafe {
@@ -9487,6 +11307,7 @@ impl KhrMaintenance3Fn {
                     b"vkGetDescriptorSetLayoutSupportKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_descriptor_set_layout_support_khr
                 } else {
@@ -9506,6 +11327,7 @@ impl KhrDrawIndirectCountFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_draw_indirect_count\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct KhrDrawIndirectCountFn {
@@ -9515,9 +11337,25 @@ pub struct KhrDrawIndirectCountFn {
 unsafe impl Send for KhrDrawIndirectCountFn {}
 unsafe impl Sync for KhrDrawIndirectCountFn {}
 impl KhrDrawIndirectCountFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_draw_indirect_count_khr: unsafe {
@@ -9538,6 +11376,7 @@ impl KhrDrawIndirectCountFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirectCountKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_indirect_count_khr
                 } else {
@@ -9563,6 +11402,7 @@ impl KhrDrawIndirectCountFn {
                     b"vkCmdDrawIndexedIndirectCountKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_indexed_indirect_count_khr
                 } else {
@@ -9576,6 +11416,7 @@ impl ExtFilterCubicFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_filter_cubic\0") };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtFilterCubicFn;
@@ -9597,6 +11438,7 @@ impl QcomRenderPassShaderResolveFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_render_pass_shader_resolve\0")
     };
     pub const SPEC_VERSION: u32 = 4u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct QcomRenderPassShaderResolveFn;
@@ -9609,6 +11451,7 @@ impl ExtGlobalPriorityFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_global_priority\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtGlobalPriorityFn;
@@ -9626,6 +11469,7 @@ impl KhrShaderSubgroupExtendedTypesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_subgroup_extended_types\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Version(crate::vk::API_VERSION_1_1);
 }
 #[derive(Clone)]
 pub struct KhrShaderSubgroupExtendedTypesFn;
@@ -9638,6 +11482,18 @@ impl Khr8bitStorageFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_8bit_storage\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_storage_buffer_storage_class\0",
+            )
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct Khr8bitStorageFn;
@@ -9651,6 +11507,12 @@ impl ExtExternalMemoryHostFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_external_memory_host\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory\0")
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetMemoryHostPointerPropertiesEXT = unsafe extern "system" fn(
@@ -9666,9 +11528,25 @@ pub struct ExtExternalMemoryHostFn {
 unsafe impl Send for ExtExternalMemoryHostFn {}
 unsafe impl Sync for ExtExternalMemoryHostFn {}
 impl ExtExternalMemoryHostFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_memory_host_pointer_properties_ext: unsafe {
@@ -9687,6 +11565,7 @@ impl ExtExternalMemoryHostFn {
                     b"vkGetMemoryHostPointerPropertiesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_memory_host_pointer_properties_ext
                 } else {
@@ -9711,6 +11590,7 @@ impl AmdBufferMarkerFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_buffer_marker\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdWriteBufferMarkerAMD = unsafe extern "system" fn(
@@ -9727,9 +11607,25 @@ pub struct AmdBufferMarkerFn {
 unsafe impl Send for AmdBufferMarkerFn {}
 unsafe impl Sync for AmdBufferMarkerFn {}
 impl AmdBufferMarkerFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_write_buffer_marker_amd: unsafe {
@@ -9748,6 +11644,7 @@ impl AmdBufferMarkerFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteBufferMarkerAMD\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_write_buffer_marker_amd
                 } else {
@@ -9761,6 +11658,9 @@ impl KhrShaderAtomicInt64Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_atomic_int64\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrShaderAtomicInt64Fn;
@@ -9773,6 +11673,14 @@ impl KhrShaderClockFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_clock\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct KhrShaderClockFn;
@@ -9785,6 +11693,7 @@ impl AmdPipelineCompilerControlFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_pipeline_compiler_control\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdPipelineCompilerControlFn;
@@ -9797,6 +11706,14 @@ impl ExtCalibratedTimestampsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_calibrated_timestamps\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceCalibrateableTimeDomainsEXT = unsafe extern "system" fn(
@@ -9821,9 +11738,25 @@ pub struct ExtCalibratedTimestampsFn {
 unsafe impl Send for ExtCalibratedTimestampsFn {}
 unsafe impl Sync for ExtCalibratedTimestampsFn {}
 impl ExtCalibratedTimestampsFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_calibrateable_time_domains_ext: unsafe {
@@ -9841,6 +11774,7 @@ impl ExtCalibratedTimestampsFn {
                     b"vkGetPhysicalDeviceCalibrateableTimeDomainsEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_calibrateable_time_domains_ext
                 } else {
@@ -9864,6 +11798,7 @@ impl ExtCalibratedTimestampsFn {
                     b"vkGetCalibratedTimestampsEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_calibrated_timestamps_ext
                 } else {
@@ -9882,6 +11817,9 @@ impl AmdShaderCorePropertiesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_shader_core_properties\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct AmdShaderCorePropertiesFn;
@@ -9893,6 +11831,9 @@ impl KhrVideoDecodeH265Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_decode_h265\0") };
     pub const SPEC_VERSION: u32 = 7u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_decode_queue\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrVideoDecodeH265Fn;
@@ -9913,6 +11854,9 @@ impl KhrGlobalPriorityFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_global_priority\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrGlobalPriorityFn;
@@ -9931,6 +11875,7 @@ impl AmdMemoryOverallocationBehaviorFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_memory_overallocation_behavior\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct AmdMemoryOverallocationBehaviorFn;
@@ -9943,6 +11888,14 @@ impl ExtVertexAttributeDivisorFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_vertex_attribute_divisor\0")
     };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtVertexAttributeDivisorFn;
@@ -9956,6 +11909,14 @@ impl GgpFrameTokenFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_GGP_frame_token\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_GGP_stream_descriptor_surface\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct GgpFrameTokenFn;
@@ -9968,6 +11929,7 @@ impl ExtPipelineCreationFeedbackFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_creation_feedback\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtPipelineCreationFeedbackFn;
@@ -9980,6 +11942,9 @@ impl KhrDriverPropertiesFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_driver_properties\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrDriverPropertiesFn;
@@ -10007,6 +11972,9 @@ impl KhrShaderFloatControlsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_float_controls\0")
     };
     pub const SPEC_VERSION: u32 = 4u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrShaderFloatControlsFn;
@@ -10026,6 +11994,7 @@ impl NvShaderSubgroupPartitionedFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_shader_subgroup_partitioned\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Version(crate::vk::API_VERSION_1_1);
 }
 #[derive(Clone)]
 pub struct NvShaderSubgroupPartitionedFn;
@@ -10038,6 +12007,9 @@ impl KhrDepthStencilResolveFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_depth_stencil_resolve\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_create_renderpass2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrDepthStencilResolveFn;
@@ -10061,6 +12033,23 @@ impl KhrSwapchainMutableFormatFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain_mutable_format\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance2\0")
+            }),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        ]),
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_image_format_list\0")
+            }),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        ]),
+    ]);
 }
 #[derive(Clone)]
 pub struct KhrSwapchainMutableFormatFn;
@@ -10073,6 +12062,9 @@ impl NvComputeShaderDerivativesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_compute_shader_derivatives\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvComputeShaderDerivativesFn;
@@ -10084,6 +12076,9 @@ impl NvMeshShaderFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_mesh_shader\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdDrawMeshTasksNV =
@@ -10115,9 +12110,25 @@ pub struct NvMeshShaderFn {
 unsafe impl Send for NvMeshShaderFn {}
 unsafe impl Sync for NvMeshShaderFn {}
 impl NvMeshShaderFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_draw_mesh_tasks_nv: unsafe {
@@ -10134,6 +12145,7 @@ impl NvMeshShaderFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_mesh_tasks_nv
                 } else {
@@ -10157,6 +12169,7 @@ impl NvMeshShaderFn {
                     b"vkCmdDrawMeshTasksIndirectNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_mesh_tasks_indirect_nv
                 } else {
@@ -10182,6 +12195,7 @@ impl NvMeshShaderFn {
                     b"vkCmdDrawMeshTasksIndirectCountNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_mesh_tasks_indirect_count_nv
                 } else {
@@ -10211,6 +12225,9 @@ impl NvFragmentShaderBarycentricFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_fragment_shader_barycentric\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvFragmentShaderBarycentricFn;
@@ -10224,6 +12241,9 @@ impl NvShaderImageFootprintFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_shader_image_footprint\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvShaderImageFootprintFn;
@@ -10235,6 +12255,9 @@ impl NvScissorExclusiveFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_scissor_exclusive\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetExclusiveScissorEnableNV = unsafe extern "system" fn(
@@ -10258,9 +12281,25 @@ pub struct NvScissorExclusiveFn {
 unsafe impl Send for NvScissorExclusiveFn {}
 unsafe impl Sync for NvScissorExclusiveFn {}
 impl NvScissorExclusiveFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_exclusive_scissor_enable_nv: unsafe {
@@ -10279,6 +12318,7 @@ impl NvScissorExclusiveFn {
                     b"vkCmdSetExclusiveScissorEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_exclusive_scissor_enable_nv
                 } else {
@@ -10301,6 +12341,7 @@ impl NvScissorExclusiveFn {
                     b"vkCmdSetExclusiveScissorNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_exclusive_scissor_nv
                 } else {
@@ -10325,6 +12366,9 @@ impl NvDeviceDiagnosticCheckpointsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_device_diagnostic_checkpoints\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetCheckpointNV =
@@ -10343,9 +12387,25 @@ pub struct NvDeviceDiagnosticCheckpointsFn {
 unsafe impl Send for NvDeviceDiagnosticCheckpointsFn {}
 unsafe impl Sync for NvDeviceDiagnosticCheckpointsFn {}
 impl NvDeviceDiagnosticCheckpointsFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_checkpoint_nv: unsafe {
@@ -10361,6 +12421,7 @@ impl NvDeviceDiagnosticCheckpointsFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCheckpointNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_checkpoint_nv
                 } else {
@@ -10382,6 +12443,7 @@ impl NvDeviceDiagnosticCheckpointsFn {
                     b"vkGetQueueCheckpointDataNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_queue_checkpoint_data_nv
                 } else {
@@ -10400,6 +12462,9 @@ impl KhrTimelineSemaphoreFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_timeline_semaphore\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetSemaphoreCounterValue =
@@ -10422,9 +12487,25 @@ pub struct KhrTimelineSemaphoreFn {
 unsafe impl Send for KhrTimelineSemaphoreFn {}
 unsafe impl Sync for KhrTimelineSemaphoreFn {}
 impl KhrTimelineSemaphoreFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_semaphore_counter_value_khr: unsafe {
@@ -10442,6 +12523,7 @@ impl KhrTimelineSemaphoreFn {
                     b"vkGetSemaphoreCounterValueKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_semaphore_counter_value_khr
                 } else {
@@ -10459,6 +12541,7 @@ impl KhrTimelineSemaphoreFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkWaitSemaphoresKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     wait_semaphores_khr
                 } else {
@@ -10475,6 +12558,7 @@ impl KhrTimelineSemaphoreFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkSignalSemaphoreKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     signal_semaphore_khr
                 } else {
@@ -10509,6 +12593,9 @@ impl IntelShaderIntegerFunctions2Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_INTEL_shader_integer_functions2\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct IntelShaderIntegerFunctions2Fn;
@@ -10520,6 +12607,7 @@ impl IntelPerformanceQueryFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_INTEL_performance_query\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkInitializePerformanceApiINTEL = unsafe extern "system" fn(
@@ -10578,9 +12666,25 @@ pub struct IntelPerformanceQueryFn {
 unsafe impl Send for IntelPerformanceQueryFn {}
 unsafe impl Sync for IntelPerformanceQueryFn {}
 impl IntelPerformanceQueryFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             initialize_performance_api_intel: unsafe {
@@ -10597,6 +12701,7 @@ impl IntelPerformanceQueryFn {
                     b"vkInitializePerformanceApiINTEL\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     initialize_performance_api_intel
                 } else {
@@ -10614,6 +12719,7 @@ impl IntelPerformanceQueryFn {
                     b"vkUninitializePerformanceApiINTEL\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     uninitialize_performance_api_intel
                 } else {
@@ -10634,6 +12740,7 @@ impl IntelPerformanceQueryFn {
                     b"vkCmdSetPerformanceMarkerINTEL\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_performance_marker_intel
                 } else {
@@ -10654,6 +12761,7 @@ impl IntelPerformanceQueryFn {
                     b"vkCmdSetPerformanceStreamMarkerINTEL\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_performance_stream_marker_intel
                 } else {
@@ -10674,6 +12782,7 @@ impl IntelPerformanceQueryFn {
                     b"vkCmdSetPerformanceOverrideINTEL\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_performance_override_intel
                 } else {
@@ -10695,6 +12804,7 @@ impl IntelPerformanceQueryFn {
                     b"vkAcquirePerformanceConfigurationINTEL\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     acquire_performance_configuration_intel
                 } else {
@@ -10715,6 +12825,7 @@ impl IntelPerformanceQueryFn {
                     b"vkReleasePerformanceConfigurationINTEL\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     release_performance_configuration_intel
                 } else {
@@ -10735,6 +12846,7 @@ impl IntelPerformanceQueryFn {
                     b"vkQueueSetPerformanceConfigurationINTEL\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_set_performance_configuration_intel
                 } else {
@@ -10756,6 +12868,7 @@ impl IntelPerformanceQueryFn {
                     b"vkGetPerformanceParameterINTEL\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_performance_parameter_intel
                 } else {
@@ -10786,6 +12899,9 @@ impl KhrVulkanMemoryModelFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_vulkan_memory_model\0") };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrVulkanMemoryModelFn;
@@ -10798,6 +12914,14 @@ impl ExtPciBusInfoFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pci_bus_info\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtPciBusInfoFn;
@@ -10809,6 +12933,19 @@ impl AmdDisplayNativeHdrFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_display_native_hdr\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_surface_capabilities2\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkSetLocalDimmingAMD = unsafe extern "system" fn(
@@ -10823,9 +12960,25 @@ pub struct AmdDisplayNativeHdrFn {
 unsafe impl Send for AmdDisplayNativeHdrFn {}
 unsafe impl Sync for AmdDisplayNativeHdrFn {}
 impl AmdDisplayNativeHdrFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             set_local_dimming_amd: unsafe {
@@ -10842,6 +12995,7 @@ impl AmdDisplayNativeHdrFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkSetLocalDimmingAMD\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     set_local_dimming_amd
                 } else {
@@ -10865,6 +13019,9 @@ impl FuchsiaImagepipeSurfaceFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_FUCHSIA_imagepipe_surface\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateImagePipeSurfaceFUCHSIA = unsafe extern "system" fn(
@@ -10880,9 +13037,25 @@ pub struct FuchsiaImagepipeSurfaceFn {
 unsafe impl Send for FuchsiaImagepipeSurfaceFn {}
 unsafe impl Sync for FuchsiaImagepipeSurfaceFn {}
 impl FuchsiaImagepipeSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_image_pipe_surface_fuchsia: unsafe {
@@ -10901,6 +13074,7 @@ impl FuchsiaImagepipeSurfaceFn {
                     b"vkCreateImagePipeSurfaceFUCHSIA\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_image_pipe_surface_fuchsia
                 } else {
@@ -10919,6 +13093,14 @@ impl KhrShaderTerminateInvocationFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_terminate_invocation\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct KhrShaderTerminateInvocationFn;
@@ -10931,6 +13113,9 @@ impl ExtMetalSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_metal_surface\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateMetalSurfaceEXT = unsafe extern "system" fn(
@@ -10946,9 +13131,25 @@ pub struct ExtMetalSurfaceFn {
 unsafe impl Send for ExtMetalSurfaceFn {}
 unsafe impl Sync for ExtMetalSurfaceFn {}
 impl ExtMetalSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_metal_surface_ext: unsafe {
@@ -10966,6 +13167,7 @@ impl ExtMetalSurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateMetalSurfaceEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_metal_surface_ext
                 } else {
@@ -10984,6 +13186,9 @@ impl ExtFragmentDensityMapFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_fragment_density_map\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtFragmentDensityMapFn;
@@ -11034,6 +13239,9 @@ impl ExtScalarBlockLayoutFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_scalar_block_layout\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtScalarBlockLayoutFn;
@@ -11047,6 +13255,7 @@ impl GoogleHlslFunctionality1Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_GOOGLE_hlsl_functionality1\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct GoogleHlslFunctionality1Fn;
@@ -11054,6 +13263,7 @@ impl GoogleDecorateStringFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_GOOGLE_decorate_string\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct GoogleDecorateStringFn;
@@ -11062,6 +13272,7 @@ impl ExtSubgroupSizeControlFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_subgroup_size_control\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Version(crate::vk::API_VERSION_1_1);
 }
 #[derive(Clone)]
 pub struct ExtSubgroupSizeControlFn;
@@ -11084,6 +13295,22 @@ impl KhrFragmentShadingRateFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_fragment_shading_rate\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_create_renderpass2\0")
+            }),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_2),
+        ]),
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                    b"VK_KHR_get_physical_device_properties2\0",
+                )
+            }),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        ]),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceFragmentShadingRatesKHR = unsafe extern "system" fn(
@@ -11106,9 +13333,25 @@ pub struct KhrFragmentShadingRateFn {
 unsafe impl Send for KhrFragmentShadingRateFn {}
 unsafe impl Sync for KhrFragmentShadingRateFn {}
 impl KhrFragmentShadingRateFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_fragment_shading_rates_khr: unsafe {
@@ -11126,6 +13369,7 @@ impl KhrFragmentShadingRateFn {
                     b"vkGetPhysicalDeviceFragmentShadingRatesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_fragment_shading_rates_khr
                 } else {
@@ -11147,6 +13391,7 @@ impl KhrFragmentShadingRateFn {
                     b"vkCmdSetFragmentShadingRateKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_fragment_shading_rate_khr
                 } else {
@@ -11200,6 +13445,9 @@ impl AmdShaderCoreProperties2Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_shader_core_properties2\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_shader_core_properties\0")
+    });
 }
 #[derive(Clone)]
 pub struct AmdShaderCoreProperties2Fn;
@@ -11212,6 +13460,9 @@ impl AmdDeviceCoherentMemoryFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_AMD_device_coherent_memory\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct AmdDeviceCoherentMemoryFn;
@@ -11229,6 +13480,14 @@ impl ExtShaderImageAtomicInt64Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_image_atomic_int64\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtShaderImageAtomicInt64Fn;
@@ -11240,6 +13499,12 @@ impl KhrSpirv14Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_spirv_1_4\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_float_controls\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct KhrSpirv14Fn;
@@ -11247,6 +13512,14 @@ impl ExtMemoryBudgetFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_memory_budget\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtMemoryBudgetFn;
@@ -11258,6 +13531,9 @@ impl ExtMemoryPriorityFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_memory_priority\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtMemoryPriorityFn;
@@ -11271,6 +13547,12 @@ impl KhrSurfaceProtectedCapabilitiesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface_protected_capabilities\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_surface_capabilities2\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct KhrSurfaceProtectedCapabilitiesFn;
@@ -11285,6 +13567,16 @@ impl NvDedicatedAllocationImageAliasingFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_dedicated_allocation\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct NvDedicatedAllocationImageAliasingFn;
@@ -11298,6 +13590,16 @@ impl KhrSeparateDepthStencilLayoutsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_separate_depth_stencil_layouts\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_create_renderpass2\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct KhrSeparateDepthStencilLayoutsFn;
@@ -11322,6 +13624,9 @@ impl ExtBufferDeviceAddressFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_buffer_device_address\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetBufferDeviceAddress = unsafe extern "system" fn(
@@ -11335,9 +13640,25 @@ pub struct ExtBufferDeviceAddressFn {
 unsafe impl Send for ExtBufferDeviceAddressFn {}
 unsafe impl Sync for ExtBufferDeviceAddressFn {}
 impl ExtBufferDeviceAddressFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_buffer_device_address_ext: unsafe {
@@ -11354,6 +13675,7 @@ impl ExtBufferDeviceAddressFn {
                     b"vkGetBufferDeviceAddressEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_buffer_device_address_ext
                 } else {
@@ -11387,6 +13709,7 @@ impl ExtToolingInfoFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_tooling_info\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceToolProperties = unsafe extern "system" fn(
@@ -11401,9 +13724,25 @@ pub struct ExtToolingInfoFn {
 unsafe impl Send for ExtToolingInfoFn {}
 unsafe impl Sync for ExtToolingInfoFn {}
 impl ExtToolingInfoFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_tool_properties_ext: unsafe {
@@ -11421,6 +13760,7 @@ impl ExtToolingInfoFn {
                     b"vkGetPhysicalDeviceToolPropertiesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_tool_properties_ext
                 } else {
@@ -11444,6 +13784,7 @@ impl ExtSeparateStencilUsageFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_separate_stencil_usage\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtSeparateStencilUsageFn;
@@ -11455,6 +13796,7 @@ impl ExtValidationFeaturesFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_validation_features\0") };
     pub const SPEC_VERSION: u32 = 5u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtValidationFeaturesFn;
@@ -11466,6 +13808,14 @@ impl KhrPresentWaitFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_present_wait\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_present_id\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkWaitForPresentKHR = unsafe extern "system" fn(
@@ -11481,9 +13831,25 @@ pub struct KhrPresentWaitFn {
 unsafe impl Send for KhrPresentWaitFn {}
 unsafe impl Sync for KhrPresentWaitFn {}
 impl KhrPresentWaitFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             wait_for_present_khr: unsafe {
@@ -11498,6 +13864,7 @@ impl KhrPresentWaitFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkWaitForPresentKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     wait_for_present_khr
                 } else {
@@ -11515,6 +13882,9 @@ impl NvCooperativeMatrixFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_cooperative_matrix\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceCooperativeMatrixPropertiesNV = unsafe extern "system" fn(
@@ -11531,9 +13901,25 @@ pub struct NvCooperativeMatrixFn {
 unsafe impl Send for NvCooperativeMatrixFn {}
 unsafe impl Sync for NvCooperativeMatrixFn {}
 impl NvCooperativeMatrixFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_cooperative_matrix_properties_nv: unsafe {
@@ -11551,6 +13937,7 @@ impl NvCooperativeMatrixFn {
                     b"vkGetPhysicalDeviceCooperativeMatrixPropertiesNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_cooperative_matrix_properties_nv
                 } else {
@@ -11571,6 +13958,16 @@ impl NvCoverageReductionModeFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_coverage_reduction_mode\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_framebuffer_mixed_samples\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV =
@@ -11587,9 +13984,25 @@ pub struct NvCoverageReductionModeFn {
 unsafe impl Send for NvCoverageReductionModeFn {}
 unsafe impl Sync for NvCoverageReductionModeFn {}
 impl NvCoverageReductionModeFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_supported_framebuffer_mixed_samples_combinations_nv: unsafe {
@@ -11609,6 +14022,7 @@ impl NvCoverageReductionModeFn {
                     b"vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_supported_framebuffer_mixed_samples_combinations_nv
                 } else {
@@ -11629,6 +14043,14 @@ impl ExtFragmentShaderInterlockFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_fragment_shader_interlock\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtFragmentShaderInterlockFn;
@@ -11640,6 +14062,12 @@ impl ExtYcbcrImageArraysFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_ycbcr_image_arrays\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_sampler_ycbcr_conversion\0")
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtYcbcrImageArraysFn;
@@ -11652,6 +14080,9 @@ impl KhrUniformBufferStandardLayoutFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_uniform_buffer_standard_layout\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrUniformBufferStandardLayoutFn;
@@ -11664,6 +14095,9 @@ impl ExtProvokingVertexFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_provoking_vertex\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtProvokingVertexFn;
@@ -11679,6 +14113,22 @@ impl ExtFullScreenExclusiveFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_full_screen_exclusive\0")
     };
     pub const SPEC_VERSION: u32 = 4u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_surface_capabilities2\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceSurfacePresentModes2EXT = unsafe extern "system" fn(
@@ -11710,9 +14160,25 @@ pub struct ExtFullScreenExclusiveFn {
 unsafe impl Send for ExtFullScreenExclusiveFn {}
 unsafe impl Sync for ExtFullScreenExclusiveFn {}
 impl ExtFullScreenExclusiveFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_surface_present_modes2_ext: unsafe {
@@ -11731,6 +14197,7 @@ impl ExtFullScreenExclusiveFn {
                     b"vkGetPhysicalDeviceSurfacePresentModes2EXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_surface_present_modes2_ext
                 } else {
@@ -11751,6 +14218,7 @@ impl ExtFullScreenExclusiveFn {
                     b"vkAcquireFullScreenExclusiveModeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     acquire_full_screen_exclusive_mode_ext
                 } else {
@@ -11771,6 +14239,7 @@ impl ExtFullScreenExclusiveFn {
                     b"vkReleaseFullScreenExclusiveModeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     release_full_screen_exclusive_mode_ext
                 } else {
@@ -11792,6 +14261,7 @@ impl ExtFullScreenExclusiveFn {
                     b"vkGetDeviceGroupSurfacePresentModes2EXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_group_surface_present_modes2_ext
                 } else {
@@ -11815,6 +14285,9 @@ impl ExtHeadlessSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_headless_surface\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateHeadlessSurfaceEXT = unsafe extern "system" fn(
@@ -11830,9 +14303,25 @@ pub struct ExtHeadlessSurfaceFn {
 unsafe impl Send for ExtHeadlessSurfaceFn {}
 unsafe impl Sync for ExtHeadlessSurfaceFn {}
 impl ExtHeadlessSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_headless_surface_ext: unsafe {
@@ -11851,6 +14340,7 @@ impl ExtHeadlessSurfaceFn {
                     b"vkCreateHeadlessSurfaceEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_headless_surface_ext
                 } else {
@@ -11869,6 +14359,19 @@ impl KhrBufferDeviceAddressFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_buffer_device_address\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::All(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                    b"VK_KHR_get_physical_device_properties2\0",
+                )
+            }),
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_device_group\0")
+            }),
+        ]),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetBufferOpaqueCaptureAddress =
@@ -11887,9 +14390,25 @@ pub struct KhrBufferDeviceAddressFn {
 unsafe impl Send for KhrBufferDeviceAddressFn {}
 unsafe impl Sync for KhrBufferDeviceAddressFn {}
 impl KhrBufferDeviceAddressFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_buffer_device_address_khr: unsafe {
@@ -11906,6 +14425,7 @@ impl KhrBufferDeviceAddressFn {
                     b"vkGetBufferDeviceAddressKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_buffer_device_address_khr
                 } else {
@@ -11926,6 +14446,7 @@ impl KhrBufferDeviceAddressFn {
                     b"vkGetBufferOpaqueCaptureAddressKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_buffer_opaque_capture_address_khr
                 } else {
@@ -11946,6 +14467,7 @@ impl KhrBufferDeviceAddressFn {
                     b"vkGetDeviceMemoryOpaqueCaptureAddressKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_memory_opaque_capture_address_khr
                 } else {
@@ -11989,6 +14511,14 @@ impl ExtLineRasterizationFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_line_rasterization\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetLineStippleEXT = unsafe extern "system" fn(
@@ -12003,9 +14533,25 @@ pub struct ExtLineRasterizationFn {
 unsafe impl Send for ExtLineRasterizationFn {}
 unsafe impl Sync for ExtLineRasterizationFn {}
 impl ExtLineRasterizationFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_line_stipple_ext: unsafe {
@@ -12022,6 +14568,7 @@ impl ExtLineRasterizationFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLineStippleEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_line_stipple_ext
                 } else {
@@ -12045,6 +14592,14 @@ impl ExtShaderAtomicFloatFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_atomic_float\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtShaderAtomicFloatFn;
@@ -12056,6 +14611,9 @@ impl ExtHostQueryResetFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_host_query_reset\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkResetQueryPool = unsafe extern "system" fn(
@@ -12071,9 +14629,25 @@ pub struct ExtHostQueryResetFn {
 unsafe impl Send for ExtHostQueryResetFn {}
 unsafe impl Sync for ExtHostQueryResetFn {}
 impl ExtHostQueryResetFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             reset_query_pool_ext: unsafe {
@@ -12088,6 +14662,7 @@ impl ExtHostQueryResetFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkResetQueryPoolEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     reset_query_pool_ext
                 } else {
@@ -12106,6 +14681,14 @@ impl ExtIndexTypeUint8Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_index_type_uint8\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtIndexTypeUint8Fn;
@@ -12122,6 +14705,14 @@ impl ExtExtendedDynamicStateFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_extended_dynamic_state\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetCullMode =
@@ -12196,9 +14787,25 @@ pub struct ExtExtendedDynamicStateFn {
 unsafe impl Send for ExtExtendedDynamicStateFn {}
 unsafe impl Sync for ExtExtendedDynamicStateFn {}
 impl ExtExtendedDynamicStateFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_cull_mode_ext: unsafe {
@@ -12214,6 +14821,7 @@ impl ExtExtendedDynamicStateFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCullModeEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_cull_mode_ext
                 } else {
@@ -12233,6 +14841,7 @@ impl ExtExtendedDynamicStateFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetFrontFaceEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_front_face_ext
                 } else {
@@ -12253,6 +14862,7 @@ impl ExtExtendedDynamicStateFn {
                     b"vkCmdSetPrimitiveTopologyEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_primitive_topology_ext
                 } else {
@@ -12274,6 +14884,7 @@ impl ExtExtendedDynamicStateFn {
                     b"vkCmdSetViewportWithCountEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_viewport_with_count_ext
                 } else {
@@ -12295,6 +14906,7 @@ impl ExtExtendedDynamicStateFn {
                     b"vkCmdSetScissorWithCountEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_scissor_with_count_ext
                 } else {
@@ -12320,6 +14932,7 @@ impl ExtExtendedDynamicStateFn {
                     b"vkCmdBindVertexBuffers2EXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_vertex_buffers2_ext
                 } else {
@@ -12340,6 +14953,7 @@ impl ExtExtendedDynamicStateFn {
                     b"vkCmdSetDepthTestEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_test_enable_ext
                 } else {
@@ -12360,6 +14974,7 @@ impl ExtExtendedDynamicStateFn {
                     b"vkCmdSetDepthWriteEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_write_enable_ext
                 } else {
@@ -12379,6 +14994,7 @@ impl ExtExtendedDynamicStateFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthCompareOpEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_compare_op_ext
                 } else {
@@ -12399,6 +15015,7 @@ impl ExtExtendedDynamicStateFn {
                     b"vkCmdSetDepthBoundsTestEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_bounds_test_enable_ext
                 } else {
@@ -12419,6 +15036,7 @@ impl ExtExtendedDynamicStateFn {
                     b"vkCmdSetStencilTestEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_stencil_test_enable_ext
                 } else {
@@ -12442,6 +15060,7 @@ impl ExtExtendedDynamicStateFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilOpEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_stencil_op_ext
                 } else {
@@ -12476,6 +15095,7 @@ impl KhrDeferredHostOperationsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_deferred_host_operations\0")
     };
     pub const SPEC_VERSION: u32 = 4u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateDeferredOperationKHR = unsafe extern "system" fn(
@@ -12509,9 +15129,25 @@ pub struct KhrDeferredHostOperationsFn {
 unsafe impl Send for KhrDeferredHostOperationsFn {}
 unsafe impl Sync for KhrDeferredHostOperationsFn {}
 impl KhrDeferredHostOperationsFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_deferred_operation_khr: unsafe {
@@ -12529,6 +15165,7 @@ impl KhrDeferredHostOperationsFn {
                     b"vkCreateDeferredOperationKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_deferred_operation_khr
                 } else {
@@ -12550,6 +15187,7 @@ impl KhrDeferredHostOperationsFn {
                     b"vkDestroyDeferredOperationKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_deferred_operation_khr
                 } else {
@@ -12570,6 +15208,7 @@ impl KhrDeferredHostOperationsFn {
                     b"vkGetDeferredOperationMaxConcurrencyKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_deferred_operation_max_concurrency_khr
                 } else {
@@ -12590,6 +15229,7 @@ impl KhrDeferredHostOperationsFn {
                     b"vkGetDeferredOperationResultKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_deferred_operation_result_khr
                 } else {
@@ -12610,6 +15250,7 @@ impl KhrDeferredHostOperationsFn {
                     b"vkDeferredOperationJoinKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     deferred_operation_join_khr
                 } else {
@@ -12635,6 +15276,9 @@ impl KhrPipelineExecutablePropertiesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_pipeline_executable_properties\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPipelineExecutablePropertiesKHR = unsafe extern "system" fn(
@@ -12668,9 +15312,25 @@ pub struct KhrPipelineExecutablePropertiesFn {
 unsafe impl Send for KhrPipelineExecutablePropertiesFn {}
 unsafe impl Sync for KhrPipelineExecutablePropertiesFn {}
 impl KhrPipelineExecutablePropertiesFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_pipeline_executable_properties_khr: unsafe {
@@ -12689,6 +15349,7 @@ impl KhrPipelineExecutablePropertiesFn {
                     b"vkGetPipelineExecutablePropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_pipeline_executable_properties_khr
                 } else {
@@ -12711,6 +15372,7 @@ impl KhrPipelineExecutablePropertiesFn {
                     b"vkGetPipelineExecutableStatisticsKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_pipeline_executable_statistics_khr
                 } else {
@@ -12733,6 +15395,7 @@ impl KhrPipelineExecutablePropertiesFn {
                     b"vkGetPipelineExecutableInternalRepresentationsKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_pipeline_executable_internal_representations_khr
                 } else {
@@ -12761,6 +15424,7 @@ impl KhrMapMemory2Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_map_memory2\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkMapMemory2KHR = unsafe extern "system" fn(
@@ -12781,9 +15445,25 @@ pub struct KhrMapMemory2Fn {
 unsafe impl Send for KhrMapMemory2Fn {}
 unsafe impl Sync for KhrMapMemory2Fn {}
 impl KhrMapMemory2Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             map_memory2_khr: unsafe {
@@ -12796,6 +15476,7 @@ impl KhrMapMemory2Fn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkMapMemory2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     map_memory2_khr
                 } else {
@@ -12811,6 +15492,7 @@ impl KhrMapMemory2Fn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkUnmapMemory2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     unmap_memory2_khr
                 } else {
@@ -12830,6 +15512,9 @@ impl ExtShaderAtomicFloat2Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_atomic_float2\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_atomic_float\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtShaderAtomicFloat2Fn;
@@ -12842,6 +15527,14 @@ impl ExtSurfaceMaintenance1Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_surface_maintenance1\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_surface_capabilities2\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtSurfaceMaintenance1Fn;
@@ -12856,6 +15549,19 @@ impl ExtSwapchainMaintenance1Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_swapchain_maintenance1\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_surface_maintenance1\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkReleaseSwapchainImagesEXT = unsafe extern "system" fn(
@@ -12869,9 +15575,25 @@ pub struct ExtSwapchainMaintenance1Fn {
 unsafe impl Send for ExtSwapchainMaintenance1Fn {}
 unsafe impl Sync for ExtSwapchainMaintenance1Fn {}
 impl ExtSwapchainMaintenance1Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             release_swapchain_images_ext: unsafe {
@@ -12888,6 +15610,7 @@ impl ExtSwapchainMaintenance1Fn {
                     b"vkReleaseSwapchainImagesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     release_swapchain_images_ext
                 } else {
@@ -12917,6 +15640,14 @@ impl ExtShaderDemoteToHelperInvocationFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtShaderDemoteToHelperInvocationFn;
@@ -12930,6 +15661,12 @@ impl NvDeviceGeneratedCommandsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_device_generated_commands\0")
     };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_buffer_device_address\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetGeneratedCommandsMemoryRequirementsNV = unsafe extern "system" fn(
@@ -12981,9 +15718,25 @@ pub struct NvDeviceGeneratedCommandsFn {
 unsafe impl Send for NvDeviceGeneratedCommandsFn {}
 unsafe impl Sync for NvDeviceGeneratedCommandsFn {}
 impl NvDeviceGeneratedCommandsFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_generated_commands_memory_requirements_nv: unsafe {
@@ -13001,6 +15754,7 @@ impl NvDeviceGeneratedCommandsFn {
                     b"vkGetGeneratedCommandsMemoryRequirementsNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_generated_commands_memory_requirements_nv
                 } else {
@@ -13021,6 +15775,7 @@ impl NvDeviceGeneratedCommandsFn {
                     b"vkCmdPreprocessGeneratedCommandsNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_preprocess_generated_commands_nv
                 } else {
@@ -13042,6 +15797,7 @@ impl NvDeviceGeneratedCommandsFn {
                     b"vkCmdExecuteGeneratedCommandsNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_execute_generated_commands_nv
                 } else {
@@ -13064,6 +15820,7 @@ impl NvDeviceGeneratedCommandsFn {
                     b"vkCmdBindPipelineShaderGroupNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_pipeline_shader_group_nv
                 } else {
@@ -13086,6 +15843,7 @@ impl NvDeviceGeneratedCommandsFn {
                     b"vkCreateIndirectCommandsLayoutNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_indirect_commands_layout_nv
                 } else {
@@ -13107,6 +15865,7 @@ impl NvDeviceGeneratedCommandsFn {
                     b"vkDestroyIndirectCommandsLayoutNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_indirect_commands_layout_nv
                 } else {
@@ -13149,6 +15908,9 @@ impl NvInheritedViewportScissorFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_inherited_viewport_scissor\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvInheritedViewportScissorFn;
@@ -13162,6 +15924,9 @@ impl KhrShaderIntegerDotProductFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_integer_dot_product\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrShaderIntegerDotProductFn;
@@ -13177,6 +15942,14 @@ impl ExtTexelBufferAlignmentFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_texel_buffer_alignment\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtTexelBufferAlignmentFn;
@@ -13192,6 +15965,14 @@ impl QcomRenderPassTransformFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_render_pass_transform\0")
     };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct QcomRenderPassTransformFn;
@@ -13209,6 +15990,9 @@ impl ExtDepthBiasControlFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_depth_bias_control\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetDepthBias2EXT = unsafe extern "system" fn(
@@ -13222,9 +16006,25 @@ pub struct ExtDepthBiasControlFn {
 unsafe impl Send for ExtDepthBiasControlFn {}
 unsafe impl Sync for ExtDepthBiasControlFn {}
 impl ExtDepthBiasControlFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_depth_bias2_ext: unsafe {
@@ -13240,6 +16040,7 @@ impl ExtDepthBiasControlFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBias2EXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_bias2_ext
                 } else {
@@ -13260,6 +16061,9 @@ impl ExtDeviceMemoryReportFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_device_memory_report\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtDeviceMemoryReportFn;
@@ -13273,6 +16077,9 @@ impl ExtAcquireDrmDisplayFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_acquire_drm_display\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_direct_mode_display\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkAcquireDrmDisplayEXT = unsafe extern "system" fn(
@@ -13295,9 +16102,25 @@ pub struct ExtAcquireDrmDisplayFn {
 unsafe impl Send for ExtAcquireDrmDisplayFn {}
 unsafe impl Sync for ExtAcquireDrmDisplayFn {}
 impl ExtAcquireDrmDisplayFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             acquire_drm_display_ext: unsafe {
@@ -13314,6 +16137,7 @@ impl ExtAcquireDrmDisplayFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAcquireDrmDisplayEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     acquire_drm_display_ext
                 } else {
@@ -13332,6 +16156,7 @@ impl ExtAcquireDrmDisplayFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetDrmDisplayEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_drm_display_ext
                 } else {
@@ -13345,6 +16170,14 @@ impl ExtRobustness2Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_robustness2\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtRobustness2Fn;
@@ -13357,6 +16190,14 @@ impl ExtCustomBorderColorFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_custom_border_color\0") };
     pub const SPEC_VERSION: u32 = 12u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtCustomBorderColorFn;
@@ -13375,6 +16216,7 @@ impl GoogleUserTypeFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_GOOGLE_user_type\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct GoogleUserTypeFn;
@@ -13382,6 +16224,7 @@ impl KhrPipelineLibraryFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_pipeline_library\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct KhrPipelineLibraryFn;
@@ -13397,6 +16240,22 @@ impl NvPresentBarrierFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_present_barrier\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_surface_capabilities2\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct NvPresentBarrierFn;
@@ -13411,6 +16270,7 @@ impl KhrShaderNonSemanticInfoFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_non_semantic_info\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct KhrShaderNonSemanticInfoFn;
@@ -13418,6 +16278,16 @@ impl KhrPresentIdFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_present_id\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct KhrPresentIdFn;
@@ -13430,6 +16300,9 @@ impl ExtPrivateDataFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_private_data\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreatePrivateDataSlot = unsafe extern "system" fn(
@@ -13470,9 +16343,25 @@ pub struct ExtPrivateDataFn {
 unsafe impl Send for ExtPrivateDataFn {}
 unsafe impl Sync for ExtPrivateDataFn {}
 impl ExtPrivateDataFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_private_data_slot_ext: unsafe {
@@ -13491,6 +16380,7 @@ impl ExtPrivateDataFn {
                     b"vkCreatePrivateDataSlotEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_private_data_slot_ext
                 } else {
@@ -13512,6 +16402,7 @@ impl ExtPrivateDataFn {
                     b"vkDestroyPrivateDataSlotEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_private_data_slot_ext
                 } else {
@@ -13531,6 +16422,7 @@ impl ExtPrivateDataFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkSetPrivateDataEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     set_private_data_ext
                 } else {
@@ -13550,6 +16442,7 @@ impl ExtPrivateDataFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetPrivateDataEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_private_data_ext
                 } else {
@@ -13575,6 +16468,9 @@ impl ExtPipelineCreationCacheControlFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_creation_cache_control\0")
     };
     pub const SPEC_VERSION: u32 = 3u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtPipelineCreationCacheControlFn;
@@ -13601,6 +16497,14 @@ impl KhrVideoEncodeQueueFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_encode_queue\0") };
     pub const SPEC_VERSION: u32 = 9u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_video_queue\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_synchronization2\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR =
@@ -13632,9 +16536,25 @@ pub struct KhrVideoEncodeQueueFn {
 unsafe impl Send for KhrVideoEncodeQueueFn {}
 unsafe impl Sync for KhrVideoEncodeQueueFn {}
 impl KhrVideoEncodeQueueFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_video_encode_quality_level_properties_khr: unsafe {
@@ -13652,6 +16572,7 @@ impl KhrVideoEncodeQueueFn {
                     b"vkGetPhysicalDeviceVideoEncodeQualityLevelPropertiesKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_video_encode_quality_level_properties_khr
                 } else {
@@ -13675,6 +16596,7 @@ impl KhrVideoEncodeQueueFn {
                     b"vkGetEncodedVideoSessionParametersKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_encoded_video_session_parameters_khr
                 } else {
@@ -13691,6 +16613,7 @@ impl KhrVideoEncodeQueueFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdEncodeVideoKHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_encode_video_khr
                 } else {
@@ -13777,6 +16700,9 @@ impl NvDeviceDiagnosticsConfigFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_device_diagnostics_config\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvDeviceDiagnosticsConfigFn;
@@ -13790,6 +16716,7 @@ impl QcomRenderPassStoreOpsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_render_pass_store_ops\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct QcomRenderPassStoreOpsFn;
@@ -13801,6 +16728,7 @@ impl NvLowLatencyFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_low_latency\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct NvLowLatencyFn;
@@ -13812,6 +16740,7 @@ impl ExtMetalObjectsFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_metal_objects\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkExportMetalObjectsEXT =
@@ -13823,9 +16752,25 @@ pub struct ExtMetalObjectsFn {
 unsafe impl Send for ExtMetalObjectsFn {}
 unsafe impl Sync for ExtMetalObjectsFn {}
 impl ExtMetalObjectsFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             export_metal_objects_ext: unsafe {
@@ -13841,6 +16786,7 @@ impl ExtMetalObjectsFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkExportMetalObjectsEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     export_metal_objects_ext
                 } else {
@@ -13869,6 +16815,14 @@ impl KhrSynchronization2Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_synchronization2\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetEvent2 = unsafe extern "system" fn(
@@ -13936,9 +16890,25 @@ pub struct KhrSynchronization2Fn {
 unsafe impl Send for KhrSynchronization2Fn {}
 unsafe impl Sync for KhrSynchronization2Fn {}
 impl KhrSynchronization2Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_event2_khr: unsafe {
@@ -13951,6 +16921,7 @@ impl KhrSynchronization2Fn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetEvent2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_event2_khr
                 } else {
@@ -13968,6 +16939,7 @@ impl KhrSynchronization2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdResetEvent2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_reset_event2_khr
                 } else {
@@ -13986,6 +16958,7 @@ impl KhrSynchronization2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdWaitEvents2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_wait_events2_khr
                 } else {
@@ -14005,6 +16978,7 @@ impl KhrSynchronization2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdPipelineBarrier2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_pipeline_barrier2_khr
                 } else {
@@ -14026,6 +17000,7 @@ impl KhrSynchronization2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteTimestamp2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_write_timestamp2_khr
                 } else {
@@ -14043,6 +17018,7 @@ impl KhrSynchronization2Fn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkQueueSubmit2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_submit2_khr
                 } else {
@@ -14066,6 +17042,7 @@ impl KhrSynchronization2Fn {
                     b"vkCmdWriteBufferMarker2AMD\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_write_buffer_marker2_amd
                 } else {
@@ -14087,6 +17064,7 @@ impl KhrSynchronization2Fn {
                     b"vkGetQueueCheckpointData2NV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_queue_checkpoint_data2_nv
                 } else {
@@ -14169,6 +17147,22 @@ impl ExtDescriptorBufferFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_descriptor_buffer\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_buffer_device_address\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_synchronization2\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_descriptor_indexing\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetDescriptorSetLayoutSizeEXT = unsafe extern "system" fn(
@@ -14266,9 +17260,25 @@ pub struct ExtDescriptorBufferFn {
 unsafe impl Send for ExtDescriptorBufferFn {}
 unsafe impl Sync for ExtDescriptorBufferFn {}
 impl ExtDescriptorBufferFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_descriptor_set_layout_size_ext: unsafe {
@@ -14286,6 +17296,7 @@ impl ExtDescriptorBufferFn {
                     b"vkGetDescriptorSetLayoutSizeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_descriptor_set_layout_size_ext
                 } else {
@@ -14308,6 +17319,7 @@ impl ExtDescriptorBufferFn {
                     b"vkGetDescriptorSetLayoutBindingOffsetEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_descriptor_set_layout_binding_offset_ext
                 } else {
@@ -14326,6 +17338,7 @@ impl ExtDescriptorBufferFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetDescriptorEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_descriptor_ext
                 } else {
@@ -14347,6 +17360,7 @@ impl ExtDescriptorBufferFn {
                     b"vkCmdBindDescriptorBuffersEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_descriptor_buffers_ext
                 } else {
@@ -14372,6 +17386,7 @@ impl ExtDescriptorBufferFn {
                     b"vkCmdSetDescriptorBufferOffsetsEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_descriptor_buffer_offsets_ext
                 } else {
@@ -14394,6 +17409,7 @@ impl ExtDescriptorBufferFn {
                     b"vkCmdBindDescriptorBufferEmbeddedSamplersEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_descriptor_buffer_embedded_samplers_ext
                 } else {
@@ -14415,6 +17431,7 @@ impl ExtDescriptorBufferFn {
                     b"vkGetBufferOpaqueCaptureDescriptorDataEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_buffer_opaque_capture_descriptor_data_ext
                 } else {
@@ -14436,6 +17453,7 @@ impl ExtDescriptorBufferFn {
                     b"vkGetImageOpaqueCaptureDescriptorDataEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_opaque_capture_descriptor_data_ext
                 } else {
@@ -14457,6 +17475,7 @@ impl ExtDescriptorBufferFn {
                     b"vkGetImageViewOpaqueCaptureDescriptorDataEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_view_opaque_capture_descriptor_data_ext
                 } else {
@@ -14478,6 +17497,7 @@ impl ExtDescriptorBufferFn {
                     b"vkGetSamplerOpaqueCaptureDescriptorDataEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_sampler_opaque_capture_descriptor_data_ext
                 } else {
@@ -14499,6 +17519,7 @@ impl ExtDescriptorBufferFn {
                     b"vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_acceleration_structure_opaque_capture_descriptor_data_ext
                 } else {
@@ -14572,6 +17593,16 @@ impl ExtGraphicsPipelineLibraryFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_graphics_pipeline_library\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_pipeline_library\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtGraphicsPipelineLibraryFn;
@@ -14597,6 +17628,9 @@ impl AmdShaderEarlyAndLateFragmentTestsFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct AmdShaderEarlyAndLateFragmentTestsFn;
@@ -14610,6 +17644,9 @@ impl KhrFragmentShaderBarycentricFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_fragment_shader_barycentric\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrFragmentShaderBarycentricFn;
@@ -14626,6 +17663,7 @@ impl KhrShaderSubgroupUniformControlFlowFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Version(crate::vk::API_VERSION_1_1);
 }
 #[derive(Clone)]
 pub struct KhrShaderSubgroupUniformControlFlowFn;
@@ -14641,6 +17679,9 @@ impl KhrZeroInitializeWorkgroupMemoryFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrZeroInitializeWorkgroupMemoryFn;
@@ -14654,6 +17695,9 @@ impl NvFragmentShadingRateEnumsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_fragment_shading_rate_enums\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_fragment_shading_rate\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetFragmentShadingRateEnumNV = unsafe extern "system" fn(
@@ -14668,9 +17712,25 @@ pub struct NvFragmentShadingRateEnumsFn {
 unsafe impl Send for NvFragmentShadingRateEnumsFn {}
 unsafe impl Sync for NvFragmentShadingRateEnumsFn {}
 impl NvFragmentShadingRateEnumsFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_fragment_shading_rate_enum_nv: unsafe {
@@ -14688,6 +17748,7 @@ impl NvFragmentShadingRateEnumsFn {
                     b"vkCmdSetFragmentShadingRateEnumNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_fragment_shading_rate_enum_nv
                 } else {
@@ -14708,6 +17769,9 @@ impl NvRayTracingMotionBlurFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_ray_tracing_motion_blur\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_pipeline\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvRayTracingMotionBlurFn;
@@ -14733,6 +17797,9 @@ impl ExtMeshShaderFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_mesh_shader\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_spirv_1_4\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdDrawMeshTasksEXT = unsafe extern "system" fn(
@@ -14768,9 +17835,25 @@ pub struct ExtMeshShaderFn {
 unsafe impl Send for ExtMeshShaderFn {}
 unsafe impl Sync for ExtMeshShaderFn {}
 impl ExtMeshShaderFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_draw_mesh_tasks_ext: unsafe {
@@ -14788,6 +17871,7 @@ impl ExtMeshShaderFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMeshTasksEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_mesh_tasks_ext
                 } else {
@@ -14811,6 +17895,7 @@ impl ExtMeshShaderFn {
                     b"vkCmdDrawMeshTasksIndirectEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_mesh_tasks_indirect_ext
                 } else {
@@ -14836,6 +17921,7 @@ impl ExtMeshShaderFn {
                     b"vkCmdDrawMeshTasksIndirectCountEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_mesh_tasks_indirect_count_ext
                 } else {
@@ -14878,6 +17964,12 @@ impl ExtYcbcr2plane444FormatsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_ycbcr_2plane_444_formats\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_sampler_ycbcr_conversion\0")
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtYcbcr2plane444FormatsFn;
@@ -14899,6 +17991,9 @@ impl ExtFragmentDensityMap2Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_fragment_density_map2\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_fragment_density_map\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtFragmentDensityMap2Fn;
@@ -14916,6 +18011,14 @@ impl QcomRotatedCopyCommandsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_rotated_copy_commands\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_copy_commands2\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct QcomRotatedCopyCommandsFn;
@@ -14927,6 +18030,14 @@ impl ExtImageRobustnessFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_robustness\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtImageRobustnessFn;
@@ -14942,6 +18053,9 @@ impl KhrWorkgroupMemoryExplicitLayoutFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrWorkgroupMemoryExplicitLayoutFn;
@@ -14954,6 +18068,14 @@ impl KhrCopyCommands2Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_copy_commands2\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdCopyBuffer2 = unsafe extern "system" fn(
@@ -14997,9 +18119,25 @@ pub struct KhrCopyCommands2Fn {
 unsafe impl Send for KhrCopyCommands2Fn {}
 unsafe impl Sync for KhrCopyCommands2Fn {}
 impl KhrCopyCommands2Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_copy_buffer2_khr: unsafe {
@@ -15012,6 +18150,7 @@ impl KhrCopyCommands2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBuffer2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_buffer2_khr
                 } else {
@@ -15028,6 +18167,7 @@ impl KhrCopyCommands2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImage2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_image2_khr
                 } else {
@@ -15048,6 +18188,7 @@ impl KhrCopyCommands2Fn {
                     b"vkCmdCopyBufferToImage2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_buffer_to_image2_khr
                 } else {
@@ -15068,6 +18209,7 @@ impl KhrCopyCommands2Fn {
                     b"vkCmdCopyImageToBuffer2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_image_to_buffer2_khr
                 } else {
@@ -15084,6 +18226,7 @@ impl KhrCopyCommands2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBlitImage2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_blit_image2_khr
                 } else {
@@ -15103,6 +18246,7 @@ impl KhrCopyCommands2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdResolveImage2KHR\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_resolve_image2_khr
                 } else {
@@ -15131,6 +18275,9 @@ impl ExtImageCompressionControlFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_compression_control\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetImageSubresourceLayout2EXT = unsafe extern "system" fn(
@@ -15146,9 +18293,25 @@ pub struct ExtImageCompressionControlFn {
 unsafe impl Send for ExtImageCompressionControlFn {}
 unsafe impl Sync for ExtImageCompressionControlFn {}
 impl ExtImageCompressionControlFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_image_subresource_layout2_ext: unsafe {
@@ -15167,6 +18330,7 @@ impl ExtImageCompressionControlFn {
                     b"vkGetImageSubresourceLayout2EXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_subresource_layout2_ext
                 } else {
@@ -15193,6 +18357,9 @@ impl ExtAttachmentFeedbackLoopLayoutFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_attachment_feedback_loop_layout\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtAttachmentFeedbackLoopLayoutFn;
@@ -15224,6 +18391,14 @@ impl Ext4444FormatsFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_4444_formats\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[derive(Clone)]
 pub struct Ext4444FormatsFn;
@@ -15240,6 +18415,9 @@ impl ExtDeviceFaultFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_device_fault\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetDeviceFaultInfoEXT = unsafe extern "system" fn(
@@ -15254,9 +18432,25 @@ pub struct ExtDeviceFaultFn {
 unsafe impl Send for ExtDeviceFaultFn {}
 unsafe impl Sync for ExtDeviceFaultFn {}
 impl ExtDeviceFaultFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_device_fault_info_ext: unsafe {
@@ -15273,6 +18467,7 @@ impl ExtDeviceFaultFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceFaultInfoEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_fault_info_ext
                 } else {
@@ -15295,6 +18490,9 @@ impl ArmRasterizationOrderAttachmentAccessFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ArmRasterizationOrderAttachmentAccessFn;
@@ -15328,6 +18526,9 @@ impl ExtRgba10x6FormatsFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_rgba10x6_formats\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_sampler_ycbcr_conversion\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtRgba10x6FormatsFn;
@@ -15340,6 +18541,9 @@ impl NvAcquireWinrtDisplayFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_acquire_winrt_display\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_direct_mode_display\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkAcquireWinrtDisplayNV =
@@ -15358,9 +18562,25 @@ pub struct NvAcquireWinrtDisplayFn {
 unsafe impl Send for NvAcquireWinrtDisplayFn {}
 unsafe impl Sync for NvAcquireWinrtDisplayFn {}
 impl NvAcquireWinrtDisplayFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             acquire_winrt_display_nv: unsafe {
@@ -15376,6 +18596,7 @@ impl NvAcquireWinrtDisplayFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAcquireWinrtDisplayNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     acquire_winrt_display_nv
                 } else {
@@ -15393,6 +18614,7 @@ impl NvAcquireWinrtDisplayFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetWinrtDisplayNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_winrt_display_nv
                 } else {
@@ -15406,6 +18628,9 @@ impl ExtDirectfbSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_directfb_surface\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateDirectFBSurfaceEXT = unsafe extern "system" fn(
@@ -15430,9 +18655,25 @@ pub struct ExtDirectfbSurfaceFn {
 unsafe impl Send for ExtDirectfbSurfaceFn {}
 unsafe impl Sync for ExtDirectfbSurfaceFn {}
 impl ExtDirectfbSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_direct_fb_surface_ext: unsafe {
@@ -15451,6 +18692,7 @@ impl ExtDirectfbSurfaceFn {
                     b"vkCreateDirectFBSurfaceEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_direct_fb_surface_ext
                 } else {
@@ -15472,6 +18714,7 @@ impl ExtDirectfbSurfaceFn {
                     b"vkGetPhysicalDeviceDirectFBPresentationSupportEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_direct_fb_presentation_support_ext
                 } else {
@@ -15490,6 +18733,9 @@ impl ValveMutableDescriptorTypeFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_VALVE_mutable_descriptor_type\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance3\0")
+    });
 }
 #[derive(Clone)]
 pub struct ValveMutableDescriptorTypeFn;
@@ -15517,6 +18763,14 @@ impl ExtVertexInputDynamicStateFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_vertex_input_dynamic_state\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetVertexInputEXT = unsafe extern "system" fn(
@@ -15533,9 +18787,25 @@ pub struct ExtVertexInputDynamicStateFn {
 unsafe impl Send for ExtVertexInputDynamicStateFn {}
 unsafe impl Sync for ExtVertexInputDynamicStateFn {}
 impl ExtVertexInputDynamicStateFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_vertex_input_ext: unsafe {
@@ -15554,6 +18824,7 @@ impl ExtVertexInputDynamicStateFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetVertexInputEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_vertex_input_ext
                 } else {
@@ -15577,6 +18848,9 @@ impl ExtPhysicalDeviceDrmFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_physical_device_drm\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtPhysicalDeviceDrmFn;
@@ -15589,6 +18863,16 @@ impl ExtDeviceAddressBindingReportFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_device_address_binding_report\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_debug_utils\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtDeviceAddressBindingReportFn;
@@ -15605,6 +18889,9 @@ impl ExtDepthClipControlFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_depth_clip_control\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtDepthClipControlFn;
@@ -15618,6 +18905,9 @@ impl ExtPrimitiveTopologyListRestartFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_primitive_topology_list_restart\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtPrimitiveTopologyListRestartFn;
@@ -15631,6 +18921,9 @@ impl KhrFormatFeatureFlags2Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_format_feature_flags2\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrFormatFeatureFlags2Fn;
@@ -15642,6 +18935,16 @@ impl FuchsiaExternalMemoryFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_FUCHSIA_external_memory\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_external_memory_capabilities\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetMemoryZirconHandleFUCHSIA = unsafe extern "system" fn(
@@ -15664,9 +18967,25 @@ pub struct FuchsiaExternalMemoryFn {
 unsafe impl Send for FuchsiaExternalMemoryFn {}
 unsafe impl Sync for FuchsiaExternalMemoryFn {}
 impl FuchsiaExternalMemoryFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_memory_zircon_handle_fuchsia: unsafe {
@@ -15684,6 +19003,7 @@ impl FuchsiaExternalMemoryFn {
                     b"vkGetMemoryZirconHandleFUCHSIA\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_memory_zircon_handle_fuchsia
                 } else {
@@ -15706,6 +19026,7 @@ impl FuchsiaExternalMemoryFn {
                     b"vkGetMemoryZirconHandlePropertiesFUCHSIA\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_memory_zircon_handle_properties_fuchsia
                 } else {
@@ -15730,6 +19051,16 @@ impl FuchsiaExternalSemaphoreFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_FUCHSIA_external_semaphore\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_external_semaphore_capabilities\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_semaphore\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkImportSemaphoreZirconHandleFUCHSIA = unsafe extern "system" fn(
@@ -15750,9 +19081,25 @@ pub struct FuchsiaExternalSemaphoreFn {
 unsafe impl Send for FuchsiaExternalSemaphoreFn {}
 unsafe impl Sync for FuchsiaExternalSemaphoreFn {}
 impl FuchsiaExternalSemaphoreFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             import_semaphore_zircon_handle_fuchsia: unsafe {
@@ -15769,6 +19116,7 @@ impl FuchsiaExternalSemaphoreFn {
                     b"vkImportSemaphoreZirconHandleFUCHSIA\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     import_semaphore_zircon_handle_fuchsia
                 } else {
@@ -15790,6 +19138,7 @@ impl FuchsiaExternalSemaphoreFn {
                     b"vkGetSemaphoreZirconHandleFUCHSIA\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_semaphore_zircon_handle_fuchsia
                 } else {
@@ -15813,6 +19162,14 @@ impl FuchsiaBufferCollectionFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_FUCHSIA_buffer_collection\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_FUCHSIA_external_memory\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_sampler_ycbcr_conversion\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateBufferCollectionFUCHSIA = unsafe extern "system" fn(
@@ -15858,9 +19215,25 @@ pub struct FuchsiaBufferCollectionFn {
 unsafe impl Send for FuchsiaBufferCollectionFn {}
 unsafe impl Sync for FuchsiaBufferCollectionFn {}
 impl FuchsiaBufferCollectionFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_buffer_collection_fuchsia: unsafe {
@@ -15879,6 +19252,7 @@ impl FuchsiaBufferCollectionFn {
                     b"vkCreateBufferCollectionFUCHSIA\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_buffer_collection_fuchsia
                 } else {
@@ -15900,6 +19274,7 @@ impl FuchsiaBufferCollectionFn {
                     b"vkSetBufferCollectionImageConstraintsFUCHSIA\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     set_buffer_collection_image_constraints_fuchsia
                 } else {
@@ -15921,6 +19296,7 @@ impl FuchsiaBufferCollectionFn {
                     b"vkSetBufferCollectionBufferConstraintsFUCHSIA\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     set_buffer_collection_buffer_constraints_fuchsia
                 } else {
@@ -15942,6 +19318,7 @@ impl FuchsiaBufferCollectionFn {
                     b"vkDestroyBufferCollectionFUCHSIA\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_buffer_collection_fuchsia
                 } else {
@@ -15963,6 +19340,7 @@ impl FuchsiaBufferCollectionFn {
                     b"vkGetBufferCollectionPropertiesFUCHSIA\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_buffer_collection_properties_fuchsia
                 } else {
@@ -15998,6 +19376,14 @@ impl HuaweiSubpassShadingFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_HUAWEI_subpass_shading\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_create_renderpass2\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_synchronization2\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI = unsafe extern "system" fn(
@@ -16016,9 +19402,25 @@ pub struct HuaweiSubpassShadingFn {
 unsafe impl Send for HuaweiSubpassShadingFn {}
 unsafe impl Sync for HuaweiSubpassShadingFn {}
 impl HuaweiSubpassShadingFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_device_subpass_shading_max_workgroup_size_huawei: unsafe {
@@ -16036,6 +19438,7 @@ impl HuaweiSubpassShadingFn {
                     b"vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_subpass_shading_max_workgroup_size_huawei
                 } else {
@@ -16054,6 +19457,7 @@ impl HuaweiSubpassShadingFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSubpassShadingHUAWEI\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_subpass_shading_huawei
                 } else {
@@ -16086,6 +19490,14 @@ impl HuaweiInvocationMaskFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_HUAWEI_invocation_mask\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_pipeline\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_synchronization2\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdBindInvocationMaskHUAWEI = unsafe extern "system" fn(
@@ -16100,9 +19512,25 @@ pub struct HuaweiInvocationMaskFn {
 unsafe impl Send for HuaweiInvocationMaskFn {}
 unsafe impl Sync for HuaweiInvocationMaskFn {}
 impl HuaweiInvocationMaskFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_bind_invocation_mask_huawei: unsafe {
@@ -16120,6 +19548,7 @@ impl HuaweiInvocationMaskFn {
                     b"vkCmdBindInvocationMaskHUAWEI\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_invocation_mask_huawei
                 } else {
@@ -16151,6 +19580,9 @@ impl NvExternalMemoryRdmaFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_external_memory_rdma\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetMemoryRemoteAddressNV = unsafe extern "system" fn(
@@ -16165,9 +19597,25 @@ pub struct NvExternalMemoryRdmaFn {
 unsafe impl Send for NvExternalMemoryRdmaFn {}
 unsafe impl Sync for NvExternalMemoryRdmaFn {}
 impl NvExternalMemoryRdmaFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_memory_remote_address_nv: unsafe {
@@ -16185,6 +19633,7 @@ impl NvExternalMemoryRdmaFn {
                     b"vkGetMemoryRemoteAddressNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_memory_remote_address_nv
                 } else {
@@ -16211,6 +19660,9 @@ impl ExtPipelinePropertiesFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_properties\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 #[doc = "Implemented for all types that can be passed as argument to `pipeline_properties` in [`PFN_vkGetPipelinePropertiesEXT`]"]
@@ -16232,9 +19684,25 @@ pub struct ExtPipelinePropertiesFn {
 unsafe impl Send for ExtPipelinePropertiesFn {}
 unsafe impl Sync for ExtPipelinePropertiesFn {}
 impl ExtPipelinePropertiesFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_pipeline_properties_ext: unsafe {
@@ -16252,6 +19720,7 @@ impl ExtPipelinePropertiesFn {
                     b"vkGetPipelinePropertiesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_pipeline_properties_ext
                 } else {
@@ -16274,6 +19743,14 @@ impl ExtMultisampledRenderToSingleSampledFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_create_renderpass2\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_depth_stencil_resolve\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtMultisampledRenderToSingleSampledFn;
@@ -16293,6 +19770,14 @@ impl ExtExtendedDynamicState2Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_extended_dynamic_state2\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetPatchControlPointsEXT =
@@ -16320,9 +19805,25 @@ pub struct ExtExtendedDynamicState2Fn {
 unsafe impl Send for ExtExtendedDynamicState2Fn {}
 unsafe impl Sync for ExtExtendedDynamicState2Fn {}
 impl ExtExtendedDynamicState2Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_patch_control_points_ext: unsafe {
@@ -16339,6 +19840,7 @@ impl ExtExtendedDynamicState2Fn {
                     b"vkCmdSetPatchControlPointsEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_patch_control_points_ext
                 } else {
@@ -16359,6 +19861,7 @@ impl ExtExtendedDynamicState2Fn {
                     b"vkCmdSetRasterizerDiscardEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_rasterizer_discard_enable_ext
                 } else {
@@ -16379,6 +19882,7 @@ impl ExtExtendedDynamicState2Fn {
                     b"vkCmdSetDepthBiasEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_bias_enable_ext
                 } else {
@@ -16395,6 +19899,7 @@ impl ExtExtendedDynamicState2Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLogicOpEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_logic_op_ext
                 } else {
@@ -16415,6 +19920,7 @@ impl ExtExtendedDynamicState2Fn {
                     b"vkCmdSetPrimitiveRestartEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_primitive_restart_enable_ext
                 } else {
@@ -16443,6 +19949,9 @@ impl QnxScreenSurfaceFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QNX_screen_surface\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateScreenSurfaceQNX = unsafe extern "system" fn(
@@ -16466,9 +19975,25 @@ pub struct QnxScreenSurfaceFn {
 unsafe impl Send for QnxScreenSurfaceFn {}
 unsafe impl Sync for QnxScreenSurfaceFn {}
 impl QnxScreenSurfaceFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_screen_surface_qnx: unsafe {
@@ -16486,6 +20011,7 @@ impl QnxScreenSurfaceFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateScreenSurfaceQNX\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_screen_surface_qnx
                 } else {
@@ -16507,6 +20033,7 @@ impl QnxScreenSurfaceFn {
                     b"vkGetPhysicalDeviceScreenPresentationSupportQNX\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_screen_presentation_support_qnx
                 } else {
@@ -16524,6 +20051,14 @@ impl ExtColorWriteEnableFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_color_write_enable\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Any(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetColorWriteEnableEXT = unsafe extern "system" fn(
@@ -16538,9 +20073,25 @@ pub struct ExtColorWriteEnableFn {
 unsafe impl Send for ExtColorWriteEnableFn {}
 unsafe impl Sync for ExtColorWriteEnableFn {}
 impl ExtColorWriteEnableFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_color_write_enable_ext: unsafe {
@@ -16558,6 +20109,7 @@ impl ExtColorWriteEnableFn {
                     b"vkCmdSetColorWriteEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_color_write_enable_ext
                 } else {
@@ -16581,6 +20133,9 @@ impl ExtPrimitivesGeneratedQueryFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_primitives_generated_query\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_transform_feedback\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtPrimitivesGeneratedQueryFn;
@@ -16597,6 +20152,9 @@ impl KhrRayTracingMaintenance1Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_maintenance1\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_acceleration_structure\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdTraceRaysIndirect2KHR = unsafe extern "system" fn(
@@ -16610,9 +20168,25 @@ pub struct KhrRayTracingMaintenance1Fn {
 unsafe impl Send for KhrRayTracingMaintenance1Fn {}
 unsafe impl Sync for KhrRayTracingMaintenance1Fn {}
 impl KhrRayTracingMaintenance1Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_trace_rays_indirect2_khr: unsafe {
@@ -16629,6 +20203,7 @@ impl KhrRayTracingMaintenance1Fn {
                     b"vkCmdTraceRaysIndirect2KHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_trace_rays_indirect2_khr
                 } else {
@@ -16662,6 +20237,16 @@ impl ExtGlobalPriorityQueryFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_global_priority_query\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_global_priority\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtGlobalPriorityQueryFn;
@@ -16676,6 +20261,9 @@ impl ExtImageViewMinLodFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_view_min_lod\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtImageViewMinLodFn;
@@ -16688,6 +20276,9 @@ impl ExtMultiDrawFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_multi_draw\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdDrawMultiEXT = unsafe extern "system" fn(
@@ -16716,9 +20307,25 @@ pub struct ExtMultiDrawFn {
 unsafe impl Send for ExtMultiDrawFn {}
 unsafe impl Sync for ExtMultiDrawFn {}
 impl ExtMultiDrawFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_draw_multi_ext: unsafe {
@@ -16734,6 +20341,7 @@ impl ExtMultiDrawFn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMultiEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_multi_ext
                 } else {
@@ -16758,6 +20366,7 @@ impl ExtMultiDrawFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawMultiIndexedEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_multi_indexed_ext
                 } else {
@@ -16776,6 +20385,16 @@ impl ExtImage2dViewOf3dFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_2d_view_of_3d\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance1\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtImage2dViewOf3dFn;
@@ -16793,6 +20412,7 @@ impl KhrPortabilityEnumerationFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_portability_enumeration\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct KhrPortabilityEnumerationFn;
@@ -16804,6 +20424,7 @@ impl ExtShaderTileImageFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_tile_image\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Version(crate::vk::API_VERSION_1_3);
 }
 #[derive(Clone)]
 pub struct ExtShaderTileImageFn;
@@ -16816,6 +20437,14 @@ impl ExtOpacityMicromapFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_opacity_micromap\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_acceleration_structure\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_synchronization2\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateMicromapEXT = unsafe extern "system" fn(
@@ -16926,9 +20555,25 @@ pub struct ExtOpacityMicromapFn {
 unsafe impl Send for ExtOpacityMicromapFn {}
 unsafe impl Sync for ExtOpacityMicromapFn {}
 impl ExtOpacityMicromapFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_micromap_ext: unsafe {
@@ -16943,6 +20588,7 @@ impl ExtOpacityMicromapFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateMicromapEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_micromap_ext
                 } else {
@@ -16960,6 +20606,7 @@ impl ExtOpacityMicromapFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyMicromapEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_micromap_ext
                 } else {
@@ -16980,6 +20627,7 @@ impl ExtOpacityMicromapFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBuildMicromapsEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_build_micromaps_ext
                 } else {
@@ -16998,6 +20646,7 @@ impl ExtOpacityMicromapFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkBuildMicromapsEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     build_micromaps_ext
                 } else {
@@ -17014,6 +20663,7 @@ impl ExtOpacityMicromapFn {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCopyMicromapEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     copy_micromap_ext
                 } else {
@@ -17034,6 +20684,7 @@ impl ExtOpacityMicromapFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCopyMicromapToMemoryEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     copy_micromap_to_memory_ext
                 } else {
@@ -17054,6 +20705,7 @@ impl ExtOpacityMicromapFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCopyMemoryToMicromapEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     copy_memory_to_micromap_ext
                 } else {
@@ -17079,6 +20731,7 @@ impl ExtOpacityMicromapFn {
                     b"vkWriteMicromapsPropertiesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     write_micromaps_properties_ext
                 } else {
@@ -17098,6 +20751,7 @@ impl ExtOpacityMicromapFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMicromapEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_micromap_ext
                 } else {
@@ -17118,6 +20772,7 @@ impl ExtOpacityMicromapFn {
                     b"vkCmdCopyMicromapToMemoryEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_micromap_to_memory_ext
                 } else {
@@ -17138,6 +20793,7 @@ impl ExtOpacityMicromapFn {
                     b"vkCmdCopyMemoryToMicromapEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_memory_to_micromap_ext
                 } else {
@@ -17162,6 +20818,7 @@ impl ExtOpacityMicromapFn {
                     b"vkCmdWriteMicromapsPropertiesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_write_micromaps_properties_ext
                 } else {
@@ -17183,6 +20840,7 @@ impl ExtOpacityMicromapFn {
                     b"vkGetDeviceMicromapCompatibilityEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_micromap_compatibility_ext
                 } else {
@@ -17205,6 +20863,7 @@ impl ExtOpacityMicromapFn {
                     b"vkGetMicromapBuildSizesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_micromap_build_sizes_ext
                 } else {
@@ -17272,6 +20931,9 @@ impl NvDisplacementMicromapFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_displacement_micromap\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_opacity_micromap\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvDisplacementMicromapFn;
@@ -17298,6 +20960,7 @@ impl ExtLoadStoreOpNoneFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_load_store_op_none\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct ExtLoadStoreOpNoneFn;
@@ -17314,6 +20977,9 @@ impl HuaweiClusterCullingShaderFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_HUAWEI_cluster_culling_shader\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdDrawClusterHUAWEI = unsafe extern "system" fn(
@@ -17333,9 +20999,25 @@ pub struct HuaweiClusterCullingShaderFn {
 unsafe impl Send for HuaweiClusterCullingShaderFn {}
 unsafe impl Sync for HuaweiClusterCullingShaderFn {}
 impl HuaweiClusterCullingShaderFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_draw_cluster_huawei: unsafe {
@@ -17353,6 +21035,7 @@ impl HuaweiClusterCullingShaderFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawClusterHUAWEI\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_cluster_huawei
                 } else {
@@ -17374,6 +21057,7 @@ impl HuaweiClusterCullingShaderFn {
                     b"vkCmdDrawClusterIndirectHUAWEI\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_cluster_indirect_huawei
                 } else {
@@ -17406,6 +21090,9 @@ impl ExtBorderColorSwizzleFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_border_color_swizzle\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_custom_border_color\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtBorderColorSwizzleFn;
@@ -17419,6 +21106,9 @@ impl ExtPageableDeviceLocalMemoryFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pageable_device_local_memory\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_memory_priority\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkSetDeviceMemoryPriorityEXT =
@@ -17430,9 +21120,25 @@ pub struct ExtPageableDeviceLocalMemoryFn {
 unsafe impl Send for ExtPageableDeviceLocalMemoryFn {}
 unsafe impl Sync for ExtPageableDeviceLocalMemoryFn {}
 impl ExtPageableDeviceLocalMemoryFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             set_device_memory_priority_ext: unsafe {
@@ -17450,6 +21156,7 @@ impl ExtPageableDeviceLocalMemoryFn {
                     b"vkSetDeviceMemoryPriorityEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     set_device_memory_priority_ext
                 } else {
@@ -17467,6 +21174,7 @@ impl KhrMaintenance4Fn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance4\0") };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Version(crate::vk::API_VERSION_1_1);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetDeviceBufferMemoryRequirements = unsafe extern "system" fn(
@@ -17497,9 +21205,25 @@ pub struct KhrMaintenance4Fn {
 unsafe impl Send for KhrMaintenance4Fn {}
 unsafe impl Sync for KhrMaintenance4Fn {}
 impl KhrMaintenance4Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_device_buffer_memory_requirements_khr: unsafe {
@@ -17517,6 +21241,7 @@ impl KhrMaintenance4Fn {
                     b"vkGetDeviceBufferMemoryRequirementsKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_buffer_memory_requirements_khr
                 } else {
@@ -17538,6 +21263,7 @@ impl KhrMaintenance4Fn {
                     b"vkGetDeviceImageMemoryRequirementsKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_image_memory_requirements_khr
                 } else {
@@ -17560,6 +21286,7 @@ impl KhrMaintenance4Fn {
                     b"vkGetDeviceImageSparseMemoryRequirementsKHR\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_image_sparse_memory_requirements_khr
                 } else {
@@ -17587,6 +21314,7 @@ impl ArmShaderCorePropertiesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_ARM_shader_core_properties\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Version(crate::vk::API_VERSION_1_1);
 }
 #[derive(Clone)]
 pub struct ArmShaderCorePropertiesFn;
@@ -17599,6 +21327,16 @@ impl ExtImageSlicedViewOf3dFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_sliced_view_of_3d\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance1\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtImageSlicedViewOf3dFn;
@@ -17612,6 +21350,9 @@ impl ValveDescriptorSetHostMappingFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_VALVE_descriptor_set_host_mapping\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetDescriptorSetLayoutHostMappingInfoVALVE = unsafe extern "system" fn(
@@ -17634,9 +21375,25 @@ pub struct ValveDescriptorSetHostMappingFn {
 unsafe impl Send for ValveDescriptorSetHostMappingFn {}
 unsafe impl Sync for ValveDescriptorSetHostMappingFn {}
 impl ValveDescriptorSetHostMappingFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_descriptor_set_layout_host_mapping_info_valve: unsafe {
@@ -17654,6 +21411,7 @@ impl ValveDescriptorSetHostMappingFn {
                     b"vkGetDescriptorSetLayoutHostMappingInfoVALVE\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_descriptor_set_layout_host_mapping_info_valve
                 } else {
@@ -17675,6 +21433,7 @@ impl ValveDescriptorSetHostMappingFn {
                     b"vkGetDescriptorSetHostMappingVALVE\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_descriptor_set_host_mapping_valve
                 } else {
@@ -17696,6 +21455,9 @@ impl ExtDepthClampZeroOneFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_depth_clamp_zero_one\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtDepthClampZeroOneFn;
@@ -17708,6 +21470,9 @@ impl ExtNonSeamlessCubeMapFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_non_seamless_cube_map\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtNonSeamlessCubeMapFn;
@@ -17724,6 +21489,16 @@ impl QcomFragmentDensityMapOffsetFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_fragment_density_map_offset\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_fragment_density_map\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct QcomFragmentDensityMapOffsetFn;
@@ -17742,6 +21517,16 @@ impl NvCopyMemoryIndirectFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_copy_memory_indirect\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_buffer_device_address\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdCopyMemoryIndirectNV = unsafe extern "system" fn(
@@ -17768,9 +21553,25 @@ pub struct NvCopyMemoryIndirectFn {
 unsafe impl Send for NvCopyMemoryIndirectFn {}
 unsafe impl Sync for NvCopyMemoryIndirectFn {}
 impl NvCopyMemoryIndirectFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_copy_memory_indirect_nv: unsafe {
@@ -17788,6 +21589,7 @@ impl NvCopyMemoryIndirectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyMemoryIndirectNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_memory_indirect_nv
                 } else {
@@ -17813,6 +21615,7 @@ impl NvCopyMemoryIndirectFn {
                     b"vkCmdCopyMemoryToImageIndirectNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_memory_to_image_indirect_nv
                 } else {
@@ -17831,6 +21634,16 @@ impl NvMemoryDecompressionFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_memory_decompression\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_buffer_device_address\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdDecompressMemoryNV = unsafe extern "system" fn(
@@ -17853,9 +21666,25 @@ pub struct NvMemoryDecompressionFn {
 unsafe impl Send for NvMemoryDecompressionFn {}
 unsafe impl Sync for NvMemoryDecompressionFn {}
 impl NvMemoryDecompressionFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_decompress_memory_nv: unsafe {
@@ -17872,6 +21701,7 @@ impl NvMemoryDecompressionFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDecompressMemoryNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_decompress_memory_nv
                 } else {
@@ -17894,6 +21724,7 @@ impl NvMemoryDecompressionFn {
                     b"vkCmdDecompressMemoryIndirectCountNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_decompress_memory_indirect_count_nv
                 } else {
@@ -17913,6 +21744,9 @@ impl NvLinearColorAttachmentFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_linear_color_attachment\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvLinearColorAttachmentFn;
@@ -17931,6 +21765,9 @@ impl GoogleSurfacelessQueryFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_GOOGLE_surfaceless_query\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0")
+    });
 }
 #[derive(Clone)]
 pub struct GoogleSurfacelessQueryFn;
@@ -17941,6 +21778,9 @@ impl ExtImageCompressionControlSwapchainFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_image_compression_control\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtImageCompressionControlSwapchainFn;
@@ -17953,6 +21793,9 @@ impl QcomImageProcessingFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_image_processing\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_format_feature_flags2\0")
+    });
 }
 #[derive(Clone)]
 pub struct QcomImageProcessingFn;
@@ -17992,6 +21835,9 @@ impl ExtExternalMemoryAcquireUnmodifiedFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtExternalMemoryAcquireUnmodifiedFn;
@@ -18004,6 +21850,9 @@ impl ExtExtendedDynamicState3Fn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_extended_dynamic_state3\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetTessellationDomainOriginEXT = unsafe extern "system" fn(
@@ -18183,9 +22032,25 @@ pub struct ExtExtendedDynamicState3Fn {
 unsafe impl Send for ExtExtendedDynamicState3Fn {}
 unsafe impl Sync for ExtExtendedDynamicState3Fn {}
 impl ExtExtendedDynamicState3Fn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_tessellation_domain_origin_ext: unsafe {
@@ -18202,6 +22067,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetTessellationDomainOriginEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_tessellation_domain_origin_ext
                 } else {
@@ -18222,6 +22088,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetDepthClampEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_clamp_enable_ext
                 } else {
@@ -18241,6 +22108,7 @@ impl ExtExtendedDynamicState3Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPolygonModeEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_polygon_mode_ext
                 } else {
@@ -18261,6 +22129,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetRasterizationSamplesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_rasterization_samples_ext
                 } else {
@@ -18281,6 +22150,7 @@ impl ExtExtendedDynamicState3Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetSampleMaskEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_sample_mask_ext
                 } else {
@@ -18301,6 +22171,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetAlphaToCoverageEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_alpha_to_coverage_enable_ext
                 } else {
@@ -18321,6 +22192,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetAlphaToOneEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_alpha_to_one_enable_ext
                 } else {
@@ -18340,6 +22212,7 @@ impl ExtExtendedDynamicState3Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLogicOpEnableEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_logic_op_enable_ext
                 } else {
@@ -18362,6 +22235,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetColorBlendEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_color_blend_enable_ext
                 } else {
@@ -18384,6 +22258,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetColorBlendEquationEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_color_blend_equation_ext
                 } else {
@@ -18405,6 +22280,7 @@ impl ExtExtendedDynamicState3Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorWriteMaskEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_color_write_mask_ext
                 } else {
@@ -18425,6 +22301,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetRasterizationStreamEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_rasterization_stream_ext
                 } else {
@@ -18445,6 +22322,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetConservativeRasterizationModeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_conservative_rasterization_mode_ext
                 } else {
@@ -18465,6 +22343,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetExtraPrimitiveOverestimationSizeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_extra_primitive_overestimation_size_ext
                 } else {
@@ -18485,6 +22364,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetDepthClipEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_clip_enable_ext
                 } else {
@@ -18505,6 +22385,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetSampleLocationsEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_sample_locations_enable_ext
                 } else {
@@ -18527,6 +22408,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetColorBlendAdvancedEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_color_blend_advanced_ext
                 } else {
@@ -18547,6 +22429,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetProvokingVertexModeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_provoking_vertex_mode_ext
                 } else {
@@ -18567,6 +22450,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetLineRasterizationModeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_line_rasterization_mode_ext
                 } else {
@@ -18587,6 +22471,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetLineStippleEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_line_stipple_enable_ext
                 } else {
@@ -18607,6 +22492,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetDepthClipNegativeOneToOneEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_clip_negative_one_to_one_ext
                 } else {
@@ -18627,6 +22513,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetViewportWScalingEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_viewport_w_scaling_enable_nv
                 } else {
@@ -18648,6 +22535,7 @@ impl ExtExtendedDynamicState3Fn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportSwizzleNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_viewport_swizzle_nv
                 } else {
@@ -18668,6 +22556,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetCoverageToColorEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_to_color_enable_nv
                 } else {
@@ -18688,6 +22577,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetCoverageToColorLocationNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_to_color_location_nv
                 } else {
@@ -18708,6 +22598,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetCoverageModulationModeNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_modulation_mode_nv
                 } else {
@@ -18728,6 +22619,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetCoverageModulationTableEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_modulation_table_enable_nv
                 } else {
@@ -18749,6 +22641,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetCoverageModulationTableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_modulation_table_nv
                 } else {
@@ -18769,6 +22662,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetShadingRateImageEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_shading_rate_image_enable_nv
                 } else {
@@ -18789,6 +22683,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetRepresentativeFragmentTestEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_representative_fragment_test_enable_nv
                 } else {
@@ -18809,6 +22704,7 @@ impl ExtExtendedDynamicState3Fn {
                     b"vkCmdSetCoverageReductionModeNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_reduction_mode_nv
                 } else {
@@ -18862,6 +22758,9 @@ impl ExtSubpassMergeFeedbackFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_subpass_merge_feedback\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtSubpassMergeFeedbackFn;
@@ -18877,6 +22776,7 @@ impl LunargDirectDriverLoadingFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_LUNARG_direct_driver_loading\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct LunargDirectDriverLoadingFn;
@@ -18890,6 +22790,18 @@ impl ExtShaderModuleIdentifierFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_module_identifier\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_EXT_pipeline_creation_cache_control\0",
+            )
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetShaderModuleIdentifierEXT = unsafe extern "system" fn(
@@ -18911,9 +22823,25 @@ pub struct ExtShaderModuleIdentifierFn {
 unsafe impl Send for ExtShaderModuleIdentifierFn {}
 unsafe impl Sync for ExtShaderModuleIdentifierFn {}
 impl ExtShaderModuleIdentifierFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_shader_module_identifier_ext: unsafe {
@@ -18931,6 +22859,7 @@ impl ExtShaderModuleIdentifierFn {
                     b"vkGetShaderModuleIdentifierEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_shader_module_identifier_ext
                 } else {
@@ -18952,6 +22881,7 @@ impl ExtShaderModuleIdentifierFn {
                     b"vkGetShaderModuleCreateInfoIdentifierEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_shader_module_create_info_identifier_ext
                 } else {
@@ -18975,6 +22905,9 @@ impl ExtRasterizationOrderAttachmentAccessFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtRasterizationOrderAttachmentAccessFn;
@@ -19002,6 +22935,19 @@ impl NvOpticalFlowFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_optical_flow\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_format_feature_flags2\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_synchronization2\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetPhysicalDeviceOpticalFlowImageFormatsNV = unsafe extern "system" fn(
@@ -19049,9 +22995,25 @@ pub struct NvOpticalFlowFn {
 unsafe impl Send for NvOpticalFlowFn {}
 unsafe impl Sync for NvOpticalFlowFn {}
 impl NvOpticalFlowFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_optical_flow_image_formats_nv: unsafe {
@@ -19070,6 +23032,7 @@ impl NvOpticalFlowFn {
                     b"vkGetPhysicalDeviceOpticalFlowImageFormatsNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_optical_flow_image_formats_nv
                 } else {
@@ -19092,6 +23055,7 @@ impl NvOpticalFlowFn {
                     b"vkCreateOpticalFlowSessionNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_optical_flow_session_nv
                 } else {
@@ -19113,6 +23077,7 @@ impl NvOpticalFlowFn {
                     b"vkDestroyOpticalFlowSessionNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_optical_flow_session_nv
                 } else {
@@ -19136,6 +23101,7 @@ impl NvOpticalFlowFn {
                     b"vkBindOpticalFlowSessionImageNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     bind_optical_flow_session_image_nv
                 } else {
@@ -19156,6 +23122,7 @@ impl NvOpticalFlowFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdOpticalFlowExecuteNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_optical_flow_execute_nv
                 } else {
@@ -19211,6 +23178,9 @@ impl ExtLegacyDitheringFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_legacy_dithering\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtLegacyDitheringFn;
@@ -19231,6 +23201,9 @@ impl ExtPipelineProtectedAccessFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_protected_access\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtPipelineProtectedAccessFn;
@@ -19248,6 +23221,9 @@ impl KhrRayTracingPositionFetchFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_position_fetch\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_acceleration_structure\0")
+    });
 }
 #[derive(Clone)]
 pub struct KhrRayTracingPositionFetchFn;
@@ -19263,6 +23239,22 @@ impl ExtShaderObjectFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_shader_object\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                    b"VK_KHR_get_physical_device_properties2\0",
+                )
+            }),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        ]),
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_dynamic_rendering\0")
+            }),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        ]),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCreateShadersEXT = unsafe extern "system" fn(
@@ -19356,9 +23348,25 @@ pub struct ExtShaderObjectFn {
 unsafe impl Send for ExtShaderObjectFn {}
 unsafe impl Sync for ExtShaderObjectFn {}
 impl ExtShaderObjectFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_shaders_ext: unsafe {
@@ -19374,6 +23382,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateShadersEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_shaders_ext
                 } else {
@@ -19391,6 +23400,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyShaderEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_shader_ext
                 } else {
@@ -19412,6 +23422,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetShaderBinaryDataEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_shader_binary_data_ext
                 } else {
@@ -19430,6 +23441,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBindShadersEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_shaders_ext
                 } else {
@@ -19449,6 +23461,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCullModeEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_cull_mode_ext
                 } else {
@@ -19468,6 +23481,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetFrontFaceEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_front_face_ext
                 } else {
@@ -19488,6 +23502,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetPrimitiveTopologyEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_primitive_topology_ext
                 } else {
@@ -19509,6 +23524,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetViewportWithCountEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_viewport_with_count_ext
                 } else {
@@ -19530,6 +23546,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetScissorWithCountEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_scissor_with_count_ext
                 } else {
@@ -19555,6 +23572,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdBindVertexBuffers2EXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_vertex_buffers2_ext
                 } else {
@@ -19575,6 +23593,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetDepthTestEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_test_enable_ext
                 } else {
@@ -19595,6 +23614,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetDepthWriteEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_write_enable_ext
                 } else {
@@ -19614,6 +23634,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthCompareOpEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_compare_op_ext
                 } else {
@@ -19634,6 +23655,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetDepthBoundsTestEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_bounds_test_enable_ext
                 } else {
@@ -19654,6 +23676,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetStencilTestEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_stencil_test_enable_ext
                 } else {
@@ -19677,6 +23700,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilOpEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_stencil_op_ext
                 } else {
@@ -19699,6 +23723,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetVertexInputEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_vertex_input_ext
                 } else {
@@ -19719,6 +23744,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetPatchControlPointsEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_patch_control_points_ext
                 } else {
@@ -19739,6 +23765,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetRasterizerDiscardEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_rasterizer_discard_enable_ext
                 } else {
@@ -19759,6 +23786,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetDepthBiasEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_bias_enable_ext
                 } else {
@@ -19775,6 +23803,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLogicOpEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_logic_op_ext
                 } else {
@@ -19795,6 +23824,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetPrimitiveRestartEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_primitive_restart_enable_ext
                 } else {
@@ -19815,6 +23845,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetTessellationDomainOriginEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_tessellation_domain_origin_ext
                 } else {
@@ -19835,6 +23866,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetDepthClampEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_clamp_enable_ext
                 } else {
@@ -19854,6 +23886,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPolygonModeEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_polygon_mode_ext
                 } else {
@@ -19874,6 +23907,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetRasterizationSamplesEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_rasterization_samples_ext
                 } else {
@@ -19894,6 +23928,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetSampleMaskEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_sample_mask_ext
                 } else {
@@ -19914,6 +23949,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetAlphaToCoverageEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_alpha_to_coverage_enable_ext
                 } else {
@@ -19934,6 +23970,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetAlphaToOneEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_alpha_to_one_enable_ext
                 } else {
@@ -19953,6 +23990,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLogicOpEnableEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_logic_op_enable_ext
                 } else {
@@ -19975,6 +24013,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetColorBlendEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_color_blend_enable_ext
                 } else {
@@ -19997,6 +24036,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetColorBlendEquationEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_color_blend_equation_ext
                 } else {
@@ -20018,6 +24058,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetColorWriteMaskEXT\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_color_write_mask_ext
                 } else {
@@ -20038,6 +24079,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetRasterizationStreamEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_rasterization_stream_ext
                 } else {
@@ -20058,6 +24100,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetConservativeRasterizationModeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_conservative_rasterization_mode_ext
                 } else {
@@ -20078,6 +24121,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetExtraPrimitiveOverestimationSizeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_extra_primitive_overestimation_size_ext
                 } else {
@@ -20098,6 +24142,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetDepthClipEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_clip_enable_ext
                 } else {
@@ -20118,6 +24163,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetSampleLocationsEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_sample_locations_enable_ext
                 } else {
@@ -20140,6 +24186,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetColorBlendAdvancedEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_color_blend_advanced_ext
                 } else {
@@ -20160,6 +24207,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetProvokingVertexModeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_provoking_vertex_mode_ext
                 } else {
@@ -20180,6 +24228,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetLineRasterizationModeEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_line_rasterization_mode_ext
                 } else {
@@ -20200,6 +24249,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetLineStippleEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_line_stipple_enable_ext
                 } else {
@@ -20220,6 +24270,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetDepthClipNegativeOneToOneEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_clip_negative_one_to_one_ext
                 } else {
@@ -20240,6 +24291,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetViewportWScalingEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_viewport_w_scaling_enable_nv
                 } else {
@@ -20261,6 +24313,7 @@ impl ExtShaderObjectFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportSwizzleNV\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_viewport_swizzle_nv
                 } else {
@@ -20281,6 +24334,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetCoverageToColorEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_to_color_enable_nv
                 } else {
@@ -20301,6 +24355,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetCoverageToColorLocationNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_to_color_location_nv
                 } else {
@@ -20321,6 +24376,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetCoverageModulationModeNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_modulation_mode_nv
                 } else {
@@ -20341,6 +24397,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetCoverageModulationTableEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_modulation_table_enable_nv
                 } else {
@@ -20362,6 +24419,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetCoverageModulationTableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_modulation_table_nv
                 } else {
@@ -20382,6 +24440,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetShadingRateImageEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_shading_rate_image_enable_nv
                 } else {
@@ -20402,6 +24461,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetRepresentativeFragmentTestEnableNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_representative_fragment_test_enable_nv
                 } else {
@@ -20422,6 +24482,7 @@ impl ExtShaderObjectFn {
                     b"vkCmdSetCoverageReductionModeNV\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_coverage_reduction_mode_nv
                 } else {
@@ -20460,6 +24521,9 @@ impl QcomTilePropertiesFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_tile_properties\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetFramebufferTilePropertiesQCOM = unsafe extern "system" fn(
@@ -20482,9 +24546,25 @@ pub struct QcomTilePropertiesFn {
 unsafe impl Send for QcomTilePropertiesFn {}
 unsafe impl Sync for QcomTilePropertiesFn {}
 impl QcomTilePropertiesFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_framebuffer_tile_properties_qcom: unsafe {
@@ -20503,6 +24583,7 @@ impl QcomTilePropertiesFn {
                     b"vkGetFramebufferTilePropertiesQCOM\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_framebuffer_tile_properties_qcom
                 } else {
@@ -20524,6 +24605,7 @@ impl QcomTilePropertiesFn {
                     b"vkGetDynamicRenderingTilePropertiesQCOM\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_dynamic_rendering_tile_properties_qcom
                 } else {
@@ -20542,6 +24624,9 @@ impl SecAmigoProfilingFn {
     pub const NAME: &'static ::std::ffi::CStr =
         unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_SEC_amigo_profiling\0") };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct SecAmigoProfilingFn;
@@ -20555,6 +24640,9 @@ impl QcomMultiviewPerViewViewportsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QCOM_multiview_per_view_viewports\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct QcomMultiviewPerViewViewportsFn;
@@ -20568,6 +24656,9 @@ impl NvRayTracingInvocationReorderFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_NV_ray_tracing_invocation_reorder\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_pipeline\0")
+    });
 }
 #[derive(Clone)]
 pub struct NvRayTracingInvocationReorderFn;
@@ -20583,6 +24674,9 @@ impl ExtMutableDescriptorTypeFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_mutable_descriptor_type\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_maintenance3\0")
+    });
 }
 #[derive(Clone)]
 pub struct ExtMutableDescriptorTypeFn;
@@ -20608,6 +24702,9 @@ impl ArmShaderCoreBuiltinsFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_ARM_shader_core_builtins\0")
     };
     pub const SPEC_VERSION: u32 = 2u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::Extension(unsafe {
+        ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0")
+    });
 }
 #[derive(Clone)]
 pub struct ArmShaderCoreBuiltinsFn;
@@ -20621,6 +24718,14 @@ impl ExtPipelineLibraryGroupHandlesFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_pipeline_library_group_handles\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_ray_tracing_pipeline\0")
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_pipeline_library\0")
+        }),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtPipelineLibraryGroupHandlesFn;
@@ -20636,6 +24741,22 @@ impl ExtDynamicRenderingUnusedAttachmentsFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                    b"VK_KHR_get_physical_device_properties2\0",
+                )
+            }),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        ]),
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::Extension(unsafe {
+                ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_dynamic_rendering\0")
+            }),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_3),
+        ]),
+    ]);
 }
 #[derive(Clone)]
 pub struct ExtDynamicRenderingUnusedAttachmentsFn;
@@ -20651,6 +24772,7 @@ impl QcomMultiviewPerViewRenderAreasFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::NONE;
 }
 #[derive(Clone)]
 pub struct QcomMultiviewPerViewRenderAreasFn;
@@ -20668,6 +24790,18 @@ impl ExtAttachmentFeedbackLoopDynamicStateFn {
         )
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_KHR_get_physical_device_properties2\0",
+            )
+        }),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"VK_EXT_attachment_feedback_loop_layout\0",
+            )
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkCmdSetAttachmentFeedbackLoopEnableEXT =
@@ -20679,9 +24813,25 @@ pub struct ExtAttachmentFeedbackLoopDynamicStateFn {
 unsafe impl Send for ExtAttachmentFeedbackLoopDynamicStateFn {}
 unsafe impl Sync for ExtAttachmentFeedbackLoopDynamicStateFn {}
 impl ExtAttachmentFeedbackLoopDynamicStateFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_set_attachment_feedback_loop_enable_ext: unsafe {
@@ -20698,6 +24848,7 @@ impl ExtAttachmentFeedbackLoopDynamicStateFn {
                     b"vkCmdSetAttachmentFeedbackLoopEnableEXT\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_attachment_feedback_loop_enable_ext
                 } else {
@@ -20721,6 +24872,29 @@ impl QnxExternalMemoryScreenBufferFn {
         ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_QNX_external_memory_screen_buffer\0")
     };
     pub const SPEC_VERSION: u32 = 1u32;
+    pub const DEPENDS: crate::vk::Depends = crate::vk::Depends::All(&[
+        crate::vk::Depends::Any(&[
+            crate::vk::Depends::All(&[
+                crate::vk::Depends::Extension(unsafe {
+                    ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                        b"VK_KHR_sampler_ycbcr_conversion\0",
+                    )
+                }),
+                crate::vk::Depends::Extension(unsafe {
+                    ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_external_memory\0")
+                }),
+                crate::vk::Depends::Extension(unsafe {
+                    ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                        b"VK_KHR_dedicated_allocation\0",
+                    )
+                }),
+            ]),
+            crate::vk::Depends::Version(crate::vk::API_VERSION_1_1),
+        ]),
+        crate::vk::Depends::Extension(unsafe {
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_queue_family_foreign\0")
+        }),
+    ]);
 }
 #[allow(non_camel_case_types)]
 pub type PFN_vkGetScreenBufferPropertiesQNX = unsafe extern "system" fn(
@@ -20735,9 +24909,25 @@ pub struct QnxExternalMemoryScreenBufferFn {
 unsafe impl Send for QnxExternalMemoryScreenBufferFn {}
 unsafe impl Sync for QnxExternalMemoryScreenBufferFn {}
 impl QnxExternalMemoryScreenBufferFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_screen_buffer_properties_qnx: unsafe {
@@ -20755,6 +24945,7 @@ impl QnxExternalMemoryScreenBufferFn {
                     b"vkGetScreenBufferPropertiesQNX\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_screen_buffer_properties_qnx
                 } else {
@@ -12,9 +12,25 @@ pub struct StaticFn {
 unsafe impl Send for StaticFn {}
 unsafe impl Sync for StaticFn {}
 impl StaticFn {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_instance_proc_addr: unsafe {
@@ -30,6 +46,7 @@ impl StaticFn {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetInstanceProcAddr\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_instance_proc_addr
                 } else {
@@ -65,9 +82,25 @@ pub struct EntryFnV1_0 {
 unsafe impl Send for EntryFnV1_0 {}
 unsafe impl Sync for EntryFnV1_0 {}
 impl EntryFnV1_0 {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_instance: unsafe {
@@ -80,6 +113,7 @@ impl EntryFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateInstance\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_instance
                 } else {
@@ -101,6 +135,7 @@ impl EntryFnV1_0 {
                     b"vkEnumerateInstanceExtensionProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     enumerate_instance_extension_properties
                 } else {
@@ -121,6 +156,7 @@ impl EntryFnV1_0 {
                     b"vkEnumerateInstanceLayerProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     enumerate_instance_layer_properties
                 } else {
@@ -230,9 +266,25 @@ pub struct InstanceFnV1_0 {
 unsafe impl Send for InstanceFnV1_0 {}
 unsafe impl Sync for InstanceFnV1_0 {}
 impl InstanceFnV1_0 {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             destroy_instance: unsafe {
@@ -244,6 +296,7 @@ impl InstanceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyInstance\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_instance
                 } else {
@@ -265,6 +318,7 @@ impl InstanceFnV1_0 {
                     b"vkEnumeratePhysicalDevices\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     enumerate_physical_devices
                 } else {
@@ -285,6 +339,7 @@ impl InstanceFnV1_0 {
                     b"vkGetPhysicalDeviceFeatures\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_features
                 } else {
@@ -306,6 +361,7 @@ impl InstanceFnV1_0 {
                     b"vkGetPhysicalDeviceFormatProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_format_properties
                 } else {
@@ -331,6 +387,7 @@ impl InstanceFnV1_0 {
                     b"vkGetPhysicalDeviceImageFormatProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_image_format_properties
                 } else {
@@ -351,6 +408,7 @@ impl InstanceFnV1_0 {
                     b"vkGetPhysicalDeviceProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_properties
                 } else {
@@ -372,6 +430,7 @@ impl InstanceFnV1_0 {
                     b"vkGetPhysicalDeviceQueueFamilyProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_queue_family_properties
                 } else {
@@ -392,6 +451,7 @@ impl InstanceFnV1_0 {
                     b"vkGetPhysicalDeviceMemoryProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_memory_properties
                 } else {
@@ -408,6 +468,7 @@ impl InstanceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceProcAddr\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_proc_addr
                 } else {
@@ -425,6 +486,7 @@ impl InstanceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateDevice\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_device
                 } else {
@@ -447,6 +509,7 @@ impl InstanceFnV1_0 {
                     b"vkEnumerateDeviceExtensionProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     enumerate_device_extension_properties
                 } else {
@@ -468,6 +531,7 @@ impl InstanceFnV1_0 {
                     b"vkEnumerateDeviceLayerProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     enumerate_device_layer_properties
                 } else {
@@ -494,6 +558,7 @@ impl InstanceFnV1_0 {
                     b"vkGetPhysicalDeviceSparseImageFormatProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_sparse_image_format_properties
                 } else {
@@ -1418,9 +1483,25 @@ pub struct DeviceFnV1_0 {
 unsafe impl Send for DeviceFnV1_0 {}
 unsafe impl Sync for DeviceFnV1_0 {}
 impl DeviceFnV1_0 {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             destroy_device: unsafe {
@@ -1432,6 +1513,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyDevice\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_device
                 } else {
@@ -1449,6 +1531,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceQueue\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_queue
                 } else {
@@ -1466,6 +1549,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkQueueSubmit\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_submit
                 } else {
@@ -1478,6 +1562,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkQueueWaitIdle\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_wait_idle
                 } else {
@@ -1490,6 +1575,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDeviceWaitIdle\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     device_wait_idle
                 } else {
@@ -1507,6 +1593,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAllocateMemory\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     allocate_memory
                 } else {
@@ -1523,6 +1610,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkFreeMemory\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     free_memory
                 } else {
@@ -1542,6 +1630,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkMapMemory\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     map_memory
                 } else {
@@ -1554,6 +1643,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkUnmapMemory\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     unmap_memory
                 } else {
@@ -1574,6 +1664,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkFlushMappedMemoryRanges\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     flush_mapped_memory_ranges
                 } else {
@@ -1595,6 +1686,7 @@ impl DeviceFnV1_0 {
                     b"vkInvalidateMappedMemoryRanges\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     invalidate_mapped_memory_ranges
                 } else {
@@ -1616,6 +1708,7 @@ impl DeviceFnV1_0 {
                     b"vkGetDeviceMemoryCommitment\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_memory_commitment
                 } else {
@@ -1634,6 +1727,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkBindBufferMemory\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     bind_buffer_memory
                 } else {
@@ -1651,6 +1745,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkBindImageMemory\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     bind_image_memory
                 } else {
@@ -1672,6 +1767,7 @@ impl DeviceFnV1_0 {
                     b"vkGetBufferMemoryRequirements\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_buffer_memory_requirements
                 } else {
@@ -1693,6 +1789,7 @@ impl DeviceFnV1_0 {
                     b"vkGetImageMemoryRequirements\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_memory_requirements
                 } else {
@@ -1715,6 +1812,7 @@ impl DeviceFnV1_0 {
                     b"vkGetImageSparseMemoryRequirements\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_sparse_memory_requirements
                 } else {
@@ -1732,6 +1830,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkQueueBindSparse\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_bind_sparse
                 } else {
@@ -1749,6 +1848,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateFence\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_fence
                 } else {
@@ -1765,6 +1865,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyFence\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_fence
                 } else {
@@ -1781,6 +1882,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkResetFences\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     reset_fences
                 } else {
@@ -1796,6 +1898,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetFenceStatus\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_fence_status
                 } else {
@@ -1814,6 +1917,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkWaitForFences\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     wait_for_fences
                 } else {
@@ -1831,6 +1935,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateSemaphore\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_semaphore
                 } else {
@@ -1848,6 +1953,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroySemaphore\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_semaphore
                 } else {
@@ -1865,6 +1971,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateEvent\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_event
                 } else {
@@ -1881,6 +1988,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyEvent\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_event
                 } else {
@@ -1896,6 +2004,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetEventStatus\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_event_status
                 } else {
@@ -1908,6 +2017,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkSetEvent\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     set_event
                 } else {
@@ -1920,6 +2030,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkResetEvent\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     reset_event
                 } else {
@@ -1937,6 +2048,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateQueryPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_query_pool
                 } else {
@@ -1954,6 +2066,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyQueryPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_query_pool
                 } else {
@@ -1979,6 +2092,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetQueryPoolResults\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_query_pool_results
                 } else {
@@ -1996,6 +2110,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateBuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_buffer
                 } else {
@@ -2012,6 +2127,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyBuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_buffer
                 } else {
@@ -2030,6 +2146,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateBufferView\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_buffer_view
                 } else {
@@ -2047,6 +2164,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyBufferView\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_buffer_view
                 } else {
@@ -2064,6 +2182,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateImage\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_image
                 } else {
@@ -2080,6 +2199,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyImage\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_image
                 } else {
@@ -2102,6 +2222,7 @@ impl DeviceFnV1_0 {
                     b"vkGetImageSubresourceLayout\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_subresource_layout
                 } else {
@@ -2119,6 +2240,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateImageView\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_image_view
                 } else {
@@ -2136,6 +2258,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyImageView\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_image_view
                 } else {
@@ -2154,6 +2277,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateShaderModule\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_shader_module
                 } else {
@@ -2174,6 +2298,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyShaderModule\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_shader_module
                 } else {
@@ -2195,6 +2320,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreatePipelineCache\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_pipeline_cache
                 } else {
@@ -2215,6 +2341,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyPipelineCache\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_pipeline_cache
                 } else {
@@ -2236,6 +2363,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetPipelineCacheData\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_pipeline_cache_data
                 } else {
@@ -2257,6 +2385,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkMergePipelineCaches\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     merge_pipeline_caches
                 } else {
@@ -2280,6 +2409,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateGraphicsPipelines\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_graphics_pipelines
                 } else {
@@ -2303,6 +2433,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateComputePipelines\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_compute_pipelines
                 } else {
@@ -2319,6 +2450,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyPipeline\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_pipeline
                 } else {
@@ -2340,6 +2472,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreatePipelineLayout\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_pipeline_layout
                 } else {
@@ -2360,6 +2493,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyPipelineLayout\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_pipeline_layout
                 } else {
@@ -2377,6 +2511,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateSampler\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_sampler
                 } else {
@@ -2393,6 +2528,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroySampler\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_sampler
                 } else {
@@ -2415,6 +2551,7 @@ impl DeviceFnV1_0 {
                     b"vkCreateDescriptorSetLayout\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_descriptor_set_layout
                 } else {
@@ -2436,6 +2573,7 @@ impl DeviceFnV1_0 {
                     b"vkDestroyDescriptorSetLayout\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_descriptor_set_layout
                 } else {
@@ -2457,6 +2595,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateDescriptorPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_descriptor_pool
                 } else {
@@ -2477,6 +2616,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyDescriptorPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_descriptor_pool
                 } else {
@@ -2497,6 +2637,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkResetDescriptorPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     reset_descriptor_pool
                 } else {
@@ -2517,6 +2658,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAllocateDescriptorSets\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     allocate_descriptor_sets
                 } else {
@@ -2535,6 +2677,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkFreeDescriptorSets\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     free_descriptor_sets
                 } else {
@@ -2557,6 +2700,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkUpdateDescriptorSets\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     update_descriptor_sets
                 } else {
@@ -2575,6 +2719,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateFramebuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_framebuffer
                 } else {
@@ -2592,6 +2737,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyFramebuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_framebuffer
                 } else {
@@ -2610,6 +2756,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateRenderPass\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_render_pass
                 } else {
@@ -2627,6 +2774,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyRenderPass\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_render_pass
                 } else {
@@ -2648,6 +2796,7 @@ impl DeviceFnV1_0 {
                     b"vkGetRenderAreaGranularity\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_render_area_granularity
                 } else {
@@ -2666,6 +2815,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateCommandPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_command_pool
                 } else {
@@ -2683,6 +2833,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyCommandPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_command_pool
                 } else {
@@ -2700,6 +2851,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkResetCommandPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     reset_command_pool
                 } else {
@@ -2720,6 +2872,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkAllocateCommandBuffers\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     allocate_command_buffers
                 } else {
@@ -2738,6 +2891,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkFreeCommandBuffers\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     free_command_buffers
                 } else {
@@ -2754,6 +2908,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkBeginCommandBuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     begin_command_buffer
                 } else {
@@ -2769,6 +2924,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkEndCommandBuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     end_command_buffer
                 } else {
@@ -2785,6 +2941,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkResetCommandBuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     reset_command_buffer
                 } else {
@@ -2801,6 +2958,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBindPipeline\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_pipeline
                 } else {
@@ -2818,6 +2976,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewport\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_viewport
                 } else {
@@ -2835,6 +2994,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetScissor\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_scissor
                 } else {
@@ -2850,6 +3010,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetLineWidth\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_line_width
                 } else {
@@ -2867,6 +3028,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBias\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_bias
                 } else {
@@ -2886,6 +3048,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetBlendConstants\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_blend_constants
                 } else {
@@ -2903,6 +3066,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBounds\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_bounds
                 } else {
@@ -2924,6 +3088,7 @@ impl DeviceFnV1_0 {
                     b"vkCmdSetStencilCompareMask\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_stencil_compare_mask
                 } else {
@@ -2944,6 +3109,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilWriteMask\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_stencil_write_mask
                 } else {
@@ -2964,6 +3130,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilReference\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_stencil_reference
                 } else {
@@ -2989,6 +3156,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBindDescriptorSets\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_descriptor_sets
                 } else {
@@ -3010,6 +3178,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBindIndexBuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_index_buffer
                 } else {
@@ -3032,6 +3201,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBindVertexBuffers\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_vertex_buffers
                 } else {
@@ -3050,6 +3220,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDraw\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw
                 } else {
@@ -3069,6 +3240,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexed\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_indexed
                 } else {
@@ -3087,6 +3259,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirect\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_indirect
                 } else {
@@ -3109,6 +3282,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndexedIndirect\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_indexed_indirect
                 } else {
@@ -3126,6 +3300,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatch\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_dispatch
                 } else {
@@ -3146,6 +3321,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatchIndirect\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_dispatch_indirect
                 } else {
@@ -3164,6 +3340,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_buffer
                 } else {
@@ -3184,6 +3361,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImage\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_image
                 } else {
@@ -3205,6 +3383,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBlitImage\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_blit_image
                 } else {
@@ -3228,6 +3407,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBufferToImage\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_buffer_to_image
                 } else {
@@ -3251,6 +3431,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImageToBuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_image_to_buffer
                 } else {
@@ -3269,6 +3450,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdUpdateBuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_update_buffer
                 } else {
@@ -3287,6 +3469,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdFillBuffer\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_fill_buffer
                 } else {
@@ -3310,6 +3493,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdClearColorImage\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_clear_color_image
                 } else {
@@ -3334,6 +3518,7 @@ impl DeviceFnV1_0 {
                     b"vkCmdClearDepthStencilImage\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_clear_depth_stencil_image
                 } else {
@@ -3356,6 +3541,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdClearAttachments\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_clear_attachments
                 } else {
@@ -3376,6 +3562,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdResolveImage\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_resolve_image
                 } else {
@@ -3392,6 +3579,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetEvent\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_event
                 } else {
@@ -3408,6 +3596,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdResetEvent\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_reset_event
                 } else {
@@ -3432,6 +3621,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdWaitEvents\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_wait_events
                 } else {
@@ -3456,6 +3646,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdPipelineBarrier\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_pipeline_barrier
                 } else {
@@ -3473,6 +3664,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginQuery\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_query
                 } else {
@@ -3489,6 +3681,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdEndQuery\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_query
                 } else {
@@ -3507,6 +3700,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdResetQueryPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_reset_query_pool
                 } else {
@@ -3525,6 +3719,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteTimestamp\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_write_timestamp
                 } else {
@@ -3550,6 +3745,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyQueryPoolResults\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_query_pool_results
                 } else {
@@ -3570,6 +3766,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdPushConstants\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_push_constants
                 } else {
@@ -3590,6 +3787,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRenderPass\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_render_pass
                 } else {
@@ -3605,6 +3803,7 @@ impl DeviceFnV1_0 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdNextSubpass\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_next_subpass
                 } else {
@@ -3618,6 +3817,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRenderPass\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_render_pass
                 } else {
@@ -3635,6 +3835,7 @@ impl DeviceFnV1_0 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdExecuteCommands\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_execute_commands
                 } else {
@@ -3654,9 +3855,25 @@ pub struct EntryFnV1_1 {
 unsafe impl Send for EntryFnV1_1 {}
 unsafe impl Sync for EntryFnV1_1 {}
 impl EntryFnV1_1 {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             enumerate_instance_version: unsafe {
@@ -3672,6 +3889,7 @@ impl EntryFnV1_1 {
                     b"vkEnumerateInstanceVersion\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     enumerate_instance_version
                 } else {
@@ -3704,9 +3922,25 @@ pub struct InstanceFnV1_1 {
 unsafe impl Send for InstanceFnV1_1 {}
 unsafe impl Sync for InstanceFnV1_1 {}
 impl InstanceFnV1_1 {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             enumerate_physical_device_groups: unsafe {
@@ -3724,6 +3958,7 @@ impl InstanceFnV1_1 {
                     b"vkEnumeratePhysicalDeviceGroups\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     enumerate_physical_device_groups
                 } else {
@@ -3744,6 +3979,7 @@ impl InstanceFnV1_1 {
                     b"vkGetPhysicalDeviceFeatures2\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_features2
                 } else {
@@ -3764,6 +4000,7 @@ impl InstanceFnV1_1 {
                     b"vkGetPhysicalDeviceProperties2\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_properties2
                 } else {
@@ -3785,6 +4022,7 @@ impl InstanceFnV1_1 {
                     b"vkGetPhysicalDeviceFormatProperties2\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_format_properties2
                 } else {
@@ -3806,6 +4044,7 @@ impl InstanceFnV1_1 {
                     b"vkGetPhysicalDeviceImageFormatProperties2\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_image_format_properties2
                 } else {
@@ -3827,6 +4066,7 @@ impl InstanceFnV1_1 {
                     b"vkGetPhysicalDeviceQueueFamilyProperties2\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_queue_family_properties2
                 } else {
@@ -3847,6 +4087,7 @@ impl InstanceFnV1_1 {
                     b"vkGetPhysicalDeviceMemoryProperties2\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_memory_properties2
                 } else {
@@ -3869,6 +4110,7 @@ impl InstanceFnV1_1 {
                     b"vkGetPhysicalDeviceSparseImageFormatProperties2\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_sparse_image_format_properties2
                 } else {
@@ -3890,6 +4132,7 @@ impl InstanceFnV1_1 {
                     b"vkGetPhysicalDeviceExternalBufferProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_external_buffer_properties
                 } else {
@@ -3911,6 +4154,7 @@ impl InstanceFnV1_1 {
                     b"vkGetPhysicalDeviceExternalFenceProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_external_fence_properties
                 } else {
@@ -3932,6 +4176,7 @@ impl InstanceFnV1_1 {
                     b"vkGetPhysicalDeviceExternalSemaphoreProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_external_semaphore_properties
                 } else {
@@ -3969,9 +4214,25 @@ pub struct DeviceFnV1_1 {
 unsafe impl Send for DeviceFnV1_1 {}
 unsafe impl Sync for DeviceFnV1_1 {}
 impl DeviceFnV1_1 {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             bind_buffer_memory2: unsafe {
@@ -3985,6 +4246,7 @@ impl DeviceFnV1_1 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkBindBufferMemory2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     bind_buffer_memory2
                 } else {
@@ -4002,6 +4264,7 @@ impl DeviceFnV1_1 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkBindImageMemory2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     bind_image_memory2
                 } else {
@@ -4025,6 +4288,7 @@ impl DeviceFnV1_1 {
                     b"vkGetDeviceGroupPeerMemoryFeatures\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_group_peer_memory_features
                 } else {
@@ -4041,6 +4305,7 @@ impl DeviceFnV1_1 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDeviceMask\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_device_mask
                 } else {
@@ -4061,6 +4326,7 @@ impl DeviceFnV1_1 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDispatchBase\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_dispatch_base
                 } else {
@@ -4082,6 +4348,7 @@ impl DeviceFnV1_1 {
                     b"vkGetImageMemoryRequirements2\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_memory_requirements2
                 } else {
@@ -4103,6 +4370,7 @@ impl DeviceFnV1_1 {
                     b"vkGetBufferMemoryRequirements2\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_buffer_memory_requirements2
                 } else {
@@ -4125,6 +4393,7 @@ impl DeviceFnV1_1 {
                     b"vkGetImageSparseMemoryRequirements2\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_image_sparse_memory_requirements2
                 } else {
@@ -4141,6 +4410,7 @@ impl DeviceFnV1_1 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkTrimCommandPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     trim_command_pool
                 } else {
@@ -4157,6 +4427,7 @@ impl DeviceFnV1_1 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceQueue2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_queue2
                 } else {
@@ -4179,6 +4450,7 @@ impl DeviceFnV1_1 {
                     b"vkCreateSamplerYcbcrConversion\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_sampler_ycbcr_conversion
                 } else {
@@ -4200,6 +4472,7 @@ impl DeviceFnV1_1 {
                     b"vkDestroySamplerYcbcrConversion\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_sampler_ycbcr_conversion
                 } else {
@@ -4222,6 +4495,7 @@ impl DeviceFnV1_1 {
                     b"vkCreateDescriptorUpdateTemplate\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_descriptor_update_template
                 } else {
@@ -4243,6 +4517,7 @@ impl DeviceFnV1_1 {
                     b"vkDestroyDescriptorUpdateTemplate\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_descriptor_update_template
                 } else {
@@ -4265,6 +4540,7 @@ impl DeviceFnV1_1 {
                     b"vkUpdateDescriptorSetWithTemplate\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     update_descriptor_set_with_template
                 } else {
@@ -4286,6 +4562,7 @@ impl DeviceFnV1_1 {
                     b"vkGetDescriptorSetLayoutSupport\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_descriptor_set_layout_support
                 } else {
@@ -4319,9 +4596,25 @@ pub struct DeviceFnV1_2 {
 unsafe impl Send for DeviceFnV1_2 {}
 unsafe impl Sync for DeviceFnV1_2 {}
 impl DeviceFnV1_2 {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             cmd_draw_indirect_count: unsafe {
@@ -4342,6 +4635,7 @@ impl DeviceFnV1_2 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdDrawIndirectCount\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_indirect_count
                 } else {
@@ -4367,6 +4661,7 @@ impl DeviceFnV1_2 {
                     b"vkCmdDrawIndexedIndirectCount\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_draw_indexed_indirect_count
                 } else {
@@ -4385,6 +4680,7 @@ impl DeviceFnV1_2 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreateRenderPass2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_render_pass2
                 } else {
@@ -4405,6 +4701,7 @@ impl DeviceFnV1_2 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRenderPass2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_render_pass2
                 } else {
@@ -4421,6 +4718,7 @@ impl DeviceFnV1_2 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdNextSubpass2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_next_subpass2
                 } else {
@@ -4437,6 +4735,7 @@ impl DeviceFnV1_2 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRenderPass2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_render_pass2
                 } else {
@@ -4454,6 +4753,7 @@ impl DeviceFnV1_2 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkResetQueryPool\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     reset_query_pool
                 } else {
@@ -4475,6 +4775,7 @@ impl DeviceFnV1_2 {
                     b"vkGetSemaphoreCounterValue\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_semaphore_counter_value
                 } else {
@@ -4491,6 +4792,7 @@ impl DeviceFnV1_2 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkWaitSemaphores\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     wait_semaphores
                 } else {
@@ -4506,6 +4808,7 @@ impl DeviceFnV1_2 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkSignalSemaphore\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     signal_semaphore
                 } else {
@@ -4525,6 +4828,7 @@ impl DeviceFnV1_2 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetBufferDeviceAddress\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_buffer_device_address
                 } else {
@@ -4545,6 +4849,7 @@ impl DeviceFnV1_2 {
                     b"vkGetBufferOpaqueCaptureAddress\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_buffer_opaque_capture_address
                 } else {
@@ -4565,6 +4870,7 @@ impl DeviceFnV1_2 {
                     b"vkGetDeviceMemoryOpaqueCaptureAddress\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_memory_opaque_capture_address
                 } else {
@@ -4583,9 +4889,25 @@ pub struct InstanceFnV1_3 {
 unsafe impl Send for InstanceFnV1_3 {}
 unsafe impl Sync for InstanceFnV1_3 {}
 impl InstanceFnV1_3 {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             get_physical_device_tool_properties: unsafe {
@@ -4603,6 +4925,7 @@ impl InstanceFnV1_3 {
                     b"vkGetPhysicalDeviceToolProperties\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_physical_device_tool_properties
                 } else {
@@ -4655,9 +4978,25 @@ pub struct DeviceFnV1_3 {
 unsafe impl Send for DeviceFnV1_3 {}
 unsafe impl Sync for DeviceFnV1_3 {}
 impl DeviceFnV1_3 {
-    pub fn load<F>(mut _f: F) -> Self
+    pub fn load<F>(f: F) -> Self
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        Self::load_reporting(f, |_, _| ())
+    }
+    #[doc = r" Loads the table like [`Self::load()`], reporting which commands were found."]
+    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+    where
+        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+    {
+        let mut report = crate::vk::LoadReport::default();
+        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+        (table, report)
+    }
+    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
     where
         F: FnMut(&::std::ffi::CStr) -> *const c_void,
+        R: FnMut(&'static ::std::ffi::CStr, bool),
     {
         Self {
             create_private_data_slot: unsafe {
@@ -4675,6 +5014,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCreatePrivateDataSlot\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     create_private_data_slot
                 } else {
@@ -4695,6 +5035,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkDestroyPrivateDataSlot\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     destroy_private_data_slot
                 } else {
@@ -4713,6 +5054,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkSetPrivateData\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     set_private_data
                 } else {
@@ -4731,6 +5073,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkGetPrivateData\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_private_data
                 } else {
@@ -4747,6 +5090,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetEvent2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_event2
                 } else {
@@ -4763,6 +5107,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdResetEvent2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_reset_event2
                 } else {
@@ -4780,6 +5125,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdWaitEvents2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_wait_events2
                 } else {
@@ -4799,6 +5145,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdPipelineBarrier2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_pipeline_barrier2
                 } else {
@@ -4817,6 +5164,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdWriteTimestamp2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_write_timestamp2
                 } else {
@@ -4834,6 +5182,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkQueueSubmit2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     queue_submit2
                 } else {
@@ -4849,6 +5198,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBuffer2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_buffer2
                 } else {
@@ -4864,6 +5214,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImage2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_image2
                 } else {
@@ -4883,6 +5234,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyBufferToImage2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_buffer_to_image2
                 } else {
@@ -4902,6 +5254,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdCopyImageToBuffer2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_copy_image_to_buffer2
                 } else {
@@ -4917,6 +5270,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBlitImage2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_blit_image2
                 } else {
@@ -4933,6 +5287,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdResolveImage2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_resolve_image2
                 } else {
@@ -4949,6 +5304,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBeginRendering\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_begin_rendering
                 } else {
@@ -4961,6 +5317,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdEndRendering\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_end_rendering
                 } else {
@@ -4976,6 +5333,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetCullMode\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_cull_mode
                 } else {
@@ -4991,6 +5349,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetFrontFace\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_front_face
                 } else {
@@ -5010,6 +5369,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetPrimitiveTopology\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_primitive_topology
                 } else {
@@ -5030,6 +5390,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetViewportWithCount\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_viewport_with_count
                 } else {
@@ -5050,6 +5411,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetScissorWithCount\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_scissor_with_count
                 } else {
@@ -5074,6 +5436,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdBindVertexBuffers2\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_bind_vertex_buffers2
                 } else {
@@ -5093,6 +5456,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthTestEnable\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_test_enable
                 } else {
@@ -5112,6 +5476,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthWriteEnable\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_write_enable
                 } else {
@@ -5131,6 +5496,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthCompareOp\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_compare_op
                 } else {
@@ -5151,6 +5517,7 @@ impl DeviceFnV1_3 {
                     b"vkCmdSetDepthBoundsTestEnable\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_bounds_test_enable
                 } else {
@@ -5170,6 +5537,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilTestEnable\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_stencil_test_enable
                 } else {
@@ -5189,6 +5557,7 @@ impl DeviceFnV1_3 {
                 }
                 let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetStencilOp\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_stencil_op
                 } else {
@@ -5209,6 +5578,7 @@ impl DeviceFnV1_3 {
                     b"vkCmdSetRasterizerDiscardEnable\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_rasterizer_discard_enable
                 } else {
@@ -5228,6 +5598,7 @@ impl DeviceFnV1_3 {
                 let cname =
                     ::std::ffi::CStr::from_bytes_with_nul_unchecked(b"vkCmdSetDepthBiasEnable\0");
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_depth_bias_enable
                 } else {
@@ -5248,6 +5619,7 @@ impl DeviceFnV1_3 {
                     b"vkCmdSetPrimitiveRestartEnable\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     cmd_set_primitive_restart_enable
                 } else {
@@ -5269,6 +5641,7 @@ impl DeviceFnV1_3 {
                     b"vkGetDeviceBufferMemoryRequirements\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_buffer_memory_requirements
                 } else {
@@ -5290,6 +5663,7 @@ impl DeviceFnV1_3 {
                     b"vkGetDeviceImageMemoryRequirements\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_image_memory_requirements
                 } else {
@@ -5312,6 +5686,7 @@ impl DeviceFnV1_3 {
                     b"vkGetDeviceImageSparseMemoryRequirements\0",
                 );
                 let val = _f(cname);
+                report(cname, !val.is_null());
                 if val.is_null() {
                     get_device_image_sparse_memory_requirements
                 } else {
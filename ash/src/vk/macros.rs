@@ -32,6 +32,25 @@ macro_rules! vk_bitflags_wrapped {
             pub const fn contains(self, other: Self) -> bool {
                 self.0 & other.0 == other.0
             }
+            #[doc = r" Returns the bits of `self` that are not in [`Self::all()`]"]
+            #[inline]
+            pub const fn unknown_bits(self) -> Self {
+                Self(self.0 & !Self::all().0)
+            }
+            #[doc = r" Iterates over the bits of `self` that are in [`Self::all()`], from the lowest,"]
+            #[doc = r" each as a value with that single bit set"]
+            #[inline]
+            pub fn iter(self) -> impl Iterator<Item = Self> {
+                let mut rest = self.0 & Self::all().0;
+                ::std::iter::from_fn(move || {
+                    if rest == 0 {
+                        return None;
+                    }
+                    let bit = rest & rest.wrapping_neg();
+                    rest &= !bit;
+                    Some(Self(bit))
+                })
+            }
         }
         impl ::std::ops::BitOr for $name {
             type Output = Self;
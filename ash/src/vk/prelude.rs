@@ -1,4 +1,7 @@
 use crate::vk;
+use std::error::Error;
+use std::ffi::CStr;
+use std::fmt;
 
 /// Holds 24 bits in the least significant bits of memory,
 /// and 8 bytes in the most significant bits of that memory,
@@ -59,3 +62,210 @@ impl From<vk::Extent2D> for vk::Rect2D {
 pub unsafe trait TaggedStructure {
     const STRUCTURE_TYPE: vk::StructureType;
 }
+
+/// The error returned when parsing an enum or flags type from a name that is not one of its
+/// values.
+///
+/// Values are parsed from the names of their constants, such as `R8G8B8A8_UNORM` or
+/// `COLOR_ATTACHMENT | SAMPLED`, or from their names in the Vulkan specification, such as
+/// `VK_FORMAT_R8G8B8A8_UNORM` or `VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT`. Raw values are accepted as
+/// well, and in hexadecimal or binary for the bits of flags.
+///
+/// ```
+/// # use ash::vk;
+/// let format: vk::Format = "VK_FORMAT_R8G8B8A8_UNORM".parse().unwrap();
+/// assert!(format == vk::Format::R8G8B8A8_UNORM);
+/// let usage = vk::ImageUsageFlags::try_from("COLOR_ATTACHMENT | VK_IMAGE_USAGE_SAMPLED_BIT");
+/// assert!(usage == Ok(vk::ImageUsageFlags::COLOR_ATTACHMENT | vk::ImageUsageFlags::SAMPLED));
+/// let error = "R8G8B8A8".parse::<vk::Format>().err().unwrap();
+/// assert_eq!(error.to_string(), "`R8G8B8A8` is not a value of `Format`");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseValueError {
+    type_name: &'static str,
+    value: String,
+}
+
+impl ParseValueError {
+    pub(crate) fn new(type_name: &'static str, value: &str) -> Self {
+        Self {
+            type_name,
+            value: value.to_owned(),
+        }
+    }
+
+    /// The name of the type that was parsed, such as `Format`.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The part of the parsed string that is not a value of the type.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a value of `{}`", self.value, self.type_name)
+    }
+}
+
+impl Error for ParseValueError {}
+
+/// Which commands of a function table were found when loading it with `load_with_report()`,
+/// such as [`vk::DeviceFnV1_3::load_with_report()`].
+///
+/// Commands that were not found are replaced by a function that panics when called. Checking the
+/// report right after loading allows falling back or failing at startup instead.
+///
+/// ```no_run
+/// # use ash::{vk, Device, Instance};
+/// # fn mesh_shader(instance: &Instance, device: &Device) {
+/// let (fp, report) = vk::ExtMeshShaderFn::load_with_report(|name| unsafe {
+///     std::mem::transmute(instance.get_device_proc_addr(device.handle(), name.as_ptr()))
+/// });
+/// if let Err(missing) = report.result() {
+///     eprintln!("Disabling mesh shaders: {missing}");
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoadReport {
+    commands: Vec<(&'static CStr, bool)>,
+}
+
+impl LoadReport {
+    pub(crate) fn push(&mut self, name: &'static CStr, loaded: bool) {
+        self.commands.push((name, loaded));
+    }
+
+    /// Returns whether `name` is a command of the table that was found.
+    pub fn is_loaded(&self, name: &CStr) -> bool {
+        self.commands
+            .iter()
+            .any(|&(command, loaded)| loaded && command == name)
+    }
+
+    /// The commands of the table that were found.
+    pub fn loaded(&self) -> impl Iterator<Item = &'static CStr> + '_ {
+        self.commands
+            .iter()
+            .filter(|&&(_, loaded)| loaded)
+            .map(|&(command, _)| command)
+    }
+
+    /// The commands of the table that were not found.
+    pub fn missing(&self) -> impl Iterator<Item = &'static CStr> + '_ {
+        self.commands
+            .iter()
+            .filter(|&&(_, loaded)| !loaded)
+            .map(|&(command, _)| command)
+    }
+
+    /// Returns an error listing the commands that were not found, if any.
+    pub fn result(&self) -> Result<(), MissingCommands> {
+        let missing = self.missing().collect::<Vec<_>>();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(MissingCommands(missing))
+        }
+    }
+}
+
+/// The commands of a function table that were not found, returned by [`LoadReport::result()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingCommands(pub Vec<&'static CStr>);
+
+impl fmt::Display for MissingCommands {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("missing commands: ")?;
+        for (i, command) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", command.to_string_lossy())?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for MissingCommands {}
+
+/// The extensions and core versions that an extension depends on, from its `depends` attribute in
+/// `vk.xml`. See for example [`KhrSwapchainFn::DEPENDS`][vk::KhrSwapchainFn::DEPENDS].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Depends {
+    /// An extension that must be enabled.
+    Extension(&'static CStr),
+    /// A core version, such as [`API_VERSION_1_1`][vk::API_VERSION_1_1], that the instance or
+    /// device must be created for.
+    Version(u32),
+    /// All of the dependencies must be met: `a+b` in `vk.xml`.
+    All(&'static [Self]),
+    /// One of the dependencies must be met: `a,b` in `vk.xml`.
+    Any(&'static [Self]),
+}
+
+impl Depends {
+    /// The dependencies of an extension without a `depends` attribute.
+    pub const NONE: Self = Self::All(&[]);
+
+    /// Returns the first [`Depends::Extension`] or [`Depends::Version`] that is not met by
+    /// `api_version` and the extensions for which `is_enabled` returns `true`. When none of the
+    /// alternatives of a [`Depends::Any`] are met, the missing dependency of the first one is
+    /// returned.
+    pub fn missing(&self, api_version: u32, is_enabled: &dyn Fn(&CStr) -> bool) -> Option<Self> {
+        match *self {
+            Self::Extension(name) if is_enabled(name) => None,
+            Self::Extension(_) => Some(*self),
+            Self::Version(version) => crate::prelude::require_version(api_version, version)
+                .err()
+                .map(|_| *self),
+            Self::All(terms) => terms
+                .iter()
+                .find_map(|term| term.missing(api_version, is_enabled)),
+            Self::Any(terms) => {
+                let mut missing = terms
+                    .iter()
+                    .map(|term| term.missing(api_version, is_enabled));
+                let first = missing.next()?;
+                if first.is_none() || missing.any(|m| m.is_none()) {
+                    None
+                } else {
+                    first
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Depends {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (terms, sep) = match self {
+            Self::Extension(name) => return write!(f, "{}", name.to_string_lossy()),
+            Self::Version(version) => {
+                return write!(
+                    f,
+                    "VK_VERSION_{}_{}",
+                    vk::api_version_major(*version),
+                    vk::api_version_minor(*version)
+                )
+            }
+            Self::All(terms) => (terms, '+'),
+            Self::Any(terms) => (terms, ','),
+        };
+        for (i, term) in terms.iter().enumerate() {
+            if i != 0 {
+                write!(f, "{sep}")?;
+            }
+            if matches!(term, Self::All(_) | Self::Any(_)) {
+                write!(f, "({term})")?;
+            } else {
+                write!(f, "{term}")?;
+            }
+        }
+        Ok(())
+    }
+}
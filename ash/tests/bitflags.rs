@@ -0,0 +1,44 @@
+#![cfg(feature = "debug")]
+
+use ash::vk;
+
+#[test]
+fn iter() {
+    let usage = vk::ImageUsageFlags::SAMPLED
+        | vk::ImageUsageFlags::TRANSFER_SRC
+        | vk::ImageUsageFlags::COLOR_ATTACHMENT;
+    assert_eq!(
+        usage.iter().collect::<Vec<_>>(),
+        [
+            vk::ImageUsageFlags::TRANSFER_SRC,
+            vk::ImageUsageFlags::SAMPLED,
+            vk::ImageUsageFlags::COLOR_ATTACHMENT,
+        ]
+    );
+    assert_eq!(vk::ImageUsageFlags::empty().iter().count(), 0);
+
+    // Bits without a name are left out, and only reported by `unknown_bits()`
+    let unknown = vk::ShaderStageFlags::from_raw(1 << 30);
+    let stages = vk::ShaderStageFlags::VERTEX | unknown;
+    assert_eq!(
+        stages.iter().collect::<Vec<_>>(),
+        [vk::ShaderStageFlags::VERTEX]
+    );
+    assert_eq!(stages.unknown_bits(), unknown);
+    assert!(vk::ShaderStageFlags::ALL_GRAPHICS.unknown_bits().is_empty());
+}
+
+#[test]
+fn all() {
+    assert!(vk::CullModeFlags::all().contains(vk::CullModeFlags::FRONT_AND_BACK));
+    assert_eq!(vk::CullModeFlags::all().iter().count(), 2);
+    // Bits added by extensions, in both 32- and 64-bit flags
+    assert!(vk::ImageUsageFlags::all().contains(vk::ImageUsageFlags::FRAGMENT_DENSITY_MAP_EXT));
+    assert!(vk::AccessFlags2::all().contains(vk::AccessFlags2::SHADER_SAMPLED_READ));
+    assert_eq!(vk::PipelineLayoutCreateFlags::all().iter().count(), 1);
+    assert_eq!(vk::DeviceCreateFlags::all(), vk::DeviceCreateFlags::empty());
+    assert_eq!(
+        vk::AccessFlags2::from_raw(u64::MAX).unknown_bits(),
+        !vk::AccessFlags2::all()
+    );
+}
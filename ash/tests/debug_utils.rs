@@ -0,0 +1,146 @@
+#![cfg(feature = "mock")]
+
+use ash::extensions::ext::{self, NamedObject};
+use ash::mock::MockDriver;
+use ash::vk::{self, Handle};
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::rc::Rc;
+
+#[test]
+fn object_names() {
+    let driver = MockDriver::new();
+    let entry = driver.entry();
+    unsafe {
+        let extension_names = [ext::DebugUtils::NAME.as_ptr()];
+        let create_info =
+            vk::InstanceCreateInfo::default().enabled_extension_names(&extension_names);
+        let instance = entry.create_instance(&create_info, None).unwrap();
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        let debug_utils = ext::DebugUtils::new(&entry, &instance);
+        let buffer = device.create_buffer(&Default::default(), None).unwrap();
+
+        let named = Rc::new(RefCell::new(Vec::new()));
+        let named_in_driver = named.clone();
+        driver.on_set_debug_utils_object_name_ext(move |_device, name_info| {
+            let name_info = &*name_info;
+            named_in_driver.borrow_mut().push((
+                name_info.object_type,
+                name_info.object_handle,
+                CStr::from_ptr(name_info.p_object_name).to_owned(),
+            ));
+            vk::Result::SUCCESS
+        });
+        debug_utils
+            .set_object_name(device.handle(), buffer, "vertices\0ignored")
+            .unwrap();
+        assert_eq!(
+            *named.borrow(),
+            [(
+                vk::ObjectType::BUFFER,
+                buffer.as_raw(),
+                CString::new("vertices").unwrap()
+            )]
+        );
+        assert_eq!(
+            debug_utils.clone().names().get(buffer).as_deref(),
+            Some("vertices")
+        );
+
+        // Names are not recorded when naming fails
+        driver.on_set_debug_utils_object_name_ext(|_, _| vk::Result::ERROR_OUT_OF_HOST_MEMORY);
+        let fence = device.create_fence(&Default::default(), None).unwrap();
+        assert_eq!(
+            debug_utils.set_object_name(device.handle(), fence, "fence"),
+            Err(vk::Result::ERROR_OUT_OF_HOST_MEMORY)
+        );
+        assert_eq!(debug_utils.names().get(fence), None);
+
+        // Objects reported by a messenger are named by the message, then by the registry
+        let name = CString::new("reported").unwrap();
+        let objects = [
+            vk::DebugUtilsObjectNameInfoEXT::default().object_handle(buffer),
+            vk::DebugUtilsObjectNameInfoEXT::default()
+                .object_handle(fence)
+                .object_name(&name),
+            vk::DebugUtilsObjectNameInfoEXT::default().object_handle(device.handle()),
+        ];
+        let callback_data = vk::DebugUtilsMessengerCallbackDataEXT::default().objects(&objects);
+        assert_eq!(
+            debug_utils.names().objects(&callback_data),
+            [
+                NamedObject {
+                    ty: vk::ObjectType::BUFFER,
+                    raw: buffer.as_raw(),
+                    name: Some("vertices".to_owned()),
+                },
+                NamedObject {
+                    ty: vk::ObjectType::FENCE,
+                    raw: fence.as_raw(),
+                    name: Some("reported".to_owned()),
+                },
+                NamedObject {
+                    ty: vk::ObjectType::DEVICE,
+                    raw: device.handle().as_raw(),
+                    name: None,
+                },
+            ]
+        );
+
+        assert_eq!(
+            debug_utils.names().remove(buffer).as_deref(),
+            Some("vertices")
+        );
+        assert_eq!(debug_utils.names().get(buffer), None);
+    }
+}
+
+#[test]
+fn label_scopes() {
+    let driver = MockDriver::new();
+    let entry = driver.entry();
+    unsafe {
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        let debug_utils = ext::DebugUtils::new(&entry, &instance);
+        let queue = device.get_device_queue(0, 0);
+        let allocate_info = vk::CommandBufferAllocateInfo::default().command_buffer_count(1);
+        let command_buffer = device.allocate_command_buffers(&allocate_info).unwrap()[0];
+
+        let labels = Rc::new(RefCell::new(Vec::new()));
+        let labels_in_driver = labels.clone();
+        driver.on_cmd_begin_debug_utils_label_ext(move |_command_buffer, label| {
+            let name = CStr::from_ptr((*label).p_label_name);
+            labels_in_driver.borrow_mut().push(name.to_owned());
+        });
+        driver.clear_calls();
+        {
+            let _frame = debug_utils.queue_label_scope(queue, "frame");
+            let _pass = debug_utils.cmd_label_scope(command_buffer, "pass");
+            let _draw = debug_utils.cmd_label_scope(command_buffer, "draw");
+        }
+
+        let calls = driver.calls();
+        assert_eq!(
+            calls.iter().map(|call| call.name).collect::<Vec<_>>(),
+            [
+                "vkQueueBeginDebugUtilsLabelEXT",
+                "vkCmdBeginDebugUtilsLabelEXT",
+                "vkCmdBeginDebugUtilsLabelEXT",
+                "vkCmdEndDebugUtilsLabelEXT",
+                "vkCmdEndDebugUtilsLabelEXT",
+                "vkQueueEndDebugUtilsLabelEXT",
+            ]
+        );
+        assert_eq!(calls[3].handles, [command_buffer.as_raw()]);
+        assert_eq!(calls[5].handles, [queue.as_raw()]);
+        assert_eq!(
+            *labels.borrow(),
+            [CString::new("pass").unwrap(), CString::new("draw").unwrap()]
+        );
+    }
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "deep_copy")]
+
+use ash::deep_copy::{DeepCopy, Value};
+use ash::vk;
+use std::collections::HashSet;
+use std::ffi::CStr;
+use std::{slice, thread};
+
+fn pipeline_copy(color_format: vk::Format) -> DeepCopy<vk::GraphicsPipelineCreateInfo<'static>> {
+    let name = b"main\0";
+    let specialization_data = 7u32.to_ne_bytes();
+    let map_entries = [vk::SpecializationMapEntry::default().size(4)];
+    let specialization_info = vk::SpecializationInfo::default()
+        .map_entries(&map_entries)
+        .data(&specialization_data);
+    let stages = [vk::PipelineShaderStageCreateInfo::default()
+        .stage(vk::ShaderStageFlags::VERTEX)
+        .name(CStr::from_bytes_with_nul(name).unwrap())
+        .specialization_info(&specialization_info)];
+    let bindings = [vk::VertexInputBindingDescription::default().stride(16)];
+    let vertex_input =
+        vk::PipelineVertexInputStateCreateInfo::default().vertex_binding_descriptions(&bindings);
+    let color_formats = [color_format];
+    let mut rendering =
+        vk::PipelineRenderingCreateInfo::default().color_attachment_formats(&color_formats);
+    let create_info = vk::GraphicsPipelineCreateInfo::default()
+        .stages(&stages)
+        .vertex_input_state(&vertex_input)
+        .push_next(&mut rendering);
+    unsafe { DeepCopy::new(&create_info) }
+}
+
+#[test]
+fn outlives_original() {
+    let copy = pipeline_copy(vk::Format::R8G8B8A8_UNORM);
+    let copy = thread::spawn(move || copy.clone()).join().unwrap();
+
+    let create_info = copy.view();
+    unsafe {
+        let stages = slice::from_raw_parts(create_info.p_stages, create_info.stage_count as usize);
+        assert_eq!(stages.len(), 1);
+        assert_eq!(stages[0].stage, vk::ShaderStageFlags::VERTEX);
+        assert_eq!(CStr::from_ptr(stages[0].p_name).to_bytes(), b"main");
+        let specialization_info = &*stages[0].p_specialization_info;
+        assert_eq!((*specialization_info.p_map_entries).size, 4);
+        assert_eq!(
+            *specialization_info.p_data.cast::<[u8; 4]>(),
+            7u32.to_ne_bytes()
+        );
+
+        let vertex_input = &*create_info.p_vertex_input_state;
+        assert_eq!((*vertex_input.p_vertex_binding_descriptions).stride, 16);
+
+        let rendering = &*create_info
+            .p_next
+            .cast::<vk::PipelineRenderingCreateInfo<'_>>();
+        assert_eq!(
+            rendering.s_type,
+            vk::StructureType::PIPELINE_RENDERING_CREATE_INFO
+        );
+        assert!(rendering.p_next.is_null());
+        assert_eq!(
+            *rendering.p_color_attachment_formats,
+            vk::Format::R8G8B8A8_UNORM
+        );
+    }
+}
+
+#[test]
+fn cache_key() {
+    let mut cache = HashSet::new();
+    assert!(cache.insert(pipeline_copy(vk::Format::R8G8B8A8_UNORM)));
+    assert!(!cache.insert(pipeline_copy(vk::Format::R8G8B8A8_UNORM)));
+    assert!(cache.insert(pipeline_copy(vk::Format::B8G8R8A8_UNORM)));
+
+    let copy = pipeline_copy(vk::Format::R8G8B8A8_UNORM);
+    let rebuilt = unsafe { DeepCopy::from_value(copy.value().clone()) }.unwrap();
+    assert_eq!(rebuilt, copy);
+    assert_ne!(rebuilt.view().p_stages, copy.view().p_stages);
+}
+
+#[test]
+fn unknown_extension_is_skipped() {
+    let copy = pipeline_copy(vk::Format::R8G8B8A8_UNORM);
+    let mut value = copy.into_value();
+    if let Value::Struct(members) = &mut value {
+        if let Value::Array(extensions) = &mut members[1] {
+            if let Value::Struct(rendering) = &mut extensions[0] {
+                rendering[0] = Value::Int(0x7fff_0000);
+            }
+        }
+    }
+    let rebuilt =
+        unsafe { DeepCopy::<vk::GraphicsPipelineCreateInfo<'static>>::from_value(value) }.unwrap();
+    assert!(rebuilt.view().p_next.is_null());
+    assert_eq!(rebuilt.view().stage_count, 1);
+}
+
+#[test]
+fn mismatched_value() {
+    let mut value = pipeline_copy(vk::Format::R8G8B8A8_UNORM).into_value();
+    if let Value::Struct(members) = &mut value {
+        members.pop();
+    }
+    let error = unsafe { DeepCopy::<vk::GraphicsPipelineCreateInfo<'static>>::from_value(value) }
+        .unwrap_err();
+    assert_eq!(error.expected, "a struct with a value for every member");
+
+    let error = unsafe {
+        DeepCopy::<vk::Extent2D>::from_value(Value::Struct(vec![
+            Value::Int(1),
+            Value::String(Default::default()),
+        ]))
+    }
+    .unwrap_err();
+    assert_eq!(error.found, Value::String(Default::default()));
+}
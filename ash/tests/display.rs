@@ -15,3 +15,46 @@ fn debug_flags() {
 fn debug_enum() {
     assert_eq!(format!("{:?}", vk::ChromaLocation::MIDPOINT), "MIDPOINT");
 }
+
+#[test]
+fn parse_flags() {
+    let flags = vk::AccessFlags::INDIRECT_COMMAND_READ | vk::AccessFlags::VERTEX_ATTRIBUTE_READ;
+    assert_eq!(format!("{:?}", flags).parse(), Ok(flags));
+    assert_eq!(
+        vk::AccessFlags::try_from("VK_ACCESS_INDIRECT_COMMAND_READ_BIT|VERTEX_ATTRIBUTE_READ"),
+        Ok(flags)
+    );
+    assert_eq!("".parse(), Ok(vk::AccessFlags::empty()));
+    assert_eq!(
+        "COLOR | 0x40000000".parse(),
+        Ok(vk::ImageAspectFlags::COLOR | vk::ImageAspectFlags::from_raw(1 << 30))
+    );
+
+    let error = "COLOR | COLOUR"
+        .parse::<vk::ImageAspectFlags>()
+        .unwrap_err();
+    assert_eq!(error.type_name(), "ImageAspectFlags");
+    assert_eq!(error.value(), "COLOUR");
+    assert_eq!(
+        error.to_string(),
+        "`COLOUR` is not a value of `ImageAspectFlags`"
+    );
+}
+
+#[test]
+fn parse_enum() {
+    assert_eq!("MIDPOINT".parse(), Ok(vk::ChromaLocation::MIDPOINT));
+    assert_eq!(
+        vk::PresentModeKHR::try_from("VK_PRESENT_MODE_FIFO_RELAXED_KHR"),
+        Ok(vk::PresentModeKHR::FIFO_RELAXED)
+    );
+    // Aliases parse, but values are printed with their original name
+    let layout = "VK_IMAGE_LAYOUT_DEPTH_READ_ONLY_OPTIMAL_KHR".parse();
+    assert_eq!(layout, Ok(vk::ImageLayout::DEPTH_READ_ONLY_OPTIMAL));
+    assert_eq!(format!("{:?}", layout.unwrap()), "DEPTH_READ_ONLY_OPTIMAL");
+    assert_eq!("1000156000".parse(), Ok(vk::Format::G8B8G8R8_422_UNORM));
+    assert_eq!(
+        "R8G8B8A8_UNROM".parse::<vk::Format>().unwrap_err().value(),
+        "R8G8B8A8_UNROM"
+    );
+}
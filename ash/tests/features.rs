@@ -0,0 +1,151 @@
+#![cfg(feature = "features")]
+
+use ash::features::Features;
+use ash::vk;
+
+/// The `s_type` of every struct in the chain of `features`.
+fn chain_types(features: &mut Features) -> Vec<vk::StructureType> {
+    let mut types = Vec::new();
+    let mut next = <*mut _>::cast::<vk::BaseOutStructure<'_>>(features.chain());
+    while let Some(base) = unsafe { next.as_ref() } {
+        types.push(base.s_type);
+        next = base.p_next;
+    }
+    types
+}
+
+#[test]
+fn compare() {
+    let supported = Features::default()
+        .with(vk::PhysicalDeviceFeatures::default().sampler_anisotropy(true))
+        .with(
+            vk::PhysicalDeviceVulkan12Features::default()
+                .buffer_device_address(true)
+                .timeline_semaphore(true),
+        );
+    let required = Features::default()
+        .with(vk::PhysicalDeviceVulkan12Features::default().buffer_device_address(true))
+        .with(vk::PhysicalDeviceVulkan13Features::default().dynamic_rendering(true));
+
+    assert_eq!(
+        required.missing(&supported),
+        ["VkPhysicalDeviceVulkan13Features::dynamicRendering"]
+    );
+    assert!(!supported.is_superset_of(&required));
+    let intersection = supported.intersection(&required);
+    assert_eq!(
+        intersection.enabled(),
+        ["VkPhysicalDeviceVulkan12Features::bufferDeviceAddress"]
+    );
+    assert!(supported.is_superset_of(&intersection));
+    assert_eq!(intersection, required.intersection(&supported));
+    assert_eq!(
+        intersection
+            .get::<vk::PhysicalDeviceVulkan12Features>()
+            .buffer_device_address,
+        vk::TRUE
+    );
+}
+
+#[test]
+fn chain() {
+    let mut features = Features::default();
+    assert_eq!(
+        chain_types(&mut features),
+        [vk::StructureType::PHYSICAL_DEVICE_FEATURES_2]
+    );
+
+    features
+        .get_mut::<vk::PhysicalDeviceVariablePointersFeatures>()
+        .variable_pointers = vk::TRUE;
+    features
+        .get_mut::<vk::PhysicalDeviceMeshShaderFeaturesEXT>()
+        .mesh_shader = vk::TRUE;
+    assert_eq!(
+        chain_types(&mut features),
+        [
+            vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            vk::StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES,
+            vk::StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT,
+        ]
+    );
+
+    // Promoted structs may not be chained together with the struct of their core version
+    features
+        .get_mut::<vk::PhysicalDeviceVulkan11Features>()
+        .multiview = vk::TRUE;
+    let types = chain_types(&mut features);
+    assert!(types.contains(&vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES));
+    assert!(!types.contains(&vk::StructureType::PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES));
+    let vulkan11 = features.get::<vk::PhysicalDeviceVulkan11Features>();
+    assert_eq!(vulkan11.variable_pointers, vk::TRUE);
+
+    let device_info = vk::DeviceCreateInfo::default().push_next(features.chain());
+    assert!(device_info.p_enabled_features.is_null());
+    assert!(!device_info.p_next.is_null());
+}
+
+#[test]
+#[cfg(feature = "mock")]
+fn query() {
+    use ash::mock::MockDriver;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let driver = MockDriver::new();
+    driver.on_get_physical_device_properties(|_, p_properties| unsafe {
+        (*p_properties).api_version = vk::API_VERSION_1_1;
+    });
+    driver.on_enumerate_device_extension_properties(|_, _, p_count, p_properties| unsafe {
+        *p_count = 1;
+        if let Some(properties) = p_properties.as_mut() {
+            let name = vk::KhrTimelineSemaphoreFn::NAME.to_bytes_with_nul();
+            for (c, &byte) in properties.extension_name.iter_mut().zip(name) {
+                *c = byte as _;
+            }
+        }
+        vk::Result::SUCCESS
+    });
+    let queried = Rc::new(RefCell::new(Vec::new()));
+    let types = Rc::clone(&queried);
+    driver.on_get_physical_device_features2(move |_, p_features| unsafe {
+        (*p_features).features.shader_int64 = vk::TRUE;
+        let mut next = (*p_features).p_next.cast::<vk::BaseOutStructure<'_>>();
+        while let Some(base) = next.as_mut() {
+            types.borrow_mut().push(base.s_type);
+            if base.s_type == vk::StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES {
+                (*next.cast::<vk::PhysicalDeviceTimelineSemaphoreFeatures<'_>>())
+                    .timeline_semaphore = vk::TRUE;
+            }
+            next = base.p_next;
+        }
+    });
+
+    let entry = driver.entry();
+    let app_info = vk::ApplicationInfo::default().api_version(vk::API_VERSION_1_3);
+    let create_info = vk::InstanceCreateInfo::default().application_info(&app_info);
+    unsafe {
+        let instance = entry.create_instance(&create_info, None).unwrap();
+        let physical_device = instance.enumerate_physical_devices().unwrap()[0];
+        let features = Features::query(&instance, physical_device).unwrap();
+        assert_eq!(
+            features.enabled(),
+            [
+                "VkPhysicalDeviceFeatures::shaderInt64",
+                "VkPhysicalDeviceTimelineSemaphoreFeatures::timelineSemaphore",
+            ]
+        );
+        assert!(features
+            .get::<vk::PhysicalDeviceTimelineSemaphoreFeatures>()
+            .p_next
+            .is_null());
+
+        // Structs of Vulkan 1.1 and of supported extensions
+        let queried = queried.borrow();
+        assert!(queried.contains(&vk::StructureType::PHYSICAL_DEVICE_MULTIVIEW_FEATURES));
+        assert!(queried.contains(&vk::StructureType::PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES));
+        assert!(!queried.contains(&vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_1_FEATURES));
+        assert!(!queried.contains(&vk::StructureType::PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT));
+        instance.destroy_instance(None);
+    }
+}
@@ -0,0 +1,116 @@
+#![cfg(all(feature = "hooks", feature = "mock"))]
+
+use ash::extensions::khr;
+use ash::hooks::{self, Hooks};
+use ash::mock::MockDriver;
+use ash::vk;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+unsafe extern "system" fn lost_fence_status(_device: vk::Device, _fence: vk::Fence) -> vk::Result {
+    vk::Result::ERROR_DEVICE_LOST
+}
+
+// Hooks are global, so they are tested in a single test
+#[test]
+fn hooks() {
+    let before = Arc::new(AtomicUsize::new(0));
+    let after = Arc::new(Mutex::new(Vec::new()));
+    let swapchains = Arc::new(AtomicUsize::new(0));
+    let before_count = before.clone();
+    hooks::install(
+        Hooks::new()
+            .before("vkCreateFence", move |call| {
+                assert_eq!(call.command().to_bytes(), b"vkCreateFence");
+                assert_eq!(call.arg_count(), 4);
+                let create_info = call.arg::<*const vk::FenceCreateInfo>(1).unwrap();
+                assert_eq!(
+                    unsafe { (*create_info).flags },
+                    vk::FenceCreateFlags::SIGNALED
+                );
+                // Arguments of another type and results are not available before the call
+                assert!(call.arg::<u32>(1).is_none());
+                assert!(call.arg::<vk::Device>(4).is_none());
+                assert!(call.result::<vk::Result>().is_none());
+                before_count.fetch_add(1, Ordering::Relaxed);
+            })
+            .after("vkCreateFence", {
+                let after = after.clone();
+                move |call| {
+                    let fence = unsafe { *call.arg::<*mut vk::Fence>(3).unwrap() };
+                    after.lock().unwrap().push((
+                        call.arg::<vk::Device>(0).unwrap(),
+                        fence,
+                        call.result::<vk::Result>().unwrap(),
+                    ));
+                }
+            })
+            .after("vkCreateSwapchainKHR", {
+                let swapchains = swapchains.clone();
+                move |_| {
+                    swapchains.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+            .resolve_with(|name| match name.to_bytes() {
+                b"vkGetFenceStatus" => Some(Some(unsafe {
+                    std::mem::transmute::<vk::PFN_vkGetFenceStatus, unsafe extern "system" fn()>(
+                        lost_fence_status,
+                    )
+                })),
+                _ => None,
+            }),
+    );
+
+    let driver = MockDriver::new();
+    let entry = driver.entry();
+    unsafe {
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        let create_info = vk::FenceCreateInfo::default().flags(vk::FenceCreateFlags::SIGNALED);
+        let fence = device.create_fence(&create_info, None).unwrap();
+        device.create_fence(&create_info, None).unwrap();
+        assert_eq!(before.load(Ordering::Relaxed), 2);
+        assert_eq!(
+            after.lock().unwrap()[..1],
+            [(device.handle(), fence, vk::Result::SUCCESS)]
+        );
+        assert_eq!(after.lock().unwrap().len(), 2);
+        assert_eq!(
+            device.get_fence_status(fence),
+            Err(vk::Result::ERROR_DEVICE_LOST)
+        );
+        assert!(driver
+            .calls()
+            .iter()
+            .all(|call| call.name != "vkGetFenceStatus"));
+
+        // Commands loaded by extension wrappers are hooked too
+        let swapchain = khr::Swapchain::new(&instance, &device);
+        swapchain
+            .create_swapchain(&Default::default(), None)
+            .unwrap();
+        assert_eq!(swapchains.load(Ordering::Relaxed), 1);
+
+        // A second device forwards to its own commands, without affecting the first one
+        hooks::install(Hooks::new().before("vkGetFenceStatus", |_| {}));
+        let other_device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        assert_eq!(other_device.get_fence_status(fence), Ok(true));
+        assert_eq!(
+            device.get_fence_status(fence),
+            Err(vk::Result::ERROR_DEVICE_LOST)
+        );
+
+        // Devices loaded without hooks call the driver directly
+        assert!(hooks::uninstall().is_some());
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        device.create_fence(&Default::default(), None).unwrap();
+        assert_eq!(before.load(Ordering::Relaxed), 2);
+        assert_eq!(device.get_fence_status(fence), Ok(true));
+    }
+}
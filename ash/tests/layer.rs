@@ -0,0 +1,145 @@
+#![cfg(all(feature = "layer", feature = "mock"))]
+
+use ash::layer::{self, Layer};
+use ash::mock::MockDriver;
+use ash::{vk, Entry};
+use std::ffi::{c_void, CStr};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+static SUBMITS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "system" fn queue_submit(
+    queue: vk::Queue,
+    submit_count: u32,
+    p_submits: *const vk::SubmitInfo<'_>,
+    fence: vk::Fence,
+) -> vk::Result {
+    SUBMITS.fetch_add(1, Ordering::Relaxed);
+    let device = layer::device(queue).unwrap();
+    (device.fp_v1_0().queue_submit)(queue, submit_count, p_submits, fence)
+}
+
+struct SubmitCounter;
+
+impl Layer for SubmitCounter {
+    fn intercept(name: &CStr) -> vk::PFN_vkVoidFunction {
+        match name.to_bytes() {
+            b"vkQueueSubmit" => Some(unsafe {
+                std::mem::transmute::<vk::PFN_vkQueueSubmit, unsafe extern "system" fn()>(
+                    queue_submit,
+                )
+            }),
+            b"vkQueueWaitIdle" => panic!("Panics must not unwind into the loader"),
+            _ => None,
+        }
+    }
+}
+
+ash::export_layer!(SubmitCounter);
+
+/// Calls the layer like the Vulkan loader does, with the mock driver as the next link.
+#[test]
+fn layer_chain() {
+    let driver = MockDriver::new();
+    let next = driver.entry();
+
+    let mut negotiate = layer::NegotiateLayerInterface {
+        s_type: layer::NegotiateLayerStructType::INTERFACE_STRUCT,
+        p_next: ptr::null_mut(),
+        loader_layer_interface_version: 3,
+        pfn_get_instance_proc_addr: None,
+        pfn_get_device_proc_addr: None,
+        pfn_get_physical_device_proc_addr: None,
+    };
+    unsafe {
+        assert_eq!(
+            vkNegotiateLoaderLayerInterfaceVersion(&mut negotiate),
+            vk::Result::SUCCESS
+        );
+        assert_eq!(negotiate.loader_layer_interface_version, 2);
+        let layered = Entry::from_static_fn(vk::StaticFn {
+            get_instance_proc_addr: negotiate.pfn_get_instance_proc_addr.unwrap(),
+        });
+
+        let mut instance_link = layer::LayerInstanceLink {
+            p_next: ptr::null_mut(),
+            pfn_next_get_instance_proc_addr: next.static_fn().get_instance_proc_addr,
+            pfn_next_get_physical_device_proc_addr: None,
+        };
+        let mut instance_link_info = layer::LayerInstanceCreateInfo {
+            s_type: vk::StructureType::LOADER_INSTANCE_CREATE_INFO,
+            p_next: ptr::null(),
+            function: layer::LayerFunction::LAYER_LINK_INFO,
+            u: layer::LayerInstanceCreateInfoU {
+                p_layer_info: &mut instance_link,
+            },
+        };
+        let create_info = vk::InstanceCreateInfo {
+            p_next: <*mut _>::cast(&mut instance_link_info),
+            ..Default::default()
+        };
+        let instance = layered.create_instance(&create_info, None).unwrap();
+        // The link was moved forward for the next layer
+        assert!(instance_link_info.u.p_layer_info.is_null());
+        let next_instance = layer::instance(instance.handle()).unwrap();
+        assert_eq!(next_instance.handle(), instance.handle());
+
+        let physical_device = instance.enumerate_physical_devices().unwrap()[0];
+        let create_device = || {
+            let mut device_link = layer::LayerDeviceLink {
+                p_next: ptr::null_mut(),
+                pfn_next_get_instance_proc_addr: next.static_fn().get_instance_proc_addr,
+                pfn_next_get_device_proc_addr: next_instance.fp_v1_0().get_device_proc_addr,
+            };
+            let mut device_link_info = layer::LayerDeviceCreateInfo {
+                s_type: vk::StructureType::LOADER_DEVICE_CREATE_INFO,
+                p_next: ptr::null(),
+                function: layer::LayerFunction::LAYER_LINK_INFO,
+                u: layer::LayerDeviceCreateInfoU {
+                    p_layer_info: &mut device_link,
+                },
+            };
+            let create_info = vk::DeviceCreateInfo {
+                p_next: <*mut _>::cast::<c_void>(&mut device_link_info),
+                ..Default::default()
+            };
+            instance
+                .create_device(physical_device, &create_info, None)
+                .unwrap()
+        };
+        let device = create_device();
+        let other_device = create_device();
+        // Each device is found through the dispatch key of its handles
+        let next_device = layer::device(device.handle()).unwrap();
+        let other_next_device = layer::device(other_device.handle()).unwrap();
+        assert!(!Arc::ptr_eq(&next_device, &other_next_device));
+        assert_eq!(next_device.handle(), device.handle());
+        assert_eq!(other_next_device.handle(), other_device.handle());
+        other_device.destroy_device(None);
+        assert!(layer::device(device.handle()).is_some());
+
+        // Panics are caught before reaching the loader
+        assert!(layered
+            .get_instance_proc_addr(instance.handle(), b"vkQueueWaitIdle\0".as_ptr().cast())
+            .is_none());
+
+        let queue = device.get_device_queue(0, 0);
+        device.queue_submit(queue, &[], vk::Fence::null()).unwrap();
+        assert_eq!(SUBMITS.load(Ordering::Relaxed), 1);
+        assert!(driver
+            .calls()
+            .iter()
+            .any(|call| call.name == "vkQueueSubmit"));
+
+        device.destroy_device(None);
+        assert!(layer::device(queue).is_none());
+        instance.destroy_instance(None);
+        assert!(layer::instance(physical_device).is_none());
+        assert!(driver
+            .live_handles()
+            .iter()
+            .all(|&(_, ty)| ty != vk::ObjectType::INSTANCE && ty != vk::ObjectType::DEVICE));
+    }
+}
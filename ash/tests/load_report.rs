@@ -0,0 +1,39 @@
+use ash::vk;
+use std::ffi::{c_void, CStr};
+
+unsafe extern "system" fn destroy_device(
+    _device: vk::Device,
+    _p_allocator: *const vk::AllocationCallbacks,
+) {
+}
+
+#[test]
+fn missing_commands() {
+    let (fp, report) = vk::DeviceFnV1_0::load_with_report(|name| {
+        if name.to_bytes() == b"vkDestroyDevice" {
+            destroy_device as *const c_void
+        } else {
+            std::ptr::null()
+        }
+    });
+    let destroy_device_name = CStr::from_bytes_with_nul(b"vkDestroyDevice\0").unwrap();
+    let create_fence_name = CStr::from_bytes_with_nul(b"vkCreateFence\0").unwrap();
+    assert!(report.is_loaded(destroy_device_name));
+    assert!(!report.is_loaded(create_fence_name));
+    assert_eq!(report.loaded().collect::<Vec<_>>(), [destroy_device_name]);
+    assert!(report.missing().any(|name| name == create_fence_name));
+    unsafe { (fp.destroy_device)(vk::Device::null(), std::ptr::null()) };
+
+    let missing = report.result().unwrap_err();
+    assert_eq!(missing.0.len(), report.missing().count());
+    assert!(missing
+        .to_string()
+        .starts_with("missing commands: vkGetDeviceQueue, vkQueueSubmit, "));
+}
+
+#[test]
+fn all_commands() {
+    let (_, report) = vk::KhrSwapchainFn::load_with_report(|_| destroy_device as *const c_void);
+    assert_eq!(report.missing().count(), 0);
+    assert_eq!(report.result(), Ok(()));
+}
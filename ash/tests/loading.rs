@@ -0,0 +1,40 @@
+#![cfg(feature = "loaded")]
+
+use ash::{Entry, LoadingError};
+use std::path::PathBuf;
+
+#[test]
+fn loader_candidates() {
+    let dir = std::env::temp_dir().join("ash-loader-candidates");
+    std::fs::create_dir_all(&dir).unwrap();
+    let missing = dir.join("missing-vulkan-loader");
+    let paths = std::env::join_paths([&missing, &dir]).unwrap();
+
+    let candidates = Entry::loader_candidates_in(Some(&paths));
+    assert_eq!(candidates[0], missing);
+    // Directories are searched for the platform names, which are also tried without a directory
+    assert_eq!(candidates[1].parent(), Some(dir.as_path()));
+    let name = PathBuf::from(candidates[1].file_name().unwrap());
+    assert!(candidates.contains(&name));
+    // A loader next to the executable is only tried after the system loader
+    let exe = std::env::current_exe().unwrap();
+    let system = candidates.iter().position(|path| *path == name).unwrap();
+    assert!(candidates[..system]
+        .iter()
+        .all(|path| path.parent() != exe.parent()));
+
+    match unsafe { Entry::load_from(&missing) } {
+        Err(LoadingError::LibraryLoadFailure(_)) => {}
+        _ => panic!("loaded a library that does not exist"),
+    }
+    // A system loader may be installed, so only check errors of the candidates above
+    if let Err(err) = unsafe { Entry::load_from_candidates(candidates.clone()) } {
+        let attempts = match &err {
+            LoadingError::NotFound(attempts) => attempts,
+            err => panic!("unexpected error {err}"),
+        };
+        assert_eq!(attempts.len(), candidates.len());
+        assert_eq!(attempts[0].path, missing);
+        assert!(err.to_string().contains(&missing.display().to_string()));
+    }
+}
@@ -0,0 +1,291 @@
+#![cfg(feature = "loaded")]
+
+use ash::manifest::{
+    correlate_layers, ExtensionManifest, IcdManifest, LayerManifest, LayerProblem, ManifestError,
+    SearchPaths,
+};
+use ash::{vk, Entry, LoadingError};
+use std::path::{Path, PathBuf};
+
+fn fixture(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(path)
+}
+
+#[test]
+fn icd_manifest() {
+    let manifest = IcdManifest::from_file(fixture("icd/lvp_icd.json")).unwrap();
+    assert_eq!(
+        manifest,
+        IcdManifest {
+            file_format_version: "1.0.1".to_owned(),
+            library_path: fixture("icd/./lib/libvulkan_lvp.so"),
+            api_version: Some(vk::make_api_version(0, 1, 3, 250)),
+            is_portability_driver: false,
+            library_arch: Some("64".to_owned()),
+        }
+    );
+
+    // File names are searched for by the dynamic linker
+    let manifest = IcdManifest::from_file(fixture("icd/MoltenVK_icd.json")).unwrap();
+    assert_eq!(manifest.library_path, Path::new("libMoltenVK.dylib"));
+    assert_eq!(manifest.api_version, Some(vk::API_VERSION_1_2));
+    assert!(manifest.is_portability_driver);
+
+    let manifest = IcdManifest::parse(
+        r#"{"file_format_version": "1.0.0", "ICD": {"library_path": "\/opt/vk🚀.so"}}"#,
+        Path::new("/etc"),
+    )
+    .unwrap();
+    assert_eq!(manifest.library_path, Path::new("/opt/vk\u{1f680}.so"));
+    assert_eq!(manifest.api_version, None);
+}
+
+#[test]
+fn invalid_icd_manifest() {
+    match IcdManifest::from_file(fixture("icd/invalid.json")) {
+        // After the trailing comma
+        Err(ManifestError::Syntax { offset, .. }) => assert_eq!(offset, 101),
+        result => panic!("unexpected {result:?}"),
+    }
+    match IcdManifest::parse(
+        r#"{"file_format_version": "1.0.0", "ICD": {}}"#,
+        Path::new(""),
+    ) {
+        Err(ManifestError::InvalidMember("ICD.library_path")) => {}
+        result => panic!("unexpected {result:?}"),
+    }
+    // Deep nesting is rejected instead of overflowing the stack
+    let nested = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+    match IcdManifest::parse(&nested, Path::new("")) {
+        Err(ManifestError::Syntax { offset, .. }) => assert_eq!(offset, 128),
+        result => panic!("unexpected {result:?}"),
+    }
+    // Escapes have exactly four hexadecimal digits, without a sign
+    match IcdManifest::parse(r#"{"ICD": "\u+041"}"#, Path::new("")) {
+        Err(ManifestError::Syntax { offset, .. }) => assert_eq!(offset, 11),
+        result => panic!("unexpected {result:?}"),
+    }
+    match IcdManifest::from_file(fixture("icd/missing.json")) {
+        Err(ManifestError::Io(_)) => {}
+        result => panic!("unexpected {result:?}"),
+    }
+}
+
+#[test]
+fn load_icd() {
+    match unsafe { Entry::load_icd(fixture("icd/invalid.json")) } {
+        Err(LoadingError::InvalidManifest(ManifestError::Syntax { .. })) => {}
+        _ => panic!("loaded an invalid manifest"),
+    }
+    match unsafe { Entry::load_icd(fixture("icd/missing_library.json")) } {
+        Err(LoadingError::LibraryLoadFailure(err)) => {
+            assert!(err.to_string().contains("no_such_driver.so"))
+        }
+        _ => panic!("loaded a driver that does not exist"),
+    }
+}
+
+#[test]
+#[cfg(all(unix, not(target_os = "android")))]
+fn search_paths() {
+    let env = |vars: &'static [(&'static str, &'static str)]| {
+        move |var: &str| {
+            vars.iter()
+                .find(|(name, _)| *name == var)
+                .map(|(_, value)| value.into())
+        }
+    };
+    let paths = |paths: &[&str]| paths.iter().map(PathBuf::from).collect::<Vec<_>>();
+
+    let search = SearchPaths::with_env(env(&[("HOME", "/home/me")]));
+    assert_eq!(
+        search.drivers,
+        paths(&[
+            "/home/me/.config/vulkan/icd.d",
+            "/etc/xdg/vulkan/icd.d",
+            "/etc/vulkan/icd.d",
+            "/home/me/.local/share/vulkan/icd.d",
+            "/usr/local/share/vulkan/icd.d",
+            "/usr/share/vulkan/icd.d",
+        ])
+    );
+    assert_eq!(
+        search.implicit_layers[2],
+        Path::new("/etc/vulkan/implicit_layer.d")
+    );
+
+    let search = SearchPaths::with_env(env(&[
+        ("XDG_DATA_DIRS", "/usr/share:/opt/share"),
+        ("VK_ICD_FILENAMES", "/opt/lvp_icd.json:/opt/icd.d"),
+        ("VK_ADD_LAYER_PATH", "/opt/layers"),
+        ("VK_IMPLICIT_LAYER_PATH", "/opt/implicit"),
+    ]));
+    assert_eq!(search.drivers, paths(&["/opt/lvp_icd.json", "/opt/icd.d"]));
+    assert_eq!(
+        search.explicit_layers,
+        paths(&[
+            "/opt/layers",
+            "/etc/xdg/vulkan/explicit_layer.d",
+            "/etc/vulkan/explicit_layer.d",
+            "/usr/share/vulkan/explicit_layer.d",
+            "/opt/share/vulkan/explicit_layer.d",
+        ])
+    );
+    assert_eq!(search.implicit_layers, paths(&["/opt/implicit"]));
+}
+
+fn fixture_search() -> SearchPaths {
+    SearchPaths {
+        drivers: vec![fixture("icd"), fixture("missing.d")],
+        explicit_layers: vec![fixture("layers/explicit_layer.d")],
+        implicit_layers: vec![fixture("layers/implicit_layer.d")],
+    }
+}
+
+#[test]
+fn enumerate_manifests() {
+    let drivers = fixture_search().drivers();
+    let names = drivers
+        .iter()
+        .map(|driver| driver.path.file_name().unwrap().to_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            "MoltenVK_icd.json",
+            "invalid.json",
+            "lvp_icd.json",
+            "missing_library.json"
+        ]
+    );
+    assert!(drivers[1].manifest.is_err());
+
+    let layers = fixture_search().layers();
+    let names = layers
+        .iter()
+        .map(|found| match &found.manifest {
+            Ok(layers) => layers
+                .iter()
+                .map(|layer| layer.name.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+            Err(err) => err.to_string(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            "Missing or invalid member `layer.library_path`",
+            "VK_LAYER_VALVE_steam_overlay_64",
+            "VK_LAYER_KHRONOS_validation",
+            "VK_LAYER_LUNARG_api_dump,VK_LAYER_LUNARG_standard_validation",
+        ]
+    );
+
+    let validation = &layers[2].manifest.as_ref().unwrap()[0];
+    assert_eq!(
+        validation,
+        &LayerManifest {
+            name: "VK_LAYER_KHRONOS_validation".to_owned(),
+            ty: "GLOBAL".to_owned(),
+            library_path: Some("libVkLayer_khronos_validation.so".into()),
+            api_version: vk::make_api_version(0, 1, 3, 250),
+            implementation_version: 1,
+            description: "Khronos Validation Layer".to_owned(),
+            functions: Default::default(),
+            instance_extensions: vec![
+                ExtensionManifest {
+                    name: "VK_EXT_debug_report".to_owned(),
+                    spec_version: 9,
+                    entrypoints: vec![],
+                },
+                ExtensionManifest {
+                    name: "VK_EXT_debug_utils".to_owned(),
+                    spec_version: 1,
+                    entrypoints: vec![],
+                },
+            ],
+            device_extensions: vec![ExtensionManifest {
+                name: "VK_EXT_debug_marker".to_owned(),
+                spec_version: 4,
+                entrypoints: vec![
+                    "vkDebugMarkerSetObjectTagEXT".to_owned(),
+                    "vkDebugMarkerSetObjectNameEXT".to_owned(),
+                ],
+            }],
+            enable_environment: Default::default(),
+            disable_environment: Default::default(),
+            component_layers: vec![],
+            implicit: false,
+        }
+    );
+    let layers_json = layers[3].manifest.as_ref().unwrap();
+    assert_eq!(layers_json[0].implementation_version, 2);
+    assert_eq!(
+        layers_json[0].library_path.as_deref(),
+        Some(fixture("layers/explicit_layer.d/libVkLayer_api_dump.so").as_path())
+    );
+    assert_eq!(layers_json[1].library_path, None);
+    assert_eq!(
+        layers_json[1].component_layers,
+        ["VK_LAYER_KHRONOS_validation"]
+    );
+    assert!(layers[1].manifest.as_ref().unwrap()[0].implicit);
+}
+
+#[test]
+fn correlate() {
+    let layers = fixture_search().layers();
+    let mut validation = vk::LayerProperties::default();
+    for (c, &byte) in validation
+        .layer_name
+        .iter_mut()
+        .zip(b"VK_LAYER_KHRONOS_validation")
+    {
+        *c = byte as _;
+    }
+
+    let statuses = correlate_layers(&layers, &[validation], |var| {
+        (var == "DISABLE_VK_LAYER_VALVE_steam_overlay_1").then(|| "1".into())
+    });
+    let statuses = statuses
+        .iter()
+        .map(|status| {
+            (
+                status.manifest.name.as_str(),
+                status.reported,
+                status.problems.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        statuses,
+        [
+            (
+                "VK_LAYER_VALVE_steam_overlay_64",
+                false,
+                vec![
+                    LayerProblem::MissingLibrary(
+                        "/opt/steam/ubuntu12_64/steamoverlayvulkanlayer.so".into()
+                    ),
+                    LayerProblem::DisabledBy("DISABLE_VK_LAYER_VALVE_steam_overlay_1".to_owned()),
+                    LayerProblem::NotEnabledBy(
+                        "ENABLE_VK_LAYER_VALVE_steam_overlay_1".to_owned(),
+                        "1".to_owned()
+                    ),
+                ]
+            ),
+            ("VK_LAYER_KHRONOS_validation", true, vec![]),
+            (
+                "VK_LAYER_LUNARG_api_dump",
+                false,
+                vec![LayerProblem::MissingLibrary(fixture(
+                    "layers/explicit_layer.d/libVkLayer_api_dump.so"
+                ))]
+            ),
+            ("VK_LAYER_LUNARG_standard_validation", false, vec![]),
+        ]
+    );
+}
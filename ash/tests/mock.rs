@@ -0,0 +1,236 @@
+#![cfg(feature = "mock")]
+
+use ash::extensions::{ext, khr};
+use ash::mock::MockDriver;
+use ash::prelude::{ApiVersionError, MissingExtensionError};
+use ash::vk::{self, Handle};
+use ash::Device;
+
+#[test]
+fn default_behaviour() {
+    let driver = MockDriver::new();
+    let entry = driver.entry();
+    unsafe {
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        let physical_devices = instance.enumerate_physical_devices().unwrap();
+        assert_eq!(physical_devices.len(), 1);
+        assert!(instance
+            .get_physical_device_queue_family_properties(physical_devices[0])
+            .is_empty());
+
+        let device = instance
+            .create_device(physical_devices[0], &Default::default(), None)
+            .unwrap();
+        let allocate_info = vk::CommandBufferAllocateInfo {
+            command_buffer_count: 3,
+            ..Default::default()
+        };
+        let command_buffers = device.allocate_command_buffers(&allocate_info).unwrap();
+        assert_eq!(command_buffers.len(), 3);
+        let fence = device.create_fence(&Default::default(), None).unwrap();
+        assert_eq!(
+            driver.live_handles(),
+            vec![
+                (instance.handle().as_raw(), vk::ObjectType::INSTANCE),
+                (
+                    physical_devices[0].as_raw(),
+                    vk::ObjectType::PHYSICAL_DEVICE
+                ),
+                (device.handle().as_raw(), vk::ObjectType::DEVICE),
+                (command_buffers[0].as_raw(), vk::ObjectType::COMMAND_BUFFER),
+                (command_buffers[1].as_raw(), vk::ObjectType::COMMAND_BUFFER),
+                (command_buffers[2].as_raw(), vk::ObjectType::COMMAND_BUFFER),
+                (fence.as_raw(), vk::ObjectType::FENCE),
+            ]
+        );
+
+        driver.clear_calls();
+        device.destroy_fence(fence, None);
+        device.free_command_buffers(vk::CommandPool::null(), &command_buffers);
+        device.destroy_device(None);
+        assert_eq!(driver.live_handles().len(), 2);
+
+        let calls = driver.calls();
+        let names = calls.iter().map(|call| call.name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["vkDestroyFence", "vkFreeCommandBuffers", "vkDestroyDevice"]
+        );
+        assert_eq!(calls[0].handles, [device.handle().as_raw(), fence.as_raw()]);
+    }
+}
+
+#[test]
+fn overrides() {
+    let driver = MockDriver::new();
+    let handler_driver = driver.clone();
+    driver
+        .on_create_fence(move |_device, _create_info, _allocator, p_fence| unsafe {
+            *p_fence = handler_driver.allocate();
+            vk::Result::SUCCESS
+        })
+        .on_wait_for_fences(|_device, _fence_count, _fences, _wait_all, _timeout| {
+            vk::Result::TIMEOUT
+        })
+        .on_get_fence_status(|_device, _fence| vk::Result::ERROR_DEVICE_LOST);
+
+    let entry = driver.entry();
+    unsafe {
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        let fence = device.create_fence(&Default::default(), None).unwrap();
+        assert_eq!(
+            device.wait_for_fences(&[fence], true, 0),
+            Ok(ash::success_codes::WaitForFences::Timeout)
+        );
+        assert_eq!(
+            device.get_fence_status(fence),
+            Err(vk::Result::ERROR_DEVICE_LOST)
+        );
+        assert!(driver
+            .live_handles()
+            .contains(&(fence.as_raw(), vk::ObjectType::FENCE)));
+        assert_eq!(
+            driver.calls().last().unwrap().handles,
+            [device.handle().as_raw(), fence.as_raw()]
+        );
+    }
+}
+
+#[test]
+fn separate_drivers() {
+    let first = MockDriver::new();
+    let instance = unsafe { first.entry().create_instance(&Default::default(), None) }.unwrap();
+
+    // Handles are dispatched to the driver that allocated them
+    let second = MockDriver::new();
+    let entry = second.entry();
+    unsafe { instance.destroy_instance(None) };
+    assert!(first.live_handles().is_empty());
+    assert_eq!(second.calls(), []);
+
+    drop(second);
+    assert_eq!(
+        unsafe { entry.create_instance(&Default::default(), None) }.err(),
+        Some(vk::Result::ERROR_INITIALIZATION_FAILED)
+    );
+}
+
+#[test]
+fn api_version() {
+    let driver = MockDriver::new();
+    driver.on_get_physical_device_properties(|_physical_device, p_properties| unsafe {
+        *p_properties = vk::PhysicalDeviceProperties {
+            api_version: vk::API_VERSION_1_1,
+            ..Default::default()
+        };
+    });
+
+    let entry = driver.entry();
+    unsafe {
+        let application_info = vk::ApplicationInfo::default().api_version(vk::API_VERSION_1_2);
+        let create_info = vk::InstanceCreateInfo::default().application_info(&application_info);
+        let instance = entry.create_instance(&create_info, None).unwrap();
+        assert_eq!(instance.api_version(), vk::API_VERSION_1_2);
+        assert!(instance.v1_1().is_ok());
+        assert!(instance.v1_3().is_err());
+
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        assert_eq!(device.api_version(), vk::API_VERSION_1_1);
+        assert!(device.v1_1().is_ok());
+        assert_eq!(
+            device.v1_3().err(),
+            Some(ApiVersionError {
+                required: vk::API_VERSION_1_3,
+                api_version: vk::API_VERSION_1_1,
+            })
+        );
+
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        assert_eq!(instance.api_version(), vk::API_VERSION_1_0);
+        assert!(instance.v1_1().is_err());
+
+        // Devices not created through `Instance::create_device()` report what they were loaded with
+        let device = Device::load(instance.fp_v1_0(), device.handle());
+        assert_eq!(device.api_version(), vk::API_VERSION_1_0);
+        assert!(device.v1_1().is_err());
+        let device = device.with_api_version(vk::API_VERSION_1_1);
+        assert!(device.v1_1().is_ok());
+    }
+}
+
+#[test]
+fn enabled_extensions() {
+    let driver = MockDriver::new();
+    let entry = driver.entry();
+    unsafe {
+        let extension_names = [khr::Surface::NAME.as_ptr()];
+        let create_info =
+            vk::InstanceCreateInfo::default().enabled_extension_names(&extension_names);
+        let instance = entry.create_instance(&create_info, None).unwrap();
+        assert_eq!(
+            instance.enabled_extensions(),
+            [khr::Surface::NAME.to_owned()]
+        );
+        assert!(khr::Surface::try_new(&entry, &instance).is_ok());
+        assert_eq!(
+            ext::DebugUtils::try_new(&entry, &instance).err(),
+            Some(MissingExtensionError {
+                extension: ext::DebugUtils::NAME,
+                missing: vk::Depends::Extension(ext::DebugUtils::NAME),
+            })
+        );
+
+        let extension_names = [khr::Swapchain::NAME.as_ptr()];
+        let create_info = vk::DeviceCreateInfo::default().enabled_extension_names(&extension_names);
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &create_info, None)
+            .unwrap();
+        assert!(device.is_extension_enabled(khr::Swapchain::NAME));
+        assert!(khr::Swapchain::try_new(&instance, &device).is_ok());
+
+        // VK_KHR_swapchain depends on the VK_KHR_surface instance extension
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &create_info, None)
+            .unwrap();
+        let error = khr::Swapchain::try_new(&instance, &device).err().unwrap();
+        assert_eq!(error.missing, vk::Depends::Extension(khr::Surface::NAME));
+        assert_eq!(
+            error.to_string(),
+            "VK_KHR_swapchain requires VK_KHR_surface, which is not enabled"
+        );
+    }
+}
+
+#[test]
+fn depends() {
+    const DEPENDS: vk::Depends = vk::Depends::All(&[
+        vk::Depends::Any(&[
+            vk::Depends::Extension(khr::GetPhysicalDeviceProperties2::NAME),
+            vk::Depends::Version(vk::API_VERSION_1_1),
+        ]),
+        vk::Depends::Extension(khr::Surface::NAME),
+    ]);
+    assert_eq!(
+        DEPENDS.to_string(),
+        "(VK_KHR_get_physical_device_properties2,VK_VERSION_1_1)+VK_KHR_surface"
+    );
+
+    let surface = |name: &std::ffi::CStr| name == khr::Surface::NAME;
+    assert_eq!(DEPENDS.missing(vk::API_VERSION_1_1, &surface), None);
+    assert_eq!(
+        DEPENDS.missing(vk::API_VERSION_1_0, &surface),
+        Some(vk::Depends::Extension(
+            khr::GetPhysicalDeviceProperties2::NAME
+        ))
+    );
+    assert_eq!(
+        DEPENDS.missing(vk::API_VERSION_1_1, &|_| false),
+        Some(vk::Depends::Extension(khr::Surface::NAME))
+    );
+}
@@ -0,0 +1,232 @@
+#![cfg(all(feature = "mock", feature = "owned"))]
+
+use ash::extensions::{ext, khr};
+use ash::mock::{Call, MockDriver};
+use ash::owned::{Owned, Parent};
+use ash::vk::{self, Handle};
+use std::sync::Arc;
+
+fn create_device(driver: &MockDriver) -> (ash::Instance, Arc<ash::Device>) {
+    let entry = driver.entry();
+    unsafe {
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        (instance, Arc::new(device))
+    }
+}
+
+#[test]
+fn drop_destroys() {
+    let driver = MockDriver::new();
+    let (instance, device) = create_device(&driver);
+    let parent = Parent::Device(device.clone());
+    unsafe {
+        let buffer = Owned::from_raw(
+            parent.clone(),
+            device.create_buffer(&Default::default(), None).unwrap(),
+        );
+        let memory = Owned::from_raw(
+            parent,
+            device.allocate_memory(&Default::default(), None).unwrap(),
+        );
+        let (raw_buffer, raw_memory) = (buffer.as_raw(), memory.as_raw());
+        driver.clear_calls();
+        drop((buffer, memory));
+
+        let calls = driver.calls();
+        assert_eq!(
+            calls.iter().map(|call| call.name).collect::<Vec<_>>(),
+            ["vkDestroyBuffer", "vkFreeMemory"]
+        );
+        assert_eq!(calls[0].handles[1], raw_buffer);
+        assert_eq!(calls[1].handles[1], raw_memory);
+        assert!(!driver
+            .live_handles()
+            .iter()
+            .any(|&(raw, _)| raw == raw_buffer || raw == raw_memory));
+
+        assert_eq!(Arc::strong_count(&device), 1);
+        device.destroy_device(None);
+        instance.destroy_instance(None);
+    }
+    assert!(driver.live_handles().is_empty());
+}
+
+#[test]
+fn free_to_pool() {
+    let driver = MockDriver::new();
+    let (instance, device) = create_device(&driver);
+    unsafe {
+        let pool = Owned::from_raw(
+            Parent::Device(device.clone()),
+            device
+                .create_command_pool(&Default::default(), None)
+                .unwrap(),
+        );
+        let allocate_info = vk::CommandBufferAllocateInfo::default()
+            .command_pool(*pool)
+            .command_buffer_count(2);
+        let command_buffers = device
+            .allocate_command_buffers(&allocate_info)
+            .unwrap()
+            .into_iter()
+            .map(|command_buffer| {
+                Owned::from_raw(Parent::CommandPool(device.clone(), *pool), command_buffer)
+            })
+            .collect::<Vec<_>>();
+        let freed = command_buffers[1].as_raw();
+        driver.clear_calls();
+        drop(command_buffers);
+        drop(pool);
+
+        let calls = driver.calls();
+        assert_eq!(
+            calls.iter().map(|call| call.name).collect::<Vec<_>>(),
+            [
+                "vkFreeCommandBuffers",
+                "vkFreeCommandBuffers",
+                "vkDestroyCommandPool"
+            ]
+        );
+        assert_eq!(
+            calls[1],
+            Call {
+                name: "vkFreeCommandBuffers",
+                handles: vec![device.handle().as_raw(), calls[2].handles[1]],
+            }
+        );
+        assert!(!driver.live_handles().iter().any(|&(raw, _)| raw == freed));
+
+        device.destroy_device(None);
+        instance.destroy_instance(None);
+    }
+}
+
+#[test]
+fn into_raw() {
+    let driver = MockDriver::new();
+    let (instance, device) = create_device(&driver);
+    unsafe {
+        let fence = Owned::from_raw(
+            Parent::Device(device.clone()),
+            device.create_fence(&Default::default(), None).unwrap(),
+        );
+        let fence = fence.into_raw();
+        assert_eq!(Arc::strong_count(&device), 1);
+        assert!(driver
+            .live_handles()
+            .contains(&(fence.as_raw(), vk::ObjectType::FENCE)));
+
+        // Null handles are not destroyed
+        drop(Owned::from_raw(
+            Parent::Device(device.clone()),
+            vk::Fence::null(),
+        ));
+        assert!(driver
+            .live_handles()
+            .contains(&(fence.as_raw(), vk::ObjectType::FENCE)));
+
+        device.destroy_fence(fence, None);
+        device.destroy_device(None);
+        instance.destroy_instance(None);
+    }
+}
+
+#[test]
+fn extension_parents() {
+    let driver = MockDriver::new();
+    let entry = driver.entry();
+    unsafe {
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        let surface = ext::HeadlessSurface::new(&entry, &instance)
+            .create_headless_surface(&Default::default(), None)
+            .unwrap();
+        let surface = Owned::from_raw(
+            Parent::KhrSurface(Arc::new(khr::Surface::new(&entry, &instance))),
+            surface,
+        );
+        let swapchain_fn = Arc::new(khr::Swapchain::new(&instance, &device));
+        let swapchain = Owned::from_raw(
+            Parent::KhrSwapchain(swapchain_fn.clone()),
+            swapchain_fn
+                .create_swapchain(&Default::default(), None)
+                .unwrap(),
+        );
+        let (raw_surface, raw_swapchain) = (surface.as_raw(), swapchain.as_raw());
+        driver.clear_calls();
+        drop((swapchain, surface));
+
+        assert_eq!(
+            driver.calls(),
+            [
+                Call {
+                    name: "vkDestroySwapchainKHR",
+                    handles: vec![device.handle().as_raw(), raw_swapchain],
+                },
+                Call {
+                    name: "vkDestroySurfaceKHR",
+                    handles: vec![instance.handle().as_raw(), raw_surface],
+                },
+            ]
+        );
+
+        device.destroy_device(None);
+        instance.destroy_instance(None);
+    }
+    assert!(driver.live_handles().is_empty());
+}
+
+#[test]
+fn promoted_handles() {
+    let driver = MockDriver::new();
+    let (instance, device) = create_device(&driver);
+    let device_1_1 = Arc::new((*device).clone().with_api_version(vk::API_VERSION_1_1));
+    unsafe {
+        let conversion_fn = Arc::new(khr::SamplerYcbcrConversion::new(&instance, &device));
+        let conversion = conversion_fn
+            .create_sampler_ycbcr_conversion(&Default::default(), None)
+            .unwrap();
+        // Devices of Vulkan 1.0 do not destroy handles of promoted extensions with core commands
+        let parent = Parent::Device(device.clone());
+        let result = std::panic::catch_unwind(|| Owned::from_raw(parent, conversion));
+        assert!(result.is_err());
+
+        driver.clear_calls();
+        drop(Owned::from_raw(Parent::Device(device_1_1), conversion));
+        let conversion = conversion_fn
+            .create_sampler_ycbcr_conversion(&Default::default(), None)
+            .unwrap();
+        drop(Owned::from_raw(
+            Parent::KhrSamplerYcbcrConversion(conversion_fn),
+            conversion,
+        ));
+        // The mock driver records commands of promoted extensions under their core name
+        let calls = driver.calls();
+        assert_eq!(
+            calls.iter().map(|call| call.name).collect::<Vec<_>>(),
+            [
+                "vkDestroySamplerYcbcrConversion",
+                "vkCreateSamplerYcbcrConversion",
+                "vkDestroySamplerYcbcrConversion"
+            ]
+        );
+        assert_eq!(calls[2].handles[1], conversion.as_raw());
+        assert_eq!(driver.live_handles().len(), 2);
+
+        device.destroy_device(None);
+        instance.destroy_instance(None);
+    }
+}
+
+#[test]
+#[should_panic(expected = "does not destroy or free COMMAND_BUFFER")]
+fn wrong_parent() {
+    let driver = MockDriver::new();
+    let (_instance, device) = create_device(&driver);
+    let _ = unsafe { Owned::from_raw(Parent::Device(device), vk::CommandBuffer::null()) };
+}
@@ -0,0 +1,149 @@
+#![cfg(all(feature = "properties", feature = "mock"))]
+
+use ash::mock::MockDriver;
+use ash::properties::{Difference, Properties};
+use ash::vk;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+fn copy_str(dst: &mut [c_char], src: &CStr) {
+    for (c, &byte) in dst.iter_mut().zip(src.to_bytes_with_nul()) {
+        *c = byte as _;
+    }
+}
+
+/// Queries the properties of a device named `name` that supports `extensions`.
+fn query(
+    name: &'static CStr,
+    max_image_dimension: u32,
+    extensions: &'static [&CStr],
+) -> Properties {
+    let driver = MockDriver::new();
+    driver.on_get_physical_device_properties(move |_, p_properties| unsafe {
+        let properties = &mut *p_properties;
+        properties.api_version = vk::API_VERSION_1_2;
+        properties.limits.max_image_dimension2_d = max_image_dimension;
+        copy_str(&mut properties.device_name, name);
+    });
+    driver.on_enumerate_device_extension_properties(move |_, _, p_count, p_properties| unsafe {
+        if !p_properties.is_null() {
+            for (i, name) in extensions.iter().enumerate() {
+                copy_str(&mut (*p_properties.add(i)).extension_name, name);
+            }
+        }
+        *p_count = extensions.len() as u32;
+        vk::Result::SUCCESS
+    });
+    driver.on_get_physical_device_properties2(|_, p_properties| unsafe {
+        let mut next = (*p_properties).p_next.cast::<vk::BaseOutStructure<'_>>();
+        while let Some(base) = next.as_mut() {
+            match base.s_type {
+                vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES => {
+                    let vulkan12 = &mut *next.cast::<vk::PhysicalDeviceVulkan12Properties<'_>>();
+                    copy_str(
+                        &mut vulkan12.driver_name,
+                        CStr::from_bytes_with_nul(b"mock\0").unwrap(),
+                    );
+                }
+                vk::StructureType::PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR => {
+                    (*next.cast::<vk::PhysicalDevicePushDescriptorPropertiesKHR<'_>>())
+                        .max_push_descriptors = 32;
+                }
+                _ => {}
+            }
+            next = base.p_next;
+        }
+    });
+
+    let entry = driver.entry();
+    let app_info = vk::ApplicationInfo::default().api_version(vk::API_VERSION_1_3);
+    let create_info = vk::InstanceCreateInfo::default().application_info(&app_info);
+    unsafe {
+        let instance = entry.create_instance(&create_info, None).unwrap();
+        let physical_device = instance.enumerate_physical_devices().unwrap()[0];
+        let properties = Properties::query(&instance, physical_device).unwrap();
+        instance.destroy_instance(None);
+        properties
+    }
+}
+
+const GPU_A: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked(b"GPU A\0") };
+const GPU_B: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked(b"GPU B\0") };
+
+#[test]
+fn query_supported() {
+    let properties = query(GPU_A, 4096, &[vk::KhrPushDescriptorFn::NAME]);
+    assert_eq!(properties.properties().device_name_as_c_str(), Some(GPU_A));
+
+    let vulkan12 = properties
+        .get::<vk::PhysicalDeviceVulkan12Properties>()
+        .unwrap();
+    assert_eq!(vulkan12.driver_name_as_c_str().unwrap().to_bytes(), b"mock");
+    assert!(vulkan12.p_next.is_null());
+    let push_descriptor = properties
+        .get::<vk::PhysicalDevicePushDescriptorPropertiesKHR>()
+        .unwrap();
+    assert_eq!(push_descriptor.max_push_descriptors, 32);
+
+    // Vulkan 1.3 and extensions that the device does not support
+    assert!(properties
+        .get::<vk::PhysicalDeviceVulkan13Properties>()
+        .is_none());
+    assert!(properties
+        .get::<vk::PhysicalDeviceRayTracingPipelinePropertiesKHR>()
+        .is_none());
+}
+
+#[test]
+fn diff() {
+    let a = query(GPU_A, 4096, &[vk::KhrPushDescriptorFn::NAME]);
+    let b = query(GPU_B, 8192, &[]);
+    assert!(a.diff(&a.clone()).is_empty());
+
+    let differences = a.diff(&b);
+    assert_eq!(
+        differences,
+        [
+            Difference {
+                name: "VkPhysicalDeviceProperties::deviceName",
+                left: Some(r#""GPU A""#.to_owned()),
+                right: Some(r#""GPU B""#.to_owned()),
+            },
+            Difference {
+                name: "VkPhysicalDeviceProperties::limits.maxImageDimension2D",
+                left: Some("4096".to_owned()),
+                right: Some("8192".to_owned()),
+            },
+            Difference {
+                name: "VkPhysicalDevicePushDescriptorPropertiesKHR::maxPushDescriptors",
+                left: Some("32".to_owned()),
+                right: None,
+            },
+        ]
+    );
+    assert_eq!(
+        differences[2].to_string(),
+        "VkPhysicalDevicePushDescriptorPropertiesKHR::maxPushDescriptors: 32 != unsupported"
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serialize() {
+    let properties = query(GPU_A, 4096, &[vk::KhrPushDescriptorFn::NAME]);
+    let json = serde_json::to_value(&properties).unwrap();
+    assert_eq!(json["properties"]["limits"]["max_image_dimension2_d"], 4096);
+    assert!(json["p_next"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|extension| extension
+            .get("PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR")
+            .is_some()));
+
+    let deserialized = serde_json::from_value::<Properties>(json).unwrap();
+    assert!(deserialized.diff(&properties).is_empty());
+    assert!(deserialized
+        .get::<vk::PhysicalDeviceVulkan13Properties>()
+        .is_none());
+}
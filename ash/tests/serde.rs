@@ -0,0 +1,126 @@
+#![cfg(feature = "serde")]
+
+use ash::deep_copy::DeepCopy;
+use ash::vk;
+use std::ffi::CStr;
+use std::slice;
+
+#[test]
+fn enums_and_flags() {
+    let format = serde_json::to_string(&vk::Format::R8G8B8A8_UNORM).unwrap();
+    assert_eq!(format, r#""R8G8B8A8_UNORM""#);
+    assert_eq!(
+        serde_json::from_str::<vk::Format>(&format).unwrap(),
+        vk::Format::R8G8B8A8_UNORM
+    );
+    assert_eq!(
+        serde_json::from_str::<vk::Format>("1000156000").unwrap(),
+        vk::Format::G8B8G8R8_422_UNORM
+    );
+    assert_eq!(
+        serde_json::from_str::<vk::Format>(r#""VK_FORMAT_R8G8B8A8_UNORM""#).unwrap(),
+        vk::Format::R8G8B8A8_UNORM
+    );
+    assert!(serde_json::from_str::<vk::Format>(r#""R8G8B8A8_UNROM""#).is_err());
+
+    let aspects = vk::ImageAspectFlags::COLOR
+        | vk::ImageAspectFlags::DEPTH
+        | vk::ImageAspectFlags::from_raw(1 << 30);
+    let json = serde_json::to_string(&aspects).unwrap();
+    assert_eq!(json, r#""COLOR | DEPTH | 0x40000000""#);
+    assert_eq!(
+        serde_json::from_str::<vk::ImageAspectFlags>(&json).unwrap(),
+        aspects
+    );
+    assert_eq!(
+        serde_json::from_str::<vk::ImageAspectFlags>(r#""""#).unwrap(),
+        vk::ImageAspectFlags::empty()
+    );
+}
+
+#[test]
+fn plain_struct() {
+    let attachment = vk::PipelineColorBlendAttachmentState::default()
+        .blend_enable(true)
+        .src_color_blend_factor(vk::BlendFactor::SRC_ALPHA)
+        .dst_color_blend_factor(vk::BlendFactor::ONE_MINUS_SRC_ALPHA)
+        .color_write_mask(vk::ColorComponentFlags::RGBA);
+    let json = serde_json::to_value(attachment).unwrap();
+    assert_eq!(json["src_color_blend_factor"], "SRC_ALPHA");
+    assert_eq!(json["color_write_mask"], "R | G | B | A");
+    let copy: vk::PipelineColorBlendAttachmentState = serde_json::from_value(json).unwrap();
+    assert_eq!(copy.blend_enable, vk::TRUE);
+    assert_eq!(
+        copy.dst_color_blend_factor,
+        attachment.dst_color_blend_factor
+    );
+    assert_eq!(copy.color_write_mask, attachment.color_write_mask);
+
+    // Missing members keep their default value
+    let copy: vk::PipelineColorBlendAttachmentState =
+        serde_json::from_str(r#"{"alpha_blend_op":"MAX"}"#).unwrap();
+    assert_eq!(copy.alpha_blend_op, vk::BlendOp::MAX);
+    assert_eq!(copy.color_write_mask, vk::ColorComponentFlags::empty());
+
+    let mut properties = vk::PhysicalDeviceProperties::default();
+    for (c, &b) in properties.device_name.iter_mut().zip(b"Mock GPU") {
+        *c = b as _;
+    }
+    let json = serde_json::to_value(properties).unwrap();
+    assert_eq!(json["device_name"], "Mock GPU");
+    let copy: vk::PhysicalDeviceProperties = serde_json::from_value(json).unwrap();
+    assert_eq!(copy.device_name, properties.device_name);
+}
+
+#[test]
+fn pointer_chain() {
+    let name = b"main\0";
+    let stages = [vk::PipelineShaderStageCreateInfo::default()
+        .stage(vk::ShaderStageFlags::VERTEX)
+        .name(CStr::from_bytes_with_nul(name).unwrap())];
+    let color_formats = [vk::Format::R8G8B8A8_UNORM, vk::Format::R16G16B16A16_SFLOAT];
+    let mut rendering =
+        vk::PipelineRenderingCreateInfo::default().color_attachment_formats(&color_formats);
+    let create_info = vk::GraphicsPipelineCreateInfo::default()
+        .stages(&stages)
+        .push_next(&mut rendering);
+    let copy = unsafe { DeepCopy::new(&create_info) };
+
+    let json = serde_json::to_value(&copy).unwrap();
+    assert_eq!(json["p_stages"][0]["p_name"], "main");
+    assert_eq!(
+        json["p_next"][0]["PIPELINE_RENDERING_CREATE_INFO"]["p_color_attachment_formats"][1],
+        "R16G16B16A16_SFLOAT"
+    );
+
+    let rebuilt: DeepCopy<vk::GraphicsPipelineCreateInfo<'static>> =
+        serde_json::from_value(json).unwrap();
+    assert_eq!(rebuilt, copy);
+    let create_info = rebuilt.view();
+    unsafe {
+        let stages = slice::from_raw_parts(create_info.p_stages, create_info.stage_count as usize);
+        assert_eq!(CStr::from_ptr(stages[0].p_name).to_bytes(), b"main");
+        let rendering = &*create_info
+            .p_next
+            .cast::<vk::PipelineRenderingCreateInfo<'_>>();
+        assert_eq!(
+            rendering.s_type,
+            vk::StructureType::PIPELINE_RENDERING_CREATE_INFO
+        );
+        assert_eq!(
+            slice::from_raw_parts(
+                rendering.p_color_attachment_formats,
+                rendering.color_attachment_count as usize
+            ),
+            color_formats
+        );
+    }
+}
+
+#[test]
+fn short_array() {
+    let json = r#"{"stage_count":2,"p_stages":[{"stage":"VERTEX","p_name":"main"}]}"#;
+    let error = serde_json::from_str::<DeepCopy<vk::GraphicsPipelineCreateInfo<'static>>>(json)
+        .unwrap_err();
+    assert!(error.to_string().contains("p_stages"), "{}", error);
+}
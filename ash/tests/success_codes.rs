@@ -0,0 +1,42 @@
+use ash::{success_codes, vk};
+
+#[test]
+fn success_codes_are_ok() {
+    assert_eq!(
+        vk::Result::TIMEOUT.success_code(),
+        Ok(success_codes::WaitForFences::Timeout)
+    );
+    assert_eq!(
+        vk::Result::SUBOPTIMAL_KHR.success_code(),
+        Ok(success_codes::AcquireNextImageKHR::SuboptimalKHR)
+    );
+    assert_eq!(
+        vk::Result::OPERATION_DEFERRED_KHR.success_code(),
+        Ok(success_codes::BuildAccelerationStructuresKHR::OperationDeferredKHR)
+    );
+}
+
+#[test]
+fn other_codes_are_errors() {
+    assert_eq!(
+        vk::Result::ERROR_DEVICE_LOST.success_code::<success_codes::WaitForFences>(),
+        Err(vk::Result::ERROR_DEVICE_LOST)
+    );
+    // Success codes of other commands are not accepted either
+    assert_eq!(
+        vk::Result::SUBOPTIMAL_KHR.success_code::<success_codes::WaitForFences>(),
+        Err(vk::Result::SUBOPTIMAL_KHR)
+    );
+}
+
+#[test]
+fn aliased_codes() {
+    assert_eq!(
+        vk::Result::PIPELINE_COMPILE_REQUIRED_EXT.success_code(),
+        Ok(success_codes::CreateGraphicsPipelines::PipelineCompileRequired)
+    );
+    assert_eq!(
+        vk::Result::from(success_codes::CreateGraphicsPipelines::PipelineCompileRequired),
+        vk::Result::PIPELINE_COMPILE_REQUIRED
+    );
+}
@@ -0,0 +1,265 @@
+#![cfg(all(feature = "trace", feature = "mock"))]
+
+use ash::deep_copy::Value;
+use ash::mock::MockDriver;
+use ash::trace::{Recorder, ReplayError, Replayer, Trace};
+use ash::vk::{self, Handle};
+use ash::Instance;
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::c_char;
+use std::ptr;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicPtr, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, Once};
+
+/// Serializes the tests that record, as recording is global to the process.
+fn lock() -> MutexGuard<'static, ()> {
+    // `Mutex::new()` is only `const` since Rust 1.63
+    static LOCK: AtomicPtr<Mutex<()>> = AtomicPtr::new(ptr::null_mut());
+    static INIT: Once = Once::new();
+    INIT.call_once(|| LOCK.store(Box::into_raw(Box::default()), Ordering::Release));
+    let lock = unsafe { &*LOCK.load(Ordering::Acquire) };
+    lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[test]
+fn record_and_replay() {
+    let _lock = lock();
+    let driver = MockDriver::new();
+    let entry = driver.entry();
+    unsafe {
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        let recorder = Recorder::new(&instance);
+        let device = recorder
+            .instance()
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+
+        let mut export_info = vk::ExportFenceCreateInfo::default()
+            .handle_types(vk::ExternalFenceHandleTypeFlags::SYNC_FD);
+        let create_info = vk::FenceCreateInfo::default()
+            .flags(vk::FenceCreateFlags::SIGNALED)
+            .push_next(&mut export_info);
+        let fence = device.create_fence(&create_info, None).unwrap();
+        device.wait_for_fences(&[fence], true, 0).unwrap();
+        device.destroy_fence(fence, None);
+
+        let trace = recorder.take_trace();
+        let commands = trace
+            .calls
+            .iter()
+            .map(|call| call.command.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            commands,
+            ["vkCreateFence", "vkWaitForFences", "vkDestroyFence"]
+        );
+        let create = &trace.calls[0];
+        assert_eq!(
+            create.outputs,
+            [Value::Array(vec![Value::Handle(
+                vk::ObjectType::FENCE,
+                fence.as_raw()
+            )])]
+        );
+        assert_eq!(
+            create.result,
+            Value::Int(vk::Result::SUCCESS.as_raw() as u64)
+        );
+        assert!(recorder.take_trace().calls.is_empty());
+
+        let mut bytes = Vec::new();
+        trace.write_to(&mut bytes).unwrap();
+        assert_eq!(Trace::read_from(bytes.as_slice()).unwrap(), trace);
+
+        // Replay against another device, checking the rebuilt arguments
+        let replay_device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        let created = Rc::new(RefCell::new(None));
+        let handler_driver = driver.clone();
+        let handler_created = created.clone();
+        driver.on_create_fence(move |_device, p_create_info, _allocator, p_fence| {
+            let create_info = &*p_create_info;
+            let export_info = &*create_info.p_next.cast::<vk::ExportFenceCreateInfo<'_>>();
+            assert_eq!(create_info.flags, vk::FenceCreateFlags::SIGNALED);
+            assert_eq!(
+                export_info.s_type,
+                vk::StructureType::EXPORT_FENCE_CREATE_INFO
+            );
+            assert_eq!(
+                export_info.handle_types,
+                vk::ExternalFenceHandleTypeFlags::SYNC_FD
+            );
+            *p_fence = handler_driver.allocate();
+            *handler_created.borrow_mut() = Some(*p_fence);
+            vk::Result::SUCCESS
+        });
+
+        driver.clear_calls();
+        let mut replayer = Replayer::new(&instance, &replay_device);
+        replayer.replay(&trace).unwrap();
+        let replayed_fence = created.borrow().unwrap();
+        assert_ne!(replayed_fence, fence);
+        assert_eq!(
+            replayer.arena().handle(fence.as_raw()),
+            replayed_fence.as_raw()
+        );
+
+        let calls = driver.calls();
+        let names = calls.iter().map(|call| call.name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["vkCreateFence", "vkWaitForFences", "vkDestroyFence"]
+        );
+        assert_eq!(
+            calls[2].handles,
+            [replay_device.handle().as_raw(), replayed_fence.as_raw()]
+        );
+    }
+}
+
+#[test]
+fn invalid_arguments() {
+    let driver = MockDriver::new();
+    let entry = driver.entry();
+    unsafe {
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        let mut replayer = Replayer::new(&instance, &device);
+        let mut call = ash::trace::Call {
+            command: "vkDestroyFence".to_owned(),
+            args: vec![Value::Handle(vk::ObjectType::DEVICE, 1)],
+            outputs: Vec::new(),
+            result: Value::Null,
+        };
+        assert!(matches!(
+            replayer.replay_call(&call),
+            Err(ReplayError::InvalidArguments(command, _)) if command == "vkDestroyFence"
+        ));
+
+        // A handle where a struct is expected
+        call.command = "vkCreateFence".to_owned();
+        call.args = vec![
+            Value::Handle(vk::ObjectType::DEVICE, 1),
+            Value::Array(vec![Value::Handle(vk::ObjectType::FENCE, 2)]),
+            Value::Null,
+            Value::Array(vec![Value::Null]),
+        ];
+        assert!(matches!(
+            replayer.replay_call(&call),
+            Err(ReplayError::InvalidArguments(_, error)) if error.expected.starts_with("a struct")
+        ));
+        assert!(driver
+            .calls()
+            .iter()
+            .all(|call| call.name != "vkCreateFence"));
+    }
+}
+
+#[test]
+fn unknown_command() {
+    let driver = MockDriver::new();
+    let entry = driver.entry();
+    unsafe {
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        let mut trace = Trace::default();
+        trace.calls.push(ash::trace::Call {
+            command: "vkCreateInstance".to_owned(),
+            args: Vec::new(),
+            outputs: Vec::new(),
+            result: Value::Null,
+        });
+        assert!(matches!(
+            Replayer::new(&instance, &device).replay(&trace),
+            Err(ReplayError::UnknownCommand(command)) if command == "vkCreateInstance"
+        ));
+    }
+}
+
+static NEXT_GET_DEVICE_PROC_ADDR: AtomicUsize = AtomicUsize::new(0);
+static LOST_DEVICE: AtomicU64 = AtomicU64::new(0);
+
+unsafe extern "system" fn lost_fence_status(_device: vk::Device, _fence: vk::Fence) -> vk::Result {
+    vk::Result::ERROR_DEVICE_LOST
+}
+
+unsafe extern "system" fn get_device_proc_addr(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    let _ = LOST_DEVICE.compare_exchange(0, device.as_raw(), Ordering::Relaxed, Ordering::Relaxed);
+    if LOST_DEVICE.load(Ordering::Relaxed) == device.as_raw()
+        && CStr::from_ptr(p_name).to_bytes() == b"vkGetFenceStatus"
+    {
+        return Some(mem::transmute::<
+            vk::PFN_vkGetFenceStatus,
+            unsafe extern "system" fn(),
+        >(lost_fence_status));
+    }
+    let next = NEXT_GET_DEVICE_PROC_ADDR.load(Ordering::Relaxed);
+    mem::transmute::<usize, vk::PFN_vkGetDeviceProcAddr>(next)(device, p_name)
+}
+
+#[test]
+fn forwards_per_device() {
+    let _lock = lock();
+    let driver = MockDriver::new();
+    let entry = driver.entry();
+    unsafe {
+        let instance = entry.create_instance(&Default::default(), None).unwrap();
+        NEXT_GET_DEVICE_PROC_ADDR.store(
+            instance.fp_v1_0().get_device_proc_addr as usize,
+            Ordering::Relaxed,
+        );
+        let instance = Instance::from_parts_1_3(
+            instance.handle(),
+            vk::InstanceFnV1_0 {
+                get_device_proc_addr,
+                ..instance.fp_v1_0().clone()
+            },
+            instance.fp_v1_1().clone(),
+            instance.fp_v1_3().clone(),
+        );
+        let recorder = Recorder::new(&instance);
+        let lost_device = recorder
+            .instance()
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+        let device = recorder
+            .instance()
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+
+        // Each device calls the commands loaded for it, not those of the last loaded device
+        let fence = lost_device.create_fence(&Default::default(), None).unwrap();
+        assert_eq!(
+            lost_device.get_fence_status(fence),
+            Err(vk::Result::ERROR_DEVICE_LOST)
+        );
+        let fence = device.create_fence(&Default::default(), None).unwrap();
+        assert_eq!(device.get_fence_status(fence), Ok(true));
+
+        let results = recorder
+            .take_trace()
+            .calls
+            .into_iter()
+            .filter(|call| call.command == "vkGetFenceStatus")
+            .map(|call| call.result)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            [
+                Value::Int(vk::Result::ERROR_DEVICE_LOST.as_raw() as u64),
+                Value::Int(vk::Result::SUCCESS.as_raw() as u64)
+            ]
+        );
+    }
+}
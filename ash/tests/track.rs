@@ -0,0 +1,276 @@
+#![cfg(all(feature = "track", feature = "mock"))]
+
+use ash::mock::MockDriver;
+use ash::track::{Tracker, Violation};
+use ash::vk::{self, Handle};
+use ash::Entry;
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, Once};
+
+/// Serializes the tests, as tracking is global to the process.
+fn lock() -> MutexGuard<'static, ()> {
+    // `Mutex::new()` is only `const` since Rust 1.63
+    static LOCK: AtomicPtr<Mutex<()>> = AtomicPtr::new(ptr::null_mut());
+    static INIT: Once = Once::new();
+    INIT.call_once(|| LOCK.store(Box::into_raw(Box::default()), Ordering::Release));
+    let lock = unsafe { &*LOCK.load(Ordering::Acquire) };
+    lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn summary(violations: &[Violation]) -> Vec<(&'static str, &'static str, vk::ObjectType)> {
+    violations
+        .iter()
+        .map(|violation| {
+            let (kind, command) = match violation {
+                Violation::Leak { command, .. } => ("leak", *command),
+                Violation::DoubleDestroy { command, .. } => ("double destroy", *command),
+                Violation::UseAfterDestroy { command, .. } => ("use after destroy", *command),
+            };
+            (kind, command, violation.object().ty)
+        })
+        .collect()
+}
+
+#[test]
+fn leaks() {
+    let _lock = lock();
+    let driver = MockDriver::new();
+    unsafe {
+        let tracker = Tracker::new(&driver.entry());
+        let instance = tracker
+            .entry()
+            .create_instance(&Default::default(), None)
+            .unwrap();
+        let physical_device = instance.enumerate_physical_devices().unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default(), None)
+            .unwrap();
+        let _queue = device.get_device_queue(0, 0);
+        let _buffer = device.create_buffer(&Default::default(), None).unwrap();
+        let pool = device
+            .create_command_pool(&Default::default(), None)
+            .unwrap();
+        let allocate_info = vk::CommandBufferAllocateInfo::default()
+            .command_pool(pool)
+            .command_buffer_count(2);
+        let command_buffers = device.allocate_command_buffers(&allocate_info).unwrap();
+
+        let live = tracker.live_objects();
+        assert_eq!(
+            live.iter().map(|object| object.ty).collect::<Vec<_>>(),
+            [
+                vk::ObjectType::INSTANCE,
+                vk::ObjectType::PHYSICAL_DEVICE,
+                vk::ObjectType::DEVICE,
+                vk::ObjectType::QUEUE,
+                vk::ObjectType::BUFFER,
+                vk::ObjectType::COMMAND_POOL,
+                vk::ObjectType::COMMAND_BUFFER,
+                vk::ObjectType::COMMAND_BUFFER,
+            ]
+        );
+        assert_eq!(
+            live[2].parent,
+            Some((vk::ObjectType::PHYSICAL_DEVICE, physical_device.as_raw()))
+        );
+        assert!(!live[3].created);
+        assert_eq!(live[6].raw, command_buffers[0].as_raw());
+        assert_eq!(
+            live[6].parent,
+            Some((vk::ObjectType::COMMAND_POOL, pool.as_raw()))
+        );
+        assert!(tracker.take_violations().is_empty());
+
+        // The command buffers are freed with their pool, the queue is not created by the
+        // application
+        device.destroy_device(None);
+        let violations = tracker.take_violations();
+        assert_eq!(
+            summary(&violations),
+            [
+                ("leak", "vkDestroyDevice", vk::ObjectType::BUFFER),
+                ("leak", "vkDestroyDevice", vk::ObjectType::COMMAND_POOL),
+            ]
+        );
+        assert!(violations[0].to_string().starts_with("BUFFER 0x"));
+        assert!(violations[0]
+            .to_string()
+            .contains("from vkCreateBuffer was not destroyed before vkDestroyDevice"));
+
+        instance.destroy_instance(None);
+        assert!(tracker.take_violations().is_empty());
+        assert!(tracker.live_objects().is_empty());
+    }
+}
+
+#[test]
+fn misuse() {
+    let _lock = lock();
+    let driver = MockDriver::new();
+    unsafe {
+        let tracker = Tracker::new(&driver.entry());
+        let instance = tracker
+            .entry()
+            .create_instance(&Default::default(), None)
+            .unwrap();
+        let device = instance
+            .create_device(vk::PhysicalDevice::null(), &Default::default(), None)
+            .unwrap();
+
+        let fence = device.create_fence(&Default::default(), None).unwrap();
+        device.destroy_fence(fence, None);
+        let _ = device.get_fence_status(fence);
+        device.destroy_fence(fence, None);
+
+        let pool = device
+            .create_command_pool(&Default::default(), None)
+            .unwrap();
+        let allocate_info = vk::CommandBufferAllocateInfo::default()
+            .command_pool(pool)
+            .command_buffer_count(1);
+        let command_buffers = device.allocate_command_buffers(&allocate_info).unwrap();
+        device.destroy_command_pool(pool, None);
+        device.free_command_buffers(pool, &command_buffers);
+
+        assert_eq!(
+            summary(&tracker.take_violations()),
+            [
+                (
+                    "use after destroy",
+                    "vkGetFenceStatus",
+                    vk::ObjectType::FENCE
+                ),
+                ("double destroy", "vkDestroyFence", vk::ObjectType::FENCE),
+                (
+                    "use after destroy",
+                    "vkFreeCommandBuffers",
+                    vk::ObjectType::COMMAND_POOL
+                ),
+                (
+                    "double destroy",
+                    "vkFreeCommandBuffers",
+                    vk::ObjectType::COMMAND_BUFFER
+                ),
+            ]
+        );
+
+        // Resetting a descriptor pool frees its sets
+        let pool = device
+            .create_descriptor_pool(&Default::default(), None)
+            .unwrap();
+        let layouts = [vk::DescriptorSetLayout::null()];
+        let allocate_info = vk::DescriptorSetAllocateInfo::default()
+            .descriptor_pool(pool)
+            .set_layouts(&layouts);
+        let sets = device.allocate_descriptor_sets(&allocate_info).unwrap();
+        assert_eq!(tracker.live_objects().len(), 4);
+        device
+            .reset_descriptor_pool(pool, Default::default())
+            .unwrap();
+        assert_eq!(tracker.live_objects().len(), 3);
+        device.free_descriptor_sets(pool, &sets).unwrap();
+        assert_eq!(
+            summary(&tracker.take_violations()),
+            [(
+                "double destroy",
+                "vkFreeDescriptorSets",
+                vk::ObjectType::DESCRIPTOR_SET
+            )]
+        );
+
+        device.destroy_descriptor_pool(pool, None);
+        device.destroy_device(None);
+        instance.destroy_instance(None);
+        assert!(tracker.take_violations().is_empty());
+    }
+}
+
+/// The `vkGetInstanceProcAddr` and `vkGetDeviceProcAddr` of the mock driver.
+static NEXT_GET_INSTANCE_PROC_ADDR: AtomicUsize = AtomicUsize::new(0);
+static NEXT_GET_DEVICE_PROC_ADDR: AtomicUsize = AtomicUsize::new(0);
+/// The first device that commands are loaded for, whose fences are lost.
+static LOST_DEVICE: AtomicU64 = AtomicU64::new(0);
+
+unsafe extern "system" fn lost_fence_status(_device: vk::Device, _fence: vk::Fence) -> vk::Result {
+    vk::Result::ERROR_DEVICE_LOST
+}
+
+unsafe extern "system" fn get_device_proc_addr(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    let _ = LOST_DEVICE.compare_exchange(0, device.as_raw(), Ordering::Relaxed, Ordering::Relaxed);
+    if LOST_DEVICE.load(Ordering::Relaxed) == device.as_raw()
+        && CStr::from_ptr(p_name).to_bytes() == b"vkGetFenceStatus"
+    {
+        return Some(mem::transmute::<
+            vk::PFN_vkGetFenceStatus,
+            unsafe extern "system" fn(),
+        >(lost_fence_status));
+    }
+    let next = NEXT_GET_DEVICE_PROC_ADDR.load(Ordering::Relaxed);
+    mem::transmute::<usize, vk::PFN_vkGetDeviceProcAddr>(next)(device, p_name)
+}
+
+unsafe extern "system" fn get_instance_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    let next = NEXT_GET_INSTANCE_PROC_ADDR.load(Ordering::Relaxed);
+    let f = mem::transmute::<usize, vk::PFN_vkGetInstanceProcAddr>(next)(instance, p_name)?;
+    if CStr::from_ptr(p_name).to_bytes() != b"vkGetDeviceProcAddr" {
+        return Some(f);
+    }
+    NEXT_GET_DEVICE_PROC_ADDR.store(f as usize, Ordering::Relaxed);
+    Some(mem::transmute::<
+        vk::PFN_vkGetDeviceProcAddr,
+        unsafe extern "system" fn(),
+    >(get_device_proc_addr))
+}
+
+#[test]
+fn forwards_per_device() {
+    let _lock = lock();
+    let driver = MockDriver::new();
+    NEXT_GET_INSTANCE_PROC_ADDR.store(
+        driver.entry().static_fn().get_instance_proc_addr as usize,
+        Ordering::Relaxed,
+    );
+    unsafe {
+        let entry = Entry::from_static_fn(vk::StaticFn {
+            get_instance_proc_addr,
+        });
+        let tracker = Tracker::new(&entry);
+        let instance = tracker
+            .entry()
+            .create_instance(&Default::default(), None)
+            .unwrap();
+        let physical_device = instance.enumerate_physical_devices().unwrap()[0];
+        let lost_device = instance
+            .create_device(physical_device, &Default::default(), None)
+            .unwrap();
+        let device = instance
+            .create_device(physical_device, &Default::default(), None)
+            .unwrap();
+
+        // Each device calls the commands loaded for it, not those of the last loaded device
+        let fence = lost_device.create_fence(&Default::default(), None).unwrap();
+        assert_eq!(
+            lost_device.get_fence_status(fence),
+            Err(vk::Result::ERROR_DEVICE_LOST)
+        );
+        lost_device.destroy_fence(fence, None);
+        let fence = device.create_fence(&Default::default(), None).unwrap();
+        assert_eq!(device.get_fence_status(fence), Ok(true));
+        device.destroy_fence(fence, None);
+
+        lost_device.destroy_device(None);
+        device.destroy_device(None);
+        instance.destroy_instance(None);
+        assert!(tracker.take_violations().is_empty());
+    }
+}
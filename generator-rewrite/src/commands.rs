@@ -0,0 +1,445 @@
+//! Function pointer tables of core versions and extensions.
+
+use crate::{
+    generator::Generator,
+    naming::{field_ident, type_ident},
+    types::type_tokens,
+};
+use analysis::xml::{
+    Command, Depends, EnumValue, EnumValueKind, Feature, Require, RequireEnum, RequireItem,
+};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use std::collections::{HashMap, HashSet};
+
+/// Generates a `vk::Depends` expression from the `depends` attribute of an extension.
+fn generate_depends(depends: &Depends) -> TokenStream {
+    match depends {
+        Depends::Name(name) => {
+            if let Some(version) = name.strip_prefix("VK_VERSION_") {
+                let version = format_ident!("API_VERSION_{}", version);
+                quote!(crate::vk::Depends::Version(crate::vk::#version))
+            } else {
+                let name = Literal::byte_string(format!("{name}\0").as_bytes());
+                quote!(crate::vk::Depends::Extension(unsafe {
+                    ::std::ffi::CStr::from_bytes_with_nul_unchecked(#name)
+                }))
+            }
+        }
+        Depends::All(terms) => {
+            let terms = terms.iter().map(generate_depends);
+            quote!(crate::vk::Depends::All(&[#(#terms),*]))
+        }
+        Depends::Any(terms) => {
+            let terms = terms.iter().map(generate_depends);
+            quote!(crate::vk::Depends::Any(&[#(#terms),*]))
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum FunctionType {
+    Static,
+    Entry,
+    Instance,
+    Device,
+}
+
+pub fn function_type(command: &Command) -> FunctionType {
+    let is_first_param_device = command.params.first().is_some_and(|param| {
+        matches!(
+            param.decl.ty.name.as_str(),
+            "VkDevice" | "VkCommandBuffer" | "VkQueue"
+        )
+    });
+    match command.name.as_str() {
+        "vkGetInstanceProcAddr" => FunctionType::Static,
+        "vkCreateInstance"
+        | "vkEnumerateInstanceLayerProperties"
+        | "vkEnumerateInstanceExtensionProperties"
+        | "vkEnumerateInstanceVersion" => FunctionType::Entry,
+        // This is actually not a device level function
+        "vkGetDeviceProcAddr" => FunctionType::Instance,
+        _ if is_first_param_device => FunctionType::Device,
+        _ => FunctionType::Instance,
+    }
+}
+
+struct FunctionPointer<'a> {
+    type_needs_defining: bool,
+    type_name: Ident,
+    pfn_type_name: Ident,
+    function_name_c: &'a str,
+    function_name_rust: Ident,
+    parameters: TokenStream,
+    parameters_unused: TokenStream,
+    returns: TokenStream,
+    parameter_validstructs: Vec<(Ident, &'a [String])>,
+}
+
+impl ToTokens for FunctionPointer<'_> {
+    /// The member of the function pointer table
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let type_name = &self.pfn_type_name;
+        let type_name = if self.type_needs_defining {
+            // Type is defined in local scope
+            quote!(#type_name)
+        } else {
+            // Type is usually defined in another module
+            quote!(crate::vk::#type_name)
+        };
+        let function_name_rust = &self.function_name_rust;
+        quote!(pub #function_name_rust: #type_name).to_tokens(tokens)
+    }
+}
+
+impl FunctionPointer<'_> {
+    fn param_traits(&self) -> TokenStream {
+        let mut tokens = TokenStream::new();
+        for (param_ident, validstructs) in &self.parameter_validstructs {
+            let param_ident = param_ident.to_string();
+            let param_ident = param_ident
+                .strip_prefix("pp_")
+                .or_else(|| param_ident.strip_prefix("p_"))
+                .unwrap_or(&param_ident);
+
+            let doc_string = format!(
+                "Implemented for all types that can be passed as argument to `{}` in [`{}`]",
+                param_ident, self.pfn_type_name
+            );
+            let param_trait_name = format_ident!(
+                "{}Param{}",
+                self.type_name,
+                param_ident.to_upper_camel_case()
+            );
+            tokens.extend(quote! {
+                #[allow(non_camel_case_types)]
+                #[doc = #doc_string]
+                pub unsafe trait #param_trait_name {}
+            });
+
+            for validstruct in validstructs.iter() {
+                let structname = type_ident(validstruct);
+                tokens.extend(quote!(unsafe impl #param_trait_name for #structname<'_> {}));
+            }
+        }
+        tokens
+    }
+
+    fn type_definition(&self) -> TokenStream {
+        let type_name = &self.pfn_type_name;
+        let parameters = &self.parameters;
+        let returns = &self.returns;
+        quote!(
+            #[allow(non_camel_case_types)]
+            pub type #type_name = unsafe extern "system" fn(#parameters) #returns;
+        )
+    }
+
+    fn loader(&self) -> TokenStream {
+        let function_name_rust = &self.function_name_rust;
+        let parameters_unused = &self.parameters_unused;
+        let returns = &self.returns;
+
+        let byte_function_name =
+            Literal::byte_string(format!("{}\0", self.function_name_c).as_bytes());
+
+        quote!(
+            #function_name_rust: unsafe {
+                unsafe extern "system" fn #function_name_rust (#parameters_unused) #returns {
+                    panic!(concat!("Unable to load ", stringify!(#function_name_rust)))
+                }
+                let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(#byte_function_name);
+                let val = _f(cname);
+                report(cname, !val.is_null());
+                if val.is_null() {
+                    #function_name_rust
+                } else {
+                    ::std::mem::transmute(val)
+                }
+            }
+        )
+    }
+}
+
+impl<'a> Generator<'a> {
+    /// Generates the struct `ident` holding a pointer to each of `commands`, and its loader.
+    /// `rename_commands` maps commands to the (aliased) name they are loaded by.
+    fn generate_function_pointers(
+        &mut self,
+        ident: Ident,
+        commands: &[&'a Command],
+        rename_commands: &HashMap<&'a str, &'a str>,
+    ) -> TokenStream {
+        // Commands can have duplicates inside them because they are declared per features. But we only
+        // really want to generate one function pointer.
+        let mut seen = HashSet::new();
+        let commands = commands
+            .iter()
+            .filter(|cmd| seen.insert(cmd.name.as_str()))
+            .map(|cmd| {
+                let name = cmd.name.as_str();
+                let pfn_type_name = format_ident!("PFN_{}", name);
+
+                // We might need to generate a function pointer for an extension, where we are given the original
+                // `cmd` and a rename back to the extension alias (typically with vendor suffix) in `rename_commands`:
+                let function_name_c = rename_commands.get(name).copied().unwrap_or(name);
+
+                let type_name = function_name_c.strip_prefix("vk").unwrap();
+                let function_name_rust = format_ident!("{}", type_name.to_snake_case());
+                let type_name = format_ident!("{}", type_name);
+
+                let params = cmd
+                    .params
+                    .iter()
+                    .filter(|param| self.is_api(&param.api))
+                    .collect::<Vec<_>>();
+
+                let params_tokens = params
+                    .iter()
+                    .map(|param| {
+                        (
+                            field_ident(&param.decl.name),
+                            type_tokens(&param.decl.ty, true),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                let params_iter = params_tokens
+                    .iter()
+                    .map(|(param_name, param_ty)| quote!(#param_name: #param_ty));
+                let parameters = quote!(#(#params_iter,)*);
+
+                let params_iter = params_tokens.iter().map(|(param_name, param_ty)| {
+                    let unused_name = format_ident!("_{}", param_name);
+                    quote!(#unused_name: #param_ty)
+                });
+                let parameters_unused = quote!(#(#params_iter,)*);
+
+                let parameter_validstructs = params
+                    .iter()
+                    .filter(|param| !param.valid_structs.is_empty())
+                    .map(|param| {
+                        (
+                            field_ident(&param.decl.name),
+                            param.valid_structs.as_slice(),
+                        )
+                    })
+                    .collect();
+
+                FunctionPointer {
+                    // PFN function pointers are global and can not have duplicates.
+                    // This can happen because there are aliases to commands
+                    type_needs_defining: self.fn_cache.insert(name),
+                    type_name,
+                    pfn_type_name,
+                    function_name_c,
+                    function_name_rust,
+                    parameters,
+                    parameters_unused,
+                    returns: match &cmd.return_type {
+                        None => quote!(),
+                        Some(ret) => {
+                            let ret_ty_tokens = type_ident(&ret.name);
+                            quote!(-> #ret_ty_tokens)
+                        }
+                    },
+                    parameter_validstructs,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let loaders = commands.iter().map(FunctionPointer::loader);
+        let loader = (!commands.is_empty()).then(|| {
+            quote! {
+                impl #ident {
+                    pub fn load<F>(f: F) -> Self
+                        where F: FnMut(&::std::ffi::CStr) -> *const c_void
+                    {
+                        Self::load_reporting(f, |_, _| ())
+                    }
+
+                    /// Loads the table like [`Self::load()`], reporting which commands were found.
+                    pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
+                        where F: FnMut(&::std::ffi::CStr) -> *const c_void
+                    {
+                        let mut report = crate::vk::LoadReport::default();
+                        let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+                        (table, report)
+                    }
+
+                    fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+                        where
+                            F: FnMut(&::std::ffi::CStr) -> *const c_void,
+                            R: FnMut(&'static ::std::ffi::CStr, bool),
+                    {
+                        Self {
+                            #(#loaders,)*
+                        }
+                    }
+                }
+            }
+        });
+
+        let param_traits = commands.iter().map(FunctionPointer::param_traits);
+        let pfn_typedefs = commands
+            .iter()
+            .filter(|pfn| pfn.type_needs_defining)
+            .map(FunctionPointer::type_definition);
+
+        let struct_contents = if commands.is_empty() {
+            quote! { pub struct #ident; }
+        } else {
+            quote! {
+                pub struct #ident {
+                    #(#commands,)*
+                }
+
+                unsafe impl Send for #ident {}
+                unsafe impl Sync for #ident {}
+            }
+        };
+
+        quote! {
+            #(#param_traits)*
+            #(#pfn_typedefs)*
+
+            #[derive(Clone)]
+            #struct_contents
+
+            #loader
+        }
+    }
+
+    pub fn generate_extension_commands(
+        &mut self,
+        extension_name: &str,
+        depends: Option<&Depends>,
+        requires: &'a [Require],
+    ) -> TokenStream {
+        let mut commands = Vec::new();
+        let mut rename_commands = HashMap::new();
+        let names = requires
+            .iter()
+            .filter(|require| self.is_api(&require.api))
+            .flat_map(|require| &require.items)
+            .filter_map(|item| match item {
+                RequireItem::Command { name, .. } => Some(name.as_str()),
+                _ => None,
+            });
+
+        // Collect a subset of commands to generate
+        for mut name in names {
+            if let Some(&cmd) = self.cmd_aliases.get(name) {
+                // This extension is referencing the base command under a different name,
+                // make sure it is generated with a rename to it.
+                rename_commands.insert(cmd, name);
+                name = cmd;
+            }
+
+            commands.push(self.commands[name]);
+        }
+
+        let ident = format_ident!(
+            "{}Fn",
+            extension_name
+                .to_upper_camel_case()
+                .strip_prefix("Vk")
+                .unwrap()
+        );
+        let fp = self.generate_function_pointers(ident.clone(), &commands, &rename_commands);
+
+        let spec_version = requires
+            .iter()
+            .flat_map(|require| &require.items)
+            .filter_map(|item| match item {
+                RequireItem::Enum(e) => Some(e),
+                _ => None,
+            })
+            .find(|e| e.name().contains("SPEC_VERSION"))
+            .and_then(|e| match e {
+                RequireEnum::Define(EnumValue {
+                    value: EnumValueKind::Value(value),
+                    ..
+                }) => {
+                    let v: u32 = value.parse().unwrap();
+                    Some(quote!(pub const SPEC_VERSION: u32 = #v;))
+                }
+                _ => None,
+            });
+
+        let depends = match depends {
+            Some(depends) => generate_depends(depends),
+            None => quote!(crate::vk::Depends::NONE),
+        };
+
+        let byte_name_ident = Literal::byte_string(format!("{extension_name}\0").as_bytes());
+        quote! {
+            impl #ident {
+                pub const NAME: &'static ::std::ffi::CStr = unsafe {
+                    ::std::ffi::CStr::from_bytes_with_nul_unchecked(#byte_name_ident)
+                };
+                #spec_version
+                pub const DEPENDS: crate::vk::Depends = #depends;
+            }
+            #fp
+        }
+    }
+
+    pub fn generate_feature(&mut self, feature: &'a Feature) -> TokenStream {
+        let (mut static_commands, mut entry_commands, mut device_commands, mut instance_commands) =
+            (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        let names = feature
+            .requires
+            .iter()
+            .flat_map(|require| &require.items)
+            .filter_map(|item| match item {
+                RequireItem::Command { name, .. } => Some(name.as_str()),
+                _ => None,
+            });
+        for name in names {
+            let Some(&command) = self.commands.get(name) else {
+                continue;
+            };
+            match function_type(command) {
+                FunctionType::Static => static_commands.push(command),
+                FunctionType::Entry => entry_commands.push(command),
+                FunctionType::Device => device_commands.push(command),
+                FunctionType::Instance => instance_commands.push(command),
+            }
+        }
+
+        let version = format!("{}_{}", feature.major, feature.minor);
+        let static_fn = if (feature.major, feature.minor) == (1, 0) {
+            self.generate_function_pointers(
+                format_ident!("StaticFn"),
+                &static_commands,
+                &HashMap::new(),
+            )
+        } else {
+            quote!()
+        };
+        let entry = self.generate_function_pointers(
+            format_ident!("EntryFnV{}", version),
+            &entry_commands,
+            &HashMap::new(),
+        );
+        let instance = self.generate_function_pointers(
+            format_ident!("InstanceFnV{}", version),
+            &instance_commands,
+            &HashMap::new(),
+        );
+        let device = self.generate_function_pointers(
+            format_ident!("DeviceFnV{}", version),
+            &device_commands,
+            &HashMap::new(),
+        );
+        quote! {
+            #static_fn
+            #entry
+            #instance
+            #device
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Type definitions that are neither enumerations nor structs: version defines, base types,
+//! bitmasks without bits, handles, function pointers and aliases.
+
+use crate::{
+    generator::Generator,
+    naming::{constant_name, khronos_link, type_ident},
+    types::{convert_c_expression, discard_outmost_delimiter, type_tokens},
+};
+use analysis::xml::{BaseType, Bitmask, Define, FuncPointer, Handle, HandleKind, TypeAlias};
+use heck::ToShoutySnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// A C macro definition, optionally prefixed by a comment.
+struct CDefine<'a> {
+    comment: Option<&'a str>,
+    name: &'a str,
+    parameters: Option<Vec<&'a str>>,
+    expr: &'a str,
+}
+
+fn parse_c_define_header(code: &str) -> Option<CDefine<'_>> {
+    let (comment, code) = match code.strip_prefix("//") {
+        Some(rest) => {
+            let (comment, rest) = rest.split_once('\n')?;
+            (Some(comment), rest)
+        }
+        None => (None, code),
+    };
+    let code = code.strip_prefix("#define ")?;
+    let name_len = code
+        .find(|c: char| !(c == '_' || c.is_alphanumeric()))
+        .unwrap_or(code.len());
+    let (name, code) = code.split_at(name_len);
+    if name.is_empty() {
+        return None;
+    }
+    let (parameters, expr) = match code.strip_prefix('(') {
+        Some(rest) => {
+            let (parameters, expr) = rest.split_once(')')?;
+            (Some(parameters.split(", ").collect()), expr)
+        }
+        None => (None, code),
+    };
+    Some(CDefine {
+        comment,
+        name,
+        parameters,
+        expr,
+    })
+}
+
+impl Generator<'_> {
+    /// Generates the `VK_*VERSION*` macros as constants and `const fn`s; other defines are C
+    /// specific.
+    pub fn generate_define(&mut self, define: &Define) -> TokenStream {
+        if !define.name.contains("VERSION") || define.code.contains("//#define") {
+            return quote!();
+        }
+        let Some(CDefine {
+            comment,
+            name: define_name,
+            parameters,
+            expr: c_expr,
+        }) = parse_c_define_header(&define.code)
+        else {
+            return quote!();
+        };
+        if define_name != define.name {
+            return quote!();
+        }
+
+        let name = constant_name(define_name);
+        let link = khronos_link(define_name);
+        let c_expr = c_expr.trim().trim_start_matches('\\');
+        let c_expr = c_expr.replace("(uint32_t)", "");
+        let c_expr = convert_c_expression(&c_expr, &self.identifier_renames);
+        let c_expr = discard_outmost_delimiter(c_expr);
+
+        let deprecated = comment
+            .and_then(|c| c.trim().strip_prefix("DEPRECATED: "))
+            .map(|comment| quote!(#[deprecated = #comment]))
+            .or_else(|| match define.deprecated.as_ref()?.as_str() {
+                "true" => Some(quote!(#[deprecated])),
+                "aliased" => {
+                    Some(quote!(#[deprecated = "an old name not following Vulkan conventions"]))
+                }
+                x => panic!("Unknown deprecation reason {}", x),
+            });
+
+        let (code, ident) = if let Some(parameters) = parameters {
+            let params = parameters
+                .iter()
+                .map(|param| format_ident!("{}", param))
+                .map(|i| quote!(#i: u32));
+            let ident = format_ident!("{}", name.to_lowercase());
+            (
+                quote!(pub const fn #ident(#(#params),*) -> u32 { #c_expr }),
+                ident,
+            )
+        } else {
+            let ident = format_ident!("{}", name);
+            (quote!(pub const #ident: u32 = #c_expr;), ident)
+        };
+
+        self.identifier_renames.insert(define.name.clone(), ident);
+
+        quote! {
+            #deprecated
+            #[doc = #link]
+            #code
+        }
+    }
+
+    pub fn generate_typedef(&self, typedef: &BaseType) -> TokenStream {
+        // Ignore forward declarations
+        let Some(ty) = &typedef.ty else {
+            return quote!();
+        };
+        let typedef_name = type_ident(&typedef.name);
+        let typedef_ty = type_ident(&ty.name);
+        let khronos_link = khronos_link(&typedef.name);
+        quote! {
+            #[doc = #khronos_link]
+            pub type #typedef_name = #typedef_ty;
+        }
+    }
+
+    /// Generates bitmasks that have no bits defined yet. The others are generated together with
+    /// their bits by [`Self::generate_enum()`].
+    pub fn generate_bitmask(&mut self, bitmask: &Bitmask) -> Option<TokenStream> {
+        if bitmask.bits.is_some() {
+            return None;
+        }
+
+        let ident = type_ident(&bitmask.name);
+        if !self.bitflags_cache.insert(ident.to_string()) {
+            return None;
+        };
+        self.const_values
+            .insert(ident.to_string(), Default::default());
+        let khronos_link = khronos_link(&bitmask.name);
+        let type_ = type_ident(&bitmask.ty);
+        Some(quote! {
+            #[repr(transparent)]
+            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[doc = #khronos_link]
+            pub struct #ident(pub(crate) #type_);
+            vk_bitflags_wrapped!(#ident, #type_);
+        })
+    }
+
+    pub fn generate_handle(&self, handle: &Handle) -> TokenStream {
+        let khronos_link = khronos_link(&handle.name);
+        let name = type_ident(&handle.name);
+        let ty = format_ident!("{}", name.to_string().to_shouty_snake_case());
+        match handle.kind {
+            HandleKind::Dispatchable => quote! {
+                define_handle!(#name, #ty, doc = #khronos_link);
+            },
+            HandleKind::NonDispatchable => quote! {
+                handle_nondispatchable!(#name, #ty, doc = #khronos_link);
+            },
+        }
+    }
+
+    pub fn generate_funcptr(&self, fnptr: &FuncPointer) -> TokenStream {
+        let name = format_ident!("{}", fnptr.name);
+        let ret_ty_tokens = fnptr.return_type.as_ref().map(|ty| {
+            let ret_ty_tokens = type_tokens(ty, true);
+            quote!(-> #ret_ty_tokens)
+        });
+        let params = fnptr.params.iter().map(|param| {
+            let ident = crate::naming::field_ident(&param.name);
+            let type_tokens = type_tokens(&param.ty, true);
+            quote! {
+                #ident: #type_tokens
+            }
+        });
+        let khronos_link = khronos_link(&fnptr.name);
+        quote! {
+            #[allow(non_camel_case_types)]
+            #[doc = #khronos_link]
+            pub type #name = Option<unsafe extern "system" fn(#(#params),*) #ret_ty_tokens>;
+        }
+    }
+
+    pub fn generate_alias(&mut self, alias: &TypeAlias) -> Option<TokenStream> {
+        let name_ident = type_ident(&alias.name);
+        if !self.ty_cache.insert(name_ident.to_string()) {
+            return None;
+        };
+        let alias_ident = type_ident(&alias.alias);
+        Some(if self.has_lifetimes.contains(&alias_ident.to_string()) {
+            quote!(pub type #name_ident<'a> = #alias_ident<'a>;)
+        } else {
+            quote!(pub type #name_ident = #alias_ident;)
+        })
+    }
+}
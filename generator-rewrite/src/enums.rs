@@ -0,0 +1,456 @@
+//! Enumerations, bitflags and constants, including the values extensions add to them.
+
+use crate::{
+    generator::Generator,
+    naming::{constant_name, format_notation, khronos_link, type_ident, variant_ident},
+    types::{ConstType, Constant},
+};
+use analysis::xml::{EnumValue, EnumValueKind, Enums, Require, RequireEnum, RequireItem};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::BTreeMap;
+
+pub struct ConstantMatchInfo {
+    pub ident: Ident,
+    /// The name of the constant in the specification, with its `VK_` prefix.
+    pub name: String,
+    pub is_alias: bool,
+    /// Whether the constant is a single bit of a flags type.
+    pub is_bit: bool,
+}
+
+/// The variants of an enumeration or bitmask, used to generate its `Debug` implementation.
+#[derive(Default)]
+pub struct ConstantTypeInfo {
+    pub values: Vec<ConstantMatchInfo>,
+    pub bitwidth: Option<u32>,
+}
+
+pub enum EnumType {
+    Bitflags(TokenStream),
+    Enum(TokenStream),
+}
+
+pub struct ConstDebugs {
+    pub core: TokenStream,
+    pub extras: TokenStream,
+    /// The known values of enums, with `FromStr` and `TryFrom<&str>`.
+    pub enums: TokenStream,
+    /// The known bits of flags, with `FromStr` and `TryFrom<&str>`.
+    pub flags: TokenStream,
+}
+
+/// A variant to emit into an `impl` block of an enumeration or bitmask.
+struct EnumConstant<'a> {
+    ident: Ident,
+    constant: Constant,
+    comment: Option<&'a str>,
+}
+
+fn doc_attribute(comment: Option<&str>) -> Option<TokenStream> {
+    comment.map(|c| {
+        let c = format_notation(c);
+        quote!(#[doc = #c])
+    })
+}
+
+impl<'a> Generator<'a> {
+    /// Returns (Constant, optional base type, is_alias)
+    fn enum_constant(
+        &self,
+        value: &EnumValue,
+        enum_name: Option<&str>,
+        extension_number: Option<u32>,
+    ) -> Option<(Constant, Option<String>, bool)> {
+        match &value.value {
+            EnumValueKind::Bitpos(bitpos) => {
+                Some((Constant::BitPos(*bitpos), value.extends.clone(), false))
+            }
+            &EnumValueKind::Offset {
+                extnumber,
+                offset,
+                negative,
+            } => {
+                let extnumber = extnumber
+                    .or(extension_number)
+                    .expect("Need an extension number");
+                let number = 1_000_000_000 + (i64::from(extnumber) - 1) * 1000 + i64::from(offset);
+                let number = if negative { -number } else { number };
+                Some((
+                    Constant::Number(number as i32),
+                    value.extends.clone(),
+                    false,
+                ))
+            }
+            EnumValueKind::Value(v) => {
+                Some((Constant::from_value(v)?, value.extends.clone(), false))
+            }
+            EnumValueKind::Alias(alias) => {
+                let base_type = value.extends.as_deref().or(enum_name)?;
+                let key = variant_ident(&self.vendors, base_type, alias);
+                if key == "DISPATCH_BASE" {
+                    None
+                } else {
+                    Some((Constant::Alias(key), Some(base_type.to_owned()), true))
+                }
+            }
+        }
+    }
+
+    fn bitflags_impl_block(&self, ident: Ident, constants: &[EnumConstant<'_>]) -> TokenStream {
+        let variants = constants.iter().map(|constant| {
+            let variant_ident = &constant.ident;
+            let notation = doc_attribute(constant.comment);
+            let value = match &constant.constant {
+                c @ Constant::Alias(_) => quote!(#c),
+                c => quote!(Self(#c)),
+            };
+            quote! {
+                #notation
+                pub const #variant_ident: Self = #value;
+            }
+        });
+
+        quote! {
+            impl #ident {
+                #(#variants)*
+            }
+        }
+    }
+
+    pub fn generate_enum(&mut self, enums: &'a Enums) -> EnumType {
+        let name = &enums.name;
+        let ident = type_ident(name);
+        let values = enums
+            .values
+            .iter()
+            .filter(|value| self.is_api(&value.api) && value.deprecated.is_none())
+            .collect::<Vec<_>>();
+
+        let mut match_info = Vec::with_capacity(values.len());
+        for value in &values {
+            self.const_cache.insert(value.name.as_str());
+            match_info.push(ConstantMatchInfo {
+                ident: variant_ident(&self.vendors, name, &value.name),
+                name: value.name.clone(),
+                is_alias: matches!(value.value, EnumValueKind::Alias(_)),
+                is_bit: matches!(value.value, EnumValueKind::Bitpos(_)),
+            });
+        }
+        self.const_values.insert(
+            ident.to_string(),
+            ConstantTypeInfo {
+                values: match_info,
+                bitwidth: enums.bitwidth,
+            },
+        );
+
+        let constants = values
+            .iter()
+            .map(|value| EnumConstant {
+                ident: variant_ident(&self.vendors, name, &value.name),
+                constant: self.enum_constant(value, Some(name), None).unwrap().0,
+                comment: value.comment.as_deref(),
+            })
+            .collect::<Vec<_>>();
+        let khronos_link = khronos_link(name);
+
+        if name.contains("Bit") {
+            let type_ = if enums.bitwidth == Some(64) {
+                quote!(Flags64)
+            } else {
+                quote!(Flags)
+            };
+
+            if !self.bitflags_cache.insert(ident.to_string()) {
+                EnumType::Bitflags(quote!())
+            } else {
+                let impl_bitflags = self.bitflags_impl_block(ident.clone(), &constants);
+                EnumType::Bitflags(quote! {
+                    #[repr(transparent)]
+                    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+                    #[doc = #khronos_link]
+                    pub struct #ident(pub(crate) #type_);
+                    vk_bitflags_wrapped!(#ident, #type_);
+                    #impl_bitflags
+                })
+            }
+        } else {
+            let (struct_attribute, special_quote) = match name.as_str() {
+                "VkResult" => (
+                    quote!(#[must_use]),
+                    self.generate_result(ident.clone(), enums),
+                ),
+                _ => (quote!(), quote!()),
+            };
+
+            let impl_block = self.bitflags_impl_block(ident.clone(), &constants);
+            EnumType::Enum(quote! {
+                #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+                #[repr(transparent)]
+                #[doc = #khronos_link]
+                #struct_attribute
+                pub struct #ident(pub(crate) i32);
+                impl #ident {
+                    #[inline]
+                    pub const fn from_raw(x: i32) -> Self { Self(x) }
+                    #[inline]
+                    pub const fn as_raw(self) -> i32 { self.0 }
+                }
+                #impl_block
+                #special_quote
+            })
+        }
+    }
+
+    fn generate_result(&self, ident: Ident, enums: &Enums) -> TokenStream {
+        let notation = enums.values.iter().map(|value| {
+            let variant_ident = variant_ident(&self.vendors, &enums.name, &value.name);
+            let notation = value
+                .comment
+                .as_deref()
+                .map(format_notation)
+                .unwrap_or_default();
+            quote! {
+                Self::#variant_ident => Some(#notation)
+            }
+        });
+
+        quote! {
+            impl ::std::error::Error for #ident {}
+            impl fmt::Display for #ident {
+                fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                    let name = match *self {
+                        #(#notation),*,
+                        _ => None,
+                    };
+                    if let Some(x) = name {
+                        fmt.write_str(x)
+                    } else {
+                        // If we don't have a nice message to show, call the generated `Debug` impl
+                        // which includes *all* enum variants, including those from extensions.
+                        <Self as fmt::Debug>::fmt(self, fmt)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates an entry of the `API Constants` block. Aliases are skipped.
+    pub fn generate_constant(&mut self, value: &'a EnumValue) -> Option<TokenStream> {
+        let c = match &value.value {
+            EnumValueKind::Value(v) => v.parse().map(Constant::Number).unwrap_or_else(|_| match v
+                .strip_prefix("0x")
+            {
+                Some(hex) => Constant::Hex(hex.to_owned()),
+                None => Constant::CExpr(v.clone()),
+            }),
+            EnumValueKind::Bitpos(bitpos) => Constant::BitPos(*bitpos),
+            EnumValueKind::Alias(_) => return None,
+            EnumValueKind::Offset { .. } => panic!("API constant {} has an offset", value.name),
+        };
+        self.const_cache.insert(value.name.as_str());
+        let name = constant_name(&value.name);
+        let ident = format_ident!("{}", name);
+        let notation = doc_attribute(value.comment.as_deref());
+
+        let ty = if name == "TRUE" || name == "FALSE" {
+            ConstType::Bool32
+        } else {
+            c.ty()
+                .unwrap_or_else(|e| panic!("API constant {}: {e}", value.name))
+        };
+        Some(quote! {
+            #notation
+            pub const #ident: #ty = #c;
+        })
+    }
+
+    /// Generates `impl` blocks with the values that `requires` add to existing enumerations and
+    /// bitmasks, for the feature or extension `origin_name`.
+    pub fn generate_extension_constants(
+        &mut self,
+        origin_name: &str,
+        extension_number: u32,
+        requires: &'a [Require],
+    ) -> TokenStream {
+        let items = requires
+            .iter()
+            .filter(|require| self.is_api(&require.api))
+            .flat_map(|require| &require.items)
+            .collect::<Vec<_>>();
+
+        let mut extended_enums = BTreeMap::<&str, Vec<EnumConstant<'_>>>::new();
+
+        for item in items {
+            let RequireItem::Enum(enum_) = item else {
+                continue;
+            };
+            if !self.const_cache.insert(enum_.name()) {
+                continue;
+            }
+            let RequireEnum::Define(value) = enum_ else {
+                continue;
+            };
+            if !self.is_api(&value.api) || value.deprecated.is_some() {
+                continue;
+            }
+
+            let Some((constant, Some(_), is_alias)) =
+                self.enum_constant(value, None, Some(extension_number))
+            else {
+                continue;
+            };
+            let is_bit = matches!(constant, Constant::BitPos(_));
+            let extends = value.extends.as_deref().unwrap();
+            let ident = variant_ident(&self.vendors, extends, &value.name);
+            self.const_values
+                .get_mut(&type_ident(extends).to_string())
+                .unwrap_or_else(|| panic!("{extends} extended by {origin_name} is not generated"))
+                .values
+                .push(ConstantMatchInfo {
+                    ident: ident.clone(),
+                    name: value.name.clone(),
+                    is_alias,
+                    is_bit,
+                });
+
+            extended_enums
+                .entry(extends)
+                .or_default()
+                .push(EnumConstant {
+                    ident,
+                    constant,
+                    comment: value.comment.as_deref(),
+                });
+        }
+
+        let enum_tokens = extended_enums.iter().map(|(extends, constants)| {
+            let doc_string = format!("Generated from '{origin_name}'");
+            let impl_block = self.bitflags_impl_block(type_ident(extends), constants);
+            quote! {
+                #[doc = #doc_string]
+                #impl_block
+            }
+        });
+        quote!(#(#enum_tokens)*)
+    }
+
+    /// Generates `all()` for every flags type, from the bits defined by the core API and
+    /// extensions.
+    pub fn generate_bitflags_all(&self) -> TokenStream {
+        let impls = self
+            .const_values
+            .iter()
+            .filter(|(ty, _)| ty.contains("Flags"))
+            .map(|(ty, info)| {
+                let ty_ident = format_ident!("{}", ty);
+                let bits = info
+                    .values
+                    .iter()
+                    .filter(|value| value.is_bit && !value.is_alias)
+                    .map(|value| {
+                        let ident = &value.ident;
+                        quote!(Self::#ident.0)
+                    });
+                let bits = quote!(#(#bits)|*);
+                let bits = if bits.is_empty() { quote!(0) } else { bits };
+                quote! {
+                    impl #ty_ident {
+                        #[doc = r" Every bit with a name in the Vulkan specification"]
+                        #[inline]
+                        pub const fn all() -> Self {
+                            Self(#bits)
+                        }
+                    }
+                }
+            });
+        quote!(#(#impls)*)
+    }
+
+    pub fn generate_const_debugs(&self) -> ConstDebugs {
+        let mut core = Vec::new();
+        let mut extras = Vec::new();
+        let mut enums = Vec::new();
+        let mut flags = Vec::new();
+        for (ty, ConstantTypeInfo { values, bitwidth }) in &self.const_values {
+            let ty_ident = format_ident!("{}", ty);
+            // Aliases come last, so that values are printed with their original name
+            let (originals, aliases): (Vec<_>, Vec<_>) =
+                values.iter().partition(|value| !value.is_alias);
+            let cases = originals.iter().chain(&aliases).map(|value| {
+                let ident = &value.ident;
+                let name = ident.to_string();
+                let spec_name = &value.name;
+                quote! { (Self::#ident.0, #name, #spec_name) }
+            });
+            let try_from = quote! {
+                impl TryFrom<&str> for #ty_ident {
+                    type Error = ParseValueError;
+                    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+                        s.parse()
+                    }
+                }
+            };
+            let debug = if ty.contains("Flags") {
+                let type_ = if bitwidth == &Some(64) {
+                    quote!(Flags64)
+                } else {
+                    quote!(Flags)
+                };
+
+                flags.push(quote! {
+                    impl #ty_ident {
+                        pub(crate) const KNOWN: Known<#type_> = &[#(#cases),*];
+                    }
+                    impl FromStr for #ty_ident {
+                        type Err = ParseValueError;
+                        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                            parse_flags(#ty, Self::KNOWN, s).map(Self)
+                        }
+                    }
+                    #try_from
+                });
+                quote! {
+                    impl fmt::Debug for #ty_ident {
+                        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                            debug_flags(f, Self::KNOWN, self.0)
+                        }
+                    }
+                }
+            } else {
+                enums.push(quote! {
+                    impl #ty_ident {
+                        pub(crate) const KNOWN: Known<i32> = &[#(#cases),*];
+                    }
+                    impl FromStr for #ty_ident {
+                        type Err = ParseValueError;
+                        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                            parse_enum(#ty, Self::KNOWN, s).map(Self)
+                        }
+                    }
+                    #try_from
+                });
+                quote! {
+                    impl fmt::Debug for #ty_ident {
+                        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                            debug_enum(f, Self::KNOWN, self.0)
+                        }
+                    }
+                }
+            };
+            if ty == "Result" || ty == "ObjectType" {
+                core.push(debug);
+            } else {
+                extras.push(debug);
+            }
+        }
+
+        ConstDebugs {
+            core: quote!(#(#core)*),
+            extras: quote!(#(#extras)*),
+            enums: quote!(#(#enums)*),
+            flags: quote!(#(#flags)*),
+        }
+    }
+}
@@ -0,0 +1,485 @@
+use crate::enums::{ConstDebugs, ConstantTypeInfo, EnumType};
+use analysis::{
+    xml::{Command, EnumsKind, Extension, RequireItem, Type},
+    Analysis, API,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::File,
+    io::Write,
+    path::Path,
+};
+
+/// State shared while generating the bindings. Several caches make sure that an item
+/// required by multiple features or extensions is only generated once, by whichever
+/// generates it first.
+pub struct Generator<'a> {
+    /// Author tags such as `KHR`, stripped from enum variants.
+    pub vendors: Vec<String>,
+    pub extensions: Vec<&'a Extension>,
+    pub required_types: HashSet<&'a str>,
+    /// Definitions of all required commands.
+    pub commands: HashMap<&'a str, &'a Command>,
+    /// Required command aliases, mapping to the command they alias.
+    pub cmd_aliases: HashMap<&'a str, &'a str>,
+    pub fn_cache: HashSet<&'a str>,
+    pub bitflags_cache: HashSet<String>,
+    pub const_cache: HashSet<&'a str>,
+    pub ty_cache: HashSet<String>,
+    pub const_values: BTreeMap<String, ConstantTypeInfo>,
+    /// Defines turned into constants or `const fn`s, used to rewrite later C expressions.
+    pub identifier_renames: BTreeMap<String, Ident>,
+    pub union_types: HashSet<&'a str>,
+    /// Structs and unions that (transitively) contain pointers, and thus get a lifetime.
+    pub has_lifetimes: HashSet<String>,
+    /// Structs that other structs can extend through their `p_next` chain.
+    pub root_structs: HashSet<String>,
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(analysis: &'a Analysis) -> Self {
+        let registry = &analysis.registry;
+        let extensions = registry
+            .extensions
+            .iter()
+            .filter(|e| {
+                e.supported.is_empty()
+                    || e.is_supported(API)
+                    // VK_ANDROID_native_buffer is for internal use only, but types defined elsewhere
+                    // reference enum extension constants.  Exempt the extension from this check until
+                    // types are properly folded in with their extension (where applicable).
+                    || e.name == "VK_ANDROID_native_buffer"
+            })
+            .collect::<Vec<_>>();
+
+        let mut generator = Self {
+            vendors: registry.tags.iter().map(|tag| tag.name.clone()).collect(),
+            extensions,
+            required_types: HashSet::new(),
+            commands: HashMap::new(),
+            cmd_aliases: HashMap::new(),
+            fn_cache: HashSet::new(),
+            bitflags_cache: HashSet::new(),
+            const_cache: HashSet::new(),
+            ty_cache: HashSet::new(),
+            const_values: BTreeMap::new(),
+            identifier_renames: BTreeMap::new(),
+            union_types: HashSet::new(),
+            has_lifetimes: HashSet::new(),
+            root_structs: HashSet::new(),
+        };
+
+        let mut required_commands = HashSet::new();
+        let requires = analysis
+            .features()
+            .flat_map(|f| &f.requires)
+            .chain(generator.extensions.iter().flat_map(|e| &e.requires))
+            .filter(|require| generator.is_api(&require.api))
+            .flat_map(|require| &require.items)
+            .collect::<Vec<_>>();
+        for item in requires {
+            match item {
+                RequireItem::Type { name, .. } => {
+                    generator.required_types.insert(name.as_str());
+                }
+                RequireItem::Command { name, .. } => {
+                    required_commands.insert(name.as_str());
+                }
+                RequireItem::Enum(_) => {}
+            }
+        }
+
+        generator.commands = registry
+            .commands
+            .iter()
+            .filter(|cmd| {
+                generator.is_api(&cmd.api) && required_commands.contains(cmd.name.as_str())
+            })
+            .map(|cmd| (cmd.name.as_str(), cmd))
+            .collect();
+        generator.cmd_aliases = registry
+            .command_aliases
+            .iter()
+            .filter(|alias| required_commands.contains(alias.name.as_str()))
+            .map(|alias| (alias.name.as_str(), alias.alias.as_str()))
+            .collect();
+
+        generator.union_types = registry
+            .types
+            .iter()
+            .filter_map(|ty| match ty {
+                Type::Union(u) => Some(u.name.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        // Identify structs that need a lifetime annotation
+        // Note that this relies on `vk.xml` defining types before they are used,
+        // as is required in C(++) too.
+        generator.has_lifetimes = registry
+            .types
+            .iter()
+            .filter_map(|ty| match ty {
+                Type::Struct(s) if s.members.iter().any(|m| m.decl.ty.is_pointer()) => {
+                    Some(crate::naming::type_ident(&s.name).to_string())
+                }
+                _ => None,
+            })
+            .collect();
+        for ty in &registry.types {
+            if let Type::Struct(s) | Type::Union(s) = ty {
+                if s.members.iter().any(|m| {
+                    generator
+                        .has_lifetimes
+                        .contains(&crate::naming::type_ident(&m.decl.ty.name).to_string())
+                }) {
+                    generator
+                        .has_lifetimes
+                        .insert(crate::naming::type_ident(&s.name).to_string());
+                }
+            }
+        }
+
+        // Root structs are all structs that are extended by other structs.
+        generator.root_structs = registry
+            .types
+            .iter()
+            .filter(|ty| generator.required_types.contains(ty.name()))
+            .filter_map(|ty| match ty {
+                Type::Struct(s) | Type::Union(s) => Some(&s.struct_extends),
+                _ => None,
+            })
+            .flatten()
+            .map(|name| crate::naming::type_ident(name).to_string())
+            .collect();
+
+        generator
+    }
+
+    /// Whether an item restricted to `api` applies to [`API`].
+    pub fn is_api(&self, api: &Option<Vec<String>>) -> bool {
+        api.as_ref().is_none_or(|api| api.iter().any(|a| a == API))
+    }
+
+    fn is_required_type(&self, ty: &Type) -> bool {
+        self.is_api(&ty.api().map(<[String]>::to_vec)) && self.required_types.contains(ty.name())
+    }
+}
+
+/// The contents of the generated `vk/*.rs` files, in order, before formatting.
+pub struct Bindings {
+    pub files: Vec<(&'static str, TokenStream)>,
+}
+
+pub fn generate(analysis: &Analysis) -> Bindings {
+    let registry = &analysis.registry;
+    let mut generator = Generator::new(analysis);
+
+    let mut enum_code = Vec::new();
+    let mut bitflags_code = Vec::new();
+    for enums in &registry.enums {
+        if enums.kind == EnumsKind::Constants
+            || !generator
+                .required_types
+                .contains(enums.name.replace("FlagBits", "Flags").as_str())
+        {
+            continue;
+        }
+        match generator.generate_enum(enums) {
+            EnumType::Enum(token) => enum_code.push(token),
+            EnumType::Bitflags(token) => bitflags_code.push(token),
+        }
+    }
+
+    let mut constants_code = registry
+        .enums
+        .iter()
+        .filter(|enums| enums.kind == EnumsKind::Constants)
+        .flat_map(|enums| &enums.values)
+        .filter_map(|value| generator.generate_constant(value))
+        .collect::<Vec<_>>();
+    // The legacy generator skips aliases of API constants, except this one.
+    constants_code.push(quote! { pub const SHADER_UNUSED_NV : u32 = SHADER_UNUSED_KHR;});
+
+    let extension_code = generator
+        .extensions
+        .clone()
+        .into_iter()
+        .map(|extension| {
+            let constants = generator.generate_extension_constants(
+                &extension.name,
+                extension.number,
+                &extension.requires,
+            );
+            let fp = generator.generate_extension_commands(
+                &extension.name,
+                extension.depends.as_ref(),
+                &extension.requires,
+            );
+            quote! {
+                #fp
+                #constants
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut definition_code = Vec::new();
+    for ty in &registry.types {
+        if let Type::Define(define) = ty {
+            if generator.is_required_type(ty) {
+                definition_code.push(generator.generate_define(define));
+            }
+        }
+    }
+    for ty in &registry.types {
+        if !generator.is_required_type(ty) {
+            continue;
+        }
+        let tokens = match ty {
+            Type::BaseType(typedef) => generator.generate_typedef(typedef),
+            Type::Struct(struct_) => generator.generate_struct(struct_),
+            Type::Bitmask(bitmask) => match generator.generate_bitmask(bitmask) {
+                Some(tokens) => tokens,
+                None => continue,
+            },
+            Type::Handle(handle) => generator.generate_handle(handle),
+            Type::FuncPointer(fnptr) => generator.generate_funcptr(fnptr),
+            Type::Union(union) => generator.generate_union(union),
+            _ => continue,
+        };
+        definition_code.push(tokens);
+    }
+
+    let aliases = registry
+        .types
+        .iter()
+        .filter_map(|ty| match ty {
+            Type::Alias(alias) if generator.required_types.contains(alias.name.as_str()) => {
+                generator.generate_alias(alias)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let feature_code = analysis
+        .features()
+        .map(|feature| generator.generate_feature(feature))
+        .collect::<Vec<_>>();
+    let feature_extensions_code = analysis
+        .features()
+        .map(|feature| generator.generate_extension_constants(&feature.name, 0, &feature.requires))
+        .collect::<Vec<_>>();
+
+    let bitflags_all = generator.generate_bitflags_all();
+    let ConstDebugs {
+        core: core_debugs,
+        extras: const_debugs,
+        enums: known_enums,
+        flags: known_flags,
+    } = generator.generate_const_debugs();
+
+    let feature_code = quote! {
+        use std::os::raw::*;
+        use crate::vk::bitflags::*;
+        use crate::vk::definitions::*;
+        use crate::vk::enums::*;
+        #(#feature_code)*
+    };
+
+    let definition_code = quote! {
+        use std::marker::PhantomData;
+        use std::fmt;
+        use std::os::raw::*;
+        use crate::vk::{Handle, ptr_chain_iter, wrap_c_str_slice_until_nul};
+        use crate::vk::aliases::*;
+        use crate::vk::bitflags::*;
+        use crate::vk::constants::*;
+        use crate::vk::enums::*;
+        use crate::vk::native::*;
+        use crate::vk::platform_types::*;
+        use crate::vk::prelude::*;
+        #(#definition_code)*
+    };
+
+    let enum_code = quote! {
+        use std::fmt;
+        use std::str::FromStr;
+        use crate::prelude::{debug_enum, parse_enum, Known};
+        use crate::vk::ParseValueError;
+        #(#enum_code)*
+        #known_enums
+        #core_debugs
+    };
+
+    let bitflags_code = quote! {
+        use std::str::FromStr;
+        use crate::prelude::{parse_flags, Known};
+        use crate::vk::definitions::*;
+        use crate::vk::ParseValueError;
+        #(#bitflags_code)*
+        #bitflags_all
+        #known_flags
+    };
+
+    let constants_code = quote! {
+        use crate::vk::definitions::*;
+        #(#constants_code)*
+    };
+
+    let extension_code = quote! {
+        use std::os::raw::*;
+        use crate::vk::platform_types::*;
+        use crate::vk::aliases::*;
+        use crate::vk::bitflags::*;
+        use crate::vk::definitions::*;
+        use crate::vk::enums::*;
+        #(#extension_code)*
+    };
+
+    let feature_extensions_code = quote! {
+        use crate::vk::bitflags::*;
+        use crate::vk::enums::*;
+        #(#feature_extensions_code)*
+    };
+
+    let const_debugs = quote! {
+        use std::fmt;
+        use crate::vk::bitflags::*;
+        use crate::vk::definitions::*;
+        use crate::vk::enums::*;
+        use crate::prelude::{debug_enum, debug_flags};
+        #const_debugs
+    };
+
+    let aliases = quote! {
+        use crate::vk::bitflags::*;
+        use crate::vk::definitions::*;
+        use crate::vk::enums::*;
+        #(#aliases)*
+    };
+
+    Bindings {
+        files: vec![
+            ("features.rs", feature_code),
+            ("definitions.rs", definition_code),
+            ("enums.rs", enum_code),
+            ("bitflags.rs", bitflags_code),
+            ("constants.rs", constants_code),
+            ("extensions.rs", extension_code),
+            ("feature_extensions.rs", feature_extensions_code),
+            ("const_debugs.rs", const_debugs),
+            ("aliases.rs", aliases),
+        ],
+    }
+}
+
+impl Bindings {
+    /// Writes every file to `vk_dir`, formatted with `rustfmt`.
+    pub fn write(&self, vk_dir: &Path) {
+        fn write_formatted(text: &[u8], out: File) -> std::process::Child {
+            let mut child = std::process::Command::new("rustfmt")
+                .stdin(std::process::Stdio::piped())
+                .stdout(out)
+                .spawn()
+                .expect("Failed to spawn `rustfmt`");
+            let mut stdin = child.stdin.take().expect("Failed to open stdin");
+            stdin.write_all(text).unwrap();
+            drop(stdin);
+            child
+        }
+
+        std::fs::create_dir_all(vk_dir).expect("failed to create vk dir");
+        let processes = self
+            .files
+            .iter()
+            .map(|(name, code)| {
+                let path = vk_dir.join(name);
+                let file = File::create(&path)
+                    .unwrap_or_else(|e| panic!("Failed to create {}: {e}", path.display()));
+                write_formatted(code.to_string().as_bytes(), file)
+            })
+            .collect::<Vec<_>>();
+        for mut p in processes {
+            let status = p.wait().unwrap();
+            assert!(status.success());
+        }
+    }
+}
+
+/// Generates `native.rs` with `bindgen`, for the types that are defined by platform headers
+/// included from `vk.xml`, such as the video codec headers.
+pub fn write_native(analysis: &Analysis, vk_headers_dir: &Path, vk_dir: &Path) {
+    let vk_include = vk_headers_dir.join("include");
+
+    let mut bindings = bindgen::Builder::default().clang_arg(format!(
+        "-I{}",
+        vk_include.to_str().expect("Valid UTF8 string")
+    ));
+
+    // Not a HashMap so that headers are processed in order of definition:
+    let mut header_includes = Vec::<(&str, &str)>::new();
+    for ty in &analysis.registry.types {
+        match ty {
+            // Includes lacking an `#include` directive are generally "irrelevant" system headers.
+            Type::Include(include) => {
+                let Some(path) = &include.path else {
+                    continue;
+                };
+                assert!(
+                    header_includes
+                        .iter()
+                        .all(|(name, _)| *name != include.name),
+                    "Header `{}` being redefined",
+                    include.name
+                );
+                header_includes.push((&include.name, path));
+
+                let path = if path == "vk_platform.h" {
+                    // Fix broken path, https://github.com/KhronosGroup/Vulkan-Docs/pull/1538
+                    // Reintroduced in: https://github.com/KhronosGroup/Vulkan-Docs/issues/1573
+                    vk_include.join("vulkan").join(path)
+                } else {
+                    vk_include.join(path)
+                };
+                bindings = bindings.header(path.to_str().expect("Valid UTF8 string"));
+            }
+            Type::External(external) => {
+                // Omit types from system and other headers
+                let is_native = external
+                    .requires
+                    .as_ref()
+                    .is_some_and(|header| header_includes.iter().any(|(name, _)| name == header));
+                if is_native {
+                    bindings = bindings.allowlist_type(&external.name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[derive(Debug)]
+    struct ParseCallbacks;
+    impl bindgen::callbacks::ParseCallbacks for ParseCallbacks {
+        fn enum_variant_behavior(
+            &self,
+            _enum_name: Option<&str>,
+            original_variant_name: &str,
+            _variant_value: bindgen::callbacks::EnumVariantValue,
+        ) -> Option<bindgen::callbacks::EnumVariantCustomBehavior> {
+            if original_variant_name.ends_with("_MAX_ENUM") {
+                Some(bindgen::callbacks::EnumVariantCustomBehavior::Hide)
+            } else {
+                None
+            }
+        }
+    }
+
+    bindings
+        .parse_callbacks(Box::new(ParseCallbacks))
+        .generate()
+        .expect("Unable to generate native bindings")
+        .write_to_file(vk_dir.join("native.rs"))
+        .expect("Couldn't write native bindings!");
+}
@@ -0,0 +1,150 @@
+//! Compares the generated bindings against the output of the legacy `generator`, item by item,
+//! so that every divergence between both generators can be reviewed.
+
+use crate::generator::Bindings;
+use quote::ToTokens;
+use similar::TextDiff;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+use syn::spanned::Spanned;
+
+/// Identifies an item within a file: its kind, name and the number of earlier items with the
+/// same kind and name, as `impl` blocks are not unique.
+type ItemKey = (&'static str, String, usize);
+
+struct Item {
+    tokens: String,
+    source: String,
+}
+
+fn item_key(item: &syn::Item) -> (&'static str, String) {
+    fn tokens(t: &impl ToTokens) -> String {
+        t.to_token_stream().to_string()
+    }
+    match item {
+        syn::Item::Const(x) => ("const", x.ident.to_string()),
+        syn::Item::Enum(x) => ("enum", x.ident.to_string()),
+        syn::Item::Fn(x) => ("fn", x.sig.ident.to_string()),
+        syn::Item::Impl(x) => {
+            let name = match &x.trait_ {
+                Some((_, path, _)) => format!("{} for {}", tokens(path), tokens(&x.self_ty)),
+                None => tokens(&x.self_ty),
+            };
+            ("impl", name)
+        }
+        syn::Item::Macro(x) => {
+            // All generated macro invocations take the name of the defined type first
+            let first = x.mac.tokens.clone().into_iter().next();
+            let name = format!("{}!({})", tokens(&x.mac.path), tokens(&first));
+            ("macro", name)
+        }
+        syn::Item::Struct(x) => ("struct", x.ident.to_string()),
+        syn::Item::Trait(x) => ("trait", x.ident.to_string()),
+        syn::Item::Type(x) => ("type", x.ident.to_string()),
+        syn::Item::Union(x) => ("union", x.ident.to_string()),
+        syn::Item::Use(x) => ("use", tokens(&x.tree)),
+        x => ("item", tokens(x)),
+    }
+}
+
+fn parse_items(path: &Path) -> BTreeMap<ItemKey, Item> {
+    let source = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()));
+    let file = syn::parse_file(&source)
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", path.display()));
+    let lines = source.lines().collect::<Vec<_>>();
+
+    let mut occurrences = BTreeMap::<(&'static str, String), usize>::new();
+    let mut items = BTreeMap::new();
+    for item in &file.items {
+        let (kind, name) = item_key(item);
+        let occurrence = occurrences.entry((kind, name.clone())).or_default();
+        let span = item.span();
+        let item_source = lines[span.start().line - 1..span.end().line].join("\n") + "\n";
+        items.insert(
+            (kind, name, *occurrence),
+            Item {
+                tokens: item.to_token_stream().to_string(),
+                source: item_source,
+            },
+        );
+        *occurrence += 1;
+    }
+    items
+}
+
+/// Prints the items that differ between both directories of generated files, returning whether
+/// they are identical.
+fn compare(files: &[&str], legacy_dir: &Path, rewrite_dir: &Path) -> bool {
+    let mut identical = true;
+    for file in files {
+        let legacy = parse_items(&legacy_dir.join(file));
+        let rewrite = parse_items(&rewrite_dir.join(file));
+
+        for ((kind, name, occurrence), legacy_item) in &legacy {
+            let key = (*kind, name.clone(), *occurrence);
+            match rewrite.get(&key) {
+                None => {
+                    identical = false;
+                    println!("{file}: {kind} `{name}` #{occurrence} is only generated by the legacy generator");
+                }
+                Some(item) if item.tokens != legacy_item.tokens => {
+                    identical = false;
+                    println!("{file}: {kind} `{name}` #{occurrence} differs");
+                    let diff = TextDiff::from_lines(&legacy_item.source, &item.source);
+                    print!(
+                        "{}",
+                        diff.unified_diff()
+                            .context_radius(3)
+                            .header("legacy", "rewrite")
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+        for (kind, name, occurrence) in rewrite.keys() {
+            if !legacy.contains_key(&(*kind, name.clone(), *occurrence)) {
+                identical = false;
+                println!("{file}: {kind} `{name}` #{occurrence} is only generated by the rewrite");
+            }
+        }
+    }
+    identical
+}
+
+/// Generates the bindings with both generators into a temporary directory and compares them.
+///
+/// `native.rs` is generated by `bindgen` in both cases and is not compared.
+pub fn golden_diff(vk_headers_dir: &Path, bindings: &Bindings) -> bool {
+    let out_dir: PathBuf = std::env::temp_dir().join(format!("ash-golden-{}", std::process::id()));
+    let legacy_dir = out_dir.join("legacy");
+    let rewrite_dir = out_dir.join("rewrite");
+
+    bindings.write(&rewrite_dir.join("vk"));
+
+    // The legacy generator runs `bindgen` after writing all other files; as `native.rs` is not
+    // compared, that step is allowed to fail (e.g. when `libclang` is not installed).
+    let legacy = std::panic::catch_unwind(|| {
+        ::generator::write_source_code(vk_headers_dir, &legacy_dir);
+    });
+    if legacy.is_err() {
+        eprintln!("Legacy generator panicked, comparing the files it wrote");
+    }
+
+    let files = bindings
+        .files
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+    let identical = compare(&files, &legacy_dir.join("vk"), &rewrite_dir.join("vk"));
+    if identical {
+        println!("No divergence from the legacy generator");
+        fs::remove_dir_all(&out_dir).expect("Failed to remove temporary directory");
+    } else {
+        println!("Generated files are kept in {}", out_dir.display());
+    }
+    identical
+}
@@ -1,5 +1,35 @@
+mod commands;
+mod definitions;
+mod enums;
+mod generator;
+mod golden;
+mod naming;
+mod structs;
+mod types;
+
 use analysis::Analysis;
+use std::path::Path;
 
 fn main() {
-    let _analysis = Analysis::new("generator/Vulkan-Headers");
+    let cwd = std::env::current_dir().unwrap();
+    let (vk_headers_dir, src_dir) = if cwd.ends_with("generator-rewrite") {
+        ("../generator/Vulkan-Headers", "../ash-rewrite/src")
+    } else {
+        ("generator/Vulkan-Headers", "ash-rewrite/src")
+    };
+    let vk_headers_dir = Path::new(vk_headers_dir);
+
+    let analysis = Analysis::new(vk_headers_dir);
+    let bindings = generator::generate(&analysis);
+
+    if std::env::args().skip(1).any(|arg| arg == "--golden-diff") {
+        if !golden::golden_diff(vk_headers_dir, &bindings) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let vk_dir = Path::new(src_dir).join("vk");
+    bindings.write(&vk_dir);
+    generator::write_native(&analysis, vk_headers_dir, &vk_dir);
 }
@@ -0,0 +1,132 @@
+use heck::{ToShoutySnakeCase, ToSnakeCase};
+use proc_macro2::{Ident, Literal};
+use quote::format_ident;
+use std::{borrow::Cow, fmt::Display};
+
+pub fn khronos_link<S: Display + ?Sized>(name: &S) -> Literal {
+    Literal::string(&format!(
+        "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/{name}.html>"
+    ))
+}
+
+/// Turns the first `<<anchor>>` cross-reference of a registry comment into a link to the
+/// specification.
+pub fn format_notation(notation: &str) -> Cow<'_, str> {
+    let link = notation.match_indices("<<").find_map(|(start, _)| {
+        let anchor = &notation[start + 2..];
+        let anchor =
+            &anchor[..anchor.find(|c: char| !(c == '_' || c == '-' || c.is_alphanumeric()))?];
+        (!anchor.is_empty() && notation[start + 2 + anchor.len()..].starts_with(">>"))
+            .then_some((start, anchor))
+    });
+    match link {
+        Some((start, anchor)) => Cow::Owned(format!(
+            "{}<https://registry.khronos.org/vulkan/specs/1.3-extensions/html/vkspec.html#{anchor}>{}",
+            &notation[..start],
+            &notation[start + 2 + anchor.len() + 2..]
+        )),
+        None => Cow::Borrowed(notation),
+    }
+}
+
+/// Maps a C type name to its Rust name, stripping the `Vk` prefix from Vulkan types.
+pub fn type_ident(type_name: &str) -> Ident {
+    let new_name = match type_name {
+        "uint8_t" => "u8",
+        "uint16_t" => "u16",
+        "uint32_t" => "u32",
+        "uint64_t" => "u64",
+        "int8_t" => "i8",
+        "int16_t" => "i16",
+        "int32_t" => "i32",
+        "int64_t" => "i64",
+        "size_t" => "usize",
+        "int" => "c_int",
+        "void" => "c_void",
+        "char" => "c_char",
+        "float" => "f32",
+        "double" => "f64",
+        "long" => "c_ulong",
+        _ => type_name.strip_prefix("Vk").unwrap_or(type_name),
+    };
+    format_ident!("{}", new_name.replace("FlagBits", "Flags"))
+}
+
+/// The snake-case name of a struct member or parameter, avoiding Rust keywords.
+pub fn field_ident(name: &str) -> Ident {
+    let name = match name {
+        "type" => "ty",
+        _ => name,
+    };
+    format_ident!("{}", name.to_snake_case())
+}
+
+pub fn constant_name(name: &str) -> &str {
+    name.strip_prefix("VK_").unwrap_or(name)
+}
+
+/// Derives the associated constant name of `variant_name` within the enum or bitmask
+/// `enum_name`, e.g. `VK_IMAGE_USAGE_TRANSFER_SRC_BIT` in `VkImageUsageFlagBits` becomes
+/// `TRANSFER_SRC`. `vendors` are the author tags from the registry, such as `KHR`.
+pub fn variant_ident(vendors: &[String], enum_name: &str, variant_name: &str) -> Ident {
+    let variant_name = variant_name.to_uppercase();
+    let struct_name = enum_name.replace("FlagBits", "").to_shouty_snake_case();
+    let vendor = vendors
+        .iter()
+        .map(|vendor| format!("_{vendor}"))
+        .find(|vendor| struct_name.ends_with(vendor.as_str()))
+        .unwrap_or_default();
+    let struct_name = struct_name.strip_suffix(vendor.as_str()).unwrap();
+    let struct_name = separate_trailing_number(struct_name);
+    let variant_name = variant_name
+        .strip_suffix(vendor.as_str())
+        .unwrap_or(&variant_name);
+
+    let new_variant_name = variant_name
+        .strip_prefix(struct_name.as_str())
+        .unwrap_or_else(|| {
+            if enum_name == "VkResult" {
+                variant_name.strip_prefix("VK").unwrap()
+            } else {
+                panic!("Failed to strip {struct_name} prefix from enum variant {variant_name}")
+            }
+        });
+
+    // Both of the above strip_prefix leave a leading `_`:
+    let new_variant_name = new_variant_name.strip_prefix('_').unwrap();
+    // Replace _BIT anywhere in the string, also works when there's a trailing
+    // vendor extension in the variant name that's not in the enum/type name:
+    let new_variant_name = new_variant_name.replace("_BIT", "");
+    if new_variant_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format_ident!("TYPE_{}", new_variant_name)
+    } else {
+        format_ident!("{}", new_variant_name)
+    }
+}
+
+/// `VK_ACCESS2` -> `VK_ACCESS_2`
+fn separate_trailing_number(name: &str) -> String {
+    let digits = name.len() - name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    if digits == 0 {
+        name.to_owned()
+    } else {
+        let (name, number) = name.split_at(name.len() - digits);
+        format!("{name}_{number}")
+    }
+}
+
+/// Interleaves a number, for example 100000 => 100_000. Mostly used to make clippy happy
+pub fn interleave_number(symbol: char, count: usize, n: &str) -> String {
+    let number: String = n
+        .chars()
+        .rev()
+        .enumerate()
+        .fold(String::new(), |mut acc, (idx, next)| {
+            if idx != 0 && idx % count == 0 {
+                acc.push(symbol);
+            }
+            acc.push(next);
+            acc
+        });
+    number.chars().rev().collect()
+}
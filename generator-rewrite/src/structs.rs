@@ -0,0 +1,688 @@
+//! Structs and unions, with their `Default`/`Debug` implementations and builder-style setters.
+
+use crate::{
+    generator::Generator,
+    naming::{field_ident, khronos_link, type_ident, variant_ident},
+    types::{
+        array_size, convert_c_expression, inner_type_tokens, is_dynamic_array,
+        is_pointer_to_static_sized_array, is_static_array, member_type_tokens, pointer_tokens,
+        safe_type_tokens,
+    },
+};
+use analysis::xml::{Member, Struct};
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::BTreeMap;
+
+fn is_opaque_type(ty: &str) -> bool {
+    matches!(
+        ty,
+        "void"
+            | "wl_display"
+            | "wl_surface"
+            | "Display"
+            | "xcb_connection_t"
+            | "ANativeWindow"
+            | "AHardwareBuffer"
+            | "CAMetalLayer"
+            | "IDirectFB"
+            | "IDirectFBSurface"
+    )
+}
+
+/// At the moment `Ash` doesn't properly derive all the necessary drives
+/// like Eq, Hash etc.
+/// To Address some cases, you can add the name of the struct that you
+/// require and add the missing derives yourself.
+fn manual_derives(struct_: &Struct) -> TokenStream {
+    match struct_.name.as_str() {
+        "VkClearRect" | "VkExtent2D" | "VkExtent3D" | "VkOffset2D" | "VkOffset3D" | "VkRect2D"
+        | "VkSurfaceFormatKHR" => quote! {PartialEq, Eq, Hash,},
+        _ => quote! {},
+    }
+}
+
+/// Structs containing bitfields, which are hand-written to pack them into [`Packed24_8`].
+fn bitfield_struct(struct_: &Struct) -> Option<TokenStream> {
+    Some(match struct_.name.as_str() {
+        "VkTransformMatrixKHR" => quote! {
+            #[repr(C)]
+            #[derive(Copy, Clone)]
+            pub struct TransformMatrixKHR {
+                pub matrix: [f32; 12],
+            }
+        },
+        "VkAccelerationStructureInstanceKHR" => quote! {
+            #[repr(C)]
+            #[derive(Copy, Clone)]
+            pub union AccelerationStructureReferenceKHR {
+                pub device_handle: DeviceAddress,
+                pub host_handle: AccelerationStructureKHR,
+            }
+            #[repr(C)]
+            #[derive(Copy, Clone)]
+            #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureInstanceKHR.html>"]
+            pub struct AccelerationStructureInstanceKHR {
+                pub transform: TransformMatrixKHR,
+                /// Use [`Packed24_8::new(instance_custom_index, mask)`][Packed24_8::new()] to construct this field
+                pub instance_custom_index_and_mask: Packed24_8,
+                /// Use [`Packed24_8::new(instance_shader_binding_table_record_offset, flags)`][Packed24_8::new()] to construct this field
+                pub instance_shader_binding_table_record_offset_and_flags: Packed24_8,
+                pub acceleration_structure_reference: AccelerationStructureReferenceKHR,
+            }
+        },
+        "VkAccelerationStructureSRTMotionInstanceNV" => quote! {
+            #[repr(C)]
+            #[derive(Copy, Clone)]
+            #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkAccelerationStructureSRTMotionInstanceNV.html>"]
+            pub struct AccelerationStructureSRTMotionInstanceNV {
+                pub transform_t0: SRTDataNV,
+                pub transform_t1: SRTDataNV,
+                /// Use [`Packed24_8::new(instance_custom_index, mask)`][Packed24_8::new()] to construct this field
+                pub instance_custom_index_and_mask: Packed24_8,
+                /// Use [`Packed24_8::new(instance_shader_binding_table_record_offset, flags)`][Packed24_8::new()] to construct this field
+                pub instance_shader_binding_table_record_offset_and_flags: Packed24_8,
+                pub acceleration_structure_reference: AccelerationStructureReferenceKHR,
+            }
+        },
+        "VkAccelerationStructureMatrixMotionInstanceNV" => quote! {
+            #[repr(C)]
+            #[derive(Copy, Clone)]
+            #[doc = "<https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/AccelerationStructureMatrixMotionInstanceNV.html>"]
+            pub struct AccelerationStructureMatrixMotionInstanceNV {
+                pub transform_t0: TransformMatrixKHR,
+                pub transform_t1: TransformMatrixKHR,
+                /// Use [`Packed24_8::new(instance_custom_index, mask)`][Packed24_8::new()] to construct this field
+                pub instance_custom_index_and_mask: Packed24_8,
+                /// Use [`Packed24_8::new(instance_shader_binding_table_record_offset, flags)`][Packed24_8::new()] to construct this field
+                pub instance_shader_binding_table_record_offset_and_flags: Packed24_8,
+                pub acceleration_structure_reference: AccelerationStructureReferenceKHR,
+            }
+        },
+        _ => return None,
+    })
+}
+
+struct PreprocessedMember<'a> {
+    member: &'a Member,
+    deprecated: Option<TokenStream>,
+}
+
+fn derive_default(
+    struct_: &Struct,
+    members: &[PreprocessedMember<'_>],
+    has_lifetime: bool,
+) -> Option<TokenStream> {
+    let name = type_ident(&struct_.name);
+    let is_structure_type = |member: &Member| member.decl.ty.name == "VkStructureType";
+
+    // These are also pointers, and therefor also don't implement Default. The spec
+    // also doesn't mark them as pointers
+    let handles = [
+        "LPCWSTR",
+        "HANDLE",
+        "HINSTANCE",
+        "HWND",
+        "HMONITOR",
+        "IOSurfaceRef",
+        "MTLBuffer_id",
+        "MTLCommandQueue_id",
+        "MTLDevice_id",
+        "MTLSharedEvent_id",
+        "MTLTexture_id",
+    ];
+    let contains_ptr = members.iter().any(|m| m.member.decl.ty.is_pointer());
+    let contains_structure_type = members.iter().any(|m| is_structure_type(m.member));
+    let contains_static_array = members.iter().any(|m| is_static_array(m.member));
+    let contains_deprecated = members.iter().any(|m| m.deprecated.is_some());
+    let allow_deprecated = contains_deprecated.then(|| quote!(#[allow(deprecated)]));
+    if !(contains_ptr || contains_structure_type || contains_static_array) {
+        return None;
+    };
+    let default_fields = members.iter().map(|PreprocessedMember { member, .. }| {
+        let param_ident = field_ident(&member.decl.name);
+        let ty = &member.decl.ty;
+        if is_structure_type(member) {
+            if member.values.is_some() {
+                quote!(#param_ident: Self::STRUCTURE_TYPE)
+            } else {
+                quote!(#param_ident: unsafe { ::std::mem::zeroed() })
+            }
+        } else if ty.is_pointer() {
+            if ty.is_const {
+                quote!(#param_ident: ::std::ptr::null())
+            } else {
+                quote!(#param_ident: ::std::ptr::null_mut())
+            }
+        } else if is_static_array(member) || handles.contains(&ty.name.as_str()) {
+            quote!(#param_ident: unsafe { ::std::mem::zeroed() })
+        } else {
+            let ty = member_type_tokens(member, false);
+            quote!(#param_ident: #ty::default())
+        }
+    });
+    let lifetime = has_lifetime.then(|| quote!(<'_>));
+    let marker = has_lifetime.then(|| quote!(_marker: PhantomData,));
+    Some(quote! {
+        impl ::std::default::Default for #name #lifetime {
+            #[inline]
+            fn default() -> Self {
+                #allow_deprecated
+                Self {
+                    #(
+                        #default_fields,
+                    )*
+                    #marker
+                }
+            }
+        }
+    })
+}
+
+impl<'a> Generator<'a> {
+    fn derive_debug(
+        &self,
+        struct_: &Struct,
+        members: &[PreprocessedMember<'_>],
+        has_lifetime: bool,
+    ) -> Option<TokenStream> {
+        let name = type_ident(&struct_.name);
+        let is_union = |member: &Member| self.union_types.contains(member.decl.ty.name.as_str());
+        let is_char_array =
+            |member: &Member| is_static_array(member) && member.decl.ty.name == "char";
+        let contains_pfn = members.iter().any(|m| m.member.decl.name.contains("pfn"));
+        let contains_static_array = members.iter().any(|m| is_char_array(m.member));
+        let contains_union = members.iter().any(|m| is_union(m.member));
+        if !(contains_union || contains_static_array || contains_pfn) {
+            return None;
+        }
+        let debug_fields = members.iter().map(|PreprocessedMember { member, .. }| {
+            let param_ident = field_ident(&member.decl.name);
+            let param_str = param_ident.to_string();
+            let debug_value = if is_char_array(member) {
+                quote! {
+                    &unsafe {
+                        ::std::ffi::CStr::from_ptr(self.#param_ident.as_ptr())
+                    }
+                }
+            } else if param_str.contains("pfn") {
+                quote! {
+                    &(self.#param_ident.map(|x| x as *const ()))
+                }
+            } else if is_union(member) {
+                quote!(&"union")
+            } else {
+                quote! {
+                    &self.#param_ident
+                }
+            };
+            quote! {
+                .field(#param_str, #debug_value)
+            }
+        });
+        let name_str = name.to_string();
+        let lifetime = has_lifetime.then(|| quote!(<'_>));
+        Some(quote! {
+            #[cfg(feature = "debug")]
+            impl fmt::Debug for #name #lifetime {
+                fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                    fmt.debug_struct(#name_str)
+                    #(#debug_fields)*
+                    .finish()
+                }
+            }
+        })
+    }
+
+    fn has_lifetime(&self, type_name: &str) -> bool {
+        self.has_lifetimes
+            .contains(&type_ident(type_name).to_string())
+    }
+
+    fn derive_setters(
+        &self,
+        struct_: &Struct,
+        members: &[PreprocessedMember<'_>],
+    ) -> Option<TokenStream> {
+        if struct_.name == "VkBaseInStructure" || struct_.name == "VkBaseOutStructure" {
+            return None;
+        }
+
+        let name = type_ident(&struct_.name);
+        let find_member = |name: &str| {
+            members
+                .iter()
+                .map(|m| m.member)
+                .find(|m| m.decl.name == name)
+        };
+
+        let next_field = find_member("pNext");
+        let structure_type_field = find_member("sType");
+
+        // Must either have both, or none:
+        assert_eq!(next_field.is_some(), structure_type_field.is_some());
+
+        let allowed_count_members = [
+            // pViewports is allowed to be empty if the viewport state is empty
+            ("VkPipelineViewportStateCreateInfo", "viewportCount"),
+            // Must match viewportCount
+            ("VkPipelineViewportStateCreateInfo", "scissorCount"),
+            // descriptorCount is settable regardless of having pImmutableSamplers
+            ("VkDescriptorSetLayoutBinding", "descriptorCount"),
+            // No ImageView attachments when VK_FRAMEBUFFER_CREATE_IMAGELESS_BIT is set
+            ("VkFramebufferCreateInfo", "attachmentCount"),
+        ];
+        // Extensions using `VkDebugReportObjectTypeEXT` are deprecated exactly because of the
+        // existence of VkObjectType, hence there won't be an additional ash trait to support it.
+        // See also https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_EXT_debug_utils.html#_description
+        let object_type_member = |member: &Member| {
+            let object_type = member.object_type.as_ref()?;
+            let object_type_field = find_member(object_type).unwrap();
+            (object_type_field.decl.ty.name != "VkDebugReportObjectTypeEXT")
+                .then(|| object_type.clone())
+        };
+        let skip_members = members
+            .iter()
+            .filter_map(|PreprocessedMember { member, .. }| {
+                // Associated _count members
+                if let Some(array_size) = array_size(member) {
+                    if !allowed_count_members.contains(&(&struct_.name, &array_size)) {
+                        return Some(array_size);
+                    }
+                }
+                object_type_member(member)
+            })
+            .collect::<Vec<_>>();
+
+        let setters = members.iter().filter_map(|PreprocessedMember { member, deprecated }| {
+            let name = &member.decl.name;
+            if skip_members.contains(name) {
+                return None;
+            }
+
+            let ty = &member.decl.ty;
+            let deprecated = deprecated.as_ref().map(|d| quote!(#d #[allow(deprecated)]));
+            let param_ident = field_ident(name);
+            let param_ty_tokens = safe_type_tokens(member, quote!('a), None);
+
+            let param_ident_string = param_ident.to_string();
+            if param_ident_string == "s_type" || param_ident_string == "p_next" {
+                return None;
+            }
+
+            let param_ident_short = param_ident_string
+                .strip_prefix("p_")
+                .or_else(|| param_ident_string.strip_prefix("pp_"))
+                .unwrap_or(&param_ident_string);
+            let mut param_ident_short = format_ident!("{}", param_ident_short);
+
+            // Unique cases
+            if struct_.name == "VkShaderModuleCreateInfo" && name == "codeSize" {
+                return None;
+            }
+
+            if struct_.name == "VkShaderModuleCreateInfo" && name == "pCode" {
+                return Some(quote! {
+                    #[inline]
+                    pub fn code(mut self, code: &'a [u32]) -> Self {
+                        self.code_size = code.len() * 4;
+                        self.p_code = code.as_ptr();
+                        self
+                    }
+                });
+            }
+
+            if name == "pSampleMask" {
+                return Some(quote! {
+                    /// Sets `p_sample_mask` to `null` if the slice is empty. The mask will
+                    /// be treated as if it has all bits set to `1`.
+                    ///
+                    /// See <https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkPipelineMultisampleStateCreateInfo.html#_description>
+                    /// for more details.
+                    #[inline]
+                    pub fn sample_mask(mut self, sample_mask: &'a [SampleMask]) -> Self {
+                        self.p_sample_mask = if sample_mask.is_empty() {
+                            std::ptr::null()
+                        } else {
+                            sample_mask.as_ptr()
+                        };
+                        self
+                    }
+                });
+            }
+
+            if ty.is_pointer() {
+                if ty.name == "char" && ty.pointers.len() == 1 {
+                    assert!(is_dynamic_array(member));
+                    assert_eq!(array_size(member), None);
+                    return Some(quote! {
+                        #[inline]
+                        #deprecated
+                        pub fn #param_ident_short(mut self, #param_ident_short: &'a ::std::ffi::CStr) -> Self {
+                            self.#param_ident = #param_ident_short.as_ptr();
+                            self
+                        }
+                    });
+                }
+
+                if let Some(array_size) = array_size(member) {
+                    let mut slice_param_ty_tokens = safe_type_tokens(member, quote!('a), None);
+
+                    let mut ptr = if ty.is_const {
+                        quote!(.as_ptr())
+                    } else {
+                        quote!(.as_mut_ptr())
+                    };
+
+                    // Interpret void array as byte array
+                    if ty.name == "void" && ty.pointers.len() == 1 {
+                        slice_param_ty_tokens = quote!([u8]);
+                        ptr = quote!(#ptr.cast());
+                    };
+
+                    let set_size_stmt = if is_pointer_to_static_sized_array(member) {
+                        // this is a pointer to a piece of memory with statically known size.
+                        let c_size = member.alt_len.as_ref().unwrap();
+                        let c_size = convert_c_expression(c_size, &BTreeMap::new());
+                        let inner_type = inner_type_tokens(member, None, None);
+
+                        slice_param_ty_tokens = quote!([#inner_type; #c_size]);
+                        ptr = quote!();
+
+                        quote!()
+                    } else {
+                        // Deal with a "special" 2D dynamic array with an inner size of 1 (effectively an array containing pointers to single objects)
+                        let array_size = if let Some(array_size) = array_size.strip_suffix(",1") {
+                            param_ident_short = format_ident!("{}_ptrs", param_ident_short);
+                            slice_param_ty_tokens = safe_type_tokens(member, quote!('a), Some(1));
+                            ptr = quote!(#ptr.cast());
+                            array_size
+                        } else {
+                            &array_size
+                        };
+
+                        let array_size_ident = format_ident!("{}", array_size.to_snake_case());
+
+                        let size_field = find_member(array_size).unwrap();
+
+                        let cast = if size_field.decl.ty.name == "size_t" {
+                            quote!()
+                        } else {
+                            quote!(as _)
+                        };
+
+                        quote!(self.#array_size_ident = #param_ident_short.len()#cast;)
+                    };
+
+                    let mutable = if ty.is_const { quote!() } else { quote!(mut) };
+
+                    return Some(quote! {
+                        #[inline]
+                        #deprecated
+                        pub fn #param_ident_short(mut self, #param_ident_short: &'a #mutable #slice_param_ty_tokens) -> Self {
+                            #set_size_stmt
+                            self.#param_ident = #param_ident_short #ptr;
+                            self
+                        }
+                    });
+                }
+            }
+
+            if ty.name == "VkBool32" {
+                return Some(quote! {
+                    #[inline]
+                    #deprecated
+                    pub fn #param_ident_short(mut self, #param_ident_short: bool) -> Self {
+                        self.#param_ident = #param_ident_short.into();
+                        self
+                    }
+                });
+            }
+
+            if let Some(object_type) = object_type_member(member) {
+                let object_type_ident = format_ident!("{}", object_type.to_snake_case());
+                return Some(quote! {
+                    #[inline]
+                    #deprecated
+                    pub fn #param_ident_short<T: Handle>(mut self, #param_ident_short: T) -> Self {
+                        self.#param_ident = #param_ident_short.as_raw();
+                        self.#object_type_ident = T::TYPE;
+                        self
+                    }
+                });
+            }
+
+            let param_ty_tokens = if is_opaque_type(&ty.name) {
+                //  Use raw pointers for void/opaque types
+                member_type_tokens(member, false)
+            } else {
+                param_ty_tokens
+            };
+
+            let lifetime = self.has_lifetime(&ty.name).then(|| quote!(<'a>));
+
+            // Fixed-size strings such as `VkPhysicalDeviceProperties::deviceName` can be read
+            // back, unless they are not nul-terminated
+            let c_str_getter = (is_static_array(member) && ty.name == "char").then(|| {
+                let getter = format_ident!("{}_as_c_str", param_ident_short);
+                quote! {
+                    #[inline]
+                    #deprecated
+                    pub fn #getter(&self) -> Option<&::std::ffi::CStr> {
+                        wrap_c_str_slice_until_nul(&self.#param_ident)
+                    }
+                }
+            });
+
+            Some(quote! {
+                #[inline]
+                #deprecated
+                pub fn #param_ident_short(mut self, #param_ident_short: #param_ty_tokens #lifetime) -> Self {
+                    self.#param_ident = #param_ident_short;
+                    self
+                }
+                #c_str_getter
+            })
+        });
+
+        let extends_name = format_ident!("Extends{}", name);
+
+        // The `p_next` field should only be considered if this struct is also a root struct
+        let root_struct_next_field =
+            next_field.filter(|_| self.root_structs.contains(&name.to_string()));
+
+        // We only implement a next method for root structs with a `pnext` field.
+        let next_function = if let Some(next_field) = root_struct_next_field {
+            assert_eq!(next_field.decl.ty.name, "void");
+            let mutability = if next_field.decl.ty.is_const {
+                quote!(const)
+            } else {
+                quote!(mut)
+            };
+            quote! {
+                /// Prepends the given extension struct between the root and the first pointer. This
+                /// method only exists on structs that can be passed to a function directly. Only
+                /// valid extension structs can be pushed into the chain.
+                /// If the chain looks like `A -> B -> C`, and you call `x.push_next(&mut D)`, then the
+                /// chain will look like `A -> D -> B -> C`.
+                pub fn push_next<T: #extends_name>(mut self, next: &'a mut T) -> Self {
+                    unsafe {
+                        let next_ptr = <*#mutability T>::cast(next);
+                        // `next` here can contain a pointer chain. This means that we must correctly
+                        // attach he head to the root and the tail to the rest of the chain
+                        // For example:
+                        //
+                        // next = A -> B
+                        // Before: `Root -> C -> D -> E`
+                        // After: `Root -> A -> B -> C -> D -> E`
+                        //                 ^^^^^^
+                        //                 next chain
+                        let last_next = ptr_chain_iter(next).last().unwrap();
+                        (*last_next).p_next = self.p_next as _;
+                        self.p_next = next_ptr;
+                    }
+                    self
+                }
+            }
+        } else {
+            quote!()
+        };
+
+        // Root structs come with their own trait that structs that extend
+        // this struct will implement
+        let next_trait = if root_struct_next_field.is_some() {
+            quote!(pub unsafe trait #extends_name {})
+        } else {
+            quote!()
+        };
+
+        let lifetime = self.has_lifetime(&struct_.name).then(|| quote!(<'a>));
+
+        // If the struct extends something we need to implement the traits.
+        let impl_extend_trait = struct_
+            .struct_extends
+            .iter()
+            .map(|extends| format_ident!("Extends{}", type_ident(extends)))
+            .map(|extends| {
+                // Extension structs always have a pNext, and therefore always have a lifetime.
+                quote!(unsafe impl #extends for #name<'_> {})
+            });
+
+        let impl_structure_type_trait = structure_type_field.map(|member| {
+            let value = member
+                .values
+                .as_deref()
+                .expect("s_type field must have a value in `vk.xml`");
+
+            assert!(!value.contains(','));
+
+            let value = variant_ident(&self.vendors, "VkStructureType", value);
+            quote! {
+                unsafe impl #lifetime TaggedStructure for #name #lifetime {
+                    const STRUCTURE_TYPE: StructureType = StructureType::#value;
+                }
+            }
+        });
+
+        Some(quote! {
+            #impl_structure_type_trait
+            #(#impl_extend_trait)*
+            #next_trait
+
+            impl #lifetime #name #lifetime {
+                #(#setters)*
+
+                #next_function
+            }
+        })
+    }
+
+    pub fn generate_struct(&self, struct_: &Struct) -> TokenStream {
+        if let Some(tokens) = bitfield_struct(struct_) {
+            return tokens;
+        }
+
+        let name = type_ident(&struct_.name);
+        let members = struct_
+            .members
+            .iter()
+            .filter(|member| self.is_api(&member.api))
+            .map(|member| {
+                let deprecated = member
+                    .deprecated
+                    .as_ref()
+                    .map(|deprecated| match deprecated.as_str() {
+                        "true" => quote!(#[deprecated]),
+                        "ignored" => {
+                            quote!(#[deprecated = "functionality described by this member no longer operates"])
+                        }
+                        x => panic!("Unknown deprecation reason {}", x),
+                    });
+                PreprocessedMember { member, deprecated }
+            })
+            .collect::<Vec<_>>();
+
+        let params = members
+            .iter()
+            .map(|PreprocessedMember { member, deprecated }| {
+                let ty = &member.decl.ty;
+                let param_ident = field_ident(&member.decl.name);
+                let param_ty_tokens = if ty.name == struct_.name {
+                    let pointer = pointer_tokens(ty);
+                    quote!(#pointer Self)
+                } else {
+                    let lifetime = self.has_lifetime(&ty.name).then(|| quote!(<'a>));
+                    let ty = member_type_tokens(member, false);
+                    quote!(#ty #lifetime)
+                };
+
+                quote!(#deprecated pub #param_ident: #param_ty_tokens)
+            });
+
+        let has_lifetime = self.has_lifetime(&struct_.name);
+        let (lifetimes, marker) = match has_lifetime {
+            true => (quote!(<'a>), quote!(pub _marker: PhantomData<&'a ()>,)),
+            false => (quote!(), quote!()),
+        };
+
+        let debug_tokens = self.derive_debug(struct_, &members, has_lifetime);
+        let default_tokens = derive_default(struct_, &members, has_lifetime);
+        let setter_tokens = self.derive_setters(struct_, &members);
+        let manual_derive_tokens = manual_derives(struct_);
+        let dbg_str = if debug_tokens.is_none() {
+            quote!(#[cfg_attr(feature = "debug", derive(Debug))])
+        } else {
+            quote!()
+        };
+        let default_str = if default_tokens.is_none() {
+            quote!(Default,)
+        } else {
+            quote!()
+        };
+        let khronos_link = khronos_link(&struct_.name);
+        quote! {
+            #[repr(C)]
+            #dbg_str
+            #[derive(Copy, Clone, #default_str #manual_derive_tokens)]
+            #[doc = #khronos_link]
+            pub struct #name #lifetimes {
+                #(#params,)*
+                #marker
+            }
+            #debug_tokens
+            #default_tokens
+            #setter_tokens
+        }
+    }
+
+    pub fn generate_union(&self, union: &Struct) -> TokenStream {
+        let name = type_ident(&union.name);
+        let fields = union.members.iter().map(|member| {
+            let name = field_ident(&member.decl.name);
+            let ty = member_type_tokens(member, false);
+            let lifetime = self
+                .has_lifetime(&member.decl.ty.name)
+                .then(|| quote!(<'a>));
+            quote! {
+                pub #name: #ty #lifetime
+            }
+        });
+        let khronos_link = khronos_link(&union.name);
+        let lifetime = self.has_lifetime(&union.name).then(|| quote!(<'a>));
+        quote! {
+            #[repr(C)]
+            #[derive(Copy, Clone)]
+            #[doc = #khronos_link]
+            pub union #name #lifetime {
+                #(#fields),*
+            }
+            impl #lifetime ::std::default::Default for #name #lifetime {
+                #[inline]
+                fn default() -> Self {
+                    unsafe { ::std::mem::zeroed() }
+                }
+            }
+        }
+    }
+}
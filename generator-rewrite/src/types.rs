@@ -0,0 +1,401 @@
+//! Conversion of C types, literals and expressions from `vk.xml` into Rust tokens.
+
+use crate::naming::{constant_name, interleave_number, type_ident};
+use analysis::xml::{ArrayLen, CType, Length, Member};
+use proc_macro2::{Delimiter, Group, Ident, Literal, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use std::{collections::BTreeMap, fmt};
+
+/// The Rust type of a constant, derived from the suffix of its C literal.
+#[derive(Copy, Clone, Debug)]
+pub enum ConstType {
+    USize,
+    U32,
+    U64,
+    Float,
+    Bool32,
+}
+
+impl ConstType {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::USize => "usize",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::Float => "f32",
+            Self::Bool32 => "Bool32",
+        }
+    }
+}
+
+impl ToTokens for ConstType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        format_ident!("{}", self.as_str()).to_tokens(tokens);
+    }
+}
+
+/// Splits an integer literal such as `3U` or `0ULL` into its digits and type.
+fn split_int_suffix(i: &str) -> Option<(&str, ConstType)> {
+    let (digits, ty) = if let Some(digits) = i.strip_suffix("ULL") {
+        (digits, ConstType::U64)
+    } else {
+        (i.strip_suffix('U')?, ConstType::U32)
+    };
+    Some((digits, ty))
+}
+
+fn parse_decimal_number(i: &str) -> Option<(ConstType, String)> {
+    let (digits, ty) = split_int_suffix(i)?;
+    (!digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+        .then(|| (ty, digits.to_owned()))
+}
+
+fn parse_hexadecimal_number(i: &str) -> Option<(ConstType, String)> {
+    let i = i.strip_prefix("0x").or_else(|| i.strip_prefix("0X"))?;
+    let (digits, ty) = split_int_suffix(i)?;
+    (!digits.is_empty() && digits.bytes().all(|b| b.is_ascii_hexdigit())).then(|| {
+        (
+            ty,
+            format!("0x{}{}", digits.to_ascii_lowercase(), ty.as_str()),
+        )
+    })
+}
+
+/// `(~0U)` or `(~0U-1)`
+fn parse_inverse_number(i: &str) -> Option<(ConstType, String)> {
+    let i = i.strip_prefix("(~")?.strip_suffix(')')?;
+    let (number, minus) = match i.split_once('-') {
+        Some((number, minus)) => (number, Some(minus)),
+        None => (i, None),
+    };
+    let (ty, num) = parse_decimal_number(number)?;
+    let expr = match minus {
+        Some(minus) if !minus.is_empty() && minus.bytes().all(|b| b.is_ascii_digit()) => {
+            format!("!{num}-{minus}")
+        }
+        Some(_) => return None,
+        None => format!("!{num}"),
+    };
+    Some((ty, expr))
+}
+
+fn parse_cfloat(i: &str) -> Option<f32> {
+    let f = i.strip_suffix(['f', 'F'])?;
+    if !f.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+    f.parse().ok()
+}
+
+/// Parses the C literals used by `vk.xml` constants and defines, returning their type and
+/// Rust representation. Integer literals without a suffix are left to the caller.
+pub fn parse_cexpr(i: &str) -> Option<(ConstType, String)> {
+    parse_cfloat(i)
+        .map(|f| (ConstType::Float, format!("{f:.2}")))
+        .or_else(|| parse_inverse_number(i))
+        .or_else(|| parse_decimal_number(i))
+        .or_else(|| parse_hexadecimal_number(i))
+}
+
+/// The value of an API constant or enum variant.
+#[derive(Clone, Debug)]
+pub enum Constant {
+    Number(i32),
+    Hex(String),
+    BitPos(u32),
+    CExpr(String),
+    Alias(Ident),
+}
+
+impl ToTokens for Constant {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Number(n) => {
+                let number = interleave_number('_', 3, &n.to_string());
+                syn::LitInt::new(&number, Span::call_site()).to_tokens(tokens);
+            }
+            Self::Hex(s) => {
+                let number = interleave_number('_', 4, s);
+                syn::LitInt::new(&format!("0x{number}"), Span::call_site()).to_tokens(tokens);
+            }
+            Self::CExpr(expr) => {
+                let (_, rexpr) = parse_cexpr(expr).expect("Unable to parse cexpr");
+                tokens.extend(rexpr.parse::<TokenStream>());
+            }
+            Self::BitPos(pos) => {
+                let value = 1u64 << pos;
+                let bit_string = format!("{value:b}");
+                let bit_string = interleave_number('_', 4, &bit_string);
+                syn::LitInt::new(&format!("0b{bit_string}"), Span::call_site()).to_tokens(tokens);
+            }
+            Self::Alias(value) => tokens.extend(quote!(Self::#value)),
+        }
+    }
+}
+
+impl Constant {
+    /// Parses the `value` attribute of an `<enum>` that is part of an enumeration.
+    pub fn from_value(value: &str) -> Option<Self> {
+        value
+            .strip_prefix("0x")
+            .map(|hex| Self::Hex(hex.to_owned()))
+            .or_else(|| value.parse().ok().map(Self::Number))
+    }
+
+    /// The type of an API constant with this value.
+    pub fn ty(&self) -> Result<ConstType, ConstantTypeError> {
+        match self {
+            Self::Number(_) | Self::Hex(_) => Ok(ConstType::USize),
+            Self::CExpr(expr) => parse_cexpr(expr)
+                .map(|(ty, _)| ty)
+                .ok_or_else(|| ConstantTypeError::UnsupportedExpression(expr.clone())),
+            Self::BitPos(_) | Self::Alias(_) => Err(ConstantTypeError::NotAConstant(self.clone())),
+        }
+    }
+}
+
+/// The error returned by [`Constant::ty()`].
+#[derive(Debug)]
+pub enum ConstantTypeError {
+    /// A C expression that [`parse_cexpr()`] does not support.
+    UnsupportedExpression(String),
+    /// A bit position or alias, which only occur as variants of enumerations and bitmasks.
+    NotAConstant(Constant),
+}
+
+impl fmt::Display for ConstantTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedExpression(expr) => write!(f, "unsupported C expression `{expr}`"),
+            Self::NotAConstant(constant) => {
+                write!(f, "{constant:?} is not a valid value for an API constant")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConstantTypeError {}
+
+/// Parses and rewrites a C literal into Rust
+///
+/// If no special pattern is recognized the original literal is returned.
+/// Any new conversions need to be added to [`parse_cexpr()`].
+///
+/// Examples:
+/// - `0x3FFU` -> `0x3ffu32`
+fn convert_c_literal(lit: Literal) -> Literal {
+    if let Some((_, rexpr)) = parse_cexpr(&lit.to_string()) {
+        let mut stream = rexpr.parse::<TokenStream>().unwrap().into_iter();
+        // If expression rewriting succeeds this should parse into a single literal
+        match (stream.next(), stream.next()) {
+            (Some(TokenTree::Literal(l)), None) => l,
+            x => panic!("Stream must contain a single literal, not {x:?}"),
+        }
+    } else {
+        lit
+    }
+}
+
+/// Parse and yield a C expression that is valid to write in Rust
+/// Identifiers are replaced with their Rust vk equivalent.
+///
+/// Examples:
+/// - `VK_MAKE_VERSION(1, 2, VK_HEADER_VERSION)` -> `make_version(1, 2, HEADER_VERSION)`
+/// - `2*VK_UUID_SIZE` -> `2 * UUID_SIZE`
+pub fn convert_c_expression(
+    c_expr: &str,
+    identifier_renames: &BTreeMap<String, Ident>,
+) -> TokenStream {
+    fn rewrite_token_stream(
+        stream: TokenStream,
+        identifier_renames: &BTreeMap<String, Ident>,
+    ) -> TokenStream {
+        stream
+            .into_iter()
+            .map(|tt| match tt {
+                TokenTree::Group(group) => TokenTree::Group(Group::new(
+                    group.delimiter(),
+                    rewrite_token_stream(group.stream(), identifier_renames),
+                )),
+                TokenTree::Ident(term) => {
+                    let name = term.to_string();
+                    identifier_renames
+                        .get(&name)
+                        .cloned()
+                        .unwrap_or_else(|| format_ident!("{}", constant_name(&name)))
+                        .into()
+                }
+                TokenTree::Literal(lit) => TokenTree::Literal(convert_c_literal(lit)),
+                tt => tt,
+            })
+            .collect()
+    }
+    let c_expr = c_expr
+        .parse()
+        .unwrap_or_else(|_| panic!("Failed to parse `{c_expr}` as Rust"));
+    rewrite_token_stream(c_expr, identifier_renames)
+}
+
+pub fn discard_outmost_delimiter(stream: TokenStream) -> TokenStream {
+    let stream = stream.into_iter().collect::<Vec<_>>();
+    // Discard the delimiter if this stream consists of a single top-most group
+    if let [TokenTree::Group(group)] = stream.as_slice() {
+        TokenTree::Group(Group::new(Delimiter::None, group.stream())).into()
+    } else {
+        stream.into_iter().collect()
+    }
+}
+
+/// Raw pointer qualifiers of `ty`, from the outermost pointer inwards.
+pub fn pointer_tokens(ty: &CType) -> TokenStream {
+    let pointers = (0..ty.pointers.len()).rev().map(|i| {
+        let pointee_is_const = match i {
+            0 => ty.is_const,
+            _ => ty.pointers[i - 1].is_const,
+        };
+        if pointee_is_const {
+            quote!(*const)
+        } else {
+            quote!(*mut)
+        }
+    });
+    quote!(#(#pointers)*)
+}
+
+/// Returns the Rust type of `ty`. When `is_ffi_param` is `true` array types (e.g. `[f32; 3]`)
+/// will be converted to pointer types (e.g. `*const [f32; 3]`), which is needed for `C`
+/// function parameters. Set to `false` for struct definitions.
+pub fn type_tokens(ty: &CType, is_ffi_param: bool) -> TokenStream {
+    assert!(!ty.is_void());
+    let base = type_ident(&ty.name);
+    match ty.arrays.as_slice() {
+        [] => {
+            let pointer = pointer_tokens(ty);
+            quote!(#pointer #base)
+        }
+        [size] => {
+            let pointer = pointer_tokens(ty);
+            // arrays in c are always passed as a pointer
+            if is_ffi_param {
+                let size = match size {
+                    ArrayLen::Literal(size) => {
+                        Literal::usize_suffixed(*size as usize).to_token_stream()
+                    }
+                    ArrayLen::Constant(size) => convert_c_expression(size, &BTreeMap::new()),
+                };
+                quote!(*const [#pointer #base; #size])
+            } else {
+                // Make sure we also rename the constant, that is used inside the static array
+                let size = convert_c_expression(&size.to_string(), &BTreeMap::new());
+                quote!([#pointer #base; #size])
+            }
+        }
+        _ => panic!("Multidimensional array `{ty}` is not supported"),
+    }
+}
+
+/// Whether `member` is a pointer to a dynamically sized array, i.e. has a `len`.
+pub fn is_dynamic_array(member: &Member) -> bool {
+    !member.len.is_empty()
+}
+
+pub fn is_static_array(member: &Member) -> bool {
+    member.decl.ty.is_array() && member.len.is_empty()
+}
+
+/// The expression giving the number of elements of a dynamic array, without a trailing
+/// `null-terminated` entry. Nested lengths are separated by commas, as in `vk.xml`.
+pub fn array_size(member: &Member) -> Option<String> {
+    let mut len = member.len.as_slice();
+    if let [rest @ .., Length::NullTerminated] = len {
+        len = rest;
+    }
+    if len.is_empty() {
+        return None;
+    }
+    let parts = len
+        .iter()
+        .map(|len| match len {
+            Length::NullTerminated => "null-terminated".to_owned(),
+            Length::Literal(n) => n.to_string(),
+            Length::Member(path) => path.join("->"),
+            Length::Latexmath(expr) => format!("latexmath:{expr}"),
+        })
+        .collect::<Vec<_>>();
+    Some(parts.join(","))
+}
+
+/// Exceptions for pointers to static-sized arrays, `vk.xml` does not annotate this.
+pub fn is_pointer_to_static_sized_array(member: &Member) -> bool {
+    is_dynamic_array(member) && member.decl.name == "pVersionData"
+}
+
+/// The Rust type of a struct member or command parameter.
+pub fn member_type_tokens(member: &Member, is_ffi_param: bool) -> TokenStream {
+    if is_pointer_to_static_sized_array(member) {
+        let ty = &member.decl.ty;
+        let pointer = pointer_tokens(ty);
+        let base = type_ident(&ty.name);
+        let size = member.alt_len.as_ref().expect("Should have altlen");
+        let size = convert_c_expression(size, &BTreeMap::new());
+        quote!(#pointer [#base; #size])
+    } else {
+        type_tokens(&member.decl.ty, is_ffi_param)
+    }
+}
+
+/// The element type of the array `member` points to, with one level of pointers removed.
+pub fn inner_type_tokens(
+    member: &Member,
+    lifetime: Option<TokenStream>,
+    inner_length: Option<u32>,
+) -> TokenStream {
+    let ty = &member.decl.ty;
+    assert!(!ty.is_void());
+    let base = type_ident(&ty.name);
+    if ty.pointers.len() < 2 {
+        return quote!(#base);
+    }
+    let mutability = if ty.is_const { quote!() } else { quote!(mut) };
+    match (lifetime, inner_length) {
+        // If the nested "dynamic array" has length 1, it's just a pointer which we convert to a
+        // safe borrow for convenience
+        (Some(lifetime), Some(1)) => quote!(&#lifetime #mutability #base),
+        _ if ty.is_const => quote!(*const #base),
+        _ => quote!(*mut #base),
+    }
+}
+
+/// Returns reference-types wrapped in their safe variant. (Dynamic) arrays become
+/// slices, pointers become Rust references.
+pub fn safe_type_tokens(
+    member: &Member,
+    lifetime: TokenStream,
+    inner_length: Option<u32>,
+) -> TokenStream {
+    let ty = &member.decl.ty;
+    assert!(!ty.is_void());
+    if is_static_array(member) {
+        // The outer type `type_tokens()` returns is [], which fits our "safe" prescription
+        member_type_tokens(member, false)
+    } else if is_dynamic_array(member) {
+        let inner = inner_type_tokens(member, Some(lifetime), inner_length);
+        quote!([#inner])
+    } else {
+        let base = type_ident(&ty.name);
+        let Some((_, inner)) = ty.pointers.split_last() else {
+            return quote!(#base);
+        };
+        let pointee_is_const = inner.last().map_or(ty.is_const, |p| p.is_const);
+        let reference = if pointee_is_const {
+            quote!(&#lifetime)
+        } else {
+            quote!(&#lifetime mut)
+        };
+        let inner = pointer_tokens(&CType {
+            pointers: inner.to_vec(),
+            ..ty.clone()
+        });
+        quote!(#reference #inner #base)
+    }
+}
@@ -0,0 +1,519 @@
+//! `DeepCopyable` implementations for the types of `vk`, backing `ash::deep_copy` and the
+//! `ash::trace` call recorder and replayer.
+
+use super::{name_to_tokens, wrappers::TypeInfo, ConstantTypeInfo, FieldExt, DESIRED_API};
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use syn::Ident;
+
+/// Types defined as `c_void` in `ash/src/vk/platform_types.rs`, which can only be pointed to.
+const OPAQUE_TYPES: &[&str] = &[
+    "void",
+    "VkRemoteAddressNV",
+    "Display",
+    "xcb_connection_t",
+    "wl_display",
+    "wl_surface",
+    "_screen_buffer",
+    "_screen_context",
+    "_screen_window",
+    "SECURITY_ATTRIBUTES",
+    "ANativeWindow",
+    "AHardwareBuffer",
+    "CAMetalLayer",
+    "IDirectFB",
+    "IDirectFBSurface",
+    "__IOSurface",
+];
+
+/// Structs that are generated by hand, or whose pointers are not described by `vk.xml`.
+pub(super) const BYTES_STRUCTS: &[&str] = &[
+    "VkBaseInStructure",
+    "VkBaseOutStructure",
+    "VkTransformMatrixKHR",
+    "VkAccelerationStructureInstanceKHR",
+    "VkAccelerationStructureSRTMotionInstanceNV",
+    "VkAccelerationStructureMatrixMotionInstanceNV",
+];
+
+/// Types defined as pointers in `ash/src/vk/platform_types.rs`, which are copied by address.
+const POINTER_TYPES: &[&str] = &[
+    "MirConnection",
+    "MirSurface",
+    "HINSTANCE",
+    "HWND",
+    "HANDLE",
+    "HMONITOR",
+    "LPCWSTR",
+    "IOSurfaceRef",
+    "MTLBuffer_id",
+    "MTLCommandQueue_id",
+    "MTLDevice_id",
+    "MTLSharedEvent_id",
+    "MTLTexture_id",
+];
+
+/// How the elements behind a pointer are recorded.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum Element {
+    /// Null-terminated `char` strings.
+    Str,
+    /// Types without a Rust definition, only recorded as bytes when their size is known.
+    Opaque,
+    /// Primitive types, recorded as bytes.
+    Data,
+    /// Video std types, recorded as bytes without following their pointers.
+    Native,
+    /// Types that implement `DeepCopyable`.
+    Copyable,
+}
+
+impl Element {
+    pub(super) fn of(name: &str, types: &TypeInfo<'_>) -> Self {
+        let name = types.resolve(name);
+        match name {
+            "char" => Self::Str,
+            _ if OPAQUE_TYPES.contains(&name) => Self::Opaque,
+            _ if name.starts_with("StdVideo") => Self::Native,
+            "uint8_t" | "uint16_t" | "uint32_t" | "uint64_t" | "int8_t" | "int16_t" | "int32_t"
+            | "int64_t" | "size_t" | "float" | "double" | "int" => Self::Data,
+            _ => Self::Copyable,
+        }
+    }
+
+    /// Records the elements at `ptr`, or a single one if `len` is `None`.
+    pub(super) fn record(self, ptr: &TokenStream, len: Option<&TokenStream>) -> TokenStream {
+        match (self, len) {
+            (Self::Str, _) => quote!(record_str(#ptr)),
+            (Self::Opaque, Some(len)) => quote!(record_data(#ptr.cast::<u8>(), #len)),
+            (Self::Opaque, None) => quote!(#ptr.record()),
+            (Self::Data, Some(len)) => quote!(record_data(#ptr, #len)),
+            (Self::Native, len) => {
+                let len = len.cloned().unwrap_or_else(|| quote!(1));
+                quote!(record_array(#ptr, #len, |element| record_bytes(element)))
+            }
+            (Self::Data | Self::Copyable, len) => {
+                let len = len.cloned().unwrap_or_else(|| quote!(1));
+                quote!(record_array(#ptr, #len, |element| element.record()))
+            }
+        }
+    }
+
+    /// Replays the pointer recorded by [`Element::record()`] from `value`, in a function that
+    /// returns a `ValueError` if `value` does not match.
+    pub(super) fn replay(self, value: &TokenStream, has_len: bool) -> TokenStream {
+        match (self, has_len) {
+            (Self::Str, _) => quote!(replay_str(#value, arena)?),
+            (Self::Opaque, true) => quote!(replay_data::<u8>(#value, arena)?.cast::<c_void>()),
+            (Self::Opaque, false) => quote!(DeepCopyable::replay(#value, arena)?),
+            (Self::Data, true) => quote!(replay_data(#value, arena)?),
+            (Self::Native, _) => {
+                quote!(replay_array(#value, arena, |value, _| replay_bytes(value))?)
+            }
+            (Self::Data | Self::Copyable, _) => {
+                quote!(replay_array(#value, arena, |value, arena| DeepCopyable::replay(value, arena))?)
+            }
+        }
+    }
+}
+
+/// The members of a struct that are part of the Vulkan API, with their `vk_parse` definition.
+pub(super) fn members<'a>(
+    struct_: &'a vkxml::Struct,
+    vk_parse_types: &HashMap<String, &'a vk_parse::Type>,
+) -> impl Iterator<Item = (&'a vkxml::Field, &'a vk_parse::TypeMemberDefinition)> {
+    let vk_parse::TypeSpec::Members(vk_parse_members) = &vk_parse_types[&struct_.name].spec else {
+        panic!()
+    };
+    struct_
+        .elements
+        .iter()
+        .filter_map(get_variant!(vkxml::StructElement::Member))
+        .zip(
+            vk_parse_members
+                .iter()
+                .filter_map(get_variant!(vk_parse::TypeMember::Definition)),
+        )
+        .filter(|(_, vk_parse_field)| {
+            matches!(vk_parse_field.api.as_deref(), None | Some(DESIRED_API))
+        })
+}
+
+/// The element count of a struct member that is a pointer, referring to other members as
+/// `self.*`.
+pub(super) fn member_len(
+    field: &vk_parse::TypeMemberDefinition,
+    struct_: &vkxml::Struct,
+) -> Option<TokenStream> {
+    let len = field.len.as_deref()?.split(',').next().unwrap();
+    if len == "null-terminated" {
+        return None;
+    }
+    if len.starts_with("latexmath") {
+        return Some(match field.altlen.as_deref() {
+            Some("codeSize / 4") => quote!(self.code_size / 4),
+            Some("(rasterizationSamples + 31) / 32") => {
+                quote!((self.rasterization_samples.as_raw() as usize + 31) / 32)
+            }
+            Some("2*VK_UUID_SIZE") => quote!(2 * vk::UUID_SIZE),
+            altlen => panic!("Unknown altlen {altlen:?} in {}", struct_.name),
+        });
+    }
+    let is_usize = struct_
+        .elements
+        .iter()
+        .filter_map(get_variant!(vkxml::StructElement::Member))
+        .any(|m| m.name.as_deref() == Some(len) && m.basetype == "size_t");
+    let member = format_ident!("{}", len.to_snake_case());
+    Some(if is_usize {
+        quote!(self.#member)
+    } else {
+        quote!(self.#member as usize)
+    })
+}
+
+/// Generates the `DeepCopyable` implementation of a struct, recording every member in order.
+fn copyable_struct(
+    struct_: &vkxml::Struct,
+    vk_parse_types: &HashMap<String, &vk_parse::Type>,
+    types: &TypeInfo<'_>,
+) -> TokenStream {
+    let name = name_to_tokens(&struct_.name);
+    let members = members(struct_, vk_parse_types);
+
+    let mut records = Vec::new();
+    let mut replays = Vec::new();
+    for (i, (field, vk_parse_field)) in members.enumerate() {
+        let ident = field.param_ident();
+        let member = quote!(self.#ident);
+        let value = quote!(&members[#i]);
+        let element = Element::of(&field.basetype, types);
+        let len = member_len(vk_parse_field, struct_);
+        let (record, replay) = match field.reference {
+            _ if ident == "p_next" => (
+                quote!(record_chain(#member)),
+                quote!(replay_chain(#value, arena)?),
+            ),
+            None if field.basetype.starts_with("PFN_") => {
+                (quote!(record_pfn(&#member)), quote!(replay_pfn(#value)?))
+            }
+            None if element == Element::Native => (
+                quote!(record_bytes(&#member)),
+                quote!(replay_bytes(#value)?),
+            ),
+            None => (
+                quote!(#member.record()),
+                quote!(DeepCopyable::replay(#value, arena)?),
+            ),
+            Some(vkxml::ReferenceType::Pointer) => (
+                element.record(&member, len.as_ref()),
+                element.replay(&value, len.is_some()),
+            ),
+            Some(_) => {
+                let inner = vk_parse_field
+                    .len
+                    .as_deref()
+                    .and_then(|len| len.split(',').nth(1));
+                match (len, inner) {
+                    (Some(len), Some("null-terminated")) => (
+                        quote!(record_array(#member, #len, |string| record_str(*string))),
+                        quote!(replay_array(#value, arena, |value, arena| replay_str(value, arena))?),
+                    ),
+                    (Some(len), Some("1")) => (
+                        quote! {
+                            record_array(#member, #len, |element| {
+                                record_array(*element, 1, |element| element.record())
+                            })
+                        },
+                        quote! {
+                            replay_array(#value, arena, |value, arena| {
+                                replay_array(value, arena, |value, arena| {
+                                    DeepCopyable::replay(value, arena)
+                                })
+                                .map(as_const)
+                            })?
+                        },
+                    ),
+                    _ => (quote!(Value::Null), quote!(ptr::null_mut())),
+                }
+            }
+        };
+        records.push(record);
+        replays.push(quote!(#ident: #replay));
+    }
+
+    let member_count = records.len();
+    let has_lifetime = types.has_lifetimes.contains(&name);
+    let lifetime = has_lifetime.then(|| quote!(<'_>));
+    let marker = has_lifetime.then(|| quote!(_marker: PhantomData,));
+    let static_type = static_type(&name, has_lifetime);
+    quote! {
+        unsafe impl DeepCopyable for vk::#name #lifetime {
+            type Static = #static_type;
+
+            unsafe fn record(&self) -> Value {
+                Value::Struct(vec![#(#records),*])
+            }
+
+            unsafe fn replay(value: &Value, arena: &mut Arena) -> Result<Self, ValueError> {
+                let members = value.members(#member_count)?;
+                Ok(Self {
+                    #(#replays,)*
+                    #marker
+                })
+            }
+        }
+    }
+}
+
+/// Generates a `DeepCopyable` implementation that copies the bytes of a type.
+fn copyable_bytes(name: &Ident, types: &TypeInfo<'_>) -> TokenStream {
+    let has_lifetime = types.has_lifetimes.contains(name);
+    let lifetime = has_lifetime.then(|| quote!(<'_>));
+    let static_type = static_type(name, has_lifetime);
+    quote! {
+        unsafe impl DeepCopyable for vk::#name #lifetime {
+            type Static = #static_type;
+
+            unsafe fn record(&self) -> Value {
+                record_bytes(self)
+            }
+
+            unsafe fn replay(value: &Value, _arena: &mut Arena) -> Result<Self, ValueError> {
+                replay_bytes(value)
+            }
+        }
+    }
+}
+
+/// The `DeepCopyable::Static` type of `vk::<name>`, with its lifetime set to `'static`.
+fn static_type(name: &Ident, has_lifetime: bool) -> TokenStream {
+    if has_lifetime {
+        quote!(vk::#name<'static>)
+    } else {
+        quote!(Self)
+    }
+}
+
+/// The types that a copy of `struct_` owns through its members and `p_next` chain, or `None` if
+/// one of its members is copied without the data that it points to.
+fn struct_dependencies<'a>(
+    struct_: &'a vkxml::Struct,
+    vk_parse_types: &HashMap<String, &'a vk_parse::Type>,
+    extenders: &HashMap<&str, Vec<&'a str>>,
+    types: &'a TypeInfo<'_>,
+) -> Option<Vec<&'a str>> {
+    let mut dependencies = Vec::new();
+    for (field, vk_parse_field) in members(struct_, vk_parse_types) {
+        if field.name.as_deref() == Some("pNext") {
+            dependencies.extend(extenders.get(struct_.name.as_str()).into_iter().flatten());
+            continue;
+        }
+        let name = types.resolve(&field.basetype);
+        let element = Element::of(name, types);
+        let is_contained = match field.reference {
+            None if name.starts_with("PFN_") => true,
+            None => element != Element::Native && !POINTER_TYPES.contains(&name),
+            Some(vkxml::ReferenceType::Pointer) => match element {
+                Element::Opaque => member_len(vk_parse_field, struct_).is_some(),
+                Element::Native => false,
+                _ => !POINTER_TYPES.contains(&name),
+            },
+            // Arrays of strings and of single elements, or null
+            Some(_) => true,
+        };
+        if !is_contained {
+            return None;
+        }
+        dependencies.push(name);
+    }
+    Some(dependencies)
+}
+
+/// The types that a byte copy of `fields` contains, or `None` if one of them is a pointer.
+fn bytes_dependencies<'a>(
+    fields: impl IntoIterator<Item = &'a vkxml::Field>,
+    types: &'a TypeInfo<'_>,
+) -> Option<Vec<&'a str>> {
+    fields
+        .into_iter()
+        .map(|field| {
+            let name = types.resolve(&field.basetype);
+            let is_pointer = field.reference.is_some()
+                || POINTER_TYPES.contains(&name)
+                || Element::of(name, types) == Element::Native;
+            (!is_pointer).then_some(name)
+        })
+        .collect()
+}
+
+/// Generates `ash/src/deep_copy/generated.rs`.
+pub fn generate_deep_copy(
+    definitions: &[&vkxml::DefinitionsElement],
+    required_types: &HashSet<&str>,
+    vk_parse_types: &HashMap<String, &vk_parse::Type>,
+    const_values: &BTreeMap<Ident, ConstantTypeInfo>,
+    types: &TypeInfo<'_>,
+) -> TokenStream {
+    let structs = definitions
+        .iter()
+        .filter_map(get_variant!(vkxml::DefinitionsElement::Struct))
+        .filter(|s| required_types.contains(s.name.as_str()))
+        .collect::<Vec<_>>();
+    let struct_impls = structs.iter().map(|s| {
+        if BYTES_STRUCTS.contains(&s.name.as_str()) {
+            copyable_bytes(&name_to_tokens(&s.name), types)
+        } else {
+            copyable_struct(s, vk_parse_types, types)
+        }
+    });
+    let unions = definitions
+        .iter()
+        .filter_map(get_variant!(vkxml::DefinitionsElement::Union))
+        .filter(|u| required_types.contains(u.name.as_str()))
+        .collect::<Vec<_>>();
+    let union_impls = unions
+        .iter()
+        .map(|u| copyable_bytes(&name_to_tokens(&u.name), types))
+        // Generated by hand along with VkAccelerationStructureInstanceKHR
+        .chain([copyable_bytes(
+            &format_ident!("AccelerationStructureReferenceKHR"),
+            types,
+        )]);
+    let enum_impls = const_values.iter().map(|(name, info)| {
+        let (record, replay) = if info.bitwidth == Some(64) {
+            (quote!(self.as_raw()), quote!(value.int()?))
+        } else {
+            (quote!(self.as_raw() as u64), quote!(value.int()? as _))
+        };
+        quote! {
+            unsafe impl DeepCopyable for vk::#name {
+                type Static = Self;
+
+                unsafe fn record(&self) -> Value {
+                    Value::Int(#record)
+                }
+
+                unsafe fn replay(value: &Value, _arena: &mut Arena) -> Result<Self, ValueError> {
+                    Ok(Self::from_raw(#replay))
+                }
+            }
+        }
+    });
+
+    let extensions = structs
+        .iter()
+        .filter(|s| !BYTES_STRUCTS.contains(&s.name.as_str()))
+        .filter(|s| {
+            s.elements
+                .iter()
+                .filter_map(get_variant!(vkxml::StructElement::Member))
+                .any(|field| field.name.as_deref() == Some("sType"))
+        })
+        .collect::<Vec<_>>();
+
+    // The structs whose `p_next` chain may contain each extension struct
+    let mut extenders = HashMap::<&str, Vec<&str>>::new();
+    for s in &extensions {
+        let extends = vk_parse_types[&s.name].structextends.as_deref();
+        for extended in extends.into_iter().flat_map(|extends| extends.split(',')) {
+            extenders
+                .entry(types.resolve(extended))
+                .or_default()
+                .push(s.name.as_str());
+        }
+    }
+    let mut dependencies = structs
+        .iter()
+        .map(|s| {
+            let dependencies = if BYTES_STRUCTS.contains(&s.name.as_str()) {
+                let fields = s
+                    .elements
+                    .iter()
+                    .filter_map(get_variant!(vkxml::StructElement::Member));
+                bytes_dependencies(fields, types)
+            } else {
+                struct_dependencies(s, vk_parse_types, &extenders, types)
+            };
+            (s.name.as_str(), dependencies)
+        })
+        .chain(
+            unions
+                .iter()
+                .map(|u| (u.name.as_str(), bytes_dependencies(&u.elements, types))),
+        )
+        .collect::<BTreeMap<_, _>>();
+    // A copy is only self-contained if all the copies that it owns are
+    loop {
+        let uncontained = dependencies
+            .iter()
+            .filter(|(_, dependencies)| dependencies.is_none())
+            .map(|(name, _)| *name)
+            .collect::<HashSet<_>>();
+        let mut changed = false;
+        for dependencies in dependencies.values_mut() {
+            if dependencies
+                .as_ref()
+                .is_some_and(|d| d.iter().any(|name| uncontained.contains(name)))
+            {
+                *dependencies = None;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let self_contained_impls = dependencies
+        .iter()
+        .filter(|(_, dependencies)| dependencies.is_some())
+        .map(|(name, _)| name_to_tokens(name))
+        // Generated by hand along with VkAccelerationStructureInstanceKHR
+        .chain([format_ident!("AccelerationStructureReferenceKHR")])
+        .map(|name| {
+            let lifetime = types.has_lifetimes.contains(&name).then(|| quote!(<'_>));
+            quote!(unsafe impl SelfContained for vk::#name #lifetime {})
+        });
+
+    let extensions = extensions
+        .iter()
+        .map(|s| name_to_tokens(&s.name))
+        .collect::<Vec<_>>();
+
+    quote! {
+        use super::*;
+        use crate::vk::TaggedStructure;
+        use std::marker::PhantomData;
+
+        #(#struct_impls)*
+        #(#union_impls)*
+        #(#enum_impls)*
+        #(#self_contained_impls)*
+
+        /// Records an extension struct of a `p_next` chain, if its type is known.
+        pub(super) unsafe fn record_extension(ptr: *const vk::BaseInStructure<'_>) -> Option<Value> {
+            Some(match (*ptr).s_type {
+                #(vk::#extensions::STRUCTURE_TYPE => (*ptr.cast::<vk::#extensions>()).record(),)*
+                _ => return None,
+            })
+        }
+
+        /// Replays an extension struct recorded by `record_extension()` into `arena`, if its type
+        /// is known.
+        pub(super) unsafe fn replay_extension(
+            s_type: vk::StructureType,
+            value: &Value,
+            arena: &mut Arena,
+        ) -> Result<Option<*mut vk::BaseOutStructure<'static>>, ValueError> {
+            Ok(Some(match s_type {
+                #(vk::#extensions::STRUCTURE_TYPE => {
+                    let extension = <vk::#extensions as DeepCopyable>::replay(value, arena)?;
+                    arena.alloc_value(extension).cast()
+                })*
+                _ => return Ok(None),
+            }))
+        }
+    }
+}
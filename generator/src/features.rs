@@ -0,0 +1,289 @@
+//! The structs extending `VkPhysicalDeviceFeatures2`, backing `ash::features::Features`.
+
+use super::{generate_depends, name_to_tokens, FieldExt, DESIRED_API};
+use heck::ToSnakeCase;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::{HashMap, HashSet};
+
+/// A struct of features, and the names of its `VkBool32` members.
+struct FeatureStruct<'a> {
+    name: &'a str,
+    members: Vec<&'a vkxml::Field>,
+    /// The versions and extensions that require the struct or one of its aliases.
+    providers: Vec<&'a str>,
+}
+
+/// The structs that extend `root`, with the versions and extensions that require each struct or
+/// one of its aliases.
+pub(super) fn structs_extending<'a>(
+    root: &str,
+    spec: &'a vk_parse::Registry,
+    extensions: &[&'a vk_parse::Extension],
+    definitions: &[&'a vkxml::DefinitionsElement],
+    required_types: &HashSet<&str>,
+    vk_parse_types: &HashMap<String, &vk_parse::Type>,
+) -> Vec<(&'a vkxml::Struct, Vec<&'a str>)> {
+    let canonical = |name: &str| -> String {
+        match vk_parse_types.get(name).and_then(|ty| ty.alias.as_deref()) {
+            Some(alias) => alias.to_owned(),
+            None => name.to_owned(),
+        }
+    };
+    let mut providers = HashMap::<String, Vec<&str>>::new();
+    let features = spec
+        .0
+        .iter()
+        .filter_map(get_variant!(vk_parse::RegistryChild::Feature))
+        .filter(|feature| super::contains_desired_api(&feature.api))
+        .map(|feature| (feature.name.as_str(), &feature.children));
+    let extensions = extensions
+        .iter()
+        .map(|extension| (extension.name.as_str(), &extension.children));
+    for (provider, children) in features.chain(extensions) {
+        let types = children
+            .iter()
+            .filter_map(get_variant!(vk_parse::ExtensionChild::Require {
+                api,
+                items
+            }))
+            .filter(|(api, _items)| matches!(api.as_deref(), None | Some(DESIRED_API)))
+            .flat_map(|(_api, items)| items)
+            .filter_map(get_variant!(vk_parse::InterfaceItem::Type { name }));
+        for name in types {
+            let list = providers.entry(canonical(name)).or_default();
+            if !list.contains(&provider) {
+                list.push(provider);
+            }
+        }
+    }
+
+    definitions
+        .iter()
+        .filter_map(get_variant!(vkxml::DefinitionsElement::Struct))
+        .filter(|s| required_types.contains(s.name.as_str()))
+        .filter(|s| {
+            vk_parse_types
+                .get(&s.name)
+                .and_then(|ty| ty.structextends.as_deref())
+                .is_some_and(|extends| extends.split(',').any(|e| e == root))
+        })
+        .map(|s| {
+            let providers = providers.remove(&s.name).unwrap_or_default();
+            assert!(!providers.is_empty(), "{} is not required", s.name);
+            (s, providers)
+        })
+        .collect()
+}
+
+/// Generates `ash/src/features/generated.rs` for the structs that extend
+/// `VkPhysicalDeviceFeatures2`, with `VkPhysicalDeviceFeatures2` itself at index 0.
+pub fn generate_features(
+    spec: &vk_parse::Registry,
+    extensions: &[&vk_parse::Extension],
+    definitions: &[&vkxml::DefinitionsElement],
+    required_types: &HashSet<&str>,
+    vk_parse_types: &HashMap<String, &vk_parse::Type>,
+) -> TokenStream {
+    let root = definitions
+        .iter()
+        .filter_map(get_variant!(vkxml::DefinitionsElement::Struct))
+        .find(|s| s.name == "VkPhysicalDeviceFeatures")
+        .expect("VkPhysicalDeviceFeatures");
+    let structs = structs_extending(
+        "VkPhysicalDeviceFeatures2",
+        spec,
+        extensions,
+        definitions,
+        required_types,
+        vk_parse_types,
+    )
+    .into_iter()
+    .map(|(s, providers)| FeatureStruct {
+        name: &s.name,
+        members: s
+            .elements
+            .iter()
+            .filter_map(get_variant!(vkxml::StructElement::Member))
+            .filter(|field| field.basetype == "VkBool32" && field.array.is_none())
+            .collect(),
+        providers,
+    })
+    .collect::<Vec<_>>();
+    let count = structs.len() + 1;
+
+    let field = |name: &str| format_ident!("{}", name.strip_prefix("Vk").unwrap().to_snake_case());
+    let name = |field: &vkxml::Field| field.name.clone().expect("member name");
+    let fields = structs.iter().map(|s| field(s.name)).collect::<Vec<_>>();
+    let types = structs
+        .iter()
+        .map(|s| name_to_tokens(s.name))
+        .collect::<Vec<_>>();
+
+    let depends = structs
+        .iter()
+        .map(|s| generate_depends(&mut s.providers.join(",").as_str()));
+
+    // Structs promoted to a core version are covered by the struct of that version, which has
+    // members of the same names
+    let core_structs = structs
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| {
+            s.name
+                .strip_prefix("VkPhysicalDeviceVulkan")
+                .and_then(|rest| rest.strip_suffix("Features"))
+                .is_some_and(|version| version.chars().all(|c| c.is_ascii_digit()))
+        })
+        .collect::<Vec<_>>();
+    let promoted_to = structs
+        .iter()
+        .map(|s| {
+            let promoted = s
+                .providers
+                .iter()
+                .any(|provider| provider.starts_with("VK_VERSION_"));
+            core_structs
+                .iter()
+                .filter(|(_, core)| core.name != s.name && promoted)
+                .find(|(_, core)| {
+                    s.members
+                        .iter()
+                        .all(|m| core.members.iter().any(|c| c.name == m.name))
+                })
+                .map(|&(index, _)| index + 1)
+        })
+        .collect::<Vec<_>>();
+    let promoted_to_tokens = promoted_to.iter().map(|index| match index {
+        Some(index) => quote!(Some(#index)),
+        None => quote!(None),
+    });
+    let promote_arms =
+        structs
+            .iter()
+            .zip(&promoted_to)
+            .enumerate()
+            .filter_map(|(index, (s, promoted_to))| {
+                let core = &fields[promoted_to.as_ref()? - 1];
+                let index = index + 1;
+                let field = &fields[index - 1];
+                let members = s.members.iter().map(|m| m.param_ident());
+                Some(quote! {
+                    #index => {
+                        #(self.#core.#members |= self.#field.#members;)*
+                    }
+                })
+            });
+
+    let root_members = root
+        .elements
+        .iter()
+        .filter_map(get_variant!(vkxml::StructElement::Member))
+        .collect::<Vec<_>>();
+    let visits = root_members
+        .iter()
+        .map(|m| {
+            (
+                0usize,
+                format!("VkPhysicalDeviceFeatures::{}", name(m)),
+                quote!(physical_device_features2.features),
+                m.param_ident(),
+            )
+        })
+        .chain(structs.iter().enumerate().flat_map(|(index, s)| {
+            let field = &fields[index];
+            s.members.iter().map(move |m| {
+                (
+                    index + 1,
+                    format!("{}::{}", s.name, name(m)),
+                    quote!(#field),
+                    m.param_ident(),
+                )
+            })
+        }))
+        .collect::<Vec<_>>();
+    let zip = visits.iter().map(|(index, name, path, member)| {
+        let name = Literal::string(name);
+        quote!(f(#index, #name, self.#path.#member, other.#path.#member);)
+    });
+    let zip_mut = visits.iter().map(|(index, name, path, member)| {
+        let name = Literal::string(name);
+        quote!(f(#index, #name, &mut self.#path.#member, other.#path.#member);)
+    });
+
+    quote! {
+        use super::*;
+
+        pub(super) const COUNT: usize = #count;
+
+        #[derive(Clone, Default)]
+        pub(super) struct Structs {
+            pub(super) physical_device_features2: vk::PhysicalDeviceFeatures2<'static>,
+            #(pub(super) #fields: vk::#types<'static>,)*
+        }
+
+        /// The versions and extensions that provide each struct.
+        pub(super) const DEPENDS: [vk::Depends; COUNT] = [vk::Depends::NONE, #(#depends),*];
+
+        /// The struct of the core version that each struct was promoted to.
+        pub(super) const PROMOTED_TO: [Option<usize>; COUNT] = [None, #(#promoted_to_tokens),*];
+
+        impl Structs {
+            pub(super) fn bases(&mut self) -> [*mut vk::BaseOutStructure<'static>; COUNT] {
+                [
+                    <*mut _>::cast(&mut self.physical_device_features2),
+                    #(<*mut _>::cast(&mut self.#fields),)*
+                ]
+            }
+
+            /// Calls `f` with the index of the struct, the name and the values in `self` and
+            /// `other` of every feature.
+            pub(super) fn zip(
+                &self,
+                other: &Self,
+                mut f: impl FnMut(usize, &'static str, vk::Bool32, vk::Bool32),
+            ) {
+                #(#zip)*
+            }
+
+            /// Like [`Structs::zip()`], with mutable values in `self`.
+            pub(super) fn zip_mut(
+                &mut self,
+                other: &Self,
+                mut f: impl FnMut(usize, &'static str, &mut vk::Bool32, vk::Bool32),
+            ) {
+                #(#zip_mut)*
+            }
+
+            /// Enables the features of the struct at `index` in the struct it was promoted to.
+            pub(super) fn promote(&mut self, index: usize) {
+                match index {
+                    #(#promote_arms)*
+                    _ => {}
+                }
+            }
+        }
+
+        impl Feature for vk::PhysicalDeviceFeatures {
+            fn get(features: &Features) -> &Self {
+                &features.structs.physical_device_features2.features
+            }
+
+            fn get_mut(features: &mut Features) -> &mut Self {
+                &mut features.structs.physical_device_features2.features
+            }
+        }
+
+        #(
+            impl Feature for vk::#types<'static> {
+                fn get(features: &Features) -> &Self {
+                    &features.structs.#fields
+                }
+
+                fn get_mut(features: &mut Features) -> &mut Self {
+                    &mut features.structs.#fields
+                }
+            }
+        )*
+    }
+}
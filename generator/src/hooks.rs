@@ -0,0 +1,133 @@
+//! Forwarding stubs for every command, backing the `before()` and `after()` hooks of
+//! `ash::hooks`.
+
+use super::{mock::Param, wrappers::TypeInfo, CommandExt, CommandMap, FunctionType};
+use heck::ToSnakeCase;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap};
+
+/// The type of a parameter with every elided lifetime made `'static`, for its `TypeId`.
+fn static_type(ty: &TokenStream) -> TokenStream {
+    ty.to_string()
+        .replace("'_", "'static")
+        .parse()
+        .expect("valid type tokens")
+}
+
+fn command(cmd: &vk_parse::CommandDefinition, index: usize, types: &TypeInfo<'_>) -> TokenStream {
+    let name = cmd.proto.name.as_str();
+    let fn_name = format_ident!("{}", name.strip_prefix("vk").unwrap().to_snake_case());
+    let pfn = format_ident!("PFN_{}", name);
+    let c_name = Literal::byte_string(format!("{name}\0").as_bytes());
+
+    let params = Param::of(cmd);
+    let mut uses = Default::default();
+    let idents = params.iter().map(|p| &p.ident).collect::<Vec<_>>();
+    let param_types = params
+        .iter()
+        .map(|p| types.param_tokens(&p.ty, 0, &mut uses))
+        .collect::<Vec<_>>();
+    let static_types = param_types.iter().map(static_type);
+    let ret = cmd.proto.type_name.as_deref().unwrap();
+    let returns = (ret != "void").then(|| types.tokens(ret, &mut uses));
+
+    // Commands other than the global ones are dispatched through their first parameter
+    let key = match cmd.function_type() {
+        FunctionType::Entry => quote!(0),
+        _ => {
+            let handle = idents[0];
+            quote!(dispatch_key(#handle.as_raw()))
+        }
+    };
+    let (result, call_result, returned, returns) = match returns {
+        Some(ret) => {
+            let static_ret = static_type(&ret);
+            (
+                quote!(let result =),
+                quote!(Some(Arg::new::<#static_ret>(<*const _>::cast(&result)))),
+                quote!(result),
+                quote!(-> #ret),
+            )
+        }
+        None => (quote!(), quote!(None), quote!(), quote!()),
+    };
+
+    quote! {
+        unsafe extern "system" fn #fn_name(#(#idents: #param_types),*) #returns {
+            let command = CStr::from_bytes_with_nul_unchecked(#c_name);
+            let next = mem::transmute::<*const c_void, vk::#pfn>(next(#index, #key));
+            let args = [#(Arg::new::<#static_types>(<*const _>::cast(&#idents))),*];
+            run_hook(&Call::new(command, &args, None), |hooks| &mut hooks.before);
+            #result next(#(#idents),*);
+            run_hook(&Call::new(command, &args, #call_result), |hooks| &mut hooks.after);
+            #returned
+        }
+    }
+}
+
+/// Generates `ash/src/hooks/generated.rs` for every command that `Entry`, `Instance`, `Device`
+/// and the extension wrappers load, except the `GetProcAddr` commands themselves.
+pub fn generate_hooks(
+    commands: &CommandMap<'_>,
+    cmd_aliases: &HashMap<&str, &str>,
+    types: &TypeInfo<'_>,
+) -> TokenStream {
+    let commands = commands
+        .values()
+        .filter(|cmd| !matches!(cmd.function_type(), FunctionType::Static))
+        .filter(|cmd| cmd.proto.name != "vkGetDeviceProcAddr")
+        .map(|cmd| (cmd.proto.name.as_str(), *cmd))
+        .collect::<BTreeMap<_, _>>();
+    let indices = commands
+        .keys()
+        .enumerate()
+        .map(|(i, &name)| (name, i))
+        .collect::<HashMap<_, _>>();
+    let stubs = commands
+        .values()
+        .map(|cmd| command(cmd, indices[cmd.proto.name.as_str()], types));
+
+    let arms = commands
+        .keys()
+        .map(|&name| (name, name))
+        .chain(
+            cmd_aliases
+                .iter()
+                .filter(|(_, name)| commands.contains_key(*name))
+                .map(|(&alias, &name)| (alias, name)),
+        )
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .map(|(name, target)| {
+            let literal = Literal::byte_string(name.as_bytes());
+            let index = indices[target];
+            let c_name = Literal::byte_string(format!("{target}\0").as_bytes());
+            let fn_name = format_ident!("{}", target.strip_prefix("vk").unwrap().to_snake_case());
+            quote! {
+                #literal => (
+                    #index,
+                    unsafe { CStr::from_bytes_with_nul_unchecked(#c_name) },
+                    #fn_name as *const c_void,
+                ),
+            }
+        });
+
+    quote! {
+        use super::*;
+        use crate::vk::Handle;
+        use std::os::raw::{c_char, c_int};
+
+        /// Returns the index of a command in the forwarding tables, the name it is hooked under
+        /// and its stub, which runs the hooks around calls to the function pointer it was loaded
+        /// as.
+        pub(super) fn stub(name: &[u8]) -> Option<(usize, &'static CStr, *const c_void)> {
+            Some(match name {
+                #(#arms)*
+                _ => return None,
+            })
+        }
+
+        #(#stubs)*
+    }
+}
@@ -48,6 +48,17 @@ macro_rules! get_variant {
     };
 }
 
+mod deep_copy;
+mod features;
+mod hooks;
+mod mock;
+mod owned;
+mod properties;
+mod serde;
+mod trace;
+mod track;
+mod wrappers;
+
 pub trait ExtensionExt {}
 #[derive(Copy, Clone, Debug)]
 pub enum CType {
@@ -1068,6 +1079,7 @@ fn generate_function_pointers<'a>(
                     }
                     let cname = ::std::ffi::CStr::from_bytes_with_nul_unchecked(#byte_function_name);
                     let val = _f(cname);
+                    report(cname, !val.is_null());
                     if val.is_null() {
                         #function_name_rust
                     } else {
@@ -1083,8 +1095,25 @@ fn generate_function_pointers<'a>(
     let loader = commands.is_empty().not().then(|| {
         quote! {
             impl #ident {
-                pub fn load<F>(mut _f: F) -> Self
+                pub fn load<F>(f: F) -> Self
+                    where F: FnMut(&::std::ffi::CStr) -> *const c_void
+                {
+                    Self::load_reporting(f, |_, _| ())
+                }
+
+                /// Loads the table like [`Self::load()`], reporting which commands were found.
+                pub fn load_with_report<F>(f: F) -> (Self, crate::vk::LoadReport)
                     where F: FnMut(&::std::ffi::CStr) -> *const c_void
+                {
+                    let mut report = crate::vk::LoadReport::default();
+                    let table = Self::load_reporting(f, |name, loaded| report.push(name, loaded));
+                    (table, report)
+                }
+
+                fn load_reporting<F, R>(mut _f: F, mut report: R) -> Self
+                    where
+                        F: FnMut(&::std::ffi::CStr) -> *const c_void,
+                        R: FnMut(&'static ::std::ffi::CStr, bool),
                 {
                     Self {
                         #(#loaders,)*
@@ -1189,6 +1218,7 @@ pub fn generate_extension_constants<'a>(
             } else {
                 continue;
             };
+            let is_bit = matches!(constant, Constant::BitPos(_));
             let ext_constant = ExtensionConstant {
                 name: &enum_.name,
                 constant,
@@ -1201,7 +1231,9 @@ pub fn generate_extension_constants<'a>(
                 .values
                 .push(ConstantMatchInfo {
                     ident: ext_constant.variant_ident(&extends),
+                    name: enum_.name.clone(),
                     is_alias,
+                    is_bit,
                 });
 
             extended_enums
@@ -1222,8 +1254,55 @@ pub fn generate_extension_constants<'a>(
     });
     quote!(#(#enum_tokens)*)
 }
+/// Generates a `vk::Depends` expression from the `depends` attribute of an extension, where `+`
+/// is AND, `,` is OR and parentheses group mixed expressions.
+fn generate_depends(depends: &mut &str) -> TokenStream {
+    let mut terms = Vec::new();
+    let mut separator = None;
+    loop {
+        let term = if let Some(rest) = depends.strip_prefix('(') {
+            *depends = rest;
+            let term = generate_depends(depends);
+            *depends = depends
+                .strip_prefix(')')
+                .expect("Unbalanced parentheses in `depends`");
+            term
+        } else {
+            let end = depends.find(['+', ',', ')']).unwrap_or(depends.len());
+            let (name, rest) = depends.split_at(end);
+            *depends = rest;
+            if let Some(version) = name.strip_prefix("VK_VERSION_") {
+                let version = format_ident!("API_VERSION_{}", version);
+                quote!(crate::vk::Depends::Version(crate::vk::#version))
+            } else {
+                let name = Literal::byte_string(format!("{name}\0").as_bytes());
+                quote!(crate::vk::Depends::Extension(unsafe {
+                    ::std::ffi::CStr::from_bytes_with_nul_unchecked(#name)
+                }))
+            }
+        };
+        terms.push(term);
+        match depends.chars().next() {
+            Some(c @ ('+' | ',')) => {
+                assert!(
+                    separator.replace(c).is_none_or(|s| s == c),
+                    "Mixed `+` and `,` without parentheses in `depends`"
+                );
+                *depends = &depends[1..];
+            }
+            _ => break,
+        }
+    }
+    match separator {
+        None => terms.pop().unwrap(),
+        Some('+') => quote!(crate::vk::Depends::All(&[#(#terms),*])),
+        Some(_) => quote!(crate::vk::Depends::Any(&[#(#terms),*])),
+    }
+}
+
 pub fn generate_extension_commands<'a>(
     extension_name: &str,
+    depends: Option<&str>,
     items: &'a [vk_parse::ExtensionChild],
     cmd_map: &CommandMap<'a>,
     cmd_aliases: &HashMap<&'a str, &'a str>,
@@ -1278,6 +1357,11 @@ pub fn generate_extension_commands<'a>(
             }
         });
 
+    let depends = match depends {
+        Some(mut depends) => generate_depends(&mut depends),
+        None => quote!(crate::vk::Depends::NONE),
+    };
+
     let byte_name_ident = Literal::byte_string(format!("{extension_name}\0").as_bytes());
     let extension_cstr = quote! {
         impl #ident {
@@ -1285,6 +1369,7 @@ pub fn generate_extension_commands<'a>(
                 ::std::ffi::CStr::from_bytes_with_nul_unchecked(#byte_name_ident)
             };
             #spec_version
+            pub const DEPENDS: crate::vk::Depends = #depends;
         }
     };
     quote! {
@@ -1309,6 +1394,7 @@ pub fn generate_extension<'a>(
     );
     let fp = generate_extension_commands(
         &extension.name,
+        extension.depends.as_deref(),
         &extension.children,
         cmd_map,
         cmd_aliases,
@@ -1570,7 +1656,9 @@ pub fn generate_enum<'a>(
         const_cache.insert(constant.name.as_str());
         values.push(ConstantMatchInfo {
             ident: constant.variant_ident(name),
+            name: constant.name.clone(),
             is_alias: constant.is_alias(),
+            is_bit: matches!(constant.spec, vk_parse::EnumSpec::Bitpos { .. }),
         });
     }
     const_values.insert(
@@ -2073,6 +2161,19 @@ fn derive_setters(
             .contains(&name_to_tokens(&field.basetype))
             .then(|| quote!(<'a>));
 
+        // Fixed-size strings such as `VkPhysicalDeviceProperties::deviceName` can be read back, unless
+        // they are not nul-terminated
+        let c_str_getter = (is_static_array(field) && field.basetype == "char").then(|| {
+            let getter = format_ident!("{}_as_c_str", param_ident_short);
+            quote! {
+                #[inline]
+                #deprecated
+                pub fn #getter(&self) -> Option<&::std::ffi::CStr> {
+                    wrap_c_str_slice_until_nul(&self.#param_ident)
+                }
+            }
+        });
+
         Some(quote!{
             #[inline]
             #deprecated
@@ -2080,6 +2181,7 @@ fn derive_setters(
                 self.#param_ident = #param_ident_short;
                 self
             }
+            #c_str_getter
         })
     });
 
@@ -2646,7 +2748,11 @@ pub fn generate_feature_extension<'a>(
 
 pub struct ConstantMatchInfo {
     pub ident: Ident,
+    /// The name of the constant in the specification, with its `VK_` prefix.
+    pub name: String,
     pub is_alias: bool,
+    /// Whether the constant is a single bit of a flags type.
+    pub is_bit: bool,
 }
 
 #[derive(Default)]
@@ -2658,68 +2764,117 @@ pub struct ConstantTypeInfo {
 pub struct ConstDebugs {
     core: TokenStream,
     extras: TokenStream,
+    /// The known values of enums, with `FromStr` and `TryFrom<&str>`.
+    enums: TokenStream,
+    /// The known bits of flags, with `FromStr` and `TryFrom<&str>`.
+    flags: TokenStream,
+}
+
+/// Generates `all()` for every flags type, from the bits defined by the core API and extensions.
+pub fn generate_bitflags_all(const_values: &BTreeMap<Ident, ConstantTypeInfo>) -> TokenStream {
+    let impls = const_values
+        .iter()
+        .filter(|(ty, _)| ty.to_string().contains("Flags"))
+        .map(|(ty, info)| {
+            let bits = info
+                .values
+                .iter()
+                .filter(|value| value.is_bit && !value.is_alias)
+                .map(|value| {
+                    let ident = &value.ident;
+                    quote!(Self::#ident.0)
+                });
+            let bits = quote!(#(#bits)|*);
+            let bits = if bits.is_empty() { quote!(0) } else { bits };
+            quote! {
+                impl #ty {
+                    #[doc = r" Every bit with a name in the Vulkan specification"]
+                    #[inline]
+                    pub const fn all() -> Self {
+                        Self(#bits)
+                    }
+                }
+            }
+        });
+    quote!(#(#impls)*)
 }
 
 pub fn generate_const_debugs(const_values: &BTreeMap<Ident, ConstantTypeInfo>) -> ConstDebugs {
     let mut core = Vec::new();
     let mut extras = Vec::new();
+    let mut enums = Vec::new();
+    let mut flags = Vec::new();
     for (ty, values) in const_values {
         let ConstantTypeInfo { values, bitwidth } = values;
-        let out = if ty.to_string().contains("Flags") {
-            let cases = values.iter().filter_map(|value| {
-                if value.is_alias {
-                    None
-                } else {
-                    let ident = &value.ident;
-                    let name = ident.to_string();
-                    Some(quote! { (#ty::#ident.0, #name) })
+        // Aliases come last, so that values are printed with their original name
+        let (originals, aliases): (Vec<_>, Vec<_>) =
+            values.iter().partition(|value| !value.is_alias);
+        let cases = originals.iter().chain(&aliases).map(|value| {
+            let ident = &value.ident;
+            let name = ident.to_string();
+            let spec_name = &value.name;
+            quote! { (Self::#ident.0, #name, #spec_name) }
+        });
+        let type_name = ty.to_string();
+        let try_from = quote! {
+            impl TryFrom<&str> for #ty {
+                type Error = ParseValueError;
+                fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+                    s.parse()
                 }
-            });
-
+            }
+        };
+        let debug = if type_name.contains("Flags") {
             let type_ = if bitwidth == &Some(64u32) {
                 quote!(Flags64)
             } else {
                 quote!(Flags)
             };
 
+            flags.push(quote! {
+                impl #ty {
+                    pub(crate) const KNOWN: Known<#type_> = &[#(#cases),*];
+                }
+                impl FromStr for #ty {
+                    type Err = ParseValueError;
+                    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                        parse_flags(#type_name, Self::KNOWN, s).map(Self)
+                    }
+                }
+                #try_from
+            });
             quote! {
                 impl fmt::Debug for #ty {
                     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                        const KNOWN: &[(#type_, &str)] = &[#(#cases),*];
-                        debug_flags(f, KNOWN, self.0)
+                        debug_flags(f, Self::KNOWN, self.0)
                     }
                 }
             }
         } else {
-            let cases = values.iter().filter_map(|value| {
-                if value.is_alias {
-                    None
-                } else {
-                    let ident = &value.ident;
-                    let name = ident.to_string();
-                    Some(quote! { Self::#ident => Some(#name), })
+            enums.push(quote! {
+                impl #ty {
+                    pub(crate) const KNOWN: Known<i32> = &[#(#cases),*];
+                }
+                impl FromStr for #ty {
+                    type Err = ParseValueError;
+                    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                        parse_enum(#type_name, Self::KNOWN, s).map(Self)
+                    }
                 }
+                #try_from
             });
             quote! {
                 impl fmt::Debug for #ty {
                     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                        let name = match *self {
-                            #(#cases)*
-                            _ => None,
-                        };
-                        if let Some(x) = name {
-                            f.write_str(x)
-                        } else {
-                            self.0.fmt(f)
-                        }
+                        debug_enum(f, Self::KNOWN, self.0)
                     }
                 }
             }
         };
         if ty == "Result" || ty == "ObjectType" {
-            core.push(out);
+            core.push(debug);
         } else {
-            extras.push(out);
+            extras.push(debug);
         }
     }
 
@@ -2730,6 +2885,12 @@ pub fn generate_const_debugs(const_values: &BTreeMap<Ident, ConstantTypeInfo>) -
         extras: quote! {
             #(#extras)*
         },
+        enums: quote! {
+            #(#enums)*
+        },
+        flags: quote! {
+            #(#flags)*
+        },
     }
 }
 pub fn extract_native_types(registry: &vk_parse::Registry) -> (Vec<(String, String)>, Vec<String>) {
@@ -3010,13 +3171,53 @@ pub fn write_source_code<P: AsRef<Path>>(vk_headers_dir: &Path, src_dir: P) {
 
     let root_structs = root_structs(&vk_parse_types, &required_types);
 
+    let wrapper_types = wrappers::TypeInfo {
+        s_type_structs: definitions
+            .iter()
+            .filter_map(get_variant!(vkxml::DefinitionsElement::Struct))
+            .filter(|s| {
+                s.elements
+                    .iter()
+                    .filter_map(get_variant!(vkxml::StructElement::Member))
+                    .any(|field| field.name.as_deref() == Some("sType"))
+            })
+            .map(|s| s.name.as_str())
+            .collect(),
+        has_lifetimes: &has_lifetimes,
+        aliases: vk_parse_types
+            .iter()
+            .filter_map(|ty| Some((ty.name.as_deref()?, ty.alias.as_deref()?)))
+            .collect(),
+        handles: definitions
+            .iter()
+            .filter_map(get_variant!(vkxml::DefinitionsElement::Handle))
+            .map(|h| h.name.as_str())
+            .collect(),
+    };
+    let wrapper_code =
+        wrappers::generate_extension_wrappers(&extensions, &commands, &cmd_aliases, &wrapper_types);
+    let (device_code, instance_code) =
+        wrappers::generate_core_wrappers(&features, &commands, &wrapper_types);
+
+    let tags = spec2
+        .0
+        .iter()
+        .filter_map(get_variant!(vk_parse::RegistryChild::Tags))
+        .flat_map(|tags| &tags.children)
+        .map(|tag| tag.name.as_str())
+        .collect::<HashSet<_>>();
+    let result_aliases = wrappers::result_aliases(&spec2);
+    let success_codes_code = wrappers::success_code_enums(&commands, &result_aliases, &tags);
+    let mock_code =
+        mock::generate_mock_commands(&commands, &cmd_aliases, &wrapper_types, &result_aliases);
+
     let vk_parse_types = vk_parse_types
         .into_iter()
         .filter_map(|t| t.name.clone().map(|n| (n, t)))
         .collect::<HashMap<_, _>>();
     let definition_code: Vec<_> = vk_parse_definitions
         .into_iter()
-        .chain(definitions.into_iter().filter_map(|def| {
+        .chain(definitions.iter().filter_map(|def| {
             generate_definition(
                 def,
                 &required_types,
@@ -3045,11 +3246,47 @@ pub fn write_source_code<P: AsRef<Path>>(vk_headers_dir: &Path, src_dir: P) {
     let feature_extensions_code =
         generate_feature_extension(&spec2, &mut const_cache, &mut const_values);
 
+    let bitflags_all = generate_bitflags_all(&const_values);
     let ConstDebugs {
         core: core_debugs,
         extras: const_debugs,
+        enums: known_enums,
+        flags: known_flags,
     } = generate_const_debugs(&const_values);
 
+    let deep_copy_code = deep_copy::generate_deep_copy(
+        &definitions,
+        &required_types,
+        &vk_parse_types,
+        &const_values,
+        &wrapper_types,
+    );
+    let serde_code = serde::generate_serde(
+        &definitions,
+        &required_types,
+        &vk_parse_types,
+        &const_values,
+        &wrapper_types,
+    );
+    let trace_code = trace::generate_trace(&commands, &cmd_aliases, &wrapper_types);
+    let track_code = track::generate_track(&commands, &cmd_aliases, &wrapper_types, &definitions);
+    let hooks_code = hooks::generate_hooks(&commands, &cmd_aliases, &wrapper_types);
+    let owned_code = owned::generate_owned(&features, &extensions, &commands, &cmd_aliases);
+    let features_code = features::generate_features(
+        &spec2,
+        &extensions,
+        &definitions,
+        &required_types,
+        &vk_parse_types,
+    );
+    let properties_code = properties::generate_properties(
+        &spec2,
+        &extensions,
+        &definitions,
+        &required_types,
+        &vk_parse_types,
+    );
+
     let src_dir = src_dir.as_ref();
 
     let vk_dir = src_dir.join("vk");
@@ -3068,6 +3305,67 @@ pub fn write_source_code<P: AsRef<Path>>(vk_headers_dir: &Path, src_dir: P) {
         File::create(vk_dir.join("const_debugs.rs")).expect("vk/const_debugs.rs");
     let vk_aliases_file = File::create(vk_dir.join("aliases.rs")).expect("vk/aliases.rs");
 
+    let extensions_dir = src_dir.join("extensions");
+    std::fs::create_dir_all(&extensions_dir).expect("failed to create extensions dir");
+    let extensions_generated_file =
+        File::create(extensions_dir.join("generated.rs")).expect("extensions/generated.rs");
+    let success_codes_file =
+        File::create(src_dir.join("success_codes.rs")).expect("success_codes.rs");
+
+    let device_dir = src_dir.join("device");
+    std::fs::create_dir_all(&device_dir).expect("failed to create device dir");
+    let device_generated_file =
+        File::create(device_dir.join("generated.rs")).expect("device/generated.rs");
+
+    let instance_dir = src_dir.join("instance");
+    std::fs::create_dir_all(&instance_dir).expect("failed to create instance dir");
+    let instance_generated_file =
+        File::create(instance_dir.join("generated.rs")).expect("instance/generated.rs");
+
+    let mock_dir = src_dir.join("mock");
+    std::fs::create_dir_all(&mock_dir).expect("failed to create mock dir");
+    let mock_commands_file = File::create(mock_dir.join("commands.rs")).expect("mock/commands.rs");
+
+    let deep_copy_dir = src_dir.join("deep_copy");
+    std::fs::create_dir_all(&deep_copy_dir).expect("failed to create deep_copy dir");
+    let deep_copy_generated_file =
+        File::create(deep_copy_dir.join("generated.rs")).expect("deep_copy/generated.rs");
+
+    let serde_dir = src_dir.join("serde");
+    std::fs::create_dir_all(&serde_dir).expect("failed to create serde dir");
+    let serde_generated_file =
+        File::create(serde_dir.join("generated.rs")).expect("serde/generated.rs");
+
+    let hooks_dir = src_dir.join("hooks");
+    std::fs::create_dir_all(&hooks_dir).expect("failed to create hooks dir");
+    let hooks_generated_file =
+        File::create(hooks_dir.join("generated.rs")).expect("hooks/generated.rs");
+
+    let features_dir = src_dir.join("features");
+    std::fs::create_dir_all(&features_dir).expect("failed to create features dir");
+    let features_generated_file =
+        File::create(features_dir.join("generated.rs")).expect("features/generated.rs");
+
+    let owned_dir = src_dir.join("owned");
+    std::fs::create_dir_all(&owned_dir).expect("failed to create owned dir");
+    let owned_generated_file =
+        File::create(owned_dir.join("generated.rs")).expect("owned/generated.rs");
+
+    let properties_dir = src_dir.join("properties");
+    std::fs::create_dir_all(&properties_dir).expect("failed to create properties dir");
+    let properties_generated_file =
+        File::create(properties_dir.join("generated.rs")).expect("properties/generated.rs");
+
+    let trace_dir = src_dir.join("trace");
+    std::fs::create_dir_all(&trace_dir).expect("failed to create trace dir");
+    let trace_generated_file =
+        File::create(trace_dir.join("generated.rs")).expect("trace/generated.rs");
+
+    let track_dir = src_dir.join("track");
+    std::fs::create_dir_all(&track_dir).expect("failed to create track dir");
+    let track_generated_file =
+        File::create(track_dir.join("generated.rs")).expect("track/generated.rs");
+
     let feature_code = quote! {
         use std::os::raw::*;
         use crate::vk::bitflags::*;
@@ -3080,7 +3378,7 @@ pub fn write_source_code<P: AsRef<Path>>(vk_headers_dir: &Path, src_dir: P) {
         use std::marker::PhantomData;
         use std::fmt;
         use std::os::raw::*;
-        use crate::vk::{Handle, ptr_chain_iter};
+        use crate::vk::{Handle, ptr_chain_iter, wrap_c_str_slice_until_nul};
         use crate::vk::aliases::*;
         use crate::vk::bitflags::*;
         use crate::vk::constants::*;
@@ -3093,13 +3391,22 @@ pub fn write_source_code<P: AsRef<Path>>(vk_headers_dir: &Path, src_dir: P) {
 
     let enum_code = quote! {
         use std::fmt;
+        use std::str::FromStr;
+        use crate::prelude::{debug_enum, parse_enum, Known};
+        use crate::vk::ParseValueError;
         #(#enum_code)*
+        #known_enums
         #core_debugs
     };
 
     let bitflags_code = quote! {
+        use std::str::FromStr;
+        use crate::prelude::{parse_flags, Known};
         use crate::vk::definitions::*;
+        use crate::vk::ParseValueError;
         #(#bitflags_code)*
+        #bitflags_all
+        #known_flags
     };
 
     let constants_code = quote! {
@@ -3128,7 +3435,7 @@ pub fn write_source_code<P: AsRef<Path>>(vk_headers_dir: &Path, src_dir: P) {
         use crate::vk::bitflags::*;
         use crate::vk::definitions::*;
         use crate::vk::enums::*;
-        use crate::prelude::debug_flags;
+        use crate::prelude::{debug_enum, debug_flags};
         #const_debugs
     };
 
@@ -3164,6 +3471,37 @@ pub fn write_source_code<P: AsRef<Path>>(vk_headers_dir: &Path, src_dir: P) {
         ),
         write_formatted(const_debugs.to_string().as_bytes(), vk_const_debugs_file),
         write_formatted(aliases.to_string().as_bytes(), vk_aliases_file),
+        write_formatted(
+            wrapper_code.to_string().as_bytes(),
+            extensions_generated_file,
+        ),
+        write_formatted(device_code.to_string().as_bytes(), device_generated_file),
+        write_formatted(
+            instance_code.to_string().as_bytes(),
+            instance_generated_file,
+        ),
+        write_formatted(
+            success_codes_code.to_string().as_bytes(),
+            success_codes_file,
+        ),
+        write_formatted(mock_code.to_string().as_bytes(), mock_commands_file),
+        write_formatted(
+            deep_copy_code.to_string().as_bytes(),
+            deep_copy_generated_file,
+        ),
+        write_formatted(serde_code.to_string().as_bytes(), serde_generated_file),
+        write_formatted(trace_code.to_string().as_bytes(), trace_generated_file),
+        write_formatted(track_code.to_string().as_bytes(), track_generated_file),
+        write_formatted(hooks_code.to_string().as_bytes(), hooks_generated_file),
+        write_formatted(owned_code.to_string().as_bytes(), owned_generated_file),
+        write_formatted(
+            features_code.to_string().as_bytes(),
+            features_generated_file,
+        ),
+        write_formatted(
+            properties_code.to_string().as_bytes(),
+            properties_generated_file,
+        ),
     ];
     for mut p in processes {
         let status = p.wait().unwrap();
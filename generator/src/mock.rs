@@ -0,0 +1,345 @@
+//! Stubs for every command, backing the `ash::mock` software driver: each stub records the call
+//! and forwards to a closure registered on the `MockDriver`, or falls back to a default that
+//! returns the first success code, allocates output handles and releases destroyed ones.
+
+use super::{
+    khronos_link, parse_c_parameter, variant_ident, wrappers::TypeInfo, CParameter, CReferenceType,
+    CommandMap, FieldExt, DESIRED_API,
+};
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap};
+use syn::Ident;
+
+/// A command parameter, named like in the function pointer types of `vk`.
+pub(super) struct Param<'a> {
+    pub param: &'a vk_parse::CommandParam,
+    pub ident: Ident,
+    pub ty: CParameter<'a>,
+}
+
+impl<'a> Param<'a> {
+    /// The parameters of `cmd` for the desired API.
+    pub fn of(cmd: &'a vk_parse::CommandDefinition) -> Vec<Self> {
+        cmd.params
+            .iter()
+            .filter(|param| matches!(param.api.as_deref(), None | Some(DESIRED_API)))
+            .map(|param| {
+                let (rem, ty) = parse_c_parameter(&param.definition.code).unwrap();
+                assert!(rem.is_empty());
+                Param {
+                    param,
+                    ident: param.param_ident(),
+                    ty,
+                }
+            })
+            .collect()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.param.definition.name
+    }
+
+    /// The first part of the `len` attribute, which describes the outermost pointer.
+    pub fn len(&self) -> Option<&str> {
+        self.param.len.as_deref().and_then(|l| l.split(',').next())
+    }
+
+    /// Whether this is a pointer to the element count of an array.
+    pub fn is_count(&self) -> bool {
+        matches!(self.ty.type_.reference_type, CReferenceType::Pointer)
+            && matches!(self.ty.type_.name, "uint32_t" | "size_t")
+    }
+}
+
+/// The element count of the array `param`, if it can be derived from the other parameters.
+pub(super) fn len_tokens(param: &Param<'_>, params: &[Param<'_>]) -> Option<TokenStream> {
+    let len = param.len()?;
+    if let Some((struct_param, member)) = len.split_once("->") {
+        let struct_param = &params.iter().find(|p| p.name() == struct_param)?.ident;
+        let member = format_ident!("{}", member.to_snake_case());
+        return Some(quote!((*#struct_param).#member as usize));
+    }
+    let count = params.iter().find(|p| p.name() == len)?;
+    let count_ident = &count.ident;
+    match count.ty.type_.reference_type {
+        CReferenceType::Value if count.ty.type_.name == "size_t" => Some(quote!(#count_ident)),
+        CReferenceType::Value => Some(quote!(#count_ident as usize)),
+        _ => None,
+    }
+}
+
+/// The fallback behaviour of a command without a registered handler.
+fn default_body(
+    cmd: &vk_parse::CommandDefinition,
+    params: &[Param<'_>],
+    types: &TypeInfo<'_>,
+    result_aliases: &HashMap<&str, &str>,
+) -> TokenStream {
+    let is_handle = |param: &Param<'_>| types.handles.contains(types.resolve(param.ty.type_.name));
+    let mut body = Vec::new();
+    let mut uses_driver = false;
+
+    let name = cmd.proto.name.as_str();
+    if name.starts_with("vkDestroy") || name.starts_with("vkFree") {
+        if let Some(param) = params.iter().rev().find(|p| is_handle(p)) {
+            uses_driver = true;
+            let ident = &param.ident;
+            match param.ty.type_.reference_type {
+                CReferenceType::Value => body.push(quote!(driver.release(#ident.as_raw());)),
+                CReferenceType::PointerToConst => {
+                    if let Some(len) = len_tokens(param, params) {
+                        body.push(quote! {
+                            if !#ident.is_null() {
+                                for i in 0..#len {
+                                    driver.release((*#ident.add(i)).as_raw());
+                                }
+                            }
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for param in params {
+        let ident = &param.ident;
+        match param.ty.type_.reference_type {
+            CReferenceType::Pointer if is_handle(param) => {
+                uses_driver = true;
+                let count = param
+                    .len()
+                    .and_then(|len| params.iter().find(|p| p.name() == len))
+                    .filter(|count| count.is_count());
+                if let Some(count) = count {
+                    // Enumerations report a single handle
+                    let count = &count.ident;
+                    body.push(quote! {
+                        if !#count.is_null() {
+                            if #ident.is_null() {
+                                *#count = 1;
+                            } else {
+                                let len = (*#count).min(1);
+                                for i in 0..len as usize {
+                                    *#ident.add(i) = driver.allocate();
+                                }
+                                *#count = len;
+                            }
+                        }
+                    });
+                } else if let Some(len) = len_tokens(param, params) {
+                    body.push(quote! {
+                        if !#ident.is_null() {
+                            for i in 0..#len {
+                                *#ident.add(i) = driver.allocate();
+                            }
+                        }
+                    });
+                } else if param.len().is_none() {
+                    body.push(quote! {
+                        if !#ident.is_null() {
+                            *#ident = driver.allocate();
+                        }
+                    });
+                }
+            }
+            CReferenceType::Pointer
+                if param.is_count()
+                    && params
+                        .iter()
+                        .any(|p| p.len() == Some(param.name()) && is_handle(p)) => {}
+            CReferenceType::Pointer
+                if param.len().is_none()
+                    && param.ty.static_array.is_none()
+                    && param.ty.type_.name != "void"
+                    && !types.has_s_type(param.ty.type_.name) =>
+            {
+                body.push(quote! {
+                    if !#ident.is_null() {
+                        ptr::write_bytes(#ident, 0, 1);
+                    }
+                });
+            }
+            CReferenceType::PointerToPointer if param.len().is_none() => {
+                body.push(quote! {
+                    if !#ident.is_null() {
+                        *#ident = ptr::null_mut();
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+
+    let ret = cmd.proto.type_name.as_deref().unwrap();
+    let returns = match ret {
+        "void" => quote!(),
+        "VkResult" => {
+            let code = cmd
+                .successcodes
+                .as_deref()
+                .and_then(|codes| codes.split(',').next())
+                .unwrap_or("VK_SUCCESS");
+            let code = variant_ident("VkResult", result_aliases.get(code).unwrap_or(&code));
+            quote!(vk::Result::#code)
+        }
+        _ => quote!(Default::default()),
+    };
+    let driver = if !uses_driver {
+        quote!(_)
+    } else {
+        quote!(driver)
+    };
+    quote! {
+        |#driver| {
+            #(#body)*
+            #returns
+        }
+    }
+}
+
+/// Generates the stub, handler alias and `MockDriver::on_*()` setter of a single command.
+fn command(
+    cmd: &vk_parse::CommandDefinition,
+    types: &TypeInfo<'_>,
+    result_aliases: &HashMap<&str, &str>,
+) -> TokenStream {
+    let name = cmd.proto.name.as_str();
+    let fn_name = format_ident!("{}", name.strip_prefix("vk").unwrap().to_snake_case());
+    let setter = format_ident!("on_{}", fn_name);
+    let handler = format_ident!("{}Fn", name.strip_prefix("vk").unwrap());
+
+    let params = Param::of(cmd);
+
+    let mut uses = Default::default();
+    let idents = params.iter().map(|p| &p.ident).collect::<Vec<_>>();
+    let param_types = params
+        .iter()
+        .map(|p| types.param_tokens(&p.ty, 0, &mut uses))
+        .collect::<Vec<_>>();
+    let ret = cmd.proto.type_name.as_deref().unwrap();
+    let returns = (ret != "void").then(|| {
+        let ret = types.tokens(ret, &mut uses);
+        quote!(-> #ret)
+    });
+
+    let handles = params
+        .iter()
+        .filter(|p| {
+            matches!(p.ty.type_.reference_type, CReferenceType::Value)
+                && types.handles.contains(types.resolve(p.ty.type_.name))
+        })
+        .map(|p| &p.ident);
+    let default = default_body(cmd, &params, types, result_aliases);
+    // Without a driver to call
+    let missing = if ret == "VkResult" {
+        quote!(unwrap_or(vk::Result::ERROR_INITIALIZATION_FAILED))
+    } else {
+        quote!(unwrap_or_default())
+    };
+
+    let doc = format!(
+        " Overrides [`{name}`]({}), which otherwise records the call and falls back to its default behaviour.",
+        khronos_link(name)
+            .to_string()
+            .trim_matches(|c| c == '"' || c == '<' || c == '>')
+    );
+
+    quote! {
+        type #handler = dyn FnMut(#(#param_types),*) #returns;
+
+        unsafe extern "system" fn #fn_name(#(#idents: #param_types),*) #returns {
+            dispatch::<#handler, _>(
+                #name,
+                &[#(#handles.as_raw()),*],
+                |f| f(#(#idents),*),
+                #default,
+            )
+            .#missing
+        }
+
+        impl MockDriver {
+            #[doc = #doc]
+            pub fn #setter(
+                &self,
+                f: impl FnMut(#(#param_types),*) #returns + 'static,
+            ) -> &Self {
+                self.set_handler::<#handler>(#name, Box::new(f))
+            }
+        }
+    }
+}
+
+/// Generates `ash/src/mock/commands.rs`.
+pub fn generate_mock_commands(
+    commands: &CommandMap<'_>,
+    cmd_aliases: &HashMap<&str, &str>,
+    types: &TypeInfo<'_>,
+    result_aliases: &HashMap<&str, &str>,
+) -> TokenStream {
+    // The loader entry points only resolve other commands
+    let is_proc_addr = |name: &str| matches!(name, "vkGetInstanceProcAddr" | "vkGetDeviceProcAddr");
+
+    let commands = commands
+        .values()
+        .map(|cmd| (cmd.proto.name.as_str(), *cmd))
+        .collect::<BTreeMap<_, _>>();
+    let stubs = commands
+        .values()
+        .filter(|cmd| !is_proc_addr(&cmd.proto.name))
+        .map(|cmd| command(cmd, types, result_aliases));
+
+    let lookup = commands
+        .keys()
+        .map(|&name| (name, name))
+        .chain(
+            cmd_aliases
+                .iter()
+                .filter(|(_alias, name)| commands.contains_key(*name))
+                .map(|(&alias, &name)| (alias, name)),
+        )
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .map(|(name, target)| {
+            let literal = proc_macro2::Literal::byte_string(name.as_bytes());
+            let fn_name = format_ident!("{}", target.strip_prefix("vk").unwrap().to_snake_case());
+            quote!(#literal => #fn_name as *const c_void,)
+        });
+
+    quote! {
+        use super::{dispatch, MockDriver};
+        use crate::vk::{self, Handle};
+        use std::ffi::CStr;
+        use std::os::raw::*;
+        use std::{mem, ptr};
+
+        /// Resolves every command known to `vk.xml` to its stub.
+        fn lookup(name: &[u8]) -> vk::PFN_vkVoidFunction {
+            let f = match name {
+                #(#lookup)*
+                _ => return None,
+            };
+            Some(unsafe { mem::transmute::<*const c_void, unsafe extern "system" fn()>(f) })
+        }
+
+        pub(super) unsafe extern "system" fn get_instance_proc_addr(
+            instance: vk::Instance,
+            p_name: *const c_char,
+        ) -> vk::PFN_vkVoidFunction {
+            MockDriver::of(instance.as_raw())?;
+            lookup(CStr::from_ptr(p_name).to_bytes())
+        }
+
+        unsafe extern "system" fn get_device_proc_addr(
+            device: vk::Device,
+            p_name: *const c_char,
+        ) -> vk::PFN_vkVoidFunction {
+            MockDriver::of(device.as_raw())?;
+            lookup(CStr::from_ptr(p_name).to_bytes())
+        }
+
+        #(#stubs)*
+    }
+}
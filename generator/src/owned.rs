@@ -0,0 +1,345 @@
+//! The commands that destroy or free a single handle, backing `ash::owned::Owned`.
+
+use super::{contains_desired_api, name_to_tokens, CommandMap, DESIRED_API};
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// The `Parent` that loads a command.
+enum Source {
+    /// A core command, loaded by the `fp_v1_*()` table of `Device` for `version`, such as
+    /// `fp_v1_1` and `API_VERSION_1_1`, which is `None` for Vulkan 1.0.
+    Core {
+        table: Ident,
+        version: Option<Ident>,
+    },
+    /// A command of the extension wrapper in the `Parent` variant `variant`.
+    Extension { variant: Ident },
+}
+
+/// A command that destroys or frees handles of one type.
+struct Destroy<'a> {
+    /// The handle type, such as `VkBuffer`.
+    handle: &'a str,
+    /// The pool that handles are freed to, such as `VkCommandPool`, or `None` for handles
+    /// destroyed through their device or instance.
+    pool: Option<&'a str>,
+    source: Source,
+    command: Ident,
+    returns: bool,
+}
+
+/// Matches `vkDestroy*(device, handle, pAllocator)` and `vkFree*(device, pool, count, pHandles)`,
+/// and for extensions `vkDestroy*(instance, handle, pAllocator)`. `name` is the name that the
+/// command is loaded under, which differs from that of `cmd` for aliases.
+fn destroy<'a>(
+    cmd: &'a vk_parse::CommandDefinition,
+    name: &str,
+    source: Source,
+) -> Option<Destroy<'a>> {
+    let name = name.strip_prefix("vk")?;
+    if !name.starts_with("Destroy") && !name.starts_with("Free") {
+        return None;
+    }
+    let params = cmd
+        .params
+        .iter()
+        .map(|param| {
+            (
+                param.definition.type_name.as_deref().unwrap_or_default(),
+                param.definition.name.as_str(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let is_core = matches!(source, Source::Core { .. });
+    let (handle, pool) = match params.as_slice() {
+        [("VkDevice", _), (handle, _), ("VkAllocationCallbacks", "pAllocator")] => (*handle, None),
+        [("VkInstance", _), (handle, _), ("VkAllocationCallbacks", "pAllocator")] if !is_core => {
+            (*handle, None)
+        }
+        [("VkDevice", _), (pool, _), ("uint32_t", _), (handle, _)] if is_core => {
+            (*handle, Some(*pool))
+        }
+        _ => return None,
+    };
+    Some(Destroy {
+        handle,
+        pool,
+        source,
+        command: format_ident!("{}", name.to_snake_case()),
+        returns: cmd.proto.type_name.as_deref() != Some("void"),
+    })
+}
+
+/// An extension wrapper that destroys handles.
+struct Wrapper {
+    variant: Ident,
+    /// Such as `khr` and `Surface`.
+    vendor: Ident,
+    name: Ident,
+    /// `device` or `instance`, the accessor of the dispatchable handle of the wrapper.
+    handle: Ident,
+}
+
+/// Generates `ash/src/owned/generated.rs` for the handles that are destroyed or freed through the
+/// core commands of `Device`, and through the extension wrappers.
+pub fn generate_owned(
+    features: &[&vkxml::Feature],
+    extensions: &[&vk_parse::Extension],
+    commands: &CommandMap<'_>,
+    cmd_aliases: &HashMap<&str, &str>,
+) -> TokenStream {
+    let mut destroys = features
+        .iter()
+        .filter(|feature| contains_desired_api(&feature.api))
+        .flat_map(|feature| {
+            let version = feature
+                .name
+                .strip_prefix("VK_VERSION_")
+                .expect("core version");
+            let table = format_ident!("fp_v{}", version);
+            let version = (version != "1_0").then(|| format_ident!("API_VERSION_{}", version));
+            feature
+                .elements
+                .iter()
+                .filter_map(get_variant!(vkxml::FeatureElement::Require))
+                .flat_map(|spec| &spec.elements)
+                .filter_map(get_variant!(vkxml::FeatureReference::CommandReference))
+                .filter_map(|cmd_ref| commands.get(&cmd_ref.name).copied())
+                .filter_map(move |cmd| {
+                    let source = Source::Core {
+                        table: table.clone(),
+                        version: version.clone(),
+                    };
+                    destroy(cmd, &cmd.proto.name, source)
+                })
+        })
+        .collect::<Vec<_>>();
+
+    // Ordered by variant, like the wrappers are by vendor and name
+    let mut wrappers = BTreeMap::new();
+    for extension in extensions {
+        if !extension
+            .supported
+            .as_deref()
+            .is_none_or(contains_desired_api)
+        {
+            continue;
+        }
+        let variant = format_ident!(
+            "{}",
+            extension
+                .name
+                .to_upper_camel_case()
+                .strip_prefix("Vk")
+                .unwrap()
+        );
+        let mut seen = HashSet::new();
+        let extension_destroys = extension
+            .children
+            .iter()
+            .filter_map(get_variant!(vk_parse::ExtensionChild::Require {
+                api,
+                items
+            }))
+            .filter(|(api, _items)| matches!(api.as_deref(), None | Some(DESIRED_API)))
+            .flat_map(|(_api, items)| items)
+            .filter_map(get_variant!(vk_parse::InterfaceItem::Command { name }))
+            .filter(|name| seen.insert(name.as_str()))
+            .filter_map(|name| {
+                let cmd = cmd_aliases.get(name.as_str()).map_or(name.as_str(), |c| c);
+                let source = Source::Extension {
+                    variant: variant.clone(),
+                };
+                destroy(commands[cmd], name, source)
+            })
+            .collect::<Vec<_>>();
+        if extension_destroys.is_empty() {
+            continue;
+        }
+
+        let (vendor, name) = extension
+            .name
+            .strip_prefix("VK_")
+            .and_then(|name| name.split_once('_'))
+            .unwrap();
+        let vendor = format_ident!("{}", vendor.to_lowercase());
+        let name = format_ident!("{}", name.to_upper_camel_case());
+        let is_device = extension.ext_type.as_deref() == Some("device");
+        wrappers.insert(
+            variant.to_string(),
+            Wrapper {
+                variant,
+                vendor,
+                name,
+                handle: format_ident!("{}", if is_device { "device" } else { "instance" }),
+            },
+        );
+        destroys.extend(extension_destroys);
+    }
+    let vendors = wrappers
+        .values()
+        .map(|wrapper| wrapper.vendor.to_string())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|vendor| format_ident!("{}", vendor));
+
+    let object_type = |name: &str| {
+        let name = name.strip_prefix("Vk").unwrap().to_shouty_snake_case();
+        let ident = format_ident!("{}", name);
+        quote!(vk::ObjectType::#ident)
+    };
+
+    let mut always = Vec::new();
+    let mut versioned = Vec::new();
+    for d in &destroys {
+        let ty = object_type(d.handle);
+        match (&d.source, d.pool) {
+            (Source::Core { .. }, Some(pool)) => {
+                let variant = name_to_tokens(pool);
+                always.push(quote!((Parent::#variant(..), #ty)));
+            }
+            (Source::Core { version: None, .. }, None) => {
+                always.push(quote!((Parent::Device(_), #ty)));
+            }
+            (
+                Source::Core {
+                    version: Some(version),
+                    ..
+                },
+                None,
+            ) => versioned.push(quote! {
+                (Parent::Device(device), #ty) => device.api_version() >= vk::#version,
+            }),
+            (Source::Extension { variant }, _) => {
+                always.push(quote!((Parent::#variant(_), #ty)));
+            }
+        }
+    }
+
+    let destroy_arms = destroys.iter().map(|d| {
+        let ty = object_type(d.handle);
+        let handle = name_to_tokens(d.handle);
+        let command = &d.command;
+        let (parent, call) = match (&d.source, d.pool) {
+            (Source::Core { table, .. }, Some(pool)) => {
+                let variant = name_to_tokens(pool);
+                (
+                    quote!(Parent::#variant(device, pool)),
+                    quote! {
+                        (device.#table().#command)(
+                            device.handle(), *pool, 1, &vk::#handle::from_raw(raw)
+                        )
+                    },
+                )
+            }
+            (Source::Core { table, .. }, None) => (
+                quote!(Parent::Device(device)),
+                quote! {
+                    (device.#table().#command)(
+                        device.handle(), vk::#handle::from_raw(raw), ptr::null()
+                    )
+                },
+            ),
+            (Source::Extension { variant }, _) => {
+                let accessor = &wrappers[&variant.to_string()].handle;
+                (
+                    quote!(Parent::#variant(wrapper)),
+                    quote! {
+                        (wrapper.fp().#command)(
+                            wrapper.#accessor(), vk::#handle::from_raw(raw), ptr::null()
+                        )
+                    },
+                )
+            }
+        };
+        let call = if d.returns {
+            quote!(let _ = #call;)
+        } else {
+            quote!(#call;)
+        };
+        quote!((#parent, #ty) => { #call })
+    });
+
+    let variants = wrappers.values().map(|wrapper| {
+        let Wrapper {
+            variant,
+            vendor,
+            name,
+            ..
+        } = wrapper;
+        let doc = format!(" Destroys handles with [`{vendor}::{name}`].");
+        quote! {
+            #[doc = #doc]
+            #variant(Arc<#vendor::#name>),
+        }
+    });
+    let debug_arms = wrappers.values().map(|wrapper| {
+        let Wrapper {
+            variant, handle, ..
+        } = wrapper;
+        let name = variant.to_string();
+        quote! {
+            Self::#variant(wrapper) => f.debug_tuple(#name).field(&wrapper.#handle()).finish(),
+        }
+    });
+
+    quote! {
+        use super::*;
+        use crate::extensions::{#(#vendors),*};
+
+        /// What destroys or frees an [`Owned`] handle.
+        #[derive(Clone)]
+        pub enum Parent {
+            /// Destroys handles with `vkDestroy*()` or `vkFreeMemory()`. The handles of
+            /// extensions that were promoted to core, such as [`vk::SamplerYcbcrConversion`], are
+            /// only destroyed if [`Device::api_version()`] includes their core version, and
+            /// otherwise by the parent of their extension, such as
+            /// [`Parent::KhrSamplerYcbcrConversion`].
+            Device(Arc<Device>),
+            /// Frees command buffers allocated from a pool.
+            CommandPool(Arc<Device>, vk::CommandPool),
+            /// Frees descriptor sets allocated from a pool created with
+            /// [`vk::DescriptorPoolCreateFlags::FREE_DESCRIPTOR_SET`].
+            DescriptorPool(Arc<Device>, vk::DescriptorPool),
+            #(#variants)*
+        }
+
+        impl fmt::Debug for Parent {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    Self::Device(device) => f.debug_tuple("Device").field(&device.handle()).finish(),
+                    Self::CommandPool(device, pool) => f
+                        .debug_tuple("CommandPool")
+                        .field(&device.handle())
+                        .field(pool)
+                        .finish(),
+                    Self::DescriptorPool(device, pool) => f
+                        .debug_tuple("DescriptorPool")
+                        .field(&device.handle())
+                        .field(pool)
+                        .finish(),
+                    #(#debug_arms)*
+                }
+            }
+        }
+
+        /// Whether `parent` destroys or frees handles of type `ty`.
+        pub(super) fn destroys(parent: &Parent, ty: vk::ObjectType) -> bool {
+            match (parent, ty) {
+                #(#always)|* => true,
+                #(#versioned)*
+                _ => false,
+            }
+        }
+
+        /// Destroys or frees the handle `raw` of type `ty` through `parent`.
+        pub(super) unsafe fn destroy(parent: &Parent, ty: vk::ObjectType, raw: u64) {
+            match (parent, ty) {
+                #(#destroy_arms)*
+                _ => unreachable!("{:?} does not destroy {:?}", parent, ty),
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! The structs extending `VkPhysicalDeviceProperties2`, backing `ash::properties::Properties`.
+
+use super::features::structs_extending;
+use super::{generate_depends, name_to_tokens, FieldExt};
+use heck::ToSnakeCase;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::{HashMap, HashSet};
+
+/// A member that is not a struct, with its path from the struct of properties.
+struct Leaf {
+    index: usize,
+    name: String,
+    path: TokenStream,
+    is_string: bool,
+}
+
+/// Collects the members of `struct_`, and of the structs nested in it.
+fn leaves(
+    index: usize,
+    struct_: &vkxml::Struct,
+    name: &str,
+    path: &TokenStream,
+    nested: &HashMap<&str, &vkxml::Struct>,
+    leaves_out: &mut Vec<Leaf>,
+) {
+    let members = struct_
+        .elements
+        .iter()
+        .filter_map(get_variant!(vkxml::StructElement::Member))
+        .filter(|field| !matches!(field.name.as_deref(), Some("sType" | "pNext")));
+    for field in members {
+        let member = field.name.as_deref().expect("member name");
+        let ident = field.param_ident();
+        let path = quote!(#path.#ident);
+        match nested.get(field.basetype.as_str()) {
+            Some(inner) if field.array.is_none() => leaves(
+                index,
+                inner,
+                &format!("{name}{member}."),
+                &path,
+                nested,
+                leaves_out,
+            ),
+            _ => leaves_out.push(Leaf {
+                index,
+                name: format!("{name}{member}"),
+                path,
+                is_string: field.basetype == "char",
+            }),
+        }
+    }
+}
+
+/// Generates `ash/src/properties/generated.rs` for the structs that extend
+/// `VkPhysicalDeviceProperties2`, with `VkPhysicalDeviceProperties2` itself at index 0.
+pub fn generate_properties(
+    spec: &vk_parse::Registry,
+    extensions: &[&vk_parse::Extension],
+    definitions: &[&vkxml::DefinitionsElement],
+    required_types: &HashSet<&str>,
+    vk_parse_types: &HashMap<String, &vk_parse::Type>,
+) -> TokenStream {
+    let nested = definitions
+        .iter()
+        .filter_map(get_variant!(vkxml::DefinitionsElement::Struct))
+        .map(|s| (s.name.as_str(), s))
+        .collect::<HashMap<_, _>>();
+    let structs = structs_extending(
+        "VkPhysicalDeviceProperties2",
+        spec,
+        extensions,
+        definitions,
+        required_types,
+        vk_parse_types,
+    );
+    let count = structs.len() + 1;
+
+    let fields = structs
+        .iter()
+        .map(|(s, _)| format_ident!("{}", s.name.strip_prefix("Vk").unwrap().to_snake_case()))
+        .collect::<Vec<_>>();
+    let types = structs
+        .iter()
+        .map(|(s, _)| name_to_tokens(&s.name))
+        .collect::<Vec<_>>();
+    let indices = (1..count).collect::<Vec<_>>();
+    let depends = structs
+        .iter()
+        .map(|(_, providers)| generate_depends(&mut providers.join(",").as_str()));
+
+    let mut leaves_out = Vec::new();
+    leaves(
+        0,
+        nested["VkPhysicalDeviceProperties"],
+        "VkPhysicalDeviceProperties::",
+        &quote!(physical_device_properties2.properties),
+        &nested,
+        &mut leaves_out,
+    );
+    for (index, ((s, _), field)) in structs.iter().zip(&fields).enumerate() {
+        leaves(
+            index + 1,
+            s,
+            &format!("{}::", s.name),
+            &quote!(#field),
+            &nested,
+            &mut leaves_out,
+        );
+    }
+    let zip = leaves_out.iter().map(|leaf| {
+        let Leaf {
+            index,
+            name,
+            path,
+            is_string,
+        } = leaf;
+        let name = Literal::string(name);
+        if *is_string {
+            quote! {
+                f(
+                    #index,
+                    #name,
+                    self.#path == other.#path,
+                    &CStrArray(&self.#path),
+                    &CStrArray(&other.#path),
+                );
+            }
+        } else {
+            quote!(f(#index, #name, self.#path == other.#path, &self.#path, &other.#path);)
+        }
+    });
+
+    quote! {
+        use super::*;
+
+        pub(super) const COUNT: usize = #count;
+
+        #[derive(Clone, Default)]
+        pub(super) struct Structs {
+            pub(super) physical_device_properties2: vk::PhysicalDeviceProperties2<'static>,
+            #(pub(super) #fields: vk::#types<'static>,)*
+        }
+
+        /// The versions and extensions that provide each struct.
+        pub(super) const DEPENDS: [vk::Depends; COUNT] = [vk::Depends::NONE, #(#depends),*];
+
+        impl Structs {
+            pub(super) fn bases(&mut self) -> [*mut vk::BaseOutStructure<'static>; COUNT] {
+                [
+                    <*mut _>::cast(&mut self.physical_device_properties2),
+                    #(<*mut _>::cast(&mut self.#fields),)*
+                ]
+            }
+
+            /// Copies the struct of properties at `ptr`, returning its index.
+            #[cfg(feature = "serde")]
+            pub(super) unsafe fn read(&mut self, ptr: *const vk::BaseOutStructure<'_>) -> Option<usize> {
+                use crate::vk::TaggedStructure;
+                match (*ptr).s_type {
+                    #(vk::#types::STRUCTURE_TYPE => {
+                        self.#fields = ptr.cast::<vk::#types<'static>>().read();
+                        self.#fields.p_next = ptr::null_mut();
+                        Some(#indices)
+                    })*
+                    _ => None,
+                }
+            }
+
+            /// Calls `f` with the index of the struct, the name of every property, whether it
+            /// is equal in `self` and `other`, and its values.
+            pub(super) fn zip(
+                &self,
+                other: &Self,
+                mut f: impl FnMut(usize, &'static str, bool, &dyn fmt::Debug, &dyn fmt::Debug),
+            ) {
+                #(#zip)*
+            }
+        }
+
+        impl Property for vk::PhysicalDeviceProperties {
+            fn get(properties: &Properties) -> Option<&Self> {
+                Some(&properties.structs.physical_device_properties2.properties)
+            }
+        }
+
+        #(
+            impl Property for vk::#types<'static> {
+                fn get(properties: &Properties) -> Option<&Self> {
+                    properties.queried[#indices].then(|| &properties.structs.#fields)
+                }
+            }
+        )*
+    }
+}
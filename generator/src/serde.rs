@@ -0,0 +1,361 @@
+//! `Serialize` and `Deserialize` implementations for the types of `vk`, backing the `serde` feature
+//! of `ash`.
+
+use super::{
+    deep_copy::{member_len, members, Element, BYTES_STRUCTS},
+    name_to_tokens,
+    wrappers::TypeInfo,
+    ConstantTypeInfo, FieldExt,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use syn::Ident;
+
+/// Platform types defined as pointers in `ash/src/vk/platform_types.rs`, whose value is
+/// meaningless outside of the process.
+const POINTER_TYPES: &[&str] = &[
+    "MirConnection",
+    "MirSurface",
+    "HINSTANCE",
+    "HWND",
+    "HANDLE",
+    "HMONITOR",
+    "LPCWSTR",
+    "IOSurfaceRef",
+    "MTLBuffer_id",
+    "MTLCommandQueue_id",
+    "MTLDevice_id",
+    "MTLSharedEvent_id",
+    "MTLTexture_id",
+];
+
+/// How a struct member is serialized.
+enum Member {
+    /// Implied by the struct, and not serialized.
+    SType,
+    /// Through its `Field` implementation.
+    Value,
+    /// A fixed-size `char` array, as a string.
+    Chars,
+    /// A pointer to the given number of elements.
+    Array(TokenStream),
+    /// A pointer to a single element.
+    Pointer,
+    /// A null-terminated string.
+    Str,
+    /// A pointer to the given number of null-terminated strings.
+    Strs(TokenStream),
+    /// A `void` pointer to the given number of bytes.
+    Bytes(TokenStream),
+    /// The `p_next` chain.
+    Chain,
+}
+
+/// How a struct member is serialized, or `None` if it holds data that can't be serialized, such
+/// as function pointers, host pointers of unknown size and video std structs.
+fn member(
+    field: &vkxml::Field,
+    vk_parse_field: &vk_parse::TypeMemberDefinition,
+    struct_: &vkxml::Struct,
+    types: &TypeInfo<'_>,
+) -> Option<Member> {
+    let ident = field.param_ident();
+    let element = Element::of(&field.basetype, types);
+    if field.basetype.starts_with("PFN_")
+        || element == Element::Native
+        || POINTER_TYPES.contains(&types.resolve(&field.basetype))
+    {
+        return None;
+    }
+    let len = member_len(vk_parse_field, struct_);
+    Some(match field.reference {
+        _ if ident == "s_type" => Member::SType,
+        _ if ident == "p_next" => Member::Chain,
+        None if field.array.is_some() && element == Element::Str => Member::Chars,
+        None => Member::Value,
+        Some(vkxml::ReferenceType::Pointer) => match (element, len) {
+            (Element::Str, _) => Member::Str,
+            (Element::Opaque, Some(len)) => Member::Bytes(len),
+            (Element::Opaque, None) => return None,
+            (_, Some(len)) => Member::Array(len),
+            (_, None) => Member::Pointer,
+        },
+        Some(_) => {
+            let inner = vk_parse_field
+                .len
+                .as_deref()
+                .and_then(|len| len.split(',').nth(1));
+            match (len, inner) {
+                (Some(len), Some("null-terminated")) => Member::Strs(len),
+                _ => return None,
+            }
+        }
+    })
+}
+
+/// Generates the `Field` and `Struct` implementations of a struct.
+fn serde_struct(
+    struct_: &vkxml::Struct,
+    members: &[(Ident, Member)],
+    types: &TypeInfo<'_>,
+) -> TokenStream {
+    let name = name_to_tokens(&struct_.name);
+    let name_str = name.to_string();
+    let lifetime = types.has_lifetimes.contains(&name).then(|| quote!(<'_>));
+    let members = members
+        .iter()
+        .filter(|(_, member)| !matches!(member, Member::SType))
+        .collect::<Vec<_>>();
+    let field_names = members
+        .iter()
+        .map(|(ident, _)| ident.to_string())
+        .collect::<Vec<_>>();
+    let field_count = members.len();
+
+    let serialize_fields = members
+        .iter()
+        .zip(&field_names)
+        .map(|((ident, member), field_name)| {
+            let value = match member {
+                Member::SType => unreachable!(),
+                Member::Value => quote!(Member(&self.#ident)),
+                Member::Chars => quote!(Chars(&self.#ident)),
+                Member::Array(len) => quote!(Array(self.#ident, #len)),
+                Member::Pointer => quote!(Pointer(self.#ident)),
+                Member::Str => quote!(Str(self.#ident)),
+                Member::Strs(len) => quote!(Strs(self.#ident, #len)),
+                Member::Bytes(len) => quote!(Bytes(self.#ident.cast(), #len)),
+                Member::Chain => quote!(Chain(self.#ident)),
+            };
+            quote!(state.serialize_field(#field_name, &#value)?;)
+        });
+    let deserialize_fields =
+        members
+            .iter()
+            .zip(&field_names)
+            .map(|((ident, member), field_name)| {
+                let value = match member {
+                    Member::SType => unreachable!(),
+                    Member::Value => quote!(map.next_value_seed(FieldSeed::new(arena))?),
+                    Member::Chars => quote!(map.next_value_seed(CharsSeed)?),
+                    Member::Array(_) => {
+                        quote!(map.next_value_seed(Nullable(ArraySeed::new(arena)))?)
+                    }
+                    Member::Pointer => {
+                        quote!(map.next_value_seed(Nullable(PointerSeed::new(arena)))?)
+                    }
+                    Member::Str => quote!(map.next_value_seed(Nullable(StrSeed(arena)))?),
+                    Member::Strs(_) => quote!(map.next_value_seed(Nullable(StrsSeed(arena)))?),
+                    Member::Bytes(_) => {
+                        quote!(map.next_value_seed(Nullable(BytesSeed(arena)))?.cast())
+                    }
+                    Member::Chain => quote!(map.next_value_seed(Nullable(ChainSeed(arena)))?),
+                };
+                quote!(#field_name => self.#ident = #value,)
+            });
+    let length_checks = members
+        .iter()
+        .zip(&field_names)
+        .filter_map(|((ident, member), field_name)| {
+            let (ptr, len) = match member {
+                Member::Array(len) | Member::Strs(len) => (quote!(self.#ident), len),
+                Member::Bytes(len) => (quote!(self.#ident.cast::<u8>()), len),
+                _ => return None,
+            };
+            Some(quote! {
+                if !#ptr.is_null() && !arena.holds(#ptr, #len) {
+                    return Some(#field_name);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    let arena = if length_checks.is_empty() {
+        quote!(_arena)
+    } else {
+        quote!(arena)
+    };
+
+    quote! {
+        unsafe impl Field for vk::#name #lifetime {
+            unsafe fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut state = serializer.serialize_struct(#name_str, #field_count)?;
+                #(#serialize_fields)*
+                state.end()
+            }
+
+            fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+                arena: &mut Arena,
+            ) -> Result<Self, D::Error> {
+                deserialize_struct(deserializer, arena)
+            }
+        }
+
+        impl Struct for vk::#name #lifetime {
+            const NAME: &'static str = #name_str;
+            const FIELDS: &'static [&'static str] = &[#(#field_names),*];
+
+            fn deserialize_field<'de, A: MapAccess<'de>>(
+                &mut self,
+                field: &str,
+                map: &mut A,
+                arena: &mut Arena,
+            ) -> Result<(), A::Error> {
+                match field {
+                    #(#deserialize_fields)*
+                    _ => return Err(de::Error::unknown_field(field, Self::FIELDS)),
+                }
+                Ok(())
+            }
+
+            fn short_array(&self, #arena: &Arena) -> Option<&'static str> {
+                #(#length_checks)*
+                None
+            }
+        }
+    }
+}
+
+/// Generates `ash/src/serde/generated.rs`.
+pub fn generate_serde(
+    definitions: &[&vkxml::DefinitionsElement],
+    required_types: &HashSet<&str>,
+    vk_parse_types: &HashMap<String, &vk_parse::Type>,
+    const_values: &BTreeMap<Ident, ConstantTypeInfo>,
+    types: &TypeInfo<'_>,
+) -> TokenStream {
+    let structs = definitions
+        .iter()
+        .filter_map(get_variant!(vkxml::DefinitionsElement::Struct))
+        .filter(|s| required_types.contains(s.name.as_str()))
+        .collect::<Vec<_>>();
+    let struct_names = structs
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect::<HashSet<_>>();
+
+    // Structs whose members can all be serialized, with the structs they refer to
+    let mut supported = HashMap::new();
+    for s in &structs {
+        if BYTES_STRUCTS.contains(&s.name.as_str()) {
+            continue;
+        }
+        let mut serde_members = Vec::new();
+        let mut dependencies = Vec::new();
+        for (field, vk_parse_field) in members(s, vk_parse_types) {
+            let Some(member) = member(field, vk_parse_field, s, types) else {
+                break;
+            };
+            let basetype = types.resolve(&field.basetype);
+            if struct_names.contains(basetype) && !BYTES_STRUCTS.contains(&basetype) {
+                dependencies.push(basetype);
+            }
+            serde_members.push((field.param_ident(), member));
+        }
+        if serde_members.len() == members(s, vk_parse_types).count() {
+            supported.insert(s.name.as_str(), (serde_members, dependencies));
+        }
+    }
+    loop {
+        let unsupported = supported
+            .iter()
+            .filter(|(_, (_, dependencies))| {
+                dependencies
+                    .iter()
+                    .any(|dependency| !supported.contains_key(dependency))
+            })
+            .map(|(&name, _)| name)
+            .collect::<Vec<_>>();
+        if unsupported.is_empty() {
+            break;
+        }
+        for name in unsupported {
+            supported.remove(name);
+        }
+    }
+
+    let structs = structs
+        .into_iter()
+        .filter_map(|s| Some((s, &supported.get(s.name.as_str())?.0)))
+        .collect::<Vec<_>>();
+    let struct_impls = structs
+        .iter()
+        .map(|(s, members)| serde_struct(s, members, types));
+    let plain_structs = structs
+        .iter()
+        .map(|(s, _)| name_to_tokens(&s.name))
+        .filter(|name| !types.has_lifetimes.contains(name));
+    let extensions = structs
+        .iter()
+        .filter(|(_, members)| {
+            members
+                .iter()
+                .any(|(_, member)| matches!(member, Member::SType))
+        })
+        .map(|(s, _)| name_to_tokens(&s.name))
+        .collect::<Vec<_>>();
+
+    let bytes_types = definitions
+        .iter()
+        .filter_map(get_variant!(vkxml::DefinitionsElement::Union))
+        .filter(|u| required_types.contains(u.name.as_str()))
+        .map(|u| u.name.as_str())
+        .chain(BYTES_STRUCTS.iter().copied())
+        .map(name_to_tokens)
+        // Generated by hand along with VkAccelerationStructureInstanceKHR
+        .chain([format_ident!("AccelerationStructureReferenceKHR")])
+        .map(|name| {
+            let lifetime = types.has_lifetimes.contains(&name).then(|| quote!(<'_>));
+            quote!(vk::#name #lifetime)
+        });
+
+    let (flags, enums): (Vec<_>, Vec<_>) = const_values
+        .keys()
+        .partition(|name| name.to_string().contains("Flags"));
+
+    quote! {
+        use super::*;
+        use crate::vk::TaggedStructure;
+
+        serde_enums!(#(#enums),*);
+
+        serde_flags!(#(#flags),*);
+
+        serde_bytes!(#(#bytes_types),*);
+
+        serde_structs!(#(#plain_structs),*);
+
+        #(#struct_impls)*
+
+        /// Serializes an extension struct of a `p_next` chain, if its type is known.
+        pub(super) unsafe fn serialize_extension<S: Serializer>(
+            ptr: *const vk::BaseInStructure<'_>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match (*ptr).s_type {
+                #(vk::#extensions::STRUCTURE_TYPE => {
+                    serialize_extension_as(&*ptr.cast::<vk::#extensions>(), (*ptr).s_type, serializer)
+                })*
+                s_type => Err(unknown_extension(s_type)),
+            }
+        }
+
+        /// Deserializes the members of an extension struct of type `s_type` into `arena`.
+        pub(super) fn deserialize_extension<'de, A: MapAccess<'de>>(
+            s_type: vk::StructureType,
+            map: &mut A,
+            arena: &mut Arena,
+        ) -> Result<*mut vk::BaseOutStructure<'static>, A::Error> {
+            match s_type {
+                #(vk::#extensions::STRUCTURE_TYPE => {
+                    deserialize_extension_as::<vk::#extensions, A>(map, arena)
+                })*
+                _ => Err(de::Error::custom(format_args!(
+                    "Unknown extension struct of type {}",
+                    s_type.as_raw()
+                ))),
+            }
+        }
+    }
+}
@@ -0,0 +1,294 @@
+//! Recording stubs and replay functions for every device-level command, backing the
+//! `ash::trace` call recorder and replayer.
+
+use super::{
+    deep_copy::Element, mock::len_tokens, mock::Param, wrappers::TypeInfo, CReferenceType,
+    CommandExt, CommandMap, FunctionType,
+};
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap};
+
+/// The element count of a command parameter that is a pointer: `Some(1)` for single elements,
+/// and `None` if it cannot be derived from the other parameters.
+fn param_len(param: &Param<'_>, params: &[Param<'_>]) -> Option<TokenStream> {
+    let Some(len) = param.len() else {
+        return Some(quote!(1));
+    };
+    if len.starts_with("latexmath") {
+        return match param.param.altlen.as_deref() {
+            Some("(samples + 31) / 32") => Some(quote!((samples.as_raw() as usize + 31) / 32)),
+            _ => None,
+        };
+    }
+    if let Some(len) = len_tokens(param, params) {
+        return Some(len);
+    }
+    // Enumerations read the capacity before the call and the element count after it
+    let count = params.iter().find(|p| p.name() == len && p.is_count())?;
+    let ident = &count.ident;
+    let value = if count.ty.type_.name == "size_t" {
+        quote!(*#ident)
+    } else {
+        quote!(*#ident as usize)
+    };
+    Some(quote!(if #ident.is_null() { 0 } else { #value }))
+}
+
+/// How a command parameter is recorded before the call, recorded after the call if the driver
+/// writes to it, and rebuilt from the recorded argument `value`.
+struct ParamCode {
+    arg: TokenStream,
+    output: Option<TokenStream>,
+    replay: TokenStream,
+}
+
+fn param_code(
+    param: &Param<'_>,
+    params: &[Param<'_>],
+    types: &TypeInfo<'_>,
+    value: &TokenStream,
+) -> ParamCode {
+    let ident = &param.ident;
+    let ptr = quote!(#ident);
+    let element = Element::of(param.ty.type_.name, types);
+    let len = param_len(param, params);
+    let null = ParamCode {
+        arg: quote!(Value::Null),
+        output: None,
+        replay: quote!(ptr::null_mut()),
+    };
+    match param.ty.type_.reference_type {
+        _ if param.ty.static_array.is_some() => ParamCode {
+            arg: quote!(record_array(#ident, 1, |element| element.record())),
+            output: None,
+            replay: quote!(replay_array(#value, arena, |value, arena| DeepCopyable::replay(value, arena))?),
+        },
+        CReferenceType::Value => ParamCode {
+            arg: quote!(#ident.record()),
+            output: None,
+            replay: quote!(DeepCopyable::replay(#value, arena)?),
+        },
+        CReferenceType::PointerToConst if param.ty.type_.name == "VkAllocationCallbacks" => null,
+        CReferenceType::PointerToConst => match len {
+            Some(len) => ParamCode {
+                arg: element.record(&ptr, param.len().map(|_| &len)),
+                output: None,
+                replay: element.replay(value, param.len().is_some()),
+            },
+            None if element == Element::Str => ParamCode {
+                arg: element.record(&ptr, None),
+                output: None,
+                replay: element.replay(value, false),
+            },
+            None => null,
+        },
+        CReferenceType::Pointer => {
+            let Some(len) = len else {
+                return ParamCode {
+                    output: Some(quote!(Value::Null)),
+                    ..null
+                };
+            };
+            let is_count = param.is_count() && params.iter().any(|p| p.len() == Some(param.name()));
+            match element {
+                Element::Opaque if param.len().is_some() => ParamCode {
+                    arg: quote!(record_data_output(#ident.cast::<u8>(), #len)),
+                    output: Some(quote!(record_data(#ident.cast::<u8>(), #len))),
+                    replay: element.replay(value, true),
+                },
+                Element::Opaque => ParamCode {
+                    output: Some(quote!(Value::Null)),
+                    ..null
+                },
+                Element::Native => ParamCode {
+                    arg: quote!(record_output(#ident, #len)),
+                    output: Some(element.record(&ptr, Some(&len))),
+                    replay: element.replay(value, true),
+                },
+                // Counts are read by the driver, and structs carry a `p_next` chain to fill in
+                _ if is_count || types.has_s_type(param.ty.type_.name) => {
+                    let record = Element::Copyable.record(&ptr, Some(&len));
+                    ParamCode {
+                        arg: record.clone(),
+                        output: Some(record),
+                        replay: Element::Copyable.replay(value, true),
+                    }
+                }
+                _ => ParamCode {
+                    arg: quote!(record_output(#ident, #len)),
+                    output: Some(Element::Copyable.record(&ptr, Some(&len))),
+                    replay: Element::Copyable.replay(value, true),
+                },
+            }
+        }
+        CReferenceType::PointerToPointer if param.len().is_none() => ParamCode {
+            arg: quote!(record_output(#ident, 1)),
+            output: Some(Element::Copyable.record(&ptr, None)),
+            replay: Element::Copyable.replay(value, true),
+        },
+        CReferenceType::PointerToPointer | CReferenceType::PointerToPointerToConst => ParamCode {
+            output: Some(quote!(Value::Null)),
+            ..null
+        },
+        _ => null,
+    }
+}
+
+/// Generates the recording stub and replay function of a device-level command.
+fn command(cmd: &vk_parse::CommandDefinition, index: usize, types: &TypeInfo<'_>) -> TokenStream {
+    let name = cmd.proto.name.as_str();
+    let fn_name = format_ident!("{}", name.strip_prefix("vk").unwrap().to_snake_case());
+    let replay_name = format_ident!("replay_{}", fn_name);
+    let pfn = format_ident!("PFN_{}", name);
+
+    let params = Param::of(cmd);
+    let param_count = params.len();
+    let mut uses = Default::default();
+    let idents = params.iter().map(|p| &p.ident).collect::<Vec<_>>();
+    let param_types = params
+        .iter()
+        .map(|p| types.param_tokens(&p.ty, 0, &mut uses))
+        .collect::<Vec<_>>();
+    let ret = cmd.proto.type_name.as_deref().unwrap();
+    let returns = (ret != "void").then(|| {
+        let ret = types.tokens(ret, &mut uses);
+        quote!(-> #ret)
+    });
+
+    let code = params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| param_code(param, &params, types, &quote!(&args[#i])))
+        .collect::<Vec<_>>();
+    let args = code.iter().map(|code| &code.arg);
+    let outputs = code.iter().filter_map(|code| code.output.as_ref());
+    let outputs = quote!(vec![#(#outputs),*]);
+    let replays = code.iter().map(|code| &code.replay);
+
+    // Device-level commands are dispatched through their first parameter
+    let handle = idents[0];
+    let key = quote!(dispatch_key(#handle.as_raw()));
+
+    let (result, record_result) = match returns {
+        Some(_) => (quote!(let result =), quote!(result.record())),
+        None => (quote!(), quote!(Value::Null)),
+    };
+    let returned = returns.as_ref().map(|_| quote!(result));
+
+    quote! {
+        unsafe extern "system" fn #fn_name(#(#idents: #param_types),*) #returns {
+            let next = mem::transmute::<*mut c_void, vk::#pfn>(next(#index, #key));
+            if !is_recording() {
+                return next(#(#idents),*);
+            }
+            let args = vec![#(#args),*];
+            #result next(#(#idents),*);
+            push_call(#name, args, #outputs, #record_result);
+            #returned
+        }
+
+        unsafe fn #replay_name(
+            f: unsafe extern "system" fn(),
+            call: &Call,
+            arena: &mut Arena,
+        ) -> Result<Value, ValueError> {
+            let f = mem::transmute::<unsafe extern "system" fn(), vk::#pfn>(f);
+            let args = args::<#param_count>(call)?;
+            #(let #idents: #param_types = #replays;)*
+            #result f(#(#idents),*);
+            let outputs: Vec<Value> = #outputs;
+            for (recorded, replayed) in call.outputs.iter().zip(&outputs) {
+                map_handles(recorded, replayed, arena);
+            }
+            Ok(#record_result)
+        }
+    }
+}
+
+/// Generates `ash/src/trace/generated.rs`.
+pub fn generate_trace(
+    commands: &CommandMap<'_>,
+    cmd_aliases: &HashMap<&str, &str>,
+    types: &TypeInfo<'_>,
+) -> TokenStream {
+    let commands = commands
+        .values()
+        .filter(|cmd| matches!(cmd.function_type(), FunctionType::Device))
+        .map(|cmd| (cmd.proto.name.as_str(), *cmd))
+        .collect::<BTreeMap<_, _>>();
+    let indices = commands
+        .keys()
+        .enumerate()
+        .map(|(i, &name)| (name, i))
+        .collect::<HashMap<_, _>>();
+    let stubs = commands
+        .values()
+        .map(|cmd| command(cmd, indices[cmd.proto.name.as_str()], types));
+
+    let mut names = BTreeMap::<&str, Vec<&str>>::new();
+    for (&alias, &name) in cmd_aliases {
+        if commands.contains_key(name) {
+            names.entry(name).or_default().push(alias);
+        }
+    }
+    for aliases in names.values_mut() {
+        aliases.sort_unstable();
+    }
+    let hooks = commands
+        .keys()
+        .map(|&name| (name, name))
+        .chain(
+            names
+                .iter()
+                .flat_map(|(&name, aliases)| aliases.iter().map(move |&alias| (alias, name))),
+        )
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .map(|(name, target)| {
+            let literal = proc_macro2::Literal::byte_string(name.as_bytes());
+            let index = indices[target];
+            let fn_name = format_ident!("{}", target.strip_prefix("vk").unwrap().to_snake_case());
+            quote!(#literal => (#index, #fn_name as *const c_void),)
+        });
+    let replays = commands.keys().map(|&name| {
+        let mut all_names = vec![name];
+        if let Some(aliases) = names.get(name) {
+            all_names.extend(aliases);
+        }
+        let all_names = all_names
+            .iter()
+            .map(|name| proc_macro2::Literal::byte_string(format!("{name}\0").as_bytes()));
+        let replay_name = format_ident!(
+            "replay_{}",
+            name.strip_prefix("vk").unwrap().to_snake_case()
+        );
+        quote!(#name => (&[#(#all_names),*], #replay_name),)
+    });
+
+    quote! {
+        use super::*;
+        use std::os::raw::c_int;
+
+        /// Returns the index of a device-level command in the forwarding tables and its recording
+        /// stub.
+        pub(super) fn stub(name: &[u8]) -> Option<(usize, *const c_void)> {
+            Some(match name {
+                #(#hooks)*
+                _ => return None,
+            })
+        }
+
+        /// The names that a recorded command can be loaded with, null-terminated, and its replay
+        /// function.
+        pub(super) fn replay_fn(command: &str) -> Option<(&'static [&'static [u8]], ReplayFn)> {
+            Some(match command {
+                #(#replays)*
+                _ => return None,
+            })
+        }
+
+        #(#stubs)*
+    }
+}
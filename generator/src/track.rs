@@ -0,0 +1,265 @@
+//! Tracking stubs for every command, backing the handle lifetime tracker of `ash::track`.
+
+use super::{
+    mock::len_tokens, mock::Param, wrappers::TypeInfo, CReferenceType, CommandExt, CommandMap,
+    FieldExt, FunctionType,
+};
+use heck::ToSnakeCase;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap};
+
+/// Finds the parent of the handles of type `handle` created by a command: a handle parameter of
+/// the parent type, a member of that type in a struct parameter, or the dispatchable handle the
+/// command is called on.
+fn parent_tokens(
+    handle: &str,
+    params: &[Param<'_>],
+    types: &TypeInfo<'_>,
+    parents: &HashMap<&str, &str>,
+    structs: &HashMap<&str, &vkxml::Struct>,
+) -> TokenStream {
+    let is_handle = |param: &Param<'_>| types.handles.contains(types.resolve(param.ty.type_.name));
+    if let Some(&parent) = parents.get(handle) {
+        for param in params {
+            let ident = &param.ident;
+            let ty = types.resolve(param.ty.type_.name);
+            match param.ty.type_.reference_type {
+                CReferenceType::Value if ty == parent => return quote!(parent_of(#ident)),
+                CReferenceType::PointerToConst if param.len().is_none() => {
+                    let member = structs.get(ty).and_then(|s| {
+                        s.elements
+                            .iter()
+                            .filter_map(get_variant!(vkxml::StructElement::Member))
+                            .find(|field| {
+                                field.reference.is_none()
+                                    && field.array.is_none()
+                                    && types.resolve(&field.basetype) == parent
+                            })
+                    });
+                    if let Some(member) = member {
+                        let member = member.param_ident();
+                        return quote!(if #ident.is_null() {
+                            None
+                        } else {
+                            parent_of((*#ident).#member)
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    match params.first() {
+        Some(param)
+            if is_handle(param)
+                && matches!(param.ty.type_.reference_type, CReferenceType::Value) =>
+        {
+            let ident = &param.ident;
+            quote!(parent_of(#ident))
+        }
+        _ => quote!(None),
+    }
+}
+
+fn command(
+    cmd: &vk_parse::CommandDefinition,
+    index: usize,
+    types: &TypeInfo<'_>,
+    parents: &HashMap<&str, &str>,
+    structs: &HashMap<&str, &vkxml::Struct>,
+) -> TokenStream {
+    let name = cmd.proto.name.as_str();
+    let fn_name = format_ident!("{}", name.strip_prefix("vk").unwrap().to_snake_case());
+    let pfn = format_ident!("PFN_{}", name);
+    let params = Param::of(cmd);
+    let is_handle = |param: &Param<'_>| types.handles.contains(types.resolve(param.ty.type_.name));
+
+    let mut uses = Default::default();
+    let idents = params.iter().map(|p| &p.ident).collect::<Vec<_>>();
+    let param_types = params
+        .iter()
+        .map(|p| types.param_tokens(&p.ty, 0, &mut uses))
+        .collect::<Vec<_>>();
+    let ret = cmd.proto.type_name.as_deref().unwrap();
+    let returns = (ret != "void").then(|| {
+        let ret = types.tokens(ret, &mut uses);
+        quote!(-> #ret)
+    });
+
+    // The last handle parameter of `vkDestroy*()` and `vkFree*()` commands is destroyed
+    let destroyed = (name.starts_with("vkDestroy") || name.starts_with("vkFree"))
+        .then(|| params.iter().rposition(is_handle))
+        .flatten();
+    let mut before = Vec::new();
+    for (i, param) in params.iter().enumerate() {
+        let ident = &param.ident;
+        match param.ty.type_.reference_type {
+            CReferenceType::Value if is_handle(param) && Some(i) == destroyed => {
+                before.push(quote!(destroy(#name, #ident);));
+            }
+            CReferenceType::PointerToConst if is_handle(param) && Some(i) == destroyed => {
+                if let Some(len) = len_tokens(param, &params) {
+                    before.push(quote! {
+                        if !#ident.is_null() {
+                            for i in 0..#len {
+                                destroy(#name, *#ident.add(i));
+                            }
+                        }
+                    });
+                }
+            }
+            CReferenceType::Value if is_handle(param) => before.push(quote!(used(#name, #ident);)),
+            _ => {}
+        }
+    }
+
+    let is_created = ["vkCreate", "vkAllocate", "vkRegister"]
+        .iter()
+        .any(|prefix| name.starts_with(prefix));
+    let mut after = Vec::new();
+    for param in &params {
+        if !(is_handle(param) && matches!(param.ty.type_.reference_type, CReferenceType::Pointer)) {
+            continue;
+        }
+        let ident = &param.ident;
+        let parent = parent_tokens(
+            types.resolve(param.ty.type_.name),
+            &params,
+            types,
+            parents,
+            structs,
+        );
+        let Some(len) = param.len() else {
+            after.push(quote! {
+                if !#ident.is_null() {
+                    created(#name, *#ident, #parent, #is_created);
+                }
+            });
+            continue;
+        };
+        let len = len_tokens(param, &params).or_else(|| {
+            // Enumerations write the element count after the call
+            let count = params.iter().find(|p| p.name() == len && p.is_count())?;
+            let count = &count.ident;
+            Some(quote!(*#count as usize))
+        });
+        if let Some(len) = len {
+            after.push(quote! {
+                if !#ident.is_null() {
+                    let parent = #parent;
+                    for i in 0..#len {
+                        created(#name, *#ident.add(i), parent, #is_created);
+                    }
+                }
+            });
+        }
+    }
+    if name == "vkResetDescriptorPool" {
+        after.push(quote!(reset(#name, descriptor_pool);));
+    }
+
+    // Commands other than the global ones are dispatched through their first parameter
+    let key = match cmd.function_type() {
+        FunctionType::Entry => quote!(0),
+        _ => {
+            let handle = idents[0];
+            quote!(dispatch_key(#handle.as_raw()))
+        }
+    };
+    let (result, check, returned) = match ret {
+        _ if after.is_empty() => (quote!(), quote!(), quote!()),
+        "void" => (quote!(), quote!(;), quote!()),
+        "VkResult" => (
+            quote!(let result =),
+            quote!(; if result.as_raw() < 0 { return result; }),
+            quote!(result),
+        ),
+        _ => (quote!(let result =), quote!(;), quote!(result)),
+    };
+
+    quote! {
+        unsafe extern "system" fn #fn_name(#(#idents: #param_types),*) #returns {
+            #(#before)*
+            let next = mem::transmute::<*mut c_void, vk::#pfn>(next(#index, #key));
+            #result next(#(#idents),*)
+            #check
+            #(#after)*
+            #returned
+        }
+    }
+}
+
+/// Generates `ash/src/track/generated.rs`.
+pub fn generate_track(
+    commands: &CommandMap<'_>,
+    cmd_aliases: &HashMap<&str, &str>,
+    types: &TypeInfo<'_>,
+    definitions: &[&vkxml::DefinitionsElement],
+) -> TokenStream {
+    // The loader entry points are replaced by `ash::track` itself
+    let is_proc_addr = |name: &str| matches!(name, "vkGetInstanceProcAddr" | "vkGetDeviceProcAddr");
+    let commands = commands
+        .values()
+        .filter(|cmd| !is_proc_addr(&cmd.proto.name))
+        .map(|cmd| (cmd.proto.name.as_str(), *cmd))
+        .collect::<BTreeMap<_, _>>();
+    let indices = commands
+        .keys()
+        .enumerate()
+        .map(|(i, &name)| (name, i))
+        .collect::<HashMap<_, _>>();
+
+    let parents = definitions
+        .iter()
+        .filter_map(get_variant!(vkxml::DefinitionsElement::Handle))
+        .filter_map(|handle| Some((handle.name.as_str(), handle.parent.as_deref()?)))
+        .collect::<HashMap<_, _>>();
+    let structs = definitions
+        .iter()
+        .filter_map(get_variant!(vkxml::DefinitionsElement::Struct))
+        .map(|s| (s.name.as_str(), s))
+        .collect::<HashMap<_, _>>();
+    let stubs = commands.values().map(|cmd| {
+        command(
+            cmd,
+            indices[cmd.proto.name.as_str()],
+            types,
+            &parents,
+            &structs,
+        )
+    });
+
+    let hooks = commands
+        .keys()
+        .map(|&name| (name, name))
+        .chain(
+            cmd_aliases
+                .iter()
+                .filter(|(_alias, name)| commands.contains_key(*name))
+                .map(|(&alias, &name)| (alias, name)),
+        )
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .map(|(name, target)| {
+            let literal = Literal::byte_string(name.as_bytes());
+            let index = indices[target];
+            let fn_name = format_ident!("{}", target.strip_prefix("vk").unwrap().to_snake_case());
+            quote!(#literal => (#index, #fn_name as *const c_void),)
+        });
+
+    quote! {
+        use super::*;
+        use std::os::raw::c_int;
+
+        /// Returns the index of a command in the forwarding tables and its tracking stub.
+        pub(super) fn stub(name: &[u8]) -> Option<(usize, *const c_void)> {
+            Some(match name {
+                #(#hooks)*
+                _ => return None,
+            })
+        }
+
+        #(#stubs)*
+    }
+}
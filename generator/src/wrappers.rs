@@ -0,0 +1,1346 @@
+//! High-level wrappers around the function pointer tables of extensions, derived from the
+//! command metadata in `vk.xml`: output parameters become return values, two-call enumerations
+//! return a [`Vec`], arrays described by a `len` become slices and [`vk::Result`] success codes
+//! are checked.
+//!
+//! [`vk::Result`]: https://docs.rs/ash/latest/ash/vk/struct.Result.html
+
+use super::{
+    khronos_link, name_to_tokens, parse_c_parameter, CParameter, CReferenceType, CommandExt,
+    CommandMap, FeatureExt, FunctionType, DESIRED_API,
+};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use syn::Ident;
+
+/// Properties of the types referenced by commands, collected while generating the definitions.
+pub struct TypeInfo<'a> {
+    /// Structs that have an `sType` member, and must thus be initialized by the caller before
+    /// Vulkan writes to them.
+    pub s_type_structs: HashSet<&'a str>,
+    /// Structs that carry a lifetime parameter.
+    pub has_lifetimes: &'a HashSet<Ident>,
+    /// Type aliases, mapping to the aliased type.
+    pub aliases: HashMap<&'a str, &'a str>,
+    /// Handle types, including aliases.
+    pub handles: HashSet<&'a str>,
+}
+
+impl TypeInfo<'_> {
+    pub(super) fn resolve<'n>(&'n self, name: &'n str) -> &'n str {
+        let mut name = name;
+        while let Some(alias) = self.aliases.get(name) {
+            name = alias;
+        }
+        name
+    }
+
+    pub(super) fn has_s_type(&self, name: &str) -> bool {
+        self.s_type_structs.contains(self.resolve(name))
+    }
+
+    /// The type of a parameter as referred to from outside the `vk` module, with `levels` of
+    /// pointers removed.
+    pub(super) fn param_tokens(
+        &self,
+        param: &CParameter<'_>,
+        levels: usize,
+        uses: &mut Uses,
+    ) -> TokenStream {
+        let ty = &param.type_;
+        let base = self.tokens(ty.name, uses);
+        let pointers: &[TokenStream] = match ty.reference_type {
+            CReferenceType::Value => &[],
+            CReferenceType::PointerToConst => &[quote!(*const)],
+            CReferenceType::Pointer => &[quote!(*mut)],
+            CReferenceType::PointerToPointer => &[quote!(*mut), quote!(*mut)],
+            CReferenceType::PointerToPointerToConst => &[quote!(*mut), quote!(*const)],
+            CReferenceType::PointerToConstPointer => &[quote!(*const), quote!(*mut)],
+            CReferenceType::PointerToConstPointerToConst => &[quote!(*const), quote!(*const)],
+        };
+        let pointers = &pointers[levels.min(pointers.len())..];
+        let ty = quote!(#(#pointers)* #base);
+        match param.static_array {
+            Some(len) if levels == 0 => quote!(*const [#ty; #len]),
+            Some(len) => quote!([#ty; #len]),
+            None => ty,
+        }
+    }
+
+    /// The type `name` as referred to from outside the `vk` module.
+    pub(super) fn tokens(&self, name: &str, uses: &mut Uses) -> TokenStream {
+        let ident = name_to_tokens(name);
+        if ident == name_to_tokens(self.resolve(name))
+            && !name.starts_with("Vk")
+            && !name.starts_with("PFN_")
+            && ident != name
+        {
+            // Primitive types, see name_to_tokens()
+            if ident.to_string().starts_with("c_") {
+                uses.raw = true;
+            }
+            quote!(#ident)
+        } else if self
+            .has_lifetimes
+            .contains(&name_to_tokens(self.resolve(name)))
+        {
+            quote!(vk::#ident<'_>)
+        } else {
+            quote!(vk::#ident)
+        }
+    }
+}
+
+/// Imports needed by the generated code of a vendor module, besides those always needed.
+#[derive(Default)]
+pub(super) struct Uses {
+    device: bool,
+    entry: bool,
+    prelude: bool,
+    raw_ptr: bool,
+    raw: bool,
+    ptr: bool,
+    success_codes: bool,
+    c_str: bool,
+    mem: bool,
+}
+
+impl Uses {
+    /// The imports of generated code, besides `crate::vk` and the wrapped handle types.
+    fn imports(&self) -> TokenStream {
+        let prelude = self.prelude.then(|| {
+            quote!(
+                use crate::prelude::*;
+            )
+        });
+        let raw_ptr = self.raw_ptr.then(|| {
+            quote!(
+                use crate::RawPtr;
+            )
+        });
+        let success_codes = self.success_codes.then(|| {
+            quote!(
+                use crate::success_codes;
+            )
+        });
+        let c_str = self.c_str.then(|| {
+            quote!(
+                use std::ffi::CStr;
+            )
+        });
+        let mem = self.mem.then(|| {
+            quote!(
+                use std::mem;
+            )
+        });
+        let raw = self.raw.then(|| {
+            quote!(
+                use std::os::raw::*;
+            )
+        });
+        let ptr = self.ptr.then(|| {
+            quote!(
+                use std::ptr;
+            )
+        });
+        quote! {
+            #prelude
+            #raw_ptr
+            #success_codes
+            #c_str
+            #mem
+            #raw
+            #ptr
+        }
+    }
+}
+
+/// A command parameter, with its name stripped from the Hungarian `p`/`pp` prefix.
+struct Param<'a> {
+    param: &'a vk_parse::CommandParam,
+    ident: Ident,
+    ty: CParameter<'a>,
+}
+
+impl Param<'_> {
+    fn name(&self) -> &str {
+        &self.param.definition.name
+    }
+
+    fn is_optional(&self) -> bool {
+        self.param
+            .optional
+            .as_deref()
+            .is_some_and(|o| o.starts_with("true"))
+    }
+
+    /// The first part of the `len` attribute, which describes the outermost pointer.
+    fn len(&self) -> Option<&str> {
+        self.param.len.as_deref().and_then(|l| l.split(',').next())
+    }
+}
+
+fn param_ident(name: &str, taken: &HashSet<&str>) -> Ident {
+    let stripped = name
+        .strip_prefix("pp")
+        .or_else(|| name.strip_prefix('p'))
+        .filter(|s| s.starts_with(|c: char| c.is_ascii_uppercase()))
+        .filter(|s| !taken.contains(s.to_snake_case().as_str()));
+    let name = stripped.unwrap_or(name);
+    let name = match name {
+        "type" => "ty",
+        _ => name,
+    };
+    format_ident!("{}", name.to_snake_case())
+}
+
+/// How a parameter is exposed by the wrapper method.
+enum Kind {
+    /// The dispatchable handle the wrapper was created for.
+    Handle,
+    /// Passed through as is.
+    Value,
+    /// A `VkBool32` taken as `bool`.
+    Bool,
+    /// A static array, taken by reference.
+    StaticArray,
+    /// `VkAllocationCallbacks`, taken as an [`Option`].
+    AllocationCallbacks,
+    /// A null-terminated string.
+    CStr,
+    /// A pointer to a single value.
+    Ref { optional: bool },
+    /// An array of values; the count of `len` (if any) is derived from the first slice.
+    Slice { len: Option<usize> },
+    /// A value Vulkan writes to, which must have been initialized by the caller.
+    MutRef,
+    /// An array Vulkan writes to, allocated by the caller.
+    MutSlice { len: Option<usize> },
+    /// A value Vulkan writes to, returned by the wrapper.
+    Output,
+    /// An array Vulkan writes to, sized by `len` and returned by the wrapper.
+    OutputVec { len: TokenStream },
+    /// An array count, derived from the length of the slice at the given index.
+    Count { slice: usize },
+}
+
+/// A wrapper method argument, with the code passing it to the function pointer.
+#[derive(Default)]
+struct Method {
+    args: Vec<TokenStream>,
+    setup: Vec<TokenStream>,
+    call_args: Vec<TokenStream>,
+    finish: Vec<TokenStream>,
+    outputs: Vec<(TokenStream, TokenStream)>,
+}
+
+struct Wrapper<'a> {
+    handle_type: &'static str,
+    types: &'a TypeInfo<'a>,
+    vendor_suffix: String,
+    /// The function pointer table the commands are called through, such as `self.fp`.
+    fp: TokenStream,
+}
+
+impl Wrapper<'_> {
+    /// Classifies all parameters, returns [`None`] when a parameter does not fit any of the
+    /// supported patterns.
+    fn classify(&self, params: &[Param<'_>]) -> Option<Vec<Kind>> {
+        let index_of = |name: &str| params.iter().position(|p| p.name() == name);
+        // Arrays with a count parameter, by the index of that count
+        let mut counted = BTreeMap::<usize, Vec<usize>>::new();
+        for (i, param) in params.iter().enumerate() {
+            if let Some(count) = param.len().and_then(index_of) {
+                if !matches!(params[count].ty.type_.reference_type, CReferenceType::Value) {
+                    return None;
+                }
+                counted.entry(count).or_default().push(i);
+            }
+        }
+
+        let mut kinds = Vec::with_capacity(params.len());
+        for (i, param) in params.iter().enumerate() {
+            let ty = &param.ty.type_;
+            let type_name = ty.name;
+            let is_void = type_name == "void";
+            let kind = if param.ty.static_array.is_some() {
+                match ty.reference_type {
+                    CReferenceType::Value if param.param.definition.code.starts_with("const ") => {
+                        Kind::StaticArray
+                    }
+                    _ => return None,
+                }
+            } else if let Some(arrays) = counted.get(&i) {
+                let is_input = |&&a: &&usize| {
+                    matches!(
+                        params[a].ty.type_.reference_type,
+                        CReferenceType::PointerToConst
+                            | CReferenceType::PointerToConstPointer
+                            | CReferenceType::PointerToConstPointerToConst
+                    )
+                };
+                let slice = arrays
+                    .iter()
+                    .find(is_input)
+                    .or_else(|| arrays.first())
+                    .copied()?;
+                Kind::Count { slice }
+            } else {
+                match ty.reference_type {
+                    _ if i == 0 && type_name == self.handle_type => Kind::Handle,
+                    CReferenceType::Value if type_name == "VkBool32" => Kind::Bool,
+                    CReferenceType::Value => Kind::Value,
+                    CReferenceType::PointerToConst if type_name == "VkAllocationCallbacks" => {
+                        Kind::AllocationCallbacks
+                    }
+                    CReferenceType::PointerToConst
+                        if type_name == "char" && param.len() == Some("null-terminated") =>
+                    {
+                        Kind::CStr
+                    }
+                    CReferenceType::PointerToConst
+                    | CReferenceType::PointerToConstPointer
+                    | CReferenceType::PointerToConstPointerToConst
+                        if param.len().is_some() =>
+                    {
+                        Kind::Slice {
+                            len: param.len().and_then(index_of),
+                        }
+                    }
+                    CReferenceType::PointerToConst if is_void => Kind::Value,
+                    CReferenceType::PointerToConst => Kind::Ref {
+                        optional: param.is_optional(),
+                    },
+                    CReferenceType::Pointer => match param.len() {
+                        Some(len) => match index_of(len) {
+                            Some(count) => match counted[&count]
+                                .iter()
+                                .map(|&a| &params[a].ty.type_.reference_type)
+                                .position(|r| !matches!(r, CReferenceType::Pointer))
+                            {
+                                // Sized by an input slice sharing the same count
+                                Some(_) => {
+                                    let input = &params[counted[&count]
+                                        .iter()
+                                        .copied()
+                                        .find(|&a| {
+                                            !matches!(
+                                                params[a].ty.type_.reference_type,
+                                                CReferenceType::Pointer
+                                            )
+                                        })
+                                        .unwrap()]
+                                    .ident;
+                                    Kind::OutputVec {
+                                        len: quote!(#input.len()),
+                                    }
+                                }
+                                None => Kind::MutSlice { len: Some(count) },
+                            },
+                            None => {
+                                let (struct_, member) =
+                                    len.split_once("->").or_else(|| len.split_once("::"))?;
+                                let struct_ = &params[index_of(struct_)?].ident;
+                                let member = format_ident!("{}", member.to_snake_case());
+                                Kind::OutputVec {
+                                    len: quote!(#struct_.#member as usize),
+                                }
+                            }
+                        },
+                        None if is_void => Kind::Value,
+                        None if self.types.has_s_type(type_name) => Kind::MutRef,
+                        None => Kind::Output,
+                    },
+                    CReferenceType::PointerToPointer if param.len().is_none() && is_void => {
+                        Kind::Output
+                    }
+                    _ => return None,
+                }
+            };
+            kinds.push(kind);
+        }
+        Some(kinds)
+    }
+
+    /// The type of a parameter in the function pointer, with `levels` of pointers removed.
+    fn pointee_tokens(&self, param: &Param<'_>, levels: usize, uses: &mut Uses) -> TokenStream {
+        self.types.param_tokens(&param.ty, levels, uses)
+    }
+
+    /// The element type of an array parameter; `void` arrays are taken as bytes.
+    fn element_tokens(&self, param: &Param<'_>, uses: &mut Uses) -> (TokenStream, bool) {
+        if param.ty.type_.name == "void"
+            && matches!(
+                param.ty.type_.reference_type,
+                CReferenceType::Pointer | CReferenceType::PointerToConst
+            )
+        {
+            (quote!(u8), true)
+        } else {
+            (self.pointee_tokens(param, 1, uses), false)
+        }
+    }
+
+    fn method(&self, params: &[Param<'_>], kinds: &[Kind], uses: &mut Uses) -> Method {
+        let mut method = Method::default();
+        for (param, kind) in params.iter().zip(kinds) {
+            let ident = &param.ident;
+            let call_arg = match kind {
+                Kind::Handle => quote!(self.handle),
+                Kind::Value => {
+                    let ty = self.pointee_tokens(param, 0, uses);
+                    method.args.push(quote!(#ident: #ty));
+                    quote!(#ident)
+                }
+                Kind::Bool => {
+                    method.args.push(quote!(#ident: bool));
+                    quote!(#ident.into())
+                }
+                Kind::StaticArray => {
+                    let ty = self.pointee_tokens(param, 1, uses);
+                    method.args.push(quote!(#ident: &#ty));
+                    quote!(#ident)
+                }
+                Kind::AllocationCallbacks => {
+                    uses.raw_ptr = true;
+                    method
+                        .args
+                        .push(quote!(#ident: Option<&vk::AllocationCallbacks>));
+                    quote!(#ident.as_raw_ptr())
+                }
+                Kind::CStr => {
+                    uses.c_str = true;
+                    method.args.push(quote!(#ident: &CStr));
+                    quote!(#ident.as_ptr())
+                }
+                Kind::Ref { optional } => {
+                    let ty = self.pointee_tokens(param, 1, uses);
+                    if *optional {
+                        uses.raw_ptr = true;
+                        method.args.push(quote!(#ident: Option<&#ty>));
+                        quote!(#ident.as_raw_ptr())
+                    } else {
+                        method.args.push(quote!(#ident: &#ty));
+                        quote!(#ident)
+                    }
+                }
+                Kind::Slice { len } => {
+                    let (ty, cast) = self.element_tokens(param, uses);
+                    let primary = len.map(|len| match &kinds[len] {
+                        Kind::Count { slice } => &params[*slice].ident,
+                        _ => unreachable!(),
+                    });
+                    let cast = cast.then(|| quote!(.cast()));
+                    match primary {
+                        Some(primary) if primary != ident && param.is_optional() => {
+                            uses.ptr = true;
+                            method.args.push(quote!(#ident: Option<&[#ty]>));
+                            method.setup.push(quote! {
+                                if let Some(#ident) = #ident {
+                                    assert_eq!(#primary.len(), #ident.len());
+                                }
+                            });
+                            quote!(#ident.map_or(ptr::null(), <[_]>::as_ptr) #cast)
+                        }
+                        Some(primary) if primary != ident => {
+                            method.args.push(quote!(#ident: &[#ty]));
+                            method
+                                .setup
+                                .push(quote!(assert_eq!(#primary.len(), #ident.len());));
+                            quote!(#ident.as_ptr() #cast)
+                        }
+                        _ => {
+                            method.args.push(quote!(#ident: &[#ty]));
+                            quote!(#ident.as_ptr() #cast)
+                        }
+                    }
+                }
+                Kind::MutRef => {
+                    let ty = self.pointee_tokens(param, 1, uses);
+                    method.args.push(quote!(#ident: &mut #ty));
+                    quote!(#ident)
+                }
+                Kind::MutSlice { len } => {
+                    let (ty, cast) = self.element_tokens(param, uses);
+                    let cast = cast.then(|| quote!(.cast()));
+                    method.args.push(quote!(#ident: &mut [#ty]));
+                    if let Some(Kind::Count { slice }) = len.map(|len| &kinds[len]) {
+                        let primary = &params[*slice].ident;
+                        if primary != ident {
+                            method
+                                .setup
+                                .push(quote!(assert_eq!(#primary.len(), #ident.len());));
+                        }
+                    }
+                    quote!(#ident.as_mut_ptr() #cast)
+                }
+                Kind::Output => {
+                    uses.mem = true;
+                    let ty = self.pointee_tokens(param, 1, uses);
+                    method
+                        .setup
+                        .push(quote!(let mut #ident: #ty = mem::zeroed();));
+                    if param.ty.type_.name == "VkBool32" {
+                        method
+                            .outputs
+                            .push((quote!(bool), quote!(#ident != vk::FALSE)));
+                    } else {
+                        method.outputs.push((ty, quote!(#ident)));
+                    }
+                    quote!(&mut #ident)
+                }
+                Kind::OutputVec { len } => {
+                    let ty = self.pointee_tokens(param, 1, uses);
+                    method
+                        .setup
+                        .push(quote!(let mut #ident = Vec::<#ty>::with_capacity(#len);));
+                    method.finish.push(quote!(#ident.set_len(#len);));
+                    method.outputs.push((quote!(Vec<#ty>), quote!(#ident)));
+                    quote!(#ident.as_mut_ptr())
+                }
+                Kind::Count { slice } => {
+                    let slice = &params[*slice].ident;
+                    if param.ty.type_.name == "size_t" {
+                        quote!(#slice.len())
+                    } else {
+                        let ty = self.pointee_tokens(param, 0, uses);
+                        quote!(#slice.len() as #ty)
+                    }
+                }
+            };
+            method.call_args.push(call_arg);
+        }
+        method
+    }
+
+    /// Generates the wrapper method(s) of a single command.
+    fn command(
+        &self,
+        cmd: &vk_parse::CommandDefinition,
+        function_name_c: &str,
+        uses: &mut Uses,
+    ) -> TokenStream {
+        let fp_member = format_ident!(
+            "{}",
+            function_name_c.strip_prefix("vk").unwrap().to_snake_case()
+        );
+        let fp = &self.fp;
+        let name = fp_member.to_string();
+        let name = name
+            .strip_suffix(self.vendor_suffix.as_str())
+            .unwrap_or(&name);
+        let name = format_ident!("{}", name);
+        let khronos_link = khronos_link(function_name_c);
+
+        let cmd_params = cmd
+            .params
+            .iter()
+            .filter(|param| matches!(param.api.as_deref(), None | Some(DESIRED_API)))
+            .collect::<Vec<_>>();
+        let mut taken = HashSet::new();
+        let mut params = Vec::with_capacity(cmd_params.len());
+        for &param in &cmd_params {
+            let (rem, ty) = parse_c_parameter(&param.definition.code).unwrap();
+            assert!(rem.is_empty());
+            let ident = param_ident(&param.definition.name, &taken);
+            params.push(Param { param, ident, ty });
+            taken.insert(param.definition.name.as_str());
+        }
+
+        let ret = cmd.proto.type_name.as_deref().unwrap();
+        let success_codes = cmd
+            .successcodes
+            .as_deref()
+            .map_or_else(Vec::new, |codes| codes.split(',').collect());
+
+        // Two-call enumerations: a pointer to a count, followed by an array of that count
+        if let [rest @ .., count, array] = params.as_slice() {
+            let is_enumeration = array.len() == Some(count.name())
+                && matches!(array.ty.type_.reference_type, CReferenceType::Pointer)
+                && matches!(count.ty.type_.reference_type, CReferenceType::Pointer)
+                && matches!(count.ty.type_.name, "uint32_t" | "size_t");
+            if is_enumeration {
+                if let Some(kinds) = self
+                    .classify(rest)
+                    .filter(|kinds| !kinds.iter().any(Self::is_output))
+                {
+                    let mut method = self.method(rest, &kinds, uses);
+                    // The enumeration helpers take an `Fn` closure, which cannot reborrow `&mut`
+                    for (param, kind) in rest.iter().zip(&kinds) {
+                        if matches!(kind, Kind::MutRef) {
+                            let ident = &param.ident;
+                            method.setup.push(quote!(let #ident: *mut _ = #ident;));
+                        }
+                    }
+                    return self.enumeration(
+                        &name,
+                        &fp_member,
+                        &khronos_link,
+                        method,
+                        (count, array),
+                        ret,
+                        uses,
+                    );
+                }
+            }
+        }
+
+        let Some(kinds) = self.classify(&params) else {
+            return self.passthrough(&name, &fp_member, &khronos_link, &params, ret, uses);
+        };
+        let Method {
+            args,
+            setup,
+            call_args,
+            finish,
+            outputs,
+        } = self.method(&params, &kinds, uses);
+
+        let (output_types, output_values): (Vec<_>, Vec<_>) = outputs.into_iter().unzip();
+        let tuple = |types: &[TokenStream]| match types {
+            [ty] => quote!(#ty),
+            types => quote!((#(#types),*)),
+        };
+        let call = quote!((#fp.#fp_member)(#(#call_args),*));
+
+        let (returns, body) = match ret {
+            "VkResult" => {
+                uses.prelude = true;
+                if success_codes.len() <= 1 {
+                    let returns = tuple(&output_types);
+                    let body = if finish.is_empty() {
+                        let value = tuple(&output_values);
+                        if output_values.is_empty() {
+                            quote!(#call.result())
+                        } else {
+                            quote!(#call.result_with_success(#value))
+                        }
+                    } else {
+                        let value = tuple(&output_values);
+                        quote! {
+                            #call.result()?;
+                            #(#finish)*
+                            Ok(#value)
+                        }
+                    };
+                    (quote!(VkResult<#returns>), body)
+                } else {
+                    // Return the success code alongside the outputs, see success_code_enums()
+                    uses.success_codes = true;
+                    let codes = success_codes_ident(&cmd.proto.name);
+                    let mut types = output_types;
+                    types.push(quote!(success_codes::#codes));
+                    let mut values = output_values;
+                    values.push(quote!(code));
+                    let returns = tuple(&types);
+                    let value = tuple(&values);
+                    let body = if values.len() == 1 {
+                        quote!(#call.success_code())
+                    } else {
+                        quote! {
+                            let code = #call.success_code()?;
+                            #(#finish)*
+                            Ok(#value)
+                        }
+                    };
+                    (quote!(VkResult<#returns>), body)
+                }
+            }
+            "void" if output_types.is_empty() => (quote!(()), quote!(#call;)),
+            "void" => {
+                let value = tuple(&output_values);
+                (
+                    tuple(&output_types),
+                    quote! {
+                        #call;
+                        #(#finish)*
+                        #value
+                    },
+                )
+            }
+            ret => {
+                let (ret_ty, ret_value) = if ret == "VkBool32" {
+                    (quote!(bool), quote!(ret != vk::FALSE))
+                } else {
+                    (self.types.tokens(ret, uses), quote!(ret))
+                };
+                let mut types = vec![ret_ty];
+                types.extend(output_types);
+                let mut values = vec![ret_value];
+                values.extend(output_values);
+                let value = tuple(&values);
+                let body = if ret != "VkBool32" && values.len() == 1 {
+                    call
+                } else {
+                    quote! {
+                        let ret = #call;
+                        #(#finish)*
+                        #value
+                    }
+                };
+                (tuple(&types), body)
+            }
+        };
+
+        let returns = if returns.to_string() == "()" {
+            None
+        } else {
+            Some(quote!(-> #returns))
+        };
+        quote! {
+            #[doc = #khronos_link]
+            #[inline]
+            pub unsafe fn #name(&self, #(#args),*) #returns {
+                #(#setup)*
+                #body
+            }
+        }
+    }
+
+    fn is_output(kind: &Kind) -> bool {
+        matches!(kind, Kind::Output | Kind::OutputVec { .. })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn enumeration(
+        &self,
+        name: &Ident,
+        fp_member: &Ident,
+        khronos_link: &proc_macro2::Literal,
+        method: Method,
+        (count, array): (&Param<'_>, &Param<'_>),
+        ret: &str,
+        uses: &mut Uses,
+    ) -> TokenStream {
+        let Method {
+            args,
+            setup,
+            call_args,
+            ..
+        } = method;
+        let fp = &self.fp;
+        let (ty, cast) = self.element_tokens(array, uses);
+        let cast = cast.then(|| quote!(.cast()));
+        let is_usize = count.ty.type_.name == "size_t";
+        let as_usize = |v: TokenStream| {
+            if is_usize {
+                v
+            } else {
+                quote!(#v as usize)
+            }
+        };
+
+        match ret {
+            "VkResult" => {
+                uses.prelude = true;
+                let read = if self.types.has_s_type(array.ty.type_.name) {
+                    quote!(read_into_defaulted_vector)
+                } else {
+                    quote!(read_into_uninitialized_vector)
+                };
+                let data = if cast.is_some() {
+                    quote!(data: *mut #ty)
+                } else {
+                    quote!(data)
+                };
+                quote! {
+                    #[doc = #khronos_link]
+                    #[inline]
+                    pub unsafe fn #name(&self, #(#args),*) -> VkResult<Vec<#ty>> {
+                        #(#setup)*
+                        #read(|count, #data| {
+                            (#fp.#fp_member)(#(#call_args,)* count, data #cast)
+                        })
+                    }
+                }
+            }
+            "void" if self.types.has_s_type(array.ty.type_.name) => {
+                // The caller has to initialize the output structs, to be able to extend them
+                uses.ptr = true;
+                uses.mem = true;
+                let len_name = format_ident!("{}_len", name);
+                let count_ty = self.pointee_tokens(count, 1, uses);
+                let array_ident = &array.ident;
+                let count_value = as_usize(quote!(count.assume_init()));
+                let count = if is_usize {
+                    quote!(#array_ident.len())
+                } else {
+                    quote!(#array_ident.len() as #count_ty)
+                };
+                let check = as_usize(quote!(count));
+                let len_doc = format!(
+                    " Retrieve the number of elements to pass to [`{name}()`][Self::{name}()]"
+                );
+                let call_len_doc = format!(
+                    " Call [`{len_name}()`][Self::{len_name}()] to query the number of elements to pass to `{array_ident}`."
+                );
+                quote! {
+                    #[doc = #len_doc]
+                    #[inline]
+                    pub unsafe fn #len_name(&self, #(#args),*) -> usize {
+                        #(#setup)*
+                        let mut count = mem::MaybeUninit::uninit();
+                        (#fp.#fp_member)(#(#call_args,)* count.as_mut_ptr(), ptr::null_mut());
+                        #count_value
+                    }
+
+                    #[doc = #khronos_link]
+                    ///
+                    #[doc = #call_len_doc]
+                    /// Be sure to [`Default::default()`]-initialize these elements and optionally set their `p_next` pointer.
+                    #[inline]
+                    pub unsafe fn #name(&self, #(#args,)* #array_ident: &mut [#ty]) {
+                        #(#setup)*
+                        let mut count = #count;
+                        (#fp.#fp_member)(#(#call_args,)* &mut count, #array_ident.as_mut_ptr());
+                        assert_eq!(#check, #array_ident.len());
+                    }
+                }
+            }
+            "void" => {
+                uses.ptr = true;
+                let len = as_usize(quote!(count));
+                quote! {
+                    #[doc = #khronos_link]
+                    #[inline]
+                    pub unsafe fn #name(&self, #(#args),*) -> Vec<#ty> {
+                        #(#setup)*
+                        let mut count = 0;
+                        (#fp.#fp_member)(#(#call_args,)* &mut count, ptr::null_mut());
+                        let mut data = Vec::with_capacity(#len);
+                        (#fp.#fp_member)(#(#call_args,)* &mut count, data.as_mut_ptr() #cast);
+                        data.set_len(#len);
+                        data
+                    }
+                }
+            }
+            _ => unreachable!("Enumeration {} returns {}", name, ret),
+        }
+    }
+
+    /// Commands that do not fit any of the supported patterns take the raw parameters.
+    fn passthrough(
+        &self,
+        name: &Ident,
+        fp_member: &Ident,
+        khronos_link: &proc_macro2::Literal,
+        params: &[Param<'_>],
+        ret: &str,
+        uses: &mut Uses,
+    ) -> TokenStream {
+        let fp = &self.fp;
+        let mut args = Vec::new();
+        let mut call_args = Vec::new();
+        for (i, param) in params.iter().enumerate() {
+            let ident = &param.ident;
+            if i == 0 && param.ty.type_.name == self.handle_type {
+                call_args.push(quote!(self.handle));
+            } else {
+                let ty = self.pointee_tokens(param, 0, uses);
+                args.push(quote!(#ident: #ty));
+                call_args.push(quote!(#ident));
+            }
+        }
+        let returns = (ret != "void").then(|| {
+            let ret = self.types.tokens(ret, uses);
+            quote!(-> #ret)
+        });
+        quote! {
+            #[doc = #khronos_link]
+            #[inline]
+            pub unsafe fn #name(&self, #(#args),*) #returns {
+                (#fp.#fp_member)(#(#call_args),*)
+            }
+        }
+    }
+}
+
+/// Core commands whose hand-written wrapper in `device.rs` or `instance.rs` differs from what
+/// would be generated, for instance to keep returning the pipelines that were created when
+/// another one failed.
+const HAND_WRITTEN_CORE: &[&str] = &[
+    "vkCreateComputePipelines",
+    "vkCreateDevice",
+    "vkCreateGraphicsPipelines",
+    "vkEnumerateDeviceExtensionProperties",
+    "vkEnumeratePhysicalDeviceGroups",
+    "vkGetDeviceProcAddr",
+    "vkGetEventStatus",
+    "vkGetFenceStatus",
+    "vkGetImageSubresourceLayout",
+    "vkGetPhysicalDeviceToolProperties",
+    "vkGetPrivateData",
+    "vkGetQueryPoolResults",
+    "vkSetPrivateData",
+];
+
+/// Generates the wrappers of the core commands, as an `impl` block per version of `Device` and
+/// `Instance` for Vulkan 1.0, and of their version views such as `DeviceV1_3` for later versions.
+pub fn generate_core_wrappers(
+    features: &[&vkxml::Feature],
+    commands: &CommandMap<'_>,
+    types: &TypeInfo<'_>,
+) -> (TokenStream, TokenStream) {
+    let mut device_uses = Uses::default();
+    let mut instance_uses = Uses::default();
+    let mut device_impls = Vec::new();
+    let mut instance_impls = Vec::new();
+    let mut device_types = Vec::new();
+    let mut instance_types = Vec::new();
+
+    for feature in features {
+        if !super::contains_desired_api(&feature.api) {
+            continue;
+        }
+        let version = feature.version_string();
+        let doc = format!(" Vulkan core {}", version.replace('_', "."));
+        let (device_type, instance_type) = if feature.is_version(1, 0) {
+            (format_ident!("Device"), format_ident!("Instance"))
+        } else {
+            (
+                format_ident!("DeviceV{}", version),
+                format_ident!("InstanceV{}", version),
+            )
+        };
+        let device = Wrapper {
+            handle_type: "VkDevice",
+            types,
+            vendor_suffix: String::new(),
+            fp: {
+                let table = format_ident!("device_fn_{}", version);
+                quote!(self.#table)
+            },
+        };
+        let instance = Wrapper {
+            handle_type: "VkInstance",
+            types,
+            vendor_suffix: String::new(),
+            fp: {
+                let table = format_ident!("instance_fn_{}", version);
+                quote!(self.#table)
+            },
+        };
+
+        let mut device_methods = Vec::new();
+        let mut instance_methods = Vec::new();
+        let names = feature
+            .elements
+            .iter()
+            .filter_map(get_variant!(vkxml::FeatureElement::Require))
+            .flat_map(|spec| &spec.elements)
+            .filter_map(get_variant!(vkxml::FeatureReference::CommandReference))
+            .map(|cmd_ref| cmd_ref.name.as_str())
+            .filter(|name| !HAND_WRITTEN_CORE.contains(name));
+        for name in names {
+            let Some(&cmd) = commands.get(name) else {
+                continue;
+            };
+            match cmd.function_type() {
+                FunctionType::Static | FunctionType::Entry => {}
+                FunctionType::Device => {
+                    device_methods.push(device.command(cmd, name, &mut device_uses));
+                }
+                FunctionType::Instance => {
+                    instance_methods.push(instance.command(cmd, name, &mut instance_uses));
+                }
+            }
+        }
+
+        if !device_methods.is_empty() {
+            device_impls.push(quote! {
+                #[doc = #doc]
+                impl #device_type {
+                    #(#device_methods)*
+                }
+            });
+            device_types.push(device_type);
+        }
+        if !instance_methods.is_empty() {
+            instance_impls.push(quote! {
+                #[doc = #doc]
+                impl #instance_type {
+                    #(#instance_methods)*
+                }
+            });
+            instance_types.push(instance_type);
+        }
+    }
+
+    // Later versions first, like the hand-written blocks
+    device_impls.reverse();
+    instance_impls.reverse();
+    let device_imports = device_uses.imports();
+    let instance_imports = instance_uses.imports();
+    (
+        quote! {
+            use super::{#(#device_types),*};
+            use crate::vk;
+            #device_imports
+
+            #(#device_impls)*
+        },
+        quote! {
+            use super::{#(#instance_types),*};
+            use crate::vk;
+            #instance_imports
+
+            #(#instance_impls)*
+        },
+    )
+}
+
+/// Extensions with a hand-written wrapper in the vendor modules of `ash::extensions`, which are
+/// not generated.
+const HAND_WRITTEN: &[&str] = &[
+    "VK_EXT_acquire_drm_display",
+    "VK_EXT_buffer_device_address",
+    "VK_EXT_calibrated_timestamps",
+    "VK_EXT_debug_marker",
+    "VK_EXT_debug_report",
+    "VK_EXT_debug_utils",
+    "VK_EXT_descriptor_buffer",
+    "VK_EXT_extended_dynamic_state",
+    "VK_EXT_extended_dynamic_state2",
+    "VK_EXT_extended_dynamic_state3",
+    "VK_EXT_full_screen_exclusive",
+    "VK_EXT_headless_surface",
+    "VK_EXT_image_compression_control",
+    "VK_EXT_image_drm_format_modifier",
+    "VK_EXT_mesh_shader",
+    "VK_EXT_metal_surface",
+    "VK_EXT_pipeline_properties",
+    "VK_EXT_private_data",
+    "VK_EXT_sample_locations",
+    "VK_EXT_shader_object",
+    "VK_EXT_tooling_info",
+    "VK_KHR_acceleration_structure",
+    "VK_KHR_android_surface",
+    "VK_KHR_buffer_device_address",
+    "VK_KHR_copy_commands2",
+    "VK_KHR_create_renderpass2",
+    "VK_KHR_deferred_host_operations",
+    "VK_KHR_device_group",
+    "VK_KHR_device_group_creation",
+    "VK_KHR_display",
+    "VK_KHR_display_swapchain",
+    "VK_KHR_draw_indirect_count",
+    "VK_KHR_dynamic_rendering",
+    "VK_KHR_external_fence_fd",
+    "VK_KHR_external_fence_win32",
+    "VK_KHR_external_memory_fd",
+    "VK_KHR_external_memory_win32",
+    "VK_KHR_external_semaphore_fd",
+    "VK_KHR_external_semaphore_win32",
+    "VK_KHR_get_memory_requirements2",
+    "VK_KHR_get_physical_device_properties2",
+    "VK_KHR_get_surface_capabilities2",
+    "VK_KHR_maintenance1",
+    "VK_KHR_maintenance3",
+    "VK_KHR_maintenance4",
+    "VK_KHR_performance_query",
+    "VK_KHR_pipeline_executable_properties",
+    "VK_KHR_present_wait",
+    "VK_KHR_push_descriptor",
+    "VK_KHR_ray_tracing_maintenance1",
+    "VK_KHR_ray_tracing_pipeline",
+    "VK_KHR_surface",
+    "VK_KHR_swapchain",
+    "VK_KHR_synchronization2",
+    "VK_KHR_timeline_semaphore",
+    "VK_KHR_wayland_surface",
+    "VK_KHR_win32_surface",
+    "VK_KHR_xcb_surface",
+    "VK_KHR_xlib_surface",
+    "VK_MVK_ios_surface",
+    "VK_MVK_macos_surface",
+    "VK_NN_vi_surface",
+    "VK_NV_coverage_reduction_mode",
+    "VK_NV_device_diagnostic_checkpoints",
+    "VK_NV_memory_decompression",
+    "VK_NV_mesh_shader",
+    "VK_NV_ray_tracing",
+];
+
+/// Generates a wrapper struct for every extension with commands, in a module per vendor.
+pub fn generate_extension_wrappers<'a>(
+    extensions: &[&'a vk_parse::Extension],
+    commands: &CommandMap<'a>,
+    cmd_aliases: &HashMap<&'a str, &'a str>,
+    types: &TypeInfo<'_>,
+) -> TokenStream {
+    let mut vendors = BTreeMap::<String, (Uses, Vec<TokenStream>)>::new();
+
+    for extension in extensions {
+        // Skip extensions that are only included for their constants, such as VK_ANDROID_native_buffer
+        if !extension
+            .supported
+            .as_deref()
+            .is_none_or(super::contains_desired_api)
+        {
+            continue;
+        }
+        if HAND_WRITTEN.contains(&extension.name.as_str()) {
+            continue;
+        }
+
+        let names = extension
+            .children
+            .iter()
+            .filter_map(get_variant!(vk_parse::ExtensionChild::Require {
+                api,
+                items
+            }))
+            .filter(|(api, _items)| matches!(api.as_deref(), None | Some(DESIRED_API)))
+            .flat_map(|(_api, items)| items)
+            .filter_map(get_variant!(vk_parse::InterfaceItem::Command { name }));
+        let mut seen = HashSet::new();
+        let extension_commands = names
+            .filter(|name| seen.insert(name.as_str()))
+            .map(|name| {
+                let cmd = cmd_aliases.get(name.as_str()).map_or(name.as_str(), |c| c);
+                (commands[cmd], name.as_str())
+            })
+            .collect::<Vec<_>>();
+        if extension_commands.is_empty() {
+            continue;
+        }
+
+        let (vendor, name) = extension
+            .name
+            .strip_prefix("VK_")
+            .and_then(|name| name.split_once('_'))
+            .unwrap();
+        let ident = format_ident!("{}", name.to_upper_camel_case());
+        let fn_ident = format_ident!(
+            "{}Fn",
+            extension
+                .name
+                .to_upper_camel_case()
+                .strip_prefix("Vk")
+                .unwrap()
+        );
+
+        // Like the hand-written `Swapchain`, device extensions load all their commands through
+        // `vkGetDeviceProcAddr()`, including those taking a `VkPhysicalDevice`
+        let is_device = extension.ext_type.as_deref() == Some("device");
+        let (uses, wrappers) = vendors.entry(vendor.to_lowercase()).or_default();
+        // For `NAME` and `new()`
+        uses.c_str = true;
+        uses.mem = true;
+        let wrapper = Wrapper {
+            handle_type: if is_device { "VkDevice" } else { "VkInstance" },
+            types,
+            vendor_suffix: format!("_{}", vendor.to_lowercase()),
+            fp: quote!(self.fp),
+        };
+
+        let methods = extension_commands
+            .iter()
+            .map(|(cmd, name)| wrapper.command(cmd, name, uses))
+            .collect::<Vec<_>>();
+
+        let (new, handle_type, handle_accessor) = if is_device {
+            uses.device = true;
+            (
+                quote! {
+                    pub fn new(instance: &Instance, device: &Device) -> Self {
+                        let handle = device.handle();
+                        let fp = vk::#fn_ident::load(|name| unsafe {
+                            mem::transmute(instance.get_device_proc_addr(handle, name.as_ptr()))
+                        });
+                        Self { handle, fp }
+                    }
+
+                    try_new!(device, vk::#fn_ident);
+                },
+                quote!(vk::Device),
+                quote!(device),
+            )
+        } else {
+            uses.entry = true;
+            (
+                quote! {
+                    pub fn new(entry: &Entry, instance: &Instance) -> Self {
+                        let handle = instance.handle();
+                        let fp = vk::#fn_ident::load(|name| unsafe {
+                            mem::transmute(entry.get_instance_proc_addr(handle, name.as_ptr()))
+                        });
+                        Self { handle, fp }
+                    }
+
+                    try_new!(instance, vk::#fn_ident);
+                },
+                quote!(vk::Instance),
+                quote!(instance),
+            )
+        };
+
+        let khronos_link = khronos_link(&extension.name);
+        wrappers.push(quote! {
+            #[doc = #khronos_link]
+            #[derive(Clone)]
+            pub struct #ident {
+                handle: #handle_type,
+                fp: vk::#fn_ident,
+            }
+
+            impl #ident {
+                #new
+
+                #(#methods)*
+
+                pub const NAME: &'static CStr = vk::#fn_ident::NAME;
+
+                #[inline]
+                pub fn fp(&self) -> &vk::#fn_ident {
+                    &self.fp
+                }
+
+                #[inline]
+                pub fn #handle_accessor(&self) -> #handle_type {
+                    self.handle
+                }
+            }
+        });
+    }
+
+    let modules = vendors.into_iter().map(|(vendor, (uses, wrappers))| {
+        let vendor = format_ident!("{}", vendor);
+        let imports = uses.imports();
+        let entry = uses.entry.then(|| quote!(Entry,));
+        let device = uses.device.then(|| quote!(Device,));
+        quote! {
+            pub mod #vendor {
+                use crate::vk;
+                use crate::{#device #entry Instance};
+                #imports
+
+                #(#wrappers)*
+            }
+        }
+    });
+
+    quote! {
+        #(#modules)*
+    }
+}
+
+/// The name of the enum holding the success codes of `command`.
+fn success_codes_ident(command: &str) -> Ident {
+    format_ident!("{}", command.strip_prefix("vk").unwrap())
+}
+
+/// Collects the aliases of `VkResult` values, such as `VK_PIPELINE_COMPILE_REQUIRED_EXT`.
+pub fn result_aliases(registry: &vk_parse::Registry) -> HashMap<&str, &str> {
+    let enums = registry
+        .0
+        .iter()
+        .filter_map(get_variant!(vk_parse::RegistryChild::Enums))
+        .filter(|enums| enums.name.as_deref() == Some("VkResult"))
+        .flat_map(|enums| &enums.children)
+        .filter_map(get_variant!(vk_parse::EnumsChild::Enum))
+        .filter_map(|enum_| match &enum_.spec {
+            vk_parse::EnumSpec::Alias { alias, .. } => Some((enum_.name.as_str(), alias.as_str())),
+            _ => None,
+        });
+    let extension_enums = registry
+        .0
+        .iter()
+        .filter_map(get_variant!(vk_parse::RegistryChild::Extensions))
+        .flat_map(|extensions| &extensions.children)
+        .flat_map(|extension| &extension.children)
+        .filter_map(get_variant!(vk_parse::ExtensionChild::Require { items }))
+        .flatten()
+        .filter_map(get_variant!(vk_parse::InterfaceItem::Enum))
+        .filter_map(|enum_| match &enum_.spec {
+            vk_parse::EnumSpec::Alias {
+                alias,
+                extends: Some(extends),
+            } if extends == "VkResult" => Some((enum_.name.as_str(), alias.as_str())),
+            _ => None,
+        });
+    enums.chain(extension_enums).collect()
+}
+
+/// Generates an enum for every command with more than one success code, so that wrappers can
+/// return codes such as `VK_TIMEOUT` or `VK_OPERATION_DEFERRED_KHR` as [`Ok`] values that cannot
+/// be mistaken for `VK_SUCCESS`.
+pub fn success_code_enums(
+    commands: &CommandMap<'_>,
+    result_aliases: &HashMap<&str, &str>,
+    tags: &HashSet<&str>,
+) -> TokenStream {
+    let commands = commands
+        .values()
+        .filter_map(|cmd| Some((cmd.proto.name.as_str(), cmd.successcodes.as_deref()?)))
+        .filter(|(_name, codes)| codes.contains(','))
+        .collect::<BTreeMap<_, _>>();
+
+    let enums = commands.into_iter().map(|(command, codes)| {
+        let ident = success_codes_ident(command);
+        let doc = format!(
+            "Success codes of [`{command}`]({})",
+            khronos_link(command)
+                .to_string()
+                .trim_matches(|c| c == '"' || c == '<' || c == '>')
+        );
+        let mut seen = HashSet::new();
+        let (variants, results): (Vec<_>, Vec<_>) = codes
+            .split(',')
+            .map(|code| *result_aliases.get(code).unwrap_or(&code))
+            .filter(|code| seen.insert(*code))
+            .map(|code| {
+                let name = code.strip_prefix("VK_").unwrap();
+                let variant = match name.rsplit_once('_') {
+                    Some((name, tag)) if tags.contains(tag) => {
+                        format_ident!("{}{}", name.to_upper_camel_case(), tag)
+                    }
+                    _ => format_ident!("{}", name.to_upper_camel_case()),
+                };
+                (variant, super::variant_ident("VkResult", code))
+            })
+            .unzip();
+        let variant_docs = results
+            .iter()
+            .map(|result| format!("[`vk::Result::{result}`]"));
+        quote! {
+            #[doc = #doc]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            pub enum #ident {
+                #(
+                    #[doc = #variant_docs]
+                    #variants,
+                )*
+            }
+
+            impl TryFrom<vk::Result> for #ident {
+                type Error = vk::Result;
+
+                #[inline]
+                fn try_from(result: vk::Result) -> Result<Self, Self::Error> {
+                    match result {
+                        #(vk::Result::#results => Ok(Self::#variants),)*
+                        err => Err(err),
+                    }
+                }
+            }
+
+            impl From<#ident> for vk::Result {
+                #[inline]
+                fn from(code: #ident) -> Self {
+                    match code {
+                        #(#ident::#variants => Self::#results,)*
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        use crate::vk;
+
+        #(#enums)*
+    }
+}